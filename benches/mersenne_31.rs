@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+extern crate anemoi;
+use anemoi::mersenne_31::*;
+use anemoi::{Jive, Sponge};
+use rand_core::OsRng;
+use rand_core::RngCore;
+
+use ark_ff::One;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    c.bench_function(
+        "anemoi-jive/mersenne_31/16-8 (128 bits security) - 2-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_16_8::STATE_WIDTH];
+
+            bench.iter(|| anemoi_16_8::AnemoiHash::compress(black_box(&v)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/mersenne_31/24-16 (128 bits security) - 2-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_24_16::STATE_WIDTH];
+
+            bench.iter(|| anemoi_24_16::AnemoiHash::compress(black_box(&v)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-sponge/mersenne_31/16-8 (128 bits security) - hash 10KB",
+        |bench| {
+            let mut data = vec![0u8; 10 * 1024];
+            let mut rng = OsRng;
+            rng.fill_bytes(&mut data);
+
+            bench.iter(|| anemoi_16_8::AnemoiHash::hash(black_box(&data)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-sponge/mersenne_31/24-16 (128 bits security) - hash 10KB",
+        |bench| {
+            let mut data = vec![0u8; 10 * 1024];
+            let mut rng = OsRng;
+            rng.fill_bytes(&mut data);
+
+            bench.iter(|| anemoi_24_16::AnemoiHash::hash(black_box(&data)))
+        },
+    );
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default();
+    targets = criterion_benchmark);
+criterion_main!(benches);
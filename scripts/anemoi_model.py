@@ -0,0 +1,468 @@
+#!/usr/bin/env python3
+"""Standalone model of the Anemoi instantiations of this crate.
+
+This model only relies on the Python standard library. For each supported
+field, it derives all constants of an instantiation from its modulus, as
+described in the Anemoi paper (Bouvier et al., "New Design Techniques for
+Efficient Arithmetization-Oriented Hash Functions: Anemoi Permutations and
+Jive Compression Mode", CRYPTO 2023, ePrint 2022/840):
+
+- ALPHA is the smallest integer a >= 3 coprime with p - 1;
+- BETA is the smallest generator g of the multiplicative group, found from
+  the factorization of p - 1, and DELTA its inverse. For the BW6-761 and
+  MNT4-298 fields, whose p - 1 could not be fully factored, g is only checked
+  against the prime factors below 2^16 and the remaining composite cofactor;
+- the round constants are derived from the first 200 decimal digits of pi;
+- the MDS matrices are the ones of the paper for 1, 2, 4 and 6 columns, and
+  the Cauchy matrices M[i][j] = 1 / (i + j + l) for 8 and 12 columns;
+- the number of rounds follows from the complexity of Groebner basis attacks,
+  as computed by `generator::num_rounds`.
+
+It then evaluates the S-Box, the linear layer, the Sponge and Jive modes, and the
+merge of two digests over the inputs of the known-answer tests found in
+`src/<field>/anemoi_*/mod.rs` and `src/<field>/anemoi_*/hasher.rs`, and checks
+that they yield the expected outputs. As it reproduces the test vectors of the BLS12-377, BLS12-381,
+BN-254, Ed-on-BLS12-377, Jubjub, Pallas and Vesta instantiations, generated
+from the reference implementation https://github.com/Nashtare/anemoi-hash, it
+can be used to independently regenerate the test vectors of the other fields.
+
+Usage:
+
+    python3 scripts/anemoi_model.py [field ...]
+
+where each field is the name of a module of this crate, e.g. `curve25519`.
+All fields with known-answer tests are checked if none is given. With
+`--print`, the expected outputs are printed instead of being checked.
+"""
+
+import math
+import os
+import re
+import sys
+
+# The first 100 decimal digits of pi.
+PI_0 = int(
+    "1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679"
+)
+# The next 100 decimal digits of pi.
+PI_1 = int(
+    "8214808651328230664709384460955058223172535940812848111745028410270193852110555964462294895493038196"
+)
+
+# For each module of this crate, its modulus and the prime factors of p - 1
+# larger than 2^16, the smaller ones being found by trial division. They are
+# None when p - 1 could not be fully factored.
+FIELDS = {
+    "baby_bear": (
+        2**31 - 2**27 + 1,
+        [],
+    ),
+    "bls12_377": (
+        0x01AE3A4617C510EAC63B05C06CA1493B1A22D9F300F5138F1EF3622FBA094800170B5D44300000008508C00000000001,
+        [6633514200929891813, 73387170334035996766247648424745786170238574695861388454532790956181],
+    ),
+    "bls12_381": (
+        0x1A0111EA397FE69A4B1BA7B6434BACD764774B84F38512BF6730D2A0F6B0F6241EABFFFEB153FFFFB9FEFFFFFFFFAAAB,
+        [
+            859267,
+            52437899,
+            2584487767265781317813,
+            15778400344354997994418419698270088123916926905054652752758194827714659,
+        ],
+    ),
+    "bw6_761": (
+        0x122E824FB83CE0AD187C94004FAFF3EB926186A81D14688528275EF8087BE41707BA638E584E91903CEBAFF25B423048689C8ED12F9FD9071DCD3DC73EBFF2E98A116C25667A8F8160CF8AEEAF0A437E6913E6870000082F49D00000000008B,
+        None,
+    ),
+    "bn_254": (
+        0x30644E72E131A029B85045B68181585D97816A916871CA8D3C208C16D87CFD47,
+        [405928799, 11465965001, 13427688667394608761327070753331941386769],
+    ),
+    "curve25519": (
+        2**255 - 19,
+        [74058212732561358302231226437062788676166966415465897661863160754340907],
+    ),
+    "ed_on_bls12_377": (
+        0x12AB655E9A2CA55660B44D1E5C37B00159AA76FED00000010A11800000000001,
+        [958612291309063373, 9586122913090633729],
+    ),
+    "goldilocks": (
+        2**64 - 2**32 + 1,
+        [65537],
+    ),
+    "grumpkin": (
+        0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001,
+        [237073, 405928799, 1670836401704629, 13818364434197438864469338081],
+    ),
+    "jubjub": (
+        0x73EDA753299D7D483339D80809A1D80553BDA402FFFE5BFEFFFFFFFF00000001,
+        [125527, 859267, 906349, 2508409, 2529403, 52437899, 254760293],
+    ),
+    "mersenne_31": (
+        2**31 - 1,
+        [],
+    ),
+    "mnt4_298": (
+        0x3BCF7BCD473A266249DA7B0548ECAEEC9635D1330EA41A9E35E51200E12C90CD65A71660001,
+        None,
+    ),
+    "mnt6_298": (
+        0x3BCF7BCD473A266249DA7B0548ECAEEC9635CF44194FB494C07925D6AD3BB4334A400000001,
+        [832668823862928114206893884097921187],
+    ),
+    "pallas": (
+        0x40000000000000000000000000000000224698FC094CF91B992D30ED00000001,
+        [539204044132271846773, 8999194758858563409123804352480028797519453],
+    ),
+    "secp256k1": (
+        2**256 - 2**32 - 977,
+        [205115282021455665897114700593932402728804164701536103180137503955397371],
+    ),
+    "secq256k1": (
+        0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141,
+        [107361793816595537, 174723607534414371449, 341948486974166000522343609283189],
+    ),
+    "stark_252": (
+        2**251 + 17 * 2**192 + 1,
+        [98714381, 166848103],
+    ),
+    "vesta": (
+        0x40000000000000000000000000000000224698FC0994A8DD8C46EB2100000001,
+        [1690502597179744445941507, 10427374428728808478656897599072717],
+    ),
+}
+
+# Fields whose elements are written in canonical form, the other ones being
+# written in Montgomery form.
+CANONICAL = {"goldilocks"}
+
+# The first row of the circulant MDS matrix of instantiations with 6 columns.
+CIRCULANT_MDS_6 = [1, 1, 3, 4, 5, 6]
+
+
+def is_probable_prime(n):
+    """Miller-Rabin test to the bases of the primes up to 37."""
+    if n < 2:
+        return False
+    bases = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]
+    if n in bases:
+        return True
+    d, s = n - 1, 0
+    while d % 2 == 0:
+        d, s = d // 2, s + 1
+    for a in bases:
+        x = pow(a, d, n)
+        if x in (1, n - 1):
+            continue
+        for _ in range(s - 1):
+            x = x * x % n
+            if x == n - 1:
+                break
+        else:
+            return False
+    return True
+
+
+def prime_factors(p, large_factors):
+    """Returns the distinct prime factors of p - 1. If `large_factors` is None,
+    the cofactor left after trial division is returned with them."""
+    factors = []
+    cofactor = p - 1
+    for q in range(2, 1 << 16):
+        if cofactor % q == 0:
+            factors.append(q)
+            while cofactor % q == 0:
+                cofactor //= q
+    if large_factors is None:
+        return factors + [cofactor] if cofactor > 1 else factors
+    for q in large_factors:
+        assert is_probable_prime(q), f"{q} is not prime"
+        assert cofactor % q == 0, f"{q} does not divide p - 1"
+        factors.append(q)
+        while cofactor % q == 0:
+            cofactor //= q
+    assert cofactor == 1, "incomplete factorization of p - 1"
+    return factors
+
+
+def num_rounds(p, alpha, num_columns, security_level):
+    """Number of rounds given in Section 6 of the Anemoi paper, where the
+    Groebner basis attack must also resist guessing g of its n unknowns."""
+    kappa = {3: 1, 5: 2}.get(alpha, 4)
+    bits = p.bit_length()
+
+    def secure(r):
+        n = 2 * num_columns * r
+        return all(
+            g * bits + (math.comb(2 * (n - g) + kappa, n - g) ** 2).bit_length() > security_level
+            for g in range(n)
+            if g * bits <= security_level
+        )
+
+    r = 1
+    while not secure(r):
+        r += 1
+    return max(10, r + min(5, num_columns + 1))
+
+
+class Anemoi:
+    """An Anemoi instantiation with `num_columns` columns over GF(p)."""
+
+    def __init__(self, p, large_factors, num_columns, capacity, security_level=128):
+        self.p = p
+        self.l = num_columns
+        self.capacity = capacity
+        self.rate = 2 * num_columns - capacity
+
+        self.alpha = next(a for a in range(3, p) if math.gcd(a, p - 1) == 1)
+        self.inv_alpha = pow(self.alpha, -1, p - 1)
+        factors = prime_factors(p, large_factors)
+        self.g = next(
+            g for g in range(2, p) if all(pow(g, (p - 1) // q, p) != 1 for q in factors)
+        )
+        self.delta = pow(self.g, -1, p)
+        self.num_rounds = num_rounds(p, self.alpha, num_columns, security_level)
+
+        g, a = self.g, self.alpha
+        self.c, self.d = [], []
+        for r in range(self.num_rounds):
+            pi_0_r = pow(PI_0, r, p)
+            c_r, d_r = [], []
+            for i in range(num_columns):
+                pi_1_i = pow(PI_1, i, p)
+                pow_alpha = pow(pi_0_r + pi_1_i, a, p)
+                c_r.append((g * pi_0_r * pi_0_r + pow_alpha) % p)
+                d_r.append((g * pi_1_i * pi_1_i + pow_alpha + self.delta) % p)
+            self.c.append(c_r)
+            self.d.append(d_r)
+
+        if num_columns in (1, 2):
+            self.mds = [[1, g], [g, (1 + g * g) % p]]
+        elif num_columns == 4:
+            g2 = g * g % p
+            self.mds = [
+                [1, 1 + g, g, g],
+                [g2, g + g2, 1 + g, 1 + 2 * g],
+                [g2, g2, 1, 1 + g],
+                [1 + g, 1 + 2 * g, g, 1 + g],
+            ]
+        elif num_columns == 6:
+            self.mds = [[CIRCULANT_MDS_6[(6 + j - i) % 6] for j in range(6)] for i in range(6)]
+        elif num_columns in (8, 12):
+            # Cauchy matrix M[i][j] = 1 / (i + j + l).
+            self.mds = [
+                [pow(i + j + num_columns, -1, p) for j in range(num_columns)]
+                for i in range(num_columns)
+            ]
+        else:
+            raise ValueError(f"unsupported number of columns {num_columns}")
+
+    def mat_mul(self, row):
+        return [sum(m * x for m, x in zip(line, row)) % self.p for line in self.mds]
+
+    def linear_layer(self, state):
+        l = self.l
+        if l == 1:
+            return self.mat_mul(state)
+        x = self.mat_mul(state[:l])
+        # The second row is rotated by one cell to the left.
+        y = self.mat_mul(state[l + 1 :] + state[l : l + 1])
+        return x + y
+
+    def sbox(self, state):
+        p, l, g = self.p, self.l, self.g
+        x, y = state[:l], state[l:]
+        x = [(x[i] - g * y[i] * y[i]) % p for i in range(l)]
+        y = [(y[i] - pow(x[i], self.inv_alpha, p)) % p for i in range(l)]
+        x = [(x[i] + g * y[i] * y[i] + self.delta) % p for i in range(l)]
+        return x + y
+
+    def permutation(self, state):
+        l = self.l
+        for r in range(self.num_rounds):
+            state = [(s + c) % self.p for s, c in zip(state, self.c[r] + self.d[r])]
+            state = self.linear_layer(state)
+            state = self.sbox(state)
+        return self.linear_layer(state)
+
+    def hash_field(self, elems, digest_size):
+        """Sponge mode over field elements, as in `Sponge::hash_field`."""
+        state = [0] * (2 * self.l)
+        idx = 0
+        for e in elems:
+            state[idx] = (state[idx] + e) % self.p
+            idx += 1
+            if idx == self.rate:
+                state = self.permutation(state)
+                idx = 0
+        if idx == 0:
+            state[-1] = (state[-1] + 1) % self.p
+        else:
+            state[idx] = (state[idx] + 1) % self.p
+            state = self.permutation(state)
+        return state[:digest_size]
+
+    def merge(self, elems, digest_size):
+        """Merge of the two digests concatenated in `elems`, as in `Sponge::merge`."""
+        if self.rate < 2 * digest_size:
+            return self.compress_k(elems, 2)
+        state = list(elems) + [0] * (2 * self.l - 2 * digest_size)
+        return self.permutation(state)[:digest_size]
+
+    def compress_k(self, elems, k):
+        """Jive mode with a compression factor k, as in `Jive::compress_k`."""
+        width = 2 * self.l
+        assert len(elems) == width and width % k == 0
+        state = self.permutation(list(elems))
+        c = width // k
+        return [
+            sum(elems[i + c * j] + state[i + c * j] for j in range(k)) % self.p
+            for i in range(c)
+        ]
+
+
+TOKEN = re.compile(
+    r"vec!\[|\[|\]|;\s*\d+|Felt::zero\(\)|Felt::one\(\)"
+    r"|Felt::new\(BigInteger\d+\(\[([^\]]*)\]\)\)|Felt::new\((0x[0-9a-fA-F_]+|\d+)\)"
+)
+
+
+def parse_array(source, start, p):
+    """Parses the nested array of field elements starting at `source[start]`,
+    where elements are given in Montgomery form over arkworks big integers,
+    or in canonical form over 64-bit words, and returns it along with the
+    position following it."""
+    stack = []
+    pos = start
+    r = 1 << (64 * ((p.bit_length() + 63) // 64))
+    inv_r = pow(r, -1, p)
+    while True:
+        m = TOKEN.search(source, pos)
+        pos = m.end()
+        token = m.group(0)
+        if token in ("[", "vec!["):
+            stack.append([])
+            continue
+        if token == "]":
+            array = stack.pop()
+            if not stack:
+                return array, pos
+            stack[-1].append(array)
+            continue
+        if token.startswith(";"):
+            # Repeated element, as in `vec![Felt::zero(); 12]`.
+            stack[-1] *= int(token[1:])
+            continue
+        if token == "Felt::zero()":
+            value = 0
+        elif token == "Felt::one()":
+            value = 1
+        elif m.group(1) is not None:
+            limbs = [int(l, 16) for l in m.group(1).replace(",", " ").split()]
+            value = sum(l << (64 * i) for i, l in enumerate(limbs)) * inv_r % p
+        else:
+            value = int(m.group(2).replace("_", ""), 0)
+        stack[-1].append(value)
+
+
+def permutation_tests(path, p):
+    """Yields the name, inputs and expected outputs of the S-Box and linear
+    layer known-answer tests of the provided file."""
+    with open(path) as f:
+        source = f.read()
+    for name in ("sbox", "mds"):
+        test = source.find(f"fn test_{name}()")
+        # Tests over random inputs do not provide expected outputs.
+        end = source.find("fn ", test + 1)
+        if test < 0 or "let output = " not in source[test : end if end > 0 else None]:
+            continue
+        inputs, pos = parse_array(source, source.find("let mut input = ", test), p)
+        outputs, _ = parse_array(source, source.find("let output = ", pos), p)
+        yield name, inputs, outputs
+
+
+CALL = re.compile(
+    r"AnemoiHash::(hash_field|merge|compress|compress_k)\((?:input|&digests)(?:, (\d+))?\)"
+)
+
+
+def known_answer_tests(path, p):
+    """Yields the mode, compression factor, inputs and expected outputs of
+    all known-answer tests of the provided file."""
+    with open(path) as f:
+        source = f.read()
+    pos = 0
+    while True:
+        start = source.find("let input_data = ", pos)
+        if start < 0:
+            return
+        inputs, pos = parse_array(source, start, p)
+        start = source.find("let output_data = ", pos)
+        outputs, pos = parse_array(source, start, p)
+        call = CALL.search(source, pos)
+        # The same vectors may be checked against several modes.
+        next_input = source.find("let input_data = ", pos)
+        while call and (next_input < 0 or call.start() < next_input):
+            k = int(call.group(2)) if call.group(2) else 2
+            yield call.group(1), k, inputs, outputs
+            call = CALL.search(source, call.end())
+
+
+def format_element(value, p, canonical=False):
+    if canonical:
+        return f"0x{value:016x}"
+    num_limbs = (p.bit_length() + 63) // 64
+    mont = value * (1 << (64 * num_limbs)) % p
+    limbs = [(mont >> (64 * i)) & (2**64 - 1) for i in range(num_limbs)]
+    return "[" + ", ".join(f"0x{l:016x}" for l in limbs) + "]"
+
+
+def check_field(field, print_outputs=False):
+    p, large_factors = FIELDS[field]
+    root = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "src", field)
+    count = 0
+    for name in sorted(os.listdir(root)):
+        m = re.fullmatch(r"anemoi_(\d+)_(\d+)", name)
+        path = os.path.join(root, name, "hasher.rs")
+        if not m or not os.path.exists(path):
+            continue
+        width, rate = int(m.group(1)), int(m.group(2))
+        anemoi = Anemoi(p, large_factors, width // 2, width - rate)
+
+        for test, inputs, outputs in permutation_tests(os.path.join(root, name, "mod.rs"), p):
+            layer = anemoi.sbox if test == "sbox" else anemoi.linear_layer
+            for elems, expected in zip(inputs, outputs):
+                actual = layer(elems)
+                if print_outputs:
+                    print(f"{field}::{name} {test}:", [format_element(v, p, field in CANONICAL) for v in actual])
+                elif actual != expected:
+                    raise AssertionError(f"{field}::{name}: {test} mismatch for input {elems}")
+                count += 1
+
+        for mode, k, inputs, outputs in known_answer_tests(path, p):
+            for elems, expected in zip(inputs, outputs):
+                if mode == "hash_field":
+                    actual = anemoi.hash_field(elems, len(expected))
+                elif mode == "merge":
+                    actual = anemoi.merge(elems, len(expected))
+                else:
+                    actual = anemoi.compress_k(elems, k)
+                if print_outputs:
+                    print(f"{field}::{name} {mode}({k}):", [format_element(v, p, field in CANONICAL) for v in actual])
+                elif actual != expected:
+                    raise AssertionError(f"{field}::{name}: {mode} mismatch for input {elems}")
+                count += 1
+    return count
+
+
+def main(args):
+    print_outputs = "--print" in args
+    fields = [a for a in args if a != "--print"] or sorted(FIELDS)
+    for field in fields:
+        count = check_field(field, print_outputs)
+        if not print_outputs:
+            print(f"{field}: {count} known-answer tests reproduced")
+
+
+if __name__ == "__main__":
+    main(sys.argv[1:])
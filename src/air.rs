@@ -0,0 +1,412 @@
+//! Execution traces and constraints of the Anemoi permutation
+//! for STARK provers
+//!
+//! The execution trace of a permutation has a row per round, containing the
+//! state at the beginning of the round and the outputs `v = x^(1/ALPHA)` of
+//! the inverse power maps of its open Flystels, followed by a last row
+//! containing the state after the last round. This last row is repeated to
+//! pad the trace to the next power of two, as expected by STARK provers. The
+//! transition constraints relate each row to the next one, and are the gate
+//! equations of [`AnemoiGates`](crate::plonk::AnemoiGates) expressed over the
+//! trace columns, multiplied by a selector disabling them on padding rows.
+//!
+//! The round constants and the selector are not part of the trace: they are
+//! given by periodic columns of period the length of the trace, known to the
+//! verifier, so that a prover cannot pick them. The input of the permutation
+//! is fixed by boundary assertions on the first row, and its output by the
+//! equations of the final linear layer, evaluated on the row following the
+//! last round.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::marker::PhantomData;
+
+use super::plonk::{AnemoiGates, Polynomial, Variable};
+use super::{Anemoi, AnemoiParameters};
+
+use ark_ff::PrimeField;
+
+/// A column of the execution trace of an Anemoi permutation.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TraceColumn {
+    /// The i-th element of the state.
+    State(usize),
+    /// The output of the inverse power map of the i-th Flystel.
+    Witness(usize),
+}
+
+/// A periodic column of the constraints of an Anemoi permutation,
+/// whose values are known to the verifier.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PeriodicColumn {
+    /// The i-th round constant added to the first row of the state.
+    ConstantC(usize),
+    /// The i-th round constant added to the second row of the state.
+    ConstantD(usize),
+    /// The selector of the rows of the rounds, set to zero on padding rows.
+    RoundSelector,
+}
+
+/// A variable of the constraints, referring to a column of either the
+/// current or the next row, or to an element of the public output.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AirVariable {
+    /// A column of the current row.
+    Current(TraceColumn),
+    /// A column of the next row.
+    Next(TraceColumn),
+    /// A periodic column at the current row.
+    Periodic(PeriodicColumn),
+    /// The i-th element of the output of the permutation.
+    Output(usize),
+}
+
+/// The execution trace of an Anemoi permutation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutionTrace<F: PrimeField> {
+    num_columns: usize,
+    rows: Vec<Vec<F>>,
+    output: Vec<F>,
+}
+
+impl<F: PrimeField> ExecutionTrace<F> {
+    /// Returns the rows of this trace.
+    pub fn rows(&self) -> &[Vec<F>] {
+        &self.rows
+    }
+
+    /// Returns the number of rows of this trace.
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the output of the permutation, obtained by applying
+    /// the final linear layer to the state of the last row.
+    pub fn output(&self) -> &[F] {
+        &self.output
+    }
+
+    /// Returns the value of the provided column at the provided row.
+    pub fn value(&self, row: usize, column: TraceColumn) -> F {
+        self.rows[row][column_index(self.num_columns, column)]
+    }
+}
+
+/// Returns the index of the provided column in a trace
+/// of an Anemoi permutation with `num_columns` columns.
+fn column_index(num_columns: usize, column: TraceColumn) -> usize {
+    match column {
+        TraceColumn::State(i) => i,
+        TraceColumn::Witness(i) => 2 * num_columns + i,
+    }
+}
+
+/// Execution trace generation and constraints for the
+/// Anemoi permutation over `F` with `NUM_COLUMNS` columns.
+#[derive(Debug)]
+pub struct AnemoiAir<F, const NUM_COLUMNS: usize, P>(PhantomData<(F, P)>);
+
+impl<F, const NUM_COLUMNS: usize, P> AnemoiAir<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+    /// The number of columns of the execution trace.
+    pub const TRACE_WIDTH: usize = 3 * NUM_COLUMNS;
+    /// The number of rows of the execution trace, the number of
+    /// rounds plus one rounded up to the next power of two.
+    pub const NUM_ROWS: usize = (P::NUM_ROUNDS + 1).next_power_of_two();
+
+    /// Returns the index of the provided column in a row of the trace.
+    pub fn column_index(column: TraceColumn) -> usize {
+        column_index(NUM_COLUMNS, column)
+    }
+
+    /// Applies an Anemoi permutation to the provided state,
+    /// and returns its execution trace of `NUM_ROWS` rows.
+    ///
+    /// The rows following the last round contain the output state of
+    /// the last round, and their witness columns are set to zero.
+    pub fn generate_trace(input: &[F]) -> ExecutionTrace<F> {
+        Self::generate_trace_with_constants(input, P::ARK_C, P::ARK_D)
+    }
+
+    /// Returns the values of the periodic columns over the `NUM_ROWS` rows
+    /// of a trace, the constants `C` followed by the constants `D` and by
+    /// the round selector.
+    ///
+    /// The values of the rows following the last round are set to zero.
+    pub fn periodic_columns() -> Vec<Vec<F>> {
+        (0..NUM_COLUMNS)
+            .map(PeriodicColumn::ConstantC)
+            .chain((0..NUM_COLUMNS).map(PeriodicColumn::ConstantD))
+            .chain([PeriodicColumn::RoundSelector])
+            .map(|column| {
+                (0..Self::NUM_ROWS)
+                    .map(|row| Self::periodic_value(row, column))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the value of the provided periodic column at the provided row.
+    pub fn periodic_value(row: usize, column: PeriodicColumn) -> F {
+        if row >= P::NUM_ROUNDS {
+            return F::zero();
+        }
+
+        match column {
+            PeriodicColumn::ConstantC(i) => P::ARK_C[row][i],
+            PeriodicColumn::ConstantD(i) => P::ARK_D[row][i],
+            PeriodicColumn::RoundSelector => F::one(),
+        }
+    }
+
+    /// Returns the boundary assertions of a permutation of the provided
+    /// input, as triplets of a row, a column and its expected value.
+    pub fn boundary_assertions(input: &[F]) -> Vec<(usize, TraceColumn, F)> {
+        assert_eq!(input.len(), Self::STATE_WIDTH);
+
+        input
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| (0, TraceColumn::State(i), value))
+            .collect()
+    }
+
+    /// Returns the transition constraints of the permutation, which all
+    /// evaluate to zero between any row of a valid trace but the last
+    /// one and its successor.
+    ///
+    /// They are the gate equations of a round multiplied by the round
+    /// selector, and hence hold trivially on padding rows.
+    pub fn transition_constraints() -> Vec<Polynomial<F, AirVariable>> {
+        let selector = Polynomial::variable(AirVariable::Periodic(PeriodicColumn::RoundSelector));
+
+        AnemoiGates::<F, NUM_COLUMNS, P>::round_equations()
+            .iter()
+            .map(|e| {
+                let constraint = e.map_variables(|v| match v {
+                    Variable::Input(i) => AirVariable::Current(TraceColumn::State(i)),
+                    Variable::Witness(i) => AirVariable::Current(TraceColumn::Witness(i)),
+                    Variable::Output(i) => AirVariable::Next(TraceColumn::State(i)),
+                    Variable::ConstantC(i) => AirVariable::Periodic(PeriodicColumn::ConstantC(i)),
+                    Variable::ConstantD(i) => AirVariable::Periodic(PeriodicColumn::ConstantD(i)),
+                });
+                &selector * &constraint
+            })
+            .collect()
+    }
+
+    /// Returns the constraints of the final linear layer, which all evaluate
+    /// to zero on the row following the last round of a valid trace, given
+    /// the permutation output.
+    pub fn output_constraints() -> Vec<Polynomial<F, AirVariable>> {
+        AnemoiGates::<F, NUM_COLUMNS, P>::final_equations()
+            .iter()
+            .map(|e| {
+                e.map_variables(|v| match v {
+                    Variable::Input(i) => AirVariable::Current(TraceColumn::State(i)),
+                    Variable::Output(i) => AirVariable::Output(i),
+                    _ => unreachable!(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the value of the provided variable when evaluating the
+    /// constraints at the provided row of a trace, given the output of
+    /// the permutation.
+    pub fn variable_value(
+        trace: &ExecutionTrace<F>,
+        output: &[F],
+        row: usize,
+        variable: AirVariable,
+    ) -> F {
+        match variable {
+            AirVariable::Current(column) => trace.value(row, column),
+            AirVariable::Next(column) => trace.value(row + 1, column),
+            AirVariable::Periodic(column) => Self::periodic_value(row, column),
+            AirVariable::Output(i) => output[i],
+        }
+    }
+
+    /// Verifies that the provided trace is a valid execution trace
+    /// of a permutation mapping `input` to `output`.
+    pub fn verify_trace(trace: &ExecutionTrace<F>, input: &[F], output: &[F]) -> bool {
+        if trace.num_rows() != Self::NUM_ROWS
+            || trace.rows().iter().any(|r| r.len() != Self::TRACE_WIDTH)
+            || input.len() != Self::STATE_WIDTH
+            || output.len() != Self::STATE_WIDTH
+        {
+            return false;
+        }
+
+        let holds = |constraints: &[Polynomial<F, AirVariable>], row: usize| {
+            constraints.iter().all(|c| {
+                c.evaluate(|v| Self::variable_value(trace, output, row, v))
+                    .is_zero()
+            })
+        };
+
+        Self::boundary_assertions(input)
+            .iter()
+            .all(|&(row, column, value)| trace.value(row, column) == value)
+            && (0..Self::NUM_ROWS - 1).all(|row| holds(&Self::transition_constraints(), row))
+            && holds(&Self::output_constraints(), P::NUM_ROUNDS)
+    }
+
+    /// Applies an Anemoi permutation with the provided round constants to
+    /// the provided state, and returns its execution trace.
+    fn generate_trace_with_constants(
+        input: &[F],
+        ark_c: &[[F; NUM_COLUMNS]],
+        ark_d: &[[F; NUM_COLUMNS]],
+    ) -> ExecutionTrace<F> {
+        assert_eq!(input.len(), Self::STATE_WIDTH);
+
+        let mut state = input.to_vec();
+        let mut rows = Vec::with_capacity(Self::NUM_ROWS);
+        for step in 0..P::NUM_ROUNDS {
+            let c = &ark_c[step];
+            let d = &ark_d[step];
+
+            let mut row = state.clone();
+            for i in 0..NUM_COLUMNS {
+                state[i] += c[i];
+                state[NUM_COLUMNS + i] += d[i];
+            }
+            Anemoi::<F, NUM_COLUMNS, P>::apply_mds(&mut state);
+
+            row.extend((0..NUM_COLUMNS).map(|i| {
+                let y2 = state[NUM_COLUMNS + i].square();
+                P::exp_inv_alpha(&(state[i] - P::mul_by_generator(&y2)))
+            }));
+            rows.push(row);
+
+            Anemoi::<F, NUM_COLUMNS, P>::apply_sbox(&mut state);
+        }
+
+        let mut row = state.clone();
+        row.resize(Self::TRACE_WIDTH, F::zero());
+        rows.resize(Self::NUM_ROWS, row);
+
+        Anemoi::<F, NUM_COLUMNS, P>::apply_mds(&mut state);
+
+        ExecutionTrace {
+            num_columns: NUM_COLUMNS,
+            rows,
+            output: state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use rand_core::OsRng;
+
+    struct AirCheck;
+
+    impl InstantiationCheck for AirCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let input: Vec<F> = (0..2 * NUM_COLUMNS).map(|_| F::rand(&mut rng)).collect();
+
+            let trace = AnemoiAir::<F, NUM_COLUMNS, P>::generate_trace(&input);
+            let mut output = input.clone();
+            Anemoi::<F, NUM_COLUMNS, P>::apply_permutation(&mut output);
+            assert_eq!(trace.output(), output);
+            let num_rows = AnemoiAir::<F, NUM_COLUMNS, P>::NUM_ROWS;
+            assert!(num_rows.is_power_of_two() && num_rows > P::NUM_ROUNDS);
+            assert_eq!(trace.num_rows(), num_rows);
+            assert!(trace
+                .rows()
+                .iter()
+                .all(|r| r.len() == AnemoiAir::<F, NUM_COLUMNS, P>::TRACE_WIDTH));
+            assert_eq!(trace.rows()[0][..2 * NUM_COLUMNS], input[..]);
+
+            let constraints = AnemoiAir::<F, NUM_COLUMNS, P>::transition_constraints();
+            assert_eq!(constraints.len(), 3 * NUM_COLUMNS);
+            assert_eq!(
+                AnemoiAir::<F, NUM_COLUMNS, P>::output_constraints().len(),
+                2 * NUM_COLUMNS
+            );
+            let periodic = AnemoiAir::<F, NUM_COLUMNS, P>::periodic_columns();
+            assert_eq!(periodic.len(), 2 * NUM_COLUMNS + 1);
+            assert!(periodic.iter().all(|c| c.len() == num_rows));
+            assert_eq!(periodic[0][0], P::ARK_C[0][0]);
+            assert_eq!(periodic[NUM_COLUMNS][1], P::ARK_D[1][0]);
+            let selector = &periodic[2 * NUM_COLUMNS];
+            assert!(selector[..P::NUM_ROUNDS].iter().all(|s| s.is_one()));
+            assert!(selector[P::NUM_ROUNDS..].iter().all(|s| s.is_zero()));
+            assert!(AnemoiAir::<F, NUM_COLUMNS, P>::verify_trace(
+                &trace, &input, &output
+            ));
+
+            // A wrong witness is detected.
+            let mut tampered = trace.clone();
+            let witness = AnemoiAir::<F, NUM_COLUMNS, P>::column_index(TraceColumn::Witness(0));
+            tampered.rows[1][witness] += F::one();
+            assert!(!AnemoiAir::<F, NUM_COLUMNS, P>::verify_trace(
+                &tampered, &input, &output
+            ));
+
+            // A wrong state after the last round is detected.
+            let mut tampered = trace.clone();
+            tampered.rows[P::NUM_ROUNDS][0] += F::one();
+            assert!(!AnemoiAir::<F, NUM_COLUMNS, P>::verify_trace(
+                &tampered, &input, &output
+            ));
+
+            // Transitions are disabled on padding rows.
+            if num_rows > P::NUM_ROUNDS + 1 {
+                let mut padded = trace.clone();
+                padded.rows[num_rows - 1][0] += F::one();
+                assert!(AnemoiAir::<F, NUM_COLUMNS, P>::verify_trace(
+                    &padded, &input, &output
+                ));
+            }
+
+            // Wrong inputs and outputs are detected.
+            let mut other = input.clone();
+            other[0] += F::one();
+            assert!(!AnemoiAir::<F, NUM_COLUMNS, P>::verify_trace(
+                &trace, &other, &output
+            ));
+            let mut other = output.clone();
+            other[0] += F::one();
+            assert!(!AnemoiAir::<F, NUM_COLUMNS, P>::verify_trace(
+                &trace, &input, &other
+            ));
+
+            // A consistent trace of a permutation with other round constants
+            // is rejected, as the constants are fixed by the verifier.
+            let mut ark_c = P::ARK_C.to_vec();
+            ark_c[0][0] += F::one();
+            let tampered = AnemoiAir::<F, NUM_COLUMNS, P>::generate_trace_with_constants(
+                &input,
+                &ark_c,
+                P::ARK_D,
+            );
+            assert!(!AnemoiAir::<F, NUM_COLUMNS, P>::verify_trace(
+                &tampered,
+                &input,
+                tampered.output()
+            ));
+        }
+    }
+
+    #[test]
+    fn test_constraints() {
+        check_all_instantiations::<AirCheck>();
+    }
+}
@@ -0,0 +1,73 @@
+use super::BigInteger64;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi, given
+/// by the Cauchy matrix `M[i][j] = 1 / (i + j + 8)`
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger64([0x53bbbbbd])),
+    Felt::new(BigInteger64([0x07c3518b])),
+    Felt::new(BigInteger64([0x5afc9631])),
+    Felt::new(BigInteger64([0x27142b72])),
+    Felt::new(BigInteger64([0x5fd27d29])),
+    Felt::new(BigInteger64([0x2a4c24c3])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x07c3518b])),
+    Felt::new(BigInteger64([0x5afc9631])),
+    Felt::new(BigInteger64([0x27142b72])),
+    Felt::new(BigInteger64([0x5fd27d29])),
+    Felt::new(BigInteger64([0x2a4c24c3])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x5afc9631])),
+    Felt::new(BigInteger64([0x27142b72])),
+    Felt::new(BigInteger64([0x5fd27d29])),
+    Felt::new(BigInteger64([0x2a4c24c3])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x27142b72])),
+    Felt::new(BigInteger64([0x5fd27d29])),
+    Felt::new(BigInteger64([0x2a4c24c3])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x5fd27d29])),
+    Felt::new(BigInteger64([0x2a4c24c3])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x2a4c24c3])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+];
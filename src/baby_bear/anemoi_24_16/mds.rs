@@ -0,0 +1,153 @@
+use super::BigInteger64;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi, given
+/// by the Cauchy matrix `M[i][j] = 1 / (i + j + 12)`
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger64([0x5fd27d29])),
+    Felt::new(BigInteger64([0x2a4c24c3])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x2a4c24c3])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x0d8fd8fe])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x64a86421])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x51261262])),
+    Felt::new(BigInteger64([0x65dddddf])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x51261262])),
+    Felt::new(BigInteger64([0x2a967084])),
+    Felt::new(BigInteger64([0x27676768])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x51261262])),
+    Felt::new(BigInteger64([0x2a967084])),
+    Felt::new(BigInteger64([0x06c7ec7f])),
+    Felt::new(BigInteger64([0x3fe1a8c6])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x51261262])),
+    Felt::new(BigInteger64([0x2a967084])),
+    Felt::new(BigInteger64([0x06c7ec7f])),
+    Felt::new(BigInteger64([0x12f5fed3])),
+    Felt::new(BigInteger64([0x169fe342])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x51261262])),
+    Felt::new(BigInteger64([0x2a967084])),
+    Felt::new(BigInteger64([0x06c7ec7f])),
+    Felt::new(BigInteger64([0x12f5fed3])),
+    Felt::new(BigInteger64([0x6e543211])),
+    Felt::new(BigInteger64([0x697e4b19])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x51261262])),
+    Felt::new(BigInteger64([0x2a967084])),
+    Felt::new(BigInteger64([0x06c7ec7f])),
+    Felt::new(BigInteger64([0x12f5fed3])),
+    Felt::new(BigInteger64([0x6e543211])),
+    Felt::new(BigInteger64([0x1d59bcd6])),
+    Felt::new(BigInteger64([0x590a90aa])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x51261262])),
+    Felt::new(BigInteger64([0x2a967084])),
+    Felt::new(BigInteger64([0x06c7ec7f])),
+    Felt::new(BigInteger64([0x12f5fed3])),
+    Felt::new(BigInteger64([0x6e543211])),
+    Felt::new(BigInteger64([0x1d59bcd6])),
+    Felt::new(BigInteger64([0x6eeeeef0])),
+    Felt::new(BigInteger64([0x138a15b9])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x51261262])),
+    Felt::new(BigInteger64([0x2a967084])),
+    Felt::new(BigInteger64([0x06c7ec7f])),
+    Felt::new(BigInteger64([0x12f5fed3])),
+    Felt::new(BigInteger64([0x6e543211])),
+    Felt::new(BigInteger64([0x1d59bcd6])),
+    Felt::new(BigInteger64([0x6eeeeef0])),
+    Felt::new(BigInteger64([0x0d06b926])),
+    Felt::new(BigInteger64([0x0d78f363])),
+    Felt::new(BigInteger64([0x6be93e95])),
+    Felt::new(BigInteger64([0x6c6508e1])),
+    Felt::new(BigInteger64([0x51261262])),
+    Felt::new(BigInteger64([0x2a967084])),
+    Felt::new(BigInteger64([0x06c7ec7f])),
+    Felt::new(BigInteger64([0x12f5fed3])),
+    Felt::new(BigInteger64([0x6e543211])),
+    Felt::new(BigInteger64([0x1d59bcd6])),
+    Felt::new(BigInteger64([0x6eeeeef0])),
+    Felt::new(BigInteger64([0x0d06b926])),
+    Felt::new(BigInteger64([0x13b3b3b4])),
+];
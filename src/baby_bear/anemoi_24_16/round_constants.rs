@@ -0,0 +1,291 @@
+use super::BigInteger64;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger64([0x44cccfa1])),
+        Felt::new(BigInteger64([0x01e0d42b])),
+        Felt::new(BigInteger64([0x1920e266])),
+        Felt::new(BigInteger64([0x76fc7de0])),
+        Felt::new(BigInteger64([0x1e39001c])),
+        Felt::new(BigInteger64([0x477b9f7e])),
+        Felt::new(BigInteger64([0x6c7a1948])),
+        Felt::new(BigInteger64([0x6c42b63d])),
+        Felt::new(BigInteger64([0x503a743d])),
+        Felt::new(BigInteger64([0x2f0ae49d])),
+        Felt::new(BigInteger64([0x08c4d626])),
+        Felt::new(BigInteger64([0x65b47304])),
+    ],
+    [
+        Felt::new(BigInteger64([0x019b5e9c])),
+        Felt::new(BigInteger64([0x2d0e02e6])),
+        Felt::new(BigInteger64([0x31055c87])),
+        Felt::new(BigInteger64([0x3cf30b9b])),
+        Felt::new(BigInteger64([0x71d437d7])),
+        Felt::new(BigInteger64([0x2b2fe495])),
+        Felt::new(BigInteger64([0x2efb180c])),
+        Felt::new(BigInteger64([0x1025c490])),
+        Felt::new(BigInteger64([0x503c387b])),
+        Felt::new(BigInteger64([0x71fa17a6])),
+        Felt::new(BigInteger64([0x4982a818])),
+        Felt::new(BigInteger64([0x73c45b1d])),
+    ],
+    [
+        Felt::new(BigInteger64([0x0dcf859a])),
+        Felt::new(BigInteger64([0x3696464f])),
+        Felt::new(BigInteger64([0x086d518a])),
+        Felt::new(BigInteger64([0x32d215c3])),
+        Felt::new(BigInteger64([0x36df4038])),
+        Felt::new(BigInteger64([0x06288ab4])),
+        Felt::new(BigInteger64([0x3dae1aa2])),
+        Felt::new(BigInteger64([0x58e1559c])),
+        Felt::new(BigInteger64([0x657b09ee])),
+        Felt::new(BigInteger64([0x4a0b54e0])),
+        Felt::new(BigInteger64([0x547b83b2])),
+        Felt::new(BigInteger64([0x521cd855])),
+    ],
+    [
+        Felt::new(BigInteger64([0x301cbeb0])),
+        Felt::new(BigInteger64([0x4bfe87a0])),
+        Felt::new(BigInteger64([0x21d4a37b])),
+        Felt::new(BigInteger64([0x59968427])),
+        Felt::new(BigInteger64([0x5d270df1])),
+        Felt::new(BigInteger64([0x4c4437d7])),
+        Felt::new(BigInteger64([0x44cbad49])),
+        Felt::new(BigInteger64([0x7223b849])),
+        Felt::new(BigInteger64([0x60828921])),
+        Felt::new(BigInteger64([0x63d9b14a])),
+        Felt::new(BigInteger64([0x2a1ba4f6])),
+        Felt::new(BigInteger64([0x61cad8c1])),
+    ],
+    [
+        Felt::new(BigInteger64([0x42a1b25d])),
+        Felt::new(BigInteger64([0x77357c5e])),
+        Felt::new(BigInteger64([0x62f4b6ed])),
+        Felt::new(BigInteger64([0x2d16b8bf])),
+        Felt::new(BigInteger64([0x5fd293f5])),
+        Felt::new(BigInteger64([0x447af6e9])),
+        Felt::new(BigInteger64([0x0ae117bd])),
+        Felt::new(BigInteger64([0x47a9e570])),
+        Felt::new(BigInteger64([0x0b263b86])),
+        Felt::new(BigInteger64([0x361be7cb])),
+        Felt::new(BigInteger64([0x5d72b560])),
+        Felt::new(BigInteger64([0x663b95ea])),
+    ],
+    [
+        Felt::new(BigInteger64([0x48e8863a])),
+        Felt::new(BigInteger64([0x758ce708])),
+        Felt::new(BigInteger64([0x44e52763])),
+        Felt::new(BigInteger64([0x2c0d2a9c])),
+        Felt::new(BigInteger64([0x0a7003de])),
+        Felt::new(BigInteger64([0x3dfa0e8c])),
+        Felt::new(BigInteger64([0x089215b0])),
+        Felt::new(BigInteger64([0x178faf0a])),
+        Felt::new(BigInteger64([0x103046e0])),
+        Felt::new(BigInteger64([0x353d6ecc])),
+        Felt::new(BigInteger64([0x24621d6e])),
+        Felt::new(BigInteger64([0x5eeb6219])),
+    ],
+    [
+        Felt::new(BigInteger64([0x33f2626c])),
+        Felt::new(BigInteger64([0x10c81d10])),
+        Felt::new(BigInteger64([0x2b3012a7])),
+        Felt::new(BigInteger64([0x15a568ef])),
+        Felt::new(BigInteger64([0x5701ceb8])),
+        Felt::new(BigInteger64([0x77bb34cc])),
+        Felt::new(BigInteger64([0x1ea075e1])),
+        Felt::new(BigInteger64([0x3df29a37])),
+        Felt::new(BigInteger64([0x0a258c80])),
+        Felt::new(BigInteger64([0x38342b6b])),
+        Felt::new(BigInteger64([0x0de8f11c])),
+        Felt::new(BigInteger64([0x5e2d6cd2])),
+    ],
+    [
+        Felt::new(BigInteger64([0x6a6787ee])),
+        Felt::new(BigInteger64([0x6a28b2d8])),
+        Felt::new(BigInteger64([0x3a34cd0c])),
+        Felt::new(BigInteger64([0x18e73596])),
+        Felt::new(BigInteger64([0x600f528c])),
+        Felt::new(BigInteger64([0x6180ff0d])),
+        Felt::new(BigInteger64([0x53352062])),
+        Felt::new(BigInteger64([0x4e989025])),
+        Felt::new(BigInteger64([0x08785a17])),
+        Felt::new(BigInteger64([0x259d6a1e])),
+        Felt::new(BigInteger64([0x0a662e7c])),
+        Felt::new(BigInteger64([0x1cc920d8])),
+    ],
+    [
+        Felt::new(BigInteger64([0x75e7b3f0])),
+        Felt::new(BigInteger64([0x0e228eb6])),
+        Felt::new(BigInteger64([0x7091a833])),
+        Felt::new(BigInteger64([0x364af4ce])),
+        Felt::new(BigInteger64([0x5d48d2ca])),
+        Felt::new(BigInteger64([0x1ae33967])),
+        Felt::new(BigInteger64([0x5487f6be])),
+        Felt::new(BigInteger64([0x3048dae2])),
+        Felt::new(BigInteger64([0x345765f9])),
+        Felt::new(BigInteger64([0x0911583e])),
+        Felt::new(BigInteger64([0x5b2b1e2a])),
+        Felt::new(BigInteger64([0x0992646b])),
+    ],
+    [
+        Felt::new(BigInteger64([0x42497ad6])),
+        Felt::new(BigInteger64([0x060546b5])),
+        Felt::new(BigInteger64([0x40b0cc51])),
+        Felt::new(BigInteger64([0x146a5873])),
+        Felt::new(BigInteger64([0x70a75d49])),
+        Felt::new(BigInteger64([0x2e1d35a8])),
+        Felt::new(BigInteger64([0x489d5f4b])),
+        Felt::new(BigInteger64([0x14a3ba4b])),
+        Felt::new(BigInteger64([0x41fe2c52])),
+        Felt::new(BigInteger64([0x2a7c6e4f])),
+        Felt::new(BigInteger64([0x4da49389])),
+        Felt::new(BigInteger64([0x16e170c8])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger64([0x62268c77])),
+        Felt::new(BigInteger64([0x13a681a7])),
+        Felt::new(BigInteger64([0x56dccea5])),
+        Felt::new(BigInteger64([0x65b773ed])),
+        Felt::new(BigInteger64([0x0ce470ac])),
+        Felt::new(BigInteger64([0x65f11b3d])),
+        Felt::new(BigInteger64([0x0062a315])),
+        Felt::new(BigInteger64([0x6d2497e3])),
+        Felt::new(BigInteger64([0x050ecd2b])),
+        Felt::new(BigInteger64([0x19672f4c])),
+        Felt::new(BigInteger64([0x24d9282b])),
+        Felt::new(BigInteger64([0x4b5f0c54])),
+    ],
+    [
+        Felt::new(BigInteger64([0x3ec84841])),
+        Felt::new(BigInteger64([0x5ea6dd31])),
+        Felt::new(BigInteger64([0x16947594])),
+        Felt::new(BigInteger64([0x4b812e77])),
+        Felt::new(BigInteger64([0x0852d535])),
+        Felt::new(BigInteger64([0x69788d23])),
+        Felt::new(BigInteger64([0x5ab6cea9])),
+        Felt::new(BigInteger64([0x30dad305])),
+        Felt::new(BigInteger64([0x24e3be38])),
+        Felt::new(BigInteger64([0x04298f23])),
+        Felt::new(BigInteger64([0x0d6a26eb])),
+        Felt::new(BigInteger64([0x0142213b])),
+    ],
+    [
+        Felt::new(BigInteger64([0x1d48bde5])),
+        Felt::new(BigInteger64([0x3a7b6f40])),
+        Felt::new(BigInteger64([0x3848b93e])),
+        Felt::new(BigInteger64([0x13ac8745])),
+        Felt::new(BigInteger64([0x17aa2c3d])),
+        Felt::new(BigInteger64([0x16bd81e8])),
+        Felt::new(BigInteger64([0x3bb61fe5])),
+        Felt::new(BigInteger64([0x4be2b2b7])),
+        Felt::new(BigInteger64([0x0c6ede51])),
+        Felt::new(BigInteger64([0x26871b04])),
+        Felt::new(BigInteger64([0x62af512c])),
+        Felt::new(BigInteger64([0x29e6ed1a])),
+    ],
+    [
+        Felt::new(BigInteger64([0x4a1c6ac5])),
+        Felt::new(BigInteger64([0x5a6a245b])),
+        Felt::new(BigInteger64([0x5c367ef9])),
+        Felt::new(BigInteger64([0x44f76973])),
+        Felt::new(BigInteger64([0x48786dc0])),
+        Felt::new(BigInteger64([0x675fa2d5])),
+        Felt::new(BigInteger64([0x4d5a2656])),
+        Felt::new(BigInteger64([0x6fab892e])),
+        Felt::new(BigInteger64([0x11fcd14e])),
+        Felt::new(BigInteger64([0x4adbeb38])),
+        Felt::new(BigInteger64([0x42d5e63a])),
+        Felt::new(BigInteger64([0x441b6150])),
+    ],
+    [
+        Felt::new(BigInteger64([0x125ed8b9])),
+        Felt::new(BigInteger64([0x3b5e9360])),
+        Felt::new(BigInteger64([0x53140cb2])),
+        Felt::new(BigInteger64([0x46351853])),
+        Felt::new(BigInteger64([0x00e16e0b])),
+        Felt::new(BigInteger64([0x1553dc2e])),
+        Felt::new(BigInteger64([0x412d0b12])),
+        Felt::new(BigInteger64([0x72ef309d])),
+        Felt::new(BigInteger64([0x625dfdfc])),
+        Felt::new(BigInteger64([0x4adb9c01])),
+        Felt::new(BigInteger64([0x2bea70eb])),
+        Felt::new(BigInteger64([0x764998c1])),
+    ],
+    [
+        Felt::new(BigInteger64([0x568ca8a0])),
+        Felt::new(BigInteger64([0x779cfa14])),
+        Felt::new(BigInteger64([0x72eb7932])),
+        Felt::new(BigInteger64([0x0b128639])),
+        Felt::new(BigInteger64([0x6165d9ff])),
+        Felt::new(BigInteger64([0x4cb9efdb])),
+        Felt::new(BigInteger64([0x04c5050e])),
+        Felt::new(BigInteger64([0x08bbf640])),
+        Felt::new(BigInteger64([0x2d4f055f])),
+        Felt::new(BigInteger64([0x0fe41f0b])),
+        Felt::new(BigInteger64([0x30c0d503])),
+        Felt::new(BigInteger64([0x34e060f9])),
+    ],
+    [
+        Felt::new(BigInteger64([0x69daa96f])),
+        Felt::new(BigInteger64([0x3b1c54b9])),
+        Felt::new(BigInteger64([0x097a8912])),
+        Felt::new(BigInteger64([0x1ceee929])),
+        Felt::new(BigInteger64([0x5e3bc975])),
+        Felt::new(BigInteger64([0x36bf3ab7])),
+        Felt::new(BigInteger64([0x431789dc])),
+        Felt::new(BigInteger64([0x5763060a])),
+        Felt::new(BigInteger64([0x4f886f9c])),
+        Felt::new(BigInteger64([0x3b1f0047])),
+        Felt::new(BigInteger64([0x428bcd4e])),
+        Felt::new(BigInteger64([0x5c66904f])),
+    ],
+    [
+        Felt::new(BigInteger64([0x32faaf0a])),
+        Felt::new(BigInteger64([0x2727ca9a])),
+        Felt::new(BigInteger64([0x232a2391])),
+        Felt::new(BigInteger64([0x2adb95ea])),
+        Felt::new(BigInteger64([0x71f42d63])),
+        Felt::new(BigInteger64([0x2b2fe512])),
+        Felt::new(BigInteger64([0x0a571476])),
+        Felt::new(BigInteger64([0x72b3dc12])),
+        Felt::new(BigInteger64([0x58861d4d])),
+        Felt::new(BigInteger64([0x33331f14])),
+        Felt::new(BigInteger64([0x49b3eac8])),
+        Felt::new(BigInteger64([0x25ad246f])),
+    ],
+    [
+        Felt::new(BigInteger64([0x5e05eda8])),
+        Felt::new(BigInteger64([0x62acb915])),
+        Felt::new(BigInteger64([0x01121153])),
+        Felt::new(BigInteger64([0x67ca67be])),
+        Felt::new(BigInteger64([0x16b8c03c])),
+        Felt::new(BigInteger64([0x041d3208])),
+        Felt::new(BigInteger64([0x2b34fd6e])),
+        Felt::new(BigInteger64([0x73ef396b])),
+        Felt::new(BigInteger64([0x2bf03bca])),
+        Felt::new(BigInteger64([0x36321fd0])),
+        Felt::new(BigInteger64([0x4203ed11])),
+        Felt::new(BigInteger64([0x32017a9e])),
+    ],
+    [
+        Felt::new(BigInteger64([0x417c9cdb])),
+        Felt::new(BigInteger64([0x71a45961])),
+        Felt::new(BigInteger64([0x60461dbf])),
+        Felt::new(BigInteger64([0x5cfeb3b0])),
+        Felt::new(BigInteger64([0x412c3308])),
+        Felt::new(BigInteger64([0x2e6c1696])),
+        Felt::new(BigInteger64([0x365f4e48])),
+        Felt::new(BigInteger64([0x6f5f0121])),
+        Felt::new(BigInteger64([0x50abea70])),
+        Felt::new(BigInteger64([0x6eb21e2e])),
+        Felt::new(BigInteger64([0x4b924abd])),
+        Felt::new(BigInteger64([0x56656f48])),
+    ],
+];
@@ -0,0 +1,74 @@
+//! The BabyBear field, of modulus `p = 2^31 - 2^27 + 1`
+//!
+//! Elements are represented with the generic 64-bit prime field type of
+//! arkworks, in Montgomery form with `R = 2^64 mod p`.
+
+use ark_ff::{biginteger::BigInteger64 as BigInteger, fields::*};
+
+/// An element of the BabyBear field.
+pub type Felt = Fp64<FeltParameters>;
+
+/// Parameters of the BabyBear field.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FeltParameters;
+
+impl Fp64Parameters for FeltParameters {}
+
+impl FftParameters for FeltParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 27;
+
+    /// `31^((p - 1) / 2^27)`
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([0x2c4f8ad3]);
+}
+
+impl FpParameters for FeltParameters {
+    /// MODULUS = 2013265921
+    const MODULUS: BigInteger = BigInteger([0x78000001]);
+
+    const MODULUS_BITS: u32 = 31;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 33;
+
+    const R: BigInteger = BigInteger([0x45dddde3]);
+
+    const R2: BigInteger = BigInteger([0x27922ab6]);
+
+    const INV: u64 = 0xc7c0000077ffffff;
+
+    /// GENERATOR = 31
+    const GENERATOR: BigInteger = BigInteger([0x05ddde6b]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([0x3c000000]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    /// T = (MODULUS - 1) / 2^27 = 15
+    const T: BigInteger = BigInteger([0xf]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([0x7]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::fields::{
+        fft_field_test, field_test, from_str_test, primefield_test,
+    };
+    use ark_std::test_rng;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_field() {
+        let mut rng = test_rng();
+        let a = Felt::rand(&mut rng);
+        let b = Felt::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Felt>();
+        fft_field_test::<Felt>();
+        from_str_test::<Felt>();
+    }
+}
@@ -0,0 +1,91 @@
+use super::BigInteger64;
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 7;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 1] = [0x66db6db7];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 31;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger64([0x1d59bcd6]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    let x2 = x.square(); //  1: 2
+    let x3 = x2 * x; //  2: 3
+    let x4 = x2.square(); //  3: 4
+    let x6 = x3.square(); //  4: 6
+    let x7 = x6 * x; //  5: 7
+    let mut t = *x;
+    t = t.square(); //  6: 2
+    t = t.square(); //  7: 4
+    t = t.square(); //  8: 8
+    t *= x4; //  9: 12
+    t = t.square(); // 10: 24
+    t = t.square(); // 11: 48
+    t = t.square(); // 12: 96
+    t *= x6; // 13: 102
+    t = t.square(); // 14: 204
+    t = t.square(); // 15: 408
+    t = t.square(); // 16: 816
+    t *= x6; // 17: 822
+    t = t.square(); // 18: 1644
+    t = t.square(); // 19: 3288
+    t = t.square(); // 20: 6576
+    t *= x6; // 21: 6582
+    t = t.square(); // 22: 13164
+    t = t.square(); // 23: 26328
+    t = t.square(); // 24: 52656
+    t *= x6; // 25: 52662
+    t = t.square(); // 26: 105324
+    t = t.square(); // 27: 210648
+    t = t.square(); // 28: 421296
+    t *= x6; // 29: 421302
+    t = t.square(); // 30: 842604
+    t = t.square(); // 31: 1685208
+    t = t.square(); // 32: 3370416
+    t *= x6; // 33: 3370422
+    t = t.square(); // 34: 6740844
+    t = t.square(); // 35: 13481688
+    t = t.square(); // 36: 26963376
+    t *= x6; // 37: 26963382
+    t = t.square(); // 38: 53926764
+    t = t.square(); // 39: 107853528
+    t = t.square(); // 40: 215707056
+    t *= x6; // 41: 215707062
+    t = t.square(); // 42: 431414124
+    t = t.square(); // 43: 862828248
+    t = t.square(); // 44: 1725656496
+    t *= x7; // 45: 1725656503
+
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use super::BigInteger384;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x0869fffffffffe38,
+        0xf3c1de8a7fffff13,
+        0xde791afc9f77bfd6,
+        0x71ebc7264b752910,
+        0xe6ddc13e80b58eb9,
+        0x01a83325a8f9ddce,
+    ])),
+    Felt::new(BigInteger384([
+        0x862f3ffffffffd9f,
+        0x2df720c9cffffec3,
+        0x5f036c766febb7c9,
+        0xd31784eab8fc7887,
+        0x6d97513d9450ca66,
+        0x00875f417432c17e,
+    ])),
+    Felt::new(BigInteger384([
+        0x88fd3ffffffffd07,
+        0x7f37c04d4ffffe74,
+        0xfe81201ffa68f7bb,
+        0x4e661ca22778db8c,
+        0xba8be6fd148d4f4f,
+        0x0114c5a35730b618,
+    ])),
+    Felt::new(BigInteger384([
+        0x8bcb3ffffffffc6f,
+        0xd0785fd0cffffe25,
+        0x9dfed3c984e637ad,
+        0xc9b4b45995f53e92,
+        0x07807cbc94c9d437,
+        0x01a22c053a2eaab3,
+    ])),
+    Felt::new(BigInteger384([
+        0x8bcb3ffffffffc6f,
+        0xd0785fd0cffffe25,
+        0x9dfed3c984e637ad,
+        0xc9b4b45995f53e92,
+        0x07807cbc94c9d437,
+        0x01a22c053a2eaab3,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x0869fffffffffe38,
+        0xf3c1de8a7fffff13,
+        0xde791afc9f77bfd6,
+        0x71ebc7264b752910,
+        0xe6ddc13e80b58eb9,
+        0x01a83325a8f9ddce,
+    ])),
+    Felt::new(BigInteger384([
+        0x862f3ffffffffd9f,
+        0x2df720c9cffffec3,
+        0x5f036c766febb7c9,
+        0xd31784eab8fc7887,
+        0x6d97513d9450ca66,
+        0x00875f417432c17e,
+    ])),
+    Felt::new(BigInteger384([
+        0x88fd3ffffffffd07,
+        0x7f37c04d4ffffe74,
+        0xfe81201ffa68f7bb,
+        0x4e661ca22778db8c,
+        0xba8be6fd148d4f4f,
+        0x0114c5a35730b618,
+    ])),
+    Felt::new(BigInteger384([
+        0x88fd3ffffffffd07,
+        0x7f37c04d4ffffe74,
+        0xfe81201ffa68f7bb,
+        0x4e661ca22778db8c,
+        0xba8be6fd148d4f4f,
+        0x0114c5a35730b618,
+    ])),
+    Felt::new(BigInteger384([
+        0x8bcb3ffffffffc6f,
+        0xd0785fd0cffffe25,
+        0x9dfed3c984e637ad,
+        0xc9b4b45995f53e92,
+        0x07807cbc94c9d437,
+        0x01a22c053a2eaab3,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x0869fffffffffe38,
+        0xf3c1de8a7fffff13,
+        0xde791afc9f77bfd6,
+        0x71ebc7264b752910,
+        0xe6ddc13e80b58eb9,
+        0x01a83325a8f9ddce,
+    ])),
+    Felt::new(BigInteger384([
+        0x862f3ffffffffd9f,
+        0x2df720c9cffffec3,
+        0x5f036c766febb7c9,
+        0xd31784eab8fc7887,
+        0x6d97513d9450ca66,
+        0x00875f417432c17e,
+    ])),
+    Felt::new(BigInteger384([
+        0x862f3ffffffffd9f,
+        0x2df720c9cffffec3,
+        0x5f036c766febb7c9,
+        0xd31784eab8fc7887,
+        0x6d97513d9450ca66,
+        0x00875f417432c17e,
+    ])),
+    Felt::new(BigInteger384([
+        0x88fd3ffffffffd07,
+        0x7f37c04d4ffffe74,
+        0xfe81201ffa68f7bb,
+        0x4e661ca22778db8c,
+        0xba8be6fd148d4f4f,
+        0x0114c5a35730b618,
+    ])),
+    Felt::new(BigInteger384([
+        0x8bcb3ffffffffc6f,
+        0xd0785fd0cffffe25,
+        0x9dfed3c984e637ad,
+        0xc9b4b45995f53e92,
+        0x07807cbc94c9d437,
+        0x01a22c053a2eaab3,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x0869fffffffffe38,
+        0xf3c1de8a7fffff13,
+        0xde791afc9f77bfd6,
+        0x71ebc7264b752910,
+        0xe6ddc13e80b58eb9,
+        0x01a83325a8f9ddce,
+    ])),
+    Felt::new(BigInteger384([
+        0x0869fffffffffe38,
+        0xf3c1de8a7fffff13,
+        0xde791afc9f77bfd6,
+        0x71ebc7264b752910,
+        0xe6ddc13e80b58eb9,
+        0x01a83325a8f9ddce,
+    ])),
+    Felt::new(BigInteger384([
+        0x862f3ffffffffd9f,
+        0x2df720c9cffffec3,
+        0x5f036c766febb7c9,
+        0xd31784eab8fc7887,
+        0x6d97513d9450ca66,
+        0x00875f417432c17e,
+    ])),
+    Felt::new(BigInteger384([
+        0x88fd3ffffffffd07,
+        0x7f37c04d4ffffe74,
+        0xfe81201ffa68f7bb,
+        0x4e661ca22778db8c,
+        0xba8be6fd148d4f4f,
+        0x0114c5a35730b618,
+    ])),
+    Felt::new(BigInteger384([
+        0x8bcb3ffffffffc6f,
+        0xd0785fd0cffffe25,
+        0x9dfed3c984e637ad,
+        0xc9b4b45995f53e92,
+        0x07807cbc94c9d437,
+        0x01a22c053a2eaab3,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x0869fffffffffe38,
+        0xf3c1de8a7fffff13,
+        0xde791afc9f77bfd6,
+        0x71ebc7264b752910,
+        0xe6ddc13e80b58eb9,
+        0x01a83325a8f9ddce,
+    ])),
+    Felt::new(BigInteger384([
+        0x862f3ffffffffd9f,
+        0x2df720c9cffffec3,
+        0x5f036c766febb7c9,
+        0xd31784eab8fc7887,
+        0x6d97513d9450ca66,
+        0x00875f417432c17e,
+    ])),
+    Felt::new(BigInteger384([
+        0x88fd3ffffffffd07,
+        0x7f37c04d4ffffe74,
+        0xfe81201ffa68f7bb,
+        0x4e661ca22778db8c,
+        0xba8be6fd148d4f4f,
+        0x0114c5a35730b618,
+    ])),
+    Felt::new(BigInteger384([
+        0x8bcb3ffffffffc6f,
+        0xd0785fd0cffffe25,
+        0x9dfed3c984e637ad,
+        0xc9b4b45995f53e92,
+        0x07807cbc94c9d437,
+        0x01a22c053a2eaab3,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+];
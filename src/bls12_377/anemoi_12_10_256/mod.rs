@@ -0,0 +1,100 @@
+use super::{sbox, BigInteger384, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 576 bytes.
+pub const STATE_WIDTH: usize = 12;
+/// 10 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 10;
+/// 2 elements of the state are reserved for capacity.
+pub const CAPACITY_WIDTH: usize = 2;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 11 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 11;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+    const CAPACITY_WIDTH: usize = CAPACITY_WIDTH;
+    const SECURITY_LEVEL: u32 = 256;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
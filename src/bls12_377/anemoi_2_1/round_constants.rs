@@ -1,315 +1,315 @@
 use super::BigInteger384;
 use super::Felt;
-use super::NUM_HASH_ROUNDS;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
 
 /// Additive round constants C for Anemoi.
-pub(crate) const C: [Felt; NUM_HASH_ROUNDS] = [
-    Felt::new(BigInteger384([
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger384([
         0xb84ebfffffffe409,
         0x9132d225affff177,
         0x77d13b548673857b,
         0x1b6315703a7a08a5,
         0x837227e22da91c38,
         0x00c061dd4813ea92,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x74034dac2931d81f,
         0x4aea7b7eb441de41,
         0xc97fc4e0ae77e68f,
         0x46681d97065a56e1,
         0xf428ac080bc359d6,
         0x00c826345c784e0c,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x2ddac3088f724417,
         0xf226a1094d8acd73,
         0x4ad0b2730feb469e,
         0x19a247b2b8d51bf1,
         0x301c54a3233336d8,
         0x00d737ed857c66a8,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x8945f5ba9fbec70f,
         0x52db7d35101dfdd6,
         0xe731a332b2f14ace,
         0x84db593a6f33450b,
         0xd52b89331990e227,
         0x012de4b2bbe0a4c8,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xe7a341ea0ec835f2,
         0x2d8932e901571700,
         0xffeaede791e5906d,
         0x717acae64da66bbe,
         0x249598981f376f6b,
         0x01197fa7f1e24729,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x6d12470ea50e10cb,
         0xc0fa39947a07445f,
         0xdd8c20fe8f3a9466,
         0x72ff7297dc37f1f6,
         0xcb755de2ec4dc814,
         0x004a97aceb124e10,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xb7c98917bb436a23,
         0x99da2691bff3fbdf,
         0x5f3fa334e1e41bd8,
         0x11db6b8b6a9fe44d,
         0x486490b928f5fcc4,
         0x018fe3625b0c1e62,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x7ad28a3e5b2eb157,
         0x7a7f1ace81dfb343,
         0x9789ee32bed7853a,
         0x5d23f0c42eeaa1dc,
         0xa23283072c36215e,
         0x00dcd7a958b08881,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x9265f3ce4d1828fd,
         0xa3304701fa785273,
         0x7891f98014d7c696,
         0x778ac2b26ef114a2,
         0xcb23f2ba9a2b387c,
         0x010de63c1ea5d8cb,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x80290e04b1e39d4c,
         0x09a6c9235fa69a1e,
         0x54604b7492a31071,
         0x80610c382ce4b57a,
         0x04889e6b59a208c6,
         0x01420455a4b8acbf,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xbeb8672966bad2e4,
         0xa6ee5492a0da65b5,
         0x1aab81dc18b38295,
         0xaa370e59e7e5e551,
         0x36bfe5665e391091,
         0x001d23093b6a3233,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x60f929dff39dec93,
         0xa3e3b509ddf4e2cb,
         0x230997f3b5dd8ed2,
         0x51e0b0baecf91f22,
         0xe5b0a61f86d9c849,
         0x0034a826c2c5d385,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xb237b6241e98fd7a,
         0xe06c6ba5c27594c7,
         0x225af546badeece5,
         0x176c5348e4c5a69d,
         0x523f96fe40dcd3f8,
         0x00d7b20d91b0c999,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xa5e84b6c8c6b0b72,
         0xdc7d6f187bce5473,
         0x4b3a5736c6c85a26,
         0x7e17e458305be6fc,
         0x9feb006f36ca65a3,
         0x00cc8361a6a44396,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xb9c8edae279ea878,
         0x04b8013813c1f826,
         0x52ed835aaa898d25,
         0x51be98d728217933,
         0x209ad37a86937ec0,
         0x012d17f9a950879e,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x3651944079574608,
         0xcc4a2c08cca0f28e,
         0x06b838ce79561aee,
         0x1b3b94be65f3c719,
         0x0912c9b364539d5e,
         0x00d570865118f8c7,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xb71d0a5818cbeeac,
         0x4e64fcec0267d360,
         0xd1f993aa9e08311b,
         0x3c50e5401339cf4d,
         0x0b1ade26c8002259,
         0x0056b3ea48f91edf,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x819755be4b8f8bcd,
         0x7f9ad569fb7bff26,
         0xfb45a2b81f4b34fe,
         0xbd63b4b9d4203761,
         0x69476bf324a1764e,
         0x004b5cf4255a276a,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x4674425703128357,
         0xd0efb95928d1cea4,
         0x75a7956bcdbc88aa,
         0x324cea780e996ee2,
         0x9a98341abb80596c,
         0x002e666fb128e8d2,
-    ])),
+    ]))],
 ];
 
 /// Additive round constants D for Anemoi.
-pub(crate) const D: [Felt; NUM_HASH_ROUNDS] = [
-    Felt::new(BigInteger384([
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger384([
         0x0f2b9dddddddc1dd,
         0xbee4d384e77768ea,
         0x022b94b0d25c368b,
         0x3ba4d123a87f69cc,
         0x01b45588a882d235,
         0x013c894bd5041075,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x657b5e0b56e4d2b7,
         0x933663e5e80640f4,
         0x86c2454201fd11ed,
         0xcf83ff8bc23367ca,
         0x9a8a7677376b0d32,
         0x0040371c5113783f,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xd324015401688047,
         0xfb9be3cde7a0ef39,
         0x4fb39a9a5b7c56a1,
         0x1c3e01dc0c9f57b3,
         0xdf6560638adc3d36,
         0x01290b3a4c5237af,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x60b0e3af020e9c96,
         0x416a5b22dfa65319,
         0x630bfe8ce801f965,
         0x9deb2a4a31ecee30,
         0x5f0972a6468933bb,
         0x00b72055d7a051b9,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xca0db9d8c5bb8e8a,
         0x89ba58de009b21e4,
         0x76b3390706f68af1,
         0x94a05f70acd10464,
         0x8c707d625b7e47d7,
         0x00134adace20802d,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xa849acfcabce741c,
         0x7a896a113fb51232,
         0x37a73d1ddfbabc9c,
         0x37f5cc90da6a5ee9,
         0xf7eeebcf560417d7,
         0x007a7516abf0be83,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x0968c0f83b99d274,
         0xf4e19a4a70777337,
         0x9e9a00528b97de76,
         0x1080aec869a53647,
         0x67b02dea6c872871,
         0x00af1aa68349a6c1,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x8da057eab75619e6,
         0x3db569dc13d3f23f,
         0x6528030b37ada2fc,
         0xe5b854fca6068f63,
         0x809575400013a9f9,
         0x017db231029e1f9c,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xf1174c8186008859,
         0x4155cc8eea9fc380,
         0x77e6c82a1d0f8cf7,
         0xb5119d96ed8f8a7c,
         0x07ff78ff185fa7e3,
         0x002f74028c46bb3c,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x6e9da662fd560b87,
         0xba8311b6267c6301,
         0x6151af9f6b285f0b,
         0x617868fc51456f65,
         0x4def95f0b53088a5,
         0x0121d8cf25e2d67c,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x92d9d0422f5f1e77,
         0xfd521bfb4e3b1158,
         0xf6cc488b1d116e43,
         0x847add22113785df,
         0xb42470d4b1ed987c,
         0x008e4b42880b103c,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x87cd6ab9a6472438,
         0xd96e0d3cb753a679,
         0x90fe75bbf68d82e8,
         0xdc5673f1ef499c8f,
         0x5e8c1b8829695570,
         0x0093593f39511671,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xa5caf7f4c5ed171a,
         0xb485b6c50b1663fd,
         0xda5b5979ee5f85fb,
         0x39bfb9b1c48e0298,
         0x2460de8347217842,
         0x017443c9722c9b91,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xd8aac18abddae532,
         0x41e6d5042103257c,
         0x0dcc358bf0e36844,
         0x12a156ce806948e7,
         0xfdd849142d54a719,
         0x00ca4060350fa7b2,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x725debd4349f35fc,
         0x76cb2304e0929a8f,
         0x21cfd3fc37bfa922,
         0xc2ac999496c50367,
         0x26d51dcf16f536ba,
         0x0046b8415396b3ba,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0xba181245b3241c8d,
         0x31029cb9cacd9637,
         0x52e5285ee0434a80,
         0xb36ad061c6ac8b50,
         0xbffd71de8109a952,
         0x0185e721dd430fa7,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x1fc229500b938f86,
         0x94a10236c4323fb5,
         0x1997e736521d0abf,
         0x23a5c9ff725bded0,
         0x34f34f29491d86d2,
         0x01162e216bfc8782,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x9dfb82d73f4aae49,
         0x04637acb7fc7fa60,
         0xc0f5afc39f361ff6,
         0xa3ae518c55393cfa,
         0xac3ea1393be51a48,
         0x0082cfbedaebc502,
-    ])),
-    Felt::new(BigInteger384([
+    ]))],
+    [Felt::new(BigInteger384([
         0x16b3f45ee642d04e,
         0x3b46a1b75b1dcb03,
         0x4fae17560b81c5b5,
         0x9d426c53a1ff8808,
         0xd8b46e7ce006ac71,
         0x00cd07aca4b89be3,
-    ])),
+    ]))],
 ];
@@ -0,0 +1,371 @@
+use super::BigInteger384;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0xb84ebfffffffe409,
+            0x9132d225affff177,
+            0x77d13b548673857b,
+            0x1b6315703a7a08a5,
+            0x837227e22da91c38,
+            0x00c061dd4813ea92,
+        ])),
+        Felt::new(BigInteger384([
+            0x62a7dbeae2048989,
+            0x42c66ca79f589974,
+            0x58c464bc7d6cd826,
+            0xa011460aa1069de0,
+            0xf0ec0bf46bd02ec6,
+            0x008e6fe6537e4eb9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x74034dac2931d81f,
+            0x4aea7b7eb441de41,
+            0xc97fc4e0ae77e68f,
+            0x46681d97065a56e1,
+            0xf428ac080bc359d6,
+            0x00c826345c784e0c,
+        ])),
+        Felt::new(BigInteger384([
+            0xbc46d198e31720c4,
+            0x5d65d1fd527ab126,
+            0x5263a97425b196e9,
+            0x3005423f8fad72ed,
+            0xf34bd0b23a47a6b6,
+            0x01578b9112bd25fa,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x2ddac3088f724417,
+            0xf226a1094d8acd73,
+            0x4ad0b2730feb469e,
+            0x19a247b2b8d51bf1,
+            0x301c54a3233336d8,
+            0x00d737ed857c66a8,
+        ])),
+        Felt::new(BigInteger384([
+            0x75e5bc392886bd1b,
+            0xbee5efc4015c81b1,
+            0x31f3ba709f34c2db,
+            0x445a44639814eace,
+            0x2dffce8351837c63,
+            0x001e61573416d6c7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x8945f5ba9fbec70f,
+            0x52db7d35101dfdd6,
+            0xe731a332b2f14ace,
+            0x84db593a6f33450b,
+            0xd52b89331990e227,
+            0x012de4b2bbe0a4c8,
+        ])),
+        Felt::new(BigInteger384([
+            0x54fec443e11b4430,
+            0x410bb1e31b433883,
+            0x3093db33dd6e5a3c,
+            0xc095390a9ff8a063,
+            0x1acd00e455511067,
+            0x01039ddcb3c99415,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xe7a341ea0ec835f2,
+            0x2d8932e901571700,
+            0xffeaede791e5906d,
+            0x717acae64da66bbe,
+            0x249598981f376f6b,
+            0x01197fa7f1e24729,
+        ])),
+        Felt::new(BigInteger384([
+            0x50cb1a8519b3d398,
+            0x59d4e3e1808b5607,
+            0xf9bf3bc4fed90b4b,
+            0x676141569b7052de,
+            0xfb4325c6bd3a4a15,
+            0x01850ff50498d04c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x6d12470ea50e10cb,
+            0xc0fa39947a07445f,
+            0xdd8c20fe8f3a9466,
+            0x72ff7297dc37f1f6,
+            0xcb755de2ec4dc814,
+            0x004a97aceb124e10,
+        ])),
+        Felt::new(BigInteger384([
+            0x837007a966e9736f,
+            0x702f9ae58da529d0,
+            0x882f22215186cd47,
+            0x17d41e161b112edf,
+            0xa0ada3814d8fb67b,
+            0x003f1d37d1c0a085,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xb7c98917bb436a23,
+            0x99da2691bff3fbdf,
+            0x5f3fa334e1e41bd8,
+            0x11db6b8b6a9fe44d,
+            0x486490b928f5fcc4,
+            0x018fe3625b0c1e62,
+        ])),
+        Felt::new(BigInteger384([
+            0x63dfa984cf4910ba,
+            0x8134dbff7f865250,
+            0x2a73ae24925c8f61,
+            0x9ab6025de9e9f509,
+            0xc398ad1a3668478b,
+            0x0078a9aee77070fb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x7ad28a3e5b2eb157,
+            0x7a7f1ace81dfb343,
+            0x9789ee32bed7853a,
+            0x5d23f0c42eeaa1dc,
+            0xa23283072c36215e,
+            0x00dcd7a958b08881,
+        ])),
+        Felt::new(BigInteger384([
+            0x06389e16214ef8fb,
+            0x68f7cd36a2791818,
+            0xdd97cce290886028,
+            0x96dce3e6df1f11be,
+            0x8c5afae58a2d1f49,
+            0x010bb308e97fff66,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9265f3ce4d1828fd,
+            0xa3304701fa785273,
+            0x7891f98014d7c696,
+            0x778ac2b26ef114a2,
+            0xcb23f2ba9a2b387c,
+            0x010de63c1ea5d8cb,
+        ])),
+        Felt::new(BigInteger384([
+            0xfc8080b5469449fd,
+            0x7c8738308b780f87,
+            0x7e87dd9ce0292876,
+            0x8d2d1f63405565e7,
+            0x426ce04b8a2c22d2,
+            0x0189786c02997aa6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x80290e04b1e39d4c,
+            0x09a6c9235fa69a1e,
+            0x54604b7492a31071,
+            0x80610c382ce4b57a,
+            0x04889e6b59a208c6,
+            0x01420455a4b8acbf,
+        ])),
+        Felt::new(BigInteger384([
+            0x194aecbc78ebdbc8,
+            0x67158f577e635adb,
+            0xd6ce1b951543bc80,
+            0x5fd70c913fc87b0e,
+            0x20d58e467c85420a,
+            0x017532ba31894464,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0x0f2b9dddddddc1dd,
+            0xbee4d384e77768ea,
+            0x022b94b0d25c368b,
+            0x3ba4d123a87f69cc,
+            0x01b45588a882d235,
+            0x013c894bd5041075,
+        ])),
+        Felt::new(BigInteger384([
+            0xc127fdd80251fe53,
+            0x5d74ae3de14a3902,
+            0xd2a54ef75ccc8895,
+            0x201a93c949443fd8,
+            0x87b0ceec0cfa8488,
+            0x0046ad8fa9d396a3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x657b5e0b56e4d2b7,
+            0x933663e5e80640f4,
+            0x86c2454201fd11ed,
+            0xcf83ff8bc23367ca,
+            0x9a8a7677376b0d32,
+            0x0040371c5113783f,
+        ])),
+        Felt::new(BigInteger384([
+            0xb56226075339b252,
+            0x92adfa9b90b93bf5,
+            0xff2cbab40cadc1a6,
+            0x18e8b63f85bec4a7,
+            0xb23030728c3ff9d7,
+            0x000bb2b3d0bd7234,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xd324015401688047,
+            0xfb9be3cde7a0ef39,
+            0x4fb39a9a5b7c56a1,
+            0x1c3e01dc0c9f57b3,
+            0xdf6560638adc3d36,
+            0x01290b3a4c5237af,
+        ])),
+        Felt::new(BigInteger384([
+            0xa7dafe93dcec9042,
+            0xcc62d003d5eccb94,
+            0x455095a638461a3d,
+            0xc0e06a8b270c7af1,
+            0xbc0675554c1e6bc0,
+            0x015a8524dc16dac0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x60b0e3af020e9c96,
+            0x416a5b22dfa65319,
+            0x630bfe8ce801f965,
+            0x9deb2a4a31ecee30,
+            0x5f0972a6468933bb,
+            0x00b72055d7a051b9,
+        ])),
+        Felt::new(BigInteger384([
+            0xb915b64785dab0ae,
+            0x33a22d4c2545b5e2,
+            0xbae8299c5fff5032,
+            0x538f76189ddf9de8,
+            0x83688569153b4afb,
+            0x01772a00b0b373f7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xca0db9d8c5bb8e8a,
+            0x89ba58de009b21e4,
+            0x76b3390706f68af1,
+            0x94a05f70acd10464,
+            0x8c707d625b7e47d7,
+            0x00134adace20802d,
+        ])),
+        Felt::new(BigInteger384([
+            0xbfe196831316c327,
+            0xba0da751ba498907,
+            0x7f0179f2c16a4d2e,
+            0x047141df35c83fe5,
+            0x41dba5a28c730b81,
+            0x01692ba8c2013c43,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xa849acfcabce741c,
+            0x7a896a113fb51232,
+            0x37a73d1ddfbabc9c,
+            0x37f5cc90da6a5ee9,
+            0xf7eeebcf560417d7,
+            0x007a7516abf0be83,
+        ])),
+        Felt::new(BigInteger384([
+            0x4b5371a6b0196db7,
+            0x2dc668dd8dcd1fc0,
+            0xf0c4314eef873cdc,
+            0x56b4e40d5470f032,
+            0xabe4cc7f4a37ef3d,
+            0x01594b2273c943ea,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x0968c0f83b99d274,
+            0xf4e19a4a70777337,
+            0x9e9a00528b97de76,
+            0x1080aec869a53647,
+            0x67b02dea6c872871,
+            0x00af1aa68349a6c1,
+        ])),
+        Felt::new(BigInteger384([
+            0x422ae574920f1002,
+            0xe043ed336a83f1c4,
+            0x7847fe508990995e,
+            0x1345b199241c9b64,
+            0xc1a1e55d0ceb5c38,
+            0x00823173f0d82c4c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x8da057eab75619e6,
+            0x3db569dc13d3f23f,
+            0x6528030b37ada2fc,
+            0xe5b854fca6068f63,
+            0x809575400013a9f9,
+            0x017db231029e1f9c,
+        ])),
+        Felt::new(BigInteger384([
+            0x20a9afd1bfe5f880,
+            0x192a5c7b3ee77f30,
+            0x9abc72999cd57d49,
+            0x7f38da2a90734017,
+            0x8340826f845b47a9,
+            0x00e8a3cb5cd2b888,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xf1174c8186008859,
+            0x4155cc8eea9fc380,
+            0x77e6c82a1d0f8cf7,
+            0xb5119d96ed8f8a7c,
+            0x07ff78ff185fa7e3,
+            0x002f74028c46bb3c,
+        ])),
+        Felt::new(BigInteger384([
+            0xe7dddd77c1ec4050,
+            0x1eb45b38b619a8b1,
+            0x8c569f5535e13636,
+            0x449e6645fa213022,
+            0x5e0601a19b527b39,
+            0x019556b351649008,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x6e9da662fd560b87,
+            0xba8311b6267c6301,
+            0x6151af9f6b285f0b,
+            0x617868fc51456f65,
+            0x4def95f0b53088a5,
+            0x0121d8cf25e2d67c,
+        ])),
+        Felt::new(BigInteger384([
+            0x0f62c92a06cde0f9,
+            0x04ee18214fb34bda,
+            0xd346109e81400a7a,
+            0xa0b5fb609e6175cb,
+            0x82bf1b1cfe6461ad,
+            0x00911d6e7c189028,
+        ])),
+    ],
+];
@@ -0,0 +1,302 @@
+use super::BigInteger384;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0xee1d00000009aaa1,
+        0x86840025e97c0007,
+        0x4f7823c40df41de8,
+        0x9e7c71f069ece051,
+        0x7dde005a606d6b99,
+        0x0de0f8777c82e085,
+    ])),
+    Felt::new(BigInteger384([
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x09d645513d83de7e,
+    ])),
+    Felt::new(BigInteger384([
+        0x6631000000105545,
+        0x211400400eec000d,
+        0x3fa7af30c820e316,
+        0xc52a8b8d6387695d,
+        0x9fb4e61d1e83eac5,
+        0x05cb922afe84dc77,
+    ])),
+    Felt::new(BigInteger384([
+        0x223b00000013aa97,
+        0xee5c004d21a40010,
+        0x37bf74e7253745ac,
+        0xd881985be054ade3,
+        0xb0a058fe7d8f2a5b,
+        0x01c0df04bf85da70,
+    ])),
+    Felt::new(BigInteger384([
+        0x223b00000013aa97,
+        0xee5c004d21a40010,
+        0x37bf74e7253745ac,
+        0xd881985be054ade3,
+        0xb0a058fe7d8f2a5b,
+        0x01c0df04bf85da70,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0xee1d00000009aaa1,
+        0x86840025e97c0007,
+        0x4f7823c40df41de8,
+        0x9e7c71f069ece051,
+        0x7dde005a606d6b99,
+        0x0de0f8777c82e085,
+    ])),
+    Felt::new(BigInteger384([
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x09d645513d83de7e,
+    ])),
+    Felt::new(BigInteger384([
+        0x6631000000105545,
+        0x211400400eec000d,
+        0x3fa7af30c820e316,
+        0xc52a8b8d6387695d,
+        0x9fb4e61d1e83eac5,
+        0x05cb922afe84dc77,
+    ])),
+    Felt::new(BigInteger384([
+        0x6631000000105545,
+        0x211400400eec000d,
+        0x3fa7af30c820e316,
+        0xc52a8b8d6387695d,
+        0x9fb4e61d1e83eac5,
+        0x05cb922afe84dc77,
+    ])),
+    Felt::new(BigInteger384([
+        0x223b00000013aa97,
+        0xee5c004d21a40010,
+        0x37bf74e7253745ac,
+        0xd881985be054ade3,
+        0xb0a058fe7d8f2a5b,
+        0x01c0df04bf85da70,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0xee1d00000009aaa1,
+        0x86840025e97c0007,
+        0x4f7823c40df41de8,
+        0x9e7c71f069ece051,
+        0x7dde005a606d6b99,
+        0x0de0f8777c82e085,
+    ])),
+    Felt::new(BigInteger384([
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x09d645513d83de7e,
+    ])),
+    Felt::new(BigInteger384([
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x09d645513d83de7e,
+    ])),
+    Felt::new(BigInteger384([
+        0x6631000000105545,
+        0x211400400eec000d,
+        0x3fa7af30c820e316,
+        0xc52a8b8d6387695d,
+        0x9fb4e61d1e83eac5,
+        0x05cb922afe84dc77,
+    ])),
+    Felt::new(BigInteger384([
+        0x223b00000013aa97,
+        0xee5c004d21a40010,
+        0x37bf74e7253745ac,
+        0xd881985be054ade3,
+        0xb0a058fe7d8f2a5b,
+        0x01c0df04bf85da70,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0xee1d00000009aaa1,
+        0x86840025e97c0007,
+        0x4f7823c40df41de8,
+        0x9e7c71f069ece051,
+        0x7dde005a606d6b99,
+        0x0de0f8777c82e085,
+    ])),
+    Felt::new(BigInteger384([
+        0xee1d00000009aaa1,
+        0x86840025e97c0007,
+        0x4f7823c40df41de8,
+        0x9e7c71f069ece051,
+        0x7dde005a606d6b99,
+        0x0de0f8777c82e085,
+    ])),
+    Felt::new(BigInteger384([
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x09d645513d83de7e,
+    ])),
+    Felt::new(BigInteger384([
+        0x6631000000105545,
+        0x211400400eec000d,
+        0x3fa7af30c820e316,
+        0xc52a8b8d6387695d,
+        0x9fb4e61d1e83eac5,
+        0x05cb922afe84dc77,
+    ])),
+    Felt::new(BigInteger384([
+        0x223b00000013aa97,
+        0xee5c004d21a40010,
+        0x37bf74e7253745ac,
+        0xd881985be054ade3,
+        0xb0a058fe7d8f2a5b,
+        0x01c0df04bf85da70,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+    Felt::new(BigInteger384([
+        0xee1d00000009aaa1,
+        0x86840025e97c0007,
+        0x4f7823c40df41de8,
+        0x9e7c71f069ece051,
+        0x7dde005a606d6b99,
+        0x0de0f8777c82e085,
+    ])),
+    Felt::new(BigInteger384([
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x09d645513d83de7e,
+    ])),
+    Felt::new(BigInteger384([
+        0x6631000000105545,
+        0x211400400eec000d,
+        0x3fa7af30c820e316,
+        0xc52a8b8d6387695d,
+        0x9fb4e61d1e83eac5,
+        0x05cb922afe84dc77,
+    ])),
+    Felt::new(BigInteger384([
+        0x223b00000013aa97,
+        0xee5c004d21a40010,
+        0x37bf74e7253745ac,
+        0xd881985be054ade3,
+        0xb0a058fe7d8f2a5b,
+        0x01c0df04bf85da70,
+    ])),
+    Felt::new(BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ])),
+];
@@ -0,0 +1,371 @@
+use super::BigInteger384;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0x554e0000006f54e6,
+            0xfb9801b4a468005b,
+            0x5e4d31fe251edcdf,
+            0xec5b788a48618a47,
+            0x01eb303433447efa,
+            0x129aa068fb2122b2,
+        ])),
+        Felt::new(BigInteger384([
+            0x36bcf2f85421d531,
+            0xcdb0b77548eef61d,
+            0xb9b1aa5f9670c1ed,
+            0x53e8474b8ecb1eb2,
+            0x4f7c9d6872597084,
+            0x151cd29979b576c3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xaf8842374610fd4f,
+            0x44977c689d0bfbff,
+            0x3219561f9f572518,
+            0x219ad8d001cefc41,
+            0xa788eb5936eb3069,
+            0x114906e42d3525b2,
+        ])),
+        Felt::new(BigInteger384([
+            0xc0a18965503674fe,
+            0xf6ba280d987ad5cd,
+            0x182bfd3839f74553,
+            0xba847ba0dda11ee1,
+            0x745076b098c11027,
+            0x15c43e198cffdb41,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xe3b9df48c8a2dce4,
+            0xbb2c1b9b44487dd4,
+            0x3f7c3a95f53d835f,
+            0xf7f5035d40a06106,
+            0x092ea343ea1e465c,
+            0x0ce327ca3934c193,
+        ])),
+        Felt::new(BigInteger384([
+            0xcd50471d39f8c23e,
+            0xc0a0bcbea3e84339,
+            0x08b6545993fa0e05,
+            0x07fae58eb8c367a1,
+            0xa422b22628d77692,
+            0x13a9da8dffccd33c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x3016ef9979d96cf6,
+            0x5856c522bae21569,
+            0x80391bdcca543195,
+            0x0b8877e14e2ab858,
+            0xe493ffb341473727,
+            0x11182fcaaa29983d,
+        ])),
+        Felt::new(BigInteger384([
+            0x53cb4d96bd4c75a3,
+            0xbda7fefa1e79bda3,
+            0xc9392cbfb1aac667,
+            0x4ac2f81ab7fd904b,
+            0x5e00bb13061ffcaf,
+            0x0d88edccad8d8f98,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xab21fd59b9c21f9a,
+            0xcbdc24518e56b16d,
+            0x1802e4c76b194656,
+            0x91ee92ec97003e5b,
+            0x1617a16309943af8,
+            0x06a590e9f424abbf,
+        ])),
+        Felt::new(BigInteger384([
+            0x13eb741fc42a81b3,
+            0x08c118a1b0353a6a,
+            0xb457e25d6a327d2a,
+            0xeedbd59b86fcd9ed,
+            0x2c5235d5ab85dfb3,
+            0x051b72d12b934685,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xa56afd4698b3e027,
+            0x82e066b3a4a90aad,
+            0x98a848c960ea604f,
+            0xdebc932ee883dd9b,
+            0xaf162edfb610ad02,
+            0x122690f274fb48f1,
+        ])),
+        Felt::new(BigInteger384([
+            0x1d4150d3b5707004,
+            0x458d72b599d11b23,
+            0x7fbfaa7ed51d15e9,
+            0x1841cf7b1f4336f3,
+            0xfa56bc8cd10b0fb9,
+            0x16c2008dabe1c052,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x0ac633ec0f9ed5c6,
+            0x2d4975e1432bc04e,
+            0x886a94c421734588,
+            0x9628d9f0fe1e020c,
+            0xe540a1f286234168,
+            0x1872e7c1a537f17b,
+        ])),
+        Felt::new(BigInteger384([
+            0xa34913ab8eca284c,
+            0x77d02eb60c93fc92,
+            0x6921cb8fc167dbb7,
+            0x62976f72cb98fcb9,
+            0x7ac798b130a2d325,
+            0x0dc08d9c33429d1b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x1266bf394049156f,
+            0x23d6e9c0313b88a5,
+            0x996e80b65d93210a,
+            0x739ce296e4c945de,
+            0xdab1df119a7c3760,
+            0x05feecdf2b61e634,
+        ])),
+        Felt::new(BigInteger384([
+            0x06925d47888290ca,
+            0x8d0fd081430ba3a0,
+            0x28dca818963b549e,
+            0x71efac4fff570af1,
+            0x2ccf8ecb28664c70,
+            0x1726cb40a95c5c6b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xc0d83808eb7f604f,
+            0x6d93e2aee6304bfc,
+            0x6596c5b8c5da2f8b,
+            0xf5ea136838bdf4df,
+            0xf9ed67ab58387808,
+            0x0a1a018aca7dc1db,
+        ])),
+        Felt::new(BigInteger384([
+            0x1d81e6c71e045f30,
+            0xc48345aad756021f,
+            0x5f1b821e399a0329,
+            0x5ee47b0cf90d5af3,
+            0x7b8717482d4a03af,
+            0x125e6dc5b55f169e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9becb1b618c73764,
+            0x256667fd6b696a00,
+            0x7f1157ab9fdd2607,
+            0x67817025c977bcca,
+            0x26dc483c1d3480cc,
+            0x0a8b3a522660ef70,
+        ])),
+        Felt::new(BigInteger384([
+            0xaa16221373bb9885,
+            0xe67930db105366ab,
+            0x9d80e4b6f7bd8156,
+            0x4e1caed917481f87,
+            0x839c09665b9be458,
+            0x17cf243d49039e50,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0xb35300000070ff8f,
+            0x623c01bb2dc4005c,
+            0x5a5914d953aa0e2b,
+            0xf606fef186c82c8a,
+            0x8a60e9a4e2ca1ec5,
+            0x109546d5dba1a1ae,
+        ])),
+        Felt::new(BigInteger384([
+            0xb7c6a2a2c919a7e8,
+            0xaedf622c4a682a7c,
+            0x2a4c8ab1ffef7f2f,
+            0x2403f35be651c6ae,
+            0x7b376e61b129a4bb,
+            0x0b5193fe9d38ca88,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x0edfd8aed14d0eee,
+            0x4c7b3e49117fb384,
+            0x9190d375aab1c894,
+            0x5435c20ee7d640b1,
+            0xd761c6e55eaf83ed,
+            0x12b482e32d994227,
+        ])),
+        Felt::new(BigInteger384([
+            0x42fdcf875068aeab,
+            0x7928949e850bc1b0,
+            0xec327805804574c6,
+            0xb38f8a88dcc86909,
+            0x476e69c54fcff817,
+            0x0f69d510d066cc7f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xc3b6cc6ac460a022,
+            0x33b6d050390ca37d,
+            0xc7aa0ed3955e9331,
+            0xd7baa33253367979,
+            0x72c71fdee90300e5,
+            0x06e787b65f0cc438,
+        ])),
+        Felt::new(BigInteger384([
+            0xd051e3e9aaacad8a,
+            0xb3b61c2410c99d40,
+            0x0573260e6f0ea9cd,
+            0xae30ab0ce47985cd,
+            0xb1004649b706c586,
+            0x05e8557268a7aaaa,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xdf0be418b6896b42,
+            0x198729d410874d6d,
+            0xdcb6cbaa24bfa62f,
+            0xb956d82a9c0f6c14,
+            0x0f74e838700ce8d9,
+            0x15ce449721dac59b,
+        ])),
+        Felt::new(BigInteger384([
+            0x25c4f1c06ef29bfd,
+            0xf9630e5bec3c2a06,
+            0x9a45da044709c6f7,
+            0xbf017e0d1f0249c0,
+            0x2c26bb20c43042cd,
+            0x0a791d91684191be,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x13c51b58762fd55d,
+            0xc3f606e37698802e,
+            0xe9b046d1d108470c,
+            0xc8392d18cc8c585f,
+            0xb7950454f1315b70,
+            0x0d9c127033530639,
+        ])),
+        Felt::new(BigInteger384([
+            0x9f9341c8f58e5f84,
+            0x7b65a5e410943d88,
+            0xfa9441df0b1509d6,
+            0xeb969570d5a8f9aa,
+            0x7114b050226d9497,
+            0x044c0f4fadc475c8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x97e30dd5766cd52b,
+            0x688796158c8e7c75,
+            0x949dbff26481cfa7,
+            0xe03ce17569f74fee,
+            0x6271027b9f20ca9d,
+            0x17ace94ee4fbe578,
+        ])),
+        Felt::new(BigInteger384([
+            0x32be110d081f8d16,
+            0xa5bf4cc7f9d3c149,
+            0xf0441f1f13a81137,
+            0xe032436ab9d6aefe,
+            0x50f6a7b14965c1bf,
+            0x148273e25ee531a2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x4525df0da55703d6,
+            0x85b8dce787244883,
+            0x9502c02e3d3f66de,
+            0x8b203eb5fd5fa145,
+            0x4f06439f378c9cfb,
+            0x0d8976760fd1ca51,
+        ])),
+        Felt::new(BigInteger384([
+            0xbaac6e7799782915,
+            0x6976406b79fdb925,
+            0x5179c7120ed87f28,
+            0x82764565d77fb46a,
+            0xd2edf99cb4a26ffb,
+            0x151249331a5f3153,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x2e8317697e8b6706,
+            0x39ab42ba083f5c4a,
+            0x609389e3b7c9a834,
+            0x254adc127a0aedb8,
+            0x3c19a386dcd0505f,
+            0x0f4a62f3881118a5,
+        ])),
+        Felt::new(BigInteger384([
+            0x45b365223bbb0a6f,
+            0x1d6ed42b922caba3,
+            0x6490aebd2b6567bf,
+            0xea0dcb74adb8b883,
+            0x317c6ac8fa04f9da,
+            0x18ac5c4d490e63a4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9a098a2d602289d6,
+            0x7ba26524bbe1e5e8,
+            0xb261465b415562dc,
+            0xfab789eac1fe80de,
+            0x64811fa26204834c,
+            0x06a949c0aa9bb083,
+        ])),
+        Felt::new(BigInteger384([
+            0x19b7e896079db0c5,
+            0x4d1c72d12524d069,
+            0x20750037f008c271,
+            0x2a2217389b6decab,
+            0x895fe6c7c660a35f,
+            0x0727d0f3d87fda0e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x43b3ff031221a4f3,
+            0xb9cdc9b4d7d468b5,
+            0xa0b3b117183bb241,
+            0x4e6c9a6a99587f8f,
+            0x02dac9201be99ea1,
+            0x02462176aff78b2a,
+        ])),
+        Felt::new(BigInteger384([
+            0x74e21f0ae20c2e22,
+            0xf56b3d42f4db99be,
+            0x33b23b99ab0f9987,
+            0xfb77fec70048e805,
+            0x02dfa1d2e99b9698,
+            0x07c4265a159d0ed3,
+        ])),
+    ],
+];
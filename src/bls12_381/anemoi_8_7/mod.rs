@@ -1,20 +1,14 @@
-use super::{mul_by_generator, sbox, BigInteger384, Felt};
-use crate::{Jive, Sponge};
-use ark_ff::{Field, One, Zero};
-use unroll::unroll_for_loops;
+use super::{sbox, BigInteger384, Felt};
+use crate::{Anemoi, AnemoiParameters};
 
-/// Digest for Anemoi
-mod digest;
-/// Sponge for Anemoi
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
 mod hasher;
 /// MDS matrix for Anemoi
 mod mds;
 /// Round constants for Anemoi
 mod round_constants;
 
-pub use digest::AnemoiDigest;
-pub use hasher::AnemoiHash;
-
 // ANEMOI CONSTANTS
 // ================================================================================================
 
@@ -33,94 +27,82 @@ pub const DIGEST_SIZE: usize = 1;
 /// The number of rounds is set to 10 to provide 128-bit security level.
 pub const NUM_HASH_ROUNDS: usize = 10;
 
-// HELPER FUNCTIONS
+// ANEMOI PARAMETERS
 // ================================================================================================
 
-#[inline(always)]
-/// Applies exponentiation of the current hash
-/// state elements with the Anemoi S-Box.
-pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
-    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
-    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
 
-    x.iter_mut().enumerate().for_each(|(i, t)| {
-        let y2 = y[i].square();
-        *t -= mul_by_generator(&y2);
-    });
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
 
-    let mut x_alpha_inv = x;
-    x_alpha_inv
-        .iter_mut()
-        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
 
-    y.iter_mut()
-        .enumerate()
-        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
 
-    x.iter_mut().enumerate().for_each(|(i, t)| {
-        let y2 = y[i].square();
-        *t += mul_by_generator(&y2) + sbox::DELTA;
-    });
+    const MDS: &'static [Felt] = &mds::MDS;
 
-    state[..NUM_COLUMNS].copy_from_slice(&x);
-    state[NUM_COLUMNS..].copy_from_slice(&y);
-}
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
 
-#[inline(always)]
-/// Applies matrix-vector multiplication of the current
-/// hash state with the Anemoi MDS matrix.
-pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
-    apply_mds_internal(&mut state[..NUM_COLUMNS]);
-    state[NUM_COLUMNS..].rotate_left(1);
-    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
 }
 
-#[inline(always)]
-fn apply_mds_internal(state: &mut [Felt]) {
-    state[0] += state[1];
-    state[2] += state[3];
-    state[3] += mul_by_generator(&state[0]);
-    state[1] = mul_by_generator(&(state[1] + state[2]));
-    state[0] += state[1];
-    state[2] += mul_by_generator(&state[3]);
-    state[1] += state[2];
-    state[3] += state[0];
-}
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
 
-// ANEMOI PERMUTATION
-// ================================================================================================
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
 
-/// Applies an Anemoi permutation to the provided state
-#[inline(always)]
-#[unroll_for_loops]
-pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
-    for i in 0..NUM_HASH_ROUNDS {
-        apply_round(state, i);
-    }
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
 
-    apply_mds(state)
-}
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
 
-/// Applies an Anemoi round to the provided state
-#[inline(always)]
-#[unroll_for_loops]
-pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
-    // determine which round constants to use
-    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
-    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
 
-    for i in 0..NUM_COLUMNS {
-        state[i] += c[i];
-        state[NUM_COLUMNS + i] += d[i];
-    }
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
 
-    apply_mds(state);
-    apply_sbox(state);
-}
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ark_ff::{One, Zero};
 
     fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
         let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
@@ -1203,7 +1185,7 @@ mod tests {
         ];
 
         for i in input.iter_mut() {
-            apply_sbox(i);
+            AnemoiPermutation::apply_sbox(i);
         }
 
         for (&i, o) in input.iter().zip(output) {
@@ -2180,7 +2162,7 @@ mod tests {
         ];
 
         for i in input.iter_mut() {
-            apply_mds(i);
+            AnemoiPermutation::apply_mds(i);
         }
         for i in input2.iter_mut() {
             apply_naive_mds(i);
@@ -0,0 +1,230 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0xe4b1c5ae034e46ca,
+        0x9cdb2d3b64716da7,
+        0x47d8eb76d8dd067e,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xb80f093bc8dd5467,
+        0xa75418645a3878e5,
+        0xae478ee651564caa,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xb80f093bc8dd5467,
+        0xa75418645a3878e5,
+        0xae478ee651564caa,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0xe4b1c5ae034e46ca,
+        0x9cdb2d3b64716da7,
+        0x47d8eb76d8dd067e,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xe4b1c5ae034e46ca,
+        0x9cdb2d3b64716da7,
+        0x47d8eb76d8dd067e,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xb80f093bc8dd5467,
+        0xa75418645a3878e5,
+        0xae478ee651564caa,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0xe4b1c5ae034e46ca,
+        0x9cdb2d3b64716da7,
+        0x47d8eb76d8dd067e,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xb80f093bc8dd5467,
+        0xa75418645a3878e5,
+        0xae478ee651564caa,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0xe4b1c5ae034e46ca,
+        0x9cdb2d3b64716da7,
+        0x47d8eb76d8dd067e,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xb80f093bc8dd5467,
+        0xa75418645a3878e5,
+        0xae478ee651564caa,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0xe4b1c5ae034e46ca,
+        0x9cdb2d3b64716da7,
+        0x47d8eb76d8dd067e,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xb80f093bc8dd5467,
+        0xa75418645a3878e5,
+        0xae478ee651564caa,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+];
@@ -0,0 +1,847 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x8c7ac37d8dacf7b1,
+            0x8379fceb85c49fef,
+            0xcdfd9f1c6987245d,
+            0x07834efb432341d9,
+        ])),
+        Felt::new(BigInteger256([
+            0xbcb4a9832e0452f6,
+            0x17f32b51a1825b47,
+            0xbc7cfa76f63d4d10,
+            0x2080396d88a28da5,
+        ])),
+        Felt::new(BigInteger256([
+            0x58383a2b3e062e78,
+            0x139087bf6787bba0,
+            0x746f4ef9c0075f89,
+            0x0c1e6f9df6e100ec,
+        ])),
+        Felt::new(BigInteger256([
+            0x33a3729bd891f831,
+            0x80c5b3dd8b12d61b,
+            0xc7202b557666d3ef,
+            0x0c6d759d8f130b61,
+        ])),
+        Felt::new(BigInteger256([
+            0xea695f786fab0f3c,
+            0xb50385589698d0eb,
+            0x55ba9e76b6dbe80e,
+            0x157778336fa48514,
+        ])),
+        Felt::new(BigInteger256([
+            0xf8ad4140bfdc74d0,
+            0x29a840229bfc6a03,
+            0x7bc3a5834f695981,
+            0x203fc041dcab396c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4a352612c6a8e3f0,
+            0x9dc26d51a557485b,
+            0xefb81e824a630f77,
+            0x1897a39081505f21,
+        ])),
+        Felt::new(BigInteger256([
+            0xaf8f311d307dde18,
+            0x2dc337dea19c5d6d,
+            0x8bca456f059100d0,
+            0x2e056bfa2066b103,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d3ccb501a400bfe,
+            0xab9ae8a14e3a9ca5,
+            0x9f6525bedf2f1f8c,
+            0x170918aab2d1b637,
+        ])),
+        Felt::new(BigInteger256([
+            0xd9315b446cf36e00,
+            0x3e13c399720beefc,
+            0x9166a9413d879c4d,
+            0x0e78cb0c63d9b369,
+        ])),
+        Felt::new(BigInteger256([
+            0x0465b76878aa6c5b,
+            0x4cfe809a19643db2,
+            0xc18c81bec544f3cc,
+            0x10437eb53da87337,
+        ])),
+        Felt::new(BigInteger256([
+            0x98c76b40c4a8195c,
+            0xd79f435d4c4c4d0c,
+            0xa196ce3f7368b239,
+            0x106195e0291cd9a0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf7d8d3b737ea1d29,
+            0xdcc2dcdfea9edeef,
+            0xa9821f4b970c5434,
+            0x0880c672be1b35ac,
+        ])),
+        Felt::new(BigInteger256([
+            0x9b033bfba8347d8e,
+            0x4989cfaefada94a3,
+            0x5644e18871b8aa8f,
+            0x0760d0b4ec5e688d,
+        ])),
+        Felt::new(BigInteger256([
+            0xaf8d70e258694b4b,
+            0xc694d35cfcce72d5,
+            0x21d62af141d3012a,
+            0x0db8ee2033a275c2,
+        ])),
+        Felt::new(BigInteger256([
+            0xe2378a9d81e9244a,
+            0xcf6ad3ffbac55253,
+            0x79f90a021a181593,
+            0x23bf3ac3da612824,
+        ])),
+        Felt::new(BigInteger256([
+            0x3ac34ae160d7db36,
+            0xfc501d65bdca5441,
+            0xd47ac42df57cc66d,
+            0x164ec9f47862e8eb,
+        ])),
+        Felt::new(BigInteger256([
+            0xacab5eacf1ee9bf6,
+            0x9616b26043ca885c,
+            0x69bfefb19f073004,
+            0x18d81f33de859078,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcfe134c7cab7cb71,
+            0xb40cb4c365f49ee2,
+            0x8a2d45ec04b07066,
+            0x1c144fb74db40686,
+        ])),
+        Felt::new(BigInteger256([
+            0xfd2c413f8aadf0dc,
+            0x25a9b563b789d1ee,
+            0x8443ae3ef1cd82d6,
+            0x1812e17b35225e1f,
+        ])),
+        Felt::new(BigInteger256([
+            0xdd5e3343bc880e9f,
+            0xf6ed2f6515c39c41,
+            0xb7d0e88824d9c7d8,
+            0x20abc3aadb358361,
+        ])),
+        Felt::new(BigInteger256([
+            0x950a517cd9b458ec,
+            0xe2c5ef668f0e7a5c,
+            0xff1b684777b73f91,
+            0x0f542748bdcd00fa,
+        ])),
+        Felt::new(BigInteger256([
+            0xe9ad4d0581f9a17f,
+            0x87cb509f6b00146e,
+            0x47770f4b7c9c4e95,
+            0x252d1197f760754a,
+        ])),
+        Felt::new(BigInteger256([
+            0xb1559b937e8111b4,
+            0x3705cea016a6d987,
+            0x74f27aeeddb47aa9,
+            0x17875540486e5cb4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa39878d923e054dd,
+            0x0bd17b23ef33a980,
+            0x10b2798eae5a9df3,
+            0x04a15145df894402,
+        ])),
+        Felt::new(BigInteger256([
+            0x46d8fca9d3a398a0,
+            0x6f8eda409a49ca67,
+            0x0d37d68dad44e002,
+            0x223844d31901ddbc,
+        ])),
+        Felt::new(BigInteger256([
+            0x7257ccaf4f0b6412,
+            0xf469403345675112,
+            0x860c0eee10d3042f,
+            0x2a47ee26a14411a9,
+        ])),
+        Felt::new(BigInteger256([
+            0x2b162e879353afc0,
+            0x45b85adb60078f6d,
+            0x9937628974cba3a4,
+            0x04b4db65f505efde,
+        ])),
+        Felt::new(BigInteger256([
+            0x9e58491567654902,
+            0xaa99f6326a90ef8b,
+            0x3215c6c4d7fc7658,
+            0x2ccc51a262b38679,
+        ])),
+        Felt::new(BigInteger256([
+            0xcdca1d716eedc693,
+            0x5c14b07ab1f4ca49,
+            0x0d51d81958e52129,
+            0x1ed827af20f46714,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x350c014927a20623,
+            0x25d0f48d0f9462b5,
+            0x068ec9b27486a617,
+            0x014c45b801fe46bd,
+        ])),
+        Felt::new(BigInteger256([
+            0xd659fbb7ab7f255a,
+            0x21e0a21f7edb5323,
+            0xc5ee450c5743164b,
+            0x0953c3786439b0b8,
+        ])),
+        Felt::new(BigInteger256([
+            0xe3b1cf89618a78bc,
+            0xbe83467c65286167,
+            0xd7271e0ba475182e,
+            0x09fa0b6770401baa,
+        ])),
+        Felt::new(BigInteger256([
+            0x246f4563fa48711d,
+            0xc587903eef01462d,
+            0x570d50d245f97425,
+            0x22bedc6d84693732,
+        ])),
+        Felt::new(BigInteger256([
+            0xc589b74e95d6e666,
+            0xef18ebbce160bbf6,
+            0xb1c10122fd2a67b3,
+            0x0f1736b4185238fa,
+        ])),
+        Felt::new(BigInteger256([
+            0xc43c3f5c9dd492d4,
+            0xf3225d2bb3ed4f9f,
+            0x10814133f88d66c4,
+            0x2549db534414b337,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd6c4ed792a61ed79,
+            0xe57202db8eaaee4c,
+            0x81a11a2c7ba52d13,
+            0x068b2baa0cc4a6b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x13778194c8698de2,
+            0xd77218dcf8f01f07,
+            0x78c8a354c9a47153,
+            0x220a2e8bb81e9d99,
+        ])),
+        Felt::new(BigInteger256([
+            0x90a7c328a4ab0f61,
+            0x93255ed99ec1591f,
+            0xd42423690ba1a321,
+            0x20c4d3e150494f99,
+        ])),
+        Felt::new(BigInteger256([
+            0xa00100f329dcd81b,
+            0x132fa270d84e7911,
+            0x50c85629de83e336,
+            0x170015d4dbb336ba,
+        ])),
+        Felt::new(BigInteger256([
+            0x955aa9cfe45e609c,
+            0xc71348da8bfc912b,
+            0xb9adbe31523a1b4c,
+            0x20dbd157b90f1a66,
+        ])),
+        Felt::new(BigInteger256([
+            0xa7724f21818f0edb,
+            0x0efd4b3ac4f67e83,
+            0xf7c38f664ed9ba3a,
+            0x2a7be01418c5517b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd41dd442ab91976b,
+            0x9f90463e3cf35c61,
+            0x576cfa3c91fc74bd,
+            0x1b9a510a722108df,
+        ])),
+        Felt::new(BigInteger256([
+            0x6578e5ec5996b67d,
+            0x23b9b04b9e02384a,
+            0x7fe603f559d48d86,
+            0x234b7978afd113e4,
+        ])),
+        Felt::new(BigInteger256([
+            0xe30e490d0ee49729,
+            0xd73fd9eab4be31ed,
+            0xf0ac81f5f063696a,
+            0x17b39d149114cf7f,
+        ])),
+        Felt::new(BigInteger256([
+            0x30e8f12076e8c253,
+            0xbbc9429582a7c13f,
+            0x1752934da5e50b21,
+            0x23a0c2bec1116e27,
+        ])),
+        Felt::new(BigInteger256([
+            0x49b163f57d36483c,
+            0xd2fe0ec3aeb03d1b,
+            0x2a12b6adb99545fd,
+            0x0e2599e1f5d80a5e,
+        ])),
+        Felt::new(BigInteger256([
+            0xfbace38591b98ec7,
+            0x8cebb0b7de924d93,
+            0x16b7314c3727171b,
+            0x25abef376b4ec3c0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcb6309851cb51205,
+            0x9a9622f96760fa86,
+            0xb806e9d7a219287c,
+            0x1bdc93ec06e2adb9,
+        ])),
+        Felt::new(BigInteger256([
+            0x02c59b21cc7c43b1,
+            0xc4ed6d619a817baf,
+            0x46832bf55f34cde4,
+            0x2afff13fbc0220ae,
+        ])),
+        Felt::new(BigInteger256([
+            0xebc7d154bd739901,
+            0xa8d0a64072a09d7a,
+            0x9d130291903bc9b4,
+            0x0f8f39ae176003b3,
+        ])),
+        Felt::new(BigInteger256([
+            0xe5426421bce8b193,
+            0xa5c909a106471b81,
+            0xe82576548c09392d,
+            0x116f37077dd6fc47,
+        ])),
+        Felt::new(BigInteger256([
+            0xe51890632231ba82,
+            0xf7db21a22fc98f64,
+            0x2aa43a9e8fc81e64,
+            0x07a220235c57f042,
+        ])),
+        Felt::new(BigInteger256([
+            0x852c10c6d4392a1a,
+            0x979b5ae7cb8d3286,
+            0x44547553937605cf,
+            0x0b421cb7e648ed14,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2af89d2b0f05d8b0,
+            0x7862ad62dc2b173e,
+            0xd0b8ab58c35ba407,
+            0x257b41417e5b8751,
+        ])),
+        Felt::new(BigInteger256([
+            0x5315bddfd95b8722,
+            0x620e69e6f79f7698,
+            0xc32bbed12b8ec0bc,
+            0x19d298b934cb2d4c,
+        ])),
+        Felt::new(BigInteger256([
+            0xb689d5bfebb3dbe9,
+            0x6f470683a1347879,
+            0xa0b55767bc93ffeb,
+            0x1eb8785bcee3c03b,
+        ])),
+        Felt::new(BigInteger256([
+            0x380e25ddc501cf89,
+            0x4a2bddeafa27a8ef,
+            0x86cd901060dc771a,
+            0x176440ac92f3cf5c,
+        ])),
+        Felt::new(BigInteger256([
+            0x1fef85b94ba737b8,
+            0xf94d706d5b7c3092,
+            0xf49d514cc07e507c,
+            0x1c27ce02c1a2c1d5,
+        ])),
+        Felt::new(BigInteger256([
+            0x00eebeddc66fed28,
+            0x3ebfbbc837739774,
+            0x4fbfa0e4e56dd5af,
+            0x261b8660dcddd755,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3cf60a6eb72a48a2,
+            0x44d071170c5702f6,
+            0xd312ff523d341dfd,
+            0x2921ffe5a570b20a,
+        ])),
+        Felt::new(BigInteger256([
+            0xbf4969afc606cba9,
+            0xb0d5ea7f0dec31e6,
+            0x7913d0e9e24800d2,
+            0x2844d10f2b8d1810,
+        ])),
+        Felt::new(BigInteger256([
+            0x99f35deac37bdd46,
+            0x07206d0fcbfe641e,
+            0xc1b935c97c75fa3d,
+            0x14882ec83be319e1,
+        ])),
+        Felt::new(BigInteger256([
+            0x23f702295a056cca,
+            0x7a09c9480331e63d,
+            0xd4cc1185041d5ac2,
+            0x0738db31fb6f242f,
+        ])),
+        Felt::new(BigInteger256([
+            0xd0e7c7b3eda71f3d,
+            0xabdbbb33efa83e31,
+            0x98a3c3fad1d0b740,
+            0x12573ba6bdd03c90,
+        ])),
+        Felt::new(BigInteger256([
+            0xb6a48aea00ec4876,
+            0xaf2f537c77677d24,
+            0xdc3a068583ff1f46,
+            0x09a26d5d8071a251,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x3c4f5e09c25ba5fd,
+            0x6422c429a52ce733,
+            0x82e7ecd3bcdaae8b,
+            0x1c52e66216e116f7,
+        ])),
+        Felt::new(BigInteger256([
+            0x99c52c2526d457e8,
+            0xa0e1eda4470f81a4,
+            0x4d7a95c913c9cd15,
+            0x19b801ad712cecba,
+        ])),
+        Felt::new(BigInteger256([
+            0x5f5c663713603065,
+            0x0f7fff14d90c2975,
+            0x2269899a0a3e6b83,
+            0x2597d27153d70b67,
+        ])),
+        Felt::new(BigInteger256([
+            0x67a1f92a2da9fe60,
+            0x7b1c3073cbc52e47,
+            0xaddb231247d6d024,
+            0x2b8a7f0895dfdf4e,
+        ])),
+        Felt::new(BigInteger256([
+            0xf36fcf59063870fd,
+            0x5a82020f143ef34e,
+            0x8dc74e11c9e1c1d1,
+            0x28f51307c860e618,
+        ])),
+        Felt::new(BigInteger256([
+            0x9cb6199ae44ae47b,
+            0xeffcd43000506bef,
+            0xe2e06a52e1245b75,
+            0x0d64ff558eb1348b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa731b3d81fb2d234,
+            0xc47aa4d60a3a7601,
+            0xcf79f8b21bf685b9,
+            0x0c363e1d8d409d90,
+        ])),
+        Felt::new(BigInteger256([
+            0x39c7a6f84da92302,
+            0xfcc16a778ca46a2d,
+            0x479f6d39a15d6ce9,
+            0x060c376041237969,
+        ])),
+        Felt::new(BigInteger256([
+            0x1188ea9513f54de3,
+            0xed99d03d0539f0dd,
+            0x7836ecd7a7a6179a,
+            0x0f517ea447fa2a03,
+        ])),
+        Felt::new(BigInteger256([
+            0xba57d50be666b427,
+            0x7e79b075f8392d8b,
+            0xa2f92d768d378496,
+            0x0c64d79da2d8f0a7,
+        ])),
+        Felt::new(BigInteger256([
+            0xba941a8233930e14,
+            0x388c6d96dc854677,
+            0x2470bdd2568ab9a3,
+            0x02901cafce973d8d,
+        ])),
+        Felt::new(BigInteger256([
+            0x2618c2eae5eec646,
+            0x7b84b2425e8cffe8,
+            0xebdb653e04e4f8a0,
+            0x0cba268cf486de3a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x76268a752861db39,
+            0xbc9113ed82889a89,
+            0x797351d166c2e70d,
+            0x2d4274511143a45f,
+        ])),
+        Felt::new(BigInteger256([
+            0x468cdacf5ccd9244,
+            0xd19e01d118e92f56,
+            0x024961a90ba8333f,
+            0x108aaf6c54536137,
+        ])),
+        Felt::new(BigInteger256([
+            0x490a2d09110f5fb5,
+            0x2a284ff07e628a73,
+            0x328704a986ebbd72,
+            0x06c018f82ed179a8,
+        ])),
+        Felt::new(BigInteger256([
+            0xa88ea146ba4d3cf6,
+            0x316555d40b875448,
+            0xc36aa0d6e669c216,
+            0x226a0c337f66f57c,
+        ])),
+        Felt::new(BigInteger256([
+            0xd6224adcdab14f74,
+            0x09729f5a4b80206c,
+            0x6f3e12e10364507e,
+            0x095a2ccd6f58435b,
+        ])),
+        Felt::new(BigInteger256([
+            0x1f2d5338d2261b65,
+            0x5b90b63d209ffe9e,
+            0xebe3994fad253aa4,
+            0x15ef74bf0ff6252c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6fe12ea447c6f942,
+            0xed1f75d65d45c1c8,
+            0xb2499a8e67fb10af,
+            0x1611356c5fa0dd07,
+        ])),
+        Felt::new(BigInteger256([
+            0x0688af48a45b729a,
+            0x9e83dc1c9d719e7b,
+            0x40c39632a0d27154,
+            0x26dc467c3d0d5ec1,
+        ])),
+        Felt::new(BigInteger256([
+            0xd4adbe9fda429011,
+            0x4b46a08f5f30e5b8,
+            0xd8fd2a137f07e9ee,
+            0x1f5274cc765a8f3f,
+        ])),
+        Felt::new(BigInteger256([
+            0xb934375b772cdea0,
+            0x358665d1a7a9ae2a,
+            0x590866ef591e51e2,
+            0x139e7f0202c8d64b,
+        ])),
+        Felt::new(BigInteger256([
+            0xe2df1c3660e782c5,
+            0x85b3c72ac08f1273,
+            0xf2b5c5d19f993e73,
+            0x1dd7faba8e4bd7b1,
+        ])),
+        Felt::new(BigInteger256([
+            0x81aa5f54c3ccfe2b,
+            0xed45c713bb5581a2,
+            0x07918c6000e7eb16,
+            0x1a3e311519d4f961,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x68ff25ed9d151663,
+            0xcf4380aa1ab729c5,
+            0xb32cc3bf19dde0b3,
+            0x133e55cc92c1622c,
+        ])),
+        Felt::new(BigInteger256([
+            0x397b91d410f9b0cc,
+            0xdb46dadb4bf229c5,
+            0x8bc56e58e301189a,
+            0x153d7a32e10685dd,
+        ])),
+        Felt::new(BigInteger256([
+            0x52ed7f2c906e7bf2,
+            0x3ba08b3f5a952d5b,
+            0x69460050f1b8705f,
+            0x0d2a6fa6fc82c507,
+        ])),
+        Felt::new(BigInteger256([
+            0x74a6c79e2cf1c929,
+            0x22d815b9acd5209a,
+            0x6d8256bf5e6b586c,
+            0x1d9f51f0db4d0cd8,
+        ])),
+        Felt::new(BigInteger256([
+            0x80d03f6769fbc0b6,
+            0x9b60469f8be08062,
+            0x9f622d2281b0b050,
+            0x09b30b23b9b89060,
+        ])),
+        Felt::new(BigInteger256([
+            0x87650853d7e24978,
+            0x053282d022640536,
+            0x61fe996202cfdbb1,
+            0x05cad3e2b274ab40,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcaa96a45b9c6e984,
+            0xcfd22e75d09ade93,
+            0x3606a9e8d6e761e6,
+            0x230f89fba23cc946,
+        ])),
+        Felt::new(BigInteger256([
+            0x99334cca01c55f61,
+            0x7427d71cc606ae1b,
+            0xd17972dd83dcc7f2,
+            0x0f7f38951944bd38,
+        ])),
+        Felt::new(BigInteger256([
+            0x947e3deebbddb277,
+            0xec49c5eb0fd9394a,
+            0x475ea5747c37fd6c,
+            0x0002cca4b8853367,
+        ])),
+        Felt::new(BigInteger256([
+            0x02160e4bd459af1a,
+            0xf1b514ee68e00867,
+            0x00059557a4f5499e,
+            0x1e6b444276851861,
+        ])),
+        Felt::new(BigInteger256([
+            0xb458f59f89da7d3c,
+            0x5defdb1e00a512f4,
+            0x645b433d1f3d7318,
+            0x2f887e653d8f476a,
+        ])),
+        Felt::new(BigInteger256([
+            0x4e0de6271fb93794,
+            0x82cf63e3b9df8626,
+            0xf22b988299559a5b,
+            0x1f62c743c29b5bc1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x389eb29789a9b320,
+            0x41197a396620479d,
+            0x2a064422ddacd464,
+            0x09f7ac60efcd33d0,
+        ])),
+        Felt::new(BigInteger256([
+            0xa28d2ec8ebd2aa2f,
+            0xdb5f8b4f568a5770,
+            0xfd411ae5f5e50e7b,
+            0x09dee01baff3b4ab,
+        ])),
+        Felt::new(BigInteger256([
+            0x49d119c6a49e28a9,
+            0x0a13864ec852d901,
+            0x75993a48648ccc3e,
+            0x28db2004bc8a1212,
+        ])),
+        Felt::new(BigInteger256([
+            0x8604b213a98df5a5,
+            0x8884cf26d10de34a,
+            0x2afe2a25bea7fc8d,
+            0x24ba088ff1cac2a5,
+        ])),
+        Felt::new(BigInteger256([
+            0x50664442a584d9b8,
+            0xe79075b0c1afc59b,
+            0xe5354a0b73f41231,
+            0x22f6557c21163368,
+        ])),
+        Felt::new(BigInteger256([
+            0xfd80520dd09695e1,
+            0x50508f67e157927b,
+            0x525b3074ef48d951,
+            0x063e0877da160499,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe9d6c70c63bd434f,
+            0x994d7e1115edf420,
+            0x39158c15e612d42f,
+            0x153b64339d93cbb4,
+        ])),
+        Felt::new(BigInteger256([
+            0xa86dc0cbd5e3b907,
+            0xc5bce332fd21af22,
+            0x3da1e3697823e2cf,
+            0x0154bd7af01060b0,
+        ])),
+        Felt::new(BigInteger256([
+            0x5016cd5667bb96ae,
+            0xec43c1d4dfd4f03e,
+            0xcb6500b83b5d4aa8,
+            0x15fe7baa45bfc7b1,
+        ])),
+        Felt::new(BigInteger256([
+            0xcacbcfec4f7dc61a,
+            0xcf342fc07cec69e6,
+            0x2acbcf2c7817dc9a,
+            0x278f47ec1f932fcb,
+        ])),
+        Felt::new(BigInteger256([
+            0xb89c2c139740a795,
+            0x9190fc0ee5e8aff9,
+            0x8eddaa6acd5df504,
+            0x0674b078a6495919,
+        ])),
+        Felt::new(BigInteger256([
+            0x41baa0341221f951,
+            0x03d61feb64ea6a88,
+            0x62e27ff44b2646b2,
+            0x2806f880563b4cc0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0e3484f1441a9326,
+            0x71535b99447ab6f5,
+            0xe7a13f6a45061f41,
+            0x173f0d95d0f59bb4,
+        ])),
+        Felt::new(BigInteger256([
+            0x72d2fea3b8031b78,
+            0x43f0a115fdc01736,
+            0x5230cf22cc5aba81,
+            0x0aca9bc29ae198a0,
+        ])),
+        Felt::new(BigInteger256([
+            0x85e8de4085846dc3,
+            0x9ad48ef7a1d6807a,
+            0xc5bd450d2a0c4245,
+            0x0f9b7ec46aab270b,
+        ])),
+        Felt::new(BigInteger256([
+            0xac3dcb9004b78a97,
+            0x9633f79904aae8d8,
+            0x499075ecad12a1f9,
+            0x171f22b57af8e912,
+        ])),
+        Felt::new(BigInteger256([
+            0x811be123ab75ef18,
+            0x936e0fba6b2126f2,
+            0xdd60f214f26764be,
+            0x01b29d3aab696a23,
+        ])),
+        Felt::new(BigInteger256([
+            0xf8525617c3db69e1,
+            0xeb85cae856047429,
+            0xde7187b4f64bccb8,
+            0x0f5e8c816fd5a13a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x07fa10bae2e04fce,
+            0x9fa304eb832414d8,
+            0x1dfaca20bfcf02b5,
+            0x2989541ec0502ea0,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d531985715754e6,
+            0x3194bc8424bd534b,
+            0xec812b33f23b1542,
+            0x0248dc6f8b8c5e91,
+        ])),
+        Felt::new(BigInteger256([
+            0xeadadacf6039a6a8,
+            0xb1ce0e239a499ca4,
+            0xe7076318afeae065,
+            0x277056a59a109ce6,
+        ])),
+        Felt::new(BigInteger256([
+            0x9939856fb9459e8a,
+            0x8b19eacbc26ab771,
+            0x05e058dddb6c47cf,
+            0x25bfc58e07f7757a,
+        ])),
+        Felt::new(BigInteger256([
+            0x5622ce9d8160624b,
+            0xe5637d6e60b3094b,
+            0xc501d1f87ca3febf,
+            0x1ee3e44d8895f50a,
+        ])),
+        Felt::new(BigInteger256([
+            0xd224703b8a0a25a5,
+            0x4babe02023584fb5,
+            0x4f3436c52048ac24,
+            0x027f40eafd1aa4a5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0ea1244eb08aa1fc,
+            0x4b9c62d637727a9b,
+            0x35fdc9c76e2003c3,
+            0x21b0e7e53cc1bdbb,
+        ])),
+        Felt::new(BigInteger256([
+            0xbe306ba583887ba9,
+            0x5fe7d752bf2c88a4,
+            0xb811e8f9dd6cdc70,
+            0x053be9e7d7aaadb7,
+        ])),
+        Felt::new(BigInteger256([
+            0xc2ee094a5d878a41,
+            0x29330ee649360254,
+            0x1db3ed27a44561cf,
+            0x11c0e2345c6c5aef,
+        ])),
+        Felt::new(BigInteger256([
+            0x79cc080b73cf1e07,
+            0x9a83705f4f976eca,
+            0x698785ffb325b28f,
+            0x0a153535c5cf2eaf,
+        ])),
+        Felt::new(BigInteger256([
+            0xfbc4b6e848e62c0c,
+            0x777d626b790190f5,
+            0x7eb0f053c26eec9b,
+            0x09942713da1fd427,
+        ])),
+        Felt::new(BigInteger256([
+            0xb8a46eaec2896076,
+            0x33287c9c4fe079fe,
+            0xa9a78dc974d3d531,
+            0x0aeb4b7cd73c742d,
+        ])),
+    ],
+];
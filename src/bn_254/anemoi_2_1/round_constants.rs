@@ -1,239 +1,239 @@
 use super::BigInteger256;
 use super::Felt;
-use super::NUM_HASH_ROUNDS;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
 
 /// Additive round constants C for Anemoi.
-pub(crate) const C: [Felt; NUM_HASH_ROUNDS] = [
-    Felt::new(BigInteger256([
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
         0x8c7ac37d8dacf7b1,
         0x8379fceb85c49fef,
         0xcdfd9f1c6987245d,
         0x07834efb432341d9,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x4a352612c6a8e3f0,
         0x9dc26d51a557485b,
         0xefb81e824a630f77,
         0x1897a39081505f21,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xf7d8d3b737ea1d29,
         0xdcc2dcdfea9edeef,
         0xa9821f4b970c5434,
         0x0880c672be1b35ac,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xcfe134c7cab7cb71,
         0xb40cb4c365f49ee2,
         0x8a2d45ec04b07066,
         0x1c144fb74db40686,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xa39878d923e054dd,
         0x0bd17b23ef33a980,
         0x10b2798eae5a9df3,
         0x04a15145df894402,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x350c014927a20623,
         0x25d0f48d0f9462b5,
         0x068ec9b27486a617,
         0x014c45b801fe46bd,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xd6c4ed792a61ed79,
         0xe57202db8eaaee4c,
         0x81a11a2c7ba52d13,
         0x068b2baa0cc4a6b4,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xd41dd442ab91976b,
         0x9f90463e3cf35c61,
         0x576cfa3c91fc74bd,
         0x1b9a510a722108df,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xcb6309851cb51205,
         0x9a9622f96760fa86,
         0xb806e9d7a219287c,
         0x1bdc93ec06e2adb9,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x2af89d2b0f05d8b0,
         0x7862ad62dc2b173e,
         0xd0b8ab58c35ba407,
         0x257b41417e5b8751,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x3cf60a6eb72a48a2,
         0x44d071170c5702f6,
         0xd312ff523d341dfd,
         0x2921ffe5a570b20a,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xe03cd8769aec639e,
         0x9b53bfeb98f9a377,
         0xb5ec50ae0882b455,
         0x1a46ec10dc69efd7,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x1b0cb93ffcd0fa57,
         0xeda606a081bb11ca,
         0x0ed6983f6f02acf6,
         0x2dc6f1876d2aa54f,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xceaddc3b7be1cee7,
         0xe43908e39bbdd3a2,
         0xf55913d172f4575b,
         0x0ed7756dd9198007,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x8e7bd021db7d4cf5,
         0xa1f631a04ba72e7a,
         0x5252474f285f66a7,
         0x0a360c8a9ca9cc81,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x9ffab705842cb0b3,
         0xeaaf46ea68e5b810,
         0x2995a93312397331,
         0x302b6b1c15116384,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xc001b04ad9bcf0f0,
         0x88f090a0c5c040d8,
         0xa3e46e626212149d,
         0x2404b348ef40fc7f,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x250a85228df17752,
         0xee38957354ffdb76,
         0x1b2d1d713b550c6b,
         0x1139d693882b8d7f,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xa6aa56921e990d74,
         0xbe6b92b7e6effb24,
         0xa5f057d401d3ae9b,
         0x1b2c7dab1a25ba63,
-    ])),
+    ]))],
 ];
 
 /// Additive round constants D for Anemoi.
-pub(crate) const D: [Felt; NUM_HASH_ROUNDS] = [
-    Felt::new(BigInteger256([
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
         0x3c4f5e09c25ba5fd,
         0x6422c429a52ce733,
         0x82e7ecd3bcdaae8b,
         0x1c52e66216e116f7,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xa731b3d81fb2d234,
         0xc47aa4d60a3a7601,
         0xcf79f8b21bf685b9,
         0x0c363e1d8d409d90,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x76268a752861db39,
         0xbc9113ed82889a89,
         0x797351d166c2e70d,
         0x2d4274511143a45f,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x6fe12ea447c6f942,
         0xed1f75d65d45c1c8,
         0xb2499a8e67fb10af,
         0x1611356c5fa0dd07,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x68ff25ed9d151663,
         0xcf4380aa1ab729c5,
         0xb32cc3bf19dde0b3,
         0x133e55cc92c1622c,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xcaa96a45b9c6e984,
         0xcfd22e75d09ade93,
         0x3606a9e8d6e761e6,
         0x230f89fba23cc946,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x389eb29789a9b320,
         0x41197a396620479d,
         0x2a064422ddacd464,
         0x09f7ac60efcd33d0,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xe9d6c70c63bd434f,
         0x994d7e1115edf420,
         0x39158c15e612d42f,
         0x153b64339d93cbb4,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x0e3484f1441a9326,
         0x71535b99447ab6f5,
         0xe7a13f6a45061f41,
         0x173f0d95d0f59bb4,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x07fa10bae2e04fce,
         0x9fa304eb832414d8,
         0x1dfaca20bfcf02b5,
         0x2989541ec0502ea0,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x0ea1244eb08aa1fc,
         0x4b9c62d637727a9b,
         0x35fdc9c76e2003c3,
         0x21b0e7e53cc1bdbb,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x943397405a1ee10b,
         0xb74bcbbc8c3c4166,
         0x277215f7d2fdf4d5,
         0x275ad99f601afd2a,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xd7d3ec0737a469c1,
         0xe13ca4a753ddd234,
         0x957079fc84258ff1,
         0x12cd418d9a11e8f8,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x0842372ec8d164f9,
         0x665f31fd7b31f304,
         0x0afc81e3aeabc214,
         0x0dcd3c6f43583144,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x26ac259bdefa1f04,
         0xdb4c8f5cbc4af0c4,
         0xce545aef27c2b678,
         0x0c5461dd846f144f,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xb32e0cd1985960ee,
         0x7c464117248aeeeb,
         0xdd2d840d225812b8,
         0x0fdd68808df5a9d4,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x0fb5990b6444793d,
         0x7f8d451f3d759ea2,
         0xb1800015113d737c,
         0x0fa4b3e66c96740c,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x33916c56a8b394ac,
         0x092f8207aedb1ec6,
         0x8ed2d5f99204c8ed,
         0x1b1d389342758cba,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xed7b6781c977e4b8,
         0x2c82fc6ef5116260,
         0x48541aa2e565c38e,
         0x1cbfe76d535bd229,
-    ])),
+    ]))],
 ];
@@ -0,0 +1,739 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x8c7ac37d8dacf7b1,
+            0x8379fceb85c49fef,
+            0xcdfd9f1c6987245d,
+            0x07834efb432341d9,
+        ])),
+        Felt::new(BigInteger256([
+            0xbcb4a9832e0452f6,
+            0x17f32b51a1825b47,
+            0xbc7cfa76f63d4d10,
+            0x2080396d88a28da5,
+        ])),
+        Felt::new(BigInteger256([
+            0x58383a2b3e062e78,
+            0x139087bf6787bba0,
+            0x746f4ef9c0075f89,
+            0x0c1e6f9df6e100ec,
+        ])),
+        Felt::new(BigInteger256([
+            0x33a3729bd891f831,
+            0x80c5b3dd8b12d61b,
+            0xc7202b557666d3ef,
+            0x0c6d759d8f130b61,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4a352612c6a8e3f0,
+            0x9dc26d51a557485b,
+            0xefb81e824a630f77,
+            0x1897a39081505f21,
+        ])),
+        Felt::new(BigInteger256([
+            0xaf8f311d307dde18,
+            0x2dc337dea19c5d6d,
+            0x8bca456f059100d0,
+            0x2e056bfa2066b103,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d3ccb501a400bfe,
+            0xab9ae8a14e3a9ca5,
+            0x9f6525bedf2f1f8c,
+            0x170918aab2d1b637,
+        ])),
+        Felt::new(BigInteger256([
+            0xd9315b446cf36e00,
+            0x3e13c399720beefc,
+            0x9166a9413d879c4d,
+            0x0e78cb0c63d9b369,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf7d8d3b737ea1d29,
+            0xdcc2dcdfea9edeef,
+            0xa9821f4b970c5434,
+            0x0880c672be1b35ac,
+        ])),
+        Felt::new(BigInteger256([
+            0x9b033bfba8347d8e,
+            0x4989cfaefada94a3,
+            0x5644e18871b8aa8f,
+            0x0760d0b4ec5e688d,
+        ])),
+        Felt::new(BigInteger256([
+            0xaf8d70e258694b4b,
+            0xc694d35cfcce72d5,
+            0x21d62af141d3012a,
+            0x0db8ee2033a275c2,
+        ])),
+        Felt::new(BigInteger256([
+            0xe2378a9d81e9244a,
+            0xcf6ad3ffbac55253,
+            0x79f90a021a181593,
+            0x23bf3ac3da612824,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcfe134c7cab7cb71,
+            0xb40cb4c365f49ee2,
+            0x8a2d45ec04b07066,
+            0x1c144fb74db40686,
+        ])),
+        Felt::new(BigInteger256([
+            0xfd2c413f8aadf0dc,
+            0x25a9b563b789d1ee,
+            0x8443ae3ef1cd82d6,
+            0x1812e17b35225e1f,
+        ])),
+        Felt::new(BigInteger256([
+            0xdd5e3343bc880e9f,
+            0xf6ed2f6515c39c41,
+            0xb7d0e88824d9c7d8,
+            0x20abc3aadb358361,
+        ])),
+        Felt::new(BigInteger256([
+            0x950a517cd9b458ec,
+            0xe2c5ef668f0e7a5c,
+            0xff1b684777b73f91,
+            0x0f542748bdcd00fa,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa39878d923e054dd,
+            0x0bd17b23ef33a980,
+            0x10b2798eae5a9df3,
+            0x04a15145df894402,
+        ])),
+        Felt::new(BigInteger256([
+            0x46d8fca9d3a398a0,
+            0x6f8eda409a49ca67,
+            0x0d37d68dad44e002,
+            0x223844d31901ddbc,
+        ])),
+        Felt::new(BigInteger256([
+            0x7257ccaf4f0b6412,
+            0xf469403345675112,
+            0x860c0eee10d3042f,
+            0x2a47ee26a14411a9,
+        ])),
+        Felt::new(BigInteger256([
+            0x2b162e879353afc0,
+            0x45b85adb60078f6d,
+            0x9937628974cba3a4,
+            0x04b4db65f505efde,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x350c014927a20623,
+            0x25d0f48d0f9462b5,
+            0x068ec9b27486a617,
+            0x014c45b801fe46bd,
+        ])),
+        Felt::new(BigInteger256([
+            0xd659fbb7ab7f255a,
+            0x21e0a21f7edb5323,
+            0xc5ee450c5743164b,
+            0x0953c3786439b0b8,
+        ])),
+        Felt::new(BigInteger256([
+            0xe3b1cf89618a78bc,
+            0xbe83467c65286167,
+            0xd7271e0ba475182e,
+            0x09fa0b6770401baa,
+        ])),
+        Felt::new(BigInteger256([
+            0x246f4563fa48711d,
+            0xc587903eef01462d,
+            0x570d50d245f97425,
+            0x22bedc6d84693732,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd6c4ed792a61ed79,
+            0xe57202db8eaaee4c,
+            0x81a11a2c7ba52d13,
+            0x068b2baa0cc4a6b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x13778194c8698de2,
+            0xd77218dcf8f01f07,
+            0x78c8a354c9a47153,
+            0x220a2e8bb81e9d99,
+        ])),
+        Felt::new(BigInteger256([
+            0x90a7c328a4ab0f61,
+            0x93255ed99ec1591f,
+            0xd42423690ba1a321,
+            0x20c4d3e150494f99,
+        ])),
+        Felt::new(BigInteger256([
+            0xa00100f329dcd81b,
+            0x132fa270d84e7911,
+            0x50c85629de83e336,
+            0x170015d4dbb336ba,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd41dd442ab91976b,
+            0x9f90463e3cf35c61,
+            0x576cfa3c91fc74bd,
+            0x1b9a510a722108df,
+        ])),
+        Felt::new(BigInteger256([
+            0x6578e5ec5996b67d,
+            0x23b9b04b9e02384a,
+            0x7fe603f559d48d86,
+            0x234b7978afd113e4,
+        ])),
+        Felt::new(BigInteger256([
+            0xe30e490d0ee49729,
+            0xd73fd9eab4be31ed,
+            0xf0ac81f5f063696a,
+            0x17b39d149114cf7f,
+        ])),
+        Felt::new(BigInteger256([
+            0x30e8f12076e8c253,
+            0xbbc9429582a7c13f,
+            0x1752934da5e50b21,
+            0x23a0c2bec1116e27,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcb6309851cb51205,
+            0x9a9622f96760fa86,
+            0xb806e9d7a219287c,
+            0x1bdc93ec06e2adb9,
+        ])),
+        Felt::new(BigInteger256([
+            0x02c59b21cc7c43b1,
+            0xc4ed6d619a817baf,
+            0x46832bf55f34cde4,
+            0x2afff13fbc0220ae,
+        ])),
+        Felt::new(BigInteger256([
+            0xebc7d154bd739901,
+            0xa8d0a64072a09d7a,
+            0x9d130291903bc9b4,
+            0x0f8f39ae176003b3,
+        ])),
+        Felt::new(BigInteger256([
+            0xe5426421bce8b193,
+            0xa5c909a106471b81,
+            0xe82576548c09392d,
+            0x116f37077dd6fc47,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2af89d2b0f05d8b0,
+            0x7862ad62dc2b173e,
+            0xd0b8ab58c35ba407,
+            0x257b41417e5b8751,
+        ])),
+        Felt::new(BigInteger256([
+            0x5315bddfd95b8722,
+            0x620e69e6f79f7698,
+            0xc32bbed12b8ec0bc,
+            0x19d298b934cb2d4c,
+        ])),
+        Felt::new(BigInteger256([
+            0xb689d5bfebb3dbe9,
+            0x6f470683a1347879,
+            0xa0b55767bc93ffeb,
+            0x1eb8785bcee3c03b,
+        ])),
+        Felt::new(BigInteger256([
+            0x380e25ddc501cf89,
+            0x4a2bddeafa27a8ef,
+            0x86cd901060dc771a,
+            0x176440ac92f3cf5c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3cf60a6eb72a48a2,
+            0x44d071170c5702f6,
+            0xd312ff523d341dfd,
+            0x2921ffe5a570b20a,
+        ])),
+        Felt::new(BigInteger256([
+            0xbf4969afc606cba9,
+            0xb0d5ea7f0dec31e6,
+            0x7913d0e9e24800d2,
+            0x2844d10f2b8d1810,
+        ])),
+        Felt::new(BigInteger256([
+            0x99f35deac37bdd46,
+            0x07206d0fcbfe641e,
+            0xc1b935c97c75fa3d,
+            0x14882ec83be319e1,
+        ])),
+        Felt::new(BigInteger256([
+            0x23f702295a056cca,
+            0x7a09c9480331e63d,
+            0xd4cc1185041d5ac2,
+            0x0738db31fb6f242f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe03cd8769aec639e,
+            0x9b53bfeb98f9a377,
+            0xb5ec50ae0882b455,
+            0x1a46ec10dc69efd7,
+        ])),
+        Felt::new(BigInteger256([
+            0xa16a9dc2052bef71,
+            0xd92eea4032f48be5,
+            0x8c34c08694742369,
+            0x24f5ea1428f18c65,
+        ])),
+        Felt::new(BigInteger256([
+            0x3095557dd4a1f444,
+            0x649e0b3c18182633,
+            0xa2571a06c43de875,
+            0x1c16b0957c61e711,
+        ])),
+        Felt::new(BigInteger256([
+            0x08d21ad205fbb046,
+            0xdc844b06a0b620c7,
+            0xa1dbd5d0dfaee509,
+            0x161add54dd6eb9f1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1b0cb93ffcd0fa57,
+            0xeda606a081bb11ca,
+            0x0ed6983f6f02acf6,
+            0x2dc6f1876d2aa54f,
+        ])),
+        Felt::new(BigInteger256([
+            0x5a8a294fe2490670,
+            0x99d2f627f25dbf83,
+            0x30648673988c2148,
+            0x17d7dae00a574de6,
+        ])),
+        Felt::new(BigInteger256([
+            0xeab0ddafd1288318,
+            0x4aa89f9a645e625d,
+            0x33d605d70d3f984a,
+            0x06d04f5fbd263f5e,
+        ])),
+        Felt::new(BigInteger256([
+            0xe327aa203645e801,
+            0x206b9693a6bd060c,
+            0x38cfa4f707cd3ffe,
+            0x1ef9ae16be4da284,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xceaddc3b7be1cee7,
+            0xe43908e39bbdd3a2,
+            0xf55913d172f4575b,
+            0x0ed7756dd9198007,
+        ])),
+        Felt::new(BigInteger256([
+            0x9c4752bcc1c69c23,
+            0xd93036128ba77f89,
+            0xbdbbc5338ad2c161,
+            0x10f051dbc62e79e6,
+        ])),
+        Felt::new(BigInteger256([
+            0x01f22d84019473a1,
+            0x8b61bd22cd8368b0,
+            0xecfe87e1b0759856,
+            0x29db436dd04cdd83,
+        ])),
+        Felt::new(BigInteger256([
+            0x0301fc13edea9ff7,
+            0x7c4f67f2ee85c45c,
+            0xf5d6c386f2940407,
+            0x0184063f71a0f6bc,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x3c4f5e09c25ba5fd,
+            0x6422c429a52ce733,
+            0x82e7ecd3bcdaae8b,
+            0x1c52e66216e116f7,
+        ])),
+        Felt::new(BigInteger256([
+            0x99c52c2526d457e8,
+            0xa0e1eda4470f81a4,
+            0x4d7a95c913c9cd15,
+            0x19b801ad712cecba,
+        ])),
+        Felt::new(BigInteger256([
+            0x5f5c663713603065,
+            0x0f7fff14d90c2975,
+            0x2269899a0a3e6b83,
+            0x2597d27153d70b67,
+        ])),
+        Felt::new(BigInteger256([
+            0x67a1f92a2da9fe60,
+            0x7b1c3073cbc52e47,
+            0xaddb231247d6d024,
+            0x2b8a7f0895dfdf4e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa731b3d81fb2d234,
+            0xc47aa4d60a3a7601,
+            0xcf79f8b21bf685b9,
+            0x0c363e1d8d409d90,
+        ])),
+        Felt::new(BigInteger256([
+            0x39c7a6f84da92302,
+            0xfcc16a778ca46a2d,
+            0x479f6d39a15d6ce9,
+            0x060c376041237969,
+        ])),
+        Felt::new(BigInteger256([
+            0x1188ea9513f54de3,
+            0xed99d03d0539f0dd,
+            0x7836ecd7a7a6179a,
+            0x0f517ea447fa2a03,
+        ])),
+        Felt::new(BigInteger256([
+            0xba57d50be666b427,
+            0x7e79b075f8392d8b,
+            0xa2f92d768d378496,
+            0x0c64d79da2d8f0a7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x76268a752861db39,
+            0xbc9113ed82889a89,
+            0x797351d166c2e70d,
+            0x2d4274511143a45f,
+        ])),
+        Felt::new(BigInteger256([
+            0x468cdacf5ccd9244,
+            0xd19e01d118e92f56,
+            0x024961a90ba8333f,
+            0x108aaf6c54536137,
+        ])),
+        Felt::new(BigInteger256([
+            0x490a2d09110f5fb5,
+            0x2a284ff07e628a73,
+            0x328704a986ebbd72,
+            0x06c018f82ed179a8,
+        ])),
+        Felt::new(BigInteger256([
+            0xa88ea146ba4d3cf6,
+            0x316555d40b875448,
+            0xc36aa0d6e669c216,
+            0x226a0c337f66f57c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6fe12ea447c6f942,
+            0xed1f75d65d45c1c8,
+            0xb2499a8e67fb10af,
+            0x1611356c5fa0dd07,
+        ])),
+        Felt::new(BigInteger256([
+            0x0688af48a45b729a,
+            0x9e83dc1c9d719e7b,
+            0x40c39632a0d27154,
+            0x26dc467c3d0d5ec1,
+        ])),
+        Felt::new(BigInteger256([
+            0xd4adbe9fda429011,
+            0x4b46a08f5f30e5b8,
+            0xd8fd2a137f07e9ee,
+            0x1f5274cc765a8f3f,
+        ])),
+        Felt::new(BigInteger256([
+            0xb934375b772cdea0,
+            0x358665d1a7a9ae2a,
+            0x590866ef591e51e2,
+            0x139e7f0202c8d64b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x68ff25ed9d151663,
+            0xcf4380aa1ab729c5,
+            0xb32cc3bf19dde0b3,
+            0x133e55cc92c1622c,
+        ])),
+        Felt::new(BigInteger256([
+            0x397b91d410f9b0cc,
+            0xdb46dadb4bf229c5,
+            0x8bc56e58e301189a,
+            0x153d7a32e10685dd,
+        ])),
+        Felt::new(BigInteger256([
+            0x52ed7f2c906e7bf2,
+            0x3ba08b3f5a952d5b,
+            0x69460050f1b8705f,
+            0x0d2a6fa6fc82c507,
+        ])),
+        Felt::new(BigInteger256([
+            0x74a6c79e2cf1c929,
+            0x22d815b9acd5209a,
+            0x6d8256bf5e6b586c,
+            0x1d9f51f0db4d0cd8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcaa96a45b9c6e984,
+            0xcfd22e75d09ade93,
+            0x3606a9e8d6e761e6,
+            0x230f89fba23cc946,
+        ])),
+        Felt::new(BigInteger256([
+            0x99334cca01c55f61,
+            0x7427d71cc606ae1b,
+            0xd17972dd83dcc7f2,
+            0x0f7f38951944bd38,
+        ])),
+        Felt::new(BigInteger256([
+            0x947e3deebbddb277,
+            0xec49c5eb0fd9394a,
+            0x475ea5747c37fd6c,
+            0x0002cca4b8853367,
+        ])),
+        Felt::new(BigInteger256([
+            0x02160e4bd459af1a,
+            0xf1b514ee68e00867,
+            0x00059557a4f5499e,
+            0x1e6b444276851861,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x389eb29789a9b320,
+            0x41197a396620479d,
+            0x2a064422ddacd464,
+            0x09f7ac60efcd33d0,
+        ])),
+        Felt::new(BigInteger256([
+            0xa28d2ec8ebd2aa2f,
+            0xdb5f8b4f568a5770,
+            0xfd411ae5f5e50e7b,
+            0x09dee01baff3b4ab,
+        ])),
+        Felt::new(BigInteger256([
+            0x49d119c6a49e28a9,
+            0x0a13864ec852d901,
+            0x75993a48648ccc3e,
+            0x28db2004bc8a1212,
+        ])),
+        Felt::new(BigInteger256([
+            0x8604b213a98df5a5,
+            0x8884cf26d10de34a,
+            0x2afe2a25bea7fc8d,
+            0x24ba088ff1cac2a5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe9d6c70c63bd434f,
+            0x994d7e1115edf420,
+            0x39158c15e612d42f,
+            0x153b64339d93cbb4,
+        ])),
+        Felt::new(BigInteger256([
+            0xa86dc0cbd5e3b907,
+            0xc5bce332fd21af22,
+            0x3da1e3697823e2cf,
+            0x0154bd7af01060b0,
+        ])),
+        Felt::new(BigInteger256([
+            0x5016cd5667bb96ae,
+            0xec43c1d4dfd4f03e,
+            0xcb6500b83b5d4aa8,
+            0x15fe7baa45bfc7b1,
+        ])),
+        Felt::new(BigInteger256([
+            0xcacbcfec4f7dc61a,
+            0xcf342fc07cec69e6,
+            0x2acbcf2c7817dc9a,
+            0x278f47ec1f932fcb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0e3484f1441a9326,
+            0x71535b99447ab6f5,
+            0xe7a13f6a45061f41,
+            0x173f0d95d0f59bb4,
+        ])),
+        Felt::new(BigInteger256([
+            0x72d2fea3b8031b78,
+            0x43f0a115fdc01736,
+            0x5230cf22cc5aba81,
+            0x0aca9bc29ae198a0,
+        ])),
+        Felt::new(BigInteger256([
+            0x85e8de4085846dc3,
+            0x9ad48ef7a1d6807a,
+            0xc5bd450d2a0c4245,
+            0x0f9b7ec46aab270b,
+        ])),
+        Felt::new(BigInteger256([
+            0xac3dcb9004b78a97,
+            0x9633f79904aae8d8,
+            0x499075ecad12a1f9,
+            0x171f22b57af8e912,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x07fa10bae2e04fce,
+            0x9fa304eb832414d8,
+            0x1dfaca20bfcf02b5,
+            0x2989541ec0502ea0,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d531985715754e6,
+            0x3194bc8424bd534b,
+            0xec812b33f23b1542,
+            0x0248dc6f8b8c5e91,
+        ])),
+        Felt::new(BigInteger256([
+            0xeadadacf6039a6a8,
+            0xb1ce0e239a499ca4,
+            0xe7076318afeae065,
+            0x277056a59a109ce6,
+        ])),
+        Felt::new(BigInteger256([
+            0x9939856fb9459e8a,
+            0x8b19eacbc26ab771,
+            0x05e058dddb6c47cf,
+            0x25bfc58e07f7757a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0ea1244eb08aa1fc,
+            0x4b9c62d637727a9b,
+            0x35fdc9c76e2003c3,
+            0x21b0e7e53cc1bdbb,
+        ])),
+        Felt::new(BigInteger256([
+            0xbe306ba583887ba9,
+            0x5fe7d752bf2c88a4,
+            0xb811e8f9dd6cdc70,
+            0x053be9e7d7aaadb7,
+        ])),
+        Felt::new(BigInteger256([
+            0xc2ee094a5d878a41,
+            0x29330ee649360254,
+            0x1db3ed27a44561cf,
+            0x11c0e2345c6c5aef,
+        ])),
+        Felt::new(BigInteger256([
+            0x79cc080b73cf1e07,
+            0x9a83705f4f976eca,
+            0x698785ffb325b28f,
+            0x0a153535c5cf2eaf,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x943397405a1ee10b,
+            0xb74bcbbc8c3c4166,
+            0x277215f7d2fdf4d5,
+            0x275ad99f601afd2a,
+        ])),
+        Felt::new(BigInteger256([
+            0x829d44a1887fc384,
+            0x9d6cf125ac5c08ed,
+            0xd9cdd36b292859c1,
+            0x1672087bc16f23ae,
+        ])),
+        Felt::new(BigInteger256([
+            0x3bdba5c7347fc552,
+            0x9bdcc7245d76eab3,
+            0x0ceccc39859caac1,
+            0x2dd46990894b29c1,
+        ])),
+        Felt::new(BigInteger256([
+            0x40f2c59de5978596,
+            0x122a0c2fb542cf9e,
+            0x4532452028469791,
+            0x2d7c3ce7942ec613,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd7d3ec0737a469c1,
+            0xe13ca4a753ddd234,
+            0x957079fc84258ff1,
+            0x12cd418d9a11e8f8,
+        ])),
+        Felt::new(BigInteger256([
+            0x80add043b9bac9c7,
+            0xcd30f9d4b3172994,
+            0x4b61fb81f9695278,
+            0x11aaaa322d3cbbb0,
+        ])),
+        Felt::new(BigInteger256([
+            0x3ae82e0d8524436a,
+            0xf1075849f10f13e7,
+            0x6bd01a339ac7556e,
+            0x20e4b9455477588e,
+        ])),
+        Felt::new(BigInteger256([
+            0x2418c8e99182af4e,
+            0x2dafe9f29a29d760,
+            0xf13a30b99b0c9500,
+            0x0e4d70211e43e4fc,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0842372ec8d164f9,
+            0x665f31fd7b31f304,
+            0x0afc81e3aeabc214,
+            0x0dcd3c6f43583144,
+        ])),
+        Felt::new(BigInteger256([
+            0x3f3821dcab548622,
+            0x9b1dc4d259b24891,
+            0x67c2c69712447a4f,
+            0x24b29829266b5543,
+        ])),
+        Felt::new(BigInteger256([
+            0x92d619f6ef2f5d54,
+            0x28ce9653ff13aea2,
+            0xfbb1e2dce31084db,
+            0x2d7ad5dbc3c3c41c,
+        ])),
+        Felt::new(BigInteger256([
+            0xc0c043095b438dec,
+            0x18234664ef43f4a5,
+            0x3d4adb9eac67e0c7,
+            0x0ac73f450eeea6c8,
+        ])),
+    ],
+];
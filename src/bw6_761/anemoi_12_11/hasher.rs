@@ -0,0 +1,4038 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger768, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger768([
+            0x4126a66f7fdc65a0,
+            0x8b2bdf5d7951649a,
+            0x2ab2e728ea47dea2,
+            0xa875235c3468d760,
+            0x0fb3d307320de265,
+            0x51c233e02aeef9a6,
+            0xe0805ceaf46183ab,
+            0xd417c4124f915529,
+            0x929398824e8f08a4,
+            0x240e43f449c384ca,
+            0xf723e44e6e081dbe,
+            0x0003996c38e8bc43,
+        ]))],
+        vec![
+            Felt::new(BigInteger768([
+                0x8376a37902b1b7f9,
+                0x09fc524d9af5e328,
+                0x7ff1df315efbc4e1,
+                0xea360acad54fe5db,
+                0xe627acafc1930be3,
+                0x8601fc22b997d3c9,
+                0x72c6bb55d5cc63f7,
+                0xb201cf6de947286a,
+                0x7d14cc5947f65f4e,
+                0xc466650a6722e388,
+                0xd1d6430bef0dcc41,
+                0x0069550ffdaeb988,
+            ])),
+            Felt::new(BigInteger768([
+                0x194828adc0863890,
+                0x676c450f1fa593ac,
+                0xaa1a74b52f747181,
+                0x32ec202f1254c818,
+                0xe96250ca81a2a5b0,
+                0xb1384bb92e8edfc9,
+                0xc2ca8f04630af425,
+                0x111cc56c2a499ad1,
+                0x50e11566c09346cf,
+                0xf3b5e10ca91e580d,
+                0xab12b2c88bbe33d7,
+                0x00ae19d139d140c9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xa038b8cd546dd80e,
+                0xbdab83a6c569dafe,
+                0x0539e50378c83026,
+                0xcc7857ee4f40b1f8,
+                0x7b0a8a7eb47b54f2,
+                0x022855b679a8703f,
+                0x0d3219c075055540,
+                0xcfe144ea7f458664,
+                0xec200ea4cad6dcbc,
+                0xcbcf159e46b2dbac,
+                0x2c7321d63ce0c7ac,
+                0x00cdb5e930fc3a10,
+            ])),
+            Felt::new(BigInteger768([
+                0x0671c2fcc9d4314c,
+                0x91009410d90e5333,
+                0xfcc64a5f29d6c50c,
+                0x3f9817a036200299,
+                0x4529efaf7c144f78,
+                0x9baff7a5cada020c,
+                0x803ceb3f6c0792d2,
+                0x23f5b459dc604ae8,
+                0x45c3f2d6bde09005,
+                0x56ed7d664291b256,
+                0x2bf1400cd64b354b,
+                0x00e3414a9e505339,
+            ])),
+            Felt::new(BigInteger768([
+                0xd0cbebf9fad4c4e6,
+                0xa2c0718382ef0b63,
+                0x6e42b4c3dd3394cb,
+                0xe453e6628ee186ed,
+                0x769f9ec356be2f7e,
+                0x4a5fad729de87367,
+                0x19a9efbc85d39caf,
+                0x1279b9de8c544be1,
+                0x5c29cda645ef64fc,
+                0x28033b46db1d497e,
+                0xdc27957323252324,
+                0x00db8bfb99e935d6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xe49d1c8ee2a728c9,
+                0x80448100c23b189b,
+                0xf48e66ac04fc0fe0,
+                0x9d534e8ed7673c4f,
+                0xba3a5aacbae1e34a,
+                0x755b0033deea92b9,
+                0x306cd863ec3bc46e,
+                0xb6fd66dddce9b3d1,
+                0x65d3a649955a820f,
+                0xf528d44ea9f3a222,
+                0xfe355dd45717b6c6,
+                0x0020dec5f490b8a0,
+            ])),
+            Felt::new(BigInteger768([
+                0x1bc672d30f5a65fe,
+                0x5208b8e4fb5d9552,
+                0x3a25e23ac22ebc25,
+                0x8763091cd105fb4a,
+                0x5a060d518369646d,
+                0xe5a77b1b3d178c66,
+                0x48c40f710d13b950,
+                0x734bab5dcc4307c8,
+                0x8263833a1d5b952d,
+                0x2bc0c9103da9a434,
+                0x3a6ec0a43c5d8a55,
+                0x007a5904e7cd8b15,
+            ])),
+            Felt::new(BigInteger768([
+                0x35c20cf18f7076e6,
+                0xb251c19b8585186a,
+                0x964d50651a85dea5,
+                0x75fc5acc7dc9961c,
+                0x494fcd431f95a658,
+                0xb8a1d9a244a61ff9,
+                0x978fc4d7b2fa01a2,
+                0x72b468350cf6a28c,
+                0xeafacf37f5478875,
+                0x5660738ac3b861c1,
+                0xe0c78d7b5000b71e,
+                0x00cfec42307f00c6,
+            ])),
+            Felt::new(BigInteger768([
+                0xb0339d9e9f04f60b,
+                0x03b4bc54cb0e3ff6,
+                0xfe9c1d2a6342d58d,
+                0x924ae34a30cdba33,
+                0xc02ba377e0a15ee1,
+                0xb41af60df43428f2,
+                0xa48420a43e322101,
+                0xc3e2ade0c4977d18,
+                0xc4ec2fc1610e0e5c,
+                0x78fbd6826eb848c2,
+                0x7a032c76b83c53c4,
+                0x0015c93318faea41,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x435c4adf70530360,
+                0xcd80acffb47b6555,
+                0x9bc3a51bdd70c14b,
+                0x18dfba41377aada0,
+                0x2463e9b9443c1170,
+                0xb80422390cfa796a,
+                0xaeed4795c57cf18d,
+                0x799515f1a9d34b3a,
+                0x4bbd924ab37e919e,
+                0xcd85c29ca995b291,
+                0x06c7b810195051c4,
+                0x00a3503f06ee7bee,
+            ])),
+            Felt::new(BigInteger768([
+                0x06d0b7195237f08a,
+                0x09bbfe194e498318,
+                0x647ebabbbaa2d46d,
+                0xc4b45a05b8496d43,
+                0xd65382e8e68d3cb8,
+                0xd915e0aa1eac112f,
+                0x5aa89bf9f6be1f94,
+                0xde4d96589767d8a0,
+                0xcae0718601e2c1a0,
+                0xc122f0938fdfcbb6,
+                0xa3844ad34fa14c77,
+                0x00ab5c90ae267abb,
+            ])),
+            Felt::new(BigInteger768([
+                0x760e5570bf4bf89e,
+                0x700b2bc7315d31f3,
+                0x485474d4019e573b,
+                0x2f6f5733576f3cd1,
+                0xe49d17dd15f0fbd1,
+                0x1ff6a4a3653c913f,
+                0x8ba50f52748c6dbf,
+                0xc575575b70eac2ed,
+                0xbc2abaae1a828495,
+                0x09b455d01f2e1407,
+                0xb32e81c0afaa7a83,
+                0x00c59649c69c7913,
+            ])),
+            Felt::new(BigInteger768([
+                0x7f47865d26ca83b7,
+                0x02b155bcc3b39853,
+                0x131742c4fe92384d,
+                0x3c97ddc8d276c2cd,
+                0x0d27abd9814feb68,
+                0x5163b340a5e3034e,
+                0xaab070745bdbb0a5,
+                0x88ef8e8c96752dce,
+                0x3a049812bf9bb3a8,
+                0x86e75ff2f59ed832,
+                0x36951cc5a84a9dab,
+                0x0068ffc349dee68b,
+            ])),
+            Felt::new(BigInteger768([
+                0x64edd2e125842379,
+                0x184d6d0c7ddc3070,
+                0xe67fbae975f3d608,
+                0x58e3dcf10b7aefe3,
+                0xad9052739182a642,
+                0x8369627c1c16e43e,
+                0x9d594bf9c44c9a25,
+                0xc8692938823ea500,
+                0xc57c14a40ca50fe7,
+                0x2cfccb3b6aef47c2,
+                0xcc977f32f3d89d13,
+                0x00469b7d6f96ffc6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x6319d4cd161a227a,
+                0xe54c89529fe19a70,
+                0x5143b9064c491783,
+                0xb6dc54e64e07fb2c,
+                0xe4c4c5e7850526da,
+                0xed2be25eb1d5c6c1,
+                0xd2b87dc0ae7794d6,
+                0x24ace49aef46725b,
+                0xedee8088a06600e3,
+                0xc49a852b15c703e6,
+                0xbee04228aaef80d6,
+                0x00091d2a7e0df913,
+            ])),
+            Felt::new(BigInteger768([
+                0x8cf2413741483f38,
+                0xb2bb13121c235ba9,
+                0xc34cd705c9e4766d,
+                0xda8fbb6228966cc6,
+                0x05605d28060bab8e,
+                0xc21cfdbac4ed5e95,
+                0xfe758ebb66b78676,
+                0x9dafd6f071e6a259,
+                0x0f49888310e2a442,
+                0x4c0dbfe0c978931f,
+                0xe721c44ae1e029ec,
+                0x008ee8fd6379d05a,
+            ])),
+            Felt::new(BigInteger768([
+                0xee982eeea2d062b3,
+                0x89fb853244525325,
+                0x86f18dd98496e37d,
+                0x327940fabb9cba02,
+                0xac9507c56dacf489,
+                0x0d813ad2d1fafe1d,
+                0x71e81b389e9bc776,
+                0x79c616519ad15ff9,
+                0xd030355cdf6ea5a3,
+                0x7ea539980c941cfc,
+                0xaef459879a24e156,
+                0x00739ad2dff0df6b,
+            ])),
+            Felt::new(BigInteger768([
+                0xbc715339e1caac50,
+                0xf8c5ec0ee7fd6de1,
+                0x7b87cd56fcc0212d,
+                0x5a976de0cb4f1052,
+                0x8a97f06dbba3b437,
+                0xee97d55e18233947,
+                0xa69676f2c23704a5,
+                0x9ff38ed9fbef586c,
+                0x7752038d041f282c,
+                0xd2f8923518afbc60,
+                0xa50c6dc1de93e376,
+                0x0064f91c1647a952,
+            ])),
+            Felt::new(BigInteger768([
+                0x0884080ff49106ed,
+                0xbe3fb179e97e8409,
+                0x26bd7685cbc858c2,
+                0xaca2f62205965c66,
+                0xd39a3c90241e92a9,
+                0xdc45875ffe4221f2,
+                0xc4e9116dcc93ac14,
+                0x4d2ed3a37bc05edd,
+                0x55b813b8089aec52,
+                0xfdc8f89e57a34722,
+                0x17b26670c965eb57,
+                0x00d6bfdd71d1ad19,
+            ])),
+            Felt::new(BigInteger768([
+                0x1b2a8d9f7a3ba767,
+                0xa666d72ddc509a0d,
+                0xaa788fba24c67aca,
+                0xe1c627ed9784ad1d,
+                0xa7a09c971629c071,
+                0x0634ece7d764502f,
+                0x74b5577017c6c0dc,
+                0x09d2cdbc24161b23,
+                0x0c20eb456434d68e,
+                0x9422a04145c03509,
+                0x0b176ede4d2aefc9,
+                0x00b08806b6dcbf5c,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger768([
+            0x2a0e6f495badeaa6,
+            0xa6f512dbceddc349,
+            0x6e8f2caa5d6df6e8,
+            0x50d0836d5fd3ca01,
+            0xe8e8d9032081c3af,
+            0xb28bb2a5b043b6d4,
+            0x15738392028efcbe,
+            0x140a23d7fc7e3174,
+            0x3097c2cddab879ae,
+            0xa39c4bbc6b0d9df4,
+            0x95f94f990579dd78,
+            0x00e2e15544b55fef,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x033b96b578f1a995,
+            0x6c52689e70322e92,
+            0xd338bda64339cd58,
+            0x5bcae222ff9871b7,
+            0xf9c14d6169e3a0a4,
+            0x19e306189ed780f9,
+            0xbad368fce4e1ab5d,
+            0xa5bb8cb8717f7634,
+            0xee979eb9e2a43833,
+            0xb2c20af6f3eb338b,
+            0x28b266a57e2f858d,
+            0x00830db56176472e,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x92903efb04555d17,
+            0xf139c5e7a1d01e0a,
+            0x2c08251928e01196,
+            0xd553295d7e035a54,
+            0x2a3ea8697fd1577d,
+            0x5bb6514b1c88a045,
+            0xc0020012dd7acb27,
+            0xb0ebe87e20f84f61,
+            0xac95b5730aad98a2,
+            0xb312c7e6b6efcf11,
+            0x5279350da219185a,
+            0x0011e02b75e5bc88,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xfe6ae60fe8885933,
+            0x8956f69f32f795a3,
+            0xb7ff10445fccad5d,
+            0xdc60fbf1cdf4f063,
+            0x4f1d3dac42bc1a8c,
+            0x70f9b8e5b2f39953,
+            0x5862bac8e32382fe,
+            0xfbbafd4623fd0b04,
+            0x9ba28faf8f2cad7f,
+            0x1c58614d0a612560,
+            0xc40e6e23ac8f8659,
+            0x001922cd22b483b2,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x0b0fc44bea4eae64,
+            0x09864ad86fe7ce33,
+            0xc1be99deba20e85d,
+            0x76ccd75fcce121ea,
+            0x925d2b0d27af7014,
+            0xbc9d56a4c4d4a6ba,
+            0x211215de35827615,
+            0x13acc48241866c81,
+            0x0baba975eb19b273,
+            0x95f82eaa23e47faf,
+            0x9158cbb4b16b3fda,
+            0x0108463848d4da0e,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x034d63b156a8b27a,
+            0xc7a6f3baebe6504e,
+            0x08182a33ae77be28,
+            0x5c91eae788d4c4c1,
+            0xd4ad80ee97dd4a33,
+            0xec1eed65220d6f0b,
+            0x11f998172f8e6186,
+            0x1df67d7df7ece5e3,
+            0x8b59bfd281db7092,
+            0xd4cad06faf8e651d,
+            0x2f76b1be437bb4ee,
+            0x00aa36917abfd850,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xcec960cdce55855c,
+            0x70848129cbdb7f8b,
+            0x3873b935f5f380fc,
+            0x8433aa394597dc18,
+            0x536a157ec6eaf5e1,
+            0xa70c2d70b28edc99,
+            0xa3e42f2d0fbb0217,
+            0x81525feab3f22808,
+            0xffd5da61c3ef2edb,
+            0x834d56ec8989bf46,
+            0x66105ccbc65632b1,
+            0x00172f7d62ec4301,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xf81a008f41d1caf4,
+            0xe95f62aa0868ed93,
+            0x252114638f754ba2,
+            0x0e09d8fd50245f5a,
+            0x27c6e961b3135335,
+            0x950005aacd99d54f,
+            0xc9799c25fe0b4400,
+            0x272aee585b98420b,
+            0x4e40e124640880a7,
+            0xb5f2419072ef39df,
+            0xdba7a0b7a416c70c,
+            0x0002a1af6b34b8b7,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x4a2f999398c18ea5,
+            0xa10f984d8442107a,
+            0x581dce0b81e17e2d,
+            0xc61b7a9e8bad9b0e,
+            0xe3dcc2a3fcce6623,
+            0xca3f495d9b148fb8,
+            0xd7e88a2adfff36d3,
+            0xb4bc1d7a140fa754,
+            0x98fdabb94e0b0b2e,
+            0x9e7eb1f8c03be635,
+            0x3a52912d6a241a72,
+            0x008ded02d4fc00db,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x6e0b413e2779e96f,
+            0x9afe313cfcd23263,
+            0x81a2149033c78fd3,
+            0xb6855881fb8f66cf,
+            0x52f0fc235d45582e,
+            0x7ff86b58850c4537,
+            0x3c2dce4cd70854a3,
+            0x6d9511f19b1a8d98,
+            0x322c37f9c127e5fa,
+            0xbc8e59d71f8ef661,
+            0xbb44dc901f1a5b87,
+            0x00c4b33fb144a2e0,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x60f9fba4818c1b11,
+                0x37703fc991465c01,
+                0x13af55cc5c584fe0,
+                0x18eee642586c76d8,
+                0x7a3cce5e7522e54e,
+                0xe7987592c23337d6,
+                0xe65203c63e549338,
+                0x90ffebb9f08ea17d,
+                0xd0d5d1399dfadeb6,
+                0xf679bb0a1a86cdb2,
+                0xbea769f359df7967,
+                0x002119fe2102ee88,
+            ])),
+            Felt::new(BigInteger768([
+                0x5a04908ea071e2ad,
+                0x2c1583590c22ece7,
+                0x212759cb7ed2d32e,
+                0x239c4a2c58a2ac99,
+                0xc6196f8b4c3c2b5b,
+                0x4bdc2d1dd6b58d9e,
+                0xb5a19d0afe18d6b3,
+                0x9a5b0f9e71fb9654,
+                0x7dd84c04d94ffed5,
+                0x306e65363cfd2aa0,
+                0x441091ea9937d0cf,
+                0x00a003f427cacbd8,
+            ])),
+            Felt::new(BigInteger768([
+                0xae1a1e196cfcf018,
+                0x928259d5503180f2,
+                0xd296459e59315814,
+                0x0a577e7832820465,
+                0xda50558602cff6a3,
+                0xd74452664ea94a72,
+                0x1427547763c01b41,
+                0xa991d863cf7bdb00,
+                0xd5fbfb47fcf49539,
+                0x299270fb42706f05,
+                0x04a06db2b2c16e76,
+                0x0122956117c9f4db,
+            ])),
+            Felt::new(BigInteger768([
+                0xcc6bb1f12e1dc8a8,
+                0xed99a8b490739b6a,
+                0x937bab1275bf31e2,
+                0xbd1d2fa443907e95,
+                0xa4436115ab70a933,
+                0x0b4c6c0e0cec14f2,
+                0x92dc2a2051272ed5,
+                0x7e0176c434cf3a21,
+                0xe913db13516d0029,
+                0x9d4503d204c40658,
+                0x4e264c39429f815e,
+                0x008b2ed03e76acd4,
+            ])),
+            Felt::new(BigInteger768([
+                0xff8416613f87ae2c,
+                0x83adb271ed614a1c,
+                0x35d63049875dbc8e,
+                0x65c2b17598f7bdda,
+                0xfe969f68cf04338e,
+                0x2942d032564007c7,
+                0x987e6a2ede6b3038,
+                0xef15c11364234546,
+                0xb6a84fc6810b78ad,
+                0x0b69059315845b2a,
+                0xa44318d71c9dc69a,
+                0x0048d0cd977ba73d,
+            ])),
+            Felt::new(BigInteger768([
+                0x4683667d31e45472,
+                0xd41fb14708b3d1cd,
+                0x298925ad66c6b70b,
+                0x9c000a404fae2630,
+                0xfdb99ca0f44186a8,
+                0x0744702e47b951ec,
+                0xfc1071661992de98,
+                0x4462ce1d6f94c64f,
+                0x78fc3e412dc4af10,
+                0xd835e0247ab763e6,
+                0x0c9cdeb010eaca30,
+                0x0034db95424242f9,
+            ])),
+            Felt::new(BigInteger768([
+                0x74b1f15c6a8ca2d0,
+                0x7882bb0376a05eaf,
+                0x89bf183cb26f9090,
+                0x38328b340f13cd59,
+                0xa856d7403bfe36c7,
+                0x65f9b05b903b741d,
+                0x7aabf07648c9a3c0,
+                0x46b497b93bf89ff3,
+                0x78259dfd24bd2e85,
+                0x32ea0f4517804c97,
+                0xb315871983c67f37,
+                0x010690c6298221ed,
+            ])),
+            Felt::new(BigInteger768([
+                0xdc27bf5a2e1beaf2,
+                0x7a5e5d6f6025fcf6,
+                0xaf763953afb52081,
+                0x8c88ebad7a1867b4,
+                0xb5fce51768ed683d,
+                0xd247c59db68d1ffa,
+                0xdd6151ce4c795ab0,
+                0x5ef836f04c99f6bf,
+                0xf5b14762251228d8,
+                0x33db0c71a5743821,
+                0x3077658bf6673e4d,
+                0x00bd66150064b5a3,
+            ])),
+            Felt::new(BigInteger768([
+                0x9c6ed342a08cd092,
+                0x0a4d471ba6f004d8,
+                0xaaca0401d28a6eec,
+                0x2c316af0c1bbfe8b,
+                0xf73c2b8e4f133393,
+                0x9523bd05b2b778b0,
+                0x073d6c843e19c1a8,
+                0x4fb4efecbb256767,
+                0xbefea3577a6d9e2b,
+                0xb2c338562a374d89,
+                0x8d63ee730857a832,
+                0x00f7895595cebfab,
+            ])),
+            Felt::new(BigInteger768([
+                0x50fc6b2d0e27345e,
+                0xa53c528e9e0cb142,
+                0x9413a0d30504369e,
+                0x67c0088a06c39517,
+                0x484357e750dbb1c7,
+                0xaf06dcda82352b47,
+                0xcb1f5fddcd8335b1,
+                0xb510b217980fd2f1,
+                0x24a7a0df59f6b0bd,
+                0xe16bdb55c0fc6937,
+                0xab0fce177506889a,
+                0x00757df334f4f624,
+            ])),
+            Felt::new(BigInteger768([
+                0xc5b207c09de5fd2f,
+                0xc63f9fb304798e06,
+                0x4f9e3b3bf4f6fb4e,
+                0x192f2ae822890842,
+                0xc36ae7fe351ca02d,
+                0x6c7e37bb44e6ca3c,
+                0x1a9e346cf2a279b5,
+                0x1e3f0f0e4a87dc4c,
+                0xc27a9b600d5c9d29,
+                0xed3fcd1e41caef49,
+                0xf2357f34493f8eb9,
+                0x01106b89ff2c2f69,
+            ])),
+            Felt::new(BigInteger768([
+                0x706b8b3808094f95,
+                0x0502bb6debe7b67f,
+                0x4bf6baa99e2ae336,
+                0x80e43094c4403023,
+                0x15dc9270beae74aa,
+                0x83ec5f60c9c49fab,
+                0x3ef352e4ecc2951b,
+                0xee28ff31617c2cae,
+                0xe4c69a18300eb9eb,
+                0x83de3b30d9926e19,
+                0xfd0a7890281d7910,
+                0x0058ed288180fc9f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x3f258b31e25e819f,
+                0x9ff9d4baf134422d,
+                0xd9089e5848706bb1,
+                0xb70dcfcc716e9daf,
+                0x505157320fe1ee18,
+                0xfb4306e102634a27,
+                0x0fcde9d68617abdd,
+                0x22fd2a66b562cf36,
+                0x7d497c2ffc6a9b7c,
+                0x8277fe59d294ace1,
+                0xef9339d139675093,
+                0x00ba86db90086698,
+            ])),
+            Felt::new(BigInteger768([
+                0x6d7860a6fd0a7606,
+                0x1aa0c2d68a61e98c,
+                0x512913ac8153dbca,
+                0x285e63beda3aa5a1,
+                0xdf581cc5fec00613,
+                0x25807db1d9366471,
+                0x0b99f76ac5a8b303,
+                0x5ef72e5d1fe39ffb,
+                0x162fc8790318982f,
+                0x4b1cdc90af2557b0,
+                0x018d2b4ec7e7c9a8,
+                0x011a76cc45970e31,
+            ])),
+            Felt::new(BigInteger768([
+                0xfd0189fcfaddfd2e,
+                0x02804ea7ff0bd39f,
+                0xc76a59a9523e5216,
+                0x34d444a2eac23cf5,
+                0xbf3b3f69d6bfd3cf,
+                0xa81e73257799d9f7,
+                0x44492dda29f44a18,
+                0xd98aa58f83f42380,
+                0x021ceb1dadb89415,
+                0xb2f9145b25df72de,
+                0x80752981931334f2,
+                0x00804cb0aa2689a2,
+            ])),
+            Felt::new(BigInteger768([
+                0x6d6fde9e85646ef1,
+                0xa93228866edf2a41,
+                0x6cf5f46b44462311,
+                0x3f2978944b4066a4,
+                0x3cb01c3f7b265bdc,
+                0xeb53bc040828d548,
+                0x9c41fd6639d3a13e,
+                0x2a60cc0f0fca3d18,
+                0xf04355b2b14188f3,
+                0x005c996fb9ecbbe9,
+                0x079a1e796a7c397c,
+                0x0011d70874b9e6f9,
+            ])),
+            Felt::new(BigInteger768([
+                0x735948669184bf33,
+                0x66ee4663f4229d3b,
+                0xbd9176b255a60874,
+                0x2e20d0f2e9a5d313,
+                0xf6e0994386f1a5cc,
+                0x7d01c1f7fd685ee9,
+                0x77667b24b6ebb256,
+                0xdaec61bfc6975980,
+                0x9be17e7cc9d88eab,
+                0xfc15572ccc49fc07,
+                0x8096e7600cb2d053,
+                0x001981d68e74e6f4,
+            ])),
+            Felt::new(BigInteger768([
+                0x105becdf156bc470,
+                0xccd56f081ec12ac3,
+                0x19a1119c8aac209a,
+                0x87d14d6a7659842e,
+                0xc4a9070aed0e3049,
+                0xbe3e58e399eedd9e,
+                0x52cabcfae06024e3,
+                0x35c4aa3047b0a158,
+                0x17baa4ae8ce0fc97,
+                0xf3b54a71c5b01660,
+                0x103efada4e5064fc,
+                0x00615e7f460d0816,
+            ])),
+            Felt::new(BigInteger768([
+                0x5450ad6d20fab1a9,
+                0xe43c34f39fbb02e1,
+                0xeee0d66fd46c80ff,
+                0x2576f05e6cb55108,
+                0x6627dead1e08b12c,
+                0x83d612ad7a886983,
+                0xedacc87b6e08aa95,
+                0x3ac7f2f676d37592,
+                0x37b92e401b9e9834,
+                0x72187bdcae49daf2,
+                0x8b7e17a2b95ccc36,
+                0x00dd36f2c4c49ccd,
+            ])),
+            Felt::new(BigInteger768([
+                0x35033a189d302b34,
+                0xebdd9d8e47d03e40,
+                0xc7207c6c224785d0,
+                0xda642de3650b94cd,
+                0x3fcbb08635a4f04e,
+                0xe97eeda619bb1816,
+                0x8efa51141ca81b3a,
+                0x926b341a9fbcc089,
+                0x5e5628d18cec94c2,
+                0x94223f2ef9dc3988,
+                0x8e310bbb3cc4125c,
+                0x009843f846b716e3,
+            ])),
+            Felt::new(BigInteger768([
+                0xf9dcfa8947ce2e42,
+                0x565fe8c5bf7b5394,
+                0x177f0fb7ef184bd3,
+                0xd2531cc1d47a65d5,
+                0x997d963b1f19e21f,
+                0xf8757014504faaf3,
+                0x6dd503bc40a57d25,
+                0x1de5b4ed910e73a8,
+                0xf9f8e6097324df2a,
+                0xa3ae57aadc801927,
+                0x3a1e8cd597ea9912,
+                0x00aa26b75a35f16b,
+            ])),
+            Felt::new(BigInteger768([
+                0x400df4a31dc3cd93,
+                0xac371f47ae4f91a3,
+                0xec3c1284d63d2e8b,
+                0x41901ce9b1ece850,
+                0xe2aa82e833c501bf,
+                0xfd652bdf13a373f5,
+                0x0f66465d8e2d4d56,
+                0x41ff14f541e24759,
+                0xa68ac3371cb4fea8,
+                0x1588b7a682559599,
+                0x50551da6098fb1a4,
+                0x00ab4e67a0ff4762,
+            ])),
+            Felt::new(BigInteger768([
+                0x3552005f839b29a9,
+                0x972da88b731f5f98,
+                0x8a9263669fddebbf,
+                0xbf7fa9e0954db36d,
+                0xca4ecec341023fdb,
+                0x433e50ec2754a217,
+                0x46de55bd897dfb29,
+                0xaf10e8e534463875,
+                0x6b37e6896f99fa64,
+                0xd731159283ad968c,
+                0x78b47165a380f173,
+                0x00c6ae5d4be4d2d9,
+            ])),
+            Felt::new(BigInteger768([
+                0x32ea501d51e088a4,
+                0xf04cb5d3c14b5d61,
+                0x3c36e69a79fcc39e,
+                0x125d7cf743ab390a,
+                0x17284e4838558bc2,
+                0x2c7c0ab44a002418,
+                0xd6098723a6c5aa60,
+                0x9b5457fa42f11440,
+                0x7b800b200460e05a,
+                0x391346500705390f,
+                0xc01b817a51eb2442,
+                0x00b070de40a2ba74,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x3af8870c56707f0e,
+                0x304b0e0d262d1c20,
+                0x637a6f1543d91a3a,
+                0x6aa86a3eadecd47d,
+                0xc94f24c9cdf6ae2c,
+                0x5dc1fb06850dfff7,
+                0xe0dcdce23ee3ff19,
+                0x3c8b3ec744d75a29,
+                0x1bfac679976ffe13,
+                0xc7c350b5a0eca0ec,
+                0xcdb2749c517d0baa,
+                0x00c128651db56753,
+            ])),
+            Felt::new(BigInteger768([
+                0xa508797ac1b71334,
+                0x364b36e7ac6ef7ce,
+                0xd098d06ebafeb6b3,
+                0x5ce09e1abb64740f,
+                0xb55bb099df62452a,
+                0x187506566fa0cfdf,
+                0x4c2be8897157c1a1,
+                0x58e6fe3b707e58a8,
+                0xc1b2b05df8adb570,
+                0x1f5653d9081b6e8d,
+                0x76820d3056f2e0e7,
+                0x003f4e3870ad1301,
+            ])),
+            Felt::new(BigInteger768([
+                0x200ef2bcbb98a0a5,
+                0x8e6455a5f1f9b315,
+                0x9184ee923753c661,
+                0x3fdfcb9708956d07,
+                0xc94520ec7e5ba86f,
+                0xb99c27db2234722f,
+                0x08d6088dd4ecfb6c,
+                0x6e3d96289e4e416f,
+                0x29faa0378e1a12e0,
+                0xd3122636259eb5c9,
+                0xd142d3649faaa0f2,
+                0x009a565353f23d1c,
+            ])),
+            Felt::new(BigInteger768([
+                0xcf2d981582b9718e,
+                0x6a20ec090c30a7a4,
+                0x19bfb2296f5c9f68,
+                0x0b4195ca1238cfa3,
+                0x1a846d93c3460b7f,
+                0x1d5963671cfe2c15,
+                0xb1dfbff167ac2acf,
+                0x078036f959303d7a,
+                0x4e5a1018168045bd,
+                0x70883f996845838c,
+                0x359cd4774a260c82,
+                0x0071073528876181,
+            ])),
+            Felt::new(BigInteger768([
+                0xeac87cf6f9aee6b9,
+                0xd8199aa78e2596e3,
+                0x1f4f0857d105743a,
+                0x17ee1e4aaaf5c42e,
+                0x982d878e0147c4f5,
+                0x4f99e2f5ba41b116,
+                0xe1c92c0608450d65,
+                0x733c009cf1884617,
+                0x19ee355e2f785d9c,
+                0x5983bd3846ea60ff,
+                0x57825efc3a80949d,
+                0x0108ac8a8634b02e,
+            ])),
+            Felt::new(BigInteger768([
+                0x32eeb16012f9a032,
+                0x717ad5c14f73d999,
+                0x583d0f17b094c645,
+                0x1eb92a4ef47ccc50,
+                0x3c40b0376ccb8545,
+                0xb0cb32f4db71c08a,
+                0x3454d256c894acfc,
+                0x8705d92199daf31d,
+                0x4596451ba7636216,
+                0x2c96291a627c6816,
+                0xae3ecad00098f900,
+                0x0009033c9ca6d03a,
+            ])),
+            Felt::new(BigInteger768([
+                0x32ef786ee1c6b9f7,
+                0xa1ac6dd963c02e07,
+                0x4f29f9c627a1d446,
+                0x284677f7b115abd0,
+                0xca371b2ade8a4e1f,
+                0x1e65c34137c3881c,
+                0x00ffca9980b14227,
+                0xe17faf7a4a3632b6,
+                0x6ad9e6f60e604652,
+                0x1ce50e79ab2b2ae1,
+                0xde0b5c60c547c803,
+                0x00c5159fcf6cf5a9,
+            ])),
+            Felt::new(BigInteger768([
+                0xef385e24e94941a0,
+                0xb9f6f17eb02cc3f6,
+                0xe5fc04ea5ea8bb12,
+                0xbc848e941f7864ad,
+                0xc9f7cbb981a44b70,
+                0x089bb84ea938856d,
+                0x07ee82bb667891ec,
+                0x16092d14e6ecb4db,
+                0x1c7bebd141e22787,
+                0x47559e092adc037e,
+                0x7ef0c908c5680d19,
+                0x00e7e93c6b862730,
+            ])),
+            Felt::new(BigInteger768([
+                0x6a99f1b177d34168,
+                0x10aba0f89e3f7d88,
+                0xd7f365debbb2da70,
+                0x2a9d7d1127403336,
+                0xc4bd71bd811824ef,
+                0x31817ab7933901db,
+                0xbd05e8c36138e9c4,
+                0x9ef181af95ee10be,
+                0x0002bfe6538ce419,
+                0xa4d934ca32d266a5,
+                0x190f7a39971fb774,
+                0x0067b9f6509f6816,
+            ])),
+            Felt::new(BigInteger768([
+                0xd7159ce0639b5938,
+                0xdd0214935e949baa,
+                0xfd896087a0a84ce0,
+                0xecb63310b45d5cf7,
+                0x10edca4e181ca05c,
+                0x22d6a95fe41583fc,
+                0x03f463d9eda738af,
+                0xcc2ba61428e34b2c,
+                0xfa6c567076da295b,
+                0xd4ea45bd2dbc6928,
+                0x81f2ae3018c3c2df,
+                0x0069bdf14418b5db,
+            ])),
+            Felt::new(BigInteger768([
+                0x3705108b2ed54161,
+                0x24a17ef54b2aa65d,
+                0x70c36e8922a314b5,
+                0xc5a7b95372040105,
+                0xd196def3adac1cfa,
+                0xc5f135395215dc96,
+                0xa67a47949aa09642,
+                0x75e02f6d02025dca,
+                0x5792187470fa862a,
+                0xe131f6dbed97a737,
+                0xa2ba8e3e6832fc18,
+                0x0037b694176f2a33,
+            ])),
+            Felt::new(BigInteger768([
+                0xa78c7e5fe193cf4f,
+                0x0c44afa9bb56e532,
+                0xe7c69767e6996d06,
+                0x0388e15c935e5e6e,
+                0x630ebe55f27bc87b,
+                0xf3d3f1d41b3e7dcd,
+                0x575fefa8def17be2,
+                0xff4652b2b42a772a,
+                0xabde612ac17d13fe,
+                0x57b0eed35c88395c,
+                0xbb34aaaaebae0c6f,
+                0x00a1a4e85c696d2a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xb0c9a3f7d15e29a9,
+                0xe9606974ef0cdbaa,
+                0xdf65608932bdea98,
+                0xd2b0892c4dc9e217,
+                0x7fe4b0729c9a330d,
+                0x3889e1c841632da7,
+                0x51fca8937ef2c6cb,
+                0xa841ac4c0ccc93a9,
+                0x573acb7044024cb8,
+                0xe635124e6c2c0c9a,
+                0xb6bb378a5b22b35a,
+                0x009acab6cfc55059,
+            ])),
+            Felt::new(BigInteger768([
+                0x935bc69513199866,
+                0xe3af97ca3e4aa523,
+                0x0427708ec1a92f84,
+                0xda56843925f1836e,
+                0x1c847511b505d180,
+                0x91b7a06ff113ca0e,
+                0x56ec9afb181f6a72,
+                0x88975475b7154f6c,
+                0x71ecd04113f0893d,
+                0x5a86e841992037ba,
+                0x3add8a1f5f362e40,
+                0x0033fa650beb3fd3,
+            ])),
+            Felt::new(BigInteger768([
+                0x63cbc40a11bfd17e,
+                0x37145bfe2d3fc2f3,
+                0x2a64d0fb5d564be7,
+                0xd9576be8bcf786cf,
+                0x3b24bda30a0a384c,
+                0xd1c06fcd41c543f1,
+                0x0a1c3200c0472d4f,
+                0x3ee3522a3788b9dc,
+                0xc35d7f24246165fb,
+                0x3ed06821d317faa8,
+                0xf0ddd9687272d119,
+                0x009509b73895f044,
+            ])),
+            Felt::new(BigInteger768([
+                0x631ce7d6023dc734,
+                0xeac4c13254df1e7e,
+                0x941a6c9f31836f15,
+                0xd11fb6d691eab48d,
+                0x120663184ec9c6bf,
+                0xca6b98e3058b0287,
+                0x127775699052a77a,
+                0xe1bb7461104fa7fe,
+                0xb10ba39055acb711,
+                0x04df5bd94f0d73db,
+                0xa052e9bc24635ed2,
+                0x00353999bfd280ee,
+            ])),
+            Felt::new(BigInteger768([
+                0x0508493cc1e9d66c,
+                0xc10911b47427c99d,
+                0x6b656c9abdd62ad9,
+                0x18b65609c744ce95,
+                0xa2c6aa3d0bd04d98,
+                0x8b0336f909f6c564,
+                0x66ed1ff2096dc819,
+                0x7abfdde3d8ddcda4,
+                0x4c5b8f08f3378561,
+                0x50b5b6c395eff7d3,
+                0x9e1b14df9dbf5125,
+                0x00b72670fa5bb8bf,
+            ])),
+            Felt::new(BigInteger768([
+                0xc5dcaf06c5c5890f,
+                0x3e3f67241ace3f3d,
+                0x40962e322ebb4399,
+                0x2e68edc316bcb001,
+                0x26cefb778fbdfe86,
+                0xfb8e4ec39f3ae463,
+                0x3dcf884ca5947193,
+                0x1f3077eb83e77a51,
+                0x997247834fd95061,
+                0x08bf1355e9e37a94,
+                0x21a479bb3eafdc39,
+                0x00a3a7fd7aa94764,
+            ])),
+            Felt::new(BigInteger768([
+                0x19bc2c44f47ad582,
+                0x631bf3d9534decb2,
+                0x8824cd9f2cbfe411,
+                0x7a595b445fc7b684,
+                0x3845cdf5e630ee02,
+                0x1fb910b7f46ca919,
+                0xf64e93a9405e4d21,
+                0x22736c535e58e9d7,
+                0x2fce8b4e55e428b3,
+                0x8046901f7b0519d9,
+                0x41baeec3e8042879,
+                0x00e744e01cd37a8f,
+            ])),
+            Felt::new(BigInteger768([
+                0x81407b1483a481de,
+                0x0a4ebb942399b874,
+                0x07c885cd6ec80aba,
+                0x68951c35dee31ab2,
+                0x87604844c08a7113,
+                0xb52762eb23f44475,
+                0x51f1febb31c054df,
+                0xbb6b8d401f16a899,
+                0x859f12223a7c883f,
+                0xc8aa07693ca864a4,
+                0xf5f5fadb365ebd6b,
+                0x00c07bc310525af5,
+            ])),
+            Felt::new(BigInteger768([
+                0x13b3737946792c96,
+                0x4403ecbe590b67ad,
+                0x0aa1b86b9b4d530e,
+                0x20bcbd5ef68ac8d5,
+                0xfbf64ffe708f20ac,
+                0x28609acadd911220,
+                0xd5903cff37acd37e,
+                0xee33ad2f7c09c6ea,
+                0xf01afc8249e3f0ef,
+                0x2774febb179af684,
+                0x9b630ca0ae616570,
+                0x0005b5713dcdda6c,
+            ])),
+            Felt::new(BigInteger768([
+                0x760105bce84c6ace,
+                0x940cb6609bb6138d,
+                0x3764b720dbc3cf35,
+                0x734cf3a491c8a324,
+                0x0dcff902c0581ec6,
+                0x2d32bd41813e342b,
+                0x21b0e234c82a157f,
+                0x89f1ce9ead394bfa,
+                0x0f52b534b45a2cb7,
+                0x5bad87eb27820a9f,
+                0xf9d3473c0e446e35,
+                0x00c9a7092ed7eb98,
+            ])),
+            Felt::new(BigInteger768([
+                0x5dfd35cd654d866c,
+                0x0a8c77f2de77c5ef,
+                0x10b7167d0441c9a4,
+                0xfbdb10d69299bcbe,
+                0x9634e9ef5c274f1d,
+                0x1045943b7248e1c6,
+                0x3d2fbf7d57861a96,
+                0xfbe079b06d3c78d9,
+                0x28641e80fc9f74cf,
+                0x782bc0116dc08ee6,
+                0x26e38d067bed386e,
+                0x006ef6bf1fc17fc0,
+            ])),
+            Felt::new(BigInteger768([
+                0x9235ac344c38f05b,
+                0xe4fd3678eb58f84b,
+                0xefd2fa27c843024c,
+                0x958f82636df5f19d,
+                0x20c601875d3a06b7,
+                0x2ad7eae57ac6c268,
+                0xc1dc5422fe89af30,
+                0x326eb6fe11863284,
+                0x2864d8bd54350042,
+                0xf243f73164279c70,
+                0xf38af88ca82a26f5,
+                0x00f348cdb5e1ec46,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x8e438bab30b4ce3b,
+                0x600e9b54ca03d738,
+                0xc14b600a63406844,
+                0xe02519f8941cf5ae,
+                0x1c443805d471d83a,
+                0xcf76d97edf0f6b76,
+                0x75b5d1f6372f8307,
+                0x78e49d1c52ad76e3,
+                0xc944b439464291cd,
+                0xb8ad866701a67d1c,
+                0xf2c4ae098f05d10f,
+                0x010dbe3a80a1913a,
+            ])),
+            Felt::new(BigInteger768([
+                0xf1292f61823a4e2a,
+                0x9fb2fc13040c35a1,
+                0xf4e43f82d618c43d,
+                0x487b86e258c0941d,
+                0x97a646365cba5a07,
+                0x024db431d91f5b80,
+                0x0ff3116b5d941a18,
+                0x87742e212d40aeb9,
+                0x0dec77139142fc4f,
+                0x50b442e18bfecc48,
+                0xad63eba3b06046d5,
+                0x0078e9de852a85bb,
+            ])),
+            Felt::new(BigInteger768([
+                0x6e82757408cd6504,
+                0xd6023a7226ee00cf,
+                0x585ad8074c56e69e,
+                0xaa10e634e8137585,
+                0x564a8397ccbcba24,
+                0xf9bb7683e64e3f48,
+                0x376e200de3cb9b56,
+                0x95089f5687d05254,
+                0x374068978e959df5,
+                0xada82cb662e5328b,
+                0xf26adf7e36fd4c8c,
+                0x0089a58d006974ef,
+            ])),
+            Felt::new(BigInteger768([
+                0xde1d2055acf75ac9,
+                0x4a7ee64b330be126,
+                0xa6306c3f9440e95f,
+                0xf492dfcde6b34281,
+                0x315b67abce6e4692,
+                0x30152a32cc821d86,
+                0xe7f7d7856359157e,
+                0x8b83704114b856ac,
+                0x79625cc950ad63b4,
+                0xde68aacfb314fd70,
+                0x55aecaa655cf6904,
+                0x00df2858212ceb73,
+            ])),
+            Felt::new(BigInteger768([
+                0x6e8488fdfa8be316,
+                0xcdad3c7197eaac25,
+                0x8e6cd163b8fe3f05,
+                0xd401d454c75582d1,
+                0x473de3424f249605,
+                0x21b0830ec4e63334,
+                0xb9e652ac5e776530,
+                0x3c6c96bb4008d9d5,
+                0x303b3fcbb5489b53,
+                0x9df0a6a0ff404779,
+                0x1bf480aae2fb3106,
+                0x0073a16dc43c8cfe,
+            ])),
+            Felt::new(BigInteger768([
+                0xcbf438bc12581f19,
+                0x131789b95bdea52e,
+                0xccc48c40a1277a45,
+                0x118a6a2682371a69,
+                0xca6b4306d202df4f,
+                0xad0f506e78d01335,
+                0xd7642aabd2dd51d1,
+                0x9dded964ab35353f,
+                0x33d4a7a8ac26ca2b,
+                0x09a5b0692c18d5b5,
+                0xcb6ad4c0cef7edb9,
+                0x003f87b20191bf8c,
+            ])),
+            Felt::new(BigInteger768([
+                0x3cdde8c94b02c8b6,
+                0xba46c781968a20e2,
+                0xf95c0138a527696d,
+                0x915e43bcd4def7d3,
+                0x934fa07196c2b0c7,
+                0x390e0575259462fb,
+                0xa6243a95df547d6c,
+                0xc34c93b873a839f6,
+                0x44794e2a96d22dd7,
+                0xd680b8a4d840b354,
+                0xd56ea46eb6254927,
+                0x000fb428f159b733,
+            ])),
+            Felt::new(BigInteger768([
+                0x4da8a75129aaab3b,
+                0xdc195febda58a36a,
+                0xb07c462b8c47c08f,
+                0xed34b45c00c9bb3f,
+                0x2919d03131faaec3,
+                0x2d627d528c68d8ca,
+                0xdc6fff2a77fe1e78,
+                0xd96f05f745db32cc,
+                0xd888254f097024b5,
+                0x3c167798fcc5742f,
+                0x90a8ccee0635a6b6,
+                0x001f0f5d64f90270,
+            ])),
+            Felt::new(BigInteger768([
+                0xaa1971cae29c77b2,
+                0xaa270b6bb2145cad,
+                0x14177464549e6afd,
+                0x8cf374a500526d00,
+                0x412875616e660279,
+                0xafef7ae3f45d7e64,
+                0xb0996dd1b58055f8,
+                0xaf5669f3441005b7,
+                0x10662d32586afe21,
+                0xd983ccd58d742743,
+                0xbc6d684d778d53c2,
+                0x001153c2f689279b,
+            ])),
+            Felt::new(BigInteger768([
+                0xec2d4fd9a5f4d557,
+                0x4ee8d9b1c378b8c0,
+                0x20e5b4191fdcc995,
+                0x4dccaef0769de181,
+                0x1fdb538511845e43,
+                0x83c5c9e8203c2c2d,
+                0x9248fd77af67418e,
+                0x455bdbe1c2877d83,
+                0xf2617aaa43182fde,
+                0xf736ba2f4efa2e7f,
+                0x50877815c0dead46,
+                0x00959f336a55425b,
+            ])),
+            Felt::new(BigInteger768([
+                0xb49bd7b13d0dbc0b,
+                0x8ae07459fd9b9655,
+                0x56acb3f3338c00d3,
+                0x6f4eccef95081d90,
+                0x3c062d2bb7fa4b54,
+                0xc81be42df613b661,
+                0xeea5d8328ddfe398,
+                0xa936da51d18731cc,
+                0x62ea0b8f35e44a30,
+                0x96bb741d2204013e,
+                0xe992c8dfc19ae387,
+                0x00f34e737f102cf9,
+            ])),
+            Felt::new(BigInteger768([
+                0xcc5b4b7e3e6d8ca7,
+                0x69eb727a9baf2977,
+                0x16fc10ee9000fcd5,
+                0xb4938e2a79eb11e8,
+                0x83589000bd463227,
+                0x36fe553f26373b16,
+                0xc3a972eee3b9cd2d,
+                0xdaad5e4ca967ebc9,
+                0x1bdf986d6638541c,
+                0xf09619d693d74b4e,
+                0x5d60adb9d9794e1a,
+                0x0090e8f783f8094e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x6acb22294f7d32c6,
+                0x1450bd23f1db2109,
+                0x4029292922041bc9,
+                0x04ae56add8e0fa02,
+                0xe1b03f0e54e677ac,
+                0x86051dd54de5a714,
+                0x622ae925cc56bd90,
+                0x6a097e9d75673426,
+                0x7a1317b11399eee5,
+                0x191a4bca990002ae,
+                0x9b314f685b1df94e,
+                0x00f4ff4f250dc94d,
+            ])),
+            Felt::new(BigInteger768([
+                0x0b19e865be6c4d82,
+                0xbbea1776c36a322a,
+                0x5646c1512b4f2785,
+                0x9c3b0434b61f87f5,
+                0x5042eae40feaa39a,
+                0x5fd767adecc042fd,
+                0xda40ee5225a5c652,
+                0x40ae9b57c842556c,
+                0xcec49dc0725f5cf6,
+                0xbc5b212a8e50fc25,
+                0x5c33cc9487552e2e,
+                0x00d168b7b8e8f342,
+            ])),
+            Felt::new(BigInteger768([
+                0x8aea9337a53efd9e,
+                0xa8cd54d05ec79ba4,
+                0x9e2058b2491abb19,
+                0x2e55419fc258b424,
+                0xbc9b4fefaf9ff851,
+                0x645d4ed1b2bc0407,
+                0xaf2514f95d12728e,
+                0x464eb8cedc0dd7fd,
+                0x6af295b1b201dd7e,
+                0x83d6684268345dc6,
+                0xad9d93c12a0e5514,
+                0x010d0d8dc385fcf5,
+            ])),
+            Felt::new(BigInteger768([
+                0x4301a6dc6655a968,
+                0x928787d24050dab5,
+                0x71784e5bacad3c55,
+                0x5267c04e10c47ea9,
+                0xce17575b4b978306,
+                0xc126f7da678b448a,
+                0xd179e179e1d0ddc6,
+                0x72757535ff1707b8,
+                0x5cf8431097958178,
+                0x9790ad67f3da4e39,
+                0xc018ea4e9194e02e,
+                0x0044238050e9c98b,
+            ])),
+            Felt::new(BigInteger768([
+                0x813ce07290d5ae4a,
+                0x6eed4998d9c13171,
+                0xc801123bbe503c9b,
+                0x8e09899c35c3b95a,
+                0x94b2367ac9536132,
+                0xcc4da50e28e6f282,
+                0x81761f6b4cf8bafe,
+                0xd576f0d17f0be715,
+                0xca4aa73c64b20679,
+                0xd4b92875cc8347d7,
+                0xd37aa3a569e9de01,
+                0x00aa325a2635acb3,
+            ])),
+            Felt::new(BigInteger768([
+                0x27bdeb4549b007dc,
+                0xdcd35052c88ff0d1,
+                0xa1c3102e0a1d556b,
+                0xc5c5823e431f0408,
+                0xca482824b69353d8,
+                0xdbb2b6a72157a2f0,
+                0x5280923c51efcbb5,
+                0xc767c90eb1ad32fb,
+                0x9dfd5e52ea6da0e6,
+                0xde2fcca8405a5d61,
+                0x5c501aeee8f2f651,
+                0x00dbc02f41b0cb84,
+            ])),
+            Felt::new(BigInteger768([
+                0xc5658afc82e13c48,
+                0x28a37942bf2334fb,
+                0x6863e89555ccddd0,
+                0x87dc87cd82735272,
+                0x7a706c103f235327,
+                0x1556d38612870081,
+                0x281260acba8ffd11,
+                0xcca0aad3e4564b56,
+                0xd5834412bb80b9f4,
+                0xf9bff845a597b85d,
+                0xac1a47843121efa8,
+                0x00cd66f9e8e473c9,
+            ])),
+            Felt::new(BigInteger768([
+                0xc83986439054f589,
+                0x6032540c16a6bf6c,
+                0x551d2aa8bd872b62,
+                0xf4d5935c697d6d55,
+                0x58f6bfad30aecb0a,
+                0x990ba137c03fcb7b,
+                0xf16d66b49dc3a3a5,
+                0xe84666a32a9ec903,
+                0x05cd5da339588846,
+                0xb27252e102aec1ec,
+                0x93c36a7bf6908d41,
+                0x00e89bd8b4fb55bb,
+            ])),
+            Felt::new(BigInteger768([
+                0xc48f50eeed520b56,
+                0x2b5a3996218e01b3,
+                0x00167cf27cf3c1b1,
+                0xe2c95b739d875750,
+                0x93e3a7bfbe22b503,
+                0x4baeddf69d665682,
+                0x89aeff2de5758ffe,
+                0x9dca2041f0d247c6,
+                0x65c981984a464880,
+                0xda9441fae5531354,
+                0x0c0535e46c8f5259,
+                0x00eb58c8f52493f8,
+            ])),
+            Felt::new(BigInteger768([
+                0xcad860fc3068e595,
+                0x21facdd182a8104b,
+                0xda052a4449a9211b,
+                0xfe6064e086bd3c01,
+                0x9ac104ee39df9191,
+                0x9f96aa13a1cd76e4,
+                0xd3cd9d93a65a91ab,
+                0x6bb1dd7298cbe70b,
+                0xb5a51d3cece531d0,
+                0xb7c2f9c1bf294a35,
+                0xa4ef9b6df5dcd675,
+                0x005bfc88121aaaa0,
+            ])),
+            Felt::new(BigInteger768([
+                0x4c8701649d4e37c2,
+                0x8600b934f012a7fb,
+                0x294044d35309b488,
+                0x4a97cd4517f12cfd,
+                0x34d043e1e69f5dfa,
+                0x33d1317c128baf21,
+                0xec72b97d00dc13ec,
+                0x8498baf33f146d9d,
+                0x2dd268befdb1419f,
+                0x07bd3bdcf210c432,
+                0xe2723597b4c5f308,
+                0x00b8a5630449f33d,
+            ])),
+            Felt::new(BigInteger768([
+                0x3076a94ee7e7e3f6,
+                0x2297d915c7ef6180,
+                0x4d37931a27ef2819,
+                0x07cab328d86bcb8d,
+                0x07a00f867ad60e5e,
+                0x4779212b035901e6,
+                0xfab1cac677672256,
+                0x7dd344b8e2938ba1,
+                0xe5eadd15fd01f6a5,
+                0x15d1e4dcc9e6f29c,
+                0xe4122e3bef3b7e7f,
+                0x0019946e379684ca,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger768([
+                0x9c4ee8e592314055,
+                0x33120f54a410d917,
+                0xa3d3dbb77cd1e6c9,
+                0x2f8ad249ab2a892d,
+                0x17581a9fcd8d55e9,
+                0x82616c165901a5d7,
+                0xe9a4d35a8e23d0ea,
+                0xee7153c802f8fe87,
+                0x44cabc32c382c87e,
+                0x53e5b2be9ab852a3,
+                0xdf1c9fd6827820b5,
+                0x010510b0e32d57ef,
+            ])),
+            Felt::new(BigInteger768([
+                0xe690f3e1ea313fcc,
+                0x83440f25f12c429c,
+                0x7c906ec978115e5b,
+                0x4844406c965eddea,
+                0x283c1852fca9b2c5,
+                0xee139668e6bf3d27,
+                0x6ec608c5ee7f6e1e,
+                0x787a7593dfed9b7f,
+                0x25badb4850ff4423,
+                0xa0b8b8d9066e0d97,
+                0x1c7c42dadca39d5c,
+                0x00485e3f4e2d6ab7,
+            ])),
+            Felt::new(BigInteger768([
+                0x07acd05082f56c49,
+                0xd683044387e10c08,
+                0x73acc8d17ae47e95,
+                0xa8a370f68b9b6446,
+                0x921f46d991a33bf4,
+                0x87b60d69bb93d089,
+                0x0972404a5c8bbd3a,
+                0xa336660b7de25310,
+                0xe59f5543ce98e54e,
+                0x6d0fa2fae510ec2e,
+                0x50fa3988ac71f09a,
+                0x0032ced7adda1108,
+            ])),
+            Felt::new(BigInteger768([
+                0x5e8b443744e51c8e,
+                0x3c0f2718b4834d61,
+                0x37ab0f08f0a03b18,
+                0xe62195bc1cb9e222,
+                0x164a7547fbe3e7f8,
+                0x5c5bd8469c30784e,
+                0x4b948ba4832e32d6,
+                0x25cbbb9c54b5a82c,
+                0x1fb3ab63a5bc0c9a,
+                0x9195b07cb347a613,
+                0xdf905e41f9d3fbab,
+                0x0037764300737541,
+            ])),
+            Felt::new(BigInteger768([
+                0x1d6816057fb5ee71,
+                0xa476f7af8d7df1c1,
+                0x71acb3fb681bc233,
+                0x9d8f5b0f46e1d945,
+                0x96986fa2228dd699,
+                0x8f578ff989eaf141,
+                0x451a3e7c019af098,
+                0xa317d2f6f099d201,
+                0x964819ad3f69b693,
+                0xb9ba5c90ee19a9c5,
+                0x8325581304a9db56,
+                0x0030a1acd2f92330,
+            ])),
+            Felt::new(BigInteger768([
+                0x72cc928e894d6066,
+                0x8850f3cce6583286,
+                0x510af7dec747fc78,
+                0xfe7a1231bcce4c6b,
+                0xad4879b12b57671e,
+                0x62be813e7b56b77b,
+                0x7b2db98f8b5730ad,
+                0x96515ca836aefd5b,
+                0xe77ce20a5f18b6bd,
+                0x21cc31068ff06789,
+                0x8182f72697d8f988,
+                0x00a6be79752f335c,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x9b64c47e38ed181b,
+                0xda68fed23ccdeda0,
+                0x8c2bf0911fc37c72,
+                0xf67f28360d02b6a0,
+                0x4caf0415246f0562,
+                0x813002b6db47383d,
+                0xae7815a36e517136,
+                0x51956ebefc1119ab,
+                0x41ef0e5ddf537a85,
+                0x8f09c7b861352ff0,
+                0x86f6ce9b95d5f23e,
+                0x00bd83976bc42cb9,
+            ])),
+            Felt::new(BigInteger768([
+                0xa880982ffb1fec42,
+                0xa61ccb993eb15d65,
+                0xec5d9b7a89e059f3,
+                0xd1960fb39388bd18,
+                0x6423d640114b9b9c,
+                0x81dcea4153236ac8,
+                0x0ef9bb37029bcf08,
+                0xa59d2516cae37119,
+                0xb7eaa9f46cda83ce,
+                0xbe945e6825d1b865,
+                0x4b65dd9dad457cb1,
+                0x002c45bffb17620d,
+            ])),
+            Felt::new(BigInteger768([
+                0xf14348676973b5b3,
+                0x1d862af9f6a3e1aa,
+                0x3a21e7fdf375759b,
+                0xce370802116f59cf,
+                0x259ffadac06b2689,
+                0xd3d8576c61e589d3,
+                0x1fda0d6a77c07994,
+                0x9caacf48d8394fd5,
+                0x5e1164c199a90141,
+                0x26ba37c8ca903baf,
+                0x408a74e1c5a82a16,
+                0x00567e81f8f8de43,
+            ])),
+            Felt::new(BigInteger768([
+                0xb05e022d3df02c13,
+                0x8c07856e3129f221,
+                0x50c892ce54fe96da,
+                0x29a91d5a89b34cdd,
+                0x44be97dacff54c6d,
+                0x8cf3c89d9f51bc92,
+                0x4e3f3d089fac9cff,
+                0x749ef9347a418e8b,
+                0xe8465f63d2fa77fc,
+                0x1d1b2057a25bba07,
+                0x8660f29c8c68fdb1,
+                0x00a8972f1a451c44,
+            ])),
+            Felt::new(BigInteger768([
+                0xad4af5ae878ff440,
+                0x76a5dac4a50c3372,
+                0x1740c1fd52205ebc,
+                0x0cf03c6a557c46aa,
+                0xd6cd98e738fa5278,
+                0xa1042f66fa927e40,
+                0x6c0c2768fbe8085e,
+                0x4d9d66a95c576dc4,
+                0xc1e7ea3c040a72d8,
+                0x99d78607667875b2,
+                0xf10df53296e60522,
+                0x010eca3c47137fea,
+            ])),
+            Felt::new(BigInteger768([
+                0x7ee4b86690c46e99,
+                0x8da6ccb217798531,
+                0xbbcd30e1514d93fe,
+                0x7957ed52e719cb93,
+                0x51a5ec26fc8410ae,
+                0x74ef4519a89c2a99,
+                0x43eca9bad724e4f1,
+                0x64cbd6f4e192c92a,
+                0x1263404824bebaa5,
+                0xec4d8b693946899e,
+                0x4c1845d7ecbf524c,
+                0x0084ffcec7f66b73,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xfcc07d2d32fe3888,
+                0x8e85ea401c2ca02d,
+                0xbd53d6dcefc6e928,
+                0x181bb8ce8df7e135,
+                0xd0a341cc4a6be79e,
+                0xc8abb0364c3e0d5f,
+                0x596f1c453606aa44,
+                0xfa626bf37c72bb85,
+                0xd8dc8f897de95d71,
+                0x47911efeac1f1b17,
+                0x6c6d09ffb1127c14,
+                0x0083652878e360e5,
+            ])),
+            Felt::new(BigInteger768([
+                0x1ba2552a493cb865,
+                0x4f7b2d8cdc18610b,
+                0x64eb07c47c7be0ce,
+                0x2fdd65022cc8f7d5,
+                0x9e7fea1734d5b09f,
+                0x0dc13620c7380856,
+                0x2f970920415572fe,
+                0x66cdb48a8b895dc1,
+                0xb1eee46d4755fd72,
+                0x38ab88198a47de25,
+                0xe830f31da14f2a74,
+                0x00a2b6a8100e64cf,
+            ])),
+            Felt::new(BigInteger768([
+                0x043fe6ae85eab547,
+                0x758a5a139d136d16,
+                0xc02957f4de8f52f8,
+                0x22745b55ac02b814,
+                0xb6653f508da5d36f,
+                0x6ff19be1b852090b,
+                0x2d336a681a78b9ac,
+                0x4f39dcc1157d6b71,
+                0x612659caf7e02ab6,
+                0xa700a6f6ea5ca782,
+                0x63c33d13a87f8c42,
+                0x00ef535a0f17a038,
+            ])),
+            Felt::new(BigInteger768([
+                0xa4f897375a7c14fc,
+                0xe2e4cc381510a814,
+                0x5347ffc1fdb0df97,
+                0x7d22f493d76a03eb,
+                0xf3d51a3560c8402b,
+                0x16763a14e6f0ef24,
+                0x53366be07b5ff855,
+                0xddfda2a624af52b7,
+                0xb991b0bbb64e1f4e,
+                0x69fcea2b0e2190a6,
+                0xf2ab5da28d71bd0e,
+                0x00d143e62652de57,
+            ])),
+            Felt::new(BigInteger768([
+                0x2e83d493c2fadc89,
+                0x0c81f0d7c31ae712,
+                0x0533022121665da0,
+                0xb8531fdab6c53efc,
+                0xf82fa96181b9ea75,
+                0x07931409d3e9e0a8,
+                0x585c5a1a9ddc1a5f,
+                0x2d4a12b0a8b60949,
+                0x604a29b3280f60fe,
+                0x5239831f7eec4955,
+                0x59065fae320c58f0,
+                0x0057c74869d5869b,
+            ])),
+            Felt::new(BigInteger768([
+                0xb1440aa4e800ba5c,
+                0xd31ee9b6bc6abbd3,
+                0x6a697dd0de7d3764,
+                0x7a0d058e1f553fc2,
+                0xe8b2724a9ae671cd,
+                0x423218bac8bf7a10,
+                0xb0f8cc0abce8623d,
+                0xf0c49583a70ffc2d,
+                0x36b83c72b2a505ef,
+                0x86e47c2f7d9aac78,
+                0xf7a32084f3531d11,
+                0x00705ac1673594fa,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xc8abde060da72ff9,
+                0x7451e239325c0bb1,
+                0x9ca9f50a2d9a4d20,
+                0xda9a082820f673b8,
+                0x00441646c19dcb98,
+                0xf3fd8fb5a87f2ad3,
+                0xe29c15acd9f125c3,
+                0x2f7e4c3ca1f1dab0,
+                0x03c1835025645b6a,
+                0x76935c7d3c86b85a,
+                0xfec2f5b870fbe74a,
+                0x0044b86bffac212c,
+            ])),
+            Felt::new(BigInteger768([
+                0xe613c215d42f6763,
+                0x54e4ec3fb85da460,
+                0x93fbe0852b7fbf07,
+                0x4d44d28d2457e8ab,
+                0x6806df3cbf10cb9e,
+                0xaeaf491d21c86f61,
+                0x0d86cfc2a0394ab2,
+                0x1cbf76ebce693afc,
+                0x00b493bcf0616008,
+                0xd8f8bf02083acd38,
+                0xf2db3dddd910e992,
+                0x01081f530275710a,
+            ])),
+            Felt::new(BigInteger768([
+                0xb4b7f80ed437ffa6,
+                0x5eebcfbf338718f5,
+                0xb94549cbd8de2963,
+                0xb8625d4150408374,
+                0x8ee1d4eeb0417f99,
+                0xf04e1b465ba0a762,
+                0x0bcc57b37bb60926,
+                0x5c76840dcbcc3c09,
+                0x38dfd24ac7f9df26,
+                0x30690ff8392ada38,
+                0x31ba6ec36257547e,
+                0x007f27e1ee477375,
+            ])),
+            Felt::new(BigInteger768([
+                0xb01deca3858dd007,
+                0xadf576851da30e59,
+                0x23c1afc79fe93c30,
+                0x82a576963cd111b4,
+                0xc4e5ef80eec52e33,
+                0x8217847c98eccdb1,
+                0xcf788d53ee496144,
+                0x1d958325243bbcb9,
+                0x18c95d13cb2c49af,
+                0x6f552ef15f4cef3f,
+                0x92a6bd49e9a71fa4,
+                0x00cc67e49ff59344,
+            ])),
+            Felt::new(BigInteger768([
+                0x505a783354c4f260,
+                0x71efbed9764bf8a1,
+                0xde4c20b702c4b723,
+                0xdafaaa97cd5127b8,
+                0xd8e27112675f2958,
+                0x4f11ec087c94e5e3,
+                0xee1dcacb6ebeaee3,
+                0x0adf9b8e07a80a29,
+                0x152f41cfaf40b924,
+                0x0ccf92610cff59a9,
+                0xa22459faa07c5287,
+                0x0000900aa52b4c11,
+            ])),
+            Felt::new(BigInteger768([
+                0x0f7f819d7afcbbd5,
+                0x3f41a9c25db99574,
+                0x91cfa9c193048093,
+                0x8c0324d8326635c9,
+                0xabb1f358649d342d,
+                0x93041f8bfa090022,
+                0x41c9d370dfef7d4c,
+                0x465a123d6bdf64af,
+                0xcc9a7840f6b59d08,
+                0x3c786bd575d8fac3,
+                0xef9f6aead6801e8f,
+                0x0062b355019f6774,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xfc1106ce3e8a85aa,
+                0x17f217ba7831663c,
+                0x54d1bb8fe7ac1e68,
+                0x57a48ac0b6f026e6,
+                0x7ac27736c5f716ac,
+                0xd31dbe8c0fe60732,
+                0x6de44845c79f4363,
+                0x7fabf5f0c3a9aa29,
+                0x110338f99145a565,
+                0x602770a7c8a6cb79,
+                0x8d6e0fd1fe7e3d35,
+                0x008df8df40421999,
+            ])),
+            Felt::new(BigInteger768([
+                0xda69a98c9076f201,
+                0x4bb3f60d9514125b,
+                0xcf247b5aa186733d,
+                0xe662a508ee7263f3,
+                0x978b16314b450013,
+                0xfb35f4e3334e3ec3,
+                0x6a2985def6663021,
+                0x7b7c4cb2cad9a848,
+                0x966e488fc234be1f,
+                0x3649076de673d5e0,
+                0x19b1aec463e73d09,
+                0x00cf02d46613ae74,
+            ])),
+            Felt::new(BigInteger768([
+                0x2e3cfd3860822a3d,
+                0xe7e3fc2c68ca0bdb,
+                0xc39264532e0f740a,
+                0x5e5e1d93508b852f,
+                0x1dc12a78ed39361f,
+                0x13676e3c278ebd2e,
+                0x8ad85aec46b8ee90,
+                0xeea4d047824b6756,
+                0x42bad2f7123c5b8a,
+                0xdc4bc24c3ccd2110,
+                0x062fc088800bd80d,
+                0x00cddfb19056f7c4,
+            ])),
+            Felt::new(BigInteger768([
+                0x87ec897df223ae7d,
+                0xa4885a1ecb929dd3,
+                0xad1bc2b77df115ee,
+                0x4824439ce64d09b2,
+                0x9f8ea087947ed7f3,
+                0x3fce303954f5cd26,
+                0xc0a4ba8045e60b9f,
+                0x8a839f2976b52a0c,
+                0x59045bc964816b3c,
+                0x34a658cde993f8bd,
+                0x248debac02fdc574,
+                0x00143686a2239c8c,
+            ])),
+            Felt::new(BigInteger768([
+                0x81e1f4a9932123f4,
+                0xd7e158d6bc07db3b,
+                0x087386b25b87cf82,
+                0xf447ee02eaf9164f,
+                0x496f7f3c0b18046b,
+                0xcd24c11e304db078,
+                0x8e2ab149a94aa750,
+                0x195c75bce4f7e612,
+                0xf697228dcee240e9,
+                0x36a79670c27ff024,
+                0xada2d8a5d5d60543,
+                0x007adf9d365e6af2,
+            ])),
+            Felt::new(BigInteger768([
+                0xb6513a3dc49df5e2,
+                0xdf5fe2a50fc839c0,
+                0x11fab6894b5bdf1d,
+                0x4881274eb29e7e82,
+                0xf7a6318f6eb41962,
+                0x68a84a39385bab22,
+                0x517f4f8f0ee4cc13,
+                0x98d78897d6af7221,
+                0xde027536bce2a8c1,
+                0xb0b890fd96923dbb,
+                0x06c965e88c7b7b70,
+                0x007579e2ad7650f9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x3f62cf1488a7e9bb,
+                0x19a04c724474d54d,
+                0xd9b3f060ba00484d,
+                0xe9525d864123e174,
+                0x9a316df1de9e8bdd,
+                0x7ed670fc28856c24,
+                0x3197a22ac3046d18,
+                0x9e402acda7c0b5ff,
+                0x8c5e5527c0b263cb,
+                0x06ec211791ce9bb2,
+                0x6dccac46aacc4f68,
+                0x000e123bca54b5c9,
+            ])),
+            Felt::new(BigInteger768([
+                0xd40608979e2b201c,
+                0xb7db88397afd1902,
+                0xb753c7ae06b1132d,
+                0x386e8b1900afb53e,
+                0x910a8daaa3fabe73,
+                0x7babfe34908bc63c,
+                0x177252c3efe3f71b,
+                0x09bba6be4bf31683,
+                0xbbc91797fee09ab1,
+                0x5f2fe8d00727d16f,
+                0x917b5f0c53b0d313,
+                0x004b0dbea4d168d7,
+            ])),
+            Felt::new(BigInteger768([
+                0x0e7a88be0c690ee8,
+                0xfbc0bfab1b1bcc47,
+                0x06e6448401e1ae28,
+                0xf2bd9f09ed77bf7b,
+                0x6d82736e77822001,
+                0x8473a45784acee36,
+                0x6a0223b1f34df18a,
+                0x50766f7b4e60010f,
+                0xeabcff64379a17c6,
+                0x3caac0e001bcdf87,
+                0x8275942faa872c18,
+                0x011cc0d430f69928,
+            ])),
+            Felt::new(BigInteger768([
+                0x72d05f76316ad6f2,
+                0x0f5e32adf92ed3b8,
+                0xc1a779f2511cc3b6,
+                0x55b306a243604b0b,
+                0xc04c95d0516e630f,
+                0x253c912e53649e79,
+                0x4d4a877d6d4cfd8c,
+                0x388f9e93b8cf961e,
+                0x4d057bb59b0ba3ac,
+                0x9c1bbf54a75d310b,
+                0x2d0b9b4eaf5335ad,
+                0x000eab9a5e03e608,
+            ])),
+            Felt::new(BigInteger768([
+                0xc311362ed6d275a0,
+                0x43f688a8c216e52a,
+                0x6b0580fdc745b148,
+                0xe33311992147f033,
+                0xb3e1e0abf8ca2460,
+                0x2ef141c0bc88f73f,
+                0x911ec1068c05f865,
+                0x18d1ff6e107ec501,
+                0x468f293b232838c6,
+                0xdd42ea4afb8bfe71,
+                0x966464330d1f3626,
+                0x00a50fa47c4c9274,
+            ])),
+            Felt::new(BigInteger768([
+                0x128b6f0727ba2125,
+                0xfe3bf5c2342cf989,
+                0xdba7c3ab7a9ff40a,
+                0x1fe0c4a4933c7162,
+                0xae1cdbcc16eadf81,
+                0x1afbed3d1fdbf54f,
+                0xc0de5ead4bdddea0,
+                0xd28147bec62031d7,
+                0x5560b59ff348433f,
+                0xacb9f17d2471a34f,
+                0xba30260ae37b60f5,
+                0x00363812e02bd0d9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x1d5141ac2464c50d,
+                0xa82bd964d80cd7be,
+                0xcda14b9a5151daf3,
+                0xb2f252d15d61c895,
+                0xb15051366d9f8102,
+                0x52bb7bdd8184233e,
+                0x6f7bd93a6cf606ca,
+                0xc8212eb82c5343e6,
+                0x3245a7b5d70522b6,
+                0x0c22a57dab1a3521,
+                0x18d85840018c1d77,
+                0x003c5fecf3af38c9,
+            ])),
+            Felt::new(BigInteger768([
+                0x2aae925cb11ce0d4,
+                0x3d46ded16a23df8f,
+                0x144f1310fedb488e,
+                0x2427a124c3d5f284,
+                0x3149f77119ee7759,
+                0xf517d65d686053c7,
+                0x74072f3b3f7a3145,
+                0xb09f0a600dedb223,
+                0xbdb76ef47d7a48ee,
+                0x72471bdc18ff69ea,
+                0xeab714b69ab4cac9,
+                0x006fb8383f47ccb0,
+            ])),
+            Felt::new(BigInteger768([
+                0xf573336e932c61e6,
+                0x0fc6ee1366efac5f,
+                0xcdf3099af03a4c4b,
+                0x4fb2466345ecae16,
+                0xce2e4e3be7c1744b,
+                0xfb6847981e4621a5,
+                0xe77cf7b0fa20157b,
+                0x25038ab2918565cc,
+                0x01c3eaa2e5cacfc0,
+                0xf94063ef07931e31,
+                0x6a261ca6946fa446,
+                0x004b056071f30c33,
+            ])),
+            Felt::new(BigInteger768([
+                0xdfda029cf22ff297,
+                0x065361baad760882,
+                0x2daeef22d21f50eb,
+                0x097e690d303b3461,
+                0x485055709947ad33,
+                0x399da96fcff66926,
+                0x194238c9870563e9,
+                0x0417d2cf65ead2ba,
+                0x236469f459705fea,
+                0xf1c79a0f2c71a074,
+                0xf5ff19a52ce14eea,
+                0x0103346e9f7ef1d6,
+            ])),
+            Felt::new(BigInteger768([
+                0x6322bda7349ae521,
+                0xfc40ce76be6f7e52,
+                0x4142397e8d07ce1d,
+                0xb6f25514add708ca,
+                0xd6c1f56c4b4c55ed,
+                0xabf420b365577445,
+                0x2ec11827155507c7,
+                0xdd68a7041e74b761,
+                0x59d89ce09a39451f,
+                0x28bc5f838e0529c5,
+                0x04a1e3631fcc5cd6,
+                0x00872831b316c27a,
+            ])),
+            Felt::new(BigInteger768([
+                0xb37ea469c801b8fa,
+                0xe6842fa83580db5a,
+                0x03882f91a30cbe8a,
+                0x2fa703b425e7e26f,
+                0x1a92caaaa7f4434b,
+                0x991a1755abb0d236,
+                0xb65114b1316f3f43,
+                0x972a30a4594154d5,
+                0x4e9bd6a27586e1d1,
+                0x487d295b3bd4b128,
+                0x87b1dbde909fde95,
+                0x00467dd9bbe2ed1f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xc5a8f4126963597f,
+                0x50f4df4be15705b5,
+                0x3f00187016a0f23b,
+                0x8f47089cb056b1b2,
+                0x37cdf8e2c68074c8,
+                0x6a270aa596d27b24,
+                0x5abafc2d53adddc3,
+                0x98d7050c449e2baa,
+                0xb85480a9d4a350bb,
+                0x823ca95050bac732,
+                0x8790017ccb3ddeab,
+                0x01111d1112d14259,
+            ])),
+            Felt::new(BigInteger768([
+                0xd877a94704cb244b,
+                0x01d2439508034f80,
+                0x22e365212d27f669,
+                0x8cd2921787373be3,
+                0x42c02f3b75f80a84,
+                0x6c4581db045fc87e,
+                0x66729555c829d479,
+                0x6207d1a46c4c1a61,
+                0xb2d97fcbace17e0a,
+                0xe2c3c2ecc3389b6d,
+                0x735c7759ef1467d5,
+                0x00a2cba3ad6029a1,
+            ])),
+            Felt::new(BigInteger768([
+                0x5c706dc799d61cc6,
+                0xa6ff07e3e5ba0bd0,
+                0x7d06e0aab4fab8cf,
+                0xf03d77fb464badb3,
+                0x26677e6b3b3308a1,
+                0x47e483cb742c1095,
+                0x3757b85aa87f8262,
+                0x3b17c81949ea1006,
+                0xb3fda370f11cc739,
+                0x173f29c948c4aff1,
+                0xab2a76f93a2d0c87,
+                0x00f7956c6337e94a,
+            ])),
+            Felt::new(BigInteger768([
+                0xaa01b6a5d026e6c4,
+                0x6dda0ec7d41afdcf,
+                0x6ec65e636b156356,
+                0x582f5a8bad9ad4c8,
+                0xe2a2952ad96a17f8,
+                0x148daebc931036e6,
+                0x74fad53123839073,
+                0x552e19afa62932ca,
+                0xc313884c54358d08,
+                0x14218d4b84421710,
+                0x6feec3b5ea86d072,
+                0x00531328bd89fc32,
+            ])),
+            Felt::new(BigInteger768([
+                0xdda3e66c3401a6c9,
+                0xfc66d5889ff4a7ac,
+                0x8fe8dc8565672e61,
+                0x5f9ea24d8ff36be1,
+                0x797078a744b1ae39,
+                0x088ca888cd839252,
+                0x7ad01bfac638f29c,
+                0x14428eb573d10677,
+                0xc62be0760c354b50,
+                0xb684f53a0899d5e0,
+                0xef5106b0c12d34f0,
+                0x00427e748e1ecd80,
+            ])),
+            Felt::new(BigInteger768([
+                0xeb6565fa575c07e1,
+                0x0a3bfd68e40229a6,
+                0x9d2043519d8488aa,
+                0x4637eaf86c3a777c,
+                0x9f708c88c6a8c476,
+                0x0113420721eb147f,
+                0x1dbf272e04863cae,
+                0xcf399407b68bee47,
+                0x0b8f69c4cfb17456,
+                0x85b1b61bd03231e3,
+                0x3c00bb972ad0fe24,
+                0x010d5f26df0d92d0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x4d92ec92fdf8cf2c,
+                0xc77d55fde3bdf03f,
+                0x0f883f87cfbbc477,
+                0x251f83615ea64b3e,
+                0x2647b1f9bf486f9e,
+                0xbfc956ce0273e9f8,
+                0x6b57863ec1367826,
+                0x8d2a27cd116b345a,
+                0x1c84863bb41106b4,
+                0x7e40fbb9b2aaf05c,
+                0xfa5b24e114912435,
+                0x005557ac30b33842,
+            ])),
+            Felt::new(BigInteger768([
+                0x94259664c30d209e,
+                0x4e47002c04eadac9,
+                0xe28f89e1b2bd4b8c,
+                0x54206e8b5de4ad69,
+                0xbb544ed7649b4ee6,
+                0x169f268591745a8f,
+                0x9765b18ed1163bc0,
+                0x3fb1b76a8ba33049,
+                0x6e9e167d6e5e5350,
+                0xf72b23f7e67d603f,
+                0x82f78206a0e8accd,
+                0x00948e84b263414e,
+            ])),
+            Felt::new(BigInteger768([
+                0x9b39f04bbb8369f9,
+                0x8705464d182b4a92,
+                0x8d872ec67f7d0fbf,
+                0xd331342775ddd768,
+                0x99fdd1cac0372333,
+                0xe23acc595ef116bf,
+                0x11d07fd88dd9efba,
+                0x6f3a37f19c1c5503,
+                0xf94e4b15ac416c91,
+                0x242c9aac9ace1b67,
+                0x54d3880827b1cb2d,
+                0x0032f9c1a81edc20,
+            ])),
+            Felt::new(BigInteger768([
+                0x49f35e85f67d6049,
+                0x21507e8904fb3967,
+                0x8e7c47313172b1f7,
+                0xa2e0bc8064c26b03,
+                0x8998fa8a8b8284d9,
+                0x4e8e2540e3ba355a,
+                0xb234b71922f5a5c0,
+                0xd649912ed7b97a66,
+                0x82f6599efe4a06cd,
+                0xf5998b64c3232fe6,
+                0x165283d62a562810,
+                0x00520e8ff68009a7,
+            ])),
+            Felt::new(BigInteger768([
+                0x16f70f53038fd681,
+                0x7a5ea56702bdb57b,
+                0x3957614e4b99a240,
+                0x2c3ed6b78dc6af33,
+                0xfaf64ae54692091b,
+                0x6d095734bdfa868c,
+                0x5a73a7d4fddc3292,
+                0x2a52f1e08681dce9,
+                0xa3cf7980d755ed3b,
+                0xd5108915d18f37a8,
+                0x4ef43d4918b0c57d,
+                0x0107efeff08b4b41,
+            ])),
+            Felt::new(BigInteger768([
+                0x2c4cdf83ee4f5563,
+                0xc63996f9d5c5c30a,
+                0xfa76c04cad308bfb,
+                0x49956b1ac36f3b4b,
+                0x3c49f9983c4c0ce0,
+                0x6702bc40ff580ea7,
+                0xc465baf27a84f7ae,
+                0xdec1f1290d3beda2,
+                0xf6c6d14cdbb6c815,
+                0x3916b623981477bb,
+                0xe2f700e457a00dfe,
+                0x00c03f96a6324217,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x95099507470ea2c3,
+                0x0a28a39fb423371f,
+                0x96f831a7c9b5999a,
+                0x836ad474e464e146,
+                0x1dfe0d5dade1747b,
+                0x847ce3b2f4f94018,
+                0x92220f7ce8ea2a79,
+                0xcae7fcdf542261f1,
+                0xc5e9cb7524748db2,
+                0x11a881554537f3c9,
+                0x12ab5abcc7b149ed,
+                0x007264bee326c7ea,
+            ])),
+            Felt::new(BigInteger768([
+                0xe3cb1e897578fd90,
+                0x8e9ea8be1d61aa45,
+                0x0053686de7d4c984,
+                0x7dc7cd48e90c78ff,
+                0x0d8c20ada5a0984a,
+                0x58731796836bfb20,
+                0x8ab5d02d514b9e43,
+                0xf63dc14ee7680cca,
+                0x39fae560394abc63,
+                0x84b1c092cc7baf6c,
+                0x7548baf3b9777ee6,
+                0x002a81de8c94f25b,
+            ])),
+            Felt::new(BigInteger768([
+                0x58ef5979b25e6258,
+                0xf08598eece83aef8,
+                0xb0eca8aa02814044,
+                0xf0530226d8905389,
+                0x50e1041c2fa21357,
+                0xd50f098300e7619d,
+                0x7e8c34ea5b9e0d26,
+                0xc0cccc02c0a61869,
+                0x7505ee40f6cc80ac,
+                0x32da36a18226097f,
+                0x13d64e89d0071593,
+                0x0048e6435d390f20,
+            ])),
+            Felt::new(BigInteger768([
+                0xc612f7839101ffdd,
+                0x548729962c28c708,
+                0xe657985d6c59d728,
+                0x87ba1840654b70cd,
+                0xc3516d94d02639e5,
+                0x75a092b41bd0e447,
+                0x4696bfd83c00d99c,
+                0xd3ca082a46c577f1,
+                0x8b1abdfa83ce1a8e,
+                0x83c3829f84959485,
+                0xab861f26106d6a52,
+                0x0043607c1db8d16b,
+            ])),
+            Felt::new(BigInteger768([
+                0xf8423cc69dbef23a,
+                0x2e2f17b6be653d55,
+                0x14a6d4d6b00e2589,
+                0x261f418369153aec,
+                0xcc0ef3635f008495,
+                0x17aceb71e10f2b1e,
+                0x9077719d5f5dec10,
+                0xdd2de9bbc96bba51,
+                0x5943eb90b94a4dff,
+                0xf0652a98c83aff22,
+                0xe57f6fca52cd4be7,
+                0x007b302b3519fd56,
+            ])),
+            Felt::new(BigInteger768([
+                0x6371f5c43b8a343c,
+                0x9ace0f46581c0078,
+                0x9faf26138fbecccc,
+                0xe42afb34e542033d,
+                0x249aea98aa0ffe6d,
+                0xd13fe5ea3f26fedd,
+                0x4c0f5afb02cf58cb,
+                0xa4b12d7871da9230,
+                0x95a87bb42a4345df,
+                0xb78c30ee0f4121b2,
+                0xa32d7cf5dc2e43e6,
+                0x00a49f98d2446865,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x7a10be03c8ae9ef3,
+                0xb346e46c57726015,
+                0x3929cea3cc958603,
+                0x672c45fae7f4ec43,
+                0xae7bcc2b95e2f1f5,
+                0xbe8c0e060ee0f022,
+                0x1dd76df6dad23791,
+                0x1d4a81caaf8030b8,
+                0x6f8ffd1e17bc1dd5,
+                0xfa52a7bdc7d3bf67,
+                0x1cf70d62e88e0f7e,
+                0x00f7b446c8cb6066,
+            ])),
+            Felt::new(BigInteger768([
+                0x95173a3f7424cef7,
+                0xde3937222d327be0,
+                0xc9b214932bf9ed9e,
+                0xc653addd42088d4f,
+                0x6986a4ceeedfe089,
+                0xfdf27f2089ee55f1,
+                0xe65e54bc96a8cef7,
+                0x00df53275c949a7d,
+                0x4b21fdb4ffe07780,
+                0xfa28dd8009af4e26,
+                0x86b5a691102138b5,
+                0x001a9b25d5c3bc64,
+            ])),
+            Felt::new(BigInteger768([
+                0x14f81a4e33c62062,
+                0xa8665fff2368a364,
+                0x8719f60e823f7f96,
+                0xd7cc0eb279bbc81b,
+                0x792e2b61d9571586,
+                0xba522c30bc080052,
+                0x13237e0639149799,
+                0x34538da306dd171d,
+                0xde7f8e3fe10109a2,
+                0x1dc13337da643c5f,
+                0x71f5bcde091f2762,
+                0x0069ad35c85c722b,
+            ])),
+            Felt::new(BigInteger768([
+                0xa9914353874cdef9,
+                0x06bf8e5236e95e12,
+                0x96a8bf414f38a366,
+                0x867d4331d17818a3,
+                0x370d6a51d23f3ec2,
+                0xc477cdc0b90a490a,
+                0xde9387b56f71129a,
+                0x47e12b22c902cd69,
+                0xfdfff7cfc25fa6a8,
+                0x4e68e923a8cd71e8,
+                0x439b79204b365dec,
+                0x00a408a1e72de48c,
+            ])),
+            Felt::new(BigInteger768([
+                0x6f58a4610f58111f,
+                0x858fcc4f3ebcbf39,
+                0xaaf5a90b9a8e45d5,
+                0x0e9987e84c719eec,
+                0x1a546b09c8c934ef,
+                0x89e019f3e32c0489,
+                0xb9b7682ff39ed173,
+                0x3ddc05ff4fc23c41,
+                0x8b66c22c06af8d9d,
+                0x9c03d6b6d05a1760,
+                0x5292951f1a9d69fc,
+                0x00fdcd64750e6c26,
+            ])),
+            Felt::new(BigInteger768([
+                0x40c4c21b865b8871,
+                0xc536c61ec6e99cfb,
+                0xeb55e3102605738f,
+                0xbb48f79ba78c1442,
+                0x7e4cacc6115378cc,
+                0x4175d3a2f679b2fd,
+                0x60d029609b008ec7,
+                0xe1be5b3afbe875d0,
+                0xb357e9fd2fe3e397,
+                0x854b8727d3cd6a26,
+                0x3ead9b38689bf20b,
+                0x004bf23b36c48890,
+            ])),
+            Felt::new(BigInteger768([
+                0xdfbd925b83cc3d14,
+                0x6afa7f56b392ddb5,
+                0xd85e06284cdcf63b,
+                0x24ad4ecac273b799,
+                0x2c50104fdd96029e,
+                0xda9301d0beb69506,
+                0x3fc1850ddf5fdbf9,
+                0xd1b043018970d96b,
+                0x157f84e69ce745d7,
+                0x51b1044f88a5c033,
+                0xaac5641391f89e07,
+                0x0076a4727375f52a,
+            ])),
+            Felt::new(BigInteger768([
+                0x48c9d3bf82b15124,
+                0x2e99bcfab0692b41,
+                0xe7a256fd25f2bdf3,
+                0x01c44cca2e512c14,
+                0x926c5a60ad3eeb81,
+                0xd682b3dbb97ef848,
+                0xb78368e103715a6e,
+                0x3c5d3907040530cd,
+                0xe130b727f0e97a05,
+                0xbf34dd3914535fa0,
+                0xe4d0ce2fb3f29ee4,
+                0x005d443d2a4da178,
+            ])),
+            Felt::new(BigInteger768([
+                0x313fda3c99e4bc99,
+                0x5f299223eab59f0c,
+                0x53f47f532c78668c,
+                0x1849e85aa65a2c69,
+                0xe06efcc9f33ee0ab,
+                0x0b658385b6976289,
+                0x908de1484b9351f5,
+                0xafc444e0fa22a642,
+                0xe7c233b12dfa2564,
+                0xbbe98f730a469ea8,
+                0xb16cc15c79054033,
+                0x00c04261b0685058,
+            ])),
+            Felt::new(BigInteger768([
+                0x6ecf17385fa207ea,
+                0xf359c8e9d1f0cd4f,
+                0xdb6fbb2758a34263,
+                0xd71571fbb0daa7f3,
+                0x168f6a6e018b8d9e,
+                0x05ac595369419b4e,
+                0xadd9c92fb9e396ce,
+                0x0761e9716bb951a1,
+                0x8555782fd4432db3,
+                0x3f8055d396c1c686,
+                0x687c967e4e366895,
+                0x0043506105ce4712,
+            ])),
+            Felt::new(BigInteger768([
+                0x36e628d243f8cb4c,
+                0x6a1cc3548983c426,
+                0x666ad51c4b9c069b,
+                0x5fb9d25e58095ec5,
+                0xd7fe576d4ccbab56,
+                0x749f5e5aa9543a5c,
+                0x98a2120fdb37c4ad,
+                0x5af0afbf48903637,
+                0xfa5e63b5d19fc5dc,
+                0x019e18f52c29785b,
+                0x2584531d33a921f3,
+                0x00c44cef8b71e7f6,
+            ])),
+            Felt::new(BigInteger768([
+                0xef0cbdefdce8bd5d,
+                0x97050ada327265df,
+                0x03959ded486b2a24,
+                0x2c7dfb4e304dea22,
+                0x9ed7e54cf0e04674,
+                0xc6bb27455bfd815a,
+                0xdb1c25c0801a4386,
+                0x67aa24403398e5bc,
+                0xefcf5877f36e151c,
+                0xf55af483d820c097,
+                0xbfd8891069a7a9f2,
+                0x010b77b001fcf13c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x777c6400451781d1,
+                0xbf17399c72eda2d3,
+                0xfb42467c58d3952f,
+                0x521eb3cdf00d4a6b,
+                0xc1000d6eb881241e,
+                0xe6bfd5bff71c1ed2,
+                0xf72c8524c1eedbd5,
+                0xfb628f4b8fe20910,
+                0xb319649da26ba327,
+                0xc2310d67ed802765,
+                0x350d315bd1f1b4d9,
+                0x010f7170c85069ef,
+            ])),
+            Felt::new(BigInteger768([
+                0xab92de72aa1b7af0,
+                0x47da1677a3e5f28b,
+                0x2301701ddbcfb586,
+                0x21e9c089854fe478,
+                0x01c20adb7edbd6d5,
+                0x82937231d95d3135,
+                0x104c9dbf26b171f8,
+                0x1cb307841410b244,
+                0xd3ceeb7c5fdb771b,
+                0x386cbfb587ce8c90,
+                0xf0b1a7526ec28b3f,
+                0x00d1b880238a6a79,
+            ])),
+            Felt::new(BigInteger768([
+                0xe6ba6b1d235a54ec,
+                0x13f4db7e572ab506,
+                0xf2c2cd41597771fa,
+                0x1d8c5295b8955b04,
+                0xe8aeb3e91c7407f2,
+                0x256945233770c809,
+                0x297e235b9b8a470c,
+                0x6ce066ff12e0264a,
+                0x24600d0bfc73ee91,
+                0xc8e1f7eeb9ac51d1,
+                0x3288a2c1827ebd4d,
+                0x003bac7a58c4cb77,
+            ])),
+            Felt::new(BigInteger768([
+                0xdff9263957822b09,
+                0x5447f3333ade8cc4,
+                0x3b5b1cd013f8f4a0,
+                0x353f57ee94769135,
+                0x21eeb64317a3daab,
+                0x3881ee132dad7182,
+                0xe0c3b103d4537995,
+                0x56358c14d6dc29ed,
+                0x6cc924831cb3d03f,
+                0x869017c97b606cec,
+                0x855c29d8ac3fd153,
+                0x002f3badb9ff1f8a,
+            ])),
+            Felt::new(BigInteger768([
+                0x588f7176355d5d94,
+                0x060094a1117387ac,
+                0xf82fdd4231b24c59,
+                0x0d900eace619f9fd,
+                0x56b1d82c41b9fa39,
+                0xb031f47ca9f84794,
+                0xa9ce396ef8258b51,
+                0xe6b3ea37d84243a1,
+                0x2e2378d06adf322f,
+                0x077b5cb7ce4a645f,
+                0x111b9459bb8efb63,
+                0x00c1149e2b048160,
+            ])),
+            Felt::new(BigInteger768([
+                0x0beb64e7d26e161a,
+                0xaf663ac7b770eca5,
+                0x9b210d5b8b2f9f82,
+                0xd20d2b6f256e08b4,
+                0xb1c429982fda2575,
+                0x75a05ba24c237f02,
+                0xa65614aa4e8b49c8,
+                0x5ae52afa6bd6a0f9,
+                0xfb6733914b76b73f,
+                0xd29bd01d5d1ac21f,
+                0xb26e0a8b2cf0012b,
+                0x010db58529c8c876,
+            ])),
+            Felt::new(BigInteger768([
+                0x196d4dc5f291788b,
+                0x5e66635286a4dfb2,
+                0x9c982e54e6631c57,
+                0x79ef70715a7dc70c,
+                0x28f4a0d9c5ae2a11,
+                0x68bbc017dbbede0b,
+                0x8c696cab33e8470c,
+                0x9fffa438bd2ec5cc,
+                0xc6638e809d59eb2f,
+                0xff2e1f430b191af3,
+                0x9368b46a754e2dca,
+                0x00cad53171883956,
+            ])),
+            Felt::new(BigInteger768([
+                0x2376f438ea0f0238,
+                0x0d70c14652f397f2,
+                0x9bf721528fe6dc4e,
+                0x963a8754957051e5,
+                0xd8e722d327b25411,
+                0xddf546c8bf3141d8,
+                0x4ea6ecbdadf0ef6e,
+                0xb7c5fbc457148cb7,
+                0x76c3dd3f27c78396,
+                0xa3d47bda2a65a818,
+                0x7202d89174514a77,
+                0x0009c47e1114ee5d,
+            ])),
+            Felt::new(BigInteger768([
+                0x91244ce3ddc26974,
+                0x0277cd78e26ee573,
+                0x9962a17042a36d68,
+                0xcf6ddee77c1a79cb,
+                0xb977324c979bbed6,
+                0xef2fdd21914965f2,
+                0x9b2b897e0c6ceacb,
+                0x02f65e6ec6e1765c,
+                0xdcf2842bac2f5460,
+                0x12eeb03bde9d1e28,
+                0xf5f8a6844fbffc84,
+                0x0034400d99352b44,
+            ])),
+            Felt::new(BigInteger768([
+                0x9a0f4c9f7bc47001,
+                0x0b4b36e1f87ccd8b,
+                0x1ae3af811286cd17,
+                0x397219314a592041,
+                0xc5ecf5ddd026844a,
+                0xc2467af5c170a033,
+                0xdd40270376ff87fb,
+                0xfbfc7e3e056f74d2,
+                0xee01ef539ea6b041,
+                0xd5b117b03cad60f1,
+                0xd146c02dc9835709,
+                0x0003e4c1f5dc6d5f,
+            ])),
+            Felt::new(BigInteger768([
+                0xc08bb14c62671849,
+                0xf3eb9bf32d3f7b52,
+                0xe3dc23a626e9e621,
+                0xdae31becff8c04e4,
+                0xc3ed6c6103d1b677,
+                0xe6badfba3d470081,
+                0x6ddff3fa8a3d4ac0,
+                0xef4c255567e5feee,
+                0x8a350189501fd5a5,
+                0x480ff4c9809f6094,
+                0x75959b2741478c2e,
+                0x010a0bab08d098ca,
+            ])),
+            Felt::new(BigInteger768([
+                0xb30a6a4b0d41e585,
+                0xd29a841fc3dbe725,
+                0x55e5ed2f85c36f1c,
+                0x4bf64e14f995166f,
+                0x2a9d1ae7874360a3,
+                0x1754b39ad2dd6da1,
+                0x63f267630d6a276f,
+                0x36d05ab83ce2c072,
+                0x0195eb0015bc4fc5,
+                0xdfd9c0d83768f790,
+                0xe7aa79167b3f475e,
+                0x00a25a6e2182d11c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x7f6b3ecdfd4e5fd6,
+                0xbadceb68b5b8494a,
+                0x8aeff400e4602026,
+                0x2e305f6d5728f764,
+                0x8d37c52ac7efc94a,
+                0xf8c6516d3ca5cf24,
+                0xb973e3810e698e2c,
+                0x426c323839e45387,
+                0x73336c8473293f26,
+                0x15f4c973f2f36e02,
+                0x384a45df165a11e2,
+                0x00f452aa0ad6abe6,
+            ])),
+            Felt::new(BigInteger768([
+                0x2946b6c1f1ada4dc,
+                0x7bcc87187ad19033,
+                0xe89038ae51ee996f,
+                0x5fe387bb66140735,
+                0xca013620dafd48fd,
+                0x3796d629920ae4f3,
+                0x13d7da374304069b,
+                0x47243541fd7cef42,
+                0x965ef77b56fc7f4d,
+                0x0a8b6643dc9d67e6,
+                0x076a6759d1985a98,
+                0x00b3f2c9956b2663,
+            ])),
+            Felt::new(BigInteger768([
+                0x46d37222e8fc950d,
+                0x1b898e8489c08141,
+                0x7fd714abb881b357,
+                0xb576b5696803485c,
+                0xce32c1d2fc94254b,
+                0xeda176f45685798e,
+                0x17bca6f08c0c5d15,
+                0x4247e04a8b9389c9,
+                0x845c18f24a41ff00,
+                0xc4c09fce0912b62b,
+                0xbd843924235d6fc7,
+                0x00295dbb01572875,
+            ])),
+            Felt::new(BigInteger768([
+                0xd512679a435a5127,
+                0xc4db6e08a563bc0a,
+                0xe1f2eb3d180c8093,
+                0xe7ee7e073e1e6b65,
+                0x920b21d336948fee,
+                0xfebd379f6034bef9,
+                0xb45cb6d1a461a95b,
+                0x835e72ac35c38a70,
+                0x4ecca2f8d0fdcd9f,
+                0x2d1c6717d6f94c3d,
+                0x9ad27e57a9695fdb,
+                0x005124f85cc064c2,
+            ])),
+            Felt::new(BigInteger768([
+                0xecc212feadc980ac,
+                0x57e6c9134fd93d0e,
+                0xf08daf8c64fce16a,
+                0x2556a10426ae60b2,
+                0xca8de94a0d3cace9,
+                0x2400c066ab0d93b9,
+                0x45d6c074568a7cde,
+                0x8f8faf9887185043,
+                0xd1318dfd22d2c362,
+                0x3ea3334710218fae,
+                0xc30ed7029606480f,
+                0x008b416b41917da9,
+            ])),
+            Felt::new(BigInteger768([
+                0xee0a467540c9a663,
+                0xa102c0ec5c9c016f,
+                0x9ef4c80580b4c2a9,
+                0xab98939230a8d07c,
+                0x7be551e2f04cbb72,
+                0xaa5fed2566377622,
+                0xe03e0e3677861224,
+                0x56227dcfc2195b9d,
+                0x576ae4a95d815bc2,
+                0xe58ecdce0366c146,
+                0xa3acb5638ceca008,
+                0x000b9f9b59973fc4,
+            ])),
+            Felt::new(BigInteger768([
+                0xb892e13f2b8b8ca7,
+                0x942a90ab4d3375a5,
+                0xecae40c9bed22c42,
+                0x56928a5fa869cc6d,
+                0xdbdd429317c035c3,
+                0xfc37524f106493ba,
+                0xe4b01f4d497ed793,
+                0xead3bbf4adb3b14f,
+                0x403a5a1ba9860e56,
+                0x6aab557f1a8839ca,
+                0x59f2748acea46738,
+                0x00f61f12339cfb82,
+            ])),
+            Felt::new(BigInteger768([
+                0xe8fbd1ad0a919fee,
+                0x2713b2d66812c98c,
+                0x75a1d9fe5b72494e,
+                0xf34ad77baa9dbe63,
+                0xa7a6a2fa42a26821,
+                0x44d8c8c43df29a01,
+                0x4fae6852155590d8,
+                0xc0148310175b545c,
+                0xc59fb254d3cd32f1,
+                0xfed62fc8a64fe59e,
+                0x7b4c3d4b74cfa287,
+                0x00743564015bed36,
+            ])),
+            Felt::new(BigInteger768([
+                0xb668a80d670917a0,
+                0xbd277a5416a7ffae,
+                0x6eaf5422ba2a141c,
+                0x68c9436b0ac44b6a,
+                0xa5683e80c1dd2a16,
+                0xf58e2ba8fb2f4708,
+                0xce94aa3a4e149d83,
+                0xefeaffdededf3919,
+                0x2ebc896a51123c00,
+                0xbc8b033cec76d0c3,
+                0x9d18ebcfced0e167,
+                0x0009992e26612bcc,
+            ])),
+            Felt::new(BigInteger768([
+                0xa39e32bb3c474b70,
+                0xe14ba32aa0718f04,
+                0xa31dbf03df0bca01,
+                0x5b32f69465cbae06,
+                0x6e32c52af33e2897,
+                0x8eb38bd269a119d6,
+                0x967f9c6a73b4aa48,
+                0x099c1bae5b525bdc,
+                0x6f275c9a024ecf12,
+                0x03115b6fa8b342b4,
+                0x7377282c7f704dc0,
+                0x0116c65d7955dce0,
+            ])),
+            Felt::new(BigInteger768([
+                0xd03a297077b43446,
+                0x8bcf3ed3d79e2427,
+                0xf62d60ec1266661c,
+                0x94b05d608d0c535b,
+                0xc3cd8bcd9ac82728,
+                0x9d6d1c3000b6c8de,
+                0x01c683c616899996,
+                0xb6f8096f741039b4,
+                0xb94374ad36c3713e,
+                0xefca0650e1a9edc2,
+                0xa7e9be7567530245,
+                0x0036208143bb01cc,
+            ])),
+            Felt::new(BigInteger768([
+                0x58303bf4948a5b94,
+                0xa4a01b21d387a641,
+                0x67a181bdefe8fa1b,
+                0x1af37c5a4c12b2d8,
+                0x1b62c726990a7be4,
+                0xa87125dd194406cb,
+                0xab9216436850b8a4,
+                0x04eed0c43c2594cd,
+                0x5fbcc8b4b02bc99f,
+                0x0ffa0c7ba674d84d,
+                0x13154c67a407cc5d,
+                0x009e826464ac39a6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xf12421f313a6a005,
+                0x26d15d1bf6704e0f,
+                0xcf3912cbe2838c80,
+                0x1e00413f96ee8618,
+                0x63e3760dd7bf94e3,
+                0xf7d6a8d82646da36,
+                0x0031742cbbc2b8e4,
+                0xf34623863acca510,
+                0xd93402bec4aec2dc,
+                0x22fda0dd80a34288,
+                0xe154bc3bb724202a,
+                0x00f6804bc96b03d9,
+            ])),
+            Felt::new(BigInteger768([
+                0xfe76502f2b9aa2e3,
+                0xdbfa4bd7f574d878,
+                0x378eb7412e7f81ce,
+                0x4f841907045b1295,
+                0x397ba1a7f220629a,
+                0x69312fd8c49f5904,
+                0x6db96a27dd2d7085,
+                0x5330c611bfe372ec,
+                0x50e4f97e838db7a4,
+                0x7982b6c42b51916e,
+                0xeaabe85a7d51a680,
+                0x00da9d7d6ac77c1a,
+            ])),
+            Felt::new(BigInteger768([
+                0xd99209e4c4b907e4,
+                0x934914b25f73784e,
+                0xe8222423601a2d32,
+                0xef449b7b4ee199cb,
+                0xfa31ef8cb03e4518,
+                0xc18c499f551984e8,
+                0x960fe060f960c4c6,
+                0xf5d8acf8a4020358,
+                0xf86a86f59b1f4fdb,
+                0x8a6dcb61a2d364e7,
+                0xdade264059893b18,
+                0x007ed5e10917c411,
+            ])),
+            Felt::new(BigInteger768([
+                0xc1d126ca5b3d78e3,
+                0x57c854f12d83d22c,
+                0xa11e8a2a12adeebb,
+                0x0f4bdcd52ada0216,
+                0x12438cedfb65ad6f,
+                0x94e1541b3469958b,
+                0x9424653d6451dbbe,
+                0x1988885295ab5b75,
+                0x0a021c82b3aaa375,
+                0x33e2b1904d8013fa,
+                0x40d65c8a882d57f7,
+                0x010759e0664d7525,
+            ])),
+            Felt::new(BigInteger768([
+                0xf04f56a3b8586233,
+                0x80a497f45b3af21d,
+                0x9d765eedb3fe130d,
+                0xe15974fae687d76d,
+                0x78f57f0f7d8f44a0,
+                0x4f5a38ebb5835b88,
+                0x8a88d513e2f142cd,
+                0xd8cf52738e8672db,
+                0x6aa7ba7d4f2743f6,
+                0x59dc780f8aa7ba69,
+                0xa33bf5845d602e75,
+                0x00f20b3cd5b7412a,
+            ])),
+            Felt::new(BigInteger768([
+                0xd542e1bab1ee1165,
+                0xe9b70a5a5aabb40d,
+                0x10611e44a534efd1,
+                0x5d5a7b56d81f4df6,
+                0x8950562371b83eb7,
+                0x3a5e14848b8f2fc0,
+                0x63f1acdc65f8b497,
+                0x8e9fb70b38a5f5e1,
+                0x1b6e5ed1daac3d57,
+                0x83cbeee2719bcdfc,
+                0x0e1473c8ea3f5bf6,
+                0x0052088aed4041f0,
+            ])),
+            Felt::new(BigInteger768([
+                0xb40dc981b337b21b,
+                0xaa2e59d7809f2863,
+                0x3f08e8bcd233ae21,
+                0x735b8bb37b41dc2e,
+                0xf560bbf0f505dbe1,
+                0x682aa767290c078e,
+                0xb25dc2d7f7f6e40f,
+                0xcec680ad5e44c974,
+                0xc2f8431e171ad1c8,
+                0xc97d1b278b0391c7,
+                0x834ec2d409bfad70,
+                0x008b12b773732d5a,
+            ])),
+            Felt::new(BigInteger768([
+                0xf7024b56149938e2,
+                0x62447732a352f5b0,
+                0x4ac5c6f093fb32a0,
+                0x79e03c944ef05ab5,
+                0x491360c007682fd9,
+                0x6ea82d972305b241,
+                0xd01cc4b7206a81a6,
+                0xe95b4e2d9fe544ef,
+                0x9efce6d2b2eae34f,
+                0x1ac79fef3d928553,
+                0x722ae3d474211ac5,
+                0x0039f4b9c0b80e7b,
+            ])),
+            Felt::new(BigInteger768([
+                0x9bb444af670be8e0,
+                0xd5493f216984b2b9,
+                0x00fe160acfd6a2e6,
+                0x2ab22c82ac8c56ec,
+                0x8688d134f37e40d0,
+                0x39e91aeac776848f,
+                0x860d961732bd8809,
+                0xb475c6580457574d,
+                0x8cc05f4dae34ae6c,
+                0x6310c8a7b45c5624,
+                0xa4bd1c278543a929,
+                0x0091ad1c3dd8a19d,
+            ])),
+            Felt::new(BigInteger768([
+                0x0b825e9589e32ee8,
+                0x306a04d4f1e3b8ec,
+                0x521daee4026b49eb,
+                0x505888e828c55653,
+                0x087def5a6932a0d3,
+                0xa2cee228ca90655f,
+                0x56d0029794d0567d,
+                0x0f18c15a76548977,
+                0x5edd1a6ded3c55f7,
+                0xce9f93afcb418a46,
+                0xeed87e27c9157207,
+                0x00e4fa8faddeb136,
+            ])),
+            Felt::new(BigInteger768([
+                0x49175c4c10b009fa,
+                0x4f1af71a2a7ce9bf,
+                0x3d35e0f98068d37b,
+                0xbc66bd352f174a04,
+                0x21f47473f34152be,
+                0x71f5d0200945ad27,
+                0xc6dc57fa7f39d567,
+                0xf9dcac6b5633cb86,
+                0x6a15d602d8acd781,
+                0xe3261600adb286c3,
+                0x0816179ca412441d,
+                0x0000f4cb9d7e0fb0,
+            ])),
+            Felt::new(BigInteger768([
+                0xc4bb240add407f6b,
+                0x7f00ae11665566e9,
+                0xf9d3be012247833b,
+                0x35ae9bf78cebdaa1,
+                0x8aea5c59279c7322,
+                0xcfbbb22a554721df,
+                0x3646863d2358484e,
+                0x8bc8fa490b441d5c,
+                0x76b73c18c8e91d86,
+                0x3b893ef877ac7db2,
+                0x7aee780236ee623e,
+                0x00f46004d1e3a95c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xe7a74dc94a922501,
+                0xa1cbb2eddb36611d,
+                0xa1cb7094cf5c8f85,
+                0x46627dfa8a1c07f7,
+                0xda890509ce943bcd,
+                0xabef80d0a0635f6c,
+                0xe3364c2e7e94b1f2,
+                0xb66650c4d2589a35,
+                0xd10b7cb296192bba,
+                0xbe17be4811c629c9,
+                0x6353d1cf60ee7121,
+                0x00551f56cfd4b3a3,
+            ])),
+            Felt::new(BigInteger768([
+                0xb5ac0cc02656cc32,
+                0x1df2fa1d27ed6eda,
+                0x987d8924895ec1b5,
+                0x2ad117ba32ca01e5,
+                0x59620065bc9055bf,
+                0x39620181ed93de66,
+                0x7828d663ae88cdf0,
+                0x45c049df03f46815,
+                0x027a31c36a5150b6,
+                0xf67831f85fb41bd3,
+                0x526030ce728df96b,
+                0x004085014c322315,
+            ])),
+            Felt::new(BigInteger768([
+                0x3de684af8b1e3577,
+                0x8bfaa92402595f1f,
+                0x794705691e92b585,
+                0x3f954a8c2261ee15,
+                0xe38c0e3e24a7467d,
+                0xd5dd390b36bc4671,
+                0x6cb55f8b7a16b424,
+                0x8a476e6fe7d4ac14,
+                0x69e035d2f1e4ab49,
+                0x72e62546e9d2e955,
+                0x2a5c14658c472198,
+                0x00e38367843eff17,
+            ])),
+            Felt::new(BigInteger768([
+                0x1de310286bb15db7,
+                0x8eeb41ad44bf34af,
+                0xccb6a7df4a6f5025,
+                0x5e8660368dbbe5e5,
+                0x04c5c610b78ab97e,
+                0x9124857d2f7c8d31,
+                0xd7e462e24ea33b43,
+                0x4a3839a41bfb6eac,
+                0x9503165e3ff110fd,
+                0x73a2484f0e9b25d7,
+                0x38ea9c74ff4c57f3,
+                0x00f0c4e1e35ad86f,
+            ])),
+            Felt::new(BigInteger768([
+                0x8be44532be236129,
+                0x2278550496f10534,
+                0xde738d273788e067,
+                0x51317a0c5af7b153,
+                0x531ce001a9d34ca4,
+                0x1b7880245c63ba7d,
+                0x428505bdbecff93c,
+                0x192b4008e4710c59,
+                0xf525e3249d2b5e84,
+                0x2e80e4df08d3a690,
+                0x902c5dbd75dabf6b,
+                0x00579c229189e8f8,
+            ])),
+            Felt::new(BigInteger768([
+                0xa3f4146da59e8daa,
+                0xd64c986d87ceceb7,
+                0x608a2bf56f91ce37,
+                0x564fb2997b07fe62,
+                0xfd7f002aac5d51ae,
+                0x3b43177fb82c6431,
+                0x3e12cb565a815495,
+                0x3cd2bcda4270839d,
+                0x7eeb8f0f09275955,
+                0xedbfe7ac107efb24,
+                0x7af7c60c30ceeb2c,
+                0x00761a453f246940,
+            ])),
+            Felt::new(BigInteger768([
+                0x34308e54c344a3a6,
+                0xb12aaf44d599054c,
+                0x0db4461ecd26497c,
+                0xea6a457f9662ab77,
+                0xe5ebc61acd1fdf87,
+                0xf46711beb9f1f5ce,
+                0x3e790e69683d1fd3,
+                0xc6342e6810c69fa3,
+                0x1129d8fc6f9b6bd3,
+                0x4e5cada60c832f50,
+                0x12c54b33f7e17576,
+                0x00753768993961c1,
+            ])),
+            Felt::new(BigInteger768([
+                0x5825941162e316af,
+                0x7002fe4e4ed9021a,
+                0xfa37f6b7d0082415,
+                0x006a13be72e20f65,
+                0xfed426eee50c912f,
+                0x21147a7b2cfb95cf,
+                0x4497348b5be5d55e,
+                0xba99ab306b7818f5,
+                0x3f7de6e638f78430,
+                0x55d30b97a76b0eb4,
+                0xdb79fc37010dba74,
+                0x0043b61cd20276bc,
+            ])),
+            Felt::new(BigInteger768([
+                0xad50758b3d82b5d4,
+                0x3dcce031cbbffa75,
+                0xb008482fff75190c,
+                0x5db8035cffd9a1cf,
+                0x14d38e88826aa356,
+                0x590bc7d84a4adaae,
+                0x6af7383b646a70fe,
+                0x2fc3ca2327af30e3,
+                0x758a5d7122ae0e1a,
+                0x1e91c99dd3b43418,
+                0x01af0215d0751e5c,
+                0x000e6c36224058a3,
+            ])),
+            Felt::new(BigInteger768([
+                0xaf5deda305d49d8c,
+                0x56fd8d162efe7fbc,
+                0x580c0cdff665ad56,
+                0x3e89e69ee27835e5,
+                0x46b99644e9b36d27,
+                0x98f62b096ffc6474,
+                0x005e5e73cdce9baa,
+                0xf4760d123273a9ad,
+                0x58767eeaac9ac12a,
+                0x52aea1e06c377d03,
+                0xf0b1f5e7ad9e59f5,
+                0x0109b4e2f94e0481,
+            ])),
+            Felt::new(BigInteger768([
+                0x13870d48b5063144,
+                0x7ba81a88b944fa1b,
+                0x1a8b0ee5abc631ff,
+                0x48e66d67cac75538,
+                0xdb5c7e9c65ea0344,
+                0x9b4312da2ba7cd8a,
+                0x1743bde08df62b6c,
+                0x40b5b9175197f89d,
+                0xb505fe1806b080a8,
+                0x3160e2faed280a73,
+                0xb2ae7cb7451a1055,
+                0x010f34560cd19997,
+            ])),
+            Felt::new(BigInteger768([
+                0x8c0bdbf32d9b4d9b,
+                0x84faab7e0288bc16,
+                0xbbbec718bae3556b,
+                0x30efefa0fd02b0e3,
+                0x4f64b73ba782d310,
+                0xfb4d1ffff3625f9f,
+                0x5d20e4d64beb10ae,
+                0xe555e4a998b30a86,
+                0x6374ff3e129407dd,
+                0x526eed592533a051,
+                0xe3cbdd75b6dbcbcb,
+                0x0068e71f9caaeb96,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xb014b7186ef76e59,
+                0x5c588e9999d85295,
+                0x579d3bebc53cf488,
+                0x04defbea387ad072,
+                0xd5e8608e899a288a,
+                0x3615adbd9ef55894,
+                0x6a5e67ea4b19989c,
+                0x5d364c03b5a0871a,
+                0xd080d1d8abb50dee,
+                0x6bcc119fd9fe0adf,
+                0x59503bcf674dc52a,
+                0x0080bd6366845b93,
+            ])),
+            Felt::new(BigInteger768([
+                0x8692cd2a0058d64a,
+                0x130ceae381abba1a,
+                0x5a11811a41e4c4c8,
+                0xa7470d95aed4414c,
+                0xfd29e7b429bf1f76,
+                0x585707a49be91b9b,
+                0x0468552b64ea98b7,
+                0x46b90e55c32b9ee0,
+                0x2d635d4ca8ceccd5,
+                0x65f97e705769f0c2,
+                0x44babd06bb44a436,
+                0x0083ac226f4977a4,
+            ])),
+            Felt::new(BigInteger768([
+                0xf7fc7bde93f9f6ee,
+                0x4edb5ca1efcb2675,
+                0x1b422c2cf00dca97,
+                0xdc11707a0feebf75,
+                0xf02e0f56b8409ca6,
+                0x293b92622fb255ae,
+                0x30dbfe702cc6c4fd,
+                0x58809af588cbe569,
+                0x50f3b34d757c4d69,
+                0x5096934ab023f850,
+                0x093b6175540fea88,
+                0x00a9acd21650245f,
+            ])),
+            Felt::new(BigInteger768([
+                0x9ef3a74672a921d9,
+                0xdc7c98dc439579de,
+                0x76d8435f8fafdfb5,
+                0xfa11929ab034d9ca,
+                0x01a8f75f3a4f9647,
+                0xf045244bc0e6d55e,
+                0x760bd7d48804a6cd,
+                0xc1ba17bf43f237b2,
+                0xa8c1e73af853b1bc,
+                0x64781f593409e0b7,
+                0xcaab861298dc50c0,
+                0x000b5089bc9d5b6d,
+            ])),
+            Felt::new(BigInteger768([
+                0x8bfa94f490f31298,
+                0x336b5a17a4e9747b,
+                0xf452044e70879553,
+                0x74fba7fb6e87a7e8,
+                0x413b7cb0bcdd09a4,
+                0x3f325102839a0dc5,
+                0xc93bbcea28049d96,
+                0x8212771cb806e99e,
+                0x41146c84a24a0693,
+                0x41d39028a0229e03,
+                0x85e19cf05b96758c,
+                0x012171ab6621a493,
+            ])),
+            Felt::new(BigInteger768([
+                0x56053c94b25cc8ec,
+                0x9b46b2a8c8a17970,
+                0x150e0a293cbc6abd,
+                0x3e49639a62ea7855,
+                0x49496d9bad5a17a4,
+                0x8037193feb434610,
+                0xec36d15a9d435bab,
+                0x3491935d35995d37,
+                0x66bb5bd8cb1a1fde,
+                0xeb4ab778180fbdf4,
+                0x9fee53b4ef687a20,
+                0x0106f5e33472680e,
+            ])),
+            Felt::new(BigInteger768([
+                0xd2e4ae8555f6dc60,
+                0xb90665e1d279fa0f,
+                0x6d2b96113ed260f1,
+                0x8aa9269717d8d706,
+                0xd77fd4f8cac09b4a,
+                0xe2be2ef754f25236,
+                0x94762149f067785e,
+                0xa7938a5595b75b49,
+                0xa8f3d4a19fcb04b9,
+                0x27c8b65077f8bd18,
+                0x2f0ce42c91fb7186,
+                0x00233e71dae9e171,
+            ])),
+            Felt::new(BigInteger768([
+                0x89f44fe6dff9bdc7,
+                0x1631c9df43b43e64,
+                0x5932639c477a8df9,
+                0xa4baee6892f23134,
+                0xcb2a630ae407ed3c,
+                0x57f8be6168f67612,
+                0x4a825a50d4c62a61,
+                0x7560368650cd44bd,
+                0xf73223131b31bade,
+                0x2056411a9fdb8717,
+                0x26f2d41d0d547933,
+                0x01074ab6e9dda106,
+            ])),
+            Felt::new(BigInteger768([
+                0x79d3ad3c2e10bfd7,
+                0xfa42a963e4894120,
+                0x1d86e7fb1addfac0,
+                0x9241f353db8aa3bb,
+                0x843ef9ce98fc0098,
+                0x1cf8f8343ba4d05a,
+                0x5d6304e0ff0b80a5,
+                0x8cd34dcc8e4e7bb8,
+                0xeeb82ac476378ea6,
+                0xf7875e0f9ed0e05a,
+                0x0a8d4a4530bf8795,
+                0x00fd058f134d03f6,
+            ])),
+            Felt::new(BigInteger768([
+                0x6b5f1a94532201bd,
+                0xa8d2a5b03abaa77a,
+                0x79aad8e7a76fe4bd,
+                0x1e543feb136af43c,
+                0x9268046d639df2ed,
+                0xf1a8cd6011075e23,
+                0x0da287e58701b74a,
+                0x4a83d41158d6739a,
+                0xb5f5aa7b13dc50ae,
+                0xbd06926390d68233,
+                0x9a0ece885d1b4ed5,
+                0x00a2aab5d09eef51,
+            ])),
+            Felt::new(BigInteger768([
+                0x9107c95dd1441737,
+                0x3da95084ed2e3f15,
+                0x1dbe50da12cd6e40,
+                0x85146df27254afa4,
+                0xcd2de1af3c44746f,
+                0x10d03f375219e3f8,
+                0x8dfb0de57c919745,
+                0x0cce3f1df092454e,
+                0x08045661bbbcb023,
+                0x12f8067453e75127,
+                0xf0f24649f847121f,
+                0x005d6264889e23a8,
+            ])),
+            Felt::new(BigInteger768([
+                0xfcf1d5f239fa9de3,
+                0x328e84ef1ee4898e,
+                0xf7689e76d58132dc,
+                0x251eb35fad03ebc6,
+                0x0217da64757ae878,
+                0x56c52ea039c50220,
+                0x1e219abe31290f79,
+                0x4a18f016d567019f,
+                0x11956de1d4df9a47,
+                0xb0c382d4686fcb99,
+                0xf10dd5e637c11890,
+                0x008e20a110f5858d,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger768([
+                0xccc6cf3b94dc9a84,
+                0xc77accdf496fd65d,
+                0x73205fd574e1835a,
+                0xdd1c878d27401dc1,
+                0xce32fd3f0dd26948,
+                0x12e5408c8b866a11,
+                0x34629721c6965bb9,
+                0xc443e6918bf03a80,
+                0x6e2fb53450fda61f,
+                0xc18999dfec11a20f,
+                0xe1b468322e98ed67,
+                0x00459910687cbe1d,
+            ])),
+            Felt::new(BigInteger768([
+                0xc34bcaa7b863bc35,
+                0x6112eba31c07c201,
+                0xef397d024508f1b4,
+                0x943ed198197f637f,
+                0x79f2336faff902ae,
+                0x26a42700eb4c6f60,
+                0x31b992fad750ae9e,
+                0xc41be79f85cd57ee,
+                0xda68f2c6d54c4939,
+                0x9af481f1c7d4d4ab,
+                0xac07cf0369559351,
+                0x0003aad6c84c454a,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x4556029429f0c183,
+                0x8803c628687e023b,
+                0xc781a1dd7a68ac92,
+                0x390555e01d86ae21,
+                0xd73fc3faa9e87f36,
+                0x6f82c09d24c542c0,
+                0x368f8f77bc45d025,
+                0xcb61fe784b1cee2c,
+                0x0f65e76bfc7f305d,
+                0x96756d1e106c9aca,
+                0xe7076f6fed692238,
+                0x00ffe430b04cbcdc,
+            ])),
+            Felt::new(BigInteger768([
+                0xe32652c3c9d48663,
+                0xd939df511754d435,
+                0xe2e6667b453be094,
+                0xdbf6039eadee2c91,
+                0x88ab866569d8f989,
+                0xfd362f0b88175463,
+                0x9d56e6fb53b92df4,
+                0x0e8c4f074132dfb5,
+                0x6011d3b0e40bf82f,
+                0x0ed6f1be7fa2b583,
+                0x4c574cd22172cd71,
+                0x0036f498e1cf1bba,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x3ae7386f7be3c9cd,
+                0x2a00f6c30c5af3d3,
+                0x6ca3384404cbf589,
+                0x5a421d3c9a582f4e,
+                0x0d5b56a1e5dfa654,
+                0xb9a69734c57ff984,
+                0xdb3025c8c8a75b4b,
+                0x066ab52c55214726,
+                0x47ca9d181d512ae5,
+                0x87a530aa9396c567,
+                0x57aedd8186a36208,
+                0x00a797a5f64cb9ae,
+            ])),
+            Felt::new(BigInteger768([
+                0x7d41f7068bb98732,
+                0x1eeda5133d93c470,
+                0x0c8f8ca86db95393,
+                0x8e6c4861cd20928b,
+                0x092aa2babc986369,
+                0xdfdfc00363ee73fc,
+                0x2ff7860c53e9aa8b,
+                0xc514467b71c3c38d,
+                0x4fb65bac2fc1646f,
+                0x7066d6099432d4bc,
+                0x00f7a8051d190555,
+                0x00c16d2aa2130a18,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xcdbe4e4836a421ff,
+                0x452d70d1dc2f1d48,
+                0x343b5f8d093d2da7,
+                0x6df710013e881ee6,
+                0x68085c47d93e748b,
+                0x335d970480b4b819,
+                0xdc86382bc465ddce,
+                0x96d46bd8756620e3,
+                0x51d0976a9c9ef3b4,
+                0xb3cbfed682b0ec3b,
+                0xd2a1be7673cf8e4f,
+                0x00c47058931ee0b3,
+            ])),
+            Felt::new(BigInteger768([
+                0xb1143056d4b9f2b4,
+                0x5b8ace1ec3ba47ab,
+                0x3380415f737cd793,
+                0xc34c57393d278731,
+                0x66c1ee399e872ed0,
+                0x3d412438a1c43fa5,
+                0x1afa758848be063f,
+                0x1033661578ff734c,
+                0x9395f32231bb887e,
+                0xcba0415e5b8f70b2,
+                0xa3999cd2943d2887,
+                0x01145267a8869db9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xcaca1b03f967e9bd,
+                0x6d4802c45969ee60,
+                0x327640063255adaf,
+                0x6516ad31984ec1cb,
+                0x60aa0c101c1621aa,
+                0x0810cb1b8d207dec,
+                0x06f6c27320acdd85,
+                0x4537b3a94009de58,
+                0xa39d7afc263b9feb,
+                0x402a5211ee9a01a6,
+                0x5850708408e6c9d1,
+                0x00e4875f20add01d,
+            ])),
+            Felt::new(BigInteger768([
+                0xfbfd754827bfdd97,
+                0x919f1bd9679a5740,
+                0x329213ae7daf9204,
+                0x9506cd2596dc4e13,
+                0x13a641d8f4b89e3b,
+                0x916d03bcfc33cc56,
+                0xcffff6b03303518b,
+                0xb2a4d78f254558a6,
+                0xf24fdd395970ead3,
+                0xdc4cd5558f77da1b,
+                0x7cf2c3939c8748bf,
+                0x00c5ed53e9a6651d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x41ccf4c58d5815e3,
+                0x44a10400cb8c057e,
+                0xa55c05f7a2ed58ab,
+                0xa3d801089dd022c3,
+                0x63286e5abe8b1f6c,
+                0xe96411f9db1b3b2b,
+                0xe4465eee240d343b,
+                0x5d51cddcf3b80d1a,
+                0x8efb72eee7ed41e3,
+                0xb088dec69e823143,
+                0x9bec0eaa9aa63492,
+                0x008668e8fe523a70,
+            ])),
+            Felt::new(BigInteger768([
+                0x185792c37e82ccab,
+                0xd2b9307edab23ebd,
+                0x7a87f655197d6c84,
+                0x632096a15bc5ed25,
+                0x1005aa5beb51cdec,
+                0x05765b968f5f07e3,
+                0xe5692c422e77a107,
+                0x9d35d10596aab379,
+                0xc337e48c3aa6545c,
+                0xb8043d7dd4d081b7,
+                0x8e8575f3d5303fcc,
+                0x009dfa03e17d006f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x005baf77529b7c41,
+                0x2b01df937e939cac,
+                0xb87d815679e3a3bc,
+                0x51fc4f3747b4f417,
+                0x58c9dd240224737b,
+                0x7f2b7880ebd191d0,
+                0xa452806e3cc9b603,
+                0x9b4e82523b798e1f,
+                0x9f2e3ac3e4831cf3,
+                0xaca993a534331099,
+                0x121a042cb6222a11,
+                0x00ceca23ba8d508e,
+            ])),
+            Felt::new(BigInteger768([
+                0x40eccf818f965031,
+                0x46ad23961d766a38,
+                0x3cadbff358317e2e,
+                0xf527a35dfb62298f,
+                0xdc3f75e20801567b,
+                0x03150453c6567b4c,
+                0x33ef2cb3e5d02f99,
+                0x2cfd1caf5c5a71f7,
+                0x1cb0290adec37605,
+                0x8576bde340b59b7a,
+                0x9517a9e93a78aa42,
+                0x003d92b833bef5cf,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xd50537a94df68de6,
+                0x57c368c6af1cc1d5,
+                0xcadf949dd22ba0d1,
+                0x6f0f57a1cff2debc,
+                0xc9a989961306d991,
+                0xec4410eb7fdeaac5,
+                0x0e3e717ecb3d22ab,
+                0xdba342b6dfd6c390,
+                0x8b385eeb7d61b601,
+                0x9483d00f1a53ac80,
+                0xb6557762c996779c,
+                0x00179c51643ec848,
+            ])),
+            Felt::new(BigInteger768([
+                0xa98f81bcf4f36286,
+                0xe175e80256b4c4af,
+                0x6626865262423311,
+                0x63a09513735f0ab5,
+                0x956c32f9e185f641,
+                0x0f812db06f8e7f96,
+                0x14429733c449c555,
+                0xe447f3eae5da65ba,
+                0x150b5b725a85e8d6,
+                0xb4326d63586e86e1,
+                0xa44eba090a8fcea9,
+                0x00332bdb21712550,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x2f9b49230a81cd27,
+                0x5f06aa9c7a5a2ce4,
+                0x29d7520ed3a58731,
+                0x16e6d179c8b752d3,
+                0x209d94a635ddec05,
+                0x10c0fa9b67548bbd,
+                0x52d34cb81e55ac62,
+                0x6271092e36866764,
+                0x7a8a78898dd342e3,
+                0x9be623f297507e02,
+                0xa2847bf4818e49be,
+                0x00989fd855d74dd7,
+            ])),
+            Felt::new(BigInteger768([
+                0xbd88e7985367e6d6,
+                0x0eabbe79a42d966a,
+                0x1ac654269f0fc01d,
+                0xda3b1987e5da28b2,
+                0x858aaf9b1df02042,
+                0x7a97a2e4638ad570,
+                0x7ac4748ee6682da0,
+                0xb7e0d9201e7496f5,
+                0x24569611396a99a3,
+                0xdf28d7f126478984,
+                0x66cbc3cc3c032906,
+                0x0069de3ce28b5ecb,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xd45473ef1182a068,
+                0xf6721d96d581501d,
+                0x5fb8a0f58695e011,
+                0xad7ac0c06344efe8,
+                0xbb1123cb85d74463,
+                0xbb7525b911349ef4,
+                0x0212628fe809b82a,
+                0xce1855a487893295,
+                0x7551859680c7e3d8,
+                0xaef1e6d60edacdcd,
+                0xae9ce2acc8265486,
+                0x010e0a6d7600537f,
+            ])),
+            Felt::new(BigInteger768([
+                0xdedb3bf9d4a29488,
+                0x45a162c1ab5a697f,
+                0x7c561ab40b2863f0,
+                0x73cba1ae89c56446,
+                0xbfe417eb74d55c0a,
+                0xd2ff9594defdc029,
+                0x9708bbefa23fbdfb,
+                0xcb97be49274e28b6,
+                0x4a2c1ada98770b4f,
+                0xb322d96b24ac45e4,
+                0x1c8a954f29c4ab11,
+                0x0034eb107c24b638,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger768([
+                0xf66a59537637c76f,
+                0xc1716cc3b64010d2,
+                0x6ea2c5f44c911958,
+                0x9d5b9c525e9efb2f,
+                0x9d48dd91fec61019,
+                0x816ce60603eef993,
+                0x5eb3eba408affc70,
+                0x2d65387fdec6c0df,
+                0x4fae97926fbf200a,
+                0x5fcb17617528b8c1,
+                0x4945aa265e541ee0,
+                0x00d556988891dc0c,
+            ])),
+            Felt::new(BigInteger768([
+                0xae13b230bbc92d5f,
+                0xa10db85323bd9483,
+                0xb4f24556230c014d,
+                0x32c0520b6c6bb22c,
+                0x2d178fdb321b91a5,
+                0x4910dc86ad606234,
+                0x8876035233f95492,
+                0x18150d60d7027c55,
+                0x9e54a10f350eaac8,
+                0xe579f5fab72c4d60,
+                0xd616bdc71fd679ef,
+                0x002e75f8fc858b5c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x50b3c3677644fb27,
+                0x7b1780e6553d5d4a,
+                0x4cbd0274dbc30e84,
+                0xd3a3109c67557525,
+                0xba22f4f39c967d2a,
+                0xdd3086483bc54475,
+                0xf5a50956413af077,
+                0x2056ec76487b5814,
+                0x2438587245ff7958,
+                0x3cbacb4cbc4186b5,
+                0xb09eb5f6a56d4b05,
+                0x006ffdebdb2f90ad,
+            ])),
+            Felt::new(BigInteger768([
+                0xbc32d25f50b553a6,
+                0xb6eb9ca7151150fb,
+                0x18d43f2e5b64914a,
+                0x4babb759de839f85,
+                0x21d7315fbb83ca50,
+                0x9540cc4b95e799d4,
+                0x5a4bd89896a0f685,
+                0x8f8a56d5d70e2fcd,
+                0x6091b2ebfbcdbf9c,
+                0xd2fde57e3cdb07ad,
+                0x2232c64e82e5ec31,
+                0x001a7c27a14afc78,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x84940b5c52ec238b,
+                0x73536670d5cd4f9a,
+                0xc64b5195fdabc90a,
+                0xd3bf9e1f96dc37c7,
+                0xe2cb1b707922390f,
+                0x95cd2305d4ec3694,
+                0x9b6ae551b610b596,
+                0x5382ae9127660c2a,
+                0x0cea24c7628f1dec,
+                0xb64cfe479bfd8148,
+                0xbd5b6bf023443f49,
+                0x00a8fe0b092368a6,
+            ])),
+            Felt::new(BigInteger768([
+                0xe9958b371f4e05ba,
+                0x7a945036c00284f6,
+                0x62f44efb74da7551,
+                0xd76772b0c18ed598,
+                0xbbe448f2cb9273ef,
+                0x765770fed32df2c5,
+                0x733b6c761d3330ca,
+                0xc9f625e848717110,
+                0xbb54ddb3b99ffc13,
+                0x5c2291e0c45a7181,
+                0xf640a77d7ea89ee1,
+                0x0080ac3fd86c6244,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x746cd76e3cee4568,
+                0x01774c7983bedf84,
+                0xc85812577c1d05c5,
+                0x9d81db0dd6e9bc22,
+                0x1b6eba3a65227be5,
+                0x2d8fe7bda9145ecb,
+                0x2d70750e73824e07,
+                0x25f1881b07af8447,
+                0x67bcc0f501e3813e,
+                0x622fe06bdec542c1,
+                0x409ce762e8fad4a1,
+                0x009615088954a907,
+            ])),
+            Felt::new(BigInteger768([
+                0x12dc0cca2a9b933d,
+                0xdc0104d7d8ac6abf,
+                0x98e2487b76e613db,
+                0x87dfb1ba38b37202,
+                0x8a6161ba27008fee,
+                0xb45ce54856da9ef3,
+                0xb29b47a4686c5004,
+                0xcb29a952d9785701,
+                0x0c6f4d4e3e76b5c3,
+                0x69e3c9abba8b0eb0,
+                0x44010cf0d8a72638,
+                0x00cef5bb1977b051,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger768([
+            0x93d6c47a5ff1bbaa,
+            0x56396719429f614b,
+            0x9e2ceebd8040304a,
+            0xbd4a86439304809d,
+            0xf3bd1bf46efd5a8a,
+            0x904f20a8e606aa0d,
+            0x2cbf3eb53a86b1f8,
+            0x0b6e82ddbdabd4e1,
+            0x0fd9a9fed135ab8c,
+            0x13e56f705f5b9c22,
+            0x43c5f96afc052ee0,
+            0x00b2457c50141f38,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xe69df3790eb9d21f,
+            0xe945aae3bcdb9df7,
+            0xa4bf1b43f0b97b7f,
+            0x25d3ba1134056a68,
+            0xb45a1aa552e7aa79,
+            0x1fe78019e4aa9843,
+            0xf414d67fd89676c2,
+            0x7ae1769ce4d77356,
+            0x13787e19fb65eb74,
+            0x07170812e12f06ff,
+            0x0c7a2cce399ea97f,
+            0x00ad5b436a925dca,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xe4ee78c953b0a7c4,
+            0x496c42c9b785190e,
+            0x4ebaa0d0e6871ec6,
+            0x5b98363486372b49,
+            0xd2afb583aeb487f6,
+            0xbe6b66dc0522eaca,
+            0xacc4c5a700c1134c,
+            0x52964e77ec5efefa,
+            0x6427c4a5d100f138,
+            0x0224fe683f9e4cf2,
+            0xbb06252d7680a961,
+            0x000dbed82022a431,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x591898fe704d5e75,
+            0xd4a1de239f433908,
+            0x569a92a9538ba5eb,
+            0x80e5f0b9344d1195,
+            0xf289372ead44c6b2,
+            0x71916561c9bee6e5,
+            0xa785bdc1c961936b,
+            0x473f9f030deb6cad,
+            0xf003a62cc4fcf46c,
+            0x91afbf09459acef7,
+            0xf76fd8179e9311e9,
+            0x00c8717c150b3e1e,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x6189bced6636a8a5,
+            0x86db4f40ecd08c9e,
+            0xa9121c0b53e0fa62,
+            0x410c550228004da4,
+            0x67a902471fa906bb,
+            0x17cc2edb0af74106,
+            0xf265dabc77a8fc26,
+            0x9b54a0d0012fdb6c,
+            0x86a8329ba4eb27ca,
+            0x0d1474adc0737def,
+            0xaa3b54d2914efa4d,
+            0x00c4b535f6f5d182,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xedc04dfaab5f5019,
+            0x3b0791237191b18a,
+            0xd818c18ff586c603,
+            0xb86b953f1516cf93,
+            0x36b4393bbd6f7fd6,
+            0x3cba63203ccbf825,
+            0x997b4971b48fe8cb,
+            0x66abde1c55dfb0f5,
+            0x9152632f75962404,
+            0x386c9f9233431bad,
+            0x03a0fe8df3ab1baa,
+            0x000a405b456cc27d,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x1d298bdc1a5c334f,
+            0x242693e406fef615,
+            0x57b261eb6ea80571,
+            0x6f569e650fd3ea50,
+            0xd5e7907314fd4189,
+            0xfff0bcb97085b0d9,
+            0xaeb30204ad267507,
+            0x25c21ab4c86d202e,
+            0x5bd4e7b41cc22a83,
+            0x039c96c8e254cddd,
+            0xcebdf9ed70e0979d,
+            0x00cbda5c3062c47f,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xad906354388edba6,
+            0x432a4102dc6e2be9,
+            0xd55175cb89215764,
+            0x344cd68c72851654,
+            0xcef8a7a9d8cc6846,
+            0x2458100a4f1fb97a,
+            0xbe81f7f6d3c16f2f,
+            0xc128ff7bb7c2de10,
+            0xc54a35e3e76ef5e9,
+            0x36e9c46a673ffbde,
+            0x36d52878328acabd,
+            0x001c8c12211c7f48,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
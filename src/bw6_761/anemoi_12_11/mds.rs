@@ -0,0 +1,518 @@
+use super::BigInteger768;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0608fffffffe917f,
+        0x0f0872a0affea6b5,
+        0xdbcc4aca877f009b,
+        0x8f40fd962cad79dd,
+        0xc6bd61ba58be2823,
+        0x42b336e6f6d86c23,
+        0xf5e8f13f9607ab11,
+        0x69f8b7fad28d630a,
+        0x720309801a155974,
+        0xd08d9f2bb533068e,
+        0x86f85c32de30fd9d,
+        0x00f5e67cd377b979,
+    ])),
+    Felt::new(BigInteger768([
+        0x136efffffffe16c9,
+        0x82cf5a6dcffe3319,
+        0x6458c05f1f0e0741,
+        0xd10ae605e52a4eda,
+        0x41ca591c0266e100,
+        0x7d0fd59c3626929f,
+        0x9967dc004d00c112,
+        0x1ccff9c033379af5,
+        0x9ad6ec10a23f63af,
+        0x5cec11251a72c235,
+        0x8d18b1ae789ba83e,
+        0x0024f5d6c91bd3ec,
+    ])),
+    Felt::new(BigInteger768([
+        0x1571fffffffd9c9e,
+        0xdd2780a35ffdc000,
+        0x02f22ea2a18db21f,
+        0xab75e537f40ecccf,
+        0x2eb4245a1ffb990c,
+        0x3df63d3e886eb6ab,
+        0x40b581c029adfa18,
+        0x4022e1be7966bbf9,
+        0x162d4490aaf12c2b,
+        0xa2709b890183c465,
+        0x64c0d06a1801521d,
+        0x0076ed55ba43bc6a,
+    ])),
+    Felt::new(BigInteger768([
+        0x1774fffffffd2273,
+        0x377fa6d8effd4ce7,
+        0xa18b9ce6240d5cfe,
+        0x85e0e46a02f34ac3,
+        0x1b9def983d905118,
+        0xfedca4e0dab6dab7,
+        0xe8032780065b331d,
+        0x6375c9bcbf95dcfc,
+        0x91839d10b3a2f4a7,
+        0xe7f525ece894c694,
+        0x3c68ef25b766fbfc,
+        0x00c8e4d4ab6ba4e8,
+    ])),
+    Felt::new(BigInteger768([
+        0x1774fffffffd2273,
+        0x377fa6d8effd4ce7,
+        0xa18b9ce6240d5cfe,
+        0x85e0e46a02f34ac3,
+        0x1b9def983d905118,
+        0xfedca4e0dab6dab7,
+        0xe8032780065b331d,
+        0x6375c9bcbf95dcfc,
+        0x91839d10b3a2f4a7,
+        0xe7f525ece894c694,
+        0x3c68ef25b766fbfc,
+        0x00c8e4d4ab6ba4e8,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0608fffffffe917f,
+        0x0f0872a0affea6b5,
+        0xdbcc4aca877f009b,
+        0x8f40fd962cad79dd,
+        0xc6bd61ba58be2823,
+        0x42b336e6f6d86c23,
+        0xf5e8f13f9607ab11,
+        0x69f8b7fad28d630a,
+        0x720309801a155974,
+        0xd08d9f2bb533068e,
+        0x86f85c32de30fd9d,
+        0x00f5e67cd377b979,
+    ])),
+    Felt::new(BigInteger768([
+        0x136efffffffe16c9,
+        0x82cf5a6dcffe3319,
+        0x6458c05f1f0e0741,
+        0xd10ae605e52a4eda,
+        0x41ca591c0266e100,
+        0x7d0fd59c3626929f,
+        0x9967dc004d00c112,
+        0x1ccff9c033379af5,
+        0x9ad6ec10a23f63af,
+        0x5cec11251a72c235,
+        0x8d18b1ae789ba83e,
+        0x0024f5d6c91bd3ec,
+    ])),
+    Felt::new(BigInteger768([
+        0x1571fffffffd9c9e,
+        0xdd2780a35ffdc000,
+        0x02f22ea2a18db21f,
+        0xab75e537f40ecccf,
+        0x2eb4245a1ffb990c,
+        0x3df63d3e886eb6ab,
+        0x40b581c029adfa18,
+        0x4022e1be7966bbf9,
+        0x162d4490aaf12c2b,
+        0xa2709b890183c465,
+        0x64c0d06a1801521d,
+        0x0076ed55ba43bc6a,
+    ])),
+    Felt::new(BigInteger768([
+        0x1571fffffffd9c9e,
+        0xdd2780a35ffdc000,
+        0x02f22ea2a18db21f,
+        0xab75e537f40ecccf,
+        0x2eb4245a1ffb990c,
+        0x3df63d3e886eb6ab,
+        0x40b581c029adfa18,
+        0x4022e1be7966bbf9,
+        0x162d4490aaf12c2b,
+        0xa2709b890183c465,
+        0x64c0d06a1801521d,
+        0x0076ed55ba43bc6a,
+    ])),
+    Felt::new(BigInteger768([
+        0x1774fffffffd2273,
+        0x377fa6d8effd4ce7,
+        0xa18b9ce6240d5cfe,
+        0x85e0e46a02f34ac3,
+        0x1b9def983d905118,
+        0xfedca4e0dab6dab7,
+        0xe8032780065b331d,
+        0x6375c9bcbf95dcfc,
+        0x91839d10b3a2f4a7,
+        0xe7f525ece894c694,
+        0x3c68ef25b766fbfc,
+        0x00c8e4d4ab6ba4e8,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0608fffffffe917f,
+        0x0f0872a0affea6b5,
+        0xdbcc4aca877f009b,
+        0x8f40fd962cad79dd,
+        0xc6bd61ba58be2823,
+        0x42b336e6f6d86c23,
+        0xf5e8f13f9607ab11,
+        0x69f8b7fad28d630a,
+        0x720309801a155974,
+        0xd08d9f2bb533068e,
+        0x86f85c32de30fd9d,
+        0x00f5e67cd377b979,
+    ])),
+    Felt::new(BigInteger768([
+        0x136efffffffe16c9,
+        0x82cf5a6dcffe3319,
+        0x6458c05f1f0e0741,
+        0xd10ae605e52a4eda,
+        0x41ca591c0266e100,
+        0x7d0fd59c3626929f,
+        0x9967dc004d00c112,
+        0x1ccff9c033379af5,
+        0x9ad6ec10a23f63af,
+        0x5cec11251a72c235,
+        0x8d18b1ae789ba83e,
+        0x0024f5d6c91bd3ec,
+    ])),
+    Felt::new(BigInteger768([
+        0x136efffffffe16c9,
+        0x82cf5a6dcffe3319,
+        0x6458c05f1f0e0741,
+        0xd10ae605e52a4eda,
+        0x41ca591c0266e100,
+        0x7d0fd59c3626929f,
+        0x9967dc004d00c112,
+        0x1ccff9c033379af5,
+        0x9ad6ec10a23f63af,
+        0x5cec11251a72c235,
+        0x8d18b1ae789ba83e,
+        0x0024f5d6c91bd3ec,
+    ])),
+    Felt::new(BigInteger768([
+        0x1571fffffffd9c9e,
+        0xdd2780a35ffdc000,
+        0x02f22ea2a18db21f,
+        0xab75e537f40ecccf,
+        0x2eb4245a1ffb990c,
+        0x3df63d3e886eb6ab,
+        0x40b581c029adfa18,
+        0x4022e1be7966bbf9,
+        0x162d4490aaf12c2b,
+        0xa2709b890183c465,
+        0x64c0d06a1801521d,
+        0x0076ed55ba43bc6a,
+    ])),
+    Felt::new(BigInteger768([
+        0x1774fffffffd2273,
+        0x377fa6d8effd4ce7,
+        0xa18b9ce6240d5cfe,
+        0x85e0e46a02f34ac3,
+        0x1b9def983d905118,
+        0xfedca4e0dab6dab7,
+        0xe8032780065b331d,
+        0x6375c9bcbf95dcfc,
+        0x91839d10b3a2f4a7,
+        0xe7f525ece894c694,
+        0x3c68ef25b766fbfc,
+        0x00c8e4d4ab6ba4e8,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0608fffffffe917f,
+        0x0f0872a0affea6b5,
+        0xdbcc4aca877f009b,
+        0x8f40fd962cad79dd,
+        0xc6bd61ba58be2823,
+        0x42b336e6f6d86c23,
+        0xf5e8f13f9607ab11,
+        0x69f8b7fad28d630a,
+        0x720309801a155974,
+        0xd08d9f2bb533068e,
+        0x86f85c32de30fd9d,
+        0x00f5e67cd377b979,
+    ])),
+    Felt::new(BigInteger768([
+        0x0608fffffffe917f,
+        0x0f0872a0affea6b5,
+        0xdbcc4aca877f009b,
+        0x8f40fd962cad79dd,
+        0xc6bd61ba58be2823,
+        0x42b336e6f6d86c23,
+        0xf5e8f13f9607ab11,
+        0x69f8b7fad28d630a,
+        0x720309801a155974,
+        0xd08d9f2bb533068e,
+        0x86f85c32de30fd9d,
+        0x00f5e67cd377b979,
+    ])),
+    Felt::new(BigInteger768([
+        0x136efffffffe16c9,
+        0x82cf5a6dcffe3319,
+        0x6458c05f1f0e0741,
+        0xd10ae605e52a4eda,
+        0x41ca591c0266e100,
+        0x7d0fd59c3626929f,
+        0x9967dc004d00c112,
+        0x1ccff9c033379af5,
+        0x9ad6ec10a23f63af,
+        0x5cec11251a72c235,
+        0x8d18b1ae789ba83e,
+        0x0024f5d6c91bd3ec,
+    ])),
+    Felt::new(BigInteger768([
+        0x1571fffffffd9c9e,
+        0xdd2780a35ffdc000,
+        0x02f22ea2a18db21f,
+        0xab75e537f40ecccf,
+        0x2eb4245a1ffb990c,
+        0x3df63d3e886eb6ab,
+        0x40b581c029adfa18,
+        0x4022e1be7966bbf9,
+        0x162d4490aaf12c2b,
+        0xa2709b890183c465,
+        0x64c0d06a1801521d,
+        0x0076ed55ba43bc6a,
+    ])),
+    Felt::new(BigInteger768([
+        0x1774fffffffd2273,
+        0x377fa6d8effd4ce7,
+        0xa18b9ce6240d5cfe,
+        0x85e0e46a02f34ac3,
+        0x1b9def983d905118,
+        0xfedca4e0dab6dab7,
+        0xe8032780065b331d,
+        0x6375c9bcbf95dcfc,
+        0x91839d10b3a2f4a7,
+        0xe7f525ece894c694,
+        0x3c68ef25b766fbfc,
+        0x00c8e4d4ab6ba4e8,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0608fffffffe917f,
+        0x0f0872a0affea6b5,
+        0xdbcc4aca877f009b,
+        0x8f40fd962cad79dd,
+        0xc6bd61ba58be2823,
+        0x42b336e6f6d86c23,
+        0xf5e8f13f9607ab11,
+        0x69f8b7fad28d630a,
+        0x720309801a155974,
+        0xd08d9f2bb533068e,
+        0x86f85c32de30fd9d,
+        0x00f5e67cd377b979,
+    ])),
+    Felt::new(BigInteger768([
+        0x136efffffffe16c9,
+        0x82cf5a6dcffe3319,
+        0x6458c05f1f0e0741,
+        0xd10ae605e52a4eda,
+        0x41ca591c0266e100,
+        0x7d0fd59c3626929f,
+        0x9967dc004d00c112,
+        0x1ccff9c033379af5,
+        0x9ad6ec10a23f63af,
+        0x5cec11251a72c235,
+        0x8d18b1ae789ba83e,
+        0x0024f5d6c91bd3ec,
+    ])),
+    Felt::new(BigInteger768([
+        0x1571fffffffd9c9e,
+        0xdd2780a35ffdc000,
+        0x02f22ea2a18db21f,
+        0xab75e537f40ecccf,
+        0x2eb4245a1ffb990c,
+        0x3df63d3e886eb6ab,
+        0x40b581c029adfa18,
+        0x4022e1be7966bbf9,
+        0x162d4490aaf12c2b,
+        0xa2709b890183c465,
+        0x64c0d06a1801521d,
+        0x0076ed55ba43bc6a,
+    ])),
+    Felt::new(BigInteger768([
+        0x1774fffffffd2273,
+        0x377fa6d8effd4ce7,
+        0xa18b9ce6240d5cfe,
+        0x85e0e46a02f34ac3,
+        0x1b9def983d905118,
+        0xfedca4e0dab6dab7,
+        0xe8032780065b331d,
+        0x6375c9bcbf95dcfc,
+        0x91839d10b3a2f4a7,
+        0xe7f525ece894c694,
+        0x3c68ef25b766fbfc,
+        0x00c8e4d4ab6ba4e8,
+    ])),
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+];
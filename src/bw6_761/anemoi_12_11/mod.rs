@@ -0,0 +1,5268 @@
+use super::{sbox, BigInteger768, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 1152 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xddf84bd0d085ad65,
+                    0x0b87e99d2b103967,
+                    0x20b7610ac27f7271,
+                    0xaa73e052483cb17d,
+                    0xe092ceb4acda2e0d,
+                    0xd41d0e82cb2f7ad0,
+                    0x59dcffd7755ca7b6,
+                    0x0567d5b7a9fbecd1,
+                    0x74d3ef6a1dc7a807,
+                    0x8f870c36e032d963,
+                    0x7c7ab006826573ee,
+                    0x012186634d148287,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf16a098385f9a1b0,
+                    0x2169bb4e59f517cd,
+                    0x4a773977c66e54c0,
+                    0x12ea489b1ca29c69,
+                    0xf4ed98ac5878bb86,
+                    0xe7a0fd338bac1957,
+                    0x89ff32246e4d9525,
+                    0x2e147cbfe5e782c4,
+                    0xfa3a7bfea1d5aba8,
+                    0xcca7f4203118cec4,
+                    0xf36e5ac6c9174034,
+                    0x00087e43a68783e7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x798fb7565170cc8c,
+                    0x58a21d63726ffcc7,
+                    0x1cdad84cdb65f159,
+                    0x1f1c936085130d99,
+                    0x3607c5ea76d27724,
+                    0x133873c829c7d8fc,
+                    0xe79c9c8172099b16,
+                    0x557c305929ec1da4,
+                    0xcf57b6721d7ad893,
+                    0x596f26511d98e156,
+                    0x7044e80cceaa72c1,
+                    0x002029ef88549b1c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c1e0fc19a00ee8a,
+                    0x70258f71bc9c3349,
+                    0x6312bf5f23dab781,
+                    0x92c549c9173a45b7,
+                    0xb3c5d9cd6464765d,
+                    0x7110e120642afd8e,
+                    0x4a54dc21fcfb08bf,
+                    0x30e7f1758bacae3c,
+                    0x284f62be149a8551,
+                    0xcbc46894f24702b6,
+                    0xd2602f8f25cb4a71,
+                    0x008685ee464fe755,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03419ab6038d7728,
+                    0x14482fe0549a3f8c,
+                    0x00338fd7eb41c125,
+                    0x237bb0912cae0f87,
+                    0x505f911caaac5d74,
+                    0x387be1bf8a5eab7a,
+                    0x7518ce4915c477b1,
+                    0x56f29a53c35b3acf,
+                    0xb77d5e83161dcc20,
+                    0x59b7ddbb8812b0b7,
+                    0xa0e9f3c062ccd894,
+                    0x00de9ef163c93b1e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcde52a10da06ae98,
+                    0xea5823e877935350,
+                    0x15f9d2c21d0f7ffd,
+                    0x2268bf411abe687f,
+                    0x30b4f0b8b5e14fb5,
+                    0x52ca94ce9295203a,
+                    0x3ee160b180457f75,
+                    0x35a1a46ac390fa0d,
+                    0x31783acac44e1da5,
+                    0xac83fcb3fa5b9662,
+                    0x98f64e4a266faa44,
+                    0x00e3df56fe285186,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc28749e65a0b68ad,
+                    0xe2e203a9834855de,
+                    0x26cac2bf1b094107,
+                    0x12a2cb6749bc336d,
+                    0x0cb404d630baa299,
+                    0x90853ad036a1a5bd,
+                    0xe28c3ed71d80dfaa,
+                    0xd54dbdb03853d0fa,
+                    0x64be4569fd39a8d7,
+                    0x0bad41df47fb1c78,
+                    0xa172bfc46672d61a,
+                    0x00444aead036d050,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7deb7c4b13a5b6ec,
+                    0xcff5c2c05469777e,
+                    0x62e50c72fc7216f0,
+                    0x445c7a9d0dfa4e01,
+                    0x82ebd6cb1b65555b,
+                    0x1a5138e60eba9b28,
+                    0x817df4b97e03cf8c,
+                    0x8ea2e44fd0536614,
+                    0x7ebd392581392561,
+                    0x98eac494e41e2de1,
+                    0x54fba6a1767dab78,
+                    0x00d9d5123d2154a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb5849811144f0945,
+                    0x06fe397d1e008442,
+                    0x113b3ee376b95934,
+                    0x6915fad2e552dd79,
+                    0x7a001d303201b0db,
+                    0x3fe2d383a3ad94db,
+                    0x6e0b270c05c65464,
+                    0x4b941da5ab8cf443,
+                    0xbcc02f92873fb3b5,
+                    0x3927261aab2d5773,
+                    0x65d831258de17c26,
+                    0x010550e4d33029b1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x948c8f07eed0c1c8,
+                    0xbb9478600333d1aa,
+                    0x58579fe8f705dfbb,
+                    0xa909ffc8564a28ed,
+                    0x6363d1ebb4449abe,
+                    0xe15613702c80179d,
+                    0x043cf69d9e207ba0,
+                    0xa4877fbf0347f850,
+                    0xfcdb8a4e79389785,
+                    0xf809c168ec8fdb6a,
+                    0xd00e5e451709b905,
+                    0x00bc29130c0f761f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x636782c5d5b12261,
+                    0x0a275d50c3465eb0,
+                    0xdfbaefec98147907,
+                    0xcb32bb1483247958,
+                    0x00aec5ab47f42404,
+                    0x67e8e993ce6a81f7,
+                    0xa6c31cc363e203d3,
+                    0x22a1569dacd6f01c,
+                    0xd95ad154ed3390c4,
+                    0xf7c62491167960e0,
+                    0x9e123868491276a6,
+                    0x00522a01babded04,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7fcf91ea4bc4190f,
+                    0x0840e6102fb500d4,
+                    0x08e227c2bbb75634,
+                    0x5615dce500c2ef20,
+                    0x92c04b1d3723dc70,
+                    0xa0d7a9d793212944,
+                    0xe0f7708d4c19043d,
+                    0x0f4d0ba5e8761468,
+                    0x4ea5b66756d6b665,
+                    0xac2535fa945bc6de,
+                    0x87e81a08ac43f705,
+                    0x01021d7a26d83702,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1bbf88b80e236563,
+                    0x5a6836428b0fb5de,
+                    0x9a576914c14a770d,
+                    0x8fe0c33de174890c,
+                    0x0b9708f095efef34,
+                    0xa396a4ef81a05e4f,
+                    0x05d24b9a9cb6dcb9,
+                    0x10bfa7d1bfce955f,
+                    0xab57894f26e4e04e,
+                    0x6ae0754fea1f778a,
+                    0x7af9330868c7cc7d,
+                    0x00ab4a6e7214f51c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf0a5ad1d44d68f13,
+                    0x909a61a3c5569655,
+                    0x8a2612b249b3d64e,
+                    0x1b944a516430adf4,
+                    0xad99f68363dd9b4d,
+                    0xa4ddd59e0ffd180a,
+                    0xacf5242af7709500,
+                    0xc756b44fff692819,
+                    0xbd30f842a3199517,
+                    0x74de406dce27fef0,
+                    0x150b8b511be9d082,
+                    0x00ebe5bcaf392af1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x318e824f4f83175b,
+                    0x9f8265450f1e37ac,
+                    0x84fd418ec3e63eb2,
+                    0xf1f646fb305571eb,
+                    0x28290cef80eacdf9,
+                    0xf1c772d5ea2b325b,
+                    0xc08c82af17a018af,
+                    0xe8f2b365e1664a3d,
+                    0x62a36c68214b480a,
+                    0x4d559f67fec0f85f,
+                    0x41e4f190ed5e48b6,
+                    0x0004b56132744ce0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe835dc6a1aa281fc,
+                    0x3b0355694a370fad,
+                    0xe6cf54f8bd461ace,
+                    0x68ec5cbb063afe86,
+                    0xbd6f873f1fc7123f,
+                    0x26a1931148694d96,
+                    0xb088b40880c29d86,
+                    0xd07d925a2cfa2c38,
+                    0x3ac316769b9d046f,
+                    0x6ff72b22f600e16e,
+                    0xde1fe7831cb0c654,
+                    0x005eecffd3ac9e53,
+                ])),
+                Felt::new(BigInteger768([
+                    0x36822c0a236060bc,
+                    0xde81306ad146da11,
+                    0x64ef8ed2d0406961,
+                    0xca6e656474dc04d1,
+                    0x1724b25e51868977,
+                    0x25ca8ddb1ca8e118,
+                    0x09e75bffe54ebe28,
+                    0xaf2b2e5ebca5bc2e,
+                    0x18dcdd446c51ed32,
+                    0x2b9d7fd44dc5b360,
+                    0xefc172ddc07dae8d,
+                    0x001be15b1f06ee9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0f3161ce400b370a,
+                    0x67bfeca5785a69cf,
+                    0x7ff69d557d79058e,
+                    0x8a10b63c948e7d33,
+                    0xb0468a865fead54b,
+                    0xc6b1348e5b5aca68,
+                    0x2d7e0de52c31690c,
+                    0xaf9571b1efd4c2c4,
+                    0x95a662243038c265,
+                    0x02bae9c3fc8aa562,
+                    0x74edf0548ba9ebe7,
+                    0x008f8331334b4de3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf79ecf50af74503b,
+                    0xe84e913f2a750c0c,
+                    0x88b0140372b27b14,
+                    0x492627a4a43f3694,
+                    0xbc7b724ccfebb3f4,
+                    0xc09fa40721f026d9,
+                    0x36c52a5b5041a2d9,
+                    0x35fb833cba0fd8b0,
+                    0x59b224761076ad0f,
+                    0x2b9d0e1419850cd7,
+                    0xd84377672f59561b,
+                    0x00732a7feb5fce9a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1f76472623c8c88f,
+                    0x229afffaae17b28e,
+                    0x224c3b6179c68a72,
+                    0x367d198829733da6,
+                    0xb40c177c0d64cb8e,
+                    0xe7bf8d20704ae47c,
+                    0xc5793dfeb50323b2,
+                    0x351df8aa067614f4,
+                    0xa47ded9349d98cdc,
+                    0x7e3cac2de8765941,
+                    0x533b32e67441605d,
+                    0x001637ef7f11f44a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0430ba1bc011725d,
+                    0x35454095e67b97b9,
+                    0xca6eda5a6cd8ea8c,
+                    0x0c9d197e1fefaac9,
+                    0xa440fe22537d3992,
+                    0x061666a1f03f4122,
+                    0x6f806b646698640c,
+                    0x89fb2c35e86ed9aa,
+                    0xd19a81f5fdaeba51,
+                    0x2adee21dd0961cb1,
+                    0x4fdba12438a6bac1,
+                    0x0095fb5b3b0b539e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x89705f1eb99e1b9c,
+                    0xe51bc4e1f3d1fcca,
+                    0xf6d484d0620a482b,
+                    0x455fe27c2b7107e3,
+                    0xecdee21674b3a203,
+                    0x7e898e565f9a7ccd,
+                    0x4287cb7ffc7baabc,
+                    0x75937606ec933780,
+                    0x5bf47fa454b56f21,
+                    0x39d08776997349d4,
+                    0xfdc3e0a91bb889be,
+                    0x00a4b13f10782f46,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe3ae364488fac741,
+                    0xbd14b26f41586a63,
+                    0x76d6807c7a5d4fa8,
+                    0x4d467cd6f8c45eb6,
+                    0x0ba6b63e60fb4fb3,
+                    0x653fb5d732504d9f,
+                    0x2e8a352c7e1ada6f,
+                    0x236d8f896d13396a,
+                    0xc9e5d8210a964efe,
+                    0x3978833c64cc6335,
+                    0x0f226bf640a50d0f,
+                    0x00a50aa104cbc351,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6864a239e5274385,
+                    0x7939cbefe1afde84,
+                    0x832e06e898fdd244,
+                    0x5386f557842cf9cd,
+                    0xba8e5da3db93c89f,
+                    0x0876e0f764df5d54,
+                    0xb9ab83fce612617d,
+                    0x21bac59dfe398dcd,
+                    0x6a075354b4746d31,
+                    0xb5e752674e652e07,
+                    0xb60c76d8c4e081e8,
+                    0x00485c23c94a3585,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7f4809fab79d31a5,
+                    0x49afb55f1e7e55f9,
+                    0xa71da1fe1bfc2edb,
+                    0xa0b5962d8f4e2714,
+                    0xf70d1689d0e22ff1,
+                    0x98b3f266c5b7ee9d,
+                    0xcd17c2e6321dc47a,
+                    0xc556dff02dba79e2,
+                    0xeb5e7a806cec11a1,
+                    0x6dc5fd98d39607b1,
+                    0xe6ec0a17683192e5,
+                    0x0052e8e6f9be7616,
+                ])),
+                Felt::new(BigInteger768([
+                    0x93127db7117caac1,
+                    0x8dc1ca772c49b38c,
+                    0x2e68f178bb8114e6,
+                    0x363981c2cf5c47c4,
+                    0x1c3778261439b138,
+                    0x3fddcd81699fc103,
+                    0x9fae9c34945fe23e,
+                    0x8dfd631cc6d9651f,
+                    0x0f042f8bc034fbd0,
+                    0x336c53b1b58147c1,
+                    0x740c397bdd5fe220,
+                    0x00b5514db09aeda9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50ddc7906a20493d,
+                    0xa85e1d5f2d551768,
+                    0xe6b57fe7aa03a519,
+                    0x6758fa7a1e771b2b,
+                    0x49140df9e1210ea1,
+                    0x8d40c605fd5a8619,
+                    0xf6d90a1c4ee05c7a,
+                    0xb2b9198cda410358,
+                    0x95bf1321b9c9b091,
+                    0xa6031eaadc46126f,
+                    0x72b9b95e0bf0666f,
+                    0x00c7b2b73d620cc8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea449167f92db024,
+                    0xdfdc0c34426620fc,
+                    0xd262fd33a968bf99,
+                    0x8825f2bde8b69ccc,
+                    0x26449923fb12af21,
+                    0x01c343ca49e8e2ff,
+                    0xef9050aa257b8744,
+                    0x8490f4131c6c34ab,
+                    0x90f711abf331cdbb,
+                    0xbb80f48b280ff030,
+                    0xb8ee6a6eb123251f,
+                    0x00829a33ae90fabd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfc1910b919df43a1,
+                    0x6117a20b11dd3be9,
+                    0xef0aa96d5a8e1444,
+                    0x3c5f83ff562f74ef,
+                    0xa325c78c025db74e,
+                    0xbe256471a327a67c,
+                    0x94f66568ec5480d9,
+                    0x0128e94823cfb987,
+                    0x7a2435fc743cc96e,
+                    0xa5f5379c5f04d6d4,
+                    0xefc31ae3b6e7257c,
+                    0x003e4537fee7df6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7bbe82fbeead4ead,
+                    0xc7dfa3eac135797c,
+                    0xde258ce1cf2d2d0e,
+                    0x540b221ce6505deb,
+                    0x9a400bfd9f84e12d,
+                    0x9e4ed576a7aa4146,
+                    0x7f764ab6f9b9d7e4,
+                    0xce6dc6a9ad98ef70,
+                    0x7b9dd4f575f5ca21,
+                    0x842bf599b728d71b,
+                    0x746fdf9932f93a22,
+                    0x0066549f618343c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe81fa49ca4925e97,
+                    0xcd25cf24ec44edef,
+                    0x1733b7fff1ae2e65,
+                    0x585d059fba0bc584,
+                    0x7d90da09204326da,
+                    0x6ecff9cf1dd7ac34,
+                    0x77a40df395d48cf3,
+                    0x053f4dde33deb87b,
+                    0x633f6eeed941d44e,
+                    0x75206871d40c0ca8,
+                    0x133cf76c35d36f79,
+                    0x00f1c0d74d9c08f6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x001db31b0f672a77,
+                    0x3b335f2bbd3c8fe4,
+                    0xb9ca4f837af6a9fb,
+                    0x77e71b5322a6e6ed,
+                    0x462cbda220deb67b,
+                    0x11a89ad6730e815b,
+                    0xf2eead8797b455ce,
+                    0x1473ade9d3f8c38d,
+                    0xf027fcfd2a9c0293,
+                    0xe899a629b7276940,
+                    0x19215594c7a462a8,
+                    0x0036de2c5e7b4a81,
+                ])),
+                Felt::new(BigInteger768([
+                    0x044a853b9902dc2d,
+                    0x6337d7f2c16f1dce,
+                    0x9cece455aaf35f66,
+                    0x0306c63104389035,
+                    0xd6bdff37a43edb05,
+                    0xabccff6887437345,
+                    0x1bbcf920bf109578,
+                    0xb65517a6da3bd575,
+                    0x0e293d4cf787da7d,
+                    0x01d38576adab4ab0,
+                    0x154d6228f73b1491,
+                    0x00c0a9a4b8a78a41,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38466aff33aa2421,
+                    0x8fa379de97fc90b8,
+                    0x84e65abd36fd1511,
+                    0xc631a8389181bcdb,
+                    0x844c6c836d82dd10,
+                    0xd0ef586e968c45d4,
+                    0xc29c4f36337c17ab,
+                    0xd6e0fb414f21b646,
+                    0xc057c44f40848c98,
+                    0xaf4175c69eb3cb3e,
+                    0x82d9bb91d5e11b06,
+                    0x00e1bb4770da0f67,
+                ])),
+                Felt::new(BigInteger768([
+                    0x89e233da23f794a7,
+                    0x526d845fa7fe7d40,
+                    0xb1f34331bb76e576,
+                    0x1d22776f77a25906,
+                    0x653a673308ee8ee3,
+                    0x4d8230a700e96e9d,
+                    0x239a4cbd9736ee4c,
+                    0x4d90c96282ce6014,
+                    0xbe0f35274e6c0e1f,
+                    0x0ac5f738b5bf5a62,
+                    0x8c63707f9954b756,
+                    0x00ff9ad31ea998cf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b41604166468b55,
+                    0x3ead63f83e9e9edd,
+                    0x745c37bdc20ff163,
+                    0xc528670bd719df37,
+                    0xe3abcd59d8114791,
+                    0xaf36126b14702ec6,
+                    0x776dee457eac62a0,
+                    0x7aad84346d04b61f,
+                    0xbe92043ddf472167,
+                    0xd0a53763a0f72b2e,
+                    0xdd65e22734f4963b,
+                    0x0009be270ee92cfb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x76ab09635b6e807a,
+                    0xb11dc48bce5dcd62,
+                    0xa96a8ac400bfafd0,
+                    0x59f7d7f86fb00518,
+                    0xafba3c1e24e6f0c7,
+                    0xe9ff613b708630ce,
+                    0x9e56fa4b91192a1b,
+                    0x06a3c286651d9de1,
+                    0x9253f49d082dd4da,
+                    0xe8dae7860b0adfa4,
+                    0x64085a73cc2ee25f,
+                    0x009ce6463668dbaf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1dfc8691f3a4d16a,
+                    0x7436545174fcbb21,
+                    0x548ba0e036154558,
+                    0x13b6c6c2098290c6,
+                    0xc403b41caa9780e3,
+                    0x59e32d01d2aa0e8d,
+                    0xfb8cfe73d979d6b6,
+                    0x0487fef3ba543c65,
+                    0x863e2c9aaa202008,
+                    0x755b33a9a0cbb6ae,
+                    0x4b78e45f0d0cd781,
+                    0x0008f2f7e7c0ebd0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72036cb9b7d02a49,
+                    0xb011bee067935644,
+                    0x87d0f9ed23e557da,
+                    0xf74c1b3634f9a210,
+                    0x73e8e6edd62bd347,
+                    0x7260aef4cfd76116,
+                    0xed8e8dc744ff3524,
+                    0xc248880b06d725e0,
+                    0x4e9bebf893db6d6f,
+                    0x6b77c10244c93fe1,
+                    0x21b868a70f7fd010,
+                    0x0049584b82cdf8f5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda68ef9a3a112537,
+                    0x694571eb798902da,
+                    0xcafcd3c3aae2f6e1,
+                    0x35625bff4819549b,
+                    0x4d25d4d100b24c7f,
+                    0xe53e8a186adcda60,
+                    0x7164740c173bb941,
+                    0xbf88ed82469b6aed,
+                    0x4c794d086b3b6155,
+                    0xc7e86f5ef0e8f3e2,
+                    0xcd9826243d07b542,
+                    0x00366f035ceadfee,
+                ])),
+                Felt::new(BigInteger768([
+                    0x63d639d1e0ee6079,
+                    0xe5db037c3e8138f1,
+                    0x9530833ae6b30ecc,
+                    0xb2340120a3da80cb,
+                    0xc6adf9dccbd23042,
+                    0x2db730db26b50e60,
+                    0x509423f472ae8ba2,
+                    0x0990aa2292762981,
+                    0xfa01290b971e1997,
+                    0x849883b67dacb4f7,
+                    0x75e5ea562db00145,
+                    0x00f185961465655d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9bb81db9872d66b9,
+                    0x37a78a0636cf63fa,
+                    0x5ee286510dc92cd8,
+                    0x623b570ce946e7cf,
+                    0x5d5fbb61aac8d47f,
+                    0xce8255a0a150dc52,
+                    0xf741d122f0b239ea,
+                    0x81557438a21e3b9f,
+                    0xc0d03341e1734024,
+                    0x1808be3ad66e3d2a,
+                    0xb2cb7791f4d468c6,
+                    0x011cff38dc89ad5c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x05b93b7cbeff723d,
+                    0x6d6c5fc3699531fb,
+                    0xebf42a4f26039002,
+                    0x69609b32da0fd3c4,
+                    0x4212d195eb7e7260,
+                    0xfe2e5517f46dfd9f,
+                    0xcaabc15ae9907376,
+                    0x66df0f85ce1c426f,
+                    0xf875981494a2fca5,
+                    0x7e4f940884731c86,
+                    0xe363a62ff5d6dcac,
+                    0x00cf141edf2a5300,
+                ])),
+                Felt::new(BigInteger768([
+                    0x47cc60088cfa007c,
+                    0xfd916179e6d6e9ce,
+                    0xd253eb068bc07292,
+                    0x5c491c086a2f3c13,
+                    0xe43df65c7a3cee14,
+                    0x870c79ae08823736,
+                    0xcaea7cbb09e167f2,
+                    0x99bed858f94d1741,
+                    0x5497ac4ab03cade5,
+                    0xd8e6160229095cc9,
+                    0xc164649041d0ff12,
+                    0x000fa22c385ce5a4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5fd9c3c2b86d3654,
+                    0x65d7a9d2fa505d31,
+                    0x9ac6c8a23317f0e5,
+                    0xd9f77643acf8f803,
+                    0xd5f01f4bea0287c3,
+                    0x340f48a3804a2765,
+                    0x9be79af8b7ec8087,
+                    0xd432f4b389ce0fd2,
+                    0xbe02a6c5c0fe7dac,
+                    0x823b0598afdf3b07,
+                    0x7532c220b792aa97,
+                    0x00a5ba360483d405,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5425b2d7221f2fda,
+                    0xa75c8aa2d96da80e,
+                    0xabd266375ce00958,
+                    0xe123d66c2c7e9852,
+                    0xc48f4400b9271cb2,
+                    0xe293d9ffa10a5827,
+                    0x023726ed83217e18,
+                    0x2efe68d77d5d6adb,
+                    0x0713df1a7577c921,
+                    0x948cdc81c8367278,
+                    0x70b976c02c6b8829,
+                    0x011fcf08f5f7da0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x23d719af4973f09b,
+                    0xc377d52773707fac,
+                    0x09d28e37f86dce9a,
+                    0x1aa7221a4b550ef4,
+                    0x3098f3a651b317cc,
+                    0x65f4c60ce4cab224,
+                    0x5304790c4d1ca14a,
+                    0xd8b5aba650938549,
+                    0xa49b2113ba5ff292,
+                    0x369b3dbb7c9a850e,
+                    0xca0c1c963fd7f746,
+                    0x003e808b4e99f5fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0066d01ed8759951,
+                    0xd55ee5b99c9f2502,
+                    0x32376b7b91e282d5,
+                    0x4420a3aae3ad7610,
+                    0x458040b9d7fff1d6,
+                    0xf14e259495404741,
+                    0x7bc4561d53547589,
+                    0xba5e28afdd0e644d,
+                    0xc91c023fdc1a82ae,
+                    0xeec9fd76bab781bb,
+                    0xa0e8c70f6dc554f8,
+                    0x00f0adaf26532030,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc04e856f447d9712,
+                    0x69af2fc74cdc1621,
+                    0x7d537a7061f543f8,
+                    0x087299fe0086ad4d,
+                    0x36d3666503fed130,
+                    0xe70e123168a92a9e,
+                    0x83926c09c419e8e3,
+                    0x13926d7559bfa24c,
+                    0x4b7f3042b98b8b85,
+                    0x7004c05a6b5f21e9,
+                    0xfc1e165b1188b596,
+                    0x00499aad801c5256,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2c697d8a6c080288,
+                    0x238c639caf8b6b69,
+                    0x361ab7c3ee870f15,
+                    0xc9bccb9583a7a740,
+                    0x8117b105efe630c0,
+                    0xa512454eb863649f,
+                    0x2b83573fdefb54f2,
+                    0x2f59622b98410284,
+                    0xe1b3a72c3414e247,
+                    0x096c0e10924dd61a,
+                    0x7792f7845866761d,
+                    0x008fdf820ceb0437,
+                ])),
+                Felt::new(BigInteger768([
+                    0x04723ea921a32e40,
+                    0x9027f4aaab903776,
+                    0x1b06bc636c28ae70,
+                    0xd71ebfc1e1e50a16,
+                    0xce203adaca6abaed,
+                    0x3b0066b1c6b1e6a6,
+                    0xacc013b3114d2836,
+                    0x774842500245fa6d,
+                    0xbe9f0e48dacbc362,
+                    0x617be358c70398ad,
+                    0xcdb05977149bac59,
+                    0x0115590be4c31d04,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa80a8bba6fdc28ea,
+                    0xbcf81da0dc8fad61,
+                    0xe69aae8c4533692b,
+                    0x01bf735b5b0ff714,
+                    0xffa6f89e313e2699,
+                    0x13424c5de6021944,
+                    0x9699728f19439bd2,
+                    0x62c9df73997d50ec,
+                    0x9cb318913ea5fbb0,
+                    0x4a9ead39f9ea2ba3,
+                    0x3751096e3c23063c,
+                    0x00afbf156cf33f6c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf8cbf68f2f053683,
+                    0xaba2bbdba758b425,
+                    0x5bde3e4b088150cc,
+                    0xffb341a252dd15f1,
+                    0x1d4dd12367a07528,
+                    0xf7fb9ed490ca0fed,
+                    0xa421cbaa9ed4ccfd,
+                    0xfb5ce29446988d3c,
+                    0xd2dedc004a8ef802,
+                    0xb77fce697d8c3fb0,
+                    0x7188c26b2dddb72f,
+                    0x007d3971a573cb04,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8183faa144464fcd,
+                    0xe250ddf596005b43,
+                    0xab2e14fa697b708a,
+                    0x25890cf01fcb51b8,
+                    0x5bd186c249c654d4,
+                    0x0c6883cbdd5704c0,
+                    0xa6384b55c0e3f03a,
+                    0x1161b9dcdde71356,
+                    0x9257c656d90a4678,
+                    0xa818ba52f5065b87,
+                    0xc62f6153903c3357,
+                    0x00c256915ec31276,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2a4f9e91b5b774cd,
+                    0x4a5d78fbf416c525,
+                    0x10b5daef8be22113,
+                    0x0cfc39ec3d7c5d55,
+                    0x18d717fc0aa6b845,
+                    0x134240241223260b,
+                    0x2d73d8b9ed70a730,
+                    0xede7d59dac597393,
+                    0x3a6891d1bf2b54cb,
+                    0x662215229e9cf3c1,
+                    0x9c773a353ed7230c,
+                    0x002fdad3b86c8353,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc5500a0ff31954a1,
+                    0x3e3d2144a1b70bf7,
+                    0x27db7c225c4ac9d4,
+                    0x19a800f0ba10f855,
+                    0xd2e284bf61b9e00a,
+                    0x2a4292308fd4de9c,
+                    0xf39ca3afd62a9848,
+                    0x1d6a10a8ba87cfbe,
+                    0xccb81385220f2172,
+                    0x36fb155301b8d34a,
+                    0x8344629317b6b395,
+                    0x004b0804e7fc9678,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2c8d3b90044907e0,
+                    0xf26ec9f75affc5ae,
+                    0xd65a1dcf2c2300b3,
+                    0x87b9bfd5c26c5bb3,
+                    0x98d7f30d5ccf0a3d,
+                    0x07812e939be22d4b,
+                    0x5f9d3a28f17e163c,
+                    0xe83bdeb3ec2dde75,
+                    0x534d17bbeff2df30,
+                    0x31fc1959753f4fd4,
+                    0x72fa789a7f0cdc95,
+                    0x0010e6dbb482ba51,
+                ])),
+                Felt::new(BigInteger768([
+                    0x49bdd56d1b3bacb3,
+                    0x0c24b41c858014db,
+                    0x64c2c82b8b1bfd25,
+                    0x4ee3f7517437e473,
+                    0x77ce3d7343dc4f05,
+                    0x42dfb64f8fba29b9,
+                    0x43011beedd2569e3,
+                    0x2af592455dbe4dac,
+                    0x14cb18f13d6c6315,
+                    0x18a56af1a31eba55,
+                    0x309d388873845a1c,
+                    0x00c78763b8178b42,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5e12f19c7dc1920c,
+                    0x2f95ad0d46589086,
+                    0xb18da6e59b1d446e,
+                    0x214a8584a7f54d66,
+                    0x9c30371bfb40c263,
+                    0x62e2fb6e0e998c18,
+                    0xb9cb0ab2bd4a1544,
+                    0xf4aa1f87dd6882c0,
+                    0xd7805d5938e4e519,
+                    0x55419c2f06fe026e,
+                    0x266acece0bacbdda,
+                    0x0005135e45fbcaea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3dbec6acc382e157,
+                    0x92c73c64d88f73fd,
+                    0x2777cb029e4a2b3c,
+                    0x2f13a791e1b575ce,
+                    0x2747d3a38249f365,
+                    0xf48eae26a876c066,
+                    0x05b0ba2391b1b362,
+                    0x65710d485329fc9b,
+                    0x803cb1d63f9abbce,
+                    0x7233ffb672c61328,
+                    0x9034f95cbdec4d0b,
+                    0x00901dd0958ca0da,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8fd91940343e9f68,
+                    0x986e5390d36fc600,
+                    0xddccccc1a7bfc90f,
+                    0x870b3a30faa45cfd,
+                    0x4dd342cb5cad0071,
+                    0xfc2574274856d689,
+                    0xef4d45638e5d2854,
+                    0x33a001c73e8e92a6,
+                    0xb16ba457fe33553d,
+                    0xd9892e777f5aa669,
+                    0xc0396d036d1c7dd9,
+                    0x00950b37d9ef568c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26dfc4a9cc82d2df,
+                    0x7b09f49b74adfbdc,
+                    0xb0b1522b7ead9fbd,
+                    0x87b06572baf1a7bc,
+                    0x3f0ee26dbb7dfbdd,
+                    0x83d808172a841013,
+                    0x4fb609927a709077,
+                    0xc712575391c3535a,
+                    0x29ca7e8e3e12c909,
+                    0x14a9c0515367d3f1,
+                    0x4c1d874ffbfb7792,
+                    0x00d949a356800ac0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x889038f738bd0c59,
+                    0xa953daab5d5bd446,
+                    0x27b399df23524d67,
+                    0x1e32b2f57b335b70,
+                    0x112a2fcf717a31c3,
+                    0xdffc4614683a327b,
+                    0x0424bb9434d253c4,
+                    0xe9ebf257d8569534,
+                    0x034b7a7f312a92b0,
+                    0x38cb0297c585d9a5,
+                    0xce5beb8b10f85db2,
+                    0x00ea490f5ca3f045,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1c865c45cb6990f,
+                    0xb85ac54b3ccdee5f,
+                    0x7dc0eaa00f6128b4,
+                    0x23a738bbdc60cdd7,
+                    0x9ce849abc8447b10,
+                    0x54b95c8a46310465,
+                    0x90ef2a97a3efce22,
+                    0xeacda073ac50121a,
+                    0x31c3d97b01ba17eb,
+                    0x5eab9c460d8fcd8f,
+                    0x1e0ca6251444d950,
+                    0x00b1106ea72e23ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6b13c3d3217ddcb4,
+                    0x54a005c55f6c6ed1,
+                    0x882d1d6e489795c3,
+                    0x69a4248eb34f608b,
+                    0xbf2269d9b88d6cc0,
+                    0xea5c506564a96f6c,
+                    0xb982f4c5fd63e274,
+                    0xc0a80d5bdefa665b,
+                    0x35fdb09c64566387,
+                    0x189e05d871264317,
+                    0xbf530ea49e94785d,
+                    0x004260cb524816a9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f16bf6bb84038a9,
+                    0x81b6cfe498484380,
+                    0x56675856798cc62c,
+                    0x7fd3d7040a6b9c4d,
+                    0xe390448d3b3a3e23,
+                    0x152165b9c6d463e0,
+                    0xa69dabe4d1689b48,
+                    0x115d16f1c46a6482,
+                    0x6f2a1a918a5086f4,
+                    0xf69d08778c0afaa5,
+                    0x6a55acf533bdcf69,
+                    0x01107453042f8191,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1fd54513457f6c46,
+                    0x477c834e8fd116e5,
+                    0x08801f6bb4d93f70,
+                    0x1cc648cbdbd392ff,
+                    0xbbf795e031053387,
+                    0x4ceeb1695a9f2e26,
+                    0x608c966492212835,
+                    0xe696429df2c01f48,
+                    0xf61d7826e0a13c4d,
+                    0x78d068e2d17415e8,
+                    0x0fb4cb9a7addb3e9,
+                    0x00d84319fee2b18d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbe928fade3b3c75e,
+                    0xcd2f517593da8820,
+                    0xdf3fd9725fd2e8de,
+                    0x53ddcbe89e1545d7,
+                    0xa96a7bc0d3fc93a3,
+                    0x6cd1904ece7a94d7,
+                    0xbb6e2e974cdaa092,
+                    0x54a65921019fd76d,
+                    0x7ccb2f0cab58028b,
+                    0x3f2a7af80ecc56c9,
+                    0x37f465e278482d0f,
+                    0x0029dc346caa98e8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcfc3eaa2e095c0fb,
+                    0xd796544e42cfda7e,
+                    0x28387c13c297a1a1,
+                    0x83646ce259f2ee0f,
+                    0xda46c18155b2d142,
+                    0x714632e7d8bd0e45,
+                    0x18263b3b55990c45,
+                    0x0f71381cb7532de5,
+                    0x7dc3b9303cbadc45,
+                    0xb77e0d7572c5c95f,
+                    0x567b0bc40993f331,
+                    0x0070da7ac3226610,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3de2fc927e1c0bee,
+                    0x13356de94f9fde9e,
+                    0x7127fca87b88803e,
+                    0x98f6b06e587044f3,
+                    0xe136e6d79cf262a3,
+                    0x6f405402ad1f2a61,
+                    0x70cb92824fba87dc,
+                    0xeba83f3d500128e2,
+                    0xea1b4e519fbd758c,
+                    0x21155032bfe57825,
+                    0xc13e16e5a60e77e2,
+                    0x0114cf7917549321,
+                ])),
+                Felt::new(BigInteger768([
+                    0x37655a2b4faa5ef4,
+                    0x88cdbc9c436047b3,
+                    0x336a86d99d28d521,
+                    0x24d0c1c316c2c718,
+                    0x813515bd2c599eea,
+                    0xae9e2542a6c5c39b,
+                    0x8dcde5b9bfa4f4cc,
+                    0xb40484d8d7a896fa,
+                    0x28a3d49489451d2c,
+                    0x049e029aadc5d39c,
+                    0x11c1115581224bcc,
+                    0x00d6f30d1215ef87,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc865d36b537c87b2,
+                    0x287bf4b1c0671f24,
+                    0x51b3303faf9812db,
+                    0xaac098c4d12c8dd1,
+                    0x1deced0b3beee2fc,
+                    0x86a5f70052926890,
+                    0xada6bedc46224177,
+                    0x6d7e805307743b8f,
+                    0x063b816a595a26e8,
+                    0x7c21a6b3e7f5cdc2,
+                    0x11805775dd5abf55,
+                    0x002ac0fc8ac1e687,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5a1b2f1d9534b003,
+                    0xcea2be58bf32dab8,
+                    0x35113839a82e84c7,
+                    0xf7aaccc9951cca05,
+                    0x3ea0ec7f8e22645a,
+                    0xd662bb35828d3b40,
+                    0xdceb374317c0ecbe,
+                    0xf28a20f291e936cd,
+                    0x693569e00c1b29c5,
+                    0x96f16d56bd470568,
+                    0xec14fc75ca5e2c98,
+                    0x003120973f83b4af,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6271bc4757b33712,
+                    0x1ffd7948b4224dba,
+                    0x85794cc39bc6ef9e,
+                    0x3cba0cc43016a4d4,
+                    0x87f4bd6156158cba,
+                    0xa60fb25be6b1fe44,
+                    0x7959f3895934785f,
+                    0xb1cd400230149a66,
+                    0x9ca2432efbf3c50e,
+                    0x9e709a027facd5ed,
+                    0x61116881226b90f7,
+                    0x0048902725548703,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8c43cd55394a3fb9,
+                    0x476ad028301a1d28,
+                    0x376c8f0d5f1f94f0,
+                    0x068764e058cdaf56,
+                    0x43ce25e21267bc23,
+                    0x91792f044ef8d7fd,
+                    0x4becf5d90334fd5b,
+                    0x046725469c712100,
+                    0x3209dd5109b9f6ac,
+                    0xdeb36af133400188,
+                    0xe9cc4b1b52477dd1,
+                    0x00fc3c9afb30e99e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb33bc001f111b4d2,
+                    0x83111710620eec65,
+                    0x89a4dcb6bfc251fa,
+                    0x5f58abb7b40bed3a,
+                    0x13c9ac02f75d4456,
+                    0xd7280db7f72707dc,
+                    0xd4cc0c9e0f0648ce,
+                    0x5890e9033ecfc392,
+                    0xce024c2f62235b18,
+                    0x116191a5a960c62d,
+                    0x6e518f95d8f69210,
+                    0x00adf254f021411f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2beda41a44abce95,
+                    0x73ed2e6c7ddb87f9,
+                    0xdf39edb280a7e8ea,
+                    0x0a6e90bf69ccb191,
+                    0x1f033ee04799b654,
+                    0x8f06fbc079c06619,
+                    0x63fa6d0bdfe61f1b,
+                    0xa63a35fa3c7a8bd7,
+                    0x07989c666c2222e9,
+                    0x28024611097a8f03,
+                    0x1a8ac9ce21e365c6,
+                    0x002dd94b42ebe936,
+                ])),
+                Felt::new(BigInteger768([
+                    0x597d55eafe6c79db,
+                    0x78c05aa3cffa876e,
+                    0x3d7ea477ff2cab60,
+                    0x4925e930359823c8,
+                    0x977811746faab4e7,
+                    0xcbfee6be79ff0d93,
+                    0xa405d20d74ddb4e4,
+                    0xb7d9b86e37977977,
+                    0x38742e9b593204e8,
+                    0xa6bba7b987d41928,
+                    0x30ced9bfaed947a5,
+                    0x000220b28af3e491,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b5c159810fe66ff,
+                    0xfb2ae55921001820,
+                    0x9764eb905c9956bd,
+                    0x3762970b2b296b7d,
+                    0x91eb0be3e9e2947e,
+                    0x3fb3193e5667a6e4,
+                    0x945e5464f9833acf,
+                    0xf0751d6131c6ad22,
+                    0xf2e08204e46bc9e1,
+                    0x8ccc645acc524095,
+                    0x38906e2fea9cf955,
+                    0x00113d83f2c52b9b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0820fd0b81e01e4c,
+                    0x2246ff5f3c1ab0eb,
+                    0x76d68e4b029503ed,
+                    0xe784a338f302d5b9,
+                    0xd7e366c1c4c7d161,
+                    0x8763d05958fd0f8c,
+                    0xeeb7e0bd411bcb7e,
+                    0x52129ec7c7d4c863,
+                    0x1a0973c065f3b6a8,
+                    0xda3ce71168d122e6,
+                    0x2991b694129288fd,
+                    0x0023320e70ff39f8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf002b670d2bcadc2,
+                    0x6b5674852f7c08c7,
+                    0xbd7168be640412f4,
+                    0x406a6daf0d5f635f,
+                    0x0222edc54b4b37f3,
+                    0xb98b6b1b697f33af,
+                    0xc8a44846f5890337,
+                    0x629df264155ad7cc,
+                    0x181a2a9ca2df15f4,
+                    0x60ba96ba5c7574ef,
+                    0xf5000569aaf23d65,
+                    0x01146a6a98cb987c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x632011da957e835d,
+                    0x552e4c3fae75388d,
+                    0x4cc00612e4a71e21,
+                    0x078f4723b260a009,
+                    0x466f5b66e15cedfb,
+                    0x5d1b77eafa89e575,
+                    0xd41d1830f2c0991c,
+                    0xfade5fc0cfcda981,
+                    0xfa70acfc06b2ad56,
+                    0x3181718eb4311cab,
+                    0x8ecbc19154af9191,
+                    0x00f45f7bc317eff2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc8e52f8cfd06b35e,
+                    0x5cd3c41d2db3afd9,
+                    0x72a534018c29adef,
+                    0x88a6131364e5304b,
+                    0xb20c3ab915faaeeb,
+                    0x429c073de3ee2198,
+                    0x7366ac6830e64488,
+                    0x0a819652cfcbd330,
+                    0xb006a8fca7b48c3c,
+                    0x8f99e41cab71db0e,
+                    0x30a17166b4d898ff,
+                    0x00e89fd4dd5f216f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a3c6084838da143,
+                    0x6bf73a2ec9eb1749,
+                    0x6066e73ff56874cf,
+                    0xaf53e35fcc3d561b,
+                    0xe8be514420b130bf,
+                    0x13ed1ac1344a245c,
+                    0xc21a03a6e9a2f896,
+                    0x60983ffe6b14b2fa,
+                    0x10e6ce04ff99cb95,
+                    0xfcae19a1f22adcb8,
+                    0x91b5b88b735c03d7,
+                    0x003ab6153267a1d4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x06e1203f2f50ed37,
+                    0x6a0db88412deabf0,
+                    0x944570d88c2bb158,
+                    0xef6dd040682102b7,
+                    0x3616739ff66352d5,
+                    0x91cf40c3c0e93a90,
+                    0x854ec0548eababfd,
+                    0x3df5cf658b1ed22e,
+                    0xc972d58eee59f8f1,
+                    0xc9e6d63019961ddd,
+                    0x043f99c037bb4e8a,
+                    0x010dbe7b92f4f5ff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x013d10f4fc7efb2f,
+                    0x6d83ec5b39512577,
+                    0xe4d07fa9f5d580a6,
+                    0x90d0acf2a2329bbc,
+                    0x95ed3fcdf8dc2f70,
+                    0x1662d55e33598631,
+                    0x3dd6be94c5972bf9,
+                    0xe9c48f96596f3982,
+                    0x3a091569c1ca2fbf,
+                    0x8f51c22cceb59632,
+                    0x97bffe37c6a030e8,
+                    0x008cdb927ffe58ac,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4beb89bb3585aee,
+                    0x04f28ff5d5ca8577,
+                    0xd67e2b1f3b1f28dc,
+                    0x7ba44eb0ddaf6419,
+                    0x7e921546c7824f08,
+                    0xdc18de5813cd31f5,
+                    0x3be06c76353ce868,
+                    0x019198509432d0c7,
+                    0xdd79b96c2a97a955,
+                    0xcfe1bfef3e477e69,
+                    0xbad26920cd43aff4,
+                    0x0062948a94ff12a5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f7bb48c6df30f01,
+                    0x7c492d59fc76618b,
+                    0x83453a5c8bc2e152,
+                    0x90a915ffcf3b0cf1,
+                    0xc64998d1d7d7be43,
+                    0x06affe8116618556,
+                    0x79dc2f01f0bbfd80,
+                    0xd6e82ccc7f06331e,
+                    0xb6aa232eab61cb17,
+                    0x9257445d8d2f3dff,
+                    0x39eff534a45b542b,
+                    0x00d9d15d73b98baa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc40fb6b4a57c0e30,
+                    0x472d616b07a32d89,
+                    0xdfe1cd5cafffd6d1,
+                    0x39c6016e2a4f468d,
+                    0xb76a70e2f93c2f78,
+                    0x5e90e6e1615701c5,
+                    0x62e0d8b57c9f56ee,
+                    0xa9344b9ec096635f,
+                    0x153687ba328fa701,
+                    0xfbaf260adee75576,
+                    0x19f642d22c6a32c5,
+                    0x00a4904f3a8e497f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdf1852b7e62391cf,
+                    0xc1826ded8327e2eb,
+                    0xc965274c94891933,
+                    0x51384299dfaefb6f,
+                    0x95357c9b8d10f856,
+                    0x430d31329c16ada3,
+                    0x0d20fd6b23b26755,
+                    0x33dcf4edf5677523,
+                    0xef21dd7f424e802b,
+                    0x83d9994adf1de892,
+                    0xfc7b56c3cfbc8452,
+                    0x00620e6791fe3b7b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x39045e77429c8c3c,
+                    0x7aca1d460bc99b90,
+                    0xb960aa58723b97e5,
+                    0xfd1f34de88ab68b1,
+                    0xdc85af7baebcae6f,
+                    0x647d6418d22742d4,
+                    0xed191d803bf0ba4d,
+                    0x4b08fcff118e752b,
+                    0x71676d342a112291,
+                    0x570a6b374eabd99b,
+                    0x6ccb44f3ba55b6b8,
+                    0x00e87cc4fd35d5d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbbeb811123d86788,
+                    0x73d0b18c5009c088,
+                    0x51b684c6edaa62ec,
+                    0x03e82ddc752dee93,
+                    0x2ffd4bb55cb30d4d,
+                    0x5a556b3ff348ed1a,
+                    0x8391e86470170024,
+                    0x643eadef18e880b6,
+                    0x8230ec47c52c2aa3,
+                    0xe806e47a6fc34f17,
+                    0xaee46ceb657f8c08,
+                    0x009c4af4d0832a6a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4e6b79c2b78363c,
+                    0xe19895cfae6ffbe9,
+                    0x653f9dfbd6e43e40,
+                    0x74594ae1a1b082da,
+                    0x00dd41e904f030cb,
+                    0x5d661fba9d50ecf9,
+                    0xb0f3dd66bafaff63,
+                    0x0acd4068289e490c,
+                    0x4d5660f06756a4bc,
+                    0x2e96cab16e321ae7,
+                    0x9c929c8d51edd573,
+                    0x00a0d136eedc0f58,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb099ba13ac79ee2d,
+                    0x84ba59cb481f4137,
+                    0x803c7d4e3e5facc4,
+                    0x3ed3566048e6a28b,
+                    0x4702867663e46324,
+                    0x36b789559c8ad25f,
+                    0x64395100c3e6a335,
+                    0x28c0fd59f471dc84,
+                    0x2732df70af1ab3a6,
+                    0xf0efe05b1a7eab38,
+                    0x67b169b19fa3173a,
+                    0x00a7d64457bf5521,
+                ])),
+                Felt::new(BigInteger768([
+                    0x77c01836506ba612,
+                    0x973b6b9ad0853969,
+                    0xe45bf4ca409eaa81,
+                    0xd61f5145445e9e6c,
+                    0x5ebedc093122cc22,
+                    0x4d4dd7ea9b34220d,
+                    0xa0c7f0cfcd222929,
+                    0x23a2c8a7cfe01202,
+                    0x6cf4d575079eadec,
+                    0xaa59b556ee56ddfa,
+                    0x4f8d3c18f7a990ce,
+                    0x0062dc9b263fb111,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f9a24e4661c642c,
+                    0x8e6dfc59d947736f,
+                    0xda2508537b512ea3,
+                    0x296e36ce01177f97,
+                    0xfd190dfe568bd8ab,
+                    0x2afac59a414f02e2,
+                    0xe5f726a752ef0ee0,
+                    0x2e719e85558c9346,
+                    0x06ebd90790e893f6,
+                    0x24de92956e4b6c6e,
+                    0x57c51e121d9d94fc,
+                    0x011844896f7ea34c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x76b72fd5172896e5,
+                    0xb87d1e46b1f8b7eb,
+                    0x81f1f72c1f021caf,
+                    0x57d807a3113c4b86,
+                    0xa1c449b432ed9da7,
+                    0x14c0a0fcbe46dc92,
+                    0x8f914fc91d162f3c,
+                    0x56fe37d1977df930,
+                    0x59216eddcba3961f,
+                    0x179f0ee6025bca23,
+                    0xc2c500940c62832b,
+                    0x00b5a0fe6516d26b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x08e3cbd7b1dbf6a0,
+                    0xa70e237cf292aa14,
+                    0xb743ef41ace81748,
+                    0xa5d55f13b73974d6,
+                    0x080cd854b3585de1,
+                    0xb21ed387b1903dac,
+                    0xd4c04ec1ec949c48,
+                    0x9b34b45ce13c5db7,
+                    0x0fe506ad694f2b66,
+                    0x26a42d9859d4bea5,
+                    0xaca496eaf3a57aa7,
+                    0x00827d275f0eb595,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6794732ead6947f4,
+                    0x87b3017ce460344f,
+                    0x0f7744c4941b7cc9,
+                    0xb17fa00675ac172b,
+                    0x3fba569280c0af9a,
+                    0x15d66d1ef1e613d8,
+                    0xa53c49a81be608dd,
+                    0x1b5305d698b31574,
+                    0x79c9e0d7893b553d,
+                    0x6cac64116b356d3a,
+                    0x217df9a4f0c60838,
+                    0x0042dac33e004a38,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa7b56a7075d0dd99,
+                    0xc70e430004dc3baa,
+                    0x406d396503f60cc2,
+                    0x4aa5ff7ad918b1fb,
+                    0x33565481ce95026f,
+                    0x9905b111106624d1,
+                    0x5ed562e0278bfa99,
+                    0xbae59d56a265479c,
+                    0x269fa775aa143269,
+                    0x5bbd5c39b1d82ade,
+                    0x0931f3375961f437,
+                    0x00fe395742ca32b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x35e6e8b9e33642b5,
+                    0xd59775c6a31b048f,
+                    0xdf11659b5d909bd9,
+                    0xc7984972cb71ede6,
+                    0x1f95846a5d0262d5,
+                    0xe2eaea8902ee8a23,
+                    0x1f5baab49546af09,
+                    0x052bf93974833ee8,
+                    0xe888aeba495cbdb6,
+                    0x2fb6a73f128be0a7,
+                    0xa4fcf538c6a1a4e7,
+                    0x00e995efe55d8e21,
+                ])),
+                Felt::new(BigInteger768([
+                    0xffd748b9c7951c76,
+                    0xe017079e2feabb0d,
+                    0xebb542169f524e77,
+                    0x1939a6f121d1faf8,
+                    0xaa02f24f88de533e,
+                    0x15dfcd3b126869aa,
+                    0x1d35292b5e4ee39d,
+                    0xb99b1eab97b0f4c4,
+                    0x3debb9c95db06899,
+                    0xc54c8f6295f2b820,
+                    0xc9aa37c4505b823c,
+                    0x01026f791c0707a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x85f6b98eaa465e8a,
+                    0xf805b601737852b3,
+                    0xec8780f539177403,
+                    0x173e576f0c0d6e87,
+                    0x659114ff27f00181,
+                    0xdad3f0e97e0d7470,
+                    0x79702d628644586e,
+                    0x010d6d9fe2a488fa,
+                    0xec5620158d0674d9,
+                    0xe073f39d594c388d,
+                    0xcb2ed916f5806a64,
+                    0x00da7a9d27bfcde8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa6a4a75df59907b4,
+                    0x98c2c547a9f27587,
+                    0x806795a4a31d83d0,
+                    0xdde73bd5994c2b7c,
+                    0xc62fb1c5811b6080,
+                    0x2a88f42cfd927bf2,
+                    0x3ce53b5ccfd36d85,
+                    0x48adb8bd118a049f,
+                    0x10431761d5542aa6,
+                    0x66b943065f8dc997,
+                    0x4344f7ae3bc6c2a2,
+                    0x000c4b6dd8d83f44,
+                ])),
+                Felt::new(BigInteger768([
+                    0x433aadae5aea62d1,
+                    0x3d86557e647db096,
+                    0x612313c2033ba9ae,
+                    0x70f14f570a6dffdf,
+                    0x1032bf8605cd6cd3,
+                    0xec31e683dd8d09de,
+                    0x4874788c9e20b6ca,
+                    0x4f3b8155589dc93a,
+                    0xdd6b7d0eb55533b8,
+                    0xd1ba075b32d776ca,
+                    0x48f1d6cacdd32ffe,
+                    0x00f57edd9fff7502,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd873baa3e0cda29,
+                    0xbffeedd6bf09bc26,
+                    0x3ff035b9bae6a258,
+                    0xcd3d8317dc6752fd,
+                    0x2ae87e290c87745a,
+                    0x95e215b9bd3d1cfd,
+                    0x176cbce214db8a70,
+                    0x14604efc0b3d352a,
+                    0x28125640e2241dc8,
+                    0x255e54323a8ea533,
+                    0x8fbcfe2049ed5020,
+                    0x00aec2234c805582,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4f6da59e022f7d0c,
+                    0xd9a007a91c8148f4,
+                    0x5c2707c2705cc68c,
+                    0x28613b677eba96eb,
+                    0xf27fca6c33d4105f,
+                    0xafe9a9afc170aac9,
+                    0x64b6070b8fb42964,
+                    0xaa8ad5c77d012bb8,
+                    0x587417896a8580f6,
+                    0x47dd4a2dcf106c50,
+                    0x53b0130633bbfa0d,
+                    0x000b514764af071f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbcdff88eda8a55fe,
+                    0xa66580646375ad32,
+                    0x6bea2011dd60032b,
+                    0x6e2023209c6cd597,
+                    0xc0965df9ae46034f,
+                    0x7526a98e21d35e06,
+                    0xc3b6fa4815f0475a,
+                    0x69dcd0c6cfa9db81,
+                    0xef27cf5cd8455651,
+                    0xc9d9b2180dc5dc1c,
+                    0x32990dcfbbf51341,
+                    0x00c2c6915aedf87c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd8b51368daddab34,
+                    0x3438994689beeb1f,
+                    0xd4901a9a7e2a01b9,
+                    0x189c9820b84a5245,
+                    0xb4a1299d9e5d4cf1,
+                    0xef53d895ecad89f2,
+                    0x42d21bdfb54073b4,
+                    0x1ad012dea166c6da,
+                    0x2ea862802ff4bfd2,
+                    0x10c65bc30aa9006c,
+                    0x9151467b7b646d91,
+                    0x00eb48c099397e75,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcbfe068ba4c20da9,
+                    0x9f568598f6bcab4c,
+                    0x02e5c78ee7095dc6,
+                    0xb9ec87d81f016f89,
+                    0x139e96de08177682,
+                    0x74cdf1ec88121e38,
+                    0xb3e5e893a2f626b7,
+                    0x6b7a9be60e986bf1,
+                    0x921fc2ee4f2db2e7,
+                    0xd52a44ba50ac3aa1,
+                    0x9e7f17ee98c1c8f1,
+                    0x007eac32db2702db,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b1adbe09bcd2952,
+                    0xc0bb39ecf450eea6,
+                    0x6effa284d1c5077c,
+                    0x3669d22d69e5b6ca,
+                    0x80b150e29e0b4a1d,
+                    0x5dc5591dee1d2519,
+                    0x66e8e7f594217dcb,
+                    0xefe6d67c19e2020f,
+                    0xe9212ef0858be11e,
+                    0xa34b2ae77ae699bf,
+                    0x01877e784bacae5a,
+                    0x01184b1dd6b84bcd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf47b2475934c3726,
+                    0x480a22c032c1aa9b,
+                    0x3aa6fd9da568edb3,
+                    0x3c8aad89eb695a39,
+                    0xa56273e0c9a9a385,
+                    0x5b53209050ab6d13,
+                    0x1737f941b56a9ab3,
+                    0x27cbbc7b8b0a34a0,
+                    0xd3f549835fa57620,
+                    0x26ec197e74c4d220,
+                    0xf044ba82e35e11ba,
+                    0x0078db1d0fb6e60b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6f46a721355a97dd,
+                    0xc538157113423e92,
+                    0x0d80b95d02a0fdd9,
+                    0xb09fae8ce022804c,
+                    0x8b69faca0f5cb981,
+                    0x70ed76568bd1eaa9,
+                    0x2acfe1b75ec068f1,
+                    0xa4df61a8c0b7db4d,
+                    0x6d664d0e61d247c6,
+                    0x00a1cb40cf57e596,
+                    0xe8c00191d80edd30,
+                    0x0109135eafe28d72,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ae70bf599637221,
+                    0xf409d59206cc73cb,
+                    0xdb6c63fbd49ff678,
+                    0x6f963cc0aa4a9790,
+                    0x5c6fb9917f745ebb,
+                    0x10d2f3a4afd38117,
+                    0x52082529d3ffd4da,
+                    0x868655719939438d,
+                    0x6de9983dbb3d6597,
+                    0xdc7b5f62407fca49,
+                    0x461b73dafcc144f1,
+                    0x003ac169bb5c3979,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1f9d400755e8e73,
+                    0x2455440b5ce99121,
+                    0x7b5d990ce41f9b34,
+                    0x0591c1a6407fdda7,
+                    0x150d323dee5f6794,
+                    0xc4841f0c7a2ce8b0,
+                    0xff0652c331b3f00b,
+                    0x44c99328c5400e67,
+                    0x9c6761a2cda16553,
+                    0x180a98b9ab9381f3,
+                    0x5843c78ed2826305,
+                    0x003c6ed50a5d0540,
+                ])),
+                Felt::new(BigInteger768([
+                    0x55e46d615e286db5,
+                    0xbf4d1375a759e63d,
+                    0x85779a83a4797978,
+                    0x26bd641cb22722d5,
+                    0xddb269d43fbf7794,
+                    0x568cf18e6bf339b5,
+                    0xe8bee8d7ad4f8b31,
+                    0x80d79339cddd3613,
+                    0x18efc8701a726471,
+                    0x903edcffa2da7f13,
+                    0x80694bc34e5161a6,
+                    0x003eee384a586be3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f142dab9877bc4a,
+                    0x71014e15ccbabd85,
+                    0x7b904c25c9bad3ac,
+                    0x084042bdb7e56abd,
+                    0x7a47124bd9bb9e65,
+                    0xedac279164f1d3f9,
+                    0xc8300adde05b3075,
+                    0x77631a403536b4a4,
+                    0xca1c4e5a3581d565,
+                    0x93fe090e0198288f,
+                    0x1f889507be6c4842,
+                    0x008f11c98cefc76a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb0d15f7aad768859,
+                    0x216735a6ce19f54a,
+                    0x42519502cea2b2a4,
+                    0x45f0b5509a61bffc,
+                    0xa2efad9453a7db5e,
+                    0x41199087e7dc8f24,
+                    0x710362004e9ec0dd,
+                    0xb10cfa92a3086350,
+                    0x6e7e802266cc862c,
+                    0xc902064d95032f7e,
+                    0x0acfaa4a0f64233d,
+                    0x0088591cc64d34bb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x68fd51ff4ce96d84,
+                    0x2f31e4ce7b54e81a,
+                    0x3beb0e2b5c1327c5,
+                    0x0e60bd2466b3cc71,
+                    0x1f9591eb45278184,
+                    0x8f0e56e48c5ff9fa,
+                    0x6ce4b1b3bbcdd81c,
+                    0xb848c1c26345e557,
+                    0x50e9dd8d5254483a,
+                    0xe10598b4aa46fc61,
+                    0xc7636aaabee41abf,
+                    0x00382f70f6ec2a6a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd6e5c9fe8fc5c67,
+                    0x7a9c2752d5ae7f63,
+                    0x1061d9773d5659b7,
+                    0xe6772b942dd7570c,
+                    0x43101406a70ed673,
+                    0x4e6ab0d2449dc95f,
+                    0x79032a30cae7991a,
+                    0x13ec8b4b32a4781e,
+                    0x3404894a4983840f,
+                    0xd15d232374820b14,
+                    0x896a85512cd957af,
+                    0x003ecdbba525d7f3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8f44fc4510c1a339,
+                    0xa26665e4aab3b1f1,
+                    0x66e48abcc9bde1ab,
+                    0x4e2dc94e8da4910e,
+                    0xf996d2e0ce5b4536,
+                    0x6fdcf64b937399bc,
+                    0x9a327523d6beffb5,
+                    0xf60d48aaba3ea247,
+                    0x8c142aef5395fc99,
+                    0x35e80e4d7c8afbbc,
+                    0xee779a884c16b69a,
+                    0x0071d95d0c3bb0df,
+                ])),
+                Felt::new(BigInteger768([
+                    0x680a40470571cb47,
+                    0x61fe52fc49554777,
+                    0x12ecad981f80840c,
+                    0x55870c9d5edc8143,
+                    0x62c58a4742413432,
+                    0x55b77b733200281d,
+                    0x945e4b6be5dbac98,
+                    0xe775ef2a47287ea2,
+                    0xac1c22312ce8214b,
+                    0xb2682844090e355f,
+                    0x7cd682636ba57780,
+                    0x003bde58b97c7c94,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4d8e90f04a48319,
+                    0x86d05888ecb86a34,
+                    0x79fc0253f6e4b477,
+                    0xb82805c4e9057565,
+                    0xbc638dc78737a510,
+                    0xa00afa69d908fd9a,
+                    0xb19c880217e43f4b,
+                    0xd5d9e4584369d382,
+                    0xdbbdab8d89d1ea29,
+                    0x7ef761f05adfac6d,
+                    0x9127e84464a534c0,
+                    0x003bfb58ba34602f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe69467d05d6faa06,
+                    0xa5db90df8c68ee2f,
+                    0x1f3e03971b7dda3e,
+                    0x3e5fd8f81b8e6edc,
+                    0x29eacfd65263670a,
+                    0xbfcc857f20d6a6ea,
+                    0xea1d8d368a57174b,
+                    0xc14342b1c3767129,
+                    0x0e1210a70d12e53c,
+                    0xe1eee4450935acc9,
+                    0x6bda04cc734e13da,
+                    0x00896eecd425a130,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a594b3742cecbc0,
+                    0x7002b9666787b1e4,
+                    0xde87dc316c36e752,
+                    0x4b1489eb282b9f01,
+                    0x18ff1c4704e63796,
+                    0xa04f0c043deab26d,
+                    0xb419240ace7eea6e,
+                    0x9b4254eb00183665,
+                    0x642e48398af2d85b,
+                    0x18479319e1c9c0a4,
+                    0xab3c8ca3f84fcbe0,
+                    0x003c7ab31e0899e5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x01b408ece5e7661e,
+                    0x004e58e2eb8843be,
+                    0xb79050f8cb300974,
+                    0xf10dbc725a0327d6,
+                    0xbcc6e2dc921d4e7c,
+                    0xc285e147375f0afc,
+                    0xe45532b3dbd29250,
+                    0x5b0df0fb8602fe59,
+                    0xf55ec4c4fb19ff27,
+                    0x9d43d3958b944293,
+                    0x94bae5c190de8e9f,
+                    0x010a70cc7b0db56b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1059cc62d4830ee5,
+                    0x3825dc3ae0f3f20e,
+                    0x7e5b7988336d3d96,
+                    0xab0ffd270be31f44,
+                    0xc31d2a71e0ad1707,
+                    0x5a9287340bc5188a,
+                    0x11dc3c3926fbd961,
+                    0x6a9644a44f531217,
+                    0x31c55c2f9650d363,
+                    0xb31ae64c2208802d,
+                    0x39a1a8f9814a6696,
+                    0x00e3f2cbf1e339ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc520a535165bb926,
+                    0x54660a48b1c791b3,
+                    0x841cd6f532222cc7,
+                    0x5028d4aef917f048,
+                    0xaef42255e89341df,
+                    0x2d9403abf606f815,
+                    0x64182a907635840f,
+                    0x0574458522fabdf4,
+                    0xba2faea604c1120c,
+                    0xf9ad85248c211f04,
+                    0x38a2bd0c1b058076,
+                    0x00bfb5f8205a53a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xed915f635748dd7c,
+                    0x8ac665474d0aa4b2,
+                    0x9eebaeca9d288373,
+                    0x65224c2479bbf3a0,
+                    0x8e2682c1a0b3798e,
+                    0x387685a035091887,
+                    0x2842d473dda4b8a6,
+                    0x5bef8b5b2d4d59b2,
+                    0x068b4d0a9c86bdd7,
+                    0x93667b44225a1592,
+                    0x30802d2450ec7a0a,
+                    0x008558a735c7b448,
+                ])),
+                Felt::new(BigInteger768([
+                    0x97e3dc021f16f6a1,
+                    0x30181886266264c9,
+                    0xb6dae664e8eeffda,
+                    0x18fee467db83c254,
+                    0xaae9410019af802f,
+                    0xa4c0ee4e8274f675,
+                    0x45c98d2dbcceb0e3,
+                    0x33a04a7080da950a,
+                    0x168a73f55f4a134e,
+                    0xd0a5d486e074378b,
+                    0x536a076c35677f70,
+                    0x00b3c81116f5b550,
+                ])),
+                Felt::new(BigInteger768([
+                    0x077adba310fdbefb,
+                    0x7bd7bb9dff813bfa,
+                    0xc3bbf6247124c929,
+                    0x37eadf2acb061ec1,
+                    0x7fbf531e3b3f8f0d,
+                    0xe74d4174377fc93b,
+                    0x8833d9e9885396ac,
+                    0x095c2dbb8c1d4be2,
+                    0x528a9b53c39e1438,
+                    0xe02a55ea911ce949,
+                    0x6982af810f80bcb6,
+                    0x004f9c2a27b2d83c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x13f6285d1df8be6d,
+                    0x8fe53c0daa310279,
+                    0xffe64fa837c6cc96,
+                    0x7dd1378661aaf787,
+                    0xdf6b05d1fce103c2,
+                    0xab63b08af90fa90d,
+                    0xa1adec682617184f,
+                    0xa104318392965937,
+                    0x1c65abf3abbf0358,
+                    0x8b8853b6814de3b8,
+                    0x906b138329fc9c89,
+                    0x011d284f883c3e3c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa774f2e3d4812349,
+                    0xf5a1eb95f458056d,
+                    0x67258c40f0c07009,
+                    0xe05b25854f139c58,
+                    0xa7b97f489f663df3,
+                    0xa1b04ad053dbf9bc,
+                    0xe70e3f1876dc7aba,
+                    0x6503cb9ad1cf55f5,
+                    0x20b7b6555b2fb472,
+                    0x981e3b511af2cc3a,
+                    0xb0f7dfa900e453ec,
+                    0x007b9e465ec2bdf7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x80e9eee0212a4f4d,
+                    0xd881427bd3e134f4,
+                    0x6d148cef9165a812,
+                    0xce39159986bae69a,
+                    0x8b51c3dd2a365ff1,
+                    0x97b1075c448de118,
+                    0x81b62d203cdefe35,
+                    0x85ea6c6006a48c81,
+                    0x53512ce51a1cc2de,
+                    0x5f7f0f4a957029ae,
+                    0x981fbcde4298c645,
+                    0x0091daf162adc410,
+                ])),
+                Felt::new(BigInteger768([
+                    0x524ef14f296ce693,
+                    0x6bbc87a0520f865f,
+                    0xf63c7dc3053f9435,
+                    0x7c7b95968d5dd12e,
+                    0x0a590e264895b1ab,
+                    0x07874f263e3fb98d,
+                    0xab83b61802fe97ab,
+                    0xbc3928e1047faf22,
+                    0xa07e8c51593d597b,
+                    0x37bca5fe41e521fd,
+                    0x250af537d729b51b,
+                    0x0105618b3cd3db8d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d398a3aea814e3b,
+                    0x44843a0b4a188ce0,
+                    0xa754015613be167d,
+                    0x7961fa98bb4afb8d,
+                    0x1f15f76131612d4b,
+                    0x74e7962ad1dde3fd,
+                    0xdab9003f29721a9e,
+                    0x94028666a0ebc1bf,
+                    0x128b504ed0a856fe,
+                    0x37567d36486349c0,
+                    0xe9c7896d658bf581,
+                    0x00b63df34aeabd5d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2ee5b8aa052ed274,
+                    0x5853bd79cf10ae7d,
+                    0x89e0d51fdc1969ea,
+                    0xd161ea756cb665a9,
+                    0x80c36bcdd84a6b47,
+                    0x5e59eda870f09df6,
+                    0xaace2bdb6ae4e5ca,
+                    0x5987506dbb89c778,
+                    0x55dae05b78f159de,
+                    0xffbddae9e6ea5005,
+                    0xc340ffc73d385976,
+                    0x01180f52edfcfd48,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba89b4e761665898,
+                    0xbb0ae477cbeb6285,
+                    0xa4da6b2d4c335c03,
+                    0x0b66e601b864d386,
+                    0x89a7bd2537558525,
+                    0xa01ae1a588d50ed8,
+                    0x6450a8999ea3b079,
+                    0x0fa711f8d0b8ddd0,
+                    0x8790e590e79de021,
+                    0xa1709ea696066a42,
+                    0xed37d5c49e79b590,
+                    0x00a32088ec8ae17d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc7a34e539c96398c,
+                    0x7f9272f466e49965,
+                    0x7ef9bf2b2055e0e7,
+                    0xee781fd225181e40,
+                    0xd554a47e996fd149,
+                    0xa0804aaeb59de594,
+                    0x5cf464cffd4a04cc,
+                    0xa4d577acaa838816,
+                    0xc32a69997850d2d6,
+                    0xbb9996518a5aafdf,
+                    0xbb97b395f4643e70,
+                    0x00e4829b6ff20861,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa9b613f41cd87db2,
+                    0xa708f132a58994ed,
+                    0x5638066c74fb3245,
+                    0x9cd3b722610fd5a8,
+                    0x1eda2f96e66e7edd,
+                    0x43d8b8fbf7b167ed,
+                    0xf7017923196664f2,
+                    0xb347e22f56892db7,
+                    0x6335936da6d7b58a,
+                    0x8a417e257a46c11c,
+                    0xfcabe2f4d9d5d7a8,
+                    0x008b2da833703caa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f6a1bc490a2c28a,
+                    0x905c9a60d7be113b,
+                    0x106babe45ab9a027,
+                    0xa95296ca791b29bd,
+                    0xb534ffde41751992,
+                    0x4424c0784772e19e,
+                    0xbb8933ade9ae73a1,
+                    0xb1ce4dd1b9d81516,
+                    0xc3416e1563baeff4,
+                    0x418341d02c998865,
+                    0x17c6af75c110922c,
+                    0x0112051fb61b58ae,
+                ])),
+                Felt::new(BigInteger768([
+                    0x67a536310f7389e9,
+                    0xc738ec9fc8314ef0,
+                    0x392130a601453c62,
+                    0x504d25d5ba4c7ce8,
+                    0x7583ac64863d808a,
+                    0x960aff35bfbf3e60,
+                    0x442440d578f457a3,
+                    0xdd5d6126f434ea34,
+                    0xc0206c6937e09469,
+                    0x8582aa89ca6f389a,
+                    0xc2a83933614b8a3b,
+                    0x003ea3ec6d3b3bea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbb0a8268777c5787,
+                    0x218767e335aa308f,
+                    0xb15605f55090e70c,
+                    0x6fe3ea37dd0cb845,
+                    0x608149b616fae9c3,
+                    0x2059614489e9c041,
+                    0x5020d757579dad85,
+                    0xc37263ba9758146c,
+                    0x186ae5362e6cb877,
+                    0x881425d35707451d,
+                    0x83fb63c62fd71672,
+                    0x00f98ecf7298b217,
+                ])),
+                Felt::new(BigInteger768([
+                    0x41d4299521f0def2,
+                    0x7e89bc5cae421825,
+                    0x59a831578210a626,
+                    0xba46c18bf90316ec,
+                    0x26ce9c056da716c2,
+                    0xea733aeed3a266de,
+                    0x1391faeeaa1c9b21,
+                    0xddf112dcaa597297,
+                    0xb1b09320d6b60948,
+                    0x1b200d76050fd01d,
+                    0xa0ca903d14481dc8,
+                    0x00ab2dbd1cd63d63,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xecd0715100c775f8,
+                    0xa2b0eba653f9f38e,
+                    0x67bd6aab15eaeeb1,
+                    0xb393c54ff604a21b,
+                    0xb53b8d0be6edfd22,
+                    0x43e31718c8dc3ff2,
+                    0x5426d7976e683e9f,
+                    0x633147c19a1b757c,
+                    0xe9b8353d466ae636,
+                    0x784a18cce520b403,
+                    0xacb60f8ececcb7d1,
+                    0x001f5cf95f6b9a42,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0fe35650b183fca4,
+                    0xbc469080ca68ad8d,
+                    0x0c599c60bebff678,
+                    0x31cae3b1c1807bdd,
+                    0x5321a225562f2c22,
+                    0x60779a5b3d04497e,
+                    0x12f68c7108b09ecb,
+                    0xda80f487738eafc4,
+                    0x671e8f81cae366d6,
+                    0x3a47d0c918fef96d,
+                    0x8447d6402fc01ca1,
+                    0x00f1b707ee888681,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2179b1647ba117f,
+                    0x921c140c105ffa3d,
+                    0xa76f1b12840a56eb,
+                    0x43943c2e8bd7ee06,
+                    0x10bf52f50618a3e3,
+                    0x2ff3255932e82662,
+                    0xdfb04340178ea23b,
+                    0xacc6e0d8e82e14ac,
+                    0xce832792c75e30e9,
+                    0x215edbce669581d4,
+                    0xf862c84f768679cc,
+                    0x0099d433f72d5187,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcbcfd9f9f64bebb7,
+                    0xe415fcc9256b0cb4,
+                    0x1c3a1e7d4557e796,
+                    0xa4fbfbace2df20a2,
+                    0x9bcaa23a89bff248,
+                    0x6a39b28e6f362207,
+                    0xfea6afc64072b951,
+                    0x01e762993ead0ad4,
+                    0x48ba1158fc020beb,
+                    0xe4bc35027a531ee5,
+                    0x2edc8496f67c8937,
+                    0x002e030f3b6a09d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40d303ea04febbe7,
+                    0x8786257e8a8bcf4f,
+                    0x476ecfcbfab49e68,
+                    0x91dcf7b81a4039a2,
+                    0xc9449a7635539aea,
+                    0x94d55ad66dad9f74,
+                    0x4d114123e075dec4,
+                    0xa77ec01219de57a7,
+                    0x7c9077c694e9badc,
+                    0xdbf257ac6a252bb9,
+                    0xb3aaa19bf3849252,
+                    0x009f9d7fce6500d3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22d5193d6a6212d4,
+                    0xa42d74f538959166,
+                    0x5401c1ffb8bed7ff,
+                    0xe8449e1f1a31873e,
+                    0xd5dec18f806b18c8,
+                    0x85c3fea2933beef3,
+                    0x4c0a51a52b4f8847,
+                    0x28d6284978009d1d,
+                    0x70e9a574e9c76633,
+                    0xba12ab3c6a802f86,
+                    0x209401d390b893f2,
+                    0x00e978088e9b3510,
+                ])),
+                Felt::new(BigInteger768([
+                    0x800ad89942b04569,
+                    0x215508ffcee2a595,
+                    0x5363db9d9cb7ae6b,
+                    0xf9b94007cc677e08,
+                    0x2224572360427118,
+                    0x891b76f609768688,
+                    0x726fda4d93639255,
+                    0x4bff598179856bf1,
+                    0x2cdb51902da00e24,
+                    0xcc790b10d8e83b1d,
+                    0xbfa5c921454eacd9,
+                    0x002dec439d4ff62d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd6e06e70ce02b45c,
+                    0x831638e54131f589,
+                    0x00b2d4f9afac34e0,
+                    0x4fc19920f94328d7,
+                    0x30b14822395b3186,
+                    0x9ec501c9ad6848ae,
+                    0xeec69735db109911,
+                    0x5d672c114b5906ad,
+                    0xc73458a826983d21,
+                    0x66ec2473a7591711,
+                    0xb5b3f6f25cadad48,
+                    0x005f9c2cc1c134da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4770c4c031e8fa33,
+                    0xd60724f6d00ee03b,
+                    0x08fb167969cb26e1,
+                    0xd10fe9ddaf208a08,
+                    0x01a90bebf1157b52,
+                    0xfd457a69c42f9bef,
+                    0x65fde82f0c2807eb,
+                    0x420a1e006c548895,
+                    0x0ccde9fe1a1f0eaa,
+                    0x0cdafcaf4b67389c,
+                    0x8315d3bf7db07a32,
+                    0x00bdee708df7c962,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6d5f9aa25a5a1a89,
+                    0x6ccf4a83cc4cb019,
+                    0x1fd6876de770a0f3,
+                    0x896eb73f8edfafff,
+                    0x11728ccdc13dac50,
+                    0x337389f100630500,
+                    0x46c0f0dc94503013,
+                    0x6d65271837401944,
+                    0x505dff6820eec5fe,
+                    0x0f74cbeac2c17258,
+                    0x23c9f2ae2596a160,
+                    0x009fa07df276ddb0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4b0d1dc54a14090,
+                    0x5a604216669b9ee5,
+                    0xb52690f80816d69a,
+                    0xd08738be6e363111,
+                    0xcbf4779fed53d923,
+                    0xacdf4f63b28acfbf,
+                    0x1dda76d308ab01e6,
+                    0xcab0d9096814af8f,
+                    0x195e5008949feb98,
+                    0x94d52e04ad7d3cf5,
+                    0xe198ec235a95455f,
+                    0x0043f73d7f70f941,
+                ])),
+                Felt::new(BigInteger768([
+                    0x84114fa0f340b88e,
+                    0x34ce8416297cfa0b,
+                    0x23d460f305b2588c,
+                    0xc278f9a8bd4756bf,
+                    0xda846524f38e695c,
+                    0x435e44cb0d519d78,
+                    0x11d80d03b9039cd2,
+                    0x2e4e51dd0d7d9a4f,
+                    0xac067057ec5d8262,
+                    0x7613dbf38e6f4642,
+                    0xa9b259ceb809513c,
+                    0x003ab43dddd61cb8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x18141eb84bf797e5,
+                    0x468014f4d696801d,
+                    0xfde7de2764789300,
+                    0x1ac023f4858803c3,
+                    0x0f4c1f31e0819cc2,
+                    0xfa689fbb269bbf15,
+                    0x7d703f5df7ef02bd,
+                    0xef2d687d096d0afa,
+                    0x3f9cf185e5e9e2e1,
+                    0x2c9cf0546ee4350e,
+                    0xcec6440c338c0c5e,
+                    0x00ba1608482ee724,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2b34727332fde165,
+                    0x57374a3480483243,
+                    0x1ae3a9b86fbc05f5,
+                    0xe1d69fccce1af074,
+                    0xdc63ba33a7cf01a1,
+                    0x2209b6f50b13d8fc,
+                    0xf30c1c6c5a3476b5,
+                    0xc7aeb3644731fd17,
+                    0x76e2c2e33aa0f71c,
+                    0x151da6f4a12734cf,
+                    0x30afa65ab62b77c5,
+                    0x002b64738ff3395e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2f3ac807bc5c7ae9,
+                    0x3b05eebe0bed4991,
+                    0x884245a278e1d124,
+                    0xeab0fd8daea16b5f,
+                    0x37ace4518ff618bb,
+                    0x185d9da509367fb3,
+                    0xe13148ca8edcdd89,
+                    0xbb1e16d808168e21,
+                    0xba170b953c647f55,
+                    0x1fd0022a3d8ba4ad,
+                    0xf6d8490f03e00db8,
+                    0x00ada419a71bc504,
+                ])),
+                Felt::new(BigInteger768([
+                    0xca590369acf6e8f4,
+                    0x4e7766ff8ad7ec9c,
+                    0x22e3e445ba8f9630,
+                    0xa157fcdc2a1cc2c8,
+                    0x410a1054a4319c1c,
+                    0x9acdd5f2243f3a74,
+                    0x70e306bce62ba181,
+                    0xd251440d1ce837cc,
+                    0xaae2e84b662afdba,
+                    0x7467d8c75ad5456a,
+                    0x406291daa91de140,
+                    0x008883a330a4b893,
+                ])),
+                Felt::new(BigInteger768([
+                    0x434df37368ed2fbd,
+                    0x623971b51929d006,
+                    0x459d9bf36754d60e,
+                    0x566f927aef7d8068,
+                    0x2a49c5f793d6368a,
+                    0x33f9aa1bd2738a11,
+                    0x69a1f23cfecee789,
+                    0x56931734c13f3a58,
+                    0xad80402200155893,
+                    0x5f52b15984b1bef1,
+                    0x33953c05e89e7443,
+                    0x00ef5920a3cabc43,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3931cf9bdb954741,
+                    0x6b06cec374898b7e,
+                    0x00a0be8201e312a3,
+                    0xcbb7410fd2c5ffe4,
+                    0xaf3e4d989d031f36,
+                    0x33759095f8db356a,
+                    0x8e64111a0f4cddfe,
+                    0x00b8d4d25bd1cb97,
+                    0xc8e5dc6ea40eab63,
+                    0x52782a2ade173d40,
+                    0xedb0583fcb87d418,
+                    0x000e069ec54a7bb4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd56f5571e618d85a,
+                    0x2ba3a2ecc6e85a83,
+                    0x3782d5cc753fc6ff,
+                    0xa900ef814ed09634,
+                    0x6790d23a1d25d485,
+                    0xabb95f2e5a9ba8fd,
+                    0x587cbd3b297680e1,
+                    0xe685929d8b4a0aa4,
+                    0x8521fff4440e47b8,
+                    0x1d08bd21b7507622,
+                    0x785c030f0463f434,
+                    0x004b869e5e76c935,
+                ])),
+                Felt::new(BigInteger768([
+                    0x97fa9681a12ca5a7,
+                    0xf5396e50b21dbe87,
+                    0xcf6cf9a98b18127c,
+                    0xb8c3d27e60a91d8a,
+                    0x9e3b65fbbbd0fac4,
+                    0x281c35dc9d3d1f38,
+                    0x9cb4614acbb45ff7,
+                    0x78331ebe869a05f2,
+                    0xc26aeeabb936bad2,
+                    0x7390858b27a8f14c,
+                    0xefaf63d5693d1bf4,
+                    0x009e05512c411a64,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8bf27ed23cd772b6,
+                    0xe1d32d547f1775b1,
+                    0xd7956f4c09a2f015,
+                    0xb3f60c1f6223249d,
+                    0x091317ef3fa637ab,
+                    0xbdc3e36640a297b9,
+                    0x6ae0e1c3f9daa97c,
+                    0xd2fe8f0e9184c5fa,
+                    0xba2acd4220661437,
+                    0xba2b350ba3535cf4,
+                    0xb1fd9925db24ac13,
+                    0x00c103404198f71e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ac387289ae99743,
+                    0x9d1b977257fc05d0,
+                    0x8f0b18538202a8db,
+                    0x562783d0e272b7bc,
+                    0xa303978ceb0fc2a6,
+                    0x9148055549e7fe1e,
+                    0x7f27214530168d21,
+                    0x9f346d483a1b30aa,
+                    0x0a371a1525f9698a,
+                    0x4f6f74d8ce8486d2,
+                    0xaddb67b78dfbd889,
+                    0x002d61ee89190e56,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6320e2976d2a1e84,
+                    0xe19ab96b888c52fb,
+                    0xf77f8ef5ab01dd9e,
+                    0x50a4cf18faf4ec87,
+                    0xd1af54ded6ef49b8,
+                    0x4dfea90590f75da9,
+                    0xe0b155136e621152,
+                    0xa15904869ba7baee,
+                    0x27f45b63e015c453,
+                    0x7cbfcabe628d1dca,
+                    0x2f22b1f184b7e7b9,
+                    0x0043a4db9c3b1569,
+                ])),
+                Felt::new(BigInteger768([
+                    0x69839e8dca78637e,
+                    0x7c23394817c8c5d4,
+                    0x79b579d1188c5272,
+                    0x4e3cbfaba1cb1956,
+                    0x852dade22bacc0c0,
+                    0x2090ac015bc6a116,
+                    0xe27d4dc1b10d83eb,
+                    0x744728ad63b80d23,
+                    0xe058edd7ee9c6a73,
+                    0xbaeeb6bef483a5c6,
+                    0x3e55542e7335d351,
+                    0x00139daf1fd24a7c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5987d82c63480f74,
+                    0x8f82288dce55d1ed,
+                    0x2daff4bdb4b32fb8,
+                    0xc611875551e832b7,
+                    0xbdad74e4fb09d91f,
+                    0xeda2f414cc003a07,
+                    0xfdb7f7c7d4426aa5,
+                    0x5ec3f1d29b0279cf,
+                    0x3614fca1da1666ee,
+                    0x167a4a9b22d3720f,
+                    0x47fbbad9caf3c41c,
+                    0x001d4b1afb0fd18b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7c2a55f403a3ba8a,
+                    0x0b6e64e76bd014b0,
+                    0xebb50a10dd8e3ae8,
+                    0xee10ddbe39cc81b7,
+                    0x4192fc29fc1fc901,
+                    0xc8c29e86477866d6,
+                    0xc47ab1d7c106d3af,
+                    0xa9461012a4bfacd7,
+                    0x71b0a5c90c725f1d,
+                    0xf2f99bc2fd8c08be,
+                    0x35fdcbb4f4fd051e,
+                    0x0004e7d45b0eb5d5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6a5b5839e1872b4e,
+                    0x90d5f1ac47c6ff57,
+                    0x6f57bdd870e87e71,
+                    0x7164495436b3a9b3,
+                    0xc740c1aa1e3fc226,
+                    0x3fe42c86e15be54f,
+                    0x70da0aef313795ce,
+                    0xde24ed352580ca02,
+                    0xdacc5b9d7bdad9ac,
+                    0x88bc6f956404a4ea,
+                    0x82cd3c778bb9efdf,
+                    0x00dd39209ad11ca3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x873d32eb6a7f9fc3,
+                    0xe3632dfe25105692,
+                    0x38ac84dc7b929838,
+                    0xed31fc1bc2008ced,
+                    0x17e55758c502a990,
+                    0xf7bb6cec366846fd,
+                    0xe8929db300da2d50,
+                    0x1d26b527631e43f0,
+                    0x7b514463208b6a38,
+                    0xa713040dfd2be966,
+                    0x3aa9a17c4e13b0a1,
+                    0x00c621270eb7ffa0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x815016ec9f499a6b,
+                    0xad50c60ec3318786,
+                    0xf20d4092b00e7f10,
+                    0xe9e3f5879b79e9cd,
+                    0x70a2cda1ebdc243d,
+                    0xf51c7a14d43164e4,
+                    0x37ac655380dc4155,
+                    0x33d7830c4e95ac6c,
+                    0x3599b3e24323c3f0,
+                    0x3e0c540cc943c829,
+                    0x65902ee5c46dd943,
+                    0x00530f3fa722eff2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0845c12ef1e82cbf,
+                    0x4799f7d72ff320bb,
+                    0xd0e02c7bcb6aeafe,
+                    0x2c6ca112b0696d96,
+                    0x9baf79ce067e227c,
+                    0x60685716f9415d4f,
+                    0x407732ffb41d16f3,
+                    0xe8dcf5cbf95e070e,
+                    0xa2b6c48f455a1e36,
+                    0xf7f78b0ca3de8467,
+                    0x93d1c6de925b5cf3,
+                    0x000cba491cec8ad8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x647fb3dc14287c5b,
+                    0xb251ee507a1beb21,
+                    0x81216509ec6e453c,
+                    0x6595ea5b456b290b,
+                    0xaa7356af0bf68340,
+                    0xae738bc34de3752b,
+                    0x8a9969612973676b,
+                    0xa6de84a4673218de,
+                    0x657066c6a71338e7,
+                    0x48b6675eeb1ffbc3,
+                    0xa6bcfba965775e4a,
+                    0x00c0fa5a1f634359,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9408eeeef6300aab,
+                    0x78e3dd206a4e0b8f,
+                    0x9d6dd3b812b25046,
+                    0x7d64822e3caea3db,
+                    0x2cfa5de3c02c24be,
+                    0xee66d01fc1e5db22,
+                    0x3f079927c45010cb,
+                    0x37d68959f2ac495a,
+                    0xc2bcec76635ef75e,
+                    0x0ae398fe9e6ae686,
+                    0xb1ab942eef2a390e,
+                    0x002b4d8be447608a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x917117c5373a8f27,
+                    0xd3c979955d23164f,
+                    0xdff91319a5253099,
+                    0x139a170987e01108,
+                    0xfd3d02d909c071ab,
+                    0x4dd62a76eeb08249,
+                    0x0d3a854cca9109a0,
+                    0x4a9e3dfdba699b93,
+                    0x4a6954beacf930e6,
+                    0x5bb7623eb5d54cff,
+                    0xe0ac663fc5a578e5,
+                    0x00f22819b9f7f5c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7dcc7d9465ce4fc8,
+                    0x98be2b46bbdbcfd2,
+                    0x32c14ad24e8000c9,
+                    0x6b0d85b34f468172,
+                    0x2ec71fefa2e08bac,
+                    0x2a8ce1cdf68ec60b,
+                    0xfc835aa78ff493bc,
+                    0x2a7b13536ff9fa44,
+                    0xa3cc409912550199,
+                    0x1a7ae4f6fd21349a,
+                    0x8f0ebaef0bf5a854,
+                    0x0033bdf4bdd7f7be,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6abe67880fae25a8,
+                    0x3a9f7e233005afc9,
+                    0x7762f6b8579576cc,
+                    0xe1f98e45c5982bc2,
+                    0xe245880216ad78f9,
+                    0xcb6e7defd65d60d3,
+                    0xe141930b02b8c315,
+                    0xc9f6afb511f80873,
+                    0x361ec94642667a7c,
+                    0xea374527a52e1b8b,
+                    0xa89952b8e1357503,
+                    0x003e13849b42846c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf5f6a5872876cd4e,
+                    0x9ec02cfc95069e36,
+                    0xacb3c073ae444388,
+                    0x2765507d7bd412a5,
+                    0x8ea78046962cc86a,
+                    0xa6c4bec35c07b225,
+                    0x5ffa60920593d08b,
+                    0xe717c5f345842597,
+                    0x0667e15c1bbdd4b8,
+                    0xf140559e4e005a28,
+                    0xa7f2384b901729e9,
+                    0x009b15b05c110737,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4136458c9b25d618,
+                    0x013d58fe8c5c563a,
+                    0x25a68036869896f2,
+                    0x87b43e08e9825d1b,
+                    0x9b2b3cc6081dd51f,
+                    0xd11223476d935936,
+                    0x4972107dbc094387,
+                    0x2d370cc210a9eb34,
+                    0x4c07f87a5733e956,
+                    0x2fe8f2bc1a3ee2b7,
+                    0xe40a032be713d6a5,
+                    0x00839a45a4a0659d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5177b696c26be82a,
+                    0xd95d1a4cb0ba622b,
+                    0xe1dbbe6557aca624,
+                    0x0921dd57b9358d60,
+                    0xa4a8645e39d35905,
+                    0x6bde2ed44d98196b,
+                    0xc26f5999be213d6c,
+                    0x7c55a6267851224b,
+                    0x3dba0f8d9c075fb5,
+                    0x23057d11dbbf82cd,
+                    0x35b8fc13627ede3d,
+                    0x008ccb3334064ebc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9cc2eb84da023554,
+                    0xd9bfcc073d03e3d7,
+                    0x482e70766cc1ebf8,
+                    0xeacecf4b859ba88a,
+                    0x1a415efd07ed1b40,
+                    0x831af56cf9059b8a,
+                    0xe9099544369f82f7,
+                    0x2e98d01b71903b37,
+                    0x874799a72335097f,
+                    0x6c13c113a73255e6,
+                    0x364a795d461435db,
+                    0x011573f232eecb9b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6762aa7a9abe73c6,
+                    0x1b700c2492541ea8,
+                    0x57761faac8e91dfa,
+                    0xcddf419ce2858360,
+                    0x83e50902e09f9f87,
+                    0x0473ae53aaaa8324,
+                    0xf872cf3833715fb2,
+                    0xeab0ceab4604f49f,
+                    0x599ba685882b2f8b,
+                    0xd1260a16203c7809,
+                    0x86697814b5752033,
+                    0x002ec5a5d8a74f0b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb888dc8558d61787,
+                    0x73519722a3110e99,
+                    0xd71864ed5c4bcea6,
+                    0xf7145ef2b2b26c59,
+                    0x8488170ad13496af,
+                    0x6b0706578c0ee433,
+                    0x5e275b7ef6ead608,
+                    0x45d16dbcfb0cdaba,
+                    0x63df7bb823395ef1,
+                    0x853dc065f85cfc6c,
+                    0xa6d3ce60dea9a96e,
+                    0x011b7f0f2ab1b89e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa247cff5ddaec9c,
+                    0x95985d85ce1ae068,
+                    0x410ebdb93a9ccc15,
+                    0xaa100cb783855108,
+                    0x046c2427b5ade25e,
+                    0x124db5ae01e1c403,
+                    0x1fa78a6fb3b96195,
+                    0xabab60ddc9c84d8a,
+                    0x65aa9f78ae7bb66d,
+                    0x91e48a374d9a0ed3,
+                    0xe6dbc9962ec827f0,
+                    0x004fc9aa0a7c67a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6d74b535f2d69002,
+                    0x7d1da4c67b177549,
+                    0x2e2ac5823ed8eded,
+                    0x2b8bd6cd4165a49d,
+                    0xdbcfac83b033a7f9,
+                    0x476c484353c9f546,
+                    0x91afd261fef01b0f,
+                    0x90aca1537acb0719,
+                    0x7664c417a7d43953,
+                    0x68b2fcbcc87e6b7c,
+                    0xb514a5d5e66ad699,
+                    0x004d769488acdd26,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a08ece5b3e23170,
+                    0xe680025d7398412e,
+                    0xd08a5e8f352b615e,
+                    0xd56db95c28806d97,
+                    0x53d452084675efb0,
+                    0x91485b8c1cfe7898,
+                    0x2173c753ccf6e773,
+                    0xa172219484f79878,
+                    0x5ddd0e74998b9cdc,
+                    0x39205cef01e5205d,
+                    0x806baad5b1b2a54a,
+                    0x010f0b7ad6262c92,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9773cd1972fc1658,
+                    0xa5183cfff764ffe1,
+                    0xa596d905278ad346,
+                    0x94c3f02ff6772357,
+                    0xfb2947043b678b80,
+                    0xbce81a0407d3aca3,
+                    0xe964b2d04c79b1c9,
+                    0xbdba2b623103001a,
+                    0x7d99e9e63bc4f75a,
+                    0x8c7dbffbaf272c85,
+                    0x7ec50c5ddbc9209e,
+                    0x00c6802333dbee71,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc92bac583b9da25a,
+                    0x31092a221e4dc168,
+                    0x62ad228a18d993ea,
+                    0x8830b4386d336be1,
+                    0xfc5969b295306093,
+                    0xf81158b849fc2e69,
+                    0x7e07638df5b822e5,
+                    0xb963b63e05d46133,
+                    0x5d7bd0a357143e70,
+                    0xba5d55828e844889,
+                    0x231447a366c8ad54,
+                    0x00c2af7f7beaaf6c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82018b3a843f1ecb,
+                    0x5968d50cac97250e,
+                    0x85fc6bbec56d38ae,
+                    0x0c8d32842723d9c6,
+                    0xcd29bd91caecc8b7,
+                    0x975d3ad7c7cde65f,
+                    0xda9b80450d8cab37,
+                    0x437b7a675120f6b5,
+                    0x12c268b1791cf4f0,
+                    0xbecd227cc9471b07,
+                    0x350d64ae537f8ea2,
+                    0x00d31301c1841246,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1eb03a35268a94d9,
+                    0x905ed5ba202c05e9,
+                    0x4489ecc36c5ebc24,
+                    0x3d80b63e1e560342,
+                    0xba143c51b669e596,
+                    0x18b5cfa58d838203,
+                    0x83afbe121cf83538,
+                    0x687823333311d1ce,
+                    0x18a58a83a6df4612,
+                    0xfc9872e7a7acae85,
+                    0x8cbebe1172c565fd,
+                    0x004501f5e6ae6613,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xfef798d8b6098b59,
+                    0x6da79785cf549e35,
+                    0x33c0da3fcdfc997f,
+                    0x7c41c559b768b19e,
+                    0xd4afc4316293013c,
+                    0xfa404a03b007d409,
+                    0xa635b6c309cb5658,
+                    0x03ac6fefb48cd727,
+                    0x17ae4fc2bdf0a685,
+                    0x56367130559081fb,
+                    0x7b3dae9d603d6af3,
+                    0x00139700bf6b7f63,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e762df5174773b2,
+                    0x9c231f1fa3d35096,
+                    0xcf0be89e2f601c56,
+                    0x11b4a7d244eb369c,
+                    0x26b17af00d4facd4,
+                    0x52e8bad69c32755e,
+                    0xcb8924786573222a,
+                    0x9eac279498870fe8,
+                    0xf17e57dfad4fdfb0,
+                    0xb706409a624bff5b,
+                    0x95f1daaffc504f24,
+                    0x001081ae46ea47ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9377c2d3d4cc9950,
+                    0x75c5351f7700d116,
+                    0xdbe651b277513739,
+                    0x331754d0683141f1,
+                    0x59d54b50e2784133,
+                    0x85db820a06ea6ef9,
+                    0x03f0481c3450f906,
+                    0x0fca26fe132f4d5b,
+                    0x8076924a16786017,
+                    0x46b802008349c791,
+                    0xe67311bdd3167a16,
+                    0x00c82028bab977e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc2b16f016cee7c11,
+                    0xb57fa28b40057001,
+                    0xa3d4238aeaf12eaa,
+                    0xed01e04ad80fbf9c,
+                    0xb4411186de3464d5,
+                    0x7e575eb34685f39a,
+                    0x394286aafb10eaaa,
+                    0xf4240ddf8d3a1e32,
+                    0x963a3b7ce5951401,
+                    0x22440b5322eb8455,
+                    0xd4a64348f8658bc3,
+                    0x00c29b512b72d3df,
+                ])),
+                Felt::new(BigInteger768([
+                    0x59785b8b3e86c15a,
+                    0xbde0ec6d6c97d660,
+                    0x664ecf1df078d6b9,
+                    0x4b4072dd58815043,
+                    0x6b0ba7b0d024acc5,
+                    0x4be921c570f9561c,
+                    0xd54c6b74545ea4c1,
+                    0xd7d85031d593ab95,
+                    0xd48ee30a4f365e54,
+                    0x06d89eeb0c52139d,
+                    0x9aa70d8beae3f344,
+                    0x009d0b07124b57f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x49801aa46bb5b6c9,
+                    0x3e88a1f7a2dfaa36,
+                    0xaaad8fad33844efa,
+                    0x54511c2e83fedd45,
+                    0xb290d138d2c99f10,
+                    0xcd6509ec569e54a6,
+                    0x1a57685b4ec5c21e,
+                    0x0f074dea3e80c500,
+                    0xfa3a4534b5f74be2,
+                    0x7a02f390d86ee22c,
+                    0xb149e132f0fb2773,
+                    0x002d6571c0b38bc9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x291840ca106c579d,
+                    0x881f60eef910df9d,
+                    0xfb13b183eb96f210,
+                    0x5b4b61851cc834ae,
+                    0x302446defa0a8c4a,
+                    0x21357a19f2ef8687,
+                    0xf24e82393f38ccfe,
+                    0xdf805dedfdf45291,
+                    0x42f8779a67da64cd,
+                    0x80b011498eb140ef,
+                    0xd2f59c1f0ea518ec,
+                    0x00e08dfd58dc959e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x611cca27d63e8e57,
+                    0x081a463a67afb3bd,
+                    0xdabea38efc057ef7,
+                    0xffd91d363f94526c,
+                    0x0def0551e2fe67ce,
+                    0x2c37a23a5490a7e2,
+                    0xb9993db20222edf9,
+                    0xa62c30463d144780,
+                    0x43d66e3c616cc8f4,
+                    0xb505a6266f6d8ca2,
+                    0x4d08cede1200dabb,
+                    0x00f856d30f761de7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x75c01c87414131e8,
+                    0x1556b901aef85cbb,
+                    0xf70abb35d84a4807,
+                    0x59a416554be876f6,
+                    0x4d59153a23550239,
+                    0x0137bfb7b5676f64,
+                    0xcea9ea1ecbe6110d,
+                    0xba171937ab2ba809,
+                    0xc8364e346466f462,
+                    0xd373df0b56932e14,
+                    0x6a7d5c983a933d1d,
+                    0x007f870056d0f469,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2fbcce00ca2967a,
+                    0xa7181abf5347198a,
+                    0xfb16f959c23481c5,
+                    0xb766ba8626651bbf,
+                    0x868f2c1e12d4cf73,
+                    0xd54919c04687c9cf,
+                    0x29c429ac15e235ee,
+                    0x2a65ea1dea0c7e49,
+                    0x4b6330ee959316c4,
+                    0xae0652da3e7fb5b0,
+                    0xa6ef8bcd0985e19e,
+                    0x00e7b955d9e6bb42,
+                ])),
+                Felt::new(BigInteger768([
+                    0x165b8d35b677286f,
+                    0x9b98a95f39061be4,
+                    0x5d82122c8a523269,
+                    0x311cc1f1106af7e9,
+                    0x981b0bb725d477a2,
+                    0x575bc5f59b4e76aa,
+                    0x28cf4658ac11977f,
+                    0x6b14db7ffe886940,
+                    0xa493c154e2dc94b1,
+                    0xc65ed64c0299eee9,
+                    0x4c67708cdb73e609,
+                    0x008995a2b98229e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f76b5f270257455,
+                    0x63f84626dcfc075d,
+                    0xeca9396eae0021e3,
+                    0x99a07273eb07a266,
+                    0x1c10e3e71e32c84d,
+                    0x4850fdd4ced12235,
+                    0xdfb06d0d3c96ab48,
+                    0x97a60b265702cbd9,
+                    0xdfa524f82e76908f,
+                    0x9cc287442fa5c228,
+                    0x75252432d4075043,
+                    0x01028793428a0bb8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb3f99eb5983e5d90,
+                    0xaccfb17352289cc3,
+                    0x4e51281e25c0fa84,
+                    0x79ca450604676811,
+                    0x6d7635de97bca407,
+                    0x8cc5d1c874309945,
+                    0x19e064bf0732b841,
+                    0xdd7175de8fb85e2b,
+                    0xcde15f95255c2e17,
+                    0xce55787a38a836b6,
+                    0xec194471495acbd9,
+                    0x010065c0f991fcb9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf394b58894d69540,
+                    0xd81d5ba96fcf9f2a,
+                    0x89a56fc9c02b427d,
+                    0x5582ac0c80d495fd,
+                    0x170210346624114b,
+                    0xdf3c3b46ba143dc7,
+                    0xe052ad9028fc6f36,
+                    0xae2c050735180ecb,
+                    0x9c8606a01c8bb89b,
+                    0x60cadde56a40852a,
+                    0xd607c010f31e0f13,
+                    0x0120df430b5ff6d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x482a2352f4bccb22,
+                    0x6e6cfec9aba54a76,
+                    0x4874fc58a983200c,
+                    0x2a51ad7409e6b138,
+                    0x3f54c02e8a8e6841,
+                    0xaa9d5eda3a13bc2c,
+                    0x94f0193b4f2fb1be,
+                    0xf2cd8d5a1ef7e2ed,
+                    0x304c7fa0d2c7836a,
+                    0xd204be3dd133333c,
+                    0x7ab3203eb7db3ea0,
+                    0x01108abc5a0d5b75,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7649dd731ac3b450,
+                    0x3cf8d97191c8c391,
+                    0xd568b293461f31ce,
+                    0xba61482bbdda8bee,
+                    0x0e21604472296edf,
+                    0xf1da41b4127270cf,
+                    0x7c689d397878288b,
+                    0xc1ce3bb5d1d95777,
+                    0x7e9caf3abbff7f68,
+                    0xa09108879d4bce11,
+                    0x88bb81f91a77e44d,
+                    0x00c6e7f2d79af370,
+                ])),
+                Felt::new(BigInteger768([
+                    0x266212c51145851e,
+                    0x33a8751a9274ded8,
+                    0x75b779f559d37af4,
+                    0xefa0906a0073b6b9,
+                    0x978975e3d5932ad6,
+                    0x407042bfa50ef5f4,
+                    0x51331f08d7457c30,
+                    0xea818960b01aa371,
+                    0xbd3e7efa993ebdb4,
+                    0xac336b33a44deeb9,
+                    0x2b86ed082947f315,
+                    0x00ea044bad502d2f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x83761ecc205db4da,
+                    0x6b1ff4c38ea980b0,
+                    0xefc65cf3970a3950,
+                    0x824070b3ec5f3b2f,
+                    0x6d6f6f83452b2f36,
+                    0x4ee758c6e643b118,
+                    0xbc4b9ecb8a3e18b6,
+                    0x6654be1a6a20da7b,
+                    0x2ae52ea3774af921,
+                    0x7b086ec9fcc32947,
+                    0x7c17dfa42525760b,
+                    0x0024ec77ed896240,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf4a2a4ab24d2852d,
+                    0xe71f6c92f3919f1f,
+                    0xb1b7ecae1ed31bb6,
+                    0xf9b48dddf4731dd4,
+                    0xc2494a0fc2f2719b,
+                    0xb0f26e61d9397202,
+                    0x92b214c01907995b,
+                    0x364e21a1dd8e06f7,
+                    0x0fa6834e14c08b1d,
+                    0xe5f23964af32772c,
+                    0x356f256fc7422a07,
+                    0x002d0ae7ab1b7d4a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfe38390784f570ec,
+                    0x4e19fe2013ba1ff6,
+                    0xb70811d514244d98,
+                    0xc92c7a0f5b1fbb16,
+                    0x106451e3d0f747fd,
+                    0xcfd2aa19382f5ab2,
+                    0x7ad9baf0fae12aa9,
+                    0x8d5f99ae6cf937c6,
+                    0x6e8fa500b6361e0a,
+                    0x1870fb054eec9fcc,
+                    0xb22958677356d471,
+                    0x00264557c157af3a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ac0995abc9e3288,
+                    0x132f75c7546eb3cd,
+                    0xf692e61d1b70f690,
+                    0x32d3b5572c5b8a4a,
+                    0x1132b091bb91f649,
+                    0x7551cdb082966175,
+                    0xf4b367672fe85df3,
+                    0x3877890ca3ba97c1,
+                    0xa808f1eb7f31b277,
+                    0xa3da0f91034ef441,
+                    0xf16300920c800cfd,
+                    0x00555d937a771068,
+                ])),
+                Felt::new(BigInteger768([
+                    0x08bb97a1fa61c795,
+                    0x10d7161001938706,
+                    0x2d7d3986e5a1c4e4,
+                    0xea9ac729047656a2,
+                    0x85692fe0366fbd4d,
+                    0x4874a4b54d6fc0f7,
+                    0xb1ff94ce68454378,
+                    0x1ebcb46718dd075f,
+                    0x24fe002c49b368d3,
+                    0x13e8738424d4cd83,
+                    0x488e8040a7de34f6,
+                    0x00d636fdc5ad6535,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd60e6e5e61370dd0,
+                    0x9db550426b307ec6,
+                    0xf63bacab921c8ed0,
+                    0x785b31c91a7a94cd,
+                    0x2133e488c3eec337,
+                    0x4c4775e741d35254,
+                    0xa5c2a54f0ac8bfcf,
+                    0x408aecf52a011405,
+                    0x67460fab53bc496f,
+                    0xb83dcc5cf7fc7613,
+                    0x833a975b86fa4413,
+                    0x0088484045705a35,
+                ])),
+                Felt::new(BigInteger768([
+                    0x24b271a18e24cc4c,
+                    0x906a6a22bba26eee,
+                    0x86a87434509b26ab,
+                    0xac15daf503c85d98,
+                    0xab719747f9ea78a0,
+                    0x32c18444c0414095,
+                    0x250b895c2c1b0547,
+                    0x0a662086e525e5f1,
+                    0x8afaf2277caa8dbd,
+                    0xb7259a5fbc6914d0,
+                    0x256b4959ef34f7a4,
+                    0x001ba6f626e6f046,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc935abcef9ef6d02,
+                    0x22a6b4938d6b327b,
+                    0x5bffb65d82c34622,
+                    0x98acf3fca6e71c85,
+                    0x28b4f809fc538189,
+                    0xa67bd07635795410,
+                    0x2449ce7cacb0d6b8,
+                    0x214e9d131b12d895,
+                    0xba7c1d1eb560ac6a,
+                    0x164e16aa9abae4f7,
+                    0xec17a79a302ec192,
+                    0x00d06f1aad9a72b7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbedab51608b3de80,
+                    0xe93c0c4f08009366,
+                    0x8843d6a4c55b2c33,
+                    0xa58bf22ff2ccdb6e,
+                    0x3a2bbcd41b2183d1,
+                    0x22457f1271ca91ba,
+                    0x7986b160a4ccf10a,
+                    0xc67243a647bdcd41,
+                    0x1cdf95760d17192e,
+                    0x248dd3f7fbdd784d,
+                    0xfad4cf3edc5db411,
+                    0x00c84f43faf72f55,
+                ])),
+                Felt::new(BigInteger768([
+                    0x485c223d2387aff2,
+                    0x4c58a3ed4a1a87f1,
+                    0x483ac10a6a3adba0,
+                    0x5cf1cde0c6e3a797,
+                    0xf8493e72be1ccd89,
+                    0x3e0e5264c1bff615,
+                    0xe25b74ab641089a7,
+                    0x822f22512fa400df,
+                    0xc04a8c05c4d0c180,
+                    0x44bb18eb44f836c8,
+                    0x3cf6e6937c2a54f4,
+                    0x0013b15c7dd681f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x164040f9637f0aba,
+                    0xfb450a789fea9a88,
+                    0x4c7cd872668c3876,
+                    0x561adafad24bde35,
+                    0x4d1d8b6b28548845,
+                    0x608e704c5ecafc42,
+                    0xf75147f59c39b498,
+                    0x9d07480fe4a7dfac,
+                    0x607b6e1205464b58,
+                    0x803238328acad20e,
+                    0x2f856e865b772824,
+                    0x00fdf77bd3e3902b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0613eda86430fca0,
+                    0x0366fd199b141cf0,
+                    0x422fbc24ccf21ac4,
+                    0xc6ccd17175b6b8d4,
+                    0x76f259037ce04353,
+                    0xa0ecaadc518d13ce,
+                    0x194de24044990903,
+                    0x4b5e13cb033973e0,
+                    0x6ff579724624b915,
+                    0x22155636b8576b46,
+                    0x525ae6339f575e95,
+                    0x00abfecb91a580ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xee2a53378c11c1ce,
+                    0x4a9a018737a78d5b,
+                    0xf1cf272b13907c38,
+                    0x85f9eb1a864f614a,
+                    0x8949d8095fb28e26,
+                    0x802307aaf21c21b8,
+                    0xfd3a3e4e43afc83f,
+                    0xd0ca666fd06b86cc,
+                    0x3a79277b9314310a,
+                    0x83b3e0f68516fd2e,
+                    0xfe3502bb2196abac,
+                    0x00aee1e7be23a0a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc6b9ec45b1e438ef,
+                    0x583cd824895622d3,
+                    0x7707f4a3223b352e,
+                    0xaac97a65daa6e038,
+                    0x6ea3ba35e333cfd6,
+                    0xd0ad8caf59697957,
+                    0x953ee52f1bb807d7,
+                    0x8f7d96bd9ebc9703,
+                    0xf74d4e6c22f075a7,
+                    0x70f551084580fbdd,
+                    0xe142dcdad496c828,
+                    0x0096cf1f405ccacc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x758fe51e78ab4b22,
+                    0x5d89906ad7bb4fcb,
+                    0x0e7a411f17361e18,
+                    0x99fa971ca3524694,
+                    0x7697d55d3e5bbb3b,
+                    0x0a467698f259e6fd,
+                    0x0f7b995ee55873fb,
+                    0x42b8a07bb9e1d011,
+                    0x4856f8f3f36e9f32,
+                    0x7d465a6befe6cc31,
+                    0xdb41bb2700e0a45c,
+                    0x00890344299e0b89,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcd2b827bc501f998,
+                    0x30908244013cdcf7,
+                    0x2d0f8ca5f008af05,
+                    0xd105b69e02c7470a,
+                    0xd5e87595a41b6738,
+                    0x2a90ec7d780deb58,
+                    0x5710401e9dea8987,
+                    0x3edd608969f93cbd,
+                    0x1084e46a0b24c729,
+                    0x781db181fb9d47d1,
+                    0xe561593b5c2cd2bb,
+                    0x00a8b003bca4241b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x54eddc9b5fa718a6,
+                    0x85fc49665b9edd82,
+                    0x1178b025c8b77448,
+                    0x48117dc187642fa8,
+                    0xb1c0ec428493d4f1,
+                    0xf9665885d15160b1,
+                    0xca21c5b47123e9ea,
+                    0xa94e367c4c86a226,
+                    0xf6edf69ce834882b,
+                    0xdfd7b0925f0c25b1,
+                    0x7af705f18363838f,
+                    0x00e30af7d4bd7ca5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x009b76e32727078c,
+                    0x4146d976e15626f5,
+                    0xcaed5ae1b6d24bc0,
+                    0xbd02df2bde60c611,
+                    0x2571b33f7b03d748,
+                    0x780df167c91f78c3,
+                    0xdf00f2f6fa2afdb0,
+                    0xf9ed4f7e72f58f87,
+                    0xf7aea199126df414,
+                    0xabd3937a5b2c1e3a,
+                    0x071452ffbdbbbbdf,
+                    0x001ddb88d52292a6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x824cf6e76a80e4d9,
+                    0x8c7d88f29b6bef63,
+                    0xb4c25f7d0593a284,
+                    0x5777ca1c487063fe,
+                    0x9b71e0369e5a8173,
+                    0x344cbafa4cc387a4,
+                    0x93e170be5dacc4c7,
+                    0x1d0218c3f9931d67,
+                    0xa15e5e8113eb03bd,
+                    0x345fb8209ad1ae03,
+                    0x35373cd3a3ad95ca,
+                    0x00058a5da272e642,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xfd2595d19a0823b4,
+                    0xc164e53c5f109283,
+                    0xec92a733e18de180,
+                    0xf37a5c8df2d47b17,
+                    0xf6ded832badb2b91,
+                    0xd515f60e37e5074e,
+                    0x1fa89af1bf9f3993,
+                    0x62d2afcc9d833229,
+                    0x1521341c42e91c12,
+                    0x7507b151f17d638c,
+                    0xed57009ac23e763a,
+                    0x00fa1989869e7515,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb9ed8e2ababc4de,
+                    0xa9908aa7ef9b374e,
+                    0x52663b49d76cfb1f,
+                    0x47b92e0fee71cb20,
+                    0x4792b3c7b48018d8,
+                    0xff9effdae3facbd1,
+                    0x63bf811f5f950229,
+                    0xfe60b6409cf925f4,
+                    0x6b3859c3bcdacbdd,
+                    0x351f91ed616b9b52,
+                    0xd655d35959fb6fc6,
+                    0x009873f80c13b02d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x73992c87c0394c22,
+                    0x7a185712d70a7ec9,
+                    0x7b047c19992ab947,
+                    0x6295895d05c2c5b4,
+                    0x0e56ab1789a4f4a2,
+                    0x1f6f9e2d06e91069,
+                    0xd35d350c9ba18e74,
+                    0xdaa5a7096bae691a,
+                    0x56e235658cab39a2,
+                    0x49c88822037a745a,
+                    0xfc02a997b8dcade2,
+                    0x00eebd75c04ddad6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x680bcd0041a2921f,
+                    0xed73b109a4f11031,
+                    0x699b614e65e1d30e,
+                    0xea3c3c98131e40ad,
+                    0xc35899e59887466f,
+                    0x5adeadfb528dafb9,
+                    0x636094a3e12f9899,
+                    0xad0553eb7faafe50,
+                    0xcc6ce8447555c185,
+                    0xdcda5663bddd4034,
+                    0xa86392f040c5b279,
+                    0x005dd6752352d126,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8244e5a5aff89ba3,
+                    0xe06c8a309f499bd0,
+                    0x5fb29375cee28e67,
+                    0xf99cd2b3e776063e,
+                    0x14d4bec807465e42,
+                    0x3e9efd47c83353d4,
+                    0x258b348c3abb8a24,
+                    0xe4b425961a55fed2,
+                    0xcdf682f24e9847cf,
+                    0x63408c14f870cbb4,
+                    0x4719bf0004b8df71,
+                    0x003654ad1133c10a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x893d2b91885a14bd,
+                    0x7745ba75e8e042c1,
+                    0xe3e016987c7b852d,
+                    0x6dc9b6388c98b92b,
+                    0x02b5db83d98f7fc9,
+                    0xf1ef1ce34bb9f74b,
+                    0xf1e54a0e4ff6f191,
+                    0xf19f14217c026077,
+                    0x83deb3dd25770a7d,
+                    0x842887daf6c7b6e0,
+                    0x72051880c094a609,
+                    0x00ea71ea50941b0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9e754e9bbc58599c,
+                    0x0d0b3dfdbbb88bc8,
+                    0x878a6eeb554428f9,
+                    0xcc74a6ccfe347b87,
+                    0x16ec9b62d1f6636d,
+                    0x60b740c19e144d14,
+                    0x77f4035ae69502f1,
+                    0x98c9a9f0d55026d1,
+                    0x718b44f00b24e689,
+                    0x7f4ee2f5cbee4170,
+                    0x2b7d8e2a47adcd60,
+                    0x00915d5961e685af,
+                ])),
+                Felt::new(BigInteger768([
+                    0x95855a3b8ea90be7,
+                    0x466f243b3f17c474,
+                    0xa1c34ea2fa07f432,
+                    0x42d40fd8843cb1c1,
+                    0x45e6ed7f9f51e69d,
+                    0xdf57fd56af284a13,
+                    0x65e2d137ec9f6c59,
+                    0xd6435cd5ac683e50,
+                    0xf6bf85114973c68d,
+                    0x5b3f2587e32e2387,
+                    0xf5501637339ffa57,
+                    0x00641a9f1b6e27c9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcad884a692cc29d8,
+                    0x8236ccb438a58b0f,
+                    0x3bf0228ee56d7503,
+                    0x4414ff137aee70c1,
+                    0x61342d9d4336db1f,
+                    0xf6b4496456d320ef,
+                    0xdb98ad9eff7fee57,
+                    0xfe92f8960c0ab669,
+                    0x7cc2083d3e55b97b,
+                    0x46c36f0477951e74,
+                    0xc78e627e871aa54f,
+                    0x005ee74089630664,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18e38090404e66ff,
+                    0x7f5b4f3638e28e3e,
+                    0x4b44627b1f57f70e,
+                    0xd9475b73e860a55e,
+                    0xe8e84b50de7b1f16,
+                    0x80eb5c87eb4c72fc,
+                    0x361e732ac48440d8,
+                    0x70cf2f72a93ea1fe,
+                    0x3fee690481d89bc8,
+                    0xcc74ea7496a8f725,
+                    0x67ab89ed22cf7c5c,
+                    0x0092ee80266eac6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf395a6251d019fca,
+                    0x0282b3c73c9e3e98,
+                    0x91bbf9146014b5f4,
+                    0xf03d3c41e2b0c6b0,
+                    0x59be0d9b6440f0bc,
+                    0xbed3959bf61c3dd3,
+                    0x65adf8c0c990221f,
+                    0x5096abfc303c1fe1,
+                    0xc925d1fbdb1a67a5,
+                    0x3e1d4ee3fd1a1147,
+                    0xe4a06213e43c7941,
+                    0x00e497327b393342,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9295a9548a171e8b,
+                    0xf158f42ccfaed138,
+                    0x2167419f63dbff15,
+                    0xdc65241ab79a9359,
+                    0x1d1f4a6cad317947,
+                    0x8177860290f52c72,
+                    0xd59bd8d55a7ff3e1,
+                    0x72ff85767962764d,
+                    0x5ebb42ffec5540ae,
+                    0xe99791baa397f710,
+                    0x51001f026decf417,
+                    0x011e673b51ecf41d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2e3dfc347d60d843,
+                    0x83c75154f566a5df,
+                    0x6553c574866b6ad2,
+                    0xc5503b075d3e11fc,
+                    0xe2b5fb4370ad17df,
+                    0x39c583441bd0a3b2,
+                    0x395c0bde071bb34b,
+                    0xec910ad23cc0a64f,
+                    0x12aa21f9c8e80f64,
+                    0xef1b9962fbbf6b32,
+                    0x7a949e1ac1d4cbef,
+                    0x00ec9c046d94f85a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf1957997af096914,
+                    0x247ac9ae3550c304,
+                    0x9b9410eb0aefa2b2,
+                    0xee9ee7d8284b29c7,
+                    0xc819c9584bd9df43,
+                    0x1dff42607732ed29,
+                    0xf3270cdedf8d5d5b,
+                    0xb13707f718c34e20,
+                    0xbcdd88f26ba88ed6,
+                    0xe33b7ced7b2bb2c1,
+                    0x4be8c6ac063cd861,
+                    0x00e486e44381f876,
+                ])),
+                Felt::new(BigInteger768([
+                    0x616e113cf7c2f82d,
+                    0xf900be2bb4c603a6,
+                    0xea33d0692255a338,
+                    0x76ce3d2a7de4b760,
+                    0x880b1f0034fb5880,
+                    0x5db784d3e94362b3,
+                    0x865a7593bf73853d,
+                    0xba4b29938cd3e244,
+                    0x42cb3df586c10b26,
+                    0xc09d6e56666b9395,
+                    0x74b496de164495a4,
+                    0x00169f3d29c00814,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb6c7ad35c7ffa37f,
+                    0xdc4ea9e5db2ce86c,
+                    0x0663ef26d844db08,
+                    0x2f897653191e1758,
+                    0x9a82e59c9b16189b,
+                    0x08882ad362041c9a,
+                    0x3d2dd3fbf94e3142,
+                    0x66fadb28c17d9277,
+                    0xdb91d77bbe45a49f,
+                    0xc355397d7e49690a,
+                    0xb133be3ae6cd6e90,
+                    0x002af8b97bf93b07,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2126a316b03a726e,
+                    0xc521a43bd2ab70d8,
+                    0xcb2b8078d240cca6,
+                    0x21e144cc13164cb9,
+                    0x3063cc4a69e5c0d2,
+                    0x1b034c4664c2439e,
+                    0x0c7257ee836dcbc3,
+                    0x5fdc3dc98e0c1533,
+                    0xd723ba6572d75f0e,
+                    0x1248b0e1a0614272,
+                    0x0b0a0264f2d87039,
+                    0x00aaed90a3c0e35e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x86e48443b66fd202,
+                    0x8909acd82d500803,
+                    0x96548d150f1996f6,
+                    0xda8ade994626bff8,
+                    0xc77dd17d2fbc8f0e,
+                    0x08e98e8b927c34e1,
+                    0x097908866c1da656,
+                    0x00f836f2b394c4e5,
+                    0xaf2bfec36584c1e0,
+                    0xf8c8b084ea50b012,
+                    0x836ecd85644b80b0,
+                    0x0067617cecb81dea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd923905de0d3078f,
+                    0x2df7163c90e3e5bc,
+                    0x930d0481b122898e,
+                    0x22e1a72acac54b0d,
+                    0x06d8f74f309f2248,
+                    0x875287cee269828b,
+                    0xc40a42c00ae4260f,
+                    0xa215e1c0f9f914d6,
+                    0x6b19d2224153e3c0,
+                    0x4719fb5358c26e14,
+                    0xb44c625161f38d1a,
+                    0x00041ed7f5d7c90b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5bc7ef497d92f1b,
+                    0x9a5ef4b1cd8a47c9,
+                    0xc791669d55376239,
+                    0x820a8b11819965ef,
+                    0x5287d5a3dac1fc60,
+                    0xc6a79e356474b11b,
+                    0x41f1a437da339d8e,
+                    0xed5abc00e3be1a58,
+                    0xf43fc9b6095ca9ca,
+                    0x96b45d0e9beaa11a,
+                    0xba4fdd30290d5afd,
+                    0x0120d364cfdd895b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b1ede6c59d25999,
+                    0xca65a4af75bdc154,
+                    0x2b3ef56a718fa1ee,
+                    0x4bd6e0679ca48922,
+                    0xc482236e2015d4cf,
+                    0xddbd013abc6d7fc0,
+                    0x8c8b8489652395b3,
+                    0xb744cbef13ecd034,
+                    0xd5f4487b3da6698d,
+                    0x1c8e1ebd81b9daf5,
+                    0x578913194f340e72,
+                    0x006f5ed6e8e93819,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03704cb3c45938ce,
+                    0x56bf5ae2956e8808,
+                    0xfd2f9020cf22a120,
+                    0x4efd85ded4325f11,
+                    0xa025b2dc3413e30c,
+                    0x8756f839fca22236,
+                    0xebe976d3bcd69b7a,
+                    0x7520a11f6f883cc4,
+                    0x2e900ca3ab77e370,
+                    0x816eba8c9ba18b72,
+                    0x3ce8e5955a80f282,
+                    0x00a349881f6402d0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa17f665b57361351,
+                    0x185eb15d7e062f85,
+                    0xb5c05eb190ca8b32,
+                    0x8a46705c67e75a68,
+                    0xcf7900b894a91685,
+                    0x435842eafc82f61b,
+                    0xf807c52b7e6a10ec,
+                    0xdcc0d1edc3713f31,
+                    0x13d92197072c6a74,
+                    0xbb267dcb2fb7fc0b,
+                    0xea8e9fc139d323f8,
+                    0x00296af588569239,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd8a5c73f8870fb5f,
+                    0xb29f76a3d986990a,
+                    0x3a3d2c309c8fefe8,
+                    0x5b35bcae668808b8,
+                    0xbea347aacbf6419b,
+                    0xde07095a764713d9,
+                    0x6b0e47b78eba7e97,
+                    0x40fef5bb17cce10d,
+                    0x378fdae948e73817,
+                    0x4211531d9eee0e18,
+                    0xe51653b57426edf2,
+                    0x00535a977d86b540,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0fe6a53345e22933,
+                    0x9617bcaee16964e5,
+                    0x1b36c2cf47f0e07c,
+                    0x623ad329f0305bde,
+                    0xdcbce7aead1945dc,
+                    0x1ba374385ab77741,
+                    0x176820e67bb3a21e,
+                    0xde614992a0808d8e,
+                    0xa24bfb253f3ed672,
+                    0xa5d4e68205c7cd1c,
+                    0x5244309331d71d9c,
+                    0x0116fbbd154d4fd2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x46fd25e9482b75a5,
+                    0xab021f3491af3f31,
+                    0xed8a19509a86e6c6,
+                    0x5cbfd43ab22f4486,
+                    0x1515e939d23e1f90,
+                    0x2f89985766ebd074,
+                    0x421c4eab0241798b,
+                    0xf790f218eb5c7b58,
+                    0x3f415673d37fcb79,
+                    0x8e17e5be563b5223,
+                    0x175006e86b25f0cb,
+                    0x0106f0fad4401370,
+                ])),
+                Felt::new(BigInteger768([
+                    0x770dc2d48cf33c25,
+                    0xc23afe5b7322a6f9,
+                    0x540df669b6a213a4,
+                    0x2d30e342150e3d35,
+                    0xae9981362f33503d,
+                    0x6d087d9b87114a47,
+                    0x5253becfc5a34f1b,
+                    0x00ac11ecfde44101,
+                    0x5897cd9225dd8df7,
+                    0x9ab5ed277e78bb93,
+                    0xd3d89ebcf3356052,
+                    0x0057928b51beab1c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe45b2a3f61e56c52,
+                    0xc592bd00e2e263aa,
+                    0xb02769db1a0164cf,
+                    0x018af2fbcf0ecab8,
+                    0x72b394e948e09b70,
+                    0x50b96b567c08575d,
+                    0xf3dd42a095532c52,
+                    0x98518cfd07536f80,
+                    0xf34fb8f56d3541d5,
+                    0x0ea41f4e0c50b55a,
+                    0xc37eceb816f13ead,
+                    0x0108c0e01c5e49e0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5a2b9cfd7453e2ed,
+                    0x55c93dc21836a069,
+                    0xa9b4d65b313eddaf,
+                    0xcfc34db362b3cf7b,
+                    0x151c4d636ab0aa22,
+                    0x08dee9012c624e72,
+                    0x9daff57da40166bb,
+                    0x6ec4f7cebcd53bed,
+                    0x297d8ee0232d2e6c,
+                    0xfabd24d6f6eb7b00,
+                    0x1750e496b9fea44f,
+                    0x00501883329853ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x339413e8c30a1a06,
+                    0xf8371028cec0d744,
+                    0xe4478eac8e4fc083,
+                    0xa0ae909a3f0527ca,
+                    0x4ee5d2f66643df28,
+                    0x7d5d5db8f649ddcb,
+                    0x360769e88aeaa7b6,
+                    0xfad7719c87d186d3,
+                    0xc128cceadc6d2132,
+                    0x6294884bf56dc5e1,
+                    0xfbe3661ddd291364,
+                    0x00bb8a2e882c1e50,
+                ])),
+                Felt::new(BigInteger768([
+                    0x05ae4e3f5c6fed68,
+                    0x29c7e9940babf230,
+                    0x5b57d0e611714d6e,
+                    0x10195b77198c36ef,
+                    0x4130925a0b1d32eb,
+                    0x01181a923181a9a1,
+                    0xc9a5b79067f02019,
+                    0x1350f942da6aa8c5,
+                    0x99f634bf6b91294f,
+                    0xd0bff699eab00554,
+                    0xdd12a6f1875d14cb,
+                    0x00598e1f61a56149,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde231b65caff5b28,
+                    0x142dd4c4e92f13ff,
+                    0x83e6553ba310e144,
+                    0x8400a4bed5f8af8d,
+                    0xa4dc0afe27a7cb94,
+                    0xe2cc4e435f724efb,
+                    0x7d414e6008751e06,
+                    0xaa2ca94d9d903ee7,
+                    0x6d7e3893f442cbe3,
+                    0xe34259499a0c53fb,
+                    0xff1412c17ba32add,
+                    0x00740cb070a7efb7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc9352a1f342a36ff,
+                    0xa6d8decd561e0ce9,
+                    0x70a0aa02fd8984d7,
+                    0x143faa3ecfc8973f,
+                    0x089eb8120dc07669,
+                    0x732fe732f8acf18d,
+                    0x202e741f82dd08cd,
+                    0x3d41a74ebd88e0a2,
+                    0xcf139ba044a29a86,
+                    0x85b107a70131c229,
+                    0x4c93787d14aececf,
+                    0x010ce357ab6a0396,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0620a438f77460ba,
+                    0x8c01e37757361147,
+                    0x1a9587691a8c2956,
+                    0x13d32bc0fe58cd9d,
+                    0xe58b367ee4e16bc5,
+                    0xfdf47cc0c3fcbd83,
+                    0xee9ce23bd7707589,
+                    0xaaef5b9b5e409a40,
+                    0xccd56767ac9cbac8,
+                    0x48db9b527f11d0e6,
+                    0x5cae3c41b82dc9ba,
+                    0x010a6291bb6fde56,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5f98b9a4486f000,
+                    0xada01b41c6afdd00,
+                    0x6d52c451177bfc1b,
+                    0x707a06345cae1f7a,
+                    0x7288bab4f0202c72,
+                    0x97227b3b81a6019e,
+                    0x8398261fe872b859,
+                    0x51f3d38b5cfb7abb,
+                    0xb262abc20d987fe9,
+                    0xf855c3711b16a51c,
+                    0x07fb1fe693967338,
+                    0x00d3b12ef039e6ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x868ed02c6a9e9f81,
+                    0x36a3c2340e119361,
+                    0xd6d629c285ff32dd,
+                    0x9983900d1bc3e67e,
+                    0xfe2f5189b38511c0,
+                    0x37657f922a8ad1c3,
+                    0x9c83e82d00bbdcb1,
+                    0xb041d7057afbc61a,
+                    0x7b9381fe927eb494,
+                    0x9ad9595ddc2d107e,
+                    0x648e23a2e6ebc7c0,
+                    0x011b19d146964626,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x260d34e1be54c65f,
+                    0x392756de48754b27,
+                    0x7503ea4e9f05ffec,
+                    0x88641e1fb46925b5,
+                    0x919df614f22e25c9,
+                    0xefb6a2c21fd622b1,
+                    0x0cae81ac494fba9b,
+                    0xafcf76ac3249f710,
+                    0x538dab68109ba36b,
+                    0x9f90f1c10f116442,
+                    0xb7e20c97cbe60a7a,
+                    0x00913d13e7dd0cc0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7d83c6448bc0595e,
+                    0x8d1c2e1cf79dd075,
+                    0x3744f9307d458507,
+                    0x342d0d6d71517eb3,
+                    0x173e51b280d1f743,
+                    0x3caf183599fec5b3,
+                    0x22919ec6bed96cca,
+                    0xa88cf8d4bc66d38a,
+                    0x09099dc4afaae220,
+                    0x905db6b7b04671f7,
+                    0xd5a8b2a73c9d8511,
+                    0x001bb44628197e58,
+                ])),
+                Felt::new(BigInteger768([
+                    0x07b9bf02c602a150,
+                    0x91dddb8aabe7659c,
+                    0xe234f584782b3e9f,
+                    0xc81f6d1232b08352,
+                    0x61a0fff2e94e3f06,
+                    0xd8111d24c843a5a8,
+                    0x1b7e3336626fcb18,
+                    0xd00ce1629efe7126,
+                    0x529a520685014456,
+                    0x7a2f103e1f3c7b62,
+                    0xf5f666a9073d7215,
+                    0x0050cedb98647737,
+                ])),
+                Felt::new(BigInteger768([
+                    0x75f7f49d37ed6642,
+                    0x4d8036df2fb43443,
+                    0x8782abdbe50d580b,
+                    0x1d89788a5d821514,
+                    0xbb0871128ad9dffe,
+                    0xb5e6c32b40188b1e,
+                    0xf0e30b45b5dade0b,
+                    0xdcf7d214fd251e7e,
+                    0x83618eae34a71985,
+                    0xd48a063a031b4969,
+                    0xdb5ea745f9670df3,
+                    0x00680b2e5b5c2f98,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa64f60b9df0ccb94,
+                    0x0f3ab7125fe74ef6,
+                    0x5a7f3849ca2b6ded,
+                    0x23a0b2227c7e9c7b,
+                    0xa23bf4180219ac6a,
+                    0xb2b970147a60f054,
+                    0x5d34429010ac378f,
+                    0xf08370aa16f924dc,
+                    0x4f6807e9c1d982fb,
+                    0xc5977317c20e44c2,
+                    0x055807c1b7d24730,
+                    0x00a83fbefb1c6015,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e7d98f65298f206,
+                    0x3b56f0f7155bb03a,
+                    0x8db438d563fb461c,
+                    0xd8f07211ec9fe96c,
+                    0x697206850bc2b7bf,
+                    0x5c2120675293214c,
+                    0x2ebc4010e86599c4,
+                    0x0d4e1f18bb4c6beb,
+                    0xec243c01a822fb8f,
+                    0x623d44f5ca351115,
+                    0xf18cf54bc103dd61,
+                    0x00d42393af23b762,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbc8b949eaa897126,
+                    0x09c02f9cfbe72801,
+                    0x86254234569770bb,
+                    0x0f3bb1a3ca8426d4,
+                    0xc27c5471f278f5a2,
+                    0xe0069bd5fcd043ad,
+                    0x7fc7b2dd5a623aee,
+                    0x9845ca0eba0a3ab5,
+                    0xed6f67ee62021c76,
+                    0x9fdf8d2bb8da0c61,
+                    0xa400ed490d3e864b,
+                    0x00bca82a7aac6379,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa89c69b3897416f9,
+                    0x75d1ca64dcb4743d,
+                    0x7a648b7daf6ffd6d,
+                    0xe10221fa4cfd137d,
+                    0xa4dc0c7589c9925c,
+                    0xcb1e8ca7a68ada81,
+                    0xcda0a360ebb74224,
+                    0x2f9ffc64de5232e1,
+                    0x9025596824469365,
+                    0x2ff79ef3263c27a2,
+                    0x7143e5e6911bba40,
+                    0x00375445c3c0e92d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc8341d080bfb6670,
+                    0x8242a42f77c5726c,
+                    0xfc34720e1483b642,
+                    0x7b7a4a180d1c9eea,
+                    0x80f8d6cadb65f02d,
+                    0x99d337040a95c4a4,
+                    0x0158f24f5c884830,
+                    0xbf8e7692721a14d8,
+                    0x6e9e3ed45de1ee20,
+                    0x64513fb4a7c62d8d,
+                    0xfad3d716145cda53,
+                    0x010c2fe84efc3840,
+                ])),
+                Felt::new(BigInteger768([
+                    0x939f8a0a42f155c1,
+                    0x71a8ff0ade77f2ce,
+                    0x276ad4f9d8b29e11,
+                    0x8fd15dfbf85a3dd2,
+                    0xec59b64b88de7753,
+                    0x5e701722bdb8d5e9,
+                    0x946c83881dd01041,
+                    0xb400a5368a99c95c,
+                    0x86bc3f923e9e5e6f,
+                    0xce9c2089aa75f37e,
+                    0xf733b8dc3bb771e0,
+                    0x00126b7950c25322,
+                ])),
+                Felt::new(BigInteger768([
+                    0x109a16f38e4a1b0f,
+                    0x73feeede7f85960b,
+                    0x6407660ff47cb5ac,
+                    0xef28a41a0bce18cf,
+                    0x867ac430209f79ca,
+                    0xdb2b16e2b5d6343b,
+                    0xd172035263337cd5,
+                    0x62b64bb0801896d7,
+                    0x8543158582e124b2,
+                    0xe8e2863b993738d9,
+                    0x13e78e9b1b8cc33d,
+                    0x00e774ccf56dc7f6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf7fd99c30e2d52ae,
+                    0x190b05a355ccc0b5,
+                    0xe8277eb052b0c0c1,
+                    0x14cffc624aab6c5f,
+                    0x19110c2421b64b33,
+                    0x37ea5f3b6c92e212,
+                    0x97a5940dda50f7cd,
+                    0x4c8e9dd53fb88a6b,
+                    0x51203b7e4afa077d,
+                    0x8da4bda78f1ad12a,
+                    0x943383aaf7279b13,
+                    0x00efaee93d761f21,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb6874aa58ee7f5a4,
+                    0x7a528875004c81cf,
+                    0xe9983e8c43097069,
+                    0xcbdf7f4b19fc4a90,
+                    0xaaee03e1c204a4df,
+                    0x006b27b8e172e061,
+                    0x7cba62e2acfd3be5,
+                    0x1ff76aa2942cd68e,
+                    0xcb39ad826ddb0ed7,
+                    0xb8dd3ef797fae0c3,
+                    0x44fcdd243f6eb4c6,
+                    0x003b43929b215aa0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x95540e87523f445d,
+                    0x3b34806a20e5d918,
+                    0x8f508c468ada8a9d,
+                    0x134c1d751b1ec9a6,
+                    0xca9c7755a2c34067,
+                    0x6351dcc7017f50f7,
+                    0x4686121a32eb9138,
+                    0x0a7373dd533d290d,
+                    0xb6c4b9623607f9f8,
+                    0x9b8c21ef50af5f42,
+                    0xea32daf7d09de618,
+                    0x00236a0358e8426b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18390f053b072dea,
+                    0xc7ac3e1c491b70fd,
+                    0xcade5e5abde52074,
+                    0xc5ca8c75dbee9cc9,
+                    0x3d1818b096bdbbdc,
+                    0x39234b846c82665a,
+                    0x84245de5807efc06,
+                    0x4eefb2032989fee8,
+                    0xbfa1f8ea9655f301,
+                    0x8fa8fb78cdcfc7b4,
+                    0xa1a2bcfcbe8134a4,
+                    0x0107b7c352a2d388,
+                ])),
+                Felt::new(BigInteger768([
+                    0x14a6270f92b97323,
+                    0xb1de0d8ebdca6a7a,
+                    0x6bee6aa0f1e6b03f,
+                    0xcff3565d4485c339,
+                    0xe6e8645ee9678bbc,
+                    0xcbe271851d44a198,
+                    0x102131e27dc1192b,
+                    0xd58282cb4fb5f61e,
+                    0x4ed46e5148036a97,
+                    0xee0afeb279b6bebd,
+                    0xa053d6bf9ca03765,
+                    0x011d4b38cc461e99,
+                ])),
+                Felt::new(BigInteger768([
+                    0x78d5c525b5fed082,
+                    0x328f18f09bc5b408,
+                    0x80f4a0aaa80e355f,
+                    0xdd1928873b81b044,
+                    0x1d068c909d853aeb,
+                    0x4a2dd3a531a6e2aa,
+                    0x72a31fe11e8ab63f,
+                    0xe2f982aacb65bd7e,
+                    0x84e3216a7033cd00,
+                    0x60841ebc9cbe3ce0,
+                    0x4571b353dd5da806,
+                    0x00614221ccb6fe3a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x13612f4537869ad1,
+                    0x3e480a12967f280a,
+                    0x9f718c3258d0b6fa,
+                    0xf612e5a342c74794,
+                    0x452e117baafbdfd1,
+                    0x7da96aea8ef010b4,
+                    0xe705488979908c30,
+                    0xca521df2dc15d246,
+                    0xa26c1d98f8ea282b,
+                    0x069fb98a0a053923,
+                    0x3ef3329158999cfc,
+                    0x011d224cfa9f6f00,
+                ])),
+                Felt::new(BigInteger768([
+                    0x57a066ec593c4b46,
+                    0xc69e5d36493b46c9,
+                    0x6c5a4ce4de3400a2,
+                    0xd8c074371c5ed69e,
+                    0xe441b1b16f3a8bf0,
+                    0xd2657c88241f4388,
+                    0x32f2fa5144ec55b6,
+                    0x6ba2211632c84fc4,
+                    0x9aec570acaa480d1,
+                    0x32a678bce0d1a756,
+                    0x6e2e9849d20966cd,
+                    0x010964697e2cf2ff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x427bd022e91591fa,
+                    0xfcc43f26fe524c3e,
+                    0x8bf91e21610bcdd8,
+                    0x6be4c4581ec26988,
+                    0x0e52f9600a4fcb04,
+                    0xa727e5fad9d87fb8,
+                    0xdb82eefd309068a9,
+                    0xe0432fcb1abc400f,
+                    0xca59808461aca157,
+                    0xe8daf11840006fc6,
+                    0x4b5314e88a88bc94,
+                    0x006e3f2b9d999aff,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa494a2d79dd1b09d,
+                    0x5ba316b10b2ca3ad,
+                    0x80f7d0a44274f10d,
+                    0x0a5a0897743bbcdf,
+                    0xa3c96feb9e77e1c9,
+                    0xa5ab61df1177b50e,
+                    0x24e75055ca84f57f,
+                    0xab6f2748c9b1ea1f,
+                    0x3ac90050ee8b53e8,
+                    0xf618d769a60a1d1f,
+                    0xeec921da91467f43,
+                    0x00998b56e3b21bd5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b673eb0ff0ad3ac,
+                    0x92e51507f00ef482,
+                    0xb59f39310e2d2fa3,
+                    0x879c34ef03d554ba,
+                    0xae7d68a20f91f59e,
+                    0x20bb4fed4736d1dd,
+                    0x4722bd8fc6bbb144,
+                    0xe2ba3536dce6b150,
+                    0xc002a0d699f7ae2a,
+                    0x1da857045e7e183a,
+                    0xc1283640a4298f96,
+                    0x007fea1d98d18e45,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8a18b32e5f83a2ce,
+                    0x807f04ce4721d4d7,
+                    0x30349506aaee1504,
+                    0x6ebf04cebcfabc1b,
+                    0xb71144fc2671e7b8,
+                    0x2818db41c2b50ecd,
+                    0x39305c7511d54e7f,
+                    0x63c51561e4ba477c,
+                    0x16fadb8b13810f86,
+                    0x619f4b86df25d02a,
+                    0x28cc3db08bd87a45,
+                    0x005a7b81a0cc63d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2036d9ec0419b710,
+                    0xdb347d8a78a70679,
+                    0x5f2fe2c16d6e9520,
+                    0x3baabff224c04d25,
+                    0xa98da47e24133b40,
+                    0x02b6fd0714bd2525,
+                    0xb40faeda7b57d559,
+                    0x55c1007783718b96,
+                    0x0ef7c07b7a4ff1aa,
+                    0x0d9f2253510b0754,
+                    0x93edacb51ad620d2,
+                    0x00073ef4568eb4f9,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
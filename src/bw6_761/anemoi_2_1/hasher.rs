@@ -0,0 +1,814 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger768, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::new(BigInteger768([
+            0x8632d3e81d103783,
+            0x7134a71e2efbba98,
+            0x38f940dbbf4c9e1f,
+            0x7eb6bc010d2e1541,
+            0xd5f6ae789613435f,
+            0xd33a5036ab5b8839,
+            0xc35c9ba47a5dbec9,
+            0x117ab9bb0ff12984,
+            0xff58de53ae7542a8,
+            0xc533f0533b8b67d4,
+            0xcad26163a8f82149,
+            0x002f51e2847d2168,
+        ]))],
+        vec![
+            Felt::new(BigInteger768([
+                0xccce1dca66ac437f,
+                0x5ddabacc875da9c4,
+                0xf6c103e6b37f48f8,
+                0x391732e3caf89fc5,
+                0x14d64e21f37b8009,
+                0x73c89e43066f59ce,
+                0xeed38432e493675c,
+                0x33d1d3b95e00cde3,
+                0x9f7b0ce3a204946b,
+                0xe1ba3fccc3611885,
+                0x9a8211a3d4318084,
+                0x00f718c935d48812,
+            ])),
+            Felt::new(BigInteger768([
+                0x3cd06146f2688143,
+                0x3f34893d1e29bceb,
+                0x148f8e1a512bf566,
+                0x81ff60b129653067,
+                0x316539da37f36f19,
+                0xdaa3278f933e311c,
+                0x20414c1c371f52af,
+                0x311b1721fef55279,
+                0xfa37e60cad74a33c,
+                0xfe2fcd90b4fbe4f6,
+                0x0a66564ddd5efed5,
+                0x00e8368c004833a9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x69c57c8669918856,
+                0x3f0d1cb90af6d198,
+                0x6a685f8822731f29,
+                0xe3ae615698ce9034,
+                0xc84b75eb0dfc24ca,
+                0xfd8dc942e453ee9a,
+                0x8971571b6fb0d485,
+                0xe302ea169e6ba3f6,
+                0xfd11f675dd5dc507,
+                0x5b4daaf225103bbe,
+                0x7e3ffd7931d76c10,
+                0x00908eed9b74f810,
+            ])),
+            Felt::new(BigInteger768([
+                0x894db06952f1b9a7,
+                0x8772e25f13bfbf42,
+                0xcbfb9e29ddb0c8fe,
+                0xdb6846335ef7f34a,
+                0xc9949315c9d9cb38,
+                0x4b01c1396b8c5411,
+                0x6574c2a6ef5c7369,
+                0xf51bb90f93741a1e,
+                0x7015a2bdcf4ffd0a,
+                0x96f16528742e2530,
+                0x1456b68eaa743d77,
+                0x00a6af9be3de3d91,
+            ])),
+            Felt::new(BigInteger768([
+                0x1540533f13b0a86a,
+                0x7b4e78f428bd5deb,
+                0x94b091230e2171ed,
+                0x57665e0a8a06fc9f,
+                0x69f3c47c5dad097c,
+                0x5e0296906b941f76,
+                0x4a50a1b8e24ce3fb,
+                0x68c67343222efd79,
+                0x0c5922941caa1f9d,
+                0xfcf0a60e2149de7d,
+                0x44d00cd12961aabd,
+                0x006e1a42252d9e07,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x74eaf2eb784aa138,
+                0x1e9beb90c21ae08c,
+                0xefcdc1b96659e232,
+                0x78fb74b9ec40eaff,
+                0xd1715aecd94bfbc2,
+                0x3075a5105c7d3b7e,
+                0x3c234d090a54bec1,
+                0xfec9c403337e1475,
+                0x818408ad9f4ab381,
+                0x49cc83835bf2920d,
+                0x8d9c47f5d21e6871,
+                0x00206722b2a67aee,
+            ])),
+            Felt::new(BigInteger768([
+                0x711877b65123c07f,
+                0xaeba686000300960,
+                0x98920ec47669af3b,
+                0xa86af5597dde19ea,
+                0xb82e61ca1b1b6793,
+                0x4fbd52ee3f3a4f9b,
+                0xc87ff6171cd5b385,
+                0xe599d19b2b462a0f,
+                0x63c2e51558ffe9c5,
+                0x410f293acf1d34ad,
+                0xb0f26f70ef4c5d02,
+                0x004b7203ef977797,
+            ])),
+            Felt::new(BigInteger768([
+                0x6c1abd7464a2642e,
+                0x4e653e248783f383,
+                0xa08595fe407c69e2,
+                0xe6a285d1731fc070,
+                0xec5e21be00d9ef41,
+                0xa46cb4a46dd690c1,
+                0x391f82b42debd589,
+                0xafadfa6e812b3193,
+                0x66ffd2b22242c9f0,
+                0x0985a69373392a3d,
+                0xd9d00a65908ef0f6,
+                0x00c26e5b33ce8cac,
+            ])),
+            Felt::new(BigInteger768([
+                0xf38f8431ff572575,
+                0x5100ef183a90682f,
+                0xde4d7bd2614bfbe1,
+                0xa2790a432c7e79e6,
+                0x4fcbdc89d2ed3770,
+                0x2374e7199f855232,
+                0x43c761fc9cef67dd,
+                0x602f11992c040a57,
+                0xc539e7998f23b9c2,
+                0x8f8ecf88b2694574,
+                0xe1967bd9cd50404b,
+                0x001d1c43109b5d6e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xde0a1e5fa3c0d13c,
+                0x1a5f147941b0d700,
+                0x5722700003f0aa62,
+                0xc8c6853d41552d28,
+                0x55941ab1d53d60f6,
+                0x1871617b639f441a,
+                0x035e807adf866f49,
+                0xb43481cd16b155a1,
+                0x9a1e0dee1b3baf0b,
+                0x517db6fc95847a47,
+                0x6d89446c43e35f45,
+                0x001661cf7f03e0c7,
+            ])),
+            Felt::new(BigInteger768([
+                0x44a286f6160709e1,
+                0xf37c15cf6272c9a2,
+                0x3b0da4369e5e5f6c,
+                0x61e1f1ba82f2f2e4,
+                0x62d71c9c422c5adf,
+                0x858033d78f500547,
+                0x7a72f7db4c4182b1,
+                0xd3f1523914167db8,
+                0x8face8d85841c687,
+                0x53ff9e3fb684b543,
+                0x02c2461cc5ad5306,
+                0x00b574806b48398a,
+            ])),
+            Felt::new(BigInteger768([
+                0x4bfd0a4ddfe97264,
+                0x0e48f7422db7a4ee,
+                0x8f6b667544a1a17c,
+                0x0b2bab5d0d1969cb,
+                0x493842800a78f94c,
+                0x987bdef84bad567b,
+                0xd0c40aa37f27d0bb,
+                0xad0bb1d032482908,
+                0x1ee05150c73f0d1d,
+                0x29d3f0469cd013e6,
+                0xc9e0c2828bb3f52c,
+                0x00248f0e369cdda2,
+            ])),
+            Felt::new(BigInteger768([
+                0xa265be781b5ee0eb,
+                0xde4bb9648ecca120,
+                0x18ac05ce1e9baaad,
+                0x11bb88f948ce61dd,
+                0xdc6413e8a306046f,
+                0xe5b543c20af226ea,
+                0xd57b385f8ffcf083,
+                0x7cf74551862755eb,
+                0x6e36155732b70325,
+                0x95836e184e2d0c33,
+                0xe22eb63379b4c7c3,
+                0x008db5a43d99254b,
+            ])),
+            Felt::new(BigInteger768([
+                0xff15ba3af861e328,
+                0x158c606c29401b0c,
+                0xe7fb0b08978b23c6,
+                0xb444910e9e0f88d8,
+                0x94e472159f84b1dc,
+                0x2207c700540ef54f,
+                0x009ce13c8b8cda26,
+                0xac05627f77d7d1cc,
+                0x7e8e1a14671004fd,
+                0x1beacb6a13d01bb8,
+                0xff739112fd5d09f5,
+                0x00574dd32bba5038,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x1f9448c7da3a036d,
+                0xcf068e04c9af588c,
+                0xdaed3a21f0ec7148,
+                0x448450179e965ae0,
+                0x76844b539e4109ac,
+                0x5a6bde99b8522991,
+                0xa04ce06d066ba909,
+                0xdba8d7765879bb3d,
+                0x05d99a724e241d39,
+                0xa40bb3bb7afa6a5d,
+                0x9dcf45ff60c9ef22,
+                0x00a923d4afdb17f2,
+            ])),
+            Felt::new(BigInteger768([
+                0xfca308f1cc6afb1d,
+                0x39d0269e0dc75d2a,
+                0xeb8d46311ba769c2,
+                0xa63712858d6afc1d,
+                0xd9f33656cf26b223,
+                0x6fb99f5883707963,
+                0xc8fc5999c173cd9e,
+                0x45eefb2f8131a113,
+                0x989fc88ebe876347,
+                0xc792b350832f88d5,
+                0xbc18ce0e74dcb57c,
+                0x0090a0b497e1b6e1,
+            ])),
+            Felt::new(BigInteger768([
+                0x3a28a82e3a59d55e,
+                0x4fab89f70bf18fde,
+                0x73ea96bf5d692900,
+                0xd6f428e80d442b44,
+                0x9dd8e4fcdf7200b1,
+                0xd59099c737bf8dbe,
+                0x2b7360f2ae55fa5c,
+                0xf6a499fb19453909,
+                0xc5cfd1c04382653f,
+                0x67731a66d69bca3a,
+                0xfbb893bf3be5d669,
+                0x00e08f898bf6aa00,
+            ])),
+            Felt::new(BigInteger768([
+                0x2f9723bfcb497822,
+                0xf3bfa9c7b7c615f7,
+                0xf856f3b8932302e0,
+                0x7022563477009708,
+                0xa734d3b28b3efeba,
+                0x97c20156eb6ad12d,
+                0x1e161d12ac29b751,
+                0x4c7777f76c7ce673,
+                0xb9947c358f0c2324,
+                0x0a9f34eb9e01a172,
+                0x5d8dec8abe7214dd,
+                0x006bae19790cfe53,
+            ])),
+            Felt::new(BigInteger768([
+                0x76959cbad6891d22,
+                0x1c076bc1400558e4,
+                0x8eb131caf00d5e4d,
+                0xc85b4ec7018b367b,
+                0x16cd9a81b05a1498,
+                0x4debaa26db9f37c3,
+                0x995d1a85982a7e90,
+                0x7e259ff525332762,
+                0xc5b306ead4b9d64a,
+                0x41ca78df39b8afc1,
+                0xd77befad0528b1cc,
+                0x0011bb3e6dcc742c,
+            ])),
+            Felt::new(BigInteger768([
+                0x7c3a3f6de03b2a4f,
+                0xbaf9b465c8d3d399,
+                0x00580303e5169b78,
+                0x64ef22e764949f24,
+                0x5f14a2430c313704,
+                0xbcd0202f1bdf0f44,
+                0x814eed424519e3b8,
+                0xb4cc36bfb29cfb9c,
+                0x50955d638723c91e,
+                0x90b8bbc910249b32,
+                0xadae93073cb5fab9,
+                0x00b3f25959faafa6,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger768([
+            0xbcf46120e3533431,
+            0xec58cc9ed2806b1b,
+            0x6037bd9a0fd17959,
+            0x17263d7fc1299ff5,
+            0x43a1e752edd2b0f1,
+            0x79ba472f8c7730dc,
+            0x2a8f11edf04a47a7,
+            0xa62277c98212fe9e,
+            0xfa6476383d8b63d7,
+            0x32d46cbd911c28b4,
+            0xcd1fa4ff3020a142,
+            0x006d4e2eefa14255,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x79083aea47742815,
+            0x9e57f5961f2ef936,
+            0x881d0b537af4b5ea,
+            0xde62fbe5c0e70bc1,
+            0x2ad0fdb40f9b5a08,
+            0x1b211b1f9b6ca617,
+            0x6c9d70657dd923a5,
+            0x78388bb5dd8c9f3b,
+            0x427a607713a16b44,
+            0x04ec959b5e5b4af0,
+            0x706daf232a515c17,
+            0x0090d24051c9a0dc,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xacde02a432720ead,
+            0xba550ab2e537b773,
+            0x8df17ba1a09c1c6e,
+            0x09d592381dd97e1e,
+            0x60a9a53029c0d08b,
+            0x3b15a0d28b24dc7a,
+            0x136e85474813bd32,
+            0xfc4e95a1bff70d12,
+            0xa7c2d0cb955c7e23,
+            0xc6ba8e2666084c1a,
+            0xd958adaa7fef4276,
+            0x00c16a4b728335ac,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xa9b95a3c454458f5,
+            0x9efb3d3c0827fc94,
+            0x0506daaf6f44c2dc,
+            0xb0b1d05da2df872c,
+            0xcd3b2cad269cccd5,
+            0xea23018c8c5dc17d,
+            0xb1ca853cdddd736b,
+            0x002e63cc00814764,
+            0xc2b0549ab0d1d4fd,
+            0x0bdca6a86ef59c5f,
+            0xd8bae60931fa2b41,
+            0x00f0efba0a28def2,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x404246381d332f4e,
+            0x599d89d355744b85,
+            0x30d3dece7951a0a5,
+            0x4f3a6ba8ce429af7,
+            0x599b1996670650b9,
+            0xc2f90d7ee65c6d2d,
+            0x56d1ac97ba40c222,
+            0x8dc8f1eef94c5a80,
+            0x8115cf0f3da0bfb9,
+            0xabb7446c04f683e3,
+            0x7c4dcba4e977eac4,
+            0x00a688707947b42f,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xe8e1f74a6ceb65dc,
+            0xf6ad8cfbf3bc8cbc,
+            0xd4aebff3cce2e05e,
+            0x3ce6f9673d77168b,
+            0x1b39e9c0ad3f4030,
+            0x0c2df3341c2b9ac5,
+            0xa08350bcae7ba560,
+            0xb0d916a0d777fc9c,
+            0x27d903c370295d13,
+            0x9ab6cc02c5c730b1,
+            0xc151e96029066f0e,
+            0x007d471a03c83a74,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xc69857aaf2e5c08a,
+            0x14c65a70ee3b656d,
+            0x23404bdc84924502,
+            0xf30bbba9cfa2cbae,
+            0x3ce3585e208302ec,
+            0x2a9be9b2b717ba39,
+            0x6c540f7aeae5485f,
+            0xd9912883cb1e3311,
+            0xe70a6c014bf9cf15,
+            0x85c73422e1abe719,
+            0xd9096562269288c3,
+            0x00759e9c6c0e090b,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x3d9da8d9a74c7866,
+            0xd979120200234e8b,
+            0xdd785b60d27389c8,
+            0xfb6a138dc3e5d64f,
+            0x5a7f265d7bfe8b34,
+            0x8404b2d06e8a9350,
+            0x33921e8a2137c106,
+            0xf256b174ab891531,
+            0x63bbc29c0beebf42,
+            0x569a109fe584273b,
+            0x86c0a0490ba4012d,
+            0x00465a009d1169fc,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xc6b018ba44c8ac03,
+            0x3682ce85b3389264,
+            0x48bc4f8d96ee3f09,
+            0xec499f8a3611f1ea,
+            0x626a8e8e6d316a6c,
+            0xa90f4d730a1e3418,
+            0xa8c23c7e52495b26,
+            0x9426f237453b74f7,
+            0xf8dde8e3a7021ee8,
+            0x069a8ffd0fc768cc,
+            0x070aad66c0eb5250,
+            0x0119249f722b352a,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xbac45684098ab8f4,
+            0x61d3c5e293ec3774,
+            0x1bf86e713a48e13f,
+            0x50803174fa5c71ba,
+            0xd8d4f0f51f3e60f4,
+            0x63fa1f8c5fa79de8,
+            0xed6ee7e51a67b09a,
+            0x3375a1c66ee8110c,
+            0xc5d9c12b35b9ec1e,
+            0x95f7eee63e842d15,
+            0xc52e9148dba51848,
+            0x0043621374def8f0,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger768([
+                0xfa4f833e425522f6,
+                0xc648cec6af497956,
+                0x1751bcd6f59ce477,
+                0xb15497e693c0fd9d,
+                0xf8aa7b142e467618,
+                0x50984c79d150c7bc,
+                0x464fea8b5f00321d,
+                0xa771c2de36d5465c,
+                0xda3608c4a0342ae1,
+                0xce17dc9facf63691,
+                0xac73013ea6116fd0,
+                0x00e38f8dffa25d10,
+            ])),
+            Felt::new(BigInteger768([
+                0x1cde3f56efab5eeb,
+                0x7fb6151982c0bd41,
+                0x452c7f5e154f01df,
+                0x8feec67291f7198c,
+                0xb683bb04f78463ab,
+                0x830c4a49a4480dc7,
+                0x388cc332efc27192,
+                0x4a5b04046357780b,
+                0x0ae2b9bdb7104d42,
+                0xea13773c22da7dc8,
+                0x742082cbf3fe22dd,
+                0x0113f100dd00fda2,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xa69449941406b404,
+                0x5f8ab0b7a98b2f1b,
+                0x5957878f4afb1e42,
+                0xb2493e6314066d75,
+                0x413ed43901c36d5f,
+                0x6c0741bfb4bc23f4,
+                0x4eb8142536c9c50b,
+                0x32826bf9db335a3b,
+                0x9c3e0e992c98f152,
+                0x0223312ba80d9fc4,
+                0x55db28366e629c26,
+                0x0099f4fe1ddb0715,
+            ])),
+            Felt::new(BigInteger768([
+                0xa62cbdf779b06f6d,
+                0x0bf1a1568d7030e5,
+                0x55f86986d1837ff1,
+                0x79355428455f13a7,
+                0x852fad4cecf390c2,
+                0xc8fee8525a3368d2,
+                0xbbcb0b22a379f5d5,
+                0x172180fa881c2477,
+                0x63a650868bde779a,
+                0xdee4149e7e0a76e0,
+                0x445bfce761440f97,
+                0x00f3e8b526ba7ff4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x8f70389ed2f20dc9,
+                0x047342165130e09d,
+                0x372eaaaf61579ee9,
+                0xd3ec7536e78dc2d2,
+                0x4d0db96f1b300359,
+                0xde84bc769920932c,
+                0xd343768635488a2a,
+                0xcad60dab87ee3d73,
+                0xc2aa8fcaf2ff9ad7,
+                0xbc160d59801d10a7,
+                0x7d094b89195601cf,
+                0x000dc0c778d7f266,
+            ])),
+            Felt::new(BigInteger768([
+                0x4796de54b9f104fa,
+                0xbd890dda0ae93f35,
+                0xbafb9570202b13f3,
+                0xa555f8a931176a26,
+                0x10dfe4192db6a614,
+                0xa819156326c8e55f,
+                0x1c519bd7482136d5,
+                0x1da35fd98a534b19,
+                0xc35b4fb29c45b433,
+                0xcb836eb05689e478,
+                0x3374c5f79d307a8d,
+                0x0070236719ac696d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x9d076afe55a943da,
+                0xab070aa2535913c6,
+                0x32cbad942ea06b71,
+                0xb25f492fa303fd25,
+                0x930076b93df35cd1,
+                0x7dcef9db75ac43c2,
+                0xeda60c4c778d7ccc,
+                0x3460cf3175a50802,
+                0x8067930f6c71e028,
+                0x785e328574fd39c1,
+                0x1362097f8d7c1023,
+                0x005261bc68d59057,
+            ])),
+            Felt::new(BigInteger768([
+                0xa60eac6d92c8a09f,
+                0xa517b96a06aa2df6,
+                0xf7769903be762a43,
+                0xe80aaab1f1e68e1a,
+                0xab0135d9f980b14b,
+                0x5c670faae2c8a4c7,
+                0x0f1f6f90d9506f97,
+                0xe43de8e828219703,
+                0x8a19c9b64bf8d200,
+                0x842a872b7716592e,
+                0xc6281e1774fa7d04,
+                0x008c56cfe41ad051,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x9e2b22ec9a310359,
+                0x680e201e1910422e,
+                0x76f52c4d6f7415c9,
+                0xaee44ff6b195649a,
+                0x4d06ee15d03281d2,
+                0x9f0b8c2d1358b0ec,
+                0xb1c3f2c5b4312635,
+                0xa26bf19dae759a23,
+                0x9ffe5a9d5119297d,
+                0xb071c45bc1cec1b0,
+                0xf2b75b944a77aa60,
+                0x00eb2fb2819a66fc,
+            ])),
+            Felt::new(BigInteger768([
+                0x16c0b496d2ad1bd8,
+                0x55d96eca353c815e,
+                0x04914340c9c878f9,
+                0xd4422ee46ebcd3ec,
+                0x37aa70b4642c157b,
+                0x46f151bde5db8c9f,
+                0x9f9b497d15276bf6,
+                0xeb5998e4cd77b18d,
+                0x09230c5151d94f76,
+                0x3946025cd3033aa9,
+                0x5c86c24243de7720,
+                0x001ee39d649b09e9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xae39fa8e8446b84d,
+                0x45ec826bd84a0d35,
+                0xfe5d7b358c0a7a70,
+                0x09e6d95062dccb7f,
+                0x4b17519ca8fda12a,
+                0x47dae0ab4bf01c02,
+                0x46017a0b8ffafdc4,
+                0xa87d56c80d75f9d1,
+                0x34e4198db54664fb,
+                0x951fdfaddf2a2914,
+                0x9ac378f607b39c7f,
+                0x00a7ca492dcbbf7a,
+            ])),
+            Felt::new(BigInteger768([
+                0x7b8e3efbf32e9a57,
+                0xfe1adafd3ec368c4,
+                0x827176567d958f5d,
+                0x1269bb39edfd2856,
+                0x2dd1d977dbbd362b,
+                0x412948e3956df0da,
+                0xe71637ac5ddf7f2e,
+                0x30c8b8a832345dd5,
+                0x84d10d727838f4dc,
+                0x143d61489eeca1ae,
+                0x64dd55d511b7bdbc,
+                0x00f9cd3ef729bff5,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger768([
+            0x6a9bad498c44b480,
+            0xe3f89fbd397e1f34,
+            0x27069d8d5ac4e4d8,
+            0x90f13cfe3725c9bd,
+            0x02883e55d2832940,
+            0xacfbc51977e7c32a,
+            0x7f56ff260f8e628e,
+            0x38a66f5f378d5696,
+            0xd19d0b6281782376,
+            0x92cb97f5324c127c,
+            0x3430d81081ba3922,
+            0x00c5870f48013fa0,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xf74fa8032427e83a,
+            0x109d68afdbd56ab5,
+            0xfc89803a7a206784,
+            0x1b9dbc800e4e80c2,
+            0xe39b49f886f7bfb1,
+            0xd993b79c8c618398,
+            0x8148c040549a1c89,
+            0x732087dfd2034199,
+            0x1ac74749f788a8df,
+            0xcaea7a124f02f169,
+            0x22bd36d740ca62d7,
+            0x0089e93796656f02,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xbf80a1a749e52ab5,
+            0x442b67b8c76ad9be,
+            0x9bc8bfc92ab798f4,
+            0xf70fa8bb4e704a64,
+            0xdc0414585372f85a,
+            0x239a2c9168949d56,
+            0x7d7e16c1de87ecda,
+            0x154f776207c385c1,
+            0xb88af8e75fbf7a05,
+            0xe59e87a66f0b5d7c,
+            0xd74cd47bdcbb248c,
+            0x0067a67c91db5123,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x7e1c6514baa9040d,
+            0x774bae5b24136d34,
+            0x060f86c56cc21e92,
+            0x68efe2fc81c3f82c,
+            0xed8938d3655a432c,
+            0x14852f9649622581,
+            0x3a17d6be3483fed2,
+            0xc00d3efb79a41037,
+            0xd8c309a71ef0db43,
+            0xd2d158024eef7b96,
+            0x6a71037dee1eacab,
+            0x0061e78b4d197a2e,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xc9cc5ec48cf9576a,
+            0x2b2cc555a84e6ca8,
+            0x4fe844e0e5614f1c,
+            0xdd708942903e98e8,
+            0xa3a9b3440cb0cecf,
+            0x876f8e00eada7643,
+            0xa6e0cb3d29d7c4b1,
+            0xae29d45a65072628,
+            0x210f0e58320fb64c,
+            0xe5c469c3ac29e201,
+            0x0bf7e9c93458b71b,
+            0x007dfec4f9855596,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xccfe2014c6848b06,
+            0xbdd886b15ba748dc,
+            0xad0ef77132b99fd3,
+            0xba9f176a22d4c948,
+            0xa57b8acaf0191c8f,
+            0x56aacd54ff791743,
+            0x12f273bcad500616,
+            0x331c1e70d62703de,
+            0xa0821d79b3200873,
+            0x2d2fee0f0af44373,
+            0x581399aab388bd39,
+            0x000ec3d80c1ccc06,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xc3e918e619ee18b0,
+            0xe3bbd1c18c64a774,
+            0x005572f49283f551,
+            0x3c6182ec9bac2888,
+            0x09a1dbe42a8ca90f,
+            0xe028cb61bc1e01c1,
+            0x35934efd0e4233d9,
+            0x7386a1ea39c3304a,
+            0xa85a3c3b28db0f0c,
+            0xb5ad3eb4afb6cfb9,
+            0xd4d97784e35bc185,
+            0x009f9a69c640ad4f,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x3787cd4aec215461,
+            0x6dd47a8f6984f1e5,
+            0x3f19547fd3a85152,
+            0xe1c219920e23977a,
+            0x59001488fb85346b,
+            0x8334b11d83af1b1a,
+            0xcd09fa89dad3207a,
+            0x758140b4e2187c42,
+            0xdec7e29ed02fc120,
+            0xb56a17b44b5b671a,
+            0x065f66ae89b148ca,
+            0x008dded8f649b07b,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x0f5fe3e31f0a98e3,
+            0x256c5b1cb3ff8bd0,
+            0x7077354b8c24fffc,
+            0x92ceb84aff3b979f,
+            0xe32c3fb8a0513b47,
+            0xa380e238e60a2e0f,
+            0x1e8d536dd5e2fa67,
+            0x3e997ccd21da976c,
+            0xcde7ba6caad09643,
+            0x7fed0f02e8ee3e74,
+            0xab96ba6ad28942d3,
+            0x009802c41c31e9bf,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x94fd77a40bf1662b,
+            0x38217141c6e86528,
+            0xc1495e3207595db8,
+            0x1097910fee726bc2,
+            0xeda4cd385e5cae12,
+            0xa0066cb1892439ac,
+            0x8e343bc1cafdbad4,
+            0x69c434d7a378ded8,
+            0xcbc84a0ddb6ac442,
+            0x54798c37cf928147,
+            0x5b714433c49f2b8d,
+            0x006a69dc9c1478ea,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+}
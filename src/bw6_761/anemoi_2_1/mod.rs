@@ -0,0 +1,1074 @@
+use super::{sbox, BigInteger768, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 192 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x32b392e40f3b8b45,
+                    0xcda5eba03bfcaab4,
+                    0x62bf0d8ee981c4d8,
+                    0x9b3e9c1b090a2538,
+                    0xe66dc29a7d695410,
+                    0xb6203bd524b44049,
+                    0xfbb8842c1cdb66f2,
+                    0xed2af2017e6b1e6b,
+                    0xee151c7612b4ce44,
+                    0x851e7d10d9e18b4b,
+                    0x8d94aa3acb63edaa,
+                    0x0104ac8fda9070cd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc7b1f7a40dbd6ccf,
+                    0x343cd7892010642b,
+                    0x36019ccd35b7eacf,
+                    0xe9e8438416177ecc,
+                    0xe0aed34f07c51640,
+                    0x5288f16e24241d11,
+                    0xd654205507b4654c,
+                    0x1890540ef3cbad17,
+                    0x456a4cab579e1492,
+                    0xa66bab0a39c9cf18,
+                    0xee4bf6df03ac8cf7,
+                    0x0071fe5fd699928e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3991c1cd380ca14a,
+                    0x3b12f6516bb3ff3c,
+                    0xb40c9268147c15e5,
+                    0x105616ce02638d15,
+                    0x15b6f9e58d8603a5,
+                    0x404097e79058ac04,
+                    0x8546cb1d81649250,
+                    0x5bfa8949d1478706,
+                    0xa80488cce2dd6cb3,
+                    0x75a25008b178341b,
+                    0x9a918503f1bc2b84,
+                    0x00949ef88fd86def,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfc1df0883aefcc8f,
+                    0x7e7505f23b862df7,
+                    0x6698110f423380e5,
+                    0x9e9da8bff9483168,
+                    0x1ebdeb569845de09,
+                    0x79befb1643ca727d,
+                    0x32ecf2d27b707471,
+                    0x6b508c512e53b654,
+                    0x3aca575a7c2b541f,
+                    0xbfcb627b058277b2,
+                    0xf3ae0680646b15f6,
+                    0x00ffafcc5b7de30c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd342f1ad8cb4bd01,
+                    0x16c655190402d1d9,
+                    0x1c15f45f6c0a774c,
+                    0xf451303865676235,
+                    0xdd1bf8ee806fbab7,
+                    0x00707b364e6a031f,
+                    0x445a2114833eea59,
+                    0x89982d6ae04d3d22,
+                    0x9070a9901e75bd63,
+                    0x1f8defaaed5674e4,
+                    0xadb6ad39379106bc,
+                    0x00bb0f7b3067d16c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x284ff327225f63e6,
+                    0x9498b06fbef1feb1,
+                    0xff8d4db5d964933e,
+                    0xb83b62424357d8fd,
+                    0x2ea4760c46fc397d,
+                    0x6fd24b286eb52c9b,
+                    0xb0fdc301d3f12ce9,
+                    0x8a243245075a04e6,
+                    0x4a5846f75f5f5747,
+                    0x3e86eb83670e8053,
+                    0xba3dcf4e55fe43ab,
+                    0x0022c131e9cef963,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe76afbb5060f9910,
+                    0x62dbc1f7e0d6d057,
+                    0x1c73a811cf3439a3,
+                    0xfd26e5af87ea6f6b,
+                    0xc968825de8beef4c,
+                    0xff48b23675fd6eb7,
+                    0x1ec0180df6bb05d8,
+                    0x509c19f94fa41baf,
+                    0xc93aca0fdeef71bb,
+                    0x72e2a490c680a305,
+                    0x56ea0c0baf41ef0c,
+                    0x00588029a006378d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x716712f1f3ca8aab,
+                    0x16c3c53408cbc4c1,
+                    0xc342363982402f79,
+                    0xd2682643dba2c44a,
+                    0x7f8c151135913b30,
+                    0xe9d8f9f3f2666451,
+                    0x332b5dc6eae26851,
+                    0x127fc085236b1edf,
+                    0x7228543e9e541b20,
+                    0x0257c3fadb6a1471,
+                    0xc736cd88bb0925f6,
+                    0x00d199a000a4be26,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xdf23f4f4aad2261d,
+                    0xd5194b3b25c90500,
+                    0x2626262c5b0e1600,
+                    0x020ba3ba2fb9b794,
+                    0x2150459a905b789f,
+                    0x6b343b449e933f43,
+                    0x62a13f2c7176ef52,
+                    0x9c273a4d8a76f6dc,
+                    0xefaf2f7728122f4d,
+                    0xef2e0bbc170c59e9,
+                    0x4afdc4a4f5d0e472,
+                    0x006c84b055a47593,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea8e0c0fdeb5b95a,
+                    0x8ba40a0bac52f830,
+                    0x204d92eff38e8d36,
+                    0x0fb6da6e29b85dea,
+                    0xbd9d0a6e6f3373cc,
+                    0x986989ea75db69b6,
+                    0x82b4a14ec970844b,
+                    0x42522341c78c692b,
+                    0x84fe08d4106986aa,
+                    0x8e7aec454d535efe,
+                    0xb8cd135530a49ebc,
+                    0x0079361dcb6aa6f8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6a11cfe616d930d5,
+                    0x13f6a65718c3e5cd,
+                    0x32b575593dc7202c,
+                    0xa1f06527d3c92d58,
+                    0xa18879716c81f7a7,
+                    0x41b8fbc6c5e1ee51,
+                    0x2c65cd44128c5c70,
+                    0x4dca0b7af6129e01,
+                    0x6da9c1d5d6f646e7,
+                    0xeb54c70b6b49f13d,
+                    0xfc026e88645595f9,
+                    0x0046342734c6de63,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71df14dd3a9defaa,
+                    0xe31a22f7eb1637d8,
+                    0x36307ad5d647beec,
+                    0xf5e1297f47bca34f,
+                    0x1a16a2f9a9a97a6b,
+                    0x1f4db2088271fc78,
+                    0xb91138d66f2ec2fa,
+                    0xf445fb3272a7aa97,
+                    0x4d22bd4325a7d480,
+                    0x433e1bea506aa532,
+                    0x910b74eef225d53e,
+                    0x0026377626c1f900,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf545592cd577327a,
+                    0x2692493dc0b0331d,
+                    0x0be323d1a4d6f86d,
+                    0x6e65f9aa1d5a1247,
+                    0x5e40787cff18c0c2,
+                    0x31387ec91ce2f779,
+                    0x130b4ebfe73c7e43,
+                    0x2380486c9f95cc29,
+                    0x100835bd09bed5fa,
+                    0x0a40279bf31c2d98,
+                    0x5c02df61c3bbb378,
+                    0x0029aaf79de66a14,
+                ])),
+                Felt::new(BigInteger768([
+                    0x02c60f0d1929dd61,
+                    0x7ea2d7465bd64193,
+                    0x7762b0601d3c05eb,
+                    0x8245e45ad94b3034,
+                    0x8db543618b164af3,
+                    0xd9f258fcb28eeee2,
+                    0x5cf648ee8c144334,
+                    0x8925d049b88ab222,
+                    0x589d1c0e5a50198d,
+                    0x347faf7eb94e24a6,
+                    0xdfdaa24549a43639,
+                    0x0041a0203dfb1efb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8844750758da9fa6,
+                    0xbd7b80991b17bc9f,
+                    0xd695d2914ac1dcff,
+                    0x27de99f79540d3a6,
+                    0xd371d453226347da,
+                    0xdff87cec58ae61f1,
+                    0x51e0f811aa5b11e4,
+                    0x7d341bcb4127a603,
+                    0x6ff86b2b07b2cc4c,
+                    0x11f008fe8b0f22a8,
+                    0xc403b1d1820a2b97,
+                    0x00bac5cc867d1f7c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x05a7dfb50984060f,
+                    0x46cfe0dad603a326,
+                    0x9bf66132f4af8f8f,
+                    0x582b9219b198823b,
+                    0xdc290ad85141bedc,
+                    0xe66202accb425e6b,
+                    0x28a561513334bd4f,
+                    0x92815b5b91f6dfc9,
+                    0x2eac44535ccd5dc2,
+                    0xcf56237cbf9eb8fa,
+                    0xbe5d4920ee7ac59d,
+                    0x0012888940c0254d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x05670131652849c8,
+                    0xcee2454860983f1a,
+                    0x00cb4597cb1c4bbc,
+                    0xf2320f3b14967f65,
+                    0x832b73d230d2c7ae,
+                    0x5c0e014325dd4466,
+                    0x5e429905a2a24c03,
+                    0xbda39cf7a30f8879,
+                    0x4577770329b13245,
+                    0x00a9ed85c6a7145e,
+                    0x294ee416a0bc4334,
+                    0x00a7b2b9177a6bac,
+                ])),
+                Felt::new(BigInteger768([
+                    0x23e58d6f555b0057,
+                    0x3ea05aadfb98e064,
+                    0xbf87b37393760bac,
+                    0x3262c55a8eca4de4,
+                    0x0b4271289335b871,
+                    0x69ad0df4618309e9,
+                    0x017ef4c7bba53051,
+                    0x4b1d69f3174c9c14,
+                    0x928192ce3b9b3e1b,
+                    0x4f18a5bfcc0a90cb,
+                    0xb137b92436c3aed0,
+                    0x00cdfc3ddea8d2dd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xafce2c25f855792f,
+                    0x7e94787e76e91fb1,
+                    0x8aff4ed68d4e2c46,
+                    0xd5028dbff1dac1a6,
+                    0x4341de545f3829af,
+                    0xa75260ae0946cede,
+                    0x569609c87746be2a,
+                    0xffa76f26c9fa90c4,
+                    0xbffca3a08516f7b0,
+                    0x404892254f366d9c,
+                    0x6671312a57a4e587,
+                    0x00cfa7741c31659a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f44a18fa2ea2e3a,
+                    0x460292b2361ec143,
+                    0xa60a4a144d1454cb,
+                    0x9c3e2a7152d85423,
+                    0xe50e240fd5472892,
+                    0x6c884a3d8fba1ef5,
+                    0x77a72dba3a66ae51,
+                    0xf97dbf3f05fb9d8b,
+                    0x70037c3dc4e66290,
+                    0xe828cc824af7efd9,
+                    0x6c67d2fe35d21046,
+                    0x00098fec0da72f13,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x151605c93835595b,
+                    0xa5fa6bfee06df573,
+                    0x04ba7255792a9302,
+                    0x7b1da4f53fd3372c,
+                    0x5532e8f0e54f5904,
+                    0xabedab704a7a06bf,
+                    0x883941ea08fee165,
+                    0x31ed5d7dd743a76a,
+                    0x115681af23ad1788,
+                    0x372f4f0039572611,
+                    0x3a9dc0f3bce97131,
+                    0x002d56a7f126714c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcdbc783c5846ed63,
+                    0xc00ddd13596f0d37,
+                    0x67f7e13a47dd5980,
+                    0xa785f3ea0eed42c4,
+                    0x63f225f39c8a6410,
+                    0xcc469a39a36468bb,
+                    0x85416eb37435df8d,
+                    0xb3e77297464cc56a,
+                    0xbb085968afdd7893,
+                    0x25724ad20aab2f9e,
+                    0xe9c2436479167245,
+                    0x006a27c7a005e568,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x23fdd51c78425d2c,
+                    0x79e41dbc9a20fd64,
+                    0x94bf81fd108bb6fe,
+                    0x59bc5817ca289a04,
+                    0xaf0ff0d4e4311f96,
+                    0x8f19ccd216c70b8f,
+                    0x5d9fbe6f77c8f346,
+                    0x352d07231e691d07,
+                    0x2de680c7f9da5a78,
+                    0x42f7c1017474d236,
+                    0x3d1bfea39d4fd678,
+                    0x00462ecffb2223b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3d53fb458a7fbbf5,
+                    0x7366dd736aa1069d,
+                    0x237cac829588ea6d,
+                    0xd8e548fcce4d63c4,
+                    0xaeec0b16ebbb039c,
+                    0x240d7597aeb4e0c4,
+                    0x12b66fc9956407d2,
+                    0xfb78298cb81ef4f1,
+                    0xd561b58b82ee6355,
+                    0x2474b5003b78efc0,
+                    0x53728481c7306af7,
+                    0x00fc9bbadab42ccc,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x60288c1f1ec7f7ba,
+                    0x9fcf4a26e75687ff,
+                    0x5b4423c9712ee383,
+                    0x2e63cb16d419ab52,
+                    0x0d221a4cb5fc173c,
+                    0x468995594a521f9a,
+                    0x33959c49e669e1a8,
+                    0x6610713bbb47cbd6,
+                    0x18d766d663311271,
+                    0x3be40cab86427bd9,
+                    0xc90a00ca8d3be82e,
+                    0x003d784b35153446,
+                ])),
+                Felt::new(BigInteger768([
+                    0x169dc94108cbe7ee,
+                    0xa07d2a1f2387b65e,
+                    0xee1d5ae1a1d2a821,
+                    0xcc8f051a744847f1,
+                    0x4fc3612b25c37726,
+                    0xae1d73e62709b20f,
+                    0xc3dbc44b8cba3fe2,
+                    0x326771ca2ad82900,
+                    0x4ed5b4356c6d00dc,
+                    0x8757ec41f8722fad,
+                    0xf609fde325d4bb1e,
+                    0x008b3333d67bf726,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x79babf98c8070969,
+                    0x0381f59172619959,
+                    0x6d2eef95e52389a5,
+                    0xdbe43eee120d5315,
+                    0xce5dccb21d481f7a,
+                    0xda723f5800401f21,
+                    0x92ef1c785363bbf2,
+                    0x5556b12456061af1,
+                    0x8a1ed8414ea8f67f,
+                    0x62bd81d8da8c1cf3,
+                    0x4bf6bcaa5f8eaabe,
+                    0x0020052dd0d13367,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf18a97f1e70c6c82,
+                    0xc135ec5c8a55ea24,
+                    0xbefd75438b5f35e0,
+                    0x792051a8b2e35928,
+                    0xe140cd89600033db,
+                    0xdac535b1c779ef22,
+                    0x489f1efb88a808ff,
+                    0x6ac4bdfa5692c434,
+                    0x2f273586cc972956,
+                    0xcf2dbca3b1a8c594,
+                    0xc0d9efdca1ba5b14,
+                    0x00ed9aabc96293fe,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x58ffe26a2bf0eaf7,
+                    0x0a6833466233c4ff,
+                    0xcaf6089ae370ec33,
+                    0x53c2e726fbad6869,
+                    0xce35ea64f5d302b3,
+                    0x4e8a7f89cfa5ee1f,
+                    0xeea48b02784d9e42,
+                    0x8635332d121f7aba,
+                    0xd8e5c1f6bcb33964,
+                    0xd6f1a8a7f252633e,
+                    0x3422bbf3f2cda0b0,
+                    0x0102fad55e1c0b16,
+                ])),
+                Felt::new(BigInteger768([
+                    0x936f041c69b3bcc7,
+                    0x4a25e71b6961ae47,
+                    0x1025b51c52c1de9a,
+                    0x739fddabd1736d4a,
+                    0xcd0debef74c50ce2,
+                    0xf6b304879536f749,
+                    0x18c845c54b96e7eb,
+                    0x458dd53182eefea3,
+                    0xf0a0152b53a758ed,
+                    0x9e887533f50613b7,
+                    0xd732a8874cad2a09,
+                    0x00b60febf8e282bc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd12eabc9e5164c2c,
+                    0xbdf81004f0f5f821,
+                    0x31eb77d414425748,
+                    0xcc9acb1796732217,
+                    0x7b9d1a080177d4cb,
+                    0xddf33d798257d529,
+                    0x2f90045fe199a37b,
+                    0x79ed689b99a23917,
+                    0x90d5a4ebb5e0e700,
+                    0x8ddc427a5a9d435c,
+                    0xb285a0616325317a,
+                    0x00b3dea3cf9ea4a4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2b3ad5d776bc8a53,
+                    0x7423fdaf12f9f2cd,
+                    0x8445903b597235fe,
+                    0x1e68afe91f54feca,
+                    0x117b7b4876d6c292,
+                    0x6c8a4e4a4ec39a62,
+                    0xf0df98da5eab5f4f,
+                    0xf9e97a6566820ecb,
+                    0x0fbf8efa4680d9d8,
+                    0xc1814ef21ca796cd,
+                    0xd7062d4af79d2dee,
+                    0x005776c3a3509615,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4103575e9d5d86f6,
+                    0xbbcbe049acfa4416,
+                    0x775ffb6cd00cea4e,
+                    0x9ae09d5899357af4,
+                    0x558df149cac2fd9f,
+                    0x29c98f9b6784dbbd,
+                    0xacecfde707f6ad6a,
+                    0xb1bf2785c9bed398,
+                    0xa4d6b73c44327fea,
+                    0xee031a69359102b3,
+                    0xc8fd3a9426b70fb5,
+                    0x00c4bdff6f51b376,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff35c089adda850e,
+                    0x04d081b440b8a206,
+                    0x53cb728a3436ce9c,
+                    0x7ce795ef65989912,
+                    0xf4a567684fb5bc63,
+                    0xfad4aa3ff7fccb65,
+                    0x313b24c01304c5de,
+                    0x4a49850b2dbeab48,
+                    0x40c9d17e45e128f3,
+                    0xb77aab646e553bd3,
+                    0x86e1ac9647ab7dad,
+                    0x00b010a9134a9caa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xdf2226bb1ed93b08,
+                    0xa9174c18fbf6a2a0,
+                    0x2c8296abe3434388,
+                    0x94c574712571e0aa,
+                    0x649cd6752a4c64c6,
+                    0xf3dae74c122ac1c4,
+                    0x739f21cceae6c83f,
+                    0x929ae52e4c7c4116,
+                    0xc98526f70661ab0f,
+                    0xbe7f7837c82f2199,
+                    0xbcb8759137213443,
+                    0x0081693ff1a8197a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b94c776f2bce3a5,
+                    0x81d170a1c38e404a,
+                    0x156802f2c3ea5bd8,
+                    0x424877e396aa7922,
+                    0xf4e381ea796fc0a5,
+                    0x49708383d34b4b31,
+                    0xf7ebee0e2ab1d750,
+                    0x98beb0dcad796d57,
+                    0x9b0db647495d50bb,
+                    0x67c166f5736fb9a9,
+                    0x28c3bf14da424816,
+                    0x0082024cb27b09de,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1571fffffffd9c9e,
+                    0xdd2780a35ffdc000,
+                    0x02f22ea2a18db21f,
+                    0xab75e537f40ecccf,
+                    0x2eb4245a1ffb990c,
+                    0x3df63d3e886eb6ab,
+                    0x40b581c029adfa18,
+                    0x4022e1be7966bbf9,
+                    0x162d4490aaf12c2b,
+                    0xa2709b890183c465,
+                    0x64c0d06a1801521d,
+                    0x0076ed55ba43bc6a,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x98c71ea1305fc70b,
+                    0xfa385ffcbe65f438,
+                    0x2171e0ddc9e38f8e,
+                    0x2ee0be896642923e,
+                    0x3acc08c68d97065b,
+                    0x1c3ab438856b8628,
+                    0xb77e69e1da2a3e69,
+                    0x5a63ae972b7334be,
+                    0x64005951bb8355e8,
+                    0x916dccc4f55594ab,
+                    0xe3963350d3ea5f2c,
+                    0x0030f68de6895489,
+                ])),
+                Felt::new(BigInteger768([
+                    0x482c0683698b7604,
+                    0x94edea18a0539ecf,
+                    0x31011c9d3599c73f,
+                    0x2a50822d40cd6c6e,
+                    0xc55b72b840f183dd,
+                    0xe692dc5731e0be5f,
+                    0x32d8980f410ebcb4,
+                    0xe72ecef881be927e,
+                    0x16d666d8e373acac,
+                    0xaa3385cbe31d5904,
+                    0xbd366484cda97977,
+                    0x00ed204fa38ea03a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6832ef7c961fe1e2,
+                    0x9f5c8fe2170d6d20,
+                    0xd51ce16e10f1512e,
+                    0x3583cb7d216c5c6e,
+                    0x1f0293e8695c8803,
+                    0x0972e1ce7c39ffd7,
+                    0x205e9f703effaaee,
+                    0xba6486e01da6ba41,
+                    0x95eacd5f674f8aea,
+                    0x47f2e2b5bc0c6193,
+                    0xfc22d3239e0861a9,
+                    0x00d8526068128d59,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd8b676eb134c2f30,
+                    0x32cc8f4fd870c35f,
+                    0x3d1d46c1d7608fce,
+                    0xb2e5bb1e48ecc016,
+                    0x3b8c4da14ae14584,
+                    0xe097677499f9f3b0,
+                    0x81bee7ddbb3f18d2,
+                    0xfe967f48c6d66684,
+                    0xb5f7e4669a26c2a8,
+                    0xecc7513a261efbaa,
+                    0x161003a3d3d51fe9,
+                    0x00586f22a280129d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x96a3eaa2ff58636f,
+                    0xd19184ac54f72088,
+                    0xbf278175b31360f7,
+                    0x09c074f9f1c4f10d,
+                    0x84981a8af7851e1b,
+                    0x2edcf6bed41fe192,
+                    0x1897a08ec4132811,
+                    0x3059911e4cf3a5cf,
+                    0x1521006e62f26ebc,
+                    0xa1b6623ad8bbfd9e,
+                    0x3f787a828231f646,
+                    0x00294a6358d9747a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc0b6d962686483a5,
+                    0xed48f074134fef58,
+                    0x8e74b807b8e8a089,
+                    0x8720c79fb4fd4f65,
+                    0xd63e210563cf4918,
+                    0x546cf2053d76ba6e,
+                    0x49f786e2d3bd380e,
+                    0xa640f76e1cd64a41,
+                    0x1ae21608198c3665,
+                    0xe1f539a9a67e0ef4,
+                    0x56239d8c51111696,
+                    0x0108a4b2aa956bb1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x33075778d28f6047,
+                    0xbfaeccfaa6e9dd39,
+                    0x24699f9bdc361f0d,
+                    0x70cb14277eb576b4,
+                    0x2cb73cbc7b395ac1,
+                    0x307e11210ce50c5d,
+                    0x0d807b15793c3f16,
+                    0xfd44b72d81216d96,
+                    0x5dd24cf0c25adc70,
+                    0x57b8c7f4121b2a6e,
+                    0x8f0a31b74d648e19,
+                    0x003fe4061abc02c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x914984c91bdb4ae1,
+                    0xf38197a460cdad3f,
+                    0xcd18cf7311de7419,
+                    0xfffed8381cbfec32,
+                    0x6ae9f4c16d497814,
+                    0xcd86708c688db31c,
+                    0x0be08f055123dd7b,
+                    0xf472e8c068c4e9f8,
+                    0xcb6428dbcb3692ba,
+                    0x70f2deda40ddeba9,
+                    0xf51a90b992664a21,
+                    0x00d73ecfd8c89ba0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4ad1d871f9129087,
+                    0xdedba549be6b87a1,
+                    0x08e9e7d24d89e34e,
+                    0xfc0eb2750dff0421,
+                    0xccfbec3df6427737,
+                    0x98e91b2e448474f8,
+                    0x0b948c68084c1623,
+                    0xd5d68b633fb74110,
+                    0xd3e7e4494f6d138f,
+                    0xa3d258c7906a33d1,
+                    0x0538ca80b1130bd2,
+                    0x0101f72c9a631ec1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xab9f716d9fffa506,
+                    0xf5654f76dd8fb043,
+                    0x398550d0f9694cc9,
+                    0x43c2cd54d4c74f64,
+                    0xaae3982b5462ac76,
+                    0x1f934ec25b11ba36,
+                    0x40c6c791d834ac1d,
+                    0x14ff4f5fe2235b36,
+                    0x4394ae31e3abd390,
+                    0x8cd32c1e8b871666,
+                    0xee43af179fdb96d5,
+                    0x006e2eb851093e16,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe1aeb5a9045301c7,
+                    0xc628eef4131322b2,
+                    0x4145a3e280275701,
+                    0x80b54d75fc5f29f6,
+                    0xdc87066da93fe6e2,
+                    0x00322566a5c75a97,
+                    0x5fa842ea1a9653dc,
+                    0x539ca0aec1ea32ad,
+                    0xad1e1d9618948e45,
+                    0xd4dc2db82d3d4e64,
+                    0x3cb82a7ae6aac531,
+                    0x006285b45b1a5f2c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a5532c8fb62e6a8,
+                    0x2792102179b4852d,
+                    0x81e65208d94865a4,
+                    0xab11fc0d39012416,
+                    0x3c14bae958038f3b,
+                    0xc34b05640be002d1,
+                    0xb36db8e33a2a5c03,
+                    0xcf7c4c014bc8e999,
+                    0xa2c77b83f9feaf04,
+                    0x5853a9fb4c190fea,
+                    0xd0ac4acaa29cd33b,
+                    0x002425906d2bfa2b,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
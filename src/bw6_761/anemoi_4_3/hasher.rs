@@ -0,0 +1,1644 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger768, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![Felt::new(BigInteger768([
+            0x2ffdaf9c3c7488fb,
+            0x59ac771fe736226e,
+            0xe033e559c19430cc,
+            0xfca6b5d0ff8c5cad,
+            0xb57a9401f5c6ae9d,
+            0xba4fe3bdec1b3382,
+            0xf6c9f34e57aee5ff,
+            0xa67fde212c197fb2,
+            0xae69766b7110fcfe,
+            0xb4e76d9b385352b5,
+            0x2ee7b024d935dee8,
+            0x00a18acfcc9f9184,
+        ]))],
+        vec![
+            Felt::new(BigInteger768([
+                0x28bc57d2e33225ab,
+                0xc818b6d784a3d15e,
+                0xe5c02f20ff5becdb,
+                0xf321862d37eef1ac,
+                0x6d32715b51d2d33b,
+                0x0e6bcd76b83b9db2,
+                0xf494db2a04ecb547,
+                0xdefef94f26713c26,
+                0x86d3105b19d23244,
+                0x4985eabcd0f93e27,
+                0xe4b0cbe556b84876,
+                0x00f88262f3d5f8fb,
+            ])),
+            Felt::new(BigInteger768([
+                0xfca5da5e3c69117d,
+                0xfd0c258227ef403e,
+                0x44dc8ffa43db4fd4,
+                0xcc7a3d970db9ee70,
+                0xb1d63eb0bc175b04,
+                0x2a2ed2fd6df49052,
+                0x5649eaf58c58c39f,
+                0x7e384bab1841b110,
+                0x853342de1a6de1a8,
+                0x0b00a7eac36423e2,
+                0x9650e8276ed299ac,
+                0x00862ebf8b45bf24,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xb9e17d3ffd431ed7,
+                0x8b7a41a0f8507546,
+                0xb2c28004d77be128,
+                0x404e7715edefab80,
+                0x6c5b75b984cc943e,
+                0x1d1a0d329fb336c2,
+                0xed6437271114c0e7,
+                0x81fc6e0516a6f74f,
+                0x35ea49c25279b0df,
+                0x043cd1ee856d31b4,
+                0xa87abdf233423db5,
+                0x00367218086257b3,
+            ])),
+            Felt::new(BigInteger768([
+                0xb227aa38c2b7c300,
+                0x9544cc8d4312482e,
+                0x07aea2166c90150c,
+                0xcf1945b9033034e6,
+                0x4bc12fc4eeecd4dc,
+                0x2914c587792726d5,
+                0x65f7793f3b3bc6de,
+                0xcbec3347f655ca5d,
+                0xfff1cae8f39a22ad,
+                0xe1fc7b56d4851294,
+                0x050404568816b5f7,
+                0x00c16e9f7d16c9a4,
+            ])),
+            Felt::new(BigInteger768([
+                0x170b6ea5e3d3a046,
+                0xe5696f3ee80938e9,
+                0x13ee69538239d8cb,
+                0x9aa2565596a27c56,
+                0x21ad047741c7caa2,
+                0x69b5d049d90efa65,
+                0x757cf55efdee9988,
+                0xdadb323c118aac7b,
+                0xb2b39917a6a9b0f7,
+                0xefac23aff70ca07b,
+                0x7b11b78cd4151d8b,
+                0x00f8c9c3e5fbcbd4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xc84e420126317757,
+                0xf6ef37bf220856d1,
+                0xe9aa8f028868b9e2,
+                0xea9368482adf15ee,
+                0x546f20b91a23a562,
+                0xda85c0bade87621e,
+                0x40540ff14217542a,
+                0xca46eef5a6155cdb,
+                0x64c34ede12e726c8,
+                0xadfe183a86f08941,
+                0x7af844c52b6bfeeb,
+                0x00fef3cc03a46eb2,
+            ])),
+            Felt::new(BigInteger768([
+                0x58ada60d48410fd0,
+                0x75a5078793ac5ee3,
+                0x2052384803727321,
+                0x284d76ce644c3857,
+                0x510527391f6d7c0c,
+                0xd5ba7983eed68360,
+                0x290cfff98d44a38f,
+                0x0cfb346a429bc5f7,
+                0xdc1ef49b6b782591,
+                0x3ee5fa3f2c900664,
+                0xa9e3890431ac46c5,
+                0x009e10f5cec7f5fb,
+            ])),
+            Felt::new(BigInteger768([
+                0x932f6dd4cc57b50a,
+                0x0d32c9c21a5783df,
+                0xc74010c3508bfb93,
+                0x6549b1ff7ee41a15,
+                0x2c7e2c27b300bb7f,
+                0x0ae7398b7e355ad6,
+                0x4afa1a3662ded7fa,
+                0x2f755376f0f9937f,
+                0xbfca835ce918dcc4,
+                0x5b2989304b425b0e,
+                0x85eb5f3965b95562,
+                0x00755a6c2fcb86c7,
+            ])),
+            Felt::new(BigInteger768([
+                0x2fb6ed14fda604e0,
+                0xbe454624222e02b7,
+                0x5f023484a61134c3,
+                0x714393431c641b80,
+                0x2b12e844f6dfbe33,
+                0x633a7ca57f1557da,
+                0x3216d8637d96e481,
+                0x7d6f98d13d34b7b0,
+                0x0c7a5242f532f63c,
+                0x3c2ec92b0ae21f88,
+                0xa401cf061bf917d5,
+                0x0114dab22115004b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xd2a6d55c42ca697a,
+                0x5debb268ea28df11,
+                0x8a38a8acd4a94da6,
+                0x54f8fbb682d950f4,
+                0x397b44c93c168105,
+                0x680d34c895dba264,
+                0xfaf7aae91ac8cb50,
+                0xc02ac75cecdf40a7,
+                0x8b451b29ccc568dc,
+                0xdc42928e24cd35cd,
+                0xe00a6b4563f6095d,
+                0x004623a5f32a4dbc,
+            ])),
+            Felt::new(BigInteger768([
+                0x1099bca3bd614ebb,
+                0x4a952da8207e588f,
+                0x263c6557be3b97ad,
+                0xba669ecbb5103b5c,
+                0x948634ed026ad915,
+                0xbedcf1122023bbe7,
+                0x46537f4cf9ab8aab,
+                0x7a8bc31340e9d9e6,
+                0xe54c12ae270d0bd0,
+                0x670aa7c5064f0fd5,
+                0x41d6615a66a8167c,
+                0x0047ed71ef7f3290,
+            ])),
+            Felt::new(BigInteger768([
+                0x3bfaa79d072aba6d,
+                0x33249418a355984f,
+                0x28f40d1cc6d509f0,
+                0x4ad2d6e638232741,
+                0xcf1c57408421e7c6,
+                0x0b352c7841e8269e,
+                0x3aad563781ecf01c,
+                0x7f38ba80021652b5,
+                0xd72f92a08201c8f8,
+                0xd0d06668440b5c3e,
+                0xce5f11c52bee0c95,
+                0x0096e74a78cbd96c,
+            ])),
+            Felt::new(BigInteger768([
+                0xade09e9f67bae0e3,
+                0x54d4ff60086e230a,
+                0x65672dd58404fac1,
+                0x772b92de359b0eae,
+                0x8a5401380294b884,
+                0xb527471ed95f1b2b,
+                0xaabc1b06bfaf040a,
+                0x6aa01ea3ddd83ffd,
+                0xaa0d50c8edc44ecb,
+                0x4cb41da7911175ff,
+                0x54f12cbf04af01b0,
+                0x0085e4ee8163838d,
+            ])),
+            Felt::new(BigInteger768([
+                0x869573b5defb33a3,
+                0x653bdb9f0aa1e642,
+                0x9944f1ee7483805a,
+                0x305bb9c56a186f65,
+                0xed8b573f94495dbc,
+                0xb17976a57c48be6a,
+                0x0340d287bd996a65,
+                0x6ef2ec323abc57d5,
+                0xf2ba27195cb1dfac,
+                0x38d3035d20050acd,
+                0x420928801072a671,
+                0x009dd13ae6368b53,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x87fde9af0f093f30,
+                0x63119cde8910e42c,
+                0x9bc4640295a38ebf,
+                0x6f09e723640c745c,
+                0xf463686929b3cda8,
+                0x8e6a7d476ebb41b5,
+                0x368bda4467c39d80,
+                0x0db9510220f2dd75,
+                0x4a686ab25fea4c11,
+                0x295536c72b0caeab,
+                0xb20673a20d337903,
+                0x000e81a7eeaab767,
+            ])),
+            Felt::new(BigInteger768([
+                0x9120ba2ba4331e23,
+                0xb8ba9cff472d92de,
+                0x07494ea013b334a3,
+                0x1b667d0d3c2ed604,
+                0x8918556ee88ebf52,
+                0xa935c95cdbc99ce8,
+                0xfa7a13a949c66157,
+                0x324638303a43b27a,
+                0x33c746bc480e73f7,
+                0x71efe29ec540d744,
+                0x693723939864e3be,
+                0x00ea1a1dcdd6683e,
+            ])),
+            Felt::new(BigInteger768([
+                0x05f3b4dd173db8ae,
+                0x63c171706041b774,
+                0x02abfb460cb7bb57,
+                0xefbdf89647f885a9,
+                0xa9cbd9e70ede8632,
+                0x057877539c99f9f1,
+                0x3a5bb7c0b1c7ba84,
+                0x8824f38b03b1b005,
+                0xe868dc10ec6dbd44,
+                0xf64ea55f6e56cbac,
+                0xb483d140f4a37236,
+                0x011b11f4e27ccf8c,
+            ])),
+            Felt::new(BigInteger768([
+                0x187defe3ce46f601,
+                0x123d74958a2b437e,
+                0x795a06f3ff35bab2,
+                0x2f230052d8031983,
+                0xe4d6bee1b8f46367,
+                0x87543fe4db800f37,
+                0xec0e564b43ee1f72,
+                0x1cc2e8c0dbda5ed7,
+                0x8d345a9d7f1682e6,
+                0x167d2648ade8d54e,
+                0x2a34d051d5062e21,
+                0x007ffa742e0d7983,
+            ])),
+            Felt::new(BigInteger768([
+                0xeeb30d23c4cb3f4e,
+                0xc1926d638def1f08,
+                0x728305a65ededb2f,
+                0x6cf1083db3a4b8de,
+                0x11319fd44c653e3d,
+                0xb7027c21164fea95,
+                0x99a1079dbdb8355f,
+                0x2f77d94d2a97084d,
+                0xf72cae77bb8b9ecb,
+                0x3197f0fdcbfb5104,
+                0xd7462f5912c9ae63,
+                0x0090221fb5e21d97,
+            ])),
+            Felt::new(BigInteger768([
+                0x2d548745e357f241,
+                0x84c50f1c7343a87d,
+                0x9f6ea79aa2d10570,
+                0x616783162a4fe1bc,
+                0x9d55f7fa7edcd634,
+                0x178e016488b4d16c,
+                0x8a1c524be1c96058,
+                0x21854563a737dce3,
+                0x00cf5582a97cab39,
+                0x6a97da65d5f6ea9e,
+                0x73438a6ed7ebb385,
+                0x00b432c452772d94,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger768([
+            0xe0b0f066b3e53d94,
+            0x96bfc8a849ca317b,
+            0xde833cc121d4af85,
+            0xae57eb6c7856c713,
+            0xa491ad41d6c3be4c,
+            0x1c8176990fa188f0,
+            0x874eaca01487b47c,
+            0xf16a849702509675,
+            0xc8be8e109782a3ec,
+            0xfa91c26c53022c4f,
+            0xccf5194c0b979980,
+            0x00f53f92c5842e42,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xb1d523498e989980,
+            0x7a2069f40de23c8e,
+            0x1835a74e85ec91ae,
+            0x964bfd522ccc2cd0,
+            0xc0452ce4366c776d,
+            0x6bff85a64450b332,
+            0x2d0e765c757a00f5,
+            0x6aaeffa607651a70,
+            0xe66a65ae9dc6ed90,
+            0x71ba9ccdc774dfa8,
+            0x6f6222c2cc353fe5,
+            0x004f5c83f83615c6,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x3f967451bac52797,
+            0x106f455afc83154d,
+            0x038ad9cef7d28660,
+            0xf521d9095b75e733,
+            0xd49ec7924a07b48d,
+            0x35ecf12375b049b3,
+            0xb80abdfaedbe467b,
+            0x17c83bc60e8a29bd,
+            0xacb0edf13fd1c39a,
+            0xcabc06fd3e983fdc,
+            0x424c7782afd137ee,
+            0x00aab334eaec049b,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x4230da3b74ba0ea3,
+            0xfad05501ad1f3c0e,
+            0xcb463267730a872c,
+            0xe57552587ffc5e34,
+            0x789cace1cec52a96,
+            0xb06c9851f9e0d72a,
+            0x65096752982a1a5e,
+            0xc7fba76d54e34109,
+            0x2488e8b0331d772f,
+            0x1861cb03bd6528e4,
+            0x88703aa4f27208fd,
+            0x009dc7d40e4fa823,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x43a369a758d0f15a,
+            0xedcb94dcc7757e65,
+            0x1d691a1f442bfec1,
+            0xe79581058f747a28,
+            0xe0bf7e7396b74189,
+            0x401234c5f4b3bee8,
+            0x1d058d8e07b28c32,
+            0x95ebb350ad7660f2,
+            0x11837c59bdf7ae09,
+            0xe450830e62f10bf1,
+            0x1e59ec91d2d49b16,
+            0x009c19f5faf62503,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x6b8154993cc1b82f,
+            0x1bca5e1f37e5e126,
+            0x4e296079e32a1f83,
+            0x651dade7e9ec136a,
+            0xb64228ba5d92b95e,
+            0x87c21abb7255a982,
+            0x3d171c17d973e2f5,
+            0x9d62c9969d1ca615,
+            0x302f687e745cf549,
+            0x29506ea333dcf052,
+            0x25978d23486824ef,
+            0x008954d805370a6c,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xa25c26bfae329a36,
+            0x49ce4570b5526a64,
+            0x28a4d3ef090d0ff5,
+            0x6f00c69f318484ab,
+            0x2fc19a3f0a4e940b,
+            0xc22a76137ec12497,
+            0xe0789a3bba4fceb3,
+            0x96ec4b4792b5c17d,
+            0xce5eee9e84fd4f2a,
+            0xc6b1c21a66ab13ab,
+            0x62f6bfc0e33b17c8,
+            0x00f3309c4e598c0f,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x7932d91a5c2b6fc1,
+            0xe65edb217176b22f,
+            0x0e11a6613dd3930b,
+            0x6c5985fb53aad0f9,
+            0x3dd0ab53822eda20,
+            0x0fda9973cfbe1791,
+            0xe1b0af348d915980,
+            0xb987a21562b0bbf2,
+            0xd6b0313325ad5d08,
+            0xc943af149655bac3,
+            0x3944c7341091c760,
+            0x0112c57e2c72c8e9,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xaa773bb4144939e9,
+            0xa13b02a36190ef5c,
+            0xceeb1dd02e7144c5,
+            0xa14fe9f25384aec5,
+            0x44387bb86cb03a3c,
+            0xb544bae4cf0b5f55,
+            0x04acddf6b4bead31,
+            0xde2835c6cf56495e,
+            0x0d498de4fc9d7c3f,
+            0x1306968c419b5b8d,
+            0xd2c84a5e565a8b48,
+            0x007336214d684159,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xbbcbca9a6f618c1e,
+            0x2152e2878580cf21,
+            0xd09dc30b07f97a35,
+            0xb2488b31df9862fd,
+            0xbc9850217af8e146,
+            0xbbf129f43c1cde0f,
+            0x565cbc190f7599a2,
+            0x22480104b31c6f8a,
+            0x0edbeee5f2613557,
+            0x7d19e19fe519e9bf,
+            0x56a1f5cd624bec4e,
+            0x00fa39b7975cc46d,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger768([
+                0x03b3d43285601989,
+                0xe22a5d89d896a501,
+                0x49ae45c1261ade13,
+                0x74d7575272d09521,
+                0xb4c3e3f17845841e,
+                0xaab93e1223a9ceab,
+                0x8030b636fc105926,
+                0x4e2ac00ad327b3c7,
+                0x2ff7969efff3a7aa,
+                0x97fa87e3a1461a50,
+                0x884bedb22b4f0631,
+                0x00f9ab6029bace37,
+            ])),
+            Felt::new(BigInteger768([
+                0x44b519323941564c,
+                0x45cfdf93bcd04049,
+                0x301788c629702d7e,
+                0x601ce534487edf28,
+                0x429fb08ec33fad6e,
+                0x69eb4dd35c99fdf8,
+                0x2611fe1ca700f7da,
+                0x9d479e364917c8cb,
+                0x7da10bffcfc8e656,
+                0xa672a957a3bff72d,
+                0x1f93222062b9d8ee,
+                0x00d421c37a92d56d,
+            ])),
+            Felt::new(BigInteger768([
+                0x14a5a4fc45cf405e,
+                0xf2424257d0d0ded2,
+                0x7afeb6fa6784a887,
+                0x62d1e6a01e3b81e6,
+                0xbdc06d4a8df1dd82,
+                0xa1607b0f2f6feea5,
+                0xcf366edae80419da,
+                0x9931bf487aa8e70f,
+                0x3d3cbd8d9a547059,
+                0x982e117e84c8695b,
+                0x4ddc93275764006b,
+                0x00c322b833689231,
+            ])),
+            Felt::new(BigInteger768([
+                0x4b6fff36b656705f,
+                0xfee84c02ea60d62f,
+                0x2002c3c0acc6508c,
+                0x828cc265c4aca55a,
+                0x3b0d5c614c806947,
+                0x31d0dbb1c7986fab,
+                0xdc2d877b7f980330,
+                0xca81dea55d423bb6,
+                0x02a4304ac2efaa6f,
+                0x7afbf9788f3cee56,
+                0x3413844db9073008,
+                0x00ac3b5fed346a7e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x35ce8febee1cf5c4,
+                0x441b3d556cd6b851,
+                0x7fff57914c2adc58,
+                0x2af7d5804b4ab4ca,
+                0x512fd87bc09da12e,
+                0xbbaba061aafb5408,
+                0x4881c2f4574985d4,
+                0x3025343dabe984aa,
+                0x4358dceb6051d778,
+                0x28e9f03199d66c84,
+                0xc70ade170240c895,
+                0x00c23e56eb00c296,
+            ])),
+            Felt::new(BigInteger768([
+                0x2b4bfd6f7d7d2bee,
+                0x06a0d8a7e490fbdb,
+                0x44216ff664704ff9,
+                0x51bcea41ca3d5232,
+                0x05dee2a37c2e554a,
+                0xbba51773e523a49c,
+                0x6874f15e47850512,
+                0xae882fec41e2dab0,
+                0xccaf1a61c633ec16,
+                0x7ae617c56fd3a74c,
+                0xc91c119e88f3d550,
+                0x00842610742a41b4,
+            ])),
+            Felt::new(BigInteger768([
+                0x3f2318a73c3dcbb6,
+                0x0e13f38ce82c431a,
+                0x2858d0c492d7b670,
+                0xb68f079a1d3fa08d,
+                0xc7a14219a2154267,
+                0xd0a28651c0fc725c,
+                0x5302d0d96a1416b7,
+                0x14af422b04e66b52,
+                0x411ae5f507482a62,
+                0xeb24b07a66f22147,
+                0xb01d979f469eda07,
+                0x00157de863407ffb,
+            ])),
+            Felt::new(BigInteger768([
+                0xa0bb68671e607d4d,
+                0xf97a5db07b92be92,
+                0xc3caaa8601ce486b,
+                0x4085338f8c445f20,
+                0x170d89e591b57ca8,
+                0xc920b9d0966c527c,
+                0x9b0204294626fbbf,
+                0x3f23a5a2e6454b6a,
+                0x64b42fbb665f8cc0,
+                0x9ee80463dafaff60,
+                0xa9bb0dede8d2b862,
+                0x00cc67c92a4e0959,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xd5e386e04b2167c7,
+                0xd71a1a21d2f18b37,
+                0xeaf186166ddc8432,
+                0x00d7eee8c44096c7,
+                0x35c118c5cd05cc91,
+                0xa3ecc7462c9756e0,
+                0x3204f0d3f2bed419,
+                0x55804969f0728b6a,
+                0xa2563d05218bdf42,
+                0x19280326829f25ed,
+                0x8aae311eedbc1966,
+                0x00708347153e4319,
+            ])),
+            Felt::new(BigInteger768([
+                0xe8db887709aec038,
+                0xbfb13c6763ea882a,
+                0xc51789907711d606,
+                0x706002ec2d68eb86,
+                0xd6fc45e4b0191abc,
+                0x8bba72cb3f6a425a,
+                0x5e03fe1a5519b534,
+                0xae05f62faf38dfe2,
+                0x6dd306cffdf6b681,
+                0x8f1ae42bdef9abad,
+                0xc98e35cf352e0f19,
+                0x00e074151cf0f130,
+            ])),
+            Felt::new(BigInteger768([
+                0x33d407830370bce6,
+                0x06d8645af0cfcc99,
+                0xfb4c47fa046d3e37,
+                0x4683f03422e5d729,
+                0x23ea297ef8373299,
+                0x5635e6c2b50557ea,
+                0xee0f6f5b4a8b296d,
+                0x672098a1ad04376a,
+                0xd43b726d6765fc1d,
+                0x40f884d80dc18b94,
+                0x150707c0cebd4485,
+                0x00938250536ec0c2,
+            ])),
+            Felt::new(BigInteger768([
+                0x900a46674bc0a8fc,
+                0xb8dbadcedbed527d,
+                0xb4e5f4e1ce8e1a53,
+                0xb9f55918e23f3c90,
+                0xdedd3d65f5aed9d1,
+                0xc8db43861e2c54d3,
+                0xab0d62c1bbdb944d,
+                0xa5dd7d896a04aa82,
+                0xb6591cb9b2b8eabb,
+                0x5819997ccc7c01c8,
+                0x6b4cabd1bfa759dc,
+                0x00a5b17dca150d47,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xb79da1d2d42e7932,
+                0xf5dd19b134d4b0e7,
+                0xcf7e4eb9b13521f3,
+                0x78d7e66ba6174ec0,
+                0x5bad2b1ecbb1c696,
+                0x72ea74b6ce4fd72d,
+                0x628f69d3c15c7c4d,
+                0x86d3fba5785127ff,
+                0x8adc100c60adc145,
+                0x07ca5754c659237e,
+                0xcce3d29fd809c927,
+                0x004e51278fec6528,
+            ])),
+            Felt::new(BigInteger768([
+                0xf4c9d525e4c1c682,
+                0xd04c1b4fe821915a,
+                0xd5ad991babc1015b,
+                0x5f073786dc5353ae,
+                0xc4d1012585730a51,
+                0x35f745f1f1a17936,
+                0xab558a9121fb2606,
+                0x318c88b5530ec588,
+                0xd6848e7d5296ea9b,
+                0x6086ab8d821b4cfc,
+                0x83e496f250aab1f1,
+                0x003a08da1a5754cf,
+            ])),
+            Felt::new(BigInteger768([
+                0xe0527c18e40554cb,
+                0x1a89583ea3b1c41e,
+                0x84a69fcbb71dae81,
+                0x99e163c3c19dfdfb,
+                0x2d3dde743d0e55d6,
+                0xdf11ff0cf6088fa7,
+                0xef378f330f959d05,
+                0xb64ed8ac1b14cccd,
+                0xdd021c56c6c94b85,
+                0xa019f1be6064345f,
+                0xb9d68226c7ac71b7,
+                0x003939c4e94ad4a6,
+            ])),
+            Felt::new(BigInteger768([
+                0x49bdd4fcb13a9036,
+                0xf85ef9e6ac9a7061,
+                0x599bd0b4c2b0d625,
+                0x29e6ec24a58849cb,
+                0x192fa48a95309e36,
+                0x64c67a8485a303b9,
+                0xd2c5d5a104c887ba,
+                0xc6f8229a244e29aa,
+                0x18ef5eef43a82439,
+                0x7aa8f2f25a3d212e,
+                0x75f22bd77c2c4110,
+                0x00629f4fd520d841,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xa91defae65badfe5,
+                0xc9ae39c099646cde,
+                0x4765b3f5c59ab45d,
+                0x902055e3e6ee0b7b,
+                0x90c90ecb10a12774,
+                0x0f0267de3b8fb2b9,
+                0x0281a58a3070e663,
+                0x716ed5d2d9472865,
+                0xa5e69ecc81399adf,
+                0x2c27677e9b2361e2,
+                0xe98565b9849ca7f3,
+                0x00094b309a85293d,
+            ])),
+            Felt::new(BigInteger768([
+                0x9a5d91515373f466,
+                0x66f7729e2b02ec6a,
+                0x53923ed3c82d2e05,
+                0x30d5c11b9a292c3f,
+                0x90f32abcdd4a8398,
+                0xbbf4d0ad7edbbfa3,
+                0xc221c87a08b98059,
+                0x1325754d5a9f5676,
+                0x5345e12e6ad4a0f4,
+                0x4afe957f298f6258,
+                0x5305a36647c66f37,
+                0x0069f204bed54984,
+            ])),
+            Felt::new(BigInteger768([
+                0xc67f306f7f751aec,
+                0x48977ca64774b911,
+                0xb34320f9ea0bdf82,
+                0x53960bfa27c7f093,
+                0xae1f3e51d7b1a86c,
+                0x7443ab6977047324,
+                0x7d771c27e3febd6d,
+                0x6b2d9d06ee90c726,
+                0x0385c312e7168394,
+                0xd8f2a80c43d06ff1,
+                0xe12db92f5bcb4897,
+                0x00ecf6a94e3e761e,
+            ])),
+            Felt::new(BigInteger768([
+                0xddbd61286993725e,
+                0x7ceac435b039e8a8,
+                0x208b71a05f4a4496,
+                0xb7ed6c9863d4a218,
+                0x362a320e43213720,
+                0xa0a798cb3751f133,
+                0xd815cb997ac6e979,
+                0xe1ddb2a0b100f2f6,
+                0x3a02ff28f233cc8c,
+                0x5747a0b4e22854f4,
+                0xae122e9725d7475f,
+                0x005f5a4ae81057cd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x73b7124e2a2921de,
+                0x65febe9f36bb3aa8,
+                0xa98f045aff2d8d64,
+                0x20d48d90c5de4d3a,
+                0xfa2d4022cf467e3b,
+                0x4365af14f9766388,
+                0xc9d8b47aa19d219f,
+                0x2513f43189c497aa,
+                0x3fce70d851e23a48,
+                0x7d0b599d0de832f4,
+                0x3df870358e725e1a,
+                0x0118c5db33f2669f,
+            ])),
+            Felt::new(BigInteger768([
+                0x96fd0df61a9cd9c7,
+                0x17e1136c145aa7bf,
+                0xf0497bee144fb8bf,
+                0x5c80953ad75b9625,
+                0xa15b7de813e4ddfb,
+                0xea47bed7120a81a2,
+                0xd92ec1761d0aaf10,
+                0xe562e73cbb0479a6,
+                0xf0509d7019279c7d,
+                0x29059080aaa50c29,
+                0xea199dcec3a11086,
+                0x002a93d029be71bd,
+            ])),
+            Felt::new(BigInteger768([
+                0x8bef28f913b741ae,
+                0xeb1d114114c5d87a,
+                0x6b71350bb3ab07ce,
+                0x549a4e400c4dbc49,
+                0xdd94a1dc6ffffdeb,
+                0x60693951009ed922,
+                0x9b6889fa66e4cc95,
+                0x43f631f711c92364,
+                0x6a6b6c15fe2620a2,
+                0xb76270a8199d6a49,
+                0xe2eac6ca6ffa8b59,
+                0x00d4a7538da5ae1a,
+            ])),
+            Felt::new(BigInteger768([
+                0xc4a1aed3881ca30e,
+                0x1826de06b0d9b253,
+                0x6f3d03a33f9656cd,
+                0x52ac4b45d1d8c222,
+                0x0fb55826c85eb6b5,
+                0x4ae12970e5d02869,
+                0x6455a35021657f5c,
+                0xbb1859ebb13892b9,
+                0xb4c8c343f44c0306,
+                0x575530c5bb3a27b1,
+                0x87fc98ef1584a95b,
+                0x005b9e6985e6f895,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger768([
+                0xd0727d88fcaaef5c,
+                0x1a6be5d710bc5fde,
+                0x69372d6d8efc0b6c,
+                0xb17fc70c6733ed6f,
+                0x3f90d620d501fce0,
+                0x6c91bbf8b1925d03,
+                0x9cabd9e7f999683f,
+                0x145944c9a71f40d7,
+                0xfff138ed76d2631d,
+                0x350f872341525bf7,
+                0xa4f96b1ab0e9b593,
+                0x0008b6c409c5feb9,
+            ])),
+            Felt::new(BigInteger768([
+                0x15ab0a89781c1aeb,
+                0x6c3e95716af7e5b5,
+                0x2bab3d1927eb3e82,
+                0xeb9ab7c456bd1ace,
+                0x9d0ff9ac61e7621e,
+                0xd99ccf963e25bd15,
+                0x6a37e339f58341b8,
+                0x0ce40b58124134b0,
+                0x96ac9fe53691911e,
+                0x07e9560cb22b62aa,
+                0xeefb61784fdf5cd6,
+                0x000c6561023ec8eb,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xd18308505e950aca,
+                0x449c3f7a49ea2506,
+                0x051316863a8c682a,
+                0x47413320d7c09565,
+                0xe84ea263d4b0ce62,
+                0x69879c14a89a0168,
+                0x8aec937d3d488a58,
+                0xb1df0797f819919e,
+                0x7b5a21a9d4e291ef,
+                0xc1aafeb2eb3c9216,
+                0x1472ddd2c8e6630b,
+                0x006536672ce8db9f,
+            ])),
+            Felt::new(BigInteger768([
+                0xf5073cfb04e1d1d4,
+                0xb830890366b26ddd,
+                0xc3f9636e16018db6,
+                0xa5492c3feb8ea8d8,
+                0x13cb07e9ec9055be,
+                0x073bc44c92d84e33,
+                0xea55e5725acef962,
+                0x9eec4c8273a118e9,
+                0x3478fb2b743aefdd,
+                0xa671101294b6b3fb,
+                0x1cec0c3d6f34636e,
+                0x010a6700588d98d0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x6d4fa2b67a20155b,
+                0x902713384b2dbd64,
+                0xd70b6f66fb271463,
+                0xd39016f764b2cd6d,
+                0xc8850aaed141aedf,
+                0x6b5cd0bcf24d07d4,
+                0x61b3b1f03e298b06,
+                0x72e375056074bddb,
+                0x7e3811155263146f,
+                0xcebe17c346f92538,
+                0xe1310ce78e0931dd,
+                0x0071bd9b3972bb13,
+            ])),
+            Felt::new(BigInteger768([
+                0x63619dab860c333e,
+                0x8a0738ab6e41734d,
+                0x4b989fb8b05edccb,
+                0x3620366898dfe0f2,
+                0x047ccc184367e877,
+                0xbad9e925c24bd844,
+                0xff54e6f812724886,
+                0x7bed78259f162d3a,
+                0xa8b9faa6da1bc890,
+                0xacf1bd3f5dea2c2f,
+                0x02b4192ef9f9a6cd,
+                0x00c98456645587c8,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x32f681701d1f369b,
+                0x749c0a7428b1c7cc,
+                0xd7c2e8546697a2cf,
+                0xa3ac10f13191d00f,
+                0xde2920eb88655cab,
+                0x4281f6c20b07910d,
+                0xdf92dedccdbfcea7,
+                0x0d5a4c1330032d4d,
+                0x030ff08aa538c21f,
+                0x58d124fc31c626fd,
+                0xc5b8e92ecb3cb2c1,
+                0x00447da5677fba86,
+            ])),
+            Felt::new(BigInteger768([
+                0xb95894f0d235aef0,
+                0xdc8f711fb4509045,
+                0xe425a6e17ff61fac,
+                0xc766431ad731e1e0,
+                0x8b4e0877b1e2df6c,
+                0x71e7a7f4cefb17ec,
+                0x0fe554c5ac404304,
+                0x4070d293aa3163cc,
+                0xdb6ac137a76aaeb0,
+                0xc3614b61f438849b,
+                0x93c3a2a2a6333889,
+                0x001c03a0f083c8ba,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xb549267393ddc89e,
+                0xfcbf219ab3934d08,
+                0x92bdb8887cb574fc,
+                0x86a7f734446a45e4,
+                0x3c20b864cfa5bad5,
+                0x7b6d914b6f8adc46,
+                0x0469a9a027c44367,
+                0x4f213fa0ca6d9807,
+                0x5454d472242567ca,
+                0x41c731cad331f7b8,
+                0x1f8b8e041856322d,
+                0x00d7aafb6b85d9b3,
+            ])),
+            Felt::new(BigInteger768([
+                0xf8fe2eb8ee885382,
+                0x5edcfe9992543dc1,
+                0x30a5f00132b2e0e8,
+                0xff66d3ae0c6106e5,
+                0x08f0488d4701339f,
+                0xb1c807b53c0cbfff,
+                0xe4ed804f21216b16,
+                0x14ed29ee77aa05d2,
+                0x46adde5354c7c47e,
+                0x65ecb2879cfd7f04,
+                0x5d4701e9513435b7,
+                0x0058f98376bd4908,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xf7e6d908f00e2573,
+                0x6333ca079b38d9e8,
+                0xc2b0fcf286453b2a,
+                0xee0e557e729399c1,
+                0xe2421447b64e8cc2,
+                0x157aeb794fb8aba6,
+                0xa614ebd4e96875ae,
+                0x51e06a0e707e138b,
+                0xe1624abf2f0c300e,
+                0x62fbd610d4a3ab6a,
+                0x2fd908fc002afd3a,
+                0x008ffc559baed0a5,
+            ])),
+            Felt::new(BigInteger768([
+                0x71ec11219e9a6d58,
+                0x1753e0e0db8a3dfd,
+                0x54b32d3455ed9f1d,
+                0xaf55490f5702cf83,
+                0x45867be09854bc19,
+                0x05e4e9b4ffd4950e,
+                0xf0b76ad277e73eff,
+                0x8745628188a83f8d,
+                0xf3e45a5b1c53dab7,
+                0x9493e28794727bcd,
+                0xb8b72fe469051fed,
+                0x0098898d25ce6136,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x9de848a16a78507e,
+                0xcaec433b885b7dc6,
+                0x197323c0f84435a2,
+                0xf631f5bcf2db5c07,
+                0xcd785951f315adf9,
+                0x557bf272a749d526,
+                0xda3aa717f5fdc9a7,
+                0x883fb3f05efdaa6e,
+                0x1d3dffa046a986bc,
+                0x060873a2f451318d,
+                0x92c603d0d7968ece,
+                0x001e614c16c71076,
+            ])),
+            Felt::new(BigInteger768([
+                0x4212299e5dd678f0,
+                0xafe6e7ccc725aa20,
+                0x755c84aabb750a76,
+                0xba7b47c82edd7a04,
+                0x518c4498aec80a8e,
+                0xabae5f16704d1c4e,
+                0x15e2f56337be08a6,
+                0xc4bc17f722d00b54,
+                0x2e75df0cde66424a,
+                0xd890bb8741c487e8,
+                0xebf4f9fdf3d46638,
+                0x006d146afb24d32c,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x3337e4182869ecf4,
+                0x737b625b9c75ecd0,
+                0x30e3ff27add2585a,
+                0x343d7ff505eec56c,
+                0x3b284626a2434ba1,
+                0xb260e80400476f71,
+                0x97d39020f04d98f0,
+                0x98c59f9f5584d774,
+                0x41d9a4da58a2c14c,
+                0xdb700fcae40f49b9,
+                0xa82301d455bc5397,
+                0x0019a680fbcbff05,
+            ])),
+            Felt::new(BigInteger768([
+                0xa0a2b9f2356e00f0,
+                0x30646b54e6fbafec,
+                0x4394de0a9f2ea88b,
+                0x5629443ee52b7f08,
+                0x2123b2154703004b,
+                0xafda322a31745e4a,
+                0x82c5baea40d3ff71,
+                0xd6db0fc81ffdbc04,
+                0xb1ddfb9721b5f5ff,
+                0x26afaaa0fe930dd2,
+                0xa0b45ff90a84fd84,
+                0x009d04f2d8b2e306,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x01d9a7f4c2de9014,
+                0x3d36ca80b0a9f55a,
+                0x0754cacd78e1f077,
+                0x46a95d335d70c5d6,
+                0x8d07a8cf914e06ca,
+                0xb38f7376c98fa791,
+                0x167e2d84c08d2174,
+                0xb36a09facfcd73f3,
+                0xd8cb30013893226a,
+                0x454ed769158b984d,
+                0x6d56ad9ba8562e35,
+                0x01025b5accda5cfb,
+            ])),
+            Felt::new(BigInteger768([
+                0x56810f5a5fd63282,
+                0xc7d010b40ebc8737,
+                0x821bcb4706e55657,
+                0x3d97dee47ab4672b,
+                0xc358ddaa98f4aa27,
+                0x37f261fad75135fa,
+                0x37d3614f22a5525b,
+                0xc042b7907c730b58,
+                0xdd0e2d92bb54c6c7,
+                0x55398a8697785d90,
+                0xe79d410a86e22151,
+                0x00be5e16c43a09a9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x6ef208a9600803f5,
+                0xa4558cd2dea5937d,
+                0xf1ec6c7531659015,
+                0x5905afa753cee0ef,
+                0xbeb5f7bc43e04383,
+                0x34b9e2345c9265d0,
+                0x064a16ddc2579b07,
+                0x533fb357f9787baf,
+                0x5daa6b47fc6acf6a,
+                0x3ff5cd2177a6a9b0,
+                0xce451b3909f35345,
+                0x008fe79d3b8dca45,
+            ])),
+            Felt::new(BigInteger768([
+                0x222d005630c2a5d1,
+                0x1b7fdc6fbc1f94fb,
+                0xe5b1e254be225f0c,
+                0xf9775b0adbfd95c8,
+                0xff4e698f9a2b8108,
+                0x58aea106fdb754cb,
+                0xcfb40eec83b05893,
+                0x89860c5461d93880,
+                0x0d918ccbc2fef313,
+                0x505f23b2cdc25a47,
+                0x737ad7daab33a34e,
+                0x00fcb0a1b102be2d,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger768([
+                0xa55cb241735e9269,
+                0x538a0d2ac47b4921,
+                0x8fb77916c3e597a4,
+                0xb5b999be884d1785,
+                0xbc6825b78286c8f9,
+                0x6e86a29007e6e41a,
+                0x25f5845d0105b08b,
+                0x3e265af99b04a323,
+                0xffb07a6fe4a6d747,
+                0x84d470b292e913aa,
+                0xb161e315ac5da404,
+                0x00a9abc7a3e4e5c9,
+            ])),
+            Felt::new(BigInteger768([
+                0x1fb45d373803f941,
+                0x69cc8ca088b501dd,
+                0x854e9726c875de6b,
+                0xbebda4d747650390,
+                0xf8f3d788e49667c7,
+                0x9f38d5916158debb,
+                0x4a5443ac63c39dce,
+                0x5e4b5b83688a9295,
+                0x991fea2807e6b9ef,
+                0x656cb82c29b76ca2,
+                0x227ab122d45cfb80,
+                0x000f012616de6d67,
+            ])),
+            Felt::new(BigInteger768([
+                0xbbd29fe49d7ee7ac,
+                0x7872c38f37dfe63d,
+                0x1cbece19a29e219e,
+                0x46e553324ce62184,
+                0xda370ca7df3f956f,
+                0x435fdb4a6fb61e3a,
+                0x1c04d88f52534c0f,
+                0x64d1c7869573cd36,
+                0xbed990e8b16d57b8,
+                0x6e7c54029c65842b,
+                0x787d001e89388f39,
+                0x00a2d210e2f5a29c,
+            ])),
+            Felt::new(BigInteger768([
+                0x46c3201eef67e377,
+                0xa97c0b9cd32fec2a,
+                0x47c8f89ad7c89587,
+                0x512652ba1475e01d,
+                0x379f024fd1aad73b,
+                0x2be9231f57839fe1,
+                0xea1275c1d344c9cb,
+                0x9fbdea3f749fc7e2,
+                0x6de799efe2a8728c,
+                0xbe48b94d11d5338c,
+                0x2a8fd4920fecd4e8,
+                0x0057ab31a0a938d1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x886a94f9b66600a5,
+                0xcc48c7e257b9b4ae,
+                0xe455ecd6cb1f61f9,
+                0x96bd07171f7a4fbc,
+                0xaa083cd1eec8fb95,
+                0xf9fc40e218f1484c,
+                0x46237a16acb9d0db,
+                0xf0d937d3309810f0,
+                0x5caf04ee9067b230,
+                0x73cca1d0e2315c58,
+                0x94fd81b653cfe7e8,
+                0x00099007bda808dc,
+            ])),
+            Felt::new(BigInteger768([
+                0xcfbd57a37b2a2cf0,
+                0xc021676368a59365,
+                0xc6c4ea0d48e1a2eb,
+                0xbe03a136709d883b,
+                0x0e75e87af339162b,
+                0x214abf7fbb5fd0e0,
+                0x869f23c1966c7b82,
+                0x5daa4d3feec47fa1,
+                0x9cf4902c10eac4ab,
+                0x40ba28bdcae742da,
+                0x28c93b95d5265114,
+                0x000b6b81237f432d,
+            ])),
+            Felt::new(BigInteger768([
+                0xef4b078a013730e1,
+                0x9ea749c5bb601810,
+                0x9c9a5d1a7f204a2c,
+                0x17de809984b96e0a,
+                0x6d802efaaf147e0a,
+                0x42f3fce445440314,
+                0xcf064d7e55cfd95a,
+                0x7ee83974908f9ced,
+                0x57cbec1227cf2d6b,
+                0x465f3bc330d81338,
+                0xc42626294fed9450,
+                0x001631c30b99f1a6,
+            ])),
+            Felt::new(BigInteger768([
+                0x816e1d8cb179c3a3,
+                0x6132a60a887e00da,
+                0xade8530eeffb3e15,
+                0xc46f86ba4e00584d,
+                0x7c7cfd7b1b3733a1,
+                0x3ea8d27dd2108d36,
+                0x2485eca54a30b723,
+                0x86d1d45b58cc043d,
+                0x4b39ecd994519e93,
+                0xb6159b5eba45a1e8,
+                0x105bef21023e3887,
+                0x0023f10aa4aeffcf,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xd1af9c79a61db496,
+                0xe2cd645cfe3e02b5,
+                0xdeb6781fdafeb478,
+                0x9a1febf59b8c5260,
+                0x9d924375a386ff0e,
+                0xd11dd178a2fb28b8,
+                0x2cf3e7aec696e3bc,
+                0xa28a685931c5473e,
+                0x20b0774a13ebba92,
+                0xaa3aecda46b33309,
+                0x349fc9f4f809e43f,
+                0x0027a220a4b3b131,
+            ])),
+            Felt::new(BigInteger768([
+                0x7c10816b19809ae6,
+                0x199d5a7c508b280a,
+                0x73f993a08b0cc8b9,
+                0x71c97a48a2a44090,
+                0x17fadd6809d098b0,
+                0xe8e36fa11a87ea45,
+                0xdcad854b2011e595,
+                0xd6b83d327545f840,
+                0xb3fb8eb3aee30399,
+                0x2c5a4fc39a19228d,
+                0xf9d833af1897cc1c,
+                0x00866b20739046a8,
+            ])),
+            Felt::new(BigInteger768([
+                0xfc095aef259e4657,
+                0x386b4ec5ee585525,
+                0xcae98f11881db02f,
+                0x71f15985266e7302,
+                0xf447b7d8dee36b8f,
+                0xde0aa365cdfdbdc6,
+                0x0698c64b7d9ebaa8,
+                0xd22064298a6539c5,
+                0x56ba110f2af7f30a,
+                0xba467c40595f4a5e,
+                0x43e6f8fa2485d220,
+                0x0097b2d08bcaff79,
+            ])),
+            Felt::new(BigInteger768([
+                0x552dbcd44956ba5c,
+                0x5e07573c41442223,
+                0xa0f7950ac71eb631,
+                0x3c256ac717e5cb3e,
+                0x525abfe6e7e5a7c9,
+                0x4d6b088d7eeecca2,
+                0x67b27aa4f3b4b6db,
+                0x941e15154c4ae461,
+                0xb456687ddf91e10e,
+                0x479808b9c1ba675a,
+                0x2c300fe14a618d7d,
+                0x01133d988dd9089c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xe5af29db44fd437e,
+                0x0469b8437c8b5df1,
+                0xd4cae4bb8f98a3de,
+                0xa961ca5afb071f3f,
+                0x961e1d25e105cb93,
+                0xdd3dd169e6ca1bf1,
+                0xa43eeeeab225282c,
+                0xd9fef177f93045f8,
+                0x163ca6ca9bf79da5,
+                0x55b7eb463e477810,
+                0x6b836d091d9f49ff,
+                0x007ba644e83bc642,
+            ])),
+            Felt::new(BigInteger768([
+                0x91640c1b7f9a1c77,
+                0x195108c6e4c5d47e,
+                0x1f6d0bf77ab256f0,
+                0x23fb0f2804b75bf6,
+                0x9e7108ab01d4b3f7,
+                0xaf2883dcc4678966,
+                0x11a0f76e91da4686,
+                0x602935d257be70df,
+                0x3134ea79fadb6510,
+                0x769d4d6a01070594,
+                0x712e2d24bffc8711,
+                0x01034156b311c4da,
+            ])),
+            Felt::new(BigInteger768([
+                0xbc471d309763b1ce,
+                0x21bda36a90f7a2a5,
+                0x2f52921ccaa57c28,
+                0x61c34411aa020d3e,
+                0x5a184967d6be1f3f,
+                0xb0d67786e7851777,
+                0x6a246ed0674e0880,
+                0x3510b116bdd57577,
+                0x3e5cba0bbb915679,
+                0x6b6ae988f1e587c7,
+                0xa1a570e95d724356,
+                0x00675e9c5444fce1,
+            ])),
+            Felt::new(BigInteger768([
+                0x7bf92e132466fe5f,
+                0x69d1ed1e7bdbac5f,
+                0xbc0495be4dec9495,
+                0x6ac64bea4eba834a,
+                0x48d2a7b2b1c1bd52,
+                0x7c552fb85dcd81b9,
+                0xfc2951901e6ed3b8,
+                0xfdb9e7e199a0c717,
+                0xec7fa148c5484344,
+                0x9c37f6523c08df95,
+                0x2bf73f9c85b292f0,
+                0x0056007174df8dbd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xc4d4933e53940712,
+                0x6d398ac66fa07448,
+                0x31aaf1f8473f5dd8,
+                0x593187c89bfd900a,
+                0x98471dbd5c2f1a78,
+                0x5392b148b7245b37,
+                0x68b7da5c2896f724,
+                0x00c49a419615f5ec,
+                0xf14bb635d6ca390e,
+                0x74d15ef19ef77655,
+                0x1e0e584fc399dc2c,
+                0x006e40c5a689b3de,
+            ])),
+            Felt::new(BigInteger768([
+                0x6663568dbc302e91,
+                0x8ce22d13aeae08a4,
+                0x2c46fe07b5f70a48,
+                0x509ad878970ad5f9,
+                0xd7b913605bc10890,
+                0x2a67ca880807e695,
+                0x326e8399c029def0,
+                0x6c9adc3e7c9ca930,
+                0xa88220f75a115f00,
+                0x279bbea4ed97c5eb,
+                0xe68e09f5d50b43f1,
+                0x00801bd908ecfe82,
+            ])),
+            Felt::new(BigInteger768([
+                0x37c22e0c09905738,
+                0x4ba05f745eb17753,
+                0x45bf1c426f78b413,
+                0xb2bd4564db329b37,
+                0x91bf59a11e6c697d,
+                0xffd57aae9aa0149e,
+                0x15f62dd31e7febc9,
+                0xfd903ccc942736b8,
+                0x9af8dd2c7eeb17b6,
+                0xf2659b8ef5f9463f,
+                0x1fd42da2a33c92f5,
+                0x00d0452b224d1545,
+            ])),
+            Felt::new(BigInteger768([
+                0x3d940831647b938a,
+                0x0290e4f0cbfc9a94,
+                0xe5dff4a0397fe742,
+                0x45de3621db7d1721,
+                0x363ef4b4eb2e726d,
+                0xebb18491fc5a32e6,
+                0x1a1c8d62db56986e,
+                0x0f98586c6a889c11,
+                0xd5181111d9b1c838,
+                0xd4d269c18c164665,
+                0xf69293f2180eae6b,
+                0x00f452e683d940f6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x65f67b9788ca27af,
+                0xf68688047e3b9cb3,
+                0x08ce2eb4ce640404,
+                0xb78830f482cfb7d3,
+                0xc2c58b7d76bdaade,
+                0x8ae6e8fd29876147,
+                0x6bbf8bc71ca8d5c1,
+                0xac847270f504d94a,
+                0xa04de11767ad0ec8,
+                0x05284cef4eceffd5,
+                0xc57be438f8733ddb,
+                0x010903e9d86170c6,
+            ])),
+            Felt::new(BigInteger768([
+                0xbc4c26033e756fba,
+                0xb143347933a4ff1f,
+                0x1828ac76cad7cadc,
+                0x4cba05d440ad26d2,
+                0x072387bf0cbcb5a2,
+                0xe6a177bd1dd18f96,
+                0x60f803f9c71d68d9,
+                0x25bb186fa4e69863,
+                0x2400db35ea228e92,
+                0x9c014def88700f02,
+                0xd2a8a18131399556,
+                0x010a1cc8599789bc,
+            ])),
+            Felt::new(BigInteger768([
+                0xf570a66746279dae,
+                0x84478795a5f8993e,
+                0x021affc9a1ec9b5b,
+                0x19ef1c25dfe4e478,
+                0x2d22646cabb25a10,
+                0x7a01525638fa1ab2,
+                0xc0468903ed941820,
+                0xdc165555415fbcf0,
+                0x1159461a98ce2d86,
+                0xdab622fc632e5a35,
+                0xfb1ee32f4a265bd9,
+                0x011823aa360a4d1e,
+            ])),
+            Felt::new(BigInteger768([
+                0x6ff0b8b8b79e63d7,
+                0xf8d612e2c18599f0,
+                0x178cd519603550f2,
+                0x21ecffc9711d1114,
+                0x46a70a0c2f3a5653,
+                0x2107558ea83a9917,
+                0x93d59405eeb429b7,
+                0xc5cc6ed201a6ff34,
+                0x47df4b4568918ba6,
+                0x64b80664c20081db,
+                0x5a94ebe75248d3e8,
+                0x00c02a42f3083ca9,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger768([
+            0xe61d881274c70a47,
+            0x86aa7b487bb44593,
+            0x94e26a86b6e749ee,
+            0x9d1a7ed0bdf1083d,
+            0xdca0cfcd36e95eff,
+            0x462e8b8eefb81a18,
+            0x06e3bd21ef1ca9f8,
+            0x213d5021b9607588,
+            0x969dd8d2ad63f43b,
+            0x3cf8dd2ff37dbea2,
+            0x93f4cc9300c91269,
+            0x00151c250c04c7a5,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xd1ed454b6376dc13,
+            0x163b8a15409c9261,
+            0xb2ff8145659d51a9,
+            0x53e9489e6ce79545,
+            0x8a3cd6714d5524f2,
+            0xea3997742878520b,
+            0x7173bdf0726360b5,
+            0xe04fade18635c16f,
+            0x5d50a6e5c895c38b,
+            0xaef5f65afe21ff89,
+            0x5fd720d0331fc73b,
+            0x004cb54289f2a664,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xdc144062002c480e,
+            0x339d0d7b496f302e,
+            0x0c971670c0954cf7,
+            0x710f369da72b0568,
+            0x5b2502eaa0bd9828,
+            0x9facf0f5a19ee288,
+            0x5d39dde92ae7b088,
+            0x7e5546f21a0601fd,
+            0xd46f95ccabf71ebe,
+            0xc289bc9823120adf,
+            0x125d5cd68307d96c,
+            0x001859cca24474d1,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xec4f1660ef54e58b,
+            0x512b7b93dd025811,
+            0xbbe88f35e68dc27c,
+            0x6b12540c08c3b1f0,
+            0x697729633a483c18,
+            0xb4699eb6da02a8fa,
+            0xef7833a27a0011ab,
+            0x4dcb1ea6da349119,
+            0xde7ab1c24ca370cf,
+            0x1c32705e25feab98,
+            0x597c8bd1716feb4b,
+            0x0060814658038341,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x75b5d413133d12bd,
+            0x9e4fe4cc54752ca8,
+            0x6695f8c60fd4414e,
+            0x89d85263eaf85370,
+            0x8b0666bf0d636ae1,
+            0xbec26f913078d172,
+            0x60ee9c51769fe9c9,
+            0x69dc1f20b90d461f,
+            0xa51e769d890700e3,
+            0x665c568d2c5c8178,
+            0x653e00bac15af123,
+            0x002efaef860ca734,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xf067943f3484a512,
+            0x7ab4cc5f3aa14542,
+            0x1a84dfddb148e4ea,
+            0xc9e10375e2dc4cf8,
+            0x4ca2e349f1968112,
+            0xed8d95b4f3525257,
+            0x8e30bb830d5ed094,
+            0xb069a2177ba3a1f8,
+            0xf8000fe5e2559b9c,
+            0x822db71c42823ce3,
+            0x584eca363e46f5c6,
+            0x00fa9ee115127fdf,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x3606b1b3b7739ac8,
+            0x18bbb4a28291f5c1,
+            0xb65e112b963444d2,
+            0x8025ff932aa02728,
+            0x6c59d0f66d033190,
+            0xaebfcd2e6a917bad,
+            0x9e2dcf9f34cdb29c,
+            0x8d4ff4969c383575,
+            0x3825047fd91dd5b0,
+            0xae6144d0d28c440c,
+            0xe89a84f66cfa4043,
+            0x0102ebddcf55bcee,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xa162252057095555,
+            0x7a82535f9e8c5005,
+            0x459372e2162690d6,
+            0x3a07ccfffed1ba27,
+            0x332c0b03ccfd9be5,
+            0xec58cfc2b275d4fd,
+            0xedf3a4a332658967,
+            0xe941752f4353a148,
+            0x7580322e4fc4f558,
+            0x4e22baa72f24bf1c,
+            0x6538f2e6e490c570,
+            0x0031142b78257c29,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x79c97f90bf6a60e1,
+            0x9ee925c45e4cc525,
+            0xfb027d3aad3d70ff,
+            0x333c60a7306eab2c,
+            0x66f1befc6acb0305,
+            0xef1c8d8e6c82cc61,
+            0x5d35e8cc8d54df58,
+            0x4af9ade6bc8e4ef3,
+            0x4bc0befb15f6d196,
+            0x2c4ffc69dd58c348,
+            0xfd0cb1107a4f8878,
+            0x009e3079584b3a94,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x8af436e250042504,
+            0x222a7d3de64b0a1c,
+            0x345e8d03ac29699b,
+            0xada504966e383704,
+            0x8dd5deacd460e2cd,
+            0x0e9746f8c0005738,
+            0xcf1b4329b19c138b,
+            0x6615a332092b87cb,
+            0xba3e13ede2552cc6,
+            0xa0d42d9d4f051c7e,
+            0x7a536678d29a0863,
+            0x00355fa89d87480c,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+    }
+}
@@ -0,0 +1,66 @@
+use super::BigInteger768;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 2]
+/// [2 5]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger768([
+        0x0202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x0051f77ef127e87d,
+    ])),
+    Felt::new(BigInteger768([
+        0x0405ffffffff0baa,
+        0xb4b04c6b1fff19ce,
+        0x3d32dc8704ff55bc,
+        0xb4d5fe641dc8fbe9,
+        0xd9d3967c3b297017,
+        0x81cccf44a4904817,
+        0x4e9b4b7fb95a720b,
+        0x46a5cffc8c5e4207,
+        0xf6acb100116390f8,
+        0x8b0914c7ce22045e,
+        0xaf503d773ecb53be,
+        0x00a3eefde24fd0fb,
+    ])),
+    Felt::new(BigInteger768([
+        0x0405ffffffff0baa,
+        0xb4b04c6b1fff19ce,
+        0x3d32dc8704ff55bc,
+        0xb4d5fe641dc8fbe9,
+        0xd9d3967c3b297017,
+        0x81cccf44a4904817,
+        0x4e9b4b7fb95a720b,
+        0x46a5cffc8c5e4207,
+        0xf6acb100116390f8,
+        0x8b0914c7ce22045e,
+        0xaf503d773ecb53be,
+        0x00a3eefde24fd0fb,
+    ])),
+    Felt::new(BigInteger768([
+        0x1571fffffffd9c9e,
+        0xdd2780a35ffdc000,
+        0x02f22ea2a18db21f,
+        0xab75e537f40ecccf,
+        0x2eb4245a1ffb990c,
+        0x3df63d3e886eb6ab,
+        0x40b581c029adfa18,
+        0x4022e1be7966bbf9,
+        0x162d4490aaf12c2b,
+        0xa2709b890183c465,
+        0x64c0d06a1801521d,
+        0x0076ed55ba43bc6a,
+    ])),
+];
@@ -0,0 +1,1900 @@
+use super::{sbox, BigInteger768, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x90b93242432233c0,
+                    0x68d9fffb895f9a6c,
+                    0xa19d96071f5e3202,
+                    0xfaaad6c0fb7bbc66,
+                    0xee3be8346fec8be2,
+                    0xac89f746024be188,
+                    0x6f76d9204121055e,
+                    0xe058943192e706da,
+                    0xaf07a5afddca36ca,
+                    0x2cbe60defe456b80,
+                    0x8f215586fb3c3f94,
+                    0x0036b8a45e78ddbc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x864b57e81c2dbb5a,
+                    0x2837df9662e1db2b,
+                    0x66ccc40c56d2fe25,
+                    0xdd5ed03a020347b1,
+                    0x23443c61eff11daa,
+                    0xf445d1344dd47a36,
+                    0xd1b9c45f1716a7da,
+                    0x41c3a5faeab3bde2,
+                    0x51dceda29845ded6,
+                    0x8fec907e77b41aa3,
+                    0x2a234b32f0b60f92,
+                    0x00973506cc006483,
+                ])),
+                Felt::new(BigInteger768([
+                    0x994e8d66ce56f70d,
+                    0x340a194be3890241,
+                    0xb23dff348f645172,
+                    0x2eda0a47cdb15a51,
+                    0x1c084f5f89e2b98a,
+                    0x71621823736ea350,
+                    0x64c2fa4d37f55ee1,
+                    0x73df14a69fc57c8c,
+                    0xb2b18c0197c715db,
+                    0x14358e7f1c8a3c4c,
+                    0x418c5fa5dfa7cb08,
+                    0x00cc081fc1025323,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc40c048bafd35db0,
+                    0xf1740280e61c05c9,
+                    0x344c293016a60615,
+                    0x2ee25d42b15f440f,
+                    0x6fc1d6fb8104b863,
+                    0x56611b68da855f34,
+                    0xa71663f6e90a681a,
+                    0x2dd7cd404d729e65,
+                    0xb673885f278e1f7f,
+                    0x13ffc91fc46bc057,
+                    0xf2f8058cc409ed95,
+                    0x010d070f753e0e9c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xaddd77c3a3ba2cce,
+                    0x513ae1b37ea9ce66,
+                    0x43d6fdfafb3a222a,
+                    0xfde8da238974a4b4,
+                    0x962c85575928fa57,
+                    0x28d209d0d105e87c,
+                    0xcdc93221dc7c7643,
+                    0x40f27bc51fa10f67,
+                    0xfa42d5d53f331b97,
+                    0x6e36abe41d3a9ced,
+                    0x8696624d9668b178,
+                    0x00807a2f382d6273,
+                ])),
+                Felt::new(BigInteger768([
+                    0x01abec47db2049ae,
+                    0x859a2c239b868d8f,
+                    0x9536b2161ea03dcc,
+                    0x4de526d18e355280,
+                    0x95e4cbccd00f8ed6,
+                    0xde52a80712ba8e3d,
+                    0x956df36b5afa6f62,
+                    0xd6c7dc0584aac4f2,
+                    0x1299a27ee76c5961,
+                    0x0bc300761f615500,
+                    0x140084857ed3d601,
+                    0x011831fb011639fa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1864ad6366f8d0dc,
+                    0xf4d6f579f3ef8257,
+                    0x19786c64c32072ff,
+                    0x4ec6b301bba6adcc,
+                    0x303bc93fb3869233,
+                    0xae177332a38ac787,
+                    0xc5e2e08ba7441f1c,
+                    0x9f7212e1519b0596,
+                    0xf7cda9adf38d7bf5,
+                    0xc1bc3a722052a7ed,
+                    0x946d419cee7a2f59,
+                    0x011b5a429a15f173,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2f8b4bc519c0e685,
+                    0x42e4407cfa9d5b99,
+                    0xe5f5f8c340133db9,
+                    0x27613ace4e89e9bb,
+                    0xf2fb7fe38827276d,
+                    0x77f74d97b5693be2,
+                    0x5fbd8116e9f671da,
+                    0x17b504b50e60b07a,
+                    0x81f89c7ae4914fca,
+                    0xc90763b2164a1321,
+                    0xb3d01702a0d77558,
+                    0x0107ce6af5c3264b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0f171593ccee7d25,
+                    0x5c6b6cb5109d1955,
+                    0x8a317074c974f1b0,
+                    0x211f99f2cfb3dadc,
+                    0xcb5ce9a826678a8a,
+                    0xe730b2e1b232599e,
+                    0x7590fc391cf09e88,
+                    0x2435d3c63c545c83,
+                    0xea115a4e8bf6b0e0,
+                    0x2d449667e39a8b60,
+                    0x85c71c447e0623ff,
+                    0x003d9180074865d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb1bff99281a9b489,
+                    0xb5171ba83c62589e,
+                    0x9bb1fb270cf83ace,
+                    0x66b5a922f4f93d42,
+                    0x2beebafce39c4c74,
+                    0xe94a46c72cb3c2ac,
+                    0x8c6a6ddd55d64ac2,
+                    0x052a36f72acfb088,
+                    0xe0763d74023b51b1,
+                    0x2131f04e5da1df39,
+                    0x135006b383d3ec82,
+                    0x00eedd05183a3148,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3236f98ad5a7fc25,
+                    0x980f7d988a09d852,
+                    0x05eb7adec6e5780e,
+                    0xecc730c69d7c4a42,
+                    0x777e84bfe03d97fa,
+                    0x0bfe0b117512ca0a,
+                    0xb830a88625abd047,
+                    0x43739963e935e71b,
+                    0xbb8ad4a6092dfcbb,
+                    0x881b8db23bebc930,
+                    0x2b8aff54752341c6,
+                    0x003b7cc510a4f09e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x314ef7ca74eafda0,
+                    0xf7adaa5760d68d96,
+                    0x5a5bb8f745825217,
+                    0xfaa7916377ce1192,
+                    0x0b417f9a2ef4b283,
+                    0xfae72fa4e86f994c,
+                    0x3fafd6e15b614d2b,
+                    0x660cc8b857aec158,
+                    0x0dfdf7906094116f,
+                    0x21f298ec3aa36fd2,
+                    0xa6a55c58d4e2d834,
+                    0x00b26a28ce9101fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x11f805dcb274b0cc,
+                    0xfb28dfaa466b0a36,
+                    0xd8eb14f4a3ead2e3,
+                    0x3225f7df4cb40994,
+                    0x44a4a7cdc78da1d8,
+                    0x8ba1aa7bc314f703,
+                    0xb544e5f9a1b8739f,
+                    0x495ab13b291f3e85,
+                    0x3dc5b57206a30c68,
+                    0x3f3ce54dde1b1722,
+                    0xb4c76bf9a0603294,
+                    0x00af426f5cbce715,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a6918dc5c356051,
+                    0xc0922534078d6a58,
+                    0xc65702a9f3e9a60d,
+                    0x5e61ab1cb05b424d,
+                    0xf71c86c4e3a1b909,
+                    0x0f9c1a133fd19641,
+                    0xc6c8a4db9ccbc26b,
+                    0xfda0ea126b0e9433,
+                    0x053d9cb204f4ce76,
+                    0xecdd581d6757c0f3,
+                    0x0de4c9872bfd72b3,
+                    0x0000155838f41cbc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaf9954784fe27642,
+                    0xb03904444938345f,
+                    0x4ba445fab63f65f5,
+                    0xcc7bd1d27b52357e,
+                    0x22412db286cfad14,
+                    0x9984fa78b87f88fe,
+                    0x3c191247c2a39dbe,
+                    0x89ef2076f2ccfbe0,
+                    0x109697e2de64d483,
+                    0xb56c63a567f1b4c2,
+                    0x4d5d732532e1279a,
+                    0x00aba368f48d32e8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44c1076de8871c77,
+                    0x6107a71d85b897aa,
+                    0x186bb9a35ec5dd1e,
+                    0x05e9713aa29eb725,
+                    0x84c4bb491fbbea12,
+                    0x28cd37c1c534422f,
+                    0x55bdaaebb17e8ef0,
+                    0xfc0b76b25dfebfe0,
+                    0xfbd0623f8a3183e7,
+                    0x118a5502e45a9236,
+                    0xe7f65ac94c3652e6,
+                    0x00b4c14e6aa98162,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8604a2c7c8c7da78,
+                    0x7d19708cda67ca15,
+                    0x9c2528f46c193ca3,
+                    0x40b0e33c5ea6b66a,
+                    0x021c80b393ea4817,
+                    0xfebfa195ab02df60,
+                    0xed100529cc64575a,
+                    0xd707d89a71a9f084,
+                    0x2b2295a7e2c8293e,
+                    0x0ba13f42b027fb20,
+                    0x9133d03a4974e8b6,
+                    0x00b02697e9d48ba1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7300b13834f27091,
+                    0x237b0238c427269e,
+                    0x43086a8b95f0898b,
+                    0xa09c331c8306562b,
+                    0x2e991e040b1e422b,
+                    0x9aad5fe878c28f90,
+                    0xd044fe24ce5dffcb,
+                    0x957471c8807bce1c,
+                    0xfde1c7f26ed8f9bf,
+                    0x5a0f5abadc6937f8,
+                    0x08ce384912225039,
+                    0x01227af7caf9d7e7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x732a98942678a0d1,
+                    0x41cf13615d08cd71,
+                    0x2a2d6aa052a94a79,
+                    0x44cbcf40038a219a,
+                    0x40dd2983cb0cacc4,
+                    0x7e2ef9dd589ab952,
+                    0x96e1821a0c869026,
+                    0x23d715c3097ca843,
+                    0x97a387f31fa10cef,
+                    0x20b74f2a9e47d187,
+                    0x0bbcc481b619f45a,
+                    0x011352f2504196d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x35ddfd1502aba779,
+                    0x0bf84882aa9b9523,
+                    0x5c35532a71b2fde8,
+                    0x309c10f2a69d0447,
+                    0x15507a712a8f2bdd,
+                    0x239779e49d9ad706,
+                    0x9e8597e31efafe5f,
+                    0x4b06081556df7db1,
+                    0xd5d4e29e52c52606,
+                    0xdc97b774a9fa281e,
+                    0x13d7017d8f10479a,
+                    0x00a62bab884a0227,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x39993028d60cea9e,
+                    0xf0dd1ae84ac7dda4,
+                    0x98119ad5045c96e4,
+                    0xbed43153cb2adc99,
+                    0x94a6815ff2e1dd33,
+                    0x627ecfe7caf7a6a9,
+                    0xd31dffdfabcb8cdf,
+                    0x4fd4785f1a37a153,
+                    0x97951aac0458617e,
+                    0xb1413dbfd6d4a62f,
+                    0x2471814923dc28b9,
+                    0x011d68c37d222d5e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2fc934c0b9a9bc12,
+                    0xeaae25ae1d984573,
+                    0xfb9a0b6ea78683cf,
+                    0x88ab88272c0ce088,
+                    0xd7b5bb81ba8f8f69,
+                    0xba242085ac480574,
+                    0xe691ad952b96fe0d,
+                    0x14333968d9ba874b,
+                    0x63187b86eb156c22,
+                    0xd61d3d46388bf335,
+                    0x49a9dfa3b2492095,
+                    0x006219f6d4453c00,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b9464e1e633176c,
+                    0x08e10366de0d3d58,
+                    0x711240a4272686d8,
+                    0x978f60b0ee6c9967,
+                    0xa7cc8ffd43d7324f,
+                    0xfb6753b5ba79018c,
+                    0x7f9a23c76aed6f2c,
+                    0x2a49bb980819aa2d,
+                    0x57aecaba307a32fa,
+                    0xc28af7c9c03ccc7c,
+                    0xcde1899dbe336853,
+                    0x005e66633346aa60,
+                ])),
+                Felt::new(BigInteger768([
+                    0x043432a122edb897,
+                    0x7172fd7835ff8cff,
+                    0xdd55686017426986,
+                    0xff3e3710e07f3b6b,
+                    0x939592b3b0d265e8,
+                    0x6921567639eb27fa,
+                    0x6d0e422b572098ff,
+                    0x6ce45a2714f78ce7,
+                    0x4c5bfcc12ea563c1,
+                    0x860e9c8cf4532f48,
+                    0x89b43224ac6ec9c6,
+                    0x00165adae868c2ed,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7e44605d458ffece,
+                    0xcac55ac9ee8a5385,
+                    0xc6357b7099116659,
+                    0x958676694b45120e,
+                    0x2a7e81648cb08bf4,
+                    0xf9b57a2979a8382d,
+                    0xe8cc4591d80a9ae5,
+                    0x16a684c9f2a8cdc2,
+                    0x88d4f65ede72b533,
+                    0x5ea2cf3f7f4f8ecf,
+                    0x37c03946fde5448a,
+                    0x0063d22ee2a367ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa25d95c69aa2f267,
+                    0x4a2dd5f11384002d,
+                    0xc4c94a940220422c,
+                    0x0994f879d4ff31d1,
+                    0x593820217a27750a,
+                    0xa8d00f2313f5d06d,
+                    0xcd88a95678f1407f,
+                    0xd26cf8cd92b4ece5,
+                    0xcd64d5ccc7366477,
+                    0xe9e790115ba43804,
+                    0xff3b45df00d41592,
+                    0x008054ab6f381122,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9514a9114058cf9e,
+                    0x04ac150e505052a2,
+                    0x12036e3308860412,
+                    0x2dfefb503f99d46c,
+                    0x729d3a516c0e23f5,
+                    0xad7cc5f2d04b3f44,
+                    0x27d091bfd4118e9f,
+                    0x931072838d245b21,
+                    0x0b9e80ee2c7f1348,
+                    0xf5eefd355b89463e,
+                    0xc740bb9f301b8a30,
+                    0x011ffc0b736eaf88,
+                ])),
+                Felt::new(BigInteger768([
+                    0x844f5c7273b4e5c5,
+                    0x01da34b3aab8e7b5,
+                    0xbe4ffd191063aca8,
+                    0x97bcb5d0be466e48,
+                    0xe2eec9dac01e025c,
+                    0xe35e48e310dd0bb0,
+                    0x7850266bd3b737c8,
+                    0xe05985dce4120c71,
+                    0x705d7a639a80d764,
+                    0xbf6cb6412827b69a,
+                    0xbb6ca30fab8b8b5e,
+                    0x006f71f47fe0b485,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9fb9ea52f4c5df51,
+                    0x418a24af34b648b0,
+                    0xf83cd639f08e65ae,
+                    0x028f410de4c4950f,
+                    0x47536e4228b0b7c3,
+                    0x5d4e1802578d276f,
+                    0x5af273777d8466cb,
+                    0x36b1446d1a5d5d26,
+                    0xfce54077ff047048,
+                    0xf8e2cbcd866da750,
+                    0x00447c1b1649506f,
+                    0x00dbca7618677624,
+                ])),
+                Felt::new(BigInteger768([
+                    0x917a79bed53e64ed,
+                    0xc6b500cff015964f,
+                    0xc4498f366f77fce3,
+                    0x38899609feed8daa,
+                    0xb808709d7d70c219,
+                    0xf4f2668176299252,
+                    0x2d820fe038b7d02d,
+                    0x1091779d9692a888,
+                    0x716f004ff95a5f4c,
+                    0x42310ebf95d1bf5a,
+                    0x3fda4d1109ffdce5,
+                    0x00e82c0e98ce860d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd3694593cf5b407e,
+                    0x622c2ef9eab67c73,
+                    0x8b965a2bed94f00a,
+                    0x0e6f458caecb1f49,
+                    0x2b4676c42b1b45a4,
+                    0x5d7ad60cefd30577,
+                    0x5feee80f799570d0,
+                    0xed92e6cc7799172f,
+                    0x416f0a237c36562c,
+                    0xd1a4099c8b10bba7,
+                    0xef928e7d120d5acc,
+                    0x006d345f915dc3e3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6d9b35be609f1a7c,
+                    0x4f32a56aed31b04f,
+                    0xc12b46411cd3608b,
+                    0xcea503263b4a6dd2,
+                    0xd2ce0bd492b479e2,
+                    0xae3410e8ee6e38b2,
+                    0x9ebcb7143a916893,
+                    0x62a669943197058d,
+                    0xd940e6c1e3bd9149,
+                    0x576534c5a4547456,
+                    0x95ac8e9ef99192cb,
+                    0x00ccdaf1c6c4520c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x818a5b8f823090f1,
+                    0x7e451a614af66c8c,
+                    0xf27818666341ba1f,
+                    0x87b0c311a92884eb,
+                    0xacc1def8153adc44,
+                    0x93cb796bc5cf1264,
+                    0x5e613248228a7a50,
+                    0xc6e2a50d2de1ced0,
+                    0xcb610a32cc9dffc6,
+                    0x4c3a604f82c6bd20,
+                    0x458f54a235e40fd1,
+                    0x007a1c1e8c895dd0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe885f33282b74bec,
+                    0xb4949ed775442d70,
+                    0x1f5c03ff68fafdf5,
+                    0xd18ca5fa0fe49aa8,
+                    0xd4d6093a1f297e47,
+                    0xb838c43fce19083a,
+                    0xe9da9d18be5ecbf5,
+                    0x3587bc5e4c45e8d4,
+                    0x5a102426f2a80937,
+                    0xe215303ed43f1257,
+                    0x7bef8fc52cdc4c27,
+                    0x00daeee7b4f9f76f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x74e7c4ffdb6f0dd2,
+                    0xfa56a9e6d79c7b7e,
+                    0x4a64ddd00cbe937e,
+                    0x0b616d545528738b,
+                    0xa57f1102fae61500,
+                    0x8e7866442ace940e,
+                    0xd5ed4e3893b954a5,
+                    0x79bc978797e15d11,
+                    0x16dbdfbc8b63cc4f,
+                    0xed28e44c0ae63d08,
+                    0x8b4797b50b9c62f8,
+                    0x007813916fd96c06,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2a09d1d051346748,
+                    0xaa7ab38fee353c8d,
+                    0x67722743a23e6937,
+                    0xa3a865ce411a094f,
+                    0xad3b4fbef0a2d38a,
+                    0x0e236995a89a2cf7,
+                    0xcf812b66cab37f04,
+                    0x90dd0f8903b05f0b,
+                    0xb3dac912f7113bbd,
+                    0xb722da03395a24bf,
+                    0x9d898ed823fb581c,
+                    0x006e5f825246d98e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe2c27da6e1a81fa3,
+                    0xbf8faaae391db55e,
+                    0xe58ef75548fd8747,
+                    0x2ecbb510d82ff68b,
+                    0x09befaa8f0051e53,
+                    0xb4fc998b8aff0bd5,
+                    0xf4f904f4a036eb9c,
+                    0x6cbf7df66ff2b884,
+                    0xd90132437233d09d,
+                    0x833a38b2764ee89e,
+                    0x791558ff7477dc74,
+                    0x00502212ede8134f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b94eb4dba1f9f13,
+                    0x13f8de9d648a3f26,
+                    0xb524a75563ae9014,
+                    0x08f1fdb749d01b61,
+                    0x8bb4c0c2f4a0635a,
+                    0x628b1534a1ac0bac,
+                    0xe01370fd4f9c0c8e,
+                    0xe2b81ea8c0d9d478,
+                    0xcc781191392e7fae,
+                    0xe406986bc0d0afcd,
+                    0x7e43075e30838f55,
+                    0x009320d6d49f3546,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5202c62758c39335,
+                    0xc870847bc2e41894,
+                    0x6ff70d63ee7fdcf1,
+                    0x89c707c35ed4d3b4,
+                    0xd07f22370df8541b,
+                    0x9114f7e27a996c73,
+                    0x25e00253a4b31dd1,
+                    0x3685edffed24d95f,
+                    0x9fb4b6d8462e4734,
+                    0x33f28ca8c9aec4c2,
+                    0x56e4d2640c69adfd,
+                    0x00e2fb48d85475c0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4d4828ff0e7850ce,
+                    0xdd427a1ee88bc1cc,
+                    0x6d5ca93ef1e29e3e,
+                    0x66dd43577a217e42,
+                    0x36bcdb25b98af05a,
+                    0xb50219252549f6a0,
+                    0x2c5dc36e54fe9589,
+                    0xa25c16fe189e6fc3,
+                    0x8a3d15a66d5bece7,
+                    0x1bd8a9e5b36368c4,
+                    0x786c4f8d1e719678,
+                    0x0021de393710b704,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7384b6d1b9599f22,
+                    0x476eaa272e8f3cbb,
+                    0xb794a137997f9909,
+                    0xe740f3bb83a6d23c,
+                    0xb7542849f516351f,
+                    0xed571c92af957c95,
+                    0x1ce25e3a0b28ce39,
+                    0xd9bec2a654afd44d,
+                    0x9c4ebfa8721ca7dc,
+                    0xb3a3df2d2f015273,
+                    0x9655464f4b41914b,
+                    0x01056b48df5fb824,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2c0f30afb809e516,
+                    0x213fe18dc8007249,
+                    0x0f5f049c9cab68cc,
+                    0x1e2f53aa5904cef9,
+                    0x85a76c9580608347,
+                    0xa2ba5be411c8153a,
+                    0x9751b36b26bc500b,
+                    0x15f6a459f9c1f844,
+                    0x4af10ca026230d0f,
+                    0xb9cf3c12e762a5a9,
+                    0xcac94efcf5c98fa4,
+                    0x00e03d60223c56c6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbf7ed47da9784ddd,
+                    0x12737ac77f123ff1,
+                    0xf75f267c4de5dcfd,
+                    0xa3462beb0fcf1e6e,
+                    0x6e684249a89b2b62,
+                    0xf5a05e31503a7b9c,
+                    0x78493ba107b89e8b,
+                    0x651614a17458e951,
+                    0x17417733a39d667b,
+                    0xca06b0f5da9ef8cb,
+                    0x34470632ceb04f25,
+                    0x00f4162fcb187ae1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd8d4d63de0353809,
+                    0x875002339444cc5a,
+                    0xe935039e6aa4377b,
+                    0x99a27ff2e93a5bab,
+                    0xd8d355ea7a060ca8,
+                    0xc743fd61a0f224e0,
+                    0x762dc41a11801a8a,
+                    0xa6f6c6406855333c,
+                    0x9d92a874fc93ef33,
+                    0xf54b2a6373af203d,
+                    0x37110d4d293e1793,
+                    0x0023242d2dd73a44,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x79e798d5f71d4ac5,
+                    0x5a984661c93e657d,
+                    0x731c54d29b80e92e,
+                    0x2faeebe9c607256b,
+                    0xc8038e1391906f26,
+                    0xee9f2ce8d78eed04,
+                    0x0dafd48c5a038f24,
+                    0xa4c94d7b3cafeaf3,
+                    0x963303191eb6e986,
+                    0x865e069bdebebbc1,
+                    0x6b1dbc9d211a938f,
+                    0x0122c2bc700297c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdf1ba85d4da68e7a,
+                    0x2ee8635a6cb81c50,
+                    0x702414ad9aaa13a9,
+                    0x24d0d78a9d846882,
+                    0xd87bd1ca57c69e40,
+                    0x0888d74681afd839,
+                    0x37342b9e6bd34b07,
+                    0xa4c590c9a37a8929,
+                    0x09612d61cf52cf09,
+                    0x239fe2239a8dd82c,
+                    0xe46cb548ffa65da7,
+                    0x0047393b6a2b4e7a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe0070c199dd62743,
+                    0xa905844ca915e513,
+                    0x978d7ea1973eec7c,
+                    0xc5ec6d21e33641d9,
+                    0xc14bf054dd60cc87,
+                    0xcbf4bdb7a1d8ebd7,
+                    0x4bbe8c5cb91fcdad,
+                    0xb82fb106b2b2c2d0,
+                    0x2ef0a922781b7a47,
+                    0x7a8ab67006dcf10e,
+                    0xf2a463cf484a5360,
+                    0x00288d153c46b843,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7fee6b9ad35580f4,
+                    0x7d5b0fe3a335dc6a,
+                    0x6b6b8c36d3ed3fd8,
+                    0xf9ef705a22d839c4,
+                    0x0f7f2b0956781cfd,
+                    0x6b4e0022019e8399,
+                    0x564a22af08c846f9,
+                    0x9955a3a29f467bc0,
+                    0x260e080498c3c93f,
+                    0x2ec8a12027063477,
+                    0x751cdf5f65fd1cb7,
+                    0x00c27c394aa4c6f5,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x7b145c4b3e6af201,
+                    0x006bd0fadffe6d33,
+                    0x0d9dd4129353cf7c,
+                    0x21a91295667571e8,
+                    0x56a58ad4c335f509,
+                    0x144e341d36eaf5a3,
+                    0x71dc0ae04941f7f2,
+                    0x30fdec0212722cf2,
+                    0xe5a0657b8137c8f6,
+                    0x4b80c816e4b58b77,
+                    0x0135ce17e6850f13,
+                    0x008e66b7664eb060,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc69b7e9ba271de28,
+                    0x686b21971ed30780,
+                    0xf556b0aedde5d36a,
+                    0x95b11a099b1d7421,
+                    0xd6211e34bf6cd13a,
+                    0x20b6438ff6b7edfe,
+                    0xbf406a817c05c5df,
+                    0xcbc66148db5dece1,
+                    0xfb16d2e678054aac,
+                    0x12accb72e8ecf1d1,
+                    0x50f0829294d3f7ee,
+                    0x00e6659330c4b2d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b3514ad6d5c236d,
+                    0x8bbfeb839f6d4895,
+                    0x01eb469253aa5eec,
+                    0x72e7d425136863d9,
+                    0x8119d90f535d1dc3,
+                    0xfdb3ce4734071840,
+                    0x47e4328ae0aed228,
+                    0x8e120ce3ec623fe3,
+                    0xd94e7baf659d5c41,
+                    0xe5059f6bfefb8835,
+                    0x5409f5daa734ce73,
+                    0x00e6ddc03b8f4d76,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b6b640fd7fa6fa9,
+                    0xd8276d444f5148f6,
+                    0x61616508edcfd6d0,
+                    0xd805f9161d15125e,
+                    0x5189776ac5e7d9b3,
+                    0x46351a722ec0b864,
+                    0xda8b6dc9c3f85e88,
+                    0x4adc1d56b1261d4f,
+                    0x7936f683a15be23f,
+                    0xc02d57186d1c9b50,
+                    0x1b775bee3712ed2b,
+                    0x005bcb21b9ba3894,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x83bd125da41d30ca,
+                    0xc1ac3e03196fc68b,
+                    0xc45b89425ee69b6e,
+                    0x9304171a8a47c3b5,
+                    0xe650115bc4ed1490,
+                    0x8de0b60f40b9823a,
+                    0x9edb052843d8aa9a,
+                    0x637ebf80b38c4e02,
+                    0xb2edd17cc639997c,
+                    0x496453bdbb5cb06e,
+                    0x6228e7af0edac73d,
+                    0x00055ae9ea15ccfa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb214c9c11ae7b6bc,
+                    0xbbe2097257e463f8,
+                    0xa51036b6e89aa072,
+                    0x9251a32a6feb43ea,
+                    0xe1619f56bf2f42e2,
+                    0xa84dd79a707747f7,
+                    0xa80668329512966c,
+                    0x454f33615d149ff5,
+                    0xfdbb7e5e4d755d62,
+                    0x8a76f640dcbe9d1f,
+                    0x0878cd455c0de53b,
+                    0x00f79200718ea0b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x944d0b78214dfe29,
+                    0x3ad9f2438aae2862,
+                    0x900f0e32ea03e0d0,
+                    0x44498e423d0bf656,
+                    0x289a567300b42a8e,
+                    0x672366cdbc3b1fed,
+                    0xea1483e68d4b8ceb,
+                    0x2cf3641f2881139b,
+                    0x7ce6e268c2724a62,
+                    0x2e706ef1b30da808,
+                    0x5a688ed52c1890ca,
+                    0x00fa748316559266,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd128bda788da9401,
+                    0x942cf5640b15f7c1,
+                    0x8e7c72f30e809e86,
+                    0xb5b81d6cd98e829b,
+                    0x2df4c0b8e9481f78,
+                    0x3441c4e2c7342071,
+                    0x7b8e9ded49cea79b,
+                    0x58fa585c8c811a48,
+                    0xdee6a04fd7ab7028,
+                    0x1f225cc7d8266651,
+                    0xe865ad4e6500eee8,
+                    0x00aed4dec6087457,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6c45c2a9e31c0a22,
+                    0x52a8dfe964b0ee15,
+                    0x8bc7603fb22a896f,
+                    0x893bb243e1cd339d,
+                    0x46944e2e51539b99,
+                    0x6b299b930174684f,
+                    0x72a69acfc34b6745,
+                    0x392b39eb52103dc7,
+                    0x7438c2c825e8c874,
+                    0xab6a9f29e4e56e0a,
+                    0xc073f2a63e50d887,
+                    0x008664c96af185b8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x66f2c2f0b33c9d76,
+                    0x4fe19dae6989c704,
+                    0x8264d47bf872a1a0,
+                    0x0e2920a3d866f478,
+                    0x40be59d66ae0ddcc,
+                    0xc68e535a400a75f1,
+                    0xcd16834159f67b44,
+                    0xaf6926a4451e0bfd,
+                    0xb1174d192c911dbf,
+                    0xf54e93c9634ccdb5,
+                    0x809340b023e330d8,
+                    0x000d15dced6badcd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xedb2c7cd578b1bc6,
+                    0x376dc788988960b6,
+                    0x6fda59a6491df11a,
+                    0xee0979b259ff1b04,
+                    0x75b619de12618231,
+                    0x184260a8884ae338,
+                    0x86bceaf041c5eab2,
+                    0x69cc9797cbd4afb5,
+                    0x5d8462a1b3adc6c1,
+                    0xc93c33f00dba3ea3,
+                    0xa76654d327076f8c,
+                    0x00688de70ff89c52,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf77d8e8873f16a02,
+                    0x558c570755eabeb8,
+                    0x05eba45bff7b8e00,
+                    0xd2eceeeb1aa55fff,
+                    0xfe96b4bc3f3f100b,
+                    0x5c2d6976941d3832,
+                    0x888d863b91007279,
+                    0xb45bd64ec63a0b97,
+                    0x9fd0efea474c3048,
+                    0x894363e647335497,
+                    0x2573933f1923e6e5,
+                    0x0057db160346011a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4b3d6199522d0449,
+                    0xc45a3397a6828033,
+                    0xedfbaf84bfadaf44,
+                    0x45df4a3a83736602,
+                    0xbef0fad683dc1f8f,
+                    0xf59b580852c3a4be,
+                    0xbd91e9b0f1a32b25,
+                    0x4e9fa1eecd37e2e1,
+                    0xfa48801c687164c0,
+                    0xe5d3903d3899c19d,
+                    0xf2b39ede3c76d6d7,
+                    0x00123a9f1becd2fd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x605d3b2483a7d4a5,
+                    0x03846a0220cca943,
+                    0x454b966bcd1c0337,
+                    0x8248c61250bed755,
+                    0xd48827dbafc75216,
+                    0x1bdc6d735b78f32f,
+                    0x7d7e9abb80b4e875,
+                    0xc18506fcae25e6e3,
+                    0x5a028b7cb9d357c7,
+                    0xc1bd3d3e55f0540b,
+                    0xd3c8c08d6bbe9957,
+                    0x004a1f0345dacb62,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb4bb72bdd698d11b,
+                    0x301692eb865c5192,
+                    0xf94c4ccecb0db338,
+                    0x9d9d5fa119ada537,
+                    0xf05c1ed9ea8852ae,
+                    0x09af3927de8b3760,
+                    0xe1fba68410b3cdd1,
+                    0x9c0c325992653181,
+                    0x82ce66d2da661b89,
+                    0x5ddfa17310f086ee,
+                    0x1d80ce3ada917f5c,
+                    0x0099b455d0ec018b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x463517b3b157e23c,
+                    0x18a71b95b8679085,
+                    0xd28316bef23d6cbd,
+                    0xb0995f861adf9e61,
+                    0x60f90563630d9a30,
+                    0x8a4d193c8449fb79,
+                    0xf01bdb4153cbc9c9,
+                    0xf679fccfffa5e3aa,
+                    0x51f865796f964746,
+                    0xb5d99a45f6a386d5,
+                    0x99ab523a4f6b94ee,
+                    0x011774bf6995d378,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x72e0841e1e7ca8c0,
+                    0x78ff4e4e3c9edf1e,
+                    0x125684e3ca8caa20,
+                    0x0fbd1b77347d3ca8,
+                    0x86efed8aef74414c,
+                    0xbf8a7b524426e458,
+                    0x56e3f78c479a33fd,
+                    0x0146efa225e0eac4,
+                    0x0a97a3724d9a5ba7,
+                    0xd7a749172942933a,
+                    0x0f8c084eede298a8,
+                    0x0054ded265352ef5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x78adb16870bc0c0f,
+                    0xc3dd149d413a370c,
+                    0x61219ecfb065327e,
+                    0x172134f4426a7aca,
+                    0x68e4531d21786d35,
+                    0x878e17906c792118,
+                    0x9ac66ef912caa409,
+                    0x43bb17cc8b1aaf9e,
+                    0x85aecb8c1f5aa31a,
+                    0xed8874e64ecb803c,
+                    0x68d6836b75ca0612,
+                    0x0031f03605e96c4e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50a001bee8938849,
+                    0x19fa899110db7d03,
+                    0x1de125b29eeec4d5,
+                    0x40a13ff656792828,
+                    0xa51aee11f98ab64b,
+                    0x70d8c23f04db61ec,
+                    0x1dc8962430504052,
+                    0x5c22827169703161,
+                    0x8440ae4040a6855a,
+                    0xd2e8697503204026,
+                    0xf7443812e098bfb3,
+                    0x003463735f29fd71,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3bc2649a45890c0d,
+                    0xd4f0fdf4bc012a87,
+                    0xae513f7e6f3cb607,
+                    0xbb61f43e76086dc0,
+                    0x690a4d196f934ba7,
+                    0x6328c48c4a715012,
+                    0x965f4d726d43ffc0,
+                    0x1b3433169e196d61,
+                    0x677c8d06c60f4a0b,
+                    0xb8d1a40fc5ee7c7f,
+                    0x0d1b99994ba8d5ef,
+                    0x00dffeaf4bce9bfd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc7f51b1f62ce36df,
+                    0x8fd6e3b5d0adb794,
+                    0x338adbfdd2a7a172,
+                    0x8c064b810ad6dc34,
+                    0xa61bfa648a01c55f,
+                    0xdc7461c7b3a7116f,
+                    0xc83c340d2ef00203,
+                    0x37299a232d0fdb27,
+                    0xbc09ad5432cd6189,
+                    0x66c7326303fb4347,
+                    0xfb10c035ae531b49,
+                    0x0119ac7be86dc364,
+                ])),
+                Felt::new(BigInteger768([
+                    0x413ac6de724b56de,
+                    0x92b7b2c2a577bf50,
+                    0x4cc30c953ac1b307,
+                    0x59c11f260189cabf,
+                    0x296a221709e50d66,
+                    0x79f189b99b09f08e,
+                    0x79867ab7a74a9666,
+                    0x34f1e75aabde930a,
+                    0xc3c86ee6688acc8d,
+                    0xe4604c7871cbae36,
+                    0x2f482dfe2e9519c8,
+                    0x0119b536977a8361,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3f4868f3db629d91,
+                    0x5851e1ec3c867a4d,
+                    0xd427e89d02470fb9,
+                    0xdf6064122a4a0cc8,
+                    0xf9ddc7c7d815f030,
+                    0x575d3bf0ccfa2805,
+                    0xea16f8da9157fc9e,
+                    0xd4123581127ce994,
+                    0xb6e63ef9bd3d47b7,
+                    0x2ea31d2b06a5241a,
+                    0xa2f722d266c4a4a7,
+                    0x01099b04dfe28d37,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2e10aad1edab0785,
+                    0x2446f0d1454435c0,
+                    0xd23707770dc84cc7,
+                    0x54ac12b7e2d0e1c9,
+                    0xcd6003d8052c1ca9,
+                    0xd91b19dfce90fa74,
+                    0xf707203371df2d38,
+                    0xe56af13ece77f0ec,
+                    0x4a63d11861b462cf,
+                    0xe5ecee00ff113635,
+                    0x958151896bd9614e,
+                    0x00ffdc57b91ef872,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1f115982834ead3b,
+                    0x041f97583da47b2f,
+                    0xcc314412793e2de1,
+                    0x1bc91923efe1083b,
+                    0x1f2e1f855a379921,
+                    0x48a72962fe66d680,
+                    0xe8bf69e4f5e53da7,
+                    0xe7936221fe243483,
+                    0x36c91f697032e1cc,
+                    0xfe8e2e27b2ece20b,
+                    0x000740bd06370071,
+                    0x00156193d0d079fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x04be31a0a90f389e,
+                    0x70aa50479a1bfddf,
+                    0x8db938d3d0622f2c,
+                    0xcd434c517adf8499,
+                    0x147d5d3f73dc037c,
+                    0xb2049655f3859aff,
+                    0x90bf3e4b67d0412d,
+                    0x9aed258cd7a655e9,
+                    0x68a911b9586b0e46,
+                    0x0fc927c24ec6b5e8,
+                    0x50ff040ca141f8d2,
+                    0x011128bad265a6ce,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd388d6ab2b2b5f8,
+                    0x091605e31e2bd99d,
+                    0x4f2af97eaa33f072,
+                    0x25348a9ded7e3118,
+                    0xe1e055acf8b6160c,
+                    0xbb12ee1383d4eb54,
+                    0x668517e05fa1dfd5,
+                    0xf68490e5a465b3fd,
+                    0xd95177f2ec0edc80,
+                    0xd1127d85e9426533,
+                    0xf2037e6380818ad4,
+                    0x01069e7d35550575,
+                ])),
+                Felt::new(BigInteger768([
+                    0xac6c2f82d2c18e47,
+                    0xd0c97a78fbc4faca,
+                    0x74274831d230f760,
+                    0x8c0ebbdc41957419,
+                    0x6120dcb05cf14b7e,
+                    0x66c6189415bcf3c9,
+                    0x0d50ec4d548a4bcb,
+                    0x9a23e23d6a23d5ac,
+                    0xe6ec7fb63cab98c0,
+                    0x14de69a2cdddc58c,
+                    0x950160219e41e5a0,
+                    0x00ae4a70af31bc4c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf349a5dfd9ec9db7,
+                    0x52df127f59388df9,
+                    0xf86efe01452b381c,
+                    0x1f0646ad13b6a292,
+                    0x37367c2ccf5f9b27,
+                    0x57f29c570eae149a,
+                    0xeb191a8e4849b46f,
+                    0x7da1800a8830a4d4,
+                    0x5be25849e09c95ff,
+                    0xa52c27d4f308a426,
+                    0xa192b8f9c1fb9275,
+                    0x00d196c5d1af4055,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaf6e1580cec0f114,
+                    0x947db1a02a557ee6,
+                    0x69d4415b9d0fc83b,
+                    0x9f1c02ffea893720,
+                    0x6c14eff776167ad3,
+                    0x4b1f7e6e67df760b,
+                    0x769b2750da3db942,
+                    0x5f9ae704a26c176d,
+                    0x107b43130d9f0cde,
+                    0x62831515bf2d585c,
+                    0xa88eacb8d60f0ba9,
+                    0x0054ef421de58548,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1088d497cb768f3d,
+                    0x3cbe5d1a40724781,
+                    0x82809dfb0ca717b7,
+                    0x0d090c6ca6d71d58,
+                    0x9b6fc5e602d87638,
+                    0xf57500a419b6652a,
+                    0x481a2fbc18fd7b68,
+                    0xd1e9d42912796f4e,
+                    0x33af79425b808869,
+                    0x09b709d63a9f2952,
+                    0xfa273878b33c11ff,
+                    0x005ded9afbabfd0e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc0c1b4a7b83b1c18,
+                    0xcdc56e0f9b92b6e1,
+                    0x7f03517a18616c06,
+                    0xc5ba90593452880f,
+                    0xed9d0e62927917cf,
+                    0xcb839f28dcadecb1,
+                    0x767a285f999260b8,
+                    0x604b663867ef091f,
+                    0x91c35efdf8eb9cf4,
+                    0x88b86a33a67ab424,
+                    0x7d2f36868d95b589,
+                    0x009367941229be79,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3a2b488b4995450e,
+                    0xf26c1b4637c47c1e,
+                    0x90910937a30fccaf,
+                    0xa58df38b929b1c8e,
+                    0xc81101db27155731,
+                    0xf846424781895431,
+                    0x0cd3a15277385dce,
+                    0x97fd8733dc4c55c3,
+                    0xd6675cfa7f0b03f3,
+                    0x9607c6bcab7f0975,
+                    0xc19a740686173a39,
+                    0x00a0908345c8e153,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe6ac540746672707,
+                    0x4e2895d26912bebd,
+                    0x8d79ee3c53a196c8,
+                    0xc0a3f0f8a735849d,
+                    0x5f0389b0451f8d00,
+                    0x30910efc302320c4,
+                    0xe2ef0ae722b313de,
+                    0x6ee88ed31831ce6a,
+                    0x0b63911eaa1f6765,
+                    0x683808d838799a19,
+                    0x32405f7d2b16a60d,
+                    0x002b4ebe7d79a26a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde461e232307a103,
+                    0xddd6a7b016fd7fa3,
+                    0xcf935ef9a6c6cbfc,
+                    0x165ecb8d783bed0f,
+                    0x7826149bf0161541,
+                    0x062861da91b90113,
+                    0x9238a11ceed824d9,
+                    0x17795f45785e81e9,
+                    0x08573f3e2e1fff8a,
+                    0x6295b35be0d68b56,
+                    0xa2b873a56237c6c0,
+                    0x00060ebf27b36bb4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa3f04139d9a5dcc,
+                    0xf31b16e8c6845ffe,
+                    0x0f01179996ab8913,
+                    0x1ac710cd4a76f524,
+                    0x66024315f28dacb4,
+                    0x2493245dabbce55f,
+                    0xab2e2d2a1f763464,
+                    0x98bf5622bc91b388,
+                    0x6e32e11e0fedc5d5,
+                    0x8e679aa7cf67554f,
+                    0xecd73c1deb76fd0d,
+                    0x0074ab655f5f73bb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0bf7d7e2597cad93,
+                    0xcb63079be81bd2ba,
+                    0x7892dc5c59e5b5b2,
+                    0x4a70d65f24f114ad,
+                    0x68014a8de36ac3bc,
+                    0x2d5432ef09b58b1e,
+                    0xb88f1f27f30cfc10,
+                    0xd1a9afe82983b0a8,
+                    0xae4d9715dc18144f,
+                    0x694e0ab9e47a69b4,
+                    0x9a451ff913f40987,
+                    0x00a678a5a7a269c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x83afeae936a12f40,
+                    0xb3b93ad181044e34,
+                    0x2064567595f6ca64,
+                    0x7e895c0e443957b8,
+                    0x32ade91b02b0da60,
+                    0xeffb0a645bea0bdc,
+                    0xeace1e0c411abd90,
+                    0xf45cc0941ba85f1b,
+                    0x641b43b8f17bc225,
+                    0xdb333a479d13e0ec,
+                    0x36cb373f8eabad27,
+                    0x00742829999bd0df,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc671fd2f5e89835c,
+                    0xabb1c49be52032a4,
+                    0x9901befeb2778abd,
+                    0xba91f143a16b96e1,
+                    0x5df79b5e50464130,
+                    0x9097f9b21b6c6f1a,
+                    0xac75b24b29cb1f62,
+                    0x4d9b15115966747c,
+                    0xb2904740235301d7,
+                    0xff4cac5714e207a1,
+                    0x319d5c2ffa989529,
+                    0x00050d2114663a79,
+                ])),
+                Felt::new(BigInteger768([
+                    0x419f6d1c93abd7d3,
+                    0x877a1c23509cb6dc,
+                    0x2b4fcacc2ffcc8b3,
+                    0x12c142285c84d2fb,
+                    0xac4b55968b14d510,
+                    0x2adf2c8c15641595,
+                    0x3ae70b1a644a0c96,
+                    0x37425c7c45321a7b,
+                    0xe7eef553210c1f38,
+                    0x5c78fa213ab49631,
+                    0x80bb869acfc2a9b0,
+                    0x00a3ce97f9b8767d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x643be6eefff4c0de,
+                    0x00b97788bf134d37,
+                    0xd499c2832b570f1e,
+                    0x3dff855fb952323c,
+                    0x58b893c532651bb6,
+                    0xcea6aa731d192689,
+                    0x8c70d57e6d2f7c10,
+                    0x88bd1f3b3c164a01,
+                    0x15f53a8a8c4fa1db,
+                    0xb2b832e3c6d993b3,
+                    0xe1390f25d976a4ce,
+                    0x00b8bf3e71080791,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c887f4670a58d40,
+                    0xdebec5464f60d0f8,
+                    0xf4482b271c22ac82,
+                    0xfa7f28f15ea7364b,
+                    0xa878a6cb1256a572,
+                    0x9e51a38993b1709a,
+                    0xafd95ef6c7757926,
+                    0xe4b9b00a1dc25a88,
+                    0x5f16cab1b772288f,
+                    0x99d2c2435aad611a,
+                    0x59c0d87723bc5071,
+                    0x0080868dec75ad67,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe865681816b01c14,
+                    0x2254d2ae6db8240a,
+                    0xd4069234c2299b7a,
+                    0xa4035d68cc931518,
+                    0x41635560eebcb90f,
+                    0xbe50801d412e165b,
+                    0xce21bebba8305d60,
+                    0x62fd91c08b74e70a,
+                    0x1d7b7397c6d4967e,
+                    0xa57c5e8f4a5db644,
+                    0x2a1c407f07df5618,
+                    0x0025dd710e9ec8d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x216ad1ef15f3c071,
+                    0x5ea42eedec4bc519,
+                    0xc5ee4a1c2341fbc9,
+                    0x88a7fac7ef9f5259,
+                    0x27e198d3d704286a,
+                    0xed79c27987378ea3,
+                    0xba0c139b80b0fb13,
+                    0x221da5f28059ff76,
+                    0xbf37956fce4fb257,
+                    0x1de1269397dbacae,
+                    0x4b7cb910f0576be5,
+                    0x00801e557c678f1e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6130a8dc4764e385,
+                    0xe823cc6a3b9d352f,
+                    0xa0f703ca7249bf11,
+                    0x0e465c48611b1fc2,
+                    0x153696d9b5f3e1cf,
+                    0xc343e360c3c6f76b,
+                    0xb3abb37e321ce8c7,
+                    0xc0161c66b9c32f0a,
+                    0x9e959f4202d37e20,
+                    0xbd3b9a7ee3f012a4,
+                    0xb69189b20187505d,
+                    0x0107469f205b2e11,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a62189701151cd2,
+                    0x95dccec63cb228a9,
+                    0x629722cc4973e8bb,
+                    0x450baa3216f0b854,
+                    0x701da8118df4d2a7,
+                    0xf365bea0fca3e443,
+                    0xd9406bb5c01c21ec,
+                    0xd426d3b6545b1eed,
+                    0x5beec18b6d224c4b,
+                    0xec8b50a13609466f,
+                    0xf95baee227adbc06,
+                    0x00e2722ae129436e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc3677cb9a4704191,
+                    0x07c837d8de512954,
+                    0x4e6ce7533c7523ca,
+                    0xe22aad578a95a96b,
+                    0xdd61ebaecd7ffead,
+                    0x7ac1ffe742914f5f,
+                    0xc3979bea4926e06c,
+                    0xac980fcf2867f1e4,
+                    0x132b632cdb1f75bc,
+                    0xd0e6f78208b8f15a,
+                    0x386004ae2f6cac1f,
+                    0x00cd421781dc76cc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdcdd626724431f9d,
+                    0x9abfd4cd1928cbf0,
+                    0x44e7c5e5a5500edd,
+                    0x7273913c92a60daf,
+                    0xd0e7f76c8b3def2f,
+                    0x3fcda9e52c28cba4,
+                    0x69a8bab0d83d776e,
+                    0x4c4b08ad9842fb2c,
+                    0x38381974726cb6ae,
+                    0x5e24db5a147479be,
+                    0x70a799aebba7fe69,
+                    0x005e4ee9ec93deba,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,611 @@
+use super::BigInteger768;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger768([
+            0xaae0ffffffefc167,
+            0xe499e1712ff0b213,
+            0x49ebe6d1127eeb90,
+            0xa48c17d0f0b3c9c4,
+            0x76498b7fda7478ef,
+            0xe3c1b33942cadf81,
+            0x160b7082fbac579a,
+            0xbca9f7c54096309b,
+            0x7ae19b95a2d6f02f,
+            0xb94385861fe6cf83,
+            0x468e01aafead9672,
+            0x00a8b58f2faaa255,
+        ])),
+        Felt::new(BigInteger768([
+            0x612d1a3cf93c09c3,
+            0x02700df3b9979ffd,
+            0x803d481f0663068d,
+            0xab06fce1abe17024,
+            0x299957b13c3e1e4b,
+            0xa0b3a5dff4009ae0,
+            0xf0d8566a3e1ca175,
+            0x52c0d8a6f5fd6cb9,
+            0x6f8d835e6de73fb6,
+            0xc545c86abf1e4de7,
+            0x9f274d9f661b6470,
+            0x00896c63a08b5825,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xd9fdc16fc7337108,
+            0x2bdecc643f94d863,
+            0x050eb307c69559b4,
+            0x1b1b4c4a84c1c2ff,
+            0x9e739df3ed5be7f4,
+            0x26bf184b252f0902,
+            0xa8846c1670f7859d,
+            0x86de6a5cfe75cf28,
+            0x82d6422754a34400,
+            0xe848c14ccdcf9d1b,
+            0x2a53b6eb1e9f47fa,
+            0x00c815a73e2b0ac3,
+        ])),
+        Felt::new(BigInteger768([
+            0x9ad1427a13241a29,
+            0x43f53b83d119964a,
+            0x7fc2c52ed666a2e6,
+            0x3d8d4969060fd215,
+            0xf9b80b7b221be589,
+            0xe273ccb47c1e7e22,
+            0x1d6ef4d2c0b3ca12,
+            0x4a50aa323f70463e,
+            0x34c5dadb33c28b69,
+            0x9dbf3b70ef2604d1,
+            0x85e228a6474af7f7,
+            0x0030d72d470fc00d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x790a228eb18f81ed,
+            0xc5cc55dcc163b1d6,
+            0x611360bad7f25451,
+            0x08f564b96198b6af,
+            0xadb91803b2eff750,
+            0xfb494673a466ba88,
+            0x2f699016985006e3,
+            0x3198ed23c4cc953b,
+            0xe671e8184de1bb36,
+            0x471ccc05413f6984,
+            0x0d9941911cf496bb,
+            0x006f6dbc446003ee,
+        ])),
+        Felt::new(BigInteger768([
+            0xd474cdeb6f9a29f1,
+            0x59ba32da5e56375d,
+            0x458f57a25604a355,
+            0x5ee537e0b3ddedf8,
+            0x8396e75f29b47524,
+            0xa4ee36ead0ea8e59,
+            0xd1022e61246f156d,
+            0x8c4e287da0f317fd,
+            0x890b7e89ecd9f2e4,
+            0x17ddb65f9cf10387,
+            0xb2a663a7757de8e8,
+            0x01229363bdce3f43,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xf5c0c593afc99597,
+            0xea14576c69d253a5,
+            0x1630d229aa9fb72e,
+            0xab8b1554e6fac3b8,
+            0x442620bd39a8906a,
+            0x6e4ca2142b847138,
+            0xd8d8653450fd7a5b,
+            0x673ec576e3e83111,
+            0x72101f77c9bf0195,
+            0x5b9578f2f475ea2f,
+            0x089f830f8ce2d7b9,
+            0x000c93212d33a0f8,
+        ])),
+        Felt::new(BigInteger768([
+            0x612493648a765d8d,
+            0xdb5d96d0c2d661c3,
+            0xebeb5de72f997e35,
+            0x616807d9e42f1707,
+            0x4cd5f37d1bb20d26,
+            0xe56efb13ef61f65d,
+            0x8c92c698890863bb,
+            0x03b73f506ed95a6a,
+            0x37cf5e658fdf8fc0,
+            0x10ec4a8e2dfcd750,
+            0xfc5de643b686fe46,
+            0x00954d619547156f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x9dcce12fc3b230e2,
+            0x482327e32463c42e,
+            0xb0ee98c5401da783,
+            0xa63bd340162fdfdb,
+            0x7a1deba7262a02aa,
+            0xa9dece19ce4edd5a,
+            0x8bbfec540a6c8a20,
+            0x3a9cf3b931fcbd42,
+            0x247d75213e7949fa,
+            0x8ffaf3c8e3096319,
+            0xbfef5223056ac774,
+            0x00bebf400fcc5b30,
+        ])),
+        Felt::new(BigInteger768([
+            0x415afc126ce13156,
+            0x5bf8b93c13d6238b,
+            0xf1b428c0459468cc,
+            0x1983c4aa10bf9014,
+            0x3050199f357de82a,
+            0x22c2ad1aa77cd47b,
+            0x04def0d1bb0d21a9,
+            0x9955e7666a36c511,
+            0x0b70568286ac0228,
+            0xdf33eac6e07a2abc,
+            0x6e35a03f9370270f,
+            0x00350230674fc368,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x84de48ada811211c,
+            0x62d41b2e34bf5732,
+            0x752cc0aeeddde33a,
+            0x35f558ab68ae90a9,
+            0x8365e1f81965ea09,
+            0x5c0936dd959ea0e4,
+            0x5e3c827c2a6640b4,
+            0x7ac92d089c400ef4,
+            0xd42b15d1aaf63e8d,
+            0xd3d0a0db57e67772,
+            0xfdb7189efd805687,
+            0x002efa6deec9da0b,
+        ])),
+        Felt::new(BigInteger768([
+            0x181142fce22596da,
+            0x2f49ce59079992a2,
+            0x0e53812e39eb211a,
+            0x5e6cf260ff26a95e,
+            0x661cd97a57cde18c,
+            0xcf9377e747c8b0d6,
+            0x39f72d5618aa3033,
+            0x4e087cf79b62cbf5,
+            0xf2bda575128302bc,
+            0x2c02840ae73fa6e2,
+            0xd6ca691adee6fb2a,
+            0x004f314f9f005e57,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x54428e1d92d16362,
+            0x496b58bb84409140,
+            0x2014031d06bb1b88,
+            0xffe968ac046b9882,
+            0x1ecef239cac8a2a0,
+            0xe28331115412a7eb,
+            0x8f2e1f6435e6f2c8,
+            0xeb3373a022dc7924,
+            0x62dd8c5fe862b2de,
+            0x50b563787e138444,
+            0x252f1e3e85733abc,
+            0x008cb31e9b873b5a,
+        ])),
+        Felt::new(BigInteger768([
+            0xa0728bdf597ec91f,
+            0xafdec06e26f94e21,
+            0xd6aef65514fdaa27,
+            0x550a5a5995fc6dda,
+            0xa1da6b9047f3976a,
+            0x1dbb06ae9705313f,
+            0x688b7ce716d9e6a0,
+            0xe63c261809efb848,
+            0x581cbe07e3e41a72,
+            0xe644311819700b72,
+            0x4bad6e27f8739be6,
+            0x0035fe2a3756723e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x2a643f949ca6b0fa,
+            0xa12b3a449dbd0f89,
+            0x703acbcaab89447f,
+            0xf62588d4f50232a5,
+            0x7cb210affcea57ea,
+            0xdbb684c9e6d55aa9,
+            0x41f31182e6ff2e9c,
+            0x6036ce06c1691370,
+            0xcb93d3ab99d0dcde,
+            0x7fcff5d16012e67a,
+            0x47e2c320e94e83fc,
+            0x0031bf6d9aa59a76,
+        ])),
+        Felt::new(BigInteger768([
+            0x6b642884555b5104,
+            0x10c179b3defb2468,
+            0x950ead621c0112ad,
+            0x3f95b389c827b3bf,
+            0x2e6b1485ae986f3a,
+            0x65c089a68cc2db6e,
+            0x6533780715498d58,
+            0x337b9107e75de23a,
+            0x99b55ad588118901,
+            0xea2e8197700e63b3,
+            0xa43cf0cdc38292b1,
+            0x010a2c7df66964fb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x23b69f3ce571eb9d,
+            0xcc94ec6cebfc6af9,
+            0x395a4784850e55a7,
+            0xa531d84144eb2d47,
+            0x10597d0d8e8743a0,
+            0x12cd36ba1ddde408,
+            0x2c7f462200750349,
+            0x90d85e936e11b44b,
+            0xd6bc6cfe0d09d937,
+            0x1de368948b17027e,
+            0x2978b876164bcba7,
+            0x006aac39e754ef75,
+        ])),
+        Felt::new(BigInteger768([
+            0xf1bb5ec32c025b22,
+            0xea0550496cee7f9b,
+            0x62fdb383bc4b5f3b,
+            0x18bd6a615c2db4b5,
+            0x2c0e48f81602611d,
+            0xc5dc2378a133e195,
+            0xfcd04e94628e06b0,
+            0x2d267c609c24b3ac,
+            0x41bbaebf3f14078b,
+            0x222f58b60d8e3b39,
+            0x55b979d46e8cecd7,
+            0x0036d08480037594,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x054d4fb1836d76e9,
+            0x804114596826f73f,
+            0xc639c96b279e26c4,
+            0x33bd8b669b573f91,
+            0x3fd05d25c145ddd9,
+            0x685b06fd66cb9682,
+            0x3fa3971270e16e6c,
+            0x02dffee42a8997ed,
+            0xe08dffa8c10bba27,
+            0x200599ff5c918c38,
+            0x9a888595a3f4f1a7,
+            0x00d8c801fa610469,
+        ])),
+        Felt::new(BigInteger768([
+            0x50801d22b055d5bc,
+            0x99a1db9362b4186b,
+            0x3f44c6e1e8387099,
+            0x0c582ad88470a6d0,
+            0x78932e48e63bffb5,
+            0x350a2e0a071292c4,
+            0x3bf98c79a0ff0a73,
+            0x939283cde7eaea86,
+            0xe4c506604a0faf8e,
+            0xc72d94e52f3e9128,
+            0x2e067462c931dbbc,
+            0x00d8d7081d8a04df,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger768([
+            0xb193ffffffef840c,
+            0x1e7d5557bff07845,
+            0x8e32219b5e466ee4,
+            0x45710c08ccf23442,
+            0x33d00730af48d55e,
+            0x80f00293e271f2bf,
+            0x67cae5e35728e29b,
+            0x161598a7f0eb4c90,
+            0x0f4b8cdde6ebf54d,
+            0xff72be82d286ad57,
+            0xc99e2c68cbe2ebc2,
+            0x00403d3c2a7caf8e,
+        ])),
+        Felt::new(BigInteger768([
+            0x5c3ff79e2cee7224,
+            0x1514222ca33b5845,
+            0x690fbabf5a309443,
+            0x9ac3cd6b7b57f280,
+            0x01bf08472303226f,
+            0xbc454852e8d30275,
+            0x3ada4b55d93668e5,
+            0x7d0ea707478f732c,
+            0xaba927e3b16df79a,
+            0xfa53b309aad08a90,
+            0x65d9f3fdf20f89fc,
+            0x008081d0788530b1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xdbbd1e24ca6628f1,
+            0x4cb73fe87174abc9,
+            0x4b8133813e3118ad,
+            0x04c4bd6d65567ee4,
+            0x19bc660995f1505a,
+            0xc12a6a2f1f69e9e2,
+            0x2390ea56ee70488f,
+            0x7a85417a1d245d23,
+            0xc969e11e9531c619,
+            0x108bdc396f35bb47,
+            0x16ddecb28b336684,
+            0x011df9c25c90a567,
+        ])),
+        Felt::new(BigInteger768([
+            0x90f07c904a0977ce,
+            0x3d8e4f5a5c9d5bc6,
+            0x6ac17d7e56086c42,
+            0x760e96ddd9dca5d8,
+            0x8fa00875dca1f5a4,
+            0xfb4271b0cb84b359,
+            0x90bdf29e7dc9c974,
+            0x0ed9aeccff5bbeb5,
+            0x230b2d0f73c2c049,
+            0xb4e107ffc99e81d3,
+            0xb60eda0e729de6bc,
+            0x00e64908429d2603,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xbee3f32bb2b14db9,
+            0xac2db8201ebf59b0,
+            0xc6b3c6f2c6f8f2e0,
+            0xafa307796c242dc7,
+            0x23cded6e1a534bbb,
+            0x5bef14063159bc5a,
+            0xd983c8b39cb8fa80,
+            0xa7ad4e5a91924a59,
+            0xd0dea2988cf88e27,
+            0x08e915f0d2893891,
+            0xf269bdb12dc5711b,
+            0x00f91dee042a2bbf,
+        ])),
+        Felt::new(BigInteger768([
+            0x1a117be9a46e9aee,
+            0x324af707a555d0cb,
+            0x39aefd016220a80f,
+            0xbbc9a0305b39d3f6,
+            0xa26e1dd22f1c7216,
+            0xfd6d8ea8a00ee6f1,
+            0x6f902b8a42c12274,
+            0x62c910f92970ce80,
+            0xc8a77657aadaba5b,
+            0x0f629982e57beae1,
+            0x0991922840129445,
+            0x00e8e9305f3c645d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x41a01cd5122e50fa,
+            0x97bb62e703908e31,
+            0x4e239f7c2e777b06,
+            0xdfc02325a6109280,
+            0x937b66a78ca7c7ec,
+            0x34dc4df236747754,
+            0xbd133402465c494a,
+            0x0dcfa6e66d92c1a0,
+            0x6546e7cd9d647dd8,
+            0xce06bb869be192d3,
+            0xff2ccb0e4e932f1d,
+            0x009ad8bda1e12fde,
+        ])),
+        Felt::new(BigInteger768([
+            0xacc6c807208dbe21,
+            0x7b34043546388de1,
+            0xb25d6a60d086a838,
+            0x4bd3db3a401554b5,
+            0x44ed9a700cb5ed2f,
+            0xa3d8311d3c835340,
+            0x654159f298504c15,
+            0x0aaea804b43bec5d,
+            0x80356408e26f0088,
+            0xb91626598ca99841,
+            0x6505e0a331fb26a7,
+            0x00603898eb98a19e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xd2449d72cbe356fe,
+            0xde885cbd79cd41a0,
+            0x8fc35ddf7e1241ca,
+            0x99461e76687f1561,
+            0xe66a7dacc264a951,
+            0xc98d3e4401daf3e9,
+            0x4fff9015ae693c06,
+            0xffc23d8fdfef1bc7,
+            0x79e40fd5ffd5591c,
+            0xadbdc4ef2ada34e0,
+            0x6f0410bc841bf50c,
+            0x0072e8157f34d108,
+        ])),
+        Felt::new(BigInteger768([
+            0x6a3295b6a8c4fd2e,
+            0xcb1e8e68c2e39313,
+            0x751525b08b8f0d76,
+            0x5b65ec325646dd78,
+            0xb73be089e3a93686,
+            0xc0d4705d30343f61,
+            0xc161141e9ea70ffe,
+            0x2f5d5ebab9660e12,
+            0x0888a4744779c412,
+            0xebd56d8f615d5b59,
+            0x60ecda79d0e024e3,
+            0x0048b8c5b3e00492,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x73b756ce26518e4b,
+            0xa48266a6aba933fe,
+            0x61b08ceb5cbeed15,
+            0xb7666c9dba693f55,
+            0x957a44ddd3525958,
+            0x9a7034922dbf05ae,
+            0xecbdcc3aad7129ba,
+            0x8c4cecf0f4aa1011,
+            0xff4b7de864aad513,
+            0x7a82f0d3dde13730,
+            0xc283c8b1b45720e2,
+            0x0117a78e620288f6,
+        ])),
+        Felt::new(BigInteger768([
+            0x06ad2e7e9418a93a,
+            0x63277bbb68276101,
+            0x89568c91c5e19120,
+            0x9614cbe2edb1c6ef,
+            0x20f39d68afbef962,
+            0x05d3ffc7221a6c67,
+            0xbcec3ba0b59e32a5,
+            0xbff2c424af84ce76,
+            0x730d4ad94b218dc7,
+            0x086d6d3b247b7eee,
+            0x0db1cb8e4f819682,
+            0x0074840af3dd0a8a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xba0b9c463c462f18,
+            0xbaee3370626a129b,
+            0x9801d97600c6df8b,
+            0x1a01d00975fc3238,
+            0x8be92a5e62d157c2,
+            0xba42f82a7fc2b614,
+            0x776de52b9100bf44,
+            0x3fc3b2c306e6c070,
+            0x95203299c0262178,
+            0x3ce5b2d34d656fea,
+            0x42daaeab18eb7dc0,
+            0x00c3308dfb292717,
+        ])),
+        Felt::new(BigInteger768([
+            0xfa9b776936a63a91,
+            0xfa223b755ec6c192,
+            0xf3290484170f788d,
+            0xbdfa9e08fac51f6e,
+            0x2993d899f1ecf440,
+            0x73de20e017e093c0,
+            0x490dc239b190ef8b,
+            0x0bae92b88f36ea11,
+            0x3211177ebb193bd3,
+            0xc153321521d455ee,
+            0xacfb7a354aaaaf26,
+            0x00cc09597420294d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x93b86200f0391517,
+            0xcc7c1c697d5c964d,
+            0xe3923441faed0f5d,
+            0xd2047a952aa415f6,
+            0x10dd14c68bd21593,
+            0x2b255c9553c13b37,
+            0xe4424b186dc3a388,
+            0xdb53462481549006,
+            0xe4ab6c43ccb37491,
+            0x1d5838c05b7af1eb,
+            0x9ebe0b1c49040fc9,
+            0x00eabeb25bbb629d,
+        ])),
+        Felt::new(BigInteger768([
+            0xd47b2851dca05a52,
+            0x2e41bdc2a83e9cbf,
+            0x96e55500887a43b6,
+            0xd1ab6ad99a9a05f6,
+            0x6b5879a4db84d569,
+            0xad08eb9d3be012c2,
+            0xfbf67628b5f71bae,
+            0x0efe906a63016034,
+            0x07fc30bb39de153a,
+            0xbd6f5dbe22b78772,
+            0x6d32eb29dcfbef7b,
+            0x00ffd15d99232a6a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xee331fb7de22fe18,
+            0xf5e901d4574ecc11,
+            0x0aadc65724148034,
+            0xa6e7f656318c7f92,
+            0xe71b8b137de399b7,
+            0xd3bcf6be5ed1c9ab,
+            0xd2ea5f0473290d72,
+            0xc721bceff425db64,
+            0xc44b690834cdb92b,
+            0xefb1a36fe2c38306,
+            0x5904d920eb2dd2db,
+            0x0055d158cdff1a91,
+        ])),
+        Felt::new(BigInteger768([
+            0xb097bc9f58661359,
+            0xec1a060331e4d2ca,
+            0xd8dd6a2c6357942b,
+            0x694b64c83c071edd,
+            0x1d6f8be3174f5ee9,
+            0x052f369537531b90,
+            0x9b7de70214df4d4a,
+            0x3452083ac375c543,
+            0xd6fc5e0666499a46,
+            0xe2dc45339e4d1a96,
+            0xc8e81620022dc447,
+            0x0081836343d56c02,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x8733a630e79c215e,
+            0xd51848a1ba8c4393,
+            0x2fae8ca270ab3c98,
+            0x4c9a7e0d423d2000,
+            0x0cf25a77560c22a0,
+            0x67979328098c1f77,
+            0x3fc7f9e8c2c241b8,
+            0xf56224a26ed03a06,
+            0xcb79d1f33e3ec970,
+            0x59f25b56574a6be0,
+            0xf8c7257bccde4a86,
+            0x00d798579b9d781b,
+        ])),
+        Felt::new(BigInteger768([
+            0xd229510348372562,
+            0xe0a871c59ebd5652,
+            0x3738c9404e5aec98,
+            0xdb6be30ec826f624,
+            0xee778ca31906ed02,
+            0x2c20445fec04727f,
+            0x3091b3dc0cc8f72a,
+            0xe67b30d0e7e98e03,
+            0x24e015f8462cb35d,
+            0x36d2ef73e138891e,
+            0xfe5fc6a9abdf0599,
+            0x00144cf8a06a75d8,
+        ])),
+    ],
+];
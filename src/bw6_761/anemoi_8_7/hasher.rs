@@ -0,0 +1,2902 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger768, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger768([
+            0xf9834e1e1d6281d3,
+            0x7b2ca90f83462a23,
+            0x7c5540183bea2c85,
+            0xa578977cd61abbb4,
+            0xc3ba52449143cd53,
+            0x3799b0127afd9274,
+            0xd98304df20d1f5da,
+            0x9f76597f43253aad,
+            0x69d85033bafbcb45,
+            0x198bbd675dbbce8b,
+            0xb86af3fa38c26224,
+            0x00d751c689080a49,
+        ]))],
+        vec![
+            Felt::new(BigInteger768([
+                0x6feb9afefaccc786,
+                0xae7d4546ff948e1b,
+                0x96528bfe0f8d7a7a,
+                0xc9df35de13dab5fc,
+                0xd371ff6041d6bce9,
+                0xfdb39d7df9ee9896,
+                0x32a78d3191cf93c8,
+                0x5ed71fe167a72336,
+                0x006e66084c854629,
+                0x4a49ee7e4478bff1,
+                0xfc0777d9e0998ccf,
+                0x00bfb278cdfdb3d4,
+            ])),
+            Felt::new(BigInteger768([
+                0x62ae9ebe9977e3d5,
+                0xce85a9abad78d91e,
+                0x4f752937f3fa91aa,
+                0xa0169b8d6a5730fd,
+                0xbe6fc94725cb57c8,
+                0xd0bacde22c7b36a6,
+                0xb5cd5af349aa32a3,
+                0xe86589210f09f628,
+                0xc6434f77d3c0541c,
+                0x29e983ec9e5538e6,
+                0xa0c05c499088d87e,
+                0x00d4d7c0258cb797,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x78c782f24ca50320,
+                0xe178e14bccf443e8,
+                0xc5fae8a14c5f93cb,
+                0x1ce06a070a12ed52,
+                0xfeec6d4cb5a6004e,
+                0x1b0891902d051070,
+                0x7303d3f4887ab7c1,
+                0x9257449c1044f501,
+                0x0f3bd56bc4e90a5c,
+                0xdbdb752f5986f29f,
+                0x145cb68a57054ecc,
+                0x0073c76882349fd7,
+            ])),
+            Felt::new(BigInteger768([
+                0x8b1b6fa6d92985e2,
+                0xa400b3ce963f49ff,
+                0x27ac49cce9c57cea,
+                0x4846788c17d1d9d9,
+                0xc34869f8257ba37b,
+                0x0c44735f81643d25,
+                0xc6fbfd805dfd5329,
+                0xb954e0e9ba93f829,
+                0x28b596a3b1cb1259,
+                0x5bb0db0efbc04e5c,
+                0xb302f60aedd5b48c,
+                0x0035e6dcb6262113,
+            ])),
+            Felt::new(BigInteger768([
+                0x028b50296a638b18,
+                0xa76ba589d8c2b761,
+                0xf3b812614a31eb2a,
+                0x034be933fb54c49c,
+                0xb9cd61a7e66adb4e,
+                0x9e76d3b63d3f6e2e,
+                0x4e9d133d88eab3a9,
+                0xd796af73c9ecf46e,
+                0x88622cfe35af9d89,
+                0x33539ec4928c7c8b,
+                0xe0ecc403b6e5b1d9,
+                0x003a4cea5c7031bb,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xb324a28054b5d981,
+                0x5021255f68f2206d,
+                0x90a99e88f9194281,
+                0x7788cca26a62c419,
+                0xd5c93e2e9ef34ce8,
+                0xd25c2ce8ebdc23a4,
+                0x1d2cf10c64cd5bdd,
+                0x5de0dc2a319cbe81,
+                0xf9ab6aacd540fd10,
+                0x178ff4fcb5789093,
+                0xdccc0a777285ad10,
+                0x00a7869333ddb460,
+            ])),
+            Felt::new(BigInteger768([
+                0xed95dcf00e880589,
+                0x1ddd180ac2dca7cd,
+                0x61e69ca83bfc0eb7,
+                0x5d92697a53125903,
+                0x4f05d96e70812b41,
+                0x574d932bbf7c36e3,
+                0xc65e992adfb34dbb,
+                0x1287a0a87dd67f5b,
+                0x8526c55f38d97bff,
+                0x2ae2c45613011e04,
+                0x397a8ba33624e3ea,
+                0x00e1542371c6ae6e,
+            ])),
+            Felt::new(BigInteger768([
+                0x9062250853bf2bf5,
+                0xe291a684b544e74b,
+                0xe490c9678173e88c,
+                0x95d5df8caf145170,
+                0x2bfc68851ea86851,
+                0xfdd1899a60e68864,
+                0xb7bcbb95493ace29,
+                0x979ece9e9ae8df9b,
+                0x10c947cf656b2dd2,
+                0x729090d4472384de,
+                0xd9f1bd81869ba326,
+                0x003d2e1f5d322dff,
+            ])),
+            Felt::new(BigInteger768([
+                0xc7b7a20d56f6bfa8,
+                0xb3af38f36c7b6226,
+                0x17eb11b3d4013cfe,
+                0x501424df051de889,
+                0x05b005ccd708adf1,
+                0x1482e7758df645ff,
+                0x7f62adfdfa344f9c,
+                0x6907fdfed0795ec4,
+                0x49f23128eb581297,
+                0x34cc6a8ce388128f,
+                0x2a11bd813930a72f,
+                0x00adfedf222e758f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x714144f161ed03d2,
+                0x0ee3c23a6e0d136e,
+                0x4b3250f9f85946e8,
+                0x28941e2351ffa4f0,
+                0xdaa4bfafcef40fad,
+                0x2a7d53aee7d7b790,
+                0xf95a5d50dd5e2b33,
+                0xbaac1ff14230d60a,
+                0x6520165d83225e4b,
+                0xe427703c78d953fe,
+                0x6ffd77b76b1192a5,
+                0x00fb76e2bbd17f31,
+            ])),
+            Felt::new(BigInteger768([
+                0xf90e70d46713b59d,
+                0x83e7393d3596bcbd,
+                0x7384295786ed7ed1,
+                0x4b91be0e637ba3e2,
+                0xab97f4a38988e56d,
+                0xb627b70c4f21049f,
+                0xd30cf37255d6060d,
+                0x828bcdefc377e977,
+                0x36f5bf8f8ac4fb98,
+                0x5e480c1e649b5174,
+                0xe1fc941eb38fcb02,
+                0x00631a14cf572a22,
+            ])),
+            Felt::new(BigInteger768([
+                0x540a44140d64d345,
+                0x6b1537e383bf3343,
+                0x95ed608046fe9a2f,
+                0xc21a18cbfffdd7c7,
+                0x459c5fc0eb96763b,
+                0x5afdb11586c1c8f8,
+                0x7fd439994dc14600,
+                0x5fe79c61090e2a02,
+                0x6bf8fd8af2658895,
+                0x95f8bdc242168ca6,
+                0x740485aae078d9df,
+                0x00d67833dbf2940e,
+            ])),
+            Felt::new(BigInteger768([
+                0xa8567663d5f2147b,
+                0xb89e044b1e80a0fd,
+                0xb31635d8c4a59abe,
+                0x0c61d0938c25b689,
+                0x93bd8776de0f5539,
+                0xa108d0c223df73f8,
+                0x77489926950aaae1,
+                0x7b2d227ddb58c7b1,
+                0x74f417a2b5ad0edc,
+                0xe26ba065bfd7ba0e,
+                0x254012bdfd997a5e,
+                0x0052c30c8c656c56,
+            ])),
+            Felt::new(BigInteger768([
+                0x52a80fe88c916d6d,
+                0xc770332c40e8bace,
+                0x81b117b620a87ded,
+                0x7889feaa3ba583a5,
+                0xb1e8f83180991f75,
+                0x7b86835cb76efa4f,
+                0xcbe900b61b80f281,
+                0x4035c9542178f35f,
+                0x2aefec179e5ae856,
+                0x74346ea485e5ec18,
+                0xd0c53e3fd124396c,
+                0x002f185727720993,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xd97925e1a76f9535,
+                0x795af21de2e67f51,
+                0xa41eb180aae8169a,
+                0x20e8b14e5fd7b707,
+                0x3197190e011d11c4,
+                0xd20e17d515b0ed88,
+                0x955d28592491f116,
+                0x30f9e30328d0f91b,
+                0x38d876bf7d0edd50,
+                0x3e98b1f5e7e06d2d,
+                0xfa3ea14bdebaca70,
+                0x0107ad3c3ce1c714,
+            ])),
+            Felt::new(BigInteger768([
+                0xc74e78db4ce7662e,
+                0x45a4af7c4e0fc9bd,
+                0x77d4924af373b59e,
+                0xc21ba76fd2aacd29,
+                0x67e89e4c13788eae,
+                0x18e92839723863be,
+                0xc528e91fe28a4641,
+                0xe5878e271367d868,
+                0xed035a9273d35ac8,
+                0x569d19c86e698006,
+                0x1f2ec01b60927514,
+                0x0020049f5e865ce3,
+            ])),
+            Felt::new(BigInteger768([
+                0xe8d711897f2869cb,
+                0x516c2e3ab0df7bda,
+                0xdc4fe6d074c32128,
+                0x7340f3f31cce16d1,
+                0x7c99640c12e77a17,
+                0x7e0bfc29d8b21f1a,
+                0x10c87efde3fa3bfe,
+                0x9185b4fc6bcd9fa5,
+                0x8cf80ff207daab28,
+                0x6d0f60dcaeb7a810,
+                0x2848cd4ef20d7441,
+                0x00447ad5330e6975,
+            ])),
+            Felt::new(BigInteger768([
+                0x4918f26b663e0ea5,
+                0xe83da8b4c49d7b86,
+                0x5386bf3df5e23ff2,
+                0x16da705f63f83c50,
+                0x7d7696d1d4681d4d,
+                0x39747e05c851f47b,
+                0x7a5aae39cda6727a,
+                0x5a33e90d43c1bb99,
+                0x22d0b0f5349bf89e,
+                0x7a8b0aa17c2b0689,
+                0x5f150eaf05ecbf1f,
+                0x0056de9ee32973b1,
+            ])),
+            Felt::new(BigInteger768([
+                0x038ceda3e13c4384,
+                0xd7f30034a22c5ec3,
+                0x0099df49730a0b30,
+                0x00e27b59e91b25ea,
+                0xa77b594fd72c4b31,
+                0x16a703dcd588e912,
+                0x3e901c0ea568fe01,
+                0x2fc013b1e6935c4f,
+                0xec9c09051735e6b0,
+                0xc22ce6adb8dac887,
+                0x9a6eb6244ce4266a,
+                0x0112456d242ecad8,
+            ])),
+            Felt::new(BigInteger768([
+                0xb264305b517bd08d,
+                0x84aa33f2dea3ea9f,
+                0x17d63ef4f8729c3d,
+                0xb09c8adb41b8de3c,
+                0xfff166b44c02f961,
+                0xfd1755235d658aef,
+                0xd70bcd091a4b9138,
+                0x95b565ff3661ea13,
+                0xa1bdff1af89929ae,
+                0x8b8e87a48ad438be,
+                0x1bf1cb3885a5c2c7,
+                0x01215bda2be777a1,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger768([
+            0x28c1716a9b783959,
+            0x7a6296a7f617ae32,
+            0xdf208c6d198bec91,
+            0x792e3880d6a41804,
+            0x0fc95244db18786c,
+            0x9229b1ce5663f4ab,
+            0xa89a5491b16e4c30,
+            0x4c2dbeaf09b8bd8a,
+            0x6bc050ff9177e98f,
+            0xe3e9201f2cd53bab,
+            0xbd77869957039573,
+            0x0117ed29bdf40413,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xace539be536b6a9b,
+            0x68c90bd75da9aff0,
+            0xe670b2df9c53e4ac,
+            0xa584e5343f4fd0f2,
+            0x38988bee9e42c04a,
+            0xc1744a82c115bdb0,
+            0x85b927e561690c88,
+            0x43719d5052e123cc,
+            0x82e9374dcba63cf9,
+            0xf5d993040d8e16db,
+            0xd5a6673a9a2a5cd6,
+            0x006c3eb5c654e665,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x0388334b1afe5fa5,
+            0xcf6cfa6d4a3975f4,
+            0x8537727166d39d98,
+            0xf7b0a20a232ca502,
+            0xb8909c86d5c66c65,
+            0x407aa4a5e3edb498,
+            0x83c1e041d64ef37c,
+            0xb15f03a142b1a46e,
+            0xbf5f59610eb2fbb6,
+            0xba3ecb5d55d62b42,
+            0xa90578b1b792ccbd,
+            0x0049c6577ac1392d,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x78056d4f0dba43fe,
+            0x9046d17266728ddb,
+            0x2cbcbe12a64f2851,
+            0xd0210c4ca966ca3c,
+            0xade72e5cf62c94e6,
+            0x22a509da83fc6a68,
+            0x1ab884e284990b79,
+            0xe06f91b0717f1e05,
+            0x3b01dccecc6b657c,
+            0xb527c3237a148b23,
+            0xdf9462633a99eb55,
+            0x00ce3454ede0af36,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x879f5040139761d0,
+            0xae6c1c727b6194e0,
+            0xa56a3140714e180e,
+            0x50e9d79a1975f996,
+            0xaf67e41eaa89bfd3,
+            0x0120be1f56b2d3f3,
+            0x263fdc959b510544,
+            0xa0d562a54058e34a,
+            0x19056d2395e3cf3a,
+            0xd45d3480f5dd182d,
+            0xb5b235438ad95922,
+            0x00b61906caa649f1,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x7c678a42ff4bc4f2,
+            0x203a45ffb4cb8e55,
+            0xc01a696a3c81007c,
+            0x2ff9c279750a2b44,
+            0xb069b9f9fdfe1f11,
+            0x24482eb7cedd0fde,
+            0xce50593726ac0977,
+            0xc28643195074ef68,
+            0x47407dd3551d5307,
+            0x28eb44865418b5ba,
+            0xf8ffb5499f842f47,
+            0x0109679dd68f25be,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xe5d246c8e833ff78,
+            0xeab4a1e66820820b,
+            0xa7b84ceb5e4fd245,
+            0xdcc97d58bd518043,
+            0xca92c4ba2c13b2bf,
+            0xf9b76fb10c58b810,
+            0xc8fcd726c23010b1,
+            0x37bd24c8b4bb5367,
+            0xe34668094f520b32,
+            0x36274a08481b4856,
+            0xe6451458e03caa3e,
+            0x0027801e0af90da7,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xb07491388551f05b,
+            0xa00bf90feaed42aa,
+            0x0616e689c69cb1a9,
+            0xf596c9b0ded76cb5,
+            0xfa135fd238f2c864,
+            0x2ac96522785a7b48,
+            0xab58027e61a94311,
+            0x1b65b9c577a8fc0b,
+            0x4a043918ab632ebf,
+            0xeff02691cc6d6856,
+            0x82b8db5140d0d41e,
+            0x003d9d0cc2b43bcd,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x6ba341ade3545083,
+            0x7ba29d9db9c121e6,
+            0x8aac0d8a1d2f5ead,
+            0x5d60bac30659480e,
+            0xddc23b2e259c296f,
+            0x5d98e1c7f69f322e,
+            0xe14b9966e202d9c1,
+            0xb77d62c48226ca67,
+            0xf4433c58af6108e6,
+            0x450849339c8c9c2c,
+            0xd2a9f8fc0414620c,
+            0x00bfb348c990dda1,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x7ceda8a06137a757,
+            0x7e8d1b0a9b098994,
+            0x15a5d5e56481d4b2,
+            0xd9865839bdb7c203,
+            0x802ae6878148dbbf,
+            0xcb865ccf187430b1,
+            0x8858e5506c3ada28,
+            0x2f50e5cad3fb8adc,
+            0x55e9e6815e5437b7,
+            0xadf3b1b34cf8369a,
+            0x2f3ee4cf9e3159b8,
+            0x0108800605cfed1b,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xd7faf66493cce67f,
+                0xfa5f4229e8d954ca,
+                0xa3c3511795c5c95c,
+                0x5e88df15d8678301,
+                0x0bff5e3b9797dd32,
+                0xc4d9295aed80288f,
+                0x04a2bc1663d77fb9,
+                0x7e966d50234da9b4,
+                0x87ec9ea6b08fbe85,
+                0xa3a505420aff0a3c,
+                0x31c6488c3de42bea,
+                0x0108d6a4fbd26abc,
+            ])),
+            Felt::new(BigInteger768([
+                0xf655e10df0c4ffb9,
+                0x2239ef5693150201,
+                0x59dc75ef3150602f,
+                0x2672070f9ab995f9,
+                0x10c0c4e3485585c7,
+                0xe4e631e6166c59e0,
+                0x1ed898551a0e1080,
+                0x4404318231b4e403,
+                0x174827af74fd284a,
+                0x8b4a901f3636e9dd,
+                0x7dc1252b1080da8c,
+                0x011b36ad1f5f485f,
+            ])),
+            Felt::new(BigInteger768([
+                0xbcc2361d52e21b62,
+                0xf3d5c597f8861ec1,
+                0x0064a836cb0285e2,
+                0xe5c8c897ca4011b4,
+                0xf5df6d930a486d35,
+                0xb3fc440ba4f91e5c,
+                0x9ccdedc6c9ae0125,
+                0x26f9e5ad79f0af65,
+                0xe9196987820bd406,
+                0xa04efb1a0736aa67,
+                0x4fc3e7f1045fa460,
+                0x00199be51ac463bb,
+            ])),
+            Felt::new(BigInteger768([
+                0xd615de0432914917,
+                0xae6b335daf396f0d,
+                0xc8ea3bd6dec456da,
+                0xb07d4b1ec49d858b,
+                0xca6e8314498ea89d,
+                0x77f5cf57e5f91939,
+                0xbcb55959bbda10ac,
+                0x4c1f134305300c59,
+                0x55eeb3df48eae5a6,
+                0x74a9890427199ce0,
+                0x6159dc7b011d0f80,
+                0x00bdd87ab70c7508,
+            ])),
+            Felt::new(BigInteger768([
+                0xd942bd8f90c00844,
+                0xcacb2567cb656b51,
+                0x95e1a3064dd55401,
+                0x6cee200c2249adb1,
+                0xf19a01137386c141,
+                0xe4e944a3d0fd8e97,
+                0x8f83ac93f2c57778,
+                0x84473a79ee75135f,
+                0x86c33b57bef6f202,
+                0xdf0ff712bb9cef26,
+                0x4eb5e24b8eb7e9ff,
+                0x005cf030de164128,
+            ])),
+            Felt::new(BigInteger768([
+                0x533f6ff870575351,
+                0xe3743282a2e29ad1,
+                0xdd6b669a1458413f,
+                0x0c5cf48a0c266804,
+                0x6a5f60a86e9edb47,
+                0xebccac34d3691276,
+                0x7fd05beb177f6ba2,
+                0x0761fbb85c416166,
+                0x5cd38cb0fdb21361,
+                0x4b44b096f33a8c7b,
+                0x4ccdc401daedf1fc,
+                0x0032cacc58845bed,
+            ])),
+            Felt::new(BigInteger768([
+                0x1dd040926b575822,
+                0xc2bceecb29afe13f,
+                0x45c9b3205e309dba,
+                0x3b71368230504559,
+                0x37ba20eaf515f6ae,
+                0x88c8a54e052326ab,
+                0x72be7c7e2a2a4e89,
+                0xc08bae678ff8f3a2,
+                0x8dfa031b872eedb2,
+                0x36cff512b45c1282,
+                0x4eb6a4aaf63da4e7,
+                0x011e0a3b557e7c96,
+            ])),
+            Felt::new(BigInteger768([
+                0xca52ed8fed00a4c2,
+                0xc840ed19ba34dbe0,
+                0x3051772f4cc94cea,
+                0x0e5ad48bb745ce09,
+                0xfb7a3edb2e0fcd50,
+                0xcaca00e069681735,
+                0x17d5853699fc7cc9,
+                0x2c43e9b200077ab2,
+                0xaddfd1c000439e6a,
+                0xc696129e09da4377,
+                0x50427dc087339e21,
+                0x0013644cf0b3af81,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xd48cfa734a245c35,
+                0x49d2471a21b60059,
+                0x8b59ea9074cccf18,
+                0x671453eac5122364,
+                0x7e61905ddcc5e3c5,
+                0xea6701b970097b62,
+                0x2e46f675cdf47380,
+                0x57c293b006b3fc2f,
+                0x6703b1514e9f5157,
+                0x5aab83e3728c1df9,
+                0x46829b59995ebd12,
+                0x00f8cd10628d0030,
+            ])),
+            Felt::new(BigInteger768([
+                0xb8eb460d68c2dd87,
+                0xda38b4fec54bd4fa,
+                0xc22ef4b6c9d89a9f,
+                0x92eb6fdb5147267b,
+                0x738e9a1d8098514b,
+                0xaf618ae3d95d9f78,
+                0xeadfc680c3946d0f,
+                0x348e237de1994a55,
+                0xcb92cf5a5f5f87f1,
+                0x3e167eda370935a4,
+                0x70214e5c3d551f1b,
+                0x00a5f155ea939cc0,
+            ])),
+            Felt::new(BigInteger768([
+                0x8167ae27d8eb1694,
+                0x98ceddc7a834c083,
+                0x8f8f6ccd27730e2a,
+                0xcbdc2613dcd372d2,
+                0x1c4a6fb7f4d3aa86,
+                0x6988cba83b0d4528,
+                0xa9798d8cfe0d6a9a,
+                0x294aa96a102e3463,
+                0x269c1715730fa50c,
+                0x96b878c5e73562a3,
+                0xefb94e9fa5117601,
+                0x0071140586f4bd49,
+            ])),
+            Felt::new(BigInteger768([
+                0x2b507ac1a7b0920e,
+                0x3c98a402bff6cc4e,
+                0xdd19bae52c119eb1,
+                0x425cfb62e738f9a0,
+                0x27439e98aa7510d7,
+                0x91e2b41a023dbcd9,
+                0x88959a6fd06330ff,
+                0xd75a8c99f69e2d4d,
+                0xbaaec9adae7d6353,
+                0xc21a876fcaef776b,
+                0x33d807e59d16fa22,
+                0x00def254957fb750,
+            ])),
+            Felt::new(BigInteger768([
+                0x0360abf3a68c08a7,
+                0xaf66578a3d0509cb,
+                0x543914bd891c59fc,
+                0x6cb47cdf1565bade,
+                0x2f77f7b096f0b628,
+                0x86caec2198c783ef,
+                0x593e144a30b959c2,
+                0xd09623dec1d648c8,
+                0x8e5f2ebd4d11614f,
+                0xe48d9f0827848be5,
+                0x4d2c593e7dd53a7e,
+                0x009f68860066fb7c,
+            ])),
+            Felt::new(BigInteger768([
+                0xb8deab92e5da028b,
+                0x6c8ee422288d7cfa,
+                0xeee23b6e313055f7,
+                0x19c297fb1cc94d4b,
+                0x4a5d0434f3b7d6b8,
+                0xf5aca204547d5bb5,
+                0xaddb1336383857b6,
+                0x6843351e0ae22172,
+                0x9aca991d6dfc7c29,
+                0xf9ae7bd16ed5edb5,
+                0x711e4a9ccd07da0e,
+                0x00063c9803becf3d,
+            ])),
+            Felt::new(BigInteger768([
+                0x7df43a5d4954d901,
+                0xfe3fde11afefeca7,
+                0xc97a4536c1858bf9,
+                0xacbbeb3caf50bfd0,
+                0x9a03d7a882ccfeba,
+                0x23ff8c1706d6aaa7,
+                0x3710e11930b291ac,
+                0x640e7db51d4db1ec,
+                0x9b745582484ebce6,
+                0xb1215b782e6b3e58,
+                0x45351c8767f75529,
+                0x00b4e293ac5f27ba,
+            ])),
+            Felt::new(BigInteger768([
+                0x4f901fd1cf321822,
+                0xe1ab69594fde1761,
+                0xcb9fe7103ce6acf7,
+                0x9069011e16b71202,
+                0x48ec4e0fcb77feaf,
+                0x4b627c634fdcaaec,
+                0xacf38398e90783ef,
+                0x74b2d8e89cd9b75c,
+                0x86cf24ecbbdaf3d0,
+                0xbfdfaabd32641b6c,
+                0xd0971233207cf16d,
+                0x000edf29df71e582,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x6f93fb112cbdc92d,
+                0x5f44099380f0e948,
+                0xee0c27ef30ad119d,
+                0x35e515e9c201214a,
+                0x3c39f6cf63bf9254,
+                0x41c7f8e7879688b1,
+                0x9cd18dddc7a22ebc,
+                0x00c74d9dc23c7c7d,
+                0xbb523935236c4fda,
+                0xd05efa6b05b2ccd4,
+                0x278b04727c71a030,
+                0x0104a0e2520a011f,
+            ])),
+            Felt::new(BigInteger768([
+                0x5bd87b92bde37942,
+                0xc4c4bbb70140a84c,
+                0x12d76794c19d5e95,
+                0x258f7a3f10916999,
+                0x2339e131015dbd62,
+                0x0ded8424e442ad70,
+                0x6a82b9d5dc3baaf6,
+                0x984d375ee4565cd2,
+                0xc011005793f14ac8,
+                0x2f1d530996d8a799,
+                0xc964c1876a0aee71,
+                0x010012acd0d57102,
+            ])),
+            Felt::new(BigInteger768([
+                0x39b643dc6feb4176,
+                0x88478934df3c2281,
+                0x55625fc5a5022976,
+                0x9bcf5bcbc10e7b1a,
+                0x92240df17302323d,
+                0x6bd8c20c91b881fa,
+                0xb94af758e0c36689,
+                0x2b7bd6a0bf1ff37c,
+                0xf8b42b7a745444b2,
+                0x6304b6a12f019244,
+                0x9af5f08050017976,
+                0x00832515d77661f0,
+            ])),
+            Felt::new(BigInteger768([
+                0xde87e8f5e724675c,
+                0x07c13a646ab05e8b,
+                0xfd29078b2233a9e0,
+                0xab782a822bad7008,
+                0xf92552332ece2934,
+                0x9c6b097050291f25,
+                0xc12681d86d29de4b,
+                0xb5e18936159013c3,
+                0x14efa4a80884b43c,
+                0xd4204a0ff1e9bef7,
+                0xa758abcf210e0ef4,
+                0x00ed78b8c2a3a65f,
+            ])),
+            Felt::new(BigInteger768([
+                0xbc26a133c78d6155,
+                0xb3a44eaddf657c2f,
+                0x7a26e134b0b05e6d,
+                0xec986ff5a720f47c,
+                0xf49da9068ec84983,
+                0x79f5d08dd80a6cc9,
+                0x37db367f44d8f702,
+                0xc1a025adaf246fbc,
+                0xe7ebe8d0cccbdd63,
+                0xa6533571b8272386,
+                0x8977ec359c9a1293,
+                0x008c4bebaa840134,
+            ])),
+            Felt::new(BigInteger768([
+                0xa3f8ece7288e6d79,
+                0xfe4108e2a115caa6,
+                0x0357a204e8fccc4f,
+                0xa1c545c0d9e0649c,
+                0x8de80cc26c1e417d,
+                0x00f89e266faed625,
+                0x2a3638d46aac34a3,
+                0x149a551b595ebafd,
+                0x8da1fd27b209a7f6,
+                0x113d76ad90714af6,
+                0x2bd3b65cd043f400,
+                0x008aa0f0fe951421,
+            ])),
+            Felt::new(BigInteger768([
+                0x9f937ea86bb5b9b7,
+                0xaa2ec987eaff23a0,
+                0x974fb62f61bde8ab,
+                0xfffcaff27bea6906,
+                0x453312117a6676ef,
+                0x6c0bd326bff34620,
+                0xca0f3a96191a811a,
+                0xd72c15c987db65e5,
+                0x089c35a906467de0,
+                0x721b76f6bcea7cbb,
+                0xf63b5d01bf7eaea3,
+                0x00257c7ab46878bb,
+            ])),
+            Felt::new(BigInteger768([
+                0xccfc3ca81e1cd57f,
+                0xba7f0250f2fa4ffd,
+                0x30083fdbee2cf4c9,
+                0xf862f0d3e6c56af1,
+                0xf929a98180f9ddef,
+                0x35affe357e12bc0a,
+                0x564459d46e1e5a78,
+                0xec7b5886be1e0d8c,
+                0x2f6c7416d8bd611d,
+                0xd3396e07629fde55,
+                0xc88d908201ec541a,
+                0x00d93376673731e0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xf933034d157ea6f3,
+                0x2d5945b88b85458c,
+                0x742ad7cc4e8250ab,
+                0xb61be3f7f720af03,
+                0xe69efe3b9c2c6b63,
+                0xd9577e10a72e2b0e,
+                0x36d70266b29fda4d,
+                0xee1c3e781d65c0c8,
+                0xef688507d0aa5bc5,
+                0xd97633e529c1c8ae,
+                0xec128ca0fb026208,
+                0x0096b8dfa6079b47,
+            ])),
+            Felt::new(BigInteger768([
+                0x6b799a99fd8414b4,
+                0x03c9015b2948a82d,
+                0x6c5164bcb041a80c,
+                0x8aa8e4e6fa55185c,
+                0x87598eb60675912e,
+                0x1db452fb458042bc,
+                0x0b1c730f00f0bd18,
+                0xd456da3ca9e42210,
+                0xde7433f693791b47,
+                0xdf407cb35c144abf,
+                0x5c010a6a3e996d00,
+                0x0121fcddd343ec3b,
+            ])),
+            Felt::new(BigInteger768([
+                0xf5c6ce9ab0a5dc83,
+                0xef5446837d8b5347,
+                0x10f677561029239e,
+                0xeb5e22ca13784617,
+                0xe363ef52e555aaf1,
+                0x019cfdc67651395c,
+                0x4b7c0fd9c50bc93c,
+                0xa8524269722997e5,
+                0xf88709c1ace870a2,
+                0x52a723814de65e88,
+                0x9d5da2e313181678,
+                0x00139176c6464446,
+            ])),
+            Felt::new(BigInteger768([
+                0x4de31379d2ded8ec,
+                0x03229936e8061b8f,
+                0xb70a4ef96e4bb00d,
+                0x2fde0de3f5a0e943,
+                0x237b18262fe302dd,
+                0x3f6e3af404c036fe,
+                0xc6048dd5482a2cbe,
+                0xc234a6c1ee4c667b,
+                0xf6d0e87a837f75d4,
+                0x4a5ebd7b393b289b,
+                0x2996eebca7135671,
+                0x00fa2208bcaca95b,
+            ])),
+            Felt::new(BigInteger768([
+                0xbcff98bcfde64b04,
+                0x20d18ce187271183,
+                0x1f67dfb48d7d80b5,
+                0xda65013738164a94,
+                0xa7799a5f2d8bb668,
+                0x9511b7029a300131,
+                0xd1eddeb5cf7c38a5,
+                0x101b5f635b390d53,
+                0xdc80c64e46e24249,
+                0xd7cc93b75753e598,
+                0x587d668f0b5f6597,
+                0x005d1f05ef4e48a7,
+            ])),
+            Felt::new(BigInteger768([
+                0xd00c3566160fa511,
+                0xd311e6873cc19e21,
+                0xc3a6d8a7c1cf6bde,
+                0xaf3a6adbd38af706,
+                0x8673f48212610705,
+                0x0c1d77b07f2b73b8,
+                0xccc539e6a2c15b1d,
+                0x4076b5bd5624f7b8,
+                0xa02eb11e26c15b1c,
+                0xe661934744ae5613,
+                0x25ef53c559a7c3ec,
+                0x006c5d4306d9b228,
+            ])),
+            Felt::new(BigInteger768([
+                0x8cb98a1c92da024a,
+                0x820427f6926f1a0a,
+                0x656b0b9dbc06f891,
+                0x08398cccafc149ee,
+                0x2092f104ce2c16d4,
+                0x62901aab531a22ed,
+                0x693c482809adf563,
+                0x9516e3fd1c17eb8c,
+                0x25e32867e6a2685b,
+                0xd465726a4bbb1d24,
+                0x8e802bf942c6c411,
+                0x00e83730a10e3263,
+            ])),
+            Felt::new(BigInteger768([
+                0x6b029d5c8b0faa34,
+                0xe63b45a97274d51e,
+                0x547c785421b3247f,
+                0x7ca11c38b58e28cc,
+                0x5a61624ef0b3f551,
+                0x8d72870ac126c62d,
+                0xbf86c97f68a6c7be,
+                0x9d3837690f32d56a,
+                0xa26322cf6dbc32bc,
+                0x90f7036bb9974643,
+                0x3271a92f14341f6e,
+                0x010fc6633fa33a9b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x0e3b48da3b224185,
+                0x0177f2e3b5ab271b,
+                0xe8c2edda87262ed2,
+                0xf05dd47698fda5fc,
+                0xbb81e12ec1189848,
+                0x1489151672bcb18d,
+                0x02ce9e8a35f9272a,
+                0xe4c637558c5746f2,
+                0x7aa1bc081b838141,
+                0x9fea9e6bab0597d1,
+                0xc3889180bad1285e,
+                0x00a03b37d230ce7c,
+            ])),
+            Felt::new(BigInteger768([
+                0x0437fe56abfd5e9a,
+                0x7613233813a68348,
+                0x170fa6309d4ba68e,
+                0x253a564a2c16b3c5,
+                0x15a770835770cfef,
+                0xb16a2a961358d353,
+                0x8da32703e1c747f8,
+                0xd5e17883803866c0,
+                0xc06886695ad128c0,
+                0x1d1676fc732021a9,
+                0xbea51102d242bcdf,
+                0x011df7006c0e2e9f,
+            ])),
+            Felt::new(BigInteger768([
+                0xd719709c1aefe923,
+                0x21c7dbc6365f3043,
+                0x73e0fc03e5500c5d,
+                0xbd045f12e2f0ec2b,
+                0xa8018a7c1789a54f,
+                0xff8a10f2c188c091,
+                0x00d9aff2c0bc13a4,
+                0xe5c691209837c001,
+                0xa7763cbe28020f65,
+                0x02722759e30b059a,
+                0x550878d5ce8e5cfd,
+                0x00911a70b426c87c,
+            ])),
+            Felt::new(BigInteger768([
+                0xfcef933b4121e9e2,
+                0xf406e2bb9c391855,
+                0x691a44be976ad7b3,
+                0x4f9ad7bb0a4922bc,
+                0xc1d46fe3725ab023,
+                0xa6b6e8780f632f7a,
+                0x7c7b145b32a12723,
+                0xb4f939c41b2e7d8f,
+                0xa8843c97f77e7a3a,
+                0x70f62cee90f9a58f,
+                0xa92d4b0220b3e7a2,
+                0x00a3fc0774b10c8b,
+            ])),
+            Felt::new(BigInteger768([
+                0xf7d8ea1d4b7f7026,
+                0x6711798d09e03404,
+                0xf6108fdcd2b68fc1,
+                0xd3fb9ddec610df5f,
+                0x0182d6d6c39fe4a4,
+                0x6ac554efe5f6f356,
+                0x55ac874a424c1c63,
+                0xa90fdbd4e9bff3c4,
+                0xfddc52ec05a99c0c,
+                0xb6edde40948d3f02,
+                0x08448a8009923ee1,
+                0x00bcbc239f7b1908,
+            ])),
+            Felt::new(BigInteger768([
+                0xa6e36cebb7f2cc8a,
+                0xbe0a2abd9aabd088,
+                0x86741e1b744204f7,
+                0xb8ba4d747db3079e,
+                0x91f451410fca3240,
+                0xf44fc73f1272b260,
+                0x6bcfb457727fed49,
+                0x3a6113b5c1eb1719,
+                0x2023aacb58bff6ea,
+                0x3f43c76911ec513a,
+                0x7bc589a95d794b5e,
+                0x0110bd4651f8363b,
+            ])),
+            Felt::new(BigInteger768([
+                0x16c91abe3b59369f,
+                0xbe86b88772f6227f,
+                0xfd88b282e9b249f8,
+                0xf99c2119a9d5ec4d,
+                0x8d709374c70a1795,
+                0x504447624724b705,
+                0x37467ebdc855508e,
+                0xd2f162d6f0fbbd75,
+                0x5da34b1c4de8f77e,
+                0xb64747960fe249ad,
+                0x680dd915df2d8b81,
+                0x008eb0aab16efd8e,
+            ])),
+            Felt::new(BigInteger768([
+                0x0c93cf7ea04adb45,
+                0x6286491d9924c737,
+                0x7a74cb4c31bd6a16,
+                0x0768989cc6419348,
+                0x1203248b8bfad492,
+                0xf83756585a7cd730,
+                0x949b0a1e4446d9c5,
+                0x811c6a632dfa5395,
+                0xfdc306f1f5df6e6d,
+                0x150ec32a81f7f4bf,
+                0x09c6a0091c0c2fc7,
+                0x0088bdd38e6029c7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xf23068ed72ff72e0,
+                0x1f60a9452097431c,
+                0x1125f063642ac147,
+                0x798db891b83dd311,
+                0xc33518b77979b047,
+                0xadeb8deb6f6e5d77,
+                0x1fbabaee403beff4,
+                0x25d378477d811004,
+                0x6193f524dcb1551d,
+                0x2270c3f3e3fbfd07,
+                0xcd455f03aff4312e,
+                0x0060999eca54827d,
+            ])),
+            Felt::new(BigInteger768([
+                0x4a66dc6e525c0078,
+                0x7bee27e431717ab6,
+                0xc0644a555518a152,
+                0xbec5a65f8f20267c,
+                0x5fb281cce86f914d,
+                0xb36e48b57d16ab84,
+                0xe741750f6e80aca3,
+                0x87c70f761cec1550,
+                0x0bb5d76f068631c5,
+                0xc81b794523fac868,
+                0x977c93d889f6ff3a,
+                0x002afcdb991c95da,
+            ])),
+            Felt::new(BigInteger768([
+                0xe5d24b5d16c0f3d1,
+                0x15a96367046a990b,
+                0x646dfb5c79a47ce9,
+                0xe7eed52b4826ba78,
+                0x31a69c26a2f6ec16,
+                0xfce03f087ca6d506,
+                0x15e6fecabb8664af,
+                0x86448d04468ea00b,
+                0xe46caaa9b8f78344,
+                0x4d0047972c1174d1,
+                0xe9f94bd04ff028ab,
+                0x0118ffe179668e6d,
+            ])),
+            Felt::new(BigInteger768([
+                0xb90e49748b993ae1,
+                0x49206aeb8cef1163,
+                0x95d0b2da5ec62853,
+                0x63d0d7528274eec4,
+                0xa1dd97e4443ee1a6,
+                0x362a91de9ea95f1e,
+                0xebb9428e2d50d3f3,
+                0x8e42216a39bbb168,
+                0x04680bae783c872e,
+                0x6c952ce47a4358ae,
+                0xac59265c96a2df50,
+                0x00ff9b350b941859,
+            ])),
+            Felt::new(BigInteger768([
+                0xf0d3715b7253d408,
+                0x730f269fa9294909,
+                0x5e6c3a85f44ffc96,
+                0x9b4bf6227f9f2cb5,
+                0x63d253625b40846d,
+                0xee3cc2869609168b,
+                0x97e20353df7cff81,
+                0x6ef3b66b7da2c987,
+                0x8fbecaa9e63f29c9,
+                0x410710a91949d69c,
+                0x9fb34a5ca706828b,
+                0x00b596b9ff47187b,
+            ])),
+            Felt::new(BigInteger768([
+                0xe1bb84a138e98edc,
+                0x2bab15920eb2b59b,
+                0x8203413d7c4cd5ee,
+                0x2393bf9844694264,
+                0x7ba509368f5d2ede,
+                0x493535e47f3f0a9a,
+                0xc06461566c1a43bc,
+                0x31458da0a9a3dec7,
+                0x89e0d68d393d54bb,
+                0x282a2d9afe21d455,
+                0xcb7fc21ee204cdd2,
+                0x007e6681c83a7b4b,
+            ])),
+            Felt::new(BigInteger768([
+                0x494977ba68851b4e,
+                0x99f092e3d4da554e,
+                0x50ec666753cedade,
+                0x82e09e990185b1ef,
+                0x97112eb555ddac8e,
+                0x777dc1c8fd0dcb35,
+                0xa3a6680757cbb9dc,
+                0xaa01b1fe0d887217,
+                0x034e703c0cfcbf92,
+                0x625a61fe48f10c0b,
+                0x5d8f7ea42030555b,
+                0x004d5fe2facd9b48,
+            ])),
+            Felt::new(BigInteger768([
+                0x11ee5eecb2131c35,
+                0x81b0a085b7ffce10,
+                0x84865c0f9d0eb765,
+                0xd6fc52c77fcf0c9a,
+                0xdb1391866d12827b,
+                0x1aa2944b6016c1ef,
+                0xb37b351647779a41,
+                0x3bb36bc669cc55e2,
+                0x2fc94f76322189ff,
+                0xba55ee2d9ca63da8,
+                0x95358584936b262a,
+                0x000f3335c4d2c174,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger768([
+                0x0e03ec2d58e1f7f5,
+                0x3434837ef4e8f33b,
+                0x833679c54965e49e,
+                0x46626ef03fe997c8,
+                0x4c6f6b80cc55c744,
+                0xa4af6f4d4299d6e0,
+                0xece639c78915f332,
+                0xde4305f6403f71a3,
+                0x0a9a2725af20531e,
+                0xbe0a83c64837419b,
+                0x5f9d2b776117fe58,
+                0x00e387045902c092,
+            ])),
+            Felt::new(BigInteger768([
+                0xb13596ffa5902d55,
+                0xc6c21e6eb449a525,
+                0xda10c088773d19ac,
+                0xb3dcac857a0219f7,
+                0x99c24a69bbcc01f4,
+                0xb5585f13ccde6105,
+                0x00f984943bfa5041,
+                0xb0b69f21c8defcf5,
+                0x01e6582294ba20b6,
+                0xd28c4f761b2d0b1b,
+                0x42ae4363d2dc96b4,
+                0x01054eaa7fa32fa9,
+            ])),
+            Felt::new(BigInteger768([
+                0xf2dcb87578b5e7cb,
+                0x5733fc2c20b1ef1a,
+                0xb23ac1de9bc33aea,
+                0x8f2eb4c143e946f3,
+                0x36f73a4e7355c1eb,
+                0x1a7e5989ce3739c0,
+                0x5315250f481f5b3e,
+                0x8aaf8a939288c910,
+                0xe36e19693c9dd3de,
+                0xe4ff514d1c398bda,
+                0x897bd3fd28641c00,
+                0x004b70816bae2601,
+            ])),
+            Felt::new(BigInteger768([
+                0x17256cb4fd3a3397,
+                0xf7e2a31a17eadf4c,
+                0x042f27739761c25d,
+                0xdab9103eeae3139e,
+                0x7cc7c0cafceddd66,
+                0x2f76b0a91da1f2d0,
+                0xc0bc148b81c593eb,
+                0x720720a6e293a899,
+                0xcc1fdec6817a767b,
+                0x560207727f032eb2,
+                0xfa3d5bb308db7a96,
+                0x007ac0bcae6f4ca7,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xd7692eeab68b8f38,
+                0x629daa0f58967898,
+                0x4dda6e2208495e7f,
+                0x8705e8d009e75ebd,
+                0x2eafbd3b81eb0baf,
+                0x9039f84ab032fa2e,
+                0x8b35553e83247566,
+                0xbe913738af73a393,
+                0x029f08b7dfd7debc,
+                0xcb13d2ac55fa3da9,
+                0x420bef83a95ced71,
+                0x000d7e0ab71fce47,
+            ])),
+            Felt::new(BigInteger768([
+                0x58274eb644b3e494,
+                0x20970353327b65e5,
+                0xe5a4301351914514,
+                0xe05f2cadc7841458,
+                0x3a391916b1f62f26,
+                0xf4c4799b04abed16,
+                0x2f24e668a8b63cc4,
+                0xc59ac3824beefdc6,
+                0x2f9c6311f287bbbb,
+                0xfb2db19010c0d904,
+                0xa928f93e949b8172,
+                0x010b46652ee98df0,
+            ])),
+            Felt::new(BigInteger768([
+                0x0332f891c76fb19a,
+                0x9d78f40e46d427ef,
+                0x96df5894e07be7c7,
+                0x7aae5147670b1ab9,
+                0x3ff66ad3c25d150f,
+                0x5f0dfb6cd8511431,
+                0x29461cd36e51878a,
+                0xd3bbe8d68683f99b,
+                0x359b7df2d12d9645,
+                0x7f0b2a0b7bfd4f9c,
+                0x7cc8f7e41bb97f6d,
+                0x0068f3bcf6a5e56a,
+            ])),
+            Felt::new(BigInteger768([
+                0xf1fab8852bf01f99,
+                0x1342f82395489c9e,
+                0xe84f334789e977e9,
+                0x1bf2cd3d6dd8af57,
+                0x525a64e67d7769db,
+                0xed4f1bc4009d3e4b,
+                0x68e08fa3efbe09d0,
+                0x818fee78acd60e2e,
+                0x2e1d9ddfc41ebeff,
+                0x75b7b22732cfe818,
+                0xfbc9d50f82318ff9,
+                0x00fe89f29ba51cec,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x36e1f99b7261f673,
+                0x2abebc10c74dc37a,
+                0x0e69e7ee4e4382cf,
+                0xb97b8b1195bed5f4,
+                0xa81652480a62996c,
+                0x99a7d68afa9ce859,
+                0xa2eee846e70b7922,
+                0xca7ea26f7fbf0258,
+                0x5ec54a7c8316a6c8,
+                0xd2e8808dc19fc891,
+                0x9e85ae3be7e75312,
+                0x00a617f9afd7f85f,
+            ])),
+            Felt::new(BigInteger768([
+                0xfe16419d5a009e4d,
+                0xe27af2e62398bee5,
+                0x08a6f4615a8ef0ab,
+                0x24b6d0196f8d858e,
+                0x24871e022f398ab8,
+                0xa7dfde83307bcbbf,
+                0x65fcfd58a11f36b8,
+                0x30ed061ceaa1d98b,
+                0x226d6466e16ef75a,
+                0xd809180d8435a33a,
+                0xa8f08b8da5d13b68,
+                0x001821ff5c64b541,
+            ])),
+            Felt::new(BigInteger768([
+                0x3b2fe192a79c4d73,
+                0x3548b8d2d53e01d0,
+                0x3ad07c536357e263,
+                0xac8fd2f1e97c9e52,
+                0xef54247775357d24,
+                0x2298dffbde13244b,
+                0xba50695e03bd0b50,
+                0x30f164ed15a0f604,
+                0x6d5ce6fb58a46c82,
+                0xe2034b010548a141,
+                0x1202476aced2b754,
+                0x0060f70e664a9602,
+            ])),
+            Felt::new(BigInteger768([
+                0x1882b4a7916b43b1,
+                0xdf01de79d0fa274e,
+                0x16974828e2d84fd6,
+                0x04cc79508b6ccb17,
+                0x4805428857c4a696,
+                0x69d505c62c1ef1b4,
+                0x526b15bdd02c9e25,
+                0xb3d706ef10d4e86c,
+                0xc2f9b7fa3c383f77,
+                0x64118df8165eb36f,
+                0x7dbcfa53c471b464,
+                0x01059d88f9d06605,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x87f0940d47999cf3,
+                0x1902f1375a547d5a,
+                0xdf74bc460b4213f4,
+                0xa6ad34b51c5f7465,
+                0xd016882b94ac1a20,
+                0xe1e79903273b874b,
+                0xd1f805d09dbafe0b,
+                0x7a4791191f6b8bb2,
+                0x3d7d624573ed0f93,
+                0x543ecc747ef0c10f,
+                0xd1f067cde97ff48e,
+                0x00b01668bc1e7702,
+            ])),
+            Felt::new(BigInteger768([
+                0x663d50b752fe7d8b,
+                0xd7def09f701b2e0b,
+                0x23eff6cbf54c154b,
+                0x5dc74fdc4b8cd40f,
+                0xa8a802a86a64b48e,
+                0xe69ff044250fbc6d,
+                0xfd731867c38b7c5d,
+                0xb8ffead23e2a4a28,
+                0x637e4e2e272610d9,
+                0xe8941db8f744aedc,
+                0x34fb32c901a12b78,
+                0x00551b365147d4c4,
+            ])),
+            Felt::new(BigInteger768([
+                0xa54cac8a33c8498d,
+                0x20f63adfe15e6c2f,
+                0x5aabec5e6b056f47,
+                0x9d3f37be216b5353,
+                0xe20582c3512c536f,
+                0xf206362b7cee1969,
+                0x777d95a503c1bcb7,
+                0x4b07faed6ac9b983,
+                0x70a8d3efcc35d84f,
+                0x2dad127bb27b9b1b,
+                0xb2e060c8854e9359,
+                0x00e9f51bf87cbc68,
+            ])),
+            Felt::new(BigInteger768([
+                0x0175532672c561b8,
+                0xcfb105c97172f573,
+                0x1f58c684cea735ca,
+                0x3c68a2f88687080e,
+                0xb5043523e26f7981,
+                0x2e61226fd6a759bf,
+                0xe6848475e4794437,
+                0xb4b213d97e29d54d,
+                0x1d3f7bd6cd5a4107,
+                0x14c6d473ada5eb1f,
+                0x30c2743e6cdb8d09,
+                0x00429457b9ecee95,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x6fab2babbd195fc7,
+                0x3e89c80a8b6d039d,
+                0xca9bea71499f4f78,
+                0xa26ed2f16b0969fe,
+                0x9ffe7af976ca07c0,
+                0xb0f2f476b26c55a6,
+                0x83e67788fa46747f,
+                0x575dee31a30866ac,
+                0xd580652ff90d17f4,
+                0xbb081ad66cc7dfd9,
+                0x0cf5be7b41970e9c,
+                0x001ee681353a9065,
+            ])),
+            Felt::new(BigInteger768([
+                0x0c7e3cbcf3a37663,
+                0x289c2e55f429a4b3,
+                0x4288e04b9287a505,
+                0xa69fb8c2c7a89861,
+                0x5137d9b5d5b2d7dd,
+                0xc3918921afbdf222,
+                0xccaf30bbc0a782fd,
+                0x0b3050e04744df0e,
+                0x6f57c625b85143fb,
+                0x2ceb1d62e1d1c489,
+                0x280e6516e59766ed,
+                0x003ca11220e79785,
+            ])),
+            Felt::new(BigInteger768([
+                0x6222a63ec577a176,
+                0x0bb83342b012fe68,
+                0x59411617aed32481,
+                0x9a54524ebaa66416,
+                0xe1b87b432a558be3,
+                0x96f094d1c4cd7e20,
+                0x448267fd2edadbdb,
+                0x94648facd8e57148,
+                0xcbcc7e2f226fa0ba,
+                0x46b58ef7fdc63126,
+                0x9dd2c74a7f8fdb2f,
+                0x00e4d33b34cfc8ee,
+            ])),
+            Felt::new(BigInteger768([
+                0x69d65f324c4a99cf,
+                0x31f8af40d2ade1e5,
+                0xed39708f19a2b92d,
+                0x3c0702b43056e117,
+                0xc6f9eda1950b933e,
+                0x3dac9190ad455d9c,
+                0x992304ede18d985b,
+                0x37e3a8e7ea0c7999,
+                0x455ab8976cf2e738,
+                0x5b389fee38708d9e,
+                0x7245be2fe9fff0be,
+                0x003daf5ad83343ae,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x0eabaee97a3bb2b9,
+                0x41e5f62a445186a7,
+                0x66f6338cb916bb26,
+                0x9a5ca37e45f848f7,
+                0xf0f6b080bc46a46b,
+                0x4ad3139bba2f691e,
+                0xaeaa930edad25341,
+                0x0a8ee8d6da26c9b7,
+                0x31fd0c987b5bfec4,
+                0x54bb0f4e6e0ccc7c,
+                0xdfb9622f181478bc,
+                0x00200e19e3c720de,
+            ])),
+            Felt::new(BigInteger768([
+                0xc14766c0fd8dfbba,
+                0x0edd504a68b85fec,
+                0x0ee2325c48f5711c,
+                0x654316ade684e568,
+                0x823baec69d6edce1,
+                0xcaa831fa02380d39,
+                0x836413dac746592f,
+                0x263ce9d73bc9adcb,
+                0x9d3a5085696f8e83,
+                0x066640b3eb2b3fe4,
+                0x0633726d6f33636f,
+                0x004ca1e46edaf655,
+            ])),
+            Felt::new(BigInteger768([
+                0xa8e5991ca382606e,
+                0xe34d2ec8f5a03cc5,
+                0xaae208379d0e7634,
+                0x132c55c16ec0db49,
+                0x25c668f2b7b8c7ca,
+                0xc28455d0dac1e43b,
+                0x9529fd97f2a94113,
+                0x7ec373559782136a,
+                0x36932a0aa14c577e,
+                0x8a82764c374298e8,
+                0x1e1bbc58e9ec8ed8,
+                0x0053b1d02a671bea,
+            ])),
+            Felt::new(BigInteger768([
+                0x8d902e6b07bb60f0,
+                0x2715e4f9afb0e07e,
+                0x3f45663419ca4aec,
+                0x6b6f582898c9ed9a,
+                0x0e720430d300f688,
+                0xb7b0e679d3ca04a0,
+                0x1c3ee9f852d588d8,
+                0xaf1bbd7bcdcb4a59,
+                0x7928bd8277406088,
+                0xfb68c51f66b0d940,
+                0xa515d4f55aa43a4a,
+                0x003b5fd3df016b2e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0x4f28732950c9da09,
+                0xc4d3053861f4ae30,
+                0x413561787889ee6b,
+                0xbfd85da2d08f1070,
+                0x178cc3ef9cd5d1ab,
+                0x55d121cefaf7167b,
+                0x4918be3c5117b149,
+                0xc486d56780c676d9,
+                0xdc79ebf7adc3f959,
+                0x0cca538ff9ca3f7d,
+                0x7f26bb9c9e998fb5,
+                0x00e034a7499f6870,
+            ])),
+            Felt::new(BigInteger768([
+                0x2e559276eeb00ed1,
+                0xdde8ac6cd3198510,
+                0xc998131f5fa91ff5,
+                0x4210875d49e816dc,
+                0x2742c10c6cec213e,
+                0xb3897d880f00992c,
+                0xcb704038650f9162,
+                0x93d89770579fc735,
+                0xe47bb74069a5d344,
+                0xe2a7bbdc6a997d38,
+                0x5d79772d8dcd48b6,
+                0x0098612ef6227fe1,
+            ])),
+            Felt::new(BigInteger768([
+                0x4b59a46efd575b9f,
+                0x9c5f8803c88e0e59,
+                0xf48bc7b0094cd4f9,
+                0xe377ac7a51e93b1a,
+                0x7780e6369e94b8f4,
+                0x8c0989b36c1e6e7b,
+                0x88fb2bf5f47b0f36,
+                0x64aaef8342fce3e5,
+                0x348d2d2920a3f56e,
+                0xf8a9fffc646ff161,
+                0x9085c17683c25543,
+                0x012094852645306b,
+            ])),
+            Felt::new(BigInteger768([
+                0x8b4ec16735c84a7d,
+                0xd6c9ef67fc70fd17,
+                0x4184dceb7d87cb0b,
+                0x07201bc1dbdb846a,
+                0x18cfa34f265f4b19,
+                0xe0c81fbc3c97a2e6,
+                0x9a83a2c186a09e44,
+                0x54a95674ddbd0241,
+                0x533649bb8e4eeb94,
+                0x7fd5296e425f7ad5,
+                0xa493f27778e10280,
+                0x00e7857d9bbfd294,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xc44b99f9253d5258,
+                0x2ea3c47a581ef01e,
+                0x3c6646c3a0c11463,
+                0x7205c6a4f448813f,
+                0x2801c186367aef13,
+                0xc2f1d832bb162e99,
+                0x5959ec5166d21b35,
+                0xf22f8839ec5103f4,
+                0x15b69f24c2051727,
+                0x50f35831a7b87755,
+                0xf0037294ba38535a,
+                0x00f10b857d83c31b,
+            ])),
+            Felt::new(BigInteger768([
+                0x4915f7553fabf6cd,
+                0xe3c7dab2669722d6,
+                0x1db25542a076f301,
+                0x0a042fba317e6d8f,
+                0x62b0deed0a0befa7,
+                0xf6bcf774cd340860,
+                0x42ace33f61ef9deb,
+                0xd6804a605dd5306f,
+                0x9280dd92a787a62e,
+                0x0377c53d374a2480,
+                0x35edf31417e68229,
+                0x00234262037a169e,
+            ])),
+            Felt::new(BigInteger768([
+                0xacf00c588587010e,
+                0xf9be1c5f805702b7,
+                0xe13a912bb5ac6a12,
+                0xdd41a8222df17460,
+                0x4a5cfacbb3283e46,
+                0x8de39e24542ac05f,
+                0x59eacc4a6bf74c38,
+                0x7e6a287ed179c2d4,
+                0xb8fd4d33f593afbd,
+                0x0feef6b6a1c857b2,
+                0xf7bb35b05da4c052,
+                0x00587957d3d2f28a,
+            ])),
+            Felt::new(BigInteger768([
+                0x3c17d557481aecf7,
+                0x9e491e2cef6ec2da,
+                0x4d79dba5d2a49969,
+                0xd8d20b48732939a8,
+                0xf7b0d033ef4d0dbf,
+                0xbd3db719b9046080,
+                0x2f4f5a4ca601d85a,
+                0xbcd486dc93a8b9f7,
+                0xa3b62c3976e4b43e,
+                0x353f04d7d7028131,
+                0xe9dee1495a2ba47b,
+                0x00b942b6949c932b,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xbb1c92227a6bebad,
+                0xc3081ddce015454f,
+                0x4c1b0eba19519b9c,
+                0x6d6d1fbcba56b490,
+                0x4c92733fb8c8920c,
+                0xec4f3cdd0604c422,
+                0xe2423fda5b9d7922,
+                0x2cb792cc5e6779e7,
+                0x8237d338f7a776c1,
+                0x881a2503fae383be,
+                0xbd0bf3b537b036fd,
+                0x00cbf11b1b489122,
+            ])),
+            Felt::new(BigInteger768([
+                0x607e327356d770f5,
+                0x9c164fb359c6e81a,
+                0x7b04e2e71ca80889,
+                0x6e84152a93be1b89,
+                0x39f009600a8c309f,
+                0x75710993d2a785b0,
+                0x872f9cca0e2c5ef6,
+                0x5953e67c69824c8b,
+                0x3641d8ef12bf4a75,
+                0x2a36688c4b556ede,
+                0x4beabbd12a0a4369,
+                0x00c80ee0835caac3,
+            ])),
+            Felt::new(BigInteger768([
+                0x88870519a966add3,
+                0x3d0cbf4990d25695,
+                0xf6bd450310c1045f,
+                0x169e2a1b6658e9f5,
+                0x31259ef26b9577e4,
+                0xdfd228ca1a8c888f,
+                0x213464e23790ac47,
+                0x10da8360160a66bf,
+                0x731db39e35c91eff,
+                0x7788ce46b72da4c3,
+                0x8ab206337c0fbf40,
+                0x00c0ec0245b62000,
+            ])),
+            Felt::new(BigInteger768([
+                0x627e70a5651320c8,
+                0xf183a5b24b50f3af,
+                0xbfa538305fd2c9cc,
+                0xd96ec530b73b474e,
+                0x8b0a5be6646a072c,
+                0xa4c500795efeb738,
+                0x65464bbf9c8f3e4f,
+                0xa01cb35c472a6e45,
+                0x5de27d23a63c5e5d,
+                0xee85d0fbe821eaa3,
+                0x19727cbd49964665,
+                0x006207784e6d6f66,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger768([
+                0xb16d894945da3d91,
+                0xe180a8140b3bbd4a,
+                0x0763a2bbfa461b14,
+                0x77273392f3a02960,
+                0x98138665ddd85c42,
+                0x655f59509bb5d83e,
+                0x368abd0c482ba87e,
+                0x0495339015ada610,
+                0x5de6052886afbf13,
+                0xa959cf258cfed596,
+                0xa1066e39618ffdf6,
+                0x0069442fc17bdd75,
+            ])),
+            Felt::new(BigInteger768([
+                0x8b027882a7bcf88a,
+                0xfd6bf1ff54b84afc,
+                0x41eae60586c1a66c,
+                0xce85cf7c06b1f2de,
+                0x1efe51910bf1ef65,
+                0x01236c1c8be7fc84,
+                0xc30d4bdd9f413745,
+                0xb230c5dc99a0522a,
+                0x74bf81be438edac3,
+                0x6e285232cabd246a,
+                0x590d759d210119c2,
+                0x00eeeafe2c5adb74,
+            ])),
+            Felt::new(BigInteger768([
+                0x529ec390f133c353,
+                0x4e055fb53cf3f3e9,
+                0x991ebe09800b1ce3,
+                0x9126bccb58f921a0,
+                0xa416006dfc9fb281,
+                0xe9af6f4171f887ce,
+                0xb6ede1f8e8dc9b28,
+                0x9c525dad85ebce60,
+                0x9ccfd314b732b634,
+                0xe7222be21bacc022,
+                0xd2c95d44fe836596,
+                0x007b6ee5f4ed098c,
+            ])),
+            Felt::new(BigInteger768([
+                0x7a3be948e9a76423,
+                0xb7202a24170d3b87,
+                0xd246ec3fdcfc6d67,
+                0x0bb23f1080d8a00d,
+                0x5808e5277a236a51,
+                0xa5dbf2724f805a51,
+                0x5df1f755a8e8ea6f,
+                0xa2db0bb971ed33cd,
+                0xedcc036d4cb3a052,
+                0xf702333c868aa954,
+                0x34a237f2e0f6644a,
+                0x003f2d0bbf654994,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x38ae6e9e6fcc865c,
+                0xf8030f5a0c53c13f,
+                0x9e93f0af470d41a6,
+                0xd9598d89fb476d28,
+                0x8f2d97acf6aa96a1,
+                0xef86a5f53a56d47d,
+                0xe91a2015c539a7f4,
+                0x659834c6cc839897,
+                0xbc41e5932eb4356a,
+                0x8e3aee642e398be5,
+                0x5664baaee2abf8e4,
+                0x005b845b2a5eec21,
+            ])),
+            Felt::new(BigInteger768([
+                0x45f58504ec608511,
+                0x2ade90e771353656,
+                0xaffb52caea365a9a,
+                0x7ed933c8f3619377,
+                0x232ed13eb2f575b6,
+                0x280cc3b7f58cb597,
+                0xd4affc0d5f5cb392,
+                0x122988b19d8425b4,
+                0x2247767b7cccccb1,
+                0x24c57c2a0ab971e8,
+                0x2038b702498d3891,
+                0x009d448b98f7f6f6,
+            ])),
+            Felt::new(BigInteger768([
+                0x1519e6ddba06a81a,
+                0x79d3ac41413848b0,
+                0x8e35dc1d2a20d089,
+                0x11fe9db3df80d1f6,
+                0xc161761572e92a4d,
+                0x2f3f966c0fabb2d8,
+                0x3e6373557a9764d1,
+                0x383b9fb1eb83e486,
+                0xae82cef5338c2fbe,
+                0x932ee65b28ee3052,
+                0xd6142723655adbb1,
+                0x00575421696af704,
+            ])),
+            Felt::new(BigInteger768([
+                0x4679d3939a27218a,
+                0x5f1117b4f89e9fd9,
+                0x309b8d24cb1eda3d,
+                0x16862a4c1544a953,
+                0x37e6e8c3b9ab6b6a,
+                0x92dc882bcce1c372,
+                0x31ea96809e9874e2,
+                0x51fe193beeff4a5e,
+                0x20d6228da0656408,
+                0xe0a8075999b4891c,
+                0x212bb863b01b5962,
+                0x0104fc7c7d738550,
+            ])),
+            Felt::new(BigInteger768([
+                0xfbe9041c1dfec1b1,
+                0x188a7bf577963e8f,
+                0x43735426b450fa22,
+                0xf8de41d443f9f9e8,
+                0xfe0a40f98da32983,
+                0x50e913a31f61c986,
+                0x026ccc699d598e90,
+                0x7296fe20314621f0,
+                0xfc46cca85021fe2b,
+                0x45b06f23d62cdb28,
+                0x9d6069c3ef706ebf,
+                0x00a1592775d32242,
+            ])),
+            Felt::new(BigInteger768([
+                0xe58c7d17b1327ebe,
+                0xd9d1b7f680cd9fba,
+                0x9e653f529ca8937e,
+                0xbe11ebd385eccf7a,
+                0x52e0cfc17d2ce708,
+                0x3faaeff057020647,
+                0x0520af38975b487c,
+                0xe3c988219d872e4f,
+                0xb04af7f6724fa2e0,
+                0xd1535b6126c05d39,
+                0x786a5f27a12215cc,
+                0x00c9fd16674798a0,
+            ])),
+            Felt::new(BigInteger768([
+                0x14141abe5d6fc2d5,
+                0xffe9f90dae0fe3e2,
+                0x5c16148da4b2f6cc,
+                0xbbfb808902f9220a,
+                0xe84283ff2419a1bd,
+                0x208933190379e7c0,
+                0xe0abb52c3dcb1d1f,
+                0x5c929867b6f841cf,
+                0xd06bb3aed486b079,
+                0x7cb1d454cff634cc,
+                0x141c577d0a8f3c75,
+                0x004269b4c41e7a28,
+            ])),
+            Felt::new(BigInteger768([
+                0xfae7a33277e76c32,
+                0x81c23e83bf33f445,
+                0x68aef5b1792c6fa4,
+                0x40af29dc549ecd05,
+                0x432518cb78d0f455,
+                0x98ad3e393257e966,
+                0x67f63a65d56cf55e,
+                0xf679afb303ea58c0,
+                0x2a8e53e70231a873,
+                0xe8d2ea5cbbfcf730,
+                0xf0b9d94a33325ba8,
+                0x000474c1fc567698,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x3134e64a0305500c,
+                0xedb418f3265a2bed,
+                0x708b6fde8c407033,
+                0x8ebe8e3f38d73275,
+                0x287ec0c016a27cb9,
+                0x98974067978b4aba,
+                0x0a44ce7cba89440e,
+                0xa122ade78a4294ec,
+                0x8b228505727aeaab,
+                0x3f8858a4961c120d,
+                0x3bc886a6e0eec548,
+                0x008d6f0b413cff46,
+            ])),
+            Felt::new(BigInteger768([
+                0xd3f8c1201eb93b3b,
+                0xeef8a5e2513df602,
+                0x01267232ef50ca47,
+                0xbdb602ef8decad53,
+                0xa97c9ac466e19e2e,
+                0xd260ad7663dc210c,
+                0xdbcc64550a254e2c,
+                0x89849cbcba8f104d,
+                0x22247647cd4e8219,
+                0xc7a50b80c03982de,
+                0xc5fc03ff15d1db28,
+                0x005a6a78809d807e,
+            ])),
+            Felt::new(BigInteger768([
+                0x4947dc327b754ee4,
+                0x89b5071997699a7f,
+                0xd92271793b84546c,
+                0x4c25d9f568c1673c,
+                0xb900fbbe2e025649,
+                0xd823d1bd3ae01580,
+                0x5130cb1fbe7d2541,
+                0x03a386d3e278be70,
+                0xf59b7fd24f82982b,
+                0x36166a077df07ed2,
+                0x81882e869ccf4d3a,
+                0x008d7183fb6792e2,
+            ])),
+            Felt::new(BigInteger768([
+                0x5ce385e7d3989f0a,
+                0x477b95c7515fc34d,
+                0x43acb67e6ea569d7,
+                0x8daff200a140deb4,
+                0x61ab7c728a8c5666,
+                0x3eab7da6255cef92,
+                0x995b2aaf7e767820,
+                0x5999f79555c5c8c7,
+                0xa09cd234fc0be6e9,
+                0xd663f21dcd5f6821,
+                0x4f05a0e6e8d49624,
+                0x003989947e8881b9,
+            ])),
+            Felt::new(BigInteger768([
+                0x43f7ca7bbbb8f093,
+                0x6f42ad84900d8eba,
+                0x317c48fe0997fad6,
+                0x06958581500151e7,
+                0x53373f3a29a8a7cf,
+                0xfcad402e93307489,
+                0x35a29cfe102b7510,
+                0x34c205cff07b13ff,
+                0xd020aa0fdca5c9f4,
+                0xdedc3bffb15f9ff0,
+                0x12a3fcb739dd25cd,
+                0x00bb112ba743439e,
+            ])),
+            Felt::new(BigInteger768([
+                0x2579b0425ad30fd2,
+                0xfb387a2d6d26dc1f,
+                0x07148f4b9580fbff,
+                0x6257eec37a3f6bfc,
+                0x29bf586eeaa09601,
+                0x6a26b207a1bd9c59,
+                0xbc6c9824890529f5,
+                0x84136740b3cd7ffb,
+                0xf1d41eba3b5c19b7,
+                0x275edf3116e68340,
+                0x658a451f4d896f8b,
+                0x008543280525989b,
+            ])),
+            Felt::new(BigInteger768([
+                0xd0281bbb2f6792c0,
+                0xf9d190bed2483022,
+                0x3ef46d56d0983efa,
+                0x9f3dcf47b7f9ad44,
+                0x0119fa6c241c1209,
+                0xd40545a31447d042,
+                0x2a7a346f69574b32,
+                0xceb1d6ac1d0d95b6,
+                0x741b7fd2a4459a11,
+                0x93dd61a5b6a3b926,
+                0x4b64acf393e61c8f,
+                0x0098c1a5a0d95130,
+            ])),
+            Felt::new(BigInteger768([
+                0x560119416f612c0a,
+                0x84500926d02c432e,
+                0xfd9e5dce6ab92267,
+                0xc1f2b8e54b37ec9d,
+                0x001ad40825058f4d,
+                0x23a19746a8d6d839,
+                0xb26c00a902b7ae63,
+                0xec0a4e2e93e27e36,
+                0xb162d3f44239db03,
+                0x620c9681b6205fe3,
+                0x906c5a5b64d247c9,
+                0x00d1b3f44c85dce6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0xa7bc4ace7314aa97,
+                0x83f8c320d542a20e,
+                0xb163135ca5fb3e55,
+                0x3703783a9e4a04c0,
+                0xbddde6c07faa8806,
+                0x91d569fbbfda21e1,
+                0xc98fececc5e1c955,
+                0xc624eb617558f847,
+                0x73e8ef5844163ecd,
+                0x6fe988ec574b55d0,
+                0xff18838822ebd03c,
+                0x00879dcf52629469,
+            ])),
+            Felt::new(BigInteger768([
+                0x117124b5983e6f40,
+                0xc328d68fc9457132,
+                0xe9af69dbbc40813c,
+                0x0261e043655b0b11,
+                0x9955970888bd2975,
+                0xf0ed3ea5f1538463,
+                0x0721afd9410dc8e3,
+                0x3b8771fb47cae26f,
+                0x81be5fff547b58ca,
+                0x2757c24ce43f80a9,
+                0x1373c61bebb57bc2,
+                0x00a623ee43f418b7,
+            ])),
+            Felt::new(BigInteger768([
+                0xfdd537fd5776a205,
+                0x7172db7ac7b8d553,
+                0xe3e68e918f6883a1,
+                0xeb9c7bf1f5172dfb,
+                0x5c06b2a8748507a0,
+                0x052d61eea07a9efc,
+                0x3738003125ccc7f7,
+                0x350e889b2aed6621,
+                0xf336fa04d66b447f,
+                0x0b6bf344e480faa0,
+                0xb6c11233f0fdcc2c,
+                0x011c180eea7a4180,
+            ])),
+            Felt::new(BigInteger768([
+                0x321cdc2e27e322a6,
+                0x7545d19e1585d8c4,
+                0x482490b9377823ee,
+                0xfb8abed3d3d84033,
+                0x77db8a769f505dd7,
+                0x9e25175b18efc296,
+                0x28e915f55ae4d4f8,
+                0xc13dd14c0e0af090,
+                0xafe42ea9550b1401,
+                0x20a63bd12e08533a,
+                0x1311c4d491f409c9,
+                0x00107c32318c061b,
+            ])),
+            Felt::new(BigInteger768([
+                0x3c29c085d43f0012,
+                0xdc5460a5a54ffec7,
+                0x509c6c67a9dcc54d,
+                0x036115fcf0222ea3,
+                0x80dfab75725bbd0d,
+                0x7ec30b78d7f9afb2,
+                0xafe264d40c95bac0,
+                0xc40f0bbcb44a616e,
+                0x949f70a36df0a8c5,
+                0xd571ded93177aec0,
+                0x3b6df0a46a0e166d,
+                0x00fa060736f6c8df,
+            ])),
+            Felt::new(BigInteger768([
+                0x87c10af52753523d,
+                0x099dfbe114fb4f4a,
+                0xad31f176904b9fd7,
+                0x6b5509c54361eb96,
+                0xecd81a74b2e1627c,
+                0x30ac90e2d4b6df01,
+                0x34cb97c6c5dbf5ce,
+                0x0a7b8aaf45acc8ca,
+                0x45803cb42580032f,
+                0x88b28db80825036a,
+                0xe698b3e40f50c872,
+                0x000b268334603b50,
+            ])),
+            Felt::new(BigInteger768([
+                0x2d56414b6b746946,
+                0x1ffee4f5416a2d49,
+                0x7359a6a0142c41cf,
+                0x5abeec425e59caab,
+                0x62ffa8a76cc7c97d,
+                0xcb1156aab0ce2e52,
+                0xa893a35f2bacf48c,
+                0xf75242dd16125ad1,
+                0x133a8378c3d8f626,
+                0xc6c2232c8e61e205,
+                0x81f202aa83ac60ec,
+                0x0002cf1938e0e5ee,
+            ])),
+            Felt::new(BigInteger768([
+                0x72afc2e7681cb6b6,
+                0x6912a7ca376ac802,
+                0x5da2469e0b0d62a9,
+                0x7d0df129b5f7e681,
+                0x533d8ce57e545af2,
+                0x411f4f90f7043d15,
+                0xdb1b75c807a4281b,
+                0x3adfb58e28ee88f3,
+                0x2dc0c2accad931e6,
+                0x1cece94418e422b6,
+                0x9f03a3ae140f2d63,
+                0x008346213de044c9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x4bb5d89bf507f4e7,
+                0x93c35cf2f90aa352,
+                0xe65acc5055349c3f,
+                0xe9b88ee5739d98ed,
+                0x64e1644c5e0860e8,
+                0xb7fbce62159007c5,
+                0xc203753b9a8df33a,
+                0x327f3bc15b5d4c38,
+                0x8370c0b67e71c2f8,
+                0x00bf36276a181552,
+                0x55cacb98575e2c52,
+                0x006c54076759b3a9,
+            ])),
+            Felt::new(BigInteger768([
+                0xc9020d65519d582e,
+                0x41ad7a43c1dbaf79,
+                0xc2cae86cef35a3d5,
+                0xb2af7d92856f26f2,
+                0x6dff32083446a4a5,
+                0x146d1a3c19a7dd7a,
+                0x7a601a94818ff013,
+                0xd405f246a22ef6cb,
+                0xd991782a986b8b18,
+                0xe234fb0e6f6a78fc,
+                0x16dff78b0d696a38,
+                0x005bb3b610c094ff,
+            ])),
+            Felt::new(BigInteger768([
+                0x44e72242c15c463c,
+                0x7eadfbac671d63a7,
+                0xb9689ecd13977c95,
+                0xe8984bceb2260b3f,
+                0xb705034c8115fbe2,
+                0xe10cb00a8308998c,
+                0xfde2aeff4cfb63a6,
+                0xe87e4c43f2916726,
+                0x3c5dbee1a3751b3b,
+                0xe0762a4d70254701,
+                0x9024cad7ed6c5d36,
+                0x00ea71a9ff263682,
+            ])),
+            Felt::new(BigInteger768([
+                0x11bbbc35b54834da,
+                0xa16efb07f8ea528f,
+                0x042073687ddebc78,
+                0xba5ae63af3959987,
+                0x0b84ccc4cc96e5be,
+                0x0fe1c4c489d46856,
+                0x9a1049bd0991d4e6,
+                0x4df2970491b20b96,
+                0x4c403bb385a04193,
+                0xff9135e22cb6dec7,
+                0xa5a127f2135f25c4,
+                0x00fd68af9885c0d4,
+            ])),
+            Felt::new(BigInteger768([
+                0x944ed3820b33e9c0,
+                0x31e888b8acd1509c,
+                0x9cbe47e925928180,
+                0xf3cc2e821152a0ab,
+                0x4cd769b1b8f8e363,
+                0x2cd9f9ff11a52179,
+                0x069f6eed56941f61,
+                0x5f5afa839586157e,
+                0xc3bedf4763890cb8,
+                0xb6871d84a4aba728,
+                0x02b59b3a51155c47,
+                0x002c2762594bd969,
+            ])),
+            Felt::new(BigInteger768([
+                0x51fbeb87ff332488,
+                0x3d71f3875c237a99,
+                0x0b68b4e75075de4a,
+                0x20b989d0e67f5345,
+                0x7eaa2dfbb87f2c91,
+                0x551573efec808e25,
+                0x430f8e1bd07bce06,
+                0xd0a6dc71762022a2,
+                0x09cfc54cc73240ee,
+                0x4358406a8cfcfc1a,
+                0xedcf993af655c668,
+                0x003d30ee4e844f7b,
+            ])),
+            Felt::new(BigInteger768([
+                0xd799d7db0e7e14a8,
+                0x2e1932deff4a083f,
+                0x3eaaff5429cc8664,
+                0x44b28ad496c205b1,
+                0xbacb455b2bd724e9,
+                0x2a4e660ec26d03a7,
+                0xe19b44cee83ef42c,
+                0x5e83c4f89727e1ea,
+                0xa36c2918cb69ead8,
+                0x2cba91bc823d3d2c,
+                0xd54166f415052eed,
+                0x0114b18bb894c337,
+            ])),
+            Felt::new(BigInteger768([
+                0xe2cf510042641fe8,
+                0x3a9ae101a90436f4,
+                0x98beb391ab0894b1,
+                0x5cba71bd7a45bf18,
+                0xf4268e0d96e80be5,
+                0xa0d06df8bd11a6d7,
+                0x7f8670cd1963c065,
+                0xf679558760f3e7ee,
+                0xdf4152535b0622a7,
+                0x83fe304fee4a6828,
+                0xed4fd6157c7a80e0,
+                0x003889c0b2bb7dbb,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x337034317c5f0d46,
+                0xdbb5798aa8ed2a0f,
+                0x4704760fb7f04e94,
+                0x2a916204c5794392,
+                0xf5750195c2d78c35,
+                0x7ecc8f9d5655956e,
+                0x3cceebb19e7f9e64,
+                0x6d87066018ab916c,
+                0x448de2ba295743f2,
+                0x4dd6ac6aa3e26ee7,
+                0x2b76e46058b57742,
+                0x00761c1ea34f00bb,
+            ])),
+            Felt::new(BigInteger768([
+                0x5df6ded996fbda77,
+                0x40b255ba60c034b9,
+                0x96a2968ad1c29571,
+                0x62fe229bb8082d1b,
+                0x270e747ce13c88e1,
+                0xd1d239a73e63750d,
+                0xaf2eabd82a9d27f3,
+                0x05759dae350b0b8f,
+                0xcebcb8c78b073282,
+                0x6f4d4c2a4c1738de,
+                0x958875a45281e794,
+                0x00a8bd84bb3f8ff2,
+            ])),
+            Felt::new(BigInteger768([
+                0x4436e24c856f5de6,
+                0xe0f3506cc550fbc0,
+                0xdbb4727c9ef4f0ed,
+                0x451430352f37a6a8,
+                0xbddd39bb01c33304,
+                0xc0b48a0f26a18955,
+                0xad48d173071927b6,
+                0xc7390882e8fd30c5,
+                0xc86f968fbed5553e,
+                0x3f370cac79676b8a,
+                0x8ca4347882bb17a8,
+                0x0026d1d03607842c,
+            ])),
+            Felt::new(BigInteger768([
+                0xd4c76363602e8c24,
+                0x883ccfa0fb2cb6c7,
+                0x78c00bc222914c45,
+                0xfd62568eb971eed6,
+                0x33a4053e2d17f363,
+                0x9f43ff947c4ca1e2,
+                0x1cb70e5076e890b7,
+                0x28bc56cefb4a6120,
+                0x1961cd2d94b4a0ac,
+                0x34dd3f4edd2fce97,
+                0xaf814cc9f1617531,
+                0x00db3dd5f7a3adb9,
+            ])),
+            Felt::new(BigInteger768([
+                0x2015f1988d8d880b,
+                0xc7fe5ae4f7f38dc9,
+                0x15d0d7704d2fec9b,
+                0x3ede6de549abf9ad,
+                0xdc3e15822cefde4b,
+                0x88f9c5d76c83cf8a,
+                0xa973e5911ca6ab68,
+                0x8dde5597b1d6e3d0,
+                0x82f4e8489932ba0c,
+                0xb0e972f5edec3826,
+                0xe2344897215a76a1,
+                0x009cec0f51657b51,
+            ])),
+            Felt::new(BigInteger768([
+                0x5351ee95ee80779e,
+                0x1aca4e275649e8fb,
+                0x7524710bb2ace285,
+                0x6284ea30df6b100f,
+                0xe4b7a6dd964b6bab,
+                0x3a90154e1fedcf22,
+                0xa27b401be7907f20,
+                0x500bc875100dd60a,
+                0x6d42451b8d29b81d,
+                0x4a0f013e920e59ff,
+                0x45f75f6ef22865e7,
+                0x00f8a9c257a71184,
+            ])),
+            Felt::new(BigInteger768([
+                0x538c45013bfc6973,
+                0x674fb6b53e8320e1,
+                0x1b3c83785fea30d6,
+                0x73b0c52db26d1543,
+                0x03b694877a22fbd8,
+                0x598bc25bd5a85af9,
+                0x0288cffd6ab773d7,
+                0xe788e1533f66f42d,
+                0x68cdfdf641b69c48,
+                0x369f5887f11e19a9,
+                0x8b810e1fb7e2eb75,
+                0x0008ca315da6c735,
+            ])),
+            Felt::new(BigInteger768([
+                0x2548008dc4ee361a,
+                0x841ae522e3f2fe86,
+                0xb79aa70e3d917ade,
+                0xea37cb3aecef042e,
+                0x9a73e8bfcbda54b9,
+                0x90a8f3f8ded9971e,
+                0xe33644df9c977b85,
+                0x8593bd9ad8514d7a,
+                0x8d89e30cfc63d1e5,
+                0xd42dd12308564db0,
+                0x029cbc38876b603e,
+                0x010db194f554c5bd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x72e010d607d3e190,
+                0x28a65f62ae6b7bee,
+                0xef254eeceaff9500,
+                0x6258894601b7e7e8,
+                0x5572b8d0dfe88fc2,
+                0x4c181153250f3df4,
+                0x67fa967db2966029,
+                0xfc1691ead3f131c9,
+                0x893b26544b1bd533,
+                0x259ae516e0c0b3e1,
+                0x4acd543b038247d9,
+                0x00e59db2208aeb21,
+            ])),
+            Felt::new(BigInteger768([
+                0xdd07ca5e7caa1fae,
+                0x422ca089293b7eba,
+                0x54718eadd12fc58c,
+                0xa72c2b8efef31b59,
+                0x5eb76b55b34173e4,
+                0x45f5779e534d76c0,
+                0xa5a25f81b17aa66f,
+                0x7188ebd35855a3c0,
+                0x158c01589cfcfeb8,
+                0x17d3466e099514de,
+                0x715ba18b57405181,
+                0x01142fdf6581cf93,
+            ])),
+            Felt::new(BigInteger768([
+                0x671266ba7813d2bd,
+                0xad2c198fbfa28e86,
+                0xadfda66ad812575f,
+                0x74a2082d33f4f949,
+                0x5399a8f6db288d9b,
+                0x3d0dbf109773561c,
+                0x32aba357cc7d5fba,
+                0x11f8a2e27b110fd6,
+                0x03c40b40b68c4be2,
+                0x46a01f8ab711ca49,
+                0xbb9d846adbfd63f3,
+                0x006df050ae554634,
+            ])),
+            Felt::new(BigInteger768([
+                0x0058b01dc084992d,
+                0x6128fa5ee3bc5181,
+                0xf4ef164b3ec468d2,
+                0x9119f61c68826f86,
+                0x8f2f2b12d56c1e4b,
+                0x9a4f6d419ad56f31,
+                0x47ab097f6ca9c560,
+                0xfcfbb0ea59a06130,
+                0x0f4fc7a9e25e9fbd,
+                0xe96b36b96a4e6186,
+                0x2192f739030839e8,
+                0x000c6f289b715972,
+            ])),
+            Felt::new(BigInteger768([
+                0x4e660c53f5d7cb2a,
+                0x5bb77670c99cd586,
+                0x8fa7119cacfbd687,
+                0x915be16bb7cecf26,
+                0xc6ba133fc5a9c456,
+                0xa9266ae10b7cdd3c,
+                0xe5bbd885d6a3721f,
+                0x35d18d1163525fa3,
+                0x186117fbc168c090,
+                0xd816af929c00eeb4,
+                0xd769c224ae72489c,
+                0x00a327570a85e144,
+            ])),
+            Felt::new(BigInteger768([
+                0xd0a1a49ed40de1b0,
+                0xbc60087d3d9c1d0e,
+                0x855b14f46a91716d,
+                0xb9a6af0db3527a83,
+                0x42f1f50be5b672a1,
+                0xc2bf95d103d87293,
+                0xc297dd11453870e1,
+                0x568a9b72bada0fc4,
+                0x26fd9c4f57aa1e89,
+                0x82d5940deebd43a8,
+                0x8948550a4223ac79,
+                0x002a37f56257f2b7,
+            ])),
+            Felt::new(BigInteger768([
+                0x7d29e2b978ecdacf,
+                0xe6b7e028424b7831,
+                0x6d8505523c154cc3,
+                0xd49145c8208e7269,
+                0x6f4f4509019a042d,
+                0xe34502943bfa216e,
+                0x3e93d6079eee933e,
+                0x3413f83dc5cd6163,
+                0x50207a967022afcd,
+                0xeac7b48f735a6832,
+                0x16d3c8b055a394a8,
+                0x005cd4fd9cdfa451,
+            ])),
+            Felt::new(BigInteger768([
+                0x63aa077237daf00f,
+                0x983a774d2edaa79b,
+                0xe16d089418881b05,
+                0xfc5072ab7028a300,
+                0xaf46d1dd97a5d30c,
+                0xfb7c9af70faf1a89,
+                0xc751f75219b96132,
+                0x2e36b2a669004b72,
+                0x39b1710af874bc33,
+                0x2b6e0a822e0f1845,
+                0xc85231f8d2ef56dd,
+                0x00f42544ec9a4844,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger768([
+            0xe001a85774623f96,
+            0x7ceac46301cf65c1,
+            0xe79732421de6b323,
+            0x32e4b2f13be8ba61,
+            0xb637094b108d6a2e,
+            0x96e946b9d55d6955,
+            0xfa1381f8438cec94,
+            0xaab903e0b3310e10,
+            0x17098b9900e341ac,
+            0x594bfb26fafe7a33,
+            0x82f50c0b5b3e2d27,
+            0x006936a2fbbbc6cf,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x3b842eb7ee9f43e9,
+            0x66ce1cc3872ea206,
+            0x869338b3ee5ebad4,
+            0xccc4067df97feb37,
+            0x177ffe538bddbb63,
+            0xc447f73c67d93ea0,
+            0x44e372203e81fd7d,
+            0xf880859863b2d6f1,
+            0xf0ef9bbd669c733a,
+            0x48b82f9a11e5c150,
+            0xc0b82335d1ed7fce,
+            0x003a71d5814cc279,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x940dd173056a2559,
+            0x3af307db211eaafb,
+            0x526ba81d0412017d,
+            0xf6ed90ab23ce1bf3,
+            0x921a036d92aa48b0,
+            0x476bd1e32250cc88,
+            0x11d8a9bc3660364c,
+            0x6fb86e2fab51d13c,
+            0x5f06d7e978da8bdb,
+            0x37e05929dfab79f4,
+            0x05adb2481c01faf6,
+            0x0101e66b70d3db9e,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xa052e4754125c538,
+            0xfaf7e417ad410c85,
+            0x675c6d464f4a2a19,
+            0x457b4885b976fade,
+            0x9deb6edebec09c71,
+            0x626518f58ce6b952,
+            0x299e7d5423cd5854,
+            0xc285e47961047b94,
+            0xdc618a4ab41b7b82,
+            0xc620b8b25485af9d,
+            0x1906a65dd850412a,
+            0x010ed2edc44c28ba,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x8f5333ccb5b1238c,
+            0x67f1b7f5ac090b6e,
+            0x4e1918547da58574,
+            0xcfb5ffa103b1fcda,
+            0x529156cbabb1b7d6,
+            0xc69f4226aa2d6016,
+            0x4173f9c7163cbea1,
+            0xe488272620eafa5e,
+            0x37b87cc3c56575f5,
+            0xfcd83e4a45255521,
+            0xd4114481e2ce3194,
+            0x00b5b20c6a1831b7,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x1caa8d7d2969c49a,
+            0x463fa780b08c3c5f,
+            0x463ea3a65ddfc106,
+            0xf79cda33d175ac73,
+            0xc82be69c3ec39d81,
+            0x08d1d7cfa1fe9913,
+            0x45224fc3686c543c,
+            0x8493dff6c79c82ae,
+            0x43114cb4d618e66a,
+            0x23707da7f06ec1e2,
+            0xc8ebc39e05c26c1c,
+            0x00844b4e4d03a197,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x6c97b40ddd04fcde,
+            0xeffcef9e614d7346,
+            0xdeef9121c63073a2,
+            0x49606b15ff6b8cb9,
+            0xb5f21a0c4ab9a5ee,
+            0x028d4d7552c19f1d,
+            0xee867ab14aab8c80,
+            0xdf6009e900265a4b,
+            0xacf19770d191b7a0,
+            0x0e552e0d4fd23065,
+            0x5bc7e688a1848f6a,
+            0x009591b21b96ed54,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xbf75cd006f8de55d,
+            0xad11afb62236a9a8,
+            0x7f305bfae1db2c8d,
+            0xec54022618e39aaf,
+            0x2bfd406fa376d0b0,
+            0x678f6b940d244672,
+            0xe2096796ca08c3c4,
+            0x7d45ca09e3704491,
+            0x90d04f3abb7a10c9,
+            0x95deb6bacf709edb,
+            0xd59bb93a20819670,
+            0x001cecb1a4fafb6e,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x8f75ccf1e9174aee,
+            0x31315dd3108f73e5,
+            0x7c0b11435ff0e035,
+            0x1b06698b2083ffe8,
+            0x0445ae46b9908c63,
+            0xb730adad7e77475e,
+            0x186e90db7b8dd3dd,
+            0x778fe059bd658123,
+            0x1ff1b758cd171c41,
+            0xbcb2bd9133629a03,
+            0xf89a8f214cbfcb82,
+            0x00d96218e8450ded,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xf839a081f387357b,
+            0xdd505be822c61f78,
+            0x20dec29dcd1387f1,
+            0x712405e7cb765587,
+            0x98854c3ec52b0711,
+            0x9be15fcc8a6dd579,
+            0xe602b7c42558c5ca,
+            0x804856bc0688273f,
+            0x06527a61a60b8e55,
+            0x41508c287efd9df4,
+            0xd8ae8f88700004fd,
+            0x00043bb883a7aad5,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger768([
+                0xf4543d69182129ac,
+                0xfc2b148f6f32d303,
+                0x0199bdec53486025,
+                0x37724223aca2285b,
+                0xfebff7b93291d1a7,
+                0x579f29cd1e77306e,
+                0x3a076148dd17bfee,
+                0x09b26ab1ec4fd83a,
+                0x9188b40f6c0f789c,
+                0xac08c0bc83cf5ff6,
+                0x670b23bc54fe91d5,
+                0x00679957cf3beea6,
+            ])),
+            Felt::new(BigInteger768([
+                0x28ca0dc34551521b,
+                0xe229e7ea479df68d,
+                0x0e59f94ef9df1076,
+                0x3445cbafbbb608c2,
+                0x4a7d7ff56b2598c7,
+                0x74315b3bf8ecd184,
+                0x14fd4cf87210af2d,
+                0x955473cd563861d2,
+                0x29a11062f9a862ce,
+                0x606a401daecca2ea,
+                0xa99a70a74b2507a9,
+                0x00914cf38372d655,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x73507bbe2ace62b2,
+                0x9b5563ef81816948,
+                0x5e6b9e2640f5f025,
+                0xaf25c8a018801435,
+                0x690e4e639ea632e4,
+                0x3188fd12c1a67998,
+                0xe43e7d61782612e7,
+                0xae5bd369a2e019c5,
+                0x872b6a92b42a0f90,
+                0x739b5ae96afd63cf,
+                0x786f3a9e6be7291f,
+                0x006851ca2b8ca610,
+            ])),
+            Felt::new(BigInteger768([
+                0x13b77d72c56cb90a,
+                0xc1713f9b3a3e8d52,
+                0x31627f8db97dabec,
+                0x5efa0306792260d5,
+                0x55e962942d8cdaf1,
+                0x1ee84fcec66db6c6,
+                0xbaeb304df3a29fbb,
+                0x2af96d4f03ef68e7,
+                0x47da7d86a172ba12,
+                0xe152f2752487d0df,
+                0xc552eca7615edf22,
+                0x0029eb33832a9ce0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x09213e5247176c93,
+                0x06d0bc8c3ebf72db,
+                0x58d6e1ef33dcd991,
+                0x4ee5a1de3e1d4d6f,
+                0xb1bdd904b6e26cba,
+                0x20c77a3ce30f6752,
+                0xa6d6b8de4d2517e3,
+                0xe2b7753f36fcbf28,
+                0x484bf732d4996b8f,
+                0xcce81d18a3269222,
+                0xddc8127946c99ab6,
+                0x00afde4992a09ee7,
+            ])),
+            Felt::new(BigInteger768([
+                0xd477d58bbb35d195,
+                0xbe8d5c9b242e00f3,
+                0x61633290d4b91b94,
+                0x6c72d449c3b7fbf4,
+                0xaada9120a173032a,
+                0x680891085ba84f84,
+                0x5b15dfb2f5f0e928,
+                0x04c22f99dd773c4e,
+                0x5571f6740930b595,
+                0xcc867a8b2a9f9ddb,
+                0xae938fb1ee9b59c5,
+                0x0000108b6b7fd2b9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger768([
+                0x33d15212a8fcbbc2,
+                0xee66abe26a0e6dfe,
+                0x7a8b456012dea926,
+                0x8fcea007721317cb,
+                0xb9b937e979a00b60,
+                0x525f1159eae37556,
+                0x10acc161812ae17d,
+                0xb2ecaf910c4ddded,
+                0xf5776303807db707,
+                0x53eebdcbd2559d1f,
+                0xeb6f7a8d96ea0685,
+                0x00dcd318657ed611,
+            ])),
+            Felt::new(BigInteger768([
+                0x47d578db35ea1d5b,
+                0x87ee88b130c1cc64,
+                0x6e9a6f7dbf3b93c1,
+                0x8d5ff7010847b633,
+                0x3e68126d4b978e75,
+                0xd5d64ae802018b74,
+                0xf3ddab4b66e36868,
+                0x695426d0369ea1b0,
+                0xb9a20be8fa73c95f,
+                0x0ff9daa15a5341f1,
+                0x359bfbfed1244ed2,
+                0x00066a953a0546ee,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger768([
+            0xcaf708bd99131940,
+            0xf122fd258ed29d2f,
+            0x3aaaba5658e4f01c,
+            0xc66aca43e9117cf8,
+            0xcd99d7e834e32d46,
+            0x4f19cd417f1a6c96,
+            0x987ce8d907e6f353,
+            0x25e9292a2e3eea5b,
+            0xc2595c87c118f6c9,
+            0x0868064e45f659a1,
+            0x2407e07674d170f7,
+            0x009d21e349494548,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x4219ecd3a0d42590,
+            0x57a517a56c9abea9,
+            0x9c57707b2d89611e,
+            0xd92b4a76910da47e,
+            0x7946a021fec027eb,
+            0xc7e2d0488268991e,
+            0x4e43e8c876d12438,
+            0x2da342beea7dd45c,
+            0x7bcaf03cd825b518,
+            0x6edd83f4e808c065,
+            0xb074d1b65b6b5467,
+            0x00ccb8ae26e79467,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x68ff96e6f988011a,
+            0x350746830be9fec7,
+            0xb0b4899215a118ef,
+            0x6311bfed777c5cc3,
+            0x6f68684446f778b1,
+            0x8c591f892090997a,
+            0x2edbdeaac9eeed74,
+            0xc327f4c4d712da1b,
+            0x1735ad23a6d45101,
+            0xdb46d008e6695ec1,
+            0xf69dac98eac4e3b3,
+            0x00ce4584c6e37eff,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x56e4ff84f21deb07,
+            0x08b5440e06e84134,
+            0xb9d23d4b48199e70,
+            0x48fcdc57707e2853,
+            0xead54fa715eeb44e,
+            0x5b6f2d970efb7455,
+            0x53f0065d21d70cd0,
+            0x8d9b9ba14210ac4e,
+            0x1a45930b12309fb4,
+            0x509d227aecd56665,
+            0xee09215e793351a8,
+            0x000f31ac94b73a93,
+        ]))],
+        [Felt::new(BigInteger768([
+            0x0593dfc71c88cf43,
+            0x56e6f8acd543b09a,
+            0x6ef38890cc25cf7a,
+            0x570e9d6599981dc5,
+            0x77e5d0c3a8d895f3,
+            0x1d6cf5e10cd791a3,
+            0x588d0d6b53658869,
+            0x481560e8735ee089,
+            0x431c7cff617b0d45,
+            0x1b70393594365203,
+            0xa9df29e712040d3d,
+            0x00ac69667ac54425,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xbb27e3141c71d3d3,
+            0x5cc3324acd01ca2d,
+            0x926943730d1a8750,
+            0x574df1ecbf474fff,
+            0xe3486295aca6d1d8,
+            0xff7ffc730a4816dd,
+            0x9aaf129f1a6c841f,
+            0xe83f9104cd6f3a4b,
+            0xfd99a268e523ec95,
+            0x723213796b08691b,
+            0x14618f791b0fc314,
+            0x004beb9e7633c3ef,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xa9cde4e5c04526f8,
+            0x4b0ec74da854512d,
+            0x5bec52165b30e775,
+            0x0fddce67e4037c6f,
+            0xe3ddf8756dd17c05,
+            0x9e1e05822f571efa,
+            0x8b4e757f52025afa,
+            0x4ee2fe249b1b3db0,
+            0x65494b0e00c6443c,
+            0x8cdc0d2b2ea3c1d9,
+            0xca1df2b633d260c9,
+            0x00693ef397218157,
+        ]))],
+        [Felt::new(BigInteger768([
+            0xbb186ee5f9975ad6,
+            0x5a25ce59b2393fa4,
+            0xb2c8984383b1b634,
+            0xeb7733e50f6d68ef,
+            0xb9267bd74f809171,
+            0xfa6776aae2130ae3,
+            0xbc5d7b7ed3fe1f26,
+            0x4c0076123e617ba4,
+            0x44d0437ce4d75e3b,
+            0xf419ba503b206047,
+            0x2c3332000266fd84,
+            0x000c6cf8d06716a6,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,3594 @@
+use super::{sbox, BigInteger768, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 768 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x638323e95b58e89f,
+                    0x40673c4dc2ef044d,
+                    0xc7137c69a8aa8d4e,
+                    0xb707754208eff8df,
+                    0x2a22d13cf577d06a,
+                    0x3c4e59e9ba808078,
+                    0xc746d052db9970d3,
+                    0xc403ffe5a0217434,
+                    0xe96a24d2bbea197d,
+                    0x8b83d4e99222f175,
+                    0xdc3c883831302025,
+                    0x0111695149545b9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a988fdf58756110,
+                    0xc0ce56a480159549,
+                    0x1b2a45730f25d576,
+                    0x7778f01fa546b6ce,
+                    0x7bf015681088c28e,
+                    0xaf1085367432f421,
+                    0x928e28e927c012bc,
+                    0xac00145ed09d5c81,
+                    0x63aa0c2493d55673,
+                    0x0ec7938edd78c5b8,
+                    0x90df19f77c74094f,
+                    0x00b4289a12987206,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f5f5f389e7fc048,
+                    0x48a3562686832e4c,
+                    0x6f81ac3292927ed2,
+                    0x4f22ad7fc45ec41b,
+                    0x9770ac5f1d53d432,
+                    0x7c08edd2855f1f52,
+                    0x206ef5512d210fe5,
+                    0x674dd7acf7779129,
+                    0x8a7a006c6696e060,
+                    0x18862e9bc60df606,
+                    0xb0950d32311d5bcd,
+                    0x00f7936eb93aca24,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1296695d50674c59,
+                    0xaa1dd33940320afa,
+                    0xdc2817f200c5faca,
+                    0x5ab7222c33b26b6d,
+                    0xac9b87ffd2b0711b,
+                    0x36d0723452e94495,
+                    0x9b044d08f808d9ce,
+                    0x538344684d59c11f,
+                    0x52727eaf2b60f5fd,
+                    0x01f8f30a62f57838,
+                    0x6995ff03c482fa0c,
+                    0x003ce15334fc6ea4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f96ad8f7606a6b0,
+                    0xe3f7cb87d9962e10,
+                    0x39c6d73c71b9081c,
+                    0xd964460ffc083881,
+                    0x9e158dda96847ae0,
+                    0xc173b2f9c026bdfa,
+                    0x3e2efa462a99f23f,
+                    0xc3abd005ca3853d8,
+                    0xab01b71644434182,
+                    0xe6d63db98bc1c2b5,
+                    0xc1f4f3660b4d754b,
+                    0x00186e2aa198a2fe,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf33a797a03dd9c6e,
+                    0x3773d98fa5e74782,
+                    0xdc4235c4b34abcf9,
+                    0x2567b317f2021e53,
+                    0xa216004eecb1065f,
+                    0xa6dbc803926a5f93,
+                    0xba74434f9023d86d,
+                    0x5972c5c7030aa323,
+                    0xfa5186ba374488db,
+                    0x13b47415cc681af1,
+                    0x89ddbe9f4bd2c8a2,
+                    0x000549f23a6ad683,
+                ])),
+                Felt::new(BigInteger768([
+                    0x20eff2338e8d1314,
+                    0xfc415661aad2fe14,
+                    0xc01f7660956e212b,
+                    0xe9bf2a7ba3160a0a,
+                    0xf8b5161e2cce472d,
+                    0x18eca4523fe3ee2a,
+                    0x2582014a31fb0d2e,
+                    0x669b6547ca5f3c39,
+                    0x62aafa9cde3fcf5d,
+                    0x539767d530f54633,
+                    0xff9daa7187472b21,
+                    0x0032b20e15751457,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb5a5382f3846a85,
+                    0xfabfb9bac65f9351,
+                    0x9e9b9ece3298bc28,
+                    0x50a58d58a2450073,
+                    0xccc5dda55eb87bba,
+                    0x023101144fe173b8,
+                    0x2cdc1d58ff10494e,
+                    0xb25aefeba4d5af1e,
+                    0xbd614b011da0a4c3,
+                    0x652841cf9fbb9f6e,
+                    0x2b35bc3a6acae118,
+                    0x005d06869f3e516d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x552b5bf0fefaf3b7,
+                    0xafd0f655eb35bf3f,
+                    0xa3366fe6a00d51da,
+                    0x075666d55d30422c,
+                    0x5482e6b38a8b6e4e,
+                    0xc23cd3e8983f0a09,
+                    0x588b7c3d0730335a,
+                    0x6b95c69f96f8d839,
+                    0x660cf916156b730b,
+                    0xc81bfde8bbe3ae07,
+                    0xd83ba0de1ee50f14,
+                    0x0090eb49b6ff9537,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b46ca8c3d012107,
+                    0xd609ff14cce59717,
+                    0x17605e4b8cd538cd,
+                    0x293bb4a283d10a0a,
+                    0x34537c41c5509067,
+                    0xdcbcd6e31a20a564,
+                    0xfcf845c7a3c5eaf2,
+                    0x7815e0ecd8e5e9a4,
+                    0xcd2cf7c24828cb36,
+                    0x4436c9c34e1b7554,
+                    0x0a014ef100f126ec,
+                    0x0059df196570653d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x14f23ca0e5222698,
+                    0x75d0924e5166f541,
+                    0x3bb9b18689554add,
+                    0x6bf9dcc2c86efc03,
+                    0x30ef0d04a04ce4a1,
+                    0xa13e6c7729b74e72,
+                    0xff5063036aacb52c,
+                    0x3a7e0aa5a895b6c1,
+                    0x110c89fc9e0fd4ae,
+                    0x8c3b894ea866ac40,
+                    0x9a333c2a7536be15,
+                    0x0093a569cd706f05,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf5c3a3852bf27cc6,
+                    0xd3dffdb2509c003d,
+                    0xb569be09c8d0ed61,
+                    0xb5842a6884abf029,
+                    0xde906a0769eea9eb,
+                    0xedcbe2b86f3b0cd8,
+                    0x6eedf6bcf68a83b8,
+                    0x5ea4b0b729e5a3d3,
+                    0xe9e4036949da641f,
+                    0x758b92f3af098606,
+                    0xceef8ba05cdb0088,
+                    0x005e2a1e304af7fa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9c25427a846f4f18,
+                    0x5da7db3454661b01,
+                    0xb9e2bde255c3444d,
+                    0x6a6fa2bb9dd300f8,
+                    0x72e6cce4a7bf2a42,
+                    0x2c133e86610e447d,
+                    0x23d230cf3709b87c,
+                    0x9c0f83dc50b1406a,
+                    0xa02878165586783f,
+                    0xcb017d0720399e9d,
+                    0xd1a43c1d311ac818,
+                    0x002db0a1cdb2da94,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4449cca577af147f,
+                    0xe285ce1ad29389a9,
+                    0x2a9ccea4ab60f015,
+                    0x237e7df4fc9aa3fb,
+                    0xb37bd0c71921958c,
+                    0x36550d07663e5f27,
+                    0x6ab545f8974876c7,
+                    0x367eaaf586416ec2,
+                    0x9a0a4abd6b38975a,
+                    0xa7547480123f86a8,
+                    0x9eabe79889555713,
+                    0x00fabee8bba60421,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ed0267bfaf8cc1e,
+                    0x96622e02183a11b7,
+                    0x0498bf41f287a4e1,
+                    0xbb76443a2ab5fcd6,
+                    0xd145f2e4d9cbd605,
+                    0xe1f62ef11f301378,
+                    0x0abfb2d68dca6491,
+                    0xfe3c4eb9c99c7595,
+                    0x4891ea8096d5828a,
+                    0x55c52d6e3c26f620,
+                    0x736d601c1ed4e224,
+                    0x01136a799498aabd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x54565cf6999c3ee7,
+                    0x5514c5973304030c,
+                    0x67de4d7d83ad90a2,
+                    0xfb12df93ba7196fc,
+                    0x877e447a99c18da3,
+                    0xf517ea98e92ba131,
+                    0x687e43abe7e88dcc,
+                    0x998aab14591d5882,
+                    0x24b3f6b9b885b170,
+                    0x1449a336fab9dd94,
+                    0xf9a35707ac339736,
+                    0x00f9ac87e0b2d4e3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7f1126561f93e180,
+                    0xc8cc81379f1d883c,
+                    0xda660d7cf0b76967,
+                    0xb8e7c46d7aab4b59,
+                    0xfcdccbb50a6ba487,
+                    0x77eb4358d1b8e305,
+                    0xba5241a0831553e3,
+                    0x9d2d2591924b1374,
+                    0x5be5a4c6fbb3e7ea,
+                    0xea950f8385929380,
+                    0x8a34c81e8082d4a7,
+                    0x00a378b569e89b6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf67500d3817226d8,
+                    0x9e4c743a02b13d1f,
+                    0x9348b8168b86de9f,
+                    0x639c01dfc5f96003,
+                    0x378de1afb41802a7,
+                    0xd56a996813400b42,
+                    0xe119385e8fb04bdd,
+                    0x6895937cd63a542d,
+                    0x2bb2dad09dff060a,
+                    0x8215ccd7651d4137,
+                    0xe4b9fd79288c6708,
+                    0x011ffa48e0608ffa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe61a7064d1704343,
+                    0xd9359d7a480fb181,
+                    0x3afd88af482b4c24,
+                    0x8c652ea564b1bae4,
+                    0xac1396b24e60e99b,
+                    0x6f9fa43d19e3b97f,
+                    0x8fb2798cf11e23e3,
+                    0x523bd8546945980d,
+                    0x89d1edb7c7be61b4,
+                    0xfcbcb203a1dbe47c,
+                    0x3204212ca5080352,
+                    0x00e9984ab3d0a844,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb776a57c20fa308b,
+                    0x12f7b0dfbe5f6ca7,
+                    0x1806a99a58d5802c,
+                    0x9b1853269ec67cf7,
+                    0x418c614e6345818f,
+                    0x48f045e34e579258,
+                    0x1c678c88e0876118,
+                    0xc6edd9f4f8b14638,
+                    0xe2c80bd5f1a2e77a,
+                    0x265e191fc27fb53d,
+                    0x35984365d7f55f49,
+                    0x00ca4b84f13303b2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2f35ca00c64dd883,
+                    0x9f57f162a2c45748,
+                    0xddae2ca85d4805c6,
+                    0xa85d6582cbcce22d,
+                    0xd9db6311f9971987,
+                    0x90791c4089b2590a,
+                    0x387190e8b5ce202d,
+                    0x68c88e18c68fbd56,
+                    0x19265d9fc3d428cc,
+                    0xbb72c724285cc073,
+                    0xa22b5f7775c0f50f,
+                    0x0012e4fcf32cf4f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x862c03b5b34f8ab6,
+                    0x436cd1fa63d53b8e,
+                    0x19d6e18469f41c39,
+                    0xf96f82dc7d33eb6e,
+                    0x98b077e2efdd884e,
+                    0x2ce91bed08178516,
+                    0x948a710c652810ec,
+                    0xa54ff3ca569a28da,
+                    0x4c1b12f2645ff574,
+                    0xe9364027a65dcac3,
+                    0x168e2f73371aa189,
+                    0x00572845f1d1ad2b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf1f9e18c47357b4d,
+                    0x9fd863de28db461b,
+                    0x5c34c8435a00ddfe,
+                    0x58c3a7da354ba7e7,
+                    0x532c1fba6bc157e7,
+                    0x51c5ec7c0db0f584,
+                    0xaaa9ee83c238de49,
+                    0xd256e5ebb3595bbd,
+                    0x2da338ac0999fec0,
+                    0x7db5771fe518e31e,
+                    0x618cfb10df50d1f3,
+                    0x0121bedcb0700798,
+                ])),
+                Felt::new(BigInteger768([
+                    0x401b0e32a52505ac,
+                    0x63d986c26da585d1,
+                    0x6ee2f6f414397dfa,
+                    0x04187760dd393afe,
+                    0x7b4490b1efa013f6,
+                    0x82acf1d2dbf48d0d,
+                    0x11be9efe8e54d437,
+                    0xe16a3c914daa5891,
+                    0xbf247e533654775b,
+                    0x8acfc1022fab057b,
+                    0x9f1f1e57b9e1e3cd,
+                    0x0055ef0be3b2c1fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x58ff407f33094086,
+                    0xecf4933aae5f780e,
+                    0x55e787702b4d4fd9,
+                    0x1c988bf7d4425fee,
+                    0x7fd36f80164ded63,
+                    0xe13b33291e034297,
+                    0x362b109ea69da4b4,
+                    0xa3255534cec8929b,
+                    0x8f8bc78d3da44488,
+                    0xbf224b65b68258dd,
+                    0xcd1deeb2696089f4,
+                    0x003e186f55743959,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaaeaad0134a3ec29,
+                    0xd7346f1de6e84bcf,
+                    0x8ab535863e169d2c,
+                    0xdd089ed73b8a9a55,
+                    0xcd1fffa269aab773,
+                    0xe2a362f8557f77b4,
+                    0x0c8bd8c8f8d3c610,
+                    0x7a7f92595f64c3fc,
+                    0xd63d3b5ccc585b09,
+                    0xf86c17c92be3fd36,
+                    0xf2d40ce9d6cef2b0,
+                    0x00f85221184e277d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x872aa52d2af4e1f7,
+                    0x61e1bbc84ec5d554,
+                    0xe53d31952c935bf4,
+                    0x72ecf1c8747b597b,
+                    0x5bfcf662f9bf56ed,
+                    0xcb63c22639b30ae7,
+                    0x257179371cad1a3b,
+                    0xd706c17527ca7738,
+                    0x45d8316190a40448,
+                    0xac3e98f42b05e18c,
+                    0xc963db082605b252,
+                    0x0003845d69dfbd8e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xac5c1128325f8aa1,
+                    0x88ea0cdfbc22ab01,
+                    0xf9952a9b09843d58,
+                    0x969ec3f46ec3abe7,
+                    0xe62be9c3965b0847,
+                    0xf04b61288d061173,
+                    0x71ddb10c96493e39,
+                    0x1a48850d63150b9c,
+                    0x7351737e007027eb,
+                    0xcce55a981dc0e796,
+                    0xaa357eec6347520f,
+                    0x0083a175d9432fdd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb16d4c8168e7f7bb,
+                    0x85a54359a42acefe,
+                    0x08bb57bf9ad4151a,
+                    0x4042b2529d50535f,
+                    0x6f0cc9142b75677c,
+                    0xfca4aca55ff25ec9,
+                    0xae5f063be69a20bd,
+                    0x086a9ca14ebaff7e,
+                    0x20da81b42c0434b3,
+                    0x2581f9c6da3c8119,
+                    0x0a9484e0a47f2493,
+                    0x00f13a0b476fbcd5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f32325366e00232,
+                    0xfae10f0cdb501bee,
+                    0xc6d930825dcfa5b5,
+                    0x8523149b4e8699eb,
+                    0x03fab3c209c3a829,
+                    0x653a192948c4a0b0,
+                    0x91dfb4ba3cc490b9,
+                    0x794915e01bf016b5,
+                    0x517a748f32eb796a,
+                    0xe0dbf2f8143c7527,
+                    0x466cbd86c06261d1,
+                    0x00131e79de5c893a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c4edf7cce80d676,
+                    0xee83d8deefdfa02f,
+                    0x1d4874acd41b1680,
+                    0xa7893ea1b3cf2714,
+                    0x6f9f7b64831cdce6,
+                    0xa15710414ddf1b6c,
+                    0x24109eb1d5860889,
+                    0x0572ea59eefa8c28,
+                    0xb6fb923b89057d5c,
+                    0x11bd6861748793d5,
+                    0xf68336194fdadab0,
+                    0x00f4fbecca2b1f0c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf3bb657be32f50aa,
+                    0x977d5efabc401c2a,
+                    0x23847fa8c279f16c,
+                    0x7e2a95baf7cf18b5,
+                    0x987a4a95b499cbc1,
+                    0x30bb94ba79173dc1,
+                    0x9de73d7758bc7084,
+                    0xce3c05bc0404b08b,
+                    0x5d1d0b24fef245d6,
+                    0x15948c77a805b3c3,
+                    0x3bffe8d9973b407e,
+                    0x00d81917ad776d04,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7ff3c6e057952d4d,
+                    0xcd8be2e415b9b8d0,
+                    0x9414b6cd599ad13d,
+                    0x6c521412afb8f244,
+                    0x0934b0302cc4bff4,
+                    0x343b106b986d2a9f,
+                    0xd9f307ec32d33146,
+                    0x1e39de5346af2be4,
+                    0xac9ee40c0559bcdc,
+                    0x373f4bda841af9e0,
+                    0xf38b110891962b1b,
+                    0x001a7f705b74e81b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9fa59bf62332b3d8,
+                    0xa005c7d734a3509f,
+                    0x48aae8287da3f6c7,
+                    0xdcdb7595ea8d26f2,
+                    0x32195380e2a972df,
+                    0x5460aa68d0e99594,
+                    0x2db8c061687ca7f0,
+                    0x9177bf18f7704d75,
+                    0x6ce605abbeb8be70,
+                    0x2cf7dd7dcb086a78,
+                    0x6c1c48837abc461f,
+                    0x008af24d5e5be3bd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf3f92c335cc9738e,
+                    0x36434eeaa8c0f783,
+                    0x562f9ea1ec6ac98d,
+                    0x9486f9877e42a960,
+                    0x6fd0a2079587d6e9,
+                    0x334a69eb7c8a0149,
+                    0x59ef848035246b89,
+                    0xa723394ce6cbf216,
+                    0x7d30b5a4cd6351e2,
+                    0xcdf9d1f121b57734,
+                    0x3a9d640efbb405cd,
+                    0x0109ca9974481322,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6271aa3ba1c929d6,
+                    0x089223c58afceecc,
+                    0x037f38ad8b199f79,
+                    0x9ee05326f6196f45,
+                    0x36769b5084604747,
+                    0x94da832f432816f3,
+                    0x667e0754867fea50,
+                    0x78e8b37f27d4a626,
+                    0x5f15383f4ee9d682,
+                    0x9e34fd29701380df,
+                    0xff929715815dc896,
+                    0x003f41363ba07e05,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9078ba0ff8453c11,
+                    0xadec65aab39b7ce2,
+                    0x11ff57fbafc16d7b,
+                    0x237709f3829292dd,
+                    0x00f5c0ff0850824a,
+                    0x6829a979973ca32b,
+                    0x7e6f62d7666ad166,
+                    0xcb0a7512b7682caa,
+                    0x459e46a70abda138,
+                    0x61430841c2ab2e7d,
+                    0x9437b9acad508b61,
+                    0x01076df27870c8cf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3752892e7e4a8e29,
+                    0xe8d6b7b8d5bd78f2,
+                    0xb86939d561a0181b,
+                    0xcfefbafab1244875,
+                    0x890c5eda8cee8b00,
+                    0x947ed4e746b884e8,
+                    0x53d20f9076d772fb,
+                    0x0c0ac6cffa77726f,
+                    0x5a04bc5fdd946d82,
+                    0x520f1e44146a276f,
+                    0xef93f98647ab8b00,
+                    0x00b6c5fd25856ff1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2c3d5acd60b56afc,
+                    0x8716876e65a743bf,
+                    0xa865a6d889d50efd,
+                    0xb5986df287cc4b8e,
+                    0xed2c57561b1641da,
+                    0xe48bdde0fbe1dcd8,
+                    0xdfb8dc1d30adfed0,
+                    0x9de9ae01c3ea8ac6,
+                    0x483fb03877a6aafd,
+                    0x20f0041f9ac480c3,
+                    0xeaa553bfffdd6c78,
+                    0x0035fa2a013b9843,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc3ffa35d767162b8,
+                    0x979584ecb145a103,
+                    0xea35b6d30ef8137a,
+                    0x466f11c19eb418e5,
+                    0x17453acc7929082e,
+                    0x5917d7cb0209500a,
+                    0x755f0709c8053bbd,
+                    0xf689014dba656148,
+                    0xff68c6f7b4820f96,
+                    0xb506fde93b5db843,
+                    0xd32c17bd27f63236,
+                    0x00c24810293cd8f9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x038f5e20d6b78631,
+                    0xb75b2fd4a4c78205,
+                    0xb341ab0dd957e1d1,
+                    0xbcdbb080388cc823,
+                    0xa7e56e1e25b76c84,
+                    0xfed1c12167335a66,
+                    0x040132e83b8767b2,
+                    0xe89de15269a845e4,
+                    0x410dd50b8f9c4c68,
+                    0x98e0ce0a662f186c,
+                    0x3c27753a529919bf,
+                    0x00b8a08d74706fd5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbacb4b59d084b189,
+                    0x91d6b6577ac86402,
+                    0x842a09b1e5d5d314,
+                    0xe38a8bc23145b1db,
+                    0x96248fbcf4a99a3c,
+                    0xa2c14de3056b9220,
+                    0xfdcf2a033ebaf91d,
+                    0xac50034351000c18,
+                    0xa2e13112bb799773,
+                    0x1fe3a406a3e935c8,
+                    0x73ef7a77f132a60e,
+                    0x01170c76df3505ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4d6a5a41e3879645,
+                    0x244a0dc99a5a0b18,
+                    0x37346de04549d3f1,
+                    0x480b42f1b2fc31f2,
+                    0xe462f00af0ec8cac,
+                    0x2105cfb80d2aa836,
+                    0xed5be395842452d8,
+                    0x1949c805fa3c8825,
+                    0x008f816e63f867e4,
+                    0x1fa6112a1f05cd2b,
+                    0xf30c109b8c32bd6e,
+                    0x0100e1a085142327,
+                ])),
+                Felt::new(BigInteger768([
+                    0x34f740c873f10e01,
+                    0xe78a489b8c5e6f20,
+                    0xec46b05d878325b1,
+                    0x0e657ff29631cd65,
+                    0x32fa1e0cd03d3d86,
+                    0xd7bed02d2ab2df47,
+                    0x89bbd27b8f23bf5f,
+                    0xc2cef5ef3f1e0bd9,
+                    0x9147131f50dc136a,
+                    0xbb5068b60ec8fdc9,
+                    0xc2cf324bc161140c,
+                    0x00f7fc195b5bb7ac,
+                ])),
+                Felt::new(BigInteger768([
+                    0x53dfc6c9b43f5d8e,
+                    0x0fb482d5c7503dab,
+                    0x71f4692e71c2c3cd,
+                    0x84e80a2659dab582,
+                    0x30454d9bd2927137,
+                    0x3d72d6ff09c7e169,
+                    0x0ca2bb94b5f7512c,
+                    0x35181284bfb0b195,
+                    0xaae178cbd769afa5,
+                    0xbceac3e7b9918c73,
+                    0x151012cc114b0aba,
+                    0x00ae40db6450537f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6c8c946640cae992,
+                    0x672becadb74ec871,
+                    0x7ecb59a43641da6e,
+                    0x9c0d51cf08356405,
+                    0x18197931c41369c2,
+                    0x7b2a961dbe5a0f8f,
+                    0xeaf013e051e6f682,
+                    0xc0013d0b254d21f5,
+                    0x9e9adbdbd0ec533e,
+                    0xbc05644e8a4d535b,
+                    0xcda7b8eb3c8fde7e,
+                    0x00744241ee10657d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d69582fa47df31a,
+                    0x88815e92e4b719f1,
+                    0x1593ef8aaf27338b,
+                    0x1f1d803c0f8dcf88,
+                    0x15cf74509a48a74a,
+                    0x778c94c13ec6fbea,
+                    0x99e60e35633918cb,
+                    0xe220f4cd990db6e2,
+                    0x59553335529f68cb,
+                    0x9062f140c04060a8,
+                    0xb037987417e0f5f3,
+                    0x00235b8cdbc728de,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb6d98489ab9dc527,
+                    0xa48e5e09c62c4113,
+                    0x1a6b207c43846ba0,
+                    0x5716f28307c842a6,
+                    0xda96420b15a3204f,
+                    0x81eb56efef390dca,
+                    0xf0b783e6e451a905,
+                    0xf328292f4a4ecd4c,
+                    0x3cdc21a1ac32a256,
+                    0x474f7d208d9305fd,
+                    0x5fd1e291160c22e7,
+                    0x011f3f37366cab87,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc6e7646707f59fb6,
+                    0x3ad532f7e82f63b4,
+                    0x9e70c31adfe8c867,
+                    0x563bcfcecba8589f,
+                    0x4809a363ca6f824b,
+                    0x69e8cde32a65be3c,
+                    0x67dae349c179128a,
+                    0x3bc8f637c0a0311a,
+                    0x052a307361b89f14,
+                    0x2ebc7aa4a08444d3,
+                    0x1988321b40133968,
+                    0x0061da2df8708fc8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x259c2ed0fff740d7,
+                    0xf5ec63f896a993e6,
+                    0x93a098dcb43c6e84,
+                    0x2a1272ff9733bde2,
+                    0x1eb8e1ae4f9efb7c,
+                    0xd847f6bde45878e0,
+                    0xbffad808112e8e04,
+                    0x954073c8c60e9da7,
+                    0xa22d73b017a29e05,
+                    0x66b114e58a2b96cf,
+                    0xca2a1f162b56b83f,
+                    0x001917e1ee4604df,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe096881cb44c444f,
+                    0xeabaecbe43282b5a,
+                    0xf8c9c32459aa18c4,
+                    0x9e91074023bd09f2,
+                    0x907e09ec8354e538,
+                    0xb052dc84e18252c0,
+                    0x96ae849cf914e8b0,
+                    0xe6be6cc0a4e89b30,
+                    0x4557feb52388f12e,
+                    0x5dc355d302956962,
+                    0x507792a51c195e2e,
+                    0x00596d8395bc93bd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0d0c278f47ee9eab,
+                    0xa9e05215cb9a48f0,
+                    0x53a091d90da5d85e,
+                    0xbf7f78a34370b01e,
+                    0x11f66606896e1b32,
+                    0x791afd36eec8aafa,
+                    0x8746651e8f7df9aa,
+                    0xe0cd86a091a9455e,
+                    0x561acb1edcdae3bc,
+                    0x847ff72652ae26b1,
+                    0x2ef30f1227bad431,
+                    0x008ae2b0cf74ed6e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x091554e28cda9408,
+                    0xcf9193ff9d444d11,
+                    0x5b629d9255f4a741,
+                    0xdfe9ad50c596edea,
+                    0x24587d79558bd026,
+                    0x297408c068f5ac1e,
+                    0x4ccc6745847c6bbf,
+                    0xd7aa83930735c91e,
+                    0x983fcb6ce627867d,
+                    0x8a05b1e346350f58,
+                    0x6f9f6c05509e4591,
+                    0x00cc736169328cc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x21a042bbac543040,
+                    0x7fa18aea800ee2da,
+                    0xb981472df2f329c1,
+                    0x1a93b73b00f24370,
+                    0xc34013dcec651307,
+                    0x9c23207ed5ea643f,
+                    0x804ac0870c4ee467,
+                    0x2194bfb33b44524f,
+                    0xeb4029c3050b25b2,
+                    0x17fab58286aad28e,
+                    0x857b9f779242fc7d,
+                    0x00186006d29d9cd6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x310f9e2995e2ac8b,
+                    0x4df0f307ce630782,
+                    0x2a008194d919a657,
+                    0x5f480497233fe2b7,
+                    0x81a4fd2dccaf045f,
+                    0xe9b26e7eb37aeb66,
+                    0xd1583636c5feda39,
+                    0x0d6f41b80233fca4,
+                    0x86c1407ed59b6b31,
+                    0xd659471634af80f2,
+                    0x8df4b92ab970919e,
+                    0x00772be9b434f20f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1518ff14add37d60,
+                    0x384b44483722f175,
+                    0x03a7259f5b748fbb,
+                    0x5a414e5fd6f3466c,
+                    0x1c97766cd7c91d77,
+                    0xac6f596864690f8d,
+                    0x5dc47c416265c377,
+                    0x1ffc7b98d529ed48,
+                    0xf54a5fb03d9db5f4,
+                    0x1672cba316b550d4,
+                    0x8644e640ba633e6c,
+                    0x012006c03fe67f8b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5b43754058c42b85,
+                    0x1dd305ae45d2e5ee,
+                    0xe7eac02eb0562a8f,
+                    0xef2748a42d392d29,
+                    0x10d8a24fc63ecbe7,
+                    0x8f277de23388dd5e,
+                    0x00b0d9b58beab175,
+                    0x6907d36b8096a3f1,
+                    0x934cbb3a6d3754b1,
+                    0x2b5eba49296864b5,
+                    0x3bc9ef47a5499b56,
+                    0x0094c43b672247a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbb913b0aa52aad84,
+                    0x9dd106ec5829b849,
+                    0x5e0653c8d1dfd24c,
+                    0x77166c1b6b3daac2,
+                    0xe729f535901873b0,
+                    0xa8ea12c31aadb4cb,
+                    0x530f547196e92ab4,
+                    0x4149fff94e3400d5,
+                    0xb68efc6182305c3f,
+                    0xe198574a8ac3d9ef,
+                    0x9bf9e361ae5f7319,
+                    0x00f9d84cb322e693,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc9124df519ebb665,
+                    0x719aa570905e71a3,
+                    0x83f7c212e0b934db,
+                    0x510b32e187a332fa,
+                    0x1144416ceae5a57e,
+                    0xb704da10b64939ce,
+                    0x06f659fa93bb5007,
+                    0x0aaafb341dc33ce8,
+                    0x3a49405d4911ef32,
+                    0xc01075c04bfe01a3,
+                    0x8b7bb387eb9970aa,
+                    0x006a695508ea8a4a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa98c816da8f904c5,
+                    0x92f958b2b9ca9d73,
+                    0xa1c79eeac0c4f5d7,
+                    0x2d62acc5d3035efd,
+                    0x2f62fd10a0d9f149,
+                    0xf345b6292c07c90c,
+                    0x3c898e05e07181d4,
+                    0x1b90f8fc82dafd56,
+                    0xf8ee87a8642039f1,
+                    0x087feee97a5346e6,
+                    0xc0ee36236690fbc0,
+                    0x00f9ba6cd232477e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x616181a0e7b93c36,
+                    0x7e01e972a4608c81,
+                    0x718bb3c25edd679a,
+                    0x3dadd465f6c4118d,
+                    0xe047515c05933373,
+                    0x76ebdc21d69c784e,
+                    0xb4089ec77627906c,
+                    0x7ca9c0722c2f4ac8,
+                    0xcd9db6301300a23f,
+                    0x74c891c17499b62f,
+                    0xbe63f7007929fca4,
+                    0x0114eb6931903171,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbb663460b7251227,
+                    0xf9bf0349f144cd58,
+                    0xf1aa1068ee62c69c,
+                    0x55f9450d10ade1f0,
+                    0x18cbe023df4d8ee1,
+                    0x77abb51fa2b43a53,
+                    0x17768a55be94f49b,
+                    0x8dfefa52d9c67c7d,
+                    0x6f74225a86025d17,
+                    0x9542f6c37e6e8ffc,
+                    0x427683c2fa46962c,
+                    0x001745cf501957f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38cdc13f32c2d957,
+                    0x7815db47608fbd7b,
+                    0x32022990ae4b5460,
+                    0xe68e1b8b19b5c026,
+                    0x5772826a487aa8af,
+                    0x9943482e2cef2aa6,
+                    0xa625aed45cc18ac5,
+                    0xbf7606f3cbaf4331,
+                    0x144d2cd58022d3f3,
+                    0x4999e4cdeb8af2e9,
+                    0xe9e6c1b51cefa0c8,
+                    0x00f3a6c5dabf482f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x212d2b051aa9a99f,
+                    0xbeec328c8e75e09c,
+                    0xe6d0a222da31c2d5,
+                    0x387250a75fe08f6e,
+                    0x2c33e8f8764b1c9a,
+                    0x5fb1da0ec32abd00,
+                    0x6a977326af43b3aa,
+                    0xc780ddf92ea7d1a3,
+                    0xebacd4b654186d3b,
+                    0xba69a8a828cdfd2b,
+                    0xe5dc93df79a4f7fc,
+                    0x009965bd5ebf6010,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9883860a8844b88a,
+                    0x147b8eb4bbb976e5,
+                    0x6b9e233a43838180,
+                    0xf58a3a692b47dfe6,
+                    0xbb0fe5748d2cf358,
+                    0xd3cc5717da0fd255,
+                    0xb6b11c13e5351f4f,
+                    0xccd191ed900da986,
+                    0x36c2274b4244e867,
+                    0x0252160176a7615d,
+                    0xe5ebf3e6671ec858,
+                    0x00eaf3cd81724f40,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf89d1ccf10ded37b,
+                    0xf262b506852631f0,
+                    0x8e646cad63371cce,
+                    0x890e8c2cbc7c9006,
+                    0xe00f893351b5c1e6,
+                    0xb64594e64ef1660a,
+                    0x367f44cf105bd7fb,
+                    0xef44435ea1bbe40e,
+                    0x984dc1c79a5ec005,
+                    0x1addd400902977c4,
+                    0x085f9ae9c53cf1e9,
+                    0x002a68cc34ad8f22,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe1d680512dbc2653,
+                    0x101b3cec19899ea6,
+                    0xca2dc2804d8c60e7,
+                    0x2bae219d59607206,
+                    0x52a1207a6f721bbf,
+                    0x19380b46a44d6947,
+                    0x4c6440c533c646e8,
+                    0x0833f50e8bc6352e,
+                    0x15b23b1d779c90e0,
+                    0x8415321c92bcbe63,
+                    0x01b5319ece565807,
+                    0x007bb6eb3f9d5ba3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x356d1e5937071112,
+                    0xd627968a8185662e,
+                    0x3cfaf6af272a46fe,
+                    0x0991a6e932aa6e76,
+                    0x372d8a5527e2fe28,
+                    0x9a4e30132d9e9509,
+                    0xd45903c3dc502559,
+                    0x0028ca44d7309482,
+                    0x47b8ecbabfbfc39c,
+                    0x4a0b20bd32278117,
+                    0x10d7bd5c06ce4b72,
+                    0x00c65381e0c1e924,
+                ])),
+                Felt::new(BigInteger768([
+                    0x08aa2752da49be78,
+                    0x367d23c4bae4dd04,
+                    0xa7a8cb29d7008858,
+                    0x9fb050369aad149e,
+                    0x953e5234f1141f08,
+                    0x4d0f2e8474baeb8c,
+                    0x505cd6176f9aa359,
+                    0xd7016e125c45aba3,
+                    0x48eeaccdbb4900aa,
+                    0x078950bfb1d8adbc,
+                    0x76b27f3795debc27,
+                    0x00a965f8b16d61e2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e975f68e9b65f0a,
+                    0x5b8d13c6be120aa9,
+                    0xa6cfb4f1458f5bb2,
+                    0x6189f4b3afa4b274,
+                    0xb50e501440735495,
+                    0x5e0305d3a5104a42,
+                    0xb438b70a0454c543,
+                    0xf5788f66ef5c3848,
+                    0x18eab5cbc67e9a7d,
+                    0x927c97b2212d3a24,
+                    0xc42b6a0e76686ece,
+                    0x010b00b3ff79d96c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x065b4f3857ada924,
+                    0x75c3d43914951535,
+                    0xcfd4c420b8bef673,
+                    0x5e2b7c6f107d4a59,
+                    0xc529aeba87f6a742,
+                    0x03c4077253277857,
+                    0x7159bd960058d6f5,
+                    0x00e0c4261c4bcf81,
+                    0x00c952f2a3cf2738,
+                    0x7643aef0ddb4a56a,
+                    0x804ea43f74335bec,
+                    0x003eb78ba35f2908,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbed55a8d1427d308,
+                    0x1e1cbca15fd06b27,
+                    0x7afb0fcca4f3edaf,
+                    0xe0a3c2c7d0431c04,
+                    0x6ae436541fe9fdb8,
+                    0x77a8008db309de2f,
+                    0xb4832fe65e72aeb0,
+                    0x161088a116669fe8,
+                    0xcb32bef34d22ba04,
+                    0x28d7861f2b1fdf88,
+                    0xe9534179fb603a3f,
+                    0x00e84fb40449146d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1264517d42eb1d9e,
+                    0xdc3fb36ad2f45049,
+                    0x1c584cff1a739ff3,
+                    0x6b14f403d2221a51,
+                    0x029f09d142cc2af4,
+                    0xef5f90e2596636e7,
+                    0x3f8f10fb92d74c9c,
+                    0x4bf5a195962e7811,
+                    0x37b033d11d179b6d,
+                    0x69291e6897d960d1,
+                    0x6b90e1ca4c8e1c10,
+                    0x00cf42f070fa28fd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3367337b4029e1b1,
+                    0xbd1ae5bf93435276,
+                    0x30cad48b1f81d127,
+                    0x4d4b30bfb42802a4,
+                    0x7e27e6d5faf010d1,
+                    0x85d23dc867e4375d,
+                    0xc5d718ee61dfc10f,
+                    0x3e31a7c0ac8a4aae,
+                    0x4ab0d51f815dc0f0,
+                    0x3e4976bb77104a16,
+                    0x91cfd27f084776e8,
+                    0x0061863985ae63b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfbd7eaedd60fe256,
+                    0x990b6e289e82b6f9,
+                    0x888ca0972c727e46,
+                    0xa0f6f494e4cac5d4,
+                    0x35b2650958d028ea,
+                    0xdf3babd6a08ac59b,
+                    0x1d8d45605f7bb3f9,
+                    0xdc87f0b5f6b08098,
+                    0xa34078d2a19eaae4,
+                    0x53c55f3611523bbb,
+                    0xd98a6e6e7d7ecbb0,
+                    0x00a180b92ce18153,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa346e9d2368ea61f,
+                    0x0af049087869fd26,
+                    0xec8d1b9422e6a522,
+                    0xd74d3662e428a622,
+                    0xcd7bc562a8e6ee2e,
+                    0x02be93cf20a213ea,
+                    0x54115b376a80c198,
+                    0x4dc1eec19da9501b,
+                    0x2ba75c3cfa988c62,
+                    0x156b0812f6382ef0,
+                    0x1ec4b46570507c8b,
+                    0x00c43220ed202994,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0f0c0099491101c8,
+                    0x50fcf34905e84899,
+                    0x7462d58b0b08ffb0,
+                    0xa5c4df6fb54824ef,
+                    0x7e4d56c2d95ee1a1,
+                    0xe1f7db908eca2646,
+                    0x843965a118eb067d,
+                    0x2bb6dc2c5a3888d8,
+                    0xa6683d17ed537bcb,
+                    0xd970fa0956d50547,
+                    0x0ebb1877f4376711,
+                    0x009df12a2c291fdf,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb47e2d5321f7f04f,
+                    0x457f8a22bc6ee81c,
+                    0xd5dc30b3351274e1,
+                    0xdf21ac13fd25d3d1,
+                    0x6aad3dc14aba8a51,
+                    0x29d524532fa7b818,
+                    0xb29fc62bfb3ccdaa,
+                    0xdabe4f89b3ec1bb1,
+                    0x3877a87476e28dac,
+                    0xfc69b9215ad4059b,
+                    0x2d3a6ab5eca50839,
+                    0x0108dc2f8ecf8816,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdff8083dad904e3a,
+                    0xbfa1c06a8bced16d,
+                    0x3e65844df736092a,
+                    0x6e669b5dad724e0a,
+                    0x584915abf88fc937,
+                    0x5d43274a1b7f201b,
+                    0xc9763532a3b2b48a,
+                    0x6b89508cf85ccd51,
+                    0x91a8630e2ac9a391,
+                    0x0a2a7f6ae7902ae1,
+                    0xf9b3f540791e2851,
+                    0x00a77b881d4e0509,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0b4b6e0fdac5a9c0,
+                    0x1a80adf7aa2fa862,
+                    0x4e5122cd50e235aa,
+                    0x8540e66a6b536bfd,
+                    0x2670c02114b54931,
+                    0x2f5787c49ba4ee48,
+                    0x3f43526e429169c9,
+                    0x28aaf5cdce687742,
+                    0x54b3b753555970a0,
+                    0x35d1517aca0602e3,
+                    0xdbc8aa196b6e6197,
+                    0x0023b7d2d628f579,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x10d402cdd5847232,
+                    0xe50b5da719029c28,
+                    0x660d1c7f0b6fcc1e,
+                    0xd94f0cf868102a15,
+                    0x0527d8a7f6551c1d,
+                    0x30c0eaf9176d3166,
+                    0x6a100386e9ab15d5,
+                    0xa06eb95443f8c59f,
+                    0x0b49f8f3b94722be,
+                    0x02b40c31655e0fb5,
+                    0xf047a12be59db65c,
+                    0x009d25f065cf0f9e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cd5ce52678a0f98,
+                    0x4d27914500b95085,
+                    0x713db9c66972a0a3,
+                    0x6ae645fc68242bed,
+                    0x0e59bb995cfbef8e,
+                    0xff8a21416e624395,
+                    0x856c51942e9ad8d6,
+                    0xb41b379fe21c7621,
+                    0x2202726d85b7bf10,
+                    0xfd986804d70bdd9e,
+                    0xa4a1c750d6e4172f,
+                    0x001a38899540437f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6563fdca138f6bbc,
+                    0x8f0c8409b6836fa4,
+                    0x046c07b1b1618ce8,
+                    0x6652a8ae9c107fb9,
+                    0xb44f38759bf39fb6,
+                    0x05b4d7bdc5e8d03b,
+                    0xb8ac169797213232,
+                    0xa390506113238fdd,
+                    0xb5ef3c183c795963,
+                    0x8885713894999606,
+                    0x95c3b820e6876352,
+                    0x009634fa1ae14aa0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb5b46a2d37b91776,
+                    0x77449a6782a9be5e,
+                    0x3eddc6807bd8c65d,
+                    0x0f7dbfbd2902585a,
+                    0x2a35a03ec549a12b,
+                    0x9ee4abe25248ce9a,
+                    0x4881626031a729da,
+                    0x3dc86611cf7e9b47,
+                    0x94b74776ffa7f2fc,
+                    0xb20e8e7180a069aa,
+                    0xe5b2091921b2e1b2,
+                    0x00fe3304d619d97e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x24ad9c4c9108a18d,
+                    0x220d4581e1684896,
+                    0x1277bf51f8d17837,
+                    0x96f1823e4edf88b2,
+                    0xab2a6aa1805b517e,
+                    0x0591bec4920ab6d1,
+                    0xdaf40236a7c3e0ff,
+                    0x1a40f82f88140349,
+                    0x31efd66747cb1309,
+                    0x9825a01680b51aef,
+                    0x6cf4d36a5ae99342,
+                    0x00f4e4b29a62f053,
+                ])),
+                Felt::new(BigInteger768([
+                    0x36360da9055048a3,
+                    0x6ab0cb9426a6a745,
+                    0x63ad336f97f00ba4,
+                    0x88ae6588ca067114,
+                    0x2aabd285daa604d1,
+                    0xaf0e02453783542e,
+                    0x8be2c0da885d7869,
+                    0x0a766f7dc43f5853,
+                    0xaff9fac1fd157aa5,
+                    0x20901e9ba055feb0,
+                    0xc59992ab9cda898e,
+                    0x00c6f9ca6524789e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22302389d7a8425f,
+                    0x41e13006ed1a5acc,
+                    0xd04dc2342d265795,
+                    0xf91c329f6b0355d0,
+                    0x7edde96b2ba44e92,
+                    0x4ecce5cbd2275593,
+                    0xb00523e24ca4ac68,
+                    0xebd3f8d3ab838803,
+                    0x3c4971696000eb65,
+                    0x9791d49926211eaa,
+                    0x9973598fb53be039,
+                    0x00ef4e92674d6d57,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb8c981808ec342b9,
+                    0xb5543b372e534a2b,
+                    0x85ac9bcd0532fb9d,
+                    0x8cd3eb77651491df,
+                    0x786c4cdf5d2a2729,
+                    0x92be083f705fde2c,
+                    0x91434bec5d40e3a4,
+                    0x1411964dfca8d3ab,
+                    0xb6642ab8f2fe2234,
+                    0x247319d93545824c,
+                    0xec02a133656d7cef,
+                    0x0043f13cb8792390,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7ea4cdc7c45458ef,
+                    0x72dab7b74d963757,
+                    0x37f7bef05ce28017,
+                    0xcbd610eb8304b5c8,
+                    0x0fbef11f81aef047,
+                    0x49ce67f358dbac94,
+                    0x92a36dc48be4fd31,
+                    0xba6f63ffe6b7c70d,
+                    0x7ee0f313d15b739f,
+                    0xb7ebecdac9a227a6,
+                    0xb2a2a2543e3dfff6,
+                    0x00584bfe539dab40,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4e6a3791ef150b98,
+                    0xcda4183eba46f511,
+                    0x96691ad22b49d72c,
+                    0xd3303a62c31b3cdc,
+                    0x0b4a618ab102d1fd,
+                    0xf2e062d1c47bc489,
+                    0xd3d4a06328336b12,
+                    0x73f0002b869c3eed,
+                    0x4c0f2ca2cbf8ba58,
+                    0x350cb69ac9bd9891,
+                    0x63d164ff9e6142dc,
+                    0x00588b5a34c6b170,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfe79f9d4f74b5244,
+                    0x9b91e95b8bd3c71c,
+                    0xf6bf8d399493dfa5,
+                    0x0dce9e85b6179ca1,
+                    0x8f8a0cdb19934b51,
+                    0x659c5296f2d6ac11,
+                    0xfa349504c9175135,
+                    0xf1e028a3805bf4d3,
+                    0x84fd61afda485208,
+                    0x5019788190856043,
+                    0xfc497e38dbe0204c,
+                    0x0023e9ae70b7cf23,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7839d7768d009ea5,
+                    0xbbf0c34edca6bc1f,
+                    0xd0df9be0c23a8e9e,
+                    0x6da40af905840019,
+                    0xe22d9bf65088173c,
+                    0x87d1a8a583d4aba7,
+                    0xcb0988f7d76de7ce,
+                    0x7dea7e5491ac0361,
+                    0x0db8f7b70de6b216,
+                    0x1a1d181c2fdf265f,
+                    0x183f427f0b19f9de,
+                    0x0039e59f9b9ffc3f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40a676ee2d8d7c08,
+                    0xd42cbef59e71c6dc,
+                    0xafd997bb13dc4395,
+                    0x6c2671506c41bed2,
+                    0xcdc651de85650bd3,
+                    0x04bc220734d89ba7,
+                    0xb163a1033ab4cda8,
+                    0x42519748b5d95392,
+                    0xab9c6a8fef5ae843,
+                    0x26ac2103a3df28b9,
+                    0xc96c38dd12fecc77,
+                    0x009bcd3806fe85e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x32934f7c5a3f6a02,
+                    0xdacefcb9b908499c,
+                    0x43a5f5d34e425714,
+                    0x3a970e95de1a0065,
+                    0x7bad0685e3ccb311,
+                    0x98b59a5772555bb8,
+                    0x635b6f5942061240,
+                    0xd360ba684a64b548,
+                    0x0b9a480c313a10fe,
+                    0x3b28a6fb34732179,
+                    0xcdf0ec882c9421e5,
+                    0x00df7b4400391c2b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x66b29afd6757e1de,
+                    0xa5874394dafa69ce,
+                    0xe68a189e1a217679,
+                    0xa53f98a9f221cbb1,
+                    0xfee81cadea646f98,
+                    0x0765cbf99e0acceb,
+                    0x9f75fb52762b1659,
+                    0x67ec1f06463aabb2,
+                    0x124ff1543522d986,
+                    0x39ce646d61e47503,
+                    0x7b4edebca63a979b,
+                    0x004520d76ff377db,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb92e7a3ec9900b1c,
+                    0x52423a6a41cb0ed4,
+                    0xa3958972d84f8383,
+                    0x993db8f3d98b447f,
+                    0xc1b0e2706bc2cfcc,
+                    0x4894ab45e4e29781,
+                    0xa76e6ab251b0b5eb,
+                    0x981ac225175fccfe,
+                    0x7ee39b1c7e59cabe,
+                    0xc14dca78b5e5a93d,
+                    0x58ee137818dc74ab,
+                    0x010dc741890e8828,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x37a4c9d114a18f52,
+                    0x44b11708867987dc,
+                    0xbac076b3c6296cb6,
+                    0x857b3b4bcb5b4392,
+                    0xd3d5235813924d29,
+                    0x8f3f948c99d9de53,
+                    0x25a18d2fd25d8368,
+                    0x859921d9c958d106,
+                    0x7ccec7b935cef650,
+                    0xd302153a7273a41a,
+                    0x7abd8446b7b37386,
+                    0x0121c163711aa372,
+                ])),
+                Felt::new(BigInteger768([
+                    0xafe75b5b70912915,
+                    0x6cafaef43a0f2b45,
+                    0x9b2d5c9131e53a23,
+                    0x36767216e7d42e42,
+                    0x1c49072733279113,
+                    0x420d579be6547d9a,
+                    0x26fc86f5aa4de90e,
+                    0x2b5b6ec909781d84,
+                    0xacf957773db5390d,
+                    0x22aa963e6c3592f6,
+                    0xc379a0bac5a24c6f,
+                    0x0085317616b5fc0d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x86e81360ee25a823,
+                    0xa148b7ef963790e4,
+                    0x6c538b034ebb97d5,
+                    0xa1ed7f9ee6eee193,
+                    0xc0bde4091cc02bfa,
+                    0xd74f5139cbc52635,
+                    0x8e1712e5c468602b,
+                    0x8b8cb436e9d3fa19,
+                    0xe910b39d1a8afdce,
+                    0x9014353895092f4d,
+                    0x761806a3fdfbb6f0,
+                    0x01191ed8d30ded79,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb20b653836d440eb,
+                    0x9b235ca8ad8d19e6,
+                    0x9507b6d70381e290,
+                    0xc4d81b8690fd761a,
+                    0xe1fde26a52d3cd18,
+                    0x2bd87af62dd8cf78,
+                    0xda3156aa6fe781db,
+                    0xabc0bf4f7541c969,
+                    0x4ea42cf4092191c5,
+                    0x125f0e1d62e76078,
+                    0x4cbccd62c8d17bc5,
+                    0x00db57762e6b58b8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x336fa818f704a41e,
+                    0xd139ab3a0bd8b952,
+                    0xbe77b09e00e1357c,
+                    0x04bfecb304573d5a,
+                    0xfdb6f4c59b397442,
+                    0x5ab3603f6aaaf49b,
+                    0x6b081157c18ac338,
+                    0x43acb2243c096898,
+                    0xa12fca788a09454c,
+                    0x47696d325ba26a3e,
+                    0xfb06cf1cfd6e5771,
+                    0x0078c2e9a39cc21a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x467884cab2bbfd53,
+                    0x7448bfd09c511d14,
+                    0x4aa5523da407181a,
+                    0x62c9ab4a13a87121,
+                    0xcc3968dcfeeadf0f,
+                    0x67e33a0e2ac9db3c,
+                    0x4014c6dc2dc17aea,
+                    0xc3d69953adf6646e,
+                    0x7974c9b48cfc65d2,
+                    0x89a078daafa379ce,
+                    0x27195ef01285e944,
+                    0x012253b353f81ef4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x012a67e03e021f83,
+                    0xed3fef7c226ba0f5,
+                    0x2bd6b719abb648de,
+                    0x3212ad1e08119ec7,
+                    0x07cf0932044dcedb,
+                    0x32994f6d468f9014,
+                    0xf013429186b49e81,
+                    0xf4eea6359aed95a3,
+                    0xfc06ea2848af0787,
+                    0x26fc5c077fa3df98,
+                    0x3a59b34c1f53e8c5,
+                    0x00f4bda908070258,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa60d99967ba23d6,
+                    0x53cf35753844157c,
+                    0x241f22e55093b39b,
+                    0x7391ebb0a1ed780d,
+                    0x90a0d0475bc9ce5e,
+                    0x76bc794b384fc40d,
+                    0x442b99bb00822d61,
+                    0xc137d079662761cc,
+                    0xc05e4ccabbf819af,
+                    0x91ac9e5c4723322a,
+                    0x69dbd0f90b5cdb8b,
+                    0x002026150283472c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x05a6165244efd4ce,
+                    0x08bd6330e7b1bad7,
+                    0x7112733df25932d9,
+                    0x087af5065261f083,
+                    0x124fef63b945d581,
+                    0xa84a207cc2eb982d,
+                    0x5e998d9c339d5a6c,
+                    0x7bdfede7ca3843f2,
+                    0x310deabb83a0e53e,
+                    0xe58f4739c3609214,
+                    0xdb0f324631133b52,
+                    0x005480c462fd7300,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd121a27a824f0f79,
+                    0x4d5a0f534922885c,
+                    0x4489e9a28816d760,
+                    0x9b4e6202f149fd0a,
+                    0xeb7a98b2b4d65ef0,
+                    0xa3ae2f6d818935be,
+                    0x8d635a0303cee9ab,
+                    0xb03e7c7ef8b011a9,
+                    0x3e876e31634451ad,
+                    0x614ecf913549b756,
+                    0xf784521fe23ec1bf,
+                    0x002ff5d30e2ed4bd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc671385deebae410,
+                    0x502a6ec1087e40ec,
+                    0xdb48ed5fff36183f,
+                    0x08860bc1ba9c2ff1,
+                    0x1322f21bf0ca2fd5,
+                    0x5b7927cc1d457ee0,
+                    0x3b6bdb13708709d4,
+                    0xf910ce36957bc5f6,
+                    0x40063f17cb0bb120,
+                    0x21f68e3956f0701b,
+                    0x4fcfbae4b348c605,
+                    0x00b1f61c48410f91,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff012fc92025c5ed,
+                    0xc8b685534a112823,
+                    0x2b2490639aa116f2,
+                    0xe9750905b62f2663,
+                    0x6b70c18ca7ec214d,
+                    0xeeb4b946e6e4dbd3,
+                    0xa2ffa8bc4a77e43e,
+                    0xa050640081830574,
+                    0x5cbd3bfbec804a5f,
+                    0x30a589982d8c27d5,
+                    0xc8d9fe23201dcdbf,
+                    0x00a6c88a423013e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x13b1876f484afff0,
+                    0x6bc0272073ce4126,
+                    0xcd89e8642c653174,
+                    0xb3b26ec1357af82f,
+                    0x3328c90fe806963d,
+                    0x2d6edf5061dc5bd4,
+                    0x1f3c3157bd046ee9,
+                    0xb17f8e2d2afae7b0,
+                    0x193d3944b8a8557c,
+                    0x3ff14e92aa46a44b,
+                    0x1ad99ebe1f191475,
+                    0x0081bc87f250f3db,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb455c848ec05ac03,
+                    0xef2227f1e9d37631,
+                    0xbe9c64d1b1dc77c6,
+                    0xe2a14de87893f001,
+                    0x0448a045d7c7f92e,
+                    0xb7d216577a93df73,
+                    0xb469918cd833d80e,
+                    0xed532945f4f9eede,
+                    0x4eb33e3bff813375,
+                    0xd0b0bb1700fc53a8,
+                    0xb31fc75d851ef6d0,
+                    0x00b2b5f2ef5e66d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26bb93647e1164d2,
+                    0x700c6a2df3b99327,
+                    0x774cea8afea4e305,
+                    0x98f6fe807c3eb10f,
+                    0x944e25f0c80398a5,
+                    0x402cd880b4c747cd,
+                    0xff7f4271c1efa4e2,
+                    0xc71ba7017efb19e5,
+                    0xc9bb4e45cfd8e8c4,
+                    0xf8ea309942c81e27,
+                    0xf0049be5ac2348bb,
+                    0x00da897f040576b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x543428f18209cce9,
+                    0x50eec7c9c138cfbc,
+                    0x988758fe3709d85a,
+                    0x523ee61765f3a996,
+                    0xdb86eb360561e6d7,
+                    0x243105e2484facfc,
+                    0x99c5b3b4dbccaef2,
+                    0x58d05df7d6e2090a,
+                    0xbb2fe78bdd7302d1,
+                    0x58b360c7ec655f6b,
+                    0x6d38a402787690d0,
+                    0x001ec520a3748ae9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x40a05c8099c6efe3,
+                    0x073e28971b1eb6b3,
+                    0x51a5e47149309b23,
+                    0xb36b821e1d1a01ab,
+                    0x710fd2eabcf2ca91,
+                    0xefe3f42f0df03c13,
+                    0x5488bb313b55306a,
+                    0xea91dbe3f3c7ac55,
+                    0x47eb40839911c1a5,
+                    0x6ddf8e8bb0713fc7,
+                    0xb0132a1a868fa159,
+                    0x004c34b4a558ee7c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7dbb0eb5332037ad,
+                    0xda76de97355a41cd,
+                    0x1fdd8aa44c6fda80,
+                    0x3fdc5825c3cb37c2,
+                    0x05b7668527011ec3,
+                    0x2a4a56cb1f4b6e6d,
+                    0x87b545223ea2ae78,
+                    0x8b297557706d614e,
+                    0xb73a7edb6239e6e0,
+                    0x713ea871812899aa,
+                    0xe56f77f0dae0cef2,
+                    0x000cc243716efbc6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbe701692776f2103,
+                    0xe561dc5ce15a8235,
+                    0x1e1121047cc14568,
+                    0x4714ce581099e6fb,
+                    0xc7f6aff94962d212,
+                    0xaa950bfcc56a211b,
+                    0x5af62841037f1801,
+                    0xd280b8837b54f451,
+                    0xbbecbe3f602ae9c1,
+                    0xbfabbe9e2e96628b,
+                    0x42187a7f613c9f17,
+                    0x0080ce5b6e13fbcd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcec1a68e8ff4c752,
+                    0xb05713b627fa6419,
+                    0x44e12cfc1240d6f2,
+                    0x78d6d328b37caf73,
+                    0xd67743f6f4cf6c85,
+                    0x1b7b1ba6968bc242,
+                    0xd861d1a44f66820d,
+                    0x87b87f2db1034cc9,
+                    0x09e61a1787a4eca7,
+                    0x9b7a990a6c626ddf,
+                    0xe525a280418e3d5e,
+                    0x00a38297d0da0578,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6c84bbbe339aad68,
+                    0xda54df9fd32d595f,
+                    0x7a8068cf171a88a6,
+                    0x7b68e95b17cf7722,
+                    0xec387c8b27a3b38b,
+                    0xea633b68f7e69b78,
+                    0xdc820e96453a1cb1,
+                    0x267806b2e9e0c145,
+                    0x0317088d955d2beb,
+                    0x49c42946338c2564,
+                    0x236a8624997ea34a,
+                    0x004237e6728a9622,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa769ad3938c5969a,
+                    0x4c058651acc9874a,
+                    0x4371cb611b31f2d5,
+                    0xbeb4fdd5821352e0,
+                    0x651f5a930cfde00d,
+                    0xcd5afc3321d192b0,
+                    0x47571274c55dd676,
+                    0x6c85ea3fdf3f1c37,
+                    0x623768cb5d0b814d,
+                    0xbbffb3ca72bb3a1b,
+                    0x077d7919330db029,
+                    0x0059d7b18c362e95,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf59613178ada5e7c,
+                    0xd21dd1d8436f315a,
+                    0x42631c6af090a238,
+                    0xa75323d9168590c4,
+                    0xd5558ae08bfc16c5,
+                    0x24e74e0bed4a3ffe,
+                    0xc47c5a743b350970,
+                    0x687c60ab41ee4d79,
+                    0xd26043e9c5378187,
+                    0xede4922683bd1d07,
+                    0x067955a8c158f112,
+                    0x00e14dda8af9cdd9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9397329407d63b78,
+                    0x3b2dce7b576d7c42,
+                    0xb7dbe6d457cd3b79,
+                    0xc796e3479cfcfa79,
+                    0xf1f2eaf1382f75f6,
+                    0x8aa3e835525fcfeb,
+                    0xea7b5f74f412e0a7,
+                    0xdd841f0f9846188c,
+                    0x36d0eecde023ad46,
+                    0x229151b8779f15e1,
+                    0xcbb6f15cbca5e1c0,
+                    0x003d3b75c7a88c95,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xcdcf9ec6f0d18402,
+                    0x6cbbbead3569c953,
+                    0x86710b2284c937db,
+                    0x8de348c1aff805b3,
+                    0xc9c97f08cbe57acd,
+                    0x342d7ee6ee540a05,
+                    0x8b44e83481cc09b6,
+                    0x59347563fe59201c,
+                    0x21593d3483ed16ef,
+                    0xc8812de83b20049a,
+                    0xcfd48c75c1cd30b2,
+                    0x00c7c6fad082f2cf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x95306edfe24f3176,
+                    0x0d15df792e68d639,
+                    0xd22460579dbd9adf,
+                    0xd53ea07864f6f5db,
+                    0x13a5e5add118f159,
+                    0x045b15c3bc9cd605,
+                    0x0d36381a1c382078,
+                    0x53bde84c032f5610,
+                    0x063f1b0fb2865241,
+                    0xa29aa0c8140382e4,
+                    0x4b54e9abb4c96a2c,
+                    0x0116a423d70e1004,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb827e7473dd534c1,
+                    0x141d2a3a48c39579,
+                    0x97d10a28383f8978,
+                    0x4af76fa12c92e29f,
+                    0x6c4d9c96c5934098,
+                    0x24d4f5a4ab54dbf2,
+                    0x61ed274daeef9763,
+                    0x8f79e744746813ad,
+                    0x314f589b3947553b,
+                    0x6a2aa1309c9668a8,
+                    0xf57887a741e58ef5,
+                    0x001faa712bd26919,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf7631a1abbb8ea5a,
+                    0x8044d64f21ed282c,
+                    0x3d3147bf3ec8cd66,
+                    0x334ba76afb18652f,
+                    0xdcafd30bd978dd8b,
+                    0x07bb650d8c3d534b,
+                    0x726b5d0093e459c6,
+                    0x397c2c097d4db55d,
+                    0x600556a79b78859c,
+                    0x93747950818d638b,
+                    0x15ab08c206d73534,
+                    0x002d6cb99214f1b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde1b5fa3a73343a3,
+                    0x6ed25d59a78566a1,
+                    0x6e234a6e2f65cb76,
+                    0xba2fecc7bbb0823b,
+                    0x6c7fda0656ed2bdf,
+                    0xb127f9f451b28017,
+                    0x382d589daa7946e8,
+                    0x4e194807bd5133b4,
+                    0x6465bcedc588c6f0,
+                    0x3599ce1d966a45e0,
+                    0x0eadc71a766a9c9c,
+                    0x00abe5a5fddebae9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51ebfaaed2a91997,
+                    0x635a132670059803,
+                    0x6b0837ef3951f2e4,
+                    0x69dd3cbb12e444ad,
+                    0xa1f711c56ce17236,
+                    0x5cec3e425571ffe0,
+                    0xae1a08bee8e70036,
+                    0x90c8c4a72bc0414f,
+                    0x7aa9de7d6a227381,
+                    0x35f4169c5bfbc929,
+                    0x491ea363a127d998,
+                    0x00cbd7b1d4112fab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x00b89f20e062f7ab,
+                    0x3df073c66ffaa14f,
+                    0x28be69df5d4e65af,
+                    0xbd5d14aba6183550,
+                    0x06bb04194e62fd46,
+                    0x8feb7ea0535fa91c,
+                    0xff2f5f91f8a2ef77,
+                    0xa136e95a4c9657a5,
+                    0x25358c562201f109,
+                    0x984dd767c98887c3,
+                    0xdd4e971b0fda6cc1,
+                    0x0030c2201d6ee2bc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4018488b4577f666,
+                    0x2a4c1ced32b75c3c,
+                    0xcec5021c404d3568,
+                    0x2d6cd77908de8a23,
+                    0x9f782fcbb88c8d78,
+                    0x1f54130f5571d76a,
+                    0xed932f7987fee698,
+                    0x40bee90152a7190a,
+                    0x7ac779c56f5d4d8f,
+                    0x340e2409900e134c,
+                    0xec23c93768025905,
+                    0x01185781de2376bf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4dedaf42601fde84,
+                    0xb6738d0c3940a572,
+                    0xa4e6321f19c31762,
+                    0xabab9ffdb8ca9499,
+                    0xeba8bb27b663a523,
+                    0x0fc2378af0a38289,
+                    0x2e01471cf30d4ce1,
+                    0x56987ad1df5a45b9,
+                    0xa7628d29e85882da,
+                    0xc22bd4313617bf0c,
+                    0x44faee2ce47fc2c7,
+                    0x00fcdaaa965aef19,
+                ])),
+                Felt::new(BigInteger768([
+                    0x711f58df3428dff9,
+                    0x73ad575c247ad36a,
+                    0x96b4773f7e002ea8,
+                    0xbf9870d01b1c6a2b,
+                    0x0ceb79ad7c40fdb5,
+                    0xf77125f0f9938a88,
+                    0xe0a111cb4f7fb461,
+                    0x60e7c0a70fd80ed2,
+                    0x4f760a78e68d5f77,
+                    0x528cc8a9fadb005d,
+                    0x47be527e67093278,
+                    0x005c76690c419d11,
+                ])),
+                Felt::new(BigInteger768([
+                    0x27bd06312a098898,
+                    0x751fe20f14f14030,
+                    0xb1688c2770e6838f,
+                    0x18f2755caafb4179,
+                    0xf6cb1d16e3288cf7,
+                    0xd07ff292cea78a08,
+                    0x69aa95da8a536601,
+                    0xe1d278096cb2f7e3,
+                    0x2e7c8d47c6652f37,
+                    0x9e6090b2c992d10c,
+                    0x32a4ff5ea59c3100,
+                    0x00ae210c355db00a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xac6326e2e33480a1,
+                    0x2ffc3ffb29ad029d,
+                    0x58a655b7b63161c6,
+                    0xd6d6322583bb1039,
+                    0x541da96a5d1998d5,
+                    0x19e9db5dc54c56d9,
+                    0x10c299203ee731f1,
+                    0x908242460da2185e,
+                    0x0a7f295d237aff97,
+                    0x33ac76e3eb040098,
+                    0x75aae870523cfb3a,
+                    0x00609d6bfd4ac797,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa48fab4be5ce1f6a,
+                    0xda3413f0f57263fe,
+                    0x4dff4581dc9e4275,
+                    0xb20f8fc1a35fa981,
+                    0x7d6dda06ec90eae9,
+                    0x8912f50b0da2cf11,
+                    0xd77b372a0ebbe421,
+                    0xe62d0c439f2436e1,
+                    0x55d4511cdab9eb02,
+                    0xe4db25f5cb2135c6,
+                    0x2fde3f1015ba0822,
+                    0x00ddb73b81606347,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0eb3bcbbf732ae3c,
+                    0x3643aa7bdaf13e22,
+                    0xeba702d19a131de7,
+                    0xe4fd46e058339662,
+                    0x9c1d68dba5dcf424,
+                    0xec3a68f00e7b3837,
+                    0xb8a0276877a33811,
+                    0xe20c07caebb4e18e,
+                    0x2ea9a878b70bd253,
+                    0xbfd8487ec3ca0439,
+                    0x42fa2bba4582be7e,
+                    0x0118daa3787d8641,
+                ])),
+                Felt::new(BigInteger768([
+                    0xadb99fa035c8762a,
+                    0xc1449abceb847e26,
+                    0x58bc7ba7c0a4e599,
+                    0xf03c90a98d8c7f5b,
+                    0x54de0a869ef9e563,
+                    0x804e2f981899965f,
+                    0xea5af8ef01a5618c,
+                    0x0087ebf3b254debc,
+                    0x438d171d25b06aec,
+                    0x76c2c4d15a3d3209,
+                    0x6a3d27b4da868224,
+                    0x0074fad25ee09359,
+                ])),
+                Felt::new(BigInteger768([
+                    0x60c42c2ca51d736a,
+                    0x8bbebca69f20df14,
+                    0x5dad87f682404188,
+                    0x6f78918acc69c942,
+                    0x8ebdf2f0929c26ad,
+                    0xa8a1477956ba70ea,
+                    0xe9f2ae416bdfa272,
+                    0xed69c03f791cb9a6,
+                    0xe121005ce728294e,
+                    0xacf23c4d2d2dd41f,
+                    0xa9405fd06680dd13,
+                    0x0109e249544bff97,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xcaa784b59e1df8e9,
+                    0x693c89b77411dd5e,
+                    0x51c9447cb74b583e,
+                    0x9f20e33fc1887cf3,
+                    0xf4cd1017e087564d,
+                    0x6dbf79bf59d9b9cc,
+                    0xe6e24a3a5f8ee299,
+                    0x8d187715e0378674,
+                    0x869cde1a0eeeeefe,
+                    0xf077851127b85b5d,
+                    0x72b218f2269bd1f2,
+                    0x00848c1536a3da89,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2495390588de087,
+                    0x9c8e33bb1eb77f2b,
+                    0x8bb8b2ac1e961bcc,
+                    0xde6c9433baaf1163,
+                    0xd2bcb1fa21a4f61d,
+                    0xf23d9cf6785f78ac,
+                    0xa29ba4c35b92451a,
+                    0x4492deedf9d0755b,
+                    0x7903a6b6871287c9,
+                    0x54f8453c88df583b,
+                    0x825f3db662ae8607,
+                    0x00a7f854dfb4a3d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa618235d4ef16c18,
+                    0x5cf35366dcd1e140,
+                    0x1e0d159829b1ec97,
+                    0x785da0f836b63001,
+                    0x42342ea13487a1f7,
+                    0x519a8a10987d37d2,
+                    0x7b95bf947c9d00f6,
+                    0xd580bce9f5fa2104,
+                    0xb01245b0a65adfd8,
+                    0x4eaeb9c149788d56,
+                    0x1ffdbd36f48c2b37,
+                    0x011422ca157bd4d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4f2980e9d8fa43c4,
+                    0x601eef9aa258c400,
+                    0x77aaf4227ecde89c,
+                    0x8c83f3663b6129a1,
+                    0xf560f538e112fba5,
+                    0xb1ba255d31063f5a,
+                    0x7ae47e063847dc33,
+                    0x11a27c06c69f2c3c,
+                    0x0bc47efa48deef9f,
+                    0x3f6373b51d6ca9b9,
+                    0xfa338bd876812434,
+                    0x00ec748dea4464bf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x56c94c9f05940b4a,
+                    0xbbb12f3a8cefb081,
+                    0xc5dceb190ecc218b,
+                    0xca46943c9b70959b,
+                    0xbbafa18f8827e876,
+                    0x0843d0ac3f63900e,
+                    0x251efa829242de67,
+                    0xaab41ca45af6e9f4,
+                    0x68e6225723e9dda0,
+                    0x3570127c21477a21,
+                    0x8890aa3610042235,
+                    0x0083cc5a2a3d3b84,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb74654f616c77a2c,
+                    0x1fb6b5c1671e0b5e,
+                    0xf0dce749294971d2,
+                    0x0564bc2a74df6236,
+                    0xa43ed0be0d31eecd,
+                    0xcc702eda253de5b3,
+                    0x27ab2fb86769ddf3,
+                    0xa27e3757fe8a41b3,
+                    0xc12b8de865da74c1,
+                    0x936f45648e87d15b,
+                    0x4a622a4546cbe491,
+                    0x010c9059822b44ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdcda2e5f33dad1ad,
+                    0xe073e8289d4ff5ca,
+                    0xccfaa94d0cb5a02b,
+                    0xa27315a24ee4c5d1,
+                    0x6929190790c981b6,
+                    0x4b954b606fbc52c3,
+                    0x328b5cf86f71458a,
+                    0x6702589f90f64c2c,
+                    0x990a7e9d4672b77b,
+                    0xeb588645f06b9cc5,
+                    0x6f2d140ea5a818aa,
+                    0x000d6e2b2fea6f8b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x180e4bb2888a8060,
+                    0xf9432e1d27eb8f13,
+                    0x6b3e57a478651110,
+                    0x3b5aa8237ac89aa3,
+                    0xa755ad4821d4f2d1,
+                    0x575852c148bc2f94,
+                    0xb74ce03b729f7993,
+                    0xa61ca5d3cebd873c,
+                    0x2b83f070baf89f4b,
+                    0xc745383e01b9e09e,
+                    0xdf76941a26321c15,
+                    0x003bebd046a50dea,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf230cd15b048d9f6,
+                    0x82c2150b8c305cb4,
+                    0x20be34b169a0dad5,
+                    0xfb44560fc2aa30de,
+                    0x34d7a2664894f6a0,
+                    0xbb06471ee1314ead,
+                    0x581e4e357d62538a,
+                    0x43dc16252854231c,
+                    0x56984193b3a10959,
+                    0xe229e48d1ddf1be1,
+                    0x332d20447d4dc205,
+                    0x00ebb9aace9c1dc9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa0f77fa4d28369b7,
+                    0xd50d41a7eca9509b,
+                    0x9d8a98915388ed2a,
+                    0x5a867a57c2e28280,
+                    0xdd030e48929e2b27,
+                    0x5577864c1f3c0a93,
+                    0x3c2dc991d9e26688,
+                    0x61ab886bbe38fc4a,
+                    0x14da2a223fdf5886,
+                    0xc1308334690fdd89,
+                    0xbde2b41525e73231,
+                    0x0074764ae83f8395,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1b5d7bba76d479d,
+                    0x1d1ab430b101a685,
+                    0xbcad3e75f70614f7,
+                    0x8292bae85d001a7f,
+                    0x266cc2851cd71394,
+                    0xe5346268ab3f1a44,
+                    0x2cded482b3775c6f,
+                    0x5e485cb08330de9f,
+                    0x958791cf7f3ac049,
+                    0x1b8d31b5a808b230,
+                    0xf764ef9220f430e3,
+                    0x000cc754a5f03741,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa0e07ca6f1828983,
+                    0xe662040bfacedad3,
+                    0x096daf56b17d7a39,
+                    0xd57b71526067e6c3,
+                    0x2f9e8a5d592c8378,
+                    0x6f5176b1c37edfc0,
+                    0xbc50e12e4fd02241,
+                    0x8f975dd6ad242536,
+                    0xaec2d52aa1ac00d4,
+                    0xfb49e7f236d3d05a,
+                    0xb63912aa2cdec0bb,
+                    0x008956401e997d56,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3324bfd02a3fe998,
+                    0xebd55601cbcd9b85,
+                    0x3b233bd59e513407,
+                    0x872108621ec81466,
+                    0x38e2bf1b2a2ad427,
+                    0x8867b7b6f886068a,
+                    0x5f7af8b9af28ab8f,
+                    0xea7cf84c2d0d15bf,
+                    0xe69bdef5f0ed415c,
+                    0xcc2942646cd5374a,
+                    0x59cc94006e2c0c92,
+                    0x00a6be17ca3e0060,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9e7257b549424c7d,
+                    0x89f2e63053d2137f,
+                    0x63c2bfd2742c2d7e,
+                    0x9b202413c413d574,
+                    0x21b7f8d935d70ec5,
+                    0x30ef6fb7400cfb6b,
+                    0x5a8fc5cb09c079bd,
+                    0x8da9123fdb5a3fdc,
+                    0xa96b6d34484d3f37,
+                    0x35ddfc14c7fcc6ad,
+                    0x1e5f8844e48469d8,
+                    0x008207fbeafa4f77,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbe5384900fc07f30,
+                    0x19150112d6c13580,
+                    0x890e94a53aa7b638,
+                    0x103a5d576a9e27fe,
+                    0x4b00d7f09af8e95a,
+                    0xbc7a788ea5e662a1,
+                    0x276e1b7fe959c494,
+                    0xeb75b34411ac3b16,
+                    0xe5c8cc2bac83acf3,
+                    0x612b7628089da241,
+                    0x547e3d40a3373010,
+                    0x00cf731b8137a258,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2366413f02c0c536,
+                    0xe5db6633951fcede,
+                    0x8e5f1c6692ea9a97,
+                    0x53154adc00f4cdb1,
+                    0x1737488cfc25a784,
+                    0xfded0738f9fbdb86,
+                    0x1b9830f042cf158c,
+                    0xddc5909f64cace18,
+                    0x28b9396a242e6858,
+                    0x9033f7b11fc13a37,
+                    0xd19a6295bb620b1c,
+                    0x00c2032637c9cce8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2618ce0fe99e5592,
+                    0x6ed9be1a18382486,
+                    0x0964419ffd7a2ab6,
+                    0xf4790057049f9964,
+                    0x7c50a537ad71970c,
+                    0x9413162416edf61f,
+                    0xb82e724743eb8a88,
+                    0x6dead52831b75479,
+                    0x2ea3c9a89aee9684,
+                    0x83419c50e6c3145f,
+                    0x7e583ef56fabaa5d,
+                    0x002d271bafe0ce10,
+                ])),
+                Felt::new(BigInteger768([
+                    0x900dc02f8e87369d,
+                    0x67ba59284166a5f1,
+                    0x44c90cbf7b11b9d2,
+                    0xdad6e7fea9960a40,
+                    0x758ef8a78490ca94,
+                    0x0bf5febc7d52798c,
+                    0x9523cd6fc902a455,
+                    0x7ad51be9e3e67056,
+                    0xdc1a65ac5e9390c4,
+                    0xe050c2742ae304a0,
+                    0x2bd925b5ae52d31a,
+                    0x00f8222e1fab867b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c1faaec6c27c4c7,
+                    0x6866cf29ea02c41c,
+                    0xdaf42f510d16f51d,
+                    0xf18627ee923ab571,
+                    0x852c07a9d14f68ca,
+                    0x3d516f9597df53c8,
+                    0xc52387c896cf3ae0,
+                    0xc88d0aef8f92885f,
+                    0xca897f012a0272ea,
+                    0xeac72483baf8f323,
+                    0xae8ca1e64cfe2690,
+                    0x002c8984e573321e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde0c6f729841e382,
+                    0xfd2dea0d53f1d28e,
+                    0x89b49315a20ab1e3,
+                    0x8cdfa0acebbef1ea,
+                    0x7179a314bdaa220f,
+                    0x942ea65273c2703a,
+                    0x2f592f42d787d3f3,
+                    0xf5fc67bd53861c0d,
+                    0x1809418ed4b1907a,
+                    0x887d3b1483d28881,
+                    0x1ad17ca4b1727302,
+                    0x00b9f4afd8e5a36d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a7ce337fae3728a,
+                    0x04f18916df0e4fe3,
+                    0xae7ebd2ab3d747cd,
+                    0xef85aed420f64bd4,
+                    0x88fbff0eaedfbfbd,
+                    0x87fb0f77b453cfcc,
+                    0x197ef1fdde88995e,
+                    0x05d303e9c51662bb,
+                    0x5d3808e019ab639c,
+                    0xc14662587138e1f4,
+                    0x1ebac4cfa9b71dd3,
+                    0x011a9d52346e5e64,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f4bb57f94136e80,
+                    0x4f45062ef458fc0a,
+                    0xcd11e951fff0eb9a,
+                    0x3bde3ddbff64cb5d,
+                    0x1c94a0ed943023b4,
+                    0xf53aca453e6e14a5,
+                    0x15db53badd9439c3,
+                    0xed8897ea17aac68e,
+                    0xbe5c7e228bf7460a,
+                    0xdfb9df6a1cd7dc7f,
+                    0xdcbeb755f55daf31,
+                    0x0094e582a3ea894c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3fe02df503470ccb,
+                    0xa9820f37f2d7b580,
+                    0x547c7b83fc9afe99,
+                    0xc7f0db70d341209a,
+                    0x2c2f6815851bf5ca,
+                    0x653ea9a5b9756c19,
+                    0xb04535bb992f1d56,
+                    0x9c246447c6896c97,
+                    0x799301c441a5fea9,
+                    0xe80e68c1ef636c5b,
+                    0x612855c97ce8d0ea,
+                    0x010787a262e00878,
+                ])),
+                Felt::new(BigInteger768([
+                    0x11dd2202175c91f8,
+                    0x94c9dad54df6a1af,
+                    0x8fa7618c957c9dfa,
+                    0x37e45b5de677e246,
+                    0x25df051a06cd7cea,
+                    0x8b40a8c428fe83e0,
+                    0x9151df5639037617,
+                    0x5e4199c241e7b790,
+                    0x5d48836a6ee8f888,
+                    0xafd526a19bc34fbe,
+                    0x39be05141768b467,
+                    0x002b382a1f77d533,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xeafb02e241ef667e,
+                    0xf4f227b1edd747b4,
+                    0x4b092f0176a31aec,
+                    0xc195a00c43d983de,
+                    0xdb5846a1c682a5ad,
+                    0xedcfb5fcfdca52f6,
+                    0x4abb086451a029e7,
+                    0x5f895edad2b68045,
+                    0x543b81e48e4fa697,
+                    0x059c065c663a208c,
+                    0x0bce396c52d1c8a0,
+                    0x00754d1b807a4848,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72eb4ec1cff25286,
+                    0xdcc49982e88bde31,
+                    0x4a213a2e2a365971,
+                    0x832332630e622a93,
+                    0x3573f33a3e6b7bc3,
+                    0xa5c39b14d59cc1ab,
+                    0xb824758f401dfbac,
+                    0xd8f295eedd25c894,
+                    0x33c46ab8f5b5151e,
+                    0x099febd1e9518572,
+                    0x494825ce8e1972fb,
+                    0x0082cfb7b2e8a92a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x464bb7155aea1398,
+                    0xc98778ff4b2d8efd,
+                    0x709b7a424933b428,
+                    0xb4d56c9aab6de022,
+                    0xd0e2e6085bdcbf6a,
+                    0xd2223012050e1934,
+                    0x49a76d7e6875b0a7,
+                    0xef24884f6ea455f3,
+                    0xdeaea83362b1170d,
+                    0xe3221c72b4b13e57,
+                    0xd2fc8e6d9cb81aa6,
+                    0x0066799449364b25,
+                ])),
+                Felt::new(BigInteger768([
+                    0x41d67fdc6bb27c65,
+                    0x82220b5c46c39c4d,
+                    0x5ce44179c93438ef,
+                    0x885b10fa62b8fd34,
+                    0x2d81299c0f4de5ae,
+                    0xb71d9eaadbd36caa,
+                    0xd7ca1fb06f4246b6,
+                    0x623cda46669eff3d,
+                    0x09eaa4ca8c04260a,
+                    0xa62cf4f6b3fefac7,
+                    0x4a7156c35245e757,
+                    0x00a7d57e8360543d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa8cc3245036822d,
+                    0x39b09cd77c4cc516,
+                    0x2d2cd5dc09e17518,
+                    0x70cabe5f2c05ed7d,
+                    0x4be04e9814d479d7,
+                    0x9281d2cc454f30c5,
+                    0x175accea787a846c,
+                    0x5c80651bf8c8fcff,
+                    0x55a0c171161c7d83,
+                    0x32e616fbced53424,
+                    0x84950d5614708fbb,
+                    0x0093ef8aaefe736f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x883d69e53dbca2f3,
+                    0xc402190e5dec755c,
+                    0x8dd4cbc61e8378d0,
+                    0x7a3067ba809d546b,
+                    0xea681013bf2cf230,
+                    0x98ef284560ce54e7,
+                    0xab9bb2e0a723cecc,
+                    0x6a84e0221c25f20c,
+                    0xe973f8491b01be04,
+                    0x4bd854afbd3ca579,
+                    0x9e50d86666da4215,
+                    0x00bda2c425c77f8a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x86136711b1261551,
+                    0xc1e395f861d86868,
+                    0xd06fe5073573f48e,
+                    0xd6ccb44796c8a19a,
+                    0x671fd312cf666ffd,
+                    0x7225d6cb17a0f941,
+                    0x9c4597e009880742,
+                    0x728b1fb85f0575a5,
+                    0x75e86d9da6a08514,
+                    0x09b06b3a630e81f0,
+                    0x562ad09241d55458,
+                    0x0041f0a092753a7e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x67d6ff840b1118ab,
+                    0x8329affa4feb8ebb,
+                    0x873d1ce5629fdf6b,
+                    0x8701bd92c837d9f8,
+                    0xc948ee5186941cac,
+                    0x545610d93579767b,
+                    0x03e63f5473721ae3,
+                    0x4c05b53359fabf75,
+                    0x1ce23789eef03295,
+                    0x5e269b4eebaf7abe,
+                    0x20dd9e7e8cc67701,
+                    0x0106a23f58e16658,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1a02ee2a8adb546e,
+                    0x227c6489d605c542,
+                    0x4eaeed3ca0502556,
+                    0xa7a0fd39d494d304,
+                    0xcf42bfe5ad988e67,
+                    0x803851e24766f44f,
+                    0x4c5dad22c54bc15e,
+                    0x246bbc00553f0ffe,
+                    0x0cb6152b42e0ca5d,
+                    0xc6f6e398ac2d0b8c,
+                    0x81fb454b5db6fa91,
+                    0x001a4127e36ca04f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a434cb6753d65ee,
+                    0xa406b41a8008af5c,
+                    0x399335a665cb5a19,
+                    0xbabbacfe81378b07,
+                    0x309de07592f5b02e,
+                    0xc528480e39647674,
+                    0xbbf4a5624d703587,
+                    0x34c70154ca9118cc,
+                    0x38522cdc6f4534b8,
+                    0x8f13e2b731bfd4a7,
+                    0xf24a5b5cfefefd89,
+                    0x00aeea68d29de0ce,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a066c32bd82c5e2,
+                    0x2e3970e62dd588a1,
+                    0x175fc2861220bf0e,
+                    0x44fa6b7a14c261a2,
+                    0x5b10dd8d9a838f91,
+                    0x7064f8fc76fa670e,
+                    0x0074a28fdac0584e,
+                    0xdc565692abd0acc9,
+                    0xae2f841661c85708,
+                    0xc0ec9cf9d113c388,
+                    0xe868f9b30de89f59,
+                    0x002d44159fbaa73d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf98f2392ecd5a817,
+                    0xe2b0bbec6f982b00,
+                    0xfae421e36354ab89,
+                    0xd74d32d1f6543469,
+                    0x113ae0c9654a46b5,
+                    0x6567857df0980f00,
+                    0xe45319c3241c0673,
+                    0x6659b0bf250ef669,
+                    0xc464ba8cc9a8e796,
+                    0x0530b10a468daa7a,
+                    0x5961de9042ca67c9,
+                    0x009bcbafd2182d8c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbe09286f4d28727e,
+                    0xb5236965b46effa0,
+                    0x06e02be684e094cf,
+                    0x0ea9a83634ac597c,
+                    0x06a4e243a74de000,
+                    0x937fb07651f1b3f7,
+                    0xe7ee4ba6a0ef9d8c,
+                    0xd02f49e49b603d5b,
+                    0x5e9b192837d57697,
+                    0xed951f77fe40f8ab,
+                    0x908e645879a50e24,
+                    0x005af7cdf6530308,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc9505d54a15e64dc,
+                    0x8eeffb1c4db7392a,
+                    0x6d22174e808d013e,
+                    0xe52fc86eeb8b8b72,
+                    0xac62be7052d343a5,
+                    0xec21fb69380e12cb,
+                    0x72dc16f0107378d1,
+                    0x5eced5e513662ee7,
+                    0x49a5bc8419938f52,
+                    0x8f77fdd239046fb5,
+                    0x045c441b3f0713d2,
+                    0x00be44feb8ffdb86,
+                ])),
+                Felt::new(BigInteger768([
+                    0x694eceb507420315,
+                    0x9485da3b09487259,
+                    0xe3fb9487a75c20ca,
+                    0x651f5add197402fb,
+                    0xdc931e2ef2ddd3f4,
+                    0xbef03ee87bf40a40,
+                    0x3468789b2b59a7ee,
+                    0x502393c90ad7a168,
+                    0x3867983fed5abf04,
+                    0xb6feb3f3de76816e,
+                    0xc8b3510171694cc8,
+                    0x003cfeddb8a91cda,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5833bbb25a73d78f,
+                    0x996857c83bf4d7e1,
+                    0x7076c8940ba5c8fd,
+                    0xe60c0a46b5867dc2,
+                    0xe0cf404e8cebec7c,
+                    0x11439255cf538ae6,
+                    0x7310fee7c3147dc8,
+                    0xa150a17d7e54d0c9,
+                    0x5dbabfde14978a1b,
+                    0x4d6698c8dc115354,
+                    0x49070df0481e38f7,
+                    0x00ba40cde02a46ac,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6bb5140817168f40,
+                    0xa8001bfbd3eda3c4,
+                    0x3afa718f2121815f,
+                    0x0022fd2b64557b9a,
+                    0x52a6399d4fe6ec53,
+                    0x374bea77cc57eb35,
+                    0xb952a97702e530df,
+                    0x0c863ebb52fdd769,
+                    0x1034b80fee29c41b,
+                    0x3279f41d0a628fd4,
+                    0xf83e5985fa5cb4ea,
+                    0x00c702672de54b6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0eb1a746a797d27b,
+                    0xaaa1332b4f1853d5,
+                    0x3ae51f8ee532c74b,
+                    0xe43ec99666309d37,
+                    0xf636ce249eae306e,
+                    0xca729af64aa7ad2d,
+                    0x6e226e6f55108166,
+                    0x93f5f0e53a95888e,
+                    0xa62c3aaa090ae1c7,
+                    0x0c4b45f89ec3403c,
+                    0x1a68e5f76063bb20,
+                    0x00f466395965f08a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6d6c9b6024ca012d,
+                    0x4630bd2f68e6286a,
+                    0x25785eaf70e3e7bf,
+                    0xb6bec6767f5a776a,
+                    0x2a47f5800d7fe7c6,
+                    0x13d0450d558cd17a,
+                    0xbfa362e16f042ec6,
+                    0xce744769f1451c97,
+                    0x3a4da44d693f41bc,
+                    0x3563d64c4382294a,
+                    0xdd5c03dcac94fc37,
+                    0x0063ccc0d2996339,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb85b4b2e22dc8b3a,
+                    0x788a698e69119692,
+                    0xc8af2ff746078294,
+                    0xe39e0ca58ca78e75,
+                    0x4255d11cb685cd4f,
+                    0xcbff26062d246381,
+                    0xdbedc36a55c9fc48,
+                    0xba9605488e75dd94,
+                    0x10ddaee42dd94d91,
+                    0x64255b77d1d83ba7,
+                    0x12c4678cd4ba9ce8,
+                    0x0071898b7dddc13c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5b774a8dae6333ab,
+                    0xc520e3d796a53c2d,
+                    0xdef5354f030ba199,
+                    0xfd9dc9aa3065ad23,
+                    0x79beff103d88cb95,
+                    0x2fdc841fc220877b,
+                    0xe7833610b545bac9,
+                    0x5867258fb79cd159,
+                    0xcaaf4316293a8474,
+                    0xa9fce1643a094d77,
+                    0x1c47f259b4a5131f,
+                    0x0098756b56e4ffd5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2ee78db28bdc6eb8,
+                    0x781bf720597e7883,
+                    0x59703c6ebeba377f,
+                    0xd68af1c73b59155c,
+                    0x36af0f3538e84554,
+                    0x0b8bf5d7322be26c,
+                    0x2631cddcb145e15c,
+                    0x8cb25a47a1a110da,
+                    0xc2603bfeb30ef2c1,
+                    0x99470c9995f777f7,
+                    0xa21288e79992cfce,
+                    0x00f0a4c5d4d1d3c6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b409f810a745ee8,
+                    0xc3f21a19194ef81b,
+                    0xa8d18cea2b75f52e,
+                    0x1585e8e49a4bd4fe,
+                    0x3c0eafaacc4a5419,
+                    0xc1ae415284262bf7,
+                    0xdddafd244f949d2c,
+                    0xa663823da28916b2,
+                    0x1765428ee608ed4b,
+                    0x6cbf201af8241a3a,
+                    0x617a20bcfffbfe13,
+                    0x00c31cab59c71f81,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa66dae91ee2799b5,
+                    0xf4208898590316b1,
+                    0x5d879507e9575a31,
+                    0xf79cad7645a6de41,
+                    0x11b07069e4176cd6,
+                    0x77d98686d204fd79,
+                    0xf5b9c1ed1fc24615,
+                    0x41cd806cfcc0e46e,
+                    0xbae73ba0bb4df0f1,
+                    0x1775c05039f3d5a1,
+                    0x6075b347f6859f8e,
+                    0x000636fe98f25e26,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x94f5c7f4f79ef77f,
+                    0x383572e19e8da2d5,
+                    0xb222948bcd5a20f4,
+                    0x490456cc25be178a,
+                    0xa2dd4a6c2d0f77f8,
+                    0xaa70c2dcf71d1e5b,
+                    0xa8a00cbe1f8abd27,
+                    0xf6ad46a4cb42f38c,
+                    0xccc90de5fff36e02,
+                    0x6dc6359f070a7185,
+                    0x168b75f40bdb06a6,
+                    0x00cf1b0aebaf32ff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f11f6e1b582b766,
+                    0x78ed18950ac5e222,
+                    0x355b9f3d8885a5fa,
+                    0xb32e99012668d0ac,
+                    0xfec7d3e6dfe0f7dc,
+                    0x836c82ab1482bcb8,
+                    0x16b5f6646e75d4b6,
+                    0x62b5ee205c902d09,
+                    0x5d79256abf394d46,
+                    0x3ae3fe7f2dd72918,
+                    0x49a159eddcecabb2,
+                    0x0039cfba22670eea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x970d0732b48f9957,
+                    0x46826325ff019bd7,
+                    0x60bb01da910cf911,
+                    0xe7b7b9a87ce24778,
+                    0x23744b8cb4fdcc4f,
+                    0x38f8d683ef9ed0d7,
+                    0xf793d8a40a0c3f43,
+                    0x3db3fd7f6b55354c,
+                    0x90509c555547560b,
+                    0x128d932dd7646b2b,
+                    0x28273aa25a5bfd06,
+                    0x009739194d105a4d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6029f96abdf0ec82,
+                    0xf036222816791e62,
+                    0xa2aa8cf3371904fe,
+                    0x06bff4d2565762f1,
+                    0xd1bb4833b6b70ee9,
+                    0x8887bade93a7c9f8,
+                    0x2b1437c25cf87fb6,
+                    0x5a337c0895635c1f,
+                    0xe04734b2f33e106d,
+                    0x0c96f4b00032aed7,
+                    0x864a535d85ffdd13,
+                    0x00abdffe10192a62,
+                ])),
+                Felt::new(BigInteger768([
+                    0x424a10b6ce950571,
+                    0x5dd8ac19c8c46ae2,
+                    0x8de9774d87eb6f1f,
+                    0xc6be484ce130c23a,
+                    0xc20b11cb2bb02c23,
+                    0x6154c5fc5ebe6223,
+                    0x7087861f741a187e,
+                    0x982f79b539cc3667,
+                    0x101a4370f5e81889,
+                    0xdc973cd5a22ad01b,
+                    0xc4e850919a3aacaa,
+                    0x006e7ae5fca78a5a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x48a1cc4647c2890e,
+                    0xf06a4802e4c90cea,
+                    0x2c115f4422ef89ed,
+                    0xf5efc7831ff8f1eb,
+                    0xfd3411a0f03f2d29,
+                    0xff61d5bdd8abf507,
+                    0x969a0ff88b430bae,
+                    0x98fb8c7931dc80ba,
+                    0x3ae8e8ba1f66dc68,
+                    0xdbebaddd7d504f60,
+                    0x249f7949a1e92a1a,
+                    0x00f826beea363018,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa5db3ee4c3cfceeb,
+                    0xac2afb6ab072a2ae,
+                    0x45f27fdde6128894,
+                    0x3e683a40ac24aec1,
+                    0xd6b415beee9e725b,
+                    0x2f88c10efbedcdca,
+                    0x7c7a5b8ac84ff3aa,
+                    0x99d0fc82a20bef19,
+                    0x32823bdf554431da,
+                    0xc4f62447d847a6de,
+                    0x47be9dcbef277b6d,
+                    0x0080c238a4208c2d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd81a6400696da757,
+                    0x4cbc9a577e901cb0,
+                    0xa35b92352cd46c37,
+                    0xaf72529e575a55f7,
+                    0xb4d0df2d07f6f744,
+                    0x8c8ff943a22267ff,
+                    0x4276240568aaf7d8,
+                    0x74ed69d6e8ba69e8,
+                    0x886792f4715cd221,
+                    0x9d4ab5d1bdb6996e,
+                    0x1d87e4ef7930cadb,
+                    0x00e073ff940892bf,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,847 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000000000050c,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0xe44705a306ce253f,
+            0x675743cbdac6074b,
+            0x336523f190a1aac2,
+            0x78312726b01a2936,
+        ])),
+        Felt::new(BigInteger256([
+            0x4fa4523589eebabf,
+            0x20b7de3392fe815f,
+            0x89c800bd1dd2392b,
+            0x65a7418aaf933ed9,
+        ])),
+        Felt::new(BigInteger256([
+            0x179f60d2193ffb02,
+            0x68e79217e8c0f7eb,
+            0x268e5a06cc56c190,
+            0x329509675d297907,
+        ])),
+        Felt::new(BigInteger256([
+            0x7a9ba8ee09b88345,
+            0x6553702b7b843e6a,
+            0xaa9d3ecf940a5b9e,
+            0x11e104d0427c3ce6,
+        ])),
+        Felt::new(BigInteger256([
+            0x07a9bf73c2ded1b3,
+            0x28ba568b9aabc586,
+            0x5cf40aa2f1aca350,
+            0x27316ef4f6691cbb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc91c450305aea9f3,
+            0x8ba82b557a153133,
+            0xa926b4eebe535fcf,
+            0x74de03beeca7e7ec,
+        ])),
+        Felt::new(BigInteger256([
+            0xd57ae39b2f49bdef,
+            0x59ddc994528ad2c1,
+            0x8ec82763a087f025,
+            0x406cde8bdb52dbfd,
+        ])),
+        Felt::new(BigInteger256([
+            0xfb5c5e7bd12c62b9,
+            0x0b3cecf05d808b3a,
+            0x817712d684d2798e,
+            0x0b7f52957fc0c0eb,
+        ])),
+        Felt::new(BigInteger256([
+            0xa4e5e85e25cfd76c,
+            0x8eecd70e67f801c3,
+            0x8ca889eed8922649,
+            0x00ce62bca051dbb0,
+        ])),
+        Felt::new(BigInteger256([
+            0x99ca58aa8ca54ac4,
+            0x90e089cd08d35122,
+            0x852cf5d07d45f93d,
+            0x420db89b6b40fcdf,
+        ])),
+        Felt::new(BigInteger256([
+            0x934a3bc7e824799a,
+            0xfffcf48530deedc1,
+            0xd1e686d88319c350,
+            0x63d79a923f7b9bac,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeabcd3f932bf75ca,
+            0x5462a57a75221fd8,
+            0xe0401ffc8b818ad4,
+            0x368aff19f3cd3056,
+        ])),
+        Felt::new(BigInteger256([
+            0x566530f0780e7e50,
+            0x1c7310f52d5dc538,
+            0xc4756f05b6a0da3e,
+            0x665f586f72c90952,
+        ])),
+        Felt::new(BigInteger256([
+            0x27ce1f012e98ee8b,
+            0xca50d98af0a3667e,
+            0xc1267e6262acbae4,
+            0x4a8a1d98bf5076dd,
+        ])),
+        Felt::new(BigInteger256([
+            0xe69bf88e85fdc07f,
+            0x9c2337fd9ae09f4d,
+            0xb41d43d3d3106716,
+            0x7cc319899b031728,
+        ])),
+        Felt::new(BigInteger256([
+            0xb28e49768da571c0,
+            0x4c5b67d17ae26950,
+            0x275a65f92d570000,
+            0x2fc63c6e0d5eeca7,
+        ])),
+        Felt::new(BigInteger256([
+            0xa644feb251570d8b,
+            0x014e7f184262e964,
+            0x2c3fbffbe7832977,
+            0x14d6bdf1c7ac0dff,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2b76347555535d5c,
+            0xa0bd73410e617c7a,
+            0x0202bafd84b253c1,
+            0x74cd3813ab4c743c,
+        ])),
+        Felt::new(BigInteger256([
+            0xaa6ad7f19a4e1f37,
+            0xf9b2e003a1787731,
+            0x4c0285278a266c67,
+            0x4250fc8654a6c580,
+        ])),
+        Felt::new(BigInteger256([
+            0x25f0fb1889088988,
+            0xd5ad9058755c6885,
+            0xa142b0f145c07173,
+            0x0c54b07076412aba,
+        ])),
+        Felt::new(BigInteger256([
+            0xfd546954ea3dbbc7,
+            0xf02623d80640ed76,
+            0x33417e448c98c46e,
+            0x0c5c2de897a0a179,
+        ])),
+        Felt::new(BigInteger256([
+            0x79f4dac0ac5f635f,
+            0x0ca17ebb1db622ad,
+            0xaef9cbbf4b25c1db,
+            0x1993a54f3f298570,
+        ])),
+        Felt::new(BigInteger256([
+            0xf8bbb9f4ec555f37,
+            0xc48cdf508a15076d,
+            0x9fe89f355072d85f,
+            0x6c00fcdbf6a72165,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc3122796a7ac5a2f,
+            0x5c48a2544850e7f2,
+            0x7bf385e3cc945556,
+            0x4e77b9d15e137fb8,
+        ])),
+        Felt::new(BigInteger256([
+            0xafb10334e4d9d4a3,
+            0x4ba4a17e8016654c,
+            0x1c5ea885655f8574,
+            0x78990c5737e9936a,
+        ])),
+        Felt::new(BigInteger256([
+            0x8c9b472dd4e60d6a,
+            0x446c5d12484caba4,
+            0x2e57e2a26e356f35,
+            0x2f1eec9f7ca843b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x6be49522140c7b3b,
+            0xe2e158ca0a7a20cd,
+            0x03a028d50c6ef605,
+            0x643a540d1efed0f6,
+        ])),
+        Felt::new(BigInteger256([
+            0x59ffc0ac5dc6ecbc,
+            0x8ab22570260a4841,
+            0xcfed6cd999ee9b33,
+            0x6058a4c7e1f31ab8,
+        ])),
+        Felt::new(BigInteger256([
+            0x3bc2c2e36308eb8f,
+            0x7ddaaf1cc0c3b459,
+            0x0868f67afdc77882,
+            0x2fcab0e82f1fd74e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x130812fee00d6c64,
+            0x818d215545de14fe,
+            0x9aba4b68bbb4297d,
+            0x2838baa79d11aa4f,
+        ])),
+        Felt::new(BigInteger256([
+            0x3e05b1865e82c77a,
+            0x67b74e159ee9219f,
+            0x75da67c4502ca321,
+            0x3d1986fe13ad31ce,
+        ])),
+        Felt::new(BigInteger256([
+            0x370753747a3a046b,
+            0x390aaa0582ea19bf,
+            0x00a8de2bcd1c7d3a,
+            0x7ae40899bfe045b0,
+        ])),
+        Felt::new(BigInteger256([
+            0x831c98ebebbcdabb,
+            0x59d9fbabbf8e32ac,
+            0x18f8fe5b01abcbf1,
+            0x454d544111dbde5c,
+        ])),
+        Felt::new(BigInteger256([
+            0x9101fae5a778a188,
+            0x872aa8a4f7fcce9e,
+            0xeecb4f618e1ac2a5,
+            0x256a9ef3e3316883,
+        ])),
+        Felt::new(BigInteger256([
+            0xc282026e24080fc4,
+            0x76ca7bc78c0aa4e6,
+            0xc3dfde6721c2ad6d,
+            0x0c9765707a2918d3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3bec9e832fec92ce,
+            0x50900c9fc2d58b32,
+            0x3752456293ce4b5d,
+            0x293526c620b2472a,
+        ])),
+        Felt::new(BigInteger256([
+            0xf4b5e23f41b7c325,
+            0x8cffca539cb06104,
+            0xe087c3e1b58765fe,
+            0x0163d29e1b9a50f5,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d3007505e5ad217,
+            0xcec62a383d5b98dd,
+            0xf303a52016ca74ff,
+            0x73c2b04140b794de,
+        ])),
+        Felt::new(BigInteger256([
+            0x6a8127d26cfe4242,
+            0x03775ad78a127d30,
+            0x83b43390a62f500d,
+            0x6cd24e818be2c8b3,
+        ])),
+        Felt::new(BigInteger256([
+            0xbd07e188793f5cfd,
+            0xc44c28335e138a3c,
+            0x2be3cfd4c8c3f271,
+            0x7939a0db0c6caf73,
+        ])),
+        Felt::new(BigInteger256([
+            0x43b624a87571fe09,
+            0x7a79b7b14d643372,
+            0x945b9f9b843ed6c8,
+            0x0abc606f40cb4c74,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6fb39b73b190ae3b,
+            0x2a9ab3b87e9de3d6,
+            0x41f12658bc6cb7c5,
+            0x0046a5a911cf0aa9,
+        ])),
+        Felt::new(BigInteger256([
+            0x62d4c0c2b8bdfa6f,
+            0x0ee565a9de74f6a0,
+            0x2ded9cf1c4870949,
+            0x0d55573af8e89e7b,
+        ])),
+        Felt::new(BigInteger256([
+            0x58d6caf9afc2df6c,
+            0x83fd56ff0ab518fd,
+            0xd71971d482697ef6,
+            0x7875f0a6db9368be,
+        ])),
+        Felt::new(BigInteger256([
+            0x70bfb294c723e66c,
+            0x5d4b8f1e668b3dc4,
+            0xe36e3fc26629c7af,
+            0x3c9cc4b012890c20,
+        ])),
+        Felt::new(BigInteger256([
+            0x24565a90d71c0700,
+            0xec09478c39c8db30,
+            0x8f8a85ee79bcfa1a,
+            0x0c990dcab262b948,
+        ])),
+        Felt::new(BigInteger256([
+            0xfc21d9725b419fca,
+            0x8d457a6f9925bb1c,
+            0x857505d780b94a7f,
+            0x6516dfaea8fa052a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x40857658b93ccfb3,
+            0xf4eed7d07dc3101b,
+            0x1f9f8eb6be1cdcbb,
+            0x36709391986752b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x73ab2da2e1251558,
+            0x6eed0de3bfc7ce94,
+            0x08bfc2f8290157dc,
+            0x2c8d1a8d95137c60,
+        ])),
+        Felt::new(BigInteger256([
+            0x96b7b1488d02f9d8,
+            0xb1a1374ec866271d,
+            0x1a98c2541a098d79,
+            0x3656048d6124bc54,
+        ])),
+        Felt::new(BigInteger256([
+            0x820de73bab9922b5,
+            0x0201643f8f80236d,
+            0x26138529d9ea7323,
+            0x4f262a29fb0481e3,
+        ])),
+        Felt::new(BigInteger256([
+            0x8f58c59d5b2d47b2,
+            0x5c07ca9a6e5e9838,
+            0xd78a9c68f03d54d8,
+            0x21f5e3b1ac484eb1,
+        ])),
+        Felt::new(BigInteger256([
+            0x0a6a77492888acef,
+            0x07b40cb754d11fd5,
+            0x9034ccc34b9aebe7,
+            0x66f64922ae241e45,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xed0c810f4cdd09dd,
+            0x81335422994f28a5,
+            0x523bf0db426df5d3,
+            0x0e8701170f63e607,
+        ])),
+        Felt::new(BigInteger256([
+            0xda4c317fee84d5c3,
+            0xc76c9e9e11ad8471,
+            0x314b7682940355a0,
+            0x58e7cf7a565c0757,
+        ])),
+        Felt::new(BigInteger256([
+            0xfa4e5f51a2e1b08f,
+            0xe817f298d618faa2,
+            0x1a9cb115ed365344,
+            0x505fe499e0466523,
+        ])),
+        Felt::new(BigInteger256([
+            0xf38a0ba19f29ed7a,
+            0x5b562b243172311b,
+            0x5f4d456e2b4e6e9b,
+            0x4a54a509aa030578,
+        ])),
+        Felt::new(BigInteger256([
+            0xc6223d0ecd9a5988,
+            0x5ebb906373e945db,
+            0x4ec3d7bf0a8f4c2d,
+            0x7a538ac6451f9e3d,
+        ])),
+        Felt::new(BigInteger256([
+            0x47f949c5d6cc4698,
+            0x1a9980963a0f9f2a,
+            0xb3e3c161612c1d5f,
+            0x4277a4b8398b1360,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbee2f06fe5a835da,
+            0xd59e0b3e611e23cb,
+            0x1f462f1f0fd853f6,
+            0x60cfbdb78334774e,
+        ])),
+        Felt::new(BigInteger256([
+            0xb214e9fccb0fe184,
+            0x10cf293bfee95a07,
+            0x37e787bb6c108fe8,
+            0x7870be22c4b7a229,
+        ])),
+        Felt::new(BigInteger256([
+            0xaa601c9b782e1ab9,
+            0x1a2dce2960545c64,
+            0x5d6b7ef8e4f37505,
+            0x12d471872e7005c0,
+        ])),
+        Felt::new(BigInteger256([
+            0xae792c1c0282a91f,
+            0x5fae3b5e9b955bc4,
+            0x914f7423689a5fa3,
+            0x6ad63264027e9e84,
+        ])),
+        Felt::new(BigInteger256([
+            0xe3d61c46336d31bf,
+            0xd981ec0402d2b734,
+            0xfa6d0b5fd4b1a69c,
+            0x280656669ab6a2f0,
+        ])),
+        Felt::new(BigInteger256([
+            0xbfe83ed48a481f31,
+            0xe14c559893846049,
+            0x77524ea020aea8ca,
+            0x7cb9680b3309069f,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000000000051f,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0x745b1e25e12bf397,
+            0x436c1968219a35b9,
+            0xd4de3b89f4d54133,
+            0x25654811d61b85a4,
+        ])),
+        Felt::new(BigInteger256([
+            0x93f1d3aea8867f90,
+            0x1002c08e1929b670,
+            0xea31a5cbc144ebff,
+            0x361efc29662778d9,
+        ])),
+        Felt::new(BigInteger256([
+            0xe09c12551216bd35,
+            0xeddfb9507c28eae2,
+            0x96a6a881ea9a7ba3,
+            0x759e7ffc5c5f90bb,
+        ])),
+        Felt::new(BigInteger256([
+            0xe10df2911c74f63c,
+            0xc3b09105ce12a789,
+            0x9cc744bca0b48ae2,
+            0x0c185e8ae3c5bb16,
+        ])),
+        Felt::new(BigInteger256([
+            0x516c7b484ae7b2ec,
+            0xbb51e2e2c1fc4229,
+            0x6940f52067dbf811,
+            0x6a5aa0c857322445,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xdad044d3398a68e4,
+            0xfddd58bd90825079,
+            0xe99fac6bc4d4725c,
+            0x3f5abc480e3a3978,
+        ])),
+        Felt::new(BigInteger256([
+            0x7742fbee3d834b12,
+            0xa827cc98afcc2075,
+            0x70ba36790b3c9923,
+            0x381db80022e689f8,
+        ])),
+        Felt::new(BigInteger256([
+            0x515ddfc5239fe655,
+            0x6cbcfcb2fa18df92,
+            0x2259af622ec63eef,
+            0x2673c5bd57e74c78,
+        ])),
+        Felt::new(BigInteger256([
+            0x7f9699b15282587d,
+            0x861a2baf11cd1401,
+            0x3d39cfe6fd56f2ea,
+            0x0e5491dac11a44f1,
+        ])),
+        Felt::new(BigInteger256([
+            0x11f0a21dd33d7c99,
+            0x6172d80f71ced988,
+            0xb7cff33a90713b0f,
+            0x06c1cadf2e1ccc9b,
+        ])),
+        Felt::new(BigInteger256([
+            0xeec0f76ca40919b1,
+            0x04c9ae446e9c89aa,
+            0x1eac68d2ffca2aa0,
+            0x717d84eec1d6f4c3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x761339f1b53f5441,
+            0xa326cbc4a17b3058,
+            0x271cdc55c67a62b2,
+            0x0b968981a546aafc,
+        ])),
+        Felt::new(BigInteger256([
+            0x71cfaf6bd4ec2af9,
+            0x474c0cdba08b0425,
+            0xaccb42f755cd488d,
+            0x689f03c24a43e066,
+        ])),
+        Felt::new(BigInteger256([
+            0xf7720672cfb091ad,
+            0x085fe22fa327ac0e,
+            0x686cdfca41184597,
+            0x700d629f275e2b83,
+        ])),
+        Felt::new(BigInteger256([
+            0x3aef100a01546129,
+            0x6fdf85805aa1a2c5,
+            0x6b124ea82c4cf908,
+            0x14d81a864bb2a982,
+        ])),
+        Felt::new(BigInteger256([
+            0xa456f91222e1c308,
+            0xf97caef5f9c9e2ef,
+            0x6061283f74fa0722,
+            0x7f0920906021e57c,
+        ])),
+        Felt::new(BigInteger256([
+            0x7b5e207f5bdfcd28,
+            0xe2aa31b9960c7687,
+            0x7f6966d298ab5616,
+            0x2d0b7a2cd9ee902e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8a7a6dbc75f00c15,
+            0xa35619fb4ace1501,
+            0x36e94c74db4df95e,
+            0x66d3fb32e44108b7,
+        ])),
+        Felt::new(BigInteger256([
+            0x998329bb95489c22,
+            0xd8605c5a24b93e26,
+            0x22622e3744f5a875,
+            0x618be090b39cb66a,
+        ])),
+        Felt::new(BigInteger256([
+            0xc942b5d8c83cfcec,
+            0xc791196d37f4361d,
+            0x3692e7773fcec9e4,
+            0x4ed32e2e65c9f936,
+        ])),
+        Felt::new(BigInteger256([
+            0x2555541f03b12ca0,
+            0x77b6f1cad61578f6,
+            0xd8405e370178241f,
+            0x416c679ccfcb4da8,
+        ])),
+        Felt::new(BigInteger256([
+            0x3f6b5daadfb884fc,
+            0x6d97464facb12454,
+            0xd60a6323ae6b96bc,
+            0x05d1c2291967981b,
+        ])),
+        Felt::new(BigInteger256([
+            0xa182af1094faef29,
+            0x59bd1261edd21c98,
+            0xe11c1b2a1d3dd2be,
+            0x2130f1ce9064bd6a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb240901d22e2ff27,
+            0x57d670549bb27755,
+            0xe0d8282da5533333,
+            0x0f84015a3d75cdf9,
+        ])),
+        Felt::new(BigInteger256([
+            0x2ef3843e3a6e47cd,
+            0x2347451b1a4c231d,
+            0x22bc6267a251f9c2,
+            0x66d974cb3d4d3e1a,
+        ])),
+        Felt::new(BigInteger256([
+            0xc017312d6eb4770d,
+            0x2f450d6d21d97018,
+            0xf3a629faea66ffe6,
+            0x40a2eec7129ecbf5,
+        ])),
+        Felt::new(BigInteger256([
+            0x240faf2b8819e253,
+            0x63674e02f143a328,
+            0xd89d199a03718df6,
+            0x6850122afd9736eb,
+        ])),
+        Felt::new(BigInteger256([
+            0xafa072d5ebba0498,
+            0xe49d144acbfa40c3,
+            0x26fc15107f57a854,
+            0x1b9c460b629ee72a,
+        ])),
+        Felt::new(BigInteger256([
+            0x74b3e73e664871ad,
+            0x0c0009743b75c05f,
+            0x799a83424cb5ab21,
+            0x34002a446f4b2d19,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfaeb5cedab1559ab,
+            0xe8d09e95da25b0ba,
+            0x3c0b87b522b2cb32,
+            0x10a7a00525709e0e,
+        ])),
+        Felt::new(BigInteger256([
+            0xb5fd13f803e882f3,
+            0xab0fa0f27a04ebc9,
+            0xb8a4bba91b5edb47,
+            0x52bc8d46c20d81fb,
+        ])),
+        Felt::new(BigInteger256([
+            0x63381edc63d9b670,
+            0x8f9909a09d5cea8d,
+            0x0263bf86d78dd1c3,
+            0x33caa895fed3736f,
+        ])),
+        Felt::new(BigInteger256([
+            0x33fc945daf9b8a22,
+            0x4615a024e73dc161,
+            0x2a62892286ee27ba,
+            0x70c5b0339970e9cf,
+        ])),
+        Felt::new(BigInteger256([
+            0xdf578e77853d01b3,
+            0x4ccb46bfded2d37a,
+            0x8246919b01c3939f,
+            0x0810de0c0cd9da72,
+        ])),
+        Felt::new(BigInteger256([
+            0xf42808317718de31,
+            0x70a5855f47a2bd46,
+            0x717e0530fef0a3e4,
+            0x382f7ca16351141c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9ec3d0a1aa55a6e5,
+            0xf1a7627380a59630,
+            0x9c1d38f15342b7f6,
+            0x0f034e2ee3ab0bbf,
+        ])),
+        Felt::new(BigInteger256([
+            0xe7a12ce0967ea56e,
+            0x0a2bf5c3a1549a70,
+            0xe6cbcf08d92f6909,
+            0x14661af2049471f9,
+        ])),
+        Felt::new(BigInteger256([
+            0x0454bae7f75baaec,
+            0x5f2862668156d8ed,
+            0xb8383dbd79b1946d,
+            0x2a089248ba449374,
+        ])),
+        Felt::new(BigInteger256([
+            0x96550b73e03e188c,
+            0x2986d7e3db4a7b26,
+            0x5897759a83e776ba,
+            0x15a9ec7f4e11a4fd,
+        ])),
+        Felt::new(BigInteger256([
+            0x86515d4a0664e3f8,
+            0xc3c09ee16e71fe5a,
+            0x82d8c95094e28e4f,
+            0x593f21fe70aef238,
+        ])),
+        Felt::new(BigInteger256([
+            0xf050129b77e3f346,
+            0xae2899dc3284bb13,
+            0x05737da7b9e29823,
+            0x33b3b9ab648d1894,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6d099d2558b0deec,
+            0xe623d1c3461c6020,
+            0xc7e86dfb791d36a4,
+            0x1d09faa00e9ee2aa,
+        ])),
+        Felt::new(BigInteger256([
+            0xf03edaf73a3bf952,
+            0xa6835950ecc7a157,
+            0x555dfc2ce56b1e99,
+            0x574ccd1d1bb9d2eb,
+        ])),
+        Felt::new(BigInteger256([
+            0x9a7a4e24757ad4db,
+            0x2ed15764585eca58,
+            0xbd7a5e85e28cb0aa,
+            0x65b1003c8ef77ac0,
+        ])),
+        Felt::new(BigInteger256([
+            0x371265c9671ad950,
+            0x9dccd461c171ad06,
+            0xd97dd5e0411e00a2,
+            0x1c69903c0e8efbd6,
+        ])),
+        Felt::new(BigInteger256([
+            0x881ea5e590f8aa95,
+            0x05ef867153d5c099,
+            0x07abd37e4317a83f,
+            0x2393bc7c507c0f7a,
+        ])),
+        Felt::new(BigInteger256([
+            0x433a96f88a6ab1b4,
+            0xdb6624d187f4b40a,
+            0x17b937f7b3991e20,
+            0x450366790692e4b6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x89654864f8a8a586,
+            0xe53d21be08b58181,
+            0x327967e3fc107ab4,
+            0x0937f06f0607ed22,
+        ])),
+        Felt::new(BigInteger256([
+            0x4c9f1831faeeb95d,
+            0x3b502d6d918e6e68,
+            0xbd12b3bdcb288c46,
+            0x2c88985628b5733c,
+        ])),
+        Felt::new(BigInteger256([
+            0x23e504cdeb069456,
+            0x913a6396d983cd95,
+            0x8ddc408ffb6fde46,
+            0x59951c09855990c2,
+        ])),
+        Felt::new(BigInteger256([
+            0x93ea6acae3dbbaa8,
+            0x7747d565adda87cb,
+            0xa905acd23621cb2f,
+            0x64f6fd9c67db3405,
+        ])),
+        Felt::new(BigInteger256([
+            0x3eaae14cad559056,
+            0xaab335624bdf72be,
+            0xdc8e7b833adb2215,
+            0x6ef49a49bb32674f,
+        ])),
+        Felt::new(BigInteger256([
+            0x9d0d0529effd63e8,
+            0x8a99e2fc07140dde,
+            0xaf5b906dffbddea1,
+            0x7ce6d7d37c8dc03d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xddd8564edff31111,
+            0xcb191857b60ed7c0,
+            0x2223318d2b3856c7,
+            0x210df35ae6653bfc,
+        ])),
+        Felt::new(BigInteger256([
+            0x5b2c1f425bf8ab3c,
+            0xed67386f754161fa,
+            0xa2abcecce1014d05,
+            0x18a2e2a9535eb9ba,
+        ])),
+        Felt::new(BigInteger256([
+            0x2f67b60a548f7c81,
+            0x214899287903decf,
+            0x4aed96d67973670d,
+            0x335e917c6ddbf518,
+        ])),
+        Felt::new(BigInteger256([
+            0xad5292642b16b6e1,
+            0x2a341691e199d32e,
+            0x9f4cd49b325c89a3,
+            0x1fe50de2803a7321,
+        ])),
+        Felt::new(BigInteger256([
+            0x1d605bf1736cd3b3,
+            0x06fe7572e3375e16,
+            0x10d51e5e0003dc66,
+            0x0711d6c4bd6a7262,
+        ])),
+        Felt::new(BigInteger256([
+            0x8287dad9f1eb2f05,
+            0xf716d1227e1fcae8,
+            0x9017ec90c025d314,
+            0x1827c8cf715570df,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x011aadac3e0a84cd,
+            0xcc219335e94fa3d6,
+            0xbc2918ad7692b7b9,
+            0x7620e1391537b595,
+        ])),
+        Felt::new(BigInteger256([
+            0x8460bfbbfdcffebc,
+            0xe36786cfcdef087f,
+            0x764388e236fe8a1b,
+            0x3af6028f7cbc3cdf,
+        ])),
+        Felt::new(BigInteger256([
+            0x30e55b50ef282e57,
+            0xfffc387b6eb11180,
+            0x5ab80d95ef208b9b,
+            0x789d4fa777077e08,
+        ])),
+        Felt::new(BigInteger256([
+            0xb9ad9adb53bbba45,
+            0xdb29ea8eb72ecec6,
+            0x9e4aac2ced987d79,
+            0x4330cc7a93b7f480,
+        ])),
+        Felt::new(BigInteger256([
+            0x8c8023259e8bf396,
+            0x2e6294d5dd92a05e,
+            0x8979fadb481639a4,
+            0x378ed3a2ce035f68,
+        ])),
+        Felt::new(BigInteger256([
+            0x4be2b7e56ab34f5d,
+            0x6a6769e743065cf7,
+            0x208222abfd98614f,
+            0x5533bd6025d54c71,
+        ])),
+    ],
+];
@@ -0,0 +1,1870 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger256([
+            0xfe1f3b6c3a71ff0d,
+            0xc13d4f0fac4b0043,
+            0xc282325844e80113,
+            0x2bc41a6bbdf0f97f,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0xea1c7067fe05aab8,
+                0xf9815967d880296d,
+                0x601710187d6e1e74,
+                0x4a7114dabbae8c7b,
+            ])),
+            Felt::new(BigInteger256([
+                0xfc372db314671604,
+                0x555832b332543156,
+                0x4f0e9041107f0fa6,
+                0x0cb5b12f10f3b770,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe533ca1bcc98014e,
+                0x7998bf1c701fe1a3,
+                0xa45a831b6683de8d,
+                0x02ac0eea127e2bcc,
+            ])),
+            Felt::new(BigInteger256([
+                0x7e6559e0fd9340ad,
+                0xaf07192343a5dd39,
+                0x3ca03ae70f7417fb,
+                0x5df427e74493df37,
+            ])),
+            Felt::new(BigInteger256([
+                0x2a6341974560ec95,
+                0xa7b0d9dbafb2d228,
+                0xc437f1d9a7b97135,
+                0x102500c377051fa8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x5915ccd34538bf07,
+                0x32e767ecf1cd400d,
+                0x82631de61baa51d9,
+                0x1a35ec2656cf2d68,
+            ])),
+            Felt::new(BigInteger256([
+                0x2a47f9119af0ab3e,
+                0xe896679bb256f9b0,
+                0x1799f128d702965a,
+                0x6e02494941ca68dd,
+            ])),
+            Felt::new(BigInteger256([
+                0x0b373f558dc453a4,
+                0x363ea99cd71e92d8,
+                0x6f29d4c22dc0693b,
+                0x7790682006f0e995,
+            ])),
+            Felt::new(BigInteger256([
+                0x884b7b8cf8d0f793,
+                0x004ff99824e89348,
+                0xb432e13462be93de,
+                0x26a6d3fa2a59ac2a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xa57d2284208bb9ac,
+                0x5dd578b24cc561ec,
+                0xdafa34a8572aa15c,
+                0x7e61afe2492a07a7,
+            ])),
+            Felt::new(BigInteger256([
+                0x94c9f8a0af5bb9f4,
+                0xd8d6e2637649c5bb,
+                0x3691535065e2f379,
+                0x1d5ef871485b7ffb,
+            ])),
+            Felt::new(BigInteger256([
+                0xd9ee8c82ef596246,
+                0xb9a04db5984d9919,
+                0x29912e3e42f796c6,
+                0x666095d0d74d8a58,
+            ])),
+            Felt::new(BigInteger256([
+                0x24c228a073acef60,
+                0x29e892d60f62a604,
+                0x0f51cfb41241f9c9,
+                0x6c06060a2c9c1db8,
+            ])),
+            Felt::new(BigInteger256([
+                0xe9b8b453280a3583,
+                0x42187ac6bf9b4455,
+                0x65292f29a537ab5f,
+                0x199f6f452ae72f4b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe99484add92bbb04,
+                0xdffe3af4a6a681f1,
+                0x0328a4bcaea5f97e,
+                0x7f520b6125742b35,
+            ])),
+            Felt::new(BigInteger256([
+                0x99b22bd3ac4ebc4e,
+                0xdb5d9027d8771838,
+                0xad3c28e0f7bd6fa6,
+                0x1cfcea7f820b5bf8,
+            ])),
+            Felt::new(BigInteger256([
+                0x636f2bb06b6ef0a5,
+                0x20cf86071191d144,
+                0xf4c788d24fb749c8,
+                0x5db16837c91c55c8,
+            ])),
+            Felt::new(BigInteger256([
+                0x985f9376f2e6debb,
+                0xbb692444243503ed,
+                0x2f8e01d57c9a17eb,
+                0x657a9e50e3ba4012,
+            ])),
+            Felt::new(BigInteger256([
+                0x1daa0d474edfc164,
+                0x05b01dc8e4b39d49,
+                0x428d72543fc96705,
+                0x0858b77bbbbf038c,
+            ])),
+            Felt::new(BigInteger256([
+                0x261787b40aaa9035,
+                0x83d8e84af142c886,
+                0x8e5e77e5fbb21997,
+                0x079bfe9f3c2fee98,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x08fa869dfefb4150,
+            0x99f3b0b205effb8c,
+            0x3608cd9eaf27fa83,
+            0x63a93f828248b9b9,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8af96cfd5124e6e6,
+            0x20c98ef4500ae548,
+            0x1db191172b3c3397,
+            0x6bf0998bc05df89a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x5e7a1ebd5409d80b,
+            0xe1e9ee9972376b11,
+            0x8d076591b4b5b990,
+            0x64347c805f6a4d21,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0a1d5bfd976026a4,
+            0x09cdb29ba75b59e3,
+            0x87f4cd7407be3c22,
+            0x05168e43a0fcdbb4,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xcc0acff8b264ab9c,
+            0xe7d257cc2bceaed1,
+            0x55d8e1bc960cbf75,
+            0x0563b671c98e4bfd,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0f45b0efe0ad8c45,
+            0x0f04b164b53933b0,
+            0xa8405a6341b657a5,
+            0x2099a6ce8eadaf79,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x3bb948de2e0542ad,
+            0x1e0db2ba1a91365e,
+            0x4d2dbad175b2d74a,
+            0x76f57d280ac3348c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa7c9b915e7451e96,
+            0x8e51122f91abf25e,
+            0x98658d096761d7f3,
+            0x550e02b5e8ee3b0e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe987ecaad8d994be,
+            0xc07994bd47a48c2b,
+            0x11ee1324a2a0401e,
+            0x05f97189600b8d15,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb10e3e441beadc95,
+            0xafb28e031d3bdbdc,
+            0x6489fd9646ec396a,
+            0x7249b74cb7288a8e,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc03b7d06efe6d7ed,
+                0xea7ca7c7d94e3028,
+                0x6f9ca98621a6d9eb,
+                0x62888b627807ae71,
+            ])),
+            Felt::new(BigInteger256([
+                0xafb1a12d81198b24,
+                0xac690c042d8cc1fd,
+                0x65fd0b7721fe7d40,
+                0x321f285ddaa9f5c9,
+            ])),
+            Felt::new(BigInteger256([
+                0x767d03d79ab29863,
+                0x80722c3e795d5570,
+                0x38c16ec14e5e19aa,
+                0x29a84209b6874135,
+            ])),
+            Felt::new(BigInteger256([
+                0xd83e8027e46e82ea,
+                0x9ee03b690d5154e7,
+                0xb8784859cd18ffdc,
+                0x1c4bb1d51556bbf2,
+            ])),
+            Felt::new(BigInteger256([
+                0xbe07c1b3fe8efb77,
+                0xdcb6a10d68398c62,
+                0x870d1bd4b989893a,
+                0x304ebbb60edabafa,
+            ])),
+            Felt::new(BigInteger256([
+                0xcf753f7ad1f1ed79,
+                0xdb154f7a2743b0e4,
+                0x1bce682ee6d0e0ee,
+                0x3480e6af82486fd9,
+            ])),
+            Felt::new(BigInteger256([
+                0xe8a381936d9a186f,
+                0x879971870bc7cc0a,
+                0x88cf8b2ade307810,
+                0x1b02ee86d24e1ddc,
+            ])),
+            Felt::new(BigInteger256([
+                0x88895bdb0abfb938,
+                0x64c9ff6812d46e0d,
+                0x47427b934b89c68f,
+                0x3c1ba4b7414d0d3a,
+            ])),
+            Felt::new(BigInteger256([
+                0x4ca86dbb670f8ce6,
+                0x034a67f0dfe7cbe1,
+                0xa00fadf8f0a20d99,
+                0x45289dc8a69c4bde,
+            ])),
+            Felt::new(BigInteger256([
+                0xf32188a4a33303c0,
+                0xc492b0f723cadc96,
+                0x4870bfd2d6308dc1,
+                0x4f84e6ded11963e5,
+            ])),
+            Felt::new(BigInteger256([
+                0xc47b5de15b6e3734,
+                0x9a5394be17bd1fd2,
+                0x9d7599599bf4a172,
+                0x1f87572aa99933d2,
+            ])),
+            Felt::new(BigInteger256([
+                0x205c60ad8a51abfa,
+                0x18efbb25216bf2ef,
+                0x251aa58deae4bcc4,
+                0x0c4e01352ccac80f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x17fc6d3a3c679c46,
+                0x106283bd1c7f22b8,
+                0xeb6f7923f5d2665a,
+                0x3f9a3e3ae64cabdd,
+            ])),
+            Felt::new(BigInteger256([
+                0x6834a1445647f1d2,
+                0xc10314608142e0fb,
+                0xe2a8ba3ee7980af1,
+                0x4c94a2cabf14e5bf,
+            ])),
+            Felt::new(BigInteger256([
+                0xc332113cd783b0cd,
+                0xe465a75b92abc2d1,
+                0x909f5e1a3351d33a,
+                0x77143121f4a6ac02,
+            ])),
+            Felt::new(BigInteger256([
+                0xf2c07b288eecd1a9,
+                0x5703e6215d94c6ba,
+                0x3041519da23c3057,
+                0x434e0f2e34c01e0f,
+            ])),
+            Felt::new(BigInteger256([
+                0xa2935ed26088a4a2,
+                0x850431224aaa6f25,
+                0x316e7397d3328e63,
+                0x521f40a7c4935080,
+            ])),
+            Felt::new(BigInteger256([
+                0x339d3b5f345e14cc,
+                0xe71c7b58b0acfc83,
+                0xaa7963c52bcc7353,
+                0x7ebd97223b0f93f7,
+            ])),
+            Felt::new(BigInteger256([
+                0xba7369aea816f90e,
+                0x2398f2a4297d5300,
+                0x28e72bcc7fbbf2ad,
+                0x69f6be97512096e1,
+            ])),
+            Felt::new(BigInteger256([
+                0xd6e2436d35407142,
+                0xfc373c6dd0985901,
+                0x16577bc98fd436bf,
+                0x211050e1fedac149,
+            ])),
+            Felt::new(BigInteger256([
+                0xa8c54861a7764c69,
+                0x8c694272ef5f90b8,
+                0x57191b88350c66b9,
+                0x73cfef49272a7140,
+            ])),
+            Felt::new(BigInteger256([
+                0x5a397e670713cf0c,
+                0xec93e75d1c5cee26,
+                0xf7cdfa37d1156dc0,
+                0x238d392a8340c799,
+            ])),
+            Felt::new(BigInteger256([
+                0x961083c2f8dfbee2,
+                0x1b07ce17d091c68e,
+                0x8967af2f9a4607a4,
+                0x5eeec8d333fde42b,
+            ])),
+            Felt::new(BigInteger256([
+                0x3b8177d833665e4c,
+                0x66d01c706eca6fe8,
+                0x49c49ed3a555ad1e,
+                0x53491784a1f5e23d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x865643b93a415c9f,
+                0x4bd784df6e3bc4d9,
+                0x4b113fc38d583ea4,
+                0x65138cc9d0b1300d,
+            ])),
+            Felt::new(BigInteger256([
+                0x979be55dd0ac9f86,
+                0x77ca428ae0188549,
+                0x59aed15b22ba0ca5,
+                0x3caba4d3c07647c2,
+            ])),
+            Felt::new(BigInteger256([
+                0x850e79d92ad32963,
+                0x09d7bee3aeb54a97,
+                0x13cf6888d0d6224d,
+                0x47bdbf04017f895b,
+            ])),
+            Felt::new(BigInteger256([
+                0x4c61d72ed73d524e,
+                0x93e34fb3d7764d48,
+                0x29e715689e2451d9,
+                0x344a61ff40417c61,
+            ])),
+            Felt::new(BigInteger256([
+                0xfaefae33a0a63540,
+                0xd657bc5fffc615f9,
+                0x8119336d9caa96a9,
+                0x2e417275d58af8b0,
+            ])),
+            Felt::new(BigInteger256([
+                0x8e9cfd025091dc16,
+                0xcc66bd7b920f189b,
+                0xa4835302a95fefd8,
+                0x6a141b416ffd46d1,
+            ])),
+            Felt::new(BigInteger256([
+                0x7de2b9dd3af62c7d,
+                0xbb9805febd965cb8,
+                0xde747c0daabc271e,
+                0x42f7cced197dbf37,
+            ])),
+            Felt::new(BigInteger256([
+                0x8c5d67d0ed631105,
+                0x237ee25024efce7a,
+                0xc3ef778460fd1e5d,
+                0x5aa8357c1a7e2d27,
+            ])),
+            Felt::new(BigInteger256([
+                0x7a00f4330e255689,
+                0x3ae65f5daee0af4c,
+                0x3c4071da380f6bd4,
+                0x49f786c66f012bcf,
+            ])),
+            Felt::new(BigInteger256([
+                0x69ad2a74841b9c77,
+                0xdde61bd603c4dcb7,
+                0xd828320c9b78980c,
+                0x435d05cf48d8dc18,
+            ])),
+            Felt::new(BigInteger256([
+                0x8fd9665476a9cb5a,
+                0xf5688e0b27f8c7ae,
+                0x0e3482cd46fb7240,
+                0x36d5f3b419f46477,
+            ])),
+            Felt::new(BigInteger256([
+                0xb3b5aaaf630b499b,
+                0x2cf7abcb67947e4a,
+                0x2aa02c0571162edc,
+                0x71fadf25774ae70e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x337bfd08550f5efb,
+                0x46084aaee33c27c2,
+                0x1c03cfd4c584a7b1,
+                0x0e0dada5b098a581,
+            ])),
+            Felt::new(BigInteger256([
+                0x63d58f16968a1821,
+                0x94f1508e70267222,
+                0x0805a8c1a729230d,
+                0x3286fd80cc483c19,
+            ])),
+            Felt::new(BigInteger256([
+                0xef2cf74f74e609db,
+                0xb2e55d8a5e69fb5c,
+                0x1915d54452a71a6d,
+                0x2ee5d174dfdf9ede,
+            ])),
+            Felt::new(BigInteger256([
+                0xd0982c3152966913,
+                0x42edf230b02c8a0f,
+                0x34c01639642d2a58,
+                0x4826dddc15e1295d,
+            ])),
+            Felt::new(BigInteger256([
+                0x5f7894dcfe3caee3,
+                0x28c6ab1e1b9f360d,
+                0x763f1afed177441a,
+                0x2c4dc3c32dd678be,
+            ])),
+            Felt::new(BigInteger256([
+                0xf86756fbadd80bbf,
+                0x5d8b279d18959635,
+                0xcad066689b6a9b44,
+                0x4753bd7e46d43f7d,
+            ])),
+            Felt::new(BigInteger256([
+                0xcfa9a07caf1665ea,
+                0xcb2f5e306a482e90,
+                0x67a5a255d914899f,
+                0x0255b7ab3429b011,
+            ])),
+            Felt::new(BigInteger256([
+                0xc68a47d7a874e087,
+                0x1395770de4df5fdf,
+                0x1783ac0ac51555ad,
+                0x4126d80fbf67e59c,
+            ])),
+            Felt::new(BigInteger256([
+                0xb16e997caef88f05,
+                0x402ef91edae39683,
+                0xe4b17e06d76cf044,
+                0x03b8bc28530164de,
+            ])),
+            Felt::new(BigInteger256([
+                0xb20615a04c38cda6,
+                0xb8829c563d2cca01,
+                0xbd165404fb99ad94,
+                0x4b99005d0f493193,
+            ])),
+            Felt::new(BigInteger256([
+                0x67090850942a38cd,
+                0x29b6fa7135608c71,
+                0xdf2a290c56ad4e3a,
+                0x743f93932fac2939,
+            ])),
+            Felt::new(BigInteger256([
+                0x8ce0a1e0d98e3c89,
+                0x547717b9f9a754b3,
+                0xb3895805925397e2,
+                0x5707af80a09a2a03,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe0a34b76824ec901,
+                0x26e1c621f78ebbac,
+                0x462289c38731631d,
+                0x02da6a20276b6121,
+            ])),
+            Felt::new(BigInteger256([
+                0xe91661eb8b373e2c,
+                0xbbbdc7cc68c20a41,
+                0x2f08cb89b1dfbec5,
+                0x258002629126a52c,
+            ])),
+            Felt::new(BigInteger256([
+                0x2ad6c0c86ea28786,
+                0x6e2b3b4a65d47703,
+                0xb9c8da3ba5c9b0dc,
+                0x1ef329579b079b2f,
+            ])),
+            Felt::new(BigInteger256([
+                0x8f1582c50d196cad,
+                0xeb513efb095ebb15,
+                0x1174c3db7e0d0efc,
+                0x5772f265d6fabde4,
+            ])),
+            Felt::new(BigInteger256([
+                0xbbd2055610f2fab9,
+                0xbc7ce07399a58718,
+                0x428cb10dd12373e5,
+                0x27da9253d5e55862,
+            ])),
+            Felt::new(BigInteger256([
+                0x54c3421944c0fce5,
+                0xa334ff38a6786e31,
+                0x7b2bc4e4a59cf9a4,
+                0x7d95e367116d9c4e,
+            ])),
+            Felt::new(BigInteger256([
+                0xb565f1c78d47d51a,
+                0x90a6b60aafb78dcd,
+                0xd6ca8c7752a9a267,
+                0x5b08181898a1a008,
+            ])),
+            Felt::new(BigInteger256([
+                0x434be515ad988283,
+                0x1bc20730194ef291,
+                0x902336f4c26b1c2d,
+                0x49bae6f8d1393aaa,
+            ])),
+            Felt::new(BigInteger256([
+                0xcc11b711107f9684,
+                0xdac36d33722def64,
+                0xfaf10f0ebd3a7f48,
+                0x33259b02bfc58d38,
+            ])),
+            Felt::new(BigInteger256([
+                0x1fc9380ceb3f000f,
+                0x08ba69f8b8ed8e40,
+                0x98ca73cc2d207d17,
+                0x71014bf4baf3631a,
+            ])),
+            Felt::new(BigInteger256([
+                0xf04f507d2cadc800,
+                0x294277ce6a7c84f1,
+                0x4e537635bbc8bee7,
+                0x5246cf281aaf2f07,
+            ])),
+            Felt::new(BigInteger256([
+                0xe235cf7302a5b904,
+                0xf9bf415e7592a1a8,
+                0x167ed127ac1ccda0,
+                0x0446daa0e82b0532,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x38e2a6bdd5bdf85a,
+                0x256820d30c2d3cb7,
+                0xece54f5addf5c52d,
+                0x1f14d3beed02ef65,
+            ])),
+            Felt::new(BigInteger256([
+                0x1b4dc1e57c20d381,
+                0x7e035ea966e7b58b,
+                0xcc104b621a9bd248,
+                0x53cdd275dca8a2ab,
+            ])),
+            Felt::new(BigInteger256([
+                0x67b522ab599a48b0,
+                0x0f9e5aa457863f19,
+                0x7a72a5eef9499f2f,
+                0x1918666a32a5ff96,
+            ])),
+            Felt::new(BigInteger256([
+                0x636f7a1886f7f61d,
+                0x6fdce13601cbe160,
+                0xbec4de092f524204,
+                0x7fefd85a97d5c4cc,
+            ])),
+            Felt::new(BigInteger256([
+                0x23c9f98e6dbbc8cc,
+                0xad304a74e79ac44f,
+                0xdac13c6ff94777e9,
+                0x42c23965d6a937aa,
+            ])),
+            Felt::new(BigInteger256([
+                0x076543538315049e,
+                0x2c5c0e2e2cc4e548,
+                0xe9d2d7a25f1dc989,
+                0x5ced8bd6b367c6db,
+            ])),
+            Felt::new(BigInteger256([
+                0x8e32d8bd8711f86c,
+                0x9407b50d618da7d4,
+                0xeb7c3b0c989786e5,
+                0x5f63bdd4f440c216,
+            ])),
+            Felt::new(BigInteger256([
+                0x9a2bd2bf1113679e,
+                0x54d850d22d32ccb5,
+                0x8c4cf98b358b7ab4,
+                0x65c536ea6b484941,
+            ])),
+            Felt::new(BigInteger256([
+                0x000d3ba4cacdfc01,
+                0x9e65ae77b06682f8,
+                0x427b3685ef695177,
+                0x22cb2a8f5979e57d,
+            ])),
+            Felt::new(BigInteger256([
+                0x185283b9809621d4,
+                0x56268c9679fa58b6,
+                0x883b49c7512df436,
+                0x2412ae9a7a406aaa,
+            ])),
+            Felt::new(BigInteger256([
+                0x0c4de6b63368e78d,
+                0x1f68c9e279b64230,
+                0x1929caa269758ebe,
+                0x475aa55506e5b26b,
+            ])),
+            Felt::new(BigInteger256([
+                0x5843df2efcfd467a,
+                0x509601f9a5ed3e4b,
+                0x7f2a3c1ee28b4472,
+                0x6c1f7d40c6a662e2,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x7d72d65bb785ec08,
+                0x0ae548c746a0f327,
+                0x9ddad0f2b719cf92,
+                0x2c48ebbb7b1aafd8,
+            ])),
+            Felt::new(BigInteger256([
+                0xbb4e6927ce5a1088,
+                0xcbaf299d032bcdc8,
+                0xb6aac6dc28f02fca,
+                0x6a735f53e10668f3,
+            ])),
+            Felt::new(BigInteger256([
+                0x373094c38fad2c42,
+                0x031fe05ce1ecdf02,
+                0x23012c98c9bbb2c2,
+                0x7a731c5963e7f40b,
+            ])),
+            Felt::new(BigInteger256([
+                0x8faf79a5712dd9aa,
+                0x5079598855a71759,
+                0x948ceb659de08c24,
+                0x5ba409e964f6fd0b,
+            ])),
+            Felt::new(BigInteger256([
+                0x77dde0542eb8876a,
+                0xc759a7ae82f68d24,
+                0xc1bef4174bcfc014,
+                0x420d331c57bc4370,
+            ])),
+            Felt::new(BigInteger256([
+                0x5ee5db40aca3b8fb,
+                0x6c1b6baa552e1eff,
+                0xac4b7703088164e1,
+                0x7333cac63b34ff08,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xda74f89c1f856a33,
+                0x198131497feb1e31,
+                0xabd63f4530e2981a,
+                0x5e3bac77986a763b,
+            ])),
+            Felt::new(BigInteger256([
+                0xb1166bfd1bc16dce,
+                0xbe4d906c7862c1b3,
+                0xc39f78368ec733e5,
+                0x6a5288f66d85a20a,
+            ])),
+            Felt::new(BigInteger256([
+                0xc44221f7e0e0d90c,
+                0x1ff9d8f76959741f,
+                0x9071fd46af9c27c7,
+                0x0c1ad93923c7ebbe,
+            ])),
+            Felt::new(BigInteger256([
+                0xfcb0d5a54982651b,
+                0x1b64b42a36a8f0d3,
+                0x039e1bbb2e89003f,
+                0x6495235d63b40499,
+            ])),
+            Felt::new(BigInteger256([
+                0xe1977f35966141c9,
+                0xda710aec8f094b58,
+                0xc07b89e25715a7f3,
+                0x7687ec0d39ef5d7d,
+            ])),
+            Felt::new(BigInteger256([
+                0x293ad1c60c7a13fe,
+                0xe763870ebaba8acf,
+                0x57a70a6d177fd0a3,
+                0x1f6b5f6b029f9ea0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x267b65ff7e767981,
+                0x090c1524321ee118,
+                0x5b60bf758597e42c,
+                0x3026a80ea9a538aa,
+            ])),
+            Felt::new(BigInteger256([
+                0xbf36f99935577f9b,
+                0x0021e7e658db2b20,
+                0x51e9d537564b1896,
+                0x17b4b58e6b46a322,
+            ])),
+            Felt::new(BigInteger256([
+                0xe7812e575f1bc8d5,
+                0x87ac651e0cab0cfb,
+                0x3aadbdc02a82188a,
+                0x4e3596f0e65bbad8,
+            ])),
+            Felt::new(BigInteger256([
+                0xf66fdc06cfbf292f,
+                0xbbec83f88e80ac77,
+                0x35b9717a5e3f645f,
+                0x732ce83a0969933c,
+            ])),
+            Felt::new(BigInteger256([
+                0x73c3cad9debddc2a,
+                0x42bac7ec733045d1,
+                0xb26b8778a3c8a01f,
+                0x4e4d88c9a66240ea,
+            ])),
+            Felt::new(BigInteger256([
+                0x4cdb87b3484fb29b,
+                0xe8588acb0675eab8,
+                0xabe3f48e8289f30c,
+                0x40463c07da7579e1,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x63c461ffa3f25407,
+                0xc976500217f94b14,
+                0x5ab77d78c0570f18,
+                0x4255ec1ad3293daf,
+            ])),
+            Felt::new(BigInteger256([
+                0xb0590e3f9f701900,
+                0x2c4663e354e840e8,
+                0xae7601258b216471,
+                0x6129ce6243dfab17,
+            ])),
+            Felt::new(BigInteger256([
+                0xe40e0b8502bca493,
+                0xd4f6101ce5ae6467,
+                0x5217f119149a312b,
+                0x03bc014c41b311e6,
+            ])),
+            Felt::new(BigInteger256([
+                0x1542f3e947b53b63,
+                0x3742cb0c64adfdc0,
+                0x42368df6c08b3ecd,
+                0x3f796554948f3dac,
+            ])),
+            Felt::new(BigInteger256([
+                0x1ba46cba60ce3b80,
+                0x53cafa86fe489ca7,
+                0xa17e1bc96302c559,
+                0x1dab2934cb83b474,
+            ])),
+            Felt::new(BigInteger256([
+                0x5f08198c9002994e,
+                0xac69d65ffbefcbe0,
+                0x1e1056407cbc1e40,
+                0x5a30272fbc69ddcf,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x76087f7750897a33,
+                0xe14412a3d39dba9d,
+                0x416e38d1936cea61,
+                0x058fd439f3276790,
+            ])),
+            Felt::new(BigInteger256([
+                0x5fa5d01e5317f755,
+                0xb0e173d93a4dc4d4,
+                0x031dfe7ba76a93fc,
+                0x3cdf9480a195612d,
+            ])),
+            Felt::new(BigInteger256([
+                0xd8d44772cd00c9e5,
+                0x935df02acb32c0ba,
+                0x3fe25d0a1c4f3620,
+                0x79142123d8028849,
+            ])),
+            Felt::new(BigInteger256([
+                0xec04213484c6a4fd,
+                0x8dd7c24249e28afb,
+                0xc1de571c947fa725,
+                0x45ddc6dadc1cfb44,
+            ])),
+            Felt::new(BigInteger256([
+                0x9de6f8a0e9488d0d,
+                0x262169ab97200859,
+                0xe0a3d024494df2e3,
+                0x7afbdd43b5b665da,
+            ])),
+            Felt::new(BigInteger256([
+                0xb51dc5b4a0726cd8,
+                0x97e6924fe283e613,
+                0xec3c2803fd4406c2,
+                0x15187dd2fa78151a,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x981f2b6327a7a410,
+                0xb22d6969ececac2b,
+                0x6b627618170c98ce,
+                0x0ee199a2199b2e97,
+            ])),
+            Felt::new(BigInteger256([
+                0xf1ab3e72abe5bc83,
+                0x3ef34449f0dd7d62,
+                0xf4a8d6fb36efc33d,
+                0x7d6733e873ebcd0e,
+            ])),
+            Felt::new(BigInteger256([
+                0xcd1336789dc54850,
+                0x9321a0107b3677c2,
+                0xafeeac50ec93c9bb,
+                0x0f5cb0eed5f8da67,
+            ])),
+            Felt::new(BigInteger256([
+                0x247796e44805da41,
+                0xd6995b679658731d,
+                0xeb7eae537d551ad0,
+                0x0a5ed4aa76f2dd0e,
+            ])),
+            Felt::new(BigInteger256([
+                0x103ad6a17775293c,
+                0x914a029057517437,
+                0xb856b78a90d6ad5b,
+                0x2e9cae93eb6a94f8,
+            ])),
+            Felt::new(BigInteger256([
+                0x486829d24fcc654f,
+                0xa575e9ae5c79b9c0,
+                0xa8558311cac41733,
+                0x341041495e0cac57,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xd77f468c1eee975d,
+                0x971973cdee577841,
+                0xfa0e67a89bdb21a2,
+                0x45227abb4e4f8b60,
+            ])),
+            Felt::new(BigInteger256([
+                0x285e8a34144cdce5,
+                0x0e89779840155dbb,
+                0x04dcf3cde35b7cca,
+                0x562dba6d4ed03e6c,
+            ])),
+            Felt::new(BigInteger256([
+                0xad92586d1bd8e674,
+                0x2e00d924f3927e7c,
+                0xc8a2e4d3ddaa4854,
+                0x3e04903c0d74e560,
+            ])),
+            Felt::new(BigInteger256([
+                0xa22bafbfab48b951,
+                0x8ece722b7940d7af,
+                0x1222008cb0536d35,
+                0x66485ddd34cc4e20,
+            ])),
+            Felt::new(BigInteger256([
+                0x5f374953dd753020,
+                0x42c71f9e444de001,
+                0x962d0a2feaece5ac,
+                0x779362cd3b0738dc,
+            ])),
+            Felt::new(BigInteger256([
+                0x8461cfc2807a41e0,
+                0x5f24998a037421ec,
+                0x92d30a19137b7449,
+                0x126b81be5ca2f2d5,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xf8c24b9a272e6672,
+                0x5c41746afaa5b82b,
+                0x18dd31300e5e9471,
+                0x71e717ff41fbee56,
+            ])),
+            Felt::new(BigInteger256([
+                0x526fca07e2e09fc2,
+                0xaf3cda1f8d50efab,
+                0xe82a9dbdcd4724e4,
+                0x79ec9ce72234cbe8,
+            ])),
+            Felt::new(BigInteger256([
+                0x339cb321c857381c,
+                0xd6143abc24505d88,
+                0x6754ffcdd5f8462e,
+                0x6a3eb55c39137de8,
+            ])),
+            Felt::new(BigInteger256([
+                0x1d4f58a7260d035d,
+                0x8b4cfe4f7c412b1e,
+                0x2acddd652fb04557,
+                0x37b4ce8fa515748a,
+            ])),
+            Felt::new(BigInteger256([
+                0x5a6eae4953fceaa5,
+                0xb29dce6189b5addc,
+                0x17993b2a1f270464,
+                0x53541377e9386ca6,
+            ])),
+            Felt::new(BigInteger256([
+                0x550b6bb7bd0b4551,
+                0x180f0ec848a68a0b,
+                0xe7f0c1f199bf51eb,
+                0x68e98ae50056168c,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xea5f7f087d390414,
+                0xeb43cc1e98ae5243,
+                0xaa2ad5ce1416e861,
+                0x2ef7adfcbed71cbd,
+            ])),
+            Felt::new(BigInteger256([
+                0xa38b442d73016ad2,
+                0xa612515de128afac,
+                0x383e1a4bc4eac4d6,
+                0x26783921e3fb0897,
+            ])),
+            Felt::new(BigInteger256([
+                0xebcdf881ebabcb73,
+                0x14e685dafcc6358c,
+                0x742b92688ac9fc04,
+                0x5ba9653a4162ae9a,
+            ])),
+            Felt::new(BigInteger256([
+                0x25cb4970ab4ef6bd,
+                0x91318823bf4c80e4,
+                0xd2e12288d3f0f822,
+                0x3b7df65229341618,
+            ])),
+            Felt::new(BigInteger256([
+                0xedbfa344bdd2a1cb,
+                0xe0ad0582d9e62186,
+                0xafec2fa90dd7a992,
+                0x1ca20ccb4c0839fd,
+            ])),
+            Felt::new(BigInteger256([
+                0xa2052cca5e09f18d,
+                0xcc2d889e6297c49b,
+                0x5508078a6ab30267,
+                0x1ec5dc233d7b79e2,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xd8ca5fc6b3cf318b,
+                0x2695c072945a05bc,
+                0x4272b08d52f507cd,
+                0x663aeba6d89d4696,
+            ])),
+            Felt::new(BigInteger256([
+                0x5d3e3cc5ce2a72c0,
+                0xabea45b2b7a8e87f,
+                0x99e5a44d2fb3730c,
+                0x6518da447742e30b,
+            ])),
+            Felt::new(BigInteger256([
+                0x1124f1f0fe913454,
+                0xc0bfa3cdcffdbe70,
+                0x2575ea7e6035f00a,
+                0x232712ef12b01363,
+            ])),
+            Felt::new(BigInteger256([
+                0x1d49643113a1483b,
+                0x9df6097d8f0e87bd,
+                0x64448e5e3391c87a,
+                0x127fdfaacc41721e,
+            ])),
+            Felt::new(BigInteger256([
+                0x0467e09731f79030,
+                0xad25eed8d849bc20,
+                0xf9193d33f2e2b842,
+                0x73d04f9147aae2a3,
+            ])),
+            Felt::new(BigInteger256([
+                0xd783019cb854ff15,
+                0x7167899680f46703,
+                0x929804ef55909cbc,
+                0x51798d4472b98127,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf27e836f1a51ead4,
+                0xb545d8e49858f800,
+                0x6d8a69c9e73f7063,
+                0x4cdc69b55227fbe2,
+            ])),
+            Felt::new(BigInteger256([
+                0x993e906b733fbd30,
+                0x7eea3b293952edab,
+                0x285a5f25cbfb7099,
+                0x4b4c0b419450ce3c,
+            ])),
+            Felt::new(BigInteger256([
+                0x877e8373d23f61da,
+                0xe5ac1256aa7b04f6,
+                0x6cffe7c855167ea0,
+                0x31b07dede6e8bec5,
+            ])),
+            Felt::new(BigInteger256([
+                0x815c8f45a228a6f5,
+                0x9c609c8e706e2d70,
+                0xd63e0428206744f4,
+                0x6ac9d6973b4a95c1,
+            ])),
+            Felt::new(BigInteger256([
+                0x8c8451f9438997e4,
+                0xeac07b2a80c9aefc,
+                0x42064638d71fbada,
+                0x42267bd00b403f73,
+            ])),
+            Felt::new(BigInteger256([
+                0xc6e41c95878a8b5d,
+                0x58df53869e4fdb3d,
+                0x1098653e0dbbfbbb,
+                0x43bf3a8f0a8a1ce5,
+            ])),
+            Felt::new(BigInteger256([
+                0xd011732213038cc9,
+                0x5c7656f757b81e03,
+                0x5e3df809ef36395a,
+                0x063a5ac0da5c90bd,
+            ])),
+            Felt::new(BigInteger256([
+                0xdcb06d98d37b365a,
+                0x1c07709b73560e2d,
+                0xcf664d93f5484b07,
+                0x3790f620a480c8f5,
+            ])),
+            Felt::new(BigInteger256([
+                0xab7397133b0c8444,
+                0x4b854788492b61a8,
+                0xd432803b4681bec0,
+                0x082f200f3d2cdbc7,
+            ])),
+            Felt::new(BigInteger256([
+                0xdb3ccd3b435427eb,
+                0x402a45c248a15eb6,
+                0x8d6360cdf677848a,
+                0x318a5eae29bfaf69,
+            ])),
+            Felt::new(BigInteger256([
+                0xb6bae340522dd0fd,
+                0x158442bc0cdbf5f5,
+                0xa004202279ecdb1a,
+                0x2fc0ba5b06681e52,
+            ])),
+            Felt::new(BigInteger256([
+                0x02e35f45321e8e5f,
+                0xe002657f8ad1e455,
+                0x11b1274207bc2b6a,
+                0x2146cb164d10f654,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xba1b239925c22f14,
+                0x2d0d2c527f6d46b7,
+                0x0d285389de074c0e,
+                0x2040f475992c1894,
+            ])),
+            Felt::new(BigInteger256([
+                0xe3d849e4a905ad86,
+                0xf436a8e2ada5a596,
+                0x32e88a2d84268365,
+                0x4f31278e1e0dd346,
+            ])),
+            Felt::new(BigInteger256([
+                0xdd1e93d58ad72236,
+                0x963c2e22ca74d869,
+                0x86dd46d0f07723be,
+                0x5de39343c768b7c4,
+            ])),
+            Felt::new(BigInteger256([
+                0xa710739868311410,
+                0x65d81cf853143adc,
+                0x80e05103b6d6f97c,
+                0x3d5ea201c556569c,
+            ])),
+            Felt::new(BigInteger256([
+                0x00a0e03c6ffbd7c8,
+                0x4fd8dd8c96614b35,
+                0xbbec76ba0d67b39f,
+                0x1d4cac553c90fb00,
+            ])),
+            Felt::new(BigInteger256([
+                0x9d0d7adb7b0bd72e,
+                0x41df0522f6a2765d,
+                0x702d17ef53c64f55,
+                0x01ab335932c45979,
+            ])),
+            Felt::new(BigInteger256([
+                0x17bae11be1aa51f1,
+                0x4974a61d5aee2150,
+                0x31c15ee18fa604be,
+                0x23d45920ad119f70,
+            ])),
+            Felt::new(BigInteger256([
+                0x8c48278cfae466db,
+                0xb6f5c1105a9029b0,
+                0x54091f658c0d8dc9,
+                0x32fbdde1e5b9111c,
+            ])),
+            Felt::new(BigInteger256([
+                0x4a10a6fcb7cf25f2,
+                0xf76e134b1852cb50,
+                0x694d9c55a75358b2,
+                0x0805d729db222e3e,
+            ])),
+            Felt::new(BigInteger256([
+                0xa50f705a8355a1e0,
+                0x46c41e7be13fead3,
+                0xe538e460afcdcf95,
+                0x61ce96b97b6f087d,
+            ])),
+            Felt::new(BigInteger256([
+                0x791ddaf20b590d9d,
+                0x06e563f8f3915383,
+                0xa5b15a4b50597d5f,
+                0x03e0ebc15d2c269e,
+            ])),
+            Felt::new(BigInteger256([
+                0x53ea300983eb77ff,
+                0xd2543db557d88d63,
+                0xbfb12299e9e979d0,
+                0x0025b1a693aee516,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x514c42b4183de402,
+                0x2c504d5398d12824,
+                0x768b5e69543957d3,
+                0x6371b8e32f181764,
+            ])),
+            Felt::new(BigInteger256([
+                0x6f021954f7d29559,
+                0xf8f07db5af904fc2,
+                0xc7b36f667d4fe1be,
+                0x0716b5366defc648,
+            ])),
+            Felt::new(BigInteger256([
+                0x86805a99d267faac,
+                0x4ed4ae4fa166c928,
+                0x2c83ae1ba1b40cf1,
+                0x0069b95713b79df2,
+            ])),
+            Felt::new(BigInteger256([
+                0x78d4af99d6134705,
+                0x057d392edc090952,
+                0x074744e59ff919e2,
+                0x1147aa2b10a4866d,
+            ])),
+            Felt::new(BigInteger256([
+                0xdd2cba352412cea7,
+                0xbe4e2fc45393d8b9,
+                0x19097342279ca464,
+                0x6d25a2c4167c8026,
+            ])),
+            Felt::new(BigInteger256([
+                0xec782c895c83a00a,
+                0xfc62166c17a5475f,
+                0xab6bae2c3f02933f,
+                0x37f90b9074ad43e2,
+            ])),
+            Felt::new(BigInteger256([
+                0x824ba866467bad16,
+                0x2502d24512be076c,
+                0x649a969da101ba35,
+                0x1225597fcb6862c8,
+            ])),
+            Felt::new(BigInteger256([
+                0x92cf8963d245f032,
+                0xad604300728e3c72,
+                0x578c04f6223942c0,
+                0x496cbc4ff0c62cde,
+            ])),
+            Felt::new(BigInteger256([
+                0x0359801bb0380eb7,
+                0xe9797ca927735bb5,
+                0x0cd2586acd456ef1,
+                0x20cc12630cc9087d,
+            ])),
+            Felt::new(BigInteger256([
+                0xf14e873a992a697f,
+                0x35665e491e4e520a,
+                0xba51dae51894d743,
+                0x0fdc2559192741ca,
+            ])),
+            Felt::new(BigInteger256([
+                0x66bb95b50da567e5,
+                0x67b95daacc6841b9,
+                0xe574930dea87771f,
+                0x1011cab946734720,
+            ])),
+            Felt::new(BigInteger256([
+                0x92623b71e9a87cd9,
+                0x98e35593e1e2b027,
+                0x0c0a7ab73d478d82,
+                0x00eb5066f8c41a59,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x72c326a29cb84001,
+                0x3995b17b4d7b5735,
+                0xdd2dd9749425c11f,
+                0x54423185578c6b5e,
+            ])),
+            Felt::new(BigInteger256([
+                0xb191b21dfcf86743,
+                0x569788132031e738,
+                0x3d20e60b745105a9,
+                0x28571825502a4437,
+            ])),
+            Felt::new(BigInteger256([
+                0x0d1e104e7c418116,
+                0xf281593b2ec78a03,
+                0xf9616c10dbecff6d,
+                0x45fb869c96b11fec,
+            ])),
+            Felt::new(BigInteger256([
+                0x3cd3479c1cad664a,
+                0x9d5606be0753f817,
+                0xe4fcce5b994d0a18,
+                0x26b6636b2b9ecbf0,
+            ])),
+            Felt::new(BigInteger256([
+                0xe0db457d472500af,
+                0x5910568be1e1497c,
+                0xf1a104814b5fee03,
+                0x0f6a48556fb89beb,
+            ])),
+            Felt::new(BigInteger256([
+                0x56f3d79deae5c8bd,
+                0x4eece39abe3d5fdd,
+                0xe50c6c4392c7c6fa,
+                0x5a684e4046121ffa,
+            ])),
+            Felt::new(BigInteger256([
+                0x1b7757909f68b566,
+                0x6240682139ed3c13,
+                0x9a0f6d72d28863db,
+                0x3317f292cbff2786,
+            ])),
+            Felt::new(BigInteger256([
+                0xd39f16bf49cb7eed,
+                0x93de00d8c385432d,
+                0xdafd6ebc0db7435c,
+                0x34509d74946c00bd,
+            ])),
+            Felt::new(BigInteger256([
+                0xf1818c75f2c4e1c0,
+                0x21311586ef68bc1e,
+                0xb925715478487170,
+                0x10723020ede80db0,
+            ])),
+            Felt::new(BigInteger256([
+                0xa729d44b77580e5d,
+                0x2a66bed4c916a943,
+                0x428411d4d6c583e3,
+                0x60ca4baccdd7d7b3,
+            ])),
+            Felt::new(BigInteger256([
+                0xc583ddbe74d18ec5,
+                0x5bd245c1d39e284e,
+                0xda4406669bd00493,
+                0x496d4d790d182858,
+            ])),
+            Felt::new(BigInteger256([
+                0xe1e0f1f61b5b170f,
+                0x0702acf2e3238eda,
+                0x27e944cbc00a013a,
+                0x1acbe581bac69183,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x986ee86281c9aa00,
+                0x233394d9d3cc47c6,
+                0x6af2916a1c6153b5,
+                0x6dd8bf45c4a230da,
+            ])),
+            Felt::new(BigInteger256([
+                0x0a1b269cc1b95f49,
+                0x5a230963e01cb3f4,
+                0x8194e9b3b7b4425f,
+                0x7eb340c2952490f2,
+            ])),
+            Felt::new(BigInteger256([
+                0x312e347f1d54137b,
+                0x37404ec8ab23b79f,
+                0x51df4681a5cdbbcb,
+                0x2f953ff30539c91c,
+            ])),
+            Felt::new(BigInteger256([
+                0xcdd0a45702edb3ee,
+                0xe1c4dae2e46aba87,
+                0xfcdaa31677b022bc,
+                0x2e6863bd0a5dfb8b,
+            ])),
+            Felt::new(BigInteger256([
+                0x728abeb66e8e929e,
+                0xd91d2b84c13eb370,
+                0xd1f6674f5cce1dd9,
+                0x656b870805eb3358,
+            ])),
+            Felt::new(BigInteger256([
+                0xd1bee4337b4e8c8d,
+                0x83b010e658ff5d43,
+                0x52038fe39353d1ab,
+                0x4574104421e619fa,
+            ])),
+            Felt::new(BigInteger256([
+                0xacc515afa8a4961e,
+                0x9e06d564fe660b67,
+                0x660f8f529131c930,
+                0x70a3a8a10bcf37e5,
+            ])),
+            Felt::new(BigInteger256([
+                0xf56868fdbdc844ab,
+                0x25db68b32e07c24a,
+                0xafeb4dda2175a351,
+                0x785e91abf68074ca,
+            ])),
+            Felt::new(BigInteger256([
+                0x7ae970838da04d68,
+                0x10e2cd31cc7c80f8,
+                0x05b70f436b6e0078,
+                0x2c3ea4bcbb0ec900,
+            ])),
+            Felt::new(BigInteger256([
+                0xb8c5c4d7b58ef1c2,
+                0x76ec354b4a888f79,
+                0xd5cac2d9c07826dc,
+                0x4aa39a0ce2117a26,
+            ])),
+            Felt::new(BigInteger256([
+                0xc5fbfa7265d5ce24,
+                0xb6fafd28595590cf,
+                0xd411593ae6270407,
+                0x2713308ccee2ad3d,
+            ])),
+            Felt::new(BigInteger256([
+                0xaa87854b6922b1d4,
+                0x74658e9423181939,
+                0xa818a7d4e2fbdde9,
+                0x7633b23058780512,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x47a0e0c641dd5e4d,
+                0x5c78820bb56265cf,
+                0x4bc4c3d5d7ed39f4,
+                0x7c605d477b4b6fb5,
+            ])),
+            Felt::new(BigInteger256([
+                0x1f0f2af13db152e8,
+                0xdc4a23d8bfde2f32,
+                0xe00498d548d2be4d,
+                0x5d5f195feddb7301,
+            ])),
+            Felt::new(BigInteger256([
+                0x0535451586dd6633,
+                0x330fcca431a02b98,
+                0x1c3811b4f271f3ec,
+                0x4f7adfbd44a8a4cd,
+            ])),
+            Felt::new(BigInteger256([
+                0x7a5d902c4c04da77,
+                0xb8572506993402d5,
+                0x87e7d3405ec5f43a,
+                0x34ff8e197fed9e2c,
+            ])),
+            Felt::new(BigInteger256([
+                0xed71ffe33aa180fc,
+                0x59de739c8bf1313f,
+                0x1a257aaa0bfa3f0d,
+                0x6439587b9046cb08,
+            ])),
+            Felt::new(BigInteger256([
+                0xf2a499153b2d6d14,
+                0x080d55d7ff70bbab,
+                0xdb3bcc909de4c38f,
+                0x773623eb4294fe8c,
+            ])),
+            Felt::new(BigInteger256([
+                0x73be7bf16176bfff,
+                0x2646e6bf09dae865,
+                0x2dc61510e02bc383,
+                0x065a378f1dff67fe,
+            ])),
+            Felt::new(BigInteger256([
+                0x144096eebea4cc3b,
+                0xe3197c7fba6b8142,
+                0xcdeb4d22e92448e3,
+                0x2f6e34033b6f6e5b,
+            ])),
+            Felt::new(BigInteger256([
+                0x20efc324d0698932,
+                0x3b15fa04718f623c,
+                0xd56b4c2180c26ec4,
+                0x2d696e285dcbbc2c,
+            ])),
+            Felt::new(BigInteger256([
+                0x33dd9cbbd9e98fe7,
+                0xac4a9cdc5aceacc4,
+                0x3883347daa0a1ad6,
+                0x359f72b9588d744d,
+            ])),
+            Felt::new(BigInteger256([
+                0xcbf51e3f8ae1bc4d,
+                0xd42df68c336b1054,
+                0x2b1b428e1d8dd288,
+                0x5f2b6607afb14cf6,
+            ])),
+            Felt::new(BigInteger256([
+                0x8276d90b177fff8f,
+                0x9785363d2e6f5d1f,
+                0x706c559b52233ccd,
+                0x7c18aa890de509d4,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x2c814b7375eb9fc7,
+                0xd55ed0d2ab845f4e,
+                0x829af1a2cca54268,
+                0x68c93b3136bee754,
+            ])),
+            Felt::new(BigInteger256([
+                0xa9e3be0dec2ba353,
+                0x8843eecfae010421,
+                0xf7832944cf5220d0,
+                0x394b340381326507,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x804e99c996c7851b,
+                0x13ec152d784dddaa,
+                0xfcc3c66e379467d5,
+                0x60de71bdf621bf77,
+            ])),
+            Felt::new(BigInteger256([
+                0xd702136871bde6fa,
+                0xc115cba569c63d56,
+                0x1ee49e5ed4d004c8,
+                0x6e530bbed3d94544,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x81c05f30bc501e93,
+                0xd373422eb1fa33e5,
+                0x487a04ae53e29cd5,
+                0x4ca9c7c93663346d,
+            ])),
+            Felt::new(BigInteger256([
+                0x02825d534d665b78,
+                0xa466f6a9edd1c251,
+                0x33873b4037147002,
+                0x4b27d9d04f25b040,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x6376da3f077d341a,
+                0xf2375aa5fbf04c23,
+                0x4e4d8a5b37f4059d,
+                0x63bd169be060040a,
+            ])),
+            Felt::new(BigInteger256([
+                0x24a41bb57727edc4,
+                0x0ff3054fb5860a89,
+                0x0ebce55cc868c17f,
+                0x7ad35ae694d8c693,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x7499f4fe4079fe5b,
+                0x7b30f0a785214375,
+                0xf69de3a9ab2a8b50,
+                0x38293c947faba0e0,
+            ])),
+            Felt::new(BigInteger256([
+                0x4642e2cd8c27f584,
+                0x1ab155f8124942ac,
+                0x5a79d266d7196cdf,
+                0x56a28eac203f9eea,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xdf85e7f540f35dcd,
+                0xa7b9e1fbff53f4c1,
+                0xe42d26b04e9af93d,
+                0x1b6772471afecab5,
+            ])),
+            Felt::new(BigInteger256([
+                0x6fe5138c6b81386b,
+                0x416039561506e002,
+                0xc49d05ebf55ede26,
+                0x33104537d8cfab0d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xe9e95b234bd5cf8d,
+                0x3eef66aa073076fb,
+                0xbe8e51723cde181b,
+                0x4dfbb23a948587fd,
+            ])),
+            Felt::new(BigInteger256([
+                0x2149c28653978341,
+                0xe40697edceca37c2,
+                0x1dd872e45dcc7768,
+                0x24958a32ff302298,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x032c300acc339a07,
+                0xde801c00d6c075d8,
+                0x8ab5c90efa62a6d1,
+                0x44f6fa83be0939e4,
+            ])),
+            Felt::new(BigInteger256([
+                0x2e1be0cdc37b8fcf,
+                0x6f44b8d260e435c3,
+                0x1b4dcb887f60e76f,
+                0x212123f9943ce781,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x8f064712d7c94c82,
+                0x5533d10bbdee2f51,
+                0x62f069372f7f884c,
+                0x3477f367ba34e43b,
+            ])),
+            Felt::new(BigInteger256([
+                0xfefbf194802d8a00,
+                0x20fbe103c4e72d64,
+                0xca5c43566418a6fc,
+                0x1abd3368c3216d3f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xa4adfc81258da1d3,
+                0x95018b6f0b1d97ce,
+                0xc263a2ffcc6ec1e5,
+                0x09b5a03a461f90a5,
+            ])),
+            Felt::new(BigInteger256([
+                0xc78f18ed3eef5328,
+                0x0c634e53df9f36c4,
+                0x07c6b5ec028de098,
+                0x7096aca7a86efc28,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger256([
+                0x1af18ad5c1a61776,
+                0xe177f54b8f31ec35,
+                0x9a15c9f5b0145fe7,
+                0x51266053c1035e0e,
+            ])),
+            Felt::new(BigInteger256([
+                0xefa6122ebc075d60,
+                0xac55764191d94526,
+                0x7df9ff10d9e1b037,
+                0x0f2584486e1cf515,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x285e555355b23fc3,
+                0x06bf09a85d80cbd0,
+                0x75ae2b872477b04a,
+                0x6d57fede196c0065,
+            ])),
+            Felt::new(BigInteger256([
+                0x825ba2dda2c1cca4,
+                0x5ee2df0e51df2099,
+                0x47362ac690823abc,
+                0x09d2ebfab1f75cdf,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x91769cdddadbfeb1,
+                0xdabadeab5cbb3d6c,
+                0x6b75d91d3962f5e2,
+                0x70710fbcee61fbe7,
+            ])),
+            Felt::new(BigInteger256([
+                0x8e42a30eadf6555a,
+                0xffa6e9c802fc4399,
+                0x194ae17e77682c02,
+                0x222304c31a9b23b6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x33e5a9f46e9e32ac,
+                0x862cefcbb3e61e5e,
+                0x63b869c1ad38f2ac,
+                0x47e8755a9ed6e170,
+            ])),
+            Felt::new(BigInteger256([
+                0xcdbee6cc966452ad,
+                0xb0acaa2ceac179c8,
+                0x589bd024c2b1d5aa,
+                0x16c6b8869862a476,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xab270cf06c52929c,
+            0x1ffbb19a90e75107,
+            0x1b443a01a8812243,
+            0x20991b72b9fbcb27,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd2e3bc675d71ea48,
+            0xaa9e0fa874504fd5,
+            0xa018a7d5691f3c45,
+            0x7ac13a264b02ea04,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb991a42bde92fc1d,
+            0x24fc02b41561b33d,
+            0x9263e62770c47831,
+            0x7c1cb2632da86308,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xce37e86675fc60a0,
+            0xe3837e39f597f133,
+            0x0ad6c98ab922d97f,
+            0x0e4cb694bf81e01c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xf664745be68fffac,
+            0xca39c92dead676d1,
+            0x772c2930af3cf502,
+            0x2a4bd99e9c95808e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa3f3bbea49877e74,
+            0xd1c4c4d17f1e6ee2,
+            0x10f48f18680b4db2,
+            0x23fe0e29b86c45ce,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2c4261709c806d07,
+            0xde58b4bc7327eb6b,
+            0x6b4949160405f875,
+            0x0dc48e3a5e18d84f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x37ea8805ac6bd3c1,
+            0xf8f98c0722e078a6,
+            0x6652e0e6d80032dc,
+            0x4089788f7b70068b,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,2441 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xbc362e968b9e0a9e,
+                    0x9e6a261688752625,
+                    0x8235b12fd7b218da,
+                    0x378049411038e8e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef812f702065d544,
+                    0x482ad74af0085f3b,
+                    0xeab06000b530f26c,
+                    0x4617156841b8833a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2761fb8183f4fdd8,
+                    0x2c9b2b29509deecf,
+                    0xde947acbac746f92,
+                    0x6b8e7454700ce989,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80a76b6dfe901688,
+                    0x50a5fb937cec85fa,
+                    0xfe2d6a712df518c9,
+                    0x303a12ec9abdaf63,
+                ])),
+                Felt::new(BigInteger256([
+                    0x75d5ad0fc64cfc01,
+                    0x1032e5dec15ba37a,
+                    0xf6a8b2d3cd4add9d,
+                    0x40df3d4f707a6989,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47954e00d82dc7b6,
+                    0xda938be63f646626,
+                    0x64595a9597c9dc76,
+                    0x6c6c9ed62f8ca833,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcde118683e3d12d0,
+                    0xc1085b037a3935ca,
+                    0x6478010ac60eec77,
+                    0x35ca3d085e4f01cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0ade2abc30af43c,
+                    0x149f9171ce973890,
+                    0x02ffc351a76ae533,
+                    0x41b55b1f9d51d012,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd1146eb2d750dd89,
+                    0x59edb81262b6dc2d,
+                    0x1de6ca004878246e,
+                    0x247d3c509b6be90a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6391710cd010998c,
+                    0xcbbb70064767594d,
+                    0x6d8d6c01c2da6ee0,
+                    0x21f40f3733dcaac1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ef1e03ab2c9db75,
+                    0x985d8ad1cbe754da,
+                    0x4fcb8f4c4c10f2ed,
+                    0x385e1105247eaa92,
+                ])),
+                Felt::new(BigInteger256([
+                    0x85202b2f33e9fbd9,
+                    0x54841232b9ec075e,
+                    0x48d99f074b51aa37,
+                    0x5914c5b7b7a371b7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe7f5f403bd69788c,
+                    0x36b43da90509c313,
+                    0x27a127d267ac2036,
+                    0x71022d08dac34267,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb28dcac785b1ceed,
+                    0x332e9ac97d5e559b,
+                    0xb697a27902d744d7,
+                    0x1033c525b2df10c9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x904ba745d75c0697,
+                    0xa1a93331a64eb89b,
+                    0x328875e3d39a345c,
+                    0x6c63b6e40a6408d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8fe383c01b933c80,
+                    0xaa5c889baf4ee1c8,
+                    0x3723de437865d158,
+                    0x015d2b9c7f15363d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8bb04f4a1a11db1f,
+                    0x19b6f293405e5308,
+                    0xd40ab033fa217c66,
+                    0x2aa48e5f8058f031,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3225538ec13ff47f,
+                    0x7d319476f1e7f294,
+                    0x538f89a758211a33,
+                    0x38a594102fee2bff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x452c44a8692a5a4d,
+                    0xc54185ea7121ea7b,
+                    0xe5f30bf28de0078d,
+                    0x3d38cfef4211051a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa488c8991a4b3124,
+                    0xb1bb159e5b3cdc22,
+                    0x4b512642439cd6d3,
+                    0x737b711889d92d54,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacdde3ededb21922,
+                    0xf99982eb75f14660,
+                    0xbd2fe126aa74a46d,
+                    0x1adfd909699ae1d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc27ba319f5835b7f,
+                    0xf8b6673044cad53e,
+                    0xf6ac07241dbc33cd,
+                    0x4c578ac09a464d4f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c71a50466621132,
+                    0xacce5777506feedc,
+                    0x325df80b5e9fcc57,
+                    0x423c25ecac692eb9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a6a9a53505f3274,
+                    0x5c42c1b115d403f2,
+                    0x695427f0486feff7,
+                    0x7979580d71d23f33,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5939822867bb60d5,
+                    0xa512af1158e449c6,
+                    0x7f495037d8dd4bc4,
+                    0x0d69d5e8bf5f6ebe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6d1af54717d2771b,
+                    0x6c34208cfdbee11c,
+                    0x324a7457aee37baa,
+                    0x765b1387ec35fd1d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71256b67d7666bbd,
+                    0x80c7d0e2ac9d55c5,
+                    0x04153ce3fb25be21,
+                    0x3a9d559fc95663e1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbb72e76d79cba1f9,
+                    0xb4a54c91a62b61d3,
+                    0x0d0b6f009c9892cb,
+                    0x302bb3497da6e348,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b1af7fca08be0d4,
+                    0x8bd36699e8b34707,
+                    0xd0355739797993e4,
+                    0x43da8e2af028fe51,
+                ])),
+                Felt::new(BigInteger256([
+                    0xebf13ca63fefea1f,
+                    0x3e61f8ba81127ed7,
+                    0xa310502198dac4cc,
+                    0x72e2b01eda2398d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03a451cc871bfbca,
+                    0x90f8a44be472cda1,
+                    0xad881ae2220ecdf0,
+                    0x095a0ca5f21324dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf1ce630d9e3986e,
+                    0x55e18d8d415facd6,
+                    0xcfe50972b07733d7,
+                    0x6c2279e0faa942e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf667c75da82a79e4,
+                    0x045b7b3132d76d2d,
+                    0x1d88e2fe8c1978de,
+                    0x5926f1a853fd9135,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4bb5394d0068141a,
+                    0x863f5fe473423fee,
+                    0xaab39253efd4eaf7,
+                    0x324c2d3ec506519e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x87844a0fbbd9c00d,
+                    0xfed84a07e988abe7,
+                    0x30fa27186238afe1,
+                    0x14eb5971037f25c3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5c4bc20250c29cc6,
+                    0xa858dd13690f8bd3,
+                    0x588fdd70274e0752,
+                    0x1f965ad5b69d1527,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf3e5b281966ef1e5,
+                    0x7331a14421c9f631,
+                    0x865613af74e9257b,
+                    0x0ef5c634cfdd490c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf98be8c833663b28,
+                    0x6b35e86eb9fa9959,
+                    0xc274363f1bc37e59,
+                    0x0edc4cbf7770a420,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e74636b0e09b46b,
+                    0xcc812211b233ec6a,
+                    0x854087917a5e7fff,
+                    0x1d569e22dd9a9f43,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7620946be516d7f5,
+                    0x2028df44353e174a,
+                    0x1835b928c996db01,
+                    0x0d3a91b5be30c112,
+                ])),
+                Felt::new(BigInteger256([
+                    0x60e79fb57e7af6ab,
+                    0x8bc985efb6651386,
+                    0x911243b1c6cf4242,
+                    0x3e823fdb27776dd8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x074484bc22129c06,
+                    0xc6882ff09eda2f45,
+                    0x0da2761e94e3a3fb,
+                    0x1a01906591e8a50c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5005e06f4a13cde0,
+                    0xceb9e6ed0edba4b5,
+                    0x333fa5be38d89b53,
+                    0x0c0e9ec7afd79cea,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc607bb4f37958b2a,
+                    0x082ba4b983ffe587,
+                    0x48785c6be36c3cfc,
+                    0x458d2f2ae39ec677,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5dfc2f30158d7ada,
+                    0x8fbc151f9a7c6cfe,
+                    0x9c0d5b1a73041190,
+                    0x5631a57a191033e7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc40f35984eb07e4e,
+                    0x1a0adbede800f490,
+                    0x74c88fbb71758723,
+                    0x240191c9a0c0f1bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0787c13bc397d8bb,
+                    0x4943c47dfb285be4,
+                    0xd4a448aa830c4c5e,
+                    0x282b6bd5d9cb55dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80346144a52abe54,
+                    0x8ceff07c60a2d086,
+                    0x0c2d8fdfcc68898d,
+                    0x4008ab9fc4b2823a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfe89d51b600d8630,
+                    0x54a939d6bc963bd0,
+                    0xe9b3d0aada5bf264,
+                    0x18610ea964500e67,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa208fd47988ce911,
+                    0xe2ed61e7d0f12cef,
+                    0xaa4e71192d075d35,
+                    0x1d7be60be43d0a8c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x39d2566b8fcbfc22,
+                    0x384317d3f7774465,
+                    0x90c648fc15bfcd80,
+                    0x1079d7078589e812,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacd36526a46268bb,
+                    0x96131075eece7809,
+                    0x0a640ec7cdea5bf5,
+                    0x472ca7b1d0f84dff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8100e7c37259915a,
+                    0x29544d0c0f855f8f,
+                    0x4cb6e06b24a3a4da,
+                    0x59a4ec22b91ad7bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a14fca858590918,
+                    0xa03bc8847279f6c2,
+                    0xbaf9fda4d79c8e54,
+                    0x0df8ae3406541614,
+                ])),
+                Felt::new(BigInteger256([
+                    0x44c7fed7b93c5065,
+                    0x3c5bc3c2cdbb7d98,
+                    0xe6f10262704c9096,
+                    0x2759c37fea9ef6c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc12f309d6602c159,
+                    0xd9b20a98ba6384dd,
+                    0xfe9a103e159e2182,
+                    0x271ab3ecce2444a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea07b219d0c7ebe9,
+                    0x5dc50ca3f73081ec,
+                    0x5abc547beb5bd8f5,
+                    0x575ef3d42e17521b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabfc0f467fce47be,
+                    0xcc2bb7f6e5bbbc38,
+                    0x321aeaea1c7a6b28,
+                    0x5450cf9fbc228c77,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6871461765a30b9a,
+                    0xd9142a06369b43f1,
+                    0x7339e18c621401c1,
+                    0x722338247ca2882c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2034324dd02705d,
+                    0xc55c2f128f2e6269,
+                    0xd20e52ed4406c79f,
+                    0x04aca2ac1d52abac,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1d1b434003728d34,
+                    0xd28552127bd86bec,
+                    0x3ef34b34350f4d07,
+                    0x444f3ddd46337720,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5eb117383054abd7,
+                    0xce5eb89804328549,
+                    0xc99d739772ba8264,
+                    0x737324664a3d4e7e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x78f6c2e60f39af4b,
+                    0x742abafbcd3336cc,
+                    0xa2ce1711e6bb5b2b,
+                    0x5988ff0f87cea619,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c14c8ffb35d260f,
+                    0xd56e11dd2af795f5,
+                    0x6486796fedb53cd1,
+                    0x46b8d4c8c795d2c2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x38a373746b3b1390,
+                    0x15c271a26e923eb2,
+                    0x7a684fbb08722d4b,
+                    0x04ebaf47dc26d00b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf8d1a51a3d732cd1,
+                    0x177646ba474b7afd,
+                    0xf0672c824673f985,
+                    0x5f7dc6329eda1f29,
+                ])),
+                Felt::new(BigInteger256([
+                    0x721a288f8d9ac063,
+                    0x5d2d0caf521d0ce3,
+                    0xfa2749666a784da2,
+                    0x0fd9e48b007b8296,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcdf5f0fb6beaa435,
+                    0xd4177059d250a798,
+                    0x86a9cea08b73cdb9,
+                    0x44e2826312600057,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5dbbbdb81d9b54d0,
+                    0xde987a38f013bf91,
+                    0x372e285d03159627,
+                    0x51b0ef2cd0ad3afb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa81bda2cc6f3e230,
+                    0xd3738977daee8b20,
+                    0xee4578bb714ff05b,
+                    0x7f36848409b0d9ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x20bfbb59779758c5,
+                    0xc250dbbcd96717d5,
+                    0x90726906f3dd3546,
+                    0x372c951813d1c09e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe7ea6afbd121c4eb,
+                    0xa9036852f5ab097d,
+                    0xd3e76750db72ebce,
+                    0x0416cbe4aff2f30d,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc124209da825215e,
+                    0xca517dd10b55a5d0,
+                    0x9b13e1620d51bb66,
+                    0x6f42e48846aab8cd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6efc00677910eba,
+                    0xa2851fe9e1d1bc5a,
+                    0x73d4ad3d81496702,
+                    0x7714eefe353e4958,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5fda31b61e074d60,
+                    0xebc13c32971a89f8,
+                    0x6e00829fbd19c081,
+                    0x37235be1387e834a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ccf67386276324c,
+                    0xbdb38d74afccef35,
+                    0x30ec4a362bca3ac7,
+                    0x10a0239d2d17fc5f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x85d179701510df83,
+                    0x860d9b0c6f9f8c1c,
+                    0xb3bdf685fb5589ba,
+                    0x6e6b3bd4f2f5cc38,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0477b0083ec4421,
+                    0x7083ea2964300cbb,
+                    0xbd438505ec5be67e,
+                    0x6f7930859aea2539,
+                ])),
+                Felt::new(BigInteger256([
+                    0x629a07e32abf4643,
+                    0x17eebaa5a698cb32,
+                    0x9373199013c0e84d,
+                    0x0e80f6e46d152cea,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf84b455996245406,
+                    0x8966a6f20689a0c8,
+                    0xb4d86a97cc99330b,
+                    0x58ed26d7f99eb9a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71506e88bacd9bb7,
+                    0x3ed4db45a37e8cc6,
+                    0xa4f3d6f64bd99a5a,
+                    0x5fdbaeab38cbc841,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ea28b1d30cf897a,
+                    0xefe421fd44974540,
+                    0xd9bffdd3f06991cf,
+                    0x3f806f8535a249ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3f34ceaca3529f3d,
+                    0x34137bcc1dc66ee2,
+                    0x41c809b54e1aee54,
+                    0x3fa13722948332c9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec939bb6bbd1d732,
+                    0x498d13d8ff2bb709,
+                    0x669049d881ab5361,
+                    0x7b55aaa3521c6071,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x81cd134f5e784908,
+                    0x2eadb1f0dbf1e553,
+                    0x6fb7c7843e5b7ad8,
+                    0x57c5a477762d0bfe,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa253b40d49d2aced,
+                    0x44679992480d2c75,
+                    0x3ea743e96d51d71f,
+                    0x5a821dccd1dd50a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc05ff6e62f325898,
+                    0x6fc3dafce0ff5ca8,
+                    0xe690cab53c1729d3,
+                    0x62340ab166074c39,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe40904640059e14a,
+                    0x808092513c16865c,
+                    0xffbfdc89f8e8c1ed,
+                    0x46f19e471db88c7a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x34a6f64eb35f7d9e,
+                    0x2cdbb1fbf1fe6023,
+                    0xbd97aaa0e7cafa60,
+                    0x2ae03dc88347d54b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x214e30d443415b9b,
+                    0x9d3a019917531a14,
+                    0xe2e1ddea29e3ee53,
+                    0x0dd33dc5bfb96b4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86035ebbcf7ad4c3,
+                    0xb2fe38c2cd54a5d7,
+                    0x81c1eaef2eddec56,
+                    0x42be05f7403fb2a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x07142b72b0984209,
+                    0xcdc3fb3b8af7b4c6,
+                    0xaad9b897f0700fb9,
+                    0x078a6ed922a301ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8fa0bd85401cc819,
+                    0x3fc70c3b181f4e14,
+                    0xca3c64c2cc6aa5d0,
+                    0x50e70cb7ab55d8b1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2ae5355d9d6cf61d,
+                    0x554b5527a4a4ac31,
+                    0x28c8706a613eda0e,
+                    0x011997c7cce24379,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa43cacc64d03840a,
+                    0x6a70d104a89baee4,
+                    0xb5aab8635b2c62fe,
+                    0x29b33aa7a115bdad,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7f2fb836c7ca1e9f,
+                    0xfd08b5f345df6a12,
+                    0x33d503b1d0dabfd9,
+                    0x3d59f5138b65958c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x33ade4a1c975ad5c,
+                    0x1a220310a419b910,
+                    0xf17df90e206d1208,
+                    0x3ea8799cb9960c1d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x173dd53765701e5f,
+                    0x50d64e11afd51a2e,
+                    0x3ba20ed13c699408,
+                    0x00415330ba2e58dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e7c4f73bd93444c,
+                    0xabac2c7573a11018,
+                    0xd575161c28183eee,
+                    0x4230a2b54bd64ae1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf19ce10ec61c195e,
+                    0xbc3897e4a2c47f5d,
+                    0x549f571c45a8cd07,
+                    0x21133ec2656bc1f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x428a8391e7fd99cd,
+                    0xcd4d28b186c95b32,
+                    0x3a30964cb8722d7a,
+                    0x0472a4d1c821cc12,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b6381e6eb2ea426,
+                    0xdccbcdf9f8e7bf8f,
+                    0x2522753724d0022f,
+                    0x4bfb2986445576a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x15632d1d2e122d47,
+                    0xbf84243474751ded,
+                    0x51fac61a7be90b16,
+                    0x6a5a070084dbcd84,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77a55874b98ee807,
+                    0x25cd18ede268fc6f,
+                    0x48b94d6c4e2a8967,
+                    0x7cac6f8c2db296db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3cd31840e7dd7589,
+                    0x950aef142738326d,
+                    0xcf636c2148b27ed2,
+                    0x30db2e349d2b2be2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7ebf31b21fc8d98f,
+                    0x88933ac9909eedb0,
+                    0x475ce341f4daacab,
+                    0x0c9c1b3f824e2250,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6a2985357d283e6c,
+                    0x7bc1a8ea170cf8b2,
+                    0x5ad81c652b887038,
+                    0x477d7f89afe0e98d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e4c6a59aedd5e39,
+                    0xb0c56c4aae53342d,
+                    0xe5886d10bec5ae94,
+                    0x1ee020b3edddd572,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x964dd5eaedde51d5,
+                    0x212effa824ec15c2,
+                    0xd2e1dc4817f83305,
+                    0x1b4e68e21500f203,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9ee137c9e1631b9,
+                    0x6c5a066b64df6aba,
+                    0x260ebae7dbf16510,
+                    0x3f27c96fd6a262b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x074931a1f1afc36f,
+                    0xfe0b6d5fa18c44b2,
+                    0xe80503a6f8ef39de,
+                    0x49ab06f7b7817fc4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacd779b1440a89db,
+                    0x1ec80814e039b8bc,
+                    0x836a554f1320bd9f,
+                    0x22beebcc88be6cf1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed28fd00fae36c2d,
+                    0x9a7192c75a5eb1a3,
+                    0x98a2e62ad71f5c09,
+                    0x2a1cda0335bd4b00,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9329873f9111d102,
+                    0x2083a5bd9f3ecd61,
+                    0x80a3cf94e5d3f908,
+                    0x6004c138f1d27dfd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xca8f98195d1b012a,
+                    0x7bf75dad9300737f,
+                    0xaa2d8f6e2776e040,
+                    0x16f10ac4f92001ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2ab59cfb39b2f5a3,
+                    0x3e9ceae3865d2049,
+                    0x81bd5fe2dc363ed5,
+                    0x58cd362578a761e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed2c295217a7e8d0,
+                    0xd02297e85055a4b5,
+                    0x4523360a0ea6293f,
+                    0x14e193c22a1c026a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9bc5123d1eb66f68,
+                    0xe5c56a0a3a6c5e35,
+                    0xfcc333dce5b88469,
+                    0x09cf6515a11c9b6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1afefb53f08b31b0,
+                    0x9702ef7ec47b019a,
+                    0x6aa76224580ed418,
+                    0x62de7d45a270e08c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5dbf73045c328d4a,
+                    0xf980bac767d7e84f,
+                    0x0e7ba7fffa53d781,
+                    0x61a78427c48458b6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf363b2c91ddf61f2,
+                    0xd6f4dff2866bb6d7,
+                    0xc7ba86f123fb6009,
+                    0x727d02bb62540375,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1038abf8f5239b9e,
+                    0x31e215c36db50721,
+                    0xc24fbafc28e7bf22,
+                    0x02e404eecc34de83,
+                ])),
+                Felt::new(BigInteger256([
+                    0x253a75aa7ec7e8b1,
+                    0xf9ffeab747cc34aa,
+                    0x9b9ed8627b1c2f5a,
+                    0x0888984d6e703921,
+                ])),
+                Felt::new(BigInteger256([
+                    0x746ed05cce4f0c92,
+                    0x2cf713d19e63e426,
+                    0x8368f6d421d301ec,
+                    0x0718d1e52432f2a6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x12e2c5634277b2e7,
+                    0x8ed585f2941593d3,
+                    0xce1aa51a7c3fe73c,
+                    0x107d305ca6766b32,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd9e5e757a523a060,
+                    0x5f63356bb263d298,
+                    0x698f5876875c6507,
+                    0x23776edc87ecd896,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d50f99fff070517,
+                    0xf58235b6e38c1cb5,
+                    0x87e8b947173436ab,
+                    0x24e7b66b938e8e98,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b99aed9d093b34b,
+                    0x34316724130ddc5d,
+                    0x59a253c58baa155e,
+                    0x6c004eea546e76eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a78b5e07f2b2fff,
+                    0xb0d885fbc22b6699,
+                    0xb2160778a7e26b5d,
+                    0x5528ba0eb32708bc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5d589893ce081e5d,
+                    0x198e34c5e6240f76,
+                    0x74cb904eab4620ff,
+                    0x4b9ef757afd2d1b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x781facd5f1b33878,
+                    0x5c011281b97ec9f4,
+                    0xe66dc5a1d7cb1d26,
+                    0x4622dece7de9ac8e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5874ea038061dd8c,
+                    0xd81b6ac5e60ca78a,
+                    0x1669351469fa9921,
+                    0x08541c174e18533a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x647af26d11661561,
+                    0x64d85e59b6148784,
+                    0xff2c665ae1ced3b7,
+                    0x78614ee6e5e80adb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b535a1b9282a40c,
+                    0xc688941def00737c,
+                    0xd4b784de998757a8,
+                    0x7ee402eba9b52a16,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4da6f5351f688c11,
+                    0x10ce6df7e712570f,
+                    0xfcb5644e30f822a8,
+                    0x35d94f9bb65b1039,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1cc027e4041dcb35,
+                    0xd7088d87e106a47c,
+                    0x9a428839c61fe454,
+                    0x0acbe3ea165a696c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7789cdc01ec4ce7c,
+                    0xe3fd4057314927a4,
+                    0x88e0625f9693a436,
+                    0x3c9eef5d72eb5caa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe47a47812423e4a,
+                    0xca48a21cde68ec0a,
+                    0xd5bdb489cc1cdc3b,
+                    0x06e1b76bf2a98043,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1df47260b4a28cd0,
+                    0x48efc5da672cd9e7,
+                    0xd740d03a65f53fe1,
+                    0x0aaa0febedb98c02,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc60740169d03665b,
+                    0xca3c91104b9160bc,
+                    0xcb3684260287f8c8,
+                    0x7197c78bbd6fa57b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xee2fbd3429947866,
+                    0x490176c519816346,
+                    0xce4dc15fdf37fccc,
+                    0x2aac9fe8ff791858,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ab238f3f821f0a9,
+                    0x22beac4eeb99ab1c,
+                    0x8ce304e7dd28989b,
+                    0x52023cef8d1e4c46,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe69d4e5306b94e58,
+                    0xe5762c25916c8bc7,
+                    0xd99a2766d26d6c29,
+                    0x7918a0dc1e8c3295,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacb65179d31913e7,
+                    0x73c23753fa801f60,
+                    0x62666d641b037110,
+                    0x5d3fc538460ed267,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf5ef5448dda6794e,
+                    0x459d39f56ecf531f,
+                    0x347d16d899737231,
+                    0x7ed97e0b8ef851f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf1bd21b14f5cc1e6,
+                    0x4d19559862aa0316,
+                    0x5128cc1bb250a8da,
+                    0x41cff89e0dfbcda7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcd0637fa37f52e21,
+                    0xdce997b215f2e44c,
+                    0x00e8673b1bb01d93,
+                    0x57ec97401848341e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x824437698863fa0c,
+                    0xbc2d010cf1bd420d,
+                    0xd4e9c16fdcbac735,
+                    0x0805ddc58f9a648e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2aaf4147d16e2ef,
+                    0x2aaf2e7f5d65bbb1,
+                    0xfac8b36e463aa796,
+                    0x402bf723dd315d8a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf75e47ef3b738da0,
+                    0x5abc8baee92731f2,
+                    0x3a4933734f8025f5,
+                    0x5943524bf5b65737,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe98d082fac815a1b,
+                    0xb6e6ba35beca3573,
+                    0x8343abd3dbc5895c,
+                    0x4d281419cae0c109,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec44abd5390fdf62,
+                    0x9a83f496a8fc11c2,
+                    0x05d5220ab4161930,
+                    0x6adc23caedc55f79,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeba039597251ecc0,
+                    0xb2487fed2347fd69,
+                    0x472e614184686557,
+                    0x751bf765485f611a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1246486950aac694,
+                    0x4bc85b245cca6cbf,
+                    0x3ec3367ecfbd22cd,
+                    0x721bc34427b80207,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed89bdc66dfb184c,
+                    0x5d52df9359de9d9e,
+                    0xd0b5141e7372a4ac,
+                    0x47e8d4d79ddf9233,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ebf8eadf6cbe2b6,
+                    0xfebea90ba9f4690e,
+                    0x19bdd28ffeefa83e,
+                    0x22d33efec727634e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9014e4dec11eeb6f,
+                    0xe33cc1e85071ea23,
+                    0x7f252fc615f8f811,
+                    0x52f1a8f9ab7d169f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x989b945414167b93,
+                    0x7016ac1f6656a896,
+                    0x319a47760a2c44f5,
+                    0x3581c6cbf246b8ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3d356d727b85b0da,
+                    0x3ba40af56e3d5bbf,
+                    0x7cda290d92bff4c7,
+                    0x769f9dc3442b193e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x009880df95b8d035,
+                    0xcc90be599d03832e,
+                    0x0ec6397a1f4e3707,
+                    0x126d72b5a2c74960,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc750dc713eea26de,
+                    0x480a76d4797c0071,
+                    0x79057692cc03940a,
+                    0x4605c86a4f451bbd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x914130f1fee0ae2d,
+                    0x861394143cb5172c,
+                    0xef14d3439da53fb3,
+                    0x69177c208f21de5f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcc9e375586b49bd0,
+                    0x09f399195d978e0e,
+                    0xb2f4653979db0382,
+                    0x4dd99735e741a440,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe589203128f1e5c0,
+                    0xdd1029de14a206a0,
+                    0xa8086ec0ae754a40,
+                    0x64e8f3a124d983c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e75d5154b7700ed,
+                    0xd6f47a35a1bf95e1,
+                    0x900185f7b78f76b5,
+                    0x3f283c7f782e2466,
+                ])),
+                Felt::new(BigInteger256([
+                    0x51da3625bf3eb232,
+                    0x14b52a73b20ae452,
+                    0x6bc973a497ccfe87,
+                    0x159f02541ce2c2da,
+                ])),
+                Felt::new(BigInteger256([
+                    0x93e30bca67db93b0,
+                    0x46068ce35683956a,
+                    0x8d84c36224335e38,
+                    0x5ed2ac8118ace56e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1efc86f75686d1cd,
+                    0x5a4cdfbb12756b23,
+                    0x294ea968508b4d26,
+                    0x075823d30780ba32,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x29834f7348a5f306,
+                    0xfc3c503a01e66058,
+                    0xee99c5c6cf26731f,
+                    0x0972b56e654c7b52,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7a86fa34c9364e2e,
+                    0x3517e07f26d05bdb,
+                    0x039e477e7b6a463b,
+                    0x72aa8e65f45a96bc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ac2b192c47dd4c0,
+                    0x8b103d8682ead642,
+                    0x0a9b8650383429e2,
+                    0x06865dfd236018e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe403cc447e8f9768,
+                    0xa0d2bd855f52eb13,
+                    0xb801ef1f4c9fe4ae,
+                    0x57a22897cbe82c10,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6d774266b49dde42,
+                    0xc14b560437bb8a49,
+                    0xaa3925cd8365c5ae,
+                    0x4efd9ff1756689e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdcfbf28c5567e55f,
+                    0xb4701bce4f63c3fe,
+                    0x65d841f8be809cd5,
+                    0x1325bfd1e2fe52d4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3710d0d9b5157aad,
+                    0xbf1d38eeec46577e,
+                    0xb927a00210f24538,
+                    0x268e1228683f3324,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9fcf281494eeb388,
+                    0xfddaf9b0aa1e94dd,
+                    0xfe6f4a64245fc816,
+                    0x7adf1a8617486452,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea1ee041eef51081,
+                    0x69e3249b38d8de86,
+                    0xf298046a3f26271b,
+                    0x023b96b7c6051c94,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b99252eea41b80e,
+                    0x0f28efb0988517a0,
+                    0xca8be25fa3283146,
+                    0x337b9c7895cb5da5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0a59afed60b2725,
+                    0x29708b49d40bc61d,
+                    0xf1ff969f19d1ff6e,
+                    0x68b873281e5d4655,
+                ])),
+                Felt::new(BigInteger256([
+                    0x50d315f940911f89,
+                    0x3975c5c0770b8a14,
+                    0x72f5363ae641cb08,
+                    0x3b157326920d0b8b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8beea6aa4f64b67e,
+                    0x761e86d6faa81081,
+                    0x8f1cc80bda472094,
+                    0x35531786ae6f3131,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe21e66e7aaf7ae0,
+                    0x27647a071363b0d5,
+                    0x44809b6d95e6261c,
+                    0x0ca29c7c22898c44,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9a027a4ec112b01,
+                    0xcf9ac4236e339a37,
+                    0x53d03fc8d2849432,
+                    0x46e8a3a23bc5d23d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fb82b970e9acd1a,
+                    0x6b58d4d588fcae63,
+                    0xc72b6a7fbe2fc19c,
+                    0x149541cf1224828d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8e8a43a418781af3,
+                    0x7529504c2cbc1751,
+                    0x33c430bde750e561,
+                    0x28b56a2a40351d46,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa2231196c913baf5,
+                    0xd79a19ffaf941154,
+                    0x1f03420f99b2d660,
+                    0x0f70f103e0b9aaf9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03aad220ade12e2b,
+                    0x35c039f8555853de,
+                    0xf569d8e03d00bfbb,
+                    0x2dab2a09eb4fec1b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe6acff7fa396573f,
+                    0x276c92880d4a6175,
+                    0xf2a7477485a696bd,
+                    0x285d5915a8a84291,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6290cbc48bd8f880,
+                    0x68508c626688fe0e,
+                    0x5496c2a7a60f2be7,
+                    0x17dffbb31d7e0f9c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c82745aa3814f8a,
+                    0x874b5b9fb355638e,
+                    0x45cb03de6a39c402,
+                    0x666f602f1033f11e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe2b4a3a600d1c689,
+                    0xd8c663fa47019f34,
+                    0x2f0fe4dea92c4a19,
+                    0x2793a68be6d10cc9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76f568c93086801f,
+                    0xb6868ba15bf2d224,
+                    0x73843bec5211fc7b,
+                    0x3809d9b47d6f6ec6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe23bc37570c9e460,
+                    0x4c53276617015f13,
+                    0xbe12e9e2535b7a9f,
+                    0x40e15cf8d79bb1c6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe7ed4c5f0dea645b,
+                    0x1a3fabc16e40b2f9,
+                    0x8886b2bf772e55fc,
+                    0x6800e9d213f42817,
+                ])),
+                Felt::new(BigInteger256([
+                    0x584594e9ba2590cb,
+                    0xe728057092b5cdce,
+                    0x0c8b3e54cedd591a,
+                    0x6b202ea9de2b0583,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4bfc9b73e76e1b21,
+                    0x6fbc0bfa7264d179,
+                    0xef89d7eb18a54bd8,
+                    0x44d83f8806921f65,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c7468dca814fb31,
+                    0x98677797aab8f2a1,
+                    0xdafdf7ab277d9b92,
+                    0x2010e0db6c253a16,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd622b2adc09042a0,
+                    0x40a76d5910f03446,
+                    0xde3c7c5ad2174261,
+                    0x1fcf23b5f78643cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb5307a7e90e8da4d,
+                    0x15ea3f95aa25c6f3,
+                    0xe3bb2fd335fb7f9e,
+                    0x505b24ff4e95c0b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb04e7afb27b69107,
+                    0xf8d98b021b5f815e,
+                    0xee62940d00ae3b4b,
+                    0x4f49987d6e063afe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47854439f0a92b75,
+                    0x8b7d9d93c2145c73,
+                    0xdf2d5aee0fdb9666,
+                    0x2c707959e5b01597,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe29db20b967a3ae,
+                    0xa0439a63ecd4e12f,
+                    0x50e32127203b748b,
+                    0x6df1d822916cf866,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3735740db764cca8,
+                    0xeca6f581ad152823,
+                    0x8c24290833544ea8,
+                    0x556c0112b0cc54d1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99b5de2f0ad5d5da,
+                    0xa06e6751998b8e4b,
+                    0x13f4e9a86c1897d9,
+                    0x7066cedcf6fcb0c6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfeb60fc4d9ec9238,
+                    0xa38e0340f8f6c1a7,
+                    0xf21b6a287f551ec2,
+                    0x640db04adc84a0c6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe25bd8b93b6c74a5,
+                    0x6833144bfebde610,
+                    0x858c7503221cc6cd,
+                    0x0a8e990912580b1e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea1849440746f3ad,
+                    0x128996833640dccb,
+                    0x0aac5743f75dff49,
+                    0x08482f1463deacc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0e5b7c4d50d2b89,
+                    0x211d4b9372cc2c1a,
+                    0x10b11f4728db782e,
+                    0x7c552f48a20d18be,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14a619e15af06153,
+                    0xa932a7042aa5e1cc,
+                    0x512b76c0ac0239f1,
+                    0x6d2cbe0c421d00e5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd334d22ff03af5a9,
+                    0x0381556959cd9fc5,
+                    0x102c20ce5609ad52,
+                    0x0eebf2be93a526b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a9c1007fb969ee8,
+                    0xd56869622f26ffb4,
+                    0xbb057d125b1de82c,
+                    0x638b8ae321388bb4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8ed85b3e8faea28,
+                    0xed1008264f592989,
+                    0x20acfbf2a2570f87,
+                    0x012395bb9d4031c3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4b6307857c5070b1,
+                    0xdbbd57e01e1c4ae2,
+                    0x69f782ac2834c947,
+                    0x5991ee5a2322f848,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e7b4328c21d739d,
+                    0x8f8bf16ae88b393e,
+                    0x413f7dd7117f0313,
+                    0x24b68156315e8f9a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaff3424b1e75f222,
+                    0x704067f287387463,
+                    0x9f92b9b47ac1ced8,
+                    0x63e6d933653d6b2f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1bb0c144e2e1db8b,
+                    0x49cd49ddc24c08bf,
+                    0xa9c28a742fcd3369,
+                    0x5599ff65cded25fb,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002f8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa15c6f90cc9a6ef0,
+                    0x0ffe896e242ee17d,
+                    0x1fa8d4404de5ba4d,
+                    0x40f674fb38732d37,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa8af319b700206bf,
+                    0x70a4c099ea0b88d1,
+                    0x1a4dcfb3a351255a,
+                    0x69e7957f703a4583,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbcdfdaf255d8f25b,
+                    0xaec076563e86d364,
+                    0x9cb74ba43640a375,
+                    0x0aa2ed8f351079ef,
+                ])),
+                Felt::new(BigInteger256([
+                    0x799e4cc30afe4a4e,
+                    0xbc7ef49536d94957,
+                    0xebc0d28798f7a72b,
+                    0x27fd2a92616c4c98,
+                ])),
+                Felt::new(BigInteger256([
+                    0x618423ffd912a7bc,
+                    0x4446bb3835d0f531,
+                    0x1930e8a0e22906ae,
+                    0x04fdccec8ec74137,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1f4aebf7f8239a5,
+                    0xa8484172c0d4cff8,
+                    0x6f27475063abda96,
+                    0x266436e50cef0aa9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x87eab00832520c0f,
+                    0xe892aebb14a119e3,
+                    0xe06c9b0996cffb08,
+                    0x52ff6befc9d48637,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1adf25bb9d0386c7,
+                    0x429d7c8ee55b5d13,
+                    0x125945bf2d9c5d19,
+                    0x4d30706def57a8fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf55b863b2049d9e2,
+                    0xb338e531d04b64a1,
+                    0x3200635b4df5c3aa,
+                    0x2c1324d59da7e646,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71c9e58f6e24cf30,
+                    0x2cf22c16c1486da0,
+                    0x680d9f954a84194a,
+                    0x73d747e2d4eba831,
+                ])),
+                Felt::new(BigInteger256([
+                    0x58355c783efd01ad,
+                    0xf92e45e3dbfe9ae7,
+                    0xe948f2bacbfa9c98,
+                    0x6eff6970f6ab3e86,
+                ])),
+                Felt::new(BigInteger256([
+                    0xddf98eaa6de0e4b1,
+                    0x627bef4c01db7cdf,
+                    0x07ad1f9696e486a1,
+                    0x1846cd53f0ee7ac6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2ece38ead28092ac,
+                    0xc52c52ee41393ff4,
+                    0x59ff1cc1038f59e4,
+                    0x0ab1e6bcdc87017a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2ba1481ccd8c24d0,
+                    0x23653d4aa3c3805b,
+                    0x65243633c2f53dea,
+                    0x7aa040d62c73fc9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3cd339c007a12dcb,
+                    0x095a506362cac32e,
+                    0x585baef137945cd6,
+                    0x5f3f4fe21862f47d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x264af9f51f533f48,
+                    0xca129769a387e320,
+                    0x36bf448fbf827fea,
+                    0x32c6e7f7a1ca8d8f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0dd35c9bd72dbe2,
+                    0x2dc7ae0416295633,
+                    0x74db1d216dd9e1dc,
+                    0x3da81fb9cf7c5725,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ede379c5072cab8,
+                    0x573706eb6319fca1,
+                    0x1671647581bf6527,
+                    0x3752e0952ba2994f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffbdb5b72a710c7c,
+                    0x09744c3bb4690b2b,
+                    0xb1bd0b912c13cc39,
+                    0x580b2783e77102f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5839204a339623b1,
+                    0x8713c2f2f0ecd50f,
+                    0xb88c7c07762a938e,
+                    0x71557d775e7bcc4c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x879c53a1d704ad95,
+                    0xec7c6985e7f497ce,
+                    0xe93f15b74ab58dc1,
+                    0x355ea448726cd534,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc24927d3eb130265,
+                    0xe174412529470c92,
+                    0xc692ab2bc0c0279f,
+                    0x20dea0f06f8fe2a4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66a2c8b0b14c1469,
+                    0x62f400dbb63d5ab5,
+                    0x806e494684393837,
+                    0x25989e956282aa42,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe24c141d9d791ae2,
+                    0x3aa3da3a3ef65584,
+                    0x7596fdd046775200,
+                    0x4ce57f1d909578fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x259d8abde118d51d,
+                    0x56e9344d21c336b3,
+                    0xa841a58b2dad5329,
+                    0x6c0f9ecd905eaf77,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb131b3a13ac58f9f,
+                    0x17681b0a3efb328a,
+                    0x73f81ee73a7ef7d1,
+                    0x547ae5a01fcfdd88,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd9d679b9d90a3134,
+                    0x6d42406eb961790b,
+                    0x17607893f945b7f7,
+                    0x76f5473c90131f03,
+                ])),
+                Felt::new(BigInteger256([
+                    0x33af3095deff75b7,
+                    0xbc387b7f60661393,
+                    0x9fe77d4c026da7d5,
+                    0x6a56d9a5198122ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbefd4d98d6a38a33,
+                    0xbb11893df29b9082,
+                    0x6f6d116181c70255,
+                    0x77928326d2062e6a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa5fd82a7c0294141,
+                    0x2816bb53fc6a660f,
+                    0x1f1b85e97438a3b3,
+                    0x1ece23066ecb0da3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfdd436d71c2c072d,
+                    0xd4f74ce0d9959218,
+                    0xae611f9c993d173a,
+                    0x492efffb63e11fc6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6d24330e64a05784,
+                    0x89e8c3f9c3c6a37b,
+                    0xf35961f5f7c58d72,
+                    0x7e375331940ad513,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf6c04f72d8be0dc8,
+                    0x52096c0e2a7af971,
+                    0xa1c62a29e44c79c7,
+                    0x5b6dbea323d5344d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e19043ab1eb2846,
+                    0x040f37c567bf9089,
+                    0x06683ac1d319a5aa,
+                    0x4304234aa7273d67,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbdac3bfc07d27ab4,
+                    0xde20705c37f1351d,
+                    0x9283baab2ba0f7e6,
+                    0x50ebf10bfb62583d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9bcfb9417188c18a,
+                    0x5f3aba2791840182,
+                    0x254bb7a0660a0712,
+                    0x642955682ce508c0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0b576e20f981cfa5,
+                    0x1121ce1979be2621,
+                    0xa8a44ce5ff3f8ae7,
+                    0x11355f10523fd29a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x235adf58be306717,
+                    0x0868a8e41d526d2f,
+                    0xb2a0d8425e668809,
+                    0x4147f0700fccd94a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5df80c6dd577a6ca,
+                    0x33cb703995534b6e,
+                    0x98e8d18d743ae706,
+                    0x69d10889c8d59813,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf4d0acaeadb55bbc,
+                    0xe2618974a53d4d36,
+                    0xe1d10974b84fe42f,
+                    0x298ac159c196104a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab72aeb6ae6e8ae2,
+                    0xdecbb89f76c536ef,
+                    0x6035f691f9272498,
+                    0x68b4d0b84e1ab76a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe4ce79ec14a231aa,
+                    0xda05296e568d9112,
+                    0xe5551479a292e26b,
+                    0x61793290c1d20656,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2263af9e2321eeb6,
+                    0x5bdad7ab75aa18ed,
+                    0xd6eeea111772a3e1,
+                    0x3c0e4c4789130b35,
+                ])),
+                Felt::new(BigInteger256([
+                    0xad6ae5d72ed58ef7,
+                    0x4d55d380592673e6,
+                    0x709f4defa701073d,
+                    0x2bb99f0b64640e27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x73885ef86670d6c9,
+                    0x91a871560d3aa44c,
+                    0x5ddbf5377e3afb9a,
+                    0x45dfdf9f18d0a52e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb74135c65999e679,
+                    0x0396cb4f0cd43e68,
+                    0x9722adba84815450,
+                    0x68108e4ebd7f3eee,
+                ])),
+                Felt::new(BigInteger256([
+                    0x87b284242effcb87,
+                    0xbcedd0b79e02efa2,
+                    0x0a956658c26dc0b3,
+                    0x2befc5fe929a6358,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b5bad4daa478f11,
+                    0x365319d1ee4c469b,
+                    0x9d6a57a8980547a0,
+                    0x4d8351eb9ff3fa90,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe60278c316a7dfed,
+                    0x58ecf96fc234b82c,
+                    0xa6c377c54bb6a372,
+                    0x5cd30b758a392138,
+                ])),
+                Felt::new(BigInteger256([
+                    0x520e693c863adb45,
+                    0xbf71c2a1e1c1ff92,
+                    0x9b4740963c8b2c8c,
+                    0x46413ae499aff1cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ce4d130238378bf,
+                    0x3dd5f91d51915585,
+                    0xef87b0951237ea35,
+                    0x43d3eb64b5094bcb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1d0c258536d0cb1f,
+                    0xe994e4a0437a2ef6,
+                    0x536ddfea30ee5d99,
+                    0x52a1a87e507fd583,
+                ])),
+                Felt::new(BigInteger256([
+                    0xca4724af01fcf3fe,
+                    0x49376d3a4599fdea,
+                    0xf18143d56e932a5b,
+                    0x573a2945b8930b87,
+                ])),
+                Felt::new(BigInteger256([
+                    0x57e62d56b95402c6,
+                    0x3972b737f9d8aaa7,
+                    0x37b57d692e9f440a,
+                    0x5dd27b992d647d6c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ed756b0bdcc2640,
+                    0xbd634fe254e3931e,
+                    0xaaf5a5ce9eed5eed,
+                    0x2f6486d675a0852b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbdf03a9bbe6a3725,
+                    0xb61c35bc1350da35,
+                    0x2d48053d8c753831,
+                    0x49c2dc158286f50d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb5ead3f93f7935ca,
+                    0xe7102cb2247b8191,
+                    0x8acfcc4ad254381c,
+                    0x6d11ac57e9c60ae7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c4c258b1f8b25c6,
+                    0x2d43f9d709f32623,
+                    0x460a7fef54c6fdd3,
+                    0x65e8107430a1720f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7ff8da7cc0ea105d,
+                    0x7c17833a4cc8a1ea,
+                    0x1cd5dd4fb8b041a5,
+                    0x06a1e1d922504fc0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe304a80946347fd9,
+                    0x94244454b9c500e0,
+                    0xe7a4a062706368b2,
+                    0x2a42689ff149ead0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7d6d11e3e7763948,
+                    0x2ad84cf155b71626,
+                    0xd15378ad8b3ef5c8,
+                    0x7430fa68631d01bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1a0a2a5dfa4eaaa7,
+                    0x69b9e82ecec7772a,
+                    0x047b2619ee453191,
+                    0x5f772da272278bc9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5d78fa9c543ab161,
+                    0xe03cb6d5657a5a0f,
+                    0x2b642ee3c5bd1d31,
+                    0x3b6f6b28d77ec989,
+                ])),
+                Felt::new(BigInteger256([
+                    0x125f7a7fbd28b470,
+                    0xdb44ec66cdf09ef4,
+                    0x3696f5bc4b1312d9,
+                    0x0cf69e63c41c54ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5483b35e954a87a,
+                    0x5b8a0d652a5cb768,
+                    0xe7601a13d2792c43,
+                    0x65e42ce974582a9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc1c0c350e46cd6f4,
+                    0xc691539565e1ae1d,
+                    0xf818abf7ed83d231,
+                    0x307c8b2aed9c1cec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69ab8668796c8243,
+                    0xa9e7bdb32a5f1baa,
+                    0x7f9b81a8fbc58f87,
+                    0x59bbab35b66989bf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb31889028529c103,
+                    0x9ca9e86a6d5afc07,
+                    0x9c22b07cfecdec99,
+                    0x78f431b7118a467a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xace6d306ee5cabac,
+                    0xfa96147fb4ab57ae,
+                    0xe95dc6f7a5101231,
+                    0x36b7070e6a586611,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbf010307924a3cd3,
+                    0x96816e721e2ef046,
+                    0x966c2b917746f9c4,
+                    0x04d27ff8e3bb325d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76bd5e0d0c01eb4a,
+                    0xc49951f90dfa8926,
+                    0x725ad57d4ac957c1,
+                    0x31d1a2c7a9bb793c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x75848c044b918a54,
+                    0x7ff201c3ace4694a,
+                    0x3703d7a540a79e8c,
+                    0x795d2b69bb1de08e,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 2]
+/// [2 5]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; (NUM_COLUMNS + 1) * (NUM_COLUMNS + 1)] = [
+    Felt::new(BigInteger256([
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x000000000000004c,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x000000000000004c,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000000000000be,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+];
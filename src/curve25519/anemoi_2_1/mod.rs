@@ -0,0 +1,599 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 64 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x2ff1ba22e22f0abe,
+                    0x19c6acda321f2ceb,
+                    0x53aeb69ac0731c2f,
+                    0x5e9def9fd555a592,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8b6238daa18ddd51,
+                    0xf281ae98fd500403,
+                    0x56be47130a69e542,
+                    0x53f0ebdc8babb4f8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2cf90cc4f0b348e9,
+                    0xad32ea1aeecd254e,
+                    0xfacf0eec6e60aaa4,
+                    0x7c088669f43ce7a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9041901fb3b72714,
+                    0xa8895f4e4d2e0a08,
+                    0xf34003243ab4e377,
+                    0x77dcbe93c95c08db,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb507a1855317acbf,
+                    0x531055691a526f7a,
+                    0x3eb490d867322a00,
+                    0x4941e5f20388280c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b6935c885f1b073,
+                    0xd9b5eb3bb52ea4ee,
+                    0x50facd758292aa7a,
+                    0x2fc493abbecde4b3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x09d97675c2835431,
+                    0xbc72530b7e16f5fa,
+                    0x35615c931097e7a8,
+                    0x00f2fa6d84aa5e34,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec46d0bc70bc4114,
+                    0x2b0799634db2bc41,
+                    0x30be800e0908287a,
+                    0x4ae647c840c50b13,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4b7e2d7f43b6bef6,
+                    0x96527d207966850d,
+                    0x51fe6e7220336312,
+                    0x04de5c471583117e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2827126b069ca949,
+                    0x3f8597db6a165306,
+                    0x0635025d4146e80e,
+                    0x2531135463e91f26,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x451e1d4ce32c52e6,
+                    0x0221a4d49819bc88,
+                    0x69b014d4e9666159,
+                    0x75b81842769d2232,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6eaa2a30d1d704e9,
+                    0xebf2a86dfd8757ce,
+                    0x5003dbc37fad90b3,
+                    0x6e90e70f7a20f7fe,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6784ffbf0d8d65f7,
+                    0x18b362fe0b738668,
+                    0x31f2c07d47be900c,
+                    0x4543722f5eed9e17,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1035dd00f04eb479,
+                    0xcd80ecf1fe8fa2e1,
+                    0x8fcc5f2fcfdbe5d3,
+                    0x73f240754d9b7429,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf0e0a6d7fb35e519,
+                    0x5b4ea4ed17362617,
+                    0xf74e248172a1f749,
+                    0x79f99f9bed64006c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x617922c8543e696a,
+                    0xddf57da8fa895828,
+                    0xdbcb7919c851c857,
+                    0x30afe7bcc6a3517c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x91f6ebb5f773cab0,
+                    0x94f74b6fb8a1f595,
+                    0xa130dbe8ac605f9a,
+                    0x0380d2b7020c9cce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x79e67ad1739e3c22,
+                    0x3d82744e23e7c94b,
+                    0x6cf5d8555e2edddf,
+                    0x7e8ea4790f59f5ca,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb2dfedae54c8e0ff,
+                    0xd6087999ad704377,
+                    0x8f077e22e5d5d27f,
+                    0x4390a0b2513862ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b82c7780a5c669a,
+                    0x3f79c7fff504bf11,
+                    0x7a1be183018b4076,
+                    0x17dd23abf35492ee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x39447fbad1c7e2a3,
+                    0x797362fee0e91f4d,
+                    0x2d1accd2473ef8ed,
+                    0x603a44f9e7d14961,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e654314daee0daa,
+                    0xd92151d0044ef745,
+                    0x7df8022540ab68ce,
+                    0x35fb1efd9377e460,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x951e7485b87b4da4,
+                    0x0290a5d55603dab0,
+                    0x91c43bd50de2f509,
+                    0x39e8cb804e2cf712,
+                ])),
+                Felt::new(BigInteger256([
+                    0x21f00975bc040de0,
+                    0x2464bb5825eafbe7,
+                    0xfac1b7030ff05751,
+                    0x7c28e4797546e19c,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x4c6f18855179255f,
+                    0xdfbab98022e9afb7,
+                    0x81bfe4703a728767,
+                    0x436e23a069d96900,
+                ])),
+                Felt::new(BigInteger256([
+                    0x15b32a40755bb98b,
+                    0x5a93bdbd8f6255a9,
+                    0xe4d6f7ed64d6a000,
+                    0x4c00804b2527e988,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x48f90796129a3ec9,
+                    0xd40983ebd8797028,
+                    0x18e5b37896ce2eea,
+                    0x119895c689fb8e37,
+                ])),
+                Felt::new(BigInteger256([
+                    0x497586d2915f16fa,
+                    0x8176afac70344480,
+                    0xa080c8fa4bc32924,
+                    0x27f1f81a376d99cc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb710f672f1930d8c,
+                    0x0369a77cdea3d3b1,
+                    0xb23d1a7eb09844b2,
+                    0x2f881219535043fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x381eaecb69a1dac6,
+                    0xeb3fcb4dd1cde4cc,
+                    0x6d2294d2425195aa,
+                    0x1be3ffab16013a80,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe1e0f05defca82da,
+                    0x76cf6ebd86056b96,
+                    0xe2cd639fc8642c88,
+                    0x71d28f424aae8654,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0487af039de97fcf,
+                    0x5885f7364ff1c122,
+                    0x2301ee82e9c29e4a,
+                    0x40391b9fa4707c37,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x63325a853f8c49ad,
+                    0x95d923177bd3bccf,
+                    0x678e9d0f941bb350,
+                    0x15a90002a6645773,
+                ])),
+                Felt::new(BigInteger256([
+                    0x50419fd28f1ed712,
+                    0x841dc8291a1bc674,
+                    0xba36d5452f016df6,
+                    0x4ed4fa01f2d7d1c6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x87090d0b77a636ba,
+                    0x16d0ff6454a195f5,
+                    0xbe046a5ef9c538da,
+                    0x1e8854ff055bc9b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabe70403f6119f16,
+                    0x81bee0dbcb113538,
+                    0x9777175f64730630,
+                    0x35b40933472d7972,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000072,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000010a,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000000be,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x77d56d063c309888,
+                    0x94e234fb41ae5b09,
+                    0x4b6dd44b041fc768,
+                    0x5b6f2436b4293c12,
+                ])),
+                Felt::new(BigInteger256([
+                    0x055e044cedbceac1,
+                    0x845827b412bf0bbc,
+                    0x7bb2a0836d162ed1,
+                    0x02dec8b88d7a61ad,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xdbe4153b35586cbd,
+                    0xd6f6e344b8e1f928,
+                    0x59e7456d2e548133,
+                    0x617c85faf8d6c1d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x013db148fc0ff087,
+                    0x2f647635e1f836d2,
+                    0x544f53d4a86c2b8c,
+                    0x6aeb0410291b1d6d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x274e5409c4d6c318,
+                    0xd9e93e18823f9d4a,
+                    0x8c824423353b7007,
+                    0x6750116f7f52b8fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86bb56def34f6109,
+                    0x9f12477ed64d1f60,
+                    0x86271d18acc875ba,
+                    0x6a84228a14a6ac79,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xeaf04e652b9d828b,
+                    0x27db5d2a25e8edda,
+                    0x28d140a59be9691d,
+                    0x7244c681938f7ec3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xda684bcdf524850b,
+                    0xa83cb18a9bc39cd7,
+                    0x74a46fce21957084,
+                    0x24c2a8a2cb8f79bd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x03b59a2a5dc9f7e4,
+                    0x9e14b369b00b49b8,
+                    0xdbfc4799f21e8f3d,
+                    0x3352f4068c13fb00,
+                ])),
+                Felt::new(BigInteger256([
+                    0x57acd4274ab2c6ed,
+                    0xc0472efc7a3259e4,
+                    0x722f6479133e8c71,
+                    0x357ae20f0affc7c8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xded7151363c974f9,
+                    0x1a4ec11beac40066,
+                    0xecf2991dc2ab453b,
+                    0x09f0676593b6bc9a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69952e2abda48908,
+                    0xb65c6313a0993606,
+                    0x715c499ae9c990a6,
+                    0x4994d7fe6e9af2a8,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0x000000000000050c,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc91c450305aea9f3,
+        0x8ba82b557a153133,
+        0xa926b4eebe535fcf,
+        0x74de03beeca7e7ec,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xeabcd3f932bf75ca,
+        0x5462a57a75221fd8,
+        0xe0401ffc8b818ad4,
+        0x368aff19f3cd3056,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x2b76347555535d5c,
+        0xa0bd73410e617c7a,
+        0x0202bafd84b253c1,
+        0x74cd3813ab4c743c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc3122796a7ac5a2f,
+        0x5c48a2544850e7f2,
+        0x7bf385e3cc945556,
+        0x4e77b9d15e137fb8,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x130812fee00d6c64,
+        0x818d215545de14fe,
+        0x9aba4b68bbb4297d,
+        0x2838baa79d11aa4f,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x3bec9e832fec92ce,
+        0x50900c9fc2d58b32,
+        0x3752456293ce4b5d,
+        0x293526c620b2472a,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x6fb39b73b190ae3b,
+        0x2a9ab3b87e9de3d6,
+        0x41f12658bc6cb7c5,
+        0x0046a5a911cf0aa9,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x40857658b93ccfb3,
+        0xf4eed7d07dc3101b,
+        0x1f9f8eb6be1cdcbb,
+        0x36709391986752b4,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xed0c810f4cdd09dd,
+        0x81335422994f28a5,
+        0x523bf0db426df5d3,
+        0x0e8701170f63e607,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbee2f06fe5a835da,
+        0xd59e0b3e611e23cb,
+        0x1f462f1f0fd853f6,
+        0x60cfbdb78334774e,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x601aef5941adb0f5,
+        0x6bdc76941b848260,
+        0xbfc87b068c64e211,
+        0x01c6ad81837fd91c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x45bda1f26ab1ac73,
+        0x481cdaa312fe4b83,
+        0x0f0766c044c4b377,
+        0x5b7cbb28698642f1,
+    ]))],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0x000000000000051f,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xdad044d3398a68e4,
+        0xfddd58bd90825079,
+        0xe99fac6bc4d4725c,
+        0x3f5abc480e3a3978,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x761339f1b53f5441,
+        0xa326cbc4a17b3058,
+        0x271cdc55c67a62b2,
+        0x0b968981a546aafc,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x8a7a6dbc75f00c15,
+        0xa35619fb4ace1501,
+        0x36e94c74db4df95e,
+        0x66d3fb32e44108b7,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xb240901d22e2ff27,
+        0x57d670549bb27755,
+        0xe0d8282da5533333,
+        0x0f84015a3d75cdf9,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xfaeb5cedab1559ab,
+        0xe8d09e95da25b0ba,
+        0x3c0b87b522b2cb32,
+        0x10a7a00525709e0e,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x9ec3d0a1aa55a6e5,
+        0xf1a7627380a59630,
+        0x9c1d38f15342b7f6,
+        0x0f034e2ee3ab0bbf,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x6d099d2558b0deec,
+        0xe623d1c3461c6020,
+        0xc7e86dfb791d36a4,
+        0x1d09faa00e9ee2aa,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x89654864f8a8a586,
+        0xe53d21be08b58181,
+        0x327967e3fc107ab4,
+        0x0937f06f0607ed22,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xddd8564edff31111,
+        0xcb191857b60ed7c0,
+        0x2223318d2b3856c7,
+        0x210df35ae6653bfc,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x011aadac3e0a84cd,
+        0xcc219335e94fa3d6,
+        0xbc2918ad7692b7b9,
+        0x7620e1391537b595,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xce28d81a3532a314,
+        0x6381ea6eb7bdcb1e,
+        0xa0b4b318348803c6,
+        0x49be2e44c8a432fc,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x2f28ee8ad59100b1,
+        0xc0504baa92d16baf,
+        0x51b98c8bc258752b,
+        0x6359eda3ade8e7ff,
+    ]))],
+];
@@ -0,0 +1,571 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000000000050c,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0xe44705a306ce253f,
+            0x675743cbdac6074b,
+            0x336523f190a1aac2,
+            0x78312726b01a2936,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc91c450305aea9f3,
+            0x8ba82b557a153133,
+            0xa926b4eebe535fcf,
+            0x74de03beeca7e7ec,
+        ])),
+        Felt::new(BigInteger256([
+            0xd57ae39b2f49bdef,
+            0x59ddc994528ad2c1,
+            0x8ec82763a087f025,
+            0x406cde8bdb52dbfd,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeabcd3f932bf75ca,
+            0x5462a57a75221fd8,
+            0xe0401ffc8b818ad4,
+            0x368aff19f3cd3056,
+        ])),
+        Felt::new(BigInteger256([
+            0x566530f0780e7e50,
+            0x1c7310f52d5dc538,
+            0xc4756f05b6a0da3e,
+            0x665f586f72c90952,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2b76347555535d5c,
+            0xa0bd73410e617c7a,
+            0x0202bafd84b253c1,
+            0x74cd3813ab4c743c,
+        ])),
+        Felt::new(BigInteger256([
+            0xaa6ad7f19a4e1f37,
+            0xf9b2e003a1787731,
+            0x4c0285278a266c67,
+            0x4250fc8654a6c580,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc3122796a7ac5a2f,
+            0x5c48a2544850e7f2,
+            0x7bf385e3cc945556,
+            0x4e77b9d15e137fb8,
+        ])),
+        Felt::new(BigInteger256([
+            0xafb10334e4d9d4a3,
+            0x4ba4a17e8016654c,
+            0x1c5ea885655f8574,
+            0x78990c5737e9936a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x130812fee00d6c64,
+            0x818d215545de14fe,
+            0x9aba4b68bbb4297d,
+            0x2838baa79d11aa4f,
+        ])),
+        Felt::new(BigInteger256([
+            0x3e05b1865e82c77a,
+            0x67b74e159ee9219f,
+            0x75da67c4502ca321,
+            0x3d1986fe13ad31ce,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3bec9e832fec92ce,
+            0x50900c9fc2d58b32,
+            0x3752456293ce4b5d,
+            0x293526c620b2472a,
+        ])),
+        Felt::new(BigInteger256([
+            0xf4b5e23f41b7c325,
+            0x8cffca539cb06104,
+            0xe087c3e1b58765fe,
+            0x0163d29e1b9a50f5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6fb39b73b190ae3b,
+            0x2a9ab3b87e9de3d6,
+            0x41f12658bc6cb7c5,
+            0x0046a5a911cf0aa9,
+        ])),
+        Felt::new(BigInteger256([
+            0x62d4c0c2b8bdfa6f,
+            0x0ee565a9de74f6a0,
+            0x2ded9cf1c4870949,
+            0x0d55573af8e89e7b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x40857658b93ccfb3,
+            0xf4eed7d07dc3101b,
+            0x1f9f8eb6be1cdcbb,
+            0x36709391986752b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x73ab2da2e1251558,
+            0x6eed0de3bfc7ce94,
+            0x08bfc2f8290157dc,
+            0x2c8d1a8d95137c60,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xed0c810f4cdd09dd,
+            0x81335422994f28a5,
+            0x523bf0db426df5d3,
+            0x0e8701170f63e607,
+        ])),
+        Felt::new(BigInteger256([
+            0xda4c317fee84d5c3,
+            0xc76c9e9e11ad8471,
+            0x314b7682940355a0,
+            0x58e7cf7a565c0757,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbee2f06fe5a835da,
+            0xd59e0b3e611e23cb,
+            0x1f462f1f0fd853f6,
+            0x60cfbdb78334774e,
+        ])),
+        Felt::new(BigInteger256([
+            0xb214e9fccb0fe184,
+            0x10cf293bfee95a07,
+            0x37e787bb6c108fe8,
+            0x7870be22c4b7a229,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x601aef5941adb0f5,
+            0x6bdc76941b848260,
+            0xbfc87b068c64e211,
+            0x01c6ad81837fd91c,
+        ])),
+        Felt::new(BigInteger256([
+            0x1e814aba5eeab529,
+            0x3ef6ac0b47c6cce4,
+            0xafc0c63efa78904b,
+            0x11db3ac874fb0e6d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x45bda1f26ab1ac73,
+            0x481cdaa312fe4b83,
+            0x0f0766c044c4b377,
+            0x5b7cbb28698642f1,
+        ])),
+        Felt::new(BigInteger256([
+            0x8f65d4de01c58e21,
+            0xf40daa8679aafc0e,
+            0x7b89162458c2830a,
+            0x145e15401967e4a6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb0da99cd229b6e9b,
+            0xa672cbfe46b9797c,
+            0x0e40b6d0944a8bfa,
+            0x65be0729ff1e60dc,
+        ])),
+        Felt::new(BigInteger256([
+            0x19eafe663ad25390,
+            0xaacbbf3a978e18c2,
+            0x1fc0197ecd5755e7,
+            0x04f57f0a8d862ce9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x80baba518009ca4c,
+            0xf1168fc962ce0fb4,
+            0x9c6872eefea512c3,
+            0x0ce602d853ba5778,
+        ])),
+        Felt::new(BigInteger256([
+            0xe159e84f8d4aad1b,
+            0x7fcc8f8af9e98732,
+            0x69892eec71e3933f,
+            0x72ee5dd378b68086,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xad861e6130f6283f,
+            0xf79dbb7054d00e9e,
+            0x06c6a7156977dfc0,
+            0x5a153cbc5da84771,
+        ])),
+        Felt::new(BigInteger256([
+            0x7a8a26742234b11c,
+            0x9a92d03f92a47425,
+            0x5e99fe407f2932c2,
+            0x4d06db1d581a06eb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x29bea87f6887f417,
+            0x418c3fc469691332,
+            0x04267fdab88219aa,
+            0x4dda0bc09a3f0314,
+        ])),
+        Felt::new(BigInteger256([
+            0x0ce2be21539746e8,
+            0x4d5a04a1b52ef639,
+            0x1ca7e466188f630d,
+            0x7b3349744bd7f078,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4579359d658f20b0,
+            0x724f84d4713d1199,
+            0xaeeecfc53bb78929,
+            0x36834096a8eb7762,
+        ])),
+        Felt::new(BigInteger256([
+            0xd5d5adde72dba0bd,
+            0x714719347f9df423,
+            0xa7cdea09d17d99e3,
+            0x72fc70a189ea2940,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x74569b2f4a683969,
+            0x93e809507778bb01,
+            0x28fed2ebdca63985,
+            0x119174008010884d,
+        ])),
+        Felt::new(BigInteger256([
+            0x6d3d02b0b029ad76,
+            0x9778dc1766d42ee0,
+            0x1ef003d84e280da6,
+            0x20bfa0bdc2819917,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfc28bb75c0f32622,
+            0x7694dc53fcfe968b,
+            0x37ffc48e9a50f778,
+            0x3d7a8e2cc166a0a8,
+        ])),
+        Felt::new(BigInteger256([
+            0x0a026365f33c28dc,
+            0x4c875835d67c331f,
+            0x4d01b80c3894a609,
+            0x1ced0b7099e443f1,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000000000051f,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0x745b1e25e12bf397,
+            0x436c1968219a35b9,
+            0xd4de3b89f4d54133,
+            0x25654811d61b85a4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xdad044d3398a68e4,
+            0xfddd58bd90825079,
+            0xe99fac6bc4d4725c,
+            0x3f5abc480e3a3978,
+        ])),
+        Felt::new(BigInteger256([
+            0x7742fbee3d834b12,
+            0xa827cc98afcc2075,
+            0x70ba36790b3c9923,
+            0x381db80022e689f8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x761339f1b53f5441,
+            0xa326cbc4a17b3058,
+            0x271cdc55c67a62b2,
+            0x0b968981a546aafc,
+        ])),
+        Felt::new(BigInteger256([
+            0x71cfaf6bd4ec2af9,
+            0x474c0cdba08b0425,
+            0xaccb42f755cd488d,
+            0x689f03c24a43e066,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8a7a6dbc75f00c15,
+            0xa35619fb4ace1501,
+            0x36e94c74db4df95e,
+            0x66d3fb32e44108b7,
+        ])),
+        Felt::new(BigInteger256([
+            0x998329bb95489c22,
+            0xd8605c5a24b93e26,
+            0x22622e3744f5a875,
+            0x618be090b39cb66a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb240901d22e2ff27,
+            0x57d670549bb27755,
+            0xe0d8282da5533333,
+            0x0f84015a3d75cdf9,
+        ])),
+        Felt::new(BigInteger256([
+            0x2ef3843e3a6e47cd,
+            0x2347451b1a4c231d,
+            0x22bc6267a251f9c2,
+            0x66d974cb3d4d3e1a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfaeb5cedab1559ab,
+            0xe8d09e95da25b0ba,
+            0x3c0b87b522b2cb32,
+            0x10a7a00525709e0e,
+        ])),
+        Felt::new(BigInteger256([
+            0xb5fd13f803e882f3,
+            0xab0fa0f27a04ebc9,
+            0xb8a4bba91b5edb47,
+            0x52bc8d46c20d81fb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9ec3d0a1aa55a6e5,
+            0xf1a7627380a59630,
+            0x9c1d38f15342b7f6,
+            0x0f034e2ee3ab0bbf,
+        ])),
+        Felt::new(BigInteger256([
+            0xe7a12ce0967ea56e,
+            0x0a2bf5c3a1549a70,
+            0xe6cbcf08d92f6909,
+            0x14661af2049471f9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6d099d2558b0deec,
+            0xe623d1c3461c6020,
+            0xc7e86dfb791d36a4,
+            0x1d09faa00e9ee2aa,
+        ])),
+        Felt::new(BigInteger256([
+            0xf03edaf73a3bf952,
+            0xa6835950ecc7a157,
+            0x555dfc2ce56b1e99,
+            0x574ccd1d1bb9d2eb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x89654864f8a8a586,
+            0xe53d21be08b58181,
+            0x327967e3fc107ab4,
+            0x0937f06f0607ed22,
+        ])),
+        Felt::new(BigInteger256([
+            0x4c9f1831faeeb95d,
+            0x3b502d6d918e6e68,
+            0xbd12b3bdcb288c46,
+            0x2c88985628b5733c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xddd8564edff31111,
+            0xcb191857b60ed7c0,
+            0x2223318d2b3856c7,
+            0x210df35ae6653bfc,
+        ])),
+        Felt::new(BigInteger256([
+            0x5b2c1f425bf8ab3c,
+            0xed67386f754161fa,
+            0xa2abcecce1014d05,
+            0x18a2e2a9535eb9ba,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x011aadac3e0a84cd,
+            0xcc219335e94fa3d6,
+            0xbc2918ad7692b7b9,
+            0x7620e1391537b595,
+        ])),
+        Felt::new(BigInteger256([
+            0x8460bfbbfdcffebc,
+            0xe36786cfcdef087f,
+            0x764388e236fe8a1b,
+            0x3af6028f7cbc3cdf,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xce28d81a3532a314,
+            0x6381ea6eb7bdcb1e,
+            0xa0b4b318348803c6,
+            0x49be2e44c8a432fc,
+        ])),
+        Felt::new(BigInteger256([
+            0x1ca34bfe2ccd758d,
+            0x12b0f5822ad44410,
+            0x322615e906cf4871,
+            0x0706dc76e020c4bc,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2f28ee8ad59100b1,
+            0xc0504baa92d16baf,
+            0x51b98c8bc258752b,
+            0x6359eda3ade8e7ff,
+        ])),
+        Felt::new(BigInteger256([
+            0x08e539f94702b091,
+            0x4855f12a40524aa8,
+            0x5fb453883a89db30,
+            0x496f68a683cbe623,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x61574bca2b365d67,
+            0xc15df7f1a8fd7179,
+            0x26f34dec449c9eda,
+            0x59d42a39350dbfb6,
+        ])),
+        Felt::new(BigInteger256([
+            0x5a7bc8e61dcb108e,
+            0xa1cbc0ca40a63f2c,
+            0xd9ebc832e1dcff38,
+            0x263fc304e976e831,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x54f927199df1a290,
+            0xea959415921a2185,
+            0x7edc95a019d5570e,
+            0x58082e1f7c87af02,
+        ])),
+        Felt::new(BigInteger256([
+            0x45ac6d9a859053a4,
+            0x556069737009c771,
+            0xed766935f1476dfb,
+            0x6b44aa05c785347e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc17296139be7c752,
+            0x806f7b681ca901bf,
+            0x699a965e0b9163b0,
+            0x2747d39c1fd906dc,
+        ])),
+        Felt::new(BigInteger256([
+            0x1e8ab6a967841e61,
+            0xff7965d3a15195b4,
+            0x62e7052185764d22,
+            0x476d92e8404c22c5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd5b8520d171f1e58,
+            0xc2fb0a20d110cea5,
+            0x5657241ba261d2d3,
+            0x697499b5be67d1f1,
+        ])),
+        Felt::new(BigInteger256([
+            0x48f08031dc8c3f6e,
+            0xaadda49a63aae01a,
+            0x1051a03f66a2b2a7,
+            0x4401f85496021bc4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x14ce24b7a7b86ea2,
+            0x8d1aeae1dd501060,
+            0xa3b681244e947cdf,
+            0x3f2dddb6abcca1e4,
+        ])),
+        Felt::new(BigInteger256([
+            0x353eb57b8f62bcf4,
+            0x682754de32852158,
+            0x3e0eb301488e240a,
+            0x28db2eacb2ccb031,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe30bd2097dc8c5fa,
+            0x184b7d6c94adf31f,
+            0xfd64dcb8e54b0d5b,
+            0x08bcb6eff8b6f8af,
+        ])),
+        Felt::new(BigInteger256([
+            0x6c06520dbde80839,
+            0xf7f125cfcadd956c,
+            0x94cf253dbb0077ec,
+            0x451f0498612965e8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf6d3d7f6c9dc25e8,
+            0x1dee7d3e8847c983,
+            0xe11e10fd9474a3d1,
+            0x1832c6666006c66d,
+        ])),
+        Felt::new(BigInteger256([
+            0x94c19869d682f6d4,
+            0xcff5cebca8999484,
+            0x97991c1396ebe8d2,
+            0x24d964955e85c625,
+        ])),
+    ],
+];
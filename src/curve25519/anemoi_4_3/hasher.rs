@@ -0,0 +1,772 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![Felt::new(BigInteger256([
+            0x0cd26bfbcf62f9cd,
+            0x4cb67da67888cfbc,
+            0x6102c42f390053e8,
+            0x402cd00cafa9481b,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0xc5f6791e9f24af87,
+                0xf37292ca699f13c3,
+                0x7628043938fb4789,
+                0x79a6f568ff3fa963,
+            ])),
+            Felt::new(BigInteger256([
+                0xb04509f0a3e495ab,
+                0x7ca109ef1084e275,
+                0xa5449c717cffbb2f,
+                0x58a1faf8cedd9bf5,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xcb18bbdd4e710e77,
+                0x84ae5e8227cd4299,
+                0x021db6766c737ceb,
+                0x48215fec3391bca1,
+            ])),
+            Felt::new(BigInteger256([
+                0xf23e23cda5fb7127,
+                0x5cb305e8fe2689de,
+                0x9302da8de22a8bfc,
+                0x638440b1f4cbac83,
+            ])),
+            Felt::new(BigInteger256([
+                0xcfd3202abc45afe5,
+                0x94b86b34397f6419,
+                0x9d211b31573399eb,
+                0x5d2b35f1b03ff5d0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x234ee76b56e06af7,
+                0x78ab9e35da95e625,
+                0x775e4b387dc064dc,
+                0x131492cf95619c78,
+            ])),
+            Felt::new(BigInteger256([
+                0x742bf08e4b8d0db9,
+                0x56ae0473cded93ef,
+                0xa4794638886391cf,
+                0x26b586f0f3405fa3,
+            ])),
+            Felt::new(BigInteger256([
+                0x443d5e8530b12dbd,
+                0x7f588dd38d696760,
+                0x40b84f4b6ae58e93,
+                0x206fdc5288c4b0de,
+            ])),
+            Felt::new(BigInteger256([
+                0xe544696182da45bc,
+                0xc101e83775fb49ef,
+                0x4a9cdd35b85859a6,
+                0x2f48922eede3028a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc266f4b01257c2e0,
+                0xcb4a7928533a6555,
+                0x2ca82738cf9152ef,
+                0x4bacfb813bfab59b,
+            ])),
+            Felt::new(BigInteger256([
+                0xac59c02f2a7e338b,
+                0x4789d2cc3e378bea,
+                0xeb4742a060cf0422,
+                0x58a6fee39db9f8f0,
+            ])),
+            Felt::new(BigInteger256([
+                0x166b21631440af52,
+                0x62fca5edc8eebd42,
+                0x03ea1c8c65689a54,
+                0x306dcbd1cd6f7578,
+            ])),
+            Felt::new(BigInteger256([
+                0x1934e128a6d04a80,
+                0x648044e0fb4d9bc7,
+                0x88ed465bf9c58f23,
+                0x2bf98e8d3c400ea3,
+            ])),
+            Felt::new(BigInteger256([
+                0xf5022880b3b0a501,
+                0xc3a6b53956d88689,
+                0xe22cbc8847f515b7,
+                0x79cec2e85c5b7a5b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xa21fa71302c7b522,
+                0xe04f83ba91afb6e3,
+                0x0fa66d83aed23991,
+                0x5c5ac2dece78866a,
+            ])),
+            Felt::new(BigInteger256([
+                0x91db79f16633ed2d,
+                0xcdd4ca54cd565f43,
+                0xae743dfe393d9cc8,
+                0x02ad88648ebbb436,
+            ])),
+            Felt::new(BigInteger256([
+                0xdbd88761330f1993,
+                0x8dd71e8e4ac53b7a,
+                0x070daddb6cf8a2b1,
+                0x6a6ddd8e431ff03f,
+            ])),
+            Felt::new(BigInteger256([
+                0x2f7631afd373b93b,
+                0x78716c8bc6ee7a1d,
+                0xd1ccac1ac431b2bb,
+                0x4ce72a7ddba34775,
+            ])),
+            Felt::new(BigInteger256([
+                0x74301213db55fb9c,
+                0xf7eaaeac897695c5,
+                0x23587fd45617dd28,
+                0x5a01a2f3161a081b,
+            ])),
+            Felt::new(BigInteger256([
+                0x2382440042ce01dd,
+                0xa942cea8e344cf63,
+                0xa8393723e0670a32,
+                0x6a448ba25250a1da,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xead04d472e08e490,
+            0x73277e4bf5d8092b,
+            0x960a8c44b904d8bb,
+            0x189caac8768ec85f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbed21039503b5085,
+            0x52c3f9a61428e583,
+            0x8f8ea108dab17dc2,
+            0x50413412320944df,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xcd7a8efab120faa7,
+            0x22b5b175d144f7cf,
+            0x051ee768f4cc6c43,
+            0x1de7c94a62aa9a59,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa0bc2dd984f0fc70,
+            0x71d86d39bbbb3595,
+            0xa317e1cc74b0a63e,
+            0x23d97daef359a7f7,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4217b51577eb8570,
+            0xc24ae50196e40221,
+            0x25e363346866b257,
+            0x74456032b794b049,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x03adf35683bd5072,
+            0x0ca17627686ac96f,
+            0xfbe4f6a98c766976,
+            0x62f80ece8a320705,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe7de4c3d4a6d92f9,
+            0xa5679fa851d1647d,
+            0x90292e42552ce013,
+            0x6b7cb679298f7a76,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x524476af37fa0d87,
+            0x383c68136f69cad5,
+            0x1ad087240aa05f0c,
+            0x20891a63002b80a0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0208b9bef6d0c301,
+            0x6842238acac8d2c0,
+            0xc08ba56d1639066b,
+            0x0499a2303e36b033,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbf4bc3811597fa8c,
+            0xd911a1562fa75cb0,
+            0x18314b6de877645b,
+            0x594d4999a39ab0dd,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0x3ec5b204dcd2acfe,
+                0x27c8307a2a5959f9,
+                0xd4b31a76890c9567,
+                0x7d22c5742355faad,
+            ])),
+            Felt::new(BigInteger256([
+                0x25be4e4b76f51e39,
+                0xf1577ca861e41565,
+                0x3d6c88a8cf83e2a5,
+                0x04b4621b3ca49711,
+            ])),
+            Felt::new(BigInteger256([
+                0x73ec0709d433440b,
+                0xce45c5a9b8576667,
+                0x0f2d89fe9aab61fe,
+                0x37def4af73b9ce3f,
+            ])),
+            Felt::new(BigInteger256([
+                0xcb6d1001d6605244,
+                0xaf2cddadd6123652,
+                0xdba586b9fc63fa0a,
+                0x5ff668142bea2306,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc40f64fba88fe937,
+                0x520444de068653a8,
+                0xa5ecc706ee622e0f,
+                0x1b56ebbdb6e87aae,
+            ])),
+            Felt::new(BigInteger256([
+                0x3458b0c56ff06057,
+                0xaee84aec5fe51411,
+                0xbe6ccc7219e83908,
+                0x7e8596800d2dc48c,
+            ])),
+            Felt::new(BigInteger256([
+                0x15e2434eb6a85e3d,
+                0xe0698d24db58167e,
+                0xd9397adfd0311760,
+                0x0bc3b4c6b6cf79b6,
+            ])),
+            Felt::new(BigInteger256([
+                0x5ddd2a70fab18a2a,
+                0x639e4c40ae564eaa,
+                0x862ee1dcd3d78bea,
+                0x7ce231a5b26ef3df,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x294e974fe8ee6a85,
+                0xc0f655d8859736f3,
+                0xa43141c9df05e381,
+                0x46f2f547ed95163a,
+            ])),
+            Felt::new(BigInteger256([
+                0x16eef690e961860c,
+                0xd12f2d73d4db8f43,
+                0xc9194b4766014ba2,
+                0x56165b772241a397,
+            ])),
+            Felt::new(BigInteger256([
+                0xf86875d0679b2737,
+                0x1741750a73437bab,
+                0x769bdf0ecc7db22e,
+                0x081dc010aebcc27b,
+            ])),
+            Felt::new(BigInteger256([
+                0x7c38a52a8388ebd1,
+                0x07fa18bae9afb59e,
+                0xf2f5fb3e19b7d03e,
+                0x7166671512d18c4c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x0af077282ce5d4dc,
+                0x5e7a771be71fb15e,
+                0x5695aab3da46088c,
+                0x004ee31603c1cfc6,
+            ])),
+            Felt::new(BigInteger256([
+                0xf55dfba9f69cda95,
+                0x718913e7a2e52cd2,
+                0xf2d1c7ceb86c436a,
+                0x266d4b26731a4b64,
+            ])),
+            Felt::new(BigInteger256([
+                0xcedf9fee2c5de29c,
+                0xb17523d7996aed06,
+                0x1c8ab508fd138962,
+                0x270ff7c4c1c3f75c,
+            ])),
+            Felt::new(BigInteger256([
+                0x59aa948a61a1a932,
+                0x6ed43df53e90da39,
+                0xa92d751342684ccc,
+                0x024d2e6aea17f192,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xcc35308947f4e106,
+                0x9d587a3d0dc48d4a,
+                0xfff95cecbcbac2d0,
+                0x6f0b8efed9423404,
+            ])),
+            Felt::new(BigInteger256([
+                0xea59e622393b3043,
+                0xb04f8b8287d445b2,
+                0x750ee3faecbce595,
+                0x50717d1d324ebd68,
+            ])),
+            Felt::new(BigInteger256([
+                0xf508d8ae2342f513,
+                0x710bdaaf6e1a90b0,
+                0xa6ea4c7cc9bac8a9,
+                0x63cc6a0cb5f3f242,
+            ])),
+            Felt::new(BigInteger256([
+                0xc675fcdbab774ca8,
+                0x3ef9c5c5b5f665f0,
+                0x89e353efb05cb624,
+                0x179e422a0a3ec60b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf0d25251aef79d3c,
+                0xc88e94c91f319964,
+                0x77205b3a4cefb791,
+                0x119cb51892e211af,
+            ])),
+            Felt::new(BigInteger256([
+                0x791301be83123c40,
+                0xe4be1631c6e1f361,
+                0x08e0502cc8cf3013,
+                0x6cc9e8143ebb23a8,
+            ])),
+            Felt::new(BigInteger256([
+                0xf146a84045c2a960,
+                0x7572a7aa7c8c777f,
+                0xb598571b1c9672d5,
+                0x544ac1a360723c1c,
+            ])),
+            Felt::new(BigInteger256([
+                0xc0bc16ba6fa637b6,
+                0x66c87e79f7687219,
+                0xac92ea86e00291af,
+                0x28f3be7ca3ea07c1,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x57a17f3021d5f2a2,
+                0x43fe34ca610b6175,
+                0x6656a27aef93cf29,
+                0x37cffd9d41f46e3a,
+            ])),
+            Felt::new(BigInteger256([
+                0x706f4a108e5c927d,
+                0xadde3891e34c0f05,
+                0x1d25ad73721e98a1,
+                0x4f31dfe61ed172c0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5302bfe46bb49c99,
+                0x150fb6ca193210cd,
+                0x3ac455fd4cdd7003,
+                0x21f8686eb7fb4420,
+            ])),
+            Felt::new(BigInteger256([
+                0x6fef7d24dc96fcfc,
+                0x397e438cd7840da9,
+                0xf2f8f00255927481,
+                0x3a111dab001409c5,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x7d81edbdd009488b,
+                0x0ba9c1e8cec30440,
+                0x6da35ecddf93107d,
+                0x1dead8fe882f5ead,
+            ])),
+            Felt::new(BigInteger256([
+                0xb0d6e24f11b96e2f,
+                0x1d788a45b030d976,
+                0xb5264159983668e6,
+                0x06f5dc36467c692b,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xa42e6056b82cbadf,
+                0x33512b62054edba5,
+                0x04cb795864d3f3ef,
+                0x18db3aacdbc260d0,
+            ])),
+            Felt::new(BigInteger256([
+                0xea2e9ff81754e377,
+                0x3c3301d7f53c96b8,
+                0x1b222146d8480c6a,
+                0x1ce3d4599409fa18,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x17a1fe0d9ef21952,
+                0x1f19c8bf0a954ef3,
+                0x9287540a6be0fe1d,
+                0x384f5151878f0ff3,
+            ])),
+            Felt::new(BigInteger256([
+                0x3e8146fc5fc3df54,
+                0x29d42ec3a4dd491f,
+                0xfdc6b2afdc2b5df1,
+                0x7fcfa4207a6c9ccf,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x4f7eaf6cb6f3b2d1,
+                0x7f9df28b7bec1866,
+                0x4c6e4fa2cb6a142f,
+                0x627af5a97fce3f73,
+            ])),
+            Felt::new(BigInteger256([
+                0x0ac25f741373138b,
+                0x199f8790a2215614,
+                0x4b16143e77243dbc,
+                0x4565b39e7f130c50,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x76539e64d6be7607,
+                0xfc6b9f531c90a5a3,
+                0x5768e924722ce5bc,
+                0x2c999eca12c59f3a,
+            ])),
+            Felt::new(BigInteger256([
+                0xd044f9dc70bdc22f,
+                0x10441d1ee24489bc,
+                0xc0baba988f198e5d,
+                0x41cb348fc33b416d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xefa83b75a02b7e6d,
+                0x464730f1cb53b1f8,
+                0xbaa50613341d0abb,
+                0x61d517566dec2dc8,
+            ])),
+            Felt::new(BigInteger256([
+                0xdd1c0a5818f9d191,
+                0xfa99f9c653979316,
+                0xb9473d2d73470115,
+                0x2c9b7e316739663e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x6cebf1f12d07f33f,
+                0x1fcc6bbbe14f7b33,
+                0x535e973a40537f90,
+                0x3646b64a26822ce2,
+            ])),
+            Felt::new(BigInteger256([
+                0xb01f96be549cd6c5,
+                0x553616bb86c79852,
+                0xf579575c0998b652,
+                0x49be5f091ab43737,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xd45f36be1aa5ca55,
+                0x8a599639f47fd615,
+                0x37bc2f5a89723ea2,
+                0x6e101afda7f86b8e,
+            ])),
+            Felt::new(BigInteger256([
+                0xe559e6a0d326662d,
+                0xe3cf16cfb7b1d10c,
+                0x37ed418a20ec2beb,
+                0x30b4bd5a6637b4ec,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0x85638c1f7919a382,
+                0x9482c29c62bfc72e,
+                0x9aedf7ac3c136cab,
+                0x55b8a607356111cd,
+            ])),
+            Felt::new(BigInteger256([
+                0x160df30a7878b3eb,
+                0x0c99911f86d63266,
+                0xe9a312959666d2c4,
+                0x7ee05d1d559ed9e9,
+            ])),
+            Felt::new(BigInteger256([
+                0x435093a86c1c3755,
+                0x6542eda0349bcd6f,
+                0x7b8182138168b7c8,
+                0x19d7520d0a9512a2,
+            ])),
+            Felt::new(BigInteger256([
+                0x5512174f6bdebcff,
+                0xc972ff250f0ea945,
+                0x7aaffa02c44c2668,
+                0x6a297e91a404c642,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x96b705ce24f5822e,
+                0x89058dab4a847da6,
+                0xba32e3e1f93774ea,
+                0x052d7aed0f66f15f,
+            ])),
+            Felt::new(BigInteger256([
+                0x94ffd2845e235629,
+                0xc7827e662a582178,
+                0x6b18dfa80230a920,
+                0x1c9c9787551bc85c,
+            ])),
+            Felt::new(BigInteger256([
+                0x89aeabb9d298979c,
+                0xa1b51d01767e345a,
+                0x01c06f54341ef5e8,
+                0x16774aeada38fdda,
+            ])),
+            Felt::new(BigInteger256([
+                0xf7d9855b33290e95,
+                0x3de800c9bf0bcd8e,
+                0x065e29f8c9ae4a37,
+                0x4eb8b3510315c4b5,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf567c65b8df91238,
+                0x9de08f8a9897e841,
+                0xf13fa8ff2ee039a7,
+                0x67913ca4b93fe941,
+            ])),
+            Felt::new(BigInteger256([
+                0xa6f0c3123f5aa51f,
+                0xd681f038490ac001,
+                0x5d2ce850a47a0951,
+                0x65c476b3599a1275,
+            ])),
+            Felt::new(BigInteger256([
+                0xb1b3a4540ecf9de0,
+                0x426dfbcbd6cff4e9,
+                0x2f6930ad6b639f87,
+                0x6afa9d9ece1e5cab,
+            ])),
+            Felt::new(BigInteger256([
+                0xa71519aa8d8dcc10,
+                0x0f1eeb75f5671b30,
+                0x752818208d275266,
+                0x5dcf8f5d8ec46d75,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe6f536d22e9793c1,
+                0xcf21c70d3be28a4b,
+                0xef81a79ae6a19460,
+                0x57f6e006f173ee71,
+            ])),
+            Felt::new(BigInteger256([
+                0x3e76136c57046e42,
+                0xbd8c438919c10fc7,
+                0x859e5c9674a04700,
+                0x16760c358850ca06,
+            ])),
+            Felt::new(BigInteger256([
+                0x37cda9dbb633c720,
+                0x5ce64dfe4a7f7965,
+                0xba4cb0eba685bb79,
+                0x20940767e92136e0,
+            ])),
+            Felt::new(BigInteger256([
+                0x7c8a01d705a3be11,
+                0x874e9c4fa81e568f,
+                0x26a4450b6819ba8f,
+                0x2794042ee8536cf7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc091258756d846e0,
+                0x168f2d82873debaa,
+                0xcc7cbca977c73e1d,
+                0x3527131a87ef5be6,
+            ])),
+            Felt::new(BigInteger256([
+                0x9da5a3742a29c9a3,
+                0x16b909ba02af8dfc,
+                0x4d49a584153fea3f,
+                0x670d0a465c82a503,
+            ])),
+            Felt::new(BigInteger256([
+                0x56ec1e1ef56baabd,
+                0xc9121ac9599e4ef0,
+                0x608b70963c6a1f40,
+                0x2508ec8c07480dca,
+            ])),
+            Felt::new(BigInteger256([
+                0x68342fa18fd61d45,
+                0x0fcd47aaeb7a04fb,
+                0x2f3c8324d33e56c5,
+                0x027909f85de56ddf,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xff7633b25af2979c,
+                0x006767f3ffff0f89,
+                0x54e87870f552877d,
+                0x7caa4556b735f9aa,
+            ])),
+            Felt::new(BigInteger256([
+                0x0f6772427271c79f,
+                0x92d43a2a21c573a0,
+                0x6852dd411b3822a2,
+                0x35e814c2ad3b4837,
+            ])),
+            Felt::new(BigInteger256([
+                0xd175c1b4ba52867c,
+                0xfa8e148bcd392e4f,
+                0xfad2f57a62b8fd73,
+                0x6dbe4fe553c38824,
+            ])),
+            Felt::new(BigInteger256([
+                0x839a62f365c6a197,
+                0x40476ed64fd3c072,
+                0x192f013c03bb0796,
+                0x47420050ea345b17,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xc810c940b0328532,
+            0xf1dc6d5c4457707a,
+            0x837c4fee61b267ca,
+            0x0701dd8360c5e0fa,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc2f23d09484b9995,
+            0x4e8dfa56f0b61e76,
+            0x2dbd45ffa26fe484,
+            0x5c098619b80f4de6,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2e58d00ce1c2b6ba,
+            0x29224c2e7ef3ddb7,
+            0x22c9a02777c97963,
+            0x24e0b534ceabc7d9,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8e5d004ecf819e56,
+            0x6f842d39fa8b725e,
+            0x1fed9a9f3d1c0059,
+            0x35bf0f066fcc5ae8,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x49ba3c78ac46ffda,
+            0x72461b74d34f1ed2,
+            0x94ef25f574a7c103,
+            0x4b583c469c6ec084,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x27a7ea20934d97f4,
+            0x0234967949ae4b25,
+            0x4377405715ab5f18,
+            0x449e404c09081486,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x3fe0d5c36a795d49,
+            0xb6075df1d8f3472f,
+            0x8b13d0ae177dfa03,
+            0x45baf61477b4cde8,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe20caa36d6f4a594,
+            0x08676cc260c0732e,
+            0xd13de3229a014976,
+            0x2a5a697000df07fd,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x95530d7f1e462b8f,
+            0xd8fc67331f5f3232,
+            0xaecf4b6bd60c1b86,
+            0x60ac926cdaa551b1,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa0e6f9ec9a6156fa,
+            0x1c709907efcda6d4,
+            0x81e3fbc498cab859,
+            0x7ef30aff6a9ef691,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+    }
+}
@@ -0,0 +1,961 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xddc2ac8f68a7af08,
+                    0xa1b22bf905b16665,
+                    0xee09cc297b357113,
+                    0x62721b6413432389,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7058e750aacf95d9,
+                    0x6bc5a88d73a912bb,
+                    0xd344ff44b52b46f0,
+                    0x1d9a02e23a28a294,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76f0822ec315bc37,
+                    0x71f1627999567c2f,
+                    0x2a850de395d4885c,
+                    0x05a051f72d4a4758,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb914209ab5cb3cf2,
+                    0x22f8619287f60338,
+                    0xd669555895e6a593,
+                    0x15ae32177ead8989,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x50b8a9c80fa60dc8,
+                    0x92e70ed9f162a997,
+                    0xa86ec2c3b8b79f9a,
+                    0x04557e4c538c4e7d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8047f37c70384eaf,
+                    0x2b2c0abf838953f8,
+                    0x4b8c1c36b1745384,
+                    0x6921b72a85fcf0d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x142bb3969cfc952d,
+                    0x734f5cfc85a5a368,
+                    0x03a9c4902983c946,
+                    0x62cb17a1d72986dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf27fb6e4995e95ff,
+                    0x16b3bde87954bbe5,
+                    0x52c20fd1cc7fa2c7,
+                    0x181dcd951ff6229c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x949df58e3b0e0797,
+                    0x66559bdc07b3cdb7,
+                    0x3b66ee93458ca149,
+                    0x2694df3b3d236425,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe86889beeb0dd0e8,
+                    0xdaff1f8e29f13778,
+                    0xc5c3cbfe745e23e7,
+                    0x7787c1ba7f02c393,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0229a544a76a27f5,
+                    0x78ffe337d57ac703,
+                    0x9eaeb2565e0670ef,
+                    0x6a0b0d0ab4084198,
+                ])),
+                Felt::new(BigInteger256([
+                    0x172cbecd2eaa3162,
+                    0x1deff828a7376e10,
+                    0xfa48b8c3f56ca492,
+                    0x69bc05b337b4cfee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6259bce6a2f81b77,
+                    0x3d88e5c9f3e99132,
+                    0x307e37fb8b91f00b,
+                    0x4473a733d81ec441,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55976c4284ff910d,
+                    0xb8bceca97ce8c5bd,
+                    0x85760c5bc863c7de,
+                    0x485cf594390a3989,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf4bd4830850f553f,
+                    0xc0623abb8cd15ae6,
+                    0xf670569be40f94a3,
+                    0x7f8179bdf539343c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec67b5a679fae670,
+                    0x09ed5f1202a2d2b5,
+                    0x2a6f14f54ee9ee2a,
+                    0x10f80c74e4a1d060,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x20a0dcfe825fc540,
+                    0x8b6cb561e0117f27,
+                    0x7a329f6aa1192630,
+                    0x3251657183ec05b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x967439294ea24d8b,
+                    0xd1c3690a23ffcb97,
+                    0x6c4d8a7231394ff9,
+                    0x57cdad206ab6d5a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa280e3fcfdd7b7cc,
+                    0x5760ad93a25df6aa,
+                    0x54e0c3ae85befe5a,
+                    0x2d4ab28c19d83557,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3aa1bac19e8fcd10,
+                    0x4addc4f6942fbd70,
+                    0x146838da44398977,
+                    0x1ff58ce4508a3877,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x825611bef6690a34,
+                    0x3c467b448dd6c33f,
+                    0x70814a75c42e9c3e,
+                    0x277d3a24f61511b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7dd829c978cfea98,
+                    0xe4878fdfaaec9ef2,
+                    0x9de53817640d0d0c,
+                    0x6aa743ae3dfe2a73,
+                ])),
+                Felt::new(BigInteger256([
+                    0x021c8ff1601136e4,
+                    0x3fe4ba0e58b981e4,
+                    0xeb0084173e8d708a,
+                    0x5eaae749b43f44ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe5f7c33bbf8aa6c,
+                    0x9fc942a4dcf2fa69,
+                    0x74de677f90b887b6,
+                    0x08eb176b5b8af1f7,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x95958d3751d5e689,
+                    0xa3edfa013e6aae00,
+                    0x3167ec7b1d1320f7,
+                    0x109ca3bc2f31e8ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0e610308742bbb7,
+                    0x6bf275bfe78d1d5c,
+                    0x191f18a2acb046c3,
+                    0x0b417628dc104964,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2ab01700909a655,
+                    0xc4217fc408ac8d08,
+                    0xf0523af06b772cf2,
+                    0x6ff6f7fa25c61511,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9052dde2ef5826eb,
+                    0x3ab08a2e08206feb,
+                    0xfc26f53331ed68d3,
+                    0x359596cb5f5a7da1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7924779d531915f1,
+                    0xd864f8e209efe2d5,
+                    0xfcbadbcc0e64c007,
+                    0x0b38a54ad2adf2d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x506e9e8da1ac32ca,
+                    0x31384e96d7322399,
+                    0x752ab3738be89554,
+                    0x2a68f9d2c3dd0c30,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9afefed27f2d31fa,
+                    0xb92385630a49629a,
+                    0x1fb2b89564f1fa05,
+                    0x01c40c87d8ad117e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc391d51faa98d139,
+                    0x420ac1e87b4c69ae,
+                    0x877c4fffa9d9be3c,
+                    0x229cc3d6cb99c4af,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0d16145f550408d2,
+                    0x8cd4849e5296a2fa,
+                    0x111324585d00170e,
+                    0x4042c45f013ae2bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47a5c761384ed9ec,
+                    0x19ca3bdac30ea836,
+                    0x3c766509f1fda1dd,
+                    0x6e44cbee369b823a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fc15120cb7137ff,
+                    0x9658266a1941ce93,
+                    0x9503c0b78a3a4d68,
+                    0x117e24604886e255,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbce0ccb0a6c0aa80,
+                    0xc7e080207002a9ae,
+                    0x34e97c577ae37d74,
+                    0x58b7d68371b9ba57,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xce11a9399f87d164,
+                    0xf1fc8bfdd7e030f3,
+                    0xd37c2d12f71520dd,
+                    0x7ba2f423273f2702,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe699bcf139e94c29,
+                    0x420f83f5bfd10015,
+                    0x9ca1a53f367977ba,
+                    0x2c14aca15acbd535,
+                ])),
+                Felt::new(BigInteger256([
+                    0x68665161b1c0fd65,
+                    0x2fe57cc9f0f20aea,
+                    0x87d32f80fcee37e7,
+                    0x04e9fb6a06218e4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc937de9e2bf5a57f,
+                    0xaac069d7b595fb83,
+                    0x914b909154b1c043,
+                    0x09b5e45ed9e3494b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x57369b4199bcd8b6,
+                    0x77a65ab9ace150f7,
+                    0xbab43f24dded3562,
+                    0x1f3fc22e83a851ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa9cc35ef8983e20e,
+                    0x0260cda27c826041,
+                    0x97374850636e9109,
+                    0x1ef259a0694131ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7db24704db93614a,
+                    0x75117d6b2ee68c9c,
+                    0x81cd18b7dcef964a,
+                    0x32de3a0146a145d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x58abfee892be9132,
+                    0x36c9196582b8f53c,
+                    0xa19a768c779a394c,
+                    0x0acdb51bfc1b31f2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcfdb76ee8b2a375c,
+                    0x55399c33e5a1bf6e,
+                    0x4dd13392bd06c18b,
+                    0x626f16ae8cffa5cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x48d77c0ad1d9e8c7,
+                    0xe0815278bb059e76,
+                    0xa7ff4e7fe73264fc,
+                    0x775450dd542feb93,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9cb3eb6c171f55f2,
+                    0xa3f1631cfc033241,
+                    0xef62363a36d19956,
+                    0x1f3f629eea295c8c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7428497f0984a766,
+                    0x70a0713cb360dbf7,
+                    0xfefd117269a6927b,
+                    0x3faf69b1a4cdc351,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x72734f08588f0739,
+                    0x5f7d059826761f80,
+                    0xe5b10842eb72b70f,
+                    0x43bbf1c95d00701c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeedbed0c548e1c8d,
+                    0x659613a3ac75d0b3,
+                    0xb76fc64487389c19,
+                    0x2784ef1d8a5b3fa5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a54079f790c407b,
+                    0x476cbcdc71337710,
+                    0x7a978a2795de27ce,
+                    0x1f5634b1b61dacdf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc66f372a82174f09,
+                    0x1d3fc0a35f8ab079,
+                    0x44552e38e6e5b82a,
+                    0x5c4b9a0baad33aaa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x26add6c1439455df,
+                    0x7eda3eb6731940da,
+                    0x0a998a21d282f749,
+                    0x51d09af14eaf62cf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1fd3bbcda43d4f26,
+                    0xdc584dcd47297891,
+                    0x641dc7445c941862,
+                    0x237d1378e3bb292a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e011fec89c0bf9a,
+                    0x3874338ca2a5ce1a,
+                    0xa6c16cf590ed8826,
+                    0x3ff1eabf234bb3a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x279dc8fe615341f1,
+                    0xaace5ab72de9920c,
+                    0x136a1dadf1f1a72a,
+                    0x1e492494d037bb6e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3b6c4b94ea7c4184,
+                    0x347b5502aa7ac5d5,
+                    0x7740e205d718277b,
+                    0x7f745b152baefea1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d56eefbf41efa1a,
+                    0xa9db13e67ad368d9,
+                    0x41deb21ff9d6efd1,
+                    0x6ed99374e7a63a0c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x372ae5253f1d8134,
+                    0x8a950da23245b13f,
+                    0x1d5f592d4d8e3015,
+                    0x228ddf1732774760,
+                ])),
+                Felt::new(BigInteger256([
+                    0xad045364a74f3884,
+                    0x31d30f007019551d,
+                    0x62e243dbdda9f02b,
+                    0x5f98955ed43c6024,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x75c462bd13d8faa4,
+                    0x29d01e9a20c7d123,
+                    0xc5e9100ed74f8ea5,
+                    0x73b3b8d99cf060d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x744ba1af2e8b3ef7,
+                    0x9cb305c0ea35d135,
+                    0x9b85b36b222d293c,
+                    0x246be5415f26e8f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7aae22773d41f134,
+                    0x952fae5813594d6f,
+                    0x6da04db0b58f46ac,
+                    0x34ee1d44d4faf468,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69abd7679e8a9ae8,
+                    0x4560f3c029217b21,
+                    0x565ea25d578d3af9,
+                    0x60a1772a32a0e05b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd9df420ec6898178,
+                    0x1035ad34cc820c66,
+                    0x39457928c206f4ef,
+                    0x7f9ae6faed34ea96,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5c6e6724890e5f69,
+                    0x0d9a76207417e757,
+                    0xad61d20b58dbad69,
+                    0x68329eb6f5071904,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7caf25aaaf504def,
+                    0x98fa082d66d9927a,
+                    0x49b27a8a8f047fa8,
+                    0x2e97f8a349d81010,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80993df5b73b237c,
+                    0x085ad50067ba48d7,
+                    0xf3e62f69361752dc,
+                    0x0f938279e35c8adc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf09eba75f1480b64,
+                    0x58117ebfb9d71424,
+                    0xf10900c8bec90ee3,
+                    0x155f5e840bd5fc44,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2eee93ba0fef6a12,
+                    0x8d140e623b85e955,
+                    0xd91807aebd81eadc,
+                    0x5de63bef7914c6e7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0591c21005eaf393,
+                    0x6ff650135c789b4a,
+                    0xcd1f85981247343f,
+                    0x61a071c377a122be,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc9fe3b70d8f1008,
+                    0x643ffa9518894ab7,
+                    0xa1a7ac7a0c6e8298,
+                    0x35ef4d5b4862b3bc,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000072,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000010a,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000072,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000010a,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x0000000000000072,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000010a,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000072,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000010a,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x502b292101ab4066,
+                    0x2aa92cdf7f61c0e8,
+                    0x549094cbf9e3ef42,
+                    0x12c5d00471b6ef68,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8f323f4e57e49d59,
+                    0xbae86d62ab395284,
+                    0x6090efdc7b007a9d,
+                    0x4d108f266dc91e76,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb174669742fd012,
+                    0xac193a5c41f19e99,
+                    0x3984428812a207c6,
+                    0x1af8036f170e9469,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0829472616be09f,
+                    0x9f9f3194f516b443,
+                    0xeda00f37bb22375b,
+                    0x55463b8fe43ad5b1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x66554e5c8c0ef43e,
+                    0x378ada51016c31fc,
+                    0xd2d518aa8bab280f,
+                    0x18cac1e31625b523,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec7e5886bc5b37a2,
+                    0x4b6e026f4a01dc89,
+                    0x09c7f89973ea6881,
+                    0x5512973f10069372,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63a008d774d4c138,
+                    0x1bb6c1d073352e41,
+                    0x60ecf79913ccb777,
+                    0x1e2cfa1316cf22b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6541319b736a420a,
+                    0x6fe1b72d89102a9d,
+                    0x689b5c27b886f714,
+                    0x7c4bdee550e9f918,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x961a298cd2ba35de,
+                    0x88317ccfa0219787,
+                    0xfafe4645cac6071e,
+                    0x5d2781fefafb72b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x598b4215999365fc,
+                    0xba3e0d85bb1697e8,
+                    0x37db3eab8f62fe0e,
+                    0x29289772dd9d1f80,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b5a1daf258a3aff,
+                    0x46fd2a44d4a4b79c,
+                    0x9da0f63678c65056,
+                    0x24b4538d392aeee4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ddf20838a31f732,
+                    0x188f622bdb8f2077,
+                    0x58a1459a3f1ad0c2,
+                    0x6bf68631a4cd2529,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5e5ba61b70ef78a5,
+                    0x63362a1bf533738e,
+                    0xfcf476e51ba9e11e,
+                    0x3c8b835c5b3e32be,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3102ede6106a3054,
+                    0x631f59f8d49cb852,
+                    0x956ea1355980eb79,
+                    0x1d82ebfa15a34e72,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5f081c56190e7d63,
+                    0x6fc050704fd41600,
+                    0x319f3dbec2abc852,
+                    0x4a7db1b3dc96c92c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x38be5b236f5eec0d,
+                    0x74b04f38b3017970,
+                    0xd0dec92e3ae6d751,
+                    0x49e980ac8e2886c0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x92bc1057d8a64070,
+                    0x2b6a9975b4b1db15,
+                    0x94091d3f73be4fc1,
+                    0x50002468d7431c9f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81e687d43a5ae06f,
+                    0x646fa90bdd7b9d82,
+                    0xd5740c8a40584ceb,
+                    0x0832e788a38d5243,
+                ])),
+                Felt::new(BigInteger256([
+                    0x79f7894b15dbbf5a,
+                    0x3a4ee55b356d6dcc,
+                    0x874b247e5420522d,
+                    0x6cc373c0770caafd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x709e3840db07ccc9,
+                    0x0d97d2e3d1b46e13,
+                    0x5848c38737452403,
+                    0x081ee02437f1660b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4e7be1ea1126df9b,
+                    0x72399b8430e2e6cf,
+                    0xa339102639cce49c,
+                    0x512bd662fdff8a14,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcbe6578e323d296e,
+                    0x7187456a9d4bb6f3,
+                    0x1f8a27fb311bb415,
+                    0x003de8b57513db11,
+                ])),
+                Felt::new(BigInteger256([
+                    0x07c367d71964f741,
+                    0x442c9abbd17a814c,
+                    0x3be6b7aa30fceb17,
+                    0x793030e237a4f93a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x151891be38b4e23b,
+                    0xf84f858aff6d9de2,
+                    0x44ecf4ec74410a6d,
+                    0x5400d387e6eb1533,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000000000050c,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0xe44705a306ce253f,
+            0x675743cbdac6074b,
+            0x336523f190a1aac2,
+            0x78312726b01a2936,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc91c450305aea9f3,
+            0x8ba82b557a153133,
+            0xa926b4eebe535fcf,
+            0x74de03beeca7e7ec,
+        ])),
+        Felt::new(BigInteger256([
+            0xd57ae39b2f49bdef,
+            0x59ddc994528ad2c1,
+            0x8ec82763a087f025,
+            0x406cde8bdb52dbfd,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeabcd3f932bf75ca,
+            0x5462a57a75221fd8,
+            0xe0401ffc8b818ad4,
+            0x368aff19f3cd3056,
+        ])),
+        Felt::new(BigInteger256([
+            0x566530f0780e7e50,
+            0x1c7310f52d5dc538,
+            0xc4756f05b6a0da3e,
+            0x665f586f72c90952,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2b76347555535d5c,
+            0xa0bd73410e617c7a,
+            0x0202bafd84b253c1,
+            0x74cd3813ab4c743c,
+        ])),
+        Felt::new(BigInteger256([
+            0xaa6ad7f19a4e1f37,
+            0xf9b2e003a1787731,
+            0x4c0285278a266c67,
+            0x4250fc8654a6c580,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc3122796a7ac5a2f,
+            0x5c48a2544850e7f2,
+            0x7bf385e3cc945556,
+            0x4e77b9d15e137fb8,
+        ])),
+        Felt::new(BigInteger256([
+            0xafb10334e4d9d4a3,
+            0x4ba4a17e8016654c,
+            0x1c5ea885655f8574,
+            0x78990c5737e9936a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x130812fee00d6c64,
+            0x818d215545de14fe,
+            0x9aba4b68bbb4297d,
+            0x2838baa79d11aa4f,
+        ])),
+        Felt::new(BigInteger256([
+            0x3e05b1865e82c77a,
+            0x67b74e159ee9219f,
+            0x75da67c4502ca321,
+            0x3d1986fe13ad31ce,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3bec9e832fec92ce,
+            0x50900c9fc2d58b32,
+            0x3752456293ce4b5d,
+            0x293526c620b2472a,
+        ])),
+        Felt::new(BigInteger256([
+            0xf4b5e23f41b7c325,
+            0x8cffca539cb06104,
+            0xe087c3e1b58765fe,
+            0x0163d29e1b9a50f5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6fb39b73b190ae3b,
+            0x2a9ab3b87e9de3d6,
+            0x41f12658bc6cb7c5,
+            0x0046a5a911cf0aa9,
+        ])),
+        Felt::new(BigInteger256([
+            0x62d4c0c2b8bdfa6f,
+            0x0ee565a9de74f6a0,
+            0x2ded9cf1c4870949,
+            0x0d55573af8e89e7b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x40857658b93ccfb3,
+            0xf4eed7d07dc3101b,
+            0x1f9f8eb6be1cdcbb,
+            0x36709391986752b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x73ab2da2e1251558,
+            0x6eed0de3bfc7ce94,
+            0x08bfc2f8290157dc,
+            0x2c8d1a8d95137c60,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xed0c810f4cdd09dd,
+            0x81335422994f28a5,
+            0x523bf0db426df5d3,
+            0x0e8701170f63e607,
+        ])),
+        Felt::new(BigInteger256([
+            0xda4c317fee84d5c3,
+            0xc76c9e9e11ad8471,
+            0x314b7682940355a0,
+            0x58e7cf7a565c0757,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000000000051f,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0x745b1e25e12bf397,
+            0x436c1968219a35b9,
+            0xd4de3b89f4d54133,
+            0x25654811d61b85a4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xdad044d3398a68e4,
+            0xfddd58bd90825079,
+            0xe99fac6bc4d4725c,
+            0x3f5abc480e3a3978,
+        ])),
+        Felt::new(BigInteger256([
+            0x7742fbee3d834b12,
+            0xa827cc98afcc2075,
+            0x70ba36790b3c9923,
+            0x381db80022e689f8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x761339f1b53f5441,
+            0xa326cbc4a17b3058,
+            0x271cdc55c67a62b2,
+            0x0b968981a546aafc,
+        ])),
+        Felt::new(BigInteger256([
+            0x71cfaf6bd4ec2af9,
+            0x474c0cdba08b0425,
+            0xaccb42f755cd488d,
+            0x689f03c24a43e066,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8a7a6dbc75f00c15,
+            0xa35619fb4ace1501,
+            0x36e94c74db4df95e,
+            0x66d3fb32e44108b7,
+        ])),
+        Felt::new(BigInteger256([
+            0x998329bb95489c22,
+            0xd8605c5a24b93e26,
+            0x22622e3744f5a875,
+            0x618be090b39cb66a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb240901d22e2ff27,
+            0x57d670549bb27755,
+            0xe0d8282da5533333,
+            0x0f84015a3d75cdf9,
+        ])),
+        Felt::new(BigInteger256([
+            0x2ef3843e3a6e47cd,
+            0x2347451b1a4c231d,
+            0x22bc6267a251f9c2,
+            0x66d974cb3d4d3e1a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfaeb5cedab1559ab,
+            0xe8d09e95da25b0ba,
+            0x3c0b87b522b2cb32,
+            0x10a7a00525709e0e,
+        ])),
+        Felt::new(BigInteger256([
+            0xb5fd13f803e882f3,
+            0xab0fa0f27a04ebc9,
+            0xb8a4bba91b5edb47,
+            0x52bc8d46c20d81fb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9ec3d0a1aa55a6e5,
+            0xf1a7627380a59630,
+            0x9c1d38f15342b7f6,
+            0x0f034e2ee3ab0bbf,
+        ])),
+        Felt::new(BigInteger256([
+            0xe7a12ce0967ea56e,
+            0x0a2bf5c3a1549a70,
+            0xe6cbcf08d92f6909,
+            0x14661af2049471f9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6d099d2558b0deec,
+            0xe623d1c3461c6020,
+            0xc7e86dfb791d36a4,
+            0x1d09faa00e9ee2aa,
+        ])),
+        Felt::new(BigInteger256([
+            0xf03edaf73a3bf952,
+            0xa6835950ecc7a157,
+            0x555dfc2ce56b1e99,
+            0x574ccd1d1bb9d2eb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x89654864f8a8a586,
+            0xe53d21be08b58181,
+            0x327967e3fc107ab4,
+            0x0937f06f0607ed22,
+        ])),
+        Felt::new(BigInteger256([
+            0x4c9f1831faeeb95d,
+            0x3b502d6d918e6e68,
+            0xbd12b3bdcb288c46,
+            0x2c88985628b5733c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xddd8564edff31111,
+            0xcb191857b60ed7c0,
+            0x2223318d2b3856c7,
+            0x210df35ae6653bfc,
+        ])),
+        Felt::new(BigInteger256([
+            0x5b2c1f425bf8ab3c,
+            0xed67386f754161fa,
+            0xa2abcecce1014d05,
+            0x18a2e2a9535eb9ba,
+        ])),
+    ],
+];
@@ -0,0 +1,739 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000000000050c,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0xe44705a306ce253f,
+            0x675743cbdac6074b,
+            0x336523f190a1aac2,
+            0x78312726b01a2936,
+        ])),
+        Felt::new(BigInteger256([
+            0x4fa4523589eebabf,
+            0x20b7de3392fe815f,
+            0x89c800bd1dd2392b,
+            0x65a7418aaf933ed9,
+        ])),
+        Felt::new(BigInteger256([
+            0x179f60d2193ffb02,
+            0x68e79217e8c0f7eb,
+            0x268e5a06cc56c190,
+            0x329509675d297907,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc91c450305aea9f3,
+            0x8ba82b557a153133,
+            0xa926b4eebe535fcf,
+            0x74de03beeca7e7ec,
+        ])),
+        Felt::new(BigInteger256([
+            0xd57ae39b2f49bdef,
+            0x59ddc994528ad2c1,
+            0x8ec82763a087f025,
+            0x406cde8bdb52dbfd,
+        ])),
+        Felt::new(BigInteger256([
+            0xfb5c5e7bd12c62b9,
+            0x0b3cecf05d808b3a,
+            0x817712d684d2798e,
+            0x0b7f52957fc0c0eb,
+        ])),
+        Felt::new(BigInteger256([
+            0xa4e5e85e25cfd76c,
+            0x8eecd70e67f801c3,
+            0x8ca889eed8922649,
+            0x00ce62bca051dbb0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeabcd3f932bf75ca,
+            0x5462a57a75221fd8,
+            0xe0401ffc8b818ad4,
+            0x368aff19f3cd3056,
+        ])),
+        Felt::new(BigInteger256([
+            0x566530f0780e7e50,
+            0x1c7310f52d5dc538,
+            0xc4756f05b6a0da3e,
+            0x665f586f72c90952,
+        ])),
+        Felt::new(BigInteger256([
+            0x27ce1f012e98ee8b,
+            0xca50d98af0a3667e,
+            0xc1267e6262acbae4,
+            0x4a8a1d98bf5076dd,
+        ])),
+        Felt::new(BigInteger256([
+            0xe69bf88e85fdc07f,
+            0x9c2337fd9ae09f4d,
+            0xb41d43d3d3106716,
+            0x7cc319899b031728,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2b76347555535d5c,
+            0xa0bd73410e617c7a,
+            0x0202bafd84b253c1,
+            0x74cd3813ab4c743c,
+        ])),
+        Felt::new(BigInteger256([
+            0xaa6ad7f19a4e1f37,
+            0xf9b2e003a1787731,
+            0x4c0285278a266c67,
+            0x4250fc8654a6c580,
+        ])),
+        Felt::new(BigInteger256([
+            0x25f0fb1889088988,
+            0xd5ad9058755c6885,
+            0xa142b0f145c07173,
+            0x0c54b07076412aba,
+        ])),
+        Felt::new(BigInteger256([
+            0xfd546954ea3dbbc7,
+            0xf02623d80640ed76,
+            0x33417e448c98c46e,
+            0x0c5c2de897a0a179,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc3122796a7ac5a2f,
+            0x5c48a2544850e7f2,
+            0x7bf385e3cc945556,
+            0x4e77b9d15e137fb8,
+        ])),
+        Felt::new(BigInteger256([
+            0xafb10334e4d9d4a3,
+            0x4ba4a17e8016654c,
+            0x1c5ea885655f8574,
+            0x78990c5737e9936a,
+        ])),
+        Felt::new(BigInteger256([
+            0x8c9b472dd4e60d6a,
+            0x446c5d12484caba4,
+            0x2e57e2a26e356f35,
+            0x2f1eec9f7ca843b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x6be49522140c7b3b,
+            0xe2e158ca0a7a20cd,
+            0x03a028d50c6ef605,
+            0x643a540d1efed0f6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x130812fee00d6c64,
+            0x818d215545de14fe,
+            0x9aba4b68bbb4297d,
+            0x2838baa79d11aa4f,
+        ])),
+        Felt::new(BigInteger256([
+            0x3e05b1865e82c77a,
+            0x67b74e159ee9219f,
+            0x75da67c4502ca321,
+            0x3d1986fe13ad31ce,
+        ])),
+        Felt::new(BigInteger256([
+            0x370753747a3a046b,
+            0x390aaa0582ea19bf,
+            0x00a8de2bcd1c7d3a,
+            0x7ae40899bfe045b0,
+        ])),
+        Felt::new(BigInteger256([
+            0x831c98ebebbcdabb,
+            0x59d9fbabbf8e32ac,
+            0x18f8fe5b01abcbf1,
+            0x454d544111dbde5c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3bec9e832fec92ce,
+            0x50900c9fc2d58b32,
+            0x3752456293ce4b5d,
+            0x293526c620b2472a,
+        ])),
+        Felt::new(BigInteger256([
+            0xf4b5e23f41b7c325,
+            0x8cffca539cb06104,
+            0xe087c3e1b58765fe,
+            0x0163d29e1b9a50f5,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d3007505e5ad217,
+            0xcec62a383d5b98dd,
+            0xf303a52016ca74ff,
+            0x73c2b04140b794de,
+        ])),
+        Felt::new(BigInteger256([
+            0x6a8127d26cfe4242,
+            0x03775ad78a127d30,
+            0x83b43390a62f500d,
+            0x6cd24e818be2c8b3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6fb39b73b190ae3b,
+            0x2a9ab3b87e9de3d6,
+            0x41f12658bc6cb7c5,
+            0x0046a5a911cf0aa9,
+        ])),
+        Felt::new(BigInteger256([
+            0x62d4c0c2b8bdfa6f,
+            0x0ee565a9de74f6a0,
+            0x2ded9cf1c4870949,
+            0x0d55573af8e89e7b,
+        ])),
+        Felt::new(BigInteger256([
+            0x58d6caf9afc2df6c,
+            0x83fd56ff0ab518fd,
+            0xd71971d482697ef6,
+            0x7875f0a6db9368be,
+        ])),
+        Felt::new(BigInteger256([
+            0x70bfb294c723e66c,
+            0x5d4b8f1e668b3dc4,
+            0xe36e3fc26629c7af,
+            0x3c9cc4b012890c20,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x40857658b93ccfb3,
+            0xf4eed7d07dc3101b,
+            0x1f9f8eb6be1cdcbb,
+            0x36709391986752b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x73ab2da2e1251558,
+            0x6eed0de3bfc7ce94,
+            0x08bfc2f8290157dc,
+            0x2c8d1a8d95137c60,
+        ])),
+        Felt::new(BigInteger256([
+            0x96b7b1488d02f9d8,
+            0xb1a1374ec866271d,
+            0x1a98c2541a098d79,
+            0x3656048d6124bc54,
+        ])),
+        Felt::new(BigInteger256([
+            0x820de73bab9922b5,
+            0x0201643f8f80236d,
+            0x26138529d9ea7323,
+            0x4f262a29fb0481e3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xed0c810f4cdd09dd,
+            0x81335422994f28a5,
+            0x523bf0db426df5d3,
+            0x0e8701170f63e607,
+        ])),
+        Felt::new(BigInteger256([
+            0xda4c317fee84d5c3,
+            0xc76c9e9e11ad8471,
+            0x314b7682940355a0,
+            0x58e7cf7a565c0757,
+        ])),
+        Felt::new(BigInteger256([
+            0xfa4e5f51a2e1b08f,
+            0xe817f298d618faa2,
+            0x1a9cb115ed365344,
+            0x505fe499e0466523,
+        ])),
+        Felt::new(BigInteger256([
+            0xf38a0ba19f29ed7a,
+            0x5b562b243172311b,
+            0x5f4d456e2b4e6e9b,
+            0x4a54a509aa030578,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbee2f06fe5a835da,
+            0xd59e0b3e611e23cb,
+            0x1f462f1f0fd853f6,
+            0x60cfbdb78334774e,
+        ])),
+        Felt::new(BigInteger256([
+            0xb214e9fccb0fe184,
+            0x10cf293bfee95a07,
+            0x37e787bb6c108fe8,
+            0x7870be22c4b7a229,
+        ])),
+        Felt::new(BigInteger256([
+            0xaa601c9b782e1ab9,
+            0x1a2dce2960545c64,
+            0x5d6b7ef8e4f37505,
+            0x12d471872e7005c0,
+        ])),
+        Felt::new(BigInteger256([
+            0xae792c1c0282a91f,
+            0x5fae3b5e9b955bc4,
+            0x914f7423689a5fa3,
+            0x6ad63264027e9e84,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x601aef5941adb0f5,
+            0x6bdc76941b848260,
+            0xbfc87b068c64e211,
+            0x01c6ad81837fd91c,
+        ])),
+        Felt::new(BigInteger256([
+            0x1e814aba5eeab529,
+            0x3ef6ac0b47c6cce4,
+            0xafc0c63efa78904b,
+            0x11db3ac874fb0e6d,
+        ])),
+        Felt::new(BigInteger256([
+            0x7a635617a0c29b55,
+            0xb802a6350fb5ba0d,
+            0x6d70eddcea28b567,
+            0x2a3a6dbe7351af07,
+        ])),
+        Felt::new(BigInteger256([
+            0x18c4237929afca2a,
+            0x0c3eaa04947cf158,
+            0xf5e39e34a26f5559,
+            0x3e00373f7224732d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x45bda1f26ab1ac73,
+            0x481cdaa312fe4b83,
+            0x0f0766c044c4b377,
+            0x5b7cbb28698642f1,
+        ])),
+        Felt::new(BigInteger256([
+            0x8f65d4de01c58e21,
+            0xf40daa8679aafc0e,
+            0x7b89162458c2830a,
+            0x145e15401967e4a6,
+        ])),
+        Felt::new(BigInteger256([
+            0xb273447b34e785c2,
+            0x9f6971077eda9001,
+            0x72a40c8c545bac58,
+            0x1071aa07d005644e,
+        ])),
+        Felt::new(BigInteger256([
+            0x152d6f2a7c14d9ae,
+            0x44cd3b1b90b9721d,
+            0xd110588fa12954b3,
+            0x2b39837ddf157d67,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb0da99cd229b6e9b,
+            0xa672cbfe46b9797c,
+            0x0e40b6d0944a8bfa,
+            0x65be0729ff1e60dc,
+        ])),
+        Felt::new(BigInteger256([
+            0x19eafe663ad25390,
+            0xaacbbf3a978e18c2,
+            0x1fc0197ecd5755e7,
+            0x04f57f0a8d862ce9,
+        ])),
+        Felt::new(BigInteger256([
+            0x6e4e400ede913e20,
+            0x557990367623b404,
+            0x0862c9b26750063f,
+            0x5f3e94b5f2c0af22,
+        ])),
+        Felt::new(BigInteger256([
+            0xf004dec285566ff6,
+            0x03c4f67c627ce96b,
+            0xde3489f03c591f38,
+            0x442069f35f9998cd,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000000000051f,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0x745b1e25e12bf397,
+            0x436c1968219a35b9,
+            0xd4de3b89f4d54133,
+            0x25654811d61b85a4,
+        ])),
+        Felt::new(BigInteger256([
+            0x93f1d3aea8867f90,
+            0x1002c08e1929b670,
+            0xea31a5cbc144ebff,
+            0x361efc29662778d9,
+        ])),
+        Felt::new(BigInteger256([
+            0xe09c12551216bd35,
+            0xeddfb9507c28eae2,
+            0x96a6a881ea9a7ba3,
+            0x759e7ffc5c5f90bb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xdad044d3398a68e4,
+            0xfddd58bd90825079,
+            0xe99fac6bc4d4725c,
+            0x3f5abc480e3a3978,
+        ])),
+        Felt::new(BigInteger256([
+            0x7742fbee3d834b12,
+            0xa827cc98afcc2075,
+            0x70ba36790b3c9923,
+            0x381db80022e689f8,
+        ])),
+        Felt::new(BigInteger256([
+            0x515ddfc5239fe655,
+            0x6cbcfcb2fa18df92,
+            0x2259af622ec63eef,
+            0x2673c5bd57e74c78,
+        ])),
+        Felt::new(BigInteger256([
+            0x7f9699b15282587d,
+            0x861a2baf11cd1401,
+            0x3d39cfe6fd56f2ea,
+            0x0e5491dac11a44f1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x761339f1b53f5441,
+            0xa326cbc4a17b3058,
+            0x271cdc55c67a62b2,
+            0x0b968981a546aafc,
+        ])),
+        Felt::new(BigInteger256([
+            0x71cfaf6bd4ec2af9,
+            0x474c0cdba08b0425,
+            0xaccb42f755cd488d,
+            0x689f03c24a43e066,
+        ])),
+        Felt::new(BigInteger256([
+            0xf7720672cfb091ad,
+            0x085fe22fa327ac0e,
+            0x686cdfca41184597,
+            0x700d629f275e2b83,
+        ])),
+        Felt::new(BigInteger256([
+            0x3aef100a01546129,
+            0x6fdf85805aa1a2c5,
+            0x6b124ea82c4cf908,
+            0x14d81a864bb2a982,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8a7a6dbc75f00c15,
+            0xa35619fb4ace1501,
+            0x36e94c74db4df95e,
+            0x66d3fb32e44108b7,
+        ])),
+        Felt::new(BigInteger256([
+            0x998329bb95489c22,
+            0xd8605c5a24b93e26,
+            0x22622e3744f5a875,
+            0x618be090b39cb66a,
+        ])),
+        Felt::new(BigInteger256([
+            0xc942b5d8c83cfcec,
+            0xc791196d37f4361d,
+            0x3692e7773fcec9e4,
+            0x4ed32e2e65c9f936,
+        ])),
+        Felt::new(BigInteger256([
+            0x2555541f03b12ca0,
+            0x77b6f1cad61578f6,
+            0xd8405e370178241f,
+            0x416c679ccfcb4da8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb240901d22e2ff27,
+            0x57d670549bb27755,
+            0xe0d8282da5533333,
+            0x0f84015a3d75cdf9,
+        ])),
+        Felt::new(BigInteger256([
+            0x2ef3843e3a6e47cd,
+            0x2347451b1a4c231d,
+            0x22bc6267a251f9c2,
+            0x66d974cb3d4d3e1a,
+        ])),
+        Felt::new(BigInteger256([
+            0xc017312d6eb4770d,
+            0x2f450d6d21d97018,
+            0xf3a629faea66ffe6,
+            0x40a2eec7129ecbf5,
+        ])),
+        Felt::new(BigInteger256([
+            0x240faf2b8819e253,
+            0x63674e02f143a328,
+            0xd89d199a03718df6,
+            0x6850122afd9736eb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfaeb5cedab1559ab,
+            0xe8d09e95da25b0ba,
+            0x3c0b87b522b2cb32,
+            0x10a7a00525709e0e,
+        ])),
+        Felt::new(BigInteger256([
+            0xb5fd13f803e882f3,
+            0xab0fa0f27a04ebc9,
+            0xb8a4bba91b5edb47,
+            0x52bc8d46c20d81fb,
+        ])),
+        Felt::new(BigInteger256([
+            0x63381edc63d9b670,
+            0x8f9909a09d5cea8d,
+            0x0263bf86d78dd1c3,
+            0x33caa895fed3736f,
+        ])),
+        Felt::new(BigInteger256([
+            0x33fc945daf9b8a22,
+            0x4615a024e73dc161,
+            0x2a62892286ee27ba,
+            0x70c5b0339970e9cf,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9ec3d0a1aa55a6e5,
+            0xf1a7627380a59630,
+            0x9c1d38f15342b7f6,
+            0x0f034e2ee3ab0bbf,
+        ])),
+        Felt::new(BigInteger256([
+            0xe7a12ce0967ea56e,
+            0x0a2bf5c3a1549a70,
+            0xe6cbcf08d92f6909,
+            0x14661af2049471f9,
+        ])),
+        Felt::new(BigInteger256([
+            0x0454bae7f75baaec,
+            0x5f2862668156d8ed,
+            0xb8383dbd79b1946d,
+            0x2a089248ba449374,
+        ])),
+        Felt::new(BigInteger256([
+            0x96550b73e03e188c,
+            0x2986d7e3db4a7b26,
+            0x5897759a83e776ba,
+            0x15a9ec7f4e11a4fd,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6d099d2558b0deec,
+            0xe623d1c3461c6020,
+            0xc7e86dfb791d36a4,
+            0x1d09faa00e9ee2aa,
+        ])),
+        Felt::new(BigInteger256([
+            0xf03edaf73a3bf952,
+            0xa6835950ecc7a157,
+            0x555dfc2ce56b1e99,
+            0x574ccd1d1bb9d2eb,
+        ])),
+        Felt::new(BigInteger256([
+            0x9a7a4e24757ad4db,
+            0x2ed15764585eca58,
+            0xbd7a5e85e28cb0aa,
+            0x65b1003c8ef77ac0,
+        ])),
+        Felt::new(BigInteger256([
+            0x371265c9671ad950,
+            0x9dccd461c171ad06,
+            0xd97dd5e0411e00a2,
+            0x1c69903c0e8efbd6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x89654864f8a8a586,
+            0xe53d21be08b58181,
+            0x327967e3fc107ab4,
+            0x0937f06f0607ed22,
+        ])),
+        Felt::new(BigInteger256([
+            0x4c9f1831faeeb95d,
+            0x3b502d6d918e6e68,
+            0xbd12b3bdcb288c46,
+            0x2c88985628b5733c,
+        ])),
+        Felt::new(BigInteger256([
+            0x23e504cdeb069456,
+            0x913a6396d983cd95,
+            0x8ddc408ffb6fde46,
+            0x59951c09855990c2,
+        ])),
+        Felt::new(BigInteger256([
+            0x93ea6acae3dbbaa8,
+            0x7747d565adda87cb,
+            0xa905acd23621cb2f,
+            0x64f6fd9c67db3405,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xddd8564edff31111,
+            0xcb191857b60ed7c0,
+            0x2223318d2b3856c7,
+            0x210df35ae6653bfc,
+        ])),
+        Felt::new(BigInteger256([
+            0x5b2c1f425bf8ab3c,
+            0xed67386f754161fa,
+            0xa2abcecce1014d05,
+            0x18a2e2a9535eb9ba,
+        ])),
+        Felt::new(BigInteger256([
+            0x2f67b60a548f7c81,
+            0x214899287903decf,
+            0x4aed96d67973670d,
+            0x335e917c6ddbf518,
+        ])),
+        Felt::new(BigInteger256([
+            0xad5292642b16b6e1,
+            0x2a341691e199d32e,
+            0x9f4cd49b325c89a3,
+            0x1fe50de2803a7321,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x011aadac3e0a84cd,
+            0xcc219335e94fa3d6,
+            0xbc2918ad7692b7b9,
+            0x7620e1391537b595,
+        ])),
+        Felt::new(BigInteger256([
+            0x8460bfbbfdcffebc,
+            0xe36786cfcdef087f,
+            0x764388e236fe8a1b,
+            0x3af6028f7cbc3cdf,
+        ])),
+        Felt::new(BigInteger256([
+            0x30e55b50ef282e57,
+            0xfffc387b6eb11180,
+            0x5ab80d95ef208b9b,
+            0x789d4fa777077e08,
+        ])),
+        Felt::new(BigInteger256([
+            0xb9ad9adb53bbba45,
+            0xdb29ea8eb72ecec6,
+            0x9e4aac2ced987d79,
+            0x4330cc7a93b7f480,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xce28d81a3532a314,
+            0x6381ea6eb7bdcb1e,
+            0xa0b4b318348803c6,
+            0x49be2e44c8a432fc,
+        ])),
+        Felt::new(BigInteger256([
+            0x1ca34bfe2ccd758d,
+            0x12b0f5822ad44410,
+            0x322615e906cf4871,
+            0x0706dc76e020c4bc,
+        ])),
+        Felt::new(BigInteger256([
+            0x2cbec051b2df5232,
+            0x9ef2fc6a321a37dd,
+            0xaec6cafd35be89f0,
+            0x42a9a9206f0a42e7,
+        ])),
+        Felt::new(BigInteger256([
+            0x4fcebdbd160b7e7c,
+            0x88dc4517c41e2d0e,
+            0x46e824c168d63121,
+            0x49012e97b67ee4c2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2f28ee8ad59100b1,
+            0xc0504baa92d16baf,
+            0x51b98c8bc258752b,
+            0x6359eda3ade8e7ff,
+        ])),
+        Felt::new(BigInteger256([
+            0x08e539f94702b091,
+            0x4855f12a40524aa8,
+            0x5fb453883a89db30,
+            0x496f68a683cbe623,
+        ])),
+        Felt::new(BigInteger256([
+            0xe02c128cbe5e9eab,
+            0x06e7c46984d8e53e,
+            0x15bfd766756220e1,
+            0x68c69721cafc435d,
+        ])),
+        Felt::new(BigInteger256([
+            0xc7956d45dfcaf00c,
+            0x41f8d35ba3f48540,
+            0x83daccd63d00d07b,
+            0x76202c8e22ae3a2a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x61574bca2b365d67,
+            0xc15df7f1a8fd7179,
+            0x26f34dec449c9eda,
+            0x59d42a39350dbfb6,
+        ])),
+        Felt::new(BigInteger256([
+            0x5a7bc8e61dcb108e,
+            0xa1cbc0ca40a63f2c,
+            0xd9ebc832e1dcff38,
+            0x263fc304e976e831,
+        ])),
+        Felt::new(BigInteger256([
+            0x6318738505c3f1aa,
+            0x5faf9e845e92e112,
+            0x817f05dcbb14cbf3,
+            0x23cc7263df4447fc,
+        ])),
+        Felt::new(BigInteger256([
+            0x697e424286c820e2,
+            0xa3a849a85828d460,
+            0x66ff6f870aeeec2b,
+            0x7b40039794bf0f5c,
+        ])),
+    ],
+];
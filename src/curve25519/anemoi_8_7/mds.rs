@@ -0,0 +1,108 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 3 2 2]
+/// [4 6 3 5]
+/// [4 4 1 3]
+/// [3 5 2 3]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000000000072,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x000000000000004c,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x000000000000004c,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000000000098,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000000000000e4,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000000000072,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000000000000be,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000000000098,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000000000098,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000000000072,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000000000072,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000000000000be,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x000000000000004c,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000000000072,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+];
@@ -0,0 +1,1711 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa9c049e61804bd74,
+                    0x3bd97732614cff04,
+                    0xbb3c657fb349984a,
+                    0x45ee86cabd25532a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2a823e16f30aff0,
+                    0x3e3fc7927a545006,
+                    0x3115ab0ae9124c89,
+                    0x0b8a2ffd8e3ae081,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69b10c1da48b0d23,
+                    0x83856d6144a52d85,
+                    0x7df19a045a34b2b5,
+                    0x2f2b119ce75f476a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x764ab7c3f683bd9d,
+                    0xbf41410e434668e8,
+                    0x62294a584df08af8,
+                    0x58198d0f9d72e49d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ec28b03504ca018,
+                    0x9d64fba774805366,
+                    0x5d4e397f14d83bf8,
+                    0x0afa594d41c8e786,
+                ])),
+                Felt::new(BigInteger256([
+                    0x27c079e6a9417720,
+                    0x933088dc97602707,
+                    0x8c668e007ae3e4f8,
+                    0x725d4dbd9b580457,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd4b923bfb181055a,
+                    0x9651c41556db5743,
+                    0x8e3355b611841b45,
+                    0x41236b0a7ec7da84,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba355b010e33e022,
+                    0x3e572925ab329587,
+                    0x22e4492611b73c0f,
+                    0x4b04895e4f4ea47e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3440014b19f77c4a,
+                    0x4eb3838fd81c23e9,
+                    0xbf62693206dff4c6,
+                    0x0874b8b87d5cc4dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2125b6c1c25138eb,
+                    0x0e05b953c44acedb,
+                    0x29f7943f82cc6f20,
+                    0x3d11293b11018a56,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5640282da7464445,
+                    0x2c2e61c944827857,
+                    0xd76250febdaff4ec,
+                    0x5409a63832e9c036,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf872161eada6f3f7,
+                    0xfad797172927e455,
+                    0xed20d6854cc94cec,
+                    0x5ed57cb0478b3606,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc414944e7137bcae,
+                    0x094e00b4e6d741c3,
+                    0x037f6db3f917e80d,
+                    0x63e3d8bc56b776a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x08f39884f6dd136a,
+                    0x3e06eebfd3a4b98a,
+                    0x7b7ff4ae022825ec,
+                    0x79c69252bd6f902f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd741e6b1fb84e4ce,
+                    0x4f2a33265ac32453,
+                    0xed4d026c5a8b4840,
+                    0x714f38c7bfeffadd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x33d7c78f1f581b4b,
+                    0x87dcc2bc4e53be6e,
+                    0x0f3ade095592fb38,
+                    0x29580ccf3a3c1ef4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x32fe323ee3cbbc45,
+                    0x73909d58169d0eef,
+                    0x105dcd011966d678,
+                    0x18dc38cfab2f4146,
+                ])),
+                Felt::new(BigInteger256([
+                    0xad4e307e2d9767d8,
+                    0x8948c947bf51b9c4,
+                    0x6cf952259a7a34de,
+                    0x0ee188fcb6624561,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2be97228ab8a361,
+                    0x5d6a5fafcaeb8998,
+                    0xacbe11d4afcff0d2,
+                    0x1e1a82fc6dbc3feb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x085c2f55131e1438,
+                    0x536d57d03b790951,
+                    0x9a178698a02313da,
+                    0x42725e308060e09e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0cc83ad5a684d023,
+                    0xfa7a35583be99cbd,
+                    0x4ca41eb1eac16562,
+                    0x76df6f9bce36909a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5dc49db65c399ee9,
+                    0x84c9121cf048c87b,
+                    0xf9a963de40977e19,
+                    0x260d8125bca5fe0e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99334e1f85ea740c,
+                    0x4fcb170234a4c1ea,
+                    0x4780caa340c07ab8,
+                    0x7f264957a0fcbdc5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x44a7fbe795e0b0fe,
+                    0x78a1e0aa4f2b7f53,
+                    0x3b1623d588cae377,
+                    0x4a592070a713e3ac,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x942f00c31533fc04,
+                    0xc0927d0a2d9735e1,
+                    0x719d79eb0a7a696c,
+                    0x5b079810b0976f2d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77c13a2cd27de714,
+                    0x0f3dd4879f33bb9f,
+                    0xdd5927c01b846628,
+                    0x17e262674be5550b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8072e8d64be60ef9,
+                    0x1c9591a61549eb4e,
+                    0x91f11c787055a9ad,
+                    0x73fe554a00d79e6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f9dd878dc08f05e,
+                    0x5996d6b877b04998,
+                    0xc8e4f1389d2ea0bb,
+                    0x726943d22fa0b5d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x015cd9d114f4c713,
+                    0xa01cebd1171f32cc,
+                    0xc7a8058322a5c673,
+                    0x14157a9b2de4762c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x903930fe23f4cc0a,
+                    0x313f591604a979b8,
+                    0xa49d2f92e7accda6,
+                    0x57bd72e3e587dbfc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x802b648f76b51610,
+                    0x0b677e1ec2e4678a,
+                    0x84664aad0bdb3f32,
+                    0x158d0e016cd6b62f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf03fda5c92946afa,
+                    0x00bfcdd7a31defbe,
+                    0xbb063d22826d02f5,
+                    0x4613ca2cc3fa0765,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x28ce1f092102ecb1,
+                    0x005588dc0f7d22f0,
+                    0x5361f321ce19d716,
+                    0x17a467bf8c3568c7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x708bd09c34e5909d,
+                    0xcce01d55870cc20c,
+                    0x471dc47513dc8c28,
+                    0x0c5ed98f6f5782fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5203143450dc7edc,
+                    0x089f26ae633a4d47,
+                    0xe405b01fb4de83d7,
+                    0x45daa8993b3408ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb9faad132cde1e1e,
+                    0x9b69c393f17ab45a,
+                    0x92864c9083e4ba65,
+                    0x7eb5e89d64d503a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8068957fdc43381,
+                    0x8473ed4c03635f7f,
+                    0xdb13b7ec382c653a,
+                    0x0b4c858e55e492d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x29263f27aa6ca3d1,
+                    0x5c91ba3004380476,
+                    0xeee12f1af296e5c1,
+                    0x42adc7928872ffa6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5420756f991d0914,
+                    0x54bd9f9f1ade470d,
+                    0xaa9e95d66a1bdd9b,
+                    0x3978351656ce1325,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4392859ce9b269be,
+                    0x082744e5ea161bbd,
+                    0xfe6bf4b1bc67e7f7,
+                    0x0707f41c42451011,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9cda92defd8aee07,
+                    0x42293f4868e80f5c,
+                    0x429de148ea0837c8,
+                    0x5904c282e09b3274,
+                ])),
+                Felt::new(BigInteger256([
+                    0x65e55db56df6a2a8,
+                    0x8e78ca629055039e,
+                    0x408228cf4934615a,
+                    0x355f628b8914ef4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea5981779e39ace3,
+                    0xac7001180f150226,
+                    0x64de7095325fb345,
+                    0x13e07affaef1dfd2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd891b43f748dd3c0,
+                    0xd4ae61a3946b89e5,
+                    0xa6a9770c1242a626,
+                    0x43179463a8bbe281,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf01e005efda8384c,
+                    0x33d1fa9a8e318f88,
+                    0x044dcef6cbafce99,
+                    0x113c4b26819e0d82,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3fcb4db15116be04,
+                    0x8b6d9a35dd4c029b,
+                    0x9765941ff062b061,
+                    0x15bb3df84c63bc20,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfaaa3a865c7088e3,
+                    0xd61086ce7ce9c174,
+                    0xa2c8a0756cf1db75,
+                    0x374a265966389e84,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf54905248befa8ec,
+                    0x99e0c0d19d8b3ed4,
+                    0x69f7030929068029,
+                    0x3acb718b3e2edd48,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000013,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000011d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000000000004c,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61f23745a19bfa8d,
+                    0xf7436e5b2fb38c93,
+                    0x0dd463c7a30354f7,
+                    0x397ea2f07670c6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0fda9144035e3e6,
+                    0xa514d2d95f066573,
+                    0xd87523385d4978a3,
+                    0x0e48f86e634fa6ae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000085,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffc7,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x7fffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3b30c1945739798d,
+                    0x556b788912488015,
+                    0x8440239edede9e81,
+                    0x6f1a1e3a269c14fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x46913927345d91da,
+                    0x26dfe554fb2f782f,
+                    0xb0aa5d94f8067218,
+                    0x02b2de2ad435c90e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0112e9e678f5f1bf,
+                    0x336b47271e6f1bc8,
+                    0xb3484424d543b7d3,
+                    0x451365b769392a5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbf23c0c7d1e30514,
+                    0xd7326f7405bd648f,
+                    0xbb8db28c2641ce65,
+                    0x6ea427ad8ba20d5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8db6490e9bdc0756,
+                    0x58ba1581ad653e5e,
+                    0x7db5079d3601062a,
+                    0x48be4b002bab2184,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55d1e7a6140815f2,
+                    0x30950d18ac5a21c9,
+                    0xb4de5aa9bf541820,
+                    0x5f34f32b08909672,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb05215065bcce0ca,
+                    0xa68db4719bb0d1ab,
+                    0x7b7aa9f5f350da89,
+                    0x09cff67f830b0cfe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25bea3c5c467a4e8,
+                    0x9ad42eac27d66bdb,
+                    0x6628cc54c8e0be7f,
+                    0x116e64bdb920e3d0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd971e3cfb8e3c8fb,
+                    0x7309807abe0e8fd6,
+                    0xbe85745d63835ee3,
+                    0x0ff52e316167feb6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x73b9cb84bff1263b,
+                    0x5809cb4e86038974,
+                    0x935e106ce93d7d32,
+                    0x31c7992732c49a7e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcdaed791401b5431,
+                    0xdb7cf1717caab360,
+                    0x44b762c9cb995b05,
+                    0x73595e191fb18ba1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe30bb66078733950,
+                    0xbd6cb91c6cac6e84,
+                    0x7c91827826d9fc9c,
+                    0x11d9930b13f83cfb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd6b69ff27bcc617,
+                    0x079decc9eaa34d6c,
+                    0x68b4b8fef00cf01b,
+                    0x6f93b627d4f4cdc0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8559c74ca27eb71d,
+                    0x0e9611515ae3f4a5,
+                    0xf9ab271386fe1c5e,
+                    0x67627a20a57e69da,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcd6b61d76d915b16,
+                    0xf8865b6ab94e45f8,
+                    0x92de3b13489db92b,
+                    0x0daa3c682ef31ae4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77f0e0e810e8e24b,
+                    0x21d1f70e3c11eb5b,
+                    0xf97dad209978b5ad,
+                    0x6e92a2a343bf41ff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe2c603ba7841e009,
+                    0x6f45c52604e59e2c,
+                    0xc2ae0181ea2b8c84,
+                    0x0f324cd3cccbb15a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd9aa000743cad464,
+                    0x924cfd35c1ba853b,
+                    0x2f82b3d9b5bf7e31,
+                    0x18ea2c6a9f17dba8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe339705e0a32825,
+                    0x11aca3f0f9939e58,
+                    0xaed031b21f514099,
+                    0x67caf2f46a5c84ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83014112b975ba7d,
+                    0xee7164e912604856,
+                    0xc2701c8e5a7b7e02,
+                    0x4699cc8d40c04fce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x137c70974eb8e0aa,
+                    0xd14b4a0f5a885721,
+                    0xd470b51ae37891f7,
+                    0x7d7f0d2c7940a0a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ed331f187b113e9,
+                    0x30fd327a1df55972,
+                    0x3a04c262427bcb69,
+                    0x1149052d8f761928,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd04a2ad0849f3feb,
+                    0xc6a6345edbe97007,
+                    0x2dc13107971b383e,
+                    0x284a8b7b62bcd405,
+                ])),
+                Felt::new(BigInteger256([
+                    0x811e61f816e2512f,
+                    0x3987e4421b6cfa14,
+                    0x0404a5394c1ebb1c,
+                    0x35e4230466e61062,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x675a8db084e8ff95,
+                    0x72061338eef6c7cb,
+                    0xb432770028d4d7b7,
+                    0x0f2ec5c974405399,
+                ])),
+                Felt::new(BigInteger256([
+                    0x203ea05d1890b951,
+                    0x1c19230bdc942955,
+                    0x8afe276a2f38df8d,
+                    0x411bb5e272bab15b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe13d01f95cfc2587,
+                    0x34fc907aa446f502,
+                    0xc02598613edd805c,
+                    0x3b6d73c1d978e56e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x02fb38a8e080d466,
+                    0x9d16900f032f5f0b,
+                    0xb324154c6f674d92,
+                    0x5ff8fdc4aa7b842c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcd9a53741eace939,
+                    0x39e1b65876d2d8a9,
+                    0x86af78f0e3167ed3,
+                    0x7c4f0c9d85dcb74d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd87c2790e3becaf,
+                    0xdcfad0a0f34855da,
+                    0x185fc94d8c6ae542,
+                    0x7685748385f497a6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0c7bcce9ca7546f2,
+                    0x6763d377d184630d,
+                    0x2b8e5cdf4b586eb3,
+                    0x045b2d41654bbdc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc5779470a76f238,
+                    0xe7b5c811422b23a7,
+                    0xe750a4a7b59b9b19,
+                    0x3bd78e04477f2b52,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8fd818c59bfc52a6,
+                    0xa5b8d0ef0f3da89d,
+                    0xda78e3312993104f,
+                    0x08395af8825f9714,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8088c49ecde1bf0a,
+                    0xbe5128666baeb821,
+                    0x5e1855085bf4039e,
+                    0x77a2efc18d180968,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd8ba994a853ea7af,
+                    0x8641a0efaea888cb,
+                    0xf0215bf527aea6a3,
+                    0x1c18eb7071f6496d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7acfec4c1a6a381,
+                    0x5f09183e7f4f5318,
+                    0x24614db8f41d8e3c,
+                    0x3b8cb6421bfab2fd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x56cf9efeb58c5a48,
+                    0x6b92fc0c6fc0d9b0,
+                    0xbcc8aebef28e7393,
+                    0x6dab2cf8cfd6368e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb27dc9efe65390b1,
+                    0x802c0397a644629f,
+                    0x4e213922b5863fbe,
+                    0x44c39e3120f5cb5e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9625f906bd6a872,
+                    0xb1c901f5b527a78c,
+                    0x6aa09d5e79e63037,
+                    0x5e0cd86f7e1bb8bc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x276289c9b51492a4,
+                    0x5649adcd2115def7,
+                    0xa6190617c08104ca,
+                    0x0c90bb3292cbf1d7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa885855ec947fdac,
+                    0xaa0ddef4b1f0e069,
+                    0x6aa99ebd9ec43d43,
+                    0x4f929e3343081d97,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa30fb3f6a8e979ff,
+                    0xba84c68ad7762cab,
+                    0x4754a99f95d433da,
+                    0x29e2f8f7d0a4c84f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x060e290632d94c56,
+                    0x1a03e5d98f3d5b0c,
+                    0xc49141f3d94a08bb,
+                    0x543b8a635fb32d15,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcbb1a357b1e65ca6,
+                    0x356e94c062304df8,
+                    0xfd0fbf6fec496915,
+                    0x75e773c852389894,
+                ])),
+                Felt::new(BigInteger256([
+                    0x49090e6c1cc38523,
+                    0xfcdfe577984314b7,
+                    0x991336797195cc41,
+                    0x6f37736f2af3e61c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77a502c7d86234b1,
+                    0xec994204f07d7a48,
+                    0xe3cae06164d4cc84,
+                    0x3cec1f8f782784eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa0dd3a638cc587c,
+                    0x36b98971d993a471,
+                    0xc704ec44247702fb,
+                    0x3ee1638e0ffd29f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x229b32a920ea8aa4,
+                    0x559d07784cdb4561,
+                    0xd7a2788565eb916a,
+                    0x62ff1070d16c48cd,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc222146685c9b515,
+                    0x9069347d83053de9,
+                    0x20606f47b788569d,
+                    0x35ebef4c09194922,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80508cc208309c0a,
+                    0xe3279a3ce3331419,
+                    0x2ba9387a6d049377,
+                    0x3bc744583e64d66f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6a165d78b33bb43d,
+                    0xee6d6f44fed2de5d,
+                    0x40d7978e8824d976,
+                    0x2ecaaf75a5ef8abe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13d4fd817e752b93,
+                    0x96010c33827682ff,
+                    0x2b213630e22ad65a,
+                    0x27c8fa2f4f99b806,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb692c3424ed2e6ba,
+                    0x8aca5e4015f9669b,
+                    0x654a22377a3fcdfc,
+                    0x27796d080209a104,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6aae0236d4f05df6,
+                    0x1028936f21f850fb,
+                    0x74c1c3c29c9f1648,
+                    0x03f5b68be53873d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x584bffd10b95e26b,
+                    0x53510fff4644a64b,
+                    0xa1cbde1c16a42c53,
+                    0x2d69b317d77b60dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x777be24ee22687d7,
+                    0xe84649a51b24172b,
+                    0x746e9f3542f6d24f,
+                    0x407641460c717680,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x82441b19f479f60b,
+                    0xa0cc8b4f9122f3da,
+                    0x9801629f316ec53d,
+                    0x35252bf771b7965d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x28b9d53eec6d4894,
+                    0x2afe1a97a2ce4cb2,
+                    0xb01894eb8f999c4f,
+                    0x08a9c0ea7d2e7192,
+                ])),
+                Felt::new(BigInteger256([
+                    0x51a8bf960429ea10,
+                    0xe16bc3c88de53d19,
+                    0x5dd067dc05f3d1b3,
+                    0x50f69b4043c66b2c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe516cee45dd5ad4a,
+                    0xb0d1e08a5c39f65a,
+                    0xce121b0256a828be,
+                    0x55c1bb2d6b3db9b8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb95e5e78d43c173e,
+                    0x919b20ca025d4a1f,
+                    0xe2dc08f433876eef,
+                    0x1f23e490a147b04c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbbe8f6711fe492e1,
+                    0x4a8e3675fb8c7af3,
+                    0xdcb1d1c19cd730fb,
+                    0x1d5b9c61d5e1af55,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71cf18e9833afbdf,
+                    0x2aa6c49118faca83,
+                    0xe565539889e9f403,
+                    0x0b376d0e41c1d767,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19e80b48136e7d5b,
+                    0x2f83446ad261fffc,
+                    0xbc9f65cc85e0d8ac,
+                    0x549ec55a9937fa82,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe9bb2b5621a5ba53,
+                    0x27eeaafa1cc5f11e,
+                    0x0d870b979453cf7d,
+                    0x5e3af2b1d13e51c0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c9b196c8b58db19,
+                    0x30eacf0c78b108c0,
+                    0x341777402cc0a986,
+                    0x1a050d9ff972c311,
+                ])),
+                Felt::new(BigInteger256([
+                    0x703f28fea8ac5d67,
+                    0x8d12209766b37a7e,
+                    0xb93f86879f28c547,
+                    0x41a76ad702048f9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x87b9602f7346e551,
+                    0x4cd2bd7225476c9d,
+                    0x28d76271a8954d15,
+                    0x0df827fdce3b108d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x940ea1e2081f328e,
+                    0xb328719028a0b304,
+                    0x679cfa238d24ed9b,
+                    0x607c877f80a40b4d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x270cd83ff1a7e7ea,
+                    0xa4845a5d76db82e3,
+                    0xa3c143458e9ef0d5,
+                    0x67bd37ff1540359f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd86d2395d7aa3e5,
+                    0x861b93715ac8944a,
+                    0xd4503fc725f9d7cf,
+                    0x1b67c017745b0245,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d4f3ff7c465e822,
+                    0xf8a5625e6533fc1d,
+                    0x5f847f55c38e024d,
+                    0x41568fe9f3841194,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xdff1c4ba8f305222,
+                    0x0acb566059407297,
+                    0xee9ccd07aecf40f5,
+                    0x2662617c3a11fa54,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3652b4d731a0ec06,
+                    0xafc499142a32ad47,
+                    0xe13d255dbe8e5772,
+                    0x33db52526f302241,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab0f7af6fbe8b4d7,
+                    0x74bbb422eabb8370,
+                    0xa876eed9c1f83c31,
+                    0x376fb66a475fb00a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x39dd5c26b2e8d3cc,
+                    0xfc8d96e85f05328a,
+                    0xd73f297bb4ba0df7,
+                    0x4cf590619ebd76e1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3588137c02bdf9d1,
+                    0x4c125e475020d287,
+                    0x08c16dffc8864559,
+                    0x5ef91eb3bf34de4d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94bf0c6568fc7a85,
+                    0x7af650a8f92ce868,
+                    0x84724c6adb91dc7d,
+                    0x4819353cc56ac872,
+                ])),
+                Felt::new(BigInteger256([
+                    0x915b705a58860ec4,
+                    0x17cff651c31a2e6b,
+                    0x4ea928d676c27f95,
+                    0x70acdd7116b995fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa888bf94a0a2a201,
+                    0x2dda870230695451,
+                    0x6cfc394c84eb181b,
+                    0x3b134ad7d96e28a4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf226f441c282e00b,
+                    0x906470cce816047e,
+                    0xb1c7050adc800273,
+                    0x06d48fd80b252b68,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0cb2e559350b0ac8,
+                    0xac4d0f3e248e762d,
+                    0xfc248a306140484e,
+                    0x05e23bcc4c9d0219,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36a5a164305b54f2,
+                    0x4c67b8799f8b5163,
+                    0x7ebe260152c4faf5,
+                    0x4fc53d788e02e589,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b6f48f1581d532e,
+                    0x4bd4c3f2b8e9b7ef,
+                    0x9a0783f8d69d72a2,
+                    0x74d632cfa53322c5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9db21f0ea23501c3,
+                    0x8bc041e5b5870971,
+                    0x9d468e0ceca05872,
+                    0x2ae6d8530cc560a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72ff59b4a6adbfa7,
+                    0x7e89bf39c180d642,
+                    0xe01d5ff1b70f3bdc,
+                    0x1c6ad8089e7d7d2d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xedbd7ea11877f277,
+                    0x37584aab883326a1,
+                    0x90a95e7292eb90e0,
+                    0x00d833bfb282b51c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c46edfd59873f57,
+                    0xcf98ed2f66819608,
+                    0x09597cbc41c3c659,
+                    0x3a3215cda0abf0bd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9e5ba28082f0fe9f,
+                    0x496885c6659f2d0a,
+                    0xdeed0c95d53a19c1,
+                    0x72abb0501255abf2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74b2421bf804ff55,
+                    0xd6aec4c913a7b94e,
+                    0x4e74e7b30f6ab48d,
+                    0x232a3268457d5b6f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x46febd8097e43dd7,
+                    0x40aab506f5531bba,
+                    0xb9e5e4e8caa71672,
+                    0x175d3191f2560f5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x53313b7816406c35,
+                    0xe0f5e889992d1bf7,
+                    0xe22e3278427f0468,
+                    0x401adcfc65527826,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff9ea8e19e280e49,
+                    0xadb58a48d75c310f,
+                    0x38ca6caeda55969d,
+                    0x593025000835cdb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6831ccf63040ac0c,
+                    0x8fc2f62cf4049ff1,
+                    0x61c645898b4ec7aa,
+                    0x496b668eac9c1f40,
+                ])),
+                Felt::new(BigInteger256([
+                    0xedd1428ba63d8140,
+                    0xdb04abe8f7be4935,
+                    0x9a19e7bdba182a24,
+                    0x551d2a8b8bb2ad4d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcb22804a9f228399,
+                    0x5af43ae0f3eb7b15,
+                    0xad2671f76072321a,
+                    0x63a644ea86c7c147,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000130,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002ac,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000001c8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000001ee,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000130,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002ac,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000001c8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000001ee,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x0000000000000130,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002ac,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000001c8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000001ee,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000000000130,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000002ac,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000001c8,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000000000001ee,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3eea70a101bd490c,
+                    0x42bcfa252f313cef,
+                    0x7b4db435d33570a8,
+                    0x16690f9eaf5a51f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabd7aa17da4773ab,
+                    0x4ddffa33e8109a53,
+                    0x21a5e59d6f818b6f,
+                    0x11a8488f1a91637d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf5fda9f66847bbe,
+                    0x7eb3cec91f17af67,
+                    0xf261d929c0d904dc,
+                    0x6cf26c94b2b53115,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd7a4b0739c271703,
+                    0xbfdfa3cd7e1863f4,
+                    0x3e8239eafe7a1b2d,
+                    0x219871168df04921,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcfaf4ccc59a4e27f,
+                    0xf03d133757013f6b,
+                    0x0d96e0f05af8dbda,
+                    0x5c122c6f88a0c573,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb3d17ef9c9d6dfe0,
+                    0xa34f61e7ecc8703e,
+                    0xf58ccc682678dff2,
+                    0x2712f1b8d14a9d0a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa71c343550b83daf,
+                    0x168bf21efe042819,
+                    0xfe838d567ec346b3,
+                    0x7c602eed055dac45,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0c36141e69844a0e,
+                    0x41fab8543d749495,
+                    0x9ffc46e53bbf2f0e,
+                    0x664a6cbf04120fd3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x69f0b7cb7dc0feb4,
+                    0x464223bc4dcc40d9,
+                    0x0010271e99738f10,
+                    0x1c931b92474b34e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bd7b5193e21accb,
+                    0x9d837ad98c3322a9,
+                    0xa064c6a1e678ffa6,
+                    0x283baf00997ce507,
+                ])),
+                Felt::new(BigInteger256([
+                    0xace4eda6a147ecb6,
+                    0x230bfd047258225c,
+                    0xe86e970e0e0dd223,
+                    0x497780504117b817,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa50367619d65294f,
+                    0x8ea9501511e8b84d,
+                    0x5e563136722c7ae8,
+                    0x6df2b0839054fe79,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7e314af78eaafd6,
+                    0x4cbf4e92effb6eb5,
+                    0xcbd8aa0cad659c3c,
+                    0x2687376310268b2c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x870e6971f26c1f51,
+                    0x90b2e671070b1d76,
+                    0x77519ac4422030b2,
+                    0x524ac6bfd4202acf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfce3641d1ca0fe3b,
+                    0xb92892e52b96f437,
+                    0x6d901611bb7bb974,
+                    0x545698ccdb9d2660,
+                ])),
+                Felt::new(BigInteger256([
+                    0xccb191dd9365e4a7,
+                    0xc8c4656b1b6743c3,
+                    0x32e2fdb52e6f5528,
+                    0x16d12ed3e0b548f5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xbf7d89f7fb96d134,
+                    0x6e78d4329eced997,
+                    0x6dfb434aaa11f0c9,
+                    0x4b89413b5e15db49,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69eba1cd05139f1e,
+                    0xec8f9b3435bd878c,
+                    0x669c79ae8638085a,
+                    0x1fd925012adedb02,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe0c45c97b67b6356,
+                    0xd6f041082c65a7d3,
+                    0x3a3fb93b9d3a9094,
+                    0x4c8fe417977a148a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb3e373acc8dae183,
+                    0x6cfe85b1ef0439f3,
+                    0x1a0fab6bd4d02fe5,
+                    0x4a0169dcc1b31579,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe25d129fa3220932,
+                    0x8e72bc8ea2de9e07,
+                    0xaef4f58da1f25817,
+                    0x7d9aa7185aa17634,
+                ])),
+                Felt::new(BigInteger256([
+                    0x599337a96d4d2cdd,
+                    0x20713d09f6e0f8bd,
+                    0xad84ec742ab97d28,
+                    0x69d5b5c46ce35161,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9bc9cf83194daf7a,
+                    0xbc9e6e4a25a671e3,
+                    0x76a179f33d5fedb5,
+                    0x6f6006c29bdd1312,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbf93097449865397,
+                    0x96db09bc6ec77f68,
+                    0x06b4f5ca9848d6fd,
+                    0x64611ec4ee7bf14d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cc3917b81b627b3,
+                    0xfcabb7b36b59e663,
+                    0x91c06dcbd7dedb9f,
+                    0x4abee60b53dcaef3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8388d9bd8cd12b2,
+                    0x96bffeecfd7e610f,
+                    0x2f82b049001e0af0,
+                    0x77c86906733b1968,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1b975b217e8293a,
+                    0x54a436ae15979a8b,
+                    0x6d9c34e2959cc7b8,
+                    0x074736c9c8a0870b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb329e0c5b64177f5,
+                    0x6e592d84d14558ab,
+                    0x08b37c1267541a67,
+                    0x4c2fde0a451e5f03,
+                ])),
+                Felt::new(BigInteger256([
+                    0x04f30395b94fded4,
+                    0xb63ffe31438fc15d,
+                    0x5be91586dabc1625,
+                    0x4e38a0a73add9847,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc06373dda6b40aa8,
+                    0x80a474f99930d1c0,
+                    0x5c7bf896b0371284,
+                    0x50897fa3e657771c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe18aed07aee6b530,
+                    0x5d2abdc311e82737,
+                    0xd3ae585127cf5871,
+                    0x3b16f1aa879e3d42,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86b010913f12eb83,
+                    0x27deea6e0c3ec18c,
+                    0x0ae16e0947eb13a4,
+                    0x1ebde4b6b25b3370,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9c6978f8729550dc,
+                    0xc5c4976006ab79aa,
+                    0xd7bff7905305b68e,
+                    0x21b223cd57684254,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf1ea820191f2600a,
+                    0xabbf1bd2f6e162de,
+                    0x2e57572de2e3e9b1,
+                    0x761e5ca1dc4b18b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x545ae2a416eafa01,
+                    0x22ac047dfcda63e0,
+                    0x0482eed8cd9e7de5,
+                    0x61230478dca503e5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa58c751fb9ce79c3,
+                    0x8afc5b68d8de26f1,
+                    0xcd9e99ffa523beb5,
+                    0x2ff5ede5ac1fc01f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7029efafe78e1953,
+                    0xdb44fd66922b891c,
+                    0xdf5990dbcc9a2c15,
+                    0x69254f8910e83f4c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe7bdc3da65257473,
+                    0x6fc4cdf6f65e3226,
+                    0x12daf2ebb22f61fd,
+                    0x7bd511a8cae4e8e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd850ac803cbd0d3e,
+                    0x4a61da75ade6a5ee,
+                    0xa44820742f9002a4,
+                    0x2ff2cde80afcdbdd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcf55bf6a080e7f65,
+                    0xd2c95316db1a8c56,
+                    0x5e2d9bb14d301e01,
+                    0x4e923f6cbfae0489,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x30d25ac5c74950ef,
+                    0x10b60f42bd96c859,
+                    0x0273f2711dc66d21,
+                    0x0b1a64a5921ecd46,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa8e788412d0cda0,
+                    0x54885d94ea45eb04,
+                    0xeb0a4b9d5dd8fbbb,
+                    0x2449d555bbe55b3c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8cca025ac67d7ab8,
+                    0x63e998e1a5f60904,
+                    0x15f84d7524b75ce9,
+                    0x2f05536881999559,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa1f5f76d375b932,
+                    0x31da8ceb4951b102,
+                    0x3f660d7b298f0e28,
+                    0x76e10712a7175431,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc727e6f19d8e53ef,
+                    0x3224843b71ced3de,
+                    0xfbf5ba0f2f26d789,
+                    0x426fba066daf451e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x932f90669aa38acc,
+                    0xd6904438af179d9a,
+                    0x471bf9e8eecea2ea,
+                    0x0d40213fb604d52a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x220ab8f6d3936429,
+                    0x0f336213290bb2e3,
+                    0x47066d21050ebd31,
+                    0x6958f85380a45c99,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72ccaed6e8b2bd09,
+                    0xb56952b020b0d73d,
+                    0x2c80814c944a51c5,
+                    0x58b1013ae682abee,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! The Curve25519 base field, of modulus
+//! `p = 57896044618658097711785492504343953926634992332820282019728792003956564819949`,
+//! i.e. `2^255 - 19`, over which both Curve25519 and Ed25519 are defined.
+//!
+//! Elements are represented with the generic prime field type of arkworks
+//! over 4 limbs, in Montgomery form.
+
+use ark_ff::{biginteger::BigInteger256 as BigInteger, fields::*};
+
+/// An element of the Curve25519 base field.
+pub type Felt = Fp256<FeltParameters>;
+
+/// Parameters of the Curve25519 base field.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FeltParameters;
+
+impl Fp256Parameters for FeltParameters {}
+
+impl FftParameters for FeltParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 2;
+
+    /// `2^((p - 1) / 2^2)`
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x3b5807d4fe2bdb04,
+        0x03f590fdb51be9ed,
+        0x6d6e16bf336202d1,
+        0x75776b0bd6c71ba8,
+    ]);
+}
+
+impl FpParameters for FeltParameters {
+    const MODULUS: BigInteger = BigInteger([
+        0xffffffffffffffed,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+
+    const MODULUS_BITS: u32 = 255;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 1;
+
+    const R: BigInteger = BigInteger([
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const R2: BigInteger = BigInteger([
+        0x00000000000005a4,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const INV: u64 = 0x86bca1af286bca1b;
+
+    /// GENERATOR = 2
+    const GENERATOR: BigInteger = BigInteger([
+        0x000000000000004c,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xfffffffffffffff6,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    const T: BigInteger = BigInteger([
+        0xfffffffffffffffb,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x1fffffffffffffff,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xfffffffffffffffd,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x0fffffffffffffff,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::fields::{
+        fft_field_test, field_test, from_str_test, primefield_test,
+    };
+    use ark_std::test_rng;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_field() {
+        let mut rng = test_rng();
+        let a = Felt::rand(&mut rng);
+        let b = Felt::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Felt>();
+        fft_field_test::<Felt>();
+        from_str_test::<Felt>();
+    }
+}
@@ -0,0 +1,358 @@
+use super::BigInteger256;
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 5;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 4] = [
+    0xccccccccccccccc1,
+    0xcccccccccccccccc,
+    0xcccccccccccccccc,
+    0x4ccccccccccccccc,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 2;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger256([
+    0x0000000000000013,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    let t1 = x.square(); //         1: 2
+    let t2 = t1 * x; //             2: 3
+    let t3 = t2 * t1; //            3: 5
+    let t4 = t3 * t1; //            4: 7
+    let t5 = t4 * t1; //            5: 9
+    let t6 = t5 * t1; //            6: 11
+    let t7 = t6 * t1; //            7: 13
+    let t8 = t7 * t1; //            8: 15
+    let t9 = t8 * t1; //            9: 17
+    let t10 = t9 * t1; //          10: 19
+    let t11 = t10 * t1; //         11: 21
+    let t12 = t11 * t1; //         12: 23
+    let t13 = t12 * t1; //         13: 25
+    let mut t0 = t10.square(); //  14: 38
+    t0 = t0.square(); //           15: 76
+    t0 = t0.square(); //           16: 152
+    t0 = t0.square(); //           17: 304
+    t0 = t0.square(); //           18: 608
+    t0 = t0.square(); //           19: 1216
+    t0 = t0.square(); //           20: 2432
+    t0 *= t13; //                  21: 2457
+    t0 = t0.square(); //           22: 4914
+    t0 = t0.square(); //           23: 9828
+    t0 = t0.square(); //           24: 19656
+    t0 = t0.square(); //           25: 39312
+    t0 = t0.square(); //           26: 78624
+    t0 *= t10; //                  27: 78643
+    t0 = t0.square(); //           28: 157286
+    t0 = t0.square(); //           29: 314572
+    t0 = t0.square(); //           30: 629144
+    t0 = t0.square(); //           31: 1258288
+    t0 = t0.square(); //           32: 2516576
+    t0 = t0.square(); //           33: 5033152
+    t0 = t0.square(); //           34: 10066304
+    t0 *= t13; //                  35: 10066329
+    t0 = t0.square(); //           36: 20132658
+    t0 = t0.square(); //           37: 40265316
+    t0 = t0.square(); //           38: 80530632
+    t0 = t0.square(); //           39: 161061264
+    t0 = t0.square(); //           40: 322122528
+    t0 *= t10; //                  41: 322122547
+    t0 = t0.square(); //           42: 644245094
+    t0 = t0.square(); //           43: 1288490188
+    t0 = t0.square(); //           44: 2576980376
+    t0 = t0.square(); //           45: 5153960752
+    t0 = t0.square(); //           46: 10307921504
+    t0 = t0.square(); //           47: 20615843008
+    t0 = t0.square(); //           48: 41231686016
+    t0 *= t13; //                  49: 41231686041
+    t0 = t0.square(); //           50: 82463372082
+    t0 = t0.square(); //           51: 164926744164
+    t0 = t0.square(); //           52: 329853488328
+    t0 = t0.square(); //           53: 659706976656
+    t0 = t0.square(); //           54: 1319413953312
+    t0 *= t10; //                  55: 1319413953331
+    t0 = t0.square(); //           56: 2638827906662
+    t0 = t0.square(); //           57: 5277655813324
+    t0 = t0.square(); //           58: 10555311626648
+    t0 = t0.square(); //           59: 21110623253296
+    t0 = t0.square(); //           60: 42221246506592
+    t0 = t0.square(); //           61: 84442493013184
+    t0 = t0.square(); //           62: 168884986026368
+    t0 *= t13; //                  63: 168884986026393
+    t0 = t0.square(); //           64: 337769972052786
+    t0 = t0.square(); //           65: 675539944105572
+    t0 = t0.square(); //           66: 1351079888211144
+    t0 = t0.square(); //           67: 2702159776422288
+    t0 = t0.square(); //           68: 5404319552844576
+    t0 *= t10; //                  69: 5404319552844595
+    t0 = t0.square(); //           70: 10808639105689190
+    t0 = t0.square(); //           71: 21617278211378380
+    t0 = t0.square(); //           72: 43234556422756760
+    t0 = t0.square(); //           73: 86469112845513520
+    t0 = t0.square(); //           74: 172938225691027040
+    t0 = t0.square(); //           75: 345876451382054080
+    t0 = t0.square(); //           76: 691752902764108160
+    t0 *= t13; //                  77: 691752902764108185
+    t0 = t0.square(); //           78: 1383505805528216370
+    t0 = t0.square(); //           79: 2767011611056432740
+    t0 = t0.square(); //           80: 5534023222112865480
+    t0 = t0.square(); //           81: 11068046444225730960
+    t0 = t0.square(); //           82: 22136092888451461920
+    t0 *= t10; //                  83: 22136092888451461939
+    t0 = t0.square(); //           84: 44272185776902923878
+    t0 = t0.square(); //           85: 88544371553805847756
+    t0 = t0.square(); //           86: 177088743107611695512
+    t0 = t0.square(); //           87: 354177486215223391024
+    t0 = t0.square(); //           88: 708354972430446782048
+    t0 = t0.square(); //           89: 1416709944860893564096
+    t0 = t0.square(); //           90: 2833419889721787128192
+    t0 *= t13; //                  91: 2833419889721787128217
+    t0 = t0.square(); //           92: 5666839779443574256434
+    t0 = t0.square(); //           93: 11333679558887148512868
+    t0 = t0.square(); //           94: 22667359117774297025736
+    t0 = t0.square(); //           95: 45334718235548594051472
+    t0 = t0.square(); //           96: 90669436471097188102944
+    t0 *= t10; //                  97: 90669436471097188102963
+    t0 = t0.square(); //           98: 181338872942194376205926
+    t0 = t0.square(); //           99: 362677745884388752411852
+    t0 = t0.square(); //          100: 725355491768777504823704
+    t0 = t0.square(); //          101: 1450710983537555009647408
+    t0 = t0.square(); //          102: 2901421967075110019294816
+    t0 = t0.square(); //          103: 5802843934150220038589632
+    t0 = t0.square(); //          104: 11605687868300440077179264
+    t0 *= t13; //                 105: 11605687868300440077179289
+    t0 = t0.square(); //          106: 23211375736600880154358578
+    t0 = t0.square(); //          107: 46422751473201760308717156
+    t0 = t0.square(); //          108: 92845502946403520617434312
+    t0 = t0.square(); //          109: 185691005892807041234868624
+    t0 = t0.square(); //          110: 371382011785614082469737248
+    t0 *= t10; //                 111: 371382011785614082469737267
+    t0 = t0.square(); //          112: 742764023571228164939474534
+    t0 = t0.square(); //          113: 1485528047142456329878949068
+    t0 = t0.square(); //          114: 2971056094284912659757898136
+    t0 = t0.square(); //          115: 5942112188569825319515796272
+    t0 = t0.square(); //          116: 11884224377139650639031592544
+    t0 = t0.square(); //          117: 23768448754279301278063185088
+    t0 = t0.square(); //          118: 47536897508558602556126370176
+    t0 *= t13; //                 119: 47536897508558602556126370201
+    t0 = t0.square(); //          120: 95073795017117205112252740402
+    t0 = t0.square(); //          121: 190147590034234410224505480804
+    t0 = t0.square(); //          122: 380295180068468820449010961608
+    t0 = t0.square(); //          123: 760590360136937640898021923216
+    t0 = t0.square(); //          124: 1521180720273875281796043846432
+    t0 *= t10; //                 125: 1521180720273875281796043846451
+    t0 = t0.square(); //          126: 3042361440547750563592087692902
+    t0 = t0.square(); //          127: 6084722881095501127184175385804
+    t0 = t0.square(); //          128: 12169445762191002254368350771608
+    t0 = t0.square(); //          129: 24338891524382004508736701543216
+    t0 = t0.square(); //          130: 48677783048764009017473403086432
+    t0 = t0.square(); //          131: 97355566097528018034946806172864
+    t0 = t0.square(); //          132: 194711132195056036069893612345728
+    t0 *= t13; //                 133: 194711132195056036069893612345753
+    t0 = t0.square(); //          134: 389422264390112072139787224691506
+    t0 = t0.square(); //          135: 778844528780224144279574449383012
+    t0 = t0.square(); //          136: 1557689057560448288559148898766024
+    t0 = t0.square(); //          137: 3115378115120896577118297797532048
+    t0 = t0.square(); //          138: 6230756230241793154236595595064096
+    t0 *= t10; //                 139: 6230756230241793154236595595064115
+    t0 = t0.square(); //          140: 12461512460483586308473191190128230
+    t0 = t0.square(); //          141: 24923024920967172616946382380256460
+    t0 = t0.square(); //          142: 49846049841934345233892764760512920
+    t0 = t0.square(); //          143: 99692099683868690467785529521025840
+    t0 = t0.square(); //          144: 199384199367737380935571059042051680
+    t0 = t0.square(); //          145: 398768398735474761871142118084103360
+    t0 = t0.square(); //          146: 797536797470949523742284236168206720
+    t0 *= t13; //                 147: 797536797470949523742284236168206745
+    t0 = t0.square(); //          148: 1595073594941899047484568472336413490
+    t0 = t0.square(); //          149: 3190147189883798094969136944672826980
+    t0 = t0.square(); //          150: 6380294379767596189938273889345653960
+    t0 = t0.square(); //          151: 12760588759535192379876547778691307920
+    t0 = t0.square(); //          152: 25521177519070384759753095557382615840
+    t0 *= t10; //                 153: 25521177519070384759753095557382615859
+    t0 = t0.square(); //          154: 51042355038140769519506191114765231718
+    t0 = t0.square(); //          155: 102084710076281539039012382229530463436
+    t0 = t0.square(); //          156: 204169420152563078078024764459060926872
+    t0 = t0.square(); //          157: 408338840305126156156049528918121853744
+    t0 = t0.square(); //          158: 816677680610252312312099057836243707488
+    t0 = t0.square(); //          159: 1633355361220504624624198115672487414976
+    t0 = t0.square(); //          160: 3266710722441009249248396231344974829952
+    t0 *= t13; //                 161: 3266710722441009249248396231344974829977
+    t0 = t0.square(); //          162: 6533421444882018498496792462689949659954
+    t0 = t0.square(); //          163: 13066842889764036996993584925379899319908
+    t0 = t0.square(); //          164: 26133685779528073993987169850759798639816
+    t0 = t0.square(); //          165: 52267371559056147987974339701519597279632
+    t0 = t0.square(); //          166: 104534743118112295975948679403039194559264
+    t0 *= t10; //                 167: 104534743118112295975948679403039194559283
+    t0 = t0.square(); //          168: 209069486236224591951897358806078389118566
+    t0 = t0.square(); //          169: 418138972472449183903794717612156778237132
+    t0 = t0.square(); //          170: 836277944944898367807589435224313556474264
+    t0 = t0.square(); //          171: 1672555889889796735615178870448627112948528
+    t0 = t0.square(); //          172: 3345111779779593471230357740897254225897056
+    t0 = t0.square(); //          173: 6690223559559186942460715481794508451794112
+    t0 = t0.square(); //          174: 13380447119118373884921430963589016903588224
+    t0 *= t13; //                 175: 13380447119118373884921430963589016903588249
+    t0 = t0.square(); //          176: 26760894238236747769842861927178033807176498
+    t0 = t0.square(); //          177: 53521788476473495539685723854356067614352996
+    t0 = t0.square(); //          178: 107043576952946991079371447708712135228705992
+    t0 = t0.square(); //          179: 214087153905893982158742895417424270457411984
+    t0 = t0.square(); //          180: 428174307811787964317485790834848540914823968
+    t0 *= t10; //                 181: 428174307811787964317485790834848540914823987
+    t0 = t0.square(); //          182: 856348615623575928634971581669697081829647974
+    t0 = t0.square(); //          183: 1712697231247151857269943163339394163659295948
+    t0 = t0.square(); //          184: 3425394462494303714539886326678788327318591896
+    t0 = t0.square(); //          185: 6850788924988607429079772653357576654637183792
+    t0 = t0.square(); //          186: 13701577849977214858159545306715153309274367584
+    t0 = t0.square(); //          187: 27403155699954429716319090613430306618548735168
+    t0 = t0.square(); //          188: 54806311399908859432638181226860613237097470336
+    t0 *= t13; //                 189: 54806311399908859432638181226860613237097470361
+    t0 = t0.square(); //          190: 109612622799817718865276362453721226474194940722
+    t0 = t0.square(); //          191: 219225245599635437730552724907442452948389881444
+    t0 = t0.square(); //          192: 438450491199270875461105449814884905896779762888
+    t0 = t0.square(); //          193: 876900982398541750922210899629769811793559525776
+    t0 = t0.square(); //          194: 1753801964797083501844421799259539623587119051552
+    t0 *= t10; //                 195: 1753801964797083501844421799259539623587119051571
+    t0 = t0.square(); //          196: 3507603929594167003688843598519079247174238103142
+    t0 = t0.square(); //          197: 7015207859188334007377687197038158494348476206284
+    t0 = t0.square(); //          198: 14030415718376668014755374394076316988696952412568
+    t0 = t0.square(); //          199: 28060831436753336029510748788152633977393904825136
+    t0 = t0.square(); //          200: 56121662873506672059021497576305267954787809650272
+    t0 = t0.square(); //          201: 112243325747013344118042995152610535909575619300544
+    t0 = t0.square(); //          202: 224486651494026688236085990305221071819151238601088
+    t0 *= t13; //                 203: 224486651494026688236085990305221071819151238601113
+    t0 = t0.square(); //          204: 448973302988053376472171980610442143638302477202226
+    t0 = t0.square(); //          205: 897946605976106752944343961220884287276604954404452
+    t0 = t0.square(); //          206: 1795893211952213505888687922441768574553209908808904
+    t0 = t0.square(); //          207: 3591786423904427011777375844883537149106419817617808
+    t0 = t0.square(); //          208: 7183572847808854023554751689767074298212839635235616
+    t0 *= t10; //                 209: 7183572847808854023554751689767074298212839635235635
+    t0 = t0.square(); //          210: 14367145695617708047109503379534148596425679270471270
+    t0 = t0.square(); //          211: 28734291391235416094219006759068297192851358540942540
+    t0 = t0.square(); //          212: 57468582782470832188438013518136594385702717081885080
+    t0 = t0.square(); //          213: 114937165564941664376876027036273188771405434163770160
+    t0 = t0.square(); //          214: 229874331129883328753752054072546377542810868327540320
+    t0 = t0.square(); //          215: 459748662259766657507504108145092755085621736655080640
+    t0 = t0.square(); //          216: 919497324519533315015008216290185510171243473310161280
+    t0 *= t13; //                 217: 919497324519533315015008216290185510171243473310161305
+    t0 = t0.square(); //          218: 1838994649039066630030016432580371020342486946620322610
+    t0 = t0.square(); //          219: 3677989298078133260060032865160742040684973893240645220
+    t0 = t0.square(); //          220: 7355978596156266520120065730321484081369947786481290440
+    t0 = t0.square(); //          221: 14711957192312533040240131460642968162739895572962580880
+    t0 = t0.square(); //          222: 29423914384625066080480262921285936325479791145925161760
+    t0 *= t10; //                 223: 29423914384625066080480262921285936325479791145925161779
+    t0 = t0.square(); //          224: 58847828769250132160960525842571872650959582291850323558
+    t0 = t0.square(); //          225: 117695657538500264321921051685143745301919164583700647116
+    t0 = t0.square(); //          226: 235391315077000528643842103370287490603838329167401294232
+    t0 = t0.square(); //          227: 470782630154001057287684206740574981207676658334802588464
+    t0 = t0.square(); //          228: 941565260308002114575368413481149962415353316669605176928
+    t0 = t0.square(); //          229: 1883130520616004229150736826962299924830706633339210353856
+    t0 = t0.square(); //          230: 3766261041232008458301473653924599849661413266678420707712
+    t0 *= t13; //                 231: 3766261041232008458301473653924599849661413266678420707737
+    t0 = t0.square(); //          232: 7532522082464016916602947307849199699322826533356841415474
+    t0 = t0.square(); //          233: 15065044164928033833205894615698399398645653066713682830948
+    t0 = t0.square(); //          234: 30130088329856067666411789231396798797291306133427365661896
+    t0 = t0.square(); //          235: 60260176659712135332823578462793597594582612266854731323792
+    t0 = t0.square(); //          236: 120520353319424270665647156925587195189165224533709462647584
+    t0 *= t10; //                 237: 120520353319424270665647156925587195189165224533709462647603
+    t0 = t0.square(); //          238: 241040706638848541331294313851174390378330449067418925295206
+    t0 = t0.square(); //          239: 482081413277697082662588627702348780756660898134837850590412
+    t0 = t0.square(); //          240: 964162826555394165325177255404697561513321796269675701180824
+    t0 = t0.square(); //          241: 1928325653110788330650354510809395123026643592539351402361648
+    t0 = t0.square(); //          242: 3856651306221576661300709021618790246053287185078702804723296
+    t0 = t0.square(); //          243: 7713302612443153322601418043237580492106574370157405609446592
+    t0 = t0.square(); //          244: 15426605224886306645202836086475160984213148740314811218893184
+    t0 *= t13; //                 245: 15426605224886306645202836086475160984213148740314811218893209
+    t0 = t0.square(); //          246: 30853210449772613290405672172950321968426297480629622437786418
+    t0 = t0.square(); //          247: 61706420899545226580811344345900643936852594961259244875572836
+    t0 = t0.square(); //          248: 123412841799090453161622688691801287873705189922518489751145672
+    t0 = t0.square(); //          249: 246825683598180906323245377383602575747410379845036979502291344
+    t0 = t0.square(); //          250: 493651367196361812646490754767205151494820759690073959004582688
+    t0 *= t10; //                 251: 493651367196361812646490754767205151494820759690073959004582707
+    t0 = t0.square(); //          252: 987302734392723625292981509534410302989641519380147918009165414
+    t0 = t0.square(); //          253: 1974605468785447250585963019068820605979283038760295836018330828
+    t0 = t0.square(); //          254: 3949210937570894501171926038137641211958566077520591672036661656
+    t0 = t0.square(); //          255: 7898421875141789002343852076275282423917132155041183344073323312
+    t0 = t0.square(); //          256: 15796843750283578004687704152550564847834264310082366688146646624
+    t0 = t0.square(); //          257: 31593687500567156009375408305101129695668528620164733376293293248
+    t0 = t0.square(); //          258: 63187375001134312018750816610202259391337057240329466752586586496
+    t0 *= t13; //                 259: 63187375001134312018750816610202259391337057240329466752586586521
+    t0 = t0.square(); //          260: 126374750002268624037501633220404518782674114480658933505173173042
+    t0 = t0.square(); //          261: 252749500004537248075003266440809037565348228961317867010346346084
+    t0 = t0.square(); //          262: 505499000009074496150006532881618075130696457922635734020692692168
+    t0 = t0.square(); //          263: 1010998000018148992300013065763236150261392915845271468041385384336
+    t0 = t0.square(); //          264: 2021996000036297984600026131526472300522785831690542936082770768672
+    t0 *= t10; //                 265: 2021996000036297984600026131526472300522785831690542936082770768691
+    t0 = t0.square(); //          266: 4043992000072595969200052263052944601045571663381085872165541537382
+    t0 = t0.square(); //          267: 8087984000145191938400104526105889202091143326762171744331083074764
+    t0 = t0.square(); //          268: 16175968000290383876800209052211778404182286653524343488662166149528
+    t0 = t0.square(); //          269: 32351936000580767753600418104423556808364573307048686977324332299056
+    t0 = t0.square(); //          270: 64703872001161535507200836208847113616729146614097373954648664598112
+    t0 = t0.square(); //          271: 129407744002323071014401672417694227233458293228194747909297329196224
+    t0 = t0.square(); //          272: 258815488004646142028803344835388454466916586456389495818594658392448
+    t0 *= t13; //                 273: 258815488004646142028803344835388454466916586456389495818594658392473
+    t0 = t0.square(); //          274: 517630976009292284057606689670776908933833172912778991637189316784946
+    t0 = t0.square(); //          275: 1035261952018584568115213379341553817867666345825557983274378633569892
+    t0 = t0.square(); //          276: 2070523904037169136230426758683107635735332691651115966548757267139784
+    t0 = t0.square(); //          277: 4141047808074338272460853517366215271470665383302231933097514534279568
+    t0 = t0.square(); //          278: 8282095616148676544921707034732430542941330766604463866195029068559136
+    t0 *= t10; //                 279: 8282095616148676544921707034732430542941330766604463866195029068559155
+    t0 = t0.square(); //          280: 16564191232297353089843414069464861085882661533208927732390058137118310
+    t0 = t0.square(); //          281: 33128382464594706179686828138929722171765323066417855464780116274236620
+    t0 = t0.square(); //          282: 66256764929189412359373656277859444343530646132835710929560232548473240
+    t0 = t0.square(); //          283: 132513529858378824718747312555718888687061292265671421859120465096946480
+    t0 = t0.square(); //          284: 265027059716757649437494625111437777374122584531342843718240930193892960
+    t0 = t0.square(); //          285: 530054119433515298874989250222875554748245169062685687436481860387785920
+    t0 = t0.square(); //          286: 1060108238867030597749978500445751109496490338125371374872963720775571840
+    t0 *= t13; //                 287: 1060108238867030597749978500445751109496490338125371374872963720775571865
+    t0 = t0.square(); //          288: 2120216477734061195499957000891502218992980676250742749745927441551143730
+    t0 = t0.square(); //          289: 4240432955468122390999914001783004437985961352501485499491854883102287460
+    t0 = t0.square(); //          290: 8480865910936244781999828003566008875971922705002970998983709766204574920
+    t0 = t0.square(); //          291: 16961731821872489563999656007132017751943845410005941997967419532409149840
+    t0 = t0.square(); //          292: 33923463643744979127999312014264035503887690820011883995934839064818299680
+    t0 *= t10; //                 293: 33923463643744979127999312014264035503887690820011883995934839064818299699
+    t0 = t0.square(); //          294: 67846927287489958255998624028528071007775381640023767991869678129636599398
+    t0 = t0.square(); //          295: 135693854574979916511997248057056142015550763280047535983739356259273198796
+    t0 = t0.square(); //          296: 271387709149959833023994496114112284031101526560095071967478712518546397592
+    t0 = t0.square(); //          297: 542775418299919666047988992228224568062203053120190143934957425037092795184
+    t0 *= t2; //                  298: 542775418299919666047988992228224568062203053120190143934957425037092795187
+    t0 = t0.square(); //          299: 1085550836599839332095977984456449136124406106240380287869914850074185590374
+    t0 = t0.square(); //          300: 2171101673199678664191955968912898272248812212480760575739829700148371180748
+    t0 = t0.square(); //          301: 4342203346399357328383911937825796544497624424961521151479659400296742361496
+    t0 = t0.square(); //          302: 8684406692798714656767823875651593088995248849923042302959318800593484722992
+    t0 = t0.square(); //          303: 17368813385597429313535647751303186177990497699846084605918637601186969445984
+    t0 = t0.square(); //          304: 34737626771194858627071295502606372355980995399692169211837275202373938891968
+    t0 * x //                     305: 34737626771194858627071295502606372355980995399692169211837275202373938891969
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
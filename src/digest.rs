@@ -1,35 +1,32 @@
-//! Digest trait implementation for Anemoi
+//! Digest implementation for Anemoi
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
-use super::DIGEST_SIZE;
-
-use super::Felt;
-use ark_ff::to_bytes;
+use ark_ff::{BigInteger, PrimeField};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-/// An Anemoi Digest for the Anemoi Hash over Felt
-pub struct AnemoiDigest([Felt; DIGEST_SIZE]);
+/// An Anemoi Digest for the Anemoi Hash over `F`
+pub struct AnemoiDigest<F: PrimeField, const DIGEST_SIZE: usize>([F; DIGEST_SIZE]);
 
-impl AnemoiDigest {
+impl<F: PrimeField, const DIGEST_SIZE: usize> AnemoiDigest<F, DIGEST_SIZE> {
     /// Returns a new Digest from a provided array
-    pub fn new(value: [Felt; DIGEST_SIZE]) -> Self {
+    pub fn new(value: [F; DIGEST_SIZE]) -> Self {
         Self(value)
     }
 
     /// Returns a reference to the wrapped digest
-    pub fn as_elements(&self) -> &[Felt; DIGEST_SIZE] {
+    pub fn as_elements(&self) -> &[F; DIGEST_SIZE] {
         &self.0
     }
 
     /// Returns the wrapped digest
-    pub fn to_elements(&self) -> [Felt; DIGEST_SIZE] {
+    pub fn to_elements(&self) -> [F; DIGEST_SIZE] {
         self.0
     }
 
-    /// Returns a `Vec<Felt>` from the provided digest slice
-    pub fn digests_to_elements(digests: &[Self]) -> Vec<Felt> {
+    /// Returns a `Vec<F>` from the provided digest slice
+    pub fn digests_to_elements(digests: &[Self]) -> Vec<F> {
         let mut res = Vec::with_capacity(digests.len() * DIGEST_SIZE);
         for digest in digests {
             res.extend(digest.as_elements())
@@ -38,28 +35,35 @@ impl AnemoiDigest {
         res
     }
 
-    /// Returns an array of bytes corresponding to the digest
-    pub fn to_bytes(&self) -> [u8; 48] {
-        to_bytes![self.0[0]].unwrap()[..48].try_into().unwrap()
+    /// Returns a sequence of bytes corresponding to the digest
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(DIGEST_SIZE * F::BigInt::NUM_LIMBS * 8);
+        for element in self.0.iter() {
+            element.write(&mut bytes).unwrap();
+        }
+
+        bytes
     }
 }
 
-impl Default for AnemoiDigest {
+impl<F: PrimeField, const DIGEST_SIZE: usize> Default for AnemoiDigest<F, DIGEST_SIZE> {
     fn default() -> Self {
-        AnemoiDigest([Felt::default(); DIGEST_SIZE])
+        AnemoiDigest([F::default(); DIGEST_SIZE])
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "bls_381"))]
 mod tests {
     #[cfg(not(feature = "std"))]
     use alloc::vec;
 
-    use super::super::Zero;
     use super::*;
-    use ark_ff::UniformRand;
+    use ark_bls12_381::Fq as Felt;
+    use ark_ff::{UniformRand, Zero};
     use rand_core::OsRng;
 
+    const DIGEST_SIZE: usize = 1;
+
     #[test]
     fn digest_elements() {
         let mut rng = OsRng;
@@ -79,7 +83,7 @@ mod tests {
             );
         }
 
-        let digest = AnemoiDigest::default();
+        let digest = AnemoiDigest::<Felt, DIGEST_SIZE>::default();
         assert_eq!(digest.to_elements(), [Felt::zero(); DIGEST_SIZE]);
         assert_eq!(digest.as_elements(), &vec![Felt::zero(); DIGEST_SIZE][..]);
         assert_eq!(digest.to_bytes(), [0u8; 48]);
@@ -0,0 +1,221 @@
+//! Duplex sponge construction for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::marker::PhantomData;
+
+use super::permutation::{Anemoi, AnemoiParameters};
+
+use ark_ff::PrimeField;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum DuplexMode {
+    Absorbing,
+    Squeezing,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// An Anemoi duplex sponge over `F` with `NUM_COLUMNS` columns, allowing
+/// to interleave absorptions and squeezes of field elements, as in
+/// interactive protocols made non-interactive through Fiat-Shamir.
+///
+/// Whenever it switches from absorbing to squeezing, the duplex pads the
+/// absorbed elements with a 1 followed by as many zeros as needed to fill
+/// the rate portion, which always lands in the rate and is hence injective.
+/// Unlike `Sponge::hash_field`, which adds its padding to the capacity after
+/// the last permutation when the rate portion is full, a full rate portion
+/// is followed by a whole block of padding. In particular, a single call to
+/// `absorb` on a sequence whose length is not a multiple of `RATE_WIDTH`,
+/// followed by `squeeze(1)`, returns the same element as `Sponge::hash_field`
+/// for digests of size 1.
+pub struct AnemoiDuplex<F: PrimeField, const NUM_COLUMNS: usize, P> {
+    state: Vec<F>,
+    idx: usize,
+    mode: DuplexMode,
+    _parameters: PhantomData<P>,
+}
+
+impl<F, const NUM_COLUMNS: usize, P> Default for AnemoiDuplex<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    fn default() -> Self {
+        Self {
+            state: vec![F::zero(); Self::STATE_WIDTH],
+            idx: 0,
+            mode: DuplexMode::Absorbing,
+            _parameters: PhantomData,
+        }
+    }
+}
+
+impl<F, const NUM_COLUMNS: usize, P> AnemoiDuplex<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+    /// The number of field elements of the state reserved for rate.
+    /// The last `CAPACITY_WIDTH` ones are reserved for capacity.
+    pub const RATE_WIDTH: usize = 2 * NUM_COLUMNS - P::CAPACITY_WIDTH;
+
+    /// Returns a new duplex sponge with an all-zero internal state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of field elements into the rate portion
+    /// of the state, applying an Anemoi permutation whenever it is full.
+    pub fn absorb(&mut self, elems: &[F]) {
+        if self.mode == DuplexMode::Squeezing {
+            self.mode = DuplexMode::Absorbing;
+            self.idx = 0;
+        }
+
+        for &element in elems.iter() {
+            // The permutation is applied lazily, so that the padding can
+            // tell apart a fully absorbed rate portion from an empty one.
+            if self.idx == Self::RATE_WIDTH {
+                Self::apply_permutation(&mut self.state);
+                self.idx = 0;
+            }
+            self.state[self.idx] += element;
+            self.idx += 1;
+        }
+    }
+
+    /// Squeezes `n` field elements from the rate portion of the state,
+    /// applying an Anemoi permutation whenever it has been entirely read.
+    pub fn squeeze(&mut self, n: usize) -> Vec<F> {
+        if self.mode == DuplexMode::Absorbing {
+            self.apply_padding();
+            self.mode = DuplexMode::Squeezing;
+            self.idx = 0;
+        }
+
+        let mut result = Vec::with_capacity(n);
+        for _ in 0..n {
+            if self.idx == Self::RATE_WIDTH {
+                Self::apply_permutation(&mut self.state);
+                self.idx = 0;
+            }
+            result.push(self.state[self.idx]);
+            self.idx += 1;
+        }
+
+        result
+    }
+
+    /// Pads the state and applies an Anemoi permutation on the
+    /// last absorbed elements.
+    fn apply_padding(&mut self) {
+        // We append 1 to the rate cell next to the last absorbed element, the
+        // remaining cells of the rate portion being implicitly padded with 0.
+        // If the rate portion has been entirely filled, the padding spans a
+        // new block, absorbed after permuting the state.
+        if self.idx == Self::RATE_WIDTH {
+            Self::apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+        self.state[self.idx] += F::one();
+        Self::apply_permutation(&mut self.state);
+    }
+
+    #[inline(always)]
+    fn apply_permutation(state: &mut [F]) {
+        Anemoi::<F, NUM_COLUMNS, P>::apply_permutation(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use crate::{AnemoiHash, Sponge, SpongeXof};
+    use rand_core::OsRng;
+
+    struct DuplexCheck;
+
+    impl InstantiationCheck for DuplexCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let rate_width = 2 * NUM_COLUMNS - P::CAPACITY_WIDTH;
+
+            for len in [1, 2, 3, 7, 11, 12, 22, 23] {
+                // The padding rules only coincide for partially filled blocks.
+                if len % rate_width == 0 {
+                    continue;
+                }
+                let elems: Vec<F> = (0..len).map(|_| F::rand(&mut rng)).collect();
+
+                let mut duplex = AnemoiDuplex::<F, NUM_COLUMNS, P>::new();
+                duplex.absorb(&elems);
+                let output = duplex.squeeze(1);
+
+                let digest = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field(&elems);
+                assert_eq!(output[..], digest.as_elements()[..1]);
+
+                // Further squeezes follow the extendable-output mode.
+                let mut squeezed = output;
+                squeezed.extend(duplex.squeeze(2 * rate_width));
+                let xof = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field_xof(
+                    &elems,
+                    2 * rate_width + 1,
+                );
+                assert_eq!(squeezed, xof);
+            }
+
+            // The padding is injective: a trailing 1 filling the rate portion
+            // is not confused with the padding of the shorter sequence, which
+            // also covers the empty sequence when the rate is 1.
+            for len in [rate_width, 2 * rate_width] {
+                let mut elems: Vec<F> = (0..len).map(|_| F::rand(&mut rng)).collect();
+                elems[len - 1] = F::one();
+
+                let mut duplex_1 = AnemoiDuplex::<F, NUM_COLUMNS, P>::new();
+                duplex_1.absorb(&elems);
+                let mut duplex_2 = AnemoiDuplex::<F, NUM_COLUMNS, P>::new();
+                duplex_2.absorb(&elems[..len - 1]);
+                assert_ne!(duplex_1.squeeze(rate_width), duplex_2.squeeze(rate_width));
+            }
+
+            // Interleaved absorptions and squeezes depend on the whole transcript.
+            let a = F::rand(&mut rng);
+            let b = F::rand(&mut rng);
+
+            let mut duplex_1 = AnemoiDuplex::<F, NUM_COLUMNS, P>::new();
+            duplex_1.absorb(&[a]);
+            let challenge_1 = duplex_1.squeeze(1);
+            duplex_1.absorb(&[b]);
+            let challenge_2 = duplex_1.squeeze(1);
+
+            let mut duplex_2 = AnemoiDuplex::<F, NUM_COLUMNS, P>::new();
+            duplex_2.absorb(&[a, b]);
+            let challenge_3 = duplex_2.squeeze(1);
+
+            assert_ne!(challenge_1, challenge_2);
+            assert_ne!(challenge_2, challenge_3);
+
+            let mut duplex_3 = AnemoiDuplex::<F, NUM_COLUMNS, P>::new();
+            duplex_3.absorb(&[a]);
+            assert_eq!(duplex_3.squeeze(1), challenge_1);
+            duplex_3.absorb(&[b]);
+            assert_eq!(duplex_3.squeeze(1), challenge_2);
+        }
+    }
+
+    #[test]
+    fn test_duplex() {
+        check_all_instantiations::<DuplexCheck>();
+    }
+}
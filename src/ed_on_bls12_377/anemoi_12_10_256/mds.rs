@@ -0,0 +1,230 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x63327fffffffffd7,
+        0xa3b2f130afffffd5,
+        0x831fa6233b14cfc8,
+        0x028cc3d94ed9852a,
+    ])),
+    Felt::new(BigInteger256([
+        0xe04effffffffffca,
+        0x160ae6401fffffc7,
+        0x99f7bb988c40dfb7,
+        0x0fd89e0b7a951fc7,
+    ])),
+    Felt::new(BigInteger256([
+        0x5359ffffffffffbc,
+        0x2eb86450bfffffb9,
+        0x501b83ef81353fa4,
+        0x0a7912df0c24150e,
+    ])),
+    Felt::new(BigInteger256([
+        0xc664ffffffffffae,
+        0x4765e2615fffffaa,
+        0x063f4c4676299f91,
+        0x051987b29db30a55,
+    ])),
+    Felt::new(BigInteger256([
+        0xc664ffffffffffae,
+        0x4765e2615fffffaa,
+        0x063f4c4676299f91,
+        0x051987b29db30a55,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x63327fffffffffd7,
+        0xa3b2f130afffffd5,
+        0x831fa6233b14cfc8,
+        0x028cc3d94ed9852a,
+    ])),
+    Felt::new(BigInteger256([
+        0xe04effffffffffca,
+        0x160ae6401fffffc7,
+        0x99f7bb988c40dfb7,
+        0x0fd89e0b7a951fc7,
+    ])),
+    Felt::new(BigInteger256([
+        0x5359ffffffffffbc,
+        0x2eb86450bfffffb9,
+        0x501b83ef81353fa4,
+        0x0a7912df0c24150e,
+    ])),
+    Felt::new(BigInteger256([
+        0x5359ffffffffffbc,
+        0x2eb86450bfffffb9,
+        0x501b83ef81353fa4,
+        0x0a7912df0c24150e,
+    ])),
+    Felt::new(BigInteger256([
+        0xc664ffffffffffae,
+        0x4765e2615fffffaa,
+        0x063f4c4676299f91,
+        0x051987b29db30a55,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x63327fffffffffd7,
+        0xa3b2f130afffffd5,
+        0x831fa6233b14cfc8,
+        0x028cc3d94ed9852a,
+    ])),
+    Felt::new(BigInteger256([
+        0xe04effffffffffca,
+        0x160ae6401fffffc7,
+        0x99f7bb988c40dfb7,
+        0x0fd89e0b7a951fc7,
+    ])),
+    Felt::new(BigInteger256([
+        0xe04effffffffffca,
+        0x160ae6401fffffc7,
+        0x99f7bb988c40dfb7,
+        0x0fd89e0b7a951fc7,
+    ])),
+    Felt::new(BigInteger256([
+        0x5359ffffffffffbc,
+        0x2eb86450bfffffb9,
+        0x501b83ef81353fa4,
+        0x0a7912df0c24150e,
+    ])),
+    Felt::new(BigInteger256([
+        0xc664ffffffffffae,
+        0x4765e2615fffffaa,
+        0x063f4c4676299f91,
+        0x051987b29db30a55,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x63327fffffffffd7,
+        0xa3b2f130afffffd5,
+        0x831fa6233b14cfc8,
+        0x028cc3d94ed9852a,
+    ])),
+    Felt::new(BigInteger256([
+        0x63327fffffffffd7,
+        0xa3b2f130afffffd5,
+        0x831fa6233b14cfc8,
+        0x028cc3d94ed9852a,
+    ])),
+    Felt::new(BigInteger256([
+        0xe04effffffffffca,
+        0x160ae6401fffffc7,
+        0x99f7bb988c40dfb7,
+        0x0fd89e0b7a951fc7,
+    ])),
+    Felt::new(BigInteger256([
+        0x5359ffffffffffbc,
+        0x2eb86450bfffffb9,
+        0x501b83ef81353fa4,
+        0x0a7912df0c24150e,
+    ])),
+    Felt::new(BigInteger256([
+        0xc664ffffffffffae,
+        0x4765e2615fffffaa,
+        0x063f4c4676299f91,
+        0x051987b29db30a55,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+    Felt::new(BigInteger256([
+        0x63327fffffffffd7,
+        0xa3b2f130afffffd5,
+        0x831fa6233b14cfc8,
+        0x028cc3d94ed9852a,
+    ])),
+    Felt::new(BigInteger256([
+        0xe04effffffffffca,
+        0x160ae6401fffffc7,
+        0x99f7bb988c40dfb7,
+        0x0fd89e0b7a951fc7,
+    ])),
+    Felt::new(BigInteger256([
+        0x5359ffffffffffbc,
+        0x2eb86450bfffffb9,
+        0x501b83ef81353fa4,
+        0x0a7912df0c24150e,
+    ])),
+    Felt::new(BigInteger256([
+        0xc664ffffffffffae,
+        0x4765e2615fffffaa,
+        0x063f4c4676299f91,
+        0x051987b29db30a55,
+    ])),
+    Felt::new(BigInteger256([
+        0x7d1c7ffffffffff3,
+        0x7257f50f6ffffff2,
+        0x16d81575512c0fee,
+        0x0d4bda322bbb9a9d,
+    ])),
+];
@@ -0,0 +1,543 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xabafffffffff9120,
+            0x4bb663a9ffff8cc3,
+            0xe91979cf61a56a4a,
+            0x0478a6f9e9e436dd,
+        ])),
+        Felt::new(BigInteger256([
+            0xa36f1774329ceb88,
+            0x28c335a5daf88982,
+            0x5c91edea0ad0b3f7,
+            0x020ad5f2bd639b37,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbfdc959fc7b4b4f6,
+            0xa1645fd4ff9605ce,
+            0xd5691b0520c7782e,
+            0x0a14dedd9894572a,
+        ])),
+        Felt::new(BigInteger256([
+            0x3fa34fcfa658b7b6,
+            0x926fbf1bfad80b3f,
+            0xfd04325b984e4684,
+            0x0e9782736f581fef,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa8710aeddc00c31f,
+            0x48d555e0b98bbcdc,
+            0x90c713f2837ff521,
+            0x0a1f01b2d09a9963,
+        ])),
+        Felt::new(BigInteger256([
+            0xe657fdc69ffcdada,
+            0x6e84f0fa5ab7542c,
+            0x2104c49ea979958a,
+            0x05403d5ed50440f7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa747c810b42443fb,
+            0xf6d421a4dfe10249,
+            0xc87bd48e64d5d249,
+            0x0b6b19608e0494d1,
+        ])),
+        Felt::new(BigInteger256([
+            0x3f1d72712937bbaa,
+            0x3a9f2e75f5d7855f,
+            0x07951c993e46fa3a,
+            0x007308631618c1ed,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4b84564b4ed4cc40,
+            0x614fb3d8ba6fa258,
+            0x6d21dc5b9cbc24a9,
+            0x0d7804aeee114147,
+        ])),
+        Felt::new(BigInteger256([
+            0xe01e615d06512a64,
+            0xa28cb477c1492122,
+            0xba7d2e5c4fe4cef3,
+            0x05e902094d782d9e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x094d0862efc8d4b2,
+            0x518ff99b3760e6b7,
+            0x14b68a84040da5fe,
+            0x0b4b897ead371aa6,
+        ])),
+        Felt::new(BigInteger256([
+            0x52aea826b11aadff,
+            0x929582b0645b6b26,
+            0xa01402c60eae13b4,
+            0x100fc88c161df24b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb65d03158e8d330a,
+            0x062c677ab9fb38fa,
+            0x395365290dc341a3,
+            0x075e6fec1dbc6a05,
+        ])),
+        Felt::new(BigInteger256([
+            0x94721b95c7cb761d,
+            0x84d886e209688d3b,
+            0xb7ba836bf79463e2,
+            0x0af0a069cd1110b1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x405cc568b090bbf5,
+            0x90216c2a4a1335e3,
+            0xdabc18b784bdda4e,
+            0x0c3faca6b6e1fb47,
+        ])),
+        Felt::new(BigInteger256([
+            0x3079e6f2573ff59e,
+            0x855611e8f887ab17,
+            0x288fcf9369d2a90d,
+            0x12601cffbbd2fbf8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1ce64c4fbff3bf46,
+            0x9ef195190fc39b99,
+            0x4f7f6c077b257685,
+            0x0af1b0422db4e68e,
+        ])),
+        Felt::new(BigInteger256([
+            0xc6edee6afd0b777e,
+            0x1b9266ccc595d5e8,
+            0x0d6bc08b6d0675e9,
+            0x0e073b16fed33b0f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd81c4503f4186b9b,
+            0xf3e86355d2c3a343,
+            0xa8655929d372f300,
+            0x0bf60b0e67b9f46c,
+        ])),
+        Felt::new(BigInteger256([
+            0xfbbc1438b710430c,
+            0xcd9b2c6ef7ce1746,
+            0x80d7948d66a027bd,
+            0x0ce0f616bfc893a6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x22235cc554950665,
+            0x363b64395e607ef2,
+            0x1f59bbca2a70ec44,
+            0x0ae482c5e3408c20,
+        ])),
+        Felt::new(BigInteger256([
+            0xa26341d4467c4890,
+            0x6a352c3c55a6480d,
+            0xceeaf5ee348d3ced,
+            0x0a841d54a5fc1775,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0094ddf87b73c62e,
+            0x3b57d5da6184528d,
+            0x29c964eae02a0341,
+            0x1195c5cb1bb5e57c,
+        ])),
+        Felt::new(BigInteger256([
+            0x3f5deb32ee7bbfc8,
+            0xacba49b99f6ad216,
+            0x9de0976e818a3b93,
+            0x0d5dd122d5778533,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3aea95da21aad6b7,
+            0xb05d537cda9edf7a,
+            0x7c96f97b91ff07f2,
+            0x084cd270a8033917,
+        ])),
+        Felt::new(BigInteger256([
+            0xe5196d7f9bd4dcb1,
+            0x9ff7cf582163768e,
+            0x21caac51aa552b02,
+            0x0f2ffbf84feb006c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbf6ac3357d5d3ee5,
+            0x7ec50f143ce32fac,
+            0xec483d394b227566,
+            0x04aa7447819c2e65,
+        ])),
+        Felt::new(BigInteger256([
+            0xfd0fbd96f7d8f8d1,
+            0xf9939ee784b98841,
+            0x7f47555077313aba,
+            0x07964b4b4be865f4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa894002e33c4357f,
+            0x383c0137fefacf63,
+            0x24af7606f48aa710,
+            0x0097eda5ce18dd64,
+        ])),
+        Felt::new(BigInteger256([
+            0x77b29a87ef1f5b9b,
+            0xc436edb4750053ff,
+            0xa4998266da0d48ec,
+            0x0edd4a20dc850f6a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb2eec9a28e2eba20,
+            0xf607d183f5f84668,
+            0x56a7d5102c268c50,
+            0x010f5901b594d4f1,
+        ])),
+        Felt::new(BigInteger256([
+            0xb2b0c27ea0f975e0,
+            0x0a10b0f05037ae0f,
+            0xc72422f6e653727d,
+            0x0e688a55d960ab49,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x366fc14bedfaf150,
+            0xd3346a63f63e005f,
+            0xe4b5fc9ceb75bcec,
+            0x0c23180cc5314c76,
+        ])),
+        Felt::new(BigInteger256([
+            0x90b80a5ce6a5091b,
+            0xd64712c5f10ea495,
+            0x7fc0413bf66a8ae5,
+            0x07f0d2e8f9e145fe,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x71c44f0b812c42c5,
+            0x55fb5c584f7fa7fd,
+            0xbd2ca413a3038998,
+            0x0a82de612f0e3d31,
+        ])),
+        Felt::new(BigInteger256([
+            0x9845d453e0bc4ce4,
+            0x7042d69b89a14748,
+            0xc73c8f3dd35d813a,
+            0x01d9cc22e06275c2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x20f4cbf8301ef131,
+            0x2a5eadc2e149e8eb,
+            0xf7935899ca6c4c50,
+            0x037af445be4ca591,
+        ])),
+        Felt::new(BigInteger256([
+            0x2379d7be0a9ca4f1,
+            0x243a291d6220a3f3,
+            0x0ea6eb9c7fc624f3,
+            0x115025bb3b6e6194,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x631f9745d173ee37,
+            0x4a87e61eafff8cc3,
+            0xe5ff9352bcdc0bbe,
+            0x0b0416f762c0c3f4,
+        ])),
+        Felt::new(BigInteger256([
+            0x912b24914e2783db,
+            0x0b810b6bebc95515,
+            0x74d39a137bdb3a53,
+            0x0ea663ad0ca26cdc,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x87f729b70dd07c39,
+            0x834a0803a0820bb8,
+            0x317e1fc7d2e63bf4,
+            0x0d71b42dcbdf1706,
+        ])),
+        Felt::new(BigInteger256([
+            0x33f8d9be368aba34,
+            0xfe97439d2c94dcbb,
+            0x13c07ca604093f30,
+            0x05591021ded87f03,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf4c0bcf4a6c3cc60,
+            0xd8d96837dbe528c6,
+            0x69b35452c2620234,
+            0x0badb273ea671d56,
+        ])),
+        Felt::new(BigInteger256([
+            0x68f425a4b4d61f57,
+            0xe27556a2dde18baa,
+            0x154c97a4fe2f8785,
+            0x0cdf0bdcc5330978,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x900b244fb0904625,
+            0xb096a2690fc1e758,
+            0x6a6bf60727070908,
+            0x041ee3f01f042972,
+        ])),
+        Felt::new(BigInteger256([
+            0x683ec4876fb9f911,
+            0x31f8798a56893602,
+            0x25951dd67283c5e2,
+            0x11e2560e17a74072,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9ac5ecc7cbcdbdce,
+            0x1c3798121f107e06,
+            0xd370b8d18d209d77,
+            0x0ee4a9c71adbe1a8,
+        ])),
+        Felt::new(BigInteger256([
+            0x65ac6db0cd60572e,
+            0x4160ec0286bac864,
+            0x3c279d78561d2ca9,
+            0x0d65c4de50a5128e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd43665b6caf64b48,
+            0xbbdc285be039533f,
+            0x0327decf2d91be11,
+            0x1054f5b00a8be98e,
+        ])),
+        Felt::new(BigInteger256([
+            0x49d2fb51d65e5fd0,
+            0x87238dc39e04a341,
+            0x492c9c98f1ce60ae,
+            0x087ded1bafa8606b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcc9541c055d23450,
+            0x0b1903f37e920489,
+            0xe9f35fa9483e30d9,
+            0x123e2e636f1b0391,
+        ])),
+        Felt::new(BigInteger256([
+            0xd6e55017d926b29e,
+            0x1406ffad5ed0245c,
+            0x2301c373ebab87ff,
+            0x0935173f5aa54976,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc2b22d9af007839b,
+            0xa6095ae2c11500d7,
+            0xea02a77c314478d6,
+            0x024c9ce75cfe9049,
+        ])),
+        Felt::new(BigInteger256([
+            0xe91bc4fbe0ccf880,
+            0x7f2a53f2d05a419e,
+            0x5331f0fe2c2d2c7d,
+            0x0e7d2afd3851d588,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x23ddc3fffe4a2579,
+            0xa7fd28b2360523b3,
+            0xae0096e00d9ab955,
+            0x087cc0ab19d909c3,
+        ])),
+        Felt::new(BigInteger256([
+            0x0431dbf2857818ed,
+            0x088a4db74ca82996,
+            0x87487e0a154f9da1,
+            0x11a2693cc159a2d2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd3c34969c9626da6,
+            0x5f45bdcc3741afc8,
+            0x31dd3ecec0046cb3,
+            0x0ace99c7ee0eaafa,
+        ])),
+        Felt::new(BigInteger256([
+            0x2daf8e75d6708053,
+            0x1ce4da36bd1cef5f,
+            0x25ab0cd869058658,
+            0x11c9a28d1c7f8ec2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x55d670d1af2e2598,
+            0xd4a98a5e3da47bd8,
+            0x67dae10f5e058bbc,
+            0x05fbf7abbc9d040c,
+        ])),
+        Felt::new(BigInteger256([
+            0x0c62cbb7eb2ba2ff,
+            0xec8fa5b295bb1087,
+            0x32c7add97df5c14d,
+            0x0babaff755bad3f0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf9306160bb9162fa,
+            0x6ff472054edb827e,
+            0x5efd875b858c043d,
+            0x0bd2af30dd22ec33,
+        ])),
+        Felt::new(BigInteger256([
+            0x6e45e47278af97d0,
+            0xc5433935ed92cd9b,
+            0xee704c853cc02177,
+            0x0daad8456d46d078,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x56903eb82dcff70b,
+            0x8d917e507232a489,
+            0xf8c19538899bd53f,
+            0x03c695341f2f4d94,
+        ])),
+        Felt::new(BigInteger256([
+            0x370b8c34f2103841,
+            0x61184d7d19c80731,
+            0xb950dab4b7c5dd37,
+            0x10b9dc789d795977,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbbea813202cbfa14,
+            0x78c9d753c2be8f5f,
+            0x9f233fd053259001,
+            0x0acb100b846b46cc,
+        ])),
+        Felt::new(BigInteger256([
+            0x25ca716ac75def3b,
+            0x7dda43799b65b387,
+            0xecc99d6f38d08a3c,
+            0x011b9f6d8aed1d92,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb008e589a1511380,
+            0x5574b1151044d103,
+            0x29239469e348a5d4,
+            0x09c957cfec948c63,
+        ])),
+        Felt::new(BigInteger256([
+            0xab6275baa6c274d7,
+            0x6bb179e4171b2131,
+            0x63b4e65182677c97,
+            0x0b736ca937365da1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9dde36649c6a8a6d,
+            0xf623c0aa32ec2b22,
+            0xd51a20f1efd4b082,
+            0x08dae778d9298911,
+        ])),
+        Felt::new(BigInteger256([
+            0xc9db2517f94b8168,
+            0x946e7c691dfc5e5b,
+            0x003db460639dcb95,
+            0x0998d12b392afea2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1379fa8a75ed676b,
+            0xc86da850a859f618,
+            0x80bbe64735fe7597,
+            0x11ef58ac36c36a21,
+        ])),
+        Felt::new(BigInteger256([
+            0x99fd3972b8adba71,
+            0x55c22d0533fb65e0,
+            0xd66d706dfa8f7877,
+            0x0121cbe6a7a902e0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd83279612d1ba95d,
+            0x39489cbfa3975bf2,
+            0x55906677b1509f0b,
+            0x0c36fa18ade3312a,
+        ])),
+        Felt::new(BigInteger256([
+            0x35007480d6c1eeb8,
+            0x377c6a543e89c6d1,
+            0x7afbe447f77e7b95,
+            0x099e05973599ae49,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8dd4b778d0bf8f8d,
+            0x01b742f21ded67d1,
+            0x93ffda14f2601d62,
+            0x080372b7f1ce3ddc,
+        ])),
+        Felt::new(BigInteger256([
+            0xbc94b915f5537e88,
+            0x85d49a9f2f94ee6b,
+            0x65bab29f61562aeb,
+            0x093d5c8bab259916,
+        ])),
+    ],
+];
@@ -0,0 +1,291 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xabafffffffff9120,
+            0x4bb663a9ffff8cc3,
+            0xe91979cf61a56a4a,
+            0x0478a6f9e9e436dd,
+        ])),
+        Felt::new(BigInteger256([
+            0xa36f1774329ceb88,
+            0x28c335a5daf88982,
+            0x5c91edea0ad0b3f7,
+            0x020ad5f2bd639b37,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbfdc959fc7b4b4f6,
+            0xa1645fd4ff9605ce,
+            0xd5691b0520c7782e,
+            0x0a14dedd9894572a,
+        ])),
+        Felt::new(BigInteger256([
+            0x3fa34fcfa658b7b6,
+            0x926fbf1bfad80b3f,
+            0xfd04325b984e4684,
+            0x0e9782736f581fef,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa8710aeddc00c31f,
+            0x48d555e0b98bbcdc,
+            0x90c713f2837ff521,
+            0x0a1f01b2d09a9963,
+        ])),
+        Felt::new(BigInteger256([
+            0xe657fdc69ffcdada,
+            0x6e84f0fa5ab7542c,
+            0x2104c49ea979958a,
+            0x05403d5ed50440f7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa747c810b42443fb,
+            0xf6d421a4dfe10249,
+            0xc87bd48e64d5d249,
+            0x0b6b19608e0494d1,
+        ])),
+        Felt::new(BigInteger256([
+            0x3f1d72712937bbaa,
+            0x3a9f2e75f5d7855f,
+            0x07951c993e46fa3a,
+            0x007308631618c1ed,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4b84564b4ed4cc40,
+            0x614fb3d8ba6fa258,
+            0x6d21dc5b9cbc24a9,
+            0x0d7804aeee114147,
+        ])),
+        Felt::new(BigInteger256([
+            0xe01e615d06512a64,
+            0xa28cb477c1492122,
+            0xba7d2e5c4fe4cef3,
+            0x05e902094d782d9e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x094d0862efc8d4b2,
+            0x518ff99b3760e6b7,
+            0x14b68a84040da5fe,
+            0x0b4b897ead371aa6,
+        ])),
+        Felt::new(BigInteger256([
+            0x52aea826b11aadff,
+            0x929582b0645b6b26,
+            0xa01402c60eae13b4,
+            0x100fc88c161df24b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb65d03158e8d330a,
+            0x062c677ab9fb38fa,
+            0x395365290dc341a3,
+            0x075e6fec1dbc6a05,
+        ])),
+        Felt::new(BigInteger256([
+            0x94721b95c7cb761d,
+            0x84d886e209688d3b,
+            0xb7ba836bf79463e2,
+            0x0af0a069cd1110b1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x405cc568b090bbf5,
+            0x90216c2a4a1335e3,
+            0xdabc18b784bdda4e,
+            0x0c3faca6b6e1fb47,
+        ])),
+        Felt::new(BigInteger256([
+            0x3079e6f2573ff59e,
+            0x855611e8f887ab17,
+            0x288fcf9369d2a90d,
+            0x12601cffbbd2fbf8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1ce64c4fbff3bf46,
+            0x9ef195190fc39b99,
+            0x4f7f6c077b257685,
+            0x0af1b0422db4e68e,
+        ])),
+        Felt::new(BigInteger256([
+            0xc6edee6afd0b777e,
+            0x1b9266ccc595d5e8,
+            0x0d6bc08b6d0675e9,
+            0x0e073b16fed33b0f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd81c4503f4186b9b,
+            0xf3e86355d2c3a343,
+            0xa8655929d372f300,
+            0x0bf60b0e67b9f46c,
+        ])),
+        Felt::new(BigInteger256([
+            0xfbbc1438b710430c,
+            0xcd9b2c6ef7ce1746,
+            0x80d7948d66a027bd,
+            0x0ce0f616bfc893a6,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x631f9745d173ee37,
+            0x4a87e61eafff8cc3,
+            0xe5ff9352bcdc0bbe,
+            0x0b0416f762c0c3f4,
+        ])),
+        Felt::new(BigInteger256([
+            0x912b24914e2783db,
+            0x0b810b6bebc95515,
+            0x74d39a137bdb3a53,
+            0x0ea663ad0ca26cdc,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x87f729b70dd07c39,
+            0x834a0803a0820bb8,
+            0x317e1fc7d2e63bf4,
+            0x0d71b42dcbdf1706,
+        ])),
+        Felt::new(BigInteger256([
+            0x33f8d9be368aba34,
+            0xfe97439d2c94dcbb,
+            0x13c07ca604093f30,
+            0x05591021ded87f03,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf4c0bcf4a6c3cc60,
+            0xd8d96837dbe528c6,
+            0x69b35452c2620234,
+            0x0badb273ea671d56,
+        ])),
+        Felt::new(BigInteger256([
+            0x68f425a4b4d61f57,
+            0xe27556a2dde18baa,
+            0x154c97a4fe2f8785,
+            0x0cdf0bdcc5330978,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x900b244fb0904625,
+            0xb096a2690fc1e758,
+            0x6a6bf60727070908,
+            0x041ee3f01f042972,
+        ])),
+        Felt::new(BigInteger256([
+            0x683ec4876fb9f911,
+            0x31f8798a56893602,
+            0x25951dd67283c5e2,
+            0x11e2560e17a74072,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9ac5ecc7cbcdbdce,
+            0x1c3798121f107e06,
+            0xd370b8d18d209d77,
+            0x0ee4a9c71adbe1a8,
+        ])),
+        Felt::new(BigInteger256([
+            0x65ac6db0cd60572e,
+            0x4160ec0286bac864,
+            0x3c279d78561d2ca9,
+            0x0d65c4de50a5128e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd43665b6caf64b48,
+            0xbbdc285be039533f,
+            0x0327decf2d91be11,
+            0x1054f5b00a8be98e,
+        ])),
+        Felt::new(BigInteger256([
+            0x49d2fb51d65e5fd0,
+            0x87238dc39e04a341,
+            0x492c9c98f1ce60ae,
+            0x087ded1bafa8606b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcc9541c055d23450,
+            0x0b1903f37e920489,
+            0xe9f35fa9483e30d9,
+            0x123e2e636f1b0391,
+        ])),
+        Felt::new(BigInteger256([
+            0xd6e55017d926b29e,
+            0x1406ffad5ed0245c,
+            0x2301c373ebab87ff,
+            0x0935173f5aa54976,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc2b22d9af007839b,
+            0xa6095ae2c11500d7,
+            0xea02a77c314478d6,
+            0x024c9ce75cfe9049,
+        ])),
+        Felt::new(BigInteger256([
+            0xe91bc4fbe0ccf880,
+            0x7f2a53f2d05a419e,
+            0x5331f0fe2c2d2c7d,
+            0x0e7d2afd3851d588,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x23ddc3fffe4a2579,
+            0xa7fd28b2360523b3,
+            0xae0096e00d9ab955,
+            0x087cc0ab19d909c3,
+        ])),
+        Felt::new(BigInteger256([
+            0x0431dbf2857818ed,
+            0x088a4db74ca82996,
+            0x87487e0a154f9da1,
+            0x11a2693cc159a2d2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd3c34969c9626da6,
+            0x5f45bdcc3741afc8,
+            0x31dd3ecec0046cb3,
+            0x0ace99c7ee0eaafa,
+        ])),
+        Felt::new(BigInteger256([
+            0x2daf8e75d6708053,
+            0x1ce4da36bd1cef5f,
+            0x25ab0cd869058658,
+            0x11c9a28d1c7f8ec2,
+        ])),
+    ],
+];
@@ -0,0 +1,711 @@
+//! Arithmetic over 256-bit prime fields without spare bit
+//!
+//! The generic prime field types of arkworks require the modulus to leave at
+//! least one spare bit in the most significant limb of its representation, and
+//! silently drop carries otherwise. This module provides a prime field type
+//! over four limbs supporting any odd modulus `2^255 < p < 2^256`, such as the
+//! base and scalar fields of secp256k1. Elements are stored in Montgomery form,
+//! with `R = 2^256`, and the field parameters are expected in the same format
+//! as for the arkworks `Fp256` type.
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+use ark_ff::{
+    BigInteger, BigInteger256, FftField, Field, FpParameters, FromBytes, One, PrimeField, ToBytes,
+    Zero,
+};
+use ark_serialize::{
+    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, EmptyFlags, Flags, SerializationError,
+};
+use ark_std::io::{Error, ErrorKind, Read, Result as IoResult, Write};
+use ark_std::rand::distributions::{Distribution, Standard};
+use ark_std::rand::Rng;
+use num_bigint::BigUint;
+use zeroize::Zeroize;
+
+/// Parameters of a prime field over four limbs whose modulus
+/// may use all 256 bits of its representation.
+pub trait FullFp256Parameters: FpParameters<BigInt = BigInteger256> {}
+
+/// An element of a 256-bit prime field, stored in Montgomery form.
+pub struct FullFp256<P>(BigInteger256, PhantomData<P>);
+
+impl<P: FullFp256Parameters> FullFp256<P> {
+    /// Returns a field element from its Montgomery representation,
+    /// which must be smaller than the modulus.
+    pub const fn new(repr: BigInteger256) -> Self {
+        Self(repr, PhantomData)
+    }
+
+    /// Returns `true` if the provided integer is smaller than the modulus.
+    #[inline(always)]
+    fn is_valid(repr: &BigInteger256) -> bool {
+        *repr < P::MODULUS
+    }
+
+    /// Computes the Montgomery product `a * b / 2^256 mod p`, with the
+    /// coarsely integrated operand scanning method. The intermediate value
+    /// fits over five limbs and an additional carry bit, as it never
+    /// exceeds `2p`, and is reduced to its canonical form at the end.
+    #[inline(always)]
+    fn mont_mul(a: &BigInteger256, b: &BigInteger256) -> BigInteger256 {
+        let modulus = P::MODULUS.0;
+        let mut t = [0u64; 5];
+
+        for &b_i in b.0.iter() {
+            let mut carry = 0;
+            for (t_j, &a_j) in t.iter_mut().zip(a.0.iter()) {
+                (*t_j, carry) = mac(*t_j, a_j, b_i, carry);
+            }
+            let (t_4, t_5) = adc(t[4], carry, 0);
+
+            let m = t[0].wrapping_mul(P::INV);
+            let (_, mut carry) = mac(t[0], m, modulus[0], 0);
+            for j in 1..4 {
+                (t[j - 1], carry) = mac(t[j], m, modulus[j], carry);
+            }
+            let (t_3, carry) = adc(t_4, carry, 0);
+            t[3] = t_3;
+            t[4] = t_5 + carry;
+        }
+
+        let mut result = BigInteger256([t[0], t[1], t[2], t[3]]);
+        if t[4] != 0 || !Self::is_valid(&result) {
+            // The borrow cancels out the high limb, if any.
+            result.sub_noborrow(&P::MODULUS);
+        }
+
+        result
+    }
+}
+
+/// Computes `a + b * c + carry`, returning the low and high limbs.
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let tmp = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+    (tmp as u64, (tmp >> 64) as u64)
+}
+
+/// Computes `a + b + carry`, returning the low and high limbs.
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let tmp = (a as u128) + (b as u128) + (carry as u128);
+    (tmp as u64, (tmp >> 64) as u64)
+}
+
+// The traits below are implemented manually to not require
+// the parameters type to implement them.
+
+impl<P> Clone for FullFp256<P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for FullFp256<P> {}
+
+impl<P> Default for FullFp256<P> {
+    #[inline]
+    fn default() -> Self {
+        Self(BigInteger256::default(), PhantomData)
+    }
+}
+
+impl<P> PartialEq for FullFp256<P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<P> Eq for FullFp256<P> {}
+
+impl<P> Hash for FullFp256<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<P: FullFp256Parameters> Zero for FullFp256<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: FullFp256Parameters> One for FullFp256<P> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(P::R)
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == P::R
+    }
+}
+
+impl<P: FullFp256Parameters> Field for FullFp256<P> {
+    type BasePrimeField = Self;
+
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() != 1 {
+            return None;
+        }
+        Some(elems[0])
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        *self + *self
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = self.double();
+        self
+    }
+
+    #[inline]
+    fn characteristic() -> &'static [u64] {
+        P::MODULUS.as_ref()
+    }
+
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        if F::BIT_SIZE > 8 {
+            return None;
+        }
+
+        let mut result_bytes = [0u8; 33];
+        result_bytes
+            .iter_mut()
+            .zip(bytes)
+            .for_each(|(result, input)| *result = *input);
+
+        // The flags are located in the last byte of the serialized form,
+        // which lies outside of the 32 bytes of the element if there are any.
+        let output_byte_size = buffer_byte_size(256 + F::BIT_SIZE);
+        let flags_mask = u8::MAX.checked_shl(8 - (F::BIT_SIZE as u32)).unwrap_or(0);
+        let flags = result_bytes[output_byte_size - 1] & flags_mask;
+
+        let repr = BigInteger256::read(&result_bytes[..32]).ok()?;
+        Self::from_repr(repr).and_then(|f| F::from_u8(flags).map(|flag| (f, flag)))
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        *self * *self
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        *self = self.square();
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            let mut exponent = P::MODULUS;
+            exponent.sub_noborrow(&BigInteger256::from(2));
+            Some(self.pow(exponent))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        self.inverse().map(|inverse| {
+            *self = inverse;
+            self
+        })
+    }
+
+    /// The Frobenius map has no effect in a prime field.
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {}
+}
+
+impl<P: FullFp256Parameters> FftField for FullFp256<P> {
+    type FftParams = P;
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self::new(P::TWO_ADIC_ROOT_OF_UNITY)
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        P::LARGE_SUBGROUP_ROOT_OF_UNITY.map(Self::new)
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self::new(P::GENERATOR)
+    }
+}
+
+impl<P: FullFp256Parameters> PrimeField for FullFp256<P> {
+    type Params = P;
+    type BigInt = BigInteger256;
+
+    #[inline]
+    fn from_repr(repr: BigInteger256) -> Option<Self> {
+        if Self::is_valid(&repr) {
+            Some(Self::new(Self::mont_mul(&repr, &P::R2)))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn into_repr(&self) -> BigInteger256 {
+        Self::mont_mul(&self.0, &BigInteger256::from(1))
+    }
+}
+
+/// Compares field elements viewing them as integers in the range
+/// `0, 1, ..., p - 1`, as done for the arkworks prime field types.
+impl<P: FullFp256Parameters> Ord for FullFp256<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.into_repr().cmp(&other.into_repr())
+    }
+}
+
+impl<P: FullFp256Parameters> PartialOrd for FullFp256<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P> Zeroize for FullFp256<P> {
+    fn zeroize(&mut self) {
+        self.0 .0.zeroize();
+    }
+}
+
+impl<P: FullFp256Parameters> Distribution<FullFp256<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FullFp256<P> {
+        loop {
+            let repr = BigInteger256([rng.gen(), rng.gen(), rng.gen(), rng.gen()]);
+            if FullFp256::<P>::is_valid(&repr) {
+                return FullFp256::new(repr);
+            }
+        }
+    }
+}
+
+impl<P: FullFp256Parameters> From<u128> for FullFp256<P> {
+    fn from(other: u128) -> Self {
+        // Any 128-bit integer is smaller than the modulus.
+        Self::from_repr(BigInteger256([other as u64, (other >> 64) as u64, 0, 0])).unwrap()
+    }
+}
+
+impl<P: FullFp256Parameters> From<u64> for FullFp256<P> {
+    fn from(other: u64) -> Self {
+        Self::from(other as u128)
+    }
+}
+
+impl<P: FullFp256Parameters> From<u32> for FullFp256<P> {
+    fn from(other: u32) -> Self {
+        Self::from(other as u128)
+    }
+}
+
+impl<P: FullFp256Parameters> From<u16> for FullFp256<P> {
+    fn from(other: u16) -> Self {
+        Self::from(other as u128)
+    }
+}
+
+impl<P: FullFp256Parameters> From<u8> for FullFp256<P> {
+    fn from(other: u8) -> Self {
+        Self::from(other as u128)
+    }
+}
+
+impl<P: FullFp256Parameters> From<bool> for FullFp256<P> {
+    fn from(other: bool) -> Self {
+        Self::from(other as u128)
+    }
+}
+
+impl<P: FullFp256Parameters> From<BigInteger256> for FullFp256<P> {
+    /// Converts a canonical representation into a field element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the representation is not smaller than the modulus.
+    fn from(repr: BigInteger256) -> Self {
+        Self::from_repr(repr).unwrap()
+    }
+}
+
+impl<P: FullFp256Parameters> From<FullFp256<P>> for BigInteger256 {
+    fn from(element: FullFp256<P>) -> Self {
+        element.into_repr()
+    }
+}
+
+impl<P: FullFp256Parameters> From<BigUint> for FullFp256<P> {
+    fn from(value: BigUint) -> Self {
+        Self::from_le_bytes_mod_order(&value.to_bytes_le())
+    }
+}
+
+impl<P: FullFp256Parameters> From<FullFp256<P>> for BigUint {
+    fn from(element: FullFp256<P>) -> Self {
+        BigUint::from_bytes_le(&element.into_repr().to_bytes_le())
+    }
+}
+
+impl<P: FullFp256Parameters> FromStr for FullFp256<P> {
+    type Err = ();
+
+    /// Interprets a string of decimal digits as a (congruent) field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || (s.starts_with('0') && s != "0") {
+            return Err(());
+        }
+
+        let ten = Self::from(10u8);
+        s.chars().try_fold(Self::zero(), |acc, c| {
+            let digit = c.to_digit(10).ok_or(())?;
+            Ok(acc * ten + Self::from(digit))
+        })
+    }
+}
+
+impl<P: FullFp256Parameters> Debug for FullFp256<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "FullFp256({})", self.into_repr())
+    }
+}
+
+impl<P: FullFp256Parameters> Display for FullFp256<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "FullFp256({})", self.into_repr())
+    }
+}
+
+impl<P: FullFp256Parameters> ToBytes for FullFp256<P> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.into_repr().write(writer)
+    }
+}
+
+impl<P: FullFp256Parameters> FromBytes for FullFp256<P> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        BigInteger256::read(reader).and_then(|repr| {
+            Self::from_repr(repr)
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "FromBytes::read failed"))
+        })
+    }
+}
+
+impl<P: FullFp256Parameters> CanonicalSerializeWithFlags for FullFp256<P> {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+
+        let output_byte_size = buffer_byte_size(256 + F::BIT_SIZE);
+
+        let mut bytes = [0u8; 33];
+        self.write(&mut bytes[..32])?;
+        bytes[output_byte_size - 1] |= flags.u8_bitmask();
+
+        writer.write_all(&bytes[..output_byte_size])?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        buffer_byte_size(256 + F::BIT_SIZE)
+    }
+}
+
+impl<P: FullFp256Parameters> CanonicalSerialize for FullFp256<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl<P: FullFp256Parameters> CanonicalDeserializeWithFlags for FullFp256<P> {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+
+        let output_byte_size = buffer_byte_size(256 + F::BIT_SIZE);
+
+        let mut masked_bytes = [0u8; 33];
+        reader.read_exact(&mut masked_bytes[..output_byte_size])?;
+
+        // The flags never overlap with the 32 bytes of the element,
+        // and the 33rd byte is left to zero when there are none.
+        let flags = F::from_u8_remove_flags(&mut masked_bytes[32])
+            .ok_or(SerializationError::UnexpectedFlags)?;
+
+        Ok((Self::read(&masked_bytes[..32])?, flags))
+    }
+}
+
+impl<P: FullFp256Parameters> CanonicalDeserialize for FullFp256<P> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(r, _)| r)
+    }
+}
+
+// ARITHMETIC
+// ================================================================================================
+
+impl<P: FullFp256Parameters> Neg for FullFp256<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            let mut result = P::MODULUS;
+            result.sub_noborrow(&self.0);
+            Self::new(result)
+        }
+    }
+}
+
+impl<P: FullFp256Parameters> AddAssign<&Self> for FullFp256<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        let carry = self.0.add_nocarry(&other.0);
+        // On carry, the wrapped sum is smaller than p,
+        // and the borrow below cancels out 2^256.
+        if carry || !Self::is_valid(&self.0) {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+}
+
+impl<P: FullFp256Parameters> SubAssign<&Self> for FullFp256<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        // On borrow, 2^256 has been added to the difference,
+        // and is replaced with p by the carry below.
+        if self.0.sub_noborrow(&other.0) {
+            self.0.add_nocarry(&P::MODULUS);
+        }
+    }
+}
+
+impl<P: FullFp256Parameters> MulAssign<&Self> for FullFp256<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        self.0 = Self::mont_mul(&self.0, &other.0);
+    }
+}
+
+impl<P: FullFp256Parameters> DivAssign<&Self> for FullFp256<P> {
+    /// Multiplies `self` by `other.inverse()`, and panics
+    /// if `other` is zero.
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, other: &Self) {
+        *self *= &other.inverse().unwrap();
+    }
+}
+
+macro_rules! impl_ops_from_ref {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident;)+) => {
+        $(
+            impl<P: FullFp256Parameters> $assign_trait<Self> for FullFp256<P> {
+                #[inline]
+                fn $assign_method(&mut self, other: Self) {
+                    self.$assign_method(&other);
+                }
+            }
+
+            impl<'a, P: FullFp256Parameters> $assign_trait<&'a mut Self> for FullFp256<P> {
+                #[inline]
+                fn $assign_method(&mut self, other: &'a mut Self) {
+                    self.$assign_method(&*other);
+                }
+            }
+
+            impl<'a, P: FullFp256Parameters> $trait<&'a Self> for FullFp256<P> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(mut self, other: &Self) -> Self {
+                    self.$assign_method(other);
+                    self
+                }
+            }
+
+            impl<'a, P: FullFp256Parameters> $trait<&'a mut Self> for FullFp256<P> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(mut self, other: &'a mut Self) -> Self {
+                    self.$assign_method(&*other);
+                    self
+                }
+            }
+
+            impl<P: FullFp256Parameters> $trait<Self> for FullFp256<P> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(mut self, other: Self) -> Self {
+                    self.$assign_method(&other);
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_ops_from_ref!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+);
+
+impl<P: FullFp256Parameters> Sum<Self> for FullFp256<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a, P: FullFp256Parameters> Sum<&'a Self> for FullFp256<P> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<P: FullFp256Parameters> Product<Self> for FullFp256<P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<'a, P: FullFp256Parameters> Product<&'a Self> for FullFp256<P> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+/// Tests shared by the fields instantiated over this type.
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use ark_serialize::SWFlags;
+    use ark_std::test_rng;
+    use ark_std::UniformRand;
+
+    // The serialization test of arkworks expects elements with and without
+    // flags to have the same size, while flags require here an additional byte.
+    pub(crate) fn serialization_test<P: FullFp256Parameters>() {
+        let mut rng = test_rng();
+
+        for _ in 0..100 {
+            let a = FullFp256::<P>::rand(&mut rng);
+
+            let mut serialized = [0u8; 32];
+            a.serialize(&mut serialized[..]).unwrap();
+            assert_eq!(a.serialized_size(), 32);
+            assert_eq!(FullFp256::deserialize(&serialized[..]).unwrap(), a);
+            assert!(a.serialize(&mut serialized[..31]).is_err());
+            assert!(FullFp256::<P>::deserialize(&serialized[..31]).is_err());
+
+            let mut serialized = [0u8; 33];
+            a.serialize_with_flags(&mut serialized[..], SWFlags::from_y_sign(true))
+                .unwrap();
+            assert_eq!(a.serialized_size_with_flags::<SWFlags>(), 33);
+            let (b, flags) =
+                FullFp256::<P>::deserialize_with_flags::<_, SWFlags>(&serialized[..]).unwrap();
+            assert_eq!(flags.is_positive(), Some(true));
+            assert_eq!(a, b);
+        }
+
+        // Non-canonical encodings are rejected.
+        let serialized = P::MODULUS.to_bytes_le();
+        assert!(FullFp256::<P>::deserialize(&serialized[..]).is_err());
+    }
+
+    pub(crate) fn arithmetic_test<P: FullFp256Parameters>() {
+        let mut rng = test_rng();
+        let modulus = BigUint::from_bytes_le(&P::MODULUS.to_bytes_le());
+
+        // Values close to the modulus and to powers of two exercise
+        // all carry and borrow branches of the reductions.
+        let one = BigUint::from(1u8);
+        let mut values: Vec<BigUint> = vec![
+            BigUint::from(0u8),
+            one.clone(),
+            BigUint::from(u64::MAX),
+            &one << 128,
+            &one << 255,
+            &modulus - 1u8,
+            &modulus - 2u8,
+            (&one << 256) - &modulus,
+            &modulus >> 1,
+        ];
+        values.extend((0..10).map(|_| BigUint::from(FullFp256::<P>::rand(&mut rng))));
+
+        for a in values.iter() {
+            for b in values.iter() {
+                let x = FullFp256::<P>::from(a.clone());
+                let y = FullFp256::<P>::from(b.clone());
+
+                assert_eq!(BigUint::from(x + y), (a + b) % &modulus);
+                assert_eq!(BigUint::from(x - y), (a + &modulus - b) % &modulus);
+                assert_eq!(BigUint::from(x * y), (a * b) % &modulus);
+                assert_eq!(BigUint::from(x.double()), (a << 1) % &modulus);
+            }
+        }
+
+        assert_eq!(
+            FullFp256::<P>::from(u128::MAX),
+            FullFp256::from(BigUint::from(u128::MAX))
+        );
+        assert_eq!(FullFp256::<P>::from_repr(P::MODULUS), None);
+    }
+}
@@ -0,0 +1,890 @@
+//! Generation of the constants of Anemoi instantiations
+//!
+//! This derives all constants of an instantiation from its field and number
+//! of columns, following the procedure of the Anemoi paper as implemented in
+//! the reference implementation used to generate the constant files of this
+//! crate:
+//!
+//! - `ALPHA` is the smallest integer `a >= 3` coprime with `p - 1`;
+//! - `BETA` is the smallest generator `g` of the multiplicative group,
+//!   and `DELTA` its inverse;
+//! - the round constants are derived from the first digits of pi,
+//!   `pi_0 = 1415...0679` and `pi_1 = 8214...8196`, as
+//!   `C[r][i] = g * pi_0^(2r) + (pi_0^r + pi_1^i)^ALPHA` and
+//!   `D[r][i] = g * pi_1^(2i) + (pi_0^r + pi_1^i)^ALPHA + DELTA`;
+//! - the MDS matrix is a sparse matrix parameterized by `g` for up to 4
+//!   columns, and a fixed circulant matrix for 6 columns, as in the paper;
+//! - as a deviation from the paper, which does not define MDS matrices for
+//!   more than 6 columns, the Cauchy matrix `M[i][j] = 1 / (i + j + num_columns)`
+//!   is used for 8 and 12 columns. Cauchy matrices are MDS by construction, see
+//!   e.g. MacWilliams and Sloane, "The Theory of Error-Correcting Codes",
+//!   Chapter 11, Section 4;
+//! - the number of rounds is derived from the complexity of Groebner basis
+//!   attacks for the targeted security level, see [`num_rounds`].
+//!
+//! The BW6-761 and MNT4-298 instantiations do not exactly reproduce the
+//! procedure of the paper, as their `p - 1` could not be fully factored:
+//! their `BETA` is the smallest `g` such that `g^((p - 1) / q) != 1` for the
+//! prime factors `q` of `p - 1` below `2^16` and for its remaining composite
+//! cofactor. This is necessary for `g` to generate the multiplicative group,
+//! but not sufficient, and their constants are derived with
+//! [`AnemoiConstants::with_generator`].
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use ark_ff::PrimeField;
+
+/// The first 100 decimal digits of pi.
+const PI_0: &str = "1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679";
+/// The next 100 decimal digits of pi.
+const PI_1: &str = "8214808651328230664709384460955058223172535940812848111745028410270193852110555964462294895493038196";
+
+/// The first row of the circulant MDS matrix of instantiations with 6 columns.
+const CIRCULANT_MDS_6: [u64; 6] = [1, 1, 3, 4, 5, 6];
+
+/// All constants defining an Anemoi instantiation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnemoiConstants<F: PrimeField> {
+    /// Exponent of the Anemoi S-Box.
+    pub alpha: u32,
+    /// Inverse exponent of the Anemoi S-Box, as little-endian limbs.
+    pub inv_alpha: Vec<u64>,
+    /// Multiplier of the Anemoi S-Box.
+    pub beta: u32,
+    /// First added constant of the Anemoi S-Box.
+    pub delta: F,
+    /// Additive round constants C, applied to the first row of the state.
+    pub c: Vec<Vec<F>>,
+    /// Additive round constants D, applied to the second row of the state.
+    pub d: Vec<Vec<F>>,
+    /// Maximum Diffusion Layer matrix, in row-major order, with the same
+    /// layout as `AnemoiParameters::MDS`.
+    pub mds: Vec<F>,
+}
+
+impl<F: PrimeField> AnemoiConstants<F> {
+    /// Generates the constants of an Anemoi instantiation over `F`
+    /// with `num_columns` columns and `num_rounds` rounds.
+    ///
+    /// The smallest generator of the multiplicative group of `F` requires the
+    /// factorization of `p - 1`, whose factors below `2^16` are found by trial
+    /// division. The other ones, which cannot be found efficiently in general,
+    /// must be given in `large_factors` as decimal strings, without repetition.
+    ///
+    /// Only instantiations with 1, 2, 4, 6, 8 or 12 columns are supported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `large_factors` contains a composite number, or does not
+    /// complete the factorization of `p - 1`.
+    pub fn generate(num_columns: usize, num_rounds: usize, large_factors: &[&str]) -> Self {
+        let beta = smallest_generator::<F>(large_factors);
+
+        Self::with_generator(num_columns, num_rounds, beta)
+    }
+
+    /// Generates the constants of an Anemoi instantiation over `F` with
+    /// `num_columns` columns and `num_rounds` rounds, given the generator
+    /// `beta` of the multiplicative group of `F`.
+    ///
+    /// This is intended for fields for which `p - 1` cannot be fully factored,
+    /// in which case it is up to the caller to ensure that `beta` is a
+    /// generator. Otherwise, [`AnemoiConstants::generate`] should be used.
+    ///
+    /// Only instantiations with 1, 2, 4, 6, 8 or 12 columns are supported.
+    pub fn with_generator(num_columns: usize, num_rounds: usize, beta: u32) -> Self {
+        assert!(
+            [1, 2, 4, 6, 8, 12].contains(&num_columns),
+            "unsupported number of columns {num_columns}"
+        );
+
+        let p_minus_one = sub_small(F::characteristic(), 1);
+        let alpha = (3..)
+            .find(|&a| gcd(a, div_small(&p_minus_one, a).1) == 1)
+            .unwrap();
+        let inv_alpha = inverse_exponent(F::characteristic(), alpha);
+
+        let g = F::from(beta as u64);
+        let delta = g.inverse().unwrap();
+
+        let pi_0 = F::from_str(PI_0).ok().unwrap();
+        let pi_1 = F::from_str(PI_1).ok().unwrap();
+
+        let mut c = Vec::with_capacity(num_rounds);
+        let mut d = Vec::with_capacity(num_rounds);
+        let mut pi_0_r = F::one();
+        for _ in 0..num_rounds {
+            let mut c_r = Vec::with_capacity(num_columns);
+            let mut d_r = Vec::with_capacity(num_columns);
+            let mut pi_1_i = F::one();
+            for _ in 0..num_columns {
+                let pow_alpha = (pi_0_r + pi_1_i).pow([alpha]);
+                c_r.push(g * pi_0_r.square() + pow_alpha);
+                d_r.push(g * pi_1_i.square() + pow_alpha + delta);
+                pi_1_i *= pi_1;
+            }
+            c.push(c_r);
+            d.push(d_r);
+            pi_0_r *= pi_0;
+        }
+
+        Self {
+            alpha: alpha as u32,
+            inv_alpha,
+            beta,
+            delta,
+            c,
+            d,
+            mds: generate_mds(num_columns, g),
+        }
+    }
+}
+
+/// Returns the number of rounds of an Anemoi instantiation over a field of
+/// `modulus_bits` bits, with `num_columns` columns and exponent `alpha`,
+/// targeting a security level of `security_level` bits.
+///
+/// This is the smallest number of rounds `r` for which the complexity of a
+/// Groebner basis attack, `binomial(4 * num_columns * r + kappa, 2 * num_columns * r)^2`,
+/// is at least `2^security_level`, increased by a security margin of
+/// `min(5, num_columns + 1)` rounds, and with a minimum of 10 rounds, as
+/// given in Section 6 of the Anemoi paper (Bouvier et al., "New Design
+/// Techniques for Efficient Arithmetization-Oriented Hash Functions: Anemoi
+/// Permutations and Jive Compression Mode", CRYPTO 2023, ePrint 2022/840).
+///
+/// The bounds of the paper are derived for large prime fields, and do not
+/// cover guess-and-determine strategies over fields whose elements can be
+/// exhaustively searched within the security level. As a deviation from the
+/// paper, the attack is hence also required to cost at least
+/// `2^security_level` after guessing any `g` of the `n = 2 * num_columns * r`
+/// unknowns of the system, at a cost of `2^(g * modulus_bits)`, which leaves
+/// a system in `n - g` unknowns of complexity `binomial(2 * (n - g) + kappa, n - g)^2`.
+/// As removing an unknown only divides the complexity of the system by about
+/// `2^4`, guessing never pays off over fields of more than 3 bits, and this
+/// additional condition yields the same number of rounds as the paper for all
+/// instantiations of this crate. For instance, over 31-bit fields at a 128-bit
+/// security level, the attack requires 2 or 3 rounds with 8 or 12 columns,
+/// which the minimum of 10 rounds largely covers.
+///
+/// The constant `kappa` is the one of the paper for `alpha` equal to 3, 5 and
+/// 7, i.e. 1, 2 and 4 respectively. Larger exponents reuse the value for
+/// `alpha = 7`, as the complexity of the attack increases with `kappa`, which
+/// itself increases with `alpha`: this is a deviation from the paper, which
+/// only yields more rounds, and reproduces the number of rounds of the
+/// reference instantiations over the scalar field of Ed-on-BLS12-377, whose
+/// exponent is 11.
+///
+/// # Panics
+///
+/// Panics if `alpha` is smaller than 3.
+pub fn num_rounds(modulus_bits: u32, alpha: u32, num_columns: usize, security_level: u32) -> usize {
+    let kappa = match alpha {
+        3 => 1,
+        5 => 2,
+        _ => {
+            assert!(alpha >= 7, "invalid exponent {alpha}");
+            4
+        }
+    };
+
+    let l = num_columns;
+    let (modulus_bits, security_level) = (modulus_bits as usize, security_level as usize);
+    let rounds = (1..)
+        .find(|r| {
+            let n = 2 * l * r;
+            // Guessing g unknowns costs 2^(g * modulus_bits), and leaves n - g unknowns.
+            (0..n)
+                .take_while(|g| g * modulus_bits <= security_level)
+                .all(|g| {
+                    let binomial = binomial(2 * (n - g) + kappa, n - g);
+                    g * modulus_bits + bit_length(&mul_limbs(&binomial, &binomial)) > security_level
+                })
+        })
+        .unwrap();
+
+    core::cmp::max(10, rounds + core::cmp::min(5, l + 1))
+}
+
+/// Returns the MDS matrix of an instantiation with `num_columns` columns.
+fn generate_mds<F: PrimeField>(num_columns: usize, g: F) -> Vec<F> {
+    let one = F::one();
+    let g2 = g.square();
+
+    match num_columns {
+        // Single-column instantiations apply a 2x2 matrix on the whole state.
+        1 | 2 => vec![one, g, g, one + g2],
+        4 => vec![
+            one,
+            one + g,
+            g,
+            g,
+            g2,
+            g + g2,
+            one + g,
+            one + g.double(),
+            g2,
+            g2,
+            one,
+            one + g,
+            one + g,
+            one + g.double(),
+            g,
+            one + g,
+        ],
+        6 => (0..6)
+            .flat_map(|i| (0..6).map(move |j| F::from(CIRCULANT_MDS_6[(6 + j - i) % 6])))
+            .collect(),
+        // Not part of the paper: a Cauchy matrix built from the distinct values
+        // `x_i = i` and `y_j = j + num_columns` is MDS, as all of its square
+        // submatrices are Cauchy matrices as well, and thus invertible, as long
+        // as the characteristic is larger than `3 * num_columns`.
+        _ => (0..num_columns)
+            .flat_map(|i| {
+                (0..num_columns)
+                    .map(move |j| F::from((i + j + num_columns) as u64).inverse().unwrap())
+            })
+            .collect(),
+    }
+}
+
+/// Returns the smallest generator of the multiplicative group of `F`, given
+/// the prime factors of `p - 1` larger than `2^16` as decimal strings.
+///
+/// It is the smallest `g` such that `g^((p - 1) / q) != 1` for all prime
+/// factors `q` of `p - 1`.
+fn smallest_generator<F: PrimeField>(large_factors: &[&str]) -> u32 {
+    let p_minus_one = sub_small(F::characteristic(), 1);
+
+    // The exponents (p - 1) / q, for all prime factors q of p - 1.
+    let (mut exponents, mut cofactor) = trial_division(&p_minus_one);
+    for factor in large_factors {
+        let q = from_decimal(factor);
+        assert!(
+            bit_length(&q) > 16 && is_probable_prime(&q),
+            "{factor} is not a prime larger than 2^16"
+        );
+
+        let mut is_factor = false;
+        while let (quotient, true) = div_limbs(&cofactor, &q) {
+            cofactor = quotient;
+            is_factor = true;
+        }
+        assert!(is_factor, "{factor} does not divide p - 1");
+        exponents.push(div_limbs(&p_minus_one, &q).0);
+    }
+    assert!(
+        trim(cofactor) == [1],
+        "the provided factors do not complete the factorization of p - 1"
+    );
+
+    (2..)
+        .find(|&g| {
+            let g = F::from(g as u64);
+            exponents.iter().all(|e| !g.pow(e).is_one())
+        })
+        .unwrap()
+}
+
+/// Returns the exponents `(p - 1) / q` for the prime factors `q` of `p - 1`
+/// below `2^16`, and the cofactor of `p - 1` left after dividing them out.
+fn trial_division(p_minus_one: &[u64]) -> (Vec<Vec<u64>>, Vec<u64>) {
+    let mut exponents = Vec::new();
+    let mut cofactor = p_minus_one.to_vec();
+    for q in 2..1 << 16 {
+        let mut is_factor = false;
+        while let (quotient, 0) = div_small(&cofactor, q) {
+            cofactor = quotient;
+            is_factor = true;
+        }
+        if is_factor {
+            exponents.push(div_small(p_minus_one, q).0);
+        }
+    }
+
+    (exponents, cofactor)
+}
+
+/// Returns the integer encoded by the decimal string `s`, as little-endian limbs.
+fn from_decimal(s: &str) -> Vec<u64> {
+    s.chars().fold(vec![0u64], |acc, c| {
+        let digit = c.to_digit(10).expect("invalid decimal digit") as u64;
+        let mut limbs = mul_small(&acc, 10);
+        // Adding a digit cannot overflow the limb added by the multiplication.
+        let mut carry = digit;
+        for l in limbs.iter_mut() {
+            let (t, overflow) = l.overflowing_add(carry);
+            *l = t;
+            carry = overflow as u64;
+        }
+
+        trim(limbs)
+    })
+}
+
+/// Returns the quotient of the division of `n` by `m`, and whether it is
+/// exact, with `n`, `m` and the quotient given as little-endian limbs.
+fn div_limbs(n: &[u64], m: &[u64]) -> (Vec<u64>, bool) {
+    let mut quotient = vec![0u64; n.len()];
+    let mut rem = vec![0u64; m.len() + 1];
+    for bit in (0..bit_length(n)).rev() {
+        shift_in(&mut rem, (n[bit / 64] >> (bit % 64)) & 1);
+        if !less_than(&rem, m) {
+            sub_assign(&mut rem, m);
+            quotient[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    (trim(quotient), rem.iter().all(|&l| l == 0))
+}
+
+/// Returns `n mod m`, with `n`, `m` and the result given as little-endian limbs.
+fn rem_limbs(n: &[u64], m: &[u64]) -> Vec<u64> {
+    let mut rem = vec![0u64; m.len() + 1];
+    for bit in (0..bit_length(n)).rev() {
+        shift_in(&mut rem, (n[bit / 64] >> (bit % 64)) & 1);
+        if !less_than(&rem, m) {
+            sub_assign(&mut rem, m);
+        }
+    }
+
+    trim(rem)
+}
+
+/// Sets `n` to `2 * n + bit`, with `n` given as little-endian limbs.
+fn shift_in(n: &mut [u64], bit: u64) {
+    let mut carry = bit;
+    for l in n.iter_mut() {
+        let next = *l >> 63;
+        *l = (*l << 1) | carry;
+        carry = next;
+    }
+}
+
+/// Sets `n` to `n - m`, with `n >= m` given as little-endian limbs.
+fn sub_assign(n: &mut [u64], m: &[u64]) {
+    let mut borrow = false;
+    for (i, l) in n.iter_mut().enumerate() {
+        let (t, b1) = l.overflowing_sub(m.get(i).copied().unwrap_or(0));
+        let (t, b2) = t.overflowing_sub(borrow as u64);
+        *l = t;
+        borrow = b1 || b2;
+    }
+    debug_assert!(!borrow);
+}
+
+/// Returns `n * m`, with `n` given as little-endian limbs.
+fn mul_small(n: &[u64], m: u64) -> Vec<u64> {
+    let mut limbs = Vec::with_capacity(n.len() + 1);
+    let mut carry = 0u128;
+    for &l in n {
+        let t = l as u128 * m as u128 + carry;
+        limbs.push(t as u64);
+        carry = t >> 64;
+    }
+    limbs.push(carry as u64);
+
+    limbs
+}
+
+/// Returns `n - m`, with `n` given as little-endian limbs.
+fn sub_small(n: &[u64], m: u64) -> Vec<u64> {
+    let mut limbs = n.to_vec();
+    let mut borrow = m;
+    for l in limbs.iter_mut() {
+        let (t, overflow) = l.overflowing_sub(borrow);
+        *l = t;
+        borrow = overflow as u64;
+    }
+    debug_assert_eq!(borrow, 0);
+
+    limbs
+}
+
+/// Returns the quotient and remainder of the division of `n`
+/// by `m`, with `n` and the quotient given as little-endian limbs.
+fn div_small(n: &[u64], m: u64) -> (Vec<u64>, u64) {
+    let mut limbs = n.to_vec();
+    let mut rem = 0u128;
+    for l in limbs.iter_mut().rev() {
+        let t = (rem << 64) + *l as u128;
+        *l = (t / m as u128) as u64;
+        rem = t % m as u128;
+    }
+
+    (limbs, rem as u64)
+}
+
+/// Returns `n * m`, with `n`, `m` and the result given as little-endian limbs.
+fn mul_limbs(n: &[u64], m: &[u64]) -> Vec<u64> {
+    let mut limbs = vec![0u64; n.len() + m.len()];
+    for (i, &a) in n.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &b) in m.iter().enumerate() {
+            let t = a as u128 * b as u128 + limbs[i + j] as u128 + carry;
+            limbs[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        limbs[i + m.len()] = carry as u64;
+    }
+
+    limbs
+}
+
+/// Returns whether `n < m`, with `n` and `m` given as little-endian limbs.
+fn less_than(n: &[u64], m: &[u64]) -> bool {
+    for i in (0..n.len().max(m.len())).rev() {
+        let (a, b) = (
+            n.get(i).copied().unwrap_or(0),
+            m.get(i).copied().unwrap_or(0),
+        );
+        if a != b {
+            return a < b;
+        }
+    }
+
+    false
+}
+
+/// Returns whether the odd integer `n > 2^16`, given as little-endian limbs,
+/// is a probable prime, with the Miller-Rabin test to the bases of the primes
+/// up to 37.
+///
+/// These bases make the test deterministic below `2^64`, and composite
+/// numbers pass it with a probability of at most `4^-12` otherwise.
+fn is_probable_prime(n: &[u64]) -> bool {
+    // n - 1 = d * 2^s, with d odd.
+    let n_minus_one = sub_small(n, 1);
+    let s = n_minus_one
+        .iter()
+        .position(|&l| l != 0)
+        .map(|i| 64 * i + n_minus_one[i].trailing_zeros() as usize)
+        .unwrap();
+    let mut d = n_minus_one.clone();
+    for _ in 0..s {
+        d = div_small(&d, 2).0;
+    }
+
+    let mul_mod = |a: &[u64], b: &[u64]| rem_limbs(&mul_limbs(a, b), n);
+    let n_minus_one = trim(n_minus_one);
+    [2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]
+        .iter()
+        .all(|&a| {
+            // x = a^d mod n
+            let mut x = vec![1u64];
+            for bit in (0..bit_length(&d)).rev() {
+                x = mul_mod(&x, &x);
+                if (d[bit / 64] >> (bit % 64)) & 1 == 1 {
+                    x = mul_mod(&x, &[a]);
+                }
+            }
+
+            if x == [1] || x == n_minus_one {
+                return true;
+            }
+            for _ in 1..s {
+                x = mul_mod(&x, &x);
+                if x == n_minus_one {
+                    return true;
+                }
+            }
+
+            false
+        })
+}
+
+/// Returns the number of bits of `n`, given as little-endian limbs.
+fn bit_length(n: &[u64]) -> usize {
+    n.iter()
+        .rposition(|&l| l != 0)
+        .map_or(0, |i| 64 * i + 64 - n[i].leading_zeros() as usize)
+}
+
+/// Returns the binomial coefficient `binomial(n, k)`, as little-endian limbs.
+fn binomial(n: usize, k: usize) -> Vec<u64> {
+    // Each intermediate value binomial(n - k + i, i) is an integer.
+    (1..=k as u64).fold(vec![1u64], |acc, i| {
+        let (quotient, rem) = div_small(&mul_small(&acc, (n - k) as u64 + i), i);
+        debug_assert_eq!(rem, 0);
+        trim(quotient)
+    })
+}
+
+/// Returns the inverse of `alpha` modulo `p - 1`, as little-endian limbs.
+///
+/// It is computed as `(k * (p - 1) + 1) / alpha`,
+/// for the only `k < alpha` making this division exact.
+fn inverse_exponent(p: &[u64], alpha: u64) -> Vec<u64> {
+    let rem = div_small(&sub_small(p, 1), alpha).1;
+    let k = (1..alpha)
+        .find(|k| (k * rem + 1).is_multiple_of(alpha))
+        .unwrap();
+
+    // k * (p - 1) + 1 = k * p - (k - 1)
+    let (limbs, rem) = div_small(&sub_small(&mul_small(p, k), k - 1), alpha);
+    debug_assert_eq!(rem, 0);
+
+    trim(limbs)
+}
+
+/// Removes the most significant zero limbs of `n`, keeping at least one limb.
+fn trim(mut n: Vec<u64>) -> Vec<u64> {
+    while n.len() > 1 && n[n.len() - 1] == 0 {
+        n.pop();
+    }
+
+    n
+}
+
+/// Returns the greatest common divisor of two integers.
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use crate::AnemoiParameters;
+    use ark_ff::FpParameters;
+
+    /// The prime factors of `p - 1` larger than `2^16`, for the fields of all
+    /// instantiations, indexed by their modulus.
+    ///
+    /// The BW6-761 and MNT4-298 fields are missing, as their `p - 1` have
+    /// composite cofactors of 735 and 245 bits which could not be factored.
+    const LARGE_FACTORS: &[(&str, &[&str])] = &[
+        // BabyBear
+        (
+            "2013265921",
+            &[],
+        ),
+        // BLS12-377
+        (
+            "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177",
+            &[
+                "6633514200929891813",
+                "73387170334035996766247648424745786170238574695861388454532790956181",
+            ],
+        ),
+        // BLS12-381
+        (
+            "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787",
+            &[
+                "859267",
+                "52437899",
+                "2584487767265781317813",
+                "15778400344354997994418419698270088123916926905054652752758194827714659",
+            ],
+        ),
+        // BN-254
+        (
+            "21888242871839275222246405745257275088696311157297823662689037894645226208583",
+            &["405928799", "11465965001", "13427688667394608761327070753331941386769"],
+        ),
+        // Curve25519
+        (
+            "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+            &["74058212732561358302231226437062788676166966415465897661863160754340907"],
+        ),
+        // Ed-on-BLS12-377
+        (
+            "8444461749428370424248824938781546531375899335154063827935233455917409239041",
+            &["958612291309063373", "9586122913090633729"],
+        ),
+        // Goldilocks
+        (
+            "18446744069414584321",
+            &["65537"],
+        ),
+        // Grumpkin
+        (
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+            &["237073", "405928799", "1670836401704629", "13818364434197438864469338081"],
+        ),
+        // Jubjub
+        (
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513",
+            &["125527", "859267", "906349", "2508409", "2529403", "52437899", "254760293"],
+        ),
+        // Mersenne-31
+        (
+            "2147483647",
+            &[],
+        ),
+        // MNT6-298
+        (
+            "475922286169261325753349249653048451545124878552823515553267735739164647307408490559963137",
+            &["832668823862928114206893884097921187"],
+        ),
+        // Pallas
+        (
+            "28948022309329048855892746252171976963363056481941560715954676764349967630337",
+            &["539204044132271846773", "8999194758858563409123804352480028797519453"],
+        ),
+        // secp256k1
+        (
+            "115792089237316195423570985008687907853269984665640564039457584007908834671663",
+            &["205115282021455665897114700593932402728804164701536103180137503955397371"],
+        ),
+        // secq256k1
+        (
+            "115792089237316195423570985008687907852837564279074904382605163141518161494337",
+            &["107361793816595537", "174723607534414371449", "341948486974166000522343609283189"],
+        ),
+        // Stark
+        (
+            "3618502788666131213697322783095070105623107215331596699973092056135872020481",
+            &["98714381", "166848103"],
+        ),
+        // Vesta
+        (
+            "28948022309329048855892746252171976963363056481941647379679742748393362948097",
+            &["1690502597179744445941507", "10427374428728808478656897599072717"],
+        ),
+    ];
+
+    /// Returns the prime factors of `p - 1` larger than `2^16`,
+    /// or `None` if `p - 1` has not been fully factored.
+    fn large_factors<F: PrimeField>() -> Option<&'static [&'static str]> {
+        let modulus = trim(F::characteristic().to_vec());
+
+        LARGE_FACTORS
+            .iter()
+            .find(|(m, _)| from_decimal(m) == modulus)
+            .map(|(_, factors)| *factors)
+    }
+
+    struct GeneratorCheck;
+
+    impl InstantiationCheck for GeneratorCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let constants = match large_factors::<F>() {
+                Some(factors) => {
+                    AnemoiConstants::<F>::generate(NUM_COLUMNS, P::NUM_ROUNDS, factors)
+                }
+                None => {
+                    // The primitivity check of BETA is only performed against the
+                    // prime factors of p - 1 below 2^16 and its composite cofactor.
+                    let p_minus_one = sub_small(F::characteristic(), 1);
+                    let (mut exponents, cofactor) = trial_division(&p_minus_one);
+                    let cofactor = trim(cofactor);
+                    assert!(!is_probable_prime(&cofactor));
+                    exponents.push(div_limbs(&p_minus_one, &cofactor).0);
+
+                    let passes = |g: u32| {
+                        let g = F::from(g as u64);
+                        exponents.iter().all(|e| !g.pow(e).is_one())
+                    };
+                    assert!(passes(P::BETA));
+                    assert!((2..P::BETA).all(|g| !passes(g)));
+
+                    AnemoiConstants::<F>::with_generator(NUM_COLUMNS, P::NUM_ROUNDS, P::BETA)
+                }
+            };
+
+            assert_eq!(constants.alpha, P::ALPHA);
+            assert_eq!(constants.beta, P::BETA);
+            assert_eq!(constants.delta, P::DELTA);
+
+            assert_eq!(constants.inv_alpha, trim(P::INV_ALPHA.to_vec()));
+
+            for r in 0..P::NUM_ROUNDS {
+                assert_eq!(constants.c[r], P::ARK_C[r]);
+                assert_eq!(constants.d[r], P::ARK_D[r]);
+            }
+            assert_eq!(constants.mds, P::MDS);
+        }
+    }
+
+    struct RoundsCheck;
+
+    impl InstantiationCheck for RoundsCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let modulus_bits = F::Params::MODULUS_BITS;
+            assert_eq!(
+                num_rounds(modulus_bits, P::ALPHA, NUM_COLUMNS, P::SECURITY_LEVEL),
+                P::NUM_ROUNDS
+            );
+        }
+    }
+
+    #[test]
+    fn test_num_rounds() {
+        check_all_instantiations::<RoundsCheck>();
+
+        assert_eq!(binomial(10, 3), vec![120]);
+        assert_eq!(bit_length(&binomial(70, 34)), 67);
+
+        // More rounds are needed for higher security levels.
+        assert!(num_rounds(255, 5, 1, 256) > num_rounds(255, 5, 1, 128));
+        assert!(num_rounds(255, 3, 1, 128) >= num_rounds(255, 5, 1, 128));
+        assert_eq!(num_rounds(64, 7, 4, 128), 10);
+    }
+
+    #[test]
+    fn test_num_rounds_small_field() {
+        // BabyBear and Mersenne-31, with exponents 7 and 5.
+        assert_eq!(num_rounds(31, 7, 8, 128), 10);
+        assert_eq!(num_rounds(31, 7, 12, 128), 10);
+        assert_eq!(num_rounds(31, 5, 8, 128), 10);
+        assert_eq!(num_rounds(31, 5, 12, 128), 10);
+
+        // Guessing unknowns only matters over fields of at most 3 bits.
+        assert_eq!(num_rounds(8, 3, 1, 128), num_rounds(255, 3, 1, 128));
+        assert_eq!(num_rounds(4, 3, 1, 128), 19);
+        assert_eq!(num_rounds(3, 3, 1, 128), 24);
+        assert_eq!(num_rounds(2, 3, 1, 128), 34);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_num_rounds_invalid_exponent() {
+        num_rounds(255, 2, 1, 128);
+    }
+
+    #[test]
+    fn test_generator() {
+        check_all_instantiations::<GeneratorCheck>();
+    }
+
+    #[test]
+    fn test_is_probable_prime() {
+        assert!(is_probable_prime(&[65537]));
+        assert!(is_probable_prime(&[u64::MAX - 58]));
+        // 2^127 - 1 is a Mersenne prime.
+        assert!(is_probable_prime(&[u64::MAX, u64::MAX >> 1]));
+
+        // 65537 * 65539
+        assert!(!is_probable_prime(&[65537 * 65539]));
+        // A strong pseudoprime to all prime bases up to 23.
+        assert!(!is_probable_prime(&[3825123056546413051]));
+        // (2^64 - 59) * 65537
+        assert!(!is_probable_prime(&mul_small(&[u64::MAX - 58], 65537)));
+
+        assert_eq!(rem_limbs(&[5, 7], &[7]), vec![5]);
+        assert_eq!(rem_limbs(&[0, 1], &[3]), vec![1]);
+        assert_eq!(rem_limbs(&[1, 0, 1], &[0, 1]), vec![1]);
+        assert_eq!(div_limbs(&[0, 3], &[0, 1]), (vec![3], true));
+        assert_eq!(div_limbs(&[1, 3], &[0, 1]), (vec![3], false));
+
+        assert_eq!(from_decimal("0"), vec![0]);
+        assert_eq!(from_decimal("18446744073709551616"), vec![0, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "bls_381")]
+    #[should_panic(expected = "the provided factors do not complete the factorization of p - 1")]
+    fn test_generator_incomplete_factors() {
+        use crate::bls12_381::Felt;
+
+        // The cofactor of p - 1 after trial division is not prime.
+        let factors = &large_factors::<Felt>().unwrap()[1..];
+        smallest_generator::<Felt>(factors);
+    }
+
+    #[test]
+    #[cfg(feature = "bls_381")]
+    #[should_panic(expected = "is not a prime larger than 2^16")]
+    fn test_generator_composite_factor() {
+        use crate::bls12_381::Felt;
+
+        // 859267 * 52437899
+        let factors = &["45058156160033", "2584487767265781317813"];
+        smallest_generator::<Felt>(factors);
+    }
+
+    /// Returns whether all square submatrices of the provided `n x n` matrix,
+    /// given in row-major order, are non-singular.
+    ///
+    /// The minors of size `k` are computed from the ones of size `k - 1` by
+    /// expanding them along their first row.
+    fn is_mds<F: PrimeField>(matrix: &[F], n: usize) -> bool {
+        // The masks of each size, and the rank of each mask among them.
+        let mut masks = vec![Vec::new(); n + 1];
+        let mut rank = vec![0; 1 << n];
+        for (mask, r) in rank.iter_mut().enumerate() {
+            let size = mask.count_ones() as usize;
+            *r = masks[size].len();
+            masks[size].push(mask);
+        }
+
+        let mut minors = vec![F::one()];
+        for k in 1..=n {
+            let (prev_count, count) = (masks[k - 1].len(), masks[k].len());
+            let mut next = vec![F::zero(); count * count];
+            for &rows in masks[k].iter() {
+                let first = rows.trailing_zeros() as usize;
+                let sub_rows = rank[rows & !(1 << first)] * prev_count;
+                for &cols in masks[k].iter() {
+                    let mut minor = F::zero();
+                    let cofactors = (0..n).filter(|j| (cols >> j) & 1 == 1).enumerate();
+                    for (position, j) in cofactors {
+                        let term =
+                            matrix[first * n + j] * minors[sub_rows + rank[cols & !(1 << j)]];
+                        if position % 2 == 0 {
+                            minor += term;
+                        } else {
+                            minor -= term;
+                        }
+                    }
+                    if minor.is_zero() {
+                        return false;
+                    }
+                    next[rank[rows] * count + rank[cols]] = minor;
+                }
+            }
+            minors = next;
+        }
+
+        true
+    }
+
+    struct MdsCheck;
+
+    impl InstantiationCheck for MdsCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            if NUM_COLUMNS > 6 {
+                assert!(is_mds(P::MDS, NUM_COLUMNS));
+            }
+        }
+    }
+
+    #[test]
+    fn test_cauchy_mds() {
+        check_all_instantiations::<MdsCheck>();
+    }
+
+    #[test]
+    #[cfg(feature = "bls_381")]
+    fn test_cauchy_mds_bls12_381() {
+        use crate::bls12_381::Felt;
+
+        // A singular 2x2 submatrix is detected.
+        let matrix: Vec<Felt> = [1u64, 2, 3, 2, 4, 7, 5, 6, 9]
+            .iter()
+            .map(|&x| Felt::from(x))
+            .collect();
+        assert!(!is_mds(&matrix, 3));
+
+        let g = Felt::from(smallest_generator::<Felt>(large_factors::<Felt>().unwrap()) as u64);
+        assert!(is_mds(&generate_mds(8, g), 8));
+        assert!(is_mds(&generate_mds(12, g), 12));
+    }
+}
@@ -0,0 +1,538 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(0x93e74c764940e1f5)],
+        vec![Felt::new(0x24f4e3202723558a), Felt::new(0xba059594c309b14d)],
+        vec![
+            Felt::new(0xc775d837bf24be04),
+            Felt::new(0x9a83b534bb91f899),
+            Felt::new(0xd797d022f41daac6),
+        ],
+        vec![
+            Felt::new(0xdcf6df3c09a18831),
+            Felt::new(0xa0080ea564c10f6e),
+            Felt::new(0x10dc7de5854a4b9f),
+            Felt::new(0x59711866714b7529),
+        ],
+        vec![
+            Felt::new(0xa529aee77357fc98),
+            Felt::new(0x1741775246eeb981),
+            Felt::new(0x028630ecbbedaa4c),
+            Felt::new(0xb5b3c26ea7e139b8),
+            Felt::new(0x85f2adb31350dcd5),
+        ],
+        vec![
+            Felt::new(0x92f88787d653dcd3),
+            Felt::new(0x83dadf3deb5814c1),
+            Felt::new(0x103978eddeff3415),
+            Felt::new(0xa8fb807d76b044d9),
+            Felt::new(0x9b256d9501d2e128),
+            Felt::new(0x4091974abf920c0a),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(0xe48989d11ba8c692),
+            Felt::new(0xd9092c0dce495e67),
+            Felt::new(0x2888269be57912f2),
+            Felt::new(0xea79b1c1cb8feda6),
+        ],
+        [
+            Felt::new(0x50025974d26411b9),
+            Felt::new(0x55983d354e5b56c9),
+            Felt::new(0xfaf83535ee557242),
+            Felt::new(0xef205ccb30f5b680),
+        ],
+        [
+            Felt::new(0xa9f351ce56e8b528),
+            Felt::new(0xf6aa860ecaaab23f),
+            Felt::new(0xbfd95ba646d0e990),
+            Felt::new(0x24ded8bb7a6e859b),
+        ],
+        [
+            Felt::new(0xefd01ac6ad92f392),
+            Felt::new(0xc647ce9d044ff892),
+            Felt::new(0x6be25b99b5b09f07),
+            Felt::new(0x68f0c9a12f5be551),
+        ],
+        [
+            Felt::new(0x17e47dc877eb6ce2),
+            Felt::new(0xa5f51393150a2ea9),
+            Felt::new(0xd48ee9c748602558),
+            Felt::new(0xe993040bb8bd2a4a),
+        ],
+        [
+            Felt::new(0xc2c54b7419f61ec6),
+            Felt::new(0x0b098c38b2850b24),
+            Felt::new(0xb75113c2b20cd97a),
+            Felt::new(0xd26a843164d21524),
+        ],
+        [
+            Felt::new(0xc05ebb51886ac98e),
+            Felt::new(0x05c538fad1453256),
+            Felt::new(0x549f59db8b1ef8f4),
+            Felt::new(0xdc3f4a3b73bda60b),
+        ],
+        [
+            Felt::new(0xa254c59f166881de),
+            Felt::new(0x337c4451df1427c2),
+            Felt::new(0xedd3f37c05eed2bb),
+            Felt::new(0x5ba38ecb38b60a9f),
+        ],
+        [
+            Felt::new(0xe8fd6d875da5c251),
+            Felt::new(0xfee240052fa32a38),
+            Felt::new(0x1aea14b1c3ada130),
+            Felt::new(0xd7f23eb0b470f393),
+        ],
+        [
+            Felt::new(0x3fb4cd5f3eb3e57c),
+            Felt::new(0x2a7dc84dbdb298bd),
+            Felt::new(0xaf39d2a76f22459b),
+            Felt::new(0x40bda6faa319e7ab),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(0x8c7542d2e2cfefb2),
+            Felt::new(0x083111efa8e707ca),
+            Felt::new(0xa5c211d206ced168),
+            Felt::new(0xb531fa665900df32),
+            Felt::new(0x3c3caf70a6dcd45c),
+            Felt::new(0x4dbcafc7544eebee),
+            Felt::new(0xb4f3069e16c2a613),
+            Felt::new(0xda4ceeb38899f199),
+            Felt::new(0xf2b74a30bc23134f),
+            Felt::new(0x64be4dbaf3389b5c),
+            Felt::new(0xae7ad6355a4db017),
+            Felt::new(0x730e047925c43af7),
+        ],
+        vec![
+            Felt::new(0xffa87d1f2e5400f2),
+            Felt::new(0x5b824ae76f7c46d2),
+            Felt::new(0x4914ae07613d3eee),
+            Felt::new(0x032fbb5265cb602b),
+            Felt::new(0xd8f07373c33012ef),
+            Felt::new(0x209930db83459329),
+            Felt::new(0x986fb80441f1273a),
+            Felt::new(0x63f636b149c668f2),
+            Felt::new(0xfd06285ba8d2340b),
+            Felt::new(0xcc69ea2ef8072824),
+            Felt::new(0x1e09506055ae9ecb),
+            Felt::new(0x230e16f489ea6981),
+        ],
+        vec![
+            Felt::new(0x288cae645ba2ddab),
+            Felt::new(0x7d974a2a520e4d86),
+            Felt::new(0x2090811e2ad81de0),
+            Felt::new(0x69a7ced647519201),
+            Felt::new(0xcc47fd97526d3c2a),
+            Felt::new(0x3a3ec69c35b73eea),
+            Felt::new(0x3177e3f09538e44d),
+            Felt::new(0xc32886cbd4945331),
+            Felt::new(0x9e6852d47778ca12),
+            Felt::new(0x4825050e34d872b5),
+            Felt::new(0x2b80ee5b4b4f941a),
+            Felt::new(0xde88ad9a21849841),
+        ],
+        vec![
+            Felt::new(0x91d7e2aa785887ba),
+            Felt::new(0x060868684fbb2824),
+            Felt::new(0x3838a44e25ffefa7),
+            Felt::new(0x6d60a559765f4c92),
+            Felt::new(0xef78bfaeb1ba5400),
+            Felt::new(0x12e9a0698002d9e9),
+            Felt::new(0x0e8590142005503d),
+            Felt::new(0x218de39a2c25ba84),
+            Felt::new(0x7127d6e87ec90436),
+            Felt::new(0xf373780627afb9b4),
+            Felt::new(0xab6efd8d2e002ca2),
+            Felt::new(0xa4305ae5d2542faa),
+        ],
+        vec![
+            Felt::new(0x8bc518fdcffd5b90),
+            Felt::new(0x03f89c2daff70c8b),
+            Felt::new(0x76b33286555c7325),
+            Felt::new(0x1f29cc8569a624eb),
+            Felt::new(0xdf5c0249b5f6f611),
+            Felt::new(0xc393bb24b7070c22),
+            Felt::new(0x2b1266e72a1353ea),
+            Felt::new(0xbb0d473ddc39d2a9),
+            Felt::new(0x845aee37d2ee7bc1),
+            Felt::new(0x9e65a85fc9e1f2a0),
+            Felt::new(0x2d8383a1ac037a0b),
+            Felt::new(0xf7102fa784421a84),
+        ],
+        vec![
+            Felt::new(0x19606954284b12ca),
+            Felt::new(0x79dfdca0a534a755),
+            Felt::new(0x05d53655416bc89f),
+            Felt::new(0x422a798c30875ac3),
+            Felt::new(0x8a50426b98316b4b),
+            Felt::new(0xc07ae716f7ea33b8),
+            Felt::new(0x349f92cf328994be),
+            Felt::new(0x8260d836e7f3924b),
+            Felt::new(0x554b7ac9f9e3cec1),
+            Felt::new(0xd260dd805268c240),
+            Felt::new(0xe813e0346bf9368c),
+            Felt::new(0x9731d594396497b5),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(0x49549e063427cb1f),
+            Felt::new(0x7943badf1050ba31),
+            Felt::new(0xe3c71f1da99e9503),
+            Felt::new(0x8d217746c70bde5f),
+            Felt::new(0xcefbff998aff2a70),
+            Felt::new(0xda9bbfdd1d531351),
+        ],
+        [
+            Felt::new(0xbdaee062fdf96775),
+            Felt::new(0xfe53539eb3dde318),
+            Felt::new(0x348e5596f7e1122e),
+            Felt::new(0x4f808d44a737a022),
+            Felt::new(0xe24d0d3c3fd3d310),
+            Felt::new(0xda08b6d3941ac784),
+        ],
+        [
+            Felt::new(0x5d1a8a0a5fc0b923),
+            Felt::new(0x36c378d969cc46e6),
+            Felt::new(0x537dee2940fa7d23),
+            Felt::new(0x416e040d7c0e6b3d),
+            Felt::new(0x792801387320098f),
+            Felt::new(0x028f1faec328f41b),
+        ],
+        [
+            Felt::new(0xad5b417a23082d0c),
+            Felt::new(0x159c653b604fde5f),
+            Felt::new(0x1cb77da624ea7b1b),
+            Felt::new(0xae08d696adc991fd),
+            Felt::new(0x20922dcc0081fbad),
+            Felt::new(0x20ba30ed8af091ef),
+        ],
+        [
+            Felt::new(0x0421d9affb82fb96),
+            Felt::new(0x0653b8e7215fd6c8),
+            Felt::new(0xca201331fd66c36c),
+            Felt::new(0x378549ece7079dbb),
+            Felt::new(0x8c86f73bd7b4e685),
+            Felt::new(0x86fd198fce89ec6c),
+        ],
+        [
+            Felt::new(0x2612a2925d544392),
+            Felt::new(0xabfb8786692ef431),
+            Felt::new(0xb2ea96a590349de4),
+            Felt::new(0x2baa8a491eca387f),
+            Felt::new(0x28828ebe29993a80),
+            Felt::new(0x7871c00124d22855),
+        ],
+        [
+            Felt::new(0x0dcf1c8a278760af),
+            Felt::new(0x1ce2d90a22da3ac1),
+            Felt::new(0x50d4c6393f15f14c),
+            Felt::new(0x44b588d33b900a42),
+            Felt::new(0xe14b78575851683e),
+            Felt::new(0x94b72b7119480ac5),
+        ],
+        [
+            Felt::new(0xa47840cd4e9a4ce0),
+            Felt::new(0x9b216fa21a43aef0),
+            Felt::new(0x06da3a8dd45b7257),
+            Felt::new(0x4812054b13a0b4d7),
+            Felt::new(0xd9d0f57dbe9f1be8),
+            Felt::new(0x88398bd87f8762d6),
+        ],
+        [
+            Felt::new(0x7c7579d716741f39),
+            Felt::new(0xbf3c7c93489eeb47),
+            Felt::new(0x1ad9a59e8a41db81),
+            Felt::new(0x1e35cf18f5091d89),
+            Felt::new(0xd349bf391d35d4fd),
+            Felt::new(0x0f436e29a6c325e7),
+        ],
+        [
+            Felt::new(0x295a239fe307d02f),
+            Felt::new(0xc563fc03afdd48f3),
+            Felt::new(0x07b1afe6a12cd7ec),
+            Felt::new(0x848f77ff862eabe2),
+            Felt::new(0x0f73e6d6f49068a4),
+            Felt::new(0x08e572e0c3600383),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(0x8c7542d2e2cfefb2),
+            Felt::new(0x083111efa8e707ca),
+            Felt::new(0xa5c211d206ced168),
+            Felt::new(0xb531fa665900df32),
+            Felt::new(0x3c3caf70a6dcd45c),
+            Felt::new(0x4dbcafc7544eebee),
+            Felt::new(0xb4f3069e16c2a613),
+            Felt::new(0xda4ceeb38899f199),
+            Felt::new(0xf2b74a30bc23134f),
+            Felt::new(0x64be4dbaf3389b5c),
+            Felt::new(0xae7ad6355a4db017),
+            Felt::new(0x730e047925c43af7),
+        ],
+        vec![
+            Felt::new(0xffa87d1f2e5400f2),
+            Felt::new(0x5b824ae76f7c46d2),
+            Felt::new(0x4914ae07613d3eee),
+            Felt::new(0x032fbb5265cb602b),
+            Felt::new(0xd8f07373c33012ef),
+            Felt::new(0x209930db83459329),
+            Felt::new(0x986fb80441f1273a),
+            Felt::new(0x63f636b149c668f2),
+            Felt::new(0xfd06285ba8d2340b),
+            Felt::new(0xcc69ea2ef8072824),
+            Felt::new(0x1e09506055ae9ecb),
+            Felt::new(0x230e16f489ea6981),
+        ],
+        vec![
+            Felt::new(0x288cae645ba2ddab),
+            Felt::new(0x7d974a2a520e4d86),
+            Felt::new(0x2090811e2ad81de0),
+            Felt::new(0x69a7ced647519201),
+            Felt::new(0xcc47fd97526d3c2a),
+            Felt::new(0x3a3ec69c35b73eea),
+            Felt::new(0x3177e3f09538e44d),
+            Felt::new(0xc32886cbd4945331),
+            Felt::new(0x9e6852d47778ca12),
+            Felt::new(0x4825050e34d872b5),
+            Felt::new(0x2b80ee5b4b4f941a),
+            Felt::new(0xde88ad9a21849841),
+        ],
+        vec![
+            Felt::new(0x91d7e2aa785887ba),
+            Felt::new(0x060868684fbb2824),
+            Felt::new(0x3838a44e25ffefa7),
+            Felt::new(0x6d60a559765f4c92),
+            Felt::new(0xef78bfaeb1ba5400),
+            Felt::new(0x12e9a0698002d9e9),
+            Felt::new(0x0e8590142005503d),
+            Felt::new(0x218de39a2c25ba84),
+            Felt::new(0x7127d6e87ec90436),
+            Felt::new(0xf373780627afb9b4),
+            Felt::new(0xab6efd8d2e002ca2),
+            Felt::new(0xa4305ae5d2542faa),
+        ],
+        vec![
+            Felt::new(0x8bc518fdcffd5b90),
+            Felt::new(0x03f89c2daff70c8b),
+            Felt::new(0x76b33286555c7325),
+            Felt::new(0x1f29cc8569a624eb),
+            Felt::new(0xdf5c0249b5f6f611),
+            Felt::new(0xc393bb24b7070c22),
+            Felt::new(0x2b1266e72a1353ea),
+            Felt::new(0xbb0d473ddc39d2a9),
+            Felt::new(0x845aee37d2ee7bc1),
+            Felt::new(0x9e65a85fc9e1f2a0),
+            Felt::new(0x2d8383a1ac037a0b),
+            Felt::new(0xf7102fa784421a84),
+        ],
+        vec![
+            Felt::new(0x19606954284b12ca),
+            Felt::new(0x79dfdca0a534a755),
+            Felt::new(0x05d53655416bc89f),
+            Felt::new(0x422a798c30875ac3),
+            Felt::new(0x8a50426b98316b4b),
+            Felt::new(0xc07ae716f7ea33b8),
+            Felt::new(0x349f92cf328994be),
+            Felt::new(0x8260d836e7f3924b),
+            Felt::new(0x554b7ac9f9e3cec1),
+            Felt::new(0xd260dd805268c240),
+            Felt::new(0xe813e0346bf9368c),
+            Felt::new(0x9731d594396497b5),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(0x2e93085acc375263),
+            Felt::new(0x3e3f9b4248d318cc),
+            Felt::new(0xcd84b4639c8e382e),
+            Felt::new(0xa2c156c1abdc9314),
+        ],
+        [
+            Felt::new(0x9a6b9faf64549ce3),
+            Felt::new(0x67eedfbdb3462d69),
+            Felt::new(0x3a1ea387d159afcf),
+            Felt::new(0xbfedb7fa3bea1d54),
+        ],
+        [
+            Felt::new(0x7bce2b7a3ed907b0),
+            Felt::new(0x0421bf0b1744b30b),
+            Felt::new(0xadf24df1d5023825),
+            Felt::new(0x769edd8a91bef333),
+        ],
+        [
+            Felt::new(0xb78d3e13032d691f),
+            Felt::new(0xe9e380bcf6c35549),
+            Felt::new(0x3317aed945473ab5),
+            Felt::new(0xfa7bec01a246ad03),
+        ],
+        [
+            Felt::new(0x0a3f0eda916dbddb),
+            Felt::new(0x5b1326aacaa4948a),
+            Felt::new(0x5089d5443f30e7ab),
+            Felt::new(0x69c2f5b90c4ccc65),
+        ],
+        [
+            Felt::new(0xac2c21ca11e1a308),
+            Felt::new(0x0fb6ae1a1958170c),
+            Felt::new(0x5553a62c054078ee),
+            Felt::new(0x406123b793733df8),
+        ],
+        [
+            Felt::new(0x7e8ac9d60fc8f4ee),
+            Felt::new(0x81922c189362d145),
+            Felt::new(0xc1649144af25c54b),
+            Felt::new(0x74bd6135e44f7e83),
+        ],
+        [
+            Felt::new(0x5a7f2bed23e8b77d),
+            Felt::new(0xcf920650decf8904),
+            Felt::new(0x2aa444ecbdac23a1),
+            Felt::new(0x9bdafa74ce9c3d99),
+        ],
+        [
+            Felt::new(0x9261120d2d01a8c6),
+            Felt::new(0x6362babb404e6fb4),
+            Felt::new(0xd837cca190ea26f1),
+            Felt::new(0x8952ff1aa41cbf03),
+        ],
+        [
+            Felt::new(0xa9c4543e1a4fbf91),
+            Felt::new(0x4ca5bacb9521d62a),
+            Felt::new(0x96bb661e5e75512f),
+            Felt::new(0x06332c19644a222d),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 3));
+    }
+}
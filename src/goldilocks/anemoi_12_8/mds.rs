@@ -0,0 +1,49 @@
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(1),
+    Felt::new(1),
+    Felt::new(3),
+    Felt::new(4),
+    Felt::new(5),
+    Felt::new(6),
+    Felt::new(6),
+    Felt::new(1),
+    Felt::new(1),
+    Felt::new(3),
+    Felt::new(4),
+    Felt::new(5),
+    Felt::new(5),
+    Felt::new(6),
+    Felt::new(1),
+    Felt::new(1),
+    Felt::new(3),
+    Felt::new(4),
+    Felt::new(4),
+    Felt::new(5),
+    Felt::new(6),
+    Felt::new(1),
+    Felt::new(1),
+    Felt::new(3),
+    Felt::new(3),
+    Felt::new(4),
+    Felt::new(5),
+    Felt::new(6),
+    Felt::new(1),
+    Felt::new(1),
+    Felt::new(1),
+    Felt::new(3),
+    Felt::new(4),
+    Felt::new(5),
+    Felt::new(6),
+    Felt::new(1),
+];
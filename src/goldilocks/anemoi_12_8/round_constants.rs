@@ -0,0 +1,170 @@
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(0x0000000000000087),
+        Felt::new(0xa44aaa1a2ecf0aa8),
+        Felt::new(0x5ceaee38621aaaf1),
+        Felt::new(0x45a90d581bbd7778),
+        Felt::new(0x284f59958be6c55d),
+        Felt::new(0xed1f32f386cf2b52),
+    ],
+    [
+        Felt::new(0x22a350c70ea16719),
+        Felt::new(0xbb40a7046aaf1cf8),
+        Felt::new(0x8c268d3558281320),
+        Felt::new(0x4338ba98527f3ce7),
+        Felt::new(0xf36760cc3ac205af),
+        Felt::new(0x76b4f1393335a844),
+    ],
+    [
+        Felt::new(0x592e0d6e2fffd76f),
+        Felt::new(0x50ef1f6e83be5eb9),
+        Felt::new(0x2b76398620175194),
+        Felt::new(0x369083a8b84cec72),
+        Felt::new(0xdcb662832fd2efbe),
+        Felt::new(0x87f6d2f8e562734e),
+    ],
+    [
+        Felt::new(0xd8ebf4e343100921),
+        Felt::new(0x1b8d447195c542de),
+        Felt::new(0x20121626e3646407),
+        Felt::new(0x0124f51729c03c1b),
+        Felt::new(0xfa1739b213a909a0),
+        Felt::new(0x5470bf7290b40f82),
+    ],
+    [
+        Felt::new(0x007f0424748e404d),
+        Felt::new(0xa992100f9c725b16),
+        Felt::new(0xa8bba715639dfff7),
+        Felt::new(0xc4f8e4639b718e78),
+        Felt::new(0x4f5e123570ab751d),
+        Felt::new(0x44bb223a500b019b),
+    ],
+    [
+        Felt::new(0x955b695150790f90),
+        Felt::new(0x163e41920258eb03),
+        Felt::new(0x9288f22a441f439b),
+        Felt::new(0x3b3d458f321d5545),
+        Felt::new(0x9c397217697324f7),
+        Felt::new(0xd60ee05e7a7d765e),
+    ],
+    [
+        Felt::new(0xbf2e53929e90453d),
+        Felt::new(0x24b6bb56be24a307),
+        Felt::new(0x9b4a7f97d0a2c73d),
+        Felt::new(0x275094821a4371f8),
+        Felt::new(0x9f8c8611b27f5f8f),
+        Felt::new(0x1f632915798844ab),
+    ],
+    [
+        Felt::new(0x6e653479c3f1b977),
+        Felt::new(0x3a94fb4dec7c88ae),
+        Felt::new(0x398aca369947f283),
+        Felt::new(0xa6505c09cbf42124),
+        Felt::new(0x77bcd3011882667d),
+        Felt::new(0xeb504fc0cdfe3e8a),
+    ],
+    [
+        Felt::new(0xe0ebc50fe230e0b4),
+        Felt::new(0xcf6f917841bda0a2),
+        Felt::new(0xafffc86f99444e15),
+        Felt::new(0x2f950dd50eadc6d3),
+        Felt::new(0x2cbc0e204eceb0df),
+        Felt::new(0xd28d25b1e1ac7afe),
+    ],
+    [
+        Felt::new(0x9e23e6812ff12051),
+        Felt::new(0x140ebb524d1d3a36),
+        Felt::new(0x16e1ca1fdc46d17c),
+        Felt::new(0xd3a5bcd28286d60f),
+        Felt::new(0x4c8ff7777d39374f),
+        Felt::new(0xec2f54ce9fae5458),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(0x249249246db6dbf5),
+        Felt::new(0x557213cd58507e98),
+        Felt::new(0x00f339d59761ec51),
+        Felt::new(0x0f7dac073b4fc425),
+        Felt::new(0x865b546bdbe54875),
+        Felt::new(0x15d1ef9947c109c5),
+    ],
+    [
+        Felt::new(0x4ff8e3b798644326),
+        Felt::new(0x752b5a83b03c9187),
+        Felt::new(0x38f2229ea97b551f),
+        Felt::new(0x15d0a3138e1d8a33),
+        Felt::new(0x5a36a56fa6cc8965),
+        Felt::new(0xa82af7aa10338757),
+    ],
+    [
+        Felt::new(0xbe98f0db9c49d5ce),
+        Felt::new(0x42ef236aabd2f59a),
+        Felt::new(0x10571f6c53f1b5e5),
+        Felt::new(0x413dbca0d6725c10),
+        Felt::new(0x7b9af7a37e6495c6),
+        Felt::new(0xf18229e6a4e774b3),
+    ],
+    [
+        Felt::new(0x758d805ed1834065),
+        Felt::new(0x44c3f07ae00312a5),
+        Felt::new(0x3c29a41a3968013e),
+        Felt::new(0x4308d61c6a0ee49f),
+        Felt::new(0xd03276df8463e88e),
+        Felt::new(0xf532be6d726249cd),
+    ],
+    [
+        Felt::new(0x1875cbcf6e09d5f4),
+        Felt::new(0x4e1df84951b8893f),
+        Felt::new(0x4028713924a9fb90),
+        Felt::new(0x8232019946c8955e),
+        Felt::new(0xa0ce8b924c6eb26e),
+        Felt::new(0x60d25d659cc19a48),
+    ],
+    [
+        Felt::new(0x68f3683c507af15e),
+        Felt::new(0x766b610abe256554),
+        Felt::new(0xe596f38d0bb18b5c),
+        Felt::new(0xb4179a03e3faa853),
+        Felt::new(0xa94b22b44bbcae6f),
+        Felt::new(0xadc752c9cdba5b32),
+    ],
+    [
+        Felt::new(0xadb0f7ac73af5f91),
+        Felt::new(0x9fce7ffe4f0e55de),
+        Felt::new(0x0943262a6d524783),
+        Felt::new(0xbb158e25a13dfd8c),
+        Felt::new(0xc788dbdd69e6218d),
+        Felt::new(0x120640afa1e26205),
+    ],
+    [
+        Felt::new(0x3bcf76cedb969b8e),
+        Felt::new(0x94945e30bfec0348),
+        Felt::new(0x866b0f03787d3a8d),
+        Felt::new(0x18fcf3e99574747a),
+        Felt::new(0x7ea0c708126ef03e),
+        Felt::new(0xbcdb059638de23a7),
+    ],
+    [
+        Felt::new(0x02574c613d589e6e),
+        Felt::new(0x7d70395758aff6df),
+        Felt::new(0x50e15238bbfc71c2),
+        Felt::new(0xf642eaaf1bb0f5ce),
+        Felt::new(0x87a147228c3e1644),
+        Felt::new(0xf8192082900f3bbf),
+    ],
+    [
+        Felt::new(0xe8d3a6fb7638ffbb),
+        Felt::new(0xeb539c5a4f2fb223),
+        Felt::new(0xe1078d11ea1f16d9),
+        Felt::new(0xc397d2d77aaa26b8),
+        Felt::new(0xd0b969a3a5c8be63),
+        Felt::new(0x3aff88ca393136c7),
+    ],
+];
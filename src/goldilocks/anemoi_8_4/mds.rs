@@ -0,0 +1,27 @@
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [ 1  8  7  7]
+/// [49 56  8 15]
+/// [49 49  1  8]
+/// [ 8 15  7  8]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(1),
+    Felt::new(8),
+    Felt::new(7),
+    Felt::new(7),
+    Felt::new(49),
+    Felt::new(56),
+    Felt::new(8),
+    Felt::new(15),
+    Felt::new(49),
+    Felt::new(49),
+    Felt::new(1),
+    Felt::new(8),
+    Felt::new(8),
+    Felt::new(15),
+    Felt::new(7),
+    Felt::new(8),
+];
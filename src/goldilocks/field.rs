@@ -0,0 +1,704 @@
+//! Arithmetic over the Goldilocks field, of modulus `p = 2^64 - 2^32 + 1`
+//!
+//! The generic prime field types of arkworks require the modulus to leave at
+//! least one spare bit in the most significant limb of its representation,
+//! which is not the case of `p`. Elements are instead stored in canonical form
+//! in a single `u64`, and reduced with the special form of the modulus, as
+//! `2^64 = 2^32 - 1 mod p` and `2^96 = -1 mod p`.
+
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+use ark_ff::{
+    BigInteger64, FftField, FftParameters, Field, FpParameters, FromBytes, One, PrimeField,
+    ToBytes, Zero,
+};
+use ark_serialize::{
+    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, EmptyFlags, Flags, SerializationError,
+};
+use ark_std::io::{Error, ErrorKind, Read, Result as IoResult, Write};
+use ark_std::rand::distributions::{Distribution, Standard};
+use ark_std::rand::Rng;
+use num_bigint::BigUint;
+use zeroize::Zeroize;
+
+/// The field modulus, `2^64 - 2^32 + 1`.
+const MODULUS: u64 = 0xffff_ffff_0000_0001;
+
+/// `2^64 - MODULUS = 2^32 - 1`, i.e. `2^64 mod p`.
+const EPSILON: u64 = 0xffff_ffff;
+
+/// Parameters of the Goldilocks field.
+///
+/// Contrary to the arkworks prime field types, elements are not stored
+/// in Montgomery form, hence the field constants below are given in
+/// canonical form, with the exception of `R` and `R2` which are only
+/// provided for completeness.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FeltParameters;
+
+impl FftParameters for FeltParameters {
+    type BigInt = BigInteger64;
+
+    const TWO_ADICITY: u32 = 32;
+
+    /// `7^((p - 1) / 2^32)`
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger64 = BigInteger64([0x1856_29dc_da58_878c]);
+}
+
+impl FpParameters for FeltParameters {
+    const MODULUS: BigInteger64 = BigInteger64([MODULUS]);
+
+    const MODULUS_BITS: u32 = 64;
+
+    const REPR_SHAVE_BITS: u32 = 0;
+
+    const R: BigInteger64 = BigInteger64([EPSILON]);
+
+    const R2: BigInteger64 = BigInteger64([0xffff_fffe_0000_0001]);
+
+    const INV: u64 = 0xffff_fffe_ffff_ffff;
+
+    const GENERATOR: BigInteger64 = BigInteger64([7]);
+
+    const CAPACITY: u32 = 63;
+
+    const T: BigInteger64 = BigInteger64([0xffff_ffff]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger64 = BigInteger64([0x7fff_ffff]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger64 = BigInteger64([0x7fff_ffff_8000_0000]);
+}
+
+/// An element of the Goldilocks field.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Felt(u64);
+
+impl Felt {
+    /// Returns a field element from its canonical representation,
+    /// which must be smaller than the modulus.
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the canonical representation of this field element.
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the field element of canonical representation `value`,
+    /// or `None` if it is not smaller than the modulus.
+    #[inline(always)]
+    fn from_canonical(value: u64) -> Option<Self> {
+        if value < MODULUS {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Reduces a 128-bit integer modulo `p`.
+    #[inline(always)]
+    fn reduce128(x: u128) -> u64 {
+        let x_lo = x as u64;
+        let x_hi = (x >> 64) as u64;
+        let x_hi_hi = x_hi >> 32;
+        let x_hi_lo = x_hi & EPSILON;
+
+        // x = x_lo + 2^64 * x_hi_lo + 2^96 * x_hi_hi
+        //   = x_lo + (2^32 - 1) * x_hi_lo - x_hi_hi mod p
+        let (t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+        // On borrow, 2^64 has been added to t0, and is removed by subtracting
+        // 2^32 - 1. This cannot underflow, as then x_lo < x_hi_hi < 2^32.
+        let t0 = if borrow { t0.wrapping_sub(EPSILON) } else { t0 };
+        let t1 = x_hi_lo * EPSILON;
+
+        let (t2, carry) = t0.overflowing_add(t1);
+        // On carry, 2^64 has been removed from t2, and is added back as 2^32 - 1.
+        // This cannot overflow, as then t2 < t1 < 2^64 - 2^33 + 1.
+        let t2 = t2.wrapping_add(EPSILON * carry as u64);
+
+        Self::canonicalize(t2)
+    }
+
+    /// Reduces a 64-bit integer modulo `p`.
+    #[inline(always)]
+    const fn canonicalize(x: u64) -> u64 {
+        if x >= MODULUS {
+            x - MODULUS
+        } else {
+            x
+        }
+    }
+}
+
+impl Zero for Felt {
+    #[inline]
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Felt {
+    #[inline]
+    fn one() -> Self {
+        Self(1)
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl Field for Felt {
+    type BasePrimeField = Self;
+
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() != 1 {
+            return None;
+        }
+        Some(elems[0])
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        *self + *self
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = self.double();
+        self
+    }
+
+    #[inline]
+    fn characteristic() -> &'static [u64] {
+        &[MODULUS]
+    }
+
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        if F::BIT_SIZE > 8 {
+            return None;
+        }
+
+        let mut result_bytes = [0u8; 9];
+        result_bytes
+            .iter_mut()
+            .zip(bytes)
+            .for_each(|(result, input)| *result = *input);
+
+        // The flags are located in the last byte of the serialized form,
+        // which lies outside of the 8 bytes of the element if there are any.
+        let output_byte_size = buffer_byte_size(64 + F::BIT_SIZE);
+        let flags_mask = u8::MAX.checked_shl(8 - (F::BIT_SIZE as u32)).unwrap_or(0);
+        let flags = result_bytes[output_byte_size - 1] & flags_mask;
+
+        let value = u64::from_le_bytes(result_bytes[..8].try_into().unwrap());
+        Self::from_canonical(value).and_then(|f| F::from_u8(flags).map(|flag| (f, flag)))
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        *self * *self
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        *self = self.square();
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.pow([MODULUS - 2]))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        self.inverse().map(|inverse| {
+            *self = inverse;
+            self
+        })
+    }
+
+    /// The Frobenius map has no effect in a prime field.
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {}
+}
+
+impl FftField for Felt {
+    type FftParams = FeltParameters;
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self(FeltParameters::TWO_ADIC_ROOT_OF_UNITY.0[0])
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self(FeltParameters::GENERATOR.0[0])
+    }
+}
+
+impl PrimeField for Felt {
+    type Params = FeltParameters;
+    type BigInt = BigInteger64;
+
+    #[inline]
+    fn from_repr(repr: BigInteger64) -> Option<Self> {
+        Self::from_canonical(repr.0[0])
+    }
+
+    #[inline]
+    fn into_repr(&self) -> BigInteger64 {
+        BigInteger64([self.0])
+    }
+}
+
+/// Compares field elements viewing them as integers in the range
+/// `0, 1, ..., p - 1`, as done for the arkworks prime field types.
+impl Ord for Felt {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for Felt {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Zeroize for Felt {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Distribution<Felt> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Felt {
+        loop {
+            if let Some(element) = Felt::from_canonical(rng.next_u64()) {
+                return element;
+            }
+        }
+    }
+}
+
+impl From<u128> for Felt {
+    fn from(other: u128) -> Self {
+        Self((other % MODULUS as u128) as u64)
+    }
+}
+
+impl From<u64> for Felt {
+    fn from(other: u64) -> Self {
+        Self(Self::canonicalize(other))
+    }
+}
+
+impl From<u32> for Felt {
+    fn from(other: u32) -> Self {
+        Self(other as u64)
+    }
+}
+
+impl From<u16> for Felt {
+    fn from(other: u16) -> Self {
+        Self(other as u64)
+    }
+}
+
+impl From<u8> for Felt {
+    fn from(other: u8) -> Self {
+        Self(other as u64)
+    }
+}
+
+impl From<bool> for Felt {
+    fn from(other: bool) -> Self {
+        Self(other as u64)
+    }
+}
+
+impl From<BigInteger64> for Felt {
+    /// Converts a canonical representation into a field element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the representation is not smaller than the modulus.
+    fn from(repr: BigInteger64) -> Self {
+        Self::from_repr(repr).unwrap()
+    }
+}
+
+impl From<Felt> for BigInteger64 {
+    fn from(element: Felt) -> Self {
+        element.into_repr()
+    }
+}
+
+impl From<BigUint> for Felt {
+    fn from(value: BigUint) -> Self {
+        Self::from_le_bytes_mod_order(&value.to_bytes_le())
+    }
+}
+
+impl From<Felt> for BigUint {
+    fn from(element: Felt) -> Self {
+        BigUint::from(element.0)
+    }
+}
+
+impl FromStr for Felt {
+    type Err = ();
+
+    /// Interprets a string of decimal digits as a (congruent) field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || (s.starts_with('0') && s != "0") {
+            return Err(());
+        }
+
+        s.chars().try_fold(Self::zero(), |acc, c| {
+            let digit = c.to_digit(10).ok_or(())?;
+            Ok(acc * Self(10) + Self(digit as u64))
+        })
+    }
+}
+
+impl Display for Felt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Felt({})", self.0)
+    }
+}
+
+impl ToBytes for Felt {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.into_repr().write(writer)
+    }
+}
+
+impl FromBytes for Felt {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        BigInteger64::read(reader).and_then(|repr| {
+            Self::from_repr(repr)
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "FromBytes::read failed"))
+        })
+    }
+}
+
+impl CanonicalSerializeWithFlags for Felt {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+
+        let output_byte_size = buffer_byte_size(64 + F::BIT_SIZE);
+
+        let mut bytes = [0u8; 9];
+        bytes[..8].copy_from_slice(&self.0.to_le_bytes());
+        bytes[output_byte_size - 1] |= flags.u8_bitmask();
+
+        writer.write_all(&bytes[..output_byte_size])?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        buffer_byte_size(64 + F::BIT_SIZE)
+    }
+}
+
+impl CanonicalSerialize for Felt {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl CanonicalDeserializeWithFlags for Felt {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+
+        let output_byte_size = buffer_byte_size(64 + F::BIT_SIZE);
+
+        let mut masked_bytes = [0u8; 9];
+        reader.read_exact(&mut masked_bytes[..output_byte_size])?;
+
+        // The flags never overlap with the 8 bytes of the element,
+        // and the 9th byte is left to zero when there are none.
+        let flags = F::from_u8_remove_flags(&mut masked_bytes[8])
+            .ok_or(SerializationError::UnexpectedFlags)?;
+
+        Ok((Self::read(&masked_bytes[..8])?, flags))
+    }
+}
+
+impl CanonicalDeserialize for Felt {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(r, _)| r)
+    }
+}
+
+// ARITHMETIC
+// ================================================================================================
+
+impl Neg for Felt {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Self(MODULUS - self.0)
+        }
+    }
+}
+
+impl AddAssign<&Self> for Felt {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        let (sum, carry) = self.0.overflowing_add(other.0);
+        // On carry, the sum is at most 2p - 2 - 2^64 and 2^64 is added back
+        // as 2^32 - 1, which cannot overflow and yields a canonical value.
+        self.0 = if carry {
+            sum + EPSILON
+        } else {
+            Self::canonicalize(sum)
+        };
+    }
+}
+
+impl SubAssign<&Self> for Felt {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        let (diff, borrow) = self.0.overflowing_sub(other.0);
+        // On borrow, 2^64 has been added to the difference,
+        // and is replaced with p by subtracting 2^32 - 1.
+        self.0 = if borrow { diff - EPSILON } else { diff };
+    }
+}
+
+impl MulAssign<&Self> for Felt {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        self.0 = Self::reduce128(self.0 as u128 * other.0 as u128);
+    }
+}
+
+impl DivAssign<&Self> for Felt {
+    /// Multiplies `self` by `other.inverse()`, and panics
+    /// if `other` is zero.
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, other: &Self) {
+        *self *= &other.inverse().unwrap();
+    }
+}
+
+macro_rules! impl_ops_from_ref {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident;)+) => {
+        $(
+            impl $assign_trait<Self> for Felt {
+                #[inline]
+                fn $assign_method(&mut self, other: Self) {
+                    self.$assign_method(&other);
+                }
+            }
+
+            impl<'a> $assign_trait<&'a mut Self> for Felt {
+                #[inline]
+                fn $assign_method(&mut self, other: &'a mut Self) {
+                    self.$assign_method(&*other);
+                }
+            }
+
+            impl<'a> $trait<&'a Self> for Felt {
+                type Output = Self;
+
+                #[inline]
+                fn $method(mut self, other: &Self) -> Self {
+                    self.$assign_method(other);
+                    self
+                }
+            }
+
+            impl<'a> $trait<&'a mut Self> for Felt {
+                type Output = Self;
+
+                #[inline]
+                fn $method(mut self, other: &'a mut Self) -> Self {
+                    self.$assign_method(&*other);
+                    self
+                }
+            }
+
+            impl $trait<Self> for Felt {
+                type Output = Self;
+
+                #[inline]
+                fn $method(mut self, other: Self) -> Self {
+                    self.$assign_method(&other);
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_ops_from_ref!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+);
+
+impl Sum<Self> for Felt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for Felt {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl Product<Self> for Felt {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for Felt {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::fields::{
+        fft_field_test, field_test, from_str_test, primefield_test,
+    };
+    use ark_serialize::SWFlags;
+    use ark_std::test_rng;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_field() {
+        let mut rng = test_rng();
+        let a = Felt::rand(&mut rng);
+        let b = Felt::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Felt>();
+        fft_field_test::<Felt>();
+        from_str_test::<Felt>();
+    }
+
+    // The serialization test of arkworks expects elements with and without
+    // flags to have the same size, while flags require here an additional byte.
+    #[test]
+    fn test_serialization() {
+        let mut rng = test_rng();
+
+        for _ in 0..100 {
+            let a = Felt::rand(&mut rng);
+
+            let mut serialized = [0u8; 8];
+            a.serialize(&mut serialized[..]).unwrap();
+            assert_eq!(a.serialized_size(), 8);
+            assert_eq!(Felt::deserialize(&serialized[..]).unwrap(), a);
+            assert!(a.serialize(&mut serialized[..7]).is_err());
+            assert!(Felt::deserialize(&serialized[..7]).is_err());
+
+            let mut serialized = [0u8; 9];
+            a.serialize_with_flags(&mut serialized[..], SWFlags::from_y_sign(true))
+                .unwrap();
+            assert_eq!(a.serialized_size_with_flags::<SWFlags>(), 9);
+            let (b, flags) = Felt::deserialize_with_flags::<_, SWFlags>(&serialized[..]).unwrap();
+            assert_eq!(flags.is_positive(), Some(true));
+            assert_eq!(a, b);
+        }
+
+        // Non-canonical encodings are rejected.
+        let serialized = MODULUS.to_le_bytes();
+        assert!(Felt::deserialize(&serialized[..]).is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = test_rng();
+        let modulus = BigUint::from(MODULUS);
+
+        // Values close to the modulus and to powers of two exercise
+        // all carry and borrow branches of the reductions.
+        let mut values: Vec<u64> = [0, 1, 2, EPSILON, EPSILON + 1, 1 << 63, MODULUS - 1]
+            .iter()
+            .chain([MODULUS - 2, MODULUS - EPSILON, MODULUS / 2].iter())
+            .copied()
+            .collect();
+        values.extend((0..20).map(|_| Felt::rand(&mut rng).0));
+
+        for &a in values.iter() {
+            for &b in values.iter() {
+                let (x, y) = (Felt::new(a), Felt::new(b));
+                let (big_a, big_b) = (BigUint::from(a), BigUint::from(b));
+
+                assert_eq!(BigUint::from(x + y), (&big_a + &big_b) % &modulus);
+                assert_eq!(
+                    BigUint::from(x - y),
+                    (&big_a + &modulus - &big_b) % &modulus
+                );
+                assert_eq!(BigUint::from(x * y), (&big_a * &big_b) % &modulus);
+            }
+        }
+
+        assert_eq!(Felt::from(u64::MAX), Felt::new(EPSILON - 1));
+        assert_eq!(Felt::from(u128::MAX), Felt::from(BigUint::from(u128::MAX)));
+        assert_eq!(Felt::from_repr(BigInteger64([MODULUS])), None);
+    }
+}
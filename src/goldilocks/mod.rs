@@ -0,0 +1,30 @@
+mod field;
+pub use field::{Felt, FeltParameters};
+
+use ark_ff::Field;
+
+mod sbox;
+
+/// An instantiation of Anemoi with state width 8 and
+/// rate 4 aimed at providing 128 bits security.
+pub mod anemoi_8_4;
+
+/// An instantiation of Anemoi with state width 12 and
+/// rate 8 aimed at providing 128 bits security.
+pub mod anemoi_12_8;
+
+/// The default Fiat-Shamir transcript over Felt, built on
+/// the instantiation with state width 8 and rate 4.
+pub type Transcript = anemoi_8_4::AnemoiTranscript;
+
+// HELPER FUNCTION
+// ================================================================================================
+
+#[inline(always)]
+fn mul_by_generator(x: &Felt) -> Felt {
+    let x2 = x.double();
+    let x4 = x2.double();
+    let x8 = x4.double();
+
+    x8 - x
+}
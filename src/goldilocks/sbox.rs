@@ -0,0 +1,72 @@
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 7;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 1] = [0x92492491b6db6db7];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 7;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(0x249249246db6db6e);
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+/// Squares `base` `N` times and multiplies the result by `tail`.
+#[inline(always)]
+fn exp_acc<const N: usize>(base: Felt, tail: Felt) -> Felt {
+    let mut result = base;
+    for _ in 0..N {
+        result.square_in_place();
+    }
+
+    result * tail
+}
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    // The inverse exponent is 0o1111111111_0_6666666666_7 in octal, which we
+    // process with a fixed window of 3 bits, for 65 squarings and 22 multiplications.
+    let x2 = x.square();
+    let x3 = x2 * x;
+    let x6 = x3.square();
+    let x7 = x6 * x;
+
+    let mut t = *x;
+    for _ in 0..9 {
+        t = exp_acc::<3>(t, *x);
+    }
+    for _ in 0..3 {
+        t.square_in_place();
+    }
+    for _ in 0..10 {
+        t = exp_acc::<3>(t, x6);
+    }
+
+    exp_acc::<3>(t, x7)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
@@ -0,0 +1,1870 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger256([
+            0x1a0b32bdec672b65,
+            0xcc644ee11d119ff5,
+            0xa08ffaec6dea9e0a,
+            0x2e73716a02e82e92,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0xf68adc6b64080328,
+                0x3e46bb636c60104b,
+                0x170a616bae13b267,
+                0x17c34e1cfaf41b36,
+            ])),
+            Felt::new(BigInteger256([
+                0x35dcadffdb79d941,
+                0x77ab2bd883d51cc2,
+                0x2772aaa95bb34459,
+                0x2ec48254626c8ce6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe8b88e92e41af61d,
+                0xa9fc13a3a4975482,
+                0x3bd3115c17fb19cd,
+                0x28e3f017f5b673d0,
+            ])),
+            Felt::new(BigInteger256([
+                0x25eb63f26df39e3d,
+                0xde65cee018788169,
+                0x1ec5fdf3daf3be15,
+                0x2cb72ae991ef74b1,
+            ])),
+            Felt::new(BigInteger256([
+                0xbf5f0b45931170d6,
+                0xcf1a8dc8cca95603,
+                0x3e791736d87a6aba,
+                0x16c76440954ae456,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x98d51008cde27935,
+                0x20635d722202ae5f,
+                0x663d35ce67de43be,
+                0x29db1bc55d8732f3,
+            ])),
+            Felt::new(BigInteger256([
+                0x8b175504bb21bf46,
+                0x9fa782192391e5a5,
+                0x558cd3556744b031,
+                0x0c6d1efd4b10ffee,
+            ])),
+            Felt::new(BigInteger256([
+                0x58ee21032904a8c7,
+                0x8ee0c5bfde73383a,
+                0x6d3085f8ec9f4d81,
+                0x2888bf05060da8f3,
+            ])),
+            Felt::new(BigInteger256([
+                0x0810e7be44d4faae,
+                0x9bdd04ce43d0a23d,
+                0x8ddc9ff32f197ca5,
+                0x1b395c001face92e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x1bbf6bdcc26a1e57,
+                0x88f5272c68b977d1,
+                0xe9caf728a70cc573,
+                0x1b2d959dcef364a4,
+            ])),
+            Felt::new(BigInteger256([
+                0x8b69fc49844e6bee,
+                0x29546dc869b5e6b1,
+                0x6933d9bbb4ddc50a,
+                0x1764fde77c291ce6,
+            ])),
+            Felt::new(BigInteger256([
+                0x7ae7baaef0f92557,
+                0x784b26f99ec6f064,
+                0x65e0b43b77ad3593,
+                0x0b740159c7e641ab,
+            ])),
+            Felt::new(BigInteger256([
+                0x847b158d80efdc21,
+                0x0564ca69424f8aea,
+                0x0379992e2de05e0f,
+                0x07db2aafa5f81f47,
+            ])),
+            Felt::new(BigInteger256([
+                0xd67c115d4ad27f7e,
+                0x93cb753395cbb291,
+                0x46ace4dda03e4b9e,
+                0x0a56e03629b9abd2,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x04a39270c78386cc,
+                0x2c721521fd4c44e0,
+                0x5959afe57f713229,
+                0x26afe3a6ce085abe,
+            ])),
+            Felt::new(BigInteger256([
+                0xfde9361deabeece2,
+                0x9af54ca44cbced46,
+                0xdaf48d5666a45821,
+                0x22b452db4d036ee7,
+            ])),
+            Felt::new(BigInteger256([
+                0x78d4cc434577bde5,
+                0xf084d4442afc9451,
+                0x76966d4ad9ffc37a,
+                0x242b315fd9221577,
+            ])),
+            Felt::new(BigInteger256([
+                0xdc285040683c480f,
+                0xbf43fa279973cbcd,
+                0xef0a84dd8a904eb0,
+                0x0bb4a48f6ccd04b2,
+            ])),
+            Felt::new(BigInteger256([
+                0x0f85383ee6e3860f,
+                0x6ad65a540421c7b8,
+                0xb56bda6bb816f1a8,
+                0x19cd07302a3dee76,
+            ])),
+            Felt::new(BigInteger256([
+                0x7ecaf38239c7a308,
+                0xd02453b318758181,
+                0x6c7abe39201e7356,
+                0x2e201e16abaf2d92,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xdd6386485f3f975f,
+            0x1aef487a075f04fc,
+            0x71ca2f391525ef6c,
+            0x02cbdbe9306a1193,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x24f382f7324ca333,
+            0x3d9d9d6bbcb4f299,
+            0x9bab39d72c2bd9cd,
+            0x0e6a26a584dd1b59,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x63cccac93bc83ed3,
+            0x1932969682efb0db,
+            0x149839ff435a91fd,
+            0x0c6f36fd13bf629c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xfa60557b9fcc693d,
+            0xdd96eec1dfa9bddc,
+            0x273ce3aaed86b944,
+            0x23bed1bb97e59646,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x71ce7d3fe7da39da,
+            0x9403192485df33b3,
+            0x3aee386f47db3f4b,
+            0x07042ec3aa33e8cb,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa4bb6fd01727f419,
+            0x5182a6ec77925f82,
+            0x1d28eda885b8b843,
+            0x0a62cd33b5eb8b2b,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0f4f97ed7fb4e4d6,
+            0x84496a9874e41e7d,
+            0x33fe68bae0f2a99b,
+            0x2bace007f2fa98d2,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x72848c78a2f025f2,
+            0x83b80e9198fec0b1,
+            0xfb20bf8c85d6e614,
+            0x19df04b5039bd2fb,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8c2186e77addf46b,
+            0x12a749e58365775e,
+            0x29a0aca85cbf0436,
+            0x083017650f4035df,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xabfd6082d8affce8,
+            0x265108ba6bd0b545,
+            0x7968ed48558c2101,
+            0x091705d2ed3322db,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd7c65cc054bbbab0,
+                0x8cd66ed23b228c3e,
+                0xdfd2700467aeb122,
+                0x29026dbe2f4e636e,
+            ])),
+            Felt::new(BigInteger256([
+                0x49a6cb6ddfdf6f8d,
+                0x2c4ccc3169ebc6aa,
+                0xcf063561699dc148,
+                0x2369bd0a4ad87737,
+            ])),
+            Felt::new(BigInteger256([
+                0xed3455f392d0f682,
+                0x1c11e4a287aa2d8e,
+                0x20e7727984cdc4bb,
+                0x097431b6fd7b50d2,
+            ])),
+            Felt::new(BigInteger256([
+                0x72f7150e5f2af977,
+                0xcfa0a77f207a9363,
+                0xecfb482d11d719fa,
+                0x0719cd7d2aaeecf9,
+            ])),
+            Felt::new(BigInteger256([
+                0xba640c0e07043105,
+                0xc3e7d9d956d9ccad,
+                0x6bfb19c8b1ac2d6f,
+                0x11bbd8ce0cca4f59,
+            ])),
+            Felt::new(BigInteger256([
+                0x3cca42f3c422313e,
+                0x4cb2a4b704d7319f,
+                0x7e161c9eab8679f3,
+                0x2b12a01a8563b2a5,
+            ])),
+            Felt::new(BigInteger256([
+                0x4b393ceab48da980,
+                0x82f790ccdcd85e72,
+                0x90fd816cabe0500b,
+                0x2a57b1b4d9d3abe9,
+            ])),
+            Felt::new(BigInteger256([
+                0xf664dcade67d5b6b,
+                0x5fa118d4258ca40e,
+                0x4f288408935fe80f,
+                0x249dc80f7be080f5,
+            ])),
+            Felt::new(BigInteger256([
+                0x4eb353af0d037403,
+                0xaf1f816d74c7c089,
+                0xde9808fc330b4a7e,
+                0x18a4f431251a97c2,
+            ])),
+            Felt::new(BigInteger256([
+                0xea21af7f37fde0b7,
+                0xccc34006cff90be9,
+                0x690e6be58ebc10f8,
+                0x18a5c351e319e977,
+            ])),
+            Felt::new(BigInteger256([
+                0x4022006c2e8b9d9e,
+                0x65247cd0b6a91b29,
+                0x07a8109bb74c9f3e,
+                0x298362767502bb62,
+            ])),
+            Felt::new(BigInteger256([
+                0x70cfa2951df083ae,
+                0x894b1744417c013a,
+                0x17605316a600d28f,
+                0x1e114c00a6d3313e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf0be8271e0b56b8c,
+                0x5dc6cd974e079eb5,
+                0xfae61a97ef34ca15,
+                0x2d2bdd47b1f2c781,
+            ])),
+            Felt::new(BigInteger256([
+                0x247477e26ae8cdcf,
+                0x775c61ddd94e03f1,
+                0xe24f09b1edd2b5a0,
+                0x19ba48ff984c6261,
+            ])),
+            Felt::new(BigInteger256([
+                0xbfe2afa8193e3584,
+                0x2370e4f5a38faeda,
+                0x0953fa07b31b2d73,
+                0x1e9c2d161f6aebd8,
+            ])),
+            Felt::new(BigInteger256([
+                0xbdc503033b2243a5,
+                0x1b5d16acd6aa5ec7,
+                0x2b44272ac8f04a88,
+                0x273cb2ceecc40c44,
+            ])),
+            Felt::new(BigInteger256([
+                0x5270e17a5ab7f1a9,
+                0xd16c2848fce3e03b,
+                0xa40d16daa91ed1b9,
+                0x2f9be544343c3161,
+            ])),
+            Felt::new(BigInteger256([
+                0x4e2b08b3ab1a4631,
+                0x7122b30d3b24df72,
+                0x822d0ac85b4b1890,
+                0x2d2ffedfd2fdd303,
+            ])),
+            Felt::new(BigInteger256([
+                0x74839a3446df69d1,
+                0x80b8b29aefa3fc48,
+                0x4d431de896ef5eeb,
+                0x267b7f9ffa687592,
+            ])),
+            Felt::new(BigInteger256([
+                0x1689fe47a3e4afbf,
+                0x65ade2dffe8e6299,
+                0x4ea1aea952ed3867,
+                0x10de18f5bb416fbb,
+            ])),
+            Felt::new(BigInteger256([
+                0x3a0ba1a07442cd92,
+                0x4dbc621b8a53d451,
+                0xf26c9829660a7fa0,
+                0x18db70e5fe505ac3,
+            ])),
+            Felt::new(BigInteger256([
+                0x91d4ba10704aea1e,
+                0xa3a0d6ea07652542,
+                0xb90b580359202b36,
+                0x131b0fc23754bc53,
+            ])),
+            Felt::new(BigInteger256([
+                0xb038c61278ffb70e,
+                0x16dbdc46215494bd,
+                0x92e63b686c2e30ad,
+                0x0cca1d840b1d8849,
+            ])),
+            Felt::new(BigInteger256([
+                0xf3384ef54dcecdd0,
+                0x6aa43ac18a23104d,
+                0x019c6215bdc93fba,
+                0x127b2887b8c89e94,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd34219b1f5d24f10,
+                0xeca1af40c79b3fc4,
+                0xfd8dbe06675a5bdc,
+                0x0fda6765ccf48587,
+            ])),
+            Felt::new(BigInteger256([
+                0xa32260f0ac0fba69,
+                0x071e3295bcc417ed,
+                0x99cf1f6324f08960,
+                0x2519ef6afba0de54,
+            ])),
+            Felt::new(BigInteger256([
+                0x86c65a1a99b57d19,
+                0x40dfee0a860dabbc,
+                0x8a75983a86921f2f,
+                0x207d26ea6a055fd0,
+            ])),
+            Felt::new(BigInteger256([
+                0x8e8432d52861f432,
+                0xd0f9c4781999e208,
+                0xb40261498ea32cbe,
+                0x02be12a4ff9b8414,
+            ])),
+            Felt::new(BigInteger256([
+                0x9f9598c2b91eb83a,
+                0x062411496b20f080,
+                0xcd8d8d315df3b1ce,
+                0x22e871ce1f827fbd,
+            ])),
+            Felt::new(BigInteger256([
+                0x1507f795e5eb3e67,
+                0x6b162e1520f40b3c,
+                0xb216d0db83ffa316,
+                0x0b87d8f7f474f804,
+            ])),
+            Felt::new(BigInteger256([
+                0xfd49d42f8f4df2be,
+                0x735394bc49a3a484,
+                0xce48af546dc0fcf5,
+                0x099cb384c49b148c,
+            ])),
+            Felt::new(BigInteger256([
+                0x9e9ad486a6477f32,
+                0x4ffe860f5b0603b4,
+                0x6772bf974821c80a,
+                0x18e0464052c01e9d,
+            ])),
+            Felt::new(BigInteger256([
+                0x2541b08d434516ae,
+                0x38f899ded2b26115,
+                0x3d5f2fd77fbfb366,
+                0x29dda6ca36c2a488,
+            ])),
+            Felt::new(BigInteger256([
+                0x283813aa7a8862c9,
+                0x1c18084007b852e9,
+                0x35ccf47340cc252f,
+                0x18529359694e5aed,
+            ])),
+            Felt::new(BigInteger256([
+                0x4988e24baad96697,
+                0xad2d871084a526ee,
+                0xb3d57ad6222b47ef,
+                0x242d154fcea6cdbc,
+            ])),
+            Felt::new(BigInteger256([
+                0xa61ccab0afcb802f,
+                0x397983f1b058517a,
+                0x8ed211642047554e,
+                0x182d07926f98e589,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb2aee44b5be0ed27,
+                0x0698bcfb2d6e3c55,
+                0x4fc60be9d9fa7cfd,
+                0x147057e69d6f39fe,
+            ])),
+            Felt::new(BigInteger256([
+                0x4a529c802ac92ccd,
+                0x7d6365a8855b7863,
+                0xaed3514f60c3c450,
+                0x0f0e52993471906a,
+            ])),
+            Felt::new(BigInteger256([
+                0xda6937c1dc30def6,
+                0xde931d2919228367,
+                0x423a3fca3dd338a6,
+                0x21161c9ad119f810,
+            ])),
+            Felt::new(BigInteger256([
+                0x34d2695185e557a0,
+                0x4aed6f43a16f0e54,
+                0xf07c50e29d08c535,
+                0x2abc84cf6014cc17,
+            ])),
+            Felt::new(BigInteger256([
+                0x74f4fceac172612f,
+                0xf00b99cfaeb1adbc,
+                0x8b9b05ea4b44c36d,
+                0x1c054a0c55387414,
+            ])),
+            Felt::new(BigInteger256([
+                0x759b83dc10191ffb,
+                0xd183ab64cd197f95,
+                0xac65b06016cd555e,
+                0x1e0e1023ecd7fa9b,
+            ])),
+            Felt::new(BigInteger256([
+                0x7ffa623f6b016f80,
+                0xc52ac355b0507345,
+                0x69039148588b31da,
+                0x12f2a3beba01a056,
+            ])),
+            Felt::new(BigInteger256([
+                0x9ee7bfade7554790,
+                0xbea9d59a07d51efe,
+                0x74b8f5021539e9f0,
+                0x0ecc5314f6e5bd79,
+            ])),
+            Felt::new(BigInteger256([
+                0x75f0b4507a32b208,
+                0xcaea0fa3483d58ca,
+                0x26f1bc439a07fda9,
+                0x0f72de29d3cac949,
+            ])),
+            Felt::new(BigInteger256([
+                0x2386219d8dc4bfb7,
+                0x40124bb9a3c43529,
+                0x1e0e46760c8f7975,
+                0x2294e705f2b1c98d,
+            ])),
+            Felt::new(BigInteger256([
+                0x0badfa63b927cd7b,
+                0x93e6d4a7d57f0e05,
+                0x0c43ee9bb124b34b,
+                0x16882adce5c9c528,
+            ])),
+            Felt::new(BigInteger256([
+                0xe2b133c1f113a7ed,
+                0x79a0920bb599a1d2,
+                0xd4873032264e946e,
+                0x20df367af8082e0c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb1fe2029464d7337,
+                0xf60ff4e06fecb823,
+                0x1e6c5cce35a08d59,
+                0x03f0ecb17e3897b8,
+            ])),
+            Felt::new(BigInteger256([
+                0x3eae63e17287dac9,
+                0xf36c481fbf0d654a,
+                0x9943d8a9b04985f4,
+                0x205c5c9e2bc1cd7d,
+            ])),
+            Felt::new(BigInteger256([
+                0xaa5f2bd0bc0bad6a,
+                0xf6d42b5bc3f17e9a,
+                0xcfcd8a42089571fb,
+                0x1611eee8797436b6,
+            ])),
+            Felt::new(BigInteger256([
+                0x8467421148241ce7,
+                0xafec173139838de9,
+                0xf74db03f424bb847,
+                0x14a6b75863b7306e,
+            ])),
+            Felt::new(BigInteger256([
+                0xd24ada1820e77d6b,
+                0x6d07bb64512f81ca,
+                0x2611c1745cb91d9f,
+                0x245a08fce1c9b6a2,
+            ])),
+            Felt::new(BigInteger256([
+                0xc94ca7d24d30a32c,
+                0xec8caa5713e44236,
+                0xeb9149b9b00cc710,
+                0x16873d8aae0286cf,
+            ])),
+            Felt::new(BigInteger256([
+                0x975387b69f3592cc,
+                0xb52c777e9f9e1019,
+                0x5c90da96a78cf40b,
+                0x22d2a09922244559,
+            ])),
+            Felt::new(BigInteger256([
+                0x38a6a7a0e5842edc,
+                0x02777756bd96f4c6,
+                0xb260e0c384ec7bfa,
+                0x22c27e98c5a8ccd0,
+            ])),
+            Felt::new(BigInteger256([
+                0xad29223fef1fdcef,
+                0xe68ac6bf6519942d,
+                0xf2b1c6b5065ee98f,
+                0x25566caec7699ae0,
+            ])),
+            Felt::new(BigInteger256([
+                0xcedaed8600d0519b,
+                0x970dee07b3fd3b26,
+                0x9a6a629ddc50735f,
+                0x032e3760bf0381f2,
+            ])),
+            Felt::new(BigInteger256([
+                0xe562cd964e7eb03b,
+                0xf25f475ae734366a,
+                0x2cb0e64f9bfde1e0,
+                0x07fd59f64f445885,
+            ])),
+            Felt::new(BigInteger256([
+                0xa91047c43f05d2bf,
+                0x5bb6bbf10b1e97f2,
+                0x6383a9b1c7d43415,
+                0x265a545f54647736,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x1022b0c6e865ae05,
+                0x35b17a8071de2eb9,
+                0x05a4a831573c930c,
+                0x033f1fe4fe379723,
+            ])),
+            Felt::new(BigInteger256([
+                0xf2b7f735dc938a7c,
+                0x4e7e393eb594be2c,
+                0x9bd7b0e54b7bd0b3,
+                0x0bb3b960a3f06dff,
+            ])),
+            Felt::new(BigInteger256([
+                0xd271c4d7906cf44e,
+                0x899314cb0028433f,
+                0xc71a2c11933df8f8,
+                0x25f5289e949f8609,
+            ])),
+            Felt::new(BigInteger256([
+                0xfe789882dc4c446f,
+                0xfac61852da6d66aa,
+                0xb30ca11a44bdcfd7,
+                0x24aef9da03edbcab,
+            ])),
+            Felt::new(BigInteger256([
+                0x32d87d64e9e1f6f0,
+                0x5dc6d595d24e3849,
+                0xa08f03f8cc990589,
+                0x0497e49780e2763f,
+            ])),
+            Felt::new(BigInteger256([
+                0xd9ce0ec9b1716410,
+                0x639c9dbc367cc915,
+                0x230d1221cf91a3a5,
+                0x0d1e4dacbe33d945,
+            ])),
+            Felt::new(BigInteger256([
+                0x18779305ddd8d576,
+                0xe0686ac621b025dd,
+                0xb8750088f22cc361,
+                0x00c09be6e922d7a4,
+            ])),
+            Felt::new(BigInteger256([
+                0x02b0df1988163442,
+                0x722a2825c382fc78,
+                0xd097a9838de2a977,
+                0x29b2173e3b26691b,
+            ])),
+            Felt::new(BigInteger256([
+                0x7949f43780344af6,
+                0xa66bf78c1345b249,
+                0x372d327fdc124e08,
+                0x05ee70cb110a967a,
+            ])),
+            Felt::new(BigInteger256([
+                0xd05532a727637469,
+                0x26169622f7c135bf,
+                0x67c3491f7ab893cb,
+                0x2377e7a44ab0e346,
+            ])),
+            Felt::new(BigInteger256([
+                0x4e850655ac91fc76,
+                0x3324600fb85ebd6f,
+                0xf6ed22fc6bee5364,
+                0x1f11a42c9a73c40e,
+            ])),
+            Felt::new(BigInteger256([
+                0x7b44f52f55bb3630,
+                0x1f0757e588c786b7,
+                0x19eebee9203265d0,
+                0x2e441fd711c268ea,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x0adfdba62b48670e,
+                0xd3eafec991328dca,
+                0x87af4e004aac55ef,
+                0x230f9462bf54db9f,
+            ])),
+            Felt::new(BigInteger256([
+                0x811ac7ca4bb7f787,
+                0x691da674837f255d,
+                0x23126c181ec253ea,
+                0x1b248f624de682af,
+            ])),
+            Felt::new(BigInteger256([
+                0xfc626798f7836831,
+                0x6cd95835cff0b02f,
+                0x557a965ca4462cf1,
+                0x29a4c1fd427fd258,
+            ])),
+            Felt::new(BigInteger256([
+                0x2d8286548368181c,
+                0x664a82d7a7785dd5,
+                0x643c355758e4a21a,
+                0x069182729689d506,
+            ])),
+            Felt::new(BigInteger256([
+                0xdc8e81a4849f0fe8,
+                0x2d16ff97cd02bb60,
+                0xb14860f71c5a2974,
+                0x0f94ceeced39c187,
+            ])),
+            Felt::new(BigInteger256([
+                0x2cf64e3177ce912f,
+                0x9196d40908f6d7f0,
+                0x40d5d9b3032c67e0,
+                0x2f0345e1892f42a5,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xd2a8de6887142d0d,
+                0xb1c6908dc5e0637d,
+                0x537ab05635518b2a,
+                0x0fa3e84b13883c79,
+            ])),
+            Felt::new(BigInteger256([
+                0x06a7ba952e021f7b,
+                0x688b4a7bd61f78ef,
+                0xd78c19b0ad4865ab,
+                0x118c3dffe46d011c,
+            ])),
+            Felt::new(BigInteger256([
+                0xc71cf0664085b849,
+                0xfc084c81ebcd4499,
+                0x9d9273609c7ca47b,
+                0x0c3f835b39afea11,
+            ])),
+            Felt::new(BigInteger256([
+                0x12ab682cc9d4f556,
+                0x0bb5b566ad25df47,
+                0xd162a4990b52064e,
+                0x1650ccc8490072c9,
+            ])),
+            Felt::new(BigInteger256([
+                0x9ec0588056d6aa10,
+                0xbba1f6547678ead0,
+                0xb652ef63bedd1728,
+                0x2b46bf47acf6547a,
+            ])),
+            Felt::new(BigInteger256([
+                0x366ae9b59afb1f29,
+                0xa6497bd89fd387ef,
+                0x7d0a6688aca801a7,
+                0x2753cc8fb1395094,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x190b8d9b757c9ebc,
+                0xfe8ecf8cf27ed90f,
+                0x26ec5e95fa9a4e68,
+                0x01304f5d768196aa,
+            ])),
+            Felt::new(BigInteger256([
+                0x4426b7294ee5c99a,
+                0xe94a42c82e9bacf6,
+                0x53f12101788da6db,
+                0x2be927497027088a,
+            ])),
+            Felt::new(BigInteger256([
+                0xceb385fb2b5bb757,
+                0x82ddbda17b572e7a,
+                0xfc925cffb27ab631,
+                0x0eb26546794aeb36,
+            ])),
+            Felt::new(BigInteger256([
+                0x48e3b05c7868ccd3,
+                0x4b6f47bd3bfa6751,
+                0xabcb89ff551ef3d2,
+                0x2b226d104854685f,
+            ])),
+            Felt::new(BigInteger256([
+                0xef39aebf4250d97d,
+                0x68184deab6746e7f,
+                0x3a0de6c39ef1bdac,
+                0x22b54a82b00204d5,
+            ])),
+            Felt::new(BigInteger256([
+                0x93b1b6b32e17b0bd,
+                0xd271ff9f74ec7331,
+                0xaea41a5e92de5d4a,
+                0x04fcc3d5c2ee7045,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xe331d68d60716c3d,
+                0xe74fc5185cdff58e,
+                0xaef5f18dcb9b8089,
+                0x2250cc0119183f0f,
+            ])),
+            Felt::new(BigInteger256([
+                0x9139bea4de5dd851,
+                0xbd60923c9529fde8,
+                0x70fb8c77c0d3d060,
+                0x29ae912aa38c3562,
+            ])),
+            Felt::new(BigInteger256([
+                0x85871ec01f73b930,
+                0x1c9a943d4a58bca8,
+                0xdb98c9a9e7c84b3c,
+                0x18b1c0cfd6708ed8,
+            ])),
+            Felt::new(BigInteger256([
+                0xc0aaa5ddec72518f,
+                0x0b946f2b800add05,
+                0x7cbc9fcebe2334a5,
+                0x109a37eff3184b1d,
+            ])),
+            Felt::new(BigInteger256([
+                0x6d79cc4fa67d377e,
+                0x92fb94238f941f7e,
+                0x0de321406d4f1771,
+                0x2d4a0feb61aafeff,
+            ])),
+            Felt::new(BigInteger256([
+                0xaf044837a28b31c3,
+                0xad9c7ef090673cec,
+                0xf2444ab4e6fb4f7d,
+                0x1d2f0456265fd7e0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5abf4b20e559607c,
+                0xbb42336ac8cd8a27,
+                0xcb80b3ea0fe39d5b,
+                0x2df44cede0a9a50e,
+            ])),
+            Felt::new(BigInteger256([
+                0x7d95306a57bf69af,
+                0xbccb219b7ee51b2a,
+                0xfe9963ecea43ab46,
+                0x12a56616f0728c3c,
+            ])),
+            Felt::new(BigInteger256([
+                0x73190384e8aaa2a1,
+                0x57c4da79d15508be,
+                0xe99c04319372cef2,
+                0x216e7caed83ac6ea,
+            ])),
+            Felt::new(BigInteger256([
+                0x20d8568de0e5f4ad,
+                0xc8c72480864d1dd6,
+                0x7ccd50288c641175,
+                0x012ddd1b00a48f78,
+            ])),
+            Felt::new(BigInteger256([
+                0x144fd2aebe4d56f2,
+                0xdf92ab19c4fd29b2,
+                0x63b5c7b8ded74eeb,
+                0x27c0def6df6740c2,
+            ])),
+            Felt::new(BigInteger256([
+                0xe6edb93408dd411a,
+                0x6bc8f9c43feeb2f5,
+                0x123b4cde2d1570ca,
+                0x208b1bda0d48b127,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x1965022caba46a88,
+                0xe28fe0648706d04f,
+                0x28ee4b913a5cca94,
+                0x1e6b4a475b32f3c0,
+            ])),
+            Felt::new(BigInteger256([
+                0x2643184bff33e8cb,
+                0x1ff763d4ee1bf177,
+                0x1f1ef57cd854b8be,
+                0x15b6bbb21aa9a5ee,
+            ])),
+            Felt::new(BigInteger256([
+                0x5056b739e4062477,
+                0x7351a54646f1b0ab,
+                0x8afed1325b492039,
+                0x1fd6f68956d057b9,
+            ])),
+            Felt::new(BigInteger256([
+                0x1bf77455c3fcbc07,
+                0x5298ae0a9272caa5,
+                0x67ea76bd60c27565,
+                0x00088627ac9dbafd,
+            ])),
+            Felt::new(BigInteger256([
+                0x22a5c68945095585,
+                0xd3f8aa2718365979,
+                0xab07b1a3a4a7164f,
+                0x1daa92a70fd54168,
+            ])),
+            Felt::new(BigInteger256([
+                0x676958e643e4de39,
+                0x45e20229aec5ec6a,
+                0xdafe528f8f071831,
+                0x12bc9a2826108b3e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xe8357cb5d5f306c8,
+                0x02aea013df41e117,
+                0xddc074fc14e1853a,
+                0x294eac48121ca2a7,
+            ])),
+            Felt::new(BigInteger256([
+                0x6e48901f9e14535f,
+                0x69ab4bd8a9c66277,
+                0xca6c16df5b4dff5d,
+                0x268bcf5688cdf527,
+            ])),
+            Felt::new(BigInteger256([
+                0xcaec45c8d992b833,
+                0x2eee63e685b7d233,
+                0x57b079e2fb38b8b9,
+                0x2d0e6c4df86c7cea,
+            ])),
+            Felt::new(BigInteger256([
+                0x010120d2e29bfc61,
+                0x7b823245e509edfa,
+                0x59383c0c0cc497cb,
+                0x16a90008fff26ce0,
+            ])),
+            Felt::new(BigInteger256([
+                0x6d1f30496a9d7855,
+                0xadb6e0a6d4e8de29,
+                0x36e5afcacee058d2,
+                0x061b9dee5bc0f58a,
+            ])),
+            Felt::new(BigInteger256([
+                0xbf79ebae274d9a32,
+                0x20f23269b833806d,
+                0x8e5a7d3c93933254,
+                0x0ef7b821bf97c1ea,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x93fc6920f1f9a902,
+                0xcf9a634eb3e4f4c8,
+                0x07e23dc5e1c0dd83,
+                0x0a3fc0c5cab28f04,
+            ])),
+            Felt::new(BigInteger256([
+                0xae6037b15cca7bc6,
+                0x7d46a92d749fa35e,
+                0xe51242c0e3aa8a79,
+                0x27b89e6852b40673,
+            ])),
+            Felt::new(BigInteger256([
+                0xa9bc6d9a115ccd71,
+                0xbaa30ae2ecc51367,
+                0x8fc79db13f18b287,
+                0x0e3d86f3e410844b,
+            ])),
+            Felt::new(BigInteger256([
+                0xd74bad25c4a0ada4,
+                0x7fd31abfc972509c,
+                0x634ad4baeba692ef,
+                0x133cc06dee808719,
+            ])),
+            Felt::new(BigInteger256([
+                0x596cfc5066f378c3,
+                0x49488fc1027381c3,
+                0x69e399cacd7a223a,
+                0x0023e6bc8099378d,
+            ])),
+            Felt::new(BigInteger256([
+                0x038db84ef160b1f2,
+                0x6b39ebcbb1254260,
+                0xd6a08b416be102f8,
+                0x2c34c0b90550399e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xff7ac41787cf6568,
+                0x5f4f2eb1eed6d845,
+                0x264a082fc512d9d5,
+                0x2d5523547bdc5906,
+            ])),
+            Felt::new(BigInteger256([
+                0xdb06345bbd8ba517,
+                0xda31df6e182e5367,
+                0xb0fe8df9eaf6bf53,
+                0x1b7d7ba05cb5e730,
+            ])),
+            Felt::new(BigInteger256([
+                0xbf6ef8b983eeb312,
+                0x4017cdd1bc470f8a,
+                0xf34345dae0fb2e1a,
+                0x0563e7521dbcb4f1,
+            ])),
+            Felt::new(BigInteger256([
+                0x2b01e8a2c941505d,
+                0x1da5bb10eea7033e,
+                0xf750d70ce119846a,
+                0x0eb8578ea7c84b29,
+            ])),
+            Felt::new(BigInteger256([
+                0x878efa6a3aefcc70,
+                0x4d79a1f34fc7f958,
+                0x4f6868a4e7e0a8b5,
+                0x291b3673c4314d7f,
+            ])),
+            Felt::new(BigInteger256([
+                0x719b74cf0cb6b1a9,
+                0xd3ba8c93cff8c7e1,
+                0xf0e879fe42ccdc32,
+                0x27c47c96e32b3b55,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x7938ce36e63c0279,
+                0x037c368adac4bc9e,
+                0xb5c850f7607db4a9,
+                0x0f8a5fb6ebfac271,
+            ])),
+            Felt::new(BigInteger256([
+                0x4b430fb9e191f591,
+                0x23ae5aabaed37821,
+                0xca501c83fdccb063,
+                0x00b2fbea90d9c483,
+            ])),
+            Felt::new(BigInteger256([
+                0xa2aa8b6c42154025,
+                0x717bbf2950d91790,
+                0xb96ad7d1e2a7f087,
+                0x195d0ffdb10637c1,
+            ])),
+            Felt::new(BigInteger256([
+                0x62646b66448297b2,
+                0x0e7125daa1c10e40,
+                0xd663d19c5531a252,
+                0x0cff475012b412c6,
+            ])),
+            Felt::new(BigInteger256([
+                0xa0b3bec7d540bd2e,
+                0x1337dbaeb4c94340,
+                0xd7e3f585003b879c,
+                0x22c79c1f9907a8c0,
+            ])),
+            Felt::new(BigInteger256([
+                0x6363f811df3a5bc4,
+                0xe680c024d53a8868,
+                0xcd49fd337c0ed838,
+                0x17e7782a988163ab,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x6273c541117475cd,
+                0xb7baf2c37bc062a6,
+                0x9a8b896889c24d7a,
+                0x1e0b0d9fd75ef35e,
+            ])),
+            Felt::new(BigInteger256([
+                0xe4e9baee401c10f0,
+                0x66ee9b17c885e5cf,
+                0x927860a664b739d6,
+                0x0116ef3acead7d70,
+            ])),
+            Felt::new(BigInteger256([
+                0xa2d41c022b7af8a7,
+                0x155342ff0292e1da,
+                0x17f92c4e9bff1bc8,
+                0x139fde28e2bff0e2,
+            ])),
+            Felt::new(BigInteger256([
+                0x690d9f144c1730b9,
+                0x45bdf24ff280358d,
+                0xd5e5c0e90cb8a0ef,
+                0x06fc1646675863ef,
+            ])),
+            Felt::new(BigInteger256([
+                0xad6483d84b61fd1e,
+                0x0f2de2ff8b19cc06,
+                0xc9c3419a8f3d6b8c,
+                0x2589d4d31cd27a18,
+            ])),
+            Felt::new(BigInteger256([
+                0x4fec33c5e9e6feaf,
+                0xe0bed47f3469f75d,
+                0xa9cdda369f6256a3,
+                0x06f4e628c3d580a6,
+            ])),
+            Felt::new(BigInteger256([
+                0x8f7fe05eb4382e60,
+                0xfbfd2eaa9994044a,
+                0xddffc31f7c675e46,
+                0x186e1213e3a52b42,
+            ])),
+            Felt::new(BigInteger256([
+                0xf9894e3ccd32b518,
+                0xd6d6cae4e751b667,
+                0xd7f8dbced8fe33f1,
+                0x2de6ff0269f3d387,
+            ])),
+            Felt::new(BigInteger256([
+                0xc5385c45f6c3e1aa,
+                0xed573be4f4428db8,
+                0xb5e8962d8c8cdea9,
+                0x037d74f57815a1f9,
+            ])),
+            Felt::new(BigInteger256([
+                0x0b32a31c5f620a45,
+                0x89a539dfa6d321ec,
+                0x748708676b7845ba,
+                0x195a8c41601b91f3,
+            ])),
+            Felt::new(BigInteger256([
+                0xc37bc807ed5daf94,
+                0xed0df6869854c5f1,
+                0xfa4661ec8499cc60,
+                0x2aebd86b87250a78,
+            ])),
+            Felt::new(BigInteger256([
+                0x446071722d4d6d68,
+                0xb02aceced0d49c6f,
+                0xff5e2c5e9618c589,
+                0x2ee984b5f1497839,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7492a8914fa5878c,
+                0xd91a6faffe239b36,
+                0x818f8561b5d3bf10,
+                0x1521912e6b0afe7f,
+            ])),
+            Felt::new(BigInteger256([
+                0x0ff3152bc30ee9e2,
+                0xe6df575e49cb205e,
+                0x1749913c50352a12,
+                0x1c53f1a2ec9c4665,
+            ])),
+            Felt::new(BigInteger256([
+                0x6423afd5208ea144,
+                0xd5dfeaf08912e181,
+                0xb0f628986462101c,
+                0x01d5fc4c3f192147,
+            ])),
+            Felt::new(BigInteger256([
+                0x2d43225f4765490a,
+                0xbf3ecc4f28c3c7f6,
+                0xfb207353e822f17b,
+                0x2a6124176b3d5bc8,
+            ])),
+            Felt::new(BigInteger256([
+                0x98ef1c812728f908,
+                0x4c53a593de4516b0,
+                0x83e093e1255eb57d,
+                0x14105324b67258a5,
+            ])),
+            Felt::new(BigInteger256([
+                0xdd1be776c2edd479,
+                0x74cc8c7a3a0fe98b,
+                0x1660a5125462708d,
+                0x1f85d677c0fda79e,
+            ])),
+            Felt::new(BigInteger256([
+                0x22dfe3bc19a38c55,
+                0xf79cad49eef03d5b,
+                0x07aeeb433e232948,
+                0x1c6af69c1885b957,
+            ])),
+            Felt::new(BigInteger256([
+                0xd79784120cf1dbc0,
+                0xde5e1aecc8e986f5,
+                0xd22911182173712c,
+                0x216266d3e9148999,
+            ])),
+            Felt::new(BigInteger256([
+                0x1e53e5b0f370f18c,
+                0x1c3ae9e6cb6e5149,
+                0x5b37f87884beec3c,
+                0x037de7689932c4ad,
+            ])),
+            Felt::new(BigInteger256([
+                0x7bee70caffa4429f,
+                0x0a2ee88e91842277,
+                0x0f96db0eb4570419,
+                0x0ddfcaa293baa832,
+            ])),
+            Felt::new(BigInteger256([
+                0x7b0597488f76b7c6,
+                0xc5895340355f9ab1,
+                0xd0bd26a9015a956d,
+                0x28bf4790425d70a6,
+            ])),
+            Felt::new(BigInteger256([
+                0xc4b7c35b0ec8958c,
+                0xf554979d17327636,
+                0xfac1af891bc7d0a4,
+                0x14d4f1fea878ff6f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf5e76e5b92b6303b,
+                0x6505117c4dbf2fcb,
+                0x64a5fdc3a524759c,
+                0x1a29f17703248c24,
+            ])),
+            Felt::new(BigInteger256([
+                0x01030ee58fa3725e,
+                0xaa478f41e81e8d29,
+                0x1712c4d8c05ef473,
+                0x0f0b78a6aaabf14d,
+            ])),
+            Felt::new(BigInteger256([
+                0x039c5b1c51695079,
+                0xe0d41cec5d4afb3c,
+                0x0824384fa6dbd6ca,
+                0x216df44275250dd7,
+            ])),
+            Felt::new(BigInteger256([
+                0x8d9b169a43675849,
+                0x3088b375721a9fca,
+                0x387eea2cc3e46720,
+                0x1d1f2a560408aa1b,
+            ])),
+            Felt::new(BigInteger256([
+                0xfa525b9737c68df5,
+                0xb541c813185ea54c,
+                0x434c1e35000ecbfa,
+                0x103724ce442037b8,
+            ])),
+            Felt::new(BigInteger256([
+                0x3f0c3906f1994f73,
+                0xb446d63e2bf415c6,
+                0x5cc1e321ab3fabae,
+                0x17c95546d33f2558,
+            ])),
+            Felt::new(BigInteger256([
+                0x40af8eb3a71f6b3a,
+                0x267e569aa0474678,
+                0xdc609b9ae690b984,
+                0x2bd53f83aabc63a1,
+            ])),
+            Felt::new(BigInteger256([
+                0x043f319c89fbab8c,
+                0x010e31cf9cdd5fed,
+                0xc4ad99093272b85c,
+                0x297c61e69f59fdec,
+            ])),
+            Felt::new(BigInteger256([
+                0xde94ebc3dc1cca44,
+                0x6274f851abd05a7c,
+                0x9b195e35447d02e3,
+                0x2d024745f020264e,
+            ])),
+            Felt::new(BigInteger256([
+                0xc1a23d005143e147,
+                0x7477fed1fa4e8d99,
+                0x63aa2bf4f2b34834,
+                0x27c77eba200ca9a6,
+            ])),
+            Felt::new(BigInteger256([
+                0x09ed93373a4fd3dd,
+                0x653c28006a0dfbf3,
+                0x5d226dd852b91ec1,
+                0x1024d0493f397e65,
+            ])),
+            Felt::new(BigInteger256([
+                0x2635679566a091e4,
+                0xfe1f766a904a11f9,
+                0x7416dc8fb05bfe06,
+                0x0c94ab2d5d996502,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd254e1a967735f27,
+                0x6129ebb409df11f3,
+                0x6d0f8b7f67209eb0,
+                0x042f8d8aa3c66c3e,
+            ])),
+            Felt::new(BigInteger256([
+                0x8478acb7131201dc,
+                0x76fbcaa3c9098121,
+                0x60a41001184f3fbb,
+                0x0e9b55354378c760,
+            ])),
+            Felt::new(BigInteger256([
+                0x157764a6dbbe2f64,
+                0xad00dec4335046db,
+                0x72a32063d16fd05b,
+                0x2d835604c86307ea,
+            ])),
+            Felt::new(BigInteger256([
+                0x2e88b4b7ec1d8b9a,
+                0x6bf16d716682ea3a,
+                0x68bafe46a10b540f,
+                0x020d37f91fab6623,
+            ])),
+            Felt::new(BigInteger256([
+                0x7f6d163b17d4a7a9,
+                0x92e98c1082088762,
+                0x0670e8e51a6e175e,
+                0x05d9be168bc99f67,
+            ])),
+            Felt::new(BigInteger256([
+                0xda9e0ab7a569dae2,
+                0xea0b4295ec4b2d6a,
+                0x87d5dfed4fc17aab,
+                0x0d4d175a9ae9a881,
+            ])),
+            Felt::new(BigInteger256([
+                0x337d93ffdf9d4d5c,
+                0x3ec9156177922c6b,
+                0xd61d2d31a475f783,
+                0x03424572c0c90d71,
+            ])),
+            Felt::new(BigInteger256([
+                0x289ae0524f4f0a31,
+                0x0980727c9ac2d1a1,
+                0x78664aca2bfbb099,
+                0x029e624622bceee2,
+            ])),
+            Felt::new(BigInteger256([
+                0x443664420f1e293e,
+                0xf69dba614bdcaaae,
+                0x22a8969b950762c6,
+                0x05a2c5d1caea7bbc,
+            ])),
+            Felt::new(BigInteger256([
+                0x163276edd636fdeb,
+                0xa18237a8486c7472,
+                0x924ee97bf5d133c5,
+                0x1d547fadfe303e49,
+            ])),
+            Felt::new(BigInteger256([
+                0x176cd3e93610f44f,
+                0x29435890c86039ed,
+                0x414791b3a3ceb55d,
+                0x287f6c6882ace494,
+            ])),
+            Felt::new(BigInteger256([
+                0xb53622adb8a16021,
+                0xa3d79282bd837038,
+                0x2de093f8b827ce57,
+                0x20a15b40a414fa86,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x62b6b18642ac510c,
+                0x079918b37831f3ed,
+                0x395281a6ab3e942a,
+                0x2b71bf0dd2401184,
+            ])),
+            Felt::new(BigInteger256([
+                0x29fa063ac91e48cf,
+                0x693dfba7500d9b93,
+                0xded50f9837003f67,
+                0x024d5534437b835b,
+            ])),
+            Felt::new(BigInteger256([
+                0x0a441f18b4960840,
+                0x3c32dcf90aa51feb,
+                0x2b1eee5127901dd2,
+                0x23655879da36d057,
+            ])),
+            Felt::new(BigInteger256([
+                0x4b52d3e43c923e0a,
+                0xf00a19fc59c72c88,
+                0x145440e3ff3c552f,
+                0x01cace86da8734e9,
+            ])),
+            Felt::new(BigInteger256([
+                0x592362912bd9af46,
+                0xe48b65074abd6859,
+                0x2d1a47523a1ac708,
+                0x228b770cb59f7bd9,
+            ])),
+            Felt::new(BigInteger256([
+                0xd7c9555c60f498c1,
+                0x97480c1df6215df2,
+                0x8ec5ac6340fc431d,
+                0x0b821ac760ecc678,
+            ])),
+            Felt::new(BigInteger256([
+                0x1e45cc33ea53352d,
+                0x1ae1b8d24a4038d9,
+                0xffba53426dceca93,
+                0x1d9ff1fc76bf9560,
+            ])),
+            Felt::new(BigInteger256([
+                0xebaff45fef172f39,
+                0x03621c9cd8c70153,
+                0x2e5d109323eac3ac,
+                0x23d4e436d58f16dd,
+            ])),
+            Felt::new(BigInteger256([
+                0xd7840b422f72abaa,
+                0x78660a97586b06ba,
+                0xafd7d520871b0870,
+                0x2ed25a40feab0af6,
+            ])),
+            Felt::new(BigInteger256([
+                0xc0381559c29b6707,
+                0x76849f8a7c36fd3e,
+                0x89c709378b89591d,
+                0x272bc8bc28189a4d,
+            ])),
+            Felt::new(BigInteger256([
+                0x6470f5e7a06511ce,
+                0x03b9f224ebc10618,
+                0xb25ca563ab6f8b39,
+                0x22f07f6e80b98f88,
+            ])),
+            Felt::new(BigInteger256([
+                0x2fc7d4ca704cce8e,
+                0x7c5c848cf604cb92,
+                0xe41f33c70608f1e1,
+                0x1fe5808ff916498c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x94866fca382fd05f,
+                0xdccea3b35c77b556,
+                0xcd01dfee26b37f53,
+                0x11299861af4b299e,
+            ])),
+            Felt::new(BigInteger256([
+                0xa232fcd3a090ac54,
+                0x490cbdb180a491c3,
+                0xc17dc73f0c4752ac,
+                0x0d3434b416ca7b03,
+            ])),
+            Felt::new(BigInteger256([
+                0xb14c7a256952124d,
+                0xa2865a926a2e3b6a,
+                0xcfdc64b883f6f5ec,
+                0x20fb73d53fd52703,
+            ])),
+            Felt::new(BigInteger256([
+                0x1968f5ceadc3547c,
+                0x3075b71d319c3f0a,
+                0x7d79df02b082ea17,
+                0x06845c142a8e10a2,
+            ])),
+            Felt::new(BigInteger256([
+                0xccceb350dbfde735,
+                0x23cdac759967734f,
+                0xd349053b97aefafb,
+                0x0ec2ecc61a13f9f6,
+            ])),
+            Felt::new(BigInteger256([
+                0x85adb90fa1edfba0,
+                0xb030c2a331458c7d,
+                0x40be23e2dbee7c1e,
+                0x28c2a6c5028b47d5,
+            ])),
+            Felt::new(BigInteger256([
+                0xb57607598d2216aa,
+                0xd93bbbb766ad7032,
+                0xb77ac556815a07da,
+                0x2bf8333ffb0c8a2a,
+            ])),
+            Felt::new(BigInteger256([
+                0xc57c930511b0585f,
+                0x29db2f1dba2406f4,
+                0x1bbed9a15756105e,
+                0x09da3c6b8b5f7b9c,
+            ])),
+            Felt::new(BigInteger256([
+                0x6b33245924ab8d64,
+                0x75390e95c0a98fc4,
+                0x54d3c058ac0ccc24,
+                0x2e99b15c48073728,
+            ])),
+            Felt::new(BigInteger256([
+                0xddaea4edf4e246f9,
+                0x2868cc13040410e2,
+                0xf9af3246f0680a46,
+                0x1152e10a080bf492,
+            ])),
+            Felt::new(BigInteger256([
+                0xc08ecebbabca5ddb,
+                0x15f89a601bddde2e,
+                0xe13afea0e44606bb,
+                0x01bf0331f9197157,
+            ])),
+            Felt::new(BigInteger256([
+                0x183e2201c69d0b9a,
+                0x38d0821445a47156,
+                0xc700569703597034,
+                0x1deaf6715700766b,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x9feecf4fb76adf26,
+                0x45a76e4eb46c88c9,
+                0xd621ff9d89cb53f8,
+                0x2be4d6da0ddccf55,
+            ])),
+            Felt::new(BigInteger256([
+                0x97b1a6bc56eea0d1,
+                0x38cb150cba34ea91,
+                0x0fd4356bf9520588,
+                0x205509438c6dfa31,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xf4a431bb2e708f65,
+                0x413ceb1bae6d2256,
+                0xef0fcd640f29ee72,
+                0x16c5dc7b18fcdadb,
+            ])),
+            Felt::new(BigInteger256([
+                0x0bdc16e3a2d233f9,
+                0xf2569372a95f6f94,
+                0x6da8df1be3c11543,
+                0x1ecc88e4fd752451,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x9316ccc1f3292f8f,
+                0xc150f2d0aa910578,
+                0xa53c5ca2ca8569e9,
+                0x0233b0b3be9ce68c,
+            ])),
+            Felt::new(BigInteger256([
+                0xdcda28a505664729,
+                0xdef7a1dc65c916e7,
+                0xf6107fa8df099f9b,
+                0x2ba409bc9a384105,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x4e6ed67546625ce9,
+                0x467e1ce84359f093,
+                0x27d1510b1db0327d,
+                0x0783ffd68ed08c94,
+            ])),
+            Felt::new(BigInteger256([
+                0xbd06b7267d5b5ba2,
+                0x4e5d98102be2a749,
+                0x27ac3144e470fc26,
+                0x27137efddbd2b837,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x43faddc0282ea17f,
+                0xfac1ee608f927b49,
+                0x942d12d7c314e419,
+                0x03eb477899bb6df2,
+            ])),
+            Felt::new(BigInteger256([
+                0xed955718d220e8a3,
+                0x2692c5d54b34d723,
+                0x62fd6812b0a20a4d,
+                0x1d4bf046f5e6deae,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xdfa10b7ce5aa7f88,
+                0x27cde7e44e192045,
+                0xaf6174c9b6bc170d,
+                0x16e6663bd007613a,
+            ])),
+            Felt::new(BigInteger256([
+                0x95af3abd6f4161e3,
+                0xdb8b514b68b525ae,
+                0xb9b1285732eac8a1,
+                0x190d331ae5cce302,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x172b49a3cc8ab8fa,
+                0x683e86e8fae42e37,
+                0x43b042226226f382,
+                0x246dbdc8dda01493,
+            ])),
+            Felt::new(BigInteger256([
+                0x649c6299214f1733,
+                0x6ad08813c303cf62,
+                0x7aae120860e966c7,
+                0x03bc819b618fbcd4,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xcdea50829772c8db,
+                0x907997fc79bcc7ed,
+                0x18a0a04b861fb080,
+                0x2a849f07a3b3f010,
+            ])),
+            Felt::new(BigInteger256([
+                0xb8a3bf3d5e155e7b,
+                0x5b54fc516a456084,
+                0x670dc773c4852b60,
+                0x12f43bb535e4bebb,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xbfa7a82b63c62cec,
+                0xa651c2fd091703a4,
+                0x69a30349e89bc462,
+                0x2e8d21deca88425a,
+            ])),
+            Felt::new(BigInteger256([
+                0xe14aa82c6fa2e1ff,
+                0x88c3a74f991f7b51,
+                0x1dac28de43a535a1,
+                0x15bc025c1c41f7d9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xfb53da016e5ce871,
+                0x0fe2a952e0d536b4,
+                0xc6305affb55b7bf3,
+                0x3062205ad24b0de4,
+            ])),
+            Felt::new(BigInteger256([
+                0x4717c9fb726ae1f0,
+                0xefb3cbb00418aca3,
+                0xda2c3dbe11c05013,
+                0x09d218b8ec1c4409,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger256([
+                0x431acb782ce719d0,
+                0xbe48370b6bfca377,
+                0xf3762850731949b6,
+                0x0eb39e8f3d2e6f14,
+            ])),
+            Felt::new(BigInteger256([
+                0x8298225aaedbc82d,
+                0x8340e4b93a22b8b3,
+                0xe8c8e9ec0ed2901c,
+                0x24dda44d7789c15e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x87e0ebf1294fcb11,
+                0xf1188f1b8b0fabc9,
+                0xfa8729bac71c41e5,
+                0x08037b3aeda7fff4,
+            ])),
+            Felt::new(BigInteger256([
+                0x71d609589e5f3858,
+                0xa1b3199532d751ae,
+                0x2dc491f7c538bf3a,
+                0x1619c3cdc917d8a5,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x37bce0f2821bfbb4,
+                0x6174b029b067dcc0,
+                0xfc6bb17acd5a942e,
+                0x0a1a10aed4563d20,
+            ])),
+            Felt::new(BigInteger256([
+                0x85cf384e371cc826,
+                0x25ef6a4675098b43,
+                0x5a382779f222a172,
+                0x21c937aa0d5b8894,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x948eacc879a8702b,
+                0xef66a0de18c9b562,
+                0x98e2d66e3699f721,
+                0x04595ae3c0c8bde9,
+            ])),
+            Felt::new(BigInteger256([
+                0x946c8a0787fcb2db,
+                0xa51b687f7e8b2f16,
+                0xd59e0f40be0c0442,
+                0x2b26f9debbd1bc9b,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xab8b0e39acc7e4b1,
+            0xccd91555b8f1b2a2,
+            0xb58766ddf34b8c1d,
+            0x13519187fcd79dcc,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9ec56e05990705e1,
+            0x6baa56418a02b1d3,
+            0x5635d25f9dbc58fe,
+            0x18963473d0fd7b13,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc18316eab8dfa3e1,
+            0xfbcaa3dca045ce95,
+            0x8894354d8b47eddd,
+            0x01a49d5b530eed27,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xf17a5dd591200af8,
+            0x1ce0451810273385,
+            0x1c564b317d9b16ad,
+            0x215409f37faced2e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0ba5b599fd7e3aee,
+            0xdefc99ff3a167924,
+            0x9fed64b204321dc0,
+            0x22e5155ae12a264c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc88a5d320ac580be,
+            0x959dbbaef12f4d70,
+            0x9f968a25c9b429e4,
+            0x2f75a757ba3b5c96,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x741054eca934ad60,
+            0xc8576f93c9ce457c,
+            0x9e96bce1236784f3,
+            0x00de458265ba5837,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc46678021041e8d3,
+            0x55575e574828d31d,
+            0x1d81225eedac04e5,
+            0x254de462b3c0b527,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,230 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x05c29c54effffff1,
+        0xa4f563c0de22677d,
+        0x334bea4e696bd28a,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x6e76dadd4fffffeb,
+        0xb3bdf20e03c9c415,
+        0xe16a48076063c05b,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x05c29c54effffff1,
+        0xa4f563c0de22677d,
+        0x334bea4e696bd28a,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x6e76dadd4fffffeb,
+        0xb3bdf20e03c9c415,
+        0xe16a48076063c05b,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x05c29c54effffff1,
+        0xa4f563c0de22677d,
+        0x334bea4e696bd28a,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x6e76dadd4fffffeb,
+        0xb3bdf20e03c9c415,
+        0xe16a48076063c05b,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0x6e76dadd4fffffeb,
+        0xb3bdf20e03c9c415,
+        0xe16a48076063c05b,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x05c29c54effffff1,
+        0xa4f563c0de22677d,
+        0x334bea4e696bd28a,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x05c29c54effffff1,
+        0xa4f563c0de22677d,
+        0x334bea4e696bd28a,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x6e76dadd4fffffeb,
+        0xb3bdf20e03c9c415,
+        0xe16a48076063c05b,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x05c29c54effffff1,
+        0xa4f563c0de22677d,
+        0x334bea4e696bd28a,
+        0x2a1f6744ce179d8e,
+    ])),
+    Felt::new(BigInteger256([
+        0x6e76dadd4fffffeb,
+        0xb3bdf20e03c9c415,
+        0xe16a48076063c05b,
+        0x07c5909386eddc93,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+];
@@ -0,0 +1,2441 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41c389f3c7ac2ef9,
+                    0x1cd6e185361b4cd9,
+                    0xca50e24d8ad7f390,
+                    0x289a4260f59e8fb7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xac20d9ee5ffba5d7,
+                    0xe9ad7fc1e7e58e77,
+                    0x232e2c957b5388bd,
+                    0x125f08a01234342e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde84137423c458c8,
+                    0x9910a7e82f4215fd,
+                    0x2f5e69b9fdfe24ba,
+                    0x0c9fb7436263b7d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ea9eaabcef81e49,
+                    0x9ea5fe33e55a1ad9,
+                    0x4702d286768d2b17,
+                    0x03b3b57317ab1fda,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff7c78fa3bbff44f,
+                    0x9ceb4c23e46ec531,
+                    0x8f7033eff01b8485,
+                    0x1bf8517f694a68a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66d607bb5debefdc,
+                    0x95d535d8ffa979a9,
+                    0x4459b4534c898a99,
+                    0x2f63e6bd7de1be8c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa9d5cce634daae,
+                    0xb444fb427f7065c3,
+                    0xd194e5ee899d9118,
+                    0x300838fc34fdb868,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8eca72dd879717dc,
+                    0x43dc6cdfc5a3bb9e,
+                    0xffa8c5a04cf9328d,
+                    0x2c266b75e66abdd7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6904f86c4e5eb39f,
+                    0x2d4cc15b24407925,
+                    0x0d4ace2590ecf2e0,
+                    0x28fb9dd1fb740a95,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf4f68981649933af,
+                    0x1982785f82ecd109,
+                    0xec7945dd9f38a477,
+                    0x200e335f82c524ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd81fc0b392e5859d,
+                    0xd0c4894ba5373933,
+                    0x52b80d9a12a43264,
+                    0x1b5c4425fbd98390,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb52e8e586b2257f3,
+                    0xeb42507362b1ac3a,
+                    0x54e51815b2573463,
+                    0x02754a1466a3bf3c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4c011578897eea63,
+                    0x6f7e297c12746844,
+                    0xf1effd71272441f3,
+                    0x0ef517481d58f5f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7216c3c18bad43dd,
+                    0x3425ac96597b9994,
+                    0xac445ae2b722b789,
+                    0x054d16e6892fae17,
+                ])),
+                Felt::new(BigInteger256([
+                    0x52b97b2ff02d028c,
+                    0xb796e47f26da79c5,
+                    0x3d94f1d4ae0b57b6,
+                    0x0101df68f9718d04,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd4b15570f5bde952,
+                    0xfb50d32a146af610,
+                    0x49dec47311bd8559,
+                    0x16e9a21ab2ac8da4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0ff53a623c72207,
+                    0x5c6fbcffd84c1cfc,
+                    0xe3043b2dbbafda35,
+                    0x25d98188768895d6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab3a25f4fdf5f31e,
+                    0x726152b33abd5adb,
+                    0x80ff6cff9e9d10ad,
+                    0x05198b1177de2461,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b52252b479525cb,
+                    0xc7f63c6d3a96c398,
+                    0xe7f26b39e77e9b1c,
+                    0x2b0ffae7c033b45f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdff62dd3393aa5c8,
+                    0xb53d871fb41594f4,
+                    0x036a7ec41dc08b3e,
+                    0x1a577a059103ed74,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55eb75a27a5b1c5d,
+                    0x565b3066c884ce5b,
+                    0x8bd208284791c544,
+                    0x284caace68197157,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0325f91a20112a12,
+                    0x73f9b640e5515b62,
+                    0xd53befa29d7824f1,
+                    0x2e8db06e93847f36,
+                ])),
+                Felt::new(BigInteger256([
+                    0x93266d80e16ac666,
+                    0x004f198b45d9ae85,
+                    0x4bcbd35bec130a6b,
+                    0x1dd5f7d9f91fd487,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf59a072d4993a7e3,
+                    0x0c16c05df6396988,
+                    0x1013162883bcf88d,
+                    0x2ea03169074e03a3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x342d8cd995c1d483,
+                    0x63355e076aa7433f,
+                    0x234b006285193041,
+                    0x1f86a9e1ba6836f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf178e77890e3bd09,
+                    0x2e6d846e76e10226,
+                    0x2c107db7c7fd3d31,
+                    0x221fe38aed708aa3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0639a0253e8abb9a,
+                    0x4bdf377401ead1b9,
+                    0xaecd35cd6e849422,
+                    0x21954e84b980c6ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8ebd6296f30805d,
+                    0xc94e2add20abe739,
+                    0xe3a1ebebe9b7e97c,
+                    0x03bacfbf0ba9cb5e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x466dc612438a4adf,
+                    0xcf0e679e31ba997b,
+                    0xcb22dabafb1312d6,
+                    0x18e212b18fab29c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c60727a41471d77,
+                    0xd8d3220ecdbac931,
+                    0xd532c80ee9fc9e2e,
+                    0x269f86a81b3bf9f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea4b175a08b5b8be,
+                    0x27de6e3dfb691198,
+                    0x897c32e70de915e1,
+                    0x15fd94d35290ec53,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1aa7404033441756,
+                    0xe16e32b4ecf570ea,
+                    0xb801557f80225914,
+                    0x257396e21eef60fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7729fd139a4648c5,
+                    0x65d003ea1a7c361c,
+                    0xb866f76aad417bba,
+                    0x2a361681871fd977,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8a5da729b8b0e78,
+                    0x08d9553f979f6801,
+                    0x6b36317066924481,
+                    0x1962c70e8faca414,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe85f7ec23f296873,
+                    0x8700e3d84cdd449d,
+                    0xdc386f684bab85f0,
+                    0x06c37337805c5698,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8512ad3449b4a2c4,
+                    0x04c10e9426933405,
+                    0xbe43426a9327a547,
+                    0x23c5542fc088a0fa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa9df87096b4f56a8,
+                    0xeb1ea322359695f4,
+                    0xddd97c1842d3294a,
+                    0x123c0c34e44512dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47654b43b055a2a6,
+                    0xdb97f4d8cf96af9d,
+                    0x36b3e9af74a9010d,
+                    0x196699d4afeb5cd6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x829eb6ebffe8b0d4,
+                    0x022501d3a45ceb00,
+                    0xff880b1e165a5a8e,
+                    0x204f91ae2e986cd8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x029c121af0da01ab,
+                    0x04e5b7305b0fd146,
+                    0x04e1d782578f536f,
+                    0x2256511fd3c5fa6c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd0837d2aca899a3,
+                    0xeb13a020e882aefb,
+                    0x4170603b220075f1,
+                    0x1f82a59b9ca63f30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf543632b31370c8b,
+                    0x0b3a2babf0c5c66c,
+                    0x02bc038bd3c5acc3,
+                    0x17959d92c534cd37,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc3b2ac1dff5e6e12,
+                    0xaaa77657346eb127,
+                    0xf3d88ae3a8755a25,
+                    0x2db9505a9a3b5ec1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc5ef6b5bc66ed55,
+                    0x4ab52899b0486b25,
+                    0xbb179201ed12c688,
+                    0x20baa4990cf3e210,
+                ])),
+                Felt::new(BigInteger256([
+                    0x16b0a30f86ccf575,
+                    0x49ad4d7624d245f1,
+                    0x4954019fc1483919,
+                    0x1a009a1612e047ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdff6bd4ef0678ca2,
+                    0x8e5d60c9fe5c05f8,
+                    0x3780ae0512e6d8b7,
+                    0x2ce0fb8646fda958,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f7d775a33629995,
+                    0xa1bc3b776257cfa5,
+                    0x996ae6ed96e6f580,
+                    0x2ba19dcea175d764,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdff660130601e123,
+                    0xcda22d6412c4fcd2,
+                    0xebfd5a77609412cb,
+                    0x1312fa7af2daa95c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x88c232d541982600,
+                    0xead11567991da2c4,
+                    0xf508a7cd3a25fd8b,
+                    0x11f1e8f7d46f3748,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89c1b1619433722b,
+                    0x5c425e329f72f05e,
+                    0xbe26e04a565448c5,
+                    0x16ce67b89580c8a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc9439a80b689624f,
+                    0xa3ccd7b7770bb08b,
+                    0x2530e712ec0c4c81,
+                    0x0e014ec4cdcb52fd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0002d8bfd538ce02,
+                    0x2e18a371d3165881,
+                    0x27c906e8dcf027ca,
+                    0x082680e1cc496dcc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcf6020cea35ff702,
+                    0x6b5552414f1eed7e,
+                    0x998af42570eee2ac,
+                    0x06e1a1bc5f1d91ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0443a6decf954d8e,
+                    0xce4775e5ccb82a4a,
+                    0x273363efb144996f,
+                    0x19bdb8fd50235140,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6a749f98f62b21b5,
+                    0x0cd25ecf211f2315,
+                    0x9bca586ff048673f,
+                    0x10d2049ff259a0f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2105a8fa98cef9cb,
+                    0x193e405825a4d5c1,
+                    0xf7cf8f3ad4804b07,
+                    0x1d227414cbc8cc83,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9148a58f1e0f5473,
+                    0x3a03a4aee25f31b3,
+                    0xa390cb3a53827d91,
+                    0x22127e76827a07ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf645ec7ec3f4fc6e,
+                    0xd86d59a8b19e6f34,
+                    0x91e13eb18db55edb,
+                    0x17eff2bc4c48efae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb621213d34232f13,
+                    0x585a8263ba39901d,
+                    0x63b6031afb54caca,
+                    0x077e035712b5c431,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d876d9f5e13dc94,
+                    0xf97d900f14702728,
+                    0xa7d65e8d7dac06b0,
+                    0x1f9e904b4152d44e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x99be396e70b35c5e,
+                    0xf4251d9d138857ad,
+                    0xca77a31e0be08699,
+                    0x2bda7cdc19910c47,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd39da22cbf6251df,
+                    0x3f361cf684ae2193,
+                    0x1f03a34bfe8eaffc,
+                    0x0492d97e6668c428,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd00c596d85a9ecf0,
+                    0x3c949553573e641e,
+                    0x316c798810c6d640,
+                    0x136c0ec610c01b22,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61c1f1974b8290c0,
+                    0xa413a8b274977ab9,
+                    0x81064d1eb854bd0c,
+                    0x2f665db3ed77adf4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe0f0ea14c2194464,
+                    0xb71b835f3dd9baa9,
+                    0xd6e9faae93d32d3b,
+                    0x20e4871d9ab15db9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x012a5b36df6d7ad9,
+                    0x3b2282fa0a07ea17,
+                    0x2dd39947c08cf213,
+                    0x1763a95a5477105a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb601e55bbac10441,
+                    0x2455ce34dc86ba6e,
+                    0xe1a6886af2d84060,
+                    0x2141cd901c3fb586,
+                ])),
+                Felt::new(BigInteger256([
+                    0x05562da8e0bad2a3,
+                    0x07fe211a1957b1d7,
+                    0x6d4988afd9571c4f,
+                    0x240bc89e86941633,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25ff38ce92b162d9,
+                    0x97ba25e71ccb0c40,
+                    0xcc856d79e053d841,
+                    0x0e363d92d4db576e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x82f17419f7ed1721,
+                    0x8fe4d53e40ca5aaa,
+                    0x8746735259f1b254,
+                    0x263fde03f8f3b12b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6589604f8637e095,
+                    0x58c3a233c2ea51a6,
+                    0x15bbc117f8ec14ec,
+                    0x15e1a3c2a9d84bd8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc21815fc72aa57a2,
+                    0xa8a58a3fc3043f7c,
+                    0xf91d53912e801ade,
+                    0x26b29b538ce24226,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7cd7225e8baf83b8,
+                    0xe4e70dcf252e4c05,
+                    0xe7fc88e9aaea5595,
+                    0x20fdcb9863f4810d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7847a0cf99f8b19,
+                    0xe7289b9a1aabefce,
+                    0x269f7f076aab09bd,
+                    0x042e4859ccbf9755,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77b946df680d6270,
+                    0x01693b16fa5dba85,
+                    0x4028f22e7e01b290,
+                    0x11d0b310c6a3012b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x795f0932b109d5df,
+                    0x43a982eda58f2017,
+                    0x6053aa8a86a96e53,
+                    0x05cf6cd4c0196bca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0021fa2d484295f6,
+                    0xf980206af7abc388,
+                    0xf550fb03f98a20e5,
+                    0x21aef041f971c645,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4012aafe38c7abfa,
+                    0xfbb81e8e0a26bc58,
+                    0xdfb6a48f54a8933e,
+                    0x2fdc1b817b44b2de,
+                ])),
+                Felt::new(BigInteger256([
+                    0x037e006c275a47c6,
+                    0xf8d11581d102d813,
+                    0x92c3b2eb910c3a77,
+                    0x02486f233fcf5107,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa000d2bb2717e1a0,
+                    0x789398a7dab6bca3,
+                    0x6dcd36e337e753d5,
+                    0x2602062bb6a9cd3c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd58376cb0f588086,
+                    0xf95d085db1d76743,
+                    0x29e4181ce6e1f0d3,
+                    0x2424f6b5995867ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41592a45b0b1e2d9,
+                    0xd3bdff55e649748a,
+                    0x4b9adee47cb759a2,
+                    0x08e752e0bd586bfd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa7a441db3aaa5af7,
+                    0x6309f51444f3e276,
+                    0xdc5bfd9cf124bcd5,
+                    0x128fc9bba85ad939,
+                ])),
+                Felt::new(BigInteger256([
+                    0x44dd2a5ee757ef7a,
+                    0xa9466d60bdb2aabb,
+                    0x045a369c6912c4d8,
+                    0x2e07f67c19296e67,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x78e465e0d0f73c17,
+                    0xf07adfc8aafe3721,
+                    0x538237ed3454954e,
+                    0x0c6d0e26d4fc791e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x70f6d0ed4fea94d4,
+                    0xbb95a99f3b971979,
+                    0x5703c42a6c4a2966,
+                    0x1dcdd2915abe7c0a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf3d81855de58ae05,
+                    0xc4d6b4c27c6f8007,
+                    0xcd274549883a84fa,
+                    0x2c13ea3a3a72e69d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e0d014d16f4bc4c,
+                    0x9f5e874d4a342e10,
+                    0xfdacac347d9ce872,
+                    0x20a5d1cda7e58e3a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8adb083b77c2f3ea,
+                    0x068efd957eb66ce6,
+                    0xcedddb1e931e5976,
+                    0x041afc8d1214f58d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x364213e8bdba4e51,
+                    0x8a991f1285c3a891,
+                    0x60d2e59d08fab7f4,
+                    0x22273713d38f38e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x093869c79181de19,
+                    0x8f77fd31975289f9,
+                    0x99aee41974ed3b29,
+                    0x13796f43bc28a678,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24e59a760874d8f9,
+                    0xe9750b30acece478,
+                    0x4bc44b9f8a36e237,
+                    0x205cf16e36e9e4bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x655ec3ef48a4e8d3,
+                    0x92a4b7e467f01247,
+                    0x95226a1336d21872,
+                    0x17c6f3c60a936835,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc7e14ee249e8e14,
+                    0xc489640b74be4080,
+                    0x7a5b7f3e7977ef8a,
+                    0x0cce083b693f81ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0x53d6861153d7927f,
+                    0x24b712fdd68520ed,
+                    0xd7e0455fad071a13,
+                    0x27b2b139b7798870,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9eac44256a281386,
+                    0x2f2b7750b551ce26,
+                    0x25b41b5c270b678c,
+                    0x01cf87a78187c12f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x14a7715bad04782f,
+                    0x296c63857d634d2c,
+                    0x8105cf5b791b232f,
+                    0x10e66f1528535d74,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0c444328bf1a4f05,
+                    0xb78071dde5a0068e,
+                    0xdf5ae0933a6be9ec,
+                    0x15d96a83841f984d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x02484d6985140f89,
+                    0xbbe7437a8e3bf3de,
+                    0x86e9ccf02e44b5e3,
+                    0x23a24cc7385e8007,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2ab3045f771b54e8,
+                    0xdd4058a4c1ffe9f2,
+                    0x66db07e916670025,
+                    0x2f0ca26718ac7fd8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x611d7667ea2f6306,
+                    0x54536348f81b953c,
+                    0xe5eb6e0b859aef5c,
+                    0x1923d4849b352ddd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6aebc9e3a71c5922,
+                    0x0ee886cffcf6acea,
+                    0x9dec4a9fc99bda60,
+                    0x0f6827590e99d356,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36212a642c2e9e25,
+                    0x4744bbd4e8a9a69b,
+                    0x8572ba077cf78cb5,
+                    0x25f4ba2393b92644,
+                ])),
+                Felt::new(BigInteger256([
+                    0x530c633a3299e69a,
+                    0xd6e1519e6ee050a7,
+                    0x1d03fd91c4a3ecb2,
+                    0x2f16d174127f57df,
+                ])),
+                Felt::new(BigInteger256([
+                    0x220f645c3cf898ba,
+                    0x7ce43b95307f6940,
+                    0xafb9970315cbc79e,
+                    0x0f60c7aa3f4949b1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2ee2c00fe8d019a,
+                    0x704bf2f51b0815c3,
+                    0xad8fbebb109e73bb,
+                    0x284f7064a4f4efea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a76a0cb175829fe,
+                    0xeb368bc1b657ce12,
+                    0xa2bcf6d3a33a8937,
+                    0x021625ea618d93fd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2c1154dc86bab2b,
+                    0xb8c0bcf8faee16bb,
+                    0xc099a397822bcd9f,
+                    0x2b55fb0b9eb2011e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4e36b73082296562,
+                    0x97e40cbafe8a1f7d,
+                    0xe1fa6f46c475c860,
+                    0x24c20433329d9b5c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90883b0f2da1cf7c,
+                    0xe3ffc6b945deb1b3,
+                    0x19427a1a1741703d,
+                    0x2d822c087312e027,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe0eb2d97fff8e1aa,
+                    0x117b905822f5d67f,
+                    0x5d6b8bba3a1e62e5,
+                    0x1e58f87746761beb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x542e54946f2476b9,
+                    0x4616f9c18d9177f2,
+                    0x69ef9e48854cc0bb,
+                    0x227c6c30125b132c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xecbec7a454354a4b,
+                    0xb54a97536eabbd6a,
+                    0x37162f282a089d00,
+                    0x1b66e5b550c207ba,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf132655720fdf39,
+                    0xf8133088f9bbd6b3,
+                    0x0d96d570cb2c10b1,
+                    0x27f52c4e738ca7e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89027e6a4d2ac853,
+                    0xfda8555296130bab,
+                    0xadc2a45943d477cb,
+                    0x0baed127c57fc555,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd37046c840c7051b,
+                    0xfd2f1d0176465460,
+                    0xf87777b15f6a8672,
+                    0x13578fb7fa76bf88,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb3966effa151b174,
+                    0x87bb2ff6db9db117,
+                    0x63a0909f8b5b52f1,
+                    0x036a33d22f1041f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe1bc131b3ef6c537,
+                    0xba41ddc983cd2da2,
+                    0x8ab2761481f22124,
+                    0x15577cbcb5c4fdf9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x694b2f297f5e4169,
+                    0xe9746525a1582457,
+                    0x43f43eec7c808533,
+                    0x1d54139b97e70e62,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3d2ef29b189f6cbb,
+                    0xbd0a80af5f4d91fd,
+                    0x5d9bcaf136664a73,
+                    0x1a7d131db1825a36,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xee1bd8e52db860ee,
+                    0x8b90da87ce018f4c,
+                    0x1f9d63bc4f9087dc,
+                    0x1e5bc23c8466f188,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0840cea19e1cad7f,
+                    0xbbcb8df8c5a750e2,
+                    0x90c65faf14c863c1,
+                    0x227f97693f6d8f19,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1491d697d27e7087,
+                    0xa87fbe045c4130c7,
+                    0x2a02a65d6d9c2250,
+                    0x13841bc2a09ca938,
+                ])),
+                Felt::new(BigInteger256([
+                    0x23a66c328e715b22,
+                    0x90c92acbb4014fe0,
+                    0x2fc3c1276aabbf26,
+                    0x256030afeb8c3f66,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bbfdf912418306e,
+                    0x4135b279dd446374,
+                    0x9bdb7d7155835e1e,
+                    0x2aec5a5b75189b01,
+                ])),
+                Felt::new(BigInteger256([
+                    0x52df61108845cb8e,
+                    0x2d9a4ab9c0bce7d9,
+                    0xeb48ac1aab5663e2,
+                    0x232575ae331073d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae8429dca1748263,
+                    0x378c5b298119b966,
+                    0x6e5c4802e7aec419,
+                    0x277196e199d8b165,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc84d3bfdc97eb92c,
+                    0xddcb4f9a12a89324,
+                    0xe023b101721d6640,
+                    0x0914f70abc64e337,
+                ])),
+                Felt::new(BigInteger256([
+                    0x200b4c873f5a2f3b,
+                    0x54770be3e1d79eae,
+                    0xde7b3bf02060369a,
+                    0x08761e88fb595813,
+                ])),
+                Felt::new(BigInteger256([
+                    0x489bfda52b07751e,
+                    0x7ebad1f3977b926d,
+                    0x14d40d1250f4c7ed,
+                    0x15418f264cbce9f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x845c3855ae1a9872,
+                    0x3e265c0616f60066,
+                    0x51d0ea8fb1f191b1,
+                    0x01e1f8331f066b56,
+                ])),
+                Felt::new(BigInteger256([
+                    0x232ad032e9a7c58b,
+                    0xf0e080d13e5e8263,
+                    0xc8549547caeb9234,
+                    0x28c85b720949d274,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5a43683241cdeb3b,
+                    0x144fae20dc7f8e2a,
+                    0xc00c8f5e3c58385c,
+                    0x091284943d87aae5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe1b33a621c984c9d,
+                    0x23d6589b20a1006a,
+                    0xb2cc9df3a38e3c62,
+                    0x257cf74b2373b12b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x73b6f73bed84b3be,
+                    0xc4200c27ec97e5f1,
+                    0x0b6d74dfa1de86cf,
+                    0x1a1084bd26f81974,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7cbf452f7b4b7a83,
+                    0x3af96cc757545eab,
+                    0xdd8f992ca030d4c0,
+                    0x0ba239a74108e938,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3580c9920522e2bd,
+                    0x38cb3004f22832f1,
+                    0x7212823995b93f4b,
+                    0x0cf12326832011b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb6b91ce6fbbeb03d,
+                    0xdf94bee638798553,
+                    0xed88f9479c6316a0,
+                    0x03378ff932dfe004,
+                ])),
+                Felt::new(BigInteger256([
+                    0x53ae89706f50bfda,
+                    0xa05728f78b7bea9d,
+                    0x7e20d061ca385443,
+                    0x205d3821eb7f07a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc52ce5a78cef2510,
+                    0xbf1aa8d63c0b7ee0,
+                    0x4d4c7223bfafc86d,
+                    0x0513b753c701ad93,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0fe80feb9f1b651,
+                    0x8a86e47d41d7b867,
+                    0x52e8eb852a83e74c,
+                    0x2e5596a59a4e89a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x837b9d85402c528e,
+                    0xa41aa85eda9377be,
+                    0xe6bdacbd5ba8f152,
+                    0x041fc15e18c45a8c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcebf50592e8a1ccb,
+                    0xc9b954e297efd2c5,
+                    0x544be6f90fabb94e,
+                    0x267525025f5ccc09,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6096a7e483d0ae61,
+                    0x1dcf7d855098276e,
+                    0x1203a279d7e6c7f1,
+                    0x20399e80a32fdd98,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1aa278b324975de7,
+                    0x81ca31b70dc391bf,
+                    0x65f975b6a2568ac3,
+                    0x2984b8434acda955,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc623ffa4c2c72ccd,
+                    0xb6f85579af1f125e,
+                    0xffe2b97f4f558678,
+                    0x02e5c0a513985b6a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbdcd0a596db68f3c,
+                    0xedb3b3bfff56084e,
+                    0x37a85fede3091bea,
+                    0x0a71f90c7553d6b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47c05b3931cf51d8,
+                    0x941ef79d2af941c2,
+                    0x6e174373e59fa8ba,
+                    0x1ebb27f7ad9d7fcd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1da5567cc7824bbd,
+                    0x4813e7c74e794fcb,
+                    0xe1830b9b3b3f2390,
+                    0x046d8ad38561e1b1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2c44676af66a9ba,
+                    0x3578c2113e221c11,
+                    0xc3688671d84a4a79,
+                    0x097601102d04dfb2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9ebc11f93e12181f,
+                    0x74e3d2b2c397278b,
+                    0x47667b3b1d91e360,
+                    0x2b438649425307cf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x925d50eeb89225ce,
+                    0xb3095245660abf94,
+                    0xcf576026694041f9,
+                    0x1f3c12fdc9bd4d45,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc9f5d5308f5fc216,
+                    0x47d48f522ff9f9e4,
+                    0xda07c0884f7f5df0,
+                    0x219b52d6d3719814,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71b2b4f934be4f10,
+                    0x05b891221c0c2c29,
+                    0x32fbe7207d965a49,
+                    0x18b1f572a9a71d76,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec48cf227869637e,
+                    0x9e849069bfdea703,
+                    0x689eb583f6c9390d,
+                    0x05a182201d92c699,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacfadff9c72069c6,
+                    0x14d7ae7e6624942b,
+                    0x8e3afe77afe8abed,
+                    0x2e220b1063839377,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe8e672dd9c7ebd18,
+                    0x404448c50e8d51bc,
+                    0x2c89862372e85729,
+                    0x2d29396052b9c61b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4dbb77f5375d9ae1,
+                    0xf24e6cec6e3a9ea1,
+                    0xd451400f76d5fe8a,
+                    0x23ccdcaae3f5de2b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4adf65997edbf038,
+                    0x9a2d69e8ed1aa987,
+                    0x75e68d80a51e0560,
+                    0x1bae1aa8f20894eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41377580cfd7a949,
+                    0xa406b49f6c75842d,
+                    0xfe68f412b49b4200,
+                    0x0c1736a6ea13f947,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b29e0f0ad2f8334,
+                    0xa158b5a2169f308a,
+                    0x0d752874d4bea699,
+                    0x02269553d626c0dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd11bb03d22819438,
+                    0x3b39d1cc18509ade,
+                    0x62bec24c8fb2166a,
+                    0x0cba38be609f7b5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc1b6487b45c4fde,
+                    0x19b0d7e9824e7d27,
+                    0xa2d375da5a162020,
+                    0x2effb554e6d7218f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0a9ccaf67da2258,
+                    0x14924f0a9b9a158c,
+                    0x8d93b0c17ea409ba,
+                    0x228197d226f34b98,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5efb1aed2ce20190,
+                    0x1248bcf5155c9c23,
+                    0x49f11e8d8fa85151,
+                    0x13a6b1e14a5eccbc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc842e35002426126,
+                    0x35d3b6be729a608a,
+                    0xae1d701c7488f7cd,
+                    0x2b6fa4f3f937a2cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a483604a52e6067,
+                    0xe822c6ae78ef0914,
+                    0x5cd4192bf2b740ca,
+                    0x154b412e8694466f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd21de9578b455876,
+                    0x61128d2503d7a4bf,
+                    0x772eb4c1ed10ec52,
+                    0x0818ef6b63e1307f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8511bb3291db0fd5,
+                    0x19ed995edf9334a6,
+                    0x61f1ceb8e2914024,
+                    0x08cdd03fe354eea2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc28ad1433e64dd4,
+                    0x5066ca60578191e6,
+                    0xd90e214e9cfe2519,
+                    0x10b2d6ff1772645c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe4073c5846545c12,
+                    0x26a5cb01429a6194,
+                    0x5279ca852ad5dc35,
+                    0x227841ea733213f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd730e28682fc3a64,
+                    0x6a261891f42aea20,
+                    0x032237d533487538,
+                    0x0f3d4f456b138dd2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x631327e1404f4e5b,
+                    0xd846fa073831e2bc,
+                    0x02cc5ed1def0b042,
+                    0x1a186a456161b02e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d67f31d836c87d2,
+                    0xf40c006016b3f7b8,
+                    0xd8069a5b3432f22e,
+                    0x24254e80094fd143,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81eff210db44966c,
+                    0x7358e75bea0a2e83,
+                    0xb8d058a12fe8d27f,
+                    0x237dc709658d2fc4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe76a5e60116d6723,
+                    0x29d58b4644d1d2f6,
+                    0xbc5211cead348b18,
+                    0x24f09b6c9ebbc7a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x49d31014bb9482a8,
+                    0x4b7036411e5ea20f,
+                    0x0e845de9682ef79c,
+                    0x11a6f447d9cf7436,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b9b42c778973a07,
+                    0x67a176ed391f28dc,
+                    0x782150518bdc922b,
+                    0x043ce182c8ea2b52,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ab8ce9f279b7fbf,
+                    0x35637a4da4925675,
+                    0xcbb07f7e1ce5a78a,
+                    0x166086389674be2e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc1aad71f1822c5e0,
+                    0x7668537d990cce05,
+                    0x059ede2fb9280b81,
+                    0x1886808b68265ee0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2f21eccc9892bbc9,
+                    0x698c4fb4d8c88893,
+                    0xf20723a54e78354a,
+                    0x2b95bfd042c5db20,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa73447f10ad6a134,
+                    0xc9384e343c6cdefa,
+                    0x5ac0537b24ea8ace,
+                    0x24d6d841649d4eb5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x681124b8187d70bc,
+                    0xbfe3a93889c67301,
+                    0xdfa38be5286612a9,
+                    0x260390f3bb527681,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa87b6495c7fa8a79,
+                    0x88053b403220ac39,
+                    0x8f1bf8236af65b3a,
+                    0x124cfa555b4a06b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc632ee689bc8c8b1,
+                    0x81b09734aa76265b,
+                    0x7d177906e77bffad,
+                    0x2009461acbfc05e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x68444698ece8fd83,
+                    0xbe6642465e322eef,
+                    0x23b3e2e3b8d41619,
+                    0x0a74735be30d77f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8bd91fd7d00862e,
+                    0x340939b4d186bc9e,
+                    0x4e36084f62c87db7,
+                    0x2e1f513e195ba04d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x560f18e762b491c4,
+                    0x6e6b453351f898c4,
+                    0xd9436f6b491d346b,
+                    0x05816b1a75cd7303,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfa7b99fe677baf91,
+                    0xcf3971991c6b2bdd,
+                    0xa8d774d55ca0b4c9,
+                    0x1f056883f014a3d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5bb04b5561d0c257,
+                    0x6a0b4a1dd07b5aa3,
+                    0x500d52422b5eec2a,
+                    0x0f6135209f2eb5e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5ffe66a17c4c9ff,
+                    0x4ba327eb726ad593,
+                    0x136550ff15004904,
+                    0x1523a4e228c6a21c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd03631fa372a3a4c,
+                    0xf64cd4e96069ea24,
+                    0x70a48f9bf76405a1,
+                    0x0a9bcb3d10849de3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x40c0cd9bff5903fa,
+                    0xf900a8fc0710234e,
+                    0x5bce7d8462070f45,
+                    0x22e08e6590fd91ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd4e98fdbbdd5de8,
+                    0x6a062ff3e57996fc,
+                    0xa0c3cf0721db2f58,
+                    0x1f07853b58f64d18,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47ec0b6b3ab36529,
+                    0x2f05012a4a1911b1,
+                    0xfaffea412536554a,
+                    0x2caf9b35de463cb9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2d8357e2da774ac,
+                    0x0cc1787eb61ddf56,
+                    0x09dcffa66b7593bb,
+                    0x074ff0213c0f3819,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c734cbf4e98d690,
+                    0x69676a8e35b17c3b,
+                    0x4ee9730bc97da18f,
+                    0x08b7dc227057b212,
+                ])),
+                Felt::new(BigInteger256([
+                    0x194890766d450c93,
+                    0xa7fb2e80c73a8060,
+                    0x9343962e7e4ab8de,
+                    0x2d29743283d7e18d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a95bce7e219943d,
+                    0x8737b1633b186108,
+                    0x9fb3aa4e8e79db68,
+                    0x0373bbd562688107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5aaa95e2e53a4a5f,
+                    0xaff6dd25d4b9813a,
+                    0x9a876761c3a96f95,
+                    0x24c4a4b9d98d5fd5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd48250039b5e5671,
+                    0x4aa0fb755806dc18,
+                    0x3b22b0463aed3b4f,
+                    0x008107509587f7e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b6d217e16db9caf,
+                    0xe7ab380f7dfa521e,
+                    0x98ec3fe1b6755af4,
+                    0x1d207d60c8bb912b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x38f750ca8a1c82ee,
+                    0x1b852898ac380452,
+                    0x223e45230cdd39b4,
+                    0x0ceca19d9eeb592f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6027903311c95481,
+                    0x4e299b4e2136c1b3,
+                    0xfc26e2c9e1a6adee,
+                    0x0892a56b0cb58942,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4ad9f90f818f8cba,
+                    0x8d11bcf598f2ab80,
+                    0x9e59d647b6a1e7ed,
+                    0x12ac2bf2b640cadd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7eb9ea035e290b6d,
+                    0xd456a666353536e7,
+                    0x28c96b50ad7e8317,
+                    0x072445f170a126d4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe4b68a82ac555ea7,
+                    0x849c46fa0fb95503,
+                    0x8e304a869a957c03,
+                    0x10a472a45894bf67,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa56599a2fdc36bfd,
+                    0x1f4fb542d2935d80,
+                    0x8f638ceed675a60a,
+                    0x1fc5a6db19de8235,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1a25eef135d857c5,
+                    0x25f891796378ea66,
+                    0xebe118de466a8159,
+                    0x292c635153b08762,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf28e1ca5076eed9a,
+                    0x09bd65d57f5511d2,
+                    0x87d13317f2ecc1f8,
+                    0x18a0e328437db269,
+                ])),
+                Felt::new(BigInteger256([
+                    0x514a628d422da924,
+                    0x1659ae21b98ac088,
+                    0x47c0feb0432ea86e,
+                    0x098b73d39ed49dd5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b32e04c3e7c10ff,
+                    0x7f15dd79429119c5,
+                    0xd55f5a1e63127db7,
+                    0x12d970a3dd2e9313,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb01197025719b787,
+                    0x10ec41a8e7d82d27,
+                    0xea6c85c26ed72478,
+                    0x0883c6f0f72eedd6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec39bf3f4ec93e0d,
+                    0x4e53d6adc58039cb,
+                    0x8f130264d2b442a4,
+                    0x054977797dd8e480,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6d7336ccad82cbb2,
+                    0xcc841e6ee58ef93b,
+                    0xec87324ac9bc395c,
+                    0x0dc0acf87a2afd39,
+                ])),
+                Felt::new(BigInteger256([
+                    0x193022659791986b,
+                    0x145ab78e8f3a5dc8,
+                    0x3002fd4378981c4d,
+                    0x2fcb1e22b400382c,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x285246528fffff97,
+                    0x82b5ba4612f0d46b,
+                    0x67136824e1f2c1ca,
+                    0x26dbd2e1a2a54ee3,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaa998f9d49b2f4d0,
+                    0x328e0ac70355c89e,
+                    0x27a360f1e3b8bebe,
+                    0x239f3830b7e60df2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd05bfd81ac826489,
+                    0x55d2418b24ba9169,
+                    0x7a3e4702cbaa1cde,
+                    0x208740343fa98d81,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc471ac85b474d7e,
+                    0x117ef2b293774d45,
+                    0x0338e340da3923b2,
+                    0x1aee0c3d0b972c97,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa272e2bf5a0e4dc2,
+                    0xb79e5ca1f82e1709,
+                    0x2d37f60808f20c72,
+                    0x106c59dbc00bb64f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc136a5aaaf387995,
+                    0xe29d8b581cd60ab3,
+                    0x7706658d7e453da0,
+                    0x2fe75f2a1529fa4c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc3eeaa4c9482a99,
+                    0x5f097e88a3b1fadf,
+                    0xdda0e2b44cd83f87,
+                    0x27b76001a6519e7f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0963d4c9efe10497,
+                    0xa104639f4f7af4c5,
+                    0xfc47fd949a0cc624,
+                    0x169aafe1206e4635,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc9d13e6ba3a33e1a,
+                    0xec81cb1b2bfdf79d,
+                    0x64c4e0dce8dd9317,
+                    0x225c1076fa692bc6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc11691a3d1ea99d8,
+                    0xb1a6bb2392a29f30,
+                    0x786518eed08bcd09,
+                    0x161bb79e8fb60e45,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c530d9da55d519b,
+                    0x85385017ec6d53c6,
+                    0xdab58f25115b660c,
+                    0x29ff1d82fbd3cdce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89e6fafad697dbe2,
+                    0x2c0cc2e2dfb3b3d3,
+                    0xa397ed96f653cb74,
+                    0x27432ba267ca7cdb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x165d748a2c8e2c7e,
+                    0xf1b7056112fd6b50,
+                    0xe5cd3d06cc7d0120,
+                    0x2ebdd5a968d3072d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4349b64d20c5d713,
+                    0x2a14cb2a1e94ee2d,
+                    0x9e12f4cc28632327,
+                    0x0921a909e06e2f99,
+                ])),
+                Felt::new(BigInteger256([
+                    0x30e84203c1fee763,
+                    0x3dda6e4a84909285,
+                    0xf967c8847f20c418,
+                    0x076ea287d58ece4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc632bf98eaf3c6b0,
+                    0x441684e5a1daa508,
+                    0x14ce245a8dbc73e6,
+                    0x056de0382950eff5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde7e5ea71482852f,
+                    0x967ba3b08109085b,
+                    0xe3cb0e74a464d577,
+                    0x2dd6ec139fdf3734,
+                ])),
+                Felt::new(BigInteger256([
+                    0x44bceed87bfd62fc,
+                    0xaf9d4fe37e8a3e2c,
+                    0x57e9403bfeadefee,
+                    0x1496a998297d85df,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c94fb7ceedc4471,
+                    0x9de977e877ed12c3,
+                    0x22700dbdf48b45c7,
+                    0x0a9fded8964e3213,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2630d093e2c1626c,
+                    0x05f438c158ddd781,
+                    0xd6488aade28d7f9d,
+                    0x0b6b7cfd4567545e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x007a857d00af431d,
+                    0x9e422abc7e96572f,
+                    0xc719da7438d17367,
+                    0x14b04441446bf041,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2bd07b1adf40bf74,
+                    0xc24682584f808ad4,
+                    0x8fe1ab38dd62ca97,
+                    0x23254d2b1aa36592,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd5ee1afaae9807e,
+                    0x7c50454c5e1d140f,
+                    0x1d1ea1acde889d0f,
+                    0x26310b2a166ab85b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x748b515ed51a3db8,
+                    0x9d219c6c0c53a57b,
+                    0xda4a3a1ee69ca64a,
+                    0x017646346049ba70,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97ecf32fb96df03c,
+                    0xa1b63e87549bfe12,
+                    0xe71a89b31844afa0,
+                    0x21bb16fddf334041,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9f2d9425aac71217,
+                    0xbe4e105235c8f990,
+                    0x71a8a850d0892125,
+                    0x04e9357f72513cdc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd471a6a880ad988e,
+                    0x34c91f0a388fcac5,
+                    0x0169bd0425cb9add,
+                    0x2fafa637ca658f55,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd4e6ccf289dc2e8,
+                    0x0c3e4e01d748c0eb,
+                    0xfba72bfcda7c8755,
+                    0x1da7b1a31b73f96c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8c7f9cdce770d10,
+                    0x96590491ec66b484,
+                    0xc71892d9bd84c40f,
+                    0x27c5e27ab3623873,
+                ])),
+                Felt::new(BigInteger256([
+                    0x708cd1283a7567a6,
+                    0x5839383383dfa027,
+                    0x518d00772ca540b6,
+                    0x2019371f2b5c943a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a8660a26b14eb78,
+                    0x545a515dd5b2565a,
+                    0xb4acd3bf459862d8,
+                    0x1e2af35874b1818c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc14b981117efaba,
+                    0xf3d00a4c591600f4,
+                    0x9fd61039dabf8c3f,
+                    0x08786993cb3b6de7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc3da88db7c70ae4e,
+                    0x862c4f1923d499ed,
+                    0x626d86e41c13e91c,
+                    0x058444fa2c86704e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18650a08c24b78aa,
+                    0xa9338bec2459b861,
+                    0x51315a8dd66f5f59,
+                    0x00756961b56c82e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x779029e614b38ed9,
+                    0x528747892fcd96cd,
+                    0x43e438905ea5d713,
+                    0x1bb06473972f129b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c739da3de9abcea,
+                    0x99dfa3e0f6454c2d,
+                    0x89be44baee1062e7,
+                    0x09c304447ae702cf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa5cdf303d8a68f96,
+                    0xadc9074c6dd18afb,
+                    0xdade4a53207e54ec,
+                    0x0e3df4210d00f030,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5a3bed16452db592,
+                    0xff55f56c4348e533,
+                    0x3cabf0b563c5745a,
+                    0x06a93672c8cf5390,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5aeeb479ce642e59,
+                    0x9efc347fb72437fa,
+                    0x4f50f1610e8a901c,
+                    0x275fd15db4828982,
+                ])),
+                Felt::new(BigInteger256([
+                    0x68a0460d735bbd6c,
+                    0x83004b43ac1f7b6b,
+                    0xf3679784ff6793e5,
+                    0x041bffcd40ce6c5e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa5f5a57e25eb0536,
+                    0x4de68ac594abd73d,
+                    0xe6ef06ecce371ba8,
+                    0x273a3cecc7020f60,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2550ab576816f0d5,
+                    0xaebfcfa0ada86c81,
+                    0xf6f85c8759173d33,
+                    0x27c65f4d47d540d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xee4aa88a568ec84f,
+                    0x52f8f19158121cef,
+                    0xbadf9adb894bd84f,
+                    0x13a1daa842fbdc0d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a47fc9af08bf8dc,
+                    0x2003a4cab37cc39f,
+                    0x953065ca92756c97,
+                    0x0d14d5b205555697,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6a03233b2e81c28b,
+                    0x4e740496fb786ef9,
+                    0xd1c790e13d1f7eb8,
+                    0x1cc35e7d83b789ea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f8885ebfd834a23,
+                    0x0257b0a3a717ef0e,
+                    0xa6c3ea00bd77647f,
+                    0x1ef6494461b22794,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc04555bf3111d9bb,
+                    0xb64888e4b8c25eb4,
+                    0x856c79839f038f22,
+                    0x03fe9790dd54c213,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc470a92f71e7bbaf,
+                    0x48e9c4728ca562a5,
+                    0x30230d13f2d883c7,
+                    0x0dee7333529f7f6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x51179b19cf407fbd,
+                    0xdedf6740e52dd851,
+                    0x5cd929e91a13a57e,
+                    0x24d0a11be2c35328,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xdc72b3fbea88e044,
+                    0x67c8ef18202b05e6,
+                    0x27b7cf62f6013e6d,
+                    0x0b774cb310a7578c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x07ca8e7d8859d205,
+                    0xc89e2821fc3fb719,
+                    0x10b66595e279f20d,
+                    0x23499dccf8cfec5c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a4480102d26d717,
+                    0x8ea2fc948dae5ae0,
+                    0x2a83a4f78985f540,
+                    0x26a92a85d72237dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09de3d71acfab54d,
+                    0x4b835549ed919f12,
+                    0x7339e3feab6ab45d,
+                    0x24cd55951da30260,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfb3096b290cc1c7b,
+                    0x21e43181243b80bb,
+                    0xaf625038a38c9a76,
+                    0x119f5a581d9a1c6d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x57a08c77d6de0d9c,
+                    0xe9831b4430550066,
+                    0x4d5466e91f6e3c13,
+                    0x0537168c7c6cf132,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc611f5a9d325a284,
+                    0x30fcb2e4794db163,
+                    0xf31f40c80e82cb3a,
+                    0x2e5c12ef4e2da3d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9532217ad1927152,
+                    0x67f6665f2f451a89,
+                    0xdee2228e7265771a,
+                    0x01d261984adb1cb0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc2b1821cd13e890,
+                    0x7120a53251619b8d,
+                    0xfc51a09a4e85e40c,
+                    0x1a7bd9db7264180e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0377113bd91919ea,
+                    0x88ee2c54298c1340,
+                    0x1aa2cd0b8b7017f0,
+                    0x1176fd23b0c1c24a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x508623fc30004297,
+                    0x21d583de65b8b759,
+                    0x17dc7a93f9c27bca,
+                    0x08c88421ceb8953d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcad3f2116223de52,
+                    0x4b4e468cd3912bfe,
+                    0x49e36fe3df73e308,
+                    0x2056ec5c205de1f1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x54aab25fc599ac7a,
+                    0xaa8c4c97fa048028,
+                    0x8e1c04f70ec9bdc5,
+                    0x182849d6225d2a5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9347cb02a9b1fd73,
+                    0x3bdaab269ba74679,
+                    0x3eb70c21dc7cbc64,
+                    0x230da169309c8608,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf254104fa6218c83,
+                    0xb68c9cec783cd766,
+                    0x4a737406a6138542,
+                    0x1386dbe3a4f97f6b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd58dcd6a0f4307c,
+                    0xbeb6fbf32c262804,
+                    0xa2fe87f1489e1a01,
+                    0x12574bdd2f5229b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x210c40920ad97a6a,
+                    0x399227f7f2c22fe5,
+                    0x52aa109612d994de,
+                    0x251894d8c906feaf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x399a0a443cb241bb,
+                    0xec6b29ef2d8b31c3,
+                    0x420dcc74629c9d83,
+                    0x04e2e2c0784ec4f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c5ddeeb3ae6a25c,
+                    0x574e853668beacce,
+                    0xfb346cf952fd0cbc,
+                    0x07562bef6a03ede2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03fb062a4e7e6dfc,
+                    0x3edba719fc3fa284,
+                    0xa39ffc89176dfdf1,
+                    0x13eb3140f33b3109,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67a11bf17ebe19ae,
+                    0x78da57a7a6bdca4f,
+                    0xc9dd246410d1577a,
+                    0x073105dd83fe12dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x91789a42d1c5ad72,
+                    0xaa080584ed0279f9,
+                    0x569a2867ac922a8f,
+                    0x2e4e5ee7c19cdac4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26c3bb05dc994f10,
+                    0x755f63538c14e5c8,
+                    0x5e8cfe80f0c2bb52,
+                    0x0eefda99b3628ba6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8ea389f321365e0,
+                    0x38994e8ad302d543,
+                    0x14e5b0fff5caa3d4,
+                    0x2b79b943ea3ece4d,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
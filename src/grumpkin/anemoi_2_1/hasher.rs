@@ -0,0 +1,390 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::new(BigInteger256([
+            0xd998779c3cb36f24,
+            0x4156401796ce4943,
+            0xa775cbbd3a4d9f72,
+            0x05fe537791e49f22,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0x5f3ebd8ac184aabf,
+                0xb833c7c5475b44dc,
+                0xd38f0972401d7893,
+                0x071e18f4372e9aed,
+            ])),
+            Felt::new(BigInteger256([
+                0xfbc5e720b18b1588,
+                0x60119e443d25f72a,
+                0xdbfa120d5931f669,
+                0x2c7596cd175b98c3,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe92df7b574c29539,
+                0x061c28b888df0d8b,
+                0xb873eb9924c7369a,
+                0x1032cbb2ca5adb19,
+            ])),
+            Felt::new(BigInteger256([
+                0xb2c8b4b59d944e12,
+                0xa16271b341dfd335,
+                0x953750202f4554e3,
+                0x09dfef7d6fc876cd,
+            ])),
+            Felt::new(BigInteger256([
+                0xf4581d36c7335481,
+                0xbb49fc8f4e57b82d,
+                0x9074b733c5f3ee28,
+                0x0837a4c5cc062c6b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc5743c552b919c7f,
+                0xacebf94feb40e3fa,
+                0x7e31953f219a159a,
+                0x18981e62e5b3856c,
+            ])),
+            Felt::new(BigInteger256([
+                0x9af1e1b0fc6dcc3c,
+                0x93635396b7c83c2a,
+                0xe303dcc8680022e2,
+                0x0782389c2d134908,
+            ])),
+            Felt::new(BigInteger256([
+                0x159c49c66766420d,
+                0xa4bb32f3aea5af67,
+                0xb4503647b0af0c5a,
+                0x1afaa81b5ca942a2,
+            ])),
+            Felt::new(BigInteger256([
+                0x4aecfadf2c09b127,
+                0x5e3fe7f2e4c51e8d,
+                0x760e06e8f1a4f4d1,
+                0x2f5e216d84d765ad,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf4294f8eea69f856,
+                0xd5165cfe28fa96d3,
+                0xd4dcb833774757ca,
+                0x166482ba9acc82e2,
+            ])),
+            Felt::new(BigInteger256([
+                0x952d3d4e3774cd6f,
+                0xddc28bc7447fe795,
+                0x163c61aa9adbcb86,
+                0x1df319eaa50d2368,
+            ])),
+            Felt::new(BigInteger256([
+                0xbe566af1cd924a45,
+                0x9e8997a4a641e4ea,
+                0x3f2cf1346befe90f,
+                0x03d684a42a36b433,
+            ])),
+            Felt::new(BigInteger256([
+                0xee7059d0e89cb518,
+                0x9e2b2e765f401205,
+                0x7e2ceb32d1502e61,
+                0x28b06f91ea832156,
+            ])),
+            Felt::new(BigInteger256([
+                0x63aec5d4c68e00ba,
+                0x3f2393bd342712ae,
+                0x3dabd88ef7245c30,
+                0x13cf69bc59ec098f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x21e315ee87a34cd2,
+                0x771fbfd7d323085a,
+                0xce1b7313b70980a4,
+                0x2d2a676c71537a1c,
+            ])),
+            Felt::new(BigInteger256([
+                0x5706c5af0e61bfb7,
+                0x3da1293d319f4352,
+                0x1d899007a9152fe2,
+                0x18938b431b0892ba,
+            ])),
+            Felt::new(BigInteger256([
+                0x532bed39ac1292c8,
+                0x02aab26b596f6f9a,
+                0x1461a5156eb37980,
+                0x1bea119fac072d58,
+            ])),
+            Felt::new(BigInteger256([
+                0x1d3fdca8aafe7be2,
+                0x0648274dc30f3abe,
+                0x699c75577b25a618,
+                0x10a785bdfb5ce4dd,
+            ])),
+            Felt::new(BigInteger256([
+                0x339204d5d1087341,
+                0xae2bde7169f71555,
+                0x8484c5cd28406677,
+                0x2d1fa41f0aff6534,
+            ])),
+            Felt::new(BigInteger256([
+                0x22a97cf3c515c294,
+                0xb86c48b7e8e36ea2,
+                0x2039cade1d913601,
+                0x15a5efc126441718,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x51f23f7944ae1340,
+            0xc8a064cadafa996c,
+            0xd77e6422dd416cb9,
+            0x1a7aa62baccea463,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x3abc294283cac963,
+            0x2bbe2e0111572c29,
+            0xee734f54b4b38378,
+            0x1dc210d28ae90d9d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x09aa8631838ed7c9,
+            0x989355a95f9d5273,
+            0x490094239a091d75,
+            0x0bbcc02133a4e04e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa814968e9dddd031,
+            0xb1569d232228ed35,
+            0xd410120b049725ab,
+            0x1f92ae059daf0993,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8cd5ebbaa89c6e1c,
+            0xd958a33044635e95,
+            0xa5498dd09a130758,
+            0x28008c61112f0335,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd4a359cbe7c24378,
+            0x98e4c4dae6917786,
+            0x7a591362776f2627,
+            0x1492f81fd460670f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd6a4eca91fc6867b,
+            0xc29c9bf01eb8d276,
+            0xc2ca0f649cafdc66,
+            0x194277a83a26e9aa,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa5804c1d0483bed5,
+            0xd07fefd52aa507ab,
+            0xd106d8c78a591f1c,
+            0x258631d54fd52575,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0ab0dd326443ab13,
+            0xa1c0b09c7760a01a,
+            0x7ffe3bff87136c8a,
+            0x11bb6cdf0c740d38,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x44a207b565d1d6f5,
+            0xc53c8ab859e7323e,
+            0x38f29ac31d35bec4,
+            0x2894823f7691534f,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0xb9485c868dd3bb52,
+                0xecebf24602ae228e,
+                0x62250abef8d977f5,
+                0x0b2de3d2d7fe700b,
+            ])),
+            Felt::new(BigInteger256([
+                0xf6b9cac1fa70059b,
+                0x27d5dfe2098b13e7,
+                0xcc001a172c3c95a9,
+                0x188e9d20340b97ef,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb97e946efb5f995e,
+                0x2b7268ade987f46b,
+                0x4713e94a51d0d9e0,
+                0x01b17392cd336146,
+            ])),
+            Felt::new(BigInteger256([
+                0xd81e9c94abebdeb3,
+                0x93a53007802624c6,
+                0xd54bfbc258fe1f36,
+                0x1aa87ce1d8e2cbd5,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x0a100ad6578fba1d,
+                0x0f942e61c573a255,
+                0x1f22c70ee8585a17,
+                0x074d9cb94977ed85,
+            ])),
+            Felt::new(BigInteger256([
+                0x13796848645653bf,
+                0x33e19c7d9bf76ca0,
+                0x40c3b2a7c053db9b,
+                0x232388bd66edb700,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x127de6cf7390e5ed,
+                0x86c24d4b70c5fc33,
+                0xb283860294fc62dc,
+                0x1ad184a5ce8a5272,
+            ])),
+            Felt::new(BigInteger256([
+                0x0831bcaf69cfa9d5,
+                0xba1cc1212cb6b9c1,
+                0xaba3894c412610a7,
+                0x09a63c290503d324,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x308ba38822ae8ec4,
+                0xf4d21506acb323c7,
+                0x2dc9c737a6b4c9d9,
+                0x0abda803da5abe35,
+            ])),
+            Felt::new(BigInteger256([
+                0xd2317165f681d12a,
+                0xf9373e85f2fd0e89,
+                0xa20c13792ce21f1e,
+                0x1efa9faf08b3dc4c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x58318a94490388b0,
+                0xd3db91ed4a94bfd1,
+                0xdaa46fbe098e4e22,
+                0x062a39c8a0768a4e,
+            ])),
+            Felt::new(BigInteger256([
+                0x3883d81985efeef7,
+                0x8f7e2f9b41f9c133,
+                0x8a30fcff5a1d5e63,
+                0x2a2f324ff81928cd,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xa56421e0881008cd,
+            0x8c952f4680268cb6,
+            0xce6948c98b5f995e,
+            0x237226104fe5ba55,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa31a4e762e93b712,
+            0xb74ab16aca16b0b9,
+            0xe51973be3c480145,
+            0x28e44e9ef2cadf77,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe1d1cf2915315033,
+            0xe8ba0b46da0da3d2,
+            0x9a4e20c48016f6a8,
+            0x0e58865ec0feb09f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x1e761a7e7e389b8c,
+            0x2bcbb5c55661f5de,
+            0x92cef6d6a57ad1d6,
+            0x0e448d9488764be0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x34bee77fb03fd4c9,
+            0xee0135db370eb9be,
+            0xaa8c8e7de0adcdd4,
+            0x2a808212c19f5f40,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xdf3d113892e65212,
+            0xc1500238f288e562,
+            0x7a788692954f2ad4,
+            0x2d8480e61c12e4a6,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x92fe9b012c427b7c,
+            0x09884125ec1a42b1,
+            0x8af7e2a3f8a4d123,
+            0x128bd5a431149369,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x898ed307a74596c2,
+            0x5f9d3199a2e48dbc,
+            0x5197ed0bcf481e6f,
+            0x2f38cabac5217475,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa55f13bb5e16d51f,
+            0x5d512bacb7463934,
+            0xc7a7eefaaaa50f8f,
+            0x259b02582e4f3d1f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4595b5224a673aea,
+            0xb74725e11be16480,
+            0x76493497baf527a1,
+            0x02fbf55866c0a22d,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+}
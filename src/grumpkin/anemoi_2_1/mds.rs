@@ -0,0 +1,34 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [ 1  5]
+/// [ 5 26]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; (NUM_COLUMNS + 1) * (NUM_COLUMNS + 1)] = [
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xac1393d48fffff77,
+        0x7c38b136dbc2c242,
+        0x5d0ab154b1c7b625,
+        0x1a5204857c7149ac,
+    ])),
+];
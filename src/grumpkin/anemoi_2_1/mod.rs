@@ -0,0 +1,599 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 64 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x96e6bdf069987ef7,
+                    0x589ddfe7f63dd4e8,
+                    0xf00fa870f59f0547,
+                    0x2fb16a76efcdcf33,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc3cd2e1d342349b2,
+                    0xf5b449fdf80e197b,
+                    0x7a0a2a9b14cbcb28,
+                    0x2895a096f09e53ee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x172f9a4db2807d4d,
+                    0xf436708fe8224589,
+                    0x3886f5e42158df55,
+                    0x2fc836bb96ec51af,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc2dd2e0d50f1736,
+                    0xd5025f6c1cae0037,
+                    0x0ad6ecf46c13fd55,
+                    0x0de9e330cd6c0116,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd5f94e422c88d252,
+                    0x5e4b1077392b8129,
+                    0xc5feae6ba1801246,
+                    0x2adfc2561cb49d57,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf049578590769968,
+                    0x54fe443efc1cb319,
+                    0x95704255dac7e3b0,
+                    0x1e23be116eae0372,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x331960402342a1ae,
+                    0x16a816235a7be503,
+                    0xf2d914e6c98430d9,
+                    0x28d9d44e22687811,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf52e7013e4afd30,
+                    0x18d440b96b6fda6a,
+                    0x8508515940b39be3,
+                    0x260118ff3de6cd8d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc379eb2a77339e61,
+                    0xf1f51d135d7260a2,
+                    0x1fc278cfa66e7804,
+                    0x22b6c84d73fb955a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2a1f7948fb522e30,
+                    0x27f276b62d55c3ef,
+                    0x573dc3a261452368,
+                    0x212214f3cb056e44,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x001eb9ab29f6069a,
+                    0x0511c411b287f218,
+                    0x7280370fd3c170eb,
+                    0x052407141009b8e1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2e1b8e9aaed73197,
+                    0x21bc8c9436b68b23,
+                    0xd7a6b566bf99d947,
+                    0x0e6afe7006bb725e,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x800cefa4f04b00c5,
+                    0xef06f139a9893a5f,
+                    0x9d1c0c737dee239f,
+                    0x13f6a0c98640250d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b32c1dd3f46351f,
+                    0xa109c50d6827ef40,
+                    0xa40144e74170f643,
+                    0x154dea6bd4ab0f1c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfe815235680172f8,
+                    0xf293af3203ddf4bc,
+                    0xdb5ce58a9a2e06d2,
+                    0x1b368a459c5ffe6a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x859746d9e8e72795,
+                    0x361329c9fb929742,
+                    0x97009e929bf1e5a7,
+                    0x2d5a7eea9b34ea86,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf42d47adf91e72c9,
+                    0xcbea96051dd34cd5,
+                    0xc2c19a52f704f1b0,
+                    0x2f2c4f0ae57c8e82,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc4f53ac7536907be,
+                    0x9baaff2ff7fb6bf7,
+                    0x2b5bcf8d13857985,
+                    0x1674a9aaa505b71c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa3fd00d785f6aa77,
+                    0xccaaac3d2683f407,
+                    0xfdc37f111b47f7c8,
+                    0x1bf03f00ab7111b3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa31bb7be490e7849,
+                    0x4735f2130046fa4b,
+                    0x4cea2db281bfd12c,
+                    0x18e8f15b25dd0a1d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe6a001676535c0ff,
+                    0x877e1d67631319a4,
+                    0xaffaed785a01893b,
+                    0x23448df2e3dd8583,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c7770cfbf0c3374,
+                    0x8ac9368af4398c23,
+                    0x77092c53f6589adb,
+                    0x097caef615c61219,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcda25d31d2cfb33a,
+                    0x7db7a41d94768cb5,
+                    0xa87a3a3a03dd6799,
+                    0x2076e99a9c02300b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0a843770b3168b0,
+                    0xb284abc09aa57fb8,
+                    0xe3635681ea2ec350,
+                    0x17df38bfd6cae97b,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xb559adfb110605b2,
+                    0xb3756ac9829933f3,
+                    0x9aaea3fb2aae1086,
+                    0x1ea8b616d66ee5ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c677988c58bf13d,
+                    0x80f60138fb4c7442,
+                    0x9c0496d6ebef4d65,
+                    0x1415e1f3835ff5c8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x00068b2ab9070ae3,
+                    0x23f6e112ea3f4f16,
+                    0xfac0cfd1f42ee41f,
+                    0x08f16e9e579b2325,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66ce9118f94bdfd5,
+                    0x0b0ccb113bb12ea3,
+                    0x8fe3e63db8e8041f,
+                    0x1e2dca3ce3d19451,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0ac90d04f0ba637a,
+                    0xf157d05d892cad12,
+                    0x779f764f9c21d375,
+                    0x04514e9825ba0b5c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7b07de18bca29a8,
+                    0x4d0b9d1a7ecca0d3,
+                    0x4daa66cc9d2e4d4e,
+                    0x05a34fd2f51907ed,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x19be5b25f34a15aa,
+                    0x865775e7b1390bd8,
+                    0xe83f755e20fb086e,
+                    0x0a72fd5803eba115,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2bf1f34dce915b4d,
+                    0x44472998f2230aee,
+                    0x5af936c3de0a45e1,
+                    0x06867ef9a17d41ee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x671f017c2b7717a4,
+                    0x6de25e0456145d1c,
+                    0xffafd2b51fcb9c15,
+                    0x09bac8578cae2d53,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14ebffcf96372c88,
+                    0x6d41393e10878bf0,
+                    0xbd5935495e7f59ff,
+                    0x011699e0a4bdf4b8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2bccb3cbbb141b92,
+                    0x2ef3d27102ea5b5c,
+                    0xb64cbbe76c436a3e,
+                    0x1798986104f088c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a05cb1deb1a4ef3,
+                    0x2711791c2e36f4d8,
+                    0x3979aeddcddb8b2a,
+                    0x014cb70c206a5723,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xc7a34315efffffe1,
+                    0x21b6df39428b5e68,
+                    0xae478ee651564cb8,
+                    0x23da8016bafd9af2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc720a2ce2fffff5d,
+                    0x66f319da7eed5381,
+                    0xa4e39ccb8aa4bcaf,
+                    0x30220cda9d67056f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1b0d0ef99fffffe6,
+                    0xeaba68a3a32a913f,
+                    0x47d8eb76d8dd0689,
+                    0x15d0085520f5bbc3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xac1393d48fffff77,
+                    0x7c38b136dbc2c242,
+                    0x5d0ab154b1c7b625,
+                    0x1a5204857c7149ac,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger256([
+                    0x1b0d0ef99fffffe6,
+                    0xeaba68a3a32a913f,
+                    0x47d8eb76d8dd0689,
+                    0x15d0085520f5bbc3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4b9b227f0cc1bbe1,
+                    0xe7dba05577a4981d,
+                    0x36250ac0c357e2c7,
+                    0x224d82f2a4eb7266,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ac9454835549c9f,
+                    0x8fa46a0ae4571b21,
+                    0x81ccfb773822b235,
+                    0x2e6c85581864514a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x36697febc7826a09,
+                    0xe29b1f8fa788e693,
+                    0xa1437de30c32efa2,
+                    0x0ea97676271e2840,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef1a258bfed7f200,
+                    0x27ac984e8deace60,
+                    0x4594d03ff2e40193,
+                    0x06b47da5e5051d41,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa13b826cabad33c2,
+                    0x7291e1e2032bd134,
+                    0xfbf3784eae0955fd,
+                    0x2081ddb6ef3732fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x12342945162c2c6f,
+                    0x11494dab217b6527,
+                    0x107aef327ed8f22a,
+                    0x16ffb90cfd98266b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf5781baafc20de2b,
+                    0xdbbb45e46be8427e,
+                    0xaf1d8731772e65d4,
+                    0x2b1378382b5deabd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe7c2a754fb35b220,
+                    0xee1fe5ed26c69523,
+                    0xe54bc3e12bece194,
+                    0x1c569e46f58c56fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcfbb008a1a8af64c,
+                    0x90287c3aa8ba18cc,
+                    0xb26ddd23f8485e12,
+                    0x0f2bc9bac463f4ef,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdfb10cee2aedfc03,
+                    0x15d7be1ae270975e,
+                    0x812e4146b667d7ff,
+                    0x1c8d3c1399801d3d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4de9ab615297a651,
+                    0xf24b2ffde9fd2395,
+                    0xd5ad263c718d2210,
+                    0x1e182b9da7043c74,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf3f04348b8108e85,
+                    0x69edb03852fc550e,
+                    0x3cea9ce881192c67,
+                    0x0698a5c7bfeaa4ee,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
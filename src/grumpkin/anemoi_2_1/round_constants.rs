@@ -0,0 +1,239 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0x4ae1f0502fffff3d,
+        0x607610cb47bf4159,
+        0x9adae5fb5a79b10a,
+        0x23983e7e77330038,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x46e64390cf95e53d,
+        0x66c265075130032e,
+        0x640d055b0de012e9,
+        0x1e84cbd4f92a5849,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xb28b2f88b415dcf6,
+        0x16b883458431d6c1,
+        0xa281629c250acb7c,
+        0x15b5a97027f4cbfd,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x01fc2956d3c502ac,
+        0xeb7de97c455bada2,
+        0x6fbecd2423a868c7,
+        0x218088c6cf87e883,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x68dd16cd9d5d63cf,
+        0xb5b96a366e5ce11f,
+        0xfb91d5b04ea051f8,
+        0x25d0c05e4022929b,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x0e4a649da30b9da7,
+        0xe9d76bb94ab7f816,
+        0x0dae0d09cc751457,
+        0x2e68ba932023bbc3,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xf84f986fa4fe0f3e,
+        0x80b592172cc2d242,
+        0xf4d60aaa77768179,
+        0x233943019b7c9d91,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xa809283ab264a9f0,
+        0x9b39847cf29ce716,
+        0x4047ccb11771158e,
+        0x3003bb3ace34402f,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xe095938246da6406,
+        0x7532ccdf944b692a,
+        0x1362cc3636a3dc89,
+        0x05b2fcd0ade1726d,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x42d9dc9c9e8c34fe,
+        0x880c31a88144e38c,
+        0xdedc13523102e6c6,
+        0x2fc6593f2977b1a6,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xeecd85964d5978a8,
+        0xa4c8bb98b0b2dd0a,
+        0xd08798ceff0f9375,
+        0x13f28ca16d590d94,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x6b7497fff51b7783,
+        0xed00b74764413a54,
+        0x4de160cb93c5f8bc,
+        0x1b609bbf57fe261c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xfb3d8f9ecd580e84,
+        0xabb33b9b53a731ef,
+        0xb3b2293f1d588023,
+        0x1f922af62b171cb1,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x9ec4f6ca598d2227,
+        0x80a9b74c17922ca8,
+        0x888d33631fd4c56f,
+        0x25318ebeaea99c8d,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x1a64496cdfa3959f,
+        0x8ed1b62121c46439,
+        0x1f22124255dcef7f,
+        0x2b1115c7e02d4267,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x248b3c832a02b01a,
+        0x07236ead72d5ff5e,
+        0x11265cafe11219b2,
+        0x1e8d28f4fb159efd,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x479a43876efa69e7,
+        0xa83124d8abf711ad,
+        0x8e1d48c3cc11130d,
+        0x0c44dc1242d6d242,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xd6e3cf57b6b2da3c,
+        0x6e76610229051e80,
+        0xce48182d330f2b85,
+        0x222431b4c102d978,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc973e05f067b65b0,
+        0xe6bed1600c62b33c,
+        0xcde0b9edd9e0a9ba,
+        0x0f70544cfbaa9e8c,
+    ]))],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0xde453430499998d5,
+        0xecefd05751c9c070,
+        0xa727433dbe778a0e,
+        0x095e27604216ccc2,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xe6b77586057b6b5f,
+        0xf8329f693a3ae618,
+        0xc7e6db7fdbe912e3,
+        0x23f1832ed7582470,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xaa7cd8d3a6268a4e,
+        0xee06cd26d758e605,
+        0x269558a263d4ffeb,
+        0x187021937d18cd3c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xcb62331502459998,
+        0x4e8690f1a49bf84e,
+        0xd9b3f15f4677fa46,
+        0x26afd1c352bbebc1,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbf7e7780f6d2dcb0,
+        0x66d5966d2cfa5749,
+        0xc4b7b8135f33a52c,
+        0x205be33f1bbd0861,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xda36b9789b70da0f,
+        0x770d64ec2a5b2a91,
+        0x46b2be29a26a9aa5,
+        0x2ee35d800e368ada,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x03eb861f3faad73e,
+        0x6b2efee54e7d0bc6,
+        0x5d75f246987e60fc,
+        0x077be59942c0487f,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x1530cec79e3bf68e,
+        0x89ee5ccc5e8e828b,
+        0x3522047745d10cf1,
+        0x1a9b8f7fa942ee7c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xf11b7fed38d2a964,
+        0x752f8e095b36feae,
+        0x5ed47d40f96caf3e,
+        0x25f523b396610b1b,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x9a85f0bba47b6f2f,
+        0x7b2abea04d783395,
+        0x503c702359510cc8,
+        0x24bb7ab5d5b1eff9,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x9f18c9fa07517881,
+        0x80f9623ff5d2430f,
+        0x3fa2cfc81746ba67,
+        0x174129f880ea622c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x6d0734fcb38bf08b,
+        0x6aa9413cbb99fad4,
+        0x3c04daf5ea9e6d0f,
+        0x2f358e770d920e37,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x53f9283f2daa1ff1,
+        0x6de1f51e0f33f4ea,
+        0x83a8db5ca148d9a7,
+        0x1137d182f35a6c57,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x14f3a9755ab655bf,
+        0x69b35d592ff38df8,
+        0x3339c3aed9355888,
+        0x29a5e6a17eb46b9c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x8c91924f0c3b62e2,
+        0xadae671b4ef82d69,
+        0x765d04b6ad485e31,
+        0x2471b928d8032f30,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xd4e849afe510b1d6,
+        0x7240538cb71dcb59,
+        0x1bc08e41a79c2349,
+        0x2fbbc288d6a93f5f,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xec54b9935dfc197d,
+        0xc008f2fbc0d648db,
+        0x60bd42bb927bb8b9,
+        0x2468cd2939ed844a,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc080e92686a8001d,
+        0xa836e163e6bffb7a,
+        0xbaffbef7af3f3ef9,
+        0x2369edb25200dc10,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x3dc46df0e81fdcc0,
+        0x1c35700f311a37ad,
+        0xa10c6ffdf02fd2c6,
+        0x2383343ed863c047,
+    ]))],
+];
@@ -0,0 +1,772 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![Felt::new(BigInteger256([
+            0x6f77e89917608de3,
+            0x2586b99f337682c7,
+            0x01d59d5082bce685,
+            0x2430253a67f8346f,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0x15746aa7d55f4fea,
+                0xcad90d468e56f786,
+                0xf6508f0ec93d5256,
+                0x03ac9def0a64e888,
+            ])),
+            Felt::new(BigInteger256([
+                0x7bba10de7777e101,
+                0xa99ea810fe43eb51,
+                0x04d2f310c1f415fd,
+                0x178a6fd87ae3bd3c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xca9200c9c000623f,
+                0xa3c93978ed4833b9,
+                0xb6dfdcc3adb5b141,
+                0x0969cd3367458cc9,
+            ])),
+            Felt::new(BigInteger256([
+                0x6eda3d2b61544a07,
+                0xcb1b082843c0ad29,
+                0xef1f46c24281d491,
+                0x26f213443bff132b,
+            ])),
+            Felt::new(BigInteger256([
+                0x3242b5497193449e,
+                0x8da8db7732362485,
+                0x64ff9080627ca88a,
+                0x28638294f1ce3593,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x0e2ea756ca065242,
+                0x6f5f26f3b3f487f6,
+                0x6cb51b11395f2d86,
+                0x1a0841b11cf14233,
+            ])),
+            Felt::new(BigInteger256([
+                0x4b5b0e0cdb858fa2,
+                0x3b19c0d7b9bd4ee3,
+                0x7adabba53c41771d,
+                0x1f6eaff61ef2d3b5,
+            ])),
+            Felt::new(BigInteger256([
+                0xd8067c9e62ff6479,
+                0x9f37e6b88a80b626,
+                0x462f22c6bde8cfa5,
+                0x0b4d746b40117ff2,
+            ])),
+            Felt::new(BigInteger256([
+                0x9b5bf0e4d9e3ec8d,
+                0x20b55eb81d965014,
+                0x669e86396f22713e,
+                0x1d1277b45809ea41,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x397b728d9618bdb7,
+                0x9728d69eaceced0c,
+                0x45b8fc47ab8c13a9,
+                0x059ed80b80ffa920,
+            ])),
+            Felt::new(BigInteger256([
+                0x9b5dff37cb323a1a,
+                0x1d7d5430c4dd824c,
+                0xf9a90b1ac24d5cb5,
+                0x15a76ed220b5a42b,
+            ])),
+            Felt::new(BigInteger256([
+                0x2a7152dbff2d5c5d,
+                0x622a4965654b8d91,
+                0x5dcd20c8cff320ec,
+                0x29fe6e978feb822a,
+            ])),
+            Felt::new(BigInteger256([
+                0xd30c5fb304e61d2a,
+                0xf0f5e5db620d7693,
+                0x7f4532ca7b244fc8,
+                0x1e82453d0d7732bc,
+            ])),
+            Felt::new(BigInteger256([
+                0x7dec33f04cd85626,
+                0x963547ceb8e1de8e,
+                0xef12fcec6294dae0,
+                0x2ac2e3dbd8b310ca,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x70bfae673bd714e3,
+                0x1a7470c5a0875481,
+                0x07ad1035b79cea32,
+                0x2f3c1f471317b57b,
+            ])),
+            Felt::new(BigInteger256([
+                0x2e65661030005416,
+                0x2721b4260a4ecd86,
+                0x6360636a76358d43,
+                0x0431fed49498b7af,
+            ])),
+            Felt::new(BigInteger256([
+                0x5161b023ce306cac,
+                0x48533aae576038b3,
+                0xb68bfed6dc53458f,
+                0x1ab6b20f33015b0d,
+            ])),
+            Felt::new(BigInteger256([
+                0xa2797ae7c8a1a675,
+                0x52ad25314c1b6930,
+                0x801d08332881725f,
+                0x1e475cc81addf8dc,
+            ])),
+            Felt::new(BigInteger256([
+                0x9a3c0eca880aec56,
+                0x87dc82058c11b21b,
+                0xa59a4d4c7fcb0cc1,
+                0x3011bc06eb55dc95,
+            ])),
+            Felt::new(BigInteger256([
+                0x2daad434989fd2fb,
+                0x3e6198ec836ef98b,
+                0x65a644bacd776674,
+                0x1696809d5b9ef201,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x332e5951c079000d,
+            0x5a7e59079452f0a4,
+            0x9373eeda2a669c93,
+            0x1d6811aaf7b0e374,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7c98e9626d1ce86d,
+            0x168eb15086e0e1cb,
+            0x3360f701bf9a918f,
+            0x13a82327cc3a6c81,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x6264593ee03674e6,
+            0x05860022172913c9,
+            0x8038446cd01128d1,
+            0x011cfd7e03255845,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x884150adc4fef839,
+            0xe35755edbfb34c39,
+            0x055ea7f7a72e4ea3,
+            0x0b303a352d675add,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7e875fd4110af8fb,
+            0xe90bcee2ed38faa2,
+            0x15257d403bb96338,
+            0x291f0118b8ca8642,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x063d2f74b3e2ece2,
+            0xbb3a9c2f10474b7e,
+            0x35372ed2eaff82cf,
+            0x12b73ec7e8111fa8,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x205aa6758a4b568a,
+            0xe7459f0ff4e3b9de,
+            0xe7b1ccbd59eda39a,
+            0x1fa83e5d2d494ba0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x73ddc0d0924f5033,
+            0x260ade5930012b36,
+            0x6849f583444a8dac,
+            0x1ddc2c963e7ae9ea,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0ea3c107967ce034,
+            0x7deb8d34f1f5863d,
+            0x8ec4b3ab3a505e80,
+            0x0a2aa963a42592f9,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9b6cd93c8888e110,
+            0x1f37ba2c23702284,
+            0x0a8cf1e8949cca0a,
+            0x0712a9d04a017e4a,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0xf60f85ba1d8c6c8b,
+                0x6ac2f52b8634c088,
+                0x19c5d1ec22dd0fb9,
+                0x28b840500b80232d,
+            ])),
+            Felt::new(BigInteger256([
+                0xd73c01552d000059,
+                0x8cc51530c60f3230,
+                0x079046f34000e59d,
+                0x21a5e6aed001e021,
+            ])),
+            Felt::new(BigInteger256([
+                0x99a89c8be0603d04,
+                0xa5aee40c3731ff9f,
+                0x0d0778dde90f4e26,
+                0x21f08dc1067869d1,
+            ])),
+            Felt::new(BigInteger256([
+                0x835d3a8a519f7a1a,
+                0xc7bc14bf48bf1cdf,
+                0x6cadec3d30e6b03e,
+                0x2cb5f56e4c8c1619,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xfa60a5bae3266b25,
+                0x5780709907744779,
+                0xd58cb009e50f0887,
+                0x1de92c2ab4700b5c,
+            ])),
+            Felt::new(BigInteger256([
+                0x6bfe22f206c05456,
+                0xef5cd5d5daf5bc37,
+                0xf718c53c97082f4a,
+                0x09e0b0a1b76edfe5,
+            ])),
+            Felt::new(BigInteger256([
+                0x77ae717db715c34c,
+                0x647d534c1e170b9b,
+                0xe54cbd9aa5f4e971,
+                0x2c5cfa05fd63aa1b,
+            ])),
+            Felt::new(BigInteger256([
+                0xf89644ceb7a0b1f9,
+                0xedfa33155a513424,
+                0x09d48ffac176b9f2,
+                0x3055a5f68b2e85f9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc3eb18f0681e1dba,
+                0xffe5eea363078315,
+                0xe4395a57fa53e2d8,
+                0x091ab052584f9b86,
+            ])),
+            Felt::new(BigInteger256([
+                0x0ad6e30ea17974e1,
+                0xe50e4341f75b117c,
+                0x37a967c48d8852c2,
+                0x04c2695323f2b8fa,
+            ])),
+            Felt::new(BigInteger256([
+                0x10841c7680e45c87,
+                0x0ba8a21de698352f,
+                0xb839d9a64bf5e094,
+                0x06da06cf854b0296,
+            ])),
+            Felt::new(BigInteger256([
+                0x8256db0080fff81c,
+                0x26c8622a2bc88f15,
+                0xeb300f41bba1a6ef,
+                0x29396f7e1db4e8b4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe2366082d11995c0,
+                0x1383915db55c9ab2,
+                0x23302bd71d4664d4,
+                0x0d693ebff8874858,
+            ])),
+            Felt::new(BigInteger256([
+                0x81c643add392cf16,
+                0x4ae20b6b993118f3,
+                0xfd2e36c2b4af00ff,
+                0x206aa1e997ce95a5,
+            ])),
+            Felt::new(BigInteger256([
+                0xba27da02b70bbb0c,
+                0x0f6eeaead0ec7dca,
+                0x3329ba9ed6d09399,
+                0x0c4b8bdcea250bb5,
+            ])),
+            Felt::new(BigInteger256([
+                0x652511c03eeb0e8e,
+                0x8cf134387f0535d2,
+                0xe74302a71ff9ad4e,
+                0x1f70f2b070ad12f9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xefb34e0ff5ba566a,
+                0x2b58122a7caba214,
+                0x465194d42be22b01,
+                0x1c0bfc0775d9e3d9,
+            ])),
+            Felt::new(BigInteger256([
+                0x58da6556d18ce9e1,
+                0x50fdcc2da4505010,
+                0x116a9fbe3fdcb8ef,
+                0x2f0d4755566ac96b,
+            ])),
+            Felt::new(BigInteger256([
+                0x035beaa86049c082,
+                0x672a3a7916c9eb7f,
+                0xa23c157b5870d36b,
+                0x1e803eeb21bed54a,
+            ])),
+            Felt::new(BigInteger256([
+                0x685b47db4dea1ff6,
+                0x071073b0ebb4e6a9,
+                0x9fae9e7983a855a6,
+                0x19e9632ba2b8038f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xfb4676a557cb6fd3,
+                0xbe53a8d4059078bd,
+                0xf9d4fc32f936028e,
+                0x0790e96ef96ad6e4,
+            ])),
+            Felt::new(BigInteger256([
+                0xca8a95dfc910927b,
+                0x5c65888a36ef32df,
+                0x5aff099bdcc55645,
+                0x281ef4bc6cfd15a1,
+            ])),
+            Felt::new(BigInteger256([
+                0xf90d6dc2cbf4d179,
+                0x79074f01edea8f22,
+                0x3dc2b80f195a2878,
+                0x1e75af52abf671d8,
+            ])),
+            Felt::new(BigInteger256([
+                0x80007e728baecf0e,
+                0x6ebd3445a58e05eb,
+                0x78c393b40f91f947,
+                0x28f9142cbe1cccfe,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0xe8e683f7885416d4,
+                0x5228037de84338dd,
+                0x8022a006a1def3b3,
+                0x04c65d7f524ba8bf,
+            ])),
+            Felt::new(BigInteger256([
+                0x0fa050aa7da8b27d,
+                0x5555f0d0eea9f8fe,
+                0x6918f9d944ca93d9,
+                0x272e215f359cc777,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x3e2b353c63965b29,
+                0xd4a0340d8d42a653,
+                0x245cca3b8fcb0a90,
+                0x11811532b34fbd79,
+            ])),
+            Felt::new(BigInteger256([
+                0xf11af0581a6807bd,
+                0x01103152de8daf09,
+                0xd8a1ee2aaf58e0d3,
+                0x205461e33fdf8947,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5c0d4e2b753fd45e,
+                0xb7f700858ddabae2,
+                0xdd2140815b81322f,
+                0x0e3b21831af05cb0,
+            ])),
+            Felt::new(BigInteger256([
+                0x041f3fa8e41efa43,
+                0xb4dd5f2ec37b55f7,
+                0x6647b61be3c094ed,
+                0x03df7617433eb187,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x0cf8d57bb4548ee6,
+                0x2f4c9bcb4f98c5b3,
+                0x3fb418ee0f40d74a,
+                0x1b3e857a09b3cf78,
+            ])),
+            Felt::new(BigInteger256([
+                0x7e0cbcff05431cca,
+                0xd60484fed89351ed,
+                0xeb088f977711508b,
+                0x1bb5bae1d640d16f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x8fefa5fa9a458d2e,
+                0xbae84b4afa5ffac8,
+                0x11845bbc539adb8b,
+                0x2671ad29e0fc800a,
+            ])),
+            Felt::new(BigInteger256([
+                0x98df69bc1762883a,
+                0x0fc08147f106b4cf,
+                0x1e9166f20adfadbc,
+                0x292d4e4578472df7,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xf576df81f952e76c,
+                0xb3bf49f1a9ef2942,
+                0xfcaa4279dca304b7,
+                0x14e553cf695edc01,
+            ])),
+            Felt::new(BigInteger256([
+                0xa441abf52d5c2b7d,
+                0xb809511e033c6e92,
+                0x66ff48667078cd0c,
+                0x0d5b6762fe49f1f8,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x380726e2c7ee5ee7,
+                0x009b9ae9b39c84eb,
+                0xb78cdcc99f9d1077,
+                0x18364b74eb4afb7f,
+            ])),
+            Felt::new(BigInteger256([
+                0xa5cf4b19810cc0ab,
+                0x41aaf7f660e370de,
+                0x7b65a9e1bebe2387,
+                0x0ee165d9d9e40641,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x372f28a2558e18e4,
+                0xd7a08d5f73469f26,
+                0xfff152e7151f88ae,
+                0x1e57d24e1e4f06b6,
+            ])),
+            Felt::new(BigInteger256([
+                0x0fd15382c238ad37,
+                0x266cb427bf7f84f7,
+                0xe34547ff21fed584,
+                0x296f45f27ef8aefd,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x407a588f8a7d98c1,
+                0x7db91713a3c117e9,
+                0x82ef9565578f02f0,
+                0x2740c908ade5deb9,
+            ])),
+            Felt::new(BigInteger256([
+                0x5aea70a28095bcf4,
+                0x8c7190e1e8977597,
+                0xab4c16b49d25d367,
+                0x2bd7dfc615aa4d83,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x6a978c7f7702b3ab,
+                0x3eee773d5bf0cc4d,
+                0xb4eb489bb242217c,
+                0x163445b710664f1e,
+            ])),
+            Felt::new(BigInteger256([
+                0x0df81e807eb731c4,
+                0x6b0b6cea87eccc05,
+                0x82ebb0955e1e6c37,
+                0x16e7f6465d58e3c6,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0x0e776e20b47e304c,
+                0x403d58f2dc21199f,
+                0x0b73ad15a87bc7e4,
+                0x1898c8bb4c9267d7,
+            ])),
+            Felt::new(BigInteger256([
+                0x8647842d527008d8,
+                0x47259dd2353f19d2,
+                0xa945d3e0550ffe0a,
+                0x0390389af3e36226,
+            ])),
+            Felt::new(BigInteger256([
+                0x411981f2b1042d58,
+                0x52475aa188a434c3,
+                0xd36c3669e6ea3748,
+                0x1d75818aa1293d7c,
+            ])),
+            Felt::new(BigInteger256([
+                0xb40fe4ae75ffebd2,
+                0xfd78ece4416548d7,
+                0xef1b8509846c7ca1,
+                0x0f6ed55b55c92d58,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xa5aa7054156e7352,
+                0xb42d1e6eb6901842,
+                0x80cd9f257974f835,
+                0x206cc84ebd1f27e3,
+            ])),
+            Felt::new(BigInteger256([
+                0x099e9ec4ccea9beb,
+                0xe43ccea9e2f52463,
+                0x186778df59883de2,
+                0x11c37250b557cee8,
+            ])),
+            Felt::new(BigInteger256([
+                0x32381533ca4f584c,
+                0xf3accf772248cd6c,
+                0xbd51725021d0b1bc,
+                0x1ad7436ef125c467,
+            ])),
+            Felt::new(BigInteger256([
+                0xbcbc381a95368055,
+                0x80c45663fdc2deeb,
+                0x73464ed88272675e,
+                0x105296b3046f3a60,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd6cc52c2eff8b5a6,
+                0x90d58f688b97740a,
+                0x5cdd407d0cd1e739,
+                0x08182351638e699f,
+            ])),
+            Felt::new(BigInteger256([
+                0x506f99d799f6b560,
+                0x2f4a782934b70a5c,
+                0xca1792b9bee46cc7,
+                0x21b6aa1d764cd6d7,
+            ])),
+            Felt::new(BigInteger256([
+                0xf969b176a8a61cdd,
+                0x963dbb2e00601ea3,
+                0xac0012171fcaca30,
+                0x08f71168c9175efe,
+            ])),
+            Felt::new(BigInteger256([
+                0x2d40f597e4c867ac,
+                0x99dac5d591157f35,
+                0xf9c31f9f3774a46b,
+                0x13c617bc5cd23ec1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x323e0a99e14d2590,
+                0xd5134091ffb9062e,
+                0xe51c6f83306b8b0c,
+                0x2f3591cfe5628f1b,
+            ])),
+            Felt::new(BigInteger256([
+                0x7a6bca39d9241209,
+                0xed45306838ddf6d8,
+                0x405e09b181f870a7,
+                0x024b9ff03e0c17c6,
+            ])),
+            Felt::new(BigInteger256([
+                0xe8c85de75b800450,
+                0x41e2fa79d4da4f74,
+                0xacd3ea757476dc97,
+                0x156c8afe8495c119,
+            ])),
+            Felt::new(BigInteger256([
+                0x646c284a202098e3,
+                0x81138ae986b685df,
+                0xcd9303739de7409d,
+                0x179ca1e6cacac024,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x018231effb25d190,
+                0x0caef22b7fcf7e50,
+                0xbcea994347d395fa,
+                0x038f01f6b0e6ffe5,
+            ])),
+            Felt::new(BigInteger256([
+                0x887d6f65aeca8e02,
+                0xbcf367027dc29123,
+                0x212279ddfd560d4e,
+                0x0311d3ffba92b113,
+            ])),
+            Felt::new(BigInteger256([
+                0x1ba81859cf309443,
+                0x7b6f1f1ce4c35c22,
+                0x711621a3ae4fe06a,
+                0x13274c6cedfcb9d0,
+            ])),
+            Felt::new(BigInteger256([
+                0x9cec2ae85addc42a,
+                0x038eb1fd6951cb85,
+                0xeb7ef9f9542e60bc,
+                0x15d1d85a67b63b04,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7aa31e466aaa2af1,
+                0xda47d4d39f1fa0a7,
+                0xa125bf11342a2d7d,
+                0x22a55a92efdecb04,
+            ])),
+            Felt::new(BigInteger256([
+                0xeb4de4aeff778bb5,
+                0xe04c00677fea914f,
+                0x3dd53e4506b71d39,
+                0x0efe1e92b4660bad,
+            ])),
+            Felt::new(BigInteger256([
+                0x5a8be982dd638b6d,
+                0xa7b6fc7ee67f5efd,
+                0x9b27c55b44db7f43,
+                0x18fb0300722471ae,
+            ])),
+            Felt::new(BigInteger256([
+                0x9dd076561695a974,
+                0xcf93ca057ca37a82,
+                0x89f57d6912517ca3,
+                0x0cb7e8a3c107a8e7,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xf886d4a205fcc951,
+            0xa77df44ed6ed31db,
+            0xe93b99dfe6a9878c,
+            0x2bf47ede87e87036,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xeb6430008dfe62e5,
+            0xad7c7d17f216e4cb,
+            0x44ae72afbda29306,
+            0x017128a311fda697,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x602c8dd4595ecea1,
+            0x6cd45fb4515610d9,
+            0x4368f69d3f41c71d,
+            0x121a979a5e2f0e38,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x47239ce6c997abaf,
+            0xdd1d3881ae72a70f,
+            0x726c62cf04d0cf78,
+            0x068ff1e8fec300be,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xef09968ce321d4e2,
+            0xeba7e5c3ca8d6355,
+            0xcf42b7b64cdca440,
+            0x2198f3aac5f631b3,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x405791116fece44e,
+            0xf39f9f0a3109811d,
+            0x27e2530d22376033,
+            0x1bd547bd98a9ad48,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x28d71f53a95174e2,
+            0xbed1dd15f17209bf,
+            0xea9192c16b4f4311,
+            0x2f48c70af011f3c8,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xca22973da5f5089c,
+            0x36ddf9747139cbd6,
+            0xe0c41c4485176b5c,
+            0x2ed52a411ffb43ff,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xcf7e8c8d2582f5db,
+            0xe4dc4a258ffa0bf7,
+            0xa1a66b512d4f13d8,
+            0x1e22094ca4d9cd09,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x730a8d084b262222,
+            0x7b305c640e53f2dc,
+            0x235830b32c213dbc,
+            0x2e7a16d5f9ba12f6,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+    }
+}
@@ -0,0 +1,961 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x1ec86971bc5817c2,
+                    0xdf9e7d7640774730,
+                    0x441ed6d820ccc070,
+                    0x0c78f928984c091c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f69deb19b7f3944,
+                    0xab152aec75d31de3,
+                    0x1a8639b2556c844d,
+                    0x0f39f59d8232a467,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8fee2ae66bf092fc,
+                    0x21a4b3947c8a457c,
+                    0xd910562c3f4e22a2,
+                    0x0dd3f791c6550fae,
+                ])),
+                Felt::new(BigInteger256([
+                    0x294f722f05a89577,
+                    0xe5dde5fef04a2784,
+                    0x1554f83145aded48,
+                    0x1bf8e252409d1a66,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x906c03bfe485ee47,
+                    0xe1b1678822052e20,
+                    0x4537199dd22604df,
+                    0x0dae54de5f852204,
+                ])),
+                Felt::new(BigInteger256([
+                    0x20141dead22fa625,
+                    0xcf1418b4d235cf17,
+                    0x8f961349cc234ba5,
+                    0x2c978ef3e80e11a1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x43741eb852e23aca,
+                    0xba0d39f44b7b4f50,
+                    0x07b2f039eed82127,
+                    0x169e2865457aeb90,
+                ])),
+                Felt::new(BigInteger256([
+                    0x270f3f73e6e18b88,
+                    0x958e2e84550cfe92,
+                    0xad9cf798af731262,
+                    0x24b11b392e29fa11,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1734d449a1eb73b5,
+                    0xaad7396ff6e44812,
+                    0x2a280943957c1d57,
+                    0x0bae3d49c0da8651,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa51bd84258a42538,
+                    0x5e149ee2999e896a,
+                    0x18022083ea1bab4d,
+                    0x223175f04543aa1f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c235d269c9f3ccb,
+                    0x3097616bef0d9848,
+                    0x9b15a106a058ad34,
+                    0x2c80f6b193adb548,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe0b616cce127af5,
+                    0x9472a5412ad8f5e3,
+                    0x2501a80b400e6a6b,
+                    0x0eda787e48c3963f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7812c003214043b9,
+                    0x52edbe9146f625da,
+                    0x863c7e005e7b1dd1,
+                    0x09bc2dc589be99b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe42a4101a0b59e9,
+                    0x33331abb3053a749,
+                    0x943a2c2649bcbac0,
+                    0x2394092c39c59218,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe9847b10da396c5a,
+                    0x0b69b5f955b75606,
+                    0xb3ffbbefe32594b2,
+                    0x1bcdc96416f73b14,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9fb7d81ae3ade95a,
+                    0x9ab12622369f2d0f,
+                    0x42463b13dea8f58d,
+                    0x1731a72f2285429c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x83aac7e8706078c1,
+                    0xc6a12370fcf27439,
+                    0x49b9ca34318b4a2a,
+                    0x290a89996da760b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1159c5f75ae353ff,
+                    0xcb2d873f370a1ec8,
+                    0xfb7067c9b7d697f6,
+                    0x0866d4445bc39224,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1a5a53339826a58,
+                    0x6829928cd76d7355,
+                    0xd82712a96cbbb065,
+                    0x028b7ec5d3cc5da0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb4cc77b8daa82974,
+                    0x3de6c9664d240114,
+                    0x31d2916bc97c5df8,
+                    0x185c256dc5e025b3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4c74b744b7b1db6c,
+                    0xce4a60679af16044,
+                    0x4af25a10e8b9200c,
+                    0x2374874ec67750a4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c8fa9b9a55de9a0,
+                    0x376f326c79a280ec,
+                    0xb0c02719b43747c7,
+                    0x2487a9053595b653,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89f22b1c22192e97,
+                    0x3e0520abf1f0eec8,
+                    0x42d59520c91ed22c,
+                    0x2570337634fdaa69,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24fefa9b98a8cbdb,
+                    0x3cc6c8b96575a4bd,
+                    0xc2a5df2dc8e1e314,
+                    0x03e18114e2a8a9c5,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x61db54f16c5e5309,
+                    0x794e6e1ec8fe8144,
+                    0x0147b76ab1cd6d26,
+                    0x154716d985639f51,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0de3e890c0adcc15,
+                    0xe55d12708eea575b,
+                    0x9561c1f2c7bf5b8f,
+                    0x1b1f24f0b3497b0c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x35b0ff3b9c72ec59,
+                    0xc238850813965700,
+                    0xebfa856faa4d3605,
+                    0x0446706932fd405a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d7bc2cd5b57f121,
+                    0xe3185486046ce81c,
+                    0x66ae399dc115836b,
+                    0x1e7e6b228b84f867,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xea58b87bfe6b6f8f,
+                    0x012b85ad22d9875d,
+                    0xdda0d247723a20d7,
+                    0x1ce6d335df7da9d3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea2026296582b180,
+                    0xffc21f6d40d92279,
+                    0x3c8e249940a074b4,
+                    0x06904f75dcc17698,
+                ])),
+                Felt::new(BigInteger256([
+                    0x29cec62eb1a4ba75,
+                    0xd3cb3ea65013ae5e,
+                    0xcaa179b23c1f2eef,
+                    0x0d960a3e8c6bb9d6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3da044f96348b7a8,
+                    0xd19f9d544c7f6ccc,
+                    0x31c8216fee5970d2,
+                    0x2e03c93aa8a0adde,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x64fb355a78449d77,
+                    0x5a3a018c019dbc01,
+                    0xf30bcec0ae48b21b,
+                    0x0e89ac0f3cf985b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0088d77d61bd2fd,
+                    0x7abfe7010b4937ea,
+                    0x75a4241e284187ae,
+                    0x29b69dd511d039e1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa34c0d3174ec95cf,
+                    0x48da796b750c6944,
+                    0xd4940cc5adb5c9ad,
+                    0x2052c44d824054fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd6b216f886254f8b,
+                    0x0c014af5bdfee06c,
+                    0x8aaf7318c5cc4fdc,
+                    0x305d77edfa3a89b8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa96577d9761e7726,
+                    0xf92bb439064f163c,
+                    0xe72364d747149309,
+                    0x188a17af7c0c1fd4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x82cb1c35fc37e5ce,
+                    0xb8622865b8747061,
+                    0xfcae5e1513fbe2fd,
+                    0x0363095e66572447,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3773569dd84810e2,
+                    0x68e2a25c93bee60a,
+                    0x05e2bea9cd9e93f8,
+                    0x060447d67358ff4c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd25572b4e0ddc718,
+                    0x50b73ed04cbcb59d,
+                    0x40cd6cf8611194d5,
+                    0x2941181837303fe1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xbc0dc4c8f9994182,
+                    0x2e33664f2076adcd,
+                    0x716dd1cd1fe95ee1,
+                    0x144ef4f465035d51,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd42fec933acdf0a,
+                    0x4cb53400c455a04c,
+                    0xe30c871ff5c80c7f,
+                    0x0c0851e00d686fea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x57f74ab7edca3f84,
+                    0xf2cd4a283fa9684a,
+                    0xb0ded39371660938,
+                    0x02dd170405653a61,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90ca39c2076b22dc,
+                    0x09febef8faf4cbd7,
+                    0x626d646b5f09ed11,
+                    0x215b323ceb8d72bf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3cdafd63e4ed51e9,
+                    0x486ccf4569cbec1f,
+                    0x0abe77fcfd6d89b9,
+                    0x2123a8afafc99276,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd41e6bf660a63c72,
+                    0xe9f2f3451d9729c5,
+                    0x659654bd290ddf19,
+                    0x0247875b20f576f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0cf7a5a2cfc5895,
+                    0x6bfa1d8ffe2b265f,
+                    0x665951abc6862951,
+                    0x2d05704f0e8123c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe2abdb65fc812235,
+                    0xea63578a91b541e5,
+                    0x4b7cc9ad1e1ddad6,
+                    0x19c8fb5e5cd6dc15,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x9c91f6427c03c606,
+                    0xa467b48b4d5897ac,
+                    0x472760b879987678,
+                    0x14849852d0c71b21,
+                ])),
+                Felt::new(BigInteger256([
+                    0x52c150453a1aa486,
+                    0x30595151d6f4c9d8,
+                    0x5a162299a939a301,
+                    0x0e171bc351c93924,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c1e4f2356158bfe,
+                    0x26b01726c66ffd90,
+                    0x365591844a59e2a9,
+                    0x04a6c85965db2c5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5645d91e3e2cd26b,
+                    0xfa9d1b0c6c14abcd,
+                    0xd6dd59d8ff4de9d3,
+                    0x26ff5ec9f4e72108,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf68586ebb68939cd,
+                    0xc8399d7edc1e7230,
+                    0xe849bd6087a1108c,
+                    0x2e18230f93c7672b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd1cf641a3e0709f7,
+                    0x7eb727d5e2c50422,
+                    0x40c46a917cc39626,
+                    0x187d62746aa5826c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf52cec657e278c31,
+                    0x62528d5615bfa7fd,
+                    0xe2589169e8e78399,
+                    0x1dc75717cea2d975,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab75655666a03513,
+                    0x13cb03f788583ffa,
+                    0xf955107a95d06b16,
+                    0x000ba1a68de2732f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfdf4158536a92f41,
+                    0x9b8b6434f4c4e98d,
+                    0x8e6d8318ff61601c,
+                    0x08833a7413cd1b8b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c7cd41b0c8f705f,
+                    0x8c8efe92565db576,
+                    0xa88c3f1386b10315,
+                    0x1977108c75e28e2b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bea32fe714d6ed7,
+                    0x4a8ecc172111c290,
+                    0x8ace4b30eaec892a,
+                    0x0a489ae2b0504262,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b5890b013d16fc1,
+                    0x78fadae423e55301,
+                    0x5231a98f64a0c48f,
+                    0x1c8e3c3acae4fbee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfe09f20b59e68d12,
+                    0x7723a4b1cc1c5205,
+                    0x5198b096a45b108a,
+                    0x19458dc5522e8451,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe23b298a6969d7fa,
+                    0xb040e1eeef8f0006,
+                    0x789091f72f689db7,
+                    0x2b8ed3bd8deb9ffc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x148b8d5cd35208ab,
+                    0xbb0c0192a35b5d2f,
+                    0x264431af224a5e5e,
+                    0x2b4e1723b6c2a29d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcd32e86298bd5962,
+                    0xd8cd62445863f99a,
+                    0xa7b1e4bf2e05ad0a,
+                    0x30631d7b202ab484,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa861544f5905c75d,
+                    0x67111b20a6cf7745,
+                    0x722ab4bf5b3ee716,
+                    0x06763f9e5ec95a17,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80ec310396ceba6b,
+                    0x2e814d5cd4fda110,
+                    0xcd4d85bba92b144d,
+                    0x1c60b1f67d5e88bc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa5fdd860b2190b9e,
+                    0xc132d8a5c01590d3,
+                    0xd8c0e25bad7f6069,
+                    0x0a11e881aa8a85a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x935f7fb988d55254,
+                    0xc3d532b41994774e,
+                    0x1907317a84264128,
+                    0x1ec6934b66ee9aef,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa7c39f72dac8c11c,
+                    0x883a92bec933135c,
+                    0x13cf40a2ab3d6982,
+                    0x17546f69e8942400,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5df9a7074bbcce57,
+                    0xc5bf64711a9db702,
+                    0xbe097efdc0332423,
+                    0x15479f30f39a2f07,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ffab5f25e4a40fc,
+                    0xd2e365e72ba032b0,
+                    0x9fda12f77f74b29f,
+                    0x270efdf855f9de50,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55c153f0e082033c,
+                    0xedd25bf9a8fbfa6a,
+                    0x6f9ccc2f4ad200a5,
+                    0x2b70990669894b92,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xc7a34315efffffe1,
+                    0x21b6df39428b5e68,
+                    0xae478ee651564cb8,
+                    0x23da8016bafd9af2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc720a2ce2fffff5d,
+                    0x66f319da7eed5381,
+                    0xa4e39ccb8aa4bcaf,
+                    0x30220cda9d67056f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc7a34315efffffe1,
+                    0x21b6df39428b5e68,
+                    0xae478ee651564cb8,
+                    0x23da8016bafd9af2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc720a2ce2fffff5d,
+                    0x66f319da7eed5381,
+                    0xa4e39ccb8aa4bcaf,
+                    0x30220cda9d67056f,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xc7a34315efffffe1,
+                    0x21b6df39428b5e68,
+                    0xae478ee651564cb8,
+                    0x23da8016bafd9af2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc720a2ce2fffff5d,
+                    0x66f319da7eed5381,
+                    0xa4e39ccb8aa4bcaf,
+                    0x30220cda9d67056f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc7a34315efffffe1,
+                    0x21b6df39428b5e68,
+                    0xae478ee651564cb8,
+                    0x23da8016bafd9af2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc720a2ce2fffff5d,
+                    0x66f319da7eed5381,
+                    0xa4e39ccb8aa4bcaf,
+                    0x30220cda9d67056f,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf6769208ae88fca3,
+                    0x6df262dc06671854,
+                    0x5145c80246374d21,
+                    0x2a93d4b0888398ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0x138a5420e2c793b1,
+                    0xb5459e7c1012813c,
+                    0x0f31f3cb0248c333,
+                    0x2169096a7794b3e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8efb6f3afc988e60,
+                    0x93d9a685d28b2f0d,
+                    0x2e38ebb7f18dfec4,
+                    0x0ddcfa1610fd5ea2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd32585b6551053dd,
+                    0xe1bc6f7b696e7842,
+                    0x6521e665809e8422,
+                    0x19935c54d99c655b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x43ec9ab31cac6b9d,
+                    0xc931abd2dccb352b,
+                    0x032f011472eef635,
+                    0x175e23fd056e12cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9daa7e71ed652406,
+                    0x1c47b2633f4a2cd8,
+                    0xe00ee48ab86bb479,
+                    0x2c8b797fc368a014,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa9b022960d65f205,
+                    0x86cc0dcc87ea363c,
+                    0x3c1f16689dd1f3fd,
+                    0x03c369c4f37bd200,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01bba3bfd1254649,
+                    0xdc1aea0c4399469c,
+                    0x56a3bbbe7c7fef2f,
+                    0x0034197daedc534d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe4a04ee49576611a,
+                    0x09ee8c7fb12693bb,
+                    0x688a330d9dd3bece,
+                    0x270df04c9ed6a212,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe416884237df55dd,
+                    0x1d681beee538d5dc,
+                    0xd1fe277d95ce5ba7,
+                    0x1b2b88400b4d37e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x73099a145a5499f3,
+                    0xc590ef0f4f84af42,
+                    0x4fe8dbcd79be1a05,
+                    0x1f94f4355b44a7b1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdf7454a864f47093,
+                    0xadc7be8a417cdd28,
+                    0xf169c510c71f022f,
+                    0x17047494d512a85b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x15c7f5dbb8f7c4ef,
+                    0x1f6484f219481f52,
+                    0x12da2dda09df6b4f,
+                    0x011a283ab1d0836f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b4101411640b0a4,
+                    0x25039260f43e2c10,
+                    0x1e833182df445de6,
+                    0x00ad4e7025cc90fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe082df4f095784b4,
+                    0xb705e0b7288d98af,
+                    0xcd75809a51f2cb12,
+                    0x16f408ef4bffc0c5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab74092c3207a02c,
+                    0xd58dac4d00f306ea,
+                    0x009ee38f37844ca4,
+                    0x0ce558778f2c85fd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x615868a57b0f6b71,
+                    0xd6fbe51762974ae4,
+                    0x4bbd804624924380,
+                    0x032cce162e0d254a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67a63c3efe1bd3a0,
+                    0x616cc6d1c1f21786,
+                    0x4801071a600665d1,
+                    0x2c40b86563a04330,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8d72c40913528c69,
+                    0x619f85a86046dadf,
+                    0x9c7b578e6621cadc,
+                    0x20bbcf60da719702,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d95cbd242b5c9a8,
+                    0x30b4bc16344b857d,
+                    0xbe38c70027a44da0,
+                    0x1c900a0d4b2d9835,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf5dff76f7578c8cd,
+                    0x148fb8635ad4c545,
+                    0x595e302a693a6d7b,
+                    0x20f1ee78e833ced3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5fb39b78c718ba55,
+                    0xb3f2458873993fac,
+                    0x53ef9eae49d33e73,
+                    0x28d45c34d90858ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x961f0b5cf7f54824,
+                    0x6b73b85b9c373595,
+                    0xad9e142ac2141c50,
+                    0x2d2a551492a4227e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab2c22df8614a9ab,
+                    0x23227646d8150bc5,
+                    0x6a5f1b3cc2528660,
+                    0x16ed1f20cd3669f9,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
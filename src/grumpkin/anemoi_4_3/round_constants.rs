@@ -0,0 +1,347 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x4ae1f0502fffff3d,
+            0x607610cb47bf4159,
+            0x9adae5fb5a79b10a,
+            0x23983e7e77330038,
+        ])),
+        Felt::new(BigInteger256([
+            0x00dcd28ab82cd15c,
+            0x5df57927ac6c7fea,
+            0x3f415239325ad8db,
+            0x23633c78c0dd035e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x46e64390cf95e53d,
+            0x66c265075130032e,
+            0x640d055b0de012e9,
+            0x1e84cbd4f92a5849,
+        ])),
+        Felt::new(BigInteger256([
+            0xa045805b22ac803b,
+            0x22fc8b00e5af8236,
+            0x572cedac37a11509,
+            0x23fb7c3958041c2c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb28b2f88b415dcf6,
+            0x16b883458431d6c1,
+            0xa281629c250acb7c,
+            0x15b5a97027f4cbfd,
+        ])),
+        Felt::new(BigInteger256([
+            0xc8a25e83f680d1fe,
+            0x6f4d480dddba1024,
+            0xfec90bd99059ba52,
+            0x2345da99a77ded0c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x01fc2956d3c502ac,
+            0xeb7de97c455bada2,
+            0x6fbecd2423a868c7,
+            0x218088c6cf87e883,
+        ])),
+        Felt::new(BigInteger256([
+            0x487ff67d83c3e2d9,
+            0x7f0ce73bc657a41d,
+            0x0a3cd11ea987ee30,
+            0x16e9d29c28cfa180,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x68dd16cd9d5d63cf,
+            0xb5b96a366e5ce11f,
+            0xfb91d5b04ea051f8,
+            0x25d0c05e4022929b,
+        ])),
+        Felt::new(BigInteger256([
+            0xf53f13e7c14e668c,
+            0xaa3482e6c00dd1fc,
+            0x703ef0fde26595b9,
+            0x2192d961f1ee50a7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0e4a649da30b9da7,
+            0xe9d76bb94ab7f816,
+            0x0dae0d09cc751457,
+            0x2e68ba932023bbc3,
+        ])),
+        Felt::new(BigInteger256([
+            0x0e61b4e3ba4e1348,
+            0x566efc334d0143e8,
+            0x342345719f57793e,
+            0x28172555bf26de74,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf84f986fa4fe0f3e,
+            0x80b592172cc2d242,
+            0xf4d60aaa77768179,
+            0x233943019b7c9d91,
+        ])),
+        Felt::new(BigInteger256([
+            0x401e244460ae9c68,
+            0xf8b1fbb26cdb5b4b,
+            0x75b72493199ec498,
+            0x140d38fecb5bacb5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa809283ab264a9f0,
+            0x9b39847cf29ce716,
+            0x4047ccb11771158e,
+            0x3003bb3ace34402f,
+        ])),
+        Felt::new(BigInteger256([
+            0x713bcfbef2d5a4f1,
+            0xf154b54a096fbbe3,
+            0x38d7b4474d1891ca,
+            0x11d6ffa0d04c165d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe095938246da6406,
+            0x7532ccdf944b692a,
+            0x1362cc3636a3dc89,
+            0x05b2fcd0ade1726d,
+        ])),
+        Felt::new(BigInteger256([
+            0x65d994648532aa68,
+            0xf5c6f71b11e7f160,
+            0x58b6764ed47b63d1,
+            0x10630ab581d6b9da,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x42d9dc9c9e8c34fe,
+            0x880c31a88144e38c,
+            0xdedc13523102e6c6,
+            0x2fc6593f2977b1a6,
+        ])),
+        Felt::new(BigInteger256([
+            0x3676c9ccaa194164,
+            0x5e187dadeaca94f3,
+            0xb68f0a3820b53294,
+            0x0bc105758abc96a3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeecd85964d5978a8,
+            0xa4c8bb98b0b2dd0a,
+            0xd08798ceff0f9375,
+            0x13f28ca16d590d94,
+        ])),
+        Felt::new(BigInteger256([
+            0xa338958c2c21780c,
+            0xdb309b5bb2118c56,
+            0x44dcda84fd6eefc4,
+            0x176aa3726111c40f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6b7497fff51b7783,
+            0xed00b74764413a54,
+            0x4de160cb93c5f8bc,
+            0x1b609bbf57fe261c,
+        ])),
+        Felt::new(BigInteger256([
+            0x614b385ad4d7346e,
+            0xfac824007d041c05,
+            0x0ca3642997d24942,
+            0x01460fa052769b39,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xde453430499998d5,
+            0xecefd05751c9c070,
+            0xa727433dbe778a0e,
+            0x095e27604216ccc2,
+        ])),
+        Felt::new(BigInteger256([
+            0xbda691efd30a8f19,
+            0xbb403a9065963f15,
+            0x227182ad9c4ae2ce,
+            0x176cedca8b9b28ae,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe6b77586057b6b5f,
+            0xf8329f693a3ae618,
+            0xc7e6db7fdbe912e3,
+            0x23f1832ed7582470,
+        ])),
+        Felt::new(BigInteger256([
+            0x259b384169d62a81,
+            0x5d09def7042034a4,
+            0xd99a514c8a1aed95,
+            0x0747ad9054daa0ef,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xaa7cd8d3a6268a4e,
+            0xee06cd26d758e605,
+            0x269558a263d4ffeb,
+            0x187021937d18cd3c,
+        ])),
+        Felt::new(BigInteger256([
+            0xa6188dbff9d5a37a,
+            0xef38ab836646eeeb,
+            0xa1708f5b5394c753,
+            0x03dfccba1b4aa6e7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcb62331502459998,
+            0x4e8690f1a49bf84e,
+            0xd9b3f15f4677fa46,
+            0x26afd1c352bbebc1,
+        ])),
+        Felt::new(BigInteger256([
+            0x3b4c7bc0b3889dea,
+            0xb2e6908dd4b72ede,
+            0x4b15c88bd249b09d,
+            0x2a5ce408abddfd84,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbf7e7780f6d2dcb0,
+            0x66d5966d2cfa5749,
+            0xc4b7b8135f33a52c,
+            0x205be33f1bbd0861,
+        ])),
+        Felt::new(BigInteger256([
+            0x7546f0201c080392,
+            0x2c21b0fa2dca883b,
+            0x1048a692f8eb19dc,
+            0x2a61c4b2cd631f33,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xda36b9789b70da0f,
+            0x770d64ec2a5b2a91,
+            0x46b2be29a26a9aa5,
+            0x2ee35d800e368ada,
+        ])),
+        Felt::new(BigInteger256([
+            0xbfd28fafc3f773d4,
+            0x8c420efa620a45e6,
+            0x8bbb840cf9bdd81d,
+            0x0671423fcbe26627,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x03eb861f3faad73e,
+            0x6b2efee54e7d0bc6,
+            0x5d75f246987e60fc,
+            0x077be59942c0487f,
+        ])),
+        Felt::new(BigInteger256([
+            0x75208d78fc9f888d,
+            0xb3fc6a5d3db4d4e2,
+            0xb53adf614098d50a,
+            0x0693a4067279b068,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1530cec79e3bf68e,
+            0x89ee5ccc5e8e828b,
+            0x3522047745d10cf1,
+            0x1a9b8f7fa942ee7c,
+        ])),
+        Felt::new(BigInteger256([
+            0x07c9f1d0dff115b4,
+            0xb0da8f762480976c,
+            0x0495bf3f816aba1c,
+            0x0ab29c55ab351d70,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf11b7fed38d2a964,
+            0x752f8e095b36feae,
+            0x5ed47d40f96caf3e,
+            0x25f523b396610b1b,
+        ])),
+        Felt::new(BigInteger256([
+            0x5be406c0886f13ea,
+            0x9e60d1d90e395667,
+            0xc2bbb4d51bb50f18,
+            0x0e84ab9588ff0b24,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9a85f0bba47b6f2f,
+            0x7b2abea04d783395,
+            0x503c702359510cc8,
+            0x24bb7ab5d5b1eff9,
+        ])),
+        Felt::new(BigInteger256([
+            0xb7895970b14c9fba,
+            0x22080c82661d2510,
+            0xfed33a3b4ef58985,
+            0x0ef9ef5c36d12dbb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9f18c9fa07517881,
+            0x80f9623ff5d2430f,
+            0x3fa2cfc81746ba67,
+            0x174129f880ea622c,
+        ])),
+        Felt::new(BigInteger256([
+            0x7cea5574e75d9c0a,
+            0x883243dfa650326f,
+            0x8adbe4b01b9847a5,
+            0x28fd0939747d716c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6d0734fcb38bf08b,
+            0x6aa9413cbb99fad4,
+            0x3c04daf5ea9e6d0f,
+            0x2f358e770d920e37,
+        ])),
+        Felt::new(BigInteger256([
+            0x8c4450dc948bd19b,
+            0x4941afd2837c1c99,
+            0xd1aab185f49cee84,
+            0x235ecac807e4dc19,
+        ])),
+    ],
+];
@@ -0,0 +1,108 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [ 1  6  5  5]
+/// [25 30  6 11]
+/// [25 25  1  6]
+/// [ 6 11  5  6]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xff7d5fb83fffff7c,
+        0x453c3aa13c61f518,
+        0xf69c0de5394e6ff7,
+        0x0c478cc3e2696a7c,
+    ])),
+    Felt::new(BigInteger256([
+        0x1a8a6eb1dfffff62,
+        0x2ff6a344df8c8658,
+        0x3e74f95c122b7681,
+        0x22179519035f2640,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0x9ece5c7b9fffffc6,
+        0xe43d5f946bfc7f16,
+        0x3dd034a6a8b1fae4,
+        0x094639f8fac1b68c,
+    ])),
+    Felt::new(BigInteger256([
+        0xff7d5fb83fffff7c,
+        0x453c3aa13c61f518,
+        0xf69c0de5394e6ff7,
+        0x0c478cc3e2696a7c,
+    ])),
+    Felt::new(BigInteger256([
+        0xff7d5fb83fffff7c,
+        0x453c3aa13c61f518,
+        0xf69c0de5394e6ff7,
+        0x0c478cc3e2696a7c,
+    ])),
+    Felt::new(BigInteger256([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+    Felt::new(BigInteger256([
+        0x9ece5c7b9fffffc6,
+        0xe43d5f946bfc7f16,
+        0x3dd034a6a8b1fae4,
+        0x094639f8fac1b68c,
+    ])),
+    Felt::new(BigInteger256([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ])),
+    Felt::new(BigInteger256([
+        0xc7a34315efffffe1,
+        0x21b6df39428b5e68,
+        0xae478ee651564cb8,
+        0x23da8016bafd9af2,
+    ])),
+];
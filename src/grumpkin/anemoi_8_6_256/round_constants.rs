@@ -0,0 +1,739 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x4ae1f0502fffff3d,
+            0x607610cb47bf4159,
+            0x9adae5fb5a79b10a,
+            0x23983e7e77330038,
+        ])),
+        Felt::new(BigInteger256([
+            0x00dcd28ab82cd15c,
+            0x5df57927ac6c7fea,
+            0x3f415239325ad8db,
+            0x23633c78c0dd035e,
+        ])),
+        Felt::new(BigInteger256([
+            0x58b4ded4c6b5e6ce,
+            0xbd469bf12e55dfad,
+            0x01239346109045d4,
+            0x1c778056098372dd,
+        ])),
+        Felt::new(BigInteger256([
+            0xdb84cb90642cbec6,
+            0xd3468b64b672f77e,
+            0x68860e52c401043d,
+            0x2e15ebb683520cd6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x46e64390cf95e53d,
+            0x66c265075130032e,
+            0x640d055b0de012e9,
+            0x1e84cbd4f92a5849,
+        ])),
+        Felt::new(BigInteger256([
+            0xa045805b22ac803b,
+            0x22fc8b00e5af8236,
+            0x572cedac37a11509,
+            0x23fb7c3958041c2c,
+        ])),
+        Felt::new(BigInteger256([
+            0x69fce1cd51e0aa4a,
+            0xd3bb7d92c2f9b923,
+            0x8e29afd3b46dbfb9,
+            0x2e512893d60492dc,
+        ])),
+        Felt::new(BigInteger256([
+            0x7c078a0e8efc58f4,
+            0x4b185c7af83130bf,
+            0x6e0e3ff4b25f8577,
+            0x14cb0d0b57fc225f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb28b2f88b415dcf6,
+            0x16b883458431d6c1,
+            0xa281629c250acb7c,
+            0x15b5a97027f4cbfd,
+        ])),
+        Felt::new(BigInteger256([
+            0xc8a25e83f680d1fe,
+            0x6f4d480dddba1024,
+            0xfec90bd99059ba52,
+            0x2345da99a77ded0c,
+        ])),
+        Felt::new(BigInteger256([
+            0xe6dce6d08f56903a,
+            0x6b1f9758997d9768,
+            0x89ca0fb1963e54c0,
+            0x2f613b350c94b50d,
+        ])),
+        Felt::new(BigInteger256([
+            0xfe582a36e88f19c4,
+            0xc5ab377f01c2b1a7,
+            0x93bb131e3043c242,
+            0x1271d071de2ea7cc,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x01fc2956d3c502ac,
+            0xeb7de97c455bada2,
+            0x6fbecd2423a868c7,
+            0x218088c6cf87e883,
+        ])),
+        Felt::new(BigInteger256([
+            0x487ff67d83c3e2d9,
+            0x7f0ce73bc657a41d,
+            0x0a3cd11ea987ee30,
+            0x16e9d29c28cfa180,
+        ])),
+        Felt::new(BigInteger256([
+            0x2e68bfa78097f7b3,
+            0x2ca8d9f38e7e64bb,
+            0xc430522a4365be37,
+            0x0f2f006ed6ff4901,
+        ])),
+        Felt::new(BigInteger256([
+            0xd6bc2f2631efee11,
+            0x94c33925139ba143,
+            0x7b83674a4927980a,
+            0x24177f095d0c5090,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x68dd16cd9d5d63cf,
+            0xb5b96a366e5ce11f,
+            0xfb91d5b04ea051f8,
+            0x25d0c05e4022929b,
+        ])),
+        Felt::new(BigInteger256([
+            0xf53f13e7c14e668c,
+            0xaa3482e6c00dd1fc,
+            0x703ef0fde26595b9,
+            0x2192d961f1ee50a7,
+        ])),
+        Felt::new(BigInteger256([
+            0xfaf5da6057c9771c,
+            0x45b8af29346a55c0,
+            0x3e946244b9c7c6d0,
+            0x1551b72a635703f2,
+        ])),
+        Felt::new(BigInteger256([
+            0x72ed2797141cbf2a,
+            0x22dee5253240e19b,
+            0xe346f285d3c26dfa,
+            0x18ae2822c6e694b6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0e4a649da30b9da7,
+            0xe9d76bb94ab7f816,
+            0x0dae0d09cc751457,
+            0x2e68ba932023bbc3,
+        ])),
+        Felt::new(BigInteger256([
+            0x0e61b4e3ba4e1348,
+            0x566efc334d0143e8,
+            0x342345719f57793e,
+            0x28172555bf26de74,
+        ])),
+        Felt::new(BigInteger256([
+            0xa77fa470872bc96e,
+            0xcdeeb2a14d23931b,
+            0xb8cc7a0c99dca384,
+            0x06d0590dc7474b2f,
+        ])),
+        Felt::new(BigInteger256([
+            0xf81092396de7f78c,
+            0x5de5b4fcaff64dc9,
+            0x887f77ee0b047ef8,
+            0x1be2432af50e60b3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf84f986fa4fe0f3e,
+            0x80b592172cc2d242,
+            0xf4d60aaa77768179,
+            0x233943019b7c9d91,
+        ])),
+        Felt::new(BigInteger256([
+            0x401e244460ae9c68,
+            0xf8b1fbb26cdb5b4b,
+            0x75b72493199ec498,
+            0x140d38fecb5bacb5,
+        ])),
+        Felt::new(BigInteger256([
+            0x0b4e8067a3aa634b,
+            0x55bc665da6df50c0,
+            0xa69268f46b163228,
+            0x01e3e0fa33c3bb3b,
+        ])),
+        Felt::new(BigInteger256([
+            0x5431a96200346032,
+            0x52577f709221c87d,
+            0xea243e96ed972ddc,
+            0x0e44091c14419eeb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa809283ab264a9f0,
+            0x9b39847cf29ce716,
+            0x4047ccb11771158e,
+            0x3003bb3ace34402f,
+        ])),
+        Felt::new(BigInteger256([
+            0x713bcfbef2d5a4f1,
+            0xf154b54a096fbbe3,
+            0x38d7b4474d1891ca,
+            0x11d6ffa0d04c165d,
+        ])),
+        Felt::new(BigInteger256([
+            0xb031f2df31d78bfd,
+            0x3c88ff5914e02d15,
+            0x092b9d948d27d34e,
+            0x027f5cc5ed4bb42f,
+        ])),
+        Felt::new(BigInteger256([
+            0xcce58226a06f2025,
+            0xb280a84f6fa5ff76,
+            0x750e0aaee55d30e1,
+            0x02137322f35d0e96,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe095938246da6406,
+            0x7532ccdf944b692a,
+            0x1362cc3636a3dc89,
+            0x05b2fcd0ade1726d,
+        ])),
+        Felt::new(BigInteger256([
+            0x65d994648532aa68,
+            0xf5c6f71b11e7f160,
+            0x58b6764ed47b63d1,
+            0x10630ab581d6b9da,
+        ])),
+        Felt::new(BigInteger256([
+            0x06af4f75d9e74ce9,
+            0xce67e1e9fbb5af42,
+            0x6c09e49713c28213,
+            0x101f029f3413817d,
+        ])),
+        Felt::new(BigInteger256([
+            0xa834067a54ed8188,
+            0x5f034491b7b38369,
+            0xa2a885b35b2f05b1,
+            0x28be526e48ec87aa,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x42d9dc9c9e8c34fe,
+            0x880c31a88144e38c,
+            0xdedc13523102e6c6,
+            0x2fc6593f2977b1a6,
+        ])),
+        Felt::new(BigInteger256([
+            0x3676c9ccaa194164,
+            0x5e187dadeaca94f3,
+            0xb68f0a3820b53294,
+            0x0bc105758abc96a3,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d190627591a903e,
+            0xedd4a385457ba965,
+            0xe63523f722b77eda,
+            0x089a04b804b0b6da,
+        ])),
+        Felt::new(BigInteger256([
+            0xd33b74288a241eee,
+            0x1c1b71adb8257d2c,
+            0x43ac08d3c92c7d88,
+            0x0a5bf4cd74a43b51,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeecd85964d5978a8,
+            0xa4c8bb98b0b2dd0a,
+            0xd08798ceff0f9375,
+            0x13f28ca16d590d94,
+        ])),
+        Felt::new(BigInteger256([
+            0xa338958c2c21780c,
+            0xdb309b5bb2118c56,
+            0x44dcda84fd6eefc4,
+            0x176aa3726111c40f,
+        ])),
+        Felt::new(BigInteger256([
+            0xd00325053a7ec956,
+            0x90cf33caf19b2c9e,
+            0x7cdfae798e6f8e47,
+            0x1398323bf4ea1707,
+        ])),
+        Felt::new(BigInteger256([
+            0xa7020eaac967198e,
+            0x39bf6fcd1769cc2a,
+            0xdd32d6e531bc3972,
+            0x09e2d5da38794be7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6b7497fff51b7783,
+            0xed00b74764413a54,
+            0x4de160cb93c5f8bc,
+            0x1b609bbf57fe261c,
+        ])),
+        Felt::new(BigInteger256([
+            0x614b385ad4d7346e,
+            0xfac824007d041c05,
+            0x0ca3642997d24942,
+            0x01460fa052769b39,
+        ])),
+        Felt::new(BigInteger256([
+            0x32fdcaf3aef06652,
+            0x771345c3b7a52ff5,
+            0x369b4c707b6d3468,
+            0x11245e6e8e78cd75,
+        ])),
+        Felt::new(BigInteger256([
+            0xf4473e21558edb76,
+            0x38187310223472ce,
+            0x4486e03bd9049986,
+            0x1d02566eb8ba485a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfb3d8f9ecd580e84,
+            0xabb33b9b53a731ef,
+            0xb3b2293f1d588023,
+            0x1f922af62b171cb1,
+        ])),
+        Felt::new(BigInteger256([
+            0xed6f2131119cd522,
+            0x245a6ac42f80f3c2,
+            0x212e6d8a7681a1e5,
+            0x050e7ec38f720ae2,
+        ])),
+        Felt::new(BigInteger256([
+            0x91bd26d71b5d6e64,
+            0x88d332c5519a5425,
+            0xc52b2523fef23f8f,
+            0x2783b23cb1735c32,
+        ])),
+        Felt::new(BigInteger256([
+            0x22978587cb09fa85,
+            0xa5fd4b73019fb6ac,
+            0x25bccab99af7e7e0,
+            0x227d19e2169d78e6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9ec4f6ca598d2227,
+            0x80a9b74c17922ca8,
+            0x888d33631fd4c56f,
+            0x25318ebeaea99c8d,
+        ])),
+        Felt::new(BigInteger256([
+            0xd89526928343d8f9,
+            0xe210d97aaeb263ce,
+            0xa1559646b4635307,
+            0x2705c5d33329d4dd,
+        ])),
+        Felt::new(BigInteger256([
+            0x819ef06010a19198,
+            0x4c9c1bb2701822f3,
+            0x58f16e604c9eb98a,
+            0x01a5bf3637e312ee,
+        ])),
+        Felt::new(BigInteger256([
+            0xf76fa50eacaca670,
+            0x107be45f74e24bc0,
+            0x34a6e4940de56cf4,
+            0x1a58e061b49be49e,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xde453430499998d5,
+            0xecefd05751c9c070,
+            0xa727433dbe778a0e,
+            0x095e27604216ccc2,
+        ])),
+        Felt::new(BigInteger256([
+            0xbda691efd30a8f19,
+            0xbb403a9065963f15,
+            0x227182ad9c4ae2ce,
+            0x176cedca8b9b28ae,
+        ])),
+        Felt::new(BigInteger256([
+            0x92b41d269555a161,
+            0xe2149f3bc7880584,
+            0x9d148047e44bcaca,
+            0x246afa849c17a8b1,
+        ])),
+        Felt::new(BigInteger256([
+            0x5cb5b8534065ccde,
+            0x6a144c8fb7d95173,
+            0x4177a2f32507f8f7,
+            0x0a662880d4100c7e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe6b77586057b6b5f,
+            0xf8329f693a3ae618,
+            0xc7e6db7fdbe912e3,
+            0x23f1832ed7582470,
+        ])),
+        Felt::new(BigInteger256([
+            0x259b384169d62a81,
+            0x5d09def7042034a4,
+            0xd99a514c8a1aed95,
+            0x0747ad9054daa0ef,
+        ])),
+        Felt::new(BigInteger256([
+            0x6c8818a04ccc5166,
+            0xd54c136ac172d23c,
+            0xc957d00170b31348,
+            0x258722c79ab12824,
+        ])),
+        Felt::new(BigInteger256([
+            0x09a664e687815396,
+            0xe6dc987bd897ee87,
+            0x9e8d4d777d71a126,
+            0x10c2184dbc0421a4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xaa7cd8d3a6268a4e,
+            0xee06cd26d758e605,
+            0x269558a263d4ffeb,
+            0x187021937d18cd3c,
+        ])),
+        Felt::new(BigInteger256([
+            0xa6188dbff9d5a37a,
+            0xef38ab836646eeeb,
+            0xa1708f5b5394c753,
+            0x03dfccba1b4aa6e7,
+        ])),
+        Felt::new(BigInteger256([
+            0x418894f9466d5e8c,
+            0xb28e3cb00212dcdb,
+            0xe5324fc0c344dcc4,
+            0x23e4f63248377f6c,
+        ])),
+        Felt::new(BigInteger256([
+            0xe4177c649d3f3b9c,
+            0xa74d82ff4c459bc8,
+            0xe47440826c171267,
+            0x0bb69c7db92cdc28,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xcb62331502459998,
+            0x4e8690f1a49bf84e,
+            0xd9b3f15f4677fa46,
+            0x26afd1c352bbebc1,
+        ])),
+        Felt::new(BigInteger256([
+            0x3b4c7bc0b3889dea,
+            0xb2e6908dd4b72ede,
+            0x4b15c88bd249b09d,
+            0x2a5ce408abddfd84,
+        ])),
+        Felt::new(BigInteger256([
+            0x5a88ce43741eaf99,
+            0xffd1dcdf032ce596,
+            0x0579c06e5471a349,
+            0x06278c4540b21561,
+        ])),
+        Felt::new(BigInteger256([
+            0x8defe1c7230ff97d,
+            0x021fe2396a37c6cd,
+            0xb21dc2e36900453e,
+            0x1fd11bee661a86ec,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbf7e7780f6d2dcb0,
+            0x66d5966d2cfa5749,
+            0xc4b7b8135f33a52c,
+            0x205be33f1bbd0861,
+        ])),
+        Felt::new(BigInteger256([
+            0x7546f0201c080392,
+            0x2c21b0fa2dca883b,
+            0x1048a692f8eb19dc,
+            0x2a61c4b2cd631f33,
+        ])),
+        Felt::new(BigInteger256([
+            0xb4513ff1764510f7,
+            0x66f536d608760219,
+            0xdf0e8eb0b8976d98,
+            0x01a61ce5257042d8,
+        ])),
+        Felt::new(BigInteger256([
+            0xb75c312d3031ac8b,
+            0xde4f12fae83a32a2,
+            0x79120c46e15edce2,
+            0x09c39eec285b3d9a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xda36b9789b70da0f,
+            0x770d64ec2a5b2a91,
+            0x46b2be29a26a9aa5,
+            0x2ee35d800e368ada,
+        ])),
+        Felt::new(BigInteger256([
+            0xbfd28fafc3f773d4,
+            0x8c420efa620a45e6,
+            0x8bbb840cf9bdd81d,
+            0x0671423fcbe26627,
+        ])),
+        Felt::new(BigInteger256([
+            0x1a07f3bd349726d1,
+            0xf378ef92bbee6c57,
+            0x8175baebdf8fd5c3,
+            0x29788d477d0a8391,
+        ])),
+        Felt::new(BigInteger256([
+            0xb1ca8ff728eca874,
+            0xf56fafce86f55b22,
+            0x8e29606bde0320fa,
+            0x12e73a0068fb62e8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x03eb861f3faad73e,
+            0x6b2efee54e7d0bc6,
+            0x5d75f246987e60fc,
+            0x077be59942c0487f,
+        ])),
+        Felt::new(BigInteger256([
+            0x75208d78fc9f888d,
+            0xb3fc6a5d3db4d4e2,
+            0xb53adf614098d50a,
+            0x0693a4067279b068,
+        ])),
+        Felt::new(BigInteger256([
+            0xbd866888f35d4c46,
+            0xd88a16ea57c13102,
+            0x9ed6e04ffbdbbd9c,
+            0x085414dea2b7cf73,
+        ])),
+        Felt::new(BigInteger256([
+            0x917d35884d809cb3,
+            0x6f58d62624f14d6e,
+            0xd7b9a3478d298171,
+            0x19754e0f22911d20,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1530cec79e3bf68e,
+            0x89ee5ccc5e8e828b,
+            0x3522047745d10cf1,
+            0x1a9b8f7fa942ee7c,
+        ])),
+        Felt::new(BigInteger256([
+            0x07c9f1d0dff115b4,
+            0xb0da8f762480976c,
+            0x0495bf3f816aba1c,
+            0x0ab29c55ab351d70,
+        ])),
+        Felt::new(BigInteger256([
+            0xc3f593ddd2b4f996,
+            0xc3921b670ff96f49,
+            0x8daa651a2b4576a2,
+            0x0f44c257900acbc6,
+        ])),
+        Felt::new(BigInteger256([
+            0x6bbcc72a3ee5e144,
+            0xd3bd6a864cace659,
+            0xeeddbf8992479c56,
+            0x1399e9c33577902a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf11b7fed38d2a964,
+            0x752f8e095b36feae,
+            0x5ed47d40f96caf3e,
+            0x25f523b396610b1b,
+        ])),
+        Felt::new(BigInteger256([
+            0x5be406c0886f13ea,
+            0x9e60d1d90e395667,
+            0xc2bbb4d51bb50f18,
+            0x0e84ab9588ff0b24,
+        ])),
+        Felt::new(BigInteger256([
+            0x79ef40be90e5b341,
+            0x3e84fe89d80f7af4,
+            0x8ecfdfaac4c7a85d,
+            0x222a6c5c0311e34c,
+        ])),
+        Felt::new(BigInteger256([
+            0x62a5a63419853b65,
+            0x41201f11fc418339,
+            0x026f2865997f9bbe,
+            0x0f267ec6d614b34d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9a85f0bba47b6f2f,
+            0x7b2abea04d783395,
+            0x503c702359510cc8,
+            0x24bb7ab5d5b1eff9,
+        ])),
+        Felt::new(BigInteger256([
+            0xb7895970b14c9fba,
+            0x22080c82661d2510,
+            0xfed33a3b4ef58985,
+            0x0ef9ef5c36d12dbb,
+        ])),
+        Felt::new(BigInteger256([
+            0x5b6114b8140feb6a,
+            0x7947743ba0d6a02e,
+            0xe73a1087bac350ce,
+            0x1fbcb77b789b5e77,
+        ])),
+        Felt::new(BigInteger256([
+            0x5c9726be42b2cda0,
+            0x41c1e88cf56e18a4,
+            0x3a01e2b97005179c,
+            0x263fb89f87ea4ceb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9f18c9fa07517881,
+            0x80f9623ff5d2430f,
+            0x3fa2cfc81746ba67,
+            0x174129f880ea622c,
+        ])),
+        Felt::new(BigInteger256([
+            0x7cea5574e75d9c0a,
+            0x883243dfa650326f,
+            0x8adbe4b01b9847a5,
+            0x28fd0939747d716c,
+        ])),
+        Felt::new(BigInteger256([
+            0xe3086e46b97cea29,
+            0xdd2035e84c28c8d1,
+            0xc34f2f7b94e308cd,
+            0x08b0126ceefa34bf,
+        ])),
+        Felt::new(BigInteger256([
+            0x451afbf145fe8de7,
+            0x2044181353e50d0c,
+            0x18f3453c46fc7c19,
+            0x03bbc719d1e4d39d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6d0734fcb38bf08b,
+            0x6aa9413cbb99fad4,
+            0x3c04daf5ea9e6d0f,
+            0x2f358e770d920e37,
+        ])),
+        Felt::new(BigInteger256([
+            0x8c4450dc948bd19b,
+            0x4941afd2837c1c99,
+            0xd1aab185f49cee84,
+            0x235ecac807e4dc19,
+        ])),
+        Felt::new(BigInteger256([
+            0x974a6cce32670054,
+            0x64dc2b2f246c26a3,
+            0xfc1310a3c081fc4f,
+            0x16c294002a8b7eb0,
+        ])),
+        Felt::new(BigInteger256([
+            0xe3a78400d69ec8fe,
+            0xc014fea470e90e2b,
+            0xff4f91c42ce6298d,
+            0x27619d0ef4286392,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x53f9283f2daa1ff1,
+            0x6de1f51e0f33f4ea,
+            0x83a8db5ca148d9a7,
+            0x1137d182f35a6c57,
+        ])),
+        Felt::new(BigInteger256([
+            0x6f91355673330ab4,
+            0xb75a26239a2cf6d1,
+            0xc808f2da00642c57,
+            0x04f7edc0578fb34d,
+        ])),
+        Felt::new(BigInteger256([
+            0x4d32c45540b5a0cb,
+            0xbb2247be22954d4e,
+            0x6c76214a711eeca7,
+            0x0af29ba3603574f9,
+        ])),
+        Felt::new(BigInteger256([
+            0x6920c70aedfb8072,
+            0x72800694b4885483,
+            0xc258b4351bf15d19,
+            0x0aad145764bafba9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x14f3a9755ab655bf,
+            0x69b35d592ff38df8,
+            0x3339c3aed9355888,
+            0x29a5e6a17eb46b9c,
+        ])),
+        Felt::new(BigInteger256([
+            0x34485f2e95b130b5,
+            0x73b7991bfc7994a1,
+            0x6a95b40df234beb2,
+            0x095997b321dd5c88,
+        ])),
+        Felt::new(BigInteger256([
+            0x9e699d7cc6d0e62b,
+            0xcdfa057e17a12b02,
+            0x93428e6b75bcf894,
+            0x2847a865cf9e4b47,
+        ])),
+        Felt::new(BigInteger256([
+            0x5b6c009c70754e88,
+            0x03d98c0b849f87ed,
+            0xabf8ac3dc44f1bc2,
+            0x15578c2d0a80e6ca,
+        ])),
+    ],
+];
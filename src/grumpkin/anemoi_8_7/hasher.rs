@@ -0,0 +1,1358 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger256([
+            0x40475ad722e33740,
+            0xe30de89e9b62ff7c,
+            0xc1921dfbc30141e3,
+            0x019b6fcf02bbd274,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0x10d73cc118370d15,
+                0x3cf8914542be1969,
+                0x583d357e3c278770,
+                0x08c225ad6c57f21e,
+            ])),
+            Felt::new(BigInteger256([
+                0xf984ddc92590a905,
+                0x866a4b9b4f5cfe1c,
+                0xb3cb83c519fdeedf,
+                0x085bab35b93847ef,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x1bd9167988069e04,
+                0x842ed9859ba9c076,
+                0x6dbb4e9aa3e6989b,
+                0x2c6c45f59fe72268,
+            ])),
+            Felt::new(BigInteger256([
+                0xa3bebdd695886cf4,
+                0xb104eca550b53803,
+                0x83c6f97aece0136a,
+                0x25b327358d3ffaa1,
+            ])),
+            Felt::new(BigInteger256([
+                0xd6a8e7703f77648f,
+                0x7861767ab52100cf,
+                0xe0354e18a3d6c249,
+                0x2a0f3eeb9cef2235,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x8e357957307f556e,
+                0x4aca02c1b5dbb13a,
+                0xfebd3b5d8e952168,
+                0x236599b8d7f53e78,
+            ])),
+            Felt::new(BigInteger256([
+                0x86696a9b9a07eb82,
+                0xe21b42fd9d4c7996,
+                0x87c353e6ce1bb4b4,
+                0x10dadd6491da897c,
+            ])),
+            Felt::new(BigInteger256([
+                0x426de6480998c03a,
+                0x572b3f4452577c4e,
+                0x0918139e12b5d876,
+                0x1076ac85a537891c,
+            ])),
+            Felt::new(BigInteger256([
+                0xef3ab7e42b4e0830,
+                0xe4299f5789cb111b,
+                0xe319a731161f9568,
+                0x17cbac187566f6f8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7fa5bb329959437d,
+                0xbadeeb1bf0450abc,
+                0x638d8378d258529d,
+                0x180d814b5c37be8d,
+            ])),
+            Felt::new(BigInteger256([
+                0x5f0ded7ad5ac5e66,
+                0xfb195c03b2b1571d,
+                0xcbb4aaaffe524942,
+                0x1c49604cefd88c6c,
+            ])),
+            Felt::new(BigInteger256([
+                0x5a65cc2b1cc291d5,
+                0x3eaad352fd4187f5,
+                0x432caa5a6a648ad3,
+                0x0d4035c1ba7d2499,
+            ])),
+            Felt::new(BigInteger256([
+                0x61e35e5b6dace0aa,
+                0x64a35e7aff421dbc,
+                0x8690a706937197aa,
+                0x27b362409633a6ef,
+            ])),
+            Felt::new(BigInteger256([
+                0x96c021e4d3fe1855,
+                0x36bc43162d3a8406,
+                0x2cb5445f6acda37f,
+                0x033b9cbb8c01a5b3,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x9caf94d584f59d6e,
+                0x02f418a3fe9dd786,
+                0xa96801a3a46505fa,
+                0x241874499f13567c,
+            ])),
+            Felt::new(BigInteger256([
+                0x36f7054f5dae3e19,
+                0x5dd0654ff48fbd1a,
+                0x4d1d47a43b7d82ee,
+                0x19c93fee2db7ad71,
+            ])),
+            Felt::new(BigInteger256([
+                0xb2dbba61d13c5d5a,
+                0x8ab3738e6c9c39d3,
+                0x997583f908bd0484,
+                0x20769f3d14f302a2,
+            ])),
+            Felt::new(BigInteger256([
+                0x6732f1208adf9f8a,
+                0x0012f774372c9b17,
+                0x0c380e802f12ff4b,
+                0x2a3963c5bcaa39c4,
+            ])),
+            Felt::new(BigInteger256([
+                0x3bafdbf2c62bbc80,
+                0xcde0555e2430c08f,
+                0x02ab638bde59aacf,
+                0x0fdb47610e50a56d,
+            ])),
+            Felt::new(BigInteger256([
+                0xde630a84b7449a4e,
+                0x76009a1bc8481aae,
+                0xb1517947583dfd08,
+                0x0b1b03e9da9ac69c,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x492927c26db2e485,
+            0xbd30cd370e9f2576,
+            0x832b9e35c1079332,
+            0x12f05a9f6aa20471,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x263ee46ea637a3df,
+            0x821b42fd542ceba4,
+            0x776af2fe73c31908,
+            0x1da1be8503159073,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8471697ac9f0f59e,
+            0x26f5be0c945180d2,
+            0x55e9ca6530d1fd26,
+            0x0b7af7837b77708c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc7cf17c392d46c5a,
+            0x543dde2b4ced2cb9,
+            0xf0c1a8493d8e6902,
+            0x304f335be02bb78a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x27f202a38362f9c0,
+            0x534697463f85c359,
+            0x494f70fb51923f0b,
+            0x008512759abe14e0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x324689945f0f73b1,
+            0x6ace3a4d4aa27579,
+            0x7cdc4bbdcffd30f0,
+            0x2de7bd8eaee0601f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x56818072b1c130a8,
+            0x3f9184a92a9b5b88,
+            0x44e5850a190822b1,
+            0x20bd2fee2cee87b1,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x35518dc490e273f5,
+            0x9278b24a35117016,
+            0xfb785b4a724e5681,
+            0x16e371b7e8171041,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x456053176c88891b,
+            0x48d6c7da07434659,
+            0x95ea9661ae1b2b4f,
+            0x0622f38e8a906205,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0c42437a36f5d024,
+            0x7a2d151b4c0bbd2d,
+            0x048ccc3aca46b589,
+            0x2e70d8a99b799a1b,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x0e32dd1a96d2ef33,
+                0x32d0d365f15da59f,
+                0xac8def92dd0fb92d,
+                0x106d80d722bc0614,
+            ])),
+            Felt::new(BigInteger256([
+                0x855330762b193468,
+                0xd1f9e5b5939da60f,
+                0x1f12d3a977bcd96f,
+                0x10c8ca5ff2384645,
+            ])),
+            Felt::new(BigInteger256([
+                0x20d754751463a6de,
+                0x1219d55bfab67997,
+                0xb7c399a155828d1a,
+                0x09724bdf98891d1e,
+            ])),
+            Felt::new(BigInteger256([
+                0xd990c5face5d80ab,
+                0x0e50530df1ea855e,
+                0x9124fd28a53818df,
+                0x2ed6e6c041150e9a,
+            ])),
+            Felt::new(BigInteger256([
+                0xe3ae058e9b46c8e3,
+                0xcfa0a5347fc18b06,
+                0xd6f4e04c512199cd,
+                0x13a8e17d2073468b,
+            ])),
+            Felt::new(BigInteger256([
+                0x2dfd4bb7dadec2ee,
+                0x4dac80e9069450c5,
+                0xbadcf188d073c9d5,
+                0x1b591bc8ed49dcd6,
+            ])),
+            Felt::new(BigInteger256([
+                0x66938312e992b5a9,
+                0x5eaeea134e0db50c,
+                0xab937aca937553d1,
+                0x2896bad60c001c24,
+            ])),
+            Felt::new(BigInteger256([
+                0x7165dbd21b6285ed,
+                0xa16c67fc3192df16,
+                0x8bfe6223d403aca1,
+                0x283b77b566125452,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x12d1420f1f83c830,
+                0x36385a5ee2e062f5,
+                0xb8f90fbbeaa8723f,
+                0x074addafb74d6b33,
+            ])),
+            Felt::new(BigInteger256([
+                0x0e85da24ec2d16a6,
+                0xcb660549d4678b1b,
+                0x11ee6d7acdcb148b,
+                0x1e4884ed18ea7ea2,
+            ])),
+            Felt::new(BigInteger256([
+                0xf791f54fe01745b2,
+                0xd79c8ca0fcad773e,
+                0xc8c5e361cb3d46fc,
+                0x02cc17a1bc95e241,
+            ])),
+            Felt::new(BigInteger256([
+                0x9e378b213d102a3d,
+                0xca54143859c88fb5,
+                0x0661348e3d5ba649,
+                0x2fade8202aa045ec,
+            ])),
+            Felt::new(BigInteger256([
+                0xf86602eaf6ac63b9,
+                0xe9206ed3aecb05f7,
+                0x2b3c475c12179a80,
+                0x301e913a9755af6c,
+            ])),
+            Felt::new(BigInteger256([
+                0x1e9ca7135d4d66c8,
+                0x6a461b948e2cb0a1,
+                0xe656c757f6a5e093,
+                0x21e22969e5d84235,
+            ])),
+            Felt::new(BigInteger256([
+                0x8bae825bb6e24911,
+                0x67c8f65918c80a64,
+                0x420c067a47f8619d,
+                0x1559d9c9c1a5a97a,
+            ])),
+            Felt::new(BigInteger256([
+                0x968e8e51642e1fc2,
+                0x1b0ac28bc5c4d086,
+                0x379d0a6b0220f0b1,
+                0x02905cd28a9140e4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xdb8b2c3bb8c8f9a1,
+                0x55efe1df6d8a84d8,
+                0x599888800dcf997d,
+                0x18cc692defa80bc4,
+            ])),
+            Felt::new(BigInteger256([
+                0xc48bd4224638df75,
+                0xb1fe3e64375df9c1,
+                0x12b86d890c021f75,
+                0x098c6fad3ac827e3,
+            ])),
+            Felt::new(BigInteger256([
+                0x7c10933bc07da39a,
+                0x4bb1653ed30dc864,
+                0xf44f79314d128fd6,
+                0x266b5df75958d698,
+            ])),
+            Felt::new(BigInteger256([
+                0x7e4d0fc25fb7016e,
+                0x278b9f22ded5e50b,
+                0xd0873c89de60abbb,
+                0x2834ba55583c0186,
+            ])),
+            Felt::new(BigInteger256([
+                0x56d74beca85a882d,
+                0xd08a0d2b00923d60,
+                0xe630139dd5992d82,
+                0x10c5bdfe23ec81ea,
+            ])),
+            Felt::new(BigInteger256([
+                0xfb7d30ecd493a63b,
+                0xd10dd026a6a0c8ad,
+                0x0102b12acf0e9634,
+                0x1be8d02869f36b10,
+            ])),
+            Felt::new(BigInteger256([
+                0xe0842c2cde0b96ad,
+                0x90fc60e39540cf6a,
+                0xfb2e374b153d5d78,
+                0x204739cf071511ca,
+            ])),
+            Felt::new(BigInteger256([
+                0x99d2a64fb3910c3a,
+                0xf99483f19c6572cf,
+                0x32236b6dd0e2a766,
+                0x1e33af91d2cf1fef,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xba3b9377bd1782ac,
+                0x1dba0d40fedd6f0c,
+                0x4682b843bb5ec856,
+                0x0c5a97282f4d7afb,
+            ])),
+            Felt::new(BigInteger256([
+                0x832fb7c82c426bf9,
+                0xe6977d0af2c6b3f1,
+                0x79fa6b6ed859fa96,
+                0x20c85a41ade5c5db,
+            ])),
+            Felt::new(BigInteger256([
+                0x29b53f14df2cfc4d,
+                0x96a98e0b7aec4188,
+                0xd253ddfc7898944e,
+                0x1aea5fc85676a70e,
+            ])),
+            Felt::new(BigInteger256([
+                0x21da1e6f0fe18d11,
+                0xbb88f4c9e98550e1,
+                0xdb30472b85635a7d,
+                0x2b48662c40f9c3e1,
+            ])),
+            Felt::new(BigInteger256([
+                0x01dcaa3f128b5ee0,
+                0x790660e06d55fa54,
+                0x03098ca2785d6e03,
+                0x1405738343b57b1d,
+            ])),
+            Felt::new(BigInteger256([
+                0x366dc00545e26c43,
+                0x4285cbd4ccc23f9d,
+                0x6355efccf9858d3c,
+                0x21ecb166d8025e52,
+            ])),
+            Felt::new(BigInteger256([
+                0x3ab8bf6a39271bca,
+                0x7225ec48df637a36,
+                0x777debdd36e4359e,
+                0x0a995f1ed8128631,
+            ])),
+            Felt::new(BigInteger256([
+                0x50429dd7021148e2,
+                0x98b6c85d8be90fd6,
+                0xf770bdf7de76a740,
+                0x08ca855c40c7a68f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7265c94839957520,
+                0x71e02ce07d05415e,
+                0xe0808ed790b01c8b,
+                0x1490303ca820349e,
+            ])),
+            Felt::new(BigInteger256([
+                0x3dcf8f91d684a325,
+                0xb01566d1492239e8,
+                0x212d3276b59a1e9d,
+                0x220a7b2549cf3967,
+            ])),
+            Felt::new(BigInteger256([
+                0x94d5de6dc76d8677,
+                0x080b82107d607f99,
+                0xfbe2baae444b46dd,
+                0x17f378d978f00e1b,
+            ])),
+            Felt::new(BigInteger256([
+                0xf8d7e6a92efb9efc,
+                0x0d056fd2b86d0628,
+                0xcb6c0f1a92c63356,
+                0x033acc9f82e88b50,
+            ])),
+            Felt::new(BigInteger256([
+                0xc356c943ba2eee91,
+                0x82de50a7afbaa5c5,
+                0x0d728d4d588b4344,
+                0x212ccb8036396873,
+            ])),
+            Felt::new(BigInteger256([
+                0x7b0c279e471213c2,
+                0x636fb1c8b47cb2ea,
+                0xd7869d55e3b5c79e,
+                0x07876c770b4fd311,
+            ])),
+            Felt::new(BigInteger256([
+                0xd034bacfa2e661a5,
+                0xa2c5113f869c6e2b,
+                0xf790e1ca7d9c5866,
+                0x2ad0f91723d14cd6,
+            ])),
+            Felt::new(BigInteger256([
+                0x29de0fc80c24b6e4,
+                0x98dda6b41c534cb0,
+                0x53008f1a20a4992e,
+                0x2cf3dd0841d51e5d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xcc9e70ad2dd3e4c0,
+                0x14ab7227a63c860d,
+                0x224e2b1ef75ea031,
+                0x1052658d9bc25b8c,
+            ])),
+            Felt::new(BigInteger256([
+                0xe5fce6f2e050576d,
+                0xc9238656a8be85e4,
+                0x48c635baae8b760a,
+                0x168d6b33c49421c4,
+            ])),
+            Felt::new(BigInteger256([
+                0xb2d1382698a52eea,
+                0x5517bea91ad26dff,
+                0xa61b3ba5edb33d80,
+                0x2ec25372024d2959,
+            ])),
+            Felt::new(BigInteger256([
+                0x8ab8fa11222c243f,
+                0x6921d7a8b5a2ff00,
+                0x55ffc19f0f54279d,
+                0x2928858c30bed898,
+            ])),
+            Felt::new(BigInteger256([
+                0xbd29b4a90be68d8d,
+                0x13772c14a5426acf,
+                0x9093880602ff139f,
+                0x14e683be41c02bca,
+            ])),
+            Felt::new(BigInteger256([
+                0x63c04bc2d52f9c68,
+                0x056b14ec5c9ea38f,
+                0x36dcb7fb8d9b272a,
+                0x256e7f77666c8498,
+            ])),
+            Felt::new(BigInteger256([
+                0x558b894ec4ddb876,
+                0x6c982a3a13fe6fd0,
+                0xf52173ec5a0aeedf,
+                0x01d8498839e7b67e,
+            ])),
+            Felt::new(BigInteger256([
+                0xf63f14e560ff0e4c,
+                0x716eedd2e63485ae,
+                0x39270bb33a5a8efc,
+                0x14be3792984cab3f,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x874038b45a9d6050,
+                0xa0113bd8a1ddcd30,
+                0x122981edae814a62,
+                0x16136fd79cbfc11b,
+            ])),
+            Felt::new(BigInteger256([
+                0x2b96c4c5755c24c1,
+                0x91ad67e3dbacb5dd,
+                0x15cdcdfd234d7483,
+                0x24ea56051ffcac16,
+            ])),
+            Felt::new(BigInteger256([
+                0xb2eff0f65a8263c7,
+                0xc7dd03562201bf16,
+                0xacc7733f973befa1,
+                0x02455c85206d55d0,
+            ])),
+            Felt::new(BigInteger256([
+                0x7dfcc6aa20bb2a8f,
+                0xe99664ba6633caae,
+                0xc2fa6acab7b436f7,
+                0x085aa0c066307ea6,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x37093b0ea825bbe4,
+                0xd1e858f80b6dbaf2,
+                0xbacceb1199661f12,
+                0x189d27f410dfeb1c,
+            ])),
+            Felt::new(BigInteger256([
+                0xadf549103483db89,
+                0xd1d2cf1ddacb9c60,
+                0x0b17143f415c63c1,
+                0x0700129ad40597a1,
+            ])),
+            Felt::new(BigInteger256([
+                0x03e9a1c57d5e661e,
+                0x27516826927ae426,
+                0x69396cf619ab7c37,
+                0x016a9c17b3b067cf,
+            ])),
+            Felt::new(BigInteger256([
+                0x6572306b5b3f77fe,
+                0x5bdf52b5258f0f6b,
+                0x219f39e43104e5ec,
+                0x2d99bc98db348de2,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x49c4d40fb9865847,
+                0x5951d13e1da49508,
+                0xdfed4c270e2cf881,
+                0x278cab88befc331c,
+            ])),
+            Felt::new(BigInteger256([
+                0x761857ba910b93b5,
+                0x0465a1760243aedc,
+                0x75e35b39b379825a,
+                0x1faf1e6b378de4be,
+            ])),
+            Felt::new(BigInteger256([
+                0x32f786f19d35aa10,
+                0x9520fa6c646ccfd3,
+                0x30d5592476fc9a5d,
+                0x0d840bf4c272bcef,
+            ])),
+            Felt::new(BigInteger256([
+                0xc3c5ae59e57c6cbd,
+                0x747c49715489e15e,
+                0xf38c32833711c8e4,
+                0x1dbce6bc25a4a427,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5e50f3431d71b7dc,
+                0x7f3026c3add39ce4,
+                0x21178f14d63a5d70,
+                0x0323eaf4c91e4971,
+            ])),
+            Felt::new(BigInteger256([
+                0x90812da6bc1faf81,
+                0x1aa09e3d0d415bf1,
+                0xc4887eb806d5ab2c,
+                0x1e85015afcc3b344,
+            ])),
+            Felt::new(BigInteger256([
+                0x34c405d7bc39d2e4,
+                0x93fc499532f6409e,
+                0x0c81a6ad94bec5fa,
+                0x1cccd4a31f88c7a8,
+            ])),
+            Felt::new(BigInteger256([
+                0x3bd502cf1bb091f2,
+                0x748dc3e08dcddb8c,
+                0xfd02139dbf340998,
+                0x2ceb5f32fe53628e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xb8c724613bbd2c47,
+                0xff02975507aadae3,
+                0x42ba89065f366d1a,
+                0x22fef91edaa15466,
+            ])),
+            Felt::new(BigInteger256([
+                0x3f3dd0a594a28d44,
+                0x956bed65149f8dd5,
+                0x49ffac8f2e0b0a01,
+                0x16544e877798e045,
+            ])),
+            Felt::new(BigInteger256([
+                0x377bf5fb4b03926c,
+                0x9162446f2c7b398e,
+                0x3610c74f792dea1a,
+                0x22c0a578c356738d,
+            ])),
+            Felt::new(BigInteger256([
+                0x971c0b285c35e26a,
+                0x5e9136c6f77e5a91,
+                0xb46da2449478514c,
+                0x109e1a248ce8d316,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x4f08f070de725b74,
+                0x9f980a53086b847a,
+                0x9fb6ed1c167e8735,
+                0x133986787c962566,
+            ])),
+            Felt::new(BigInteger256([
+                0x58a2b318a3ed88be,
+                0x73061c40b2c4ad82,
+                0xe34a7d77cc70e05b,
+                0x2f31ed776a85fb4e,
+            ])),
+            Felt::new(BigInteger256([
+                0x85fe93e6ec0fb86f,
+                0xb7ed6790c8fad983,
+                0x495adf700fb72c98,
+                0x1cfb085cf62d0622,
+            ])),
+            Felt::new(BigInteger256([
+                0x69c61541c4bac621,
+                0xb0a9a9e30519479a,
+                0xaa82a6b745e17605,
+                0x007ce3574f9f574f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x67e8d3b99354777f,
+                0xcfedc7e3ec2d5fec,
+                0x24a443d425075f57,
+                0x27f5211bb54031cc,
+            ])),
+            Felt::new(BigInteger256([
+                0xae19556cedf7d9f1,
+                0xd840ebe5141c5baa,
+                0x8d425bb729f4d5ab,
+                0x17db1fe14cbb51ca,
+            ])),
+            Felt::new(BigInteger256([
+                0xd6ce2f97e6d5dfaf,
+                0xf9ca3893f6d77636,
+                0xbbea841b55dd4ced,
+                0x0cd8bfe557d4aeb0,
+            ])),
+            Felt::new(BigInteger256([
+                0x87e9e75b0c85e72f,
+                0xdcdaf05279e7628b,
+                0xab68c4dd2495460a,
+                0x100f05b65570db11,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xb45eb8cba3715655,
+                0x7896192ff39e32ef,
+                0xe17864c73674ce4b,
+                0x0f04112f1871ea3a,
+            ])),
+            Felt::new(BigInteger256([
+                0xea41b50e7e75ca43,
+                0x25eebff47bb7e0bc,
+                0xc1c8a6e77d65c6f6,
+                0x2147b23a6ca4b19a,
+            ])),
+            Felt::new(BigInteger256([
+                0x5af7b547ce7b73af,
+                0x5474aa6149cea8bd,
+                0x513566b0e0727aab,
+                0x1496c1aa87bc9112,
+            ])),
+            Felt::new(BigInteger256([
+                0x2604118701647e63,
+                0xc80ffdb0ba41a06a,
+                0xde423f7cc6b6e3db,
+                0x0f6f1f44a0d6568e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x44a2562f7196d766,
+                0xea0a10636c1292a9,
+                0x5f567e19e0085957,
+                0x16373c08d701f52b,
+            ])),
+            Felt::new(BigInteger256([
+                0x8132f5cb4f4f3262,
+                0x808514da163dc52b,
+                0xfaa883c063c911d6,
+                0x1b905cd2ca3a2301,
+            ])),
+            Felt::new(BigInteger256([
+                0xd669885c02c8838c,
+                0x22712c175c052dab,
+                0xf93466e948f71757,
+                0x00282e322d0974ac,
+            ])),
+            Felt::new(BigInteger256([
+                0x8a505f560a9d50df,
+                0x9c41e6db78ebf1ae,
+                0x6750876154e3e874,
+                0x246559ea807c7d67,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xfc159f7db4cb9722,
+                0x1add16f18e2beec2,
+                0x020d58a9c4c6110a,
+                0x2443359dcf52a0ea,
+            ])),
+            Felt::new(BigInteger256([
+                0x99833a20ddb37ff6,
+                0x8656b630b950d727,
+                0x60579e48f57cb012,
+                0x244f699b41bd802b,
+            ])),
+            Felt::new(BigInteger256([
+                0x88594f353add1096,
+                0x6d8b075e8c3af89e,
+                0xc2f50f0b6da8ed93,
+                0x1b83a1f9841310d8,
+            ])),
+            Felt::new(BigInteger256([
+                0x9ad3609556a1033e,
+                0xa5b8e1ab3c1e0b93,
+                0xab15a6869fee61bc,
+                0x085fa8f4574a49eb,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xfdcfc10629ccbd59,
+                0x31d3cd33b9556c9e,
+                0x1685602f502a9e9b,
+                0x156fba1d442c7586,
+            ])),
+            Felt::new(BigInteger256([
+                0xabc2bd1473ab62a0,
+                0x2b85b2812c11e8a3,
+                0x27049fe68c76c4fc,
+                0x02e01b5ce52504f1,
+            ])),
+            Felt::new(BigInteger256([
+                0x85341d435cbd89bb,
+                0x21eaded0ed68db6e,
+                0x904349d6f5b825df,
+                0x03ecacd8344f5ad9,
+            ])),
+            Felt::new(BigInteger256([
+                0x8bdbef9894484cb0,
+                0xf2182f405aa3702c,
+                0xfc093ce2b8a25c69,
+                0x17534760872aa4f1,
+            ])),
+            Felt::new(BigInteger256([
+                0xcbe124bebae15906,
+                0x1083a4f79973955e,
+                0xb10eef7ba326b62e,
+                0x2c9317c0eccd84a6,
+            ])),
+            Felt::new(BigInteger256([
+                0x25e85436d7f36567,
+                0x68b20b3888ad7228,
+                0xd1da93f8bc7bf780,
+                0x0e342541f9e7d647,
+            ])),
+            Felt::new(BigInteger256([
+                0x80d71f5ff9247d5a,
+                0xc7a975ccce56033f,
+                0xd264fef4daa44a02,
+                0x25902981944afce5,
+            ])),
+            Felt::new(BigInteger256([
+                0xe657e6c61eab6c19,
+                0x356eabaad2fa04f7,
+                0xa2502aa8177bfb64,
+                0x266fbe5a7c845fe1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x6c679615ecc86d87,
+                0x4972fa17d688dbed,
+                0xe19f9bf0cb02deee,
+                0x1f0deb28009ba0c5,
+            ])),
+            Felt::new(BigInteger256([
+                0xe0f82e0f3727f413,
+                0xce924af5e17cf569,
+                0xaa0e03fc65336d63,
+                0x009aa81d0625ce8e,
+            ])),
+            Felt::new(BigInteger256([
+                0x996b141f4b7e91b8,
+                0x04fd36ab948d439a,
+                0xf06ad866d1c61461,
+                0x02403f8046820f4e,
+            ])),
+            Felt::new(BigInteger256([
+                0xa0339f73f915add2,
+                0xd86f6857dc71b4fd,
+                0x87b5e562bcdaa95d,
+                0x2dea553aa8a800f3,
+            ])),
+            Felt::new(BigInteger256([
+                0x63c1b62e453ec16d,
+                0x3e46d706f1c19b34,
+                0x13696c6552d56bab,
+                0x1fcfa45c18fb1e89,
+            ])),
+            Felt::new(BigInteger256([
+                0x4f361c562ae2e805,
+                0xaf7fed09a99a01f5,
+                0x113dcf36b95ca58b,
+                0x2476922d3a3ac01f,
+            ])),
+            Felt::new(BigInteger256([
+                0x54646da12c44f047,
+                0xbf461a3601964dff,
+                0x273681a8406b6328,
+                0x21679a5a79045c5b,
+            ])),
+            Felt::new(BigInteger256([
+                0x87f2eebb0d7bf9af,
+                0x91878446d32b29da,
+                0x7d4c3ba390119032,
+                0x165c87b5a901701d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc9811937955e032f,
+                0x8161a0363f735129,
+                0xfbacddeb44ee8519,
+                0x251275aa37e9602f,
+            ])),
+            Felt::new(BigInteger256([
+                0xe6c6f3631aa89658,
+                0x98660459824a3ee8,
+                0x175b222fb17a5857,
+                0x153889f9c8aa05ef,
+            ])),
+            Felt::new(BigInteger256([
+                0x3a2d581e7b963a8a,
+                0xb394ad947fa2e24f,
+                0xb65e757fdbac5dd5,
+                0x0428605a13515bdf,
+            ])),
+            Felt::new(BigInteger256([
+                0xa915a2c6e2bb0d6a,
+                0xa453c92383359b67,
+                0xb1afec1a7769b235,
+                0x2b5447cb860978b9,
+            ])),
+            Felt::new(BigInteger256([
+                0xfaf1db6b8b2626be,
+                0xc68fc9b6621aef9f,
+                0x036d9ddcf0f0c987,
+                0x19d4dc94736f5341,
+            ])),
+            Felt::new(BigInteger256([
+                0xbde426bd7be9c976,
+                0xea81750cb58c5a31,
+                0x8bb5e2a2f0468046,
+                0x2ed6cf3bc5803751,
+            ])),
+            Felt::new(BigInteger256([
+                0xae00295220835e63,
+                0xaa793361bd8a1fea,
+                0x9956a23362bec19b,
+                0x0aab627410d6d65e,
+            ])),
+            Felt::new(BigInteger256([
+                0x60d377e549e93311,
+                0x71b4357655004365,
+                0x9595c1f700f133a8,
+                0x25ee6f480dff251d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x32eddb76a2645abc,
+                0x8975598f4bbd50cb,
+                0xbc3c8305c5a117b9,
+                0x083aad0209940073,
+            ])),
+            Felt::new(BigInteger256([
+                0xd7c21c76e6ef2dbd,
+                0x70466944e957b171,
+                0x6def279b7d583616,
+                0x2cf1c3a52a26ee1d,
+            ])),
+            Felt::new(BigInteger256([
+                0xbe2b7355d52c8c78,
+                0x859883e64f512930,
+                0x6692bc4402bfd934,
+                0x252d5fa70735b3dc,
+            ])),
+            Felt::new(BigInteger256([
+                0x898b6f87b2a7a48c,
+                0x2406ac0d47ceb638,
+                0xfbc933163bfed84d,
+                0x20c8d5bd2b648343,
+            ])),
+            Felt::new(BigInteger256([
+                0xcc249ae270d48861,
+                0x1c82f9568e057e8d,
+                0xfff0e68c88312bc9,
+                0x173827a60e004e21,
+            ])),
+            Felt::new(BigInteger256([
+                0xe977b33afc7fc6af,
+                0xf93d582a5e68761e,
+                0x8dc8d18c97f96de4,
+                0x27303d4aba05a2cf,
+            ])),
+            Felt::new(BigInteger256([
+                0x373b4911aabb6c17,
+                0x30e9d470d9444fd0,
+                0xc9f8fd3e9062a9d4,
+                0x0647a7bd84f4d99e,
+            ])),
+            Felt::new(BigInteger256([
+                0xf02012ed7b171b97,
+                0x49691e97b2a48168,
+                0xcbfe995e18df6743,
+                0x02b147a988c0cf03,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x64affcbccdb04282,
+                0x6666734868cbc81f,
+                0x7e1774a2ccb40461,
+                0x15947d73a98b17ef,
+            ])),
+            Felt::new(BigInteger256([
+                0x2bca06b829ef6bbf,
+                0x7f0961cebb36dbfe,
+                0xc21f84348e54f121,
+                0x2f353388d490b8a7,
+            ])),
+            Felt::new(BigInteger256([
+                0x5cc5ea7391b8535b,
+                0x530f6ed166cf40dd,
+                0x0f624aab32aa9e74,
+                0x1057e0efdb966bcb,
+            ])),
+            Felt::new(BigInteger256([
+                0x8c94763ef2903eeb,
+                0xfd041f1de6530938,
+                0x9256f8a9e734b274,
+                0x2cd4f86a61b3f73b,
+            ])),
+            Felt::new(BigInteger256([
+                0xc08acd39ad2a8360,
+                0x4395e88bcbea7872,
+                0x8bec588b208c73eb,
+                0x2137ba1b799c4c89,
+            ])),
+            Felt::new(BigInteger256([
+                0x360b819932823313,
+                0xc9bbc69b13f7b964,
+                0xb5be18b9d25b8827,
+                0x11bd0b328bf4059a,
+            ])),
+            Felt::new(BigInteger256([
+                0xbdbae172cde709dd,
+                0xdbf0a5e4b58bf240,
+                0x54cc3ccf2f30212f,
+                0x11126ea3c62cccf6,
+            ])),
+            Felt::new(BigInteger256([
+                0x402c17ab5c39699c,
+                0x4fb4032eababd43e,
+                0x61f6248d87bd46bd,
+                0x00e88b695af97d21,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7a5077e7ffdcd8a8,
+                0x0b94d49d1410c628,
+                0x4c804677519da409,
+                0x050261a0e1907302,
+            ])),
+            Felt::new(BigInteger256([
+                0x6c684a221e7c1c19,
+                0x984915cea9397391,
+                0x04592f1840ff537e,
+                0x1d99f920ab6d5731,
+            ])),
+            Felt::new(BigInteger256([
+                0xfbb9d45bd4a5297f,
+                0xb63ed05cef4ff768,
+                0x265c949ab63e14a2,
+                0x035511b8f45f06a5,
+            ])),
+            Felt::new(BigInteger256([
+                0xbd2ad4b58ab95371,
+                0xad46cfc14084ba86,
+                0xf3777348103d673a,
+                0x08d4e3c8e8754fc8,
+            ])),
+            Felt::new(BigInteger256([
+                0x944243449737d6bb,
+                0x59fe9620c93248ed,
+                0xa5fddd581eedf6cf,
+                0x2491ebfeeb5f6830,
+            ])),
+            Felt::new(BigInteger256([
+                0xb691b5ab5fb2f758,
+                0xcaede92c85139552,
+                0x99e6585fdd31ee73,
+                0x16a40b7715ede39f,
+            ])),
+            Felt::new(BigInteger256([
+                0x012d6f82cdda03d7,
+                0x1715bd328a862034,
+                0xf0b6f1fddd4f0e3f,
+                0x2c337b38a83b9f7d,
+            ])),
+            Felt::new(BigInteger256([
+                0x948ade89bb570f0f,
+                0x10872fcf980464e6,
+                0x0c6b2ace29ebcfd7,
+                0x2a05ca6d880b2a87,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x9fe1bf865b371366,
+            0xbafe23848c069c41,
+            0xdf68e83e9f3d8d22,
+            0x153974af6228a17e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0a7860bbc5477588,
+            0xfeb7faa92489da53,
+            0x986c6074a3f18c9a,
+            0x1e3d44cc9298d845,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2ed675eded4402c7,
+            0x16ece600e56c13f4,
+            0x0991a79b6cb22d63,
+            0x11b41fbf1c3e389f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd7a73e68d17bcc31,
+            0x51f301e5886633dd,
+            0x7e833cab2e002775,
+            0x0a988340215ae699,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x5ad4f33cbd34b053,
+            0xedad94f1ee539992,
+            0xadb37e1f46704ad5,
+            0x1b52bfc0d4871459,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xaa23452af65e1ca3,
+            0x3d865f2e4f3bf13c,
+            0x5de00c7e00c6ddcf,
+            0x17436132a78f66a3,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4132b32a04e74afb,
+            0x73b805509e456d46,
+            0xd2b2983ce4f9047c,
+            0x2e5a9ab7e48fa4d8,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x6110b8fa2e48bb73,
+            0x275780a370267e10,
+            0x68ac7ff745986256,
+            0x11a39e55a70ecd98,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2526c64a208f059d,
+            0x524c3ae5a4efd859,
+            0xba9fa7636c49f895,
+            0x177fc4c825fa63af,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x230fee159b2ea546,
+            0x7e6ad186a385b116,
+            0x61efd9051c8617dc,
+            0x1d8172047030ab27,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger256([
+                0x4c777ebb16304c4e,
+                0x0f6e1c0906573562,
+                0x31425639233d1d70,
+                0x14e968b832014dd8,
+            ])),
+            Felt::new(BigInteger256([
+                0x3a57ab7e513eb50b,
+                0xb5dce187accb1ec0,
+                0xf2d58f2018326e71,
+                0x202b7107a5dcbd6c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc36f2e452faa5895,
+                0x1fe003d854d83ee7,
+                0x15f06589b8e84ffb,
+                0x151d28bc698463a5,
+            ])),
+            Felt::new(BigInteger256([
+                0x6184b56a458f3563,
+                0x970b039841cc3e77,
+                0x08476b52ef6795db,
+                0x014c2a2c35288bfd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3cbd16a495eac5aa,
+                0xe9f224596f9e9947,
+                0x24ea24c7160e9cc1,
+                0x13ee6bbc2a2a94d2,
+            ])),
+            Felt::new(BigInteger256([
+                0x827314f2f414df9f,
+                0x486a8c187d236730,
+                0xda2740aa52557785,
+                0x2a73924758bfab95,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe1c2dfbf4bde14d0,
+                0x7b02e5441aba6d76,
+                0x5c1159a4183a9b6a,
+                0x152b7da0e85789bd,
+            ])),
+            Felt::new(BigInteger256([
+                0x580eb8bcd7bb593e,
+                0x7e707c77d4db2836,
+                0x4db0ed113781a083,
+                0x05b19eb6acadf3ab,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xa4dcde3d9b40f94c,
+            0xd2fe6c07d0353b30,
+            0xc9a6b7b25eb222b6,
+            0x2cabd0210d550654,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xaeb18bf924f7e1c7,
+            0x9661396001338a13,
+            0xe8d54081cfa22680,
+            0x06e96290b24b5c44,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2b80a97a3fbbdf7d,
+            0x6a3afea82fb784c7,
+            0x0bde8b6f2634bc64,
+            0x01e119b2a282ebff,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x17aed35fd3db6f7a,
+            0x2ecb3aa412aca346,
+            0x1c9341905629226b,
+            0x1571d903c679accb,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa6c34b1ef0169ede,
+            0x8a421fc5a0dfaddf,
+            0x2bffe7819da99e52,
+            0x1b0ad84c47106037,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7bdc232d4e9ae104,
+            0xd168b0b21db24319,
+            0xf90fa0b4a80e7065,
+            0x12c5008f7de0a70e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x3cf84741460d51d6,
+            0x09033e8114f1fe11,
+            0xda997216c9d0bcde,
+            0x1f25637d08688975,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x40cbfcc0b0572e3b,
+            0x328bb21510daa449,
+            0xb99616b0d2a7138c,
+            0x14acc436ba86cc63,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,1711 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcd60fdc935c6709b,
+                    0xe02846a4ff0aeedb,
+                    0xe0265d3e83310bbb,
+                    0x07dca2db95c60173,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba6b8676b8cf122b,
+                    0xe2a90fc96dc4ffa0,
+                    0xe39a828e4dc85e0b,
+                    0x08f5dbbdb105b8a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x179d9283410eb1b9,
+                    0x807ebc5ecbf25eba,
+                    0x1288fa1fbf6d1609,
+                    0x03ce0445943c7a27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0f2b9aa565cd1af7,
+                    0xc47f2ba42223419d,
+                    0xd253faaa830fc8a9,
+                    0x2879c5d658559549,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa26165e05c2df325,
+                    0xa8a64e998725dd26,
+                    0x6b940f649e9f95f2,
+                    0x19bfa6f373c72dec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdeca541e574a1b49,
+                    0xf02c1bea6c5244a9,
+                    0x1c377d9854412ca9,
+                    0x21e187e5ff86617d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd253e0306551299e,
+                    0x2c5ec3287c21bac6,
+                    0x1df03cc81a0bd174,
+                    0x0822a4697b1a64be,
+                ])),
+                Felt::new(BigInteger256([
+                    0x59f383d9947bfc5d,
+                    0xf34f1ead38fd477c,
+                    0x7e74f815487ec07b,
+                    0x1be3ff7f66d6e88d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf8610e32d32b1095,
+                    0xce26887d70083172,
+                    0x096a67b18430ccde,
+                    0x0a0612edb164f1bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd45ee86cdadfadf,
+                    0xa4331e9efb063047,
+                    0x26d0598f3d59bd21,
+                    0x1e38428c69eece1d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab6e76e9a3f94658,
+                    0x0b00eef3a1c55f56,
+                    0x1e80c8e6d0a36d87,
+                    0x00a0802eb70760ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8f49c620e0529f28,
+                    0xdcca32ddf9a475ff,
+                    0x6390f90b14e8e6a1,
+                    0x04b53adbe4482780,
+                ])),
+                Felt::new(BigInteger256([
+                    0x991b1b6a8e9ce067,
+                    0x67551c5abd42a15f,
+                    0x1aa2b7d04ce17334,
+                    0x207525d1e8d3c851,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde0c36c6e0e97ea0,
+                    0x7c4517f1149738fc,
+                    0x6130d030325b1c95,
+                    0x2dc529eab4c24268,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99f59e7fbee17b75,
+                    0xb31688553f0db7ee,
+                    0xa415fc59476b83ab,
+                    0x15218931482d66f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x79c62710e2b883b6,
+                    0x9930265700db6f4c,
+                    0xc4959d756f8a4c0d,
+                    0x13ddec6056d8f04a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe85707510d65c6fd,
+                    0x77088842d8c69da8,
+                    0xf3a841283b3c8c15,
+                    0x2176dd96d9d1eec0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86121477df911d33,
+                    0xb9da0c256b54bc55,
+                    0x9136b6f460b06919,
+                    0x26588bb991bf0c7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9996034ada46a846,
+                    0x957ad0257d4faa25,
+                    0x4834c30451429688,
+                    0x19348cfdf0cffa4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacef861eb00080d0,
+                    0x31cfe025baabd28f,
+                    0x5af04a85412cc1c0,
+                    0x21e6410c2643263b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe19b50eabf089187,
+                    0xcb911893918f1856,
+                    0xf580ad8293549137,
+                    0x23bb6ff47a901a22,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18afa75cbfc170cc,
+                    0x9abe58b4ba8f33b7,
+                    0x2a30dc019f36af78,
+                    0x2d8040d9f5ac1a5d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5c9545e33f8c9ffd,
+                    0xd52f162f8fb17a2d,
+                    0x96968bfd131011dc,
+                    0x2736fe5dded703e5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcb63941f533059ab,
+                    0x8ef26ce00263e7be,
+                    0x5aebd1169a3ed1b1,
+                    0x0a07ce8f72df231b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x37cec52be7f0dfe2,
+                    0xfd941d6b65d1883e,
+                    0x647fa4c059e93d38,
+                    0x20ca57ac6582555a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb751db619b9334bf,
+                    0x931b1ed910276d18,
+                    0xf37639ab08736613,
+                    0x2075d34c79759d0b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b6c8a45f6adb179,
+                    0x34b68177c2a9f2e3,
+                    0xc73c4bdbb1a18c78,
+                    0x10b3de1d8f91a479,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0d9ec64072cdf08,
+                    0xc180260b5803135d,
+                    0x5f7fa9754ab8b922,
+                    0x0b049338247e26f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0f87654d2e35d2b9,
+                    0xbeb80403cfbb20f8,
+                    0x0ced5c5e2946ef4d,
+                    0x2ef71e22e378cc20,
+                ])),
+                Felt::new(BigInteger256([
+                    0x46ad290b85fd2775,
+                    0x2d66f4ac014d2638,
+                    0x5efda8db62fc8d8b,
+                    0x1a0ba7b522119ca4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe5b5e75689e8e45,
+                    0x369c6ee2646130d5,
+                    0xa429a3ae6d60d8ca,
+                    0x22e59a9200d0728f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2e45c1f301f646e2,
+                    0xb15c02f223a33b43,
+                    0xc238da0dec45afa7,
+                    0x12b72c0f58e42fb1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x96cd8c6e66419154,
+                    0x5bd33923f6169a41,
+                    0xfd955ebb2982c973,
+                    0x01b1b65bda3b6f3c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x208a391d2e1a3781,
+                    0x3b9856cf0c4d955d,
+                    0xcb221d30a8f16689,
+                    0x287ead5e4119754b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x95270a752b106fc2,
+                    0xcf73d7fc88de0013,
+                    0xf827c8979e41df5a,
+                    0x13505aa4b5edc0d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd9188a591da8ec39,
+                    0x1d8e08bcdb917741,
+                    0x86f9b3bc5ba168e9,
+                    0x1f460bcaf009ca8f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5cc3f0408164f471,
+                    0x95ba852b07daf333,
+                    0xc7619fe1b4890f24,
+                    0x17fa0c167ac79144,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc52b0f8e3d34dceb,
+                    0xfbbcc7b5e18329df,
+                    0x36ed697cdc2a5e04,
+                    0x16b3e2e37e7d1853,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe15521375342f458,
+                    0x21d307234873b19a,
+                    0xa303c6d9c7aaf961,
+                    0x300eae35d5e8a021,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d770736b1dbef9f,
+                    0x4d6f73ad3c7a68ba,
+                    0xb6df659e8f9ec430,
+                    0x153c6bb599034a49,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x91bb5a5ab6c5a977,
+                    0x3f9bc3b6f639a121,
+                    0x4cdd9390181e78bc,
+                    0x11d9f63a719046fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xedefaf10c442ec86,
+                    0x8e9902ebed7fc87d,
+                    0xdc7996eee8d34fd8,
+                    0x2cc11f05c5daade5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x73c9617bcd0c67b0,
+                    0x9095d47c8498bec8,
+                    0x7855c85413bb1f9a,
+                    0x06b79bf76b42a55c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3fa2d9d579dec67a,
+                    0xa818db33d99beb68,
+                    0xc290db67865684e9,
+                    0x24d67440fbe80357,
+                ])),
+                Felt::new(BigInteger256([
+                    0xebed0967a95fb8c8,
+                    0xfd2f398f934135b7,
+                    0xe905c4db9e2448c3,
+                    0x032cc22f8ca41cda,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbec74b978f85b9f6,
+                    0x21a815a612fe8416,
+                    0x2286129d8b1144c6,
+                    0x17329c3133bc04b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1484d53a794be9e3,
+                    0x036af2df89d3d615,
+                    0x3a7c1ea3b918f898,
+                    0x21ec699c0dc093ca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0526664263fbac76,
+                    0x3d84c31adcda8122,
+                    0x03ce96cee64f0a35,
+                    0x2de61cb12c4dbf04,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd745397409999999,
+                    0xb4ada7d483c3efa8,
+                    0xc49ca2f8e57f3161,
+                    0x162a3754ac156cb3,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb78f05bd94ca546,
+                    0xc9a51beac27654c8,
+                    0x082dd2c927560408,
+                    0x0eea1f600f3a75ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefc9888f2268d41d,
+                    0x561d320d44cca494,
+                    0xf1327952fe7f3bb4,
+                    0x25cf0a5b6646ea17,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fcd565fbfa6800c,
+                    0xf436172438995a7a,
+                    0xbbb735d55295eee2,
+                    0x2cff83c987466038,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x019b87935b411194,
+                    0x9cedf1a233f237e0,
+                    0x3e2dce654e42214e,
+                    0x1c02a6b6d2e7eddb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0686f609999979,
+                    0xae309ec54c95dd80,
+                    0xba93ec28b55425bc,
+                    0x09a068f885e1677c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974bc177a0000006,
+                    0xf13771b2da58a367,
+                    0x51e1a2470908122e,
+                    0x2259d6b14729c0fa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xab8b940210826b95,
+                    0x8b707af404016048,
+                    0x4bd30251167ef6c2,
+                    0x0b9b9c7527c0f02a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd922c6d80cefeed0,
+                    0x2848bf006ce6eec4,
+                    0xfb2b2d9ae70dc673,
+                    0x1d095f748bbaad0a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0725ec060f0ac5cb,
+                    0x5724f7ae2cf45760,
+                    0x241dfe1f6c44b751,
+                    0x2e40415748728824,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2e61fc3fab5d8732,
+                    0x3412d2f86a79b250,
+                    0xa51d0b827dcea0cb,
+                    0x1936e9a3a6482697,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe40b4b26b60a6e6e,
+                    0xa4537b3bd711c0ea,
+                    0x186544352e5d1ef1,
+                    0x1a7c9a2e22836f79,
+                ])),
+                Felt::new(BigInteger256([
+                    0x393185fa4d6df13d,
+                    0xb2cadf0bf74f0179,
+                    0xda90b13532cbaf4c,
+                    0x2b711700991bfda3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14d95f40ab18d781,
+                    0x64b495e560fc094f,
+                    0x42c386212f2c8f08,
+                    0x07b2cc1b1b09eb18,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc412a7ad8fa70d6d,
+                    0x6a813fbacc89ebe0,
+                    0x1bce7fa31a0d39a8,
+                    0x0e4e6476b7fe13a7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2cb150fd7e49044f,
+                    0x54f0684803ef0849,
+                    0x5f5f5c7f6180fc44,
+                    0x2eefb87cd744aae4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4b6d45175a4097dd,
+                    0xd2b42a82f0caa01c,
+                    0xe9da3ddf7ba7c98d,
+                    0x041b134ffbdf1967,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0f689d80150b097c,
+                    0x53a8065d052891e1,
+                    0x81e430e3dd055980,
+                    0x2463dab6f057b18c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a481ceecf0e1cb7,
+                    0x08416f96ffdc7a48,
+                    0x3cbebf48f386b325,
+                    0x0bb5741805ef6deb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x056d675e8ed7b288,
+                    0x4c2e0c5df61eab07,
+                    0x3c6693d93546eda1,
+                    0x2f0410f7e7740b26,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfbc18a6d60b3a1b1,
+                    0x90787369bca2dc0f,
+                    0x522c95c8d0a355de,
+                    0x082701ce9d7edc71,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd1e785fb8ef1d13,
+                    0xb70aad4270259d75,
+                    0xdc0ef9df3741bd0b,
+                    0x1e19825c5ec9338a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1a4ade76ade90134,
+                    0xb295460aca386d79,
+                    0xb286a66afaa3c2f8,
+                    0x217f8c40a33ccc49,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa98d30f8335b4890,
+                    0xeed00c5bdc742b6e,
+                    0xf1adddc2faf2ad0c,
+                    0x0a8c94922104c052,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18f6b8edc52430bc,
+                    0x560b7fbead34eef2,
+                    0x87f01e0d79a64d5d,
+                    0x0ceeda59af2969d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaac8133d859451b8,
+                    0x642d9d0e71907d89,
+                    0xda7b1300f75aaf7e,
+                    0x1581b595cd0b44cd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ffab94a393fab34,
+                    0x528a3e80082be215,
+                    0x727e6246ff40b5c0,
+                    0x0ab3848c0b41e5a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x520c51090925f2cb,
+                    0xd7867bf263a71f93,
+                    0xc7881a998b50da69,
+                    0x1a518019fdeae56e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ecc37221dc58e19,
+                    0x99a5304a937f5dd1,
+                    0xec93db67f5098314,
+                    0x2e9a17e02d57033e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa7aaea2b57c669c3,
+                    0x297b5d2c7fc2ab39,
+                    0x5b75c7e0d03d30cf,
+                    0x0b4c28cd9938ab11,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd25c9a7f2ad4365c,
+                    0xa17d84254910443d,
+                    0xe1663111c9b0391e,
+                    0x231ad06d4d5614e3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x427ddc6ac2df60a9,
+                    0x11158d8996041d2f,
+                    0x7c8514ee59778149,
+                    0x06c01a0f82785527,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ae32b528fdb3138,
+                    0x6a8d6a82c207ffde,
+                    0xc70b1f200d0486a2,
+                    0x2305df40c16227dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb367c1c4d3fc9e3a,
+                    0x18049e112d5d8f05,
+                    0xa565586815f7516a,
+                    0x06a13ab3c13bd442,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc96a6e0e60a57e1,
+                    0x508eb2d2503bd586,
+                    0xad9af57ee59781b5,
+                    0x1ca56fef546077b8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5afdf42bb23d601f,
+                    0x9c00ca42af0d25c2,
+                    0xd97e0c7528cfbec5,
+                    0x24861464384fd7fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7f476c01450f80a7,
+                    0xcf28c4f49c3b7263,
+                    0x5a21c6d616dcd52f,
+                    0x1fe23ec0588389f9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37b6bf13232a6e5a,
+                    0x8471aedc007bf7f6,
+                    0xa470a40b75d9f81c,
+                    0x064b1cbf6de313b3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2cc30b0a96df7011,
+                    0xf34e9f9a523ddeb6,
+                    0x9f40275bf7349688,
+                    0x1fca887232144b41,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfda6dca5179c2e6d,
+                    0xfbda4940f2848565,
+                    0x55c3f4882787f8d5,
+                    0x1ad2e1634edef2cd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x209039cd857c212c,
+                    0x9e23c89a5237f388,
+                    0x30faffda156da72e,
+                    0x27ea95b79e0d6373,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd1009d1af5e7992a,
+                    0x2c1cbaf606f76b53,
+                    0x6945280dfe3ea764,
+                    0x225c209a9664a263,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6cbb51e6d384179,
+                    0x29d6c3f3ae87c9a7,
+                    0x643c3da75ad31569,
+                    0x2eb42f19d39459f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1e850f3ba4bc580,
+                    0x84bd6b1819004f94,
+                    0x95f446790da975bf,
+                    0x2a3700e5bd12cbe6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7a384c7214eec872,
+                    0x9d3ea0252c9a7ef9,
+                    0x97fdab0e02b14f82,
+                    0x2f1f6cbfd40600bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1a2f9fb92c2aa75,
+                    0x95dd35366077ae7e,
+                    0xef3d4b7615a6baee,
+                    0x1f9e8b29aa714a56,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bee54a801850ffa,
+                    0x453e81215c6845bc,
+                    0x399255392f113cb1,
+                    0x05d788dc06f7e874,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4b7fc5474a327fae,
+                    0xcc0c4ddaaec1f213,
+                    0x77a69ee17f61761b,
+                    0x2c088757456fa5cf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b879c8856377cbb,
+                    0x5aa8b91667bc40b8,
+                    0x8ad83ad844a420ec,
+                    0x001f43ffb05a51b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd1a3dc03cd1a68bd,
+                    0x834ce25e0236fd9e,
+                    0xbbedeba4f4c6f496,
+                    0x2767a4ef1a150f2f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1d5bdd8fe09b232a,
+                    0x67ec6890aa82604f,
+                    0x4fbb20b248dffdee,
+                    0x1f6edf45e33d4969,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed696d9d9fa9f8d6,
+                    0x6f8c56cf60739f7f,
+                    0x5b844b59046860a4,
+                    0x11fd02604bfbeff2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a120fe625d910f4,
+                    0xc67054e35fc1da1d,
+                    0x01e86f38ed96a166,
+                    0x11220e3e8ac7505d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a7f3c388f71ee6d,
+                    0x50c94c0f49e7f923,
+                    0xecb22618f861c924,
+                    0x234fa68e27cc6480,
+                ])),
+                Felt::new(BigInteger256([
+                    0x185a64148eee5e8a,
+                    0x512fa904d161d851,
+                    0xfdeeb50d3226b5f4,
+                    0x2ba02b906a3aaf6b,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xee6fd921938352e5,
+                    0xe4b51229020ff882,
+                    0x71ed480ae9476363,
+                    0x20a958d9bde5c087,
+                ])),
+                Felt::new(BigInteger256([
+                    0xca8f0fa81136855d,
+                    0xb0eb9cc78b04c0be,
+                    0xcb19adbfab8d690e,
+                    0x191582c5a0a182ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd7ec63bd291e00c3,
+                    0xdc4877432226dab1,
+                    0xfd15465137fd309d,
+                    0x021cb8c9b0475933,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea3dfd0ed4bc3ea1,
+                    0xd887b93e4fadd4f4,
+                    0x39178dd375b8751f,
+                    0x2ea744e57d2d3f78,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1380ad1c365bfc6d,
+                    0x0b2db6fa91aafaed,
+                    0x2f2e70a4651358b9,
+                    0x1541e723d3d89b53,
+                ])),
+                Felt::new(BigInteger256([
+                    0x317fa674c9f0b046,
+                    0xda3ca6964d4cd289,
+                    0xedf6693782b09860,
+                    0x00c6366aae997ee6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe96c3a31ffe7e78,
+                    0x0131a37dfe77d0b1,
+                    0xc334a5e9f8a271a6,
+                    0x19d9d5a4ea6ce4c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba1d782fa4e9fcaf,
+                    0xe8469e009c3a8a0d,
+                    0x3bf781c79b664811,
+                    0x260ad639f03df749,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6fb9b965bf548261,
+                    0x0c85bccfe69e75a5,
+                    0x501b8d4a0929ec7a,
+                    0x2612b51f9912d3e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14acd4ad9eb6182d,
+                    0xd17aeff539bb76a1,
+                    0x40872a6d26ceb341,
+                    0x0b82b66db1cc36fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ad4ef6476f97af8,
+                    0x6109d4e5621cf349,
+                    0xe1033b4857aa1e16,
+                    0x2897592c56c84b28,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f5ba8e88690cf99,
+                    0xf6c88074f167f7b7,
+                    0xec83574362d26247,
+                    0x235d56f8f0ed7dc2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8262a82b4ea83da,
+                    0x2bbf0a47fdcffbc6,
+                    0xe5b4e0bf8aab7231,
+                    0x06bc6984331a6cd3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7dc21e2714c07fe3,
+                    0x084bc40faa7aa244,
+                    0xe08792d2169125a8,
+                    0x1d4f4c356c31804e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5c124c1a5e9c9c87,
+                    0xa79cdcc7bbafb68e,
+                    0xd558915de24d5dda,
+                    0x04d0069b66779188,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5049ad85dd11f868,
+                    0xc3b0aa649bb41ee0,
+                    0xde78a6ddef31a8d8,
+                    0x1bbc98bc4a627717,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2c6831b52fe31be0,
+                    0x62212f932a08e75b,
+                    0x53cb1bec5b88c49f,
+                    0x01282f80e226f6fd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e6f4ceb2b0f5d5c,
+                    0x1b80c72fdac86fd3,
+                    0x337655dc2fddca08,
+                    0x21cfc5f271c6213a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6a0663cba3558d5b,
+                    0xf239449b9f20b311,
+                    0xe507c075c03a24f0,
+                    0x01c2f9a31530450b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18e230dcbd679003,
+                    0x007c869caf27d03e,
+                    0xd4fb2e687bb14334,
+                    0x04332afdde1fdb89,
+                ])),
+                Felt::new(BigInteger256([
+                    0x759843fe0ac3238e,
+                    0x65d4be0475eaa967,
+                    0xa7a2c1bf9998beb5,
+                    0x10316626bce6fd85,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1399cfdda3367e69,
+                    0xbad19a54e255fc27,
+                    0xf5afb8cbd0fba294,
+                    0x0fb773af9e90c023,
+                ])),
+                Felt::new(BigInteger256([
+                    0x251ab12508052267,
+                    0x14a98917558a49f4,
+                    0x1f0294546f58c71c,
+                    0x2803ca5e35d9ccf2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xadc297bf1a9854ee,
+                    0xf531610d55c33170,
+                    0x5878ed48149c8e41,
+                    0x20d03a57b7d51ef7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xae1f1cb79cfc65f2,
+                    0x1ec71613b2023f6d,
+                    0x47e365c4828dc9df,
+                    0x0fd9b95d7e4dda40,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf97b071a754c2383,
+                    0xb181b1a1ade78351,
+                    0x71eb298b9161379b,
+                    0x2fdc92ddbf4e2079,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0cb1364ab3a5ddb,
+                    0x96b570582e0a126b,
+                    0x199c5202b9193842,
+                    0x13cc0bf619f465f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4772febc2202cb1d,
+                    0xc9d0391c94f88820,
+                    0x770af8f4d074f6b9,
+                    0x02fb639ae8b5c505,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8251d7987ab69ce2,
+                    0x9ddab2795606cead,
+                    0x678b586c999e64e3,
+                    0x20abbc4189d0e270,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86cd57d30777027c,
+                    0x73b900258ebd27ea,
+                    0x9decb286ba199c99,
+                    0x2d70c9b02a4c308a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8902eb5e59558f69,
+                    0xd04ff44f1176198a,
+                    0x19dae26f158fa256,
+                    0x28008a68f1a643b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x133b25369ea1bb26,
+                    0xb0b1fb800478558a,
+                    0x8c1868b6efb2db60,
+                    0x056d108352143368,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cbe4acc16a51329,
+                    0x0ec3d825e3f7795a,
+                    0x4b7081333df37267,
+                    0x1862632347b6dbc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x874011444e9a6603,
+                    0x2cf23966b8d22e1f,
+                    0xe2511c194760d356,
+                    0x1fc455686d5dd026,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5aa5212721577036,
+                    0x68fa17d98dcafb6a,
+                    0x53f577ffc6af6dbd,
+                    0x2d9f425252f5b35b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x84da266ff68814c0,
+                    0x2d9fbc1e8ef13ab4,
+                    0x4150a53695e4cde3,
+                    0x1994327b15f23ecf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x496f29085ac5f057,
+                    0xcdae93bc8a6c568e,
+                    0xa6161dc4f28217ba,
+                    0x242debd33cd77472,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9ca79d0b7254ae2e,
+                    0x4caf2cdd0dcfbfdd,
+                    0xe6f4c5a441b6dc31,
+                    0x244dba3fd096134d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1acf9eb2945b80d2,
+                    0x07ce94d5f5b67f96,
+                    0x0faa52e5d150b789,
+                    0x1ed141f0912ba199,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6623c478750466a0,
+                    0xc799200bcc4ec9d4,
+                    0x7c503a9e83edd97a,
+                    0x0dd6a0431ce3055c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x91746c74a2cf96cb,
+                    0xa44d11abf05da297,
+                    0x0b16130ded25354e,
+                    0x1a91d2a585acaab3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4eb21408d1c373be,
+                    0x0e191ef7716f9639,
+                    0x004b68444467a3b6,
+                    0x12d735ce7cfcacb6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x880ebc075fa01cca,
+                    0x352b5a7a09b69b9c,
+                    0x53a2d328d2b844aa,
+                    0x08fbf06800393295,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf3f9f91bfa044d4e,
+                    0x2244f9a7756e091f,
+                    0x8f2eb8f14e85ad8f,
+                    0x0e017458e4da94de,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41c1ad1fc058dc7e,
+                    0x16de8dd78877c49d,
+                    0x90cebc5df0fac44a,
+                    0x233e1c7ee0e5145e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x27122a116c70ac85,
+                    0x0d179a8ee268fb8d,
+                    0xebae6f8733e0871d,
+                    0x1b8b6ccf82ef97da,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe31f0f35b16e6cd,
+                    0x9f05d2124c95515f,
+                    0x65b41853a9493925,
+                    0x0350d13f7ed5b035,
+                ])),
+                Felt::new(BigInteger256([
+                    0x10f0c183d3c2ad55,
+                    0xd6178d1c516677dd,
+                    0x5885cc0a00fa2f41,
+                    0x27998d2b631abed2,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger256([
+                    0x66719f918fffffa7,
+                    0x05f43ecdae87dd7f,
+                    0xec17c38cfa08479d,
+                    0x2d20ba0fb5bf517e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c977867bffffe84,
+                    0x52f3346b50bce85f,
+                    0x68d88517c400d5b8,
+                    0x2b1b8d79ba564212,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f52410ecffffed3,
+                    0xbaf7e2c8e0f6a533,
+                    0x499e0869baeb1a77,
+                    0x1a0fc2ed38a6aef2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc15e06793fffff6c,
+                    0xc1fdb619a0caec04,
+                    0x7197b27d2138ea24,
+                    0x0602a595cf4f67e1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66719f918fffffa7,
+                    0x05f43ecdae87dd7f,
+                    0xec17c38cfa08479d,
+                    0x2d20ba0fb5bf517e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c977867bffffe84,
+                    0x52f3346b50bce85f,
+                    0x68d88517c400d5b8,
+                    0x2b1b8d79ba564212,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f52410ecffffed3,
+                    0xbaf7e2c8e0f6a533,
+                    0x499e0869baeb1a77,
+                    0x1a0fc2ed38a6aef2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc15e06793fffff6c,
+                    0xc1fdb619a0caec04,
+                    0x7197b27d2138ea24,
+                    0x0602a595cf4f67e1,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x66719f918fffffa7,
+                    0x05f43ecdae87dd7f,
+                    0xec17c38cfa08479d,
+                    0x2d20ba0fb5bf517e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c977867bffffe84,
+                    0x52f3346b50bce85f,
+                    0x68d88517c400d5b8,
+                    0x2b1b8d79ba564212,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f52410ecffffed3,
+                    0xbaf7e2c8e0f6a533,
+                    0x499e0869baeb1a77,
+                    0x1a0fc2ed38a6aef2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc15e06793fffff6c,
+                    0xc1fdb619a0caec04,
+                    0x7197b27d2138ea24,
+                    0x0602a595cf4f67e1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x66719f918fffffa7,
+                    0x05f43ecdae87dd7f,
+                    0xec17c38cfa08479d,
+                    0x2d20ba0fb5bf517e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c977867bffffe84,
+                    0x52f3346b50bce85f,
+                    0x68d88517c400d5b8,
+                    0x2b1b8d79ba564212,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f52410ecffffed3,
+                    0xbaf7e2c8e0f6a533,
+                    0x499e0869baeb1a77,
+                    0x1a0fc2ed38a6aef2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc15e06793fffff6c,
+                    0xc1fdb619a0caec04,
+                    0x7197b27d2138ea24,
+                    0x0602a595cf4f67e1,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x598e6e6e700dafff,
+                    0x50ac6f19af8866b3,
+                    0x80e5518c47195387,
+                    0x27dbea505b68cc95,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbef0003237016e95,
+                    0xc81ae2774559396f,
+                    0x5a356ddc80ad58e5,
+                    0x0e121db70da51194,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e607a8d6bad96d8,
+                    0x0d0f224e22e58bfe,
+                    0x1657121ace68d1d1,
+                    0x1ff50f0610c38886,
+                ])),
+                Felt::new(BigInteger256([
+                    0x05994061ec6b27e3,
+                    0xfbec390f6c8bc5f8,
+                    0xe0edc4571a705c57,
+                    0x247f542e88defc8d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a2d548f62458499,
+                    0x38d8f9e5e2125a7e,
+                    0x1e1d9564cb534af5,
+                    0x10da291316d9171a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd4974673bc3cd96,
+                    0x734a4f2567270a8c,
+                    0x8c30cac23ce1122f,
+                    0x2f2ca46c43aa07d3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f50945bd36d77ba,
+                    0xedabb70b571fe2b8,
+                    0x66edfec86b5f78e3,
+                    0x088e38f5e4a5b43b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x427833675a4d6ab9,
+                    0x14926a6c816833de,
+                    0x9a32808d1481ccb9,
+                    0x1a0f612c443cc44a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf7e617310b4c883a,
+                    0x1a7bf47e215d6ec1,
+                    0xa3c4af7287632e39,
+                    0x02ff5bef5e78c8c6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x27e57c174aa95dfb,
+                    0x8667ae666a56967e,
+                    0xbe0549cf03a3910e,
+                    0x2a6bf12cdd1be734,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7083fd65ad67704e,
+                    0x21b87e64ef99a371,
+                    0xf2930c5d2ab7aa34,
+                    0x289db257e8508928,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69d8ba96b8125c94,
+                    0x9e444b6253e7d103,
+                    0xc9e440b95289f55a,
+                    0x2c52446c5fc95c2f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x10d748a0665a9853,
+                    0x0257d6fb2947ac99,
+                    0xd43d8a3ebfad7e85,
+                    0x24fb4550c1a8dc77,
+                ])),
+                Felt::new(BigInteger256([
+                    0x742ab15e81a651b4,
+                    0x4e7795e04310ab1e,
+                    0x7401ad8da72699db,
+                    0x04a0483347d4a9a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3d27d2ff8ea8d5cb,
+                    0xfc085fbc7ff35758,
+                    0x55a4477ee0579ed8,
+                    0x01c455b358d4df07,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a9bb01a9c16aa3b,
+                    0xfcd264563f05a239,
+                    0x6612091420ac20ce,
+                    0x14c3131d8d4a21db,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6207103255f0deda,
+                    0x23e331a9eb405496,
+                    0x495eb292a04f2814,
+                    0x28445088c895e0a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9fab33cc106f29d5,
+                    0x0c906b2189e62b3c,
+                    0xe4c74d733d360964,
+                    0x236bcadb1b424d2c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x887ba23a1570c437,
+                    0x664f303aa3772dd4,
+                    0x22aa0ca9284d6ff7,
+                    0x1e1f6fc5a69984c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe196e3e11a14cd05,
+                    0xf7b9e8f3cb991676,
+                    0xe160030bcefbd318,
+                    0x19be0cfbc590b498,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc2ca9a62e1ea735,
+                    0x6be259651cba986b,
+                    0x7d26e4cbc50d5a6e,
+                    0x10eb43e05d67daa0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc7f22a4376e0e4d9,
+                    0x818648342f4923d0,
+                    0x1abf6c344dffee99,
+                    0x20b082f608d35fe8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0980c0c03fdde73,
+                    0xbceb29f2d0b560ee,
+                    0xf9fa8ed247c5a57e,
+                    0x171c2eb09ea47233,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf5ffb139f10beecd,
+                    0xede756d3cfadfcf3,
+                    0x3a6385e597441768,
+                    0x156b099af939d89a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8127f55adef707d4,
+                    0xe8ce4ce1c6b0d38c,
+                    0x0428a82b8e913f89,
+                    0x1dc7e4cdfde872a1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x58d9aa5c0d0f0b35,
+                    0xbabac3d7394a4689,
+                    0x495b889cd8a55805,
+                    0x30059d972c8af2e6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b69e33f50608cd5,
+                    0x7a01566258c9c52b,
+                    0x46b12a0adc81c199,
+                    0x218fb6918b0cdad5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x336ff025cc648233,
+                    0xaa9f128de7281572,
+                    0x120a85b33827d31d,
+                    0x0c94a06cf24ebc23,
+                ])),
+                Felt::new(BigInteger256([
+                    0x45ae29e12e321711,
+                    0x7484f55274788124,
+                    0x806e53685c8190b1,
+                    0x286945f434ec9288,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bde9322b0492ccc,
+                    0xd7a6654b28dc270d,
+                    0xddc3f934970976d3,
+                    0x12123b44088492cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf21eb6def2e3a79f,
+                    0x7ef67c24dadd76cc,
+                    0x5cccf50b88afccb5,
+                    0x0eb5faf60e58d458,
+                ])),
+                Felt::new(BigInteger256([
+                    0x703db9a92ce78d62,
+                    0xd4855b3e2a41316e,
+                    0x4bbcdb80f560bca2,
+                    0x106096367e897891,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xeb058afe15a10ffe,
+                    0x533759498d99c673,
+                    0x9842bd03288ec48e,
+                    0x26ae4ca9374f95b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc66181d07c1c58b4,
+                    0x608475f9f0c5c35c,
+                    0xb43a1ca6c25683b7,
+                    0x24de3c9ae1aeda78,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b785d4edbbac1e1,
+                    0x20cf45f4863c33d1,
+                    0x9168b7399d9aac89,
+                    0x05f25a0a7e425082,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8a9c6b476668293,
+                    0x91fa132bd8693290,
+                    0xb4298cbace8f8497,
+                    0x0645f4baae4dcee8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4872568b8b6d65e4,
+                    0xfc1a023e3a396a34,
+                    0x761ffd8dec3ec42c,
+                    0x23c540490d819c78,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86d437112199d668,
+                    0x89e046bb882178a7,
+                    0x99f4dea4aebbe7ac,
+                    0x26b366fb543c8bfa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1f726afacdc9f83,
+                    0xba101de7d7b4dd55,
+                    0x6223b3ea54035edc,
+                    0x15a8d46fc74b043f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0825fdaa87a44133,
+                    0x2e9e1c360879797b,
+                    0x2acf685131dbfba2,
+                    0x146ba57729949820,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8262a2769d9a5fb2,
+                    0xe511e6b5b4132cc7,
+                    0xe160e499a6420cdf,
+                    0x0c9a850287037b69,
+                ])),
+                Felt::new(BigInteger256([
+                    0x68ba3b0a471d4910,
+                    0xdc91e3a24038c51a,
+                    0x59b91402d1307299,
+                    0x22109f9b07955926,
+                ])),
+                Felt::new(BigInteger256([
+                    0x829c237d2e15f3e6,
+                    0x81b2454774396092,
+                    0xcb696504daf9e661,
+                    0x127ad499a20994fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83b352222e7de1a8,
+                    0xba524a23b1e41f25,
+                    0x0fe5a99565013db4,
+                    0x0bb39b611334a485,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1aaa4c57f383c66a,
+                    0x2669cb7c21748da5,
+                    0x1a0b7dd0d955dcd1,
+                    0x22843108c663d7c2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa2fe7fea4e116e4e,
+                    0x5c38ccd0114a9b7c,
+                    0x5a10b89a9c7e6c6b,
+                    0x09fdcb292049aee7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6d984e9722153b36,
+                    0xe1ec6df51ed45ac4,
+                    0x9167c2a4a0524fdc,
+                    0x0655d82f5bab1f35,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc738aa3fd982837e,
+                    0xf90bd757adfe109d,
+                    0x6085ca9b161c00f3,
+                    0x1e7e4a072b5cd3cb,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
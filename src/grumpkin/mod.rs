@@ -0,0 +1,61 @@
+pub use ark_bn254::Fr as Felt;
+pub use ark_ff::BigInteger256;
+use ark_ff::Field;
+
+mod sbox;
+
+/// An instantiation of Anemoi with state width 2 and
+/// rate 1 aimed at providing 128 bits security.
+pub mod anemoi_2_1;
+
+/// An instantiation of Anemoi with state width 4 and
+/// rate 3 aimed at providing 128 bits security.
+pub mod anemoi_4_3;
+
+/// An instantiation of Anemoi with state width 8 and
+/// rate 7 aimed at providing 128 bits security.
+pub mod anemoi_8_7;
+
+/// An instantiation of Anemoi with state width 12 and
+/// rate 11 aimed at providing 128 bits security.
+pub mod anemoi_12_11;
+
+/// An instantiation of Anemoi with state width 2 and
+/// rate 1 aimed at providing 80 bits security.
+#[cfg(feature = "security_80")]
+pub mod anemoi_2_1_80;
+
+/// An instantiation of Anemoi with state width 4 and
+/// rate 3 aimed at providing 80 bits security.
+#[cfg(feature = "security_80")]
+pub mod anemoi_4_3_80;
+
+/// An instantiation of Anemoi with state width 4 and
+/// rate 2 aimed at providing 256 bits security.
+#[cfg(feature = "security_256")]
+pub mod anemoi_4_2_256;
+
+/// An instantiation of Anemoi with state width 8 and
+/// rate 6 aimed at providing 256 bits security.
+#[cfg(feature = "security_256")]
+pub mod anemoi_8_6_256;
+
+/// An instantiation of Anemoi with state width 12 and
+/// rate 10 aimed at providing 256 bits security.
+#[cfg(feature = "security_256")]
+pub mod anemoi_12_10_256;
+
+/// The default Fiat-Shamir transcript over Felt, built on
+/// the instantiation with state width 4 and rate 3.
+pub type Transcript = anemoi_4_3::AnemoiTranscript;
+
+// HELPER FUNCTION
+// ================================================================================================
+
+#[inline(always)]
+fn mul_by_generator(x: &Felt) -> Felt {
+    let x2 = x.double();
+    let x4 = x2.double();
+
+    x4 + x
+}
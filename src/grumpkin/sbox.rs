@@ -0,0 +1,362 @@
+use super::BigInteger256;
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 5;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 4] = [
+    0xcfe7f7a98ccccccd,
+    0x535cb9d394945a0d,
+    0x93736af8679aad17,
+    0x26b6a528b427b354,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 5;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger256([
+    0xd745397409999999,
+    0xb4ada7d483c3efa8,
+    0xc49ca2f8e57f3161,
+    0x162a3754ac156cb3,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    let t1 = x.square(); //         1: 2
+    let t2 = t1 * x; //             2: 3
+    let t3 = t2 * t1; //            3: 5
+    let t4 = t3 * t1; //            4: 7
+    let t5 = t4 * t1; //            5: 9
+    let t6 = t5 * t1; //            6: 11
+    let t7 = t6 * t1; //            7: 13
+    let t8 = t7 * t1; //            8: 15
+    let t9 = t8 * t1; //            9: 17
+    let t10 = t9 * t1; //          10: 19
+    let t11 = t10 * t1; //         11: 21
+    let t12 = t11 * t1; //         12: 23
+    let t13 = t12 * t1; //         13: 25
+    let t14 = t13 * t1; //         14: 27
+    let t15 = t14 * t1; //         15: 29
+    let t16 = t15 * t1; //         16: 31
+    let mut t0 = t10.square(); //  17: 38
+    t0 = t0.square(); //           18: 76
+    t0 = t0.square(); //           19: 152
+    t0 = t0.square(); //           20: 304
+    t0 = t0.square(); //           21: 608
+    t0 *= t6; //                   22: 619
+    t0 = t0.square(); //           23: 1238
+    t0 = t0.square(); //           24: 2476
+    t0 = t0.square(); //           25: 4952
+    t0 = t0.square(); //           26: 9904
+    t0 = t0.square(); //           27: 19808
+    t0 *= t7; //                   28: 19821
+    t0 = t0.square(); //           29: 39642
+    t0 = t0.square(); //           30: 79284
+    t0 = t0.square(); //           31: 158568
+    t0 = t0.square(); //           32: 317136
+    t0 = t0.square(); //           33: 634272
+    t0 *= t5; //                   34: 634281
+    t0 = t0.square(); //           35: 1268562
+    t0 = t0.square(); //           36: 2537124
+    t0 = t0.square(); //           37: 5074248
+    t0 = t0.square(); //           38: 10148496
+    t0 = t0.square(); //           39: 20296992
+    t0 *= t5; //                   40: 20297001
+    t0 = t0.square(); //           41: 40594002
+    t0 = t0.square(); //           42: 81188004
+    t0 = t0.square(); //           43: 162376008
+    t0 = t0.square(); //           44: 324752016
+    t0 = t0.square(); //           45: 649504032
+    t0 = t0.square(); //           46: 1299008064
+    t0 *= t9; //                   47: 1299008081
+    t0 = t0.square(); //           48: 2598016162
+    t0 = t0.square(); //           49: 5196032324
+    t0 = t0.square(); //           50: 10392064648
+    t0 = t0.square(); //           51: 20784129296
+    t0 = t0.square(); //           52: 41568258592
+    t0 *= t7; //                   53: 41568258605
+    t0 = t0.square(); //           54: 83136517210
+    t0 = t0.square(); //           55: 166273034420
+    t0 = t0.square(); //           56: 332546068840
+    t0 = t0.square(); //           57: 665092137680
+    t0 = t0.square(); //           58: 1330184275360
+    t0 = t0.square(); //           59: 2660368550720
+    t0 = t0.square(); //           60: 5320737101440
+    t0 = t0.square(); //           61: 10641474202880
+    t0 = t0.square(); //           62: 21282948405760
+    t0 *= t10; //                  63: 21282948405779
+    t0 = t0.square(); //           64: 42565896811558
+    t0 = t0.square(); //           65: 85131793623116
+    t0 = t0.square(); //           66: 170263587246232
+    t0 = t0.square(); //           67: 340527174492464
+    t0 = t0.square(); //           68: 681054348984928
+    t0 *= t14; //                  69: 681054348984955
+    t0 = t0.square(); //           70: 1362108697969910
+    t0 = t0.square(); //           71: 2724217395939820
+    t0 = t0.square(); //           72: 5448434791879640
+    t0 = t0.square(); //           73: 10896869583759280
+    t0 = t0.square(); //           74: 21793739167518560
+    t0 = t0.square(); //           75: 43587478335037120
+    t0 *= t7; //                   76: 43587478335037133
+    t0 = t0.square(); //           77: 87174956670074266
+    t0 = t0.square(); //           78: 174349913340148532
+    t0 = t0.square(); //           79: 348699826680297064
+    t0 = t0.square(); //           80: 697399653360594128
+    t0 *= t3; //                   81: 697399653360594133
+    t0 = t0.square(); //           82: 1394799306721188266
+    t0 = t0.square(); //           83: 2789598613442376532
+    t0 = t0.square(); //           84: 5579197226884753064
+    t0 = t0.square(); //           85: 11158394453769506128
+    t0 = t0.square(); //           86: 22316788907539012256
+    t0 = t0.square(); //           87: 44633577815078024512
+    t0 *= t5; //                   88: 44633577815078024521
+    t0 = t0.square(); //           89: 89267155630156049042
+    t0 = t0.square(); //           90: 178534311260312098084
+    t0 = t0.square(); //           91: 357068622520624196168
+    t0 = t0.square(); //           92: 714137245041248392336
+    t0 = t0.square(); //           93: 1428274490082496784672
+    t0 = t0.square(); //           94: 2856548980164993569344
+    t0 = t0.square(); //           95: 5713097960329987138688
+    t0 *= t14; //                  96: 5713097960329987138715
+    t0 = t0.square(); //           97: 11426195920659974277430
+    t0 = t0.square(); //           98: 22852391841319948554860
+    t0 = t0.square(); //           99: 45704783682639897109720
+    t0 = t0.square(); //          100: 91409567365279794219440
+    t0 = t0.square(); //          101: 182819134730559588438880
+    t0 *= t10; //                 102: 182819134730559588438899
+    t0 = t0.square(); //          103: 365638269461119176877798
+    t0 = t0.square(); //          104: 731276538922238353755596
+    t0 = t0.square(); //          105: 1462553077844476707511192
+    t0 = t0.square(); //          106: 2925106155688953415022384
+    t0 = t0.square(); //          107: 5850212311377906830044768
+    t0 *= t7; //                  108: 5850212311377906830044781
+    t0 = t0.square(); //          109: 11700424622755813660089562
+    t0 = t0.square(); //          110: 23400849245511627320179124
+    t0 = t0.square(); //          111: 46801698491023254640358248
+    t0 = t0.square(); //          112: 93603396982046509280716496
+    t0 = t0.square(); //          113: 187206793964093018561432992
+    t0 = t0.square(); //          114: 374413587928186037122865984
+    t0 *= t12; //                 115: 374413587928186037122866007
+    t0 = t0.square(); //          116: 748827175856372074245732014
+    t0 = t0.square(); //          117: 1497654351712744148491464028
+    t0 *= t2; //                  118: 1497654351712744148491464031
+    t0 = t0.square(); //          119: 2995308703425488296982928062
+    t0 = t0.square(); //          120: 5990617406850976593965856124
+    t0 = t0.square(); //          121: 11981234813701953187931712248
+    t0 = t0.square(); //          122: 23962469627403906375863424496
+    t0 = t0.square(); //          123: 47924939254807812751726848992
+    t0 = t0.square(); //          124: 95849878509615625503453697984
+    t0 = t0.square(); //          125: 191699757019231251006907395968
+    t0 = t0.square(); //          126: 383399514038462502013814791936
+    t0 = t0.square(); //          127: 766799028076925004027629583872
+    t0 *= t13; //                 128: 766799028076925004027629583897
+    t0 = t0.square(); //          129: 1533598056153850008055259167794
+    t0 = t0.square(); //          130: 3067196112307700016110518335588
+    t0 = t0.square(); //          131: 6134392224615400032221036671176
+    t0 *= t4; //                  132: 6134392224615400032221036671183
+    t0 = t0.square(); //          133: 12268784449230800064442073342366
+    t0 = t0.square(); //          134: 24537568898461600128884146684732
+    t0 = t0.square(); //          135: 49075137796923200257768293369464
+    t0 = t0.square(); //          136: 98150275593846400515536586738928
+    t0 = t0.square(); //          137: 196300551187692801031073173477856
+    t0 = t0.square(); //          138: 392601102375385602062146346955712
+    t0 *= t7; //                  139: 392601102375385602062146346955725
+    t0 = t0.square(); //          140: 785202204750771204124292693911450
+    t0 = t0.square(); //          141: 1570404409501542408248585387822900
+    t0 = t0.square(); //          142: 3140808819003084816497170775645800
+    t0 = t0.square(); //          143: 6281617638006169632994341551291600
+    t0 = t0.square(); //          144: 12563235276012339265988683102583200
+    t0 = t0.square(); //          145: 25126470552024678531977366205166400
+    t0 *= t11; //                 146: 25126470552024678531977366205166421
+    t0 = t0.square(); //          147: 50252941104049357063954732410332842
+    t0 = t0.square(); //          148: 100505882208098714127909464820665684
+    t0 = t0.square(); //          149: 201011764416197428255818929641331368
+    t0 *= t3; //                  150: 201011764416197428255818929641331373
+    t0 = t0.square(); //          151: 402023528832394856511637859282662746
+    t0 = t0.square(); //          152: 804047057664789713023275718565325492
+    t0 = t0.square(); //          153: 1608094115329579426046551437130650984
+    t0 = t0.square(); //          154: 3216188230659158852093102874261301968
+    t0 = t0.square(); //          155: 6432376461318317704186205748522603936
+    t0 = t0.square(); //          156: 12864752922636635408372411497045207872
+    t0 = t0.square(); //          157: 25729505845273270816744822994090415744
+    t0 = t0.square(); //          158: 51459011690546541633489645988180831488
+    t0 *= t12; //                 159: 51459011690546541633489645988180831511
+    t0 = t0.square(); //          160: 102918023381093083266979291976361663022
+    t0 = t0.square(); //          161: 205836046762186166533958583952723326044
+    t0 = t0.square(); //          162: 411672093524372333067917167905446652088
+    t0 = t0.square(); //          163: 823344187048744666135834335810893304176
+    t0 *= t3; //                  164: 823344187048744666135834335810893304181
+    t0 = t0.square(); //          165: 1646688374097489332271668671621786608362
+    t0 = t0.square(); //          166: 3293376748194978664543337343243573216724
+    t0 = t0.square(); //          167: 6586753496389957329086674686487146433448
+    t0 = t0.square(); //          168: 13173506992779914658173349372974292866896
+    t0 = t0.square(); //          169: 26347013985559829316346698745948585733792
+    t0 = t0.square(); //          170: 52694027971119658632693397491897171467584
+    t0 *= t7; //                  171: 52694027971119658632693397491897171467597
+    t0 = t0.square(); //          172: 105388055942239317265386794983794342935194
+    t0 = t0.square(); //          173: 210776111884478634530773589967588685870388
+    t0 = t0.square(); //          174: 421552223768957269061547179935177371740776
+    t0 = t0.square(); //          175: 843104447537914538123094359870354743481552
+    t0 *= t4; //                  176: 843104447537914538123094359870354743481559
+    t0 = t0.square(); //          177: 1686208895075829076246188719740709486963118
+    t0 = t0.square(); //          178: 3372417790151658152492377439481418973926236
+    t0 = t0.square(); //          179: 6744835580303316304984754878962837947852472
+    t0 = t0.square(); //          180: 13489671160606632609969509757925675895704944
+    t0 = t0.square(); //          181: 26979342321213265219939019515851351791409888
+    t0 = t0.square(); //          182: 53958684642426530439878039031702703582819776
+    t0 = t0.square(); //          183: 107917369284853060879756078063405407165639552
+    t0 *= t12; //                 184: 107917369284853060879756078063405407165639575
+    t0 = t0.square(); //          185: 215834738569706121759512156126810814331279150
+    t0 = t0.square(); //          186: 431669477139412243519024312253621628662558300
+    t0 = t0.square(); //          187: 863338954278824487038048624507243257325116600
+    t0 = t0.square(); //          188: 1726677908557648974076097249014486514650233200
+    t0 = t0.square(); //          189: 3453355817115297948152194498028973029300466400
+    t0 = t0.square(); //          190: 6906711634230595896304388996057946058600932800
+    t0 = t0.square(); //          191: 13813423268461191792608777992115892117201865600
+    t0 *= t15; //                 192: 13813423268461191792608777992115892117201865629
+    t0 = t0.square(); //          193: 27626846536922383585217555984231784234403731258
+    t0 = t0.square(); //          194: 55253693073844767170435111968463568468807462516
+    t0 = t0.square(); //          195: 110507386147689534340870223936927136937614925032
+    t0 = t0.square(); //          196: 221014772295379068681740447873854273875229850064
+    t0 = t0.square(); //          197: 442029544590758137363480895747708547750459700128
+    t0 *= t4; //                  198: 442029544590758137363480895747708547750459700135
+    t0 = t0.square(); //          199: 884059089181516274726961791495417095500919400270
+    t0 = t0.square(); //          200: 1768118178363032549453923582990834191001838800540
+    t0 = t0.square(); //          201: 3536236356726065098907847165981668382003677601080
+    t0 = t0.square(); //          202: 7072472713452130197815694331963336764007355202160
+    t0 = t0.square(); //          203: 14144945426904260395631388663926673528014710404320
+    t0 *= t3; //                  204: 14144945426904260395631388663926673528014710404325
+    t0 = t0.square(); //          205: 28289890853808520791262777327853347056029420808650
+    t0 = t0.square(); //          206: 56579781707617041582525554655706694112058841617300
+    t0 = t0.square(); //          207: 113159563415234083165051109311413388224117683234600
+    t0 = t0.square(); //          208: 226319126830468166330102218622826776448235366469200
+    t0 = t0.square(); //          209: 452638253660936332660204437245653552896470732938400
+    t0 = t0.square(); //          210: 905276507321872665320408874491307105792941465876800
+    t0 *= t5; //                  211: 905276507321872665320408874491307105792941465876809
+    t0 = t0.square(); //          212: 1810553014643745330640817748982614211585882931753618
+    t0 = t0.square(); //          213: 3621106029287490661281635497965228423171765863507236
+    t0 = t0.square(); //          214: 7242212058574981322563270995930456846343531727014472
+    t0 = t0.square(); //          215: 14484424117149962645126541991860913692687063454028944
+    t0 = t0.square(); //          216: 28968848234299925290253083983721827385374126908057888
+    t0 = t0.square(); //          217: 57937696468599850580506167967443654770748253816115776
+    t0 *= t9; //                  218: 57937696468599850580506167967443654770748253816115793
+    t0 = t0.square(); //          219: 115875392937199701161012335934887309541496507632231586
+    t0 = t0.square(); //          220: 231750785874399402322024671869774619082993015264463172
+    t0 = t0.square(); //          221: 463501571748798804644049343739549238165986030528926344
+    t0 = t0.square(); //          222: 927003143497597609288098687479098476331972061057852688
+    t0 = t0.square(); //          223: 1854006286995195218576197374958196952663944122115705376
+    t0 *= t7; //                  224: 1854006286995195218576197374958196952663944122115705389
+    t0 = t0.square(); //          225: 3708012573990390437152394749916393905327888244231410778
+    t0 = t0.square(); //          226: 7416025147980780874304789499832787810655776488462821556
+    t0 = t0.square(); //          227: 14832050295961561748609578999665575621311552976925643112
+    t0 = t0.square(); //          228: 29664100591923123497219157999331151242623105953851286224
+    t0 = t0.square(); //          229: 59328201183846246994438315998662302485246211907702572448
+    t0 = t0.square(); //          230: 118656402367692493988876631997324604970492423815405144896
+    t0 = t0.square(); //          231: 237312804735384987977753263994649209940984847630810289792
+    t0 = t0.square(); //          232: 474625609470769975955506527989298419881969695261620579584
+    t0 = t0.square(); //          233: 949251218941539951911013055978596839763939390523241159168
+    t0 = t0.square(); //          234: 1898502437883079903822026111957193679527878781046482318336
+    t0 *= t14; //                 235: 1898502437883079903822026111957193679527878781046482318363
+    t0 = t0.square(); //          236: 3797004875766159807644052223914387359055757562092964636726
+    t0 = t0.square(); //          237: 7594009751532319615288104447828774718111515124185929273452
+    t0 = t0.square(); //          238: 15188019503064639230576208895657549436223030248371858546904
+    t0 = t0.square(); //          239: 30376039006129278461152417791315098872446060496743717093808
+    t0 = t0.square(); //          240: 60752078012258556922304835582630197744892120993487434187616
+    t0 *= t10; //                 241: 60752078012258556922304835582630197744892120993487434187635
+    t0 = t0.square(); //          242: 121504156024517113844609671165260395489784241986974868375270
+    t0 = t0.square(); //          243: 243008312049034227689219342330520790979568483973949736750540
+    t0 = t0.square(); //          244: 486016624098068455378438684661041581959136967947899473501080
+    t0 = t0.square(); //          245: 972033248196136910756877369322083163918273935895798947002160
+    t0 = t0.square(); //          246: 1944066496392273821513754738644166327836547871791597894004320
+    t0 *= t16; //                 247: 1944066496392273821513754738644166327836547871791597894004351
+    t0 = t0.square(); //          248: 3888132992784547643027509477288332655673095743583195788008702
+    t0 = t0.square(); //          249: 7776265985569095286055018954576665311346191487166391576017404
+    t0 = t0.square(); //          250: 15552531971138190572110037909153330622692382974332783152034808
+    t0 = t0.square(); //          251: 31105063942276381144220075818306661245384765948665566304069616
+    t0 = t0.square(); //          252: 62210127884552762288440151636613322490769531897331132608139232
+    t0 = t0.square(); //          253: 124420255769105524576880303273226644981539063794662265216278464
+    t0 = t0.square(); //          254: 248840511538211049153760606546453289963078127589324530432556928
+    t0 *= t16; //                 255: 248840511538211049153760606546453289963078127589324530432556959
+    t0 = t0.square(); //          256: 497681023076422098307521213092906579926156255178649060865113918
+    t0 = t0.square(); //          257: 995362046152844196615042426185813159852312510357298121730227836
+    t0 = t0.square(); //          258: 1990724092305688393230084852371626319704625020714596243460455672
+    t0 = t0.square(); //          259: 3981448184611376786460169704743252639409250041429192486920911344
+    t0 = t0.square(); //          260: 7962896369222753572920339409486505278818500082858384973841822688
+    t0 *= t14; //                 261: 7962896369222753572920339409486505278818500082858384973841822715
+    t0 = t0.square(); //          262: 15925792738445507145840678818973010557637000165716769947683645430
+    t0 = t0.square(); //          263: 31851585476891014291681357637946021115274000331433539895367290860
+    t0 = t0.square(); //          264: 63703170953782028583362715275892042230548000662867079790734581720
+    t0 = t0.square(); //          265: 127406341907564057166725430551784084461096001325734159581469163440
+    t0 *= t7; //                  266: 127406341907564057166725430551784084461096001325734159581469163453
+    t0 = t0.square(); //          267: 254812683815128114333450861103568168922192002651468319162938326906
+    t0 = t0.square(); //          268: 509625367630256228666901722207136337844384005302936638325876653812
+    t0 = t0.square(); //          269: 1019250735260512457333803444414272675688768010605873276651753307624
+    t0 = t0.square(); //          270: 2038501470521024914667606888828545351377536021211746553303506615248
+    t0 = t0.square(); //          271: 4077002941042049829335213777657090702755072042423493106607013230496
+    t0 = t0.square(); //          272: 8154005882084099658670427555314181405510144084846986213214026460992
+    t0 *= t10; //                 273: 8154005882084099658670427555314181405510144084846986213214026461011
+    t0 = t0.square(); //          274: 16308011764168199317340855110628362811020288169693972426428052922022
+    t0 = t0.square(); //          275: 32616023528336398634681710221256725622040576339387944852856105844044
+    t0 = t0.square(); //          276: 65232047056672797269363420442513451244081152678775889705712211688088
+    t0 = t0.square(); //          277: 130464094113345594538726840885026902488162305357551779411424423376176
+    t0 = t0.square(); //          278: 260928188226691189077453681770053804976324610715103558822848846752352
+    t0 = t0.square(); //          279: 521856376453382378154907363540107609952649221430207117645697693504704
+    t0 = t0.square(); //          280: 1043712752906764756309814727080215219905298442860414235291395387009408
+    t0 = t0.square(); //          281: 2087425505813529512619629454160430439810596885720828470582790774018816
+    t0 *= t13; //                 282: 2087425505813529512619629454160430439810596885720828470582790774018841
+    t0 = t0.square(); //          283: 4174851011627059025239258908320860879621193771441656941165581548037682
+    t0 = t0.square(); //          284: 8349702023254118050478517816641721759242387542883313882331163096075364
+    t0 = t0.square(); //          285: 16699404046508236100957035633283443518484775085766627764662326192150728
+    t0 = t0.square(); //          286: 33398808093016472201914071266566887036969550171533255529324652384301456
+    t0 = t0.square(); //          287: 66797616186032944403828142533133774073939100343066511058649304768602912
+    t0 *= t10; //                 288: 66797616186032944403828142533133774073939100343066511058649304768602931
+    t0 = t0.square(); //          289: 133595232372065888807656285066267548147878200686133022117298609537205862
+    t0 = t0.square(); //          290: 267190464744131777615312570132535096295756401372266044234597219074411724
+    t0 = t0.square(); //          291: 534380929488263555230625140265070192591512802744532088469194438148823448
+    t0 = t0.square(); //          292: 1068761858976527110461250280530140385183025605489064176938388876297646896
+    t0 = t0.square(); //          293: 2137523717953054220922500561060280770366051210978128353876777752595293792
+    t0 = t0.square(); //          294: 4275047435906108441845001122120561540732102421956256707753555505190587584
+    t0 = t0.square(); //          295: 8550094871812216883690002244241123081464204843912513415507111010381175168
+    t0 *= t13; //                 296: 8550094871812216883690002244241123081464204843912513415507111010381175193
+    t0 = t0.square(); //          297: 17100189743624433767380004488482246162928409687825026831014222020762350386
+    t0 = t0.square(); //          298: 34200379487248867534760008976964492325856819375650053662028444041524700772
+    t0 = t0.square(); //          299: 68400758974497735069520017953928984651713638751300107324056888083049401544
+    t0 = t0.square(); //          300: 136801517948995470139040035907857969303427277502600214648113776166098803088
+    t0 = t0.square(); //          301: 273603035897990940278080071815715938606854555005200429296227552332197606176
+    t0 *= t10; //                 302: 273603035897990940278080071815715938606854555005200429296227552332197606195
+    t0 = t0.square(); //          303: 547206071795981880556160143631431877213709110010400858592455104664395212390
+    t0 = t0.square(); //          304: 1094412143591963761112320287262863754427418220020801717184910209328790424780
+    t0 = t0.square(); //          305: 2188824287183927522224640574525727508854836440041603434369820418657580849560
+    t0 = t0.square(); //          306: 4377648574367855044449281149051455017709672880083206868739640837315161699120
+    t0 = t0.square(); //          307: 8755297148735710088898562298102910035419345760166413737479281674630323398240
+    t0 = t0.square(); //          308: 17510594297471420177797124596205820070838691520332827474958563349260646796480
+    t0 * t7 //                    309: 17510594297471420177797124596205820070838691520332827474958563349260646796493
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
@@ -0,0 +1,824 @@
+//! Sponge and Jive trait implementations for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::marker::PhantomData;
+
+use super::digest::AnemoiDigest;
+use super::permutation::{Anemoi, AnemoiParameters};
+use super::{Jive, Sponge, SpongeXof, XofReader};
+
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// An Anemoi hash instantiation over `F` with `NUM_COLUMNS` columns,
+/// returning digests of `DIGEST_SIZE` field elements.
+///
+/// Besides the one-shot methods of the `Sponge` trait, it can process
+/// data incrementally through [`AnemoiHash::update`] or
+/// [`AnemoiHash::update_field`], followed by [`AnemoiHash::finalize`].
+pub struct AnemoiHash<F: PrimeField, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P> {
+    state: Vec<F>,
+    idx: usize,
+    buf: Vec<u8>,
+    _parameters: PhantomData<P>,
+}
+
+impl<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P> Default
+    for AnemoiHash<F, NUM_COLUMNS, DIGEST_SIZE, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    fn default() -> Self {
+        Self {
+            state: vec![F::zero(); Self::STATE_WIDTH],
+            idx: 0,
+            buf: Vec::new(),
+            _parameters: PhantomData,
+        }
+    }
+}
+
+impl<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>
+    AnemoiHash<F, NUM_COLUMNS, DIGEST_SIZE, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+    /// The number of field elements of the state reserved for rate.
+    /// The last `CAPACITY_WIDTH` ones are reserved for capacity.
+    pub const RATE_WIDTH: usize = 2 * NUM_COLUMNS - P::CAPACITY_WIDTH;
+
+    /// The number of bytes absorbed per field element when hashing
+    /// sequences of bytes, guaranteed to always represent a valid element.
+    pub(crate) const BYTES_PER_ELEMENT: usize = (F::Params::CAPACITY / 8) as usize;
+    /// The number of bytes of the canonical encoding of a field element.
+    pub(crate) const ELEMENT_ENCODING_SIZE: usize = F::BigInt::NUM_LIMBS * 8;
+
+    #[inline(always)]
+    pub(crate) fn apply_permutation(state: &mut [F]) {
+        Anemoi::<F, NUM_COLUMNS, P>::apply_permutation(state)
+    }
+
+    /// Returns a new hasher with an all-zero internal state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new hasher personalized with the provided domain tag.
+    ///
+    /// The capacity registers of the initial state are set to the field
+    /// elements derived from `tag` by [`AnemoiHash::domain_tag`] and permuted,
+    /// so that digests computed for distinct domains are independent.
+    pub fn with_domain(tag: &[u8]) -> Self {
+        Self::with_domain_field(&Self::domain_tag(tag))
+    }
+
+    /// Returns a new hasher whose capacity registers are initialized
+    /// with the provided `CAPACITY_WIDTH` field elements.
+    ///
+    /// A non-zero domain is followed by an Anemoi permutation of the state,
+    /// so that it is mixed into the rate registers even if no input is ever
+    /// absorbed, or if the input length is a multiple of `RATE_WIDTH`.
+    /// An all-zero domain yields the same hasher as [`AnemoiHash::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain is not made of `CAPACITY_WIDTH` elements.
+    pub fn with_domain_field(domain: &[F]) -> Self {
+        assert_eq!(domain.len(), P::CAPACITY_WIDTH, "invalid domain length");
+
+        let mut hasher = Self::default();
+        if domain.iter().any(|d| !d.is_zero()) {
+            hasher.state[Self::RATE_WIDTH..].copy_from_slice(domain);
+            Self::apply_permutation(&mut hasher.state);
+        }
+
+        hasher
+    }
+
+    /// Returns the `CAPACITY_WIDTH` field elements derived from a domain tag,
+    /// obtained by hashing it with the extendable-output Anemoi Sponge mode.
+    ///
+    /// Over small fields, a single element would not be enough to separate
+    /// domains at the security level of the instantiation, hence the tag
+    /// fills the whole capacity.
+    pub fn domain_tag(tag: &[u8]) -> Vec<F> {
+        AnemoiHash::<F, NUM_COLUMNS, 1, P>::hash_xof(tag, P::CAPACITY_WIDTH)
+    }
+
+    /// Absorbs the provided sequence of bytes into the internal state.
+    ///
+    /// Successive calls are equivalent to a single call on the concatenation
+    /// of all provided sequences, and `finalize` then returns the same digest
+    /// as `Sponge::hash` over that concatenation.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+
+        // Complete the pending chunk from a previous call first, if any.
+        if !self.buf.is_empty() {
+            let missing = Self::BYTES_PER_ELEMENT - self.buf.len();
+            if bytes.len() < missing {
+                self.buf.extend_from_slice(bytes);
+                return;
+            }
+
+            self.buf.extend_from_slice(&bytes[..missing]);
+            bytes = &bytes[missing..];
+
+            let element = Self::bytes_to_element(&self.buf);
+            self.buf.clear();
+            self.absorb_element(element);
+        }
+
+        let mut chunks = bytes.chunks_exact(Self::BYTES_PER_ELEMENT);
+        for chunk in &mut chunks {
+            self.absorb_element(Self::bytes_to_element(chunk));
+        }
+
+        // The last chunk may be smaller than the others, in which case we keep
+        // it aside until we get more bytes or the hasher is finalized.
+        self.buf.extend_from_slice(chunks.remainder());
+    }
+
+    /// Absorbs the provided sequence of field elements into the internal state.
+    ///
+    /// Successive calls are equivalent to a single call on the concatenation
+    /// of all provided sequences, and `finalize` then returns the same digest
+    /// as `Sponge::hash_field` over that concatenation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some bytes previously provided through `update` have not been
+    /// absorbed yet, i.e. if their total length is not a multiple of the number
+    /// of bytes encoded per field element.
+    pub fn update_field(&mut self, elems: &[F]) {
+        assert!(
+            self.buf.is_empty(),
+            "cannot absorb field elements with pending bytes"
+        );
+
+        for &element in elems.iter() {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Applies the final padding to the internal state and returns the digest.
+    pub fn finalize(mut self) -> AnemoiDigest<F, DIGEST_SIZE> {
+        self.apply_padding();
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        AnemoiDigest::new(self.state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Applies the final padding to the internal state and returns a reader
+    /// to squeeze an arbitrary number of outputs from it.
+    ///
+    /// The first `DIGEST_SIZE` squeezed elements are equal to the digest
+    /// returned by [`AnemoiHash::finalize`].
+    pub fn finalize_xof(mut self) -> AnemoiXofReader<F, NUM_COLUMNS, P> {
+        self.apply_padding();
+
+        AnemoiXofReader::new(self.state)
+    }
+
+    /// Absorbs the last pending bytes, if any, and pads the internal state.
+    fn apply_padding(&mut self) {
+        // If the last chunk of bytes is smaller than the others, we append a byte set to 1
+        // to its end, padding the sequence in a way that adding additional trailing zeros will
+        // yield a different hash.
+        // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+        if !self.buf.is_empty() {
+            self.buf.push(1);
+            let element = Self::bytes_to_element(&self.buf);
+            self.buf.clear();
+            self.absorb_element(element);
+        }
+
+        // If the message length is a multiple of RATE_WIDTH, the rate registers have just been
+        // permuted and we add sigma = 1 to the last register of the capacity.
+        // Otherwise, we append 1 to the rate cell next to the one where we previously appended
+        // the last message element, and apply a final Anemoi permutation to the whole state.
+        if self.idx == 0 {
+            self.state[Self::STATE_WIDTH - 1] += F::one();
+        } else {
+            self.state[self.idx] += F::one();
+            Self::apply_permutation(&mut self.state);
+        }
+    }
+
+    /// Applies an Anemoi permutation to the provided state, tweaked by adding
+    /// `domain` to its last register before each round.
+    ///
+    /// A zero domain yields the regular Anemoi permutation.
+    fn apply_permutation_with_domain(state: &mut [F], domain: F) {
+        for i in 0..P::NUM_ROUNDS {
+            state[Self::STATE_WIDTH - 1] += domain;
+            Anemoi::<F, NUM_COLUMNS, P>::apply_round(state, i);
+        }
+
+        Anemoi::<F, NUM_COLUMNS, P>::apply_mds(state)
+    }
+
+    /// Absorbs a single field element into the rate portion of the state. An Anemoi
+    /// permutation is applied to the internal state if all the the rate registers have
+    /// been filled with additional values. We then reset the insertion index.
+    #[inline(always)]
+    fn absorb_element(&mut self, element: F) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == Self::RATE_WIDTH {
+            Self::apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Converts a chunk of at most `BYTES_PER_ELEMENT` bytes into a field element.
+    /// The conversion is guaranteed to succeed as we spare at least one last byte
+    /// to ensure this can represent a valid element encoding.
+    #[inline(always)]
+    fn bytes_to_element(chunk: &[u8]) -> F {
+        let mut buf = vec![0u8; Self::ELEMENT_ENCODING_SIZE];
+        buf[..chunk.len()].copy_from_slice(chunk);
+
+        F::read(&buf[..]).unwrap()
+    }
+}
+
+impl<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P> Sponge<F>
+    for AnemoiHash<F, NUM_COLUMNS, DIGEST_SIZE, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    type Digest = AnemoiDigest<F, DIGEST_SIZE>;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        let mut hasher = Self::new();
+        hasher.update(bytes);
+
+        hasher.finalize()
+    }
+
+    fn hash_field(elems: &[F]) -> Self::Digest {
+        let mut hasher = Self::new();
+        hasher.update_field(elems);
+
+        hasher.finalize()
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // When the rate cannot hold both digests at once, we use internally the
+        // Jive compression method, as compressing the digests through the Sponge
+        // construction would require two internal permutation calls.
+        if Self::RATE_WIDTH < 2 * DIGEST_SIZE {
+            let result = Self::compress(&Self::Digest::digests_to_elements(digests));
+            return Self::Digest::new(result.try_into().unwrap());
+        }
+
+        // initialize state to all zeros
+        let mut state = vec![F::zero(); Self::STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        Self::apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P> SpongeXof<F>
+    for AnemoiHash<F, NUM_COLUMNS, DIGEST_SIZE, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    type Reader = AnemoiXofReader<F, NUM_COLUMNS, P>;
+
+    fn xof(bytes: &[u8]) -> Self::Reader {
+        let mut hasher = Self::new();
+        hasher.update(bytes);
+
+        hasher.finalize_xof()
+    }
+
+    fn xof_field(elems: &[F]) -> Self::Reader {
+        let mut hasher = Self::new();
+        hasher.update_field(elems);
+
+        hasher.finalize_xof()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A reader squeezing an arbitrary number of outputs from an Anemoi
+/// hash state, by reading its rate portion and applying an Anemoi
+/// permutation every time it has been entirely read.
+pub struct AnemoiXofReader<F: PrimeField, const NUM_COLUMNS: usize, P> {
+    state: Vec<F>,
+    idx: usize,
+    buf: Vec<u8>,
+    _parameters: PhantomData<P>,
+}
+
+impl<F, const NUM_COLUMNS: usize, P> AnemoiXofReader<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements of the state squeezed between two permutations.
+    pub const RATE_WIDTH: usize = 2 * NUM_COLUMNS - P::CAPACITY_WIDTH;
+
+    /// The number of squeezed field elements combined into each block of
+    /// output bytes, i.e. the smallest one for which a block holds at least
+    /// one byte.
+    pub(crate) const ELEMENTS_PER_BLOCK: usize =
+        elements_per_block(F::Params::MODULUS_BITS, P::SECURITY_LEVEL);
+
+    /// The number of bytes extracted from each block of squeezed field elements.
+    ///
+    /// A block of `k` elements `x_i` is read as the integer `sum x_i * p^i`,
+    /// uniformly distributed in `[0, p^k)`. Keeping its `b` least significant
+    /// bytes yields a statistical distance to uniform of at most
+    /// `2^(8b) / p^k < 2^(8b - k * (MODULUS_BITS - 1))`, hence we keep
+    /// `floor((k * (MODULUS_BITS - 1) - SECURITY_LEVEL) / 8)` bytes.
+    pub(crate) const BYTES_PER_BLOCK: usize =
+        bytes_per_block(F::Params::MODULUS_BITS, P::SECURITY_LEVEL);
+
+    /// Returns a new reader from a padded hash state.
+    pub(crate) fn new(state: Vec<F>) -> Self {
+        Self {
+            state,
+            idx: 0,
+            buf: Vec::new(),
+            _parameters: PhantomData,
+        }
+    }
+
+    /// Squeezes the next block of field elements, and
+    /// returns the bytes extracted from it.
+    fn squeeze_block(&mut self) -> Vec<u8> {
+        let elements: Vec<F> = (0..Self::ELEMENTS_PER_BLOCK)
+            .map(|_| self.squeeze_element())
+            .collect();
+
+        block_bytes(&elements, Self::BYTES_PER_BLOCK)
+    }
+
+    #[inline(always)]
+    fn squeeze_element(&mut self) -> F {
+        if self.idx == Self::RATE_WIDTH {
+            Anemoi::<F, NUM_COLUMNS, P>::apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+        self.idx += 1;
+
+        self.state[self.idx - 1]
+    }
+}
+
+impl<F, const NUM_COLUMNS: usize, P> XofReader<F> for AnemoiXofReader<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    fn squeeze(&mut self, n: usize) -> Vec<F> {
+        self.buf.clear();
+
+        (0..n).map(|_| self.squeeze_element()).collect()
+    }
+
+    /// Squeezes the next `n` bytes, extracted from the `BYTES_PER_BLOCK`
+    /// least significant bytes of the integers encoded by the next blocks
+    /// of `ELEMENTS_PER_BLOCK` squeezed field elements.
+    ///
+    /// Bytes extracted but not returned are kept for subsequent calls to this
+    /// method, but are discarded by calls to `squeeze`.
+    fn squeeze_bytes(&mut self, n: usize) -> Vec<u8> {
+        let mut result = Vec::with_capacity(n);
+        while result.len() < n {
+            if self.buf.is_empty() {
+                let bytes = self.squeeze_block();
+                // Keep the bytes in reverse order to pop them in order.
+                self.buf.extend(bytes.iter().rev());
+            }
+            result.push(self.buf.pop().unwrap());
+        }
+
+        result
+    }
+}
+
+/// Returns the smallest number of elements of a field of `modulus_bits` bits
+/// from which a byte can be extracted with a statistical distance to uniform
+/// of at most `2^-security_level`.
+const fn elements_per_block(modulus_bits: u32, security_level: u32) -> usize {
+    let mut num_elements = 1;
+    while num_elements * (modulus_bits - 1) < security_level + 8 {
+        num_elements += 1;
+    }
+
+    num_elements as usize
+}
+
+/// Returns the number of bytes which can be extracted from a block of elements
+/// of a field of `modulus_bits` bits, with a statistical distance to uniform
+/// of at most `2^-security_level`.
+const fn bytes_per_block(modulus_bits: u32, security_level: u32) -> usize {
+    let num_elements = elements_per_block(modulus_bits, security_level) as u32;
+
+    ((num_elements * (modulus_bits - 1) - security_level) / 8) as usize
+}
+
+/// Returns the `num_bytes` least significant bytes of the integer `sum x_i * p^i`
+/// encoded by the field elements `x_i` of a block.
+pub(crate) fn block_bytes<F: PrimeField>(elements: &[F], num_bytes: usize) -> Vec<u8> {
+    // Computes sum x_i * p^i with Horner's method, over 64-bit limbs.
+    let modulus = F::Params::MODULUS;
+    let mut block: Vec<u64> = Vec::new();
+    for element in elements.iter().rev() {
+        block = mul_add(&block, modulus.as_ref(), element.into_repr().as_ref());
+    }
+
+    block
+        .iter()
+        .flat_map(|limb| limb.to_le_bytes())
+        .take(num_bytes)
+        .collect()
+}
+
+/// Returns `a * b + c`, where all integers are encoded as little-endian 64-bit limbs.
+fn mul_add(a: &[u64], b: &[u64], c: &[u64]) -> Vec<u64> {
+    let mut result = vec![0u64; (a.len() + b.len()).max(c.len()) + 1];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let t = result[i + j] as u128 + x as u128 * y as u128 + carry;
+            result[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        result[i + b.len()] = carry as u64;
+    }
+
+    let mut carry = 0u128;
+    for (i, r) in result.iter_mut().enumerate() {
+        let t = *r as u128 + c.get(i).copied().unwrap_or(0) as u128 + carry;
+        *r = t as u64;
+        carry = t >> 64;
+    }
+
+    result
+}
+
+impl<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P> Jive<F>
+    for AnemoiHash<F, NUM_COLUMNS, DIGEST_SIZE, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+
+    fn compress(elems: &[F]) -> Vec<F> {
+        assert!(elems.len() == Self::STATE_WIDTH);
+
+        let mut state = elems.to_vec();
+        Self::apply_permutation(&mut state);
+
+        let mut result = vec![F::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result
+    }
+
+    fn compress_k(elems: &[F], k: usize) -> Vec<F> {
+        assert!(elems.len() == Self::STATE_WIDTH);
+        assert!(Self::STATE_WIDTH.is_multiple_of(k));
+        // We can output as few as 1 element while
+        // maintaining the targeted security level.
+        assert!(k <= Self::STATE_WIDTH);
+
+        let mut state = elems.to_vec();
+        Self::apply_permutation(&mut state);
+
+        let mut result = vec![F::zero(); Self::STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+
+    fn compress_with_domain(elems: &[F], domain: F) -> Vec<F> {
+        assert!(elems.len() == Self::STATE_WIDTH);
+
+        // The domain cannot be absorbed in the input state as is, since it
+        // would only amount to translating the inputs of the regular mode.
+        let mut state = elems.to_vec();
+        Self::apply_permutation_with_domain(&mut state, domain);
+
+        let mut result = vec![F::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use rand_core::{OsRng, RngCore};
+
+    struct StreamingCheck;
+
+    impl InstantiationCheck for StreamingCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+
+            for len in [0, 1, 31, 32, 47, 48, 100, 517] {
+                let mut bytes = vec![0u8; len];
+                rng.fill_bytes(&mut bytes);
+
+                let mut hasher = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::new();
+                let mut remaining = &bytes[..];
+                while !remaining.is_empty() {
+                    let split = (rng.next_u32() as usize % 40).min(remaining.len());
+                    hasher.update(&remaining[..split]);
+                    remaining = &remaining[split..];
+                }
+                assert_eq!(
+                    hasher.finalize(),
+                    AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash(&bytes)
+                );
+            }
+
+            for len in [0, 1, 2, 3, 7, 11, 12, 25] {
+                let elems: Vec<F> = (0..len).map(|_| F::rand(&mut rng)).collect();
+
+                let mut hasher = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::new();
+                for chunk in elems.chunks(2) {
+                    hasher.update_field(chunk);
+                }
+                assert_eq!(
+                    hasher.finalize(),
+                    AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field(&elems)
+                );
+            }
+        }
+    }
+
+    struct XofCheck;
+
+    impl InstantiationCheck for XofCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let rate_width = 2 * NUM_COLUMNS - P::CAPACITY_WIDTH;
+
+            for len in [0, 1, 2, 7, 11, 12] {
+                let elems: Vec<F> = (0..len).map(|_| F::rand(&mut rng)).collect();
+
+                let digest = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field(&elems);
+                let output = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field_xof(
+                    &elems,
+                    3 * rate_width + 1,
+                );
+                assert_eq!(&output[..DIGEST_SIZE], digest.as_elements());
+
+                // Squeezing in several steps yields the same output.
+                let mut reader = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::xof_field(&elems);
+                let mut squeezed = Vec::new();
+                for n in [1, rate_width, 2, 3 * rate_width] {
+                    squeezed.extend(reader.squeeze(n));
+                }
+                assert_eq!(&squeezed[..output.len()], &output[..]);
+
+                // Blocks squeezed after successive permutations are distinct.
+                assert_ne!(&output[..rate_width], &output[rate_width..2 * rate_width]);
+            }
+
+            let mut bytes = vec![0u8; 100];
+            rng.fill_bytes(&mut bytes);
+
+            let digest = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash(&bytes);
+            let output =
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_xof(&bytes, DIGEST_SIZE);
+            assert_eq!(&output[..DIGEST_SIZE], digest.as_elements());
+
+            let mut hasher = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::new();
+            hasher.update(&bytes);
+            let output = hasher.finalize_xof().squeeze_bytes(200);
+
+            let mut reader = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::xof(&bytes);
+            let mut squeezed = Vec::new();
+            for n in [1, 30, 31, 33, 60, 45] {
+                squeezed.extend(reader.squeeze_bytes(n));
+            }
+            assert_eq!(squeezed, output);
+
+            // Bytes are extracted from the integer encoded by each block.
+            let mut reader = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::xof(&bytes);
+            let num_bytes = AnemoiXofReader::<F, NUM_COLUMNS, P>::BYTES_PER_BLOCK;
+            let num_elements = AnemoiXofReader::<F, NUM_COLUMNS, P>::ELEMENTS_PER_BLOCK;
+            let block = reader.squeeze(num_elements);
+            if num_elements == 1 {
+                let expected = block[0].into_repr().to_bytes_le();
+                assert_eq!(&output[..num_bytes], &expected[..num_bytes]);
+            }
+        }
+    }
+
+    struct DomainCheck;
+
+    impl InstantiationCheck for DomainCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let rate_width = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::RATE_WIDTH;
+            let tag = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::domain_tag(b"domain");
+            assert_eq!(tag.len(), P::CAPACITY_WIDTH);
+            assert_eq!(
+                tag[0],
+                AnemoiHash::<F, NUM_COLUMNS, 1, P>::hash(b"domain").to_elements()[0]
+            );
+            assert_ne!(
+                tag,
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::domain_tag(b"other domain")
+            );
+
+            let zero = vec![F::zero(); P::CAPACITY_WIDTH];
+            let mut one = zero.clone();
+            one[0] = F::one();
+
+            // The domain must be bound to the digest for empty inputs and inputs
+            // whose length is a multiple of the rate as well.
+            for len in [0, 5, rate_width, 2 * rate_width] {
+                let elems: Vec<F> = (0..len).map(|_| F::rand(&mut rng)).collect();
+
+                let hash_with_domain = |domain: &[F]| {
+                    let mut hasher =
+                        AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::with_domain_field(domain);
+                    hasher.update_field(&elems);
+                    hasher.finalize()
+                };
+
+                let digest = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field(&elems);
+                assert_eq!(digest, hash_with_domain(&zero));
+                assert_ne!(digest, hash_with_domain(&one));
+                assert_ne!(digest, hash_with_domain(&tag));
+                assert_ne!(hash_with_domain(&one), hash_with_domain(&tag));
+
+                // All elements of the tag are bound to the digest.
+                let mut other_tag = tag.clone();
+                other_tag[P::CAPACITY_WIDTH - 1] += F::one();
+                assert_ne!(hash_with_domain(&tag), hash_with_domain(&other_tag));
+                other_tag[0] += F::one();
+                assert_ne!(hash_with_domain(&tag), hash_with_domain(&other_tag));
+
+                let mut hasher =
+                    AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::with_domain(b"domain");
+                hasher.update_field(&elems);
+                assert_eq!(hasher.finalize(), hash_with_domain(&tag));
+            }
+
+            let input: Vec<F> = (0..2 * NUM_COLUMNS).map(|_| F::rand(&mut rng)).collect();
+            let output = AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::compress(&input);
+            assert_eq!(
+                output,
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::compress_with_domain(
+                    &input,
+                    F::zero()
+                )
+            );
+            assert_ne!(
+                output,
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::compress_with_domain(&input, tag[0])
+            );
+        }
+    }
+
+    struct MergeCheck;
+
+    impl InstantiationCheck for MergeCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let mut random_digest = || {
+                AnemoiDigest::<F, DIGEST_SIZE>::new([(); DIGEST_SIZE].map(|_| F::rand(&mut rng)))
+            };
+            let a = random_digest();
+            let b = random_digest();
+            let c = random_digest();
+
+            // The merge depends on both digests, and on their order.
+            let merge = |x: AnemoiDigest<F, DIGEST_SIZE>, y: AnemoiDigest<F, DIGEST_SIZE>| {
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::merge(&[x, y])
+            };
+            assert_ne!(merge(a, b), merge(a, c));
+            assert_ne!(merge(a, b), merge(c, b));
+            assert_ne!(merge(a, b), merge(a, a));
+            assert_ne!(merge(a, b), merge(b, a));
+        }
+    }
+
+    #[test]
+    fn test_merge() {
+        check_all_instantiations::<MergeCheck>();
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        check_all_instantiations::<DomainCheck>();
+    }
+
+    #[test]
+    fn test_xof() {
+        check_all_instantiations::<XofCheck>();
+    }
+
+    #[test]
+    fn test_streaming() {
+        check_all_instantiations::<StreamingCheck>();
+    }
+
+    #[test]
+    fn test_xof_bytes_per_block() {
+        // BLS12-381, BN-254 and Goldilocks at 128 bits of security.
+        assert_eq!(elements_per_block(381, 128), 1);
+        assert_eq!(bytes_per_block(381, 128), 31);
+        assert_eq!(elements_per_block(254, 128), 1);
+        assert_eq!(bytes_per_block(254, 128), 15);
+        assert_eq!(elements_per_block(64, 128), 3);
+        assert_eq!(bytes_per_block(64, 128), 7);
+        // BabyBear and Mersenne-31 at 128 bits of security.
+        assert_eq!(elements_per_block(31, 128), 5);
+        assert_eq!(bytes_per_block(31, 128), 2);
+        // BN-254 at 80 and 256 bits of security.
+        assert_eq!(bytes_per_block(254, 80), 21);
+        assert_eq!(elements_per_block(254, 256), 2);
+        assert_eq!(bytes_per_block(254, 256), 31);
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let max = u64::MAX;
+        // (2^64 - 1)^2 + (2^64 - 1) = 2^128 - 2^64
+        assert_eq!(mul_add(&[max], &[max], &[max]), vec![0, max, 0]);
+        assert_eq!(mul_add(&[], &[3, 1], &[5]), vec![5, 0, 0]);
+        // (2^64 + 2) * 3 + 2^128 + 1 = 2^128 + 3 * 2^64 + 7
+        assert_eq!(mul_add(&[2, 1], &[3], &[1, 0, 1]), vec![7, 3, 1, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "bls_381")]
+    fn test_xof_bytes_per_block_bls_381() {
+        use crate::bls12_381::anemoi_2_1::AnemoiXofReader;
+
+        assert_eq!(AnemoiXofReader::ELEMENTS_PER_BLOCK, 1);
+        assert_eq!(AnemoiXofReader::BYTES_PER_BLOCK, 31);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "bls_381")]
+    fn test_update_field_with_pending_bytes() {
+        use crate::bls12_381::{anemoi_4_3::AnemoiHash, Felt};
+
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1, 2, 3]);
+        hasher.update_field(&[Felt::from(1u64)]);
+    }
+}
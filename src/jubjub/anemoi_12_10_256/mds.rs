@@ -0,0 +1,230 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000005fffffffa,
+        0x098e27ee0009d806,
+        0xcca4efcfc634efe0,
+        0x486e140d064f104e,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000007fffffff8,
+        0x6212dfe8000d2008,
+        0x66313fbfb2f13fd5,
+        0x6092c566b31415be,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000afffffff5,
+        0x66d9f3df00120c0b,
+        0xcc83b7a7960bb7c5,
+        0x04c9cf6d363b9de5,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000cfffffff3,
+        0xbf5eabd90015540d,
+        0x6610079782c807ba,
+        0x1cee80c6e300a355,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000cfffffff3,
+        0xbf5eabd90015540d,
+        0x6610079782c807ba,
+        0x1cee80c6e300a355,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000005fffffffa,
+        0x098e27ee0009d806,
+        0xcca4efcfc634efe0,
+        0x486e140d064f104e,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000007fffffff8,
+        0x6212dfe8000d2008,
+        0x66313fbfb2f13fd5,
+        0x6092c566b31415be,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000afffffff5,
+        0x66d9f3df00120c0b,
+        0xcc83b7a7960bb7c5,
+        0x04c9cf6d363b9de5,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000afffffff5,
+        0x66d9f3df00120c0b,
+        0xcc83b7a7960bb7c5,
+        0x04c9cf6d363b9de5,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000cfffffff3,
+        0xbf5eabd90015540d,
+        0x6610079782c807ba,
+        0x1cee80c6e300a355,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000005fffffffa,
+        0x098e27ee0009d806,
+        0xcca4efcfc634efe0,
+        0x486e140d064f104e,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000007fffffff8,
+        0x6212dfe8000d2008,
+        0x66313fbfb2f13fd5,
+        0x6092c566b31415be,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000007fffffff8,
+        0x6212dfe8000d2008,
+        0x66313fbfb2f13fd5,
+        0x6092c566b31415be,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000afffffff5,
+        0x66d9f3df00120c0b,
+        0xcc83b7a7960bb7c5,
+        0x04c9cf6d363b9de5,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000cfffffff3,
+        0xbf5eabd90015540d,
+        0x6610079782c807ba,
+        0x1cee80c6e300a355,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000005fffffffa,
+        0x098e27ee0009d806,
+        0xcca4efcfc634efe0,
+        0x486e140d064f104e,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000005fffffffa,
+        0x098e27ee0009d806,
+        0xcca4efcfc634efe0,
+        0x486e140d064f104e,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000007fffffff8,
+        0x6212dfe8000d2008,
+        0x66313fbfb2f13fd5,
+        0x6092c566b31415be,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000afffffff5,
+        0x66d9f3df00120c0b,
+        0xcc83b7a7960bb7c5,
+        0x04c9cf6d363b9de5,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000cfffffff3,
+        0xbf5eabd90015540d,
+        0x6610079782c807ba,
+        0x1cee80c6e300a355,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000005fffffffa,
+        0x098e27ee0009d806,
+        0xcca4efcfc634efe0,
+        0x486e140d064f104e,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000007fffffff8,
+        0x6212dfe8000d2008,
+        0x66313fbfb2f13fd5,
+        0x6092c566b31415be,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000afffffff5,
+        0x66d9f3df00120c0b,
+        0xcc83b7a7960bb7c5,
+        0x04c9cf6d363b9de5,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000cfffffff3,
+        0xbf5eabd90015540d,
+        0x6610079782c807ba,
+        0x1cee80c6e300a355,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ])),
+];
@@ -0,0 +1,571 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x00000055ffffffaa,
+            0xde4ae6fe008d1856,
+            0xca916d4cc3a16e35,
+            0x0e29ca100517e9be,
+        ])),
+        Felt::new(BigInteger256([
+            0x8e4b366c91d85f64,
+            0xd1725408b713a189,
+            0x191fa3dbeb1f02f3,
+            0x2db6b209c4f75e49,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x39e0de29f4b12254,
+            0x4542c782afdc7771,
+            0x73b230448757841f,
+            0x4c66a29cf76de864,
+        ])),
+        Felt::new(BigInteger256([
+            0x07cc39a6994baddc,
+            0x5cfc96417918d480,
+            0x5cf7196e6c84dfed,
+            0x68d156f877ea8a5f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3fc21fde7d7b78d8,
+            0x82f0645e8f5af33c,
+            0x1bb34fcb9aea8258,
+            0x15a7d78b0c310f52,
+        ])),
+        Felt::new(BigInteger256([
+            0xe85354c6303129ed,
+            0x4a4c3ada62e4e51d,
+            0xb822a76308dd3aaf,
+            0x38aaca5df031d0d9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe7c71a3a93f9cf8d,
+            0xc1a4d5f501e1e624,
+            0xe0520e391ee539f2,
+            0x5ce1f5aa1353f292,
+        ])),
+        Felt::new(BigInteger256([
+            0xa6c8d78f14ddbc80,
+            0x42963f76b3326fb7,
+            0x22d86558b3d7dddd,
+            0x4ea73ae6d7a1f859,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf468ec640f9392e9,
+            0xd0b2b188f86888b7,
+            0x32641a368b203537,
+            0x63a9c811eda5f734,
+        ])),
+        Felt::new(BigInteger256([
+            0x2994338bf99e4923,
+            0x2b69c199a60c5746,
+            0x9357e42aed43113a,
+            0x2578a301f83072a8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xaea1c1ed34038852,
+            0x8fee23ccb54da3d2,
+            0x223ee1a3d2627714,
+            0x0e328961d797ccde,
+        ])),
+        Felt::new(BigInteger256([
+            0xf0c935cc3a1e02e5,
+            0x253d70a70c145e9c,
+            0x4719f12fdf39facc,
+            0x2ff8e18bc4cffb1c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc4534349519b429e,
+            0x7afc9f1db435b2c5,
+            0x90bddf0600dc3a58,
+            0x73050f54a903dff4,
+        ])),
+        Felt::new(BigInteger256([
+            0x1e1a7421bb7bfd58,
+            0xe3d16bed961a9a0a,
+            0x488c10f297eef93c,
+            0x6b357fc8861ef0d8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1f77830c66fb7746,
+            0xc7e57426850a228b,
+            0x90c8fd2720008c56,
+            0x0db8e1cc4926a688,
+        ])),
+        Felt::new(BigInteger256([
+            0x732855acb4b0ecb4,
+            0x2ccd4c938458f9b7,
+            0x0413cda2c83f417d,
+            0x442a3022818fe55b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x80c25874b4297a46,
+            0xaa9f314b56e67a01,
+            0xc2cfcec4f5772235,
+            0x19b1f168c24e4630,
+        ])),
+        Felt::new(BigInteger256([
+            0x6fd624771d471e51,
+            0x87836a0ff5ceb3b7,
+            0x71d3d846a2949e7b,
+            0x2cd481e87b3d8b56,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x7179a4ce184eefbc,
+            0x347ac5811ccf5b5d,
+            0xc914af5ca815aa17,
+            0x6a3578c4c95b5a78,
+        ])),
+        Felt::new(BigInteger256([
+            0xf965ecd5a3b011dc,
+            0xc82cc1c4799cfc98,
+            0xe9b90a31b6fae240,
+            0x24c94dafbaa90180,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x794b4100dd1885cb,
+            0x473889e579f746ef,
+            0xd268e9e7d52bfbd4,
+            0x3523006b457febe7,
+        ])),
+        Felt::new(BigInteger256([
+            0x12ecadedcf006432,
+            0x1451870846c4f490,
+            0x3add7167ec731b7d,
+            0x6e49209c3f3ae598,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc55c39240eeaba51,
+            0xf27f43ba16fde720,
+            0xe5ff54914a397668,
+            0x38718ba071c2dc66,
+        ])),
+        Felt::new(BigInteger256([
+            0xcaf6246737b7497c,
+            0x93673b95be09e3b3,
+            0xc698ff4f0af39654,
+            0x3d3b5e55166c7bda,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x740cea485117c11a,
+            0x4d6661c82aac9ac0,
+            0x4627ef655e4f1327,
+            0x6a10d5663c8a24a3,
+        ])),
+        Felt::new(BigInteger256([
+            0xe40a0a182af89611,
+            0x153d2bb68c6e925f,
+            0x30dee4b639e7d82b,
+            0x7079199588380f7b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf9a548af2e4428ed,
+            0x7e8a037c215f10bd,
+            0x8c39efe749e53632,
+            0x1aa0a3d77819d265,
+        ])),
+        Felt::new(BigInteger256([
+            0xd967e677f99d4b94,
+            0x388c81f0b6b8a6cb,
+            0x4fd2444ca14685fc,
+            0x14c4e7be1c4f1737,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x74073016720a1350,
+            0x3d923251644d8036,
+            0xa9747caea29d99c7,
+            0x071a6ea4666478dc,
+        ])),
+        Felt::new(BigInteger256([
+            0x6a15e55b88ce5382,
+            0x8ca20044238fa22e,
+            0x80adda729b9b974b,
+            0x716d24367d17eabc,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xae91a1d77faf6323,
+            0x1c99942a8063cf67,
+            0x0a03777e699efe83,
+            0x38411bddbcc55532,
+        ])),
+        Felt::new(BigInteger256([
+            0x51ac6eac01075636,
+            0xc233e0aaf7769682,
+            0x8d0fe462df6e5bab,
+            0x6108ec4f8aa3a15d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x06cff957e7e8f721,
+            0x36864d1fbcf36304,
+            0xa1429057a9d4289a,
+            0x4d4209ab37c8a348,
+        ])),
+        Felt::new(BigInteger256([
+            0x679f4e534f6614b2,
+            0xceac167f337dc80e,
+            0x5c1165671af7eaa3,
+            0x2693e0e20ae6ea28,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xef00b0381bff71d6,
+            0x1bec1a892294bda2,
+            0x510d3f697aaa5ee7,
+            0x16a8f36730e1f797,
+        ])),
+        Felt::new(BigInteger256([
+            0x69e3ca128e43c0bf,
+            0xc4c97b8802bf007c,
+            0x353f92e483cbad57,
+            0x0a380e27d491d879,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa7f0da0e84adb570,
+            0x3824e496e12e0ffc,
+            0xfb70dfe5ca0fe1be,
+            0x6039283ff91f9f2a,
+        ])),
+        Felt::new(BigInteger256([
+            0x0e8df31d487d6862,
+            0xb470e96a6995ab2d,
+            0xb96e05dad0092580,
+            0x042e026296cfb95f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbb86801af7be44db,
+            0xea9a75442038863f,
+            0xc0275b7e1f490ab9,
+            0x07a1ffd9ef3df859,
+        ])),
+        Felt::new(BigInteger256([
+            0x77fa8f2c2fb2f0f6,
+            0x021655dc5cdf2a40,
+            0x8948fec8cc65ca27,
+            0x01037655ca0d7459,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xdb6db731db6db685,
+            0x7142c545dbfb8331,
+            0x900aec24bf80a3ea,
+            0x010d14a33c66b40d,
+        ])),
+        Felt::new(BigInteger256([
+            0x8454e7e5ad0ee33d,
+            0x3c9b7db4e87bdf02,
+            0x624ff7a9d9eff4f0,
+            0x35b144c687f7dcc0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x85a7e15aaf2d443b,
+            0x117700705caabf2b,
+            0x388a69df469d9aaa,
+            0x24a3065923591607,
+        ])),
+        Felt::new(BigInteger256([
+            0x6e2f377493909cc1,
+            0x01621a937be0eed7,
+            0xa58627ff1ebcb2c0,
+            0x562502de2f876c2a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x64d2592ebf0f4b82,
+            0xe00a6585e62ef6c5,
+            0x018a3ce5c7ba4395,
+            0x58df94b12b8f8584,
+        ])),
+        Felt::new(BigInteger256([
+            0x27ff88b4b18dc994,
+            0x2bd9e3630fb45f45,
+            0xee76916b1efce02f,
+            0x1d0c285a71a47deb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x881777a8f8b525a4,
+            0x0cecfabfd8b3dad2,
+            0x8309a9d37073249b,
+            0x468825ca5ab5c71d,
+        ])),
+        Felt::new(BigInteger256([
+            0x61b52f9ab961df95,
+            0x660fafa5dffe3702,
+            0x4946d5e8f85784cd,
+            0x4d64b330aab5810c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf6f0aa2500024218,
+            0x4265762a8c34c425,
+            0x79d9852ea691db8b,
+            0x40cff07e2f0a974f,
+        ])),
+        Felt::new(BigInteger256([
+            0x46b7ebea29d5c550,
+            0x754dd19f8fd26551,
+            0x5e842418fba673d5,
+            0x17b61397c546c6ec,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x41dad06db733ecfe,
+            0xd08ca59bf4b3c172,
+            0x5ea6eae943e9a327,
+            0x4ca798897a1986e9,
+        ])),
+        Felt::new(BigInteger256([
+            0x9e9e3eeafd17348e,
+            0xea4f99d7a175f2da,
+            0xd3fef7633a110b21,
+            0x0f979189c965ec07,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xacc654df74dce163,
+            0xade94d12e983c7d7,
+            0xc5606bd87f206595,
+            0x63f66c0a34d7ab35,
+        ])),
+        Felt::new(BigInteger256([
+            0x212980551e86691b,
+            0xeeef6547216281b9,
+            0x00e572bb0924e0c1,
+            0x713e24a79da47042,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xda40257681aa35eb,
+            0x506dc70d67f989a9,
+            0x2fd11bf58d484e28,
+            0x156d5f9b7f802afa,
+        ])),
+        Felt::new(BigInteger256([
+            0x488cf2b40f287857,
+            0x8d86eadebd423373,
+            0x26d2c1672878bf96,
+            0x60f5f61b439b1df5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4b322f3ccd0ecf67,
+            0x797ce549c39bcbef,
+            0x343809622d01f632,
+            0x2a0f6ef12a584db9,
+        ])),
+        Felt::new(BigInteger256([
+            0x54e1f5dc75f54070,
+            0x2e926972b87dd842,
+            0x66f2e7d9cd112ec0,
+            0x5249479a6ef94707,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x94d8dd7553f1e7b5,
+            0x81132674f7e8767a,
+            0x94adad7a6ef16db8,
+            0x704845e0ca98ec0b,
+        ])),
+        Felt::new(BigInteger256([
+            0x3761201a1f1bd6d3,
+            0xecf66e1caaafea53,
+            0x3908dd4570c86229,
+            0x3ff362f54798473c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6fb35712e61106d3,
+            0x5b6816cc8e042489,
+            0x30e28a835048c5a8,
+            0x5cff3fcdcf9efb5d,
+        ])),
+        Felt::new(BigInteger256([
+            0x23f0be9e17c1b237,
+            0xacf4bb50b0cd48c8,
+            0xe9d40ef150dfc993,
+            0x374f00d52b6e2bed,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xca468ceb9c49f34f,
+            0xacd4547375fb7978,
+            0x72723646766d9b70,
+            0x3a4d7885d712c459,
+        ])),
+        Felt::new(BigInteger256([
+            0xea7c72cc04df4f78,
+            0x25ed97b3730148a8,
+            0xd6c2b5fa2a1977a4,
+            0x542e9364076e17f5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbb633db4dffe7db8,
+            0x36bb142399035de3,
+            0x01d18bbd42d58989,
+            0x2b4cba1eae3364cd,
+        ])),
+        Felt::new(BigInteger256([
+            0x45fc5821f9a81fad,
+            0xd6c3297650bf2820,
+            0x703f560411600ad4,
+            0x46cc4677859303cd,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x7cb7dfe6170f2533,
+            0x6264668431f21f7d,
+            0x1db2258208960f22,
+            0x6b161522ae6c6270,
+        ])),
+        Felt::new(BigInteger256([
+            0x7716784d223114d7,
+            0xa0da8c5a1d472c29,
+            0x31c776d54947432e,
+            0x0663f9dfb4b5de22,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc29a141c0fb82c00,
+            0xb333a6c30d4188af,
+            0x814d5ff59454ff21,
+            0x0132789dc94536c2,
+        ])),
+        Felt::new(BigInteger256([
+            0xd344c3ff6645392f,
+            0x86b71c17227f2145,
+            0xa903baa776a2e0e8,
+            0x0caecf06420cdf82,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8af61c0668d61aa6,
+            0xe0813377b62d328e,
+            0xa44b08c61acc31e8,
+            0x3475fa1554677d3d,
+        ])),
+        Felt::new(BigInteger256([
+            0x48ace37829f6dab7,
+            0x5e4ccb5c8339cc46,
+            0xab0e4aa0838d4b59,
+            0x725512b0adf77d91,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x624ba433b8ba647b,
+            0xba17d48ad0e545ec,
+            0x0007af245e943fc5,
+            0x48b10de0932c24e3,
+        ])),
+        Felt::new(BigInteger256([
+            0xddb6f3cc60004f0a,
+            0x2a6ee94e9d697d93,
+            0x3e8d5929c2a9be17,
+            0x371a2d40f1fc1feb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbd3a2c24afa9f346,
+            0x6822c237b2f39f09,
+            0x65d4b0e7d31fce3e,
+            0x0bd42e53c5fa917e,
+        ])),
+        Felt::new(BigInteger256([
+            0x52b9409c61b70f2d,
+            0xe9316e9ae917b480,
+            0xcdbdd958cf32d8f6,
+            0x147a913df55c2688,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa6e9676aba54a90a,
+            0x960a5f6ab82187d9,
+            0x7549262feed32b37,
+            0x437420ee3a3561e0,
+        ])),
+        Felt::new(BigInteger256([
+            0x28227b15bded28fb,
+            0x3e4553a5968151a6,
+            0xea36f922f1600347,
+            0x706dea8d8d34ad85,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x82a6b6fc4338e868,
+            0xa3f9f8de585b90fc,
+            0xf74b293c974e2b60,
+            0x668717b9eb7af704,
+        ])),
+        Felt::new(BigInteger256([
+            0x59b6c0abbaf66180,
+            0x3fe980d7eafdab9b,
+            0x10e9c9752dbacf10,
+            0x01122f0c285ea9e5,
+        ])),
+    ],
+];
@@ -0,0 +1,291 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x00000055ffffffaa,
+            0xde4ae6fe008d1856,
+            0xca916d4cc3a16e35,
+            0x0e29ca100517e9be,
+        ])),
+        Felt::new(BigInteger256([
+            0x8e4b366c91d85f64,
+            0xd1725408b713a189,
+            0x191fa3dbeb1f02f3,
+            0x2db6b209c4f75e49,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x39e0de29f4b12254,
+            0x4542c782afdc7771,
+            0x73b230448757841f,
+            0x4c66a29cf76de864,
+        ])),
+        Felt::new(BigInteger256([
+            0x07cc39a6994baddc,
+            0x5cfc96417918d480,
+            0x5cf7196e6c84dfed,
+            0x68d156f877ea8a5f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3fc21fde7d7b78d8,
+            0x82f0645e8f5af33c,
+            0x1bb34fcb9aea8258,
+            0x15a7d78b0c310f52,
+        ])),
+        Felt::new(BigInteger256([
+            0xe85354c6303129ed,
+            0x4a4c3ada62e4e51d,
+            0xb822a76308dd3aaf,
+            0x38aaca5df031d0d9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe7c71a3a93f9cf8d,
+            0xc1a4d5f501e1e624,
+            0xe0520e391ee539f2,
+            0x5ce1f5aa1353f292,
+        ])),
+        Felt::new(BigInteger256([
+            0xa6c8d78f14ddbc80,
+            0x42963f76b3326fb7,
+            0x22d86558b3d7dddd,
+            0x4ea73ae6d7a1f859,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf468ec640f9392e9,
+            0xd0b2b188f86888b7,
+            0x32641a368b203537,
+            0x63a9c811eda5f734,
+        ])),
+        Felt::new(BigInteger256([
+            0x2994338bf99e4923,
+            0x2b69c199a60c5746,
+            0x9357e42aed43113a,
+            0x2578a301f83072a8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xaea1c1ed34038852,
+            0x8fee23ccb54da3d2,
+            0x223ee1a3d2627714,
+            0x0e328961d797ccde,
+        ])),
+        Felt::new(BigInteger256([
+            0xf0c935cc3a1e02e5,
+            0x253d70a70c145e9c,
+            0x4719f12fdf39facc,
+            0x2ff8e18bc4cffb1c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc4534349519b429e,
+            0x7afc9f1db435b2c5,
+            0x90bddf0600dc3a58,
+            0x73050f54a903dff4,
+        ])),
+        Felt::new(BigInteger256([
+            0x1e1a7421bb7bfd58,
+            0xe3d16bed961a9a0a,
+            0x488c10f297eef93c,
+            0x6b357fc8861ef0d8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1f77830c66fb7746,
+            0xc7e57426850a228b,
+            0x90c8fd2720008c56,
+            0x0db8e1cc4926a688,
+        ])),
+        Felt::new(BigInteger256([
+            0x732855acb4b0ecb4,
+            0x2ccd4c938458f9b7,
+            0x0413cda2c83f417d,
+            0x442a3022818fe55b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x80c25874b4297a46,
+            0xaa9f314b56e67a01,
+            0xc2cfcec4f5772235,
+            0x19b1f168c24e4630,
+        ])),
+        Felt::new(BigInteger256([
+            0x6fd624771d471e51,
+            0x87836a0ff5ceb3b7,
+            0x71d3d846a2949e7b,
+            0x2cd481e87b3d8b56,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x7179a4ce184eefbc,
+            0x347ac5811ccf5b5d,
+            0xc914af5ca815aa17,
+            0x6a3578c4c95b5a78,
+        ])),
+        Felt::new(BigInteger256([
+            0xf965ecd5a3b011dc,
+            0xc82cc1c4799cfc98,
+            0xe9b90a31b6fae240,
+            0x24c94dafbaa90180,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xdb6db731db6db685,
+            0x7142c545dbfb8331,
+            0x900aec24bf80a3ea,
+            0x010d14a33c66b40d,
+        ])),
+        Felt::new(BigInteger256([
+            0x8454e7e5ad0ee33d,
+            0x3c9b7db4e87bdf02,
+            0x624ff7a9d9eff4f0,
+            0x35b144c687f7dcc0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x85a7e15aaf2d443b,
+            0x117700705caabf2b,
+            0x388a69df469d9aaa,
+            0x24a3065923591607,
+        ])),
+        Felt::new(BigInteger256([
+            0x6e2f377493909cc1,
+            0x01621a937be0eed7,
+            0xa58627ff1ebcb2c0,
+            0x562502de2f876c2a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x64d2592ebf0f4b82,
+            0xe00a6585e62ef6c5,
+            0x018a3ce5c7ba4395,
+            0x58df94b12b8f8584,
+        ])),
+        Felt::new(BigInteger256([
+            0x27ff88b4b18dc994,
+            0x2bd9e3630fb45f45,
+            0xee76916b1efce02f,
+            0x1d0c285a71a47deb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x881777a8f8b525a4,
+            0x0cecfabfd8b3dad2,
+            0x8309a9d37073249b,
+            0x468825ca5ab5c71d,
+        ])),
+        Felt::new(BigInteger256([
+            0x61b52f9ab961df95,
+            0x660fafa5dffe3702,
+            0x4946d5e8f85784cd,
+            0x4d64b330aab5810c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf6f0aa2500024218,
+            0x4265762a8c34c425,
+            0x79d9852ea691db8b,
+            0x40cff07e2f0a974f,
+        ])),
+        Felt::new(BigInteger256([
+            0x46b7ebea29d5c550,
+            0x754dd19f8fd26551,
+            0x5e842418fba673d5,
+            0x17b61397c546c6ec,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x41dad06db733ecfe,
+            0xd08ca59bf4b3c172,
+            0x5ea6eae943e9a327,
+            0x4ca798897a1986e9,
+        ])),
+        Felt::new(BigInteger256([
+            0x9e9e3eeafd17348e,
+            0xea4f99d7a175f2da,
+            0xd3fef7633a110b21,
+            0x0f979189c965ec07,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xacc654df74dce163,
+            0xade94d12e983c7d7,
+            0xc5606bd87f206595,
+            0x63f66c0a34d7ab35,
+        ])),
+        Felt::new(BigInteger256([
+            0x212980551e86691b,
+            0xeeef6547216281b9,
+            0x00e572bb0924e0c1,
+            0x713e24a79da47042,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xda40257681aa35eb,
+            0x506dc70d67f989a9,
+            0x2fd11bf58d484e28,
+            0x156d5f9b7f802afa,
+        ])),
+        Felt::new(BigInteger256([
+            0x488cf2b40f287857,
+            0x8d86eadebd423373,
+            0x26d2c1672878bf96,
+            0x60f5f61b439b1df5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4b322f3ccd0ecf67,
+            0x797ce549c39bcbef,
+            0x343809622d01f632,
+            0x2a0f6ef12a584db9,
+        ])),
+        Felt::new(BigInteger256([
+            0x54e1f5dc75f54070,
+            0x2e926972b87dd842,
+            0x66f2e7d9cd112ec0,
+            0x5249479a6ef94707,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x94d8dd7553f1e7b5,
+            0x81132674f7e8767a,
+            0x94adad7a6ef16db8,
+            0x704845e0ca98ec0b,
+        ])),
+        Felt::new(BigInteger256([
+            0x3761201a1f1bd6d3,
+            0xecf66e1caaafea53,
+            0x3908dd4570c86229,
+            0x3ff362f54798473c,
+        ])),
+    ],
+];
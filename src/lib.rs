@@ -4,6 +4,10 @@
 //!
 //! All hash instantiations are defined using a `Sponge` trait and can both
 //! process sequences of bytes or native field elements.
+//!
+//! Each instantiation is a set of `AnemoiParameters` for the generic
+//! `Anemoi` permutation, parameterized by its underlying field and its
+//! number of columns.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(rustdoc::broken_intra_doc_links)]
@@ -19,6 +23,48 @@ extern crate alloc;
 mod traits;
 pub use traits::*;
 
+mod permutation;
+pub use permutation::*;
+
+mod digest;
+pub use digest::*;
+
+mod hasher;
+pub use hasher::*;
+
+mod duplex;
+pub use duplex::*;
+
+mod safe;
+pub use safe::*;
+
+mod transcript;
+pub use transcript::*;
+
+pub mod air;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+
+pub mod merkle;
+
+pub mod plonk;
+
+#[cfg(feature = "r1cs")]
+pub mod r1cs;
+
+#[cfg(any(feature = "secp256k1", feature = "secq256k1"))]
+mod full_fp256;
+
+#[cfg(test)]
+mod test_utils;
+
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over the BabyBear field.
+#[cfg(feature = "baby_bear")]
+pub mod baby_bear;
+
 /// An implementation of instantiations of the Anemoi permutation
 /// in Sponge and Jive modes targetting the 128-bit security level
 /// over BLS12-377 base field.
@@ -37,24 +83,84 @@ pub mod bls12_381;
 #[cfg(feature = "bn_254")]
 pub mod bn_254;
 
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over BW6-761 base field.
+#[cfg(feature = "bw6_761")]
+pub mod bw6_761;
+
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over Curve25519 base field.
+#[cfg(feature = "curve25519")]
+pub mod curve25519;
+
 /// An implementation of instantiations of the Anemoi permutation
 /// in Sponge and Jive modes targetting the 128-bit security level
 /// over ED_ON_BLS12-377 base field.
 #[cfg(feature = "ed_on_bls12_377")]
 pub mod ed_on_bls12_377;
 
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over the Goldilocks field.
+#[cfg(feature = "goldilocks")]
+pub mod goldilocks;
+
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over Grumpkin base field.
+#[cfg(feature = "grumpkin")]
+pub mod grumpkin;
+
 /// An implementation of instantiations of the Anemoi permutation
 /// in Sponge and Jive modes targetting the 128-bit security level
 /// over Jubjub base field.
 #[cfg(feature = "jubjub")]
 pub mod jubjub;
 
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over the Mersenne-31 field.
+#[cfg(feature = "mersenne_31")]
+pub mod mersenne_31;
+
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over MNT4-298 base field.
+#[cfg(feature = "mnt4_298")]
+pub mod mnt4_298;
+
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over MNT6-298 base field.
+#[cfg(feature = "mnt6_298")]
+pub mod mnt6_298;
+
 /// An implementation of instantiations of the Anemoi permutation
 /// in Sponge and Jive modes targetting the 128-bit security level
 /// over Pallas base field.
 #[cfg(feature = "pallas")]
 pub mod pallas;
 
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over secp256k1 base field.
+#[cfg(feature = "secp256k1")]
+pub mod secp256k1;
+
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over secp256k1 scalar field.
+#[cfg(feature = "secq256k1")]
+pub mod secq256k1;
+
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over the Stark field.
+#[cfg(feature = "stark_252")]
+pub mod stark_252;
+
 /// An implementation of instantiations of the Anemoi permutation
 /// in Sponge and Jive modes targetting the 128-bit security level
 /// over Vesta base field.
@@ -0,0 +1,294 @@
+//! Batched openings of several leaves of a Merkle tree
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::{MerkleError, MerkleTree};
+use crate::Jive;
+
+use ark_ff::PrimeField;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// An authentication path for several leaves of a Merkle tree at once.
+///
+/// Siblings shared by several leaves, or which can be recomputed from the
+/// opened leaves, are only included once, if at all. The nodes are stored
+/// level by level from the leaves up to the root, and within each level in
+/// increasing order of their position.
+pub struct MerkleBatchPath<F: PrimeField, const NODE_SIZE: usize> {
+    depth: usize,
+    nodes: Vec<[F; NODE_SIZE]>,
+}
+
+impl<F: PrimeField, const NODE_SIZE: usize> MerkleBatchPath<F, NODE_SIZE> {
+    /// Returns a new batch authentication path for a tree of
+    /// depth `depth`, from the provided sequence of nodes.
+    pub fn new(depth: usize, nodes: Vec<[F; NODE_SIZE]>) -> Self {
+        Self { depth, nodes }
+    }
+
+    /// Returns the depth of the tree this path relates to.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the nodes of this path.
+    pub fn nodes(&self) -> &[[F; NODE_SIZE]] {
+        &self.nodes
+    }
+}
+
+impl<F, H, const ARITY: usize, const NODE_SIZE: usize> MerkleTree<F, H, ARITY, NODE_SIZE>
+where
+    F: PrimeField,
+    H: Jive<F>,
+{
+    /// Returns a batch authentication path for the leaves at the provided
+    /// positions, which may be given in any order and contain duplicates.
+    pub fn prove_batch(
+        &self,
+        indices: &[usize],
+    ) -> Result<MerkleBatchPath<F, NODE_SIZE>, MerkleError> {
+        if indices.is_empty() {
+            return Err(MerkleError::EmptyBatch);
+        }
+        if let Some(&index) = indices.iter().find(|&&i| i >= self.num_leaves) {
+            return Err(MerkleError::InvalidLeafIndex(index));
+        }
+
+        let mut positions = indices.to_vec();
+        positions.sort_unstable();
+        positions.dedup();
+
+        let mut nodes = Vec::new();
+        for layer in self.layers[..self.depth()].iter() {
+            let mut parents = Vec::with_capacity(positions.len());
+            for group in positions.chunk_by(|a, b| a / ARITY == b / ARITY) {
+                let first = group[0] - group[0] % ARITY;
+                nodes.extend(
+                    (first..first + ARITY)
+                        .filter(|i| group.binary_search(i).is_err())
+                        .map(|i| layer[i]),
+                );
+                parents.push(group[0] / ARITY);
+            }
+            positions = parents;
+        }
+
+        Ok(MerkleBatchPath::new(self.depth(), nodes))
+    }
+
+    /// Returns the root obtained by hashing the provided leaves, given with
+    /// their positions, along the provided batch authentication path, or
+    /// `None` if it is malformed or a position is given conflicting leaves.
+    ///
+    /// The number of leaves of the tree is provided by the verifier: the
+    /// depth of the path must match the one of such a tree, and no position
+    /// may point to a padding leaf.
+    ///
+    /// Each node above the opened leaves is computed with
+    /// a single call to `Jive::compress_k`.
+    pub fn compute_batch_root(
+        leaves: &[(usize, [F; NODE_SIZE])],
+        path: &MerkleBatchPath<F, NODE_SIZE>,
+        num_leaves: usize,
+    ) -> Option<[F; NODE_SIZE]> {
+        if leaves.is_empty()
+            || path.depth != Self::depth_for(num_leaves)
+            || leaves.iter().any(|(i, _)| *i >= num_leaves)
+        {
+            return None;
+        }
+
+        let mut known = leaves.to_vec();
+        known.sort_unstable_by_key(|(i, _)| *i);
+        known.dedup_by(|a, b| a.0 == b.0 && a.1 == b.1);
+        if known.windows(2).any(|w| w[0].0 == w[1].0) {
+            return None;
+        }
+
+        let mut path_nodes = path.nodes.iter();
+        for _ in 0..path.depth {
+            let mut parents = Vec::with_capacity(known.len());
+            for group in known.chunk_by(|a, b| a.0 / ARITY == b.0 / ARITY) {
+                let first = group[0].0 - group[0].0 % ARITY;
+                let mut children = Vec::with_capacity(ARITY);
+                let mut group_nodes = group.iter().peekable();
+                for i in first..first + ARITY {
+                    match group_nodes.peek() {
+                        Some((j, node)) if *j == i => {
+                            children.push(*node);
+                            group_nodes.next();
+                        }
+                        _ => children.push(*path_nodes.next()?),
+                    }
+                }
+                parents.push((group[0].0 / ARITY, Self::hash_children(&children)));
+            }
+            known = parents;
+        }
+
+        // All nodes of the path must have been used, and
+        // the positions must fit within its depth.
+        if path_nodes.next().is_some() || known.len() != 1 || known[0].0 != 0 {
+            return None;
+        }
+
+        Some(known[0].1)
+    }
+
+    /// Verifies that the provided leaves, given with their positions,
+    /// belong to the tree of `num_leaves` leaves of the provided root,
+    /// given their batch authentication path.
+    pub fn verify_batch(
+        root: &[F; NODE_SIZE],
+        leaves: &[(usize, [F; NODE_SIZE])],
+        path: &MerkleBatchPath<F, NODE_SIZE>,
+        num_leaves: usize,
+    ) -> bool {
+        Self::compute_batch_root(leaves, path, num_leaves).as_ref() == Some(root)
+    }
+}
+
+#[cfg(all(test, feature = "bls_381"))]
+mod tests {
+    use super::*;
+    use crate::bls12_381::{anemoi_12_11, anemoi_2_1, anemoi_4_3, anemoi_8_7, Felt};
+    use ark_ff::UniformRand;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use rand_core::{OsRng, RngCore};
+
+    static COMPRESSIONS: AtomicUsize = AtomicUsize::new(0);
+
+    /// A Jive wrapper counting its calls to `compress_k`.
+    #[derive(Debug)]
+    struct CountingJive<H>(H);
+
+    impl<H: Jive<Felt>> Jive<Felt> for CountingJive<H> {
+        const STATE_WIDTH: usize = H::STATE_WIDTH;
+
+        fn compress(elems: &[Felt]) -> Vec<Felt> {
+            H::compress(elems)
+        }
+
+        fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+            COMPRESSIONS.fetch_add(1, Ordering::Relaxed);
+            H::compress_k(elems, k)
+        }
+
+        fn compress_with_domain(elems: &[Felt], domain: Felt) -> Vec<Felt> {
+            H::compress_with_domain(elems, domain)
+        }
+    }
+
+    fn check_batch<H: Jive<Felt>, const ARITY: usize, const NODE_SIZE: usize>() {
+        let mut rng = OsRng;
+        let num_leaves = ARITY * ARITY * ARITY;
+        let leaves: Vec<[Felt; NODE_SIZE]> = (0..num_leaves)
+            .map(|_| [(); NODE_SIZE].map(|_| Felt::rand(&mut rng)))
+            .collect();
+        let tree = MerkleTree::<Felt, H, ARITY, NODE_SIZE>::new(&leaves).unwrap();
+        let root = tree.root();
+
+        for num_queries in [1, 2, ARITY + 1, num_leaves] {
+            let indices: Vec<usize> = (0..num_queries)
+                .map(|_| rng.next_u32() as usize % num_leaves)
+                .collect();
+            let opened: Vec<(usize, [Felt; NODE_SIZE])> =
+                indices.iter().map(|&i| (i, leaves[i])).collect();
+
+            let path = tree.prove_batch(&indices).unwrap();
+            assert!(MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify_batch(
+                &root, &opened, &path, num_leaves
+            ));
+
+            // The batch path never exceeds the individual ones combined.
+            let individual: usize = indices
+                .iter()
+                .map(|&i| tree.prove(i).unwrap().siblings().len() * (ARITY - 1))
+                .sum();
+            assert!(path.nodes().len() <= individual);
+
+            let mut tampered = opened.clone();
+            tampered[0].1 = [Felt::rand(&mut rng); NODE_SIZE];
+            assert!(!MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify_batch(
+                &root, &tampered, &path, num_leaves
+            ));
+        }
+
+        // The depth is fixed by the verifier, not by the path: opening an
+        // internal node of the first level against a shortened path fails.
+        let parent = [(0, tree.layers[1][0])];
+        let path = tree.prove_batch(&[0]).unwrap();
+        let short_path = MerkleBatchPath::new(path.depth() - 1, path.nodes()[ARITY - 1..].to_vec());
+        assert!(MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify_batch(
+            &root,
+            &parent,
+            &short_path,
+            num_leaves / ARITY
+        ));
+        assert!(!MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify_batch(
+            &root,
+            &parent,
+            &short_path,
+            num_leaves
+        ));
+
+        // Opening all leaves requires no additional node.
+        let indices: Vec<usize> = (0..num_leaves).collect();
+        assert!(tree.prove_batch(&indices).unwrap().nodes().is_empty());
+
+        assert_eq!(tree.prove_batch(&[]), Err(MerkleError::EmptyBatch));
+        assert_eq!(
+            tree.prove_batch(&[0, num_leaves]),
+            Err(MerkleError::InvalidLeafIndex(num_leaves))
+        );
+    }
+
+    #[test]
+    fn test_merkle_batch_path() {
+        check_batch::<anemoi_2_1::AnemoiHash, 2, 1>();
+        check_batch::<anemoi_4_3::AnemoiHash, 2, 2>();
+        check_batch::<anemoi_4_3::AnemoiHash, 4, 1>();
+        check_batch::<anemoi_8_7::AnemoiHash, 2, 4>();
+        check_batch::<anemoi_8_7::AnemoiHash, 4, 2>();
+        check_batch::<anemoi_8_7::AnemoiHash, 8, 1>();
+        check_batch::<anemoi_12_11::AnemoiHash, 3, 4>();
+        check_batch::<anemoi_12_11::AnemoiHash, 12, 1>();
+    }
+
+    #[test]
+    fn test_merkle_batch_path_compressions() {
+        type Tree = MerkleTree<Felt, CountingJive<anemoi_4_3::AnemoiHash>, 2, 2>;
+
+        let leaves: Vec<[Felt; 2]> = (0..8u64)
+            .map(|i| [Felt::from(i), Felt::from(i + 8)])
+            .collect();
+        let tree = Tree::new(&leaves).unwrap();
+        let root = tree.root();
+
+        // Leaves 0, 1 and 3 share their ancestors: the path only contains leaf 2
+        // and the right subtree of the root, and the root is recomputed with one
+        // compression for each of the nodes 0-1, 2-3, 0-3 and 0-7.
+        let path = tree.prove_batch(&[3, 0, 1]).unwrap();
+        assert_eq!(path.nodes(), &[leaves[2], tree.layers[2][1]]);
+
+        COMPRESSIONS.store(0, Ordering::Relaxed);
+        let opened = [(0, leaves[0]), (1, leaves[1]), (3, leaves[3])];
+        assert!(Tree::verify_batch(&root, &opened, &path, 8));
+        assert_eq!(COMPRESSIONS.load(Ordering::Relaxed), 4);
+
+        // Conflicting leaves at a same position are rejected.
+        let opened = [(0, leaves[0]), (0, leaves[1])];
+        let path = tree.prove_batch(&[0]).unwrap();
+        assert!(!Tree::verify_batch(&root, &opened, &path, 8));
+
+        // Padding leaves cannot be opened.
+        let tree = Tree::new(&leaves[..6]).unwrap();
+        let padded_tree = Tree::new(&[&leaves[..6], &[[Felt::from(0u64); 2]; 2]].concat()).unwrap();
+        let opened = [(6, [Felt::from(0u64); 2])];
+        let path = padded_tree.prove_batch(&[6]).unwrap();
+        assert!(Tree::verify_batch(&tree.root(), &opened, &path, 8));
+        assert!(!Tree::verify_batch(&tree.root(), &opened, &path, 6));
+    }
+}
@@ -0,0 +1,448 @@
+//! Append-only incremental Merkle trees built on the `merge`
+//! method of an Anemoi Sponge
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use core::marker::PhantomData;
+
+use super::MerkleError;
+use crate::{AnemoiDigest, Sponge};
+
+use ark_ff::PrimeField;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// An authentication path for a leaf of an incremental Merkle tree.
+///
+/// It contains the `DEPTH` siblings of the nodes on the path
+/// from the leaf up to the root.
+pub struct IncrementalMerklePath<F: PrimeField, const DIGEST_SIZE: usize> {
+    position: usize,
+    siblings: Vec<AnemoiDigest<F, DIGEST_SIZE>>,
+}
+
+impl<F: PrimeField, const DIGEST_SIZE: usize> IncrementalMerklePath<F, DIGEST_SIZE> {
+    /// Returns a new authentication path for the leaf at `position`, from
+    /// the provided siblings, ordered from the leaves level up to the root.
+    pub fn new(position: usize, siblings: Vec<AnemoiDigest<F, DIGEST_SIZE>>) -> Self {
+        Self { position, siblings }
+    }
+
+    /// Returns the position of the authenticated leaf.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the siblings of this path, from the leaves level up to the root.
+    pub fn siblings(&self) -> &[AnemoiDigest<F, DIGEST_SIZE>] {
+        &self.siblings
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// The data tracked to maintain the authentication path of a marked leaf.
+struct Witness<F: PrimeField, const DIGEST_SIZE: usize> {
+    leaf: AnemoiDigest<F, DIGEST_SIZE>,
+    // The siblings of the path which are known so far. Left siblings are
+    // known as soon as the leaf is appended, and right ones once their
+    // subtree has been entirely filled.
+    siblings: Vec<Option<AnemoiDigest<F, DIGEST_SIZE>>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// The state of an incremental Merkle tree, which can be saved and restored.
+struct TreeState<F: PrimeField, const DIGEST_SIZE: usize> {
+    size: usize,
+    // The roots of the last complete subtrees of each height which are
+    // left children, only meaningful at heights where the bit of `size`
+    // is set. The last slot holds the root once the tree is full.
+    frontier: Vec<AnemoiDigest<F, DIGEST_SIZE>>,
+    last_leaf: Option<AnemoiDigest<F, DIGEST_SIZE>>,
+    witnesses: BTreeMap<usize, Witness<F, DIGEST_SIZE>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// An append-only binary Merkle tree of depth `DEPTH` over `F`, whose nodes
+/// are digests merged with the Sponge `H`.
+///
+/// Leaves are appended from left to right, and all leaves not appended yet
+/// are set to the default digest. The tree only stores its frontier, i.e.
+/// the roots of its rightmost complete subtrees, along with the data required
+/// to maintain the authentication paths of the leaves explicitly marked by
+/// [`IncrementalMerkleTree::mark`].
+///
+/// The state of the tree can be saved with [`IncrementalMerkleTree::checkpoint`]
+/// and later restored with [`IncrementalMerkleTree::rewind`].
+pub struct IncrementalMerkleTree<F: PrimeField, H, const DEPTH: usize, const DIGEST_SIZE: usize> {
+    state: TreeState<F, DIGEST_SIZE>,
+    checkpoints: Vec<TreeState<F, DIGEST_SIZE>>,
+    // Roots of empty subtrees, indexed by their height.
+    empty_hashes: Vec<AnemoiDigest<F, DIGEST_SIZE>>,
+    _hasher: PhantomData<H>,
+}
+
+impl<F, H, const DEPTH: usize, const DIGEST_SIZE: usize> Default
+    for IncrementalMerkleTree<F, H, DEPTH, DIGEST_SIZE>
+where
+    F: PrimeField,
+    H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>>,
+{
+    fn default() -> Self {
+        assert!(
+            DEPTH > 0 && DEPTH < usize::BITS as usize,
+            "the depth of an incremental Merkle tree must be between 1 and {}",
+            usize::BITS - 1
+        );
+
+        Self {
+            state: TreeState {
+                size: 0,
+                frontier: vec![AnemoiDigest::default(); DEPTH + 1],
+                last_leaf: None,
+                witnesses: BTreeMap::new(),
+            },
+            checkpoints: Vec::new(),
+            empty_hashes: super::empty_hashes::<F, H, DIGEST_SIZE>(DEPTH),
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<F, H, const DEPTH: usize, const DIGEST_SIZE: usize>
+    IncrementalMerkleTree<F, H, DEPTH, DIGEST_SIZE>
+where
+    F: PrimeField,
+    H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>>,
+{
+    /// The maximum number of leaves of this tree.
+    pub const CAPACITY: usize = 1 << DEPTH;
+
+    /// Returns a new empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of leaves appended to this tree.
+    pub fn size(&self) -> usize {
+        self.state.size
+    }
+
+    /// Appends a leaf to this tree, and returns its position.
+    pub fn append(&mut self, leaf: AnemoiDigest<F, DIGEST_SIZE>) -> Result<usize, MerkleError> {
+        let position = self.state.size;
+        if position == Self::CAPACITY {
+            return Err(MerkleError::TreeFull);
+        }
+
+        let mut node = leaf;
+        let mut height = 0;
+        while height < DEPTH {
+            // The subtree of height `height` containing the new leaf is now
+            // complete, and may be the missing sibling of some witnesses.
+            let index = position >> height;
+            for (&marked, witness) in self.state.witnesses.iter_mut() {
+                if (marked >> height) ^ 1 == index {
+                    witness.siblings[height] = Some(node);
+                }
+            }
+
+            if index & 1 == 1 {
+                node = H::merge(&[self.state.frontier[height], node]);
+                height += 1;
+            } else {
+                break;
+            }
+        }
+        // Either `node` is a left child, or the last leaf has been appended
+        // and `node` is the root of the full tree.
+        self.state.frontier[height] = node;
+
+        self.state.size += 1;
+        self.state.last_leaf = Some(leaf);
+
+        Ok(position)
+    }
+
+    /// Returns the root of this tree.
+    pub fn root(&self) -> AnemoiDigest<F, DIGEST_SIZE> {
+        if self.state.size == Self::CAPACITY {
+            return self.state.frontier[DEPTH];
+        }
+
+        self.partial_root(DEPTH)
+    }
+
+    /// Marks the last appended leaf, so that its authentication path is
+    /// maintained as the tree grows, and returns its position.
+    ///
+    /// Returns `None` if the tree is empty.
+    pub fn mark(&mut self) -> Option<usize> {
+        let leaf = self.state.last_leaf?;
+        let position = self.state.size - 1;
+
+        // Left siblings are the roots of complete subtrees of the frontier.
+        let siblings = (0..DEPTH)
+            .map(|height| {
+                if (position >> height) & 1 == 1 {
+                    Some(self.state.frontier[height])
+                } else {
+                    None
+                }
+            })
+            .collect();
+        self.state
+            .witnesses
+            .insert(position, Witness { leaf, siblings });
+
+        Some(position)
+    }
+
+    /// Stops maintaining the authentication path of the leaf at `position`,
+    /// and returns whether it was marked.
+    pub fn unmark(&mut self, position: usize) -> bool {
+        self.state.witnesses.remove(&position).is_some()
+    }
+
+    /// Returns the positions of all marked leaves.
+    pub fn marked_positions(&self) -> Vec<usize> {
+        self.state.witnesses.keys().copied().collect()
+    }
+
+    /// Returns the marked leaf at `position`, if any.
+    pub fn marked_leaf(&self, position: usize) -> Option<AnemoiDigest<F, DIGEST_SIZE>> {
+        self.state.witnesses.get(&position).map(|w| w.leaf)
+    }
+
+    /// Returns the authentication path of the marked leaf at `position`
+    /// with respect to the current root, or `None` if it is not marked.
+    pub fn witness(&self, position: usize) -> Option<IncrementalMerklePath<F, DIGEST_SIZE>> {
+        let witness = self.state.witnesses.get(&position)?;
+
+        // Only the lowest missing right sibling may be partially filled, as
+        // all subtrees on its right are still empty.
+        let mut partial_done = false;
+        let siblings = witness
+            .siblings
+            .iter()
+            .enumerate()
+            .map(|(height, sibling)| match sibling {
+                Some(sibling) => *sibling,
+                None if !partial_done => {
+                    partial_done = true;
+                    self.partial_root(height)
+                }
+                None => self.empty_hashes[height],
+            })
+            .collect();
+
+        Some(IncrementalMerklePath::new(position, siblings))
+    }
+
+    /// Saves the current state of this tree, which can later be restored by
+    /// [`IncrementalMerkleTree::rewind`], and returns the number of saved states.
+    pub fn checkpoint(&mut self) -> usize {
+        self.checkpoints.push(self.state.clone());
+
+        self.checkpoints.len()
+    }
+
+    /// Restores the state of this tree to the last checkpoint, which is
+    /// removed, and returns whether there was any.
+    ///
+    /// Leaves appended and marks set since that checkpoint are discarded,
+    /// while leaves unmarked since then are marked again.
+    pub fn rewind(&mut self) -> bool {
+        match self.checkpoints.pop() {
+            Some(state) => {
+                self.state = state;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the root obtained by merging `leaf` along the provided
+    /// authentication path, or `None` if it is malformed.
+    pub fn compute_root(
+        leaf: &AnemoiDigest<F, DIGEST_SIZE>,
+        path: &IncrementalMerklePath<F, DIGEST_SIZE>,
+    ) -> Option<AnemoiDigest<F, DIGEST_SIZE>> {
+        if path.siblings.len() != DEPTH || path.position >= Self::CAPACITY {
+            return None;
+        }
+
+        let mut node = *leaf;
+        for (height, sibling) in path.siblings.iter().enumerate() {
+            node = if (path.position >> height) & 1 == 1 {
+                H::merge(&[*sibling, node])
+            } else {
+                H::merge(&[node, *sibling])
+            };
+        }
+
+        Some(node)
+    }
+
+    /// Verifies that `leaf` belongs to the tree of the provided
+    /// root, given its authentication path.
+    pub fn verify(
+        root: &AnemoiDigest<F, DIGEST_SIZE>,
+        leaf: &AnemoiDigest<F, DIGEST_SIZE>,
+        path: &IncrementalMerklePath<F, DIGEST_SIZE>,
+    ) -> bool {
+        Self::compute_root(leaf, path).as_ref() == Some(root)
+    }
+
+    /// Returns the root of the subtree of the provided height
+    /// in which the next leaf is to be appended.
+    fn partial_root(&self, height: usize) -> AnemoiDigest<F, DIGEST_SIZE> {
+        let mut node = self.empty_hashes[0];
+        for h in 0..height {
+            node = if (self.state.size >> h) & 1 == 1 {
+                H::merge(&[self.state.frontier[h], node])
+            } else if node == self.empty_hashes[h] {
+                self.empty_hashes[h + 1]
+            } else {
+                H::merge(&[node, self.empty_hashes[h]])
+            };
+        }
+
+        node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::{SparseMerkleKey, SparseMerkleTree};
+    use rand_core::OsRng;
+
+    const DEPTH: usize = 8;
+
+    fn check_incremental_tree<F, H, const DIGEST_SIZE: usize>()
+    where
+        F: PrimeField,
+        H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>>,
+    {
+        let mut rng = OsRng;
+        let mut tree = IncrementalMerkleTree::<F, H, DEPTH, DIGEST_SIZE>::new();
+        // The leaf at position i is stored under a key starting with i.
+        let mut reference = SparseMerkleTree::<F, H, DEPTH, DIGEST_SIZE>::new();
+        assert_eq!(tree.root(), reference.root());
+        assert_eq!(tree.mark(), None);
+
+        let check_witnesses = |tree: &IncrementalMerkleTree<F, H, DEPTH, DIGEST_SIZE>| {
+            let root = tree.root();
+            for position in tree.marked_positions() {
+                let path = tree.witness(position).unwrap();
+                let leaf = tree.marked_leaf(position).unwrap();
+                assert!(IncrementalMerkleTree::<F, H, DEPTH, DIGEST_SIZE>::verify(
+                    &root, &leaf, &path
+                ));
+            }
+        };
+
+        let mut roots = Vec::new();
+        for i in 0..14 {
+            let leaf = AnemoiDigest::new([(); DIGEST_SIZE].map(|_| F::rand(&mut rng)));
+            assert_eq!(tree.append(leaf), Ok(i));
+            if [0, 5, 6, 11].contains(&i) {
+                assert_eq!(tree.mark(), Some(i));
+            }
+            if i == 9 {
+                assert_eq!(tree.checkpoint(), 1);
+            }
+
+            let mut key: SparseMerkleKey = Default::default();
+            key[0] = i as u8;
+            reference.insert(&key, leaf);
+
+            assert_eq!(tree.root(), reference.root());
+            check_witnesses(&tree);
+            roots.push(tree.root());
+        }
+
+        assert!(tree.unmark(0));
+        assert!(!tree.unmark(0));
+        assert_eq!(tree.marked_positions(), vec![5, 6, 11]);
+
+        // Rewinding discards leaves appended and marks set after the checkpoint.
+        assert!(tree.rewind());
+        assert!(!tree.rewind());
+        assert_eq!(tree.size(), 10);
+        assert_eq!(tree.root(), roots[9]);
+        assert_eq!(tree.marked_positions(), vec![0, 5, 6]);
+        check_witnesses(&tree);
+    }
+
+    #[test]
+    #[cfg(feature = "jubjub")]
+    fn test_incremental_merkle_tree_jubjub() {
+        use crate::jubjub::{anemoi_2_1, anemoi_4_3, Felt};
+
+        check_incremental_tree::<Felt, anemoi_2_1::AnemoiHash, { anemoi_2_1::DIGEST_SIZE }>();
+        check_incremental_tree::<Felt, anemoi_4_3::AnemoiHash, { anemoi_4_3::DIGEST_SIZE }>();
+    }
+
+    #[test]
+    #[cfg(feature = "pallas")]
+    fn test_incremental_merkle_tree_pallas() {
+        use crate::pallas::{anemoi_2_1, anemoi_4_3, Felt};
+
+        check_incremental_tree::<Felt, anemoi_2_1::AnemoiHash, { anemoi_2_1::DIGEST_SIZE }>();
+        check_incremental_tree::<Felt, anemoi_4_3::AnemoiHash, { anemoi_4_3::DIGEST_SIZE }>();
+    }
+
+    #[test]
+    #[cfg(feature = "bn_254")]
+    fn test_incremental_merkle_tree_bn_254() {
+        use crate::bn_254::{anemoi_2_1, anemoi_4_3, Felt};
+
+        check_incremental_tree::<Felt, anemoi_2_1::AnemoiHash, { anemoi_2_1::DIGEST_SIZE }>();
+        check_incremental_tree::<Felt, anemoi_4_3::AnemoiHash, { anemoi_4_3::DIGEST_SIZE }>();
+    }
+
+    #[test]
+    #[cfg(feature = "bn_254")]
+    fn test_incremental_merkle_tree_full() {
+        use crate::bn_254::{anemoi_2_1, Felt};
+        use crate::merkle::MerkleTree;
+        use ark_ff::UniformRand;
+
+        type Tree = IncrementalMerkleTree<Felt, anemoi_2_1::AnemoiHash, 2, 1>;
+
+        let mut rng = OsRng;
+        let leaves: Vec<[Felt; 1]> = (0..4).map(|_| [Felt::rand(&mut rng)]).collect();
+
+        let mut tree = Tree::new();
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(tree.append(AnemoiDigest::new(*leaf)), Ok(i));
+            if i == 1 {
+                assert_eq!(tree.mark(), Some(1));
+            }
+        }
+        assert_eq!(tree.mark(), Some(3));
+        assert_eq!(
+            tree.append(AnemoiDigest::default()),
+            Err(MerkleError::TreeFull)
+        );
+
+        // Merging two single-element digests with Anemoi-2-1 amounts to
+        // compressing them with its Jive mode.
+        let reference = MerkleTree::<Felt, anemoi_2_1::AnemoiHash, 2, 1>::new(&leaves).unwrap();
+        let root = tree.root();
+        assert_eq!(root.as_elements(), &reference.root());
+        assert_ne!(root, tree.empty_hashes[2]);
+
+        for position in [1, 3] {
+            let path = tree.witness(position).unwrap();
+            assert!(Tree::verify(
+                &root,
+                &AnemoiDigest::new(leaves[position]),
+                &path
+            ));
+        }
+    }
+}
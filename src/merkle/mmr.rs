@@ -0,0 +1,557 @@
+//! Merkle Mountain Ranges built on the `merge` method of an Anemoi Sponge
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::marker::PhantomData;
+
+use super::MerkleError;
+use crate::{AnemoiDigest, Jive, Sponge};
+
+use ark_ff::PrimeField;
+
+/// The domain of the Jive compression of pairs of leaves,
+/// to separate it from the merge of internal nodes.
+const LEAF_DOMAIN: u64 = 1;
+
+/// Returns the height and index within their level of the
+/// peaks of a Merkle Mountain Range of `size` leaves,
+/// from left to right.
+fn peak_positions(size: usize) -> Vec<(usize, usize)> {
+    let mut positions = Vec::with_capacity(size.count_ones() as usize);
+    let mut offset = 0;
+    for height in (0..usize::BITS as usize).rev() {
+        if (size >> height) & 1 == 1 {
+            positions.push((height, offset >> height));
+            offset += 1 << height;
+        }
+    }
+
+    positions
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// An inclusion proof for a leaf of a Merkle Mountain Range.
+pub struct MmrInclusionProof<F: PrimeField, const DIGEST_SIZE: usize> {
+    size: usize,
+    leaf_index: usize,
+    siblings: Vec<AnemoiDigest<F, DIGEST_SIZE>>,
+    peaks: Vec<AnemoiDigest<F, DIGEST_SIZE>>,
+}
+
+impl<F: PrimeField, const DIGEST_SIZE: usize> MmrInclusionProof<F, DIGEST_SIZE> {
+    /// Returns the number of leaves of the range this proof relates to.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the index of the authenticated leaf.
+    pub fn leaf_index(&self) -> usize {
+        self.leaf_index
+    }
+
+    /// Returns the siblings of the path from the leaf up to its
+    /// peak, from the leaves level upwards.
+    pub fn siblings(&self) -> &[AnemoiDigest<F, DIGEST_SIZE>] {
+        &self.siblings
+    }
+
+    /// Returns all peaks of the range, from left to right.
+    pub fn peaks(&self) -> &[AnemoiDigest<F, DIGEST_SIZE>] {
+        &self.peaks
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A proof that a Merkle Mountain Range of `old_size` leaves
+/// is a prefix of a range of `new_size` leaves.
+pub struct MmrConsistencyProof<F: PrimeField, const DIGEST_SIZE: usize> {
+    old_size: usize,
+    new_size: usize,
+    old_peaks: Vec<AnemoiDigest<F, DIGEST_SIZE>>,
+    // For each old peak, the siblings of the path
+    // from it up to the new peak containing it.
+    paths: Vec<Vec<AnemoiDigest<F, DIGEST_SIZE>>>,
+    new_peaks: Vec<AnemoiDigest<F, DIGEST_SIZE>>,
+}
+
+impl<F: PrimeField, const DIGEST_SIZE: usize> MmrConsistencyProof<F, DIGEST_SIZE> {
+    /// Returns the number of leaves of the older range.
+    pub fn old_size(&self) -> usize {
+        self.old_size
+    }
+
+    /// Returns the number of leaves of the newer range.
+    pub fn new_size(&self) -> usize {
+        self.new_size
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A Merkle Mountain Range over `F`, whose nodes are digests
+/// hashed with the Sponge `H`.
+///
+/// A range of `n` leaves is a sequence of perfect binary trees, its peaks,
+/// whose sizes are the powers of two of the binary decomposition of `n`, in
+/// decreasing order. The peaks are bagged from right to left, i.e. each peak
+/// is merged with the bag of all peaks on its right, and the root of the range
+/// is the hash of its size and of this bag.
+///
+/// Internal nodes are merged with `Sponge::merge`, except for the parents of
+/// leaves, which are compressed with `Jive::compress_with_domain` at the same
+/// cost, so that an internal node cannot be opened as a leaf. Binding the size
+/// into the root ensures that two ranges of distinct sizes never share it.
+pub struct MerkleMountainRange<F: PrimeField, H, const DIGEST_SIZE: usize> {
+    // All nodes of the range, indexed by their height and
+    // their index within their level.
+    levels: Vec<Vec<AnemoiDigest<F, DIGEST_SIZE>>>,
+    _hasher: PhantomData<H>,
+}
+
+impl<F, H, const DIGEST_SIZE: usize> Default for MerkleMountainRange<F, H, DIGEST_SIZE>
+where
+    F: PrimeField,
+    H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>> + Jive<F>,
+{
+    fn default() -> Self {
+        Self {
+            levels: vec![Vec::new()],
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<F, H, const DIGEST_SIZE: usize> MerkleMountainRange<F, H, DIGEST_SIZE>
+where
+    F: PrimeField,
+    H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>> + Jive<F>,
+{
+    /// Returns a new empty range.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of leaves of this range.
+    pub fn size(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Appends a leaf to this range, and returns its index.
+    pub fn append(&mut self, leaf: AnemoiDigest<F, DIGEST_SIZE>) -> usize {
+        let index = self.size();
+
+        let mut node = leaf;
+        let mut height = 0;
+        loop {
+            self.levels[height].push(node);
+            let len = self.levels[height].len();
+            if len % 2 == 1 {
+                break;
+            }
+
+            node = Self::hash_node(height, &self.levels[height][len - 2], &node);
+            height += 1;
+            if height == self.levels.len() {
+                self.levels.push(Vec::new());
+            }
+        }
+
+        index
+    }
+
+    /// Returns the peaks of this range, from left to right.
+    pub fn peaks(&self) -> Vec<AnemoiDigest<F, DIGEST_SIZE>> {
+        self.peaks_at(self.size())
+    }
+
+    /// Returns the root of this range, or the default digest if it is empty.
+    pub fn root(&self) -> AnemoiDigest<F, DIGEST_SIZE> {
+        Self::root_from_peaks(self.size(), &self.peaks())
+    }
+
+    /// Returns the root of the prefix of this range
+    /// of `size` leaves, if it is not larger than it.
+    pub fn root_at(&self, size: usize) -> Result<AnemoiDigest<F, DIGEST_SIZE>, MerkleError> {
+        if size > self.size() {
+            return Err(MerkleError::InvalidSize(size));
+        }
+
+        Ok(Self::root_from_peaks(size, &self.peaks_at(size)))
+    }
+
+    /// Returns the root of a range of `size` leaves with the provided
+    /// peaks, or the default digest if it is empty.
+    pub fn root_from_peaks(
+        size: usize,
+        peaks: &[AnemoiDigest<F, DIGEST_SIZE>],
+    ) -> AnemoiDigest<F, DIGEST_SIZE> {
+        if size == 0 {
+            return AnemoiDigest::default();
+        }
+
+        let mut elems = Vec::with_capacity(DIGEST_SIZE + 1);
+        elems.push(F::from(size as u64));
+        elems.extend_from_slice(Self::bag_peaks(peaks).as_elements());
+
+        H::hash_field(&elems)
+    }
+
+    /// Bags the provided peaks from right to left into a single digest,
+    /// or returns the default digest if there is none.
+    pub fn bag_peaks(peaks: &[AnemoiDigest<F, DIGEST_SIZE>]) -> AnemoiDigest<F, DIGEST_SIZE> {
+        let mut peaks = peaks.iter().rev();
+        let mut bag = match peaks.next() {
+            Some(peak) => *peak,
+            None => return AnemoiDigest::default(),
+        };
+        for peak in peaks {
+            bag = H::merge(&[*peak, bag]);
+        }
+
+        bag
+    }
+
+    /// Returns an inclusion proof for the leaf at position `leaf_index`.
+    pub fn prove(
+        &self,
+        leaf_index: usize,
+    ) -> Result<MmrInclusionProof<F, DIGEST_SIZE>, MerkleError> {
+        let size = self.size();
+        if leaf_index >= size {
+            return Err(MerkleError::InvalidLeafIndex(leaf_index));
+        }
+
+        let (height, _) = peak_positions(size)
+            .into_iter()
+            .find(|&(height, index)| leaf_index >> height == index)
+            .unwrap();
+
+        Ok(MmrInclusionProof {
+            size,
+            leaf_index,
+            siblings: self.path(0, leaf_index, height),
+            peaks: self.peaks(),
+        })
+    }
+
+    /// Verifies that `leaf` belongs to the range of the
+    /// provided root, given its inclusion proof.
+    ///
+    /// The size of the range given by the proof is bound to the root,
+    /// and the position of the leaf must lie within it.
+    pub fn verify_inclusion(
+        root: &AnemoiDigest<F, DIGEST_SIZE>,
+        leaf: &AnemoiDigest<F, DIGEST_SIZE>,
+        proof: &MmrInclusionProof<F, DIGEST_SIZE>,
+    ) -> bool {
+        if proof.leaf_index >= proof.size {
+            return false;
+        }
+
+        let positions = peak_positions(proof.size);
+        if proof.peaks.len() != positions.len() {
+            return false;
+        }
+
+        let k = positions
+            .iter()
+            .position(|&(height, index)| proof.leaf_index >> height == index)
+            .unwrap();
+        if proof.siblings.len() != positions[k].0 {
+            return false;
+        }
+
+        let peak = Self::climb(leaf, 0, proof.leaf_index, &proof.siblings);
+
+        peak == proof.peaks[k] && Self::root_from_peaks(proof.size, &proof.peaks) == *root
+    }
+
+    /// Returns a proof that the prefix of this range of `old_size`
+    /// leaves is consistent with the current range.
+    pub fn prove_consistency(
+        &self,
+        old_size: usize,
+    ) -> Result<MmrConsistencyProof<F, DIGEST_SIZE>, MerkleError> {
+        let new_size = self.size();
+        if old_size > new_size {
+            return Err(MerkleError::InvalidSize(old_size));
+        }
+
+        let new_positions = peak_positions(new_size);
+        let paths = peak_positions(old_size)
+            .into_iter()
+            .map(|(height, index)| {
+                let (new_height, _) = new_positions
+                    .iter()
+                    .find(|&&(h, i)| h >= height && index >> (h - height) == i)
+                    .unwrap();
+                self.path(height, index, *new_height)
+            })
+            .collect();
+
+        Ok(MmrConsistencyProof {
+            old_size,
+            new_size,
+            old_peaks: self.peaks_at(old_size),
+            paths,
+            new_peaks: self.peaks(),
+        })
+    }
+
+    /// Verifies that the range of the provided old root is a prefix of
+    /// the range of the provided new root, given a consistency proof.
+    pub fn verify_consistency(
+        old_root: &AnemoiDigest<F, DIGEST_SIZE>,
+        new_root: &AnemoiDigest<F, DIGEST_SIZE>,
+        proof: &MmrConsistencyProof<F, DIGEST_SIZE>,
+    ) -> bool {
+        if proof.old_size > proof.new_size {
+            return false;
+        }
+
+        let old_positions = peak_positions(proof.old_size);
+        let new_positions = peak_positions(proof.new_size);
+        if proof.old_peaks.len() != old_positions.len()
+            || proof.paths.len() != old_positions.len()
+            || proof.new_peaks.len() != new_positions.len()
+        {
+            return false;
+        }
+
+        // Each old peak must lie below one of the new peaks.
+        for ((&(height, index), old_peak), path) in old_positions
+            .iter()
+            .zip(proof.old_peaks.iter())
+            .zip(proof.paths.iter())
+        {
+            let k = new_positions
+                .iter()
+                .position(|&(h, i)| h >= height && index >> (h - height) == i)
+                .unwrap();
+            if path.len() != new_positions[k].0 - height
+                || Self::climb(old_peak, height, index, path) != proof.new_peaks[k]
+            {
+                return false;
+            }
+        }
+
+        Self::root_from_peaks(proof.old_size, &proof.old_peaks) == *old_root
+            && Self::root_from_peaks(proof.new_size, &proof.new_peaks) == *new_root
+    }
+
+    /// Returns the peaks of the prefix of this range of `size` leaves.
+    fn peaks_at(&self, size: usize) -> Vec<AnemoiDigest<F, DIGEST_SIZE>> {
+        peak_positions(size)
+            .into_iter()
+            .map(|(height, index)| self.levels[height][index])
+            .collect()
+    }
+
+    /// Returns the siblings of the path from the node at the provided
+    /// height and index up to its ancestor at height `top`.
+    fn path(&self, height: usize, index: usize, top: usize) -> Vec<AnemoiDigest<F, DIGEST_SIZE>> {
+        (height..top)
+            .map(|h| self.levels[h][(index >> (h - height)) ^ 1])
+            .collect()
+    }
+
+    /// Returns the parent node of the provided children at `height`.
+    fn hash_node(
+        height: usize,
+        left: &AnemoiDigest<F, DIGEST_SIZE>,
+        right: &AnemoiDigest<F, DIGEST_SIZE>,
+    ) -> AnemoiDigest<F, DIGEST_SIZE> {
+        if height > 0 {
+            return H::merge(&[*left, *right]);
+        }
+
+        // The Jive state holds at least two digests, the remaining
+        // registers, if any, being set to zero.
+        let mut elems = vec![F::zero(); H::STATE_WIDTH];
+        elems[..DIGEST_SIZE].copy_from_slice(left.as_elements());
+        elems[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(right.as_elements());
+        let result = H::compress_with_domain(&elems, F::from(LEAF_DOMAIN));
+
+        AnemoiDigest::new(
+            result[..DIGEST_SIZE]
+                .try_into()
+                .expect("the Jive compression by 2 yields at least DIGEST_SIZE elements"),
+        )
+    }
+
+    /// Merges `node`, whose height is `height` and whose index
+    /// within its level is `index`, along the provided siblings.
+    fn climb(
+        node: &AnemoiDigest<F, DIGEST_SIZE>,
+        height: usize,
+        index: usize,
+        siblings: &[AnemoiDigest<F, DIGEST_SIZE>],
+    ) -> AnemoiDigest<F, DIGEST_SIZE> {
+        let mut node = *node;
+        for (i, sibling) in siblings.iter().enumerate() {
+            node = if (index >> i) & 1 == 1 {
+                Self::hash_node(height + i, sibling, &node)
+            } else {
+                Self::hash_node(height + i, &node, sibling)
+            };
+        }
+
+        node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    fn check_mmr<F, H, const DIGEST_SIZE: usize>()
+    where
+        F: PrimeField,
+        H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>> + Jive<F>,
+    {
+        let mut rng = OsRng;
+        let mut mmr = MerkleMountainRange::<F, H, DIGEST_SIZE>::new();
+        assert_eq!(mmr.root(), AnemoiDigest::default());
+
+        let leaves: Vec<AnemoiDigest<F, DIGEST_SIZE>> = (0..11)
+            .map(|_| AnemoiDigest::new([(); DIGEST_SIZE].map(|_| F::rand(&mut rng))))
+            .collect();
+        let mut roots = vec![mmr.root()];
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(mmr.append(*leaf), i);
+            roots.push(mmr.root());
+        }
+
+        // 11 = 8 + 2 + 1 leaves, bagged from right to left.
+        type Mmr<F, H, const DIGEST_SIZE: usize> = MerkleMountainRange<F, H, DIGEST_SIZE>;
+        let hash_node = Mmr::<F, H, DIGEST_SIZE>::hash_node;
+        let peaks = mmr.peaks();
+        assert_eq!(peaks.len(), 3);
+        assert_eq!(peaks[2], leaves[10]);
+        assert_eq!(peaks[1], hash_node(0, &leaves[8], &leaves[9]));
+        assert_ne!(peaks[1], H::merge(&[leaves[8], leaves[9]]));
+        assert_eq!(
+            peaks[0],
+            hash_node(
+                2,
+                &H::merge(&[
+                    hash_node(0, &leaves[0], &leaves[1]),
+                    hash_node(0, &leaves[2], &leaves[3])
+                ]),
+                &H::merge(&[
+                    hash_node(0, &leaves[4], &leaves[5]),
+                    hash_node(0, &leaves[6], &leaves[7])
+                ]),
+            )
+        );
+        let bag = H::merge(&[peaks[0], H::merge(&[peaks[1], peaks[2]])]);
+        assert_eq!(Mmr::<F, H, DIGEST_SIZE>::bag_peaks(&peaks), bag);
+        let mut elems = vec![F::from(11u64)];
+        elems.extend_from_slice(bag.as_elements());
+        assert_eq!(mmr.root(), H::hash_field(&elems));
+
+        let root = mmr.root();
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = mmr.prove(i).unwrap();
+            assert!(MerkleMountainRange::<F, H, DIGEST_SIZE>::verify_inclusion(
+                &root, leaf, &proof
+            ));
+            assert!(!MerkleMountainRange::<F, H, DIGEST_SIZE>::verify_inclusion(
+                &root,
+                &leaves[(i + 1) % leaves.len()],
+                &proof
+            ));
+        }
+        assert_eq!(mmr.prove(11), Err(MerkleError::InvalidLeafIndex(11)));
+
+        // The size of a proof is bound to the root: 13 = 8 + 4 + 1 leaves
+        // would yield the same bag of peaks and the same path for leaf 0.
+        let mut proof = mmr.prove(0).unwrap();
+        proof.size = 13;
+        assert!(!MerkleMountainRange::<F, H, DIGEST_SIZE>::verify_inclusion(
+            &root, &leaves[0], &proof
+        ));
+
+        // An internal node cannot be opened as a leaf: the first 8 leaves form
+        // a single peak, which is also the one of the 4 nodes above them.
+        let root_8 = mmr.root_at(8).unwrap();
+        let nodes = &mmr.levels[1][..4];
+        let forged = MmrInclusionProof {
+            size: 4,
+            leaf_index: 0,
+            siblings: vec![nodes[1], hash_node(1, &nodes[2], &nodes[3])],
+            peaks: vec![peaks[0]],
+        };
+        assert_eq!(
+            Mmr::<F, H, DIGEST_SIZE>::climb(&nodes[0], 1, 0, &forged.siblings),
+            peaks[0]
+        );
+        for root in [
+            root_8,
+            Mmr::<F, H, DIGEST_SIZE>::root_from_peaks(4, &forged.peaks),
+        ] {
+            assert!(!MerkleMountainRange::<F, H, DIGEST_SIZE>::verify_inclusion(
+                &root, &nodes[0], &forged
+            ));
+        }
+
+        for (old_size, old_root) in roots.iter().enumerate() {
+            assert_eq!(mmr.root_at(old_size), Ok(*old_root));
+
+            let proof = mmr.prove_consistency(old_size).unwrap();
+            assert!(
+                MerkleMountainRange::<F, H, DIGEST_SIZE>::verify_consistency(
+                    old_root, &root, &proof
+                )
+            );
+            if old_size > 0 {
+                assert!(
+                    !MerkleMountainRange::<F, H, DIGEST_SIZE>::verify_consistency(
+                        &roots[old_size - 1],
+                        &root,
+                        &proof
+                    )
+                );
+            }
+        }
+        assert_eq!(
+            mmr.prove_consistency(12).err(),
+            Some(MerkleError::InvalidSize(12))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bls_381")]
+    fn test_mmr_bls12_381() {
+        use crate::bls12_381::{anemoi_2_1, anemoi_4_3, Felt};
+
+        check_mmr::<Felt, anemoi_2_1::AnemoiHash, { anemoi_2_1::DIGEST_SIZE }>();
+        check_mmr::<Felt, anemoi_4_3::AnemoiHash, { anemoi_4_3::DIGEST_SIZE }>();
+    }
+
+    #[test]
+    #[cfg(feature = "bn_254")]
+    fn test_mmr_bn_254() {
+        use crate::bn_254::{anemoi_2_1, anemoi_8_7, Felt};
+
+        check_mmr::<Felt, anemoi_2_1::AnemoiHash, { anemoi_2_1::DIGEST_SIZE }>();
+        check_mmr::<Felt, anemoi_8_7::AnemoiHash, { anemoi_8_7::DIGEST_SIZE }>();
+    }
+
+    #[test]
+    #[cfg(feature = "pallas")]
+    fn test_mmr_pallas() {
+        use crate::pallas::{anemoi_2_1, Felt};
+
+        check_mmr::<Felt, anemoi_2_1::AnemoiHash, { anemoi_2_1::DIGEST_SIZE }>();
+    }
+
+    #[test]
+    #[cfg(feature = "vesta")]
+    fn test_mmr_vesta() {
+        use crate::vesta::{anemoi_4_3, Felt};
+
+        check_mmr::<Felt, anemoi_4_3::AnemoiHash, { anemoi_4_3::DIGEST_SIZE }>();
+    }
+}
@@ -0,0 +1,401 @@
+//! Merkle trees built on the Jive compression mode of Anemoi
+//!
+//! A tree of arity `ARITY` with nodes of `NODE_SIZE` field elements compresses
+//! the concatenation of `ARITY` sibling nodes into their parent node through
+//! `Jive::compress_k` with a compression factor of `ARITY`. Hence, it can be
+//! instantiated with any Jive implementation whose state width is equal to
+//! `ARITY * NODE_SIZE`, e.g. a 4-ary tree with single-element nodes, or a
+//! binary tree with nodes of two elements, over Anemoi-4-3.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use super::{AnemoiDigest, Jive, Sponge};
+
+use ark_ff::PrimeField;
+
+mod batch;
+pub use batch::*;
+
+mod frontier;
+pub use frontier::*;
+
+mod mmr;
+pub use mmr::*;
+
+mod sparse;
+pub use sparse::*;
+
+/// Errors returned when building or opening a Merkle tree.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MerkleError {
+    /// A tree cannot be built from an empty sequence of leaves.
+    EmptyTree,
+    /// The provided leaf index is out of the bounds of the tree.
+    InvalidLeafIndex(usize),
+    /// No leaf can be appended to a tree which is already full.
+    TreeFull,
+    /// The provided size is larger than the one of the tree.
+    InvalidSize(usize),
+    /// A batch opening must contain at least one leaf.
+    EmptyBatch,
+}
+
+impl fmt::Display for MerkleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MerkleError::EmptyTree => write!(f, "a tree must have at least one leaf"),
+            MerkleError::InvalidLeafIndex(index) => {
+                write!(f, "the leaf index {} is out of bounds", index)
+            }
+            MerkleError::TreeFull => write!(f, "the tree is full"),
+            MerkleError::InvalidSize(size) => {
+                write!(f, "the size {} is larger than the one of the tree", size)
+            }
+            MerkleError::EmptyBatch => write!(f, "a batch must contain at least one leaf"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MerkleError {}
+
+/// Returns the roots of empty binary subtrees of height 0 to `depth`, whose
+/// leaves are all set to the default digest, merged with the Sponge `H`.
+pub(crate) fn empty_hashes<F, H, const DIGEST_SIZE: usize>(
+    depth: usize,
+) -> Vec<AnemoiDigest<F, DIGEST_SIZE>>
+where
+    F: PrimeField,
+    H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>>,
+{
+    let mut empty_hashes = Vec::with_capacity(depth + 1);
+    empty_hashes.push(AnemoiDigest::default());
+    for height in 0..depth {
+        let child = empty_hashes[height];
+        empty_hashes.push(H::merge(&[child, child]));
+    }
+
+    empty_hashes
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// An authentication path for a leaf of a Merkle tree.
+///
+/// It contains, from the leaves level up to the root, the `ARITY - 1`
+/// siblings of the node on the path from the leaf to the root.
+pub struct MerklePath<F: PrimeField, const NODE_SIZE: usize> {
+    index: usize,
+    siblings: Vec<Vec<[F; NODE_SIZE]>>,
+}
+
+impl<F: PrimeField, const NODE_SIZE: usize> MerklePath<F, NODE_SIZE> {
+    /// Returns a new authentication path for the leaf at position `index`.
+    pub fn new(index: usize, siblings: Vec<Vec<[F; NODE_SIZE]>>) -> Self {
+        Self { index, siblings }
+    }
+
+    /// Returns the index of the authenticated leaf.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the siblings of this path, from the leaves level up to the root.
+    pub fn siblings(&self) -> &[Vec<[F; NODE_SIZE]>] {
+        &self.siblings
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A Merkle tree of arity `ARITY` over `F`, with nodes of `NODE_SIZE` field
+/// elements, compressed with the Jive mode of `H`.
+///
+/// The number of leaves is padded with all-zero nodes to the next
+/// power of `ARITY`. The root does not commit to the number of leaves,
+/// which must hence be known to verifiers: trees of `n` leaves and of
+/// the same `n` leaves followed by all-zero ones share the same root.
+pub struct MerkleTree<F: PrimeField, H, const ARITY: usize, const NODE_SIZE: usize> {
+    // All levels of the tree, from the (padded) leaves up to the root.
+    layers: Vec<Vec<[F; NODE_SIZE]>>,
+    num_leaves: usize,
+    _hasher: PhantomData<H>,
+}
+
+impl<F, H, const ARITY: usize, const NODE_SIZE: usize> MerkleTree<F, H, ARITY, NODE_SIZE>
+where
+    F: PrimeField,
+    H: Jive<F>,
+{
+    /// Builds a new Merkle tree from the provided leaves.
+    pub fn new(leaves: &[[F; NODE_SIZE]]) -> Result<Self, MerkleError> {
+        assert!(ARITY > 1, "a Merkle tree must have an arity of at least 2");
+        assert_eq!(
+            ARITY * NODE_SIZE,
+            H::STATE_WIDTH,
+            "the Jive state width must be equal to ARITY * NODE_SIZE"
+        );
+
+        if leaves.is_empty() {
+            return Err(MerkleError::EmptyTree);
+        }
+
+        let width = ARITY.pow(Self::depth_for(leaves.len()) as u32);
+
+        let mut layer = leaves.to_vec();
+        layer.resize(width, [F::zero(); NODE_SIZE]);
+
+        let mut layers = vec![layer];
+        while layers[layers.len() - 1].len() > 1 {
+            let parents = layers[layers.len() - 1]
+                .chunks(ARITY)
+                .map(Self::hash_children)
+                .collect();
+            layers.push(parents);
+        }
+
+        Ok(Self {
+            layers,
+            num_leaves: leaves.len(),
+            _hasher: PhantomData,
+        })
+    }
+
+    /// Compresses `ARITY` sibling nodes into their parent node.
+    pub fn hash_children(children: &[[F; NODE_SIZE]]) -> [F; NODE_SIZE] {
+        debug_assert_eq!(children.len(), ARITY);
+
+        let mut elems = Vec::with_capacity(ARITY * NODE_SIZE);
+        for child in children.iter() {
+            elems.extend_from_slice(child);
+        }
+
+        H::compress_k(&elems, ARITY)
+            .try_into()
+            .expect("compressing ARITY * NODE_SIZE elements by ARITY yields NODE_SIZE elements")
+    }
+
+    /// Returns the root of this tree.
+    pub fn root(&self) -> [F; NODE_SIZE] {
+        self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Returns the depth of this tree, i.e. the length of its authentication paths.
+    pub fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// Returns the depth of a tree of `num_leaves` leaves.
+    pub fn depth_for(num_leaves: usize) -> usize {
+        let mut depth = 0;
+        let mut width = 1;
+        while width < num_leaves {
+            width *= ARITY;
+            depth += 1;
+        }
+
+        depth
+    }
+
+    /// Returns the leaves of this tree, excluding padding.
+    pub fn leaves(&self) -> &[[F; NODE_SIZE]] {
+        &self.layers[0][..self.num_leaves]
+    }
+
+    /// Returns the authentication path of the leaf at position `index`.
+    pub fn prove(&self, index: usize) -> Result<MerklePath<F, NODE_SIZE>, MerkleError> {
+        if index >= self.num_leaves {
+            return Err(MerkleError::InvalidLeafIndex(index));
+        }
+
+        let mut siblings = Vec::with_capacity(self.depth());
+        let mut position = index;
+        for layer in self.layers[..self.depth()].iter() {
+            let first = position - position % ARITY;
+            let level_siblings = (first..first + ARITY)
+                .filter(|&i| i != position)
+                .map(|i| layer[i])
+                .collect();
+            siblings.push(level_siblings);
+            position /= ARITY;
+        }
+
+        Ok(MerklePath::new(index, siblings))
+    }
+
+    /// Returns the root obtained by hashing `leaf` along the provided
+    /// authentication path, or `None` if the path is malformed.
+    ///
+    /// The number of leaves of the tree is provided by the verifier: the
+    /// path must have the depth of such a tree, and its index must not
+    /// point to a padding leaf.
+    pub fn compute_root(
+        leaf: &[F; NODE_SIZE],
+        path: &MerklePath<F, NODE_SIZE>,
+        num_leaves: usize,
+    ) -> Option<[F; NODE_SIZE]> {
+        if path.index >= num_leaves || path.siblings.len() != Self::depth_for(num_leaves) {
+            return None;
+        }
+
+        let mut node = *leaf;
+        let mut position = path.index;
+        for level_siblings in path.siblings.iter() {
+            if level_siblings.len() != ARITY - 1 {
+                return None;
+            }
+
+            let offset = position % ARITY;
+            let mut children = Vec::with_capacity(ARITY);
+            children.extend_from_slice(&level_siblings[..offset]);
+            children.push(node);
+            children.extend_from_slice(&level_siblings[offset..]);
+
+            node = Self::hash_children(&children);
+            position /= ARITY;
+        }
+
+        Some(node)
+    }
+
+    /// Verifies that `leaf` belongs to the tree of `num_leaves` leaves
+    /// of the provided root, given its authentication path.
+    pub fn verify(
+        root: &[F; NODE_SIZE],
+        leaf: &[F; NODE_SIZE],
+        path: &MerklePath<F, NODE_SIZE>,
+        num_leaves: usize,
+    ) -> bool {
+        Self::compute_root(leaf, path, num_leaves).as_ref() == Some(root)
+    }
+}
+
+#[cfg(all(test, feature = "bls_381"))]
+mod tests {
+    use super::*;
+    use crate::bls12_381::{anemoi_12_11, anemoi_2_1, anemoi_4_3, anemoi_8_7, Felt};
+    use ark_ff::{UniformRand, Zero};
+    use rand_core::OsRng;
+
+    fn check_tree<H: Jive<Felt>, const ARITY: usize, const NODE_SIZE: usize>() {
+        let mut rng = OsRng;
+
+        for num_leaves in [1, 2, ARITY, ARITY + 1, 2 * ARITY * ARITY] {
+            let leaves: Vec<[Felt; NODE_SIZE]> = (0..num_leaves)
+                .map(|_| [(); NODE_SIZE].map(|_| Felt::rand(&mut rng)))
+                .collect();
+            let tree = MerkleTree::<Felt, H, ARITY, NODE_SIZE>::new(&leaves).unwrap();
+            let root = tree.root();
+            assert_eq!(tree.leaves(), &leaves[..]);
+
+            for index in [0, num_leaves / 2, num_leaves - 1] {
+                let leaf = &leaves[index];
+                let path = tree.prove(index).unwrap();
+                assert_eq!(path.siblings().len(), tree.depth());
+                assert!(MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify(
+                    &root, leaf, &path, num_leaves
+                ));
+
+                // Tampered leaves or indices are rejected.
+                let other_leaf = [Felt::rand(&mut rng); NODE_SIZE];
+                assert!(!MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify(
+                    &root,
+                    &other_leaf,
+                    &path,
+                    num_leaves
+                ));
+                if tree.depth() > 0 {
+                    let other_path = MerklePath::new(index ^ 1, path.siblings().to_vec());
+                    assert!(!MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify(
+                        &root,
+                        leaf,
+                        &other_path,
+                        num_leaves
+                    ));
+
+                    // Paths shorter than the depth of the tree are rejected,
+                    // even when opening an internal node as a leaf.
+                    let parent = tree.layers[1][index / ARITY];
+                    let short_path = MerklePath::new(index / ARITY, path.siblings()[1..].to_vec());
+                    assert!(!MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify(
+                        &root,
+                        &parent,
+                        &short_path,
+                        num_leaves
+                    ));
+
+                    // The root cannot be opened as a leaf of an empty path.
+                    assert!(!MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify(
+                        &root,
+                        &root,
+                        &MerklePath::new(0, vec![]),
+                        num_leaves
+                    ));
+                }
+            }
+
+            // Padding leaves cannot be opened, although their path is valid.
+            let width = ARITY.pow(tree.depth() as u32);
+            if width > num_leaves {
+                let padding = [Felt::zero(); NODE_SIZE];
+                let mut padded_leaves = leaves.clone();
+                padded_leaves.resize(width, padding);
+                let padded_tree =
+                    MerkleTree::<Felt, H, ARITY, NODE_SIZE>::new(&padded_leaves).unwrap();
+                assert_eq!(padded_tree.root(), root);
+
+                let path = padded_tree.prove(num_leaves).unwrap();
+                assert!(MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify(
+                    &root, &padding, &path, width
+                ));
+                assert!(!MerkleTree::<Felt, H, ARITY, NODE_SIZE>::verify(
+                    &root, &padding, &path, num_leaves
+                ));
+            }
+
+            assert_eq!(
+                tree.prove(num_leaves),
+                Err(MerkleError::InvalidLeafIndex(num_leaves))
+            );
+        }
+
+        assert_eq!(
+            MerkleTree::<Felt, H, ARITY, NODE_SIZE>::new(&[]).err(),
+            Some(MerkleError::EmptyTree)
+        );
+    }
+
+    #[test]
+    fn test_merkle_tree() {
+        check_tree::<anemoi_2_1::AnemoiHash, 2, 1>();
+        check_tree::<anemoi_4_3::AnemoiHash, 2, 2>();
+        check_tree::<anemoi_4_3::AnemoiHash, 4, 1>();
+        check_tree::<anemoi_8_7::AnemoiHash, 2, 4>();
+        check_tree::<anemoi_8_7::AnemoiHash, 8, 1>();
+        check_tree::<anemoi_12_11::AnemoiHash, 3, 4>();
+        check_tree::<anemoi_12_11::AnemoiHash, 12, 1>();
+    }
+
+    #[test]
+    fn test_merkle_root() {
+        let leaves: Vec<[Felt; 1]> = (0..4u64).map(|i| [Felt::from(i)]).collect();
+        let tree = MerkleTree::<Felt, anemoi_2_1::AnemoiHash, 2, 1>::new(&leaves).unwrap();
+
+        let compress =
+            |a: Felt, b: Felt| <anemoi_2_1::AnemoiHash as Jive<Felt>>::compress(&[a, b])[0];
+        let expected = compress(
+            compress(leaves[0][0], leaves[1][0]),
+            compress(leaves[2][0], leaves[3][0]),
+        );
+        assert_eq!(tree.root(), [expected]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the Jive state width must be equal to ARITY * NODE_SIZE")]
+    fn test_merkle_tree_invalid_width() {
+        let leaves = [[Felt::zero(); 1]; 4];
+        let _ = MerkleTree::<Felt, anemoi_4_3::AnemoiHash, 2, 1>::new(&leaves);
+    }
+}
@@ -0,0 +1,370 @@
+//! Sparse Merkle trees built on the `merge` method of an Anemoi Sponge
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use core::marker::PhantomData;
+
+use crate::{AnemoiDigest, Sponge};
+
+use ark_ff::PrimeField;
+
+/// The size in bytes of the keys of a sparse Merkle tree.
+pub const SPARSE_KEY_SIZE: usize = 32;
+
+/// A key of a sparse Merkle tree, whose bits are read from the most
+/// significant bit of its first byte onwards to select the path
+/// from the root to the corresponding leaf.
+pub type SparseMerkleKey = [u8; SPARSE_KEY_SIZE];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// An authentication path for a key of a sparse Merkle tree.
+///
+/// It contains the `DEPTH` siblings of the nodes on the path
+/// from the leaf of the key up to the root.
+pub struct SparseMerkleProof<F: PrimeField, const DIGEST_SIZE: usize> {
+    siblings: Vec<AnemoiDigest<F, DIGEST_SIZE>>,
+}
+
+impl<F: PrimeField, const DIGEST_SIZE: usize> SparseMerkleProof<F, DIGEST_SIZE> {
+    /// Returns a new proof from the provided siblings,
+    /// ordered from the leaves level up to the root.
+    pub fn new(siblings: Vec<AnemoiDigest<F, DIGEST_SIZE>>) -> Self {
+        Self { siblings }
+    }
+
+    /// Returns the siblings of this proof, from the leaves level up to the root.
+    pub fn siblings(&self) -> &[AnemoiDigest<F, DIGEST_SIZE>] {
+        &self.siblings
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A sparse Merkle tree of depth `DEPTH` over `F`, whose nodes are
+/// digests merged with the Sponge `H`.
+///
+/// The tree has `2^DEPTH` leaves, indexed by the first `DEPTH` bits of
+/// their [`SparseMerkleKey`]. Absent leaves hold the default digest, and
+/// only nodes which differ from the root of an empty subtree of the same
+/// height are stored. Hence, storing the default digest at some key is
+/// equivalent to removing it from the tree.
+pub struct SparseMerkleTree<F: PrimeField, H, const DEPTH: usize, const DIGEST_SIZE: usize> {
+    // Non-empty nodes, indexed by their height and the
+    // prefix of the keys of the leaves below them.
+    nodes: BTreeMap<(usize, SparseMerkleKey), AnemoiDigest<F, DIGEST_SIZE>>,
+    // Roots of empty subtrees, indexed by their height.
+    empty_hashes: Vec<AnemoiDigest<F, DIGEST_SIZE>>,
+    _hasher: PhantomData<H>,
+}
+
+impl<F, H, const DEPTH: usize, const DIGEST_SIZE: usize> Default
+    for SparseMerkleTree<F, H, DEPTH, DIGEST_SIZE>
+where
+    F: PrimeField,
+    H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>>,
+{
+    fn default() -> Self {
+        Self {
+            nodes: BTreeMap::new(),
+            empty_hashes: Self::compute_empty_hashes(),
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<F, H, const DEPTH: usize, const DIGEST_SIZE: usize> SparseMerkleTree<F, H, DEPTH, DIGEST_SIZE>
+where
+    F: PrimeField,
+    H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>>,
+{
+    /// Returns a new empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the roots of empty subtrees of height 0 to `DEPTH`.
+    ///
+    /// The first one is the default digest, and each
+    /// subsequent one merges two copies of the previous one.
+    pub fn compute_empty_hashes() -> Vec<AnemoiDigest<F, DIGEST_SIZE>> {
+        assert!(
+            DEPTH > 0 && DEPTH <= 8 * SPARSE_KEY_SIZE,
+            "the depth of a sparse Merkle tree must be between 1 and 256"
+        );
+
+        super::empty_hashes::<F, H, DIGEST_SIZE>(DEPTH)
+    }
+
+    /// Returns the roots of empty subtrees of this tree, indexed by their height.
+    pub fn empty_hashes(&self) -> &[AnemoiDigest<F, DIGEST_SIZE>] {
+        &self.empty_hashes
+    }
+
+    /// Returns the root of this tree.
+    pub fn root(&self) -> AnemoiDigest<F, DIGEST_SIZE> {
+        self.node(DEPTH, &[0u8; SPARSE_KEY_SIZE])
+    }
+
+    /// Returns the value stored at the provided key, if any.
+    pub fn get(&self, key: &SparseMerkleKey) -> Option<AnemoiDigest<F, DIGEST_SIZE>> {
+        self.nodes.get(&(0, Self::prefix(key, 0))).copied()
+    }
+
+    /// Returns whether a value is stored at the provided key.
+    pub fn contains(&self, key: &SparseMerkleKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts or updates the value at the provided key, and returns its
+    /// previous value, if any.
+    pub fn insert(
+        &mut self,
+        key: &SparseMerkleKey,
+        value: AnemoiDigest<F, DIGEST_SIZE>,
+    ) -> Option<AnemoiDigest<F, DIGEST_SIZE>> {
+        let previous = self.get(key);
+
+        let mut node = value;
+        for height in 0..=DEPTH {
+            let prefix = Self::prefix(key, height);
+            if node == self.empty_hashes[height] {
+                self.nodes.remove(&(height, prefix));
+            } else {
+                self.nodes.insert((height, prefix), node);
+            }
+
+            if height < DEPTH {
+                let sibling = self.node(height, &Self::sibling_prefix(key, height));
+                node = Self::merge_with_sibling(key, height, &node, &sibling);
+            }
+        }
+
+        previous
+    }
+
+    /// Removes the value at the provided key, and returns it, if any.
+    pub fn remove(&mut self, key: &SparseMerkleKey) -> Option<AnemoiDigest<F, DIGEST_SIZE>> {
+        self.insert(key, AnemoiDigest::default())
+    }
+
+    /// Returns the authentication path of the provided key, which proves
+    /// either the membership of its value, or its absence from the tree.
+    pub fn prove(&self, key: &SparseMerkleKey) -> SparseMerkleProof<F, DIGEST_SIZE> {
+        let siblings = (0..DEPTH)
+            .map(|height| self.node(height, &Self::sibling_prefix(key, height)))
+            .collect();
+
+        SparseMerkleProof::new(siblings)
+    }
+
+    /// Returns the root obtained by merging `value`, stored at `key`, along
+    /// the provided authentication path, or `None` if it is malformed.
+    pub fn compute_root(
+        key: &SparseMerkleKey,
+        value: &AnemoiDigest<F, DIGEST_SIZE>,
+        proof: &SparseMerkleProof<F, DIGEST_SIZE>,
+    ) -> Option<AnemoiDigest<F, DIGEST_SIZE>> {
+        if proof.siblings.len() != DEPTH {
+            return None;
+        }
+
+        let mut node = *value;
+        for (height, sibling) in proof.siblings.iter().enumerate() {
+            node = Self::merge_with_sibling(key, height, &node, sibling);
+        }
+
+        Some(node)
+    }
+
+    /// Verifies that `value` is stored at `key` in the tree of the
+    /// provided root, given its authentication path.
+    pub fn verify_membership(
+        root: &AnemoiDigest<F, DIGEST_SIZE>,
+        key: &SparseMerkleKey,
+        value: &AnemoiDigest<F, DIGEST_SIZE>,
+        proof: &SparseMerkleProof<F, DIGEST_SIZE>,
+    ) -> bool {
+        Self::compute_root(key, value, proof).as_ref() == Some(root)
+    }
+
+    /// Verifies that no value is stored at `key` in the tree of
+    /// the provided root, given its authentication path.
+    pub fn verify_non_membership(
+        root: &AnemoiDigest<F, DIGEST_SIZE>,
+        key: &SparseMerkleKey,
+        proof: &SparseMerkleProof<F, DIGEST_SIZE>,
+    ) -> bool {
+        Self::verify_membership(root, key, &AnemoiDigest::default(), proof)
+    }
+
+    /// Returns the node at the provided height and key prefix.
+    fn node(&self, height: usize, prefix: &SparseMerkleKey) -> AnemoiDigest<F, DIGEST_SIZE> {
+        self.nodes
+            .get(&(height, *prefix))
+            .copied()
+            .unwrap_or(self.empty_hashes[height])
+    }
+
+    /// Merges the node at the provided height on the path of `key` with its sibling.
+    fn merge_with_sibling(
+        key: &SparseMerkleKey,
+        height: usize,
+        node: &AnemoiDigest<F, DIGEST_SIZE>,
+        sibling: &AnemoiDigest<F, DIGEST_SIZE>,
+    ) -> AnemoiDigest<F, DIGEST_SIZE> {
+        if Self::path_bit(key, height) {
+            H::merge(&[*sibling, *node])
+        } else {
+            H::merge(&[*node, *sibling])
+        }
+    }
+
+    /// Returns whether the node at the provided height on the
+    /// path of `key` is the right child of its parent.
+    fn path_bit(key: &SparseMerkleKey, height: usize) -> bool {
+        let bit = DEPTH - 1 - height;
+        (key[bit / 8] >> (7 - bit % 8)) & 1 == 1
+    }
+
+    /// Returns the key prefix identifying the node at the provided
+    /// height on the path of `key`, i.e. its first `DEPTH - height`
+    /// bits, followed by zeros.
+    fn prefix(key: &SparseMerkleKey, height: usize) -> SparseMerkleKey {
+        let len = DEPTH - height;
+        let mut prefix = [0u8; SPARSE_KEY_SIZE];
+        prefix[..len / 8].copy_from_slice(&key[..len / 8]);
+        if !len.is_multiple_of(8) {
+            prefix[len / 8] = key[len / 8] & (0xff << (8 - len % 8));
+        }
+
+        prefix
+    }
+
+    /// Returns the key prefix identifying the sibling of the
+    /// node at the provided height on the path of `key`.
+    fn sibling_prefix(key: &SparseMerkleKey, height: usize) -> SparseMerkleKey {
+        let bit = DEPTH - 1 - height;
+        let mut prefix = Self::prefix(key, height);
+        prefix[bit / 8] ^= 1 << (7 - bit % 8);
+
+        prefix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use crate::{AnemoiHash, AnemoiParameters};
+    use rand_core::{OsRng, RngCore};
+
+    /// Returns a random key whose first byte is set to `first_byte`,
+    /// so that keys are distinct even in shallow trees.
+    fn random_key(first_byte: u8) -> SparseMerkleKey {
+        let mut key = [0u8; SPARSE_KEY_SIZE];
+        OsRng.fill_bytes(&mut key);
+        key[0] = first_byte;
+        key
+    }
+
+    fn check_sparse_tree<F, H, const DEPTH: usize, const DIGEST_SIZE: usize>()
+    where
+        F: PrimeField,
+        H: Sponge<F, Digest = AnemoiDigest<F, DIGEST_SIZE>>,
+    {
+        let mut rng = OsRng;
+        let mut tree = SparseMerkleTree::<F, H, DEPTH, DIGEST_SIZE>::new();
+        let empty_root = tree.root();
+        assert_eq!(empty_root, tree.empty_hashes()[DEPTH]);
+
+        let keys: Vec<SparseMerkleKey> = [0x00, 0x40].map(random_key).to_vec();
+        let values: Vec<AnemoiDigest<F, DIGEST_SIZE>> = (0..2)
+            .map(|_| AnemoiDigest::new([(); DIGEST_SIZE].map(|_| F::rand(&mut rng))))
+            .collect();
+
+        for (key, value) in keys.iter().zip(values.iter()) {
+            assert_eq!(tree.insert(key, *value), None);
+        }
+
+        let root = tree.root();
+        for (key, value) in keys.iter().zip(values.iter()) {
+            assert_eq!(tree.get(key), Some(*value));
+            let proof = tree.prove(key);
+            assert!(
+                SparseMerkleTree::<F, H, DEPTH, DIGEST_SIZE>::verify_membership(
+                    &root, key, value, &proof
+                )
+            );
+            assert!(
+                !SparseMerkleTree::<F, H, DEPTH, DIGEST_SIZE>::verify_non_membership(
+                    &root, key, &proof
+                )
+            );
+        }
+
+        let absent_key = random_key(0xc0);
+        assert!(!tree.contains(&absent_key));
+        let proof = tree.prove(&absent_key);
+        assert!(
+            SparseMerkleTree::<F, H, DEPTH, DIGEST_SIZE>::verify_non_membership(
+                &root,
+                &absent_key,
+                &proof
+            )
+        );
+        assert!(
+            !SparseMerkleTree::<F, H, DEPTH, DIGEST_SIZE>::verify_membership(
+                &root,
+                &absent_key,
+                &values[0],
+                &proof
+            )
+        );
+
+        // Updates and removals are reflected in the root, and removing all
+        // values brings the tree back to its empty state.
+        assert_eq!(tree.insert(&keys[0], values[1]), Some(values[0]));
+        assert_ne!(tree.root(), root);
+        assert_eq!(tree.insert(&keys[0], values[0]), Some(values[1]));
+        assert_eq!(tree.root(), root);
+
+        for (key, value) in keys.iter().zip(values.iter()) {
+            assert_eq!(tree.remove(key), Some(*value));
+            assert_eq!(tree.remove(key), None);
+        }
+        assert_eq!(tree.root(), empty_root);
+        assert!(tree.nodes.is_empty());
+    }
+
+    struct SparseTreeCheck;
+
+    impl InstantiationCheck for SparseTreeCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            check_sparse_tree::<F, AnemoiHash<F, NUM_COLUMNS, DIGEST_SIZE, P>, 8, DIGEST_SIZE>();
+        }
+    }
+
+    #[test]
+    fn test_sparse_merkle_tree() {
+        check_all_instantiations::<SparseTreeCheck>();
+    }
+
+    #[test]
+    #[cfg(feature = "bn_254")]
+    fn test_sparse_merkle_tree_full_depth() {
+        use crate::bn_254::anemoi_2_1;
+
+        check_sparse_tree::<
+            crate::bn_254::Felt,
+            anemoi_2_1::AnemoiHash,
+            256,
+            { anemoi_2_1::DIGEST_SIZE },
+        >();
+    }
+}
@@ -0,0 +1,457 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger64, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 16],
+        vec![Felt::one(); 16],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger64([0x175534e8]))],
+        vec![
+            Felt::new(BigInteger64([0x67c16834])),
+            Felt::new(BigInteger64([0x6d4c69a4])),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x73183817])),
+            Felt::new(BigInteger64([0x043b8cda])),
+            Felt::new(BigInteger64([0x2e464638])),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x6c3442c2])),
+            Felt::new(BigInteger64([0x600bfc8f])),
+            Felt::new(BigInteger64([0x1f923052])),
+            Felt::new(BigInteger64([0x53916049])),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x5adeefa5])),
+            Felt::new(BigInteger64([0x5dfc4e11])),
+            Felt::new(BigInteger64([0x7a668e9f])),
+            Felt::new(BigInteger64([0x388336a5])),
+            Felt::new(BigInteger64([0x1dff79dd])),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x318cdddd])),
+            Felt::new(BigInteger64([0x13cf6153])),
+            Felt::new(BigInteger64([0x4be89ecb])),
+            Felt::new(BigInteger64([0x0c98e4e0])),
+            Felt::new(BigInteger64([0x5a2ea91a])),
+            Felt::new(BigInteger64([0x60136dbe])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger64([0x00ace160])),
+            Felt::new(BigInteger64([0x0731ac30])),
+            Felt::new(BigInteger64([0x2457055c])),
+            Felt::new(BigInteger64([0x5c0e0996])),
+            Felt::new(BigInteger64([0x4f426dfa])),
+            Felt::new(BigInteger64([0x289f71c9])),
+            Felt::new(BigInteger64([0x32d9a3e3])),
+            Felt::new(BigInteger64([0x5c40157e])),
+        ],
+        [
+            Felt::new(BigInteger64([0x05e720a0])),
+            Felt::new(BigInteger64([0x3596cd45])),
+            Felt::new(BigInteger64([0x6aa30f0b])),
+            Felt::new(BigInteger64([0x0ed24a59])),
+            Felt::new(BigInteger64([0x29095c56])),
+            Felt::new(BigInteger64([0x7a621bd9])),
+            Felt::new(BigInteger64([0x6c0ce933])),
+            Felt::new(BigInteger64([0x72ac6c58])),
+        ],
+        [
+            Felt::new(BigInteger64([0x5a22a2ef])),
+            Felt::new(BigInteger64([0x466ad8b9])),
+            Felt::new(BigInteger64([0x5840baca])),
+            Felt::new(BigInteger64([0x18435549])),
+            Felt::new(BigInteger64([0x33ef03bb])),
+            Felt::new(BigInteger64([0x7b4c4026])),
+            Felt::new(BigInteger64([0x48d42e90])),
+            Felt::new(BigInteger64([0x5a28d9a7])),
+        ],
+        [
+            Felt::new(BigInteger64([0x0e858708])),
+            Felt::new(BigInteger64([0x4c0f9c5a])),
+            Felt::new(BigInteger64([0x5d285f0c])),
+            Felt::new(BigInteger64([0x64898c19])),
+            Felt::new(BigInteger64([0x3d98506d])),
+            Felt::new(BigInteger64([0x634ce75d])),
+            Felt::new(BigInteger64([0x480b7afa])),
+            Felt::new(BigInteger64([0x46a91f57])),
+        ],
+        [
+            Felt::new(BigInteger64([0x08877516])),
+            Felt::new(BigInteger64([0x48886e71])),
+            Felt::new(BigInteger64([0x7c1c4290])),
+            Felt::new(BigInteger64([0x089bf629])),
+            Felt::new(BigInteger64([0x26cb6725])),
+            Felt::new(BigInteger64([0x7250fbeb])),
+            Felt::new(BigInteger64([0x05928efe])),
+            Felt::new(BigInteger64([0x56f105a0])),
+        ],
+        [
+            Felt::new(BigInteger64([0x41e2d12c])),
+            Felt::new(BigInteger64([0x3081f7b9])),
+            Felt::new(BigInteger64([0x2325c0fc])),
+            Felt::new(BigInteger64([0x31353f4d])),
+            Felt::new(BigInteger64([0x67da4c28])),
+            Felt::new(BigInteger64([0x4c40341f])),
+            Felt::new(BigInteger64([0x1a98be08])),
+            Felt::new(BigInteger64([0x4925fcad])),
+        ],
+        [
+            Felt::new(BigInteger64([0x149b3771])),
+            Felt::new(BigInteger64([0x4aa234b9])),
+            Felt::new(BigInteger64([0x1eec0bca])),
+            Felt::new(BigInteger64([0x309d6806])),
+            Felt::new(BigInteger64([0x66cd69b5])),
+            Felt::new(BigInteger64([0x2152d148])),
+            Felt::new(BigInteger64([0x7d3ccd6e])),
+            Felt::new(BigInteger64([0x18617dc5])),
+        ],
+        [
+            Felt::new(BigInteger64([0x76c08ef2])),
+            Felt::new(BigInteger64([0x2a338706])),
+            Felt::new(BigInteger64([0x475b5446])),
+            Felt::new(BigInteger64([0x261f8425])),
+            Felt::new(BigInteger64([0x3e6202dd])),
+            Felt::new(BigInteger64([0x63a13974])),
+            Felt::new(BigInteger64([0x46de25b3])),
+            Felt::new(BigInteger64([0x4b2947ef])),
+        ],
+        [
+            Felt::new(BigInteger64([0x068d8aad])),
+            Felt::new(BigInteger64([0x0163b595])),
+            Felt::new(BigInteger64([0x419cd257])),
+            Felt::new(BigInteger64([0x12cd6ca5])),
+            Felt::new(BigInteger64([0x2100bf18])),
+            Felt::new(BigInteger64([0x6e906bbc])),
+            Felt::new(BigInteger64([0x1a5246be])),
+            Felt::new(BigInteger64([0x4a9c540e])),
+        ],
+        [
+            Felt::new(BigInteger64([0x13f7008b])),
+            Felt::new(BigInteger64([0x3b5268eb])),
+            Felt::new(BigInteger64([0x00418f91])),
+            Felt::new(BigInteger64([0x4e115001])),
+            Felt::new(BigInteger64([0x096f516d])),
+            Felt::new(BigInteger64([0x1939504c])),
+            Felt::new(BigInteger64([0x5c5d3eb9])),
+            Felt::new(BigInteger64([0x15409cd4])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 16],
+        vec![Felt::one(); 16],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x6991c792])),
+            Felt::new(BigInteger64([0x7f3806ee])),
+            Felt::new(BigInteger64([0x053a81bd])),
+            Felt::new(BigInteger64([0x317fac5a])),
+            Felt::new(BigInteger64([0x10ede91a])),
+            Felt::new(BigInteger64([0x79457be6])),
+            Felt::new(BigInteger64([0x1023db3b])),
+            Felt::new(BigInteger64([0x73f222e4])),
+            Felt::new(BigInteger64([0x26bf4c0d])),
+            Felt::new(BigInteger64([0x33105a50])),
+            Felt::new(BigInteger64([0x7663446c])),
+            Felt::new(BigInteger64([0x545aad7b])),
+            Felt::new(BigInteger64([0x3881c340])),
+            Felt::new(BigInteger64([0x6f61fbcb])),
+            Felt::new(BigInteger64([0x66403e13])),
+            Felt::new(BigInteger64([0x2dd3b6cc])),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x2df51628])),
+            Felt::new(BigInteger64([0x3995119b])),
+            Felt::new(BigInteger64([0x0ff1daf3])),
+            Felt::new(BigInteger64([0x32781a45])),
+            Felt::new(BigInteger64([0x7e99ed87])),
+            Felt::new(BigInteger64([0x3fff752e])),
+            Felt::new(BigInteger64([0x665e326c])),
+            Felt::new(BigInteger64([0x23763f06])),
+            Felt::new(BigInteger64([0x7af01747])),
+            Felt::new(BigInteger64([0x6b5db909])),
+            Felt::new(BigInteger64([0x6a86f1c7])),
+            Felt::new(BigInteger64([0x6c788de9])),
+            Felt::new(BigInteger64([0x4ab1b133])),
+            Felt::new(BigInteger64([0x4692a1ce])),
+            Felt::new(BigInteger64([0x2f2c5bb5])),
+            Felt::new(BigInteger64([0x3aa27bd0])),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x4cc382a0])),
+            Felt::new(BigInteger64([0x1224c513])),
+            Felt::new(BigInteger64([0x7de097ca])),
+            Felt::new(BigInteger64([0x747da83f])),
+            Felt::new(BigInteger64([0x220d1f7e])),
+            Felt::new(BigInteger64([0x4463e650])),
+            Felt::new(BigInteger64([0x472be62a])),
+            Felt::new(BigInteger64([0x1dcc869e])),
+            Felt::new(BigInteger64([0x08843a57])),
+            Felt::new(BigInteger64([0x51efd095])),
+            Felt::new(BigInteger64([0x491bd738])),
+            Felt::new(BigInteger64([0x461eff6c])),
+            Felt::new(BigInteger64([0x6c6dee15])),
+            Felt::new(BigInteger64([0x30d122ea])),
+            Felt::new(BigInteger64([0x3e4a771d])),
+            Felt::new(BigInteger64([0x6d268914])),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x0a46ed8e])),
+            Felt::new(BigInteger64([0x6623dc60])),
+            Felt::new(BigInteger64([0x326fbb45])),
+            Felt::new(BigInteger64([0x53fb03fd])),
+            Felt::new(BigInteger64([0x35ba7de2])),
+            Felt::new(BigInteger64([0x1bccb662])),
+            Felt::new(BigInteger64([0x12382dcd])),
+            Felt::new(BigInteger64([0x2058db21])),
+            Felt::new(BigInteger64([0x632daeee])),
+            Felt::new(BigInteger64([0x12bba6e5])),
+            Felt::new(BigInteger64([0x6b74f466])),
+            Felt::new(BigInteger64([0x1f0bda64])),
+            Felt::new(BigInteger64([0x06de9948])),
+            Felt::new(BigInteger64([0x2a17ff25])),
+            Felt::new(BigInteger64([0x317ab2ec])),
+            Felt::new(BigInteger64([0x7beafd50])),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x4b0ebb14])),
+            Felt::new(BigInteger64([0x6d97be6b])),
+            Felt::new(BigInteger64([0x07eb6bc4])),
+            Felt::new(BigInteger64([0x1a425798])),
+            Felt::new(BigInteger64([0x4f52727a])),
+            Felt::new(BigInteger64([0x39efaed4])),
+            Felt::new(BigInteger64([0x1e33bfe3])),
+            Felt::new(BigInteger64([0x79b7a803])),
+            Felt::new(BigInteger64([0x5a23e6af])),
+            Felt::new(BigInteger64([0x74732747])),
+            Felt::new(BigInteger64([0x4696aef9])),
+            Felt::new(BigInteger64([0x401a18b5])),
+            Felt::new(BigInteger64([0x62832b86])),
+            Felt::new(BigInteger64([0x2262f9fd])),
+            Felt::new(BigInteger64([0x22bc8992])),
+            Felt::new(BigInteger64([0x597aae96])),
+        ],
+        vec![
+            Felt::new(BigInteger64([0x3016acb6])),
+            Felt::new(BigInteger64([0x52c01708])),
+            Felt::new(BigInteger64([0x76252623])),
+            Felt::new(BigInteger64([0x5ccc4e35])),
+            Felt::new(BigInteger64([0x0d99b7a0])),
+            Felt::new(BigInteger64([0x077fd2af])),
+            Felt::new(BigInteger64([0x19ea6b65])),
+            Felt::new(BigInteger64([0x0272badf])),
+            Felt::new(BigInteger64([0x4fcef5b9])),
+            Felt::new(BigInteger64([0x065adf82])),
+            Felt::new(BigInteger64([0x546df56c])),
+            Felt::new(BigInteger64([0x5b348320])),
+            Felt::new(BigInteger64([0x4012ea05])),
+            Felt::new(BigInteger64([0x486c2ae7])),
+            Felt::new(BigInteger64([0x2bf2885b])),
+            Felt::new(BigInteger64([0x25d6d4e1])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger64([0x202bbfb5])),
+            Felt::new(BigInteger64([0x0bb11058])),
+            Felt::new(BigInteger64([0x2fb93e4a])),
+            Felt::new(BigInteger64([0x3f0eda95])),
+            Felt::new(BigInteger64([0x06dbd0ee])),
+            Felt::new(BigInteger64([0x4af310ab])),
+            Felt::new(BigInteger64([0x5e4ac65b])),
+            Felt::new(BigInteger64([0x0357373e])),
+        ],
+        [
+            Felt::new(BigInteger64([0x3e72bd51])),
+            Felt::new(BigInteger64([0x74d78274])),
+            Felt::new(BigInteger64([0x78d4399d])),
+            Felt::new(BigInteger64([0x6f183600])),
+            Felt::new(BigInteger64([0x3456a476])),
+            Felt::new(BigInteger64([0x2ab653d5])),
+            Felt::new(BigInteger64([0x52ccf1ba])),
+            Felt::new(BigInteger64([0x2ea822a0])),
+        ],
+        [
+            Felt::new(BigInteger64([0x5acb9f06])),
+            Felt::new(BigInteger64([0x4b72b0a1])),
+            Felt::new(BigInteger64([0x74d405ef])),
+            Felt::new(BigInteger64([0x579e92ce])),
+            Felt::new(BigInteger64([0x1a99ac02])),
+            Felt::new(BigInteger64([0x6ecdc8d6])),
+            Felt::new(BigInteger64([0x11ee2b56])),
+            Felt::new(BigInteger64([0x2947a7bc])),
+        ],
+        [
+            Felt::new(BigInteger64([0x247bccaf])),
+            Felt::new(BigInteger64([0x73bf085d])),
+            Felt::new(BigInteger64([0x5a9463e4])),
+            Felt::new(BigInteger64([0x56fb8805])),
+            Felt::new(BigInteger64([0x7c7fb195])),
+            Felt::new(BigInteger64([0x77677e8f])),
+            Felt::new(BigInteger64([0x4552b0e5])),
+            Felt::new(BigInteger64([0x5d745735])),
+        ],
+        [
+            Felt::new(BigInteger64([0x0f564adf])),
+            Felt::new(BigInteger64([0x2bc0e884])),
+            Felt::new(BigInteger64([0x799c3b47])),
+            Felt::new(BigInteger64([0x66ffd0f7])),
+            Felt::new(BigInteger64([0x3aa365f0])),
+            Felt::new(BigInteger64([0x49917100])),
+            Felt::new(BigInteger64([0x2c670392])),
+            Felt::new(BigInteger64([0x223f398b])),
+        ],
+        [
+            Felt::new(BigInteger64([0x04f16e7b])),
+            Felt::new(BigInteger64([0x15fa88b5])),
+            Felt::new(BigInteger64([0x63de4a09])),
+            Felt::new(BigInteger64([0x7c9ddfd1])),
+            Felt::new(BigInteger64([0x571bd082])),
+            Felt::new(BigInteger64([0x2a8a5702])),
+            Felt::new(BigInteger64([0x7afe0db4])),
+            Felt::new(BigInteger64([0x34cae08f])),
+        ],
+        [
+            Felt::new(BigInteger64([0x5dae524b])),
+            Felt::new(BigInteger64([0x6841dafb])),
+            Felt::new(BigInteger64([0x6bcb012c])),
+            Felt::new(BigInteger64([0x01255e9a])),
+            Felt::new(BigInteger64([0x5b7ed26d])),
+            Felt::new(BigInteger64([0x59ed6b17])),
+            Felt::new(BigInteger64([0x64bcb4ee])),
+            Felt::new(BigInteger64([0x60eb9141])),
+        ],
+        [
+            Felt::new(BigInteger64([0x3e8b8077])),
+            Felt::new(BigInteger64([0x0406c3a4])),
+            Felt::new(BigInteger64([0x22613f4c])),
+            Felt::new(BigInteger64([0x6948f651])),
+            Felt::new(BigInteger64([0x45a0d1c4])),
+            Felt::new(BigInteger64([0x0e2e1525])),
+            Felt::new(BigInteger64([0x60691f8c])),
+            Felt::new(BigInteger64([0x656608e4])),
+        ],
+        [
+            Felt::new(BigInteger64([0x5d932ad6])),
+            Felt::new(BigInteger64([0x0d700d57])),
+            Felt::new(BigInteger64([0x2c1e8b11])),
+            Felt::new(BigInteger64([0x38a1187d])),
+            Felt::new(BigInteger64([0x02988664])),
+            Felt::new(BigInteger64([0x747009a3])),
+            Felt::new(BigInteger64([0x6fef3d3b])),
+            Felt::new(BigInteger64([0x645e3bcc])),
+        ],
+        [
+            Felt::new(BigInteger64([0x37c1d378])),
+            Felt::new(BigInteger64([0x247152ff])),
+            Felt::new(BigInteger64([0x09d70f8c])),
+            Felt::new(BigInteger64([0x0a0a5bf0])),
+            Felt::new(BigInteger64([0x1e30c242])),
+            Felt::new(BigInteger64([0x1ceec894])),
+            Felt::new(BigInteger64([0x277a4323])),
+            Felt::new(BigInteger64([0x74fb2c4d])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+}
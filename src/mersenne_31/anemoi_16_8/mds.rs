@@ -0,0 +1,73 @@
+use super::BigInteger64;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi, given
+/// by the Cauchy matrix `M[i][j] = 1 / (i + j + 8)`
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger64([0x40000000])),
+    Felt::new(BigInteger64([0x471c71c7])),
+    Felt::new(BigInteger64([0x66666666])),
+    Felt::new(BigInteger64([0x51745d17])),
+    Felt::new(BigInteger64([0x55555555])),
+    Felt::new(BigInteger64([0x62762762])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x471c71c7])),
+    Felt::new(BigInteger64([0x66666666])),
+    Felt::new(BigInteger64([0x51745d17])),
+    Felt::new(BigInteger64([0x55555555])),
+    Felt::new(BigInteger64([0x62762762])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x66666666])),
+    Felt::new(BigInteger64([0x51745d17])),
+    Felt::new(BigInteger64([0x55555555])),
+    Felt::new(BigInteger64([0x62762762])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x51745d17])),
+    Felt::new(BigInteger64([0x55555555])),
+    Felt::new(BigInteger64([0x62762762])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x55555555])),
+    Felt::new(BigInteger64([0x62762762])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x62762762])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+];
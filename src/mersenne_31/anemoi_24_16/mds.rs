@@ -0,0 +1,153 @@
+use super::BigInteger64;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi, given
+/// by the Cauchy matrix `M[i][j] = 1 / (i + j + 12)`
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger64([0x55555555])),
+    Felt::new(BigInteger64([0x62762762])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x62762762])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5b6db6db])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x44444444])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x313b13b1])),
+    Felt::new(BigInteger64([0x20000000])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x313b13b1])),
+    Felt::new(BigInteger64([0x17b425ed])),
+    Felt::new(BigInteger64([0x3c3c3c3c])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x313b13b1])),
+    Felt::new(BigInteger64([0x17b425ed])),
+    Felt::new(BigInteger64([0x6db6db6d])),
+    Felt::new(BigInteger64([0x638e38e3])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x313b13b1])),
+    Felt::new(BigInteger64([0x17b425ed])),
+    Felt::new(BigInteger64([0x6db6db6d])),
+    Felt::new(BigInteger64([0x469ee584])),
+    Felt::new(BigInteger64([0x7286bca1])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x313b13b1])),
+    Felt::new(BigInteger64([0x17b425ed])),
+    Felt::new(BigInteger64([0x6db6db6d])),
+    Felt::new(BigInteger64([0x469ee584])),
+    Felt::new(BigInteger64([0x22222222])),
+    Felt::new(BigInteger64([0x33333333])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x313b13b1])),
+    Felt::new(BigInteger64([0x17b425ed])),
+    Felt::new(BigInteger64([0x6db6db6d])),
+    Felt::new(BigInteger64([0x469ee584])),
+    Felt::new(BigInteger64([0x22222222])),
+    Felt::new(BigInteger64([0x6f7bdef7])),
+    Felt::new(BigInteger64([0x679e79e7])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x313b13b1])),
+    Felt::new(BigInteger64([0x17b425ed])),
+    Felt::new(BigInteger64([0x6db6db6d])),
+    Felt::new(BigInteger64([0x469ee584])),
+    Felt::new(BigInteger64([0x22222222])),
+    Felt::new(BigInteger64([0x6f7bdef7])),
+    Felt::new(BigInteger64([0x10000000])),
+    Felt::new(BigInteger64([0x68ba2e8b])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x313b13b1])),
+    Felt::new(BigInteger64([0x17b425ed])),
+    Felt::new(BigInteger64([0x6db6db6d])),
+    Felt::new(BigInteger64([0x469ee584])),
+    Felt::new(BigInteger64([0x22222222])),
+    Felt::new(BigInteger64([0x6f7bdef7])),
+    Felt::new(BigInteger64([0x10000000])),
+    Felt::new(BigInteger64([0x707c1f07])),
+    Felt::new(BigInteger64([0x48590b21])),
+    Felt::new(BigInteger64([0x6aaaaaaa])),
+    Felt::new(BigInteger64([0x5c28f5c2])),
+    Felt::new(BigInteger64([0x313b13b1])),
+    Felt::new(BigInteger64([0x17b425ed])),
+    Felt::new(BigInteger64([0x6db6db6d])),
+    Felt::new(BigInteger64([0x469ee584])),
+    Felt::new(BigInteger64([0x22222222])),
+    Felt::new(BigInteger64([0x6f7bdef7])),
+    Felt::new(BigInteger64([0x10000000])),
+    Felt::new(BigInteger64([0x707c1f07])),
+    Felt::new(BigInteger64([0x1e1e1e1e])),
+];
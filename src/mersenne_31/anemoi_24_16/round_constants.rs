@@ -0,0 +1,291 @@
+use super::BigInteger64;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger64([0x0000009c])),
+        Felt::new(BigInteger64([0x0823edaa])),
+        Felt::new(BigInteger64([0x77436b07])),
+        Felt::new(BigInteger64([0x2f8c2e40])),
+        Felt::new(BigInteger64([0x3f39f0fd])),
+        Felt::new(BigInteger64([0x289b6906])),
+        Felt::new(BigInteger64([0x2d9fb38d])),
+        Felt::new(BigInteger64([0x72100a8c])),
+        Felt::new(BigInteger64([0x20bfa41e])),
+        Felt::new(BigInteger64([0x55d58d4d])),
+        Felt::new(BigInteger64([0x0a748d83])),
+        Felt::new(BigInteger64([0x43dbf49c])),
+    ],
+    [
+        Felt::new(BigInteger64([0x27450304])),
+        Felt::new(BigInteger64([0x01932fc1])),
+        Felt::new(BigInteger64([0x3914f1d7])),
+        Felt::new(BigInteger64([0x5629d2a9])),
+        Felt::new(BigInteger64([0x5abb1c21])),
+        Felt::new(BigInteger64([0x558da870])),
+        Felt::new(BigInteger64([0x7b719052])),
+        Felt::new(BigInteger64([0x40aa169c])),
+        Felt::new(BigInteger64([0x76566729])),
+        Felt::new(BigInteger64([0x44355130])),
+        Felt::new(BigInteger64([0x698cfa8b])),
+        Felt::new(BigInteger64([0x0936362f])),
+    ],
+    [
+        Felt::new(BigInteger64([0x349b07c1])),
+        Felt::new(BigInteger64([0x79355123])),
+        Felt::new(BigInteger64([0x136786b5])),
+        Felt::new(BigInteger64([0x0936cd27])),
+        Felt::new(BigInteger64([0x0056933f])),
+        Felt::new(BigInteger64([0x3a383080])),
+        Felt::new(BigInteger64([0x388c2d27])),
+        Felt::new(BigInteger64([0x041fd38f])),
+        Felt::new(BigInteger64([0x5aa15421])),
+        Felt::new(BigInteger64([0x749c02a2])),
+        Felt::new(BigInteger64([0x68ba3ec8])),
+        Felt::new(BigInteger64([0x7d14ef6f])),
+    ],
+    [
+        Felt::new(BigInteger64([0x02673afa])),
+        Felt::new(BigInteger64([0x45367743])),
+        Felt::new(BigInteger64([0x79c2339b])),
+        Felt::new(BigInteger64([0x6d476175])),
+        Felt::new(BigInteger64([0x20db7cc1])),
+        Felt::new(BigInteger64([0x040ab699])),
+        Felt::new(BigInteger64([0x6a93d504])),
+        Felt::new(BigInteger64([0x271c8bcc])),
+        Felt::new(BigInteger64([0x5b05043e])),
+        Felt::new(BigInteger64([0x214b4b1b])),
+        Felt::new(BigInteger64([0x25220ff3])),
+        Felt::new(BigInteger64([0x06ad85ef])),
+    ],
+    [
+        Felt::new(BigInteger64([0x2790df89])),
+        Felt::new(BigInteger64([0x2dd63836])),
+        Felt::new(BigInteger64([0x59957252])),
+        Felt::new(BigInteger64([0x1e7375ee])),
+        Felt::new(BigInteger64([0x07d33752])),
+        Felt::new(BigInteger64([0x49b7898b])),
+        Felt::new(BigInteger64([0x1fec04b8])),
+        Felt::new(BigInteger64([0x06f03f9f])),
+        Felt::new(BigInteger64([0x61755c33])),
+        Felt::new(BigInteger64([0x248cf25a])),
+        Felt::new(BigInteger64([0x049498be])),
+        Felt::new(BigInteger64([0x5ed2af96])),
+    ],
+    [
+        Felt::new(BigInteger64([0x63ec4efe])),
+        Felt::new(BigInteger64([0x76365f96])),
+        Felt::new(BigInteger64([0x3e5818e4])),
+        Felt::new(BigInteger64([0x3714e187])),
+        Felt::new(BigInteger64([0x44dbc0a5])),
+        Felt::new(BigInteger64([0x60bb4d7d])),
+        Felt::new(BigInteger64([0x1be3965b])),
+        Felt::new(BigInteger64([0x1f6bcaca])),
+        Felt::new(BigInteger64([0x181fe2d3])),
+        Felt::new(BigInteger64([0x56dbf8f4])),
+        Felt::new(BigInteger64([0x00e1bb5a])),
+        Felt::new(BigInteger64([0x0649c7bc])),
+    ],
+    [
+        Felt::new(BigInteger64([0x0fa2c186])),
+        Felt::new(BigInteger64([0x2f8cbac8])),
+        Felt::new(BigInteger64([0x5075f49f])),
+        Felt::new(BigInteger64([0x1ff419d8])),
+        Felt::new(BigInteger64([0x6f5b3bfb])),
+        Felt::new(BigInteger64([0x3463c9f2])),
+        Felt::new(BigInteger64([0x3724e0b6])),
+        Felt::new(BigInteger64([0x5738bec5])),
+        Felt::new(BigInteger64([0x373402fc])),
+        Felt::new(BigInteger64([0x7ffecd69])),
+        Felt::new(BigInteger64([0x6d33fcba])),
+        Felt::new(BigInteger64([0x444a08c7])),
+    ],
+    [
+        Felt::new(BigInteger64([0x5ef50286])),
+        Felt::new(BigInteger64([0x42d0b497])),
+        Felt::new(BigInteger64([0x444ef97f])),
+        Felt::new(BigInteger64([0x09c5e559])),
+        Felt::new(BigInteger64([0x0f3d8e09])),
+        Felt::new(BigInteger64([0x47bd35a9])),
+        Felt::new(BigInteger64([0x15f9bf92])),
+        Felt::new(BigInteger64([0x11038547])),
+        Felt::new(BigInteger64([0x03c76e4e])),
+        Felt::new(BigInteger64([0x60131341])),
+        Felt::new(BigInteger64([0x1ee8ac23])),
+        Felt::new(BigInteger64([0x1306467c])),
+    ],
+    [
+        Felt::new(BigInteger64([0x2abc845e])),
+        Felt::new(BigInteger64([0x2f061ba2])),
+        Felt::new(BigInteger64([0x65b01c8f])),
+        Felt::new(BigInteger64([0x00878d79])),
+        Felt::new(BigInteger64([0x2c1eb6ec])),
+        Felt::new(BigInteger64([0x78acb908])),
+        Felt::new(BigInteger64([0x627fec01])),
+        Felt::new(BigInteger64([0x6d4fdcfb])),
+        Felt::new(BigInteger64([0x4b35a24c])),
+        Felt::new(BigInteger64([0x2c32d266])),
+        Felt::new(BigInteger64([0x335c6153])),
+        Felt::new(BigInteger64([0x600804d0])),
+    ],
+    [
+        Felt::new(BigInteger64([0x13f908aa])),
+        Felt::new(BigInteger64([0x79294401])),
+        Felt::new(BigInteger64([0x5669be7c])),
+        Felt::new(BigInteger64([0x013a9a6b])),
+        Felt::new(BigInteger64([0x61f7000a])),
+        Felt::new(BigInteger64([0x01a21a25])),
+        Felt::new(BigInteger64([0x3705001f])),
+        Felt::new(BigInteger64([0x69148874])),
+        Felt::new(BigInteger64([0x71f2c4b8])),
+        Felt::new(BigInteger64([0x71871596])),
+        Felt::new(BigInteger64([0x4358d750])),
+        Felt::new(BigInteger64([0x39ba0793])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger64([0x36db6e53])),
+        Felt::new(BigInteger64([0x3a832310])),
+        Felt::new(BigInteger64([0x46668a9a])),
+        Felt::new(BigInteger64([0x1ac09ecb])),
+        Felt::new(BigInteger64([0x5aeaac91])),
+        Felt::new(BigInteger64([0x424a4524])),
+        Felt::new(BigInteger64([0x6b09f4fe])),
+        Felt::new(BigInteger64([0x1ab67cc2])),
+        Felt::new(BigInteger64([0x7219d910])),
+        Felt::new(BigInteger64([0x3dbad690])),
+        Felt::new(BigInteger64([0x4c1be090])),
+        Felt::new(BigInteger64([0x185bf1dc])),
+    ],
+    [
+        Felt::new(BigInteger64([0x20aaff65])),
+        Felt::new(BigInteger64([0x767cf3d0])),
+        Felt::new(BigInteger64([0x4ac2a013])),
+        Felt::new(BigInteger64([0x03e8d1de])),
+        Felt::new(BigInteger64([0x38f6665f])),
+        Felt::new(BigInteger64([0x31c71338])),
+        Felt::new(BigInteger64([0x7b66606d])),
+        Felt::new(BigInteger64([0x2bdb177b])),
+        Felt::new(BigInteger64([0x0a3b2ac6])),
+        Felt::new(BigInteger64([0x6ea5291c])),
+        Felt::new(BigInteger64([0x6dbedc42])),
+        Felt::new(BigInteger64([0x2040c218])),
+    ],
+    [
+        Felt::new(BigInteger64([0x161b2aa1])),
+        Felt::new(BigInteger64([0x56393bb2])),
+        Felt::new(BigInteger64([0x0d2f5b70])),
+        Felt::new(BigInteger64([0x1f0ff2da])),
+        Felt::new(BigInteger64([0x46ac03fb])),
+        Felt::new(BigInteger64([0x7e8bc1c6])),
+        Felt::new(BigInteger64([0x209b23c1])),
+        Felt::new(BigInteger64([0x576afaec])),
+        Felt::new(BigInteger64([0x56a03e3c])),
+        Felt::new(BigInteger64([0x0726010e])),
+        Felt::new(BigInteger64([0x550646fe])),
+        Felt::new(BigInteger64([0x7c39a1d7])),
+    ],
+    [
+        Felt::new(BigInteger64([0x4efb8928])),
+        Felt::new(BigInteger64([0x0d4e8d21])),
+        Felt::new(BigInteger64([0x5e9e33a5])),
+        Felt::new(BigInteger64([0x6e34b277])),
+        Felt::new(BigInteger64([0x524518cc])),
+        Felt::new(BigInteger64([0x3372732e])),
+        Felt::new(BigInteger64([0x3db6f6ed])),
+        Felt::new(BigInteger64([0x657bde78])),
+        Felt::new(BigInteger64([0x421819a8])),
+        Felt::new(BigInteger64([0x1ee974d5])),
+        Felt::new(BigInteger64([0x7c824377])),
+        Felt::new(BigInteger64([0x70e663a5])),
+    ],
+    [
+        Felt::new(BigInteger64([0x5a38e9d0])),
+        Felt::new(BigInteger64([0x5c020a2c])),
+        Felt::new(BigInteger64([0x24852e75])),
+        Felt::new(BigInteger64([0x05748309])),
+        Felt::new(BigInteger64([0x1f508f76])),
+        Felt::new(BigInteger64([0x5f330239])),
+        Felt::new(BigInteger64([0x5922e2b9])),
+        Felt::new(BigInteger64([0x2b634e64])),
+        Felt::new(BigInteger64([0x2e9c2db6])),
+        Felt::new(BigInteger64([0x083ed82d])),
+        Felt::new(BigInteger64([0x4208885b])),
+        Felt::new(BigInteger64([0x2f1f4966])),
+    ],
+    [
+        Felt::new(BigInteger64([0x44ade63b])),
+        Felt::new(BigInteger64([0x527bbe82])),
+        Felt::new(BigInteger64([0x376161fc])),
+        Felt::new(BigInteger64([0x4c2f7b97])),
+        Felt::new(BigInteger64([0x0a72a5bf])),
+        Felt::new(BigInteger64([0x24505321])),
+        Felt::new(BigInteger64([0x03340152])),
+        Felt::new(BigInteger64([0x71f86684])),
+        Felt::new(BigInteger64([0x1360414b])),
+        Felt::new(BigInteger64([0x68a76bbc])),
+        Felt::new(BigInteger64([0x6c6f37ec])),
+        Felt::new(BigInteger64([0x04afee81])),
+    ],
+    [
+        Felt::new(BigInteger64([0x76bd132b])),
+        Felt::new(BigInteger64([0x122ad41d])),
+        Felt::new(BigInteger64([0x4fd7f820])),
+        Felt::new(BigInteger64([0x3b676e51])),
+        Felt::new(BigInteger64([0x3b4adb7e])),
+        Felt::new(BigInteger64([0x7e5189fe])),
+        Felt::new(BigInteger64([0x24ce0616])),
+        Felt::new(BigInteger64([0x301e14e9])),
+        Felt::new(BigInteger64([0x38cd1bdd])),
+        Felt::new(BigInteger64([0x1822fa9b])),
+        Felt::new(BigInteger64([0x5f1a33b6])),
+        Felt::new(BigInteger64([0x4908e9f5])),
+    ],
+    [
+        Felt::new(BigInteger64([0x637b7d01])),
+        Felt::new(BigInteger64([0x42daf6c1])),
+        Felt::new(BigInteger64([0x611d25d5])),
+        Felt::new(BigInteger64([0x42a562a7])),
+        Felt::new(BigInteger64([0x78995660])),
+        Felt::new(BigInteger64([0x2f171e8b])),
+        Felt::new(BigInteger64([0x210f0dc7])),
+        Felt::new(BigInteger64([0x07550440])),
+        Felt::new(BigInteger64([0x22ccb004])),
+        Felt::new(BigInteger64([0x15a36948])),
+        Felt::new(BigInteger64([0x2e3b0bf4])),
+        Felt::new(BigInteger64([0x3531507f])),
+    ],
+    [
+        Felt::new(BigInteger64([0x446da112])),
+        Felt::new(BigInteger64([0x443b0005])),
+        Felt::new(BigInteger64([0x17a8eb1f])),
+        Felt::new(BigInteger64([0x4e91ad00])),
+        Felt::new(BigInteger64([0x2aa5217d])),
+        Felt::new(BigInteger64([0x75314423])),
+        Felt::new(BigInteger64([0x02bfdc70])),
+        Felt::new(BigInteger64([0x78cbfe2d])),
+        Felt::new(BigInteger64([0x7f65863b])),
+        Felt::new(BigInteger64([0x76edcaa5])),
+        Felt::new(BigInteger64([0x57d9635d])),
+        Felt::new(BigInteger64([0x175db10d])),
+    ],
+    [
+        Felt::new(BigInteger64([0x4003cb34])),
+        Felt::new(BigInteger64([0x20b7ce3b])),
+        Felt::new(BigInteger64([0x1abc32e2])),
+        Felt::new(BigInteger64([0x619e5fc8])),
+        Felt::new(BigInteger64([0x72d71071])),
+        Felt::new(BigInteger64([0x10804b16])),
+        Felt::new(BigInteger64([0x699e9663])),
+        Felt::new(BigInteger64([0x06ea4f7d])),
+        Felt::new(BigInteger64([0x387c4e7e])),
+        Felt::new(BigInteger64([0x4e9bb3ac])),
+        Felt::new(BigInteger64([0x7a2f7f30])),
+        Felt::new(BigInteger64([0x036959a6])),
+    ],
+];
@@ -0,0 +1,32 @@
+mod field;
+pub use ark_ff::BigInteger64;
+use ark_ff::Field;
+pub use field::{Felt, FeltParameters};
+
+mod sbox;
+
+/// An instantiation of Anemoi with state width 16 and
+/// rate 8 aimed at providing 128 bits security,
+/// with roughly 124 bits of collision resistance.
+pub mod anemoi_16_8;
+
+/// An instantiation of Anemoi with state width 24 and
+/// rate 16 aimed at providing 128 bits security,
+/// with roughly 124 bits of collision resistance.
+pub mod anemoi_24_16;
+
+/// The default Fiat-Shamir transcript over Felt, built on
+/// the instantiation with state width 16 and rate 8.
+pub type Transcript = anemoi_16_8::AnemoiTranscript;
+
+// HELPER FUNCTION
+// ================================================================================================
+
+#[inline(always)]
+fn mul_by_generator(x: &Felt) -> Felt {
+    let x2 = x.double();
+    let x4 = x2.double();
+    let x8 = x4.double();
+
+    x8 - x
+}
@@ -0,0 +1,85 @@
+use super::BigInteger64;
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 5;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 1] = [0x66666665];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 7;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger64([0x36db6db7]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    let x2 = x.square(); //  1: 2
+    let x3 = x2 * x; //  2: 3
+    let x5 = x3 * x2; //  3: 5
+    let x6 = x3.square(); //  4: 6
+    let mut t = x6;
+    t = t.square(); //  5: 12
+    t = t.square(); //  6: 24
+    t = t.square(); //  7: 48
+    t = t.square(); //  8: 96
+    t *= x6; //  9: 102
+    t = t.square(); // 10: 204
+    t = t.square(); // 11: 408
+    t = t.square(); // 12: 816
+    t = t.square(); // 13: 1632
+    t *= x6; // 14: 1638
+    t = t.square(); // 15: 3276
+    t = t.square(); // 16: 6552
+    t = t.square(); // 17: 13104
+    t = t.square(); // 18: 26208
+    t *= x6; // 19: 26214
+    t = t.square(); // 20: 52428
+    t = t.square(); // 21: 104856
+    t = t.square(); // 22: 209712
+    t = t.square(); // 23: 419424
+    t *= x6; // 24: 419430
+    t = t.square(); // 25: 838860
+    t = t.square(); // 26: 1677720
+    t = t.square(); // 27: 3355440
+    t = t.square(); // 28: 6710880
+    t *= x6; // 29: 6710886
+    t = t.square(); // 30: 13421772
+    t = t.square(); // 31: 26843544
+    t = t.square(); // 32: 53687088
+    t = t.square(); // 33: 107374176
+    t *= x6; // 34: 107374182
+    t = t.square(); // 35: 214748364
+    t = t.square(); // 36: 429496728
+    t = t.square(); // 37: 858993456
+    t = t.square(); // 38: 1717986912
+    t *= x5; // 39: 1717986917
+
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use super::BigInteger320;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x813c791797c48d13,
+        0x7bf18ae35a039bcc,
+        0x86afc7321ddcd0d8,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x99ff9392f028116f,
+        0x65d004f93dba29c1,
+        0x4c8f4cb946d77c19,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0xe9b5d7b3d72595ca,
+        0x0e049bb0d050a9a3,
+        0x478008dd12bef670,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x0278f22f2f891a26,
+        0xf7e315c6b4073799,
+        0x0d5f8e643bb9a1b0,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0x0278f22f2f891a26,
+        0xf7e315c6b4073799,
+        0x0d5f8e643bb9a1b0,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x813c791797c48d13,
+        0x7bf18ae35a039bcc,
+        0x86afc7321ddcd0d8,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x99ff9392f028116f,
+        0x65d004f93dba29c1,
+        0x4c8f4cb946d77c19,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0xe9b5d7b3d72595ca,
+        0x0e049bb0d050a9a3,
+        0x478008dd12bef670,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0xe9b5d7b3d72595ca,
+        0x0e049bb0d050a9a3,
+        0x478008dd12bef670,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x0278f22f2f891a26,
+        0xf7e315c6b4073799,
+        0x0d5f8e643bb9a1b0,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x813c791797c48d13,
+        0x7bf18ae35a039bcc,
+        0x86afc7321ddcd0d8,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x99ff9392f028116f,
+        0x65d004f93dba29c1,
+        0x4c8f4cb946d77c19,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0x99ff9392f028116f,
+        0x65d004f93dba29c1,
+        0x4c8f4cb946d77c19,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0xe9b5d7b3d72595ca,
+        0x0e049bb0d050a9a3,
+        0x478008dd12bef670,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x0278f22f2f891a26,
+        0xf7e315c6b4073799,
+        0x0d5f8e643bb9a1b0,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x813c791797c48d13,
+        0x7bf18ae35a039bcc,
+        0x86afc7321ddcd0d8,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x813c791797c48d13,
+        0x7bf18ae35a039bcc,
+        0x86afc7321ddcd0d8,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x99ff9392f028116f,
+        0x65d004f93dba29c1,
+        0x4c8f4cb946d77c19,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0xe9b5d7b3d72595ca,
+        0x0e049bb0d050a9a3,
+        0x478008dd12bef670,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x0278f22f2f891a26,
+        0xf7e315c6b4073799,
+        0x0d5f8e643bb9a1b0,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x813c791797c48d13,
+        0x7bf18ae35a039bcc,
+        0x86afc7321ddcd0d8,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x99ff9392f028116f,
+        0x65d004f93dba29c1,
+        0x4c8f4cb946d77c19,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0xe9b5d7b3d72595ca,
+        0x0e049bb0d050a9a3,
+        0x478008dd12bef670,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x0278f22f2f891a26,
+        0xf7e315c6b4073799,
+        0x0d5f8e643bb9a1b0,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+];
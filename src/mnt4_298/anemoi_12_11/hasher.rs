@@ -0,0 +1,2141 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger320, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger320([
+            0xb6e9b362434259e9,
+            0xa467ae1e511ca20e,
+            0x1f2274722c8066e5,
+            0xabca127472e06b1f,
+            0x000002094e9162a9,
+        ]))],
+        vec![
+            Felt::new(BigInteger320([
+                0xf22321a352f28129,
+                0x9f38e894beaa398f,
+                0x56f70acc81ee3b99,
+                0xadf04ba186432497,
+                0x00000211cb523c90,
+            ])),
+            Felt::new(BigInteger320([
+                0x4e02e127b5b52bbf,
+                0xd4954b8d981ad238,
+                0x8ac729d49b14351e,
+                0x998f209264e9d5e8,
+                0x0000034cc447bd8c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x9c55b2963a6a3691,
+                0xc12900d175784f33,
+                0x90b897cb558b117b,
+                0x24bbffd84f62442b,
+                0x0000007ee15488cd,
+            ])),
+            Felt::new(BigInteger320([
+                0xb355e97b81db71dd,
+                0xc76fe7461ab4581e,
+                0x1850f8f84a1b925d,
+                0xa7e34e96ebf85546,
+                0x0000036ac79895fc,
+            ])),
+            Felt::new(BigInteger320([
+                0x3c6a582796b3d5ad,
+                0x42ff7c2a59f6a8e9,
+                0x2f4838b3e4254d36,
+                0x1715f167791c0028,
+                0x00000198cd98ad86,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x4c89a7a5df9f7e52,
+                0x3c9083c3c3991b14,
+                0x1ff499cc32990a4e,
+                0x4e86d069d32e36ed,
+                0x000002226eb6fd48,
+            ])),
+            Felt::new(BigInteger320([
+                0xf3fd30914fb342ff,
+                0xd5a97b00a4246ce5,
+                0x062c3e9d21fe6ff9,
+                0x018486890e90add3,
+                0x000002ed8ab82ffe,
+            ])),
+            Felt::new(BigInteger320([
+                0x8c3a1b9b0c4e43d6,
+                0xc155ddb00844fbe8,
+                0x34e3e1119e749d92,
+                0xe93bcd69c4ec3ea7,
+                0x0000006ecc622578,
+            ])),
+            Felt::new(BigInteger320([
+                0x596d6019d42647c1,
+                0x31318b9cd58b3f8f,
+                0x77922553c5d30455,
+                0xc28d9a176d3f7973,
+                0x0000035ae9243dc7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x718c95800f9e29fb,
+                0x4b5a3bd58bf2f435,
+                0xac91c364d556369c,
+                0x5e6a34b3ea431919,
+                0x000002f27ce726d3,
+            ])),
+            Felt::new(BigInteger320([
+                0x5d69a1782f734b41,
+                0x88559208f0033fdc,
+                0x6f4ba0b8056ad5f5,
+                0xf7e3cdf9cf11bf3c,
+                0x0000008dbc3c5956,
+            ])),
+            Felt::new(BigInteger320([
+                0xb520652b81e8635e,
+                0xbf11a796ad142348,
+                0x1ac9aae1a9a42d33,
+                0x088bfbfea33dab59,
+                0x0000000a27c3b579,
+            ])),
+            Felt::new(BigInteger320([
+                0x2b56c9e534cb6e82,
+                0xbe9607a14be81c8d,
+                0x7ddb1653523fe49a,
+                0x08fa0b3c7b7d0eb3,
+                0x0000025dfb41d300,
+            ])),
+            Felt::new(BigInteger320([
+                0x59d7ba7d7a8f6a07,
+                0x8d8b755fd0c3df46,
+                0x685d749b589e8f3c,
+                0x3ff408371c246937,
+                0x00000037daa8a681,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x25c9f766c5daf2e4,
+                0x037c3ac46f89514d,
+                0x7d8fe4d9238fdf0e,
+                0x13be38ba625d4e47,
+                0x00000213b49f1751,
+            ])),
+            Felt::new(BigInteger320([
+                0xc07e0a26f500d032,
+                0x5d6ffc61c81197e1,
+                0x043ccc95c7ca8f28,
+                0x9b928259241932d1,
+                0x000002ab5bb55668,
+            ])),
+            Felt::new(BigInteger320([
+                0xd55463674784a09f,
+                0x483a8f4e677f0ca4,
+                0x3ffc53c073eaec3a,
+                0x8a9fa7b45f2b456f,
+                0x000003a4e28edce6,
+            ])),
+            Felt::new(BigInteger320([
+                0x552088cf51f2bc14,
+                0xf915972ce14e6aa9,
+                0x2c9de36636558a8a,
+                0x5e725d604d184f00,
+                0x000001e90bd24d68,
+            ])),
+            Felt::new(BigInteger320([
+                0x6936ab108fc3223c,
+                0x91f5d616ef838fac,
+                0x85bb642c5f6b041e,
+                0xcdfc2ffea6e169ca,
+                0x00000263246ad1f9,
+            ])),
+            Felt::new(BigInteger320([
+                0x758f87a1d11052be,
+                0x4b1f0945ce854475,
+                0x25eca61564793b2a,
+                0x495d7b2f4ceb2a7c,
+                0x0000033aebd1955e,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x4bc2cda5bd668889,
+            0x93c0bfe7667912ac,
+            0x0f64fcebc9c44121,
+            0xa7ada012e434c522,
+            0x000003b6c0b45c14,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x749a8a319bd062f0,
+            0x653281eeefbfeccd,
+            0x6b8c90d4b2688d65,
+            0x1112b927208a6054,
+            0x00000022a73992ea,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x45fcc0a514c07d47,
+            0x8d0deda4c7b3fd65,
+            0xe366d86b8c5ea584,
+            0x479de6f7c6b74e5e,
+            0x0000025340ae99bf,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x1dc5bcfba4f0b0bb,
+            0xc57a3a58815c7d69,
+            0x5a699571b2b8839e,
+            0x1c601b6ff51017e9,
+            0x00000381446ce86b,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xef8c7225d98c64da,
+            0x0cb69dd0e33dd08f,
+            0xa2f720903562498d,
+            0x3e5aa00990eab157,
+            0x00000132a2a0e009,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x8d627bcf4537b27c,
+            0x477939b93fd4b9d1,
+            0x67eebc25f3b87a0e,
+            0x73beead82f8a360e,
+            0x0000034e8762e697,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x04b8880e9e9c6555,
+            0x994fefd555904e5f,
+            0x6f1c1e5593ea2df8,
+            0x5148a6a1c831bd62,
+            0x0000010ae2e7ccf2,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x4c76a3297b53f658,
+            0xa0d608f0bf59c6e8,
+            0x84ff26ae27d55c55,
+            0x3e5d68d079de7d46,
+            0x000001018a37c943,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xb376a879c52bcaad,
+            0x16b8dd2b21f11aa5,
+            0x141bcdb1e24a1eef,
+            0xd57354ea222f9710,
+            0x000002d3404938a5,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x8fbb453945daadf2,
+            0x267ee3d1770e0f74,
+            0x5b72876056def08e,
+            0x8535b8a360f34a44,
+            0x000000a10ced47ba,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x6c8d30eac66db4ee,
+                0xb18df2263607f23f,
+                0xfdd74b2ea320e592,
+                0xd79f568c8f3b4b90,
+                0x000002d29967cdba,
+            ])),
+            Felt::new(BigInteger320([
+                0x0b0b935fc41d3b42,
+                0x2c46066ccb836c69,
+                0x7dd1e9411c1b950e,
+                0xc01ecaddf87799ad,
+                0x000001da979774b8,
+            ])),
+            Felt::new(BigInteger320([
+                0x5b42c7480dffc1af,
+                0xb21116aae0d2466c,
+                0x93252469efe74fb9,
+                0x12c7045e0a394fc1,
+                0x000000bc89420835,
+            ])),
+            Felt::new(BigInteger320([
+                0x1c2d037941b4d12a,
+                0x37acb5422cd1ba43,
+                0xf03ef0d968179a96,
+                0x0090bdc6de405f83,
+                0x0000038a31826694,
+            ])),
+            Felt::new(BigInteger320([
+                0x319f9c38098633b1,
+                0x219d468507bf786a,
+                0x354c1648abee5291,
+                0x28cf89634f281ae7,
+                0x000002099ec9e590,
+            ])),
+            Felt::new(BigInteger320([
+                0x15219ca681072450,
+                0x8162acb3de11c725,
+                0x95c895ae8c091720,
+                0xcb93301053fb20a9,
+                0x000002362f32b5b0,
+            ])),
+            Felt::new(BigInteger320([
+                0x0c65614270644102,
+                0x92e3041911d85a9c,
+                0x29fee36b7eeab9e0,
+                0x343051a349530ec2,
+                0x000001b305c3edb0,
+            ])),
+            Felt::new(BigInteger320([
+                0x0067cc38d8908041,
+                0xe3e5f2e547d82350,
+                0x16087608ac5797e5,
+                0x2bbdfe8731d9b860,
+                0x000002c6c858603b,
+            ])),
+            Felt::new(BigInteger320([
+                0xfd08f81166b455d1,
+                0x236ef2d213eeb328,
+                0xd012e192d2546a7c,
+                0xab117fffdb133b1f,
+                0x000003ab52d2f344,
+            ])),
+            Felt::new(BigInteger320([
+                0x09b31b7860370ee7,
+                0x8aa385c6855d1177,
+                0x5b3e479a43b9ea58,
+                0x2d17288a3390eac8,
+                0x00000083a0eeee94,
+            ])),
+            Felt::new(BigInteger320([
+                0xce02890d0abba338,
+                0x53562326025e115a,
+                0x0b681cf67c62942d,
+                0xba578f76b3570600,
+                0x000000b48216db43,
+            ])),
+            Felt::new(BigInteger320([
+                0xe71361783dc2e82b,
+                0xd1487ef5f930bf79,
+                0xc83fb302faa006cb,
+                0x6b52de5b3c843918,
+                0x000001a8832aa9da,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xd7349788f49a63e4,
+                0xf75147e2eef131c9,
+                0x732f43c3bb066b5b,
+                0xff91953ac6a6c0a8,
+                0x000000d15c81015e,
+            ])),
+            Felt::new(BigInteger320([
+                0xb6836f1ea9e3d3a6,
+                0x0eb5ee8fe1195123,
+                0xf2ec6052605f6301,
+                0x01e84e30b9729498,
+                0x0000015c54a3c04a,
+            ])),
+            Felt::new(BigInteger320([
+                0x4af050d9b3921c12,
+                0xbe22fbbdecce92c2,
+                0xb8ccc9908c89113c,
+                0xdf1b716a12fce2d7,
+                0x0000028cb38b17de,
+            ])),
+            Felt::new(BigInteger320([
+                0x1f7fb2ec88ca6f92,
+                0xd92848bc4aa0ba57,
+                0x7b06714017be8058,
+                0x6531e9978d426491,
+                0x000001debc798a0d,
+            ])),
+            Felt::new(BigInteger320([
+                0x48e0f77574fdd0af,
+                0x54ffb3a64f684994,
+                0x95234d5ee28fe014,
+                0x275cc9f733196432,
+                0x0000014cccd7f01a,
+            ])),
+            Felt::new(BigInteger320([
+                0xc3c4d279dee2c09b,
+                0x6e8d808f2bef092a,
+                0x66ebbbda671ba821,
+                0xd97b4dff13429a6f,
+                0x0000034c6aba97af,
+            ])),
+            Felt::new(BigInteger320([
+                0x59044f62b0f65043,
+                0x75739da1f02403a7,
+                0x19fdc307728f523b,
+                0x48a869e828ceb4de,
+                0x000001290b57ee94,
+            ])),
+            Felt::new(BigInteger320([
+                0xc62c5a9e1232cdb2,
+                0xf7360ae6c7951bbf,
+                0x4ff580acb1e608c2,
+                0x0f64868bf1e9a864,
+                0x000000f34cd1608c,
+            ])),
+            Felt::new(BigInteger320([
+                0xcb2efc18b1ce5458,
+                0x18c7c4d82950ebca,
+                0xbcf4d3e914f806cc,
+                0xffe9216ba790cdf1,
+                0x000001aa62730697,
+            ])),
+            Felt::new(BigInteger320([
+                0x996b869971d32353,
+                0x0758801c53c945fa,
+                0x3e3d554c653375b2,
+                0x4cfebd669d958d93,
+                0x000001969f086842,
+            ])),
+            Felt::new(BigInteger320([
+                0xf69786ae67e74840,
+                0x35bbe10a46100286,
+                0x9a3d7303c38f782c,
+                0x40fab6767577e36e,
+                0x0000002eef523247,
+            ])),
+            Felt::new(BigInteger320([
+                0xa475a245729ba937,
+                0xff35ebd029c0c602,
+                0xaff3f89987da34cc,
+                0xa81acf16209aced3,
+                0x000003ab658b7d5d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x2994d495c84cee2e,
+                0x0ba0928f30e2276c,
+                0x8981b9d9b191bddc,
+                0xd944bc9f64c9ad5e,
+                0x0000036370d74b19,
+            ])),
+            Felt::new(BigInteger320([
+                0x326c3fd0b6564af7,
+                0xbb8ccc0ad48f65ee,
+                0xabfcb5fe80c78e6a,
+                0xf38dad3c41b9e7a5,
+                0x0000018815b675de,
+            ])),
+            Felt::new(BigInteger320([
+                0x982847a74131cd28,
+                0x3c8dab2a57e93ac5,
+                0xb36e8cfe6ecfb0c4,
+                0x5e6dde2b8aac864d,
+                0x000000dc47bb7b48,
+            ])),
+            Felt::new(BigInteger320([
+                0xa3aaf7e294bdcbbe,
+                0x7526555c8d43bcf2,
+                0xdf9dd399dcbf84ef,
+                0x4ae5a28e22cf37b2,
+                0x00000031326ab393,
+            ])),
+            Felt::new(BigInteger320([
+                0x2abbf96a44f2cc25,
+                0xeb10ceaf05ab1b07,
+                0x77c2b1c9115e4413,
+                0xb46e31481401d2e4,
+                0x000000b8170fb4e1,
+            ])),
+            Felt::new(BigInteger320([
+                0xa9fd5f211feeced2,
+                0x94b7c4b27684af27,
+                0x888c2243d8c6a3db,
+                0x1740be67928dad14,
+                0x000002637ea9f9da,
+            ])),
+            Felt::new(BigInteger320([
+                0x2777ffd1a8ec2681,
+                0x3e741445414d3b7b,
+                0xa211f8286fa988d8,
+                0xf8ac04c62b564598,
+                0x000000a8dfc51790,
+            ])),
+            Felt::new(BigInteger320([
+                0xd80bae0f4928da87,
+                0xaff972a65604966e,
+                0xbe099a349fbe719e,
+                0xc1db3a3eb3e75f54,
+                0x000000a707f9356b,
+            ])),
+            Felt::new(BigInteger320([
+                0x1cf5749bdea88e4b,
+                0x4d667d7fe4fc856b,
+                0x4f2d9f7ff37e9504,
+                0x7ddf2eb30045e326,
+                0x000001a5c3088d86,
+            ])),
+            Felt::new(BigInteger320([
+                0x1c14742a387d4697,
+                0xf047dfad6c7a4118,
+                0x4d7c25d287b451ab,
+                0x8d311fbcd49cc96b,
+                0x0000033d8e6be46e,
+            ])),
+            Felt::new(BigInteger320([
+                0x0e0d8b8ab940aead,
+                0x317af5ed63e15ce9,
+                0x89305570c8064f21,
+                0xe0bde8863b2687f7,
+                0x000000ca3ffef753,
+            ])),
+            Felt::new(BigInteger320([
+                0xf59c0923e4856a17,
+                0xe687d1991077eb06,
+                0xa828e79d31eb9346,
+                0xf7c266aa72a469e2,
+                0x000001c2924f7602,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xf272f3c4e9f74fda,
+                0xa6e300690f57f53c,
+                0xc97eb5a9cc1aa3dd,
+                0x0608e1a6b1a14343,
+                0x0000023425a583b1,
+            ])),
+            Felt::new(BigInteger320([
+                0x6e50c54fb89c51a0,
+                0x84e7c612a9ab934f,
+                0x47a7eecce41bfe8d,
+                0x2eda986f56d59d5b,
+                0x00000389adb6cee4,
+            ])),
+            Felt::new(BigInteger320([
+                0x22c4249761c82b66,
+                0x42354a6946c2b1f6,
+                0x20b34bf9fa463d1e,
+                0xf084af20a1680dc7,
+                0x0000018bd6aa26ba,
+            ])),
+            Felt::new(BigInteger320([
+                0xa39659efb001d76a,
+                0xdf65d7ac8acd8c5f,
+                0x4f3c66c12838856e,
+                0x6b3664f2aa17d6ae,
+                0x000002fc8c6f7033,
+            ])),
+            Felt::new(BigInteger320([
+                0x4d989f89cceab8c3,
+                0x3aad42568ce00c15,
+                0xaee64c3450715154,
+                0x4b46c93933c43def,
+                0x0000033ac1af8788,
+            ])),
+            Felt::new(BigInteger320([
+                0x7b56fd3e84fda5c8,
+                0xd758921468cc1de2,
+                0x005ab95be1adc6cf,
+                0x8253b70b2e88caff,
+                0x00000102fa4f948c,
+            ])),
+            Felt::new(BigInteger320([
+                0xdc770e8c1d60b9c7,
+                0x0fa075c423df3cf1,
+                0xf7775a6d393bbb81,
+                0xba205e3203922da2,
+                0x00000252fffd47f7,
+            ])),
+            Felt::new(BigInteger320([
+                0x318e5a0e208d1eb1,
+                0x853662db66c408f7,
+                0xc627218cbf7fc5c2,
+                0x2928e11cb912bea4,
+                0x00000213782f39f6,
+            ])),
+            Felt::new(BigInteger320([
+                0x1b69aacfc008ab7a,
+                0x1128ea9621b8047c,
+                0x4424833f8a2383a7,
+                0x2d7c0f393a2a3d5f,
+                0x000000d50c7a3bd5,
+            ])),
+            Felt::new(BigInteger320([
+                0x273e5f91136241d9,
+                0x6185a6aa9fa3f00b,
+                0x870c78a238cbcb99,
+                0xc60d4be397f62f92,
+                0x000000b048846547,
+            ])),
+            Felt::new(BigInteger320([
+                0xdcc3d047a123cd76,
+                0x92074d1aaf9e353d,
+                0x8bd56660d2c6aad4,
+                0x6c34d10897a62ce4,
+                0x000000e0152091d9,
+            ])),
+            Felt::new(BigInteger320([
+                0x05a510dc6def4449,
+                0xc49fc9556559f0bd,
+                0xacd778dad97b471d,
+                0xa70443d0131caca0,
+                0x0000001f0ba73167,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x7be2565eaba0bc70,
+                0x058cd2a221c1a2a9,
+                0x9d780fe0302488a8,
+                0x88fb56ef4d26e61d,
+                0x00000245ff362f3f,
+            ])),
+            Felt::new(BigInteger320([
+                0x5e2c3bcee7406afe,
+                0x9440ee832e8fd57a,
+                0xe899359ab7e08675,
+                0x591839ce7fe9b06e,
+                0x000002706880f7ba,
+            ])),
+            Felt::new(BigInteger320([
+                0xc5b08c00097a9431,
+                0x0c361c369765f29d,
+                0x48fc0f104e5bcd6c,
+                0xbdc19db9749c14da,
+                0x0000038226102d60,
+            ])),
+            Felt::new(BigInteger320([
+                0x99624a09aca20094,
+                0x8821b5484877b099,
+                0x83ffa50b4f9fa2ec,
+                0xd614136416234efb,
+                0x0000028955014be7,
+            ])),
+            Felt::new(BigInteger320([
+                0xe8725fd6cfc7e2b8,
+                0x2df6c2f589a32809,
+                0x87a637c404b0301a,
+                0xe1a7069b0f7f9770,
+                0x000000cfa619d0e5,
+            ])),
+            Felt::new(BigInteger320([
+                0xf500c0e3643a2ff7,
+                0xe580b25527fd411f,
+                0xfc8a8ffb109527e8,
+                0x587dd12442d81ba9,
+                0x000000819103b318,
+            ])),
+            Felt::new(BigInteger320([
+                0x12dbe05066826d89,
+                0x3012796e9224bebb,
+                0x7413d076c8fed518,
+                0x70e16dbe8ce95f0b,
+                0x0000001b57e7b88b,
+            ])),
+            Felt::new(BigInteger320([
+                0x731138cb6413816b,
+                0xf941e1c930cc420d,
+                0x7cbab58d19db8d14,
+                0xb33264bf9933c22b,
+                0x000002b3c2240cd5,
+            ])),
+            Felt::new(BigInteger320([
+                0x56dbd2344f7bf0b1,
+                0x51d7d7334bde17bd,
+                0xf5c0bbaba4b69c7c,
+                0xf08969a1aa5182b9,
+                0x000003b617162021,
+            ])),
+            Felt::new(BigInteger320([
+                0x6e68f26987958a0c,
+                0x5bc4b55f95c18036,
+                0x8fb12ff44ae5fc9e,
+                0xceb5d63177c36e4c,
+                0x0000034ccac25c76,
+            ])),
+            Felt::new(BigInteger320([
+                0x7affd79b2a7fd579,
+                0x90281a4aba984146,
+                0x486aa8b517b61545,
+                0xc9f397c6ea714ae6,
+                0x000000315651d124,
+            ])),
+            Felt::new(BigInteger320([
+                0x71d740ebdddfdf85,
+                0x746fe26d94ca42ee,
+                0xbc5425819b9a2a73,
+                0xb4dd0d729e6a179e,
+                0x00000364ef5948da,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x323df2a1b22d005c,
+                0xd85c4e1cebef9b94,
+                0xcbbc9150fa36919d,
+                0x36e533254c201ca0,
+                0x000003691d59d552,
+            ])),
+            Felt::new(BigInteger320([
+                0xddaf471d8d820ca8,
+                0xef811ad2de12219d,
+                0x7eb357c6cef70885,
+                0x1fc4a4bf4a7028c6,
+                0x000002d826c8738d,
+            ])),
+            Felt::new(BigInteger320([
+                0x2b7ce0ff5dd2464a,
+                0x438f931633aa115b,
+                0x597f5fb0e5bd3bee,
+                0x868a1ab8114cf3fe,
+                0x000000ec5d9c3205,
+            ])),
+            Felt::new(BigInteger320([
+                0x096395702115e3f0,
+                0x428396f9376dce6b,
+                0xe4b10e60416aa8d7,
+                0x5a4377cea53a0938,
+                0x0000008f29ada782,
+            ])),
+            Felt::new(BigInteger320([
+                0xde425a4a9feb19e5,
+                0x81d026e0c7aa78c7,
+                0x5a164e5d2c82ceba,
+                0x15915f70b201b3ff,
+                0x000003a605a2d5a7,
+            ])),
+            Felt::new(BigInteger320([
+                0xa05d77b47dc8d4b6,
+                0x31481b6a7f8d63a3,
+                0x048f2e8d4f755a6c,
+                0x880a9cea65e3ffa3,
+                0x000000137dca3ed5,
+            ])),
+            Felt::new(BigInteger320([
+                0x7c5ce96e5eb235c6,
+                0x66bfed4995b3023a,
+                0xa19a10a8e0a9158d,
+                0x632d2c39515026b0,
+                0x000002052d8a9a40,
+            ])),
+            Felt::new(BigInteger320([
+                0xcb93586fd2b9d526,
+                0xe4a7323cfe348d35,
+                0x9738afe10ca352ac,
+                0xad2be2783f082a41,
+                0x000003abc7afabfc,
+            ])),
+            Felt::new(BigInteger320([
+                0xf6c36e63f9e3553c,
+                0x2546001e754c6ba8,
+                0x7a0831b02479052a,
+                0x4a091c9d6ccf034e,
+                0x00000090b5cdfcb1,
+            ])),
+            Felt::new(BigInteger320([
+                0x99326a6f39bd3abf,
+                0xeef353f304ca5e1a,
+                0xc084198301c0babd,
+                0xdcf29eebaf5202b8,
+                0x000002ae7d0f7ff8,
+            ])),
+            Felt::new(BigInteger320([
+                0x48924f1df47fb4b1,
+                0x6d6abf9bbd515cd8,
+                0x58c6e6685ca5903c,
+                0xbe888e9b69e31364,
+                0x000001729cebba01,
+            ])),
+            Felt::new(BigInteger320([
+                0x0991ae2428caf09d,
+                0x2477ecf44788f984,
+                0x34c90575a14070c7,
+                0xe7318667e5c18fbe,
+                0x0000018788b19b70,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger320([
+                0xab4b7b1e37ffa6ea,
+                0x62096de3a6168954,
+                0x04df0153e9d7373e,
+                0x2368da90caee111f,
+                0x00000221dcd08ba4,
+            ])),
+            Felt::new(BigInteger320([
+                0x4caaefb86d173cce,
+                0x138ab4cbac9688e5,
+                0xbe1b38985e2fcc88,
+                0x511fb26848dde6fe,
+                0x0000025b981af44e,
+            ])),
+            Felt::new(BigInteger320([
+                0xe035d3bc2167b1f5,
+                0x0825c8d924f6a380,
+                0x84b191a94891e2ee,
+                0x1d9d7b9201113f14,
+                0x00000131bc723ad1,
+            ])),
+            Felt::new(BigInteger320([
+                0x241f2fd2fe7de13d,
+                0xd3db84ed85cd7d3a,
+                0x5e473e963862d733,
+                0xc77a1c18a12f46d7,
+                0x0000019bbd90ad74,
+            ])),
+            Felt::new(BigInteger320([
+                0xdbf5d52df49c7fa7,
+                0x7fa8a4b830e2b628,
+                0x06361d4cfc525a8b,
+                0x1afda7d2b6128657,
+                0x0000005e28e1c667,
+            ])),
+            Felt::new(BigInteger320([
+                0x634fbfeec7ef9974,
+                0x31e5921a5870da34,
+                0xa1729e06355222c7,
+                0xc3335cd20d3b834b,
+                0x000000a763d7d1df,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xd6cc0cc6cc045626,
+                0x349965b273a88e45,
+                0xb93e622454c41d6e,
+                0xd6b03d5c7ceb32e5,
+                0x0000039d4a4b5fb8,
+            ])),
+            Felt::new(BigInteger320([
+                0x5160e9fed54ff29b,
+                0xf7425d5397e57e9a,
+                0x29593a8fe053cbf9,
+                0x0adc5292abdf37ed,
+                0x0000007b5385f800,
+            ])),
+            Felt::new(BigInteger320([
+                0x23768207e6de9c78,
+                0x26d5fc1f19c77822,
+                0x700e01b87ea7fbc2,
+                0x855a787f8d216d8d,
+                0x00000072de205a8a,
+            ])),
+            Felt::new(BigInteger320([
+                0x22fdf1de85543cbf,
+                0x6a5dfd1ee3f7102d,
+                0x7f2a7261fe2072ea,
+                0xb7ebf175241dcfe5,
+                0x000002b711115e96,
+            ])),
+            Felt::new(BigInteger320([
+                0x7af50b1f0a221ecf,
+                0xf148e9ab329827b9,
+                0xad9fd4931cb319fb,
+                0x222bd5ab3eeb96a1,
+                0x0000014ed172e345,
+            ])),
+            Felt::new(BigInteger320([
+                0xb0d97e54c09620dc,
+                0xf808079d236eeccc,
+                0x2fcfbfb93affff4f,
+                0x18a425b8e4c7b547,
+                0x000002d4f6442133,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x4ceb112387a9333a,
+                0x71fb3bd113d70164,
+                0x0c67b28b7185d1d8,
+                0xe15c5088fd2bb205,
+                0x00000168a5ba9407,
+            ])),
+            Felt::new(BigInteger320([
+                0xe44da2ca57ec286a,
+                0x44caeb3e852cfcbc,
+                0xa18c618ac3d1510f,
+                0x3b2c268e8322ed51,
+                0x000000fe43fbbd94,
+            ])),
+            Felt::new(BigInteger320([
+                0xf2c85d15fdef2238,
+                0x0c51162e97126ae9,
+                0xca049ef0d1214a2c,
+                0xa42c95b29aa36faf,
+                0x00000167efed4f80,
+            ])),
+            Felt::new(BigInteger320([
+                0xda5f24597921f22c,
+                0xf4930635e83cb2e8,
+                0x239ae68a62530c28,
+                0x24a7a308416ab8d4,
+                0x000003860cd5e0a0,
+            ])),
+            Felt::new(BigInteger320([
+                0x358081a4d68f0425,
+                0x1e6404eac4de7acf,
+                0xf7361b2c409455dd,
+                0x8882b3107539739d,
+                0x000001701c3b3600,
+            ])),
+            Felt::new(BigInteger320([
+                0xac0b663c3f9ce552,
+                0x2d1270a0f67de3d8,
+                0x9202a91f980f9e5e,
+                0xf816cb2bd14ea43c,
+                0x00000217aa18ab29,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x8c3b4086e42d31ca,
+                0xf0daa9b6567b6a18,
+                0x81740e2fd8daa0d4,
+                0x55753cb1e99f7587,
+                0x0000037ef89432ca,
+            ])),
+            Felt::new(BigInteger320([
+                0x6042c6afc4a1eea1,
+                0x4d43720aa1b72fd1,
+                0x1ad4f288669da79f,
+                0x8cb38d716939c521,
+                0x00000337116ca66c,
+            ])),
+            Felt::new(BigInteger320([
+                0xb5c3d807f200ecef,
+                0xb449201a2558a035,
+                0x23b0303158720bab,
+                0x6c8cb0262138a62e,
+                0x0000016dd050be14,
+            ])),
+            Felt::new(BigInteger320([
+                0x42751b2462c61176,
+                0x01db4a72b31d34d9,
+                0x453317bcd6763afd,
+                0xf8fe24b5d725958d,
+                0x000000d58d61b55e,
+            ])),
+            Felt::new(BigInteger320([
+                0x10d38050d6938893,
+                0xa9eba557b272823e,
+                0x86b431796d4e412e,
+                0x9a44c1424e594414,
+                0x000002c7aa9487af,
+            ])),
+            Felt::new(BigInteger320([
+                0x908f51a8bd984f90,
+                0xc4464f56360cc72e,
+                0x91b239cf0d187a6e,
+                0xa62cee41d1cb82f7,
+                0x00000277ddb17fd9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xd18c581da46de4d7,
+                0x011cb0e9ebca940f,
+                0x79fdb49cae03390b,
+                0x6a8dc111e0b415ca,
+                0x000001bfe0523a72,
+            ])),
+            Felt::new(BigInteger320([
+                0xa0bfc06298de7cc5,
+                0xbf9a7a6e6118e2cd,
+                0x7cbf3ddac2ff83f6,
+                0x2b61dff1655346f7,
+                0x0000028b12596f52,
+            ])),
+            Felt::new(BigInteger320([
+                0xd4b989347e91e5da,
+                0x40bb6737cdfc6673,
+                0x61a9e562964a8685,
+                0xd8405aef189eaea8,
+                0x00000215ae7d3f57,
+            ])),
+            Felt::new(BigInteger320([
+                0x70c12326ea067074,
+                0xcc1e87a5a6104a87,
+                0x5deb03d1fedb5920,
+                0x5e21b2b18df85422,
+                0x000000af7eef3886,
+            ])),
+            Felt::new(BigInteger320([
+                0xe815e258538080ea,
+                0x24759e5eb1cac193,
+                0x57b45791a2fadd24,
+                0x73e805a6f9c8afa2,
+                0x000000c62cd6fe5e,
+            ])),
+            Felt::new(BigInteger320([
+                0x76bf47e780045b33,
+                0xf14a8e827365180e,
+                0x990761cd08a4c344,
+                0x93e9dffc9011b402,
+                0x000001c02be7f41d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x1b1288fe3812c90d,
+                0x6d5199eba3fc6ed8,
+                0xbf4b011197fa5337,
+                0x265feb75ed94c4c8,
+                0x0000000836a661fc,
+            ])),
+            Felt::new(BigInteger320([
+                0x7b6e81e727c48c3b,
+                0xac6c9d1e4f069ea1,
+                0x994a10d7af7677aa,
+                0x8b33bdb436d53050,
+                0x00000168c978a54d,
+            ])),
+            Felt::new(BigInteger320([
+                0x9b143afe9bc1d576,
+                0x89941844f5b06e85,
+                0x8ebcc048e05d11e9,
+                0x4b710c24e425a00b,
+                0x0000015111226279,
+            ])),
+            Felt::new(BigInteger320([
+                0x39089d3ef23ba2ca,
+                0x710ae964eac3681b,
+                0x97898a5856d66679,
+                0xa3c3a7035cad470b,
+                0x00000347696716d7,
+            ])),
+            Felt::new(BigInteger320([
+                0x8daa05820c60d1d0,
+                0x34bc03fb47b4f163,
+                0x01abe46619a2df6c,
+                0x18451c11dd7967d6,
+                0x0000009ddcc20164,
+            ])),
+            Felt::new(BigInteger320([
+                0xe5bcf33e3b1f7e33,
+                0x10ba88d0f7152a3b,
+                0x5b8458079e17372a,
+                0x31b20b2015a53918,
+                0x000000c10469e68f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x525c927371d86443,
+                0x3a0c88de2cd527ac,
+                0xdc612b336e5ab137,
+                0x09172a54a7018291,
+                0x000001a6dab6c260,
+            ])),
+            Felt::new(BigInteger320([
+                0x4e741737bba94764,
+                0x016aa1f93f38e791,
+                0x32bd25b6510ce085,
+                0xb9638fdf05aedb2e,
+                0x000002d2a5942f44,
+            ])),
+            Felt::new(BigInteger320([
+                0x403e8a7c992f1481,
+                0xeaef66e167e4a38c,
+                0x0faa093b1d394e87,
+                0xb9cbb95b35ba2aa6,
+                0x0000037fe74a11dc,
+            ])),
+            Felt::new(BigInteger320([
+                0xe2533663ab5a76d4,
+                0xbdf10c10d25a7cae,
+                0xc342a3b9a3eeaaf7,
+                0x252912de24a4057d,
+                0x000002697fd9fc70,
+            ])),
+            Felt::new(BigInteger320([
+                0xcae91f28be62dcbb,
+                0xdf7d201525134fb1,
+                0xd7b16918948bd980,
+                0x64b60ef306d47abe,
+                0x00000302e1203eb7,
+            ])),
+            Felt::new(BigInteger320([
+                0xeae81d093e45515c,
+                0xdd42fbd303295f9d,
+                0xf712fcc49c0941df,
+                0xd436e49532872b25,
+                0x000003019ce77912,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x9303bdc1c528f91d,
+                0xf8c3d1f0dd6c20f6,
+                0x2c6f02bdd36ce16b,
+                0x7b784d86b2354371,
+                0x00000280f2ae4c74,
+            ])),
+            Felt::new(BigInteger320([
+                0xdb52d443598201fd,
+                0x48a15d30d126011f,
+                0xf870a88079fdbc08,
+                0xc2e917c2730de9b6,
+                0x000000fcaaf3565d,
+            ])),
+            Felt::new(BigInteger320([
+                0xde4cc7a9d25bb6e1,
+                0x465b76daf16602c1,
+                0xb7dd59b4b2af3df5,
+                0xf19a6200212771ef,
+                0x000001428ee85b82,
+            ])),
+            Felt::new(BigInteger320([
+                0xbd22e1b4f7a05a73,
+                0x36edceb55c6e8679,
+                0xde6e20edb20a1d9b,
+                0xd2b4a0dff6318670,
+                0x000002dbd1fc5949,
+            ])),
+            Felt::new(BigInteger320([
+                0xd8a809789076a55f,
+                0x5b0cbe328df3edac,
+                0xf9aa331180cd70dc,
+                0xf30c58c88a9c43a0,
+                0x000002ca419f13af,
+            ])),
+            Felt::new(BigInteger320([
+                0xf01b92d91afadad4,
+                0x2043280f1567f06d,
+                0x08d0d88a6bf160e9,
+                0x261c2d33cf8f98e8,
+                0x0000017bbc567fe0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x983e5c6e31792fed,
+                0x1292d9425c4f329d,
+                0xfc33f42651b573f3,
+                0x5a4628b83b1f7089,
+                0x000000af271d11f9,
+            ])),
+            Felt::new(BigInteger320([
+                0xad5886028c2f1fda,
+                0x105fe7bd856f114c,
+                0x4ef617081dc58f2b,
+                0xcaca376ca0a7cad5,
+                0x00000165acc1eccc,
+            ])),
+            Felt::new(BigInteger320([
+                0x189b9d6d5c399881,
+                0x0ea68c527e74b4ab,
+                0xf377d41dde8dce20,
+                0x11cb05203b00fdaf,
+                0x0000032c79199ab2,
+            ])),
+            Felt::new(BigInteger320([
+                0x465cf87ebe012848,
+                0xaa7d34e258df7edf,
+                0xf61a640cd72e0e71,
+                0x6238bf61783ed330,
+                0x000001da6ffdd25d,
+            ])),
+            Felt::new(BigInteger320([
+                0xeea76a1c22035724,
+                0x3b25deb74a92ad9c,
+                0xd998060cee2869e1,
+                0x72d8be79669b7223,
+                0x0000002ccb2905da,
+            ])),
+            Felt::new(BigInteger320([
+                0xc15f34accc875563,
+                0xd72bfa39387d58e1,
+                0x8730bc8c94256d0b,
+                0x0bf38f88a83a960a,
+                0x0000004adaa5faa9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x59b3f17ec5a432a0,
+                0x4a7e701912b7eea5,
+                0xea372f3c62c98903,
+                0x670f8de65e05e312,
+                0x00000395228f83c8,
+            ])),
+            Felt::new(BigInteger320([
+                0x60e79f3b3f694977,
+                0x9843e7ca5b915200,
+                0x92f3ac380efe863c,
+                0xaeb5f69e1efa41d5,
+                0x000001d51238552c,
+            ])),
+            Felt::new(BigInteger320([
+                0x11792266987c96c9,
+                0xc3b9e2f25c87a669,
+                0x35d98863935225fe,
+                0x9cab957baebc1f1b,
+                0x000003936ac36c1e,
+            ])),
+            Felt::new(BigInteger320([
+                0xd53bd9ffa6f81cd0,
+                0x2b1ea5bc4a54edf4,
+                0xe56367db6753566c,
+                0xfd16d7d2da2cb660,
+                0x00000211050013a3,
+            ])),
+            Felt::new(BigInteger320([
+                0xcea55f68f844f921,
+                0x998c2568a6f315ea,
+                0xb0a62010711aae66,
+                0x23def3baa695db17,
+                0x00000213272e8e2b,
+            ])),
+            Felt::new(BigInteger320([
+                0xfec219a4852d5cab,
+                0x38c5b5a0e742e524,
+                0x02cbf344f135e5bf,
+                0xc11cc2b4326eaafe,
+                0x0000037697ecf666,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x2d90b94a32bfd724,
+                0xf3f2b943b167862d,
+                0x533b2ea9e256eff6,
+                0x70083032ab5a704a,
+                0x000000484968b944,
+            ])),
+            Felt::new(BigInteger320([
+                0xcb6cfa3022c4a387,
+                0xee990748a0d21b9c,
+                0x402dacad12d1e17d,
+                0x9e19cdf675b7972e,
+                0x000002683723d492,
+            ])),
+            Felt::new(BigInteger320([
+                0x232172c90599456b,
+                0x77e3b7d740602f89,
+                0x52ad552a45dfdea5,
+                0xe273731d37bd7513,
+                0x00000333ec3997a8,
+            ])),
+            Felt::new(BigInteger320([
+                0xc83707f2cafe14f1,
+                0xe475016c8f462ea0,
+                0x8e9d75b4745fe5cc,
+                0xc4d2a821d63d09a8,
+                0x000001e42f45abad,
+            ])),
+            Felt::new(BigInteger320([
+                0x29c217ea82131c03,
+                0x90e8630753c22e84,
+                0x795f4be5cb74f553,
+                0xd08510d1a773cc5e,
+                0x000002f14cc10271,
+            ])),
+            Felt::new(BigInteger320([
+                0x9d3a4e360817a638,
+                0x84a01e2367d04bfc,
+                0x881cb1577983f2e2,
+                0x176beb04a1429863,
+                0x0000017bdc4d0be4,
+            ])),
+            Felt::new(BigInteger320([
+                0x1b3aedb87c64a2e7,
+                0xd6ec6389ebd8c253,
+                0x821ae9d271988218,
+                0x2c9434a762ee0d89,
+                0x00000384e83ec9a4,
+            ])),
+            Felt::new(BigInteger320([
+                0x40a6c1de44d8ad7a,
+                0x637c9ee147a870df,
+                0xc468375c3fbdffe0,
+                0x6bc19470255039e7,
+                0x00000058e3223f5a,
+            ])),
+            Felt::new(BigInteger320([
+                0x7554a6e6da3cb496,
+                0x4a3b5b9c792033a8,
+                0xf89cfabcab18a946,
+                0x07a33038eb8ddcdb,
+                0x000002b5bc579187,
+            ])),
+            Felt::new(BigInteger320([
+                0xc672918930f8f99c,
+                0xbc56aa653697f8c8,
+                0x7bbe35266fe6e907,
+                0x389b310cd1de2dd1,
+                0x00000213ae563725,
+            ])),
+            Felt::new(BigInteger320([
+                0x6fba2b07d9c2b991,
+                0x54d3cd264516ca36,
+                0xf7e1cd9f538b5f36,
+                0x3ea2107fcd5f3b20,
+                0x000000e03a74f7cf,
+            ])),
+            Felt::new(BigInteger320([
+                0x92c74fe6bc2926a7,
+                0xca2118a328acde59,
+                0xac1fb48a51eb9668,
+                0x0128f010a502c691,
+                0x000000b47ac822e1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x663be84552f81c94,
+                0x28a47be6be835859,
+                0xbcf89ce54bf9f756,
+                0xe1de8e481bd3cf6a,
+                0x00000144cfc34cfa,
+            ])),
+            Felt::new(BigInteger320([
+                0xa5a0531e4f71e153,
+                0xede060a54f634080,
+                0x9902ecbea54bf326,
+                0x71b8d02484618b45,
+                0x00000232cfe6ace6,
+            ])),
+            Felt::new(BigInteger320([
+                0x4677969fefe52cb7,
+                0x6a93f6d504bafcef,
+                0xa91a5aa0dcfee3ba,
+                0x92dca73a203a6fd9,
+                0x00000235536fec48,
+            ])),
+            Felt::new(BigInteger320([
+                0xcce946f52b1de552,
+                0x0a3c10c929c48776,
+                0xdf302d994c4ce620,
+                0xe472437dc13b2b55,
+                0x0000012b2766db4c,
+            ])),
+            Felt::new(BigInteger320([
+                0x0e2f04ff3ba3d9a3,
+                0x34375ffd219d0a23,
+                0x0a95312a5e8021b4,
+                0xa9d70be03c9d75f0,
+                0x00000062422aceb0,
+            ])),
+            Felt::new(BigInteger320([
+                0x5acc4334006a19b5,
+                0xd5a7210032ce5178,
+                0xe4fbc010c51721e8,
+                0x80b53242ed31b107,
+                0x000002cfec67e004,
+            ])),
+            Felt::new(BigInteger320([
+                0x046d14b5cc42c930,
+                0xfd965a8f110d8b15,
+                0x67686aea59a83e32,
+                0x0905b8f5f12a2349,
+                0x000000e268a54972,
+            ])),
+            Felt::new(BigInteger320([
+                0x13c34f9b5bbe5331,
+                0x477a03d2a275cc1b,
+                0xa885a49a3223a628,
+                0x48422d378b48ded8,
+                0x000000f30877649a,
+            ])),
+            Felt::new(BigInteger320([
+                0xb3452c045eb2ad5e,
+                0x0b99a1b25449f1d6,
+                0xc7bf6f7f31aeee27,
+                0x828acbab30797557,
+                0x00000121eda613a9,
+            ])),
+            Felt::new(BigInteger320([
+                0x56df1819cec9da6c,
+                0xec90a713a649fc92,
+                0x3b34f0b42909015f,
+                0x75a69520aa466d5e,
+                0x000003776a103a49,
+            ])),
+            Felt::new(BigInteger320([
+                0xbd9e69769d6561e5,
+                0x1ed60fa35d530402,
+                0x6d797dd27f2a0bfe,
+                0xb3cceb681d7e9bf3,
+                0x0000039830c35bc4,
+            ])),
+            Felt::new(BigInteger320([
+                0x5fab7b09a7d0f1f3,
+                0xf18acfd0ba0a5cdb,
+                0x2e756c42e3c69eed,
+                0x48fed075fbc7a056,
+                0x0000013290434abd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x8d3161bf884cd20b,
+                0x6a5dcf9228fe4dae,
+                0xf69056a6325b7beb,
+                0x52ea90f982e18665,
+                0x000001ac5d1d0cd7,
+            ])),
+            Felt::new(BigInteger320([
+                0xfe761a3555f64daa,
+                0x78306f0c23b912a5,
+                0x502fb04806d36704,
+                0x3fc775388353bf6d,
+                0x00000274b9a1e882,
+            ])),
+            Felt::new(BigInteger320([
+                0xcc1123bcb9f85a81,
+                0x03495a0ea27f1bce,
+                0xc78ed37ba050c1d5,
+                0xaae020e4f92576dc,
+                0x0000003a8eba43f9,
+            ])),
+            Felt::new(BigInteger320([
+                0x4565a3c482abd0dc,
+                0x926c54fb9905e056,
+                0x20d1b3c277d2ca89,
+                0x9e810a8be82895a4,
+                0x0000039f5a8f4290,
+            ])),
+            Felt::new(BigInteger320([
+                0xad18d0bafaaab72a,
+                0x05bebad957694941,
+                0x36854b5a320de249,
+                0xd79f8dba1dd10898,
+                0x000003291fc3c853,
+            ])),
+            Felt::new(BigInteger320([
+                0xfef3bcb927f559c6,
+                0x36795ce83abfe1e7,
+                0xca9581a74c761ddb,
+                0x537f111c44ca764a,
+                0x000000e854beb134,
+            ])),
+            Felt::new(BigInteger320([
+                0x34bebfe697a4f43d,
+                0xd81d5df22f2f0b57,
+                0x0d0e45b387e71fb4,
+                0xcc1f293cbe09ed26,
+                0x000001c0456a934a,
+            ])),
+            Felt::new(BigInteger320([
+                0x07d151f149f6532d,
+                0xd1399b316248b631,
+                0x7063fcfece61979a,
+                0x2fd6f04dc25f6bfe,
+                0x0000024fb8b65049,
+            ])),
+            Felt::new(BigInteger320([
+                0xc83afd60093566fe,
+                0xda241fa17f70e08b,
+                0xfce3b6267f52c11b,
+                0xa6b0eb8b9f7546c2,
+                0x000000b908846d78,
+            ])),
+            Felt::new(BigInteger320([
+                0xd96d72bf654ab862,
+                0x76c373daac4653bb,
+                0x1bd7a84f92ddde36,
+                0x3d2ea77c17de97bd,
+                0x0000017171ef3fc5,
+            ])),
+            Felt::new(BigInteger320([
+                0xad1ac597cef06f5c,
+                0x6d664ea0b34d20d7,
+                0xa6daf95e731c9aee,
+                0x1e42751bf6ef07f5,
+                0x0000022ff6be4b2e,
+            ])),
+            Felt::new(BigInteger320([
+                0xe6a174f4b4976b24,
+                0x3b0a4af661f876f3,
+                0x6def842364426028,
+                0x9371027f4a34ad60,
+                0x00000087faca52ef,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xfded178f1ce6a9f3,
+                0x0d068fee42713631,
+                0x7a94c1736552eb08,
+                0x62d5f42ee210c313,
+                0x0000012f3ecbf139,
+            ])),
+            Felt::new(BigInteger320([
+                0xf7f17b81d62bb653,
+                0xfedfb723961bdecd,
+                0xe53d4cad5f8247b5,
+                0x0cb616c3510ddda8,
+                0x000001125b58dd0f,
+            ])),
+            Felt::new(BigInteger320([
+                0x60b656abd7a1c3bd,
+                0x8833c2326b7d769a,
+                0x3365a06dbe593d9d,
+                0x61cd027ad57181d0,
+                0x0000039fd084cd12,
+            ])),
+            Felt::new(BigInteger320([
+                0xa75311bffee92ef1,
+                0x63312d8e84eb8b9a,
+                0xc0c8d474572a9f22,
+                0xf1526dc92c732816,
+                0x000003935878e7f2,
+            ])),
+            Felt::new(BigInteger320([
+                0x9d1e0c4a716eba79,
+                0xc6ba154d04ce858d,
+                0x07f9d7932a15b22d,
+                0x314d02916b2efffb,
+                0x0000034b3f0bdd20,
+            ])),
+            Felt::new(BigInteger320([
+                0x1478d0ee0b988f64,
+                0x2c6911697afe4b5c,
+                0xe47484bcdb882918,
+                0x282232b9b8f411c3,
+                0x000002e875295432,
+            ])),
+            Felt::new(BigInteger320([
+                0xb0f9c96b16a0ff7c,
+                0x17dd9f92b587c276,
+                0x1282af7721a9d1e0,
+                0x073f190c10a7b2d4,
+                0x0000015b8e2be54f,
+            ])),
+            Felt::new(BigInteger320([
+                0x5f6d5d41393ed5af,
+                0x864fed66a43101da,
+                0x4dc0c6b5bd0ce4e1,
+                0x62233822c3b7774a,
+                0x000001ab9ad2018c,
+            ])),
+            Felt::new(BigInteger320([
+                0x1b1e317704719065,
+                0xba010238ceb303fa,
+                0x4dd290fa8b669a93,
+                0x55485e4fb4333e14,
+                0x00000218e9ff91cb,
+            ])),
+            Felt::new(BigInteger320([
+                0xd36a370a3581a45c,
+                0x60e375ac18b477a0,
+                0x36b7908579c6fe46,
+                0x481c0ae8c23e2fe3,
+                0x000002fffdd0dc41,
+            ])),
+            Felt::new(BigInteger320([
+                0xf2a4876f5723022e,
+                0x878a199687d6c207,
+                0xdf88d1e56c4d4b16,
+                0xef3bd201af0b5b1e,
+                0x0000025785ce7f22,
+            ])),
+            Felt::new(BigInteger320([
+                0xec7f360f2acd8fa4,
+                0x4f95ff9a4daf85e2,
+                0x894193f5935d026f,
+                0x11fe017e054315cf,
+                0x0000036602b19be0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xc7d9bd617ee507fb,
+                0x73b98cfa16c3b61a,
+                0x5dda69abaa5baf2f,
+                0x8b2fc1a9c4952c3f,
+                0x0000037e353ee55b,
+            ])),
+            Felt::new(BigInteger320([
+                0x7f9cd13f425fb66f,
+                0x5609790b8ba65f77,
+                0xcc0d7625fcf49ac9,
+                0x755962e54359a727,
+                0x000000fa642995a4,
+            ])),
+            Felt::new(BigInteger320([
+                0x3471718ed9f80410,
+                0x089790958f2de24f,
+                0x8dd4e5045b065a6c,
+                0xe9da48591d628a9b,
+                0x0000013ff620710a,
+            ])),
+            Felt::new(BigInteger320([
+                0xafd8e8f18c4851f0,
+                0x46175c2ddabb4124,
+                0xc03f37901ce82651,
+                0x080e738a1a22935b,
+                0x000002763a2c4c64,
+            ])),
+            Felt::new(BigInteger320([
+                0xd3dcc6f5ed121620,
+                0x0c590cb4d9102499,
+                0x70975f9a9272c08e,
+                0xb3577f7f54b0fca6,
+                0x0000033a8e750511,
+            ])),
+            Felt::new(BigInteger320([
+                0x03117d81e0f7d360,
+                0x14354b7d9b814e6d,
+                0x976aed24b3137945,
+                0x905c78cc96e08163,
+                0x000000d435b03087,
+            ])),
+            Felt::new(BigInteger320([
+                0x5ff208d41b639c1a,
+                0xd44c63795045995f,
+                0x911a663349755d34,
+                0xeff4ef9f495a4105,
+                0x00000062c05cf2d2,
+            ])),
+            Felt::new(BigInteger320([
+                0x06b6b2d2559d2720,
+                0xb8b2164616a6dcab,
+                0x354f2e3007be2521,
+                0xc03b210e2f237dd0,
+                0x000002a1a3754f49,
+            ])),
+            Felt::new(BigInteger320([
+                0xb6633af9152024d5,
+                0xa4b90895641e8659,
+                0x8e2755edf0559f74,
+                0x94fc1b5beef3214c,
+                0x000003319004ea5f,
+            ])),
+            Felt::new(BigInteger320([
+                0xcd620011cd4a79dd,
+                0x7537a6a976d270eb,
+                0xbf9f001821398244,
+                0x7e0744c3942a00ff,
+                0x000003a8cf6c2ae9,
+            ])),
+            Felt::new(BigInteger320([
+                0x909d7c9bb63b693b,
+                0x8b667cb57f8f7a5a,
+                0xfc52fbb9e4fbd4d0,
+                0xc39261bc58b61158,
+                0x0000020dcc84d3b0,
+            ])),
+            Felt::new(BigInteger320([
+                0x0d59e84c2b7d37eb,
+                0x83fd823f819cfb88,
+                0x2a52f38fe6ad7234,
+                0xb86d5d29e79b4131,
+                0x0000035bfeed29fd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x7149a08c8c084e00,
+                0x8879740d2c790023,
+                0xfa5c283a3c58b8d6,
+                0xa8887272b31c747f,
+                0x0000001ac807e562,
+            ])),
+            Felt::new(BigInteger320([
+                0x795a29787263335d,
+                0xe5bbe81a5484c64e,
+                0x72cc219e1c2b5fd6,
+                0x5b189738fe5032d4,
+                0x000001d2d9dc1101,
+            ])),
+            Felt::new(BigInteger320([
+                0xc93e0b28e17fd9a4,
+                0x6088ddc7dfaf7741,
+                0x72f77fa1c86de238,
+                0x2fee6a5f90a176a1,
+                0x000001a1be934c8d,
+            ])),
+            Felt::new(BigInteger320([
+                0xf485842d7b5c70aa,
+                0x6f92c3d6e9d13540,
+                0x05ff29a2c3597594,
+                0xbd0918ed45b347c0,
+                0x0000009228a2ab73,
+            ])),
+            Felt::new(BigInteger320([
+                0x7840d0bc62d41da1,
+                0xc70a768492eaa8c2,
+                0xd6bcb1e3ea7cfe8f,
+                0x979788d9953195db,
+                0x00000362d05cc126,
+            ])),
+            Felt::new(BigInteger320([
+                0xd6193692aea21631,
+                0x1416803c2d00ee7f,
+                0x8d9b6161e4060b89,
+                0x5f4a4301fcea1ca9,
+                0x0000009fbc41f157,
+            ])),
+            Felt::new(BigInteger320([
+                0xebd4b91c31bed5cf,
+                0x9905a2bdd4e2c75f,
+                0xede3411edf32aa8f,
+                0x463b79c9da66f520,
+                0x0000011b528b59ce,
+            ])),
+            Felt::new(BigInteger320([
+                0x818cb46f92fdea05,
+                0x96828859f989bf9d,
+                0xe6e17fd0dfb7694a,
+                0x1455ca7523176c73,
+                0x000003112ea7b9ed,
+            ])),
+            Felt::new(BigInteger320([
+                0x945a2866664b34ec,
+                0xa5f476c850a4ce3f,
+                0x64263a4e167ea45b,
+                0xe6a5bdecad522948,
+                0x0000015f950c06c6,
+            ])),
+            Felt::new(BigInteger320([
+                0xc8132e4f7f0e8ec5,
+                0x37aeba381759e6e0,
+                0x51e0d138be9cafa3,
+                0xe460226761dbc90e,
+                0x00000272c05044d6,
+            ])),
+            Felt::new(BigInteger320([
+                0x72f0508863fd2330,
+                0xc9693b6df0e49b1f,
+                0x6cc65573c7213a5b,
+                0x4e6289d1c9fc99d3,
+                0x000002bc0e001814,
+            ])),
+            Felt::new(BigInteger320([
+                0xdf3c184b1f9618c2,
+                0x861e7e668ae67a3b,
+                0x679ae4edeb41683c,
+                0xac3debe2feb93a68,
+                0x00000077ac729ca2,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger320([
+                0x677724084e03d886,
+                0xe9d7db74fbefe2fe,
+                0x8fc6b04a2ebb74b7,
+                0x5c03fdf58211d68a,
+                0x000003b1c2248cdc,
+            ])),
+            Felt::new(BigInteger320([
+                0x0b0d091fc21eb77e,
+                0xd7a1e87539b4d241,
+                0xf2e64bd16ed19598,
+                0x396706b54f985c92,
+                0x000000e1c1c69f2f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xac2ac3934b9f116c,
+                0x0b0e681e6ee8200e,
+                0x0bfd6f0c930c0242,
+                0xdbd066e9a147e286,
+                0x000001a20221c914,
+            ])),
+            Felt::new(BigInteger320([
+                0x5c2b83d7a9d45035,
+                0x17fe7eb14e2b6d81,
+                0x0d64a347bc610d4a,
+                0x390645230d14688b,
+                0x0000024a631ea356,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xac271983eac15996,
+                0x5b06738c1ea7d90a,
+                0x02b3a345262840f7,
+                0x6ba574ae655840c4,
+                0x00000083ba264515,
+            ])),
+            Felt::new(BigInteger320([
+                0xa1ab57059f44ffe7,
+                0x24c67eb712c7856b,
+                0x8c3b27d16120caac,
+                0xb5847024ee2bf5d3,
+                0x000002df032d74ea,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xc0b8ec2ac9f5a74a,
+                0xcbbba86b8c067066,
+                0x95fadd13e4748bda,
+                0x177a64df09d0b6ac,
+                0x0000003a83ffcfa7,
+            ])),
+            Felt::new(BigInteger320([
+                0x6a3a5d22739a4fa6,
+                0xd1bb287539c11dc6,
+                0x26cb7ab0ed192c20,
+                0x89787bcb6a7a8917,
+                0x000002c784c30731,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x1c8c0b0c28e59c2c,
+                0xb6aadbfa1f68d381,
+                0x60e21752b5b77c22,
+                0xc91a5a1fd48828df,
+                0x0000007b7414f2ce,
+            ])),
+            Felt::new(BigInteger320([
+                0xd6187b2772de389a,
+                0x4f990f1bd5dd838b,
+                0x54ed7b5851653973,
+                0xe4d8ce7222e4a839,
+                0x0000016c14b77b66,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x346adeeb6cabe10e,
+                0x25a3640acfdc7e7c,
+                0x7f91fd1506372d3c,
+                0xad80d94e92a82217,
+                0x0000027941e8ddb2,
+            ])),
+            Felt::new(BigInteger320([
+                0x7074b60360738be0,
+                0x8ad9f69fb88ea4dd,
+                0x19e5edc07a36908e,
+                0x7a1fee10bfd8f340,
+                0x000000ff93fbec7a,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x9c2bf8b7a8b5e477,
+                0x9abbf4c7a575bc60,
+                0x55236bdfbc94a0ef,
+                0x810475e18abce071,
+                0x0000011a45e560bb,
+            ])),
+            Felt::new(BigInteger320([
+                0xbbdf92288ce60cd3,
+                0x95e8c2827ff11a3a,
+                0x7ad0ae148a78c93e,
+                0x847d1b1f4cd41e8a,
+                0x0000036ed7bf1c20,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xa8d8f1a0e48155ca,
+                0x8c28e1c4ee71ce42,
+                0x2b2494c740c025ce,
+                0xf109bd199f868151,
+                0x0000034e17ef51c2,
+            ])),
+            Felt::new(BigInteger320([
+                0xe37adeb02d86c8e1,
+                0x1a613a0a63289845,
+                0xddf03071c60e1ccc,
+                0x4274b5c7962b8283,
+                0x00000249ba95fd9b,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x951807f76963d43b,
+                0x2aeeacaa3debe009,
+                0xdf11a04ea83c6b61,
+                0xc556f5f1c13225db,
+                0x00000154c8dae089,
+            ])),
+            Felt::new(BigInteger320([
+                0xe20fee3adfcfb0d9,
+                0x4ebbf5ba00cbd0b7,
+                0xc9354632a3cba23b,
+                0x255a8afbe1977d92,
+                0x0000034dd98f4c16,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xca8b9bebec2c2784,
+                0x78eea3c903180248,
+                0xf7cb849bbf89f7a8,
+                0xf7965bb2c6bcddf3,
+                0x000000671aec66bf,
+            ])),
+            Felt::new(BigInteger320([
+                0xcc240d6b2540c854,
+                0x192a02bb50dd1fbe,
+                0x689a7cb3751ac955,
+                0x8bcc4c55f14c3676,
+                0x000003244643e7ca,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger320([
+                0xfadbd4918df2b52b,
+                0x129f8f1b88bcf4c4,
+                0x5aed0bb54114cdb0,
+                0x7cadb5e194eacbf9,
+                0x0000036f7809be9b,
+            ])),
+            Felt::new(BigInteger320([
+                0xc8ca58e0595e7b0e,
+                0x5e7ca733433ed0cd,
+                0xea2bc531e8e9b8b0,
+                0x26801c7b8e24201f,
+                0x0000019599500724,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x7e636226902f9449,
+                0xfdc1aa42ce8e5634,
+                0xc8c17efb06cdce0c,
+                0xb95ab6ac9ccd72ce,
+                0x000002a5df7667b8,
+            ])),
+            Felt::new(BigInteger320([
+                0x054f9a4dccc31114,
+                0xd3edd2b7f5fe9acc,
+                0x7828f30137d85c66,
+                0xe5d4dcd09a899dbc,
+                0x0000016ab4207b03,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x0dfb91b66d60fc4e,
+                0x4187bf034d375d23,
+                0x6c550ee218239549,
+                0x2f72811af3a0bffa,
+                0x0000023dd9352995,
+            ])),
+            Felt::new(BigInteger320([
+                0x4748e086e276b73a,
+                0x50bb979b13323510,
+                0xa3836c8b56450205,
+                0xfa951c4f478e1b2f,
+                0x00000325a9bf7e9e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x8e4f9e225e9da2ef,
+                0x0d54704af2339e7b,
+                0x819e31f753cf1ac4,
+                0x4080bd7913485f3e,
+                0x0000026ec0c6de93,
+            ])),
+            Felt::new(BigInteger320([
+                0xcd76fd45778fc832,
+                0xac31339dbbe43fa1,
+                0x436f9d8463dc715f,
+                0xa26d03195e8d166e,
+                0x0000033e7cedaa6a,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x8b925581cf46ef66,
+            0xa3d4520e06db760c,
+            0xffcdacd580b8d295,
+            0xd1f50ce537a28348,
+            0x000003782ea3d48d,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x0eb52e09e1b20e3f,
+            0x5b7c71134f6be5d6,
+            0x237309bb425e31b0,
+            0xedbe5bfff291d101,
+            0x0000000b5a9345e6,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xf093dccc43db0216,
+            0xf6d3544172405754,
+            0x2be158d7363f00f1,
+            0x9b1d9a56aa420f70,
+            0x000002d1b423bfe9,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xe9ecd9dad24cd796,
+            0x4688b397275f8f69,
+            0x0821334b593bffec,
+            0x57491ad86572c168,
+            0x000001775e66a121,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xadb1726d46f73d3b,
+            0x55f452afaecd1940,
+            0xbda35cf83ddbba9e,
+            0x5d3b410964f50bc1,
+            0x000000b9d575bf73,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x86cd8b1e9c1f1873,
+            0xb6532ced7a9866f5,
+            0x986138419600de4e,
+            0xa341382f456d583f,
+            0x0000025c6fe2bd71,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x4f3c889789f9f14c,
+            0xa04d5921d2d9c7b3,
+            0xe8b6a0ca04efb455,
+            0x17d2aa08ceac979b,
+            0x00000024648d706e,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xaf72f311c35271a7,
+            0x56604415a85d01ef,
+            0x0a46de8ce586dd5b,
+            0x20d7bdcbaaa145e5,
+            0x000002f1506ab6be,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,2790 @@
+use super::{sbox, BigInteger320, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 456 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (38-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9dc696453cf332ce,
+                    0xdba4f83fb0698751,
+                    0xc2c2cd56cca7ef4b,
+                    0xb36a868ab7080d60,
+                    0x000001d98da13b99,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb35dfb7a0b276f2,
+                    0x4f3b782d1e9f329f,
+                    0x32af203abe1c51bf,
+                    0x3f4003f7433388b1,
+                    0x00000265b75c7031,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5b986196798c0e5,
+                    0x5062f448db8d1472,
+                    0x5f3d63d9e815bd8f,
+                    0x0ebe69c4d5f7d841,
+                    0x000002c29995d109,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa16c675ed13ee521,
+                    0x0577134e8aa47804,
+                    0xc85bff3291e8f9d0,
+                    0xef016a0ad2f7da50,
+                    0x00000258b5b7134d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28196acd999a6ce8,
+                    0xe1acacb34033e783,
+                    0x20045461b04a0add,
+                    0x3ec3202842238e57,
+                    0x000003b4d646bf2f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2409e8fec9a03cd,
+                    0xff513d847bfdb23d,
+                    0x017cb5bcc761e23d,
+                    0xc0c4ed222ae0d9c2,
+                    0x0000029a04fbb0ac,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f9102e6d8f88363,
+                    0x0fc7c8dfc8619ff4,
+                    0x94ab3a221cd5d487,
+                    0x3450aa6302ecdd94,
+                    0x00000332d8e6f800,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa151b42e4a38c939,
+                    0xd75d0ed7760a61c3,
+                    0x9bffc613b0d19c32,
+                    0x5a6169d6da486349,
+                    0x000001c88b648193,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b0d9f473d7e5dbd,
+                    0x40a9ae80396ec205,
+                    0xa9631fb988da8fbf,
+                    0xcd5a06e8cf88c383,
+                    0x0000012274b410e7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c10761ec92a6fdc,
+                    0xf86662f6969d15ad,
+                    0x29f09292fc749b75,
+                    0x245368cb546cf5b2,
+                    0x000000128c9cbd1b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1befc2141917994d,
+                    0x478b0454230a6e48,
+                    0x79187dc90e9996d1,
+                    0xe9ee7051bf6fcd4d,
+                    0x0000007e40d9336e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaaf93ffe76d72519,
+                    0x2b02a3e2f763d6f7,
+                    0xfd5774f7670b83e9,
+                    0xb9e76b341b060d32,
+                    0x0000005576a7b8de,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb2e1a020e6136353,
+                    0x02b6914725bdca22,
+                    0x91d4d0f8c7cdef09,
+                    0x4f1f35eaeab46351,
+                    0x000002a3bc98db05,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26b77e519e697cf0,
+                    0x28bb30da9ece0e38,
+                    0x21b69b99d1ea21ab,
+                    0xdcef620635e86bb6,
+                    0x000001a4af2342d8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc901f8562bf60ba4,
+                    0xdbe1eb808f14011c,
+                    0x95ffcd31ab9ad36d,
+                    0x3491f0d068bd91cb,
+                    0x000000190044e5de,
+                ])),
+                Felt::new(BigInteger320([
+                    0x892c1849b377fa56,
+                    0xb8f9c6143be4b5bb,
+                    0xa8f9dc9bcd62ce4e,
+                    0xc794f3d4a36ef039,
+                    0x0000029841bcf6c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f282e1a2423bcba,
+                    0x4dc68f89d5c2df7c,
+                    0x588236a62a548743,
+                    0x6b34809e7f4dbb51,
+                    0x000002570d3c44c8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae7b702b76d55596,
+                    0xe35df910ff04e7d9,
+                    0x722f4c0b5803f483,
+                    0xe2ea82d96c5ccec6,
+                    0x000001d6bd6b4686,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9beacd13c31ddaeb,
+                    0xf4efec90a369ae0a,
+                    0xd2341cb474f11e74,
+                    0x7d360910ecad8e8a,
+                    0x000000625edaa7a5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c28fca328297063,
+                    0x6a811c70a0cd000e,
+                    0x2200a38bd5060f5f,
+                    0x6f88775cde8ec5cc,
+                    0x000001016d7b2913,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31afccff901a512c,
+                    0x09d7d5ffb403b3c9,
+                    0xc35916622c90556a,
+                    0x592d22a8da7bffdb,
+                    0x000001240ebe029c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4c41075d589ca1e,
+                    0x165b78cc7bf056bc,
+                    0x8aaeba320ad8d1ca,
+                    0x427eb7ee3438858e,
+                    0x00000304c5fe84e0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26e57582617b1aa9,
+                    0xa1db4a83436b7d76,
+                    0x8640419b6c49ef60,
+                    0x2b37b1d84033201e,
+                    0x000002b44a9d49ee,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5471028d831a5d65,
+                    0x66a275864e894c81,
+                    0xa8a354d4fe3cba3e,
+                    0x907d4f16ae6cf32a,
+                    0x0000007bc1497836,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x85896d8c8dae5e71,
+                    0xe0d58d3ca0def74b,
+                    0x4789c4036de4a1dc,
+                    0x199603e732a95228,
+                    0x000002e69c45c9c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60163bb3e20b24d9,
+                    0xc07fe2190df32b29,
+                    0x10ce7b9480c99bbd,
+                    0x23686e506856c6f6,
+                    0x000002e7d3a9d1f6,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe8e9bbed0cc58741,
+                    0xb1e1d3e339c78527,
+                    0x7b89a69ddfebb26e,
+                    0x601aca989d08eaac,
+                    0x00000035b151db28,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd45e53771dbdbcf1,
+                    0xdb80952836665183,
+                    0x18c5c22c4e28f6ad,
+                    0x9a4a586da91d05b2,
+                    0x0000024bf5199af3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5496014bd3167bef,
+                    0x3873c499f8f9cc84,
+                    0x24408cd0d619c21b,
+                    0x8c0120088c87f0b6,
+                    0x000002142ea2d1de,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa04ee66f5bbeed60,
+                    0xcfdd73169aaf2545,
+                    0xbeb5bb3c60d9dbd6,
+                    0xc641e60c82d2051e,
+                    0x000002aca6756de5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfb84174d5ae8484,
+                    0x4b5878687228e4f8,
+                    0xd0d6f549aa61b547,
+                    0x507db1c8794ee346,
+                    0x0000023388564cc4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7fcb926bf34847ca,
+                    0x16b87ce708415059,
+                    0x4991db37e4219bf0,
+                    0x0d8fc481335ae839,
+                    0x000000047c9e91d9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5aa04e6f96c23a9c,
+                    0x1b78b8fc2d05c8f1,
+                    0xef181a2c3f4dffa0,
+                    0x93d81ed3f1274553,
+                    0x000000a8bb15b489,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14f691c152e4e788,
+                    0x3bf3202cff0586c7,
+                    0xc1c10f24a1a4ac7a,
+                    0x22db2264e2e85669,
+                    0x000003add7643545,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9ea6536be6e68ed,
+                    0x6147f1b9f2506602,
+                    0xc951416d912b672e,
+                    0x3d1896e970d65adf,
+                    0x00000053a5dee533,
+                ])),
+                Felt::new(BigInteger320([
+                    0x607c659e44b0f720,
+                    0x9b38cdf941754e9e,
+                    0x8be97fa0f6f0d3eb,
+                    0x2c9386445ceb0ae8,
+                    0x000001ee6bc73504,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5ce067cf7fd05bf7,
+                    0x72d8a79b78b1467e,
+                    0xa5b02fdca987a294,
+                    0x3246cd123bb61560,
+                    0x000000f531ada0f7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46734507201d26b9,
+                    0x2d64e1d1851d2b8a,
+                    0xeef5042c091537ae,
+                    0x6f6bb230d1583f8c,
+                    0x000002506548e1e6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5828b2ad9b6d2c78,
+                    0x81bf79d01d3f1379,
+                    0x45a330e33bd297f0,
+                    0xc2303c4716fc92a4,
+                    0x000002ae5010fe2a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde707753f40649bd,
+                    0xe366db4503472052,
+                    0x28b265425cbcb6ad,
+                    0x583e945e79494a04,
+                    0x000003b7590d4047,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93b3f6085a4554bd,
+                    0xb8f1000e5f9645ac,
+                    0xb75721c10e0b19fb,
+                    0x03e55f504715ab4a,
+                    0x000000d201213258,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e4b48a9ebc0c5de,
+                    0x5f4a9b8fee71abbc,
+                    0x645714f53cc38750,
+                    0xd0bbafa505132c60,
+                    0x000003284bf4a245,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4bd2521c0cdcfae9,
+                    0xfa3759f06b1d546f,
+                    0xd48a9a9b8a64e2d8,
+                    0x90984a17854392bf,
+                    0x000002c7c88c0277,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4679e5c76f8848fd,
+                    0x1ac6889e81e7c18b,
+                    0x64e7517539c3a1b4,
+                    0xaf460000392c1e28,
+                    0x0000028e13de892b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b80cc1abf5ab35a,
+                    0x0938aa85bf839a43,
+                    0x03872165dab3531f,
+                    0x9de8182a4243d7f6,
+                    0x0000015d8aeb2e4a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3cf58f6b60d8037c,
+                    0x1f9435cd9af83594,
+                    0x207db972f57d86ec,
+                    0xe2d033c2e6cc5288,
+                    0x000000bf9473b46c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf95de52b2067710,
+                    0x538b40b4376388d7,
+                    0x61b43fc7aa9bf3d7,
+                    0xeac8d3069924ed1b,
+                    0x00000009c5310f4a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1143453d780eda28,
+                    0xaa5a19b99e537d6f,
+                    0x3ee5edfae9de5a2a,
+                    0xd1231808dd9e2b59,
+                    0x0000033f6d12ee6a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x05a519afeefd929c,
+                    0x3bd31a36a0af3e6d,
+                    0x941cf1bbb66c9bf5,
+                    0x44b34e10fa0be90e,
+                    0x00000311d9e0f5b4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4c4521782b2c982,
+                    0x0fe5c3bdf72cc593,
+                    0xdd2d5665e625f544,
+                    0x44a57b19a0e86c4d,
+                    0x000002cbe914c83d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x796902d3d446e108,
+                    0xa2fdc49008ec2f53,
+                    0x1728283f9c5fddb0,
+                    0xb21354ef1350137d,
+                    0x00000262a3a62af5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad264bc1b9e8b256,
+                    0x2cb9d8466a873ff4,
+                    0x490398b072af4450,
+                    0x9d1832b661bdd62a,
+                    0x0000011dc4b26137,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf866bbea13ec0fd2,
+                    0x0dcb539f9ac920cc,
+                    0x235df8a666da84e7,
+                    0x1365d59ab8e474e0,
+                    0x0000010874bd0e17,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90d0be5d851811b8,
+                    0x20f5982e3339e852,
+                    0x5654239ce1cccf88,
+                    0x7ca4f2457aebc9dc,
+                    0x0000033f385df6b9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62740c1726d71b47,
+                    0x305667318a2885b7,
+                    0xc359b52a7e213ec5,
+                    0x3df7cb1ce12c785b,
+                    0x00000048c3506af5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b8302c71ff7fe0f,
+                    0xf14b93d35f340812,
+                    0x791b8340ca841839,
+                    0xd39579f917fabe37,
+                    0x000001b37bdfc3c8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e98de206a8995ef,
+                    0x6d40ac1fa3462ec0,
+                    0xa485225c4f45e82a,
+                    0x1d904369facdce45,
+                    0x0000037d89643460,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd3c27cd5560ec9b,
+                    0xbcb8cf28a124a61f,
+                    0x88b0e48ae61ea725,
+                    0x35d7162373740269,
+                    0x000003b7722aeb3a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55ca1629353e1c9d,
+                    0x213820b63a284821,
+                    0xd02de2a81a74f06e,
+                    0x794afdd3d7e34d85,
+                    0x0000003820980f3d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2baf3f8d8fec73bd,
+                    0x6675e24809d4899c,
+                    0xff73a4bfb6bca51c,
+                    0x88658fa940e491a4,
+                    0x000003b7ff8afc6d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb048a62ed20c3f0e,
+                    0xc2ee2d34190122c1,
+                    0x2c024e61df433d3f,
+                    0x191941fad6a4d6c9,
+                    0x000000c43f64f301,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b44aad934c6dd5c,
+                    0xcd8980b46a597d00,
+                    0x604b3da3a7c78747,
+                    0x96b0cc4399f8494b,
+                    0x00000090e3568a27,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91d9a08115a574df,
+                    0x2943436962daf0c4,
+                    0xf4a47bae2a054338,
+                    0xd5d57b912698863b,
+                    0x00000359bea74df8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c951d29fd3474d9,
+                    0x0e9a32f6561116bc,
+                    0xfbec80bab72b96f3,
+                    0x15ce59206992be97,
+                    0x0000017d0a6a3a7d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfaa72a0b059ee3a0,
+                    0xecdfe90ec8d68e42,
+                    0x7acb0fe574ca6034,
+                    0xbaa4c5c14f1423d7,
+                    0x0000026d7822d529,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5bb90c6a6531b178,
+                    0x5b337387c899afb2,
+                    0x39fa22cbac1f6e46,
+                    0x6dd3c45e74785248,
+                    0x0000030f161e9c0f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba15f4ba2f063f82,
+                    0xfb7012b932f6166f,
+                    0xc94546cc54896a0f,
+                    0x9cb50a7b78d6929c,
+                    0x0000019eead2c844,
+                ])),
+                Felt::new(BigInteger320([
+                    0x475f31f31cd7695b,
+                    0x17600223e4888dda,
+                    0x7a1e1fcfbf958426,
+                    0x018c1659aa7b660f,
+                    0x00000246ad4678e4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc23529a895bb1579,
+                    0xfee3f72e0f7378ad,
+                    0x9882900af75a055b,
+                    0x58780dfc5ce2ab1b,
+                    0x000002cf25a39952,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44df4777e30e6baa,
+                    0x41f3519c730af890,
+                    0x7f76c6b46cc2b299,
+                    0x07e56e9159092fb3,
+                    0x000001d07d59f472,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90bd52bdcda5aa39,
+                    0x93e2fb3d1794ee9e,
+                    0x3b241383a8e059c7,
+                    0x1afc9a8a95b791bb,
+                    0x000000dfbd0f78e5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fa89e5a42599aa3,
+                    0xd29aac34d75cb131,
+                    0x69b639308d1e487a,
+                    0x6246bb7634c3f0de,
+                    0x00000364ba0ea415,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x258e264b053f7072,
+                    0x9309656f335a06b5,
+                    0x4f205e0dc4e48426,
+                    0xd0381fdeb0af0496,
+                    0x00000341bf4b948e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52689320435312b8,
+                    0xd3f36d2d6dcbec26,
+                    0xfe4c14a30d96be64,
+                    0xecda65b3c079e329,
+                    0x0000019b03b240ff,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb77afb9ef8db3d3f,
+                    0xe463e2023058ba28,
+                    0x264a1cdf183a8eab,
+                    0x98f81a78c91f6ac7,
+                    0x000000618bce8bd3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x748a627a8cf967bb,
+                    0xfbfb08f9a08d55bc,
+                    0x12ef5cb4d8696178,
+                    0x4fde78e156b4c2c0,
+                    0x0000007df61b25f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b7bf6c0a6cf7afa,
+                    0x77065a1944d676d4,
+                    0x6abf7ce2380b66a8,
+                    0x31e2a1cd4a2b3ab6,
+                    0x00000140d97772c9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x659b23158acf37ea,
+                    0x2fdd9da2e3df6368,
+                    0x4c7471c0cb4ac615,
+                    0x5ab0f547b00d3255,
+                    0x00000078bd0fb6de,
+                ])),
+                Felt::new(BigInteger320([
+                    0x401f008ef9faa68b,
+                    0x2637b2b165f50c22,
+                    0x94bc39bc6605b295,
+                    0xa6b479e17a157a33,
+                    0x000000cae80d0858,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44b62ae608d08061,
+                    0xe179a0438c375a0c,
+                    0x67250c636098782a,
+                    0x3bf04d4449fce800,
+                    0x000002abc5cd4b69,
+                ])),
+                Felt::new(BigInteger320([
+                    0x164f8de6f57ef713,
+                    0xd660de02589219c3,
+                    0x463a9705917ad9e2,
+                    0x0c69aed0d9add22e,
+                    0x00000285b2ea4af7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a220742802ae964,
+                    0x312ff5fec550820d,
+                    0xdd6f0e3ea0ccf177,
+                    0x32eec781d66b2057,
+                    0x00000376c06405b1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6bb80fb34243311,
+                    0xe6bdb523bf085ba8,
+                    0x0146b29bf6c07da2,
+                    0xdc4c966ecf4c3478,
+                    0x00000389f9391b7d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe056297e7109731,
+                    0x1775828beba55434,
+                    0x5feda59ba0628de4,
+                    0x8205ef8c09672d95,
+                    0x0000008cafc84d45,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2256d3485160821f,
+                    0xfd703911f027c392,
+                    0x5e05af7696a339a9,
+                    0x8539a58722a694fa,
+                    0x00000030211e42fb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3484d62077298128,
+                    0xdfc8bfc9da06d300,
+                    0xba0d49b24505280c,
+                    0x918446d262dd1515,
+                    0x0000024e0ef0eaf8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe213994f57c697b9,
+                    0xf0d9f4a27bd63e6f,
+                    0x4deb86763a01784d,
+                    0x4700cae0e964e5a0,
+                    0x0000008c2ccb437b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f3ace7ef7d22167,
+                    0xac424e754d7b881d,
+                    0x56b00f6ad8b3801b,
+                    0xc8a17d2d0818316d,
+                    0x0000030862b37f5d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b85b0000a1124bb,
+                    0x27070db5b0ec7e98,
+                    0xdf9a90e8fcba377c,
+                    0x5c844b5cb88cefcd,
+                    0x000002cf8aa63aec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3863c7a3ded5a933,
+                    0x7238446c6cb20eee,
+                    0xe60e48e3c7a49e3a,
+                    0xd53056045e72b40c,
+                    0x0000037f9fab30a1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89fb612827b75f53,
+                    0xdf90b2c1035974d3,
+                    0x509203b560e28ec4,
+                    0xa061bbe455609aaf,
+                    0x000002e307c2bcfb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b71f3a4efa97edd,
+                    0x799a56596952eb5c,
+                    0x4bbf3247bfd6ec23,
+                    0xae7c8775f76cdc6b,
+                    0x0000022cc658e54c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8b6ef417d9ad9b7,
+                    0x2c6ad3882928009f,
+                    0x06f28e2be25ea49a,
+                    0x8865df751b1f449f,
+                    0x0000014e228f26bf,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c541437e6249d5a,
+                    0x89c2aa24a1841668,
+                    0x08887515748c3d71,
+                    0x76e579193c12b32c,
+                    0x000000abd3c8ffed,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87a3a5dc49148846,
+                    0xbff7ac399fe5a9f1,
+                    0x1b84857ed07b0926,
+                    0xbfc5b4f689904118,
+                    0x00000146a7e11739,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9dd9b443ea7d6d7,
+                    0xcf584839ebda8b9f,
+                    0x71d1f58c6ff4f60a,
+                    0x714b3f0627c211d9,
+                    0x00000026b1432f5d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1738b4dbfc0fa2b8,
+                    0x88dce75ceff37584,
+                    0xab495efce30f0f75,
+                    0x9617d42e82988efc,
+                    0x000002e7d8ef3193,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1d21c9c19b2fb4d,
+                    0xf7be37c041c99a26,
+                    0x4c206a65c44eb5e9,
+                    0x485737685d4f032c,
+                    0x0000010ba85886df,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93144f42965c5b44,
+                    0x531adb7dfb8ecd0a,
+                    0xa6915b72e643d7df,
+                    0x8d2755eb487ab174,
+                    0x0000011aa8c461ae,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e86d3bc7bdb8ed8,
+                    0x73831f1bec19c785,
+                    0x71ec7d35f392a8d1,
+                    0xb0408290499e027e,
+                    0x0000012f325a67c3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf10e8a1cca591bb,
+                    0xbcdccf4e5588a704,
+                    0xfa77fb66138b0c97,
+                    0x487178b32d6ea563,
+                    0x0000006a4ba7ec7e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02d280b8f7f91fad,
+                    0x434c21f2fb0d0114,
+                    0x9c1ead225719822d,
+                    0x15e74c37f7ea0434,
+                    0x00000270b2ef891e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fe2af53d614843c,
+                    0x3715df3e45a13398,
+                    0x3e920b546f2db716,
+                    0xd00e460da3ad9b7f,
+                    0x0000026c26ccbb6d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2271bc7c4301f5c,
+                    0x0c71e260f64a002a,
+                    0x4fadbb963a007239,
+                    0x7d70f8cd6f39107f,
+                    0x000000ecc1bb43e5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe88650e41b9284fd,
+                    0x2f480aa50e7dc325,
+                    0xba6230457453c17c,
+                    0x4427465b94984958,
+                    0x0000027dc551d560,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d32efb7da94745e,
+                    0xaeb85c1ee08c7ac8,
+                    0xf9f60588f5274319,
+                    0xb37ed1e59ba0eea4,
+                    0x0000031808888c04,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbea0f5c0b2fd4ad8,
+                    0x6b3f3f1debd8e584,
+                    0xaa8387587c635d2e,
+                    0x92cc4ece25b960b2,
+                    0x000003444bd59ef3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd67f9a7eb55f2180,
+                    0x3abec071ff421072,
+                    0x2c87c98f004cc206,
+                    0xede13daf90ae726e,
+                    0x000003a57bfe256d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9b831b7da186364a,
+                    0x417bff88de150ef4,
+                    0x7b8320c7c961b209,
+                    0x64e81952b3ef1a9f,
+                    0x000002e1574b33ac,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f207ed9d42ef4e4,
+                    0x0be84f5811313120,
+                    0x12b4723a0ba0673c,
+                    0xfbe823f39989d155,
+                    0x0000030b37abc79b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fe679158d2157e2,
+                    0xdd37be8d7a04b444,
+                    0xdd0a2f2100cf39f4,
+                    0x264e7a2ff9cbc3c9,
+                    0x000001c7813fe915,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9832d058c2746ab,
+                    0xc6b33486a6ca1bce,
+                    0x3cebb51e1cf49395,
+                    0x17951d6d917f68ef,
+                    0x0000015974922e36,
+                ])),
+                Felt::new(BigInteger320([
+                    0x871c46758c09af55,
+                    0x3a90591350860f39,
+                    0xbaa6b764bbd67cd1,
+                    0x7330d5725d544f5c,
+                    0x00000330d7bfec52,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4581acb6116683d,
+                    0x760ebee39b8ca167,
+                    0x3dc1c2ecb183cf9e,
+                    0x5fadee5568cb2a61,
+                    0x000003a557bccd6a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d24ded9aa681e13,
+                    0x4e5d0b3e1ef4b059,
+                    0x032fc2f504c41ac5,
+                    0xc8d0c9ae011e42ea,
+                    0x0000003e89ecd9cc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a550053ab752bba,
+                    0x8aafd4a25dd571bb,
+                    0x4b96dd2fdde041eb,
+                    0x7a1e09ee06e78751,
+                    0x000002c16e7e9ea7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x070c03b5b1ed3c65,
+                    0xf31dad4376c1d764,
+                    0x85083588558ef89a,
+                    0x34e904373bc1a92e,
+                    0x00000296414b34e9,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc26a6427f4d64e42,
+                    0x301ea42e27033c1c,
+                    0x331cfb9f56af24ff,
+                    0x1575351cc7e0283f,
+                    0x00000105da87f20e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x799528997d76c644,
+                    0xf155a07f315f0291,
+                    0x3dd013466ef433c1,
+                    0xdacfa37e5a505612,
+                    0x0000033f9cd49abc,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0fef290924495f1,
+                    0x33b55504d9e3083c,
+                    0xd0f79bd4e25ccfe1,
+                    0x3133842cf67ee715,
+                    0x000003637afcf88d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23ea2560a541bc81,
+                    0x7b6df2f2eb6c7772,
+                    0x2d6d65ba556992d0,
+                    0xccd8aef77c2f6e1e,
+                    0x000002d48d5e32d7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e73c1c98889cf57,
+                    0x00338c43d7c499df,
+                    0x90447aa4cbc6d04e,
+                    0xca514d1a28b65018,
+                    0x000001b2e5c79397,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd28dd5a96e194db0,
+                    0x003f3600ebad3a0e,
+                    0xdc89e83c8b1fd4f4,
+                    0x2cb29de6a5182cf3,
+                    0x000001896126d5a5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70010b0b9cbecb88,
+                    0xe075dc7940f2a829,
+                    0x25ad6aa46b3dbafd,
+                    0x41b572114cd5cb35,
+                    0x000002e4155ebc7e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8b9d38dcfdec173,
+                    0x7c2ef5a6c6c40b85,
+                    0xc6879a7860a72982,
+                    0xe44de11d59539b7e,
+                    0x000001b85c2306dd,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0ca82ca7d8d18cd,
+                    0x827f278ed11b50d7,
+                    0xdf53c48fbe798101,
+                    0xc0f69a78eee1ab02,
+                    0x0000007e88d95da4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0e66cc28be8499a,
+                    0x42a5c5ff3df23bb5,
+                    0x8b48f0ab8bb70bf3,
+                    0xa8e9a220abcac5db,
+                    0x000000554f5058fb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9243f2a498a69cbc,
+                    0xc5cd8417a153a453,
+                    0x425f4ebd803dfafa,
+                    0xe59ed8629d7b44f6,
+                    0x0000011e65d1562d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabce28b44d6e834e,
+                    0xe46ac2b34cd24dfb,
+                    0x88f2d48ea99d1515,
+                    0xf6f5d9727579a96d,
+                    0x00000333014babdf,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4730145c1d4cbe89,
+                    0x1e54fe498f705b23,
+                    0xe394b3b0b5f6c01c,
+                    0x133225768d40efd2,
+                    0x000001b3d2119b14,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7e5a0f56584689d,
+                    0xe7fb1dfb68bafe09,
+                    0xb2c93e8b8d022814,
+                    0x008bf50a8c06695a,
+                    0x00000083f8fc5b43,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e6266532546def0,
+                    0x989b7df437e2f092,
+                    0xf1ea5c1076d7136e,
+                    0x183d8b3a12f78414,
+                    0x0000031df826a25d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd7734eae174e84f8,
+                    0x4654c833b3521bde,
+                    0xf4fdbdc73a5e0d5b,
+                    0x0dfac0ffcc86a22f,
+                    0x0000010d979fb0eb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5947d07f8873d502,
+                    0x382d5523d03b1bf5,
+                    0x90cd977fc3002149,
+                    0x7ebaf7863f52480b,
+                    0x000001d9f0436066,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b580c4fc2cc54fc,
+                    0x0fa30ce59b93e47c,
+                    0x2198f33fea3644c4,
+                    0x7d2061162d2d3b0b,
+                    0x0000005369296a3e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1082abc0f348fc1,
+                    0xe9f1b7be0304fb5d,
+                    0x3ba88bbdfe276954,
+                    0x7bb33869527ff3cd,
+                    0x00000094771e30cd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b4cbefac63640bb,
+                    0xa49bd4867bea9ac1,
+                    0xbce44b1d597b7ba7,
+                    0x87bd92f1a9781953,
+                    0x000003814d99058e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5603aa1b58c0f866,
+                    0x73d117fad509373c,
+                    0x01237c06f88f11fe,
+                    0xc73a5349ed956224,
+                    0x000000885a33df15,
+                ])),
+                Felt::new(BigInteger320([
+                    0x114532db3245c2a7,
+                    0x0a52ec914da7c067,
+                    0xa5e5ce602c1dd2ae,
+                    0xff9cf0f93adf7c3e,
+                    0x0000036ae4510fae,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63a433f1c8c94f31,
+                    0x39e0114cc3237d6f,
+                    0xcdb90f46f874899e,
+                    0xcfb4a351bcc11cbb,
+                    0x000002d0dda6dd25,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc117425bcabf6186,
+                    0x40d567a4c85e721f,
+                    0xcd3ce76a76945de7,
+                    0xad145b7f047b69cf,
+                    0x0000005a53952bf0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc711201ee6092ce,
+                    0x3641a0adc1718d0e,
+                    0x21c6adae09992e1d,
+                    0xcb0149b85f28e0de,
+                    0x000003104ccb8f2e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec9d8a2922cbda57,
+                    0x74731877e767d7b5,
+                    0xacf12bb83b70f75f,
+                    0xea5b9aa508f5eb6b,
+                    0x0000018998efb6e6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22b72b91f777efbd,
+                    0xd878eba014665a17,
+                    0xebd757a878e0917a,
+                    0x82143cbfa610e74e,
+                    0x0000005fdbcc3584,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa889dff35a51967b,
+                    0x9f0331c4086b39c6,
+                    0x4ba129eea793046b,
+                    0xdb8ea953a86e5b1e,
+                    0x0000024c15735d98,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f58ea707d2a72a8,
+                    0x3aaff2a284b2bb69,
+                    0x9de5465a54c5d4af,
+                    0x2fa6c11934f9bae0,
+                    0x000002a2666651b3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaea8f02f665c2a88,
+                    0xad6987fa155726e7,
+                    0x89dd445f50e4c866,
+                    0xdc6b808b917b16f7,
+                    0x0000028e60a9a907,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e96c0e15f617913,
+                    0x293f892c0716bcbd,
+                    0x5aacd54160390928,
+                    0xb695bcb2f4ed7562,
+                    0x000000c7e321fb27,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7dba8c066525a8b,
+                    0x059dc34c072193d1,
+                    0x05dfe27d4148cc9f,
+                    0x8db2f0a869c079f0,
+                    0x000001c7e5aa877d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf573a33baead15c3,
+                    0xc72a434e9f708f71,
+                    0x79ede84f790addd4,
+                    0x423aea8698b48e38,
+                    0x000000e7963eedf6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8de6a3492459e536,
+                    0x67dde4bd1d949cd8,
+                    0x5164d072a5bf55ed,
+                    0x833af71c3bbf30b7,
+                    0x0000002c45e71b7a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb76e065179e3eb59,
+                    0x0ffad9e1eac6c483,
+                    0x75b3d104b3fbd99a,
+                    0xd341b5ccca7da9a5,
+                    0x000002eaec594975,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa01d9d10507085d,
+                    0xaaa144397564af89,
+                    0xd8ee4997b624b396,
+                    0x4a185018615050d0,
+                    0x00000203b731e704,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52b30be588423429,
+                    0x0e67487ee85de184,
+                    0xc1d301f69c50528b,
+                    0x17227132f79a1d94,
+                    0x0000002b0b98e924,
+                ])),
+                Felt::new(BigInteger320([
+                    0x371bcbf5cfbbfa45,
+                    0x87fa2d8e4316794f,
+                    0xfd3c06314762f268,
+                    0x0138121f13b9d8b8,
+                    0x000000ae0b57cdf0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1326ad4e9fb4b03d,
+                    0x08c59a47ef5efadf,
+                    0x2138cb449f97c686,
+                    0xb353cd99a5addf34,
+                    0x0000032456d72903,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3c1e7c11d6f546ad,
+                    0xf5a4f936d356cd1d,
+                    0xf5c00cbdb1e48eb7,
+                    0xf8a61882f46fa291,
+                    0x000001e39ba42636,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9273be5be045736,
+                    0xef6a05c4ea2413c8,
+                    0x0c5088e50b0f6566,
+                    0x1e26ad5c4827ccd2,
+                    0x000002aa7314497f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0eee661b2b6e2b56,
+                    0x3a5d0fa9a0d07c8d,
+                    0xc5a3853ff25d391a,
+                    0xa5411bdf9d1a1a73,
+                    0x0000039a9f5613fe,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc393c9ce0ed31ff3,
+                    0x0b3a3087641e2273,
+                    0xd80536c1f41f94af,
+                    0x3b1d3e95623a98b3,
+                    0x000001e1f5d1c359,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54d903571ff82e55,
+                    0x86fc5165408bb16d,
+                    0xa320244557f681ec,
+                    0x09c417886975482b,
+                    0x000002fcb8fed31f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb47f769a13da7f2d,
+                    0xa03597779886641f,
+                    0xc7741c5d3eacfb29,
+                    0x4eaf7c69f508f077,
+                    0x000002a40018dc1c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc35a4c5ac397a1f6,
+                    0xa1cd4bddbc3befae,
+                    0x67458592920c6dfe,
+                    0xdae6e64a9039ac57,
+                    0x0000034a5871b6a7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5c29813806ea8d1,
+                    0x0352416955f812ba,
+                    0x2db9a764cbaa79ba,
+                    0x55a19163e6fb436d,
+                    0x000002db767e76a4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34fd773bac51a597,
+                    0x3e95a0770afb51cd,
+                    0x1e171c3229185c9c,
+                    0x73559f82ed234d75,
+                    0x0000010092b5e508,
+                ])),
+                Felt::new(BigInteger320([
+                    0x577d9a9add91eb3f,
+                    0x3626d07e695a626b,
+                    0xff81d5f03d7a9e1f,
+                    0x15f6a3f70e5f7906,
+                    0x00000302cd3a7bc6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x264a3f4fdafb944b,
+                    0x89a82384eecc7834,
+                    0x73f0a4c93b96dba0,
+                    0x9587cec006062c58,
+                    0x00000259fd162785,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f34fe536cc555f5,
+                    0x053d697485e71da6,
+                    0x6f05f70d5bb67cc6,
+                    0x0a87f6e9b62b86d6,
+                    0x000003577754ad10,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe87d528aa513815c,
+                    0x627dcc33066de133,
+                    0x5bbec4fb588b5ce9,
+                    0x7b46a38ad37a9f4d,
+                    0x0000011653316f24,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0514a67ac74b7df,
+                    0x742d8e373a10b8b5,
+                    0x2d1643a77cf28e1d,
+                    0x6163248d38d03878,
+                    0x00000278ab028074,
+                ])),
+                Felt::new(BigInteger320([
+                    0x707fca0ef7fbc3cb,
+                    0x88d36db77c2bfb65,
+                    0x4c0204a469c790b5,
+                    0xed79ce38f6de9fc3,
+                    0x000001943819f6b2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf37bf586362f82b,
+                    0x78bbe2deeda398bd,
+                    0x143392915fb05f6c,
+                    0xc78aa3b93b0c56b5,
+                    0x000000e18b8b2cc2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd550143fcd5a87e8,
+                    0xe557d68277af9710,
+                    0x62da865009f881ad,
+                    0x50e2f7baed4819c8,
+                    0x0000013eb138418f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a404209a83b0f66,
+                    0xba1e7992075bc37c,
+                    0xa265a2b0d4a23160,
+                    0x2300d24c9d597c73,
+                    0x000001d221be2ab1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf98b2f109b2dc26d,
+                    0x665dc34d15c5e276,
+                    0x9b82e960abfe8451,
+                    0x9ee1db7a3f09449a,
+                    0x0000033a03c0d863,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb214c80dc45a733,
+                    0x91cee9b3401ebe3e,
+                    0x7bcdca315152c082,
+                    0x0caa1fd8a0919b97,
+                    0x000000774aa6bfc5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1570c2208023263c,
+                    0x46f0e99e16ae3873,
+                    0xce1c39a1d0ab7d47,
+                    0x7def76ef5b7f5032,
+                    0x0000032090ee65e6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x859f7e15cd9d1e94,
+                    0xfd1bd30dffeded18,
+                    0xb7a14f3a175dfdbf,
+                    0x8651cf709ff634ea,
+                    0x0000013201d30ea7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1250f6c62e2d010e,
+                    0x5d29e76cf81e6b30,
+                    0x6e6fbae1a83ff02d,
+                    0x8bfc41656c01ad48,
+                    0x0000018840daea9d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64ac867d24de7532,
+                    0xce604cd13e2c0fe8,
+                    0x5428c58aea453685,
+                    0x99e7b6af9def6ef9,
+                    0x00000059a799cbe0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x052466ee68e9b273,
+                    0xdf67431382c46151,
+                    0x78234308b7ca84f0,
+                    0xc9267388db1223d2,
+                    0x000000533cb6df07,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad1e9ab9f5319351,
+                    0x08d3802eccaf5b9b,
+                    0x09da50943346219c,
+                    0x913016abc060b941,
+                    0x00000062891b7a06,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa69147cfa22870c8,
+                    0x41669bfde04438a4,
+                    0x0d44cd20ac8ce019,
+                    0x29376dc19e292c3f,
+                    0x000000e5f722867b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73e710d8aa776a83,
+                    0x1fa809033d60a6ca,
+                    0x089b408d0261a19c,
+                    0x6061a836a59e5342,
+                    0x0000008c6b9a79fa,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfcc6b8fef95fc2ac,
+                    0xa1511498d9981abe,
+                    0x13455e3fe26b0d90,
+                    0x140e3be4121437e5,
+                    0x0000025d70ea1364,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7036abccb526cf69,
+                    0xdbeb2c008f708adf,
+                    0xbacc465125b69cff,
+                    0xb2f304960ecac9f9,
+                    0x0000038b87cfd759,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd9d464c45253599,
+                    0x8ea977c038cbeaf8,
+                    0x9e1300cce5c93396,
+                    0x492a6704cb232752,
+                    0x0000029b28ac55b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1ad0400071ff185,
+                    0xe9ddd2a3e8aa914a,
+                    0x61bab8b7a761ca9a,
+                    0xff7b8b2feca9acff,
+                    0x0000038a3f5fef68,
+                ])),
+                Felt::new(BigInteger320([
+                    0x587e3c4d200d7425,
+                    0xd81f6c169f91c44f,
+                    0x889d8bf512f6d615,
+                    0xa41d246e61c615ce,
+                    0x000001e417d84b33,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82d7b0e54d044ae0,
+                    0x0a74414bd9db7d5e,
+                    0xdb00baa9af513394,
+                    0x84e9c99d678bd1dc,
+                    0x0000020bd501b03b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x990f6f7407274496,
+                    0x2dc672bdf4eff272,
+                    0xfd7b8d9a9fd229be,
+                    0x56fc9df962d5b8a2,
+                    0x000002c89afb70f0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7b46ada4ee1f195,
+                    0x90f3582fa2e4e19e,
+                    0xaf2083358f3c465d,
+                    0x08a6923e2caa8f8a,
+                    0x000001633e1b420f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6f9f247f60bf8c2f,
+                    0x1e979b4558468040,
+                    0xbf0a9558c6b74f17,
+                    0x2c66f25955c4df1a,
+                    0x000000f1eccf58f4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfaaecd711e38e331,
+                    0xf84ce35d9ff447ce,
+                    0xd5478a5d9d3806cc,
+                    0xefa7ac37b045008d,
+                    0x000002ab542c113f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaff993d84d66ae0e,
+                    0x9ac31d00644f9e76,
+                    0xa27b5a00346c0236,
+                    0x726c93e33d7e8872,
+                    0x00000039d74a9706,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb71a823d3a7ae6f,
+                    0x4f0d53bc13a4adea,
+                    0x23a31778652c52f8,
+                    0xe2bbdbef70529bc4,
+                    0x000002ea23301588,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9bbbca7632eecf0,
+                    0x29efd6c8a2a56e73,
+                    0xb7e08c8068c2ad99,
+                    0x422c823de9227547,
+                    0x000003090783bd05,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b8e1c047bfb3cd1,
+                    0x86b9ea926721c7d1,
+                    0xc3f2a49a65f3c342,
+                    0x226b607bfb082d31,
+                    0x0000026917148e38,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac7d352ed9d7a6b5,
+                    0xe3a5b0c97fefbffe,
+                    0x2a2086f139633210,
+                    0xf1946dae21bc544b,
+                    0x00000207aff4ec8f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2857da596d64aee1,
+                    0x54210bff76293af3,
+                    0x00631b105c637bde,
+                    0xa0a4192bd2532d48,
+                    0x000001403fff1c2b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd26b30456a884c43,
+                    0x5cee6d8479ff23c6,
+                    0x7c7a231c4d9dfcc2,
+                    0xbef0ca51db19e0e6,
+                    0x000001c4e490ce05,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a7340360c106ff9,
+                    0x15e71e3e018bd361,
+                    0xfe09eb61baaa6dc1,
+                    0x6ca154c5b8398feb,
+                    0x0000019898d1fb49,
+                ])),
+                Felt::new(BigInteger320([
+                    0x960ad1408be88a0f,
+                    0xbfbba38a0f9cf9e5,
+                    0x22e935373be8f780,
+                    0x8ff039378c901e4c,
+                    0x0000032fc332f126,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76faf54ba72777f6,
+                    0x9f423e6ed2f33c5c,
+                    0x18adf3fa5a6a0c47,
+                    0xd5151d0a2de59b88,
+                    0x0000014884436fd9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x035c76a9c0fc5a7b,
+                    0xace8e0d94800f3c2,
+                    0x1a951ae0daf658f1,
+                    0xdf1763ab68b61785,
+                    0x0000004e2225e11f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63dba0faafdf3969,
+                    0xeb91b2764a5a65ba,
+                    0x4df20e16c4211ec4,
+                    0xd96f4be70ce3f13d,
+                    0x000002e885a88784,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e47d13aef517fe4,
+                    0xbc203c375d708d37,
+                    0x0c9dd7e417c3a09d,
+                    0x8a188acd0c283df6,
+                    0x000002724c8ab11c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc926711bb159d030,
+                    0xdd03967bdf3db226,
+                    0xb8d5f223305c415a,
+                    0xed244b44c06b1831,
+                    0x0000026e96131026,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0dcbde926a9a0b71,
+                    0x3643c04ca96e96f0,
+                    0xd4cc4825dd85347b,
+                    0x49b97e9c38681d7a,
+                    0x0000009cd823ed51,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d019fd87bcc41a2,
+                    0x1bc8088b69f0766d,
+                    0x5a746d1c3e822ff0,
+                    0x58e9aaef20161aee,
+                    0x000000b720ac3cf9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64e375af6678dd4b,
+                    0x3391de1778c6e966,
+                    0xbe33f881052ff543,
+                    0x72385a1d72586a37,
+                    0x00000143149fb4c2,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78af32e4c76364a0,
+                    0xa9a856b9b5270f97,
+                    0xb80036089901a88c,
+                    0x5f2f013c5148e096,
+                    0x0000011a3b5f62be,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3d9321aab72a9da,
+                    0xc9a2b414fbb18431,
+                    0x811f905b861ca57f,
+                    0x9561d6e393d09faa,
+                    0x0000023252127942,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65b98bda8e1add89,
+                    0x2681167f7c5ad294,
+                    0x2129f8989ebc0dbf,
+                    0x9f39658f729459f5,
+                    0x0000011be386a261,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3508e52f154451af,
+                    0x2be48923f2b159cd,
+                    0xcbb599fd411729a9,
+                    0xc7ac7c3b052c3dae,
+                    0x00000334b63820ac,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed205aade386d9c5,
+                    0x39a536b19fa2d9d4,
+                    0x8d6a3031b3676e3f,
+                    0xaabc88a458cd3ee3,
+                    0x000002a30e7f723b,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddca8874eb305727,
+                    0xf6688b64f022987c,
+                    0x53115a10ede8a8d5,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2dd20acc1490b448,
+                    0x40b12a6640c92c0a,
+                    0x934e25a0a3ff01b5,
+                    0xb97dec5fcd0a42fb,
+                    0x000000266e462c9e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f4b7d50970f663e,
+                    0x0ae6837f928a1f1b,
+                    0x1f3ece7d2d87d366,
+                    0xc3eca208fcd95f19,
+                    0x0000030eb9283def,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6e7b71bf803aa95,
+                    0xeeb82f38ca9cd580,
+                    0x1e12fc3bf8e5d7cd,
+                    0x54ba2063219b0e53,
+                    0x00000235720d642c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa19d28e339cab40,
+                    0x6405056fa95eddae,
+                    0x95426b22385540b7,
+                    0x9797483b07b49482,
+                    0x000001da803330a2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeaa56c44e6b7a96f,
+                    0x5e7b63ac3c9a6076,
+                    0xbe51d9853732423f,
+                    0x5ed35c51d7bbea95,
+                    0x00000032de98b89c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d5032f9602e2aee,
+                    0x6cdab035ff6b3f07,
+                    0x8cee5b8285a7c32f,
+                    0x4bf2e33a83efa35d,
+                    0x000002467d91816f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32d463cba6e049b4,
+                    0x16ceb9719d353cd5,
+                    0x55eb5bf82e9d0d25,
+                    0x2d4194f216423b10,
+                    0x000002797ae69ae2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef44cbfb0fd2ea64,
+                    0x8c4b132f1ef1151f,
+                    0x4e226f0fd5f40676,
+                    0x004bfade2f4a69d1,
+                    0x000001847226a81e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb18e47e0da259ede,
+                    0x0f34d0ec9ae125e3,
+                    0xdd119c847f7f7c9f,
+                    0xc41ec7f99acd3e53,
+                    0x000000426e9c2ceb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94902862f4f46a48,
+                    0xe831ae19bb98b10d,
+                    0x248480e7553b3bea,
+                    0x17c0d39cb4f7e652,
+                    0x000000ecf8ae99ff,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa137c7f025255b57,
+                    0x38ff452ad8768e69,
+                    0x2e60abc8c54d9d49,
+                    0x854ced852462fd04,
+                    0x0000029dee30238f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc32bf751013bf09,
+                    0x808f13cb6caac53a,
+                    0xbb3a1d90274e7054,
+                    0xb82d69460fc81581,
+                    0x000002270a1516b6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x54e630df35f4825a,
+                    0x8df3a4b59684eb36,
+                    0x765a5c857d5bb1f2,
+                    0x1a60735a3eab8d68,
+                    0x00000157d53d6309,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28cf39f7b208bf9c,
+                    0x365a10ccdaa5d7d3,
+                    0x0b17ad1c417b5965,
+                    0x5c582cdd594d5d21,
+                    0x0000038be1563071,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70e8057ace4a3f94,
+                    0x6e770724a795051b,
+                    0x6d5cd54deaedee51,
+                    0x895f416d0300fc0f,
+                    0x000001dae89f6f54,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f0bf8684d469e87,
+                    0xbd6138d8a36ebde9,
+                    0x6edaab9f9e1ee7c8,
+                    0xa83a36d368483b90,
+                    0x000002f78cc20cc9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4cc8b48220af76b4,
+                    0xd756d14759cd528c,
+                    0x1b47332f9324dc91,
+                    0x18c5b9c9375a20db,
+                    0x000002ada357760e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe8128ae8a8f22e83,
+                    0x184f4c7fb5a76e97,
+                    0xea7fc0c4988ce4a6,
+                    0xb762d1fe6e987008,
+                    0x000001f2091053f0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2c80ed441d2dacf,
+                    0x170b65619e3ecbd7,
+                    0xccda006c5e494552,
+                    0x330f6adf34346537,
+                    0x000002ad487da38e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67c04801fd7fc430,
+                    0x8aa6329c4872df1d,
+                    0x683a781ab44ea350,
+                    0x38534df04df264c5,
+                    0x000001f531a471fa,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2de6e77fd72136d3,
+                    0x8938dde6abe783cd,
+                    0xa8a52a450b53b872,
+                    0x57aba8a20596c1e0,
+                    0x00000040be3a6aa4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06d69435611832c6,
+                    0xa9caefb791ade063,
+                    0x150c20b2007c912d,
+                    0x0c8ed7707cc4d41d,
+                    0x00000179700f690e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd664647f87e69aab,
+                    0xe0143a944a06a129,
+                    0x09d07d57312bbbc7,
+                    0x05e0645b3fa2a3ce,
+                    0x00000384d1e8be5b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2092e1e5f38d1af,
+                    0x71ea6bd13637c760,
+                    0xb213d81e08170001,
+                    0x2ba4b8aeaf9c2331,
+                    0x000000abed130127,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdfe50d80a164692d,
+                    0x5fde0170b64514cb,
+                    0xedc84ed333b7751b,
+                    0xb8ea85ca99c72d9b,
+                    0x000002cd4f12ff86,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a90007611d5d8ea,
+                    0x2279f00d716390b9,
+                    0x070a62e0dc9611c4,
+                    0x0ae9ad4e07c11da7,
+                    0x000001222054a4d4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf38fa042abe26743,
+                    0xf09e0e5f90b6a2e3,
+                    0xc9360f409ca5a7b1,
+                    0x2ecd65d0003cabee,
+                    0x000000194aa94948,
+                ])),
+                Felt::new(BigInteger320([
+                    0x885f8b11ef54e1c9,
+                    0x4145b1f3c6590057,
+                    0x54506e82ef7a5b7a,
+                    0x30bde3124e6142f5,
+                    0x0000001fe0c87d5e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8c1096c29159ed0,
+                    0x4c892bc54168fbea,
+                    0x358d8b779f88bbef,
+                    0x4b1a240187232d2c,
+                    0x0000033a102fca65,
+                ])),
+                Felt::new(BigInteger320([
+                    0x124df5c6d9dfcfcb,
+                    0x8c87e51de71adffe,
+                    0xdf08c633d9e20c42,
+                    0xd2941d1317db408a,
+                    0x00000258713e8ffe,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89e295a2dcc905e2,
+                    0x63d5ffc3166f8a66,
+                    0x52051c845ebc6a08,
+                    0x52ba777036ac0d25,
+                    0x000000c68b5c91c0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7db185c837dd40e,
+                    0x3414b958ad5d2605,
+                    0x85899a004240e7e5,
+                    0x056fed761ffdf586,
+                    0x00000315003c4d50,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99ed7054b74fb249,
+                    0x50ead6a8a7168f0e,
+                    0xe2f16f1f064a6bd6,
+                    0x367e9d7c9cdbdf1b,
+                    0x0000006575f69431,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ba1726feb73d0ee,
+                    0xa841a90c0ef145a2,
+                    0xb6484c5b35ac6259,
+                    0x1b32efabed622de8,
+                    0x0000029748491c1a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06fb2fd0e3596558,
+                    0x1925e491b662c2ed,
+                    0x70d496584aa5fffa,
+                    0x2ad73e1797013af7,
+                    0x000002bf9f62a087,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63c2d69ed89f1b0f,
+                    0x9ddcd086af671350,
+                    0xf4c6635208245547,
+                    0x25322cacabc450cf,
+                    0x00000173743691a8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2c3c60b89e3debf0,
+                    0xe8844899571e3c40,
+                    0xa0099c77a5dcd5f4,
+                    0x957f4d2432aa3498,
+                    0x000001500fb4f4f3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa050ad1f04b8a09,
+                    0xa92add1f20428148,
+                    0x9d60ff0a935ac41e,
+                    0x4e3a13d7c695e333,
+                    0x00000228dc278753,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7abaf962bf4a5111,
+                    0x7b02abb40a4104e1,
+                    0x4227f29f2c1d2a5b,
+                    0x75a5a55fe8fc09d2,
+                    0x0000007d74c8002d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31a3edb2c6deea76,
+                    0x3e8f65f17bc1bef4,
+                    0xb708f09b6f668e00,
+                    0x53210cd080c47953,
+                    0x000003790877f24c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04f277372208a9a8,
+                    0x3d09bf1eb12c03ab,
+                    0xa8250f5ff5572651,
+                    0xedfb795d0d5ad0e6,
+                    0x000001414da49a66,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90daa63e0a76a380,
+                    0xb79ab4075f076f93,
+                    0x62a16f85557e5b1c,
+                    0x606047e05e12bedb,
+                    0x0000031f2d7719e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb28bc09d03585215,
+                    0xb136ac999f4dd88c,
+                    0x0e2bcf4519932cc5,
+                    0xd3be124bd64ddde4,
+                    0x0000006bcfe36611,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedcf7bdd805b0829,
+                    0x9e962129552ae728,
+                    0x2b321a878deee607,
+                    0x7c207b26a432d718,
+                    0x00000383693fd584,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf835ebaae88eab04,
+                    0xb3d598e915e88800,
+                    0xf74f47c4edc8ad53,
+                    0x53d295868a9ec38c,
+                    0x0000039afc17b8f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f3b03d75f8da2f7,
+                    0x758d788a505ff709,
+                    0x57a6a1309bc44e35,
+                    0xee534a7a1d71d3a7,
+                    0x00000306290515af,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b3ddcf6136fde28,
+                    0x2eff635af43ffc49,
+                    0x7ea29ae2288fb290,
+                    0x4404f47617ac36f7,
+                    0x00000083ba9edfb5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c1e3b47fe2caf6e,
+                    0xa2fe1d9d854629d2,
+                    0x5b93780bfe05ab9a,
+                    0x3517768340e6b37d,
+                    0x00000120da0b87b6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2166992b3cd140d0,
+                    0x09772d823e418967,
+                    0xb631b510a88254fb,
+                    0x7adce0b213694af0,
+                    0x000000bb66cf966a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7e07d824455c970,
+                    0xaed9ca2362b6f79e,
+                    0x57e86c9c4a6f4917,
+                    0xcf18da9e9877abe2,
+                    0x000000620e3b98cf,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd17e3344f4392697,
+                    0x1afe36def9254c4e,
+                    0x002a2b0bfcc10535,
+                    0x26e8aac2ef3bf58b,
+                    0x0000018261501fcc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9331124a0a1b06cf,
+                    0xf44d5b353d6c2210,
+                    0xc5596be57eae2eab,
+                    0x8766f883466ea60a,
+                    0x000001c215e41dd0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8ebf076853fd7e8,
+                    0x3d20c65cd2957558,
+                    0x22f88b8d5a228d11,
+                    0xdcf40788ef124543,
+                    0x000000318febc2ea,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8224a09a9198208a,
+                    0x6c2fa6e6951aecc4,
+                    0x0b0290c0e78a9510,
+                    0x456848c623be0392,
+                    0x000000319dbf5b66,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe59dba4e1c97b34d,
+                    0xd33ddd9a265de17e,
+                    0x8d74e4d8434a208b,
+                    0x60207bcc2b5cecfa,
+                    0x000001c503800ec4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x09f858408bec9507,
+                    0x4b1b2db760b4794e,
+                    0xf887aea72943ab72,
+                    0xf5deb0c9a207f5f9,
+                    0x0000021212292bee,
+                ])),
+                Felt::new(BigInteger320([
+                    0x773778a10bc730ad,
+                    0x06ecf2602b8a0f29,
+                    0xa8d5272da26a22f0,
+                    0xcbcab2ad56245555,
+                    0x000003b7861cab9c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd34b8efd69fcec4,
+                    0x5f8cff0ccc268a8b,
+                    0xfbbe49a05272538a,
+                    0x4d37aaac1c9f36d8,
+                    0x00000057ad6e0923,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b206d4b17b1c886,
+                    0x0ca170ad86639dc9,
+                    0x4546897cec667cb6,
+                    0x28bd6be6e2efde87,
+                    0x0000001f70a75762,
+                ])),
+                Felt::new(BigInteger320([
+                    0x937b17b0ef927c48,
+                    0x8fc344ea7ec972b6,
+                    0xed679cee10939145,
+                    0xf7c29710ab92530f,
+                    0x000001393d216849,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9a548b39e639174a,
+                    0x2b475f14ba8f207a,
+                    0x2be8eedfe6f42e9c,
+                    0x20aff764e07a0880,
+                    0x000001781c4dfddb,
+                ])),
+                Felt::new(BigInteger320([
+                    0xacb8f743178fbea8,
+                    0x67d7b24d17562577,
+                    0x455db0dd6bff6627,
+                    0x1cf8a88f0a2f5ab0,
+                    0x000001d18e8c6fb7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed7298279a0995c5,
+                    0xc5609136cefea088,
+                    0x79b8b01f2ec85e18,
+                    0xbb55b716b937e773,
+                    0x00000262f44140af,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2ee8cf7a1f928cf,
+                    0xcde3db1ab0c848ac,
+                    0xb65c2e6d52d00ebb,
+                    0x8420cc22a020157d,
+                    0x000001fd0aba8d16,
+                ])),
+                Felt::new(BigInteger320([
+                    0x981ec3974f8ea2b2,
+                    0x51210789f82fe1cd,
+                    0x5d8d44a5b3aaac63,
+                    0x8efd8f9ef898bd52,
+                    0x00000351cbab75b1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5424222903739d75,
+                    0xc396658b816e55f4,
+                    0x07e968e6a22a3114,
+                    0x932843fa0f6e2ed8,
+                    0x000001b94f89e1b3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x464ec544dcb35e62,
+                    0x5b62d732b8459f8e,
+                    0x166afe3ffd141b97,
+                    0x9bf253782eb8ef08,
+                    0x000002d327c1710d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1847ccd5b79091b2,
+                    0x0738a1458f583965,
+                    0x1fb321ed2008ee97,
+                    0xf72f5427b75b0bd4,
+                    0x000002c0a7f6a6ca,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16c0f9bad4e223e6,
+                    0x88bda7e3c6dc47f0,
+                    0x0a476100ead0cab4,
+                    0xd69baa2573a72519,
+                    0x0000025277643a01,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80c0fb4f6c71d845,
+                    0x70c5a5761cdd420f,
+                    0x68dfe616d673208c,
+                    0xd8de870a4f30d331,
+                    0x000001c521b1c522,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabcaec4d2618ca15,
+                    0xc7f8aef01952adc1,
+                    0xf6dca543998648b3,
+                    0x0235b90bdd4eafd7,
+                    0x000003642718d7b4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b58fc0fd756689a,
+                    0xf585f87fc0350302,
+                    0x46127472c871e019,
+                    0x58725fdf9bcd48b0,
+                    0x000000621d8c6829,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
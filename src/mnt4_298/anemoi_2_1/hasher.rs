@@ -0,0 +1,443 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger320, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::new(BigInteger320([
+            0x7c82d04b946b3b2a,
+            0x31c69b7a8b72bb4a,
+            0x1478fa4b7bda4892,
+            0x5b97ef29a27bdb84,
+            0x00000127cf95d18c,
+        ]))],
+        vec![
+            Felt::new(BigInteger320([
+                0xb96f89f1d38ae19b,
+                0x0aefffafe6659b9e,
+                0x81586a5aeb208062,
+                0x5b45d82b1a212b05,
+                0x0000024f6264e0d3,
+            ])),
+            Felt::new(BigInteger320([
+                0x20c0ffe93b57b9d6,
+                0xed8af16517cc8b4e,
+                0x0f4c81f46edb1dc3,
+                0x1e6a8599c73303d6,
+                0x000003b51750e804,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xed0ce57b8fc0fb68,
+                0x30e7ea243a7843b6,
+                0x98954a2ce7992203,
+                0xddd955c3ac44db9b,
+                0x0000023f3cda22fc,
+            ])),
+            Felt::new(BigInteger320([
+                0x6f053165d793b4c7,
+                0x3b5b67401ab61204,
+                0xfdd12adc8c6015bd,
+                0x963ae6b36ddc568c,
+                0x000003a43da0f248,
+            ])),
+            Felt::new(BigInteger320([
+                0xcb693e925cc1e9f3,
+                0x52fcaf5f75cd04c8,
+                0xe751eca0dfd3223e,
+                0x821b096b22f68025,
+                0x0000034b1abe0ca6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x5ef5f76e5184c6c8,
+                0x57d689407074a455,
+                0x7df4852d7e1ea691,
+                0x8add43698097c156,
+                0x000002eea3f56718,
+            ])),
+            Felt::new(BigInteger320([
+                0x6a53289da54ee6b1,
+                0xd292109e01c99e5b,
+                0xe919a92464907c89,
+                0x033d974249194656,
+                0x0000001728630d10,
+            ])),
+            Felt::new(BigInteger320([
+                0x6053cf6a54fdb962,
+                0xc2a3b7ab03a3d06e,
+                0x05cf879c2069249d,
+                0xfa942d540c936aa1,
+                0x000001348dc9dbe9,
+            ])),
+            Felt::new(BigInteger320([
+                0xc6604e91a01169b8,
+                0xa9a72ef195e4deab,
+                0x893e7a07212e653b,
+                0x60a1147bd2af8fdc,
+                0x000000658230070a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x5c042e69d42cb0fe,
+                0x11af02e03beea17a,
+                0x77642f04065972a1,
+                0xf11f628cd09fa3e0,
+                0x0000019fb8707d54,
+            ])),
+            Felt::new(BigInteger320([
+                0x2c464b3d38417f4c,
+                0x22ba3a5c715931e6,
+                0x764bd0d131163543,
+                0x0298d156abc589fa,
+                0x000003581e07197a,
+            ])),
+            Felt::new(BigInteger320([
+                0x9a4635336f0e7d05,
+                0x89c76518c8e95fc5,
+                0x57991829a2e47634,
+                0xf553a39a7f86689f,
+                0x0000027d779db779,
+            ])),
+            Felt::new(BigInteger320([
+                0x857204297687d8eb,
+                0x51b5e036cbd3b63e,
+                0xb78ec9643d2a097b,
+                0xb44f346e4d4eb483,
+                0x0000003596b69f5a,
+            ])),
+            Felt::new(BigInteger320([
+                0xf4c79e3a70dedaa2,
+                0x059703f7a92b92cc,
+                0x0c9d465ea2b42a61,
+                0xd2d5444a6048e674,
+                0x0000027daf879ba6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xb84034ac28404d26,
+                0x0d052228884eb5d1,
+                0x5a2303eb54062d43,
+                0xa81f93e0b48c8714,
+                0x000000cad2f30141,
+            ])),
+            Felt::new(BigInteger320([
+                0xa427642bbdc02fe7,
+                0x035627d0619126ed,
+                0xdbb937990160974e,
+                0x2c38f62c1276269b,
+                0x00000206e1eeb219,
+            ])),
+            Felt::new(BigInteger320([
+                0xc80734211717b595,
+                0xb7094b7c53e261ef,
+                0xd97e7bb767f6deb9,
+                0xa489f9b0abee9215,
+                0x000003a8acb3dd2c,
+            ])),
+            Felt::new(BigInteger320([
+                0x0fa9b14a1ebcdb5d,
+                0x781738956a890ae2,
+                0x7dd12764ad12d5ce,
+                0x0197d6a11230ebf9,
+                0x00000371eb066b77,
+            ])),
+            Felt::new(BigInteger320([
+                0x0a981b14af7407b0,
+                0x6fc24f1853e32142,
+                0x1c61785ad54b81b3,
+                0xec7b802830c58079,
+                0x000001733d1d7610,
+            ])),
+            Felt::new(BigInteger320([
+                0xabc82eb2cf5e1d0a,
+                0xf9bfb0eb2aa80825,
+                0x85ec0a1911bdb150,
+                0xa85c96df31dcec95,
+                0x000001905e8e93b9,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0xe38be1d7965334e1,
+            0x6fa384b55b2d9d74,
+            0xeb38d23bd150ad39,
+            0x8aa6ea900774819a,
+            0x00000290c2aff55c,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xd55c258951522f96,
+            0xbbb9245017737b28,
+            0x5f301f427d7d53f9,
+            0xbbfa90455b34bb57,
+            0x0000030649e43fa7,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xea3c05ee8db53d4d,
+            0x6c2f86f2463d2479,
+            0xa4b8c672be042d5b,
+            0xafea0e9b9f8480df,
+            0x00000197087b04d7,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xd81596ef257a5c59,
+            0x76266871d9efbc1e,
+            0x0149288b4142a52d,
+            0x8efa14713a312535,
+            0x0000022839271120,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xff64daa31b4a69ee,
+            0xb358ddac20bb77e9,
+            0x3863a7dc73fe7f16,
+            0xd49e406eef433c34,
+            0x000003286cfd52ed,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x912c10052451d45e,
+            0xa011e9ad5b0d9d3a,
+            0xf98fe42922f7d822,
+            0x5343b340338e4a32,
+            0x0000014bd9d0b36a,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x57f5017f73260e58,
+            0x75208e9ac35e9605,
+            0xf0f5c517a0dc85ee,
+            0x6c05689f9dff668b,
+            0x0000021b83982139,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x5c007be36cfda786,
+            0xae5709b94493588f,
+            0xf3f0a6c3e1199b19,
+            0xad0bcda2adc9a6de,
+            0x0000018c9125e742,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x547e96e219249b72,
+            0xe2dfb8535ca9cb81,
+            0xed84cdd2c7db5ed3,
+            0x811cdc7e46631a82,
+            0x000002558e60b237,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x6c5166f26aa80e22,
+            0x2a1c4eb9becd4296,
+            0x41e19a0a5c77a550,
+            0xc150199e5a672a8e,
+            0x000002927b8c14c5,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger320([
+                0x6746ab21e10c4cd6,
+                0xb4a0ccfcd64ffd88,
+                0xbdb81a04f099f5f2,
+                0x8f1db721fb972530,
+                0x000001450ffaa08b,
+            ])),
+            Felt::new(BigInteger320([
+                0x9b3dc66fdef04edc,
+                0xdfa5804c288a74c7,
+                0xc4071e158f739b56,
+                0x31ad7a4f05842c0a,
+                0x0000017887240813,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x6d6cdcd98acf26b1,
+                0x746fc180f54a5ec1,
+                0x45251587ae2bcc19,
+                0x73992ea030b2ef5e,
+                0x000002c1d2004738,
+            ])),
+            Felt::new(BigInteger320([
+                0x6304fe1d324cd0ff,
+                0x4ebafa0e21a2cc1d,
+                0xf46354f34be9f6bd,
+                0x3d3e5bbede52ba92,
+                0x0000013e4fbd7ca1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xd0d73316404339d7,
+                0x17678de4b7a943d3,
+                0xbf29ed7a85582b90,
+                0x446ba2162fbfbc73,
+                0x000000af5f74b357,
+            ])),
+            Felt::new(BigInteger320([
+                0xb53c4129e9f1f276,
+                0x347e3d332cde2d21,
+                0x7a496222fdfa2fab,
+                0x16e34fbe821da8a1,
+                0x000003455593c3c4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xcc11dfe6ac26176f,
+                0x677dcd0718f380a9,
+                0x8452270540cc9918,
+                0xff69754eb268274d,
+                0x0000039610a39cdb,
+            ])),
+            Felt::new(BigInteger320([
+                0x3a55158c5c96444a,
+                0x8a409044d7cbf0f6,
+                0x110d0c073becd43d,
+                0x6b5ff4b292ca167b,
+                0x000002a9e8325a1f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xb15b9acf61d78b83,
+                0x004c01c0b2efe0f8,
+                0xb16b7be73372b400,
+                0xd6b3d98b17991efb,
+                0x000002e9a16a2f30,
+            ])),
+            Felt::new(BigInteger320([
+                0x75ee4f328d12f08c,
+                0x19d1b98a0f1f3d9c,
+                0xebb0817bb91ac91f,
+                0xa4e0c23cb2f44080,
+                0x000001ee85f71f62,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x2963c2c0f07a3b19,
+                0x8b8a2fa1879083f6,
+                0x3b1552ca110f92ee,
+                0x67e8159ea27672f6,
+                0x0000009e3a393608,
+            ])),
+            Felt::new(BigInteger320([
+                0xe5d8fd1bad61eb25,
+                0xfa24f26481169975,
+                0x85d37fb3bd3a6673,
+                0xfb7d5237e5b96c4e,
+                0x000003acf298cda9,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0xbcef2bca72f80da1,
+            0x84d03cd1685cb002,
+            0x94c2e2830bcb21cd,
+            0xf65ceceddd41a696,
+            0x0000024733702b31,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x4b5b149d0b23c99a,
+            0x42f2a6156bf73566,
+            0xdbf746e21a0102b5,
+            0x3f660ccf384dbe7e,
+            0x000002354cecda6d,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x07ca7fc8eaf16bf6,
+            0x6fe554e2af7e1827,
+            0x151cd58190863600,
+            0xb0a489aaf876d7a2,
+            0x00000384ce589a31,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x69895aa4c77364a4,
+            0xa13b0d39f5f5c239,
+            0x4335858f7b8418f5,
+            0xb91bd08fd826f6a1,
+            0x000000de43b8adca,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x13872665aff3e59e,
+            0xab2df70f6be55670,
+            0x96a2142a36042e1e,
+            0xe25ac370c139e8a2,
+            0x00000374d727310e,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x853ba0bba2dd541a,
+            0x3562dff2984f09f8,
+            0x3b9f92868078dca4,
+            0xa6e35067c46f1ede,
+            0x0000021b2357a463,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xa8bc13303349a6ca,
+            0xd5a83ea38027a56b,
+            0x89574ccfb0952fd4,
+            0x3d47ec573cd62050,
+            0x0000013376968bc9,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x3b602ebf046febfe,
+            0xc07da7775ed69499,
+            0x9adce80c590d5f0c,
+            0xca5639948fb62c72,
+            0x000000f3408d3758,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xe5f7ec6f5f400133,
+            0x2255ac324de0453b,
+            0x89d5027eee3125d1,
+            0xb1bcb1493a45310e,
+            0x000000f928b8d436,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x81063896b75857c0,
+            0x20ac3df41a44330f,
+            0xa8e75dca57e2ec9b,
+            0x204a0314ed448fa8,
+            0x00000286a56b4384,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+}
@@ -0,0 +1,38 @@
+use super::BigInteger320;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [  1  17]
+/// [ 17 290]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; (NUM_COLUMNS + 1) * (NUM_COLUMNS + 1)] = [
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x259ae5b7c4d1ca15,
+        0xbc20e3dfe73f0ac3,
+        0x97505c422d1f08e7,
+        0x49d149cf165e1b2c,
+        0x000003a87fe6a0cc,
+    ])),
+    Felt::new(BigInteger320([
+        0x259ae5b7c4d1ca15,
+        0xbc20e3dfe73f0ac3,
+        0x97505c422d1f08e7,
+        0x49d149cf165e1b2c,
+        0x000003a87fe6a0cc,
+    ])),
+    Felt::new(BigInteger320([
+        0x3e321fade28befb0,
+        0x0bc582b0dbc555ab,
+        0x585a4652f9c40316,
+        0x4735b8e0be058212,
+        0x000000652d03c5a6,
+    ])),
+];
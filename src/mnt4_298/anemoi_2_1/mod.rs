@@ -0,0 +1,654 @@
+use super::{sbox, BigInteger320, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 76 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (38-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 18 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 18;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x6c1c83f744d6e116,
+                    0x8bed50023805a498,
+                    0xe6858ac6171164f8,
+                    0xbf82ab2f9fabdbc2,
+                    0x000000f465a4ceff,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2d5848e18f5ccce,
+                    0x850c7f332f11454b,
+                    0x5fcf4ceba0ba6b59,
+                    0xeb00f01d93051f30,
+                    0x0000009a66974be9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf2263cff53183722,
+                    0x9697f1424b51d6d9,
+                    0xf69a385453c348d6,
+                    0x6fe05a2dc5cb76d9,
+                    0x000000ee3495879f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x235f573acf3a65ac,
+                    0xed4e4bbf09fe90d4,
+                    0xb8072118a78b9fce,
+                    0xa7ca36e42682a111,
+                    0x000000b2986c2193,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfee46bd44ace645b,
+                    0x2c6a61ebe3bae401,
+                    0x29b200c536396625,
+                    0x4706d67100d06523,
+                    0x000001164b17a1c0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ba085e1b16b5a59,
+                    0xe46e4047b18c077c,
+                    0x4169fca9669a49b5,
+                    0x5d5660c3b545df29,
+                    0x00000268d1fe4071,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9c636b9f2b5886e6,
+                    0xc13c505e52fca0a8,
+                    0x027fb46199a9fc2f,
+                    0x6fa0fc8d2369bc0c,
+                    0x0000038b0efad68f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e34eb55cf418933,
+                    0xf2442c9691446d57,
+                    0x78d18e17b42a284f,
+                    0xab96bcee6a8420cd,
+                    0x000001de551b8d35,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xed186da2265a908b,
+                    0x2b10a46fb41d06b0,
+                    0x615457d614b85bdf,
+                    0x8078db4dcf0c6f85,
+                    0x00000235aaa283e5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bd9714e8eed39fc,
+                    0x2dcd78b9a672cd5a,
+                    0xf1dc92d83118286f,
+                    0x32a61f5886a880e5,
+                    0x00000174a96627c7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9118079cba32ed7e,
+                    0xcf1fcf32dae2ab98,
+                    0x433ea30c008f898c,
+                    0x44de58df41868495,
+                    0x0000018ff6251743,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90cbf741edc4e150,
+                    0x32d2961ef6a51c5d,
+                    0x4191ade45c51e9e0,
+                    0x897a05e38d73a152,
+                    0x000001e21901f4e1,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdaf3c3b7216b1c18,
+                    0xfd9dedb8d61c6907,
+                    0x2c9e1e5c24ed1d3c,
+                    0xbe95e5c903bf6e43,
+                    0x0000033a928e74d0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41955e699662925e,
+                    0x028c20bf4ddcfaaa,
+                    0xd71e60b157070acb,
+                    0xc63b8cf86665ea68,
+                    0x00000010003c53a5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8598a4a703517651,
+                    0x79088b17ebc792a8,
+                    0x142bad7635ea8ae0,
+                    0xd1709979a0b685ff,
+                    0x000000ed9efa2315,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d3986aca1e97727,
+                    0xa3fb8e90ab970dcd,
+                    0xdaa9dd2358c53eef,
+                    0xe5fa9eed6b088dce,
+                    0x00000283212159f8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x29f82cd462647ddb,
+                    0xc15b421e16b98555,
+                    0x44e56962609b8cfb,
+                    0xf545ab7df9d13949,
+                    0x000003442071a353,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeaac6e89e148eca5,
+                    0xe5754f914885205d,
+                    0xbad447d07e831b82,
+                    0xea4dd811adade128,
+                    0x000001422d70b4f9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x072b26210f382007,
+                    0x0260cbf13d25ba5d,
+                    0xdec86ad4cedd601d,
+                    0xa7a37ad26b551e3b,
+                    0x00000194bba52887,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f516a5090932472,
+                    0x94133efb2a40d046,
+                    0x449716e9aece4e2b,
+                    0x3e7ec31214e2e04c,
+                    0x00000363b794300a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x427e219218c89bbd,
+                    0x96763fc5024d94ce,
+                    0xad28dc416499d915,
+                    0x1b178932fe658f54,
+                    0x000000957cc62852,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f4ac19116bf2da6,
+                    0x22c1491f93bd43af,
+                    0x3e7475165d6281f0,
+                    0x010eeff2e768cb0c,
+                    0x00000022485c9505,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd10ebe35a4e0f46f,
+                    0xfb0532f3abbcfb85,
+                    0xfef2ec8bfe45c11e,
+                    0xe051b2b9ab4d6617,
+                    0x000003671428a423,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03a5adb37795543f,
+                    0xa2c7932f8087d68c,
+                    0x9c1224cc64f1dae9,
+                    0x9e2112b8858c06ff,
+                    0x000000265217106a,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xcfa83810ed107364,
+                    0x7d0fe86fe80b516c,
+                    0x0a43264fb6696350,
+                    0xadd8a6a877776d74,
+                    0x0000009ee273efe3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc8bd307d5c38078,
+                    0x91c557a9551616a7,
+                    0xcf4b101d3c3dedd6,
+                    0x75f85d2a48611b26,
+                    0x00000366ce9b16d3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0acffdb51c737fa4,
+                    0xb17f66e53b3ab61f,
+                    0x3725e9949da647bd,
+                    0x5f5214127ff43505,
+                    0x0000015c2e115990,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3751c7cbf0ae6e9,
+                    0x38962000ee7c536e,
+                    0x9b6d8bf21aa665eb,
+                    0xa3aeb5643d91cc6c,
+                    0x000002a01ca50bb1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2be65bc85e654a18,
+                    0x3a62d61a858283e5,
+                    0x36a88ff4c17d5273,
+                    0xbfef094d54e08781,
+                    0x00000169d9149b7f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91749c42db15cdb9,
+                    0xd586e2b64c56260b,
+                    0x7852e302071d7765,
+                    0x37262939ff656ad7,
+                    0x0000035ef6a668a4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xee486a5dfb9f0535,
+                    0x075756047c76301c,
+                    0xec7272b6320c877f,
+                    0x80c20fba9a63a9ea,
+                    0x0000017e8b5f142a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74addc0023cf9d74,
+                    0x998d87d573aac189,
+                    0x5059ee24f310f261,
+                    0x0de071123d6553d8,
+                    0x000000e1cf7236ef,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa557fbaeeb8919c7,
+                    0x42c96a6628957bb6,
+                    0x545e594f59e20c92,
+                    0x2eba6db56629dff4,
+                    0x00000241c37800b5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff50885da1febf68,
+                    0x8dcfbe12107e69bf,
+                    0x2cc8cc664acc482e,
+                    0xa667af7d2fb53b88,
+                    0x000001773a4874db,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8f2bceaf2fb89fa6,
+                    0x0f7c5adbc2ec9210,
+                    0xd715aae04c94a6fa,
+                    0x6b352cb59e29e801,
+                    0x0000012d04ecb22c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9017ed9e28288380,
+                    0xea466a521a834b5d,
+                    0xc8e8d2f7dca65c6f,
+                    0x1bceb1100bd77bb8,
+                    0x000001c572718f78,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x755129d8abcf4e70,
+                    0x64557a9779d58aa5,
+                    0x92411865f906833e,
+                    0xd08365c9d3291835,
+                    0x000001acaa67001b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ac02f0b35f7b9c4,
+                    0x863c833271e4525b,
+                    0x24bbd931c9cfdb13,
+                    0xeea0de122cb348b0,
+                    0x00000050b52d91fe,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x259ae5b7c4d1ca15,
+                    0xbc20e3dfe73f0ac3,
+                    0x97505c422d1f08e7,
+                    0x49d149cf165e1b2c,
+                    0x000003a87fe6a0cc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e321fade28befb0,
+                    0x0bc582b0dbc555ab,
+                    0x585a4652f9c40316,
+                    0x4735b8e0be058212,
+                    0x000000652d03c5a6,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger320([
+                    0x259ae5b7c4d1ca15,
+                    0xbc20e3dfe73f0ac3,
+                    0x97505c422d1f08e7,
+                    0x49d149cf165e1b2c,
+                    0x000003a87fe6a0cc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8d30ac497a11fb4d,
+                    0x52366827cda1ff76,
+                    0xea416b6e426650d6,
+                    0xff5ab13a729546a9,
+                    0x0000026016b00127,
+                ])),
+                Felt::new(BigInteger320([
+                    0x793a0e061193308a,
+                    0x35157d40807772b8,
+                    0xa5608b2aa5363201,
+                    0x709a8f45afb72a4c,
+                    0x000002a9ab2e0983,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x78fcd5c07b64d511,
+                    0x5f80de8943fb9796,
+                    0x063ac40005ccc161,
+                    0xa1226856ba5ed18a,
+                    0x00000122782d2af0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbeff1e80b9bf0d05,
+                    0x47d37646dc911e0e,
+                    0x0eaaa101abdf48cf,
+                    0x2af8ec12576c0fcd,
+                    0x0000033940f4bf6a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0ce42cec45ddf352,
+                    0x8f6391aed65a3790,
+                    0x502bd644c652627c,
+                    0xe57ba0ea7747ab72,
+                    0x000002a5b6111ba0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00018bb62e0af61f,
+                    0x472ce6e6bad32dbb,
+                    0x480aacead5afb6b0,
+                    0xd79221680ee3d6bd,
+                    0x0000038470ef48e7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x89a0acf696d079e5,
+                    0x3315cdb7e64cd1f2,
+                    0x16b01d9ce1dfdc51,
+                    0xe310ff7a0f5ce90d,
+                    0x000001897301683b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18ff7be70dddb5a2,
+                    0x325af97786e45021,
+                    0x458463d867683d5e,
+                    0xb13667ddacbe7dcf,
+                    0x000000d7ac6053bf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1a572d6d93a9cfa8,
+                    0xe1efd205091a1ef3,
+                    0xc12c6a62c5ec8141,
+                    0xcad214b5fb608417,
+                    0x00000101dc1df421,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ae7333aaaad898c,
+                    0x881522ee66ba3f99,
+                    0xd500b767fb3419e5,
+                    0x94c27d1c425caee0,
+                    0x000003a2f952594b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd85be35c4f395b1e,
+                    0x90da4f5cfca422b0,
+                    0xd715623b0b074316,
+                    0x30bdc9d929f67ad0,
+                    0x00000161e0909589,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37e500a0c2939078,
+                    0xfeca5b48faa944ab,
+                    0x4fbba08f69aeab71,
+                    0x8a043cc9e713a83e,
+                    0x000002d78d9e82de,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
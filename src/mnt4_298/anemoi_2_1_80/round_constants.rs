@@ -0,0 +1,179 @@
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger320([
+        0xbc31409cbfa8a653,
+        0xef892a90c139d0f1,
+        0xfe2bce26405b3feb,
+        0xc8007482cc4d18c4,
+        0x0000009f75ee5f8c,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x67c57ebe0163df1e,
+        0xa499bc2f7af4f8a7,
+        0x0eeb36dceedbfc8a,
+        0x5c131f17645e24e6,
+        0x000002cca985720b,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xa39596a5eacd6039,
+        0xbf9efac152d0f74f,
+        0x7a9ef4021cabb164,
+        0x0485a9169b4688b7,
+        0x00000131c9bef086,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xd9686a20883264c6,
+        0xcb364b80a5e32921,
+        0xc1f506639babe13d,
+        0x8631dd213eb4a0b5,
+        0x000001289fb515bb,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x07c356323e6b062d,
+        0x37cfb1f5d30ec891,
+        0x49ca94414570cedb,
+        0x00d756ded3eabad3,
+        0x00000189447cdc8e,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x815603bac05f14c3,
+        0x886ba6c2528f61e5,
+        0x10b244c3133be733,
+        0xe8a8100725b6a634,
+        0x000000f52758f679,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x138e2d62422d4b38,
+        0x0663af85aa28707b,
+        0xd9048e417d4f59c3,
+        0xbddcd63a4d0bfedc,
+        0x000002648deb0190,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x16eec3bfec6f6119,
+        0x39b3f3f6d8378c2d,
+        0x96d49382d1244ee6,
+        0xe47cb012b2593d06,
+        0x000000a6726efcd9,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x93f1a3384504710d,
+        0xb3928d3e8e414e60,
+        0x34ecb6299cda21b7,
+        0x33b06d157f549e8f,
+        0x000001742ffa53cb,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x4183873715e1116c,
+        0xedd77cba1b95c656,
+        0xf46d98d543108b94,
+        0xf358d321e2c2b8ea,
+        0x000000ce50f0709c,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x7fd46be2304b8d94,
+        0x5aa6e0f69a7a6293,
+        0xd04c71adbd40fdae,
+        0x718f2dc8d9d95fa3,
+        0x000002a31a0bbf63,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x305dd762d986b647,
+        0xb91959936c50230f,
+        0x04e008053eacee9e,
+        0x65b779e123187945,
+        0x00000098b4c39249,
+    ]))],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger320([
+        0x33e9ed6fcb53ea59,
+        0x6f366c32ef485a0b,
+        0x6310b5e11f29fd5b,
+        0x3901bb069e439a9a,
+        0x000002eccd701bcd,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xa788530d49ae4543,
+        0x7ca3bfad7152a554,
+        0x7323845e93f23e6a,
+        0x14e27d4b7e3adeee,
+        0x000001fb4480104e,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xd8dac9fd19dbbc98,
+        0xd4d00b5d86cc3f33,
+        0x7fe17752d03b2658,
+        0x1c0d2028e675e090,
+        0x000001ec28a7888f,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xf6172ceb3cb9bc57,
+        0x518132926f302dad,
+        0xca925f2611188f7c,
+        0xfb86ff45250b2df9,
+        0x0000010733fb4174,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x725b1557ea42b3c8,
+        0xbc91c8c3de561061,
+        0x6a64e55d27efd2fc,
+        0x653df4b9bf95e7f1,
+        0x000000825ff9bd92,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xcbb82b49d1bbd033,
+        0xfcddd29824e0c84f,
+        0x0ab488b65da97923,
+        0x5e04dd074f0c0dd3,
+        0x0000014fac6644dd,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xf7c37372082cc081,
+        0xdf326d83c22e4247,
+        0x703253800a59e210,
+        0x91eb16f3b36e18d1,
+        0x000001f73296d1a3,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x0929ae3509af6c99,
+        0x253c646854a2968b,
+        0xec9b4d6a2f500bf8,
+        0x6b0eaed9af67a4e3,
+        0x000000cc148a694b,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x4c6c5b0e34234aee,
+        0x8382cb9cf24f4528,
+        0x824a7baa19d92e7c,
+        0x546c851f0702ffa1,
+        0x00000277ec36dc42,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xfc56e3996898d744,
+        0x4a38a76ca9441b63,
+        0x52d14bebd5f7b759,
+        0x3f2abcf7ab3b4538,
+        0x000000e95d67673c,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x7ce709ac553b6e88,
+        0x679ebf7b1cc96e29,
+        0x9f6b8378feec7324,
+        0x67fbb13867bc9e03,
+        0x000001e784140559,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x549722d2f1dbd5ef,
+        0x2c66a2183a9a6cfd,
+        0xbc016f6618b63ffa,
+        0x13ea4236b415be80,
+        0x0000017c9b9033d3,
+    ]))],
+];
@@ -0,0 +1,881 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger320, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![Felt::new(BigInteger320([
+            0x12cc74145f0f3fca,
+            0xd0a671489f489c65,
+            0x0dce46995e549db2,
+            0x7e694a26fd113d47,
+            0x0000026a57bbe274,
+        ]))],
+        vec![
+            Felt::new(BigInteger320([
+                0x51f88be6fd9cb5a6,
+                0x8bb9a0dbfffaa03d,
+                0x169a3d17beb3f538,
+                0x45eaabcaf1fbd41f,
+                0x0000020258fb40e7,
+            ])),
+            Felt::new(BigInteger320([
+                0x0aac9cf9632dafec,
+                0xe7d815426cb43412,
+                0xf2475b90f9a04504,
+                0xc6206d24eb4b3bab,
+                0x000001018a72b05b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xadbaa064566afedb,
+                0xec68ce284070ecfd,
+                0xf1dd96114a5f50bd,
+                0xd8c8d28f37db4e89,
+                0x00000331a04d1b96,
+            ])),
+            Felt::new(BigInteger320([
+                0xf4c40c1fe9f8799a,
+                0x6c7d1151042823ea,
+                0x9461a41096280377,
+                0x094575dbe41d9ddc,
+                0x0000012be32eec14,
+            ])),
+            Felt::new(BigInteger320([
+                0x94df3e471d6451f6,
+                0x7e2f8d3bc0850576,
+                0xf97e63e1f51cd2c5,
+                0x1637855afe6d3253,
+                0x000000ab1f6d95e6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x9ad1b79d982b253f,
+                0x61d013ab374d7070,
+                0x40a818beb3bf9c29,
+                0xf22ea6914f268cac,
+                0x0000009e4bf2907a,
+            ])),
+            Felt::new(BigInteger320([
+                0xe69ab55bcd4ea6de,
+                0x558726147afd0775,
+                0x153fd8469f4073b4,
+                0x2c28d12ea2ee5cc3,
+                0x00000392ff390c91,
+            ])),
+            Felt::new(BigInteger320([
+                0x5ca36f59a8a4a411,
+                0xd169a8505b00dc8b,
+                0x1c56a96eea6c229b,
+                0x15c538175ba9388e,
+                0x000001a3ab4891df,
+            ])),
+            Felt::new(BigInteger320([
+                0x2a279c428625d09f,
+                0x83c22113e36c8f11,
+                0x445cb12f49ce08f6,
+                0x6d7c45abdd66dec8,
+                0x000002e0b309d42c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x806de34098e43c2d,
+                0x7f64e261f1c8c7ad,
+                0x4487a8236adda4a8,
+                0x2ad45ef295b684b0,
+                0x000002201cf92b26,
+            ])),
+            Felt::new(BigInteger320([
+                0x1671b6cf53ebcdfa,
+                0xf7035858daf993fd,
+                0xa8ed7429d31c891e,
+                0x23fdffbc881a3707,
+                0x000001c9ecae3562,
+            ])),
+            Felt::new(BigInteger320([
+                0xadfa2c7ab9b0ff58,
+                0x72db889e3b6dd9d8,
+                0xb0852f13b6eee0e1,
+                0xd7ef4f73292693b2,
+                0x0000027648bf54a2,
+            ])),
+            Felt::new(BigInteger320([
+                0x878adc5c8f371258,
+                0xd4294a7b4ae59918,
+                0x52216b70e50fc994,
+                0xb62ed5ef13844499,
+                0x000001e3cce0912f,
+            ])),
+            Felt::new(BigInteger320([
+                0x69e82f1346abfb75,
+                0xcc7ea987dafbc1e7,
+                0x1662a35b075abeb1,
+                0x4920cdda7c124c99,
+                0x000000f2a7f6648b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x5153e936180d0243,
+                0xfd3b955c3b5f0af5,
+                0x2f54ff508b7fc727,
+                0x96b21de2ef12960d,
+                0x000000c267c139b6,
+            ])),
+            Felt::new(BigInteger320([
+                0x5c3e661f70ee6e8e,
+                0x2baae53cc3eaee4d,
+                0x847af28bb33d0bfc,
+                0x56229ea7d8ce5206,
+                0x00000315a75a7b4f,
+            ])),
+            Felt::new(BigInteger320([
+                0x9e50dae0c10fc156,
+                0x547f62f18078069b,
+                0x14d0795d66ac5a50,
+                0x277b7887c3346f75,
+                0x0000008392f46765,
+            ])),
+            Felt::new(BigInteger320([
+                0x5657c66c393dcbd6,
+                0x985665ebcabb8e5f,
+                0x183c4bd71a46ae72,
+                0xc9b77103336caee2,
+                0x000002cca92486c5,
+            ])),
+            Felt::new(BigInteger320([
+                0x368e00cfc446d371,
+                0x2e1a14b08d3f5cf7,
+                0xb958dae7c8109923,
+                0x8235b2b877166403,
+                0x00000020eef39fd1,
+            ])),
+            Felt::new(BigInteger320([
+                0x41760869eaf9f811,
+                0x61866e30f4803e52,
+                0xca3e243dace50ef0,
+                0x9bf54496915df73f,
+                0x0000006820735735,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x335cd441608f65a4,
+            0x17c01842585ed91b,
+            0x9cf618f795b8c04e,
+            0xbf3db368509c75e5,
+            0x00000070e178b93a,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xb5cea57dae19a8fd,
+            0x0e65e81ae94bef9a,
+            0x3adabf93b216063c,
+            0xd19bbbec2b5368c2,
+            0x000002ac3b226216,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xba891f21441d5c04,
+            0x2216b0dca01c1761,
+            0x31606a1c3c3a0ff4,
+            0x899f8e43512e05ba,
+            0x000002b6bd853861,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x2670a09b88f8e85f,
+            0x308fb9bca98b8852,
+            0x2c1eefac0a5b606e,
+            0x5bf4204eff1a9cb7,
+            0x0000016097ba0383,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x78350ca9f24e7c30,
+            0xe541b57279bdcb97,
+            0xcf4edda2fc9485d1,
+            0x0d2e7ee9b5d79127,
+            0x000002d9a8cb3a69,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x79f1d094b666cc71,
+            0x51d76ac94ba35739,
+            0xb71e7bb0c99a9562,
+            0x5b1a7f495fd1687c,
+            0x000001848abed5b6,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x3d1e57c82a3d70da,
+            0x342797a82ec8eb36,
+            0x1db3d517963571a9,
+            0x76482d05fcb4f501,
+            0x00000361df93b334,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xc30bf4c93a728d00,
+            0x1b09f865bd4c9f00,
+            0x85a87dee927d7177,
+            0xf21c728cd4c21f45,
+            0x000002c8e8cefcae,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x26097c45738f5ea2,
+            0xf224d231d7438a23,
+            0xbb12a77e1537740a,
+            0x1a0b95f8e278abcc,
+            0x00000282027b736b,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x280e191f528359b4,
+            0x79e2e1f81db7556e,
+            0x3552825f6b197346,
+            0x12569a0059386e13,
+            0x00000084c1708148,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger320([
+                0x5280db2357a7db90,
+                0xb8ce4cb7af0c7af5,
+                0xdf8151337fee23b0,
+                0x43a78d2fffcab767,
+                0x000000662188003d,
+            ])),
+            Felt::new(BigInteger320([
+                0xa85c2140c5a30aef,
+                0xeea74e89424636c3,
+                0xf7ea70b09b695037,
+                0x679ce850133519dd,
+                0x0000024c3726ed2a,
+            ])),
+            Felt::new(BigInteger320([
+                0x523c8a6a62e87aa6,
+                0xcdb7477427475528,
+                0xf554225756ce3714,
+                0x833eec7fb5099cad,
+                0x000000488f81f419,
+            ])),
+            Felt::new(BigInteger320([
+                0x0bd6f865f4f3cca6,
+                0xc508f901d81d1d08,
+                0x26e9d694824046e8,
+                0xab96056abea329f8,
+                0x00000257247bdb4c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x8d5e9fb43cfeb914,
+                0x2333be6b19fa44c2,
+                0x4e0495ec77d913d3,
+                0x494f1bc4002a955d,
+                0x0000023a0528111a,
+            ])),
+            Felt::new(BigInteger320([
+                0x55923b57c737d97c,
+                0x5d67a7551b292768,
+                0x6a9d605003109976,
+                0x23fcba2023b0987d,
+                0x000003a63496e033,
+            ])),
+            Felt::new(BigInteger320([
+                0xa34706107c6bc82b,
+                0x5bdde44a88876d8e,
+                0xf0a617a8dc689d79,
+                0x8202743f2700da62,
+                0x000001bf3957d077,
+            ])),
+            Felt::new(BigInteger320([
+                0x77382fc462cfcd03,
+                0xa737e3e1f1e0839d,
+                0x754887fb19f871fb,
+                0x81f772d998418418,
+                0x000003911e4e48fa,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x4725a4ae079bee71,
+                0x1c7607d16b40df6b,
+                0x38d04f42fd5cc317,
+                0x5928f8351a12e1e0,
+                0x00000121aa2c5f97,
+            ])),
+            Felt::new(BigInteger320([
+                0x46caeb8fd4aadb15,
+                0x19d67c83195e45df,
+                0x7291ff663ac28216,
+                0xc9b9e52994358f25,
+                0x00000214190999fd,
+            ])),
+            Felt::new(BigInteger320([
+                0xfab535fda8dadc86,
+                0xe4207be989ed0441,
+                0x7a71e340fd25864f,
+                0xc0c3cb092dfb9d12,
+                0x000001a998b62936,
+            ])),
+            Felt::new(BigInteger320([
+                0x7a59f197078f2854,
+                0x2b6cbd894092e24a,
+                0xd946cce30655e219,
+                0x8e9e07337b46ce58,
+                0x00000200e663bfc3,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xd8fe378ead54a3d7,
+                0xfca021f18f20a727,
+                0x35373f3ad2aedee3,
+                0x2d4819b7e157e56f,
+                0x000003212e0102ff,
+            ])),
+            Felt::new(BigInteger320([
+                0xdb17a3103c2f7d03,
+                0x185c15064ea6be11,
+                0x85e524af77eadf6b,
+                0x4e9da01a547d5ad6,
+                0x000000176f399c6a,
+            ])),
+            Felt::new(BigInteger320([
+                0x040400edf436107c,
+                0xe29cf559f035bcd7,
+                0xa3d62328c6fba621,
+                0x0076218d9f68c7b1,
+                0x0000037a7eeba65c,
+            ])),
+            Felt::new(BigInteger320([
+                0x53f75e3eebc39a98,
+                0x5fd03723235b8d1a,
+                0xc3d7ae481114809f,
+                0x456b20fb77814383,
+                0x0000028e705e2c8f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x79c2be892d71f212,
+                0x7b7896346d177e53,
+                0xd09b07d945439d7b,
+                0x15bc526b7fc77e4f,
+                0x000001ba203b7a1f,
+            ])),
+            Felt::new(BigInteger320([
+                0x367ef2a7f141d299,
+                0x9715f74b2b2750b6,
+                0x023193441c3a0a6a,
+                0x350b7ba18ba8f5cb,
+                0x00000055d23f808e,
+            ])),
+            Felt::new(BigInteger320([
+                0x6fbb66fc2b6f357f,
+                0x47828093bc015e02,
+                0x528dd20ed00ecfc2,
+                0xe7335e63fd3a3f8d,
+                0x00000361bc502bf9,
+            ])),
+            Felt::new(BigInteger320([
+                0x2a0f57b71ca6d346,
+                0x54551a7d5060e554,
+                0x13a4cd562ae596b3,
+                0xd977ac069f249988,
+                0x00000364bf45739a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x98413e99bdede9ce,
+                0x71eadef876ae33fd,
+                0x8a569a5a1039adaa,
+                0xb730663ab444d5c4,
+                0x00000064af61d3f8,
+            ])),
+            Felt::new(BigInteger320([
+                0x2039d5a7899b5877,
+                0x9168ed1cde8524ca,
+                0x6b393929955e2ede,
+                0x8be983f44be12afa,
+                0x0000019ad1d02a32,
+            ])),
+            Felt::new(BigInteger320([
+                0x7eef4bf48fb9df2f,
+                0x7532bcb9f4921510,
+                0x3a322630244bde9e,
+                0xd4fc07fdfd3b1b58,
+                0x00000350c4638f81,
+            ])),
+            Felt::new(BigInteger320([
+                0x24883b77c2bdd5dd,
+                0x83e0e964e49846de,
+                0x90d6e8b3a2bcddb2,
+                0xb7d2ed80dd92cdd9,
+                0x00000336f0a7af92,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger320([
+                0x75c456a9619f2253,
+                0x5c1ffe5e682ddf94,
+                0x0f65cfd2e995f278,
+                0x7e36d6ee44972a27,
+                0x00000255594f6091,
+            ])),
+            Felt::new(BigInteger320([
+                0x8c60b3ad8c47268c,
+                0xe7c8d31dd17379e7,
+                0x5c58eaca25b0cdad,
+                0xc44eae8fbfef8525,
+                0x000000fbcc0fdd50,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x95cd30b314ff3cf0,
+                0xff27d28563465ac0,
+                0xed5e738a2b80b6a5,
+                0x06da9115a1d2b484,
+                0x00000311d99434e1,
+            ])),
+            Felt::new(BigInteger320([
+                0xba6f9b65710cc408,
+                0xd6e2272030b8316f,
+                0x81d8f9f9496a8b3a,
+                0xc13e2b8be103f266,
+                0x00000165bad470fb,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xb962af02a1512910,
+                0x7aa6a4d094280d34,
+                0xfc3bb0ef8471e681,
+                0xc5116416f4a720bf,
+                0x000003b223253a3f,
+            ])),
+            Felt::new(BigInteger320([
+                0xbb38ceb4e049d016,
+                0xfc5c70d5b783fbcd,
+                0x801eff803fa6a2c7,
+                0xf762fad16e8b9758,
+                0x00000369de398683,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xa60db9dbc40f6666,
+                0x653e757bee4d7b3c,
+                0x4dc1a0d8817c19a1,
+                0x79d7e69731ba175f,
+                0x0000014b5c3aeb0c,
+            ])),
+            Felt::new(BigInteger320([
+                0xe5680b2701a90730,
+                0x5273589888b84cea,
+                0x6dc4235aa55a8e4f,
+                0x301dedf570f98551,
+                0x000001f7a3719bfa,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xec6f588f78c3add9,
+                0x5a5704fb20d53314,
+                0xa84de5e38b1f9853,
+                0xfccb5928faa697d6,
+                0x00000234f9faadad,
+            ])),
+            Felt::new(BigInteger320([
+                0xaf6a647e534307e7,
+                0xebffdbcfc9e0a8bc,
+                0x7d34d6e696a986cb,
+                0x5422efa6daae514c,
+                0x000001ea7684806e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x9946e69bfebc65b6,
+                0x2263c2ba925ce462,
+                0x20bfca855a252856,
+                0x185d47abb8ae907e,
+                0x000002dc050cac71,
+            ])),
+            Felt::new(BigInteger320([
+                0xe424cef3e2c9a030,
+                0x312b899dece8345d,
+                0xd2d15058152ad420,
+                0xc6bcdd12df6588c7,
+                0x000000e7e6318310,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x25a25d897b7b9819,
+                0x290f088920c957b8,
+                0x3b434d3e523ea5d8,
+                0xa6b147dca113d005,
+                0x000003a6b7c59ee1,
+            ])),
+            Felt::new(BigInteger320([
+                0xb1be0e831f7e4860,
+                0x5a38cac5edf5964a,
+                0xd14094a31a9ec61e,
+                0x8070f95085006ba2,
+                0x000000fab7ab4ef0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x807f31777e315de2,
+                0x44a21b2f214e9ab1,
+                0x3fde5101084abbf7,
+                0xe3d918bd04193aa4,
+                0x00000389f14e812e,
+            ])),
+            Felt::new(BigInteger320([
+                0xdf861af5ba136bb9,
+                0xb355911c404cb521,
+                0xc3d5395a9c23dd1a,
+                0x3d8a4e454c8ce113,
+                0x0000019fc0ef2ae5,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xd0833006dc054c09,
+                0xeec489d388eca7da,
+                0x6b75795f452b59d2,
+                0x4dbcf019ad0c3728,
+                0x000000be1e234ffb,
+            ])),
+            Felt::new(BigInteger320([
+                0x2e2e5cccba886f90,
+                0x5f3249419c9f4ddc,
+                0x4d47ae8b9d1219f9,
+                0x3f897f5860925e07,
+                0x000002362de06fed,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xf3f1f0431142679f,
+                0x165480918e1d97e5,
+                0xf76281cc24cb732e,
+                0xdd3ecb04d2faa6e0,
+                0x000001ce57a25651,
+            ])),
+            Felt::new(BigInteger320([
+                0xe0d4ca10965e3577,
+                0x56654f6e3434073e,
+                0x484122dcfe09ba64,
+                0x52065b55da044244,
+                0x000001b3554c7ce4,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger320([
+                0xb995109c2ed48b11,
+                0x36aa2db71ad96928,
+                0x60dbe3a9c6d120ff,
+                0xe373f8c79172b23b,
+                0x000002fa26a79246,
+            ])),
+            Felt::new(BigInteger320([
+                0x6811533613b049f3,
+                0x5cc50a5b658fb130,
+                0x3da74d86bf2bad11,
+                0x42d7b6e7d2c2e341,
+                0x0000028f95edce6e,
+            ])),
+            Felt::new(BigInteger320([
+                0xc55d69ef36e04a3b,
+                0xfe2e1bf87da353d7,
+                0x80c079e4dbf5d130,
+                0xe99718e814dcf0d9,
+                0x000001fca8843954,
+            ])),
+            Felt::new(BigInteger320([
+                0x3b0e9183b8fda62f,
+                0xf06acfba34917772,
+                0x3b609b3b3243cfdd,
+                0xeac9432eb5f5cd0f,
+                0x000002ff2506f761,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x4b19a22151fa23c0,
+                0x29bff1ec9e126d27,
+                0x95022af260315cc5,
+                0xfb1574122d597ac5,
+                0x00000086a28fd92f,
+            ])),
+            Felt::new(BigInteger320([
+                0x15ddc03fe16b09eb,
+                0x6834208eaa156d8f,
+                0x61b953dfa029cbfc,
+                0x2002069b11dd79fd,
+                0x00000123c08989a0,
+            ])),
+            Felt::new(BigInteger320([
+                0xad9b85931f628f66,
+                0x310e85069e569e26,
+                0x813161eb04a983d8,
+                0x1482a549bb26e849,
+                0x000002647c560ce6,
+            ])),
+            Felt::new(BigInteger320([
+                0x32e6638a9b304535,
+                0xfb35f8a4502a10a6,
+                0xfabd561eec266f8b,
+                0x5bfac22adc7869a4,
+                0x000000b4ec30c5e9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x2d9da920de9a1cc1,
+                0xb5ee1c91d2c582b6,
+                0x7bad9005eff1f54a,
+                0x5b56a7d82e50035f,
+                0x00000303ade4cfae,
+            ])),
+            Felt::new(BigInteger320([
+                0x7e20dc5da2415a89,
+                0xdcdd0f099322e690,
+                0x9bb4b56dd4b971c6,
+                0xaf1c992b429ff974,
+                0x000002b28c715132,
+            ])),
+            Felt::new(BigInteger320([
+                0x03de6414ec857adb,
+                0x53759090af2c3ab2,
+                0x00fb866c7a24702c,
+                0xbafe9f18a25b50ba,
+                0x000002d46ebd163b,
+            ])),
+            Felt::new(BigInteger320([
+                0x4c50ccc2c63170f6,
+                0x4067e32c807ca83f,
+                0x0192465840ed00e7,
+                0xd61fb7351619a0c6,
+                0x00000098fc26e60c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x40150c745e1d077e,
+                0xa1a3aeaabe5ac6d5,
+                0xfcb1f981185b0ffa,
+                0xac9c45d93cdc7b9f,
+                0x0000002d1813e769,
+            ])),
+            Felt::new(BigInteger320([
+                0x3c8393ce1fed4a75,
+                0x6cdfc94bf9e225d6,
+                0x085a65221c9d25b1,
+                0xfd7f0cd1e473c6df,
+                0x000001a271b04e96,
+            ])),
+            Felt::new(BigInteger320([
+                0x64de85de1f4da30a,
+                0x12aadaf9d5dec75b,
+                0x9a27db10408eaf22,
+                0xb539484b10f7f475,
+                0x0000016475b5c222,
+            ])),
+            Felt::new(BigInteger320([
+                0xed68b6f9822a90c2,
+                0xd5d163f27cd449f6,
+                0x0c867e4fdca4e1e3,
+                0x15755d4e888503c7,
+                0x00000062df37427b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x23baab6ea260cc98,
+                0x4f6defa668dfb6b4,
+                0xe2b67dabe9a045f9,
+                0xfd06a72f0c45fc6d,
+                0x000002aa736f1aa7,
+            ])),
+            Felt::new(BigInteger320([
+                0x0764add9a88663e1,
+                0x444604c7e81aa624,
+                0x187e1775c40ded38,
+                0xb708c51d26d1fdab,
+                0x000001c4b2fbc5e4,
+            ])),
+            Felt::new(BigInteger320([
+                0x5951f91fe82131f9,
+                0x57f89a5910195bf4,
+                0x3a9313d606b39005,
+                0xa5b17a2dabac51e5,
+                0x0000004bd6ac5a70,
+            ])),
+            Felt::new(BigInteger320([
+                0xbc2c693c6f49a887,
+                0x0537bcd13a5ae64e,
+                0x062740e32e6fa2ff,
+                0x1591882179652a0b,
+                0x00000146ac5e0335,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x8957758750406a0a,
+                0xcf7d328798f6c62f,
+                0xc02692396e216153,
+                0x1837c430eab0e02b,
+                0x000003173b306642,
+            ])),
+            Felt::new(BigInteger320([
+                0x95a71e9835f71ef6,
+                0x05b4221c0f07106d,
+                0xa5d06d19f8873c28,
+                0x4c162c3b3330a684,
+                0x000000fdeaf3aabd,
+            ])),
+            Felt::new(BigInteger320([
+                0x5a7905b2257fda4b,
+                0x392bf9d57144e3e9,
+                0x85f41440a0054c2d,
+                0x19a3d3317d490d2a,
+                0x000000645d9b9d06,
+            ])),
+            Felt::new(BigInteger320([
+                0x49f3985f200b4464,
+                0x3c9e0227f77b0cdd,
+                0x21907fce9be91889,
+                0x0c961afc881e7f1a,
+                0x000002963339153d,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x02250a56ede648df,
+            0x43e8d17c39a1597c,
+            0x6bbeba9d0f46c026,
+            0x4285857e0486af4c,
+            0x00000351255f3de2,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x872ff5be14a600f7,
+            0x9460164742de7e1d,
+            0xa448a42017d810f6,
+            0x25b29803db26525c,
+            0x000000ba9cabd169,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xab8ea75d1034f925,
+            0x35593247fa8bfaef,
+            0xb16be70c6705585f,
+            0x1a0e3a4abb826389,
+            0x0000035f09a1ec50,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x8b75c502c5b86d96,
+            0xb7b1ce147705c827,
+            0xbb85c43326d6a7f0,
+            0xa9f5d48ca2b39cb0,
+            0x00000342ffac8706,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x12a4551b3836c62f,
+            0x00474e47a76995ad,
+            0x9bc09f80a191d96b,
+            0x7b38f6153cffcd29,
+            0x000001e2a2168368,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x5baef1a593d463fc,
+            0x16a4ced5a8c55252,
+            0x5a2cda763d35e2a9,
+            0xbb05de1f0397d0f4,
+            0x000003af3a514150,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x64e5c166e282bc83,
+            0x8a80e50799a5049d,
+            0x4e57a0ddc2cb189b,
+            0xd67d4b80e20805fe,
+            0x000002b29a732462,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xa0907935dc5a0356,
+            0x4bc5cff237913520,
+            0x52e91d1cab1924e1,
+            0x4adf314ff23adbe9,
+            0x000003135d7fb302,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x4daf5b9c0d93ba20,
+            0x539de56fef47060b,
+            0x0d119f72928c2930,
+            0x4da689168ea76085,
+            0x0000001e8a5529d0,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x9b8e3a1b0cd72a8e,
+            0x94511f224b20c0da,
+            0x8ee90aae18882409,
+            0xbba6b4964cdd31f1,
+            0x0000026c4aa4e4c1,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+    }
+}
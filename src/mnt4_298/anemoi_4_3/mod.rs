@@ -0,0 +1,1074 @@
+use super::{sbox, BigInteger320, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 152 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (38-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 11 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 11;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x0d7f5d01d71a0444,
+                    0xd3f58e442260b23a,
+                    0x2b248d773cdf4ea7,
+                    0x6ebdcef24762868a,
+                    0x00000034dd5c7292,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79a57140bd1ea0d4,
+                    0x951af74d43f20335,
+                    0xebdc06161402dc02,
+                    0xf14084e8c108d5b5,
+                    0x0000022db116190f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2b64dfa8b756d01,
+                    0x1ac11d53d82d43be,
+                    0x802660f5d8b2538f,
+                    0xee5a2238766966d1,
+                    0x0000037c59ba6889,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ff188e8d1dab801,
+                    0xa1ff2b0c5af4fecb,
+                    0x2ca18dc5fe3b275f,
+                    0xb2d22622d27bf507,
+                    0x000000ca100276c4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x296d6329b5d8b6b7,
+                    0x0869eaadc934406e,
+                    0x49fe3877e29ccd1d,
+                    0x52c16d5a15132d18,
+                    0x000000dff4851765,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c988fb0d4534813,
+                    0x0b71cf508618266c,
+                    0xe2599b379ed0666d,
+                    0xae2e7e01c29af3d5,
+                    0x00000210091d242a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc50c59d529f7dbb6,
+                    0x5509d8306e9c8f32,
+                    0x234905c919d43922,
+                    0x0ece2b7bf45846be,
+                    0x0000020d8ee52993,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf75eb421e836b15a,
+                    0x46c9a0c14df9ac00,
+                    0x80bef36a1e849f76,
+                    0x864951239aa7fa38,
+                    0x00000166fc4a46c6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdf2b85c4ca836aab,
+                    0x29277eef6071c645,
+                    0xbf2b21c5d36befe0,
+                    0xe1cbee50cbb48b40,
+                    0x000001d79b891715,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1675f6dcb27eb4e7,
+                    0xa923ffa305fca5ac,
+                    0x3056e28de40f3324,
+                    0xfa7c72bfd3314229,
+                    0x0000004becee1cc3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03dbfb47c4fea300,
+                    0x5f92133693778994,
+                    0x48fab99ca71ce4bb,
+                    0x885b15068a5e0d50,
+                    0x000001d08ba2c485,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fe43a5373c36756,
+                    0xc01e203d05c69047,
+                    0xa85b4fea96cbc6fc,
+                    0xe6fff18069be813b,
+                    0x00000268e0e39f78,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x01874fe192148b85,
+                    0xe120e395640bfb36,
+                    0x2570dc14291b40a0,
+                    0x8fcb7a3f859f785a,
+                    0x000001d8ea472d77,
+                ])),
+                Felt::new(BigInteger320([
+                    0x126bef2cedc3e16a,
+                    0x3fe02bb951aaf9ea,
+                    0xa98b1523e7419c2e,
+                    0xf84b802530f302f8,
+                    0x00000131c1ca5c73,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3efb483f50d1e4b1,
+                    0x0b8321d9f783e586,
+                    0xe8d01c56e09a8cab,
+                    0xfb550d249b890ee4,
+                    0x0000010bb40d8c76,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc13d67c15a645bd5,
+                    0xa146c9d64b727776,
+                    0x7037f34aeb59430d,
+                    0xdfa46324d07ae5f3,
+                    0x0000004ec616716f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x541b27e63f622a50,
+                    0xe1f4f40d44a071ae,
+                    0xf9ffd1c0e56434af,
+                    0x0673a35acc781427,
+                    0x0000012eac6b1094,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3355b964f8e7fa6,
+                    0xcb6ac60b7234610c,
+                    0x1107ab3ad5cef2a9,
+                    0x4f0553dd0817a610,
+                    0x00000366333dcfa9,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfef5c6ee5603cc76,
+                    0x9dfed9b1b4e1da24,
+                    0x09dc6e5bca98919b,
+                    0x0e099b4a2df9d237,
+                    0x0000037a71c46c39,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ef9aba6a6a81fb3,
+                    0x9d244dcaebb5a65b,
+                    0x774592f34a964c27,
+                    0x9811bd8e37f9c014,
+                    0x000000772d19ed63,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe87f92e5885be976,
+                    0xd682aea86768af93,
+                    0x33e6e51e7c161022,
+                    0x6ca98eb7e2e0f07e,
+                    0x0000033010276164,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe434e24e7afd8f92,
+                    0xda28f94501609d67,
+                    0xb68909672615aa07,
+                    0xb0e1681f2c37b726,
+                    0x0000036eb5cbc8d8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22141ab3ea317609,
+                    0xb1afa162d3b49b60,
+                    0x5e4f0ca32ff96a30,
+                    0x4619c51dea27c0e3,
+                    0x00000278260395d9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8545a920cd10f095,
+                    0xf7f65e305b1e632c,
+                    0x8b47ad3919dde704,
+                    0xe88a83820e159b95,
+                    0x000001c6e0c236bb,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x92d30988a52722a8,
+                    0x2501d67d6a21f25e,
+                    0x5c607e5110d53d89,
+                    0x26f3f977a659bd27,
+                    0x0000000367793db5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c3e4f17263d0ddc,
+                    0x46940a3a77bf2312,
+                    0x53b88f24f5e6a967,
+                    0x2ef5d43f83390b72,
+                    0x000000dae9e75d01,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88b4e6ffa72f083f,
+                    0xbc8bf3a97e9c102f,
+                    0x158410e92ec2aab1,
+                    0x73d590f2e3561232,
+                    0x0000024b6b63f42c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa4f349a379af11e,
+                    0xba7b005313b6180b,
+                    0x8564ce859635a19b,
+                    0x3e9f540b45b79358,
+                    0x000001a06d113b78,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4a0f4c895f1e5dee,
+                    0x2b881858e3fc305e,
+                    0x7138876b3e732ba6,
+                    0x7714e7d19c8fcd67,
+                    0x0000032e01551f98,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf97bdab71f1ae8f1,
+                    0x460ae74dae9e14a4,
+                    0x0c0f0ac8a018cee5,
+                    0x2a156192c61a337d,
+                    0x00000187a2259180,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83f6ee97778dc96b,
+                    0x56b14720b0eb3917,
+                    0xd9cc06b3d1ef075e,
+                    0xde023b1a7160436f,
+                    0x000001eb6320573a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda41abc828dc6342,
+                    0xe436620c7ff7e5d0,
+                    0xad32b6cf046f9542,
+                    0xda458d8e4d46581e,
+                    0x00000389e80ef13d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x12ba30a91c34c1c0,
+                    0x6b50a3d9078680f4,
+                    0x62bfd46e5664f271,
+                    0x9c37f780e3d41cdc,
+                    0x0000039f59345262,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd8b7c90de5532bf,
+                    0xd462811853b100a1,
+                    0xee30e4ad47664d76,
+                    0x07eac87ef0460917,
+                    0x0000039cb74c9600,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10f9d270d3e6daf0,
+                    0xa94057c488314c66,
+                    0xbd8e1da8b03bea87,
+                    0xd4c908b2af7723b5,
+                    0x0000015ec4b4fb26,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7085ff3b75a7fdd,
+                    0xc6785c795467755f,
+                    0xc9a2dc063283d1f5,
+                    0x9d8bd8eca2e933b1,
+                    0x0000013cd0e75810,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfcd3c9d5887bf41d,
+                    0xb3a37593f665c9be,
+                    0x42a1a8fe6f8786f6,
+                    0xcb6ef20b55f04cca,
+                    0x000001e3428db8b1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1f4be9e119fe040,
+                    0xd9e5c0f3b536c51b,
+                    0x54121b23d56a4e05,
+                    0x98e99105978fb139,
+                    0x0000020f6566fdab,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51e0a7fa21d4abc5,
+                    0xbca853d45247a1be,
+                    0x4f7df02f83b8ebb4,
+                    0xa9c4fe2eb3816dda,
+                    0x000003475a379edd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c8db9539d520091,
+                    0x1dde3fee0d01c44e,
+                    0x9d1316b001ad11a3,
+                    0xbfd5316fe64b8a97,
+                    0x0000029d869a0812,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0e90f05c484a1807,
+                    0xd1aa5cef37113518,
+                    0xca0a3ca2eb5e3835,
+                    0x6068efc19743baba,
+                    0x00000383565917d4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcba12f193e45525a,
+                    0xec310b51fa0b4b1d,
+                    0x6d167a76f194c492,
+                    0x2824e5d49e943298,
+                    0x00000034a7fd006f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa532d61f00cc767f,
+                    0xa18a66e081f2b608,
+                    0xe4019741f5e8ccc3,
+                    0xfb2d1992882e0007,
+                    0x0000028d8b215305,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe218147f8fdecb6d,
+                    0x1710971cb599d1ea,
+                    0x848e6c129a28ea2d,
+                    0x648aa09e287819b9,
+                    0x000002d39193520f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb8b969e1391406bc,
+                    0x1a076cb18dbc02ea,
+                    0x3f8da9dcac8b6b5a,
+                    0x42463cf02646fbc0,
+                    0x0000029e379fc17e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb905557f86c16834,
+                    0x0c16308554f1fb17,
+                    0x1b577cf0bf846915,
+                    0x60591008675b3a04,
+                    0x0000018012c510dc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6925ce4922d90119,
+                    0xc81ae632af630333,
+                    0x9234cc98bb613cdc,
+                    0xd05979a3f0c8ec23,
+                    0x00000160d795e986,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30daca0093e1e9f9,
+                    0x61f104c9d8659e71,
+                    0xb326d922149669ac,
+                    0xb0e6e693d5fa2452,
+                    0x0000024403f9725f,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xa39a4bf1460310fa,
+                    0x79b4f61216f75ae9,
+                    0x8e14f4d1261ff667,
+                    0x909093ba29f0e1c3,
+                    0x0000015ff59b001c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd70a85aa1aeab03b,
+                    0x6bbfcfd70c56c803,
+                    0x9bb6acb9293994fa,
+                    0xafece454ff761474,
+                    0x00000033d702d2d9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a52ea51adbf5b1f,
+                    0xa2dcf5cfcf42067e,
+                    0x91f0af40e7071e87,
+                    0x32cbf729705c11f3,
+                    0x000003b79524452e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6f381b71bda0596,
+                    0x74e3b834b3eeca17,
+                    0xf1752e5f015bb5c6,
+                    0x2fbb38f290098e47,
+                    0x000001928d500e97,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x13750f766ce619ef,
+                    0x4518c2ac787f22a8,
+                    0x69fe7164e3f6eb0a,
+                    0x6a0b70fb44a10671,
+                    0x00000123025a1970,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f42b1c29c764ecd,
+                    0x95d8c874aaa82294,
+                    0x444a9869e7a9443b,
+                    0xaa8ee9781ff7cc95,
+                    0x0000001eae903c67,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a097471dca26037,
+                    0xab4932509ae4ab3f,
+                    0xa68b41066c168762,
+                    0x7cd9c3e46875ddad,
+                    0x00000086e4722e21,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04344072af447943,
+                    0x986abd4735e8abb1,
+                    0xd0127196fe8d5bfa,
+                    0xc3843df4f9b7fc01,
+                    0x0000025c3b007b8a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x48f11b24a30a6297,
+                    0xb5ddf582dc7c2412,
+                    0xa5e02adfb41f5b1e,
+                    0xb592bfc5d21097dd,
+                    0x00000070affc34a6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e95636cddc429f9,
+                    0xe8ee18e956f0cbe9,
+                    0x4434bdbd93576b43,
+                    0xeae8abe12ae9ee9a,
+                    0x000001bab6e28650,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0147ffd1289b4cba,
+                    0xd036b9d353fe4326,
+                    0x03869f94d6f36e4f,
+                    0x042a4ff18d67e587,
+                    0x000000429d97ddc3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe222629f188a9989,
+                    0x5ef6251cad7d1d7a,
+                    0x9f60b38bf904213a,
+                    0x8e9a621a9cfba37c,
+                    0x0000033852c3eaaf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6525ae17f6c4cdc2,
+                    0x37b52eb30e620e0d,
+                    0x3a8017c9a9437d7f,
+                    0xdb3fb187d04ba4be,
+                    0x0000024ebd606931,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b2656444aaeaac5,
+                    0x8d952fbe92a4e4b0,
+                    0x76db86c3504315e4,
+                    0x046296a1fd788699,
+                    0x0000001f4710923c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e78f8c9cb73fc45,
+                    0xa2726c7fe9633a44,
+                    0x0147cc7b076a8203,
+                    0xaccfa93d18c60974,
+                    0x000001efbc520267,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe324482fab5af577,
+                    0x66eadb2ca5fdc34e,
+                    0x019774b57c890e50,
+                    0x9490bd3bca263043,
+                    0x000002e11d5fdfb2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xadddef110ff8ea67,
+                    0x3418f0be8587d4a4,
+                    0xe758badabd48e423,
+                    0xa1483148a72966ef,
+                    0x000002af0a0be8b1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd142773cb957c1d9,
+                    0x45223465230af69b,
+                    0xaa4908149c896f30,
+                    0x9ff7edc30f8f0fce,
+                    0x000002d9b3be9b9c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0286d6923cf2c780,
+                    0x11ebca32ff38abdb,
+                    0x46dea9a5db0d2fab,
+                    0xdda15d9c2a987977,
+                    0x000001c3a973eaed,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca00ce0f0eb70b5a,
+                    0x1fbc3f4c96892730,
+                    0xd4f5e943922f31e8,
+                    0xe6a1605768a4c280,
+                    0x00000298eff9597e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaca29befc44f1b6e,
+                    0xc616ed4b4a7e902d,
+                    0x9268ec95fcf2b856,
+                    0x801659c04a4d56ed,
+                    0x0000004f0dfbb81d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf18e24bc05add05,
+                    0xdf003a750d2cf802,
+                    0x52d9b5284c1635f7,
+                    0xa5f2234f576747bf,
+                    0x000003ab9be5c23f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d56ec52d952a50d,
+                    0x0fda9b3fde33e7e0,
+                    0x1342343b11dc24e6,
+                    0xf91ef3c81f640f3f,
+                    0x0000011e4c24fe86,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49a37368a080931c,
+                    0x22b6067e21bd986b,
+                    0x285305e56867740f,
+                    0x96fec18740017617,
+                    0x000000755e538e6d,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x755129d8abcf4e70,
+                    0x64557a9779d58aa5,
+                    0x92411865f906833e,
+                    0xd08365c9d3291835,
+                    0x000001acaa67001b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ac02f0b35f7b9c4,
+                    0x863c833271e4525b,
+                    0x24bbd931c9cfdb13,
+                    0xeea0de122cb348b0,
+                    0x00000050b52d91fe,
+                ])),
+                Felt::new(BigInteger320([
+                    0x755129d8abcf4e70,
+                    0x64557a9779d58aa5,
+                    0x92411865f906833e,
+                    0xd08365c9d3291835,
+                    0x000001acaa67001b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ac02f0b35f7b9c4,
+                    0x863c833271e4525b,
+                    0x24bbd931c9cfdb13,
+                    0xeea0de122cb348b0,
+                    0x00000050b52d91fe,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x755129d8abcf4e70,
+                    0x64557a9779d58aa5,
+                    0x92411865f906833e,
+                    0xd08365c9d3291835,
+                    0x000001acaa67001b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ac02f0b35f7b9c4,
+                    0x863c833271e4525b,
+                    0x24bbd931c9cfdb13,
+                    0xeea0de122cb348b0,
+                    0x00000050b52d91fe,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x755129d8abcf4e70,
+                    0x64557a9779d58aa5,
+                    0x92411865f906833e,
+                    0xd08365c9d3291835,
+                    0x000001acaa67001b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ac02f0b35f7b9c4,
+                    0x863c833271e4525b,
+                    0x24bbd931c9cfdb13,
+                    0xeea0de122cb348b0,
+                    0x00000050b52d91fe,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x224055e29e32c4e4,
+                    0x5fc7dffb979a9518,
+                    0x1a47a3b985dfaa1e,
+                    0x9ce598c17917e8f3,
+                    0x00000114450e2c1d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf91ce04cd6b1c35b,
+                    0xc15e2212d91a7652,
+                    0x2ebd667d98c71d55,
+                    0x919376b76b4b3a5e,
+                    0x000003988e006f02,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc9ad5221dc91294,
+                    0xe947f1be1430493f,
+                    0x289572152a8e7d39,
+                    0xc67e343ae37121f9,
+                    0x00000137012e8afa,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a4e0b76ffb596ed,
+                    0x96a8af393fb68f49,
+                    0x82448a548c0a49e2,
+                    0x92c893609cbd5826,
+                    0x000001f0dace851f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x56e2dd62d0c1558c,
+                    0x387e126bcda96e81,
+                    0xf2f2906d463472ff,
+                    0xbd88f1f564159c5a,
+                    0x0000032c99ee1c52,
+                ])),
+                Felt::new(BigInteger320([
+                    0x159fac6045b8fd0b,
+                    0xbeed9274e327b424,
+                    0x4d572c3c7a183a5e,
+                    0x5f10fa2499c28ed0,
+                    0x000001bf5b0c7f93,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3ae78f301dadce7,
+                    0x33496a868bb7e0ae,
+                    0x7e8566da14d2c6c9,
+                    0x26c7d246f27ab6dd,
+                    0x0000001a7f5f0e69,
+                ])),
+                Felt::new(BigInteger320([
+                    0x589f7c94fc2b0b8e,
+                    0x1329453fe21a96d9,
+                    0x0d671581ce15babf,
+                    0x101eba9a829c0263,
+                    0x0000024959c2231d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3182d8e643472c19,
+                    0x6208656d6a994c10,
+                    0xa0d942bef1672439,
+                    0xde3b296a15c72034,
+                    0x000003ac0edd50dc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e6b8eb5cdb81791,
+                    0x0f34bbea07fde9bd,
+                    0x78c6ccd26cea9385,
+                    0xe40dfd747a6d749d,
+                    0x0000009b400ac94d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65d0b2cde80eb1e1,
+                    0xaf44b5689f1f76db,
+                    0x4573b9a7850812b2,
+                    0x909d67e9b1813856,
+                    0x0000002ada5ffbbb,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc423df7d91951cab,
+                    0x73c6c5c5e51527b7,
+                    0xa035f3b4ac7cac2d,
+                    0x9e9e367656fca341,
+                    0x0000031b1df79537,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x59a492467af824d6,
+                    0x5cf376fc7a352fab,
+                    0x5425415ea0a4c0c2,
+                    0x83658dabfd9c4060,
+                    0x000000a57dbd4aba,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cfa603d925d1cf9,
+                    0x366c4fc60decf2eb,
+                    0x77774b45410d80f8,
+                    0x7954b5d2857823e1,
+                    0x000003a2b128dfb7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27b944683178b603,
+                    0xe18b115a4b742331,
+                    0xf4f6f462b4eef851,
+                    0x58c3b0bf8a19d7f1,
+                    0x00000227e92e9084,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3746242ca67c126e,
+                    0x0c0bb1cfc0d903cc,
+                    0x585a29276889164d,
+                    0x39d0f7ccb79a12f1,
+                    0x000001348b094ead,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5da0f7819c9ec9c3,
+                    0x75bddfabbaa17e09,
+                    0xe8120a2d686fca72,
+                    0x018f1f3b2bb52868,
+                    0x0000028b651d7439,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c58da9acf1b27c0,
+                    0x02c76660ba43ac59,
+                    0x904a44702f0f95cf,
+                    0x1daf494d1a52c814,
+                    0x0000013fcdd95dfa,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac8e5af18fa64ad2,
+                    0x431591bd004c2025,
+                    0x3248e22c3575d4f2,
+                    0x8b2772c8ff422ef6,
+                    0x000002af72c54fad,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b60d45f7635bf66,
+                    0x7263cf5436c6257a,
+                    0x1a843ded0afa08c6,
+                    0x7e76459141b9a11f,
+                    0x0000028eabb44008,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcc7c3d5073f7c8b3,
+                    0x7a7c992c187a272f,
+                    0x63f15e0d39393f28,
+                    0x5ec8682a9d23d1b5,
+                    0x000002e4ec70571d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54a53bb1403b30da,
+                    0x69fbf73a3d88cd28,
+                    0xdcd1f09a01d6bad8,
+                    0x0fab0d849b2394fb,
+                    0x00000089c307ece0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f28f524d7ff88f4,
+                    0xe7e8e4e44d8fb7f3,
+                    0x790e8ee0c6a5f2c2,
+                    0xf60e3abd0f34d27d,
+                    0x000000c796184f21,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83e8b0b5dd18bd3e,
+                    0xb154244e115ef4d4,
+                    0xbc6d54fe2ba7b118,
+                    0x68de6c7d2ad50be7,
+                    0x000003285c8bc26d,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0xbc31409cbfa8a653,
+            0xef892a90c139d0f1,
+            0xfe2bce26405b3feb,
+            0xc8007482cc4d18c4,
+            0x0000009f75ee5f8c,
+        ])),
+        Felt::new(BigInteger320([
+            0x2a544b894d236d72,
+            0xeb7f3a48167d8dbe,
+            0x358a42602f1ae16b,
+            0x12137c7d19a87c75,
+            0x000003037d1f22fa,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x67c57ebe0163df1e,
+            0xa499bc2f7af4f8a7,
+            0x0eeb36dceedbfc8a,
+            0x5c131f17645e24e6,
+            0x000002cca985720b,
+        ])),
+        Felt::new(BigInteger320([
+            0x95680d82252c41a5,
+            0x7e22bf1e7a20ff66,
+            0xbcfc38cb2285ff68,
+            0x8f1c3be1e6c0e849,
+            0x0000010e612aa11a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa39596a5eacd6039,
+            0xbf9efac152d0f74f,
+            0x7a9ef4021cabb164,
+            0x0485a9169b4688b7,
+            0x00000131c9bef086,
+        ])),
+        Felt::new(BigInteger320([
+            0x19544226b5136442,
+            0x0f13d560578ae6a0,
+            0xd6dc3598a7231123,
+            0x027484aa65ee179e,
+            0x00000085085a85f0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd9686a20883264c6,
+            0xcb364b80a5e32921,
+            0xc1f506639babe13d,
+            0x8631dd213eb4a0b5,
+            0x000001289fb515bb,
+        ])),
+        Felt::new(BigInteger320([
+            0x01fb1be371d3485f,
+            0xa514c72617572170,
+            0x425fc2dc8fe7fc5f,
+            0xf8f6e3f724ca972b,
+            0x00000349ed10be81,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x07c356323e6b062d,
+            0x37cfb1f5d30ec891,
+            0x49ca94414570cedb,
+            0x00d756ded3eabad3,
+            0x00000189447cdc8e,
+        ])),
+        Felt::new(BigInteger320([
+            0xe59d3741106f8d8d,
+            0x2f8667aa7702264a,
+            0x44c45834264b573e,
+            0xb046d725e9a8ad1f,
+            0x0000037ecd0ec128,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x815603bac05f14c3,
+            0x886ba6c2528f61e5,
+            0x10b244c3133be733,
+            0xe8a8100725b6a634,
+            0x000000f52758f679,
+        ])),
+        Felt::new(BigInteger320([
+            0xb27e0106389f7802,
+            0x08409e9ab5029a93,
+            0x8e60553ea7266b8c,
+            0x08dd453941a93d35,
+            0x000000c9090e8b68,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x138e2d62422d4b38,
+            0x0663af85aa28707b,
+            0xd9048e417d4f59c3,
+            0xbddcd63a4d0bfedc,
+            0x000002648deb0190,
+        ])),
+        Felt::new(BigInteger320([
+            0xb6dbc3b78a3040bb,
+            0x1deff74e0823247a,
+            0x06b9c122d3f56b4e,
+            0x25f15b1a56f70566,
+            0x0000000957e71bc4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x16eec3bfec6f6119,
+            0x39b3f3f6d8378c2d,
+            0x96d49382d1244ee6,
+            0xe47cb012b2593d06,
+            0x000000a6726efcd9,
+        ])),
+        Felt::new(BigInteger320([
+            0x6f163f044396ad25,
+            0x9302bf542d2462be,
+            0xe2f8830e2c4aab8d,
+            0x659cd211bfeee789,
+            0x000003b1b1b31021,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x93f1a3384504710d,
+            0xb3928d3e8e414e60,
+            0x34ecb6299cda21b7,
+            0x33b06d157f549e8f,
+            0x000001742ffa53cb,
+        ])),
+        Felt::new(BigInteger320([
+            0x299e2b6ef0a57114,
+            0xa5e6f906ede47c34,
+            0x46be620ace0d9709,
+            0x647b8a707054b5af,
+            0x000003b8428b5781,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x4183873715e1116c,
+            0xedd77cba1b95c656,
+            0xf46d98d543108b94,
+            0xf358d321e2c2b8ea,
+            0x000000ce50f0709c,
+        ])),
+        Felt::new(BigInteger320([
+            0x8d3d35c6bb8b3713,
+            0xaba6c66b5695a44d,
+            0x5d2649f3765d8829,
+            0x87830aec77b7f869,
+            0x00000258ac541581,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7fd46be2304b8d94,
+            0x5aa6e0f69a7a6293,
+            0xd04c71adbd40fdae,
+            0x718f2dc8d9d95fa3,
+            0x000002a31a0bbf63,
+        ])),
+        Felt::new(BigInteger320([
+            0x3ff12803773119a5,
+            0x73673fe4c1d36b08,
+            0x86f95f1c046f77d3,
+            0xf076c29a92e19a4e,
+            0x000003619964a5f8,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x33e9ed6fcb53ea59,
+            0x6f366c32ef485a0b,
+            0x6310b5e11f29fd5b,
+            0x3901bb069e439a9a,
+            0x000002eccd701bcd,
+        ])),
+        Felt::new(BigInteger320([
+            0xcd9b83be388d27e4,
+            0xcff535c72d93f3ad,
+            0x34512906626c93fc,
+            0x84c388034a21bb1f,
+            0x0000028f71cd8a9d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa788530d49ae4543,
+            0x7ca3bfad7152a554,
+            0x7323845e93f23e6a,
+            0x14e27d4b7e3adeee,
+            0x000001fb4480104e,
+        ])),
+        Felt::new(BigInteger320([
+            0xc9c6438dbe9b1e37,
+            0xfc9f5fd7aaa696fc,
+            0x86054e9b79326753,
+            0xec0083b606d0b3b5,
+            0x00000138910ebf33,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd8dac9fd19dbbc98,
+            0xd4d00b5d86cc3f33,
+            0x7fe17752d03b2658,
+            0x1c0d2028e675e090,
+            0x000001ec28a7888f,
+        ])),
+        Felt::new(BigInteger320([
+            0x4334d73a3546370e,
+            0xcab78337c5ae196d,
+            0x40ef81380c48ac22,
+            0xbe10e55cb75080db,
+            0x0000023afc2c9dcf,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf6172ceb3cb9bc57,
+            0x518132926f302dad,
+            0xca925f2611188f7c,
+            0xfb86ff45250b2df9,
+            0x0000010733fb4174,
+        ])),
+        Felt::new(BigInteger320([
+            0x4a386a100619165c,
+            0x90286814c9ac02d2,
+            0xe4df1a8a59d79fbf,
+            0x6ffacb1d69a3e143,
+            0x000000671e83959e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x725b1557ea42b3c8,
+            0xbc91c8c3de561061,
+            0x6a64e55d27efd2fc,
+            0x653df4b9bf95e7f1,
+            0x000000825ff9bd92,
+        ])),
+        Felt::new(BigInteger320([
+            0x44d058230d6bb195,
+            0x5abb1bb3bc715904,
+            0xca2f719eba60816b,
+            0xb8c25ea0db86eba0,
+            0x000003737d752203,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcbb82b49d1bbd033,
+            0xfcddd29824e0c84f,
+            0x0ab488b65da97923,
+            0x5e04dd074f0c0dd3,
+            0x0000014fac6644dd,
+        ])),
+        Felt::new(BigInteger320([
+            0xf17b8a519b20a9df,
+            0x232567abc17bebe6,
+            0xed336180a32a2388,
+            0x224efbd97131b637,
+            0x0000021f230559a2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf7c37372082cc081,
+            0xdf326d83c22e4247,
+            0x703253800a59e210,
+            0x91eb16f3b36e18d1,
+            0x000001f73296d1a3,
+        ])),
+        Felt::new(BigInteger320([
+            0x8fac6b83a1542c71,
+            0x9d3152875a50e130,
+            0x02b84eb0129619a7,
+            0x9e148573c38c30be,
+            0x00000097917c6bad,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0929ae3509af6c99,
+            0x253c646854a2968b,
+            0xec9b4d6a2f500bf8,
+            0x6b0eaed9af67a4e3,
+            0x000000cc148a694b,
+        ])),
+        Felt::new(BigInteger320([
+            0x8cdfb4db40952f11,
+            0xe353e9a2929749f2,
+            0xd2a13be0def95dc0,
+            0xeddd95db1b800c3b,
+            0x00000115f0fb27f5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x4c6c5b0e34234aee,
+            0x8382cb9cf24f4528,
+            0x824a7baa19d92e7c,
+            0x546c851f0702ffa1,
+            0x00000277ec36dc42,
+        ])),
+        Felt::new(BigInteger320([
+            0x0da76ea6bf82c161,
+            0xda9ff1423afa4fd2,
+            0x2dfe26769f8f98ef,
+            0x86e6677c5685d396,
+            0x000001fa9bf48b5b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xfc56e3996898d744,
+            0x4a38a76ca9441b63,
+            0x52d14bebd5f7b759,
+            0x3f2abcf7ab3b4538,
+            0x000000e95d67673c,
+        ])),
+        Felt::new(BigInteger320([
+            0x3cabf3e55f677358,
+            0xae7a8e591e6be444,
+            0x205ac558badad9f9,
+            0x7769de624663961a,
+            0x0000036f4db48bf7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7ce709ac553b6e88,
+            0x679ebf7b1cc96e29,
+            0x9f6b8378feec7324,
+            0x67fbb13867bc9e03,
+            0x000001e784140559,
+        ])),
+        Felt::new(BigInteger320([
+            0x319f2789ed457106,
+            0x26d1bba47e4a6187,
+            0xbae93935f7b11355,
+            0x8af82faa26f7ea11,
+            0x000003a198566bc5,
+        ])),
+    ],
+];
@@ -0,0 +1,791 @@
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0xbc31409cbfa8a653,
+            0xef892a90c139d0f1,
+            0xfe2bce26405b3feb,
+            0xc8007482cc4d18c4,
+            0x0000009f75ee5f8c,
+        ])),
+        Felt::new(BigInteger320([
+            0x2a544b894d236d72,
+            0xeb7f3a48167d8dbe,
+            0x358a42602f1ae16b,
+            0x12137c7d19a87c75,
+            0x000003037d1f22fa,
+        ])),
+        Felt::new(BigInteger320([
+            0x0a82d09c663f2f98,
+            0x565aa7257941ed46,
+            0x4a372c4df28477d1,
+            0xc05826ff5fcb00ea,
+            0x0000035ca28cf7a3,
+        ])),
+        Felt::new(BigInteger320([
+            0xbc3a1c8e7fe3e2f7,
+            0x4eb273927950ffaa,
+            0x4fcf114424820f55,
+            0x93457a945b01b1ee,
+            0x0000011bd8699b2d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x67c57ebe0163df1e,
+            0xa499bc2f7af4f8a7,
+            0x0eeb36dceedbfc8a,
+            0x5c131f17645e24e6,
+            0x000002cca985720b,
+        ])),
+        Felt::new(BigInteger320([
+            0x95680d82252c41a5,
+            0x7e22bf1e7a20ff66,
+            0xbcfc38cb2285ff68,
+            0x8f1c3be1e6c0e849,
+            0x0000010e612aa11a,
+        ])),
+        Felt::new(BigInteger320([
+            0x454615216d5f9b3f,
+            0x2ea83b7ba0d4039d,
+            0xb713b906f17c0a4d,
+            0x0f7c60f6ad477290,
+            0x000002507a045ec6,
+        ])),
+        Felt::new(BigInteger320([
+            0xe73b9e99e9c2019a,
+            0x8ce49771f225ecab,
+            0xe0e8f71c33501bfb,
+            0xc059c990e8d8a29d,
+            0x000002711578dff3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa39596a5eacd6039,
+            0xbf9efac152d0f74f,
+            0x7a9ef4021cabb164,
+            0x0485a9169b4688b7,
+            0x00000131c9bef086,
+        ])),
+        Felt::new(BigInteger320([
+            0x19544226b5136442,
+            0x0f13d560578ae6a0,
+            0xd6dc3598a7231123,
+            0x027484aa65ee179e,
+            0x00000085085a85f0,
+        ])),
+        Felt::new(BigInteger320([
+            0xd0fbbb70bf1bb4d5,
+            0x178b04198a00a4e0,
+            0x7d4be28b4833962d,
+            0xbb37035d8563df90,
+            0x0000037834afb85c,
+        ])),
+        Felt::new(BigInteger320([
+            0xebd62b96b915d4e3,
+            0x3362838b8e19a19e,
+            0x20a725899dd79f6e,
+            0x857fa8021c79cdab,
+            0x000002be74694f2d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd9686a20883264c6,
+            0xcb364b80a5e32921,
+            0xc1f506639babe13d,
+            0x8631dd213eb4a0b5,
+            0x000001289fb515bb,
+        ])),
+        Felt::new(BigInteger320([
+            0x01fb1be371d3485f,
+            0xa514c72617572170,
+            0x425fc2dc8fe7fc5f,
+            0xf8f6e3f724ca972b,
+            0x00000349ed10be81,
+        ])),
+        Felt::new(BigInteger320([
+            0x0a62b30d6376d9f7,
+            0xe5d7e69a15c3da31,
+            0x1884ad2277c78637,
+            0x3a4e9325c5f99568,
+            0x00000218dbd74570,
+        ])),
+        Felt::new(BigInteger320([
+            0xd8857fa2c655ab3b,
+            0x3999b6eed35afa5d,
+            0x2b6b29f78d25e7cf,
+            0xa55388a6a3c7060a,
+            0x000001602d905709,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x07c356323e6b062d,
+            0x37cfb1f5d30ec891,
+            0x49ca94414570cedb,
+            0x00d756ded3eabad3,
+            0x00000189447cdc8e,
+        ])),
+        Felt::new(BigInteger320([
+            0xe59d3741106f8d8d,
+            0x2f8667aa7702264a,
+            0x44c45834264b573e,
+            0xb046d725e9a8ad1f,
+            0x0000037ecd0ec128,
+        ])),
+        Felt::new(BigInteger320([
+            0xd3c79fd1bd2f96b1,
+            0x980f50d89379c817,
+            0x9618ba1a7a171a8f,
+            0xeb46a473fde37762,
+            0x00000169207067fa,
+        ])),
+        Felt::new(BigInteger320([
+            0x3acc3545593e3ca3,
+            0x43c55d577506566f,
+            0xe1ffb40b5ff0c862,
+            0x2cb1003dc7579b75,
+            0x0000039f6978cec0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x815603bac05f14c3,
+            0x886ba6c2528f61e5,
+            0x10b244c3133be733,
+            0xe8a8100725b6a634,
+            0x000000f52758f679,
+        ])),
+        Felt::new(BigInteger320([
+            0xb27e0106389f7802,
+            0x08409e9ab5029a93,
+            0x8e60553ea7266b8c,
+            0x08dd453941a93d35,
+            0x000000c9090e8b68,
+        ])),
+        Felt::new(BigInteger320([
+            0x66ed7fd6fdb46ad0,
+            0x8696a177569b6102,
+            0xb84cbc5fc528a1b6,
+            0x1ef7bb6bde85febc,
+            0x00000131504cc070,
+        ])),
+        Felt::new(BigInteger320([
+            0x2e4b8b309fde1bf8,
+            0xb471e50c492d8e65,
+            0x4780baf1cae5e9db,
+            0xe87e809a9a8c7260,
+            0x000003596f30a7d7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x138e2d62422d4b38,
+            0x0663af85aa28707b,
+            0xd9048e417d4f59c3,
+            0xbddcd63a4d0bfedc,
+            0x000002648deb0190,
+        ])),
+        Felt::new(BigInteger320([
+            0xb6dbc3b78a3040bb,
+            0x1deff74e0823247a,
+            0x06b9c122d3f56b4e,
+            0x25f15b1a56f70566,
+            0x0000000957e71bc4,
+        ])),
+        Felt::new(BigInteger320([
+            0x745f09f55cb6a8aa,
+            0xcf40be211d95d483,
+            0xf643e4de29b13e89,
+            0x120853fe9a8f911c,
+            0x000001ecb71b0d2b,
+        ])),
+        Felt::new(BigInteger320([
+            0x16bc166d3f912fb7,
+            0xaa4ed2f6444b1c04,
+            0xdc573fce52eff1a9,
+            0x8c7550fa9e7a587f,
+            0x0000034229e41c98,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x16eec3bfec6f6119,
+            0x39b3f3f6d8378c2d,
+            0x96d49382d1244ee6,
+            0xe47cb012b2593d06,
+            0x000000a6726efcd9,
+        ])),
+        Felt::new(BigInteger320([
+            0x6f163f044396ad25,
+            0x9302bf542d2462be,
+            0xe2f8830e2c4aab8d,
+            0x659cd211bfeee789,
+            0x000003b1b1b31021,
+        ])),
+        Felt::new(BigInteger320([
+            0xc7df53518adb6033,
+            0xf9d269d36afcfe16,
+            0x3732a71a4fc028e7,
+            0xc10f6b549f12dd84,
+            0x0000011e98d52a19,
+        ])),
+        Felt::new(BigInteger320([
+            0xcfd84f353418c718,
+            0x39a713529747ece9,
+            0xbd6727647af1e85d,
+            0x3290e2b5c96ffbbe,
+            0x0000027241ad616e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x93f1a3384504710d,
+            0xb3928d3e8e414e60,
+            0x34ecb6299cda21b7,
+            0x33b06d157f549e8f,
+            0x000001742ffa53cb,
+        ])),
+        Felt::new(BigInteger320([
+            0x299e2b6ef0a57114,
+            0xa5e6f906ede47c34,
+            0x46be620ace0d9709,
+            0x647b8a707054b5af,
+            0x000003b8428b5781,
+        ])),
+        Felt::new(BigInteger320([
+            0xb99b5affe4963db8,
+            0x105021cd770b7fb0,
+            0x74dc524c2bf82718,
+            0x94d76aae58cbe41d,
+            0x00000231bd5e8534,
+        ])),
+        Felt::new(BigInteger320([
+            0x73ea013aba2f5647,
+            0xda75d224c9d185b9,
+            0x2a32ec8b74ef60cd,
+            0x29a863c104e43a75,
+            0x00000350d06790c1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x4183873715e1116c,
+            0xedd77cba1b95c656,
+            0xf46d98d543108b94,
+            0xf358d321e2c2b8ea,
+            0x000000ce50f0709c,
+        ])),
+        Felt::new(BigInteger320([
+            0x8d3d35c6bb8b3713,
+            0xaba6c66b5695a44d,
+            0x5d2649f3765d8829,
+            0x87830aec77b7f869,
+            0x00000258ac541581,
+        ])),
+        Felt::new(BigInteger320([
+            0xb0167fde12cbf94e,
+            0xbb2754d5380a5492,
+            0x347b7569d55d8a43,
+            0x780c4d790f50dd91,
+            0x0000022b291d45c7,
+        ])),
+        Felt::new(BigInteger320([
+            0x39f36a9ae9d55730,
+            0xe5dfb43f3f774978,
+            0x68741114cea7cb59,
+            0x187e8f3ae9b50a1e,
+            0x0000027b659aca44,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7fd46be2304b8d94,
+            0x5aa6e0f69a7a6293,
+            0xd04c71adbd40fdae,
+            0x718f2dc8d9d95fa3,
+            0x000002a31a0bbf63,
+        ])),
+        Felt::new(BigInteger320([
+            0x3ff12803773119a5,
+            0x73673fe4c1d36b08,
+            0x86f95f1c046f77d3,
+            0xf076c29a92e19a4e,
+            0x000003619964a5f8,
+        ])),
+        Felt::new(BigInteger320([
+            0xb08db895a3e853d0,
+            0x8a8038a36cb6557d,
+            0xbc885b13317cfc8c,
+            0xa916064468c41360,
+            0x00000093897fcae3,
+        ])),
+        Felt::new(BigInteger320([
+            0xbd15617e15f43c47,
+            0xdf53d83152e3e67f,
+            0xa6c867879dc5bc66,
+            0x3ff47c55cb98cbdd,
+            0x000003af63f9b89d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x305dd762d986b647,
+            0xb91959936c50230f,
+            0x04e008053eacee9e,
+            0x65b779e123187945,
+            0x00000098b4c39249,
+        ])),
+        Felt::new(BigInteger320([
+            0x6cdb36874b37e613,
+            0xc32d90a80533cbf7,
+            0x4b291aaa86d37816,
+            0xb37b222577ed43a2,
+            0x000000325835e10c,
+        ])),
+        Felt::new(BigInteger320([
+            0x5553a17c7b6594dc,
+            0x46f1033c11e4a276,
+            0x7457f95a333cd3b0,
+            0x593415e671f86b39,
+            0x000001c292c62637,
+        ])),
+        Felt::new(BigInteger320([
+            0x7d60307bff72aab7,
+            0xcf9ea527a86fa931,
+            0x910a27c168663714,
+            0xda96d18c7063ec1c,
+            0x000002596004538e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x199256161d237529,
+            0xada631719110e35d,
+            0xfbc763e39dfa8701,
+            0xdb076f62f5b36ca0,
+            0x00000021feed4ccb,
+        ])),
+        Felt::new(BigInteger320([
+            0x65edcd4b2ed2ab3e,
+            0x0e309301b4b5e64b,
+            0xdf26e1f324d8567f,
+            0x97e0424702c94968,
+            0x0000011e281bf591,
+        ])),
+        Felt::new(BigInteger320([
+            0x6b715c3088169abd,
+            0x29f99e8f3ac599ff,
+            0x113eb81cf77c497b,
+            0x3df656fdfd0e2249,
+            0x0000016f2bad8984,
+        ])),
+        Felt::new(BigInteger320([
+            0x529ae98eecb7ba06,
+            0x0c5bc31cc37fe4e8,
+            0x2a8c100d7188cced,
+            0x2b9fecdd9213b2f2,
+            0x0000023a60bb8198,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x33e9ed6fcb53ea59,
+            0x6f366c32ef485a0b,
+            0x6310b5e11f29fd5b,
+            0x3901bb069e439a9a,
+            0x000002eccd701bcd,
+        ])),
+        Felt::new(BigInteger320([
+            0xcd9b83be388d27e4,
+            0xcff535c72d93f3ad,
+            0x34512906626c93fc,
+            0x84c388034a21bb1f,
+            0x0000028f71cd8a9d,
+        ])),
+        Felt::new(BigInteger320([
+            0x4bedfa4abd85c6e6,
+            0x5a29306ebdf6da3e,
+            0x874baed9375295e1,
+            0xc749ae03530bc870,
+            0x000000fc2617ddfc,
+        ])),
+        Felt::new(BigInteger320([
+            0x36693404673b6748,
+            0xe6e14fa2d80762d4,
+            0x7eae56547138b851,
+            0xad79b8cfbff84123,
+            0x000002b0dce7d70d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa788530d49ae4543,
+            0x7ca3bfad7152a554,
+            0x7323845e93f23e6a,
+            0x14e27d4b7e3adeee,
+            0x000001fb4480104e,
+        ])),
+        Felt::new(BigInteger320([
+            0xc9c6438dbe9b1e37,
+            0xfc9f5fd7aaa696fc,
+            0x86054e9b79326753,
+            0xec0083b606d0b3b5,
+            0x00000138910ebf33,
+        ])),
+        Felt::new(BigInteger320([
+            0x17c83ca672ab54ad,
+            0xcc7d69fefef8223c,
+            0xbe6a6abc59a4ddb7,
+            0x00a22448901ec6d8,
+            0x0000008e38c4fb95,
+        ])),
+        Felt::new(BigInteger320([
+            0x2974dd8c0db8a80a,
+            0x7d70355e192b7369,
+            0x0f1ba1f3464e4968,
+            0x225c1f7c95b56a06,
+            0x000000e75d6ffdd6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd8dac9fd19dbbc98,
+            0xd4d00b5d86cc3f33,
+            0x7fe17752d03b2658,
+            0x1c0d2028e675e090,
+            0x000001ec28a7888f,
+        ])),
+        Felt::new(BigInteger320([
+            0x4334d73a3546370e,
+            0xcab78337c5ae196d,
+            0x40ef81380c48ac22,
+            0xbe10e55cb75080db,
+            0x0000023afc2c9dcf,
+        ])),
+        Felt::new(BigInteger320([
+            0x990041fdab2b647d,
+            0xf2873fbb25c25eb6,
+            0x25acca0fbed59cab,
+            0x0b14df8d998dd1a9,
+            0x00000341b75e4ef2,
+        ])),
+        Felt::new(BigInteger320([
+            0x2391c990c3d0718d,
+            0x61152e95f2bcc393,
+            0xefe4062fbf4effef,
+            0x463a16cbfaa932e3,
+            0x000002c0804e66d6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf6172ceb3cb9bc57,
+            0x518132926f302dad,
+            0xca925f2611188f7c,
+            0xfb86ff45250b2df9,
+            0x0000010733fb4174,
+        ])),
+        Felt::new(BigInteger320([
+            0x4a386a100619165c,
+            0x90286814c9ac02d2,
+            0xe4df1a8a59d79fbf,
+            0x6ffacb1d69a3e143,
+            0x000000671e83959e,
+        ])),
+        Felt::new(BigInteger320([
+            0xb9d0c90dd4ff84d1,
+            0x31edf8b146d750ae,
+            0xc4406a18b046c601,
+            0xe7fa1a67754abceb,
+            0x0000010693e36fb5,
+        ])),
+        Felt::new(BigInteger320([
+            0xf7aaad1056894317,
+            0xd866386ecd4fd8f9,
+            0xfe02e00f707a819a,
+            0xc3dba2821d1da0ad,
+            0x000000866ed30262,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x725b1557ea42b3c8,
+            0xbc91c8c3de561061,
+            0x6a64e55d27efd2fc,
+            0x653df4b9bf95e7f1,
+            0x000000825ff9bd92,
+        ])),
+        Felt::new(BigInteger320([
+            0x44d058230d6bb195,
+            0x5abb1bb3bc715904,
+            0xca2f719eba60816b,
+            0xb8c25ea0db86eba0,
+            0x000003737d752203,
+        ])),
+        Felt::new(BigInteger320([
+            0x9a2b8887976e9796,
+            0x2446760a57a78fec,
+            0x24c038cd7cbbe126,
+            0x2a69cc0a5a39934f,
+            0x0000032e57701bff,
+        ])),
+        Felt::new(BigInteger320([
+            0xa7da5f0de0c22a89,
+            0xe1090e93b0f5784f,
+            0xcc94627cb057b810,
+            0x3a4a95d04602d5f3,
+            0x000001e031f22f64,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcbb82b49d1bbd033,
+            0xfcddd29824e0c84f,
+            0x0ab488b65da97923,
+            0x5e04dd074f0c0dd3,
+            0x0000014fac6644dd,
+        ])),
+        Felt::new(BigInteger320([
+            0xf17b8a519b20a9df,
+            0x232567abc17bebe6,
+            0xed336180a32a2388,
+            0x224efbd97131b637,
+            0x0000021f230559a2,
+        ])),
+        Felt::new(BigInteger320([
+            0x440efa9bcc127989,
+            0xc0d3f85290b3395e,
+            0x556d6486d2a8c531,
+            0xccaaed89d0d6009b,
+            0x0000009af9200d5f,
+        ])),
+        Felt::new(BigInteger320([
+            0x7b241d628ce717b3,
+            0x4165ab504c26cedf,
+            0x0b7d5c3a833b6759,
+            0x070e455256e1e75f,
+            0x000002fba13a75db,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf7c37372082cc081,
+            0xdf326d83c22e4247,
+            0x703253800a59e210,
+            0x91eb16f3b36e18d1,
+            0x000001f73296d1a3,
+        ])),
+        Felt::new(BigInteger320([
+            0x8fac6b83a1542c71,
+            0x9d3152875a50e130,
+            0x02b84eb0129619a7,
+            0x9e148573c38c30be,
+            0x00000097917c6bad,
+        ])),
+        Felt::new(BigInteger320([
+            0xeb53a33adfb7713c,
+            0x6ddaa7249d621841,
+            0x30900e5079ce5862,
+            0x1e6cf9d5c9ec4551,
+            0x0000008e7f8cdbca,
+        ])),
+        Felt::new(BigInteger320([
+            0xfd67c71fe13ce54b,
+            0x9b9f2b628cf8c7e0,
+            0x3d7f62624de26584,
+            0x09b6896b97dc7fd4,
+            0x0000021c7b8c6c4b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0929ae3509af6c99,
+            0x253c646854a2968b,
+            0xec9b4d6a2f500bf8,
+            0x6b0eaed9af67a4e3,
+            0x000000cc148a694b,
+        ])),
+        Felt::new(BigInteger320([
+            0x8cdfb4db40952f11,
+            0xe353e9a2929749f2,
+            0xd2a13be0def95dc0,
+            0xeddd95db1b800c3b,
+            0x00000115f0fb27f5,
+        ])),
+        Felt::new(BigInteger320([
+            0x4cd990fc651cbefc,
+            0xab26054a4f2e7a66,
+            0x3017c53570fe7784,
+            0x7ff7cf39651bdfa1,
+            0x000000535eb69517,
+        ])),
+        Felt::new(BigInteger320([
+            0xc489a44d2d0512e3,
+            0x3db11e32445ad157,
+            0xdd283ea1470590fc,
+            0x6255d934597e70fb,
+            0x000001df90c54d7f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x4c6c5b0e34234aee,
+            0x8382cb9cf24f4528,
+            0x824a7baa19d92e7c,
+            0x546c851f0702ffa1,
+            0x00000277ec36dc42,
+        ])),
+        Felt::new(BigInteger320([
+            0x0da76ea6bf82c161,
+            0xda9ff1423afa4fd2,
+            0x2dfe26769f8f98ef,
+            0x86e6677c5685d396,
+            0x000001fa9bf48b5b,
+        ])),
+        Felt::new(BigInteger320([
+            0x04d5660b90b66ae2,
+            0xa60b8b3142dfe86a,
+            0x65587c006c09c567,
+            0xede9e7d5a974df6f,
+            0x000002449d610c37,
+        ])),
+        Felt::new(BigInteger320([
+            0x2edb23b384fa7073,
+            0xc2e7aaf15e875691,
+            0x418b0f615fd6591f,
+            0xf39773821f92a8e7,
+            0x0000039c39a098d7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xfc56e3996898d744,
+            0x4a38a76ca9441b63,
+            0x52d14bebd5f7b759,
+            0x3f2abcf7ab3b4538,
+            0x000000e95d67673c,
+        ])),
+        Felt::new(BigInteger320([
+            0x3cabf3e55f677358,
+            0xae7a8e591e6be444,
+            0x205ac558badad9f9,
+            0x7769de624663961a,
+            0x0000036f4db48bf7,
+        ])),
+        Felt::new(BigInteger320([
+            0xfda92f762285126f,
+            0xdd53aa8d2d7f1b91,
+            0x35fd8cb42b574792,
+            0xfc349c6ca0c4041e,
+            0x00000155595a3af2,
+        ])),
+        Felt::new(BigInteger320([
+            0xf73d31a018395d53,
+            0x5ac2795ffdcd7895,
+            0x90d22180cf76e2ab,
+            0x0d8370c8452da3cb,
+            0x000001de1f0e4083,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7ce709ac553b6e88,
+            0x679ebf7b1cc96e29,
+            0x9f6b8378feec7324,
+            0x67fbb13867bc9e03,
+            0x000001e784140559,
+        ])),
+        Felt::new(BigInteger320([
+            0x319f2789ed457106,
+            0x26d1bba47e4a6187,
+            0xbae93935f7b11355,
+            0x8af82faa26f7ea11,
+            0x000003a198566bc5,
+        ])),
+        Felt::new(BigInteger320([
+            0x096c7feff73f880e,
+            0x9eed258ba7ebe118,
+            0xf9b49a75934e3477,
+            0x7a3f136f6752408e,
+            0x000002a40f0ae3d9,
+        ])),
+        Felt::new(BigInteger320([
+            0xbc9e69eb16905d86,
+            0x04cd512405dacc25,
+            0x3fe1d6a84d591d6a,
+            0xdf93f77cec7c179d,
+            0x0000023b7afe7e32,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x549722d2f1dbd5ef,
+            0x2c66a2183a9a6cfd,
+            0xbc016f6618b63ffa,
+            0x13ea4236b415be80,
+            0x0000017c9b9033d3,
+        ])),
+        Felt::new(BigInteger320([
+            0x85afe3b3b4b17c28,
+            0xdced76680da600ce,
+            0x671b4a5a1272ef7d,
+            0x05c2d41b0f1d9a41,
+            0x00000211d3ec026d,
+        ])),
+        Felt::new(BigInteger320([
+            0x0c4c402250bc07cd,
+            0x800976c647f55e56,
+            0xce97c4eed058b696,
+            0x3fbd4359cbf04ab4,
+            0x000001b59d58c64d,
+        ])),
+        Felt::new(BigInteger320([
+            0xa40fe68ef3740aaa,
+            0x5b6d881aa761cd2f,
+            0x1225ec77b05773fd,
+            0x31fc919994613eb8,
+            0x00000284f3cd74b8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd5a4a59368143ddc,
+            0x71d2c572f4e32adb,
+            0xd2dd4a43b0a925d0,
+            0x89e1561f70740a32,
+            0x00000037d92dc1c8,
+        ])),
+        Felt::new(BigInteger320([
+            0x169b7e84cae7ea5e,
+            0x78cfc43e52b018b3,
+            0x1b0d90a1e91d1b59,
+            0xeacf12a383bcf85e,
+            0x0000022f9745ea64,
+        ])),
+        Felt::new(BigInteger320([
+            0xba42fee39008b6b9,
+            0xb3f15d96065e536f,
+            0x8b7302b0cd3d79d4,
+            0x2526a2d840c95a1a,
+            0x0000009429b3fd0d,
+        ])),
+        Felt::new(BigInteger320([
+            0x1123a3af1354c304,
+            0xe909f18c57fa0677,
+            0xcb9c53c2f21f5748,
+            0x83accb519fd45de3,
+            0x00000197e7f87634,
+        ])),
+    ],
+];
@@ -0,0 +1,1551 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger320, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger320([
+            0xfd4e2ac6c76c0128,
+            0x7b91c9cd7a6b2ff2,
+            0xcda9d9e4f0734c8d,
+            0x76d0a14abc1383bd,
+            0x000001802300dd28,
+        ]))],
+        vec![
+            Felt::new(BigInteger320([
+                0x60961f97b289e410,
+                0x57ebd22183ac2eca,
+                0x35b09e4050b2945b,
+                0x2253b6cecf72eeb6,
+                0x0000010ffa4c264b,
+            ])),
+            Felt::new(BigInteger320([
+                0xa4902acbf5ef52f5,
+                0x7095b652785bc2e3,
+                0xedc090f2576527fe,
+                0xb28c23cf42e51279,
+                0x000000e500f13a8e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x61cc2ffb6350c2e9,
+                0x50e23e7f5a3d520a,
+                0xb6b1c3ac484d0bef,
+                0x1e5cd69aadb92963,
+                0x000002601174bb03,
+            ])),
+            Felt::new(BigInteger320([
+                0x9fe22e6ab4c56a00,
+                0x776fc8f67ff8797d,
+                0xc82c66df0fb1974d,
+                0x44ada93a6b7f85bd,
+                0x00000342c2885588,
+            ])),
+            Felt::new(BigInteger320([
+                0x852e61cd52033d36,
+                0x1a35e8fa266a4cd0,
+                0xae1b9f85519ab404,
+                0x18bfcb09dfd6112e,
+                0x00000281a3b31443,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x19151ad48a9e4631,
+                0xf1a38a835141a6eb,
+                0xdd79a93458dd0fa8,
+                0x7ba56c8e5f0e0cd4,
+                0x0000011d9f80568c,
+            ])),
+            Felt::new(BigInteger320([
+                0x7c1523f3046a327c,
+                0x8ce9d1fde5cb9b47,
+                0xd75e4a54fee09b8a,
+                0x88b356081cd0bc32,
+                0x0000032514925e2f,
+            ])),
+            Felt::new(BigInteger320([
+                0xc733da19071817d9,
+                0xacad24618ac9f0a0,
+                0x9dcc711b4577d88f,
+                0xc0dd58071af5d15e,
+                0x000003082cb8ab58,
+            ])),
+            Felt::new(BigInteger320([
+                0xff2d57e77f84d25e,
+                0xdb1bbf89503b6814,
+                0x7f46beacf7c82650,
+                0xf4c656fcbbb7e77e,
+                0x0000024365e32f89,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x90fd19e737f90de9,
+                0xe2170884c7a5e3ec,
+                0xed37d29ef0ab0d6e,
+                0x93e22ae28540bb53,
+                0x0000026a2fe89c17,
+            ])),
+            Felt::new(BigInteger320([
+                0x36108fd8bfc34ec9,
+                0x3c9407216e16701c,
+                0x60421271415d1d8f,
+                0x1c5d07708770ce72,
+                0x0000012b08494751,
+            ])),
+            Felt::new(BigInteger320([
+                0x6fc31a7fc0ee312b,
+                0x847400b9d65d8661,
+                0x78a897be1c549137,
+                0x77f222657a6933bb,
+                0x000002d7d8f16f19,
+            ])),
+            Felt::new(BigInteger320([
+                0xaeb5d7c3d60ff9ff,
+                0x9dc601a913492cc3,
+                0x98f168de99685808,
+                0x9f109cab19999ed7,
+                0x000000c08afa107e,
+            ])),
+            Felt::new(BigInteger320([
+                0x4deeb6c65777a465,
+                0x5ce83c8631093db0,
+                0x06ce120cf12b797f,
+                0xe99bb9c4a4944b09,
+                0x00000154e065e76d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x19242235280eb8df,
+                0x0c616be4f184e1ea,
+                0x283274a827bfcef7,
+                0xd5811ca2a502daef,
+                0x000002e8ff71d048,
+            ])),
+            Felt::new(BigInteger320([
+                0x8e404da5ab723047,
+                0xf2694086a1f52dce,
+                0xd4487f877b0893be,
+                0xa04dd2556c25a1b6,
+                0x000001c39f4f2d2d,
+            ])),
+            Felt::new(BigInteger320([
+                0xe3dd3e41ffb8bff7,
+                0x2fa91e2444b380f1,
+                0xe8f3847d1a880b49,
+                0x02133d434f74bfc4,
+                0x000000ed4a047116,
+            ])),
+            Felt::new(BigInteger320([
+                0x00b700598319d985,
+                0x8b361c2916226913,
+                0x6819ec2d58653daa,
+                0xfb5c51c8ffd54c5d,
+                0x000002c0cc1b71f4,
+            ])),
+            Felt::new(BigInteger320([
+                0xd75015559d48f359,
+                0x0f0f8ebc5b7eb524,
+                0x60c07fd723f59502,
+                0xd3835dab9b363281,
+                0x000002ca88aa875c,
+            ])),
+            Felt::new(BigInteger320([
+                0xe169330ebb83f141,
+                0x9a3006b7d65a3872,
+                0x26035cbd8dfd8fa2,
+                0xe6449c34e4dd8ea7,
+                0x000000e220efe8a7,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0xc271523a89219fe8,
+            0xeb0655ea85f67472,
+            0xd738fc84979daef8,
+            0x63e8273536ece61a,
+            0x0000013737701c82,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xec3a3e4bf647fff8,
+            0x7c680922cf3d10af,
+            0xd706a7c4ecd0febf,
+            0x800a863ff6eff644,
+            0x000000715bed05b8,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x39f38b9c48ef076a,
+            0xb56ddd279aca968c,
+            0x8c76f080ca95b200,
+            0xcfcc10c52aa7db22,
+            0x0000018d7e3b0624,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xed49d7e9809a682c,
+            0x97007ce5eceaad14,
+            0x470884f09deb63dc,
+            0x45f9bdaf6d1afaae,
+            0x000003b87994efa9,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xd289ca91b3e75a27,
+            0x001bf089a3700ae7,
+            0x60c353f044d3581f,
+            0x273b62811fe1b064,
+            0x000001b5e315b6f3,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xdfe4ba84d25939de,
+            0xb5de47d9dbd5e9e1,
+            0xaa29a755e8ebf3f2,
+            0xc2da05a612b511ab,
+            0x0000025479e1a699,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xc58d639feff8d0a9,
+            0xff7eae67aa8772e1,
+            0x59d7ea6e65d6b776,
+            0x37dc94d30115ec88,
+            0x000001dc674aff1b,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x4f2a69e8141392bd,
+            0x22b68d20390f3053,
+            0xe4a1ed6dedf16c0b,
+            0x19f994d7bee33969,
+            0x0000025ef2753293,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x1abf26fe4ac88d94,
+            0xb8338737b2f6ca81,
+            0x98c775aa4c620957,
+            0xa55aed4d918a1e98,
+            0x0000033c785e5249,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xd6742cd81ec0d8b9,
+            0x74c7e571fa50b945,
+            0xda60385a4fb9a216,
+            0x5878e7a398e967ba,
+            0x0000033d7114a50f,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xfec92bb940b0e414,
+                0x443bf87c429f2173,
+                0xe4c0dd97d5a286db,
+                0x82773e4a572a5cb6,
+                0x000002d65f617729,
+            ])),
+            Felt::new(BigInteger320([
+                0xda6787b64379fd3c,
+                0x5657c26a6714c429,
+                0xec62414a88249aa8,
+                0xfb8ff0c87ba4f7da,
+                0x0000034e3da47db8,
+            ])),
+            Felt::new(BigInteger320([
+                0x7e033b30b2f010b2,
+                0x16fcba94ec19ac62,
+                0x1efbdfaabef71754,
+                0xdf93b8f53ffa4fb2,
+                0x00000126545c486d,
+            ])),
+            Felt::new(BigInteger320([
+                0xf718b2f65f46e073,
+                0x36bdfbc10949b5a6,
+                0x64c897c35c124809,
+                0x71d8e5ac748ea9f5,
+                0x000001f28ad73cce,
+            ])),
+            Felt::new(BigInteger320([
+                0xe71b1a1f36f63ff3,
+                0xf3abc06143d757a2,
+                0x99f808f798a9de6e,
+                0x04f501c064ecb43e,
+                0x0000025ecf8ea058,
+            ])),
+            Felt::new(BigInteger320([
+                0xd1c0b88f0e268f6a,
+                0xb207a5a1d14e0308,
+                0x164fe10a385f2485,
+                0xb291ff3452c4e3b0,
+                0x000000c6a42508bc,
+            ])),
+            Felt::new(BigInteger320([
+                0x3636619f4822fa8a,
+                0x2485b91a15b4f795,
+                0xa6500e9f17d03ebb,
+                0x2490ab98b6dacc29,
+                0x000000cf090a8a8a,
+            ])),
+            Felt::new(BigInteger320([
+                0x702e68714d72d4a3,
+                0xa3fa3403a863945b,
+                0xd15b51aba9422c11,
+                0x2e34a07c70d90df1,
+                0x0000013fe7ea46bd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x73daca1bd2b13eea,
+                0x094eeac1826cfc9f,
+                0xf7b505890876f872,
+                0x015349bbf2f2ab23,
+                0x0000024be805da09,
+            ])),
+            Felt::new(BigInteger320([
+                0x743e22956d7ed461,
+                0x3166131ed053fc5d,
+                0x394b68a9fd6b0db3,
+                0xa694946b58301669,
+                0x0000003a236d7deb,
+            ])),
+            Felt::new(BigInteger320([
+                0x72f4ea06023c5686,
+                0x3452ddb19da6a6c4,
+                0x3e771726e040fcb3,
+                0x585698c426034b69,
+                0x000001bfe14a0219,
+            ])),
+            Felt::new(BigInteger320([
+                0xb2fdcd40a4e4db10,
+                0xcbd181da42db5c3d,
+                0xf95791d6c30daa2a,
+                0xcff9baf669281385,
+                0x000001648d2f5ee8,
+            ])),
+            Felt::new(BigInteger320([
+                0x54e1e425bcd262f4,
+                0xa00b65660095e83f,
+                0xc7bc72d9a5e16ca7,
+                0x21a0a3f72b5002bc,
+                0x000000ef6d101534,
+            ])),
+            Felt::new(BigInteger320([
+                0x43dca79ae3838651,
+                0x7089e577ada62b95,
+                0x37c94bd9fd60f6cf,
+                0x1d7b5a65f345b1f9,
+                0x000001c8be2e9c08,
+            ])),
+            Felt::new(BigInteger320([
+                0xbc2c6f6638e0fc09,
+                0x763f6b93ac4a8870,
+                0x4db32ea80c2ad4b0,
+                0x07d6cb348d988957,
+                0x0000024525cd7f5b,
+            ])),
+            Felt::new(BigInteger320([
+                0x0be730545c27d753,
+                0xee150ad2cf531128,
+                0x5771d60ca11530f0,
+                0xeced0059f11c1729,
+                0x000002b6c81eb1df,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x01a569116376dbc1,
+                0x810f10e174954fbb,
+                0x3d74d7c12c796afe,
+                0x7248a8e1953923ed,
+                0x0000011b61358ef9,
+            ])),
+            Felt::new(BigInteger320([
+                0x8f04ea74ddf694c8,
+                0x0c5b62b0339d697c,
+                0xc9b0f91f7c8cc4d4,
+                0xea0c2c29d7b36eea,
+                0x000000ac8ff4e75e,
+            ])),
+            Felt::new(BigInteger320([
+                0x343d9add4ebf3161,
+                0xd7c66d506d58887a,
+                0x3a083b4e126e0479,
+                0x0fdbcde9d8497251,
+                0x0000015eb968e108,
+            ])),
+            Felt::new(BigInteger320([
+                0x646c58b6631efd88,
+                0xbc4077b36d1461c3,
+                0x099e8f51469cd34c,
+                0x85e046b9cb803c2f,
+                0x000000516c43a12b,
+            ])),
+            Felt::new(BigInteger320([
+                0x962386dcf04addb4,
+                0xd212f53ddaa061c5,
+                0x1ffd50b62ead836a,
+                0x107e45f2c95ea7d4,
+                0x00000320108460f3,
+            ])),
+            Felt::new(BigInteger320([
+                0x0e562ba8760811a9,
+                0x824ebccb64fa9059,
+                0x330107a8983b66b9,
+                0x501f0d6c7a24f952,
+                0x000002b3f7dac9fe,
+            ])),
+            Felt::new(BigInteger320([
+                0xb93cd6fe1305cb8a,
+                0x885534dc82a578d5,
+                0x15d45db2d0e7cfe9,
+                0x7855ccd8b819c913,
+                0x00000023fdd31e37,
+            ])),
+            Felt::new(BigInteger320([
+                0x56ceaffbe5871edc,
+                0xa9d80bc7c6bc3e42,
+                0x1b467b9eda640a26,
+                0x7b12b08e58dbfd32,
+                0x000001c1e8b1451a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x7584630ee62ba25a,
+                0x50730e3a0174a805,
+                0xd10c711be2defc62,
+                0x07a6e86f43d616e2,
+                0x000001a16f9849e6,
+            ])),
+            Felt::new(BigInteger320([
+                0xf64dd16f2c3af610,
+                0x52924d09035178d5,
+                0x00a268c862705236,
+                0xa5244e64a5a87e63,
+                0x000002c60222a3b4,
+            ])),
+            Felt::new(BigInteger320([
+                0xfc8964bb02fdae36,
+                0xaac4d7b96d3c4f29,
+                0xd18ecae17a3f3179,
+                0xcc81727fa2dc921f,
+                0x0000002e3d550fbe,
+            ])),
+            Felt::new(BigInteger320([
+                0xcbfce8a25330848f,
+                0xaaa650a9539ca222,
+                0x7578140ca2a6f086,
+                0xc34cfe953ce16e6a,
+                0x0000017c9fafeb3d,
+            ])),
+            Felt::new(BigInteger320([
+                0xc7b0f6f63f721b35,
+                0x4249d4749ddfaee1,
+                0xddefdd458486e082,
+                0x31e41f618a073fe7,
+                0x000001ae645dd7eb,
+            ])),
+            Felt::new(BigInteger320([
+                0x4e3365477f015de1,
+                0x130efa156f9d314f,
+                0xb93e600c23c46424,
+                0x1df40d48c0beb0a0,
+                0x000001f6c83cc9b7,
+            ])),
+            Felt::new(BigInteger320([
+                0x657329d3c807181f,
+                0x35ba406aca997aff,
+                0xaf49e7939f772e8e,
+                0x3a30fbd136412f98,
+                0x00000144a6d40b75,
+            ])),
+            Felt::new(BigInteger320([
+                0xe48b8966efed79f1,
+                0xd1542f3b2e5fe126,
+                0x95703de9d8b10b01,
+                0xcfcaae8a573997a3,
+                0x000000bbc9eccfbc,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x5255f4f723123c04,
+                0x1081f5eeb4b51371,
+                0xc9f2be10c3896c68,
+                0x515d74d054f8661f,
+                0x000000479a8645d4,
+            ])),
+            Felt::new(BigInteger320([
+                0xce7673ffeff2ceaf,
+                0x1a9bdfede3b91eaa,
+                0x41282002953740f3,
+                0x06cf073f6e3b0dbd,
+                0x00000252b3528da2,
+            ])),
+            Felt::new(BigInteger320([
+                0x1476ded365f46d77,
+                0x32c9e02f3c2dbab8,
+                0x48c99b52c33e2e21,
+                0xe311422a0ecf17b0,
+                0x0000035d9d6290c4,
+            ])),
+            Felt::new(BigInteger320([
+                0xa6b36b5b69a22e1a,
+                0xa6bf037f915b65c5,
+                0x6ff803f448be9a6c,
+                0x6d71b4157f70fe0e,
+                0x000003014bb093ce,
+            ])),
+            Felt::new(BigInteger320([
+                0x836ebcc6c7f48b83,
+                0xc1c11d4b021b8f37,
+                0x29358c78fc713d01,
+                0x67ea150abbfa8249,
+                0x000001753d100967,
+            ])),
+            Felt::new(BigInteger320([
+                0x2c73fa6c78a36ec5,
+                0x2f7bb3e8a09f1073,
+                0x8a8f1afe2b08f917,
+                0x6ee1e9e75bbb9cfe,
+                0x00000331cf1029b0,
+            ])),
+            Felt::new(BigInteger320([
+                0x2f8f6df79d4deb31,
+                0x56d729c1f94f01e4,
+                0x410d656c696f8cbc,
+                0xd1abd358e060431b,
+                0x000002c4ecbea9a5,
+            ])),
+            Felt::new(BigInteger320([
+                0x9d619dfc8eff68e3,
+                0x074b002814a9f80e,
+                0x90966136f9c98ae0,
+                0x723eb00807374de4,
+                0x00000085354fdc4e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x450ddb0e7d68f67b,
+                0x846cd17876139ada,
+                0xe793c3692e172956,
+                0x8439fb1e3b309026,
+                0x000000cb60b1fb29,
+            ])),
+            Felt::new(BigInteger320([
+                0x9e5d731183923a2d,
+                0x4a991b04bf94e66d,
+                0x67a6764bb5850259,
+                0x0ad65e26a56d4c01,
+                0x00000261fff72e8c,
+            ])),
+            Felt::new(BigInteger320([
+                0x4f57d7203fe2fe1d,
+                0xcb572f47d9fa16e2,
+                0xbfcb44e43fac7721,
+                0xfeacde7d62df9081,
+                0x000001ab4cca75af,
+            ])),
+            Felt::new(BigInteger320([
+                0x987a77e32b977671,
+                0x49ac77a912b82de7,
+                0x5ed4ca8a78c61fcd,
+                0x54c333dcdd7953ba,
+                0x0000000f3eecf44b,
+            ])),
+            Felt::new(BigInteger320([
+                0x2b67d5cc0eca3cd9,
+                0x9ecd8e7f58c4cc1a,
+                0x74e60e35aac476ba,
+                0x297a457357867f85,
+                0x00000263bc6620d0,
+            ])),
+            Felt::new(BigInteger320([
+                0xe90a944e9c986884,
+                0x4dc0fe6bef7589c7,
+                0xd602782b73d9a3bf,
+                0xbddc8daabb081b69,
+                0x0000036aa1f2aff6,
+            ])),
+            Felt::new(BigInteger320([
+                0x51f3dc7e1bf56ef7,
+                0x4d1165dda8b7a5a7,
+                0xcc9a0faa7f7836f0,
+                0x2a8c91bfe8098f46,
+                0x0000017ab34e733d,
+            ])),
+            Felt::new(BigInteger320([
+                0xeea72bfc842b4887,
+                0xc86d4162c57458ee,
+                0xb192fb9b92ead5eb,
+                0xd2654096163cde45,
+                0x000001dd014607f2,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger320([
+                0x162ad5483d83ae96,
+                0x18f5cb571942fa85,
+                0x6a9c543788eb2b9b,
+                0xd4aa2aeeb8d6d795,
+                0x000001e9e059cacc,
+            ])),
+            Felt::new(BigInteger320([
+                0x08e3077e6885aaf9,
+                0x91abc3a0b50b4854,
+                0x75d76ec55172964e,
+                0x68ab6c306e87d328,
+                0x00000008021240a4,
+            ])),
+            Felt::new(BigInteger320([
+                0x0a674221e69a79cc,
+                0x62cf7fe08750a642,
+                0x284dd022890ea9d7,
+                0xe8a707548010e319,
+                0x00000068abd96747,
+            ])),
+            Felt::new(BigInteger320([
+                0xd71d1c1a5c45856d,
+                0x098f0a7a3a10e57c,
+                0xb6a743a4a147a8cd,
+                0x78880cf5e49595bf,
+                0x0000037271869649,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x2042913085922b49,
+                0x36c4eed04012c5d5,
+                0xd4b4085f482d5763,
+                0x1293b730ec947b35,
+                0x00000084ed545a9b,
+            ])),
+            Felt::new(BigInteger320([
+                0x0680102a2d7548cf,
+                0xffd124585da45a2f,
+                0x8ba1caa7ef405672,
+                0x07a445cbb37d61d4,
+                0x0000025497bc9674,
+            ])),
+            Felt::new(BigInteger320([
+                0x1947ea4d1e0a2ed8,
+                0x5f23b5fffadbc3de,
+                0xba4647d1efcd666e,
+                0x0e6f472a1ba8d0c1,
+                0x000001cce2ca3671,
+            ])),
+            Felt::new(BigInteger320([
+                0xce164064a997b6b0,
+                0x8fd87dde18c6e69e,
+                0xd5d183df2fa83d3d,
+                0x8b57a57e96493448,
+                0x0000033c9525b716,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xdaa742fbd1f1a1be,
+                0x98e1aa853577165f,
+                0xd454e1f77a9b47e6,
+                0x8872d745b905c5a3,
+                0x00000224249d5089,
+            ])),
+            Felt::new(BigInteger320([
+                0x2a147335834dc43c,
+                0xe01c11677f93faee,
+                0xb5e011345b1e6f35,
+                0x47cb3c7c337d2390,
+                0x00000066eeb9ed33,
+            ])),
+            Felt::new(BigInteger320([
+                0x49c23f77c5ae0d52,
+                0x15ac6da3dd9fb358,
+                0x297c6ff0695e8acb,
+                0x54ef5cc9186d0a37,
+                0x000000cb92efc33e,
+            ])),
+            Felt::new(BigInteger320([
+                0x3c9a99564e9808e9,
+                0xbc7e067503e739e8,
+                0xd765e55a3f29c54c,
+                0x1192693a42fa478b,
+                0x000001907aecb10c,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x533cd23030feea85,
+                0x403cafff8ce71d11,
+                0x8439b15495a25c70,
+                0x095d4e4b099e0e8d,
+                0x000003550d9f1605,
+            ])),
+            Felt::new(BigInteger320([
+                0x8b26f394bda919b8,
+                0x0f1742931732fc38,
+                0xe2016b057ad7fa0e,
+                0x8610249bf7e67635,
+                0x00000197534dcc3a,
+            ])),
+            Felt::new(BigInteger320([
+                0x5e6ff936376c2692,
+                0x2a2dc04197c043a9,
+                0x5dd25286a3fe888c,
+                0xbb890f230403a1f7,
+                0x00000361b08194f3,
+            ])),
+            Felt::new(BigInteger320([
+                0x4b9b63a0f5435e7c,
+                0x6378539ad030f9be,
+                0x55c38b38bf235995,
+                0x6ad51130f257bb9b,
+                0x000001d7a40c7953,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x993f3c1b97a2bcd5,
+                0xf5351120c20a03ca,
+                0xe1042f07e8540a60,
+                0xcb14b639bd425f58,
+                0x000000222a56ec6a,
+            ])),
+            Felt::new(BigInteger320([
+                0xdfbca50d9f38d4fb,
+                0xd8994da89c89c56a,
+                0xc022f8217941bb85,
+                0x31f1969984826fc5,
+                0x000001903e27783e,
+            ])),
+            Felt::new(BigInteger320([
+                0xd2f35e61ce804e28,
+                0xe67694c875b96379,
+                0xab15085595f1fc6e,
+                0xd71dcf3d009a010b,
+                0x000001d8fef82b37,
+            ])),
+            Felt::new(BigInteger320([
+                0x0299cf19479bd1e1,
+                0x015b76bdfbb87f84,
+                0xe60324bde57ecfb0,
+                0x662280e5c016979e,
+                0x000003ab5b08fde9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xb66dc92ab1e12170,
+                0xad7a643dfdc21d9d,
+                0x8e94f552a5ad6e73,
+                0x7977ca1a33475eaf,
+                0x0000026b561f8eb0,
+            ])),
+            Felt::new(BigInteger320([
+                0x9e9206037b55f254,
+                0x7c72a9f425388053,
+                0x8fdc9110263c3c5f,
+                0xc61d508bdab3b05c,
+                0x000002177ca7c3b9,
+            ])),
+            Felt::new(BigInteger320([
+                0xa55f4775658d225b,
+                0x4ad97b9ef1474688,
+                0x52822d8f45cc7004,
+                0x9bb8cb13762fd56a,
+                0x0000028f5e1907a2,
+            ])),
+            Felt::new(BigInteger320([
+                0xfbf8388a27b6fe6b,
+                0xa33f1aba8d7e4403,
+                0xffe1f27cde856340,
+                0x23a8402385fec2ec,
+                0x0000024ed5718d35,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xbdfeb44f0a0b5a59,
+                0x73baa3f3e17bb060,
+                0x1c52684b533e0fc6,
+                0xda03e3f6a64f6ea1,
+                0x000000ba9acf50fd,
+            ])),
+            Felt::new(BigInteger320([
+                0x395082c08774c704,
+                0xa02d88f2602bb7d2,
+                0x32ae1d8e9fcbdcfe,
+                0xbd1ebcfdda6c6ada,
+                0x000002148ca5d58e,
+            ])),
+            Felt::new(BigInteger320([
+                0xcd3e0c7b88a4a7d0,
+                0x79fe59746733aa92,
+                0x027d4f032c749f28,
+                0xbdc8df896ea90596,
+                0x000002a2635a141c,
+            ])),
+            Felt::new(BigInteger320([
+                0x8d0ef0b475b46c0e,
+                0x691cd80fa9fd6f1c,
+                0x522b3db5cbace4bf,
+                0x6337698b6ca6437d,
+                0x0000018e15a26428,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xd5b0e07db838416a,
+                0x021cb01315158410,
+                0x0f4302e363f2c94e,
+                0xc3f5a62f40381c75,
+                0x0000003f04af41b3,
+            ])),
+            Felt::new(BigInteger320([
+                0xce36c484b15feac2,
+                0xaffdc2957cc93298,
+                0x83407755bf054887,
+                0x2525677c82887b2f,
+                0x0000010b4147a1e2,
+            ])),
+            Felt::new(BigInteger320([
+                0xbc2007936c06a27d,
+                0xe3ce1304bdd6a9b8,
+                0x0ac515b7f822f8ab,
+                0xcc718bfaeb1f01a3,
+                0x000001be1a10ea78,
+            ])),
+            Felt::new(BigInteger320([
+                0x727e4526e1bf1375,
+                0xe02f7457936a8e66,
+                0x243d9021484b5a9d,
+                0xefd4570032524983,
+                0x000001c5f6a6423d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x4378194360f03a3a,
+                0x6bcea5d0a79051bc,
+                0x51306cefdb38e83f,
+                0x67666bb83f586614,
+                0x00000390dc6c4b8e,
+            ])),
+            Felt::new(BigInteger320([
+                0xade46a9bcd0b0848,
+                0xf1877388bd611683,
+                0x9fcdebcab53efefb,
+                0xbc637571facbb423,
+                0x00000087d9ea3a44,
+            ])),
+            Felt::new(BigInteger320([
+                0x8b789e365e4e05ac,
+                0xee80e6c632945f3d,
+                0x993b83cd19fda30b,
+                0x9b991e97aa96f855,
+                0x000001b07a9b245a,
+            ])),
+            Felt::new(BigInteger320([
+                0xa771938afa86328b,
+                0xb19b04d28d797c8f,
+                0x49a6353f563845ee,
+                0xc1baf742d8203206,
+                0x000001ae9e116a64,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x5dbde06bd780452a,
+                0x7b5159d876f88056,
+                0x6b211e13fe4080f1,
+                0xf0b3ca965af44b42,
+                0x00000090ad0cc3e4,
+            ])),
+            Felt::new(BigInteger320([
+                0x7519e4adddad67b8,
+                0xf2bdc3b09c42c11e,
+                0x6e14b871df32cb7d,
+                0x9acf09e0bfdf9c3d,
+                0x000000524f2e9123,
+            ])),
+            Felt::new(BigInteger320([
+                0x53a138c75990f981,
+                0x5288497dc3a72a1e,
+                0x665dc69202583a75,
+                0x7e1061b34f632a59,
+                0x0000034c4155aa70,
+            ])),
+            Felt::new(BigInteger320([
+                0x8f5c49f7157609cf,
+                0xf6580b9a8e37f943,
+                0x514fa7b06207fd49,
+                0xf02323f15de72055,
+                0x000000ec6e1e50ef,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x20eda82a31b4aaff,
+                0x7d31fc7ca90483cc,
+                0x44c2fa6023cd819a,
+                0xfbf7ac1249671907,
+                0x00000269f429793b,
+            ])),
+            Felt::new(BigInteger320([
+                0xd1aa64efb2ff7c0f,
+                0xa0ecb4ebbd72b4b6,
+                0xce6b1f5a0e810615,
+                0x33515ddd51b25172,
+                0x0000012a309e1e92,
+            ])),
+            Felt::new(BigInteger320([
+                0x825db37375178825,
+                0x61bba8368b0b61ad,
+                0xe56609602c0180d8,
+                0x0a472b96741e8854,
+                0x000000b9d9905156,
+            ])),
+            Felt::new(BigInteger320([
+                0x88b176d5fdd3b16a,
+                0xc25286138bcc59f9,
+                0x9a4224cfc861b290,
+                0x795d569e65e38b43,
+                0x000000b4bb921c0b,
+            ])),
+            Felt::new(BigInteger320([
+                0xd12f5948df19ba35,
+                0x97c25de3012fb81f,
+                0x30fb16394d1b05d2,
+                0x72119e6f6d4eaba7,
+                0x0000011e4564653e,
+            ])),
+            Felt::new(BigInteger320([
+                0x68c08ebcdc2a09e1,
+                0x10eba28ab28304d9,
+                0xab7a8e095f4bf625,
+                0xb28579894268c82c,
+                0x000003198778024e,
+            ])),
+            Felt::new(BigInteger320([
+                0xd72a3beb1c0276a8,
+                0xda769bbed2e2acfe,
+                0xcbe1755b10761fd9,
+                0xa3042caaf1f07dec,
+                0x0000031f851a23af,
+            ])),
+            Felt::new(BigInteger320([
+                0x311891f6ccbe5f6a,
+                0xc9a8548ad83d6d8f,
+                0xbd176e1ac49e2bb1,
+                0x4e537bbfd65f84f7,
+                0x000003165b608d3c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x5fe8feba1c1e8a64,
+                0xd24c159016d53e22,
+                0x8fec2047114ee213,
+                0x98f937bd7c3211c1,
+                0x0000016ebd1c28c5,
+            ])),
+            Felt::new(BigInteger320([
+                0x1ac5f7cb5f173353,
+                0x84df3d772f1cd4fb,
+                0xb724dda264f4e66f,
+                0x7721062b3f0e318f,
+                0x000001adf1002846,
+            ])),
+            Felt::new(BigInteger320([
+                0x2397f4d7f0582a1a,
+                0xc5764acb1cd7141c,
+                0xe8941e2a11dd2424,
+                0x14ab8f09b79cdf65,
+                0x000000133733cdc9,
+            ])),
+            Felt::new(BigInteger320([
+                0x4284b8367c2162f0,
+                0x4feaa5669f1ca535,
+                0x4f491f681442c3a8,
+                0xfc07e12305e32937,
+                0x000003357234b014,
+            ])),
+            Felt::new(BigInteger320([
+                0x49795a5e91e6c64d,
+                0x947561a442650210,
+                0x2d0d2869436a8241,
+                0xc252452abc9ac047,
+                0x000001be11a9b7ba,
+            ])),
+            Felt::new(BigInteger320([
+                0xaa8db39bc498531d,
+                0x303cb07a0af970a4,
+                0x5696ae7c6c56c348,
+                0xa400e932ebad465c,
+                0x0000000d9b3795ee,
+            ])),
+            Felt::new(BigInteger320([
+                0xf8033d1c0c392e8f,
+                0xbc966dc630199bb9,
+                0x443ccc1bd581bfe4,
+                0x4477b1cffb5a8b20,
+                0x000000e28256f6a9,
+            ])),
+            Felt::new(BigInteger320([
+                0x9e05e88d0ee6e68d,
+                0xe3ec065a02f52775,
+                0x99d26263a0d7687c,
+                0x675a419515be84a6,
+                0x0000007217a02257,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x99f2b57513683522,
+                0xd2c3ae3123ab9d55,
+                0xa193caee3a7f224a,
+                0xfd5c63db6315a3b3,
+                0x000000ce138b4efa,
+            ])),
+            Felt::new(BigInteger320([
+                0x0b71edf516210bc2,
+                0x2779d80317534c73,
+                0x7604eae430437dbc,
+                0xf36c196a5647143a,
+                0x00000022234eb00e,
+            ])),
+            Felt::new(BigInteger320([
+                0xebbb7c566426e42b,
+                0xb60996191bae2cfb,
+                0x2aaefe66eef8d569,
+                0x788ff82c2963681b,
+                0x0000018cca48c8de,
+            ])),
+            Felt::new(BigInteger320([
+                0x454b9709f55bf0ca,
+                0x06a664abca686751,
+                0xca5da0e4994a9e7a,
+                0x07393422a29c9cd1,
+                0x000001474bb01532,
+            ])),
+            Felt::new(BigInteger320([
+                0x762ef1347c41a797,
+                0x4d4e712a4ebd08f2,
+                0xfec518cde5b0d8a4,
+                0xf2cbbe4e56c5dcb9,
+                0x000001477eb4bac7,
+            ])),
+            Felt::new(BigInteger320([
+                0xa7148598c906e4c9,
+                0x3be9d62cbff41c5d,
+                0x62336c88c993b0cf,
+                0x87c459af2d383c28,
+                0x000002519ca8952f,
+            ])),
+            Felt::new(BigInteger320([
+                0x932f60b0cb9ddd57,
+                0x3264ccc63bd2fcb6,
+                0x4bf8cca9d63384d2,
+                0x74b7cc9c4b707fa6,
+                0x000001a4f50ea122,
+            ])),
+            Felt::new(BigInteger320([
+                0xe4ef33d729747973,
+                0x112a3c5c2b929341,
+                0x1e02ce516d67a5f4,
+                0x682cca312a180789,
+                0x000000d164f59e9f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x061ed0eec18fe12b,
+                0xb5ae66cbbe159a31,
+                0xbaf9a9c7986eede6,
+                0xec40e46a24a5a0a4,
+                0x0000007f6ee5d57c,
+            ])),
+            Felt::new(BigInteger320([
+                0xad87239eb25e3520,
+                0xb70d4ee1456dc926,
+                0x168eed1cd9b12b82,
+                0x662cc65a55f84a86,
+                0x0000027eadda5fa6,
+            ])),
+            Felt::new(BigInteger320([
+                0xf54f6d15758c3014,
+                0x8654edf933aa54ed,
+                0x325e435472dc3d87,
+                0xc0e4200cbadfffed,
+                0x000001e3c7eba570,
+            ])),
+            Felt::new(BigInteger320([
+                0xb4e062e089f176ed,
+                0x02b360ba20f9c5b4,
+                0xbce02988356ef836,
+                0x9ecc7677db25cc3c,
+                0x00000134ebb8a0c3,
+            ])),
+            Felt::new(BigInteger320([
+                0xe9c3526c1a875ebd,
+                0xab1e258b2accb00e,
+                0x1e8b36c69eea0d00,
+                0xcb77424bb7fb987f,
+                0x000001f053b81a8c,
+            ])),
+            Felt::new(BigInteger320([
+                0x9c389facb7b19312,
+                0x45c3a7adb7a470ba,
+                0xff36ebdabab51ef4,
+                0x60e66fa1ee4e2ac4,
+                0x000002dac9cf5bb5,
+            ])),
+            Felt::new(BigInteger320([
+                0x4e0aa6418093ae67,
+                0x0dfeca9f502e2fb4,
+                0x345033d4bda223fa,
+                0x379e539d5a850d34,
+                0x0000016b9e5ded30,
+            ])),
+            Felt::new(BigInteger320([
+                0x3d87240f860dc926,
+                0x66234bb65a085e76,
+                0x487955ac5ca8f1da,
+                0x5fbfb7a25d0832bb,
+                0x000000e3cd4cc019,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xc5e0b2fc593c8a8b,
+                0x662fc92f70b58ab9,
+                0x404edf46d5954012,
+                0x4451051dc8216892,
+                0x000001484a4b2b33,
+            ])),
+            Felt::new(BigInteger320([
+                0xca7ac472c8069dc9,
+                0x9fe58f16e96ba27e,
+                0x79d578d61bfcf661,
+                0xb9bb4997e144dbe0,
+                0x00000262e6495f15,
+            ])),
+            Felt::new(BigInteger320([
+                0x425a6ba704bbe0d3,
+                0x18117fbf81396000,
+                0x56c99f686eae9fed,
+                0x5cdd9bf6a5de6757,
+                0x000000e0071188e1,
+            ])),
+            Felt::new(BigInteger320([
+                0xeae372216bb83941,
+                0x0740d4b343f6c870,
+                0xaaa3dd48d517a673,
+                0xd210633fe8b8a12e,
+                0x0000024beda0cad7,
+            ])),
+            Felt::new(BigInteger320([
+                0x0f9a65a21cca1edc,
+                0x48ef2ef7243cf6ee,
+                0xb697cbbb8677703a,
+                0xb287f019319309f2,
+                0x00000331ba698282,
+            ])),
+            Felt::new(BigInteger320([
+                0x1ee49aa597f15f2e,
+                0x0b18b4c6dd452e7c,
+                0x024c4ce642ccd0ae,
+                0xaf98e7b3f8d6745c,
+                0x000002f5a9d72f84,
+            ])),
+            Felt::new(BigInteger320([
+                0xfe7e7861423ad998,
+                0x6262b3472b6a1550,
+                0xce36884a6025126b,
+                0x6a51b9951d8a837c,
+                0x0000004db4be3714,
+            ])),
+            Felt::new(BigInteger320([
+                0xc219b89686169f20,
+                0xcbdd7c82c1a363c2,
+                0x698fd6ae95584c23,
+                0x24ae2173240e2a6c,
+                0x000002e5ba5da0a1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x69ccd8f29e0ecf77,
+                0xcb1aebd0ff91fd06,
+                0xf1191a3f0a6cb3f0,
+                0xcaae92effd2e6ae0,
+                0x00000383d0fbff71,
+            ])),
+            Felt::new(BigInteger320([
+                0x404a438cbb04e044,
+                0xeb2790204003b210,
+                0xc10a20a0e99ff295,
+                0x03504ab419d32677,
+                0x000001784fb1cb2d,
+            ])),
+            Felt::new(BigInteger320([
+                0x251e18634984b5cf,
+                0xd70edfffc822d0c1,
+                0x4aa3522cfa2f388a,
+                0xbcff1ee6cdd62230,
+                0x0000020f7563b28c,
+            ])),
+            Felt::new(BigInteger320([
+                0xc1268a9ed19d067a,
+                0x8b3c25aac63ecd34,
+                0x186902a3ab55c971,
+                0xdc9fd1520a3aa521,
+                0x00000032b72848c3,
+            ])),
+            Felt::new(BigInteger320([
+                0x299fd2bceb353b22,
+                0x68f146b8ba90d54a,
+                0xeb49ee4317da7a39,
+                0xd16cbc14f9e71f1d,
+                0x000000e52c01f6da,
+            ])),
+            Felt::new(BigInteger320([
+                0x429f8e22ce3047d3,
+                0x28f608a7eee48398,
+                0x460d3083c6c0cad0,
+                0xcb507b966a59a924,
+                0x000002b79635d49e,
+            ])),
+            Felt::new(BigInteger320([
+                0xc00aca65bf7ab7ae,
+                0x3be52c32e8bcbd2c,
+                0x3ef1e3cc53daace8,
+                0x04c31ee8ad1d3580,
+                0x0000022fddb4b722,
+            ])),
+            Felt::new(BigInteger320([
+                0xee08c8e1b9de6cdc,
+                0x01e6e3dd9d1e32f0,
+                0x394f27c27163f6f0,
+                0x0157c2ee850f45f4,
+                0x000000c421384ec5,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x378564a8778358c7,
+            0xd55635f43e8fc085,
+            0xf47a0d60a7a0e3a3,
+            0xfc1e86cbe454cf07,
+            0x00000210080f348e,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x7c071f5797dd599e,
+            0xa23e804a0f19ae5b,
+            0x5a900bf19cbcefad,
+            0x6f32a06a02a338f7,
+            0x000000690d8735af,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xc20bb8a4f81f7c34,
+            0x097e4ca74571f07b,
+            0xc0287f13212ed64a,
+            0x9459b527a039e668,
+            0x0000012a2976dd93,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xf65575e7388b8949,
+            0x59a63fb269cb3a8b,
+            0x83f36752b975d6cb,
+            0x70ff49ffa87f3938,
+            0x000002abc601479f,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xa107ebdfe5fcc94b,
+            0xa655a246c6417901,
+            0x5e8b0371a6df8a2a,
+            0x7280bcf14d046278,
+            0x00000244c6681dbf,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xda59d45cd5d8802a,
+            0xbcd4b4da70183247,
+            0xa4a8d038262bad7c,
+            0xcb7c2bfcff53714f,
+            0x000003a04ad55b7d,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x752c7855e99d5ef5,
+            0x21f4a6ff09950873,
+            0x2e8d21620b8cecb8,
+            0x1b3955436c984eab,
+            0x000003b06899b92c,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x922f6ff6993b7dd4,
+            0xb39f5f2fa3d7e075,
+            0x2f03afe44e5e33fd,
+            0xca57e6ec43500f61,
+            0x0000006043081877,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x289a217934a83f7f,
+            0x8335467d818d9e1b,
+            0x79f984722a1872d4,
+            0x2dbab070c97c61e5,
+            0x000001550ba8bb19,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x0e314a2c60a07139,
+            0x2572d54bf8fc377c,
+            0xbe482d033965cb0d,
+            0x3fbcde9db684eb58,
+            0x000001e45c33e2f0,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger320([
+                0x4864ecdc8d81ece4,
+                0x439a931652eac22a,
+                0x4b182371c1d4de45,
+                0xcc8c687d7419ae43,
+                0x000001a57b8531d2,
+            ])),
+            Felt::new(BigInteger320([
+                0xba0d741d64a27d7a,
+                0x871be888b84e65c7,
+                0x5d0a524c8fa5c6b0,
+                0x3a2c1e3146ae4be1,
+                0x000000274a736b1c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x0d8562f553f8231f,
+                0x29272fe1ffc76a34,
+                0x9c261eaa6b9ec792,
+                0x2aaf4e4907fd9a25,
+                0x00000380e9ad248f,
+            ])),
+            Felt::new(BigInteger320([
+                0x7ec698bdf8b57e84,
+                0x60a2289a6fd169e1,
+                0x0e5600922c09ac2a,
+                0x9be51485466dd4dd,
+                0x000000ecb2a5c3cf,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x6e87cc6f9d6f3df7,
+                0x4f4e9c41be71bd13,
+                0x7960c53c8aa2e100,
+                0x55cab48d22cda634,
+                0x000001c3d7a0a983,
+            ])),
+            Felt::new(BigInteger320([
+                0xb018cce344ca369d,
+                0xe2d6387e2e103d10,
+                0x3ab1dcb6809eccff,
+                0xf4c5652ff6fe538e,
+                0x000003b3702960b0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x34340f9e83f08a8c,
+                0x48d09675e09fe8ff,
+                0xd2a7e1d069b42580,
+                0xcea63c6614fbd1b6,
+                0x0000009bb5515267,
+            ])),
+            Felt::new(BigInteger320([
+                0xc64731931adf5969,
+                0x069e8de183c594a8,
+                0x158fd81f9ffa6320,
+                0x96f3d4431d86b687,
+                0x000003b65c9a8576,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x50b5be73eaa0c4e1,
+            0xc7e37f69f66f78ed,
+            0xc42a8f0f106d69d3,
+            0xbc28822f9ce63ead,
+            0x0000034e44d0b0f8,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x90b98554ed661e11,
+            0x8ca836c74fa4110a,
+            0xfab64e5010574448,
+            0xbbf2cd5f2b55da83,
+            0x0000028858aa854e,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x395f2c8dd2b5aaca,
+            0x8081f519bd54cafa,
+            0xa06a9fdd6f6d19f7,
+            0x8c3df0eb4456cbea,
+            0x00000087aa00049f,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x15d375740f4ea696,
+            0xf70eba5493ffe6a7,
+            0x15bd106b4c03d6c2,
+            0x6555a3e4d6718ac8,
+            0x0000008ac27b5fd2,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x24bfa8de880a3773,
+            0x468d3f804cea7bab,
+            0xc8423556c8454548,
+            0xd3aa25e5543dc5dc,
+            0x00000037ee469865,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x7b0bdf145fee6bad,
+            0xae8421efa89dc576,
+            0x63a72dc0f31424cf,
+            0xbe58c0786e48d855,
+            0x0000035cbf2ab103,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xba5bba8e12666e2a,
+            0xec165051da0610dc,
+            0xe9399bee4758e2c2,
+            0xefc9a226fa1ad61e,
+            0x00000321cf8114ba,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x5ef0a8ca71c7d781,
+            0xfc1f57a698a820fd,
+            0x6ac4598c33fc6b8a,
+            0xe2b2e02290851962,
+            0x00000067ca0f66c1,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
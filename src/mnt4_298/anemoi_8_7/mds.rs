@@ -0,0 +1,124 @@
+use super::BigInteger320;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [  1  18  17  17]
+/// [289 306  18  35]
+/// [289 289   1  18]
+/// [ 18  35  17  18]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x755129d8abcf4e70,
+        0x64557a9779d58aa5,
+        0x92411865f906833e,
+        0xd08365c9d3291835,
+        0x000001acaa67001b,
+    ])),
+    Felt::new(BigInteger320([
+        0x259ae5b7c4d1ca15,
+        0xbc20e3dfe73f0ac3,
+        0x97505c422d1f08e7,
+        0x49d149cf165e1b2c,
+        0x000003a87fe6a0cc,
+    ])),
+    Felt::new(BigInteger320([
+        0x259ae5b7c4d1ca15,
+        0xbc20e3dfe73f0ac3,
+        0x97505c422d1f08e7,
+        0x49d149cf165e1b2c,
+        0x000003a87fe6a0cc,
+    ])),
+    Felt::new(BigInteger320([
+        0xee7bdb8cfb8e6b55,
+        0x6390ebf9492ed5c8,
+        0x5d698a2f2ddc88bf,
+        0xc0839ce6013a8509,
+        0x0000026102836656,
+    ])),
+    Felt::new(BigInteger320([
+        0x4b09eaea4efa3569,
+        0xde07ec7adf4dd279,
+        0x29cb1d0dfde860bc,
+        0x67eec2176fe84ba7,
+        0x0000024c8aad32af,
+    ])),
+    Felt::new(BigInteger320([
+        0x755129d8abcf4e70,
+        0x64557a9779d58aa5,
+        0x92411865f906833e,
+        0xd08365c9d3291835,
+        0x000001acaa67001b,
+    ])),
+    Felt::new(BigInteger320([
+        0xd1df3935ff3b1884,
+        0xdecc7b190ff48755,
+        0x5ea2ab44c9125b3b,
+        0x77ee8afb41d6ded3,
+        0x000001983290cc74,
+    ])),
+    Felt::new(BigInteger320([
+        0xee7bdb8cfb8e6b55,
+        0x6390ebf9492ed5c8,
+        0x5d698a2f2ddc88bf,
+        0xc0839ce6013a8509,
+        0x0000026102836656,
+    ])),
+    Felt::new(BigInteger320([
+        0xee7bdb8cfb8e6b55,
+        0x6390ebf9492ed5c8,
+        0x5d698a2f2ddc88bf,
+        0xc0839ce6013a8509,
+        0x0000026102836656,
+    ])),
+    Felt::new(BigInteger320([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x755129d8abcf4e70,
+        0x64557a9779d58aa5,
+        0x92411865f906833e,
+        0xd08365c9d3291835,
+        0x000001acaa67001b,
+    ])),
+    Felt::new(BigInteger320([
+        0x755129d8abcf4e70,
+        0x64557a9779d58aa5,
+        0x92411865f906833e,
+        0xd08365c9d3291835,
+        0x000001acaa67001b,
+    ])),
+    Felt::new(BigInteger320([
+        0xd1df3935ff3b1884,
+        0xdecc7b190ff48755,
+        0x5ea2ab44c9125b3b,
+        0x77ee8afb41d6ded3,
+        0x000001983290cc74,
+    ])),
+    Felt::new(BigInteger320([
+        0x259ae5b7c4d1ca15,
+        0xbc20e3dfe73f0ac3,
+        0x97505c422d1f08e7,
+        0x49d149cf165e1b2c,
+        0x000003a87fe6a0cc,
+    ])),
+    Felt::new(BigInteger320([
+        0x755129d8abcf4e70,
+        0x64557a9779d58aa5,
+        0x92411865f906833e,
+        0xd08365c9d3291835,
+        0x000001acaa67001b,
+    ])),
+];
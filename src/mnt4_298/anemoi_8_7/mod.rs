@@ -0,0 +1,1942 @@
+use super::{sbox, BigInteger320, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 304 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (38-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8a08bd5ea40d17cc,
+                    0xca12a872d79857c3,
+                    0xe63dd8b033f8378c,
+                    0x23575ce2bbf0c5e2,
+                    0x0000005868a85aae,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34e6ebaba25162ba,
+                    0xb43e50c5a3b1d3d6,
+                    0x13482b52d055dd7c,
+                    0xc7c64c4fb71bc7e9,
+                    0x000003081088ed13,
+                ])),
+                Felt::new(BigInteger320([
+                    0x940f38019453d424,
+                    0x3869804a53d6c2a8,
+                    0xa786627c745b2eda,
+                    0x0cb2e281b1e202a2,
+                    0x000002c21ce5dd7d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2eaf864a622de4fc,
+                    0xe100b07087f92596,
+                    0xda2e27b33dca37a5,
+                    0x73be18a87567ddb9,
+                    0x00000036d8a21d3c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2688b3fe120c3fda,
+                    0xd3b387fd0d5f75f8,
+                    0x1e989806a4de81c2,
+                    0xe328346347278053,
+                    0x0000036de9a57731,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd555c7ae943b433e,
+                    0x1aa6b3ac1405563c,
+                    0xbd721f7248e413dc,
+                    0x97b21cccbbd5ccc2,
+                    0x0000028eb2d40d5d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x115f24b05bfbb217,
+                    0x5e6cd62565189421,
+                    0x8bb91893778cf2dc,
+                    0x41164c9a563d7357,
+                    0x000003500ac1a1ff,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbbddba18940f81db,
+                    0xc3026fc56c9a16c5,
+                    0xa51ea4b9041b16b1,
+                    0x10f4726739399924,
+                    0x000002fa755f0f31,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb0f2298dbbb9089d,
+                    0xa3ded4950a950474,
+                    0x058e414afd098cbd,
+                    0x5f2c68467a4dc564,
+                    0x0000017cc5c5c222,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6c2dc343f495c68,
+                    0x9564f8c919437e36,
+                    0xe1a6e9bfb3ccdf81,
+                    0x261470975a1e7a90,
+                    0x000001470cb06e87,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ed8714a38039041,
+                    0x59b98c11cfaf97e6,
+                    0xebf0b58e2168257b,
+                    0x69de0e9788828eb6,
+                    0x0000027ad9d00ec0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b51daa08bb9ddba,
+                    0xac6540891226c6c4,
+                    0x06747f898c4ee237,
+                    0xd65c5d4b7c4ae174,
+                    0x000000e47bb41f21,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46f767919fc18f74,
+                    0x9cca01f7367ba16e,
+                    0x3d1c5f4263a6ee66,
+                    0x596db45c0a2b4c5c,
+                    0x00000300c22e0080,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf7a17af131444ac,
+                    0x49b0347b7b5da9d8,
+                    0xb76eeaf71cc6fb29,
+                    0xd9706f1fb07d68b6,
+                    0x00000188b94c2188,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf00564742bb7f80c,
+                    0xb894389f929b764e,
+                    0x46995e9b50e6a8b6,
+                    0xccac35cab34db7bd,
+                    0x0000034a2cdd74e3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91a38637aa708780,
+                    0x79c04891cf2a279f,
+                    0xd2adebd6fc145b3e,
+                    0xb554a11716f93652,
+                    0x000001d1fa619a65,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3093255504169a9d,
+                    0xec9a0d519e022e6c,
+                    0x9533f124eee2714e,
+                    0xa97871d684d2a004,
+                    0x0000000554973221,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda814ecf9111a3c9,
+                    0x00cba7f08fa304e3,
+                    0x9ac675a4362e2c37,
+                    0x981fc8ea08d080cd,
+                    0x0000011ae98eb07a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c2a698cbf19e255,
+                    0x5e7c16eb43f735e7,
+                    0xad4c04fb30f1f26d,
+                    0xbe212df81e60136c,
+                    0x00000365ca2fc026,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2df4d63d0806cb65,
+                    0xfd2053cd2c30c0a2,
+                    0xd016f6972e54541a,
+                    0x8914c6a475e717a7,
+                    0x0000036cea4894bb,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd309ae11ecf1953c,
+                    0x7a8d43801be866d4,
+                    0xcd5a611453bbc9c1,
+                    0x2a23f45d3b7b1be7,
+                    0x0000006f4a475e06,
+                ])),
+                Felt::new(BigInteger320([
+                    0x737f36778c66958f,
+                    0xe520a606c1db69d8,
+                    0x0d62648eb1fc11f7,
+                    0x0ebbcf6f10c514e4,
+                    0x00000331d17c292d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6ada4ab1d38ff9a,
+                    0xd9205fdb8ed1af9a,
+                    0x784fc15a400325f4,
+                    0xc6636bd14710ef5c,
+                    0x0000002115b9d8b3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc2b02ecd0b42247,
+                    0xf82036f3943d374b,
+                    0x1a0efeb2a1ad0598,
+                    0xabf2186f4059b723,
+                    0x0000020bd898f79b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe3d21b8ff900535b,
+                    0x18dbc4f32914a449,
+                    0x002e57f2bccec5e0,
+                    0x367ae5afcd3b37d2,
+                    0x000000c52a0e0682,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd47e417c813767fa,
+                    0x08fac665443bb7c1,
+                    0x3e9af1cb20f97368,
+                    0x30769a010ba745d2,
+                    0x00000250ad7bb2a8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9617815aa95e2817,
+                    0x25aacea0f5896736,
+                    0xc043c0cdceee0ced,
+                    0x92cd0caf210dc483,
+                    0x00000229423f5f9d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77956c7dfc6f9297,
+                    0x93fbb71ec6e041f6,
+                    0xc3da4c1fd095e70a,
+                    0x038c53f76557b07a,
+                    0x000001a9c2bf2db2,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c2d3c481dee26c2,
+                    0xe14e92cb3eed7a78,
+                    0x4e4eb4e5dda834a1,
+                    0x7737788587156ee5,
+                    0x0000018c056aeadc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4bc5abf7b06a7d13,
+                    0x2192645de1ab817d,
+                    0x1d3660dcd581f78a,
+                    0x8225130fad5430aa,
+                    0x000003453f3ac423,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa193d181736ec95d,
+                    0xd08af170f3de3b9d,
+                    0xece2d03c98c7bd4c,
+                    0xcde62ca0ac723515,
+                    0x0000025a27ccdda9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43b17c87d37ffcb2,
+                    0x73bb03610cde58b7,
+                    0xee848df36a800819,
+                    0x46dce14e08d2ad6f,
+                    0x00000222acb98f5a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x309d595fb647d24f,
+                    0xbf00cd9d06ec52da,
+                    0x03815ccb2827c908,
+                    0xfc15e38737fafe42,
+                    0x0000003bc56d17ea,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefb6e519f76d3956,
+                    0x0020f3b1d8a23c1e,
+                    0x26f62e468673dd5d,
+                    0xab6320faadec16d7,
+                    0x0000019462c685d4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c44a72a5653faa9,
+                    0x5f02c9d14d7bbb84,
+                    0x9f547933d1413d91,
+                    0x96f0316ce0a5a8ba,
+                    0x00000374aa29c131,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6a83990b20c89ca,
+                    0x00b889c3907d89c0,
+                    0x161af4f55f947a1f,
+                    0x10f117f1842825f0,
+                    0x000003b99bb0d13b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0296d73199da8c6,
+                    0x6a0527704738f5e4,
+                    0x849af8ffaee1cf99,
+                    0x11bc7d154dc61e32,
+                    0x0000003d7924d602,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1fe71639655c4992,
+                    0xa88c7f444c27cd49,
+                    0xdd8c765d591491c6,
+                    0x26422d6216c66c4a,
+                    0x000003a83684a583,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90bb1fade2337cfc,
+                    0xe641c7d2a3d504b1,
+                    0xfde33399722d02c0,
+                    0xe91f899e39d426ce,
+                    0x00000221cc20ce87,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a149759b79ce4f4,
+                    0xa60e60a3b6fc2820,
+                    0x414a06c67de99155,
+                    0xf153388a76650419,
+                    0x00000373acb808f5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8090cc7c069cf26a,
+                    0xafe186b3486f09e5,
+                    0x4bcf26b4d8902892,
+                    0xeaf9756949ae9cde,
+                    0x0000003e01d80d03,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3007dbec41faed9,
+                    0x5ff012d97c1b6f8e,
+                    0x96fa88c602d197d2,
+                    0x1ce452e19d3f7530,
+                    0x000000947fda7098,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06f37fceb3f38a13,
+                    0x3e9243a76a70326c,
+                    0x1c097f8b9cdfaa27,
+                    0x4ef5ce8c091793ba,
+                    0x0000000a7d742e52,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22360e4f029824ad,
+                    0x4b06da804b09a0e1,
+                    0x2a241aa2c6e9dabd,
+                    0xdc90f3520e25f50c,
+                    0x0000020e77268b72,
+                ])),
+                Felt::new(BigInteger320([
+                    0x516ce9a72e710ddc,
+                    0xc0435669de94f63b,
+                    0xa50b4f558e4c77bd,
+                    0xf236d4cff7f3b64f,
+                    0x00000359234936d1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e7722bed6cc8281,
+                    0xa0e307372dac6853,
+                    0x4cc2de1f73dcdf48,
+                    0x18c1d623948312e3,
+                    0x0000016d3f5443f3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06ae415ee992e85e,
+                    0x25793e46c0fc35e0,
+                    0xfd7298770889ec63,
+                    0x0f435639fce7340a,
+                    0x00000221de63fc48,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf7933232a5b4eaf,
+                    0x247153e64c8639e3,
+                    0xc246a1d1ff410447,
+                    0xb3b93b7775862e64,
+                    0x0000009324548e45,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77b8acd30bab4406,
+                    0x7fad41a22e0e8919,
+                    0x64e4e7badecebd6f,
+                    0x71014683d1f681d5,
+                    0x0000024d5781bc40,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0369fd9efb295671,
+                    0x51d2b766ded8256b,
+                    0x0210816ec14243dc,
+                    0xd3003f8a4f8f3094,
+                    0x00000328cd0bf43f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73ff5b47a5d367ce,
+                    0xec2fd4c4c5847e40,
+                    0xc49a216f30ef2e88,
+                    0x9392b1478878932e,
+                    0x000003b57c17a400,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494dc317e76e781f,
+                    0x6069216a2adb6a16,
+                    0xb4c3154a3f4fccff,
+                    0xc22433b1142fabcb,
+                    0x00000047c08a47ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa418d678138acf2f,
+                    0x479cc8fbbe25cdd5,
+                    0xc5005054065eeb80,
+                    0x6a2a3769a4289364,
+                    0x00000135cdcb76f1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23fd005146149275,
+                    0xa258d8db56c405ac,
+                    0x2c3736bd7ac20fc3,
+                    0x9f1e87affd6f457c,
+                    0x0000003d0a2be7e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb049bbdf19027ba5,
+                    0x57cb69486d69801d,
+                    0x050f43dc341885a9,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbaaf847ac6f02eba,
+                    0xa2dcc4553d49d1bd,
+                    0xd8eb24e35ad12163,
+                    0xc11eaefd8580d2ab,
+                    0x000002665ee2d437,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37648655e95be375,
+                    0x4c0111d1d822ed82,
+                    0x00e62ba883001bd4,
+                    0xbec5c552263bf629,
+                    0x0000017c6e2931ce,
+                ])),
+                Felt::new(BigInteger320([
+                    0x13d3d2892c84d34a,
+                    0x8fb2f91a9a381e36,
+                    0xdbe92382add57a08,
+                    0x1da322d0fa190202,
+                    0x0000028f40ce62f9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x33c9476cb2f219b8,
+                    0xb7438cbefa8fcc81,
+                    0x17dad958e3991bed,
+                    0x864690d9e1e59825,
+                    0x000002b8fff5f359,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f610bcb027065fe,
+                    0xaf9bfef8ba285e2e,
+                    0x8ee81c62e872a130,
+                    0xd1cae0521cf53dc5,
+                    0x000001fc31406c5e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08c8bc9019e978dd,
+                    0xdfa7ddb8831697f5,
+                    0x0ccbf84b3a9f7f81,
+                    0xecf9abfd8c884f31,
+                    0x000001bf8d771fb6,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca3caab8f64c2868,
+                    0xee8f89ddabcef551,
+                    0x7e80158a7afb8a22,
+                    0xc3a520757575358b,
+                    0x000001b72f7866dd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ad796152bb11a18,
+                    0x3977327f1fcd4a0a,
+                    0x5d11b5529e175405,
+                    0x5643480026ff8cb3,
+                    0x000000141a083fcb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9cedae38aeaff46f,
+                    0x1bbe9cae5dc509ff,
+                    0xd8b8616c5ff4f766,
+                    0xff2c212f13e41b4a,
+                    0x0000007f4681aa7f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d20fab7dabbbcc8,
+                    0xde2e9f23654fc35c,
+                    0x96b087e7290c2c0d,
+                    0x1e8b479d3c0fc41e,
+                    0x0000009c91dc32bb,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbef07d8ff9e06280,
+                    0x57000bfc239ab2d0,
+                    0xd5e81fdc97b8a36c,
+                    0x5674360058d2a86a,
+                    0x000002d962112e65,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a5cf4bb300afe3f,
+                    0xb175499f8038a1a6,
+                    0xe633705eb08d23dd,
+                    0x2487e731d0a1d9c1,
+                    0x000000b53774754a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50202e6fb934b5f1,
+                    0x0e90ded49ac93a4a,
+                    0x01237c85e820cedd,
+                    0x24227db3c1a82b48,
+                    0x000000a730491ad3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e6e36a855449583,
+                    0x9df4eca35d864633,
+                    0x881607c181f9156f,
+                    0xc03cb127cd5ad719,
+                    0x00000034c4fc516d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90e62d3f60037413,
+                    0xb8919fe7efdf5688,
+                    0xc1afc3b0cecbb426,
+                    0x133ba05169cf7927,
+                    0x000003abec3239b2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc461e0cfab1dd49,
+                    0x69c4fa0aac0dea3f,
+                    0xc622b524cdb997b3,
+                    0x196a689f75e3b387,
+                    0x000001f21d53e919,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbf44816c2accfc30,
+                    0x38dcf5c96cc91dbf,
+                    0xa31c5c86e4ab55aa,
+                    0xc1c4265e43b4c507,
+                    0x00000068911f90ac,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6bd60f9dc00f4485,
+                    0xf19f30bdc6f4169c,
+                    0xf9b915064d6163b6,
+                    0xa06dc0efa27b1b4f,
+                    0x000000e3039fd060,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32327ee5b529e1dd,
+                    0x38b1bdda4de4f863,
+                    0xf2f569171177e88e,
+                    0x3497e5afc7e5c123,
+                    0x000003781170eba2,
+                ])),
+                Felt::new(BigInteger320([
+                    0x979dbd316e65199a,
+                    0xf7e6be994e1242e3,
+                    0xf3e48f9d7495dddc,
+                    0x8046aee6f386573d,
+                    0x000001e5a1864ebc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1425582f8299e506,
+                    0x5f90795d017131cc,
+                    0x0f8224fecfddc802,
+                    0xb21175b51a7a4ad1,
+                    0x000000d9729ae071,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28082ff69dc7fd9d,
+                    0x80f8495d2becf040,
+                    0xf5d88f54b35f4868,
+                    0x753d306dea435e84,
+                    0x000000050b7fa538,
+                ])),
+                Felt::new(BigInteger320([
+                    0x628adac6c901ff1c,
+                    0x7dc4fc683b4e6dab,
+                    0x4c7158d98d13f1e3,
+                    0x98f78bdfd4a24d2f,
+                    0x0000006a6c58e2af,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7e6aabdeba2f67d,
+                    0x3033429118b92f5e,
+                    0xb3f84778a622e448,
+                    0x355a1ebc95db69a3,
+                    0x000002ac57810fce,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8fbeccb3b407bd9f,
+                    0x8d3fb361390f85e9,
+                    0xe1bebc0fbba3b267,
+                    0xfcd44ab32b975fb6,
+                    0x0000030f9221fdbc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a17496098c3d731,
+                    0xb7d20a456434e346,
+                    0xc96521417e1bf762,
+                    0xa6b38572ce78e9ea,
+                    0x0000019bf347c83f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00550484536c1e43,
+                    0xab333666a539f07a,
+                    0x1a81266bcac8fe6e,
+                    0xb4b0c0d941a6fc0b,
+                    0x0000031b68d7d347,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee44195d0d76eca2,
+                    0x5499e7199abe42de,
+                    0x3be7498c913dfe7b,
+                    0x729247d30e15fb25,
+                    0x000003652a79f237,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96be4d2e2788b202,
+                    0xbb65b14e56813957,
+                    0x8d9c29a81af32cdb,
+                    0x3fa564cbb5c7e437,
+                    0x0000026bdbc6690b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef9ba711a4f33a00,
+                    0xaef0657a514afd61,
+                    0xb8e58557a34c2bd3,
+                    0xce4edbfa1aea49aa,
+                    0x0000003a07034692,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1ddb871be60cda8,
+                    0x394456d4326a9689,
+                    0x1378e2e52612909a,
+                    0xa27cc534dfaa7528,
+                    0x0000032439ec7f0d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff4391e3129fb701,
+                    0xbfbd2efa950a502a,
+                    0x64e2847816ae0099,
+                    0xd991de0929c4918d,
+                    0x00000385cfd27bb0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x97794af861efad38,
+                    0x66065ddac89a9e15,
+                    0x29a8c53003020454,
+                    0x358e0317c4a03728,
+                    0x000000c850011f8f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x546d63f70e3aa02f,
+                    0xc9db54a1a4c948ea,
+                    0x7dadb0de1c1c809f,
+                    0x702c961781ea321e,
+                    0x0000008392a95739,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde64f48880b9fb5e,
+                    0x02dd38e156194f28,
+                    0x907999471a98a6bd,
+                    0x21884929f8970f56,
+                    0x00000093356aaa4f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d58960500474c8e,
+                    0xc032aa4c98732a4f,
+                    0x7210569254a08f09,
+                    0xecb5200b393cfa30,
+                    0x0000017232f014f4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8197cc23368fc998,
+                    0x76440e60babb1e17,
+                    0x2718d1def0b14fe0,
+                    0xc1e0ba8d83bd4d3b,
+                    0x0000014b6dda3e38,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84bddc8f1f9df04b,
+                    0x5067cad2abf8bf44,
+                    0x594f1f514e503683,
+                    0x32c2a512b724b257,
+                    0x000001a4b57af1a2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1587edefd916747,
+                    0x2ad4742c359b0074,
+                    0xf79af80ef35808d4,
+                    0x4db06315edfcfbd7,
+                    0x000000824d016745,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5e5b18c96060652,
+                    0x1b416d7335b7fc52,
+                    0x988bcbd7b2e7be65,
+                    0x728e4daa34ab26ed,
+                    0x000000a12965a198,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4e37ff31ee566de5,
+                    0xf19c93c6c5bb8e97,
+                    0xc3068dc53afd0213,
+                    0xf701ab8a62f0aac6,
+                    0x0000022382ff9b56,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2448aaa0496c517c,
+                    0x5d1d16c2c9e87b28,
+                    0x88ba354f2a6e79ec,
+                    0x96f129b41d050eef,
+                    0x000000fb4f2308ea,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88b906f6b6418fa9,
+                    0x295962fdf24ea9ec,
+                    0x8ef4349622e02a4a,
+                    0x374bad7b31f5bc08,
+                    0x000002e3b7539272,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7211e4465986666d,
+                    0x8fa102abc6ccd054,
+                    0x9decce3728410fda,
+                    0xabb6f383c15f0d97,
+                    0x000001a4dfd08ebd,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca5c26e562acfa87,
+                    0xdeffa833f11316b0,
+                    0x410633dd01af6afd,
+                    0x3c2c2fe4cbaeab99,
+                    0x000000920734f711,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ea9f49b07ae0cc4,
+                    0x772d7426160a8232,
+                    0x355c494f4e7fe5c8,
+                    0x89d007df69bb92c8,
+                    0x000003b7cb0d5633,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcbe8a344af9327e9,
+                    0xcdc7193b1a12a634,
+                    0x5b04ab1769d5f695,
+                    0xe39192bced7f1128,
+                    0x000000f1308b75dd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x990198812e59ea49,
+                    0xdf90c5f8abd3f96e,
+                    0xc746fe5efb4b3447,
+                    0x079eecd4ab59b7b7,
+                    0x000002f624dd47fa,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaa0cca2350da30ba,
+                    0x8afdb9af84137578,
+                    0xba011d1103c074ba,
+                    0x37207c91090d1b93,
+                    0x0000002a5e7a149a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ffe5ffc1ce36d94,
+                    0xca7451d227bd83d4,
+                    0x72c53297998a60f4,
+                    0xa0585468a13b4a1c,
+                    0x0000012725286091,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4aa21a0874dfbd0,
+                    0xa12958a8f5ffe4d3,
+                    0x5ee1a4246cc608b6,
+                    0x21b031c6f80908b8,
+                    0x000001e1f654ab60,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc9243c5df0d0573,
+                    0x847e33ec74885ed0,
+                    0x74463e7df604dfb6,
+                    0x7d1aab9f40280dad,
+                    0x00000113e6df9a27,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1839b6997cc4d8d1,
+                    0x869397a74b4334cc,
+                    0xb714919aadc1271a,
+                    0x40b228120ab26e33,
+                    0x000003ae6296404b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e9e97cf2331e476,
+                    0x893c51a33522b46a,
+                    0x32ddb8583439072d,
+                    0xb11b07eee3235598,
+                    0x0000029cda82d254,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa788656f7ca9422b,
+                    0xd70eb137f88a3dee,
+                    0x13774c0d2650dc6c,
+                    0x9914f02e9f6f7d92,
+                    0x00000242d0eaabed,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcfceeffd5e710cc0,
+                    0x925d5dd846e6adb2,
+                    0x61eeaeb78cf50ac6,
+                    0x0ca10cd4035f9ce2,
+                    0x0000027ed4c0ee9e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x722864b35741f3f5,
+                    0xaf5ca0be99b4b200,
+                    0x1483685c4631ae43,
+                    0x1622a9f4cb17f8f0,
+                    0x00000124ca17a97c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x924d2518ab1a063e,
+                    0x7284ddeda5ee299b,
+                    0x2ef363925b18ce64,
+                    0x676cdbc690024ddd,
+                    0x00000176dad2c82b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x724c3724bf45c176,
+                    0xd2dd4355fee468ab,
+                    0x0acf7c78d558bfb2,
+                    0x04acf41ef6b47801,
+                    0x0000032b85b8bbe9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e1251067a8ea31f,
+                    0x39c01c1d349d46a2,
+                    0xc4b74e4f58a32df6,
+                    0xe695b37f45b2192e,
+                    0x0000038a1cc670ea,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0345f60d7037f019,
+                    0xffeb996bae8e333f,
+                    0x2d284d05675fee73,
+                    0xde68b057a3b62753,
+                    0x00000204a862c616,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe1153a9d42d6b09,
+                    0x64ec0e97002ba4f9,
+                    0xea13d88ae1c2a959,
+                    0xb3c829a2a441284e,
+                    0x00000370eadd848e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0532f3721b62e8e,
+                    0x4baa7cb181b7f4e3,
+                    0x1a50bacad320f22b,
+                    0x9ec6dc7d4f9923a5,
+                    0x000002b033505323,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc389adf7d26342e,
+                    0x34d1a8070a288df7,
+                    0x8964703873ca120c,
+                    0x79bf4ea49f20f0a2,
+                    0x000001b518eb40d9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4faef8234438bca6,
+                    0x898331f3bc96c4af,
+                    0x718af97236f5abaa,
+                    0x2ed4b2c49d99cfac,
+                    0x0000012cb63937c8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a054ec1e3f2c3bd,
+                    0xbd60cf7688b46478,
+                    0xa5f2ddd5759ef905,
+                    0x89d74f12411106ea,
+                    0x0000028747d23886,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ebb07a4397ada5f,
+                    0x93b3ab6c5b23f3cd,
+                    0x588103253c6d6421,
+                    0xc9c36309a6d678f8,
+                    0x0000020216ffc17c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5358709839c468a,
+                    0xd23e6f5e164d6b8c,
+                    0x0c3e86559f9a7490,
+                    0x91317df9a0d13d4e,
+                    0x000000e2edf79d99,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39b5b2d9efdfed5c,
+                    0x48eb359103fa45b6,
+                    0x79368d236ebec9ae,
+                    0x496ae8da67d0d3cf,
+                    0x000001cd3719a621,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63e271d7cc6a78eb,
+                    0xb744def79285a493,
+                    0x1a7c5eb29d1458de,
+                    0x899e08f9f3aba414,
+                    0x000003924545aef1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8571393597ccef0,
+                    0x0fbc913d6f61ff1d,
+                    0xb1cc71f39600d2c4,
+                    0x272ec0f0057da9e9,
+                    0x000000f50f9e834b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e52e175c72f570f,
+                    0xf66fde4108e9e859,
+                    0xde6f1ff458a003a1,
+                    0xcc23b8a92599abf6,
+                    0x000001dea8e55054,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x725e2b572b3bf9aa,
+                    0x82d9ae90c393e396,
+                    0x3e499d13aa83823f,
+                    0x92a4f6042b84559f,
+                    0x000002dc8b9f0bd7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb36590986ffff93b,
+                    0x39f0f721ccb3dd84,
+                    0x7c7fb55240a96402,
+                    0x9f1ad5c1506d1d58,
+                    0x000001f9a684686a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f5f72a1a3e31bdb,
+                    0x7a737f2882999b80,
+                    0x66d647b9c229c2d4,
+                    0x38b818bc1dfc880a,
+                    0x00000324ef34270d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67cde3b86d83efb2,
+                    0x75f085511bec9994,
+                    0xa03fd36b6cf8e21c,
+                    0xab1b83668e4b9ffb,
+                    0x000000a8e5034d24,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b57368e593ada9b,
+                    0xfe21032ebb7d4834,
+                    0xabcd99608e545b54,
+                    0x16703606828d6c98,
+                    0x0000009591d288d3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0a29b671131747c,
+                    0x7de9013f4a18437d,
+                    0xebf8a7b6634266ee,
+                    0xfe7ba764f491ba2b,
+                    0x000001d9c6522c5c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b300e7a40cb7ded,
+                    0x4c198a26fde6005b,
+                    0xe49bbbf3dc151072,
+                    0x82916f17193dd002,
+                    0x000001912cb207d3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a1b7dac09dfe728,
+                    0x6e8f642e5e2c2ca3,
+                    0x8065726a6641487a,
+                    0x5794dc44fa9ab480,
+                    0x00000065b63ea7ca,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2e6ee88e3fdbfdc5,
+                    0x9accd083fea76c13,
+                    0x2b0badaafcd2a6db,
+                    0x8b67bbb9aaef8efa,
+                    0x000000a116067f9b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed02c2d82c3c2dde,
+                    0x0fa4b7b8ae021786,
+                    0xa9bf97b61ae53d4a,
+                    0x5e524926bcc7d712,
+                    0x00000393e8ec8b8d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15f63bdc149b86a7,
+                    0x242a3a538a017851,
+                    0x0696a6f4faffd1c2,
+                    0x666ed19afa9a92f1,
+                    0x000003731a48dd50,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57b8cb3c06f7fc4f,
+                    0x6f8956efdd37c15f,
+                    0x1fbe8c190877a412,
+                    0x90c46903260d8972,
+                    0x000002914786b20a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe01dae6a895e2495,
+                    0x4af500871f415b6f,
+                    0xa530e8b39149d62b,
+                    0x1952236dc789c6d8,
+                    0x000000d0ad5f2cc8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96b2b88a93ebdc1e,
+                    0x4d230dbc47f67939,
+                    0x1ba59eed0ed128d1,
+                    0xadb597ddc00cdf4b,
+                    0x00000207e32ac369,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fce623ed44d53d4,
+                    0xa51bc9bfce6efc88,
+                    0x3d62d9e890df2c10,
+                    0x5036a4f2ca9c5c4b,
+                    0x0000000cb77c7315,
+                ])),
+                Felt::new(BigInteger320([
+                    0x833057dddf91ac3c,
+                    0xeb0c4f358081eae6,
+                    0x9d73554aeccb0982,
+                    0x57cbdb7a56cffe98,
+                    0x000001a1c7a61070,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf5eea6b5157b1b61,
+                    0x7b0654236ed31af6,
+                    0x76c0ff2b638ba06a,
+                    0x4572d9939eed3e0f,
+                    0x00000302f2043254,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1188859465c5b79,
+                    0xec68951a731f0a83,
+                    0x655d9a4e8ceca42f,
+                    0xdb311816b5a357cf,
+                    0x000000800748b5bd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c30ca5af4a2d5d0,
+                    0x22bd796f5c5b159b,
+                    0xbf722b24a0b660d7,
+                    0xcaf1a979c8932d4f,
+                    0x0000031d9f388af9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a1aa5e8323dbb56,
+                    0x09c5695d4614454f,
+                    0xa364c6f5d56bd696,
+                    0x3d1cca535bd52cdc,
+                    0x000000a4a1f2bffc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6bc2417af8be9801,
+                    0xd4f7ed36676b1674,
+                    0x527fe8159e8efc78,
+                    0x5770bde0f336e558,
+                    0x0000002809e1a090,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0e2dcb12a140a4b,
+                    0xa836f020569e5db9,
+                    0xc13f7ac03c942f75,
+                    0x1f60e522d54e494f,
+                    0x0000003ef84f6932,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94a0c82b759e88e2,
+                    0x14e3c1091e18f3c3,
+                    0x16d3e6ec82fd39bb,
+                    0xa6206b0c67804e7c,
+                    0x000002e088ac07cb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40f478dfadc14520,
+                    0x138e5219ce864c96,
+                    0x796d787a8b0be809,
+                    0x7370a1872837a5b8,
+                    0x000002be6fe8f01f,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger320([
+                    0x4730630eab0a66f4,
+                    0x4321f5b089ca11fb,
+                    0xf0e3c3aac218de7a,
+                    0x4871f0c514fff708,
+                    0x00000344dcf7cc90,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee9c7cd112c707b0,
+                    0x0167076810069e17,
+                    0xe23ad82133b76622,
+                    0x2c18068736c21e9b,
+                    0x000000787aaebcaf,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8f24eb91883a974,
+                    0x920205e34da9a806,
+                    0x7d161f84c393de29,
+                    0x35d696f2eab8cac2,
+                    0x000000b5e23157a5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5002c5ea38df7f77,
+                    0xe0448d6b489e8b3e,
+                    0x8497a58c2e1808cb,
+                    0x1dfa5722af26814d,
+                    0x0000012017cbc491,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4730630eab0a66f4,
+                    0x4321f5b089ca11fb,
+                    0xf0e3c3aac218de7a,
+                    0x4871f0c514fff708,
+                    0x00000344dcf7cc90,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee9c7cd112c707b0,
+                    0x0167076810069e17,
+                    0xe23ad82133b76622,
+                    0x2c18068736c21e9b,
+                    0x000000787aaebcaf,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8f24eb91883a974,
+                    0x920205e34da9a806,
+                    0x7d161f84c393de29,
+                    0x35d696f2eab8cac2,
+                    0x000000b5e23157a5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5002c5ea38df7f77,
+                    0xe0448d6b489e8b3e,
+                    0x8497a58c2e1808cb,
+                    0x1dfa5722af26814d,
+                    0x0000012017cbc491,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x4730630eab0a66f4,
+                    0x4321f5b089ca11fb,
+                    0xf0e3c3aac218de7a,
+                    0x4871f0c514fff708,
+                    0x00000344dcf7cc90,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee9c7cd112c707b0,
+                    0x0167076810069e17,
+                    0xe23ad82133b76622,
+                    0x2c18068736c21e9b,
+                    0x000000787aaebcaf,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8f24eb91883a974,
+                    0x920205e34da9a806,
+                    0x7d161f84c393de29,
+                    0x35d696f2eab8cac2,
+                    0x000000b5e23157a5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5002c5ea38df7f77,
+                    0xe0448d6b489e8b3e,
+                    0x8497a58c2e1808cb,
+                    0x1dfa5722af26814d,
+                    0x0000012017cbc491,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4730630eab0a66f4,
+                    0x4321f5b089ca11fb,
+                    0xf0e3c3aac218de7a,
+                    0x4871f0c514fff708,
+                    0x00000344dcf7cc90,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee9c7cd112c707b0,
+                    0x0167076810069e17,
+                    0xe23ad82133b76622,
+                    0x2c18068736c21e9b,
+                    0x000000787aaebcaf,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8f24eb91883a974,
+                    0x920205e34da9a806,
+                    0x7d161f84c393de29,
+                    0x35d696f2eab8cac2,
+                    0x000000b5e23157a5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5002c5ea38df7f77,
+                    0xe0448d6b489e8b3e,
+                    0x8497a58c2e1808cb,
+                    0x1dfa5722af26814d,
+                    0x0000012017cbc491,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x82fe5cf3ba50fa82,
+                    0x67aff0625d122ce9,
+                    0xc1cbc622768813cd,
+                    0xfb3a6802a86487bd,
+                    0x0000003848c5b843,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe230e0c00396fbd,
+                    0x0803d920e75b50f3,
+                    0xdae9404b3cd0cab0,
+                    0x700475f69dfd952b,
+                    0x0000013905aa3f68,
+                ])),
+                Felt::new(BigInteger320([
+                    0x052fdb37b3a61389,
+                    0xf5c5f440361a1d57,
+                    0x45e3c9d163938c91,
+                    0x4c42deed9fe1731e,
+                    0x000002524086fc50,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf40166b13a91831d,
+                    0x0ec593b553bac562,
+                    0x6da4967eb011feab,
+                    0xf8f6188449395ac5,
+                    0x000001481f021f9e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcdd421e32f22c9f6,
+                    0x4f6371ad07c0f4c2,
+                    0x5017a8979e156317,
+                    0xd98ada787c500494,
+                    0x000002867a08a8e9,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcfcce7eca2fa88fe,
+                    0x9d0cc0b3209c53e8,
+                    0x5d9b9347fe35f2de,
+                    0xf7757f201006cce4,
+                    0x00000213cfe92d6d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f12eceda24ce5a8,
+                    0x6c4a6e82f568436c,
+                    0x88ea25b25d974277,
+                    0xc5b478276e9946eb,
+                    0x000000b009912e52,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e7d6785194b3361,
+                    0x54aecc69119efdc6,
+                    0x775d6cfff2454266,
+                    0x703f325621eadfc2,
+                    0x00000314fced3538,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6301103bd2731211,
+                    0x1599b578f66e3a62,
+                    0xd510c49547e4e7b2,
+                    0x8273f4a3b194de06,
+                    0x00000340291101e5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38d69ff8d4f7c43a,
+                    0xe951f2f4d92c9fe9,
+                    0x0022e7172d184774,
+                    0x11bca79d98866c16,
+                    0x000002934df3cbd4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda4b198904e0ac5c,
+                    0xf599bc2822614122,
+                    0x6e88ee70867ddc6a,
+                    0x0cd838b5420bd4dc,
+                    0x000001eec9cd3b96,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb73aa536b2ef5286,
+                    0x39b2af3c463b5f6c,
+                    0xc68b63b19c80e0ed,
+                    0x8061ad9280115f96,
+                    0x00000287a4d23510,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bd126acac49199e,
+                    0x859186a2e17c0b6c,
+                    0x1b1df2445f9dfd74,
+                    0xb0734eb906a45880,
+                    0x000003055490ed78,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80cfdeeeb9f8f78b,
+                    0x7ec8aedb4236bfb3,
+                    0xe1f796d0fd007ef5,
+                    0x47bed2061c79f617,
+                    0x000000c8cebbee5f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca5664c8922d7783,
+                    0x6823d022917e4011,
+                    0x004f6e7ef532ec1b,
+                    0x477464cf61ff94fd,
+                    0x00000027a09c0425,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d4f856b98103ca1,
+                    0xcf3d9e2fff85de42,
+                    0x95e737b93eeeb52e,
+                    0xa4c94153dcdff6da,
+                    0x000000d4ed9af54d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x74c70c79c6ddaf50,
+                    0x35ce5b84f5abca4b,
+                    0xfc0508e901c8cb0a,
+                    0x0e419fdf92b724e6,
+                    0x00000270e5fdc26f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb8a8221fcb9eda1,
+                    0xeff3e244f07fa97b,
+                    0xbbfd8c262e8e3e4b,
+                    0xd74c759912b58d94,
+                    0x000002d4e15c4649,
+                ])),
+                Felt::new(BigInteger320([
+                    0x576ae632eca1beb3,
+                    0xbf5fa4cbeefefa45,
+                    0x0c8791217c46e707,
+                    0xdf50b2f2b6f8eab6,
+                    0x0000005b01ad1fb5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x651b0cb96d977c5c,
+                    0x60e8c9ae42d6023d,
+                    0x1dc37b67e8fbefc1,
+                    0x1a16cea5da2b04c2,
+                    0x000002bb3f2db995,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b793fb9807090d0,
+                    0x62f9dc2149cf6e18,
+                    0xfc5791dd66c898da,
+                    0xc4992e0a516cce68,
+                    0x000000f9f41f0621,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71550a9234eb6d51,
+                    0x87fdc4514c16b660,
+                    0x6899b854d7cd7a45,
+                    0x85cd38ce9196dccc,
+                    0x000001438b796f5f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x790879e968fc245b,
+                    0xaa5b7506b30edde6,
+                    0x6d9c2dba4706dc23,
+                    0xcf9ab01091a307d2,
+                    0x00000113f481c706,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26f33945a74e43a2,
+                    0x800dde7ac68a6b09,
+                    0x60cd74e3975c4520,
+                    0x793c7e7f8384e05c,
+                    0x000001437be294ce,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x352a76803d514412,
+                    0x9f84601220c18153,
+                    0x108cecd986b355b2,
+                    0xc2cf7f489462232f,
+                    0x0000022737b2b274,
+                ])),
+                Felt::new(BigInteger320([
+                    0x694fae7738b67a98,
+                    0x15abd1eb72826e23,
+                    0x04b63175739cff81,
+                    0xd2d0273a30a4bb54,
+                    0x000000dbd3f03243,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59e8f3e696a1296b,
+                    0x32f2178be208adeb,
+                    0xaef29701d8169010,
+                    0x41c073b718340b1c,
+                    0x0000038ace60f411,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7dd695c3b0c54e3,
+                    0xfa505c23d8b0b817,
+                    0xa7a5ea7f92014e0a,
+                    0xc6e0653ff48f2158,
+                    0x000002cc26d6760e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a078ba2e37f2d0d,
+                    0xfb74ecc00b046361,
+                    0x6b1e36adbdc724c2,
+                    0xde41f6a42e50965a,
+                    0x000001e31720fe44,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5ed42d39c172831,
+                    0x50ad1d484abd5893,
+                    0xcacb502ab26c52b2,
+                    0x3a51df1da32085e2,
+                    0x000002c508b33b51,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1eab8ab7992eed8c,
+                    0xdd90d89036972af9,
+                    0x6552b3c3d6e97465,
+                    0x3ab6da57daef2528,
+                    0x0000008fecd99cc9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d8ea48098262091,
+                    0x4922fbd47c634bd6,
+                    0x41d9d826afb75ee1,
+                    0x602e610d20a0e722,
+                    0x000000c3506db716,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x930b3ccfb35ae98b,
+                    0x3a1b4cc424177d0c,
+                    0xc5221f531c9360eb,
+                    0xae0979da49d80854,
+                    0x000002d760fc50b6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40ed92b427eb43ce,
+                    0x4358b5b05e6606a3,
+                    0x7c86c8147b86d10e,
+                    0xc8b0d1fb38e96f33,
+                    0x000000a13cd9c01f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc954014ae0a885e6,
+                    0xb3aef128e6f80d30,
+                    0x8c2fee2276ab5448,
+                    0x04615d0156c8cceb,
+                    0x000001fedad07a92,
+                ])),
+                Felt::new(BigInteger320([
+                    0x084faac80bf5cb99,
+                    0xd9e8ec5d220fe230,
+                    0x2bb88b1b72c15930,
+                    0x142d5973394cbd79,
+                    0x0000022a3be62770,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47d6d5e199e69edc,
+                    0xace0c3b1eb5b4bbe,
+                    0x213e67e89e12027d,
+                    0xda278b93ce6dd6f8,
+                    0x00000367ea26cab0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49156b874baecd2d,
+                    0x1159fc3686f10c9c,
+                    0xb9c23d1c6b0f1240,
+                    0xf7a8b5c516aa59e7,
+                    0x0000021831e66188,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7bfb06f1a015e43,
+                    0x56b81000b1fb369f,
+                    0xf18c4e096cad64a4,
+                    0x1b6d6701d2e5be85,
+                    0x000000c4e266cd57,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac06ec23a114f279,
+                    0x7d5f31c75c18ec53,
+                    0xc2ab78ea27519283,
+                    0x782c4ab23e514538,
+                    0x000002352f404963,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0fa9f260c0eb2d55,
+                    0xedcc1c37f9e5c859,
+                    0x4cf430afdef507bb,
+                    0x87e15f7ab590cc6f,
+                    0x000001266e405afb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a11ea69311ee35f,
+                    0xd55c08e19fc8d79b,
+                    0xf85575f26a035219,
+                    0x85f5f1b29b0f3b24,
+                    0x000002eebef41652,
+                ])),
+                Felt::new(BigInteger320([
+                    0x186250bc5aa52ce3,
+                    0x0d54f28936b526aa,
+                    0xbc9115591e735e0e,
+                    0x7c525f44925eb005,
+                    0x0000018e5243cef5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0715295f617cd15,
+                    0xbb50cccc2f0da9a3,
+                    0xdf749288db2a5dee,
+                    0xc97eec4333faa42b,
+                    0x000001ae22813cf1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87f8b43a87f55945,
+                    0xa439bc257b059934,
+                    0x7fb9e37964a54288,
+                    0x25d9e54b3f121f17,
+                    0x0000006889f128fe,
+                ])),
+                Felt::new(BigInteger320([
+                    0x828c9e742996e025,
+                    0x5ca51b8d2d01d94a,
+                    0x06392fe29d310473,
+                    0x6c2378bf64e0bb5d,
+                    0x000000c69615ab72,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60178f1641541a0d,
+                    0x7586109126b39193,
+                    0x5e92ae15f81d2b1b,
+                    0x0bcae3a3629d3411,
+                    0x0000037d8d1ff372,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb2c52f69e7fbb735,
+                    0x70acfdf432dc53f5,
+                    0x12744498a4cf8e6e,
+                    0xb94af5b0105c792a,
+                    0x000001529831ac15,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! The MNT4-298 base field, of modulus
+//! `p = 475922286169261325753349249653048451545124879242694725395555128576210262817955800483758081`,
+//! which is also the scalar field of MNT6-298.
+//!
+//! Elements are represented with the generic prime field type of arkworks
+//! over 5 limbs, in Montgomery form.
+
+use ark_ff::{biginteger::BigInteger320 as BigInteger, fields::*};
+
+/// An element of the MNT4-298 base field.
+pub type Felt = Fp320<FeltParameters>;
+
+/// Parameters of the MNT4-298 base field.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FeltParameters;
+
+impl Fp320Parameters for FeltParameters {}
+
+impl FftParameters for FeltParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 17;
+
+    /// `17^((p - 1) / 2^17)`
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x884ce85c8d89f2b9,
+        0x8366528dcef9a167,
+        0x8a465859c7d431ff,
+        0xce4c49d76adbcbc5,
+        0x0000039fc98494e1,
+    ]);
+}
+
+impl FpParameters for FeltParameters {
+    const MODULUS: BigInteger = BigInteger([
+        0xc90cd65a71660001,
+        0x41a9e35e51200e12,
+        0xcaeec9635d1330ea,
+        0xa266249da7b0548e,
+        0x000003bcf7bcd473,
+    ]);
+
+    const MODULUS_BITS: u32 = 298;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 22;
+
+    const R: BigInteger = BigInteger([
+        0x18c31a7b5863845c,
+        0xe9de7a15e3b68df5,
+        0xc5df858728faab40,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ]);
+
+    const R2: BigInteger = BigInteger([
+        0x0065acec5613d220,
+        0xa266a1adbf2bc893,
+        0x66bd7673318850e1,
+        0x1f32e014ad38d47b,
+        0x00000224f0918a34,
+    ]);
+
+    const INV: u64 = 0xb071a1b67165ffff;
+
+    /// GENERATOR = 17
+    const GENERATOR: BigInteger = BigInteger([
+        0x259ae5b7c4d1ca15,
+        0xbc20e3dfe73f0ac3,
+        0x97505c422d1f08e7,
+        0x49d149cf165e1b2c,
+        0x000003a87fe6a0cc,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x64866b2d38b30000,
+        0x20d4f1af28900709,
+        0x657764b1ae899875,
+        0xd133124ed3d82a47,
+        0x000001de7bde6a39,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    const T: BigInteger = BigInteger([
+        0x070964866b2d38b3,
+        0x987520d4f1af2890,
+        0x2a47657764b1ae89,
+        0x6a39d133124ed3d8,
+        0x0000000001de7bde,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x0384b24335969c59,
+        0xcc3a906a78d79448,
+        0x1523b2bbb258d744,
+        0x351ce899892769ec,
+        0x0000000000ef3def,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::fields::{
+        fft_field_test, field_serialization_test, field_test, from_str_test, primefield_test,
+    };
+    use ark_std::test_rng;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_field() {
+        let mut rng = test_rng();
+        let a = Felt::rand(&mut rng);
+        let b = Felt::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Felt>();
+        fft_field_test::<Felt>();
+        from_str_test::<Felt>();
+        field_serialization_test::<Felt>(38);
+    }
+}
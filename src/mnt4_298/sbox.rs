@@ -0,0 +1,410 @@
+use super::BigInteger320;
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 11;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 5] = [
+    0xcfc00457a7e88ba3,
+    0xa97bc470b082ef1f,
+    0xa20f72d01308b924,
+    0x49d1567634f30f29,
+    0x000001b2fc3e8f1d,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 17;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger320([
+    0x77b8acd30bab4406,
+    0x7fad41a22e0e8919,
+    0x64e4e7badecebd6f,
+    0x71014683d1f681d5,
+    0x0000024d5781bc40,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    let t1 = x.square(); //         1: 2
+    let t2 = t1 * x; //             2: 3
+    let t3 = t2 * t1; //            3: 5
+    let t4 = t3 * t1; //            4: 7
+    let t5 = t4 * t1; //            5: 9
+    let t6 = t5 * t1; //            6: 11
+    let t7 = t6 * t1; //            7: 13
+    let t8 = t7 * t1; //            8: 15
+    let t9 = t8 * t1; //            9: 17
+    let t10 = t9 * t1; //          10: 19
+    let t11 = t10 * t1; //         11: 21
+    let t12 = t11 * t1; //         12: 23
+    let t13 = t12 * t1; //         13: 25
+    let t14 = t13 * t1; //         14: 27
+    let t15 = t14 * t1; //         15: 29
+    let t16 = t15 * t1; //         16: 31
+    let mut t0 = t14.square(); //  17: 54
+    t0 = t0.square(); //           18: 108
+    t0 = t0.square(); //           19: 216
+    t0 = t0.square(); //           20: 432
+    t0 = t0.square(); //           21: 864
+    t0 = t0.square(); //           22: 1728
+    t0 = t0.square(); //           23: 3456
+    t0 *= t12; //                  24: 3479
+    t0 = t0.square(); //           25: 6958
+    t0 = t0.square(); //           26: 13916
+    t0 = t0.square(); //           27: 27832
+    t0 *= t4; //                   28: 27839
+    t0 = t0.square(); //           29: 55678
+    t0 = t0.square(); //           30: 111356
+    t0 = t0.square(); //           31: 222712
+    t0 = t0.square(); //           32: 445424
+    t0 = t0.square(); //           33: 890848
+    t0 = t0.square(); //           34: 1781696
+    t0 = t0.square(); //           35: 3563392
+    t0 = t0.square(); //           36: 7126784
+    t0 = t0.square(); //           37: 14253568
+    t0 *= t16; //                  38: 14253599
+    t0 = t0.square(); //           39: 28507198
+    t0 = t0.square(); //           40: 57014396
+    t0 = t0.square(); //           41: 114028792
+    t0 = t0.square(); //           42: 228057584
+    t0 = t0.square(); //           43: 456115168
+    t0 = t0.square(); //           44: 912230336
+    t0 *= t9; //                   45: 912230353
+    t0 = t0.square(); //           46: 1824460706
+    t0 = t0.square(); //           47: 3648921412
+    t0 = t0.square(); //           48: 7297842824
+    t0 *= t4; //                   49: 7297842831
+    t0 = t0.square(); //           50: 14595685662
+    t0 = t0.square(); //           51: 29191371324
+    t0 = t0.square(); //           52: 58382742648
+    t0 = t0.square(); //           53: 116765485296
+    t0 = t0.square(); //           54: 233530970592
+    t0 = t0.square(); //           55: 467061941184
+    t0 = t0.square(); //           56: 934123882368
+    t0 = t0.square(); //           57: 1868247764736
+    t0 *= t15; //                  58: 1868247764765
+    t0 = t0.square(); //           59: 3736495529530
+    t0 = t0.square(); //           60: 7472991059060
+    t0 = t0.square(); //           61: 14945982118120
+    t0 = t0.square(); //           62: 29891964236240
+    t0 = t0.square(); //           63: 59783928472480
+    t0 *= t5; //                   64: 59783928472489
+    t0 = t0.square(); //           65: 119567856944978
+    t0 = t0.square(); //           66: 239135713889956
+    t0 = t0.square(); //           67: 478271427779912
+    t0 = t0.square(); //           68: 956542855559824
+    t0 = t0.square(); //           69: 1913085711119648
+    t0 = t0.square(); //           70: 3826171422239296
+    t0 = t0.square(); //           71: 7652342844478592
+    t0 *= t15; //                  72: 7652342844478621
+    t0 = t0.square(); //           73: 15304685688957242
+    t0 = t0.square(); //           74: 30609371377914484
+    t0 = t0.square(); //           75: 61218742755828968
+    t0 = t0.square(); //           76: 122437485511657936
+    t0 = t0.square(); //           77: 244874971023315872
+    t0 = t0.square(); //           78: 489749942046631744
+    t0 = t0.square(); //           79: 979499884093263488
+    t0 = t0.square(); //           80: 1958999768186526976
+    t0 *= t11; //                  81: 1958999768186526997
+    t0 = t0.square(); //           82: 3917999536373053994
+    t0 = t0.square(); //           83: 7835999072746107988
+    t0 = t0.square(); //           84: 15671998145492215976
+    t0 = t0.square(); //           85: 31343996290984431952
+    t0 = t0.square(); //           86: 62687992581968863904
+    t0 = t0.square(); //           87: 125375985163937727808
+    t0 *= t13; //                  88: 125375985163937727833
+    t0 = t0.square(); //           89: 250751970327875455666
+    t0 = t0.square(); //           90: 501503940655750911332
+    t0 = t0.square(); //           91: 1003007881311501822664
+    t0 = t0.square(); //           92: 2006015762623003645328
+    t0 = t0.square(); //           93: 4012031525246007290656
+    t0 *= t14; //                  94: 4012031525246007290683
+    t0 = t0.square(); //           95: 8024063050492014581366
+    t0 = t0.square(); //           96: 16048126100984029162732
+    t0 = t0.square(); //           97: 32096252201968058325464
+    t0 = t0.square(); //           98: 64192504403936116650928
+    t0 = t0.square(); //           99: 128385008807872233301856
+    t0 = t0.square(); //          100: 256770017615744466603712
+    t0 = t0.square(); //          101: 513540035231488933207424
+    t0 *= t7; //                  102: 513540035231488933207437
+    t0 = t0.square(); //          103: 1027080070462977866414874
+    t0 = t0.square(); //          104: 2054160140925955732829748
+    t0 = t0.square(); //          105: 4108320281851911465659496
+    t0 = t0.square(); //          106: 8216640563703822931318992
+    t0 = t0.square(); //          107: 16433281127407645862637984
+    t0 = t0.square(); //          108: 32866562254815291725275968
+    t0 *= t8; //                  109: 32866562254815291725275983
+    t0 = t0.square(); //          110: 65733124509630583450551966
+    t0 = t0.square(); //          111: 131466249019261166901103932
+    t0 = t0.square(); //          112: 262932498038522333802207864
+    t0 = t0.square(); //          113: 525864996077044667604415728
+    t0 *= t2; //                  114: 525864996077044667604415731
+    t0 = t0.square(); //          115: 1051729992154089335208831462
+    t0 = t0.square(); //          116: 2103459984308178670417662924
+    t0 = t0.square(); //          117: 4206919968616357340835325848
+    t0 = t0.square(); //          118: 8413839937232714681670651696
+    t0 = t0.square(); //          119: 16827679874465429363341303392
+    t0 = t0.square(); //          120: 33655359748930858726682606784
+    t0 = t0.square(); //          121: 67310719497861717453365213568
+    t0 = t0.square(); //          122: 134621438995723434906730427136
+    t0 *= t8; //                  123: 134621438995723434906730427151
+    t0 = t0.square(); //          124: 269242877991446869813460854302
+    t0 = t0.square(); //          125: 538485755982893739626921708604
+    t0 = t0.square(); //          126: 1076971511965787479253843417208
+    t0 = t0.square(); //          127: 2153943023931574958507686834416
+    t0 = t0.square(); //          128: 4307886047863149917015373668832
+    t0 *= t3; //                  129: 4307886047863149917015373668837
+    t0 = t0.square(); //          130: 8615772095726299834030747337674
+    t0 = t0.square(); //          131: 17231544191452599668061494675348
+    t0 = t0.square(); //          132: 34463088382905199336122989350696
+    t0 = t0.square(); //          133: 68926176765810398672245978701392
+    t0 = t0.square(); //          134: 137852353531620797344491957402784
+    t0 = t0.square(); //          135: 275704707063241594688983914805568
+    t0 *= t7; //                  136: 275704707063241594688983914805581
+    t0 = t0.square(); //          137: 551409414126483189377967829611162
+    t0 = t0.square(); //          138: 1102818828252966378755935659222324
+    t0 = t0.square(); //          139: 2205637656505932757511871318444648
+    t0 = t0.square(); //          140: 4411275313011865515023742636889296
+    t0 *= x; //                   141: 4411275313011865515023742636889297
+    t0 = t0.square(); //          142: 8822550626023731030047485273778594
+    t0 = t0.square(); //          143: 17645101252047462060094970547557188
+    t0 = t0.square(); //          144: 35290202504094924120189941095114376
+    t0 = t0.square(); //          145: 70580405008189848240379882190228752
+    t0 = t0.square(); //          146: 141160810016379696480759764380457504
+    t0 = t0.square(); //          147: 282321620032759392961519528760915008
+    t0 = t0.square(); //          148: 564643240065518785923039057521830016
+    t0 = t0.square(); //          149: 1129286480131037571846078115043660032
+    t0 = t0.square(); //          150: 2258572960262075143692156230087320064
+    t0 *= t8; //                  151: 2258572960262075143692156230087320079
+    t0 = t0.square(); //          152: 4517145920524150287384312460174640158
+    t0 = t0.square(); //          153: 9034291841048300574768624920349280316
+    t0 = t0.square(); //          154: 18068583682096601149537249840698560632
+    t0 = t0.square(); //          155: 36137167364193202299074499681397121264
+    t0 *= t4; //                  156: 36137167364193202299074499681397121271
+    t0 = t0.square(); //          157: 72274334728386404598148999362794242542
+    t0 = t0.square(); //          158: 144548669456772809196297998725588485084
+    t0 = t0.square(); //          159: 289097338913545618392595997451176970168
+    t0 = t0.square(); //          160: 578194677827091236785191994902353940336
+    t0 = t0.square(); //          161: 1156389355654182473570383989804707880672
+    t0 = t0.square(); //          162: 2312778711308364947140767979609415761344
+    t0 *= t6; //                  163: 2312778711308364947140767979609415761355
+    t0 = t0.square(); //          164: 4625557422616729894281535959218831522710
+    t0 = t0.square(); //          165: 9251114845233459788563071918437663045420
+    t0 *= x; //                   166: 9251114845233459788563071918437663045421
+    t0 = t0.square(); //          167: 18502229690466919577126143836875326090842
+    t0 = t0.square(); //          168: 37004459380933839154252287673750652181684
+    t0 = t0.square(); //          169: 74008918761867678308504575347501304363368
+    t0 = t0.square(); //          170: 148017837523735356617009150695002608726736
+    t0 = t0.square(); //          171: 296035675047470713234018301390005217453472
+    t0 = t0.square(); //          172: 592071350094941426468036602780010434906944
+    t0 = t0.square(); //          173: 1184142700189882852936073205560020869813888
+    t0 = t0.square(); //          174: 2368285400379765705872146411120041739627776
+    t0 = t0.square(); //          175: 4736570800759531411744292822240083479255552
+    t0 = t0.square(); //          176: 9473141601519062823488585644480166958511104
+    t0 = t0.square(); //          177: 18946283203038125646977171288960333917022208
+    t0 = t0.square(); //          178: 37892566406076251293954342577920667834044416
+    t0 *= t10; //                 179: 37892566406076251293954342577920667834044435
+    t0 = t0.square(); //          180: 75785132812152502587908685155841335668088870
+    t0 = t0.square(); //          181: 151570265624305005175817370311682671336177740
+    t0 = t0.square(); //          182: 303140531248610010351634740623365342672355480
+    t0 = t0.square(); //          183: 606281062497220020703269481246730685344710960
+    t0 = t0.square(); //          184: 1212562124994440041406538962493461370689421920
+    t0 = t0.square(); //          185: 2425124249988880082813077924986922741378843840
+    t0 = t0.square(); //          186: 4850248499977760165626155849973845482757687680
+    t0 = t0.square(); //          187: 9700496999955520331252311699947690965515375360
+    t0 = t0.square(); //          188: 19400993999911040662504623399895381931030750720
+    t0 *= t9; //                  189: 19400993999911040662504623399895381931030750737
+    t0 = t0.square(); //          190: 38801987999822081325009246799790763862061501474
+    t0 = t0.square(); //          191: 77603975999644162650018493599581527724123002948
+    t0 = t0.square(); //          192: 155207951999288325300036987199163055448246005896
+    t0 = t0.square(); //          193: 310415903998576650600073974398326110896492011792
+    t0 *= t4; //                  194: 310415903998576650600073974398326110896492011799
+    t0 = t0.square(); //          195: 620831807997153301200147948796652221792984023598
+    t0 = t0.square(); //          196: 1241663615994306602400295897593304443585968047196
+    t0 = t0.square(); //          197: 2483327231988613204800591795186608887171936094392
+    t0 = t0.square(); //          198: 4966654463977226409601183590373217774343872188784
+    t0 = t0.square(); //          199: 9933308927954452819202367180746435548687744377568
+    t0 = t0.square(); //          200: 19866617855908905638404734361492871097375488755136
+    t0 *= t5; //                  201: 19866617855908905638404734361492871097375488755145
+    t0 = t0.square(); //          202: 39733235711817811276809468722985742194750977510290
+    t0 = t0.square(); //          203: 79466471423635622553618937445971484389501955020580
+    t0 = t0.square(); //          204: 158932942847271245107237874891942968779003910041160
+    t0 = t0.square(); //          205: 317865885694542490214475749783885937558007820082320
+    t0 = t0.square(); //          206: 635731771389084980428951499567771875116015640164640
+    t0 = t0.square(); //          207: 1271463542778169960857902999135543750232031280329280
+    t0 *= t5; //                  208: 1271463542778169960857902999135543750232031280329289
+    t0 = t0.square(); //          209: 2542927085556339921715805998271087500464062560658578
+    t0 = t0.square(); //          210: 5085854171112679843431611996542175000928125121317156
+    t0 = t0.square(); //          211: 10171708342225359686863223993084350001856250242634312
+    t0 = t0.square(); //          212: 20343416684450719373726447986168700003712500485268624
+    t0 *= t3; //                  213: 20343416684450719373726447986168700003712500485268629
+    t0 = t0.square(); //          214: 40686833368901438747452895972337400007425000970537258
+    t0 = t0.square(); //          215: 81373666737802877494905791944674800014850001941074516
+    t0 = t0.square(); //          216: 162747333475605754989811583889349600029700003882149032
+    t0 = t0.square(); //          217: 325494666951211509979623167778699200059400007764298064
+    t0 = t0.square(); //          218: 650989333902423019959246335557398400118800015528596128
+    t0 = t0.square(); //          219: 1301978667804846039918492671114796800237600031057192256
+    t0 = t0.square(); //          220: 2603957335609692079836985342229593600475200062114384512
+    t0 *= t12; //                 221: 2603957335609692079836985342229593600475200062114384535
+    t0 = t0.square(); //          222: 5207914671219384159673970684459187200950400124228769070
+    t0 = t0.square(); //          223: 10415829342438768319347941368918374401900800248457538140
+    t0 = t0.square(); //          224: 20831658684877536638695882737836748803801600496915076280
+    t0 = t0.square(); //          225: 41663317369755073277391765475673497607603200993830152560
+    t0 = t0.square(); //          226: 83326634739510146554783530951346995215206401987660305120
+    t0 *= t12; //                 227: 83326634739510146554783530951346995215206401987660305143
+    t0 = t0.square(); //          228: 166653269479020293109567061902693990430412803975320610286
+    t0 = t0.square(); //          229: 333306538958040586219134123805387980860825607950641220572
+    t0 = t0.square(); //          230: 666613077916081172438268247610775961721651215901282441144
+    t0 = t0.square(); //          231: 1333226155832162344876536495221551923443302431802564882288
+    t0 = t0.square(); //          232: 2666452311664324689753072990443103846886604863605129764576
+    t0 *= t9; //                  233: 2666452311664324689753072990443103846886604863605129764593
+    t0 = t0.square(); //          234: 5332904623328649379506145980886207693773209727210259529186
+    t0 = t0.square(); //          235: 10665809246657298759012291961772415387546419454420519058372
+    t0 = t0.square(); //          236: 21331618493314597518024583923544830775092838908841038116744
+    t0 = t0.square(); //          237: 42663236986629195036049167847089661550185677817682076233488
+    t0 = t0.square(); //          238: 85326473973258390072098335694179323100371355635364152466976
+    t0 = t0.square(); //          239: 170652947946516780144196671388358646200742711270728304933952
+    t0 *= t4; //                  240: 170652947946516780144196671388358646200742711270728304933959
+    t0 = t0.square(); //          241: 341305895893033560288393342776717292401485422541456609867918
+    t0 = t0.square(); //          242: 682611791786067120576786685553434584802970845082913219735836
+    t0 = t0.square(); //          243: 1365223583572134241153573371106869169605941690165826439471672
+    t0 = t0.square(); //          244: 2730447167144268482307146742213738339211883380331652878943344
+    t0 = t0.square(); //          245: 5460894334288536964614293484427476678423766760663305757886688
+    t0 = t0.square(); //          246: 10921788668577073929228586968854953356847533521326611515773376
+    t0 = t0.square(); //          247: 21843577337154147858457173937709906713695067042653223031546752
+    t0 = t0.square(); //          248: 43687154674308295716914347875419813427390134085306446063093504
+    t0 *= t6; //                  249: 43687154674308295716914347875419813427390134085306446063093515
+    t0 = t0.square(); //          250: 87374309348616591433828695750839626854780268170612892126187030
+    t0 = t0.square(); //          251: 174748618697233182867657391501679253709560536341225784252374060
+    t0 = t0.square(); //          252: 349497237394466365735314783003358507419121072682451568504748120
+    t0 = t0.square(); //          253: 698994474788932731470629566006717014838242145364903137009496240
+    t0 = t0.square(); //          254: 1397988949577865462941259132013434029676484290729806274018992480
+    t0 *= x; //                   255: 1397988949577865462941259132013434029676484290729806274018992481
+    t0 = t0.square(); //          256: 2795977899155730925882518264026868059352968581459612548037984962
+    t0 = t0.square(); //          257: 5591955798311461851765036528053736118705937162919225096075969924
+    t0 = t0.square(); //          258: 11183911596622923703530073056107472237411874325838450192151939848
+    t0 = t0.square(); //          259: 22367823193245847407060146112214944474823748651676900384303879696
+    t0 = t0.square(); //          260: 44735646386491694814120292224429888949647497303353800768607759392
+    t0 = t0.square(); //          261: 89471292772983389628240584448859777899294994606707601537215518784
+    t0 = t0.square(); //          262: 178942585545966779256481168897719555798589989213415203074431037568
+    t0 = t0.square(); //          263: 357885171091933558512962337795439111597179978426830406148862075136
+    t0 = t0.square(); //          264: 715770342183867117025924675590878223194359956853660812297724150272
+    t0 = t0.square(); //          265: 1431540684367734234051849351181756446388719913707321624595448300544
+    t0 *= t12; //                 266: 1431540684367734234051849351181756446388719913707321624595448300567
+    t0 = t0.square(); //          267: 2863081368735468468103698702363512892777439827414643249190896601134
+    t0 = t0.square(); //          268: 5726162737470936936207397404727025785554879654829286498381793202268
+    t0 = t0.square(); //          269: 11452325474941873872414794809454051571109759309658572996763586404536
+    t0 = t0.square(); //          270: 22904650949883747744829589618908103142219518619317145993527172809072
+    t0 = t0.square(); //          271: 45809301899767495489659179237816206284439037238634291987054345618144
+    t0 *= t8; //                  272: 45809301899767495489659179237816206284439037238634291987054345618159
+    t0 = t0.square(); //          273: 91618603799534990979318358475632412568878074477268583974108691236318
+    t0 = t0.square(); //          274: 183237207599069981958636716951264825137756148954537167948217382472636
+    t0 = t0.square(); //          275: 366474415198139963917273433902529650275512297909074335896434764945272
+    t0 = t0.square(); //          276: 732948830396279927834546867805059300551024595818148671792869529890544
+    t0 = t0.square(); //          277: 1465897660792559855669093735610118601102049191636297343585739059781088
+    t0 = t0.square(); //          278: 2931795321585119711338187471220237202204098383272594687171478119562176
+    t0 = t0.square(); //          279: 5863590643170239422676374942440474404408196766545189374342956239124352
+    t0 = t0.square(); //          280: 11727181286340478845352749884880948808816393533090378748685912478248704
+    t0 *= t16; //                 281: 11727181286340478845352749884880948808816393533090378748685912478248735
+    t0 = t0.square(); //          282: 23454362572680957690705499769761897617632787066180757497371824956497470
+    t0 = t0.square(); //          283: 46908725145361915381410999539523795235265574132361514994743649912994940
+    t0 = t0.square(); //          284: 93817450290723830762821999079047590470531148264723029989487299825989880
+    t0 = t0.square(); //          285: 187634900581447661525643998158095180941062296529446059978974599651979760
+    t0 = t0.square(); //          286: 375269801162895323051287996316190361882124593058892119957949199303959520
+    t0 *= t13; //                 287: 375269801162895323051287996316190361882124593058892119957949199303959545
+    t0 = t0.square(); //          288: 750539602325790646102575992632380723764249186117784239915898398607919090
+    t0 = t0.square(); //          289: 1501079204651581292205151985264761447528498372235568479831796797215838180
+    t0 = t0.square(); //          290: 3002158409303162584410303970529522895056996744471136959663593594431676360
+    t0 = t0.square(); //          291: 6004316818606325168820607941059045790113993488942273919327187188863352720
+    t0 = t0.square(); //          292: 12008633637212650337641215882118091580227986977884547838654374377726705440
+    t0 *= t16; //                 293: 12008633637212650337641215882118091580227986977884547838654374377726705471
+    t0 = t0.square(); //          294: 24017267274425300675282431764236183160455973955769095677308748755453410942
+    t0 = t0.square(); //          295: 48034534548850601350564863528472366320911947911538191354617497510906821884
+    t0 = t0.square(); //          296: 96069069097701202701129727056944732641823895823076382709234995021813643768
+    t0 = t0.square(); //          297: 192138138195402405402259454113889465283647791646152765418469990043627287536
+    t0 = t0.square(); //          298: 384276276390804810804518908227778930567295583292305530836939980087254575072
+    t0 = t0.square(); //          299: 768552552781609621609037816455557861134591166584611061673879960174509150144
+    t0 = t0.square(); //          300: 1537105105563219243218075632911115722269182333169222123347759920349018300288
+    t0 = t0.square(); //          301: 3074210211126438486436151265822231444538364666338444246695519840698036600576
+    t0 = t0.square(); //          302: 6148420422252876972872302531644462889076729332676888493391039681396073201152
+    t0 = t0.square(); //          303: 12296840844505753945744605063288925778153458665353776986782079362792146402304
+    t0 = t0.square(); //          304: 24593681689011507891489210126577851556306917330707553973564158725584292804608
+    t0 = t0.square(); //          305: 49187363378023015782978420253155703112613834661415107947128317451168585609216
+    t0 = t0.square(); //          306: 98374726756046031565956840506311406225227669322830215894256634902337171218432
+    t0 = t0.square(); //          307: 196749453512092063131913681012622812450455338645660431788513269804674342436864
+    t0 = t0.square(); //          308: 393498907024184126263827362025245624900910677291320863577026539609348684873728
+    t0 = t0.square(); //          309: 786997814048368252527654724050491249801821354582641727154053079218697369747456
+    t0 *= t9; //                  310: 786997814048368252527654724050491249801821354582641727154053079218697369747473
+    t0 = t0.square(); //          311: 1573995628096736505055309448100982499603642709165283454308106158437394739494946
+    t0 = t0.square(); //          312: 3147991256193473010110618896201964999207285418330566908616212316874789478989892
+    t0 = t0.square(); //          313: 6295982512386946020221237792403929998414570836661133817232424633749578957979784
+    t0 = t0.square(); //          314: 12591965024773892040442475584807859996829141673322267634464849267499157915959568
+    t0 = t0.square(); //          315: 25183930049547784080884951169615719993658283346644535268929698534998315831919136
+    t0 = t0.square(); //          316: 50367860099095568161769902339231439987316566693289070537859397069996631663838272
+    t0 *= t12; //                 317: 50367860099095568161769902339231439987316566693289070537859397069996631663838295
+    t0 = t0.square(); //          318: 100735720198191136323539804678462879974633133386578141075718794139993263327676590
+    t0 = t0.square(); //          319: 201471440396382272647079609356925759949266266773156282151437588279986526655353180
+    t0 = t0.square(); //          320: 402942880792764545294159218713851519898532533546312564302875176559973053310706360
+    t0 *= t3; //                  321: 402942880792764545294159218713851519898532533546312564302875176559973053310706365
+    t0 = t0.square(); //          322: 805885761585529090588318437427703039797065067092625128605750353119946106621412730
+    t0 = t0.square(); //          323: 1611771523171058181176636874855406079594130134185250257211500706239892213242825460
+    t0 = t0.square(); //          324: 3223543046342116362353273749710812159188260268370500514423001412479784426485650920
+    t0 = t0.square(); //          325: 6447086092684232724706547499421624318376520536741001028846002824959568852971301840
+    t0 = t0.square(); //          326: 12894172185368465449413094998843248636753041073482002057692005649919137705942603680
+    t0 = t0.square(); //          327: 25788344370736930898826189997686497273506082146964004115384011299838275411885207360
+    t0 = t0.square(); //          328: 51576688741473861797652379995372994547012164293928008230768022599676550823770414720
+    t0 *= t16; //                 329: 51576688741473861797652379995372994547012164293928008230768022599676550823770414751
+    t0 = t0.square(); //          330: 103153377482947723595304759990745989094024328587856016461536045199353101647540829502
+    t0 = t0.square(); //          331: 206306754965895447190609519981491978188048657175712032923072090398706203295081659004
+    t0 = t0.square(); //          332: 412613509931790894381219039962983956376097314351424065846144180797412406590163318008
+    t0 *= t3; //                  333: 412613509931790894381219039962983956376097314351424065846144180797412406590163318013
+    t0 = t0.square(); //          334: 825227019863581788762438079925967912752194628702848131692288361594824813180326636026
+    t0 = t0.square(); //          335: 1650454039727163577524876159851935825504389257405696263384576723189649626360653272052
+    t0 = t0.square(); //          336: 3300908079454327155049752319703871651008778514811392526769153446379299252721306544104
+    t0 = t0.square(); //          337: 6601816158908654310099504639407743302017557029622785053538306892758598505442613088208
+    t0 = t0.square(); //          338: 13203632317817308620199009278815486604035114059245570107076613785517197010885226176416
+    t0 = t0.square(); //          339: 26407264635634617240398018557630973208070228118491140214153227571034394021770452352832
+    t0 = t0.square(); //          340: 52814529271269234480796037115261946416140456236982280428306455142068788043540904705664
+    t0 = t0.square(); //          341: 105629058542538468961592074230523892832280912473964560856612910284137576087081809411328
+    t0 *= t9; //                  342: 105629058542538468961592074230523892832280912473964560856612910284137576087081809411345
+    t0 = t0.square(); //          343: 211258117085076937923184148461047785664561824947929121713225820568275152174163618822690
+    t0 = t0.square(); //          344: 422516234170153875846368296922095571329123649895858243426451641136550304348327237645380
+    t0 = t0.square(); //          345: 845032468340307751692736593844191142658247299791716486852903282273100608696654475290760
+    t0 = t0.square(); //          346: 1690064936680615503385473187688382285316494599583432973705806564546201217393308950581520
+    t0 = t0.square(); //          347: 3380129873361231006770946375376764570632989199166865947411613129092402434786617901163040
+    t0 = t0.square(); //          348: 6760259746722462013541892750753529141265978398333731894823226258184804869573235802326080
+    t0 *= t15; //                 349: 6760259746722462013541892750753529141265978398333731894823226258184804869573235802326109
+    t0 = t0.square(); //          350: 13520519493444924027083785501507058282531956796667463789646452516369609739146471604652218
+    t0 = t0.square(); //          351: 27041038986889848054167571003014116565063913593334927579292905032739219478292943209304436
+    t0 = t0.square(); //          352: 54082077973779696108335142006028233130127827186669855158585810065478438956585886418608872
+    t0 = t0.square(); //          353: 108164155947559392216670284012056466260255654373339710317171620130956877913171772837217744
+    t0 = t0.square(); //          354: 216328311895118784433340568024112932520511308746679420634343240261913755826343545674435488
+    t0 * t2 //                    355: 216328311895118784433340568024112932520511308746679420634343240261913755826343545674435491
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
@@ -0,0 +1,2141 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger320, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger320([
+            0x924199d1d27ae230,
+            0x3d6a107a670f3a65,
+            0x50623de3eee6af66,
+            0xd65e7abd1addafcc,
+            0x0000035d30bc808a,
+        ]))],
+        vec![
+            Felt::new(BigInteger320([
+                0x8fb2624dbb683009,
+                0x602685e08c13d2f5,
+                0x5a08d451b49ba212,
+                0xf6187d00f9789490,
+                0x00000042b6188ebe,
+            ])),
+            Felt::new(BigInteger320([
+                0x5375a89a95d8009b,
+                0x81e02df7ed47275c,
+                0x1aa382b52e585a4c,
+                0x83e790494b9bfeb2,
+                0x000000e31c1e73e0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x5d9a7e3cf5e3d344,
+                0x2997e60d9debaa0f,
+                0x843018a685fcc7cc,
+                0xa127a4b0cfacc11c,
+                0x0000036c16fc68c5,
+            ])),
+            Felt::new(BigInteger320([
+                0x7ae9b3c63b832010,
+                0xfcb03a0c9b45d7ae,
+                0x1096fd5faaab2f15,
+                0xcf270aa520ece4c4,
+                0x000002b7debe5bcc,
+            ])),
+            Felt::new(BigInteger320([
+                0xf9606d0bd603fd2a,
+                0x71fc92cc67bbabae,
+                0xb812e4668a318761,
+                0x91a263256d674ee5,
+                0x0000019b5daf1e2e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xefc66bf7b0e2748b,
+                0x4c6ccea682a5b454,
+                0x419e4734a0f26a20,
+                0x515aa8ad1b81e9b3,
+                0x0000031be75ad5a4,
+            ])),
+            Felt::new(BigInteger320([
+                0x49914b483073d286,
+                0xb68b29524fc10b57,
+                0xf2d3e8a86840cc2f,
+                0x36c366d737624f5b,
+                0x0000036b2bb026d6,
+            ])),
+            Felt::new(BigInteger320([
+                0x684eef0abc3937ad,
+                0x6e6d9f22793adc97,
+                0xed20e0a90d18bf98,
+                0x4232ab07cb036be8,
+                0x000003754fdf80d7,
+            ])),
+            Felt::new(BigInteger320([
+                0xb512f5e2fd79d4b3,
+                0xae1fe10213a8eb9e,
+                0x2690f5cce3d0d6a3,
+                0x612a920a08f0758c,
+                0x0000009d43cb20d2,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x92486a71f30446f6,
+                0xbb97af6ca9d498c9,
+                0x3dcc0c867b6d652b,
+                0x783dbd5d71d68ecd,
+                0x0000037fe4c56ea8,
+            ])),
+            Felt::new(BigInteger320([
+                0x60dcadb93b48b1ad,
+                0xbab52c35affe647c,
+                0x5603f8e234924cc5,
+                0x3d40a877aaeec827,
+                0x0000016c46f0ea00,
+            ])),
+            Felt::new(BigInteger320([
+                0x53ea99f029b0e158,
+                0x19e607eb598e4cec,
+                0x181870fcfd80d65a,
+                0xa60cd01e45e77bb9,
+                0x00000192b8d5d691,
+            ])),
+            Felt::new(BigInteger320([
+                0xe3d4f8811db4e477,
+                0x3bc112d368d68048,
+                0x2008a70090ec7dab,
+                0xf37dbef1543808e9,
+                0x00000226c83fab18,
+            ])),
+            Felt::new(BigInteger320([
+                0x7974f4922055b8df,
+                0x30b6a0fd59d6f4bd,
+                0xaee609333d51b1a9,
+                0x3ab974180f681d74,
+                0x000000a3b5606e03,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x96645d7b857ebae5,
+                0xbb3f5e2ad510dd68,
+                0x6ec4e3d1e3459e78,
+                0x33e32bf67a03474c,
+                0x0000002d9d81af5b,
+            ])),
+            Felt::new(BigInteger320([
+                0x7cb7393cb0d0563f,
+                0x26a58ed39ed98e46,
+                0x074b39ee8d2649e9,
+                0x23f558f92587f8db,
+                0x00000315f25c6c3c,
+            ])),
+            Felt::new(BigInteger320([
+                0x3f1aa69defdedfcd,
+                0x6bc83e1e7a90147a,
+                0x369806bea7e485e3,
+                0x1b0fd12159ffd9d2,
+                0x00000377af41efb3,
+            ])),
+            Felt::new(BigInteger320([
+                0x39fb2075d6508287,
+                0x6fc827dada2af904,
+                0xbd6acdcae938c2f9,
+                0x95ea33cec0769764,
+                0x00000097b75bb22f,
+            ])),
+            Felt::new(BigInteger320([
+                0xf5472281b41de7cb,
+                0xb66fcd837c75cab8,
+                0x8bb551a6b15890fc,
+                0x39f0730ea5d18b2a,
+                0x0000035c370624a9,
+            ])),
+            Felt::new(BigInteger320([
+                0x8b1107c1cec8ce9b,
+                0xfb8b38ceae46fe2d,
+                0x31ae489f9e9bbcd6,
+                0x048e8ecea680a417,
+                0x000003b5d56533ba,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0xe7e8f3a0b0efb2f5,
+            0xcbcf914ca2f6386e,
+            0x5f272c2718663b0e,
+            0xd4db40714854257d,
+            0x0000031adfcff1a2,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xebed3c7dea9f3b10,
+            0x998ab9557493c63e,
+            0x942da477d8ce1d84,
+            0x64fa8829bcc5482d,
+            0x000003ac48b2c1ac,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x2ccb0d30d40d5204,
+            0x055b74815b0ac01b,
+            0x61154c6d9c2f3862,
+            0x11f74a180057efa3,
+            0x0000007ad646370a,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xe877a6bc24fa43ed,
+            0x93b9b24d61339158,
+            0x734f8541cdc2e080,
+            0x673fe5a7cb010bc0,
+            0x0000038c17eebb6a,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xd3f2178da7f7262a,
+            0xa477f621717de3cd,
+            0xfdea9c8e63546d90,
+            0xa7f68e031ebeafa0,
+            0x00000100462be73d,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x1905c12b718d1221,
+            0x62a4716b1e8e0860,
+            0xed83bcb1366395e2,
+            0x49f2bea83ae708db,
+            0x000000f68ae1bf0a,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x5d15aeb81a540280,
+            0x095fb5e67cbe4dd7,
+            0x91f4f67b8fa07085,
+            0x73d0fcf5654362bc,
+            0x0000001d6740c62e,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xf8ba41e9926be961,
+            0x00d538c0206b5e86,
+            0xff7c7a0788bfa701,
+            0x463828876ff22049,
+            0x000001907fd4c275,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x3f93b319c472750a,
+            0x9d777fa69a513ee3,
+            0xaae2bc8a1ade58aa,
+            0xda0e3df90dc39151,
+            0x00000007c23413e9,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x71e51cde6128d13c,
+            0x2420face605840b9,
+            0xeacf2182fe6dec93,
+            0xb29e72473644e59c,
+            0x00000162f3b9334e,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xc9e040ece2776543,
+                0x7b458da675b5bb35,
+                0x9226bb2a447471ff,
+                0xfec387efb9b99062,
+                0x000000897cd2d547,
+            ])),
+            Felt::new(BigInteger320([
+                0x7fd761e9dd30f5e5,
+                0xaba31a1c4818bef9,
+                0x889f45a7f9b82af7,
+                0x3e5060ab18c4f6f5,
+                0x000001536afca138,
+            ])),
+            Felt::new(BigInteger320([
+                0x4fb1aef504c25173,
+                0xea11872682e8665e,
+                0xc03b89379afee7b8,
+                0x90a0a45de249a80e,
+                0x000000620882aa88,
+            ])),
+            Felt::new(BigInteger320([
+                0x2173f8df8ddca34d,
+                0xe26a66a65189f996,
+                0xdbd7f2423d1ae238,
+                0x2db48771b0ed5cde,
+                0x000001a91810d5cf,
+            ])),
+            Felt::new(BigInteger320([
+                0xbe28677507c04159,
+                0x5deec44824d5ae0f,
+                0xe0b8d93ad7362123,
+                0xb3609cd363c0022e,
+                0x000000a97a1b4872,
+            ])),
+            Felt::new(BigInteger320([
+                0x9a6afe5355423f4b,
+                0x902f3209e7bf9afb,
+                0x2bfc17be4df94598,
+                0x4f08ff98faf5a20c,
+                0x0000005be119f488,
+            ])),
+            Felt::new(BigInteger320([
+                0xbb50ae6a24e96152,
+                0xe2e65ec7c8ced324,
+                0xd6be25a4b35b3775,
+                0x3fec5726fa1b65c9,
+                0x000002ed0a008029,
+            ])),
+            Felt::new(BigInteger320([
+                0x73643aa8bba75c01,
+                0xaf32b5f2a8cbd3ea,
+                0x3d89579e85f3d9d9,
+                0xa54c9d74948990b3,
+                0x0000025f2aaf9caa,
+            ])),
+            Felt::new(BigInteger320([
+                0x2d0e4b2c22205104,
+                0x0899a58c7a8ce580,
+                0x176ca1e7aba19c93,
+                0x4cd2742c4d446dc3,
+                0x000002a0410b84a9,
+            ])),
+            Felt::new(BigInteger320([
+                0x5695812e46798666,
+                0x153f0472352444ad,
+                0xa53b4421d5333c5a,
+                0x19b40a84d7effb91,
+                0x000003097cc6b276,
+            ])),
+            Felt::new(BigInteger320([
+                0x4b8277e6e13b1daf,
+                0x42705b27f0e18faa,
+                0xff27e237551d0fe9,
+                0x3b1c434243172b45,
+                0x000000b43e3f1a22,
+            ])),
+            Felt::new(BigInteger320([
+                0xb9f26944a50bf967,
+                0x0108c3eb1de975dc,
+                0xeb821d0cdb136f59,
+                0xa764f451c34eabc0,
+                0x0000023cfd7328ef,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x86823fb156c9ae36,
+                0x235f9f013d06edfe,
+                0x4d6f7dc91690fbcc,
+                0xf1000e6e628a5019,
+                0x000002572a66cdfc,
+            ])),
+            Felt::new(BigInteger320([
+                0x161a9c721799130b,
+                0x17a5a44aa985c2e4,
+                0x6f5d8c2da1e9c320,
+                0xe9b5cc34ef5a05a0,
+                0x0000005804dc4e90,
+            ])),
+            Felt::new(BigInteger320([
+                0x451da4432c8c7f12,
+                0x2d3dcac438ab4e3a,
+                0xc64c2fa2c200fdc3,
+                0x4c2850212cabcdf9,
+                0x0000039db714436e,
+            ])),
+            Felt::new(BigInteger320([
+                0x4f1284bc7a9bf4be,
+                0xf35a2e76e61707b3,
+                0x24dd08fb34cea2c7,
+                0xf2ae5f0af95ceafa,
+                0x000003b6e4a3513d,
+            ])),
+            Felt::new(BigInteger320([
+                0x1ea97f570a0765d2,
+                0x57a4fb69dd730097,
+                0xaad5203c2b19e761,
+                0xc4a86a5c4c31157b,
+                0x00000367f0ec4798,
+            ])),
+            Felt::new(BigInteger320([
+                0xc2b8f9b109880847,
+                0x12b03c7c94edc5c3,
+                0x6f4b5dc9e1d11ff9,
+                0x7855ca57ba95b902,
+                0x000002ad3e63d348,
+            ])),
+            Felt::new(BigInteger320([
+                0x62ed9408385f1486,
+                0x00e174d632ae3fe1,
+                0x868b7df6fcf43efd,
+                0xb1faab57f678965c,
+                0x00000005660afac0,
+            ])),
+            Felt::new(BigInteger320([
+                0x329c1b05286b552e,
+                0x7fdbdc2d80d60def,
+                0xe018b377de2e1b00,
+                0xecb814a0fc10a0a6,
+                0x000000ec4086d29b,
+            ])),
+            Felt::new(BigInteger320([
+                0xfaed820fdae2e1b1,
+                0x3f97d5dbeed64541,
+                0xc098dda6fc37876c,
+                0x38a862443377402b,
+                0x0000028c9d045a54,
+            ])),
+            Felt::new(BigInteger320([
+                0xa3c1ee25c1de1759,
+                0xd7f19b5b5f95cd48,
+                0xc40d76b24ccbedb2,
+                0xad01c2f17457cf51,
+                0x0000012b8ddf2fc9,
+            ])),
+            Felt::new(BigInteger320([
+                0xecd9324bc92641e9,
+                0x166e220a2fd8a1f6,
+                0x264c06ddacd2abf4,
+                0x8045066565b665c3,
+                0x000003a0ad0f098c,
+            ])),
+            Felt::new(BigInteger320([
+                0x9df27d28197a154e,
+                0xbacae5dc8b2acb1e,
+                0x0d962776590f7dcb,
+                0xae14fc8813f52e3f,
+                0x0000007b93d83bcd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x05a6842a42651f96,
+                0x783f450ce1b70ceb,
+                0xe9190466bcfba8e9,
+                0xaaaee243e241e27c,
+                0x0000029b2faa762c,
+            ])),
+            Felt::new(BigInteger320([
+                0xa85536872dbf9c1d,
+                0xbdf2d2d6787f4e3a,
+                0x91d646b8daf1bdbc,
+                0xfd891932b78be546,
+                0x000000c9110eebc4,
+            ])),
+            Felt::new(BigInteger320([
+                0xaa93326d4cd9aff2,
+                0xbd3cc5f857e89d16,
+                0x89bfdb641b4555a5,
+                0x4f28b53bd84982bb,
+                0x000000823f620f09,
+            ])),
+            Felt::new(BigInteger320([
+                0x4b2c18a5bdea6a04,
+                0x752a5fa5fd29b578,
+                0x2ba3bd472e753378,
+                0x2a6a893389c8f2ab,
+                0x00000199b650d064,
+            ])),
+            Felt::new(BigInteger320([
+                0x2f935b1616ac8942,
+                0x467a737b5f789a66,
+                0x58f79fd867ae6215,
+                0x422446b8e1c6a5da,
+                0x000000424dedc71f,
+            ])),
+            Felt::new(BigInteger320([
+                0x177b8fffaaede79d,
+                0x0e08715f0a2ef1c9,
+                0x5f550d7420dc0090,
+                0x7c6721183db61ae4,
+                0x000000cac6812a16,
+            ])),
+            Felt::new(BigInteger320([
+                0x3483a703142a5597,
+                0xe214dc971cae735b,
+                0xc106042336a7da93,
+                0xeaf171c4ef099e78,
+                0x000001aa90587347,
+            ])),
+            Felt::new(BigInteger320([
+                0x00f220d4e4c4142a,
+                0x783efb984b216d71,
+                0xd4496cc616aa2fe0,
+                0x816b0186fb2c2b17,
+                0x000000a1c4cc5290,
+            ])),
+            Felt::new(BigInteger320([
+                0xa5766da2f8904d3b,
+                0x2ad364e24eba92eb,
+                0x75d43634fe242f18,
+                0x65aadb2afe224512,
+                0x0000034315f74472,
+            ])),
+            Felt::new(BigInteger320([
+                0x95c327c5f52d995c,
+                0xc8e1cc20402005c4,
+                0xa11eb3576d4c21c9,
+                0x00cd3f2b10c5c2d0,
+                0x00000181b8a3bcda,
+            ])),
+            Felt::new(BigInteger320([
+                0x87c604090236dd97,
+                0x74d87ee939947107,
+                0xe2e24124c1b0d15d,
+                0xeb37651ae7962230,
+                0x000001e3c64bcd95,
+            ])),
+            Felt::new(BigInteger320([
+                0x10eae5d2515683ed,
+                0xb863fffc17cc6634,
+                0x3a35accc52792d1e,
+                0xcf7cb62d10880f81,
+                0x000003221bb790d8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x3f2fc6d7906a3596,
+                0xc6a0238e120d8f92,
+                0x0da40d6f8618e360,
+                0x82d90bf69cb54288,
+                0x0000004ab5ea9767,
+            ])),
+            Felt::new(BigInteger320([
+                0x7b73619d2263f040,
+                0x87c31f09a5e6457f,
+                0xf7e8d5f9902dda9b,
+                0xfffad5e7cdcdbc87,
+                0x00000000e3ff9e55,
+            ])),
+            Felt::new(BigInteger320([
+                0xf1614741a1cf5ddd,
+                0xaa44e8264afd7be2,
+                0x625ef81dd6efdbe3,
+                0x1485f5d766f23b0f,
+                0x0000018fb8812b5b,
+            ])),
+            Felt::new(BigInteger320([
+                0xbd6c3e3c3128ca7b,
+                0x013257d561c60dee,
+                0x57b5fef51aab761f,
+                0x6bf380c9d7ab6861,
+                0x0000007c81653b1a,
+            ])),
+            Felt::new(BigInteger320([
+                0x20ef6b3d322f0218,
+                0x970f265488b4bdd2,
+                0xafceefc612532e79,
+                0xc4e5db0188d2ef73,
+                0x000000b6b2aa1eaa,
+            ])),
+            Felt::new(BigInteger320([
+                0x452eb09f4e89f62e,
+                0xef6ce7d30bbb89af,
+                0x2a30688a5f64179f,
+                0x97a9d2d104918466,
+                0x000000654eaaac61,
+            ])),
+            Felt::new(BigInteger320([
+                0x1b190850fa8c9988,
+                0xcd9f3c5e6593708a,
+                0x75f5a6905461c55c,
+                0x75840a5b0b4cf226,
+                0x000001ed96e74c65,
+            ])),
+            Felt::new(BigInteger320([
+                0x83be32565cec1cb6,
+                0x2ed5f8cf01559138,
+                0xb8e285adef291522,
+                0x491b5dcba04fe3c6,
+                0x0000024d369ac1ed,
+            ])),
+            Felt::new(BigInteger320([
+                0xa006b0c139a0f05f,
+                0x8ed553896babcc02,
+                0x2cb234e54f7b1bea,
+                0x7836ded18096a6f4,
+                0x000001baa66910b4,
+            ])),
+            Felt::new(BigInteger320([
+                0x01871a589a863a7c,
+                0x738debeda490fa70,
+                0x50767839985188de,
+                0x1696b84c9f879fe1,
+                0x0000006a6a3a0be1,
+            ])),
+            Felt::new(BigInteger320([
+                0x37eceb3f6cf10c27,
+                0xb894ea30cbf86dc9,
+                0x0ca61e08b396f565,
+                0xbf0e6d5df8dcdfe3,
+                0x000000d1edeaefbc,
+            ])),
+            Felt::new(BigInteger320([
+                0xd18616361094f1f9,
+                0x1a17850e26732cdf,
+                0x0eaa0b7f4c98143f,
+                0x48e18894b104c836,
+                0x000000b6deac0d14,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xb9dc9a3c9de8bb4e,
+                0x7a622789d1f27b77,
+                0x22ee0a719fb73d1d,
+                0x54b61bae82341323,
+                0x000001f77d35c499,
+            ])),
+            Felt::new(BigInteger320([
+                0x789aa046287b0f7c,
+                0x6615c072b5ba8b05,
+                0x2ce18b09226332f8,
+                0x29513a7d875c1bcc,
+                0x00000262298af1db,
+            ])),
+            Felt::new(BigInteger320([
+                0x186939df67660821,
+                0x6abf006ae1b95905,
+                0x5c2791e0145d20f3,
+                0x594f3c0cdfb9baeb,
+                0x00000081a1654dbf,
+            ])),
+            Felt::new(BigInteger320([
+                0x040ef9b8a42cec99,
+                0x051820ad9ceb5588,
+                0x52711a7fe3d2a811,
+                0x4a8981d923e17632,
+                0x0000006ed512d2ac,
+            ])),
+            Felt::new(BigInteger320([
+                0xf46588489050fa57,
+                0x2efae2362cf22a86,
+                0xf98cd38d656a4530,
+                0x190fa4c89791f36f,
+                0x0000016efc8a73cc,
+            ])),
+            Felt::new(BigInteger320([
+                0xa83625e0d0c4de14,
+                0x26fd74658c8402aa,
+                0xfaca18c688a1a012,
+                0x572eb8ead1f3e049,
+                0x000000bbe7b3c6f6,
+            ])),
+            Felt::new(BigInteger320([
+                0xa3dfeb612631928b,
+                0x575d916b28417f21,
+                0xd7de6a72733dac34,
+                0xa98c21c867d972e5,
+                0x000001ea6f0273d5,
+            ])),
+            Felt::new(BigInteger320([
+                0x83c6b893dcc8f7eb,
+                0xcf18a735c22c4987,
+                0xd5c67a51a3543346,
+                0x9419a4658fc51666,
+                0x000002605b30430a,
+            ])),
+            Felt::new(BigInteger320([
+                0xf33f8fd625b01c7a,
+                0xd0963ab8d746d866,
+                0xa24d6a71c3600442,
+                0x9913f660806ceb04,
+                0x000000a566ba966b,
+            ])),
+            Felt::new(BigInteger320([
+                0x9ecc13af978feaa0,
+                0xb7e7266f8ef3ce34,
+                0x62950da87be2386c,
+                0x8c68a48412e7993b,
+                0x0000021e7ae45ed9,
+            ])),
+            Felt::new(BigInteger320([
+                0x2c0a0fe58975d114,
+                0x6717123e80a3d90d,
+                0x5a36735149a8443a,
+                0x3792466e98794a2b,
+                0x000000478cbe2ace,
+            ])),
+            Felt::new(BigInteger320([
+                0x3ef991fbd58fef30,
+                0x7d842ec13e51c67b,
+                0xc71c62400dfb55fb,
+                0x34e380d2960cd4c7,
+                0x000001e16af4ac02,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xc36e307996c231d4,
+                0x08cd6245a9dc7ffd,
+                0x1ca31544c10e8377,
+                0x58b495f16325f4df,
+                0x000001547e8609fa,
+            ])),
+            Felt::new(BigInteger320([
+                0x92cad9f8939e7d5e,
+                0xdd46d75d687afdbe,
+                0xad023df2ab2a6c40,
+                0x7fe3a2ba189b0016,
+                0x00000265cc9da1d4,
+            ])),
+            Felt::new(BigInteger320([
+                0xfe4ada1c53b735b7,
+                0x2c5b14cc12498740,
+                0xeddbe93d74cca84d,
+                0x35bf3ac85f0779f8,
+                0x0000004420f27575,
+            ])),
+            Felt::new(BigInteger320([
+                0x3b4d9507ed3c8421,
+                0x9248476757424517,
+                0x6cda831ffabe5081,
+                0x84d90ff3afdb24e4,
+                0x0000008b255c82dd,
+            ])),
+            Felt::new(BigInteger320([
+                0xd1f9999895d673e2,
+                0x11d84a4ffd4acd6e,
+                0x1b1d81c694aaf04a,
+                0xb6838ef28b5992d4,
+                0x0000021ddf577d03,
+            ])),
+            Felt::new(BigInteger320([
+                0xd7c76e1a026d4eff,
+                0x3dc6eabc047b1fc6,
+                0x1ff61b97a776fd02,
+                0x6560065683d8646b,
+                0x0000006d14c8536b,
+            ])),
+            Felt::new(BigInteger320([
+                0xbcf147e869b24b1a,
+                0x2ac12a6c7d7b40eb,
+                0x1169e3f72009a0cc,
+                0xac9d4077b148349e,
+                0x00000364dbf93569,
+            ])),
+            Felt::new(BigInteger320([
+                0x353cd0c4bfef7232,
+                0x79d5e251a31969f5,
+                0xfaf0db6a3cfabc0c,
+                0x4097d2cd7313f4a8,
+                0x0000031d66042b3d,
+            ])),
+            Felt::new(BigInteger320([
+                0x94815a7dbb7729fa,
+                0x625048cae849c6ae,
+                0x7b493d6e6c7d3175,
+                0x5efbd3524df59eb7,
+                0x0000002a85a9bd6c,
+            ])),
+            Felt::new(BigInteger320([
+                0xc0821ed09a9fc32d,
+                0xc584a8e97016e0ca,
+                0x415dcde37f881432,
+                0x494b1141ac5d1d7c,
+                0x0000009cdac647cb,
+            ])),
+            Felt::new(BigInteger320([
+                0x8a4c809fc9f6e075,
+                0xb683663c310b61bd,
+                0xd61a9bd283118f18,
+                0x31f1204cc1ae6b9b,
+                0x000002b9b3a3522e,
+            ])),
+            Felt::new(BigInteger320([
+                0x195799b329b49df8,
+                0x7b1efda34913b554,
+                0x849c1f98387849ae,
+                0x02e01457e926d321,
+                0x00000149af29c5e5,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger320([
+                0xe113eafee3bb8717,
+                0xd310ba2818f9c2e2,
+                0x5fcbd2ba6e3741a2,
+                0xd164ef2a6c53b435,
+                0x0000011601c3e142,
+            ])),
+            Felt::new(BigInteger320([
+                0x3e3c95625a1a0114,
+                0x6a478b9f7382c0cc,
+                0x505eb9ea98412d1b,
+                0xd6dc1837c332806f,
+                0x00000207ae47ecef,
+            ])),
+            Felt::new(BigInteger320([
+                0xef1cb636b73a88ab,
+                0x6563c44bc8b5e010,
+                0x13d27e1bb381871c,
+                0xb8d22ab7892993a5,
+                0x000003824fde4501,
+            ])),
+            Felt::new(BigInteger320([
+                0x4289551b207101c5,
+                0xda51a43076379c1b,
+                0x5feed7b96353247c,
+                0x80c46beb2713a24f,
+                0x000003846691e65a,
+            ])),
+            Felt::new(BigInteger320([
+                0x7c860fe84f7db386,
+                0x08737fa9aa9f8023,
+                0x4eb346131611e544,
+                0xa0784fbf9d2fbcb1,
+                0x00000369a0aef9d1,
+            ])),
+            Felt::new(BigInteger320([
+                0x187659471d0d7b90,
+                0x67eb1e90d65595c0,
+                0xfee73e3cb00f9650,
+                0x6bbcb56b356d06a9,
+                0x0000033de5f76787,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x3f9c506d5aed0f6d,
+                0xa1cb2bcb4aee2c4e,
+                0x4fd0e5c8a1bf912e,
+                0x64bdefa6fa0d2c92,
+                0x000000de18cfc96a,
+            ])),
+            Felt::new(BigInteger320([
+                0x870efb27125f43f4,
+                0xb909207cbc66ac6d,
+                0xec6053b738910adf,
+                0xae57851a2d93476d,
+                0x00000131754a938a,
+            ])),
+            Felt::new(BigInteger320([
+                0x5ccd7e76acccac5f,
+                0x5b9a92f1e4ef746c,
+                0xaafa52cc98195792,
+                0x20b81f9a08ded80b,
+                0x0000026b24a8ee03,
+            ])),
+            Felt::new(BigInteger320([
+                0x3bf7ca5de461a88e,
+                0x9df4c9e240c1b655,
+                0xf3ea0cd53ee04fb0,
+                0x74d2f55c18dfb62a,
+                0x000000d1df18e432,
+            ])),
+            Felt::new(BigInteger320([
+                0x0284d546a7918a79,
+                0xfb2137605f160e2d,
+                0x04e7d7ed877c7a39,
+                0x31ba57312b0e9250,
+                0x000000e8d41042c4,
+            ])),
+            Felt::new(BigInteger320([
+                0x4a9443a12f309024,
+                0x3d71c299959fbca8,
+                0x39b32d8a00150f49,
+                0xcd411ba54bf3aa2e,
+                0x000003542d2eb0cb,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x463d81dcb2334a03,
+                0x255552b82d344619,
+                0xb705f705624fc7c2,
+                0x4fc83e68c7eb7103,
+                0x000002d573d71eab,
+            ])),
+            Felt::new(BigInteger320([
+                0x4f520ff975ad3875,
+                0x5148b03507106a68,
+                0x3304243bd7ec3965,
+                0xd973d3a80d39286d,
+                0x0000036ddf7d9921,
+            ])),
+            Felt::new(BigInteger320([
+                0x90aa88b4e3171f41,
+                0x004dce6bb585343c,
+                0x13563db876ad6715,
+                0xead3a21957829c6b,
+                0x000002857fdc35a5,
+            ])),
+            Felt::new(BigInteger320([
+                0x4e8f1ace9a833014,
+                0xbdd95460ff01443f,
+                0x95e9030e9330426a,
+                0x030df94592f3549b,
+                0x0000001549a43abd,
+            ])),
+            Felt::new(BigInteger320([
+                0xdc78b0b291abd799,
+                0xdf04df8497a3b562,
+                0xbd456d859e2fcd15,
+                0x64c71d624e025fd6,
+                0x000000a14baf4d0a,
+            ])),
+            Felt::new(BigInteger320([
+                0xef3ae890ac181385,
+                0xe2beebd3c96d3005,
+                0xa6e126b9585726ca,
+                0x40370b489716636e,
+                0x000002e83a8ea7e3,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x45551d01ba25c0f7,
+                0x9c8daffcb8072bc4,
+                0x57750335109e5579,
+                0xdb43a2229f50c7f9,
+                0x000002e3f94e3c20,
+            ])),
+            Felt::new(BigInteger320([
+                0x605e84084bedd7f7,
+                0x2f702a819d0829bd,
+                0xc22a42d3b98db488,
+                0x2517f3ba200e1538,
+                0x0000013839ec7c7b,
+            ])),
+            Felt::new(BigInteger320([
+                0xdceab8e70ee8a264,
+                0x62dda179714c339b,
+                0xeab0a476873c1e94,
+                0xd09f1577fd1ab19b,
+                0x00000006f804ad01,
+            ])),
+            Felt::new(BigInteger320([
+                0xc069e92ce16c05b6,
+                0xd3698445088e5d10,
+                0x8e6f03ac5aefc408,
+                0x11de794daaeb9d54,
+                0x000003acd4991169,
+            ])),
+            Felt::new(BigInteger320([
+                0x36118a4b3a94361b,
+                0xf21ef7665716638b,
+                0xa8c03ddcf569207b,
+                0x5db371fd76a16fba,
+                0x00000111b6a25ab3,
+            ])),
+            Felt::new(BigInteger320([
+                0x8abcc0d57024b531,
+                0x25b8ceb61d08d294,
+                0x0118409afb4c8e6f,
+                0xf77b8048a36a74aa,
+                0x00000062d9ad609e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xe372f7c7085be320,
+                0x009e3d5fdb5aef7b,
+                0x956605403dd04fd2,
+                0xdd3a4fca67db63e2,
+                0x000002da95446605,
+            ])),
+            Felt::new(BigInteger320([
+                0xe6df5f2ea06d7c3c,
+                0x4fad38a532795767,
+                0xaf68ce15a8586988,
+                0xbe0242d78b0a1412,
+                0x000000edf18edc70,
+            ])),
+            Felt::new(BigInteger320([
+                0x8f6bf164b0a39b9a,
+                0x65d3d3b9226c309f,
+                0x200adb827bb99b1e,
+                0x2290ec49995463a8,
+                0x000001992b5d1466,
+            ])),
+            Felt::new(BigInteger320([
+                0x9517529454509020,
+                0x8cc9610d11a48221,
+                0xf7d0ba34d4e8502f,
+                0xbdf71be31825eb07,
+                0x000001d03737e459,
+            ])),
+            Felt::new(BigInteger320([
+                0xe7b4d926c6811f16,
+                0xc2d42788250f6bc0,
+                0x44ee7250601ccc2c,
+                0xdb32396d3c4a569a,
+                0x000001032785d95f,
+            ])),
+            Felt::new(BigInteger320([
+                0xf94a0e0b884bcfe9,
+                0x8217c9d2c6e4e24a,
+                0x2e643b308a8c633b,
+                0x2b2138f49ecd8168,
+                0x0000039ed0434667,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x78a2bc562fe7b69b,
+                0x67494ddd76933a7b,
+                0x37a3301dd44df0fe,
+                0xeae6e19483fdb648,
+                0x000000ab8ea6925b,
+            ])),
+            Felt::new(BigInteger320([
+                0x19e1eb5a25ef4458,
+                0xd220c3960a1502e7,
+                0xa658c82b243501a3,
+                0xebcad2e71c155116,
+                0x000000f2452fb2d8,
+            ])),
+            Felt::new(BigInteger320([
+                0xf94ff1d3f02b62c7,
+                0x4e194f1798dffac6,
+                0x8248a40df85eda48,
+                0xeb78ef52ebe60ac3,
+                0x0000019a12fd571d,
+            ])),
+            Felt::new(BigInteger320([
+                0x385bade66eecba43,
+                0xa56fc3122a1a541c,
+                0xcb4f8952efec9bf5,
+                0x864f148dee66fd9d,
+                0x00000009217a16ca,
+            ])),
+            Felt::new(BigInteger320([
+                0xb70020dc14c1a633,
+                0xd8e9485ef24ff8d2,
+                0xf4f588c77909b429,
+                0xe32b491029fea35d,
+                0x00000331dbab3b69,
+            ])),
+            Felt::new(BigInteger320([
+                0x7384ef5de43bb9fd,
+                0xc138d062fbef1624,
+                0xf4c83fe4709d8cc1,
+                0x9fa5aa7a303311a5,
+                0x00000171f0ce0e92,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xfac253afa39bf9dd,
+                0x00fadc89b441899d,
+                0x64b8ea6dae0bceb3,
+                0xda7f8aadf703d60c,
+                0x00000316784091e1,
+            ])),
+            Felt::new(BigInteger320([
+                0xfdf98e22eb9bcc07,
+                0x9ce9773c71ca436c,
+                0x7c53ad978b053041,
+                0xf943e6b9dafa078b,
+                0x00000070d3168bf9,
+            ])),
+            Felt::new(BigInteger320([
+                0xe1c785b6b177f709,
+                0x4d208ede88e6a008,
+                0x3dfe4ab0d8857018,
+                0x6af165e1cedc1bbd,
+                0x00000136393b4e54,
+            ])),
+            Felt::new(BigInteger320([
+                0x2b53c4fb75150856,
+                0x8c70aeb6f49b1af7,
+                0xcefc47346f3e22c8,
+                0x07f691dd5a9584d7,
+                0x00000366cb11f7ae,
+            ])),
+            Felt::new(BigInteger320([
+                0xf898aebc7132f820,
+                0xd852154bd4020639,
+                0xe482c0f78b52f55e,
+                0x640e831bef8b811a,
+                0x0000033d30234aa0,
+            ])),
+            Felt::new(BigInteger320([
+                0x8044e224d7ebb37a,
+                0x01943475133070c3,
+                0xf8ccf422953c8905,
+                0xea6c70642b2ad7c7,
+                0x0000032b289be38b,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x4145848236fda0ae,
+                0x3890fa3df5283449,
+                0x1723cd930d5ef66f,
+                0x61d8ed924b810b5d,
+                0x00000038b55cf528,
+            ])),
+            Felt::new(BigInteger320([
+                0x058dc3ed2067664d,
+                0x8dd96c13e89c902c,
+                0x797c62dbf290867c,
+                0x7781030df0868df2,
+                0x0000001a1091da9c,
+            ])),
+            Felt::new(BigInteger320([
+                0x25bd2056de700fc8,
+                0xf9a3f8a66ea2ff1b,
+                0x2a5ee1777341a947,
+                0x350ef3b9fee95ffb,
+                0x000000cf878e9eff,
+            ])),
+            Felt::new(BigInteger320([
+                0x442387d58556fcaa,
+                0xd847f1ec36c71f98,
+                0xd4dc9ef7ec597f90,
+                0x0015533d1126789a,
+                0x000002d1ab17e2c7,
+            ])),
+            Felt::new(BigInteger320([
+                0x9ba29e7a9315a669,
+                0x28d43b9d7a20ed7d,
+                0x0a782d5906633052,
+                0xd8ff9c63e0b53525,
+                0x000003891c2d62f8,
+            ])),
+            Felt::new(BigInteger320([
+                0x66bd9178e300d24e,
+                0x2fa7d06e0fd39dd3,
+                0xe9927013b9872eec,
+                0xb9bd7e9bf347a61b,
+                0x0000001aadef53d6,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xfecf013d445171ae,
+                0x1b5e1623871a352d,
+                0x0a28e1252d472e47,
+                0xe4bcfbcbc6829361,
+                0x0000017425050b56,
+            ])),
+            Felt::new(BigInteger320([
+                0x0012512a864f31a3,
+                0xf19fed49077d48c9,
+                0xb4b8cf74ce374ef5,
+                0x83cebdf09757df46,
+                0x000001f23302ca42,
+            ])),
+            Felt::new(BigInteger320([
+                0x0a5219cb8adffe8f,
+                0x69ffaf056ed1127a,
+                0xac7365e67c215e51,
+                0x2c1642dc45258031,
+                0x000000fd26c16afe,
+            ])),
+            Felt::new(BigInteger320([
+                0x403e76ae36d76f87,
+                0xbc2c6b298bb0aec2,
+                0xaa4ec3167c9b2143,
+                0xcd185b447b74c720,
+                0x000000570feade5d,
+            ])),
+            Felt::new(BigInteger320([
+                0x3943698b48dccacd,
+                0xe95e23b1126c605f,
+                0xab4e1da3d7942ee8,
+                0xb84ef85eba75ecee,
+                0x000003aa6d27b617,
+            ])),
+            Felt::new(BigInteger320([
+                0x910ef89533b268a9,
+                0x2e4a2e4cf7256e6a,
+                0x65ab6b176cccd307,
+                0x01129315929655c6,
+                0x0000022016791866,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x1fabacd871e8cba2,
+                0x3f05e37224525512,
+                0xa641ed95b4d48621,
+                0xa6f85fb52ff97575,
+                0x00000264eb27f78b,
+            ])),
+            Felt::new(BigInteger320([
+                0xbbaed275c6184ab0,
+                0x5ab4e82ded051c09,
+                0xa216520eed465eeb,
+                0x62de10a284d1beb6,
+                0x000001e95b2384a4,
+            ])),
+            Felt::new(BigInteger320([
+                0x11b2ea41749ad1d6,
+                0x841082b4d512ccbd,
+                0x3aef01c3a7915c82,
+                0x38eb509a927db447,
+                0x000002cad8028484,
+            ])),
+            Felt::new(BigInteger320([
+                0xbd0fc887078e575a,
+                0xaf2648df897b29ae,
+                0xffe97da17b452c75,
+                0xfe6087b7dfffbd8a,
+                0x0000032905659841,
+            ])),
+            Felt::new(BigInteger320([
+                0x7ec92da755ba3f55,
+                0xeea05cdcf9ed7923,
+                0x4a9072c3c866c764,
+                0x4566598305b198cb,
+                0x0000018d5482a508,
+            ])),
+            Felt::new(BigInteger320([
+                0x40347e4d5ec02ac3,
+                0xf1630a82798ca1d3,
+                0x147cc2a3678fc912,
+                0xd6b96d377b2783a6,
+                0x000002e4f4ebbe93,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x6f7ba1d228c484b5,
+                0x2d4ec04928752b89,
+                0x60d63dea8203f4f4,
+                0x8780b69b5f292100,
+                0x000001446e73bdc0,
+            ])),
+            Felt::new(BigInteger320([
+                0xbad6ccf2fd44f2b6,
+                0x08302dda5b56574c,
+                0x706a9f09c8132b5e,
+                0x815f90c129defeae,
+                0x0000009780f07559,
+            ])),
+            Felt::new(BigInteger320([
+                0x2886693e747e7b43,
+                0xc7450bd9126792b3,
+                0x7ae5b97cc56b1d0a,
+                0xdebff2d12c644749,
+                0x0000016160914346,
+            ])),
+            Felt::new(BigInteger320([
+                0x147dafe01ae6b747,
+                0xec4341e787ab985f,
+                0xa10180f6a21a45de,
+                0x79ed270305633d74,
+                0x000000fb2a9ca1c1,
+            ])),
+            Felt::new(BigInteger320([
+                0xf7a7261b7ac3b483,
+                0xac9afd0717c2d1ae,
+                0xd2becae385a02b1b,
+                0x9199e1b1d7d23624,
+                0x0000036327eb19db,
+            ])),
+            Felt::new(BigInteger320([
+                0x4aee2d52f6a995e4,
+                0xf8cd947688061e93,
+                0x7b290e8340e30940,
+                0xa449c398bc57fa2b,
+                0x000001a23d56f439,
+            ])),
+            Felt::new(BigInteger320([
+                0x6bf3e7e45645adf1,
+                0x0771a92371b52066,
+                0xdb61ed20947fe52e,
+                0x8bd49e70909eddf7,
+                0x000002c482af36a2,
+            ])),
+            Felt::new(BigInteger320([
+                0x8a44260bbc3d6cd7,
+                0xde10cf61782b64f5,
+                0x679c23322117397a,
+                0x035d8dd971ce87cd,
+                0x000001d1588f148d,
+            ])),
+            Felt::new(BigInteger320([
+                0x51311b6509aaffab,
+                0x1b0fec1a492117e0,
+                0x7bcbccc8ad78267b,
+                0x42764746a6833dd0,
+                0x000001a3003f2b96,
+            ])),
+            Felt::new(BigInteger320([
+                0x91b1b21033e885b6,
+                0x4f2683f1139a83c7,
+                0x196b23b16021c581,
+                0x238c40e4f64f7a98,
+                0x000001ecef0e2936,
+            ])),
+            Felt::new(BigInteger320([
+                0x2229058f325c7c74,
+                0xa8d8c4a6fd37001d,
+                0x8cc4115f15d9ef16,
+                0x245bce9e069e0099,
+                0x0000028f1369de78,
+            ])),
+            Felt::new(BigInteger320([
+                0x1117d6d388c2f42e,
+                0xb24cadc758e358c6,
+                0x842d6121dfebf137,
+                0x0ac7fecd8b5e31ed,
+                0x000000b36e8a4df5,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xe20e477cfd0815a4,
+                0x3881f4b2fd6ce261,
+                0x266a7527edcfb11a,
+                0x4f1b9d204e51f2d8,
+                0x00000165285c3fca,
+            ])),
+            Felt::new(BigInteger320([
+                0x9a576f9c7ca0faeb,
+                0x41d74d351ec6c510,
+                0x16c77743d9e95d30,
+                0x3892f29f50b44fab,
+                0x000000d9b64be3a0,
+            ])),
+            Felt::new(BigInteger320([
+                0x8389a846945661d0,
+                0x729656b567414768,
+                0xcb0250d5fa8c299e,
+                0x535684810edc591d,
+                0x000002ee67b7fc5a,
+            ])),
+            Felt::new(BigInteger320([
+                0x5ad114467027546a,
+                0x405aa6392034084a,
+                0xd379803396ac3da2,
+                0x1df563e846872c2a,
+                0x000002c0dc7fe21c,
+            ])),
+            Felt::new(BigInteger320([
+                0xa6dd37602ae3a4c8,
+                0x08368262c2d8e3e5,
+                0x92776157ec1b4ace,
+                0xd6c4c7ac8ae4ea19,
+                0x000002e3144dae25,
+            ])),
+            Felt::new(BigInteger320([
+                0x95e3f80f9da92eab,
+                0x8375a48e1e2a5aef,
+                0x888562642cd08e00,
+                0x3b8016b3bebf6366,
+                0x0000023dce134532,
+            ])),
+            Felt::new(BigInteger320([
+                0x1569b3fcade42b70,
+                0x6268a239265a0889,
+                0x69d480217db6fb0d,
+                0x3878149402f4a08a,
+                0x0000036ec4b55d52,
+            ])),
+            Felt::new(BigInteger320([
+                0xc35f71f5cabe07e1,
+                0x49e2c3ca6685bfdc,
+                0x01c0ec289f9f2467,
+                0x03ce8973a3d4b1a6,
+                0x00000313c69135fd,
+            ])),
+            Felt::new(BigInteger320([
+                0xb4542f8eb84d705e,
+                0x1ab22b40cd10414d,
+                0x228edc085779801b,
+                0x3c485b7d04a22a4c,
+                0x00000125a46458ca,
+            ])),
+            Felt::new(BigInteger320([
+                0xebd59d17cd5f3f8f,
+                0x3be66d7e54244968,
+                0x5d9592e6ef4f0287,
+                0x7d2a60c076bb1c7c,
+                0x0000024ee92e5177,
+            ])),
+            Felt::new(BigInteger320([
+                0xee9fa26e142e7e76,
+                0xcf5985bac47b0244,
+                0xa01b59b3cad1bc05,
+                0x2115d5e3b02cb970,
+                0x0000001c8a52a5a8,
+            ])),
+            Felt::new(BigInteger320([
+                0xf9b5a45846424e5e,
+                0xbf04ea87776c4e43,
+                0x123771acb2f0c291,
+                0xa47cbd7d4bca4bc7,
+                0x00000298649f5dd0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xab18f4ecbd9cf45d,
+                0xa896169206807aa2,
+                0x12521ed98fa2c36b,
+                0x0b065da93ca3b009,
+                0x0000010d6a9d20e6,
+            ])),
+            Felt::new(BigInteger320([
+                0x364dce7120649e74,
+                0xe8e45bf54f227a75,
+                0x0e05d960fb89218d,
+                0x1558b34bd8395458,
+                0x000003575f2009d7,
+            ])),
+            Felt::new(BigInteger320([
+                0x7c0e7aac7ded85f8,
+                0x6a282db42c441c65,
+                0x8e639ddac2b300f1,
+                0x92dc3d2672e35641,
+                0x000001da0b6dae6f,
+            ])),
+            Felt::new(BigInteger320([
+                0xf1d37178d1039f20,
+                0x8019bc6f6b618831,
+                0x97e2680a7413e04d,
+                0x9c152caae8dbf1db,
+                0x00000027163107af,
+            ])),
+            Felt::new(BigInteger320([
+                0xa326a919ac57f150,
+                0x78c95ac04efcfa65,
+                0x54ac0172d83dac71,
+                0x545baae59fc5f4d8,
+                0x000001ca23bf5b71,
+            ])),
+            Felt::new(BigInteger320([
+                0x0fdd5f3f1bd8e0a7,
+                0x377e6052ff3a7141,
+                0x87bc93147969ddf4,
+                0x1523f33420928017,
+                0x0000000ddeaba843,
+            ])),
+            Felt::new(BigInteger320([
+                0x87915b823acf772c,
+                0xa54e391e018b92d0,
+                0x68e100037a64e31b,
+                0x0c9c5cc05c4323d1,
+                0x00000151c27b8403,
+            ])),
+            Felt::new(BigInteger320([
+                0xc8db9df62c8e0c57,
+                0xcff492864a4731b8,
+                0x7ade06f1c0015834,
+                0xca08eb4dba3bb86c,
+                0x0000039fadb0e478,
+            ])),
+            Felt::new(BigInteger320([
+                0xe0ccdc7785bbcc1a,
+                0x3e018d381d918134,
+                0x04114c8aea719fb8,
+                0xf02308ce83debd20,
+                0x000002265e212895,
+            ])),
+            Felt::new(BigInteger320([
+                0x36b37e375c7b4327,
+                0x7599d22d9082dc54,
+                0x27ed6bf3651bb5f6,
+                0x75d95e7660127adb,
+                0x0000025b4cbcc40e,
+            ])),
+            Felt::new(BigInteger320([
+                0x91b39b4f929857a7,
+                0x59781139f40c83cc,
+                0x2a75750ca52122dd,
+                0x8e41001488ce9b73,
+                0x00000073405c350a,
+            ])),
+            Felt::new(BigInteger320([
+                0x9f4a350b167ca4d8,
+                0x8f86144d9dbf7e1e,
+                0x43c41dd3d9a0e8f0,
+                0x401f0444f440287b,
+                0x0000004616f0b326,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xdb687beff753f1e3,
+                0x908fefa657dbc629,
+                0x92d9c6caa4fcb46d,
+                0x4bc6103548515f44,
+                0x000003ac32ea9ac2,
+            ])),
+            Felt::new(BigInteger320([
+                0x83a2cfd0d679f707,
+                0xd080e5853aa835ff,
+                0x7cade47d62ea38aa,
+                0x8ce5c55d5ceb35a0,
+                0x000002992c1bb464,
+            ])),
+            Felt::new(BigInteger320([
+                0x8b7b2e554ac9fcc9,
+                0x30d7b889da81ab41,
+                0x89602ce6bfd90e80,
+                0xe152fe68e40c4467,
+                0x0000001f95b139f6,
+            ])),
+            Felt::new(BigInteger320([
+                0x139f31081aa00f8d,
+                0x4f369231c502361e,
+                0x6e280e7333003b06,
+                0x378bc20cc39feaef,
+                0x0000025a8cdbec77,
+            ])),
+            Felt::new(BigInteger320([
+                0xb5b27dbc46cc748d,
+                0x40d30047521e3329,
+                0x209d30873971a02f,
+                0x0e2e70be93ae8c6b,
+                0x000003a0fb71840a,
+            ])),
+            Felt::new(BigInteger320([
+                0x0aaa5c454eff171b,
+                0xc68d8f2641bb67d0,
+                0x8a6cb01aac0eb6d0,
+                0xae050c548a29b734,
+                0x000000aa9202f089,
+            ])),
+            Felt::new(BigInteger320([
+                0x56d106234d75da87,
+                0xf01eea8d86112744,
+                0x2564bdfe3389f9a2,
+                0xd14d098de573d4d4,
+                0x0000033001d85fc5,
+            ])),
+            Felt::new(BigInteger320([
+                0xd8180573909f3bdd,
+                0xb78aaa252df9f87d,
+                0x36e1c49f68f9f131,
+                0xce89492362ffc0c8,
+                0x000000761a03410d,
+            ])),
+            Felt::new(BigInteger320([
+                0x0869309e268fad37,
+                0xc9c46592a619a301,
+                0x3953516180862421,
+                0x0c45f28eb41dac1d,
+                0x0000020d6b6fbf7b,
+            ])),
+            Felt::new(BigInteger320([
+                0x894b3a9e87431db5,
+                0x7806b72617905f41,
+                0xd7393ccc44c593ed,
+                0x9c6ec49307a05de0,
+                0x0000001ea6f02275,
+            ])),
+            Felt::new(BigInteger320([
+                0x847c1c43404f4506,
+                0xdd375a508bbc77eb,
+                0x6b3e89ff1cc5b624,
+                0x181365207755d94e,
+                0x0000025c235e2994,
+            ])),
+            Felt::new(BigInteger320([
+                0xc7edeb7b64c26ac2,
+                0xc2e5a4d0e4861ad5,
+                0xd384b63eb236f552,
+                0xd82bc72b1f10ce95,
+                0x00000148117e32fd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x911fa96c17639ceb,
+                0xfca4352e777a3c12,
+                0x1d175ddda03a1c2f,
+                0x03c0cfc9c0514933,
+                0x0000000955dff35a,
+            ])),
+            Felt::new(BigInteger320([
+                0xccee0f10fb073cd5,
+                0x90a5897aeee86f01,
+                0xd2523379632954ce,
+                0x468fa571112ca3ee,
+                0x0000029a5e753235,
+            ])),
+            Felt::new(BigInteger320([
+                0xa8284c0fb0a708d5,
+                0xa0b792668d3c0376,
+                0xf19da7f658da25c9,
+                0xc36b396e0bd9d328,
+                0x0000006ed3c989ce,
+            ])),
+            Felt::new(BigInteger320([
+                0xaacd150ebccbc007,
+                0x2833ba616ce2524a,
+                0xd76ef0112d83d528,
+                0xb6450d7aeaa34a10,
+                0x00000313a58d4a40,
+            ])),
+            Felt::new(BigInteger320([
+                0x129bd9c65559c6ef,
+                0xe54d2294b8892076,
+                0x25cd60692c00b6ed,
+                0x3648f2dce9ac5065,
+                0x00000167dbb00226,
+            ])),
+            Felt::new(BigInteger320([
+                0x6510c4ddeb42ee4f,
+                0x0ea91b0dc7dbf565,
+                0xb2618ebb488cdc67,
+                0xd69a2d715dcf3f54,
+                0x000002fb7d021fae,
+            ])),
+            Felt::new(BigInteger320([
+                0x5495dc484f812e78,
+                0x3f572a4ca48cd009,
+                0x65ae594d400edaea,
+                0x3b929af0dba89255,
+                0x0000008cbb8733d5,
+            ])),
+            Felt::new(BigInteger320([
+                0xc5d228cb0d8c7a22,
+                0x52668ad0e7e7b5e1,
+                0x3b4f294a9fd2ca25,
+                0xdb9c094a7a5d669c,
+                0x00000327c6db96ac,
+            ])),
+            Felt::new(BigInteger320([
+                0x5a295884bcdf9172,
+                0x47c2d87326c63fa9,
+                0xf433e2b055b8709f,
+                0x2387e6331591230a,
+                0x00000125941c64a6,
+            ])),
+            Felt::new(BigInteger320([
+                0x00b97246aaf75e5b,
+                0x31d6b8b677ed9a64,
+                0xf0e0fda8e1dc963d,
+                0xb326dfe5206d1e68,
+                0x0000031a07f3653a,
+            ])),
+            Felt::new(BigInteger320([
+                0x4fea5005c67fb8dc,
+                0x07e1725d00eb7fe0,
+                0xb6dc6d777d92f0d8,
+                0x29302a3b7ef7a36f,
+                0x000001dc8f01e2a0,
+            ])),
+            Felt::new(BigInteger320([
+                0x1c6c7566275ba5af,
+                0x43b6bb14063e1958,
+                0x433ab943b35f1c13,
+                0x27d9ef9c1650bb80,
+                0x0000010a76739cb8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x80f2d579b339a368,
+                0x2d314d86b558b9e7,
+                0xf967079f61c7d250,
+                0xb9054aa844c5c38c,
+                0x000003b2b8be4818,
+            ])),
+            Felt::new(BigInteger320([
+                0x3d1005cabbd0ffe5,
+                0xfe4d13fd1b7aff59,
+                0x1eff6fe39df35aed,
+                0xc310691abad81aba,
+                0x0000026649fc6a8f,
+            ])),
+            Felt::new(BigInteger320([
+                0x97feaf57ccf5040e,
+                0xb93eb342bfa34e52,
+                0xfa0e88ae34357463,
+                0xa2261089c8226540,
+                0x0000014ccda12042,
+            ])),
+            Felt::new(BigInteger320([
+                0xe886ad05ceb835a3,
+                0xd5ec1b1513235b23,
+                0x79a12bc649eeadef,
+                0xaba97d83a909b31b,
+                0x000003b762b0daef,
+            ])),
+            Felt::new(BigInteger320([
+                0x479d516ec9b6d558,
+                0xb4afa5078c16dc8f,
+                0xf1aa670e3084919b,
+                0x299bc07e8f6ef00e,
+                0x0000032afa0ee3cd,
+            ])),
+            Felt::new(BigInteger320([
+                0x9768d702764ce6f8,
+                0xba2931c87c027755,
+                0xc404c2ce674b0f2a,
+                0x0b623dc5c2f19a67,
+                0x0000017bd477b009,
+            ])),
+            Felt::new(BigInteger320([
+                0xc13763444854445f,
+                0xf429e3e78ae47909,
+                0x1832c5e4a0f67d08,
+                0x33e1df0a9f3e0e07,
+                0x000001d887c9e046,
+            ])),
+            Felt::new(BigInteger320([
+                0x2ab5ac7bbf10167d,
+                0xc81707f293cae054,
+                0xb092328fa5cc1b5b,
+                0x22c9f6b1adb3ff8e,
+                0x00000338e9e4abbc,
+            ])),
+            Felt::new(BigInteger320([
+                0x7aba3ba6ba41fb34,
+                0xc1db72cd1d303b84,
+                0x0a4e2f7df7af58db,
+                0x52b6798819d68ea3,
+                0x00000181cab6c08b,
+            ])),
+            Felt::new(BigInteger320([
+                0x87733f7c34a448c6,
+                0x3e5b423c4a21b986,
+                0xd100eb4b793dcf7c,
+                0xea6ab76f5cb29cf9,
+                0x00000123cdfebbf1,
+            ])),
+            Felt::new(BigInteger320([
+                0x5232fc39fdc22a43,
+                0x98e986bb5112a49d,
+                0x138505dfd6dc5dee,
+                0x583fecb21b892c73,
+                0x000001660b07d346,
+            ])),
+            Felt::new(BigInteger320([
+                0x93b4ef90dcfee4bc,
+                0x54415332837a56a5,
+                0x0d3b09c8c47745b4,
+                0x73e7599903149465,
+                0x00000247496db71f,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger320([
+                0xd63047d5ea73c346,
+                0x4a55660e67944d6f,
+                0x2c7404227de22ad9,
+                0xe5e32066434c5b6e,
+                0x0000008802d7772e,
+            ])),
+            Felt::new(BigInteger320([
+                0x22b5da7c97987e67,
+                0xb5f1b6519b551d00,
+                0x19573d19f1bb64be,
+                0x7e90f052d052804b,
+                0x000001500b5791ea,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xe3ab6f86af4b4644,
+                0xfd3daa15fc964413,
+                0x34c4471f64612165,
+                0x14ca41d4864a425f,
+                0x0000007519cc25be,
+            ])),
+            Felt::new(BigInteger320([
+                0x5257d48225f17ca5,
+                0x992660f1006ab497,
+                0x4f0ec4b31a922844,
+                0x4e05717deab65338,
+                0x0000019a89d55415,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xf81d86a026f640dc,
+                0x095eb4a0e7ffc4e4,
+                0xbcb2d8e01a38ba58,
+                0xfcfcd946c5c018b6,
+                0x0000023f47a5cce7,
+            ])),
+            Felt::new(BigInteger320([
+                0xd1d8deb4bc487c0d,
+                0xf697a4623d2173d9,
+                0xa4df84a0667f6105,
+                0x7a52b3988f928be8,
+                0x000002ae6bf3a74e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x9d0e2b9003a29975,
+                0xf640fcd4ee0c5817,
+                0x1ff71c25304f52f4,
+                0x673004fa6b5c94c1,
+                0x0000003fb0386f62,
+            ])),
+            Felt::new(BigInteger320([
+                0xf041f9669d7e92dd,
+                0x2d4931753041ee8e,
+                0x86c2bdb7b2d5c56b,
+                0x8c0bc8b2c6b3d2a8,
+                0x0000018af0761a0f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x98ec90792d74da83,
+                0x2d989fc413b6c6be,
+                0xb0f0b7a9c929b95b,
+                0xd8e7908929b06632,
+                0x0000033a46c23ef2,
+            ])),
+            Felt::new(BigInteger320([
+                0xc3fcc319d387d08d,
+                0xa4e100fdc4d4f431,
+                0xb4876e470048e11a,
+                0x22dd29160b1b68ca,
+                0x000002facaa1ba61,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x9484b5617a2712d8,
+                0xa18164ae16f84121,
+                0xe19f6ea01402ddff,
+                0xa21d785d0aa810c2,
+                0x0000003b532edd3c,
+            ])),
+            Felt::new(BigInteger320([
+                0xa18ce9804efbe2cf,
+                0x3e8101d42b1a4354,
+                0xd9dc653c085b8f49,
+                0x8808b2d325c6bf43,
+                0x0000035ef5b4dab8,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xe1a6de4802b8ac57,
+                0x0d8333c5029d47d2,
+                0x32b67859784d3af0,
+                0xb04d61fb1fdcdc91,
+                0x0000004417ee37d2,
+            ])),
+            Felt::new(BigInteger320([
+                0xd8b279a2a0c78bcc,
+                0xda2746cac2e1545c,
+                0x66c2fbc1773296b3,
+                0x0e55ef69784d8206,
+                0x000002aaf5cb7229,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x7939dc0de20e88c5,
+                0x66f46e0b184b8ae6,
+                0x22e8deff35d7871a,
+                0x37ba3140b0588e9a,
+                0x0000022a6997bcb8,
+            ])),
+            Felt::new(BigInteger320([
+                0xe2952a2de796e232,
+                0x65bb82d921023869,
+                0x511712ab4dbec681,
+                0xef04f56dcfed489c,
+                0x000001509442b2bc,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x3e172431a453b971,
+                0x874be61eecd6a604,
+                0x81b0dc18da1694e5,
+                0xef9e4400eb9f50f8,
+                0x000002cebb86d2cc,
+            ])),
+            Felt::new(BigInteger320([
+                0xe961c800422cdecf,
+                0x7b5484086620fe50,
+                0x8f564d13ac4f6181,
+                0x3102680189b3f91e,
+                0x000002b5581c536d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x0b42af93d657bfac,
+                0xa074f75e7be3cb41,
+                0xc77059fa3b36f401,
+                0xab43dbc9f4230354,
+                0x0000007fd735d696,
+            ])),
+            Felt::new(BigInteger320([
+                0xa98feabfcc428d2d,
+                0x34b77eed007d5e7f,
+                0xb32d096264002bea,
+                0x015da128e1e2408d,
+                0x000003a5926b7690,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger320([
+                0x98a85bc9e137e192,
+                0xf2a6381fee155515,
+                0x78cb7b270ce82127,
+                0xb541a63deb896c8e,
+                0x0000022872c43548,
+            ])),
+            Felt::new(BigInteger320([
+                0x5e91207843f0c0e6,
+                0x61ad1b54f9377d51,
+                0x0bb06462f8722489,
+                0x4d3f9f2e50aa6628,
+                0x00000266efa1c32c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x642fcff1ee264473,
+                0x96f511c5d81405af,
+                0xc61df119ce69cac2,
+                0xe0327f0509ce4a3a,
+                0x000000593e698add,
+            ])),
+            Felt::new(BigInteger320([
+                0x7fb8fa9b2631fdc3,
+                0x73655d2148778217,
+                0xd1f7cbee4121d660,
+                0x59c28fd9bc7efe30,
+                0x0000010ce26cc86a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xc715cf3495516b8b,
+                0x7e5474d1df55b279,
+                0x2e13b1b8026c1149,
+                0xe10ccb08c4c45f53,
+                0x0000028b2d7c84db,
+            ])),
+            Felt::new(BigInteger320([
+                0x10986a3de04ec8fe,
+                0x21b110c44ba9db07,
+                0xdecbab4d4df1aed6,
+                0x49c71b52f2d5dc8d,
+                0x000003444a1ebfa1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xc994e502a40e4e86,
+                0x7fd25e78aceedb6e,
+                0x3bf155450d4af250,
+                0xb8e3e1ec48cd99e6,
+                0x00000011cec2ddb2,
+            ])),
+            Felt::new(BigInteger320([
+                0x97d81bfff7aeea55,
+                0x45fc8c2f02628c23,
+                0x163306d378050ef3,
+                0xc3585301ffae1bb7,
+                0x000002405a8d8e09,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0xc37e756062fc8569,
+            0x2a714b5c6ed924b6,
+            0x0c4015b9c2b23c66,
+            0xae4fcbe8db37d761,
+            0x000002f0a54da11c,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xb46bcd2bc0fc7743,
+            0x0590a6f3d0d33f93,
+            0x620f70bff3e136e1,
+            0xac00727e92420c18,
+            0x0000004c6b2304e7,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x733d95c15abe9d4a,
+            0x8a1dbc8499a1290f,
+            0x9f06f13f73842f80,
+            0xac675f32affb5476,
+            0x000002103cdf226f,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xcc7dd6340972cbf2,
+            0x217b5b588f2b4abc,
+            0xf0e93c42922870ee,
+            0x0e70ae337cbdbf32,
+            0x00000242d20d520e,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x91f01827446d98b4,
+            0xa36af350c5c8a6de,
+            0xd3a8165c9692af1e,
+            0x75183cd3fe412001,
+            0x0000018b90fd2b67,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x4f61fcaacc59f6ed,
+            0x81d50ca306d94a45,
+            0xd4fec0020a1e0174,
+            0xfb1a4128bcc6ccf5,
+            0x000001e50fe56ce5,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xa53fbad98369486f,
+            0x22e2ae2b53f9d8d2,
+            0xf0335bbf4a9de06b,
+            0x64f4fcb2dc9ceb83,
+            0x0000007b5632334a,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x5aa7672f4641d237,
+            0x9dc23bf7b5a3eae5,
+            0xce4eb4d13b67f3ae,
+            0xe1ed75d5aee87b9f,
+            0x0000038a855bc4cd,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
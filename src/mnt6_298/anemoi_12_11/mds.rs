@@ -0,0 +1,266 @@
+use super::BigInteger320;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x8e033c2bff328d13,
+        0xd73909015a64dbc6,
+        0x86afe005a85db77a,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x511ab71bfeee116f,
+        0x72b9d00453faf34f,
+        0x4c8f6dd354ce5fd5,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0x58eefd67fea995ca,
+        0x12f14affbb33a004,
+        0x4780323da44ac69b,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x1c067857fe651a26,
+        0xae721202b4c9b78d,
+        0x0d5fc00b50bb6ef5,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0x1c067857fe651a26,
+        0xae721202b4c9b78d,
+        0x0d5fc00b50bb6ef5,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x8e033c2bff328d13,
+        0xd73909015a64dbc6,
+        0x86afe005a85db77a,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x511ab71bfeee116f,
+        0x72b9d00453faf34f,
+        0x4c8f6dd354ce5fd5,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0x58eefd67fea995ca,
+        0x12f14affbb33a004,
+        0x4780323da44ac69b,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x58eefd67fea995ca,
+        0x12f14affbb33a004,
+        0x4780323da44ac69b,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x1c067857fe651a26,
+        0xae721202b4c9b78d,
+        0x0d5fc00b50bb6ef5,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x8e033c2bff328d13,
+        0xd73909015a64dbc6,
+        0x86afe005a85db77a,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x511ab71bfeee116f,
+        0x72b9d00453faf34f,
+        0x4c8f6dd354ce5fd5,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0x511ab71bfeee116f,
+        0x72b9d00453faf34f,
+        0x4c8f6dd354ce5fd5,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0x58eefd67fea995ca,
+        0x12f14affbb33a004,
+        0x4780323da44ac69b,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x1c067857fe651a26,
+        0xae721202b4c9b78d,
+        0x0d5fc00b50bb6ef5,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x8e033c2bff328d13,
+        0xd73909015a64dbc6,
+        0x86afe005a85db77a,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x8e033c2bff328d13,
+        0xd73909015a64dbc6,
+        0x86afe005a85db77a,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x511ab71bfeee116f,
+        0x72b9d00453faf34f,
+        0x4c8f6dd354ce5fd5,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0x58eefd67fea995ca,
+        0x12f14affbb33a004,
+        0x4780323da44ac69b,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x1c067857fe651a26,
+        0xae721202b4c9b78d,
+        0x0d5fc00b50bb6ef5,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0x8e033c2bff328d13,
+        0xd73909015a64dbc6,
+        0x86afe005a85db77a,
+        0xd8e29d2b85c1a038,
+        0x000001866efac6d5,
+    ])),
+    Felt::new(BigInteger320([
+        0x511ab71bfeee116f,
+        0x72b9d00453faf34f,
+        0x4c8f6dd354ce5fd5,
+        0x01faddc3ea3cf1d0,
+        0x000003479137fa99,
+    ])),
+    Felt::new(BigInteger320([
+        0x58eefd67fea995ca,
+        0x12f14affbb33a004,
+        0x4780323da44ac69b,
+        0x88acf9bea707eed9,
+        0x0000014bbbb859e8,
+    ])),
+    Felt::new(BigInteger320([
+        0x1c067857fe651a26,
+        0xae721202b4c9b78d,
+        0x0d5fc00b50bb6ef5,
+        0xb1c53a570b834071,
+        0x0000030cddf58dab,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+];
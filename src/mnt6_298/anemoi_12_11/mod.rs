@@ -0,0 +1,2790 @@
+use super::{sbox, BigInteger320, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 456 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (38-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe716bf313a7f3797,
+                    0xa774f4276b3a31b7,
+                    0xcaaa5920cb4fde4d,
+                    0xf9307fb4807a4c50,
+                    0x0000025c690c7f2f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55c0c21045b18171,
+                    0xb1b36a953e99708d,
+                    0x81a0d92da40b2759,
+                    0xb7eebb0176d697a8,
+                    0x0000012e5c9a520c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ed88a4e25c20f5c,
+                    0x93c863111fbb2abd,
+                    0x1483456e17cdd899,
+                    0xebc01aa195dc0cb2,
+                    0x000001f3d48d52a7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3721def8fa651a21,
+                    0x43cf011ef81fe4e1,
+                    0xeae444187dc56714,
+                    0x3a4f0ed5ca02968c,
+                    0x0000012110552880,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c8a2f18c629453b,
+                    0x2785b575c2ff345e,
+                    0xbf806da7713eb4e3,
+                    0x763b200ee5c3396b,
+                    0x0000016113a3b876,
+                ])),
+                Felt::new(BigInteger320([
+                    0x168a56a7a465a851,
+                    0xf40c680b2e6b9c06,
+                    0xa2eed979cecaa1e0,
+                    0xb642c5a1405850fa,
+                    0x000000cb72a71cf1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x590e090422dd4985,
+                    0xe433a03c39d39293,
+                    0xa51a4fdf0f3ba66b,
+                    0x119f47abf5701d53,
+                    0x000001fff9edeec1,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe4259b7a9be258b,
+                    0x40f013f2885a9f62,
+                    0x1c94057ba5ac3f75,
+                    0x02847a5899affa55,
+                    0x0000003378d2af93,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24b12defaf1bd13e,
+                    0xbcf006e6884f08f5,
+                    0xf3a01ebb7d86a2be,
+                    0xe519e7ee9c8dad30,
+                    0x0000017fbe64d040,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60a1e89cc5b6f498,
+                    0x7c28edf1ac991acd,
+                    0xaecdcb176ca5f390,
+                    0x83c82795fdb1e106,
+                    0x0000014c1e48e57a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5388f2ae0176f3fa,
+                    0x6a5dce307ee66775,
+                    0xab21f61bbba165d1,
+                    0xba400e2743bb5659,
+                    0x000003564ac66d23,
+                ])),
+                Felt::new(BigInteger320([
+                    0x546bede7aee50bcf,
+                    0x0a4425b1d1f59f1f,
+                    0x38b834b25c6c2d37,
+                    0x785c40d8566e03eb,
+                    0x000002a0010b3965,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x37288d5dcc4f7905,
+                    0x169867c0545307df,
+                    0xa7bb5abd23287d14,
+                    0xfda18d09eb94c702,
+                    0x000000fee967581f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x873dba77739752f3,
+                    0x4f91a0200ba01d9a,
+                    0x95d186c909fa9a0d,
+                    0x60fe61772d64ff63,
+                    0x00000028aeb5d25a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73c45646767c0f2b,
+                    0xe34eff890518d70a,
+                    0x908f420f04c49b18,
+                    0xd927894cb67e1a33,
+                    0x00000119684006ce,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61975c279b58cf79,
+                    0xdbd500ad2ab2a1af,
+                    0x4f9f2aa284eacc04,
+                    0x34e3a4df93ba6503,
+                    0x000000fd7a696c95,
+                ])),
+                Felt::new(BigInteger320([
+                    0x023ea8ce24b9c1ab,
+                    0x0ae7dcd65371259c,
+                    0x4ada1ca9c6404788,
+                    0xd8e25aad49d9e3b7,
+                    0x00000334cb70ba64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6372782173486be,
+                    0xc79398bba2bf7f58,
+                    0x7e35372ba06f5a33,
+                    0xb8eb520d89728706,
+                    0x000002dfa0917f9d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21e1084ada92e549,
+                    0x82411b21acddef6f,
+                    0xe3fe5bf2331bd70f,
+                    0x9386129f8e500b7f,
+                    0x00000350afc92499,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf16ba5423b6b954,
+                    0xe668b5270566c652,
+                    0xabb52ac2e0a36c3b,
+                    0x649d5036225d3b38,
+                    0x000000f73c855e4b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35d6748ee4f7b7d1,
+                    0xb932f17ddde1ceb6,
+                    0x7869a3642ae7ab28,
+                    0x49fafc5c49e9113d,
+                    0x000000bfeb793738,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ce30f8e7c68a8ec,
+                    0x045b95cd43962ca3,
+                    0x3d573a60f60ee6eb,
+                    0x4e61d1f57114a3e3,
+                    0x000002dda661767e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43b4b0a3ddb99104,
+                    0xa668b5d8ea97cea5,
+                    0x25d517ecd84310d4,
+                    0x2ffbf41da66abbd1,
+                    0x000000086452aafb,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde38ad39d0e2ba17,
+                    0x2e77f3bf8a362a24,
+                    0x86efead82c5a8df0,
+                    0xd20da319da8ce92b,
+                    0x00000273325d0569,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb8ca10d8ea141c06,
+                    0x8c02c8c72255a53c,
+                    0x5e8c880b918d0872,
+                    0x5512aa8598014ff1,
+                    0x000000235543b0b9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x741d55d5bbf190dd,
+                    0x9acc751e49c190de,
+                    0x4d9284285f4f0908,
+                    0x8040b0046706b7a1,
+                    0x000002cc6d695756,
+                ])),
+                Felt::new(BigInteger320([
+                    0x137705a5cbb1e7c8,
+                    0x21a1aea8581c1e5c,
+                    0x5c4434a3c9fe01f8,
+                    0x8caf6c6a0c89534a,
+                    0x000003a611209acd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7134742cfe6f27f7,
+                    0xe5ff11c59ac7d045,
+                    0xb98ae2a4ad7a9ce4,
+                    0xbb80ed7981a8e98b,
+                    0x0000016effcdd764,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2aed668deafc5b79,
+                    0xef88a188f4e339ba,
+                    0x806bd6733041c2f8,
+                    0xd63a2cb621cee122,
+                    0x000001efeba8160f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cf8a414fd823a49,
+                    0xd7183f886778528d,
+                    0x4b89583c5d971215,
+                    0xba6a4ddfd7860d00,
+                    0x0000015508fbd756,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf093b4c0631daec2,
+                    0x008c9b74d122f3cf,
+                    0x8079bef1fa231e85,
+                    0xe7962281fce10576,
+                    0x0000008d472fe234,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3be6166b1e8ce9b,
+                    0xb18383e76a07f024,
+                    0x88eb3e3a8e9155cd,
+                    0x0c5504392afba858,
+                    0x000000496a0ac992,
+                ])),
+                Felt::new(BigInteger320([
+                    0x485e898166e23eee,
+                    0xf6816c5a02402f0b,
+                    0xfe2f247806826d16,
+                    0xb2fdb7a7e5d1337c,
+                    0x000001311d9d6eaa,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06f017de442f6361,
+                    0x372f934ef09c8ca8,
+                    0x0b852f55c987d18f,
+                    0xdab604ed96329c8c,
+                    0x000003b6dfbe7b5c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x777bd4b2c0be8d05,
+                    0x957381a6ecb6932c,
+                    0xab5b6c21ff911f70,
+                    0x73e54524d652ca24,
+                    0x0000031b8406b60a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54a107f0daa8f8e7,
+                    0xe375dd984f8a67d7,
+                    0x0f1cf1976fe1105d,
+                    0xb9413f4ef2aa22fa,
+                    0x00000154586b5b46,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x22637b4edcd2365b,
+                    0x64f8b21f9b53459a,
+                    0x40fb113ead6ed405,
+                    0x238693c2835a83e7,
+                    0x000002860088b50f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee9af9080e3ebc31,
+                    0x436337065805a374,
+                    0xe2983041cc623e60,
+                    0x0b54bd110dfc89f8,
+                    0x0000002c54c34c51,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ec3f3efa3cbdb1d,
+                    0xf1a9dd9f7e664922,
+                    0x2b1299219e06fc33,
+                    0x612afbe4ff9622a0,
+                    0x0000006a539840f5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7b50af828f05e35,
+                    0xe0f482a226c7e7a3,
+                    0xdd4c9ccd815441d4,
+                    0xbc3ebcbb3ed13be9,
+                    0x0000012730b09d83,
+                ])),
+                Felt::new(BigInteger320([
+                    0x523a180c7d7de429,
+                    0x90432317746c1cac,
+                    0x8e2223e9478801a3,
+                    0x28d59d3b53d81dd5,
+                    0x000003a60ddaf8b8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c5f6129a1f8213f,
+                    0xc02c084ef62d8a06,
+                    0xb4fa5166b64d1925,
+                    0x9d3901e5fd8d2cd9,
+                    0x0000027636452a45,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f9c854efd977d0b,
+                    0xbf0b2c2544760da0,
+                    0x4c6a145ffdb05010,
+                    0xe0f77297d33d3211,
+                    0x00000170c389a0d9,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3c85b715b8fe810,
+                    0xe90ebd24ff6c2c86,
+                    0x6a7bf92d9870f5ce,
+                    0xb743f03b8c3743e1,
+                    0x000000847865aefd,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa818542e3470b549,
+                    0x053eb8e16bf6c0f3,
+                    0x334775fcbfd82796,
+                    0x202afccc1200d44e,
+                    0x0000007b3e5e3563,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d0e42da21721d8d,
+                    0xbb216199078eb38e,
+                    0x0b333500dbc7b287,
+                    0x3a2b85c08736dfc1,
+                    0x000002516a987b54,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3de353b67defdcf,
+                    0x6f651dc8b355ba59,
+                    0x38043c72d94938fb,
+                    0x248f0a00f8fb3c16,
+                    0x0000018fa14b6472,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf06fa02484b831ac,
+                    0x3636027a7c8cef3e,
+                    0xd342c1a78749601a,
+                    0x357b687c744eb52a,
+                    0x000003b7be14ff4e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23e1fb8494502f20,
+                    0x1aeae1625b1c0a8c,
+                    0x336c1930a0a9b781,
+                    0xde3f7b8ef7f0db4f,
+                    0x0000006295e7dff8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cbec8765b8869c5,
+                    0x96c974fa05f77872,
+                    0x3439c57740aa05cf,
+                    0x7c057d865e3be3ee,
+                    0x0000002bfa66777c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e932cac7737ac8a,
+                    0x34722d543d21ffde,
+                    0x65169660a57fa26f,
+                    0x4a541c5624c35f17,
+                    0x0000013f20c7f854,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4996b4ab63ea0f9,
+                    0x884a802ebf301004,
+                    0x52b22e4e21f97b67,
+                    0x3ccb04d3fdd9ced3,
+                    0x00000260eb1dcc70,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc90b88599024a71c,
+                    0xc96163603e9a7737,
+                    0x2936e1306231925b,
+                    0x7bd44f53c43485b7,
+                    0x000000b9e76eaa63,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64353956d35a62a8,
+                    0xab5d0f0d4be63986,
+                    0x3e97a84e8ea587c0,
+                    0x8a42e4f3e2c1b1b3,
+                    0x000000a847d3ea3b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51d7773ea510fa7e,
+                    0x827164dd5242941f,
+                    0x56d880f3b8be255d,
+                    0xfee2c334c14926a0,
+                    0x00000124c06ae80a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7bcb6f83bd500e4,
+                    0xfea538b0303bee0e,
+                    0xc48ca78cddba7daf,
+                    0x509e5bf255709119,
+                    0x00000034d16d9337,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa40595dbc00d6674,
+                    0x167f45cbadd0412d,
+                    0x1fb1693c002ac8d4,
+                    0xbd2b0a5d8233cf6e,
+                    0x000001266a74ab74,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc538fda5b2dec3c3,
+                    0xd81e9610010ef388,
+                    0xb2cd7bc9b38ca424,
+                    0xf2be3bee260f8892,
+                    0x0000005542ef76a1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x677a937741aedd68,
+                    0x444593fea5ff0c60,
+                    0x6558b130cea5b936,
+                    0x6a97c2883dc9267e,
+                    0x000002ca78a1411f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedaab98f8201c3b5,
+                    0x20445531885383d3,
+                    0x5454b5eea4289b01,
+                    0x5a614066908f0bdc,
+                    0x00000306fcac40a1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfa4bf02259c0e41d,
+                    0x166637804a045654,
+                    0x680a2c1df3d34d63,
+                    0x269c4797bc9f3ff2,
+                    0x000002292ab49490,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d84ef8d1e51b4ad,
+                    0xa83fe1c265db5d64,
+                    0x888da1c5dfca4928,
+                    0xb142b88ad177ec04,
+                    0x0000035d45d7f7f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a5c415449b5c039,
+                    0xc44c4899f9445340,
+                    0x1f8dd61befe5db9a,
+                    0xd86b0b834df57c36,
+                    0x00000052065b8663,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf1bb2805afb6967,
+                    0x63b570495ae06c7a,
+                    0x6c86b08b040df867,
+                    0x46e658494ccb7f71,
+                    0x000002d72b7f4e30,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1bf485b2dd2b00a7,
+                    0x5bd8f430c380ee0e,
+                    0x38a1cc41b9acfed0,
+                    0xe248c65ad5e30173,
+                    0x0000033fb2d7916b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed6b9c3ab140ecb6,
+                    0x06ea71d8b809840f,
+                    0x0c297c161c5dc0cd,
+                    0x99dfebcb87cb1d42,
+                    0x000001027dabbaae,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedf47143d050075f,
+                    0x24dfb816bfd12c49,
+                    0xdfd4cef68649d6ae,
+                    0xdb5df09f57cce30d,
+                    0x0000007849b187e7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2468f6e691534e7c,
+                    0x7ba14ae190f23cf9,
+                    0xfdb7ec3ed944637d,
+                    0xbd02cf9d4978df27,
+                    0x0000015d6a3d405f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06d18d5b5ac81694,
+                    0x37efaaefe9504e10,
+                    0x8b9cac9d5bc7f54f,
+                    0xb93d40e3420790eb,
+                    0x00000169a48bec37,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe32f330e880cca25,
+                    0x9e97ec0f3666281b,
+                    0xe36f913383482ce5,
+                    0xb2e3791ec3b8317d,
+                    0x000002b00ba5687c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xacb0043f291b6d3a,
+                    0x66874d75bd61529d,
+                    0x1d3337e11740d890,
+                    0x7d8cd2b424c61452,
+                    0x000002a003b506c2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5d5c22fe342b54a,
+                    0xe9555f4c92787278,
+                    0xa70ac1405a052a6a,
+                    0xe9f8b5b72a5fb1b0,
+                    0x000001ab21b7154d,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc1b07fa54a01fc1c,
+                    0x817d5a2fe0f2bb29,
+                    0x64a7cceb1964f924,
+                    0x95e81b244ad96fd6,
+                    0x00000231c17b0b1c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67f6f4174e91d575,
+                    0x3cda093d40c1f059,
+                    0x2e13f9a226aa76e4,
+                    0xe92c22b3815f7448,
+                    0x00000197d27d064f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1046f1b1bf566881,
+                    0x9ae74a491f47ebb1,
+                    0x2ad2f427f3a3a629,
+                    0xf7b0aa351beb74a1,
+                    0x0000020d442f3db7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91fa6f2cdc3d44b0,
+                    0x29e3f2932c0201b1,
+                    0x9e49aa3384dc12fe,
+                    0x3d30627d8c23b517,
+                    0x00000163955a5a74,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7232f3daad30474f,
+                    0x0957e735d8eafb73,
+                    0x744c8a68edab631b,
+                    0xfae3e83353ead0f0,
+                    0x0000015c6b129944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ac693b5f7bfbac0,
+                    0x26e2daabcbdc6909,
+                    0xa44ed9fe3cc1120b,
+                    0x0511adf77ce9b2ea,
+                    0x0000001cc0021c7c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e1ce919eab0799d,
+                    0x15bec7a5e5997467,
+                    0xb7fd162ba947c8c8,
+                    0x245d7bab48e5f8f4,
+                    0x000001fe74b01195,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5162ba8e63f26ac9,
+                    0x143a4ac8d99a8728,
+                    0x3686e57b9f95f05b,
+                    0x852dac75703f7c86,
+                    0x00000221cfd03519,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2092f5aeeac99b36,
+                    0x4d118e42b53bf544,
+                    0xfad1dd5a561d85b5,
+                    0x23a9ec2c2c23d4fb,
+                    0x00000256b47b9eea,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6df5aa3bf57c3937,
+                    0xa72f3997d39979e1,
+                    0x12fa0ac91189cf1f,
+                    0x348dc22554449eaa,
+                    0x0000007a38ca61a7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x173491cba3bfa091,
+                    0x59bd3e52cd2661b7,
+                    0x9166d69de96b377d,
+                    0xa053359ca47eff96,
+                    0x000001aa45b2dc18,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e842586cf3ed6f6,
+                    0xef2a766c9d307a90,
+                    0xa35d278856f79d6d,
+                    0xb2f58c55c132188f,
+                    0x000000e1ae354c0c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x378add1337928bdc,
+                    0xf19b4f90a36a7b05,
+                    0xe558bd69214235d7,
+                    0x78c4700ff9c9b235,
+                    0x000002c069a17b46,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbbbb2281b437f5d5,
+                    0x6ab769f0008d1746,
+                    0x55905aacf1246ac4,
+                    0x52c8a2a1e6cf2b82,
+                    0x0000033c3f0e3be6,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed0767da9c0e5a98,
+                    0x3ae7aaf860ae6706,
+                    0x3e77a360862b268a,
+                    0x7143e90291c80303,
+                    0x000003451aac86a0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaecebdbea1a05262,
+                    0x5bf40e0db1e6193e,
+                    0x465e865640a69b4f,
+                    0xfc36bc81f983bff3,
+                    0x0000014e6489b2a3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34e1056e65633e63,
+                    0x0c1bea3d4b8a6a4d,
+                    0xb45b3fdf82287302,
+                    0x792b31195f43bc47,
+                    0x00000027c112a2cd,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc82eca25a6b5993,
+                    0x4a21b95e1e64d8aa,
+                    0xcd2c266ad346b51d,
+                    0xc8dbb02f1f5a0652,
+                    0x000001e5a9f8b38a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeacae72141695017,
+                    0xd9f381b13b39feeb,
+                    0xdd24fb25c27fce66,
+                    0x5e169311d5abbbea,
+                    0x000002078f32a9e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xafe4a3ce78b4abb4,
+                    0xac21b26267ffc78f,
+                    0x2c37ee41294e60ac,
+                    0x1cf538cd21f406a0,
+                    0x000001c0cc359714,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1dd19e449a7dd841,
+                    0x423b12d320935c59,
+                    0x955039c59058ea7b,
+                    0x0bbcc63ff0969baa,
+                    0x000002acd8ecdc43,
+                ])),
+                Felt::new(BigInteger320([
+                    0x882c08d63a8ca45e,
+                    0xceb2022dc4c34ad7,
+                    0x2b7fa45a6cbd51e3,
+                    0xb01ba46fa459f7d8,
+                    0x00000115f99a0ca2,
+                ])),
+                Felt::new(BigInteger320([
+                    0x382822836765bc48,
+                    0x6bf3df6409d953a2,
+                    0x3c9e40bf4fb79876,
+                    0x137f53d31f8dd690,
+                    0x0000029074857adf,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa97d3e66d5921e3,
+                    0xe3f4935d800ebe83,
+                    0x1cb8b1e5ee4a4c02,
+                    0x67aee9c9cdf60ca1,
+                    0x000001cebd9cc899,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2e76756e6d2e1593,
+                    0xb245ca52c54822a6,
+                    0x89e6ae9bdd1998c9,
+                    0x2ad62d2b320c9665,
+                    0x0000030393e5612c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x015ab98e8037379b,
+                    0xef067a5aafd33571,
+                    0xf3adaa2c25dda93f,
+                    0x33f93da06f9ca904,
+                    0x000000828e3d77b3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b13a42580e2d913,
+                    0x479112f59563807b,
+                    0xa9c82396bbcf8ad3,
+                    0x05b2c13916faa860,
+                    0x000001cbe16f83e5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa106f717676d13eb,
+                    0xa7c7f2c3aeb2f233,
+                    0xadf859a906daca74,
+                    0x1e6b4ba27b1102b3,
+                    0x00000186eec3000b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd18f45b3f4e3a098,
+                    0x8bf8383624dc28fa,
+                    0x04b5be11569a631b,
+                    0x1ada020fc791081b,
+                    0x0000007edccc0559,
+                ])),
+                Felt::new(BigInteger320([
+                    0x816bed3fe1aec08e,
+                    0x069618df409edc67,
+                    0x5e18f2612e646393,
+                    0x2794ffc9f27d0cf3,
+                    0x0000036cdf0b6cda,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2cdf9ad9a018ed9,
+                    0xe2752b745955ea3d,
+                    0xa5c48963821297da,
+                    0x1ef1f4ce5c2191f0,
+                    0x00000172e143c3ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd8295a1cd53f609,
+                    0xee769882b80f8aef,
+                    0xb081d44254d2332b,
+                    0xbdd69407c5225e60,
+                    0x000001abad8bae52,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda34c3f0fd0718e0,
+                    0xa8091eed35234948,
+                    0x46a52a5f28e4cc12,
+                    0x971c46314856792c,
+                    0x000001a1e8c69c0c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe801111e637359b7,
+                    0xc214ef93feaf5145,
+                    0x6cafca0b31f21ee3,
+                    0x00406f7c7135f768,
+                    0x000000cc2e77a3e4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5dab517c7f0ff288,
+                    0x32ae2d6f46bb42ab,
+                    0x174d0a48427eb9fa,
+                    0x6067f8343bb19f51,
+                    0x00000235562ca99f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb44528e20688379,
+                    0x575a0dad3b06b44e,
+                    0xce59a3e8c7bcade8,
+                    0x857836edc7e2c182,
+                    0x0000026e5882d792,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c64d1d49bac3a5,
+                    0x368b742ff73cd28c,
+                    0xd4b9aaf53a9a0529,
+                    0xaf129769cbff963d,
+                    0x0000000f85a0bb67,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc6cc6909ac44bb5,
+                    0x42d525ac2ed3f5b4,
+                    0x729e076fb4f2dc70,
+                    0x99fc7575c89d563f,
+                    0x000001074d7213b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee27256e89a8e08b,
+                    0xeaa2067be84f85c5,
+                    0x39374ea846443343,
+                    0xfc6493fa7d8155c1,
+                    0x00000363e6bc2d77,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc0603e0c0504e21,
+                    0x5c82a6a5ec647f6c,
+                    0x50a20a49b4120d16,
+                    0x3f364c494e0f9a0c,
+                    0x0000022454b1e901,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8d512a22f9c03d1,
+                    0x1c7ec3f17da290e3,
+                    0xd9b5b2a0a023d9fb,
+                    0x600a66f77694ba20,
+                    0x000001a1008d977f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa05d2d9df7fbb994,
+                    0xb0f4f825bacaeba3,
+                    0xa65716caf10b10b7,
+                    0x606001dec8260251,
+                    0x00000012d7fec91e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41589b29fd302ff1,
+                    0xdb752116f93b878c,
+                    0xc02e34a4b0f50637,
+                    0x6be0ab6021b3bbe9,
+                    0x0000026b6f998d65,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6242bf1180d57e5e,
+                    0x69254e8f1781a19a,
+                    0xc97a0f70a2bb79b5,
+                    0xe1b86b0362fb7aee,
+                    0x000000b037df2e4c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a8555b79ccab25f,
+                    0x6798bef9fd855e7d,
+                    0x86d76525b2417692,
+                    0xd1f8d562e7cf1111,
+                    0x0000016fcada79f8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7614e7d8c80136b5,
+                    0xbc44a6656e14e9ee,
+                    0x75ddab6eb14b7861,
+                    0x8317abeeafdc884c,
+                    0x00000008bf55bdee,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07d8536f4815be13,
+                    0x39d95628c879538b,
+                    0x8a21efdaeb92f224,
+                    0xb9b47f9299b45770,
+                    0x000001f06ef9b18a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bdd392cc66d8db7,
+                    0xde18af13070ac7a0,
+                    0x0d9e26fbb10273f5,
+                    0x5f11af7eaa3437fd,
+                    0x00000390e553797b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76f9285b962e2fb8,
+                    0x7c6d8674f11171c5,
+                    0x82a65d1d59af0f8c,
+                    0xaf255a79566d22c0,
+                    0x000003b253963a03,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ea5b0e6880f9ab1,
+                    0x6192a1d259880cb2,
+                    0xb17855fda79c3a4a,
+                    0xa9b90065b48aab4b,
+                    0x0000006d6811d82e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda0635409a1ccac5,
+                    0x427986a04933b972,
+                    0x02f07b0d03bba119,
+                    0x69f41bb8d61b1489,
+                    0x0000018309fea1ce,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8df412220212f2cf,
+                    0xc94296211240de01,
+                    0x708ee1a2b06adccd,
+                    0x73929ebf15d10af9,
+                    0x00000177cf57624b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x138340fc25fd0f0c,
+                    0x01e720340432acaf,
+                    0x9f3c0325bf825a36,
+                    0x8b69f172bf6dadb6,
+                    0x0000017267f1802b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a297905d60a4a53,
+                    0x544181dee5bbff92,
+                    0x05f906ba8ff65e22,
+                    0x84403b0b20e8b943,
+                    0x000002f3379b3847,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9803ff6d1047a33,
+                    0x8ac61c9bd4179a10,
+                    0x3e9e88a78cd3c159,
+                    0x0574a231b2da580a,
+                    0x000001741b9d3339,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c52b7c2ae9f5b5c,
+                    0xd808d0a5173c414a,
+                    0xd812b3a675f70641,
+                    0xc163bd8148f646dd,
+                    0x000000365aea4e18,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2d250f683c76f5a,
+                    0xbd951450358f1f1c,
+                    0x26e06f42aef9ff42,
+                    0xfa3250a6edf75e13,
+                    0x000002d078bfff6f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe22b96d86b333421,
+                    0x13a32435084d2ade,
+                    0xd099b40c4c4361f0,
+                    0x785bc2ed37be996d,
+                    0x000003b1af7ccbb0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x613db18df6084a03,
+                    0x63669bdf4c8616ca,
+                    0xd1331a5323e6bb91,
+                    0x04a4bcd415d44497,
+                    0x000003625b1672a4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecfc7d34772a76da,
+                    0x95fdada8ed17d198,
+                    0xba4df342421b68da,
+                    0x440af027ed4162b3,
+                    0x000003503e204238,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe7f32189999c75df,
+                    0xad705dd8a17e91be,
+                    0xf777a374dd48696f,
+                    0xe1cce58259b41cdd,
+                    0x0000026682c13f67,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18556f52c6ebeb3a,
+                    0xed79a381084c3170,
+                    0x680ab065390db6a3,
+                    0x9aa3cf4679a9eea9,
+                    0x0000032903b913a1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x638752e0e1b2f3d2,
+                    0x8dabcf5aba952663,
+                    0xf24c5eb86db50b4a,
+                    0x5a154f4e0c643fa4,
+                    0x000002ee355c6b9a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4e5e5d22f7f23d8,
+                    0xd8c8ee5b6a75e39f,
+                    0xc93ff76c0ce82b4d,
+                    0x63f5727d1e03523a,
+                    0x0000014ead5e78b2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4d9c96402f58051,
+                    0x044e935efeaf10ff,
+                    0xba9403d092be6434,
+                    0x253c638142aa39ef,
+                    0x00000367700aa0bf,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d947849be4edce9,
+                    0xb104fa26d99494c8,
+                    0x2c0da1ee21b3d9eb,
+                    0xca0ec38cde9de332,
+                    0x000002cba5e18ac4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c62e42b1fde4ecf,
+                    0x998541c31f970722,
+                    0x43d1b1758193b12c,
+                    0x058d9e6c68f5a75a,
+                    0x000002169917c463,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e13d71bea387e1f,
+                    0xcbe26d943302ea5e,
+                    0x73ce445961bb0c65,
+                    0x29e394e9bf0c4f3c,
+                    0x0000028159ea8077,
+                ])),
+                Felt::new(BigInteger320([
+                    0x149ebd39cb32d254,
+                    0x4ba5cc66f75cc7d9,
+                    0xef8be4ed56b6af68,
+                    0x28ddcc7ad3475213,
+                    0x00000390d17d82e9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9523e2b437adbaee,
+                    0x23ae5a2df771f6f0,
+                    0x2f94812866ceb055,
+                    0x5d0b1a846240ea5b,
+                    0x0000009caa6910f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59c6e62054924bd4,
+                    0x38c72b855783feae,
+                    0x9e89feec2291e43d,
+                    0x5795255b011a5ae4,
+                    0x0000006e2afb45cd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39db245b3611c204,
+                    0xdd2eabf9ed866961,
+                    0x7f0a81acc2728831,
+                    0x103d620c5a21c6cd,
+                    0x00000294d458b189,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9f5d8eb4d9f1d133,
+                    0x331db7b9f6def1db,
+                    0x77ff5c977d581595,
+                    0x6bacb759ee16a778,
+                    0x0000010f9002ada5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecf68a5eaa906875,
+                    0xad36c554e7a0663a,
+                    0x5e7ff1a418da7dcb,
+                    0x9bc91c608f3c673e,
+                    0x00000156b1269243,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedb51d9a0d8fafe9,
+                    0x0119f77eaa3d6f08,
+                    0x215acad20bf9886f,
+                    0xac9bc7c18c1c8ab6,
+                    0x00000126aa559f20,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4173dbbd676cf3b3,
+                    0xed2b9f10311e8f7b,
+                    0x009bc8977935658e,
+                    0xa70cdf53f8de3fbf,
+                    0x00000198035687b8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb08b7d4bac8c7ded,
+                    0xdec93ce6fc60abe9,
+                    0x7e0ad52de3628484,
+                    0xdf4fe77daed312e1,
+                    0x0000024772380dd6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49b24af1fa53220b,
+                    0x1d24bdf5e852ca04,
+                    0xcc6585ea4245e22d,
+                    0x84711dddb484342e,
+                    0x000000770ba19d36,
+                ])),
+                Felt::new(BigInteger320([
+                    0x985598df54fed807,
+                    0x1e7cb59384a82f8a,
+                    0x577369b03b594f07,
+                    0xf21c83cdeaed4b6c,
+                    0x000002d529fe33fd,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb31f039aaf6aaaf3,
+                    0xc51fde9324388b69,
+                    0x9a69e5c8c2c4aaf1,
+                    0x30214d8dd24e531a,
+                    0x00000006850a99a8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36c622a78da13d42,
+                    0x4eb8da094e5fe8e3,
+                    0x075d15b82730dbb4,
+                    0xc056d0cdf9b4ddad,
+                    0x000000409ca3eb2d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe74d39972c834cbc,
+                    0x8a188f6150f7a563,
+                    0x58a1da36744d23f7,
+                    0x2b1a8023de6d3a47,
+                    0x000002b6d811a889,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4a39a7c8de3f054,
+                    0xb0c85b425eb4a9d7,
+                    0xd2fe3c8e119693ca,
+                    0xee3345e3007e4869,
+                    0x000003990047643d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5ab50da2d646f3f,
+                    0xf13fc56f6e76be77,
+                    0x63d932b0d4170013,
+                    0x6dfad289a237057f,
+                    0x0000002ee690f53d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc01f1994b2a0f269,
+                    0xb963f0b9efce9984,
+                    0xad016fb01e46aac6,
+                    0xdd7d2f56bd048406,
+                    0x0000028f9b848150,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1acad1160fae1d18,
+                    0x6d958d621d05629d,
+                    0x6d759488b0181759,
+                    0x3f9781812960282b,
+                    0x0000007bb44ee049,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5446aaa9f124963b,
+                    0x45ba9a4a1573cab5,
+                    0xea2444c3e00d297b,
+                    0xa8a0f93feeeee746,
+                    0x000002b112d87de4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36fab042cd2aa4bf,
+                    0x240f3115a40bce0f,
+                    0xf2b801aafd30304a,
+                    0xa6dbf42338e10d93,
+                    0x0000021968c35e19,
+                ])),
+                Felt::new(BigInteger320([
+                    0x752d1e892f0c7704,
+                    0x95a0a7f1f2f9d81d,
+                    0xfaa377c390e88894,
+                    0xcd7dc3a09529c76b,
+                    0x000002c87822defa,
+                ])),
+                Felt::new(BigInteger320([
+                    0x409cdd0fd3e18f75,
+                    0xb7aef66305bb1ec4,
+                    0x22c3187018802fcb,
+                    0xec97b7ffc6b62a68,
+                    0x0000007ee0ce2f0f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9b0fdd36926099e,
+                    0x9b7d89fc6012c10f,
+                    0xe50771628019932e,
+                    0x2bb9e71ec83d7f5a,
+                    0x000003a89de558a0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x685db5a4eaf0aab5,
+                    0xb1ff0fc2899d56d3,
+                    0xf72956b8677e8400,
+                    0xeec0992312c78cd4,
+                    0x000000b91288034b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde47f39fbff4692d,
+                    0xb85ca8d202da65c6,
+                    0x0eb7bea423bdf863,
+                    0xc2f44f1cdd6411c7,
+                    0x0000000711079c49,
+                ])),
+                Felt::new(BigInteger320([
+                    0xace7295f83085615,
+                    0xce3e32a20950cbed,
+                    0x5a5046d0445e8125,
+                    0xe737adc57df76f7b,
+                    0x000001b859445859,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8b0eb8461ac15d7,
+                    0x7c03bccd8255f8f4,
+                    0xa3802ec61e624c3f,
+                    0xbcf56f7fcce4336d,
+                    0x00000181d2a27d49,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c176bb3cc2ad9d3,
+                    0xef0d46ebef532d04,
+                    0xb7f95af2cf0ec59c,
+                    0x19767f86a95cedfd,
+                    0x0000034c06a8dc69,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3889c8e2b58b19ee,
+                    0xc5f540e49ea3305f,
+                    0x9bc8a24bfa268fa5,
+                    0xcd0f62f5723732d9,
+                    0x000000d3bb707bc0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcabcb37818e29fb6,
+                    0xe65d05c17b775d78,
+                    0x0da3715b5212edce,
+                    0x699a376e7885dd03,
+                    0x000002abf1f51b5d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x782af00b56a6d1b8,
+                    0x84853140252f5d86,
+                    0x1135876bc2fab762,
+                    0xf03a0986ab33ef96,
+                    0x0000002ca5d044e2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6e1ee0d8f69f4e1,
+                    0x67297fc807f88222,
+                    0xdec4dc95d3e80aa7,
+                    0xc0327364c6e7356c,
+                    0x000001cec1fb04ff,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc631c981524993ee,
+                    0x0dbc3de3c5713b0b,
+                    0x7f6e18c900b907e6,
+                    0x75fc1c63f503a83b,
+                    0x00000385398a4614,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9738d662cd38e94,
+                    0x6c21d4f3347da20a,
+                    0xdd1e62dc020d0355,
+                    0xd5f7b898504ff038,
+                    0x000003397ebb174e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0bb989cc532e1e2b,
+                    0x9902f617e0a2ea2f,
+                    0x08c6ee58d95b92c1,
+                    0x22eed1c7c2513246,
+                    0x00000392a8e31bc0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6d81532f83e3a67,
+                    0xd77a86c7fd1a415f,
+                    0x4a3cd81e63cc1c3d,
+                    0x0adcdd70b2c67c77,
+                    0x000003521b75d7c4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f68d4fe042e592e,
+                    0xb5f6e91909f0ab01,
+                    0x6b8bddd76b2532ef,
+                    0x27b70e1be2bc95d8,
+                    0x000003857ef19b92,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6196e20c8dc044cd,
+                    0x2d94c3cf21833cbe,
+                    0xb1c65cc8912336d7,
+                    0x0d63a8a56447524b,
+                    0x00000238e85c0ddd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00a4152ba096ce98,
+                    0xfd3741df355a8eab,
+                    0x26b704120d5887d8,
+                    0x0185f91ce110dcf4,
+                    0x000001bd850b161d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1581da0cde719067,
+                    0xf30f2dacee546a28,
+                    0xc1211f2a7e769405,
+                    0xe744e0d3376c9bb1,
+                    0x000002d83cc42f51,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x020aa9f8d93bc56f,
+                    0x0260dfda270052ee,
+                    0x0b6c63bf7d7b9725,
+                    0x17865237c26b4053,
+                    0x000000371632ab96,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4349e11fa9b13d76,
+                    0xcab7ef8a29c12510,
+                    0xa447b286f174eeb7,
+                    0x980e64c846ae612c,
+                    0x000001f4c2679dd7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x484558b514fbe5b5,
+                    0xefd9a364a7d0209a,
+                    0xc1ad4c10d582a165,
+                    0x339a4a51ae6349de,
+                    0x000002f36ead1442,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7d18576c372048c,
+                    0xd8ad057c427fce51,
+                    0x0acc6a0a50a5b54b,
+                    0x0787c158674e20c5,
+                    0x00000396a9dc4258,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9837e86158a7fc2,
+                    0x285065c1b427fbc4,
+                    0x0e06fed7adc3c8d4,
+                    0x066835190a1ff617,
+                    0x0000034c16a92832,
+                ])),
+                Felt::new(BigInteger320([
+                    0x258c87f07c3140db,
+                    0x34c028da33bd4bc0,
+                    0xa4f4b58486e002e5,
+                    0x9acce0932c218ca6,
+                    0x000003911748aee4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35a755a9849d84dd,
+                    0xe2a4df17243f82ec,
+                    0xcc77de816384ae69,
+                    0x755484a0c269a3c1,
+                    0x000001a748ec7ed4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f23de669863360a,
+                    0x01418885d8617d8f,
+                    0xa0409ff48b873453,
+                    0x34a25eb9846ba03f,
+                    0x0000034aba46b073,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16ecc3113e385a45,
+                    0x30e11a587916b8b6,
+                    0x74813874814f26c7,
+                    0xd179b56669126cfc,
+                    0x0000017e4c6b3a2e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25bb5e2eebb3131d,
+                    0xf1f7f91b201afd88,
+                    0xbcf16270381808f8,
+                    0x8e975e59336015be,
+                    0x00000076a3c42fc7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81aa383136c11c19,
+                    0xff736a631ca46c7d,
+                    0x055fc3d8266b21f9,
+                    0xf5c10152bcf419ec,
+                    0x0000009f135d2d27,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2adb69f1526b0ebc,
+                    0xb0f6060478e65044,
+                    0xd996718300aa619e,
+                    0xc5fea31925bbded5,
+                    0x000002b0e1e97bb8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3f6cde0a0fe22d82,
+                    0x4c06df94df3b004e,
+                    0x4c9193d10db04fc6,
+                    0x73e457440177cd37,
+                    0x000002e3cbb0817e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ae169fb87fda184,
+                    0xe6fdf03282c82422,
+                    0xe3aa6043fdee957b,
+                    0xdd059c4a3c87e39d,
+                    0x0000016f0ca98318,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf72ccf5c8be900a,
+                    0x8f5e81e4240714ed,
+                    0x99bb987b563c4d9b,
+                    0xa65e8c90068f2f7a,
+                    0x000002bea650160b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd744a122bbf3b1ba,
+                    0xcc2b8716978a7c6e,
+                    0xaa8d28525b739529,
+                    0x143552f8c31f5c55,
+                    0x0000032e4bb56d59,
+                ])),
+                Felt::new(BigInteger320([
+                    0x768990fd6326383e,
+                    0x6ec7aa528f0da502,
+                    0xc4aa68135a1a9cbe,
+                    0xe41ee1bfc8153b0b,
+                    0x0000005a1bee787f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ca9556792bb115b,
+                    0x4ccf27bf5523046b,
+                    0x74abdad115733ada,
+                    0x1587be17ed7bff3b,
+                    0x000002a964a5ad09,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe15ad97f67c3c880,
+                    0x5391fcb38b9c9abd,
+                    0x5c18f7db4ccb6d5d,
+                    0xb0dd2c6116c74877,
+                    0x000000f4b3cf1ced,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfec896d5a87f1059,
+                    0x87d9a5c1473400b9,
+                    0xbe3d8d3c7a3a9830,
+                    0x319315ac0c7bdb42,
+                    0x000003b445d0940a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2079bbb441c1937d,
+                    0xed89f707b1fa65bf,
+                    0x9863dedf867e6b86,
+                    0x14de0ae2f010bb2e,
+                    0x00000050ba3bdaec,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd25f3ded193fda7,
+                    0xd2318bf543d2e78f,
+                    0xa9ad451ffa538e84,
+                    0xdfaa58dee4ed3037,
+                    0x0000012f598acca2,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5317610f8528e82b,
+                    0x10d987d2ab9f4fff,
+                    0xd89d3d9a91ad5ab0,
+                    0xed5d2a3ebc8292c6,
+                    0x000001d11ea478b9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x954f901f115f2425,
+                    0x4fd3df545ad27905,
+                    0xd9bbd57ef1cc340b,
+                    0xa4860230e9f85819,
+                    0x0000009980992b45,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x19056f8a28e0b5cf,
+                    0x548ba888e7170f49,
+                    0x31b8a4c73147bb0a,
+                    0x1371721859562e19,
+                    0x000000f15eaaba83,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf80ee194f73614e,
+                    0x16f003466f60e15f,
+                    0x24fa3e31f61d5a7c,
+                    0x8388d0add616a167,
+                    0x000000735b491a61,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcdd419f0547740ee,
+                    0x2e465faf7f22e462,
+                    0x0b332c0325be9d95,
+                    0xb5fd4e29574d8aad,
+                    0x0000028348db912e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ca3ab6030bbe9d7,
+                    0xe8df8ce9488688e6,
+                    0x81938041b75402d9,
+                    0xb2b30cea69cc5a7c,
+                    0x0000020980080de2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc09365740adb0a31,
+                    0xa31f6dc39a4f56e4,
+                    0xe52dff432f1e98ee,
+                    0x25d049b0e5b63b5d,
+                    0x0000025899788732,
+                ])),
+                Felt::new(BigInteger320([
+                    0x690e0c1e49847e8c,
+                    0x5567ae3fe52b4ad4,
+                    0xfb61dd7666856cfb,
+                    0x3c0f4c8cd4beec6a,
+                    0x0000035e919f0f94,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf716482fa40723f4,
+                    0x2dfa5ce677e9ed05,
+                    0x30a3860f0d9c9c68,
+                    0xee3168a4e3293f6f,
+                    0x000003a87d56ad33,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb079e75db455b1b3,
+                    0x4940a0a233427130,
+                    0xcae5cb8ec8750a18,
+                    0x0a0bbf56112aee94,
+                    0x0000018ae8e55e84,
+                ])),
+                Felt::new(BigInteger320([
+                    0x492fdfccbcd41d75,
+                    0xd6be960be3b355bd,
+                    0xc05c260646e8a95b,
+                    0xa54232a83a3e7509,
+                    0x0000017ed0ce7201,
+                ])),
+                Felt::new(BigInteger320([
+                    0xafaaf9b1d1664efb,
+                    0xe8c073dcb9a6e0b6,
+                    0x08c22c56c27bd1ae,
+                    0xa24b2eec2ce73ef6,
+                    0x000000c6a5dcfcf9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26e1d6ef2f4b2b62,
+                    0x584e18289c58b343,
+                    0xb96c91f8036b235a,
+                    0x90cccaf513855954,
+                    0x000003a8a5a7ddae,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0816eb0c560e866a,
+                    0x3d431dd95c228052,
+                    0xbe443eca0293bb07,
+                    0x381196ee638d6ea2,
+                    0x000000cf32943e1a,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa878c0fbfaa65727,
+                    0x507bdff75a71153d,
+                    0x5311ff933436d8d7,
+                    0x804dc25cf46f66d6,
+                    0x00000171f724932e,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6953a48187965a0b,
+                    0xc21a6fdd00f80efe,
+                    0xf10e9cbb12d6db7d,
+                    0xafe7e0a61487f56d,
+                    0x0000003f083c617d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a4ec37e9fe37c8e,
+                    0xd1aac99bfb634fa1,
+                    0x86387bc02bb2abdd,
+                    0x08a825d2ee3af31b,
+                    0x000002a5fa2b2925,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae9c68eaa50a2e46,
+                    0x2a59b38d43b5c807,
+                    0x9b10eb16128bd973,
+                    0xf60e3858773e894c,
+                    0x000002595b629c52,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c089e5010ea4646,
+                    0x74cfd433d7557946,
+                    0xa2dc62aeaf2e0506,
+                    0x0a925e8ca44fd632,
+                    0x000000ae7dd3bf29,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a9ae7cd8f52db1d,
+                    0xffa8b303e12e80b2,
+                    0x4a535f5e898cdf11,
+                    0xee78bf5b667ebe94,
+                    0x0000006e940577d9,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf73684841aa0a246,
+                    0x5c95cc278bfa28f8,
+                    0x852581a404cb9d21,
+                    0xe1d6ee08388947c8,
+                    0x000001e25888d430,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0155810db14cc14,
+                    0x1191f8206ceeb529,
+                    0x0a44161074378c74,
+                    0xa5287bae890393b8,
+                    0x000003577a6ac50b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x503b109ddedc51c4,
+                    0x0857400ee55ecc13,
+                    0x2f9361ca4f4a7d17,
+                    0xd881d0ca511f2305,
+                    0x000002a439dc6423,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e3904de556cb12f,
+                    0x52190a31bd38610f,
+                    0xee8f65e3d7c52924,
+                    0x7260fe602098eba9,
+                    0x00000124a2611990,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf36026104729921e,
+                    0xfde2f9f2c6c83abf,
+                    0x87ff823738ed4265,
+                    0xa428c9fd864b8a2f,
+                    0x00000005dab44394,
+                ])),
+                Felt::new(BigInteger320([
+                    0x522e2f9ef9105ec1,
+                    0xa04f770aaeb43d89,
+                    0x67c9751f02005985,
+                    0x06913815b917493b,
+                    0x000001e09ee2fa88,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c182f393528bac6,
+                    0x39476873b5437c96,
+                    0x89140d4badbdf3b9,
+                    0x375c6c10b64e0742,
+                    0x000003a7901f425d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xebda98fcacef14f7,
+                    0xed0f73189959c439,
+                    0x3722ae24863c7b2a,
+                    0x8bd345bb439882de,
+                    0x000002c3134a50e3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5be0e31077b1f955,
+                    0x93e5d6b3ee5cfea2,
+                    0xe8d3f7cb59f653d6,
+                    0x8aabab8bda5ff47a,
+                    0x000001113dbe9b16,
+                ])),
+                Felt::new(BigInteger320([
+                    0x747eec137634486f,
+                    0x5f924073739794d6,
+                    0xf2256e03c2478804,
+                    0x2822e90f6dfad56e,
+                    0x000000ebecc659dd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58bc07f437a1aa97,
+                    0xb704337b53fa546c,
+                    0x876af043bce512c2,
+                    0x2c39b29899869fd0,
+                    0x00000189325cac41,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfff3f529de401dab,
+                    0x93f4d7b3c819eb84,
+                    0x2ff2b55a7275776d,
+                    0x2d3a3893ab7ba6dc,
+                    0x00000191222870af,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6847fa07963f33da,
+                    0x3aa799303e7b8ad9,
+                    0xa5e0887120291a13,
+                    0x443a188722631255,
+                    0x000001591c263838,
+                ])),
+                Felt::new(BigInteger320([
+                    0x945034982e68e843,
+                    0x52a005d43013feea,
+                    0x4abdd9e7255d2b6b,
+                    0xa1adbbe819f0119a,
+                    0x000001c7b9fc35b9,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc63824e280e985c,
+                    0x2022f9b6208407ce,
+                    0xbf48958bee769b42,
+                    0xbfa7d1228cc8fb23,
+                    0x000000f7dc648c15,
+                ])),
+                Felt::new(BigInteger320([
+                    0x360cec24043aec6c,
+                    0x773ccfa108310e5b,
+                    0x8e812a50c49dc4a8,
+                    0xc7c3ebc3b8a3f0aa,
+                    0x000001a0bc65e3f8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa94e7125e703c9b0,
+                    0x95c94869fdf19d20,
+                    0xc2261920cdf6f12d,
+                    0xdde4a6d0cb0678af,
+                    0x000002f6a3b1c125,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcdd43c70cf26b9b0,
+                    0x9246f93e33b16f40,
+                    0xc966c40d96144a83,
+                    0x9e4f700cccdd6d06,
+                    0x000000b6083f8a66,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3e4453a5172d049,
+                    0xe510bffc877d378a,
+                    0x059e4cf63505940a,
+                    0xf1cae12cd6ce46f5,
+                    0x00000147e056e293,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb7d6cceeb86f3d62,
+                    0xdc907c51e351a084,
+                    0x80cb78ae9941f6bc,
+                    0xbd12aeacd6a3a81f,
+                    0x0000035821fe6e99,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66edc8f488520436,
+                    0xcb9217b14298e2ee,
+                    0xf8e9067b7c5381e6,
+                    0x742b66a756c54de0,
+                    0x00000250fd0cc1a8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b6719c3b142fc91,
+                    0x41f39525710d39bb,
+                    0xbc4c9cc41501352c,
+                    0x48603876c4591439,
+                    0x000000bfc76d92e4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x782cffc8c2b0a519,
+                    0x91c624239af512b0,
+                    0xb8acbf5707ff3fd6,
+                    0x8a816eca9609c3d6,
+                    0x000002cf443d028b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c3e1eda1106c4fa,
+                    0x8c7a813f1a8a6dea,
+                    0x9da6ad7918393999,
+                    0x78aa9aa741fcef12,
+                    0x000001b9bd7810eb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e904d1e426d0e72,
+                    0x81559a0858c2b6c9,
+                    0x84d1b34727b6cd34,
+                    0x2b3f407d44eed2d6,
+                    0x000001ce25539535,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36884c7373552352,
+                    0xa71ef74998fd2ac3,
+                    0x7c4941aed5e39003,
+                    0x32cbf382164dc0c2,
+                    0x0000000f08bf15b5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x787044b05ed54090,
+                    0xfcc75fe7b0caaa4f,
+                    0xd05ee5bce47abf54,
+                    0xa035251549208b04,
+                    0x000000525ee4fb2c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0dd959f08b1784c,
+                    0x315573121959e376,
+                    0xbd9883c8c8f129b7,
+                    0xac45c4bda0791f58,
+                    0x000000f8d6ace183,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7258410ec0f4fdc1,
+                    0xff3d1cda214e3df5,
+                    0x8b8403d6b34fb519,
+                    0x0ff7da391e90e7f7,
+                    0x00000374931fe202,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1cc8345ab35df203,
+                    0xfad7de6566f9df76,
+                    0x69d7bb879d2733f8,
+                    0xd68cc94354d918b2,
+                    0x0000036c474740c6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08fd7ffee893e2a5,
+                    0x2a6bc66eb39cf914,
+                    0x56fa686d443eedfb,
+                    0x0e8ac2a3dc566524,
+                    0x0000038553d7be4c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xba1409142484ca10,
+                    0x50d800efe7921731,
+                    0x94e047bdf0de2372,
+                    0x02f69e6c71dfd39e,
+                    0x000003744d472ab0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59e9f028e38a14ac,
+                    0x3470b56f7a040a1b,
+                    0xb896a980a7ca59ae,
+                    0xf23d6051e2633038,
+                    0x00000320e60f9479,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe4e0cf0e4224b8d7,
+                    0x148635bb4941f729,
+                    0xe4eb0356689c7764,
+                    0xc52092442432660b,
+                    0x0000026411f576c6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x551045de80dcf21b,
+                    0x760356e911267411,
+                    0x71a7eccce5dd4262,
+                    0xcd8bdf799af76882,
+                    0x000001132664bee7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5831b8ddc1cb9e86,
+                    0x8fa861d16b3be0bd,
+                    0x216c683cc9f25e3b,
+                    0x3803bf1de80db321,
+                    0x000003060a1c7ab3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29c6b4f6f8e96ab9,
+                    0xcaa76e6b69373c16,
+                    0x4a179f8b62485382,
+                    0x11036a7dea168c00,
+                    0x0000031b849a4f28,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c070b592c81a0dc,
+                    0xf0393020c76c2d14,
+                    0x145e3296bf98efd9,
+                    0x13a95acb8911426a,
+                    0x00000221a0a6ee92,
+                ])),
+                Felt::new(BigInteger320([
+                    0x284d11444141d91f,
+                    0x8da6b4f2503b25a9,
+                    0xaf841d766795ecc5,
+                    0xe55fe0d31c409dfa,
+                    0x0000017bd7165c32,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97f1d2369b6d9aa3,
+                    0xee48f644fedc977c,
+                    0xa4fbc013b1952659,
+                    0xa148e2ba43236839,
+                    0x00000012495779cc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44f57c0935ddc793,
+                    0x234929e4eef771e6,
+                    0x22186e7e6f3a4b34,
+                    0x4a02495e3fb563f6,
+                    0x000001c84ff18525,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41711ea97c19a6eb,
+                    0x326f2fb45678c187,
+                    0x9d43133e804e75c4,
+                    0x39d0f8def278cfa2,
+                    0x000000126c31234e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fdce57d86bdfb07,
+                    0x872472e0ba01ca2d,
+                    0x9af0e2c42fcd61e8,
+                    0xb64d3139a535f81a,
+                    0x000000a065e9c171,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x267dc5c6420bc759,
+                    0x3c696f93ac55116b,
+                    0x82bbe0d2c0dbc22b,
+                    0x90402fa9a2e93118,
+                    0x000001e43c8d43dc,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdefdc5e14e248f7b,
+                    0x16c32483b2555866,
+                    0x1f4a5e5730df077c,
+                    0xd6b6b36f7cc0e90c,
+                    0x000000eb265937e7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ac23a5e786ddc82,
+                    0xf056adbb72784021,
+                    0x4c79ab17ac4b245e,
+                    0xd6a96d10bd814415,
+                    0x0000034c7172ee91,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad6f127b733f5bd5,
+                    0x693952f52c9f603d,
+                    0x890973accc48cba5,
+                    0xa3066d7fd3466234,
+                    0x000001db4cad1144,
+                ])),
+                Felt::new(BigInteger320([
+                    0x736f43ba98ed52b0,
+                    0x0ec809345fa6215f,
+                    0x5f8d31b2abd4cb4f,
+                    0x4352eb6fdbabfc8b,
+                    0x000001a18785ead9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x657622715c382d9d,
+                    0x4ff48890e532e4cb,
+                    0xd6974d89ba3787f8,
+                    0x1fbd97acb2011795,
+                    0x000001776454b939,
+                ])),
+                Felt::new(BigInteger320([
+                    0x722d60243912a52a,
+                    0xd035e0a110bbd9be,
+                    0x5dc674308e7124f0,
+                    0x284d0dafc9c1e228,
+                    0x0000012572aa42ff,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf64101f8e21a26c6,
+                    0x2cf7bd8ba3f36a02,
+                    0x5261450015cd4002,
+                    0x36e501a489ff9902,
+                    0x000002b5e2644fa4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3865332feee3ae5,
+                    0x7a499752bdb087e9,
+                    0x3ce55c589400edd5,
+                    0x8256e893037348a7,
+                    0x000002debd877176,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a07ca45a20f7a88,
+                    0x2ca3faf7d65f1a76,
+                    0x2715a7034685dd42,
+                    0xa050539b94d0aaa1,
+                    0x0000029ec82f8e35,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf1b8187b07fd0e1,
+                    0x96e133e849e3bc1a,
+                    0xdeb870b22d50f426,
+                    0x18e630078ca4dc5c,
+                    0x000000d9a8739fef,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1e77d1d1ddeeb9b,
+                    0x3c4ed24b04e6501b,
+                    0x1e17b2e09941f66c,
+                    0x4f44cadec3670949,
+                    0x000000995cded8d5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6b5cf09b280bab73,
+                    0x071f5c71e9ccc81d,
+                    0x8eab5b1410432faa,
+                    0x69fbc0abd19b4190,
+                    0x0000006e6924f790,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8546b66c6654411,
+                    0x61c222e95e1161a0,
+                    0x6f893a20851157ae,
+                    0x31d82983b47e8e1b,
+                    0x00000394ecda5274,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7071435074ee185f,
+                    0xac32ecf9b811db57,
+                    0xa8da81dcd7f8d89c,
+                    0x2d9963f94aec5361,
+                    0x000002941aef1234,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7975bb443b3b2f9,
+                    0x912749c93120fd2a,
+                    0xf97ad5934b95f692,
+                    0xfa7ba3758827818c,
+                    0x000001f310f820cc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e5324c4d78aef09,
+                    0xd8f3a258fbd03ef7,
+                    0x28b28cf2c60b77c1,
+                    0xb2e4ba0068ed12f8,
+                    0x0000017d6b12e0fe,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39ed58e6e76a2a4c,
+                    0x361a3a3267c71960,
+                    0xc2778bda1ef0c1d6,
+                    0xba73f5e4f3ef047b,
+                    0x00000298912e820c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd003e0a828fce15f,
+                    0xba372531751443ef,
+                    0xe333ac1d6798f483,
+                    0xea1959b888ab34ea,
+                    0x0000012391fd8e7b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78439c91e27be629,
+                    0x8eb719c2240b02ae,
+                    0xeac6bfcc16434a6a,
+                    0xc31412a3d227ad0e,
+                    0x000002e974e87893,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa40b67bd859ec69e,
+                    0x569f0851e8e8d525,
+                    0x4adb92f6f647328e,
+                    0x4ddcdfb71e23fb7e,
+                    0x000000cc9c8d81e0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xccb79767db505196,
+                    0x152ac861d463eef2,
+                    0x092744d3fd84c6a3,
+                    0x0e186671b0ab0a88,
+                    0x0000001a35684d46,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c5c44343d29c474,
+                    0x6eb390cd8f5182be,
+                    0xe80c2b3ec49577de,
+                    0x11b646692bf2532d,
+                    0x000003871fb4a554,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f6190ecc541694f,
+                    0xf1fe0ebe4e23a313,
+                    0x51f9b32190b2ae01,
+                    0xe002b2792864e66c,
+                    0x0000024c2638a698,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
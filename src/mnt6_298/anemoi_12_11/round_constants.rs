@@ -0,0 +1,891 @@
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x1bdd4333f4c3b705,
+            0xdcb001ed15b0eeb9,
+            0x66f4515e645ac0ce,
+            0xb065e14d0a251c4d,
+            0x000002a93b06b96f,
+        ])),
+        Felt::new(BigInteger320([
+            0xf11e3b0e560b6547,
+            0x3a7d9ea76edd993d,
+            0x83e2396c711e480b,
+            0x4aa7d169c4e963f0,
+            0x0000027916f8befb,
+        ])),
+        Felt::new(BigInteger320([
+            0x1c37ae49ba60cff2,
+            0xdb7d2dcdd277be0b,
+            0xf9099901c2948b6e,
+            0xafb6dd537b8a60ae,
+            0x000002e1b0157d8c,
+        ])),
+        Felt::new(BigInteger320([
+            0x4dbf9f5b0fe18281,
+            0xcd2f9d9c35ba4214,
+            0xe7e1ceafd92c0b24,
+            0x6405b4a83e7f5000,
+            0x00000145366a8c4b,
+        ])),
+        Felt::new(BigInteger320([
+            0x884e24518de34126,
+            0x591891ba1b431bed,
+            0x25ea6db57b6b12b6,
+            0xb671f3cd2d0c1268,
+            0x0000036a825fe8f1,
+        ])),
+        Felt::new(BigInteger320([
+            0x65ec489f1ec39ca6,
+            0x85fbb1793d87c51e,
+            0x44da72cbbd6c4a86,
+            0xdf4758883931d235,
+            0x000000b76618f569,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa9d309451b376f8a,
+            0xc74669b0c4b1cb41,
+            0x47f938f5d73495cf,
+            0x325a80138838343a,
+            0x000000f20ca8dc46,
+        ])),
+        Felt::new(BigInteger320([
+            0x1598328fda437a18,
+            0xe6cc0347b3f49c58,
+            0x101a72970f315209,
+            0x33c5a1f0c691a993,
+            0x0000031b59a88da4,
+        ])),
+        Felt::new(BigInteger320([
+            0x3c2d5a43417afda7,
+            0x7648ffd86e3e47d2,
+            0x208c1a4603e03335,
+            0x4eb2c3c5aa599e21,
+            0x00000009bb2d5179,
+        ])),
+        Felt::new(BigInteger320([
+            0xefb68c4ab4bc8346,
+            0xa3bcabd3c85cebdb,
+            0xb51b438f1a14ee0b,
+            0x1a79f15ae55d2ffc,
+            0x00000005d119476a,
+        ])),
+        Felt::new(BigInteger320([
+            0x6aade7a8cc80f594,
+            0xde6bfc6f8389ad65,
+            0xa309d4217ffc5443,
+            0xb68f7cf467754eb7,
+            0x0000019daaf0bee6,
+        ])),
+        Felt::new(BigInteger320([
+            0x38ab7d19a856dc8d,
+            0xd9dc84e7a6ffbe6a,
+            0xc8fb0396a64cb958,
+            0x9c9f29cf2d9d2f9c,
+            0x000001599aeb9f3f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9d182de6290d824d,
+            0xcd9a4936a5704c7b,
+            0xd09a9ab87a9e0541,
+            0x97a5b1ce7037eb37,
+            0x0000018343df4cf1,
+        ])),
+        Felt::new(BigInteger320([
+            0x988523659ac8a98c,
+            0x6e769270bbbe2dd2,
+            0xd9f7e1f2c3511b72,
+            0xf94bfab297ba51b1,
+            0x000003817d001729,
+        ])),
+        Felt::new(BigInteger320([
+            0x56a3f64ad9a5d219,
+            0x2beda8e081339ebf,
+            0xf1277fbce602c4da,
+            0x4af82663365fcce4,
+            0x000001ed5b6d19f2,
+        ])),
+        Felt::new(BigInteger320([
+            0x291e594d6a3578ee,
+            0x0ceb7b909814cc77,
+            0x153ef2feffabcfc7,
+            0x84ceb6f3858862d4,
+            0x0000018c3084433b,
+        ])),
+        Felt::new(BigInteger320([
+            0xd0e05b477d9f7903,
+            0x33f0a97fcc15cfb5,
+            0x7bac1361699b361e,
+            0xf05bb462f95f492e,
+            0x00000236e5c5b1d2,
+        ])),
+        Felt::new(BigInteger320([
+            0x37cdfce8a330358f,
+            0xb22396f8ef0838f9,
+            0xd96a34b8f65ef274,
+            0xce9e3b573495589b,
+            0x000003825203c306,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6aaeec843c13f4ef,
+            0x7b0ce59793a32621,
+            0xe9ccf8cb3af9808d,
+            0xa9500b05ef10bcd2,
+            0x00000134d5c5240e,
+        ])),
+        Felt::new(BigInteger320([
+            0x61da5a302c14a9d6,
+            0xac5dd73f823c51c9,
+            0xe77e2cc27404d115,
+            0x1f65b16f4914c607,
+            0x000000d10e35d17e,
+        ])),
+        Felt::new(BigInteger320([
+            0x42a0b2411001ef58,
+            0xd4bda93dc3091a9e,
+            0xb63c52b9ad004ee6,
+            0xd64acf1b22146f03,
+            0x00000049835e7907,
+        ])),
+        Felt::new(BigInteger320([
+            0xc16f6c6576f1c979,
+            0xc0bffe29c53a37d1,
+            0x6e5d1114ffe59d81,
+            0xaddef02f30e176f5,
+            0x00000156c8e1ca6e,
+        ])),
+        Felt::new(BigInteger320([
+            0xcde98a202dd6ef88,
+            0xba91b5650d93da44,
+            0xc45f23af78be171b,
+            0xe4acaac958f7eb47,
+            0x0000000e801f29da,
+        ])),
+        Felt::new(BigInteger320([
+            0xe0a7af2e86ec9608,
+            0xac89a0aff0978ed2,
+            0x3b8a2d760127a242,
+            0x7989655cf9b98206,
+            0x00000057bdf7bdb7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0cf040e4b49533af,
+            0xa9c3dd79340117f4,
+            0x86caf39b678c03f6,
+            0xad36bd8bf51d999a,
+            0x000001ae5f72fe5e,
+        ])),
+        Felt::new(BigInteger320([
+            0x5e2100d88ae937e9,
+            0x11338a93b648fa7a,
+            0x8ccda3da524e213a,
+            0xdee79a0dfdac44d6,
+            0x00000340f79f22f7,
+        ])),
+        Felt::new(BigInteger320([
+            0x88e1a65b4dc7ee31,
+            0x7891d491f1fdcf48,
+            0x9e895113443af03f,
+            0x8bdcbc57084836c4,
+            0x000002248e0bb310,
+        ])),
+        Felt::new(BigInteger320([
+            0x4fd95a881201b463,
+            0x2fb6f80cff5faf56,
+            0xcd2462d6aa9c508a,
+            0x283b3e421dd00979,
+            0x0000039b4d24c0dc,
+        ])),
+        Felt::new(BigInteger320([
+            0x9def00c017365be7,
+            0x5cb0ed129bc8753c,
+            0xb701388fc1348b67,
+            0x4c673bef53ecbdff,
+            0x00000057c96c2297,
+        ])),
+        Felt::new(BigInteger320([
+            0x35fc7e85129a1f81,
+            0x5f8c5cf6b0626ffb,
+            0xf2892832ad63e63d,
+            0x674956074ec8de2d,
+            0x000000a3416ed28f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9adccc720dfdba5c,
+            0x407980eb30dbc7e3,
+            0x3a6ddb000dc4a65a,
+            0xeaa18cd0c6f385bc,
+            0x000003b8e77428cd,
+        ])),
+        Felt::new(BigInteger320([
+            0xd8464041620bef57,
+            0x02ba11a2a4424047,
+            0x3dca11149126c9fe,
+            0x103f74e2cfd3fab3,
+            0x000000a28b59dd3f,
+        ])),
+        Felt::new(BigInteger320([
+            0xacdc9cbedacec34b,
+            0xdb1a14fbec103dfa,
+            0xd3c6d0dc74950f56,
+            0xd22b13d24c28dd59,
+            0x00000329bb5875c2,
+        ])),
+        Felt::new(BigInteger320([
+            0xdde2a84bcdc3b16d,
+            0x2498a3d25f30be2f,
+            0x2eee8322f05d9e60,
+            0x68303f1c53e043a4,
+            0x0000016d1813b4fd,
+        ])),
+        Felt::new(BigInteger320([
+            0x4d105dd83af2aa5b,
+            0x755c3e4dadd19fdb,
+            0xe3f927be0988bfa7,
+            0x6ad469faf17ca8a6,
+            0x00000320bce1e840,
+        ])),
+        Felt::new(BigInteger320([
+            0xcf46d248b826d272,
+            0xc4ec8c34771d7dc0,
+            0x296b0abf52d8ba05,
+            0x4d0c3606546293ab,
+            0x0000001537f4b96f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x812520c9536af420,
+            0xca1d21fdf3125ac3,
+            0xfbe7bca00d4c3a49,
+            0xe0bcc71b9dd46968,
+            0x0000000b543bd11e,
+        ])),
+        Felt::new(BigInteger320([
+            0x17708b92d15aec84,
+            0xb0c065d025cf601d,
+            0xbd70a369e8d2d59c,
+            0x290be49e66327849,
+            0x0000020a44689072,
+        ])),
+        Felt::new(BigInteger320([
+            0xa9cdc1b3686c8e6f,
+            0xf8b03750bbbd4375,
+            0x16e0e9fe361ac62e,
+            0x47bf184422c88e20,
+            0x0000009c52233fc4,
+        ])),
+        Felt::new(BigInteger320([
+            0x18f710c73431d150,
+            0xf4e1c3605dc6e8bf,
+            0x3590985676acb608,
+            0xafbac855e285bb5f,
+            0x000000ee066ec666,
+        ])),
+        Felt::new(BigInteger320([
+            0x2ca24238d2a23817,
+            0x973612d655247ec0,
+            0x4c91e939d24f6e4b,
+            0x2fba096acea1710b,
+            0x00000067fd574e67,
+        ])),
+        Felt::new(BigInteger320([
+            0x3f7906d2789c2ced,
+            0xfdc37dc26a7f378b,
+            0xea87025abcac6354,
+            0x27e6422a64917f78,
+            0x00000015f5def149,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x005d513fcc04ab9e,
+            0x31b90296c160d202,
+            0xdfd82bdd914bd3fb,
+            0xec665c52abf896c7,
+            0x000001a6c6a34578,
+        ])),
+        Felt::new(BigInteger320([
+            0xf3a160e71af26386,
+            0xfd59b07dbb393857,
+            0xbf006b6824556ae2,
+            0x5d42dd820f08e025,
+            0x00000231d274c895,
+        ])),
+        Felt::new(BigInteger320([
+            0xf859667dd68d0b35,
+            0xb9422e0c0f35d57a,
+            0xc89aaed150789f8c,
+            0x22cb7dee8e9cc489,
+            0x0000020cbe21ca64,
+        ])),
+        Felt::new(BigInteger320([
+            0x12a362ccfb7effaa,
+            0xda8bff41279ff407,
+            0x033b63103c503e6c,
+            0xa90b38f64b7a3e4a,
+            0x0000030a1f79c65b,
+        ])),
+        Felt::new(BigInteger320([
+            0x3ae52bf8788f2e4a,
+            0xd7e4cf1590fe27c9,
+            0xced0da049553dda4,
+            0x49b38a88b5f2eef9,
+            0x0000019e30f780df,
+        ])),
+        Felt::new(BigInteger320([
+            0x62dc1e2df365bfe1,
+            0xa6205da494625d73,
+            0x7c2e6eb08c9a695d,
+            0x18b0892a2112b802,
+            0x000003b1c9a25d7f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x710ac9f3f206cbe7,
+            0x086b73bb984d57a3,
+            0x30b495d3a6d543cc,
+            0x86650159921e0680,
+            0x0000037651806d09,
+        ])),
+        Felt::new(BigInteger320([
+            0x0791406f8a91b75f,
+            0xb51895ac4d807d9f,
+            0xacb5bd083feb6d1e,
+            0x1cf5381a0fc150fa,
+            0x00000140710d28b2,
+        ])),
+        Felt::new(BigInteger320([
+            0xf9d2594728a2e6f9,
+            0xfe308e6de84c4ea0,
+            0x9aa0d993f7f9be49,
+            0x1f352289a855b8d2,
+            0x0000036486a03577,
+        ])),
+        Felt::new(BigInteger320([
+            0x938053de35eeb041,
+            0x15ca6537a542bdda,
+            0x55ad26c66f0fe649,
+            0x46f816eaa3a55629,
+            0x0000015aff66eba1,
+        ])),
+        Felt::new(BigInteger320([
+            0x90fa9e65d1d07261,
+            0xff760971391df00d,
+            0x8255f1ad6616aee7,
+            0x4bd39dcd292ccc14,
+            0x000002e03611a3f7,
+        ])),
+        Felt::new(BigInteger320([
+            0x7bdd26abc9a9af10,
+            0x7e9c7804ca1a55cd,
+            0xa52d2052a66afd09,
+            0xf8cc817add180b19,
+            0x0000021b1829f953,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0b76cde2cfe6e624,
+            0xafc4ca3fb40f433f,
+            0x851ddfdc4e5e245b,
+            0x0f6caee4dadac7a3,
+            0x0000035ee794696d,
+        ])),
+        Felt::new(BigInteger320([
+            0x473c67aa35d361b7,
+            0x14332f4bd4c54ee6,
+            0xd5d2c151c95eeb7d,
+            0xb5beca90943552c6,
+            0x000002587f0f54d8,
+        ])),
+        Felt::new(BigInteger320([
+            0xc372541bc04a128d,
+            0x122d1beb8b352d98,
+            0xdc98f2a21d472d01,
+            0xbedd3863497b4a2a,
+            0x0000036afce82a7a,
+        ])),
+        Felt::new(BigInteger320([
+            0xae5c70922c347fa6,
+            0x9c8e8932cf912a80,
+            0x639eb59fa17adf36,
+            0x0e49c3a329c6623d,
+            0x000001a4fa13bad5,
+        ])),
+        Felt::new(BigInteger320([
+            0xec921ff1b12de54e,
+            0xb8b3e8ca58081d2a,
+            0x2f137241b88d5d6c,
+            0xc15f9c272fcc8f24,
+            0x0000026bbeea7aa4,
+        ])),
+        Felt::new(BigInteger320([
+            0x18ab0dfe17b7a53d,
+            0xd4ee7ae9607a233f,
+            0x365670f41d3e81fc,
+            0x8a8d264f0c9679ce,
+            0x0000000fa6fdf3a9,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x21a0406cc189aaa8,
+            0x1e7ebea218b90671,
+            0x89ba6e3a0830785c,
+            0xee96557bcf54b55f,
+            0x00000395899902b3,
+        ])),
+        Felt::new(BigInteger320([
+            0xfcfbbdba63090a29,
+            0x4c4844a2ce7b5b05,
+            0xc078e5d646c24281,
+            0x120cc52f1d68c3c4,
+            0x000003a617bf2d6c,
+        ])),
+        Felt::new(BigInteger320([
+            0x206707839ef1ef02,
+            0x4b450fbfa6f9e402,
+            0x49562efc385b3cac,
+            0xc6a622070e340499,
+            0x000000695d840101,
+        ])),
+        Felt::new(BigInteger320([
+            0x4b2fbdfc5f6dc9b1,
+            0x4eca7091481814a3,
+            0xad79dba109b6424e,
+            0x7d704920e9fc5ebf,
+            0x0000015b33bff45b,
+        ])),
+        Felt::new(BigInteger320([
+            0x020addb41d37868d,
+            0x8b230a902eac433b,
+            0xc611eacb53588129,
+            0xfcd48761213758b7,
+            0x00000140f5e3fa8a,
+        ])),
+        Felt::new(BigInteger320([
+            0xbb9ffc408c1f84a8,
+            0xfc6ed696918dcdb7,
+            0x561afadd904b34fd,
+            0x1184d81f200c84fc,
+            0x000000800f7a832f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc06325b2093e3ffd,
+            0x992c30b1e5e685d5,
+            0x02d73bf40bbcffd9,
+            0xb3cdfb4aa7d898ea,
+            0x000001a2783229e7,
+        ])),
+        Felt::new(BigInteger320([
+            0x76ff9fcc0881fbc9,
+            0x8d6467879f619628,
+            0x19da3bc01893bd67,
+            0x9c077820d1d18076,
+            0x0000004f7fa92bfe,
+        ])),
+        Felt::new(BigInteger320([
+            0x0c6d0755474cf988,
+            0x7171381df34a7b79,
+            0xd3df5fc6674dd884,
+            0x4b4b341f3f246237,
+            0x000001127d4fadbf,
+        ])),
+        Felt::new(BigInteger320([
+            0xb936fec42589a747,
+            0xb0b7d51c8b44cc1a,
+            0xddba000638461946,
+            0x198db17992fb5ee7,
+            0x0000039ce322884b,
+        ])),
+        Felt::new(BigInteger320([
+            0xb07af4e37d1617cc,
+            0x9bd6cb99477ce262,
+            0xa63800bd4590b6c8,
+            0xe29b3c2e5dc1b533,
+            0x000002f53328a950,
+        ])),
+        Felt::new(BigInteger320([
+            0x9f2c4fef36f3a15f,
+            0xe066b451193269df,
+            0x725371cb09de564c,
+            0x121fb06e6ee8ae01,
+            0x000000e661443162,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8e363c7277c0412a,
+            0x28ec233621b8c7d3,
+            0xfb6be61cb78bb3c5,
+            0x523a0ed47a682b44,
+            0x00000288f867033f,
+        ])),
+        Felt::new(BigInteger320([
+            0xd47a82c129b319a7,
+            0x9e7b09af023ee866,
+            0x53aaf381d518cb49,
+            0x9aaeb2b18d8a03f2,
+            0x0000010aebff1e30,
+        ])),
+        Felt::new(BigInteger320([
+            0x0171957c4023bc64,
+            0xb081f4246153932a,
+            0x146e0da351d5aea2,
+            0x80b1788bb5ba6c59,
+            0x0000034b668ddee4,
+        ])),
+        Felt::new(BigInteger320([
+            0x11e989423bae8b58,
+            0xa8096bd023b302a6,
+            0xe2e22e78c94dfde6,
+            0x1a9d34437606188d,
+            0x000001bb93cf1855,
+        ])),
+        Felt::new(BigInteger320([
+            0x35f825fd8ee089a4,
+            0x7f7e3fa058bf5aa3,
+            0x23debeffdaa09b87,
+            0xb32230ce328b3679,
+            0x00000026bf3f3075,
+        ])),
+        Felt::new(BigInteger320([
+            0x78dcc1dd9278e8cb,
+            0x4219d960bc4ea532,
+            0xf2b5eb536255d3e2,
+            0x7d3fa3c56070b25d,
+            0x00000364615abdd5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xe5e0648601896490,
+            0x0097bcb9a5276e3a,
+            0x2f3184d0ecf95a52,
+            0xe6fa3b97b8528bf6,
+            0x000002e2ee34042e,
+        ])),
+        Felt::new(BigInteger320([
+            0xe32657a531c1cab6,
+            0x01e497a7f05643f2,
+            0x46b3485657d2edc3,
+            0xe6446197a5a65bed,
+            0x000002bfd8d8d6ca,
+        ])),
+        Felt::new(BigInteger320([
+            0x7781bae7ed428a67,
+            0x838af1a43864dbca,
+            0xf41621418de563f0,
+            0x8f19f4cf60809d8d,
+            0x0000024ff26667cc,
+        ])),
+        Felt::new(BigInteger320([
+            0x344e05cfbf2d8ca7,
+            0x8616eb8be6143ac2,
+            0x56938d303e99f6e2,
+            0xc6c3410ae070bbc5,
+            0x0000022e9013c95a,
+        ])),
+        Felt::new(BigInteger320([
+            0x7857f2efb5dab0ee,
+            0x2ba194afc1d69cc7,
+            0x5213d952bbc9e95b,
+            0xccef1f5df8e5bc37,
+            0x00000263b53ca6c3,
+        ])),
+        Felt::new(BigInteger320([
+            0xabc9dd98ecf7fa08,
+            0x66b8e03a5319c7cd,
+            0x6f6924b1e230aef1,
+            0xab40a156e4a66ade,
+            0x000000e23135e258,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb2f088c7586ab887,
+            0x19312cb3afefe995,
+            0x042b2d3f491a262e,
+            0x20a69f34396287b6,
+            0x00000094514efc9f,
+        ])),
+        Felt::new(BigInteger320([
+            0x0a3bce2e6ef66e00,
+            0x509cc3148ecd762c,
+            0x23fe6d0c65aa865a,
+            0xdb8bfb4cd540f9b4,
+            0x000002679baf4664,
+        ])),
+        Felt::new(BigInteger320([
+            0xe8917ee309689e77,
+            0x11419510d1c419fc,
+            0x145ecd3954ae4dbc,
+            0x7a719321c1b78447,
+            0x00000162d680bff5,
+        ])),
+        Felt::new(BigInteger320([
+            0xed86c3d3389d8cc8,
+            0xdef05d878aa43bce,
+            0xed568c9018def25d,
+            0x76e5403448626d41,
+            0x000001aaedc3dde8,
+        ])),
+        Felt::new(BigInteger320([
+            0x2e6f6e147d9a3285,
+            0xb2ec907d4cd32c1b,
+            0x47a0653490c2e7ae,
+            0x0cd58638168e0276,
+            0x000003a1cfb39214,
+        ])),
+        Felt::new(BigInteger320([
+            0xe730b174570598b9,
+            0xfee760a10fac9d51,
+            0x295296701aef7cf3,
+            0x712c67b55c693a8d,
+            0x0000022285d6e9c4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x38a3946f18f4de9e,
+            0xd3d8e2764fb20e8a,
+            0x586febe2d38c77f1,
+            0x899f76422443ebe0,
+            0x000002ea638f551b,
+        ])),
+        Felt::new(BigInteger320([
+            0x7c278db1ad3ac4d8,
+            0x66155c741fae30fe,
+            0x759cb18588bcde7e,
+            0x3871ddeac0742799,
+            0x00000014b9a92eb9,
+        ])),
+        Felt::new(BigInteger320([
+            0x0452f560fd9112fb,
+            0x97bbe7cb6ebdfdb4,
+            0xea3e244169421c33,
+            0xec4df2661ea3a2e4,
+            0x000002b38e0cb0b4,
+        ])),
+        Felt::new(BigInteger320([
+            0x2e99c6555b81293d,
+            0xf30d67a51fba2a81,
+            0xbab14d7e9fce3128,
+            0x84ce6d753f2e7403,
+            0x000003853aaed48a,
+        ])),
+        Felt::new(BigInteger320([
+            0x1a1416a308782062,
+            0xf440a8016f6660eb,
+            0x4a4b623e605c89b9,
+            0xb46a976f25791097,
+            0x000002f955abb156,
+        ])),
+        Felt::new(BigInteger320([
+            0x7841855263b3eb14,
+            0x8839a22f794f201c,
+            0x00d6503ba49e001c,
+            0x827d4f7d7b0e6813,
+            0x000001e0069bfeb1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x20f0378b9de4d19c,
+            0xc42725d2a5870f8b,
+            0xd2700f3eedd8895c,
+            0xccc7ca671723d03f,
+            0x000002b9e4b079c7,
+        ])),
+        Felt::new(BigInteger320([
+            0x0212f3245c0c7b3e,
+            0x7f7d06e3a27c5421,
+            0xe2dabc339e392603,
+            0xfbe542e2cb215153,
+            0x0000013c8f5489d3,
+        ])),
+        Felt::new(BigInteger320([
+            0x0348691acab19759,
+            0x1bfcac69d2097150,
+            0xe5de7f1f458c5087,
+            0xaeef10b21142545d,
+            0x000003a33930f711,
+        ])),
+        Felt::new(BigInteger320([
+            0xb06f48f201720259,
+            0x2eb7dd751f91585d,
+            0xaeeadb0ae3ed84b8,
+            0x76ffebeb422297e2,
+            0x000002c645a68ddb,
+        ])),
+        Felt::new(BigInteger320([
+            0x40671524dfaa6757,
+            0x817bd2cc17fa431d,
+            0xa07b9c12e6f37444,
+            0x23f72c1b76ec851f,
+            0x00000000b2bdbf65,
+        ])),
+        Felt::new(BigInteger320([
+            0x2f34d3fd63abfec8,
+            0x2c71e9ff6df1dd34,
+            0xaf89c02fcc41e552,
+            0x07fe50ddff8c0004,
+            0x000001a0e122de73,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x1b98851393d21187,
+            0x6d2a01399f36f811,
+            0x83f0ce2136d74946,
+            0xea0f1d4f7d6d0fc0,
+            0x000000e36dd083fb,
+        ])),
+        Felt::new(BigInteger320([
+            0x14f71a2e22f77aae,
+            0x08c69866f5a50877,
+            0x7ce99d39fbaf2317,
+            0xe4201e1573cd1fe0,
+            0x000001af2bd62c44,
+        ])),
+        Felt::new(BigInteger320([
+            0xcd442af6b6259c8c,
+            0x1df59df350e3749c,
+            0x6528939724e9501d,
+            0x9b99340dd53b9ce9,
+            0x000001a1bbe8178b,
+        ])),
+        Felt::new(BigInteger320([
+            0x258bb8094612b920,
+            0x55c9142414cbd4ed,
+            0x4a25f5696e903354,
+            0x81ee1a3d033c2cef,
+            0x00000170756a23aa,
+        ])),
+        Felt::new(BigInteger320([
+            0x855d509a02eae5f8,
+            0xfedad5e11192c841,
+            0xbb39a5e5cbeb4b6a,
+            0xf1f48f885e1369be,
+            0x00000181f4d35c2a,
+        ])),
+        Felt::new(BigInteger320([
+            0xce08086a5bc91a29,
+            0x1635c4afc3367463,
+            0x0ec17c2a612f1193,
+            0x0a66558f14324ab0,
+            0x000001cacb9ee083,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x19750e876b42f228,
+            0x56998d59abae554d,
+            0xa83b8248d0583388,
+            0xe719d9768f2e5a21,
+            0x000003606c64750b,
+        ])),
+        Felt::new(BigInteger320([
+            0xb6160a7644058edf,
+            0xd3429890bd772558,
+            0x3e0d390b9b3c9fc3,
+            0x06de8fcda0216b5e,
+            0x0000016b3e2555e1,
+        ])),
+        Felt::new(BigInteger320([
+            0x5bec2e7fb9aa38a8,
+            0x75a119505f84c55d,
+            0x0a9d088b5061e94b,
+            0xfb0eefc91a906bdb,
+            0x000003a6f81d4c1d,
+        ])),
+        Felt::new(BigInteger320([
+            0x3397b9da31f12a0f,
+            0xa3c49515c7f9765b,
+            0x70060351254755a1,
+            0x82e70f5187031f77,
+            0x0000006ec90e126f,
+        ])),
+        Felt::new(BigInteger320([
+            0x68a1d3c70d9aea67,
+            0x39292b37ef3d6820,
+            0x422d07c020a5971f,
+            0x5720b9ecfce92182,
+            0x000003716da448c2,
+        ])),
+        Felt::new(BigInteger320([
+            0x743821a7e37bc9b0,
+            0x016efa0b2e794458,
+            0x0b2e77fdfef71fb0,
+            0x4d8e64fffbd37870,
+            0x000000e18ddd45d7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xeebe711ca53acc46,
+            0xe908531d0e3ee5e4,
+            0x2fd975b24880d687,
+            0x983551fb2ca99770,
+            0x0000015fba986853,
+        ])),
+        Felt::new(BigInteger320([
+            0x309e90574b5ef918,
+            0x1d72a16f8b8a9b9c,
+            0x9a5ee6b5f54fe092,
+            0xc7bbed3d7953e955,
+            0x0000009a044778eb,
+        ])),
+        Felt::new(BigInteger320([
+            0x606987faad69241d,
+            0x74b3160d493c4951,
+            0x7fc9cafa464f1a72,
+            0xc2cad09c1074795e,
+            0x000001c426853805,
+        ])),
+        Felt::new(BigInteger320([
+            0x449469d8844eb956,
+            0x10e77457cb73f2d1,
+            0x7c1b04ee85465294,
+            0x14b2aba10992fc45,
+            0x0000028c7397acfb,
+        ])),
+        Felt::new(BigInteger320([
+            0xff16b3f949101d35,
+            0xdd7c79d054f63a39,
+            0x221f31b543bc0813,
+            0xf4c08340584760bd,
+            0x00000113ae9d1653,
+        ])),
+        Felt::new(BigInteger320([
+            0x07269c448da17fbf,
+            0x3e1fb8369e05219a,
+            0x9a7b3b63a35ea8a8,
+            0xa9c8f96b27c0b7de,
+            0x000000a9cc8e0b84,
+        ])),
+    ],
+];
@@ -0,0 +1,443 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger320, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::new(BigInteger320([
+            0x5c35297607fc37bf,
+            0xa92899e27841a429,
+            0x51b1f44050409786,
+            0xd824c3b10d55b030,
+            0x000003b1fe592e64,
+        ]))],
+        vec![
+            Felt::new(BigInteger320([
+                0x6195eab5a257bafa,
+                0x50a5c4082e938534,
+                0x3ac51302df86c906,
+                0xcbc36e8fc0b178dc,
+                0x000000ab09a847c5,
+            ])),
+            Felt::new(BigInteger320([
+                0x1d198971ca525594,
+                0x435facd0e832128f,
+                0xc05ac38be8d5d661,
+                0x93e6d2f812f04cbd,
+                0x000000c3f48140b9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xb2cf486d22b60d09,
+                0x36a365a3572d5bbc,
+                0xe1e5c38ef505000e,
+                0x5dd68336883d2f82,
+                0x000000cf7746b2c9,
+            ])),
+            Felt::new(BigInteger320([
+                0x2f0638287c7e5ff0,
+                0x0acde5e79d32fda4,
+                0xffb25aef87c6b782,
+                0x7051e37ac1e34201,
+                0x00000135aa0f1948,
+            ])),
+            Felt::new(BigInteger320([
+                0xc330bdfbe120cbee,
+                0x55e72f5bef92b483,
+                0x91cae31660aef94b,
+                0xcd9ae51e098f3e31,
+                0x00000382cb926345,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x47335d5f61b7a80b,
+                0xaf1a661cd0d84270,
+                0xfc0354addec5bcfd,
+                0xf87d74a4ecf697c5,
+                0x000000e10b803405,
+            ])),
+            Felt::new(BigInteger320([
+                0xde8f61cefed3a868,
+                0x618751b73343e791,
+                0x5e9e8398f78b20a2,
+                0x906e200f8233169f,
+                0x000001d4b3e1e46a,
+            ])),
+            Felt::new(BigInteger320([
+                0xbb393197fdadfe70,
+                0xbd99885097eee40c,
+                0x56433e0fe56d751e,
+                0x06ac97cf0bc0d538,
+                0x000000965b7fc8a4,
+            ])),
+            Felt::new(BigInteger320([
+                0xa1ab6583c09400de,
+                0xa5b53b54e527b8e5,
+                0xb3804b5aa199edb2,
+                0x9fab3d963a73bc0e,
+                0x0000033604136316,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x39def85693d916bf,
+                0x0f9c92335614d8ca,
+                0x5c4501decaef5cd8,
+                0x860db2b022888d9f,
+                0x0000038dba9ad770,
+            ])),
+            Felt::new(BigInteger320([
+                0x4f83d6bfd87bddfd,
+                0x9d7f0151f827b939,
+                0x51b124d370674f62,
+                0x3cd9bcb79acae784,
+                0x000002601614731f,
+            ])),
+            Felt::new(BigInteger320([
+                0x37867540c309ce09,
+                0x75865461bbec2027,
+                0x03310f04d66e5483,
+                0x8bb58271d330608f,
+                0x00000028d97f69c5,
+            ])),
+            Felt::new(BigInteger320([
+                0x4d7c7405aadd0f3e,
+                0x56b397109039be88,
+                0x7fbf020f36cad25c,
+                0x710adbcc04e741d0,
+                0x000000f637f14e01,
+            ])),
+            Felt::new(BigInteger320([
+                0xf595883869881868,
+                0x7d1d65a86ddb8912,
+                0x3217e8bf54b19ff7,
+                0x93162e475187ff5f,
+                0x0000027bc81a28c0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x0de7eb3cd1eaccd7,
+                0x97969cee129ab602,
+                0x5f3bcfe788237704,
+                0x732da17552b4754f,
+                0x0000032a9db39796,
+            ])),
+            Felt::new(BigInteger320([
+                0x566e04ea3cb87624,
+                0x6317ac0bfbede723,
+                0x5c18bacc67a1d9bf,
+                0x254504b9091bb0ca,
+                0x0000028a2e20150f,
+            ])),
+            Felt::new(BigInteger320([
+                0x2449fa300df867f1,
+                0x8a33e2841de391e8,
+                0xbdb8ff8834c4c9f8,
+                0xe0d04ddb92ede920,
+                0x00000138540488f0,
+            ])),
+            Felt::new(BigInteger320([
+                0x9e982f928af46b70,
+                0xd7b156da6747736b,
+                0xdd1497259949319f,
+                0x6f6da5b90b6665a9,
+                0x000001f86c70edad,
+            ])),
+            Felt::new(BigInteger320([
+                0xd12007961aebfc8d,
+                0x1d1df40cbb5bf951,
+                0xd8e2b821c29efd32,
+                0xf2483c5877a18b8f,
+                0x000003b3243c738e,
+            ])),
+            Felt::new(BigInteger320([
+                0x81f7d90742f9ff97,
+                0xa4e9090a230ac42f,
+                0x47e42b3b9ad77a33,
+                0xa786f3975e31cd73,
+                0x0000023baacc2f6a,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x441230a360ad52d7,
+            0xc29a78516a198633,
+            0x1f3b3cf50336ba4c,
+            0x82e300bfb438a886,
+            0x000002e3811c6986,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xcc4f7ca9f4111f7f,
+            0x278dfd7010ed9cd1,
+            0x4e58c002b974a783,
+            0xc036496c56c57ac6,
+            0x0000020319675f78,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x89d93886ea1bf551,
+            0xe2a9a7944efd9399,
+            0x91e834f3f7ff98e9,
+            0x5fd9183b26cfdf8e,
+            0x000001c727e592d5,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x370e3340caa34cbd,
+            0xecac086e5a475622,
+            0xb9518a0c979fa2e2,
+            0xaf0eaa0e2d3f1329,
+            0x0000012dd2319287,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x50b8f858613d7213,
+            0x123d76b770ba6fd8,
+            0x30356b8c65d07075,
+            0xfd26c753721f2d5a,
+            0x00000161fc474eef,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xcddb834bc86562e8,
+            0xe304a60aa5b00bf7,
+            0x031b24a4dbc65be6,
+            0x67711567dd18099a,
+            0x0000003f7375965c,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xafeaa1f41f5db139,
+            0x1af077b832426b5f,
+            0xe581f78bb2551127,
+            0x8789c36353dafab1,
+            0x00000221b6e859ff,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xbf95adf9d75e6fdd,
+            0xc289d7e06a575a71,
+            0x11987294257523dc,
+            0x03d10852ba534746,
+            0x00000111c2bb72bf,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x19f9fb4ce4e786bd,
+            0x08b50d8c7a903473,
+            0x257e9e538ac95b63,
+            0x55ebe23b4353a13c,
+            0x000002e875640adb,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xd9320574c1dc8761,
+            0xa54c20e158daf42a,
+            0xe5f8dff11d24cef4,
+            0x1720d928f6d83241,
+            0x000000896ea5d968,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger320([
+                0x331e012bd0c31b5f,
+                0xe1e8d455f2e5b413,
+                0x6f3dcf05f1c660ab,
+                0x96e7d61eec3f6276,
+                0x000000350766b021,
+            ])),
+            Felt::new(BigInteger320([
+                0x01468be1c3441b41,
+                0xa64d60d5a2bc8f18,
+                0xaf70c9ba0c1f294e,
+                0xb137b2705cd52371,
+                0x000001ac064bd241,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x478edb2ea0e93e74,
+                0xaa42ce794cfe5bed,
+                0x9756c1932781531e,
+                0x10fb9190843ab76b,
+                0x0000002074329c92,
+            ])),
+            Felt::new(BigInteger320([
+                0x98e18ce12a71ecdc,
+                0xfeaf576db627d2d8,
+                0x32bb459c81bfe828,
+                0xb449e400feb546a6,
+                0x0000008680fde889,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x070de2aa43ca06e5,
+                0xdc967607453ec109,
+                0x2808b46610dfbe33,
+                0xf285bd2ea0f13b67,
+                0x00000228c1cb8b51,
+            ])),
+            Felt::new(BigInteger320([
+                0x92c2e8f549d47095,
+                0x9c8c71e24222ca93,
+                0x9ba5b3cbeed77d93,
+                0xa31b6ab441a38ee8,
+                0x000001df13a7a191,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x927f0e8a05efa13b,
+                0xdea9a5a3ba26ebac,
+                0x4d4203b831b98735,
+                0x6045aa85a284518a,
+                0x00000196c5116191,
+            ])),
+            Felt::new(BigInteger320([
+                0xd651237adace6c9f,
+                0x543e6084bc7953be,
+                0x07579a996f556647,
+                0xa563fbc84d19bdd7,
+                0x0000023b77a17cff,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x8f30df5f782f7cd5,
+                0x696490fe177c0fdc,
+                0x05c1c13e0fde8ce1,
+                0x7afdc17a1b447fb1,
+                0x000001714b338359,
+            ])),
+            Felt::new(BigInteger320([
+                0xc13e82b19fec7f37,
+                0x91bb5ddf87d43900,
+                0xaa952ee37a11e053,
+                0x47c8d25caea8ffe8,
+                0x0000022fcae7db0c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x50a2e3ac638db2d6,
+                0x188e81d4afc9d83a,
+                0x241ce9890f6e82b8,
+                0xc1fd514729b19355,
+                0x000002a8c989fd51,
+            ])),
+            Felt::new(BigInteger320([
+                0xc06c5045250b02c1,
+                0x18b152f044a93d0a,
+                0xe1a031053281e2f1,
+                0x5685f64a65f2bd10,
+                0x0000019651d0f3a5,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x119569ee2dee2fa3,
+            0x8e1ca5746f386d33,
+            0x70edb82ea51fdc80,
+            0xcfe66499345bbe8d,
+            0x0000002a57736e4f,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xf46448d578922983,
+            0xd77374456a10e996,
+            0xc6cfb511f514d904,
+            0xb107194de7a07edd,
+            0x0000035a7cf7b2fc,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x49704fea8d3c96f2,
+            0xeae022569e890bdd,
+            0xe5f664e94ce51897,
+            0x1cf6048982878eb2,
+            0x000001fae6a1d62a,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x64270ef4342e933a,
+            0xacac012401f4a178,
+            0x34822067cac75003,
+            0x78355d34f6aafd64,
+            0x000002e7cccd23d1,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x9582eceb23ba3fc1,
+            0x0fb0712d86558897,
+            0x11330f9a913a68d4,
+            0x170bddf8e776d6fe,
+            0x00000164b670bc20,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x3fdfa34f079f94d2,
+            0xe897aeb11b0ae2e9,
+            0xcd86afa2257e2a37,
+            0x8586449998e2bcef,
+            0x0000034bf28a2737,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xf0f98c00b4c68002,
+            0x7e59a48e4d6db3d6,
+            0x5b419690b6f96e5a,
+            0x4ec74a249c452cd3,
+            0x00000300743ffa07,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x68746627819e376c,
+            0xd0f61dd7c2466438,
+            0x786a8ecfed64e3e8,
+            0x10954191502c9982,
+            0x000001198f4067e7,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x313e42eccb8340fa,
+            0x7855c48d53eb81f4,
+            0xc9585a8a3591ca55,
+            0x577f7675569cf2be,
+            0x000002bbc52882f0,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x74c1fad195d12508,
+            0xe200fd8aa5b6bfb6,
+            0x80e08da217b9fbeb,
+            0x7df25a15c44c866f,
+            0x000000c1bfbf3c8a,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+}
@@ -0,0 +1,654 @@
+use super::{sbox, BigInteger320, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 76 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (38-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xd3a7269bb45dde80,
+                    0xe3e25df141f43729,
+                    0x141abd3fc2f86bdb,
+                    0xf942b1c5b96d1ac9,
+                    0x0000039c84a64bbe,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb884a34b77a27c46,
+                    0x3836b62a1a115265,
+                    0xcbf959b414cd8003,
+                    0xe054bae06156abf4,
+                    0x0000002639b521d8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2c7f81e97fac1f38,
+                    0x36d8dccc4f4cdb47,
+                    0x8e916296978c9517,
+                    0x97b63300c889b1f5,
+                    0x0000008efdf5382b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8bc9201265740c9,
+                    0x7bdda9760f9ac28e,
+                    0x83e69414bcabffca,
+                    0x55575f23eaac9f3a,
+                    0x0000035b84514dec,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xccf3ada75ab772c4,
+                    0x2f2bdd87f1107cdd,
+                    0xd165556ea580c802,
+                    0x6b892c9e7f10a30b,
+                    0x0000038a83651b31,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b619a222285d9a6,
+                    0x65fd08f1a791b72e,
+                    0x82290cbe6512b684,
+                    0x9274e10455103b6a,
+                    0x00000323c2c7277c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb119d2d9b03bf0a0,
+                    0xb620daef665793d5,
+                    0xcd5ea865c875c8a8,
+                    0x1ac44c36692e9781,
+                    0x00000333fe181612,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf536169720f44e02,
+                    0x759d4fb35e4173f2,
+                    0x793876fc99c654cf,
+                    0xd948f40492aed3c8,
+                    0x00000282e51f868b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbbdaaaa2d6d53c61,
+                    0x4648941b27b2e6fc,
+                    0x94ad2c9906bc1a66,
+                    0x74d1e44711853a76,
+                    0x0000002e8bfadae8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7328a923579e5db2,
+                    0x29d6958641d9103c,
+                    0x0746c85d5aff274b,
+                    0xfaf7bef5d0adf111,
+                    0x000000d2a41fc031,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x64475cf7c0a46d71,
+                    0x9eb70e597e6bbb2e,
+                    0xc915eb49cdbebdf8,
+                    0xbe14f7d7cbae530f,
+                    0x00000248b4c9fb45,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bc1e0a7306173b5,
+                    0x941644690cf6bae4,
+                    0xb016f2220060d3b9,
+                    0xd5307cb55b6bcb35,
+                    0x000001c07ed30648,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb340a15077052b1b,
+                    0x37af259efae80181,
+                    0xc94453ba76be4c84,
+                    0x0de53329e46998c7,
+                    0x000000a356eb74d5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8c1710d99e0e9f1,
+                    0xd44cace1305edbfe,
+                    0x661d5cfd96c4e74f,
+                    0x4a3a6efd5084aa74,
+                    0x000002281fd9f0ac,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x620db35797b5ae0f,
+                    0x62ba261ef8b3ba0a,
+                    0x5f7529c3bbffa616,
+                    0x11b7eeef815e4227,
+                    0x000000a0926c3a56,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3ae854f5130869a,
+                    0x9070cd9a1ebd2102,
+                    0xcde27bc8fc119c9f,
+                    0x80aecfeb2dcc5fc3,
+                    0x000000835d497b0d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfaaddc389940e2e1,
+                    0x1bb7479aa4a4fc73,
+                    0x0e03cff68616c291,
+                    0xa0ade0fc59d2b4cb,
+                    0x0000013828671694,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5dcba0c6f1ef687b,
+                    0x00fc40a3299bdd20,
+                    0x5090473df822c8cd,
+                    0x622c82c9a31d57f8,
+                    0x000002f60c18af56,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x783a4ad8ce3a6716,
+                    0x602c2659490667ca,
+                    0xcd18966e0d89715e,
+                    0xe070e4430ed3e39d,
+                    0x000003a729754e74,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b614fb2e604408d,
+                    0x0c34f6c0a7944f82,
+                    0xa65a404c66a5a71d,
+                    0x07e110e09a6a7ec9,
+                    0x000000427718dfe4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x41da9d158e41a35e,
+                    0x85557c5d19314bc0,
+                    0xed5d0d92f22c5dc5,
+                    0xf0398acaf95913a2,
+                    0x00000096b0764ef6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x462356858b00348f,
+                    0xb717c0bd16287be5,
+                    0xdfdfb1eb333e970e,
+                    0xec3bf3bddf705337,
+                    0x000002cef1239103,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xed66869ef2235e89,
+                    0xe40d86ecbe58a22d,
+                    0x9cf612e000b2deaa,
+                    0x4142ddcaec17674c,
+                    0x0000037fff4e4962,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92cb31d6959adc9b,
+                    0xaaadaa8684a70083,
+                    0x7ad2dea5b2c5d27b,
+                    0x74f0da93f09bc636,
+                    0x000000983c291089,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x56c0cc84f89b03ba,
+                    0xcc07bee2ceb81b71,
+                    0x010e039bbf4a23c9,
+                    0xe7481b2ec8d0fb44,
+                    0x000002eb4f53329e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfdbca7ca66ff26b3,
+                    0x441e7ccd1d2ef00b,
+                    0x93102265a86287e4,
+                    0x640c056c3af168d8,
+                    0x000003448866851a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaaab29c7032980a4,
+                    0xdde4a79f038bb665,
+                    0xacfadff9e72819f9,
+                    0x3114e93114802f44,
+                    0x000002b964aa5192,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0746baa1e7519f8d,
+                    0x8c777b32f3a59b03,
+                    0x35cc65b8f81d8833,
+                    0x32d4d7912e84dd3c,
+                    0x000000b5bc9276a7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe1c816875dd73bb4,
+                    0x612960d05a8768e9,
+                    0x323b6c4c0a6dc0d6,
+                    0x57f3a17ad6e20606,
+                    0x000000144c1d2524,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff0c086ad742baf0,
+                    0x9fb59aa7fe0e4211,
+                    0x9b9792292df7dc3c,
+                    0xbf41007c822eca8d,
+                    0x000002b80e067e44,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5721a901374d3377,
+                    0xebf8933ebe582dfd,
+                    0x609b942ea162d1a8,
+                    0x6a19ef925936fc9e,
+                    0x0000027c72dc43cc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b18ceb222393087,
+                    0x59cf3b5ceeb27944,
+                    0x8dfe3822c23df183,
+                    0xa681bf3b85cd4a87,
+                    0x000000facf7f3fa9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x373727c461656775,
+                    0xa5f385a3762e2ae8,
+                    0x7f6c77d570b82a75,
+                    0x72a14c9b8f0bf596,
+                    0x00000067670cc101,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94b95c1dbc405d41,
+                    0xd034566f05cbac9b,
+                    0x23128fb5c9fd7b46,
+                    0x1ab96eff6b242e8b,
+                    0x000003bc0912b9b3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb480e7bc82537500,
+                    0x165c673edb0cef83,
+                    0x7f8d99aa3838a453,
+                    0x2d0e2fca544fbe0f,
+                    0x00000251a094544e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd98b01417a6c96cc,
+                    0x3ed6e1c24cfd0200,
+                    0x3313b8d4543c0288,
+                    0xece71ad51942ab74,
+                    0x00000092f74875bc,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xb9b2411bfd0eafef,
+                    0xc61a10fadd9fecbd,
+                    0x89f128e59811f3fb,
+                    0x980c0f780adadabb,
+                    0x0000009ba1f11320,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d879cbfe24e63b0,
+                    0x61f2d8c07d1a814a,
+                    0x936d93fee33bad06,
+                    0xd4c4920d81a733cd,
+                    0x0000005b862e4a21,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb1ddfacffd532b94,
+                    0x25e295ff76674008,
+                    0x8f00647b48958d36,
+                    0x1159f37d4e0fddb2,
+                    0x000002977770b3d1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96ecd693e4fb381d,
+                    0x37598ec89910ac15,
+                    0xcf5bf8e6f79a6165,
+                    0x65d0c32ddb47aaa9,
+                    0x00000181067a6ac4,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger320([
+                    0xb1ddfacffd532b94,
+                    0x25e295ff76674008,
+                    0x8f00647b48958d36,
+                    0x1159f37d4e0fddb2,
+                    0x000002977770b3d1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaac280a8fe9286af,
+                    0x9fa4f2a1cd44ba77,
+                    0x9d4a47160e8d2477,
+                    0x1a2907de310c1ab4,
+                    0x000001f3edb0ed96,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45c2728c58b86983,
+                    0x9cd82ef1b3adb7c2,
+                    0xf65e30ee0c2c6b12,
+                    0x9b4178683748788d,
+                    0x0000005e02e2d241,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7ce80ad20c59bc24,
+                    0x63fcdf8d6348eedc,
+                    0x3119466cde66e8d3,
+                    0xe8990ba196502a81,
+                    0x00000258d2e94b32,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84c3eafe62d2f8ef,
+                    0x90a06e8b664e6ea9,
+                    0x5f306da57a6916f7,
+                    0x7a6a702f1f848aef,
+                    0x000001c02b426bee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb969fa37c672890d,
+                    0xbf40572b468811d3,
+                    0xb99fa0304b6e9021,
+                    0x5fb2a60856e39fa6,
+                    0x0000011a123444aa,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b6630ac97bc156f,
+                    0x265d1e420846b3dc,
+                    0x7b0777e2096cb8cf,
+                    0x95090ef6f0020964,
+                    0x00000285dcdaaf91,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd4501e0a8d8918ba,
+                    0x7c3500c95a38aa2b,
+                    0x8bbd696020f17c0b,
+                    0x03f8fa0c9c2ae83d,
+                    0x00000111a69e4312,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44705d2fa99427c8,
+                    0x42055f23bdd0de7f,
+                    0xa297f9b9f4d00537,
+                    0xe70915e0a8695f42,
+                    0x000000746a776102,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb5d4b285bbe90bf5,
+                    0xf721edadf87cbcb6,
+                    0xf0d2370db3146b67,
+                    0x25e2346d31927971,
+                    0x0000005e1467b57c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3c520b3135ad4d2,
+                    0x7c3e5333284da0ea,
+                    0xc059ecdb6bd56bc1,
+                    0xf32956a5b32c986f,
+                    0x000003abdd62fc18,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbd688b034a9158f6,
+                    0x942ea0c6b8342de4,
+                    0xe8753f2ec8a83a7b,
+                    0x2948f2e20189c77b,
+                    0x0000009559ef44c8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x845d3abe641a1067,
+                    0x0d5fdd7deca9621c,
+                    0x7cb96744cdda09c7,
+                    0xe75a730b80f421bc,
+                    0x000002ab82e4511a,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger320([
+        0x1bdd4333f4c3b705,
+        0xdcb001ed15b0eeb9,
+        0x66f4515e645ac0ce,
+        0xb065e14d0a251c4d,
+        0x000002a93b06b96f,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xa9d309451b376f8a,
+        0xc74669b0c4b1cb41,
+        0x47f938f5d73495cf,
+        0x325a80138838343a,
+        0x000000f20ca8dc46,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x9d182de6290d824d,
+        0xcd9a4936a5704c7b,
+        0xd09a9ab87a9e0541,
+        0x97a5b1ce7037eb37,
+        0x0000018343df4cf1,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x6aaeec843c13f4ef,
+        0x7b0ce59793a32621,
+        0xe9ccf8cb3af9808d,
+        0xa9500b05ef10bcd2,
+        0x00000134d5c5240e,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x0cf040e4b49533af,
+        0xa9c3dd79340117f4,
+        0x86caf39b678c03f6,
+        0xad36bd8bf51d999a,
+        0x000001ae5f72fe5e,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x9adccc720dfdba5c,
+        0x407980eb30dbc7e3,
+        0x3a6ddb000dc4a65a,
+        0xeaa18cd0c6f385bc,
+        0x000003b8e77428cd,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x812520c9536af420,
+        0xca1d21fdf3125ac3,
+        0xfbe7bca00d4c3a49,
+        0xe0bcc71b9dd46968,
+        0x0000000b543bd11e,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x005d513fcc04ab9e,
+        0x31b90296c160d202,
+        0xdfd82bdd914bd3fb,
+        0xec665c52abf896c7,
+        0x000001a6c6a34578,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x710ac9f3f206cbe7,
+        0x086b73bb984d57a3,
+        0x30b495d3a6d543cc,
+        0x86650159921e0680,
+        0x0000037651806d09,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x0b76cde2cfe6e624,
+        0xafc4ca3fb40f433f,
+        0x851ddfdc4e5e245b,
+        0x0f6caee4dadac7a3,
+        0x0000035ee794696d,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xf5d4fd76cec94e7d,
+        0x489e044aad064f23,
+        0x7ffe63bbb2b79bf3,
+        0x64470bf8efc081dc,
+        0x000001dfa513e3ef,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xe4e7d2a43cd246d6,
+        0x5f473bd0cdbd6793,
+        0x2f05bb4c0e3a970c,
+        0x5e61798c377a3e4d,
+        0x000002a9ddb539bf,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xd2bce5bef8a9ddc0,
+        0x367d8cb8f63d77ad,
+        0xcd300cd283923cdf,
+        0xbe8ef668fe05d593,
+        0x000000764eeefbc4,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x73f83b665c0e58b7,
+        0xf6c861e62da32a0c,
+        0xdbdc59ce2a8cee65,
+        0x586f5917a29f0c40,
+        0x0000002d1a61b8c1,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xaa366cfba20ca45a,
+        0xc25be94557b5f368,
+        0x52b4ec9e75853429,
+        0x7e9dbd9813d2a7db,
+        0x000002433a000587,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x3cbcf82532cc970e,
+        0x31e059d0bde8be12,
+        0xbe80862916a76cd9,
+        0x9b698553f677efe0,
+        0x000002c871319dfb,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x70fc7d4c2730e16c,
+        0x41373070a78d4170,
+        0xdc41760145a3a2da,
+        0x47ecd01350766b61,
+        0x000002ad591d346c,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x1b13c81fb7189876,
+        0x6a32e075247c4923,
+        0xb09303d24f41469f,
+        0x3537ed4656a1fa9d,
+        0x00000329d06345fc,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xe37793a048cc4e7a,
+        0xe7c179d24034c5bf,
+        0x78334c2efdb06ac5,
+        0x98476479f64c45e2,
+        0x0000026523879767,
+    ]))],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger320([
+        0x21a0406cc189aaa8,
+        0x1e7ebea218b90671,
+        0x89ba6e3a0830785c,
+        0xee96557bcf54b55f,
+        0x00000395899902b3,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xc06325b2093e3ffd,
+        0x992c30b1e5e685d5,
+        0x02d73bf40bbcffd9,
+        0xb3cdfb4aa7d898ea,
+        0x000001a2783229e7,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x8e363c7277c0412a,
+        0x28ec233621b8c7d3,
+        0xfb6be61cb78bb3c5,
+        0x523a0ed47a682b44,
+        0x00000288f867033f,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xe5e0648601896490,
+        0x0097bcb9a5276e3a,
+        0x2f3184d0ecf95a52,
+        0xe6fa3b97b8528bf6,
+        0x000002e2ee34042e,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xb2f088c7586ab887,
+        0x19312cb3afefe995,
+        0x042b2d3f491a262e,
+        0x20a69f34396287b6,
+        0x00000094514efc9f,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x38a3946f18f4de9e,
+        0xd3d8e2764fb20e8a,
+        0x586febe2d38c77f1,
+        0x899f76422443ebe0,
+        0x000002ea638f551b,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x20f0378b9de4d19c,
+        0xc42725d2a5870f8b,
+        0xd2700f3eedd8895c,
+        0xccc7ca671723d03f,
+        0x000002b9e4b079c7,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x1b98851393d21187,
+        0x6d2a01399f36f811,
+        0x83f0ce2136d74946,
+        0xea0f1d4f7d6d0fc0,
+        0x000000e36dd083fb,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x19750e876b42f228,
+        0x56998d59abae554d,
+        0xa83b8248d0583388,
+        0xe719d9768f2e5a21,
+        0x000003606c64750b,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xeebe711ca53acc46,
+        0xe908531d0e3ee5e4,
+        0x2fd975b24880d687,
+        0x983551fb2ca99770,
+        0x0000015fba986853,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xf11373557f32f39c,
+        0x36a8988ecd038594,
+        0x8d71fc63c9b1f7e2,
+        0x5c49d31193085239,
+        0x000002ab8a0d76d0,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xd18ca1a7411c5446,
+        0x3e5187dde48939d0,
+        0x90afb3344e4aafec,
+        0x8e22b3d3cba6315c,
+        0x0000036abb7858ab,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x822dea9be8fbf784,
+        0x55c7038793350b79,
+        0x62a0ce22e2ea925c,
+        0xd6febce20c109b56,
+        0x00000313ca56a531,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xd032735f09df8e48,
+        0xa9982089879e402f,
+        0xc2093aa154d29ed6,
+        0x6a2ed176527aa86a,
+        0x0000039ecdfcf7fa,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xff0b0713bbb1194f,
+        0xa616556fcdc6a3ca,
+        0x0ab53516c12fe06e,
+        0x690748e3ff9def59,
+        0x000002f6a51c424a,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xd088a8b298463f1b,
+        0x864c4317fde84c1a,
+        0x69c7c727fc8c3f6e,
+        0x4f6044ebee6663c3,
+        0x0000006fb8e01dc0,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x0b12f15e0e0251f5,
+        0xb8d5075420608727,
+        0x965bb0f077ac106c,
+        0xa8b6f0c8d024bbe9,
+        0x00000031b46c0174,
+    ]))],
+    [Felt::new(BigInteger320([
+        0x40e60c88aa8281c3,
+        0x8cb28036062c7310,
+        0xa2a2392cf55a11d5,
+        0x8365b58b9aa18326,
+        0x000000e910330aec,
+    ]))],
+    [Felt::new(BigInteger320([
+        0xd4ffd45b1631ec8b,
+        0x73905f377564e4f4,
+        0x7e0db630a60c36e5,
+        0x9e3e283bb34b1114,
+        0x000001fcd1445145,
+    ]))],
+];
@@ -0,0 +1,651 @@
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x1bdd4333f4c3b705,
+            0xdcb001ed15b0eeb9,
+            0x66f4515e645ac0ce,
+            0xb065e14d0a251c4d,
+            0x000002a93b06b96f,
+        ])),
+        Felt::new(BigInteger320([
+            0xf11e3b0e560b6547,
+            0x3a7d9ea76edd993d,
+            0x83e2396c711e480b,
+            0x4aa7d169c4e963f0,
+            0x0000027916f8befb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa9d309451b376f8a,
+            0xc74669b0c4b1cb41,
+            0x47f938f5d73495cf,
+            0x325a80138838343a,
+            0x000000f20ca8dc46,
+        ])),
+        Felt::new(BigInteger320([
+            0x1598328fda437a18,
+            0xe6cc0347b3f49c58,
+            0x101a72970f315209,
+            0x33c5a1f0c691a993,
+            0x0000031b59a88da4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9d182de6290d824d,
+            0xcd9a4936a5704c7b,
+            0xd09a9ab87a9e0541,
+            0x97a5b1ce7037eb37,
+            0x0000018343df4cf1,
+        ])),
+        Felt::new(BigInteger320([
+            0x988523659ac8a98c,
+            0x6e769270bbbe2dd2,
+            0xd9f7e1f2c3511b72,
+            0xf94bfab297ba51b1,
+            0x000003817d001729,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6aaeec843c13f4ef,
+            0x7b0ce59793a32621,
+            0xe9ccf8cb3af9808d,
+            0xa9500b05ef10bcd2,
+            0x00000134d5c5240e,
+        ])),
+        Felt::new(BigInteger320([
+            0x61da5a302c14a9d6,
+            0xac5dd73f823c51c9,
+            0xe77e2cc27404d115,
+            0x1f65b16f4914c607,
+            0x000000d10e35d17e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0cf040e4b49533af,
+            0xa9c3dd79340117f4,
+            0x86caf39b678c03f6,
+            0xad36bd8bf51d999a,
+            0x000001ae5f72fe5e,
+        ])),
+        Felt::new(BigInteger320([
+            0x5e2100d88ae937e9,
+            0x11338a93b648fa7a,
+            0x8ccda3da524e213a,
+            0xdee79a0dfdac44d6,
+            0x00000340f79f22f7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9adccc720dfdba5c,
+            0x407980eb30dbc7e3,
+            0x3a6ddb000dc4a65a,
+            0xeaa18cd0c6f385bc,
+            0x000003b8e77428cd,
+        ])),
+        Felt::new(BigInteger320([
+            0xd8464041620bef57,
+            0x02ba11a2a4424047,
+            0x3dca11149126c9fe,
+            0x103f74e2cfd3fab3,
+            0x000000a28b59dd3f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x812520c9536af420,
+            0xca1d21fdf3125ac3,
+            0xfbe7bca00d4c3a49,
+            0xe0bcc71b9dd46968,
+            0x0000000b543bd11e,
+        ])),
+        Felt::new(BigInteger320([
+            0x17708b92d15aec84,
+            0xb0c065d025cf601d,
+            0xbd70a369e8d2d59c,
+            0x290be49e66327849,
+            0x0000020a44689072,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x005d513fcc04ab9e,
+            0x31b90296c160d202,
+            0xdfd82bdd914bd3fb,
+            0xec665c52abf896c7,
+            0x000001a6c6a34578,
+        ])),
+        Felt::new(BigInteger320([
+            0xf3a160e71af26386,
+            0xfd59b07dbb393857,
+            0xbf006b6824556ae2,
+            0x5d42dd820f08e025,
+            0x00000231d274c895,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x710ac9f3f206cbe7,
+            0x086b73bb984d57a3,
+            0x30b495d3a6d543cc,
+            0x86650159921e0680,
+            0x0000037651806d09,
+        ])),
+        Felt::new(BigInteger320([
+            0x0791406f8a91b75f,
+            0xb51895ac4d807d9f,
+            0xacb5bd083feb6d1e,
+            0x1cf5381a0fc150fa,
+            0x00000140710d28b2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0b76cde2cfe6e624,
+            0xafc4ca3fb40f433f,
+            0x851ddfdc4e5e245b,
+            0x0f6caee4dadac7a3,
+            0x0000035ee794696d,
+        ])),
+        Felt::new(BigInteger320([
+            0x473c67aa35d361b7,
+            0x14332f4bd4c54ee6,
+            0xd5d2c151c95eeb7d,
+            0xb5beca90943552c6,
+            0x000002587f0f54d8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf5d4fd76cec94e7d,
+            0x489e044aad064f23,
+            0x7ffe63bbb2b79bf3,
+            0x64470bf8efc081dc,
+            0x000001dfa513e3ef,
+        ])),
+        Felt::new(BigInteger320([
+            0x4fbf14e4a1588ef5,
+            0xab608d375d112330,
+            0xd456d8998251cd38,
+            0x21c2d7b5d37a84a6,
+            0x000001687f23787f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xe4e7d2a43cd246d6,
+            0x5f473bd0cdbd6793,
+            0x2f05bb4c0e3a970c,
+            0x5e61798c377a3e4d,
+            0x000002a9ddb539bf,
+        ])),
+        Felt::new(BigInteger320([
+            0x30944ca666c8e123,
+            0xad1358a5cb8f3797,
+            0xe9d203d1596ca35c,
+            0x4ca11b2c5f97399c,
+            0x000001c6c9cb30f1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd2bce5bef8a9ddc0,
+            0x367d8cb8f63d77ad,
+            0xcd300cd283923cdf,
+            0xbe8ef668fe05d593,
+            0x000000764eeefbc4,
+        ])),
+        Felt::new(BigInteger320([
+            0xdc98a497eb7118cc,
+            0x938a699268214de7,
+            0x255a49e036a49feb,
+            0x06b9e08375b48999,
+            0x000002f10fc7f2f4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x73f83b665c0e58b7,
+            0xf6c861e62da32a0c,
+            0xdbdc59ce2a8cee65,
+            0x586f5917a29f0c40,
+            0x0000002d1a61b8c1,
+        ])),
+        Felt::new(BigInteger320([
+            0xbfdbe1f1b37491a9,
+            0xf5d3dd7adc044255,
+            0xaafd8cbbf1b9370b,
+            0xa8809bc845bf6d15,
+            0x000001ddaae0ae33,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xaa366cfba20ca45a,
+            0xc25be94557b5f368,
+            0x52b4ec9e75853429,
+            0x7e9dbd9813d2a7db,
+            0x000002433a000587,
+        ])),
+        Felt::new(BigInteger320([
+            0xac0eeb6876862d30,
+            0xbc3c904ac00dbe84,
+            0x98c80dcd0b966a09,
+            0xcfd0db89c13b0fb0,
+            0x000003a8d6e7c0c7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x3cbcf82532cc970e,
+            0x31e059d0bde8be12,
+            0xbe80862916a76cd9,
+            0x9b698553f677efe0,
+            0x000002c871319dfb,
+        ])),
+        Felt::new(BigInteger320([
+            0xd2272f5bbcd5d7c9,
+            0xa784a22a09648df9,
+            0x13e39b20cde275c6,
+            0xba585f167a78f182,
+            0x00000134caf703e1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x70fc7d4c2730e16c,
+            0x41373070a78d4170,
+            0xdc41760145a3a2da,
+            0x47ecd01350766b61,
+            0x000002ad591d346c,
+        ])),
+        Felt::new(BigInteger320([
+            0x6a0de71c099b229b,
+            0x0f5c16347cb1bc93,
+            0xc3aa6985da8500dc,
+            0x6f6088a2a253b585,
+            0x000002327f592d05,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x1b13c81fb7189876,
+            0x6a32e075247c4923,
+            0xb09303d24f41469f,
+            0x3537ed4656a1fa9d,
+            0x00000329d06345fc,
+        ])),
+        Felt::new(BigInteger320([
+            0xc0a7e7cddb319b62,
+            0xb26f4b64a293b005,
+            0x8571925b2af6cd3a,
+            0xa377530da06038a1,
+            0x000000402476c836,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xe37793a048cc4e7a,
+            0xe7c179d24034c5bf,
+            0x78334c2efdb06ac5,
+            0x98476479f64c45e2,
+            0x0000026523879767,
+        ])),
+        Felt::new(BigInteger320([
+            0x6ca3e5f50aab6bc7,
+            0x05a04f9818254886,
+            0x7bb74e683bc83840,
+            0xf1a02027eece0024,
+            0x00000204c2c66ce9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x682074ca9ddbaa95,
+            0x84589dbd2eec550d,
+            0x5718b001a761483a,
+            0x19c792ae51b9b741,
+            0x00000140f0689039,
+        ])),
+        Felt::new(BigInteger320([
+            0xf0dd4c27b6025ee3,
+            0xcc59188328d17e6a,
+            0x637a91f989e2ed7a,
+            0x32d76a4f24a01f53,
+            0x0000013b37ea7515,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x21a0406cc189aaa8,
+            0x1e7ebea218b90671,
+            0x89ba6e3a0830785c,
+            0xee96557bcf54b55f,
+            0x00000395899902b3,
+        ])),
+        Felt::new(BigInteger320([
+            0xfcfbbdba63090a29,
+            0x4c4844a2ce7b5b05,
+            0xc078e5d646c24281,
+            0x120cc52f1d68c3c4,
+            0x000003a617bf2d6c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc06325b2093e3ffd,
+            0x992c30b1e5e685d5,
+            0x02d73bf40bbcffd9,
+            0xb3cdfb4aa7d898ea,
+            0x000001a2783229e7,
+        ])),
+        Felt::new(BigInteger320([
+            0x76ff9fcc0881fbc9,
+            0x8d6467879f619628,
+            0x19da3bc01893bd67,
+            0x9c077820d1d18076,
+            0x0000004f7fa92bfe,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8e363c7277c0412a,
+            0x28ec233621b8c7d3,
+            0xfb6be61cb78bb3c5,
+            0x523a0ed47a682b44,
+            0x00000288f867033f,
+        ])),
+        Felt::new(BigInteger320([
+            0xd47a82c129b319a7,
+            0x9e7b09af023ee866,
+            0x53aaf381d518cb49,
+            0x9aaeb2b18d8a03f2,
+            0x0000010aebff1e30,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xe5e0648601896490,
+            0x0097bcb9a5276e3a,
+            0x2f3184d0ecf95a52,
+            0xe6fa3b97b8528bf6,
+            0x000002e2ee34042e,
+        ])),
+        Felt::new(BigInteger320([
+            0xe32657a531c1cab6,
+            0x01e497a7f05643f2,
+            0x46b3485657d2edc3,
+            0xe6446197a5a65bed,
+            0x000002bfd8d8d6ca,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb2f088c7586ab887,
+            0x19312cb3afefe995,
+            0x042b2d3f491a262e,
+            0x20a69f34396287b6,
+            0x00000094514efc9f,
+        ])),
+        Felt::new(BigInteger320([
+            0x0a3bce2e6ef66e00,
+            0x509cc3148ecd762c,
+            0x23fe6d0c65aa865a,
+            0xdb8bfb4cd540f9b4,
+            0x000002679baf4664,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x38a3946f18f4de9e,
+            0xd3d8e2764fb20e8a,
+            0x586febe2d38c77f1,
+            0x899f76422443ebe0,
+            0x000002ea638f551b,
+        ])),
+        Felt::new(BigInteger320([
+            0x7c278db1ad3ac4d8,
+            0x66155c741fae30fe,
+            0x759cb18588bcde7e,
+            0x3871ddeac0742799,
+            0x00000014b9a92eb9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x20f0378b9de4d19c,
+            0xc42725d2a5870f8b,
+            0xd2700f3eedd8895c,
+            0xccc7ca671723d03f,
+            0x000002b9e4b079c7,
+        ])),
+        Felt::new(BigInteger320([
+            0x0212f3245c0c7b3e,
+            0x7f7d06e3a27c5421,
+            0xe2dabc339e392603,
+            0xfbe542e2cb215153,
+            0x0000013c8f5489d3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x1b98851393d21187,
+            0x6d2a01399f36f811,
+            0x83f0ce2136d74946,
+            0xea0f1d4f7d6d0fc0,
+            0x000000e36dd083fb,
+        ])),
+        Felt::new(BigInteger320([
+            0x14f71a2e22f77aae,
+            0x08c69866f5a50877,
+            0x7ce99d39fbaf2317,
+            0xe4201e1573cd1fe0,
+            0x000001af2bd62c44,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x19750e876b42f228,
+            0x56998d59abae554d,
+            0xa83b8248d0583388,
+            0xe719d9768f2e5a21,
+            0x000003606c64750b,
+        ])),
+        Felt::new(BigInteger320([
+            0xb6160a7644058edf,
+            0xd3429890bd772558,
+            0x3e0d390b9b3c9fc3,
+            0x06de8fcda0216b5e,
+            0x0000016b3e2555e1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xeebe711ca53acc46,
+            0xe908531d0e3ee5e4,
+            0x2fd975b24880d687,
+            0x983551fb2ca99770,
+            0x0000015fba986853,
+        ])),
+        Felt::new(BigInteger320([
+            0x309e90574b5ef918,
+            0x1d72a16f8b8a9b9c,
+            0x9a5ee6b5f54fe092,
+            0xc7bbed3d7953e955,
+            0x0000009a044778eb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf11373557f32f39c,
+            0x36a8988ecd038594,
+            0x8d71fc63c9b1f7e2,
+            0x5c49d31193085239,
+            0x000002ab8a0d76d0,
+        ])),
+        Felt::new(BigInteger320([
+            0x5118103691f9e553,
+            0x69670ac1d9a403b1,
+            0xfb9b00cfcb1a6c10,
+            0xa2fa1e650a121bc5,
+            0x000002751651308c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd18ca1a7411c5446,
+            0x3e5187dde48939d0,
+            0x90afb3344e4aafec,
+            0x8e22b3d3cba6315c,
+            0x0000036abb7858ab,
+        ])),
+        Felt::new(BigInteger320([
+            0x2353a11cab4a9fd2,
+            0x5c198df93ef0b3e4,
+            0x654c8b47cb4aff25,
+            0x0596d50a8712f36e,
+            0x000002c859c2750a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x822dea9be8fbf784,
+            0x55c7038793350b79,
+            0x62a0ce22e2ea925c,
+            0xd6febce20c109b56,
+            0x00000313ca56a531,
+        ])),
+        Felt::new(BigInteger320([
+            0xd6e0fa441bfae3ce,
+            0x87867d9fcf5120ef,
+            0x09acd15b6ad6f6bc,
+            0x05f801f56f5ec18f,
+            0x0000021245a6ed1a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd032735f09df8e48,
+            0xa9982089879e402f,
+            0xc2093aa154d29ed6,
+            0x6a2ed176527aa86a,
+            0x0000039ecdfcf7fa,
+        ])),
+        Felt::new(BigInteger320([
+            0x66ed6ab9a17d7878,
+            0x7d56395d003797b5,
+            0xe00c33b9f0d8e8d0,
+            0xa10e6f1fe13a7b72,
+            0x000001d318f33e25,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xff0b0713bbb1194f,
+            0xa616556fcdc6a3ca,
+            0x0ab53516c12fe06e,
+            0x690748e3ff9def59,
+            0x000002f6a51c424a,
+        ])),
+        Felt::new(BigInteger320([
+            0x4bbad64fd0625363,
+            0x74a999b40056ae23,
+            0x9faa1c702c1b17a2,
+            0xa108c1ce98a5c961,
+            0x000000dffc7b4e43,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd088a8b298463f1b,
+            0x864c4317fde84c1a,
+            0x69c7c727fc8c3f6e,
+            0x4f6044ebee6663c3,
+            0x0000006fb8e01dc0,
+        ])),
+        Felt::new(BigInteger320([
+            0x27509a0062873116,
+            0xc735c0bf385730e6,
+            0xa3ea35114289ccd9,
+            0x99e9c2e2ad6780b5,
+            0x000002d9bc967d46,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0b12f15e0e0251f5,
+            0xb8d5075420608727,
+            0x965bb0f077ac106c,
+            0xa8b6f0c8d024bbe9,
+            0x00000031b46c0174,
+        ])),
+        Felt::new(BigInteger320([
+            0xc582154530a44464,
+            0x523f2265e478172d,
+            0x6283fd669b4ff2ec,
+            0xfbc54d8c5d02215e,
+            0x000003b48498f3ad,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x40e60c88aa8281c3,
+            0x8cb28036062c7310,
+            0xa2a2392cf55a11d5,
+            0x8365b58b9aa18326,
+            0x000000e910330aec,
+        ])),
+        Felt::new(BigInteger320([
+            0xa7d7e64e0ed335ef,
+            0xa03420737336eed6,
+            0x5c4020a75fd21cee,
+            0x1d3fbf871f5fdc7b,
+            0x000001fd0e3786c7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd4ffd45b1631ec8b,
+            0x73905f377564e4f4,
+            0x7e0db630a60c36e5,
+            0x9e3e283bb34b1114,
+            0x000001fcd1445145,
+        ])),
+        Felt::new(BigInteger320([
+            0x6446ac231848bb17,
+            0x616b1e43a9eb11cb,
+            0x9b6247f815f24748,
+            0x80cb63803f1c9218,
+            0x000001dd22b74bf4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x747ccb73b2ad16e8,
+            0x66f8778dd0e1133d,
+            0xcb91d70ae70b831a,
+            0xd2be8f999cf87faa,
+            0x00000109019f7ec1,
+        ])),
+        Felt::new(BigInteger320([
+            0x035428440b0b7c75,
+            0x7ef4db9a275be6ab,
+            0xf1c44890fb5b6b43,
+            0x7502e6d1032eae7e,
+            0x00000143fb5588ca,
+        ])),
+    ],
+];
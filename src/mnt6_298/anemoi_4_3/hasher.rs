@@ -0,0 +1,881 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger320, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![Felt::new(BigInteger320([
+            0x5da3d8e37651ab5c,
+            0x74d2d7b006925ac5,
+            0xc62d740b6be03a31,
+            0xc4fd5c30a61383ae,
+            0x00000025ac069dd5,
+        ]))],
+        vec![
+            Felt::new(BigInteger320([
+                0x629b407726255210,
+                0x5c9869b779753e0e,
+                0x5a25edcec8a43133,
+                0x27bc2a1284a16de7,
+                0x00000078013ae56e,
+            ])),
+            Felt::new(BigInteger320([
+                0x668bf062087008dd,
+                0xf1e7bb27cdf395e1,
+                0x8c18e73ca552e4b9,
+                0x767c17901c0e97c2,
+                0x0000024aca3ef784,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xa81e90af6ad61f5b,
+                0x608e1ee738d92ad8,
+                0x1eb0ee49e4f13edb,
+                0x30ec9a102e769d7e,
+                0x000001af890134ea,
+            ])),
+            Felt::new(BigInteger320([
+                0xcfdd1bbb4efcae88,
+                0x4ed3d7e26cee78cd,
+                0x6335fef4628bb35f,
+                0xf543a2a4d51b19ca,
+                0x000003a3b4cb0d78,
+            ])),
+            Felt::new(BigInteger320([
+                0x3b2fd4e79592a740,
+                0xc9a52830cc40fc7f,
+                0xb35bc8cc8c44db53,
+                0x550b4caf5e95c272,
+                0x0000039283cbefb8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xc31349b89b2a8ffc,
+                0x28c44f075fd41473,
+                0x9780149545fd3b1a,
+                0x654df9a7c7e069b5,
+                0x00000256c2b085b4,
+            ])),
+            Felt::new(BigInteger320([
+                0xb82d367e6947744b,
+                0x23b3241cfc5bdad5,
+                0xb030918d1b016591,
+                0x82ffb1b0b1547ead,
+                0x00000010310942aa,
+            ])),
+            Felt::new(BigInteger320([
+                0x6408b9c0af3598f9,
+                0xee6e6b1557ecaf94,
+                0x8fd2d31f80d32f89,
+                0x549ff49a02dc46a2,
+                0x000002baaa359d8b,
+            ])),
+            Felt::new(BigInteger320([
+                0x27571f4eeff408c2,
+                0x9e19b8894131b11a,
+                0x0a952d860552d8db,
+                0x47e26b6d2ca37345,
+                0x000003a8017012c3,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xfe1892349badfe3b,
+                0x3176c93e383a9f90,
+                0x5784e8c741e1f2f3,
+                0x3470305fe0b706c9,
+                0x0000004872331a36,
+            ])),
+            Felt::new(BigInteger320([
+                0x6b0ede2d8f1ebcd5,
+                0xcbdae5f764ea8719,
+                0xffc41a9cda73b329,
+                0xf6719a6a9228ef75,
+                0x000001278548344a,
+            ])),
+            Felt::new(BigInteger320([
+                0xa80aa7e3dd73e807,
+                0x41de1fc02ae0f6d0,
+                0xaf353a4877aa8f54,
+                0x9571eb053cbd260d,
+                0x000002198adc64e4,
+            ])),
+            Felt::new(BigInteger320([
+                0xf0d8bc63564c5f7d,
+                0xd897a42d2d3e4249,
+                0xfdc1b4f2d87a1960,
+                0x945d7a3af5859c06,
+                0x0000020134e8751c,
+            ])),
+            Felt::new(BigInteger320([
+                0x7b047ec5d61700fc,
+                0x50d5fbbc7e6fbdea,
+                0x17afc0cd70304d4e,
+                0x05eb915095821930,
+                0x0000006afbd0191e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xd0dc9a16bcd1c412,
+                0xddbcc8950db4c1da,
+                0xe268aad46b7c2655,
+                0xeb64e493a7a69288,
+                0x000002730411cda6,
+            ])),
+            Felt::new(BigInteger320([
+                0x261329b02de7a60e,
+                0xf74499e793884a85,
+                0xba50f1986dd1c722,
+                0xe4b349e6acbe1b5b,
+                0x00000212c234aa98,
+            ])),
+            Felt::new(BigInteger320([
+                0x67f83a1020327a34,
+                0x8b9e05338cd40502,
+                0xfee7b04383535bc8,
+                0x13b0daab126f281f,
+                0x00000005ad3fe486,
+            ])),
+            Felt::new(BigInteger320([
+                0xe3c1eed60a331333,
+                0x44bb893d7e342c54,
+                0xf6e4fb821948e65b,
+                0xf9d7abd06cd26ab2,
+                0x000003521a21fcf3,
+            ])),
+            Felt::new(BigInteger320([
+                0xd27e628fceeb3d32,
+                0x1bd9b25ff8799a99,
+                0x0baed52d73c75865,
+                0xcf88f8bab8e53509,
+                0x0000035cd1c03cd0,
+            ])),
+            Felt::new(BigInteger320([
+                0xb5e176954c8395f0,
+                0x0a60f211b6229e5a,
+                0x25324baf69cb58ac,
+                0xeaecbf93e8b7cad4,
+                0x0000011643abd12a,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x49ebee3fc8181078,
+            0xc7eaefaab2f4025e,
+            0x65cb75c0a8b668c5,
+            0x3d3863c86cc80f95,
+            0x000002ad4fae1dbd,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x52d021b348c23de8,
+            0xe191b00b337f56af,
+            0x0d4cd3952cb25577,
+            0x7697c1307369131f,
+            0x0000033750d76ff4,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xcf57daeabea740d1,
+            0x8928e17c349b9db5,
+            0x7b8dd3a16d7dbd59,
+            0x8b61aad97b8339ea,
+            0x000002f179c3dd98,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xa095a59dfbc09a8f,
+            0xe355fbdb90efe832,
+            0x9ef4868357a0c103,
+            0xf1c2aa274e397306,
+            0x000003a6089e0f83,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x9472ce2feb378dfd,
+            0xe3314550dd099203,
+            0x206caa4725ceaf95,
+            0x2155ad6cd8f9fd19,
+            0x0000035ee14feed0,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x6287ff4ce531bd7a,
+            0xbb0deee6a4aee50b,
+            0x4a3a52740bf69360,
+            0xeafa5366dfa6a699,
+            0x000001c1ce045231,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xaa128b4d6546b498,
+            0x9b7eff648400a4ce,
+            0x461c7f3f3528a68d,
+            0x768eca9b100d7d46,
+            0x000000ffe33112b8,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x1fa2301d0175bc70,
+            0x65ede8757726cb70,
+            0xa10f910298dad201,
+            0xb1dca6b9f858cb62,
+            0x00000072f5d43134,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x2f0be5c795699df7,
+            0xddc771e110fc1a70,
+            0x5fd78286053bd6eb,
+            0x920dccf0ea2cf5b2,
+            0x0000037015e4963c,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x3ae7841633076055,
+            0x8a7469b6e2f0df0e,
+            0x6a1ec87fe89fbf11,
+            0x21d7cda00158aeea,
+            0x00000057b989d50c,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger320([
+                0x7d684e73b666e10c,
+                0x8788fb54412b2422,
+                0xaf0f67a556e24651,
+                0x8e1a8b1b1a40a4d0,
+                0x000000e8a4fc6cb6,
+            ])),
+            Felt::new(BigInteger320([
+                0x7e6b490cca5c1b08,
+                0x0a2755514368beb3,
+                0x28da31892f31235e,
+                0x640b21f994c83faf,
+                0x000003037f71fc25,
+            ])),
+            Felt::new(BigInteger320([
+                0x819f00fce62b51b3,
+                0x1e09d2d0a3c9648f,
+                0xaa834f3f2364ba04,
+                0x5be7f32b1ee9aca8,
+                0x000002af54327607,
+            ])),
+            Felt::new(BigInteger320([
+                0x76a6d2eac1ac08e6,
+                0x643fa5e35a1e4675,
+                0xae93793a614d38ee,
+                0xaae0fa419a28d8cf,
+                0x0000018ae97927b3,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x857cb1cc2e47c069,
+                0xc8406cdc76b3d008,
+                0xb337c97638316c69,
+                0x9e8e6eb8b6cf54d0,
+                0x000001ca86c226e8,
+            ])),
+            Felt::new(BigInteger320([
+                0xbb39456369b82ff5,
+                0xcea0c231870e4244,
+                0x4aee56d71777b7af,
+                0xd2e3a0404d98404d,
+                0x0000032d5b05aef9,
+            ])),
+            Felt::new(BigInteger320([
+                0xde6ec5b35f8d0012,
+                0x5bbb23862c08d60a,
+                0x5173e193788d4ff7,
+                0xd7206069a7cef420,
+                0x000002aac97395fb,
+            ])),
+            Felt::new(BigInteger320([
+                0x92bd2c7fada5216d,
+                0xb504e5c059545648,
+                0x2f4c35a6186cf6a4,
+                0x1124dc09bc761c0b,
+                0x0000015cc5584576,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x671ba2f9f8535d10,
+                0x33b8bb10e16bae96,
+                0xcd290ec3298c80c1,
+                0xf69d1e9ab454d3cb,
+                0x00000049cc1a46d3,
+            ])),
+            Felt::new(BigInteger320([
+                0x455a414f66943d7c,
+                0xf1d8b1f87706608a,
+                0x5f7e79f5df70bc32,
+                0xb1ffa0a8583d3bb9,
+                0x000001ad55554100,
+            ])),
+            Felt::new(BigInteger320([
+                0xe350b79d7cb537a6,
+                0xdc61a829e61e48c1,
+                0xe5544ae08f9b8771,
+                0x04df7b5a37178761,
+                0x000001476120c7a3,
+            ])),
+            Felt::new(BigInteger320([
+                0xd8cf6de1d53b2549,
+                0xcd063ca42e6e3a33,
+                0xb83d950f5e4cd1df,
+                0x60c5d57541c5e911,
+                0x000001f3b7027a3b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x96c598423745e7dd,
+                0xdb3f47513412ac65,
+                0x04f8c672820387f4,
+                0x08ef710cfa1d9c66,
+                0x000000edebe98613,
+            ])),
+            Felt::new(BigInteger320([
+                0xf178eb8dac963231,
+                0x512bd6792e1c7aa5,
+                0x55dfd0bed8a65b24,
+                0xf8a6e15b75072d93,
+                0x000000c0be0051ae,
+            ])),
+            Felt::new(BigInteger320([
+                0x58d2b9ccf509b71d,
+                0xe98ab8f0bda930b5,
+                0x47629a287a3a3262,
+                0x0277d239222043de,
+                0x000002215e7e4159,
+            ])),
+            Felt::new(BigInteger320([
+                0x3f4a1d7d68161e30,
+                0xa570ae72a528d0cc,
+                0xec2123cce575c4da,
+                0x2df1bb4ea017445e,
+                0x000003397a04746c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x62ebf309591e10f6,
+                0xbccdb6affbce0738,
+                0x92fa5ffeea0219ef,
+                0x2a98773482c5fcf9,
+                0x00000050ccba8440,
+            ])),
+            Felt::new(BigInteger320([
+                0xe53b09fcdf3e0367,
+                0xaaf6aef571a6ff6e,
+                0x7a506a3459baa8aa,
+                0x63ae455427f72ccf,
+                0x000002b86aecd4ea,
+            ])),
+            Felt::new(BigInteger320([
+                0xe64fa6d967ce5767,
+                0x53d3af5d6086df1b,
+                0xe03bbad03adf1a3b,
+                0xec3be189ea484711,
+                0x000001e06722321b,
+            ])),
+            Felt::new(BigInteger320([
+                0xd24a0092731be5cb,
+                0xd01f49c17d3d4b88,
+                0x1ac531f4c2722330,
+                0x72f83e973003749b,
+                0x000001ca855c9de6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x033ea22d72f03b4e,
+                0xe48784a8a8266714,
+                0x96182dfe22ea5b5d,
+                0xb913a39b49423731,
+                0x000000e981ec38ce,
+            ])),
+            Felt::new(BigInteger320([
+                0xe027751ec0549be7,
+                0xe8f1322391a2cb6c,
+                0xc48624ac2265d267,
+                0x850705fe44b60973,
+                0x000000c6b51c5fab,
+            ])),
+            Felt::new(BigInteger320([
+                0xa35c004e3d3f3afd,
+                0x0c64a5ab356e00db,
+                0x032bd0919ecd0671,
+                0xa116322e7c7d7a98,
+                0x000000797929826b,
+            ])),
+            Felt::new(BigInteger320([
+                0xfe89ed7157f337f9,
+                0x9526cf9fa0e04fe0,
+                0xe66ee82587954b84,
+                0x0eed119e9c94d8d8,
+                0x000000578bef8693,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger320([
+                0xce1dab5a4239cce2,
+                0x0ad817ca52866772,
+                0x999575cdba61b917,
+                0xeb089b9fddff1619,
+                0x000001073dc5f01d,
+            ])),
+            Felt::new(BigInteger320([
+                0x9784a83f85170137,
+                0x325a83857d8e34b1,
+                0xa7c3ae57aff66227,
+                0x6c508e6d1da57846,
+                0x000001446c07efd0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x65e32055b2388890,
+                0xe1308365990a80b6,
+                0x31ebffe4e8ef44bc,
+                0x67f48e16fb23993b,
+                0x000001cb753412d4,
+            ])),
+            Felt::new(BigInteger320([
+                0xccf154b6ea8fea75,
+                0xf7ebb76a2a4f217c,
+                0x5dd0d1abce0a56de,
+                0x01e35f3a33fb27c5,
+                0x0000009551b2204c,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xf47bd4da515c89df,
+                0x96d97969bc795fa3,
+                0x1a7a4e83f4fbca6a,
+                0xc6f507aa83ad52a7,
+                0x000002fe7d25b956,
+            ])),
+            Felt::new(BigInteger320([
+                0x1d85a3d785d689da,
+                0xcf0867bfc4b455f3,
+                0x97b05a926305d37b,
+                0xa13fa3103e636b6a,
+                0x0000032e29494179,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x0575940ae42d743d,
+                0x5ae533420fc2c48e,
+                0xf3687577a46ba5b4,
+                0x58ba817d1d3f11f8,
+                0x0000006d5bdbbfcd,
+            ])),
+            Felt::new(BigInteger320([
+                0xa52d72ff79c0e65b,
+                0x0d8e64e1ab7c30b9,
+                0xe9c40a1ddb4fa549,
+                0xd256c31be25277b2,
+                0x0000036c76e15a56,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x16e981039c379c58,
+                0x63042fc689eb4a89,
+                0x6175cc799964910d,
+                0xde04927206121836,
+                0x000000e143991751,
+            ])),
+            Felt::new(BigInteger320([
+                0x53caedd8d1bc5b71,
+                0xe7bdbc59d7675de3,
+                0x11fa8a2926f78d56,
+                0x1c17fe244e1e5029,
+                0x000001bc811480de,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xbe9731c86c771c32,
+                0x814b8019069afe8f,
+                0x8b0ea869e6fddb8a,
+                0xeb81266b1d05e77a,
+                0x0000007acc4ffbc0,
+            ])),
+            Felt::new(BigInteger320([
+                0xc50ade058c334261,
+                0x59b8d298821da965,
+                0x01cbf42cd98b0252,
+                0xf755aae7ed745b26,
+                0x0000007785c600f3,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x9c0166bed72b77f0,
+                0xbd4a086fa9752eff,
+                0x6771c879b8d5fe9f,
+                0xf8323d14949a135a,
+                0x000000d058ccda24,
+            ])),
+            Felt::new(BigInteger320([
+                0xba81c6ced6f900ae,
+                0x43f0ec1db9fae214,
+                0xeb92a9cd9add572c,
+                0xcd1cfdca199a841f,
+                0x00000338b671a0df,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x68a7016996a3d2d3,
+                0xe885f02d98d4a174,
+                0x13821db55695d649,
+                0x96d80be774e79609,
+                0x0000001e84562223,
+            ])),
+            Felt::new(BigInteger320([
+                0x0084aeac9b168eb9,
+                0x9defa99b02e6c303,
+                0x056163073b401db8,
+                0xecd32d5eac5ad1ff,
+                0x000000ca09bdc317,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xc01f141c7cbe6f3a,
+                0x931758d77b77edbe,
+                0x7010d46f41a339eb,
+                0x34997326f590d0d3,
+                0x00000247a45c03ce,
+            ])),
+            Felt::new(BigInteger320([
+                0xb4ff80902c3b9135,
+                0xeea257b5ce57c372,
+                0x980ebef473b4cbf0,
+                0x618f2eb0321910a1,
+                0x000003053e8e8905,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x68f95f3091ea193d,
+                0xdd5d2915184f9493,
+                0x8813acf61535c2a7,
+                0xc9d25543eca274d6,
+                0x000001e1c20f1414,
+            ])),
+            Felt::new(BigInteger320([
+                0x6605c4cb97f57792,
+                0xd0d20518fb13fabd,
+                0x2efbd16a705cedfe,
+                0x3dcbbafddb352b5a,
+                0x000002f45402dbe0,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger320([
+                0xb18225768cc207d0,
+                0xe18fce3238a38d9f,
+                0x3002de92afb45e21,
+                0xde11a6af61669499,
+                0x0000002e5642fe20,
+            ])),
+            Felt::new(BigInteger320([
+                0xc2df034a125c9739,
+                0x9d24da99f6aa8f98,
+                0x8f86c3d8adfcf1ae,
+                0x87d950c246121116,
+                0x0000035be781b863,
+            ])),
+            Felt::new(BigInteger320([
+                0xdd5f004c1506aa06,
+                0xc403f309e13ccc85,
+                0xfe229841d29c460d,
+                0x21192b362d7ee110,
+                0x000000a33ebcfec2,
+            ])),
+            Felt::new(BigInteger320([
+                0xa8bb2a5d85c090cf,
+                0x090f50f7a6eb1d78,
+                0x92f545794671523d,
+                0x112105e6d136b6f7,
+                0x0000009d6cd65ac8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x5241813e9e0453d3,
+                0xc85bff627f22f774,
+                0x9e5f890af7f3f004,
+                0xfa7dd5d24dc42703,
+                0x000002b25bb90e2a,
+            ])),
+            Felt::new(BigInteger320([
+                0x9b9fe7c574bf60d6,
+                0xf17d08f0c35cc124,
+                0xb45dffa285cb247b,
+                0xb77259318df4dfc1,
+                0x000000e8def36d04,
+            ])),
+            Felt::new(BigInteger320([
+                0x39210a14201ef57f,
+                0xabe8560b0e27ef73,
+                0x6a667bd79dc678d0,
+                0x9a8c65cafe91f71a,
+                0x000003036d17c89c,
+            ])),
+            Felt::new(BigInteger320([
+                0xa8dfb1defc7cbefb,
+                0xe886ad50829cff85,
+                0x3321afa05dea34bd,
+                0xfa48314b00a79788,
+                0x000002bd8809328b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xed45be9915f1ac31,
+                0x4a54b7b7e4e119a6,
+                0x21941f43b9e1ae5c,
+                0xddea1cceac6e6ba8,
+                0x00000054d3d24d7d,
+            ])),
+            Felt::new(BigInteger320([
+                0x5a87b9531eb75863,
+                0x31cc5d1fdc49aa43,
+                0xb44c40c648d56fd2,
+                0xff313b25a91a2c2c,
+                0x00000020c62a98f4,
+            ])),
+            Felt::new(BigInteger320([
+                0x012657245ae386b5,
+                0x415afe993100b548,
+                0x2e6529eb45445cd7,
+                0xb3d92200073614b7,
+                0x0000024f93b3b48d,
+            ])),
+            Felt::new(BigInteger320([
+                0xc4f6fd7c18b952d1,
+                0x06674a2e39b8fd09,
+                0x5aa1f173fb2779e5,
+                0xf453a95e9a1b9be5,
+                0x00000339989a7c02,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x74abecc381738a4b,
+                0x018048a4042898a2,
+                0x9e9051404b0a9c20,
+                0xde243005d21c0c18,
+                0x000001a69355e31c,
+            ])),
+            Felt::new(BigInteger320([
+                0xee352b5e9666299b,
+                0x22002f0fe561ebdb,
+                0x2a01cdaa899ba766,
+                0x4098c63b0c69c487,
+                0x00000044e0bf2096,
+            ])),
+            Felt::new(BigInteger320([
+                0xcbb5d8c6b18c1c7e,
+                0x9975e3c7611660d4,
+                0x8858ac510f786395,
+                0xa35958e63c8b6f19,
+                0x000003a7f65c35ac,
+            ])),
+            Felt::new(BigInteger320([
+                0xb13c90323d060773,
+                0xe00bcfc6f03285bd,
+                0x8ffc857c5cc45f76,
+                0xc1a7b0c5d3c378a0,
+                0x00000060b72df7bc,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xfcf4bb977da0d037,
+                0x31153587a5cd83dd,
+                0x0ef6331a7e6c62dc,
+                0x019d3f800970a786,
+                0x0000003fafb380a4,
+            ])),
+            Felt::new(BigInteger320([
+                0x19fa1770f188e959,
+                0x971a54a04a8e7400,
+                0x3dd99043961403c0,
+                0xef2c7febbf6dca34,
+                0x000000878f292cca,
+            ])),
+            Felt::new(BigInteger320([
+                0x569b804bc98191e9,
+                0x3f5c4564a665ee34,
+                0x94de321e2ba7d112,
+                0xae25e934abe433a2,
+                0x0000006d559386b1,
+            ])),
+            Felt::new(BigInteger320([
+                0xe82d5ce78778af45,
+                0x3bcd308f6437f4b5,
+                0x2aaf30e1a467c1a7,
+                0x2fe857197e97d1c3,
+                0x000000d1f668c0c8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x6084f6a0ba8923e9,
+                0xbafe69328b5ed936,
+                0xe801f55e86b8b880,
+                0x87e02e5ee3f3612f,
+                0x000003327f978b9d,
+            ])),
+            Felt::new(BigInteger320([
+                0xf20326e581261d32,
+                0xa7efc34f18ca3bfc,
+                0xd3f724cf5ccfa666,
+                0x27e9f7f16e93d7c5,
+                0x000000b5d8deadf8,
+            ])),
+            Felt::new(BigInteger320([
+                0x37f2a5c13de849ba,
+                0x326e3bba0ac2fb52,
+                0xd58d7119898192e5,
+                0x0666809f7ca3a094,
+                0x000002aaa116236c,
+            ])),
+            Felt::new(BigInteger320([
+                0x4d9a03ebd233a00c,
+                0x039cb960f95b7df9,
+                0xb086c84a911e673a,
+                0x9056f1e6bcae8705,
+                0x0000000fdc54af9a,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x65a25399c750ce19,
+            0x3d329b4fd0149c24,
+            0x415924256a581b3e,
+            0x57592a0cfba48e60,
+            0x0000024ba9cddfee,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x32d4750c9cc87305,
+            0xd91c3acfc359a233,
+            0x8fbcd190b6f99b9b,
+            0x69d7ed512f1ec100,
+            0x00000260c6e63320,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x56be440dd73313b8,
+            0x6a989521eed04ac3,
+            0xe73bdfb2fb0d5c51,
+            0xc5ce861d1a606982,
+            0x0000026faeb2265c,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xef5fd2665dee5a97,
+            0x6d2a4c1c28e18a73,
+            0x123db63222c70968,
+            0x88ab1ffb57e1351d,
+            0x0000001cdb0045b0,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x42f85f346cf16bb5,
+            0xbb07ae4e60f3fe68,
+            0x95fd8c7c141dea1b,
+            0xe2c2d081d0442131,
+            0x000000514b18ef6a,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x155327f6b0b0ef63,
+            0x4ffd833b314ca3f2,
+            0xa6e94f50a19e0748,
+            0xccbe8717c8cccd0b,
+            0x00000078700d4f8e,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xef1207d67e78fdcd,
+            0x3e9b501cf16009a2,
+            0x64b3903d0d7d0353,
+            0x3e7f1a325ad172e4,
+            0x00000386eab38bf0,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x8a9ec924ecb03bd1,
+            0x7109bb13c71be733,
+            0xb375bf0c5b8fa55c,
+            0x2490898b53da3b38,
+            0x0000027076162f00,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xb28feb9b60840f24,
+            0x9468266b41d964ae,
+            0x4d022f52150e893a,
+            0xdfbdc1300ee1298e,
+            0x000000857c182b4d,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x769943a1b4a40997,
+            0x0ac066f7de7d45f6,
+            0x06c8e644716de174,
+            0xb0fc6f21a47936c9,
+            0x000002280b09682b,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+    }
+}
@@ -0,0 +1,38 @@
+use super::BigInteger320;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [  1  10]
+/// [ 10 101]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0xb1ddfacffd532b94,
+        0x25e295ff76674008,
+        0x8f00647b48958d36,
+        0x1159f37d4e0fddb2,
+        0x000002977770b3d1,
+    ])),
+    Felt::new(BigInteger320([
+        0xb1ddfacffd532b94,
+        0x25e295ff76674008,
+        0x8f00647b48958d36,
+        0x1159f37d4e0fddb2,
+        0x000002977770b3d1,
+    ])),
+    Felt::new(BigInteger320([
+        0x96ecd693e4fb381d,
+        0x37598ec89910ac15,
+        0xcf5bf8e6f79a6165,
+        0x65d0c32ddb47aaa9,
+        0x00000181067a6ac4,
+    ])),
+];
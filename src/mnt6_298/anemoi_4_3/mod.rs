@@ -0,0 +1,1074 @@
+use super::{sbox, BigInteger320, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 152 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (38-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xdc498bbfe30ea11b,
+                    0x6b852f9c231e2b46,
+                    0x4a2c54f21435a964,
+                    0x6a6f507a14fb2e9d,
+                    0x0000019bebb5d764,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6f15246e5e5e504,
+                    0x8e7562bac6cd9d36,
+                    0x01a41992fde69a28,
+                    0xb11278e0719c62f6,
+                    0x0000026acdba3e31,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0586a5a360116d2,
+                    0xef4afa9d8803e404,
+                    0x307bf5ef22c46f3e,
+                    0xb84922fd8d9d230d,
+                    0x00000308b0f9e163,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7afa8d713d01f9e5,
+                    0x227253e2cfe8ef79,
+                    0x5a7657dc1742a0a8,
+                    0x1edd79669d86faea,
+                    0x000001f1b02e5de7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe266f657cb1ef48b,
+                    0x4ac1e09c8262ebcc,
+                    0x3ce9e0e94645cfec,
+                    0x9873fec9d4fb86d3,
+                    0x0000013910178474,
+                ])),
+                Felt::new(BigInteger320([
+                    0x101c99d3e018585c,
+                    0xae52ad12cdaf813b,
+                    0x3dab92a10f603a61,
+                    0xdf1427e52396829b,
+                    0x0000016f5e0d1bad,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d55ec60e102dcb6,
+                    0xacb15efe0e31eb65,
+                    0xca1aa3a8b03d14d1,
+                    0xd1230f66a366a83f,
+                    0x000003137e293836,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68b9c15a7ed08ea7,
+                    0x5fcdcd8cbc58172a,
+                    0x3478cefbc2396b8d,
+                    0x4d1e6f524d714d79,
+                    0x0000035a11d2cb10,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x70dec6b3c566ff43,
+                    0xa1e78a4f50df6ead,
+                    0x6121ac010633d86b,
+                    0xe9c7909d2c273825,
+                    0x0000026e77328c20,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30c1d6fc4e929348,
+                    0xd6c8864be03dfaee,
+                    0xe94865599576a303,
+                    0xa1f0150df1d39944,
+                    0x0000014111a63216,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cb79ae915b5009d,
+                    0x41abb31baaa57ae2,
+                    0x96f914150d2a6dc8,
+                    0x2fbe0fa91aa0da72,
+                    0x0000006a03927d72,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7711e78b4e2a6c1,
+                    0x59d26a4c2c153cc7,
+                    0xcdb2887e33df4d65,
+                    0x1f008e3a946e5f4d,
+                    0x0000017ccf402c69,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb4932b5358acfcdd,
+                    0xb43edaec4c14304b,
+                    0x4568cabae224b487,
+                    0x1bdc17f0e349129b,
+                    0x000002610edce344,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd00fd208a89e495,
+                    0xa90a6c5230a4ea42,
+                    0x615c399512bacc7b,
+                    0xb14af78f93ab9552,
+                    0x000003716fa8bade,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b74fd6c0b95faeb,
+                    0x82474b25d3f4316f,
+                    0xb4f08fe2ba767217,
+                    0xe2173ed3bf711ffe,
+                    0x00000364564ffad7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96f2f3a077d223be,
+                    0xe8e12e797622470c,
+                    0x793d0a9049d9839e,
+                    0x0ab1b000960cfd8c,
+                    0x0000009b7f3ed772,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x44330d2717d617ac,
+                    0xab57b7eff7012e2b,
+                    0x2beea757048813df,
+                    0x00a38da9810c5584,
+                    0x000002991cff5f55,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59eff851dd16f048,
+                    0xecd96b70a3cfca32,
+                    0x40ef1f6ae70cfe37,
+                    0xf3224409905df32b,
+                    0x00000239cd2cb1ec,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9342a79c5d622f61,
+                    0x66abc85df1a41854,
+                    0x78c4742befb3474f,
+                    0x88633663420f75bb,
+                    0x0000012f02428a2f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf72bace1aa462cdd,
+                    0xbc940fdc912ac52f,
+                    0x0d2678fb843b1264,
+                    0x16a993d31dbe28b8,
+                    0x0000024904ad698c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9fd32fa3dec1691b,
+                    0x1b9ac6aa680ea827,
+                    0xeecfe48e3f0337ae,
+                    0xf70c0a31351c6ede,
+                    0x000002e229c64bf4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf90ab2353f9db752,
+                    0x22a76339010dc48a,
+                    0x0106c99fe4eff9cf,
+                    0xa7c36c77d37ab51a,
+                    0x000000a0032a339d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2be7d81558f35cf9,
+                    0x78dafc39d37e1193,
+                    0x3b89500166b365f7,
+                    0xd50530f2d5cd50fc,
+                    0x000002fc70c1f611,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b76903cdb7c45d1,
+                    0x822c913928a20854,
+                    0xfe648dcf4e4dc1cf,
+                    0xe37dcab09f4fdb17,
+                    0x0000018aadf6c939,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb51964dd09c9a5b6,
+                    0x8a1ffb33bc37b4cd,
+                    0xd9e78cdba708b441,
+                    0x4edb34367a6a9556,
+                    0x000002c65347aca4,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f82ec07f1537b93,
+                    0x1f29a00a5fb20105,
+                    0xa4be8795b866e6a1,
+                    0xaa0651c19d021f87,
+                    0x000000ce2961f688,
+                ])),
+                Felt::new(BigInteger320([
+                    0x202d486e11a5cd5c,
+                    0xcc55bcd2921a87c4,
+                    0xc329ea7c428e1311,
+                    0xf794cdcbed385907,
+                    0x0000000244516653,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcbacda1f748a2867,
+                    0x9f6d71d52c741125,
+                    0xc272bd10da54c1b4,
+                    0xea1a4b63d3a5b0e7,
+                    0x000002a1b8966286,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe878bc580fadabd3,
+                    0x348c53c7332c83e6,
+                    0xf819a4531f299f8a,
+                    0x217e46771017ccd3,
+                    0x000001c79860452f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d28e4d94058894b,
+                    0xd8044c5af9448c2c,
+                    0x02fba4cb2dc1df19,
+                    0xb11faa1e47f6d5b6,
+                    0x000001e5365de83a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21e3bc3681314f08,
+                    0x950d6800f514fda0,
+                    0xe136be46b6282457,
+                    0xd87ce82143fba75e,
+                    0x0000002753243c53,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa81b73282a97f0f5,
+                    0xa8a17e3cc6622ca8,
+                    0x879beddf88aa8112,
+                    0x46d7d3a5a2c1ece6,
+                    0x000001158216acb4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb83ccd38aa79c369,
+                    0xf28be0a6c47bd13c,
+                    0xa51ce7d1bc4aedd1,
+                    0x68b235e76642d44d,
+                    0x00000124d650769a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3cc0a2bbabc2ecdc,
+                    0x31958534a40babe8,
+                    0x76450d9190f145fe,
+                    0x45bdff3575b45dd3,
+                    0x0000013cd6c550b7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24a5efbfac812c9e,
+                    0xac4d7a2c6cbeb10a,
+                    0x5947ba679bf6f020,
+                    0xcb007ab69bcf4e18,
+                    0x0000031402af80f3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cea5fd9c06dce99,
+                    0xa88ab92579d9890d,
+                    0x5d6c556a5c6b2f1d,
+                    0x78501c423494f6f4,
+                    0x000002af9cc57eba,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe9338b2eb18475ca,
+                    0x6db0f2a34037fcc0,
+                    0x5751f9050dc6364c,
+                    0x1aad9c5b62e8fa85,
+                    0x00000137d9c68659,
+                ])),
+                Felt::new(BigInteger320([
+                    0x346cdbc143e21fd2,
+                    0x6fbdb588a7b68b6e,
+                    0x20a455da77aebc79,
+                    0xa7aaa6d46d6eba14,
+                    0x00000224240384df,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6af0f156d23849c2,
+                    0x827a0384397b9430,
+                    0x32d76a2994e89c0f,
+                    0xff58f2be71dcc834,
+                    0x000002bd09778158,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35d786558f23fab0,
+                    0x58da1833ceb28d10,
+                    0x3f33fd7f0fe51db2,
+                    0x2c409da39494d1b2,
+                    0x000002670359bfa5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x53fe8c9572fe29da,
+                    0xa42dfdecbfdc6e1f,
+                    0x946e3b00ad157acf,
+                    0x2a93d681eab4ebfc,
+                    0x0000016a98a7887e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x610d2514de615e1f,
+                    0x30ff74b7b6d70e75,
+                    0x6eb76b9db4741f5c,
+                    0xf5958cb0790f58f1,
+                    0x000003a25251a1e5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b68123ace19d058,
+                    0x56f98cb9f1b36cb3,
+                    0xf2f3d0bf7bb55da1,
+                    0x4eb6552d8ae140a4,
+                    0x000003335ffa6d12,
+                ])),
+                Felt::new(BigInteger320([
+                    0x447cb415411234e3,
+                    0x2c78dbf6c2cdd6fe,
+                    0x31c5f16f73995641,
+                    0xe3b0502929b13901,
+                    0x000003b115bb0772,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9552ec53f4e89cda,
+                    0xaeb39336bd180263,
+                    0x2a751d1e89625b6c,
+                    0xf70d1f3491123bce,
+                    0x000000842fffc9b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x424d10bea65cd691,
+                    0xdd84a6e15cb46513,
+                    0xd32f1e2271556aa9,
+                    0xd17c22576f8c47ed,
+                    0x000000023567093b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82992c963188cf31,
+                    0x8cc85be6ba9dd1e3,
+                    0x7b4c031efef37a87,
+                    0x202957f5173b45b4,
+                    0x000002fbbac76fb1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a302e5c8371a04e,
+                    0xdf754120e3b30614,
+                    0x107196ec6e356867,
+                    0x95ad45f5d7ee4917,
+                    0x000001e4fd1626ed,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xad6e5f5d65b6d4fd,
+                    0x51f50af266234d8f,
+                    0x711a8dd600acb0ae,
+                    0xafb1a4c064bc3ad9,
+                    0x00000140660f9790,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fe0e8914f38f49e,
+                    0x63c28b101a980c2c,
+                    0x30cecd932ecbca02,
+                    0x07042727732bf805,
+                    0x0000036e37e14dac,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab054e6de2b2165f,
+                    0x52c7c24a25ee0547,
+                    0x986ce835a4db8cbf,
+                    0x534afaef3dbb64e7,
+                    0x0000004cb6ab0068,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08b7c94a0c358fad,
+                    0xc353136259f90fdd,
+                    0x9e1176f2a0b1b782,
+                    0x2cc237bcfc4ee965,
+                    0x000001238ed62dc9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x90e3ab546932f381,
+                    0x5c2bb06b22d905ad,
+                    0x26d91db9d2fcf2e6,
+                    0xd80b933de2ab2ed2,
+                    0x00000046a2518a16,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5a27b67780deecb,
+                    0x654606b66b5d0a88,
+                    0xdd2c0691f531cf46,
+                    0x6609f6bdb3dec53f,
+                    0x0000025ef4bb5f61,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75aadc3505009e14,
+                    0x786f4664d274bfe9,
+                    0x848a32fc52f17b53,
+                    0x4d9e7c7b1b5bf40c,
+                    0x000002930d8c69a5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72d8241cd615680d,
+                    0xca2a3f9f3c1ed131,
+                    0xb7b2e88cc82a9498,
+                    0xea18555352d5d62a,
+                    0x0000028fe25ddc06,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x58ae4ea5d2032dc1,
+                    0xbece91aed25fe28d,
+                    0x3b87e36053129269,
+                    0xd0a3c6bfcc54b344,
+                    0x000001c2c7925087,
+                ])),
+                Felt::new(BigInteger320([
+                    0x666c40bcad1b0501,
+                    0x293c7e44f7293d3a,
+                    0x4985ceaaacfca216,
+                    0x067b3e3513bed57f,
+                    0x0000018bfea3556f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa96b1e442f39f7b3,
+                    0x0bd8ba1dab291176,
+                    0x74878f474f3df2d9,
+                    0x9de83f610b687cc3,
+                    0x000002063fb3409b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04803377669002aa,
+                    0x8bf1459c613ad219,
+                    0xfc064000a6061825,
+                    0xa8a1d351d0d4df2d,
+                    0x000000f7947b7372,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9f8d39159070234b,
+                    0x28522ba5eb56d7ba,
+                    0xb2ef8483e932eb9e,
+                    0xe2873af9348374a4,
+                    0x00000350c79bd36c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xacaedc9d74e9db13,
+                    0xd0903078218bf70a,
+                    0xbee0b8f89804d5f0,
+                    0xed931596e13a8bad,
+                    0x000000d8538da90e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5941571e9867e535,
+                    0xd82ded7f815dd3c6,
+                    0x1d6241905a3cb315,
+                    0xe2e60bea36f9eae2,
+                    0x000001fcd1ecafd3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd5190e55f0181b37,
+                    0x07cb50fb22a22bd1,
+                    0x4d18c371f458a311,
+                    0x908da46db7ccb356,
+                    0x000003a3f87dc893,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd65c527c79e19098,
+                    0x6ac04b8aafde5ed8,
+                    0xb533b19891cd2ad6,
+                    0xa0c816802ee4f578,
+                    0x0000016eddcab733,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1fc3059d8ee959ed,
+                    0x16ed584c2bf593d4,
+                    0x1bb111bbc2ed506a,
+                    0xb7cf359dd49107b5,
+                    0x000001db3e26c206,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e1ec96692f39c62,
+                    0xd97c9c7a315e76b0,
+                    0xb39b69d868d16022,
+                    0x335c25b526b1c28d,
+                    0x000002ac5963be44,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a000feb9c19c389,
+                    0x5fd59a9f9ada12c2,
+                    0x25d8ed697af3e496,
+                    0x684e6a9bafc5e22e,
+                    0x000002f4613531a1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7b6c0dad2d5a1d96,
+                    0xdd62c127e323bfb1,
+                    0x54880f52caf5e32c,
+                    0xd7e2fdbe36f4c075,
+                    0x0000029fc18ee014,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc19cc05ad8229f7,
+                    0x98aca0fa08ad8660,
+                    0xfa1fd23eb6fc3d85,
+                    0xf538817efa2910a5,
+                    0x000000d623a4da7e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd21144668063a77e,
+                    0xd64d177796a722c5,
+                    0x37d15e2bff2b4802,
+                    0x664518ec8c329676,
+                    0x000002daa9360a68,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53fe5d7bc9a0f226,
+                    0xc8be7235ecc8b14d,
+                    0x4034dd8e2469075a,
+                    0x9b0093735d54d1d3,
+                    0x0000021662fc626a,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xb9b2411bfd0eafef,
+                    0xc61a10fadd9fecbd,
+                    0x89f128e59811f3fb,
+                    0x980c0f780adadabb,
+                    0x0000009ba1f11320,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d879cbfe24e63b0,
+                    0x61f2d8c07d1a814a,
+                    0x936d93fee33bad06,
+                    0xd4c4920d81a733cd,
+                    0x0000005b862e4a21,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9b2411bfd0eafef,
+                    0xc61a10fadd9fecbd,
+                    0x89f128e59811f3fb,
+                    0x980c0f780adadabb,
+                    0x0000009ba1f11320,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d879cbfe24e63b0,
+                    0x61f2d8c07d1a814a,
+                    0x936d93fee33bad06,
+                    0xd4c4920d81a733cd,
+                    0x0000005b862e4a21,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0xb9b2411bfd0eafef,
+                    0xc61a10fadd9fecbd,
+                    0x89f128e59811f3fb,
+                    0x980c0f780adadabb,
+                    0x0000009ba1f11320,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d879cbfe24e63b0,
+                    0x61f2d8c07d1a814a,
+                    0x936d93fee33bad06,
+                    0xd4c4920d81a733cd,
+                    0x0000005b862e4a21,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb9b2411bfd0eafef,
+                    0xc61a10fadd9fecbd,
+                    0x89f128e59811f3fb,
+                    0x980c0f780adadabb,
+                    0x0000009ba1f11320,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d879cbfe24e63b0,
+                    0x61f2d8c07d1a814a,
+                    0x936d93fee33bad06,
+                    0xd4c4920d81a733cd,
+                    0x0000005b862e4a21,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb6da9b467df06320,
+                    0x61facd4f4acb05d6,
+                    0x36c98117900c4689,
+                    0x4043e2bfff40f208,
+                    0x000001e9df392838,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0d7bd7a3a9cd3d8,
+                    0x53d6c7fb9855c598,
+                    0x9315202aa18d01e2,
+                    0xbb4626f57d9370fe,
+                    0x0000002321afe528,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfba9a4f0e72a6f62,
+                    0x03d75e4042e7d9d5,
+                    0xc763bfa7b450f567,
+                    0xcb49e077bdf085e2,
+                    0x00000065b9c75d68,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4628b32ea5a7032,
+                    0x7de824c5309f1ccf,
+                    0x97639b5f5310e130,
+                    0xa1c79aff01704b34,
+                    0x0000008900b7d20c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe5a9418719be4769,
+                    0x6d302b5de64aee0e,
+                    0x08f8a71939358228,
+                    0x060a5af4fb3ce7f8,
+                    0x0000018e6336492f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd132381e797cb8e1,
+                    0xbc02884320d4abce,
+                    0x0b276800bd77de86,
+                    0x18d8f1d8e57e82b5,
+                    0x000002faf5e6e969,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecae4db3081b94ce,
+                    0x9f81eb5a74206485,
+                    0x589164b07aeb9ac8,
+                    0x817e31d2ce9b0ec0,
+                    0x00000223a3b02d52,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50e6a95b56146e1a,
+                    0xcfcaafbfed882c2e,
+                    0x389f698cf26bfdab,
+                    0x8e2793053f488c37,
+                    0x00000189a4013428,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6bdc037495115fc7,
+                    0x6e06504230869b86,
+                    0xef06d07da11fe0f3,
+                    0x87dba25bf307b801,
+                    0x00000246db00550f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x397127727fc8c2c1,
+                    0x91c3d8ad6e3acf84,
+                    0xde313b3eca81e21a,
+                    0x86aaba1aa3ea0a39,
+                    0x000001e2be39ad54,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa5f5aed3ed3afa3,
+                    0x19fa0e9f35026a98,
+                    0x92a7e9d8edac4cb7,
+                    0xa7b59607fc781806,
+                    0x000002853acbd344,
+                ])),
+                Felt::new(BigInteger320([
+                    0x524e3b0ca37ed40a,
+                    0x309c3820bcb34fa6,
+                    0xa28f30090d4b26ed,
+                    0xba351b6152471d1d,
+                    0x0000010fc57fb220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2c9839502192b206,
+                    0x5c182c4083b63daa,
+                    0xc6ea620fc2868247,
+                    0x4313a50509bbebc1,
+                    0x0000008d23edf0a7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf5de51ac4a4cf4e,
+                    0x6e389ef5b44cf4dc,
+                    0xb919c432d451ab25,
+                    0xe9f1632b9ae16cb0,
+                    0x0000029ec31c3b23,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee1339afe4270f43,
+                    0x95decfc8c21bf09a,
+                    0xb1569ac14cfe186d,
+                    0x9f253fe1ef6de4d2,
+                    0x000001165d4fac24,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7437fa1181ee7dd0,
+                    0xc10627405f5cf958,
+                    0xa9f7f0f3454ce29e,
+                    0x33281ce49a33dd70,
+                    0x000001a58fd2ebe7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6baa83700eff13d5,
+                    0x6797425e8ba30eff,
+                    0xd37473fd5e4d0712,
+                    0xa2e17796361d9bc1,
+                    0x0000014e749e2534,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22a28a1224e0203c,
+                    0x30fa0be6e93be953,
+                    0xfd713d775b12d263,
+                    0x2d6b73a2faa81f9a,
+                    0x000003b4e51db8ba,
+                ])),
+                Felt::new(BigInteger320([
+                    0x185d7d71599ddf56,
+                    0xffb2a33087fcc9d9,
+                    0x9d638e260873dadf,
+                    0xf91ce35f9ce52bcf,
+                    0x0000038518f0d121,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc125a16c131e55b4,
+                    0x059a0413c9982ce5,
+                    0xec2b19731bcd5d11,
+                    0x967f9948bac22b15,
+                    0x0000007da56b9d13,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9de79c9df46fc13a,
+                    0xdd8e72dd153029d2,
+                    0x83e8b2ff36e7c73a,
+                    0x274bc378ad2ebdd3,
+                    0x000003833685c023,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71c9106d39dfb632,
+                    0x6aa97158b7466729,
+                    0xfed1bbb89773b795,
+                    0xc896daaad8c981e3,
+                    0x000002518f3ae3cd,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae91645ccd857d0a,
+                    0x4d76fca53c64b669,
+                    0x16ec402b3477cacf,
+                    0x868267c399cc0dfb,
+                    0x000000b9413226e1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d38c6be879a89e0,
+                    0xe6605ddccddb6d3f,
+                    0x872e4d1551f0b0f1,
+                    0x6290dd553eca7927,
+                    0x0000029d45b1e650,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,395 @@
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x1bdd4333f4c3b705,
+            0xdcb001ed15b0eeb9,
+            0x66f4515e645ac0ce,
+            0xb065e14d0a251c4d,
+            0x000002a93b06b96f,
+        ])),
+        Felt::new(BigInteger320([
+            0xf11e3b0e560b6547,
+            0x3a7d9ea76edd993d,
+            0x83e2396c711e480b,
+            0x4aa7d169c4e963f0,
+            0x0000027916f8befb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa9d309451b376f8a,
+            0xc74669b0c4b1cb41,
+            0x47f938f5d73495cf,
+            0x325a80138838343a,
+            0x000000f20ca8dc46,
+        ])),
+        Felt::new(BigInteger320([
+            0x1598328fda437a18,
+            0xe6cc0347b3f49c58,
+            0x101a72970f315209,
+            0x33c5a1f0c691a993,
+            0x0000031b59a88da4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9d182de6290d824d,
+            0xcd9a4936a5704c7b,
+            0xd09a9ab87a9e0541,
+            0x97a5b1ce7037eb37,
+            0x0000018343df4cf1,
+        ])),
+        Felt::new(BigInteger320([
+            0x988523659ac8a98c,
+            0x6e769270bbbe2dd2,
+            0xd9f7e1f2c3511b72,
+            0xf94bfab297ba51b1,
+            0x000003817d001729,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6aaeec843c13f4ef,
+            0x7b0ce59793a32621,
+            0xe9ccf8cb3af9808d,
+            0xa9500b05ef10bcd2,
+            0x00000134d5c5240e,
+        ])),
+        Felt::new(BigInteger320([
+            0x61da5a302c14a9d6,
+            0xac5dd73f823c51c9,
+            0xe77e2cc27404d115,
+            0x1f65b16f4914c607,
+            0x000000d10e35d17e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0cf040e4b49533af,
+            0xa9c3dd79340117f4,
+            0x86caf39b678c03f6,
+            0xad36bd8bf51d999a,
+            0x000001ae5f72fe5e,
+        ])),
+        Felt::new(BigInteger320([
+            0x5e2100d88ae937e9,
+            0x11338a93b648fa7a,
+            0x8ccda3da524e213a,
+            0xdee79a0dfdac44d6,
+            0x00000340f79f22f7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9adccc720dfdba5c,
+            0x407980eb30dbc7e3,
+            0x3a6ddb000dc4a65a,
+            0xeaa18cd0c6f385bc,
+            0x000003b8e77428cd,
+        ])),
+        Felt::new(BigInteger320([
+            0xd8464041620bef57,
+            0x02ba11a2a4424047,
+            0x3dca11149126c9fe,
+            0x103f74e2cfd3fab3,
+            0x000000a28b59dd3f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x812520c9536af420,
+            0xca1d21fdf3125ac3,
+            0xfbe7bca00d4c3a49,
+            0xe0bcc71b9dd46968,
+            0x0000000b543bd11e,
+        ])),
+        Felt::new(BigInteger320([
+            0x17708b92d15aec84,
+            0xb0c065d025cf601d,
+            0xbd70a369e8d2d59c,
+            0x290be49e66327849,
+            0x0000020a44689072,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x005d513fcc04ab9e,
+            0x31b90296c160d202,
+            0xdfd82bdd914bd3fb,
+            0xec665c52abf896c7,
+            0x000001a6c6a34578,
+        ])),
+        Felt::new(BigInteger320([
+            0xf3a160e71af26386,
+            0xfd59b07dbb393857,
+            0xbf006b6824556ae2,
+            0x5d42dd820f08e025,
+            0x00000231d274c895,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x710ac9f3f206cbe7,
+            0x086b73bb984d57a3,
+            0x30b495d3a6d543cc,
+            0x86650159921e0680,
+            0x0000037651806d09,
+        ])),
+        Felt::new(BigInteger320([
+            0x0791406f8a91b75f,
+            0xb51895ac4d807d9f,
+            0xacb5bd083feb6d1e,
+            0x1cf5381a0fc150fa,
+            0x00000140710d28b2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0b76cde2cfe6e624,
+            0xafc4ca3fb40f433f,
+            0x851ddfdc4e5e245b,
+            0x0f6caee4dadac7a3,
+            0x0000035ee794696d,
+        ])),
+        Felt::new(BigInteger320([
+            0x473c67aa35d361b7,
+            0x14332f4bd4c54ee6,
+            0xd5d2c151c95eeb7d,
+            0xb5beca90943552c6,
+            0x000002587f0f54d8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf5d4fd76cec94e7d,
+            0x489e044aad064f23,
+            0x7ffe63bbb2b79bf3,
+            0x64470bf8efc081dc,
+            0x000001dfa513e3ef,
+        ])),
+        Felt::new(BigInteger320([
+            0x4fbf14e4a1588ef5,
+            0xab608d375d112330,
+            0xd456d8998251cd38,
+            0x21c2d7b5d37a84a6,
+            0x000001687f23787f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xe4e7d2a43cd246d6,
+            0x5f473bd0cdbd6793,
+            0x2f05bb4c0e3a970c,
+            0x5e61798c377a3e4d,
+            0x000002a9ddb539bf,
+        ])),
+        Felt::new(BigInteger320([
+            0x30944ca666c8e123,
+            0xad1358a5cb8f3797,
+            0xe9d203d1596ca35c,
+            0x4ca11b2c5f97399c,
+            0x000001c6c9cb30f1,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x21a0406cc189aaa8,
+            0x1e7ebea218b90671,
+            0x89ba6e3a0830785c,
+            0xee96557bcf54b55f,
+            0x00000395899902b3,
+        ])),
+        Felt::new(BigInteger320([
+            0xfcfbbdba63090a29,
+            0x4c4844a2ce7b5b05,
+            0xc078e5d646c24281,
+            0x120cc52f1d68c3c4,
+            0x000003a617bf2d6c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc06325b2093e3ffd,
+            0x992c30b1e5e685d5,
+            0x02d73bf40bbcffd9,
+            0xb3cdfb4aa7d898ea,
+            0x000001a2783229e7,
+        ])),
+        Felt::new(BigInteger320([
+            0x76ff9fcc0881fbc9,
+            0x8d6467879f619628,
+            0x19da3bc01893bd67,
+            0x9c077820d1d18076,
+            0x0000004f7fa92bfe,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8e363c7277c0412a,
+            0x28ec233621b8c7d3,
+            0xfb6be61cb78bb3c5,
+            0x523a0ed47a682b44,
+            0x00000288f867033f,
+        ])),
+        Felt::new(BigInteger320([
+            0xd47a82c129b319a7,
+            0x9e7b09af023ee866,
+            0x53aaf381d518cb49,
+            0x9aaeb2b18d8a03f2,
+            0x0000010aebff1e30,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xe5e0648601896490,
+            0x0097bcb9a5276e3a,
+            0x2f3184d0ecf95a52,
+            0xe6fa3b97b8528bf6,
+            0x000002e2ee34042e,
+        ])),
+        Felt::new(BigInteger320([
+            0xe32657a531c1cab6,
+            0x01e497a7f05643f2,
+            0x46b3485657d2edc3,
+            0xe6446197a5a65bed,
+            0x000002bfd8d8d6ca,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb2f088c7586ab887,
+            0x19312cb3afefe995,
+            0x042b2d3f491a262e,
+            0x20a69f34396287b6,
+            0x00000094514efc9f,
+        ])),
+        Felt::new(BigInteger320([
+            0x0a3bce2e6ef66e00,
+            0x509cc3148ecd762c,
+            0x23fe6d0c65aa865a,
+            0xdb8bfb4cd540f9b4,
+            0x000002679baf4664,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x38a3946f18f4de9e,
+            0xd3d8e2764fb20e8a,
+            0x586febe2d38c77f1,
+            0x899f76422443ebe0,
+            0x000002ea638f551b,
+        ])),
+        Felt::new(BigInteger320([
+            0x7c278db1ad3ac4d8,
+            0x66155c741fae30fe,
+            0x759cb18588bcde7e,
+            0x3871ddeac0742799,
+            0x00000014b9a92eb9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x20f0378b9de4d19c,
+            0xc42725d2a5870f8b,
+            0xd2700f3eedd8895c,
+            0xccc7ca671723d03f,
+            0x000002b9e4b079c7,
+        ])),
+        Felt::new(BigInteger320([
+            0x0212f3245c0c7b3e,
+            0x7f7d06e3a27c5421,
+            0xe2dabc339e392603,
+            0xfbe542e2cb215153,
+            0x0000013c8f5489d3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x1b98851393d21187,
+            0x6d2a01399f36f811,
+            0x83f0ce2136d74946,
+            0xea0f1d4f7d6d0fc0,
+            0x000000e36dd083fb,
+        ])),
+        Felt::new(BigInteger320([
+            0x14f71a2e22f77aae,
+            0x08c69866f5a50877,
+            0x7ce99d39fbaf2317,
+            0xe4201e1573cd1fe0,
+            0x000001af2bd62c44,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x19750e876b42f228,
+            0x56998d59abae554d,
+            0xa83b8248d0583388,
+            0xe719d9768f2e5a21,
+            0x000003606c64750b,
+        ])),
+        Felt::new(BigInteger320([
+            0xb6160a7644058edf,
+            0xd3429890bd772558,
+            0x3e0d390b9b3c9fc3,
+            0x06de8fcda0216b5e,
+            0x0000016b3e2555e1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xeebe711ca53acc46,
+            0xe908531d0e3ee5e4,
+            0x2fd975b24880d687,
+            0x983551fb2ca99770,
+            0x0000015fba986853,
+        ])),
+        Felt::new(BigInteger320([
+            0x309e90574b5ef918,
+            0x1d72a16f8b8a9b9c,
+            0x9a5ee6b5f54fe092,
+            0xc7bbed3d7953e955,
+            0x0000009a044778eb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf11373557f32f39c,
+            0x36a8988ecd038594,
+            0x8d71fc63c9b1f7e2,
+            0x5c49d31193085239,
+            0x000002ab8a0d76d0,
+        ])),
+        Felt::new(BigInteger320([
+            0x5118103691f9e553,
+            0x69670ac1d9a403b1,
+            0xfb9b00cfcb1a6c10,
+            0xa2fa1e650a121bc5,
+            0x000002751651308c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd18ca1a7411c5446,
+            0x3e5187dde48939d0,
+            0x90afb3344e4aafec,
+            0x8e22b3d3cba6315c,
+            0x0000036abb7858ab,
+        ])),
+        Felt::new(BigInteger320([
+            0x2353a11cab4a9fd2,
+            0x5c198df93ef0b3e4,
+            0x654c8b47cb4aff25,
+            0x0596d50a8712f36e,
+            0x000002c859c2750a,
+        ])),
+    ],
+];
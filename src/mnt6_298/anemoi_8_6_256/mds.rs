@@ -0,0 +1,124 @@
+use super::BigInteger320;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [  1  11  10  10]
+/// [100 110  11  21]
+/// [100 100   1  11]
+/// [ 11  21  10  11]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0xb9b2411bfd0eafef,
+        0xc61a10fadd9fecbd,
+        0x89f128e59811f3fb,
+        0x980c0f780adadabb,
+        0x0000009ba1f11320,
+    ])),
+    Felt::new(BigInteger320([
+        0xb1ddfacffd532b94,
+        0x25e295ff76674008,
+        0x8f00647b48958d36,
+        0x1159f37d4e0fddb2,
+        0x000002977770b3d1,
+    ])),
+    Felt::new(BigInteger320([
+        0xb1ddfacffd532b94,
+        0x25e295ff76674008,
+        0x8f00647b48958d36,
+        0x1159f37d4e0fddb2,
+        0x000002977770b3d1,
+    ])),
+    Felt::new(BigInteger320([
+        0x8f189047e53fb3c2,
+        0x972213cd31d7ff60,
+        0xd46b347ca81dfa9f,
+        0xdf1ea7331e7cada0,
+        0x0000037cdbfa0b74,
+    ])),
+    Felt::new(BigInteger320([
+        0x85b35673e292df55,
+        0xc1bb5dc515e1d495,
+        0x987ccf9493bf4640,
+        0x4e127612c4dc36c4,
+        0x000002575badead2,
+    ])),
+    Felt::new(BigInteger320([
+        0xb9b2411bfd0eafef,
+        0xc61a10fadd9fecbd,
+        0x89f128e59811f3fb,
+        0x980c0f780adadabb,
+        0x0000009ba1f11320,
+    ])),
+    Felt::new(BigInteger320([
+        0x6b903bebfa61db83,
+        0xebfca6fa54072cc6,
+        0x18f18d60e0a78131,
+        0xa96602f558eab86e,
+        0x000003331961c6f1,
+    ])),
+    Felt::new(BigInteger320([
+        0x8f189047e53fb3c2,
+        0x972213cd31d7ff60,
+        0xd46b347ca81dfa9f,
+        0xdf1ea7331e7cada0,
+        0x0000037cdbfa0b74,
+    ])),
+    Felt::new(BigInteger320([
+        0x8f189047e53fb3c2,
+        0x972213cd31d7ff60,
+        0xd46b347ca81dfa9f,
+        0xdf1ea7331e7cada0,
+        0x0000037cdbfa0b74,
+    ])),
+    Felt::new(BigInteger320([
+        0xc3177aefffbb845c,
+        0x9b80c702f9961788,
+        0xc5df8dcdac70a85a,
+        0x29184098647b5197,
+        0x000001c1223d33c3,
+    ])),
+    Felt::new(BigInteger320([
+        0xb9b2411bfd0eafef,
+        0xc61a10fadd9fecbd,
+        0x89f128e59811f3fb,
+        0x980c0f780adadabb,
+        0x0000009ba1f11320,
+    ])),
+    Felt::new(BigInteger320([
+        0xb9b2411bfd0eafef,
+        0xc61a10fadd9fecbd,
+        0x89f128e59811f3fb,
+        0x980c0f780adadabb,
+        0x0000009ba1f11320,
+    ])),
+    Felt::new(BigInteger320([
+        0x6b903bebfa61db83,
+        0xebfca6fa54072cc6,
+        0x18f18d60e0a78131,
+        0xa96602f558eab86e,
+        0x000003331961c6f1,
+    ])),
+    Felt::new(BigInteger320([
+        0xb1ddfacffd532b94,
+        0x25e295ff76674008,
+        0x8f00647b48958d36,
+        0x1159f37d4e0fddb2,
+        0x000002977770b3d1,
+    ])),
+    Felt::new(BigInteger320([
+        0xb9b2411bfd0eafef,
+        0xc61a10fadd9fecbd,
+        0x89f128e59811f3fb,
+        0x980c0f780adadabb,
+        0x0000009ba1f11320,
+    ])),
+];
@@ -0,0 +1,1551 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger320, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger320([
+            0xc29a8486676951f4,
+            0x4ec28eeb85453d19,
+            0x82d32133339ba433,
+            0xc19c9c12906cdf89,
+            0x0000000ad3d4ac4c,
+        ]))],
+        vec![
+            Felt::new(BigInteger320([
+                0x50405d549e24bb4e,
+                0xb90e01ee2f14128e,
+                0x614640570c89e187,
+                0x0879993c2c0a6e2d,
+                0x000002843b63357e,
+            ])),
+            Felt::new(BigInteger320([
+                0x18d04dc3bcc0055c,
+                0x814195c64adde30f,
+                0xbeeb8d62c9b9eb0f,
+                0x90d46120800df584,
+                0x000000e48555100a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xd97e20620be6b263,
+                0xbbddaf517233b6de,
+                0x5e2e18fa3213a022,
+                0x4b89096064486d96,
+                0x0000004f1ad74e43,
+            ])),
+            Felt::new(BigInteger320([
+                0x2254ff236c165c11,
+                0xf659ce1804139a49,
+                0x1667282f081078b4,
+                0xb55b1398c59f5e19,
+                0x00000125d815f6fb,
+            ])),
+            Felt::new(BigInteger320([
+                0x7d517695fc05f2e2,
+                0xdf3796f6d137ecc4,
+                0xba369045fb0097fa,
+                0x8d266725196e5f7b,
+                0x000000b9af6b2d23,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x90ff1d03ae0e8f05,
+                0x777b82f818f6cb01,
+                0xbfe4795104fa1441,
+                0x26ce38f7c386740a,
+                0x000001da0f89258e,
+            ])),
+            Felt::new(BigInteger320([
+                0x466a331089ef4505,
+                0x96750f900056cc12,
+                0x2369a37f1919f362,
+                0x94dbeac03a25fc43,
+                0x00000089b3984523,
+            ])),
+            Felt::new(BigInteger320([
+                0x837b1f5ca2c4888f,
+                0x57324a1995138399,
+                0x9e4520d6375d48af,
+                0x726963687082b357,
+                0x00000383f121b346,
+            ])),
+            Felt::new(BigInteger320([
+                0xa9bc0e70aeaa1154,
+                0x0561863ae9bb9b2c,
+                0x71aa85e2c97ede16,
+                0xd454e1adaed68349,
+                0x000002d25c1da8f4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xf0104921f882bb60,
+                0xdc35c12ee7eb09a3,
+                0x0f3a63ea7f3b1347,
+                0x75f296fd6f3b4b9a,
+                0x0000024b64a40ead,
+            ])),
+            Felt::new(BigInteger320([
+                0xf61a1437569aea26,
+                0xf86299cf50bb29ac,
+                0x0243a793eaeda44e,
+                0xb6d50dce21125638,
+                0x000002e81bbf9c3d,
+            ])),
+            Felt::new(BigInteger320([
+                0x44439b801e6251e7,
+                0xfdc8d76e341343ec,
+                0x5007f7120cc05495,
+                0x8ee4f8123629ed12,
+                0x00000023e3205d81,
+            ])),
+            Felt::new(BigInteger320([
+                0x14cecddecfe1378e,
+                0x58db4d7f6a189892,
+                0x46176f01a1851a55,
+                0x92285124ea4b83b9,
+                0x000001b8d2238cf6,
+            ])),
+            Felt::new(BigInteger320([
+                0x032988888fac63a1,
+                0xf19c4553fa2cff08,
+                0x5fa36a89580d2c18,
+                0xdee73a3f1ee1f8a5,
+                0x000001e94642542e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x1d03ff326f0f2cb5,
+                0x31d16fe638f88f24,
+                0x69c8ac29903d1305,
+                0x3042576069029cd1,
+                0x000000f275d924d5,
+            ])),
+            Felt::new(BigInteger320([
+                0x8cd15f0783ff14ee,
+                0x8687be5a6c14f669,
+                0xc65e6fb2f7a66330,
+                0x7d55c0907bc9b479,
+                0x00000188ce16f68f,
+            ])),
+            Felt::new(BigInteger320([
+                0x2f30483a754cb91b,
+                0xd6a6f8cc36b136a6,
+                0x7d2c8027cb3795db,
+                0x05d83954f9f57780,
+                0x000002018466ef5f,
+            ])),
+            Felt::new(BigInteger320([
+                0x26e3378b3e88756d,
+                0x919c1b1a93d1ba3f,
+                0xbae2c6ed3c458712,
+                0xe8f0e9a1cbd45443,
+                0x000001fdaa8c0439,
+            ])),
+            Felt::new(BigInteger320([
+                0x0d4835f10c9a7fcb,
+                0x0613dc286e59673d,
+                0x58c3ca94a9af2298,
+                0xed562c0501fe84d7,
+                0x000001344b2e7cb7,
+            ])),
+            Felt::new(BigInteger320([
+                0x6653d71bcdc78fa8,
+                0x46d18c7d671fc3be,
+                0x7b0fa79f775fd9c6,
+                0xd9795431c00955a4,
+                0x000003049bf305e8,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0xd74f70e3f4058228,
+            0xfa74079ff7aaa0aa,
+            0xd1a925a0771583a0,
+            0x025f6a50917fe503,
+            0x00000235e347d33c,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x5233d07c4e6ae4f5,
+            0xe54c95f745900e2d,
+            0x30ac6092a7ab4293,
+            0x008ad753c988e7d9,
+            0x000002e51a79254f,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xdc125d9c5dfa4257,
+            0x0c4e20dd50b46fe0,
+            0xac8c3e4473bc6bfb,
+            0x2270209be8500141,
+            0x00000211dae6c990,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x6ab4a9742bfc178c,
+            0xcd4ec36750562f43,
+            0xd214d35c1e021a3f,
+            0x4a16b3074c2c382a,
+            0x000002c711f1a14d,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x5588d391d4408900,
+            0x19f00ec58a32af88,
+            0x0fb10f30550f9075,
+            0x6be5d8756a2271e5,
+            0x000000444b76faa4,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xc275ca11c5ca7bf1,
+            0x4c6e21d3bbc27baa,
+            0x345a6ec49cf0aaeb,
+            0x50af51775d509154,
+            0x00000131de97b033,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xa545de0bca8bee2e,
+            0x4595fa94c4e182a3,
+            0x63422d88b8b55053,
+            0x7598e98fdd498678,
+            0x000000b63bc9442e,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x185696ed6300c4e3,
+            0x2fe69979c5bbf47e,
+            0xf21b57f524bd10c1,
+            0x9ae69cc3a4a4f6b2,
+            0x000003791651ad61,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xd89bdee394189023,
+            0x4dba8c06b6cebe9a,
+            0x4a50adef12799671,
+            0x588815122ba15f1f,
+            0x000002d998f1bb4a,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xa3d1cea9df2e1807,
+            0xf9af51e62d5ff3fb,
+            0x3cd688a95f9b8b6d,
+            0xa4908560d5b275bb,
+            0x0000035252f658e3,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xd7eb5aedfdaaf226,
+                0xcb40c55483a5f308,
+                0xa8953ca8a806244e,
+                0xecd0193b208516d2,
+                0x0000020b9c8d96f4,
+            ])),
+            Felt::new(BigInteger320([
+                0xe16b740289eb4ec0,
+                0x9015afcf617c4fd6,
+                0x40ba5647f4545a56,
+                0x1444c3d94ced2cfd,
+                0x0000030a33261816,
+            ])),
+            Felt::new(BigInteger320([
+                0x77a78a43b0a42090,
+                0xb0c35da8db537dc6,
+                0x67d8f0a07867e11c,
+                0xdcfa72be733f5337,
+                0x000002fd18236ce5,
+            ])),
+            Felt::new(BigInteger320([
+                0xec3d65def2d367aa,
+                0x4e085607b1b2961e,
+                0x030cadcc932e2915,
+                0x398c22e26fa93b32,
+                0x0000002f09940168,
+            ])),
+            Felt::new(BigInteger320([
+                0x2fdd1987cf460dd4,
+                0xa7ea2909fc0e1b7a,
+                0x16a419af6d11995b,
+                0x304d83300e54c88e,
+                0x00000276a830bb0d,
+            ])),
+            Felt::new(BigInteger320([
+                0x1657f316801e5ff5,
+                0x7cafc725739507ee,
+                0xe12ba8311315e086,
+                0xa6df84a3b9d4fdd9,
+                0x000001b71a5305d2,
+            ])),
+            Felt::new(BigInteger320([
+                0x9c7971ed41830a88,
+                0x26fbf4732f9f6862,
+                0x8b61c2acc42968ee,
+                0x475214ce84dc0dd9,
+                0x0000008634148617,
+            ])),
+            Felt::new(BigInteger320([
+                0x95d4a92c1df172ce,
+                0xe5f023bfb75b0bc1,
+                0x58e6e9339cf3fdad,
+                0x0d213e5a61240e38,
+                0x00000385a5d5d6a7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xc0121fb86caae0ea,
+                0xf5065eff17e1d782,
+                0x2491b0bfb0806004,
+                0x891c4f39cb36dbbf,
+                0x000002d18e3374fb,
+            ])),
+            Felt::new(BigInteger320([
+                0x5e3fab716a1ee66c,
+                0x44e18963adb0e07d,
+                0xca7528da44c8e84d,
+                0x5730d768c0c9bcdd,
+                0x000001d031f2eb77,
+            ])),
+            Felt::new(BigInteger320([
+                0x35b1b4b7e907d5de,
+                0x05f9884b92e80e4d,
+                0x081237e7c91caf4e,
+                0xdc994f1d465dd7cc,
+                0x00000233f59f8d84,
+            ])),
+            Felt::new(BigInteger320([
+                0x9f5eef8c9161c52f,
+                0x6e9796f1bd59d904,
+                0x3f1c531f6557f5e9,
+                0xa124289d1bfb91f0,
+                0x0000033b61a104ad,
+            ])),
+            Felt::new(BigInteger320([
+                0x1fae1f92ff3872bd,
+                0xe3a08aa18cde0048,
+                0x8e53a2b916e99460,
+                0x4618b8c256470183,
+                0x0000039dfb30e72c,
+            ])),
+            Felt::new(BigInteger320([
+                0x6e5f8f8c6ebeb330,
+                0xa8cb7090e71bd6c2,
+                0xdfd029c5a594dad3,
+                0x69442596cc231970,
+                0x000001bdb72a7366,
+            ])),
+            Felt::new(BigInteger320([
+                0x3de50bf830b07f55,
+                0x82771b77d6bfd0ca,
+                0xa69fe942f882a15a,
+                0x1d818de40a204445,
+                0x0000028bfad01ef4,
+            ])),
+            Felt::new(BigInteger320([
+                0x516cecc55bada7bf,
+                0xb2968aae54981dd0,
+                0x79e13bccd9cd1c78,
+                0x705d0e5873ac4155,
+                0x000002a35b278378,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x693ee91de504f8b3,
+                0x4f8971709550243e,
+                0x887e64bc3c3c8357,
+                0x792022ff4f2f349f,
+                0x000001c87e225006,
+            ])),
+            Felt::new(BigInteger320([
+                0x11ec38540437183d,
+                0x56822877efe48f13,
+                0xf2b314176d2abaed,
+                0x34642f95dcf5ec91,
+                0x000000525840e500,
+            ])),
+            Felt::new(BigInteger320([
+                0xe146630550c3a793,
+                0x63da938045d6d1c4,
+                0xd335529d55161c54,
+                0x9c3e222979dd149f,
+                0x0000014518652bdd,
+            ])),
+            Felt::new(BigInteger320([
+                0x623a9f26f846b79d,
+                0x6ac8cf9730face16,
+                0xd54fc2ec4ed33637,
+                0xfb7ca49fe0faa083,
+                0x000001a2a4289b1f,
+            ])),
+            Felt::new(BigInteger320([
+                0x98ea2fc6c8528c1d,
+                0xe9aa5f666568dea7,
+                0xb0072f8366804d8f,
+                0x8b12923e9f5dde8c,
+                0x00000180bf81c27c,
+            ])),
+            Felt::new(BigInteger320([
+                0x7225417a3582cd3f,
+                0xd14bc40b6953eb6a,
+                0xd2f5434c1cb3105a,
+                0x0cf57d2c26bc0b0e,
+                0x000002f8c406d33f,
+            ])),
+            Felt::new(BigInteger320([
+                0x4a8af1c96905107c,
+                0x45de5bd216565019,
+                0x7e256473a645cecb,
+                0x485c1bd5db49ae7d,
+                0x000000e7c791a8f8,
+            ])),
+            Felt::new(BigInteger320([
+                0x8c807f5d85357e73,
+                0x3d683ee8176f2bec,
+                0x58b7464ad2af98e3,
+                0x820aeae6fe2c9bee,
+                0x000003945841906b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x2e6337b6284bacaf,
+                0x32a9ce16799505dc,
+                0x252d522e7cac3f6f,
+                0xc338ed2a29e2d01d,
+                0x000001afa485cbbf,
+            ])),
+            Felt::new(BigInteger320([
+                0x455556f3eb141f43,
+                0x57eb1d254bee2901,
+                0x12c0cfee7cf97de6,
+                0xf8fa32f1cd910242,
+                0x000001ba27261355,
+            ])),
+            Felt::new(BigInteger320([
+                0xf3a02ec4e3c0db23,
+                0xb7aab4f07bfc5f4a,
+                0x44c22ec6e6fdfaa3,
+                0x804cba6b8678d525,
+                0x00000208a40e1813,
+            ])),
+            Felt::new(BigInteger320([
+                0x194e21edfafb609e,
+                0x0d27763d1d078c30,
+                0xb5aaa223aa78dcf0,
+                0x870d67c6994aa681,
+                0x000002073f765f4d,
+            ])),
+            Felt::new(BigInteger320([
+                0x8abcd72060fca895,
+                0x8b2f1d3aad507277,
+                0x7bfb3ce04e0ed2b2,
+                0xc519edeb0c1287e6,
+                0x0000002c95f3b8dd,
+            ])),
+            Felt::new(BigInteger320([
+                0x985e92282f5d9f11,
+                0x2a7f0df189d2271d,
+                0xf3e930c6d69d6b73,
+                0x7b5adb142bf52321,
+                0x000002fb2c8f26bd,
+            ])),
+            Felt::new(BigInteger320([
+                0xe4639bcee23165a7,
+                0x1cef1d2caa477b9c,
+                0x4d4ac393a5011987,
+                0xc3d452252809c496,
+                0x00000385bc2e5850,
+            ])),
+            Felt::new(BigInteger320([
+                0x78f6fae16c244509,
+                0x076d25a72474604c,
+                0x552435d645eaf8ef,
+                0x8554b5fadc290d17,
+                0x00000061843f56a2,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xef5b2ed0e3091fcc,
+                0xd4dd94c41a47fcf0,
+                0x58afac33ebfcda3c,
+                0x2c3644e12e6f7fb0,
+                0x00000256ce64117f,
+            ])),
+            Felt::new(BigInteger320([
+                0xa98d456b7006d9bb,
+                0xfa288b8290e52e02,
+                0xb2beb8c0f2bea7df,
+                0x5dcee2fe8f871845,
+                0x00000246c185dd7c,
+            ])),
+            Felt::new(BigInteger320([
+                0x974058545401ca84,
+                0x11010b62fbfd6b82,
+                0x2f1baef5d4b81fc5,
+                0xcfeb31eb5fb55c8e,
+                0x000000ed74014844,
+            ])),
+            Felt::new(BigInteger320([
+                0xfb96525db8f5a63f,
+                0xdba6695e7868bc26,
+                0x67f791d39ae62539,
+                0xba2bc435e71d333e,
+                0x0000020f48ddf394,
+            ])),
+            Felt::new(BigInteger320([
+                0xe55194cef6806ddb,
+                0x415cdde128b90450,
+                0x70e8d651a5216769,
+                0x2775ce1a359a9c50,
+                0x000003b7460fd713,
+            ])),
+            Felt::new(BigInteger320([
+                0x9a6cd671846a3f6c,
+                0x9a3620869e6bf613,
+                0x730a9d18aafe2ae4,
+                0xa45f571b1897c786,
+                0x000002443b02aad2,
+            ])),
+            Felt::new(BigInteger320([
+                0x15355f67d87bb3e7,
+                0xf5edc8f40fba8ebc,
+                0x8b5f9e0faa4651bd,
+                0xd5a654694bd1d969,
+                0x000002f33c5114ea,
+            ])),
+            Felt::new(BigInteger320([
+                0x700857d494c6a58c,
+                0x3b83f6087081ddb5,
+                0xd3edacfb97aec14a,
+                0x5ab9d5b7e2ce4608,
+                0x0000032ddb656a89,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x1f04eae384123ce4,
+                0x4d4036ee09358bea,
+                0xa4243d43fe89c519,
+                0x66d11dfed755439f,
+                0x0000011b4bbff2d8,
+            ])),
+            Felt::new(BigInteger320([
+                0x03d5bb129d210801,
+                0xb176bd9f2ccc80a5,
+                0x5678bba51b7a862a,
+                0x010abcdc79bc7621,
+                0x000001c29377a5df,
+            ])),
+            Felt::new(BigInteger320([
+                0x810ef68c36052aa6,
+                0xabef1c1c0ae2e136,
+                0x799d67bf2b2cd418,
+                0xd997b875ffc8c1a6,
+                0x000000d4e710ddf5,
+            ])),
+            Felt::new(BigInteger320([
+                0x2cdeb2528d4f2ee6,
+                0x1e3e0352d150b6fa,
+                0xb19fff3a84ffdad6,
+                0x93472cdb27261d60,
+                0x0000031c75180bf6,
+            ])),
+            Felt::new(BigInteger320([
+                0xc7745af5e0df5d29,
+                0x283029cc7f35b554,
+                0x90efe854dae7dfb7,
+                0x3cb85e6f629cf912,
+                0x0000000be1195db2,
+            ])),
+            Felt::new(BigInteger320([
+                0x8cd9317a12f665de,
+                0xa2bc0e60613c9006,
+                0xb2fed3a0f6a5e616,
+                0xc196b8815460cd2d,
+                0x000001750a6e505a,
+            ])),
+            Felt::new(BigInteger320([
+                0x65d07d2f840590f5,
+                0x84d50f4d27bb2188,
+                0x98ff023b705a5aa8,
+                0x38903b9bb9d461e0,
+                0x000000291e482e4e,
+            ])),
+            Felt::new(BigInteger320([
+                0x668c96317738f81e,
+                0xc342d82004e3f3f6,
+                0xa9e8bfbd855c935a,
+                0xa51287e26a0da796,
+                0x00000121499f6d44,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger320([
+                0x5336ee21fef3609f,
+                0x920d3e3e54c68434,
+                0xc640449db3a0ac60,
+                0xd7f1b33a7b2051a0,
+                0x000001d04e0e6120,
+            ])),
+            Felt::new(BigInteger320([
+                0xa21c2370c48fb5cc,
+                0x739cf1a7027a12a8,
+                0xd69e63d1e658a803,
+                0xc255c4e2af6f1b3a,
+                0x0000036341dd66d1,
+            ])),
+            Felt::new(BigInteger320([
+                0xefe471ac376803c7,
+                0xe5200d375ff55aa4,
+                0xbb83a482045c64cb,
+                0x686eac1c5a34ab9e,
+                0x000000f36bfbf5b4,
+            ])),
+            Felt::new(BigInteger320([
+                0x463e8bac24c545a6,
+                0x747c472a85f8358c,
+                0xcdfa9b14a738dff3,
+                0xbcd263490471059e,
+                0x0000027d9d41e71a,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xa1f03afa9120c7ed,
+                0x7e9aa006eadf16fd,
+                0xa2a7c3b8e2f6a5e6,
+                0xa611168e1b1c435d,
+                0x0000029930f97bda,
+            ])),
+            Felt::new(BigInteger320([
+                0x2e1aac0e1a1f84bc,
+                0x94e8de2e00386185,
+                0x957875f0275cdd7c,
+                0xd3ab39ba89731fad,
+                0x000002422813224c,
+            ])),
+            Felt::new(BigInteger320([
+                0xdedf2d05dc15ff3e,
+                0x60042c54f024ffde,
+                0x0c97ce0a09b546c6,
+                0xc029f84e3f6d62ee,
+                0x00000079506f4d68,
+            ])),
+            Felt::new(BigInteger320([
+                0x24d635519c665aaa,
+                0xe39527f1f9152b5a,
+                0x840e1dbf153b7d43,
+                0xb4043d44464e0e08,
+                0x000003831eac5555,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xe814648c13279b03,
+                0x23736565f2f0b128,
+                0x5601250edfd22e46,
+                0x6b57341ab1732b70,
+                0x000000fbc3ce33a4,
+            ])),
+            Felt::new(BigInteger320([
+                0xc591f65506eaca90,
+                0x4bcd3e9952f2ca3b,
+                0x2b5c88eef6e0e983,
+                0x6fd58b713b0c7106,
+                0x0000025cd5ac3c88,
+            ])),
+            Felt::new(BigInteger320([
+                0x068c8c4da594071e,
+                0x9e499daf2e5ec04f,
+                0x14a2c9707038dca9,
+                0x10f48eddb1031538,
+                0x000002c965465ad9,
+            ])),
+            Felt::new(BigInteger320([
+                0x8e4a5cb471624c1d,
+                0x93ea6b39a2f13940,
+                0xc1e71e803d07d5f3,
+                0x174430de50527a9e,
+                0x000001c726edf435,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x0defcd2caf341d9d,
+                0xea8173be11d06e3d,
+                0x435ce79540893902,
+                0x46b1a3906310577d,
+                0x00000011e8cf9b1d,
+            ])),
+            Felt::new(BigInteger320([
+                0xf84ee89f33315f48,
+                0xb26415828bff1a7a,
+                0x795e214f30de353a,
+                0x6b02dd0ef19412a2,
+                0x000002ca86236c23,
+            ])),
+            Felt::new(BigInteger320([
+                0x4e8f52899f3f8357,
+                0x8d0d77252de5b516,
+                0xefdb56b995ca442a,
+                0x3aa98ef5c7b6940f,
+                0x000002504b7c591d,
+            ])),
+            Felt::new(BigInteger320([
+                0x6dab58b78ddfb013,
+                0xda85ad1eeed59f17,
+                0x8225eb6e38c6ee00,
+                0xe36da691dfc267de,
+                0x000000dafa383d2b,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xb6de81fcc3a04ef7,
+                0x0c19aa9aeaeacfac,
+                0xca6e907fdedca804,
+                0x525aaad64c6e370e,
+                0x0000002d2891d7d3,
+            ])),
+            Felt::new(BigInteger320([
+                0xe9edc758d8d72247,
+                0x6072c5b921cc495a,
+                0xae77121dcd8bafdc,
+                0x71ef8f70adfbf810,
+                0x000001dbba849abb,
+            ])),
+            Felt::new(BigInteger320([
+                0xc22bf09b81947e08,
+                0xb72b0a0218500224,
+                0xeff33a97b9e2a379,
+                0x6caaa2948e013f24,
+                0x000000113a64cf1e,
+            ])),
+            Felt::new(BigInteger320([
+                0xa1a5c5c2ea366291,
+                0xb9429f28c91efab9,
+                0x44f7365141aa24c7,
+                0x96a8f2e69fb4b5b6,
+                0x0000005fec02d774,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x5ab6e7e5f00f4d92,
+                0xc7a77c2b41ffcf92,
+                0xba24e50f6d6b3eb6,
+                0xd700d1980777c9a5,
+                0x000001178e12da5d,
+            ])),
+            Felt::new(BigInteger320([
+                0x6a84662f0cac1f8b,
+                0xbdff31ff83ab4c4e,
+                0xc674a7be7f2ec51a,
+                0x53bb4c890658c1c1,
+                0x000001070205bb74,
+            ])),
+            Felt::new(BigInteger320([
+                0x44aa652ef8af3991,
+                0x4520b7ac2571ecc7,
+                0xcb4b221b7b48831c,
+                0xa4fba6efe7e0af21,
+                0x000001db20a0f4fd,
+            ])),
+            Felt::new(BigInteger320([
+                0x941dd8155254c540,
+                0x69cc3d6a30217bdc,
+                0xdb2b037d6831b9bc,
+                0x97a63fb51be8d58f,
+                0x00000048df0b67ca,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x00ff670390a81bee,
+                0xae87eb4a7841e310,
+                0xf1606ae7c520dafc,
+                0x21a73271a129dccc,
+                0x000000b895f7cd81,
+            ])),
+            Felt::new(BigInteger320([
+                0x4c5c74c76a3f6660,
+                0x3fa0ffa4448a2889,
+                0xdcff25047781ff52,
+                0x2d862a5fcbe63aee,
+                0x0000019de7712868,
+            ])),
+            Felt::new(BigInteger320([
+                0xac85cf837cb4106f,
+                0x36c73b111f9d5d46,
+                0xa01a26a50a0e01e9,
+                0xe9eaad078ad41a76,
+                0x000000b9700c970a,
+            ])),
+            Felt::new(BigInteger320([
+                0x0da1c2263fe54a73,
+                0xe68b7babf8a81306,
+                0x7325a97a1fd370ba,
+                0x726bc5b553269a47,
+                0x00000379f715dc92,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x7004b1812564b4b4,
+                0xaaa82f1ba3e94a0f,
+                0xb6e456dbbf0fee9a,
+                0x60300ddd90548f3d,
+                0x0000003c2862730d,
+            ])),
+            Felt::new(BigInteger320([
+                0x8d461a02e66c5198,
+                0xc26d7dc6518e9dd2,
+                0x4a4cd15ce9e2fabc,
+                0x51797d3b207a8ad5,
+                0x00000377be10f366,
+            ])),
+            Felt::new(BigInteger320([
+                0x52ad726fec5e2d14,
+                0x00acf583972015ed,
+                0xab03b55c46a81f97,
+                0x203e238a1ac913eb,
+                0x0000003b4211c943,
+            ])),
+            Felt::new(BigInteger320([
+                0xac97f756a999fa5d,
+                0x7e4bef861a1abaef,
+                0x21a78a89b6ec4841,
+                0xb848e051f77255cf,
+                0x000003373c31ca40,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0xd0eadf23c97b7a01,
+                0x2e895a42040bf153,
+                0x5e61ff9bfcb603ae,
+                0xf58f290f2a7b44e5,
+                0x000002d2d1f918d6,
+            ])),
+            Felt::new(BigInteger320([
+                0x9bde2e0274e49521,
+                0x901e88febc539b44,
+                0xa6bba2a13e2f827c,
+                0x55cc9d1ea4cbb06a,
+                0x00000346a395df63,
+            ])),
+            Felt::new(BigInteger320([
+                0x7e8c57b5dc0f7739,
+                0x6c5c50ec4cffe979,
+                0xaff3396ed9d060a3,
+                0xe808b2a6d35f7df1,
+                0x000001b57b912ec2,
+            ])),
+            Felt::new(BigInteger320([
+                0xa8b7b113bd526e5a,
+                0x3ca1675745d103e8,
+                0x3c0ada257375511a,
+                0x56a06fcb9b0d8655,
+                0x000002b4d90dd86e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger320([
+                0x14b7f2317f7db680,
+                0x29090017e6659d0e,
+                0x5415829ade3dc0fe,
+                0x553fc3500e7f45fb,
+                0x000000fb15af7689,
+            ])),
+            Felt::new(BigInteger320([
+                0x441765acc71f646f,
+                0x7dad9555436a8ae1,
+                0xf89e6c3cb28a522c,
+                0x19a3cd27d68e5a53,
+                0x00000034bde15752,
+            ])),
+            Felt::new(BigInteger320([
+                0x423585e1323a75e4,
+                0x2b99abce239d4f76,
+                0xc0bb8230b4818db7,
+                0xd167f569414ecd21,
+                0x0000012239dea2bd,
+            ])),
+            Felt::new(BigInteger320([
+                0xf9a478edc01e8111,
+                0x6ea726d4608c34fd,
+                0xf39e54d797ab9672,
+                0xed985552797c8f05,
+                0x0000036bada1bb7e,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xcc68ead69ade370d,
+                0x8f8c1a5919bb3748,
+                0x95d2fc343b7c8262,
+                0xacf49261143d416e,
+                0x00000282e76afd03,
+            ])),
+            Felt::new(BigInteger320([
+                0x4c5c5fd9eb3acf1e,
+                0xd292d4b7dfff0d7b,
+                0xaeb3e981f53c788f,
+                0xd39a21d547033fb1,
+                0x00000149416e8411,
+            ])),
+            Felt::new(BigInteger320([
+                0x6fad8cecac57ac36,
+                0xc346d62cd0f6d20d,
+                0xdbc1f9c3c31c752d,
+                0xf21623b124150c60,
+                0x00000010653f42fc,
+            ])),
+            Felt::new(BigInteger320([
+                0x1e39e52ac8b3c99d,
+                0x41018d434171e53d,
+                0x22556bb26f4df0a7,
+                0x341589ca01b34ccd,
+                0x0000005f95b595d4,
+            ])),
+            Felt::new(BigInteger320([
+                0x8c4902c56676418c,
+                0x3c9c56e80bc643f8,
+                0x277e49a334466afc,
+                0xf091fe4cfb1d8783,
+                0x000000aed9375f1a,
+            ])),
+            Felt::new(BigInteger320([
+                0x5ec4237225076be2,
+                0x5b375f7446835f1b,
+                0x277f3d61dc6e73de,
+                0x09419660fc2ce1d6,
+                0x000003ad30f3d312,
+            ])),
+            Felt::new(BigInteger320([
+                0x0175fcf44043d40f,
+                0x1ed8a36d11566102,
+                0xf7f3399761f8bdaa,
+                0x85957ab6b1804978,
+                0x000001ca6b4e5136,
+            ])),
+            Felt::new(BigInteger320([
+                0xc79fb6a94d667eba,
+                0x6f65ca8c1e0caaec,
+                0x6e381ceecfdc69ca,
+                0x8dd99c3e1a732fe9,
+                0x00000338f3bd264e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xceffe442922b7176,
+                0xa43fb3280080a382,
+                0x11cd5713fb3f37e4,
+                0x0d25d62264c7eb7d,
+                0x000002455f329418,
+            ])),
+            Felt::new(BigInteger320([
+                0xe50a21ec7e3ff14a,
+                0xcb078b7f7d33d684,
+                0xe0ff4d7f5ffe1027,
+                0x196c8bce581bd1b2,
+                0x000000f4f5ebdb86,
+            ])),
+            Felt::new(BigInteger320([
+                0xc31b9d0167bd86fc,
+                0x17e46510b19fb269,
+                0xd3228e3e4fee9f81,
+                0x6ed4abc7fa8094d8,
+                0x0000018dfdef0732,
+            ])),
+            Felt::new(BigInteger320([
+                0x1018963e072c3e6b,
+                0xdfb1d7f38580ea2b,
+                0xec51020d980846bd,
+                0x6ef31199a9637390,
+                0x000001669e017650,
+            ])),
+            Felt::new(BigInteger320([
+                0x1840a65921fcce1b,
+                0x0b57853c70d002df,
+                0xe6ed9fbe40003dc6,
+                0xcce5c7de639fbdb6,
+                0x0000035bc8724bc6,
+            ])),
+            Felt::new(BigInteger320([
+                0x413c2fa8ee1bd2cc,
+                0x673052d68f0d892c,
+                0x4b61f1cdad74c249,
+                0x4bf647d7f50a53e0,
+                0x0000010f883104fc,
+            ])),
+            Felt::new(BigInteger320([
+                0x19d7b811ac320c0f,
+                0x35775c882d4e65aa,
+                0xc732e56cd9011b65,
+                0xad5744d173cdf5aa,
+                0x0000009850ee715d,
+            ])),
+            Felt::new(BigInteger320([
+                0xa6c8a5c330be5aed,
+                0x342df215a2efaa4b,
+                0x57d4e64ce1a6f0ba,
+                0x911a79a92fca05b2,
+                0x0000023aa1ca0dd5,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xbbff89c19c64b92d,
+                0x7d7f4a9e4b8a3f95,
+                0xc5ca38e896f5be1e,
+                0xfc733c1342ad8355,
+                0x0000033cab6ce526,
+            ])),
+            Felt::new(BigInteger320([
+                0x9d4ac426c45fe5de,
+                0x0e13e8fdcd831fb5,
+                0x8c9696c0f62cb47a,
+                0x67a930c821d3c3c7,
+                0x000001528c8fea35,
+            ])),
+            Felt::new(BigInteger320([
+                0x0f06a095902026b4,
+                0x5cdbc7031547e2c0,
+                0x69c8238e0b367546,
+                0xf0472cbac5bccf15,
+                0x000000cc86e6c2f6,
+            ])),
+            Felt::new(BigInteger320([
+                0x8df2b487abe3d1af,
+                0x741afb3748149a33,
+                0x4b4cd33e602911f6,
+                0xf45d56666b6c6ed3,
+                0x00000358cda074f0,
+            ])),
+            Felt::new(BigInteger320([
+                0x6c4569a011d07adc,
+                0x4182941dad95bd96,
+                0xaeb89cc995c5d635,
+                0xc16dfc4ebe5a09da,
+                0x000000aa9bf1d7f5,
+            ])),
+            Felt::new(BigInteger320([
+                0x6307e8353257b8f1,
+                0x7c5b8dfb18f1e94f,
+                0xb709ffe8270d10d2,
+                0xe8e1755047ed6209,
+                0x0000025a440e61ff,
+            ])),
+            Felt::new(BigInteger320([
+                0x951af74c1646bbc7,
+                0xa14db8aa10459140,
+                0xde515431a7a807f2,
+                0x8e34c690f0bcf4b3,
+                0x000000270956cda1,
+            ])),
+            Felt::new(BigInteger320([
+                0x3ed64c943172a4bf,
+                0x64ab9668684a31c3,
+                0x452b628446061314,
+                0x0b0e6400a9d3c5ff,
+                0x00000345d7ee03bd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x81acd3e553f3e515,
+                0x27b441b7710d5f81,
+                0x85c8f0a1adee26df,
+                0xfa2cf998267da0bf,
+                0x000000a7044364c4,
+            ])),
+            Felt::new(BigInteger320([
+                0x750b73daeadca57d,
+                0x74c8e1e7087a7626,
+                0x4e81b04d02cee57a,
+                0xdac259b78bc15f4e,
+                0x000001856aae1eac,
+            ])),
+            Felt::new(BigInteger320([
+                0x0ad0cc4f659a60e8,
+                0x4bb2264937576dfa,
+                0xffb5e2d3fc5a0f17,
+                0x82e8b87cf7080c1b,
+                0x00000063e77702aa,
+            ])),
+            Felt::new(BigInteger320([
+                0xe0e343002d774091,
+                0x83ea14bdc6d91266,
+                0x67804bafb6e75462,
+                0x762b2de740c9a4de,
+                0x000001ac4e0e9d2f,
+            ])),
+            Felt::new(BigInteger320([
+                0x30c65a36e648d251,
+                0x2e7b51328d669f9c,
+                0x7db546baacca7ba7,
+                0xd01577646d28b91e,
+                0x0000039578e33ed8,
+            ])),
+            Felt::new(BigInteger320([
+                0x05aa24478ec6db03,
+                0xbc6a6f75112aef40,
+                0x0c7a078423ab3b91,
+                0xdbd644cca9e5f441,
+                0x000003694fa4e937,
+            ])),
+            Felt::new(BigInteger320([
+                0xee308036a1168042,
+                0x0b520cf66dc64306,
+                0x9688a8685992663d,
+                0x3a4679bb0ec25b13,
+                0x000000cc1c7afc62,
+            ])),
+            Felt::new(BigInteger320([
+                0x20d403e6a91dc838,
+                0x2ba15fe1bd62039b,
+                0x0ff4b0d556a60f3b,
+                0x6389b9f6f046d253,
+                0x0000019047e49c84,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x22d116ffaa3b2e37,
+                0x8ee4b0df33c91cab,
+                0x4f761cc939d4682e,
+                0xd5a9e1ffe99b84b5,
+                0x000002b58ae2065a,
+            ])),
+            Felt::new(BigInteger320([
+                0xab661927bb091d6c,
+                0x023d80a2dcb3d1eb,
+                0x6f8c7019bd8e48c2,
+                0xcc6d6e7b7ceedf04,
+                0x000001d879a2440a,
+            ])),
+            Felt::new(BigInteger320([
+                0x2bc5a84834ce08e9,
+                0x16020b0ee8398ef9,
+                0x7f61d66af8031211,
+                0x53a5e3dee62e47ba,
+                0x0000010f747d051a,
+            ])),
+            Felt::new(BigInteger320([
+                0xc29c5e28319bccd8,
+                0x48b60390236b4134,
+                0x171f295378b7e295,
+                0xbac83d82113603df,
+                0x000002a80494fdb4,
+            ])),
+            Felt::new(BigInteger320([
+                0xed0f9a25a6bff024,
+                0x66976d3e8077ee44,
+                0x50558e8ba135fc08,
+                0x5ae6dc6669ca4ed9,
+                0x0000029a1a97bb12,
+            ])),
+            Felt::new(BigInteger320([
+                0x6dd2191268cdc001,
+                0x0d2688bcfb70a31d,
+                0xaf09c720c8f7b7e4,
+                0xfcf70f9532326bab,
+                0x00000022099cf06a,
+            ])),
+            Felt::new(BigInteger320([
+                0x713fb45b013280bd,
+                0xa138c018235a9b7a,
+                0x1d4652b6fcb4bdfe,
+                0xbce0c23c870e319e,
+                0x0000031aacef9fea,
+            ])),
+            Felt::new(BigInteger320([
+                0xe5e151142a996680,
+                0x49db00efe497dc95,
+                0x1e7a0130c27f6afe,
+                0xa45e5e37daa4f239,
+                0x00000347acc1034b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x30ef93075cc4e239,
+                0xb7eddad7d979b2b3,
+                0x8439db3bdce3eba9,
+                0xa5b711dd8b18069d,
+                0x00000178f0a20d64,
+            ])),
+            Felt::new(BigInteger320([
+                0x7bdf11a2857de59a,
+                0x55e7d5fcbd2bc776,
+                0x216ba8679387ee12,
+                0x2bacd7c95f2bc23c,
+                0x00000122bae89a24,
+            ])),
+            Felt::new(BigInteger320([
+                0xb1e5e0aa1cd4be95,
+                0xd60191fa07ab077c,
+                0xbfe213145c78fccd,
+                0xe3ae014a3122f033,
+                0x0000031a12dc2a9d,
+            ])),
+            Felt::new(BigInteger320([
+                0x119c7258c4b2a05b,
+                0xcf8c4d2ea1b9941a,
+                0x38ae3c451eb1ba4b,
+                0xebb2471602c31a56,
+                0x000002154a56bb34,
+            ])),
+            Felt::new(BigInteger320([
+                0x433d163114a62135,
+                0xd230b79f2c9d1d02,
+                0xc99cfd1d4b05d078,
+                0xe47e0b5f4d4ae904,
+                0x0000008c82075de5,
+            ])),
+            Felt::new(BigInteger320([
+                0xecc7410f5760cec3,
+                0xefcd5ad1f6d2749d,
+                0x4e85894c02e39aec,
+                0x0bba6c4458224914,
+                0x000000d1551f5c2a,
+            ])),
+            Felt::new(BigInteger320([
+                0xd8d6f54aca5845a9,
+                0xa7490c595704388c,
+                0x4f034c20fccd9453,
+                0x73d4defc010099fe,
+                0x00000193b23e7552,
+            ])),
+            Felt::new(BigInteger320([
+                0x2c1fffe419281d3f,
+                0xe12b86c025d932d1,
+                0xa63b1d0cd77a429c,
+                0x977ac56986ff5cbe,
+                0x000002a6b1598b39,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0xb4efa5a31fb05fd6,
+            0x68b3ec3818735166,
+            0x907f553f8ba615f9,
+            0x7abc3e4739d474fb,
+            0x0000012aa9affbda,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x5d39e01823bca68f,
+            0x608a3a6caf96ce14,
+            0x32e892ab6f5bc443,
+            0xa91e3c9fdaea2ae4,
+            0x0000015dd8ae97fe,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xcbf6da9b3108b8cc,
+            0xaae214d8f2789f4c,
+            0xc20a0327ca0b473c,
+            0xbe99360c9e74832f,
+            0x0000006f36351653,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x07362c690f84b04e,
+            0x092f617d282d7212,
+            0x63cd81a8e3045ed4,
+            0x2d659189546d117f,
+            0x0000024abceac916,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xa6b2376d5b70ded4,
+            0xe67f9eac94136d38,
+            0xfa2c2624ce720b13,
+            0xbafa44c51958022b,
+            0x00000335be7c3fbc,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xc2974159b16145c3,
+            0x988d0841344a37a6,
+            0xe155ba2e300fb128,
+            0x7d0e3df3640d6138,
+            0x000002c35a77fb5f,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x7830f65d8914a3e4,
+            0x2aeb3d92ead77ca6,
+            0x8fc6d13cc7ec6a58,
+            0xa92076edd8eca631,
+            0x000003bb8d1a36f9,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x541a828c6a3a1332,
+            0x3823c667d3e765b9,
+            0x6d5215246276b286,
+            0xcb9ab982e1a5f8e6,
+            0x0000003903236151,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xb1cb17648e0f1302,
+            0x4d01ee96475fbea7,
+            0xd6e44e4f147aa586,
+            0xd2cea1390d066de3,
+            0x0000003bdb00a944,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x05f81d4eca4104b3,
+            0xd234cd828ccfe6d4,
+            0xe248fa77e8d8428a,
+            0xb521afef0856e53b,
+            0x000000786f899c75,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger320([
+                0x6b6f65b5f3b189b7,
+                0xe164b03f6c490319,
+                0xbc9c1a48dc867f29,
+                0xcd66102acc1500e0,
+                0x000002b765a36f01,
+            ])),
+            Felt::new(BigInteger320([
+                0xbe746ee764af73cb,
+                0xa31c1089ec88f49c,
+                0x5525760a17d9780b,
+                0x2c77d8725e298879,
+                0x0000015e3012a677,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xe4eb827081a2ab98,
+                0x592eba9c4bc6ce01,
+                0x6c79ff4b5c0b6267,
+                0x2d068b19bfb1737a,
+                0x0000037f4d75869d,
+            ])),
+            Felt::new(BigInteger320([
+                0x5e26275f4752229d,
+                0x754d904383f75942,
+                0xdb0aa540a85dbe1d,
+                0xa11484f664f16626,
+                0x000001c98b147a05,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0x1c8adfa7864be477,
+                0x8d9c6869d598cfce,
+                0xcbf77ec1e79a884c,
+                0xb9a3f35462873932,
+                0x000000239a425afc,
+            ])),
+            Felt::new(BigInteger320([
+                0xc2ebfd87cd83a3fc,
+                0x385fd8280a47ac6d,
+                0x8025e725a78b0684,
+                0xc785b71447bb5d6d,
+                0x000002d76b089a80,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger320([
+                0xe3ffb68b4d60c8f8,
+                0x959f686d4744d09b,
+                0x85a901b9c1ed805b,
+                0x30265059a3e6397b,
+                0x0000014affc04db5,
+            ])),
+            Felt::new(BigInteger320([
+                0x442a2e0e72035eed,
+                0x1df7f0b911818889,
+                0xfa6330739972972b,
+                0x9b1db36a25f228bf,
+                0x00000132e9b3a8a7,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger320([
+            0x79bc845397c72a95,
+            0x5d4a308ceac4602f,
+            0x07a7108e75dfeb62,
+            0x4cfd9fa6f8ce90b0,
+            0x0000023eea6c2441,
+        ]))],
+        [Felt::new(BigInteger320([
+            0xfbfd82a26863e295,
+            0xeb3ccdbc7a3ecbab,
+            0x979e69c516e79833,
+            0x90009a7b7ad996a0,
+            0x000000c3151b0847,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x9feed52f61aaed24,
+            0xe90ae3e17fd45690,
+            0x28d98948cf44c873,
+            0x036479c0bf5f8ed3,
+            0x00000315519a9b54,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x92315788f93b12d5,
+            0xb6092f82897ebd93,
+            0x63f0a1487cfed6eb,
+            0x8e449c1edc33a8e8,
+            0x000001d6df85c5ff,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x1670cca4e108d833,
+            0xd6e895da9486e6a7,
+            0xd2ea3383f645657a,
+            0x20331cbb318ec108,
+            0x0000010e39348983,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x9150064132bd94d7,
+            0xb8f323341d537854,
+            0x188290ea47fbed40,
+            0x53b8270f08076b35,
+            0x000000e9deb0e667,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x947d08b60c1aa4b7,
+            0x0f9fd9866410bd02,
+            0xe28ba057b92ea98e,
+            0xef1bce29a0f146a4,
+            0x000001ba8fc6cac6,
+        ]))],
+        [Felt::new(BigInteger320([
+            0x41616312f3b56685,
+            0x986c46dda00ff54f,
+            0xed2230964ecd5463,
+            0xadf7239e1762ec09,
+            0x0000037b29835f55,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,1942 @@
+use super::{sbox, BigInteger320, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 304 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (38-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa0a4a7c8a686afa1,
+                    0x834c6b998414c395,
+                    0xfde5d36dc5d41a6c,
+                    0x6520c3056a89b7c6,
+                    0x000001337ab575bc,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf912544ca5af32c1,
+                    0x4ff7881c8cac9aa3,
+                    0xafe913067193e25c,
+                    0x176f6e3012d857eb,
+                    0x0000032b902c77f8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb275eae305a55ae,
+                    0x73bce3740a450e0b,
+                    0xe5f0e6a33d73bce1,
+                    0x3eb9cbad2e4a0f6d,
+                    0x000002554db3b9e3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc4711a297e94693,
+                    0x4614504f8e232077,
+                    0x56a3d9c93599524b,
+                    0x907e47e3f195969b,
+                    0x0000018152da5b57,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3064de91dddb0ea2,
+                    0x32817612b2f0e0c5,
+                    0x019172c7e0697129,
+                    0x80e2701b59b76f65,
+                    0x0000025be6998539,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2dab6e200b2b642b,
+                    0x702ad6b625fac2d2,
+                    0xccee2f6ea3889981,
+                    0xf1f978f0e663941b,
+                    0x000002b84e200e87,
+                ])),
+                Felt::new(BigInteger320([
+                    0x925dd1fb4320e368,
+                    0xbd1c65c62d3a8a48,
+                    0x8850562067b6c58d,
+                    0xa4820f2834aa918e,
+                    0x00000197168152c3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcde626340ee49fdc,
+                    0x8db7c9e8195d0628,
+                    0xe7f35fa9d1f5077f,
+                    0x9103d27e6e24395f,
+                    0x00000392975d782e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd54b12ca11e59139,
+                    0xb6fa1dae60d6999f,
+                    0x22ac0c5f6fe4f125,
+                    0x7ef631fdcdd1299c,
+                    0x000002f7cea43ac8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99f6205d228e0952,
+                    0x9c07364f9a7ab499,
+                    0xe89f8da35ae37124,
+                    0xc80192f4b05da80a,
+                    0x00000397f36fd488,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfde049d791efd7dc,
+                    0xb91d4392bad3ab84,
+                    0xd91cd786add5b61d,
+                    0x44d98afbc6f4ad78,
+                    0x0000032804f4f34d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96d5e30300908f33,
+                    0x7f58e6e32403df31,
+                    0x8ec50218909b25a0,
+                    0xe3d939e4c279c950,
+                    0x000002a3a292183b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x068d4c35819854c1,
+                    0x224576ff5c1fa505,
+                    0x448cebfe2cf9b5a6,
+                    0x38cccabde097dbd1,
+                    0x000001b2cb668882,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ada2647260f6a9e,
+                    0x307496265dde0f53,
+                    0x1d0b9c7f24f4658c,
+                    0xc45949858704e17e,
+                    0x000001628e81088e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x311fe100f2cb1edb,
+                    0x917483b632987fee,
+                    0x4951f7c836319ab7,
+                    0x7460c1d04a7c0384,
+                    0x000000b6b1c2e36f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5e097ca552d8d3d,
+                    0xa4a3599ef7eb03c1,
+                    0x88db165af2d9d82d,
+                    0xbd5457ba0c17b852,
+                    0x000002bbec29868e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8bc13cf0c469aebc,
+                    0xa983a7fc6ffafbe1,
+                    0x79acdacaf8bf63eb,
+                    0xcaf5c499f4d7382b,
+                    0x000000c728906576,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6100e8511f67922,
+                    0x1665448f2f621813,
+                    0xf7ac728fbfe2fe77,
+                    0x5b020d5c7ba7949e,
+                    0x000001c939b31b36,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc98d7e8ce6c3ab13,
+                    0x41ff40274ee045e9,
+                    0x358eaed0fe8b2d1b,
+                    0xaf7626e33d8e138e,
+                    0x0000036ee3e7d3bb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x796e90e1ca9a53bc,
+                    0x7f7313f2f001a5e8,
+                    0xa3fef87fdb03c837,
+                    0x3549bb27d9fd863f,
+                    0x0000012c39549c90,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01a0e76aad847f3a,
+                    0xaa8540feed1734b0,
+                    0x4ffe033917490397,
+                    0x4955d801d292d874,
+                    0x0000036b09f75c41,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5beb43c88a637885,
+                    0xeadb49362d5c9c2d,
+                    0x7951feb703f01f0e,
+                    0x68c7a967c088e1e2,
+                    0x0000022ad63dabfe,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1efb4cd61e3f8aa,
+                    0x6fcc372dc5fcb10d,
+                    0x4df4db60c0a39b69,
+                    0x4768292da62e375d,
+                    0x000000e4720b47fb,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3874a0b5161b6b9,
+                    0xf79ad4c5b65f166c,
+                    0x65c08f00caa9e1e4,
+                    0xe4ade29000d78d6d,
+                    0x0000001847811e53,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4df2c138e3d63940,
+                    0x12acc69b42b5703a,
+                    0xce1dd4a027c7ae2d,
+                    0x062c0670e937e120,
+                    0x0000024fbbc49a2b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2cd6255d887c8ae,
+                    0xc3132e449c86e5ca,
+                    0x1d8da9fd9b892d5f,
+                    0xc7fc1b55123ca24a,
+                    0x000002c22c020ff0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc638298d4e0b2e06,
+                    0x72c150472a55cfd9,
+                    0x43deedb53a855b32,
+                    0xc7af1da943dec431,
+                    0x000001063462f1bf,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb1adef22575a404,
+                    0x4919bbe0a0fbba81,
+                    0xe736aaa4aededd69,
+                    0xef5e6c45cf56d033,
+                    0x00000090aa9275b7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5e0083d0176a82b,
+                    0x72f330f93eb51288,
+                    0xf6dda9c38c219a28,
+                    0x53e1da5f3aa51679,
+                    0x00000151f378bf94,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf3a70ad510a2527,
+                    0x41f2ec06d716b7ce,
+                    0x486fa2a094c1c072,
+                    0x754eeead14d09d69,
+                    0x000001fd90e59aa5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3b45c666d8bed3e,
+                    0xa75acd80e08c1833,
+                    0xca45fdd6d0155615,
+                    0x760bc87c4a4e96a3,
+                    0x00000148dbe01d21,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7dbd056b15a26b72,
+                    0x3d9a597228a309ba,
+                    0x4f952c5fb7519e4f,
+                    0x5a77b0636063fb84,
+                    0x000002f168c65953,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x61f244f9793cf520,
+                    0x1ec8538d082159cf,
+                    0xcc2d9dc51e0596ea,
+                    0xa8b5cb44f79e8ff4,
+                    0x0000033d6e0ff453,
+                ])),
+                Felt::new(BigInteger320([
+                    0xacd84548d12d0dfb,
+                    0x51d0c4d2386b3fc7,
+                    0x47be25eb550cae53,
+                    0x9528f07d186ca78c,
+                    0x00000010a831db91,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe891cd4ba80d91f0,
+                    0x8f4b55e73a38928e,
+                    0x668c7bac8189a8bc,
+                    0x6bed91ef4baae1cb,
+                    0x0000008f2950f14e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7b497429784a9f7,
+                    0x77bfc765c583e46b,
+                    0xe9c84e574b2d8e79,
+                    0xcf635e0e68be3fdd,
+                    0x000001c5ba632874,
+                ])),
+                Felt::new(BigInteger320([
+                    0x466126b20c5c9b0a,
+                    0xc48e35c2735bfa6a,
+                    0x7a170e59eec6561a,
+                    0xf6a29678ee26bcd9,
+                    0x000002179ae91068,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfdae99683a92bd08,
+                    0x28f5c695425444a4,
+                    0x52f0173a694fc190,
+                    0x10525e51cddb11b5,
+                    0x0000029c8993952a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b81480d1ff5ea65,
+                    0x2f72b9cb6c962c44,
+                    0x592597d52f624895,
+                    0x64cfe6ca1c96e810,
+                    0x0000023e5ee31df8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x496ee0955037cb5f,
+                    0xb79f671818607625,
+                    0xef9174fb39d150e4,
+                    0x9969998780bcbb52,
+                    0x00000338cdd434ce,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa14b973e858b34ad,
+                    0x28063d62e4074540,
+                    0x5dd3f26adbfa758e,
+                    0x81806ba54e1f011a,
+                    0x0000034c8fcc8e3d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e36d084324e76f6,
+                    0x5cfd5795cffff88f,
+                    0x78a3bf3e8099524b,
+                    0xccc451adf41e2a3b,
+                    0x000003650ae3653d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa159d250cd2e25b4,
+                    0x7395a5146359eb99,
+                    0x54d657e9e3418604,
+                    0xbc62dd467bb623aa,
+                    0x0000018cb95151a0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9122f305ef1fef83,
+                    0x5559982ad86e40c4,
+                    0x3cc4e26359584383,
+                    0xc3307e58cf9cad9d,
+                    0x0000003a459f36af,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e0b8466bae36e31,
+                    0x9941024b7ecf3fb2,
+                    0xcfb75f4ab8627905,
+                    0xf1f57fedf150724a,
+                    0x0000004c922c000b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91a04604fdd6de50,
+                    0xd459ad06af426bbc,
+                    0xc73b37d09d81d940,
+                    0x07c7811da90dfebb,
+                    0x000003acd4bb131f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb6bfe5f5ebcf236,
+                    0x200ec6abe7d05752,
+                    0x6af917594db09101,
+                    0x50d7eb5646bee4ab,
+                    0x000001b197632836,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd34dfff0e78d582,
+                    0xf73f5d6815bc93f4,
+                    0x30544e8286a9cecd,
+                    0xcef4172dadaaa3b9,
+                    0x000001dfe223126c,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05c2fd38ccc5f3a3,
+                    0x41cebcb5030817b8,
+                    0x22c61cdba3d5b78d,
+                    0x3e30742ec52f9912,
+                    0x000000ec4e924944,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa2d6d0fc89de9b,
+                    0x88725f9de99493a5,
+                    0x65487e3380219431,
+                    0xc3eac07f7a9dbd5e,
+                    0x0000038a0e40860c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb0021c0641b54f,
+                    0xc10a99477be5a22f,
+                    0xe4a3602b4f656173,
+                    0x7f7bdcd3e9403ad3,
+                    0x000000627c42e477,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe81d609fbb38d1bd,
+                    0xc47930013c695280,
+                    0xe5c3185bda318a49,
+                    0x5860004854139b11,
+                    0x000002b0830125c7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a758b6ca941fc3,
+                    0x6dba9697d84d9da1,
+                    0xaf617bcb23ede71b,
+                    0xa6005b9111d975de,
+                    0x000001bfb850887c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf753e54c9d4a392,
+                    0x07e8cdafe0a80475,
+                    0xacb745c13be7ab89,
+                    0xd63c83a6d00a73cd,
+                    0x00000187f0835c64,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82bb9b400447ba5,
+                    0x5fc8850498c7534a,
+                    0x050f3b95b083993a,
+                    0x794de405433502f7,
+                    0x000001fbd57fa0b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x12c40b16544636e9,
+                    0x0da50a17e54c8500,
+                    0x8dfd4c16c52a5802,
+                    0x0d5669c9f57b0d5a,
+                    0x000001090a7602bc,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8f1613b3b5c0a1a,
+                    0x9885d2ac86fe3fa5,
+                    0x14c05a7e66f07664,
+                    0xa48d1fd914537a9e,
+                    0x000000392ccd3f29,
+                ])),
+                Felt::new(BigInteger320([
+                    0x875ba5b0561af0d4,
+                    0xbcbfebdf88b7a243,
+                    0x13d37c2115369836,
+                    0x341ace72d9b45651,
+                    0x0000032f3a63833b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x320de19101f50d94,
+                    0x2314472fd3795357,
+                    0xbac834729f71e9db,
+                    0xc8ad6d999e4a4dc9,
+                    0x00000244716f0b5f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x933347ff80428c4e,
+                    0x61ddb515ce917d93,
+                    0x4994452fc4331eb3,
+                    0x98be40866c45d5b3,
+                    0x000000800843f017,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a80e0fed5c93bd8,
+                    0xb1bb34fc497d7fcb,
+                    0xb4a24bed39fcfafb,
+                    0xefb1229a2bd478a0,
+                    0x0000000fa885f38e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f661c6339d8a8aa,
+                    0x6ae81ba24d8c53f6,
+                    0x61856a6000be5ebe,
+                    0xbad0096e3a17fc6d,
+                    0x0000006bb6ca5397,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3706ec5b8867360,
+                    0x90563d8ccdbcff98,
+                    0x36bd8f4419719d80,
+                    0x7ca46357f2d8f046,
+                    0x0000023363748ca7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x149410d99f4c725b,
+                    0x8b8bf1e435e53d09,
+                    0xe0c946380394327d,
+                    0xf0f8a74180c0c249,
+                    0x00000186d0792022,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc17be11413d51ac3,
+                    0xc1422328c8ec7167,
+                    0x278188e6ddc5eb7d,
+                    0x03e20486d6306832,
+                    0x0000005c905c4fdb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61d8c2703f9d2cc1,
+                    0xd064029dad861404,
+                    0x472db73dc6f2b50f,
+                    0xf674d2287a076dc7,
+                    0x000002a19e3baf3b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ae9f083bed381bc,
+                    0xd638516985bbec8c,
+                    0x8aa689e223c1bb8d,
+                    0x5100d9440e595fa0,
+                    0x00000097f4ab0682,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80c19da83053407b,
+                    0x0629a109873317bc,
+                    0xe808ed98b6d96035,
+                    0x8e4c126e18131cff,
+                    0x0000036c027de326,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e046377cd6148d4,
+                    0x8c1a3600b6f07a4a,
+                    0x493513c74fa87049,
+                    0x7df96b706c4ed247,
+                    0x0000009f1daaa790,
+                ])),
+                Felt::new(BigInteger320([
+                    0x503db36c46c76b6b,
+                    0x4b6ce1c59982121e,
+                    0x33653941c20de051,
+                    0x3b150a889ce1a5f6,
+                    0x000002867855eab4,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa5d1ac9e48cc2fe,
+                    0x855e26aac4445b6c,
+                    0x82f93bb30888c31c,
+                    0x1fb1c908516e32f8,
+                    0x00000114fe8f60ba,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x85d76cd6dff6f0b9,
+                    0x2cfd11e3a23c9f70,
+                    0x47b8beb797cad874,
+                    0x4dd85424d4578198,
+                    0x000002203148ad8b,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaced35d3f559e5df,
+                    0x389421a8a0af9d5f,
+                    0xe74d3a45e94c1c59,
+                    0xa923c40cb47f7130,
+                    0x0000009c1903a2c3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a79b2360a30c428,
+                    0xc34c1291256c6704,
+                    0x58851c564e7c4963,
+                    0x5d51716dae7fef2c,
+                    0x000002e69879fc2d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7d322dd2fa83c57,
+                    0xfa2d9aee2992b891,
+                    0x1d895057a776099e,
+                    0x7fe1141d10fc1df5,
+                    0x000002b0a0496bea,
+                ])),
+                Felt::new(BigInteger320([
+                    0x710fe77d8f6a8ac7,
+                    0xb8775d029de05696,
+                    0x7aaa62bab192e062,
+                    0x971f2447e14c3904,
+                    0x0000018af2c37157,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe8c7fa8b9e4218b5,
+                    0xe9531bdaeb30c162,
+                    0x4396a5710851c55e,
+                    0xee0e474ae2d8efe6,
+                    0x000001e1b1055015,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f68bfd949d8cd34,
+                    0xc8f7045d83b83e71,
+                    0x8447d8fdb83a31bf,
+                    0xa5d1b0e86dd94bb2,
+                    0x0000024dd7b713b1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x789480d40fbddff5,
+                    0x425070bb36b3a1eb,
+                    0xbb023a1bce52b03c,
+                    0xe47e07ae0c3fa5cf,
+                    0x00000388b2e21e9a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xca81289978e15b75,
+                    0xb572c01ce68eb9cf,
+                    0x0a76da48bc04cd00,
+                    0x152d41e63fde4e1f,
+                    0x0000034a3d05729c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x768968614d9e017c,
+                    0xb758dbe5f3b4a398,
+                    0xbed67d049a97d472,
+                    0x90d950fd53f21d46,
+                    0x000003a93d32382b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4732eedb5f37b89f,
+                    0x30f189443fbd6161,
+                    0xb65d8e22fd978e56,
+                    0xe173140d7e8c5517,
+                    0x000002178c0236a7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9790b6b8d5c6d09f,
+                    0x824d5e412fdb0e4d,
+                    0x4900d57e2c3676f6,
+                    0x3e9a2dd57163a1fe,
+                    0x00000224ff534d3c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdada6efd7084decf,
+                    0xdfa37f26badefe26,
+                    0x04bae4d2a85baa56,
+                    0xc1641dc605d88790,
+                    0x000001cf23f14d4a,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcbbad3f51ac240a3,
+                    0x66ab7a4e46401640,
+                    0x12120ecc20c72119,
+                    0x7d81c4ce8d3053e5,
+                    0x00000284a2c0c4fe,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3754b34e20db2e33,
+                    0x7a73052561619d50,
+                    0x7ce1007961136a74,
+                    0x690930976b84a79f,
+                    0x000002444ff0c5b8,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdfc03c96704e3b78,
+                    0x208195b294cd90b1,
+                    0xce21864c988c7f58,
+                    0xb9f0eb63222a6a07,
+                    0x000001078b8fbbf1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcf009388a37d42c1,
+                    0xb1ff152d79cebc15,
+                    0xfdcf8c0f526ba5fb,
+                    0x2b016617c22769f5,
+                    0x000001fc63b9a8f7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78ac65e156bc4145,
+                    0x2b20cf2b48fc9d61,
+                    0xc22e4fc826521a0a,
+                    0x317d7aecd87d3ee1,
+                    0x0000013c760fa1ad,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ea714dee602d2ce,
+                    0x4943786f61a32819,
+                    0xf8cf196ecbdebb63,
+                    0x6f002ec5181b6d58,
+                    0x000001b13f9852b2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa814c49b3cc0e134,
+                    0xca428c3bb77c15b8,
+                    0x8905620d83db6774,
+                    0x1ce48fce7aebafcf,
+                    0x0000010efdfa2c06,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74109b22704ec727,
+                    0xac2c1dcd02e45fe3,
+                    0x32af53e165cf243f,
+                    0x5c78b81725d260ee,
+                    0x000001531e153f13,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b8bd15b8ea4c1e9,
+                    0x988e6a80c928c644,
+                    0x17293a734d867ce0,
+                    0x1620913b3a04b14a,
+                    0x000001f93b6910e6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9063d5e85d6f26bf,
+                    0xdee01f610720de6b,
+                    0x056f3f686fa42e6c,
+                    0xc5752eeecd35410a,
+                    0x0000018b2323b405,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0399f6a369da8417,
+                    0x080532949c4fcd6f,
+                    0x40c57296429bfd0b,
+                    0xe940971afac1e3b2,
+                    0x000000e7d86cd340,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x200083e44282f205,
+                    0xe70dedf8ff8572f7,
+                    0x0494ef0a180d3bf4,
+                    0x39391ae1f211ba25,
+                    0x0000018fcfa20fbe,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6dc700b6216f6e91,
+                    0x32edb0644baedeea,
+                    0xff807acf04ce6264,
+                    0x2ece8caec238878f,
+                    0x0000021f6dae5bc3,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19bb25024636470f,
+                    0x4a87acdc96b023f8,
+                    0xa1a41a0f2b23b85d,
+                    0x101a321f97663a65,
+                    0x00000310e747e0a5,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2facb3cd93ad56a,
+                    0xfec97ae47784c376,
+                    0x758aa685d52e0538,
+                    0xbdaa2ec04d533494,
+                    0x000003695387f31c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5fa13021e207ca3,
+                    0x84caddcf81dd09a1,
+                    0x224306fdcb44bfa7,
+                    0x252b640a15695c25,
+                    0x00000274f3d6a985,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ead3ae7245a470e,
+                    0xfff90a976bbea919,
+                    0xcf4add143563e9c2,
+                    0x61eac95aeadb8eba,
+                    0x000001e70b1d5e8f,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1af325f9b6cceeb,
+                    0xcb66fdd35be462d1,
+                    0xf279bd17d3e2fed1,
+                    0x8e72121901869504,
+                    0x0000026f4aa65ec2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1ea28131b2dd447,
+                    0x67122cdea3e42214,
+                    0xf2020a08aa620402,
+                    0x7a8540e23b4a2a88,
+                    0x0000035009d5a9a2,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc0bbeb83ea35e0dc,
+                    0x74961bbc1bf1d74b,
+                    0x5a07a77387f7c8af,
+                    0x8ce7c0927d71bbb9,
+                    0x000000a14b1d80ac,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbc293869d608d11,
+                    0x119c0c08a8e80fee,
+                    0x54e5234ad72b7997,
+                    0x3173fbfb00252215,
+                    0x000003107f460c01,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06acc1871f6d804f,
+                    0x27c096b95b7de8b4,
+                    0x376224492698a27b,
+                    0x3d0531a39ccda661,
+                    0x000000e013a75078,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b264e0f88a503f7,
+                    0x1d08dd638af6df03,
+                    0x26d0433bf2cec3bb,
+                    0x2bd0d567fa08a72d,
+                    0x0000027f7646bc95,
+                ])),
+                Felt::new(BigInteger320([
+                    0x112ebeb906dc4034,
+                    0x532c0fc666f88449,
+                    0xa5411bc0ec0c98ab,
+                    0xd1b8e1c8ef105a2e,
+                    0x0000033663548731,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ae03a41f535ec17,
+                    0x4e9da70b0dead421,
+                    0xb4bcc1fb9d826e7e,
+                    0xe3f7eaae61441e54,
+                    0x0000020547e7da92,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6d5134aa47a26b9,
+                    0xd2448df27c48b28c,
+                    0xfc4bf4f68da133cf,
+                    0xa4fde11484bfffec,
+                    0x00000384800bea43,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2ad494d9428fc6d,
+                    0x2e5c267b4fa626dc,
+                    0x447876403a2ffa61,
+                    0xa4099637fb2f985e,
+                    0x000001d7e4bc9c5d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3f63e2933b515943,
+                    0x4360020473e5558e,
+                    0x0415ecb5b97f478a,
+                    0x06a62d60064cc20c,
+                    0x000001a95634ef12,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9850d6fad773fb3d,
+                    0x07adab84639c875b,
+                    0x63e0cb5b0622491c,
+                    0x7acd1da795e299f0,
+                    0x000000548a14a954,
+                ])),
+                Felt::new(BigInteger320([
+                    0x684e1ffd27667698,
+                    0x5fb1faa166bc0656,
+                    0xb3d12c3413fe2f0c,
+                    0x3e7bfd1960b890e3,
+                    0x00000265cd4f020d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68e1041acab745d0,
+                    0x0a290037a76a7343,
+                    0x620113cf193d1b84,
+                    0x6d1697d0b7384349,
+                    0x0000012b4f5f44f8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x323eaa1429f64c9b,
+                    0x5edeeac35de8afa0,
+                    0xf6a21c7cedf1d520,
+                    0x5ac9bdf64bae634f,
+                    0x0000037f74ac6d8e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08b70f6869abf002,
+                    0xd22125ee9ce1921f,
+                    0x7c35882a17083a3f,
+                    0xd49bc8b04f634ac0,
+                    0x0000027aa24eae79,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcabb4b922a58f3ac,
+                    0x28564470cdeeb492,
+                    0x7f3b3390a211fa48,
+                    0x064d9616b78ca190,
+                    0x00000014ec7ffa09,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ebf05916e933efa,
+                    0x23adea950996b787,
+                    0xb5a9bcf81ee94a3f,
+                    0x74ce6f7f9f4614db,
+                    0x000003013527ad4a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x12c4c533567efde8,
+                    0xe706705a654805ec,
+                    0x986ebb752b25557b,
+                    0xe6c35d77e08c32ba,
+                    0x000000aafddfc192,
+                ])),
+                Felt::new(BigInteger320([
+                    0xded5e38b1dd919de,
+                    0x457694e66e881395,
+                    0x1392cb601aae79ec,
+                    0xc9fe39306c4f4aab,
+                    0x000003691d0088ac,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e98fdb73c1cdefe,
+                    0x15831e0010fec333,
+                    0x6ae8031ecd9c19b8,
+                    0x995ae64473c74bfd,
+                    0x0000002732dd81bd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x830050521603a19a,
+                    0x2605b0b11056ef61,
+                    0x592d9aac5a129d03,
+                    0xafe5041dc29642ef,
+                    0x00000319a2bc8cbb,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe714abf7e294fd8e,
+                    0x98455b2c48e4582e,
+                    0x457cc1edc0743313,
+                    0x07dcec78cbb5362d,
+                    0x0000027e79ec8271,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53194b5ed2626408,
+                    0x22e6ab0c51d75347,
+                    0x5de98c0f112df0b5,
+                    0xd9e6514875468073,
+                    0x000002540702d54d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf917d483a92eb87,
+                    0x9c8296f3f5252833,
+                    0xe8e072937732ab92,
+                    0x6444ccc704db4d17,
+                    0x0000006a03c14801,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1288494511e9840,
+                    0xf7e2fb87c0641474,
+                    0x2c7bcbb540c763e7,
+                    0x9a470038e5f03ad9,
+                    0x000002bab5831ab7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2e0cb00214263db9,
+                    0x9d9ffb55c82f8062,
+                    0x45236fb428aff4ec,
+                    0xb7dd3b68525b9df6,
+                    0x00000281c77206b7,
+                ])),
+                Felt::new(BigInteger320([
+                    0x638fcc896e69aede,
+                    0x52d8232c1d056d28,
+                    0x2533d32ce5d432f5,
+                    0x0e5ce2acd0aa60a7,
+                    0x00000323d2f7a410,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8e14e0d852002be,
+                    0xfd5a46bb530f0f60,
+                    0x0bf629a50d762745,
+                    0x970499ef86376399,
+                    0x0000002b0f073512,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee7fa944c902c5a9,
+                    0xd4a188927421bb8d,
+                    0x92b460d375e6207f,
+                    0xf0fca5438ddf6eb2,
+                    0x0000025219c1bf80,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51b71c5841b527f4,
+                    0x474cbe81857a7ed8,
+                    0x664747aa46391a75,
+                    0x6c1f885992a5fbd5,
+                    0x0000015bc8c7b22d,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf969a0eea0620f06,
+                    0xcf0bc3e923a3f4fb,
+                    0x9c6587679a277a58,
+                    0x588a2455e5aa4a23,
+                    0x000000c599322128,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c7f985222012665,
+                    0xb62f34c735ecfa00,
+                    0xdd6e46756d377e2d,
+                    0x2cf8b70bcc9f2c44,
+                    0x000002894ebef1c9,
+                ])),
+                Felt::new(BigInteger320([
+                    0x014cd30030296c46,
+                    0xd77dff2f346508bf,
+                    0xc1b5b21896150bf4,
+                    0xd3b0f677ef853aa2,
+                    0x000000209490291f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x647d956cd97f12c4,
+                    0xfe636aa65c213e12,
+                    0x3b8c7ea01c948ac8,
+                    0x25a6710d472fb4cf,
+                    0x000003b705dad3f3,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6a2461d103c9adf,
+                    0x3a94f47c841f06db,
+                    0xec01dbfa68d2927c,
+                    0x40d0a9db03b69216,
+                    0x0000008afd6c20fb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60f9ab9b03e60d5d,
+                    0x228c720e9cd3aa44,
+                    0x2624ba220de59617,
+                    0xaa533e1c852ad176,
+                    0x00000154410d6172,
+                ])),
+                Felt::new(BigInteger320([
+                    0x714ae619146c7fdd,
+                    0x67cbe80f005751b7,
+                    0x834d05e4445c2cf0,
+                    0x56d0c1b32fd80500,
+                    0x000001a87d3cafed,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f39066ab675fc2b,
+                    0x0d4b2aefd3f4bd4d,
+                    0xdd5eaaf8179050e4,
+                    0x5e9d04ca71db1231,
+                    0x000001ddd9f12b58,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa751cbea87498dfe,
+                    0x0489540a285ca10b,
+                    0x39e5c9f5d79fb4d9,
+                    0x1967f1ea49bc9170,
+                    0x000002ddb23d307e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06865ff4111e0c9c,
+                    0x123528c784931fd2,
+                    0x29b34c49f51d93b5,
+                    0xd8b023baece04ab9,
+                    0x000000ec7ec96360,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5e3232973241daf,
+                    0x70838c024ff2bb84,
+                    0xf8ae53ba1d31dc2a,
+                    0xce156fc074b7aded,
+                    0x000001fc71661854,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4073299277768aa5,
+                    0x01db04b5e73e90a8,
+                    0x0c2e93447ef585d2,
+                    0x48bd37db3f141e48,
+                    0x000000ed7b86bd8a,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c22dc86adf39669,
+                    0x32e62dda20a75982,
+                    0xb5a3336b6e9f6c84,
+                    0x44b83814efa37ac9,
+                    0x000001bbf048db7c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1e20ea07a6dc324,
+                    0x37dcd1dd120c35db,
+                    0xe289b6c9579ace48,
+                    0xf85c42712c064177,
+                    0x0000009d7fca1426,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1561882fe0ce22a,
+                    0xe8e22fac3200387e,
+                    0x9c6cbc8e10203c1e,
+                    0xfeeb57c840b9892d,
+                    0x0000008686a8ca10,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3dac47cd0b72b507,
+                    0x7665600c12ae66f8,
+                    0xf0871852b18a2e77,
+                    0xec01d3e2e2931374,
+                    0x000001e670b47a7e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x167691434ae9ebfc,
+                    0x0163a2e9f3ffe533,
+                    0xd53a6ad2da30b1b7,
+                    0x7758d73b8b87a642,
+                    0x0000039c2cb168e8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0416d267e6685ffb,
+                    0xf645900893735669,
+                    0x0fc2f22f5424f4bc,
+                    0xbe0cf3ac5a804c5f,
+                    0x0000000cf14e0b3d,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a63ea6036e692d5,
+                    0xaac580aa96769298,
+                    0xce6153003ad4da89,
+                    0xe19e7799c1f21a54,
+                    0x000000e0c5c5b4ec,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger320([
+                    0x69ff4863f7708b71,
+                    0xb6cd6bed9f49aeb0,
+                    0xd7f3ece31bc53398,
+                    0x9f0bedcfbc153e9a,
+                    0x00000011c396059e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc387fa7bbf431e87,
+                    0x1461917854a617d2,
+                    0x79ed2790faae32e4,
+                    0x29d6e677f7bdce71,
+                    0x0000022903812772,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24747353c7499bcd,
+                    0x994c6789162b2d60,
+                    0x62c98ce5dad60e6b,
+                    0x3a95553b5ceede77,
+                    0x000001dc8ca8b4e6,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd58f844ff1d266f4,
+                    0xa2ca12e7f350db76,
+                    0xf0e57a43fc6cb4ca,
+                    0x4871f0c514fff708,
+                    0x00000344dcf7cc90,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69ff4863f7708b71,
+                    0xb6cd6bed9f49aeb0,
+                    0xd7f3ece31bc53398,
+                    0x9f0bedcfbc153e9a,
+                    0x00000011c396059e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc387fa7bbf431e87,
+                    0x1461917854a617d2,
+                    0x79ed2790faae32e4,
+                    0x29d6e677f7bdce71,
+                    0x0000022903812772,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24747353c7499bcd,
+                    0x994c6789162b2d60,
+                    0x62c98ce5dad60e6b,
+                    0x3a95553b5ceede77,
+                    0x000001dc8ca8b4e6,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd58f844ff1d266f4,
+                    0xa2ca12e7f350db76,
+                    0xf0e57a43fc6cb4ca,
+                    0x4871f0c514fff708,
+                    0x00000344dcf7cc90,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x69ff4863f7708b71,
+                    0xb6cd6bed9f49aeb0,
+                    0xd7f3ece31bc53398,
+                    0x9f0bedcfbc153e9a,
+                    0x00000011c396059e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc387fa7bbf431e87,
+                    0x1461917854a617d2,
+                    0x79ed2790faae32e4,
+                    0x29d6e677f7bdce71,
+                    0x0000022903812772,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24747353c7499bcd,
+                    0x994c6789162b2d60,
+                    0x62c98ce5dad60e6b,
+                    0x3a95553b5ceede77,
+                    0x000001dc8ca8b4e6,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd58f844ff1d266f4,
+                    0xa2ca12e7f350db76,
+                    0xf0e57a43fc6cb4ca,
+                    0x4871f0c514fff708,
+                    0x00000344dcf7cc90,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x69ff4863f7708b71,
+                    0xb6cd6bed9f49aeb0,
+                    0xd7f3ece31bc53398,
+                    0x9f0bedcfbc153e9a,
+                    0x00000011c396059e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc387fa7bbf431e87,
+                    0x1461917854a617d2,
+                    0x79ed2790faae32e4,
+                    0x29d6e677f7bdce71,
+                    0x0000022903812772,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24747353c7499bcd,
+                    0x994c6789162b2d60,
+                    0x62c98ce5dad60e6b,
+                    0x3a95553b5ceede77,
+                    0x000001dc8ca8b4e6,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd58f844ff1d266f4,
+                    0xa2ca12e7f350db76,
+                    0xf0e57a43fc6cb4ca,
+                    0x4871f0c514fff708,
+                    0x00000344dcf7cc90,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x999d2aa73e151b41,
+                    0x3b01d0b413e8d0d4,
+                    0x690e0de13bb363d2,
+                    0x5a1147b999034829,
+                    0x000000c8bb25971e,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe149d0a7abc6496b,
+                    0x76791cb17ff5e97e,
+                    0xb504e39b7ba22a82,
+                    0x7aa48ae2be3535d7,
+                    0x000002628be345c2,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28e7f066f5479c16,
+                    0xc66027ba9e899511,
+                    0x2ff4d715421dc761,
+                    0x3c88db18fb1876ee,
+                    0x0000018ea36466de,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa11460b8129a6a70,
+                    0xc523437b97bc8bdc,
+                    0x77ca5eab44582e7c,
+                    0x9d7a0c7fee0d4bd5,
+                    0x000002d86bef85f8,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e536f5914a9f43c,
+                    0xd802be51ce7b2bce,
+                    0xa6dd1b4e7ae740f1,
+                    0x5ae5d7d1837ba9a1,
+                    0x0000018e5f62643e,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ee89f656360f8b8,
+                    0x34ca6fbb3971934c,
+                    0x070f008f869d5688,
+                    0x604a795ae0d2f9d6,
+                    0x00000020ed37a3cc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x170748f4e867174b,
+                    0x82609a5f62cc8358,
+                    0x464bd2cfd9e5764f,
+                    0xebf448ae9bab19e7,
+                    0x0000005f5e0c2ff0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c63eb501c66f080,
+                    0xbf701f8675a045a9,
+                    0x8788c64f4714175a,
+                    0x5fd6655cf3afe974,
+                    0x00000057166f5497,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa61479e1f27680e8,
+                    0xe98734e39179ba3a,
+                    0x3ba14bdd1fbec590,
+                    0xa932d68fd98e5b8f,
+                    0x00000394b686af67,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48fad073120ca4b7,
+                    0xcbf46024ea3459c0,
+                    0x2abca951b275796c,
+                    0x7e7f367829bf8f6b,
+                    0x000002f180584a06,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a9b101f325b784f,
+                    0x2d7ad8ca303c7c6f,
+                    0x5712158552584482,
+                    0x56bfaaefec608fd8,
+                    0x0000015aaa1b3305,
+                ])),
+                Felt::new(BigInteger320([
+                    0x187181b178e313b2,
+                    0xfe81344635c64daa,
+                    0xebacd7ff8791fe15,
+                    0x566580fabc7e3b5b,
+                    0x0000023cfa58edab,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9df277a030dddc26,
+                    0xfc2f13b3368bd915,
+                    0x69ed14f70530989a,
+                    0xcab46f2f9594fba0,
+                    0x0000011e02d08933,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10debc5aaaea71c4,
+                    0x8400c13d6cd97bed,
+                    0x55767ed943401eda,
+                    0xb0e8629564b7a02c,
+                    0x000000cd02068687,
+                ])),
+                Felt::new(BigInteger320([
+                    0x465e9fb50e11794c,
+                    0x824917e9a11de989,
+                    0xe6fa259cf729bac7,
+                    0xc11d522cd61290dc,
+                    0x0000023e8e04a5d6,
+                ])),
+                Felt::new(BigInteger320([
+                    0x388f0a5e23050d85,
+                    0x496dc5f42dabf50a,
+                    0xdb803ba446866e63,
+                    0x9f6abbfee91ff5a3,
+                    0x000000514da8e841,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2b38e502d4182050,
+                    0x8b4f90a427e8544c,
+                    0xd3cb7989544b1979,
+                    0x0600674cdd360b49,
+                    0x0000036e29a3419b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78aa5450006e2208,
+                    0xa3a142d45f0e0058,
+                    0x0e2f72bd2906ecf1,
+                    0xadfadd431b0cbc10,
+                    0x0000005b8e4d98bd,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f0c180ba0ae197e,
+                    0x44ceb7710af5c58e,
+                    0xe66580091a8fa2e0,
+                    0x58bc0c9e8ab22e2b,
+                    0x000001017f8aa162,
+                ])),
+                Felt::new(BigInteger320([
+                    0x571d5515758caf9a,
+                    0xa6c7e582a1ff40d4,
+                    0x61d6e7e20d30bd92,
+                    0x00ab9699c41b3d80,
+                    0x000002753c48bf68,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17cd7d575bb65cd2,
+                    0xc4cb161ea3cb097f,
+                    0xef4633f0ca5f5e1a,
+                    0xfa463789c7d4465c,
+                    0x0000030b869c9e2c,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ea156486c199e28,
+                    0xcb2c7443aa13db98,
+                    0x42ae1547a438a375,
+                    0x93666b0d9ad590d6,
+                    0x000001e9406170ce,
+                ])),
+                Felt::new(BigInteger320([
+                    0x197ea1981d5890e5,
+                    0xc5caa0254d454d94,
+                    0x9a31dff96239e1a2,
+                    0xd74b51934d231804,
+                    0x0000019bc488eff0,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f745ab5bfe075ee,
+                    0xfce2a5111fa0dddf,
+                    0xa1309d1cf6f79f53,
+                    0x5ca12bb01b58df84,
+                    0x0000030eae4ba219,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4ad0d8e0e00c9539,
+                    0xac2ad6cba97c7135,
+                    0x621a55215b24d9e1,
+                    0x7f8526f9978cb32c,
+                    0x0000021ff42259f1,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75be82e685f39403,
+                    0xaa3f73706c89533f,
+                    0x320ccc5871c1dc3c,
+                    0x23a02cea923bc84e,
+                    0x000003b5de485e59,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0146f1ed2876eb60,
+                    0xf3436f1f15e464c1,
+                    0x6f5af0b4c82ce8a7,
+                    0xc7eeff687604bf30,
+                    0x0000037e8cd2dabb,
+                ])),
+                Felt::new(BigInteger320([
+                    0x353a1558c2ae98b8,
+                    0x30e8cff7ebd8c6f1,
+                    0x6d4abc8992f06fe5,
+                    0x0864af3608a2cb18,
+                    0x0000011a9a377e06,
+                ])),
+                Felt::new(BigInteger320([
+                    0x752942de89207f96,
+                    0x0ad82d0c6d8a67cf,
+                    0xf7c1a8cae818950e,
+                    0xcafb6340919dafa7,
+                    0x00000268fbb7f4d9,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb65e86c4b8704b5d,
+                    0x2a19354667aceb51,
+                    0x4d9fabf8f4944e88,
+                    0x88ece3b17d4f238c,
+                    0x00000115db3abfc5,
+                ])),
+                Felt::new(BigInteger320([
+                    0x871e7d26f1b30132,
+                    0xa47c00ea53b3727e,
+                    0xcfb1d10b13dab200,
+                    0x43745bd29dfaea4c,
+                    0x000001fbc773dddd,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec9fc1fa62b5bdaf,
+                    0xb0def62d4d657b10,
+                    0xfde9e69933711687,
+                    0xb8a033e035ef528c,
+                    0x000003351e46ec65,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x83843b4d7f533e93,
+                    0x196078dc757e3515,
+                    0x10be94c032a7e499,
+                    0xa2084d6af5410eda,
+                    0x0000022dab049ffa,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f0d6cb9c776f002,
+                    0xe5f3a28f57ddccc4,
+                    0x9d2ec5a5eecd801d,
+                    0xcc4e6564347a8885,
+                    0x0000022c61145b84,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b65d85231df5f11,
+                    0x0a423cce304271c9,
+                    0xe90fc21ce4987735,
+                    0xee570e43e26f6c02,
+                    0x00000083c199dc04,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ce73d19b51486c2,
+                    0xf37087ed5bf7362e,
+                    0x9c69b40551982341,
+                    0x5cb664cd35d3e226,
+                    0x0000018eac2cebcc,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fc7dedce3991b29,
+                    0xf42c5dafd2fb4a85,
+                    0x12fbca0db7137ba0,
+                    0xe8c22c5df526fd63,
+                    0x000002f581b6508b,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52a03f251ddfd7d4,
+                    0xbaf23febf21e1798,
+                    0x4120e4952a947745,
+                    0x8635e1d0563e3fc8,
+                    0x000000afba17a88f,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60b08c26d2ae5745,
+                    0xdd845f0dcc128df0,
+                    0x91be30c7403e4432,
+                    0x8f8bcb1797b41e8e,
+                    0x000001846967ebe2,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd8f58ed8e1b214d,
+                    0x18c2247d1e49f962,
+                    0x1c12ac3ecf89d0ab,
+                    0xc1ec76d6548f0710,
+                    0x0000019aa0cc21a1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4c0a859ea5b8762c,
+                    0x9eeaac356400622b,
+                    0x785bff31657b8adf,
+                    0xd24282128d15aaf3,
+                    0x00000181513e3673,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fbd2f7982beb940,
+                    0x9298b60a7fb28849,
+                    0xf489022c2a6033eb,
+                    0xc66b87a9687d3b15,
+                    0x000002459e756658,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1048aff402706422,
+                    0x286f3c6523981048,
+                    0x3dfec9aab2799b62,
+                    0x819e75870a1f2934,
+                    0x0000036db906c8eb,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc666aa119eaa2db,
+                    0xb857c14ce46dd486,
+                    0x1a72fce732bf7349,
+                    0xe6f938ed0b28deeb,
+                    0x0000027b47d92b9c,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9f9ec5ec8e2da55,
+                    0x07c33a2e527b04bd,
+                    0xa038b8fdcbdb8339,
+                    0x96f91d949ebc13c3,
+                    0x0000020aedeb1b28,
+                ])),
+                Felt::new(BigInteger320([
+                    0x976849c6908a9495,
+                    0x74d4f81c346a162e,
+                    0xac5008692c7958cc,
+                    0xebf06c2d9a20d346,
+                    0x00000131784283c9,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7fbc112f8fa0637,
+                    0x64baf0e069624d0c,
+                    0xf114ac6d8ef37c07,
+                    0x8a5d1980e16cb224,
+                    0x000002cfa856dcc7,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb08c0a73c18c86f8,
+                    0x59e79f67f8039389,
+                    0x95519584ab33a266,
+                    0x40f7705df0bb53f7,
+                    0x0000032add39d2a1,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
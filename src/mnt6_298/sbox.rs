@@ -0,0 +1,413 @@
+use super::BigInteger320;
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 5;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 5] = [
+    0x629c2a1ccccccccd,
+    0x2f6dd66c751788a9,
+    0xd58bd44f7d903477,
+    0xe851b6e48626aa0b,
+    0x000002fd92fd76c2,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 10;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger320([
+    0x05c2fd38ccc5f3a3,
+    0x41cebcb5030817b8,
+    0x22c61cdba3d5b78d,
+    0x3e30742ec52f9912,
+    0x000000ec4e924944,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    let t1 = x.square(); //         1: 2
+    let t2 = t1 * x; //             2: 3
+    let t3 = t2 * t1; //            3: 5
+    let t4 = t3 * t1; //            4: 7
+    let t5 = t4 * t1; //            5: 9
+    let t6 = t5 * t1; //            6: 11
+    let t7 = t6 * t1; //            7: 13
+    let t8 = t7 * t1; //            8: 15
+    let t9 = t8 * t1; //            9: 17
+    let t10 = t9 * t1; //          10: 19
+    let t11 = t10 * t1; //         11: 21
+    let t12 = t11 * t1; //         12: 23
+    let t13 = t12 * t1; //         13: 25
+    let t14 = t13 * t1; //         14: 27
+    let t15 = t14 * t1; //         15: 29
+    let mut t0 = t12.square(); //  16: 46
+    t0 = t0.square(); //           17: 92
+    t0 = t0.square(); //           18: 184
+    t0 = t0.square(); //           19: 368
+    t0 = t0.square(); //           20: 736
+    t0 *= t15; //                  21: 765
+    t0 = t0.square(); //           22: 1530
+    t0 = t0.square(); //           23: 3060
+    t0 = t0.square(); //           24: 6120
+    t0 = t0.square(); //           25: 12240
+    t0 *= t5; //                   26: 12249
+    t0 = t0.square(); //           27: 24498
+    t0 = t0.square(); //           28: 48996
+    t0 = t0.square(); //           29: 97992
+    t0 = t0.square(); //           30: 195984
+    t0 = t0.square(); //           31: 391968
+    t0 = t0.square(); //           32: 783936
+    t0 = t0.square(); //           33: 1567872
+    t0 *= t12; //                  34: 1567895
+    t0 = t0.square(); //           35: 3135790
+    t0 = t0.square(); //           36: 6271580
+    t0 = t0.square(); //           37: 12543160
+    t0 = t0.square(); //           38: 25086320
+    t0 = t0.square(); //           39: 50172640
+    t0 *= t15; //                  40: 50172669
+    t0 = t0.square(); //           41: 100345338
+    t0 = t0.square(); //           42: 200690676
+    t0 = t0.square(); //           43: 401381352
+    t0 = t0.square(); //           44: 802762704
+    t0 = t0.square(); //           45: 1605525408
+    t0 = t0.square(); //           46: 3211050816
+    t0 *= t15; //                  47: 3211050845
+    t0 = t0.square(); //           48: 6422101690
+    t0 = t0.square(); //           49: 12844203380
+    t0 = t0.square(); //           50: 25688406760
+    t0 = t0.square(); //           51: 51376813520
+    t0 *= t6; //                   52: 51376813531
+    t0 = t0.square(); //           53: 102753627062
+    t0 = t0.square(); //           54: 205507254124
+    t0 = t0.square(); //           55: 411014508248
+    t0 = t0.square(); //           56: 822029016496
+    t0 = t0.square(); //           57: 1644058032992
+    t0 = t0.square(); //           58: 3288116065984
+    t0 = t0.square(); //           59: 6576232131968
+    t0 = t0.square(); //           60: 13152464263936
+    t0 = t0.square(); //           61: 26304928527872
+    t0 *= t12; //                  62: 26304928527895
+    t0 = t0.square(); //           63: 52609857055790
+    t0 = t0.square(); //           64: 105219714111580
+    t0 *= x; //                    65: 105219714111581
+    t0 = t0.square(); //           66: 210439428223162
+    t0 = t0.square(); //           67: 420878856446324
+    t0 = t0.square(); //           68: 841757712892648
+    t0 = t0.square(); //           69: 1683515425785296
+    t0 = t0.square(); //           70: 3367030851570592
+    t0 = t0.square(); //           71: 6734061703141184
+    t0 = t0.square(); //           72: 13468123406282368
+    t0 *= t3; //                   73: 13468123406282373
+    t0 = t0.square(); //           74: 26936246812564746
+    t0 = t0.square(); //           75: 53872493625129492
+    t0 = t0.square(); //           76: 107744987250258984
+    t0 = t0.square(); //           77: 215489974500517968
+    t0 = t0.square(); //           78: 430979949001035936
+    t0 = t0.square(); //           79: 861959898002071872
+    t0 = t0.square(); //           80: 1723919796004143744
+    t0 = t0.square(); //           81: 3447839592008287488
+    t0 *= t14; //                  82: 3447839592008287515
+    t0 = t0.square(); //           83: 6895679184016575030
+    t0 = t0.square(); //           84: 13791358368033150060
+    t0 = t0.square(); //           85: 27582716736066300120
+    t0 = t0.square(); //           86: 55165433472132600240
+    t0 = t0.square(); //           87: 110330866944265200480
+    t0 = t0.square(); //           88: 220661733888530400960
+    t0 *= t14; //                  89: 220661733888530400987
+    t0 = t0.square(); //           90: 441323467777060801974
+    t0 = t0.square(); //           91: 882646935554121603948
+    t0 = t0.square(); //           92: 1765293871108243207896
+    t0 = t0.square(); //           93: 3530587742216486415792
+    t0 *= t5; //                   94: 3530587742216486415801
+    t0 = t0.square(); //           95: 7061175484432972831602
+    t0 = t0.square(); //           96: 14122350968865945663204
+    t0 = t0.square(); //           97: 28244701937731891326408
+    t0 *= x; //                    98: 28244701937731891326409
+    t0 = t0.square(); //           99: 56489403875463782652818
+    t0 = t0.square(); //          100: 112978807750927565305636
+    t0 = t0.square(); //          101: 225957615501855130611272
+    t0 = t0.square(); //          102: 451915231003710261222544
+    t0 = t0.square(); //          103: 903830462007420522445088
+    t0 = t0.square(); //          104: 1807660924014841044890176
+    t0 *= t2; //                  105: 1807660924014841044890179
+    t0 = t0.square(); //          106: 3615321848029682089780358
+    t0 = t0.square(); //          107: 7230643696059364179560716
+    t0 = t0.square(); //          108: 14461287392118728359121432
+    t0 = t0.square(); //          109: 28922574784237456718242864
+    t0 = t0.square(); //          110: 57845149568474913436485728
+    t0 = t0.square(); //          111: 115690299136949826872971456
+    t0 = t0.square(); //          112: 231380598273899653745942912
+    t0 = t0.square(); //          113: 462761196547799307491885824
+    t0 *= t10; //                 114: 462761196547799307491885843
+    t0 = t0.square(); //          115: 925522393095598614983771686
+    t0 = t0.square(); //          116: 1851044786191197229967543372
+    t0 = t0.square(); //          117: 3702089572382394459935086744
+    t0 = t0.square(); //          118: 7404179144764788919870173488
+    t0 = t0.square(); //          119: 14808358289529577839740346976
+    t0 = t0.square(); //          120: 29616716579059155679480693952
+    t0 *= t11; //                 121: 29616716579059155679480693973
+    t0 = t0.square(); //          122: 59233433158118311358961387946
+    t0 = t0.square(); //          123: 118466866316236622717922775892
+    t0 *= x; //                   124: 118466866316236622717922775893
+    t0 = t0.square(); //          125: 236933732632473245435845551786
+    t0 = t0.square(); //          126: 473867465264946490871691103572
+    t0 = t0.square(); //          127: 947734930529892981743382207144
+    t0 = t0.square(); //          128: 1895469861059785963486764414288
+    t0 = t0.square(); //          129: 3790939722119571926973528828576
+    t0 = t0.square(); //          130: 7581879444239143853947057657152
+    t0 = t0.square(); //          131: 15163758888478287707894115314304
+    t0 = t0.square(); //          132: 30327517776956575415788230628608
+    t0 = t0.square(); //          133: 60655035553913150831576461257216
+    t0 = t0.square(); //          134: 121310071107826301663152922514432
+    t0 *= t12; //                 135: 121310071107826301663152922514455
+    t0 = t0.square(); //          136: 242620142215652603326305845028910
+    t0 = t0.square(); //          137: 485240284431305206652611690057820
+    t0 = t0.square(); //          138: 970480568862610413305223380115640
+    t0 = t0.square(); //          139: 1940961137725220826610446760231280
+    t0 = t0.square(); //          140: 3881922275450441653220893520462560
+    t0 *= t11; //                 141: 3881922275450441653220893520462581
+    t0 = t0.square(); //          142: 7763844550900883306441787040925162
+    t0 = t0.square(); //          143: 15527689101801766612883574081850324
+    t0 = t0.square(); //          144: 31055378203603533225767148163700648
+    t0 *= t2; //                  145: 31055378203603533225767148163700651
+    t0 = t0.square(); //          146: 62110756407207066451534296327401302
+    t0 = t0.square(); //          147: 124221512814414132903068592654802604
+    t0 = t0.square(); //          148: 248443025628828265806137185309605208
+    t0 = t0.square(); //          149: 496886051257656531612274370619210416
+    t0 = t0.square(); //          150: 993772102515313063224548741238420832
+    t0 = t0.square(); //          151: 1987544205030626126449097482476841664
+    t0 = t0.square(); //          152: 3975088410061252252898194964953683328
+    t0 = t0.square(); //          153: 7950176820122504505796389929907366656
+    t0 *= t12; //                 154: 7950176820122504505796389929907366679
+    t0 = t0.square(); //          155: 15900353640245009011592779859814733358
+    t0 = t0.square(); //          156: 31800707280490018023185559719629466716
+    t0 = t0.square(); //          157: 63601414560980036046371119439258933432
+    t0 = t0.square(); //          158: 127202829121960072092742238878517866864
+    t0 = t0.square(); //          159: 254405658243920144185484477757035733728
+    t0 *= t11; //                 160: 254405658243920144185484477757035733749
+    t0 = t0.square(); //          161: 508811316487840288370968955514071467498
+    t0 = t0.square(); //          162: 1017622632975680576741937911028142934996
+    t0 = t0.square(); //          163: 2035245265951361153483875822056285869992
+    t0 = t0.square(); //          164: 4070490531902722306967751644112571739984
+    t0 = t0.square(); //          165: 8140981063805444613935503288225143479968
+    t0 = t0.square(); //          166: 16281962127610889227871006576450286959936
+    t0 = t0.square(); //          167: 32563924255221778455742013152900573919872
+    t0 = t0.square(); //          168: 65127848510443556911484026305801147839744
+    t0 *= t10; //                 169: 65127848510443556911484026305801147839763
+    t0 = t0.square(); //          170: 130255697020887113822968052611602295679526
+    t0 = t0.square(); //          171: 260511394041774227645936105223204591359052
+    t0 = t0.square(); //          172: 521022788083548455291872210446409182718104
+    t0 = t0.square(); //          173: 1042045576167096910583744420892818365436208
+    t0 = t0.square(); //          174: 2084091152334193821167488841785636730872416
+    t0 *= t14; //                 175: 2084091152334193821167488841785636730872443
+    t0 = t0.square(); //          176: 4168182304668387642334977683571273461744886
+    t0 = t0.square(); //          177: 8336364609336775284669955367142546923489772
+    t0 = t0.square(); //          178: 16672729218673550569339910734285093846979544
+    t0 = t0.square(); //          179: 33345458437347101138679821468570187693959088
+    t0 = t0.square(); //          180: 66690916874694202277359642937140375387918176
+    t0 *= t15; //                 181: 66690916874694202277359642937140375387918205
+    t0 = t0.square(); //          182: 133381833749388404554719285874280750775836410
+    t0 = t0.square(); //          183: 266763667498776809109438571748561501551672820
+    t0 = t0.square(); //          184: 533527334997553618218877143497123003103345640
+    t0 = t0.square(); //          185: 1067054669995107236437754286994246006206691280
+    t0 *= t5; //                  186: 1067054669995107236437754286994246006206691289
+    t0 = t0.square(); //          187: 2134109339990214472875508573988492012413382578
+    t0 = t0.square(); //          188: 4268218679980428945751017147976984024826765156
+    t0 = t0.square(); //          189: 8536437359960857891502034295953968049653530312
+    t0 = t0.square(); //          190: 17072874719921715783004068591907936099307060624
+    t0 = t0.square(); //          191: 34145749439843431566008137183815872198614121248
+    t0 = t0.square(); //          192: 68291498879686863132016274367631744397228242496
+    t0 = t0.square(); //          193: 136582997759373726264032548735263488794456484992
+    t0 = t0.square(); //          194: 273165995518747452528065097470526977588912969984
+    t0 = t0.square(); //          195: 546331991037494905056130194941053955177825939968
+    t0 = t0.square(); //          196: 1092663982074989810112260389882107910355651879936
+    t0 *= t7; //                  197: 1092663982074989810112260389882107910355651879949
+    t0 = t0.square(); //          198: 2185327964149979620224520779764215820711303759898
+    t0 = t0.square(); //          199: 4370655928299959240449041559528431641422607519796
+    t0 = t0.square(); //          200: 8741311856599918480898083119056863282845215039592
+    t0 = t0.square(); //          201: 17482623713199836961796166238113726565690430079184
+    t0 = t0.square(); //          202: 34965247426399673923592332476227453131380860158368
+    t0 = t0.square(); //          203: 69930494852799347847184664952454906262761720316736
+    t0 = t0.square(); //          204: 139860989705598695694369329904909812525523440633472
+    t0 = t0.square(); //          205: 279721979411197391388738659809819625051046881266944
+    t0 *= t15; //                 206: 279721979411197391388738659809819625051046881266973
+    t0 = t0.square(); //          207: 559443958822394782777477319619639250102093762533946
+    t0 = t0.square(); //          208: 1118887917644789565554954639239278500204187525067892
+    t0 = t0.square(); //          209: 2237775835289579131109909278478557000408375050135784
+    t0 = t0.square(); //          210: 4475551670579158262219818556957114000816750100271568
+    t0 = t0.square(); //          211: 8951103341158316524439637113914228001633500200543136
+    t0 *= t13; //                 212: 8951103341158316524439637113914228001633500200543161
+    t0 = t0.square(); //          213: 17902206682316633048879274227828456003267000401086322
+    t0 = t0.square(); //          214: 35804413364633266097758548455656912006534000802172644
+    t0 = t0.square(); //          215: 71608826729266532195517096911313824013068001604345288
+    t0 = t0.square(); //          216: 143217653458533064391034193822627648026136003208690576
+    t0 = t0.square(); //          217: 286435306917066128782068387645255296052272006417381152
+    t0 *= t8; //                  218: 286435306917066128782068387645255296052272006417381167
+    t0 = t0.square(); //          219: 572870613834132257564136775290510592104544012834762334
+    t0 = t0.square(); //          220: 1145741227668264515128273550581021184209088025669524668
+    t0 = t0.square(); //          221: 2291482455336529030256547101162042368418176051339049336
+    t0 = t0.square(); //          222: 4582964910673058060513094202324084736836352102678098672
+    t0 = t0.square(); //          223: 9165929821346116121026188404648169473672704205356197344
+    t0 = t0.square(); //          224: 18331859642692232242052376809296338947345408410712394688
+    t0 *= t14; //                 225: 18331859642692232242052376809296338947345408410712394715
+    t0 = t0.square(); //          226: 36663719285384464484104753618592677894690816821424789430
+    t0 = t0.square(); //          227: 73327438570768928968209507237185355789381633642849578860
+    t0 = t0.square(); //          228: 146654877141537857936419014474370711578763267285699157720
+    t0 = t0.square(); //          229: 293309754283075715872838028948741423157526534571398315440
+    t0 = t0.square(); //          230: 586619508566151431745676057897482846315053069142796630880
+    t0 = t0.square(); //          231: 1173239017132302863491352115794965692630106138285593261760
+    t0 *= t15; //                 232: 1173239017132302863491352115794965692630106138285593261789
+    t0 = t0.square(); //          233: 2346478034264605726982704231589931385260212276571186523578
+    t0 = t0.square(); //          234: 4692956068529211453965408463179862770520424553142373047156
+    t0 = t0.square(); //          235: 9385912137058422907930816926359725541040849106284746094312
+    t0 = t0.square(); //          236: 18771824274116845815861633852719451082081698212569492188624
+    t0 = t0.square(); //          237: 37543648548233691631723267705438902164163396425138984377248
+    t0 = t0.square(); //          238: 75087297096467383263446535410877804328326792850277968754496
+    t0 *= t13; //                 239: 75087297096467383263446535410877804328326792850277968754521
+    t0 = t0.square(); //          240: 150174594192934766526893070821755608656653585700555937509042
+    t0 = t0.square(); //          241: 300349188385869533053786141643511217313307171401111875018084
+    t0 = t0.square(); //          242: 600698376771739066107572283287022434626614342802223750036168
+    t0 = t0.square(); //          243: 1201396753543478132215144566574044869253228685604447500072336
+    t0 *= t6; //                  244: 1201396753543478132215144566574044869253228685604447500072347
+    t0 = t0.square(); //          245: 2402793507086956264430289133148089738506457371208895000144694
+    t0 = t0.square(); //          246: 4805587014173912528860578266296179477012914742417790000289388
+    t0 = t0.square(); //          247: 9611174028347825057721156532592358954025829484835580000578776
+    t0 = t0.square(); //          248: 19222348056695650115442313065184717908051658969671160001157552
+    t0 = t0.square(); //          249: 38444696113391300230884626130369435816103317939342320002315104
+    t0 = t0.square(); //          250: 76889392226782600461769252260738871632206635878684640004630208
+    t0 = t0.square(); //          251: 153778784453565200923538504521477743264413271757369280009260416
+    t0 = t0.square(); //          252: 307557568907130401847077009042955486528826543514738560018520832
+    t0 *= t15; //                 253: 307557568907130401847077009042955486528826543514738560018520861
+    t0 = t0.square(); //          254: 615115137814260803694154018085910973057653087029477120037041722
+    t0 = t0.square(); //          255: 1230230275628521607388308036171821946115306174058954240074083444
+    t0 = t0.square(); //          256: 2460460551257043214776616072343643892230612348117908480148166888
+    t0 = t0.square(); //          257: 4920921102514086429553232144687287784461224696235816960296333776
+    t0 = t0.square(); //          258: 9841842205028172859106464289374575568922449392471633920592667552
+    t0 = t0.square(); //          259: 19683684410056345718212928578749151137844898784943267841185335104
+    t0 *= t9; //                  260: 19683684410056345718212928578749151137844898784943267841185335121
+    t0 = t0.square(); //          261: 39367368820112691436425857157498302275689797569886535682370670242
+    t0 = t0.square(); //          262: 78734737640225382872851714314996604551379595139773071364741340484
+    t0 = t0.square(); //          263: 157469475280450765745703428629993209102759190279546142729482680968
+    t0 = t0.square(); //          264: 314938950560901531491406857259986418205518380559092285458965361936
+    t0 = t0.square(); //          265: 629877901121803062982813714519972836411036761118184570917930723872
+    t0 *= t8; //                  266: 629877901121803062982813714519972836411036761118184570917930723887
+    t0 = t0.square(); //          267: 1259755802243606125965627429039945672822073522236369141835861447774
+    t0 = t0.square(); //          268: 2519511604487212251931254858079891345644147044472738283671722895548
+    t0 = t0.square(); //          269: 5039023208974424503862509716159782691288294088945476567343445791096
+    t0 = t0.square(); //          270: 10078046417948849007725019432319565382576588177890953134686891582192
+    t0 = t0.square(); //          271: 20156092835897698015450038864639130765153176355781906269373783164384
+    t0 = t0.square(); //          272: 40312185671795396030900077729278261530306352711563812538747566328768
+    t0 = t0.square(); //          273: 80624371343590792061800155458556523060612705423127625077495132657536
+    t0 = t0.square(); //          274: 161248742687181584123600310917113046121225410846255250154990265315072
+    t0 *= t9; //                  275: 161248742687181584123600310917113046121225410846255250154990265315089
+    t0 = t0.square(); //          276: 322497485374363168247200621834226092242450821692510500309980530630178
+    t0 = t0.square(); //          277: 644994970748726336494401243668452184484901643385021000619961061260356
+    t0 = t0.square(); //          278: 1289989941497452672988802487336904368969803286770042001239922122520712
+    t0 = t0.square(); //          279: 2579979882994905345977604974673808737939606573540084002479844245041424
+    t0 *= t3; //                  280: 2579979882994905345977604974673808737939606573540084002479844245041429
+    t0 = t0.square(); //          281: 5159959765989810691955209949347617475879213147080168004959688490082858
+    t0 = t0.square(); //          282: 10319919531979621383910419898695234951758426294160336009919376980165716
+    t0 = t0.square(); //          283: 20639839063959242767820839797390469903516852588320672019838753960331432
+    t0 = t0.square(); //          284: 41279678127918485535641679594780939807033705176641344039677507920662864
+    t0 = t0.square(); //          285: 82559356255836971071283359189561879614067410353282688079355015841325728
+    t0 = t0.square(); //          286: 165118712511673942142566718379123759228134820706565376158710031682651456
+    t0 *= t6; //                  287: 165118712511673942142566718379123759228134820706565376158710031682651467
+    t0 = t0.square(); //          288: 330237425023347884285133436758247518456269641413130752317420063365302934
+    t0 = t0.square(); //          289: 660474850046695768570266873516495036912539282826261504634840126730605868
+    t0 = t0.square(); //          290: 1320949700093391537140533747032990073825078565652523009269680253461211736
+    t0 = t0.square(); //          291: 2641899400186783074281067494065980147650157131305046018539360506922423472
+    t0 = t0.square(); //          292: 5283798800373566148562134988131960295300314262610092037078721013844846944
+    t0 = t0.square(); //          293: 10567597600747132297124269976263920590600628525220184074157442027689693888
+    t0 *= t3; //                  294: 10567597600747132297124269976263920590600628525220184074157442027689693893
+    t0 = t0.square(); //          295: 21135195201494264594248539952527841181201257050440368148314884055379387786
+    t0 = t0.square(); //          296: 42270390402988529188497079905055682362402514100880736296629768110758775572
+    t0 = t0.square(); //          297: 84540780805977058376994159810111364724805028201761472593259536221517551144
+    t0 = t0.square(); //          298: 169081561611954116753988319620222729449610056403522945186519072443035102288
+    t0 = t0.square(); //          299: 338163123223908233507976639240445458899220112807045890373038144886070204576
+    t0 *= t4; //                  300: 338163123223908233507976639240445458899220112807045890373038144886070204583
+    t0 = t0.square(); //          301: 676326246447816467015953278480890917798440225614091780746076289772140409166
+    t0 = t0.square(); //          302: 1352652492895632934031906556961781835596880451228183561492152579544280818332
+    t0 = t0.square(); //          303: 2705304985791265868063813113923563671193760902456367122984305159088561636664
+    t0 = t0.square(); //          304: 5410609971582531736127626227847127342387521804912734245968610318177123273328
+    t0 = t0.square(); //          305: 10821219943165063472255252455694254684775043609825468491937220636354246546656
+    t0 = t0.square(); //          306: 21642439886330126944510504911388509369550087219650936983874441272708493093312
+    t0 = t0.square(); //          307: 43284879772660253889021009822777018739100174439301873967748882545416986186624
+    t0 = t0.square(); //          308: 86569759545320507778042019645554037478200348878603747935497765090833972373248
+    t0 = t0.square(); //          309: 173139519090641015556084039291108074956400697757207495870995530181667944746496
+    t0 *= t11; //                 310: 173139519090641015556084039291108074956400697757207495870995530181667944746517
+    t0 = t0.square(); //          311: 346279038181282031112168078582216149912801395514414991741991060363335889493034
+    t0 = t0.square(); //          312: 692558076362564062224336157164432299825602791028829983483982120726671778986068
+    t0 = t0.square(); //          313: 1385116152725128124448672314328864599651205582057659966967964241453343557972136
+    t0 = t0.square(); //          314: 2770232305450256248897344628657729199302411164115319933935928482906687115944272
+    t0 = t0.square(); //          315: 5540464610900512497794689257315458398604822328230639867871856965813374231888544
+    t0 = t0.square(); //          316: 11080929221801024995589378514630916797209644656461279735743713931626748463777088
+    t0 = t0.square(); //          317: 22161858443602049991178757029261833594419289312922559471487427863253496927554176
+    t0 *= t4; //                  318: 22161858443602049991178757029261833594419289312922559471487427863253496927554183
+    t0 = t0.square(); //          319: 44323716887204099982357514058523667188838578625845118942974855726506993855108366
+    t0 = t0.square(); //          320: 88647433774408199964715028117047334377677157251690237885949711453013987710216732
+    t0 = t0.square(); //          321: 177294867548816399929430056234094668755354314503380475771899422906027975420433464
+    t0 = t0.square(); //          322: 354589735097632799858860112468189337510708629006760951543798845812055950840866928
+    t0 = t0.square(); //          323: 709179470195265599717720224936378675021417258013521903087597691624111901681733856
+    t0 = t0.square(); //          324: 1418358940390531199435440449872757350042834516027043806175195383248223803363467712
+    t0 = t0.square(); //          325: 2836717880781062398870880899745514700085669032054087612350390766496447606726935424
+    t0 *= t13; //                 326: 2836717880781062398870880899745514700085669032054087612350390766496447606726935449
+    t0 = t0.square(); //          327: 5673435761562124797741761799491029400171338064108175224700781532992895213453870898
+    t0 = t0.square(); //          328: 11346871523124249595483523598982058800342676128216350449401563065985790426907741796
+    t0 = t0.square(); //          329: 22693743046248499190967047197964117600685352256432700898803126131971580853815483592
+    t0 = t0.square(); //          330: 45387486092496998381934094395928235201370704512865401797606252263943161707630967184
+    t0 = t0.square(); //          331: 90774972184993996763868188791856470402741409025730803595212504527886323415261934368
+    t0 *= t10; //                 332: 90774972184993996763868188791856470402741409025730803595212504527886323415261934387
+    t0 = t0.square(); //          333: 181549944369987993527736377583712940805482818051461607190425009055772646830523868774
+    t0 = t0.square(); //          334: 363099888739975987055472755167425881610965636102923214380850018111545293661047737548
+    t0 = t0.square(); //          335: 726199777479951974110945510334851763221931272205846428761700036223090587322095475096
+    t0 = t0.square(); //          336: 1452399554959903948221891020669703526443862544411692857523400072446181174644190950192
+    t0 = t0.square(); //          337: 2904799109919807896443782041339407052887725088823385715046800144892362349288381900384
+    t0 = t0.square(); //          338: 5809598219839615792887564082678814105775450177646771430093600289784724698576763800768
+    t0 = t0.square(); //          339: 11619196439679231585775128165357628211550900355293542860187200579569449397153527601536
+    t0 *= t13; //                 340: 11619196439679231585775128165357628211550900355293542860187200579569449397153527601561
+    t0 = t0.square(); //          341: 23238392879358463171550256330715256423101800710587085720374401159138898794307055203122
+    t0 = t0.square(); //          342: 46476785758716926343100512661430512846203601421174171440748802318277797588614110406244
+    t0 = t0.square(); //          343: 92953571517433852686201025322861025692407202842348342881497604636555595177228220812488
+    t0 = t0.square(); //          344: 185907143034867705372402050645722051384814405684696685762995209273111190354456441624976
+    t0 = t0.square(); //          345: 371814286069735410744804101291444102769628811369393371525990418546222380708912883249952
+    t0 *= t10; //                 346: 371814286069735410744804101291444102769628811369393371525990418546222380708912883249971
+    t0 = t0.square(); //          347: 743628572139470821489608202582888205539257622738786743051980837092444761417825766499942
+    t0 = t0.square(); //          348: 1487257144278941642979216405165776411078515245477573486103961674184889522835651532999884
+    t0 = t0.square(); //          349: 2974514288557883285958432810331552822157030490955146972207923348369779045671303065999768
+    t0 = t0.square(); //          350: 5949028577115766571916865620663105644314060981910293944415846696739558091342606131999536
+    t0 = t0.square(); //          351: 11898057154231533143833731241326211288628121963820587888831693393479116182685212263999072
+    t0 = t0.square(); //          352: 23796114308463066287667462482652422577256243927641175777663386786958232365370424527998144
+    t0 = t0.square(); //          353: 47592228616926132575334924965304845154512487855282351555326773573916464730740849055996288
+    t0 *= t13; //                 354: 47592228616926132575334924965304845154512487855282351555326773573916464730740849055996313
+    t0 = t0.square(); //          355: 95184457233852265150669849930609690309024975710564703110653547147832929461481698111992626
+    t0 = t0.square(); //          356: 190368914467704530301339699861219380618049951421129406221307094295665858922963396223985252
+    t0 = t0.square(); //          357: 380737828935409060602679399722438761236099902842258812442614188591331717845926792447970504
+    t0 * t3 //                    358: 380737828935409060602679399722438761236099902842258812442614188591331717845926792447970509
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
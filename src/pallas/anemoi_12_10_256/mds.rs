@@ -0,0 +1,230 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x6b0ee5d0fffffff5,
+        0x86f76d2b99b14bd0,
+        0xfffffffffffffffe,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x065a221cfffffff1,
+        0xfddd093b747d6762,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x3cf09ab4ffffffe9,
+        0xeba8415b2a159e85,
+        0xfffffffffffffffc,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x3cf09ab4ffffffe9,
+        0xeba8415b2a159e85,
+        0xfffffffffffffffc,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x6b0ee5d0fffffff5,
+        0x86f76d2b99b14bd0,
+        0xfffffffffffffffe,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x065a221cfffffff1,
+        0xfddd093b747d6762,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x3cf09ab4ffffffe9,
+        0xeba8415b2a159e85,
+        0xfffffffffffffffc,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x6b0ee5d0fffffff5,
+        0x86f76d2b99b14bd0,
+        0xfffffffffffffffe,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x065a221cfffffff1,
+        0xfddd093b747d6762,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x065a221cfffffff1,
+        0xfddd093b747d6762,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x3cf09ab4ffffffe9,
+        0xeba8415b2a159e85,
+        0xfffffffffffffffc,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x6b0ee5d0fffffff5,
+        0x86f76d2b99b14bd0,
+        0xfffffffffffffffe,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x6b0ee5d0fffffff5,
+        0x86f76d2b99b14bd0,
+        0xfffffffffffffffe,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x065a221cfffffff1,
+        0xfddd093b747d6762,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x3cf09ab4ffffffe9,
+        0xeba8415b2a159e85,
+        0xfffffffffffffffc,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x6b0ee5d0fffffff5,
+        0x86f76d2b99b14bd0,
+        0xfffffffffffffffe,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x065a221cfffffff1,
+        0xfddd093b747d6762,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x3cf09ab4ffffffe9,
+        0xeba8415b2a159e85,
+        0xfffffffffffffffc,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+];
@@ -0,0 +1,34 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi.
+/// [1   5]
+/// [5  26]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; (NUM_COLUMNS + 1) * (NUM_COLUMNS + 1)] = [
+    Felt::new(BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ])),
+    Felt::new(BigInteger256([
+        0x5ed150a4ffffff99,
+        0x359872984207c5e5,
+        0xfffffffffffffff2,
+        0x3fffffffffffffff,
+    ])),
+];
@@ -0,0 +1,167 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0x0b0ce7e8ffffff6d,
+        0x51762746a8ccf527,
+        0xffffffffffffffec,
+        0x3fffffffffffffff,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc06782695ec9eb24,
+        0x890d38c8c464d4d5,
+        0x1a4caa8fbcd5d19d,
+        0x35723d110821bec9,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x78817653ff6be0d9,
+        0x81a69f4d7f450cd7,
+        0xd744923c361f9b6a,
+        0x0afbbe8518033770,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x5fcd2e48568e5ee3,
+        0xc8b7bcea8555fcb7,
+        0xa4d4f6d10e4528a1,
+        0x2b59f32a502b98e5,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x2c2ca7a82f04be0d,
+        0x7ea536487a11888f,
+        0xdee482ac9661839a,
+        0x3468093968a345e1,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x871637143fb75990,
+        0x25b881420f49c51d,
+        0xf4415b05ce4edae9,
+        0x2a07d022621ddc85,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc57dd1a29ee6d139,
+        0xe53d649c324f4d80,
+        0x9accce2be6a38f3d,
+        0x2f74d48d03e8cafa,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbbaf94bfa7d60018,
+        0xe3a245502d9cc2e1,
+        0x6caedc363b2c54f2,
+        0x28d40806dcb2e31c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x5de7b12757bc3840,
+        0x2f9a37d2ba0bdbc0,
+        0xfefe6f5ded23785f,
+        0x356ca8ded5aca8db,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xa9bbf8f179ce7b75,
+        0x66364a9c18f0f5c0,
+        0xca28f26ed2a5879f,
+        0x1f35a427ea54c3dc,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbec9bc4e0c2aab74,
+        0xbb7e3d50de386c01,
+        0x63b2e85f530b50bc,
+        0x0a9a04ef51428741,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xee4c95b6874f8b47,
+        0x405d57fe5ace482b,
+        0xf52b6381e64c9f0c,
+        0x1e507aa3934360ae,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x4a460bbab9a500ba,
+        0x13a2234c1e8f0b56,
+        0x5502cdc2d0b4c33a,
+        0x381de7018d672a67,
+    ]))],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0x7c5e333a99999905,
+        0xe76b98e699eb6694,
+        0xccccccccccccccb8,
+        0x0ccccccccccccccc,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xb538a036662d31cf,
+        0x57c1e2e37215e9f1,
+        0xd5b55175ecad2084,
+        0x3fcb8bcd293285dc,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbc72050c22d9a9e8,
+        0x30914f1fcdfa2528,
+        0x2a125fb3f317ea02,
+        0x096c722acbf6dff1,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x0b1e5a280780e514,
+        0x1c94d4abe2160df4,
+        0xc0756ba23b24a120,
+        0x25826c054c39a181,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x3f8b840d9eb3db04,
+        0x087ca030fa92a8ea,
+        0xf882c9b9af688615,
+        0x37aa6a3ad71e7af3,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xda46a62b8bf193f7,
+        0x40c8dc4e83587830,
+        0x75410cb54cdeb781,
+        0x0db0365e854b71c3,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbe7754780505a69c,
+        0x275a45ac4d2c4d8a,
+        0xda0391063147fa17,
+        0x04c275ae4e74f53c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc78b31f8b14956fa,
+        0x181eafd6d8230436,
+        0x0e7b8e6fa055b802,
+        0x3f18de1c0ffb6edd,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbd841ce928afa16d,
+        0xf7cd0804568a48a8,
+        0x0e28adfb86eb08b3,
+        0x327949c8522dd1f8,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x36e3791e5d34a163,
+        0x3d733720169c243a,
+        0x3c7ec3b1bbf0695e,
+        0x22cfa289a878e567,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x246cdec06c4f8847,
+        0xe5d4931a509266e2,
+        0xf3c725171c5bded4,
+        0x2200026ebde51bf0,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc1c726e7dfaf9646,
+        0xef5696372d613703,
+        0x663f9eb2a6c21ce7,
+        0x0f2c86af2a25edb5,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x381c59056eddee2e,
+        0x3951d7261cc66c09,
+        0x26605f1dc5e06373,
+        0x2076f42c1b86dab8,
+    ]))],
+];
@@ -0,0 +1,548 @@
+//! Generic implementation of the Anemoi permutation
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::Permutation;
+use ark_ff::PrimeField;
+use core::marker::PhantomData;
+
+/// Trait defining the set of parameters of an Anemoi instantiation
+/// with `NUM_COLUMNS` columns over a prime field `F`.
+pub trait AnemoiParameters<F: PrimeField, const NUM_COLUMNS: usize> {
+    /// The number of rounds of the permutation.
+    const NUM_ROUNDS: usize;
+
+    /// The number of field elements of the state reserved for capacity
+    /// by the Sponge-based modes, which are its last ones.
+    const CAPACITY_WIDTH: usize = 1;
+
+    /// The security level targeted by this instantiation, in bits.
+    const SECURITY_LEVEL: u32 = 128;
+
+    /// Exponent of the Anemoi S-Box.
+    const ALPHA: u32;
+    /// Inverse exponent of the Anemoi S-Box, as little-endian limbs.
+    const INV_ALPHA: &'static [u64];
+    /// Multiplier of the Anemoi S-Box, also used as
+    /// generator in the MDS matrix.
+    const BETA: u32;
+    /// First added constant of the Anemoi S-Box.
+    const DELTA: F;
+    /// Second added constant of the Anemoi S-Box.
+    const QUAD: u32;
+
+    /// Additive round constants C, applied to the first row of the state.
+    const ARK_C: &'static [[F; NUM_COLUMNS]];
+    /// Additive round constants D, applied to the second row of the state.
+    const ARK_D: &'static [[F; NUM_COLUMNS]];
+
+    /// Maximum Diffusion Layer matrix, in row-major order.
+    ///
+    /// It is of size `NUM_COLUMNS * NUM_COLUMNS` and applied on each row of
+    /// the state, except for single-column instantiations, where it is a 2x2
+    /// matrix applied on the whole state.
+    const MDS: &'static [F];
+
+    /// Computes `x^INV_ALPHA`. Instantiations are encouraged to
+    /// override it with a dedicated addition chain.
+    #[inline(always)]
+    fn exp_inv_alpha(x: &F) -> F {
+        x.pow(Self::INV_ALPHA)
+    }
+
+    /// Multiplies the provided element by `BETA`. Instantiations are
+    /// encouraged to override it with a dedicated addition chain.
+    #[inline(always)]
+    fn mul_by_generator(x: &F) -> F {
+        *x * F::from(Self::BETA as u64)
+    }
+}
+
+/// The Anemoi permutation over `F` with `NUM_COLUMNS` columns,
+/// i.e. a state of `2 * NUM_COLUMNS` field elements.
+#[derive(Debug)]
+pub struct Anemoi<F, const NUM_COLUMNS: usize, P>(PhantomData<(F, P)>);
+
+impl<F, const NUM_COLUMNS: usize, P> Anemoi<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+
+    #[inline(always)]
+    /// Applies exponentiation of the current hash
+    /// state elements with the Anemoi S-Box.
+    pub fn apply_sbox(state: &mut [F]) {
+        debug_assert_eq!(state.len(), Self::STATE_WIDTH);
+
+        let mut x: [F; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [F; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+        x.iter_mut().enumerate().for_each(|(i, t)| {
+            let y2 = y[i].square();
+            *t -= P::mul_by_generator(&y2);
+        });
+
+        let mut x_alpha_inv = x;
+        x_alpha_inv
+            .iter_mut()
+            .for_each(|t| *t = P::exp_inv_alpha(t));
+
+        y.iter_mut()
+            .enumerate()
+            .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+        x.iter_mut().enumerate().for_each(|(i, t)| {
+            let y2 = y[i].square();
+            *t += P::mul_by_generator(&y2) + P::DELTA;
+        });
+
+        state[..NUM_COLUMNS].copy_from_slice(&x);
+        state[NUM_COLUMNS..].copy_from_slice(&y);
+    }
+
+    #[inline(always)]
+    /// Applies matrix-vector multiplication of the current
+    /// hash state with the Anemoi MDS matrix.
+    ///
+    /// Instantiations with 1, 2, 4 or 6 columns rely on a dedicated
+    /// implementation of their MDS matrix, while all others fall back
+    /// to a naive matrix-vector multiplication with `P::MDS`.
+    pub fn apply_mds(state: &mut [F]) {
+        debug_assert_eq!(state.len(), Self::STATE_WIDTH);
+
+        match NUM_COLUMNS {
+            1 => {
+                state[0] += P::mul_by_generator(&state[1]);
+                state[1] += P::mul_by_generator(&state[0]);
+            }
+            2 => {
+                state[0] += P::mul_by_generator(&state[1]);
+                state[1] += P::mul_by_generator(&state[0]);
+
+                state[3] += P::mul_by_generator(&state[2]);
+                state[2] += P::mul_by_generator(&state[3]);
+                state.swap(2, 3);
+            }
+            4 => {
+                Self::apply_mds_4(&mut state[..NUM_COLUMNS]);
+                state[NUM_COLUMNS..].rotate_left(1);
+                Self::apply_mds_4(&mut state[NUM_COLUMNS..]);
+            }
+            6 => {
+                Self::apply_mds_6(&mut state[..NUM_COLUMNS]);
+                state[NUM_COLUMNS..].rotate_left(1);
+                Self::apply_mds_6(&mut state[NUM_COLUMNS..]);
+            }
+            _ => {
+                Self::apply_naive_mds(&mut state[..NUM_COLUMNS]);
+                state[NUM_COLUMNS..].rotate_left(1);
+                Self::apply_naive_mds(&mut state[NUM_COLUMNS..]);
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn apply_mds_4(row: &mut [F]) {
+        row[0] += row[1];
+        row[2] += row[3];
+        row[3] += P::mul_by_generator(&row[0]);
+        row[1] = P::mul_by_generator(&(row[1] + row[2]));
+        row[0] += row[1];
+        row[2] += P::mul_by_generator(&row[3]);
+        row[1] += row[2];
+        row[3] += row[0];
+    }
+
+    #[inline(always)]
+    fn apply_mds_6(row: &mut [F]) {
+        let x: [F; 6] = row[..6].try_into().unwrap();
+
+        let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+        row[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+        row[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+        row[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+        row[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+        row[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+        row[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+    }
+
+    #[inline(always)]
+    fn apply_naive_mds(row: &mut [F]) {
+        let x: [F; NUM_COLUMNS] = row[..NUM_COLUMNS].try_into().unwrap();
+
+        for (i, r) in row.iter_mut().enumerate() {
+            *r = F::zero();
+            for (j, s) in x.iter().enumerate() {
+                *r += *s * P::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+    }
+
+    /// Applies an Anemoi permutation to the provided state
+    #[inline(always)]
+    pub fn apply_permutation(state: &mut [F]) {
+        for i in 0..P::NUM_ROUNDS {
+            Self::apply_round(state, i);
+        }
+
+        Self::apply_mds(state)
+    }
+
+    /// Applies an Anemoi round to the provided state
+    #[inline(always)]
+    pub fn apply_round(state: &mut [F], step: usize) {
+        // determine which round constants to use
+        let c = &P::ARK_C[step % P::NUM_ROUNDS];
+        let d = &P::ARK_D[step % P::NUM_ROUNDS];
+
+        for i in 0..NUM_COLUMNS {
+            state[i] += c[i];
+            state[NUM_COLUMNS + i] += d[i];
+        }
+
+        Self::apply_mds(state);
+        Self::apply_sbox(state);
+    }
+
+    #[inline(always)]
+    /// Applies the inverse of the Anemoi S-Box to the
+    /// current hash state elements.
+    ///
+    /// Inverting the Flystel amounts to running its three steps backwards,
+    /// hence this also relies on the inverse power map `exp_inv_alpha`.
+    pub fn apply_inverse_sbox(state: &mut [F]) {
+        debug_assert_eq!(state.len(), Self::STATE_WIDTH);
+
+        let mut x: [F; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [F; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+        x.iter_mut().enumerate().for_each(|(i, t)| {
+            let y2 = y[i].square();
+            *t -= P::mul_by_generator(&y2) + P::DELTA;
+        });
+
+        let mut x_alpha_inv = x;
+        x_alpha_inv
+            .iter_mut()
+            .for_each(|t| *t = P::exp_inv_alpha(t));
+
+        y.iter_mut()
+            .enumerate()
+            .for_each(|(i, t)| *t += x_alpha_inv[i]);
+
+        x.iter_mut().enumerate().for_each(|(i, t)| {
+            let y2 = y[i].square();
+            *t += P::mul_by_generator(&y2);
+        });
+
+        state[..NUM_COLUMNS].copy_from_slice(&x);
+        state[NUM_COLUMNS..].copy_from_slice(&y);
+    }
+
+    #[inline(always)]
+    /// Applies matrix-vector multiplication of the current
+    /// hash state with the inverse of the Anemoi MDS matrix.
+    ///
+    /// Instantiations with 1, 2 or 4 columns invert their dedicated
+    /// implementation step by step, while all others fall back to a
+    /// naive matrix-vector multiplication with the inverse of `P::MDS`.
+    pub fn apply_inverse_mds(state: &mut [F]) {
+        Self::apply_inverse_mds_with(state, &Self::inverse_mds_constants())
+    }
+
+    /// Applies the inverse of the Anemoi MDS matrix, given the output of
+    /// `inverse_mds_constants` so that it is only computed once per permutation.
+    #[inline(always)]
+    fn apply_inverse_mds_with(state: &mut [F], inverse_mds: &[F]) {
+        debug_assert_eq!(state.len(), Self::STATE_WIDTH);
+
+        match NUM_COLUMNS {
+            1 => {
+                state[1] -= P::mul_by_generator(&state[0]);
+                state[0] -= P::mul_by_generator(&state[1]);
+            }
+            2 => {
+                state[1] -= P::mul_by_generator(&state[0]);
+                state[0] -= P::mul_by_generator(&state[1]);
+
+                state.swap(2, 3);
+                state[2] -= P::mul_by_generator(&state[3]);
+                state[3] -= P::mul_by_generator(&state[2]);
+            }
+            4 => {
+                Self::apply_inverse_mds_4(&mut state[..NUM_COLUMNS], inverse_mds[0]);
+                Self::apply_inverse_mds_4(&mut state[NUM_COLUMNS..], inverse_mds[0]);
+                state[NUM_COLUMNS..].rotate_right(1);
+            }
+            _ => {
+                Self::apply_naive_inverse_mds(&mut state[..NUM_COLUMNS], inverse_mds);
+                Self::apply_naive_inverse_mds(&mut state[NUM_COLUMNS..], inverse_mds);
+                state[NUM_COLUMNS..].rotate_right(1);
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn apply_inverse_mds_4(row: &mut [F], inv_beta: F) {
+        row[3] -= row[0];
+        row[1] -= row[2];
+        row[2] -= P::mul_by_generator(&row[3]);
+        row[0] -= row[1];
+        row[1] = row[1] * inv_beta - row[2];
+        row[3] -= P::mul_by_generator(&row[0]);
+        row[2] -= row[3];
+        row[0] -= row[1];
+    }
+
+    #[inline(always)]
+    fn apply_naive_inverse_mds(row: &mut [F], inverse_mds: &[F]) {
+        let x: [F; NUM_COLUMNS] = row[..NUM_COLUMNS].try_into().unwrap();
+
+        for (i, r) in row.iter_mut().enumerate() {
+            *r = F::zero();
+            for (j, s) in x.iter().enumerate() {
+                *r += *s * inverse_mds[i * NUM_COLUMNS + j];
+            }
+        }
+    }
+
+    /// Returns the constants required by `apply_inverse_mds_with`, i.e. the
+    /// inverse of `P::BETA` for instantiations with 4 columns, the inverse of
+    /// `P::MDS` for those relying on `apply_naive_inverse_mds`, or an empty
+    /// vector for all others.
+    fn inverse_mds_constants() -> Vec<F> {
+        match NUM_COLUMNS {
+            1 | 2 => Vec::new(),
+            4 => vec![F::from(P::BETA as u64).inverse().unwrap()],
+            _ => Self::inverse_mds(),
+        }
+    }
+
+    /// Computes the inverse of `P::MDS` through Gauss-Jordan elimination.
+    fn inverse_mds() -> Vec<F> {
+        let n = NUM_COLUMNS;
+        let mut m = P::MDS[..n * n].to_vec();
+        let mut inverse = vec![F::zero(); n * n];
+        for i in 0..n {
+            inverse[i * n + i] = F::one();
+        }
+
+        for col in 0..n {
+            let pivot = (col..n)
+                .find(|&r| !m[r * n + col].is_zero())
+                .expect("the MDS matrix is invertible");
+            for j in 0..n {
+                m.swap(pivot * n + j, col * n + j);
+                inverse.swap(pivot * n + j, col * n + j);
+            }
+
+            let pivot_inv = m[col * n + col].inverse().unwrap();
+            for j in 0..n {
+                m[col * n + j] *= pivot_inv;
+                inverse[col * n + j] *= pivot_inv;
+            }
+
+            for r in (0..n).filter(|&r| r != col) {
+                let factor = m[r * n + col];
+                for j in 0..n {
+                    let (a, b) = (m[col * n + j], inverse[col * n + j]);
+                    m[r * n + j] -= factor * a;
+                    inverse[r * n + j] -= factor * b;
+                }
+            }
+        }
+
+        inverse
+    }
+
+    /// Applies the inverse of an Anemoi permutation to the provided state
+    #[inline(always)]
+    pub fn apply_inverse_permutation(state: &mut [F]) {
+        // The constants of the inverse MDS matrix are computed once for all rounds.
+        let inverse_mds = Self::inverse_mds_constants();
+        Self::apply_inverse_mds_with(state, &inverse_mds);
+
+        for i in (0..P::NUM_ROUNDS).rev() {
+            Self::apply_inverse_round_with(state, i, &inverse_mds);
+        }
+    }
+
+    /// Applies the inverse of an Anemoi round to the provided state
+    #[inline(always)]
+    pub fn apply_inverse_round(state: &mut [F], step: usize) {
+        Self::apply_inverse_round_with(state, step, &Self::inverse_mds_constants())
+    }
+
+    #[inline(always)]
+    fn apply_inverse_round_with(state: &mut [F], step: usize, inverse_mds: &[F]) {
+        Self::apply_inverse_sbox(state);
+        Self::apply_inverse_mds_with(state, inverse_mds);
+
+        // determine which round constants to use
+        let c = &P::ARK_C[step % P::NUM_ROUNDS];
+        let d = &P::ARK_D[step % P::NUM_ROUNDS];
+
+        for i in 0..NUM_COLUMNS {
+            state[i] -= c[i];
+            state[NUM_COLUMNS + i] -= d[i];
+        }
+    }
+}
+
+impl<F, const NUM_COLUMNS: usize, P> Permutation<F> for Anemoi<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+
+    const NUM_ROUNDS: usize = P::NUM_ROUNDS;
+
+    /// Applies the Anemoi permutation, i.e. all its rounds
+    /// followed by a last application of the linear layer.
+    fn permute(state: &mut [F]) {
+        assert_eq!(state.len(), Self::STATE_WIDTH);
+        Self::apply_permutation(state)
+    }
+
+    fn round(state: &mut [F], i: usize) {
+        assert_eq!(state.len(), Self::STATE_WIDTH);
+        assert!(i < P::NUM_ROUNDS, "invalid round index {i}");
+        Self::apply_round(state, i)
+    }
+
+    fn inverse_permute(state: &mut [F]) {
+        assert_eq!(state.len(), Self::STATE_WIDTH);
+        Self::apply_inverse_permutation(state)
+    }
+}
+
+#[cfg(all(test, feature = "bls_381"))]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::bls12_381::{anemoi_12_11, anemoi_4_3, anemoi_8_7, Felt};
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use ark_ff::UniformRand;
+    use rand_core::OsRng;
+
+    fn check_naive_mds<const NUM_COLUMNS: usize, P: AnemoiParameters<Felt, NUM_COLUMNS>>() {
+        let mut rng = OsRng;
+
+        for _ in 0..100 {
+            let mut state: Vec<Felt> = (0..2 * NUM_COLUMNS).map(|_| Felt::rand(&mut rng)).collect();
+
+            let mut naive_state = state.to_vec();
+            Anemoi::<Felt, NUM_COLUMNS, P>::apply_naive_mds(&mut naive_state[..NUM_COLUMNS]);
+            naive_state[NUM_COLUMNS..].rotate_left(1);
+            Anemoi::<Felt, NUM_COLUMNS, P>::apply_naive_mds(&mut naive_state[NUM_COLUMNS..]);
+
+            Anemoi::<Felt, NUM_COLUMNS, P>::apply_mds(&mut state);
+            assert_eq!(state, naive_state);
+        }
+    }
+
+    #[test]
+    fn test_naive_mds() {
+        check_naive_mds::<{ anemoi_4_3::NUM_COLUMNS }, anemoi_4_3::AnemoiParams>();
+        check_naive_mds::<{ anemoi_8_7::NUM_COLUMNS }, anemoi_8_7::AnemoiParams>();
+        check_naive_mds::<{ anemoi_12_11::NUM_COLUMNS }, anemoi_12_11::AnemoiParams>();
+    }
+
+    struct InverseCheck;
+
+    impl InstantiationCheck for InverseCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+
+            for _ in 0..10 {
+                let input: Vec<F> = (0..2 * NUM_COLUMNS).map(|_| F::rand(&mut rng)).collect();
+
+                let mut state = input.clone();
+                Anemoi::<F, NUM_COLUMNS, P>::apply_sbox(&mut state);
+                Anemoi::<F, NUM_COLUMNS, P>::apply_inverse_sbox(&mut state);
+                assert_eq!(state, input);
+
+                Anemoi::<F, NUM_COLUMNS, P>::apply_mds(&mut state);
+                Anemoi::<F, NUM_COLUMNS, P>::apply_inverse_mds(&mut state);
+                assert_eq!(state, input);
+
+                Anemoi::<F, NUM_COLUMNS, P>::apply_round(&mut state, 1);
+                Anemoi::<F, NUM_COLUMNS, P>::apply_inverse_round(&mut state, 1);
+                assert_eq!(state, input);
+
+                Anemoi::<F, NUM_COLUMNS, P>::apply_permutation(&mut state);
+                assert_ne!(state, input);
+                Anemoi::<F, NUM_COLUMNS, P>::apply_inverse_permutation(&mut state);
+                assert_eq!(state, input);
+
+                Anemoi::<F, NUM_COLUMNS, P>::apply_inverse_permutation(&mut state);
+                Anemoi::<F, NUM_COLUMNS, P>::apply_permutation(&mut state);
+                assert_eq!(state, input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_permutation() {
+        check_all_instantiations::<InverseCheck>();
+    }
+
+    struct PermutationCheck;
+
+    impl InstantiationCheck for PermutationCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let input: Vec<F> = (0..2 * NUM_COLUMNS).map(|_| F::rand(&mut rng)).collect();
+
+            let mut state = input.clone();
+            permute_with::<F, Anemoi<F, NUM_COLUMNS, P>>(&mut state);
+
+            // The permutation is made of all its rounds,
+            // followed by a last application of the linear layer.
+            let mut rounds = input.clone();
+            for i in 0..P::NUM_ROUNDS {
+                <Anemoi<F, NUM_COLUMNS, P> as Permutation<F>>::round(&mut rounds, i);
+            }
+            Anemoi::<F, NUM_COLUMNS, P>::apply_mds(&mut rounds);
+            assert_eq!(rounds, state);
+
+            <Anemoi<F, NUM_COLUMNS, P> as Permutation<F>>::inverse_permute(&mut state);
+            assert_eq!(state, input);
+        }
+    }
+
+    /// Applies a permutation through its public trait only.
+    fn permute_with<F: PrimeField, T: Permutation<F>>(state: &mut [F]) {
+        assert_eq!(state.len(), T::STATE_WIDTH);
+        T::permute(state);
+    }
+
+    #[test]
+    fn test_permutation_trait() {
+        check_all_instantiations::<PermutationCheck>();
+
+        assert_eq!(
+            <anemoi_4_3::AnemoiPermutation as Permutation<Felt>>::STATE_WIDTH,
+            anemoi_4_3::STATE_WIDTH
+        );
+        assert_eq!(
+            <anemoi_4_3::AnemoiPermutation as Permutation<Felt>>::NUM_ROUNDS,
+            anemoi_4_3::NUM_HASH_ROUNDS
+        );
+    }
+}
@@ -0,0 +1,408 @@
+//! Plonkish gate equations for the Anemoi permutation
+//!
+//! A round of Anemoi is described as a set of polynomial equations relating
+//! the state at the beginning of the round, the round constants, the outputs
+//! `v = (x - BETA * y^QUAD)^(1/ALPHA)` of the open Flystels, and the state at
+//! the end of the round. For each column `i`, with `(x, y)` the state after
+//! the round constants and the linear layer, and `(x', y')` the round output:
+//!
+//! - `y - v - y' = 0`
+//! - `x - BETA * y^QUAD - v^ALPHA = 0`
+//! - `v^ALPHA + BETA * y'^QUAD + DELTA - x' = 0`
+//!
+//! The round constants are represented as fixed columns, so that the same
+//! equations hold for every round, and the trace generator records all the
+//! values these equations refer to.
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Sub};
+
+use super::{Anemoi, AnemoiParameters};
+
+use ark_ff::PrimeField;
+
+/// A variable of the gate equations of an Anemoi round.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Variable {
+    /// The i-th element of the state at the beginning of the round.
+    Input(usize),
+    /// The output of the inverse power map of the i-th Flystel.
+    Witness(usize),
+    /// The i-th element of the state at the end of the round.
+    Output(usize),
+    /// The i-th round constant added to the first row of the state.
+    ConstantC(usize),
+    /// The i-th round constant added to the second row of the state.
+    ConstantD(usize),
+}
+
+/// A multivariate polynomial over `F` in variables of type `V`, stored as
+/// a sparse sum of monomials. Each monomial is given as the sorted list of
+/// its variables, repeated according to their exponent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Polynomial<F: PrimeField, V: Copy + Ord = Variable> {
+    terms: BTreeMap<Vec<V>, F>,
+}
+
+impl<F: PrimeField, V: Copy + Ord> Default for Polynomial<F, V> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: PrimeField, V: Copy + Ord> Polynomial<F, V> {
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        Self {
+            terms: BTreeMap::new(),
+        }
+    }
+
+    /// Returns a constant polynomial.
+    pub fn constant(value: F) -> Self {
+        Self::zero().add_term(Vec::new(), value)
+    }
+
+    /// Returns the polynomial made of a single variable.
+    pub fn variable(variable: V) -> Self {
+        Self::zero().add_term(vec![variable], F::one())
+    }
+
+    /// Returns the terms of this polynomial, as pairs
+    /// of their coefficient and their monomial.
+    pub fn terms(&self) -> impl Iterator<Item = (F, &[V])> {
+        self.terms.iter().map(|(m, c)| (*c, m.as_slice()))
+    }
+
+    /// Returns the total degree of this polynomial.
+    pub fn degree(&self) -> usize {
+        self.terms.keys().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Evaluates this polynomial given an assignment of its variables.
+    pub fn evaluate(&self, assignment: impl Fn(V) -> F) -> F {
+        self.terms
+            .iter()
+            .map(|(m, c)| m.iter().fold(*c, |acc, v| acc * assignment(*v)))
+            .sum()
+    }
+
+    /// Returns this polynomial multiplied by a scalar.
+    pub fn scale(&self, scalar: F) -> Self {
+        self.terms.iter().fold(Self::zero(), |acc, (m, c)| {
+            acc.add_term(m.clone(), *c * scalar)
+        })
+    }
+
+    /// Returns this polynomial raised to the power `exp`.
+    pub fn pow(&self, exp: u32) -> Self {
+        (0..exp).fold(Self::constant(F::one()), |acc, _| &acc * self)
+    }
+
+    /// Returns the polynomial obtained by substituting
+    /// each variable of this one with another variable.
+    pub fn map_variables<W: Copy + Ord>(&self, f: impl Fn(V) -> W) -> Polynomial<F, W> {
+        self.terms.iter().fold(Polynomial::zero(), |acc, (m, c)| {
+            let mut monomial: Vec<W> = m.iter().map(|v| f(*v)).collect();
+            monomial.sort_unstable();
+            acc.add_term(monomial, *c)
+        })
+    }
+
+    fn add_term(mut self, monomial: Vec<V>, coeff: F) -> Self {
+        let c = *self.terms.entry(monomial.clone()).or_insert_with(F::zero) + coeff;
+        if c.is_zero() {
+            self.terms.remove(&monomial);
+        } else {
+            self.terms.insert(monomial, c);
+        }
+
+        self
+    }
+}
+
+impl<F: PrimeField, V: Copy + Ord> Add<&Polynomial<F, V>> for Polynomial<F, V> {
+    type Output = Self;
+
+    fn add(mut self, other: &Self) -> Self {
+        for (m, c) in other.terms.iter() {
+            self = self.add_term(m.clone(), *c);
+        }
+
+        self
+    }
+}
+
+impl<F: PrimeField, V: Copy + Ord> Sub<&Polynomial<F, V>> for Polynomial<F, V> {
+    type Output = Self;
+
+    fn sub(self, other: &Self) -> Self {
+        self + &other.scale(-F::one())
+    }
+}
+
+impl<F: PrimeField, V: Copy + Ord> Mul<&Polynomial<F, V>> for &Polynomial<F, V> {
+    type Output = Polynomial<F, V>;
+
+    fn mul(self, other: &Polynomial<F, V>) -> Polynomial<F, V> {
+        let mut result = Polynomial::zero();
+        for (m1, c1) in self.terms.iter() {
+            for (m2, c2) in other.terms.iter() {
+                let mut monomial = [m1.as_slice(), m2.as_slice()].concat();
+                monomial.sort_unstable();
+                result = result.add_term(monomial, *c1 * c2);
+            }
+        }
+
+        result
+    }
+}
+
+/// The values taken by the variables of the gate
+/// equations of a given round of an Anemoi permutation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundTrace<F: PrimeField> {
+    /// The round constants `C` and `D`, concatenated.
+    pub constants: Vec<F>,
+    /// The state at the beginning of the round.
+    pub input: Vec<F>,
+    /// The state after the round constants and the linear layer.
+    pub linear: Vec<F>,
+    /// The outputs of the inverse power maps of the Flystels.
+    pub witness: Vec<F>,
+    /// The state at the end of the round.
+    pub output: Vec<F>,
+}
+
+impl<F: PrimeField> RoundTrace<F> {
+    /// Returns the value of the provided variable in this round.
+    pub fn value(&self, variable: Variable) -> F {
+        let num_columns = self.witness.len();
+
+        match variable {
+            Variable::Input(i) => self.input[i],
+            Variable::Witness(i) => self.witness[i],
+            Variable::Output(i) => self.output[i],
+            Variable::ConstantC(i) => self.constants[i],
+            Variable::ConstantD(i) => self.constants[num_columns + i],
+        }
+    }
+}
+
+/// The trace of an Anemoi permutation, recording all
+/// intermediate values of each of its rounds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermutationTrace<F: PrimeField> {
+    /// The traces of all rounds of the permutation.
+    pub rounds: Vec<RoundTrace<F>>,
+    /// The output of the permutation, after the final linear layer.
+    pub output: Vec<F>,
+}
+
+impl<F: PrimeField> PermutationTrace<F> {
+    /// Returns the value of the provided variable in the final linear layer,
+    /// whose input is the output of the last round.
+    pub fn final_value(&self, variable: Variable) -> F {
+        match variable {
+            Variable::Input(i) => self.rounds[self.rounds.len() - 1].output[i],
+            Variable::Output(i) => self.output[i],
+            _ => panic!("the final linear layer only has input and output variables"),
+        }
+    }
+}
+
+/// Plonkish gate equations and witness trace generation
+/// for the Anemoi permutation over `F` with `NUM_COLUMNS` columns.
+#[derive(Debug)]
+pub struct AnemoiGates<F, const NUM_COLUMNS: usize, P>(PhantomData<(F, P)>);
+
+impl<F, const NUM_COLUMNS: usize, P> AnemoiGates<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+
+    /// Returns the matrix of the linear layer of Anemoi, acting on the whole
+    /// state, as a sequence of rows.
+    ///
+    /// This is the MDS matrix of the instantiation applied to the first row
+    /// of the state and to the second row rotated by one position.
+    pub fn linear_layer() -> Vec<Vec<F>> {
+        let mut columns = Vec::with_capacity(Self::STATE_WIDTH);
+        for j in 0..Self::STATE_WIDTH {
+            let mut unit = vec![F::zero(); Self::STATE_WIDTH];
+            unit[j] = F::one();
+            Anemoi::<F, NUM_COLUMNS, P>::apply_mds(&mut unit);
+            columns.push(unit);
+        }
+
+        (0..Self::STATE_WIDTH)
+            .map(|i| columns.iter().map(|c| c[i]).collect())
+            .collect()
+    }
+
+    /// Returns the gate equations of an Anemoi round, which all evaluate
+    /// to zero on a valid [`RoundTrace`]. They are given column by column,
+    /// in the order listed in the module documentation.
+    pub fn round_equations() -> Vec<Polynomial<F>> {
+        let beta = F::from(P::BETA as u64);
+
+        let constants: Vec<Polynomial<F>> = (0..Self::STATE_WIDTH)
+            .map(|i| {
+                let constant = if i < NUM_COLUMNS {
+                    Variable::ConstantC(i)
+                } else {
+                    Variable::ConstantD(i - NUM_COLUMNS)
+                };
+                Polynomial::variable(Variable::Input(i)) + &Polynomial::variable(constant)
+            })
+            .collect();
+        let linear = Self::apply_linear_layer(&constants);
+
+        let mut equations = Vec::with_capacity(3 * NUM_COLUMNS);
+        for i in 0..NUM_COLUMNS {
+            let x = &linear[i];
+            let y = &linear[NUM_COLUMNS + i];
+            let v = Polynomial::variable(Variable::Witness(i));
+            let x_out = Polynomial::variable(Variable::Output(i));
+            let y_out = Polynomial::variable(Variable::Output(NUM_COLUMNS + i));
+            let v_alpha = v.pow(P::ALPHA);
+
+            equations.push(y.clone() - &v - &y_out);
+            equations.push(x.clone() - &y.pow(P::QUAD).scale(beta) - &v_alpha);
+            equations.push(
+                v_alpha + &y_out.pow(P::QUAD).scale(beta) + &Polynomial::constant(P::DELTA)
+                    - &x_out,
+            );
+        }
+
+        equations
+    }
+
+    /// Returns the equations of the final linear layer of the permutation,
+    /// which all evaluate to zero given [`PermutationTrace::final_value`].
+    pub fn final_equations() -> Vec<Polynomial<F>> {
+        let input: Vec<Polynomial<F>> = (0..Self::STATE_WIDTH)
+            .map(|i| Polynomial::variable(Variable::Input(i)))
+            .collect();
+
+        Self::apply_linear_layer(&input)
+            .into_iter()
+            .enumerate()
+            .map(|(i, p)| p - &Polynomial::variable(Variable::Output(i)))
+            .collect()
+    }
+
+    /// Applies an Anemoi permutation to the provided state,
+    /// and returns the trace of all its intermediate values.
+    pub fn generate_trace(input: &[F]) -> PermutationTrace<F> {
+        assert_eq!(input.len(), Self::STATE_WIDTH);
+
+        let mut state = input.to_vec();
+        let mut rounds = Vec::with_capacity(P::NUM_ROUNDS);
+        for step in 0..P::NUM_ROUNDS {
+            let round_input = state.clone();
+            let constants = [P::ARK_C[step].as_slice(), P::ARK_D[step].as_slice()].concat();
+
+            for (s, c) in state.iter_mut().zip(constants.iter()) {
+                *s += c;
+            }
+            Anemoi::<F, NUM_COLUMNS, P>::apply_mds(&mut state);
+            let linear = state.clone();
+
+            let witness = (0..NUM_COLUMNS)
+                .map(|i| {
+                    let y = linear[NUM_COLUMNS + i];
+                    P::exp_inv_alpha(&(linear[i] - P::mul_by_generator(&y.pow([P::QUAD as u64]))))
+                })
+                .collect();
+            Anemoi::<F, NUM_COLUMNS, P>::apply_sbox(&mut state);
+
+            rounds.push(RoundTrace {
+                constants,
+                input: round_input,
+                linear,
+                witness,
+                output: state.clone(),
+            });
+        }
+        Anemoi::<F, NUM_COLUMNS, P>::apply_mds(&mut state);
+
+        PermutationTrace {
+            rounds,
+            output: state,
+        }
+    }
+
+    fn apply_linear_layer(state: &[Polynomial<F>]) -> Vec<Polynomial<F>> {
+        Self::linear_layer()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(state.iter())
+                    .fold(Polynomial::zero(), |acc, (m, s)| acc + &s.scale(*m))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use rand_core::OsRng;
+
+    struct GatesCheck;
+
+    impl InstantiationCheck for GatesCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let input: Vec<F> = (0..2 * NUM_COLUMNS).map(|_| F::rand(&mut rng)).collect();
+
+            let trace = AnemoiGates::<F, NUM_COLUMNS, P>::generate_trace(&input);
+            let mut output = input.clone();
+            Anemoi::<F, NUM_COLUMNS, P>::apply_permutation(&mut output);
+            assert_eq!(trace.output, output);
+            assert_eq!(trace.rounds.len(), P::NUM_ROUNDS);
+
+            let equations = AnemoiGates::<F, NUM_COLUMNS, P>::round_equations();
+            assert_eq!(equations.len(), 3 * NUM_COLUMNS);
+            assert_eq!(
+                equations.iter().map(Polynomial::degree).max(),
+                Some(P::ALPHA.max(P::QUAD) as usize)
+            );
+
+            for round in trace.rounds.iter() {
+                assert!(equations
+                    .iter()
+                    .all(|e| e.evaluate(|v| round.value(v)).is_zero()));
+            }
+            assert!(AnemoiGates::<F, NUM_COLUMNS, P>::final_equations()
+                .iter()
+                .all(|e| e.evaluate(|v| trace.final_value(v)).is_zero()));
+
+            // A wrong witness is detected.
+            let mut round = trace.rounds[0].clone();
+            round.witness[0] += F::one();
+            assert!(!equations
+                .iter()
+                .all(|e| e.evaluate(|v| round.value(v)).is_zero()));
+        }
+    }
+
+    #[test]
+    fn test_round_equations() {
+        check_all_instantiations::<GatesCheck>();
+    }
+}
@@ -0,0 +1,489 @@
+//! R1CS gadgets for the Anemoi permutation and its Sponge and Jive modes
+//!
+//! The S-Box is represented as an open Flystel: the output `v = x^(1/ALPHA)`
+//! of the inverse power map is allocated as a witness, and checked in-circuit
+//! by raising it to `ALPHA`. As the quadratic functions of the Flystel only
+//! cost one constraint each, and all constant additions and linear layers are
+//! free, a round of an instantiation with `NUM_COLUMNS` columns costs
+//! `NUM_COLUMNS * (2 + c)` constraints, where `c` is the number of
+//! multiplications required to compute `v^ALPHA`, i.e. 2 for `ALPHA = 3`
+//! and 3 for `ALPHA = 5`, as stated in the Anemoi paper.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::marker::PhantomData;
+
+use super::permutation::AnemoiParameters;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::AllocVar,
+    fields::{fp::FpVar, FieldVar},
+    R1CSVar,
+};
+use ark_relations::r1cs::SynthesisError;
+
+/// Returns the number of multiplications, i.e. R1CS constraints,
+/// needed to raise a variable to the power `alpha`.
+pub fn exp_alpha_cost(alpha: u32) -> usize {
+    // The last multiplication is merged with the equality check.
+    (alpha.ilog2() + alpha.count_ones() - 1) as usize
+}
+
+/// Returns the number of R1CS constraints of an Anemoi
+/// permutation with the provided parameters.
+pub fn permutation_cost<F, const NUM_COLUMNS: usize, P>() -> usize
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    P::NUM_ROUNDS * NUM_COLUMNS * (2 + exp_alpha_cost(P::ALPHA))
+}
+
+/// The gadget counterpart of the Anemoi permutation over `F`
+/// with `NUM_COLUMNS` columns.
+#[derive(Debug)]
+pub struct AnemoiPermutationVar<F, const NUM_COLUMNS: usize, P>(PhantomData<(F, P)>);
+
+impl<F, const NUM_COLUMNS: usize, P> AnemoiPermutationVar<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+
+    /// Applies the Anemoi S-Box to the provided state variables,
+    /// as `NUM_COLUMNS` open Flystels.
+    pub fn apply_sbox(state: &mut [FpVar<F>]) -> Result<(), SynthesisError> {
+        debug_assert_eq!(state.len(), Self::STATE_WIDTH);
+
+        let beta = F::from(P::BETA as u64);
+
+        for i in 0..NUM_COLUMNS {
+            let x = &state[i];
+            let y = &state[NUM_COLUMNS + i];
+
+            let x_minus_quad = x - y.square()? * beta;
+
+            // v = (x - beta * y^2)^(1/alpha), checked by raising it to alpha.
+            let v = if x_minus_quad.is_constant() {
+                FpVar::Constant(P::exp_inv_alpha(&x_minus_quad.value()?))
+            } else {
+                FpVar::new_witness(x_minus_quad.cs(), || {
+                    Ok(P::exp_inv_alpha(&x_minus_quad.value()?))
+                })?
+            };
+            Self::exp_alpha_minus_one(&v)?.mul_equals(&v, &x_minus_quad)?;
+
+            let y_out = y - &v;
+            let x_out = x_minus_quad + y_out.square()? * beta + P::DELTA;
+
+            state[i] = x_out;
+            state[NUM_COLUMNS + i] = y_out;
+        }
+
+        Ok(())
+    }
+
+    /// Applies the Anemoi linear layer to the provided state variables.
+    ///
+    /// This does not create any constraint.
+    pub fn apply_mds(state: &mut [FpVar<F>]) {
+        debug_assert_eq!(state.len(), Self::STATE_WIDTH);
+
+        let beta = F::from(P::BETA as u64);
+
+        match NUM_COLUMNS {
+            1 => {
+                state[0] += &state[1] * beta;
+                state[1] += &state[0] * beta;
+            }
+            2 => {
+                state[0] += &state[1] * beta;
+                state[1] += &state[0] * beta;
+
+                state[3] += &state[2] * beta;
+                state[2] += &state[3] * beta;
+                state.swap(2, 3);
+            }
+            4 => {
+                Self::apply_mds_4(&mut state[..NUM_COLUMNS], beta);
+                state[NUM_COLUMNS..].rotate_left(1);
+                Self::apply_mds_4(&mut state[NUM_COLUMNS..], beta);
+            }
+            _ => {
+                Self::apply_naive_mds(&mut state[..NUM_COLUMNS]);
+                state[NUM_COLUMNS..].rotate_left(1);
+                Self::apply_naive_mds(&mut state[NUM_COLUMNS..]);
+            }
+        }
+    }
+
+    fn apply_mds_4(row: &mut [FpVar<F>], beta: F) {
+        row[0] = &row[0] + &row[1];
+        row[2] = &row[2] + &row[3];
+        row[3] += &row[0] * beta;
+        row[1] = (&row[1] + &row[2]) * beta;
+        row[0] = &row[0] + &row[1];
+        row[2] += &row[3] * beta;
+        row[1] = &row[1] + &row[2];
+        row[3] = &row[3] + &row[0];
+    }
+
+    fn apply_naive_mds(row: &mut [FpVar<F>]) {
+        let x = row.to_vec();
+
+        for (i, r) in row.iter_mut().enumerate() {
+            *r = x.iter().enumerate().fold(FpVar::zero(), |acc, (j, s)| {
+                acc + s * P::MDS[i * NUM_COLUMNS + j]
+            });
+        }
+    }
+
+    /// Applies an Anemoi round to the provided state variables.
+    pub fn apply_round(state: &mut [FpVar<F>], step: usize) -> Result<(), SynthesisError> {
+        let c = &P::ARK_C[step % P::NUM_ROUNDS];
+        let d = &P::ARK_D[step % P::NUM_ROUNDS];
+
+        for i in 0..NUM_COLUMNS {
+            state[i] += c[i];
+            state[NUM_COLUMNS + i] += d[i];
+        }
+
+        Self::apply_mds(state);
+        Self::apply_sbox(state)
+    }
+
+    /// Applies an Anemoi permutation to the provided state variables.
+    pub fn apply_permutation(state: &mut [FpVar<F>]) -> Result<(), SynthesisError> {
+        for i in 0..P::NUM_ROUNDS {
+            Self::apply_round(state, i)?;
+        }
+
+        Self::apply_mds(state);
+
+        Ok(())
+    }
+
+    /// Returns `v^(ALPHA - 1)` through square-and-multiply.
+    fn exp_alpha_minus_one(v: &FpVar<F>) -> Result<FpVar<F>, SynthesisError> {
+        let exponent = P::ALPHA - 1;
+
+        let mut result: Option<FpVar<F>> = None;
+        let mut power = v.clone();
+        for bit in 0..u32::BITS - exponent.leading_zeros() {
+            if bit > 0 {
+                power = power.square()?;
+            }
+            if (exponent >> bit) & 1 == 1 {
+                result = Some(match result {
+                    Some(r) => r * &power,
+                    None => power.clone(),
+                });
+            }
+        }
+
+        Ok(result.unwrap_or(FpVar::one()))
+    }
+}
+
+/// The gadget counterpart of the Sponge mode of Anemoi over `F` with
+/// `NUM_COLUMNS` columns, returning digests of `DIGEST_SIZE` elements.
+#[derive(Debug)]
+pub struct AnemoiSpongeVar<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>(
+    PhantomData<(F, P)>,
+);
+
+impl<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>
+    AnemoiSpongeVar<F, NUM_COLUMNS, DIGEST_SIZE, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+    /// The number of field elements of the state reserved for rate.
+    pub const RATE_WIDTH: usize = 2 * NUM_COLUMNS - P::CAPACITY_WIDTH;
+
+    /// Hashes the provided sequence of field element variables,
+    /// following `Sponge::hash_field`.
+    pub fn hash_field(elems: &[FpVar<F>]) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        let mut state = vec![FpVar::zero(); Self::STATE_WIDTH];
+
+        let mut i = 0;
+        for element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % Self::RATE_WIDTH == 0 {
+                AnemoiPermutationVar::<F, NUM_COLUMNS, P>::apply_permutation(&mut state)?;
+                i = 0;
+            }
+        }
+
+        if i == 0 {
+            state[Self::STATE_WIDTH - 1] += F::one();
+        } else {
+            state[i] += F::one();
+            AnemoiPermutationVar::<F, NUM_COLUMNS, P>::apply_permutation(&mut state)?;
+        }
+
+        state.truncate(DIGEST_SIZE);
+
+        Ok(state)
+    }
+
+    /// Merges two digests given as field element variables,
+    /// following `Sponge::merge`.
+    pub fn merge(digests: &[Vec<FpVar<F>>; 2]) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        debug_assert!(digests.iter().all(|d| d.len() == DIGEST_SIZE));
+
+        // Digests are compressed with the Jive mode when
+        // they do not fit in the rate portion of the state.
+        if Self::RATE_WIDTH < 2 * DIGEST_SIZE {
+            let elems = [digests[0].clone(), digests[1].clone()].concat();
+            return AnemoiJiveVar::<F, NUM_COLUMNS, P>::compress(&elems);
+        }
+
+        let mut state = vec![FpVar::zero(); Self::STATE_WIDTH];
+        state[..DIGEST_SIZE].clone_from_slice(&digests[0]);
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].clone_from_slice(&digests[1]);
+        AnemoiPermutationVar::<F, NUM_COLUMNS, P>::apply_permutation(&mut state)?;
+
+        state.truncate(DIGEST_SIZE);
+
+        Ok(state)
+    }
+}
+
+/// The gadget counterpart of the Jive compression mode
+/// of Anemoi over `F` with `NUM_COLUMNS` columns.
+#[derive(Debug)]
+pub struct AnemoiJiveVar<F, const NUM_COLUMNS: usize, P>(PhantomData<(F, P)>);
+
+impl<F, const NUM_COLUMNS: usize, P> AnemoiJiveVar<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+
+    /// Compresses the provided field element variables by 2,
+    /// following `Jive::compress`.
+    pub fn compress(elems: &[FpVar<F>]) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        Self::compress_k(elems, 2)
+    }
+
+    /// Compresses the provided field element variables by a
+    /// factor k, following `Jive::compress_k`.
+    pub fn compress_k(elems: &[FpVar<F>], k: usize) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        assert!(elems.len() == Self::STATE_WIDTH);
+        assert!(Self::STATE_WIDTH.is_multiple_of(k));
+        assert!(k <= Self::STATE_WIDTH);
+
+        let mut state = elems.to_vec();
+        AnemoiPermutationVar::<F, NUM_COLUMNS, P>::apply_permutation(&mut state)?;
+
+        let c = Self::STATE_WIDTH / k;
+        let result = (0..c)
+            .map(|i| {
+                (0..k).fold(FpVar::zero(), |acc, j| {
+                    acc + &elems[i + c * j] + &state[i + c * j]
+                })
+            })
+            .collect();
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use crate::{Anemoi, AnemoiHash, Jive, Sponge};
+    use ark_r1cs_std::eq::EqGadget;
+    use ark_relations::r1cs::ConstraintSystem;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_exp_alpha_cost() {
+        assert_eq!(exp_alpha_cost(3), 2);
+        assert_eq!(exp_alpha_cost(5), 3);
+        assert_eq!(exp_alpha_cost(7), 4);
+        assert_eq!(exp_alpha_cost(11), 5);
+    }
+
+    struct GadgetCheck;
+
+    impl InstantiationCheck for GadgetCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let state_width = 2 * NUM_COLUMNS;
+            let input: Vec<F> = (0..state_width).map(|_| F::rand(&mut rng)).collect();
+
+            // A single permutation has the expected number of constraints.
+            let cs = ConstraintSystem::<F>::new_ref();
+            let mut state: Vec<FpVar<F>> = input
+                .iter()
+                .map(|x| FpVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+                .collect();
+            AnemoiPermutationVar::<F, NUM_COLUMNS, P>::apply_permutation(&mut state).unwrap();
+            assert!(cs.is_satisfied().unwrap());
+            assert_eq!(
+                cs.num_constraints(),
+                permutation_cost::<F, NUM_COLUMNS, P>()
+            );
+
+            // The permutation output is bound to its input: a wrong output
+            // witness does not satisfy the constraint system.
+            let mut output = input.clone();
+            Anemoi::<F, NUM_COLUMNS, P>::apply_permutation(&mut output);
+            for (i, expected) in [output[0], output[0] + F::one()].iter().enumerate() {
+                let cs = ConstraintSystem::<F>::new_ref();
+                let mut state: Vec<FpVar<F>> = input
+                    .iter()
+                    .map(|x| FpVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+                    .collect();
+                AnemoiPermutationVar::<F, NUM_COLUMNS, P>::apply_permutation(&mut state).unwrap();
+                let expected = FpVar::new_witness(cs.clone(), || Ok(*expected)).unwrap();
+                state[0].enforce_equal(&expected).unwrap();
+                assert_eq!(cs.is_satisfied().unwrap(), i == 0);
+            }
+
+            // Gadgets match their native counterparts.
+            let cs = ConstraintSystem::<F>::new_ref();
+            let vars: Vec<FpVar<F>> = input
+                .iter()
+                .map(|x| FpVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+                .collect();
+
+            let hash =
+                AnemoiSpongeVar::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field(&vars).unwrap();
+            assert_eq!(
+                hash.value().unwrap(),
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field(&input).to_elements()
+            );
+
+            let compressed = AnemoiJiveVar::<F, NUM_COLUMNS, P>::compress(&vars).unwrap();
+            assert_eq!(
+                compressed.value().unwrap(),
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::compress(&input)
+            );
+
+            let compressed =
+                AnemoiJiveVar::<F, NUM_COLUMNS, P>::compress_k(&vars, state_width).unwrap();
+            assert_eq!(
+                compressed.value().unwrap(),
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::compress_k(&input, state_width)
+            );
+
+            let digests = [
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field(&input[..1]),
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::hash_field(&input[1..]),
+            ];
+            let digest_vars = digests.map(|d| {
+                d.as_elements()
+                    .iter()
+                    .map(|x| FpVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+                    .collect::<Vec<_>>()
+            });
+            let merged =
+                AnemoiSpongeVar::<F, NUM_COLUMNS, DIGEST_SIZE, P>::merge(&digest_vars).unwrap();
+            assert_eq!(
+                merged.value().unwrap(),
+                AnemoiHash::<F, NUM_COLUMNS, DIGEST_SIZE, P>::merge(&digests).to_elements()
+            );
+
+            assert!(cs.is_satisfied().unwrap());
+
+            // An invalid S-Box witness is detected.
+            let cs = ConstraintSystem::<F>::new_ref();
+            let mut state: Vec<FpVar<F>> = input
+                .iter()
+                .map(|x| FpVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+                .collect();
+            AnemoiPermutationVar::<F, NUM_COLUMNS, P>::apply_sbox(&mut state).unwrap();
+            assert!(cs.is_satisfied().unwrap());
+            let witness = cs.borrow().unwrap().witness_assignment.len();
+            cs.borrow_mut().unwrap().witness_assignment[witness - 1] += F::one();
+            assert!(!cs.is_satisfied().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_gadgets() {
+        check_all_instantiations::<GadgetCheck>();
+    }
+
+    /// Returns the number of constraints of a single permutation gadget.
+    #[cfg(any(feature = "bls_381", feature = "bn_254"))]
+    fn num_permutation_constraints<F, const NUM_COLUMNS: usize, P>() -> usize
+    where
+        F: PrimeField,
+        P: AnemoiParameters<F, NUM_COLUMNS>,
+    {
+        let cs = ConstraintSystem::<F>::new_ref();
+        let mut state: Vec<FpVar<F>> = (0..2 * NUM_COLUMNS)
+            .map(|i| FpVar::new_witness(cs.clone(), || Ok(F::from(i as u64))).unwrap())
+            .collect();
+        AnemoiPermutationVar::<F, NUM_COLUMNS, P>::apply_permutation(&mut state).unwrap();
+
+        cs.num_constraints()
+    }
+
+    // With ALPHA = 5, a round costs 5 constraints per column, which gives the
+    // R1CS constraint counts reported in the Anemoi paper for the 19, 12, 10
+    // and 10 rounds of the 2-1, 4-3, 8-7 and 12-11 instantiations.
+    #[test]
+    #[cfg(feature = "bls_381")]
+    fn test_constraint_counts_bls12_381() {
+        use crate::bls12_381::*;
+
+        assert_eq!(
+            num_permutation_constraints::<Felt, 1, anemoi_2_1::AnemoiParams>(),
+            95
+        );
+        assert_eq!(
+            num_permutation_constraints::<Felt, 2, anemoi_4_3::AnemoiParams>(),
+            120
+        );
+        assert_eq!(
+            num_permutation_constraints::<Felt, 4, anemoi_8_7::AnemoiParams>(),
+            200
+        );
+        assert_eq!(
+            num_permutation_constraints::<Felt, 6, anemoi_12_11::AnemoiParams>(),
+            300
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bn_254")]
+    fn test_constraint_counts_bn_254() {
+        use crate::bn_254::*;
+
+        assert_eq!(
+            num_permutation_constraints::<Felt, 1, anemoi_2_1::AnemoiParams>(),
+            95
+        );
+        assert_eq!(
+            num_permutation_constraints::<Felt, 2, anemoi_4_3::AnemoiParams>(),
+            120
+        );
+        assert_eq!(
+            num_permutation_constraints::<Felt, 4, anemoi_8_7::AnemoiParams>(),
+            200
+        );
+        assert_eq!(
+            num_permutation_constraints::<Felt, 6, anemoi_12_11::AnemoiParams>(),
+            300
+        );
+    }
+}
@@ -0,0 +1,385 @@
+//! SAFE (Sponge API for Field Elements) implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use super::hasher::AnemoiHash;
+use super::permutation::{Anemoi, AnemoiParameters};
+use super::{SafeSponge, SpongeXof};
+
+use ark_ff::PrimeField;
+
+/// A single operation of a SAFE sponge.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SpongeOp {
+    /// Absorption of the given number of field elements.
+    Absorb(u32),
+    /// Squeeze of the given number of field elements.
+    Squeeze(u32),
+}
+
+/// The sequence of operations a SAFE sponge instance is meant to perform.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IOPattern(Vec<SpongeOp>);
+
+impl IOPattern {
+    /// The maximal number of elements of an aggregated operation, as the most
+    /// significant bit of its encoding is reserved to flag absorptions.
+    pub const MAX_LENGTH: u32 = 0x7fff_ffff;
+
+    /// Returns a new IO pattern from the provided sequence of operations.
+    ///
+    /// Returns an error if consecutive operations of the same kind add up
+    /// to more than `MAX_LENGTH` elements.
+    pub fn new(ops: Vec<SpongeOp>) -> Result<Self, SafeError> {
+        Self::aggregate(&ops)?;
+
+        Ok(Self(ops))
+    }
+
+    /// Returns the sequence of operations of this IO pattern.
+    pub fn ops(&self) -> &[SpongeOp] {
+        &self.0
+    }
+
+    /// Returns the encoding of this IO pattern, used to derive the tag of a
+    /// SAFE sponge instance.
+    ///
+    /// Following the SAFE specification, consecutive operations of the same
+    /// kind are aggregated, and each resulting operation is encoded as a 32-bit
+    /// big-endian word, with its most significant bit set for absorptions.
+    pub fn encode(&self) -> Vec<u8> {
+        // The aggregated lengths have been checked when creating the pattern.
+        let ops = Self::aggregate(&self.0).unwrap();
+
+        ops.iter()
+            .flat_map(|&op| Self::encode_op(op).to_be_bytes())
+            .collect()
+    }
+
+    /// Aggregates consecutive operations of the same kind, and checks that
+    /// none of the resulting operations exceeds `MAX_LENGTH` elements.
+    fn aggregate(ops: &[SpongeOp]) -> Result<Vec<SpongeOp>, SafeError> {
+        let mut result: Vec<SpongeOp> = Vec::with_capacity(ops.len());
+        for &op in ops.iter() {
+            let merged = match (result.last(), op) {
+                (Some(&SpongeOp::Absorb(a)), SpongeOp::Absorb(b)) => {
+                    Some(SpongeOp::Absorb(Self::add_lengths(a, b)?))
+                }
+                (Some(&SpongeOp::Squeeze(a)), SpongeOp::Squeeze(b)) => {
+                    Some(SpongeOp::Squeeze(Self::add_lengths(a, b)?))
+                }
+                _ => None,
+            };
+            match merged {
+                Some(merged) => *result.last_mut().unwrap() = merged,
+                None => {
+                    Self::add_lengths(0, Self::length(op))?;
+                    result.push(op);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn add_lengths(a: u32, b: u32) -> Result<u32, SafeError> {
+        a.checked_add(b)
+            .filter(|&length| length <= Self::MAX_LENGTH)
+            .ok_or(SafeError::InvalidIOPattern)
+    }
+
+    fn length(op: SpongeOp) -> u32 {
+        match op {
+            SpongeOp::Absorb(n) | SpongeOp::Squeeze(n) => n,
+        }
+    }
+
+    fn encode_op(op: SpongeOp) -> u32 {
+        match op {
+            SpongeOp::Absorb(n) => 0x8000_0000 | n,
+            SpongeOp::Squeeze(n) => n,
+        }
+    }
+}
+
+/// Errors returned by a SAFE sponge when its IO pattern is violated.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SafeError {
+    /// The call does not match the next operation of the IO pattern.
+    IOPatternViolation,
+    /// The number of provided elements does not match the announced length.
+    InvalidInputLength,
+    /// The sponge was finished before its IO pattern was entirely performed.
+    IOPatternNotFinished,
+    /// An aggregated operation of the IO pattern exceeds `IOPattern::MAX_LENGTH`.
+    InvalidIOPattern,
+}
+
+impl fmt::Display for SafeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SafeError::IOPatternViolation => {
+                write!(f, "the call does not match the IO pattern")
+            }
+            SafeError::InvalidInputLength => {
+                write!(f, "the input length does not match the announced one")
+            }
+            SafeError::IOPatternNotFinished => {
+                write!(f, "the IO pattern has not been entirely performed")
+            }
+            SafeError::InvalidIOPattern => {
+                write!(
+                    f,
+                    "an operation of the IO pattern exceeds 2^31 - 1 elements"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SafeError {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// An Anemoi SAFE sponge over `F` with `NUM_COLUMNS` columns.
+///
+/// The tag of an instance is computed by hashing the encoding of its IO
+/// pattern followed by its domain separator with the extendable-output Anemoi
+/// Sponge mode, and its `CAPACITY_WIDTH` elements are stored in the capacity
+/// registers of the initial state.
+pub struct AnemoiSafeSponge<F: PrimeField, const NUM_COLUMNS: usize, P> {
+    state: Vec<F>,
+    absorb_pos: usize,
+    squeeze_pos: usize,
+    io_pattern: IOPattern,
+    io_count: usize,
+    _parameters: PhantomData<P>,
+}
+
+impl<F, const NUM_COLUMNS: usize, P> AnemoiSafeSponge<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = 2 * NUM_COLUMNS;
+    /// The number of field elements of the state reserved for rate.
+    /// The last `CAPACITY_WIDTH` ones are reserved for capacity.
+    pub const RATE_WIDTH: usize = 2 * NUM_COLUMNS - P::CAPACITY_WIDTH;
+
+    /// Returns the tag of a SAFE sponge instance for the given IO pattern
+    /// and domain separator, made of `CAPACITY_WIDTH` field elements.
+    pub fn tag(io_pattern: &IOPattern, domain_separator: &[u8]) -> Vec<F> {
+        let mut encoding = io_pattern.encode();
+        encoding.extend_from_slice(domain_separator);
+
+        AnemoiHash::<F, NUM_COLUMNS, 1, P>::hash_xof(&encoding, P::CAPACITY_WIDTH)
+    }
+
+    /// Checks that the next operation of the IO pattern is the provided one.
+    fn check_next_op(&mut self, op: SpongeOp) -> Result<(), SafeError> {
+        match self.io_pattern.0.get(self.io_count) {
+            Some(&expected) if expected == op => {
+                self.io_count += 1;
+                Ok(())
+            }
+            _ => Err(SafeError::IOPatternViolation),
+        }
+    }
+
+    #[inline(always)]
+    fn apply_permutation(state: &mut [F]) {
+        Anemoi::<F, NUM_COLUMNS, P>::apply_permutation(state)
+    }
+}
+
+impl<F, const NUM_COLUMNS: usize, P> SafeSponge<F> for AnemoiSafeSponge<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    fn start(io_pattern: IOPattern, domain_separator: &[u8]) -> Self {
+        let mut state = vec![F::zero(); Self::STATE_WIDTH];
+        state[Self::RATE_WIDTH..].copy_from_slice(&Self::tag(&io_pattern, domain_separator));
+
+        Self {
+            state,
+            absorb_pos: 0,
+            // Squeezing right after starting requires a first permutation.
+            squeeze_pos: Self::RATE_WIDTH,
+            io_pattern,
+            io_count: 0,
+            _parameters: PhantomData,
+        }
+    }
+
+    fn absorb(&mut self, length: u32, elems: &[F]) -> Result<(), SafeError> {
+        if elems.len() != length as usize {
+            return Err(SafeError::InvalidInputLength);
+        }
+        self.check_next_op(SpongeOp::Absorb(length))?;
+
+        for &element in elems.iter() {
+            if self.absorb_pos == Self::RATE_WIDTH {
+                Self::apply_permutation(&mut self.state);
+                self.absorb_pos = 0;
+            }
+            self.state[self.absorb_pos] += element;
+            self.absorb_pos += 1;
+        }
+        // Any subsequent squeeze must be preceded by a permutation.
+        self.squeeze_pos = Self::RATE_WIDTH;
+
+        Ok(())
+    }
+
+    fn squeeze(&mut self, length: u32) -> Result<Vec<F>, SafeError> {
+        self.check_next_op(SpongeOp::Squeeze(length))?;
+
+        let mut result = Vec::with_capacity(length as usize);
+        for _ in 0..length {
+            if self.squeeze_pos == Self::RATE_WIDTH {
+                Self::apply_permutation(&mut self.state);
+                self.squeeze_pos = 0;
+                self.absorb_pos = 0;
+            }
+            result.push(self.state[self.squeeze_pos]);
+            self.squeeze_pos += 1;
+        }
+
+        Ok(result)
+    }
+
+    fn finish(mut self) -> Result<(), SafeError> {
+        // Erase the internal state regardless of the outcome.
+        self.state.iter_mut().for_each(|s| *s = F::zero());
+
+        if self.io_count != self.io_pattern.0.len() {
+            return Err(SafeError::IOPatternNotFinished);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::*;
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_io_pattern_encoding() {
+        let io_pattern = IOPattern::new(vec![
+            SpongeOp::Absorb(2),
+            SpongeOp::Absorb(1),
+            SpongeOp::Squeeze(1),
+            SpongeOp::Absorb(5),
+            SpongeOp::Squeeze(2),
+            SpongeOp::Squeeze(3),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            io_pattern.encode(),
+            vec![0x80, 0, 0, 3, 0, 0, 0, 1, 0x80, 0, 0, 5, 0, 0, 0, 5]
+        );
+        assert!(IOPattern::default().encode().is_empty());
+
+        // Aggregated lengths cannot overflow nor reach the absorption flag.
+        let max = IOPattern::MAX_LENGTH;
+        let io_pattern = IOPattern::new(vec![SpongeOp::Absorb(max), SpongeOp::Squeeze(max)]);
+        assert_eq!(
+            io_pattern.unwrap().encode(),
+            vec![0xff, 0xff, 0xff, 0xff, 0x7f, 0xff, 0xff, 0xff]
+        );
+        for ops in [
+            vec![SpongeOp::Absorb(max + 1)],
+            vec![SpongeOp::Squeeze(u32::MAX)],
+            vec![SpongeOp::Absorb(max), SpongeOp::Absorb(1)],
+            vec![SpongeOp::Squeeze(u32::MAX), SpongeOp::Squeeze(2)],
+        ] {
+            assert_eq!(IOPattern::new(ops), Err(SafeError::InvalidIOPattern));
+        }
+    }
+
+    struct SafeCheck;
+
+    impl InstantiationCheck for SafeCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let elems: Vec<F> = (0..15).map(|_| F::rand(&mut rng)).collect();
+            let io_pattern = IOPattern::new(vec![
+                SpongeOp::Absorb(3),
+                SpongeOp::Squeeze(1),
+                SpongeOp::Absorb(12),
+                SpongeOp::Squeeze(13),
+            ])
+            .unwrap();
+
+            let run = |domain_separator: &[u8]| {
+                let mut sponge = AnemoiSafeSponge::<F, NUM_COLUMNS, P>::start(
+                    io_pattern.clone(),
+                    domain_separator,
+                );
+                sponge.absorb(3, &elems[..3]).unwrap();
+                let mut output = sponge.squeeze(1).unwrap();
+                sponge.absorb(12, &elems[3..]).unwrap();
+                output.extend(sponge.squeeze(13).unwrap());
+                sponge.finish().unwrap();
+
+                output
+            };
+
+            let output = run(b"domain");
+            assert_eq!(output, run(b"domain"));
+            assert_ne!(output, run(b"other domain"));
+
+            // The tag fills the whole capacity of the initial state.
+            let tag = AnemoiSafeSponge::<F, NUM_COLUMNS, P>::tag(&io_pattern, b"domain");
+            assert_eq!(tag.len(), P::CAPACITY_WIDTH);
+            let sponge =
+                AnemoiSafeSponge::<F, NUM_COLUMNS, P>::start(io_pattern.clone(), b"domain");
+            assert_eq!(sponge.state[2 * NUM_COLUMNS - P::CAPACITY_WIDTH..], tag[..]);
+
+            // Violations of the IO pattern are reported.
+            let mut sponge =
+                AnemoiSafeSponge::<F, NUM_COLUMNS, P>::start(io_pattern.clone(), b"domain");
+            assert_eq!(
+                sponge.absorb(2, &elems[..3]),
+                Err(SafeError::InvalidInputLength)
+            );
+            assert_eq!(
+                sponge.absorb(2, &elems[..2]),
+                Err(SafeError::IOPatternViolation)
+            );
+            assert_eq!(sponge.squeeze(1), Err(SafeError::IOPatternViolation));
+            sponge.absorb(3, &elems[..3]).unwrap();
+            assert_eq!(sponge.finish(), Err(SafeError::IOPatternNotFinished));
+
+            let mut sponge = AnemoiSafeSponge::<F, NUM_COLUMNS, P>::start(
+                IOPattern::new(vec![SpongeOp::Squeeze(1)]).unwrap(),
+                b"domain",
+            );
+            sponge.squeeze(1).unwrap();
+            assert_eq!(sponge.squeeze(1), Err(SafeError::IOPatternViolation));
+            sponge.finish().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_safe_sponge() {
+        check_all_instantiations::<SafeCheck>();
+    }
+}
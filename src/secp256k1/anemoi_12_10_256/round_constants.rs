@@ -0,0 +1,847 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x0000002300008593,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0xc759ab3b33c7f9b6,
+            0x1a2e82d7ce979427,
+            0x390bf16667c443b7,
+            0x206a06ea519624fa,
+        ])),
+        Felt::new(BigInteger256([
+            0x8d6f3d3e5d4bcd77,
+            0x85a431cc17b3f493,
+            0x1149232813f2563c,
+            0x99f4b5294ee523e1,
+        ])),
+        Felt::new(BigInteger256([
+            0xfdbeba661bd7f43d,
+            0x3f38f256ad647c59,
+            0xc86db61fdb0001b3,
+            0x57c29f7ede16dd8d,
+        ])),
+        Felt::new(BigInteger256([
+            0xf37d22e742f4a194,
+            0x281fe1f1003e92f3,
+            0x8e102f6d1176ea2a,
+            0x402195ba2489cabd,
+        ])),
+        Felt::new(BigInteger256([
+            0x543a87e77b5a221a,
+            0xc090de5ccf87e68b,
+            0xddf58607b1040ef1,
+            0xdc31d4ac9c813f6f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x36e9d41e0a529925,
+            0x3ce4e373e3338ab4,
+            0xe0d5b70dc27ef66b,
+            0xd568ac692b332d54,
+        ])),
+        Felt::new(BigInteger256([
+            0xecf27193e10b15cd,
+            0x3bd58c9cad01ebf4,
+            0x1e37005f634ec584,
+            0xf6e2011c3d25999d,
+        ])),
+        Felt::new(BigInteger256([
+            0x9d315a854141607a,
+            0xdb0bace6c1e9cff5,
+            0x6eea207f535b175d,
+            0x7f3466b6426cada4,
+        ])),
+        Felt::new(BigInteger256([
+            0x6ccff56068572ed8,
+            0x27d49f3bb09a3953,
+            0x53b4f63a079ff2ec,
+            0x83da44900a8cfe74,
+        ])),
+        Felt::new(BigInteger256([
+            0x433faa5d204ef629,
+            0x0dd9e6cde82b2043,
+            0x4ef77b55f8dd50b7,
+            0x174382ff2383bb79,
+        ])),
+        Felt::new(BigInteger256([
+            0x953d034806e73aa0,
+            0x5665a301131238be,
+            0x9175cd8bc0e815e0,
+            0x61189776a6b48dc6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x88dfef1c2b006d22,
+            0x2d3f03efe6c22223,
+            0xcb6ffb67274f1513,
+            0xc954145cbe871688,
+        ])),
+        Felt::new(BigInteger256([
+            0x27880225dcba54a9,
+            0x0dd4734af814523c,
+            0x9235f5e1ce5177d3,
+            0x1ea43e000ac2eef0,
+        ])),
+        Felt::new(BigInteger256([
+            0x007d3c4983aa1cf4,
+            0xf95beb182b28206f,
+            0x0c61d9e712c4261e,
+            0x57be3a2f3be22f88,
+        ])),
+        Felt::new(BigInteger256([
+            0xb4b9c607e8023c7d,
+            0xc831d3afd4ad5f78,
+            0x82378bf24268591e,
+            0x52c062281f59ba98,
+        ])),
+        Felt::new(BigInteger256([
+            0x19d3b0fd85e586ae,
+            0xa53398c3f7005548,
+            0x6696d1d3d329945c,
+            0x860c1f8c805cf5aa,
+        ])),
+        Felt::new(BigInteger256([
+            0xbb7657c300ee29f5,
+            0x38150e5e7238ea91,
+            0x86ea146c206e8bb6,
+            0x3d76a912fde1334a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x66561d21fd5b5a56,
+            0xb0d4ddba9d8459bf,
+            0x479bf57319de8030,
+            0x8da7b38283d9e362,
+        ])),
+        Felt::new(BigInteger256([
+            0x6368ff7cf3cb2286,
+            0x45752631fe5a1664,
+            0xae7dab3ba08e20bf,
+            0xd9ea4126dc384569,
+        ])),
+        Felt::new(BigInteger256([
+            0x0cfa6a91a7f4ff29,
+            0xade0dddb63173b49,
+            0x0c96d43357249e0a,
+            0x80319fb611766f6a,
+        ])),
+        Felt::new(BigInteger256([
+            0xc7237fe6b4aceaa1,
+            0x61ac187b7ef8690e,
+            0x2e891ddf52d2dbb0,
+            0x8967eb6b694c1712,
+        ])),
+        Felt::new(BigInteger256([
+            0x8b690aa4394144f1,
+            0x0fb9938ffb372e84,
+            0x3ddef84af39052ea,
+            0x1e7d5c24a3b94c1a,
+        ])),
+        Felt::new(BigInteger256([
+            0xe43c94082642b61e,
+            0x28d5f207a89786ac,
+            0xfed9fb13bb75b7e9,
+            0x0409aab0343b5bee,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x58f22d6947d4e0e0,
+            0xf36ad2fb07d2d517,
+            0x151c5c6499c72abc,
+            0x1dabc8658671b9a5,
+        ])),
+        Felt::new(BigInteger256([
+            0xe4156510e38a844b,
+            0x02e8d98399eef919,
+            0x77969747b0cc4741,
+            0xaaa05d7fa2143a24,
+        ])),
+        Felt::new(BigInteger256([
+            0x9cb979dc9c1a788d,
+            0x75469d4f7f289af1,
+            0x4ce677ad440f185a,
+            0x3e82f6f25143fbbd,
+        ])),
+        Felt::new(BigInteger256([
+            0x1393f6eadea9d4da,
+            0xd3619eb2d85d27bf,
+            0xaa50423486c7d283,
+            0x28b19c05f63d2e04,
+        ])),
+        Felt::new(BigInteger256([
+            0xfe37e59196151b99,
+            0xa8997415caa889f1,
+            0xcf42b39df864b581,
+            0x844c1c3aa39ee99d,
+        ])),
+        Felt::new(BigInteger256([
+            0x22fa416a3d532994,
+            0xb61f0be2a2e9054a,
+            0x0c8e7015608b5d95,
+            0x252dfd40e1cdb649,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8bfe0a699487da57,
+            0x96a2a2e68d5d69cd,
+            0xe5d6078b3d92f92b,
+            0xeb9fe7b9678996b2,
+        ])),
+        Felt::new(BigInteger256([
+            0xc718f1882e487442,
+            0xc34ec759d19d2720,
+            0x406e067fc70b6cee,
+            0x8e0ba0ce9f11bf4d,
+        ])),
+        Felt::new(BigInteger256([
+            0xc4e81e50ad1bdcb7,
+            0x804a0e086e16cc4c,
+            0xea38fd44db6ebb56,
+            0x37413ce823fa8d20,
+        ])),
+        Felt::new(BigInteger256([
+            0x2afc83ea032ffec4,
+            0xc6f539d0a730e649,
+            0xb9e45904f377e2a8,
+            0x7374fcfb9e25eb79,
+        ])),
+        Felt::new(BigInteger256([
+            0x918dcce93cc188e3,
+            0x06d531875cd2694c,
+            0x76c4a43899bd9aa6,
+            0x391e91a1d260b52f,
+        ])),
+        Felt::new(BigInteger256([
+            0xae291979ef350d7a,
+            0x0854d4c175a7f04e,
+            0xb02925aff96a8f08,
+            0xf8adff6e414a62c7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9e3c5ebf3ff57390,
+            0xd32cee23e992b308,
+            0x7e750739aef968c1,
+            0x8be5560f8b223cd8,
+        ])),
+        Felt::new(BigInteger256([
+            0x7e16df8ffc53e754,
+            0x22fe83c05a677ff1,
+            0xb211448a437ce05a,
+            0x729d0ceaff905e00,
+        ])),
+        Felt::new(BigInteger256([
+            0xa5e4e244a8ad466d,
+            0x223988a706d0e512,
+            0x31ebed12c7341867,
+            0x9baf81e299ca11a2,
+        ])),
+        Felt::new(BigInteger256([
+            0x3473d65cef542761,
+            0x9259740e1d374ea7,
+            0xa314a3c3810a0dd6,
+            0x777cc121f692b124,
+        ])),
+        Felt::new(BigInteger256([
+            0x24a474aea78a4cea,
+            0xb3a3c9a36c016545,
+            0x6a0b3dc3dc33440a,
+            0x77157ad7405e15de,
+        ])),
+        Felt::new(BigInteger256([
+            0x58dfc3ab4d589d84,
+            0x45c953e64076ab10,
+            0x2dbf7904542f47d2,
+            0x9ba5c0862e81749e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9dbcc6e9e15e16b0,
+            0xb1883138228b13dd,
+            0x2bb79af062c2269a,
+            0xb68978ee9ca4eac5,
+        ])),
+        Felt::new(BigInteger256([
+            0x90afc95d74e94e0f,
+            0x0980e79242832160,
+            0xce0067c45ecdaa91,
+            0x883d962b06c755b2,
+        ])),
+        Felt::new(BigInteger256([
+            0xc0a8375302d18a37,
+            0x1cc1aeae25d39e01,
+            0x0df5b256139e2bed,
+            0x305303bfd9f37e4a,
+        ])),
+        Felt::new(BigInteger256([
+            0x649d70e2020dbb86,
+            0x3c4acdce75deafdd,
+            0x5cf43e3e8ec6de5e,
+            0x3032a25546a1d8cd,
+        ])),
+        Felt::new(BigInteger256([
+            0x0d0d214b6e83bd23,
+            0xdb66bf76c85a079f,
+            0xe830163ce2626a59,
+            0x7c359d800dca9d23,
+        ])),
+        Felt::new(BigInteger256([
+            0x3e94698f6f95d4a8,
+            0x2f8f3ac977c55dc0,
+            0x03a5d27af3e596bc,
+            0xeec866497d128a62,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x82deaeb5dabe0c68,
+            0x6376261e0c6aa77c,
+            0xe9c9169c5512100f,
+            0xcc39dc1d8b74fd6e,
+        ])),
+        Felt::new(BigInteger256([
+            0x62437a1e964b091a,
+            0x54cd922bfa409042,
+            0x167e79f70b3820ba,
+            0x57a46b917f2ff1c2,
+        ])),
+        Felt::new(BigInteger256([
+            0x7eef68bb4f8130aa,
+            0x3ed3153ff9eac18f,
+            0x4c87369945842f6a,
+            0x7aeaa55c945dfb7a,
+        ])),
+        Felt::new(BigInteger256([
+            0x5a299e985c370b76,
+            0x8531b3935c576bfe,
+            0xd3b3ddaf56c8574b,
+            0x6b9c2731d1386594,
+        ])),
+        Felt::new(BigInteger256([
+            0x777ec1c7f27ae0e4,
+            0x51f4b3ee90d50cd1,
+            0xf64202299bbca6fe,
+            0x73c85bad4c57e341,
+        ])),
+        Felt::new(BigInteger256([
+            0xafe7508baecc5666,
+            0xedc68333a96d6fa4,
+            0x0a05cb197ee890c8,
+            0x1896e5c014f1315b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4da2622849cadbf9,
+            0xd2a2d2e1657e00d6,
+            0x7e8818adee48f8f3,
+            0x5106cbb7435e6d67,
+        ])),
+        Felt::new(BigInteger256([
+            0x8bbc83ef8435eca3,
+            0x1e5dc61a2f2842e3,
+            0x9043aa12508c07c8,
+            0x03759b950510557e,
+        ])),
+        Felt::new(BigInteger256([
+            0x257fcbd61d293283,
+            0x0e9060084bffe7ef,
+            0x928928568205f80e,
+            0x4cc6a1c73df97499,
+        ])),
+        Felt::new(BigInteger256([
+            0x532676622eaeee52,
+            0x16804677d9e7d36f,
+            0x06f67ba6b89baafe,
+            0x8ee8dc69e455db85,
+        ])),
+        Felt::new(BigInteger256([
+            0xd88ed2ad0e6491e3,
+            0xd28d8a733d1cd610,
+            0x5d60f725e1764cde,
+            0x626a532971d5fc3a,
+        ])),
+        Felt::new(BigInteger256([
+            0xb0f3cdd5f732537a,
+            0x346d6e88c4ef9a00,
+            0xc4559582630f3d7a,
+            0xb35696bd7ddab03d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2bdd02a8194ca6e1,
+            0xa73a608fa01b2634,
+            0x9294d7c987cabde6,
+            0x058d9495a3d528b0,
+        ])),
+        Felt::new(BigInteger256([
+            0x779e18ecd8597d03,
+            0x960aba7a63afca58,
+            0x1cf2eb398233f9e3,
+            0x2734183c5af5e32d,
+        ])),
+        Felt::new(BigInteger256([
+            0xfef0ed81827670c7,
+            0xd68e433f782c7295,
+            0xdbaab132d64c81d9,
+            0x0e546ceaa6440437,
+        ])),
+        Felt::new(BigInteger256([
+            0xa92de6e33664c509,
+            0x20c08bc0c64cd2e9,
+            0xbfdd8d035427c0e7,
+            0xf901c9953e72a07a,
+        ])),
+        Felt::new(BigInteger256([
+            0x2eafaba42f4a5f64,
+            0xcf5dc6f384461aec,
+            0x3bbf1206afc31bc8,
+            0xedc126a925570a0f,
+        ])),
+        Felt::new(BigInteger256([
+            0xfde8fa14abd4a129,
+            0x4f5ea056e035579b,
+            0x790e4bbe72225b1a,
+            0x4138c55826601f0d,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000235555dc2e,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0x0f42d4c513066487,
+            0x6e4f6668e5f35b62,
+            0x39507628c0873840,
+            0xdf41b7838734dcc0,
+        ])),
+        Felt::new(BigInteger256([
+            0xc7977d3d454f524a,
+            0xdc2210690d4cf0d1,
+            0x1221dce1e0fc722c,
+            0x3f319a827ba46e0d,
+        ])),
+        Felt::new(BigInteger256([
+            0xb7734081cbd700a9,
+            0x0028a2241cf06fd4,
+            0x3f31dea9a0022ffc,
+            0x661446d5e16fcb3d,
+        ])),
+        Felt::new(BigInteger256([
+            0x989a92815bb51ded,
+            0x35ac7028a7354cd8,
+            0x846469e5f7401dad,
+            0x2fa31f379964dce0,
+        ])),
+        Felt::new(BigInteger256([
+            0xd4c78488207d4de5,
+            0x7ed2aaad6b95e171,
+            0x94b45cb3ffa3632e,
+            0x3ec052d553dd8af3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfcbfba6d5f75e3bb,
+            0x62a10c2b44ffc651,
+            0x3dfaec31a98697d8,
+            0x9c8508cb712a127e,
+        ])),
+        Felt::new(BigInteger256([
+            0xfab1816dc017786a,
+            0xb5b298e52629eecc,
+            0x7ba0ba45a3195b7a,
+            0x7cd60e17b8bb368c,
+        ])),
+        Felt::new(BigInteger256([
+            0x9d2f80d22912d577,
+            0x5745b43b194f07d1,
+            0xcce80f5d076cd4bb,
+            0xeb8da871b522dcf9,
+        ])),
+        Felt::new(BigInteger256([
+            0xec5a61cb18242f3f,
+            0x0e8077c081f2686b,
+            0x279e53e7b3a9c2a2,
+            0x5948484953dcd14d,
+        ])),
+        Felt::new(BigInteger256([
+            0xae33004538dd62ac,
+            0x41229dbcf0ee15c5,
+            0xa270eaf2c5ae25a7,
+            0xcde168dede55b2c5,
+        ])),
+        Felt::new(BigInteger256([
+            0xdb9fe636abd85695,
+            0x3a63980910ec6f42,
+            0xa559d95bf68f0b8a,
+            0x8ac37201a407be73,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeb110ca6e75c886f,
+            0x35d4ef75c2151bef,
+            0x2858de8f68edeb33,
+            0xb506bb44f8918867,
+        ])),
+        Felt::new(BigInteger256([
+            0xd1a2493a22ff842c,
+            0x6a8b4261eac31342,
+            0xef635dcc68b3427c,
+            0xc92e95817a6c1894,
+        ])),
+        Felt::new(BigInteger256([
+            0x9cd699d1d2b462a8,
+            0x586fb53afc141679,
+            0x6a2376c9216d182f,
+            0xe8adc670a2abeb92,
+        ])),
+        Felt::new(BigInteger256([
+            0xd09f69adff080d9b,
+            0x91b76f031f8c4cbf,
+            0x55e497a449095d87,
+            0x4cc4b0675cbd1a26,
+        ])),
+        Felt::new(BigInteger256([
+            0x21223e2205acc7b9,
+            0xbb561281794a08f9,
+            0xb9d3ef74fa919dff,
+            0x61404ff22f4279ab,
+        ])),
+        Felt::new(BigInteger256([
+            0x9e3471ed0d1816a1,
+            0xfeecc634e999df44,
+            0x9a91ce40b0acb612,
+            0x8bb7ce23ef47f0ac,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbd98710e46abceac,
+            0x2b19936744ee6cdf,
+            0x275a4cf9764c7e1c,
+            0xff109da33f819c55,
+        ])),
+        Felt::new(BigInteger256([
+            0x02947cf4c704b2b4,
+            0x13dabf6fbd1ff0bf,
+            0x8e80878455bf1334,
+            0x0a2adbe0cd7eb622,
+        ])),
+        Felt::new(BigInteger256([
+            0x9e64fe7c83f3a1b7,
+            0x7ea37225001a4aa7,
+            0xed2de573809cb7e6,
+            0x96d76f2ff9dd7288,
+        ])),
+        Felt::new(BigInteger256([
+            0xd81a59ef58a71899,
+            0x9ce07df595ee6fa9,
+            0x850b9def744307e4,
+            0x09227ce3284cbdb4,
+        ])),
+        Felt::new(BigInteger256([
+            0x87c8ce2a45fcdf05,
+            0x978ad7744997fb89,
+            0x13f18a4a35c78458,
+            0x7f67cfc2d43c1730,
+        ])),
+        Felt::new(BigInteger256([
+            0xbc0be493bf60fbd3,
+            0x615c7404ec0f94b3,
+            0x9557294666830a11,
+            0xd80112f9a73f6065,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x61c752e75c3f7dc6,
+            0x2f3f35d3f75e77d7,
+            0x709a81841e71f824,
+            0x8553ebf7c5c93d59,
+        ])),
+        Felt::new(BigInteger256([
+            0x34d3b41981de3938,
+            0x92de1feda0d66314,
+            0xd35941298e3a0931,
+            0xd12031ab170a759e,
+        ])),
+        Felt::new(BigInteger256([
+            0xdfb6df59433343ab,
+            0x0798dec5644d39ef,
+            0xa93d568695c401b2,
+            0x4b67ffddbd5ac99d,
+        ])),
+        Felt::new(BigInteger256([
+            0xd61da2844dbe2791,
+            0xd025b1593774bdf9,
+            0x7c928fddd074ce33,
+            0x9eab66ef38ed9f68,
+        ])),
+        Felt::new(BigInteger256([
+            0xac2a7aa96deade3d,
+            0xf1fa6526612ae696,
+            0x2115133662d8b66b,
+            0xdb75c94a57d17f75,
+        ])),
+        Felt::new(BigInteger256([
+            0xac5c6387a18b97d9,
+            0xb0353b0c2e82a2f0,
+            0x1ecb6be133d57f39,
+            0xef649efbd8818581,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x82f13b39eb4619a9,
+            0xf0c6ceba95043ce9,
+            0x73125db9bbccc4f8,
+            0x3fa18bca4a5744fc,
+        ])),
+        Felt::new(BigInteger256([
+            0x05f54be20eefc7ca,
+            0x7193d6bef09fc177,
+            0xcdeee1709e082d45,
+            0xa0e4f578b77e255c,
+        ])),
+        Felt::new(BigInteger256([
+            0xf6038f1e96884670,
+            0x30ec18796b569ba6,
+            0x784e0d2d26b2a314,
+            0x307fc65233878596,
+        ])),
+        Felt::new(BigInteger256([
+            0xdba43ad4b497f016,
+            0xe20915721e63acdf,
+            0xbde4d7bd36b3dcbe,
+            0xd5c84863844c8772,
+        ])),
+        Felt::new(BigInteger256([
+            0x2d9e6d5256eaea22,
+            0x6e85eb930b6ff64d,
+            0xfa5534dffdc099f6,
+            0x7ca1bf302a09759b,
+        ])),
+        Felt::new(BigInteger256([
+            0x25a946e995c11e2b,
+            0x20bacce6195cbe51,
+            0xf424528ac643af12,
+            0xaf3e21a7db745c94,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf5d6bce21fa375e5,
+            0x714ac049354b1ebf,
+            0x1cd274554ea19b99,
+            0x22111d8a37468ba2,
+        ])),
+        Felt::new(BigInteger256([
+            0x1d9a673c65eafddf,
+            0x153d3976bd7bb2e3,
+            0x50b336683be807bb,
+            0xc7a084fee1536490,
+        ])),
+        Felt::new(BigInteger256([
+            0x37a780651b097329,
+            0x16d5396948224d08,
+            0xd12213e833e6672f,
+            0xd7182eb672adaa97,
+        ])),
+        Felt::new(BigInteger256([
+            0x45c2ba9b29abdf87,
+            0xf166f600d87badd9,
+            0xb8363968e5b46ef6,
+            0x1bfa2ff3a60fed9d,
+        ])),
+        Felt::new(BigInteger256([
+            0x215c426a4aa3712c,
+            0x5f4e2a005eb08ae1,
+            0xfebce55861a4aa65,
+            0xfcc2cbcf615d76ca,
+        ])),
+        Felt::new(BigInteger256([
+            0x31071e6d7cd47138,
+            0xa228f25c283d11ae,
+            0x82dbbccc4276cee6,
+            0x9460062992020eeb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb82e9d0743f9d5ba,
+            0x1a84c7de305ecfe8,
+            0x60817c13836e5f9f,
+            0x217323e780169d97,
+        ])),
+        Felt::new(BigInteger256([
+            0xf30ac904616e214f,
+            0xc69e61c967b2a4a5,
+            0x030ecda9d83cd81e,
+            0xb1fef1bd1fd7c04b,
+        ])),
+        Felt::new(BigInteger256([
+            0x15424d6df81b73a8,
+            0xdc3c23f12940564b,
+            0x43984d33015480e1,
+            0x40799411ea247b48,
+        ])),
+        Felt::new(BigInteger256([
+            0x38c3cd19bf532c90,
+            0x66371441f33e5f63,
+            0x088247eb747545ab,
+            0xa96df4a52d6c794f,
+        ])),
+        Felt::new(BigInteger256([
+            0xcc9c6701948a9e1a,
+            0x51efe4547d247d8e,
+            0x134e31d8e8d7d6e1,
+            0xd6a0d1f666176219,
+        ])),
+        Felt::new(BigInteger256([
+            0xd9933c4c21ff6511,
+            0x56cd9dc021a714b1,
+            0xef2e8a4a633123fd,
+            0xbc408f6b17e088b7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd555c1c83d1334ca,
+            0x5ee11595665b02f8,
+            0x449a2264d4fc17b6,
+            0x7037706b98b02e1a,
+        ])),
+        Felt::new(BigInteger256([
+            0xfca3b6ba828945b2,
+            0xa45965346b8cb2f8,
+            0x71940a81e3e51cea,
+            0xba79b078c209da33,
+        ])),
+        Felt::new(BigInteger256([
+            0x0b8ebbcb44848373,
+            0x90bbe3544974194a,
+            0xa830fc1b92785301,
+            0xc4251f03ce587651,
+        ])),
+        Felt::new(BigInteger256([
+            0x665537c61935e9a9,
+            0x418c52d825d3baf5,
+            0xa54912019bb48d3b,
+            0x1deb62d6e1cc83ef,
+        ])),
+        Felt::new(BigInteger256([
+            0x6f134474183b2f04,
+            0x5aec319d91bc2232,
+            0x4767486b016fe228,
+            0x07477978ce6e2610,
+        ])),
+        Felt::new(BigInteger256([
+            0x82eb603d60ef5027,
+            0xa7733efb9f6bc607,
+            0x1b95ad8e4d71ecac,
+            0x1f22f836d988ad8a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x62b8449e875db8c4,
+            0x0ebfb27e0eae52a2,
+            0xd4de5922fde01b7e,
+            0x1622e6d42e7a09ae,
+        ])),
+        Felt::new(BigInteger256([
+            0xe8bb8fef4bb1dda4,
+            0xae9b8947efb45be9,
+            0xe6de6f49b8e61edb,
+            0x8769674b25caa98b,
+        ])),
+        Felt::new(BigInteger256([
+            0x74bdee49ed6a39b5,
+            0xa12b1e41eac935f9,
+            0xe9b822855ea73688,
+            0xb71fa23d55d45b0c,
+        ])),
+        Felt::new(BigInteger256([
+            0x21f0def3c6eb80ee,
+            0x138cd5e1f2a418b6,
+            0xd410e4a58d34fbd1,
+            0x62569eddd2ca657b,
+        ])),
+        Felt::new(BigInteger256([
+            0x92c224bd0f62946c,
+            0x1c36f8478d43e1c1,
+            0xaa0b7213d6d6a2ec,
+            0x1707f7c3d1ccaaa4,
+        ])),
+        Felt::new(BigInteger256([
+            0x4696aceb849301a4,
+            0x2ecc1a760a2de6b3,
+            0xd16aaca3c145b441,
+            0xdb01300320529808,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbae2f30b3778b425,
+            0x820266335f068398,
+            0xe78ff239f98a7e22,
+            0xbcd97a1dbb470b07,
+        ])),
+        Felt::new(BigInteger256([
+            0x4e8d32da806ea24e,
+            0xc4f3a3af39f6eef7,
+            0x72328a6c4cb6aea8,
+            0x9d57ae5da8067d4a,
+        ])),
+        Felt::new(BigInteger256([
+            0xc81f1de33350ac43,
+            0x07d427802cb0cc38,
+            0x317e855d15165e06,
+            0x6add37cbea7530bb,
+        ])),
+        Felt::new(BigInteger256([
+            0xf1e85d62af3a8bef,
+            0xbc784131f4c423c8,
+            0x8b9ccffd8ae9af6b,
+            0xbe9f5674593d7081,
+        ])),
+        Felt::new(BigInteger256([
+            0x62d30ba210e19637,
+            0xb7b25aceea283235,
+            0x870e66f0074c0f87,
+            0x948e95aeb1a3fe89,
+        ])),
+        Felt::new(BigInteger256([
+            0x0d7be71819ce839d,
+            0xe868724b3b2eafe7,
+            0x84c83cdb32816f92,
+            0x5b132908f52e4ce8,
+        ])),
+    ],
+];
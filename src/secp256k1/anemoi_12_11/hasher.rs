@@ -0,0 +1,1870 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger256([
+            0x54e4f99d85d5af2d,
+            0xf04766dac4ae6cbf,
+            0x120b411caee818bb,
+            0x2d7fdca4c3e96495,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0xf75a89c06256361a,
+                0xadd861118d7cc4f1,
+                0x50fe1f25bbf73dea,
+                0xc2eb0752d4d0d947,
+            ])),
+            Felt::new(BigInteger256([
+                0xe2cc19c5b2f891bb,
+                0xffb1155c68d53640,
+                0xfbbcd374a36c620b,
+                0xa37d722f06d53900,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x0e8a982519d99ba2,
+                0x72cadde687845b3c,
+                0xe3ae71747915d311,
+                0x59aeb1ef450748f4,
+            ])),
+            Felt::new(BigInteger256([
+                0x841bd08cb7321d83,
+                0x5c79669f016fb228,
+                0x7ad82aadb2a55670,
+                0x42fd74536731053c,
+            ])),
+            Felt::new(BigInteger256([
+                0x68e7de01fac3e999,
+                0xc7ab9129b1cea214,
+                0x19fe1d02626e32cb,
+                0x3dec2973942f5e3a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xae6634f1ff6f0b39,
+                0xd26bfe3a7377a47c,
+                0xe418ce4cdb8ba3c5,
+                0x78387179cbb70872,
+            ])),
+            Felt::new(BigInteger256([
+                0xd095048cb30058d6,
+                0xf8e0cb33e803b5ce,
+                0x83dc88be212bb23e,
+                0xf98c02431e409b72,
+            ])),
+            Felt::new(BigInteger256([
+                0xba4b4e5816726dd1,
+                0x1daa2769b4963e1c,
+                0xc046046371b44b68,
+                0x84e45b1062075105,
+            ])),
+            Felt::new(BigInteger256([
+                0x6dad1f4a98995457,
+                0xe143ea8f9856b002,
+                0xd459049735045023,
+                0x47a8e6cd9c0e5888,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xdcc7d55a77751d73,
+                0xbc041c762140ae3a,
+                0x1308c5b4d63e742d,
+                0xe4a49f0b92b068d6,
+            ])),
+            Felt::new(BigInteger256([
+                0xebd6e97d34bacf1d,
+                0x7b14d714daabd846,
+                0xbe3e5e744b36212f,
+                0xc0a15dec4968be7a,
+            ])),
+            Felt::new(BigInteger256([
+                0x9fb16e70b13b6d1d,
+                0xb14defd9f50e851b,
+                0x32de26e87baebd1d,
+                0x4b754a96508f6f50,
+            ])),
+            Felt::new(BigInteger256([
+                0xc0780994b6782096,
+                0x8cbeee76bdfcc55d,
+                0x0d234a60e39e4fe1,
+                0x33d40234ae6ab75b,
+            ])),
+            Felt::new(BigInteger256([
+                0xcd56f8366d64ba6c,
+                0x19212ca35f8cdbe3,
+                0xb5f7cf1e8e571799,
+                0x53b12f28de124373,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x51fe903e8ce05ccb,
+                0xc66d8e78d6f5a6db,
+                0x3f605431436acda3,
+                0x827ed6d90a113367,
+            ])),
+            Felt::new(BigInteger256([
+                0x6dac48d32ae894ab,
+                0x5b1a5e7f9a839a35,
+                0x6e517a4b807466fa,
+                0xf190dc99edb068dd,
+            ])),
+            Felt::new(BigInteger256([
+                0x9c510bcb70e09d6b,
+                0x511d474312cfba01,
+                0x41fde9f07df71074,
+                0x3c111078ea957207,
+            ])),
+            Felt::new(BigInteger256([
+                0x5dcf6c0b39e7a259,
+                0x3311084c48f6a9e3,
+                0x577393b0b8895878,
+                0x140238c614a9bba1,
+            ])),
+            Felt::new(BigInteger256([
+                0x75fa66be42d2047e,
+                0x834677a176da0243,
+                0xd1d01aec13ccbb60,
+                0xe731e441ffcf855f,
+            ])),
+            Felt::new(BigInteger256([
+                0x537685d2ec3c6635,
+                0x1f2c71ad363b4057,
+                0x680a1f0135960bf0,
+                0x20c75b7f9b426942,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xc312b61940ceba04,
+            0x9e4c81ae2e6410da,
+            0xb8d4de74ee95ce37,
+            0x760fe62c3a9b15f1,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb06495af47574945,
+            0xa0e4155a53093b01,
+            0xb647d30c449a66f5,
+            0x3c28e36486746d9e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x409b0dea3208f54b,
+            0x3178fd51ebb41bce,
+            0xf7a0d6bc3d0dcce7,
+            0xe4e997c7aa1ee3c7,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbcbd5fb4436a58f2,
+            0x1946181df2d68f64,
+            0x95eeb7296c65fc2e,
+            0xa94ba92f6643a3a0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc0dd0bf1af8fcd5f,
+            0x06cdc6150bf4654d,
+            0x50ea3403799e0dee,
+            0xc35a7c3301d2c590,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4e82e8680e671f80,
+            0x7b5f073effc1d01d,
+            0x0fd4dde86880435b,
+            0x3a85f1cbe46bdddf,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa33d68aafffdd49b,
+            0xb4fd95ef9a1f3dd8,
+            0xb1e15806d1fdacaf,
+            0x1eaca6931dbc3264,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4df70da34e46d832,
+            0xf1a002c1cc4d8523,
+            0x61e1a2a4455c1d53,
+            0x30d7e229af4d780d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x32ec209a7620cad1,
+            0x343f7a2235e233b1,
+            0xb48d3f8ffed1e8a8,
+            0x8e644de18e638cab,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x77a6831b934dea8e,
+            0xb5091536e40a277c,
+            0xbe3e105f83173caa,
+            0x39cc489075941f53,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x2ef1ba8b4100338a,
+                0x34d979939b6737f4,
+                0xa38a64618debb7ac,
+                0x3e56c9d45179e8a8,
+            ])),
+            Felt::new(BigInteger256([
+                0x0cff5212c459162b,
+                0x3a4f14950b09d6d0,
+                0xf32da2e94ceab4fa,
+                0x9d4392dfed1ca097,
+            ])),
+            Felt::new(BigInteger256([
+                0xb15bf5cf7e1bb350,
+                0xa6ef7c7ae7d01b5e,
+                0x84be21465949e103,
+                0xe45e20b9e14f908f,
+            ])),
+            Felt::new(BigInteger256([
+                0xb5fc47fb5ebdd5cf,
+                0x85ff4d90cff1dd8c,
+                0xf19830c608086424,
+                0x350d5de1fb93cc88,
+            ])),
+            Felt::new(BigInteger256([
+                0x1da1d31505ded0a0,
+                0xec6524070c398dd1,
+                0x4628d43a8fbe8daf,
+                0x8a6e13e47d19cc48,
+            ])),
+            Felt::new(BigInteger256([
+                0x7254e40512cf1285,
+                0xaadf518e65ff15e2,
+                0xe564edaed40ddde6,
+                0x80a67c5d96b7ad97,
+            ])),
+            Felt::new(BigInteger256([
+                0x8906c30ee0c77e4b,
+                0xfa529bd35b9343cf,
+                0xa60164af3e88374a,
+                0x8dac610dca95c303,
+            ])),
+            Felt::new(BigInteger256([
+                0xf37f46d548d6616f,
+                0x58f3cba3032825ef,
+                0x5831a2ea0f273469,
+                0x877151cc9a3a862d,
+            ])),
+            Felt::new(BigInteger256([
+                0xd5f46ef25b080604,
+                0x5827127302f1065e,
+                0x8939d29f568d5b06,
+                0xb91ac7e115c5fc96,
+            ])),
+            Felt::new(BigInteger256([
+                0x403e99ed8f77ca8c,
+                0x2a584bfdd5783ba9,
+                0x8a36da34ef60b80c,
+                0x905967498280e0f7,
+            ])),
+            Felt::new(BigInteger256([
+                0xf45bf1beafe553db,
+                0x2a64662779fb5fd4,
+                0x63e49dae8f50a9ad,
+                0xc4f12595596a25eb,
+            ])),
+            Felt::new(BigInteger256([
+                0x38a2d33a8946c393,
+                0x980ddeb01e39a0c3,
+                0xffab89e30c703e38,
+                0xc57f8f82612c8c10,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xaa50855750e7ccc8,
+                0x81a82f5df66cea2e,
+                0xb74d8f6d34f869b4,
+                0x11d2603e1d3f9f55,
+            ])),
+            Felt::new(BigInteger256([
+                0x5ff13305d5ec1e01,
+                0xd1956590f9360014,
+                0x0ff2e0c3d22b5bb2,
+                0xb06b7ee14d0ada1e,
+            ])),
+            Felt::new(BigInteger256([
+                0x80517518abfc400b,
+                0xbab4aa8f0f7e5fb4,
+                0xc7ee3d4a09be8d6e,
+                0x629538c2107c88da,
+            ])),
+            Felt::new(BigInteger256([
+                0x8a063a1a138f95b9,
+                0x848543aa8f929786,
+                0x5d8221a91ffb9445,
+                0x0aa38a3cc91c4e42,
+            ])),
+            Felt::new(BigInteger256([
+                0x2ce6019743675b07,
+                0xbf3ca9215425e652,
+                0x7c0f02b949a72094,
+                0xf7135b3b76636071,
+            ])),
+            Felt::new(BigInteger256([
+                0xbb702154bf177880,
+                0x9259eceeef1ac6b7,
+                0xd425ffff2f30e27e,
+                0x37710804b43f424b,
+            ])),
+            Felt::new(BigInteger256([
+                0xa4ffcd34b42c1fe1,
+                0xc859d79474ef5ec0,
+                0xa1efd91c4d2dfade,
+                0x121fa394f0ab6c3f,
+            ])),
+            Felt::new(BigInteger256([
+                0xc1444fd9006289ff,
+                0x4a2362549df5307e,
+                0x46ee7ef0cd19ad78,
+                0x2f9323b78efe4914,
+            ])),
+            Felt::new(BigInteger256([
+                0xac0d33ea33f52066,
+                0x9fe9882e25985f05,
+                0x17e13961fd2adc7c,
+                0x239de7a1660fdfb8,
+            ])),
+            Felt::new(BigInteger256([
+                0x96dc0ddac8f90665,
+                0x55cf5dc9fc56a56e,
+                0xd7a7e1ea141d4702,
+                0xf5278008d790ad7d,
+            ])),
+            Felt::new(BigInteger256([
+                0x9189912b46b0d36c,
+                0x4ac8e19e16f5f8a8,
+                0x7dfdf0a7879a4a4a,
+                0xd8f33052c133dc06,
+            ])),
+            Felt::new(BigInteger256([
+                0x344ecc8ef2a763b3,
+                0xe982e95a3ba5ef7f,
+                0x7cd3907c7aff7e60,
+                0x85e50501efcc2677,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x2aebc6988799b4c6,
+                0x5b66fe01d00fea5d,
+                0xc9f553b87aa36e86,
+                0x8cc2e6d751b2cc04,
+            ])),
+            Felt::new(BigInteger256([
+                0x7666bc9cee5552d8,
+                0x0017e997f9804ca1,
+                0x75ce7c5a84357867,
+                0x161c9a3c22dff896,
+            ])),
+            Felt::new(BigInteger256([
+                0x4e641dfe11bb500b,
+                0xe8d36c33f0068ecb,
+                0x43896bc38b0bba3a,
+                0x2d15507ecab07bda,
+            ])),
+            Felt::new(BigInteger256([
+                0x435c1457d38b6b0f,
+                0x359b70f8336497c6,
+                0xbbed24a8ad576b34,
+                0x8d8d1a4f2a67bc1b,
+            ])),
+            Felt::new(BigInteger256([
+                0x8cc1b27e0599ad2a,
+                0xb67e5df79e3353e5,
+                0xae62dc95f4512be8,
+                0x190fd32fa0d6fabe,
+            ])),
+            Felt::new(BigInteger256([
+                0xf4a38cf96a3dd979,
+                0x21fc564b41c588e0,
+                0x6ab238a004415be7,
+                0x54ff162129344be0,
+            ])),
+            Felt::new(BigInteger256([
+                0xb360fefacf1669e4,
+                0xdebbcdff173c81c8,
+                0x860ba1b2478c44d6,
+                0xbd5f8f643c196177,
+            ])),
+            Felt::new(BigInteger256([
+                0xe5cfe7e224919fe7,
+                0x14d5e632114c464b,
+                0xf508542bccbcf02f,
+                0x30927099db2a98c4,
+            ])),
+            Felt::new(BigInteger256([
+                0x34ab5cbc8bb264b8,
+                0x2fa6cbf238f25183,
+                0x171fbc036a689542,
+                0x0a833e5e80b6ebab,
+            ])),
+            Felt::new(BigInteger256([
+                0x41d8749e503de184,
+                0xcde69e0884aa1252,
+                0x7eefa1aeccdb2074,
+                0xab012af750c0b108,
+            ])),
+            Felt::new(BigInteger256([
+                0x91243cdc92f60791,
+                0x6c44d3ad833681bb,
+                0x12c54a111dcbe7e3,
+                0xccb2a0f60446eea6,
+            ])),
+            Felt::new(BigInteger256([
+                0x9618b8ab81c7e0f4,
+                0x93477f7dfb56ddd5,
+                0xc9405d61dd0824fa,
+                0xc5bf8e6b977e92d4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb502c906ea64381b,
+                0xdabc6741b6f66652,
+                0xdce54116168f3f76,
+                0x30ef4c65655a2731,
+            ])),
+            Felt::new(BigInteger256([
+                0x71dca6712ad8329d,
+                0xb6c52f8eab311ed6,
+                0x3c3a62640cab83fc,
+                0x291e4ebf4fd394e7,
+            ])),
+            Felt::new(BigInteger256([
+                0x78fdc8a451def026,
+                0xd12dd03890cb4f4d,
+                0x82d190c369631b4e,
+                0x0012c5c9326f16e8,
+            ])),
+            Felt::new(BigInteger256([
+                0x1f64c9f98e9b8b00,
+                0x147994ad60a1cec0,
+                0xcf313aab195a96f9,
+                0x5d4b65151d847bc8,
+            ])),
+            Felt::new(BigInteger256([
+                0x0e98fb1f3f392ee9,
+                0x2dd714784d545b42,
+                0x4295b2b37fc0e37c,
+                0x1da7fe412d2973ae,
+            ])),
+            Felt::new(BigInteger256([
+                0xeb6066a310daae63,
+                0x7c1fec60aa8b11be,
+                0x97c53c77e6094e04,
+                0x8d4903f8b377581f,
+            ])),
+            Felt::new(BigInteger256([
+                0x2765044288008c97,
+                0x026558e9e765e95d,
+                0xcf48b38fe1cdc474,
+                0x36ec1fddc7b5b20f,
+            ])),
+            Felt::new(BigInteger256([
+                0xb3bc0c323cf682a1,
+                0x8aa851da5b243e5c,
+                0x06a1e8ad67a90b68,
+                0x5cc75252971aeb30,
+            ])),
+            Felt::new(BigInteger256([
+                0x05d2c5ea2bf6b9b5,
+                0xbddb33bf07940444,
+                0x639f6e66a3ac8077,
+                0xf9e3f28bb1fb1854,
+            ])),
+            Felt::new(BigInteger256([
+                0xb001ae6cd5924d6d,
+                0xc77b5c9b18a85461,
+                0xa750087d460632cd,
+                0xe808d7652d2bf31a,
+            ])),
+            Felt::new(BigInteger256([
+                0x8dbff604777373af,
+                0xb7b47ef079b351f1,
+                0x4844b52a4988f4ec,
+                0x6e0bf247261ddde7,
+            ])),
+            Felt::new(BigInteger256([
+                0xa61ce6f0f7e48adc,
+                0xa596c24fb141b64f,
+                0x66d847bad65faa6a,
+                0x3507cbc5ec13bec3,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x642f21bf982c3015,
+                0x3899984f29f59157,
+                0x402a7b9540642505,
+                0xf7f3c3ea36e32d6b,
+            ])),
+            Felt::new(BigInteger256([
+                0xb603e463e0df20b7,
+                0x7c6b79a1e5dcad21,
+                0x6eb5d977156791d0,
+                0x832e671a1a8ac66d,
+            ])),
+            Felt::new(BigInteger256([
+                0xe11cf202f3caced3,
+                0x67642bf2af5be7e7,
+                0xfe197109719dfa9f,
+                0xa17c567e86c24264,
+            ])),
+            Felt::new(BigInteger256([
+                0xaa3b99774ba03b81,
+                0x6404da521e514756,
+                0xf492171dc46b6bd4,
+                0xb607b82806da3d83,
+            ])),
+            Felt::new(BigInteger256([
+                0x274dd8354f5cbb74,
+                0xf8eef1377ef852b0,
+                0x59ada907023dac6b,
+                0x7f401967aa43c6ef,
+            ])),
+            Felt::new(BigInteger256([
+                0x3eba41bb8dcd0e68,
+                0x6eab544d63b4fa02,
+                0xd8aac60625db48f4,
+                0xa5eda04bac95fe1b,
+            ])),
+            Felt::new(BigInteger256([
+                0x1f7d9bba313e5128,
+                0xdc1c5aef6e278403,
+                0x4d03b2320c6f5135,
+                0xed17a2ff94110d00,
+            ])),
+            Felt::new(BigInteger256([
+                0x0f54b54730db9bde,
+                0xb7bd5da58806c677,
+                0xd5503f243480a83c,
+                0xcc5812c37b573142,
+            ])),
+            Felt::new(BigInteger256([
+                0x80623dceaba889fb,
+                0x418a2e9829db743f,
+                0xa8e08c05a1cc6514,
+                0x6eb5a59efff70704,
+            ])),
+            Felt::new(BigInteger256([
+                0xc8940b4a0c399a75,
+                0xc1cb83ab6505df68,
+                0x1a008321cf703f8c,
+                0xaf373e9e0d65bc7f,
+            ])),
+            Felt::new(BigInteger256([
+                0x0051d798b5a96785,
+                0x654cf8b9d99fecc6,
+                0x462c5e69b25dd46e,
+                0x5247c782d10d4cba,
+            ])),
+            Felt::new(BigInteger256([
+                0x0870d01e30f5fc3d,
+                0xc401c80f280f7022,
+                0x3af48c47016e52ee,
+                0xceb7a4c6355036a2,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x23746f575dae5473,
+                0xeea0281a3f4ebf3f,
+                0x440d991062b88e9b,
+                0x690e1c5de928ed03,
+            ])),
+            Felt::new(BigInteger256([
+                0xcfaffa30bab75e64,
+                0x72a51b98f4f6a4a2,
+                0x93259f12ad3a0deb,
+                0xdcffae2f4a78995e,
+            ])),
+            Felt::new(BigInteger256([
+                0xe05542683eefc01b,
+                0x1e9ecb58836a384d,
+                0x0b83d876d2421274,
+                0x1433d37c106cd914,
+            ])),
+            Felt::new(BigInteger256([
+                0x482d3d7d389c6b3c,
+                0x9593881c6e31a1fb,
+                0xc2b79cba2fa40c21,
+                0xff628ad93f5ed623,
+            ])),
+            Felt::new(BigInteger256([
+                0x6b6a9dd4b68ad7f3,
+                0x563f1fd525b5d758,
+                0xa0d3cc40dfe7479a,
+                0x4116ad8a7e1139c2,
+            ])),
+            Felt::new(BigInteger256([
+                0xe3102c98497efcf0,
+                0x97ce95be8c1c7472,
+                0xc3f01cd58e9118a5,
+                0x385f8ba7e90884e7,
+            ])),
+            Felt::new(BigInteger256([
+                0x8a86b59fed03e86a,
+                0x2678174b1f62c0ac,
+                0x34935c5f4fbc8456,
+                0xde223e6510ea7270,
+            ])),
+            Felt::new(BigInteger256([
+                0x5f7b308bf8ad196e,
+                0xbbbe9f840e506e8e,
+                0x067ee1e37b862bdd,
+                0xf7c8b8960b81414a,
+            ])),
+            Felt::new(BigInteger256([
+                0x688a7ad3b0b9c67e,
+                0x2ed1406fbfa908d1,
+                0x57a098b8dba54ac8,
+                0xc63cb70858f2c83e,
+            ])),
+            Felt::new(BigInteger256([
+                0x493c82bd0144de4a,
+                0x71401a3186363056,
+                0x87013d14537abe83,
+                0x129111e986d0ec2b,
+            ])),
+            Felt::new(BigInteger256([
+                0x8a79b38011325dd2,
+                0xc401309210d42d6d,
+                0x75fe484419ef8e74,
+                0x119f4b734c179e0a,
+            ])),
+            Felt::new(BigInteger256([
+                0xf4aabf3aa7ede1ab,
+                0x7d183e0c806e12b1,
+                0x314ed8036570c2b5,
+                0x7122458f15387cfc,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x5c69d2ad8d12791a,
+                0x9abaf7a029436c6a,
+                0x0b7d556ae762c9a9,
+                0x0d5faf71f4057418,
+            ])),
+            Felt::new(BigInteger256([
+                0x59b4413c51e8d62b,
+                0x577be3422c18b0b9,
+                0xc26e168b9f46a288,
+                0x98fc0766280beb31,
+            ])),
+            Felt::new(BigInteger256([
+                0x7b8f3e45f5311a31,
+                0x1640439e567ead27,
+                0x7fc745a2cbfddc16,
+                0x090432999799d652,
+            ])),
+            Felt::new(BigInteger256([
+                0x1105e7cfd320cf3b,
+                0xa20bb663c42fa1d4,
+                0xc9da4ef846d1e3c7,
+                0x92119e2ccf5c506b,
+            ])),
+            Felt::new(BigInteger256([
+                0x734d707ba4033780,
+                0x3d56b093388675b8,
+                0x8adaac2d3bdf8a87,
+                0x3881c8f2a4d718f7,
+            ])),
+            Felt::new(BigInteger256([
+                0x4b300ab5fe63fa66,
+                0xffd137c17da02aa7,
+                0x1ce1f816fbd9486e,
+                0x85518bb6f0f4b4e2,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x49e1e652510c7e06,
+                0x151f23076bbc5bc3,
+                0x5aa64ea52e05d6fd,
+                0xef76339450d59f01,
+            ])),
+            Felt::new(BigInteger256([
+                0x99a5fc1b083195c3,
+                0x1d01eccf9defbeae,
+                0x2c3a67c702b1926b,
+                0xb167205d9b3dd9b3,
+            ])),
+            Felt::new(BigInteger256([
+                0x8edd08a3940811b6,
+                0x7186f7ad3e7e4c68,
+                0x91946d5cf07d3335,
+                0x3729feaa2d7978e2,
+            ])),
+            Felt::new(BigInteger256([
+                0x9a950fb78508aa8b,
+                0x4f9d30d815d2e2cf,
+                0x8730466e5147755d,
+                0xef71e90e5302ad7a,
+            ])),
+            Felt::new(BigInteger256([
+                0x31eda6f8bdc7b355,
+                0x1278f92f3e312b4b,
+                0x663aefebdee2c87a,
+                0xba8fcc0323751cb9,
+            ])),
+            Felt::new(BigInteger256([
+                0xf5be3cd418fa9ef3,
+                0x05775f5c42f95996,
+                0x2813a4c6a1bd7cb4,
+                0x728a9ac1f1963aa6,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xcbc5d97e9ea0872c,
+                0xa95d0c2c960e83d1,
+                0xca008a6140a1ff38,
+                0x5a23bc35276892a7,
+            ])),
+            Felt::new(BigInteger256([
+                0xde756cd13a4e03e1,
+                0xbe47b4e65c90c5eb,
+                0xee910c416ac2264a,
+                0x26dcfbca34408ce2,
+            ])),
+            Felt::new(BigInteger256([
+                0x3c207c85ef426121,
+                0xc25a46269b802bd7,
+                0x0f6ade7f7bb7fcdc,
+                0x68d86f79b5d96f52,
+            ])),
+            Felt::new(BigInteger256([
+                0xac03670c882843f3,
+                0x06952b01deeb873b,
+                0xb490b44ade0dc7c6,
+                0x98abdfa0171acebb,
+            ])),
+            Felt::new(BigInteger256([
+                0x97874cb4ca5ab2cd,
+                0x0cf2e035af0d5cc6,
+                0xcbbb1e9640d6ac43,
+                0xbd92982a45e43542,
+            ])),
+            Felt::new(BigInteger256([
+                0x4fd4be6e7f8c6b52,
+                0x2731eb1251c3755b,
+                0x0ca9174b8403e419,
+                0x0f3f9aa33b3d6d7e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x1946517b9182a37a,
+                0x46b7937d283ffd05,
+                0xa933b3fd2a22cde8,
+                0x973f67472d7d4627,
+            ])),
+            Felt::new(BigInteger256([
+                0xd34c2dee031ce625,
+                0xc3e204d8e06b1a0b,
+                0x3fb1c860c73bb6b7,
+                0xee0c3f7a08829b27,
+            ])),
+            Felt::new(BigInteger256([
+                0x36e72d63e242213d,
+                0x5bb7a5fd9b630cd8,
+                0xedd8a14484ddce39,
+                0x45c70453ead5c02c,
+            ])),
+            Felt::new(BigInteger256([
+                0x1bf70a5622fdc143,
+                0xc1a524cf1ffdb158,
+                0x84f40dd6ecbf18e9,
+                0xd28b9ded64ae41df,
+            ])),
+            Felt::new(BigInteger256([
+                0x4f1dedcebcf015f2,
+                0xe5100044ff98da0c,
+                0x7f9eeff5126fe35f,
+                0x2dc7e8291b8d4d16,
+            ])),
+            Felt::new(BigInteger256([
+                0xa2ae95f83b00900b,
+                0xcdb1f3f022f8582a,
+                0x773579b114d1a330,
+                0x61c78c8c70baca2d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x7f6bb32d6054eea8,
+                0xa81cf65f80b32930,
+                0x3990703699058de4,
+                0x060612d64f406384,
+            ])),
+            Felt::new(BigInteger256([
+                0xcd63d87a4d274af7,
+                0xdc4e8957ad5cd760,
+                0x2b363a7f327a7d79,
+                0x394fb2acf5d269b1,
+            ])),
+            Felt::new(BigInteger256([
+                0x8559a810bcbf303f,
+                0x538272ed2d1edbcf,
+                0x11fa75ae58cd87c8,
+                0xdecf17849df805f4,
+            ])),
+            Felt::new(BigInteger256([
+                0x00cd561598852fb9,
+                0xe572a2d57c8460db,
+                0x20e67e7f2814b1c7,
+                0x869cdc38b45594dd,
+            ])),
+            Felt::new(BigInteger256([
+                0xa8d0c566843942f1,
+                0x592e02a1616c2da6,
+                0x83761717ffb01c8a,
+                0x59b076e865d9584f,
+            ])),
+            Felt::new(BigInteger256([
+                0x6898abd06a22a776,
+                0x844e3a4c35e898ee,
+                0xd399752a59169817,
+                0x0f7298b60b0fc90d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x747726ad6c0eb675,
+                0xcd6ce6def1626ff6,
+                0x16ccf9e294e665dd,
+                0xeee6030d8b2ad9fe,
+            ])),
+            Felt::new(BigInteger256([
+                0x0285aa30ca59b9ed,
+                0x2a1c77418ee8a243,
+                0xc63a7534dff108f9,
+                0x76099845317e5249,
+            ])),
+            Felt::new(BigInteger256([
+                0x7e77b68d890469e2,
+                0x96cff361ef7f1426,
+                0x5f285351613b44c6,
+                0x37288140080eddf9,
+            ])),
+            Felt::new(BigInteger256([
+                0xb794369b1a90358c,
+                0x094354d823640ad8,
+                0x4ee8c8669cb6ce01,
+                0xa96a3722b9f77ed1,
+            ])),
+            Felt::new(BigInteger256([
+                0x4893315d585dbfd7,
+                0x0356e7e813e5f6f4,
+                0x50e3da6ad8e8c9d0,
+                0x2f907daf75a6fe10,
+            ])),
+            Felt::new(BigInteger256([
+                0xbe591d7dfdf38eef,
+                0xfb9c9be6d01a8c75,
+                0xc05962424c643824,
+                0xc92758da23976f54,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x594d6f2a2b8ae60b,
+                0x4a32c8d6a304bcae,
+                0xdfab591f8086cd63,
+                0x819e08946aac9d83,
+            ])),
+            Felt::new(BigInteger256([
+                0xfd30bdd75c4b9f91,
+                0xc81ff75172bc7c46,
+                0x279d6a83e6d9e6c8,
+                0x69defac240f1545b,
+            ])),
+            Felt::new(BigInteger256([
+                0xa264185c70d0afe3,
+                0xb3d8154c6a92bd7f,
+                0x3b54041fcb0819ae,
+                0x6a92e42700b9d800,
+            ])),
+            Felt::new(BigInteger256([
+                0x8192bf2dd801f6ec,
+                0xc4618bdb7ce2a1ab,
+                0x2adb2ab6a38edf97,
+                0xd1781c19d7c28fd1,
+            ])),
+            Felt::new(BigInteger256([
+                0xcc29771686645fcb,
+                0x8ad68a932fe50965,
+                0xbe6994db60a46acd,
+                0x71a6bdf107ab84db,
+            ])),
+            Felt::new(BigInteger256([
+                0x48a02a3e1a0c76e4,
+                0x3aa1814a5bd2a243,
+                0xcb6e8170b65b5127,
+                0xfa393c7b9d06d13e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x8521aa1674b5f3a1,
+                0x4168f3a0e8ccf3cb,
+                0xa2597ea2a73b1a70,
+                0x29a12483190a5da7,
+            ])),
+            Felt::new(BigInteger256([
+                0xd1a66149677af16f,
+                0xf0a7328b543733ec,
+                0x485408f29402fc65,
+                0x29039410f3a5847b,
+            ])),
+            Felt::new(BigInteger256([
+                0x817f4add2c9d7861,
+                0x25c0649d2c149654,
+                0x2f4c2d7ca7def941,
+                0xd693e97c1228e0e3,
+            ])),
+            Felt::new(BigInteger256([
+                0x14410ceb5febf695,
+                0x607034c6dc8c6cad,
+                0x88aaa9db22f2ffc8,
+                0x6e2b19d1397ca590,
+            ])),
+            Felt::new(BigInteger256([
+                0x7d98a1295c806168,
+                0x971f81cee29088aa,
+                0x22b3dfa89bc684fd,
+                0x192377680c0d9092,
+            ])),
+            Felt::new(BigInteger256([
+                0x340133a86066406d,
+                0x91320c3b1b6647ea,
+                0x89a5fdf0d3cf7feb,
+                0x11bfe85bbc753475,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x3ed4fc02b3cbc971,
+                0xe9caab141e7cd182,
+                0xb54511ceac7c212b,
+                0x82a7106ed0c4e527,
+            ])),
+            Felt::new(BigInteger256([
+                0x3d607a0c930f7eb3,
+                0xd38d173479e5f693,
+                0x4a89e6183cc045ec,
+                0x12cd4a1972c5cf41,
+            ])),
+            Felt::new(BigInteger256([
+                0xc3b551d62855bad4,
+                0xbb9d7fc869ffa4bc,
+                0xc1ea93fa373f3877,
+                0x3fc0efef60cd0828,
+            ])),
+            Felt::new(BigInteger256([
+                0xf8a8250ef7042273,
+                0x8a0c7047b8fcad3d,
+                0x889364d875e3c932,
+                0xd282294423653211,
+            ])),
+            Felt::new(BigInteger256([
+                0x20c390f107bfb843,
+                0xa1e864f22631e278,
+                0x98c3a3cff3f5a03f,
+                0x72bab3751f0cae8a,
+            ])),
+            Felt::new(BigInteger256([
+                0xee412cb129b91a12,
+                0x7caa5d0b56f4ac9c,
+                0xee7daf458b4be09b,
+                0xa3dcd04372e59a0a,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xad8243be3ff3fa8f,
+                0x525ec3834b6e4480,
+                0xd0cd81303aa40d8b,
+                0x6cacd11df7dd71c8,
+            ])),
+            Felt::new(BigInteger256([
+                0x19331e4b17c38b0e,
+                0x52edbd433947dab7,
+                0x7350f04fb2f61be0,
+                0xded356fbaf26f885,
+            ])),
+            Felt::new(BigInteger256([
+                0xb4c4ef19dd04f910,
+                0xcd2970dfc2bdf0f8,
+                0xffbe8ed30d2996f0,
+                0x80979fcadf2760ed,
+            ])),
+            Felt::new(BigInteger256([
+                0x2067cfb4b869ca28,
+                0xdaff83fe53165705,
+                0x952b05356d3fa81c,
+                0xc96d0a4881d1c7ad,
+            ])),
+            Felt::new(BigInteger256([
+                0xb7af8145787dabb7,
+                0x7eeddf8ee7023245,
+                0x674ba3cd3bb9152c,
+                0x2a07b81074eb1729,
+            ])),
+            Felt::new(BigInteger256([
+                0x5be7172edc17ad70,
+                0x8b4f91ed19ef3db8,
+                0x0cc7372ada105e43,
+                0xb355137701692453,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x5f4a5676ceb8b745,
+                0x06ed21ea63cbf3c8,
+                0x325b269c9ac396f1,
+                0x97e14efe0be460c7,
+            ])),
+            Felt::new(BigInteger256([
+                0xc269a3b75b0e87e6,
+                0x0de6fec4dc8a8008,
+                0x5a3aa6acf7906ce9,
+                0x03e69baaeec7c07a,
+            ])),
+            Felt::new(BigInteger256([
+                0xbbdfc3bb7a857a1a,
+                0xf5eb5aeb2d82273c,
+                0xeffbf4dc5d677dd4,
+                0xc9e08ab851f10388,
+            ])),
+            Felt::new(BigInteger256([
+                0xc4324e6e45234623,
+                0x51a18ae65e63e47a,
+                0x7dbebe2c3021be75,
+                0x85b4933ec69d30f1,
+            ])),
+            Felt::new(BigInteger256([
+                0xe30064946bf0be2e,
+                0x5326d346771ab1ff,
+                0xb1fd215df93e2823,
+                0xd495947b5d7307df,
+            ])),
+            Felt::new(BigInteger256([
+                0x70fd067ebe8952cd,
+                0xd1fd0a64d647305e,
+                0x12d00e39bf8840b2,
+                0xb0518eaff45e8d71,
+            ])),
+            Felt::new(BigInteger256([
+                0xf1cc9c9f64fa5bb3,
+                0x939f824de5e0fc54,
+                0x11829a7e14453705,
+                0xa918d015f931e79d,
+            ])),
+            Felt::new(BigInteger256([
+                0x72bed1f0aeb80493,
+                0xd97be9b5ba308510,
+                0xc89dd852a0983739,
+                0x6ca098bce21a2c50,
+            ])),
+            Felt::new(BigInteger256([
+                0xde61f93c84f7a9b7,
+                0x06bcc8e329723c28,
+                0x9b5288775966e18d,
+                0x5a31013c65cc0d8f,
+            ])),
+            Felt::new(BigInteger256([
+                0x8200914b331cf5d4,
+                0x12326310580127cd,
+                0x37128cefd57c950e,
+                0xade4ef21bab3fa06,
+            ])),
+            Felt::new(BigInteger256([
+                0x33951aa2c51dadc1,
+                0xd9b91424c2433d3d,
+                0x7e5fe98ea259d275,
+                0xa45be4a35c0c96f4,
+            ])),
+            Felt::new(BigInteger256([
+                0x51a5c41c4daf6ac0,
+                0xae78596eb9c1cae6,
+                0xd0392ea55d8a6de6,
+                0xdb01dcaa2f092f05,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x339aa53d752592ae,
+                0x534396c7f6b84611,
+                0x0c12dafd7a02f215,
+                0xadca4fe3218abc46,
+            ])),
+            Felt::new(BigInteger256([
+                0x33d4eb6b32a11f36,
+                0x6a1ad81b3ac1651b,
+                0x8799f5b142fb5c77,
+                0x3278ed969da6932c,
+            ])),
+            Felt::new(BigInteger256([
+                0x1b6ce3f26f5a9c12,
+                0x917a6ce4a6aa7913,
+                0x4e7b415f839ac1e9,
+                0x587bea728fd1d4ae,
+            ])),
+            Felt::new(BigInteger256([
+                0x7534b58ebd3cac59,
+                0xc920cb3b89b5fcbe,
+                0xdb02eead98c1ad78,
+                0x6e0c04f9d0d23cd8,
+            ])),
+            Felt::new(BigInteger256([
+                0x14d47cd83169f665,
+                0xb13642d01ac9ba37,
+                0xc1199a278ad1c95b,
+                0x50b82f87d12a36e9,
+            ])),
+            Felt::new(BigInteger256([
+                0xdb78e2bf0f1efd01,
+                0x465726ba71c36fbe,
+                0xf6b28c2bd7eb778c,
+                0xad3693344c130080,
+            ])),
+            Felt::new(BigInteger256([
+                0xf4496ca2c9e05bb7,
+                0xe7afb15dce6af2e2,
+                0x3718c061052b8a75,
+                0x13ffc01fddf9a178,
+            ])),
+            Felt::new(BigInteger256([
+                0x7cd3705f68f0d682,
+                0xcfc52528f42c9217,
+                0x92a1316e07a0356d,
+                0x3d779d1f67bc6dc0,
+            ])),
+            Felt::new(BigInteger256([
+                0xe58ed9a9a59a7e6a,
+                0x10af8e9eb7099513,
+                0x6c3e1cf26f40a4eb,
+                0x16b844d1a1135ee6,
+            ])),
+            Felt::new(BigInteger256([
+                0xd158ab800acca758,
+                0x8eeee9e944cb67a7,
+                0xb6553f63e602f6b5,
+                0x5b20bab5ddd0e0c2,
+            ])),
+            Felt::new(BigInteger256([
+                0xede2078554d22b73,
+                0x8c62f73c0bfb531c,
+                0x735518aceccc85fa,
+                0x1554c24729955909,
+            ])),
+            Felt::new(BigInteger256([
+                0xe4c241aed7770ceb,
+                0x1c92a14e05637837,
+                0x1fcdc8a51e5a857c,
+                0xb1f7dfe1e1fb3874,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x563ed2ed08524110,
+                0x8d5a1d571208cdd1,
+                0x12156db3d80736c9,
+                0x88a143f79b0ab4f3,
+            ])),
+            Felt::new(BigInteger256([
+                0xf99658d8dc0c24d2,
+                0x37c5273020988bd5,
+                0xfc2751f6675eaca0,
+                0x4dea674726714dc9,
+            ])),
+            Felt::new(BigInteger256([
+                0xf018d29f55e3b1c2,
+                0xcff2d0d128b65ce4,
+                0xe87c482e60aeabd7,
+                0xe3ecf7267a1f7023,
+            ])),
+            Felt::new(BigInteger256([
+                0xe9b020214fe4cb1e,
+                0xfe611edca099bfff,
+                0xa10fd5726fad2225,
+                0xcc9a7619ff7237d5,
+            ])),
+            Felt::new(BigInteger256([
+                0x3ef4dee4ba64c0e1,
+                0x38888d0f4359b974,
+                0x49aeb457205a623a,
+                0xaf610c57f0b9adba,
+            ])),
+            Felt::new(BigInteger256([
+                0x74b6625dc844bd4d,
+                0xa63e2c7431f9e14a,
+                0xa1cd6390addb913e,
+                0x4062b7bc0ed9204e,
+            ])),
+            Felt::new(BigInteger256([
+                0xdcc6b534e001b7ba,
+                0xab07b4d06f1181f3,
+                0xa455aafaf903da06,
+                0x74caf7eab2bd55fd,
+            ])),
+            Felt::new(BigInteger256([
+                0xe162b4913e2a3bb7,
+                0x48bcb56b65e7f4e6,
+                0x1a7fb07aefe5cb63,
+                0x967de93226815f1d,
+            ])),
+            Felt::new(BigInteger256([
+                0xb5462f65d6c2f2e8,
+                0x5f95e255504b0204,
+                0x0f23f949631aca00,
+                0x782e492c13e1a646,
+            ])),
+            Felt::new(BigInteger256([
+                0x689271950b6f1dd5,
+                0x9b0c502f1d606e0f,
+                0x76e4a21c7323adee,
+                0x3710bcaf04f0f9e4,
+            ])),
+            Felt::new(BigInteger256([
+                0x357009772c8f51d3,
+                0x9323588992255534,
+                0x822f0f450def01ed,
+                0xd0768802c3664b27,
+            ])),
+            Felt::new(BigInteger256([
+                0xe6906480c5af1d87,
+                0xc8a042bcac7f047b,
+                0xea06da06947b3ea6,
+                0xc6015b5f425c33ff,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x4ff9fafd21d8baef,
+                0x49e525b0f699ed01,
+                0x5177d4c4a422a459,
+                0x5916b666bc13580c,
+            ])),
+            Felt::new(BigInteger256([
+                0x0fa8c61e7aca0f72,
+                0xa4e540cad45c97c3,
+                0xf22fbd8257cadb81,
+                0xb36031cfe97c71a2,
+            ])),
+            Felt::new(BigInteger256([
+                0x47912beea181e6ef,
+                0xce2cef944aa7a32d,
+                0x368140b6691c5350,
+                0x4ee614acf608bb29,
+            ])),
+            Felt::new(BigInteger256([
+                0x37a7d84354606ff1,
+                0x3e5f3e6ae1fe9d87,
+                0xd9fbe74900d82efd,
+                0x2d89839dcbad9e07,
+            ])),
+            Felt::new(BigInteger256([
+                0x8b745f64011da35f,
+                0x04fa7f6a95d02bba,
+                0x37e2194ea264542c,
+                0xab7f5bc859ffdaa4,
+            ])),
+            Felt::new(BigInteger256([
+                0xd8934338408dacac,
+                0x4a7d6b36010df8a5,
+                0x89b76b8cc9b38522,
+                0xb0d5ad812d793262,
+            ])),
+            Felt::new(BigInteger256([
+                0xc78c0dba0a80f45c,
+                0xf75c6024b5dec364,
+                0x577d7ee598e4ffed,
+                0xbbd23a3a22368402,
+            ])),
+            Felt::new(BigInteger256([
+                0xf9a07365c4b80bda,
+                0x6e7f49f6040be0e6,
+                0xe4f16fcad00594f5,
+                0x7fdb7e3bf5610ea5,
+            ])),
+            Felt::new(BigInteger256([
+                0xd8378fba9c01c096,
+                0x676ce17e7d7daeba,
+                0x04a71898948dafd1,
+                0xd47155f1273ca675,
+            ])),
+            Felt::new(BigInteger256([
+                0x763ec696922985da,
+                0xf4e9c7d021e5fbef,
+                0x3e0bbc95a40a4738,
+                0xe4903f1c12bdc0d4,
+            ])),
+            Felt::new(BigInteger256([
+                0x7642604f2d501a82,
+                0xd0fea577391c8704,
+                0x9fed8af401bbd952,
+                0x53b9a591ce4c110c,
+            ])),
+            Felt::new(BigInteger256([
+                0x1ee5bfe7f1ba2aa0,
+                0xfc5a0b375847b89c,
+                0xd60271354b8e2cc4,
+                0x0593a58177bc31c9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xbe09371d4e47aa10,
+                0x10a5b2ffddfd56d1,
+                0xf8ccfb9a9e65737b,
+                0xc453ab4305e6a15a,
+            ])),
+            Felt::new(BigInteger256([
+                0x36bf8a55e80dddb0,
+                0xdb575846ee72431b,
+                0xee1004d08e162bfe,
+                0xaaf6053b9d79214e,
+            ])),
+            Felt::new(BigInteger256([
+                0xb5cc49fad77667fe,
+                0x549f98cea64f3b66,
+                0x96654148c321c865,
+                0xd8b6a4bd3dbbc212,
+            ])),
+            Felt::new(BigInteger256([
+                0x08252ad11a042b99,
+                0x0ea9eb44676758d0,
+                0xad8d1fc3238b5768,
+                0xf5ee7dd084f4f1ef,
+            ])),
+            Felt::new(BigInteger256([
+                0x14ddc3892aa4583d,
+                0x7d257ded377097cb,
+                0xe9fc0066a7bfd639,
+                0x81d850fda71c1c65,
+            ])),
+            Felt::new(BigInteger256([
+                0xa5282acb5714b285,
+                0xfd8aca08bbae8a19,
+                0xc7da65f58191e06e,
+                0x7b332889753843ab,
+            ])),
+            Felt::new(BigInteger256([
+                0xf393211988c4a85c,
+                0xa31ec569919e8014,
+                0x9c336fd003bc2eeb,
+                0x30c00898954d0cfb,
+            ])),
+            Felt::new(BigInteger256([
+                0xb21238af926ded8b,
+                0x20fce832de69020b,
+                0x8cbc0c04ff6883b7,
+                0x9366ef4a125d4ddf,
+            ])),
+            Felt::new(BigInteger256([
+                0xfa37ee33679b9eae,
+                0x6019aacf43b36043,
+                0x7b720cf46ba2b83a,
+                0xbfb5182a45db598c,
+            ])),
+            Felt::new(BigInteger256([
+                0x2a4904ce63089bee,
+                0x9b8611ca822ce6ee,
+                0x6fbe67e50a5f9aa6,
+                0x0ebe1af2f9446d81,
+            ])),
+            Felt::new(BigInteger256([
+                0x7847af15f518cda4,
+                0x8093f4e2a5807af5,
+                0x33973bad8b05cb06,
+                0xf4f9ef2d433d09ad,
+            ])),
+            Felt::new(BigInteger256([
+                0x5e22bf3e683f4bb5,
+                0x274e9c652d73cc72,
+                0x447ab2ca7f8c796c,
+                0xd3ff0f96b9d14920,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xaa2a03ef04a8ff51,
+                0xd0abc00eef2fda77,
+                0x28b4d30232435779,
+                0xec28b16f415a9ab1,
+            ])),
+            Felt::new(BigInteger256([
+                0x5ce9fd91e565db56,
+                0x01db72fca16806c2,
+                0x0e58dbe993634b2d,
+                0x86cc9d80f83e3871,
+            ])),
+            Felt::new(BigInteger256([
+                0x7e1fae27ab3f05b7,
+                0x176709e365b652be,
+                0xa94893a301054d05,
+                0x2d6ce181d58754c0,
+            ])),
+            Felt::new(BigInteger256([
+                0x8750034cc8028734,
+                0x838353572d8c8493,
+                0x94c76d7b5f35002a,
+                0xeeb682dbe6d0820f,
+            ])),
+            Felt::new(BigInteger256([
+                0x245d4ee38f509de7,
+                0x05c93b976d150573,
+                0x75979f58ec29d728,
+                0x7d54d86e73c73af6,
+            ])),
+            Felt::new(BigInteger256([
+                0x7fffb1cb220783b5,
+                0xa3a8d3ace645fe7d,
+                0x61e45c3ed094bc76,
+                0x63f87b2f50208f4f,
+            ])),
+            Felt::new(BigInteger256([
+                0xed46019cc8f78d7e,
+                0xc672b748d8603ff9,
+                0x8c531e2b6596d959,
+                0x999088f65e24733a,
+            ])),
+            Felt::new(BigInteger256([
+                0x7ea8f8b76b7df26e,
+                0x985fbcff0dd4cdc4,
+                0xab51657ea279978e,
+                0xfaa9739b88d0bc4e,
+            ])),
+            Felt::new(BigInteger256([
+                0x3b7159e1abbe6a4f,
+                0x202df1942c51ac58,
+                0x0d30ae8689ff028c,
+                0xabf75e2082938d0a,
+            ])),
+            Felt::new(BigInteger256([
+                0x7dea323f7ce50283,
+                0x18fdb4bcd8f5a1fa,
+                0xcd5f85986c37f319,
+                0x272ab1be1d5fdc92,
+            ])),
+            Felt::new(BigInteger256([
+                0x4fb41b3ec5a2bf0a,
+                0x3159ff7252d730af,
+                0xd81cc15830615205,
+                0x8db49c235b9245aa,
+            ])),
+            Felt::new(BigInteger256([
+                0x8814ee47708079d0,
+                0x2e5607c886f69055,
+                0x1521ea399f22ed34,
+                0x2c9651a9fa450939,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x4b46816f2646cacb,
+                0xee51ebd1b8488f4a,
+                0x161f473aef403046,
+                0x4ee5aafe30766362,
+            ])),
+            Felt::new(BigInteger256([
+                0xb5ea33c3236da39d,
+                0xf958d1676de87d34,
+                0xa92a5d9ae1f1cebe,
+                0xb05f3149e85cf07f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x0aac95efa2dc46e2,
+                0x991f13e3e86bd377,
+                0x5275abedfd65d2ac,
+                0xe12ffe41a1c4349d,
+            ])),
+            Felt::new(BigInteger256([
+                0x29f948a8a634e6e3,
+                0x72167d03f6bbfb15,
+                0xdb7e52fbf5b6847c,
+                0x1363a42ddfd6c1d3,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x9f6da2ba583d9eeb,
+                0x78aa3288e09c0c6f,
+                0xa5268776fd30a858,
+                0x808ec3d92326373c,
+            ])),
+            Felt::new(BigInteger256([
+                0xda4d924c4202b326,
+                0xec0ecafa8d3fc282,
+                0xafcad7d7ccd3d229,
+                0xcec8760d8698c91c,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x9f4b6caf30b4de7a,
+                0x877f39bfc33be3e9,
+                0x16ab4536c1707f81,
+                0x0ace53c433e0536b,
+            ])),
+            Felt::new(BigInteger256([
+                0x91f1ce3e611b3f15,
+                0x53391d982361238e,
+                0x3bdb4fe8c8cc72d2,
+                0x225f69f3ddeba734,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x28b35283f18cfc13,
+                0xbef5b54701b3d45f,
+                0x09691b7011b4cab1,
+                0x9a230ea34551602d,
+            ])),
+            Felt::new(BigInteger256([
+                0x15c0c401d933d2fa,
+                0x9fa057417f75ab1a,
+                0x52a1d2a221d61008,
+                0x399978b6308a444e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xaf3c0fd61b5ea03f,
+                0x9a8eccc831c3268e,
+                0xabde01b46d051191,
+                0x3bb3913aea948158,
+            ])),
+            Felt::new(BigInteger256([
+                0x7f2d186cbb82c2eb,
+                0x3c3f0023cff0de27,
+                0xe36908008adb071a,
+                0x0a5d78ef7c953e90,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x85e9fe82f957e452,
+                0x45a60999c60a3652,
+                0x6b5393f19a938733,
+                0x97c6ee46d4107820,
+            ])),
+            Felt::new(BigInteger256([
+                0x55ab8af73bd98d47,
+                0xb66e4d5a9d0acc53,
+                0x47f4b1903be8711b,
+                0x25d0bee93ec8bb95,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x8593819e611c1228,
+                0xf1ec80e8e81b16e0,
+                0xcdafd8657bfd89a0,
+                0xd0811c39c1c7812f,
+            ])),
+            Felt::new(BigInteger256([
+                0x8fade0a3e3cf148a,
+                0x6a562793c75c21db,
+                0xf2beafdd74684ab9,
+                0x0db232960b1d57b9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x0f0f9c0c23690870,
+                0xff4454bda732d5ee,
+                0xb4a1b21168c548cf,
+                0xd99cad1def8fdd88,
+            ])),
+            Felt::new(BigInteger256([
+                0x56bd67977cb13a1d,
+                0x2c6ca2c7a702c404,
+                0x197d658ef599d317,
+                0xb674763fbc805aca,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x1e87cedb674ed3d4,
+                0x3c02d65a297a3afc,
+                0x39780108bb8b8cd2,
+                0x49deb83fdfa9dd28,
+            ])),
+            Felt::new(BigInteger256([
+                0xa201c378f903fc4b,
+                0x51a99be42df5c16d,
+                0xd431064d779d0202,
+                0x8122f73fe7aed6cc,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger256([
+                0x03b6c2fa7735feb5,
+                0x46fef0676fa305b5,
+                0xf1f8e3658ccb9d4f,
+                0x3c8ce14c8f63095e,
+            ])),
+            Felt::new(BigInteger256([
+                0x7ceb19d28bc53250,
+                0x98868595748eb969,
+                0xdf2db9d57a910675,
+                0x234d58d91f22bfd7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe877a475636edcbe,
+                0x3cdb7016a6bc524f,
+                0x04392256de87f1fe,
+                0x35516544c836af75,
+            ])),
+            Felt::new(BigInteger256([
+                0x37661152a79c0a04,
+                0xdc025b6515fe70f4,
+                0xce638f5215396933,
+                0xab783eb82edd8c25,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x380a2b36f268b691,
+                0x3a334d137b7b5a62,
+                0x84e963578a4cefad,
+                0xb870f1adaa0ce0f4,
+            ])),
+            Felt::new(BigInteger256([
+                0x72ac745a5199311e,
+                0x5754e30e0ed45084,
+                0xdc01fb013404989e,
+                0x2e97dd7dc719e8b8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x879c3544bad71a0a,
+                0xa6814d4919786d11,
+                0x505bcb5b35368ef0,
+                0xb98f1af4211eec2d,
+            ])),
+            Felt::new(BigInteger256([
+                0x37c35601967f07e2,
+                0x9540f26ad9cc2d2e,
+                0x92c2023b6a40e9d5,
+                0x9d29b31c6bd6e073,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x1153caf4cee10496,
+            0x9912e0e0199e8313,
+            0xd5c74044b4810107,
+            0xcc1af44babbba3a3,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7d01a99f7764cbcb,
+            0x173345e1a42903ac,
+            0xa42acc82118cba50,
+            0x52bad68d32198be0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbd62ed0838164209,
+            0xf5ed356e1f3f64a5,
+            0x3193e9c16af198a0,
+            0x6302e725d08000ce,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb705e64e60c789cb,
+            0x39af612fef6d2928,
+            0x98d9746147b870a1,
+            0x386c5f4961313d01,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc9ab29aef7ee8f45,
+            0x971b6e35c7c0c543,
+            0x0e82b23f9cbdc5b6,
+            0x6e65e39895d358f3,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x1682d5e56a7e4dc0,
+            0x248ffc73e0ac86be,
+            0x5d9a5ddfcdfc0815,
+            0x379c7473c9ff3463,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe9d976387420b794,
+            0xabdb3504b96e7ab6,
+            0x0dca79b706611f18,
+            0x52bcd956ba7d47e3,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x83be7f9f29dfc820,
+            0xefc8ec2ed61e4152,
+            0x62cc95c43ce0db7b,
+            0x71345619eeaa2ef1,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,230 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000300000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000400000f44,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000500001315,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000006000016e6,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000006000016e6,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000300000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000400000f44,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000500001315,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000500001315,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000006000016e6,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000300000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000400000f44,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000400000f44,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000500001315,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000006000016e6,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000300000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000300000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000400000f44,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000500001315,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000006000016e6,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000300000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000400000f44,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x0000000500001315,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000006000016e6,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+];
@@ -0,0 +1,2441 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x25968670bfadc47f,
+                    0x64468980369fd875,
+                    0xa6facffbf84e76e5,
+                    0x4d73c83fa5c40594,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d3bee8af0b1f63f,
+                    0x6a73f89da167a2eb,
+                    0x83d5b18892b6ee07,
+                    0x6b36ae9288ca8e9f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x491d27b03d29137c,
+                    0xc353787c2ea4d8ac,
+                    0x901b096fc309b37f,
+                    0x21595ce7823adc2f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x911fe9dc0ce2bb01,
+                    0xfcfae0fc14c7ae85,
+                    0xccf3c2dbaefed997,
+                    0xa1875b38d900175d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc44085b78efea2e7,
+                    0xff3e2ba2cc7827ce,
+                    0xd6cc0cd186a0dfd0,
+                    0x7b56a8da82a5ff80,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe6f9b67709c2c886,
+                    0xe390abdb67cc300c,
+                    0x3a9b2bf891d7188b,
+                    0x556546f35e3d85b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd5e72974773c40e1,
+                    0x41b91f8eea8754fe,
+                    0x729c26759e6f3a0c,
+                    0x6e59f4566182ca17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe501b728174f8e8d,
+                    0xf20fc6df1e4789d3,
+                    0x6ba554ffe1690f94,
+                    0x2dd9fd34c252e9d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb868f8e77b186421,
+                    0x256cddb4cecd6a9a,
+                    0xa1c7b66ade1675ac,
+                    0x017a19ff90c0b784,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7ada99903540977c,
+                    0x1b8b464680b13f95,
+                    0x1017f07c22923b2b,
+                    0xff3868243400a044,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e914fdd248eabb6,
+                    0xfc6702ed5381e05b,
+                    0x31c4a47046031b62,
+                    0x256b19e6a4c1cb68,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6a6cdd151c13836,
+                    0xd08b9d7d2fab4658,
+                    0x1772917b5ac559aa,
+                    0x1c51500c366560b6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x587fba67f3e97dde,
+                    0xc00c34b69f43cdc1,
+                    0x6c304e15a8c693e1,
+                    0xd19d8daf8538f551,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d12c80d736de405,
+                    0x0d6d4a29bc701235,
+                    0x847c4c7490650fdd,
+                    0x2e4533738c9240f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x32675db70493c0a4,
+                    0x2f8092b12f6324ff,
+                    0x0ed9ba1eeb44a893,
+                    0x680af0dc1d13209a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd5521665a0d62e8,
+                    0xd6c28cc9019a5645,
+                    0x3c611410c073d0a8,
+                    0x1fe949a58f65585e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5311f35c41ccef47,
+                    0x13d82c3f1f06e69f,
+                    0x84ad988ec66b3b11,
+                    0x8dcb7e76f53ba811,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa3df3ba864e12987,
+                    0x0fbaaf27b52bc45c,
+                    0xd8b223806605acd3,
+                    0x4f8c5ad833f0397a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3eb935fb35e92ed8,
+                    0x612cf262589c841a,
+                    0x9f724607ed5ce928,
+                    0x951c37ab1e6090b3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2df0142913d33d78,
+                    0xb45abcdae72a72b6,
+                    0xbec16dedbdb6ae7b,
+                    0xe5215fc9318f9cfd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa24096257782e042,
+                    0xe81c602fde3c58e4,
+                    0xf18a519b9e41d52b,
+                    0x231407193c64e91b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeddbb65c286e19ba,
+                    0xd3f5983922eab47f,
+                    0x7ff15aff58359179,
+                    0xb5197774ac1d7210,
+                ])),
+                Felt::new(BigInteger256([
+                    0x79af0b666563b280,
+                    0x4e7f562c624b8ce9,
+                    0x293f6c2e5338028d,
+                    0x168fb538c94a97b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d7bab0fced87389,
+                    0x085c65a6cc1821d9,
+                    0xb5fea4f7e0c833c6,
+                    0x4a867eff3d809779,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb95ec0b0d53fb8ea,
+                    0xcb437b941deaf7de,
+                    0x03c3921960b8a25c,
+                    0x21aa1745d5f3167f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfba80050e5e906ac,
+                    0xed70b817944ecb2b,
+                    0x4a2d53da3b59d2bb,
+                    0x9ea06f81c381e33b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb963140fbed0b639,
+                    0xa85548dd766aa721,
+                    0x7a8cf77356dba20f,
+                    0xf703ea40217a7e27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90a7688acf15f366,
+                    0x78a6ce9fcb4f31b3,
+                    0x4e68d7e8cf7dae9e,
+                    0xed64d9f58bbf37ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0x180f86ef34033053,
+                    0x42b8aeb24b83519b,
+                    0x98cb6658b5e4ecf4,
+                    0xd344e4e2f98628d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e918fdd26c3f482,
+                    0xbdac02f042a66c65,
+                    0xc42ec233a45ffbfc,
+                    0xaf752447c0c4f3f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1d1c6089615d021,
+                    0x237a7782de55112b,
+                    0x13134ff6040081c7,
+                    0x1596cf71b869712e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4529b1c8e3e0cec6,
+                    0x96d1715e6ea0aee8,
+                    0xa713d67a063ef9aa,
+                    0xfae17ad66cd7b8c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe9d9396940abc48a,
+                    0xdf372ebe797af5d2,
+                    0xdcf326067fa7c5a6,
+                    0xcb4347c36c3ce770,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed2d5d4b7d09c0b5,
+                    0x7aad30126bfc2d40,
+                    0x8a4ad76bba745ea7,
+                    0xb24b72d90176cbce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe3b0c6d186db5b30,
+                    0x0e8a2be65b9937cb,
+                    0xf95e381c6d1b71cf,
+                    0x16c420d028288f58,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd4d6a071a4a532b,
+                    0x95fd75354a78a24a,
+                    0x16769228007d3126,
+                    0x4d100f9511aee828,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd7c366ad7909b6b6,
+                    0xe527953a9e91e614,
+                    0x0b536e6d2ea59d58,
+                    0xfd4fb8dcd5028fa0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x107749ee60a36434,
+                    0x2427baeaa6e7db6d,
+                    0x2e9766da82e4f085,
+                    0x275bf26ddb709fc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x578c8e6b31324340,
+                    0x5f0b9ae011396f48,
+                    0xf01d3704311d2f75,
+                    0x43989212f8c33f27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9803865f0326642c,
+                    0xe52f620ae64c3d8d,
+                    0xf75fd76bc837ffa3,
+                    0x6e5aa20daf92896a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x427fa68a0da05f4b,
+                    0x50543c8f1d156a72,
+                    0x07e7b63fb48d5344,
+                    0x33835f04155da1e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd4e88e28af25dc7e,
+                    0x28efd8f260b3a564,
+                    0x6c7bc7e83dd03d2d,
+                    0x6b3b444a33dba981,
+                ])),
+                Felt::new(BigInteger256([
+                    0x07b608bf6016f903,
+                    0x408cae0426cfe1bd,
+                    0x84bc188720addaf9,
+                    0xc9903ddb176c5550,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf5c13d495836f4f0,
+                    0x75c1f172ff545094,
+                    0xbfb08f4790ebf501,
+                    0xbae40b23aad6726c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec73e97430d6847c,
+                    0x2d6e30db7e2bd5c5,
+                    0xab9b164fdbe256f6,
+                    0xdef1663461290152,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1db20cfba8c1c42a,
+                    0x47e2d0be3091c25a,
+                    0xe3fab0c4a6bad5e8,
+                    0x20f6ba0b6c9e1c05,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37319ab8db7acfaa,
+                    0x9e2e0eb6b1a3c6a7,
+                    0x561f28a74d873be6,
+                    0x31ff8b360b13e92f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e7dd51a9e4d4398,
+                    0x88823cb84a767fa8,
+                    0x2c85d2cc448faef1,
+                    0x3bf919618191aa88,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x33d02a9c79986425,
+                    0x59be5ac365269ab5,
+                    0x8af86516ee142a93,
+                    0x0fa08fd6e938166c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6a2eeab7aa21d35,
+                    0x58e1b7e861f25956,
+                    0xf25ed41409acead6,
+                    0xd604d3e4e340d0be,
+                ])),
+                Felt::new(BigInteger256([
+                    0x272b9ba6a7586386,
+                    0x2373184abf2cab34,
+                    0xd8419917193a74ec,
+                    0x0f4843e2170ec74d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01e090cecd3acb07,
+                    0x55f378d51dcaefb5,
+                    0x0ef3a75026eb2370,
+                    0xfe26b80b747ceac9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3bc9f87366a38955,
+                    0x67a429c7b5f72fed,
+                    0x6280e8a441fe63a6,
+                    0x24b9a9f32e75542a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e80436b9281d5f5,
+                    0x49012f07252f29f0,
+                    0x86c1e9e5cf0f504b,
+                    0xd3d7838d356a083f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d5f4b62d4d7797f,
+                    0x1c58dcdc865bc679,
+                    0x113ab06707b75061,
+                    0xdeb1bc78423df781,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37618ad8b7161a7a,
+                    0xf70249bc89951bd8,
+                    0x34488265fc7e9806,
+                    0x748f9483f0ad8381,
+                ])),
+                Felt::new(BigInteger256([
+                    0xddc16a3c4186333e,
+                    0x50ca6b5f49ed26c5,
+                    0xf6a7cec9d1750c22,
+                    0x73b318c22148803d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0de955427e2e8f74,
+                    0xfc264412677b559b,
+                    0xc8139cdd19723de6,
+                    0x85678cdb53f7fe00,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72c8295f5f962ba0,
+                    0xa2140ce3ec713372,
+                    0x46ea9126b1af1304,
+                    0xb175dcc52d33207c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe92948f3f7e3645f,
+                    0x0a09cf21155f630b,
+                    0xd818ec8409bec8fe,
+                    0x75a5e0f5ad9bc63a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8119af57429c74c2,
+                    0xb4ed7fb59f648c35,
+                    0x939c603a42e041a8,
+                    0x3de6aeaae1f9c065,
+                ])),
+                Felt::new(BigInteger256([
+                    0x040b7229cd52a4f7,
+                    0xaa4978815f691430,
+                    0x0661c84f8addea4f,
+                    0x800b21d1a061398e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcd74788256462687,
+                    0x2197f1cc8f4f5e79,
+                    0xddf368c349fc0ab4,
+                    0xfa5242c6ee988642,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb436d98ae32dbcbf,
+                    0xe648c11a68ea60bc,
+                    0xaf697bd84269ce07,
+                    0x7bcf20437ad81f47,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa952ffc3518c18ca,
+                    0xa9b93e1272719a61,
+                    0xffd1561a87c49671,
+                    0x633f21b1641988af,
+                ])),
+                Felt::new(BigInteger256([
+                    0x46b5ec4fcd48a65b,
+                    0x801f4093431d983e,
+                    0xd116bf21e894c16f,
+                    0x92d82fc7bf29d3f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e352d687f579e6f,
+                    0x1af1b21b1ecd1999,
+                    0x6f0694a1dc9a5f21,
+                    0xdd0bd64c7afc5ce9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c99f691d72fe0c4,
+                    0x0660a74c40484ebe,
+                    0xeac6441afacac14e,
+                    0x7032264668193569,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a04d18f66e0012b,
+                    0x6448e3fb3177467e,
+                    0x311ad0fb0e199350,
+                    0xe91007c7941f463f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa39e0e69fd561b9,
+                    0x26bbe9dc79270b36,
+                    0x84435c42feb9b4d9,
+                    0xbd552feec6a59d93,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3beadd4ebeee0742,
+                    0x1cb8dc7bc031e845,
+                    0x4821efd74856d423,
+                    0x92c9ed15ab1d4af8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x134451843e20bba1,
+                    0xe9efdd9d1a338ee0,
+                    0x3535888c9fa0ade0,
+                    0xc9e47928a83af707,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa060e458a9eaa612,
+                    0x03e74e36717f8284,
+                    0xa9506aea4a4bc6f0,
+                    0xf0107b3c0aa1bd01,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c51f4d79a5b05b2,
+                    0x6f18351a0c65b366,
+                    0x8c818ac17a282409,
+                    0xb1bc49d8a8f50347,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4dce5518bee2542d,
+                    0x387ed777893dbb10,
+                    0xc6b3b7fbe52b1f96,
+                    0x8d417282e2475a9e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe754185013bb5c1,
+                    0x61763e7586dbd304,
+                    0x9048e0b01f432dfa,
+                    0x17a86397f4136366,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b27350fe2229680,
+                    0x7a6f14398d17dcfe,
+                    0x53722c55f104987f,
+                    0x205e8b9fc43d8a44,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99076be33559fd5b,
+                    0xac7a0acf2e64517f,
+                    0x89f368c21e657f86,
+                    0x2bec75acd9e6cb4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2e586923b841b61,
+                    0x5795b259ea54c990,
+                    0x3878f54283066257,
+                    0x9e3f7a5d10ac5c55,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a94bc82a4ef232d,
+                    0x6757cd01dbb272b1,
+                    0xb3cdd8ca33ffc971,
+                    0x93507fb36c8b64f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97f4fc7550997abd,
+                    0x3e7238c4476395c2,
+                    0x5ffcef77c945c7f2,
+                    0x5f40c352cc518df6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcddd5c133ffbc87f,
+                    0x319c223b4d516f3b,
+                    0x4afa4072ed533b38,
+                    0xa2be4058ae4e8afc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b7e1c8e78be7454,
+                    0x5144a726f595f28c,
+                    0xe4ea1ac6a9277870,
+                    0xd1a26fbb5662b58c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf77149871012d069,
+                    0x67e00e07c11c82a2,
+                    0x1f30d6b1cf6940cc,
+                    0x6fe295d17873c132,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x87bc8522afd98586,
+                    0x85fda086759040c5,
+                    0x758c37e9b5298a7f,
+                    0x9d36db12faa3dea3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13bff97e4b5466d9,
+                    0xf5677aa985af6f15,
+                    0x11017ba9cfaddb7e,
+                    0xa314ba48567b13e7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb9d7f7b668a76290,
+                    0xdc153032fca77dc2,
+                    0x09950ff4aa385fc5,
+                    0x0ef80c50d492a3d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa4bc55a88393bd3e,
+                    0x52705d6e1f5b1049,
+                    0xa9e0f5518e36d084,
+                    0x1715f1097e7df278,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3017a38845c82839,
+                    0x5c37994b74f4abbe,
+                    0x81057e6d3b0cce49,
+                    0xf14b0a5c2aeb898d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe6d2fdd2b7282b66,
+                    0xd54a6f14349051fc,
+                    0xd5c037d3df76cf7f,
+                    0x09fd6a36969db4d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7bb17a82c7772342,
+                    0xee9579da30680ca9,
+                    0x48e3866ed8e960dc,
+                    0x2d4da30e4979e5bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5067aec2fd4d2c97,
+                    0xb94a85498883878e,
+                    0xe9b164c428bff1d5,
+                    0x0857f0bac93c21da,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24f9b6cec0f74991,
+                    0x0c6c35a3fd68597a,
+                    0x31ed56b5dd99191d,
+                    0xc70d9f26334dbe4b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x166156a1958a8342,
+                    0x194208ba86f6e442,
+                    0xe2aa4fed4c6b95ed,
+                    0xec03a615e224f8d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f567fdda9f708d3,
+                    0xd520323893b459cd,
+                    0x3be37b82122aefd2,
+                    0x8f6072c1cc1224bf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26ffe5407d4867e7,
+                    0xb6d441b23e7e493d,
+                    0xa5fcc6f132aa0e27,
+                    0xe865910e31b80bc5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x54fe0a6c225e0690,
+                    0x49198c55598d5afb,
+                    0xd10ba9e75a935715,
+                    0xeed42b5500b53295,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3644144a59f674be,
+                    0x5095ea40c3874a10,
+                    0xe3ac83bb94ae9430,
+                    0x18587ca4a18fd648,
+                ])),
+                Felt::new(BigInteger256([
+                    0x27bbc09682e90cac,
+                    0xb56f6446688d56b4,
+                    0xbc3d45e625080a47,
+                    0x7544b996f92bf6f9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2850893f396ff5e9,
+                    0x60d72b672eb0d4f2,
+                    0xdbf2cde5264b7ee4,
+                    0x8da06ca3715dd438,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf65158cbc6b3d959,
+                    0xf49edfeedecc6b46,
+                    0xb5be366f070711ef,
+                    0x14b3277ebcf75724,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf1b38312dca4558d,
+                    0x6c0d441d925c0f44,
+                    0xa1fdf38c5de51d08,
+                    0xddb618587334b105,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa3a9522890290c11,
+                    0xb9ab4aa57676e707,
+                    0x476863220a3dd541,
+                    0x835f51f96196f34e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa636ecbe9c4804d0,
+                    0xc9620cb6f9de0484,
+                    0x787a1d8836e80175,
+                    0x9f64274e65a34c6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8dc05e45946d7ae6,
+                    0x679f69edd4c854a4,
+                    0x1d206269f08eb4df,
+                    0x7d8d19fde57dba10,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4db0f7aa365979dd,
+                    0xa18c9834bb36f65e,
+                    0x5e437caa804baa91,
+                    0x5b470fa8a1fa491a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x603858dad59f8474,
+                    0x9ccd9826a4b4f8c3,
+                    0x2dc412646f833001,
+                    0xa4ad4ffd408e6f16,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe494803800f331b1,
+                    0x42d318ecabb676b4,
+                    0xdfb496ce21134de3,
+                    0x04653e3ac60dd6b3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6965d6f00c2402e6,
+                    0x5267f61c4d779803,
+                    0x056bc312589a7392,
+                    0x902ee891f3a9b319,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1bf006e5d7af6ce8,
+                    0xf538f4a16d4fc0a1,
+                    0xb360cdd40d0ce818,
+                    0x13c3de9bfe73e022,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdbc8f3b1fb848547,
+                    0xc80af15a05ac752d,
+                    0x939239d3df9de29a,
+                    0x3e0261d600ce785e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc94e68840d7bf00e,
+                    0x716797247f18d173,
+                    0x45305fa6f14e096f,
+                    0xd6ed84d2c6b39022,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18118bef76f0d978,
+                    0xd324789ac97711fc,
+                    0xd8326af2831cbf48,
+                    0x72006121524d7b09,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc3dee25f3a69b519,
+                    0xf2b05d346618c978,
+                    0xf28327ea158426bb,
+                    0x59c8a8adbae364fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8f11d7a4527d220,
+                    0xf3fb862b70244e11,
+                    0x26dcec5b117f896c,
+                    0x2f6ea4c7dd003dd1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd39eafa6795e6c1,
+                    0x5fd20d4f495fd069,
+                    0x903a0d693a60671d,
+                    0xfaa609e7318a4679,
+                ])),
+                Felt::new(BigInteger256([
+                    0x78c69703f3cb11dd,
+                    0x7052caa685a69841,
+                    0xb9c3f292ad0d88ff,
+                    0x137d4759804464ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8e54d80f5fe3395b,
+                    0xf204ba52de771fed,
+                    0x85c8a27495f309ee,
+                    0x59ae3b4fe29c90d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e909e649f6d7fee,
+                    0x5c3e55599f24b3ed,
+                    0x683e8db9e0803b84,
+                    0xd0fbf3f4b1277173,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2a776c9e208e326,
+                    0xb5557bca6409f560,
+                    0x3fb9ea39ac0ebbd3,
+                    0x539e8f045d485a45,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf4304fa1182d418b,
+                    0x09d676065fcd1d73,
+                    0x8a57bd30d221bbcd,
+                    0xd464d2e5d177131d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x165dbee80bf7dded,
+                    0x18470da6279085b9,
+                    0xe6bc2cdc0c33884b,
+                    0x7945383b8c2d0298,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7df1d981a7dfab85,
+                    0xca6ed15d7fce0b9a,
+                    0x1616fc0e7404944b,
+                    0x61dfed5f14305fe9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc2558b6077ee2543,
+                    0xb58efffe9b23e681,
+                    0x9e286c74a177682c,
+                    0x5d2a0e7507563205,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9fb4ea64d5b3f6dd,
+                    0xd81078bfd3375215,
+                    0x05379fe6ef66b9c2,
+                    0x132e519d2ca2a267,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa4e0db33777ad7ae,
+                    0x15483c05ae2127aa,
+                    0xc4889bfa182d25f1,
+                    0xb68ac8bfc2a0775d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d4f29fadc083252,
+                    0x35e6960b62c3fa2a,
+                    0x9d24f19398206d68,
+                    0x41b6ba5e4fa8d3db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb4ad29cb59affa5,
+                    0xb190d863a5db6bb6,
+                    0x3e9a2bc54fda33e6,
+                    0x274548e7b02fff45,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c2b55deabbb6f01,
+                    0xa6ab2dbf92f2649d,
+                    0xfb005ea894483c08,
+                    0xec8f875ecdf2ced1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x137b806616f485b4,
+                    0x10e1784edf673a88,
+                    0x9413d168527b57b4,
+                    0x97ec9d3b8c54d881,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00e30c6a8ff51e3f,
+                    0x290eaf412478cb4a,
+                    0xb7c8469abe033a94,
+                    0x4f008366ef93bb64,
+                ])),
+                Felt::new(BigInteger256([
+                    0x62575475f0eeb37b,
+                    0x7b50afdf8928baf8,
+                    0x84b34359709520a0,
+                    0x3866167a8034f432,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2502714702aa351c,
+                    0xc03b029f3bb7c4a7,
+                    0xab6bd900e95da739,
+                    0x7ab05302aa674cd0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3ef980b5f35784ba,
+                    0x936ee392d265254f,
+                    0x986f988b8898564c,
+                    0x8af6e17ded04b156,
+                ])),
+                Felt::new(BigInteger256([
+                    0x195cb413d956b1d3,
+                    0xdc219e60472b9af8,
+                    0x1ac3b040e24c6a55,
+                    0x5e332cc7546a22e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3823af34b6717ac7,
+                    0x2ee02f7f6e9f7976,
+                    0xf10dd95c3bf4cdab,
+                    0x5cafc3beb70ecd90,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea9e4d343bc9d870,
+                    0xca3425ba6a6a29c6,
+                    0xe1477a661025e55a,
+                    0x4f0998c377265aeb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb3b45d8a0957e646,
+                    0x5562d63bca32440f,
+                    0x692f55f13c74eef3,
+                    0xd08d30c36cca55fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbb28afe481bf3e3a,
+                    0x508b031e43152081,
+                    0xb14ed86479afe973,
+                    0x1b4774617fb719cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x047cb903b5dd00eb,
+                    0x77421fe2194867b3,
+                    0x8856bd45d6a710c7,
+                    0x5b49ac1105b1717e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9778ea6e7da77f43,
+                    0xb263e182f44435d9,
+                    0xe0a7f4c6a8489e2f,
+                    0x973c6640149dcccf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3f1aca05be0d50bb,
+                    0x08bf278e180ad39f,
+                    0x246f1762991b2511,
+                    0x2a226cce847f352e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5a82d974e1757fb,
+                    0x268fb2bab829661a,
+                    0x22bb6fd287d4436f,
+                    0x58caf90b4f55bed5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf51878848c5d8457,
+                    0x2708167db64685b1,
+                    0x5c049c8d7105bbcf,
+                    0x9d3cd3fbeae44f83,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x35208ac967e9cafb,
+                    0x43a815ee539caf93,
+                    0xf396ac727c72c967,
+                    0x288691af59962b60,
+                ])),
+                Felt::new(BigInteger256([
+                    0x826d145fdbe3c8fe,
+                    0x29823fea91c21359,
+                    0x9514a1f1ea9dcd5e,
+                    0x02b0e352c4034977,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfcf804fd391060db,
+                    0xc90ba2e41c7dd0f8,
+                    0xbd334db600911566,
+                    0xeafd9301ad33f559,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0204a82caf6595a8,
+                    0x2a51cd1b665fd372,
+                    0x74a577635d045247,
+                    0x298b00c99a4c03f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25d80ca93279866c,
+                    0x3a412f653b538390,
+                    0x29605cb2f823fecc,
+                    0x15aefd7328260e22,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd72debcfac3d6bfe,
+                    0xb428646e0732a74c,
+                    0x189c77f4c75544ee,
+                    0xfc73d1cc0d904ac6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7f4ac88011aa9c7a,
+                    0x8347dc1ef7e6f53d,
+                    0xa58b9456ac8e9285,
+                    0x7fc90dcb39a610ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc26e09f5ae042e8d,
+                    0xcc1ca8ca4b51fcbd,
+                    0x2f94a2e0f0f68fc2,
+                    0x3424e9ea9919b675,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f9259f736a5705e,
+                    0xc9c5dd492209a413,
+                    0x8529becea8c3d7bf,
+                    0x18711f4d42b5707e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b6684a8676d7f4e,
+                    0xc54bde8b56ad6cb5,
+                    0x121e9b572ea7f737,
+                    0xef4140f883c369ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa8a226589b6eb10,
+                    0xb3165891a6130005,
+                    0xbefabac00586456e,
+                    0xd47698924dc2b7f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6a8df972660694d7,
+                    0xa89d3d0972779eea,
+                    0xb74c400b01699242,
+                    0x80beabfedf8eca62,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4932d68e12063c3f,
+                    0xd5c53b78c4eeb86e,
+                    0x63ef6936aaedb0a2,
+                    0xa206093d0b4cd0c3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94b4313b3e35dfbf,
+                    0xdb8be3bb8ca3ddb7,
+                    0x8f506dace8c01498,
+                    0x6ae3a9363910af29,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcf26949ac9b93fc3,
+                    0x1f77f69c5c5f7c4a,
+                    0xc8c20b1e38681b24,
+                    0x5b6e205bb755b7ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x17b367eecc993d08,
+                    0xd3116e19de9b1eca,
+                    0xc39b1f25d2e68c58,
+                    0x6be4eade13a676d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc794afd704318436,
+                    0x4268f2cda3c0541f,
+                    0x2c8b2015216bef5e,
+                    0x9139265a1160b01a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc28f2d0140741d76,
+                    0x511c2755c4174d0f,
+                    0x53a3a2885c8c974f,
+                    0x71b8a742998a239e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1dfbb375b33b2f18,
+                    0xeef2f4e2970b0ec4,
+                    0x29c1e725c73c207e,
+                    0x98356d3c89c73433,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3657237f7ecdd742,
+                    0xab20aa94876f3809,
+                    0xde58374ac3a94388,
+                    0xe0fe941c8b12149a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeed40fdac3657518,
+                    0xb90dbe9fdd6f33dc,
+                    0x1cbf8e4c8776e480,
+                    0xa4709121422a2ae5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9252da08f1cd615d,
+                    0x08fea89f1adb1832,
+                    0x2d6601a24f82906f,
+                    0x8f2b3862575131f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x810b1f96a3256de2,
+                    0x8a344909b83bfef4,
+                    0x29828ab792745fb7,
+                    0x09674b2287bff558,
+                ])),
+                Felt::new(BigInteger256([
+                    0x06f6591a62579e21,
+                    0x46c2b28400a253d1,
+                    0x4a35d6561bdf0ec4,
+                    0x685362b22ed1fc3a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x54ebb4f4c65697f0,
+                    0xa2fb5cd6fdaa6cc9,
+                    0x43dc9f8a3f8f8c1d,
+                    0x77f4b5ffc8e6c5b8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9492877e4375c791,
+                    0x7979c9af371e578a,
+                    0x130b31b871418dd7,
+                    0x5f5165ce0437c19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0213e395bd1a389,
+                    0xa108117ab1a35ede,
+                    0x4bdd1921eedb97ce,
+                    0x0780bd9e44e3abbd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc4e810b7a127e491,
+                    0x473193dba2d9044b,
+                    0xe4a83f93aead564e,
+                    0x23c433d78f75cdd2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd1542f7103a9a94,
+                    0xdfdb072199fc2d3e,
+                    0x1e16145a2c5d5d20,
+                    0x943aaaefc2827dd8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7d0ef48438f4fd7b,
+                    0xe7860b9b48f4a113,
+                    0x64b42be091d17f1a,
+                    0xcb2c8388b4f7d245,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdea5d9e2d29d9e20,
+                    0xb3648c81b4d0f366,
+                    0xec91f9902bce262d,
+                    0x626527bebb51c4d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x425ad76506804179,
+                    0x94c97241bdcfcb1c,
+                    0x81fda644d4e1f69b,
+                    0x4abd672a4206ccf0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66ce60040c2c3540,
+                    0x9118f2aad4415fce,
+                    0x3547cf66aa54bb2e,
+                    0xa73c2be699dcbd48,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f65515f89cda9ed,
+                    0x95d8547da07312a9,
+                    0xa309f0509c39fff4,
+                    0xed7999f9b90b1dc6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b12e8a20a33f682,
+                    0xeb5880b9723ec943,
+                    0x4a76af58b1209e81,
+                    0x6802f1371e009098,
+                ])),
+                Felt::new(BigInteger256([
+                    0x210606efcc30be4b,
+                    0xa77dcba15ea6647f,
+                    0x2478bbec487a7c23,
+                    0x4586c21abf147738,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x39913aeb6da74b78,
+                    0x373f983007c61969,
+                    0x0edb4ec2bae7ef0d,
+                    0x236a6f5513b11be6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0cc7793132bfe93a,
+                    0x24fc9301df2198d6,
+                    0x0910678d11af2080,
+                    0x8c80a94dc086c26c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd775adb5a9bbf38d,
+                    0x0119133ea788099e,
+                    0x44918ca289fc81de,
+                    0x2778d6c9b17e4f9c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc3ac8122aa10cc31,
+                    0xa53f380c70a91aff,
+                    0xa8a573d01522585d,
+                    0x851e8c45ac5f04ea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4949a169068d3d1e,
+                    0xc08cf6b1a7462ccb,
+                    0x7ab1e1fecb573961,
+                    0x3425ff27547cd9db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63d085ff248b0d0d,
+                    0x21b360faee52c758,
+                    0xf3382e50f6529de5,
+                    0x5f2b9211b550caa5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc861982076db5448,
+                    0xc4237c3936f4d538,
+                    0x030001b814acf73b,
+                    0x33340942382a0538,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7094e2412677a700,
+                    0x4ea9ca691e1a8cd3,
+                    0xb777bf98eba7baa9,
+                    0xc3f5666f9fc9d2ea,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd09420c983f1816,
+                    0x5e50104481e184d7,
+                    0x0ceb0b71b612dced,
+                    0x98bf2d98de175932,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1535ffa8db2fafa6,
+                    0xa97cd62ed04b5000,
+                    0xc4caa1b7cc6f0aa9,
+                    0x93a731f77b263837,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b39cffa464d2a0b,
+                    0x3bfd5e29f1ad09c4,
+                    0xeb0f37732fa5be4a,
+                    0xaa14a81bcd898dd6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe0b141f2bec65cc5,
+                    0x73de97cf0a8df566,
+                    0x060f30ffa04632d6,
+                    0xe4e3b4983cad4382,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8cdfb6f42a935936,
+                    0x833a5b1fd9e26a81,
+                    0x6319568c25513726,
+                    0xa293a5d43aa9e9e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e16ba7819dcd3db,
+                    0x2b21ae003437e038,
+                    0x731354b019e6392c,
+                    0x45bbebe7100fa2fd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf819bab57d196715,
+                    0xf814a1493dacdbe9,
+                    0xf5055b02b1d652cc,
+                    0x8ad67543725df240,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41d284056529f0e0,
+                    0x7f0fd865b7a169c2,
+                    0x508b3cd00d9028fd,
+                    0xbcbf6a68931448e7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff9a1741414309fb,
+                    0x94dc39e8bf34912a,
+                    0xa9a8b13f41767d64,
+                    0xe814647b857e3297,
+                ])),
+                Felt::new(BigInteger256([
+                    0x85ed0149b049bdf1,
+                    0x15cf0ee5be6dee73,
+                    0xebc218d70abd9cc4,
+                    0x13b31fafc033272b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7f1fa10f444f57ec,
+                    0x2f6dce9def658998,
+                    0x7aa94cf905c9c868,
+                    0x9cece8e580034361,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ca93fba1ff22fe3,
+                    0x45c83d44b3261199,
+                    0x47be31b3e73354cd,
+                    0xfa478431cc914bc2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe5a8481e5480eca6,
+                    0x7601952633de7e00,
+                    0xd6385facb070bb26,
+                    0xb55834483b6b4c61,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7ca27836096ec4b,
+                    0xa3576949e4905923,
+                    0xa7bf6a41d80d1266,
+                    0x89e301f731a8d976,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b6f9b941ed944e0,
+                    0x235c550cf90d8443,
+                    0x6853146accab1d4a,
+                    0x30d98f8793b62cff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x50174512c10d45c3,
+                    0x6ba2126f75f6a35d,
+                    0xf45c991367928dd2,
+                    0x69f7fd3bc196d51a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc90dcfaacc319eec,
+                    0xf64821294f129491,
+                    0x84f2678ea5c3b3c9,
+                    0xd6a821fb1635dc84,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4bfa7c88c36b680b,
+                    0x905d2f9450bc2bf5,
+                    0xa75091a4383e401b,
+                    0xfb7dde3574a7b5e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x87b484e7ab20821a,
+                    0x011def24c3718dde,
+                    0x51d3cdeb446ded82,
+                    0x42c3acd239cce460,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b0cb4efd90b6020,
+                    0x4bd4c347baa50d1b,
+                    0xd3c4813767c7ff42,
+                    0x356437cb1053045f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1d8fcad5b20581ed,
+                    0x70f2f8ae1db14c7f,
+                    0x84b4461c09d716f6,
+                    0x428c1e466a8765bc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xca1cc92c6d33b416,
+                    0x9efe537cd76b6a54,
+                    0x96cd0ef1412db9ae,
+                    0x8754999d28bbbab6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89695a6cf57e30ce,
+                    0x04780ac9a8548b01,
+                    0x53afc39fc6a4ba79,
+                    0x9d231cba459d2090,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09b99830e22ee402,
+                    0x45432b7a1469fa19,
+                    0x7450237bfebb1e5e,
+                    0x71abb5c29fedea4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4454808a4235f53a,
+                    0x4e9c8c0ec89855ad,
+                    0x7b0f0a992227cc48,
+                    0xf8c01e9a9ffd3905,
+                ])),
+                Felt::new(BigInteger256([
+                    0x472b9c98731fb9ef,
+                    0x392e63438d88e832,
+                    0xe339a940ed08f213,
+                    0x15e96db97991e1e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ae7677fe2fc834a,
+                    0xb40564bb31f109a6,
+                    0xab77f3678b21cd66,
+                    0x3ce5a5868c8858b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe336f483e9e45aac,
+                    0xeb79926f87a842c4,
+                    0xd2abce4f50e62f3e,
+                    0x48b96adac75ba18e,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7ed41508b2655793,
+                    0xcdccba812829013d,
+                    0xf567b44f6188b7ab,
+                    0xe97e0f35a8856bfa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8f7c2055b9b8fd10,
+                    0x75428171e856cedc,
+                    0x86502b14b226e5e0,
+                    0xa275f1963e09fd52,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe56db2985fbffd80,
+                    0xbe187d0e5e3eae47,
+                    0x50d8e4ed51473706,
+                    0x222affb24e35e07c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfbda27422813e06b,
+                    0x157d9271897b51ca,
+                    0x0ed0e9bc356ec8bc,
+                    0x66159d0634c3cf5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa30ff459bfb6f841,
+                    0x4a8fd26179189085,
+                    0xdef4b2a910382e16,
+                    0x26aff55a5251e352,
+                ])),
+                Felt::new(BigInteger256([
+                    0x749d5c8a27e93f15,
+                    0xc9a5e394e5e10b6b,
+                    0xffbbc237a7d36038,
+                    0x2ad44e1d5c609078,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeee30dc3a9ee861f,
+                    0x10e0dde5156fc10b,
+                    0x5cc7d8f44e32ad53,
+                    0xeea3dd2855d29e08,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefd951e53fc04c41,
+                    0xa3dcfb07d03d4328,
+                    0x0a9f4188f235237a,
+                    0x3fdb9a6fe4d4c733,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c5a030317d83ca6,
+                    0x368bda5f7896e697,
+                    0x9a0e0ecf940d1ad1,
+                    0xdc776218e48bf800,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdef37afe5a9a5f8c,
+                    0xa6a256d2dfc8323d,
+                    0x2bc4a1673438d05a,
+                    0xbae137f2a2ca3d00,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37a9ad669bc0a751,
+                    0xc453f4ce6a503a59,
+                    0xd4b621732e58a7be,
+                    0xa5c8cd0754b92200,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe21077b2160f57ae,
+                    0x3504c002f1384aa3,
+                    0xb3c7070276894f20,
+                    0xe50f5c546c1416a4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1f6ae298777d3792,
+                    0x8eb46577ac6af92c,
+                    0x307fe4715ae780be,
+                    0x4f396a50a918c893,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d44a5632ceb0a64,
+                    0x1608195d85e2dac4,
+                    0x4edefa82efdcaa9e,
+                    0x53a49f4fb45c2278,
+                ])),
+                Felt::new(BigInteger256([
+                    0x320c1846741a0d53,
+                    0x50565a375b199828,
+                    0xb01cb4c3ace9c198,
+                    0xed5540c8f42afc18,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e2d6ad3cba50a42,
+                    0x666eff1fcbcc7a29,
+                    0xfbed32c493e00e05,
+                    0x15673c29882e8eb8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x52827c162fc5a0ec,
+                    0x12c4c124eeb3678b,
+                    0x6aaba76f2d31bcc3,
+                    0xe516b12bbba76223,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2a7610694c2864c3,
+                    0xe531bfc5cff92281,
+                    0x65d6dd7a8a634c3e,
+                    0x44f1aa03f7e4536f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb706321dc34a77b6,
+                    0xa37ac11c23cdabfd,
+                    0x96ec8fedba3d6503,
+                    0xf36f77d516f4d11c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc170365a0fcb99e,
+                    0x2936d454435a323c,
+                    0xf65f58eb77f706d6,
+                    0x3021d08ddf53ae6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x48dbe36f974c86a8,
+                    0xd13ca96506f19d06,
+                    0xe0c81634519c4b1e,
+                    0x71feabe3d6fb552f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3668c979631b5c5,
+                    0x1d8f3161900af3b6,
+                    0x0a80c4f6950eb739,
+                    0x4f8b6df0d6e9e2dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6528b6372b75c3d5,
+                    0x8581a2f97b674cdc,
+                    0x5e0be50748399c58,
+                    0x398aad84458dd3b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19182340c137e3ce,
+                    0x44cd1a1bbf2e34db,
+                    0xa0c08b79e2d48a75,
+                    0x442b5e1ff8484570,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4d4643f348c3f47f,
+                    0xe8bf13b08cbf9ace,
+                    0xbfc787fd80d506df,
+                    0x25098fe9e3fc24ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76920815867a287d,
+                    0xc7051a5598032a61,
+                    0x13e9f39e740f614c,
+                    0xeaa4405c3fc68bdd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x248e9fad85f11531,
+                    0x2da67376854d9c63,
+                    0xe32a8d4c4f3e118c,
+                    0xa856f13af590a263,
+                ])),
+                Felt::new(BigInteger256([
+                    0x747d1fe4edd2ad18,
+                    0x8f1d8a774bc8b6d7,
+                    0x66b3e51e5e2ab543,
+                    0x02f2b01c46e7a7ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb9480fc43c121e4b,
+                    0x668c1e8a01b066f8,
+                    0x71cfe0db043db4d1,
+                    0x40a7d2d49842d552,
+                ])),
+                Felt::new(BigInteger256([
+                    0x573f1e3fc01aa775,
+                    0x1e29197e7cadb4eb,
+                    0x3cf47d1b5426f246,
+                    0xab4d663ffa7083f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90a60c18c58add18,
+                    0x0e9daa7c528b32d8,
+                    0x0c0588fe89a07d74,
+                    0x0472ded89e5816f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92e2d4dc296e760c,
+                    0x84a163ed40fde3e9,
+                    0xf46482e0542d0dc1,
+                    0x8f43d3a7ddfd0f79,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3cbe2b76ac94fd06,
+                    0x93c1556bb0800d69,
+                    0xd6d5d56efc68dfb9,
+                    0x10c17bb6e6de97b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8fdda32adfedc4da,
+                    0x58b8732ece908369,
+                    0x193e9fedcd910f41,
+                    0xd4b53e926eb7ef1c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc161f1c6d13c387e,
+                    0xceef24606da44d56,
+                    0x5a1cb38cd02729b0,
+                    0x3886781df9289070,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd083cba521019383,
+                    0xd8877221e45171de,
+                    0xe9b4a39099184f86,
+                    0x01f4bd37bf98df00,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa0bf0cd641e2d6e7,
+                    0x81795c797a73c725,
+                    0xa100ab577db9c8e5,
+                    0x7a9336458083e1fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0e32f783d864ef8,
+                    0x0c059f334c0023d2,
+                    0x4b9697c0397c40c7,
+                    0xc44597dcc9d024d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72c428d64e64b2e6,
+                    0x60f61e4728f6dc74,
+                    0xaad856ceeb116c38,
+                    0xc1cfcd48163526b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b541074cd03b8ac,
+                    0x676a04f04ad83960,
+                    0x01286d5d4367d558,
+                    0x0fc956355b2a5816,
+                ])),
+                Felt::new(BigInteger256([
+                    0x584328528c1e7719,
+                    0x00ea1cc8131075d0,
+                    0x097610d969dac1f7,
+                    0x13a70001c8adad71,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaba7317e4b0564f6,
+                    0x89d6df9350906581,
+                    0x5af1754e3bf10809,
+                    0x987810bf29579ffb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4fe73dbcec7e69b8,
+                    0x7593b6445614f313,
+                    0xef4b8d4d630b7a53,
+                    0x6ba788e4a5d87cf9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8416ffa47a867625,
+                    0x37eb8bc218d33ed0,
+                    0x06ee9daf7fdb21f2,
+                    0x55f7bf2d8c1c548f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6529ed9a8b0e4270,
+                    0x10d542535f436c0c,
+                    0xb0e075b10e531145,
+                    0x217d2ea832dcdb36,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7d5ab921fdddff5,
+                    0x076aeab884af2a5a,
+                    0xb5546f86158a5216,
+                    0xaa1122026b363bf4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc5f673ef7c29eb1,
+                    0x54a0183b5edf4f12,
+                    0xbd7314936c4378d1,
+                    0xa5b49209058a9262,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4132dacbaaf73294,
+                    0xb6db35d7139621d2,
+                    0xb00aa5c3042b5bcd,
+                    0xbfc14476ce921ed6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0c1e2dacb86df187,
+                    0xfe00c980d840f20d,
+                    0xce41bacb12bd2c3e,
+                    0x7a694fb00ace6f3e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14efae763c65289f,
+                    0x74402d59690f6560,
+                    0xedbfae9c0fe2573f,
+                    0xd917b3ba729e7982,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa5557b43d7414533,
+                    0x1fe33ea0a75f08d5,
+                    0x9385bbca04bbd1d2,
+                    0x1de9506d1c68d300,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfdbe7f96d18049a4,
+                    0x0a66d93790cdf8e3,
+                    0x47605c45b10967f3,
+                    0x07ee205c42557509,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47f0e4fea934f59e,
+                    0xb3a74aff5e520624,
+                    0x37625468ee51383e,
+                    0x1d1eee08e43d1414,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8430f22a9e3d4d1a,
+                    0xf3398e9c3d0de113,
+                    0x08d7310a11b287f1,
+                    0xd30bcd36f4de5f1e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa2a08c7570bb839a,
+                    0x69fe965d631e1677,
+                    0x5c47ed087b0f33dd,
+                    0xd0146409b3d59655,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67b3fa5e2b12c357,
+                    0xc1ccc006bb520af4,
+                    0x9c27169114ee438d,
+                    0x67f57f6b7a0a18b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe13e05f26e960f14,
+                    0xe843e159b40d39e3,
+                    0x43d6b45a993c05b1,
+                    0x8fcefb726d8ad863,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9691187616abac90,
+                    0x567d29e3688797aa,
+                    0x093ab72364ea6db2,
+                    0x20c1696f5aa991f9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd76430dd534140d1,
+                    0x494dfa339b6b310a,
+                    0x57d187a4e8fd4cfa,
+                    0xa1a4aad9038c7a98,
+                ])),
+                Felt::new(BigInteger256([
+                    0x45a3ff9004ae1c38,
+                    0x9b32885a7173e343,
+                    0xa7d94344cb4a7da4,
+                    0x4ed8ced93191b9c2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa8d45be7fe7deaa1,
+                    0xa806fb9e75b29b08,
+                    0x9124200602b1865c,
+                    0xe8ba16e2829badfb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x33f7177facf07659,
+                    0x196db3b0ca6aa235,
+                    0x96f2b3b403db5e63,
+                    0x95367776da790efe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2907b8a1e087f4d4,
+                    0x4d5cadb2a49e1863,
+                    0x9388ccde5cba1bf9,
+                    0x735a850d57a096aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb549a61ad74eb8cd,
+                    0x4b68c3d3c33919e1,
+                    0x60333e1a8001e402,
+                    0xe29a0ecdeff8f3f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b92cb53ff62a08e,
+                    0x00d0ad5e565b385b,
+                    0xd758df2ee21e8b89,
+                    0xc9fc45882b761da3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5e8a6bc899839a70,
+                    0x6baf64717dd9c6c4,
+                    0x98108dd6e35669e5,
+                    0xf5c2d61f54e7c2a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7b971ac5c211918,
+                    0xc3b09127e5a5bed7,
+                    0x5a6594c150e0418b,
+                    0xba307647653b16f9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe27a7b001e6d0e51,
+                    0x07aca20eceb7f983,
+                    0x07af6e54cdc93e1e,
+                    0xa3f5fa85f83bcbe6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2bf84794e1f06dd6,
+                    0xf37bcc2f1168caba,
+                    0x7d5f07184c0698a3,
+                    0xae630b2652368b2e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf1ecfa5d33e3487d,
+                    0xa218d54f406df363,
+                    0x0518c70963df4f66,
+                    0x82f068f89dd06da5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc06595ad2050dc86,
+                    0x50359c977eeed5a7,
+                    0xe4782979805300bc,
+                    0xf8d1290e17c2e2c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x469da73c5516a76b,
+                    0x233ecdc37451e7c8,
+                    0x0f7242cc7d092478,
+                    0x3589a1f41699c259,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,390 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::new(BigInteger256([
+            0xb68c6a0d5b870607,
+            0x87c122434c060130,
+            0xd989078eec99945f,
+            0x806fec57c4554ff2,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0x75bebb37978e1747,
+                0xa752703483985feb,
+                0xb5b0395aa856d138,
+                0x0fc6fca68aae04b2,
+            ])),
+            Felt::new(BigInteger256([
+                0xd2a3775ba0e13b4c,
+                0x104077a18c4d5568,
+                0x7a2cedfb8efa5a6c,
+                0xb1063a6d3ae31fb7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x6dcd148cb03d0444,
+                0xc6f14baffdd162a6,
+                0x3c221a9a3c929003,
+                0x60e12492da2e2447,
+            ])),
+            Felt::new(BigInteger256([
+                0x8f84f604f18c1a97,
+                0xdb9cfe3bd601135b,
+                0x0aa0818f505fd5e4,
+                0xa24dafa9e7649872,
+            ])),
+            Felt::new(BigInteger256([
+                0x24082a741aba5ebe,
+                0x9ba98a4546ecf521,
+                0xf602a787d458ffe0,
+                0x2e29ac2763704671,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x11e01a81ccb7c21c,
+                0xdea0acafcc4d4079,
+                0xf4a22b5f42e98d06,
+                0x029aebff19473b3a,
+            ])),
+            Felt::new(BigInteger256([
+                0x07e9607bd50b4bf9,
+                0x7b967b7ba3d2879e,
+                0x32c191ae766bb389,
+                0x23df4a1af973bbb7,
+            ])),
+            Felt::new(BigInteger256([
+                0x869febf54bf85ff2,
+                0x8fe2b6aa9bc5a012,
+                0xe4e9cfa8103990de,
+                0xa1298b1ad14c0ebf,
+            ])),
+            Felt::new(BigInteger256([
+                0xd894bc87e88aaa37,
+                0xdb767d1ae6702e0b,
+                0x1cd79ac57ea5cb99,
+                0x5fdebab7eb78eb9f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x36c109c6f35f8a0d,
+                0xdf39eb8e64daacf0,
+                0xa4391c666d212e42,
+                0x629df6aad114f570,
+            ])),
+            Felt::new(BigInteger256([
+                0x010a0567b64617bb,
+                0x5ba5863cb5dfdef7,
+                0xaf2e56d6eb035ad2,
+                0xca2bc40d95f5890c,
+            ])),
+            Felt::new(BigInteger256([
+                0xda6136d05ce4a866,
+                0xf2aa1d5795dc0de1,
+                0x07d26a5614d0a76d,
+                0xb0a003b394a9f64a,
+            ])),
+            Felt::new(BigInteger256([
+                0x129b9485665e3f9d,
+                0x180491cecf8cdcbe,
+                0x37584d9c2847dcad,
+                0x545ce6775f2d303f,
+            ])),
+            Felt::new(BigInteger256([
+                0x3e9386f99780f905,
+                0xa81d795bf3542c52,
+                0x3432963b0a9fbe6d,
+                0xb66cb46da34df268,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x4ad979344c7a7429,
+                0x424bda611c27e013,
+                0x711cdc62fd51fee9,
+                0xba49d16db5e023ff,
+            ])),
+            Felt::new(BigInteger256([
+                0xff55bc372ff9f3ed,
+                0xdc67f4ef66d957d9,
+                0xe8ca938cfca781e4,
+                0xeead61c1f002720b,
+            ])),
+            Felt::new(BigInteger256([
+                0x73e5e31072613d51,
+                0x20d84ac3e3c6b70b,
+                0x5d9024eaf8752672,
+                0x86e795781827de5b,
+            ])),
+            Felt::new(BigInteger256([
+                0xd9c8463f5ad99e19,
+                0xadbbb459b66a1188,
+                0xe068c5ad89e501f7,
+                0x960ed239ad77741f,
+            ])),
+            Felt::new(BigInteger256([
+                0xd1b87fe90dd62a0a,
+                0x4da003e52a86a359,
+                0x2c2ab35167eb2b8b,
+                0xee36aa97c53474a7,
+            ])),
+            Felt::new(BigInteger256([
+                0xa7609b705d470bb8,
+                0xd4f677394e6af06e,
+                0xf4cff68cd058c34b,
+                0xcf31db4959d25c3b,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x760f7af4632083d1,
+            0xea1d1984c45e7fc9,
+            0x16becea4e55f7d8f,
+            0xc53475fd3a7a0ebb,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0acae31afd29f358,
+            0xc17aab3e7fedad2d,
+            0x43eb7fc372c2f89e,
+            0x6231f7f7e1bda636,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd19c6ee75c99ff04,
+            0x19b0966695e2843c,
+            0x437de5e1b2bc008d,
+            0x679201de749cc840,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd25ccf1cc5a7fa4f,
+            0xeacc12e575fbabfa,
+            0x8ff1572d562a300d,
+            0x66e1f897dde55049,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x700d7afd62a087c3,
+            0xe98ada131273d963,
+            0x2b18b2acda4f2b5e,
+            0xdc3e6a5bcaba21a8,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb26d0847a89f5242,
+            0xab3bb17dd7915c69,
+            0x41924092ff6d5626,
+            0x0f3953eb22dfd1b6,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xada97e7d1061942a,
+            0x31531fac16575769,
+            0x9f8847e189dcc499,
+            0x3353aa56f4d949e2,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa416ebbfaa45ea0a,
+            0xf06313bc4b3bf1a6,
+            0x5789559d2297dccf,
+            0xa9444c505d25f071,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbcd3fa463689211c,
+            0x34d7a0770f5dd74f,
+            0x33672d81ac68e506,
+            0x1149e80e7b92bdda,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2366bf9a1e9d6ee1,
+            0xd68197168b8c7c34,
+            0x4d797c00d80afce2,
+            0x57233455dda268c1,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0xee10f593d0406d82,
+                0xd2ac3484632f49e0,
+                0xc13136e1ee67b0dd,
+                0x0720ed80e9c6f60f,
+            ])),
+            Felt::new(BigInteger256([
+                0x8d310f4983847180,
+                0x934e4091f881df04,
+                0xb8c0a43ca68a3419,
+                0x46e1b111a40fa5d0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x4823107d37ea0251,
+                0x21692ff4cd50a0bd,
+                0x54a391dd4ffb72d6,
+                0x64c765fddea9fbd5,
+            ])),
+            Felt::new(BigInteger256([
+                0x46c68eab53843fd4,
+                0x1327a21460f5d431,
+                0xa3dc628857684caf,
+                0x47ed52c90a0b62c7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x9a97e1e22985ed6d,
+                0x9ab159a26e68a64f,
+                0x724fa5abcb606c68,
+                0xca8a75745adf16e1,
+            ])),
+            Felt::new(BigInteger256([
+                0x1434c990cf8bd3d1,
+                0x169e3f16e1050150,
+                0x0a89f314fc0eaf81,
+                0x498010f8d716f7a5,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7c8b399e372b4b5a,
+                0x7dc0e1d9dd81dde7,
+                0xb02063edeab69133,
+                0x914c93fcaed981bb,
+            ])),
+            Felt::new(BigInteger256([
+                0xd62cffbbcca8e81b,
+                0xf4b049bd58b4bd7b,
+                0x817387baf2a94501,
+                0x6a34a777ef0b5d9c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7f61f33ec5fe6557,
+                0x4e314af58576de47,
+                0xec9bdecd91191ce2,
+                0xce1f53a4f9e45960,
+            ])),
+            Felt::new(BigInteger256([
+                0xef4904ede976da40,
+                0xf9d5f53d43d95d92,
+                0x26c2bfaa46eedfe0,
+                0xf8229bfd2715029c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3a21e52dd55ee834,
+                0xc29ec9f141f8bcae,
+                0x403e96ca20b98c89,
+                0x71b3ec19eee718bb,
+            ])),
+            Felt::new(BigInteger256([
+                0x1958c1aca7a7381a,
+                0x1ba2d85c57c02886,
+                0x3283481233fb52ba,
+                0x0ba2993f3977be0e,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xe72826c325831902,
+            0x26a1224869213422,
+            0x255086af41c8ab94,
+            0x7f4fbe40884ece6d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x3803c9ae0a1f4ed8,
+            0x390376c3a2801d17,
+            0x351157cdb851af8f,
+            0xe4bf98abb46609c7,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xf7d6677fdf8b3301,
+            0x7da812955ecff2de,
+            0x69e7ae94ef40440f,
+            0x44a9a1cd6dcfa0e6,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xaf1adf3e32735f44,
+            0x1e03cffb0baac625,
+            0x2c9a10422bfb8127,
+            0x7cd7c4debffe7c74,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc3900b4ccadb6221,
+            0xba0456bf2b7e865c,
+            0xf486c2bbcd1c2416,
+            0x4d0e89395d81df2c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x856a608a473f243e,
+            0xa3ebfa2a5e821c7d,
+            0xa16525ea7a0ef02b,
+            0x1c408a0b588ec40d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd7769a5bae5cbf9b,
+            0xe75711f9777938d8,
+            0xf8307920b7e9f198,
+            0x2038eaae54f641cc,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb675ded462872663,
+            0xdc0c5a8c32dd9f1c,
+            0xbc03c777622465cc,
+            0x9c7f9f421aa44023,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x95f4bd85e38587fb,
+            0x3a9ff9d7a668d57d,
+            0x81b1b65f302794a6,
+            0xdb694c2351a135ba,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd0cb6871d1af8a94,
+            0x767e8320d414f947,
+            0x07281befbebb6d87,
+            0xf94c5e657f41656a,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+}
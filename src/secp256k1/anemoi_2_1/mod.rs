@@ -0,0 +1,599 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 64 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x9e4bfb1fcdb6660f,
+                    0xffec5dce0099c908,
+                    0xcafd71c2272f3671,
+                    0xc0732b982180d2a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd123eab742c224f6,
+                    0x64d4496243f04732,
+                    0x03fb873ea0f6ec74,
+                    0x9bedbca157d20c14,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc69582860a53615f,
+                    0x4781f0c2dc1ab2d2,
+                    0x84a45a90d6f7f808,
+                    0x43f93995bc707a40,
+                ])),
+                Felt::new(BigInteger256([
+                    0x54ff59d4fe4aad1c,
+                    0xb240329190f6c58b,
+                    0x6375fa8187690170,
+                    0x3b3d1d8bca8a7d36,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xdf4db00cc3f85719,
+                    0x775051b16b3cea1c,
+                    0x4322c07e56762d4e,
+                    0x79ae8c56304e3055,
+                ])),
+                Felt::new(BigInteger256([
+                    0x473ee8cb3fe9b353,
+                    0x46884a2af3b7e189,
+                    0xc952e60ae6b07bf2,
+                    0x9282b6aa14ec3e5f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x414c2941eb9fa82d,
+                    0x80e0a2010aa4f46f,
+                    0x8e5566048e2a2123,
+                    0x6efe826416d09eb7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1021502f53379a92,
+                    0xa69bd842772e66f3,
+                    0x19cf1913b3684f74,
+                    0xf5b69bca5231299e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xed9378cb82fc7c86,
+                    0x079e4ab13d35d2d8,
+                    0xcc434801195a7754,
+                    0xc1548999dad59452,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c8862c9d8ab4de8,
+                    0x0611b7fc0d42dc18,
+                    0x57705a72dd3af275,
+                    0x3f21ac5d16cd1c20,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x89525242fcf7337b,
+                    0x75b672778c74340a,
+                    0xe92862bcac41fa39,
+                    0x5afadc41a88e21b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb61928eaffc57f4e,
+                    0x935857588db45ac9,
+                    0x1c764d767b68c366,
+                    0x76a29b02d38a6096,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x36943cfc42f626ec,
+                    0xa831e6d9757b880a,
+                    0x12738a948a57343b,
+                    0x4231e94c3b2e6190,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b2096ede00261e5,
+                    0xa52280e290596905,
+                    0x374756fe440036bb,
+                    0x9a1d16ef5794969c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x459123a8c443ea4d,
+                    0x1e0f05541991843d,
+                    0xda0fa1ca582c2854,
+                    0x73614b87d8a3e94e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x46ff13cd88a4f0b4,
+                    0x41b8c3bc267331c1,
+                    0xefced2196addec67,
+                    0x80f6eb85cf26e8c6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf7831d0420a40ea6,
+                    0xf2cb58518be8cd13,
+                    0x375bf89ca2fcfb58,
+                    0x781feae2bf1c7e44,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3cd2a0f51c0a7b67,
+                    0xd1a9ca28aa2fdd4a,
+                    0x74b3055ff68d4cde,
+                    0x095c814380a99cca,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x36f926ba369af3b0,
+                    0xbca94acb2c2186e2,
+                    0x1ff0946dbbd08979,
+                    0x2e48e1ee8bc65292,
+                ])),
+                Felt::new(BigInteger256([
+                    0x45a65299d5811a0f,
+                    0x286069ffa1317a9e,
+                    0x44e405480cd2704c,
+                    0xd189924223581b28,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x25fe272f4ac8b2f9,
+                    0x373e0afb9e6217f9,
+                    0x33232fa19b37ac93,
+                    0x07e25788d523ef21,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa704b02a5eaf1436,
+                    0xaff8ac54f8bc7f5d,
+                    0x2d451951510ddd41,
+                    0x98643a55d54464db,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5103f6d61e6053f6,
+                    0x0d56417210013213,
+                    0xc5418df5f968e579,
+                    0xf27219584d523275,
+                ])),
+                Felt::new(BigInteger256([
+                    0x114044ca178bbb72,
+                    0x6340d6defcbc9b8f,
+                    0xef037778ddb8c30d,
+                    0x4005ecaaba9bf1a4,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x3eaa322a05ae8c11,
+                    0xa84b11dd99469ec3,
+                    0x4b23583ec8f4f393,
+                    0xac4a6ce45e505cf0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb9c98ec29a261d55,
+                    0x243ae1ec3f9173fd,
+                    0x2710ed1154aceb78,
+                    0x21018467017896f8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc4dea2950dfbdc37,
+                    0xc3445f8c1ec0a617,
+                    0x5cf9ba2f2bb422d2,
+                    0x02cb5141710e6165,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0acdfcd0a77f968a,
+                    0x3236ac8b04eab725,
+                    0x92d41f2a3fdd47ac,
+                    0xd5a28df868acbadb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x74f60f5122fffe62,
+                    0x7b4b3199a8db0e58,
+                    0xec98551251602b33,
+                    0x0e9ae1076c6e1fc5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf8f6df3106f923f8,
+                    0x49deea1777045888,
+                    0xce6dc39ae2d28838,
+                    0x62803185f19951aa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xff871f50d0ff7542,
+                    0xd5f70e48221c01c8,
+                    0xe94ce9ac5cf8ad01,
+                    0xa858bcc32fd25b2e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b3e0954fb006f07,
+                    0x2958732f883d1624,
+                    0x16bc6eb67493760e,
+                    0x1e6909030d987524,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7a579749187931cd,
+                    0x7fc2aefeb7158e8e,
+                    0x9a6b3e45c06aef0a,
+                    0x3211fcaac5887196,
+                ])),
+                Felt::new(BigInteger256([
+                    0x841a8dcaa42c5d38,
+                    0xa28fb1586b90c379,
+                    0x860de96f908ce14d,
+                    0xd60f1f1bfe0ba891,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfb358a5fdddf11dc,
+                    0x928402e98c08df88,
+                    0x62758a45fa68fb28,
+                    0xa54b6b645cfc0e20,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72ce7c5b71ce4111,
+                    0x859c60c87d7c52c5,
+                    0xaed2730bad352322,
+                    0x7276d99da714e722,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000300000b73,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000a0000262a,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger256([
+                    0x0000000300000b73,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6c06de72d420e7e1,
+                    0x14fbb7a257fafabc,
+                    0xc0561f72c6fbb5fc,
+                    0x0f4efa1962ba21d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfdde2a1b1688d4f8,
+                    0x632e08d347826432,
+                    0x68134b69a9a00d6c,
+                    0x4eee72b329a6fc82,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe5489909047aa777,
+                    0x59e8652d2d80cb86,
+                    0x157617adeb4bf9d7,
+                    0x83b2fb2aab1491f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbaa7c7edb4ef9491,
+                    0x3fefdc128d6d19b9,
+                    0xd336663401c13532,
+                    0x60bb7f7869ea70c3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5fdaace537eb6e1b,
+                    0x58e7efe00de817f3,
+                    0x57e19fe2f9d7c3dc,
+                    0x361b7599413a14c6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1886e5e1aebb721a,
+                    0x5496b9b7a0bca063,
+                    0xd612a343d059d3cd,
+                    0x04d29251b5478ffd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd1413b50c200c628,
+                    0x520067d6bad34436,
+                    0x2d8235cfbab30f2c,
+                    0x0393d7cc589bba9b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0f01bb474102c17f,
+                    0x1f59aab3b8b6e2c9,
+                    0x9f431025a4aca393,
+                    0x29249068176ba4f5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x06a740ab04fe5117,
+                    0x6771c307f9c7d8fb,
+                    0x2c94fa94721192f3,
+                    0xb43f59febfab6b4b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x98104fcdb327581f,
+                    0xd8e4fa7058e84e6a,
+                    0x0bccd92ce6c19a27,
+                    0xf2cd2d183d0dea73,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x53a0ff733349d8e0,
+                    0x23592543047dd7d9,
+                    0x6eece36902086490,
+                    0xfcaff83d523ac388,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6db17ab80babd724,
+                    0xefa7d0918af5da51,
+                    0xfb991d46b34e50d2,
+                    0x6886c2559dc531bb,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0x0000002300008593,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x36e9d41e0a529925,
+        0x3ce4e373e3338ab4,
+        0xe0d5b70dc27ef66b,
+        0xd568ac692b332d54,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x88dfef1c2b006d22,
+        0x2d3f03efe6c22223,
+        0xcb6ffb67274f1513,
+        0xc954145cbe871688,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x66561d21fd5b5a56,
+        0xb0d4ddba9d8459bf,
+        0x479bf57319de8030,
+        0x8da7b38283d9e362,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x58f22d6947d4e0e0,
+        0xf36ad2fb07d2d517,
+        0x151c5c6499c72abc,
+        0x1dabc8658671b9a5,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x8bfe0a699487da57,
+        0x96a2a2e68d5d69cd,
+        0xe5d6078b3d92f92b,
+        0xeb9fe7b9678996b2,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x9e3c5ebf3ff57390,
+        0xd32cee23e992b308,
+        0x7e750739aef968c1,
+        0x8be5560f8b223cd8,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x9dbcc6e9e15e16b0,
+        0xb1883138228b13dd,
+        0x2bb79af062c2269a,
+        0xb68978ee9ca4eac5,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x82deaeb5dabe0c68,
+        0x6376261e0c6aa77c,
+        0xe9c9169c5512100f,
+        0xcc39dc1d8b74fd6e,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x4da2622849cadbf9,
+        0xd2a2d2e1657e00d6,
+        0x7e8818adee48f8f3,
+        0x5106cbb7435e6d67,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x2bdd02a8194ca6e1,
+        0xa73a608fa01b2634,
+        0x9294d7c987cabde6,
+        0x058d9495a3d528b0,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x1a920821d238c9a3,
+        0x8c2ceaed29f9454e,
+        0xe2455b591f564c3b,
+        0x65e58d20d749994f,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x6939a1145a11448b,
+        0x723d40980f164866,
+        0xdb83046fd2329473,
+        0x69275f04665616bf,
+    ]))],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0x000000235555dc2e,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xfcbfba6d5f75e3bb,
+        0x62a10c2b44ffc651,
+        0x3dfaec31a98697d8,
+        0x9c8508cb712a127e,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xeb110ca6e75c886f,
+        0x35d4ef75c2151bef,
+        0x2858de8f68edeb33,
+        0xb506bb44f8918867,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbd98710e46abceac,
+        0x2b19936744ee6cdf,
+        0x275a4cf9764c7e1c,
+        0xff109da33f819c55,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x61c752e75c3f7dc6,
+        0x2f3f35d3f75e77d7,
+        0x709a81841e71f824,
+        0x8553ebf7c5c93d59,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x82f13b39eb4619a9,
+        0xf0c6ceba95043ce9,
+        0x73125db9bbccc4f8,
+        0x3fa18bca4a5744fc,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xf5d6bce21fa375e5,
+        0x714ac049354b1ebf,
+        0x1cd274554ea19b99,
+        0x22111d8a37468ba2,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xb82e9d0743f9d5ba,
+        0x1a84c7de305ecfe8,
+        0x60817c13836e5f9f,
+        0x217323e780169d97,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xd555c1c83d1334ca,
+        0x5ee11595665b02f8,
+        0x449a2264d4fc17b6,
+        0x7037706b98b02e1a,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x62b8449e875db8c4,
+        0x0ebfb27e0eae52a2,
+        0xd4de5922fde01b7e,
+        0x1622e6d42e7a09ae,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbae2f30b3778b425,
+        0x820266335f068398,
+        0xe78ff239f98a7e22,
+        0xbcd97a1dbb470b07,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc282009409bbe21d,
+        0xa57882dcde0eeb6d,
+        0x8c5681dd7fe8678e,
+        0x2cab23141860bc37,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc77cc2367f84221c,
+        0x77e929db8320019a,
+        0x8a932e04ca82e946,
+        0x019b3d5dbc30011d,
+    ]))],
+];
@@ -0,0 +1,571 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x0000002300008593,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0xc759ab3b33c7f9b6,
+            0x1a2e82d7ce979427,
+            0x390bf16667c443b7,
+            0x206a06ea519624fa,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x36e9d41e0a529925,
+            0x3ce4e373e3338ab4,
+            0xe0d5b70dc27ef66b,
+            0xd568ac692b332d54,
+        ])),
+        Felt::new(BigInteger256([
+            0xecf27193e10b15cd,
+            0x3bd58c9cad01ebf4,
+            0x1e37005f634ec584,
+            0xf6e2011c3d25999d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x88dfef1c2b006d22,
+            0x2d3f03efe6c22223,
+            0xcb6ffb67274f1513,
+            0xc954145cbe871688,
+        ])),
+        Felt::new(BigInteger256([
+            0x27880225dcba54a9,
+            0x0dd4734af814523c,
+            0x9235f5e1ce5177d3,
+            0x1ea43e000ac2eef0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x66561d21fd5b5a56,
+            0xb0d4ddba9d8459bf,
+            0x479bf57319de8030,
+            0x8da7b38283d9e362,
+        ])),
+        Felt::new(BigInteger256([
+            0x6368ff7cf3cb2286,
+            0x45752631fe5a1664,
+            0xae7dab3ba08e20bf,
+            0xd9ea4126dc384569,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x58f22d6947d4e0e0,
+            0xf36ad2fb07d2d517,
+            0x151c5c6499c72abc,
+            0x1dabc8658671b9a5,
+        ])),
+        Felt::new(BigInteger256([
+            0xe4156510e38a844b,
+            0x02e8d98399eef919,
+            0x77969747b0cc4741,
+            0xaaa05d7fa2143a24,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8bfe0a699487da57,
+            0x96a2a2e68d5d69cd,
+            0xe5d6078b3d92f92b,
+            0xeb9fe7b9678996b2,
+        ])),
+        Felt::new(BigInteger256([
+            0xc718f1882e487442,
+            0xc34ec759d19d2720,
+            0x406e067fc70b6cee,
+            0x8e0ba0ce9f11bf4d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9e3c5ebf3ff57390,
+            0xd32cee23e992b308,
+            0x7e750739aef968c1,
+            0x8be5560f8b223cd8,
+        ])),
+        Felt::new(BigInteger256([
+            0x7e16df8ffc53e754,
+            0x22fe83c05a677ff1,
+            0xb211448a437ce05a,
+            0x729d0ceaff905e00,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9dbcc6e9e15e16b0,
+            0xb1883138228b13dd,
+            0x2bb79af062c2269a,
+            0xb68978ee9ca4eac5,
+        ])),
+        Felt::new(BigInteger256([
+            0x90afc95d74e94e0f,
+            0x0980e79242832160,
+            0xce0067c45ecdaa91,
+            0x883d962b06c755b2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x82deaeb5dabe0c68,
+            0x6376261e0c6aa77c,
+            0xe9c9169c5512100f,
+            0xcc39dc1d8b74fd6e,
+        ])),
+        Felt::new(BigInteger256([
+            0x62437a1e964b091a,
+            0x54cd922bfa409042,
+            0x167e79f70b3820ba,
+            0x57a46b917f2ff1c2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4da2622849cadbf9,
+            0xd2a2d2e1657e00d6,
+            0x7e8818adee48f8f3,
+            0x5106cbb7435e6d67,
+        ])),
+        Felt::new(BigInteger256([
+            0x8bbc83ef8435eca3,
+            0x1e5dc61a2f2842e3,
+            0x9043aa12508c07c8,
+            0x03759b950510557e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2bdd02a8194ca6e1,
+            0xa73a608fa01b2634,
+            0x9294d7c987cabde6,
+            0x058d9495a3d528b0,
+        ])),
+        Felt::new(BigInteger256([
+            0x779e18ecd8597d03,
+            0x960aba7a63afca58,
+            0x1cf2eb398233f9e3,
+            0x2734183c5af5e32d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1a920821d238c9a3,
+            0x8c2ceaed29f9454e,
+            0xe2455b591f564c3b,
+            0x65e58d20d749994f,
+        ])),
+        Felt::new(BigInteger256([
+            0xa4e8f5b94f2b449c,
+            0xa5dbcfcc21c3c779,
+            0x83e77e3557dca9aa,
+            0xd991ad3712a33e4d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6939a1145a11448b,
+            0x723d40980f164866,
+            0xdb83046fd2329473,
+            0x69275f04665616bf,
+        ])),
+        Felt::new(BigInteger256([
+            0xe7f0fb23c7be8d52,
+            0x8ed9742271b82cd0,
+            0x1197cc329c122095,
+            0xba819131c461daf7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbf584e81608ef619,
+            0xc5dbbd43c6d43d91,
+            0x6ff7f332c5c72485,
+            0x2a8124341623e80b,
+        ])),
+        Felt::new(BigInteger256([
+            0x243c1c220958d99b,
+            0xdad550ea25ee733a,
+            0xb2ed03014fd2316a,
+            0xf2d3214b5ed611cf,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x87389d18e0b1c980,
+            0xa4823a109400fab9,
+            0xd4e971b900a5619f,
+            0xee311e35bdbb47da,
+        ])),
+        Felt::new(BigInteger256([
+            0x952d0526c1ed48fd,
+            0xd0f995d87fc64f1f,
+            0x217faf865f54c7e5,
+            0x652e9dba45526fa5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd9ea158b88975f9d,
+            0x580bd8b7b52bd895,
+            0x964837d20c407d33,
+            0xe4021989e9adff10,
+        ])),
+        Felt::new(BigInteger256([
+            0x233a9dc3953cc69e,
+            0x798b490f06e4a0a6,
+            0xf6de2aa36910cb99,
+            0x7cb8873b15c6c4db,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8a40c44fcc07eaf1,
+            0x529c70a064c3d19b,
+            0x81240ac411a9fac1,
+            0x2671bcee55065e82,
+        ])),
+        Felt::new(BigInteger256([
+            0xc0b97ed68e2e91a1,
+            0x84734e1883a97fa1,
+            0xf063cc454ae7d53d,
+            0x9ae0f1b4ada1d91e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe48eaf89830eb08e,
+            0x2fd44441159a8096,
+            0xa8369b4e1dd69967,
+            0xfbc89f7f4ab3761d,
+        ])),
+        Felt::new(BigInteger256([
+            0x966dff3011b6b089,
+            0xa747e9146286d338,
+            0x067e62fcc0cbce7e,
+            0xb2f212603e4fffec,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x65108a09a1547a03,
+            0xf1571148fb3be578,
+            0xe4a495d6d92a61ca,
+            0x188325f45b403f87,
+        ])),
+        Felt::new(BigInteger256([
+            0x9535e172f8b1e6d8,
+            0x60dc7604dfff6aad,
+            0xbf379a4ffaf1e3e8,
+            0x8f799a9382fe86a2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x193501bdc58150e3,
+            0x8e5d60d7c7167fed,
+            0xcccf34ce19a9ad26,
+            0xfbf2ae3988ceab58,
+        ])),
+        Felt::new(BigInteger256([
+            0xabf4a693f5bce505,
+            0x8479b115da4b8850,
+            0x784a67f681de4213,
+            0x92241bda08486822,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000235555dc2e,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0x0f42d4c513066487,
+            0x6e4f6668e5f35b62,
+            0x39507628c0873840,
+            0xdf41b7838734dcc0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfcbfba6d5f75e3bb,
+            0x62a10c2b44ffc651,
+            0x3dfaec31a98697d8,
+            0x9c8508cb712a127e,
+        ])),
+        Felt::new(BigInteger256([
+            0xfab1816dc017786a,
+            0xb5b298e52629eecc,
+            0x7ba0ba45a3195b7a,
+            0x7cd60e17b8bb368c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeb110ca6e75c886f,
+            0x35d4ef75c2151bef,
+            0x2858de8f68edeb33,
+            0xb506bb44f8918867,
+        ])),
+        Felt::new(BigInteger256([
+            0xd1a2493a22ff842c,
+            0x6a8b4261eac31342,
+            0xef635dcc68b3427c,
+            0xc92e95817a6c1894,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbd98710e46abceac,
+            0x2b19936744ee6cdf,
+            0x275a4cf9764c7e1c,
+            0xff109da33f819c55,
+        ])),
+        Felt::new(BigInteger256([
+            0x02947cf4c704b2b4,
+            0x13dabf6fbd1ff0bf,
+            0x8e80878455bf1334,
+            0x0a2adbe0cd7eb622,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x61c752e75c3f7dc6,
+            0x2f3f35d3f75e77d7,
+            0x709a81841e71f824,
+            0x8553ebf7c5c93d59,
+        ])),
+        Felt::new(BigInteger256([
+            0x34d3b41981de3938,
+            0x92de1feda0d66314,
+            0xd35941298e3a0931,
+            0xd12031ab170a759e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x82f13b39eb4619a9,
+            0xf0c6ceba95043ce9,
+            0x73125db9bbccc4f8,
+            0x3fa18bca4a5744fc,
+        ])),
+        Felt::new(BigInteger256([
+            0x05f54be20eefc7ca,
+            0x7193d6bef09fc177,
+            0xcdeee1709e082d45,
+            0xa0e4f578b77e255c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf5d6bce21fa375e5,
+            0x714ac049354b1ebf,
+            0x1cd274554ea19b99,
+            0x22111d8a37468ba2,
+        ])),
+        Felt::new(BigInteger256([
+            0x1d9a673c65eafddf,
+            0x153d3976bd7bb2e3,
+            0x50b336683be807bb,
+            0xc7a084fee1536490,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb82e9d0743f9d5ba,
+            0x1a84c7de305ecfe8,
+            0x60817c13836e5f9f,
+            0x217323e780169d97,
+        ])),
+        Felt::new(BigInteger256([
+            0xf30ac904616e214f,
+            0xc69e61c967b2a4a5,
+            0x030ecda9d83cd81e,
+            0xb1fef1bd1fd7c04b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd555c1c83d1334ca,
+            0x5ee11595665b02f8,
+            0x449a2264d4fc17b6,
+            0x7037706b98b02e1a,
+        ])),
+        Felt::new(BigInteger256([
+            0xfca3b6ba828945b2,
+            0xa45965346b8cb2f8,
+            0x71940a81e3e51cea,
+            0xba79b078c209da33,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x62b8449e875db8c4,
+            0x0ebfb27e0eae52a2,
+            0xd4de5922fde01b7e,
+            0x1622e6d42e7a09ae,
+        ])),
+        Felt::new(BigInteger256([
+            0xe8bb8fef4bb1dda4,
+            0xae9b8947efb45be9,
+            0xe6de6f49b8e61edb,
+            0x8769674b25caa98b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbae2f30b3778b425,
+            0x820266335f068398,
+            0xe78ff239f98a7e22,
+            0xbcd97a1dbb470b07,
+        ])),
+        Felt::new(BigInteger256([
+            0x4e8d32da806ea24e,
+            0xc4f3a3af39f6eef7,
+            0x72328a6c4cb6aea8,
+            0x9d57ae5da8067d4a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc282009409bbe21d,
+            0xa57882dcde0eeb6d,
+            0x8c5681dd7fe8678e,
+            0x2cab23141860bc37,
+        ])),
+        Felt::new(BigInteger256([
+            0x94c217b61097751d,
+            0x13484b4ced3534d3,
+            0x2e3d297c1131b987,
+            0x5f2ef3c3895918fb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc77cc2367f84221c,
+            0x77e929db8320019a,
+            0x8a932e04ca82e946,
+            0x019b3d5dbc30011d,
+        ])),
+        Felt::new(BigInteger256([
+            0x8e1d45d0771a82ea,
+            0xe8a640f6fd1dad3f,
+            0xc0ec7a89ed2569f1,
+            0x11cd20244fda7d1a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf0bdd762e6a2e9eb,
+            0xbe6a13e2f1faea7f,
+            0x01e6346c19ff1084,
+            0x819e61adf0e1f796,
+        ])),
+        Felt::new(BigInteger256([
+            0x9d8ace8f1955e945,
+            0x27848b1a6870e762,
+            0x451fc8fcfccd11f3,
+            0x08c80f5e6f32d920,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf5bbae686d7fe295,
+            0x9dff7d279566e7c1,
+            0xaa71e8045208015b,
+            0xa39ca990b15cde59,
+        ])),
+        Felt::new(BigInteger256([
+            0x4b993fffd8a47648,
+            0x1e97bc8098880362,
+            0xf74caa94097a5c2b,
+            0xd971d9ae6e92bde9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2de8101c30aad560,
+            0xfe2d8b97faf0178c,
+            0xe6c38da2a02ab6db,
+            0xbbaffc9d03d2fced,
+        ])),
+        Felt::new(BigInteger256([
+            0xbf21c1dec7395468,
+            0x73cddf806404a6d6,
+            0x479e053655bdf9cb,
+            0x133e1ae7658a7a7f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x411126c8e66108bc,
+            0x1c9ede25e7f20cb1,
+            0x579b8246bf9a185d,
+            0x93e39dfc0d96071b,
+        ])),
+        Felt::new(BigInteger256([
+            0xbf730ada3270c773,
+            0xa2969f2f1e3381f1,
+            0xc71fc88a519ae762,
+            0xc72a835b9bd0397d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x76c9af747e66d4a6,
+            0x6607f4f14f38f83f,
+            0x8a252c472bb66b14,
+            0x80bf24e17edad343,
+        ])),
+        Felt::new(BigInteger256([
+            0x709228a496f7e8d7,
+            0x319c7d55b381121b,
+            0xe8b178b8276e94b5,
+            0xf6c0485ba81614d7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0cf9bf087b7f208e,
+            0x89151976b5f1e49f,
+            0xc7464f23652ae16d,
+            0x5ae028b0427c843c,
+        ])),
+        Felt::new(BigInteger256([
+            0x85083ffc5cc5a56a,
+            0x4cbb61c3b211310e,
+            0xa21dd85edfb55814,
+            0x90ae4de89fd9831d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe52dc6f14192ab1e,
+            0x9349420b701bc4cb,
+            0xc563815d61c91318,
+            0x8c143853ec0bb2ee,
+        ])),
+        Felt::new(BigInteger256([
+            0xbfd69550fbb75376,
+            0xdd8675da9aac9469,
+            0x7123394822c09c8e,
+            0xe11d568da124277e,
+        ])),
+    ],
+];
@@ -0,0 +1,772 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![Felt::new(BigInteger256([
+            0xcc1f0bf6a009254e,
+            0xc504ff832c74fb48,
+            0x6b70bca65c38e485,
+            0xb9b98b60b926458c,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0xe7bbdec4efc44c3b,
+                0x2dd0758c9bf1052e,
+                0x166f97656ba88399,
+                0xf30b5449b750e25a,
+            ])),
+            Felt::new(BigInteger256([
+                0x6cab8e3d5c7a1021,
+                0xc64f5ddbdf96232e,
+                0xffbf2907679ef0cc,
+                0x84b4601e68d74a3d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x30634b499d3c12f3,
+                0xf4721af42938129d,
+                0x56bf3d42639962a3,
+                0x59755e81073c7319,
+            ])),
+            Felt::new(BigInteger256([
+                0x97b970ec36b1074c,
+                0x6a6aa302c202dffa,
+                0x8f076b1e5e0513ad,
+                0x0a0acb0f9cd56d92,
+            ])),
+            Felt::new(BigInteger256([
+                0x26759a7b5a156bda,
+                0xd3662e75a64becf6,
+                0xee521a8fff796768,
+                0xadc0ed268aff1c32,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x93063279d3b77981,
+                0xf11e7627811fe711,
+                0xa8db9d3aa228b4db,
+                0x0e01f472fa2cd323,
+            ])),
+            Felt::new(BigInteger256([
+                0xe257680e42ccecd1,
+                0x47155695cffb1174,
+                0x784a47d7a38ad4a4,
+                0x84019206954d65c7,
+            ])),
+            Felt::new(BigInteger256([
+                0x88a20357a968d9d1,
+                0x5b1f6a3c301f59e4,
+                0x5a20b3ec29ad4c51,
+                0xbd675ae3ae21eedd,
+            ])),
+            Felt::new(BigInteger256([
+                0x2d8bce43994e7a6b,
+                0x3e508ae876a6d78e,
+                0x78b4f5e0068e5c98,
+                0xcbb198a983c5182d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x970ddbfb4486ce5c,
+                0x17ec5f364c666824,
+                0x914edf9be7fa6cc7,
+                0x258ad2edaabc9ca1,
+            ])),
+            Felt::new(BigInteger256([
+                0x3d6e6071535eb26a,
+                0x4159e9ccbc3af3d2,
+                0x5d82ab577cb1a696,
+                0x8250e3503d8cf964,
+            ])),
+            Felt::new(BigInteger256([
+                0x0487274ae8f285c6,
+                0x9f5e27e3789fdb9c,
+                0xd3c8a0303782752f,
+                0xc530ab6626351c03,
+            ])),
+            Felt::new(BigInteger256([
+                0x39d6ce7f0288aa85,
+                0x68cfbfe437f7d37d,
+                0xcd5cec5472536eae,
+                0x7eac90dbb6728869,
+            ])),
+            Felt::new(BigInteger256([
+                0xc02fb8491b09e288,
+                0x30329c5ddc9d55ee,
+                0xfe820df41b4e9144,
+                0x692d7464df905603,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x875c7cdb6d1cfcdb,
+                0xa5e85773b5fa108e,
+                0xbeedb0b884ee5507,
+                0xabdc70cd9b6c3103,
+            ])),
+            Felt::new(BigInteger256([
+                0x35ec985a5f33c217,
+                0xba30d1684b957734,
+                0x29e304a0c0147d7b,
+                0xbf08c52e3747505f,
+            ])),
+            Felt::new(BigInteger256([
+                0x1a5a187b6463f788,
+                0x1b16d190028da616,
+                0x776f7631a6998788,
+                0x2dbca2a97ae42d80,
+            ])),
+            Felt::new(BigInteger256([
+                0x8af05298425369a7,
+                0xa958d1389272df57,
+                0x2afe28c743b512b9,
+                0x5fdada6ad388764b,
+            ])),
+            Felt::new(BigInteger256([
+                0xbc346f9cdb55ec3a,
+                0xedcc833a67083ced,
+                0x156269233af17478,
+                0x1bc424a86f9cb813,
+            ])),
+            Felt::new(BigInteger256([
+                0xe682f771b26ce477,
+                0xcf1e0ceea646471e,
+                0x0d860f5526641698,
+                0x31fac54799ca3de8,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xd536ad07e76328cd,
+            0x73f562cee8a840a4,
+            0x525caf1d1f1e4c4a,
+            0x5c4dd0291e4df50b,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2e5d2c415b3618dd,
+            0x9cacfdd97693f887,
+            0xccf602a6f8ded3bf,
+            0xe17751caf39039ea,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb14640a9105c8bb9,
+            0xd29c51fd9cc4dd96,
+            0xeef1dda322132c83,
+            0xddf9f87030ec2859,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x62afbfc1c6510138,
+            0xe9bdd282eeb80fc2,
+            0xa357e45869f9f9a0,
+            0x662c518b04f27937,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4b1765565b10e629,
+            0xa4c5e932cf5cc1c0,
+            0x21285daac356af9c,
+            0xd710839c6f5661fd,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x30301cc8efb8d0d5,
+            0x3dd9d433ad77ae5b,
+            0x2910d036ca96712d,
+            0xd2ac55bafaa695ee,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc99b5f768c3c5c8b,
+            0x614a873d2cc0d5e6,
+            0x1882a6ac3e42a8ef,
+            0xa3b4217f40a2b946,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9f99cfb6d3783dfc,
+            0x6d983b911ae937fe,
+            0xb4b46d29ae6ef62b,
+            0x72fc3f70ba45c1b5,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xf844a4e41bd3a925,
+            0x923fb11526720367,
+            0x20924c3a83548cd6,
+            0xea4e22990a8c5598,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9324516a4f82a6cf,
+            0x64301ee1e8ea24bf,
+            0x670bd1bf0d9bb763,
+            0xe80ba9f61cedd45a,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0xedb780a191f4c468,
+                0x04aa0adb3b172e6b,
+                0xaf22155b73d80518,
+                0xcd95e525bb1b5845,
+            ])),
+            Felt::new(BigInteger256([
+                0x09782ee8134ebd23,
+                0x45751d677823ade2,
+                0xeeef167fdabc0bf4,
+                0xf27967099c222aa1,
+            ])),
+            Felt::new(BigInteger256([
+                0xa48de51a54ad4f9b,
+                0x2d1f2734fb79fa88,
+                0x7c80eb84b7ec48f5,
+                0xcc7c40d4dc48c812,
+            ])),
+            Felt::new(BigInteger256([
+                0xb30b8415fd5d250d,
+                0x54a54253a6611046,
+                0xee8495cfd1f4bd56,
+                0x744a3bc8887085d9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xed78244fa6473f0d,
+                0x9e4d8a2479bfecb5,
+                0x45f712129efd2ad6,
+                0x50f5351955af162f,
+            ])),
+            Felt::new(BigInteger256([
+                0x4477c5ae1e3bc7d6,
+                0x776b01f341649656,
+                0x14bff4530610354a,
+                0x19d1033fea73b24a,
+            ])),
+            Felt::new(BigInteger256([
+                0xa42ce9d1643d3330,
+                0x39940733c8d24b69,
+                0x10d35cbc1cac8749,
+                0x96bce44aafc6a30e,
+            ])),
+            Felt::new(BigInteger256([
+                0x17510c9b253902d4,
+                0x3f7284f283b74585,
+                0x4226def20cb48b47,
+                0x228e063245601e7a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x45d8f59b2f175dda,
+                0x67f11d23bfd78b46,
+                0xec0190e6c7c9cabd,
+                0xc1f2d76984001811,
+            ])),
+            Felt::new(BigInteger256([
+                0x6c9577852d010eb5,
+                0xe616aa919b87b804,
+                0x7fecb6670c1c5e8f,
+                0x8e0ccd553f41b974,
+            ])),
+            Felt::new(BigInteger256([
+                0x886bfd90a44a1077,
+                0xda0817103dcb6f97,
+                0x4d1e138652c799a3,
+                0xd5d6bc71b8ff159b,
+            ])),
+            Felt::new(BigInteger256([
+                0x975c3e2a17c63654,
+                0xad67e35bb23198ab,
+                0x09e4996178ff08fa,
+                0x9a5daa072f777e82,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x97bdafaebc2e19ba,
+                0x72f076e01c008179,
+                0x223bd04a7c9f8390,
+                0xaa41445ee052bca8,
+            ])),
+            Felt::new(BigInteger256([
+                0xe66bfed1bbdf6dd6,
+                0xbec7e30bf160fdb1,
+                0x8afbead86643bace,
+                0x4b27e483635012bb,
+            ])),
+            Felt::new(BigInteger256([
+                0xa0c15d9ca559e4e7,
+                0x03d1cff013825760,
+                0x38eefe574a5f5ac3,
+                0x61c3e0476470f84e,
+            ])),
+            Felt::new(BigInteger256([
+                0x27a8c02129b4f3bb,
+                0xb0eb937e5ba428a4,
+                0x1af6d4690433f0a0,
+                0x87ebda04aefac3b7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x6f6802aa0057dad6,
+                0x91dc43a45d77b263,
+                0xb1726ba16517b45d,
+                0x11278aff997db83e,
+            ])),
+            Felt::new(BigInteger256([
+                0xd894c76b73d4de65,
+                0x4a2d9a3db651bbf3,
+                0x61929b2c55e0df19,
+                0xe1abf17474f741e6,
+            ])),
+            Felt::new(BigInteger256([
+                0x0d0346d76dd757e1,
+                0xbbfe5903d2e62291,
+                0x7342a96323b746d1,
+                0xa8fc14048be6675b,
+            ])),
+            Felt::new(BigInteger256([
+                0x5f328dfa1fd1d5a9,
+                0xe2de976deb3a457e,
+                0x059c9835bc317631,
+                0x2dd590da7717b95f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x415c340a824dfe34,
+                0xa9848cb7a01c6d95,
+                0xaea72237f10f7956,
+                0x5570853e008ac495,
+            ])),
+            Felt::new(BigInteger256([
+                0xf75b89f3f429bf4e,
+                0x9706bc2065bce3bd,
+                0x910f2e06c064fee5,
+                0xec436d00acb72d6d,
+            ])),
+            Felt::new(BigInteger256([
+                0x18df1b490f8c7ad2,
+                0x375160b56eb845d3,
+                0x10318dd5ff285638,
+                0x1e1f00374487236c,
+            ])),
+            Felt::new(BigInteger256([
+                0x9d50027bbc1d79dd,
+                0xbc462012cec59f89,
+                0x89c8fe40d6bc77a8,
+                0xb7b53d88037e0476,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x614d28ab6c785b0b,
+                0x337c623fec0a337e,
+                0x792d9cdba21beecc,
+                0x2a3759d680a8c3fa,
+            ])),
+            Felt::new(BigInteger256([
+                0x3d94c7f4467028d0,
+                0x8e2462155ad383c8,
+                0xf9434e72232cee4b,
+                0x124cdecf4a9984ed,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x661c8f8f543cabd9,
+                0xf0c6e46cb061496d,
+                0xf03292feaa4c6130,
+                0x1a896fec06d32ea2,
+            ])),
+            Felt::new(BigInteger256([
+                0xed0146800117ce25,
+                0x22b9ccc0f20bc314,
+                0xcd4bf2c536b24ba6,
+                0x5ce31f23255ede04,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x89186466819dd5a0,
+                0x5ddc50d63f7098d2,
+                0x602ea8e72be5f3d6,
+                0xd377b5c3970948cd,
+            ])),
+            Felt::new(BigInteger256([
+                0x98724197c7f73aff,
+                0xf337a78e34809aa8,
+                0x28a42e043ffb4388,
+                0x39949f23e74f6ee2,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xb038c8a7ccbb6d59,
+                0x62789eeb3e9c8eec,
+                0x287ed3aade424d36,
+                0x8d74df6406a167f0,
+            ])),
+            Felt::new(BigInteger256([
+                0x5e0d8434247a8819,
+                0x20d56cd4eb925ad9,
+                0x1a566e0642c04227,
+                0x2bfe3324fdf781b9,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x6d049952e43df4c3,
+                0x4e9f299d3e022a72,
+                0x25584db6175204e4,
+                0x7f8a577431bb11e0,
+            ])),
+            Felt::new(BigInteger256([
+                0xc0a74ba30c805406,
+                0xe8c279104c73cc41,
+                0x8e4439f623183651,
+                0x26265158bc0e3d40,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5f3aa815d1552432,
+                0x853d540763de519d,
+                0x50ec3dc4bf848b1c,
+                0x225cf03238795fc5,
+            ])),
+            Felt::new(BigInteger256([
+                0xe49ec36adb710fd5,
+                0xdbabce6270c7e0ee,
+                0x43ca503352829cfe,
+                0x0589d533f382c8f4,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x43471df2b52ce9e8,
+                0x82547ffac354dfa3,
+                0x4fcaa3dd185f00dd,
+                0x277d70ac406d241d,
+            ])),
+            Felt::new(BigInteger256([
+                0x21c0436a33d63f26,
+                0x061509a8deff3d75,
+                0x0f23e61fdbc86310,
+                0xa770d385a605b711,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x84e7d174df5eb947,
+                0x5acf0ae6e5b1cad0,
+                0xecda87f162179c3d,
+                0xc0ca0f973af18a10,
+            ])),
+            Felt::new(BigInteger256([
+                0x3115a8fdff7db165,
+                0x64c5a66bd2c7d121,
+                0xccfe1b7dc267167a,
+                0x0e192ff7f02b86b8,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x95f43e498d5a19ab,
+                0x762628969afa7152,
+                0x8ccd5b18e3777933,
+                0xb8536b447c93de46,
+            ])),
+            Felt::new(BigInteger256([
+                0xd2200d7cde2d3d7c,
+                0x6308b68a09923992,
+                0xf8a4c58b9a7885a0,
+                0x10eaead87eb2b497,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x784e2d08a9f045f5,
+                0x0534f763fcc5eeb7,
+                0xee0beffeec507a1f,
+                0x793049dd66cc4608,
+            ])),
+            Felt::new(BigInteger256([
+                0xc69aa122cc64d1ab,
+                0x840e8f87137b703b,
+                0x123b85b36908497d,
+                0x8c14b6e20ce8f42a,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0xaa0767594b1ee1b5,
+                0x19123cbf93f2bbcb,
+                0x7fb2f9a8764b074e,
+                0x6b3edcaa0133b183,
+            ])),
+            Felt::new(BigInteger256([
+                0x7e6ae5a022c8d2c8,
+                0x41926a35d57984e2,
+                0xfc20bee912f6e59b,
+                0x6272e1d7163f1eca,
+            ])),
+            Felt::new(BigInteger256([
+                0x6bf3f94d5f60b74d,
+                0x10783b5acb7fa329,
+                0x6d41f5126ce898fe,
+                0x73d3b35886d239f4,
+            ])),
+            Felt::new(BigInteger256([
+                0x25fa5b9ff8b1ffe0,
+                0x7cd4d091698b9ff2,
+                0x0a14e84141e665dd,
+                0x0a6df80dab700fb1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xcdb52a4512fdae2b,
+                0xf7250f4479e7afe1,
+                0x5479246da6ceae73,
+                0x03fe3a7a434114b9,
+            ])),
+            Felt::new(BigInteger256([
+                0x30f96f7169c7ade0,
+                0x409fb2388e8aaedc,
+                0x9c90a3197028759e,
+                0x8a72d0e630c5e704,
+            ])),
+            Felt::new(BigInteger256([
+                0x7551e31cc83982c6,
+                0xea37239cc6a89125,
+                0xb0d996ff66d32aad,
+                0x045f3ae9b74815f0,
+            ])),
+            Felt::new(BigInteger256([
+                0x8e5cb8168a3a254e,
+                0x933c04ad5384ed8f,
+                0x0d99d1045e621a37,
+                0x6ff14c87eefc739b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb7029ac1077ba89b,
+                0x3db0138310d44356,
+                0xf5de95cf119c5ed6,
+                0x0c89c0c91f9456cc,
+            ])),
+            Felt::new(BigInteger256([
+                0x2580e574b8219e5f,
+                0x9391de79d7806e7d,
+                0xb6b2d896ada0445e,
+                0xd8d18eb188e6ce19,
+            ])),
+            Felt::new(BigInteger256([
+                0x73f4a6a9817fb1f5,
+                0x26c05193feb65006,
+                0x45b1f262a8be8884,
+                0xa787eeeb5ba0d64a,
+            ])),
+            Felt::new(BigInteger256([
+                0x63d16e88302830fc,
+                0x9ddb580bf0b98671,
+                0x77b0473452d98fd7,
+                0x9da57a2d7902aca4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xacf2600adb2612f3,
+                0x07c0023cc2dfa17f,
+                0xa510fc7dca99f685,
+                0x4cd1463491963832,
+            ])),
+            Felt::new(BigInteger256([
+                0x8de03481c2e4abe8,
+                0xae31fbe55aa3f0a1,
+                0x57cd7b830ca4d2c0,
+                0xf6d4bfa31fcb255c,
+            ])),
+            Felt::new(BigInteger256([
+                0x2d7350f2518d274c,
+                0x3880c9f213a3e0dc,
+                0xde57b9ec487b86e8,
+                0x6c9b34e3cc601470,
+            ])),
+            Felt::new(BigInteger256([
+                0x2faeca6349415009,
+                0xd581b241a14b2d30,
+                0x8e649e573ddc687a,
+                0x5184065cb565700a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd1afae6e901e655c,
+                0xdce56fda372924a6,
+                0xe44ea0d309630e2e,
+                0x130037ba656c92ba,
+            ])),
+            Felt::new(BigInteger256([
+                0x118ad3e6449ab60b,
+                0xe25bc34094351c5c,
+                0x5c0596be4377ee10,
+                0xebffb85eed7dc1af,
+            ])),
+            Felt::new(BigInteger256([
+                0x54a4b0bcce3cf5a9,
+                0x99af0f9f9f3d6561,
+                0xfb9b3f2162252c60,
+                0xe058277febaeb610,
+            ])),
+            Felt::new(BigInteger256([
+                0x52ab8f6060d2cbdb,
+                0x4cd4303b4c3b3c52,
+                0x2ecf8aaea28093d2,
+                0x3f54b31a62bfa6da,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xef7e102137150a41,
+                0x1117b4620a58bfc7,
+                0x1fa43cd8df27212f,
+                0x535b82cf8cdeef87,
+            ])),
+            Felt::new(BigInteger256([
+                0xfb12f3b2997d3679,
+                0x12633d6ea5b81dc3,
+                0xf13ed4aa1ab7a684,
+                0x82b1462152b0711d,
+            ])),
+            Felt::new(BigInteger256([
+                0x087bc467dbe8d170,
+                0x3be256d48ed6aa28,
+                0x523e8ab4338c3ff1,
+                0x3383af84bcdaf8d3,
+            ])),
+            Felt::new(BigInteger256([
+                0xebd08dadbf08485a,
+                0xc2678961ede2b877,
+                0x0e0b1ce7500b4ccb,
+                0x0593b376b78fa681,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x9ee1f09fb2e883db,
+            0xc1a0c45546ddb746,
+            0x7270eb4dc548dd17,
+            0x3c8438a5cb4248e8,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x531dd60f555479fe,
+            0x1380b12da26d0c82,
+            0xbd7e85c3e0feacd7,
+            0x776c8f0f2c320ca7,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x218aa5ff49951470,
+            0x5113f86473f1337b,
+            0x88d2d6eb6be1375f,
+            0x0d0c54e77e58b7af,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0e464cdbf135f572,
+            0x834e0bc02a2ee9c6,
+            0x42d541b121028f5d,
+            0xb97312890498e9a9,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4ad6dfe586f35ea8,
+            0x6e984ca5dcfc6ab8,
+            0x2f09a15b10b6c954,
+            0x57faa18de3d3d981,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x66fddca7c5738ae4,
+            0x1926878f53718f66,
+            0x8b5c8a704d167885,
+            0xf3ec63ea705cf0a9,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x13a6ea68085929a9,
+            0x9d9aefdb2375d330,
+            0xd87fe4781eeebc2f,
+            0x3388fd0d80463208,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xeabfc79eef9429c7,
+            0x54363a3d6d628e70,
+            0x77ad441d82e9c2fd,
+            0x713527022929f46b,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa20ef94b87991cee,
+            0x6d10bf5e700901e9,
+            0xf68c5d8c68ade5a7,
+            0x3768a5a66d20a0d7,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9c42100fb3d7deee,
+            0x1377f5246f9b4215,
+            0x4b20e432f82486a6,
+            0x43d220d162c6fef9,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+    }
+}
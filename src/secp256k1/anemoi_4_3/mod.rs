@@ -0,0 +1,961 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xb6d5252340864bfb,
+                    0xd353310b24d97983,
+                    0x6e6648ed0b89b074,
+                    0xcae16e7fc6b9f844,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc99ff1c62b4003fe,
+                    0xaa25ab93885cc247,
+                    0x4552430d64682466,
+                    0x6c239170960d39b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa34fb3eba243977e,
+                    0xa2db9823b0b02ad5,
+                    0x8cef5628ed52bc9d,
+                    0x7a39c1f901eaf203,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61eb78e5ace5e75a,
+                    0x5ef071741c68ddd4,
+                    0x331767d67f5d0eff,
+                    0x2470ae22180bde59,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xce6ace0f789ac5c4,
+                    0x81b2f25252a35609,
+                    0x89336885c2c1da9e,
+                    0x14dbe5aaf62d0982,
+                ])),
+                Felt::new(BigInteger256([
+                    0x416ad2b9b85d8237,
+                    0x9f41c146dff95920,
+                    0xc6e50e0427f27449,
+                    0x6b78b4de95e0615a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6a343c2502a8119f,
+                    0x90557fd09739bc42,
+                    0xecef34ba5771fb77,
+                    0xfb3a9daa6c666696,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1ab98364d4db023,
+                    0xa2c52c77d765ccad,
+                    0x247e9d70a90b253d,
+                    0x663cec135254335a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0d6a3af7f9e0cdef,
+                    0x929e5790ba9ba24c,
+                    0x3b76a3fd8da509c0,
+                    0xc0d4b8e0677d8b90,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0af05bb0189e1986,
+                    0x8b99bc811b90867d,
+                    0x7b3465476851629f,
+                    0x52486d6c9c528921,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4928f981797f6aed,
+                    0x1bff7a2536b8c4b0,
+                    0x6c4ebc2d00bd17d3,
+                    0xefab51eeb4e04cac,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa078aef27f93886a,
+                    0xa1bb72788841c45a,
+                    0x0ed213264928b4b0,
+                    0x787ff517631b4686,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x90fae3e72ee0f794,
+                    0xad3cc744ed53af7c,
+                    0xe1b69bc7e682d4f9,
+                    0xe9ef16ae9c7781fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0xca41816ab49463c3,
+                    0x4ea0986ac283c049,
+                    0x6752cb2264990a55,
+                    0x40c3735799557705,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0efd48f960414714,
+                    0xd420495852e3c9b1,
+                    0xc4ca5225a3e42bbd,
+                    0x797a16a106f7a0ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff051343331e424a,
+                    0xeef9371a0ee63c90,
+                    0x82e6c8ad14bd2fc6,
+                    0x55d13d59198a10e3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x51bac1ef559dee72,
+                    0xa44d157d50d470bd,
+                    0xe6c0659b5e39dd13,
+                    0xa6f2b55c8ca1b616,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb54116bf0b6d5069,
+                    0xdce936f4876f004c,
+                    0x65713dad483380d6,
+                    0x5bce85c666f7473a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe91f35fd2540d42a,
+                    0xf5b6d5d8fc274c2f,
+                    0xbacf4f4e6de48069,
+                    0x72d9b0fdd57fb2da,
+                ])),
+                Felt::new(BigInteger256([
+                    0xad7f7eace06eaabb,
+                    0x761bde883b9c6443,
+                    0xd674913d3cf82925,
+                    0x00a8e54eeb4dd12c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfcc2bd9ff0d3aa7b,
+                    0xe6531d2ebe12ab27,
+                    0xccae04bcc85e8737,
+                    0xeadc575cc53c5eba,
+                ])),
+                Felt::new(BigInteger256([
+                    0xee33ac26200f9fb1,
+                    0x4d0b835a31eadc84,
+                    0xc5ad1852f7e1be6d,
+                    0x4ab92a268fbfcf30,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13b248698ce2af79,
+                    0x82f91ddb457bf343,
+                    0xb98e609755348e39,
+                    0x0830200d09869215,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9ed180e1c417e27d,
+                    0x4f738897526cb09c,
+                    0xc213ab64e3dbb4b6,
+                    0x7213e9c889c9bc90,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x87cd0e951c296993,
+                    0xfba517ad9e9a21ff,
+                    0xb9bad28d478d0955,
+                    0x03303a50ef9a4a88,
+                ])),
+                Felt::new(BigInteger256([
+                    0xede594d6209178c6,
+                    0x1bb227e272756f92,
+                    0x4b05015da82e58c1,
+                    0x4b158e929b77cabe,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf86e68a046f888c9,
+                    0x286cb04469f957d6,
+                    0x1878ba9fe5df7ddd,
+                    0x5effb4adde240888,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8e39d224ee01a9f3,
+                    0x20aff3ec1209ed5f,
+                    0x160a43456275ad2b,
+                    0xfa6c33e6055ff024,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd4aa68dd98763516,
+                    0x8f11fd4ddf57e588,
+                    0xc43a337755812b05,
+                    0xfcf2d95ba9bdd6e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8228f3754e2af286,
+                    0xb7e77f3815138fa0,
+                    0xf816076861e9ff3b,
+                    0xb053d36acc0939b3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc1262b51e9443f50,
+                    0x28624bd3535405a4,
+                    0x423117e4c0786c09,
+                    0x8d9a69483f61ae00,
+                ])),
+                Felt::new(BigInteger256([
+                    0x108720892f8f6315,
+                    0xc29865bd35dd0be1,
+                    0x2631db3993f2db9c,
+                    0xc770f54501508cc3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x89df477aace826eb,
+                    0x31505ca76c31b8ce,
+                    0xfbaafaf60a849ca2,
+                    0x33bca19c740bc269,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd1fad8a05d7167d5,
+                    0xaacae650f94d17bd,
+                    0xa9f37ad69dab8c6e,
+                    0x1946f8bb98337004,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcbde1c8b7e1e7c3a,
+                    0x7963c3852a4f81d5,
+                    0x810b633cf3403734,
+                    0xd7b923b97c878270,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc100e658cb5643f9,
+                    0x033cede3530f053e,
+                    0x49f3fb6c23764c93,
+                    0xe4f8f817b45c772e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7ddaf37c23429ca2,
+                    0xc7877757f5aa46e1,
+                    0x18f2000d0882c7cb,
+                    0x08d3ae1770f86416,
+                ])),
+                Felt::new(BigInteger256([
+                    0x40c193c8e52c4155,
+                    0x1be61de7c604ddee,
+                    0x254070de864d1f72,
+                    0x76b39d5cd0f5fb8b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x57f1d24629d2d1cc,
+                    0x378235624c7cf2e1,
+                    0x1ca83e5d208ff486,
+                    0x973c1280123c175d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xccf523f3c9b662bc,
+                    0x1a2f1fa1c6e57e07,
+                    0x7a4c8714404a777e,
+                    0x20bab92ea6cc7727,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb168bb49ef5d0e0a,
+                    0x1de016a9b446b39b,
+                    0x0f50e6f1f376194b,
+                    0x63a9fe2c627c414f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x475608c479185307,
+                    0xeb1e0fbcf1b0c667,
+                    0x6e126447ee9ab345,
+                    0x8eea3bc250bc4a83,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd0b39eeb2e73b186,
+                    0x69a46e0706d62060,
+                    0xa4b1fd4558958d9d,
+                    0xd075225bc07e117e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x59a9c87fcf1d0fe6,
+                    0x39e745039ec009dc,
+                    0xede5069fe6a7c13c,
+                    0xa82d36e9e7f7a352,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf28df19484832e4f,
+                    0x8865bc610a41e320,
+                    0x6b9201e7b10eb8b4,
+                    0xf762a19d9d49fa65,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37c10e7fdd8b9468,
+                    0xf8f8a1eef711f566,
+                    0xe8bd606ba0f51c0c,
+                    0xf79c917231316e7b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcaf43e1f7e3f53e2,
+                    0xf61dd62c6ac55127,
+                    0x85648df6c717e63c,
+                    0x643ca77b1501a45b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b516583fd63e07a,
+                    0x5033bdc10ef09aad,
+                    0xe33eeb4a17cd2177,
+                    0x9e10e3fd2fc655a8,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xc7220997f0e1a6e3,
+                    0x13334925cbcf122a,
+                    0xc47e69e0a91fa22e,
+                    0x48502d6466f505f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18f705eac6b612c1,
+                    0xdd81abf7cc9633bb,
+                    0x437d5a085a20f570,
+                    0xaafad32214a01503,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd88590e6dd2f85e5,
+                    0x88bf30c4c635f3c8,
+                    0x4fc951e0786830cb,
+                    0xcad200690755c5ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa64f17ab37fc9f64,
+                    0x31a66ed333542836,
+                    0xfe9a0da1f770008a,
+                    0x3974454adb48e60c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x002e093c708067cb,
+                    0x30c0aa1983f9eb32,
+                    0xbd477f3d5a1f94d5,
+                    0x0dddb626d8bd1c64,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5c612a0083588c80,
+                    0xa9109fba6b71cb1f,
+                    0xb932c38f5258ffe0,
+                    0xa9613e267dfc3265,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ea9b160340e65a1,
+                    0x3f5d85b95f4495d1,
+                    0x1b30aa78cc54776e,
+                    0x4a0da6a03a419be7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x932968bf3d21690f,
+                    0xfacffea5ae28b8a1,
+                    0xe52dcdea7513464a,
+                    0xa4c13d9b4744c736,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3e86a9030c1c2e9f,
+                    0x68517083ac32a517,
+                    0x0a6a391b822006ee,
+                    0x294acfd604b06d9e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa834cd2cfb8f3570,
+                    0x6a4a82d1ec7cf88c,
+                    0x81434e94cf0bd789,
+                    0x844798578c54f142,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa506e7fb587f1a0e,
+                    0xe7455b980f9def4e,
+                    0x2744f9b303d617b3,
+                    0x6960fc151648ac7e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9204db7a2fd48a67,
+                    0x2a30cc59e2accc0f,
+                    0x65c77646f173d911,
+                    0x052c7906f41a4acd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xdc1ba26c73cac7aa,
+                    0xa9201f3f14430cb3,
+                    0xf0bcf3550135dcd8,
+                    0x6c8ad4cc056b017a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9bc3f0c4b1fbd6e9,
+                    0x58bc527a4de63279,
+                    0xbf7f1d04c245679d,
+                    0x1b6cb1e8abfbcc77,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1d2ee8b14b8354de,
+                    0xdd94ccea6b4c671d,
+                    0x4bd7ad9a65a4292c,
+                    0x396943f50593a8ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa8198f448a21eddb,
+                    0x035ce1e0741e7b8e,
+                    0x0d772263dac05721,
+                    0xb6ac09512da62b4a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x63459dfa427b8ffd,
+                    0xc37032a74e9a59f0,
+                    0xacec4563157cd624,
+                    0x4614c98a55c09fdc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb37d66fb629892ca,
+                    0x492f64f3d97265dd,
+                    0x2326e4c80361a143,
+                    0xe1745675cf2d189e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1771d79de6403b12,
+                    0xa3059c82315246d5,
+                    0x3e6fee58176268d2,
+                    0x72215fe71debb6cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b5cf4af67046e95,
+                    0x74739dfb7d22109f,
+                    0x60ba2cea747df858,
+                    0xac72e0e1f4fab98b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x862e10e83edc9e9d,
+                    0xfa573ce626fc85e6,
+                    0xa125ac4251b84d3b,
+                    0x971723da5d0bb7bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb56c6c30e55c8be4,
+                    0xb343519703a60977,
+                    0x9537ac01b95da475,
+                    0xe25eb7fc490043af,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa57575c1214e95d,
+                    0x32575839fe066f2a,
+                    0xd1b01d9248f5153b,
+                    0x0ab9bc28763b0af3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x187dbb84ee405dbf,
+                    0x859fdccdc73a5dd0,
+                    0xac03df52dcae5e0d,
+                    0x709f6eae7916803d,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x12071b5a4503e6c8,
+                    0xabb84d0d3191ad5c,
+                    0x8ef677f9b7828280,
+                    0x4940a6caa4d544fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f0c57fa95c1caea,
+                    0xe0aa931f614b3bcf,
+                    0xf060c1f580a87cf2,
+                    0x86bcc782031fe3fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fdfca61cf8b38b5,
+                    0xcbe4012185f60391,
+                    0xedf6034360a892ec,
+                    0x99ea4685f14a37d4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6824f00e4bd137a6,
+                    0xec6b34295817fe7c,
+                    0x19ab5baa9a61e991,
+                    0x9890d3fadb346d6c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1551873ffa8a14ed,
+                    0x2bf28948c64f4c90,
+                    0xe8dfc9eb512a9477,
+                    0x0a01709a52b1b395,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9c55bfc072f6cb47,
+                    0x2ce83b94be5fb0cf,
+                    0x73d2215145d8bd46,
+                    0xc7658ff576114d27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3f267ce0d94c9dc3,
+                    0xb8e88fd1cbf67a16,
+                    0x36bfcd54da10ac95,
+                    0x82ea317bf6099aec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c1d2803bff442bb,
+                    0x6a17352ec3280414,
+                    0xbf7012775a867d2f,
+                    0xd2cc3b141c5e6cab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3725108afec9d2c0,
+                    0xa730f8f971a98ebd,
+                    0x8e3424d9ef438d8a,
+                    0xb62198dca9af4165,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4da3fecff7ecb552,
+                    0x5fdd6dbe4179a4c4,
+                    0x2bdfbd229cd68029,
+                    0xa6ac62ed8962b573,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8119936d3951dc62,
+                    0xe000df22118699fb,
+                    0xdb96635ffcf6202c,
+                    0x414f6d4636f45047,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2853a2440474b305,
+                    0x8747f8fe4431bd41,
+                    0xba0823d2fab8783a,
+                    0x2d4f43e7bb259d55,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaf6774ba89be4c65,
+                    0xb35516adfdf5a420,
+                    0x2f3a4a63480613b0,
+                    0xbed0ea86095e66e2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa9fa4ef64f36c3ba,
+                    0x72bb968447c71edb,
+                    0x4d2dfc2e9a57a2af,
+                    0x57df717ac817011e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffa649596cabf046,
+                    0x9c1b489fb603b0e5,
+                    0xf0fe2b330bacd2a7,
+                    0x62e7d5303e612611,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c21c4be91872981,
+                    0xb1e6a6c98d5779cf,
+                    0x1ed22f3388aaa123,
+                    0x6220c385c0b71b23,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7dbdd2ee6a454ffd,
+                    0x9efe6182daf18b89,
+                    0x1660f3bb1fa1b9ee,
+                    0xea71ccebc347e9b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2cb6dfc9a1688e34,
+                    0x262a897c6a47087a,
+                    0x6649bff96246cf0f,
+                    0xa0c9bd391904d5c3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe1b27b8b19c5276d,
+                    0x5d8473821118e51e,
+                    0x1c09f7f2baa532d0,
+                    0x02d700974ebdddf0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc894a3f338fb159,
+                    0xbb92f708649cf631,
+                    0x928dd63047520143,
+                    0x7aa661ad0a25509c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa673557deef24dbc,
+                    0x142131ab31eea24d,
+                    0x60ccb0477dd13a9d,
+                    0x3e334bcf380c82cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa8c66cabb23378e9,
+                    0xefa6e6989971f060,
+                    0xb79dbcd832d1544c,
+                    0x9cf89b69f125cc14,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1783c199247f19d6,
+                    0x1ca5e57bc14dab50,
+                    0x21143809b78d9dbf,
+                    0x90cca327dbc7a119,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0e29c287f923ab0,
+                    0x884908ad41ef711a,
+                    0x34ecc5af6f9dee78,
+                    0xbd1fa5a00991ee3f,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
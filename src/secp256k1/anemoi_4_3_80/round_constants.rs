@@ -0,0 +1,291 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x0000002300008593,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0xc759ab3b33c7f9b6,
+            0x1a2e82d7ce979427,
+            0x390bf16667c443b7,
+            0x206a06ea519624fa,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x36e9d41e0a529925,
+            0x3ce4e373e3338ab4,
+            0xe0d5b70dc27ef66b,
+            0xd568ac692b332d54,
+        ])),
+        Felt::new(BigInteger256([
+            0xecf27193e10b15cd,
+            0x3bd58c9cad01ebf4,
+            0x1e37005f634ec584,
+            0xf6e2011c3d25999d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x88dfef1c2b006d22,
+            0x2d3f03efe6c22223,
+            0xcb6ffb67274f1513,
+            0xc954145cbe871688,
+        ])),
+        Felt::new(BigInteger256([
+            0x27880225dcba54a9,
+            0x0dd4734af814523c,
+            0x9235f5e1ce5177d3,
+            0x1ea43e000ac2eef0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x66561d21fd5b5a56,
+            0xb0d4ddba9d8459bf,
+            0x479bf57319de8030,
+            0x8da7b38283d9e362,
+        ])),
+        Felt::new(BigInteger256([
+            0x6368ff7cf3cb2286,
+            0x45752631fe5a1664,
+            0xae7dab3ba08e20bf,
+            0xd9ea4126dc384569,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x58f22d6947d4e0e0,
+            0xf36ad2fb07d2d517,
+            0x151c5c6499c72abc,
+            0x1dabc8658671b9a5,
+        ])),
+        Felt::new(BigInteger256([
+            0xe4156510e38a844b,
+            0x02e8d98399eef919,
+            0x77969747b0cc4741,
+            0xaaa05d7fa2143a24,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8bfe0a699487da57,
+            0x96a2a2e68d5d69cd,
+            0xe5d6078b3d92f92b,
+            0xeb9fe7b9678996b2,
+        ])),
+        Felt::new(BigInteger256([
+            0xc718f1882e487442,
+            0xc34ec759d19d2720,
+            0x406e067fc70b6cee,
+            0x8e0ba0ce9f11bf4d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9e3c5ebf3ff57390,
+            0xd32cee23e992b308,
+            0x7e750739aef968c1,
+            0x8be5560f8b223cd8,
+        ])),
+        Felt::new(BigInteger256([
+            0x7e16df8ffc53e754,
+            0x22fe83c05a677ff1,
+            0xb211448a437ce05a,
+            0x729d0ceaff905e00,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9dbcc6e9e15e16b0,
+            0xb1883138228b13dd,
+            0x2bb79af062c2269a,
+            0xb68978ee9ca4eac5,
+        ])),
+        Felt::new(BigInteger256([
+            0x90afc95d74e94e0f,
+            0x0980e79242832160,
+            0xce0067c45ecdaa91,
+            0x883d962b06c755b2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x82deaeb5dabe0c68,
+            0x6376261e0c6aa77c,
+            0xe9c9169c5512100f,
+            0xcc39dc1d8b74fd6e,
+        ])),
+        Felt::new(BigInteger256([
+            0x62437a1e964b091a,
+            0x54cd922bfa409042,
+            0x167e79f70b3820ba,
+            0x57a46b917f2ff1c2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4da2622849cadbf9,
+            0xd2a2d2e1657e00d6,
+            0x7e8818adee48f8f3,
+            0x5106cbb7435e6d67,
+        ])),
+        Felt::new(BigInteger256([
+            0x8bbc83ef8435eca3,
+            0x1e5dc61a2f2842e3,
+            0x9043aa12508c07c8,
+            0x03759b950510557e,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x000000235555dc2e,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0x0f42d4c513066487,
+            0x6e4f6668e5f35b62,
+            0x39507628c0873840,
+            0xdf41b7838734dcc0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfcbfba6d5f75e3bb,
+            0x62a10c2b44ffc651,
+            0x3dfaec31a98697d8,
+            0x9c8508cb712a127e,
+        ])),
+        Felt::new(BigInteger256([
+            0xfab1816dc017786a,
+            0xb5b298e52629eecc,
+            0x7ba0ba45a3195b7a,
+            0x7cd60e17b8bb368c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeb110ca6e75c886f,
+            0x35d4ef75c2151bef,
+            0x2858de8f68edeb33,
+            0xb506bb44f8918867,
+        ])),
+        Felt::new(BigInteger256([
+            0xd1a2493a22ff842c,
+            0x6a8b4261eac31342,
+            0xef635dcc68b3427c,
+            0xc92e95817a6c1894,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbd98710e46abceac,
+            0x2b19936744ee6cdf,
+            0x275a4cf9764c7e1c,
+            0xff109da33f819c55,
+        ])),
+        Felt::new(BigInteger256([
+            0x02947cf4c704b2b4,
+            0x13dabf6fbd1ff0bf,
+            0x8e80878455bf1334,
+            0x0a2adbe0cd7eb622,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x61c752e75c3f7dc6,
+            0x2f3f35d3f75e77d7,
+            0x709a81841e71f824,
+            0x8553ebf7c5c93d59,
+        ])),
+        Felt::new(BigInteger256([
+            0x34d3b41981de3938,
+            0x92de1feda0d66314,
+            0xd35941298e3a0931,
+            0xd12031ab170a759e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x82f13b39eb4619a9,
+            0xf0c6ceba95043ce9,
+            0x73125db9bbccc4f8,
+            0x3fa18bca4a5744fc,
+        ])),
+        Felt::new(BigInteger256([
+            0x05f54be20eefc7ca,
+            0x7193d6bef09fc177,
+            0xcdeee1709e082d45,
+            0xa0e4f578b77e255c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf5d6bce21fa375e5,
+            0x714ac049354b1ebf,
+            0x1cd274554ea19b99,
+            0x22111d8a37468ba2,
+        ])),
+        Felt::new(BigInteger256([
+            0x1d9a673c65eafddf,
+            0x153d3976bd7bb2e3,
+            0x50b336683be807bb,
+            0xc7a084fee1536490,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb82e9d0743f9d5ba,
+            0x1a84c7de305ecfe8,
+            0x60817c13836e5f9f,
+            0x217323e780169d97,
+        ])),
+        Felt::new(BigInteger256([
+            0xf30ac904616e214f,
+            0xc69e61c967b2a4a5,
+            0x030ecda9d83cd81e,
+            0xb1fef1bd1fd7c04b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd555c1c83d1334ca,
+            0x5ee11595665b02f8,
+            0x449a2264d4fc17b6,
+            0x7037706b98b02e1a,
+        ])),
+        Felt::new(BigInteger256([
+            0xfca3b6ba828945b2,
+            0xa45965346b8cb2f8,
+            0x71940a81e3e51cea,
+            0xba79b078c209da33,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x62b8449e875db8c4,
+            0x0ebfb27e0eae52a2,
+            0xd4de5922fde01b7e,
+            0x1622e6d42e7a09ae,
+        ])),
+        Felt::new(BigInteger256([
+            0xe8bb8fef4bb1dda4,
+            0xae9b8947efb45be9,
+            0xe6de6f49b8e61edb,
+            0x8769674b25caa98b,
+        ])),
+    ],
+];
@@ -0,0 +1,1358 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger256([
+            0x6221822039946b0e,
+            0x86b8ef1238c025ca,
+            0x72a5b64fef766d7f,
+            0x788c8069450d3f94,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0x0acff612ba71c120,
+                0x158def516ae96d7f,
+                0x39c2b17aa5836fcc,
+                0xa9d46c1e2ed57b25,
+            ])),
+            Felt::new(BigInteger256([
+                0x0eff52e0cc9c7f77,
+                0xfdca6344503f06a7,
+                0xdbcac6f497a5808d,
+                0x123934071c46e933,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xa9f24f944dc8df8b,
+                0xeb4164e08208feb8,
+                0xd8ecdfc47c525c95,
+                0x14da9e4135655abd,
+            ])),
+            Felt::new(BigInteger256([
+                0x437d12e5601e7398,
+                0x68c883b1352961a0,
+                0x86285826d53be02c,
+                0x9a79fa9f06c7de32,
+            ])),
+            Felt::new(BigInteger256([
+                0x4dd1305a15d0e404,
+                0xb80725c294e08c2f,
+                0xafb3f72f09032b3e,
+                0x2a4765b5d8029f43,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x54f830b3e7447cd6,
+                0xb4a24cb49979a076,
+                0xb33f6adff3916712,
+                0x2448fe6cb91e1746,
+            ])),
+            Felt::new(BigInteger256([
+                0x49f1ffe301020bf0,
+                0xd4b50b9afcbb0ed6,
+                0xbc4c4028e3bb28f6,
+                0x9e7cf7586e6f1f55,
+            ])),
+            Felt::new(BigInteger256([
+                0x7b5a113953e08109,
+                0x67c5d4c392c3e104,
+                0x88fdd1447208d36d,
+                0x563d6d0f7808ac91,
+            ])),
+            Felt::new(BigInteger256([
+                0x97528cad13e45637,
+                0x7c67e5bfe8f685ec,
+                0xab6476938d3736a1,
+                0x2002802911808ef4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x29d5fd4f727b6f5a,
+                0x3a8570108eef337d,
+                0x65d03e1656fca698,
+                0x035c0dcf8efa31ba,
+            ])),
+            Felt::new(BigInteger256([
+                0xfe1a857493dc69e0,
+                0xc4be8bee60bc5f9a,
+                0x5a2bb084c222f86c,
+                0x31d2763179284bc8,
+            ])),
+            Felt::new(BigInteger256([
+                0x9d6fceb79087d9b6,
+                0x7a4224b7e5fe155f,
+                0x899f07bcfd61040e,
+                0xc4e90316339127a4,
+            ])),
+            Felt::new(BigInteger256([
+                0x4847248cadccfd4e,
+                0xc2bb5ae5130e0e9e,
+                0x7b0b78dcfdf48376,
+                0xc875273f3a21b797,
+            ])),
+            Felt::new(BigInteger256([
+                0x9d17a3b760d78787,
+                0x26f57d13ff54836c,
+                0x45d1cde5667d9010,
+                0xad97086145b8899a,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd6892a60934b883b,
+                0xbbb014d564450538,
+                0x27b03416ecfe418e,
+                0xce23095ec6842748,
+            ])),
+            Felt::new(BigInteger256([
+                0x884401ee93389776,
+                0x2c67569f5b762e91,
+                0xa528620618bac821,
+                0x059db351ce3270f2,
+            ])),
+            Felt::new(BigInteger256([
+                0xc8407460dee874fc,
+                0x36add7cb70069f1b,
+                0xde90cbaf6db247ce,
+                0x1845697be7a11c97,
+            ])),
+            Felt::new(BigInteger256([
+                0x729b7c7037a9e7c0,
+                0x3d6eb2d12438c3c1,
+                0xd2f3d9772c49c94f,
+                0x372680875a046caf,
+            ])),
+            Felt::new(BigInteger256([
+                0x98c769c2e2c781a2,
+                0x5fb88648a4d8c8fc,
+                0x58a249455d1ea1b8,
+                0x08cfbbf44b423d20,
+            ])),
+            Felt::new(BigInteger256([
+                0xa76e05125fcaaeee,
+                0xc2f6746b94296109,
+                0x8598a5a1dbe15ace,
+                0x56717524a5c815bc,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x27d3f65041fd6407,
+            0x05d11056507ef95a,
+            0xada81e79e053c975,
+            0x842426ed4641c345,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2fd5c6847aef4565,
+            0x932fa8bd902d44d9,
+            0x936527c815e49436,
+            0x5bfaa7146de4e4f8,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9a1d3a2f884a780c,
+            0x0b12d05cd74349e9,
+            0xe4f1a3017b905c6a,
+            0x6fc090be3cfd7369,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbb884c71675f78e4,
+            0x213210d7ae858ce0,
+            0xaf5f309a47b4b0fe,
+            0x3fb49f061cd4d704,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb7cda783769a7d8a,
+            0x7dd7387677954a37,
+            0xdb53f865ba640aa6,
+            0xc2ef90b35d133294,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb576b0e3b0759503,
+            0x9ec23b15f0669739,
+            0xdc57841e0a8c49df,
+            0x2a044740d552fc6f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8f08b2fd6c169e3d,
+            0x93c75ca9471654fa,
+            0xcc34e9c5256c1a4e,
+            0xfd0b00b94c121d22,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x34b02133fe113296,
+            0xcbe1008f0e5e4616,
+            0xabad4e894760c6ac,
+            0xeb57bc5dd2c27850,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x46d3194ea9a03484,
+            0x27f53e26b3707bdb,
+            0x37323002b985eba5,
+            0xa8cc6f4025a97fc6,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7f3aa19ff5701d2f,
+            0x6091b45ddb07c687,
+            0x66fa57718f1a1a98,
+            0x1a3e6022cae0b678,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x2965d37f4415da16,
+                0xe0f1b0ff983d06ae,
+                0x62ced4a7bf5afa49,
+                0xf14ba9116867d5a6,
+            ])),
+            Felt::new(BigInteger256([
+                0x0f47bd130ce7e4d7,
+                0xe66c5358e14128f4,
+                0x9a13219a7f82e404,
+                0xedb065c140f13699,
+            ])),
+            Felt::new(BigInteger256([
+                0xb15de13ea3e5a0eb,
+                0x28ad0e6dcd7b4d54,
+                0x84f95c725394a857,
+                0xe09f89ccc0278e9a,
+            ])),
+            Felt::new(BigInteger256([
+                0x5c7842b278c4502f,
+                0xe10c2fd1bb8c3ee3,
+                0x913054e2bbd58d14,
+                0x635e60debe7c5697,
+            ])),
+            Felt::new(BigInteger256([
+                0x89cfe2b4697790e3,
+                0xa49c7ac9736b536c,
+                0xbf1efb02261ad36b,
+                0x2c7a8e6c0c76e931,
+            ])),
+            Felt::new(BigInteger256([
+                0xb7bcbf60c633d1fd,
+                0x8a2248588b05871f,
+                0x1c2ca5b81577ad58,
+                0x720d14d86710fef1,
+            ])),
+            Felt::new(BigInteger256([
+                0xcf5baa2cefc90557,
+                0xed3c7dcecd2f0e7b,
+                0x4396f1f884634228,
+                0x67508309b6069a17,
+            ])),
+            Felt::new(BigInteger256([
+                0x42191132edf97494,
+                0x3125894eaa8c64c0,
+                0x31131a9416fdf951,
+                0xe2259d10ae542a1e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x0b1ddbd080d43eb6,
+                0xaeddf61a00c370d2,
+                0xb1e9f6b642bb2257,
+                0xc255c97b767c5f72,
+            ])),
+            Felt::new(BigInteger256([
+                0x54200c48912e6d50,
+                0xa784bbf55c715a07,
+                0xd94ef1e3918277a7,
+                0x0eb97fae0f0a8191,
+            ])),
+            Felt::new(BigInteger256([
+                0x3ed07ce14b0226e0,
+                0x361fb3d9c460f1ab,
+                0xebec857994e36ab2,
+                0xee1bcbd38c971ebd,
+            ])),
+            Felt::new(BigInteger256([
+                0xfa0180b5dcfd3f7c,
+                0x0468c11b69833290,
+                0xa48f9a7bf8763b3d,
+                0xd1dc9dba8ea31ef3,
+            ])),
+            Felt::new(BigInteger256([
+                0x629b9faa5d36db03,
+                0x1c5a518e6b9480e7,
+                0x6679c65072839e4b,
+                0x5f9680e9bd7adb30,
+            ])),
+            Felt::new(BigInteger256([
+                0x9e0f2e7e6fab8bc3,
+                0x340ce54b1c106361,
+                0x29df2ba1c7430bb3,
+                0x5b44bf6da4fdc905,
+            ])),
+            Felt::new(BigInteger256([
+                0x9fbf55cc981367a6,
+                0x2f8fa97d9b62961b,
+                0xa943326963c6f0ff,
+                0xe74e2ee9a6b12b2a,
+            ])),
+            Felt::new(BigInteger256([
+                0x8222b532e38e08a9,
+                0xc422c2722266a3b4,
+                0xc2094ebdd905857c,
+                0x9923ec8e6fa17630,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe1c4ea0ebe6f2735,
+                0x1b3a94678005b497,
+                0xc0d30e868605fd92,
+                0x6c2b850a27093e36,
+            ])),
+            Felt::new(BigInteger256([
+                0xb09a12aaf161ddca,
+                0x50e30f2531c91747,
+                0x80e3999ad0d07c17,
+                0xbaaacf9ae8e07a75,
+            ])),
+            Felt::new(BigInteger256([
+                0xca1bc0c57ceaf50a,
+                0x6d94be4bc110bba3,
+                0x0e75b41521e1fce1,
+                0xa767a5e2e564601b,
+            ])),
+            Felt::new(BigInteger256([
+                0x3f175d1d0cc6172b,
+                0x13cbccbcd830f967,
+                0x0c37b30384c8a020,
+                0x895a7c44035e3b72,
+            ])),
+            Felt::new(BigInteger256([
+                0x6b31c50cb1bd0c42,
+                0x8b633a52aa517d65,
+                0x8dc85d7a63c51ea4,
+                0xc3fa3ae20b3d5eb3,
+            ])),
+            Felt::new(BigInteger256([
+                0x3aeb26a7ddfc61b6,
+                0x28a27b9423f38510,
+                0x62e010496a3be6db,
+                0x83d018383ab3aa1e,
+            ])),
+            Felt::new(BigInteger256([
+                0x9006493d9cea15a0,
+                0x9c9e3bc1bf2c12d9,
+                0xcd2871a9da074f74,
+                0x71ee17abcc2371a1,
+            ])),
+            Felt::new(BigInteger256([
+                0x8204a15203fd58e8,
+                0x1093b73cb47ea27b,
+                0xf37b0bb430f22e9f,
+                0xcec0b8a701fd441f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe901458b389a1590,
+                0x0a9eba544378770d,
+                0xc8ba726200ecf126,
+                0x6d73756535691e46,
+            ])),
+            Felt::new(BigInteger256([
+                0xa0c22c29fbd942c4,
+                0x87ddeea3d56af801,
+                0x96796227247d8929,
+                0x9b150ed49e5f4a09,
+            ])),
+            Felt::new(BigInteger256([
+                0x30b558ca2c6682b8,
+                0x1e459328489bc142,
+                0xfe6b7ee8220d128a,
+                0x5d763f7ee7e94734,
+            ])),
+            Felt::new(BigInteger256([
+                0x2a8fe2bc4cb29769,
+                0x50e1b83cb60910a9,
+                0xd0603d2f58d46398,
+                0x25fc6b1ca5716907,
+            ])),
+            Felt::new(BigInteger256([
+                0x98e9f9c05082f82e,
+                0x5cb1f435ae9a05c4,
+                0x630c49bd15595b08,
+                0x972e81f7637dd600,
+            ])),
+            Felt::new(BigInteger256([
+                0x78bb3838b2b9b2e0,
+                0x1f16076897e3464a,
+                0xb4734c64e29e5047,
+                0xea163a0c6f316b90,
+            ])),
+            Felt::new(BigInteger256([
+                0xdacd2d76e0ab19de,
+                0x04d27dc5376b73e0,
+                0xb50e51f7210d418b,
+                0x8b9c8f032708892d,
+            ])),
+            Felt::new(BigInteger256([
+                0x8dc4eb847fe9ec24,
+                0x3c34da6511ab0747,
+                0xeee15bd034e07e2b,
+                0x43800cda617454f6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x10a55a29ae7c9b30,
+                0x6a654b742fcf840d,
+                0x71442f6967b5977d,
+                0x6e214ac174fdfa78,
+            ])),
+            Felt::new(BigInteger256([
+                0x204f7cd4efd2eafd,
+                0x133129bee8ca6eea,
+                0xa5138e398a3a1d5a,
+                0x3a4ce5d63b328bd3,
+            ])),
+            Felt::new(BigInteger256([
+                0x6bdf3e64d1ce96f4,
+                0x6364032556b7a294,
+                0xe9d796bf2799f335,
+                0x3ad713edf2703fbb,
+            ])),
+            Felt::new(BigInteger256([
+                0x063d2f95ea1bf726,
+                0x12ebe7b734c21851,
+                0x96d7d11db3351df7,
+                0x96cb47eb25b27209,
+            ])),
+            Felt::new(BigInteger256([
+                0x75725ad65ecb2816,
+                0x96e331237665f3c0,
+                0xf1761d0263491ffc,
+                0x8109cc510f711a6d,
+            ])),
+            Felt::new(BigInteger256([
+                0xf5f9276ad855ad0d,
+                0xf6a672d03132b9cd,
+                0x938d1c1cf1a8b146,
+                0x699c859bc6726ec6,
+            ])),
+            Felt::new(BigInteger256([
+                0x07bd893cf9e94add,
+                0xaf0f85946845bede,
+                0xc8c375579429a5fc,
+                0x28e42a5987dd02f1,
+            ])),
+            Felt::new(BigInteger256([
+                0xd109853a53467fdb,
+                0x3c6dc997c079dd3a,
+                0x0e60536fd914044b,
+                0x6cd59fc17cf13cdd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3c4a7b5d01c75b8e,
+                0x3f8b93df619a2b78,
+                0x8161983ae181e2b3,
+                0x9509a098d93cde5c,
+            ])),
+            Felt::new(BigInteger256([
+                0x0517c4b02e60fec0,
+                0x9082659a7561bda4,
+                0x1ecd7c0d5bd1da57,
+                0x04b0d19885d34e9f,
+            ])),
+            Felt::new(BigInteger256([
+                0x669e1e8071c2d019,
+                0x6773c20da7d8502c,
+                0x5819d6b01cb3cbb4,
+                0x4088b1a4eebdb221,
+            ])),
+            Felt::new(BigInteger256([
+                0x93ca06238321d7e5,
+                0x946ede932d1025cd,
+                0x8699ec9203fa3483,
+                0x32929985293c57ef,
+            ])),
+            Felt::new(BigInteger256([
+                0x686dc8a91782bde6,
+                0x66b5562e0e4e3458,
+                0xace0fae85d2066ad,
+                0xd24e754e1f567e5e,
+            ])),
+            Felt::new(BigInteger256([
+                0xd9003b2037dee319,
+                0x10c2ed5b605391c1,
+                0x6922ba967aba7bd3,
+                0x2345e0b674de110b,
+            ])),
+            Felt::new(BigInteger256([
+                0x3b86a943b6fda7e7,
+                0xe314b526953f7618,
+                0xa42cce980490a37e,
+                0xf5ecc8ef8b481bff,
+            ])),
+            Felt::new(BigInteger256([
+                0xdb781f3e1f4dc5b6,
+                0x8d351b00b4b939e9,
+                0xa32e09fbd49984d0,
+                0x2cdbaa36c890899e,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0xbb79e3b13284041f,
+                0xf8268450edb6b97c,
+                0xc40f753b60fff875,
+                0xdf2c7b96c70cdbf2,
+            ])),
+            Felt::new(BigInteger256([
+                0xa1467eeaf97ccdb5,
+                0xbdc48cd85de79d80,
+                0x30bdafc13f04f3e1,
+                0x54042fa2fb59085a,
+            ])),
+            Felt::new(BigInteger256([
+                0x9f1d150bf2b7b37a,
+                0xa1808d7354cdb5aa,
+                0xfb0266c3e8d0f62e,
+                0x7a335cea938ccbbf,
+            ])),
+            Felt::new(BigInteger256([
+                0xe78f5a87a8aaaf7b,
+                0xe50fe3ec30609f35,
+                0x28709bfde1de824d,
+                0xb98b59d79ab7f940,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xe32cc33efebe6687,
+                0x75506b85bd441dc8,
+                0xda191583398ce7a9,
+                0xa006fc6b84d8ce33,
+            ])),
+            Felt::new(BigInteger256([
+                0x6203d068531f4e5e,
+                0x54924de82da3b869,
+                0x4b437fa0089b3b5a,
+                0x7110a1c03e136b01,
+            ])),
+            Felt::new(BigInteger256([
+                0x9e4439e4ac028c92,
+                0xe8a82b8348626884,
+                0x4052d29b1e1452be,
+                0xe835ee7c97cb9551,
+            ])),
+            Felt::new(BigInteger256([
+                0x93c2944c07f5e92e,
+                0xee888ce5935deb04,
+                0xe6ad09c9d6440d34,
+                0x790f0d78b2d8b891,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x973b76419c7fdf13,
+                0xab13d354b538dd59,
+                0xad004d236d2206d5,
+                0x7036bfe6f8c9f7db,
+            ])),
+            Felt::new(BigInteger256([
+                0xe1fcb2aebcd01eab,
+                0x189c14c9fa0221fc,
+                0xfc9af77ad731a8f3,
+                0x7a1563cc3192df7d,
+            ])),
+            Felt::new(BigInteger256([
+                0x3a785561aa2c509e,
+                0x407fb6f7d936a1a0,
+                0x76a7dd0d1675a5be,
+                0x49638fbb6587e627,
+            ])),
+            Felt::new(BigInteger256([
+                0x0b010c8e95048a4b,
+                0x430f289eafc8420d,
+                0x1ad44b57962313e4,
+                0x8a3df036188dc7cb,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xe711de86ad92518e,
+                0x5b80bfd456c9e4f8,
+                0x3288c0e3264f5533,
+                0xc1986102aa960867,
+            ])),
+            Felt::new(BigInteger256([
+                0xb451ea4161efa6af,
+                0x1e5775d30df3697d,
+                0x38e26bed3ccef4cf,
+                0x7963005550b9b8c0,
+            ])),
+            Felt::new(BigInteger256([
+                0xe13d04087a056320,
+                0x7d3a1020d6a4bd0a,
+                0x3efb20edc8c87b28,
+                0x0338d17fce160926,
+            ])),
+            Felt::new(BigInteger256([
+                0x06d6153e3af66ba3,
+                0xff120c533a1ef548,
+                0xa4dfd889af8dee22,
+                0x12e5ec23175a0af8,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x692eaad14800a39c,
+                0x02bfb4b4b5f72e7b,
+                0x5f1fd9235336be87,
+                0x8580801f5cd4aef1,
+            ])),
+            Felt::new(BigInteger256([
+                0xb3b341325226a70b,
+                0xf85afd59e3cfee65,
+                0x0671da76c90f4aa6,
+                0x174a9d937bc33c02,
+            ])),
+            Felt::new(BigInteger256([
+                0xb0351f53e943b3da,
+                0xcc05993dbff1dff0,
+                0xdd0fdba468d09d9d,
+                0x418d0f8d42e2921c,
+            ])),
+            Felt::new(BigInteger256([
+                0xea7981ed2d264ce1,
+                0x5eb59c361b104a04,
+                0x60dd59000aed1fa1,
+                0x50c913bf9bc588d4,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x56439528b538846a,
+                0xf017d98211598b47,
+                0xb267de7d2c93833d,
+                0x9f6bf35b2a433131,
+            ])),
+            Felt::new(BigInteger256([
+                0x3ee472f3c0f1d724,
+                0x924d024c4143f171,
+                0x7eb24f178b805c43,
+                0x8bb3aef8267b41eb,
+            ])),
+            Felt::new(BigInteger256([
+                0x90d365cb69b13651,
+                0xedda2c8ef99a1ad4,
+                0x11ccf78710add4d1,
+                0xa8c57c51db6ecf55,
+            ])),
+            Felt::new(BigInteger256([
+                0x047ba62baed85f46,
+                0x20a053e81373cf60,
+                0xbcaec42b39fe87dc,
+                0xd3ba594de72fb13f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x3b6e5170d9778b82,
+                0xf31bb1e2c5ca405b,
+                0x292c8f881761f71c,
+                0x5db511ecdda8c405,
+            ])),
+            Felt::new(BigInteger256([
+                0x19b92ad0ef73b3b6,
+                0xdee47d0b48ff90ea,
+                0x07aac544acec99b3,
+                0x395b908c71f884da,
+            ])),
+            Felt::new(BigInteger256([
+                0xe57304ab82b625e5,
+                0xaf5486e088c2b407,
+                0x47f3efcf24daeb80,
+                0x115f3554b6db827b,
+            ])),
+            Felt::new(BigInteger256([
+                0x3a6309833a1a5d10,
+                0x8ac20ee732b5f488,
+                0x367b5e9a6b85c25b,
+                0xbb3c38c2b2760a04,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x9ad4af3cabba921b,
+                0xe8446d6776c22a57,
+                0x80266d56d598632e,
+                0x11a9350bd0a902d0,
+            ])),
+            Felt::new(BigInteger256([
+                0xf6c7bf879f8b5dc7,
+                0x16ee7276d9effa76,
+                0x20f4f699caa7e2b0,
+                0x84efabc1d3df51d7,
+            ])),
+            Felt::new(BigInteger256([
+                0xed2001540cb7cbdc,
+                0xa904d3d1c798c7e3,
+                0xbdb1e640d30729f7,
+                0x709ee4d40f13e826,
+            ])),
+            Felt::new(BigInteger256([
+                0x25433a60be8d353a,
+                0x109dc7a4e204bbbf,
+                0xd34701d430080d7a,
+                0xe1ef706c040c35fe,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x429c3f6eae35ed10,
+                0x4b81a43a4c5e9cee,
+                0x7ce3fc9ff06d2836,
+                0x6212c50ea4d75ba6,
+            ])),
+            Felt::new(BigInteger256([
+                0x7df0a3a59cfa77a5,
+                0x25477f31e9363477,
+                0x0a8c4fbee0e659d3,
+                0xe6b07411ee7f805a,
+            ])),
+            Felt::new(BigInteger256([
+                0x39d7784859cde96b,
+                0x28ef82749102baa0,
+                0xa22baaf5f1ba0b00,
+                0x67c85d89f1eb19a6,
+            ])),
+            Felt::new(BigInteger256([
+                0x2b7e18b250573b0d,
+                0xbace7a4cce9f8dd1,
+                0x667b611e475574b1,
+                0x702d47c7c1cc5122,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x6cdc9fbaaf6f1a2f,
+                0x13a778f3d5aeaa7f,
+                0x2ad607c689570ff5,
+                0x727ece746309f627,
+            ])),
+            Felt::new(BigInteger256([
+                0x6115193a797f4706,
+                0xd795a14acc286f3c,
+                0x328459670b74d48f,
+                0xecb3e5988d9bb437,
+            ])),
+            Felt::new(BigInteger256([
+                0x396618bab519e574,
+                0xbc6b25c9b91a2609,
+                0xf44033ea9a9418b0,
+                0xf25932ae8e9955da,
+            ])),
+            Felt::new(BigInteger256([
+                0x60e625e3eab003da,
+                0x56b62eed8a4d503e,
+                0xa88f65702bf812ae,
+                0xca060693b007a2ec,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe09209724b18de4e,
+                0x1ffbaf1914e4aa73,
+                0x2f5d69c3c989bacb,
+                0x4358e5521c498d97,
+            ])),
+            Felt::new(BigInteger256([
+                0x4d1a36539fe8414c,
+                0xe4c6d1607b1e4286,
+                0x361abd8dd245bc39,
+                0x01d5aa48ce4f62b2,
+            ])),
+            Felt::new(BigInteger256([
+                0xa87d44667cc7055c,
+                0xe11c862bd03904f4,
+                0x9c5837b7c5f95850,
+                0x782c827f0174cc99,
+            ])),
+            Felt::new(BigInteger256([
+                0x8daf1172a3237313,
+                0x794a52738f2dc212,
+                0x0841cdd90ac7597e,
+                0xe8bb82dd81da44f8,
+            ])),
+            Felt::new(BigInteger256([
+                0x0aa63297dd7eaa54,
+                0x68853357f9d18912,
+                0xaf0c646c93224569,
+                0x50cd3c5e48fa34f7,
+            ])),
+            Felt::new(BigInteger256([
+                0x9ea649cd0ee0290a,
+                0x1e1e692e5209a87f,
+                0x52e7854269d55d77,
+                0xf9e5ca91a7ccd8da,
+            ])),
+            Felt::new(BigInteger256([
+                0xc13fa50d10e325e9,
+                0x894da95f89c1b89c,
+                0xdab26e97634f9314,
+                0x236c35bdd12723a5,
+            ])),
+            Felt::new(BigInteger256([
+                0x528939b076f99a6e,
+                0xe442ec29c5b4364a,
+                0xef9a45b2e2d613e9,
+                0x7c0dd641c7ce7abe,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xdd9c38c7e088c75d,
+                0xfc81d59ca37edb62,
+                0x4aea0c080ed25d8d,
+                0x456bed497acd92ae,
+            ])),
+            Felt::new(BigInteger256([
+                0xf1846ca3995a4884,
+                0x5eeb1ad0cc1f2249,
+                0x9dd7d651d6625a76,
+                0xcf886a76290abc4b,
+            ])),
+            Felt::new(BigInteger256([
+                0xa1899ade99b21bce,
+                0xa6a60efaed424bf0,
+                0xd1c03f88c37ca4e0,
+                0x5065bf53867f52cf,
+            ])),
+            Felt::new(BigInteger256([
+                0x70c3859061ac746a,
+                0x5198a4eb1ed27ade,
+                0xa2bf223af21a9c37,
+                0x6739f4a25ad44c3a,
+            ])),
+            Felt::new(BigInteger256([
+                0x79c5a5c4ef4483a0,
+                0x6061a6fa6d9fbc1a,
+                0x3310f25186e04e7e,
+                0x40ad8ca83396cdae,
+            ])),
+            Felt::new(BigInteger256([
+                0xc4190d8cb74de057,
+                0xef763073d769d144,
+                0x5822f2a7e0276d24,
+                0xbd074b348e44c893,
+            ])),
+            Felt::new(BigInteger256([
+                0x0b1f972b3a5ce15c,
+                0x6740145ea73fcc68,
+                0xe572a4e9bd2171c4,
+                0x9ee45a79d982204d,
+            ])),
+            Felt::new(BigInteger256([
+                0xd3d92fbe24de5c9f,
+                0x1f2bf6260913e659,
+                0x2b475fa69ad457de,
+                0x31573b204747e92e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x0ad5316ef62db3de,
+                0x86b987a856c0acb9,
+                0xdf0049ffe44718f5,
+                0x4339847e68acc19b,
+            ])),
+            Felt::new(BigInteger256([
+                0x4d216dedc88f1e96,
+                0x1ab1929ec2ae792b,
+                0x33abb055fbd0352e,
+                0x861af1430338cb26,
+            ])),
+            Felt::new(BigInteger256([
+                0x5ab27652db746a82,
+                0xa11c31cadf0b0950,
+                0x6f31fc672e59f92b,
+                0x1065aa14a1df8973,
+            ])),
+            Felt::new(BigInteger256([
+                0xe24fa9fe42304516,
+                0x98c6ec51041e0a9b,
+                0x2bb66d754b6d1dc3,
+                0xd9ab8b60998b017c,
+            ])),
+            Felt::new(BigInteger256([
+                0x85c491b26f5b8065,
+                0x202aeefafc47ffe0,
+                0x9d606e8d06c0a315,
+                0x525bd1851f8c26ac,
+            ])),
+            Felt::new(BigInteger256([
+                0x94a66856a16ca10a,
+                0xcdc229b54033a571,
+                0xfae2fe3996bc5915,
+                0x5357687fa2fce98a,
+            ])),
+            Felt::new(BigInteger256([
+                0xf8744740b6147b68,
+                0xe616b6de518f87eb,
+                0x57853c0c4c9e93ff,
+                0x479b6275956c95b5,
+            ])),
+            Felt::new(BigInteger256([
+                0x6612afbb49ad8564,
+                0x0249c965c4d5d87f,
+                0x55ccfabb70ac841a,
+                0x22fda01e00e81fd4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x43719956e6a1d262,
+                0x184e08ee67d2f14a,
+                0x2d1f2e600c85de55,
+                0x69459387cbebc041,
+            ])),
+            Felt::new(BigInteger256([
+                0xc8c0f350a5fc358c,
+                0xcbb6bbb9c870ef30,
+                0x49f574e33a5b69c5,
+                0x16c92c6236d78964,
+            ])),
+            Felt::new(BigInteger256([
+                0x9c755a97d3c3a4f9,
+                0xc361814d7f950862,
+                0x21abfa58d181319c,
+                0x432d5595bcd26d99,
+            ])),
+            Felt::new(BigInteger256([
+                0x00ab762d9b845e3d,
+                0x430ec36d13edf6c3,
+                0x4a0808d66b8d015d,
+                0xfe13dfd5ae3e088a,
+            ])),
+            Felt::new(BigInteger256([
+                0x8f3fc413fff696b6,
+                0xd8e47c022ce22a6b,
+                0xaebabebe1638bee4,
+                0x39338e917637bb98,
+            ])),
+            Felt::new(BigInteger256([
+                0x591bf16d5490a9fb,
+                0xb299b15040c8b61b,
+                0xe9df6625c9e1d5ca,
+                0x25377a7d8e78c4a7,
+            ])),
+            Felt::new(BigInteger256([
+                0x3789015dc004ee4d,
+                0xe102a1c6072a1fe4,
+                0x20ecf848ff84bbf5,
+                0xe1be3dce2f982380,
+            ])),
+            Felt::new(BigInteger256([
+                0x157425ef3d2a3729,
+                0x7967ec80f945010f,
+                0xa930548a2d008997,
+                0x2e739d7c22f8199d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x523ffd15fc92ef7a,
+                0x9ad58f63adb09ec6,
+                0xc93c0490d9aba7b3,
+                0x3eec6022cb1cc3ae,
+            ])),
+            Felt::new(BigInteger256([
+                0x3fbb1d2d7de936c4,
+                0x53a13ef9f9fc2ee6,
+                0x3c2509320fd00401,
+                0xed01ee7cdaf983d0,
+            ])),
+            Felt::new(BigInteger256([
+                0xd789b8a56c3e6579,
+                0x7fda93bf7a7de4b2,
+                0x2bb8d3af08057c85,
+                0xa0da0fd56f4e17a8,
+            ])),
+            Felt::new(BigInteger256([
+                0xe2a0972bf1709682,
+                0x9bf4dcec751927dc,
+                0x9b16cfd87feb0526,
+                0x93d54169e23f2a73,
+            ])),
+            Felt::new(BigInteger256([
+                0xc861c300438ab65c,
+                0xbe675824ec4832e0,
+                0x983873269e4374a9,
+                0x9c56ee1da1b4005a,
+            ])),
+            Felt::new(BigInteger256([
+                0x5228cdabe61d7d42,
+                0x66d540cc9ec31fa4,
+                0x1d5857c080512dd5,
+                0x698d621e0e066b9f,
+            ])),
+            Felt::new(BigInteger256([
+                0xe7c651f67719fe30,
+                0x9efc1d9635ba038a,
+                0x781a3ecc714c36bb,
+                0x3aab1587fe0ff3a5,
+            ])),
+            Felt::new(BigInteger256([
+                0x1a46afad6dec33cb,
+                0x855ffce343a4cd47,
+                0x59eca980e41836ef,
+                0x864f949555052df9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xe03f6e260c4f69df,
+                0xcf8c50e6bbd1ab30,
+                0x1b9ac59a2929da4f,
+                0xda777d2d540a4d83,
+            ])),
+            Felt::new(BigInteger256([
+                0xb8a2176c3d57cec8,
+                0xe80f7f762d1f34d5,
+                0x49fe3796154a776d,
+                0xeb85964e1ab92154,
+            ])),
+            Felt::new(BigInteger256([
+                0xcf386d220eb43cb2,
+                0x19f6f6d9fd4d428f,
+                0x9673a0f20e01b6c3,
+                0xd41ee4112f1f4d75,
+            ])),
+            Felt::new(BigInteger256([
+                0x8ac3b7ed49224000,
+                0x4fa1a630356f17e6,
+                0x5daf4c14f35f54a2,
+                0x4513108627755491,
+            ])),
+            Felt::new(BigInteger256([
+                0xe795d68b6238d11a,
+                0x394d72a055836dd2,
+                0xc40cdd33c63cf7b6,
+                0xb033acbbe1996986,
+            ])),
+            Felt::new(BigInteger256([
+                0xf760d67e2a6a632c,
+                0xcb108e5408e5cb49,
+                0x3ed8948b824af96e,
+                0x7856610aed24c36d,
+            ])),
+            Felt::new(BigInteger256([
+                0x53c58417b1aed325,
+                0x81c81293619b7b88,
+                0x676c5c80ef9f3561,
+                0xc2c9bd42a29a9bbc,
+            ])),
+            Felt::new(BigInteger256([
+                0x2f2c7319cb7cc701,
+                0xe26a5220b24ed923,
+                0x975203afaec78db7,
+                0x7dbff8ea41c8ab6c,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xe36cd231c7633c6b,
+            0x3c7b8288d0ccabdd,
+            0x184027be6ab464d4,
+            0x66ef61fbf0aaa94d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x773761da05d63247,
+            0xa11371d6c6a829bb,
+            0x4c5c7188368082f7,
+            0x725c9a210d908718,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbeb18ae19880dc78,
+            0x473ec7b53839e303,
+            0x3b176d02f0ec696b,
+            0xbdeda3a4a872854c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8376e20fc47dcad1,
+            0xf624521b758100c9,
+            0x4f462647db74b34d,
+            0x511a1efae0bfd546,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x5a3e724477c08675,
+            0x1b400d0502ce2de0,
+            0x09bdce5c6fd1e840,
+            0x5ae9e20364cb52a3,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa284eacd899a2ab6,
+            0x1663d642491c7625,
+            0xcdf24c9b438439aa,
+            0xce79099e44a44995,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa81b1d0b93e02963,
+            0xfbaa46b30b6ca49f,
+            0x4c7dcd2a580840cc,
+            0x08a599b31df4f74e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x101d6b8c2bd49cd5,
+            0x26fa578186867cfc,
+            0x546a67e89c372bff,
+            0xe3df7874f1fd6813,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7dab82395dfab5df,
+            0x57108ecf68f294fe,
+            0xe8c0c9e9d202423b,
+            0xc203e2d4c405ff41,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x48240af0ab8b4061,
+            0x15a1a783bfba48ff,
+            0x4975b4d3f64c863a,
+            0x245da177e533be2d,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger256([
+                0xba22dedfb21ecd1a,
+                0x2d94b73112d4dcd2,
+                0xe57051da9b14ea9a,
+                0x7aead19d9e2c57ee,
+            ])),
+            Felt::new(BigInteger256([
+                0xc6898205002f5ed6,
+                0xa0772e6060ba88b8,
+                0xdb97b3f64faeb893,
+                0x8175d26f36330f91,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xed0185b54bfb9f2b,
+                0x35ea8b717bcd241c,
+                0x2a7b99532fdd4555,
+                0x49c6e41f122c6880,
+            ])),
+            Felt::new(BigInteger256([
+                0x81a131148f570277,
+                0x2d11dfe69e53125d,
+                0x0c7946cfeb0494b5,
+                0x12d96a442acaf1c1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xbf95ec858b3091dd,
+                0xd0eb39e1a761d267,
+                0x1d9fbf5a74513242,
+                0x26566cf4a33452f9,
+            ])),
+            Felt::new(BigInteger256([
+                0xd3bd5bbb07fc078e,
+                0xa2a4c311a1388637,
+                0x930d474019e39424,
+                0x945ebfa9bf6139f6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x58ebb630bd3cf297,
+                0x566a5cccc7c95b83,
+                0xc41cd181afba4da4,
+                0xd20a8e9f6d205e6b,
+            ])),
+            Felt::new(BigInteger256([
+                0xccdea7b3b0807c9f,
+                0x346bd5c7781fb73f,
+                0xc74a36509b00dafb,
+                0x1f03150f84003381,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x2886c958d6f6c2c9,
+            0x161242b739b5d04e,
+            0x0c65e0b514837c19,
+            0xd21f092711b53d6b,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xca1ba1546a581f5a,
+            0xd7bb03c6779dda4c,
+            0x9e312bf697c1b380,
+            0xae087b0f42e112a7,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xeec123bcee8cf58a,
+            0x602ac634740ef0b3,
+            0x469ffa90aab62e87,
+            0xaf6cfd9b6188df88,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x00bead3bcf9e6132,
+            0xda802c3fe860cb5c,
+            0x3f75dabd4e2a3739,
+            0x840cf7efe00e4867,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x1af4ab8df092c4d6,
+            0xbd0dbd98c2337bf5,
+            0x2f28dbfafc3e7ee6,
+            0xe66b300d5c743b88,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xf7e2a9195f3e3304,
+            0xfb98a97cd37c74df,
+            0x93b7a0b67e795a7e,
+            0x65ad273ffd41dde6,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xeb030733a6e7dfd4,
+            0x183cc5d4012fdea9,
+            0xa01f1e709723623d,
+            0x7d781380bbb4564f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x726e85d6485cbbc7,
+            0xcc8ce4946c9999a9,
+            0x00269b1278bf355e,
+            0xdaacafea4ba6930f,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
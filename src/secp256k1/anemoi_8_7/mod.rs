@@ -0,0 +1,1711 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2b235879646e96b6,
+                    0x67aa5ed30608159a,
+                    0xe2b45320877dde54,
+                    0x2394606add3f64d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec480f39c064ca78,
+                    0xdb8a78a88ad244f5,
+                    0x7c507eb735e20f09,
+                    0xc1ce7377ea4632f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe0e199a13d9f62b0,
+                    0x6e544f6c3b75e52e,
+                    0xa620e881f3f2f08b,
+                    0xe568ff79cbb40de5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f55952dd8603a08,
+                    0x7d1e56bf61b9ac94,
+                    0x9026919d87f6ef09,
+                    0x653a61b942365418,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36be8774559015c5,
+                    0xf0dd99f3a6b763a6,
+                    0xba1ee36ddc1e2153,
+                    0x29e122994034e49c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81b96e5eeab08415,
+                    0x97edafe74d8f5131,
+                    0x0b4e0dacab11f0f2,
+                    0xe7732d1cc8055175,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa42c28ade87474e8,
+                    0x48647154cb6fdf4d,
+                    0xb5cee0562f393dc9,
+                    0xd287d004910472be,
+                ])),
+                Felt::new(BigInteger256([
+                    0xce47ec183ac11163,
+                    0x6967a69096981056,
+                    0x04bb13176eb770a6,
+                    0xbbb184d1e6cf9818,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0cd603006d579d7e,
+                    0xeb236eb7f006ebe8,
+                    0x08f62eb09e20378f,
+                    0x403984e518347f77,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f562fe448035b08,
+                    0x0ab5ff540e442fef,
+                    0x7a58d16a62acb962,
+                    0xe17b5082de1a3291,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb45fa98eb52c4a7d,
+                    0x01347f99fc141846,
+                    0xfd7e723f5c8e5be3,
+                    0xbeafb82153b355eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x931c091cc55d0249,
+                    0x4620b22b93133fc1,
+                    0x179c48ebe9f4a540,
+                    0x7fba322f92a6b53f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb969215bce2e0b58,
+                    0x981b7db360b0f451,
+                    0xb29ff9af2feb1739,
+                    0x0c7ab944376140f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb3a21aee760c8fcb,
+                    0x8b5f3db993e69f49,
+                    0x101713eb08c0589b,
+                    0xf7ba2d1123e82026,
+                ])),
+                Felt::new(BigInteger256([
+                    0x28bb8b296890a849,
+                    0x3069a5188ef44f62,
+                    0xa2d1e9b47d970673,
+                    0x7ed6cfdadc140d3a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90a2f996a679a976,
+                    0xe2760bf5a0a095d7,
+                    0x078714248fda88b7,
+                    0x21a6f8f38724b709,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x651ed5a3b0cc5f60,
+                    0x5f3679681f2ae3a8,
+                    0x30d30b3dbda775f2,
+                    0x9b396c4da0e1e2ca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x10f0463214bcca60,
+                    0xa3f2fc69d059f94d,
+                    0xd63222b0e3e00053,
+                    0x58954c0bda449a1e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x309230979dbc909a,
+                    0xd2aff2ba7c0b7c0a,
+                    0x5f6bd89157fb2228,
+                    0xa7460ca3cc098609,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd29506773c680ce3,
+                    0x65eebc6fe38b2286,
+                    0x824a25107c41c7f7,
+                    0x8dc538df8c2118e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6fb9f0604bf0eb57,
+                    0xa185021ca1576bfd,
+                    0x633de53ab6e8c733,
+                    0x64602e123e988f78,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc28ecad42a5d64b,
+                    0x93604db37987a963,
+                    0x9f7db2eee5a916ac,
+                    0xa675b6eb8a332a66,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f3b5d8fce5c397b,
+                    0x9985c29b622ccaf7,
+                    0x621abf0b89dac7bd,
+                    0x7152c8d50c2630a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9397e877c41e8786,
+                    0x31cd0047faec47c5,
+                    0x75087d116ceb21f2,
+                    0x678b6539ce2afefe,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3d2da0573db4c054,
+                    0x3b278473c3776b33,
+                    0x3b138824682a51c9,
+                    0x90ad770116e06771,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2d3a1d053f66934,
+                    0x18389225135b72d9,
+                    0x76bb842ccf008445,
+                    0x3d7211b37997038f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb30b3fe6495f9102,
+                    0x96c8dfb9cc8eed5b,
+                    0x9d1743ebac4f9fac,
+                    0x45a61ec7f52684f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e3ac626672b09f7,
+                    0xe51b5a43def4aaf1,
+                    0x15fbfdd3fb5affa0,
+                    0x66d0c0cddedfbba3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1729e70e72626ec7,
+                    0xbc08ca540205f846,
+                    0x00197b34e5f0009f,
+                    0xc5b71f6361bcfeb8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x774aff8b48f88d34,
+                    0x505861485236c3f8,
+                    0x31dec3b654646923,
+                    0x950685799ff8f947,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c04efc86f0d59a3,
+                    0xd4827cfd15709d07,
+                    0xf7c56de2bbb2258b,
+                    0x78b8d83641dccab4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x16837c3a36c423f5,
+                    0x76a7649f0403a2cc,
+                    0x098c8b81cbd51336,
+                    0x3949d5794dfa339e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9ee376236b68fce9,
+                    0x455049a63ce54256,
+                    0x61cdaf66685c2a53,
+                    0x5031887815e6d0f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81ff4d549f4290db,
+                    0x896a82eccbe8f88c,
+                    0x68dbd0526495d342,
+                    0x7d5a3f826c3b34c9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e03dbb941978c29,
+                    0x0a671d86bd75d68f,
+                    0xb7deca3289eda8c1,
+                    0x926a69a6d4340d3e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x301bc6fcd132c307,
+                    0xe3305ac5288e683f,
+                    0x03d234c96f37dbb7,
+                    0x8967171bc612e131,
+                ])),
+                Felt::new(BigInteger256([
+                    0x12311e5787cfff88,
+                    0xca086927195b15cc,
+                    0xdcb63cd660b7411a,
+                    0xb83e23d47dcfd465,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8bd62fb19a308664,
+                    0xc6e8ba7bf5faf65d,
+                    0x0fb54bd12f46cc9f,
+                    0x0911d43abda44d12,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ea7234574a157f3,
+                    0x4e275e90d3db3afc,
+                    0x7396fafe1b02c146,
+                    0x0331fe9175fbef8f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x183419111a548287,
+                    0xcfb56ba765d3235e,
+                    0xb060dc6e993e3e2c,
+                    0xd18053f51e78fe2c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf07ede2a9ac36dd8,
+                    0x0aeb3300a6eb9c41,
+                    0x3e2ba50bad319518,
+                    0x818322373cacb900,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe50ce3a9b2f65e18,
+                    0x3f9e09e9d55a1154,
+                    0xde93720ee76e08b9,
+                    0xf2202919f7871d34,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1fd6a3f7e01f584d,
+                    0xbfb663cf2244ae07,
+                    0x2f4f5627e77f91be,
+                    0x4dc5c0fba589d494,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae510638ae215fed,
+                    0xcf4242ef64bf278c,
+                    0xf952a8e801972da0,
+                    0x1d00c434e7a89d83,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae218f5439199397,
+                    0x2380bbc7586f016d,
+                    0x46065334683f87ff,
+                    0x19aed4371e286e14,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ca726ae3dc56ffa,
+                    0x61ce566918bee98b,
+                    0x4e02ad3c97c01d69,
+                    0xd3cc2a0f63d5cd07,
+                ])),
+                Felt::new(BigInteger256([
+                    0xad2c4714178ef336,
+                    0x5a9ed9ef7ecb0b00,
+                    0x5c0ea86252c8996b,
+                    0xf064bb90af67f9db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x303bbe57a8fb0ae6,
+                    0xfee3cbd72616fc40,
+                    0x8353e17881448b47,
+                    0x810e7dbfcb8334a3,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c29ded5176ffbef,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x8f4c41450acec8ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x707b8ae517f58446,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0xa60ebcaeccd281fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74e089ba9b17d1b2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0xccd674127211b107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e96c3bc50c098c3,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0f263df83116cce4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffdfffff85e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfab827fcecc65f58,
+                    0xb820d1c44e93e60d,
+                    0x1e939e9b49125560,
+                    0xbb7c2c34c9225954,
+                ])),
+                Felt::new(BigInteger256([
+                    0x928ccf2ef6cc1102,
+                    0x1abe28ecd04b4213,
+                    0x5587a820bf82a036,
+                    0x34c22c343060ac9e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72ff91d55f4e94ae,
+                    0x640672fd2f5b3889,
+                    0xa0006b2388482687,
+                    0x6e7665426a8c3940,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9c73bdc6395f0db8,
+                    0x6c093c10cfd6643e,
+                    0x7b82eb70191a0c7f,
+                    0xad9161e345431ab5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb29924084fd7fc5d,
+                    0x24ff0fe62e854965,
+                    0xa5253427904b8d00,
+                    0xa4f844be2b588501,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fc52b079dbfe596,
+                    0x2035a42c348b1c33,
+                    0xe0e35ac6797b15e9,
+                    0x287ec72087f138ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x15e71f6d16f99240,
+                    0x9ddd867c5ab04b0a,
+                    0x5c63e8fca01d4b6b,
+                    0xec6ffc276dc5383b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x940fedaaffaa3a06,
+                    0x2c53dbcef7e420d9,
+                    0xc1a99d2a913604d6,
+                    0xea4b5f148b3c33ad,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cc6cf62d11f03f5,
+                    0x9bcf5a99e7d7da20,
+                    0x2463d7843e9287c9,
+                    0x5d8faa13dc81a2b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf665ed1f06d73885,
+                    0x87d3d3bab5c38291,
+                    0x4c24767979c7dc5b,
+                    0x3f851197d8e2d803,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd25f4f5ad3c75839,
+                    0xc08b2def8a14f4fd,
+                    0x089061736b4e18c6,
+                    0x5c3b731b30aa2f14,
+                ])),
+                Felt::new(BigInteger256([
+                    0x805b47d841320c08,
+                    0x02af19837ad2c8fa,
+                    0x29637e4c13c78ab1,
+                    0xbefba553364a9bde,
+                ])),
+                Felt::new(BigInteger256([
+                    0x30697f2da5c89981,
+                    0xfb1db8e276dd5dcb,
+                    0x6ea1a2fe1d0013c3,
+                    0xc02b2e1cfa849baf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69e36685e26fea8f,
+                    0x666b0f531daa964f,
+                    0x3f59d69146d0d12f,
+                    0xde9fb701b159cbd3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0d4d39e6ca30d308,
+                    0xd1bc6e02f684ab5b,
+                    0xfe8971738f6d2bb8,
+                    0xddac53fd58fa9df1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0651835ce1aa37a5,
+                    0x7ae21dd3dcc44e3f,
+                    0xb6f6de8ac02e0bb9,
+                    0x40ae7df8bf3d79ea,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x564d1e70affb1ea9,
+                    0x4135faba957b7e01,
+                    0x1aa4431734dc8823,
+                    0x75bcad58fb53d6dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa4a2b8b2adc930bd,
+                    0xed7de0496b80d418,
+                    0x9048f3739eb49e32,
+                    0xf37f1eff442a17bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0fe9e08cea7b2b3,
+                    0xdf09fa83d65f07e6,
+                    0x26c67e0a5abbcedd,
+                    0x68463e1486ca0106,
+                ])),
+                Felt::new(BigInteger256([
+                    0x62adbfeee614168a,
+                    0xfaf633fa84255050,
+                    0xba6a5d9912e30746,
+                    0x55e01e9bd7e9cd90,
+                ])),
+                Felt::new(BigInteger256([
+                    0x21ef24919ad01fb8,
+                    0x1a8848f05dc6945d,
+                    0x6529e687c87c9fef,
+                    0xb2621827fe11b7cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x08318d4d1ef0d26e,
+                    0x1c4c7d78c42e9156,
+                    0xf97a0ee54709b9c4,
+                    0x081a7f83af641ce8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x32d65c27cc485220,
+                    0x7003f9d503c63741,
+                    0x0676b5ad84dc093a,
+                    0xbb716d6810aa7263,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7af96727e5c18223,
+                    0xefc8295b93e8889d,
+                    0x0d8da27c5ca6e69e,
+                    0xd63fcf9e70f7e7a9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x00f582d82308d462,
+                    0xa03012225e9c5846,
+                    0x60ec438fe49e0f14,
+                    0x7d7d9c58ee97fd08,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3fc49ab891bf1e3,
+                    0x27483b265b5ff1dd,
+                    0x1c26a9ddbd523b54,
+                    0x760ebd2eec08bb5f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c045a099fbd9b49,
+                    0x38f9239cd8c4f83e,
+                    0x64c34608ccad742c,
+                    0x69aef8d4ed7acfb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe9af7aa62efd8a60,
+                    0x6cd3f3ab06cbb3f1,
+                    0x708607e89c7f7654,
+                    0xb1f63ecd6843dca1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a89ee4c353cfc61,
+                    0xc118ce8c3916fec7,
+                    0x447de96b486f9b59,
+                    0x65b81db355fe7fd3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5c9c47cacb864fd,
+                    0x3830ae213ef3d515,
+                    0xbb26d582ea0b1e0b,
+                    0x132248b9b8c37d4c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4fec08e80f9b9b78,
+                    0x343eaab86bc6ac16,
+                    0x9b55b3795f8c5872,
+                    0xdffa436276af437d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x68667736807ccf24,
+                    0xf8871139264803af,
+                    0xb7f0200f272ec7e8,
+                    0xfaa129e23b70f91f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x888250e4bd0ec42b,
+                    0xe80f9a6fb57ca860,
+                    0x79d0489f96ebc73e,
+                    0xbae46f15145f5e90,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0d9e5ae45aec2a0,
+                    0x38432147f86c6c30,
+                    0xc3a7625cd42d1b8c,
+                    0x6ae826afd4162dba,
+                ])),
+                Felt::new(BigInteger256([
+                    0x46ccb40f83e6e699,
+                    0x8ceb2f1950f16864,
+                    0xac2905f12f16e5ed,
+                    0xd222e9ab1daaee80,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e3879891f976e68,
+                    0x2253997bc2a820f8,
+                    0x559a2eb9df8c7a55,
+                    0x3174e99a0cf139c2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a50d3d3636e5484,
+                    0x10e971e74de5b17d,
+                    0xf0d63386d3819561,
+                    0x1ac2555d8d191750,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bb3a917cb85a7aa,
+                    0xf41f636c296db9ad,
+                    0x4f276fd6bcefe785,
+                    0x51950b9f5793b302,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd25e1c47227e25d3,
+                    0x4a8acabdc44451b2,
+                    0xf3ec8f2c4c8a3984,
+                    0x2b522530d4f3c656,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14b7a7e5060557a3,
+                    0x34e9426b378016b7,
+                    0x3fa5e61cb1822720,
+                    0x370518be7cdfd3db,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x99e10bc8458081a8,
+                    0xfd3bb28239bb80d6,
+                    0x003fb47f03d4e411,
+                    0x4c9fce0eeaa1ec72,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd72b06e42ac92a45,
+                    0x11fe2399f53ca8dc,
+                    0xbd89e2c7ed181b41,
+                    0x82249d73fd07b5c6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3f0f003fd864399d,
+                    0xb946b5278b4ac82c,
+                    0x7158144d7e757b5f,
+                    0x1610fd7680dd434f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f138791a7796f5,
+                    0xb3b746e983e40010,
+                    0xc9e87e8e00594738,
+                    0x58185f3f70274d9a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc503a4b8406c4f4c,
+                    0x245b3f396553dda8,
+                    0xb280c365e70b951a,
+                    0xbfb604de29853cd4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa51eede8fd3ff57c,
+                    0xbe48787ef964f402,
+                    0x0a191165e71c7e7e,
+                    0x4f9baac5d9f55df0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13f43e90b30ccf77,
+                    0xa9eff2e9b352b2c6,
+                    0x0a27f28687131504,
+                    0x5f7a334dcf9e2c91,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7a8e2d21670da7c2,
+                    0x4e9e044bdad30530,
+                    0xad6424db79e40d9e,
+                    0x4bc4556271fd0231,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0438b0a5d202dfa5,
+                    0xed6c5ad1cdbc006f,
+                    0xa6fcb161aa1615ac,
+                    0x66e9f0a30ecc5131,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6990d960bf31083a,
+                    0x0ce1fdbbef400e5d,
+                    0xf8403c06470946d7,
+                    0x9c4aa2169dd95313,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b903c3325b20409,
+                    0x3dd881e36957752e,
+                    0x224727a724c6b3e8,
+                    0xf45992ae0eb562ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc25d487f34d2670,
+                    0xa01dfb47f55701c2,
+                    0x102ca755f53eae27,
+                    0x583cfba0ba36b4ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x38786b719b4a57d3,
+                    0x00b58dc7d2af6d74,
+                    0xf27f1510067b1d00,
+                    0x12272eff22aa4253,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe60ffba10b61d7d4,
+                    0x487f5e6748bb3d7d,
+                    0xa9c95bef3be6eb7c,
+                    0x7248359d07ef7ef4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc621d740e2f5abf,
+                    0x6f0af0537bb027b0,
+                    0xe35c8605fb451d22,
+                    0xa58b50700af3bb10,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe2859ef7ebc3e985,
+                    0xc9c16277d3dedadc,
+                    0x6eea5fd6ce8397f3,
+                    0xf88449fe9d6659c3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x665b263df2161a25,
+                    0xc591a6e4bc259aac,
+                    0xb7dd33ad365c0388,
+                    0x1454333b92874ad2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe402f0ae0d789c3f,
+                    0xaa113cef42fa3d4c,
+                    0xd63ad565e586f92f,
+                    0xc9b5f60c9a0aa05a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd6eb7a7d87583c79,
+                    0x98145c639416c901,
+                    0x9597909a17935fb0,
+                    0x4209ad9a4a058b4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xce267fc712a50176,
+                    0x5e9e043d85e89ffb,
+                    0x766aabc280d5762a,
+                    0x363cbe4c517dfa1e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5314bba5d802ad52,
+                    0x33c4253002edd1ea,
+                    0x563aff5b6b5948e3,
+                    0xcbeaf31586e13ff8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec2c6e6017e1528f,
+                    0xffedcc5219e0de7f,
+                    0x87e5f2a9c44b0a01,
+                    0x0e923e6dae60ea51,
+                ])),
+                Felt::new(BigInteger256([
+                    0x922d473aea9734b2,
+                    0x972704a6411b7230,
+                    0x53d3e4feec9c8e81,
+                    0xd257c78451978e59,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71d7c6b07663f91b,
+                    0x739b383a5b070dbd,
+                    0x94f458e46d891341,
+                    0xe3aab2d03449514e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd3989601b4662fb8,
+                    0xda76dfc37282deae,
+                    0x812df2eff87bc2a2,
+                    0x848948d3cf851f27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x548f74a032e37782,
+                    0x4593615d3d807e17,
+                    0x670f07eb44b7cb18,
+                    0x3f361caabbcd3f19,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3228c827d8f97fa9,
+                    0xdf461f9bafa49112,
+                    0x2c51845f042232bf,
+                    0x51b733c86001d0f9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa4d92ed58a4f582e,
+                    0x5385cfd9aa59fb34,
+                    0x304d929a6a51aea7,
+                    0x2955db744d2ccf51,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f2d8cfc93a60eda,
+                    0xfb2ee8bbb9f8e63f,
+                    0x03ec64cdfa1e7f3d,
+                    0x31c21b9b25a8cc7a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x579e29697c407c4f,
+                    0xbccdca56496ce03d,
+                    0xa087a92d800fae33,
+                    0x2a3b15a02733cffb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83f2245c6a44a190,
+                    0xfe16b7c278bae81d,
+                    0x1127897bc3a25c98,
+                    0x18f57e1fd0531c40,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa3824f7b89e866d,
+                    0x24ef119125b85a5d,
+                    0x85fafebfb70bb640,
+                    0xeb53439bc897c10e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x27115ee6991fd0ef,
+                    0x9f95f4d243fdf867,
+                    0x9240b05aaca123f9,
+                    0x3d664ca0de7e8abb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24919965243adab4,
+                    0xe70e8aadc1a4c6bc,
+                    0xdc1e8fa765bdc413,
+                    0x2d56800b95f854b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9614d27a283dc2d2,
+                    0xd957c7ed4208817f,
+                    0x5e4aa57bb56b2110,
+                    0x921650f69d0e70bc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf457a26d9d51fe55,
+                    0x3162c0b8279621ab,
+                    0x91a9ab4237c1cce7,
+                    0x3a32deda1b5bd82a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c879502d2da303b,
+                    0x410703adf5598bb1,
+                    0x1350663358cae0ee,
+                    0xe2073a60fb248f19,
+                ])),
+                Felt::new(BigInteger256([
+                    0x807e1c7c0a7ebb67,
+                    0x8775dbb44166cc28,
+                    0x1bab284835451b8a,
+                    0x5a49b21263bb98c7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01fe8d70f6405a7b,
+                    0xf019fdae9ebb4217,
+                    0x432d492627ab0ca6,
+                    0x7ca711b9f4503454,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9dae8ab2e8a0dc9d,
+                    0x1ab87cb0d34b8a62,
+                    0x1dc1004f4bf3fec5,
+                    0x0c39b827b21e49c5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3801edba791593c8,
+                    0x78039d2517e8637e,
+                    0x32a0c37fa956891c,
+                    0x5d874003206ddde1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf3cebd3969868c1f,
+                    0xa2b243374ed85700,
+                    0xff3f02618ce8478e,
+                    0xf0f62698fd172c4f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd1822b80e8b2367,
+                    0xe9d97fdf00ede3a9,
+                    0x761fc4cab4085304,
+                    0x9d0a3b4a17fa6aa8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x124751841b632b53,
+                    0x0c221565129cd34f,
+                    0xbea71a69932864a3,
+                    0x14d05266b0667fc2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x479510bf782a181e,
+                    0x9e193b504e0409f5,
+                    0x75c04dbb8666bce9,
+                    0x76e67b9dcbf69a79,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c35331f47ec2849,
+                    0x4e8ba208d4882ad5,
+                    0x574ff99aa5286d97,
+                    0x609b2a46b7f96e29,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9ea9348572b2bded,
+                    0xa60d576d732a0764,
+                    0x15e0d792d884df32,
+                    0x4239238842ec7cb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd4c17aa7da5866fb,
+                    0x7e3b28aadd7d6c1c,
+                    0x8109daf637f43d08,
+                    0x8aad39bfeb08a562,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7dcca2617e42a98b,
+                    0x6e33709100660bc5,
+                    0x7c73f595b3285174,
+                    0xeccd4aab845286fd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb4ec793d2eede7cc,
+                    0x234b1dc08cbf4f28,
+                    0x9efcf8462c30a2ef,
+                    0xc936f11aeb3363d4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3ee4e86fdbf3e3f4,
+                    0xa338a12c0b0381e4,
+                    0xbfc9e72e5ed96f8b,
+                    0x16d099afdb47a15d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3fce9a6f40b8d961,
+                    0xc1abf273943d8ee0,
+                    0x4d5ea18d1ec0de08,
+                    0xa9e24f56fdf6ec87,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bbbe9525396b5cb,
+                    0x9967a6a6d220fa58,
+                    0x31ae09d9b57c4529,
+                    0x94792a9ec7ce899f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5282f32fa4d6a4f,
+                    0xdf1f792c58ae9df8,
+                    0x08e706422381c78b,
+                    0xc8306d122f469b9c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a0e4c12c6ff3e58,
+                    0x9be5f5a39dca4cde,
+                    0x528ee4dfc449a54e,
+                    0xbecf7d653678cbea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x35bad38a2c858919,
+                    0x9a5b4aab1f4e70f6,
+                    0xdacf9eee1a8e6a7b,
+                    0x6eb1edb5a433bddb,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x919e486019c496de,
+                    0xbad7c943a6c79eb7,
+                    0x1f590e72144b5738,
+                    0xbdd823e9e0f5e450,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c1226f2964a9d9a,
+                    0x4b9afcb4cd5b9154,
+                    0x7c3a3eb09707a7df,
+                    0x2cca3ae83db33c1b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x083d68990db9ee9b,
+                    0x8b118bfee3900169,
+                    0xfc1e1da673dbad2a,
+                    0x7226a9b80962fc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9720baffc0ad842f,
+                    0x49e0ce34d312ccdf,
+                    0x0d3c7dffdce81aec,
+                    0x1fb2d7b7a11d85cd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x289290b3d94a1dbe,
+                    0x640ff0742b26b534,
+                    0x5b77d2bba7f77ee1,
+                    0x2877e25673f03f7e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x008aa624fec12151,
+                    0xecf9ffe16d1e6f57,
+                    0xfd8d967c49c7cfca,
+                    0x283518ba331972bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7a6a2e873f0839f7,
+                    0x40745e2806631f51,
+                    0x2f3ba5b5d8fc5988,
+                    0x1790bc70d20c6d45,
+                ])),
+                Felt::new(BigInteger256([
+                    0x48614767c14818bd,
+                    0x8b646a6c4b185234,
+                    0xf5688dab53f6b5bd,
+                    0x8219a37ccf442fe2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe59cd7c7f5f05432,
+                    0x51edbc85160ccad8,
+                    0x34cf3e5a95b269d8,
+                    0xa3ff4f21cd3c5c79,
+                ])),
+                Felt::new(BigInteger256([
+                    0x481308c9c452682b,
+                    0xe191487ebac4d394,
+                    0x14d1c6978fb387f5,
+                    0xaf4d402ec1c929aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacd447edcdf0ca5d,
+                    0xff466fcda3d7e0b4,
+                    0x6e1a915015e41ad5,
+                    0xe9581a55211eb85e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92dd9c5607418447,
+                    0xff746c3e9954f2c0,
+                    0x558205566a30d62b,
+                    0x745a8946a4701820,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83a71256ad723726,
+                    0x52a7f72a382d4639,
+                    0x98c38f6501645876,
+                    0x66b24e30263ed78b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x349f748197a5498c,
+                    0x4dfa4be776ce2124,
+                    0x6472a2fca38f5361,
+                    0x8158fce675d0ee12,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f5217c5ecab99a7,
+                    0x926725b5999d2b9b,
+                    0xa768eb405312936e,
+                    0xfb90b4a84f8a8f31,
+                ])),
+                Felt::new(BigInteger256([
+                    0x51c8eed08cde85ae,
+                    0x4baa8f434c100a35,
+                    0x822c15ba7f746ae4,
+                    0xd55b531bad098184,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaadc4d7ca9ce9ce7,
+                    0x8928339876807be0,
+                    0x3347578956b69339,
+                    0xf288e934c645fc6c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1aa2611e652d5a0,
+                    0xb6d79ba04fa7dff6,
+                    0x62b6fd2bedbbee6f,
+                    0x0792b6c2b58dfcb1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2ef5e33623cddc22,
+                    0x4db9a928892ac0dc,
+                    0x17aca07dd438e8f1,
+                    0xd8c9330c7a9a5e86,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc82d9c3ae9faf636,
+                    0x3cccc6d430e48d67,
+                    0x1c4bdab578a2eb12,
+                    0x671cf524b569e680,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf397d0bb0a20cdd7,
+                    0x15829846cb6c4289,
+                    0x82dbf9c5a217c112,
+                    0xe5512bc43341e995,
+                ])),
+                Felt::new(BigInteger256([
+                    0x370897d845808de8,
+                    0xfe515d8cea4678bb,
+                    0xa60221075f2dcaaf,
+                    0xb1317bcc4eb43e69,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f0114e321e4ddf0,
+                    0xa3b3475ee101fe8c,
+                    0xd4d559eb00c8146a,
+                    0x0f10e3c812f9410f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb576470a51563a1f,
+                    0x415f074ccbdc81d9,
+                    0x9bd5f68f674c60b6,
+                    0xe0a5029f3f7f7ac2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x589d23357aba8aa7,
+                    0x5bffb979876cfcda,
+                    0xd297e1320b1efe31,
+                    0x579bdc415f9eb857,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1d2841c90315b6fa,
+                    0x3006804794e3fe24,
+                    0xea8333c73288934b,
+                    0x28640e040dd38569,
+                ])),
+                Felt::new(BigInteger256([
+                    0x102e16df45b5d7f6,
+                    0x5aab464e1319c06d,
+                    0x864a929739c87d27,
+                    0x3b84fe6f22abac87,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fddae87501c8fb6,
+                    0x214ff8b1bfeb5bf0,
+                    0xaf5f4c7a79fd8341,
+                    0xd2052120d85f2ee2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe71ab16615f15ef0,
+                    0x5b1c538b164316bf,
+                    0xbb948068c22ded40,
+                    0x17a8d0943cc4f4b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4accdf964ff3d1b3,
+                    0x366faa0ac00541e3,
+                    0x1d5aa4f858ceb14a,
+                    0x638590a29bd3e3b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe62ed81e3ac18c76,
+                    0x52e32fe289e43962,
+                    0xc09e95fdf390ec3b,
+                    0x22cd83dab71abc1f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b184432eb08dc44,
+                    0x02d2e361ac02cd2f,
+                    0xeb6e3ae731c946c3,
+                    0x7e82565a400ceb1d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x755b3f5a9cfac758,
+                    0xe4bf69ce8de9e46c,
+                    0xcdf16aa2b289ce4e,
+                    0x36ef83796ded4e62,
+                ])),
+                Felt::new(BigInteger256([
+                    0xda15ffcd2df3c13e,
+                    0xd4d042250f50e94b,
+                    0xcdabc81d652a3938,
+                    0x9b263d3f33aa099f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x908b6b6773951d9e,
+                    0x0ac6b8b2e827bf5e,
+                    0x319a235be8df3b95,
+                    0xb2b42061e341c56e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b1491be603261a4,
+                    0x41032048d4c8e739,
+                    0x2237d6afe86ea4f3,
+                    0x373809b476e2ecb9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfbdda76f0a3eac8c,
+                    0x3bbe2bb023b4aa9d,
+                    0x9331d1fb424cd838,
+                    0x6e3ad880e8a92089,
+                ])),
+                Felt::new(BigInteger256([
+                    0x45e6a2419a36c066,
+                    0xd7260d4e76d3d05e,
+                    0x54c36d4b81466f36,
+                    0xeb246f2fdabb86b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14e762784a96fde1,
+                    0x9000db149ad157fa,
+                    0x2ec26c7dbca6e3c8,
+                    0x1fbde47decf85108,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa40def1eb2457eee,
+                    0xb7a2536348cf4b40,
+                    0x5084933f41bb7b7f,
+                    0xcd9e3d8ba5517b98,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcd990ff9900097a0,
+                    0x2a0da27211267ab5,
+                    0x1fe170e0dcb9c5ee,
+                    0x53c1ca2bbcdbbfff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1813f2bd85ca8e0,
+                    0x5fea7ff8d64b0f8c,
+                    0xf0ad4d22b15e50b9,
+                    0xc23d7f6b0c2e6a0a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x06a14ad2f58caa39,
+                    0xc75b6bd85249efc5,
+                    0xec3cca1db3fd7f2e,
+                    0x247ff105bed894dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa592fd4ad84b3bec,
+                    0xa0353fda4cd41a60,
+                    0xbf92dc0199858121,
+                    0x1fb0ccd609646cfc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x91c59619969f3741,
+                    0xea0023b0a426135d,
+                    0x789b9418a4c86bb5,
+                    0xccefaba44d30a1b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7023e92a1be515fd,
+                    0xc2257f76bfbfc46d,
+                    0xf5d2b37cd3a351ab,
+                    0xc58597903e99c241,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd94ce5746928b34,
+                    0x532aca9612129be6,
+                    0xd8ad0a8616a652d0,
+                    0x7f95d66357288811,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfb24f1422e1bfa16,
+                    0xf47816c759b1ce3a,
+                    0xbcab5f5811a6f76e,
+                    0xf66895a9463d61e8,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! The secp256k1 base field, of modulus
+//! `p = 115792089237316195423570985008687907853269984665640564039457584007908834671663`,
+//! which is also the scalar field of secq256k1.
+//!
+//! The modulus uses all 256 bits of its representation, hence elements are
+//! represented with the in-crate `FullFp256` type over 4 limbs, in Montgomery form.
+
+use crate::full_fp256::{FullFp256, FullFp256Parameters};
+use ark_ff::{biginteger::BigInteger256 as BigInteger, fields::*};
+
+/// An element of the secp256k1 base field.
+pub type Felt = FullFp256<FeltParameters>;
+
+/// Parameters of the secp256k1 base field.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FeltParameters;
+
+impl FullFp256Parameters for FeltParameters {}
+
+impl FftParameters for FeltParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 1;
+
+    /// `3^((p - 1) / 2^1)`
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xfffffffdfffff85e,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+}
+
+impl FpParameters for FeltParameters {
+    const MODULUS: BigInteger = BigInteger([
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+
+    const MODULUS_BITS: u32 = 256;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 0;
+
+    const R: BigInteger = BigInteger([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const R2: BigInteger = BigInteger([
+        0x000007a2000e90a1,
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const INV: u64 = 0xd838091dd2253531;
+
+    /// GENERATOR = 3
+    const GENERATOR: BigInteger = BigInteger([
+        0x0000000300000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    const T: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffffbfffff0b,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::full_fp256::tests::{arithmetic_test, serialization_test};
+    use ark_algebra_test_templates::fields::{
+        fft_field_test, field_test, from_str_test, primefield_test,
+    };
+    use ark_std::test_rng;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_field() {
+        let mut rng = test_rng();
+        let a = Felt::rand(&mut rng);
+        let b = Felt::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Felt>();
+        fft_field_test::<Felt>();
+        from_str_test::<Felt>();
+    }
+
+    #[test]
+    fn test_serialization() {
+        serialization_test::<FeltParameters>();
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        arithmetic_test::<FeltParameters>();
+    }
+}
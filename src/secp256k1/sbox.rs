@@ -0,0 +1,362 @@
+use super::BigInteger256;
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 5;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 4] = [
+    0x66666665fffffe79,
+    0x6666666666666666,
+    0x6666666666666666,
+    0x6666666666666666,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 3;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger256([
+    0x000000005555569b,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    let t1 = x.square(); //         1: 2
+    let t2 = t1 * x; //             2: 3
+    let t3 = t2 * t1; //            3: 5
+    let t4 = t3 * t1; //            4: 7
+    let t5 = t4 * t1; //            5: 9
+    let t6 = t5 * t1; //            6: 11
+    let t7 = t6 * t1; //            7: 13
+    let t8 = t7 * t1; //            8: 15
+    let t9 = t8 * t1; //            9: 17
+    let t10 = t9 * t1; //          10: 19
+    let t11 = t10 * t1; //         11: 21
+    let t12 = t11 * t1; //         12: 23
+    let t13 = t12 * t1; //         13: 25
+    let t14 = t13 * t1; //         14: 27
+    let t15 = t14 * t1; //         15: 29
+    let t16 = t15 * t1; //         16: 31
+    let mut t0 = t13.square(); //  17: 50
+    t0 = t0.square(); //           18: 100
+    t0 = t0.square(); //           19: 200
+    t0 = t0.square(); //           20: 400
+    t0 = t0.square(); //           21: 800
+    t0 *= t10; //                  22: 819
+    t0 = t0.square(); //           23: 1638
+    t0 = t0.square(); //           24: 3276
+    t0 = t0.square(); //           25: 6552
+    t0 = t0.square(); //           26: 13104
+    t0 = t0.square(); //           27: 26208
+    t0 = t0.square(); //           28: 52416
+    t0 = t0.square(); //           29: 104832
+    t0 *= t13; //                  30: 104857
+    t0 = t0.square(); //           31: 209714
+    t0 = t0.square(); //           32: 419428
+    t0 = t0.square(); //           33: 838856
+    t0 = t0.square(); //           34: 1677712
+    t0 = t0.square(); //           35: 3355424
+    t0 *= t10; //                  36: 3355443
+    t0 = t0.square(); //           37: 6710886
+    t0 = t0.square(); //           38: 13421772
+    t0 = t0.square(); //           39: 26843544
+    t0 = t0.square(); //           40: 53687088
+    t0 = t0.square(); //           41: 107374176
+    t0 = t0.square(); //           42: 214748352
+    t0 = t0.square(); //           43: 429496704
+    t0 *= t13; //                  44: 429496729
+    t0 = t0.square(); //           45: 858993458
+    t0 = t0.square(); //           46: 1717986916
+    t0 = t0.square(); //           47: 3435973832
+    t0 = t0.square(); //           48: 6871947664
+    t0 = t0.square(); //           49: 13743895328
+    t0 *= t10; //                  50: 13743895347
+    t0 = t0.square(); //           51: 27487790694
+    t0 = t0.square(); //           52: 54975581388
+    t0 = t0.square(); //           53: 109951162776
+    t0 = t0.square(); //           54: 219902325552
+    t0 = t0.square(); //           55: 439804651104
+    t0 = t0.square(); //           56: 879609302208
+    t0 = t0.square(); //           57: 1759218604416
+    t0 *= t13; //                  58: 1759218604441
+    t0 = t0.square(); //           59: 3518437208882
+    t0 = t0.square(); //           60: 7036874417764
+    t0 = t0.square(); //           61: 14073748835528
+    t0 = t0.square(); //           62: 28147497671056
+    t0 = t0.square(); //           63: 56294995342112
+    t0 *= t10; //                  64: 56294995342131
+    t0 = t0.square(); //           65: 112589990684262
+    t0 = t0.square(); //           66: 225179981368524
+    t0 = t0.square(); //           67: 450359962737048
+    t0 = t0.square(); //           68: 900719925474096
+    t0 = t0.square(); //           69: 1801439850948192
+    t0 = t0.square(); //           70: 3602879701896384
+    t0 = t0.square(); //           71: 7205759403792768
+    t0 *= t13; //                  72: 7205759403792793
+    t0 = t0.square(); //           73: 14411518807585586
+    t0 = t0.square(); //           74: 28823037615171172
+    t0 = t0.square(); //           75: 57646075230342344
+    t0 = t0.square(); //           76: 115292150460684688
+    t0 = t0.square(); //           77: 230584300921369376
+    t0 *= t10; //                  78: 230584300921369395
+    t0 = t0.square(); //           79: 461168601842738790
+    t0 = t0.square(); //           80: 922337203685477580
+    t0 = t0.square(); //           81: 1844674407370955160
+    t0 = t0.square(); //           82: 3689348814741910320
+    t0 = t0.square(); //           83: 7378697629483820640
+    t0 = t0.square(); //           84: 14757395258967641280
+    t0 = t0.square(); //           85: 29514790517935282560
+    t0 *= t13; //                  86: 29514790517935282585
+    t0 = t0.square(); //           87: 59029581035870565170
+    t0 = t0.square(); //           88: 118059162071741130340
+    t0 = t0.square(); //           89: 236118324143482260680
+    t0 = t0.square(); //           90: 472236648286964521360
+    t0 = t0.square(); //           91: 944473296573929042720
+    t0 *= t10; //                  92: 944473296573929042739
+    t0 = t0.square(); //           93: 1888946593147858085478
+    t0 = t0.square(); //           94: 3777893186295716170956
+    t0 = t0.square(); //           95: 7555786372591432341912
+    t0 = t0.square(); //           96: 15111572745182864683824
+    t0 = t0.square(); //           97: 30223145490365729367648
+    t0 = t0.square(); //           98: 60446290980731458735296
+    t0 = t0.square(); //           99: 120892581961462917470592
+    t0 *= t13; //                 100: 120892581961462917470617
+    t0 = t0.square(); //          101: 241785163922925834941234
+    t0 = t0.square(); //          102: 483570327845851669882468
+    t0 = t0.square(); //          103: 967140655691703339764936
+    t0 = t0.square(); //          104: 1934281311383406679529872
+    t0 = t0.square(); //          105: 3868562622766813359059744
+    t0 *= t10; //                 106: 3868562622766813359059763
+    t0 = t0.square(); //          107: 7737125245533626718119526
+    t0 = t0.square(); //          108: 15474250491067253436239052
+    t0 = t0.square(); //          109: 30948500982134506872478104
+    t0 = t0.square(); //          110: 61897001964269013744956208
+    t0 = t0.square(); //          111: 123794003928538027489912416
+    t0 = t0.square(); //          112: 247588007857076054979824832
+    t0 = t0.square(); //          113: 495176015714152109959649664
+    t0 *= t13; //                 114: 495176015714152109959649689
+    t0 = t0.square(); //          115: 990352031428304219919299378
+    t0 = t0.square(); //          116: 1980704062856608439838598756
+    t0 = t0.square(); //          117: 3961408125713216879677197512
+    t0 = t0.square(); //          118: 7922816251426433759354395024
+    t0 = t0.square(); //          119: 15845632502852867518708790048
+    t0 *= t10; //                 120: 15845632502852867518708790067
+    t0 = t0.square(); //          121: 31691265005705735037417580134
+    t0 = t0.square(); //          122: 63382530011411470074835160268
+    t0 = t0.square(); //          123: 126765060022822940149670320536
+    t0 = t0.square(); //          124: 253530120045645880299340641072
+    t0 = t0.square(); //          125: 507060240091291760598681282144
+    t0 = t0.square(); //          126: 1014120480182583521197362564288
+    t0 = t0.square(); //          127: 2028240960365167042394725128576
+    t0 *= t13; //                 128: 2028240960365167042394725128601
+    t0 = t0.square(); //          129: 4056481920730334084789450257202
+    t0 = t0.square(); //          130: 8112963841460668169578900514404
+    t0 = t0.square(); //          131: 16225927682921336339157801028808
+    t0 = t0.square(); //          132: 32451855365842672678315602057616
+    t0 = t0.square(); //          133: 64903710731685345356631204115232
+    t0 *= t10; //                 134: 64903710731685345356631204115251
+    t0 = t0.square(); //          135: 129807421463370690713262408230502
+    t0 = t0.square(); //          136: 259614842926741381426524816461004
+    t0 = t0.square(); //          137: 519229685853482762853049632922008
+    t0 = t0.square(); //          138: 1038459371706965525706099265844016
+    t0 = t0.square(); //          139: 2076918743413931051412198531688032
+    t0 = t0.square(); //          140: 4153837486827862102824397063376064
+    t0 = t0.square(); //          141: 8307674973655724205648794126752128
+    t0 *= t13; //                 142: 8307674973655724205648794126752153
+    t0 = t0.square(); //          143: 16615349947311448411297588253504306
+    t0 = t0.square(); //          144: 33230699894622896822595176507008612
+    t0 = t0.square(); //          145: 66461399789245793645190353014017224
+    t0 = t0.square(); //          146: 132922799578491587290380706028034448
+    t0 = t0.square(); //          147: 265845599156983174580761412056068896
+    t0 *= t10; //                 148: 265845599156983174580761412056068915
+    t0 = t0.square(); //          149: 531691198313966349161522824112137830
+    t0 = t0.square(); //          150: 1063382396627932698323045648224275660
+    t0 = t0.square(); //          151: 2126764793255865396646091296448551320
+    t0 = t0.square(); //          152: 4253529586511730793292182592897102640
+    t0 = t0.square(); //          153: 8507059173023461586584365185794205280
+    t0 = t0.square(); //          154: 17014118346046923173168730371588410560
+    t0 = t0.square(); //          155: 34028236692093846346337460743176821120
+    t0 *= t13; //                 156: 34028236692093846346337460743176821145
+    t0 = t0.square(); //          157: 68056473384187692692674921486353642290
+    t0 = t0.square(); //          158: 136112946768375385385349842972707284580
+    t0 = t0.square(); //          159: 272225893536750770770699685945414569160
+    t0 = t0.square(); //          160: 544451787073501541541399371890829138320
+    t0 = t0.square(); //          161: 1088903574147003083082798743781658276640
+    t0 *= t10; //                 162: 1088903574147003083082798743781658276659
+    t0 = t0.square(); //          163: 2177807148294006166165597487563316553318
+    t0 = t0.square(); //          164: 4355614296588012332331194975126633106636
+    t0 = t0.square(); //          165: 8711228593176024664662389950253266213272
+    t0 = t0.square(); //          166: 17422457186352049329324779900506532426544
+    t0 = t0.square(); //          167: 34844914372704098658649559801013064853088
+    t0 = t0.square(); //          168: 69689828745408197317299119602026129706176
+    t0 = t0.square(); //          169: 139379657490816394634598239204052259412352
+    t0 *= t13; //                 170: 139379657490816394634598239204052259412377
+    t0 = t0.square(); //          171: 278759314981632789269196478408104518824754
+    t0 = t0.square(); //          172: 557518629963265578538392956816209037649508
+    t0 = t0.square(); //          173: 1115037259926531157076785913632418075299016
+    t0 = t0.square(); //          174: 2230074519853062314153571827264836150598032
+    t0 = t0.square(); //          175: 4460149039706124628307143654529672301196064
+    t0 *= t10; //                 176: 4460149039706124628307143654529672301196083
+    t0 = t0.square(); //          177: 8920298079412249256614287309059344602392166
+    t0 = t0.square(); //          178: 17840596158824498513228574618118689204784332
+    t0 = t0.square(); //          179: 35681192317648997026457149236237378409568664
+    t0 = t0.square(); //          180: 71362384635297994052914298472474756819137328
+    t0 = t0.square(); //          181: 142724769270595988105828596944949513638274656
+    t0 = t0.square(); //          182: 285449538541191976211657193889899027276549312
+    t0 = t0.square(); //          183: 570899077082383952423314387779798054553098624
+    t0 *= t13; //                 184: 570899077082383952423314387779798054553098649
+    t0 = t0.square(); //          185: 1141798154164767904846628775559596109106197298
+    t0 = t0.square(); //          186: 2283596308329535809693257551119192218212394596
+    t0 = t0.square(); //          187: 4567192616659071619386515102238384436424789192
+    t0 = t0.square(); //          188: 9134385233318143238773030204476768872849578384
+    t0 = t0.square(); //          189: 18268770466636286477546060408953537745699156768
+    t0 *= t10; //                 190: 18268770466636286477546060408953537745699156787
+    t0 = t0.square(); //          191: 36537540933272572955092120817907075491398313574
+    t0 = t0.square(); //          192: 73075081866545145910184241635814150982796627148
+    t0 = t0.square(); //          193: 146150163733090291820368483271628301965593254296
+    t0 = t0.square(); //          194: 292300327466180583640736966543256603931186508592
+    t0 = t0.square(); //          195: 584600654932361167281473933086513207862373017184
+    t0 = t0.square(); //          196: 1169201309864722334562947866173026415724746034368
+    t0 = t0.square(); //          197: 2338402619729444669125895732346052831449492068736
+    t0 *= t13; //                 198: 2338402619729444669125895732346052831449492068761
+    t0 = t0.square(); //          199: 4676805239458889338251791464692105662898984137522
+    t0 = t0.square(); //          200: 9353610478917778676503582929384211325797968275044
+    t0 = t0.square(); //          201: 18707220957835557353007165858768422651595936550088
+    t0 = t0.square(); //          202: 37414441915671114706014331717536845303191873100176
+    t0 = t0.square(); //          203: 74828883831342229412028663435073690606383746200352
+    t0 *= t10; //                 204: 74828883831342229412028663435073690606383746200371
+    t0 = t0.square(); //          205: 149657767662684458824057326870147381212767492400742
+    t0 = t0.square(); //          206: 299315535325368917648114653740294762425534984801484
+    t0 = t0.square(); //          207: 598631070650737835296229307480589524851069969602968
+    t0 = t0.square(); //          208: 1197262141301475670592458614961179049702139939205936
+    t0 = t0.square(); //          209: 2394524282602951341184917229922358099404279878411872
+    t0 = t0.square(); //          210: 4789048565205902682369834459844716198808559756823744
+    t0 = t0.square(); //          211: 9578097130411805364739668919689432397617119513647488
+    t0 *= t13; //                 212: 9578097130411805364739668919689432397617119513647513
+    t0 = t0.square(); //          213: 19156194260823610729479337839378864795234239027295026
+    t0 = t0.square(); //          214: 38312388521647221458958675678757729590468478054590052
+    t0 = t0.square(); //          215: 76624777043294442917917351357515459180936956109180104
+    t0 = t0.square(); //          216: 153249554086588885835834702715030918361873912218360208
+    t0 = t0.square(); //          217: 306499108173177771671669405430061836723747824436720416
+    t0 *= t10; //                 218: 306499108173177771671669405430061836723747824436720435
+    t0 = t0.square(); //          219: 612998216346355543343338810860123673447495648873440870
+    t0 = t0.square(); //          220: 1225996432692711086686677621720247346894991297746881740
+    t0 = t0.square(); //          221: 2451992865385422173373355243440494693789982595493763480
+    t0 = t0.square(); //          222: 4903985730770844346746710486880989387579965190987526960
+    t0 = t0.square(); //          223: 9807971461541688693493420973761978775159930381975053920
+    t0 = t0.square(); //          224: 19615942923083377386986841947523957550319860763950107840
+    t0 = t0.square(); //          225: 39231885846166754773973683895047915100639721527900215680
+    t0 *= t13; //                 226: 39231885846166754773973683895047915100639721527900215705
+    t0 = t0.square(); //          227: 78463771692333509547947367790095830201279443055800431410
+    t0 = t0.square(); //          228: 156927543384667019095894735580191660402558886111600862820
+    t0 = t0.square(); //          229: 313855086769334038191789471160383320805117772223201725640
+    t0 = t0.square(); //          230: 627710173538668076383578942320766641610235544446403451280
+    t0 = t0.square(); //          231: 1255420347077336152767157884641533283220471088892806902560
+    t0 *= t10; //                 232: 1255420347077336152767157884641533283220471088892806902579
+    t0 = t0.square(); //          233: 2510840694154672305534315769283066566440942177785613805158
+    t0 = t0.square(); //          234: 5021681388309344611068631538566133132881884355571227610316
+    t0 = t0.square(); //          235: 10043362776618689222137263077132266265763768711142455220632
+    t0 = t0.square(); //          236: 20086725553237378444274526154264532531527537422284910441264
+    t0 = t0.square(); //          237: 40173451106474756888549052308529065063055074844569820882528
+    t0 = t0.square(); //          238: 80346902212949513777098104617058130126110149689139641765056
+    t0 = t0.square(); //          239: 160693804425899027554196209234116260252220299378279283530112
+    t0 *= t13; //                 240: 160693804425899027554196209234116260252220299378279283530137
+    t0 = t0.square(); //          241: 321387608851798055108392418468232520504440598756558567060274
+    t0 = t0.square(); //          242: 642775217703596110216784836936465041008881197513117134120548
+    t0 = t0.square(); //          243: 1285550435407192220433569673872930082017762395026234268241096
+    t0 = t0.square(); //          244: 2571100870814384440867139347745860164035524790052468536482192
+    t0 = t0.square(); //          245: 5142201741628768881734278695491720328071049580104937072964384
+    t0 *= t10; //                 246: 5142201741628768881734278695491720328071049580104937072964403
+    t0 = t0.square(); //          247: 10284403483257537763468557390983440656142099160209874145928806
+    t0 = t0.square(); //          248: 20568806966515075526937114781966881312284198320419748291857612
+    t0 = t0.square(); //          249: 41137613933030151053874229563933762624568396640839496583715224
+    t0 = t0.square(); //          250: 82275227866060302107748459127867525249136793281678993167430448
+    t0 = t0.square(); //          251: 164550455732120604215496918255735050498273586563357986334860896
+    t0 = t0.square(); //          252: 329100911464241208430993836511470100996547173126715972669721792
+    t0 = t0.square(); //          253: 658201822928482416861987673022940201993094346253431945339443584
+    t0 *= t13; //                 254: 658201822928482416861987673022940201993094346253431945339443609
+    t0 = t0.square(); //          255: 1316403645856964833723975346045880403986188692506863890678887218
+    t0 = t0.square(); //          256: 2632807291713929667447950692091760807972377385013727781357774436
+    t0 = t0.square(); //          257: 5265614583427859334895901384183521615944754770027455562715548872
+    t0 = t0.square(); //          258: 10531229166855718669791802768367043231889509540054911125431097744
+    t0 = t0.square(); //          259: 21062458333711437339583605536734086463779019080109822250862195488
+    t0 *= t10; //                 260: 21062458333711437339583605536734086463779019080109822250862195507
+    t0 = t0.square(); //          261: 42124916667422874679167211073468172927558038160219644501724391014
+    t0 = t0.square(); //          262: 84249833334845749358334422146936345855116076320439289003448782028
+    t0 = t0.square(); //          263: 168499666669691498716668844293872691710232152640878578006897564056
+    t0 = t0.square(); //          264: 336999333339382997433337688587745383420464305281757156013795128112
+    t0 = t0.square(); //          265: 673998666678765994866675377175490766840928610563514312027590256224
+    t0 = t0.square(); //          266: 1347997333357531989733350754350981533681857221127028624055180512448
+    t0 = t0.square(); //          267: 2695994666715063979466701508701963067363714442254057248110361024896
+    t0 *= t13; //                 268: 2695994666715063979466701508701963067363714442254057248110361024921
+    t0 = t0.square(); //          269: 5391989333430127958933403017403926134727428884508114496220722049842
+    t0 = t0.square(); //          270: 10783978666860255917866806034807852269454857769016228992441444099684
+    t0 = t0.square(); //          271: 21567957333720511835733612069615704538909715538032457984882888199368
+    t0 = t0.square(); //          272: 43135914667441023671467224139231409077819431076064915969765776398736
+    t0 = t0.square(); //          273: 86271829334882047342934448278462818155638862152129831939531552797472
+    t0 = t0.square(); //          274: 172543658669764094685868896556925636311277724304259663879063105594944
+    t0 *= t16; //                 275: 172543658669764094685868896556925636311277724304259663879063105594975
+    t0 = t0.square(); //          276: 345087317339528189371737793113851272622555448608519327758126211189950
+    t0 = t0.square(); //          277: 690174634679056378743475586227702545245110897217038655516252422379900
+    t0 = t0.square(); //          278: 1380349269358112757486951172455405090490221794434077311032504844759800
+    t0 = t0.square(); //          279: 2760698538716225514973902344910810180980443588868154622065009689519600
+    t0 = t0.square(); //          280: 5521397077432451029947804689821620361960887177736309244130019379039200
+    t0 *= t16; //                 281: 5521397077432451029947804689821620361960887177736309244130019379039231
+    t0 = t0.square(); //          282: 11042794154864902059895609379643240723921774355472618488260038758078462
+    t0 = t0.square(); //          283: 22085588309729804119791218759286481447843548710945236976520077516156924
+    t0 = t0.square(); //          284: 44171176619459608239582437518572962895687097421890473953040155032313848
+    t0 = t0.square(); //          285: 88342353238919216479164875037145925791374194843780947906080310064627696
+    t0 = t0.square(); //          286: 176684706477838432958329750074291851582748389687561895812160620129255392
+    t0 *= t16; //                 287: 176684706477838432958329750074291851582748389687561895812160620129255423
+    t0 = t0.square(); //          288: 353369412955676865916659500148583703165496779375123791624321240258510846
+    t0 = t0.square(); //          289: 706738825911353731833319000297167406330993558750247583248642480517021692
+    t0 = t0.square(); //          290: 1413477651822707463666638000594334812661987117500495166497284961034043384
+    t0 = t0.square(); //          291: 2826955303645414927333276001188669625323974235000990332994569922068086768
+    t0 = t0.square(); //          292: 5653910607290829854666552002377339250647948470001980665989139844136173536
+    t0 *= t16; //                 293: 5653910607290829854666552002377339250647948470001980665989139844136173567
+    t0 = t0.square(); //          294: 11307821214581659709333104004754678501295896940003961331978279688272347134
+    t0 = t0.square(); //          295: 22615642429163319418666208009509357002591793880007922663956559376544694268
+    t0 = t0.square(); //          296: 45231284858326638837332416019018714005183587760015845327913118753089388536
+    t0 = t0.square(); //          297: 90462569716653277674664832038037428010367175520031690655826237506178777072
+    t0 *= t8; //                  298: 90462569716653277674664832038037428010367175520031690655826237506178777087
+    t0 = t0.square(); //          299: 180925139433306555349329664076074856020734351040063381311652475012357554174
+    t0 = t0.square(); //          300: 361850278866613110698659328152149712041468702080126762623304950024715108348
+    t0 = t0.square(); //          301: 723700557733226221397318656304299424082937404160253525246609900049430216696
+    t0 = t0.square(); //          302: 1447401115466452442794637312608598848165874808320507050493219800098860433392
+    t0 = t0.square(); //          303: 2894802230932904885589274625217197696331749616641014100986439600197720866784
+    t0 = t0.square(); //          304: 5789604461865809771178549250434395392663499233282028201972879200395441733568
+    t0 *= t8; //                  305: 5789604461865809771178549250434395392663499233282028201972879200395441733583
+    t0 = t0.square(); //          306: 11579208923731619542357098500868790785326998466564056403945758400790883467166
+    t0 = t0.square(); //          307: 23158417847463239084714197001737581570653996933128112807891516801581766934332
+    t0 = t0.square(); //          308: 46316835694926478169428394003475163141307993866256225615783033603163533868664
+    t0 * x //                     309: 46316835694926478169428394003475163141307993866256225615783033603163533868665
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
@@ -0,0 +1,1870 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger256([
+            0x615eca1b592b5320,
+            0x7cdfd7b97531e48a,
+            0xc6b8e148e39309ba,
+            0xdf07e7754889feb1,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0xe5f87d27ba1c30ba,
+                0x70f8a72311c3544d,
+                0x91da2815ed9d437c,
+                0xd2840f2a0fe9d8ac,
+            ])),
+            Felt::new(BigInteger256([
+                0xc05269dcf7d6d663,
+                0xc1053660d1e0b2ea,
+                0xbfccff3ee27bf541,
+                0x0470740e2e0ed618,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd9ad3f5fda599f2c,
+                0x27df381d2aeb4192,
+                0x3d763413ad5daed8,
+                0xf6e1c38552ad4824,
+            ])),
+            Felt::new(BigInteger256([
+                0xcbab3096dbab476c,
+                0x03bd0192c968516a,
+                0xe242756c49c07a1d,
+                0x4b7da83699cebc87,
+            ])),
+            Felt::new(BigInteger256([
+                0x7d72c5de7114d84d,
+                0x22fbe32275b95ef3,
+                0x294e262c6fca710c,
+                0x64d00af2ef91a5d7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xbc81d3a4b7e393fc,
+                0xbc10dbb42c4c93c2,
+                0xbe3d7f04f918ed6b,
+                0x8f0e85b8025046e4,
+            ])),
+            Felt::new(BigInteger256([
+                0x20a3abb7692610bb,
+                0x2bcf32cc109f90fc,
+                0x6fb1fa86ed3cb77a,
+                0xc07968fba48dee84,
+            ])),
+            Felt::new(BigInteger256([
+                0xb2ffd54cb5bc143e,
+                0x9aa88908f9a690ce,
+                0xc10c1d413c2d5823,
+                0x53127f490bd275ba,
+            ])),
+            Felt::new(BigInteger256([
+                0x54432f1f4ade7fa7,
+                0xb19e3c524e16a042,
+                0x7c81866dc4ced5a7,
+                0x75030a202cd99e49,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x191e92e5c13c0231,
+                0xee78bc6f13531135,
+                0xb8a16670d6f916b6,
+                0x8fe752584d079a75,
+            ])),
+            Felt::new(BigInteger256([
+                0xb8dd66a96de31ae0,
+                0x1d473cb62023e67a,
+                0x30276191d39b315b,
+                0x432fc1aa405a1f4b,
+            ])),
+            Felt::new(BigInteger256([
+                0x2b71658865e72ee3,
+                0xd407766e0e39edac,
+                0xe0247fab7f42a7b0,
+                0xcc4271ccc6ea382c,
+            ])),
+            Felt::new(BigInteger256([
+                0x2090c96a12e59bf3,
+                0x12e46273944242f8,
+                0x9c88563128630704,
+                0xc23f372f23d9aeb8,
+            ])),
+            Felt::new(BigInteger256([
+                0x660c4eca1035ef36,
+                0xf8a145d390a112f8,
+                0x6562cc1e651b2b2e,
+                0xba6e48a70bf2e703,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc9fd1c9e08b22ace,
+                0x0f46e15295bc9458,
+                0xe7b3adcece3c9ec2,
+                0x96c53880f660fb40,
+            ])),
+            Felt::new(BigInteger256([
+                0xc69793db9ae5c917,
+                0xc1e88b542776fceb,
+                0x023d081b43d0aaf3,
+                0xcab24bcf097acb60,
+            ])),
+            Felt::new(BigInteger256([
+                0x3e03b0a8f10c7440,
+                0x8b0ce7d39fd6739a,
+                0xe166e578a252ef31,
+                0x173039b7f1bd189a,
+            ])),
+            Felt::new(BigInteger256([
+                0x93172ffb8d69fe35,
+                0xf348b1205c60717c,
+                0x0e42997669d689e2,
+                0xbac4e9e73393dfbe,
+            ])),
+            Felt::new(BigInteger256([
+                0xf63245236031ff16,
+                0xae5dbd6547356d04,
+                0xd17a2d31602b8500,
+                0x3880e1ed87181b5a,
+            ])),
+            Felt::new(BigInteger256([
+                0xcecd36e1e9012a52,
+                0x9b43ecfcd09bfe2d,
+                0xad57be91820e3741,
+                0x963a0c9d9585d401,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xd8502e286bde4a1f,
+            0xf03a69cab3a56363,
+            0x8d4a7b22b9313c8c,
+            0x63d62f4a2602834c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x1471a875f0678865,
+            0xd8f1e1e046cb1d91,
+            0x63943fcaf00f46ae,
+            0x86fae819460176f6,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x28b5b40ab96afbdc,
+            0x98ca886843a51ff7,
+            0xf51558f2859e6228,
+            0x40813d5dc80daaae,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb24e81118593f2d0,
+            0x0242e4ebb82970fc,
+            0xe9baf2b420d5aeaa,
+            0x4b6b7115e1806752,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xaec361157c801633,
+            0x8981ede561c162ab,
+            0x05bee21942ebd7d7,
+            0xd210a3c4114c0958,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xef2c03aa06ca1352,
+            0x6f50e7d7feb277df,
+            0x60560bc0aebf2e37,
+            0x755ce1df7bbc6b08,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8b16aa029562c725,
+            0x48118b5ad3300b59,
+            0x3158937597b81708,
+            0x9bdbd689cfd1ccb9,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa1d3c1bec31fd7bd,
+            0x76c5a3ee76e317c7,
+            0x2293b8edddb546d5,
+            0x80c11ab8527f01dd,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x26f361f3d1e3bdc3,
+            0x62cfd10616a3655d,
+            0x43696bd8b49d3b38,
+            0xb290a278e1eca88d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x5b3bf0edb0902bff,
+            0x83d022a71d88efde,
+            0x15a6383134a8c7b8,
+            0x545f4bf2b144420f,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xec89a18c8e913b8f,
+                0x618f8164698efbb1,
+                0x50dcab068d353b81,
+                0x8e676c1214945b22,
+            ])),
+            Felt::new(BigInteger256([
+                0xbd945df0b5f2d36a,
+                0xdaa897abf2e5fb41,
+                0x8c96848eb16d639d,
+                0x4eb8b3565f0de362,
+            ])),
+            Felt::new(BigInteger256([
+                0x6a30c4e668fa6b73,
+                0xcb86b5f0f2067d95,
+                0x15674cade53ce450,
+                0xe6203dcd1fa36d09,
+            ])),
+            Felt::new(BigInteger256([
+                0x87a3c1ca0ed5c17d,
+                0x1bb6508b2fd22a33,
+                0x9de5c3ca86b448d0,
+                0xfe3010b250a9e816,
+            ])),
+            Felt::new(BigInteger256([
+                0x59967777efe28001,
+                0xa5bd94b56bd552c4,
+                0x3d83a87ddf05e847,
+                0xe78e91fc70b3bd11,
+            ])),
+            Felt::new(BigInteger256([
+                0x90b955bb9ebcd733,
+                0x5ed60d905c9912a6,
+                0x7beaf8317189f12e,
+                0xbd4a503264ae37bf,
+            ])),
+            Felt::new(BigInteger256([
+                0xf569bd1ba26ec7fa,
+                0x7a6d8412449ab9a5,
+                0x302a2a1435c4a9ad,
+                0xd72ca1cc68564495,
+            ])),
+            Felt::new(BigInteger256([
+                0x21ff8c0d4e19a12b,
+                0xf1c9321bfb14a47f,
+                0x69b0fb01016e4dcb,
+                0xfbc7cdecf8e06475,
+            ])),
+            Felt::new(BigInteger256([
+                0xaed0084d152aec1c,
+                0x0b3e181be75ad5ce,
+                0x66b79dd9189d4d6f,
+                0x41e2c049cfe9c0c1,
+            ])),
+            Felt::new(BigInteger256([
+                0x2e7adf7a9c791585,
+                0xb9c410fb42759327,
+                0x3b8afcc24e1cc021,
+                0x0e64eb5a7038254e,
+            ])),
+            Felt::new(BigInteger256([
+                0xf2d9d5f1f60b2aad,
+                0x888e7489db158be4,
+                0x02e4cda6fc681d23,
+                0x7eb85764e50c2af4,
+            ])),
+            Felt::new(BigInteger256([
+                0xb32dc162e64bdda4,
+                0x3febf23d2ac9c3cb,
+                0xd87be33e8fb6b33c,
+                0x57ed8081df7def4c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x0a3b241de38e715e,
+                0xd2b1aba876a9ee0e,
+                0x860255927d6a7f3d,
+                0xc59b50794f2b7b10,
+            ])),
+            Felt::new(BigInteger256([
+                0xbee7637008bbb8b7,
+                0x5c2917cbc67b8cc5,
+                0x8879756b31882272,
+                0xdd91f1f85de594ad,
+            ])),
+            Felt::new(BigInteger256([
+                0x1cb1a6aaf7b9e41e,
+                0x926089559217130b,
+                0xfda4fce79b0f5fe2,
+                0x3aa9745426abbe58,
+            ])),
+            Felt::new(BigInteger256([
+                0x9e13f9fad82af76d,
+                0x0e891402d9b0fe9d,
+                0xe25799a44a93ee3d,
+                0xea595351eef6502e,
+            ])),
+            Felt::new(BigInteger256([
+                0x557b46c9901ed0c5,
+                0x98f98582b3b54580,
+                0x91e7251e9402ca5a,
+                0xeafe9e5b8d0d9b20,
+            ])),
+            Felt::new(BigInteger256([
+                0x301224fe8e2f262e,
+                0x8fd563946777dfe2,
+                0xe798394ebd6cd149,
+                0x7ac2b1957721f769,
+            ])),
+            Felt::new(BigInteger256([
+                0x446c5e5ef3d8a974,
+                0x4217ad2a867e9527,
+                0xf525e17cfe3a1836,
+                0xb2e1d52c611d5330,
+            ])),
+            Felt::new(BigInteger256([
+                0x801442be868f9af7,
+                0x49fc923004ac09d5,
+                0x264d35797bc73034,
+                0x5ad855191444cf0f,
+            ])),
+            Felt::new(BigInteger256([
+                0x29a18bbb9b230e69,
+                0x1c8bfcce6c544a26,
+                0xb91e78f380a81940,
+                0xdd6f5f8bf808d9ed,
+            ])),
+            Felt::new(BigInteger256([
+                0x19861bdc9c7845a0,
+                0x645e2a168fc21412,
+                0x89d221d501523355,
+                0x7ef08aeef9bc2bf0,
+            ])),
+            Felt::new(BigInteger256([
+                0x4aeac1846963faf9,
+                0x9b0ce02569f7c3ec,
+                0x79b25ad6b515cf26,
+                0x2feb33baeb4db605,
+            ])),
+            Felt::new(BigInteger256([
+                0xa15251d67658779a,
+                0xc3c42f4364dfb73c,
+                0xf3c2fea8cb6b7d9b,
+                0x12040dc501d831b0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x0058705ffbb6663e,
+                0x98360877b0b567d3,
+                0x7a346ef17f3e792b,
+                0xfe86a5fce8996dd5,
+            ])),
+            Felt::new(BigInteger256([
+                0x865f009943316da3,
+                0xf413795137960f27,
+                0x48ce0e68590bed7c,
+                0xd42d5e2cc3b9143a,
+            ])),
+            Felt::new(BigInteger256([
+                0x77b89312bea5642b,
+                0xc0634be0a8c41302,
+                0x84179c798afc1a1b,
+                0x34bf95427d597576,
+            ])),
+            Felt::new(BigInteger256([
+                0xec600d9a6f924620,
+                0xf96e335a0c95d48e,
+                0x81f0322cf7ca0d85,
+                0x6ce59df29611e0bd,
+            ])),
+            Felt::new(BigInteger256([
+                0x4abc43d84714fdf1,
+                0x402e2f8a44a7b2ce,
+                0x9f2523026c22655c,
+                0xa36b6d8e9e060d27,
+            ])),
+            Felt::new(BigInteger256([
+                0xe5fa0916e7e6c522,
+                0x5114c4267b8f4e04,
+                0x3b2a25b8e1545294,
+                0x3963c2e484b80542,
+            ])),
+            Felt::new(BigInteger256([
+                0x0c4334f25258e1b9,
+                0xe58f57d332b3e403,
+                0x2a94645ab796eeec,
+                0xba6f4132706755c3,
+            ])),
+            Felt::new(BigInteger256([
+                0x31c208d142aa2566,
+                0x5979fa72f5ce22de,
+                0xaa386597a84a6345,
+                0x1402267abd1cf58e,
+            ])),
+            Felt::new(BigInteger256([
+                0x253e9c6409ddb6fc,
+                0x6bdca9cbc347bee6,
+                0x17bd5aee0a8f230d,
+                0x752c1349b84d0b69,
+            ])),
+            Felt::new(BigInteger256([
+                0xc44d66fbb91e1650,
+                0x6557764a961e10e1,
+                0xfae8d33e049dfb85,
+                0xbaf8307f93bb58cd,
+            ])),
+            Felt::new(BigInteger256([
+                0xcfef6dac12a3c54e,
+                0xaddaa9fc6f8d7bb7,
+                0xe00c8417438bdb98,
+                0x64448613a4b5b1b9,
+            ])),
+            Felt::new(BigInteger256([
+                0x2d4a61e679661f56,
+                0xe812746dfae864b6,
+                0xe2c1ed8ea887daf0,
+                0xcdbcf2457647adcb,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xfacac02f38452f4b,
+                0x1728735cd04c5583,
+                0x8ba98d65af900e3f,
+                0xef29249b0da8d666,
+            ])),
+            Felt::new(BigInteger256([
+                0x280b1b17b32e6d7e,
+                0xa4f7484a4a0f5f5e,
+                0x2bdd6a30dafc6b19,
+                0xa73f6ebb59d09452,
+            ])),
+            Felt::new(BigInteger256([
+                0x4719625878cdb95f,
+                0xa3fc1a1c9d2b7a40,
+                0x324008b463622ce9,
+                0xed421bae12cdfe46,
+            ])),
+            Felt::new(BigInteger256([
+                0x07df79a3f441b372,
+                0xe012b12bed248d41,
+                0x3021748a41e91eba,
+                0xa388db8c2edc9ecf,
+            ])),
+            Felt::new(BigInteger256([
+                0xb887dc3072d7a11e,
+                0xb43cf09645a29582,
+                0x7f4e21fb88cf982c,
+                0xa5f53a03956d6b9d,
+            ])),
+            Felt::new(BigInteger256([
+                0x2941f7b79d316bb2,
+                0x79c8b088d14c2513,
+                0xe5f3a223acc09c23,
+                0x8e8c842404646f99,
+            ])),
+            Felt::new(BigInteger256([
+                0x0105261e202f5e13,
+                0x2af1847d9571bfe9,
+                0x438053555eaee35c,
+                0x98738e530a20ea3a,
+            ])),
+            Felt::new(BigInteger256([
+                0xd34bc123260d6b6a,
+                0x0b4f19dc0eefdcf0,
+                0xe28fa9dbf028c63a,
+                0x06c50a3082dc4d9e,
+            ])),
+            Felt::new(BigInteger256([
+                0x3ba12bae4582f464,
+                0x51a74c5dc016752e,
+                0xa914bcda6a1d4f00,
+                0x5607d58751f1f4b9,
+            ])),
+            Felt::new(BigInteger256([
+                0x528900ff77291aa2,
+                0xba020df134e9d424,
+                0xafd61c71079711eb,
+                0x00c362d3a2d2499b,
+            ])),
+            Felt::new(BigInteger256([
+                0xebbf00ec5929b570,
+                0xdfdf01b38e2742f7,
+                0xd977d422d8ee4e07,
+                0x655f998068821d57,
+            ])),
+            Felt::new(BigInteger256([
+                0x59f10315a0826745,
+                0xc9ae54d1edfe239c,
+                0xc414b69d4bb460f5,
+                0xc8665759ebce4faa,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x222aea236979f24e,
+                0xe3e0dee93841b9fd,
+                0x713d86fa6c1d7706,
+                0x45a137bfc4eea90b,
+            ])),
+            Felt::new(BigInteger256([
+                0xed6c0ae66e91f039,
+                0x09622baec0acb73e,
+                0x4cd144f63e6ad20a,
+                0x753dee1432ceb845,
+            ])),
+            Felt::new(BigInteger256([
+                0x02e1304b37b48571,
+                0xb2feb859f66f33bf,
+                0x2cbbaa7dc04caece,
+                0x01c492918594f5f0,
+            ])),
+            Felt::new(BigInteger256([
+                0x39a126e8399dd4cf,
+                0x91025ff7c144e4db,
+                0xeafc3f07d54a4ea2,
+                0x08d44c6695171fc3,
+            ])),
+            Felt::new(BigInteger256([
+                0xa1f0eda8af95d4cc,
+                0xa3123fa3167aa9d5,
+                0xfb2383cdbc8cad96,
+                0xb1601219db0daa9e,
+            ])),
+            Felt::new(BigInteger256([
+                0x77d77910b00b3c67,
+                0x6a5d2228f34daa96,
+                0x67eb61a05525b229,
+                0xf546c1ce5fc4a975,
+            ])),
+            Felt::new(BigInteger256([
+                0x8de5c31da069d195,
+                0x9c6cfa8e2f96ee7c,
+                0x69aba340033a0858,
+                0x0cc5d929b8b9f279,
+            ])),
+            Felt::new(BigInteger256([
+                0x049f10a350db41e8,
+                0x6acd27ef18ac8e11,
+                0xdef47faec6e5c32a,
+                0x3857bb86d7c944ba,
+            ])),
+            Felt::new(BigInteger256([
+                0x21bb60bbf9de11d2,
+                0xfa10e1b36620b2fb,
+                0xb5ed6558303e739a,
+                0x0be0f287e8abe4b4,
+            ])),
+            Felt::new(BigInteger256([
+                0xd39053410a8153b3,
+                0x20df101360f855e7,
+                0x944f041472b7a3ca,
+                0x4ab6e2a9eefdd167,
+            ])),
+            Felt::new(BigInteger256([
+                0xfe22a06111978bbe,
+                0xec5a1f183b1fdc23,
+                0x0e0a114d3c9fd9cb,
+                0x8733db020e286af3,
+            ])),
+            Felt::new(BigInteger256([
+                0x90acbfe856ea0094,
+                0x9e95fecc6a9bf14f,
+                0xebf56965c9615eea,
+                0x9b4e110eef4aef14,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x658a5666a12b2388,
+                0xd4d99a2e280f141b,
+                0x1128f5f7aec8fa2a,
+                0xf9ff2cc01947c8aa,
+            ])),
+            Felt::new(BigInteger256([
+                0x507ca081c3c49f5a,
+                0x287fb24206768e3c,
+                0xf1b07f55ec755e69,
+                0x5f2d91ed65ecd25c,
+            ])),
+            Felt::new(BigInteger256([
+                0x602e784f8d9fa9ec,
+                0xf31a0cc35c0b4305,
+                0x609363d948ff5528,
+                0x292b7f69331e808a,
+            ])),
+            Felt::new(BigInteger256([
+                0xb43d9c6a9a3eaa2f,
+                0x912bb9508485bbf7,
+                0x162e4618daa48e66,
+                0x1477f05390202fa2,
+            ])),
+            Felt::new(BigInteger256([
+                0x3ea9b5f9a693137e,
+                0x95e6725d89ad925f,
+                0x688e02897a5cf308,
+                0xc83b4aee55f50154,
+            ])),
+            Felt::new(BigInteger256([
+                0x08b36d5f76f3875d,
+                0xeed04e78adc44315,
+                0x5d49b1940f276430,
+                0x3914a3cc1d9ddee0,
+            ])),
+            Felt::new(BigInteger256([
+                0xf1fc7af0222b8103,
+                0xfbcbe5bbead9d328,
+                0x7c88112c8c36be70,
+                0xf27a5c7a4f5bb0b6,
+            ])),
+            Felt::new(BigInteger256([
+                0x8a6186d4d2d8c3f2,
+                0xd7f56a0a4660442e,
+                0xc50c9deae8b1a2b2,
+                0xfda39fcf29cd7626,
+            ])),
+            Felt::new(BigInteger256([
+                0x76eb5efd929fa1e1,
+                0xc2db593867b64766,
+                0xf45fda2c320c9d48,
+                0x66bb0014f8dcb82b,
+            ])),
+            Felt::new(BigInteger256([
+                0x3e4838b2b41ea6f0,
+                0xe65e293785ff08fb,
+                0x5358f28ac9e7f421,
+                0x91dc42c457b56a5f,
+            ])),
+            Felt::new(BigInteger256([
+                0x92de0e44a1e62fac,
+                0x27dde3cd622e170b,
+                0xd566243b649b0ffa,
+                0x0303a7682ac01674,
+            ])),
+            Felt::new(BigInteger256([
+                0x294fa06153ef6217,
+                0xfe22ed5a24ff9af5,
+                0xc6dc52de20397aac,
+                0x4f5309d19fcd8e9d,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x2dafaf573ae478b1,
+                0x178c217462d1cc0e,
+                0xed9622a7e12c60ad,
+                0x26ab3e9edb368431,
+            ])),
+            Felt::new(BigInteger256([
+                0x3989f3358c850a8a,
+                0x832cfb9f5ce75b09,
+                0xdcd8a3d42c4e1e85,
+                0x50829ddc6f3706aa,
+            ])),
+            Felt::new(BigInteger256([
+                0x0e22a8894971deb6,
+                0x2220e913267922bf,
+                0x3a5bf747f4dd243e,
+                0x601cd943a69ba36e,
+            ])),
+            Felt::new(BigInteger256([
+                0xc90f09f691d2922a,
+                0xd50b4c15d078352c,
+                0x7c97cf7cd6e7d927,
+                0x74a918fe40fa8cf5,
+            ])),
+            Felt::new(BigInteger256([
+                0x256550f8ade651fa,
+                0x7cf1566807c5118f,
+                0x3e76e937828e262a,
+                0xb7e1bd9ff8d2947f,
+            ])),
+            Felt::new(BigInteger256([
+                0x2d4b940497656821,
+                0x7a6cecc270bf0a97,
+                0x86729a8f4ea165de,
+                0x4a0cda300cf296d7,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x88d164c225f5ad92,
+                0x2a52a3fb7471bbc9,
+                0xe89f1714f798851c,
+                0x08f813810fe09681,
+            ])),
+            Felt::new(BigInteger256([
+                0x566ea23e98bd53a2,
+                0x15b6dc5f6365b867,
+                0xe31d9c15d00abe07,
+                0xe0041710ee6bad9c,
+            ])),
+            Felt::new(BigInteger256([
+                0xcc42264fcced0bd7,
+                0x36afa8e5127a2779,
+                0xed3fca30d63441bd,
+                0x90b48fd604caeb19,
+            ])),
+            Felt::new(BigInteger256([
+                0x8a89075fb6a02edb,
+                0x1081c58484fac23e,
+                0x331b6714bdc9add4,
+                0xda2b6fe649b71360,
+            ])),
+            Felt::new(BigInteger256([
+                0x82b4e1c597171059,
+                0x8cb854ec83caf916,
+                0xa231853746a21906,
+                0xa6dfe3de5de028eb,
+            ])),
+            Felt::new(BigInteger256([
+                0xcce396d27d2a1ef2,
+                0xf42b4d188a8c46fb,
+                0x39c7070980eb1339,
+                0xde88a7909aae8d23,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5c6d36b7ea2b000f,
+                0x662f0d2acee6702d,
+                0xa0d42a5b8e2ede83,
+                0x1fc33262ef0c3f42,
+            ])),
+            Felt::new(BigInteger256([
+                0x000b2aea10179432,
+                0x07d3978b1faa9a0a,
+                0xe0fc70846c592389,
+                0x58826e5b29aadca3,
+            ])),
+            Felt::new(BigInteger256([
+                0xef2bad95c8247190,
+                0x86e2512d36c406e9,
+                0x07eaf4f602be7465,
+                0x6c2280cb63cac80f,
+            ])),
+            Felt::new(BigInteger256([
+                0x3d8679fdee459b54,
+                0xcec8a0a36a7a4c66,
+                0x905f22d7f8378c44,
+                0xf3b0d916627233df,
+            ])),
+            Felt::new(BigInteger256([
+                0xc810ef2c8fe25cfb,
+                0x832e12acaa7f808f,
+                0x6868e879cd78add9,
+                0x281a2a4ef1e6ab91,
+            ])),
+            Felt::new(BigInteger256([
+                0x482a59990a3ce9b1,
+                0xf7be6ec16ec7872a,
+                0x8bb7353d0e7fbfc9,
+                0x07515c062f1aba7c,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x298a84f46295ee9f,
+                0x2fb0ff4a001f1c29,
+                0x9d95987e0379ba86,
+                0x2d8a2e52f997f900,
+            ])),
+            Felt::new(BigInteger256([
+                0xd0cc01bc8d41db5e,
+                0xacbc4fa1e88efe7d,
+                0xe4e007567649ab78,
+                0xeefbd252ef60d8d0,
+            ])),
+            Felt::new(BigInteger256([
+                0xd8526cf510658f05,
+                0x2a32346e58e63f63,
+                0xb035cacd853679fc,
+                0x56e33fa846824741,
+            ])),
+            Felt::new(BigInteger256([
+                0xe847ad1150122dbf,
+                0xc553c011bee3acd3,
+                0x8085743920a78c4f,
+                0xc87845960ce95251,
+            ])),
+            Felt::new(BigInteger256([
+                0xa7e053a25dbe1dec,
+                0x34f1456b65a908bb,
+                0xb2e10d49fd30b1dc,
+                0xcdea2ac9ad150da6,
+            ])),
+            Felt::new(BigInteger256([
+                0xd41f7b0019f7b5e3,
+                0xaef7bc3c92699b36,
+                0xc809e0d7412ea329,
+                0x9ca56be15267d57c,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x10c18603e15fe068,
+                0x02113c503726772e,
+                0x4b3c60f96d13b7c5,
+                0x949ac1e1afc33289,
+            ])),
+            Felt::new(BigInteger256([
+                0x8882c3c81146bc90,
+                0xd619d73622b886aa,
+                0x492947c9b6dff937,
+                0x366802d4347a8885,
+            ])),
+            Felt::new(BigInteger256([
+                0x0ee3b4e03def328c,
+                0x9180b571771eeeda,
+                0x83d21696a53d16d4,
+                0x4f3f00582f4a63c5,
+            ])),
+            Felt::new(BigInteger256([
+                0xe1ac16ec73c2e83c,
+                0x3377d40918670f56,
+                0xa6345a9f8ca65ee0,
+                0x721bb538c9ca3175,
+            ])),
+            Felt::new(BigInteger256([
+                0x302d48401c1fb5e2,
+                0xd4e95019cf8cdebf,
+                0x5d9e97920c6f66e5,
+                0x9ffe8e563776916f,
+            ])),
+            Felt::new(BigInteger256([
+                0x0487304d2e9c0fc9,
+                0xeb86c71114f17451,
+                0xdb6e818a998c5265,
+                0x59b17ac9c9b57dea,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x04a89dc799d53fc2,
+                0xc4379e31987558f0,
+                0x2469558cc1185204,
+                0xfee49c1c9abec1b3,
+            ])),
+            Felt::new(BigInteger256([
+                0x44a07efe77969f70,
+                0x61935ae83feb945a,
+                0x30c3dd249034bf4d,
+                0xd81ae1955145825a,
+            ])),
+            Felt::new(BigInteger256([
+                0x9d9adfe89f3d234e,
+                0x9fe4906cd6d9c1df,
+                0xfbc913735a219cb7,
+                0xcaa879ade986b7a0,
+            ])),
+            Felt::new(BigInteger256([
+                0xa78d9ec53d16a635,
+                0xd967b39c448f9968,
+                0x13aefa6949d77eff,
+                0xf11ffe0ed580ef12,
+            ])),
+            Felt::new(BigInteger256([
+                0xd7d8c1e442a8553e,
+                0xc6a3721ee62ce98f,
+                0x2fe92a0cabe0b97c,
+                0xac475f071477ebc9,
+            ])),
+            Felt::new(BigInteger256([
+                0x306241e5dc5efdae,
+                0x553c0c3d4f24e275,
+                0x333bccd42af23e0a,
+                0x588a01692bd8f578,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5445e171a3d2e966,
+                0x827b3458d49b6079,
+                0xa3f85c6973a5670b,
+                0x4759284f820a0b2e,
+            ])),
+            Felt::new(BigInteger256([
+                0xd6a34cd08c806152,
+                0xc1e823c4fab11283,
+                0xfbb0dca16a51fdfa,
+                0xfb3496a9dab188bf,
+            ])),
+            Felt::new(BigInteger256([
+                0xafcbc074fa388c16,
+                0xcda50f9377f209d7,
+                0x262989e93cf6e61c,
+                0xec84830c5041c862,
+            ])),
+            Felt::new(BigInteger256([
+                0x24ec50d357275864,
+                0x8a1ceda39f8ecbda,
+                0xfa051d9aa89b3c0e,
+                0xc00263790ddb3695,
+            ])),
+            Felt::new(BigInteger256([
+                0xb73e7867b8b79c61,
+                0x35822536e0ec8af0,
+                0x44442c611c2d3e5a,
+                0xea9603b9de466e5d,
+            ])),
+            Felt::new(BigInteger256([
+                0x343c49c5b4f24f5a,
+                0xe0c51875d7fc9605,
+                0x3acc32f264b8c8b1,
+                0x7a2551201fd331cb,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x0bdf567c1d62b04f,
+                0x3ad01cb0aeb5ca8c,
+                0x33fe05070415302a,
+                0xbc63edfebea23d56,
+            ])),
+            Felt::new(BigInteger256([
+                0xf0e92c9c0d7d4896,
+                0x3730bbbf2c3cd093,
+                0x7bd62ce186bb3ba2,
+                0xd158707a0b9b24b3,
+            ])),
+            Felt::new(BigInteger256([
+                0x1b3cb08ed7338513,
+                0x689c987df57bbda9,
+                0xd967d3a24db8bb1d,
+                0x7a9c69e336ebf675,
+            ])),
+            Felt::new(BigInteger256([
+                0x30e2668c6e1780bb,
+                0x774d037c23954569,
+                0x47ada4afaa452a14,
+                0xb91fdbd821949e44,
+            ])),
+            Felt::new(BigInteger256([
+                0x1804656f3b8e0b67,
+                0xf464708f696481dc,
+                0x5f77e16d0db55936,
+                0x20a88898a6116272,
+            ])),
+            Felt::new(BigInteger256([
+                0x6819976103264ec2,
+                0x947b49196eedec46,
+                0x4908296248782354,
+                0xb242dddea9f54959,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xd948cabd5b02cb88,
+                0x04a9f6f05a7ad063,
+                0xcd19b859e2dd37a3,
+                0x5ca6071831004d65,
+            ])),
+            Felt::new(BigInteger256([
+                0x931f2d38a42c0b26,
+                0x87c153f60e2c4972,
+                0x2f633ff42275100c,
+                0xa6698b79b35323d5,
+            ])),
+            Felt::new(BigInteger256([
+                0xc094776ac72e2e1c,
+                0xeff8dece82e16aba,
+                0x47bafbf1974c7bfb,
+                0x68d17bc9889358fa,
+            ])),
+            Felt::new(BigInteger256([
+                0x2aaf249b922dca34,
+                0x187259a62e7a86eb,
+                0x34b56a709715a118,
+                0xfdc31323ed4f1199,
+            ])),
+            Felt::new(BigInteger256([
+                0x47905ea74291c29f,
+                0x178d5a000c9eac49,
+                0xf009b72ea6c91660,
+                0x32b77f4076ec483d,
+            ])),
+            Felt::new(BigInteger256([
+                0xb16265313877ac3e,
+                0xca0ccef00f27ffc3,
+                0x3a1f9c053d1c94fc,
+                0xba2fca7e0396cbc6,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xfa4987acb1aca62f,
+                0x457db39de28de8fd,
+                0xc3bbc086eaa95697,
+                0xc9f3d59f92f50fdf,
+            ])),
+            Felt::new(BigInteger256([
+                0x11652ed48404b51e,
+                0x0307775420655575,
+                0xcfc7fe6928fa9466,
+                0x2d9a3aa03ef551be,
+            ])),
+            Felt::new(BigInteger256([
+                0x2175cc3fbad6e725,
+                0xfaaa05f18a4ec4d3,
+                0x18ad1da812983575,
+                0x004b34484c3529b4,
+            ])),
+            Felt::new(BigInteger256([
+                0xc652c66018effe32,
+                0xe1a0e13cc759dabc,
+                0xe6a760c898351dd9,
+                0xa97e644c2259f7f9,
+            ])),
+            Felt::new(BigInteger256([
+                0x11ed415cae90b228,
+                0x9fb37a9c347118fc,
+                0xf97375bf41ca809f,
+                0x1c618fa33cfbd98a,
+            ])),
+            Felt::new(BigInteger256([
+                0xb5611f7d3b6332ef,
+                0x290ea36c422a4bb7,
+                0xcadfde10a70b69f5,
+                0xff2af1e573317139,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x89e2d654b0f0ef9f,
+                0xc3c78bebaf319641,
+                0x6607415802b81204,
+                0x680a0bb4fe20f159,
+            ])),
+            Felt::new(BigInteger256([
+                0x214ec6e6bec05ee8,
+                0x5b1241f09dfabbf0,
+                0xb4bd53d5af3b0aef,
+                0x88cf69912b80345c,
+            ])),
+            Felt::new(BigInteger256([
+                0xa2e4cc961ee65f3b,
+                0xbabc22837c8a006e,
+                0x61ca7d7e67edc0d5,
+                0x1449a77541cbf896,
+            ])),
+            Felt::new(BigInteger256([
+                0xf16b24d51c522bcf,
+                0x3822c72f66fa8d35,
+                0x2f6a6b63618780a2,
+                0xbec85ffa72ee5517,
+            ])),
+            Felt::new(BigInteger256([
+                0x1969592b0a01fd81,
+                0x82a61ace24bc63c4,
+                0x687b6681e72af1f6,
+                0xa16d3c0b6cf8afd7,
+            ])),
+            Felt::new(BigInteger256([
+                0x8202cbfcd4edd145,
+                0xd4dcb96b64c01a1e,
+                0xb5f3ea804420fc48,
+                0x5e2bb5781423a3cf,
+            ])),
+            Felt::new(BigInteger256([
+                0x0806335647498394,
+                0xe5f65fe6177f985b,
+                0x018fbee3a4198b0a,
+                0x23812fb9c31a465d,
+            ])),
+            Felt::new(BigInteger256([
+                0x16e084039b771d73,
+                0x3f3ccf9bde164916,
+                0x53375b87fc6d801a,
+                0x1e9095e07ee01993,
+            ])),
+            Felt::new(BigInteger256([
+                0x2584471f16b60049,
+                0x107954a6cebb0363,
+                0xf20eb493b8f4fc64,
+                0x15a2181a0d654a06,
+            ])),
+            Felt::new(BigInteger256([
+                0xcbee2c337a0da065,
+                0x48c21a4b5e3b865e,
+                0x3538e4c128d1c20f,
+                0x4328bb72261c6e7a,
+            ])),
+            Felt::new(BigInteger256([
+                0x0d640e452ca60bd9,
+                0xf113dbba9b042d6d,
+                0x42461a67c0635706,
+                0xe4a3d85f5e698d91,
+            ])),
+            Felt::new(BigInteger256([
+                0xdc79afca5c4d67d4,
+                0x732fd94ddb9f0f6a,
+                0xac46d831766aa2ff,
+                0x69583cc4bddf2377,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf1c486c7690883f3,
+                0x25f0fcffb5613908,
+                0x3b5d8e58aba9a5fe,
+                0x4345c765c85c76c3,
+            ])),
+            Felt::new(BigInteger256([
+                0x20235811bbcd117d,
+                0xad4671d52327cf83,
+                0xd65ece4815b5baeb,
+                0xf5500cc537f5b7b5,
+            ])),
+            Felt::new(BigInteger256([
+                0x6b857e05fb7e8253,
+                0x79468a48c0a27ba8,
+                0x656914082d9da619,
+                0x6e0335d5f6d54783,
+            ])),
+            Felt::new(BigInteger256([
+                0x95d077a32096a527,
+                0x6a8e626e65096a8d,
+                0xa064166275ee1b70,
+                0x5b354aff2ce5c0b6,
+            ])),
+            Felt::new(BigInteger256([
+                0x7e0f337fab328070,
+                0x2342a7ad0ce1f970,
+                0x06017c26f58fbce6,
+                0x876e78d21ba1dbd2,
+            ])),
+            Felt::new(BigInteger256([
+                0xe6a1d40d47e8d5c9,
+                0x72b5e064fc3b1cb1,
+                0x7b9c8a695762ccec,
+                0xed3344c24a7fc998,
+            ])),
+            Felt::new(BigInteger256([
+                0x996f6e2e3004084e,
+                0xdd9dbe36226ddf67,
+                0x7d1f423d3f174c42,
+                0xa495ceeabfe893af,
+            ])),
+            Felt::new(BigInteger256([
+                0x130c78efe2239f2c,
+                0xeb9294188d008f41,
+                0xbec9189f0f6fdd41,
+                0x604b6fc499be2481,
+            ])),
+            Felt::new(BigInteger256([
+                0x625e85cf0f8b88a5,
+                0xa708ded9f2da40a1,
+                0x60a83f760bc78a85,
+                0x1866094027436152,
+            ])),
+            Felt::new(BigInteger256([
+                0xad8a41d1def215cc,
+                0xb32423e27b1b674c,
+                0x3a518eb5491e1947,
+                0x5db5af50abc5f5c3,
+            ])),
+            Felt::new(BigInteger256([
+                0x09ef1c84455be0cf,
+                0x474b57f62c2a882c,
+                0x4a206d15bb571347,
+                0xec9cf8a34d8d0e5e,
+            ])),
+            Felt::new(BigInteger256([
+                0xd8a19fc4e30310e3,
+                0x6c17a9500885a863,
+                0xd5c769d1edd41fb5,
+                0x3d33affc124c4ff0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x9b700eb659590eba,
+                0xed3ce212556c9488,
+                0xf087a130a7b30d78,
+                0xf166376120a602ff,
+            ])),
+            Felt::new(BigInteger256([
+                0xf661b0f516d5a54e,
+                0x5e60e66ee7689d6a,
+                0x25b1aed3f2b54708,
+                0x66cf36d28b6ffdeb,
+            ])),
+            Felt::new(BigInteger256([
+                0x6cd891c2b78ff19d,
+                0xf25f4c591c505a03,
+                0x751fdb946d17d7e6,
+                0x4f3cf254b09e5969,
+            ])),
+            Felt::new(BigInteger256([
+                0x422a0a15d582272c,
+                0xdef40b6eaa681393,
+                0xa5c7b067ef5ae151,
+                0xd82624359b1180fe,
+            ])),
+            Felt::new(BigInteger256([
+                0xd2874c9ea15ef865,
+                0x254a34c6fa5973da,
+                0xa11dbf24abb39e35,
+                0xb1c44fec526a6d9d,
+            ])),
+            Felt::new(BigInteger256([
+                0x1efc93aff5e3cc10,
+                0xea6aa25249de529d,
+                0x589ca1654157ecc9,
+                0x1069d7933a7376da,
+            ])),
+            Felt::new(BigInteger256([
+                0x838dac95307b0b34,
+                0x89327f14f9cab45a,
+                0xf28f722299d3cf7a,
+                0x532eac6a96efac4f,
+            ])),
+            Felt::new(BigInteger256([
+                0x2a068177f4c8ba63,
+                0xcbd648b120c5027a,
+                0x0202e3d960d2bdbd,
+                0xa5f7149e8d51663c,
+            ])),
+            Felt::new(BigInteger256([
+                0xa9ff9b27ff15d0af,
+                0x9cae74379359c0fd,
+                0x39e4aaa8f1a8a699,
+                0xcc42611777f7e2d5,
+            ])),
+            Felt::new(BigInteger256([
+                0x4326936da0067c1d,
+                0x8e3680f165163b48,
+                0x77fff7646c520dd0,
+                0x3b313a70e5d731c2,
+            ])),
+            Felt::new(BigInteger256([
+                0x3373dfe2c706be16,
+                0x56381b5d279a76a1,
+                0x957e3961c5564cb9,
+                0x7bf7182c44efb9d4,
+            ])),
+            Felt::new(BigInteger256([
+                0x706d216825b8421e,
+                0xe909c647d2a0b282,
+                0xa73f00731d6d02e3,
+                0x83744790402e007b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x946cd4ab712b2dc3,
+                0x2b6d43c6a1a06e1c,
+                0xa75becc016a7b114,
+                0x884a6f69bab73d1d,
+            ])),
+            Felt::new(BigInteger256([
+                0x612a1235b303515c,
+                0x265e1677199d5a5f,
+                0xa76ef5cdf64b3261,
+                0x4568ff4996a8ffc5,
+            ])),
+            Felt::new(BigInteger256([
+                0x5dbf768b8c116ac1,
+                0xb54e2db9091b7b3b,
+                0x15616bab560ee0fa,
+                0x1ad178ac4ccb3c6b,
+            ])),
+            Felt::new(BigInteger256([
+                0xb148b30997ebbce3,
+                0x8380f0c5f6ea8d83,
+                0x902f8702d5d68e84,
+                0x465dd4164390b0db,
+            ])),
+            Felt::new(BigInteger256([
+                0x013e68f00baf40d0,
+                0x0b75b5b31d021067,
+                0x19ac80e27b85a63c,
+                0xbf2b72301234cc70,
+            ])),
+            Felt::new(BigInteger256([
+                0x7a8c4deb465b1e87,
+                0x1502af9013a6b7b3,
+                0xd3bf7d92eb066ac3,
+                0xd8bfc30d28823821,
+            ])),
+            Felt::new(BigInteger256([
+                0x8c464a6581eca177,
+                0x140ba9498c03049b,
+                0x3df77c9339d85b65,
+                0x14e1f0382de35d32,
+            ])),
+            Felt::new(BigInteger256([
+                0x55ca56067a12dda7,
+                0x11e2fe22faabb17c,
+                0x90764c913010a6f3,
+                0x6e7681785d7a163c,
+            ])),
+            Felt::new(BigInteger256([
+                0xf24aefb74597368e,
+                0xaa82d51aecaa876b,
+                0x78ed9d18a93cc73e,
+                0x13891f84b04a47b3,
+            ])),
+            Felt::new(BigInteger256([
+                0x0aa763b2e6289960,
+                0xe388bdddc17998e4,
+                0x2738529eb6fbd504,
+                0x5ea2964ab0248a55,
+            ])),
+            Felt::new(BigInteger256([
+                0xace1d643024fd6d0,
+                0x1b2410ae798be86e,
+                0x7a7275fd47e953a9,
+                0xbfb9bdf39a2b0a78,
+            ])),
+            Felt::new(BigInteger256([
+                0x769d255a02b7e636,
+                0x98310eeb4b676460,
+                0xf72be175c6086c2a,
+                0x365f4eb99d0becb8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xbef48569e33ef6fc,
+                0x7e1641e167b689d9,
+                0x5e416885c10b7acc,
+                0xa3435fc8d498ca37,
+            ])),
+            Felt::new(BigInteger256([
+                0x88ce85f837e9d74d,
+                0x4c97b235889202ba,
+                0x3a9f39c0af50755d,
+                0x1557fc83ff1fb049,
+            ])),
+            Felt::new(BigInteger256([
+                0xbb84edabd227b2ba,
+                0x049487498c52baa7,
+                0x0de5283f09a035a1,
+                0x6340a6abb527075c,
+            ])),
+            Felt::new(BigInteger256([
+                0x63d875fde7d4c2ed,
+                0xfa5a04d5c0e5b7f7,
+                0x9120207ee0c78a62,
+                0x7417525d1e44f48f,
+            ])),
+            Felt::new(BigInteger256([
+                0x7539ca3de73f2373,
+                0xf57e3589f4e3da5a,
+                0x6afc75bd656a5025,
+                0x6ffcb5263d2a17bd,
+            ])),
+            Felt::new(BigInteger256([
+                0x440bc8ac6102c2ce,
+                0x2a65a1e3de6061a3,
+                0x50ad42633b72a115,
+                0xa6496e4974799815,
+            ])),
+            Felt::new(BigInteger256([
+                0x0fa6eaf4d0f9e5c1,
+                0x0f811db52f8cb3c4,
+                0x0ab1ed75c24e3af6,
+                0x8adf65a9e18e1859,
+            ])),
+            Felt::new(BigInteger256([
+                0xf1202dea9cfe0d19,
+                0x014cff0dcaccafc1,
+                0x5f18d8da9ad352be,
+                0xf8442e3f0239fdfd,
+            ])),
+            Felt::new(BigInteger256([
+                0x98688a7a7de73e80,
+                0xa4635108572f7bab,
+                0xfb2a0565871f2130,
+                0x941757075939f987,
+            ])),
+            Felt::new(BigInteger256([
+                0xacf44cb01d5c136e,
+                0x1709e4ed2f4c2f37,
+                0x0b0dea17a2333f06,
+                0x7d8d6806ebcf87b0,
+            ])),
+            Felt::new(BigInteger256([
+                0x28c30cd02456d83a,
+                0x51d8077513ee6402,
+                0x343ae3080b5e8fbb,
+                0x3d3e5763d619091c,
+            ])),
+            Felt::new(BigInteger256([
+                0x51953b0e74a91ab1,
+                0xa122129505c47a2c,
+                0xff12936f8ca1a3d3,
+                0xcbbac2128eeffd35,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7e3e8035efb7fbc0,
+                0x5ec1628bef1420cf,
+                0x2ca461b1a3fd1ae5,
+                0xaab24400e1860edd,
+            ])),
+            Felt::new(BigInteger256([
+                0xa74567c014451c0f,
+                0xba5458becbdff497,
+                0x0fca026961a49bfa,
+                0x8f9ac4d985b3a85e,
+            ])),
+            Felt::new(BigInteger256([
+                0x3fab6c59d8d5a9a6,
+                0x9a92d147b6c3fe24,
+                0x91a03bac05495b68,
+                0x0f33bc2cde49c5fb,
+            ])),
+            Felt::new(BigInteger256([
+                0x3b54d569adec25de,
+                0x17dd821ab9ffc71b,
+                0x62f9fc12d6669f95,
+                0xdb61dbfc2b93c983,
+            ])),
+            Felt::new(BigInteger256([
+                0x2821eb5455506b5b,
+                0x674b3739bac9cca3,
+                0x22663aa3232b1d03,
+                0x468211f3d6bc90da,
+            ])),
+            Felt::new(BigInteger256([
+                0xfe37409119eef5e1,
+                0x58805640a7700df2,
+                0x37edc191616267c2,
+                0x20265639b0352e60,
+            ])),
+            Felt::new(BigInteger256([
+                0x3e78f11cb39bf568,
+                0xcdda72cb5daceafd,
+                0xc7ff40f6abb80cbc,
+                0xdf77f8b644aa18ba,
+            ])),
+            Felt::new(BigInteger256([
+                0x16dac9c42d27292d,
+                0x67f9498644036293,
+                0x7dfe059b4f72639b,
+                0x93da038ce4dce941,
+            ])),
+            Felt::new(BigInteger256([
+                0x40bd9bfd6db6733e,
+                0x170347c953f7aa57,
+                0xf234cc3dd504b40d,
+                0xa362ed8da6943ba5,
+            ])),
+            Felt::new(BigInteger256([
+                0xea52daf608e4ca62,
+                0x19742232e58edb49,
+                0xb6f231ec4370e735,
+                0x13a1d33f307ba3eb,
+            ])),
+            Felt::new(BigInteger256([
+                0x74cd6eb3d0d58599,
+                0x0f91c11405769754,
+                0x3316938f3ade9862,
+                0xdf9c6c7be1101ec1,
+            ])),
+            Felt::new(BigInteger256([
+                0xe6bcc612f50df49d,
+                0xbf347b875ed70909,
+                0xbc9ab86f0ccd062c,
+                0x5206cb881bb82c36,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0xa1654a4c62066820,
+                0xfbef8408e1c76020,
+                0x666903275897ab16,
+                0x3ea9d5827aa4bc1f,
+            ])),
+            Felt::new(BigInteger256([
+                0x701232a3e586c394,
+                0x17f65790eed5fa91,
+                0xdfe30de051d75d8d,
+                0x0f38910abd242a77,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x17f60e4ab9c38881,
+                0x330bc4e65b6e3c1e,
+                0x7810667d146edfe1,
+                0x408c8735728baa87,
+            ])),
+            Felt::new(BigInteger256([
+                0x2e3683572c1b1eed,
+                0xa506352f145b812a,
+                0x50000a340ebf7f17,
+                0x98b82e87d2d14e20,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x13a9d37a4231ce9a,
+                0x703f7104b029f7a7,
+                0x112807cb5e6600c2,
+                0xb3ffdd7d44bdb2e3,
+            ])),
+            Felt::new(BigInteger256([
+                0xc5e99ff43863d7f6,
+                0x13abca0949a3cd5e,
+                0xfd12c89973106f99,
+                0x5384a377bb37caff,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xe9eae6ff00835a4f,
+                0xd4259c3d0f65c40c,
+                0x00ac709585e0e65f,
+                0x525798c4ed2f4de9,
+            ])),
+            Felt::new(BigInteger256([
+                0x0d8e6cb456df3c7e,
+                0xabaa1222db4b0611,
+                0x2d6f5c66d81fdaf4,
+                0x541983ca4eb2009f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x05f3ceb8e7854886,
+                0x8707cfa38ac6b099,
+                0xb3e34e39b52caa12,
+                0xa9fa2793e65deeb2,
+            ])),
+            Felt::new(BigInteger256([
+                0xa4a15f7c34aa9a74,
+                0x357864ea7251149b,
+                0xc38a2e61e021e63f,
+                0xa4c2e0546e01d2c0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x3c8d8635ba169aa2,
+                0x999aa4e0dd9fe111,
+                0xd3db4cd544412b63,
+                0x4000a0a00d22e784,
+            ])),
+            Felt::new(BigInteger256([
+                0x6296eac0e62bb10d,
+                0xa42db4765fdb6338,
+                0x80b4fd3ee84d241c,
+                0x1e6b4f733502242e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x151a14526c116d91,
+                0xa02e40bda6b8b9fa,
+                0x38aebf4b412ab347,
+                0xabf9ab61d36a95eb,
+            ])),
+            Felt::new(BigInteger256([
+                0xbc87344be1e23d73,
+                0xd6f585a61fdd3214,
+                0xf9df87a059ac5b3a,
+                0x36412f417a01018a,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xa83f2849648ed8bd,
+                0x2ae2403cd42fd728,
+                0x856b46ab19b76a1f,
+                0x9c6fadf54ee26976,
+            ])),
+            Felt::new(BigInteger256([
+                0xa403c100c6c64948,
+                0x9c06752d636decdb,
+                0x08db0083a0282221,
+                0x90f064194ab6beef,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xf4c7901adecb5932,
+                0x397c0044bf419932,
+                0x01a278e3def6ccb0,
+                0xffde0b800dbac943,
+            ])),
+            Felt::new(BigInteger256([
+                0xf4b07e5c39132297,
+                0x784512256ec6c8fa,
+                0x236586fc8e43b5c8,
+                0x28aaed15eb2b5e77,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x0b51393a9d3d01a7,
+                0xc2e6908e4fdb9032,
+                0x7067bff87f46a72f,
+                0x0ea21602cfc415ef,
+            ])),
+            Felt::new(BigInteger256([
+                0x44b6b90b6b4d33a1,
+                0x828649ac3c30fb26,
+                0x45124d8307a0e6b8,
+                0xd3ad9092f429fbb1,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger256([
+                0xc1d817e0cecf4e11,
+                0x7ac39c0398222f0e,
+                0xa77b17a02385621c,
+                0x4ad6b614adecac8b,
+            ])),
+            Felt::new(BigInteger256([
+                0xc7f26bb24360282f,
+                0x667cd2ba4348aa75,
+                0xac58aa628f57b88d,
+                0xad62bef5541b287d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7c9cc37549fdfe80,
+                0x74395337b99d8bd0,
+                0xafe31530baf66841,
+                0x711346ddc802f930,
+            ])),
+            Felt::new(BigInteger256([
+                0x326dc10f7219c674,
+                0x648d6cf28979e7ea,
+                0x850546932d871b99,
+                0xaf2dc57f8e8d1ad8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x149c8bfa7ffea7f8,
+                0x2c076bd2f104993e,
+                0x44756da04ebd0b9a,
+                0x679e804d38b65460,
+            ])),
+            Felt::new(BigInteger256([
+                0xc2b2faf6dcc6cd74,
+                0x36f84fc7b63c1e44,
+                0xa512a9218313e329,
+                0x538168110ca3efc8,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x9573c1d1a1e18acc,
+                0xaee87bf0d21f8ce3,
+                0xa54bf3073d83df73,
+                0xe34ae0ffc460aab4,
+            ])),
+            Felt::new(BigInteger256([
+                0xc1e5514b0bdb1ac8,
+                0x5867b8aa52a89c70,
+                0x6bc4475facbd5265,
+                0x2a8cd5b732b89913,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xf9bc65aa77fd6dab,
+            0x94eff35ffba287f1,
+            0xbe8270a72751893b,
+            0x39bb9661644724d4,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9ccdb34f5a3b8566,
+            0x02b24fc5816efb31,
+            0xe1a7889ee93ba083,
+            0xc45dd71264499307,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x280d02e1eb382402,
+            0x314bf1d5e3c73b11,
+            0xc3e45d890125010e,
+            0xca1c4bc7d322c546,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xff95bc8343734149,
+            0x8e74adc16ed171e9,
+            0x5adcb66332c5c8a4,
+            0x8cc3e851ec09f359,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x88ac232ffce3b807,
+            0x145944116ad6caf1,
+            0x135b27285d9edc47,
+            0xbb3bc2b6873997aa,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x39c3d68eb71aadf9,
+            0xf0fc17191969b139,
+            0xdaebc31235bfb6a1,
+            0x9aa2038f4569ff07,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x97fe252fb315a8e8,
+            0xe1bef5043903e97f,
+            0x2565b8d0fbc013be,
+            0xa7699d606518beb6,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8dafac932eb6894e,
+            0x596d11b245568926,
+            0xbd991dd36979ea67,
+            0x7cae542149702f73,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,230 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5 6]
+/// [6 1 1 3 4 5]
+/// [5 6 1 1 3 4]
+/// [4 5 6 1 1 3]
+/// [3 4 5 6 1 1]
+/// [1 3 4 5 6 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc088e4598f5d3c3d,
+        0xcff3694bf2261f4c,
+        0x0000000000000003,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00b685ccbf26fafc,
+        0x15448c6542dd7f11,
+        0x0000000000000005,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x40e4273feef0b9bb,
+        0x5a95af7e9394ded5,
+        0x0000000000000006,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x8111c8b31eba787a,
+        0x9fe6d297e44c3e99,
+        0x0000000000000007,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x8111c8b31eba787a,
+        0x9fe6d297e44c3e99,
+        0x0000000000000007,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc088e4598f5d3c3d,
+        0xcff3694bf2261f4c,
+        0x0000000000000003,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00b685ccbf26fafc,
+        0x15448c6542dd7f11,
+        0x0000000000000005,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x40e4273feef0b9bb,
+        0x5a95af7e9394ded5,
+        0x0000000000000006,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x40e4273feef0b9bb,
+        0x5a95af7e9394ded5,
+        0x0000000000000006,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x8111c8b31eba787a,
+        0x9fe6d297e44c3e99,
+        0x0000000000000007,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc088e4598f5d3c3d,
+        0xcff3694bf2261f4c,
+        0x0000000000000003,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00b685ccbf26fafc,
+        0x15448c6542dd7f11,
+        0x0000000000000005,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00b685ccbf26fafc,
+        0x15448c6542dd7f11,
+        0x0000000000000005,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x40e4273feef0b9bb,
+        0x5a95af7e9394ded5,
+        0x0000000000000006,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x8111c8b31eba787a,
+        0x9fe6d297e44c3e99,
+        0x0000000000000007,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc088e4598f5d3c3d,
+        0xcff3694bf2261f4c,
+        0x0000000000000003,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc088e4598f5d3c3d,
+        0xcff3694bf2261f4c,
+        0x0000000000000003,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00b685ccbf26fafc,
+        0x15448c6542dd7f11,
+        0x0000000000000005,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x40e4273feef0b9bb,
+        0x5a95af7e9394ded5,
+        0x0000000000000006,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x8111c8b31eba787a,
+        0x9fe6d297e44c3e99,
+        0x0000000000000007,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc088e4598f5d3c3d,
+        0xcff3694bf2261f4c,
+        0x0000000000000003,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x00b685ccbf26fafc,
+        0x15448c6542dd7f11,
+        0x0000000000000005,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x40e4273feef0b9bb,
+        0x5a95af7e9394ded5,
+        0x0000000000000006,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x8111c8b31eba787a,
+        0x9fe6d297e44c3e99,
+        0x0000000000000007,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+];
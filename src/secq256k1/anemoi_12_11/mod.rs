@@ -0,0 +1,2441 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0a4ef928f5e42712,
+                    0x232774927ff7a05c,
+                    0x8a7d41c851b8aa09,
+                    0xb13105d85469eb8e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a32ebc2b98c5dbd,
+                    0xabcd8983197a3bce,
+                    0xe0befc1b9b2c6042,
+                    0x4ba17dc8c911980c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe20766b728be251,
+                    0x0f263119849b56f3,
+                    0xa89cd08714d3fa6a,
+                    0xa974907cc5bf8627,
+                ])),
+                Felt::new(BigInteger256([
+                    0x675b8e2d8a883b1d,
+                    0xdf8484e4ffdd2501,
+                    0xbb7c202584f39395,
+                    0x23f28aead71ec139,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6efd0964f70bd82a,
+                    0xfa268e6ab8bf2ea3,
+                    0xb55dba38f6bf9ff6,
+                    0x7fc61eab791e4e77,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe56112b2e8f6ef0c,
+                    0xb1c1926577377511,
+                    0x89ab82fc93794df2,
+                    0x027cfbae18f1fa38,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7f1bacc7f3e5e5fb,
+                    0x41be8de65dc28157,
+                    0x9d3725a89edd1d2b,
+                    0x032ab175cd16dd37,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaad44e1d6744ba17,
+                    0xdf18a13f4070d60e,
+                    0xf98cb660b9272e24,
+                    0xa762118ae73c97ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd61d3971f8d1becc,
+                    0xa964054e8d829091,
+                    0xe88e2559a4a2d3f9,
+                    0xc2553113f58e0c29,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ebf3c6bb00fe076,
+                    0x709f8a3c670bd54d,
+                    0x1a87815e627b38c5,
+                    0xeea33aa6b6bebeac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x395c378b17a10075,
+                    0x9b5c57d1b420bb24,
+                    0x6947bdef7663db1a,
+                    0xcff67879193a01fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe84dbbd5f91b2c05,
+                    0x4df3b8a1f74d57d8,
+                    0x70ff5ecc23c8f46e,
+                    0xb642834ae9695bc6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc7ff5f7c6eac6366,
+                    0x7568662204d68e32,
+                    0x04aaf874145fdac9,
+                    0x3cd029c00a290a5c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5dca042a0fe5e747,
+                    0x6163dca8ec11e11b,
+                    0x110136fbaddd1e5b,
+                    0x35790f529149c1f9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5024131ee85403f6,
+                    0xf3dd2355c5a25db3,
+                    0xd81aa54268c4e2a4,
+                    0xa530574be3e0c487,
+                ])),
+                Felt::new(BigInteger256([
+                    0x70dc1809cdbbb775,
+                    0xbc7dab6299c38635,
+                    0xfbdbdd67904644f6,
+                    0xf3f06a3becd94cd2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c5b41c896c16804,
+                    0x92eb2aa1c26c44ab,
+                    0x8af9200931f2df5b,
+                    0xe0855f50393e36c9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef14c19155a828fc,
+                    0x096aaec73b177287,
+                    0xdd90fcbdc56d64a1,
+                    0xf2fd20b3da13bb62,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc3ad2cb1a633d31,
+                    0xbb46daa606267f9f,
+                    0x95a21696e16d6e3e,
+                    0x21d0bb550cc1134c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb923c1847d8b35c1,
+                    0xf3555c3f7effc50a,
+                    0xfe57a8cdf7e3d062,
+                    0xa5c645f6eabf2e71,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3cef1c871805c724,
+                    0x61dda0b685b4e2bc,
+                    0x2f39511ff99cf6e2,
+                    0x9fb35d6d0d05cc68,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa5693c5bdbb8971d,
+                    0xb713d1340ca015bd,
+                    0xfa9d09217343c343,
+                    0xe5f148fc4c9877cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf33edfbfb52cbf1e,
+                    0x12b7b608a091e3b5,
+                    0x46355bcfe791ee8e,
+                    0xf1cd956a5a838e82,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5793e44f323c9c15,
+                    0x507def80e700d764,
+                    0xd95c3c5fedf829a6,
+                    0xda4cc293dada4515,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x471c811ab3592d89,
+                    0x769adca4dae35b07,
+                    0x3fa70417a65674d9,
+                    0x27bdc0d7288ad9c2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x805d97230b79ec5e,
+                    0xeafc2d750152b627,
+                    0x19022d06ce0c9c9f,
+                    0xfdbb7f61e8543bb8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x359fbe62c919edeb,
+                    0x83a4cfc93075c542,
+                    0xa869d1a827df2d77,
+                    0xd24a3a39cc904283,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6e9bcb3cba0614e,
+                    0x711e4234348308fc,
+                    0xe4ebef2581e5407f,
+                    0x20078149d704fb05,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83d1e8bdb2bb259f,
+                    0x10ca289d4491594f,
+                    0xdd5106a0414d98cd,
+                    0x653dba854a7016dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6a52c167e068372,
+                    0x64a10d41f26aa9fd,
+                    0x40d296dd034c462e,
+                    0xedd12722d19aee71,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe5ce9e1e3b81bfb0,
+                    0xec41753da82f38ac,
+                    0x480571bc408df6d8,
+                    0x3e6842a6762017a4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x27ec8b4e6f2d7600,
+                    0x559bc4511f204b7f,
+                    0x09ce89546b41b4f7,
+                    0x4c11690909a81f35,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa2e2069888aba7b4,
+                    0x39019aa393a9bd49,
+                    0xf9e18bc153396200,
+                    0x1a387fb48d748377,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4db32d7a1d2470c5,
+                    0xe7fb30c9b7ea17c2,
+                    0x6ed0a29a57d26e5f,
+                    0xad315b7b692361f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae46c7d2ec59c02a,
+                    0x4277c01d941c70e8,
+                    0xad7c6a6f55f74898,
+                    0x95beb86fb827185a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c36672eac6d2132,
+                    0xafdbe780058cf991,
+                    0xdb915f74445f4003,
+                    0xd79a56b1971057b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x243058a52f41d341,
+                    0xeb657865e66da469,
+                    0x75f700f6a399ac30,
+                    0x22c0ddc17b343ddf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a6ddb951515f0f8,
+                    0x9fac1d5584623dcc,
+                    0x6cbb4801a9e0823f,
+                    0x7f9c56a640f5944b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x50d9084ada0924f6,
+                    0xb86a7196994fed99,
+                    0x07df977aa2eacfa1,
+                    0x437ce4516352e0fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x970129cbf08b071e,
+                    0xd9f86457c2cd406f,
+                    0x862c78fd1f1a7ac1,
+                    0x6e98d826f19208dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2cb861ce826e366e,
+                    0x9c9a298e0791a56d,
+                    0x69d434de9ccff77b,
+                    0xaf01bd72669aee38,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc6cabbe402aa7d4,
+                    0xe008de957d8d2193,
+                    0x697ac4a887898d77,
+                    0x6180ab73acbf9b8b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x634fc9f5e7cceedc,
+                    0xfd004e4d17d637c4,
+                    0x2e21ef2efd8d2b0e,
+                    0x25349f1f78975f71,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcee6828032b3d59c,
+                    0xe8ad789ccc774674,
+                    0x5c3c5bb7d4e2ea0b,
+                    0x70fbefbb7606d229,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6f0698a80bea3da,
+                    0x0a5b93d177a6d0b2,
+                    0x7e2fca4c46e273f6,
+                    0x72f23f3a207be7f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc95174e1eb17ccbf,
+                    0x17ed199a64d90c47,
+                    0x09707ad3e66573b6,
+                    0x9b41f22d9851a942,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71ef11abd6327ee1,
+                    0xc33a6eb81e8f76c7,
+                    0x1abe00a24755cef7,
+                    0x7b20555d335b3c57,
+                ])),
+                Felt::new(BigInteger256([
+                    0x31eac3b9ca0ab2d6,
+                    0x5ad653b6a1bfab49,
+                    0x0481dc8fe4307280,
+                    0xd7a80b6e6f4ef2e1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xac258fd1d7881b1e,
+                    0xfd12f2fc417d72b5,
+                    0x443d4d230c2809ef,
+                    0x0ac0c254a8b708f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x188c1cf85e2dd6f4,
+                    0x67282af51f7f7b26,
+                    0x1522e7696e06d1ea,
+                    0x844aecadeadaeed8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe57bedea1f7acdec,
+                    0x9d55956720b2987f,
+                    0xebb14a61f5956d2a,
+                    0x032773b1a8bf4dcd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x17a78bc3b3dd8f74,
+                    0x25ebba380ffcc317,
+                    0xc9bb0505aa71cb4c,
+                    0x630d2c1a7273772e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41e92eaebbe1b92a,
+                    0x0353a59ec6dda912,
+                    0x72764993f3d27d85,
+                    0x950c5231d9e3c627,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ad2aa2b01fae54d,
+                    0xae806ed5ff9e5b78,
+                    0xd412833f323e29de,
+                    0xf3192b50b0a1918d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7ff05c35d8a68135,
+                    0xb203fe46d53f641a,
+                    0x4e97c4c4ce645dc6,
+                    0x9017aa74e2122b4b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c42aae8cf3eb669,
+                    0x0d186d4a1e0a1dd6,
+                    0x79750db11cd0bbeb,
+                    0x724be91aee25e270,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa235026dfa84b702,
+                    0x32b4ecfe0530cafd,
+                    0x35816cbff392e757,
+                    0xa788b96486073c0d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5d723c5304a82dba,
+                    0x5064f5414991b392,
+                    0x8c6b1537ec5a8ddf,
+                    0x4dbdad6953335e3b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6952cbca451cd90a,
+                    0xaa11886838e7049a,
+                    0xbf1735f1259e9132,
+                    0x6c8e0029e442aeaa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd4ad074762375475,
+                    0x405bbf163e1111c0,
+                    0x010d267441ef8569,
+                    0x0dfe2e2f77321dba,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x70bb07abd443446a,
+                    0x2a275804fe20ea13,
+                    0x889a1095b23ebe84,
+                    0x47864f0ed60727ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0x39863fa05accd9bf,
+                    0x275f3f70eadf6cfe,
+                    0x827f8ae18b13fc51,
+                    0x4408268157b0759d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b9ee4188b5c96b9,
+                    0x605b86459ae00f08,
+                    0xb6d4d7dad1950c41,
+                    0x644ff1d16f32a256,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaeb272aaf012137f,
+                    0x93992248514321cd,
+                    0x3f1e4c340f667973,
+                    0x653f765613318894,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7d00b7d7bea14457,
+                    0x6e707e72e2030241,
+                    0xe4421f19fded1cb2,
+                    0x139875389a5d64f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8885a42f0d2a2d5,
+                    0xc9407a2716a4e6e1,
+                    0x871865cc1e0a29a9,
+                    0x14ed3679261f3320,
+                ])),
+                Felt::new(BigInteger256([
+                    0x996bd660e097df23,
+                    0x9b60092e37e62c1e,
+                    0x87b706019631945e,
+                    0xf78c7565814c681b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2550d93700bf0bf6,
+                    0x11d210d7b788fc10,
+                    0x32117c522f356053,
+                    0x198c8810d3690da3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7613135521449e34,
+                    0x1a5fc7a21a56ce9a,
+                    0x11a40f0c6806d357,
+                    0xc4366e7d445387f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x75c2ee3ae16aee6a,
+                    0x2d4595e2e96b9cee,
+                    0xa2deb35a7fe277aa,
+                    0x67906d6efc2ee89f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61e3efc5afa81411,
+                    0xe42b6681c5cde591,
+                    0xc56421d12a9af069,
+                    0x8bbcb19fc4e45413,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c08173e5941e549,
+                    0x257fbe586d49045b,
+                    0x6afcbbee4c22f284,
+                    0x6b462bd4d75881c9,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8dfb6cf9f3af7798,
+                    0x640421be22e2b24c,
+                    0x2af570c6b8d000db,
+                    0xcdb1758dbae4ae1b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6898ee1df20d52b,
+                    0xc4ff557e0c7afcfd,
+                    0xfb0443c34a83f9da,
+                    0x73a2482e238a5295,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb142ff0241598477,
+                    0x74286167f8f56016,
+                    0x9cc95b93f943a26e,
+                    0xd477579f1e4ad1b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fb618556a2515aa,
+                    0xd3fe5c3b0a179b86,
+                    0x2c92058114888544,
+                    0x212e3106fefb9bc3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf20c2005a912a61e,
+                    0x4f4cc832d37e0ee9,
+                    0x3d2948b5a0236b1a,
+                    0x9bc1ee3f32a646f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbfccc40378ef3360,
+                    0x002b4a4d3dac7d87,
+                    0x09384463fd4b2475,
+                    0xa2b22310a7edd34d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2fcd3d86c8d91e5,
+                    0x5dd6750933594bd3,
+                    0xb08c8c93039708eb,
+                    0x0ae09fb17918cc46,
+                ])),
+                Felt::new(BigInteger256([
+                    0x800a5b3531f8dab2,
+                    0xfaf14a8080176723,
+                    0x26b8c084bca748e3,
+                    0xe5e43a6e4e2facc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa25f448f64bac76e,
+                    0x2a7473243ac29fd0,
+                    0x443b280508562250,
+                    0x38a5c29a9c32bf69,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89ec9d7fc650bd8c,
+                    0x0633ec34fd958ca5,
+                    0xf0a0d17ac0674e1a,
+                    0x4e3bc25a93308f55,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5534c6e28030b3c4,
+                    0xd01ce7b890050432,
+                    0x9438deffc5bc083e,
+                    0xd9aebe32bb5135cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x872752e4664ead55,
+                    0x73b09cdce1920e00,
+                    0x9da3e0032fcd16b7,
+                    0xfb4ae5718103e50b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xee2a8770854b73be,
+                    0xac83e5ca81dcf057,
+                    0xddfb0a4545b1372a,
+                    0x9918245414b89913,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe595d20de86be8fa,
+                    0xb7e983e314ed31e6,
+                    0x5c99db906fc41d5f,
+                    0x0955330a7ce0d2cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe1ab1829206c8389,
+                    0xd910ab77d5e86054,
+                    0xa67713a7de3f8440,
+                    0xbe20b204d69817df,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00c511ea9b82ab56,
+                    0x67f7eebe7dfa933b,
+                    0x2046b07a959f388b,
+                    0x3748ac9de6e3cb60,
+                ])),
+                Felt::new(BigInteger256([
+                    0x009ea7e87fe538fe,
+                    0x0f6c36555ae8b7bf,
+                    0x8ce1439029c52563,
+                    0x78bbbeb4bb1a7c94,
+                ])),
+                Felt::new(BigInteger256([
+                    0x08307cda8839ff84,
+                    0x592cbb0a116cde0a,
+                    0x4601c4ee16ab8db6,
+                    0x4ee6ed110dba66a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa162f151209f7e4c,
+                    0xc9c25c8a1af83351,
+                    0xc5e4bfed91d807de,
+                    0x54236cf77c804b3d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x467c404601fb29ed,
+                    0x0d04e0a52f9f9765,
+                    0xb54283e70d0be6bc,
+                    0x18a61056387a5f7d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa497ff4759eed212,
+                    0x1caac191d5bc2cb4,
+                    0x019f9443bcc779cc,
+                    0x55b642a920a3d4b8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd0ac7cc6eadc4a9a,
+                    0x55885e86d5951ac7,
+                    0x4a977b3fb18628f7,
+                    0xfaf0bb7346912752,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26cb2192b2d1f694,
+                    0xe0e3891bfaf02de5,
+                    0xab12fc0c2a1282e7,
+                    0xd985913bb67e07fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b613addcad91f1a,
+                    0x19be95307437d794,
+                    0x0ae9305d730eaba1,
+                    0x2704a3698f6ed3f0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd60903ca0191d677,
+                    0x85cda28da61bfdd7,
+                    0x96f8753dfb253279,
+                    0x0e3967189b762560,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec228766c5186da4,
+                    0xdcc35c5eea0ffd8b,
+                    0x343644df26290c90,
+                    0x8830f78242686b6b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x680af701400827ab,
+                    0x339b7d15b24163a4,
+                    0xfe786bf7444ff8ef,
+                    0x93e87164c80158d3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa504ba1017cd8409,
+                    0xbbbd2131b0187d36,
+                    0xde9fa07adbb2bd32,
+                    0xc901f165437a8829,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5e9cedae11516b85,
+                    0xc40bd08d1579f568,
+                    0x23030e0200e034c1,
+                    0x043d8b308304a386,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b618995225bdd72,
+                    0x731e01ab4569a4e7,
+                    0x6993b73bf5d023dd,
+                    0x107e074536b45c52,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc4f01d8ca16d202b,
+                    0x6f049f93331a491b,
+                    0x58b65a2f896fa5fd,
+                    0x51a0f65e1d5d7134,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe0b15bb05b844a70,
+                    0xdb3ec004ca936680,
+                    0xb76443ba6a3c123c,
+                    0x4eabd22ff88742a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b32b26ba0fb2203,
+                    0xd03675f150bb89e9,
+                    0x64380405d8a1a26e,
+                    0x73fab50711135d91,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdf0361d409243be6,
+                    0xdb3779810436f76b,
+                    0x54bc5ec2beb78374,
+                    0x98fa3c571ed43c77,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd53398da81b5dd45,
+                    0xe2adbd9ffd21880c,
+                    0x8306022f054300dc,
+                    0xdfb8e7e40a9095d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74607ab12bd84ad1,
+                    0x1f47f8eabfde1a67,
+                    0x48149d50a680bd98,
+                    0x769ac045cd126c77,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfea7024befda1264,
+                    0x0bb3ba431f0f26a0,
+                    0xa55a4bdebd7b0450,
+                    0x04b084a53202c139,
+                ])),
+                Felt::new(BigInteger256([
+                    0x68fd031282af0901,
+                    0xd76a0e2708219afe,
+                    0x76d9030d3146b11c,
+                    0x0901572b50b4472a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9461acaa0085778a,
+                    0xf1b73e1dbcd08e12,
+                    0x80a0cf29e31d7f39,
+                    0xa0efdd3839e0c24e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18c8bc53ed563de4,
+                    0x7036fc0fd9de4e00,
+                    0x4823d09b29b31f53,
+                    0xaebca23643673c98,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab97943aadd7209d,
+                    0x186673eabd702893,
+                    0x12a5d658309f1ce6,
+                    0x7c355c710c8b3f73,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdab5e85a4e1df50f,
+                    0x37a8be1a67e8dbf3,
+                    0x93b3e933fd5c2097,
+                    0x9678ccb457d97afa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe5cf873ca8c00e4f,
+                    0x919935fb2006baa7,
+                    0x353af9a8da278bcf,
+                    0x1472855d469b7ad8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5d4260412cc53645,
+                    0xa05f27553cbceb3f,
+                    0xbb49288468660c57,
+                    0x2acd2503c3ed5fdc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7426bd02354bb9e3,
+                    0x2d56d0511602cef0,
+                    0x961cf60ed4f4becd,
+                    0xd6370ef0a4c2e319,
+                ])),
+                Felt::new(BigInteger256([
+                    0x820efc616429555e,
+                    0xd5070b12377127d4,
+                    0x08d1b755f86ed1e8,
+                    0xce7ef866f20988a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b15443d5071d6d2,
+                    0x641633ac068cb43c,
+                    0xad4b8ee2f69cf992,
+                    0xf97e1f9df598f4aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0eca76a7da73858b,
+                    0xf2a8b8d16906af39,
+                    0x723217074012f510,
+                    0x5d2bc69574aa00ae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc4cb8722df275453,
+                    0x13def6b047acff32,
+                    0xcfa41e7b5b89a081,
+                    0xcbbb97e01602d7bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x790c559da6c1a453,
+                    0x4373ac4154f60eec,
+                    0x31f8c8bd73d5d8ca,
+                    0xc7e8e74b13682d49,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae716b98b994e0b8,
+                    0x888853d7eb214b6a,
+                    0x8035fbadb9a9439a,
+                    0xeb1c46d80890985f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81d08a1dcf9b8f39,
+                    0xc116f36ff17262e8,
+                    0x6debb6faa84fa77e,
+                    0x3ad750c24dbdf8db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x710a57eb429f00cf,
+                    0xd15ce279424f4a53,
+                    0x59c781ee4a6134cc,
+                    0x2fa1e214e61e836d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d0d63586e80c6d0,
+                    0x25883749077d3442,
+                    0xdb0e708bfd0edd87,
+                    0x13465792d9c6f18b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x10789ae64c742df9,
+                    0x0382479e46b7703a,
+                    0x6f0490cb8bda84c7,
+                    0x183e408b30a1b86d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeadd89d235fc9d59,
+                    0xf456c9f6f4720e0c,
+                    0x684ed9b84711b9eb,
+                    0x775def179715c98c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc029dc9b1cdf6572,
+                    0x47ccdfdf4176f986,
+                    0xd3254e85149505c4,
+                    0xe640f4194ee44e67,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd35cfc63e81d6be1,
+                    0x304e13119d21d68c,
+                    0x341ce2e0701d49a1,
+                    0x25ac14c157894f7e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe5dc65096114528f,
+                    0xb6464f1e888a09e8,
+                    0x4d5306cf494b3365,
+                    0x0c6ca54b706a6458,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf47799faa83c926f,
+                    0x1a065a047ec0fe71,
+                    0xb42b3a901f604964,
+                    0x6243d1b6e00fa037,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1b84bd85194bfc36,
+                    0x1b24642917f8cb32,
+                    0x53e878341d1d0852,
+                    0x0205afcd7c6daa57,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfb756ae807eb79c8,
+                    0x96cc0c909ec1b4c0,
+                    0x0d75235e12e7968f,
+                    0xa468c95544338f7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13f027e689fe5507,
+                    0xb69eca15cbae40fe,
+                    0xaa501e4c45d334f2,
+                    0xd775cbecd2448d50,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14ff946a5a05a160,
+                    0x20413e381d14c1a4,
+                    0xad67caa4229ed937,
+                    0x8a761edb7dcd6679,
+                ])),
+                Felt::new(BigInteger256([
+                    0x12b213da9a016173,
+                    0x1cfc3935e93d638e,
+                    0x34f25f2fd6291a07,
+                    0x485b5a62977fc820,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4fd999386dae4a8d,
+                    0xa42146a76ba3860c,
+                    0x60a6efb83c0fa32f,
+                    0xdf8f9125b6d421fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe7e6579ccbf1c7e,
+                    0xfa08d6788631098c,
+                    0xf6e53ccb8d884603,
+                    0x873a059d5eaedbee,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a24b0e6a93e4a19,
+                    0xcfbc443fcc85bac3,
+                    0x48c6229dea54c0f3,
+                    0xe8dfa79dd05037e1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa4fb72230e8bd47b,
+                    0xfe8a59904aa705dc,
+                    0x774eadad3cd70e6a,
+                    0x05c3142200503d55,
+                ])),
+                Felt::new(BigInteger256([
+                    0x54b67eb0ce7a9f5a,
+                    0xa9b21132dbf4bf43,
+                    0x9548df3a02b42ca5,
+                    0x3b300212c8fde0c3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7af0db810f753a5,
+                    0xc2ced8ebb2a635cd,
+                    0x22a40afcef932889,
+                    0x9bdc81dfd62a0bbd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2be064009721c101,
+                    0xd5b234aa8ee0c0e0,
+                    0xf7184bb4304e9c42,
+                    0x992c7857f1318ee2,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x22370acc5a5e6816,
+                    0xdcba555f20b49d06,
+                    0x9f6a26487f15721d,
+                    0x6ba96aa6fbcc3785,
+                ])),
+                Felt::new(BigInteger256([
+                    0x505b3bb9827e98c6,
+                    0xad8713f686e7d237,
+                    0xc3efaf1abe01810f,
+                    0x872018d67a3f266f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x35f3fbc3d96fcffa,
+                    0x69ed535568c96b8f,
+                    0x12bdb583c3a613d8,
+                    0xd1ca36208b2e968a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8620f335f22f6de4,
+                    0xb7301fe3ce76c674,
+                    0x08a4a84d75e6dd57,
+                    0xfdb2fa09ffc714da,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf56f1e87c68acd1,
+                    0xaf2a846457d0cc59,
+                    0xcd26ffbaf7fd79ad,
+                    0xf3e7183f3f8e8b52,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f18463fb522a117,
+                    0x2090dabf6853878d,
+                    0x556f48228b3bbde2,
+                    0xb1717e17dca20a0b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe4f08f48f500a80b,
+                    0xe201526478ceed01,
+                    0xa96b78f872f973fb,
+                    0x5d43946a279cd0f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3da28732ac465f00,
+                    0x0db47a50ebcc7ea4,
+                    0x39f0d1b7f5ebc3bb,
+                    0x29116b8b8d133240,
+                ])),
+                Felt::new(BigInteger256([
+                    0x022b525ddbb0f5b2,
+                    0x2f0467f46a5a994d,
+                    0x5fdf342ebc47253b,
+                    0xfeee278c00d3e98a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7ae7c060c7289997,
+                    0xa02d1e8302d8dff3,
+                    0xbe9361ff78b1fa6f,
+                    0x0d988a555eebdaa1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a45ab647a11acf0,
+                    0x04e8741e1816ff5a,
+                    0x24871963edd12d51,
+                    0x160383a6f7320b95,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3259cef04176757f,
+                    0x9c5511115c857683,
+                    0xfd9bf1b469c1deac,
+                    0xc7f0e39925eb2b36,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x42e0370c9637c2ff,
+                    0xc2d9aefca0357847,
+                    0x7ae1e37b4c8d5dd8,
+                    0xcb2cf187d3cbf04b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7af69d3773da3fb1,
+                    0x7de4acd41b96e0a7,
+                    0x20115225618f6278,
+                    0xca111099095ec28a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x70f3c8f69f0de43f,
+                    0x736861779fb9952e,
+                    0x640bb7e9e1bda419,
+                    0xc0945b095e906a72,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7220afddb110cbbb,
+                    0xc392adb4199fa49b,
+                    0x568c6a79d509bb6f,
+                    0x8b2517df9fa8ffe0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf1078240d0dd97f1,
+                    0x04c961096c04aba0,
+                    0xfbb1e68f2e604004,
+                    0x6c61b6303e5da1f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76c2259fd0b62f31,
+                    0xffe701dba282e78c,
+                    0xac0f6e8e097389ad,
+                    0x1b4ac0c77e2b401e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb6753a4f4e633864,
+                    0x9e7f65d428139857,
+                    0x88bfe2d8de3a472e,
+                    0x32317d157795b96e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x64109a861c34b58f,
+                    0xd21822e2db39d5ea,
+                    0x629945e8b3a5abf6,
+                    0x8dd176633d19f994,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf8d6be1273c9eaac,
+                    0x449af58291c40fc5,
+                    0x45ca95a2d537a71c,
+                    0x5f8a47f2e670b6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbb12eba788890999,
+                    0xabc3981a7b842a61,
+                    0x4b521b57dd179e36,
+                    0xc6d007177c233b45,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd7844e18f70c1a7,
+                    0xccea445ba088f770,
+                    0x10094aa7db8d9cb6,
+                    0xd88bf8dbf90d2d66,
+                ])),
+                Felt::new(BigInteger256([
+                    0x91221156e376c5cd,
+                    0x04f4c8bdedd55980,
+                    0x0fdbc44dded24093,
+                    0x3fcd33eabe1bb259,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x198761b770183b57,
+                    0x81008165db74c2bf,
+                    0x794f3efe41e4351a,
+                    0xb6f26ea4cf90a8a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa81d71e5a31d9bd7,
+                    0x0aab3b9e32067d62,
+                    0x8e4c9be80ef7bee5,
+                    0x93f112e5d6093054,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77a2a963e4c79578,
+                    0xb491ea225214971f,
+                    0x717fe9ea80665431,
+                    0xb3a5e33472d20a91,
+                ])),
+                Felt::new(BigInteger256([
+                    0x047f1213465f6561,
+                    0x454136b95498f376,
+                    0x404bcff24503a59a,
+                    0x37ca90b91966681b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x307adb6d2c845429,
+                    0x96e04443daeccfd0,
+                    0xdf23ea3bdfb6b1ba,
+                    0xa14bbefaebac39eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb9cbd14722c13503,
+                    0xbdacd65bf850a18c,
+                    0xf2a3f0f9fd3e302d,
+                    0xea9d864465307a36,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf8a041e5b394e84a,
+                    0x6daa9cee61db0821,
+                    0xb3de9f9a697f72b5,
+                    0x35c55731d6dc6eec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd8696ea67979f37d,
+                    0x2a1ef01c5bbdc639,
+                    0xfb912cf5b02bdbc7,
+                    0x9f18ad3b4e3e1788,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2bdb2cb8b203d1f4,
+                    0x3efdb15317a961af,
+                    0xaea24920ffdd76fe,
+                    0x60c4a8499760be0a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcf86f026b014b965,
+                    0x0227e1a5f8814dd1,
+                    0xa8fbf3bc6b7afa88,
+                    0x3776b0dd356fc512,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc945c4172becee00,
+                    0x2af3b04020f5f7d7,
+                    0x60b703114dd1b575,
+                    0xba513a0808036fcc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc1c305d6b3a83e49,
+                    0xc3abad284056333f,
+                    0xb9a753baaa455c42,
+                    0x467801d049281985,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe42e0d5f2ae70f37,
+                    0x255c1b92afe11f51,
+                    0xa7dc392b1479a1d3,
+                    0x2ee722a474386faa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe112e5c3de512dd,
+                    0xc9834418cf1b832d,
+                    0x4f2f49507a3078f8,
+                    0x1badb147b8a0b9a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6fb88ef48b5ec501,
+                    0x49a3ebd1ad620604,
+                    0x518bafb1a2ded31d,
+                    0x0f3f75b67d5a144a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x060e53beb783e15f,
+                    0xb9862bf65fc585b2,
+                    0x8554068394ec4af3,
+                    0x8b0b7ae7c4544ae8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x016b06bf7a2aa176,
+                    0xe0375ff8db1009f7,
+                    0xb5f9c4ee8f7115cf,
+                    0xe192daec3865e026,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c26fd0ec9b5f80d,
+                    0x33c13d097a174903,
+                    0x549a3fa666bd3613,
+                    0xe34cbb44bd2960f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1886db33d24f81ad,
+                    0x04b3e286345a8c83,
+                    0xed290bd6c6a320c1,
+                    0xe32689944593e751,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36475acd3e36b39e,
+                    0x42ff5ac144eac2c0,
+                    0x24b2175882647267,
+                    0xd553f3c99a81d4a6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x05ca2b5e67f4b99e,
+                    0x7231f2f24fc73c3c,
+                    0xcb463e10e4cccc56,
+                    0xc983dfa0713de5e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x366d4d420b58f9c0,
+                    0xc2bfc615b3f859b5,
+                    0x90cd1ae9bfdd1b7a,
+                    0xd14dab04e5d52383,
+                ])),
+                Felt::new(BigInteger256([
+                    0x324384bf9cac50d6,
+                    0xf408048227b88f02,
+                    0xa0fd37197d818af5,
+                    0x22aa88e40e43176f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa9d92e33d2420ea2,
+                    0x066b74f4e69101d6,
+                    0xcec37140f1138a7d,
+                    0xea7b1b9bba93e6bc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x90c53f9f72c43113,
+                    0xf414ee5e771140d8,
+                    0x72bc611a3c00cf4f,
+                    0x82b6c197c4e440ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490add98cca30234,
+                    0xbb856d23fab0c925,
+                    0xd730a864821820e3,
+                    0x049c7e748e340b6f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x030778d3fe5a0229,
+                    0x8baca46dac3ce841,
+                    0x4f9704601a058acc,
+                    0x9f28dc34d9566021,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3df724168f10d16e,
+                    0xb37187aa1dd6d620,
+                    0xe0789961fbc5ce96,
+                    0x78285c73d2c068e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6543aadb07a5d421,
+                    0x074cc42f2f12bfbb,
+                    0x56b06429f45df8d1,
+                    0xa338b58e888c87ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd693ceff86703414,
+                    0x3868f59cca7bd3d3,
+                    0xf8b95037766e627f,
+                    0xedda1160ed91350a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5d4da6ac43410c5,
+                    0x096ef14ee090fc15,
+                    0x2057071669ace94e,
+                    0x295161a179b599f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe92c15897ae059c,
+                    0xbcc446a78abaaefc,
+                    0x2f5f950d08b91d2c,
+                    0xc508b8c3ab139c5e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd35beab74b874aa5,
+                    0x29af35437393329d,
+                    0x4f56f46ae6e3bb58,
+                    0xa8bbf8a3b095bad3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x35b71428fb42d9f4,
+                    0x686ad36623138ff1,
+                    0x5c588e64d96a2038,
+                    0x3ad5e39748eba9f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa41da13d65359ad1,
+                    0x655fa34aebeecb89,
+                    0xa9e364a8fe78c0b8,
+                    0xce8622c4e3d3b6d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x866caa3e0eb6f2be,
+                    0x25b4ea9f266a61f2,
+                    0x99b60190118519bc,
+                    0x260ab02016751775,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe6550fb6f63e98ae,
+                    0xd6e7ffc6f0d10bb7,
+                    0x67da7d731d1652c6,
+                    0xcdaffc1cd28a6784,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7382952a9e33cd9b,
+                    0xcb71d90d35fa872c,
+                    0xdeef292587db712c,
+                    0x1a42cad8b9cefb8c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x88e1c0b85ea8f8d5,
+                    0x458ab6ae0c58c4ab,
+                    0xebc317f26c4908be,
+                    0x09ad025d9f6ce882,
+                ])),
+                Felt::new(BigInteger256([
+                    0x974ba7c513f9d961,
+                    0xce00dac9c7ebe4a9,
+                    0xd0a4419bfb43cfc6,
+                    0x96127304672e74a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x163a43d8e98ac2a3,
+                    0xb9180f0c64613708,
+                    0xdb86ea904e05490a,
+                    0xdd27e35c94801521,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5bcaeabc0245750,
+                    0x245bc12f0d22738e,
+                    0xbb89786b0ec128ef,
+                    0xa64682e79aae104b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe1860f0c8331997,
+                    0x9105fcabe79148c5,
+                    0xe51b3fef71653f4c,
+                    0xb32b555298912f67,
+                ])),
+                Felt::new(BigInteger256([
+                    0x244ed0560b880a6d,
+                    0x825abba260bd6633,
+                    0x2b14f547f25f9c86,
+                    0x7496b1057a8bf61b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffff606ab8c06203,
+                    0xc36b90117c4a8822,
+                    0x3e65e32c5200b9fe,
+                    0xdbe97127ee23fe5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2080afe7feeabdd3,
+                    0x82b46dff41d288ba,
+                    0xcfc4ef2b16c35ce4,
+                    0x63ba72e56c70267c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaada053514ec7843,
+                    0xc88194059da33786,
+                    0x82906356f2890831,
+                    0x5a1a24cdf115d403,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf57c09fb3ce8ee23,
+                    0x22c6b171d39d9a0f,
+                    0xa929985bcf58cec9,
+                    0x981e0d2cd30bac59,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x77107ce4db613ae9,
+                    0x2915caec5052f311,
+                    0xc084469a7afe77db,
+                    0x4b207bd2804869dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1504d16690b6a19,
+                    0x935109e2f23d532d,
+                    0x8ce0aad275fd75d5,
+                    0x1ecf947338f0a82d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x95d50b6f25a856b3,
+                    0x517c3336758f30e3,
+                    0x8d4557973de896c4,
+                    0xb6081b9184a171c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc36e271f09d029bf,
+                    0x9f48aa1d12a41af5,
+                    0x97e2c62e57d95594,
+                    0x42f1f8206abfe3d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9bb75b4a03beb9ad,
+                    0x888b1d98db57d0dd,
+                    0xa223f4858e272583,
+                    0x0ffa2484af25437c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7b975fb9eb931b1,
+                    0xb197a4b9e391f01a,
+                    0x85c099af70612f6d,
+                    0xa58d7f71f020bb3b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf43f98d21990482d,
+                    0x6fcb47a99786322e,
+                    0x54371fcd962f9ed8,
+                    0xc0212d2e32b797c1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x910cb27038162585,
+                    0xf0b896a43f67889d,
+                    0x2d35f055a7d9fca6,
+                    0x370f3692f185d601,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2f5f66a3b0757d2,
+                    0x15e641b35403c58c,
+                    0xe28b8612a6f445cd,
+                    0xb76911722caa2021,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0f81cd3a8f9033cd,
+                    0x0f701a29e8191408,
+                    0x5cf90dc809be0fac,
+                    0xe63ed66905eb2220,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe19cc3a88fd975f,
+                    0x41c09615c536df1f,
+                    0x686232e49dfc26e2,
+                    0x6431226a9dd8c3ba,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7984d49ba69661f,
+                    0xea894267d85d7954,
+                    0xa5929868a023abd1,
+                    0xd73887c8ea58678b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5854673b2265e72c,
+                    0xeb244027725b7079,
+                    0x141e1f65c813148e,
+                    0xc5388659a4579c34,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b3ed3b4e04fd84a,
+                    0x94f31dc0cd3b2740,
+                    0xb4226163767d1c78,
+                    0x291ef916ae0096d3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83524894b96fb3e7,
+                    0x010c33b4c5e8959e,
+                    0x84bcee922fd56a6a,
+                    0xb250b3d50d339165,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe19ad87387fb861e,
+                    0x3d2f1e64fee7478d,
+                    0x3a9114d68438d4e3,
+                    0xebea4bbb0df46ec1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x401d00c0e28cfa33,
+                    0xa068e5032f312125,
+                    0x94315e910aa8064c,
+                    0x173be45ff42fd09d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x911bc4f9a6e4fec1,
+                    0xf3226a06947eb0c1,
+                    0xae3da3dd4117c8aa,
+                    0x89000cbf7cd3ecf7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x620590b05d19a7b2,
+                    0x8d457cb5cdc0f210,
+                    0x4e497ecd1dd38e94,
+                    0x1026a9230035ec26,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7af58c04e7faa1b4,
+                    0x9a8764e817b081d3,
+                    0xfbfeb3da4d46897f,
+                    0xfd1341080ab2bc53,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf08ed271bdfc0002,
+                    0xe22d5648be501a0c,
+                    0x3dac1da94f8f5fe6,
+                    0x29de8fab26008e56,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe2a7fa0822ed4944,
+                    0x0885854a58977c74,
+                    0x656b5f607c495b93,
+                    0x6cc70fdf7b58e826,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3177dc779a667469,
+                    0x9445a51ee409d070,
+                    0xb0641812a3a3eb80,
+                    0x8ef42c92c9c16c98,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d43cc9be7f7b2e2,
+                    0xc0f232e38380f2fd,
+                    0x995666240896f465,
+                    0xb36cfb7baa76d5ac,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x89d9da48c79c00ed,
+                    0x62a256fb373928e8,
+                    0x68d61176342420c4,
+                    0xeb2b4e8d5d2997fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ba152c1c8aacee2,
+                    0x2a03db740538c356,
+                    0xd24ecd705ad3fa64,
+                    0x52e7db641b15b1ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa76859abac224ad0,
+                    0xff4bfc12a7751b3b,
+                    0xce1f1edffcca52a5,
+                    0x842c648af6097678,
+                ])),
+                Felt::new(BigInteger256([
+                    0x212f4ce0950881a5,
+                    0x0f0c72d686368d8a,
+                    0x4d17101c71426ffc,
+                    0x8e514ecc5200e449,
+                ])),
+                Felt::new(BigInteger256([
+                    0xca42a358280869a1,
+                    0xddc7c74aeadc1530,
+                    0x302e7912fe96036e,
+                    0xd181b121ee3414da,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa5889d2187f2bfcf,
+                    0x0efae071f295f68f,
+                    0x592b387d04fd592f,
+                    0x0ab607eb872e422d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf5c08ee9f1ba8a02,
+                    0x64f50c72a297c025,
+                    0xff7fbdd4f61895c9,
+                    0x327e64791ceefd25,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d466d7c8d9282d5,
+                    0x1c47227709da302e,
+                    0xc920c0c22e676731,
+                    0xa87dd1dd113df06e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc3086d139f4ae7ca,
+                    0x608ddb49ef96c78a,
+                    0x07c707f9ce26a47d,
+                    0xfc5ca0ff87d82710,
+                ])),
+                Felt::new(BigInteger256([
+                    0x504034e2c94f5c76,
+                    0x0d36aad9742eb619,
+                    0x83520d81797a0b25,
+                    0x4f1b64fa443b88e1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9ec3cebc1c7308a,
+                    0x2801a55a8accc6d9,
+                    0xabf873d0100f63b9,
+                    0x4fd2d25ca3853f5f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0d12fa66934d48e5,
+                    0xf4b3c09893283388,
+                    0x9cc57c9b49e8527b,
+                    0x1f6cedc89ffd9d00,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7bc6d9104385df23,
+                    0x894dc63ef2ad306e,
+                    0x8a7d022e009c7184,
+                    0x602bcae83f2e3848,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d40638fa853a8d4,
+                    0x58b1694db0f328ca,
+                    0x9ecab58996245d90,
+                    0xdc457b98cfb0b206,
+                ])),
+                Felt::new(BigInteger256([
+                    0x555e4fcba06a1e2f,
+                    0x113e6715567a7b76,
+                    0x6d9ed954c49636cc,
+                    0x5ccc435a78640bb5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe233efefa30f5dea,
+                    0xce6f3c3b180a7ace,
+                    0x4dbffbcddea6d9db,
+                    0xb8024ae190582696,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb6636f4ee0d5ac55,
+                    0x1359759427103c82,
+                    0xf6986ae5c840f690,
+                    0x4ecdf13c41779d9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81cf4997fc723835,
+                    0x2cfc2bb62481401a,
+                    0x0eb1d1b2ba8788f3,
+                    0xa2e550aa78b50630,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0f4d5f76e1175f7e,
+                    0xd451354d7478d924,
+                    0xbf1bffd9fa03360e,
+                    0x84b9bb8d3ca6706e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd619e40f002cbae,
+                    0xe3f83604733135e2,
+                    0xf8128a8fd10704ea,
+                    0x1c75fa5f671a6b27,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd53aad80b7344ea,
+                    0x2e145c98a38553b4,
+                    0xcdd8be4113e8cf0b,
+                    0x23beadbfe6201706,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24fdcde7f7675abe,
+                    0xefc5fdc84079254e,
+                    0x2732010fc33ee3f6,
+                    0xe34e70a29cce5943,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc39f77721444859,
+                    0x62a1d231638e5f54,
+                    0xf10c8c8e8271ff06,
+                    0x5a308b7f676121bf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x729bccf2dff0e4d2,
+                    0x1913cb067fcd1dd6,
+                    0xb469040e10df5be3,
+                    0x86741a6d71ead94c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1edd7b2c30d58c4a,
+                    0x1c7ac655b168add9,
+                    0x405e524587acf615,
+                    0x08a73a886642f4df,
+                ])),
+                Felt::new(BigInteger256([
+                    0x32ac883d2505e4f5,
+                    0x9111c0c34428581e,
+                    0xaefbe1451913c323,
+                    0x4eac26b2432d53a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x96cef63875dbd798,
+                    0xd00bc1ac8b28a5e3,
+                    0x5fd7e2fc05339fc4,
+                    0x61a05d8a29aebd72,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b09106e987ad2c8,
+                    0x93ce1e534df5d1e0,
+                    0xfdc3d6d26a0c116e,
+                    0xc1cdee08169c0d59,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd6dd90d4192d4c56,
+                    0x68ecd97c3031669a,
+                    0xd528276e8fedda61,
+                    0x18677d482ccd0329,
+                ])),
+                Felt::new(BigInteger256([
+                    0x65c98ecc2f9df7c9,
+                    0xeff05b88b95f7bd0,
+                    0x99e113e545de9d90,
+                    0x2727cec413785335,
+                ])),
+                Felt::new(BigInteger256([
+                    0x07e6495d9b23a2ee,
+                    0x8f8cb5ef4fc13032,
+                    0xfde5f9a147653499,
+                    0x0e7ca1aa10464169,
+                ])),
+                Felt::new(BigInteger256([
+                    0x255204c6c276ea3c,
+                    0x64ab37626dc41137,
+                    0xce2258c94683c248,
+                    0x541a41c761d29315,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb30e11df071720b4,
+                    0x7e6fd88846106a33,
+                    0x1da1b7da4819c959,
+                    0xee550cead1d97dc4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b049bb52a8e1bf6,
+                    0x4d39b70ec9be9854,
+                    0x18e2ec240ad87528,
+                    0x31570b963c2aaf84,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3ca48fc01d4d53ca,
+                    0x70fdfbc92453e7d5,
+                    0x4e96bd6766d36d6b,
+                    0x3eb8612d174d0ca2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71e4dbcdfc270aa1,
+                    0xec90ee555a36c632,
+                    0x9ad2b3a4ee341771,
+                    0xc0c46146541a2d92,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2530e1551e8fc190,
+                    0x1d18bc74281b597d,
+                    0xfb7f5c33b57d8028,
+                    0x94b21c5c8deebaa0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa02f5713d2615576,
+                    0xa3b8e9282b7e3b05,
+                    0xc388faf71650cacf,
+                    0x6c472ae8d56c551d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x30500ac99225b7dd,
+                    0x7ca1378855103ff5,
+                    0x7e01650c4d359c5d,
+                    0x7251d8baa661c8a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd0270ecab46914ea,
+                    0xcf7da786a3f5837e,
+                    0x5f6fe8a41ae54918,
+                    0x5a29d3f8737ab58e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x969c3425d4b004d0,
+                    0xea36feb08edf1be5,
+                    0xc905814ed47b396b,
+                    0x0a5943ec1640909f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25c0ad32c6a57e50,
+                    0x8584260e3a52a6a5,
+                    0xa79c98863100b764,
+                    0x06be7c469741d28a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x332cbccd0424f963,
+                    0xfe6bcb1f20693b00,
+                    0x8f7eacb907ea13c0,
+                    0x0fb24fe524f93229,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09195d16f6257322,
+                    0xc329340fd6edae6b,
+                    0xb6885d2862fa524f,
+                    0xe9cb57e8542203f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5bda3c66b6fdd802,
+                    0x57bab8ce8e9e5e8c,
+                    0x300b38c4e4ce394c,
+                    0xc146308f328181d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0d164d37b7c6c15,
+                    0x8396106e1b2f3f2e,
+                    0x9dfa9b810227d65d,
+                    0x8002dd30a959c5b1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x45e63adf60b7a934,
+                    0x3e461e65f0aaec70,
+                    0x275644b580e6cd52,
+                    0x27f4557e58847001,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd5ab693ad9ca2f0,
+                    0xd8d08c43f583d9f5,
+                    0x8e411e424d967f1d,
+                    0x9d9f2c7836fce8b0,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,599 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 64 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x3ed7003f42bd8b4f,
+                    0x26b6a9e1d5e5ec84,
+                    0x0567f77693abf17f,
+                    0x0594bf665e41c5a1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x813205edf6bf2ad2,
+                    0x0be1c45c62254175,
+                    0x29696e703ce5c8d6,
+                    0xf46868170e0fef9a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2337ea61bbcaa338,
+                    0xe5e596acee238626,
+                    0x64f87ad3ea1c9a10,
+                    0x0c1f2ec2e4715e82,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae79788abf5e6e46,
+                    0x1d3fb5f6e97120b3,
+                    0x13284d1a100b479e,
+                    0x2e916c12000910f3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x69d3c7987ba09da8,
+                    0x1b828f57a0f852a1,
+                    0x96a3fb472743a0e2,
+                    0xb800ad2fafff4104,
+                ])),
+                Felt::new(BigInteger256([
+                    0x50bca1da3ba8b547,
+                    0xd7433790f2075128,
+                    0x6b1d5d813f8132e5,
+                    0xa86daa488d92ff78,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3642d06442bad054,
+                    0x51b6181d3a53926c,
+                    0xc0e2630bc648edad,
+                    0x545d543547006864,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba7c7e3db59b6102,
+                    0x7ba057d7cb0df77e,
+                    0x1b1f31c26afddfe4,
+                    0x35169f270bd5cd6f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xce8682523a44397e,
+                    0x490c8a22770e54ab,
+                    0x6147c2f814c6b58f,
+                    0x21de97c56bf9e031,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6178b72ca72c21ee,
+                    0x609d98fc94fb86f8,
+                    0xd4e28bbf79a07f29,
+                    0xe8c5956989f76f26,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9f0499e2a43ce756,
+                    0xcfb0fff85f237442,
+                    0x3e9f9a746f05c0c6,
+                    0xbd821112e71ce8eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x853eae292658d391,
+                    0x63ad5c01ef532745,
+                    0x633536bc034e8c5b,
+                    0xf26f01db2a917317,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcb60a736c2b71fa1,
+                    0x3a6360083cf0edf1,
+                    0x4e635b4594f7c6cb,
+                    0x186b605f50a444fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7dfa7a1df0f7c7e5,
+                    0xf3eabfcd09dc23db,
+                    0xab8f8f31aa0b0ee6,
+                    0x7f452164a2d03435,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x88cf97ad0260ed38,
+                    0x55d2e860c9494e53,
+                    0x4ed5258789c6c48a,
+                    0x655e37479a23e00e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4de09512333baf1c,
+                    0x781b01df425f477d,
+                    0x6e610006a38672fb,
+                    0xd76de91bd4baf2cf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1456f38787948229,
+                    0x86eb42c6e439031a,
+                    0x15b8d2fd8e77dc3b,
+                    0x2672a1aa2a08cf94,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a9d4e90fd1b65d0,
+                    0xd63213e5ff7f06f1,
+                    0xac9412d227e34a6e,
+                    0xab0ca2b9dbc5cf13,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa57601de4ff70e2d,
+                    0x821a69ae775ba527,
+                    0x44aaf086760cfe9e,
+                    0x8a320500544cc26a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86168ceb646bb07b,
+                    0x43905774866cb4ad,
+                    0x63c42ba46e774163,
+                    0x2cc14a09e9790f96,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x63360bd1deceb3d0,
+                    0xe3a9cc45bc9aedf2,
+                    0xc0225afaf4fa5add,
+                    0x7ce684ee1afac830,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc8976af6b0a0a13,
+                    0xaab16454f5e95433,
+                    0xa61cfdbb3917b436,
+                    0x554e61c0fb9a03fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb79b8ce955708af5,
+                    0xd85be629fafc39f6,
+                    0xf1f9afebc9d160da,
+                    0x2c1c0dfbcb3b9817,
+                ])),
+                Felt::new(BigInteger256([
+                    0xad217c351078a0c3,
+                    0xa8baf5cbd3f9a9ed,
+                    0x580d0fce0d2b8196,
+                    0x84f56cf3799bb4e0,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x6cb63823978d7c97,
+                    0x4686d24cafd93199,
+                    0x77e5ea8cd33b0c22,
+                    0x71e7e84d7ea0274e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x29d788a65a3b7fa8,
+                    0x68efae32060f74b4,
+                    0x80b0850d686baaf6,
+                    0x59e2be259025ebb2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x826fe06630a34f8f,
+                    0x855982bb376ea21e,
+                    0x7c312e175272a84f,
+                    0x58ca20532a9ce365,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaeeea013cae5a88f,
+                    0x82469e3b35899c53,
+                    0x141ab59d3daf47c0,
+                    0x2580de11032b444d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xadba425d5a1ab141,
+                    0xe7d0f4c2cc1956cb,
+                    0x298baf950db8063f,
+                    0x3a661b78bdd22ee0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19f2c7c02e897675,
+                    0x48ac6a5ae02cd01e,
+                    0x8ef70406855c9975,
+                    0xfdb6cb31a7e8ccad,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x390d8e4d830f27a3,
+                    0xc732129ee907326c,
+                    0x143f5d2023c33ab4,
+                    0xa4b0dafcc1db50e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb08decc3126b3dc,
+                    0x8d4f06d6578124dd,
+                    0xc2d737ef3818c097,
+                    0x1c2df9ba6a7509e3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7f6a57fbbe7082ec,
+                    0xeea53c437bafd2b0,
+                    0x59e084f8259488df,
+                    0xbd91729a5e20a05f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd94517735aa4e1d2,
+                    0xca0734df2852adb6,
+                    0xddde6d5f705527f9,
+                    0x68cc99a9467f0af7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3978c232e661abff,
+                    0x705eba768aa9c1f2,
+                    0xb0c01ebf68b75469,
+                    0x84800bc07ad6cd53,
+                ])),
+                Felt::new(BigInteger256([
+                    0x646b45c11dd800a9,
+                    0xaf41d35a7064c11f,
+                    0x38d123f898ed4087,
+                    0xd0de1b592e2ff9ec,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc13f6a264e843739,
+                    0xe537f5b135039e5d,
+                    0x0000000000000008,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x88e9887f5567414e,
+                    0x89d8daf1c3d0b454,
+                    0x000000000000003f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger256([
+                    0xc13f6a264e843739,
+                    0xe537f5b135039e5d,
+                    0x0000000000000008,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x11f637966ec177ad,
+                    0xafb6dbdd7bb4220f,
+                    0xfcb98deaae2cb8e1,
+                    0xe71b1b546fa9992f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x28a4d67680403ddd,
+                    0xd6d683d84c48a1b7,
+                    0x69c366782ba4b929,
+                    0xaba07d749dc91c02,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8b23e263ecb4aa37,
+                    0x5a98f972fee9482c,
+                    0x08ec2564023d9e94,
+                    0x5f5032ca40cbc181,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd4513b5a369cd8e,
+                    0x8717b692cf595513,
+                    0x528fbb594d5e9dd1,
+                    0xc0b24198c8bd8ed4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x249d22c4ee6125ad,
+                    0xc9bfd2f02256a5fc,
+                    0x124ccbc2b340387d,
+                    0x2a65a9d4552fc79f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a99fe0912c4fbae,
+                    0x578d751e71fa198b,
+                    0x0f1096596c1e24e8,
+                    0x267e6ffffc374207,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x767947560ae7d166,
+                    0xe9ac65949e469441,
+                    0x6821e4aaac707eda,
+                    0x69f2af15ab0e961e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd8e2b6800cdaa9e3,
+                    0xc0f937329d9551f4,
+                    0x9bc47899ef2c3897,
+                    0x01d0c35217db24b8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x30d6e07cc84febe7,
+                    0x44cb17a98818b1fd,
+                    0x6af5829437e8a0b8,
+                    0x9b29a63b4b99ed26,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fdbc0a993fb501f,
+                    0xc0d966e723dd0ab4,
+                    0xca94ff6cf7b18d08,
+                    0xa6f0254857b48704,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7979732dd6042918,
+                    0xdb12548781b74867,
+                    0x3e781a8b97341826,
+                    0x3a92cb30be26a2c9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3718aee857889dcf,
+                    0x376469419dd67b7c,
+                    0xee19ddc9bb59e99a,
+                    0x6ae1a9ae613e6d6c,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0xc6f3988c47bc0f19,
+        0x8f5c58db4bef96e5,
+        0x0000000000000031,
+        0x0000000000000000,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x5ff4b1a7453bdad6,
+        0xe2497da068b9e9d8,
+        0x45b4c9d1fc8c977e,
+        0xbda683562e59c485,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x988203b9f0208614,
+        0x53ea6556b35d3057,
+        0x8d9db9f45c855028,
+        0x6c4195d2d4d0a4df,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x7153987e23edcd35,
+        0xbc579ac4af2e1923,
+        0x7e77c55b55378c6a,
+        0x3f2a599073014a45,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x0c5b4c5764926513,
+        0x6aff8b216952fb75,
+        0x311cd1089bf47002,
+        0x174a31fa23e0ba1e,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbc0f17da9476f007,
+        0xa3660978e4c0442c,
+        0x281afe467fec2c90,
+        0xb54b9a4a344bf358,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x57ac2b3b2fb5635e,
+        0xc51eafd25f3fafa7,
+        0x42e000d3764c05c1,
+        0xed30642391f40f24,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x4a6773d8cb41c1c3,
+        0xcec91b17c8a33aed,
+        0x44a385ad6b108746,
+        0x8995c90f6d70ff30,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbdbd44eee34b4052,
+        0x104fd7269a9ce806,
+        0x822bc7e3a5d526ae,
+        0x36410d5d56a90bec,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x11c29d8d8299fcce,
+        0x7958d1b23086c2af,
+        0x7036e4bccf7f5965,
+        0x4229d60e1fe50392,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xeea24850538c8bea,
+        0xf3d53551d975a79c,
+        0x3e18ef6fc7c86c86,
+        0xd4f1b74f8bb43bc0,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x22643bbea5481d93,
+        0x57ba70045d1cedeb,
+        0xcebed7f6785208ef,
+        0x6b5af4c3b73061f2,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xe4e084659b1cb91e,
+        0xca5a0249e005a469,
+        0x1267632fb34fa675,
+        0xb106db806ace66eb,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x5576a4f43f0b9c80,
+        0x54111b0c2c7001bc,
+        0x447eaa38c4f1aa7a,
+        0xbb8c72b0411af9a2,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x8ec87439d9453b24,
+        0x59bbac78bc5a5cc3,
+        0x4f32523289bd84d0,
+        0x2636aab03a308ebf,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x2719e29de7f1acdc,
+        0xab7885f3b304561c,
+        0xbe8c1b27e6d4cd48,
+        0x9a6028affd227427,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x7b55c1b79ddb795f,
+        0x08c12252fdf2c543,
+        0xee6e891903d24919,
+        0xd3f152f3e45cb1bc,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x41e9de8d973ff25a,
+        0xb18c191edcec8749,
+        0x74255d09d260436e,
+        0x4fc7651c6c694573,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x32ffaca20370df77,
+        0xdcbf253b69b31ed4,
+        0x5db93a81f2c0c644,
+        0x3fe03c9798fe9d4b,
+    ]))],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0x0ffe17b82c6d58f5,
+        0xb0e4d715f93da951,
+        0x6db6db6db6db6de7,
+        0xb6db6db6db6db6db,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x1c6b8c5b4edd188a,
+        0x4ddb343165d65410,
+        0x4e0fbad59cb52fac,
+        0x8ea026fc1b3f1da2,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xac2511923538441f,
+        0x475b6a78a8a05d3e,
+        0x0c2f6f031ad399da,
+        0x360e3e442f05868c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xebf2131019c850af,
+        0x195fe34fcba01777,
+        0x9e44a2d62df7af03,
+        0x3d845298c69bc0af,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x4593096a20111152,
+        0xaaee9a5920e72eee,
+        0xe59837eab99d5438,
+        0xf83d9925d682b5bb,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x38c0226c8bfff33b,
+        0xdc5ad2abbee3290d,
+        0x3c12169d4d0d9942,
+        0x1456aab895b0518e,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xedb492d25bc1b7ff,
+        0x915dbda1eed7826b,
+        0xa3906ed5a40de2e2,
+        0x18f0f06d6cf3b738,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xd1db3882da177fa7,
+        0x0ce55cd08c1bd652,
+        0x950aa34f52777da2,
+        0x518884ebcba66112,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xe4303bebb27190a6,
+        0x178e730ba555b43c,
+        0x2187052ade1bebe4,
+        0xbe2eeebf26bb08ac,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x242c4aae05e3929f,
+        0xc364ac73fac15077,
+        0x695c4e9a2604f050,
+        0x649ea037a0bfc50d,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xd848878376df4a95,
+        0xf2e27b0bf63f3ae2,
+        0xd6bcedb515f89187,
+        0x18289cb3e4b7ab1d,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x8f7af6ff63677b70,
+        0xe5f317407842f302,
+        0x3ca8fa1d5cc2767a,
+        0x7f5b42f4292b4e42,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x33c7f1f84594d465,
+        0x4868d498afcc15f7,
+        0xd6b9e27e961d44a9,
+        0x07b58fd70288b7e0,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x710e807fb75b0c7d,
+        0x1766f090417d4356,
+        0xab943a2ba59dacd4,
+        0xb9749cfbd5734c68,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xe635f418ef6a8dd0,
+        0xa2314f877640d43f,
+        0xd2a9fbd196fe305a,
+        0x48c739dc18ac95ac,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xb1e7e8f93b4b549c,
+        0x760af7f4837283d3,
+        0x97c666edba769735,
+        0x591b1b7a659191b4,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x0eeaa5f90e527689,
+        0x7d56a0042817553f,
+        0x2658c32e571eb53e,
+        0xaf67476f99cab351,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x82f1f96778ded79d,
+        0x18241787a091d7e3,
+        0x881e2d9f3708a392,
+        0x20183f1ae9836e8d,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xe9f5fca3e8516695,
+        0x782796ece483bc52,
+        0x210efd7d2b7e38da,
+        0x8da679382a7dd8a3,
+    ]))],
+];
@@ -0,0 +1,571 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xc6f3988c47bc0f19,
+            0x8f5c58db4bef96e5,
+            0x0000000000000031,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0x3576c90fea25d26b,
+            0x8ef6c71c7d78ed9e,
+            0x6accec4f8a3e1954,
+            0x0e39c033d0abe172,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x5ff4b1a7453bdad6,
+            0xe2497da068b9e9d8,
+            0x45b4c9d1fc8c977e,
+            0xbda683562e59c485,
+        ])),
+        Felt::new(BigInteger256([
+            0xfe9a6049f7bf80eb,
+            0x6e4e9491fb8b7081,
+            0x5fb21bf549cc34a6,
+            0x909ed8f65174d62f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x988203b9f0208614,
+            0x53ea6556b35d3057,
+            0x8d9db9f45c855028,
+            0x6c4195d2d4d0a4df,
+        ])),
+        Felt::new(BigInteger256([
+            0x5aa00e8fcc3b0527,
+            0xadda6639da90731a,
+            0x821c16f0449a5a51,
+            0x5b4b7a2a6c687fbe,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x7153987e23edcd35,
+            0xbc579ac4af2e1923,
+            0x7e77c55b55378c6a,
+            0x3f2a599073014a45,
+        ])),
+        Felt::new(BigInteger256([
+            0x00b63781b40602e0,
+            0x8612f7a9b6e32deb,
+            0xe37a87c530490774,
+            0x0baaee9914ff913f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0c5b4c5764926513,
+            0x6aff8b216952fb75,
+            0x311cd1089bf47002,
+            0x174a31fa23e0ba1e,
+        ])),
+        Felt::new(BigInteger256([
+            0xd8ace9667c2a7436,
+            0xb660ce56800c701d,
+            0xf3e58d770358305f,
+            0xc268d7892039ddb4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbc0f17da9476f007,
+            0xa3660978e4c0442c,
+            0x281afe467fec2c90,
+            0xb54b9a4a344bf358,
+        ])),
+        Felt::new(BigInteger256([
+            0xd101a4d45826aa06,
+            0x7975ee21bc6062b1,
+            0x6256fc5cb70df84a,
+            0xd49924a79d7fa3b4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x57ac2b3b2fb5635e,
+            0xc51eafd25f3fafa7,
+            0x42e000d3764c05c1,
+            0xed30642391f40f24,
+        ])),
+        Felt::new(BigInteger256([
+            0xbe86a50dce66df88,
+            0x68cc44852337f66c,
+            0x94c640e726f19d1a,
+            0x6fdcf8469cf17462,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4a6773d8cb41c1c3,
+            0xcec91b17c8a33aed,
+            0x44a385ad6b108746,
+            0x8995c90f6d70ff30,
+        ])),
+        Felt::new(BigInteger256([
+            0x92c5a9d01bc4786b,
+            0xad58565de19fb9c9,
+            0x6ff5fdf2a72f023f,
+            0xa3e13641a2e70fc0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbdbd44eee34b4052,
+            0x104fd7269a9ce806,
+            0x822bc7e3a5d526ae,
+            0x36410d5d56a90bec,
+        ])),
+        Felt::new(BigInteger256([
+            0x4413f83970b484f2,
+            0x3574fa5e9606d9e0,
+            0xe86104d0e8da92df,
+            0x05dd0e0dfa1d86af,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x11c29d8d8299fcce,
+            0x7958d1b23086c2af,
+            0x7036e4bccf7f5965,
+            0x4229d60e1fe50392,
+        ])),
+        Felt::new(BigInteger256([
+            0xf3d6b633dfd623a7,
+            0x37b0bf2ae25e809c,
+            0x38b8e3f119e75674,
+            0xd3ba0b34e1edb14e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeea24850538c8bea,
+            0xf3d53551d975a79c,
+            0x3e18ef6fc7c86c86,
+            0xd4f1b74f8bb43bc0,
+        ])),
+        Felt::new(BigInteger256([
+            0x3d077d9022fff666,
+            0x2c73e6199a88bff2,
+            0xf71e7e09b37937bd,
+            0xe82e7b4f975dab15,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x22643bbea5481d93,
+            0x57ba70045d1cedeb,
+            0xcebed7f6785208ef,
+            0x6b5af4c3b73061f2,
+        ])),
+        Felt::new(BigInteger256([
+            0x4293a396bd3b85d0,
+            0xaf55fe6466828290,
+            0x2284f1cb4ba2690f,
+            0x9090e0c1fc14af05,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe4e084659b1cb91e,
+            0xca5a0249e005a469,
+            0x1267632fb34fa675,
+            0xb106db806ace66eb,
+        ])),
+        Felt::new(BigInteger256([
+            0xdcc110979040028e,
+            0xe9cb200fd4d6b952,
+            0x44d3cedf88c5e920,
+            0x637b95d1da7939f6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x5576a4f43f0b9c80,
+            0x54111b0c2c7001bc,
+            0x447eaa38c4f1aa7a,
+            0xbb8c72b0411af9a2,
+        ])),
+        Felt::new(BigInteger256([
+            0xb8b793cfb47b2f32,
+            0x7b41b0889a0d96aa,
+            0xdab74640be8eb25c,
+            0x5dcea8b239686fff,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8ec87439d9453b24,
+            0x59bbac78bc5a5cc3,
+            0x4f32523289bd84d0,
+            0x2636aab03a308ebf,
+        ])),
+        Felt::new(BigInteger256([
+            0x40846f17ad552590,
+            0x1f71355202e9cd55,
+            0x13dab2bd62b7d395,
+            0xf96310fe16def991,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2719e29de7f1acdc,
+            0xab7885f3b304561c,
+            0xbe8c1b27e6d4cd48,
+            0x9a6028affd227427,
+        ])),
+        Felt::new(BigInteger256([
+            0xd63eb18c195e4c48,
+            0x30d7ce933a867e8c,
+            0x01629415f770b0aa,
+            0xca74948ac1f9b9d1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x7b55c1b79ddb795f,
+            0x08c12252fdf2c543,
+            0xee6e891903d24919,
+            0xd3f152f3e45cb1bc,
+        ])),
+        Felt::new(BigInteger256([
+            0xa0ce758dd18eb64d,
+            0xc5f9254b777c38c6,
+            0xde4faad5edf04861,
+            0x14fe66e9ba0481cc,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x41e9de8d973ff25a,
+            0xb18c191edcec8749,
+            0x74255d09d260436e,
+            0x4fc7651c6c694573,
+        ])),
+        Felt::new(BigInteger256([
+            0x218b8c63db9f0d8a,
+            0x2d694c67e2e643ab,
+            0x762b8dd87beb8686,
+            0x621db18ff77a0382,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x32ffaca20370df77,
+            0xdcbf253b69b31ed4,
+            0x5db93a81f2c0c644,
+            0x3fe03c9798fe9d4b,
+        ])),
+        Felt::new(BigInteger256([
+            0x2548616712635c67,
+            0xb5c6e9872162602f,
+            0xba3e7dbc318e4d0d,
+            0x64d67a4f6e473041,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x71a5814193e2c0b0,
+            0x113a8452d837a811,
+            0x6b139822c99251f6,
+            0x3ae902a8a1513f24,
+        ])),
+        Felt::new(BigInteger256([
+            0xd0f4e5c3c4bbfb68,
+            0xee964f8054a43c77,
+            0x52e8001c30f30481,
+            0xc08dff447c1171e2,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x0ffe17b82c6d58f5,
+            0xb0e4d715f93da951,
+            0x6db6db6db6db6de7,
+            0xb6db6db6db6db6db,
+        ])),
+        Felt::new(BigInteger256([
+            0xde58221ff2f02787,
+            0xceb698ef77fd8b30,
+            0xe117521cf4768ea7,
+            0xca63ddc2ec159094,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1c6b8c5b4edd188a,
+            0x4ddb343165d65410,
+            0x4e0fbad59cb52fac,
+            0x8ea026fc1b3f1da2,
+        ])),
+        Felt::new(BigInteger256([
+            0x1ae814e22579c9df,
+            0xf8179ebb45de65e1,
+            0x70a097589d51d470,
+            0x66e72c747e562793,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xac2511923538441f,
+            0x475b6a78a8a05d3e,
+            0x0c2f6f031ad399da,
+            0x360e3e442f05868c,
+        ])),
+        Felt::new(BigInteger256([
+            0xce19f64c356bce72,
+            0xbf82bef41d0a2b28,
+            0x0941565eb645aba0,
+            0x2a66d274069959b2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xebf2131019c850af,
+            0x195fe34fcba01777,
+            0x9e44a2d62df7af03,
+            0x3d845298c69bc0af,
+        ])),
+        Felt::new(BigInteger256([
+            0xdb2b8bf7cdf9919a,
+            0x015293cd208bb766,
+            0x0bdaef9fbc6631aa,
+            0x0f539779a895fff1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4593096a20111152,
+            0xaaee9a5920e72eee,
+            0xe59837eab99d5438,
+            0xf83d9925d682b5bb,
+        ])),
+        Felt::new(BigInteger256([
+            0xb1e921d08b8bea74,
+            0x59d8543fd58e8e82,
+            0xb0f47eb8d45e1c34,
+            0xa8aaee8d12d7d199,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x38c0226c8bfff33b,
+            0xdc5ad2abbee3290d,
+            0x3c12169d4d0d9942,
+            0x1456aab895b0518e,
+        ])),
+        Felt::new(BigInteger256([
+            0xad89894a73c8b87a,
+            0xd0a20aece3b9d2b9,
+            0x7ee19f13378c6c99,
+            0x38f2e4ee3edffa31,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xedb492d25bc1b7ff,
+            0x915dbda1eed7826b,
+            0xa3906ed5a40de2e2,
+            0x18f0f06d6cf3b738,
+        ])),
+        Felt::new(BigInteger256([
+            0x74384515eec280aa,
+            0x0df182d3af4ef494,
+            0xfe0a3949081081d7,
+            0xa0ec3468b7ed14bd,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd1db3882da177fa7,
+            0x0ce55cd08c1bd652,
+            0x950aa34f52777da2,
+            0x518884ebcba66112,
+        ])),
+        Felt::new(BigInteger256([
+            0x7a10485e4eb3418f,
+            0x09abebaef24ee056,
+            0xc8f0a5f441f30038,
+            0x7122a1f6411869e9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe4303bebb27190a6,
+            0x178e730ba555b43c,
+            0x2187052ade1bebe4,
+            0xbe2eeebf26bb08ac,
+        ])),
+        Felt::new(BigInteger256([
+            0xca5dc91a63f3e086,
+            0x5aeae9dbedf6313d,
+            0x904fcc77d47e5fb2,
+            0x93199f480a2b7bb6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x242c4aae05e3929f,
+            0xc364ac73fac15077,
+            0x695c4e9a2604f050,
+            0x649ea037a0bfc50d,
+        ])),
+        Felt::new(BigInteger256([
+            0x66173d388738c4b8,
+            0x9ff3ed84f9cf998c,
+            0x3a71d82e23c9f4fc,
+            0xfb7d8536a2c46b10,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd848878376df4a95,
+            0xf2e27b0bf63f3ae2,
+            0xd6bcedb515f89187,
+            0x18289cb3e4b7ab1d,
+        ])),
+        Felt::new(BigInteger256([
+            0x868496a76a6bc051,
+            0x49b87f6c0488de5f,
+            0x985606aeb506645b,
+            0x30b4108c305d12ba,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8f7af6ff63677b70,
+            0xe5f317407842f302,
+            0x3ca8fa1d5cc2767a,
+            0x7f5b42f4292b4e42,
+        ])),
+        Felt::new(BigInteger256([
+            0x0f8138bb9f73eeed,
+            0x5bc5f938cedf12cf,
+            0x99029e51e36fde38,
+            0xa9dfdecaae0b939b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x33c7f1f84594d465,
+            0x4868d498afcc15f7,
+            0xd6b9e27e961d44a9,
+            0x07b58fd70288b7e0,
+        ])),
+        Felt::new(BigInteger256([
+            0x4b51b69b2f076a56,
+            0x40c022dda11c5643,
+            0x11b9d88e1ef08ef0,
+            0xbf78fa00b22f8333,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x710e807fb75b0c7d,
+            0x1766f090417d4356,
+            0xab943a2ba59dacd4,
+            0xb9749cfbd5734c68,
+        ])),
+        Felt::new(BigInteger256([
+            0x3426493f50e3aa6f,
+            0x5cced9a4fc51636c,
+            0x4a6060935297bc53,
+            0x610582d60dbcbb0d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe635f418ef6a8dd0,
+            0xa2314f877640d43f,
+            0xd2a9fbd196fe305a,
+            0x48c739dc18ac95ac,
+        ])),
+        Felt::new(BigInteger256([
+            0x37f66a4e175d423b,
+            0xcb6f4f125abe2fbd,
+            0x9fe5e6bc235586bd,
+            0x214250023556f8c5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb1e7e8f93b4b549c,
+            0x760af7f4837283d3,
+            0x97c666edba769735,
+            0x591b1b7a659191b4,
+        ])),
+        Felt::new(BigInteger256([
+            0xc0e391cb90d0ff48,
+            0x19a1942c582b376b,
+            0xe3306a3b7e6f8234,
+            0x8e7e372d6a64cfa4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0eeaa5f90e527689,
+            0x7d56a0042817553f,
+            0x2658c32e571eb53e,
+            0xaf67476f99cab351,
+        ])),
+        Felt::new(BigInteger256([
+            0x540c92403654fff8,
+            0x1374d37b9e1ff425,
+            0x1ecd6f4af499bc23,
+            0xf5c30b3daf6e7ba8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x82f1f96778ded79d,
+            0x18241787a091d7e3,
+            0x881e2d9f3708a392,
+            0x20183f1ae9836e8d,
+        ])),
+        Felt::new(BigInteger256([
+            0xc26a8121e156fe0d,
+            0xb2389e68f3c21f6c,
+            0x92b7e8cd93f0ee46,
+            0x37bd3b66b49024e3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe9f5fca3e8516695,
+            0x782796ece483bc52,
+            0x210efd7d2b7e38da,
+            0x8da679382a7dd8a3,
+        ])),
+        Felt::new(BigInteger256([
+            0x3c158b4d1b5ceec5,
+            0x6f66aed0e96988d5,
+            0x8627cb171da8c740,
+            0xb7eb66c83fc263e0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x340149019163113f,
+            0xbe145b005914a50f,
+            0xd70b559675132613,
+            0xbbc12f70c37795b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x335528db161f15f6,
+            0xfef89cdf736f2461,
+            0xc77347ef8fd0e03d,
+            0x46b4dbe4de33c0b9,
+        ])),
+    ],
+];
@@ -0,0 +1,772 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![Felt::new(BigInteger256([
+            0x2327148ac3c11427,
+            0x8137d9549ad87ea5,
+            0x36af6f004f1db5ee,
+            0xf7f41b6cfd40de6e,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0xa8f7436c3a37c768,
+                0xf79af2c9a4e4fcb2,
+                0x8f4f0ef7e597e9b9,
+                0xccfb3b5474823bf5,
+            ])),
+            Felt::new(BigInteger256([
+                0x1b72c070b67561a8,
+                0x6adaf5c38bde06e1,
+                0x99ec9cdbb194f78d,
+                0xf424989270275192,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3307aa07761b030c,
+                0x862907d3a1653ca6,
+                0x6b89a974593d650f,
+                0x88ce744560d21b6b,
+            ])),
+            Felt::new(BigInteger256([
+                0x11ff72e60967bd3c,
+                0x33ea8d38e0d2a9cb,
+                0x2f16e7f65bb82005,
+                0x198cdad8aed1b3b5,
+            ])),
+            Felt::new(BigInteger256([
+                0x82d7e267a1d7a2d2,
+                0x440300e1e30391ab,
+                0x2ff46e19d20d7f60,
+                0xc98b891dd4bf5281,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x1fe0443614a1b469,
+                0xdf850bde19ba8533,
+                0x9e78ec5f7a158fc0,
+                0x9d7413b2562f3b9b,
+            ])),
+            Felt::new(BigInteger256([
+                0x7909db560bdb3af6,
+                0x0ccea7af169c2c74,
+                0xb43c9bcdd51420db,
+                0x9f185a197a0c76b1,
+            ])),
+            Felt::new(BigInteger256([
+                0xd3525e00ba4bd64d,
+                0x8cd0c60593ce9364,
+                0x49f692f1c39dd49b,
+                0x22913cf96b447cea,
+            ])),
+            Felt::new(BigInteger256([
+                0x6af822ad527b931e,
+                0x6cca857c48aab670,
+                0xbed67ec0c14cbcf9,
+                0xfe44438a40ed0c55,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3d9594d6651810db,
+                0xdd040f9009bc84ed,
+                0x6507f8ef90991308,
+                0x353a275ba4dcb438,
+            ])),
+            Felt::new(BigInteger256([
+                0xd99fa4b5766fe830,
+                0x9032fce4c8beb054,
+                0x746b7b232232e969,
+                0x60d1a494da98ef64,
+            ])),
+            Felt::new(BigInteger256([
+                0xe47f4240d0f1fa14,
+                0x3f1d9ed9f275bd74,
+                0x0adac2c4a89177bb,
+                0x306702b8bdb7fbef,
+            ])),
+            Felt::new(BigInteger256([
+                0x72728903331d1506,
+                0xaecda214f2be85d8,
+                0xbfc467969d373b3d,
+                0x75a23af446fa554f,
+            ])),
+            Felt::new(BigInteger256([
+                0xe926095035904219,
+                0x2f5384018dfc927a,
+                0xa4d24462b8c568a9,
+                0xc1aace14a2de5af4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x10c98fc1eea687ea,
+                0x324ca0f082be7b91,
+                0xef7995fbaa729166,
+                0x9b8f36bd4668b1ce,
+            ])),
+            Felt::new(BigInteger256([
+                0x02742abc1ff13e4e,
+                0x13c6b74256e04518,
+                0x5d9ab5500a2424d0,
+                0x3c225c22c338a0a5,
+            ])),
+            Felt::new(BigInteger256([
+                0x9f3a0d61fc04f3cd,
+                0x315f02de8a73e135,
+                0xbc149e96186fd6f6,
+                0x0743936519cbcafc,
+            ])),
+            Felt::new(BigInteger256([
+                0xb85c92c80dddd2be,
+                0x9ca4182c79f62ee4,
+                0xca9d46d0e40a27a5,
+                0xaacb6dd87d6b12c2,
+            ])),
+            Felt::new(BigInteger256([
+                0x4cce78ff14034622,
+                0x93128b4ca31dbf94,
+                0x84e062ad3c7cdabb,
+                0x4571aec7b314d03d,
+            ])),
+            Felt::new(BigInteger256([
+                0xb354ba647d6caa61,
+                0x04a668c3b1c1e722,
+                0x7229a2a0741f7ae4,
+                0x57c408e22297c3bc,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x5866857366c09199,
+            0x09fb28f6ec5b6e43,
+            0xd9d78508ae2e2e80,
+            0xeee511a28e403d2c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x3e24ae95674b58ca,
+            0xe856b315a7067914,
+            0xe0545874f562ed66,
+            0xb6b773666301cd2c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xebeca4b74da4ca75,
+            0xd56153d9273dd0e5,
+            0x1a3d181f3f034dfc,
+            0xfa58fa3b585b98dd,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x3b1b366af97c455f,
+            0xffb9a4e7fd3ece2e,
+            0x87546e7c6f699bf8,
+            0xdf7c4f5f27511141,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd51d3e5f75ab52bf,
+            0xe4e89f12751bb9d4,
+            0xe04975f05abb95c0,
+            0x9edacc7297afd115,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb3a40664be2d531d,
+            0x4c7837050a593cbc,
+            0x2520a159064440a7,
+            0x95cf7eabffcd78c3,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe99d27205a3e104e,
+            0x0ad7bf0fff0452b4,
+            0x00037917fb818338,
+            0x2913bf7debeacf8d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8362f98ad32e986f,
+            0x661d1d0fd0b7ad38,
+            0x6ece3e0fac00977e,
+            0xe50c0056c4d456db,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2dcb4b11fb1ed1a8,
+            0xabf25fbc39e6e2c1,
+            0xd2f95925bc73ee8f,
+            0x70bfbb5eff6d9900,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7efc1c560ca3e523,
+            0x8deecb5f38cd29f8,
+            0xe1f9534859aa4985,
+            0x021f263214544daf,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0x91e22ce5e156e07c,
+                0x983d2b88253b5cd1,
+                0xf0475929e5046aec,
+                0xc488d552a4e29b1d,
+            ])),
+            Felt::new(BigInteger256([
+                0x61c495ee6da67211,
+                0xbd3a431ada23205e,
+                0x5ed9b107fbdb5860,
+                0x886c77f46f8e1f31,
+            ])),
+            Felt::new(BigInteger256([
+                0x22e377282b7ecfbf,
+                0xf5dcda04dd4d5a74,
+                0x896f0cab34f0e782,
+                0x5044fccdaf9539da,
+            ])),
+            Felt::new(BigInteger256([
+                0x60b7b28d2f660127,
+                0x4188172699255f75,
+                0x00c24fc85a735bda,
+                0x31bb6bf0f2af0284,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3d71fa6088f32f11,
+                0x655345dedf456258,
+                0x31637f7f9e7f684b,
+                0x24036dbc7abaf19c,
+            ])),
+            Felt::new(BigInteger256([
+                0x7cedada4a875cbd6,
+                0xc563561321ec8556,
+                0x0111d4b7dd069dff,
+                0x661e86a40e4f2ed0,
+            ])),
+            Felt::new(BigInteger256([
+                0xa28a7f43863a67d7,
+                0x795b2f1605103c23,
+                0x21cf51d010551224,
+                0x52df5b824e7ee605,
+            ])),
+            Felt::new(BigInteger256([
+                0x3c6908f2e5810b62,
+                0xde5db6b4e4f3a958,
+                0x120d1aaf3f52a4a6,
+                0x4f382075282c5153,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf36136111e258f97,
+                0x95b2060185f04487,
+                0x1bf4b6ed0bba0fb8,
+                0xc2e16e821f169589,
+            ])),
+            Felt::new(BigInteger256([
+                0xacd60f28ae674fcc,
+                0xdd244bfe60843f22,
+                0xf0f392e256b91bda,
+                0x818cfe3def181bc1,
+            ])),
+            Felt::new(BigInteger256([
+                0x10f60a8e921169b4,
+                0x5a3118ba86c799f2,
+                0xb858332016f5bf12,
+                0x82f7785139ab358d,
+            ])),
+            Felt::new(BigInteger256([
+                0x554fd9aaef208f6a,
+                0x398048c8b806b6ca,
+                0x6e5b9d4229923698,
+                0x9e55bb01f3c0a915,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x243c38b3b8f5fb7b,
+                0x08b433b070e68838,
+                0xa7ecf107bab76f90,
+                0x2a0f484ca57f2883,
+            ])),
+            Felt::new(BigInteger256([
+                0xfd3b3e30f3aa9839,
+                0xf3a2029d6ce12fec,
+                0x002143e2c5f6e658,
+                0x180bfec80b83dde2,
+            ])),
+            Felt::new(BigInteger256([
+                0x641e679501f0844a,
+                0x095670a7167a0a3c,
+                0xcaa3c3fd1180082e,
+                0xa8f1bbb8352691e4,
+            ])),
+            Felt::new(BigInteger256([
+                0xb1a5aa580c1eecb8,
+                0x2cfcec5bbd0373c9,
+                0xe8ab3e9e95c3ec8d,
+                0xc265d4357c609abd,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x09e7aa9fdcbbc769,
+                0xbff531225fef0aec,
+                0x928453a9796f3430,
+                0xf75cfc6391cef0a0,
+            ])),
+            Felt::new(BigInteger256([
+                0x6290f647a2e73f1a,
+                0x28aaa7e8a26ef410,
+                0x39aa81362c977c6f,
+                0xef96d850defcc652,
+            ])),
+            Felt::new(BigInteger256([
+                0x9914f18a81ef00b2,
+                0x8c526f50cad3f284,
+                0x65220b9d8c10b522,
+                0x33bb52e67ae65d4b,
+            ])),
+            Felt::new(BigInteger256([
+                0x6df9b6cbfc73eacb,
+                0x7d070bd61484ef09,
+                0x0e277bac680e5613,
+                0xb385e27c5b1c9327,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x90ea1cfbbac06500,
+                0xdacd9ba22cc0a68a,
+                0x5cb2ce4502f6a84a,
+                0xc7d366fcb39b2098,
+            ])),
+            Felt::new(BigInteger256([
+                0xe334167700787c5b,
+                0x2af87e6fea28886f,
+                0x88f8cf19e791ed1a,
+                0xce3b4656bcb14c7d,
+            ])),
+            Felt::new(BigInteger256([
+                0x0cc86998c6039303,
+                0xa6b9d2f27aded4da,
+                0xec4e1ff32f51b12b,
+                0x7d51cad5dfb8b5d0,
+            ])),
+            Felt::new(BigInteger256([
+                0xbb4441c50cd9673b,
+                0xbefa5e35f228379d,
+                0x9160f1d8e15225c2,
+                0x2260fb172f911db5,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x86ac0c8adfc7311e,
+                0xd26549f31aeecd74,
+                0xe4aeb84de9c53f60,
+                0x56368bd70c2e1e83,
+            ])),
+            Felt::new(BigInteger256([
+                0x0bc92048b2bd0e60,
+                0x9f99c75eae36b165,
+                0xd6b99af13ccd2bf0,
+                0xa9f719f88d495678,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x836fa34538a3eb05,
+                0x1f0e32ed60151f75,
+                0xa027a0ef1c04eaa9,
+                0x3a366e46887fef7a,
+            ])),
+            Felt::new(BigInteger256([
+                0x7faf616a38782803,
+                0x4617cd03c23c0297,
+                0x1bacc233bba883bf,
+                0x69f635c2a2dc3af8,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x4c2faaee2834c3a6,
+                0xa488bb16a933d22e,
+                0x796fc95c1b1c92bb,
+                0xbd89372ee8bffe78,
+            ])),
+            Felt::new(BigInteger256([
+                0xb75a5e6e8e743355,
+                0x180a5860302b0a3f,
+                0xc397799e31644d08,
+                0x5dd2fdad643263ef,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x41759b91b2d809e0,
+                0x67fdf87515b59c0d,
+                0x506b727ae9bfc553,
+                0x06ab078477cfd62b,
+            ])),
+            Felt::new(BigInteger256([
+                0xce378b215333c967,
+                0xab7823976af193f2,
+                0x079266851d5f830b,
+                0xefd15e0e6c7573a2,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xcc5c67343562bcae,
+                0x4833c5117fbb42b9,
+                0x84f7ce3f6300bc7c,
+                0x9565e091ad27556a,
+            ])),
+            Felt::new(BigInteger256([
+                0x5b78ca237d93c614,
+                0x727f57bb00e79c19,
+                0x906eb941f9912b70,
+                0xb50e507cd286c52d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xccf54bae7fe5fd98,
+                0xb04b1bbbc723b205,
+                0x1ced65dd5477cc9d,
+                0x72662d73cf9b4543,
+            ])),
+            Felt::new(BigInteger256([
+                0x9ea4eff227a58ec7,
+                0x18b501697997a750,
+                0x8ae9c07259a25069,
+                0x9e5108b528e370d1,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xe49865b995e1117c,
+                0xf59fdd7c2b36e879,
+                0xc4a90d606cfebf00,
+                0x2998516d8ccc7978,
+            ])),
+            Felt::new(BigInteger256([
+                0x9bcaabb84adef240,
+                0x99856736f75aec0c,
+                0x62c2893d0148d57f,
+                0xf505c1633c7e4332,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x51c03e38582db885,
+                0xa0fd97cfdf1a25ec,
+                0xbf57e677c280b608,
+                0x4a173f9a957cc29a,
+            ])),
+            Felt::new(BigInteger256([
+                0xf3dccc48b02cc602,
+                0x7ca4eaaa2170327c,
+                0x3c1a274bad9005b8,
+                0x772af0226e8535cb,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x4ff3957a60c447b6,
+                0x9f7a1e24bd67b5a0,
+                0x8a633abf133b31af,
+                0x6f4cce9a1591117b,
+            ])),
+            Felt::new(BigInteger256([
+                0x1f8d55b2413b3111,
+                0xe9d46d7c46651f96,
+                0xe1781583c7a344c0,
+                0x48e4cd94e4ffa933,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xead3cace5868f91f,
+                0x1e5bd7df7f8f1dac,
+                0x501f0b4438e87940,
+                0xceffa9404504ec7b,
+            ])),
+            Felt::new(BigInteger256([
+                0x6e9b2576f97f91a4,
+                0x59681bab4c1983fc,
+                0xcee267b68519fb3c,
+                0x97b8e3b420e0964d,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0xe12474079ed3d72b,
+                0x54ddce9752ec7a51,
+                0x801da62ded5e22a7,
+                0x41615c81660b7ef7,
+            ])),
+            Felt::new(BigInteger256([
+                0x0c7a370da8b72b58,
+                0x6e3b4ea1e480c74d,
+                0xa7a52166ba15c6e9,
+                0xcfc98df985a17fca,
+            ])),
+            Felt::new(BigInteger256([
+                0xe69a610719c7fe57,
+                0xf9785ce5c57e11df,
+                0x94266495dff61980,
+                0xfe217b9111696c2b,
+            ])),
+            Felt::new(BigInteger256([
+                0x5c3e08b78f327e3a,
+                0xa4680b527c983f46,
+                0x563ff51c11cc758d,
+                0xc9eded5ed7926375,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x4c6589132e7e21a0,
+                0x8c31290e2d4d81d7,
+                0x442fd28c30b6081d,
+                0x424281c32390b7e8,
+            ])),
+            Felt::new(BigInteger256([
+                0x9f8f458378ed78e0,
+                0x38a27880e9db0d42,
+                0x29ad4cf7ec77f12f,
+                0xe4d8d50f4e9905f1,
+            ])),
+            Felt::new(BigInteger256([
+                0x642be4b0063619dd,
+                0x330de139c9bbbdbc,
+                0x253ad1acd0005340,
+                0x5d8d863269525598,
+            ])),
+            Felt::new(BigInteger256([
+                0x0bc048a722a9131b,
+                0x8e8abc9aa9d5e018,
+                0xca0355890facf0c8,
+                0xa3d98d9df650761d,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xa46a236d7556055b,
+                0x38f299e7ceb55d9d,
+                0xbcab7652a819bbbd,
+                0x3a839fdcea054adf,
+            ])),
+            Felt::new(BigInteger256([
+                0xb41ddbbbc5497bd3,
+                0x04f6f8c35387f96f,
+                0x0876585c9f003805,
+                0xe4d11ed6773ad7b2,
+            ])),
+            Felt::new(BigInteger256([
+                0x35a72998f19911a9,
+                0x6fffe2945350fbcb,
+                0x065d10fd27272e8f,
+                0x7ef6b44b54b9b642,
+            ])),
+            Felt::new(BigInteger256([
+                0xc2136905e3db5c8a,
+                0x2257dd57711ac37d,
+                0x74692d779a905877,
+                0x2d4fd0d7301b5dfa,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x264e565ca5e67968,
+                0xeda9bbcd4934a8bf,
+                0x99048e5620f7d045,
+                0xc058a2de69176e14,
+            ])),
+            Felt::new(BigInteger256([
+                0x8c617f0f41d717d9,
+                0x08c09e0eacfa08f3,
+                0x284e80cbef78907f,
+                0x9026eb313ea44a25,
+            ])),
+            Felt::new(BigInteger256([
+                0xb88b364a9b0a776d,
+                0xc4ce512b5fe80e5b,
+                0x072b2da9681c9b40,
+                0xfb82f8c08358b4da,
+            ])),
+            Felt::new(BigInteger256([
+                0x2963d894654f005f,
+                0xf4a5fea9a3f6d147,
+                0xfbbc05c03df37767,
+                0x7443fe8e6a067a0b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x27bc8ada5c6da898,
+                0x1e255a0acdf8a245,
+                0xad8318205ee97d57,
+                0x7348bb4bbb0b4f0e,
+            ])),
+            Felt::new(BigInteger256([
+                0x68d8b71dff624b7e,
+                0x7525b4cb12236d8b,
+                0x9dd0ad8378f1cb69,
+                0x9dbd399319654e44,
+            ])),
+            Felt::new(BigInteger256([
+                0x6c986ccaf18567af,
+                0xa3d9c2ef136a7d28,
+                0xab7cafb338f88e8c,
+                0x026f81ae6cb68875,
+            ])),
+            Felt::new(BigInteger256([
+                0x5a147085b3cfa54d,
+                0x48ced521979d47b0,
+                0x04055bf59db38afe,
+                0xffc833958d2bf333,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x5c29e4659f63e351,
+                0xb3e14efeaa13d0e5,
+                0x191192c0e4a808d5,
+                0x23e9cb3a88aab248,
+            ])),
+            Felt::new(BigInteger256([
+                0x6f17c2e6c1d976d4,
+                0x409639b58d0a6182,
+                0xe4b9165d97b254b8,
+                0x3f55ccae9bd6b059,
+            ])),
+            Felt::new(BigInteger256([
+                0x1346380d9b8e9c71,
+                0xd3a10230c9a5def7,
+                0x66f2719a3e3e9079,
+                0xd02865d873e171a3,
+            ])),
+            Felt::new(BigInteger256([
+                0x69f6e45f1785c1cc,
+                0x7ea68657b36a41be,
+                0x2781f7b8b6ba5190,
+                0xad590291da9086c0,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xd2a2ce46c24dfe3d,
+            0xb750346b19dcde9d,
+            0xbb68533f26926b52,
+            0x002da5cf997774fc,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x031f04af711c1308,
+            0x6525fff12251220d,
+            0xbbd46322d7ad6e68,
+            0xa42ca4092b5c2a72,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x43b7aacfe672b5ba,
+            0x01e436902a163c32,
+            0x3d0742fa4c80dfc5,
+            0x1b5c34dc4cf26268,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0fad26b3060bd347,
+            0x13761c0c80a73000,
+            0x57fdd900071f485f,
+            0xf67c6592e44549cd,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x3156551338657a5f,
+            0x4f0e6e3542897026,
+            0xea7f204f49d26539,
+            0xd21a19687a18d275,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x631a54da0929e7db,
+            0xa5ce808edded33bf,
+            0xca492a6e3ac54e6a,
+            0xc0e958cac50f9060,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe9d366e651d22751,
+            0x0d655a7d9664143d,
+            0xff3f81512a84cf7f,
+            0xbe9149939a0d6b00,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x103697a83203494f,
+            0x9c0310b6107fddaf,
+            0xb57c4eaecfb34439,
+            0x7d3cfd5141df1838,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbb13fa3d61f48602,
+            0x03cbde611c78d907,
+            0xb5cfae9aeb9d3e5a,
+            0x5c59d2d9e1f39f2a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0a7c3647934b21c5,
+            0xd547e98cf9dbcd99,
+            0x79324cc4a372a82c,
+            0x1fd6eff41eac8514,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+    }
+}
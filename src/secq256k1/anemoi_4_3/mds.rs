@@ -0,0 +1,34 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [ 1  7]
+/// [ 7 50]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc13f6a264e843739,
+        0xe537f5b135039e5d,
+        0x0000000000000008,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc13f6a264e843739,
+        0xe537f5b135039e5d,
+        0x0000000000000008,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x88e9887f5567414e,
+        0x89d8daf1c3d0b454,
+        0x000000000000003f,
+        0x0000000000000000,
+    ])),
+];
@@ -0,0 +1,961 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x88666146bd8e6526,
+                    0x229a06dfec133c06,
+                    0x20de7f29b3c9861a,
+                    0x06a91d4d6e3172ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c41465727cbe893,
+                    0x683baaaff0499774,
+                    0x1aee4f3d63fa48e3,
+                    0x01361b02ec7fdd91,
+                ])),
+                Felt::new(BigInteger256([
+                    0xca400c9f8a099ce9,
+                    0x0c25df55a4aea91c,
+                    0xb5e4407441d04372,
+                    0x454216ada281b052,
+                ])),
+                Felt::new(BigInteger256([
+                    0x434bf3b93a410775,
+                    0xe7a514ea8fa95a9e,
+                    0xf85e8b1feeb82864,
+                    0x5e818a4a917c949d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3b7b890d5620e1bc,
+                    0xe2ad615f731593c1,
+                    0x3edbd4e7f5633ff3,
+                    0xbe5eb4e6c4d5464a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdf336f8a634c06d9,
+                    0x8fe77dc84559bcfb,
+                    0xd7c967e033a26f99,
+                    0x25fe035df74b9457,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfeb1eb6d06bdfd39,
+                    0x8f08cf15430db3d6,
+                    0x9c5f91e18e999325,
+                    0xd64d2f36f97ee869,
+                ])),
+                Felt::new(BigInteger256([
+                    0x40f1971e1a409bef,
+                    0x3db14e3c11a0a6b2,
+                    0xf2f34a418041afb7,
+                    0xb61641e2e69e1a0e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xeffb9690e459bef4,
+                    0x77d36abb1e5b695e,
+                    0x2f15c63d7ee8beaf,
+                    0x03c57153825164e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a7cc3a18f5a5115,
+                    0xeddfcadca02e341e,
+                    0xb73e1bfcf4719bfe,
+                    0x6c84d7449c264e03,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae18497f833b45ab,
+                    0xd56aa4a486cbefee,
+                    0x0cdec7fd928e88d4,
+                    0x3f1aeea283427b25,
+                ])),
+                Felt::new(BigInteger256([
+                    0x679975c5f5957be4,
+                    0x69f2dd4f064a5a92,
+                    0x01df1b94ca70ca7d,
+                    0xdbe986f10bcbebc7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1837699a4d2fdf51,
+                    0x0f5165babfadd1d8,
+                    0xd5c8e34c6434d173,
+                    0xa7130d17f5ccfae8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff199cd962b552cb,
+                    0x5d7c9d30fd196199,
+                    0xa3f48bee869efd17,
+                    0xc7567942cf49012d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x43815ed4e090f3b2,
+                    0xcbc2c31c35c9eaff,
+                    0x1dfff4f2fe30189c,
+                    0x90247c8591f1573d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x48ab260ae21329fb,
+                    0x7e3b9042a0448377,
+                    0x1b8f91a8696348b9,
+                    0x4da5db2cecff4cf5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xbaafe32ff717ab55,
+                    0x69eb74a25ba3f1ad,
+                    0xd962d1e6b95d60e7,
+                    0x96dc7cc3c9c422bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe273a0bba14fb6cb,
+                    0xfda7dee179e03225,
+                    0x3077088d41992447,
+                    0xbac360d374decd7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5c696080ba9f007e,
+                    0xf0494c74dd251b95,
+                    0xcb586c8c2a1388df,
+                    0xfa02b4a5fa8eadcd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6450b2be00c4d849,
+                    0xeef4500913e627b4,
+                    0x280c5e6bba48224e,
+                    0x43657d452661305c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x594e5eeeeafd44da,
+                    0x1f5e7836c6f40769,
+                    0x0b363e0c2b570f6a,
+                    0xe2f86566bf5f696a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f3e20c48cec4a82,
+                    0x5fedf04a20250f9e,
+                    0xecf713db766b6983,
+                    0xc8d9bc5165f028a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8193e2e1965a4993,
+                    0x4c0f375cc0064d3e,
+                    0x50e585ec5beba42a,
+                    0x9cd1169a841d3d1e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x52d88d996df0ff5f,
+                    0xe06e3d11b54e9ecf,
+                    0xf6f1686f4650babd,
+                    0x3ac3ed4bac3d18ca,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd7c9223873149214,
+                    0xcdfbf13ffd8c5f97,
+                    0x82fa2615114d00dc,
+                    0x5b965fd3cd831138,
+                ])),
+                Felt::new(BigInteger256([
+                    0x950a167b878cc5a0,
+                    0x3a51ec1c95058130,
+                    0x4d9b46a44810c3a7,
+                    0x2f144db0c2ce786e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0c283da1e6e12ca,
+                    0x601b8a1d26ba011c,
+                    0x1595b3dae1781b9f,
+                    0x53c68a844fc1d77a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e224c63e92eacd0,
+                    0x49354ae35cee5a4f,
+                    0x9eebe8eb475ca734,
+                    0x48878c297e42195d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb04f6b1f241eb9fe,
+                    0xcb149ee57d8a97f6,
+                    0xc5a818d7a937e41a,
+                    0x430056528886251f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x644aa9803d1daa7a,
+                    0xa628bd6c7e51384a,
+                    0x1dd2f236e00c46e9,
+                    0xd1fcb6fa8a2e0f06,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdf523cbe6341c90c,
+                    0xc258897e14302c56,
+                    0x36761413d239ed29,
+                    0x60facd3e6cd6d9b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3696055c55bb177,
+                    0xd04d63ee3cfba8f3,
+                    0x39db31d2c0d3c1dc,
+                    0x1847ddb265eff945,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x827531873aeeffd9,
+                    0x8d10713b3c66d988,
+                    0x6369f21f1dbbf8e1,
+                    0x617f2849fa117854,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b9fc6e5228c4ce6,
+                    0xd5955f763eb6d958,
+                    0xafeadf748e2001eb,
+                    0x915347f7277cf724,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe3dc3cb16773e03,
+                    0xec04510c7f9a99be,
+                    0x589e9cb6a4bac106,
+                    0x7356f1a12fa6a456,
+                ])),
+                Felt::new(BigInteger256([
+                    0x213f821d8222c360,
+                    0xff5a2e08d7bf42e7,
+                    0xc57b953943554f57,
+                    0xbaf645d0650aaa7f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8150bbb4e24cc02f,
+                    0x9021df40242b6826,
+                    0xb6c5e8be1865946d,
+                    0x7669f42f6616c65d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41dbf3be6090ba6a,
+                    0x9f31acf3fb458ea0,
+                    0x4aa68c46c850404e,
+                    0x350decbb8dd3089d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x93ae639810fb8199,
+                    0xa0ae32d76b696ece,
+                    0x698cc717be1d6077,
+                    0x1032cb3a9145b9ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe95129c723c98e95,
+                    0x6c8ab74db4e3da01,
+                    0x4fd6d49c4b7dc9d4,
+                    0x74121e38f7530305,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2511c90827cf4a55,
+                    0x9a068da267455379,
+                    0x8ae0441ffe76e383,
+                    0x54c5a8e1b67434d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2f3cc7491d5eddc,
+                    0xcac5e36edfd381bc,
+                    0x5fbfabd3174043d3,
+                    0xd12201b61676aa4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8b1275513e80042d,
+                    0x876035a562cb66db,
+                    0x0cc1dfa8aad5d072,
+                    0xf4fcc3c3a3e147c1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd22c73f78b495146,
+                    0xf2aa463e7592defc,
+                    0xce2c2ba6e9590b5a,
+                    0x37709ccce1c9adea,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x05d7d240d6e6d69f,
+                    0x08e570d657003391,
+                    0x46f8bf694231b5e2,
+                    0xf5fd25501e89dae7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d1eeba5bae7425d,
+                    0xac63f0921f344bbf,
+                    0x6e88aa59408743da,
+                    0x8b56d564baa179b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc19a0f07d311aa46,
+                    0xada9c8eee4dd866e,
+                    0xef9b3d757a3448a3,
+                    0xe6ae551a2906f0df,
+                ])),
+                Felt::new(BigInteger256([
+                    0xce9bb40fd1cc9e82,
+                    0x0c695091896f1e1f,
+                    0xd0a48ccdd255205c,
+                    0x0e0fc57322de13af,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x720af703577df841,
+                    0xded540aff33c02d6,
+                    0xbf6f44da74064883,
+                    0x330c89884ce0259d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8de3e3bd98f85ae,
+                    0x4511f01e68889bc1,
+                    0xb67fd772db2b1f57,
+                    0x8b0ba21f3b7a86cd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9ba7a3da1460460,
+                    0x66ce4ae49bed4a3e,
+                    0x8c0b2d6930cbd8dd,
+                    0x92ef884aff8f1b6a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x691bb199b8ec2cc8,
+                    0xf233b0c5c34522bb,
+                    0x120b0c80e3ac39e7,
+                    0x8977f548ccc327ef,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xbc9704fd15e7a6ff,
+                    0x3c4c8a1ee2753b30,
+                    0xeb9d51e168259f1c,
+                    0xd3c7f28c0e759593,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0334f885794590a5,
+                    0x467a509b07283b97,
+                    0xd264b915a6f92206,
+                    0xd75cf534972b4c14,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd375aaa531af7d0e,
+                    0x06a82e09311b0c1f,
+                    0xb4f70b66fedd0492,
+                    0x7b465b853b505185,
+                ])),
+                Felt::new(BigInteger256([
+                    0x460c17bc05878870,
+                    0x926d68c32c46a034,
+                    0x5b18c437397052e8,
+                    0x4cdcefa27bd30b0a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9a9407d401070cde,
+                    0x41e721709c5d0941,
+                    0xa646c4bba2102cc1,
+                    0x9ef64a1ed4043c72,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfb8fa2efcc39f863,
+                    0xe7a80723037e8265,
+                    0x3105df7c0d17b31f,
+                    0x77ada1262077fd7d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae439265fbf8fa26,
+                    0x6e5c8294793e5766,
+                    0xa4fd56d0e09190cd,
+                    0xd42415a5e0137a53,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe29cea81fc8f4b04,
+                    0xfb98316a3c48c183,
+                    0xccc7f2bf82d19978,
+                    0x2972cc88452a2ed4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7fbaeee53394a267,
+                    0x4c373cca2aecea1c,
+                    0x8ae448c3d600c98e,
+                    0x38a45031a06754a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89e381305b7abd9c,
+                    0xa8ae9a0c9db16e8e,
+                    0x20f55b01eb003be8,
+                    0x05af1a10853d7f6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x78a8dde149415f29,
+                    0x11239f3c212c5725,
+                    0xad07832ae56ed7ce,
+                    0xe3675d0dbea97923,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe1884c30a84ebb2e,
+                    0xc2911fe9fd95abbc,
+                    0x17b130f56855b243,
+                    0xa872c107fc95e283,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcdc87c8f70401e6c,
+                    0x5832e5c43fc67cd3,
+                    0x07299300cb6a5bd3,
+                    0x2f201e422567ff81,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdcbe6cfd30a85162,
+                    0xb710b48eb365ddd2,
+                    0x57b93ff93132bd5d,
+                    0x87a3e341bc6692e5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47483a66262fbcb2,
+                    0xafa46c38c1be9a88,
+                    0x907c99fa852d8a1a,
+                    0x780eed5ddfee7882,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6940d13f8fa71bed,
+                    0x9282a8cea8831273,
+                    0xdac0cfb92f021f6d,
+                    0x491eb3feaa4538fd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x82cdd364f1075f90,
+                    0xd9c34c3c776041cd,
+                    0x950041b3291f09c4,
+                    0xeebaee1446297737,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4dcd6c2d890f39c4,
+                    0xb938b06446627d56,
+                    0xc5ab3e1b80c00cd9,
+                    0x6338012a5f166496,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26137b8fa728ac1f,
+                    0xed9a8178ce3f1870,
+                    0x1f8cae64d8b5c646,
+                    0x0aa38e98c2473db8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0d4c4c52e58ce2f,
+                    0x00f71051102e9730,
+                    0x01cf3f886e5d8e83,
+                    0x13c8448b259f8b9c,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf0d5307309919aff,
+                    0xd7975dea11d5c433,
+                    0xbcee28fe723423eb,
+                    0x005df862ed39d53d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5eb291611c8ac2a7,
+                    0x2a358184e560f92d,
+                    0xe102f667fa981aca,
+                    0x8d9d6cd3b80f5b7d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3deb8f15e0fd4664,
+                    0xd71c496b499fa985,
+                    0xe6594a61393f27fa,
+                    0x8e04af55c9ace7d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabe1e9a38759ec18,
+                    0x5dd8d938e2286bf4,
+                    0xd87c3611c185f0be,
+                    0x751053a383497258,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x541b9956858913fc,
+                    0xc98b90f3f8db1aeb,
+                    0xac5e6178f8f58d4f,
+                    0xb752a6fc30a4aa25,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90da51230ef5d644,
+                    0x23e0f6c568bad6db,
+                    0x88f9636475afff3b,
+                    0xda9f8619ebabf31c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcecca699f0b1300f,
+                    0x90fa144f762a145f,
+                    0x4dda1408317b72ea,
+                    0xabc970471b0545b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbbf2601ab5788732,
+                    0x58146bb3ffd67993,
+                    0xd5ed97a0593d2902,
+                    0x2dc86d76f8753965,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3c0a60bc25fa13d0,
+                    0x6772bcb1a6f8b958,
+                    0xfd6fe11ffdb612a4,
+                    0xe4b5b229b74c2ade,
+                ])),
+                Felt::new(BigInteger256([
+                    0x20ea10c7f4cafb8d,
+                    0x5bb202967897d269,
+                    0x1f15075bfd1235a6,
+                    0xb8a5804a238d2996,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe75a128bcf4edbc9,
+                    0x5ab572f820920427,
+                    0x4fb55275a6cc8f1d,
+                    0xf66f641165b2871e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc2f97e5ef5a533de,
+                    0xce8a9d0e924012db,
+                    0xd2f2980870297aa3,
+                    0x912fd21fa7f52c27,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x44f37737b3efd1ab,
+                    0xe8fd73227ac6f002,
+                    0x7199c5d143026cea,
+                    0x606e06a54515d0a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xece7069ca69cf6c7,
+                    0x923f06309a90be26,
+                    0x3c29c3bac0113657,
+                    0xa8b1489568d63409,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf38260cc7d2cdc7,
+                    0xda714d26ca184c5c,
+                    0xd2e5c721ae5d98ed,
+                    0xe0464c683338327c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x047152610e8936d3,
+                    0xef74b0fcdcda0c0c,
+                    0x714ff516a9fe0657,
+                    0x055373e72532da8d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x97865bd554379457,
+                    0x299b3ef719b5ace4,
+                    0x6d3a52d123cd8967,
+                    0xe49b550e4c3603c6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x827cb8859ce7d83d,
+                    0x7a363fe84ba9d6ac,
+                    0x545183b12bd17f37,
+                    0xc7e336a5d1e0ad52,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1cc34e642a528108,
+                    0x2ff507a7e6df6b7a,
+                    0xce290592d340e62c,
+                    0x9187318fc9ca848f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1155e4f00d983ee6,
+                    0x149c2e3554b809f0,
+                    0x339bc0fe4bf3d555,
+                    0x72c1484c64781871,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x63f4acfd3fcf3029,
+                    0xba4388465637ce76,
+                    0xfcaef473ae5f63bc,
+                    0xa542f63cdfc63756,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a34ace706e085df,
+                    0xe655f6b5e4c6a1a4,
+                    0xae73ed45455bc707,
+                    0xe80cbcd47d81e7f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab5d25b2c0758308,
+                    0x80309a9eb3e84241,
+                    0xdea8044a5b55fa73,
+                    0x5e412ab875923ba4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55fac65949f2bed5,
+                    0xf99102025b07a7c4,
+                    0x3624cc6d580f9f71,
+                    0x9e6bb9a3f946df3a,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [ 1  8  7  7]
+/// [49 56  8 15]
+/// [49 49  1  8]
+/// [ 8 15  7  8]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x016d0b997e4df5f8,
+        0x2a8918ca85bafe22,
+        0x000000000000000a,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc13f6a264e843739,
+        0xe537f5b135039e5d,
+        0x0000000000000008,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc13f6a264e843739,
+        0xe537f5b135039e5d,
+        0x0000000000000008,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x48bbe70c259d828f,
+        0x4487b7d873195490,
+        0x000000000000003e,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x09fb51327421b9c8,
+        0x29bfad89a81cf2ee,
+        0x0000000000000047,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x016d0b997e4df5f8,
+        0x2a8918ca85bafe22,
+        0x000000000000000a,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc2ac75bfccd22d31,
+        0x0fc10e7bbabe9c7f,
+        0x0000000000000013,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x48bbe70c259d828f,
+        0x4487b7d873195490,
+        0x000000000000003e,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x48bbe70c259d828f,
+        0x4487b7d873195490,
+        0x000000000000003e,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x016d0b997e4df5f8,
+        0x2a8918ca85bafe22,
+        0x000000000000000a,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x016d0b997e4df5f8,
+        0x2a8918ca85bafe22,
+        0x000000000000000a,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc2ac75bfccd22d31,
+        0x0fc10e7bbabe9c7f,
+        0x0000000000000013,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0xc13f6a264e843739,
+        0xe537f5b135039e5d,
+        0x0000000000000008,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger256([
+        0x016d0b997e4df5f8,
+        0x2a8918ca85bafe22,
+        0x000000000000000a,
+        0x0000000000000000,
+    ])),
+];
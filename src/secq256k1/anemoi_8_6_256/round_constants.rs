@@ -0,0 +1,739 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xc6f3988c47bc0f19,
+            0x8f5c58db4bef96e5,
+            0x0000000000000031,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger256([
+            0x3576c90fea25d26b,
+            0x8ef6c71c7d78ed9e,
+            0x6accec4f8a3e1954,
+            0x0e39c033d0abe172,
+        ])),
+        Felt::new(BigInteger256([
+            0x42ee09dc2ed55820,
+            0x1457e7328d128109,
+            0x66f5c3cf97415800,
+            0x72377f7040af46af,
+        ])),
+        Felt::new(BigInteger256([
+            0x7d64307dd6ef109c,
+            0x9256bdb4e0ca78db,
+            0x057de8c365bcd1cf,
+            0x837c908e98ae95a7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x5ff4b1a7453bdad6,
+            0xe2497da068b9e9d8,
+            0x45b4c9d1fc8c977e,
+            0xbda683562e59c485,
+        ])),
+        Felt::new(BigInteger256([
+            0xfe9a6049f7bf80eb,
+            0x6e4e9491fb8b7081,
+            0x5fb21bf549cc34a6,
+            0x909ed8f65174d62f,
+        ])),
+        Felt::new(BigInteger256([
+            0xdd8357545f2852e1,
+            0x0eaab071a2475ab6,
+            0xcb91b00749e5c268,
+            0x75221b553b46533f,
+        ])),
+        Felt::new(BigInteger256([
+            0x0ba32e01dd8a280e,
+            0x5341c67779aa294e,
+            0xabf0ebf4f27ae04e,
+            0x1f93108eed5ea1e5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x988203b9f0208614,
+            0x53ea6556b35d3057,
+            0x8d9db9f45c855028,
+            0x6c4195d2d4d0a4df,
+        ])),
+        Felt::new(BigInteger256([
+            0x5aa00e8fcc3b0527,
+            0xadda6639da90731a,
+            0x821c16f0449a5a51,
+            0x5b4b7a2a6c687fbe,
+        ])),
+        Felt::new(BigInteger256([
+            0x7780b9e221dca820,
+            0xf21c2928dc27d475,
+            0x0cb70708d20792b0,
+            0x86cf729540b0330e,
+        ])),
+        Felt::new(BigInteger256([
+            0x4cca918aaa04b817,
+            0x2377c56240a22f3d,
+            0x5f1102006ad954f3,
+            0xa5694f6f39a18dba,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x7153987e23edcd35,
+            0xbc579ac4af2e1923,
+            0x7e77c55b55378c6a,
+            0x3f2a599073014a45,
+        ])),
+        Felt::new(BigInteger256([
+            0x00b63781b40602e0,
+            0x8612f7a9b6e32deb,
+            0xe37a87c530490774,
+            0x0baaee9914ff913f,
+        ])),
+        Felt::new(BigInteger256([
+            0x1c4bccb6a07a86d1,
+            0xfb33c928ba6e62aa,
+            0xe789a4c459efc7a3,
+            0xb2b2f3436e50b010,
+        ])),
+        Felt::new(BigInteger256([
+            0xc1cbf654512d3a9f,
+            0x5a48c3628e61cec2,
+            0x3d44faf8b9fd4409,
+            0x670ce9ea7e5f438d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0c5b4c5764926513,
+            0x6aff8b216952fb75,
+            0x311cd1089bf47002,
+            0x174a31fa23e0ba1e,
+        ])),
+        Felt::new(BigInteger256([
+            0xd8ace9667c2a7436,
+            0xb660ce56800c701d,
+            0xf3e58d770358305f,
+            0xc268d7892039ddb4,
+        ])),
+        Felt::new(BigInteger256([
+            0x6fdd53e660a9eaa5,
+            0x7d609ca961eb2b5a,
+            0x8e10c20514dc77ff,
+            0xe8eae9a92edb8714,
+        ])),
+        Felt::new(BigInteger256([
+            0x9116c0e134526b76,
+            0xa0a0e02844eca7a2,
+            0x8920c04d816d0104,
+            0x4076dee66436b82d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbc0f17da9476f007,
+            0xa3660978e4c0442c,
+            0x281afe467fec2c90,
+            0xb54b9a4a344bf358,
+        ])),
+        Felt::new(BigInteger256([
+            0xd101a4d45826aa06,
+            0x7975ee21bc6062b1,
+            0x6256fc5cb70df84a,
+            0xd49924a79d7fa3b4,
+        ])),
+        Felt::new(BigInteger256([
+            0x7bf2ff9c0b371710,
+            0xe48b3e6db5666a64,
+            0xf721dfdc086b014f,
+            0x9f280bca79aa8afd,
+        ])),
+        Felt::new(BigInteger256([
+            0x541508934aeb3fd6,
+            0xff8ae16c59b050d4,
+            0xb3ee1dc62e94c2aa,
+            0x3b2ab2937d84a375,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x57ac2b3b2fb5635e,
+            0xc51eafd25f3fafa7,
+            0x42e000d3764c05c1,
+            0xed30642391f40f24,
+        ])),
+        Felt::new(BigInteger256([
+            0xbe86a50dce66df88,
+            0x68cc44852337f66c,
+            0x94c640e726f19d1a,
+            0x6fdcf8469cf17462,
+        ])),
+        Felt::new(BigInteger256([
+            0xd7de8a759f26b4fb,
+            0xc2225035e2cd5fc2,
+            0x4c8ecb071b30d096,
+            0x89ffba13a65c6c55,
+        ])),
+        Felt::new(BigInteger256([
+            0xc3f9e91f1bb495d1,
+            0x792654fa80ce7530,
+            0x87898813d13fd0cd,
+            0x330dec651d01e1ae,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4a6773d8cb41c1c3,
+            0xcec91b17c8a33aed,
+            0x44a385ad6b108746,
+            0x8995c90f6d70ff30,
+        ])),
+        Felt::new(BigInteger256([
+            0x92c5a9d01bc4786b,
+            0xad58565de19fb9c9,
+            0x6ff5fdf2a72f023f,
+            0xa3e13641a2e70fc0,
+        ])),
+        Felt::new(BigInteger256([
+            0x6590c1305dbfb7b0,
+            0x81850f1c86371f7e,
+            0xd1659e34b74385e7,
+            0xd84f282397079ddf,
+        ])),
+        Felt::new(BigInteger256([
+            0x29e87288a7fe8b74,
+            0x118e4f86ea1f1ac9,
+            0x64151f0e75542f53,
+            0x1f3fbc368ed12635,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbdbd44eee34b4052,
+            0x104fd7269a9ce806,
+            0x822bc7e3a5d526ae,
+            0x36410d5d56a90bec,
+        ])),
+        Felt::new(BigInteger256([
+            0x4413f83970b484f2,
+            0x3574fa5e9606d9e0,
+            0xe86104d0e8da92df,
+            0x05dd0e0dfa1d86af,
+        ])),
+        Felt::new(BigInteger256([
+            0x391f5edcd5857328,
+            0x6fb5eff479803ba3,
+            0x0a210bf912f839e6,
+            0xc5acd4c2b1ec9e65,
+        ])),
+        Felt::new(BigInteger256([
+            0x24b0a39dc10153a2,
+            0x116873ba8ecf770a,
+            0xed9d9f9db7be41fe,
+            0x55ba6c8a9911f350,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x11c29d8d8299fcce,
+            0x7958d1b23086c2af,
+            0x7036e4bccf7f5965,
+            0x4229d60e1fe50392,
+        ])),
+        Felt::new(BigInteger256([
+            0xf3d6b633dfd623a7,
+            0x37b0bf2ae25e809c,
+            0x38b8e3f119e75674,
+            0xd3ba0b34e1edb14e,
+        ])),
+        Felt::new(BigInteger256([
+            0xe6c0e7088689b992,
+            0xa810b40784a3f3e1,
+            0xf6bc10857e6e86f8,
+            0x533120f62eb7fc09,
+        ])),
+        Felt::new(BigInteger256([
+            0xaa451fbdca71e4fb,
+            0x81b64f942829027b,
+            0x970260de546398cb,
+            0x062b95ff31aa915b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xeea24850538c8bea,
+            0xf3d53551d975a79c,
+            0x3e18ef6fc7c86c86,
+            0xd4f1b74f8bb43bc0,
+        ])),
+        Felt::new(BigInteger256([
+            0x3d077d9022fff666,
+            0x2c73e6199a88bff2,
+            0xf71e7e09b37937bd,
+            0xe82e7b4f975dab15,
+        ])),
+        Felt::new(BigInteger256([
+            0x81b639e527237b1c,
+            0x0bf7a2634332ae35,
+            0x97cdb5166bbc0267,
+            0xa657669610693075,
+        ])),
+        Felt::new(BigInteger256([
+            0x1f6085a7d5fc96c9,
+            0x4f818c6f349172b1,
+            0xc550db87b534c0b7,
+            0xebdd7a77f6557dd7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x22643bbea5481d93,
+            0x57ba70045d1cedeb,
+            0xcebed7f6785208ef,
+            0x6b5af4c3b73061f2,
+        ])),
+        Felt::new(BigInteger256([
+            0x4293a396bd3b85d0,
+            0xaf55fe6466828290,
+            0x2284f1cb4ba2690f,
+            0x9090e0c1fc14af05,
+        ])),
+        Felt::new(BigInteger256([
+            0xe4c68e604db6c6df,
+            0xaa08af151bc241d3,
+            0x398e0a4b02ec3d04,
+            0x39e3d7832976eedc,
+        ])),
+        Felt::new(BigInteger256([
+            0xb069756c7b76c92e,
+            0x98c166252eb4c7a4,
+            0x1a8a30a4f8236e38,
+            0x60f7fa973e8ef22a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe4e084659b1cb91e,
+            0xca5a0249e005a469,
+            0x1267632fb34fa675,
+            0xb106db806ace66eb,
+        ])),
+        Felt::new(BigInteger256([
+            0xdcc110979040028e,
+            0xe9cb200fd4d6b952,
+            0x44d3cedf88c5e920,
+            0x637b95d1da7939f6,
+        ])),
+        Felt::new(BigInteger256([
+            0x36c2450eec439a67,
+            0x7a9187a9e42cb07e,
+            0xcb41df9a9fb27fc5,
+            0x7b87d53e608a313b,
+        ])),
+        Felt::new(BigInteger256([
+            0x967118b02aa315c0,
+            0xc5dcf71456da0c10,
+            0x20f092f313a7e9fd,
+            0x6150223c4c339d91,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x5576a4f43f0b9c80,
+            0x54111b0c2c7001bc,
+            0x447eaa38c4f1aa7a,
+            0xbb8c72b0411af9a2,
+        ])),
+        Felt::new(BigInteger256([
+            0xb8b793cfb47b2f32,
+            0x7b41b0889a0d96aa,
+            0xdab74640be8eb25c,
+            0x5dcea8b239686fff,
+        ])),
+        Felt::new(BigInteger256([
+            0xe9b95dca7ad00f71,
+            0x94792623ff911ddf,
+            0x600d7dbaa6de4f65,
+            0x5378515c7fbf4b7f,
+        ])),
+        Felt::new(BigInteger256([
+            0x3ab876326b2b7aa5,
+            0x171af94fe7feeeef,
+            0xdd7e542c79367dbd,
+            0x8265a7215a7ba8af,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x0ffe17b82c6d58f5,
+            0xb0e4d715f93da951,
+            0x6db6db6db6db6de7,
+            0xb6db6db6db6db6db,
+        ])),
+        Felt::new(BigInteger256([
+            0xde58221ff2f02787,
+            0xceb698ef77fd8b30,
+            0xe117521cf4768ea7,
+            0xca63ddc2ec159094,
+        ])),
+        Felt::new(BigInteger256([
+            0x1af6a4ab1a9c1b35,
+            0x2ad23982630d8be4,
+            0xe07a5dfbe1bda6da,
+            0x1f675a595c890d51,
+        ])),
+        Felt::new(BigInteger256([
+            0x40bafd4e5040d0d5,
+            0x9c4928279b4286b6,
+            0xf54e35ee80e9e8c7,
+            0xff35cbad787f76aa,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1c6b8c5b4edd188a,
+            0x4ddb343165d65410,
+            0x4e0fbad59cb52fac,
+            0x8ea026fc1b3f1da2,
+        ])),
+        Felt::new(BigInteger256([
+            0x1ae814e22579c9df,
+            0xf8179ebb45de65e1,
+            0x70a097589d51d470,
+            0x66e72c747e562793,
+        ])),
+        Felt::new(BigInteger256([
+            0xe8caac3840154b0f,
+            0x29dd17fe77595d99,
+            0xdfba5fc97daf3bb8,
+            0x3c702c2d6897bc23,
+        ])),
+        Felt::new(BigInteger256([
+            0x0238b4e74c021d60,
+            0x61ec462733392f31,
+            0x36654eb5f6f521bc,
+            0xb56a819cdea7252b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xac2511923538441f,
+            0x475b6a78a8a05d3e,
+            0x0c2f6f031ad399da,
+            0x360e3e442f05868c,
+        ])),
+        Felt::new(BigInteger256([
+            0xce19f64c356bce72,
+            0xbf82bef41d0a2b28,
+            0x0941565eb645aba0,
+            0x2a66d274069959b2,
+        ])),
+        Felt::new(BigInteger256([
+            0xd9f441ea3e4020a5,
+            0x952ddf46a9609a07,
+            0x97167ad623f6bd85,
+            0x46f08838db512481,
+        ])),
+        Felt::new(BigInteger256([
+            0xdab9ed0783bcec7f,
+            0xff52b6bc430f5793,
+            0x5fbc28cc8d7947e6,
+            0x3413c5489839998f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xebf2131019c850af,
+            0x195fe34fcba01777,
+            0x9e44a2d62df7af03,
+            0x3d845298c69bc0af,
+        ])),
+        Felt::new(BigInteger256([
+            0xdb2b8bf7cdf9919a,
+            0x015293cd208bb766,
+            0x0bdaef9fbc6631aa,
+            0x0f539779a895fff1,
+        ])),
+        Felt::new(BigInteger256([
+            0xe5bac1786da0c4c5,
+            0x07dcc2afaed5f9a9,
+            0x132440fdc650cb5f,
+            0xa761597e02573642,
+        ])),
+        Felt::new(BigInteger256([
+            0xb6b6be8adba83476,
+            0x9fbaf825b7fdc886,
+            0xdf2b4a30f70f0fe3,
+            0x2a44b05ad65ce41f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4593096a20111152,
+            0xaaee9a5920e72eee,
+            0xe59837eab99d5438,
+            0xf83d9925d682b5bb,
+        ])),
+        Felt::new(BigInteger256([
+            0xb1e921d08b8bea74,
+            0x59d8543fd58e8e82,
+            0xb0f47eb8d45e1c34,
+            0xa8aaee8d12d7d199,
+        ])),
+        Felt::new(BigInteger256([
+            0x38132c9c233e101d,
+            0xb2417ff6422c5743,
+            0x4e59e7a5c6263d59,
+            0xc032be0721e99279,
+        ])),
+        Felt::new(BigInteger256([
+            0x449acb9884718e12,
+            0xc8f9db9809aad68b,
+            0xbfb598ed03678e7c,
+            0xe648137a1b3bddf3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x38c0226c8bfff33b,
+            0xdc5ad2abbee3290d,
+            0x3c12169d4d0d9942,
+            0x1456aab895b0518e,
+        ])),
+        Felt::new(BigInteger256([
+            0xad89894a73c8b87a,
+            0xd0a20aece3b9d2b9,
+            0x7ee19f13378c6c99,
+            0x38f2e4ee3edffa31,
+        ])),
+        Felt::new(BigInteger256([
+            0x0746e2eaaa4215ff,
+            0x87cf958316c7882c,
+            0x16e6b6f1692d4f23,
+            0xf487896b1b7af8fb,
+        ])),
+        Felt::new(BigInteger256([
+            0x0ae4bf56a74afaa8,
+            0xdb9873bdf045d160,
+            0x49fea7da6007d89d,
+            0x5f139069e34c2bd4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xedb492d25bc1b7ff,
+            0x915dbda1eed7826b,
+            0xa3906ed5a40de2e2,
+            0x18f0f06d6cf3b738,
+        ])),
+        Felt::new(BigInteger256([
+            0x74384515eec280aa,
+            0x0df182d3af4ef494,
+            0xfe0a3949081081d7,
+            0xa0ec3468b7ed14bd,
+        ])),
+        Felt::new(BigInteger256([
+            0x7c89cac972b50557,
+            0xf8b0ebe7f9a36b6e,
+            0xb90cf7c7dc938ed8,
+            0xac14b38fc1c82430,
+        ])),
+        Felt::new(BigInteger256([
+            0x9420fce7ac97a210,
+            0xe87e2be8ccd8e3a0,
+            0x6a5367d36353572e,
+            0x23ac4616fc64b3eb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd1db3882da177fa7,
+            0x0ce55cd08c1bd652,
+            0x950aa34f52777da2,
+            0x518884ebcba66112,
+        ])),
+        Felt::new(BigInteger256([
+            0x7a10485e4eb3418f,
+            0x09abebaef24ee056,
+            0xc8f0a5f441f30038,
+            0x7122a1f6411869e9,
+        ])),
+        Felt::new(BigInteger256([
+            0x3bd5000a43e1300e,
+            0x6f4201d121a5538f,
+            0x2d9a7a95324b5d65,
+            0x9696513235a90f89,
+        ])),
+        Felt::new(BigInteger256([
+            0xeb7ae3641bab00f6,
+            0xf2c35a5e6a0a51d9,
+            0x3695ae6dc10cceee,
+            0xac10457af169b240,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe4303bebb27190a6,
+            0x178e730ba555b43c,
+            0x2187052ade1bebe4,
+            0xbe2eeebf26bb08ac,
+        ])),
+        Felt::new(BigInteger256([
+            0xca5dc91a63f3e086,
+            0x5aeae9dbedf6313d,
+            0x904fcc77d47e5fb2,
+            0x93199f480a2b7bb6,
+        ])),
+        Felt::new(BigInteger256([
+            0xee90717cabc13cb5,
+            0x6be65feeace60048,
+            0xb54a07fededfe040,
+            0x43ef2356c26aaaeb,
+        ])),
+        Felt::new(BigInteger256([
+            0xc56fe83f24c81a53,
+            0x0110fbd7a6b23eaf,
+            0x0f124ea25456b076,
+            0xa2861b546d871a39,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x242c4aae05e3929f,
+            0xc364ac73fac15077,
+            0x695c4e9a2604f050,
+            0x649ea037a0bfc50d,
+        ])),
+        Felt::new(BigInteger256([
+            0x66173d388738c4b8,
+            0x9ff3ed84f9cf998c,
+            0x3a71d82e23c9f4fc,
+            0xfb7d8536a2c46b10,
+        ])),
+        Felt::new(BigInteger256([
+            0x47fb0e58e11f09dd,
+            0xa1bd3fc526d41a55,
+            0xfbaf39216894ff06,
+            0x6bfa5851effecd4b,
+        ])),
+        Felt::new(BigInteger256([
+            0xf6cd790fb292326a,
+            0x6edaf374aed62bee,
+            0x12413c790f3ad8f7,
+            0xed7e2d90b6e87cff,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd848878376df4a95,
+            0xf2e27b0bf63f3ae2,
+            0xd6bcedb515f89187,
+            0x18289cb3e4b7ab1d,
+        ])),
+        Felt::new(BigInteger256([
+            0x868496a76a6bc051,
+            0x49b87f6c0488de5f,
+            0x985606aeb506645b,
+            0x30b4108c305d12ba,
+        ])),
+        Felt::new(BigInteger256([
+            0x79ff51d4f1f83582,
+            0x755475ffe73a7a62,
+            0x3c3f721a4d8d0889,
+            0xdfe2b92ca9d8af9a,
+        ])),
+        Felt::new(BigInteger256([
+            0x4325710c5e260d12,
+            0xf1a79b480dcda1a2,
+            0xe00e4b8a67b68ef8,
+            0xf3f22d4453bc175d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8f7af6ff63677b70,
+            0xe5f317407842f302,
+            0x3ca8fa1d5cc2767a,
+            0x7f5b42f4292b4e42,
+        ])),
+        Felt::new(BigInteger256([
+            0x0f8138bb9f73eeed,
+            0x5bc5f938cedf12cf,
+            0x99029e51e36fde38,
+            0xa9dfdecaae0b939b,
+        ])),
+        Felt::new(BigInteger256([
+            0xa0adc3d0e321df36,
+            0xe7e2074d0edddf96,
+            0xb345eb307afd8bb2,
+            0x443892e5dbddeaf2,
+        ])),
+        Felt::new(BigInteger256([
+            0x97cc7e51ce369d68,
+            0x0f63f9995704c82b,
+            0x0a8dc48940e58506,
+            0x39d6162fb4ed08a2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x33c7f1f84594d465,
+            0x4868d498afcc15f7,
+            0xd6b9e27e961d44a9,
+            0x07b58fd70288b7e0,
+        ])),
+        Felt::new(BigInteger256([
+            0x4b51b69b2f076a56,
+            0x40c022dda11c5643,
+            0x11b9d88e1ef08ef0,
+            0xbf78fa00b22f8333,
+        ])),
+        Felt::new(BigInteger256([
+            0x944c8b5e3e3db169,
+            0x62efe7db3b315af2,
+            0x9b621da81620ff1a,
+            0xc88af6c738b091f8,
+        ])),
+        Felt::new(BigInteger256([
+            0x1f77327439f1e8a5,
+            0xe7049281e3131949,
+            0x675c83ff5ac73171,
+            0x7cdca3fae85118af,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x710e807fb75b0c7d,
+            0x1766f090417d4356,
+            0xab943a2ba59dacd4,
+            0xb9749cfbd5734c68,
+        ])),
+        Felt::new(BigInteger256([
+            0x3426493f50e3aa6f,
+            0x5cced9a4fc51636c,
+            0x4a6060935297bc53,
+            0x610582d60dbcbb0d,
+        ])),
+        Felt::new(BigInteger256([
+            0x5421b385ca6b39e8,
+            0x076faca3ec93f825,
+            0xd2f0cc6c1b2b32e2,
+            0x47b4e8da5483ae0c,
+        ])),
+        Felt::new(BigInteger256([
+            0xd09c9f62781b60ff,
+            0xc2dabb0c0a362bf8,
+            0xc6ad55dcbe342958,
+            0x452b9ed4f337259e,
+        ])),
+    ],
+];
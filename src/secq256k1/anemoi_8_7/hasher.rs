@@ -0,0 +1,1358 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger256([
+            0x48780ad3a9660fc5,
+            0xf80af68d152fa4e5,
+            0x6da30b77def47d2a,
+            0x39c358764c6d90b4,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0xf3fab787fcc856ee,
+                0xa71b9889ce02b279,
+                0x08a35f4927affe83,
+                0xd1bc18060d520310,
+            ])),
+            Felt::new(BigInteger256([
+                0x2202e363d3a2e200,
+                0x55a65f12c647363a,
+                0x611fc278021b743f,
+                0x360f5eae4a0c7ff4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xcb3910f501f45333,
+                0xf32ada8a9bd235c6,
+                0x6ae7f33437e0671d,
+                0x82c3cc647c98f3fb,
+            ])),
+            Felt::new(BigInteger256([
+                0x7d9f49d81d01edb3,
+                0x5514a87bbf0b67cb,
+                0x9f6361bf0d2d57f6,
+                0x7afc59fb44a3efff,
+            ])),
+            Felt::new(BigInteger256([
+                0xb3cc8d8f4049c109,
+                0x7bcd26e7c51046ab,
+                0x9ab3dff7e65c27d5,
+                0xbc8645ad61b92296,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x461921a78dbc2dd4,
+                0xb4df164c7a67a230,
+                0x23bef4f84e4df75a,
+                0x661b2350f2a9bbee,
+            ])),
+            Felt::new(BigInteger256([
+                0x4d340fd514767351,
+                0x78ceba05caf4e8fd,
+                0x41d758d6be148e35,
+                0xfdd5084fc223ce94,
+            ])),
+            Felt::new(BigInteger256([
+                0x2ac849d1ef6998c4,
+                0x89985b74beb06e9d,
+                0xcb0e929389f67280,
+                0x6bdb32182888ffb6,
+            ])),
+            Felt::new(BigInteger256([
+                0x9529a3e997da769f,
+                0x64c37cc7a5c15d44,
+                0x59e0295343e792fd,
+                0x36368563d7ab816b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xac987a0c17975fb1,
+                0xcb91d8ce21c73a7a,
+                0xdcc557c4190614be,
+                0xeb40708c3b5db726,
+            ])),
+            Felt::new(BigInteger256([
+                0xf66f77a4366d97a6,
+                0xb0eb829bbdaa2900,
+                0x838bda3e4bdaa10d,
+                0xf0baf813c957d504,
+            ])),
+            Felt::new(BigInteger256([
+                0xb10f43e5e478f29e,
+                0xcb7fb2931f804462,
+                0x68fc9595b4ac241c,
+                0x4af5b370349cd98c,
+            ])),
+            Felt::new(BigInteger256([
+                0x974bfea8c71d20ac,
+                0x4ce33c54508dcfd8,
+                0x78952638e00d3da2,
+                0xe6351c2feb4e2cd2,
+            ])),
+            Felt::new(BigInteger256([
+                0xc125d38390e1e3a4,
+                0x95b89f541e07c0eb,
+                0xa16a90201437c994,
+                0xb9deeec2145b6ec6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb63c1bf9f42a1356,
+                0xc980e21dde227955,
+                0x4b1b0a2017ed312f,
+                0x9f8f99f712d6d78d,
+            ])),
+            Felt::new(BigInteger256([
+                0xd9fceea735cc0eb5,
+                0xd5835f89d34f18e4,
+                0x89a46f498d270c8a,
+                0x962dac34b2391851,
+            ])),
+            Felt::new(BigInteger256([
+                0xe907dd4df6bc1d15,
+                0xbe598a02883f4dc6,
+                0xb48dd560dd629bfa,
+                0x504a945c169d8b74,
+            ])),
+            Felt::new(BigInteger256([
+                0xc9a64c2461b6c669,
+                0x4afacda8626ba92f,
+                0x267ea6ec5ae79004,
+                0xb8acb8b568f9e461,
+            ])),
+            Felt::new(BigInteger256([
+                0x18ad7af84c7cda9f,
+                0x7cd43cc30bcecf38,
+                0x4b4bae087022b073,
+                0x4efebe7bd1905ecc,
+            ])),
+            Felt::new(BigInteger256([
+                0x4e4744ce9079ac87,
+                0xbff35cb02ae1ecaa,
+                0x2b397b4dd4a54a94,
+                0x899b8401fc88152c,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x5917739ddbec5cfe,
+            0xe94c65b83b81011a,
+            0x1a379ecba9b3a7d8,
+            0x387a2da4d94cc00d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xee721786583535f8,
+            0x8d4f3a86b70405ba,
+            0x3a8e5c2d8013c2f3,
+            0xa512d4b532aa33a2,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd057dafe84ffb1f0,
+            0xccaec0e24e9ff06d,
+            0x2fcec452a5bb4a20,
+            0xb4f08a9ed459cf6f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x79db11019af1ee45,
+            0xe5caaa55b0968c18,
+            0xf458f0f0c87ca93a,
+            0xe6a7db8f8c408ffb,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x1e2544cf59571af7,
+            0xe024a02affb812c2,
+            0x4b0968758bf0298b,
+            0xb94542ee3644d0f2,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xdc8403802cd803e6,
+            0xf7efcf99a259f500,
+            0x75f85c5e0689743e,
+            0x67171233b84d525a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc7d6b4534aeaf64f,
+            0xaf9d9255fa7b2217,
+            0xd926c83d3e4aa572,
+            0x922dc16f5d6788b0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7b980e7bbb29ef0b,
+            0x50d65428f651fee8,
+            0x4dc6d2436bc8f6a4,
+            0xb516ef1db51cab4e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7f5c41ef4583373a,
+            0x2850f2fae35623b6,
+            0x7c498a3187a7694e,
+            0x29925439e003f073,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x768c1995f46b3398,
+            0x00f581ff936b5dbe,
+            0x535ae4f80e03e16e,
+            0xfae190abc3d3c9a0,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xee01da901b98a4f2,
+                0x2de4d9fdeb0f46a3,
+                0xdd87c37cb213f60e,
+                0xca30728e122c0091,
+            ])),
+            Felt::new(BigInteger256([
+                0x66608273486e7bcc,
+                0x738b8b8c33b68492,
+                0xdb80d0befcadaa87,
+                0xa6975c1fa47b94ed,
+            ])),
+            Felt::new(BigInteger256([
+                0x17f9805969c74b23,
+                0x601619faad722c85,
+                0x54e3c670ee186cdc,
+                0xa9f551464a531336,
+            ])),
+            Felt::new(BigInteger256([
+                0xb77f04c3d9f1f500,
+                0xb03a3606d5a05464,
+                0x851f6acda1193aba,
+                0x259f6b4af738ee69,
+            ])),
+            Felt::new(BigInteger256([
+                0xcfb9c76192c52c2b,
+                0x252f995d01397678,
+                0x316d9769a1acf40e,
+                0x65d417e38f8e4a79,
+            ])),
+            Felt::new(BigInteger256([
+                0x3d6a156d25d555dc,
+                0x18a8816cbe3b65f9,
+                0x04ddffaba62005bf,
+                0x3869bd6dfa3326eb,
+            ])),
+            Felt::new(BigInteger256([
+                0x3f925a947dd2e89e,
+                0x0d516aadcdae0d8f,
+                0x04cbfdc01d52c9f4,
+                0x9926b4acdcb3b373,
+            ])),
+            Felt::new(BigInteger256([
+                0x1711f6b06138a294,
+                0xba2987559c436d09,
+                0xc02623e76e03c02f,
+                0xcf77bfe866868f3c,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb5fe4f0d0c4203ff,
+                0x2b2b9d512e6470b9,
+                0x6e182a5b44b51909,
+                0xad650fe11a24983c,
+            ])),
+            Felt::new(BigInteger256([
+                0x0a710f064ce3c567,
+                0x69fdbbd28b5eb83d,
+                0xa1b2366ab5723b02,
+                0x5e43a9928b4873f9,
+            ])),
+            Felt::new(BigInteger256([
+                0x4f2d6757ef36f987,
+                0x1f825dcba6ffb37b,
+                0x9aee70fee4d5ad40,
+                0x6e6a73dfa4f8c05b,
+            ])),
+            Felt::new(BigInteger256([
+                0xc7878764d78c0e57,
+                0xeda8695da5f43319,
+                0x1af9aad9701bc27f,
+                0x208af401ba9dc346,
+            ])),
+            Felt::new(BigInteger256([
+                0x8dcdc6d3940bb79f,
+                0x0b8320bbcc85e648,
+                0x3848013cc3853f6f,
+                0xc829cf02907ab43d,
+            ])),
+            Felt::new(BigInteger256([
+                0x9e010973baf91d92,
+                0x9ed9e3adf9051022,
+                0xca5fcb99c37e238b,
+                0x8887b0f8f03b8743,
+            ])),
+            Felt::new(BigInteger256([
+                0xf5621043ca3870af,
+                0x1a87c89bc1b954c6,
+                0x3968be1e435c923d,
+                0x9bda7baa8d892d09,
+            ])),
+            Felt::new(BigInteger256([
+                0x3024f1f6dcbac3af,
+                0x34eedac7cd31279e,
+                0xeab9f5ccafb80abc,
+                0x52fd74430de07c70,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf9a48e9bc5266b74,
+                0x9cdfcdcb0e2e8fd3,
+                0x5e124795f423ad29,
+                0x34d1393dd23ddffd,
+            ])),
+            Felt::new(BigInteger256([
+                0x7045083def882266,
+                0x25fa89362766c667,
+                0x7c10b1b3cea91c40,
+                0x73ea4e27aa63616e,
+            ])),
+            Felt::new(BigInteger256([
+                0x1f192ab573f7f0d9,
+                0x77f6f5eb9d4efc69,
+                0xa357f1d38e68c071,
+                0xa0d31fe3476f9c55,
+            ])),
+            Felt::new(BigInteger256([
+                0xba12f70bd41c3ff9,
+                0x9d3bc5a9ebf317f6,
+                0x258eca54d5adca60,
+                0x28ff235564addb55,
+            ])),
+            Felt::new(BigInteger256([
+                0xfebe8a37bb9c9b26,
+                0x7d3d282855714e1f,
+                0x1d1eade8239626bc,
+                0x8addbffa40458429,
+            ])),
+            Felt::new(BigInteger256([
+                0x4166f88652283b8f,
+                0x10e751809ea32fb3,
+                0x95e34c00c045b0d7,
+                0xff4d8aede856d310,
+            ])),
+            Felt::new(BigInteger256([
+                0xdf3f7ecb04d7139e,
+                0xfac044c8230c69f2,
+                0x12cadea80c186c91,
+                0x6f11d84269fbd239,
+            ])),
+            Felt::new(BigInteger256([
+                0x43f190b84caf3440,
+                0xd03cf939f21ea19d,
+                0xc7e4bdfb32fd1d12,
+                0x63ab85e4798da7de,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x917d012e8a843ebb,
+                0xcbb8eae3e54e9927,
+                0x1ba3886b07b80b11,
+                0xe13f1555d686cb7a,
+            ])),
+            Felt::new(BigInteger256([
+                0x5069add6af8e1950,
+                0x3c6e861ccc38d547,
+                0x23a406ff2e3f830e,
+                0x788f66466d3f0644,
+            ])),
+            Felt::new(BigInteger256([
+                0x20d321cb54f1014a,
+                0x5a4823e4cb319390,
+                0x53c300214eeca44b,
+                0x83e47e06749a3f2e,
+            ])),
+            Felt::new(BigInteger256([
+                0x91830467d5fa5e77,
+                0x0fa2e279275db43c,
+                0x2cbb147fc050e0c9,
+                0xfa6405a56042c81e,
+            ])),
+            Felt::new(BigInteger256([
+                0xef1877b9576d2819,
+                0xcbe2d63991dc8fda,
+                0x6916098ddeb3f119,
+                0x33bded109f3e0d02,
+            ])),
+            Felt::new(BigInteger256([
+                0xc792a771d4c4ef8f,
+                0x4a82cf08a41ca97b,
+                0x9f81edf511af66d4,
+                0xb3c941e8493cb2ae,
+            ])),
+            Felt::new(BigInteger256([
+                0x710006e2c5f8cd05,
+                0x6834318ea22535df,
+                0x0f6ac2411698728e,
+                0xabcb085a127da04f,
+            ])),
+            Felt::new(BigInteger256([
+                0x3bf7d8af6c81b942,
+                0xccbadb5cf1b5f199,
+                0xdd46aaeff58fc89e,
+                0x421a5390c0fa9b87,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3c17e385e769240a,
+                0x892b20f6ad18d8f8,
+                0x87e019e536bede87,
+                0x86bd6ee0a2ba0e1b,
+            ])),
+            Felt::new(BigInteger256([
+                0xeb7e810736894ed3,
+                0x77f414093b912665,
+                0x2826f7e2c91e131a,
+                0xc45acb3440afedc4,
+            ])),
+            Felt::new(BigInteger256([
+                0x96d1dad260f32cb4,
+                0x2988d6e6df45dc0c,
+                0xb952f714f3724040,
+                0xc6142e0bb08d3ce9,
+            ])),
+            Felt::new(BigInteger256([
+                0xc752fe997f1fd215,
+                0x516163b2b3f91a12,
+                0x4417f6fbeaf71a29,
+                0x34450d0999ad8285,
+            ])),
+            Felt::new(BigInteger256([
+                0xa919f42a2713cb83,
+                0x100979292bb1e799,
+                0x2d401beb01a95d11,
+                0xe41d151802690b0d,
+            ])),
+            Felt::new(BigInteger256([
+                0x8e2072d229d1d22e,
+                0x33c2ec99bbabbee6,
+                0x2ea0941225555a67,
+                0x8ad18c2cd642b14f,
+            ])),
+            Felt::new(BigInteger256([
+                0x05ab368a7a485d68,
+                0x44dc103f6fa7c23c,
+                0x33b19c175cf4ac58,
+                0x3f5140983b658f71,
+            ])),
+            Felt::new(BigInteger256([
+                0x9ebd930513b970ec,
+                0x0ea05b143b667ba0,
+                0x5900b6784bcaeaa5,
+                0x7d7fe553b1c31f08,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3ff9dc32a9e7efcb,
+                0xb840ee314fc35f06,
+                0x09d73e6715c35d06,
+                0x4336cce48ce5c734,
+            ])),
+            Felt::new(BigInteger256([
+                0x403ef1e1f004e451,
+                0x2f52ef64ad304abd,
+                0x973758a63985bdbc,
+                0x84a5339abeb1b922,
+            ])),
+            Felt::new(BigInteger256([
+                0x9601380aa6302e2d,
+                0xcb335c99c1937569,
+                0x9ba925133b8b46c6,
+                0x87579815a5be4aa2,
+            ])),
+            Felt::new(BigInteger256([
+                0x7d40dd55aee40b92,
+                0x2b2a145d370f8d39,
+                0x3aef54924267178d,
+                0x8b6abff03e323659,
+            ])),
+            Felt::new(BigInteger256([
+                0x6fbcb125612405ed,
+                0x85a175c3a06bdaca,
+                0x2b9cf3da4945cbfc,
+                0x85c1d0857433e990,
+            ])),
+            Felt::new(BigInteger256([
+                0x1c218a3bec7ab9fa,
+                0x502080779e486d50,
+                0xf0268545cc380104,
+                0xfebe77444c1dbbdc,
+            ])),
+            Felt::new(BigInteger256([
+                0x3e5e92f989049db7,
+                0x4fdee5ad77e6ed8a,
+                0x40bbc4fec5b0bb5f,
+                0x32b755da55b8ca6a,
+            ])),
+            Felt::new(BigInteger256([
+                0xfa6af598a87d62ea,
+                0xab0f6f2b9c9a6514,
+                0xc28a7f17d69bdb32,
+                0x8967e872a2f9a6bc,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0xefa5cfef753805d7,
+                0x51402acc2c44f244,
+                0x63dae9b433976c2f,
+                0xb4914c08fc40e0e9,
+            ])),
+            Felt::new(BigInteger256([
+                0xcdab1c0a2e9288d5,
+                0x7a252c2e9c734146,
+                0xa7ac4a82f9a9d488,
+                0xe43247f6e19a1875,
+            ])),
+            Felt::new(BigInteger256([
+                0x74a680fd12055ba4,
+                0xd86b520894022bc8,
+                0xee49f7d5573bcb16,
+                0xfbbfab7b9a9b180f,
+            ])),
+            Felt::new(BigInteger256([
+                0x14d76666ff3b3cfa,
+                0x94fd88a5be1d1d5b,
+                0x953df61898a08937,
+                0xc90d071d0fa721a3,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x25f7aa87d9fad14e,
+                0xcc261ce7e8f496c8,
+                0x6705c7d19c042f49,
+                0x433e37ffbf720339,
+            ])),
+            Felt::new(BigInteger256([
+                0xe2c648dca77cf8b9,
+                0x7534a86f5bdd4eb5,
+                0x802642bfd8f44304,
+                0x30234d2e0fd9cfee,
+            ])),
+            Felt::new(BigInteger256([
+                0x62513d59c0a05d3d,
+                0x12fc303e31cfea20,
+                0xd558ad89bfd4a4c0,
+                0x1c2ad75c0bd1668c,
+            ])),
+            Felt::new(BigInteger256([
+                0x1476420ce58f79f5,
+                0x9712b53f81e379a0,
+                0x79c41fdeb61b0ff9,
+                0xd3351eccf0f7014e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xc1d5e80c9c6c2beb,
+                0x27730279b8f85354,
+                0x73f2c4b9940813ef,
+                0x89955ffefa77047b,
+            ])),
+            Felt::new(BigInteger256([
+                0x792487f997749a3c,
+                0x32040fcabc646a9c,
+                0x9612d41fa96c4c07,
+                0xbd0b1ce5689eafbf,
+            ])),
+            Felt::new(BigInteger256([
+                0xe23871910e9a559a,
+                0x502cc1c93fba6aa4,
+                0x6866130f46fbedee,
+                0x4a24569a25aa8378,
+            ])),
+            Felt::new(BigInteger256([
+                0xd12bb2322457c4b3,
+                0x549c66e3c62ea2ac,
+                0x9abffa2c3256f4da,
+                0x58e10d76127302be,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xa7b8e1a1fb0e3241,
+                0x40853997513eed87,
+                0x8f94b5bfd90a3716,
+                0x81c97c368ded84ea,
+            ])),
+            Felt::new(BigInteger256([
+                0xae819a7b6b5bff2e,
+                0x7b88b33953492dc8,
+                0x7b170bf02e5109ac,
+                0xd9555a8ba9629ff1,
+            ])),
+            Felt::new(BigInteger256([
+                0x56a9fc5befb4f6ba,
+                0x57f8a9194fc4cfc2,
+                0x8c817af7b4327a34,
+                0xcd9efce390509b37,
+            ])),
+            Felt::new(BigInteger256([
+                0xf0de83e4a1cde415,
+                0xdf56d4d6a35e7621,
+                0xad11cadea3412526,
+                0x57402913b10d1f3c,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xb488c46ddbae843a,
+                0xccb64dc06bc61262,
+                0x26fe21a883c4db08,
+                0xb03177fa175fd95b,
+            ])),
+            Felt::new(BigInteger256([
+                0x4f97db0e3b3bf5f5,
+                0x2d265b6dc1cbc4c4,
+                0x856909095304a179,
+                0x3cb7f9e6d244377b,
+            ])),
+            Felt::new(BigInteger256([
+                0x874c4beb9a6323e1,
+                0x4f3ae8282b22a47d,
+                0x0e020a41f482afe6,
+                0xb296fcbec5201719,
+            ])),
+            Felt::new(BigInteger256([
+                0x2da4f59101171662,
+                0xdfef46ccf053ea68,
+                0x83562a38d63865bf,
+                0x775e5b19e0a7984f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x4fc14334fd2dba0f,
+                0xb668a5fbf68b95f8,
+                0xd28bc425199f9c47,
+                0x2c38c70fcc522fc9,
+            ])),
+            Felt::new(BigInteger256([
+                0x2068e35a24160cd4,
+                0x24c739fad3168b06,
+                0x2de9e2db0932b6d0,
+                0x6abe3d621024418f,
+            ])),
+            Felt::new(BigInteger256([
+                0x8ba99d6d6857ee86,
+                0x630d1dc5d8b6d7d3,
+                0xe55dc2270ac8023b,
+                0x1e5752f750b9bd63,
+            ])),
+            Felt::new(BigInteger256([
+                0x5853815c6ad28245,
+                0x4d7bbfd162ed0a5c,
+                0x0859bb39ac5cac79,
+                0xde0148d99c8d9a46,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x0526d1e50eaed66a,
+                0xb155608323775f72,
+                0xa1638466a13c9fe4,
+                0xf451b6b0620af93c,
+            ])),
+            Felt::new(BigInteger256([
+                0xbf3d0e41b93c846a,
+                0xa376abfee3c48937,
+                0xa2263d247bb7e499,
+                0xfcc2739d671083e8,
+            ])),
+            Felt::new(BigInteger256([
+                0x14230b5a40f2b608,
+                0x29691c8a24118fa7,
+                0x5d19730cd09e45e6,
+                0x0e97dc931a0f8b0d,
+            ])),
+            Felt::new(BigInteger256([
+                0x1077bbe2954cb19c,
+                0xb9208d1695b4dfa7,
+                0x72686727c763b24c,
+                0x5981a19be5dcd6cc,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x320a610c8401c5bd,
+                0x63c5636116ece67f,
+                0xf218c251ca9986ad,
+                0xc63bcb2ddcdfa936,
+            ])),
+            Felt::new(BigInteger256([
+                0x087cf094bcee4a74,
+                0x3b1e2b48bfc9f5ce,
+                0x43d234dd673b908c,
+                0x87c6ce5027a75e6d,
+            ])),
+            Felt::new(BigInteger256([
+                0xbe270838b932fa13,
+                0x67d6630e30a780a6,
+                0x4e52c0c0fae3e079,
+                0x21f0a361452e7dde,
+            ])),
+            Felt::new(BigInteger256([
+                0xaaaf70b67ceee349,
+                0x8fb84d0cadee280c,
+                0x21a882008d65e6b5,
+                0x644523ec77a20930,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x6dcde3850b1d2076,
+                0x00d8b9953c999c81,
+                0xaf9a7ff7da238b3a,
+                0x4ecae2d227c0e1b3,
+            ])),
+            Felt::new(BigInteger256([
+                0x09300bb8a4ab8049,
+                0xe1b65284ccf7667c,
+                0xfbf1daecf3e197b3,
+                0x23dad97c77da5bc4,
+            ])),
+            Felt::new(BigInteger256([
+                0xd43ff19356532523,
+                0x024178ee762c1981,
+                0xad612a9ea9a7590e,
+                0x96b4858669c60b6b,
+            ])),
+            Felt::new(BigInteger256([
+                0xccad732c256346a8,
+                0x2f9b6682307d6f9d,
+                0xf7d639a9857b98a6,
+                0x8022ebf304b6ecc8,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x681d9bd9d254cf55,
+                0x92ba4d971bd2aeb6,
+                0x0d928852c5cf4258,
+                0xc9a330fd43625701,
+            ])),
+            Felt::new(BigInteger256([
+                0x849755c5fe5f67d1,
+                0x7a15f533f7246b7e,
+                0xf355ee50177da4e9,
+                0xf1e44e68dce91b60,
+            ])),
+            Felt::new(BigInteger256([
+                0xd8ec95820144d05b,
+                0xda44daf81fa9bf85,
+                0xb4f9671732597293,
+                0x7bc287cca444e9be,
+            ])),
+            Felt::new(BigInteger256([
+                0xb87bc14b2ea4c78b,
+                0x5bced2adb679b9c0,
+                0x14ec46489bfbacba,
+                0xf98e831cac322033,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd32050529a48e34d,
+                0xdf47c011ae5f19d8,
+                0xdf4514d3b9861cda,
+                0xbba14166787d8912,
+            ])),
+            Felt::new(BigInteger256([
+                0x6e6aa431d87f12e0,
+                0xba9df76f20f72a74,
+                0x9673e12ac5853c1e,
+                0xe812f1126cad7eb1,
+            ])),
+            Felt::new(BigInteger256([
+                0x163187080c6b225a,
+                0x30e581319eeac4e4,
+                0x21512534706fbcd8,
+                0x8af748eb06d37c31,
+            ])),
+            Felt::new(BigInteger256([
+                0x55af1eb401d756e6,
+                0x891dc677673bcade,
+                0x244f3419f01f6484,
+                0x1cc7a3dcd7442385,
+            ])),
+            Felt::new(BigInteger256([
+                0x60da0d7221f69217,
+                0x9b8d90b3779d4323,
+                0x65e603ae6aa5ac56,
+                0x55e574cf35b64a39,
+            ])),
+            Felt::new(BigInteger256([
+                0x19a0a17a94153a8d,
+                0xc22900eef5be234e,
+                0x2cb05bc965dfb18e,
+                0x6c37de6dc0bfa7d7,
+            ])),
+            Felt::new(BigInteger256([
+                0xf991d6ee42edd2a1,
+                0xd0d37404020a5c1b,
+                0xfd8e6fd3980257d2,
+                0xc99b28d6e3e18260,
+            ])),
+            Felt::new(BigInteger256([
+                0x2e31f00d0cf7032d,
+                0xa460744c95fdd6f1,
+                0x49c57506c237da30,
+                0x6a7491859a66fada,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf4c07fe20f797320,
+                0x5da664e70581d52c,
+                0x36c623c33367368e,
+                0x5e700b73bd98f1a1,
+            ])),
+            Felt::new(BigInteger256([
+                0xd9a65a7f03cfb303,
+                0xbadd365ccf733c13,
+                0x30d34515a001756b,
+                0x278352009501812e,
+            ])),
+            Felt::new(BigInteger256([
+                0x1e2b945fa7d751ea,
+                0x545ce7ae1e5f57a1,
+                0xeb323ef65c329833,
+                0x89b0f55d224f2601,
+            ])),
+            Felt::new(BigInteger256([
+                0x15e3599aa4a785c8,
+                0x38fb9ae97ca4a150,
+                0x5bd5bdfefbfd08e8,
+                0x43b97bf3cffd6039,
+            ])),
+            Felt::new(BigInteger256([
+                0x7e757b66653ad1ca,
+                0x0064c79cc4f4d21e,
+                0xc4195e44d43a7eba,
+                0xee23f6dc84ef9407,
+            ])),
+            Felt::new(BigInteger256([
+                0x85dd1ab5297984e5,
+                0x97dca896daba50ad,
+                0xc5764e8f58d0b974,
+                0xeb6155d79077896e,
+            ])),
+            Felt::new(BigInteger256([
+                0xa68b091e787f8861,
+                0xd777f4b245c86ae5,
+                0x049fc3778b626e6c,
+                0x49a5090b207b2cee,
+            ])),
+            Felt::new(BigInteger256([
+                0x03fb725d0c877ae9,
+                0x5fa5600c44939fb7,
+                0x0a62f7bb565c9987,
+                0x2af60bc94b546113,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xab59910c27079035,
+                0x00e0029afbccd551,
+                0x0bd03f176e2e6911,
+                0xa0e877d67e563f4e,
+            ])),
+            Felt::new(BigInteger256([
+                0x57bdffa530de07fa,
+                0x4c715351059228fb,
+                0x80f6a3779a012082,
+                0xd1bde36b0daf688c,
+            ])),
+            Felt::new(BigInteger256([
+                0xbc65bcd11e68bc44,
+                0xdeaa90df4146d113,
+                0x8f3aeea33162f534,
+                0x8f16264f11035a9b,
+            ])),
+            Felt::new(BigInteger256([
+                0xd99355b45f4ce10a,
+                0x67544b891b06075d,
+                0xe18ed0f54c71176a,
+                0xcf48c360f2679816,
+            ])),
+            Felt::new(BigInteger256([
+                0x4103c9b55e7b89c2,
+                0xe8f1781311ae0bcf,
+                0x242198f06993ca18,
+                0x80279df26efd5db4,
+            ])),
+            Felt::new(BigInteger256([
+                0x4b749356b55ef0bb,
+                0xac0a3308858179cd,
+                0x957792a09b6e4c43,
+                0x15186892db9452e1,
+            ])),
+            Felt::new(BigInteger256([
+                0x9a2515777d6f9700,
+                0xed70e359768c7a56,
+                0x417414324b31cba7,
+                0xd505dda5701056b7,
+            ])),
+            Felt::new(BigInteger256([
+                0x5e8fc5774ebf1c33,
+                0xa74ddb6122f91a88,
+                0x72fb66b537efd4e0,
+                0x1013e4206080df31,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x7b2764062cbf63d8,
+                0x8d6595fe82f46dff,
+                0xa453b95394ef5242,
+                0xb41de1d3eb403ed9,
+            ])),
+            Felt::new(BigInteger256([
+                0x604dcc7160e3ac43,
+                0xcd462b1b0ed928bf,
+                0x1349a33edcc7a9eb,
+                0x8c95242c15b6d7f4,
+            ])),
+            Felt::new(BigInteger256([
+                0x88da088d0e8488ad,
+                0xe5f3938b111357c0,
+                0x93b7142140fa9234,
+                0x6cc048cbf6d45869,
+            ])),
+            Felt::new(BigInteger256([
+                0x7fbc1850760d8b1a,
+                0xdb6cd3003595fd49,
+                0xb32a85f6497d00e5,
+                0xb1b6a5b57547899c,
+            ])),
+            Felt::new(BigInteger256([
+                0x3dad6439fc0b677c,
+                0xe47504948faa8654,
+                0xdfafb03d9f14ded1,
+                0x8695450d8573b497,
+            ])),
+            Felt::new(BigInteger256([
+                0x89e7bf7f2d3b9180,
+                0x9ca9e442725bb9f8,
+                0x1d3d0a69893978b2,
+                0xa029fade4b44ddbd,
+            ])),
+            Felt::new(BigInteger256([
+                0x7423dd1e3a6b2702,
+                0x6af36628986b1bd3,
+                0x9bb3d701a6a13afe,
+                0x94cbd7071e656fc6,
+            ])),
+            Felt::new(BigInteger256([
+                0xe36f31ea9f53e3c8,
+                0xd19f3af524132294,
+                0xfeafd25e5c368e14,
+                0xa6329a763c805e28,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xab7e5d361ec698f0,
+                0x44ada6a1f0991a7c,
+                0x98ef1454fd12fe39,
+                0x46d37c1bb19c613c,
+            ])),
+            Felt::new(BigInteger256([
+                0x12bbfd65eccaf2b2,
+                0xb93a7d7ae5dc9737,
+                0xa289630014592c9e,
+                0xf856ac7bbf75650a,
+            ])),
+            Felt::new(BigInteger256([
+                0xc3add5e29ad6c185,
+                0x02174610852bd4ae,
+                0xbdbac7cce40db410,
+                0xa9d99cc2cd778369,
+            ])),
+            Felt::new(BigInteger256([
+                0x25f586de7b7769de,
+                0x063df4b624c054f7,
+                0xb16fd02673093b33,
+                0x3670a0f64f5f5929,
+            ])),
+            Felt::new(BigInteger256([
+                0x33ffd09aff64ba48,
+                0xb196a600cf8bd93d,
+                0x48b89b7390488dff,
+                0xe225b9a14a623f0b,
+            ])),
+            Felt::new(BigInteger256([
+                0xe435a08c33a1ecd8,
+                0x98ad9cf72063e854,
+                0x762ad6e11f092ccf,
+                0x03c4ec0769a48c0c,
+            ])),
+            Felt::new(BigInteger256([
+                0x852606c05d1216aa,
+                0x18caa642fd928817,
+                0x65b9ae705f4034cf,
+                0x77e9d6418c1d8276,
+            ])),
+            Felt::new(BigInteger256([
+                0x1701f628e42c2369,
+                0x70fc47e622e8679c,
+                0x62831efbce8e2442,
+                0x65152d9cda7ba76e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3b4532536c295d46,
+                0xf4fda83f5e3a0dad,
+                0x1a14f472a5335d1e,
+                0x3ba4a7b26eda848a,
+            ])),
+            Felt::new(BigInteger256([
+                0x698474a2ee1b9258,
+                0xbbb11cba36fcfa31,
+                0x95ac95a49e3fa02a,
+                0xf4857c733ed2a8b6,
+            ])),
+            Felt::new(BigInteger256([
+                0x2a527c5e2ecaccc8,
+                0x5ce2392b66b71abc,
+                0xe844051371e0908d,
+                0x3452b3ff7e1ad524,
+            ])),
+            Felt::new(BigInteger256([
+                0x6800acb979b49132,
+                0x77d01b86bf9df534,
+                0xa36969bb6d71b8bd,
+                0xf9a6436b041c8bea,
+            ])),
+            Felt::new(BigInteger256([
+                0x3039546422eb3525,
+                0x46016d5d39800ba8,
+                0x004ec408f1a8256e,
+                0xea1bcee5eabeee64,
+            ])),
+            Felt::new(BigInteger256([
+                0x8b2ce14028ebd42e,
+                0xd8533d25173526c8,
+                0x0734d9af8c70e984,
+                0xa08f56ab78cb176f,
+            ])),
+            Felt::new(BigInteger256([
+                0x6a9588abb5280d87,
+                0xc0f239ad5fd477c9,
+                0x9c61a08a1ecb583d,
+                0x921dc19203c518db,
+            ])),
+            Felt::new(BigInteger256([
+                0x63e9d567e4b28da3,
+                0x855a700bb38d1e40,
+                0x52a14e77eaa0dfcc,
+                0xd6dbdd16731536c1,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x0757b7b744686387,
+            0x08c19af50cfd9bfc,
+            0x8f0f22251d1d950a,
+            0x5d904698881d3312,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbfb3143e57715ff8,
+            0x30bacdee493ca902,
+            0x3648d7f9eae82709,
+            0x62c17b56cc143b03,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x2e8c353c969c9f33,
+            0x43915e0acbfd2b07,
+            0x0d2ba614b6c742c0,
+            0xe9a5e0f49b333a72,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x1e1e3f44578089bc,
+            0x7dffb0f3391a20bd,
+            0x443f07865ecee020,
+            0x7ffdfcb978addf50,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7c0389eeec624e08,
+            0xfb47cd87db767465,
+            0x98f2b39ada01f86c,
+            0x5f97cd123edc6c59,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x08ec106661eec53c,
+            0xb6ff75640226e4a4,
+            0xd55a25a5db195727,
+            0x80a5b4d8abfab74d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7ec881b649c90f9a,
+            0x59ba5e2f0617e067,
+            0x0aedbc44a3837c1e,
+            0xdaf4648aa91fdf09,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0e47a1ec6541f3c0,
+            0x478a37368d35896d,
+            0xf75601f5ffff9bab,
+            0x30231189d54e4001,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0bfd134360182dce,
+            0xd71a42ac0ebe8f56,
+            0xf29023da8548ef59,
+            0x0ae001c4a450d220,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xda68e47b11e6bac4,
+            0xcaf9043acea025fa,
+            0x7aa5256639b79a64,
+            0xe47a06bc07983594,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger256([
+                0x6c1fb548ead57110,
+                0x6c50b166ef638e5d,
+                0x2af59c8aebf40429,
+                0x3b7f9d0a94336988,
+            ])),
+            Felt::new(BigInteger256([
+                0x74fe2776e98525ed,
+                0x1888e6432b83da96,
+                0xb17d23c18fc32c5b,
+                0xa53dd0474d4e0766,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x114f77a4034b2cc8,
+                0x0f6713279422b7fc,
+                0x308a769c8b5f2e42,
+                0xe5ba6415c4605283,
+            ])),
+            Felt::new(BigInteger256([
+                0xc29b4eee9cce4a16,
+                0xc94380a620520193,
+                0xb93c46eb1499b468,
+                0x2f2a290640f46ac0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xfa9cc976c3ba6082,
+                0x7378381df89dc1d9,
+                0x60d60c4cd601d92b,
+                0x5abf931ec3466d33,
+            ])),
+            Felt::new(BigInteger256([
+                0x3e0f26d05ca07749,
+                0x69165214921ebe15,
+                0x221af4f48fbe5252,
+                0x4f0b2ab8f3676670,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xfe22912c6ecc6c79,
+                0xdb75315d375cd950,
+                0x6efbf6b58fab7cde,
+                0xc187a54b13823cb1,
+            ])),
+            Felt::new(BigInteger256([
+                0x514d9a77c7b22168,
+                0x1f6eae01dd24b1a2,
+                0xc87272d9f6f2d126,
+                0x3727c28a5d248a98,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xdc2d93ffedbb1cb9,
+            0x3b0a82e6f5ca21ad,
+            0x6d7b281377e6c1bb,
+            0x5fbc0c04eda27573,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x54cacacdb3976d28,
+            0x410f2afa0dca53d8,
+            0x93feab1c7b536cc7,
+            0xfc654fda39790f43,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xf741693a02107868,
+            0x30b8f00b8fc3c48f,
+            0x3e1e2ecf27e6d5e3,
+            0x14cc7edfc473d469,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd5b054a37f2bed42,
+            0x9ce8b14cb92f2ce5,
+            0x6d5fd2969f17cd61,
+            0x4f5877f17cbae82d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x56892534ec2eea2f,
+            0xb00bf9447fea8a4f,
+            0x7c8eaba3819a7dd4,
+            0xe7d61dacaac09ad1,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x40570609274fc82a,
+            0x1cd37ea66caf4f81,
+            0xec594c9647db182a,
+            0x2d99ca24503d8399,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4b22d3ad7cb522b2,
+            0x0d4cf6807159d27c,
+            0xa939f9ae89bb5d0f,
+            0xd07bb1cd2564667b,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe4abde9aa07bf2c1,
+            0xc7482fb7e38504fd,
+            0x5dda9e9119d11539,
+            0xbc8631c283a32881,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,1711 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x63b9acb4022d7bbc,
+                    0xe5ad5f718db8d9c2,
+                    0x4bcc66a4147839ba,
+                    0x4fe3aa20ecbce772,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0257f8061447e94d,
+                    0x7a894135150be662,
+                    0x40d77f015496def2,
+                    0x0b2d7476fe9a85f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x30aef469ef334570,
+                    0x65eb432fcc3b03bb,
+                    0x75112542a3508a2a,
+                    0x161e43edd6c38449,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66c4c735d9e772f0,
+                    0xe6295046d6990e27,
+                    0x64082a7fedee2f8f,
+                    0xdd102480701aee57,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5732f7db424e4b9e,
+                    0xdbb4863651366336,
+                    0x40797f0b378b0ce4,
+                    0x6f90ec4ced082d27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b32dd2beebb7c23,
+                    0x7dc20f14332dae1a,
+                    0xbb942f27b2a5fa3c,
+                    0xdeee41db90e597ca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86ffa7704d700706,
+                    0x2ff0b7270162e2ea,
+                    0xd13d1e908a906c07,
+                    0x032b5800c05e3257,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6a4e53c8bcaa6d9,
+                    0x4165ef9441324387,
+                    0xf3f9d99eb28e1fc5,
+                    0xe519f4e4157f7264,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf7ecedcea81d7e86,
+                    0xcaf06f7b1ef86d16,
+                    0x4a2c5e046a19b54c,
+                    0x997839a3577c77f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe9df866c501e13c8,
+                    0x47d64da35634be76,
+                    0x294eae83cbdc9ded,
+                    0x99bd3bb2ed10b983,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb27552f7a99f381,
+                    0xf294aab3af02a3d0,
+                    0x2417f36c4bd279d1,
+                    0x8aa91d1817186704,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f3de5a542f9d1b3,
+                    0xf6d46c5437e12b02,
+                    0x28c0b9d0b18a140d,
+                    0x400b14cb7a7078e6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e9e0618fb5c2c70,
+                    0x309d0329df42ed39,
+                    0x06b2ea0ad576a947,
+                    0x8958df67c098d7ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86d4fd95dd6dec2f,
+                    0xc5732cef11aa9978,
+                    0x3ac51a5e2955a008,
+                    0xa1357a1e2bea0cca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x992706620e373a46,
+                    0x97de0fead2792bd4,
+                    0x856735366b6d8111,
+                    0xd7cb5383be74f7fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc19c555ce1298680,
+                    0xb4535956a1cc5ea1,
+                    0x58296467ef52f120,
+                    0xe42b560b6359d28e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x17547c418b22510d,
+                    0x144a022b1bbfd7d1,
+                    0xce48418134e14053,
+                    0xd3854dba302c47dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1ddafb578ca6c9c,
+                    0x58222c330a94d13e,
+                    0xcb8a63a024392143,
+                    0xc43f3e4c570193e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb64b3d3f8ddfe672,
+                    0x9605d93d66ec4d8c,
+                    0xadf5a9290f78a73e,
+                    0x045cd55bad3755fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8b451302c6bcb08,
+                    0x898e1cdb9785af18,
+                    0x09bea38e3dafd7bd,
+                    0x846709370a9d5803,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9183b6666ab4443c,
+                    0x0b13102e4b835107,
+                    0xd2717fddf9a10935,
+                    0xe0780170fc11163a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e80d4a743294d61,
+                    0x7c481e0a7bf1911d,
+                    0x8c3c8bf01311b34f,
+                    0x7db9d9ef7eae4e35,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7693404dcab1a8d8,
+                    0x388c996a5182df92,
+                    0x5d6bf565fcac1d85,
+                    0xe6a1d179fd8de996,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe9226f8d347f22e7,
+                    0xa5856df574008755,
+                    0xd08bdb89da9404a5,
+                    0xf67b4dddb6cb6150,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x13213f29d009ca47,
+                    0x43a0935b4fec43bb,
+                    0x557a74bb6ba6dddf,
+                    0xb05a1e42d3388b5c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x594e93844467932c,
+                    0x33bfe08028224681,
+                    0x38dae159e908b3e3,
+                    0x2e4e0400cdafbd1a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90b12b330a3a27da,
+                    0xb33a881bb96048da,
+                    0x43180a7d45e2faa4,
+                    0xed9b16a30b9def27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7edce948137cadd6,
+                    0x93419aab8e33a8f8,
+                    0xf62212ab1f23ed35,
+                    0x0f928931820c1317,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77c1fd82dfc8a79e,
+                    0xdbc51fa1f2932ad2,
+                    0xd5b2b6d0db37ffda,
+                    0xbbc08e944fba0a08,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7af01d32979ce6ba,
+                    0x5c40e86166624efd,
+                    0xa66500d66fecb149,
+                    0x982e4506a49933a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x185ba910a081051c,
+                    0x71f623e836fbd143,
+                    0xe1ef67098326f5c6,
+                    0x850dacfb6c0c3606,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd0018e21f56f80b,
+                    0xe861a5abf4f5a4bb,
+                    0xe7adcec68ef30fde,
+                    0x14900d7164d552f9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc7990c338d661d45,
+                    0xed59eaaf7ea9e207,
+                    0xbb8cc2d9e0508ee3,
+                    0x0f53eb68f98c77f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb84c40ab0ed92e32,
+                    0xea153ec823c5ef6d,
+                    0x83f112a8d94d45f0,
+                    0x09ac5d339b97fb62,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6f5fcbed5b13e76f,
+                    0xf7e8dbd2c891d8c2,
+                    0x2c2e56ea761b1872,
+                    0x79ff7762e34b975e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeeb00b1e4386d92c,
+                    0xe45e3ec24c2fd93f,
+                    0x8477fc494f09602b,
+                    0x20f3fc0247a596fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9c5fde7380b05075,
+                    0xf612969691147020,
+                    0x9baaf4996aa0c80c,
+                    0x8c925ced7069c31b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcab1243e5c4dd9cc,
+                    0x6076b6b38041e4c5,
+                    0xe724ee8e5a70a5bc,
+                    0x3a20fb7e340be518,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e2c600aa6cade91,
+                    0x74eaf780f11e9cde,
+                    0xe7d73aa669127e0c,
+                    0xf38bce9af0f09597,
+                ])),
+                Felt::new(BigInteger256([
+                    0xceddeba97c892f19,
+                    0xcf6121d061ba2d36,
+                    0xde8d78c0fe5004d1,
+                    0x1f46f83b0bb0ab12,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc7ef25707b374c3b,
+                    0x0a25ddf59ece2d28,
+                    0x9cfcc40490a37d8d,
+                    0xdd6c1b820fa17642,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc84a092510ac5f18,
+                    0x724b67259f9c2b0c,
+                    0x6a5650adf555ea7f,
+                    0x3bd6242b73efbcf7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a816634de0dc70a,
+                    0xa4c47a81508d82dc,
+                    0x0b66172470e44c1e,
+                    0xf536c7a958894700,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1489d6cb5a6e89f9,
+                    0x37898de51db48263,
+                    0x13d88731689f8603,
+                    0x67bade0a090b665b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3d23ad8787ca02c,
+                    0xccf976b1aad760a6,
+                    0x013aae1e58978983,
+                    0xb495dacc6503abd8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc6423038e831eec,
+                    0xff9e01547a3b4a16,
+                    0xc80e34d0c70f50a8,
+                    0xefb8c35af0d64291,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ef68b0a3e4c0d87,
+                    0x277e6fa999d68ee5,
+                    0x1adf47af5accf86a,
+                    0x2fed5414762cac1d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe82ecb99da89cd31,
+                    0x92b4edffd04b00ec,
+                    0xe27bc39ed3d4935d,
+                    0x278ac30a7068b2c5,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x490a7f2be4b149dc,
+                    0x21887e3aad4e126b,
+                    0x6db6db6db6db6db6,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2c9ed3010cbd9df,
+                    0x74621436d2099337,
+                    0x6561d3b9b440493b,
+                    0xf43d11d9a8e44d7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b611db7cfeb3a0f,
+                    0xd95232c563001d77,
+                    0x0c0c3ab1c55ba3ab,
+                    0x51d11ab9e3c6b19a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ace13210a1402ab,
+                    0x4e034504e432956e,
+                    0x67ee6d39274ccf8f,
+                    0xf042e7bea2c2f167,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8332ff3f4ec4737,
+                    0x09e795c0156bf66a,
+                    0x5136a59334f04d38,
+                    0xeadc30ef11212e19,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a778ac562ff3fd4,
+                    0x4c1197053309108d,
+                    0x6db6db6db6db6dc0,
+                    0xb6db6db6db6db6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fa4bd19a06c8282,
+                    0x755db9cd5e914077,
+                    0xfffffffffffffffd,
+                    0xffffffffffffffff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa0c87c4fe85dbb3e,
+                    0xb4936ad4da280028,
+                    0x496078faa63344c6,
+                    0x3e7a9cd422da8fa3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x10af249c15ac5523,
+                    0x9330b0e6731b0fb8,
+                    0xcd9b2e193c465d3d,
+                    0xa5ed0674ea9221df,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b9b11080a5d3115,
+                    0x24bd06ea45061258,
+                    0xffb99c6b159e3c84,
+                    0xba092f629e14312b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd2bd190d0f89c5e,
+                    0x4cbd40598ef80560,
+                    0x07b6b7705e0073a9,
+                    0x6407288d0fc8805e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ae03f8f9eeb234a,
+                    0xfa6d08b22e23f715,
+                    0xed1e497e1940cf81,
+                    0x2b7c9fc6bba57a56,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97aa9ad4ff78619e,
+                    0x154b99074e19999d,
+                    0x3258240620cd72c6,
+                    0x4caea26dc31fae2b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94b0786c5205470d,
+                    0x23581a1672d7a2bb,
+                    0xe2c2a84db5659bd0,
+                    0x01c0ef9e0f315949,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd50942eb9ffd864e,
+                    0x4b0d84bc37b679eb,
+                    0xf2be281ed5357b2b,
+                    0x62bbc301a1794b19,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7fcda043dc992779,
+                    0x61cacf2477dffc4e,
+                    0x9d061b2236ab469f,
+                    0x9c37afa5a70f0dcf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94337c6ffbc70fb0,
+                    0x41723b4be9b65f32,
+                    0x8ffaa89d62b69ef1,
+                    0x27f4a8e07b03f61a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92b5ef7f1c22cbfa,
+                    0x0e236bfa37694196,
+                    0x75add7da95c48875,
+                    0x50bfd0e83769c9e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa64dd505e13a860c,
+                    0x00f3832d947f2940,
+                    0xfe67bcba533f648f,
+                    0x5965c6c4b40f8f0f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x177417b865f5e1d2,
+                    0xd4c082b2d177baf2,
+                    0xc4e491d65be2494a,
+                    0x1c5b86e09a0fb60a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f12005dd3343307,
+                    0x16628983ee5d1c3d,
+                    0x6757573c411aa31a,
+                    0xc61cb15e9a36874d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x387ed1f1b0c80e1d,
+                    0x876ab0ba35d7901b,
+                    0x08863f903d75bb88,
+                    0x809b8153cb1156f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeae7f6c48f5a2241,
+                    0xccafb46748d50599,
+                    0x6ac946040ea4eaa4,
+                    0x28893883c52618f9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x262cf5bd4a16dbfe,
+                    0x7e3500ed0a46e026,
+                    0xe3b7b2866db4dca0,
+                    0x5730a646ed2e3b0c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01ed5cf96bde0f8d,
+                    0x43cb6c2ed945c9ea,
+                    0x79ab733cbba08830,
+                    0x7d83429c08fc1bdf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe89930bd2b1bbb6,
+                    0xc6b975b542c42c0c,
+                    0x57f447948b92373f,
+                    0x3fd134645e0fb862,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7e0aae016439503a,
+                    0xd2d50fa205638f46,
+                    0xe4968079238a4718,
+                    0x2f6d234662e1d1ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf86304d547c3aa4b,
+                    0x4a5acb32ec1920eb,
+                    0x58e3595a1a6b5181,
+                    0x588a567a0f2ded55,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc925be6aa9cddee6,
+                    0xd50bd04a70701d75,
+                    0x7ccbe1814b227bf3,
+                    0x7b2d2d78ae81624f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdea30350ef7be7f1,
+                    0xfc5949c52d1c8db4,
+                    0x59a7b0fb05938d26,
+                    0x4c034fda7e33e6bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1917c2a13df11d7,
+                    0x75ce14ff76b1ab4d,
+                    0xc37e2cbbee3ef0fb,
+                    0xa88f627762b7428e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa66016a767aa2e12,
+                    0x3ad4f331fa1e5ed0,
+                    0xf174029c95e93f91,
+                    0x986cae31665b1d9e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71c86ca179d1e299,
+                    0xb1b20b7486f29f91,
+                    0xc07d6c6265bb72a0,
+                    0x9f1bc321c9d0317b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x552ab44182b3a870,
+                    0x9945c2fbaf566d9e,
+                    0xfe78fbdebe8d0832,
+                    0x62d2b62fc10b6612,
+                ])),
+                Felt::new(BigInteger256([
+                    0x75e977c1d4a599b7,
+                    0x59e449a2093aa0a2,
+                    0xd3328934d9d09acf,
+                    0x4cc6f263b7e69969,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a45adb73aad27a7,
+                    0x2646e841da2c8363,
+                    0x289efc67aabfdd91,
+                    0x59b3408e2b5de866,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24ccd36e8de0963e,
+                    0xfa6ac34fbeed3f0e,
+                    0xf8f4aaa241875be6,
+                    0x3f50b135718ff45f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x17d014a2c0c0eddf,
+                    0x13b91f1ba5d5133c,
+                    0x1aa9752cf93cbec2,
+                    0x6d2cbe62f78f04bf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x584d1b0822894a9e,
+                    0x82a6ed79a1490e9d,
+                    0x6e881d3ae0e162b3,
+                    0xb6542287e8de417b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xbf7c011dad719b39,
+                    0xebafa431fbf488ad,
+                    0x7c0ae866316c0aca,
+                    0xa30db2a65227ce19,
+                ])),
+                Felt::new(BigInteger256([
+                    0x697a86dfd06703cb,
+                    0x3ac98e6a28947621,
+                    0xae218261016b08c5,
+                    0x86ac8fcd448b8c52,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7b8d6f7f8ebd6d0,
+                    0x937e1919a15aebd3,
+                    0x7ce84ccdf476e026,
+                    0xfab004f1c72ef5f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6edbe308cda6c49b,
+                    0x63d63f0722149166,
+                    0x5f0859fec0db64ae,
+                    0x4f8ce5d8f55cbf0f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb198b1f2676d19c5,
+                    0x3c96261395313961,
+                    0x30c4403de2d7df59,
+                    0xd35ab3781fcd7a24,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdac908a04f9a3d47,
+                    0xf64315682d12fa28,
+                    0x3d93902e2ef05aa3,
+                    0xd56698192a6e8223,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd8da92a69d6f425,
+                    0xaa53dc36604dcb08,
+                    0x51820d0e8ec4d735,
+                    0xdaa29965cb530d76,
+                ])),
+                Felt::new(BigInteger256([
+                    0x12d07d712f17571a,
+                    0xacfe63da6a18077d,
+                    0xe31016a7abc2120c,
+                    0x165b9d76d61fa31e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x92551b4ed062c467,
+                    0x37462437a024e30e,
+                    0x8db98b271360a18d,
+                    0x61ccfcdfcc11723f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x440558b1402518f5,
+                    0x0592bc6163e49224,
+                    0x54fc2f04f1dad20c,
+                    0x74af0a6455880348,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa71ef573ef3b3e76,
+                    0xddb8e2cebeff93c8,
+                    0x7935c41aeff905ae,
+                    0x32aecfc4ac4f654c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x559f8d13bf4aefdf,
+                    0xe8e0262d4a2ebf81,
+                    0xbe97a7dc802bf9fa,
+                    0x126f80ba63597f36,
+                ])),
+                Felt::new(BigInteger256([
+                    0x192c7f2b5dd872ac,
+                    0x14e91288ce03ff3f,
+                    0xb7dbc82089112992,
+                    0xf68c401ba8dc3d9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x96744d7f04dd1dbe,
+                    0x18300ba70f4eb54e,
+                    0x1d313e1311803953,
+                    0x87612c3039fefc4f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd05dbf34457babc4,
+                    0x27c47481c6f53cc2,
+                    0x31f2f500b7c755f4,
+                    0xc3b649684226609f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa36a2527b64ee5ca,
+                    0x81e7add02450ceef,
+                    0x23cb81a4e02ea58d,
+                    0x10fce8d755f026e1,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5e18a768fa4c2928,
+                    0xb55e074dcb04491b,
+                    0xc47a201c860917ee,
+                    0xa8c7e627d7f54ea7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc97d24a8011cc33,
+                    0x83c21ae49d54a02e,
+                    0x13a53a3ac16fa4ef,
+                    0x6134904991e9cfef,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25a7e283ebbedee1,
+                    0x5093e4c76336be4a,
+                    0x84509f528d815e25,
+                    0xf9b9c2d3db3c12ba,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18c1d66c1ba51fa6,
+                    0xa58cd86bb95e40c4,
+                    0xd1abcaf9b54607af,
+                    0xe401ca40d4fe392b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe5236530fb40f315,
+                    0x27344de76caa1829,
+                    0x5ecde64f0fbd740e,
+                    0x089d9f810ce23558,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe88944a875502d4d,
+                    0x01bc9277d3a3ecac,
+                    0x42561d54817975ed,
+                    0x5b5c0eed76f2eb44,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4dc69bb3a4cfee13,
+                    0xa65d2ee7060442c3,
+                    0x6f8304c33a0ae983,
+                    0x0e39b8c5c7569b13,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2de010032d128534,
+                    0xd1fa2e32aa9dd394,
+                    0x72d19b1b32157162,
+                    0x4dde1e4174b1ebf6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2d0949ccf2d0bbec,
+                    0x345946884e90f358,
+                    0x19a2981714a97e42,
+                    0x27cf947a02aa7b83,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a581716be8207e0,
+                    0xc32f4d17e4283639,
+                    0xa730a5bc53df0246,
+                    0xb202b744a8ae5345,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f1c6b5df5c60280,
+                    0x1ac97eec61285c3e,
+                    0xcd30bd57a557af87,
+                    0x3d9790a647dbd7a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf22184044fefe78d,
+                    0x2759978cc682ffd6,
+                    0x193b62c3869347bc,
+                    0x3670d6bec991a657,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6324c1dbfe1ae37,
+                    0xde73fdc0bc2d7afd,
+                    0x18021d6b78886ce9,
+                    0x69d4d63d8d68f308,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6f13709e10c3629,
+                    0xfab94eb3617731b3,
+                    0x5b99545ba0c9eaf9,
+                    0xe0f91ccdeede4ac3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8f8e9274f8904286,
+                    0x463eec2241a42543,
+                    0xa6d775d5aef26a7f,
+                    0xd0bcda72ff7905a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ed6a11fb8d2d52d,
+                    0xf5ef29d06da5fcb7,
+                    0x59e95e20e682dd7b,
+                    0x9b4d1f710d787d85,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x94595aa8e96005e1,
+                    0xdb41022133eea2be,
+                    0x4a77ec8dd70fc702,
+                    0x24e1bda3112ae4e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7aab0d8bc505a359,
+                    0x4e2342d4755e6934,
+                    0x223951fe8f317cff,
+                    0x97e63f62f34e6e24,
+                ])),
+                Felt::new(BigInteger256([
+                    0xda222196aafb04d4,
+                    0x83d8c1778b09516b,
+                    0x87f4382f86ab7288,
+                    0x3c9d4f162c3137cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8314edc039e4c23,
+                    0xa5f2b9ea038452c3,
+                    0x8ad938307427156f,
+                    0x86c658736b0e6e06,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2adbcc9e15cdc429,
+                    0xe9de418213b2f1d4,
+                    0x148bed5a41a68411,
+                    0x0ed03852e87fe1c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5f866d3a0c00afaa,
+                    0x5ae2b17c0c6037aa,
+                    0xaec12172d26a4472,
+                    0xa67f0118954ff453,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf07e642635ace05,
+                    0xa4eb7211785c8760,
+                    0x1ae0b1df6e11b44c,
+                    0xd085ac9eb553df30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe761d01845df3c32,
+                    0x9e45dc6f16887615,
+                    0x7329dd087385a9e2,
+                    0xf2c6c0707b52ab5d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfd573b2539a131b9,
+                    0xf61b403288ceeca6,
+                    0xf972750d5e7b2404,
+                    0x8c3b1acc2a417566,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66235595337d70ae,
+                    0xe7b124b0bb73bc81,
+                    0xd1a359e2e0e502c2,
+                    0x1a0fe17e245b9c72,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1220122fd9b7c778,
+                    0x58155f8502fa8379,
+                    0x458317eb984fab78,
+                    0x160ed5e553876a90,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1155eb2cbaea737c,
+                    0x94e13b75de4f4805,
+                    0xaf08a820d227f1f5,
+                    0xbd44acd36182b7d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd04f365c145e19c9,
+                    0x28ccd162076f54d4,
+                    0x9b5a46a59ab4d2b7,
+                    0xbee7b1d7979e653d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ead68d97ae63876,
+                    0x34d42b2e433c02cf,
+                    0xffca8afa76d0f8a4,
+                    0xae5e5d3ad9788621,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5d1efb5f2fc5caa8,
+                    0x4a0b5f55159b014e,
+                    0xb02e021329e163ff,
+                    0x5bee71f0f88f4899,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3d0eafb7104ec32b,
+                    0x1272431b1db20134,
+                    0x141ac93bd554c476,
+                    0x7f81f1e94f993778,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x31af79962e15b567,
+                    0xfc85b8dab0d72deb,
+                    0x3a46d8390dfd91d8,
+                    0xa7664b8ade726c1d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc099ec500f3595b6,
+                    0x4783066d449d1359,
+                    0x17aa8a1af4a6ead4,
+                    0x3b4fdf8250a9cae5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x11ae2c25ae4d342f,
+                    0x1f413b366f8acc3c,
+                    0xdde96de6434c80ad,
+                    0x94860e9a2f1da996,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb94bc9941b793c0d,
+                    0xc91815816983c193,
+                    0x4b5b8b0cc92b5b5a,
+                    0x7706def61b10b203,
+                ])),
+                Felt::new(BigInteger256([
+                    0x95eaa8e182516a7d,
+                    0xc348ca4dc3d45c74,
+                    0x4d1916fefcc6e122,
+                    0x5ce4bf1888e7509b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x31a7ba716c4ea534,
+                    0x7ee0354068a26b84,
+                    0x70576d5f703ba85e,
+                    0xb96e398ab2db5c49,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe46fde12585b598,
+                    0x5bbc2fb022d8d43b,
+                    0xb29d237c4c65ca48,
+                    0x4e9ee2f560d16d1b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86dabfcb90feecad,
+                    0x7a7283faf1dd077a,
+                    0xeeb180c814fe1422,
+                    0x8175216f32927840,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x90098fe28b281f2f,
+                    0x85b17a78fd6c657f,
+                    0xb02abd61dbf34ee5,
+                    0xbd25a3becf6b6188,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0809c46ed069bb06,
+                    0x71ea09f1d1a1970c,
+                    0xc71afae9c4808b93,
+                    0xbfd6bca35a83b6b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1a0ca449b5653811,
+                    0x74917a4567c54072,
+                    0x5e1fad0bb2bc1d8c,
+                    0x984902788bca54c6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7658b91eb5b48f1a,
+                    0xa83e41c25371bab0,
+                    0x430ecd208e763809,
+                    0xfe2aa5013cef80b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa4fb8ff32b2ccb75,
+                    0xb022cea1a80ebaf3,
+                    0x8cf4ea6b6e4c6827,
+                    0xcb6347e1eeb12b3a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67b9d4b3042efb7e,
+                    0x35b1908c7b299810,
+                    0x5d311779ba8434d8,
+                    0xf94cb23bfdff6db0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14955b75abc56747,
+                    0x964bef07e1169905,
+                    0x9a7b1889be7c195e,
+                    0xe2f8906711e46a4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x88493a94a64259d6,
+                    0x6be5d911b66ec968,
+                    0x1a96a2b9556684c8,
+                    0x56396c4e46ec1fd8,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger256([
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfa955f802b326c61,
+                    0xe3663c6d89324038,
+                    0xbb8ada0864fa0851,
+                    0xc28d440538dbe16c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71b95b24b96d80c8,
+                    0xcac941c2919274ff,
+                    0x531ac186034f0de8,
+                    0xbbab8e2395df79ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd20216cb3862c881,
+                    0x65d44aa0c1707dd4,
+                    0x6faf41d4e5cdc276,
+                    0x031ac08fc6e2b6f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcf97956e1db7377c,
+                    0x435d1d04a2b9e025,
+                    0x76121d650e8d3a1f,
+                    0xec764b5ff2f3f0b8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9c5f3b0c45752421,
+                    0xd4ded7b298e39446,
+                    0x79cacd561e950824,
+                    0x2a8c056d3cb4ad07,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25e522dbee574ae9,
+                    0xcfa674e3c6ae8989,
+                    0x3bc1ff5cf767ff31,
+                    0x896c9152c6417432,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbfd5c82bc3024228,
+                    0xa2e15e90077324b2,
+                    0x73d0541e9457567d,
+                    0xc8765398c7d64d82,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe3aa71b86f41313,
+                    0x64bad87deb4d17ce,
+                    0xb588a24b4ef1184b,
+                    0x16421ad4fd998ec5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x69e899cc4d28573f,
+                    0x475265620b3d27d6,
+                    0xa01ca6b7e70e5359,
+                    0xe4202262c21b87af,
+                ])),
+                Felt::new(BigInteger256([
+                    0x53fd6e5708d5567c,
+                    0xab2533feb20263fd,
+                    0x5ec411cf5a985aa6,
+                    0xacac9cbf772b57b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2176356e6cffc309,
+                    0x5b5b623cd97e65b8,
+                    0x7f7aa8eadc1287d6,
+                    0xa25ec61b60689ece,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0f32e30c4dfd099,
+                    0x189ffc0169d3e903,
+                    0xff1eba43495d1edd,
+                    0x0f510b583b1ad583,
+                ])),
+                Felt::new(BigInteger256([
+                    0x59231bac8f865087,
+                    0xc5d6b01ffd67de7f,
+                    0xafc563dfb1ac47ce,
+                    0x8acda82c26d08ba6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x60a880c1f2b98da7,
+                    0xe1e1776cae7ccdae,
+                    0x3ee5969b4fc293c1,
+                    0xc6dfd5b64fefb42d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e32d0076c99748e,
+                    0xa254253ba4e7a5ea,
+                    0x9190fcecedd2a16d,
+                    0xedc824cb177678ea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7ef77ba880243c7b,
+                    0xaae1eee7f7ed3773,
+                    0xd8dd08a4585b0b18,
+                    0xd09c4130389cb16a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xebc228a9b59fcae0,
+                    0x2616d76cebeb2759,
+                    0xdfe08f222c5d66d3,
+                    0x3bcd4d7dce5bdeca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5f638500a822b984,
+                    0x70ffd238ee304320,
+                    0x1fda4328807e4573,
+                    0x141cd15029c01860,
+                ])),
+                Felt::new(BigInteger256([
+                    0x82a5c48ba0e897de,
+                    0x744d3fc1ab9227b9,
+                    0xacaaf292ba6222a2,
+                    0x951780d85fdd8c9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4df678366cf67154,
+                    0x485d238d241fac98,
+                    0x63927d296c4d5858,
+                    0xec0b911b58bb920c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd9f3bc96459a90c1,
+                    0x05aaff0260eb86d1,
+                    0x3bbf3921372d2899,
+                    0x35cd3365fab0c9db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f8b5fe65c036604,
+                    0x230a4e105b4aed09,
+                    0x21a02067cf259735,
+                    0xeff648cbb8ba2c2f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x548139b2e557d070,
+                    0xa7cfb8ce208ae4cb,
+                    0xaf82ba98d874675d,
+                    0x312dc31d08ad35d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec7c201b44ca3829,
+                    0xa35798603cf16f9d,
+                    0xd3b7edbb3c3779f0,
+                    0x85be2bbbedab743d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe7eb3f7d34808b0e,
+                    0x939a9845c274ff82,
+                    0x365f847b4ee88826,
+                    0x2402b9ca406549b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x724e23ad4372f351,
+                    0x2dc6d0bf2eea5214,
+                    0x083eaa795a7f08f4,
+                    0x524644395d75cbae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeddd74ea7c110aaa,
+                    0x77f89d5cb0b7fbb9,
+                    0x9cf4f4ee4af6a795,
+                    0xd48e86b96bad93d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf27f4702da322916,
+                    0x93a84571122bc677,
+                    0x7300d52ddcb18999,
+                    0x6d544ca5c8337e79,
+                ])),
+                Felt::new(BigInteger256([
+                    0x575a1fbf37438956,
+                    0x63b65a72bc95e4b4,
+                    0x4d6e0abdd61f3aea,
+                    0x42b56708f07813e7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x57309e295ee56a31,
+                    0xb038cec866304501,
+                    0x3af57bb4a28bf408,
+                    0xa7dbbfe65e7b387d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabd084160217a2b8,
+                    0x10b321f25328c42e,
+                    0x9d7ffe046d1f15c3,
+                    0x6f732809400af351,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1bad1f4174a3aee9,
+                    0x2f290e36b7a15479,
+                    0xb8942cc2d5b49624,
+                    0x49b6c313464d2046,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x04ef4137da472972,
+                    0x02e95d2f9b98074e,
+                    0x187df7b60a7becbe,
+                    0xd2bfc68f6b05447d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x171f794fa886588a,
+                    0xe67493952eb7b21d,
+                    0xae862dc315faa8f9,
+                    0xa1a4df5347f2ce9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x04799dfe0b8a7a35,
+                    0x2793f5ad8893ec14,
+                    0xe7f998610e2338e9,
+                    0xb19b43d10c09c122,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f7c3e3df153aa83,
+                    0x8d76a359f04d3022,
+                    0xa173330ee626b0de,
+                    0x7cc1d2e1cfdb7790,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2ee03342cdc668d1,
+                    0x7cbb12a24db526d4,
+                    0xa7caafb34fccaf91,
+                    0x42da74ebd9bb432c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab45366eade62d31,
+                    0x68931a633b47d99d,
+                    0x8d2011a6a9474c56,
+                    0x2deae72303ee033e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xac53bb7e71f41f2c,
+                    0xc6746cb1790df289,
+                    0x0849f2cf161c0f6b,
+                    0xf31d8eb73ddf8977,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd591508c9b6a861b,
+                    0x1f82953516ebdfe7,
+                    0xe993bcb474fdb34d,
+                    0xd81afb84ec5c158b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0624f9da2890143b,
+                    0x791342ec052757da,
+                    0x5147ebe5c85802b1,
+                    0xd9051d2e209ded7b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x64d9aa59a5fe4abc,
+                    0x461a97341467b550,
+                    0x24f09024047ed79f,
+                    0xd1f55a1870b8d35a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36f5a64408c9cf75,
+                    0x09f3fbcc2f05b9c6,
+                    0x4aee5c89dc9aaf68,
+                    0x75ec9d4c7a09fe1c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa72892989cb68bbc,
+                    0x265fc8c9ca93778e,
+                    0xd73ec317b9f93419,
+                    0x421664de831717db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0bf2afd6e93667f4,
+                    0xbc0ead2ed40b9b3e,
+                    0xc5dab7c908497a70,
+                    0xfc5a22c6046fcd87,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c0dfcd7d2592a02,
+                    0xe700109d5bbd59e3,
+                    0xb861ae848021eb36,
+                    0xfaaa4cb6bb9dc642,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcc981e9cc8e0e392,
+                    0x3c40061c34a94f7e,
+                    0xea3326bef0d8befe,
+                    0xda956c93c711d0b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99976533811a553e,
+                    0xca8fe4416be2ea83,
+                    0x1884f24cc5980629,
+                    0xcda23d1d625be09f,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! The secp256k1 scalar field, of modulus
+//! `p = 115792089237316195423570985008687907852837564279074904382605163141518161494337`,
+//! which is also the base field of secq256k1.
+//!
+//! The modulus uses all 256 bits of its representation, hence elements are
+//! represented with the in-crate `FullFp256` type over 4 limbs, in Montgomery form.
+
+use crate::full_fp256::{FullFp256, FullFp256Parameters};
+use ark_ff::{biginteger::BigInteger256 as BigInteger, fields::*};
+
+/// An element of the secp256k1 scalar field.
+pub type Felt = FullFp256<FeltParameters>;
+
+/// Parameters of the secp256k1 scalar field.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FeltParameters;
+
+impl FullFp256Parameters for FeltParameters {}
+
+impl FftParameters for FeltParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 6;
+
+    /// `7^((p - 1) / 2^6)`
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x944cf2a220910e04,
+        0x815c829c780589f4,
+        0x55980b07bc222113,
+        0xc702b0d248825b36,
+    ]);
+}
+
+impl FpParameters for FeltParameters {
+    const MODULUS: BigInteger = BigInteger([
+        0xbfd25e8cd0364141,
+        0xbaaedce6af48a03b,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+    ]);
+
+    const MODULUS_BITS: u32 = 256;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 0;
+
+    const R: BigInteger = BigInteger([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ]);
+
+    const R2: BigInteger = BigInteger([
+        0x896cf21467d7d140,
+        0x741496c20e7cf878,
+        0xe697f5e45bcd07c6,
+        0x9d671cd581c69bc5,
+    ]);
+
+    const INV: u64 = 0x4b0dff665588b13f;
+
+    /// GENERATOR = 7
+    const GENERATOR: BigInteger = BigInteger([
+        0xc13f6a264e843739,
+        0xe537f5b135039e5d,
+        0x0000000000000008,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xdfe92f46681b20a0,
+        0x5d576e7357a4501d,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    const T: BigInteger = BigInteger([
+        0xeeff497a3340d905,
+        0xfaeabb739abd2280,
+        0xffffffffffffffff,
+        0x03ffffffffffffff,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x777fa4bd19a06c82,
+        0xfd755db9cd5e9140,
+        0xffffffffffffffff,
+        0x01ffffffffffffff,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::full_fp256::tests::{arithmetic_test, serialization_test};
+    use ark_algebra_test_templates::fields::{
+        fft_field_test, field_test, from_str_test, primefield_test,
+    };
+    use ark_std::test_rng;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_field() {
+        let mut rng = test_rng();
+        let a = Felt::rand(&mut rng);
+        let b = Felt::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Felt>();
+        fft_field_test::<Felt>();
+        from_str_test::<Felt>();
+    }
+
+    #[test]
+    fn test_serialization() {
+        serialization_test::<FeltParameters>();
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        arithmetic_test::<FeltParameters>();
+    }
+}
@@ -0,0 +1,363 @@
+use super::BigInteger256;
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 5;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 4] = [
+    0x99751870a691cdcd,
+    0xc88be3ebbf6d4cfc,
+    0xcccccccccccccccb,
+    0xcccccccccccccccc,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 7;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger256([
+    0x490a7f2be4b149dc,
+    0x21887e3aad4e126b,
+    0x6db6db6db6db6db6,
+    0xb6db6db6db6db6db,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    let t1 = x.square(); //         1: 2
+    let t2 = t1 * x; //             2: 3
+    let t3 = t2 * t1; //            3: 5
+    let t4 = t3 * t1; //            4: 7
+    let t5 = t4 * t1; //            5: 9
+    let t6 = t5 * t1; //            6: 11
+    let t7 = t6 * t1; //            7: 13
+    let t8 = t7 * t1; //            8: 15
+    let t9 = t8 * t1; //            9: 17
+    let t10 = t9 * t1; //          10: 19
+    let t11 = t10 * t1; //         11: 21
+    let t12 = t11 * t1; //         12: 23
+    let t13 = t12 * t1; //         13: 25
+    let t14 = t13 * t1; //         14: 27
+    let t15 = t14 * t1; //         15: 29
+    let t16 = t15 * t1; //         16: 31
+    let mut t0 = t13.square(); //  17: 50
+    t0 = t0.square(); //           18: 100
+    t0 = t0.square(); //           19: 200
+    t0 = t0.square(); //           20: 400
+    t0 = t0.square(); //           21: 800
+    t0 *= t10; //                  22: 819
+    t0 = t0.square(); //           23: 1638
+    t0 = t0.square(); //           24: 3276
+    t0 = t0.square(); //           25: 6552
+    t0 = t0.square(); //           26: 13104
+    t0 = t0.square(); //           27: 26208
+    t0 = t0.square(); //           28: 52416
+    t0 = t0.square(); //           29: 104832
+    t0 *= t13; //                  30: 104857
+    t0 = t0.square(); //           31: 209714
+    t0 = t0.square(); //           32: 419428
+    t0 = t0.square(); //           33: 838856
+    t0 = t0.square(); //           34: 1677712
+    t0 = t0.square(); //           35: 3355424
+    t0 *= t10; //                  36: 3355443
+    t0 = t0.square(); //           37: 6710886
+    t0 = t0.square(); //           38: 13421772
+    t0 = t0.square(); //           39: 26843544
+    t0 = t0.square(); //           40: 53687088
+    t0 = t0.square(); //           41: 107374176
+    t0 = t0.square(); //           42: 214748352
+    t0 = t0.square(); //           43: 429496704
+    t0 *= t13; //                  44: 429496729
+    t0 = t0.square(); //           45: 858993458
+    t0 = t0.square(); //           46: 1717986916
+    t0 = t0.square(); //           47: 3435973832
+    t0 = t0.square(); //           48: 6871947664
+    t0 = t0.square(); //           49: 13743895328
+    t0 *= t10; //                  50: 13743895347
+    t0 = t0.square(); //           51: 27487790694
+    t0 = t0.square(); //           52: 54975581388
+    t0 = t0.square(); //           53: 109951162776
+    t0 = t0.square(); //           54: 219902325552
+    t0 = t0.square(); //           55: 439804651104
+    t0 = t0.square(); //           56: 879609302208
+    t0 = t0.square(); //           57: 1759218604416
+    t0 *= t13; //                  58: 1759218604441
+    t0 = t0.square(); //           59: 3518437208882
+    t0 = t0.square(); //           60: 7036874417764
+    t0 = t0.square(); //           61: 14073748835528
+    t0 = t0.square(); //           62: 28147497671056
+    t0 = t0.square(); //           63: 56294995342112
+    t0 *= t10; //                  64: 56294995342131
+    t0 = t0.square(); //           65: 112589990684262
+    t0 = t0.square(); //           66: 225179981368524
+    t0 = t0.square(); //           67: 450359962737048
+    t0 = t0.square(); //           68: 900719925474096
+    t0 = t0.square(); //           69: 1801439850948192
+    t0 = t0.square(); //           70: 3602879701896384
+    t0 = t0.square(); //           71: 7205759403792768
+    t0 *= t13; //                  72: 7205759403792793
+    t0 = t0.square(); //           73: 14411518807585586
+    t0 = t0.square(); //           74: 28823037615171172
+    t0 = t0.square(); //           75: 57646075230342344
+    t0 = t0.square(); //           76: 115292150460684688
+    t0 = t0.square(); //           77: 230584300921369376
+    t0 *= t10; //                  78: 230584300921369395
+    t0 = t0.square(); //           79: 461168601842738790
+    t0 = t0.square(); //           80: 922337203685477580
+    t0 = t0.square(); //           81: 1844674407370955160
+    t0 = t0.square(); //           82: 3689348814741910320
+    t0 = t0.square(); //           83: 7378697629483820640
+    t0 = t0.square(); //           84: 14757395258967641280
+    t0 = t0.square(); //           85: 29514790517935282560
+    t0 *= t13; //                  86: 29514790517935282585
+    t0 = t0.square(); //           87: 59029581035870565170
+    t0 = t0.square(); //           88: 118059162071741130340
+    t0 = t0.square(); //           89: 236118324143482260680
+    t0 = t0.square(); //           90: 472236648286964521360
+    t0 = t0.square(); //           91: 944473296573929042720
+    t0 *= t10; //                  92: 944473296573929042739
+    t0 = t0.square(); //           93: 1888946593147858085478
+    t0 = t0.square(); //           94: 3777893186295716170956
+    t0 = t0.square(); //           95: 7555786372591432341912
+    t0 = t0.square(); //           96: 15111572745182864683824
+    t0 = t0.square(); //           97: 30223145490365729367648
+    t0 = t0.square(); //           98: 60446290980731458735296
+    t0 = t0.square(); //           99: 120892581961462917470592
+    t0 *= t13; //                 100: 120892581961462917470617
+    t0 = t0.square(); //          101: 241785163922925834941234
+    t0 = t0.square(); //          102: 483570327845851669882468
+    t0 = t0.square(); //          103: 967140655691703339764936
+    t0 = t0.square(); //          104: 1934281311383406679529872
+    t0 = t0.square(); //          105: 3868562622766813359059744
+    t0 *= t10; //                 106: 3868562622766813359059763
+    t0 = t0.square(); //          107: 7737125245533626718119526
+    t0 = t0.square(); //          108: 15474250491067253436239052
+    t0 = t0.square(); //          109: 30948500982134506872478104
+    t0 = t0.square(); //          110: 61897001964269013744956208
+    t0 = t0.square(); //          111: 123794003928538027489912416
+    t0 = t0.square(); //          112: 247588007857076054979824832
+    t0 = t0.square(); //          113: 495176015714152109959649664
+    t0 *= t13; //                 114: 495176015714152109959649689
+    t0 = t0.square(); //          115: 990352031428304219919299378
+    t0 = t0.square(); //          116: 1980704062856608439838598756
+    t0 = t0.square(); //          117: 3961408125713216879677197512
+    t0 = t0.square(); //          118: 7922816251426433759354395024
+    t0 = t0.square(); //          119: 15845632502852867518708790048
+    t0 *= t10; //                 120: 15845632502852867518708790067
+    t0 = t0.square(); //          121: 31691265005705735037417580134
+    t0 = t0.square(); //          122: 63382530011411470074835160268
+    t0 = t0.square(); //          123: 126765060022822940149670320536
+    t0 = t0.square(); //          124: 253530120045645880299340641072
+    t0 = t0.square(); //          125: 507060240091291760598681282144
+    t0 = t0.square(); //          126: 1014120480182583521197362564288
+    t0 = t0.square(); //          127: 2028240960365167042394725128576
+    t0 *= t13; //                 128: 2028240960365167042394725128601
+    t0 = t0.square(); //          129: 4056481920730334084789450257202
+    t0 = t0.square(); //          130: 8112963841460668169578900514404
+    t0 = t0.square(); //          131: 16225927682921336339157801028808
+    t0 = t0.square(); //          132: 32451855365842672678315602057616
+    t0 = t0.square(); //          133: 64903710731685345356631204115232
+    t0 *= t10; //                 134: 64903710731685345356631204115251
+    t0 = t0.square(); //          135: 129807421463370690713262408230502
+    t0 = t0.square(); //          136: 259614842926741381426524816461004
+    t0 = t0.square(); //          137: 519229685853482762853049632922008
+    t0 = t0.square(); //          138: 1038459371706965525706099265844016
+    t0 = t0.square(); //          139: 2076918743413931051412198531688032
+    t0 = t0.square(); //          140: 4153837486827862102824397063376064
+    t0 = t0.square(); //          141: 8307674973655724205648794126752128
+    t0 *= t13; //                 142: 8307674973655724205648794126752153
+    t0 = t0.square(); //          143: 16615349947311448411297588253504306
+    t0 = t0.square(); //          144: 33230699894622896822595176507008612
+    t0 = t0.square(); //          145: 66461399789245793645190353014017224
+    t0 = t0.square(); //          146: 132922799578491587290380706028034448
+    t0 = t0.square(); //          147: 265845599156983174580761412056068896
+    t0 *= t10; //                 148: 265845599156983174580761412056068915
+    t0 = t0.square(); //          149: 531691198313966349161522824112137830
+    t0 = t0.square(); //          150: 1063382396627932698323045648224275660
+    t0 = t0.square(); //          151: 2126764793255865396646091296448551320
+    t0 = t0.square(); //          152: 4253529586511730793292182592897102640
+    t0 = t0.square(); //          153: 8507059173023461586584365185794205280
+    t0 = t0.square(); //          154: 17014118346046923173168730371588410560
+    t0 = t0.square(); //          155: 34028236692093846346337460743176821120
+    t0 *= t13; //                 156: 34028236692093846346337460743176821145
+    t0 = t0.square(); //          157: 68056473384187692692674921486353642290
+    t0 = t0.square(); //          158: 136112946768375385385349842972707284580
+    t0 = t0.square(); //          159: 272225893536750770770699685945414569160
+    t0 = t0.square(); //          160: 544451787073501541541399371890829138320
+    t0 = t0.square(); //          161: 1088903574147003083082798743781658276640
+    t0 *= t8; //                  162: 1088903574147003083082798743781658276655
+    t0 = t0.square(); //          163: 2177807148294006166165597487563316553310
+    t0 = t0.square(); //          164: 4355614296588012332331194975126633106620
+    t0 = t0.square(); //          165: 8711228593176024664662389950253266213240
+    t0 = t0.square(); //          166: 17422457186352049329324779900506532426480
+    t0 = t0.square(); //          167: 34844914372704098658649559801013064852960
+    t0 = t0.square(); //          168: 69689828745408197317299119602026129705920
+    t0 = t0.square(); //          169: 139379657490816394634598239204052259411840
+    t0 *= t9; //                  170: 139379657490816394634598239204052259411857
+    t0 = t0.square(); //          171: 278759314981632789269196478408104518823714
+    t0 = t0.square(); //          172: 557518629963265578538392956816209037647428
+    t0 = t0.square(); //          173: 1115037259926531157076785913632418075294856
+    t0 = t0.square(); //          174: 2230074519853062314153571827264836150589712
+    t0 = t0.square(); //          175: 4460149039706124628307143654529672301179424
+    t0 = t0.square(); //          176: 8920298079412249256614287309059344602358848
+    t0 = t0.square(); //          177: 17840596158824498513228574618118689204717696
+    t0 = t0.square(); //          178: 35681192317648997026457149236237378409435392
+    t0 *= t12; //                 179: 35681192317648997026457149236237378409435415
+    t0 = t0.square(); //          180: 71362384635297994052914298472474756818870830
+    t0 = t0.square(); //          181: 142724769270595988105828596944949513637741660
+    t0 *= t2; //                  182: 142724769270595988105828596944949513637741663
+    t0 = t0.square(); //          183: 285449538541191976211657193889899027275483326
+    t0 = t0.square(); //          184: 570899077082383952423314387779798054550966652
+    t0 = t0.square(); //          185: 1141798154164767904846628775559596109101933304
+    t0 = t0.square(); //          186: 2283596308329535809693257551119192218203866608
+    t0 = t0.square(); //          187: 4567192616659071619386515102238384436407733216
+    t0 = t0.square(); //          188: 9134385233318143238773030204476768872815466432
+    t0 = t0.square(); //          189: 18268770466636286477546060408953537745630932864
+    t0 = t0.square(); //          190: 36537540933272572955092120817907075491261865728
+    t0 *= t16; //                 191: 36537540933272572955092120817907075491261865759
+    t0 = t0.square(); //          192: 73075081866545145910184241635814150982523731518
+    t0 = t0.square(); //          193: 146150163733090291820368483271628301965047463036
+    t0 = t0.square(); //          194: 292300327466180583640736966543256603930094926072
+    t0 = t0.square(); //          195: 584600654932361167281473933086513207860189852144
+    t0 = t0.square(); //          196: 1169201309864722334562947866173026415720379704288
+    t0 = t0.square(); //          197: 2338402619729444669125895732346052831440759408576
+    t0 *= t12; //                 198: 2338402619729444669125895732346052831440759408599
+    t0 = t0.square(); //          199: 4676805239458889338251791464692105662881518817198
+    t0 = t0.square(); //          200: 9353610478917778676503582929384211325763037634396
+    t0 = t0.square(); //          201: 18707220957835557353007165858768422651526075268792
+    t0 = t0.square(); //          202: 37414441915671114706014331717536845303052150537584
+    t0 = t0.square(); //          203: 74828883831342229412028663435073690606104301075168
+    t0 = t0.square(); //          204: 149657767662684458824057326870147381212208602150336
+    t0 *= t16; //                 205: 149657767662684458824057326870147381212208602150367
+    t0 = t0.square(); //          206: 299315535325368917648114653740294762424417204300734
+    t0 = t0.square(); //          207: 598631070650737835296229307480589524848834408601468
+    t0 = t0.square(); //          208: 1197262141301475670592458614961179049697668817202936
+    t0 = t0.square(); //          209: 2394524282602951341184917229922358099395337634405872
+    t0 *= t6; //                  210: 2394524282602951341184917229922358099395337634405883
+    t0 = t0.square(); //          211: 4789048565205902682369834459844716198790675268811766
+    t0 = t0.square(); //          212: 9578097130411805364739668919689432397581350537623532
+    t0 = t0.square(); //          213: 19156194260823610729479337839378864795162701075247064
+    t0 = t0.square(); //          214: 38312388521647221458958675678757729590325402150494128
+    t0 = t0.square(); //          215: 76624777043294442917917351357515459180650804300988256
+    t0 *= t7; //                  216: 76624777043294442917917351357515459180650804300988269
+    t0 = t0.square(); //          217: 153249554086588885835834702715030918361301608601976538
+    t0 = t0.square(); //          218: 306499108173177771671669405430061836722603217203953076
+    t0 = t0.square(); //          219: 612998216346355543343338810860123673445206434407906152
+    t0 = t0.square(); //          220: 1225996432692711086686677621720247346890412868815812304
+    t0 = t0.square(); //          221: 2451992865385422173373355243440494693780825737631624608
+    t0 = t0.square(); //          222: 4903985730770844346746710486880989387561651475263249216
+    t0 *= t10; //                 223: 4903985730770844346746710486880989387561651475263249235
+    t0 = t0.square(); //          224: 9807971461541688693493420973761978775123302950526498470
+    t0 = t0.square(); //          225: 19615942923083377386986841947523957550246605901052996940
+    t0 = t0.square(); //          226: 39231885846166754773973683895047915100493211802105993880
+    t0 = t0.square(); //          227: 78463771692333509547947367790095830200986423604211987760
+    t0 = t0.square(); //          228: 156927543384667019095894735580191660401972847208423975520
+    t0 = t0.square(); //          229: 313855086769334038191789471160383320803945694416847951040
+    t0 = t0.square(); //          230: 627710173538668076383578942320766641607891388833695902080
+    t0 *= t16; //                 231: 627710173538668076383578942320766641607891388833695902111
+    t0 = t0.square(); //          232: 1255420347077336152767157884641533283215782777667391804222
+    t0 = t0.square(); //          233: 2510840694154672305534315769283066566431565555334783608444
+    t0 = t0.square(); //          234: 5021681388309344611068631538566133132863131110669567216888
+    t0 = t0.square(); //          235: 10043362776618689222137263077132266265726262221339134433776
+    t0 *= t5; //                  236: 10043362776618689222137263077132266265726262221339134433785
+    t0 = t0.square(); //          237: 20086725553237378444274526154264532531452524442678268867570
+    t0 = t0.square(); //          238: 40173451106474756888549052308529065062905048885356537735140
+    t0 = t0.square(); //          239: 80346902212949513777098104617058130125810097770713075470280
+    t0 = t0.square(); //          240: 160693804425899027554196209234116260251620195541426150940560
+    t0 = t0.square(); //          241: 321387608851798055108392418468232520503240391082852301881120
+    t0 = t0.square(); //          242: 642775217703596110216784836936465041006480782165704603762240
+    t0 = t0.square(); //          243: 1285550435407192220433569673872930082012961564331409207524480
+    t0 *= t13; //                 244: 1285550435407192220433569673872930082012961564331409207524505
+    t0 = t0.square(); //          245: 2571100870814384440867139347745860164025923128662818415049010
+    t0 = t0.square(); //          246: 5142201741628768881734278695491720328051846257325636830098020
+    t0 = t0.square(); //          247: 10284403483257537763468557390983440656103692514651273660196040
+    t0 = t0.square(); //          248: 20568806966515075526937114781966881312207385029302547320392080
+    t0 = t0.square(); //          249: 41137613933030151053874229563933762624414770058605094640784160
+    t0 = t0.square(); //          250: 82275227866060302107748459127867525248829540117210189281568320
+    t0 *= t15; //                 251: 82275227866060302107748459127867525248829540117210189281568349
+    t0 = t0.square(); //          252: 164550455732120604215496918255735050497659080234420378563136698
+    t0 = t0.square(); //          253: 329100911464241208430993836511470100995318160468840757126273396
+    t0 = t0.square(); //          254: 658201822928482416861987673022940201990636320937681514252546792
+    t0 = t0.square(); //          255: 1316403645856964833723975346045880403981272641875363028505093584
+    t0 = t0.square(); //          256: 2632807291713929667447950692091760807962545283750726057010187168
+    t0 = t0.square(); //          257: 5265614583427859334895901384183521615925090567501452114020374336
+    t0 *= t9; //                  258: 5265614583427859334895901384183521615925090567501452114020374353
+    t0 = t0.square(); //          259: 10531229166855718669791802768367043231850181135002904228040748706
+    t0 *= x; //                   260: 10531229166855718669791802768367043231850181135002904228040748707
+    t0 = t0.square(); //          261: 21062458333711437339583605536734086463700362270005808456081497414
+    t0 = t0.square(); //          262: 42124916667422874679167211073468172927400724540011616912162994828
+    t0 = t0.square(); //          263: 84249833334845749358334422146936345854801449080023233824325989656
+    t0 = t0.square(); //          264: 168499666669691498716668844293872691709602898160046467648651979312
+    t0 = t0.square(); //          265: 336999333339382997433337688587745383419205796320092935297303958624
+    t0 = t0.square(); //          266: 673998666678765994866675377175490766838411592640185870594607917248
+    t0 = t0.square(); //          267: 1347997333357531989733350754350981533676823185280371741189215834496
+    t0 *= t4; //                  268: 1347997333357531989733350754350981533676823185280371741189215834503
+    t0 = t0.square(); //          269: 2695994666715063979466701508701963067353646370560743482378431669006
+    t0 = t0.square(); //          270: 5391989333430127958933403017403926134707292741121486964756863338012
+    t0 = t0.square(); //          271: 10783978666860255917866806034807852269414585482242973929513726676024
+    t0 = t0.square(); //          272: 21567957333720511835733612069615704538829170964485947859027453352048
+    t0 = t0.square(); //          273: 43135914667441023671467224139231409077658341928971895718054906704096
+    t0 = t0.square(); //          274: 86271829334882047342934448278462818155316683857943791436109813408192
+    t0 = t0.square(); //          275: 172543658669764094685868896556925636310633367715887582872219626816384
+    t0 *= t3; //                  276: 172543658669764094685868896556925636310633367715887582872219626816389
+    t0 = t0.square(); //          277: 345087317339528189371737793113851272621266735431775165744439253632778
+    t0 = t0.square(); //          278: 690174634679056378743475586227702545242533470863550331488878507265556
+    t0 = t0.square(); //          279: 1380349269358112757486951172455405090485066941727100662977757014531112
+    t0 = t0.square(); //          280: 2760698538716225514973902344910810180970133883454201325955514029062224
+    t0 = t0.square(); //          281: 5521397077432451029947804689821620361940267766908402651911028058124448
+    t0 = t0.square(); //          282: 11042794154864902059895609379643240723880535533816805303822056116248896
+    t0 *= t7; //                  283: 11042794154864902059895609379643240723880535533816805303822056116248909
+    t0 = t0.square(); //          284: 22085588309729804119791218759286481447761071067633610607644112232497818
+    t0 = t0.square(); //          285: 44171176619459608239582437518572962895522142135267221215288224464995636
+    t0 = t0.square(); //          286: 88342353238919216479164875037145925791044284270534442430576448929991272
+    t0 = t0.square(); //          287: 176684706477838432958329750074291851582088568541068884861152897859982544
+    t0 = t0.square(); //          288: 353369412955676865916659500148583703164177137082137769722305795719965088
+    t0 = t0.square(); //          289: 706738825911353731833319000297167406328354274164275539444611591439930176
+    t0 = t0.square(); //          290: 1413477651822707463666638000594334812656708548328551078889223182879860352
+    t0 *= t9; //                  291: 1413477651822707463666638000594334812656708548328551078889223182879860369
+    t0 = t0.square(); //          292: 2826955303645414927333276001188669625313417096657102157778446365759720738
+    t0 = t0.square(); //          293: 5653910607290829854666552002377339250626834193314204315556892731519441476
+    t0 = t0.square(); //          294: 11307821214581659709333104004754678501253668386628408631113785463038882952
+    t0 = t0.square(); //          295: 22615642429163319418666208009509357002507336773256817262227570926077765904
+    t0 = t0.square(); //          296: 45231284858326638837332416019018714005014673546513634524455141852155531808
+    t0 *= t13; //                 297: 45231284858326638837332416019018714005014673546513634524455141852155531833
+    t0 = t0.square(); //          298: 90462569716653277674664832038037428010029347093027269048910283704311063666
+    t0 = t0.square(); //          299: 180925139433306555349329664076074856020058694186054538097820567408622127332
+    t0 = t0.square(); //          300: 361850278866613110698659328152149712040117388372109076195641134817244254664
+    t0 = t0.square(); //          301: 723700557733226221397318656304299424080234776744218152391282269634488509328
+    t0 = t0.square(); //          302: 1447401115466452442794637312608598848160469553488436304782564539268977018656
+    t0 *= t12; //                 303: 1447401115466452442794637312608598848160469553488436304782564539268977018679
+    t0 = t0.square(); //          304: 2894802230932904885589274625217197696320939106976872609565129078537954037358
+    t0 = t0.square(); //          305: 5789604461865809771178549250434395392641878213953745219130258157075908074716
+    t0 = t0.square(); //          306: 11579208923731619542357098500868790785283756427907490438260516314151816149432
+    t0 = t0.square(); //          307: 23158417847463239084714197001737581570567512855814980876521032628303632298864
+    t0 = t0.square(); //          308: 46316835694926478169428394003475163141135025711629961753042065256607264597728
+    t0 = t0.square(); //          309: 92633671389852956338856788006950326282270051423259923506084130513214529195456
+    t0 * t7 //                    310: 92633671389852956338856788006950326282270051423259923506084130513214529195469
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
@@ -0,0 +1,1870 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger256([
+            0xccd5d0af127da595,
+            0xbc10b7484b217f45,
+            0x560163fc8ae12288,
+            0x0526e8964468d823,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0xef07bf4ad821e5a5,
+                0xcc35763406c8a588,
+                0x6a674a707e12e98e,
+                0x07cd48580b3ba10d,
+            ])),
+            Felt::new(BigInteger256([
+                0x12db9bdcd19fa7cb,
+                0xc09f6d7838c52f79,
+                0x6bb4bf904056eda6,
+                0x01bfb8a8784fd941,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xaaf327ee3cd95ded,
+                0x3fe6f6931a0e8ed4,
+                0xe9d8e57c2ae10f14,
+                0x00ac07e6a9766601,
+            ])),
+            Felt::new(BigInteger256([
+                0xcefe3d424b0d439c,
+                0x51fffd280d35b086,
+                0xbc8799cbbe62d503,
+                0x00b52fe1dccb12c1,
+            ])),
+            Felt::new(BigInteger256([
+                0x5b1041ce6dd6c7d3,
+                0x873690b8460ed2dc,
+                0x9e30af276684f59c,
+                0x011ca45e8e061ff4,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xac368eb29da9f643,
+                0x0b0d1b492e206f35,
+                0x1504be63bed52157,
+                0x047ab0a08d806ec9,
+            ])),
+            Felt::new(BigInteger256([
+                0x92582010432d3e48,
+                0x25c0add83d16b217,
+                0xbf15393261c31e9d,
+                0x0285e1e57b17d788,
+            ])),
+            Felt::new(BigInteger256([
+                0xa83b065d0ac4a02c,
+                0xc8b0c8d815f3d90d,
+                0x09b1f7ff89aa107a,
+                0x051790b05accfc1e,
+            ])),
+            Felt::new(BigInteger256([
+                0x6e45fcea282c69b0,
+                0x2616f0f1ac4b502c,
+                0xe823c9ee7fa7648d,
+                0x016e9806de540aa0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x1a6b4f6e51362753,
+                0x490e1e4f181d64e5,
+                0x10cb382385795492,
+                0x03537635d7d6e689,
+            ])),
+            Felt::new(BigInteger256([
+                0x787718cf8f13ec7e,
+                0x8f18891154c31371,
+                0xab5b593bb104990c,
+                0x01c4c14ef2b42484,
+            ])),
+            Felt::new(BigInteger256([
+                0x5ef261844db1a2cd,
+                0x3fdd665542080748,
+                0xa0740f821948fa45,
+                0x00e517fd00f8a219,
+            ])),
+            Felt::new(BigInteger256([
+                0x7dc84997dc8d5fd9,
+                0xbe580f75f4159e08,
+                0x76f39550d4eeb899,
+                0x00a82bfa9c9b2810,
+            ])),
+            Felt::new(BigInteger256([
+                0x5a8e0d81ba494a6e,
+                0x26a10f49c6405e2f,
+                0x8bf43d3b87c67c86,
+                0x0144e2fe890e74a1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x6d3603d177ba3a12,
+                0xcbe4931cbc94e43b,
+                0x24b38ecee951a3fb,
+                0x0346308e5ce03f88,
+            ])),
+            Felt::new(BigInteger256([
+                0x595a0a2269562c83,
+                0x454b3d787011aa96,
+                0xd8c74c38bfba065f,
+                0x02c8dba98e305803,
+            ])),
+            Felt::new(BigInteger256([
+                0x0673256df0d9f545,
+                0xdd774b23e677f692,
+                0x4dbe90f5d4d79a97,
+                0x0099c32169be9775,
+            ])),
+            Felt::new(BigInteger256([
+                0xb811a934d845c49e,
+                0xe30132bab488983a,
+                0xa62ba9de4253bba1,
+                0x03de7100c76837a4,
+            ])),
+            Felt::new(BigInteger256([
+                0x576d3e607ac7ab78,
+                0xc703e6fb9f3aae6a,
+                0xa96552fc4de82612,
+                0x0588085d15c5a0f5,
+            ])),
+            Felt::new(BigInteger256([
+                0xedac05ec1ca13a46,
+                0xa3999f8e54ae42c2,
+                0x3e9013edb9775f50,
+                0x06fb030706782d35,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xaa9032e3fdf9693e,
+            0x8466f7f976af5690,
+            0xb4714e167c3f5d77,
+            0x03d3a947e3bc767f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xee16df86920e27a9,
+            0xbc1b10655de19bd1,
+            0x7ad57d9242e5585d,
+            0x019e3634b0fe8c92,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x44513bb4b8bdc113,
+            0xf37a694d79bc75a6,
+            0x59cbe5982297dcef,
+            0x0541f02eae22e410,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xecc9c710a6c05a2b,
+            0xefdd7c4b834d75c7,
+            0x251387eea13ba7bd,
+            0x0062f200a89a4c4a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x84377fdb39c35071,
+            0x3c90792a9d7f86da,
+            0xfe1e999b581cf180,
+            0x01ed47dbfb25374a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9d4212c8b096f5da,
+            0x11ce7272a1abdf5a,
+            0x5d6b67247a1e7e1e,
+            0x00aee2dfaa9feb1d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x1b75a4b253890385,
+            0xe6e77adacd54eebe,
+            0xfc168a0808e0b345,
+            0x0184f6ae6b6098e7,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb1ee0bf22148a695,
+            0x8e2cd247211b0270,
+            0xf6a3271c027ea98c,
+            0x007e5f5d44533131,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x39d1014cdf842140,
+            0x874871c88d6c1692,
+            0x2716b3b4143ce1e3,
+            0x03b82bcd1425b4e9,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xfb82057db37bfd3a,
+            0x01534bc9ae1fc676,
+            0x5cef933bd7cabe20,
+            0x01db46fca61392d0,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x68015d99506d4f22,
+                0x1eec9dfc0d7c1b03,
+                0xdb1b2be06f1bdb5d,
+                0x01f89e52d5835f87,
+            ])),
+            Felt::new(BigInteger256([
+                0x5257dab49823f065,
+                0xcba47e44640bdc29,
+                0xaed9bcc45d489bed,
+                0x061f7577b3100d54,
+            ])),
+            Felt::new(BigInteger256([
+                0xdcd533efddd909bf,
+                0x862fb211ad380a55,
+                0x9ae4d22365fd241a,
+                0x0189b63258318a19,
+            ])),
+            Felt::new(BigInteger256([
+                0x168e29954617c776,
+                0x3c36cd01b4b63bbe,
+                0x61ba0ca32460c0c7,
+                0x01cfbb80ff59bf36,
+            ])),
+            Felt::new(BigInteger256([
+                0xb3138547fc1f437f,
+                0x91af04b42f659f4a,
+                0x20e34b79e83953f9,
+                0x0105b751d7336d83,
+            ])),
+            Felt::new(BigInteger256([
+                0x0bcd6e08fdd8ecce,
+                0xf51820d2857fdbc8,
+                0xbb9c4fdf796cdc5b,
+                0x0562c61df6666702,
+            ])),
+            Felt::new(BigInteger256([
+                0xaec75c7de2b51f58,
+                0xd7b19cd4b6aaa27b,
+                0x6e41a3b115e027d4,
+                0x05f8c2883a32555c,
+            ])),
+            Felt::new(BigInteger256([
+                0x4fd294c4c59f37c2,
+                0x69842263108806be,
+                0xb87e63ab41cf8062,
+                0x0132cad26a642749,
+            ])),
+            Felt::new(BigInteger256([
+                0x7412ed04a6d22244,
+                0x2ce2cbb0fa203c3b,
+                0x8b138677603f0187,
+                0x01d9eca481bf689e,
+            ])),
+            Felt::new(BigInteger256([
+                0x4ef947eebd44f3d3,
+                0xcd6be751f9ef6964,
+                0x982f1d2927446e57,
+                0x04d186dc77c32cbb,
+            ])),
+            Felt::new(BigInteger256([
+                0x5ab6054911e029f7,
+                0xebf3459d8d87c73b,
+                0xb417dacd33d9b7f7,
+                0x07d3439ac3cdd055,
+            ])),
+            Felt::new(BigInteger256([
+                0x52b62aa38a58b346,
+                0x8978d9501f471176,
+                0x4f49964f5ebcbacd,
+                0x0455569f467da80e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xee13ef00e80d9d98,
+                0xb833b985e81d41d1,
+                0xeb9890d442518853,
+                0x07091f651decaa01,
+            ])),
+            Felt::new(BigInteger256([
+                0x3318bd1fc38df337,
+                0x2e6f5b876fb0d34b,
+                0xd1f4aed3d801ff3f,
+                0x04f5f3aa0bde2f91,
+            ])),
+            Felt::new(BigInteger256([
+                0xf8c4729bc50b503c,
+                0xb013a2beb31567a2,
+                0x99ecc6ca86cdbe59,
+                0x0116a1690f7ef273,
+            ])),
+            Felt::new(BigInteger256([
+                0x34cd006c439b044e,
+                0xbd7ad1f57bbf460f,
+                0xdab964184800e7a6,
+                0x04ca5c2b73841d60,
+            ])),
+            Felt::new(BigInteger256([
+                0xe256119e1045dae9,
+                0x8ddb5045849fe5a0,
+                0x000e9b6998ddcb2a,
+                0x066890fa7ad0974f,
+            ])),
+            Felt::new(BigInteger256([
+                0x33219df356f83e4c,
+                0xf10a004387fd136d,
+                0x891ee9459bc09cfd,
+                0x05f8dff7e9176f4b,
+            ])),
+            Felt::new(BigInteger256([
+                0x4e912f1f5a7b2776,
+                0x8eb437008951165d,
+                0x3801ce9b3d64d936,
+                0x045fe20c7747c40f,
+            ])),
+            Felt::new(BigInteger256([
+                0xcdb0cf7e0a4418a1,
+                0x8fcb4b10e4ee4b93,
+                0x89b7bb7ee2ca9bed,
+                0x01bd41042eea4388,
+            ])),
+            Felt::new(BigInteger256([
+                0x1b9b0ed48e10181f,
+                0xe837f8f0cb20f0f8,
+                0x70e18614b0a41af5,
+                0x06e2436a3d369906,
+            ])),
+            Felt::new(BigInteger256([
+                0xca7845e5d2b2984c,
+                0x088940008958e654,
+                0x18674a0495922cf0,
+                0x06454f3a0c5cccc3,
+            ])),
+            Felt::new(BigInteger256([
+                0xa9b9af26f1b339bd,
+                0x02a7179f7788cea9,
+                0xfddb96507e80fa57,
+                0x006298a1ecd76833,
+            ])),
+            Felt::new(BigInteger256([
+                0x81d6ecdf628cf760,
+                0x5b2900abdc53ccea,
+                0xc2b77de21a994a11,
+                0x07ce8fd28a11e4c6,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x563f6332a9095cf0,
+                0x1f155c132e9a1cd8,
+                0x25b5c6fdeaecb733,
+                0x0723f200c5fefca1,
+            ])),
+            Felt::new(BigInteger256([
+                0xe1b6df8e6296ce2b,
+                0x870a1703970c3f90,
+                0x0a4ae32e282a4cae,
+                0x05e57fd0d984e1d1,
+            ])),
+            Felt::new(BigInteger256([
+                0x16796aed9cd23233,
+                0x023d5cc80ae9d2ea,
+                0xda17c1468905e7bb,
+                0x06e6c9b397e9e2b2,
+            ])),
+            Felt::new(BigInteger256([
+                0x5a673fa904fdd3f7,
+                0x98e9fd3327b7aeed,
+                0x2bb5b5f02b2af121,
+                0x068b658c1fd1c75d,
+            ])),
+            Felt::new(BigInteger256([
+                0xcae6af08a0400cfa,
+                0xfe5b9ff9d1add881,
+                0x385f769bb3613eb6,
+                0x009580380a8e83b3,
+            ])),
+            Felt::new(BigInteger256([
+                0xe845b170dbd8a0c9,
+                0xd3211278f92a2427,
+                0xadc1fabb9eb4c444,
+                0x017f366dfb63fd6b,
+            ])),
+            Felt::new(BigInteger256([
+                0x00797c3b84d535fa,
+                0xef1b83b5a7b37e7a,
+                0x9e023dfa1f1cd8d1,
+                0x0038ebc7cf9fde73,
+            ])),
+            Felt::new(BigInteger256([
+                0xcac492139f663a69,
+                0xf804d06d04a9e04e,
+                0x9630215957aef7ae,
+                0x00b11098cee8b9bb,
+            ])),
+            Felt::new(BigInteger256([
+                0xc62f8f456c9fb226,
+                0x289ef819add78fc6,
+                0x1e1a2dec6a85a4c9,
+                0x0625f6e1856bfdc2,
+            ])),
+            Felt::new(BigInteger256([
+                0x969860e8f8d13eb3,
+                0x41c5a3b09df63280,
+                0x9d698656fbd45755,
+                0x072a2f14c66ecb0c,
+            ])),
+            Felt::new(BigInteger256([
+                0x9b3164ee122bd315,
+                0x775346191d948169,
+                0x9e05fe4d70f1e363,
+                0x0690bd4098ec5f19,
+            ])),
+            Felt::new(BigInteger256([
+                0xa4f6f5f5138db983,
+                0x5e9bc429ed2898ec,
+                0x0f928510ee8cf7d2,
+                0x028af75f60e468fa,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x21a441d7c6b1b9b9,
+                0x394ecc1a87c1bd44,
+                0x5a5983ff341edc8f,
+                0x03ed2bc770148b77,
+            ])),
+            Felt::new(BigInteger256([
+                0x430b708d8dd224c4,
+                0x5224577e4fa7378d,
+                0x08c41f9cae9e375e,
+                0x03881885742f29ad,
+            ])),
+            Felt::new(BigInteger256([
+                0x597ffd5df6aa5418,
+                0xa09de159382fd0db,
+                0xc42f005bc198368b,
+                0x07f0f265008bb46c,
+            ])),
+            Felt::new(BigInteger256([
+                0x87706f965c98fbb4,
+                0x99c0209119c519e6,
+                0x6c54d5ba7f80c9d4,
+                0x01596ca6fa938fb4,
+            ])),
+            Felt::new(BigInteger256([
+                0x1243c18eedbe39fb,
+                0x2f7098ebe3173c68,
+                0xc9548441bd6dd3bb,
+                0x01952196833f348f,
+            ])),
+            Felt::new(BigInteger256([
+                0xea4765e22a59dd70,
+                0xecee7c1c86ab64ac,
+                0x4da83e044f562622,
+                0x014e3e97979e448d,
+            ])),
+            Felt::new(BigInteger256([
+                0x67b63c142ee79ec4,
+                0xc0ad03bbad349bc6,
+                0x0bf92b9170bedee8,
+                0x015f1200b69b884e,
+            ])),
+            Felt::new(BigInteger256([
+                0x971d4861a629b52c,
+                0xbbc7c3b47bec1458,
+                0xd26aa462f701d87e,
+                0x0479550b9f01b27d,
+            ])),
+            Felt::new(BigInteger256([
+                0xdf2fd64155731eb6,
+                0x13a09e573dc13689,
+                0x1374aca00f7c7880,
+                0x06edc059dc449d30,
+            ])),
+            Felt::new(BigInteger256([
+                0x152cef6307d5f032,
+                0x5f03613902d85b3d,
+                0xf4872ff94b3936fb,
+                0x0255f542f3b4c1ef,
+            ])),
+            Felt::new(BigInteger256([
+                0xfb2eca155db65c94,
+                0x0d5dd8bf5ef029c8,
+                0xcde02a0a96f09070,
+                0x052ae43e29b60655,
+            ])),
+            Felt::new(BigInteger256([
+                0x41cc0a4f157e490a,
+                0xe1c9f4020bb5f52a,
+                0x77a0daa784b54fb2,
+                0x06696f3b3facddc0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc9d98b1bf0cade22,
+                0x66bcc5ea7f56390c,
+                0xf0dbfe282663c572,
+                0x01195139c7814a48,
+            ])),
+            Felt::new(BigInteger256([
+                0x8686c806ccbfdd0b,
+                0x71c2e328bc8ad87f,
+                0xa3ba76730573c729,
+                0x00ecb55c8ffc7c45,
+            ])),
+            Felt::new(BigInteger256([
+                0x9f4a8555df77558c,
+                0xdc1b664427057bd1,
+                0xbf0cc7c2855dc2ce,
+                0x064735b5b2c1880b,
+            ])),
+            Felt::new(BigInteger256([
+                0x5299f64710992fd6,
+                0x25c433678afe5760,
+                0xfd4cf4a9cbdbd761,
+                0x01cf9fdbd450743f,
+            ])),
+            Felt::new(BigInteger256([
+                0xd2f4a8ea37397a7b,
+                0xe7426a36de7cae49,
+                0xea42aa52d8ea6ea9,
+                0x05371ddcdfb222e3,
+            ])),
+            Felt::new(BigInteger256([
+                0x9fa505edbeaf5730,
+                0xa85af9025b97f85c,
+                0xff1410b77c535b1c,
+                0x058b1d5c1c539d9f,
+            ])),
+            Felt::new(BigInteger256([
+                0x93d14159232438b1,
+                0xe8313c82b95a3c13,
+                0xceb72c91829d220f,
+                0x0089437fac326bdd,
+            ])),
+            Felt::new(BigInteger256([
+                0x5b22c2e791c17d8a,
+                0x68057da69144d5a6,
+                0x8d0059dbda1eef46,
+                0x051c0bb57b82fb26,
+            ])),
+            Felt::new(BigInteger256([
+                0x912477df4d871d3a,
+                0x61b1d10872bff653,
+                0x485bf9a8c2a7ee90,
+                0x057f4d19e681d7cc,
+            ])),
+            Felt::new(BigInteger256([
+                0xfb92d598b33c76ce,
+                0x1dbcab4f131ea8d3,
+                0xfa05bfaa89690e7d,
+                0x0351aec8ae20247f,
+            ])),
+            Felt::new(BigInteger256([
+                0x28de1772dffb4e7b,
+                0xc673f6e60b9d87b6,
+                0x9ac82550a132272e,
+                0x04f6d54609a1ce6f,
+            ])),
+            Felt::new(BigInteger256([
+                0x5352c74f0beeed3b,
+                0x8d27aca96ffed0ce,
+                0x0ee4739b30de3958,
+                0x0767bdb21e82d5f1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf5c66f79ac210458,
+                0xbdb8bc4b8b265da1,
+                0x84925697c011e064,
+                0x0413ba2603e666cd,
+            ])),
+            Felt::new(BigInteger256([
+                0xd29eb2ffb94133fb,
+                0x5144b7767cb2d110,
+                0xc75f368418fae905,
+                0x00e0169ad3461fdb,
+            ])),
+            Felt::new(BigInteger256([
+                0xf9dc10bbae75827f,
+                0x4d36bd916c85816e,
+                0x75feb05ec6d00ba4,
+                0x069a7bf9434e2b70,
+            ])),
+            Felt::new(BigInteger256([
+                0x623d66e5ace4194a,
+                0x19e4dc1a4d7dc4aa,
+                0xc283019083793dd9,
+                0x05a76b40df4f0306,
+            ])),
+            Felt::new(BigInteger256([
+                0xbb36214b057612f7,
+                0xeabac6df07f49772,
+                0xd1bd6e14388c43f2,
+                0x02eadd79bcf5efeb,
+            ])),
+            Felt::new(BigInteger256([
+                0xf1880f21837b6e72,
+                0xfe5fb4f672d7f3e6,
+                0xefd8f83f8d1c8319,
+                0x075ca510614bd026,
+            ])),
+            Felt::new(BigInteger256([
+                0xabac5e453512f432,
+                0x4a578e94771d3637,
+                0x4673bb469a6306d6,
+                0x069d46c8193d07af,
+            ])),
+            Felt::new(BigInteger256([
+                0xa35be0760d71ee59,
+                0xf9105e64b29187dd,
+                0xc6107e175f4df4cb,
+                0x04b3836e310a43b1,
+            ])),
+            Felt::new(BigInteger256([
+                0x232b2ee5afc65f8e,
+                0xb96f4e2af3550c36,
+                0xeb9150dbddaed43d,
+                0x0599e3343064fa3b,
+            ])),
+            Felt::new(BigInteger256([
+                0xceb26075ad936190,
+                0x285414e871bc6cec,
+                0x386c3438a8577adb,
+                0x0230b38cf71fd12d,
+            ])),
+            Felt::new(BigInteger256([
+                0xd680fc57c1ccec2e,
+                0xdaf597f7e949e873,
+                0x2a5984b74cabe48e,
+                0x0004f127ab61a729,
+            ])),
+            Felt::new(BigInteger256([
+                0xcc3a5d7621b4072a,
+                0xf733ce969d99da70,
+                0x19d7ce7391b87037,
+                0x073ae0912fa2f591,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x850ab85bef52a313,
+                0x0fa1b352cf25c866,
+                0xb09936a97784f392,
+                0x0371ab9aeaf8feff,
+            ])),
+            Felt::new(BigInteger256([
+                0x5d843927e029e04a,
+                0x1701e9e83602777f,
+                0x3f58fddd9ce45abc,
+                0x03b4ebdd50a00e64,
+            ])),
+            Felt::new(BigInteger256([
+                0x1caa2d670ecf8b7c,
+                0xed685f904b38dcfa,
+                0x8a5ae26d8ae101c1,
+                0x01bec38107d13ef7,
+            ])),
+            Felt::new(BigInteger256([
+                0x42f8fcec4537f4cb,
+                0xfecb163486054d19,
+                0xe31bb82abfa11f13,
+                0x03663a797a06e8e4,
+            ])),
+            Felt::new(BigInteger256([
+                0x4f16750f41b6b615,
+                0xa8bfb742387b7808,
+                0x0b30fb0e30dd3867,
+                0x042ec3ef8e88c3bb,
+            ])),
+            Felt::new(BigInteger256([
+                0xb483054ff4b7f9b8,
+                0x3b5c378aa2ccd959,
+                0xac809eae3f0b6b1d,
+                0x04860b9540ad4535,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x9fa14b3059614fdc,
+                0xa7cefcd92abc7ea0,
+                0x7828aa1a8bb93d82,
+                0x000108c7a0fc2ad2,
+            ])),
+            Felt::new(BigInteger256([
+                0x2f93694733cdb675,
+                0xb6bf0f09ae575ae9,
+                0xec7ccfda76c78e21,
+                0x03034526d4195632,
+            ])),
+            Felt::new(BigInteger256([
+                0x9d7039b9a8ae24c8,
+                0x7b691aa61c9accbb,
+                0x20041deb7c71b97d,
+                0x05eeb23820fc89ef,
+            ])),
+            Felt::new(BigInteger256([
+                0x4ea916cf377b4423,
+                0xc2fef8cfa9bbd7d3,
+                0xa86c4794b40a889f,
+                0x015f20283c8ac839,
+            ])),
+            Felt::new(BigInteger256([
+                0xd8cf15c02da4d730,
+                0x8ef46c1a12124230,
+                0x36c7e6abd0ea6857,
+                0x01413407633686a1,
+            ])),
+            Felt::new(BigInteger256([
+                0x9d666ffeb835a325,
+                0x42da83e55f8f6d41,
+                0x5ce307b68f27abb3,
+                0x0222f9f3e0756e66,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xdfc620c80c5026ae,
+                0x2bcc2a7f22c4435a,
+                0x98a95363d1bfa2e2,
+                0x04c180c948060fe7,
+            ])),
+            Felt::new(BigInteger256([
+                0xcc2b03acc6dfa41c,
+                0x3a210129c3514276,
+                0x8dcad0ffb70e4e3b,
+                0x07dbee49e36ae6e1,
+            ])),
+            Felt::new(BigInteger256([
+                0x5a1801aed87e9f2a,
+                0x862a225efebbe328,
+                0xd7db7532d49c6e53,
+                0x01b1dc5f4f87cd01,
+            ])),
+            Felt::new(BigInteger256([
+                0x9b95956eb8d6273c,
+                0x9aa529b78a7f3150,
+                0xb30f232f4cca42cc,
+                0x039a3e3e926507a2,
+            ])),
+            Felt::new(BigInteger256([
+                0xb7f2f6cfb3d18ddc,
+                0x88ee878fddb4416c,
+                0x8c19f20f6f98e790,
+                0x07f6de3f04df3a48,
+            ])),
+            Felt::new(BigInteger256([
+                0xff696235179d466c,
+                0x616acb5881d73970,
+                0x6db17f899cd25760,
+                0x074e3496db24dad7,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x2bde71abeb65ba7d,
+                0x04e6caad150adb0f,
+                0x5732d6fd7823de42,
+                0x02f172cfd9bd86b7,
+            ])),
+            Felt::new(BigInteger256([
+                0x7ce79c478579fa44,
+                0xc03fb3bb0df6dc0e,
+                0x83b2f6e6f1f3f4c7,
+                0x07f68d68ff7c24d0,
+            ])),
+            Felt::new(BigInteger256([
+                0xb0e73c949004742b,
+                0x57fbc62edc0b0f9b,
+                0x5981cbac98b4fc06,
+                0x04e6a8e8e82be744,
+            ])),
+            Felt::new(BigInteger256([
+                0x7e4f2700fcab1dce,
+                0xb727c97004e7114d,
+                0xa896122ca8c60112,
+                0x05ba92840724aefc,
+            ])),
+            Felt::new(BigInteger256([
+                0xc22aaa4c135aff35,
+                0x6946fc7ee9eeb592,
+                0xcb0402f2fe6561b5,
+                0x043af24fdddd4a48,
+            ])),
+            Felt::new(BigInteger256([
+                0x055a06130eeaf0e4,
+                0xb5505b0f4735c2f4,
+                0x2db66deca9344e86,
+                0x029dd5710f6240c4,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x9b8ffaafdcf6a2a9,
+                0x375ab981c1ec4a79,
+                0x947321d5bb846db1,
+                0x07961526e1004da1,
+            ])),
+            Felt::new(BigInteger256([
+                0x9c64d4d525f7a44c,
+                0x463e4d2b531c464f,
+                0xf61f80b0945886df,
+                0x01d3163f6abd5dd7,
+            ])),
+            Felt::new(BigInteger256([
+                0x5f2137695ed860c9,
+                0xe24ac328891f247a,
+                0xa9fe4540ab08dad9,
+                0x026996b54636a9dc,
+            ])),
+            Felt::new(BigInteger256([
+                0x811786356cf142fb,
+                0x8a9072edba941eea,
+                0x7618871d0f9b95a1,
+                0x05f50393a737be57,
+            ])),
+            Felt::new(BigInteger256([
+                0x755ee60a8a4aea1c,
+                0x71ba2b0e35ec2193,
+                0xe064c45d102d22b6,
+                0x00930ca83715d7b2,
+            ])),
+            Felt::new(BigInteger256([
+                0xc5a443ab9b3e14a2,
+                0xa2d4406ca801b06f,
+                0x41f8fe4872ede07a,
+                0x05201e5c2bf348f6,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x2cef80c9d920c9d0,
+                0x3a9d4ed84d3c31c3,
+                0xf5a24d9db02056e0,
+                0x00b4d17c97c89578,
+            ])),
+            Felt::new(BigInteger256([
+                0x213c14ab99941134,
+                0xfb0e10fd20381c14,
+                0xd402ef7a6a43714d,
+                0x05a5b9ee4084a837,
+            ])),
+            Felt::new(BigInteger256([
+                0x834a99958daf44fd,
+                0x1c518eb4c0433357,
+                0x601dbf1bcf6e425d,
+                0x07a97c52e568e863,
+            ])),
+            Felt::new(BigInteger256([
+                0x0e53279240bc62c5,
+                0xeaa90cbb42885bc0,
+                0xfd94b62463ae1d56,
+                0x07e7b0938b2a3a67,
+            ])),
+            Felt::new(BigInteger256([
+                0x73be3e73f41a7919,
+                0x38c5f7086c6a5fdd,
+                0x8f07b69d6349b074,
+                0x0770e5ed245e80eb,
+            ])),
+            Felt::new(BigInteger256([
+                0xfb77a8e8c0a128f0,
+                0xf5ce229a7a765208,
+                0x7b6220aca303fd91,
+                0x02cc03362278c670,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x018794a0450df6ab,
+                0x1d57c3e1edd94cb4,
+                0xebf51b4ec314350f,
+                0x06a4773908f00fb1,
+            ])),
+            Felt::new(BigInteger256([
+                0x4a39147102027c3a,
+                0x828e78c207620d9b,
+                0x4b15e9d87008317b,
+                0x07fa4a0ddc4f11a0,
+            ])),
+            Felt::new(BigInteger256([
+                0x6b95ec7c74bdfc47,
+                0x2678ed9e7fbed09d,
+                0x4ef05745a0cfee2c,
+                0x075e149d7f37858c,
+            ])),
+            Felt::new(BigInteger256([
+                0x718e6fcb3b5bec99,
+                0x32f7ae32426f15d1,
+                0x97300c6c33fa4e1b,
+                0x07180d6abdb9abec,
+            ])),
+            Felt::new(BigInteger256([
+                0x567122b3d6b996c4,
+                0x3baf724d12039341,
+                0x8c6499f269696096,
+                0x018dab94a84e11e2,
+            ])),
+            Felt::new(BigInteger256([
+                0xe692c9a517292696,
+                0x2954778dd110eb11,
+                0xfc9a019da8b2bb89,
+                0x03d22a17a8743c51,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xbb3371844558819c,
+                0x1954c8d60855379b,
+                0xd21edae9540e8b21,
+                0x05c2fb1ccd3a444d,
+            ])),
+            Felt::new(BigInteger256([
+                0x780ff69dc0b6d22b,
+                0x2a253009389d93e4,
+                0xe414952800b84afe,
+                0x039f847aad122a79,
+            ])),
+            Felt::new(BigInteger256([
+                0x4df478f195def37f,
+                0xefc17de1e92eead3,
+                0x5382238d6d4965cf,
+                0x0496489a08e938fc,
+            ])),
+            Felt::new(BigInteger256([
+                0xba9090167dc566a9,
+                0xd2ee3a1ca78d7b67,
+                0x17ed3f26991190c0,
+                0x05fd6e6b267777d5,
+            ])),
+            Felt::new(BigInteger256([
+                0xb5d70f5bafe7e474,
+                0x45daf1e1eccdc546,
+                0x0442d3c5f7067584,
+                0x0007525b07582bf7,
+            ])),
+            Felt::new(BigInteger256([
+                0xff72b685e8d8b0cc,
+                0x4e4905da7a7dddf6,
+                0x1bf41d15d61ac3a8,
+                0x04c3d54d9663cfee,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x80b321474519ed58,
+                0x4cc8e84cf74a22d5,
+                0x01fe93e2e36b9cf5,
+                0x00ece9133ae3d68b,
+            ])),
+            Felt::new(BigInteger256([
+                0xd9724d80d4f9e4eb,
+                0xa542197d29c4f935,
+                0x06837217b144f327,
+                0x07e9c6b8b774da80,
+            ])),
+            Felt::new(BigInteger256([
+                0x4a7f3c8549f41c49,
+                0xfe62d5a5475ba832,
+                0x881343f164e870e8,
+                0x07e761c5eaa7d92a,
+            ])),
+            Felt::new(BigInteger256([
+                0xb997fcb184062c70,
+                0x797d67cf4f247ef8,
+                0xf8faab2715bf316e,
+                0x06ec8aca1c1bb134,
+            ])),
+            Felt::new(BigInteger256([
+                0x34fffedfd424a94a,
+                0x0bd9215b7a068628,
+                0xe81a5b578ba02dca,
+                0x048a08c7aa773aec,
+            ])),
+            Felt::new(BigInteger256([
+                0x915d204b2616d761,
+                0x2963eb6e3a6f9f54,
+                0x907c50d9b84d8843,
+                0x042ef31b8f09cfc3,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x6735a601203ad222,
+                0x241284eb4dca06f1,
+                0x22c6237c059672c5,
+                0x0572958d93820b3f,
+            ])),
+            Felt::new(BigInteger256([
+                0xe093e09d4e56c743,
+                0x89173d14880cbe42,
+                0x5fbbd3093013018d,
+                0x0402b182bf820ddf,
+            ])),
+            Felt::new(BigInteger256([
+                0x083bbef8677369b6,
+                0xa4110381ee9133f4,
+                0xd59bbb2a06f7f269,
+                0x03f1f5f4a5630507,
+            ])),
+            Felt::new(BigInteger256([
+                0x9f5d9cf1f3ae2ddf,
+                0x707562f65ca08959,
+                0x4870f96e5ecbc148,
+                0x07382448ec2f601d,
+            ])),
+            Felt::new(BigInteger256([
+                0x79a19f21d8581409,
+                0x55db9e684722cf6b,
+                0x047004771229db46,
+                0x01fdba412da922ab,
+            ])),
+            Felt::new(BigInteger256([
+                0x31990bfad050aa67,
+                0x8519b2613c431979,
+                0x5f69b691f1ed8471,
+                0x047c9d9222070a66,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 12],
+        vec![Felt::one(); 12],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x690244aa1a329550,
+                0xa0df79a77f299d0d,
+                0xdf497dd836c4f76a,
+                0x047553212048616c,
+            ])),
+            Felt::new(BigInteger256([
+                0xb82f36abfff6a848,
+                0x913339b69e2b8bc8,
+                0xd3e1695116b146ea,
+                0x010508f6eb5a590b,
+            ])),
+            Felt::new(BigInteger256([
+                0xe757924c7315649b,
+                0x0224f1e761889b9d,
+                0xd125b3e2fbc8fd78,
+                0x07ee656eb528d8f6,
+            ])),
+            Felt::new(BigInteger256([
+                0x353bac507f2dd2b8,
+                0xdd7ea4da6c5248bf,
+                0x3b8b086ed060ef9c,
+                0x0439d3d2647711f7,
+            ])),
+            Felt::new(BigInteger256([
+                0x5789872b53733b1d,
+                0xcc50c62010dbdff6,
+                0x7bbad7e42a7add88,
+                0x020bf6c5e6fb6e3c,
+            ])),
+            Felt::new(BigInteger256([
+                0xfcecb067dc76a0fb,
+                0x77555b0336465c8d,
+                0xe5e67b262906a0c3,
+                0x04f700789b5f1b36,
+            ])),
+            Felt::new(BigInteger256([
+                0x46f755e1283b7407,
+                0xb001752b3e5f4586,
+                0x488fc929623db2e1,
+                0x01d8b05f4f491d1c,
+            ])),
+            Felt::new(BigInteger256([
+                0x7803281f45565f5c,
+                0x1309f23e8fc5b0bf,
+                0xef10f75f8ca749eb,
+                0x02c08114d6ecad86,
+            ])),
+            Felt::new(BigInteger256([
+                0xd8bc967247d8d0b3,
+                0xfae04afdbf443c64,
+                0x98c52c42f7de6631,
+                0x0238f8d9683364c2,
+            ])),
+            Felt::new(BigInteger256([
+                0x3e76c9bce685055b,
+                0x12c3a90417f3fee2,
+                0x26923418b426e781,
+                0x051da1921b513441,
+            ])),
+            Felt::new(BigInteger256([
+                0xae2a35492cf437fc,
+                0x0aa8348f9c41f1f2,
+                0x563e63383cc7571b,
+                0x04eccbc6c48ffaa6,
+            ])),
+            Felt::new(BigInteger256([
+                0xed4d61b010842137,
+                0xfcbcf2d7f405371a,
+                0xe20e4556239add13,
+                0x015ff24f6ee8a0c7,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x80aa3ac2801cbf36,
+                0x34a3e4de0ef3899f,
+                0xdd0db7ab81d9e2ba,
+                0x0445b211744b6d2c,
+            ])),
+            Felt::new(BigInteger256([
+                0x0e5e484f605ba2bb,
+                0x710de731857a7ddf,
+                0x16696ea43692a95c,
+                0x01af6964b6ecc987,
+            ])),
+            Felt::new(BigInteger256([
+                0x8e07a1db063974b5,
+                0x908e240dc69ba73b,
+                0x8120afdd3a7aa0e4,
+                0x00a35fd5db01966a,
+            ])),
+            Felt::new(BigInteger256([
+                0x8158a47fbd322d92,
+                0x56a8ea77548b43b9,
+                0xf3504034e0d671b3,
+                0x00970759aa723b30,
+            ])),
+            Felt::new(BigInteger256([
+                0x751a5f2810e3be21,
+                0x03012b5c53bfc44d,
+                0x75755229cb61f593,
+                0x05b748e80e29f3b4,
+            ])),
+            Felt::new(BigInteger256([
+                0xba129acd2950a583,
+                0x2b535acf5e3ca5a6,
+                0x6f8ac5665046df42,
+                0x06f668864a2ce033,
+            ])),
+            Felt::new(BigInteger256([
+                0xc4590334d27b7674,
+                0x47ad8b21c4ebc66e,
+                0xc763c8472a80bb31,
+                0x05492e155bed802e,
+            ])),
+            Felt::new(BigInteger256([
+                0x64a669c181fd916f,
+                0x630d44dd276d5559,
+                0xb656cb30fa40f5a6,
+                0x040b7583af28288d,
+            ])),
+            Felt::new(BigInteger256([
+                0xdbd3088a419f5a46,
+                0x16f5bf42874a7c6b,
+                0xde92b2d48d7e6f71,
+                0x022f23afe290f8ce,
+            ])),
+            Felt::new(BigInteger256([
+                0xdfcb7ad7c4911231,
+                0x29afab3a65ff240d,
+                0x13ed7c80200aa29a,
+                0x020949f656cc4c91,
+            ])),
+            Felt::new(BigInteger256([
+                0x6acc4ae6d72e28a5,
+                0x8614cf4441fe6486,
+                0x71a6a4a15f7513a2,
+                0x033b171cc65226d1,
+            ])),
+            Felt::new(BigInteger256([
+                0x969b483c0564593c,
+                0x2cfd34b20035ffe9,
+                0x5b585ed40c6ff28d,
+                0x00be0182e6c41caf,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb48fab8b2430ce79,
+                0xf15fd514238501cd,
+                0x2eb4e436d5d30678,
+                0x06903027f462310a,
+            ])),
+            Felt::new(BigInteger256([
+                0xd92f140d8694ce59,
+                0xf6ed936537f4c5c7,
+                0x2e8f80b797fbbc36,
+                0x044a7dba427e64bf,
+            ])),
+            Felt::new(BigInteger256([
+                0xdea1897f60caace4,
+                0xc423080035cade79,
+                0x5eee5625441c7a45,
+                0x028d05bc0fbcb51b,
+            ])),
+            Felt::new(BigInteger256([
+                0x0449491feb9e5275,
+                0x60d783107c54a1f9,
+                0x4d3e8f99851aaa04,
+                0x01546ce1d8c5c83a,
+            ])),
+            Felt::new(BigInteger256([
+                0xb7c959f116394bee,
+                0x7eecefef92fdc4a5,
+                0xcc82062e9a5ea353,
+                0x03cabd514ce52747,
+            ])),
+            Felt::new(BigInteger256([
+                0x312c4be995efaa86,
+                0x47caea979c87725e,
+                0x6c3bdf459a27c5f1,
+                0x0672a5b1ac21f4f5,
+            ])),
+            Felt::new(BigInteger256([
+                0x87c73c15fb2fa9c2,
+                0x51a93b1ea259d2a1,
+                0xc5978816f0daeaf1,
+                0x0583d178b572f582,
+            ])),
+            Felt::new(BigInteger256([
+                0xd81314a0a7f367f7,
+                0x643bce9ecdb9eddb,
+                0xc0b07c4147daeffe,
+                0x00476a705a9033d8,
+            ])),
+            Felt::new(BigInteger256([
+                0xbd95cb0ae7bc40c1,
+                0x28432bb0d06be69f,
+                0x71710b36a18e8de6,
+                0x024bc1253fbda010,
+            ])),
+            Felt::new(BigInteger256([
+                0x25815e532f609bf4,
+                0xf7b7d7e148e4af8a,
+                0x737a7b9d2e0d1b93,
+                0x0266e451cdc234f7,
+            ])),
+            Felt::new(BigInteger256([
+                0x6b2a7abd58d12bda,
+                0xa92447d44df25b14,
+                0x7e51a27fb0b8f830,
+                0x030886ac86699169,
+            ])),
+            Felt::new(BigInteger256([
+                0x2db0ef21b33018ea,
+                0xc57538775537829a,
+                0x28eef29f7a0e86e4,
+                0x0786f29d39d6f0ac,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x28d8e998c92889e9,
+                0xa5c003ce3b4e89b5,
+                0xeb1ba8e8f34481b5,
+                0x05db2922c2cf8134,
+            ])),
+            Felt::new(BigInteger256([
+                0xcebf7102bd765970,
+                0x1d08426438a919d1,
+                0xc81ee615c3e1efdd,
+                0x021c5453d0725e52,
+            ])),
+            Felt::new(BigInteger256([
+                0x55d24cf1ef221577,
+                0x1a29c816a3e98666,
+                0x5dc42be7b6979671,
+                0x077bd557d947252f,
+            ])),
+            Felt::new(BigInteger256([
+                0x8a442b197e82cf86,
+                0xe5d0c28df8bb8286,
+                0x84a023654da2102c,
+                0x073b8432c7ea014f,
+            ])),
+            Felt::new(BigInteger256([
+                0xc27218d4d6ad94ac,
+                0xba5c7d75c68260c4,
+                0xc3659a4f96c6b382,
+                0x07fbeea329327f4f,
+            ])),
+            Felt::new(BigInteger256([
+                0x7ce772c051046061,
+                0xdfd009624147fa09,
+                0x21bea931d9172fd8,
+                0x032efaa841577775,
+            ])),
+            Felt::new(BigInteger256([
+                0x9268035033df46ae,
+                0xc0c6cfb9a4d14fb8,
+                0x7a3aa1e072bcbdbe,
+                0x0312c14c88429e0f,
+            ])),
+            Felt::new(BigInteger256([
+                0x0aeea5595463b54d,
+                0xb6ba0e7658f43166,
+                0x6accf8c9d6d6954e,
+                0x032964af028a5891,
+            ])),
+            Felt::new(BigInteger256([
+                0xbb05982464d2adb1,
+                0xb597f719a40ea3af,
+                0xd30f792a9d645952,
+                0x01a28fe00b438172,
+            ])),
+            Felt::new(BigInteger256([
+                0xc07d73bed4814c58,
+                0x96cb3a6096aaeeba,
+                0x81e05dbbbbddae93,
+                0x02efa3a6a616c865,
+            ])),
+            Felt::new(BigInteger256([
+                0x1d68dc4cafa15cac,
+                0xda9a56d34be1b0c6,
+                0x67c1f3565a178871,
+                0x07126e1e7b091a7b,
+            ])),
+            Felt::new(BigInteger256([
+                0x546bd671c7a3a36a,
+                0x844c32f772eba9ab,
+                0x518befd0dabe5079,
+                0x07f68b6277ab45b1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x5f1e6d81945f3a6d,
+                0x7e7e94e34c2c84e4,
+                0x3c037a8b316b8d7a,
+                0x01d4460aab8be540,
+            ])),
+            Felt::new(BigInteger256([
+                0x85e083c90ee6423c,
+                0x981afebaea685233,
+                0x06b3b3f609c3d5df,
+                0x04ad2b2f9dda8f02,
+            ])),
+            Felt::new(BigInteger256([
+                0xc559b6fef607d184,
+                0xc56c6638f0169939,
+                0x83f8baa33bc8fa70,
+                0x020061c7358720d4,
+            ])),
+            Felt::new(BigInteger256([
+                0x7d0a76b34e0891f8,
+                0x9f97c9a78032ab33,
+                0x670189d30dd8b6f4,
+                0x03eeeb141d5d86f7,
+            ])),
+            Felt::new(BigInteger256([
+                0x05c1a33f8a55ab89,
+                0x8a401e9dd0ed3f19,
+                0x6f5558c76d0fe337,
+                0x021305e31eec22b7,
+            ])),
+            Felt::new(BigInteger256([
+                0x7819daa473d1520a,
+                0xa63e3e1339df3775,
+                0xb95906d7e0f100f4,
+                0x0637cfe02d48e72b,
+            ])),
+            Felt::new(BigInteger256([
+                0xa72ba2f5709fb596,
+                0x65937b586fabfe5e,
+                0xf43ac77b998f03e3,
+                0x079e8b27b691b4ae,
+            ])),
+            Felt::new(BigInteger256([
+                0xd06e9a3b36ca1fde,
+                0x21072a743b5a3b6d,
+                0x78d22924dc44c92b,
+                0x04fc5a7bfd19c447,
+            ])),
+            Felt::new(BigInteger256([
+                0x2da2910091ef674c,
+                0x11a71ad881817d08,
+                0x7d09999bc0328995,
+                0x04a5975e17e582bd,
+            ])),
+            Felt::new(BigInteger256([
+                0x66ad9a70d9adba1e,
+                0x383c434e0e0586cc,
+                0xcdb001d6deb812e0,
+                0x06c72abb39353974,
+            ])),
+            Felt::new(BigInteger256([
+                0x8739babbe8687ded,
+                0x4aed3f12bd64f47e,
+                0xae0f550e78efa176,
+                0x038f8bc84acfd97c,
+            ])),
+            Felt::new(BigInteger256([
+                0xe514aa343849e38c,
+                0x78378e93d84621fc,
+                0x42d837b1ddce3cae,
+                0x00b4b1278d36e343,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x40b0dd76c6ed4c20,
+                0x26f251fab0baf6b2,
+                0xf7231615f1a10455,
+                0x0470197d6410df96,
+            ])),
+            Felt::new(BigInteger256([
+                0x36e839c0c5de617e,
+                0x2148dec649cbe4f0,
+                0x6e36849d9adeaa31,
+                0x05fdce52384a815f,
+            ])),
+            Felt::new(BigInteger256([
+                0x9f7ab379c730d5f9,
+                0xa0ac22bafde8c4d7,
+                0x03c9218829f0d2d5,
+                0x031e1bc30d62dfee,
+            ])),
+            Felt::new(BigInteger256([
+                0xa1cc0020b3b3b550,
+                0x3d765afcce016d07,
+                0xb67f9572f35146ef,
+                0x053663df4b14e0db,
+            ])),
+            Felt::new(BigInteger256([
+                0x3115e2b94801e8ae,
+                0xcddda7c7aba93b33,
+                0xa63b8e5a057874d6,
+                0x01666065476b5a16,
+            ])),
+            Felt::new(BigInteger256([
+                0x455889da38d0e4e4,
+                0x20e52a81da69ae8d,
+                0xa29d36ae3a67f42e,
+                0x0498a61c7b248983,
+            ])),
+            Felt::new(BigInteger256([
+                0x50440f34face22dd,
+                0x3d1bfd04e423868d,
+                0xdf493f2e54b60860,
+                0x06f13bb5ca7c2dce,
+            ])),
+            Felt::new(BigInteger256([
+                0x0e871b740787b79a,
+                0xa0fad91fa1de4ee6,
+                0x65c0ffb6bb687171,
+                0x04cd53785fb4b164,
+            ])),
+            Felt::new(BigInteger256([
+                0xe0f3e13fccf13012,
+                0xa183b698951859ad,
+                0xdfc1a9b093c33dea,
+                0x00a21426bb51fc74,
+            ])),
+            Felt::new(BigInteger256([
+                0xac67b014849dd0bc,
+                0xe726906c099d13d1,
+                0x318320f69e66ec42,
+                0x05a38a1a828a3c42,
+            ])),
+            Felt::new(BigInteger256([
+                0x72feb58f28b9b739,
+                0xda5f9dbdebff411e,
+                0x742ffa853a671bec,
+                0x021c5a1d1fddcf6f,
+            ])),
+            Felt::new(BigInteger256([
+                0x1f01917286c83bc7,
+                0x39f75212a334518c,
+                0x27c3605dcac410bf,
+                0x044c41aafb5c0a1d,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0xf0cb5ad23fd8e4a3,
+                0xa5c9ca2552da1d68,
+                0x4625142533432dbb,
+                0x015f330b815301a1,
+            ])),
+            Felt::new(BigInteger256([
+                0x55003b641a19cecc,
+                0x512937a75ed49df2,
+                0xcef554b69b90e4ed,
+                0x03a131ec0b543c6d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x15e09aaa2fb44bd4,
+                0xb22c839959698d8d,
+                0xcef4aeb1d9155f57,
+                0x0730ef07252f3b62,
+            ])),
+            Felt::new(BigInteger256([
+                0x1ba2f015237e9dbd,
+                0xbc988bbeb7a29ffe,
+                0xf1cc1f25b9f9c274,
+                0x06855f42f1198cd2,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xf1d1194698a053b3,
+                0x3ae4d46dff3467ef,
+                0xfc9ebaa615f4f8c6,
+                0x066a3b679c6d1720,
+            ])),
+            Felt::new(BigInteger256([
+                0x6729fb50975311c2,
+                0x3630f639cfa7ad38,
+                0xae8b73b8a0aae868,
+                0x02c4611f50f4c939,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x9ef0588c8ec52ddc,
+                0xc6298d5adb04a03d,
+                0x7bb8a59d0f3e3bfd,
+                0x04130e089fc6b833,
+            ])),
+            Felt::new(BigInteger256([
+                0x0090c95b911008f4,
+                0x2cb7d83a5a13b050,
+                0x59ff770043ee4461,
+                0x004ef55e1603146f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xe337a7171a40580b,
+                0xfc15cceacd85931b,
+                0x5c9c8f2ee6eb99cd,
+                0x007366d482b54021,
+            ])),
+            Felt::new(BigInteger256([
+                0x3cab4cbce3aa1638,
+                0x4426117872c2eab5,
+                0x1b0f7cd5293f0714,
+                0x0137ebdb15e3fa85,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5a7a1d080c97cada,
+                0x965a72a2109246ee,
+                0x893341ac24d86403,
+                0x062950d392c75ab7,
+            ])),
+            Felt::new(BigInteger256([
+                0x2e5156fa0aee984d,
+                0x6d9535226f02c9ca,
+                0x9f8f761b264910c5,
+                0x05402887801ae189,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xc01f920742a91c8b,
+                0xf691c7160b6cea3e,
+                0x06ad28a48a2a75e0,
+                0x06b1a62f04be1f34,
+            ])),
+            Felt::new(BigInteger256([
+                0x87f21a70fbe1a90f,
+                0x6dca0b76adc93c3c,
+                0xcd98c2a97f9f612e,
+                0x021191190822cfcb,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x1ba01dcdfd06f8fa,
+                0x61fd2d3c4cd21524,
+                0xebef4581c1a57ad0,
+                0x01674c34cd54da2f,
+            ])),
+            Felt::new(BigInteger256([
+                0x4167e5f965addf49,
+                0xf966763e736ae2af,
+                0x8a76acfce29a6c2b,
+                0x049e9c8d61ef5d00,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xd04fd5fd77141313,
+                0x6a137360e415c011,
+                0x978bba0262d29e67,
+                0x00e94ee26fc80ef8,
+            ])),
+            Felt::new(BigInteger256([
+                0xa07cc66437cc398d,
+                0x4882ed661ab96247,
+                0x8d35739ee52feae1,
+                0x07eac8a945c6132f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xa7612131c4ab9ba9,
+                0x0f0f813518b3093f,
+                0x382f0489ac7049fa,
+                0x071fec27f599863e,
+            ])),
+            Felt::new(BigInteger256([
+                0x842a01ba0e6f56d6,
+                0xd250919ae5c1666f,
+                0x7cd369766294c07f,
+                0x02ef2af7e892cdf9,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger256([
+                0x367200c019929640,
+                0xe07d65dfd101eda7,
+                0xc0223cc5bdc202eb,
+                0x011e8280b110e733,
+            ])),
+            Felt::new(BigInteger256([
+                0x5dfcd32f46229266,
+                0x0170e4543132aca4,
+                0xd99d1afb725c8377,
+                0x03edaef8463264ce,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x542e35e474afc8bc,
+                0x4c5e14ff8b2018c0,
+                0x815deffaca75bee0,
+                0x0582e3216e5ba4e5,
+            ])),
+            Felt::new(BigInteger256([
+                0xf633daecb145b31d,
+                0xd88180edc44ef39e,
+                0xe8524420a65936a1,
+                0x0587151232e507ef,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x14749415db5ebcfc,
+                0xbd1606c6fb11c7ab,
+                0xa1752a5e565c9011,
+                0x07b893efa8783f91,
+            ])),
+            Felt::new(BigInteger256([
+                0xa102dee4d6984473,
+                0xaa8083d73ed61696,
+                0x0f0fb83208fbb7d2,
+                0x053f64bac6af00c9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xfd743f6d9832911f,
+                0x0809d72b8ce450f1,
+                0xe7fa024486de87b1,
+                0x02c989892a28d73f,
+            ])),
+            Felt::new(BigInteger256([
+                0xc8feba268e831fc0,
+                0x14de5b82990e178c,
+                0xc66df2a0cefa959a,
+                0x04a153585b4a2612,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x5dc2b562ffa60b9a,
+            0x78bc8ee210c7396a,
+            0x7b947e007a6994db,
+            0x064574c88f78ae2e,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x99f5e8f1573b60fc,
+            0x9629424110a9c194,
+            0x82ac97ff8ec1fa3f,
+            0x0574a8154d855632,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x634350bd2f9ce97b,
+            0x6d79b4b891476852,
+            0x007f0ab2b668d7f5,
+            0x01d45a7ceaaf9e31,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9414a6cc3a823ff6,
+            0x2f70d7b21ce9f045,
+            0x82a83ed272524f9d,
+            0x065aa760f5cc076a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc538176db45d101b,
+            0xba63c484cc4b1b9c,
+            0xee091aca94644079,
+            0x01217a1fdc68a3bc,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x03e2eede0e628d65,
+            0x09e2ad1d63d8266a,
+            0x8a9d963fb018c1ac,
+            0x0578b5b8f15c6598,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe4b3e1b0e5d30768,
+            0xdb15df8f36df82b2,
+            0xfee33641870fb213,
+            0x07a16c383fc59eb0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x001d4763b5c44c4c,
+            0x7f76ac484a581802,
+            0x23ef7df87fe2437c,
+            0x04fe92dcc3bcd090,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
@@ -0,0 +1,2441 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2948d2dd4feb5d86,
+                    0x7752e457fed1a8a7,
+                    0x3829a7826c1ca51f,
+                    0x01dd05a1dec15763,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdeed48e72cc9c7cc,
+                    0xf0059e5a78c57790,
+                    0xcc485fbbaf0d7342,
+                    0x049f192c807f1d93,
+                ])),
+                Felt::new(BigInteger256([
+                    0x931efb042e4f12e8,
+                    0xbfdd8db1ca518c65,
+                    0xd5559f2b213ba942,
+                    0x029c8d02ffa05d56,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5577865321750493,
+                    0xa90da2ece0618bd7,
+                    0x4ead53795b61571f,
+                    0x00bc548e9f30b26c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ce5b1833166bb83,
+                    0xd54664c569a6fff8,
+                    0xfa7f438941c7a83f,
+                    0x04eda02ee6b06f5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe7792730a327cd0,
+                    0x558015564b3efa44,
+                    0x80ebc690ed58c87f,
+                    0x00cccc876d7bc894,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe1ac46aea2de4a29,
+                    0x6f1844538d4f834c,
+                    0xb3b9d0e8cc6a1394,
+                    0x004009b7fa66e8bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe993c31a85e17502,
+                    0x9253b6196844bcbc,
+                    0x6a4c24299328dcb9,
+                    0x07aa6bfdf3c942ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcad61f58287cca28,
+                    0xf77b8cd0491fe428,
+                    0x760383b9e05e4d34,
+                    0x0297137ebe02abf8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1adea9e54904fee6,
+                    0xb2b60ff5c318bf4f,
+                    0x86293ddde85490d2,
+                    0x07ff5934293256d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41b2bd553a8e91e3,
+                    0xa3725013583fcfe0,
+                    0x64f5bdce465023fd,
+                    0x06b45666039455c9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8af414000604e70,
+                    0x269a8915bb829d97,
+                    0x76e6133e517aca81,
+                    0x030efc13604c2b64,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2579458871aadd97,
+                    0x52cf5506e8933e4a,
+                    0xcac62d19621b678f,
+                    0x0349adc5b6fe59f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x08c3af1112ae2cba,
+                    0x8b0f2730c86a3db5,
+                    0x79349c1e3e8b5a77,
+                    0x00e22774d66cfd70,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90bbe5000f368727,
+                    0x168ce3b0ad25a59d,
+                    0x46466da94cb51124,
+                    0x03c4b058c10ae4a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7472bb08ef77dffa,
+                    0xcadc0f0066996cd2,
+                    0x68f68956b3b50cfb,
+                    0x04c23419ee9d7b05,
+                ])),
+                Felt::new(BigInteger256([
+                    0x390c3dfff36e45ca,
+                    0x45db8b5a6cf9d1f4,
+                    0xc751dabaedc2c5f5,
+                    0x00de7180b3361f54,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4606b5bc95b4d354,
+                    0xb310414727692dcd,
+                    0x5f8661f61c025516,
+                    0x060f57b0a27ee222,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf96d431af273d575,
+                    0xeeb8c8cfce9ef8f7,
+                    0x5db6b05f15161f3f,
+                    0x02596d0c3a1766b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c113e49f80db544,
+                    0xed87b6e669c597ac,
+                    0xc4da730c1f5876c1,
+                    0x00e5a608d6dfc2b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x305bc2ba8ae53853,
+                    0xdce158bc4de3147a,
+                    0x634e8f9fc973a569,
+                    0x052100439fadee60,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77b2636f19abc080,
+                    0x4dfc5fbc9e783043,
+                    0xa5b5517fed644ea2,
+                    0x01ea2d9bf65670d6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe875baa75350fff2,
+                    0x408a1e7fd3791e76,
+                    0xd85ee4b84a27ca14,
+                    0x07027a1dcad1b541,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92ced6dc490f693d,
+                    0x7e92c5686a4f6e7e,
+                    0x100ae9a1fa25cb20,
+                    0x055eedb71c4713a6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1d06b7d0b385c967,
+                    0x90b61d546a6d9429,
+                    0x4c2e15eea5daca1e,
+                    0x07c01288dae846e6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb138965669a2265b,
+                    0x4bf1e8aa7c6b6da5,
+                    0x18d13796dde9a419,
+                    0x069a8950cfd831b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb01593926ab63044,
+                    0x4ed1fce30211dfdc,
+                    0xff7a6a604ac6aedb,
+                    0x002bfc3b42bbb593,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd43418257c7948cd,
+                    0x1c346162900fdca9,
+                    0xe1926c31ea453c3e,
+                    0x07313a2bf4a4c7e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa270f19e3cecc73,
+                    0xa47eacc001c5874f,
+                    0x08c00770259bfed1,
+                    0x050b202a26075791,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c4fce05d609c0f2,
+                    0x153f6dc29a051655,
+                    0x2950eef8112ebd15,
+                    0x0489d692e5e16aa8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x730a20d0739ed161,
+                    0x3b42cd00bc3bde2f,
+                    0xfed47628043c0b73,
+                    0x042a2063f156719c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf11ddf5084e395da,
+                    0x343f8bed7ccee40d,
+                    0x8507bf3b32c5f91c,
+                    0x0261a97a86125520,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b033befe5adc162,
+                    0x64459a41a429087e,
+                    0x3a40c657c3a7022f,
+                    0x078821180dd20fd6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xce5b1097fe3e4d33,
+                    0x42098892fe0156ed,
+                    0xefa486a659748f18,
+                    0x05680b6ed95c2951,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8b7d3a80836e656a,
+                    0xe3ab75594d5ed969,
+                    0x57b116b024b32667,
+                    0x008ab4072537bb3e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c74f654a39be467,
+                    0x5e624d1917a707e4,
+                    0x6ddc1008e9d4581e,
+                    0x02beca94defb1f8b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xce87e700af8c1717,
+                    0x8791a1a023cddbcf,
+                    0x57b4fbbafce7aebf,
+                    0x024c574d2bd87557,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb623a0596f5b210a,
+                    0xb466d4b8a88f0323,
+                    0xf3f8224506ed3229,
+                    0x037be7131131d944,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5f39e352e6679033,
+                    0x794fa484135b1479,
+                    0x86915c196a098582,
+                    0x021bc4b2a671032a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x601d470dc53d71a0,
+                    0x3b90efe9ae7e460e,
+                    0x73cb3be34d13d661,
+                    0x0662e59bc8091cbd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1c2e731609fe4b5,
+                    0xa7b13d27458ffc27,
+                    0x918663c6d30177fa,
+                    0x0086fa67ab5dd496,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa36c416aa577114e,
+                    0xa3eb8b6d1969f83c,
+                    0xe6fc5d9a16a39c7b,
+                    0x04c55c826bc46897,
+                ])),
+                Felt::new(BigInteger256([
+                    0x98e977d35e04cb02,
+                    0x85b7840eecb94975,
+                    0x45503d8b856fea12,
+                    0x01a608c35f6e975d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x95ed72849c482973,
+                    0xe8eb2a1786dc5b66,
+                    0xebe660cd645840bd,
+                    0x0750b7c74bc6cebf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9272de5f091064e2,
+                    0xc071e64ebcee74a2,
+                    0xbef50886c381be72,
+                    0x079bf791da6867e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ae123e07f35057b,
+                    0xd2aea3c04143022a,
+                    0xd2c16ec7e7b5217f,
+                    0x04202d273c52c75b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6cdbbeb5cfb14cc4,
+                    0x571d67b6bfc4b597,
+                    0x39335c5259968c82,
+                    0x04307a57d8286efd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x20ee10ba5ec86fb5,
+                    0x27918fdc793ee04f,
+                    0xef79a5482dce8d1f,
+                    0x0691b35b4eee6713,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa3cd543d21c2daba,
+                    0x95e2affc7220d8a2,
+                    0x17987c7764f8628c,
+                    0x0421f24edbe6cb5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2a7f4b8c948d8209,
+                    0x3fad4c93be0a25f3,
+                    0xbe2b5092bb830fca,
+                    0x071ba609da86793b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ac47d2ecf75700b,
+                    0x073783a819b651b1,
+                    0xd3492e797500b9b6,
+                    0x0215b2e494dc2f86,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaebbf9942b12cc94,
+                    0xf25f9eec4affc0c7,
+                    0x1b8cbbf592894e1e,
+                    0x03ecf445f7ad8d00,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86de310eb5ec6c2f,
+                    0xc091c1332269fdf2,
+                    0xa64f04653d9fc3b2,
+                    0x0738209a40fdb369,
+                ])),
+                Felt::new(BigInteger256([
+                    0x223a503057d9e51f,
+                    0x617dbf4c98242b6b,
+                    0x861e88d818617953,
+                    0x0463feccf3fe5775,
+                ])),
+                Felt::new(BigInteger256([
+                    0x825c0af2f3ca7fb0,
+                    0xdafc4831d58d70c1,
+                    0xb85e60a204215477,
+                    0x0518765a3d077589,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2bc82ee9fe0dd59a,
+                    0x861ddef0986608c5,
+                    0x890faa61a4d92453,
+                    0x02bf197f4b5faa15,
+                ])),
+                Felt::new(BigInteger256([
+                    0x969d380273ae0ea2,
+                    0x767c6cebe55f58f4,
+                    0x24443571f2e1d158,
+                    0x00e9252fd96fb6d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x17408f1a5a3888ef,
+                    0xccc387b90ab6fea4,
+                    0xf138f375d282333f,
+                    0x006f768680057b09,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6798ad2a3278c73,
+                    0xd7b948273763796d,
+                    0x25063c331842b727,
+                    0x069c36ed3d0bb83b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc32b00dd3d786b9f,
+                    0x7d04d97363d2d2c9,
+                    0x1287330057a5c2a2,
+                    0x079c8f59cbe80e89,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x72e23ed7a3b0cddf,
+                    0x48fab90941be0a5f,
+                    0xd57d954a638cf04a,
+                    0x019387d3d104ddea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x639b0836ddbe85b2,
+                    0xcf3777743776eff7,
+                    0x4170825c72130744,
+                    0x069abf43acca73c7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfad6e687cafa7ebe,
+                    0x8703444f0f2a9a93,
+                    0xae47a40589481b3f,
+                    0x00bdca0fedbf266a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4372f1db17cb4e97,
+                    0x04a607fa40da52aa,
+                    0x7ccec76deead132b,
+                    0x07c4957328527717,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa08181752eddc8fa,
+                    0x3ba4e97b34d8f5d0,
+                    0xc46b1eacf90c4575,
+                    0x017f68899629ac28,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae1286bd17205b26,
+                    0xa877099c39547caf,
+                    0x83c1eee4e663b443,
+                    0x06797f042aa42ee9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x295d9e8e4327fa8a,
+                    0x57f216f811724feb,
+                    0x0ae8fda82fbf4bd3,
+                    0x03eaaa7b99f22cf2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94c093e23635d3ba,
+                    0xfaa4eb6c98d80fde,
+                    0x379625d5357aa875,
+                    0x02f04e38b667284f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ecc338f334c9941,
+                    0xac53a413be551749,
+                    0x0ac7eb005d0053dd,
+                    0x03d8db016120d2f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe503a60d8fe61bf9,
+                    0x00e8049ae425d78f,
+                    0x438fe7ea81654e26,
+                    0x066e3cd9c00a9382,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef5d87ae995da304,
+                    0x020d021a30dd1d84,
+                    0xf0bc6680de3954de,
+                    0x0292fd7218dde5d6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d11365f9d61829b,
+                    0x710ab15dd28023f4,
+                    0x5ac3f3bdffc2aea5,
+                    0x06f4f3860eb7dfe2,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6b42075806da0a99,
+                    0x8d4a8830a8e5cfc8,
+                    0xb8ca7886aa8ba747,
+                    0x0550051ddd1550f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x04d57f0babc52bd5,
+                    0xe8e2986d99370744,
+                    0xd113d921afd0821b,
+                    0x0756064be1959432,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb66fecacfda03b1c,
+                    0xab7404eae6b686d3,
+                    0x00805649ec4a743d,
+                    0x038228828862cb81,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe63fe0078b2e8c7b,
+                    0x4684779ee4f17889,
+                    0x4e0947f63681d7cf,
+                    0x02267074fcba98d1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x930e39e1ab53cbb2,
+                    0xa1030456987ecf31,
+                    0xa4f326d386876bd2,
+                    0x06205ff99110f901,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa66fc4cde1d259c2,
+                    0xf08676f440662e7a,
+                    0x33a68bce927f2a34,
+                    0x06b8462a96fdad32,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa51294be55d67a24,
+                    0x81eb3892a9fce31d,
+                    0xf19d3ecfe74368ab,
+                    0x0762d37784dce30d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb9be334814da3b92,
+                    0x2d84b2b6c7b3ecfc,
+                    0xf8e039c929dc2dad,
+                    0x057a4cd62e5b0198,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18b7dd4bb17045e8,
+                    0x80939f21d24386be,
+                    0x23bd7c5693f40d13,
+                    0x058a5d564fedd3cf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a47fe1652f79bf1,
+                    0x5b3d34a5e1a65ff7,
+                    0x1bbe0c41fd461a76,
+                    0x01d4fee8466b497e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x610c46ec9f8ecde6,
+                    0x0f6d957060179b04,
+                    0x40552bcd1d39fa39,
+                    0x0112f2347a59df11,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba3c1b9dd9a68e11,
+                    0x0cd4f724a4b955f5,
+                    0x2661eddb65bba5cf,
+                    0x0443e0c40be030ca,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc994e524a4c9e537,
+                    0xfd9998bb1362732d,
+                    0x1cc03cd480552b3b,
+                    0x0262a62b5cf1b5df,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdebf8f7815cbb57b,
+                    0xfc79c0e7e35effdc,
+                    0x74447af668861306,
+                    0x0454d94fdbd7821f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa883ce7013312d00,
+                    0x0cc7d9b21b19ee5e,
+                    0x4d45cc77cfcb5401,
+                    0x01017e84cb5a4d57,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0d65c447402ef968,
+                    0x87b384d828064a7a,
+                    0x1d99920e5e451e40,
+                    0x01b39c1939931d0a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x392ec84606cf5879,
+                    0x6f4d481a4b21955a,
+                    0x9ba5939d93e8e6ea,
+                    0x0479956860225a89,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77aa9aea33f77fb0,
+                    0xd703ce570a10a979,
+                    0x0d77d8d135ce8d12,
+                    0x047d6c26ebf30555,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b3d507dc653b97b,
+                    0x86689819b04b09ab,
+                    0x54e7aba8101d6e5a,
+                    0x0422e7140ff69c44,
+                ])),
+                Felt::new(BigInteger256([
+                    0x56a52eaea4c4304a,
+                    0xa21b5703d04d13b1,
+                    0x6634222dc894d666,
+                    0x06334f9d08297e33,
+                ])),
+                Felt::new(BigInteger256([
+                    0x340ad042e713f74c,
+                    0x5490eed22bb8e875,
+                    0x42deeab2b2e3df4d,
+                    0x077a255740758cd7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa60eb212ceb4d1c4,
+                    0xd4e0a63d0b52006b,
+                    0x8cb8ce7247c6949e,
+                    0x026eeb09ac056064,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8d38192bde9e44b0,
+                    0xbe0fa7ff04549fff,
+                    0x36910b145fec1f49,
+                    0x00db3a7a0c4cfd81,
+                ])),
+                Felt::new(BigInteger256([
+                    0x20a97685c57b1e4e,
+                    0xa306c08e36de5075,
+                    0x0c9d7cbc8f6551ba,
+                    0x06e76709f7388b68,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x12181f9a5ee703c4,
+                    0x263ca33af40eae53,
+                    0xf2535954b6c75204,
+                    0x04763840c18f9417,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e74d98eda24babd,
+                    0x502564f1d415aabc,
+                    0xf255d68c100f50d3,
+                    0x05dd659417453d66,
+                ])),
+                Felt::new(BigInteger256([
+                    0x125ec4fcf6fbc640,
+                    0x8c3bdb0f0055ada9,
+                    0x75d3be3f9fd7e486,
+                    0x072aaacd172820c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab5adaaad15386b6,
+                    0x38b70182c91f6b13,
+                    0x148564d1b8c9cca6,
+                    0x05fa0adbf1da1920,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbb774434797a1d23,
+                    0x2bfe01bbf6a085a8,
+                    0x56ae110d227e00b3,
+                    0x0176a398e8731c6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55e027a8c5eca31d,
+                    0x5b563d44fc6b361f,
+                    0xf3137f3ea170f8a7,
+                    0x07750af7f030ccc7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c4eb942088d2bcc,
+                    0x4350ee0a02bd9818,
+                    0xdb319587000bc434,
+                    0x07b4bd6a26f6b805,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9c276ba494611c83,
+                    0xf7d2d31c52e90be6,
+                    0x090af08449e45310,
+                    0x02da53565e16fdfb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1a816209f1bf264c,
+                    0xcca644cd1d9d7b59,
+                    0xd34bca75f9b7bde9,
+                    0x06fc22811db72217,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa752e03b9db21f2,
+                    0x73b76971044b80f7,
+                    0xaa49724888331748,
+                    0x05af3b5f1cfaf748,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ae375df17933049,
+                    0xf57d5a6b5c8f1b02,
+                    0xd60f02a9285d9a05,
+                    0x041acd33a5cd36a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd71df6fc61212dd2,
+                    0xd818e6ed350fabfa,
+                    0x6334d59526142031,
+                    0x05e201b324419554,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb1c37552678d06cf,
+                    0xe2f94f8e7db4c40b,
+                    0xea80ef6fbb5e0277,
+                    0x05a007beefc26daa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc695110960ca21e4,
+                    0x39561775aad8ffe7,
+                    0xebd16b26e1458195,
+                    0x0319728350ebeb08,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcb5fcd4a53f84bdf,
+                    0x475a47aebf626975,
+                    0x4eeedf1085a64885,
+                    0x018a1ae7374247aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x34c2ad065afe6761,
+                    0xb37c9b0bab6f1a01,
+                    0xda89aaf3f18f5e7e,
+                    0x039e54d96ec1e276,
+                ])),
+                Felt::new(BigInteger256([
+                    0x22d852d1e0470c67,
+                    0x45456e80617e0b8e,
+                    0x1f09f969b7587d80,
+                    0x03615d660a07ffbb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe88198c4165eb9a0,
+                    0xf9f3766c595c28e8,
+                    0xabaffd319edae11d,
+                    0x04258fcd59cec2f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5543c62171580cfe,
+                    0x338d258163cc6cb1,
+                    0x23eae68ac5cf4b4d,
+                    0x0655b4983ae0c157,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8f6e047f56813f30,
+                    0xdd40e4d35d721637,
+                    0xd38edf3513c0bae0,
+                    0x0550f63ede8c06c5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x071b751c6c36206a,
+                    0x5459b8d54f0d5aac,
+                    0x359a4e70e47383e2,
+                    0x0495384f09491911,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcc8f878e936e1a1a,
+                    0x2c02e7921376e6a1,
+                    0x48f5d9b1015de300,
+                    0x04f1be0c0b362398,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa342df8025fc85d6,
+                    0x5a40cd3612127307,
+                    0x32222c528b1d2eac,
+                    0x0131729bff9a72b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x21c95beb7290d15a,
+                    0x4d018a5f11423170,
+                    0x3d89d2eba4a855d9,
+                    0x05598ef8c9c98662,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc05f96db2159d104,
+                    0x1f2e2caf8e7c7100,
+                    0x8f61b0195525fee5,
+                    0x05978c2e0ab92a24,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd52fa5a32e0ce47f,
+                    0x868d78457c4108f3,
+                    0xc6f904ca80fc2556,
+                    0x0094e5079d0ca71e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b579667d55808f3,
+                    0x7ccec08772f7cfed,
+                    0xc01440bef56e37bf,
+                    0x00ad424712051823,
+                ])),
+                Felt::new(BigInteger256([
+                    0x443aa51753bf723c,
+                    0xcce1e24207095788,
+                    0x41aa2061b3241a60,
+                    0x03433e0bbe2ad53b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd875d3756bc2fca5,
+                    0xcd08e9a3185abce6,
+                    0x75d01ffbc4c86ba7,
+                    0x06136cd7f9668099,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb89760c1b9d38895,
+                    0x5a0d3c8323343828,
+                    0xf9878d04c500f845,
+                    0x0741843829f3e673,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b11c7bcaff730ae,
+                    0x419a7d29c9d67d00,
+                    0x9d34d88cc4a1a015,
+                    0x00ddbeab337778b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc9706a1b89e058cc,
+                    0x764112950f904f66,
+                    0x49cfc78ea76f22cc,
+                    0x004b437b7dc085d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x180d9d0fd321e33f,
+                    0x6cd57321f2c9d9f3,
+                    0xf397b149d9201148,
+                    0x064c1ae616a1df27,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc4fa1c0822007075,
+                    0x56a72f1bacdcacc4,
+                    0x00ed8709c19f169f,
+                    0x044b1782401454ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7df2c51950076a39,
+                    0xc671b7f5323f5d52,
+                    0x49a0c73349ca4067,
+                    0x008d5ecf6150a140,
+                ])),
+                Felt::new(BigInteger256([
+                    0x05509a5103f7d4e2,
+                    0xdbc6e759c81417fe,
+                    0xcfe35fa4090345ae,
+                    0x009535c3e19d5c4a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4b553a2dfea003d4,
+                    0x5f22e22b98be721f,
+                    0xc01561a1db3e0373,
+                    0x040341a23c291a29,
+                ])),
+                Felt::new(BigInteger256([
+                    0x51582c77aca9a193,
+                    0xf4fc8987328ef12b,
+                    0x21e85f8925d8e9cd,
+                    0x04cce5b8d97ac9e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x668a108867bb6d1c,
+                    0xfde2ec892396e9b0,
+                    0x87f029447593dfb9,
+                    0x06ba14c7b1736085,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ead916e35199f84,
+                    0xa3bf21831850c6a7,
+                    0x4c33d7e5f15c89fb,
+                    0x03312b22c39ac535,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0d9c56adcb23816,
+                    0xa9cd3035d79ddeba,
+                    0xd1e33bd0219483c1,
+                    0x058047fd8e1968f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x949ea85e8b868b08,
+                    0xed8561cc133a6cc0,
+                    0xf4ed6ef050e50aec,
+                    0x06e9761895a244e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdbfc675f25b9c120,
+                    0xea47a4c66a1fd57d,
+                    0x11461996a5105cca,
+                    0x077ffd0c87839b76,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa5429ec4d5ebf48b,
+                    0xe95964899140b238,
+                    0x3b24618d2bf2b28d,
+                    0x06cb31caa79e0590,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9c049ac9f71d6a5b,
+                    0xb2e826bcf9b129a5,
+                    0x2cf4f072f660da40,
+                    0x000955b6e2afe993,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4bc7173641616445,
+                    0xfde37bf81200ac5c,
+                    0x69c236bd025538ec,
+                    0x035cb6d75297f0ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc3cb63f4713b56c1,
+                    0x8fefe17ef8300207,
+                    0x8d7c558de27d9ecf,
+                    0x06aaa8ae1adf499d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c783f38e6ca69c1,
+                    0x7454306cad057a7b,
+                    0xa7eca4c760b609e5,
+                    0x0408ddcb59f5c576,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe2f20b0b0450560d,
+                    0x373146eb09e31832,
+                    0xaaae4a49dad05aa4,
+                    0x00ba164fd386519f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x087dc899c7bdef05,
+                    0x1fa0aebcf7d8e193,
+                    0x5a16bd0a78cc1f12,
+                    0x01452acd7a77de99,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbef28c4d8ae752a8,
+                    0x814bc2324c52976b,
+                    0xa5339416c3b2fa4d,
+                    0x06b1a3df769a5492,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0fe21b85d9326db,
+                    0x4cefe3a28e0a6c1a,
+                    0x3b1385da29d58373,
+                    0x06980de0a076125f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f0a13fde434e24e,
+                    0xcaca45d6eada7804,
+                    0x379576241916b2b7,
+                    0x000cc0d7e4a4edd2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b0bb568f8206c26,
+                    0x656fe510988e434d,
+                    0x3e1780a1a02ce55a,
+                    0x0752fd922346b7c7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf6daab47b025194e,
+                    0x29f7abc5d34a5e04,
+                    0x7ca2885cb03fee28,
+                    0x051b952afca59c4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x04317c0167af92a7,
+                    0x23f140b0bc516381,
+                    0x73c8164679107d8d,
+                    0x063fd4919b283970,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbcec5c5a15babf5e,
+                    0x8f5411f3672d088d,
+                    0x554d63ff6a366c34,
+                    0x02a27660683694d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x84a1d316c9a34ae5,
+                    0x86c4022612cb1c12,
+                    0xd731fc506718b0bc,
+                    0x046b4ca907d9566e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x11b0094393f45cb1,
+                    0x47b531c2ce9c46e6,
+                    0x290563c0d4417f2b,
+                    0x029435875e5eece4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb614150eb0a4b9b5,
+                    0xb823c68e59d508b5,
+                    0xdf26e867f3972f27,
+                    0x026e58938e8ccd2b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x471973a5c1205235,
+                    0x99b8cf3dfd1e4814,
+                    0x3936012e2fb14ab6,
+                    0x0292705fb69b8a4b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf6481c2e6b98e9ae,
+                    0x51617105fb255830,
+                    0xad58bcc4cb1dfd98,
+                    0x02161bec2303cf3b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x536812be6cb9d3a3,
+                    0xf8d8f7f6af41557b,
+                    0xdbcdf5b9360d37bf,
+                    0x0168fa9474d2a722,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa16f05f202181547,
+                    0x8f77b2e6c36a0795,
+                    0x27d14953bb9c0d01,
+                    0x019d0525447f4a70,
+                ])),
+                Felt::new(BigInteger256([
+                    0x685ed8cc6dc8c817,
+                    0x1a12f6bbae0a410a,
+                    0x86561edce0dd5e7b,
+                    0x03360d1dedcb3eca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a5ab013c249489e,
+                    0xb0e53d176438b6d0,
+                    0x8ab0b10a9c7a72b2,
+                    0x06f03f9865ee6a29,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5410fe07a147bef,
+                    0xc10cb26b288e939a,
+                    0xe68d063e56ad5808,
+                    0x0552e70d32f96eb1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71892dc754f33fda,
+                    0xd0b5927a7e557b68,
+                    0x3f9581ca487e0041,
+                    0x07e91760f6983213,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a8f33f17152d8fd,
+                    0xaad06b74001c0026,
+                    0x2a70b36e8ed90406,
+                    0x0107eb294005eb4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf3865e3210c38d70,
+                    0xe47ac25861d208a9,
+                    0x0690d4daefd5b74e,
+                    0x0452d5be42b02a58,
+                ])),
+                Felt::new(BigInteger256([
+                    0xadecc0ec7a1ec9c6,
+                    0xd5af714b6d694ec1,
+                    0xc906c6c8abbff082,
+                    0x0295514615ef9d44,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3ae9071caa3553fc,
+                    0x282177b313e48abd,
+                    0x4c75225373a13d0b,
+                    0x0208d961ded826f1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2e78305cb06d30b2,
+                    0xd10159588da3544f,
+                    0xf0598f046f675589,
+                    0x01183005fc3c40ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x62b325f223493e94,
+                    0x2103dcfc66845e34,
+                    0x4dacc57a0d3d07c5,
+                    0x02464db99e5fe68e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5fd75f8d7fbb5200,
+                    0x041c51a158915795,
+                    0xede84156070ab612,
+                    0x022b55057bd44a9b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94456acea5660c6d,
+                    0x9ddcd7239c149b1f,
+                    0x21a3a4d0d359eb5d,
+                    0x007900983390ee88,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeae0ba4adbe44c97,
+                    0xde985537277aa7c3,
+                    0xe46046b836f5c936,
+                    0x05aa646eb3a7a82d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fa9451708ebb69f,
+                    0xb3df56c6f40bea21,
+                    0x7c57de540b881059,
+                    0x05656549fb791db5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1259894cfec8ada1,
+                    0x71b4409874921e28,
+                    0x00f8ec607b532621,
+                    0x070678dad6a68937,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4b8074c35ad5e889,
+                    0x49c0955e0ceca0d1,
+                    0x014de244dbf1bec8,
+                    0x044e8ad8533014c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x60f8d755abb32e2c,
+                    0x3c6402e7f5db0db2,
+                    0xdb4571b8d29c706a,
+                    0x022cec789f0a6dde,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8378722bd5b4c441,
+                    0x8dc25612520c708f,
+                    0x9a380ff8cc6a7f37,
+                    0x0672375c9687f812,
+                ])),
+                Felt::new(BigInteger256([
+                    0x42bfde1aabfd9bfc,
+                    0x2013051347e5b523,
+                    0x5a70813baefeae59,
+                    0x069a3860d40eff88,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80a6af193994c3ea,
+                    0xa21c4bed1863b378,
+                    0x52242d0490176a99,
+                    0x05fe39c9eebcbe5a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8b7361583ecb2a3d,
+                    0x8b3153dcff5b5044,
+                    0x850e43108004039d,
+                    0x0363375897355f98,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2eb3151894f7feeb,
+                    0x648d04699cee0b4f,
+                    0xc06c827e4b7768ad,
+                    0x04732e7905d10942,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb44f9ee8fd2338e2,
+                    0x9dfd922aeeebd39d,
+                    0x5db7adc48af3c778,
+                    0x02835785fc5b6fb1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa94170af907d315a,
+                    0x5e0015d86269a878,
+                    0xc06527b4985992ef,
+                    0x029b4c9461f5d5f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3600fb61f19740ec,
+                    0x0f681cd7eedb162a,
+                    0x26dbb7765f21d78b,
+                    0x0360f24ee32075f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0db8f859f364729,
+                    0xa7e9bdbed7229461,
+                    0x9781a9155d5a97b1,
+                    0x007a6144d94808bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c921fbbc6a92d8b,
+                    0xf880360a49405c86,
+                    0x176ab1acd9761632,
+                    0x01c70fb77068bd58,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb83b46faa364ee38,
+                    0xecf334f9590099ab,
+                    0x27469d2f0cfc05a7,
+                    0x01bc2344de6f30d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94767d2f37a5de5e,
+                    0xdbee3f4ab137e79e,
+                    0xe1a704be338d5559,
+                    0x028afefd588f1046,
+                ])),
+                Felt::new(BigInteger256([
+                    0x594d88f6501c68b9,
+                    0x0afac28d1962c21d,
+                    0x0f44618ef63e18de,
+                    0x06250d446d0c7051,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fb93a771f0b7e93,
+                    0xc1d97017ffba06c4,
+                    0xb9bc5dab1fb38a47,
+                    0x02aceb9be8c1a8c3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd45f38587ac9bfd8,
+                    0xcf62927a72217517,
+                    0xcdb53558d2549a88,
+                    0x00ba355a9ef5ce59,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x043d5bdd66fa2cff,
+                    0x4957bc612a3fad68,
+                    0xc7b0d6423b11e8cb,
+                    0x03d29b74490fc797,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb4394a73e98cdfd2,
+                    0x855e44968e82cc59,
+                    0x2060ac46016e2290,
+                    0x0325344821239402,
+                ])),
+                Felt::new(BigInteger256([
+                    0x774d3d4d0f5af4bb,
+                    0xf8635a104ab0bc8d,
+                    0xbe45dc99440922e5,
+                    0x07da03b17b3cc230,
+                ])),
+                Felt::new(BigInteger256([
+                    0x004dd502dfbb66fe,
+                    0xc6028875d57d011d,
+                    0x1a1c6e2a00cf553e,
+                    0x006a4e9d62dff718,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9fcfd79c74003709,
+                    0x5af1a30803391c1e,
+                    0x8e6ade69d912e925,
+                    0x078e325d9f23ac19,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01c2f0822b873bff,
+                    0xc58a338eab0df317,
+                    0x0e8082325df13aad,
+                    0x048ffb55c516916e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec5b28a76c74de25,
+                    0xfbcc04e0a038d15f,
+                    0x62235663b1d34b87,
+                    0x06b39a74916302a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3546f58073495d6b,
+                    0x09ae569608d68a81,
+                    0x587170c2c0a95deb,
+                    0x009d21709f0939b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x82bd2d63e171aff0,
+                    0x99717520aa7d5518,
+                    0x74744f5500e3e5c8,
+                    0x06838c9a846d509b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2525a479d675d760,
+                    0xa5de1dea6eed6cf9,
+                    0x6dfcb12497a3affd,
+                    0x06ba1a3a5e0c5509,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13afe030e89bdf99,
+                    0x0a20ccf5d483e817,
+                    0x82f07da4c67b8926,
+                    0x0631b9db5db4438f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x808fcfb3c3bc39d2,
+                    0x7fb739aa597ec3e3,
+                    0x97e3d192f728b05c,
+                    0x07962a5a355e2b5e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x164619604860cd7a,
+                    0x2bcef7bf539bf78b,
+                    0x4f0237d645a40ee2,
+                    0x044025d184109084,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb6142b1bb15d02b2,
+                    0xa71b779fa64c3137,
+                    0xa2a65552136e6ff7,
+                    0x01f8e132471fa7ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3aa7e5fa38eb0ba6,
+                    0x0ae3a39e4273c9b8,
+                    0xe9f56dbb4390e568,
+                    0x0384aa88ac3a39ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03d8fdf2f0a54e55,
+                    0xd30054d3cc4d4a42,
+                    0xd4743118296680b8,
+                    0x075da608df8bd3b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x920970d80cbb1032,
+                    0x24344650a26e069b,
+                    0x88a6b88684b8080b,
+                    0x05b1a6b9ffa41a08,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc66246a0d6e42ec,
+                    0x54181d4224457831,
+                    0xb5b895d81b4cb8e4,
+                    0x03526bc4e75672c3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x556c5ea587ef2eea,
+                    0x2a5d86a95e7347bd,
+                    0xbffdbcc829482d6c,
+                    0x044d74ac5420e5a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc2d45853f6ba6b05,
+                    0x25b3d50a71cdf6b6,
+                    0x845c3d639b29c4ed,
+                    0x04768e20b4bc8e0e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x27ccfac93adb0f0d,
+                    0x71a3b886a668b859,
+                    0x5ccc6e3d4def7210,
+                    0x062aa69022448126,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab945e690242ce31,
+                    0x0a7a1be54fbd0c07,
+                    0x344f80cc9166e236,
+                    0x025c8d27a5b7506d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf6890288e04c439a,
+                    0x3550296f0b94bac4,
+                    0xe88534915ea070ac,
+                    0x00aac4391387a40a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7f660cc38c6b8af,
+                    0x13343020cdb8aca5,
+                    0xd81debee537012cd,
+                    0x0039c3201b5bc59a,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x19b8a3da28dbccd5,
+                    0x6507865f48775c58,
+                    0x6b262d7f848a5421,
+                    0x04a619e27e866a79,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef79d756eeb6607a,
+                    0xf93c5607cfce7f12,
+                    0xc565fc23601d0935,
+                    0x04ed75680a8fa1ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5aeaaa7539a0195e,
+                    0x961486a0fdaa3b38,
+                    0xf1556093d45aaaf3,
+                    0x05fd5af05288ded5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa117b6ef638c40cf,
+                    0xcab8eb360b480337,
+                    0x794fd0c780e2c9f6,
+                    0x0609cdee68fdc1db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x659494c4f035930f,
+                    0xa86adb67525107f1,
+                    0x7a89d15bfa651c7e,
+                    0x07ab33308026294c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x02746c04ac64e9f5,
+                    0x421f563202994a87,
+                    0x00bab4815be147fa,
+                    0x07e7ee3abec9e5bf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed47e3a5be3bddcc,
+                    0xfae7078c4b383b88,
+                    0x3a52ba6e4d0a795b,
+                    0x034240ee49083039,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a5cdfe53ca4f590,
+                    0x7744a29cd77d4748,
+                    0x4a085ca86412ef29,
+                    0x078cab2e578d4c1e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x167a5ec8b8e8935a,
+                    0xae71e5d354b51a05,
+                    0xd2ef0218b1b872fc,
+                    0x01c6cdb17d85b786,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8982d07a1a402b16,
+                    0x02c1650b0839cae2,
+                    0x02e31c383a7eb072,
+                    0x0504bbba87a662c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae7dfcca56ae2b06,
+                    0xdb52b1833468e935,
+                    0x367187acb4e6b57b,
+                    0x0265056e5afda5ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe141355c87343f4e,
+                    0xce55cb0d345ae239,
+                    0x551f2116df290a0b,
+                    0x006b1e9c1efe5ce5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x85503c4c2deaa806,
+                    0xbb4233f9d3374d23,
+                    0xf91084fd9a98ac2c,
+                    0x01074fc652a32f04,
+                ])),
+                Felt::new(BigInteger256([
+                    0xedd6ca39e7ee75ce,
+                    0x6fbc6988909f0b21,
+                    0x26aa8636e400d40e,
+                    0x074e3aa061cea0e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x808e4eb80c8878bc,
+                    0x830335c7e22474fc,
+                    0xef86d251b7559220,
+                    0x01c9fedc7d8de4ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc91cbaef6195863e,
+                    0x8f1aa2c695da473a,
+                    0xbca0f2386c9330e8,
+                    0x06d1fda2aa86e779,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcac8d5ef4e084fe0,
+                    0x16b36edef6f609c5,
+                    0xabf94b19acce0c7c,
+                    0x02f1114be6b486be,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b50f5c31648c150,
+                    0x8bee8629f51c098f,
+                    0x2843e73bf4f70d1c,
+                    0x00be567c421feb3d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x647202d3109173bc,
+                    0xcea78f27b05afbe6,
+                    0x876ba003a00e2dac,
+                    0x025c7215e27e6bc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x06fc5369f961fe6b,
+                    0x5a604b71ba85e683,
+                    0xbc2c8fe6b4ca3ac1,
+                    0x075329c90655de67,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e3b9083c285980c,
+                    0x4b2fc5862576b000,
+                    0x8bbad92214bad887,
+                    0x000b473b122a74ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb8afe7ab81921e7e,
+                    0x34bff1803fc7b6ad,
+                    0xc311814aaea096aa,
+                    0x0353d79738536e71,
+                ])),
+                Felt::new(BigInteger256([
+                    0x942ecc9e18f97138,
+                    0x7f1302dc31759507,
+                    0x72824ca594d8dd8f,
+                    0x0054c792c6c05bc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9ba7418ec5e8653f,
+                    0x4154954ec8e9fc56,
+                    0x7798a5a01b94626b,
+                    0x02bec58c9b23078c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf6226232697c5d9e,
+                    0x06033a84ebdae95b,
+                    0xee3e435537297d28,
+                    0x0078d5164379bbfc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ffd2ab651f19d77,
+                    0xe27cd2db474db2b8,
+                    0x41d1c1e24040f7c6,
+                    0x061250d8564cb71c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2befe31522568999,
+                    0xe85d702b66906d4b,
+                    0x31d89f9229d918ca,
+                    0x0457da895bad5403,
+                ])),
+                Felt::new(BigInteger256([
+                    0x948eb0dd0c946487,
+                    0x99d5d0c7ea3e3709,
+                    0x544325fd56f6a70e,
+                    0x0317d7bdc96e3393,
+                ])),
+                Felt::new(BigInteger256([
+                    0x699486dbe2497426,
+                    0x5145cef5c1d4604c,
+                    0xd7d70b94dcf90bce,
+                    0x05203d5dbde02edb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c37d94a8479f4b1,
+                    0x453fc09710c8ad98,
+                    0x0fcca3842955a598,
+                    0x02593023f9e8d602,
+                ])),
+                Felt::new(BigInteger256([
+                    0x333cbe535039b8b1,
+                    0x847eadfe4de30b0a,
+                    0xb587f43084306006,
+                    0x0458f4fcb7a5570a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd00e0b4b86a2ad56,
+                    0xa5d95b17158dc73d,
+                    0xda0fa4f67caadc6c,
+                    0x015e5177911387b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x50e9d698f5f40229,
+                    0x36163d94b9272f01,
+                    0x191edf71545f624a,
+                    0x05b74193eb9651d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2223dddbaa008b43,
+                    0xbed07b37ab442e32,
+                    0x9a6294e60863b3d8,
+                    0x0473f2a335e3a195,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b3e63822c2560f6,
+                    0x67e0250ed4c85c7f,
+                    0xe5d7de5901be20e9,
+                    0x027c6c24241f120a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe04dbddd68fbd50c,
+                    0x14b317e6a51468d1,
+                    0xae02f7f8cc4a1a5e,
+                    0x009d1b2f1dcd3abb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2f455bf5805b88c3,
+                    0x7e3a68d9a7ca616e,
+                    0xaf8af97ed985d253,
+                    0x0590a1dca1429195,
+                ])),
+                Felt::new(BigInteger256([
+                    0xafc909459ec230c0,
+                    0xe4e3f7659e53f884,
+                    0x0ea06507eedc5349,
+                    0x030367637e383b79,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9475a52197071650,
+                    0xdd21b35124cde54e,
+                    0x0c88fe79fafdc5f3,
+                    0x04ce2bab83c3df86,
+                ])),
+                Felt::new(BigInteger256([
+                    0x02ffc2f4328f4091,
+                    0x3c9d3e727e46eb19,
+                    0xb66b85bfcaa6f83c,
+                    0x024c359534fdb07f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0119c1fcf5ae46f7,
+                    0x350e45bd30dcdd7f,
+                    0xa62f86c8b34273c2,
+                    0x04063499d2d8f43a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bf3c3850d73cdf2,
+                    0x0b2981cdee2a2463,
+                    0x7fd43e00201edf29,
+                    0x0492aedfafee33dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8c8210a5575d182,
+                    0xd1259ced4772574e,
+                    0xaeb6720fd67d5898,
+                    0x07b76190de6bac97,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaeaadf7785b73b33,
+                    0xd233e8151693fb0c,
+                    0xb5b27c2c5f6f0820,
+                    0x06ebc6b2645e8bab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c5c3d667905ba47,
+                    0xe55246dc78d50853,
+                    0x9e1654586903e7ee,
+                    0x020b82165fd8cef1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x38dd959f724c4c10,
+                    0xbc11445ac0070ae6,
+                    0x2ea97dd77991bdfe,
+                    0x014227bd9bc4652f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x48582d5b550c348a,
+                    0xc073d1ed088d3047,
+                    0x884feb592e5fd11f,
+                    0x00a872d3a2b084e7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe0407883cd6551aa,
+                    0x4ab7d26ec6de95e6,
+                    0x93a4f7bee68fd1fc,
+                    0x05763f31524201ca,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x49368b5f46b20215,
+                    0x37df95800139f301,
+                    0xaa6e363b7cdeb6d6,
+                    0x0155f51d21a342d4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcdee3ef6ab9c1f95,
+                    0xcf513038bb52cc9b,
+                    0x5d4edd8de45283f0,
+                    0x00367aaed15e6fdb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaca0e538756410ad,
+                    0x744fe34600600b84,
+                    0x665d27b977c479a7,
+                    0x072b19b5040660a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0749d1ad5e1c2170,
+                    0x041d2b010b911532,
+                    0x0a52b5297f5b0fd1,
+                    0x03c8fc5efea4e63e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd585361905d4aadf,
+                    0x8ffc1328dd72fe45,
+                    0xc988787546fc166d,
+                    0x05eabb587d677942,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc04557a8a33e2880,
+                    0x8040a271ea60a60a,
+                    0x097a2cf8c7f037dc,
+                    0x00a2f9ad07b9f915,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83269384d7f667c7,
+                    0x229996e4d2fa8292,
+                    0x66e4e84da125af43,
+                    0x023a56a627957f37,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc2a33d8752ac9492,
+                    0x81a1007d5314dfcb,
+                    0xc82b1436c5b59fd4,
+                    0x035c4aba62358469,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86c3723d4532cfad,
+                    0xe7651b13fbf19953,
+                    0xb6170cdcd38cb0a3,
+                    0x00a3afc8d725288d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b00bdab2cd7a569,
+                    0x86616cbc200a8170,
+                    0x7d35c8f2680d30a3,
+                    0x054595b88dccee0d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x479005f2b3c86260,
+                    0x786f7a26e3eb1a91,
+                    0x0cbf1f268b9f53a0,
+                    0x00fc39d2be095b2e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa43e7765ff5f61b9,
+                    0x9a368d4da3c92ed7,
+                    0xededd7617f14eaac,
+                    0x02416a044a9fd743,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9049fceaf7510ee9,
+                    0xed2cbca8f83fa128,
+                    0xeeeeb86c04c3af86,
+                    0x06a46700b8496711,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18101ca2e490598c,
+                    0x672393c282631a60,
+                    0x933b74ae106aed79,
+                    0x047a167f2da1654c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1a0d49a5178ca9c6,
+                    0x6090e4e70ef6059a,
+                    0x8a3136e3391d4c0c,
+                    0x0256f818671ce858,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72817cf21381b089,
+                    0x2797fdbbf82f4807,
+                    0x634dc77e0d227f40,
+                    0x06ff8c8712712ac3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5efff667936d685,
+                    0xc09b585d70cad872,
+                    0x4283966308e62cd1,
+                    0x0371419d2a14f532,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7efdf1d54d2f2fdd,
+                    0x9903586040abc534,
+                    0xeeb0cd3194d0612c,
+                    0x028e05d84d42a0b3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9fdc945a050ba7ef,
+                    0xea3c9f99710fcbe8,
+                    0xd8b408a4b0b10eda,
+                    0x075378b697d5ce53,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2edcf313127f771,
+                    0x1ee9b0c9d2dad642,
+                    0x85415cb5ba5b722b,
+                    0x03b929055111a450,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9ca59847df6668dd,
+                    0x89366cb85dc30f5c,
+                    0x67bd34ab6ae5c5fe,
+                    0x003b1b76b11fa874,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc690fcf9bfa25579,
+                    0xd57b83b6da58fb69,
+                    0x1be8786488a6a708,
+                    0x04a7ad9f8bdaba93,
+                ])),
+                Felt::new(BigInteger256([
+                    0x562f9ad4dd690166,
+                    0xe97f9c18ef6656b0,
+                    0x110ed93d94b86659,
+                    0x0264d78bc886c3f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde6c726eee6ba5f1,
+                    0x4caee4cd0eab495a,
+                    0xc74ad682c19e758e,
+                    0x016692fa0c550e62,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
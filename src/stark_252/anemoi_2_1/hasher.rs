@@ -0,0 +1,390 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::new(BigInteger256([
+            0x644f305fdb4bd00d,
+            0xba791af8e70aa73b,
+            0xf2ce27f04feea3f7,
+            0x00f24b95b16531b9,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0x5652829069c7d759,
+                0xbf885a8070d0d884,
+                0x0393bcc7cacdd5f6,
+                0x062d379121d35271,
+            ])),
+            Felt::new(BigInteger256([
+                0x33d204fe31aed456,
+                0xd074fe816a2e53b4,
+                0x88abcf9e6d4e1d6c,
+                0x02a891f4a303099b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x1012af83063c0aa7,
+                0x981206ef00db914c,
+                0xddb64ac69f7451f5,
+                0x015db951c09fd991,
+            ])),
+            Felt::new(BigInteger256([
+                0x08a44556fa6864c9,
+                0xfed09a879e2ef93f,
+                0x8b6d98ff9fa18ae8,
+                0x07db25c90cb167c3,
+            ])),
+            Felt::new(BigInteger256([
+                0x09d9a3c0ae774edb,
+                0x475026b999e12583,
+                0xc36dc3a780fee27d,
+                0x0243492d09721783,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd5d85a93cfef4dd2,
+                0x867b411781a75004,
+                0x7fd6d7e197caa6a6,
+                0x06e3aadfb7603f8c,
+            ])),
+            Felt::new(BigInteger256([
+                0x335a658e6887e6f0,
+                0x59e2e199720ed7a7,
+                0x67efb267381efbc8,
+                0x068283912dc4d15b,
+            ])),
+            Felt::new(BigInteger256([
+                0x7cee18bb0022ebdb,
+                0xade435901f477315,
+                0x361993897f26ed3e,
+                0x04bf14f987d1dd5d,
+            ])),
+            Felt::new(BigInteger256([
+                0xbebc60132bd9b297,
+                0x006a807e8fac81bb,
+                0xb37d71eab1cde146,
+                0x049bd8c7196b0828,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x9115eaf537d09354,
+                0xb5d6a5f4bb370a7f,
+                0xae42aa6fbdcec5a4,
+                0x003b4dcf7110633f,
+            ])),
+            Felt::new(BigInteger256([
+                0xf9af48d8547fc58d,
+                0x0908ed6140068db0,
+                0xa20357c3414c40e4,
+                0x006bf055e9948074,
+            ])),
+            Felt::new(BigInteger256([
+                0xe87275504632b786,
+                0xc6e8ad8ba3cd128a,
+                0xed407364c2f0735b,
+                0x013c4073812cc54e,
+            ])),
+            Felt::new(BigInteger256([
+                0xd593f5923e040fe3,
+                0x29bf61edd3d023a6,
+                0xe14ffb3fdc01cef6,
+                0x02cc28c22cd2e92e,
+            ])),
+            Felt::new(BigInteger256([
+                0xc4d18617644da8c0,
+                0xcf2baa1f7efa65a8,
+                0x3f4f52329b5bf9f1,
+                0x069c2c17171b3eca,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x92b8bcee9eb77e88,
+                0x3eb5376cc4fc537b,
+                0x8e6148cf61eca95c,
+                0x010ecc9c627381b9,
+            ])),
+            Felt::new(BigInteger256([
+                0x5b6898882ce19170,
+                0xc86fe150579bdaa1,
+                0x1a1b242dd27c624e,
+                0x05fc264021e6a9b9,
+            ])),
+            Felt::new(BigInteger256([
+                0x67b0a014016b851f,
+                0x2c5b071d0cdbeb97,
+                0xef661f687c8d65ad,
+                0x0357deac999de026,
+            ])),
+            Felt::new(BigInteger256([
+                0x73a61afb6a56f6f8,
+                0xd7400b9a044aff14,
+                0xad8b13ee09f9c78d,
+                0x03576a2cb32527f5,
+            ])),
+            Felt::new(BigInteger256([
+                0x92ee56b82671e7f4,
+                0xa6c3db2f73a9a802,
+                0x09cf62c728b4c854,
+                0x020e15cfa23e7016,
+            ])),
+            Felt::new(BigInteger256([
+                0x5dc53e2925db2eb9,
+                0x071d80d9b32e15eb,
+                0xb638588482025404,
+                0x0543da1ef8cc13da,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xce5050876b28b8bb,
+            0xd4333f00080fd870,
+            0x44b9a6fad8c0f6cf,
+            0x000c97a7d7943d04,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc89bf24e3cf7f41f,
+            0xd41979fc45f37189,
+            0xb175f912f8ee6585,
+            0x02f7fae7acf76a9a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x13879525072b47ac,
+            0x78685a09be1969ae,
+            0xf0d581d8dcb8f2f1,
+            0x044e472ca377fede,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xca386ff5ea274235,
+            0x33df08c9edf1cacd,
+            0xd867d92b4cbe3e5e,
+            0x002194a853759b76,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0b44bb1424566a80,
+            0x71b0e93eb78cd3f6,
+            0xeaba42b126967927,
+            0x03c8551bba1e16d8,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbc6e8908ee1961a6,
+            0x1771d58a7d9b81e9,
+            0x47ce289f2bfc8cbe,
+            0x06bcf4bdb75313ce,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb7efb839147177dd,
+            0x6977576d3a4e90c0,
+            0x261aeeca789d5819,
+            0x0625d873e0e531d5,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x96a1328b1ee5c2c5,
+            0x16388dc324488632,
+            0xf9cbadd09076bb60,
+            0x00a4198f8e5fa209,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8537dd80bc8c25a4,
+            0x75392270aada313f,
+            0x0b82172fa1566959,
+            0x056bc001e0beb90a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xab68e4d4cfcfc0ad,
+            0x251ee067d828b0de,
+            0xda42b6e35ae5695d,
+            0x07afe5c41ce35a46,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0x35a4e81af5d97ddd,
+                0x8af2d206f65d2cfb,
+                0xe691f6cbce994006,
+                0x06f9f33a1ced5607,
+            ])),
+            Felt::new(BigInteger256([
+                0x54e4d746325c74d6,
+                0x12decc6f3d054b00,
+                0xb7a5b1df3139de70,
+                0x010809fba273f269,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb5b71665b356795a,
+                0x9da1c75cbc2360fb,
+                0xba48490a1e6b7d88,
+                0x06dcdc535c425834,
+            ])),
+            Felt::new(BigInteger256([
+                0x28e693251f9a9cf4,
+                0x31590fe16186be12,
+                0x2a9599453f5d3d5f,
+                0x03c723973a292618,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xec63272cf3b2c016,
+                0x3a027e74231847b2,
+                0xd76a0389a815ece4,
+                0x04c20fff38691797,
+            ])),
+            Felt::new(BigInteger256([
+                0xd0a8c26d3b9bb0d5,
+                0xcd8f675d7cdc47be,
+                0x1b1e005df37dd937,
+                0x035849636e4ec3ee,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x44d856b591783a4b,
+                0x289e7bc15c2ad571,
+                0x7b74cc0328e59beb,
+                0x051ab98450a8c1f8,
+            ])),
+            Felt::new(BigInteger256([
+                0xf036dc2f8ea72a2b,
+                0x7a3e2476881399a1,
+                0xb4dea7625039ab0e,
+                0x024b16f031c9f70f,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xbd0451fe9bac1163,
+                0xabe715fcc1b5efe1,
+                0x1017f5d9343205c4,
+                0x03d55b69f8ce9077,
+            ])),
+            Felt::new(BigInteger256([
+                0x2bf0b7c8e31da768,
+                0x010e9f0ff5bf35aa,
+                0xbaa17ba265f92187,
+                0x07919d83bdc06155,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xa284618d1ba0de5e,
+                0x8112ec02c93ba974,
+                0x4f6ced0ca4b989d0,
+                0x0528698bc695b998,
+            ])),
+            Felt::new(BigInteger256([
+                0xc89258b0c2953b8e,
+                0xf45f954c86fbf080,
+                0x031c9dd04c2b5e7e,
+                0x049c33adabed32c8,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0xe4c2a379283e78e6,
+            0x3688800eaeb24680,
+            0x7447b57863d81a9a,
+            0x00f5e9cf56062946,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8d80c3c4b999ff78,
+            0x28903347c1d5493c,
+            0x2839945632cad5ee,
+            0x01e2d098e666cdf5,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8e4bfd069d4c9922,
+            0x8b92eab5cf26ec6c,
+            0x4c98c3e1b5c873bd,
+            0x039c7283f243d11d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x00e440ec1bfa62f5,
+            0x79ad502f9fb95539,
+            0x68e8e75c1d7268b0,
+            0x0772fbefa80d91e9,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x1e8c4856a5dfe2a2,
+            0xbecc5e09428dd58a,
+            0x80b1c3eb31863fe3,
+            0x0394f5c554d65992,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x225c7972e6fc92f4,
+            0xf8acf7568adb29ae,
+            0xdd0660a6de6180c8,
+            0x05d0abfd53d2f09d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa3dff380525de133,
+            0x2931c8199f57b255,
+            0x75b4f27ee9f3f56a,
+            0x010d7754f721e952,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x449e20e340e96e0c,
+            0x4898e2783acbf261,
+            0x0c4115141298a135,
+            0x05a29320b91c7fc4,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x5dc473a032379d03,
+            0xf9c38c54ee1d424f,
+            0x292781e0210c4ab7,
+            0x004d540e88a5c3ea,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x7449bbae2bf481e2,
+            0x9774921158409938,
+            0xbd4d8d6e356ab8f1,
+            0x04b29adb3a01fc28,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+}
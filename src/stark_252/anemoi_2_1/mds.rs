@@ -0,0 +1,34 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [ 1  3]
+/// [ 3 10]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; (NUM_COLUMNS + 1) * (NUM_COLUMNS + 1)] = [
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xfffffffffffffec1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07ffffffffffead0,
+    ])),
+];
@@ -0,0 +1,599 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 64 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x559f1d74e2e506dd,
+                    0x9c6351679339b457,
+                    0xb7b75fee248784b1,
+                    0x030e4ae95aea2efc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa88e04c3fcedb608,
+                    0x5f8197d28cda37e1,
+                    0x8d1c07a63c1d325a,
+                    0x04d3a0888c29a6b6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb0d8cbebbfa9b819,
+                    0x2276d04ecceb56a7,
+                    0x5a8ba750819bf023,
+                    0x024dedb60cdac4be,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7a148068e4f1597,
+                    0xfaf16f7b7517fe3b,
+                    0xd1d99b70669a4f10,
+                    0x03f102dac1a0ca4a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x43de16e7e06d183d,
+                    0x6e8be2c66825c843,
+                    0x1a88dd468d0cc477,
+                    0x03de94be7dd52dd5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcfe5796674d6b879,
+                    0xf1357fb4df463735,
+                    0xc18f320a4ee7f048,
+                    0x06c11ca7a50bbeb8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x08b469edbfb4c2f6,
+                    0xd7bb9f106e390f4b,
+                    0x450c07e7118f5d31,
+                    0x059ac21e8d6acad4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeaf6fc2e8b8b4b91,
+                    0x24126673c33435bf,
+                    0xce09801813159e76,
+                    0x03652bf6ce81c7a1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5c982c5797e91c4b,
+                    0x729c9730a7b74cde,
+                    0x2fbca00ee7e916a1,
+                    0x06a0d5bdb636c163,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd995cbd958110f82,
+                    0x647a19302cbfc3ab,
+                    0x5e110baeb74eb572,
+                    0x034d36f9e3cea709,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6540fab994f24d54,
+                    0xa74f278879f7f167,
+                    0x7ada468a475da0cd,
+                    0x0215bd5e5ee4db29,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8903c243fac7789b,
+                    0xbc2185c42324aecd,
+                    0x0eb010d403af6246,
+                    0x050b8216add21851,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd5bf9f92f4bfe7cb,
+                    0x67b66ec5ad30cf44,
+                    0xe65ca73081f85e98,
+                    0x078d3c8363978db1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb388427f970550d,
+                    0xf421366e8b09687d,
+                    0x9161f4993843130a,
+                    0x07031364f2c44491,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x569fe1ba5078f968,
+                    0x6dc18ed3c28d1039,
+                    0xf7dfc6a190b24bf2,
+                    0x0077cc3b1cae8b51,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0599c4d3420b3faa,
+                    0x31d7c12c6a264dce,
+                    0x8ead4047cc4d39a1,
+                    0x07c28e61248b800d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x69387245ce49785c,
+                    0x997b001a711b71d5,
+                    0x8d39b555d9a710ba,
+                    0x010c18b6a1ff037b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe28614306df6a97d,
+                    0xc458a43b55b256a9,
+                    0x0f49482c310e1452,
+                    0x01ce96e84569b21d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc0b692987e2e33f7,
+                    0x4a9798926d769fc5,
+                    0xadc6aff8e6388de8,
+                    0x049ec5475d820386,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e7422bb5581ca0a,
+                    0x58c25fcd190476d8,
+                    0x5af675971d463dcb,
+                    0x037c34f98ac8f03e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x07ded62d8f946271,
+                    0x9350f0aab556415c,
+                    0x43959d07bd047d41,
+                    0x073c08711a4f3e23,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b1ee5761e1a3b06,
+                    0x3ab421e968e4c76d,
+                    0x03b83450a95eb984,
+                    0x04d05fb7ded9141c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x600f2e146517c751,
+                    0x729c3f510a56e3a5,
+                    0xd3229c7ceb373417,
+                    0x0524dac3efe7fe31,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7f36f0c72691c9bc,
+                    0x306cf8056b5e8ab5,
+                    0xb4a41e2b2625ca42,
+                    0x06e00f660b2e04d6,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x6e9c9ea49bd30eaf,
+                    0x2747993c8b454620,
+                    0xd0541841afbb57f6,
+                    0x053461a5240e1e66,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b371ce4052e21cf,
+                    0x4aa0abf1f52723a7,
+                    0x523b110c627a7b13,
+                    0x049efbd62d3d8419,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x378c342279009c97,
+                    0xc89ec1182a810a75,
+                    0x4602fe3222164007,
+                    0x00c21d7bc328c61a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7e57a994c5c60c12,
+                    0x732a3385f04b1fd3,
+                    0x6b39459a1f5f36e8,
+                    0x010baf79eb145c52,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0200292e33cffd2b,
+                    0x563fb5f3743e75f1,
+                    0xde78a76826935cf7,
+                    0x0554fe0909fb6083,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4b41fe34e0c3b3d1,
+                    0x9eb609df54722d22,
+                    0x7f78a3131366d470,
+                    0x00236d52e0d05533,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x07f9b0d8ab26431b,
+                    0x4ea4995699f0cb55,
+                    0xb129accbf529b887,
+                    0x054fa3adb2167e4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x95fca484e7ad6f85,
+                    0x4358653b9facf4c6,
+                    0xa6efc5aa65b030bb,
+                    0x01ed6986c2b4ccc2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xeca9ac5b8be03679,
+                    0xc934a4dcb3d54807,
+                    0x1f6f5e264e2ad651,
+                    0x04664c9aa94b90de,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8274b4fa9502b7fb,
+                    0x5f48ab2c01d4fe14,
+                    0x910a4099d5d4fc48,
+                    0x003a02e6275c1575,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5b9460d0f620a0b7,
+                    0x0f2ff6b7533d3cf7,
+                    0x3905ce356449b671,
+                    0x02949f9b35e26554,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09555931efef38d7,
+                    0x9a0d2fc624309c71,
+                    0xe0465d6c2b15b384,
+                    0x07805932248223d4,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xffffffffffffffa1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff9b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffec1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffead0,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffa1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff9b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe041f550ab5d741a,
+                    0x07299d126abab116,
+                    0xc7054b66d72ac930,
+                    0x03115527abc6aa90,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1bfcfcd607467e1c,
+                    0x601d8329355736ec,
+                    0xa74af340e7fad6a3,
+                    0x05d2fb4d309183ba,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb29330e0ca52c0cd,
+                    0x221d5ba9fb6269ef,
+                    0x87aecf008033e4c1,
+                    0x03e52be98465db11,
+                ])),
+                Felt::new(BigInteger256([
+                    0x96113c3724be4e78,
+                    0xd9824683e2725da2,
+                    0x0245b29b9ffae52b,
+                    0x04bb33367845ed76,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe3c623ccd61b189e,
+                    0x3261d3917194fd57,
+                    0x5ce290a160c7da49,
+                    0x05bf4601ac6c601e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf694699b6314fda9,
+                    0x35db8493a9312529,
+                    0x962054f735be634c,
+                    0x01613f57e615756c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc9ef9e67622e91a9,
+                    0x18adc90978f7a9a8,
+                    0xa5f8fdcb263a4ab9,
+                    0x0317e041fa34e481,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf3cb7fbb0e39247f,
+                    0x8d61c0580a93f1c0,
+                    0x98dabf0bd85f10e6,
+                    0x03350a4cb1537a36,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7407cb4b4ae85e6a,
+                    0xe70ea660b9544245,
+                    0xd28e1ff3cfa9cb2a,
+                    0x0514554d1f5fd13e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde8c16dc75bbd338,
+                    0x14749e4e2dd1c4e4,
+                    0x08b4a07544d25dc9,
+                    0x077702cd857b8921,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x77946c66c5ee4b39,
+                    0xdd578609bfcf124a,
+                    0xd9d8e679e58ad0fe,
+                    0x0115ab31a368d09f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x70129e6641ba1a81,
+                    0x3213c1e3639dd350,
+                    0x6dd110d9dbb62681,
+                    0x02c15ac70ebc95a3,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0xfffffffffffffea1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07ffffffffffe8b0,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xfbfeb6480dfe122e,
+        0xd42973e6fd7a78ac,
+        0xcf8569fe1a40aca9,
+        0x079817ad4f627bc8,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x364bfb4b89130222,
+        0xdf2f91795b8970c8,
+        0x6574ec5139c3b88f,
+        0x00fb393cbcadd3af,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xf3aac841154555a8,
+        0x55edd7c71a9dcf10,
+        0x086db79108b1cd4c,
+        0x0111c3fd45ee3085,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x33d622593364471e,
+        0xe60fc56bab5345a0,
+        0x928814b70fccea55,
+        0x0727b9c7ceb871b2,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xab0c42ca099b5b2a,
+        0x77be7eeb75f4f283,
+        0xd5b0e93d98ea3c2b,
+        0x00d6b8b36119d367,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xbcfd89316e851dcb,
+        0x60b97e066f67d02d,
+        0x75e9eb11f7561fc5,
+        0x07726e4115659650,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc3f9d606e9cabda2,
+        0x3d56ef7d63e44c63,
+        0x97408f3e741f5de4,
+        0x05eb054c1509c394,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xb9cc59ec944fed67,
+        0x7cafee18764bfb5c,
+        0x0f25696c57a528f6,
+        0x0048de517bbab7d5,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x87cb687b0eb143c5,
+        0x68c765b73a564086,
+        0x0f76c3516fe90cfb,
+        0x06e1849f7a0dd5ef,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x28fb19f6af648897,
+        0xebb5d3e2119be543,
+        0x5c0c4d8f36e4cf3b,
+        0x005117ef70e35f12,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xad50630a2033c612,
+        0x4e9fd327ced9664a,
+        0xd3ffa378ad686862,
+        0x047f1a666d34fcf0,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xffc5ae44f503f19e,
+        0xf438086f44ac9bde,
+        0xe4032763825767c3,
+        0x01e196b7b04a71c2,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x981f922813377605,
+        0x3cdb5c37252ba09e,
+        0x689a20bb08147619,
+        0x008e3977a512b938,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x2a30b4ffd840a4ad,
+        0x570190b927f8eadc,
+        0x83210b8686f438dd,
+        0x04ef598867e161c9,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x29a088565d330ca5,
+        0x4ee14143dcae08c6,
+        0x05e31dd914aadc74,
+        0x055cbc1ec958892d,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x346702f471a2220d,
+        0xdd96a62fa4a2252d,
+        0xe76c881499fab870,
+        0x029f2b12c68c7337,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xacffeb840b8cb263,
+        0xa7c8c1a5da02962b,
+        0x9c0c6323dd2b65a9,
+        0x06628934b485bc00,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xfb56a991b280e9e2,
+        0x41e8eaf03f4e4ec6,
+        0x82537d3b74b00223,
+        0x007d1588f6f1677f,
+    ]))],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
+        0xaaaaaaaaaaaaa941,
+        0xaaaaaaaaaaaaaaaa,
+        0xaaaaaaaaaaaaaaaa,
+        0x07ffffffffffe7fb,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x3c4d27430f19d19c,
+        0x6bcd8aec51fe1fb7,
+        0x16bb7d2483897660,
+        0x0380b6acdbdc9869,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc9c08d3753292eb6,
+        0x159233435e07ec45,
+        0xf513437ffe6051ee,
+        0x0005c4ac3d670eff,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc5cac29164deab9e,
+        0x55cdbe39a62a4d52,
+        0x4f6158bc2e8b58ee,
+        0x0026f6ab661eae5b,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x590610b3fd60631a,
+        0xa580ad0caa920e8b,
+        0xa3a79a6e05c0c0c4,
+        0x058d479ede112a38,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xd459a07d31af8a42,
+        0x0d7de03a694977a2,
+        0xc9ae8927c829c032,
+        0x062654d51c46c186,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x32a880ec64d7c0ee,
+        0x3967854a152481fc,
+        0x53642fc1610d38bb,
+        0x067a4b90cafbeefe,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xef1a2525916fa3db,
+        0x9b730070a8b98f25,
+        0x4fb054773050f063,
+        0x0675eecf85b56701,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xa4b60ee803012d3c,
+        0x83374fd87388c597,
+        0x024b7823a859ca4b,
+        0x0420a72911f509a2,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xc31bba3eb910bbd1,
+        0xdd91b76111b7d50b,
+        0x52ab2d2848ba1fac,
+        0x011b71f397c53843,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x693f0d16b5ca63f5,
+        0xe44469c7f8ac7abc,
+        0x216f103a0c7c4cc4,
+        0x03ff8df0f2bca9c8,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x0292914750caf31e,
+        0x9b37d39735ef8ca0,
+        0xe628d9f7fc1b2cb4,
+        0x0009465852ecb506,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x8c014234eca2fc9a,
+        0xc353e76d9f4a3c50,
+        0x7afaac585ffb4c40,
+        0x06c79d3089b1e8e1,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x4178be55c6535d04,
+        0xf2ecdc40ec582f57,
+        0x10463c1736415e82,
+        0x058da77c5ecba574,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x997736c3b2e500cc,
+        0x06d528e0515e95a1,
+        0x9df1aea56991f7ef,
+        0x074661113b68609c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x5bda19435b106789,
+        0xa1d4c8f80e23f372,
+        0x201f70168b5b9c21,
+        0x0663dbe23a0f9476,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x78d65a3599a805c3,
+        0x3a5f93706cc6e195,
+        0x94e6fe4672d45b0e,
+        0x05f5e0b7c51f503a,
+    ]))],
+    [Felt::new(BigInteger256([
+        0x294ac601a5dc324b,
+        0xd1a7cd202ead42f8,
+        0x5faaef5c8f76310d,
+        0x010724e7e91f557c,
+    ]))],
+    [Felt::new(BigInteger256([
+        0xfc49ea9ccd6d8fdb,
+        0xab3a2c8c0b0b4dbf,
+        0x5eb8c5f02957cfb3,
+        0x005c929dace1f084,
+    ]))],
+];
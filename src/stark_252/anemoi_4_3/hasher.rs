@@ -0,0 +1,772 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![Felt::new(BigInteger256([
+            0x5516e7892b618c94,
+            0x0fc05185d69f772b,
+            0x9a955b656a0954ee,
+            0x02f5e9b3d3d24801,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0x358a1b4490410989,
+                0xa997042a2f510ddf,
+                0xa7985f886d508c91,
+                0x036df81ec6036294,
+            ])),
+            Felt::new(BigInteger256([
+                0x2f5239b35eef2540,
+                0x2df9693c545edd1f,
+                0x85378e18457e180c,
+                0x0630be03067789e0,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xa967ea8db011580d,
+                0x69a3e2473b45acad,
+                0x1df665450d3444d1,
+                0x00943bc71296c91a,
+            ])),
+            Felt::new(BigInteger256([
+                0xd2a79f3d26bdcfe2,
+                0x57882b0342415d78,
+                0x6f2ac8116b229ebf,
+                0x0368bec43d316af5,
+            ])),
+            Felt::new(BigInteger256([
+                0x9a2c3e36c87d48b4,
+                0x566d6023b8405c74,
+                0xfa8910ac4ecb06ce,
+                0x04afd1ba529ddacb,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x1f21730732c33c5e,
+                0x3dc1d13bcad69955,
+                0x8400ff0fe55f892d,
+                0x036e0ecf00ea5aca,
+            ])),
+            Felt::new(BigInteger256([
+                0x80d35ff5774fe350,
+                0x0a4b363a86372df1,
+                0xb9e756983c725711,
+                0x02d94e8b8720a3ad,
+            ])),
+            Felt::new(BigInteger256([
+                0x80dfd7dc3b4857d4,
+                0xd316d4f0eaece282,
+                0x02102de54b7a0c18,
+                0x027114e012d45736,
+            ])),
+            Felt::new(BigInteger256([
+                0xa8bd722cbb1337e4,
+                0xb0cf1ec8798b2f56,
+                0xb5c1440cba1b1aac,
+                0x060669113e83ad79,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x5d55a67faffced2b,
+                0xa3c62f48ab521c52,
+                0x5620d69dd1729eee,
+                0x0126ef0ee10cc460,
+            ])),
+            Felt::new(BigInteger256([
+                0xccb92fe4c907b80a,
+                0x571d0d2084881b99,
+                0xeb08008ea6da857b,
+                0x048150dd5614214b,
+            ])),
+            Felt::new(BigInteger256([
+                0x5d9fe72e84438740,
+                0x4d007c84fe15d279,
+                0x6532c46d2ac23e1b,
+                0x0295a6873da6db4f,
+            ])),
+            Felt::new(BigInteger256([
+                0xd909b7aa33252d80,
+                0xae3120afc3bc46fb,
+                0x63bd6c265b226dfa,
+                0x05450850bd0110ee,
+            ])),
+            Felt::new(BigInteger256([
+                0x8bb656545484f079,
+                0xe56a3bb4a7455ad8,
+                0xa77fe1b13998b827,
+                0x07f1acea713041df,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x617105c627e6e733,
+                0x63d7a412c249be04,
+                0x9f55670f5a2c2a42,
+                0x053107d38c36f623,
+            ])),
+            Felt::new(BigInteger256([
+                0xa28932a0b6aace7c,
+                0x9a260a571a313286,
+                0x06714384d754c2bd,
+                0x005fa1610493154e,
+            ])),
+            Felt::new(BigInteger256([
+                0x5309c510ad8a0edd,
+                0x5875055ca9700091,
+                0x1c27f24730f851f2,
+                0x07df39964874105b,
+            ])),
+            Felt::new(BigInteger256([
+                0x19e50133c60ebf1f,
+                0xbf027ab940a66731,
+                0x805d3121ae046706,
+                0x03da9f37c9b5a1ff,
+            ])),
+            Felt::new(BigInteger256([
+                0xaef93efed4e87f96,
+                0xa486c876ef6045f6,
+                0xeeee670823bc8248,
+                0x078ada4913a298c2,
+            ])),
+            Felt::new(BigInteger256([
+                0x009d28f191325a85,
+                0xedf3492548d1eabf,
+                0x8e19c6847782dc36,
+                0x01a65323ecabb649,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x0c1f189fea39f848,
+            0x89f34e6c98c3af94,
+            0xc50d6257a0599865,
+            0x079b46da158ed9c1,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xb4358fe9de07951b,
+            0xaa11e69f2ef6a9cb,
+            0x33bc719db5f12351,
+            0x06a746ca315a4abb,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc8ec3e7b40406235,
+            0x9820ef7d587f64df,
+            0x7d3a938024fb135c,
+            0x00b564c805c63939,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc51617372b7b6503,
+            0x1ccd8668c40aead9,
+            0xf85babb4d440298c,
+            0x0607cfc8423c91c1,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xcb7fdaea31de448a,
+            0x96725f419e105367,
+            0x69b382490f2d2736,
+            0x00d5915acd09c7ba,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x83f43fa404a392a8,
+            0xd2610be7c3088a65,
+            0x8af3d2e62326965b,
+            0x0606b4d20cc8a653,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x02f3b7f955c1954b,
+            0xa6fa577f33b62c7c,
+            0x9273c7b0faa9426d,
+            0x061ffaa6e1ef76dc,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x8a7d18e81eda2a2c,
+            0x4f9e52baaa2f8186,
+            0xf537d9297fd963f7,
+            0x03e95705ddb23005,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xde8012127f626852,
+            0xf20b24f6e0b48186,
+            0x74eba87e2636e15e,
+            0x0207bb1bcbb92d95,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x753b246672f040d0,
+            0xf22d669767efe5aa,
+            0x6ed0450fcc336e6f,
+            0x05adf8531b26d5a4,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0x7b8e778c4f62056d,
+                0x98ed0854329053c5,
+                0xe1c3d5adfc649069,
+                0x058ba191346a1dc8,
+            ])),
+            Felt::new(BigInteger256([
+                0xcaf5cbb280541098,
+                0x6c897bb15584fb83,
+                0x4f3a2e7a4fc2cc34,
+                0x00f66b8efb7a8a1b,
+            ])),
+            Felt::new(BigInteger256([
+                0x6fc83205ad994242,
+                0x201b614bf097f22d,
+                0x42289cca54fe1c1b,
+                0x026bbcd5c9243d73,
+            ])),
+            Felt::new(BigInteger256([
+                0x9dc367aa81996c91,
+                0xa125d8e83be4eafe,
+                0xc344ec590bed1cfe,
+                0x017d3ae05a2ea4eb,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x6ee7ef021aea90c3,
+                0xaa8ba4ff85ac24af,
+                0x7e0425a91fed5faf,
+                0x012939da1b176251,
+            ])),
+            Felt::new(BigInteger256([
+                0x4191489c73fa294d,
+                0x078337dd8bc6f569,
+                0xadfb9c1a44b747bf,
+                0x0672e548fba78e79,
+            ])),
+            Felt::new(BigInteger256([
+                0xe8ac88946a52b667,
+                0xd46f1b0f961bfcb8,
+                0x783817714d0fa4e7,
+                0x018115bfe9483f11,
+            ])),
+            Felt::new(BigInteger256([
+                0x1cccacf14e742432,
+                0x694923c99791e279,
+                0xf63555382daf241e,
+                0x0492085e2e26c591,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x4aa645bd95fd9edd,
+                0x01c4683cb70f32a9,
+                0x21db167165050828,
+                0x015da64a66ec060f,
+            ])),
+            Felt::new(BigInteger256([
+                0xd0d8ae1d4f03d3c5,
+                0x3b37f313c1591999,
+                0x60bcedd9f4681026,
+                0x041b478840141de9,
+            ])),
+            Felt::new(BigInteger256([
+                0xb470f71fb3f6a2a0,
+                0xcfa78482ab8fb1dc,
+                0x4cc080f75527102b,
+                0x0327858e8c71953c,
+            ])),
+            Felt::new(BigInteger256([
+                0xed7dd3b19b1b8a23,
+                0xd722634ec3a02f9c,
+                0x3af1cc61d74d3a17,
+                0x0588792842b3e39b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xcccd923c126ed020,
+                0x66ffd81775608237,
+                0xc4b25484cf1c1983,
+                0x040408fc08c078ca,
+            ])),
+            Felt::new(BigInteger256([
+                0xf9760bc62e6dbc08,
+                0x361ee9c2fc630a78,
+                0xb8779d5524c13cc8,
+                0x061aaa9c80953c44,
+            ])),
+            Felt::new(BigInteger256([
+                0x9a81bc1062682de7,
+                0xef3eb8a0c001424c,
+                0xe8e07b0669609c27,
+                0x05ffbd5a867e7f97,
+            ])),
+            Felt::new(BigInteger256([
+                0xf9fbe44770432abb,
+                0x61b4ce5c0c11ba20,
+                0x5e32086381f6f780,
+                0x00cdf785a6d1f989,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x5d5954aa2fbd4a18,
+                0x395e4443cfd36167,
+                0x3c0f9e1e1cd5ad51,
+                0x047e899cceb80b6d,
+            ])),
+            Felt::new(BigInteger256([
+                0x428b82b2a35fbd6c,
+                0xd45410a83ab1dbbf,
+                0x8b5c0f4f4b9c9b99,
+                0x05baa5dd9ec5f9b7,
+            ])),
+            Felt::new(BigInteger256([
+                0xaa39c9a0f5d457b7,
+                0xbf05cf80582bc30c,
+                0x3034fb036da54e12,
+                0x0606d3a7819a8031,
+            ])),
+            Felt::new(BigInteger256([
+                0x9d30e87c2c64d45b,
+                0x204e5c7337ba3611,
+                0x2071814fe9cbe5f0,
+                0x076c52456285a43e,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xa7bbefcc78301414,
+                0x1c4fa5b5431b1166,
+                0x20643f85d6238d84,
+                0x006d61bfa95ed2b8,
+            ])),
+            Felt::new(BigInteger256([
+                0x35bb056b40623e42,
+                0xf7d5ceb5a820438a,
+                0x69ab443ad119f6ce,
+                0x026ba81e5eb080d4,
+            ])),
+            Felt::new(BigInteger256([
+                0xc832469cdd8b372c,
+                0x03225cb780067f81,
+                0xf61b2dc46153833f,
+                0x07511c4b76ad1e72,
+            ])),
+            Felt::new(BigInteger256([
+                0xb0e5684cea52f66a,
+                0x39580b83dac569db,
+                0x955b11c5ba261a9f,
+                0x07f680f439a8bfbd,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0xaaf660129aaf0d5a,
+                0x8d909a9182191ac3,
+                0x302b75b0041def3c,
+                0x0631bb21ed4a89fb,
+            ])),
+            Felt::new(BigInteger256([
+                0x5a7c493aa824529d,
+                0x476c48e85654243c,
+                0x997153a90ad665c9,
+                0x027e0c82ad3c79a6,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x1df28db157118fae,
+                0x3e2de1337ab1032d,
+                0xccad7fe5cc6a8c6d,
+                0x038e959a870695e0,
+            ])),
+            Felt::new(BigInteger256([
+                0x9ea9bf9f7a07fcce,
+                0x85939f144c31dcf7,
+                0x35b035fd0cfff4da,
+                0x01eb4b485f7b530f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xe89c64c1dbaba496,
+                0x05c6ee3a89c130c3,
+                0xb579c21d86671fed,
+                0x070a4f373e68b526,
+            ])),
+            Felt::new(BigInteger256([
+                0x97fe9332d02e700d,
+                0xa67463d82a27f0eb,
+                0xf798920389a92f81,
+                0x02607a6b228344f5,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x803bae1563e77d71,
+                0x10ffdb653b5227e7,
+                0x9041e861b5bc16a2,
+                0x05574a47ceb0300f,
+            ])),
+            Felt::new(BigInteger256([
+                0xdb9c78c742889dcb,
+                0x637440028d80646b,
+                0xd02bccf125f0a71f,
+                0x0634ba52c96564c0,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5cafb287c0013cef,
+                0x2734b512f7fc208c,
+                0xd40fab493c70e4bb,
+                0x0524078c36141e93,
+            ])),
+            Felt::new(BigInteger256([
+                0xd1ef5366e97b55b9,
+                0x22e62887bb2ef692,
+                0xc38731bedd0d7af4,
+                0x042efbe79b526327,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x694323a580e5835f,
+                0xe2c17c2c35cb8456,
+                0xc7e6120cd80b097a,
+                0x05ec84d285e2bf60,
+            ])),
+            Felt::new(BigInteger256([
+                0xb6ff5bca7795ae5c,
+                0xec31e5145f6f3488,
+                0xefab3fb29e16eac2,
+                0x003b78f7f6aaf55d,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x58be760fa9d4c877,
+                0x460510e137865da7,
+                0x9a338355d08f97c6,
+                0x038ce9d6395499a4,
+            ])),
+            Felt::new(BigInteger256([
+                0xf0b330232afcd167,
+                0x4ef32571caeeb2a7,
+                0x709fa161b8c3beab,
+                0x02e3e453576e2e21,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x5479876992bead7f,
+                0x6d13a699c7403d92,
+                0xe6522046fe715952,
+                0x056e2f132b1590d4,
+            ])),
+            Felt::new(BigInteger256([
+                0x1192fa2a5a20cd69,
+                0xfaf75369506f5104,
+                0x92160797c5bf3ef1,
+                0x06d7e66e7d42f0e5,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x0aac719acf7ef15c,
+                0xd50743d6c6f56cd4,
+                0x727af91edc66d520,
+                0x041043c6787ffb45,
+            ])),
+            Felt::new(BigInteger256([
+                0x9b8f50b699c9fe09,
+                0x6f697d704c4640e0,
+                0x5fa5d0c8c57a19d1,
+                0x046eeaef616b58bc,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x6a5ddffc1ed58866,
+                0x294804d76658b648,
+                0xe9595017a1cd8717,
+                0x03fdcfd368cac03e,
+            ])),
+            Felt::new(BigInteger256([
+                0x6b2b7986cf1a3a56,
+                0x27e629d05fea3c80,
+                0xb106fee5c4881bcd,
+                0x0088d9add9906690,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+        vec![Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+        vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+        vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        vec![
+            Felt::new(BigInteger256([
+                0x9a7776181f8550aa,
+                0x0ca3fc027e2934f5,
+                0xf96b3e0366f55dc7,
+                0x023cab6442985a77,
+            ])),
+            Felt::new(BigInteger256([
+                0x2f806adc4ed76032,
+                0x7a15673788b6fc7c,
+                0x7a83220b67242928,
+                0x04e2259c39f71884,
+            ])),
+            Felt::new(BigInteger256([
+                0x6fb7635af2788ebb,
+                0xd40f4f8a7184d2e6,
+                0x3e1d3002bffc313a,
+                0x05ea59f22c2ddedb,
+            ])),
+            Felt::new(BigInteger256([
+                0x5820aab8778d5ad7,
+                0x7f2b6e39716f8c3e,
+                0x674ab04ab5b3c315,
+                0x05464666621002a5,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x07fe78888ba707f4,
+                0x7b01ca748f20f628,
+                0x414941f889615f47,
+                0x00e526bbbcf16926,
+            ])),
+            Felt::new(BigInteger256([
+                0x987d275ec338e4e8,
+                0xf965b7366e76fcac,
+                0x27c1b0f8e834eda1,
+                0x008a807d09c050d5,
+            ])),
+            Felt::new(BigInteger256([
+                0xcdcea88a72290ad7,
+                0xa35475e0087866f9,
+                0x756a225412da9d9d,
+                0x01952f6ad4869bee,
+            ])),
+            Felt::new(BigInteger256([
+                0x7bcff5856b4639c7,
+                0x35e89f55b7d2ee94,
+                0xc0a5fb6c4a2cb3c9,
+                0x057b552a6b4b96a5,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xf4d571bb0eefef9f,
+                0x3ddd11e190a67c6b,
+                0xf1b55c5493372bdc,
+                0x00f701f89e2f07ce,
+            ])),
+            Felt::new(BigInteger256([
+                0xb27a5599ac7be472,
+                0xb655cac449564587,
+                0x7d26864b6feed707,
+                0x04dd5ff81ba261eb,
+            ])),
+            Felt::new(BigInteger256([
+                0xf50f55e040f220d4,
+                0xe43b656a69f49a4c,
+                0x339c96e6d10bfb8f,
+                0x00c11d47f7c3fc02,
+            ])),
+            Felt::new(BigInteger256([
+                0x7d36d2e86a56b890,
+                0x120f4e55f8bce802,
+                0x28d7e65f31c9de5b,
+                0x04687be69c754a76,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd29715ffd628766a,
+                0x5255e595cb52319f,
+                0x14692049d7f6d8d8,
+                0x04a395beeb6e56d6,
+            ])),
+            Felt::new(BigInteger256([
+                0xd6372ce944b98b6d,
+                0x095854f771fcff8f,
+                0x1b100623cf4e4de4,
+                0x068b80bb93150998,
+            ])),
+            Felt::new(BigInteger256([
+                0xce324335c16b4575,
+                0xd0d5e5ae112b0025,
+                0x37873588a3fdb238,
+                0x04041248e1963a22,
+            ])),
+            Felt::new(BigInteger256([
+                0xfc225a3fe19e17ad,
+                0xae4c2118ff2ecd44,
+                0xbdb81310085275ac,
+                0x01b85800f7b2ad27,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xc94d59c53da8b8f1,
+                0x7003732a69dca13b,
+                0x62da3db07b22fe2e,
+                0x03189c474cfd2a21,
+            ])),
+            Felt::new(BigInteger256([
+                0xb7859ed50f6f720f,
+                0xf1e0f6bc20da8f38,
+                0x09a438041619bdb4,
+                0x068932c3d056bc8e,
+            ])),
+            Felt::new(BigInteger256([
+                0xd1eec5564a2ae2f7,
+                0x6837f4bd71fa8dc7,
+                0x2b6d46f141965241,
+                0x026d62e1d9bbab4a,
+            ])),
+            Felt::new(BigInteger256([
+                0x410ea0d5942d4315,
+                0x65a9252a1854a9fb,
+                0xe5f0d01d89c25ffa,
+                0x07e1eea727bfe7fe,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x678e6d6940f8a159,
+                0x93dc5dd82dcf1535,
+                0xedb284c9a676359c,
+                0x0338e977e4957acd,
+            ])),
+            Felt::new(BigInteger256([
+                0x1eed381e83d29c57,
+                0xaced4f544b615356,
+                0x6c3376795f0c1767,
+                0x05aa69f0f13e24c2,
+            ])),
+            Felt::new(BigInteger256([
+                0xaf6ae0c0ce11962d,
+                0xa2f7083736c93388,
+                0xbcf1b67389a463f1,
+                0x051be74c848e7772,
+            ])),
+            Felt::new(BigInteger256([
+                0xbd178fd24f8f5fcb,
+                0xc624275af60f6f1e,
+                0x76e1cb48a45f1b9c,
+                0x03f593a13c7f3cb1,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x0572a94d42d35ff6,
+            0xd4fce379d86d3f00,
+            0xc99cc9590ef45505,
+            0x00afc7a49a870390,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbc9c4d50d1198c7c,
+            0xc3c18047c6e2e024,
+            0x025db5e2d96a8147,
+            0x0579e0e2e681e8f0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x809af7f4abda14a2,
+            0xac3b5212b3e921af,
+            0xad12542110104f6e,
+            0x016ac9a260ebfa0b,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x5bd826dca6701b3b,
+            0x74741b67c8d28c53,
+            0x606db552dbacbdc1,
+            0x038c049a981594bf,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xbbd8a4b2f14364a3,
+            0x121a9d97f580eafc,
+            0xf1b4579e1529b714,
+            0x026c310e536839a5,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x51d09080e6eb5cb8,
+            0x7a83b7d8ffacf0a9,
+            0x18a87c790d00d9d4,
+            0x0674e7a360ef9623,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xeb4e69a4e61cccf0,
+            0x642d01861445ed05,
+            0x0e3ce97f42fe0ab9,
+            0x03386056b6a1c44f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd77bb9b7648f9c82,
+            0x7f1df5886bea97bc,
+            0x264dbc7755254e07,
+            0x00c4c208edfd74d7,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0879e04c766a4533,
+            0xbbd756f4499b75a1,
+            0x2e1f73cdc39e3a74,
+            0x07346529217b24e3,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xcacd05b33402e192,
+            0xd93469ccab83485b,
+            0x1bee1004c94e9767,
+            0x060ddb66a8b3f901,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+    }
+}
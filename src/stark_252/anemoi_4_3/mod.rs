@@ -0,0 +1,961 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x4b1d92bbc92447b7,
+                    0xb68701af98786210,
+                    0x85e9ea702a629e83,
+                    0x0795aa8ce3c6415f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7121dbde2c300619,
+                    0x7b93c049a5a7eaed,
+                    0x70f4c81b5e0b35dc,
+                    0x04dda9fac806fde3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd391bedc2d5c6adc,
+                    0x2e8045a43bb6a185,
+                    0x0cfaa3086752b939,
+                    0x05c26557ad362e6a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8514d4e96602db92,
+                    0x2523d9ff52a5d95e,
+                    0x2ba7d0ef7fb460f7,
+                    0x07f5acea024d688e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb221f0c747df5bb9,
+                    0x6654d1149522a7af,
+                    0x856d69bddf81f57d,
+                    0x057d474119403058,
+                ])),
+                Felt::new(BigInteger256([
+                    0x911de293f5ed18e7,
+                    0x62f729d824baa280,
+                    0x755bde4f433cffa0,
+                    0x04a171b360792e40,
+                ])),
+                Felt::new(BigInteger256([
+                    0x297e5f8579598599,
+                    0x97aa967244b87d1b,
+                    0xbc9a1dad4e9c1e41,
+                    0x025a128e6778b89f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01029d294456dd8b,
+                    0x043bd4bc300b24a9,
+                    0xe1e1f0678825e79f,
+                    0x06ca66c12befe06a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcb2d678a689a85dc,
+                    0x6fe3c9ea56d2d910,
+                    0x109409c04e5b4e98,
+                    0x0148f625de269ab1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47d8e2a3b41c6458,
+                    0xfb020c0bee9645ac,
+                    0xd0861ebf361f1087,
+                    0x0505ae84eb5c5b7f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3ce1b7905896bbca,
+                    0xab4a030d10ed8e43,
+                    0x9ae6eaf4deb9a568,
+                    0x0690c920952409d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x584abedb149bba3b,
+                    0xf72617ca9252f663,
+                    0x7927964bdd785511,
+                    0x06883301beabb1e4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd4acbea214fa309c,
+                    0xb0146eab06d670d9,
+                    0x02ea337581def789,
+                    0x0744341bc9e27295,
+                ])),
+                Felt::new(BigInteger256([
+                    0x954e89a75a2a6b07,
+                    0xce0a5dbd88dcd85c,
+                    0xb8c195abf0bb645f,
+                    0x05c8fc4bf12bd975,
+                ])),
+                Felt::new(BigInteger256([
+                    0x21c7c444dbfac11d,
+                    0xac47f9eea1f51f9f,
+                    0x7cba61a1a75b70b6,
+                    0x0337c0d3d96f2473,
+                ])),
+                Felt::new(BigInteger256([
+                    0x28597eed1ff91786,
+                    0xa2e3277120436b79,
+                    0xd4c51d29b6a99f67,
+                    0x005f088ccb29084c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2d3134b5a62ccdf0,
+                    0x6ae4a9ba03311103,
+                    0xbb57d3ea36efdea7,
+                    0x060c4aa834ae9927,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67b8295c47a44560,
+                    0x5326f28eb50ba1c7,
+                    0x0a7e99676f1c28f0,
+                    0x00743508214206e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8f365961e385bd97,
+                    0x619036d618c394cc,
+                    0x7b67179bcf02dfeb,
+                    0x0258d96ce55fbc0f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc1dcce3ac5abeb2a,
+                    0x2e4d10b3c4ab1f8c,
+                    0xdb0f7df6c3897c9a,
+                    0x0166957cd37ca5f5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf8554b7475172409,
+                    0x23bf6cc5aedb4949,
+                    0x9cb9c5dbf788d5e2,
+                    0x05ed7785a238f561,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18541d6eb229b9df,
+                    0x3aba93ffac10e47d,
+                    0x6c44302e53858063,
+                    0x073559505f3647e2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5e02a7cef7cf013c,
+                    0x3646a8e2f123120c,
+                    0x0e8f6259c9da3769,
+                    0x0102e3e18ae6428a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7d80f78509adb093,
+                    0x184ef561726ead0f,
+                    0x47f29064829d8b85,
+                    0x072b8ed964fa4837,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd7006f945b9b7885,
+                    0x7f5105a6cbd49920,
+                    0xfd9320af15f84c37,
+                    0x0689e4c0dc6c3c32,
+                ])),
+                Felt::new(BigInteger256([
+                    0xad6bcb64ce4a4658,
+                    0x1c762b1f7be418a8,
+                    0x8a3740c028907847,
+                    0x0232f7df2017f57e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ac942913c2fb774,
+                    0x71cabf71b4c6e706,
+                    0x0b59029e5e916d20,
+                    0x07884d8b318fca46,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd27f5d764a7f4043,
+                    0x092502a82eaf1bb9,
+                    0x5c064daebb51f2b0,
+                    0x0239d9f0c2d5321d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3e15e3ee32bcf947,
+                    0xba021993f8256286,
+                    0x882f2a888b8e7cc8,
+                    0x0758fa3a8f53ded9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe4f9cb31d7d90531,
+                    0x3700c661c3006b6f,
+                    0xe44dbd19121b4ac2,
+                    0x05a186d7a042da2b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc20fb724c369592b,
+                    0x162f790fa785e5d8,
+                    0xd8e85b08ca4a94c2,
+                    0x055ef6ac95e494c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde72f4d91b8b33b7,
+                    0x32e27f3141b495a0,
+                    0x61310d5198e15184,
+                    0x071356e0fec01014,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd10280adab4f8fd5,
+                    0x0ba7ec95f20a6999,
+                    0x35fae2c7b9d6f192,
+                    0x00a7264407ca7fb0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6696e4f6cc1627bc,
+                    0xf47b827a76b10be6,
+                    0x94fbf9245b82aab5,
+                    0x057d13a343321e96,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90e6c6cf464e70f4,
+                    0xa5934ec7c4382008,
+                    0x7b2a47b2de981828,
+                    0x02e59d7decea965f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5da4e7a59b7f798f,
+                    0xf94945bd1a3c6d26,
+                    0x026ae61355597ef0,
+                    0x02db2a3c5b87fe00,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1c4cd72805d4e88d,
+                    0x68e87ad0655333ef,
+                    0x3dba6b028594957e,
+                    0x01811c6fd7bb852d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6b7cf70ffaa9074,
+                    0xbbeeefef62a8b051,
+                    0xc458e00d70921bbb,
+                    0x046849a0ca724984,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb4f532dfe427b78a,
+                    0xcfee0caa4acf0234,
+                    0xe381a611c4f39877,
+                    0x05079575b3b42b99,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e3d9f6f97692baf,
+                    0xd6d500d045bb30bc,
+                    0xaef8411533922b8f,
+                    0x01cf1f77c02eddff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xef642890f8405b86,
+                    0x7576c9293555eea7,
+                    0x1786cd67db1fceb9,
+                    0x04c7f73337ea0fbd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83adde0136582bd0,
+                    0x26c0c1b55d778254,
+                    0xf86da395d05cd4c8,
+                    0x0546a73701460fc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdf92d88a8bb672e6,
+                    0x627ec972622c353b,
+                    0xe0a086b1fba4134d,
+                    0x04d79ae7904018cd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3546a1557be8942f,
+                    0x7a979faa6100ce0e,
+                    0x95e26b69419a776d,
+                    0x030dafa51a4d3917,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xad1d127b10a13dcd,
+                    0x353906a98029987b,
+                    0x2415fe916e501e34,
+                    0x01351e6c788c8cec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeaa7045135942613,
+                    0xf1946911e6e7d482,
+                    0x4454bc399078faa0,
+                    0x0437070a92902e54,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01e9b6c524172d19,
+                    0xa15e2a1c3ea4bf69,
+                    0x4e5697f1994db8fd,
+                    0x042c3f2b4e2cdc7a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x190f3b7b4a8ef49c,
+                    0x3841b88aeb3a012d,
+                    0x4a1d6632cc043c79,
+                    0x0090dd74036dc051,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one(), Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x3f98c494dc2687ad,
+                    0x56f0b32e1656eb27,
+                    0x0ac27eee8ceebef7,
+                    0x040c8631e7dea3d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a9519adbc88ea50,
+                    0xf4a8f46e882ea86f,
+                    0xa8e8e6e288b117b4,
+                    0x063f29304c0c953b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xafae6546d7ff30e3,
+                    0x0f0d598632351e31,
+                    0x3afb2bacc55fe869,
+                    0x029174e2fb783f2c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47c9a8b69b996f4d,
+                    0x520927d3c8fed8df,
+                    0xc34cbdaed548ba06,
+                    0x0186ab4c3ff09446,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe5007766289ba098,
+                    0xf5b1dccb845dab4d,
+                    0xf7358117774c18f4,
+                    0x02c13f2dc449c897,
+                ])),
+                Felt::new(BigInteger256([
+                    0x940d93024ffb3b29,
+                    0xd9b2521c34d46ed1,
+                    0xf038cb91745bd459,
+                    0x078f522720e3e7bf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x23d0467a98ce1e9a,
+                    0xf449b8e2b68777ab,
+                    0x9661a2dd3b7eaef6,
+                    0x00bab77a6364485f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe9464ffe575ca018,
+                    0x46020a9ad38f11e8,
+                    0xdb84d6cf65e9d7ec,
+                    0x0628d9f461993229,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb948d7d827201e71,
+                    0xcfe8c473cd51b82a,
+                    0x7ebfb7b23b075aec,
+                    0x0469326082ab85f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcea88d91e19e6a44,
+                    0xc372b8b9c8283ad1,
+                    0xba5517d85a570001,
+                    0x00981f7407f41952,
+                ])),
+                Felt::new(BigInteger256([
+                    0x783c1ad0c62fb88f,
+                    0xc47c46fdf8f6c835,
+                    0xfddf8b8c636dcc45,
+                    0x059cb7ba3c18e084,
+                ])),
+                Felt::new(BigInteger256([
+                    0x79058df4ac8ba898,
+                    0xc810fca0ff8460be,
+                    0x77ca1b70aecda86f,
+                    0x006dc0ad4296a6ee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6097b6c5bff0ce11,
+                    0xe45380df92f623cd,
+                    0x3b9368e873f9c2c4,
+                    0x024f1fa536c1a536,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90af19c69a91474f,
+                    0xece356c8cdc37fd4,
+                    0x8fe5e213471e3b76,
+                    0x03bf0d6e58b8b1ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xefa4ff5635bafa5f,
+                    0x126c2e7aee9922fa,
+                    0x4fcf1caa7a503b49,
+                    0x03489b4533e893c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a4958d4d6c8d119,
+                    0x259c7ff9f9d78af7,
+                    0x0f4196d081928b80,
+                    0x008f3ef49720d296,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa1395fd372611d2e,
+                    0x408f6fb615b586a8,
+                    0x29d39a7385119ed7,
+                    0x01911b84fe63e4a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdef55ed13548f7ed,
+                    0x8a8cd00796625e4c,
+                    0x2b3a4ec333479667,
+                    0x072cc7b24fc21619,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2e17ef86c6f7351d,
+                    0xa0ae40e6e3e1b0a7,
+                    0xe5997bc7008644aa,
+                    0x006aee0158bcdf57,
+                ])),
+                Felt::new(BigInteger256([
+                    0x52d8aa86f81c4589,
+                    0x648ee5a283b81e8b,
+                    0xb305ce8689295696,
+                    0x02a0b53a487be431,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc0e7b4c401879734,
+                    0x4cecc65e0b5a514f,
+                    0xfa4bc4390c3e9265,
+                    0x003846b1c54bac32,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf3d4207f2134bf60,
+                    0xd11b22141ec156d3,
+                    0xabe64574bb29aaf4,
+                    0x0758100449f6864c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a5406f2e048ea1c,
+                    0x5e75c75c6acc2800,
+                    0x546cdc7b2446c1d0,
+                    0x00be75f6ccb71cb8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x502d28130ceda409,
+                    0x5820dfc3d319de3d,
+                    0xb1c96ba128ea4837,
+                    0x06d9f39de545407c,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xef58119e11c1469b,
+                    0x34eb9079aee2e474,
+                    0x057d339627020616,
+                    0x06ca01c2cc046369,
+                ])),
+                Felt::new(BigInteger256([
+                    0x089d4e87f1ccbe1e,
+                    0x936ba5db94d755ce,
+                    0xb96081a4fdb729f7,
+                    0x029d2e78b019bf43,
+                ])),
+                Felt::new(BigInteger256([
+                    0x56d4d88b239701f5,
+                    0x7f3134665f9e3374,
+                    0x743e40b525687341,
+                    0x013b09f5325951ba,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb42ceee842c436c2,
+                    0x8ca0f6b9510fb88e,
+                    0x97b5edcc3599422d,
+                    0x064292c29284345b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa129306d188d5210,
+                    0x82c8d32022daf7c2,
+                    0xc7dfe3cbd45f9602,
+                    0x016f35a326f57fa4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7789244999a33158,
+                    0x620ccb7c9d655619,
+                    0x47d876f4f17a9661,
+                    0x03dcf31095c4669d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x54b7236e21c6fbe5,
+                    0x22df3542f72578ea,
+                    0x9ea9bf671865e4d1,
+                    0x005900638bc60b37,
+                ])),
+                Felt::new(BigInteger256([
+                    0x21f5b0c4fe231249,
+                    0x5ce758ab9bf7e26a,
+                    0x725ee11284b05d6a,
+                    0x01c5b8a506b66a06,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2542808dcbfb5d3d,
+                    0x1a40eea125ca68a0,
+                    0xadbeff3b4a0c5af2,
+                    0x063190bc9a87d1e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e700f3b459081f9,
+                    0x1235849d398774b2,
+                    0xc392158a387c10d8,
+                    0x032cd1a9d78b8eed,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe1b9de66ff1ad243,
+                    0x1585d19aea68b95e,
+                    0x7168be15d9170d41,
+                    0x0143e7dbf6e1485b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1d69b605c3802f57,
+                    0x050dbbceb830f452,
+                    0x5219c5cdeeb2f409,
+                    0x01686f4e20bcb986,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x12a504198fa4a3fd,
+                    0xaafd8539fc40a34b,
+                    0xeb450f2249547529,
+                    0x058c47f040ebbaf0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc89e2613497f3344,
+                    0xeddbe676c28569b5,
+                    0x51b50f7a231b9af3,
+                    0x0463e53f1b7be29f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd93856d777f9c035,
+                    0x5ce10b6ac5a2f3e7,
+                    0xfeaeeccff0833d5b,
+                    0x026910c432da8dd1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b4e03dc9da83afd,
+                    0x290f50bb3f81feb2,
+                    0x4bdbe31a4bd9f35b,
+                    0x0283cd91cc783d29,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3e197c47123c04f3,
+                    0xe035dfccd8dca18f,
+                    0xab8286bd1ee8620d,
+                    0x0717729bedaa26d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9941d3a66bfd06c3,
+                    0x2b2e6f6e20f842fa,
+                    0x2dc1e2fa9000bc91,
+                    0x04731f8618c08a58,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd20791b4d01e4e0,
+                    0x4699a8572f5d3080,
+                    0x63d241db8abc2496,
+                    0x03e17f3e52b28239,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5795ad8adfce3bc,
+                    0x747b39ec71f94229,
+                    0x11104159a0bab26d,
+                    0x040f6bbc50d465f3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9c6416416525d552,
+                    0xc03e2c9a679e55cb,
+                    0xfdfe94973dbb9343,
+                    0x064076bea32f3ef6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc900634350a63f53,
+                    0x11d5a7e3559c5836,
+                    0xa5e2033a745c64c0,
+                    0x02197440338442fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f293cebadc8625c,
+                    0x738235d9137e563e,
+                    0xaf10011295be8da8,
+                    0x011555824b6a9694,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd7cfbdb5e9a21130,
+                    0xb8fc68e7a5472aba,
+                    0x619cdfb2e5826ac9,
+                    0x03fe767daef6e076,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
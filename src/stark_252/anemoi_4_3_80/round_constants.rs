@@ -0,0 +1,291 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xfffffffffffffea1,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x07ffffffffffe8b0,
+        ])),
+        Felt::new(BigInteger256([
+            0xd9a51415d93746c2,
+            0x65a965c6c42d6733,
+            0xea613593a1f0cfd2,
+            0x049e4416a959b21c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfbfeb6480dfe122e,
+            0xd42973e6fd7a78ac,
+            0xcf8569fe1a40aca9,
+            0x079817ad4f627bc8,
+        ])),
+        Felt::new(BigInteger256([
+            0xc3609de584c4e331,
+            0xfb3d2640675a8c21,
+            0x7376547cd59c599f,
+            0x060a8fda78999521,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x364bfb4b89130222,
+            0xdf2f91795b8970c8,
+            0x6574ec5139c3b88f,
+            0x00fb393cbcadd3af,
+        ])),
+        Felt::new(BigInteger256([
+            0x73acc413f534714e,
+            0xfddd5a77f657abfb,
+            0xf05b9318c0eae61b,
+            0x05637e8f6bcf83ca,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf3aac841154555a8,
+            0x55edd7c71a9dcf10,
+            0x086db79108b1cd4c,
+            0x0111c3fd45ee3085,
+        ])),
+        Felt::new(BigInteger256([
+            0x7719a15fbed79504,
+            0xd8e33794a80c0f5e,
+            0x20f3b5c9ec86b73e,
+            0x01def8997bb0a5ef,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x33d622593364471e,
+            0xe60fc56bab5345a0,
+            0x928814b70fccea55,
+            0x0727b9c7ceb871b2,
+        ])),
+        Felt::new(BigInteger256([
+            0xcb72973907af855d,
+            0x94cd147bf36b7b84,
+            0x39a6113657564859,
+            0x06bcbe350497e60e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xab0c42ca099b5b2a,
+            0x77be7eeb75f4f283,
+            0xd5b0e93d98ea3c2b,
+            0x00d6b8b36119d367,
+        ])),
+        Felt::new(BigInteger256([
+            0xfccc5efb96271243,
+            0xf02fd3be55918d32,
+            0xdda5b8de28838dac,
+            0x010f1bf6c5f2d7c4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbcfd89316e851dcb,
+            0x60b97e066f67d02d,
+            0x75e9eb11f7561fc5,
+            0x07726e4115659650,
+        ])),
+        Felt::new(BigInteger256([
+            0x4f3158beba0e6f7d,
+            0x556a6fa3cebeac83,
+            0x665954eb26971e3d,
+            0x016720746984f19b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc3f9d606e9cabda2,
+            0x3d56ef7d63e44c63,
+            0x97408f3e741f5de4,
+            0x05eb054c1509c394,
+        ])),
+        Felt::new(BigInteger256([
+            0x1cec2a825d7ac055,
+            0x95814174d5f86a98,
+            0xdc2ce5f0d11f68da,
+            0x02d39c95cb79c241,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb9cc59ec944fed67,
+            0x7cafee18764bfb5c,
+            0x0f25696c57a528f6,
+            0x0048de517bbab7d5,
+        ])),
+        Felt::new(BigInteger256([
+            0x948e7a7332f4a89e,
+            0x370b6c8e1b09e8c0,
+            0xe93874fba35859a1,
+            0x02f120c19fe6ed71,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x87cb687b0eb143c5,
+            0x68c765b73a564086,
+            0x0f76c3516fe90cfb,
+            0x06e1849f7a0dd5ef,
+        ])),
+        Felt::new(BigInteger256([
+            0x991eb23ce8fbfd70,
+            0x019853cae668c06f,
+            0x316a2256c95279ed,
+            0x00eb3241411c0ef4,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xaaaaaaaaaaaaa941,
+            0xaaaaaaaaaaaaaaaa,
+            0xaaaaaaaaaaaaaaaa,
+            0x07ffffffffffe7fb,
+        ])),
+        Felt::new(BigInteger256([
+            0xf0de40729d3c193e,
+            0x4f7de3e7600c13ac,
+            0x2ca79dbc31f24d5c,
+            0x067c7dad60627393,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3c4d27430f19d19c,
+            0x6bcd8aec51fe1fb7,
+            0x16bb7d2483897660,
+            0x0380b6acdbdc9869,
+        ])),
+        Felt::new(BigInteger256([
+            0x703d90929f3aca7b,
+            0xd20b10bbad1234fa,
+            0x52482521243bf635,
+            0x03d16870bc1c73ed,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc9c08d3753292eb6,
+            0x159233435e07ec45,
+            0xf513437ffe6051ee,
+            0x0005c4ac3d670eff,
+        ])),
+        Felt::new(BigInteger256([
+            0x73afd7b1d8a4c5be,
+            0x7369cfb7ea0a2947,
+            0x1795a7c56ade5259,
+            0x064c4395a3918147,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc5cac29164deab9e,
+            0x55cdbe39a62a4d52,
+            0x4f6158bc2e8b58ee,
+            0x0026f6ab661eae5b,
+        ])),
+        Felt::new(BigInteger256([
+            0xb5c81d6227cb12d6,
+            0x17ecf17d24cc8f6e,
+            0xff831472f7b715c0,
+            0x02d264de52e9e5f0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x590610b3fd60631a,
+            0xa580ad0caa920e8b,
+            0xa3a79a6e05c0c0c4,
+            0x058d479ede112a38,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d310745eb05c935,
+            0x9367cf92e3de463e,
+            0xe261546b32a0f1a7,
+            0x070085a2caf960bf,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd459a07d31af8a42,
+            0x0d7de03a694977a2,
+            0xc9ae8927c829c032,
+            0x062654d51c46c186,
+        ])),
+        Felt::new(BigInteger256([
+            0x92a83e60d7956936,
+            0xc51908833a1a1420,
+            0x693f16463d19e492,
+            0x003cf1af382887fe,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x32a880ec64d7c0ee,
+            0x3967854a152481fc,
+            0x53642fc1610d38bb,
+            0x067a4b90cafbeefe,
+        ])),
+        Felt::new(BigInteger256([
+            0x316ad22bc9bb3a7c,
+            0x6d424a5d65af6020,
+            0xdb6f571875a50a12,
+            0x024d375ad6240c74,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xef1a2525916fa3db,
+            0x9b730070a8b98f25,
+            0x4fb054773050f063,
+            0x0675eecf85b56701,
+        ])),
+        Felt::new(BigInteger256([
+            0xb49afb531e79ce6a,
+            0x32c725de0c01af28,
+            0x2c3868a772a7ce39,
+            0x053cbfaff32e27da,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa4b60ee803012d3c,
+            0x83374fd87388c597,
+            0x024b7823a859ca4b,
+            0x0420a72911f509a2,
+        ])),
+        Felt::new(BigInteger256([
+            0xec06b120bb00104e,
+            0x7cbca1c4097ab4c9,
+            0x73fa4130d963cdd5,
+            0x00a7232fed2a0159,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc31bba3eb910bbd1,
+            0xdd91b76111b7d50b,
+            0x52ab2d2848ba1fac,
+            0x011b71f397c53843,
+        ])),
+        Felt::new(BigInteger256([
+            0x40fd85b2acb59d59,
+            0xb58c78eaaefe56c3,
+            0x0c3a49ab877a5f7d,
+            0x0503592c15dc3385,
+        ])),
+    ],
+];
@@ -0,0 +1,1358 @@
+//! Test vectors for the Sponge and Jive modes of Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use super::{AnemoiHash, BigInteger256, Felt};
+use crate::{AnemoiDigest, Jive, Sponge};
+use ark_ff::{One, Zero};
+
+#[test]
+fn test_anemoi_hash() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![Felt::new(BigInteger256([
+            0xadf68e849a88df52,
+            0x9e6c0941776fc61e,
+            0xf33c7b1bae58c640,
+            0x03739c6148bea37c,
+        ]))],
+        vec![
+            Felt::new(BigInteger256([
+                0x3b7e7c4329235dc9,
+                0x40e67bdf08b76b3a,
+                0xcbf4515e842b0236,
+                0x010b480355827000,
+            ])),
+            Felt::new(BigInteger256([
+                0xafc0072b614e7cd8,
+                0xd95bdc7f4139b616,
+                0xf483d036abcfda19,
+                0x077ea5e121a7d24b,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x4210bfa864952991,
+                0x975ebf8a29f347df,
+                0xeefccbed2f461389,
+                0x07cd02af9d18dc71,
+            ])),
+            Felt::new(BigInteger256([
+                0x472e1b028ca53440,
+                0x9e89ddad4ded8f20,
+                0xa7aad982784be202,
+                0x075634e2091a2971,
+            ])),
+            Felt::new(BigInteger256([
+                0x808e1cf97f5d2ab4,
+                0x925f3fe854a27581,
+                0xaa5f49e66ed62bf7,
+                0x01ae80c535403713,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x71c955452d5d8861,
+                0x3178e6e76c5ec873,
+                0x72db6ec8bd389581,
+                0x03d7c50d62f9f558,
+            ])),
+            Felt::new(BigInteger256([
+                0x45ac02dc692f9e45,
+                0x92235cda37835c7f,
+                0xff8dd139ddd49bd3,
+                0x04c26e6595099e32,
+            ])),
+            Felt::new(BigInteger256([
+                0x6fb3e6a8e9babf7d,
+                0xf062e9471a27ffaf,
+                0x844c49a30c1625c2,
+                0x05ae1683066f8bb9,
+            ])),
+            Felt::new(BigInteger256([
+                0x4c9cb8daf562fe6b,
+                0x29f2078e0789cc86,
+                0xdad2e2a967485b9a,
+                0x05a188eb655b43d3,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd594802c19f1ccb1,
+                0x926b595713f05317,
+                0xa6e2fd49935814a3,
+                0x0284722466ab344c,
+            ])),
+            Felt::new(BigInteger256([
+                0x2acbfc0d0fd010e0,
+                0x4abd50d3292265d9,
+                0xd3819c3658ac22fc,
+                0x03325c9de53d118a,
+            ])),
+            Felt::new(BigInteger256([
+                0x5d1bf91a034a94a7,
+                0x9150107657e89c89,
+                0xaa645a0834aeaa85,
+                0x0280941d2a49ffdc,
+            ])),
+            Felt::new(BigInteger256([
+                0xa5046a625112991e,
+                0xeb6b69c971f38ea0,
+                0x812bacc14fbf14e6,
+                0x03bf760e704d7123,
+            ])),
+            Felt::new(BigInteger256([
+                0x1cf9bf802eda2b64,
+                0x07ad3c88f97869f3,
+                0x11ec034dfae855e0,
+                0x05f1bb6d67cb7968,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xd8e2d9c0878371bc,
+                0x1ab1782290ef2334,
+                0x1a6123e4ea45f763,
+                0x044c32bb67fe8fbe,
+            ])),
+            Felt::new(BigInteger256([
+                0x4ce71b7b3f7e82f9,
+                0x99902ad1f71cf384,
+                0x458e71a0aca7c39c,
+                0x01c38e7c24477e20,
+            ])),
+            Felt::new(BigInteger256([
+                0xba33f0f2b10cb39d,
+                0x404adaf3fd5a0339,
+                0x2ca83b844985b136,
+                0x026d70c9920b8623,
+            ])),
+            Felt::new(BigInteger256([
+                0x347ed53bc6b385f0,
+                0x1c82968ed1c0a761,
+                0x06eee2a1cff6c69c,
+                0x0401319462e36a67,
+            ])),
+            Felt::new(BigInteger256([
+                0xbe368babe7b70a03,
+                0x587e02d654b7a025,
+                0x58d12e4bbf0aed1c,
+                0x0490c082382c5eae,
+            ])),
+            Felt::new(BigInteger256([
+                0xc2f40896d145bb4e,
+                0xcd48823f1efee95d,
+                0xa24cd8fbc80708ad,
+                0x07107e132e59e824,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x86ed786391a69b45,
+            0x914520df6b6a8eff,
+            0x8a241498c6cd8e42,
+            0x0120fd33c04faf3d,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x35a277c9ee97cedf,
+            0x6d9958720e0782a3,
+            0xae237d187647ad90,
+            0x00e85b6cc706b87a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x599a5128173a6f12,
+            0x44a7825bd7ac0923,
+            0x27eaac186ff56889,
+            0x047db880def53654,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x13f70b7edfa47650,
+            0xecf8bad316475a1b,
+            0x541351b2a04ff34a,
+            0x00c7a0906c9d6051,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xdb9def8bad589a7d,
+            0x7ea504a0d1e4c6d8,
+            0x709060abdaa42a2d,
+            0x033982c4ef25a501,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xd5b405ccb735cce1,
+            0xfe27483f961d5a4d,
+            0xf2f253f9a88fcfa1,
+            0x07a689e5b6a2e712,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x6c79e6aef9e924e6,
+            0x24bad9fa90511288,
+            0xd557b0a3edc0397b,
+            0x03c5b4949285f2ab,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xc654a120d3e42d6e,
+            0xad8714182025319f,
+            0x0b451a42a422bbe7,
+            0x07cae4e674f7b4c2,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xcf23926a22e75e8f,
+            0xc35a5c83994b68c0,
+            0x04a480fe247ad755,
+            0x0707859775a882b5,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x1c844a39b3b1dcfd,
+            0x454f76d288edb0a4,
+            0xdf5d14b998090068,
+            0x036460c6cd35fc1c,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+    }
+}
+
+#[test]
+fn test_anemoi_jive() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xa340cabcaaf550c1,
+                0x2e1673382acd8105,
+                0xfa9e8afadb0492c3,
+                0x01349596828fbaa5,
+            ])),
+            Felt::new(BigInteger256([
+                0x179833ee1f0133d1,
+                0xf47d59f4b64b5451,
+                0xfa8cc2b7029101ec,
+                0x000cc420a699f937,
+            ])),
+            Felt::new(BigInteger256([
+                0xd2e95705d85e5b54,
+                0xf2cafd0d7edbf9af,
+                0x156ac594c70d6045,
+                0x04ef904319c090a9,
+            ])),
+            Felt::new(BigInteger256([
+                0xe922729538ba179b,
+                0xa4c50b642739233d,
+                0x5be4b4f413ce4ec9,
+                0x04edfe7853d44082,
+            ])),
+            Felt::new(BigInteger256([
+                0x541df2a9d6238335,
+                0x01fa13c0a866935e,
+                0x31fa7bbcf749c48d,
+                0x0001e413c0eb5f8b,
+            ])),
+            Felt::new(BigInteger256([
+                0xa356dc486206f3f7,
+                0xe38c2e59f511b1f1,
+                0x093849981d3285ff,
+                0x072a7ced4d97910c,
+            ])),
+            Felt::new(BigInteger256([
+                0x26f14a8f85e35bf4,
+                0x37c619fdf9be064f,
+                0x5835a9b2f0506d3f,
+                0x033cdc4b4edd406b,
+            ])),
+            Felt::new(BigInteger256([
+                0x67fd2ed8b0546005,
+                0xdcf06803e3f7c304,
+                0x72a48ae35f4109e1,
+                0x004e0444f71bdc47,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3cb05372dee312fd,
+                0x69b10ea0d170d40e,
+                0x75f0783b541ef52b,
+                0x02b9617a61e25e50,
+            ])),
+            Felt::new(BigInteger256([
+                0x382e49f3be625c07,
+                0xdb525558a047bd10,
+                0xbadf506a66068a74,
+                0x0008d87c6895bc04,
+            ])),
+            Felt::new(BigInteger256([
+                0xeecf6495e8bf3033,
+                0xb14379c78628f450,
+                0xef186befb8a3674e,
+                0x0453360713f0fbdd,
+            ])),
+            Felt::new(BigInteger256([
+                0xc4f4a6e3379775f4,
+                0x53430a305f65ec1a,
+                0x2712e4bd684811d1,
+                0x0557c697259ece21,
+            ])),
+            Felt::new(BigInteger256([
+                0x539954d238f1c62e,
+                0x9791cba5901ebf70,
+                0x3e80402dd20a901b,
+                0x05edcfe920b5f6f6,
+            ])),
+            Felt::new(BigInteger256([
+                0x06e6fab7d5232e4e,
+                0x9cfa0a0d04dfaae6,
+                0x6965973d309d393d,
+                0x00558c5b28fdc9c2,
+            ])),
+            Felt::new(BigInteger256([
+                0x44df2dca0aed4579,
+                0x3a745a8e52c2cb57,
+                0x89de01a4d981f76b,
+                0x0663ac5a9899c057,
+            ])),
+            Felt::new(BigInteger256([
+                0x9f88a0dbac2cff38,
+                0xbe02285701358e19,
+                0x1de33b0af191cd63,
+                0x06f266532f639b68,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x6dc77dc8a062b44e,
+                0x1b4ecd4228db6ce6,
+                0xc76ea7ba7629dd1c,
+                0x0198d770cc7be1dd,
+            ])),
+            Felt::new(BigInteger256([
+                0x58d636ad83fde3c3,
+                0x45e50ea93cddd9fb,
+                0xfe14a75d6927cee0,
+                0x06059f1555b5b53e,
+            ])),
+            Felt::new(BigInteger256([
+                0x6e22c1593da14aa9,
+                0xfad8cee2c6f605fd,
+                0x77a8310bbea68aa7,
+                0x01b4b0e95433b400,
+            ])),
+            Felt::new(BigInteger256([
+                0xf0a78de706cfcf15,
+                0x53e8085eb00f9220,
+                0x77ef616b58d75f92,
+                0x05b06633af5575cd,
+            ])),
+            Felt::new(BigInteger256([
+                0x8a5921ac706cf1c3,
+                0x71a37b71accca98a,
+                0x3a876ffd9cfd69d0,
+                0x0328c58365cd96c7,
+            ])),
+            Felt::new(BigInteger256([
+                0xb55b2848894d3103,
+                0xb36ec45496b972cc,
+                0x5825b057d8495653,
+                0x02d96f8fb276a699,
+            ])),
+            Felt::new(BigInteger256([
+                0xb88d2599a281a0df,
+                0x7e5a9dbc7ce0d38d,
+                0xbee2ebc3a1c2b200,
+                0x0721ebc0e5982946,
+            ])),
+            Felt::new(BigInteger256([
+                0x31f07d2077843267,
+                0xf8083573d7870e70,
+                0x6dbcde9865fb51f8,
+                0x04f1f848c26a61fc,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xdcf34c1b18a49166,
+                0xfaadbcbe3c70528d,
+                0x3f4f1a8ab3cc94aa,
+                0x06255f72ec5ef0a3,
+            ])),
+            Felt::new(BigInteger256([
+                0x36736d144b8eff39,
+                0xc2d446b2a909ef75,
+                0xfdb6037503b4766c,
+                0x0565d4ea955b027c,
+            ])),
+            Felt::new(BigInteger256([
+                0x1dd6f8e6d4eb927c,
+                0x0b29df7d48913f11,
+                0x044dad6c2f72b75a,
+                0x02be689ca173f1d4,
+            ])),
+            Felt::new(BigInteger256([
+                0x88a96f7f168674f8,
+                0xd9997a024763e1bd,
+                0xfd3496f36b8f2292,
+                0x00f527154ae16e54,
+            ])),
+            Felt::new(BigInteger256([
+                0x427576232fcb1f09,
+                0xa5dec733c9c2607d,
+                0x3e6ee5e2b9f0affe,
+                0x04e973fe1ad8a121,
+            ])),
+            Felt::new(BigInteger256([
+                0x5097a2679d3fc04d,
+                0xb3f865304f95129d,
+                0x228c412135df7cde,
+                0x042327e531e82bf8,
+            ])),
+            Felt::new(BigInteger256([
+                0x2065902d2aaf5779,
+                0x21412a7e12371605,
+                0xcd81751aadbf0782,
+                0x036a67b1ff967b6e,
+            ])),
+            Felt::new(BigInteger256([
+                0x65b985ded85df7be,
+                0xd98453e1c8fcdd49,
+                0x48dfdbcf7cc0d2e5,
+                0x03a090c610876bba,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3d4668f5d1280092,
+                0xb81a0dda7fc9a2da,
+                0x74e6d682a6b43931,
+                0x00a7853994a789d7,
+            ])),
+            Felt::new(BigInteger256([
+                0xa38d96798819cbca,
+                0x1f465ab8085f5318,
+                0x6db3d15f7229ef61,
+                0x02ce5f63c2ac845f,
+            ])),
+            Felt::new(BigInteger256([
+                0x5c26bb9654118c73,
+                0xf84e042133642a39,
+                0xd9cbe6b84e4b4d49,
+                0x07c0124eeee80c10,
+            ])),
+            Felt::new(BigInteger256([
+                0x48569bbce7b9134b,
+                0x3d9a88724a8662d8,
+                0xc39c8d73af3e1d08,
+                0x074bccba93bae4c4,
+            ])),
+            Felt::new(BigInteger256([
+                0xb38688e3ade325cd,
+                0xa316a15149e620b0,
+                0xad636dfff164ec6d,
+                0x01da2b29326b42bb,
+            ])),
+            Felt::new(BigInteger256([
+                0x1bfee0dc4f49c4c2,
+                0xfd944ceb7257ffa6,
+                0x26108133a48acda2,
+                0x04c3f44019824235,
+            ])),
+            Felt::new(BigInteger256([
+                0xa7481fcd9fd32618,
+                0x7c8ce76dce527541,
+                0x8581c86d661acd06,
+                0x0305898d09323787,
+            ])),
+            Felt::new(BigInteger256([
+                0x98511d5f23da31f5,
+                0x38a73c21c27bd649,
+                0xb13045e5f1dabb34,
+                0x049ed8783d283378,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xcd4a7e3baf8e7838,
+                0xf0c4fb3081945a28,
+                0xbc0edf0430087fa0,
+                0x07553dc6d9e68a94,
+            ])),
+            Felt::new(BigInteger256([
+                0xbeefc69463ec90a3,
+                0xdb2ecf61b974621e,
+                0xc14f36ec81ed319c,
+                0x0672c7de5d2fbc1d,
+            ])),
+            Felt::new(BigInteger256([
+                0x3fec20756f9c7d62,
+                0x009fdabb6b42178f,
+                0x24f159abd2c96f52,
+                0x06076b5f00ce3210,
+            ])),
+            Felt::new(BigInteger256([
+                0x3abfa0480c23ffa5,
+                0x97ee41d0a5f028e0,
+                0x927b8e276bb1079e,
+                0x027abbb976a5c756,
+            ])),
+            Felt::new(BigInteger256([
+                0xd902d20b7687e5f6,
+                0xa3343c1abb662052,
+                0x047ca74554bdb8ef,
+                0x02c0cc9afcacda0c,
+            ])),
+            Felt::new(BigInteger256([
+                0x179e27761418e7c2,
+                0xe4d97fe9a2e070f4,
+                0x3756135b2c249f00,
+                0x050358dc9ed24858,
+            ])),
+            Felt::new(BigInteger256([
+                0x5b35fd04c2076bde,
+                0xa71e7ed44addc37b,
+                0xf4b123b26f342942,
+                0x00790e44031ffd59,
+            ])),
+            Felt::new(BigInteger256([
+                0xb2efd3957aea9b6d,
+                0x4b6246c60c9a772e,
+                0x3658b45d385c6277,
+                0x01a7dc5385e70b6b,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [
+            Felt::new(BigInteger256([
+                0x9fd5fe38b34b466d,
+                0x86c487757c69de7a,
+                0x30f17f672195604e,
+                0x00e097f86ad5f20a,
+            ])),
+            Felt::new(BigInteger256([
+                0xc9fcca32f6808389,
+                0x5cc5f46bfa7f6571,
+                0x0f36812b93bbbcc9,
+                0x012bd5876d7d4401,
+            ])),
+            Felt::new(BigInteger256([
+                0x66f2536ed05cc981,
+                0x08e4eb2945103ecd,
+                0xd91ba65445b086ae,
+                0x07d03360a01aa1a9,
+            ])),
+            Felt::new(BigInteger256([
+                0x66c0702120e74f30,
+                0x454d167655f5f549,
+                0xe01253825804b764,
+                0x02323f32cf94808b,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x55ee57654f1e5364,
+                0x91309b285ca53df1,
+                0x2caccb0d08ba4ad0,
+                0x05508b8814c65156,
+            ])),
+            Felt::new(BigInteger256([
+                0x67d4b55c056a7964,
+                0x5f226e57e5d47c54,
+                0xa1ed44aa6faa6ece,
+                0x00fd73bd5f32efc2,
+            ])),
+            Felt::new(BigInteger256([
+                0xc64bda2dca1ce250,
+                0x4f7df44872fca1b8,
+                0x9ac46a19c42e451b,
+                0x066f105c5acb58aa,
+            ])),
+            Felt::new(BigInteger256([
+                0xe23a0e880bd5c305,
+                0x368f4b8c0571dc03,
+                0xb730b0c833cb9d1f,
+                0x05e3ff2c8cac1659,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xda0a0aa6e0d40a9a,
+                0xd6a037077ed32c16,
+                0x5049d7ecf3ad3414,
+                0x078507252d995c86,
+            ])),
+            Felt::new(BigInteger256([
+                0x12674efb497f45ed,
+                0x65b6869585686ff7,
+                0x5e5ba8278e1d48da,
+                0x06edba815ef67f3c,
+            ])),
+            Felt::new(BigInteger256([
+                0x7374885e9c98fec0,
+                0xfa80abbea4f31bcb,
+                0x15f7d87766f8556d,
+                0x07ef762c2ce4ea8b,
+            ])),
+            Felt::new(BigInteger256([
+                0x05c3a3fa2081b589,
+                0xea4806a48d8f4c1f,
+                0x3c8951579792e7f1,
+                0x07f39a01c537f22b,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x94d1312add0aa667,
+                0xf6a8147c81eba7a1,
+                0x6bbfd553c9e379a1,
+                0x0493e0ed0c08b3d3,
+            ])),
+            Felt::new(BigInteger256([
+                0xf938cbb5932c8597,
+                0x4d9027c9db0fe8a1,
+                0x4fca97fe1508dad8,
+                0x02cdef65bf1da800,
+            ])),
+            Felt::new(BigInteger256([
+                0xba43701b0d324771,
+                0x834c7050a22d042e,
+                0xee1b6b532e2b2bc1,
+                0x06d82fa82a993b0c,
+            ])),
+            Felt::new(BigInteger256([
+                0xed0264462ee5186e,
+                0x9419a7b451baedf8,
+                0x40bed63f3f328f13,
+                0x03c8a9026e3baaee,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xabeab320c37ad57e,
+                0x1e45033724594baa,
+                0x3b8a3fecc49b20da,
+                0x03f0a0ec78baa4e4,
+            ])),
+            Felt::new(BigInteger256([
+                0xa1086177e5cd869d,
+                0xcd02418fc7189d1f,
+                0xcc65c9a5ede75b9e,
+                0x069aad48745a484b,
+            ])),
+            Felt::new(BigInteger256([
+                0xcac3eb5440b89167,
+                0x7ff56a6ff940c854,
+                0xaf42ac37822c6b09,
+                0x030f6fdab47ba459,
+            ])),
+            Felt::new(BigInteger256([
+                0x685cdd3703026975,
+                0x6501f79fad8566de,
+                0x4196c40eeec8fc01,
+                0x07c9088cda038c9b,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xfc24ab2704eca45f,
+                0x594862064e170b3f,
+                0x8002fce44f727bf2,
+                0x074de6f71d434067,
+            ])),
+            Felt::new(BigInteger256([
+                0xbe334a664f5d3a7b,
+                0xa399c4062e774937,
+                0x7e82af2e0e653d32,
+                0x0727a6c662dcbf67,
+            ])),
+            Felt::new(BigInteger256([
+                0xb949accac273fbf5,
+                0x298ca62af33467c8,
+                0xb715034f8b032b3a,
+                0x04a9b75df534017f,
+            ])),
+            Felt::new(BigInteger256([
+                0xc1e2a8ffca6c712d,
+                0xd03791be65b12393,
+                0xecc51b60a0472a85,
+                0x060e49c82e6f723e,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xca94cf414ef7cebb,
+                0xbe822661ac2b4f97,
+                0xd250fb0d6f634980,
+                0x01a4c14e849e9322,
+            ])),
+            Felt::new(BigInteger256([
+                0x706407363ed4544b,
+                0x0238b34a79d80d3f,
+                0x02732d97d5706f33,
+                0x075757efaf2210c7,
+            ])),
+            Felt::new(BigInteger256([
+                0x3d292a3fc0ca13b0,
+                0x3a49f8039f8fdf73,
+                0xb6898fb3f3f31bc3,
+                0x02b8277e3cf7b975,
+            ])),
+            Felt::new(BigInteger256([
+                0x07bdbbe5e9e99507,
+                0x14d8cf5123d99a20,
+                0xa4beccc695d60812,
+                0x0449768811c11863,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0x6fde62ec634f19ce,
+                0x54d9043083b45bd1,
+                0xb188c6414a61b05a,
+                0x026c1211ecc609be,
+            ])),
+            Felt::new(BigInteger256([
+                0x965f4d2a6afe598a,
+                0x401e6cc150fda85a,
+                0x586d915eba5f18d8,
+                0x027be0adf087d94a,
+            ])),
+            Felt::new(BigInteger256([
+                0xcf7fcba726f0f81b,
+                0x944d5c79973dfcf6,
+                0xa891d8faa8bbea95,
+                0x01cd12c7d027523e,
+            ])),
+            Felt::new(BigInteger256([
+                0x891477bbae785ece,
+                0xdcdfaeb314004560,
+                0x674cffe52e045785,
+                0x060fe8d0f66ff5d8,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xb609b7efcb96cdff,
+                0xbb524c0f6fad3371,
+                0x44d5b89ed4d68ac7,
+                0x062c1e85d490acb5,
+            ])),
+            Felt::new(BigInteger256([
+                0x725a8637f782eee1,
+                0xcb1c353f4dfa0358,
+                0xbfb2aec92afcba1c,
+                0x006b618f9542f673,
+            ])),
+            Felt::new(BigInteger256([
+                0x3a252d535c1f4bc4,
+                0x7604f38b1d8b2d20,
+                0x785574fcd43dac5e,
+                0x05e089d214e2a138,
+            ])),
+            Felt::new(BigInteger256([
+                0x45225887b098d975,
+                0x82997482d5e06bcc,
+                0xaef33e6bc806bf16,
+                0x0730bcfa15ee121f,
+            ])),
+        ],
+        [
+            Felt::new(BigInteger256([
+                0xda42348543a9756e,
+                0x07ffe9f8c0c06cc0,
+                0xd901277d2f40bc3d,
+                0x047f0afe619bb0fa,
+            ])),
+            Felt::new(BigInteger256([
+                0xb3c6e0b77a700959,
+                0x49e38808a229a22a,
+                0x6218349d04131ef2,
+                0x02702a90222e5658,
+            ])),
+            Felt::new(BigInteger256([
+                0xd1525fe4bd079733,
+                0xb826aa7f2f5fdfb5,
+                0x5d2132758fb60fec,
+                0x037edb0d057b5dc9,
+            ])),
+            Felt::new(BigInteger256([
+                0x35efa980b37d3d20,
+                0x2249100aea2dd1fa,
+                0xaf28a90aae254129,
+                0x0476ab81e0a95dca,
+            ])),
+        ],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+    }
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+    }
+
+    let input_data = [
+        vec![Felt::zero(); 8],
+        vec![Felt::one(); 8],
+        vec![
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+        ],
+        vec![
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::one(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+            Felt::zero(),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x929c5363fbe81841,
+                0x2f1fec342f492fd6,
+                0xdf15a2996b0aaf2e,
+                0x00b6e01f11950c1e,
+            ])),
+            Felt::new(BigInteger256([
+                0x933516db6a19e7fd,
+                0x2c958ab5ab1ed6b4,
+                0x8dfff475bb51b685,
+                0x058b05ef48d4c4b9,
+            ])),
+            Felt::new(BigInteger256([
+                0xc9ca0c0c79ce1c39,
+                0x677931dc544313cd,
+                0x95f14064dc1c2e10,
+                0x03fd1907c97f72d4,
+            ])),
+            Felt::new(BigInteger256([
+                0x3a3a40f0a9f02f9e,
+                0xd47084960364c602,
+                0xacfc3b1546090c68,
+                0x039e73a6266a28b8,
+            ])),
+            Felt::new(BigInteger256([
+                0xdfd924414477ca10,
+                0x69171b4560177379,
+                0xece1a0135abe1526,
+                0x0701fc27decc77b0,
+            ])),
+            Felt::new(BigInteger256([
+                0x95d1af15db6af1a6,
+                0xe572bd7accb979ff,
+                0xdff439fb78d1833e,
+                0x06a6cda46ebde7b4,
+            ])),
+            Felt::new(BigInteger256([
+                0xaf83312f7960c247,
+                0x146b49f184eacbac,
+                0x0afecd6af6aa82b3,
+                0x029fde44104e91e0,
+            ])),
+            Felt::new(BigInteger256([
+                0x43c9bc7d6d97a24c,
+                0x1c6b49caea34aa81,
+                0xeecf99f623a5e74d,
+                0x00c9ec3d174a4c90,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x156f057ffb50e9eb,
+                0x94e753d87b9dacb1,
+                0x6e96708b12b66dd7,
+                0x00e3061b21b3c984,
+            ])),
+            Felt::new(BigInteger256([
+                0xf06b73807a66afc6,
+                0xd02ce89ad1297192,
+                0xd2fa064ac967c38c,
+                0x02ce4da0621b623b,
+            ])),
+            Felt::new(BigInteger256([
+                0xae8956e79ac8758c,
+                0x1dc9f1c774d09401,
+                0xeb20520b94975aa6,
+                0x028ae3b57f22d68e,
+            ])),
+            Felt::new(BigInteger256([
+                0x984204025ae84454,
+                0x96fe6f3aedd815ac,
+                0xbaa4eaa0776ffa04,
+                0x05fab93f24385e7b,
+            ])),
+            Felt::new(BigInteger256([
+                0x4322915ac7a68340,
+                0x6c1f2418a1587f4a,
+                0xe633e531cb3a2ada,
+                0x0455c4978dec002e,
+            ])),
+            Felt::new(BigInteger256([
+                0x008c8d5d7265edfd,
+                0x05cedd774c8b456b,
+                0x77375679a5e796c2,
+                0x042d4fff5b65008d,
+            ])),
+            Felt::new(BigInteger256([
+                0x9972a7e0a1723d08,
+                0xfd2b22cf6e7892b7,
+                0xa9a613541d709725,
+                0x0324199764a0ba3f,
+            ])),
+            Felt::new(BigInteger256([
+                0x39a2d1a6c20d3421,
+                0x7dd88ba4231c9d95,
+                0x79723373996e3ecc,
+                0x0663c6d18482eb44,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x576718cfef92e029,
+                0x84207bd2f01da51e,
+                0x663d0c8c8cdc9bc0,
+                0x04a07a02157eef6e,
+            ])),
+            Felt::new(BigInteger256([
+                0x00198b9288dc64af,
+                0xbd52f2070ab643ec,
+                0xd478bb296de19ef1,
+                0x013185971fb1822d,
+            ])),
+            Felt::new(BigInteger256([
+                0x4e8b10511bcac36b,
+                0xbcefbdedc30c2cab,
+                0x38178d8c96208e7c,
+                0x02914b9668ebbf32,
+            ])),
+            Felt::new(BigInteger256([
+                0x10ca3bdc88561eca,
+                0x1ddb64cf7d6724c6,
+                0x3e6c0791e4a76008,
+                0x06b1934774ee84c2,
+            ])),
+            Felt::new(BigInteger256([
+                0x6ce430791be53e79,
+                0xfe687fba4fdbfe4f,
+                0x4c0d969c32314b19,
+                0x07692bea8d8d4589,
+            ])),
+            Felt::new(BigInteger256([
+                0x260dacebb291417d,
+                0xc5dfd2c97810d92d,
+                0xfca394364128f19c,
+                0x07e3ff99c3785c63,
+            ])),
+            Felt::new(BigInteger256([
+                0x1ec14d8690b98fb9,
+                0x993b9fbf8415cd27,
+                0x6138f18cebbf8643,
+                0x068df6a1ec3485d8,
+            ])),
+            Felt::new(BigInteger256([
+                0x2926c01175a32212,
+                0x0be0caf185379eaf,
+                0x0320a3c9fdc4f923,
+                0x0502ce24a3654a05,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x9a7f7a83f3e84247,
+                0x4045b487cd4285a2,
+                0x240fbb9f7719217e,
+                0x03373ecf7b0cc583,
+            ])),
+            Felt::new(BigInteger256([
+                0x4a5ff4bf4cf97f62,
+                0x5fd643b09f61e383,
+                0xb3459a4e376c1826,
+                0x0558a358c1106444,
+            ])),
+            Felt::new(BigInteger256([
+                0xf3b5e2164cbaba3f,
+                0xa3259f1916ca7953,
+                0x9244a95cbd005705,
+                0x061b5229efcd9d0f,
+            ])),
+            Felt::new(BigInteger256([
+                0x913e012583b37393,
+                0xdeaec73ceffca363,
+                0x33151d2c9f080fec,
+                0x0372d518c4500c30,
+            ])),
+            Felt::new(BigInteger256([
+                0x816d7f9ad8e783af,
+                0xce9c271ed07c5cc2,
+                0xe477f3fbf8d6c32d,
+                0x03c8d65f70c7edd7,
+            ])),
+            Felt::new(BigInteger256([
+                0x99a26bdfde20b4ef,
+                0xe176890c767ad776,
+                0xd861d851042e4aae,
+                0x03c8862af8584ab8,
+            ])),
+            Felt::new(BigInteger256([
+                0xf48a9bd2ad530820,
+                0x7163f05c69edb6c5,
+                0x46a1e23b2682beaf,
+                0x0772711422aead5f,
+            ])),
+            Felt::new(BigInteger256([
+                0x71445acef8820c5e,
+                0x90f9adefa97a787c,
+                0x1ff724875f412c84,
+                0x00f44fb5c329a286,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xba7385a8686ae6b0,
+                0x48409243b88dcef7,
+                0x2253e6d4ec1cdf36,
+                0x04bb27151a122ede,
+            ])),
+            Felt::new(BigInteger256([
+                0x3b4dd2fc4d865b2c,
+                0x627078380bab3783,
+                0x3e9490202c75dc44,
+                0x066ad9238ff354a0,
+            ])),
+            Felt::new(BigInteger256([
+                0x57779cea618080cc,
+                0xb67db5a30451b597,
+                0x80ce451198640042,
+                0x014a7138aada328c,
+            ])),
+            Felt::new(BigInteger256([
+                0xee0fc78b4a027e19,
+                0x74a274fb616eb1aa,
+                0x493fae85596f481e,
+                0x00d67e0949b19205,
+            ])),
+            Felt::new(BigInteger256([
+                0xabee44adc3a38407,
+                0x8dd5562a8f0db306,
+                0x0fb412e3959de7c1,
+                0x0055ebf1cbd9beee,
+            ])),
+            Felt::new(BigInteger256([
+                0xa38d734ac60bd42f,
+                0xcd1fde957d864075,
+                0xdbf1e4553e5148f8,
+                0x0340305c08792d7e,
+            ])),
+            Felt::new(BigInteger256([
+                0xd3184cec21216b4e,
+                0x87cc0754387bc604,
+                0xa49710f800c69702,
+                0x040a5f55f2c8ac68,
+            ])),
+            Felt::new(BigInteger256([
+                0x71d08ec7e1e6fc98,
+                0x40b788f35657822b,
+                0xd7d79a50d61c4258,
+                0x01e12ae5135ec1d2,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xaeda6745bd3aa87c,
+                0x432fece1a1e47026,
+                0x4549b4ee018a7107,
+                0x01f753848a9a9b42,
+            ])),
+            Felt::new(BigInteger256([
+                0x64c30d08c9ec1c02,
+                0xe231002ece46091b,
+                0x82c9a2f75a7f8dde,
+                0x03d5c90033b2712b,
+            ])),
+            Felt::new(BigInteger256([
+                0x9a8ba47720037454,
+                0x42e57a45b0c7fb14,
+                0xfc12bdd15cc5029d,
+                0x03677f5717f1520f,
+            ])),
+            Felt::new(BigInteger256([
+                0x9a60038bbd3486ac,
+                0x440aebcb8171e87d,
+                0x358ee57138178185,
+                0x0109b0a0520e3ac9,
+            ])),
+            Felt::new(BigInteger256([
+                0xaf07ae708c51a964,
+                0x316a6fffb3ac04a9,
+                0xc3cc63b582885d68,
+                0x079ff05d4d201367,
+            ])),
+            Felt::new(BigInteger256([
+                0x05c33c79494f5180,
+                0x46b16c9c8db24e15,
+                0x91ca70fdf261bd00,
+                0x06424cd198d8dc8f,
+            ])),
+            Felt::new(BigInteger256([
+                0xfde7d6f4cbe14d0b,
+                0x6e5a0d5b03447670,
+                0x8b9ced107e1bf762,
+                0x0266369f5a2ea95d,
+            ])),
+            Felt::new(BigInteger256([
+                0xe13ca8a9f13011d7,
+                0xaf3002553f795c34,
+                0xc48b75b1fb85c958,
+                0x061d0a3bd19e8df3,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x37858bfb9b0fe2a6,
+            0x31bc7d8111ef7803,
+            0xf955fa6953065b2a,
+            0x040ee0134802582f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x6648f5772a7b721b,
+            0x76604954bae83802,
+            0x208f2a99705e9bd9,
+            0x02a10ece5b70affb,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x65a985fae76e04cd,
+            0x211f700036be03f8,
+            0x0126a9e38055ba4f,
+            0x0655d1d47eacb846,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x354fd141ac4e8bdb,
+            0x5b9e544b50e3826b,
+            0xea64aee44c4a0f4f,
+            0x0202a8fd63fb41ac,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x0ade8ee3b33cbea1,
+            0x3eb750ea248890c0,
+            0x09b9756e736b8d3d,
+            0x0622af0bcd09fe10,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4018a7ca683e05c1,
+            0xb01134eb187bc664,
+            0x265cec2de6cd811b,
+            0x067759b004754949,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xa3fd90d8943518fd,
+            0x3206f1efc271b784,
+            0x5954be092d6ef3f3,
+            0x053ca904a7f49c5b,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x35b39901dca99be7,
+            0xc93176e04e7881fe,
+            0xcb111d67f653a9c7,
+            0x041c73b9a3df4e0a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x62086382292360e8,
+            0x9aa949acd6858140,
+            0x68f0ed51c448e6ec,
+            0x0562e5dbadb1f25f,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x870f437fb66f06e9,
+            0xb99750b4f47822a2,
+            0xb3ff51a65eec8253,
+            0x01a03b52b83b4323,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+    }
+}
+
+#[test]
+fn test_anemoi_merge() {
+    // Computed with scripts/anemoi_model.py, which reproduces
+    // the test vectors of the reference implementation
+    let input_data = [
+        vec![Felt::zero(), Felt::zero()],
+        vec![Felt::zero(), Felt::one()],
+        vec![Felt::one(), Felt::zero()],
+        vec![Felt::one(), Felt::one()],
+        vec![
+            Felt::new(BigInteger256([
+                0x96a30bf3bbf94363,
+                0x3353573f525ea1db,
+                0xa207665379a30ab8,
+                0x04b26b0d6ba8653b,
+            ])),
+            Felt::new(BigInteger256([
+                0x689834f50607767f,
+                0xec21e3918bbfd4b3,
+                0xe172e52742c53af3,
+                0x00eccbbeb95fe9c1,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0xb18213b31f6c67d9,
+                0x7a2bd919ac5cf499,
+                0xbe54493d910d5caa,
+                0x04bf201959afced3,
+            ])),
+            Felt::new(BigInteger256([
+                0x599af91901d3a3ff,
+                0x87ca36c472a2010f,
+                0x4fc4cbd6a802308b,
+                0x004bd88e52bbd3b9,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x2f8a5aefc32a3176,
+                0x4c13092b356087e3,
+                0xa696be85a8b46200,
+                0x0534ace3c4d10eef,
+            ])),
+            Felt::new(BigInteger256([
+                0x84a6b2d5f23674d0,
+                0x4a75ae45bf1d02b4,
+                0xc69bf28665532997,
+                0x00c8f6b9553a98eb,
+            ])),
+        ],
+        vec![
+            Felt::new(BigInteger256([
+                0x3c9660d12aa71d00,
+                0x39d36b8080362c99,
+                0x3394f172f00c7c15,
+                0x02344d4cfeff87ed,
+            ])),
+            Felt::new(BigInteger256([
+                0x9099006f4f5eb315,
+                0xa18d803674a76457,
+                0x635a2ead46a33220,
+                0x03c95ea5df6eff7a,
+            ])),
+        ],
+    ];
+
+    let output_data = [
+        [Felt::new(BigInteger256([
+            0x4fc95fa6e830e353,
+            0x856208013219101c,
+            0x3e2e78eec2c35dca,
+            0x0674f4c4dd185ff0,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x4ec9666f7a9585dc,
+            0xb351bfab4308a4bd,
+            0x27ab1d166234fe7e,
+            0x00a481b1ae5c761a,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xddeb38a10177dbc7,
+            0x5d9b52cf3a7cc480,
+            0x9ef027daea5d4b86,
+            0x05b55ffbf5155e21,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x9674946c17d32174,
+            0x6019ca512bea045b,
+            0xca53323885ddfb78,
+            0x0074e6ef3cc944a1,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x13a9ede91ae252b4,
+            0xba0e94686062216f,
+            0xfd0b524a24979859,
+            0x07df166e709c922b,
+        ]))],
+        [Felt::new(BigInteger256([
+            0xe304b64599d1d70e,
+            0x3edef0dd6e34c0f3,
+            0x320209ed3d16625c,
+            0x02d3bffe593ba7cb,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x161f9545a415e998,
+            0x3058f27452a67fee,
+            0xb98b9e56496ad7dd,
+            0x055e006fbb407b3c,
+        ]))],
+        [Felt::new(BigInteger256([
+            0x96d8dd322a5049d0,
+            0x169815f7a2106e3d,
+            0xaa0f5db7383c719d,
+            0x00acd6ecf1cd10dc,
+        ]))],
+    ];
+
+    for (input, expected) in input_data.iter().zip(output_data) {
+        let digests = [AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])];
+        assert_eq!(expected, AnemoiHash::merge(&digests).to_elements());
+    }
+}
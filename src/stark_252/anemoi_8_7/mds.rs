@@ -0,0 +1,108 @@
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [ 1  4  3  3]
+/// [ 9 12  4  7]
+/// [ 9  9  1  4]
+/// [ 4  7  3  4]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xfffffffffffffee1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07ffffffffffecf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xfffffffffffffe81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07ffffffffffe690,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff21,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff130,
+    ])),
+    Felt::new(BigInteger256([
+        0xfffffffffffffee1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07ffffffffffecf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xfffffffffffffee1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07ffffffffffecf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff21,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff130,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+];
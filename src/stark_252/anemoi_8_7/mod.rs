@@ -0,0 +1,1711 @@
+use super::{sbox, BigInteger256, Felt};
+use crate::{Anemoi, AnemoiParameters};
+
+/// Test vectors for the Sponge and Jive modes of Anemoi
+#[cfg(test)]
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+// ANEMOI PARAMETERS
+// ================================================================================================
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Parameters of this Anemoi instantiation
+pub struct AnemoiParams;
+
+impl AnemoiParameters<Felt, NUM_COLUMNS> for AnemoiParams {
+    const NUM_ROUNDS: usize = NUM_HASH_ROUNDS;
+
+    const ALPHA: u32 = sbox::ALPHA;
+    const INV_ALPHA: &'static [u64] = &sbox::INV_ALPHA;
+    const BETA: u32 = sbox::BETA;
+    const DELTA: Felt = sbox::DELTA;
+    const QUAD: u32 = sbox::QUAD;
+
+    const ARK_C: &'static [[Felt; NUM_COLUMNS]] = &round_constants::C;
+    const ARK_D: &'static [[Felt; NUM_COLUMNS]] = &round_constants::D;
+
+    const MDS: &'static [Felt] = &mds::MDS;
+
+    #[inline(always)]
+    fn exp_inv_alpha(x: &Felt) -> Felt {
+        sbox::exp_inv_alpha(x)
+    }
+
+    #[inline(always)]
+    fn mul_by_generator(x: &Felt) -> Felt {
+        super::mul_by_generator(x)
+    }
+}
+
+/// The Anemoi permutation of this instantiation
+pub type AnemoiPermutation = Anemoi<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi hash instantiation
+pub type AnemoiHash = crate::AnemoiHash<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// A reader squeezing outputs of the extendable-output mode of Anemoi
+pub type AnemoiXofReader = crate::AnemoiXofReader<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi duplex sponge instantiation
+pub type AnemoiDuplex = crate::AnemoiDuplex<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi SAFE sponge instantiation
+pub type AnemoiSafeSponge = crate::AnemoiSafeSponge<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// A Fiat-Shamir transcript built on this Anemoi instantiation
+pub type AnemoiTranscript = crate::AnemoiTranscript<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub type AnemoiDigest = crate::AnemoiDigest<Felt, DIGEST_SIZE>;
+
+/// Plonkish gate equations and trace generation for this instantiation
+pub type AnemoiGates = crate::plonk::AnemoiGates<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// STARK execution trace generation and transition constraints for this instantiation
+pub type AnemoiAir = crate::air::AnemoiAir<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Anemoi permutation of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiPermutationVar = crate::r1cs::AnemoiPermutationVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// The R1CS gadget of the Sponge mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiSpongeVar =
+    crate::r1cs::AnemoiSpongeVar<Felt, NUM_COLUMNS, DIGEST_SIZE, AnemoiParams>;
+
+/// The R1CS gadget of the Jive mode of this instantiation
+#[cfg(feature = "r1cs")]
+pub type AnemoiJiveVar = crate::r1cs::AnemoiJiveVar<Felt, NUM_COLUMNS, AnemoiParams>;
+
+/// An append-only incremental Merkle tree of depth `DEPTH`
+/// built on the Sponge mode of this instantiation
+pub type IncrementalMerkleTree<const DEPTH: usize> =
+    crate::merkle::IncrementalMerkleTree<Felt, AnemoiHash, DEPTH, DIGEST_SIZE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xbf0e6c9643a623ff,
+                    0x5ee642fab603bec5,
+                    0x19d0df6d52791b54,
+                    0x07e30eacc407e975,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcc315f76bee40261,
+                    0xbcbcec1e49e7e035,
+                    0xa1f3fcb73cc883c3,
+                    0x0536ab4879444031,
+                ])),
+                Felt::new(BigInteger256([
+                    0x45b7848d0bb32239,
+                    0x8497adf7e3340edc,
+                    0x2da966c1b88e73ed,
+                    0x0406e3a25c2e21dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x93f2a9e3bce4400d,
+                    0xbea545530cb5ab20,
+                    0x9f73fd2104f00f7b,
+                    0x0646cb4ef94d5713,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed75b6449b4b216f,
+                    0x9ed8840a9a7ea576,
+                    0xefaa0019bcd503f7,
+                    0x050ee91ac56a54a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc22f98779e896f8,
+                    0x1e29cfe890928319,
+                    0x788f7dae65eee3f6,
+                    0x060f29bec982a7d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x933f9d4f665d9130,
+                    0x9265951a2c424908,
+                    0xa97bbf906310565a,
+                    0x054f4e73ec8be5d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80603299ccd30733,
+                    0x497fc96dce67cb9e,
+                    0xce282a574657781e,
+                    0x03bbb6759126cd6d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa0fef1dce7f0db52,
+                    0x46b028061cefb204,
+                    0xc42f165f4cd0ab35,
+                    0x00c3864dd277a4a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9c726c383368cb33,
+                    0xa7662da2fc19a1e6,
+                    0xf775e73820298413,
+                    0x01d1cdd4c105a24e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae0b79d3bcb5c4fa,
+                    0x2f60123485d5064c,
+                    0x51e10f44e172a99f,
+                    0x012e20986bc71fae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbccb03e4fe462006,
+                    0x3ad6c89ad180df9a,
+                    0x537602c108ab6201,
+                    0x031a736679306656,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1952fb3396012ee8,
+                    0x900852fbea615223,
+                    0x2975e70051741754,
+                    0x00f14cb4d217918e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00b41c10bef86741,
+                    0x53e37a47371cd4d7,
+                    0x9f53dd67ace59d38,
+                    0x0292e77f183187e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2e86a75e4ce94eb2,
+                    0xd3c03fe819861fcd,
+                    0x89548b8fea2eac33,
+                    0x0621944a74652f0c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa9208f64b14dfb4f,
+                    0xe1111b51c347e2a9,
+                    0x83d452f3cdde04a3,
+                    0x0664399ef5a3d7dc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa19772b739c82493,
+                    0x31e441979d28f5bc,
+                    0xc9d39913e18b88fe,
+                    0x02559f8f0a339521,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4fc13a0de083f461,
+                    0x0b58913897216b7b,
+                    0x8c689ab3089cebdf,
+                    0x0011e4bfa24b4b99,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc7391de545c17519,
+                    0xd8710a0d183f1324,
+                    0xd4a5e22c3de65094,
+                    0x07944818c620bb0f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2023dc6789c02ff7,
+                    0xee679a269d46e363,
+                    0xe2e618d4a71cbee1,
+                    0x01e812e815163225,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbfe3f66b07cf00a0,
+                    0xbb4f4f58b168e10c,
+                    0xaaa15abfa837d137,
+                    0x05800cfe9dea928d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacd92cf422f7245d,
+                    0xb1e02a5f8bbfa92f,
+                    0x95881d86d1240ba7,
+                    0x0125bad9377bb46b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c7ea1a0e07b5be6,
+                    0x60031615298d093c,
+                    0xf318f58c90b76812,
+                    0x01c4291d70878476,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4bab899343dd5464,
+                    0x0c6654034b9fb4c5,
+                    0xdf6226bf261ea7a1,
+                    0x027e3e4fdc418e36,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x03201052b091c2f0,
+                    0xf58078af002dd7c5,
+                    0xf97b66d4ab14b948,
+                    0x0240ddae7f4fff65,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb6edc63b9551b692,
+                    0xbf19365ddf18ba2c,
+                    0x3cfef758aa25577a,
+                    0x041782548e90a3e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe86dc15eb1128f8,
+                    0xb23653ca2d13fcdc,
+                    0xc47953cf2d81f5fa,
+                    0x018ab5ea34cbb24c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd28e389fb8cb636a,
+                    0x092df8cc7bd1dcdb,
+                    0xc9f98adbed11ca3b,
+                    0x02179087cd9f4859,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e0cb3259d1231c5,
+                    0x9c8b99994a4a1f48,
+                    0xbb94800935c72ee5,
+                    0x01988b546171dec3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b8dea5186a91283,
+                    0x738f1e05ee4c9f6d,
+                    0xc5a2db3f825833af,
+                    0x0127cb79f8a04260,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63b0e7cf13f4ed43,
+                    0xd2df92d0e505fbc6,
+                    0xef2fe7b6823b02be,
+                    0x02f5697ffd370bd4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa959ad6eaea5be29,
+                    0xbedc74c5aba380a6,
+                    0x043a6d583cbc111e,
+                    0x00e7dfcfd12f147f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7f8698ac8dc090f9,
+                    0x3e007c7831a214ae,
+                    0xf74276524f13c94f,
+                    0x0417e4a01e81b4f9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7a1783e2fdde59d1,
+                    0x661f74b83664b882,
+                    0x0839e4030784ba39,
+                    0x039d973ff1be469d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19c87ec04a8be843,
+                    0xe668cb0e252d7083,
+                    0x77a93a0f43dcaa2c,
+                    0x07abbb4d78676b1f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8a4c8b8f43b3f36,
+                    0xcdf10e05d3a9a76b,
+                    0xcf0baaf30ae2c496,
+                    0x0092b5b0ee624b7a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x429e44d97f2212da,
+                    0x7c2fcc020e8e71d0,
+                    0xf63dc17da2ca5da2,
+                    0x06435fdbfa8f5cfe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3180ab034d46a414,
+                    0xeac1b997b8ad90fa,
+                    0xd63e986cc2de70e9,
+                    0x042eafe2962dbed7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24d23ee1f5dfaecc,
+                    0x6bc270ebd1fcfe5e,
+                    0xd35e4fe804d82717,
+                    0x06047e76d3e2988c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc262d3f386d8ef2f,
+                    0xc5e7333b6b6be8ca,
+                    0x589199a00a3c9fcf,
+                    0x02368b92f3814eff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x75a69bc03fb4c045,
+                    0xadc8075ce8854380,
+                    0x9819778f5c3ca3df,
+                    0x063d0aef8fc54dee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xee2834a05f1d46dc,
+                    0x3f1b189ce4614bc5,
+                    0x30bd1e3b04dc2ab0,
+                    0x02a1d0f6e2e49465,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcced2d4e3b4652a6,
+                    0x09dc3600db98121f,
+                    0xb36d74418954b9ee,
+                    0x02c54f2db16a93f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x131eecac006b4aff,
+                    0xdc535a9a4ecf410c,
+                    0xb8d994cc464d2d0e,
+                    0x029ef745778a77f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x17d96715f45090f4,
+                    0x54f46ca2f51defa1,
+                    0x70939ea73b2eef29,
+                    0x076fec60bab945d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd64515acc2da3044,
+                    0x9290fb11920ed1b5,
+                    0x90f8da3f5692fbd9,
+                    0x0378dddb28f68d26,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14745717d9de417a,
+                    0x5f42f9bfbf5a9f5c,
+                    0x4cc537407fdfda9a,
+                    0x070dc9048c95e06f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcb4f6f13e8655ec6,
+                    0x9d3202676de13591,
+                    0xf37be5d5fef26391,
+                    0x0580318488fbbde7,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xacb1e54e06cf2d24,
+                    0x9b54031d4e257b0b,
+                    0x5388cb403fb69f72,
+                    0x04e2a19166113f59,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b6cd9c11478dd3e,
+                    0xbd341c078057e510,
+                    0x2c4f1d2c1ea78277,
+                    0x00dc03cfd8d6487c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a073649285e2514,
+                    0xece3a267484e4271,
+                    0xb32bbf7bfa3fa12b,
+                    0x064e00e3e2142e2d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a0f0992bd8815e9,
+                    0xfc76c9833974ca1c,
+                    0xf93d9ad82e231979,
+                    0x045fd238486a5fa5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6498eaeef91e8cce,
+                    0xbcd2191522f72bf9,
+                    0x7eb69e9c87734c27,
+                    0x06bf147e0c8526fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c0f08c0e7351c76,
+                    0xe888987e3f82d613,
+                    0x2b4d8ae8c3980657,
+                    0x01a1e291c74784d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x60199ecf463ba844,
+                    0xa16701cd30941bf0,
+                    0x5fca502de2811486,
+                    0x0375d803e7c6430e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d7decb751ec8a47,
+                    0x2cf135ed80f91aef,
+                    0xcbd6539716c4bf6f,
+                    0x01bcca33e6774ae7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xefd95cc037a9bfe7,
+                    0xf7402ffabad49766,
+                    0xd41f2e0028e6de16,
+                    0x05ee223b08718167,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8a39a316fb7ebdc,
+                    0x33964a928520c6a3,
+                    0x1ff30cb5dc946103,
+                    0x060b190caeed9e96,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e395ac81b182bc4,
+                    0xa147d79c9d00ee98,
+                    0x1179821402132e0f,
+                    0x05a1d2f23ce4cdf4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b2203ff4dc94c30,
+                    0xdd42a458188036cd,
+                    0xa5024881ba88e221,
+                    0x0663407f32bf7c8b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x524d739943ce2b46,
+                    0x2b87184781fb70ac,
+                    0xe14e6f875136f025,
+                    0x079b9fcb02520cf0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf2f0ab48fe692899,
+                    0xd48ce98c3741f762,
+                    0x0e6d63ec346533a0,
+                    0x00dd8d0cb35b7fdc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd2e1e3f5d7dc3e5,
+                    0x16b7632d4f0b0e5c,
+                    0xfeadae55cf02c738,
+                    0x01c113a63c6644a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd398bc0c29cb2c8c,
+                    0xb0ee9fd0ed7bbb2a,
+                    0x409d92e4f549caf2,
+                    0x040441c573cc5cab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8c4b556a620bfd60,
+                    0x2d4a6653b1157f4d,
+                    0x42e597ac10057d3e,
+                    0x061e601116b37273,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd7a54e153d8641dd,
+                    0x8d79e6a9be82b807,
+                    0x381407f52a73fc0b,
+                    0x02a02d90b920d68d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbed1a5a87010c4d0,
+                    0xc60b1bebf2363076,
+                    0xf872509cae351ed8,
+                    0x03578a3caf609c3c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd335c681d722d278,
+                    0x89100874e48f1f36,
+                    0xe7b164c4f466a595,
+                    0x042788fd39fe1413,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa837d28dd3af3623,
+                    0x22740f41fe37150e,
+                    0xd749ee0304226239,
+                    0x07e75d66657ce339,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81bd56ffe004c390,
+                    0x2b529e3677911374,
+                    0x4ef45c266edb8ce0,
+                    0x07f3a16d9c61d6e6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9637d8adbe4106da,
+                    0x6fad8038a7c49bc9,
+                    0x76a6980d51f1d84d,
+                    0x03fd760389736513,
+                ])),
+                Felt::new(BigInteger256([
+                    0x11b7d8424dcb11dd,
+                    0x54782f7cf3410b4f,
+                    0x388236520d285b74,
+                    0x049c3f3801da2529,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4276d6ce6bd765c4,
+                    0xb0c123abae2c3d2e,
+                    0xc3211b6783786f35,
+                    0x07e337f6ed7f318e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb880c577cadff500,
+                    0xa7a5bf4394331f4d,
+                    0x163fe17efe30af99,
+                    0x05f68a1fbc704277,
+                ])),
+                Felt::new(BigInteger256([
+                    0x964dfdf494c68e9b,
+                    0xe3f28edb43f21846,
+                    0xa4660c662d17cc13,
+                    0x01ddf7a3be54323f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd4b65c5a900d8edd,
+                    0x2d6dc945b199fedc,
+                    0x56ed24f9a5a5f13e,
+                    0x07eef1aa4f50660c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4b751a5d830256c8,
+                    0x26b0a95e547e24e4,
+                    0x7a7d711d39ee9878,
+                    0x013900547729f030,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf12f2bbff6e5b48a,
+                    0xabd5d3cf95832609,
+                    0x3c0dcfd271be524f,
+                    0x008a7245df5e5e88,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe93b4fd693dacaec,
+                    0x5ee4c39d78125344,
+                    0xf3518e7a98045d3d,
+                    0x0652ab54049a4b7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x22474021549ebdc2,
+                    0x6b67a37b64ab3b82,
+                    0xf6740745ca10f73c,
+                    0x07328cbb136fe7b7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0dd193f9c9ef8d6d,
+                    0x828f63a985c95ec2,
+                    0xac416b230032b498,
+                    0x05a3c92832995cb0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26791485e97958e2,
+                    0x66a5d7bcb2d549b6,
+                    0x97512a350582bbf6,
+                    0x05f1b4f2158a74dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c6278ec72febf42,
+                    0x9127a25eaa3d7c12,
+                    0x5957f2f2fbe4b654,
+                    0x0279c5430b09fc1b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01114f1d463dcf6d,
+                    0x859e9ad8d3214df7,
+                    0xac689e87b5067409,
+                    0x03d6c69ea7a0f9a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa4fef6f76d0a3e27,
+                    0x498715dbc8455e50,
+                    0xe09f9835e5c6455d,
+                    0x01ae533d927a8943,
+                ])),
+                Felt::new(BigInteger256([
+                    0x669098bd46b2336d,
+                    0x99d7cd40bf3219c8,
+                    0x743ebcaad95378ab,
+                    0x050a989d2905e5fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabf4fc484f87006e,
+                    0x57682437423530b2,
+                    0xe680f261bba8a36e,
+                    0x0714948035d17cf8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2a6dad4bbeb5b247,
+                    0x9d20fe4e7ceb4a7c,
+                    0x74b97ec5267ea717,
+                    0x03d6fab4f195fd85,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x17f59272960a660a,
+                    0x24dc3ca349383f4f,
+                    0x8e55fd729a99dbc1,
+                    0x00810635042814b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdac204338e8450cc,
+                    0x381c8a1191bd77de,
+                    0x25663681171732ee,
+                    0x0623a4e33d7c1442,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90d9e266d031f32a,
+                    0xc194174c7ad4aa91,
+                    0x20ff154d1d5253d4,
+                    0x050987f26dfdb2de,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd929188a1a32722,
+                    0xb7da7c64052209b7,
+                    0x0c092ab71e049a0a,
+                    0x003de03b49066290,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3935458e8ff57e29,
+                    0x69c8b2ad36574b7b,
+                    0x1514f82b9ca2821d,
+                    0x06059ef83826ae82,
+                ])),
+                Felt::new(BigInteger256([
+                    0x87f3a5b55e218ef7,
+                    0x8b0e9b88c3da34e1,
+                    0xd6a6e98747dec8da,
+                    0x01b1c1e55518e2bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9582a53ef2be16a7,
+                    0x47f75c75eba3a84e,
+                    0x82f79d0bcc76aa05,
+                    0x029b8e646ea0eacc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfa48c9db1d8e977c,
+                    0x73da432ad73f72c8,
+                    0x71866b8e383145a2,
+                    0x0599fe40084c4c5e,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Computed with scripts/anemoi_model.py, which reproduces
+        // the test vectors of the reference implementation
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xffbc09691f26e76f,
+                    0x1d594dc40922b837,
+                    0x67d7ff2d2e53515d,
+                    0x04d67d79811439fd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0c0a4592f336bc9,
+                    0xb91557923c208fd5,
+                    0xaf57212ec078b6f3,
+                    0x00d179117bc2fdcc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f62e81aa28e9f60,
+                    0xa1fba8b7bfdac30f,
+                    0x8a0d9e236d6da64f,
+                    0x047102b94a1e4c36,
+                ])),
+                Felt::new(BigInteger256([
+                    0x91de077b3160e318,
+                    0x0a767d2ffe49769b,
+                    0x3c64c4bdf31d54fd,
+                    0x07528b9328fe3b82,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbfeefb09653f521e,
+                    0x9789a8c0ec973f76,
+                    0x3417d06067b2a3be,
+                    0x05a463d1e9c39b1e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6a879174152be3b4,
+                    0x60205eec76fab138,
+                    0x4c8d16a3c0557dad,
+                    0x042a5cb0f3b36774,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbbe34de06c332ee5,
+                    0x562ec28895df76ef,
+                    0x59dbbacf0ff435a9,
+                    0x041a27d01f0d46f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb53e862256494282,
+                    0x40d4e7de32cf38be,
+                    0xd3ba2ddb69acd46d,
+                    0x003d36a7eba95514,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1aafa992fb8a3913,
+                    0x1feee4148e9ce6b5,
+                    0xd34c7592a803a371,
+                    0x043d65cf6b7cf2dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x74984b8dd4b63e1c,
+                    0x98d81d8aa23c77a7,
+                    0x2381c35a4267bab7,
+                    0x07a7630a7c142fb5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94fc199bfe515e85,
+                    0xd733cc4494e8795d,
+                    0x69cb0685aec15cd3,
+                    0x03296d7a03e3665f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x209e3a6cf3743f77,
+                    0x716332eb82c31019,
+                    0xa75ba4d2a554025d,
+                    0x028d045b6d0c26ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcd94a31b08730041,
+                    0x06370e6b2b82f720,
+                    0x4f15d3966690d7c7,
+                    0x037b3d7c6452e0c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xad9a255aa6a4a314,
+                    0x7010721edb6db9fa,
+                    0x2314461ff902e3a1,
+                    0x04f875cf78c4b755,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1fc2640190bbc4f8,
+                    0x4b52b00695050290,
+                    0xb8f3662e1d6d8126,
+                    0x02fc29b07e2b0378,
+                ])),
+                Felt::new(BigInteger256([
+                    0x459f72744982ac9e,
+                    0xb8f9593b39696dd8,
+                    0x5600984d3ffc4623,
+                    0x0278248f7c7d8840,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa2f1847b21d8912a,
+                    0xdf74c63673eef718,
+                    0xd92c64ec0a60f07c,
+                    0x06177cd31809a9a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5fd63142aa7d541e,
+                    0xc11d5ffe713acc1d,
+                    0x856013426378f2f0,
+                    0x05562b7200def9e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf771775d6fd0ad0d,
+                    0x6a0702f0a43bfa1f,
+                    0x11fb6cee40bdd498,
+                    0x042bd42485b8d382,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb51842e1065a7a0e,
+                    0x7d80e5f2af4830d5,
+                    0xe1f210d0dbef5851,
+                    0x0590dd62f478564a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x738ed6abff3afbc7,
+                    0xdbd66e6e7f7e3f32,
+                    0xecea0732c8be4c43,
+                    0x029adae4f6b20b2f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8262e4923dc3dfe6,
+                    0xa384902500cfd3be,
+                    0xf2f00a0fe543d9eb,
+                    0x054bd9803b15b806,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ef645da3895d900,
+                    0xad24b7fdcd419ee7,
+                    0x3c6fb0a5470cbe21,
+                    0x057cbe75c5aee1aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x940412fa98cc7f08,
+                    0x3086fd93d71e845f,
+                    0x34b86fc0a5c523bb,
+                    0x06f9e29ed3f708e7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7d124cf25bcfaea6,
+                    0xcbf74beed98e90b2,
+                    0x50c86855ff7f6753,
+                    0x035cccc9016233bc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb03d72535810fc1,
+                    0x84d2e678bb208206,
+                    0xb421ee222aa557e7,
+                    0x067568887a4731c7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a5ac965dedd4e90,
+                    0x0f49546f26d132d4,
+                    0xb0e95a1c73caa847,
+                    0x05d074335db5e6eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x561577a585ac1d73,
+                    0x815b3afba8d08f4a,
+                    0xef3c6ebc923fd537,
+                    0x05322aab38fac310,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a6e83f08512bf50,
+                    0x988a6ce826c555fb,
+                    0x96e0ec958e252631,
+                    0x03a1dc55b5d7681e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6ee99ce0cb0fbed,
+                    0x095caad764d86a83,
+                    0xe6879299be95d038,
+                    0x03efbbf13aa3205a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe42022e7fe0bd923,
+                    0x5575abd5478ce688,
+                    0x9ebbce010b63b7f9,
+                    0x017a090c89c65f83,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa7d80911398ca871,
+                    0x6c02d2825fbb0167,
+                    0x8bebb73cf1a639dc,
+                    0x07767590299b8a4c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4b97de3a80161841,
+                    0x6346082bba5c27f8,
+                    0x8d964233b175192e,
+                    0x0274ffed1ab867a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc929ae804facbb9c,
+                    0x35f05efeee4d6eac,
+                    0x7ebdf718703ea831,
+                    0x01554a86577df7f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76ff422205165014,
+                    0x2d9aa33071fda369,
+                    0x6fd3d031c5beaa7c,
+                    0x060924c0169487a6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fd85df5431ce9b8,
+                    0x69120d4632c38b80,
+                    0x974995e761584b47,
+                    0x05fb354e5cdb826a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9dddef3b2ad54db8,
+                    0xbbb50acbb8e6fa92,
+                    0xfaa41b1469f310a3,
+                    0x05b2ef83cb6d7240,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde99409174519cbc,
+                    0x33a0a840b5a4fed2,
+                    0x0116d58ca93b38e8,
+                    0x0142d1a02c098120,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfe2e7044f4965730,
+                    0x32762677e72e51dd,
+                    0xed20eab1f530642e,
+                    0x01b606852f81ed2d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d5fab72b21c7b74,
+                    0xa8b63fefcf339c54,
+                    0x83a64aebc0d23dbe,
+                    0x00f558052226aeea,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3851651bb238b640,
+                    0x4679947915eaf6d9,
+                    0x1997487cab441279,
+                    0x00775fa5875eda1e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ee0377f43aee836,
+                    0xd8ea42d466d719cc,
+                    0x34405e91ee188286,
+                    0x06ce94d971f31c76,
+                ])),
+                Felt::new(BigInteger256([
+                    0xebbad2de140785ca,
+                    0x494685fae9cf6f57,
+                    0x62782693d395dba3,
+                    0x01805fb014eddb30,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26cbc92a5d68f1e7,
+                    0xfc5d0b5358320348,
+                    0xde62dee7ce3bcfda,
+                    0x01bd4a250304f047,
+                ])),
+                Felt::new(BigInteger256([
+                    0x258c60bca0f3bbbb,
+                    0xb01cedf4ea36cc52,
+                    0x282341a63149203f,
+                    0x061dc8fc040a5b83,
+                ])),
+                Felt::new(BigInteger256([
+                    0x445fad19c005e775,
+                    0xe896fa384455cc5b,
+                    0x338f0c440fb0f8cd,
+                    0x06b102371247064c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd5f43a0245042ed6,
+                    0xe0209032606229ad,
+                    0x47d764c9bb8426d8,
+                    0x0490786469635428,
+                ])),
+                Felt::new(BigInteger256([
+                    0x59d0778d9ecd3c39,
+                    0xa266729902b17e03,
+                    0x7f1930e7caacd6ee,
+                    0x00e54579f48e8898,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffea1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe8b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffc01,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffbc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd21,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffcf30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffdc1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd9d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffea1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe8b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffc01,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffbc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd21,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffcf30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffdc1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd9d0,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffea1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe8b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffc01,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffbc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd21,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffcf30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffdc1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd9d0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffea1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe8b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffc01,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffbc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd21,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffcf30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffdc1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd9d0,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd681698f57c31df6,
+                    0x07051dc43411a48e,
+                    0x788bac8c51d71f12,
+                    0x03670ca4c975c805,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc141dda932a7e36c,
+                    0x864fe5ee172c8279,
+                    0xada56087021a5bdc,
+                    0x0261f2029e46bfeb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b3d21dc293f18ae,
+                    0x55b96d80285e25f8,
+                    0x4c48d4569f0f451b,
+                    0x04a2dbe8d1a82fb0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x79d57a517432fab3,
+                    0x94c78af70224f353,
+                    0xfa7dd25e11588d01,
+                    0x03b17bd8e8f9aab2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb99d4c78f8925d24,
+                    0x41f71aec2cabf597,
+                    0xcb71fc937444bcd6,
+                    0x0637cb5ef02f53a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1eeb964eed17cdb,
+                    0x936bb17678a96d72,
+                    0xa2d1f786ff09baf3,
+                    0x072ab55718ec87ca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ebc4c40779e324f,
+                    0x07c3b7ff58d7a003,
+                    0x7dc8cc665b0eb174,
+                    0x01376e793b7de281,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeccce57fe1eee709,
+                    0xfc6e280c3fd1ad86,
+                    0xf2c4414c4cd47a98,
+                    0x04a9bcb41234f9bc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0ddfd3e523b40b71,
+                    0x5d1457cf5e9161b7,
+                    0x94c78504ade2abe3,
+                    0x03fe4779ae9c5935,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f94803b71da213c,
+                    0xc00cfcbb85510e5d,
+                    0x5272e63c454f0372,
+                    0x00820d30a3396ef1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x20fca1771e668cf9,
+                    0x1bbfa68b57990b03,
+                    0xb4799a2481d7b5b8,
+                    0x00688e90dc2e383c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc55edb487e9b070,
+                    0xf8cc8f9873e08ce5,
+                    0x208dd49e1278c8ba,
+                    0x0239a662d25be785,
+                ])),
+                Felt::new(BigInteger256([
+                    0x663ff60edf74bd8d,
+                    0xdaec692c5e46f326,
+                    0xf6252283626041fa,
+                    0x02c342b513e20001,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d163fd105c6ab85,
+                    0x87db0d3fc95221d8,
+                    0x68ba6b9af0230d78,
+                    0x02cd5935d928e118,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb432d31e5db25604,
+                    0x6851c638db7deb3c,
+                    0x4e9cf565a4333045,
+                    0x07feb600bc369be4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9bea353e8e08f5ef,
+                    0x134cde07db221fe7,
+                    0xd951fb040c424819,
+                    0x061c5eb15d0410fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x27e778412e4f56ec,
+                    0x9a8200da3366a86f,
+                    0xca752b32ee4c42fd,
+                    0x00a63f318a190e12,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09f7a9111a391663,
+                    0xff1dcd3cbe117f96,
+                    0x589c9cd80d969448,
+                    0x0682cb0aa8f855dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe4d7e68db83fa4bd,
+                    0x052df2976fd49a5c,
+                    0xecb3e9d38d2434b9,
+                    0x014a341e37c7eb29,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe556dc5b99ab80cd,
+                    0xf9b9596b922c22e6,
+                    0xc80ca48f13c94597,
+                    0x00801563c94b4e8c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14f4b8eee831b44d,
+                    0x7d2fb42339ac9a11,
+                    0x4996317f4d01226f,
+                    0x07fd0be2b1cc7a8f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81fd79fd30b7e994,
+                    0xa25aac877f4fb067,
+                    0xacf493b4784437a4,
+                    0x07a719c566b07ce2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e61eb7abedfee2c,
+                    0x75d4408713b488fc,
+                    0x92be1cea5793ad42,
+                    0x0272a5d8b5a89bf3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c8f0ee04a79dac1,
+                    0x4b01fafa235f3134,
+                    0xc49f68d19ab136db,
+                    0x02f1aea9aacc527d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9a726ca95f7031ac,
+                    0x9130941234f5df29,
+                    0x01c17b69bc343f6d,
+                    0x063a4b86ae90f85a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xedd43561df83e933,
+                    0x2c3867aca481e4f1,
+                    0x96ef4439ca24366d,
+                    0x076514f2cd7d16cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab77ecd0116475ed,
+                    0xebd20602043b1881,
+                    0x9a181f483814b63a,
+                    0x02fcfebd9a9583e5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf8ca5095990e8a4f,
+                    0x04ea66449bd3a69f,
+                    0xffbced8ecce25257,
+                    0x00e316265a87eba1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e42cc7340be97b7,
+                    0x6cdb186c168d0ad0,
+                    0xc9dcb6156b86d047,
+                    0x0320d5d500157555,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa84acaf6e47ce5e0,
+                    0x319a53f47487ec89,
+                    0xdb67247ee58de475,
+                    0x046ce4aa245a9e88,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf516bb39ae7b2339,
+                    0x239192350a6032c5,
+                    0x96cdcf0444009c5f,
+                    0x06b5d3d2e8aea911,
+                ])),
+                Felt::new(BigInteger256([
+                    0x39dd8685e607d635,
+                    0x21dc895a428253f1,
+                    0xf087c47b57988f0d,
+                    0x0700012403295ced,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x64c578819762b410,
+                    0xff0d958b61d56f68,
+                    0x9de650e0e7b49b3d,
+                    0x03d73831d3006542,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4132984f270349e4,
+                    0x9aa3a729e42df5fb,
+                    0xcd82431140727dd4,
+                    0x001f85c6eeb5f2a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf12eac885f6369b1,
+                    0x34cc78c92b021d37,
+                    0x3bf02b7c7a71a3f6,
+                    0x001298088debecf4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2e27ca749c8195d,
+                    0x33c2d8518e95bed7,
+                    0x5a2c92acae282aa4,
+                    0x05314cda867f0644,
+                ])),
+                Felt::new(BigInteger256([
+                    0x890bd1aedd8054fd,
+                    0x2abb2252eaae0b00,
+                    0x3079b254fe4cb4c8,
+                    0x0413c24fb2cd9927,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8420c0c18231a6ab,
+                    0xf2fc033684181726,
+                    0x106e6b875a481dc8,
+                    0x039b998edf05831f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd7dc9fe90d99459a,
+                    0x2e57af9c363d5cd6,
+                    0xce2c7970fa670617,
+                    0x0280af6487c35845,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd40d36d430d7e77,
+                    0x18b499487a0ef7a5,
+                    0xf5c50e2543829caf,
+                    0x02b13a4390dd562f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xab6617321545be27,
+                    0x7b0d53b5774bb5e9,
+                    0xad29d337491b1f0f,
+                    0x056ab08a9703ae1b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bdcf3914e2dff34,
+                    0xb0e6c169a7f3b78c,
+                    0xf7eaaddf2084e21f,
+                    0x000fdcc68294fe74,
+                ])),
+                Felt::new(BigInteger256([
+                    0x07a878fa2ecfdf83,
+                    0x553d4501ad6a1248,
+                    0x989881b670c65810,
+                    0x01eb20bae4e346ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa7c6b62d54658b6d,
+                    0xd595e4f145c3eb21,
+                    0x7513a74ae36cadea,
+                    0x04f9d82c85fe81ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1a471e0193598aa4,
+                    0x60a35cab8c975213,
+                    0x48a1f714f1a379bc,
+                    0x05fc0f2aa19f02eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdce85161de466812,
+                    0x0939da6d5de3b1a7,
+                    0x437bb703db1dcee0,
+                    0x01644d7083a9cc35,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdcf51a7c4ff6f3b9,
+                    0x714e082c7604559d,
+                    0x764030fcb4af74ca,
+                    0x06a9b8e15db523be,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ecf3412436b893b,
+                    0x6ae6e9e064f60080,
+                    0xe2f88be4848bf8f0,
+                    0x05de47f918a86d76,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            AnemoiPermutation::apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! The Stark field, of modulus
+//! `p = 3618502788666131213697322783095070105623107215331596699973092056135872020481`,
+//! i.e. `2^251 + 17 * 2^192 + 1`, over which the field elements (felt252)
+//! of Starknet and Cairo are defined.
+//!
+//! Elements are represented with the generic prime field type of arkworks
+//! over 4 limbs, in Montgomery form.
+
+use ark_ff::{biginteger::BigInteger256 as BigInteger, fields::*};
+
+/// An element of the Stark field.
+pub type Felt = Fp256<FeltParameters>;
+
+/// Parameters of the Stark field.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FeltParameters;
+
+impl Fp256Parameters for FeltParameters {}
+
+impl FftParameters for FeltParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 192;
+
+    /// `3^((p - 1) / 2^192)`
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x4106bccd64a2bdd8,
+        0xaaada25731fe3be9,
+        0x0a35c5be60505574,
+        0x07222e32c47afc26,
+    ]);
+}
+
+impl FpParameters for FeltParameters {
+    const MODULUS: BigInteger = BigInteger([
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0800000000000011,
+    ]);
+
+    const MODULUS_BITS: u32 = 252;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 4;
+
+    const R: BigInteger = BigInteger([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ]);
+
+    const R2: BigInteger = BigInteger([
+        0xfffffd737e000401,
+        0x00000001330fffff,
+        0xffffffffff6f8000,
+        0x07ffd4ab5e008810,
+    ]);
+
+    const INV: u64 = 0xffffffffffffffff;
+
+    /// GENERATOR = 3
+    const GENERATOR: BigInteger = BigInteger([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8000000000000000,
+        0x0400000000000008,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    const T: BigInteger = BigInteger([
+        0x0800000000000011,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x0400000000000008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::fields::{field_serialization_test, field_test, from_str_test};
+    use ark_ff::{One, PrimeField};
+    use ark_std::test_rng;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_field() {
+        let mut rng = test_rng();
+        let a = Felt::rand(&mut rng);
+        let b = Felt::rand(&mut rng);
+        field_test(a, b);
+        from_str_test::<Felt>();
+        field_serialization_test::<Felt>(32);
+
+        let one = Felt::one();
+        assert_eq!(Felt::from(one.into_repr()), one);
+    }
+
+    // The FFT test of arkworks computes `2^TWO_ADICITY` over a `u64`,
+    // which overflows for the two-adicity 192 of this field.
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let mut root = Felt::two_adic_root_of_unity();
+        for _ in 0..FeltParameters::TWO_ADICITY - 1 {
+            root.square_in_place();
+        }
+        assert_eq!(root, -Felt::one());
+        assert_eq!(root.square(), Felt::one());
+
+        for i in 0..64 {
+            let size = 1u64 << i;
+            let root = Felt::get_root_of_unity(size as usize).unwrap();
+            assert_eq!(root.pow([size]), Felt::one());
+        }
+    }
+}
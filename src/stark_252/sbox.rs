@@ -0,0 +1,352 @@
+use super::BigInteger256;
+use super::Felt;
+
+use ark_ff::Field;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 3;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 4] = [
+    0xaaaaaaaaaaaaaaab,
+    0xaaaaaaaaaaaaaaaa,
+    0xaaaaaaaaaaaaaaaa,
+    0x0555555555555560,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 3;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger256([
+    0xaaaaaaaaaaaaaaa1,
+    0xaaaaaaaaaaaaaaaa,
+    0xaaaaaaaaaaaaaaaa,
+    0x07ffffffffffff5b,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+#[inline(always)]
+pub(crate) fn exp_inv_alpha(x: &Felt) -> Felt {
+    let t1 = x.square(); //         1: 2
+    let t2 = t1 * x; //             2: 3
+    let t3 = t2 * t1; //            3: 5
+    let t4 = t3 * t1; //            4: 7
+    let t5 = t4 * t1; //            5: 9
+    let t6 = t5 * t1; //            6: 11
+    let t7 = t6 * t1; //            7: 13
+    let t8 = t7 * t1; //            8: 15
+    let t9 = t8 * t1; //            9: 17
+    let t10 = t9 * t1; //          10: 19
+    let t11 = t10 * t1; //         11: 21
+    let mut t0 = t11.square(); //  12: 42
+    t0 = t0.square(); //           13: 84
+    t0 = t0.square(); //           14: 168
+    t0 = t0.square(); //           15: 336
+    t0 = t0.square(); //           16: 672
+    t0 = t0.square(); //           17: 1344
+    t0 *= t11; //                  18: 1365
+    t0 = t0.square(); //           19: 2730
+    t0 = t0.square(); //           20: 5460
+    t0 = t0.square(); //           21: 10920
+    t0 = t0.square(); //           22: 21840
+    t0 = t0.square(); //           23: 43680
+    t0 = t0.square(); //           24: 87360
+    t0 *= t11; //                  25: 87381
+    t0 = t0.square(); //           26: 174762
+    t0 = t0.square(); //           27: 349524
+    t0 = t0.square(); //           28: 699048
+    t0 = t0.square(); //           29: 1398096
+    t0 = t0.square(); //           30: 2796192
+    t0 = t0.square(); //           31: 5592384
+    t0 *= t11; //                  32: 5592405
+    t0 = t0.square(); //           33: 11184810
+    t0 = t0.square(); //           34: 22369620
+    t0 = t0.square(); //           35: 44739240
+    t0 = t0.square(); //           36: 89478480
+    t0 = t0.square(); //           37: 178956960
+    t0 = t0.square(); //           38: 357913920
+    t0 *= t11; //                  39: 357913941
+    t0 = t0.square(); //           40: 715827882
+    t0 = t0.square(); //           41: 1431655764
+    t0 = t0.square(); //           42: 2863311528
+    t0 = t0.square(); //           43: 5726623056
+    t0 = t0.square(); //           44: 11453246112
+    t0 = t0.square(); //           45: 22906492224
+    t0 *= t11; //                  46: 22906492245
+    t0 = t0.square(); //           47: 45812984490
+    t0 = t0.square(); //           48: 91625968980
+    t0 = t0.square(); //           49: 183251937960
+    t0 = t0.square(); //           50: 366503875920
+    t0 = t0.square(); //           51: 733007751840
+    t0 = t0.square(); //           52: 1466015503680
+    t0 *= t11; //                  53: 1466015503701
+    t0 = t0.square(); //           54: 2932031007402
+    t0 = t0.square(); //           55: 5864062014804
+    t0 = t0.square(); //           56: 11728124029608
+    t0 = t0.square(); //           57: 23456248059216
+    t0 = t0.square(); //           58: 46912496118432
+    t0 = t0.square(); //           59: 93824992236864
+    t0 *= t11; //                  60: 93824992236885
+    t0 = t0.square(); //           61: 187649984473770
+    t0 = t0.square(); //           62: 375299968947540
+    t0 = t0.square(); //           63: 750599937895080
+    t0 = t0.square(); //           64: 1501199875790160
+    t0 = t0.square(); //           65: 3002399751580320
+    t0 = t0.square(); //           66: 6004799503160640
+    t0 *= t11; //                  67: 6004799503160661
+    t0 = t0.square(); //           68: 12009599006321322
+    t0 *= x; //                    69: 12009599006321323
+    t0 = t0.square(); //           70: 24019198012642646
+    t0 = t0.square(); //           71: 48038396025285292
+    t0 = t0.square(); //           72: 96076792050570584
+    t0 = t0.square(); //           73: 192153584101141168
+    t0 = t0.square(); //           74: 384307168202282336
+    t0 = t0.square(); //           75: 768614336404564672
+    t0 = t0.square(); //           76: 1537228672809129344
+    t0 = t0.square(); //           77: 3074457345618258688
+    t0 = t0.square(); //           78: 6148914691236517376
+    t0 = t0.square(); //           79: 12297829382473034752
+    t0 *= t11; //                  80: 12297829382473034773
+    t0 = t0.square(); //           81: 24595658764946069546
+    t0 = t0.square(); //           82: 49191317529892139092
+    t0 = t0.square(); //           83: 98382635059784278184
+    t0 = t0.square(); //           84: 196765270119568556368
+    t0 = t0.square(); //           85: 393530540239137112736
+    t0 = t0.square(); //           86: 787061080478274225472
+    t0 *= t11; //                  87: 787061080478274225493
+    t0 = t0.square(); //           88: 1574122160956548450986
+    t0 = t0.square(); //           89: 3148244321913096901972
+    t0 = t0.square(); //           90: 6296488643826193803944
+    t0 = t0.square(); //           91: 12592977287652387607888
+    t0 = t0.square(); //           92: 25185954575304775215776
+    t0 = t0.square(); //           93: 50371909150609550431552
+    t0 *= t11; //                  94: 50371909150609550431573
+    t0 = t0.square(); //           95: 100743818301219100863146
+    t0 = t0.square(); //           96: 201487636602438201726292
+    t0 = t0.square(); //           97: 402975273204876403452584
+    t0 = t0.square(); //           98: 805950546409752806905168
+    t0 = t0.square(); //           99: 1611901092819505613810336
+    t0 = t0.square(); //          100: 3223802185639011227620672
+    t0 *= t11; //                 101: 3223802185639011227620693
+    t0 = t0.square(); //          102: 6447604371278022455241386
+    t0 = t0.square(); //          103: 12895208742556044910482772
+    t0 = t0.square(); //          104: 25790417485112089820965544
+    t0 = t0.square(); //          105: 51580834970224179641931088
+    t0 = t0.square(); //          106: 103161669940448359283862176
+    t0 = t0.square(); //          107: 206323339880896718567724352
+    t0 *= t11; //                 108: 206323339880896718567724373
+    t0 = t0.square(); //          109: 412646679761793437135448746
+    t0 = t0.square(); //          110: 825293359523586874270897492
+    t0 = t0.square(); //          111: 1650586719047173748541794984
+    t0 = t0.square(); //          112: 3301173438094347497083589968
+    t0 = t0.square(); //          113: 6602346876188694994167179936
+    t0 = t0.square(); //          114: 13204693752377389988334359872
+    t0 *= t11; //                 115: 13204693752377389988334359893
+    t0 = t0.square(); //          116: 26409387504754779976668719786
+    t0 = t0.square(); //          117: 52818775009509559953337439572
+    t0 = t0.square(); //          118: 105637550019019119906674879144
+    t0 = t0.square(); //          119: 211275100038038239813349758288
+    t0 = t0.square(); //          120: 422550200076076479626699516576
+    t0 = t0.square(); //          121: 845100400152152959253399033152
+    t0 *= t11; //                 122: 845100400152152959253399033173
+    t0 = t0.square(); //          123: 1690200800304305918506798066346
+    t0 = t0.square(); //          124: 3380401600608611837013596132692
+    t0 = t0.square(); //          125: 6760803201217223674027192265384
+    t0 = t0.square(); //          126: 13521606402434447348054384530768
+    t0 = t0.square(); //          127: 27043212804868894696108769061536
+    t0 = t0.square(); //          128: 54086425609737789392217538123072
+    t0 *= t11; //                 129: 54086425609737789392217538123093
+    t0 = t0.square(); //          130: 108172851219475578784435076246186
+    t0 = t0.square(); //          131: 216345702438951157568870152492372
+    t0 = t0.square(); //          132: 432691404877902315137740304984744
+    t0 = t0.square(); //          133: 865382809755804630275480609969488
+    t0 = t0.square(); //          134: 1730765619511609260550961219938976
+    t0 = t0.square(); //          135: 3461531239023218521101922439877952
+    t0 *= t11; //                 136: 3461531239023218521101922439877973
+    t0 = t0.square(); //          137: 6923062478046437042203844879755946
+    t0 = t0.square(); //          138: 13846124956092874084407689759511892
+    t0 = t0.square(); //          139: 27692249912185748168815379519023784
+    t0 = t0.square(); //          140: 55384499824371496337630759038047568
+    t0 = t0.square(); //          141: 110768999648742992675261518076095136
+    t0 = t0.square(); //          142: 221537999297485985350523036152190272
+    t0 *= t11; //                 143: 221537999297485985350523036152190293
+    t0 = t0.square(); //          144: 443075998594971970701046072304380586
+    t0 = t0.square(); //          145: 886151997189943941402092144608761172
+    t0 = t0.square(); //          146: 1772303994379887882804184289217522344
+    t0 = t0.square(); //          147: 3544607988759775765608368578435044688
+    t0 = t0.square(); //          148: 7089215977519551531216737156870089376
+    t0 = t0.square(); //          149: 14178431955039103062433474313740178752
+    t0 *= t11; //                 150: 14178431955039103062433474313740178773
+    t0 = t0.square(); //          151: 28356863910078206124866948627480357546
+    t0 = t0.square(); //          152: 56713727820156412249733897254960715092
+    t0 = t0.square(); //          153: 113427455640312824499467794509921430184
+    t0 = t0.square(); //          154: 226854911280625648998935589019842860368
+    t0 = t0.square(); //          155: 453709822561251297997871178039685720736
+    t0 = t0.square(); //          156: 907419645122502595995742356079371441472
+    t0 *= t11; //                 157: 907419645122502595995742356079371441493
+    t0 = t0.square(); //          158: 1814839290245005191991484712158742882986
+    t0 = t0.square(); //          159: 3629678580490010383982969424317485765972
+    t0 = t0.square(); //          160: 7259357160980020767965938848634971531944
+    t0 = t0.square(); //          161: 14518714321960041535931877697269943063888
+    t0 = t0.square(); //          162: 29037428643920083071863755394539886127776
+    t0 = t0.square(); //          163: 58074857287840166143727510789079772255552
+    t0 *= t11; //                 164: 58074857287840166143727510789079772255573
+    t0 = t0.square(); //          165: 116149714575680332287455021578159544511146
+    t0 = t0.square(); //          166: 232299429151360664574910043156319089022292
+    t0 = t0.square(); //          167: 464598858302721329149820086312638178044584
+    t0 = t0.square(); //          168: 929197716605442658299640172625276356089168
+    t0 = t0.square(); //          169: 1858395433210885316599280345250552712178336
+    t0 = t0.square(); //          170: 3716790866421770633198560690501105424356672
+    t0 *= t11; //                 171: 3716790866421770633198560690501105424356693
+    t0 = t0.square(); //          172: 7433581732843541266397121381002210848713386
+    t0 = t0.square(); //          173: 14867163465687082532794242762004421697426772
+    t0 = t0.square(); //          174: 29734326931374165065588485524008843394853544
+    t0 = t0.square(); //          175: 59468653862748330131176971048017686789707088
+    t0 = t0.square(); //          176: 118937307725496660262353942096035373579414176
+    t0 = t0.square(); //          177: 237874615450993320524707884192070747158828352
+    t0 *= t11; //                 178: 237874615450993320524707884192070747158828373
+    t0 = t0.square(); //          179: 475749230901986641049415768384141494317656746
+    t0 = t0.square(); //          180: 951498461803973282098831536768282988635313492
+    t0 = t0.square(); //          181: 1902996923607946564197663073536565977270626984
+    t0 = t0.square(); //          182: 3805993847215893128395326147073131954541253968
+    t0 = t0.square(); //          183: 7611987694431786256790652294146263909082507936
+    t0 = t0.square(); //          184: 15223975388863572513581304588292527818165015872
+    t0 *= t11; //                 185: 15223975388863572513581304588292527818165015893
+    t0 = t0.square(); //          186: 30447950777727145027162609176585055636330031786
+    t0 = t0.square(); //          187: 60895901555454290054325218353170111272660063572
+    t0 = t0.square(); //          188: 121791803110908580108650436706340222545320127144
+    t0 = t0.square(); //          189: 243583606221817160217300873412680445090640254288
+    t0 = t0.square(); //          190: 487167212443634320434601746825360890181280508576
+    t0 = t0.square(); //          191: 974334424887268640869203493650721780362561017152
+    t0 *= t11; //                 192: 974334424887268640869203493650721780362561017173
+    t0 = t0.square(); //          193: 1948668849774537281738406987301443560725122034346
+    t0 = t0.square(); //          194: 3897337699549074563476813974602887121450244068692
+    t0 = t0.square(); //          195: 7794675399098149126953627949205774242900488137384
+    t0 = t0.square(); //          196: 15589350798196298253907255898411548485800976274768
+    t0 = t0.square(); //          197: 31178701596392596507814511796823096971601952549536
+    t0 = t0.square(); //          198: 62357403192785193015629023593646193943203905099072
+    t0 *= t11; //                 199: 62357403192785193015629023593646193943203905099093
+    t0 = t0.square(); //          200: 124714806385570386031258047187292387886407810198186
+    t0 = t0.square(); //          201: 249429612771140772062516094374584775772815620396372
+    t0 = t0.square(); //          202: 498859225542281544125032188749169551545631240792744
+    t0 = t0.square(); //          203: 997718451084563088250064377498339103091262481585488
+    t0 = t0.square(); //          204: 1995436902169126176500128754996678206182524963170976
+    t0 = t0.square(); //          205: 3990873804338252353000257509993356412365049926341952
+    t0 *= t11; //                 206: 3990873804338252353000257509993356412365049926341973
+    t0 = t0.square(); //          207: 7981747608676504706000515019986712824730099852683946
+    t0 = t0.square(); //          208: 15963495217353009412001030039973425649460199705367892
+    t0 = t0.square(); //          209: 31926990434706018824002060079946851298920399410735784
+    t0 = t0.square(); //          210: 63853980869412037648004120159893702597840798821471568
+    t0 = t0.square(); //          211: 127707961738824075296008240319787405195681597642943136
+    t0 = t0.square(); //          212: 255415923477648150592016480639574810391363195285886272
+    t0 *= t11; //                 213: 255415923477648150592016480639574810391363195285886293
+    t0 = t0.square(); //          214: 510831846955296301184032961279149620782726390571772586
+    t0 = t0.square(); //          215: 1021663693910592602368065922558299241565452781143545172
+    t0 = t0.square(); //          216: 2043327387821185204736131845116598483130905562287090344
+    t0 = t0.square(); //          217: 4086654775642370409472263690233196966261811124574180688
+    t0 = t0.square(); //          218: 8173309551284740818944527380466393932523622249148361376
+    t0 = t0.square(); //          219: 16346619102569481637889054760932787865047244498296722752
+    t0 *= t11; //                 220: 16346619102569481637889054760932787865047244498296722773
+    t0 = t0.square(); //          221: 32693238205138963275778109521865575730094488996593445546
+    t0 = t0.square(); //          222: 65386476410277926551556219043731151460188977993186891092
+    t0 = t0.square(); //          223: 130772952820555853103112438087462302920377955986373782184
+    t0 = t0.square(); //          224: 261545905641111706206224876174924605840755911972747564368
+    t0 = t0.square(); //          225: 523091811282223412412449752349849211681511823945495128736
+    t0 = t0.square(); //          226: 1046183622564446824824899504699698423363023647890990257472
+    t0 *= t11; //                 227: 1046183622564446824824899504699698423363023647890990257493
+    t0 = t0.square(); //          228: 2092367245128893649649799009399396846726047295781980514986
+    t0 = t0.square(); //          229: 4184734490257787299299598018798793693452094591563961029972
+    t0 = t0.square(); //          230: 8369468980515574598599196037597587386904189183127922059944
+    t0 = t0.square(); //          231: 16738937961031149197198392075195174773808378366255844119888
+    t0 = t0.square(); //          232: 33477875922062298394396784150390349547616756732511688239776
+    t0 = t0.square(); //          233: 66955751844124596788793568300780699095233513465023376479552
+    t0 *= t11; //                 234: 66955751844124596788793568300780699095233513465023376479573
+    t0 = t0.square(); //          235: 133911503688249193577587136601561398190467026930046752959146
+    t0 = t0.square(); //          236: 267823007376498387155174273203122796380934053860093505918292
+    t0 = t0.square(); //          237: 535646014752996774310348546406245592761868107720187011836584
+    t0 = t0.square(); //          238: 1071292029505993548620697092812491185523736215440374023673168
+    t0 = t0.square(); //          239: 2142584059011987097241394185624982371047472430880748047346336
+    t0 = t0.square(); //          240: 4285168118023974194482788371249964742094944861761496094692672
+    t0 *= t11; //                 241: 4285168118023974194482788371249964742094944861761496094692693
+    t0 = t0.square(); //          242: 8570336236047948388965576742499929484189889723522992189385386
+    t0 = t0.square(); //          243: 17140672472095896777931153484999858968379779447045984378770772
+    t0 = t0.square(); //          244: 34281344944191793555862306969999717936759558894091968757541544
+    t0 = t0.square(); //          245: 68562689888383587111724613939999435873519117788183937515083088
+    t0 = t0.square(); //          246: 137125379776767174223449227879998871747038235576367875030166176
+    t0 = t0.square(); //          247: 274250759553534348446898455759997743494076471152735750060332352
+    t0 *= t11; //                 248: 274250759553534348446898455759997743494076471152735750060332373
+    t0 = t0.square(); //          249: 548501519107068696893796911519995486988152942305471500120664746
+    t0 = t0.square(); //          250: 1097003038214137393787593823039990973976305884610943000241329492
+    t0 = t0.square(); //          251: 2194006076428274787575187646079981947952611769221886000482658984
+    t0 = t0.square(); //          252: 4388012152856549575150375292159963895905223538443772000965317968
+    t0 = t0.square(); //          253: 8776024305713099150300750584319927791810447076887544001930635936
+    t0 = t0.square(); //          254: 17552048611426198300601501168639855583620894153775088003861271872
+    t0 *= t11; //                 255: 17552048611426198300601501168639855583620894153775088003861271893
+    t0 = t0.square(); //          256: 35104097222852396601203002337279711167241788307550176007722543786
+    t0 = t0.square(); //          257: 70208194445704793202406004674559422334483576615100352015445087572
+    t0 = t0.square(); //          258: 140416388891409586404812009349118844668967153230200704030890175144
+    t0 = t0.square(); //          259: 280832777782819172809624018698237689337934306460401408061780350288
+    t0 = t0.square(); //          260: 561665555565638345619248037396475378675868612920802816123560700576
+    t0 = t0.square(); //          261: 1123331111131276691238496074792950757351737225841605632247121401152
+    t0 *= t11; //                 262: 1123331111131276691238496074792950757351737225841605632247121401173
+    t0 = t0.square(); //          263: 2246662222262553382476992149585901514703474451683211264494242802346
+    t0 = t0.square(); //          264: 4493324444525106764953984299171803029406948903366422528988485604692
+    t0 = t0.square(); //          265: 8986648889050213529907968598343606058813897806732845057976971209384
+    t0 = t0.square(); //          266: 17973297778100427059815937196687212117627795613465690115953942418768
+    t0 = t0.square(); //          267: 35946595556200854119631874393374424235255591226931380231907884837536
+    t0 = t0.square(); //          268: 71893191112401708239263748786748848470511182453862760463815769675072
+    t0 *= t11; //                 269: 71893191112401708239263748786748848470511182453862760463815769675093
+    t0 = t0.square(); //          270: 143786382224803416478527497573497696941022364907725520927631539350186
+    t0 = t0.square(); //          271: 287572764449606832957054995146995393882044729815451041855263078700372
+    t0 = t0.square(); //          272: 575145528899213665914109990293990787764089459630902083710526157400744
+    t0 = t0.square(); //          273: 1150291057798427331828219980587981575528178919261804167421052314801488
+    t0 = t0.square(); //          274: 2300582115596854663656439961175963151056357838523608334842104629602976
+    t0 = t0.square(); //          275: 4601164231193709327312879922351926302112715677047216669684209259205952
+    t0 *= t11; //                 276: 4601164231193709327312879922351926302112715677047216669684209259205973
+    t0 = t0.square(); //          277: 9202328462387418654625759844703852604225431354094433339368418518411946
+    t0 = t0.square(); //          278: 18404656924774837309251519689407705208450862708188866678736837036823892
+    t0 = t0.square(); //          279: 36809313849549674618503039378815410416901725416377733357473674073647784
+    t0 = t0.square(); //          280: 73618627699099349237006078757630820833803450832755466714947348147295568
+    t0 = t0.square(); //          281: 147237255398198698474012157515261641667606901665510933429894696294591136
+    t0 = t0.square(); //          282: 294474510796397396948024315030523283335213803331021866859789392589182272
+    t0 *= t11; //                 283: 294474510796397396948024315030523283335213803331021866859789392589182293
+    t0 = t0.square(); //          284: 588949021592794793896048630061046566670427606662043733719578785178364586
+    t0 = t0.square(); //          285: 1177898043185589587792097260122093133340855213324087467439157570356729172
+    t0 = t0.square(); //          286: 2355796086371179175584194520244186266681710426648174934878315140713458344
+    t0 = t0.square(); //          287: 4711592172742358351168389040488372533363420853296349869756630281426916688
+    t0 = t0.square(); //          288: 9423184345484716702336778080976745066726841706592699739513260562853833376
+    t0 = t0.square(); //          289: 18846368690969433404673556161953490133453683413185399479026521125707666752
+    t0 *= t11; //                 290: 18846368690969433404673556161953490133453683413185399479026521125707666773
+    t0 = t0.square(); //          291: 37692737381938866809347112323906980266907366826370798958053042251415333546
+    t0 = t0.square(); //          292: 75385474763877733618694224647813960533814733652741597916106084502830667092
+    t0 = t0.square(); //          293: 150770949527755467237388449295627921067629467305483195832212169005661334184
+    t0 = t0.square(); //          294: 301541899055510934474776898591255842135258934610966391664424338011322668368
+    t0 = t0.square(); //          295: 603083798111021868949553797182511684270517869221932783328848676022645336736
+    t0 = t0.square(); //          296: 1206167596222043737899107594365023368541035738443865566657697352045290673472
+    t0 *= t11; //                 297: 1206167596222043737899107594365023368541035738443865566657697352045290673493
+    t0 = t0.square(); //          298: 2412335192444087475798215188730046737082071476887731133315394704090581346986
+    t0 * x //                     299: 2412335192444087475798215188730046737082071476887731133315394704090581346987
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn test_alpha() {
+        let mut a = -Felt::one();
+        for _ in 0..100 {
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+            assert_eq!(exp_inv_alpha(&a).pow([ALPHA as u64]), a);
+            a += a;
+        }
+    }
+}
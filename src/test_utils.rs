@@ -0,0 +1,183 @@
+//! Helpers to test generic code against all Anemoi instantiations
+
+use super::AnemoiParameters;
+use ark_ff::PrimeField;
+
+/// A check to be run against a given Anemoi instantiation.
+pub(crate) trait InstantiationCheck {
+    /// Runs the check against the instantiation over `F` with
+    /// `NUM_COLUMNS` columns and digests of `DIGEST_SIZE` elements.
+    fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+    where
+        F: PrimeField,
+        P: AnemoiParameters<F, NUM_COLUMNS>;
+}
+
+/// Runs the check `$check` against the instantiations of the provided modules.
+#[allow(unused_macros)]
+macro_rules! check_instantiations {
+    ($check:ty; $($instantiation:ident),+) => {{
+        $(
+            <$check>::check::<
+                Felt,
+                { $instantiation::NUM_COLUMNS },
+                { $instantiation::DIGEST_SIZE },
+                $instantiation::AnemoiParams,
+            >();
+        )+
+    }};
+}
+
+/// Runs the provided check against all instantiations enabled through features.
+pub(crate) fn check_all_instantiations<C: InstantiationCheck>() {
+    #[cfg(feature = "baby_bear")]
+    {
+        use crate::baby_bear::*;
+        check_instantiations!(C; anemoi_16_8, anemoi_24_16);
+    }
+    #[cfg(feature = "bls_377")]
+    {
+        use crate::bls12_377::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "bls_381")]
+    {
+        use crate::bls12_381::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "bn_254")]
+    {
+        use crate::bn_254::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "bw6_761")]
+    {
+        use crate::bw6_761::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "curve25519")]
+    {
+        use crate::curve25519::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "ed_on_bls12_377")]
+    {
+        use crate::ed_on_bls12_377::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "grumpkin")]
+    {
+        use crate::grumpkin::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "jubjub")]
+    {
+        use crate::jubjub::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "mersenne_31")]
+    {
+        use crate::mersenne_31::*;
+        check_instantiations!(C; anemoi_16_8, anemoi_24_16);
+    }
+    #[cfg(feature = "mnt4_298")]
+    {
+        use crate::mnt4_298::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "mnt6_298")]
+    {
+        use crate::mnt6_298::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "pallas")]
+    {
+        use crate::pallas::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "secp256k1")]
+    {
+        use crate::secp256k1::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "secq256k1")]
+    {
+        use crate::secq256k1::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "stark_252")]
+    {
+        use crate::stark_252::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "vesta")]
+    {
+        use crate::vesta::*;
+        check_instantiations!(C; anemoi_2_1, anemoi_4_3, anemoi_8_7, anemoi_12_11);
+        #[cfg(feature = "security_80")]
+        check_instantiations!(C; anemoi_2_1_80, anemoi_4_3_80);
+        #[cfg(feature = "security_256")]
+        check_instantiations!(C; anemoi_4_2_256, anemoi_8_6_256, anemoi_12_10_256);
+    }
+    #[cfg(feature = "goldilocks")]
+    {
+        use crate::goldilocks::*;
+        check_instantiations!(C; anemoi_8_4, anemoi_12_8);
+    }
+}
@@ -1,8 +1,35 @@
 use ark_ff::Field;
 
+use crate::{IOPattern, SafeError};
+
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+/// Trait for exposing a cryptographic permutation over field elements,
+/// on top of which custom modes of operation can be built.
+pub trait Permutation<F: Field> {
+    /// The number of field elements in the state.
+    const STATE_WIDTH: usize;
+
+    /// The number of rounds of the permutation.
+    const NUM_ROUNDS: usize;
+
+    /// Applies the permutation to the provided state.
+    ///
+    /// The slice must be of length `STATE_WIDTH`.
+    fn permute(state: &mut [F]);
+
+    /// Applies the round of index `i` of the permutation to the provided state.
+    ///
+    /// The slice must be of length `STATE_WIDTH`.
+    fn round(state: &mut [F], i: usize);
+
+    /// Applies the inverse of the permutation to the provided state.
+    ///
+    /// The slice must be of length `STATE_WIDTH`.
+    fn inverse_permute(state: &mut [F]);
+}
+
 /// Trait for implementing a Sponge construction.
 pub trait Sponge<F: Field> {
     /// Specifies a digest type returned by this hasher.
@@ -20,6 +47,9 @@ pub trait Sponge<F: Field> {
 
 /// Trait for implementing a Jive compression function instantiation.
 pub trait Jive<F: Field> {
+    /// The number of field elements in the underlying hash state.
+    const STATE_WIDTH: usize;
+
     /// Compresses the provided field element slice as input by 2.
     ///
     /// The slice must be of the same length than the underlying hash state.
@@ -29,4 +59,70 @@ pub trait Jive<F: Field> {
     ///
     /// The slice must be of the same length than the underlying hash state.
     fn compress_k(elems: &[F], k: usize) -> Vec<F>;
+
+    /// Compresses the provided field element slice as input by 2,
+    /// personalized with the provided domain.
+    ///
+    /// The slice must be of the same length than the underlying hash state.
+    /// A zero domain yields the same output as `compress`.
+    fn compress_with_domain(elems: &[F], domain: F) -> Vec<F>;
+}
+
+/// Trait for implementing an extendable-output (XOF) mode
+/// on top of a Sponge construction.
+pub trait SpongeXof<F: Field>: Sponge<F> {
+    /// Specifies a reader type to squeeze outputs from.
+    type Reader: XofReader<F>;
+
+    /// Absorbs the provided sequence of bytes and returns
+    /// a reader to squeeze an arbitrary number of outputs.
+    fn xof(bytes: &[u8]) -> Self::Reader;
+
+    /// Absorbs the provided sequence of field elements and returns
+    /// a reader to squeeze an arbitrary number of outputs.
+    fn xof_field(elems: &[F]) -> Self::Reader;
+
+    /// Returns `n` field elements squeezed from the provided sequence of bytes.
+    fn hash_xof(bytes: &[u8], n: usize) -> Vec<F> {
+        Self::xof(bytes).squeeze(n)
+    }
+
+    /// Returns `n` field elements squeezed from the provided sequence of field elements.
+    fn hash_field_xof(elems: &[F], n: usize) -> Vec<F> {
+        Self::xof_field(elems).squeeze(n)
+    }
+}
+
+/// Trait for squeezing outputs of an extendable-output function.
+pub trait XofReader<F: Field> {
+    /// Squeezes the next `n` field elements.
+    fn squeeze(&mut self, n: usize) -> Vec<F>;
+
+    /// Squeezes the next `n` bytes.
+    fn squeeze_bytes(&mut self, n: usize) -> Vec<u8>;
+}
+
+/// Trait for implementing the SAFE (Sponge API for Field Elements) interface,
+/// where all calls to an instance must follow an IO pattern given at start.
+pub trait SafeSponge<F: Field>: Sized {
+    /// Starts a new instance following the provided IO pattern, and
+    /// domain-separated by the provided sequence of bytes.
+    fn start(io_pattern: IOPattern, domain_separator: &[u8]) -> Self;
+
+    /// Absorbs `length` field elements, given by the provided slice.
+    ///
+    /// Returns an error if the slice is not of size `length`, or if this call
+    /// does not match the next operation of the IO pattern.
+    fn absorb(&mut self, length: u32, elems: &[F]) -> Result<(), SafeError>;
+
+    /// Squeezes `length` field elements.
+    ///
+    /// Returns an error if this call does not match the next operation
+    /// of the IO pattern.
+    fn squeeze(&mut self, length: u32) -> Result<Vec<F>, SafeError>;
+
+    /// Finishes this instance, erasing its internal state.
+    ///
+    /// Returns an error if the IO pattern has not been entirely performed.
+    fn finish(self) -> Result<(), SafeError>;
 }
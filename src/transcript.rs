@@ -0,0 +1,285 @@
+//! Fiat-Shamir transcript built on the Anemoi duplex sponge
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::duplex::AnemoiDuplex;
+use super::hasher::{block_bytes, AnemoiXofReader};
+use super::permutation::AnemoiParameters;
+
+use ark_ec::AffineCurve;
+use ark_ff::{FpParameters, PrimeField, ToConstraintField};
+
+// Operation identifiers, absorbed ahead of each label so that
+// different kinds of messages can never be confused with one another.
+const OP_DOMAIN: u64 = 0;
+const OP_FIELD: u64 = 1;
+const OP_BYTES: u64 = 2;
+const OP_POINT: u64 = 3;
+const OP_CHALLENGE: u64 = 4;
+
+/// Number of extra bytes squeezed when deriving a scalar challenge,
+/// so that its reduction modulo the scalar field order is nearly uniform.
+const SCALAR_SECURITY_BYTES: usize = 16;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A Fiat-Shamir transcript over `F` with `NUM_COLUMNS` columns, built on
+/// top of the Anemoi duplex sponge.
+///
+/// Every message is absorbed as an operation identifier, followed by its
+/// length-prefixed label and its length-prefixed content, all encoded as
+/// field elements. This encoding is injective, hence messages or challenges
+/// appended under distinct labels are always domain-separated.
+pub struct AnemoiTranscript<F: PrimeField, const NUM_COLUMNS: usize, P> {
+    duplex: AnemoiDuplex<F, NUM_COLUMNS, P>,
+}
+
+impl<F, const NUM_COLUMNS: usize, P> AnemoiTranscript<F, NUM_COLUMNS, P>
+where
+    F: PrimeField,
+    P: AnemoiParameters<F, NUM_COLUMNS>,
+{
+    /// The number of bytes that can be safely packed into a field element.
+    pub(crate) const BYTES_PER_ELEMENT: usize = (F::Params::CAPACITY / 8) as usize;
+
+    /// The number of squeezed field elements combined into each block of
+    /// challenge bytes, as in `AnemoiXofReader`.
+    const ELEMENTS_PER_BLOCK: usize = AnemoiXofReader::<F, NUM_COLUMNS, P>::ELEMENTS_PER_BLOCK;
+
+    /// The number of challenge bytes extracted from each block of squeezed
+    /// field elements, as in `AnemoiXofReader`.
+    const BYTES_PER_BLOCK: usize = AnemoiXofReader::<F, NUM_COLUMNS, P>::BYTES_PER_BLOCK;
+
+    /// Returns a new transcript for the protocol identified by `label`.
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Self {
+            duplex: AnemoiDuplex::new(),
+        };
+        transcript.append_label(OP_DOMAIN, label);
+
+        transcript
+    }
+
+    /// Appends a sequence of field elements to the transcript under `label`.
+    pub fn append_field(&mut self, label: &[u8], elems: &[F]) {
+        self.append_label(OP_FIELD, label);
+        self.duplex.absorb(&[F::from(elems.len() as u64)]);
+        self.duplex.absorb(elems);
+    }
+
+    /// Appends a sequence of bytes to the transcript under `label`.
+    pub fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.append_label(OP_BYTES, label);
+        self.absorb_bytes(bytes);
+    }
+
+    /// Appends a point of a curve defined over `F` to the
+    /// transcript under `label`, through its affine coordinates.
+    pub fn append_point<G>(&mut self, label: &[u8], point: &G)
+    where
+        G: AffineCurve<BaseField = F> + ToConstraintField<F>,
+    {
+        // The conversion of a point over its own base field never fails.
+        let coordinates = point.to_field_elements().unwrap();
+
+        self.append_label(OP_POINT, label);
+        self.duplex.absorb(&[F::from(coordinates.len() as u64)]);
+        self.duplex.absorb(&coordinates);
+    }
+
+    /// Returns a challenge field element bound to the
+    /// current state of the transcript and to `label`.
+    pub fn challenge_field(&mut self, label: &[u8]) -> F {
+        self.challenge_fields(label, 1)[0]
+    }
+
+    /// Returns `n` challenge field elements bound to the
+    /// current state of the transcript and to `label`.
+    pub fn challenge_fields(&mut self, label: &[u8], n: usize) -> Vec<F> {
+        self.append_label(OP_CHALLENGE, label);
+
+        self.duplex.squeeze(n)
+    }
+
+    /// Returns a challenge scalar of an arbitrary prime field `S`, typically
+    /// the scalar field of a curve defined over `F`, bound to the current
+    /// state of the transcript and to `label`.
+    ///
+    /// The squeezed field elements are not uniform bytes, as the order of `F`
+    /// is not a power of two. They are hence converted to bytes as in
+    /// `AnemoiXofReader::squeeze_bytes`, by blocks of `ELEMENTS_PER_BLOCK`
+    /// elements from which only `BYTES_PER_BLOCK` bytes are extracted, each
+    /// block being within a statistical distance of `2^-SECURITY_LEVEL` to
+    /// uniform. The scalar is then obtained by reducing enough of those bytes
+    /// modulo the order of `S` for the result to be statistically close to
+    /// uniform.
+    pub fn challenge_scalar<S: PrimeField>(&mut self, label: &[u8]) -> S {
+        let num_bytes = (S::Params::MODULUS_BITS as usize).div_ceil(8) + SCALAR_SECURITY_BYTES;
+        let num_blocks = num_bytes.div_ceil(Self::BYTES_PER_BLOCK);
+
+        let elements = self.challenge_fields(label, num_blocks * Self::ELEMENTS_PER_BLOCK);
+        let bytes: Vec<u8> = elements
+            .chunks(Self::ELEMENTS_PER_BLOCK)
+            .flat_map(|block| block_bytes(block, Self::BYTES_PER_BLOCK))
+            .collect();
+
+        S::from_le_bytes_mod_order(&bytes[..num_bytes])
+    }
+
+    /// Absorbs an operation identifier followed by the provided label.
+    fn append_label(&mut self, op: u64, label: &[u8]) {
+        self.duplex.absorb(&[F::from(op)]);
+        self.absorb_bytes(label);
+    }
+
+    /// Absorbs the length of `bytes` followed by their packing
+    /// into field elements of `BYTES_PER_ELEMENT` bytes each.
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.duplex.absorb(&[F::from(bytes.len() as u64)]);
+
+        let elems: Vec<F> = bytes
+            .chunks(Self::BYTES_PER_ELEMENT)
+            .map(F::from_le_bytes_mod_order)
+            .collect();
+        self.duplex.absorb(&elems);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{check_all_instantiations, InstantiationCheck};
+    use rand_core::OsRng;
+
+    struct TranscriptCheck;
+
+    impl InstantiationCheck for TranscriptCheck {
+        fn check<F, const NUM_COLUMNS: usize, const DIGEST_SIZE: usize, P>()
+        where
+            F: PrimeField,
+            P: AnemoiParameters<F, NUM_COLUMNS>,
+        {
+            let mut rng = OsRng;
+            let elems: Vec<F> = (0..5).map(|_| F::rand(&mut rng)).collect();
+
+            let run = |append: &dyn Fn(&mut AnemoiTranscript<F, NUM_COLUMNS, P>)| {
+                let mut transcript = AnemoiTranscript::<F, NUM_COLUMNS, P>::new(b"protocol");
+                append(&mut transcript);
+                transcript.challenge_field(b"challenge")
+            };
+
+            let challenge = run(&|t| {
+                t.append_field(b"elems", &elems);
+                t.append_bytes(b"bytes", b"message");
+            });
+
+            // Transcripts are deterministic.
+            assert_eq!(
+                challenge,
+                run(&|t| {
+                    t.append_field(b"elems", &elems);
+                    t.append_bytes(b"bytes", b"message");
+                })
+            );
+
+            // Labels, their boundaries and message kinds are all domain-separated.
+            assert_ne!(
+                challenge,
+                run(&|t| {
+                    t.append_field(b"other", &elems);
+                    t.append_bytes(b"bytes", b"message");
+                })
+            );
+            assert_ne!(
+                run(&|t| t.append_bytes(b"ab", b"c")),
+                run(&|t| t.append_bytes(b"a", b"bc"))
+            );
+            assert_ne!(
+                run(&|t| t.append_field(b"label", &[F::from(7u64)])),
+                run(&|t| t.append_bytes(b"label", &[7]))
+            );
+            assert_ne!(
+                run(&|t| t.append_field(b"label", &elems[..2])),
+                run(&|t| {
+                    t.append_field(b"label", &elems[..1]);
+                    t.append_field(b"label", &elems[1..2]);
+                })
+            );
+
+            // Successive challenges differ, and depend on their label.
+            let mut transcript_1 = AnemoiTranscript::<F, NUM_COLUMNS, P>::new(b"protocol");
+            let mut transcript_2 = AnemoiTranscript::<F, NUM_COLUMNS, P>::new(b"protocol");
+            let mut transcript_3 = AnemoiTranscript::<F, NUM_COLUMNS, P>::new(b"protocol");
+            let challenge_1 = transcript_1.challenge_field(b"a");
+            assert_ne!(challenge_1, transcript_1.challenge_field(b"a"));
+            assert_eq!(challenge_1, transcript_2.challenge_field(b"a"));
+            assert_ne!(challenge_1, transcript_3.challenge_field(b"b"));
+
+            let mut transcript_4 = AnemoiTranscript::<F, NUM_COLUMNS, P>::new(b"protocol");
+            transcript_4.challenge_field(b"a");
+            let scalar: F = transcript_2.challenge_scalar(b"scalar");
+            assert_eq!(scalar, transcript_4.challenge_scalar(b"scalar"));
+
+            // Each block of squeezed elements holds at least one byte, and
+            // its extracted bytes are within a statistical distance of
+            // 2^-SECURITY_LEVEL to uniform, i.e. 2^(8b) / p^k <= 2^-SECURITY_LEVEL.
+            let num_elements = AnemoiTranscript::<F, NUM_COLUMNS, P>::ELEMENTS_PER_BLOCK;
+            let num_bytes = AnemoiTranscript::<F, NUM_COLUMNS, P>::BYTES_PER_BLOCK;
+            assert!(num_bytes >= 1);
+            assert!(
+                8 * num_bytes + P::SECURITY_LEVEL as usize
+                    <= num_elements * (F::Params::MODULUS_BITS as usize - 1)
+            );
+
+            // Challenge scalars are reduced from the bytes extracted from
+            // such blocks, and not from the low bytes of each element.
+            let mut transcript = AnemoiTranscript::<F, NUM_COLUMNS, P>::new(b"protocol");
+            let scalar: F =
+                AnemoiTranscript::<F, NUM_COLUMNS, P>::new(b"protocol").challenge_scalar(b"scalar");
+            let total_bytes =
+                (F::Params::MODULUS_BITS as usize).div_ceil(8) + SCALAR_SECURITY_BYTES;
+            let num_blocks = total_bytes.div_ceil(num_bytes);
+            let bytes: Vec<u8> = transcript
+                .challenge_fields(b"scalar", num_blocks * num_elements)
+                .chunks(num_elements)
+                .flat_map(|block| block_bytes(block, num_bytes))
+                .collect();
+            assert_eq!(scalar, F::from_le_bytes_mod_order(&bytes[..total_bytes]));
+        }
+    }
+
+    #[test]
+    fn test_transcript() {
+        check_all_instantiations::<TranscriptCheck>();
+    }
+
+    #[cfg(feature = "bls_381")]
+    #[test]
+    fn test_transcript_points() {
+        use crate::bls12_381::Transcript;
+        use ark_bls12_381::{Fr, G1Affine};
+        use ark_ec::ProjectiveCurve;
+        use ark_ff::{UniformRand, Zero};
+
+        let mut rng = OsRng;
+        let point = G1Affine::prime_subgroup_generator()
+            .mul(Fr::rand(&mut rng))
+            .into_affine();
+
+        let run = |point: &G1Affine| {
+            let mut transcript = Transcript::new(b"protocol");
+            transcript.append_point(b"point", point);
+            transcript.challenge_scalar::<Fr>(b"challenge")
+        };
+
+        let challenge = run(&point);
+        assert_eq!(challenge, run(&point));
+        assert_ne!(challenge, run(&-point));
+        assert_ne!(challenge, run(&G1Affine::zero()));
+
+        // A point is not confused with its sequence of coordinates.
+        let mut transcript = Transcript::new(b"protocol");
+        transcript.append_field(b"point", &point.to_field_elements().unwrap());
+        assert_ne!(challenge, transcript.challenge_scalar::<Fr>(b"challenge"));
+    }
+}
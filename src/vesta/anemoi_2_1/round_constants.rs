@@ -1,239 +1,239 @@
 use super::BigInteger256;
 use super::Felt;
-use super::NUM_HASH_ROUNDS;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
 
 /// Additive round constants C for Anemoi.
-pub(crate) const C: [Felt; NUM_HASH_ROUNDS] = [
-    Felt::new(BigInteger256([
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
         0x7346fc0cffffff6d,
         0x517627467fa308c8,
         0xffffffffffffffec,
         0x3fffffffffffffff,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xba0d6b9b3fa3633d,
         0xf99eef39c529d723,
         0x7af28749f7395589,
         0x02ef61c6b77a510f,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x698d33f778f7c4fa,
         0x870adf3fd9e4ec90,
         0xa084add0a525fa2d,
         0x1ac8048f2e389485,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x79b834feef2c3839,
         0xaae19cadc9407e29,
         0x66d36f5b19289453,
         0x34440a184a180800,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x8f9d974c0fddcdaa,
         0x451b85da76eaa3bb,
         0x4f05589eb4cd7c68,
         0x14e18498bd951684,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x32e5e8b5e721710e,
         0x783d8096d132985c,
         0x428ca9de5e3fe006,
         0x384cfb58bd05691c,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x271faad71772fc51,
         0xe95b52fe5732e618,
         0xd0504b779f895c73,
         0x3cf7dffba61d8a86,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xebfc726afe288f17,
         0x105c9600c55d5461,
         0x6e45121d3212bccb,
         0x277db81baa443223,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xca19b06b4e6df2a3,
         0xcce22050a4ad7858,
         0xc644747d2cae1918,
         0x177eaf84e1d0f4c9,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x4cd177b08d9633fb,
         0x77467f1fb1ef8f62,
         0x4bf0ddb4360a9750,
         0x27a64389729f63b6,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x812fab45562ff73f,
         0x2325cf23f7b477de,
         0xd37cdf06721fd649,
         0x1b8b993601740b7d,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xf065d1802ed61450,
         0xa8e06df42094ff2c,
         0x71252e1be9f0cf66,
         0x2a462608860e473c,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x8a42005618a3c2ce,
         0x66ac2f02865236a3,
         0xf0639c434ed30775,
         0x2f18f6f191e2cc6a,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x7fd98f7e1bafe818,
         0xd383016c29da2fe8,
         0xb9f93235e6b59e91,
         0x2773260c3b41acbb,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xef4c9d850d8e1164,
         0x5dfa23883bb53772,
         0x2cd7e6067b06dee7,
         0x29e1ae252dc93fb3,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xed6a2deeb907ba7e,
         0xeb5fa1abdadad8cf,
         0x6cf3f740f4039d74,
         0x048982945f76c93c,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x1cd906f0bd47baa3,
         0x2b289297eb1be753,
         0x62adb274632bae54,
         0x0b89b47c3a6539fa,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xae0038cb1b319dd5,
         0x57840f96d9e9e629,
         0x88ce079537e2e089,
         0x2d8c944ddb13cc8c,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x0c543ab2b002e44e,
         0xb3df2c0368bed662,
         0x1075c7e44e0fed20,
         0x1b47985d6a922230,
-    ])),
+    ]))],
 ];
 
 /// Additive round constants D for Anemoi.
-pub(crate) const D: [Felt; NUM_HASH_ROUNDS] = [
-    Felt::new(BigInteger256([
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [Felt::new(BigInteger256([
         0xf93bea3e99999905,
         0xe76b98e6704ec765,
         0xccccccccccccccb8,
         0x0ccccccccccccccc,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x2b5942a60725fb0e,
         0x82fa22a8633a158e,
         0x7222c0fd00a61c88,
         0x3d0f46fc36cb6075,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xe978ee1860aee0be,
         0x3109d849e842304e,
         0x9e6a224077aac46f,
         0x2ad600d144e2d50c,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xb57e8ab7feaec69e,
         0x2f7a9fdbb5f3e6e1,
         0xf227f11a3b85b6a4,
         0x10e8ae3876dd9e99,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x828b305803f5fd96,
         0x862a411d717cf719,
         0x0f9c5b2bcc9d5b90,
         0x356fb258c8b075f6,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x6ce057804b0a17ad,
         0x916a08e0fd6a7e29,
         0x041f00529db459c9,
         0x2a8b216cdae77b2e,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xfc82d052d6df88cf,
         0xe94aae1c2867a290,
         0xef69272bbe5d7e80,
         0x05a2f82d7ef1affb,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xc848163e0d44e6d8,
         0xe3d056079f9b7269,
         0x9803ca32145c41bf,
         0x053c63e6cdf2161b,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x105609aeb533f374,
         0x2bf1e08032a32853,
         0x4a62c0966a99a8eb,
         0x142f1db7015fc17b,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x05b85a24ac81294a,
         0xb4ce7d0a14651042,
         0xa2fa7a5c6a57a856,
         0x312eda050b089406,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x0c4948be505aaede,
         0xd3d3bb2402b8bb13,
         0x0b4a72becb400a63,
         0x01351a8eec56175d,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x086657bb8f486269,
         0xbe9809521aee1a62,
         0x0e984b1d04b2a72f,
         0x1f10220f1dd01c47,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x9de6ae95c2bf5fc6,
         0x4898a92fb476a665,
         0x366c9bae3148a0e4,
         0x39f632468836ec30,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x544437c8b9eb5d1a,
         0x558307805b544380,
         0x22e6cd65805104c2,
         0x37858dfffbba37a7,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xe6fdd88dde787d78,
         0xfc73c515a9ae02dd,
         0x765fcb9fd0874202,
         0x2556b767fb0f66fc,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xbe23d660d0c1ba31,
         0xc7e19ca47597940b,
         0xd886c6c5b19347d7,
         0x3999466d95a53c8c,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x11e904906fcb4839,
         0xff5a34fea28f5b1c,
         0x48ac78f66d1eb212,
         0x0638614229272ad4,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0x6e1f39e0db24efcb,
         0x9b300f43201911fa,
         0x25f80103810854a7,
         0x060fd8a0bbd58b37,
-    ])),
-    Felt::new(BigInteger256([
+    ]))],
+    [Felt::new(BigInteger256([
         0xf0f7eade770cd809,
         0x4c73ee292ec8b1c7,
         0x8fb678d0e163a00c,
         0x219d2f5520a30535,
-    ])),
+    ]))],
 ];
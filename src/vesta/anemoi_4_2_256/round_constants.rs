@@ -0,0 +1,571 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x7346fc0cffffff6d,
+            0x517627467fa308c8,
+            0xffffffffffffffec,
+            0x3fffffffffffffff,
+        ])),
+        Felt::new(BigInteger256([
+            0xdd1178d05cddbbb9,
+            0xbd8c24862fe7c4d1,
+            0x04dfdb230c1bf060,
+            0x3659b14ca19b3891,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xba0d6b9b3fa3633d,
+            0xf99eef39c529d723,
+            0x7af28749f7395589,
+            0x02ef61c6b77a510f,
+        ])),
+        Felt::new(BigInteger256([
+            0xe0b3151465440acd,
+            0x3b48888c6c5565be,
+            0x5f2d2585f096804e,
+            0x291429a9ea04cb3f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x698d33f778f7c4fa,
+            0x870adf3fd9e4ec90,
+            0xa084add0a525fa2d,
+            0x1ac8048f2e389485,
+        ])),
+        Felt::new(BigInteger256([
+            0x6056828de708b943,
+            0x825dbbe3f5424de2,
+            0xf6baed51e54a76a3,
+            0x19f87a536cf65d5a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x79b834feef2c3839,
+            0xaae19cadc9407e29,
+            0x66d36f5b19289453,
+            0x34440a184a180800,
+        ])),
+        Felt::new(BigInteger256([
+            0xdcfec7f06a770db9,
+            0x19fc3b99f8731eb8,
+            0x573b5ec5a02fb0a5,
+            0x3211ce60c0dde888,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8f9d974c0fddcdaa,
+            0x451b85da76eaa3bb,
+            0x4f05589eb4cd7c68,
+            0x14e18498bd951684,
+        ])),
+        Felt::new(BigInteger256([
+            0xcfae98be18a0c4e7,
+            0x4fed38d71eb4bfff,
+            0xf0725685381c83aa,
+            0x092a2f60cb5606b4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x32e5e8b5e721710e,
+            0x783d8096d132985c,
+            0x428ca9de5e3fe006,
+            0x384cfb58bd05691c,
+        ])),
+        Felt::new(BigInteger256([
+            0x1d55ea153e3948c1,
+            0x402e3d750f834353,
+            0x0760b8cf40c32b3c,
+            0x29d57ef2fd77eb59,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x271faad71772fc51,
+            0xe95b52fe5732e618,
+            0xd0504b779f895c73,
+            0x3cf7dffba61d8a86,
+        ])),
+        Felt::new(BigInteger256([
+            0xf8159070f5824aa5,
+            0xcb8739dac5ec94dc,
+            0x426cf3f16f092a30,
+            0x28c71fceb13f06a1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xebfc726afe288f17,
+            0x105c9600c55d5461,
+            0x6e45121d3212bccb,
+            0x277db81baa443223,
+        ])),
+        Felt::new(BigInteger256([
+            0x79c027999f48d390,
+            0xabc4f9e711570b5d,
+            0xd9b640bcb4a40845,
+            0x3abd153ea3d7be1e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xca19b06b4e6df2a3,
+            0xcce22050a4ad7858,
+            0xc644747d2cae1918,
+            0x177eaf84e1d0f4c9,
+        ])),
+        Felt::new(BigInteger256([
+            0xb89f7e263bff609c,
+            0x48305c88fa7bbe57,
+            0xee816831d64ba437,
+            0x337af74a01eda4c5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4cd177b08d9633fb,
+            0x77467f1fb1ef8f62,
+            0x4bf0ddb4360a9750,
+            0x27a64389729f63b6,
+        ])),
+        Felt::new(BigInteger256([
+            0xe49efb7d804e4de7,
+            0xd6886c7479f7090a,
+            0x612144415c31d7b3,
+            0x3f53c94988bb2f1e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x812fab45562ff73f,
+            0x2325cf23f7b477de,
+            0xd37cdf06721fd649,
+            0x1b8b993601740b7d,
+        ])),
+        Felt::new(BigInteger256([
+            0xc9b58a29456f8b55,
+            0x1984fa73238ebb53,
+            0xef18cd32c4784108,
+            0x0517a92b8524e547,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf065d1802ed61450,
+            0xa8e06df42094ff2c,
+            0x71252e1be9f0cf66,
+            0x2a462608860e473c,
+        ])),
+        Felt::new(BigInteger256([
+            0x3294a4bfa7b90e2e,
+            0x1c5d13295d13a850,
+            0xc266e7734d3ba80b,
+            0x1dd1dda4fa6468fb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8a42005618a3c2ce,
+            0x66ac2f02865236a3,
+            0xf0639c434ed30775,
+            0x2f18f6f191e2cc6a,
+        ])),
+        Felt::new(BigInteger256([
+            0xd6581d3b5a832ac6,
+            0x71fb2ebefc82605c,
+            0xb59bbf08c5b4fbc4,
+            0x290483c2bb812c48,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x7fd98f7e1bafe818,
+            0xd383016c29da2fe8,
+            0xb9f93235e6b59e91,
+            0x2773260c3b41acbb,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d7c1f69c9a4d6af,
+            0x1ca14c17e9fb6837,
+            0x2f66e6aa9e9fcb5b,
+            0x31eeea22ed1cca7d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xef4c9d850d8e1164,
+            0x5dfa23883bb53772,
+            0x2cd7e6067b06dee7,
+            0x29e1ae252dc93fb3,
+        ])),
+        Felt::new(BigInteger256([
+            0xa98032d0fc9f1d51,
+            0x9e8a1cd3028edb47,
+            0x843b63627fd6f136,
+            0x3239e197e71a4435,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xed6a2deeb907ba7e,
+            0xeb5fa1abdadad8cf,
+            0x6cf3f740f4039d74,
+            0x048982945f76c93c,
+        ])),
+        Felt::new(BigInteger256([
+            0xcd63a0895a50f349,
+            0xbe8857f0d546088d,
+            0xa5fb564709e675d8,
+            0x090fb284de692c67,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x1cd906f0bd47baa3,
+            0x2b289297eb1be753,
+            0x62adb274632bae54,
+            0x0b89b47c3a6539fa,
+        ])),
+        Felt::new(BigInteger256([
+            0x0b6356bc611c97ec,
+            0x14c09e3e9fe49093,
+            0x184eac754fd9dbfa,
+            0x29f1286022f3dbd8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xae0038cb1b319dd5,
+            0x57840f96d9e9e629,
+            0x88ce079537e2e089,
+            0x2d8c944ddb13cc8c,
+        ])),
+        Felt::new(BigInteger256([
+            0x27107656a75084d4,
+            0x16afa83dbd120ecd,
+            0xec4ebef5f095e568,
+            0x20bdd87b6b8927b5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0c543ab2b002e44e,
+            0xb3df2c0368bed662,
+            0x1075c7e44e0fed20,
+            0x1b47985d6a922230,
+        ])),
+        Felt::new(BigInteger256([
+            0x5cb3460b7691bfc7,
+            0xf32c05de3f7f8794,
+            0x6b828827878c4f66,
+            0x3ec42bcbf489856a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x093a657f5542d952,
+            0x5de38d80f00d36cd,
+            0x2164c901c12f9601,
+            0x13da131feb5c319e,
+        ])),
+        Felt::new(BigInteger256([
+            0x6572f34c15545364,
+            0x268eb79e377929cb,
+            0x1d5605aca84f9db6,
+            0x25b72c7633a59e69,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xf93bea3e99999905,
+            0xe76b98e6704ec765,
+            0xccccccccccccccb8,
+            0x0ccccccccccccccc,
+        ])),
+        Felt::new(BigInteger256([
+            0x54b77d06498c2839,
+            0x615c40f05b9d31ea,
+            0x2c76c6a290adc30e,
+            0x2421f328b24eb6ab,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2b5942a60725fb0e,
+            0x82fa22a8633a158e,
+            0x7222c0fd00a61c88,
+            0x3d0f46fc36cb6075,
+        ])),
+        Felt::new(BigInteger256([
+            0x2b222be17fdb7584,
+            0x8df134cd324600e9,
+            0xb1277debb1c84d2d,
+            0x042f83eead3c8bf2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe978ee1860aee0be,
+            0x3109d849e842304e,
+            0x9e6a224077aac46f,
+            0x2ad600d144e2d50c,
+        ])),
+        Felt::new(BigInteger256([
+            0x45ac679221d4a7ee,
+            0x17f0c6bc3514973e,
+            0x4f6a80746f9446c6,
+            0x0b01eba4c7874f2f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb57e8ab7feaec69e,
+            0x2f7a9fdbb5f3e6e1,
+            0xf227f11a3b85b6a4,
+            0x10e8ae3876dd9e99,
+        ])),
+        Felt::new(BigInteger256([
+            0x0a7633adcd0e6f06,
+            0xac6fe992203035ec,
+            0x3d59ff377a51d8d6,
+            0x2fb1e790318a306f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x828b305803f5fd96,
+            0x862a411d717cf719,
+            0x0f9c5b2bcc9d5b90,
+            0x356fb258c8b075f6,
+        ])),
+        Felt::new(BigInteger256([
+            0x28065cad5fcdc7ba,
+            0x7c9005e84abc18fb,
+            0x0bd377c507b168b3,
+            0x0ab3d2301a581774,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6ce057804b0a17ad,
+            0x916a08e0fd6a7e29,
+            0x041f00529db459c9,
+            0x2a8b216cdae77b2e,
+        ])),
+        Felt::new(BigInteger256([
+            0x49016ee3f536c248,
+            0x6735708976c4d79b,
+            0x23bd2df637fcaae0,
+            0x3d0f1a165f40aeb8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfc82d052d6df88cf,
+            0xe94aae1c2867a290,
+            0xef69272bbe5d7e80,
+            0x05a2f82d7ef1affb,
+        ])),
+        Felt::new(BigInteger256([
+            0xbf29cbf10803aa0b,
+            0xd9513fc2d22affd0,
+            0xbc4fee5845a2521e,
+            0x126dad0fcdf9dd63,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc848163e0d44e6d8,
+            0xe3d056079f9b7269,
+            0x9803ca32145c41bf,
+            0x053c63e6cdf2161b,
+        ])),
+        Felt::new(BigInteger256([
+            0x47bce1710179fe39,
+            0x8d1364b8269ed7e0,
+            0x5e3f17844eb2931b,
+            0x397736190b6c5364,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x105609aeb533f374,
+            0x2bf1e08032a32853,
+            0x4a62c0966a99a8eb,
+            0x142f1db7015fc17b,
+        ])),
+        Felt::new(BigInteger256([
+            0x6446024cf5da3454,
+            0x92d42e86b9e673ef,
+            0xcd69d2fdcbfc39ea,
+            0x1126da8b656322c4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x05b85a24ac81294a,
+            0xb4ce7d0a14651042,
+            0xa2fa7a5c6a57a856,
+            0x312eda050b089406,
+        ])),
+        Felt::new(BigInteger256([
+            0x02f008d4f24e161d,
+            0xffa47c2d0de18f88,
+            0x12f4ff9c4843ee9a,
+            0x29d7d4d4650b10bc,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x0c4948be505aaede,
+            0xd3d3bb2402b8bb13,
+            0x0b4a72becb400a63,
+            0x01351a8eec56175d,
+        ])),
+        Felt::new(BigInteger256([
+            0x46803da692af15dc,
+            0xd80d913d699cad03,
+            0x81b07f9dd55d7b03,
+            0x0bbc9f93b3eda274,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x086657bb8f486269,
+            0xbe9809521aee1a62,
+            0x0e984b1d04b2a72f,
+            0x1f10220f1dd01c47,
+        ])),
+        Felt::new(BigInteger256([
+            0x3c4640ff5b402f2f,
+            0x3fef595192767200,
+            0xbaa423271fc285b5,
+            0x33974ebad60cef53,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x9de6ae95c2bf5fc6,
+            0x4898a92fb476a665,
+            0x366c9bae3148a0e4,
+            0x39f632468836ec30,
+        ])),
+        Felt::new(BigInteger256([
+            0x4f66f65e57b39aa5,
+            0x3f7bbaba5c1bd5bc,
+            0x566edd265fef9b14,
+            0x14dd3426f5bbfd5b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x544437c8b9eb5d1a,
+            0x558307805b544380,
+            0x22e6cd65805104c2,
+            0x37858dfffbba37a7,
+        ])),
+        Felt::new(BigInteger256([
+            0x9750f297baf51e98,
+            0x8a3563fa4cea816c,
+            0xf31ea08cf000376c,
+            0x22fcc725f17c06b5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe6fdd88dde787d78,
+            0xfc73c515a9ae02dd,
+            0x765fcb9fd0874202,
+            0x2556b767fb0f66fc,
+        ])),
+        Felt::new(BigInteger256([
+            0x069b98bd209e5c4c,
+            0x2897d02ea1fcac50,
+            0x288d67ae8d1c5a33,
+            0x0eaa5fe9f8471ccc,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbe23d660d0c1ba31,
+            0xc7e19ca47597940b,
+            0xd886c6c5b19347d7,
+            0x3999466d95a53c8c,
+        ])),
+        Felt::new(BigInteger256([
+            0x038773dec51fc5e3,
+            0x869e64b7a177c967,
+            0x6c58447e7f3b261c,
+            0x1f1aeb6d587e5105,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x11e904906fcb4839,
+            0xff5a34fea28f5b1c,
+            0x48ac78f66d1eb212,
+            0x0638614229272ad4,
+        ])),
+        Felt::new(BigInteger256([
+            0x65dd7f3f66b4f869,
+            0xd486527388cd09f9,
+            0x591791aa1191e599,
+            0x059b4a35559c7dff,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6e1f39e0db24efcb,
+            0x9b300f43201911fa,
+            0x25f80103810854a7,
+            0x060fd8a0bbd58b37,
+        ])),
+        Felt::new(BigInteger256([
+            0xd8e08d70ba58a9b2,
+            0x683652b43e4ae918,
+            0xe442d716f1805f67,
+            0x1a3c91dd903197ad,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf0f7eade770cd809,
+            0x4c73ee292ec8b1c7,
+            0x8fb678d0e163a00c,
+            0x219d2f5520a30535,
+        ])),
+        Felt::new(BigInteger256([
+            0xa6c1211a90b08669,
+            0x7754d9d236fe6897,
+            0x458d57c6d2a50833,
+            0x261537d2ee8119bd,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfbd5e184cc985cf1,
+            0x6c8e914257c00ee3,
+            0x0b600c92b07821a5,
+            0x2ca003aef067697c,
+        ])),
+        Felt::new(BigInteger256([
+            0xbd789a34dfbea9ea,
+            0x20cdcd2dd0a1077f,
+            0x621b67f04f5d2f3b,
+            0x1f7892147c978794,
+        ])),
+    ],
+];
@@ -0,0 +1,291 @@
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0x7346fc0cffffff6d,
+            0x517627467fa308c8,
+            0xffffffffffffffec,
+            0x3fffffffffffffff,
+        ])),
+        Felt::new(BigInteger256([
+            0xdd1178d05cddbbb9,
+            0xbd8c24862fe7c4d1,
+            0x04dfdb230c1bf060,
+            0x3659b14ca19b3891,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xba0d6b9b3fa3633d,
+            0xf99eef39c529d723,
+            0x7af28749f7395589,
+            0x02ef61c6b77a510f,
+        ])),
+        Felt::new(BigInteger256([
+            0xe0b3151465440acd,
+            0x3b48888c6c5565be,
+            0x5f2d2585f096804e,
+            0x291429a9ea04cb3f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x698d33f778f7c4fa,
+            0x870adf3fd9e4ec90,
+            0xa084add0a525fa2d,
+            0x1ac8048f2e389485,
+        ])),
+        Felt::new(BigInteger256([
+            0x6056828de708b943,
+            0x825dbbe3f5424de2,
+            0xf6baed51e54a76a3,
+            0x19f87a536cf65d5a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x79b834feef2c3839,
+            0xaae19cadc9407e29,
+            0x66d36f5b19289453,
+            0x34440a184a180800,
+        ])),
+        Felt::new(BigInteger256([
+            0xdcfec7f06a770db9,
+            0x19fc3b99f8731eb8,
+            0x573b5ec5a02fb0a5,
+            0x3211ce60c0dde888,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x8f9d974c0fddcdaa,
+            0x451b85da76eaa3bb,
+            0x4f05589eb4cd7c68,
+            0x14e18498bd951684,
+        ])),
+        Felt::new(BigInteger256([
+            0xcfae98be18a0c4e7,
+            0x4fed38d71eb4bfff,
+            0xf0725685381c83aa,
+            0x092a2f60cb5606b4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x32e5e8b5e721710e,
+            0x783d8096d132985c,
+            0x428ca9de5e3fe006,
+            0x384cfb58bd05691c,
+        ])),
+        Felt::new(BigInteger256([
+            0x1d55ea153e3948c1,
+            0x402e3d750f834353,
+            0x0760b8cf40c32b3c,
+            0x29d57ef2fd77eb59,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x271faad71772fc51,
+            0xe95b52fe5732e618,
+            0xd0504b779f895c73,
+            0x3cf7dffba61d8a86,
+        ])),
+        Felt::new(BigInteger256([
+            0xf8159070f5824aa5,
+            0xcb8739dac5ec94dc,
+            0x426cf3f16f092a30,
+            0x28c71fceb13f06a1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xebfc726afe288f17,
+            0x105c9600c55d5461,
+            0x6e45121d3212bccb,
+            0x277db81baa443223,
+        ])),
+        Felt::new(BigInteger256([
+            0x79c027999f48d390,
+            0xabc4f9e711570b5d,
+            0xd9b640bcb4a40845,
+            0x3abd153ea3d7be1e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xca19b06b4e6df2a3,
+            0xcce22050a4ad7858,
+            0xc644747d2cae1918,
+            0x177eaf84e1d0f4c9,
+        ])),
+        Felt::new(BigInteger256([
+            0xb89f7e263bff609c,
+            0x48305c88fa7bbe57,
+            0xee816831d64ba437,
+            0x337af74a01eda4c5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x4cd177b08d9633fb,
+            0x77467f1fb1ef8f62,
+            0x4bf0ddb4360a9750,
+            0x27a64389729f63b6,
+        ])),
+        Felt::new(BigInteger256([
+            0xe49efb7d804e4de7,
+            0xd6886c7479f7090a,
+            0x612144415c31d7b3,
+            0x3f53c94988bb2f1e,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xf93bea3e99999905,
+            0xe76b98e6704ec765,
+            0xccccccccccccccb8,
+            0x0ccccccccccccccc,
+        ])),
+        Felt::new(BigInteger256([
+            0x54b77d06498c2839,
+            0x615c40f05b9d31ea,
+            0x2c76c6a290adc30e,
+            0x2421f328b24eb6ab,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x2b5942a60725fb0e,
+            0x82fa22a8633a158e,
+            0x7222c0fd00a61c88,
+            0x3d0f46fc36cb6075,
+        ])),
+        Felt::new(BigInteger256([
+            0x2b222be17fdb7584,
+            0x8df134cd324600e9,
+            0xb1277debb1c84d2d,
+            0x042f83eead3c8bf2,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xe978ee1860aee0be,
+            0x3109d849e842304e,
+            0x9e6a224077aac46f,
+            0x2ad600d144e2d50c,
+        ])),
+        Felt::new(BigInteger256([
+            0x45ac679221d4a7ee,
+            0x17f0c6bc3514973e,
+            0x4f6a80746f9446c6,
+            0x0b01eba4c7874f2f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb57e8ab7feaec69e,
+            0x2f7a9fdbb5f3e6e1,
+            0xf227f11a3b85b6a4,
+            0x10e8ae3876dd9e99,
+        ])),
+        Felt::new(BigInteger256([
+            0x0a7633adcd0e6f06,
+            0xac6fe992203035ec,
+            0x3d59ff377a51d8d6,
+            0x2fb1e790318a306f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x828b305803f5fd96,
+            0x862a411d717cf719,
+            0x0f9c5b2bcc9d5b90,
+            0x356fb258c8b075f6,
+        ])),
+        Felt::new(BigInteger256([
+            0x28065cad5fcdc7ba,
+            0x7c9005e84abc18fb,
+            0x0bd377c507b168b3,
+            0x0ab3d2301a581774,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x6ce057804b0a17ad,
+            0x916a08e0fd6a7e29,
+            0x041f00529db459c9,
+            0x2a8b216cdae77b2e,
+        ])),
+        Felt::new(BigInteger256([
+            0x49016ee3f536c248,
+            0x6735708976c4d79b,
+            0x23bd2df637fcaae0,
+            0x3d0f1a165f40aeb8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfc82d052d6df88cf,
+            0xe94aae1c2867a290,
+            0xef69272bbe5d7e80,
+            0x05a2f82d7ef1affb,
+        ])),
+        Felt::new(BigInteger256([
+            0xbf29cbf10803aa0b,
+            0xd9513fc2d22affd0,
+            0xbc4fee5845a2521e,
+            0x126dad0fcdf9dd63,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc848163e0d44e6d8,
+            0xe3d056079f9b7269,
+            0x9803ca32145c41bf,
+            0x053c63e6cdf2161b,
+        ])),
+        Felt::new(BigInteger256([
+            0x47bce1710179fe39,
+            0x8d1364b8269ed7e0,
+            0x5e3f17844eb2931b,
+            0x397736190b6c5364,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x105609aeb533f374,
+            0x2bf1e08032a32853,
+            0x4a62c0966a99a8eb,
+            0x142f1db7015fc17b,
+        ])),
+        Felt::new(BigInteger256([
+            0x6446024cf5da3454,
+            0x92d42e86b9e673ef,
+            0xcd69d2fdcbfc39ea,
+            0x1126da8b656322c4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x05b85a24ac81294a,
+            0xb4ce7d0a14651042,
+            0xa2fa7a5c6a57a856,
+            0x312eda050b089406,
+        ])),
+        Felt::new(BigInteger256([
+            0x02f008d4f24e161d,
+            0xffa47c2d0de18f88,
+            0x12f4ff9c4843ee9a,
+            0x29d7d4d4650b10bc,
+        ])),
+    ],
+];